@@ -1,4 +1,6 @@
 
+#![allow(clippy::needless_range_loop)]
+
 use criterion::{criterion_group, criterion_main, Criterion};
 use std::hint::{black_box};
 
@@ -6,8 +8,6 @@ use rust_poseidon_bn254_pure::bn254::field::*;
 
 //------------------------------------------------------------------------------
 
-type Triple = [Felt; 3];
-
 fn initial_state<const T: usize>() -> [Felt; T] {
   let mut xs: [Felt; T] = [Default::default(); T];
   for i in 0..T {
@@ -40,7 +40,7 @@ mod v2 {
   use super::*;
   use rust_poseidon_bn254_pure::poseidon2::old::*;
 
-  fn iterate_poseidon2<const T: usize>(n: usize) -> [Felt; T] where Params: Poseidon2Params<false,T> {
+  fn iterate_poseidon2<const T: usize>(n: usize) -> [Felt; T] where Old: Poseidon2Instance<T> {
     let mut state: [Felt; T] = initial_state::<T>();
     for _i in 0..n {
       state = permute::<T>(state);
@@ -48,7 +48,7 @@ mod v2 {
     state
   }
   
-  pub fn bench_iterated_poseidon2<const T: usize>(c: &mut Criterion, n: usize) where Params: Poseidon2Params<false,T> {
+  pub fn bench_iterated_poseidon2<const T: usize>(c: &mut Criterion, n: usize) where Old: Poseidon2Instance<T> {
     let msg = format!("Poseidon2 permutation w/ state width t={} iterated {} times", T, n);
     c.bench_function(&msg, |b| b.iter(|| iterate_poseidon2::<T>(black_box(n)) ));
   }
@@ -0,0 +1,100 @@
+#!/usr/bin/env python3
+#
+# a reference implementation of circomlib's Poseidon (`PoseidonEx` in
+# `circuits/poseidon.circom`), reading the constants directly from the
+# upstream `poseidon_constants.circom` in this directory
+#
+# This is the "optimized" form used by both circomlib and circomlibjs: the
+# round constants `C` are pre-transformed, the last full round before the
+# partial rounds uses the matrix `P`, and the partial rounds use the sparse
+# matrices `S`. The number of full rounds is 8; the number of partial rounds
+# depends on the width (`N_ROUNDS_P` below, from `poseidon.circom`).
+#
+# It is independent of the Rust constant tables, so it is used to generate
+# the test vectors for the widths we don't have circomlibjs outputs for.
+#
+# usage: python3 poseidon_circomlib.py
+#
+
+import os
+import re
+
+P = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+
+N_ROUNDS_F = 8
+N_ROUNDS_P = [ 56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65, 70, 60, 64, 68 ]
+
+#-------------------------------------------------------------------------------
+# parse the constants: each function is a chain of `if (t==N) { return ... }`
+
+def parse_function(src, name):
+  body  = src[ src.index('function %s(t)' % name): ]
+  end   = body.find('\n}\n')
+  body  = body if end < 0 else body[:end]
+  parts = re.split( r'if \(t\s*==\s*(\d+)\)' , body )
+  table = {}
+  for i in range(1, len(parts), 2):
+    table[int(parts[i])] = [ int(h,16) for h in re.findall( r'0x[0-9a-fA-F]+' , parts[i+1] ) ]
+  return table
+
+SRC = open( os.path.join( os.path.dirname(os.path.abspath(__file__)) , 'poseidon_constants.circom' ) ).read()
+
+C = parse_function( SRC , 'POSEIDON_C' )
+M = parse_function( SRC , 'POSEIDON_M' )
+Q = parse_function( SRC , 'POSEIDON_P' )
+S = parse_function( SRC , 'POSEIDON_S' )
+
+#-------------------------------------------------------------------------------
+
+def square(t, xs):
+  assert len(xs) == t*t
+  return [ xs[i*t:(i+1)*t] for i in range(t) ]
+
+def sbox(x):
+  return pow(x, 5, P)
+
+# `out[i] = sum_j m[j][i] * in[j]`, as circomlib's `Mix`
+def mix(t, m, state):
+  return [ sum( m[j][i] * state[j] for j in range(t) ) % P for i in range(t) ]
+
+def ark(t, c, ofs, state):
+  return [ (x + c[ofs+i]) % P for i,x in enumerate(state) ]
+
+def poseidon_ex(inputs, initial_state=0, n_outs=1):
+  t  = len(inputs) + 1
+  nF = N_ROUNDS_F // 2
+  nP = N_ROUNDS_P[t-2]
+  c  = C[t]
+  s  = S[t]
+  m  = square( t , M[t] )
+  q  = square( t , Q[t] )
+
+  state = ark( t , c , 0 , [initial_state] + list(inputs) )
+  for r in range(nF-1):
+    state = ark( t , c , (r+1)*t , [ sbox(x) for x in state ] )
+    state = mix( t , m , state )
+  state = ark( t , c , nF*t , [ sbox(x) for x in state ] )
+  state = mix( t , q , state )
+
+  for r in range(nP):
+    x0   = ( sbox(state[0]) + c[(nF+1)*t + r] ) % P
+    ofs  = (2*t-1)*r
+    y0   = ( s[ofs] * x0 + sum( s[ofs+i] * state[i] for i in range(1,t) ) ) % P
+    state = [y0] + [ (state[i] + x0 * s[ofs+t+i-1]) % P for i in range(1,t) ]
+
+  for r in range(nF-1):
+    state = ark( t , c , (nF+1)*t + nP + r*t , [ sbox(x) for x in state ] )
+    state = mix( t , m , state )
+  state = mix( t , m , [ sbox(x) for x in state ] )
+
+  return state[:n_outs]
+
+def poseidon(inputs):
+  return poseidon_ex(inputs)[0]
+
+#-------------------------------------------------------------------------------
+
+if __name__ == '__main__':
+  for n in range(1,17):
+    print( 'poseidon([1..%d]) = %d' % ( n , poseidon( list(range(1,n+1)) ) ) )
+
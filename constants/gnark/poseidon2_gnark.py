@@ -0,0 +1,154 @@
+#!/usr/bin/env python3
+#
+# generates gnark-crypto's BN254 Poseidon2 round constants 
+# (see `ecc/bn254/fr/poseidon2` in gnark-crypto)
+#
+# The constants are derived from the seed string
+#
+#   "Poseidon2-BN254[t=<width>,rF=<full rounds>,rP=<partial rounds>,d=5]"
+#
+# by iterating (legacy, pre-standard) Keccak-256: rnd_0 = keccak(seed), and 
+# rnd_{i+1} = keccak(rnd_i) is the i-th constant, as a big-endian integer 
+# reduced modulo the field prime. The order is: full rounds (`t` constants
+# each), partial rounds (one constant each), full rounds.
+#
+# usage: python3 poseidon2_gnark.py [output directory]
+#
+
+import os
+import sys
+
+P = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+
+# (width, full rounds, partial rounds)
+INSTANCES = [ (2,6,50) , (3,8,56) ]
+
+#-------------------------------------------------------------------------------
+# Keccak-256 (with the original 0x01 padding, as in Ethereum)
+
+RC = [ 0x0000000000000001, 0x0000000000008082, 0x800000000000808A, 0x8000000080008000
+     , 0x000000000000808B, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009
+     , 0x000000000000008A, 0x0000000000000088, 0x0000000080008009, 0x000000008000000A
+     , 0x000000008000808B, 0x800000000000008B, 0x8000000000008089, 0x8000000000008003
+     , 0x8000000000008002, 0x8000000000000080, 0x000000000000800A, 0x800000008000000A
+     , 0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008 ]
+
+ROT = [ [ 0, 36,  3, 41, 18]
+      , [ 1, 44, 10, 45,  2]
+      , [62,  6, 43, 15, 61]
+      , [28, 55, 25, 21, 56]
+      , [27, 20, 39,  8, 14] ]
+
+M64 = (1 << 64) - 1
+
+def rol(x, n):
+  return ((x << n) | (x >> (64 - n))) & M64 if n else x
+
+def keccak_f(A):
+  for rc in RC:
+    C = [ A[x][0] ^ A[x][1] ^ A[x][2] ^ A[x][3] ^ A[x][4] for x in range(5) ]
+    D = [ C[(x-1) % 5] ^ rol(C[(x+1) % 5], 1) for x in range(5) ]
+    A = [ [ A[x][y] ^ D[x] for y in range(5) ] for x in range(5) ]
+    B = [ [0]*5 for _ in range(5) ]
+    for x in range(5):
+      for y in range(5):
+        B[y][(2*x + 3*y) % 5] = rol(A[x][y], ROT[x][y])
+    A = [ [ B[x][y] ^ ((~B[(x+1) % 5][y]) & B[(x+2) % 5][y]) for y in range(5) ] for x in range(5) ]
+    A[0][0] ^= rc
+  return A
+
+def keccak256(msg):
+  rate = 136
+  msg  = bytearray(msg) + b'\x01'
+  while len(msg) % rate != 0:
+    msg += b'\x00'
+  msg[-1] |= 0x80
+  A = [ [0]*5 for _ in range(5) ]
+  for off in range(0, len(msg), rate):
+    block = msg[off:off+rate]
+    for i in range(rate // 8):
+      x, y = i % 5, i // 5
+      A[x][y] ^= int.from_bytes(block[8*i:8*i+8], 'little')
+    A = keccak_f(A)
+  out = b''
+  for i in range(4):
+    out += A[i % 5][i // 5].to_bytes(8, 'little')
+  return out
+
+assert keccak256(b'').hex() == 'c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470'
+
+#-------------------------------------------------------------------------------
+
+def round_constants(t, rf, rp):
+  seed = "Poseidon2-BN254[t=%d,rF=%d,rP=%d,d=5]" % (t, rf, rp)
+  rnd  = keccak256(seed.encode())
+  def next_const():
+    nonlocal rnd
+    rnd = keccak256(rnd)
+    return int.from_bytes(rnd, 'big') % P
+  initial  = [ next_const() for _ in range((rf//2) * t) ]
+  internal = [ next_const() for _ in range(rp) ]
+  final    = [ next_const() for _ in range((rf//2) * t) ]
+  return (initial, internal, final)
+
+#-------------------------------------------------------------------------------
+# reference permutation (for the regression test; note that its output is not
+# checked against gnark-crypto itself)
+
+def mds(xs):
+  s = sum(xs)
+  return [ (s + x) % P for x in xs ]
+
+def diag(xs):
+  # the internal matrix is `1 + diag(1,..,1,2)`
+  s = sum(xs)
+  return [ (s + x) % P for x in xs[:-1] ] + [ (s + 2*xs[-1]) % P ]
+
+def permute(t, rf, rp, xs):
+  (initial, internal, final) = round_constants(t, rf, rp)
+  xs = mds(xs)
+  for r in range(rf//2):
+    xs = mds([ pow(x + c, 5, P) for (x,c) in zip(xs, initial[r*t:(r+1)*t]) ])
+  for c in internal:
+    xs = diag([ pow(xs[0] + c, 5, P) ] + xs[1:])
+  for r in range(rf//2):
+    xs = mds([ pow(x + c, 5, P) for (x,c) in zip(xs, final[r*t:(r+1)*t]) ])
+  return xs
+
+#-------------------------------------------------------------------------------
+# rust output
+
+SEP = [ "" , "//------------------------------------------------------------------------------" , "" ]
+
+def rust_mont(x):
+  m  = (x << 256) % P
+  ws = [ (m >> (32*i)) & 0xffffffff for i in range(8) ]
+  return "Mont::unsafe_make( [ " + " , ".join("0x%08x" % w for w in ws) + " ] )"
+
+def rust_const(comment, name, xs):
+  ls = [ ("  [ " if i == 0 else "  , ") + rust_mont(x) for (i,x) in enumerate(xs) ]
+  return [ comment , "pub const %s: [Mont; %d] = " % (name, len(xs)) ] + ls + [ "  ];" ]
+
+def rust_source(t, rf, rp):
+  (initial, internal, final) = round_constants(t, rf, rp)
+  kat = permute(t, rf, rp, list(range(t)))
+  ls  = [ "" 
+        , "// gnark-crypto's Poseidon2 constants for `t = %d` (`rF = %d`, `rP = %d`)" % (t, rf, rp)
+        , "" 
+        , "use crate::bn254::montgomery::*;" 
+        , "" ]
+  ls += SEP + rust_const( "// initial (external) round constants (flattened)" , "INITIAL"  , initial  )
+  ls += SEP + rust_const( "// middle (internal) round constants"              , "INTERNAL" , internal )
+  ls += SEP + rust_const( "// final (external) round constants (flattened)"   , "FINAL"    , final    )
+  ls += SEP + rust_const( "// regression answer for the input [0,1,..]: from the reference\n// permutation of the generator script, not from gnark-crypto" , "KAT_MONT" , kat )
+  ls += SEP
+  return "\n".join(ls) + "\n"
+
+if __name__ == "__main__":
+  tgt = sys.argv[1] if len(sys.argv) > 1 else "out"
+  os.makedirs(tgt, exist_ok=True)
+  for (t,rf,rp) in INSTANCES:
+    path = os.path.join(tgt, "t%d.rs" % t)
+    print(path)
+    with open(path, "w") as f:
+      f.write(rust_source(t, rf, rp))
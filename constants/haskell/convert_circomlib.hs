@@ -88,6 +88,6 @@ writeRustConstants w@(MkW t) = do
 main :: IO ()
 main = do
   createDirectoryIfMissing False tgtDir
-  forM_ [2..5] $ \t -> writeRustConstants (MkW t)
+  forM_ [2..17] $ \t -> writeRustConstants (MkW t)
 
 --------------------------------------------------------------------------------
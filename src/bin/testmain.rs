@@ -1,13 +1,11 @@
 
 #![allow(unused)]
-#![feature(random)]
+#![allow(clippy::println_empty_string)]
 
 use std::time::Instant;
 
-use std::ops::{RangeFull};
-use std::random::{Distribution,DefaultRandomSource,random};
-
 use rust_poseidon_bn254_pure::bn254::traits::*;
+use rust_poseidon_bn254_pure::bn254::random::*;
 use rust_poseidon_bn254_pure::bn254::bigint::*;
 use rust_poseidon_bn254_pure::bn254::constant::*;
 use rust_poseidon_bn254_pure::bn254::montgomery::*;
@@ -18,11 +16,11 @@ use rust_poseidon_bn254_pure::poseidon2;
 
 //------------------------------------------------------------------------------
 
-type Big = BigInt<8>;
+type Big = BigInt256;
 
-const BIG1  : Big  = BigInt::make( [ 0x096113a8 , 0x5734d4ed , 0xef9d7088 , 0xf5b8189d , 0x9941cff9 , 0x233b0905 , 0x1d027fb4 , 0x7279de57 ] );
-const BIG2  : Big  = BigInt::make( [ 0x7efecde8 , 0xa15ee255 , 0x10e87829 , 0x0f02eb31 , 0x74348ba5 , 0x6c7965aa , 0xdba5852c , 0x997936ef ] );
-const BIG3  : Big  = BigInt::make( [ 0x928f0277 , 0xbfe4f704 , 0x382292f7 , 0x4e01efdc , 0x387b826d , 0x432e0d72 , 0x25c1e982 , 0x2437a9f1 ] );
+const BIG1  : Big  = BigInt::from_words32( [ 0x096113a8 , 0x5734d4ed , 0xef9d7088 , 0xf5b8189d , 0x9941cff9 , 0x233b0905 , 0x1d027fb4 , 0x7279de57 ] );
+const BIG2  : Big  = BigInt::from_words32( [ 0x7efecde8 , 0xa15ee255 , 0x10e87829 , 0x0f02eb31 , 0x74348ba5 , 0x6c7965aa , 0xdba5852c , 0x997936ef ] );
+const BIG3  : Big  = BigInt::from_words32( [ 0x928f0277 , 0xbfe4f704 , 0x382292f7 , 0x4e01efdc , 0x387b826d , 0x432e0d72 , 0x25c1e982 , 0x2437a9f1 ] );
 
 const FELT1 : Felt = Felt::unsafe_make( [ 0x20dc4a9f , 0x00d47967 , 0xca5c5b8a , 0xd03cc3b9 , 0xbf51bf7f , 0x5ab6f194 , 0x6024036b , 0x22ac1ee6 ] );
 const FELT2 : Felt = Felt::unsafe_make( [ 0xe34d39b8 , 0x1f9c48a6 , 0xeb5f4c17 , 0x703bcf35 , 0xa362c094 , 0x596d982a , 0x7b59b4fa , 0x2063f06a ] );
@@ -79,7 +77,7 @@ fn main() {
   println!("B3+B1 = {} + {}", z, e);  
   println!("-----");
 
-  let u: BigInt<16> = BigInt::<8>::mul( BIG1, BIG2 );
+  let u: BigInt512 = BigInt256::mul( BIG1, BIG2 );
   println!("B1*B2 = {}", u);  
 
   println!("");
@@ -138,7 +136,7 @@ fn main() {
   println!("");
 
   let now = Instant::now();
-  let mut state: [Felt; 3] = input.clone(); 
+  let mut state: [Felt; 3] = input; 
   for _i in 0..10000 {
     state = poseidon2::old::permute(state);
   }
@@ -209,8 +207,9 @@ fn main() {
 
   println!("");
   println!("20 random field elements");
+  let mut source = SipRandomSource::new();
   for i in 0..20 {
-    let x: Felt = random(..);
+    let x: Felt = Felt::sample(&mut source);
     println!(" - {} {} {}", x, Felt::to_bigint(x) < FIELD_PRIME , Felt::is_valid(x) );
   }
   
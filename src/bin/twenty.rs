@@ -12,7 +12,7 @@ fn main() {
   println!("y  = {}", input[1] );
   println!("z  = {}", input[2] );
 
-  let mut state: [Felt; 3] = input.clone(); 
+  let mut state: [Felt; 3] = input; 
   for _i in 0..20 {
     state = poseidon2::old::permute(state);
   }
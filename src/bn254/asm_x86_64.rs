@@ -0,0 +1,247 @@
+
+//
+// Montgomery multiplication on x86_64 using the BMI2 / ADX instructions
+//
+// `mulx` is a widening multiplication which doesn't touch the flags, while `adcx`
+// and `adox` are add-with-carry instructions using only the carry (resp. the
+// overflow) flag; so we can run two independent carry chains in parallel, one
+// for the low and one for the high halves of the products.
+//
+// The algorithm is the same "no-carry" CIOS as the portable `Mont::mul_cios`,
+// with 4 limbs of 64 bits. These instructions are not available on every x86_64
+// CPU, so the caller must check `is_supported()` first (this is done by `Mont::mul`).
+//
+
+use crate::bn254::bigint::*;
+use crate::bn254::constant::*;
+use crate::bn254::platform::{Limb,NLIMBS,LIMB_BITS};
+
+//------------------------------------------------------------------------------
+
+type Big = BigInt256;
+
+// the field prime as 64 bit words
+static PRIME64: [u64; 4] = to_words64(FIELD_PRIME);
+
+// -1/p mod 2^64
+const MONT_Q64: u64 = 0xc2e1f593efffffff;
+
+//------------------------------------------------------------------------------
+
+// with 64 bit limbs these are just copies (the casts are needed for 32 bit limbs)
+#[inline(always)]
+#[allow(clippy::unnecessary_cast)]
+const fn to_words64(big: Big) -> [u64; 4] {
+  if LIMB_BITS == 64 {
+    let ls: [Limb; NLIMBS] = BigInt::to_limbs(big);
+    [ ls[0] as u64 , ls[1] as u64 , ls[2] as u64 , ls[3] as u64 ]
+  }
+  else {
+    let ws: [u32; 8] = BigInt::to_words32(big);
+    [ (ws[0] as u64) | ((ws[1] as u64) << 32)
+    , (ws[2] as u64) | ((ws[3] as u64) << 32)
+    , (ws[4] as u64) | ((ws[5] as u64) << 32)
+    , (ws[6] as u64) | ((ws[7] as u64) << 32)
+    ]
+  }
+}
+
+#[inline(always)]
+fn from_words64(xs: [u64; 4]) -> Big {
+  if LIMB_BITS == 64 {
+    let mut ls: [Limb; NLIMBS] = [0; NLIMBS];
+    for i in 0..4 { ls[i] = xs[i] as Limb; }
+    BigInt::make(ls)
+  }
+  else {
+    BigInt::from_words32(
+      [ xs[0] as u32 , (xs[0] >> 32) as u32
+      , xs[1] as u32 , (xs[1] >> 32) as u32
+      , xs[2] as u32 , (xs[2] >> 32) as u32
+      , xs[3] as u32 , (xs[3] >> 32) as u32
+      ] )
+  }
+}
+
+// runtime CPU feature detection (the result is cached by `std`)
+#[inline(always)]
+pub fn is_supported() -> bool {
+  std::is_x86_feature_detected!("bmi2") && std::is_x86_feature_detected!("adx")
+}
+
+//------------------------------------------------------------------------------
+
+/// `x*y/2^256 mod 2p`; both inputs must be less than `p`.
+///
+/// # Safety
+///
+/// The CPU must support the BMI2 and ADX extensions, see `is_supported()`
+#[inline(always)]
+pub unsafe fn mont_mul_adx(big1: Big, big2: Big) -> Big {
+
+  let a: [u64; 4] = to_words64(big1);
+  let b: [u64; 4] = to_words64(big2);
+  let r0: u64;
+  let r1: u64;
+  let r2: u64;
+  let r3: u64;
+
+  // the state `T` lives in `r8..r12`; after each round, the registers rotate
+  // by one, as the lowest word of `T` becomes zero and is shifted out
+  std::arch::asm!(
+
+    // round 0: T = a*b[0]
+    "mov   rdx, qword ptr [{b}]",
+    "mulx  r9,  r8,  qword ptr [{a}]",
+    "mulx  r10, rax, qword ptr [{a} + 8]",
+    "add   r9,  rax",
+    "mulx  r11, rax, qword ptr [{a} + 16]",
+    "adc   r10, rax",
+    "mulx  r12, rax, qword ptr [{a} + 24]",
+    "adc   r11, rax",
+    "adc   r12, 0",
+    // T += m*p, where m = T[0]*q
+    "mov   rdx, r8",
+    "imul  rdx, {q}",
+    "xor   eax, eax",
+    "mulx  r13, rax, qword ptr [{p}]",
+    "adox  r8,  rax",
+    "adcx  r9,  r13",
+    "mulx  r13, rax, qword ptr [{p} + 8]",
+    "adox  r9,  rax",
+    "adcx  r10, r13",
+    "mulx  r13, rax, qword ptr [{p} + 16]",
+    "adox  r10, rax",
+    "adcx  r11, r13",
+    "mulx  r13, rax, qword ptr [{p} + 24]",
+    "adox  r11, rax",
+    "adcx  r12, r13",
+    "mov   eax, 0",
+    "adox  r12, rax",
+
+    // round 1: T = [r9,r10,r11,r12,r8]
+    "mov   rdx, qword ptr [{b} + 8]",
+    "xor   r8d, r8d",
+    "mulx  r13, rax, qword ptr [{a}]",
+    "adox  r9,  rax",
+    "adcx  r10, r13",
+    "mulx  r13, rax, qword ptr [{a} + 8]",
+    "adox  r10, rax",
+    "adcx  r11, r13",
+    "mulx  r13, rax, qword ptr [{a} + 16]",
+    "adox  r11, rax",
+    "adcx  r12, r13",
+    "mulx  r13, rax, qword ptr [{a} + 24]",
+    "adox  r12, rax",
+    "adcx  r8,  r13",
+    "mov   eax, 0",
+    "adox  r8,  rax",
+    "mov   rdx, r9",
+    "imul  rdx, {q}",
+    "xor   eax, eax",
+    "mulx  r13, rax, qword ptr [{p}]",
+    "adox  r9,  rax",
+    "adcx  r10, r13",
+    "mulx  r13, rax, qword ptr [{p} + 8]",
+    "adox  r10, rax",
+    "adcx  r11, r13",
+    "mulx  r13, rax, qword ptr [{p} + 16]",
+    "adox  r11, rax",
+    "adcx  r12, r13",
+    "mulx  r13, rax, qword ptr [{p} + 24]",
+    "adox  r12, rax",
+    "adcx  r8,  r13",
+    "mov   eax, 0",
+    "adox  r8,  rax",
+
+    // round 2: T = [r10,r11,r12,r8,r9]
+    "mov   rdx, qword ptr [{b} + 16]",
+    "xor   r9d, r9d",
+    "mulx  r13, rax, qword ptr [{a}]",
+    "adox  r10, rax",
+    "adcx  r11, r13",
+    "mulx  r13, rax, qword ptr [{a} + 8]",
+    "adox  r11, rax",
+    "adcx  r12, r13",
+    "mulx  r13, rax, qword ptr [{a} + 16]",
+    "adox  r12, rax",
+    "adcx  r8,  r13",
+    "mulx  r13, rax, qword ptr [{a} + 24]",
+    "adox  r8,  rax",
+    "adcx  r9,  r13",
+    "mov   eax, 0",
+    "adox  r9,  rax",
+    "mov   rdx, r10",
+    "imul  rdx, {q}",
+    "xor   eax, eax",
+    "mulx  r13, rax, qword ptr [{p}]",
+    "adox  r10, rax",
+    "adcx  r11, r13",
+    "mulx  r13, rax, qword ptr [{p} + 8]",
+    "adox  r11, rax",
+    "adcx  r12, r13",
+    "mulx  r13, rax, qword ptr [{p} + 16]",
+    "adox  r12, rax",
+    "adcx  r8,  r13",
+    "mulx  r13, rax, qword ptr [{p} + 24]",
+    "adox  r8,  rax",
+    "adcx  r9,  r13",
+    "mov   eax, 0",
+    "adox  r9,  rax",
+
+    // round 3: T = [r11,r12,r8,r9,r10]
+    "mov   rdx, qword ptr [{b} + 24]",
+    "xor   r10d, r10d",
+    "mulx  r13, rax, qword ptr [{a}]",
+    "adox  r11, rax",
+    "adcx  r12, r13",
+    "mulx  r13, rax, qword ptr [{a} + 8]",
+    "adox  r12, rax",
+    "adcx  r8,  r13",
+    "mulx  r13, rax, qword ptr [{a} + 16]",
+    "adox  r8,  rax",
+    "adcx  r9,  r13",
+    "mulx  r13, rax, qword ptr [{a} + 24]",
+    "adox  r9,  rax",
+    "adcx  r10, r13",
+    "mov   eax, 0",
+    "adox  r10, rax",
+    "mov   rdx, r11",
+    "imul  rdx, {q}",
+    "xor   eax, eax",
+    "mulx  r13, rax, qword ptr [{p}]",
+    "adox  r11, rax",
+    "adcx  r12, r13",
+    "mulx  r13, rax, qword ptr [{p} + 8]",
+    "adox  r12, rax",
+    "adcx  r8,  r13",
+    "mulx  r13, rax, qword ptr [{p} + 16]",
+    "adox  r8,  rax",
+    "adcx  r9,  r13",
+    "mulx  r13, rax, qword ptr [{p} + 24]",
+    "adox  r9,  rax",
+    "adcx  r10, r13",
+    "mov   eax, 0",
+    "adox  r10, rax",
+
+    // the result is T = [r12,r8,r9,r10]
+
+    a = in(reg) a.as_ptr(),
+    b = in(reg) b.as_ptr(),
+    p = in(reg) PRIME64.as_ptr(),
+    q = in(reg) MONT_Q64,
+    out("rax") _,
+    out("rdx") _,
+    out("r13") _,
+    out("r12") r0,
+    out("r8")  r1,
+    out("r9")  r2,
+    out("r10") r3,
+    out("r11") _,
+    options(pure, readonly, nostack),
+  );
+
+  from_words64( [r0,r1,r2,r3] )
+}
+
+//------------------------------------------------------------------------------
@@ -1,33 +1,38 @@
 
 
 //
-// big integers, represented as little-endian arrays of u32-s
+// big integers, represented as little-endian arrays of limbs
+// (either `u32` or `u64`, see `platform`)
 //
 
 #![allow(dead_code)]
 #![allow(non_snake_case)]
 #![allow(unused_parens)]
+
+// the limb loops are written with explicit indices, mirroring the math
+#![allow(clippy::needless_range_loop)]
 #![allow(unused_imports)]
 
 use std::fmt;
+use std::str::FromStr;
 use std::cmp::{Ordering,min};
-use std::ops::{Neg,Add,Sub,RangeFull};
-
-use std::random::{RandomSource,Distribution};
+use std::ops::{Neg,Add,Sub};
 
 use unroll::unroll_for_loops;
 
 use crate::bn254::traits::*;
+use crate::bn254::random::*;
+use crate::bn254::error::*;
 use crate::bn254::platform::*;
 use crate::bn254::constant::*;
 
 //------------------------------------------------------------------------------
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub struct BigInt<const N: usize>([u32; N]);
+pub struct BigInt<const N: usize>([Limb; N]);
 
-pub type BigInt256 = BigInt<8>;
-pub type BigInt512 = BigInt<16>;
+pub type BigInt256 = BigInt<NLIMBS>;
+pub type BigInt512 = BigInt<{2*NLIMBS}>;
 
 //------------------------------------------------------------------------------
 // display traits
@@ -36,7 +41,7 @@ impl<const N: usize> fmt::Display for BigInt<N> {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
     write!(f, "0x")?;
     for i in (0..N).rev() {
-      write!(f, "{:08x}", self.0[i])?;
+      write!(f, "{:0width$x}", self.0[i], width = 2*LIMB_BYTES)?;
     }
     Ok(())
   }
@@ -67,7 +72,7 @@ impl<const N: usize> Sub for BigInt<N> {
 }
 
 impl<const N: usize> PartialOrd for BigInt<N> {
-  fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(BigInt::cmp(*self, *other)) }
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(Ord::cmp(self, other)) }
 }
 
 impl<const N: usize> Ord for BigInt<N> {
@@ -87,15 +92,25 @@ impl<const N: usize> One for BigInt<N> {
   fn is_one(x: Self) -> bool { BigInt::is_one(x) }
 }
 
+impl<const N: usize> Zeroize for BigInt<N> {
+  fn zeroize(&mut self) {
+    for x in self.0.iter_mut() {
+      // volatile writes are never elided by the compiler
+      unsafe { std::ptr::write_volatile(x, 0) }
+    }
+    std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+  }
+}
+
 //------------------------------------------------------------------------------
 // conversion traits
 
-impl<const N: usize> From<[u32; N]> for BigInt<N> {
-  fn from(limbs: [u32; N]) -> Self { BigInt(limbs) }
+impl<const N: usize> From<[Limb; N]> for BigInt<N> {
+  fn from(limbs: [Limb; N]) -> Self { BigInt(limbs) }
 }
 
-impl<const N: usize> Into<[u32; N]> for BigInt<N> {
-  fn into(self: Self) -> [u32; N] { self.0 }
+impl<const N: usize> From<BigInt<N>> for [Limb; N] {
+  fn from(big: BigInt<N>) -> Self { big.0 }
 }
 
 //------------------------------------------------------------------------------
@@ -110,97 +125,113 @@ impl<const N: usize> From<u32> for BigInt<N> {
 }
 
 //------------------------------------------------------------------------------
-// random trait
+// parsing
 
-impl<const N: usize> Distribution<BigInt<N>> for RangeFull {
-  fn sample(&self, source: &mut (impl RandomSource + ?Sized)) -> BigInt<N> {
-    BigInt::sample(source)
-  }
+// decimal, or hexadecimal with a `0x` prefix
+impl<const N: usize> FromStr for BigInt<N> {
+  type Err = FeltError;
+  fn from_str(s: &str) -> Result<Self, FeltError> { BigInt::parse(s) }
 }
 
 //------------------------------------------------------------------------------
 // internal implementations
 
+#[allow(clippy::should_implement_trait)]
 impl<const N: usize> BigInt<N> {
 
   #[inline(always)]
-  pub const fn to_limbs(big: BigInt<N>) -> [u32; N] { big.0 }
+  pub const fn to_limbs(big: BigInt<N>) -> [Limb; N] { big.0 }
 
   #[inline(always)]
-  pub const fn from_limbs(limbs: [u32; N]) -> BigInt<N> { BigInt(limbs) }
+  pub const fn from_limbs(limbs: [Limb; N]) -> BigInt<N> { BigInt(limbs) }
  
   #[inline(always)]
-  pub const fn make(ls: [u32; N]) -> BigInt<N> { BigInt(ls) }
+  pub const fn make(ls: [Limb; N]) -> BigInt<N> { BigInt(ls) }
 
   //------------------------------------
   // conversion to/from bytes
+  //
+  // (stable rust cannot express `[u8; LIMB_BYTES*N]`, so the generic versions
+  // work with slices of length `LIMB_BYTES*N`; see also the fixed size 
+  // versions for `BigInt256` below)
 
-  pub fn to_le_bytes(big: BigInt<N>) -> [u8; 4*N] {
-    let mut buf : [u8; 4*N] = [0; 4*N];
+  pub fn write_le_bytes(big: BigInt<N>, buf: &mut [u8]) {
+    assert_eq!( buf.len() , LIMB_BYTES*N , "BigInt::write_le_bytes: wrong buffer size" );
     for i in 0..N {
-      let k = 4*i;
-      buf[k..k+4].copy_from_slice(&big.0[i].to_le_bytes());
+      let k = LIMB_BYTES*i;
+      buf[k..k+LIMB_BYTES].copy_from_slice(&big.0[i].to_le_bytes());
     }
-    buf
   }
 
-  pub fn from_le_bytes(buf : [u8; 4*N]) -> BigInt<N> {
-    let mut ws: [u32; N] = [0; N];
+  pub fn read_le_bytes(buf : &[u8]) -> BigInt<N> {
+    assert_eq!( buf.len() , LIMB_BYTES*N , "BigInt::read_le_bytes: wrong buffer size" );
+    let mut ws: [Limb; N] = [0; N];
     for i in 0..N {
-      let k = 4*i;
-      let mut xs: [u8; 4] = [0; 4];
-      for j in 0..4 { xs[j] = buf[k+j]; }       // stupid rust...
-      let w: u32 = u32::from_le_bytes(xs);
+      let k = LIMB_BYTES*i;
+      let mut xs: [u8; LIMB_BYTES] = [0; LIMB_BYTES];
+      xs.copy_from_slice(&buf[k..k+LIMB_BYTES]);
+      let w: Limb = Limb::from_le_bytes(xs);
       ws[i] = w;
     }
     BigInt(ws)
   }
 
-  pub fn to_be_bytes(big: BigInt<N>) -> [u8; 4*N] {
-    let mut buf : [u8; 4*N] = [0; 4*N];
+  pub fn write_be_bytes(big: BigInt<N>, buf: &mut [u8]) {
+    assert_eq!( buf.len() , LIMB_BYTES*N , "BigInt::write_be_bytes: wrong buffer size" );
     for i in 0..N {
-      let k = 4*i;
-      buf[k..k+4].copy_from_slice(&big.0[N-1-i].to_be_bytes());
+      let k = LIMB_BYTES*i;
+      buf[k..k+LIMB_BYTES].copy_from_slice(&big.0[N-1-i].to_be_bytes());
     }
-    buf
   }
 
-  pub fn from_be_bytes(buf: [u8; 4*N]) -> BigInt<N> {
-    let mut ws: [u32; N] = [0; N];
+  pub fn read_be_bytes(buf: &[u8]) -> BigInt<N> {
+    assert_eq!( buf.len() , LIMB_BYTES*N , "BigInt::read_be_bytes: wrong buffer size" );
+    let mut ws: [Limb; N] = [0; N];
     for i in 0..N {
-      let k = 4*i;
-      let mut xs: [u8; 4] = [0; 4];
-      for j in 0..4 { xs[j] = buf[k+j]; }   
-      let w: u32 = u32::from_be_bytes(xs);
+      let k = LIMB_BYTES*i;
+      let mut xs: [u8; LIMB_BYTES] = [0; LIMB_BYTES];
+      xs.copy_from_slice(&buf[k..k+LIMB_BYTES]);
+      let w: Limb = Limb::from_be_bytes(xs);
       ws[N-1-i] = w;
     }
     BigInt(ws)
   }
 
+  // checked versions of the above, for untrusted input
+  pub fn try_read_le_bytes(buf: &[u8]) -> Result<BigInt<N>, FeltError> {
+    if buf.len() != LIMB_BYTES*N { return Err(FeltError::InvalidLength) }
+    Ok(BigInt::read_le_bytes(buf))
+  }
+
+  pub fn try_read_be_bytes(buf: &[u8]) -> Result<BigInt<N>, FeltError> {
+    if buf.len() != LIMB_BYTES*N { return Err(FeltError::InvalidLength) }
+    Ok(BigInt::read_be_bytes(buf))
+  }
+
   //------------------------------------
   // decimal printing
 
   pub fn divmod_small(big: BigInt<N>, modulus: u32) -> (BigInt<N> , u32) {
-    let u64_modulus: u64 = modulus as u64;
-    let mut carry: u32 = 0;
-    let mut qs: [u32; N] = [0; N];
+    let wide_modulus: DLimb = modulus as DLimb;
+    let mut carry: Limb = 0;
+    let mut qs: [Limb; N] = [0; N];
     for i in 0..N {
-      let x: u64 = ((carry as u64) << 32) + (big.0[N-1-i] as u64);
-      qs[N-1-i] = (x / u64_modulus) as u32;
-      carry     = (x % u64_modulus) as u32;
+      let x: DLimb = ((carry as DLimb) << LIMB_BITS) + (big.0[N-1-i] as DLimb);
+      qs[N-1-i] = (x / wide_modulus) as Limb;
+      carry     = (x % wide_modulus) as Limb;
     }
-    (BigInt(qs), carry)
+    (BigInt(qs), carry as u32)
   }
 
   pub fn to_decimal_string(input: BigInt<N>) -> String {
     let mut digits: Vec<u8> = Vec::new();
-    let mut big: BigInt<N> = input.clone();
+    let mut big: BigInt<N> = input;
     while( !BigInt::is_zero(big) ) {
       let (q,r) = BigInt::divmod_small(big, 10);
       digits.push( 48 + (r as u8) );
       big = q;
     }
-    if digits.len() == 0 {
+    if digits.is_empty() {
       digits.push( 48 ); 
     }
     digits.reverse();
@@ -212,14 +243,47 @@ impl<const N: usize> BigInt<N> {
   }
 
   //------------------------------------
+  // parsing (the inverse of the above)
 
-  pub fn truncate1(big: BigInt<{N+1}>) -> BigInt<N> {
-    // let small: [u32; N] = &big.limbs[0..N];
-    let mut small: [u32; N] = [0; N];
-    for i in 0..N { small[i] = big.0[i]; }
-    BigInt(small)
+  // `radix*big + digit`, or `None` if it overflows
+  fn scale_add_digit(big: BigInt<N>, radix: u32, digit: u32) -> Option<BigInt<N>> {
+    let (scaled, hi) = BigInt::scale(radix as Limb, big);
+    let (sum   , c ) = BigInt::addCarry(scaled, BigInt::from_u32(digit));
+    if hi == 0 && !c { Some(sum) } else { None }
+  }
+
+  fn from_digits(digits: &str, radix: u32) -> Result<BigInt<N>, FeltError> {
+    if digits.is_empty() { return Err(FeltError::EmptyString) }
+    let mut big: BigInt<N> = BigInt::zero();
+    for ch in digits.chars() {
+      let d: u32 = ch.to_digit(radix).ok_or(FeltError::InvalidDigit)?;
+      big = BigInt::scale_add_digit(big, radix, d).ok_or(FeltError::Overflow)?;
+    }
+    Ok(big)
   }
 
+  pub fn from_decimal_str(s: &str) -> Result<BigInt<N>, FeltError> {
+    BigInt::from_digits(s, 10)
+  }
+
+  // the `0x` prefix is optional here
+  pub fn from_hex_str(s: &str) -> Result<BigInt<N>, FeltError> {
+    let digits = s.strip_prefix("0x").or(s.strip_prefix("0X")).unwrap_or(s);
+    BigInt::from_digits(digits, 16)
+  }
+
+  // decimal, or hexadecimal if it starts with `0x`
+  pub fn parse(s: &str) -> Result<BigInt<N>, FeltError> {
+    if s.starts_with("0x") || s.starts_with("0X") {
+      BigInt::from_hex_str(s)
+    }
+    else {
+      BigInt::from_decimal_str(s)
+    }
+  }
+
+  //------------------------------------
+
   pub fn zero() -> BigInt<N> {
     BigInt([0; N])
   }
@@ -230,7 +294,7 @@ impl<const N: usize> BigInt<N> {
 
   pub fn from_u32(x: u32) -> BigInt<N> {
     let mut xs = [0; N];
-    xs[0] = x;
+    xs[0] = x as Limb;
     BigInt(xs)
   }
 
@@ -242,7 +306,7 @@ impl<const N: usize> BigInt<N> {
   }
 
   pub fn is_one(big: BigInt<N>) -> bool {
-    let limbs: [u32; N] = big.0;
+    let limbs: [Limb; N] = big.0;
     let mut ok: bool = limbs[0] == 1;
     if ok {
       for i in 1..N {
@@ -285,11 +349,11 @@ impl<const N: usize> BigInt<N> {
 
   #[unroll_for_loops]
   pub fn rotLeftBy1(big: BigInt<N>, cin: bool) -> (bool, BigInt<N>) {
-    let     limbs : [u32; N] = big.0;
-    let mut out   : [u32; N] = [0; N];
+    let     limbs : [Limb; N] = big.0;
+    let mut out   : [Limb; N] = [0; N];
     let mut carry : bool = cin;
     for i in 0..N {
-      let (c,y) = rotLeft32By1(limbs[i], carry);
+      let (c,y) = rotLeftLimbBy1(limbs[i], carry);
       out[i] = y;
       carry  = c;
     }
@@ -298,11 +362,11 @@ impl<const N: usize> BigInt<N> {
 
   #[unroll_for_loops]
   pub fn rotRightBy1(cin: bool, big: BigInt<N>) -> (BigInt<N>, bool) {
-    let     limbs : [u32; N] = big.0;
-    let mut out   : [u32; N] = [0; N];
+    let     limbs : [Limb; N] = big.0;
+    let mut out   : [Limb; N] = [0; N];
     let mut carry : bool = cin;
     for i in (0..N).rev() {
-      let (y,c) = rotRight32By1(carry, limbs[i]);
+      let (y,c) = rotRightLimbBy1(carry, limbs[i]);
       out[i] = y;
       carry  = c;
     }
@@ -324,9 +388,9 @@ impl<const N: usize> BigInt<N> {
   #[unroll_for_loops]
   pub fn addCarry(big1: BigInt<N>, big2: BigInt<N>) -> (BigInt<N>, bool) {
     let mut c  : bool = false;  
-    let mut zs : [u32; N] = [0; N];
+    let mut zs : [Limb; N] = [0; N];
     for i in 0..N {
-      let (z,cout) = addCarry32( big1.0[i] , big2.0[i] , c);
+      let (z,cout) = addCarryLimb( big1.0[i] , big2.0[i] , c);
       zs[i] = z;
       c = cout;
     }
@@ -338,9 +402,9 @@ impl<const N: usize> BigInt<N> {
   #[unroll_for_loops]
   pub fn subBorrow(big1: BigInt<N>, big2: BigInt<N>) -> (BigInt<N>, bool) {
     let mut c  : bool = false;  
-    let mut zs : [u32; N] = [0; N];
+    let mut zs : [Limb; N] = [0; N];
     for i in 0..N {
-      let (z,cout) = subBorrow32( big1.0[i] , big2.0[i] , c );
+      let (z,cout) = subBorrowLimb( big1.0[i] , big2.0[i] , c );
       zs[i] = z;
       c = cout;
     }
@@ -362,15 +426,53 @@ impl<const N: usize> BigInt<N> {
     BigInt::sub( BigInt::zero() , big )
   }
 
+  //------------------------------------
+  // constant time helpers
+
+  // all ones if `choice` is true, zero otherwise. The `black_box` is there
+  // to discourage the compiler from turning the masking back into a branch
+  #[inline(always)]
+  fn choice_mask(choice: bool) -> Limb {
+    std::hint::black_box( (choice as Limb).wrapping_neg() )
+  }
+
+  // returns `big1` if `choice` is false, and `big2` if it's true (without branching)
+  #[inline(always)]
+  #[unroll_for_loops]
+  pub fn conditional_select(big1: BigInt<N>, big2: BigInt<N>, choice: bool) -> BigInt<N> {
+    let mask: Limb = BigInt::<N>::choice_mask(choice);
+    let mut zs : [Limb; N] = [0; N];
+    for i in 0..N {
+      zs[i] = big1.0[i] ^ ( mask & (big1.0[i] ^ big2.0[i]) );
+    }
+    BigInt(zs)
+  }
+
+  #[inline(always)]
+  #[unroll_for_loops]
+  pub fn ct_eq(big1: BigInt<N>, big2: BigInt<N>) -> bool {
+    let mut acc: Limb = 0;
+    for i in 0..N {
+      acc |= big1.0[i] ^ big2.0[i];
+    }
+    std::hint::black_box(acc) == 0
+  }
+
+  #[inline(always)]
+  pub fn ct_is_zero(big: BigInt<N>) -> bool {
+    BigInt::ct_eq( big , BigInt::zero() )
+  }
+
 
   //------------------------------------
   // multiplication
 
-  pub fn scale(scalar: u32, big2: BigInt<N>) -> (BigInt<N>, u32) {
-    let mut c  : u32 = 0;
-    let mut zs : [u32; N] = [0; N];
+  #[inline(always)]
+  pub fn scale(scalar: Limb, big2: BigInt<N>) -> (BigInt<N>, Limb) {
+    let mut c  : Limb = 0;
+    let mut zs : [Limb; N] = [0; N];
     for i in 0..N {
-      let (lo,hi) = mulAdd32(scalar, big2.0[i], c);
+      let (lo,hi) = mulAddLimb(scalar, big2.0[i], c);
       zs[i] = lo;
       c = hi;
     }
@@ -380,11 +482,11 @@ impl<const N: usize> BigInt<N> {
 
   #[inline(always)]
   #[unroll_for_loops]
-  pub fn scaleAdd(scalar: u32, vector: BigInt<N>, add: BigInt<N>) -> (BigInt<N>, u32) {
-    let mut c  : u32 = 0;
-    let mut zs : [u32; N] = [0; N];
+  pub fn scaleAdd(scalar: Limb, vector: BigInt<N>, add: BigInt<N>) -> (BigInt<N>, Limb) {
+    let mut c  : Limb = 0;
+    let mut zs : [Limb; N] = [0; N];
     for i in 0..N {
-      let (lo,hi) = mulAddAdd32(scalar, vector.0[i], c, add.0[i]);
+      let (lo,hi) = mulAddAddLimb(scalar, vector.0[i], c, add.0[i]);
       zs[i] = lo;
       c = hi;
     }
@@ -392,55 +494,128 @@ impl<const N: usize> BigInt<N> {
     (big, c)
   }
 
+  // the result size `K` must be `N+M` (stable rust cannot express this in the type)
   #[inline(always)]
   #[unroll_for_loops]
-  pub fn multiply<const M: usize>(big1: BigInt<N>, big2: BigInt<M>) -> BigInt<{N+M}> {
-    let mut product : [u32; N+M] = [0; N+M];
-    let mut state   : [u32; N]   = [0; N];
+  pub fn multiply<const M: usize, const K: usize>(big1: BigInt<N>, big2: BigInt<M>) -> BigInt<K> {
+    const { assert!( K == N+M , "BigInt::multiply: the result size must be N+M" ) };
+    let mut product : [Limb; K] = [0; K];
+    let mut state   : [Limb; N] = [0; N];
     for j in 0..M {
       let (scaled,carry) = BigInt::scaleAdd( big2.0[j], big1, BigInt(state) );
       product[j] = scaled.0[0];
-      for i in 1..N { state[i-1] = scaled.0[i] }
+      state[0..N-1].copy_from_slice(&scaled.0[1..N]);
       state[N-1] = carry;
     }
-    for i in 0..N { 
-      product[i+M] = state[i]
-    }
+    product[M..N+M].copy_from_slice(&state);
   
     BigInt(product)
   }
 
+  //------------------------------------
+  // random
+
+  pub fn sample(source: &mut (impl RandomSource + ?Sized)) -> BigInt<N> {
+    let mut xs: [Limb; N] = [0; N];
+    for i in 0..N {
+      let mut buf: [u8; LIMB_BYTES] = [0; LIMB_BYTES];
+      source.fill_bytes(&mut buf);
+      xs[i] = Limb::from_le_bytes(buf);
+    }
+    BigInt::make(xs)
+  }
+
+}
+
+// -----------------------------------------------------------------------------
+// routines specialized to the prime number
+
+#[allow(clippy::should_implement_trait)]
+impl BigInt256 {
+
+  //------------------------------------
+  // constant construction from 32 bit words
+  // (so that the constants are independent of the limb size)
+
+  pub const fn from_words32(ws: [u32; 8]) -> BigInt256 {
+    let k: usize = 8 / NLIMBS;          // number of words per limb
+    let mut ls: [Limb; NLIMBS] = [0; NLIMBS];
+    let mut i: usize = 0;
+    while i < 8 {
+      ls[i/k] |= (ws[i] as Limb) << (32 * (i%k));
+      i += 1;
+    }
+    BigInt(ls)
+  }
+
+  // (the cast is a no-op with 32 bit limbs)
+  #[allow(clippy::unnecessary_cast)]
+  pub const fn to_words32(big: BigInt256) -> [u32; 8] {
+    let k: usize = 8 / NLIMBS;
+    let mut ws: [u32; 8] = [0; 8];
+    let mut i: usize = 0;
+    while i < 8 {
+      ws[i] = (big.0[i/k] >> (32 * (i%k))) as u32;
+      i += 1;
+    }
+    ws
+  }
+
+  //------------------------------------
+  // conversion to/from bytes
+
+  pub fn to_le_bytes(big: BigInt256) -> [u8; 32] {
+    let mut buf: [u8; 32] = [0; 32];
+    BigInt::write_le_bytes(big, &mut buf);
+    buf
+  }
+
+  pub fn from_le_bytes(buf: [u8; 32]) -> BigInt256 {
+    BigInt::read_le_bytes(&buf)
+  }
+
+  pub fn to_be_bytes(big: BigInt256) -> [u8; 32] {
+    let mut buf: [u8; 32] = [0; 32];
+    BigInt::write_be_bytes(big, &mut buf);
+    buf
+  }
+
+  pub fn from_be_bytes(buf: [u8; 32]) -> BigInt256 {
+    BigInt::read_be_bytes(&buf)
+  }
+
+  //------------------------------------
+  // multiplication
+
   #[inline(always)]
-  pub fn mul(big1: BigInt<N>, big2: BigInt<N>) -> BigInt<{N+N}> {
-    BigInt::multiply(big1,big2)
+  pub fn mul(big1: BigInt256, big2: BigInt256) -> BigInt512 {
+    BigInt::multiply::<NLIMBS,{2*NLIMBS}>(big1,big2)
   }
 
   // x*y + z
   #[inline(always)]
-  pub fn mulAdd(big1: BigInt<N>, big2: BigInt<N>, big3: BigInt<N>) -> BigInt<{N+N}> {
+  pub fn mulAdd(big1: BigInt256, big2: BigInt256, big3: BigInt256) -> BigInt512 {
     // first compute the product
-    let mut product : [u32; N+N] = [0; N+N];
-    let mut state   : [u32; N]   = [0; N];
-    for j in 0..N {
+    let mut product : [Limb; 2*NLIMBS] = [0; 2*NLIMBS];
+    let mut state   : [Limb;   NLIMBS] = [0;   NLIMBS];
+    for j in 0..NLIMBS {
       let (scaled,carry) = BigInt::scaleAdd( big2.0[j], big1, BigInt(state) );
       product[j] = scaled.0[0];
-      for i in 1..N { state[i-1] = scaled.0[i] }
-      state[N-1] = carry;
-    }
-    for i in 0..N { 
-      product[i+N] = state[i]
+      state[0..NLIMBS-1].copy_from_slice(&scaled.0[1..NLIMBS]);
+      state[NLIMBS-1] = carry;
     }
+    product[NLIMBS..2*NLIMBS].copy_from_slice(&state);
   
     // then add the third number
     let mut carry: bool = false;  
-    for i in 0..N {
-      let (z,c)  = addCarry32( product[i] , big3.0[i] , carry );
+    for i in 0..NLIMBS {
+      let (z,c)  = addCarryLimb( product[i] , big3.0[i] , carry );
       carry      = c; 
       product[i] = z;
     }
     // continue carrying
-    for i in N..(N+N) {
-      let (z,c)  = addCarry32( product[i] , 0 , carry );
+    for i in NLIMBS..(2*NLIMBS) {
+      let (z,c)  = addCarryLimb( product[i] , 0 , carry );
       carry      = c; 
       product[i] = z;
     }
@@ -450,64 +625,134 @@ impl<const N: usize> BigInt<N> {
 
   // x*y + (z << 256)
   #[inline(always)]
-  pub fn mulAddShifted(big1: BigInt<N>, big2: BigInt<N>, big3: BigInt<N>) -> BigInt<{N+N}> {
+  pub fn mulAddShifted(big1: BigInt256, big2: BigInt256, big3: BigInt256) -> BigInt512 {
     // first compute the product
-    let mut product : [u32; N+N] = [0; N+N];
-    let mut state   : [u32; N]   = [0; N];
-    for j in 0..N {
+    let mut product : [Limb; 2*NLIMBS] = [0; 2*NLIMBS];
+    let mut state   : [Limb;   NLIMBS] = [0;   NLIMBS];
+    for j in 0..NLIMBS {
       let (scaled,carry) = BigInt::scaleAdd( big2.0[j], big1, BigInt(state) );
       product[j] = scaled.0[0];
-      for i in 1..N { state[i-1] = scaled.0[i] }
-      state[N-1] = carry;
-    }
-    for i in 0..N { 
-      product[i+N] = state[i]
+      state[0..NLIMBS-1].copy_from_slice(&scaled.0[1..NLIMBS]);
+      state[NLIMBS-1] = carry;
     }
+    product[NLIMBS..2*NLIMBS].copy_from_slice(&state);
   
     // then add the third number, shifted
     let mut carry: bool = false;  
-    for i in 0..N {
-      let (z,c)    = addCarry32( product[i+N] , big3.0[i] , carry );
-      carry        = c; 
-      product[i+N] = z;
+    for i in 0..NLIMBS {
+      let (z,c)         = addCarryLimb( product[i+NLIMBS] , big3.0[i] , carry );
+      carry             = c; 
+      product[i+NLIMBS] = z;
     }
 
     BigInt(product)
   }
 
-  // TODO: optimize this?!
-  pub fn sqr_naive(big: BigInt<N>) -> BigInt<{N+N}> {
-    BigInt::multiply(big,big)
+  // acc + x*y, modulo `2^512` (the caller must make sure it doesn't overflow).
+  // As in `Mont::redc`, the carry out of the top of each row is deferred to the next row
+  #[inline(always)]
+  #[unroll_for_loops]
+  pub fn mulAcc(acc: BigInt512, big1: BigInt256, big2: BigInt256) -> BigInt512 {
+    let mut T: [Limb; 2*NLIMBS] = acc.0;
+    let mut deferred: bool = false;
+    for i in 0..NLIMBS {
+      let mut carry: Limb = 0;
+      for j in 0..NLIMBS {
+        let (lo,hi) = mulAddAddLimb( big1.0[i], big2.0[j], carry, T[i+j] );
+        T[i+j] = lo;
+        carry  = hi;
+      }
+      let (x,c) = addCarryLimb( T[i+NLIMBS] , carry , deferred );
+      T[i+NLIMBS] = x;
+      deferred    = c;
+    }
+    BigInt(T)
   }
 
+  // acc + (z << 256), modulo `2^512`
   #[inline(always)]
-  pub fn sqr(big: BigInt<N>) -> BigInt<{N+N}> {
-    BigInt::multiply(big,big)
+  #[unroll_for_loops]
+  pub fn addShifted(acc: BigInt512, big: BigInt256) -> BigInt512 {
+    let mut T: [Limb; 2*NLIMBS] = acc.0;
+    let mut carry: bool = false;  
+    for i in 0..NLIMBS {
+      let (z,c)   = addCarryLimb( T[i+NLIMBS] , big.0[i] , carry );
+      carry       = c; 
+      T[i+NLIMBS] = z;
+    }
+    BigInt(T)
   }
 
-  //------------------------------------
-  // random
+  // squaring as a plain multiplication (reference implementation for testing)
+  pub fn sqr_naive(big: BigInt256) -> BigInt512 {
+    BigInt::multiply::<NLIMBS,{2*NLIMBS}>(big,big)
+  }
 
-  pub fn sample(source: &mut (impl RandomSource + ?Sized)) -> BigInt<N> {
-    let mut xs: [u32; N] = [0; N];
-    for i in 0..N {
-      xs[i] = RangeFull.sample(source);
+  // dedicated squaring: we compute the off-diagonal products `x[i]*x[j]`
+  // (with `i<j`) only once, double them with a shift, then add the diagonal 
+  // squares. This needs `N(N+1)/2` limb multiplications instead of `N^2`
+  #[inline(always)]
+  #[unroll_for_loops]
+  pub fn sqr(big: BigInt256) -> BigInt512 {
+    let xs: [Limb; NLIMBS] = big.0;
+    let mut T: [Limb; 2*NLIMBS] = [0; 2*NLIMBS];
+
+    // off-diagonal part
+    for i in 0..(NLIMBS-1) {
+      let mut carry: Limb = 0;
+      for j in (i+1)..NLIMBS {
+        let (lo,hi) = mulAddAddLimb( xs[i], xs[j], carry, T[i+j] );
+        T[i+j] = lo;
+        carry  = hi;
+      }
+      T[i+NLIMBS] = carry;
     }
-    BigInt::make(xs)
+
+    // doubling (the top bit is always zero, as the off-diagonal sum is less than `2^511`)
+    for i in (1..(2*NLIMBS)).rev() {
+      T[i] = (T[i] << 1) | (T[i-1] >> (LIMB_BITS-1));
+    }
+    T[0] <<= 1;
+
+    // diagonal part
+    let mut carry: bool = false;
+    for i in 0..NLIMBS {
+      let (lo,hi)   = mulExtLimb( xs[i], xs[i] );
+      let (z0,c0)   = addCarryLimb( T[2*i  ] , lo , carry );
+      let (z1,c1)   = addCarryLimb( T[2*i+1] , hi , c0    );
+      T[2*i  ] = z0;
+      T[2*i+1] = z1;
+      carry    = c1;
+    }
+
+    BigInt(T)
   }
 
-}
+  //------------------------------------
 
-// -----------------------------------------------------------------------------
-// routines specialized to the prime number
+  pub fn truncate1(big: BigInt<{NLIMBS+1}>) -> BigInt256 {
+    let mut small: [Limb; NLIMBS] = [0; NLIMBS];
+    small.copy_from_slice(&big.0[0..NLIMBS]);
+    BigInt(small)
+  }
 
-impl BigInt256 {
+  pub const fn extend1(big: BigInt256) -> BigInt<{NLIMBS+1}> {
+    let mut ls: [Limb; NLIMBS+1] = [0; NLIMBS+1];
+    let mut i: usize = 0;
+    while i < NLIMBS {
+      ls[i] = big.0[i];
+      i += 1;
+    }
+    BigInt(ls)
+  }
+
+  //------------------------------------
 
   #[inline(always)]
   #[unroll_for_loops]
   pub fn is_lt_prime(big: BigInt256) -> bool {
     let mut less: bool = false;
-    for i in (0..8).rev() {
+    for i in (0..NLIMBS).rev() {
       if big.0[i] < PRIME_ARRAY[i] {
         less = true;
         break;
@@ -528,9 +773,9 @@ impl BigInt256 {
   #[unroll_for_loops]
   pub fn add_prime(big: BigInt256) -> (BigInt256, bool) {
     let mut c  : bool     = false;  
-    let mut zs : [u32; 8] = [0; 8];
-    for i in 0..8 {
-      let (z,cout) = addCarry32( big.0[i] , PRIME_ARRAY[i] , c );
+    let mut zs : [Limb; NLIMBS] = [0; NLIMBS];
+    for i in 0..NLIMBS {
+      let (z,cout) = addCarryLimb( big.0[i] , PRIME_ARRAY[i] , c );
       zs[i] = z;
       c = cout;
     }
@@ -542,9 +787,9 @@ impl BigInt256 {
   #[unroll_for_loops]
   pub fn add_half_prime_plus_1(big: BigInt256) -> BigInt256 {
     let mut c  : bool     = false;  
-    let mut zs : [u32; 8] = [0; 8];
-    for i in 0..8 {
-      let (z,cout) = addCarry32( big.0[i] , HALFP_PLUS_1.0[i] , c );
+    let mut zs : [Limb; NLIMBS] = [0; NLIMBS];
+    for i in 0..NLIMBS {
+      let (z,cout) = addCarryLimb( big.0[i] , HALFP_PLUS_1.0[i] , c );
       c     = cout;
       zs[i] = z;
     }
@@ -556,9 +801,9 @@ impl BigInt256 {
   #[unroll_for_loops]
   pub fn subtract_prime(big: BigInt256) -> (BigInt256, bool) {
     let mut c  : bool     = false;  
-    let mut zs : [u32; 8] = [0; 8];
-    for i in 0..8 {
-      let (z,cout) = subBorrow32( big.0[i] , PRIME_ARRAY[i] , c );
+    let mut zs : [Limb; NLIMBS] = [0; NLIMBS];
+    for i in 0..NLIMBS {
+      let (z,cout) = subBorrowLimb( big.0[i] , PRIME_ARRAY[i] , c );
       zs[i] = z;
       c = cout;
     }
@@ -566,8 +811,69 @@ impl BigInt256 {
     (big, c)
   }
 
+  // reduces `lo + 2^256*hi < 2^32*p` modulo `p`. We estimate the quotient from the
+  // top 32 bits, using `floor(2^286/p)`, which leaves a remainder less than `3p`.
+  // (the casts are no-ops with 64 bit limbs)
+  #[inline(always)]
+  #[allow(clippy::unnecessary_cast)]
+  fn reduce_scaled(lo: BigInt256, hi: Limb) -> BigInt256 {
+    let top: u64 = ((hi as u64) << 2) | ((lo.0[NLIMBS-1] >> (LIMB_BITS-2)) as u64);
+    let q:   u64 = (top * SCALE_RECIPROCAL) >> 32;
+    let (qp, _) = BigInt::scale(q as Limb, FIELD_PRIME);
+    BigInt256::reduce_mod_prime( BigInt::sub(lo, qp) )
+  }
+
+  // `k*x mod p` for a 32 bit `k`, assuming `x < p`.
+  // note: `k` is typically a constant, so the branch is resolved at compile time
+  #[inline(always)]
+  pub fn scale_mod_prime(k: u32, big: BigInt256) -> BigInt256 {
+    let (lo, hi) = BigInt::scale(k as Limb, big);
+    if k <= 5 {
+      // then `k*x < 5p < 2^256`
+      BigInt256::reduce_mod_prime(lo)
+    }
+    else {
+      BigInt256::reduce_scaled(lo, hi)
+    }
+  }
+
+  // `k*x + y mod p` for a 32 bit `k`, assuming `x,y < p`
+  #[inline(always)]
+  pub fn scale_add_mod_prime(k: u32, big1: BigInt256, big2: BigInt256) -> BigInt256 {
+    let (lo, hi) = BigInt::scaleAdd(k as Limb, big1, big2);
+    if k <= 4 {
+      BigInt256::reduce_mod_prime(lo)
+    }
+    else {
+      BigInt256::reduce_scaled(lo, hi)
+    }
+  }
+
+  // note: with the `constant-time` feature, these are the branch-free versions below
+
+  // reduces any 256 bit number (these are less than `6p`) modulo `p`: we estimate
+  // the quotient from the top 3 bits `i`, which leaves a remainder less than `2p`.
+  // `floor(i * 169 / 256)` agrees with `floor(i * 2^253 / p)` for all `i < 8`
+  #[inline(always)]
+  pub fn reduce_mod_prime(big: BigInt256) -> BigInt256 {
+    let top: Limb = big.0[NLIMBS-1] >> (LIMB_BITS - 3);
+    let q:   Limb = (top * PRIME_QUOTIENT_MUL) >> 8;
+    let multiple: BigInt256 = if cfg!(feature = "constant-time") {
+      // don't index the table with secret data
+      let (m, _) = BigInt::scale( q , FIELD_PRIME );
+      m
+    }
+    else {
+      PRIME_MULTIPLES[q as usize]
+    };
+    BigInt256::subtract_prime_if_necessary( BigInt::sub( big , multiple ) )
+  }
+
   #[inline(always)]
   pub fn subtract_prime_if_necessary(big: BigInt256) -> BigInt256 {
+    if cfg!(feature = "constant-time") {
+      return BigInt256::ct_subtract_prime_if_necessary(big)
+    }
     if BigInt256::is_lt_prime(big) {
       big
     }
@@ -579,6 +885,9 @@ impl BigInt256 {
 
   #[inline(always)]
   pub fn div_by_2_mod_prime(big: BigInt256) -> BigInt256 {
+    if cfg!(feature = "constant-time") {
+      return BigInt256::ct_div_by_2_mod_prime(big)
+    }
     let (half, carry): (BigInt256, bool) = BigInt::shiftRightBy1(big);
     if carry {
       BigInt256::add_half_prime_plus_1(half)
@@ -590,6 +899,9 @@ impl BigInt256 {
 
   #[inline(always)]
   pub fn sub_mod_prime(big1: BigInt256, big2: BigInt256) -> BigInt256 {
+    if cfg!(feature = "constant-time") {
+      return BigInt256::ct_sub_mod_prime(big1, big2)
+    }
     let (big, carry) = BigInt::subBorrow(big1, big2);
     if carry {
       let (corrected, _) = BigInt::add_prime(big);
@@ -599,16 +911,38 @@ impl BigInt256 {
       big
     }
   }
-  
+
+  //------------------------------------
+  // constant time versions
+
+  // we always compute both candidates, and select using the borrow / carry
+
+  #[inline(always)]
+  pub fn ct_subtract_prime_if_necessary(big: BigInt256) -> BigInt256 {
+    let (corrected, borrow) = BigInt256::subtract_prime(big);
+    BigInt::conditional_select( corrected , big , borrow )
+  }
+
+  #[inline(always)]
+  pub fn ct_div_by_2_mod_prime(big: BigInt256) -> BigInt256 {
+    let (half, carry): (BigInt256, bool) = BigInt::shiftRightBy1(big);
+    let corrected = BigInt256::add_half_prime_plus_1(half);
+    BigInt::conditional_select( half , corrected , carry )
+  }
+
+  #[inline(always)]
+  pub fn ct_sub_mod_prime(big1: BigInt256, big2: BigInt256) -> BigInt256 {
+    let (big, borrow) = BigInt::subBorrow(big1, big2);
+    let (corrected, _) = BigInt::add_prime(big);
+    BigInt::conditional_select( big , corrected , borrow )
+  }
+
   //------------------------------------
   // random
 
   fn sample_masked(source: &mut (impl RandomSource + ?Sized)) -> BigInt256 {
-    let mut xs: [u32; 8] = [0; 8];
-    for i in 0..8 {
-      xs[i] = RangeFull.sample(source);
-    }
-    xs[7] = xs[7] & 0x_3FFF_FFFF;
+    let mut xs: [Limb; NLIMBS] = BigInt::to_limbs(BigInt::sample(source));
+    xs[NLIMBS-1] &= (Limb::MAX >> 2);
     BigInt::make(xs)
   }
 
@@ -1,25 +1,64 @@
 
 use crate::bn254::bigint::*;
+use crate::bn254::platform::{Limb,NLIMBS};
 
 //------------------------------------------------------------------------------
 // field prime (BN254 scalar field)
 
-type Big = BigInt<8>;
+type Big = BigInt256;
 
-pub const PRIME_ARRAY  : [u32; 8]  =               [ 0xf0000001 , 0x43e1f593 , 0x79b97091 , 0x2833e848 , 0x8181585d , 0xb85045b6 , 0xe131a029 , 0x30644e72 ];
-pub const PRIME_EXT    : BigInt<9> = BigInt::make( [ 0xf0000001 , 0x43e1f593 , 0x79b97091 , 0x2833e848 , 0x8181585d , 0xb85045b6 , 0xe131a029 , 0x30644e72 , 0x00000000 ] );
-pub const FIELD_PRIME  : Big       = BigInt::make( [ 0xf0000001 , 0x43e1f593 , 0x79b97091 , 0x2833e848 , 0x8181585d , 0xb85045b6 , 0xe131a029 , 0x30644e72 ] );
-pub const PRIME_PLUS_1 : Big       = BigInt::make( [ 0xf0000002 , 0x43e1f593 , 0x79b97091 , 0x2833e848 , 0x8181585d , 0xb85045b6 , 0xe131a029 , 0x30644e72 ] );
-pub const HALFP_PLUS_1 : Big       = BigInt::make( [ 0xf8000001 , 0xa1f0fac9 , 0x3cdcb848 , 0x9419f424 , 0x40c0ac2e , 0xdc2822db , 0x7098d014 , 0x18322739 ] );
+pub const FIELD_PRIME  : Big = BigInt::from_words32( [ 0xf0000001 , 0x43e1f593 , 0x79b97091 , 0x2833e848 , 0x8181585d , 0xb85045b6 , 0xe131a029 , 0x30644e72 ] );
+pub const PRIME_PLUS_1 : Big = BigInt::from_words32( [ 0xf0000002 , 0x43e1f593 , 0x79b97091 , 0x2833e848 , 0x8181585d , 0xb85045b6 , 0xe131a029 , 0x30644e72 ] );
+pub const PRIME_MINUS_2: Big = BigInt::from_words32( [ 0xefffffff , 0x43e1f593 , 0x79b97091 , 0x2833e848 , 0x8181585d , 0xb85045b6 , 0xe131a029 , 0x30644e72 ] );
+pub const HALFP_PLUS_1 : Big = BigInt::from_words32( [ 0xf8000001 , 0xa1f0fac9 , 0x3cdcb848 , 0x9419f424 , 0x40c0ac2e , 0xdc2822db , 0x7098d014 , 0x18322739 ] );
+
+// small multiples of the prime, for reducing lazy sums (note: `5p < 2^256 < 6p`)
+pub const PRIME_TIMES_2: Big = BigInt::from_words32( [ 0xe0000002 , 0x87c3eb27 , 0xf372e122 , 0x5067d090 , 0x0302b0ba , 0x70a08b6d , 0xc2634053 , 0x60c89ce5 ] );
+pub const PRIME_TIMES_3: Big = BigInt::from_words32( [ 0xd0000003 , 0xcba5e0bb , 0x6d2c51b3 , 0x789bb8d9 , 0x84840917 , 0x28f0d123 , 0xa394e07d , 0x912ceb58 ] );
+pub const PRIME_TIMES_4: Big = BigInt::from_words32( [ 0xc0000004 , 0x0f87d64f , 0xe6e5c245 , 0xa0cfa121 , 0x06056174 , 0xe14116da , 0x84c680a6 , 0xc19139cb ] );
+
+pub const PRIME_MULTIPLES: [Big; 5] = [ BigInt::from_words32([0; 8]) , FIELD_PRIME , PRIME_TIMES_2 , PRIME_TIMES_3 , PRIME_TIMES_4 ];
+
+// `floor(2^286 / p)`, for reducing products with 32 bit scalars
+pub const SCALE_RECIPROCAL: u64 = 0x15291d189;
+
+// `floor(i * 2^253 / p) = floor(i * 169 / 256)` for `i < 8`, a lower estimate of `x / p`
+// from the top 3 bits `i` of `x`
+pub const PRIME_QUOTIENT_MUL: Limb = 169;
+
+pub const PRIME_ARRAY  : [Limb; NLIMBS]     = BigInt::to_limbs( FIELD_PRIME );
+pub const PRIME_EXT    : BigInt<{NLIMBS+1}> = BigInt::extend1( FIELD_PRIME );
 
 //------------------------------------------------------------------------------
 // montgomery constants
 
-pub const MONT_Q : u32 = 0xefffffff;
+// -1/p mod 2^LIMB_BITS (the 32 bit value 0xefffffff is just the low half of the 64 bit one)
+pub const MONT_Q : Limb = 0xc2e1f593efffffff_u64 as Limb;
 
 // R^k = (2^256)^k mod P
-pub const BIG_R1 : Big = BigInt::make( [ 0x4ffffffb , 0xac96341c , 0x9f60cd29 , 0x36fc7695 , 0x7879462e , 0x666ea36f , 0x9a07df2f , 0x0e0a77c1 ] );
-pub const BIG_R2 : Big = BigInt::make( [ 0xae216da7 , 0x1bb8e645 , 0xe35c59e3 , 0x53fe3ab1 , 0x53bb8085 , 0x8c49833d , 0x7f4e44a5 , 0x0216d0b1 ] );
-pub const BIG_R3 : Big = BigInt::make( [ 0xb4bf0040 , 0x5e94d8e1 , 0x1cfbb6b8 , 0x2a489cbe , 0xa19fcfed , 0x893cc664 , 0x7fcc657c , 0x0cf8594b ] );
+pub const BIG_R1 : Big = BigInt::from_words32( [ 0x4ffffffb , 0xac96341c , 0x9f60cd29 , 0x36fc7695 , 0x7879462e , 0x666ea36f , 0x9a07df2f , 0x0e0a77c1 ] );
+pub const BIG_R2 : Big = BigInt::from_words32( [ 0xae216da7 , 0x1bb8e645 , 0xe35c59e3 , 0x53fe3ab1 , 0x53bb8085 , 0x8c49833d , 0x7f4e44a5 , 0x0216d0b1 ] );
+pub const BIG_R3 : Big = BigInt::from_words32( [ 0xb4bf0040 , 0x5e94d8e1 , 0x1cfbb6b8 , 0x2a489cbe , 0xa19fcfed , 0x893cc664 , 0x7fcc657c , 0x0cf8594b ] );
+
+//------------------------------------------------------------------------------
+// exponentiation constants (square roots etc)
+
+// `p - 1 = 2^TWO_ADICITY * TRACE`, with `TRACE` odd
+pub const TWO_ADICITY  : u32 = 28;
+pub const TRACE        : Big = BigInt::from_words32( [ 0x3e1f593f , 0x9b970914 , 0x833e8487 , 0x181585d2 , 0x85045b68 , 0x131a029b , 0x0644e72e , 0x00000003 ] );
+
+// `(TRACE - 1) / 2`
+pub const TRACE_MINUS_1_DIV_2 : Big = BigInt::from_words32( [ 0x1f0fac9f , 0xcdcb848a , 0x419f4243 , 0x0c0ac2e9 , 0xc2822db4 , 0x098d014d , 0x83227397 , 0x00000001 ] );
+
+// `(p - 1) / 2` (the exponent of the Legendre symbol)
+pub const HALFP_MINUS_1 : Big = BigInt::from_words32( [ 0xf8000000 , 0xa1f0fac9 , 0x3cdcb848 , 0x9419f424 , 0x40c0ac2e , 0xdc2822db , 0x7098d014 , 0x18322739 ] );
+
+// the multiplicative generator `g = 5` (a quadratic non-residue)
+pub const MULT_GENERATOR : u32 = 5;
+
+// `g^TRACE`, a primitive `2^TWO_ADICITY`-th root of unity, 
+// in standard and Montgomery representation
+pub const ROOT_OF_UNITY      : Big = BigInt::from_words32( [ 0x725b19f0 , 0x9bd61b6e , 0x41112ed4 , 0x402d111e , 0x8ef62abc , 0x00e0a7eb , 0xa58a7e85 , 0x2a3c09f0 ] );
+pub const ROOT_OF_UNITY_MONT : Big = BigInt::from_words32( [ 0x80d13d9c , 0x636e7355 , 0x2445ffd6 , 0xa22bf374 , 0x1eb203d8 , 0x56452ac0 , 0x2963f9e7 , 0x1860ef94 ] );
 
 //------------------------------------------------------------------------------
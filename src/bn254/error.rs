@@ -0,0 +1,34 @@
+
+//
+// errors for the fallible conversions (parsing, decoding untrusted input)
+//
+
+use std::fmt;
+
+//------------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FeltError {
+  NotInRange,           // the value is not less than the field prime
+  Overflow,             // the value does not fit into the big integer
+  InvalidLength,        // wrong number of input bytes
+  EmptyString,          // nothing to parse
+  InvalidDigit,         // not a decimal (or hex, after `0x`) digit
+}
+
+impl fmt::Display for FeltError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let msg = match self {
+      FeltError::NotInRange    => "value is not in the range [0..p-1]",
+      FeltError::Overflow      => "value does not fit into the big integer",
+      FeltError::InvalidLength => "invalid input length",
+      FeltError::EmptyString   => "cannot parse a number from an empty string",
+      FeltError::InvalidDigit  => "invalid digit found in string",
+    };
+    f.write_str(msg)
+  }
+}
+
+impl std::error::Error for FeltError {}
+
+//------------------------------------------------------------------------------
@@ -1,20 +1,23 @@
 
 //
-// standard representation of field elements
+// field elements, as seen from the outside
 //
-// note: this is used primarily for input/output; 
-// for actual computations, use the Montgomery representation!
+// All the input/output (bytes, strings, bigints) is in the standard representation,
+// but internally a `Felt` is just a wrapper around `Mont`: so the arithmetic costs 
+// the same as with `Mont`, and converting between the two is free. Only the I/O 
+// functions pay for the conversion (a single Montgomery multiplication).
 //
 
 #![allow(dead_code)]
 #![allow(non_snake_case)]
 
 use std::fmt;
-use std::ops::{Neg,Add,Sub,Mul,Div,RangeFull};
-
-use std::random::{RandomSource,Distribution};
+use std::str::FromStr;
+use std::ops::{Neg,Add,Sub,Mul,Div};
 
 use crate::bn254::traits::*;
+use crate::bn254::random::*;
+use crate::bn254::error::*;
 use crate::bn254::bigint::*;
 use crate::bn254::constant::*;
 use crate::bn254::montgomery::*;
@@ -22,17 +25,26 @@ use crate::bn254::euclid::*;
 
 //------------------------------------------------------------------------------
 
-type Big = BigInt<8>;
+type Big = BigInt256;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub struct Felt(Big);
+// the Montgomery representation is a bijection, so equality is the same
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Felt(Mont);
 
 //------------------------------------------------------------------------------
 // display traits
 
+// prints the standard representation (unlike `Mont`)
+impl fmt::Debug for Felt {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.debug_tuple("Felt").field(&Felt::to_bigint(*self)).finish()
+  }
+}
+
 impl fmt::Display for Felt {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    f.write_fmt(format_args!("{}",self.0))
+    f.write_fmt(format_args!("{}",Felt::to_bigint(*self)))
   }
 }
 
@@ -83,8 +95,18 @@ impl One for Felt {
   fn is_one(x: Self)  -> bool { Felt::is_one(x)  }
 }
 
+impl Zeroize for Felt {
+  fn zeroize(&mut self) { self.0.zeroize() }
+}
+
+// panics on zero
 impl Inv for Felt {
-  fn inv(x: Self) -> Self { Felt::inv(x) }
+  fn inv(x: Self) -> Self { 
+    match Felt::inv(x) {
+      Some(y) => y,
+      None    => panic!("Felt::inv: zero is not invertible"),
+    }
+  }
 }
 
 //------------------------------------------------------------------------------
@@ -94,22 +116,27 @@ impl From<Mont> for Felt {
   fn from(mont: Mont) -> Self { Felt::from_mont(mont) }
 }
 
-impl Into<Mont> for Felt {
-  fn into(self: Self) -> Mont { Felt::to_mont(self) }
+impl From<Felt> for Mont {
+  fn from(x: Felt) -> Self { Felt::to_mont(x) }
 }
 
-// note: we dont implement `From<BigInt<8>>` as it's unsafe, 
-// and we don't have a "safe" (modulo p) implementation yet
+// note: we dont implement `From<BigInt256>`, as silently reducing modulo p
+// is rarely what one wants; use `Felt::from_bigint_mod_order` for that
+
+impl TryFrom<BigInt256> for Felt {
+  type Error = FeltError;
+  fn try_from(big: BigInt256) -> Result<Self, FeltError> { Felt::try_from_bigint(big) }
+}
 
-impl Into<BigInt<8>> for Felt {
-  fn into(self: Self) -> BigInt<8> { Felt::to_bigint(self) }
+impl From<Felt> for BigInt256 {
+  fn from(x: Felt) -> Self { Felt::to_bigint(x) }
 }
 
 //------------------------------------------------------------------------------
 // small values
 
 impl Default for Felt {
-  fn default() -> Self { Felt(BigInt::zero()) }
+  fn default() -> Self { Felt::zero() }
 }
 
 impl From<u32> for Felt {
@@ -117,181 +144,341 @@ impl From<u32> for Felt {
 }
 
 //------------------------------------------------------------------------------
-// random trait
+// checked decoding and parsing
 
-impl Distribution<Felt> for RangeFull {
-  fn sample(&self, source: &mut (impl RandomSource + ?Sized)) -> Felt {
-    Felt(BigInt::sample_mod_prime(source))
+// little-endian
+impl TryFrom<[u8; 32]> for Felt {
+  type Error = FeltError;
+  fn try_from(buf: [u8; 32]) -> Result<Self, FeltError> { Felt::try_from_le_bytes(buf) }
+}
+
+// little-endian; the slice must be exactly 32 bytes long
+impl TryFrom<&[u8]> for Felt {
+  type Error = FeltError;
+  fn try_from(buf: &[u8]) -> Result<Self, FeltError> { 
+    let arr: [u8; 32] = buf.try_into().map_err(|_| FeltError::InvalidLength)?;
+    Felt::try_from_le_bytes(arr)
   }
 }
 
+// decimal, or hexadecimal with a `0x` prefix
+impl FromStr for Felt {
+  type Err = FeltError;
+  fn from_str(s: &str) -> Result<Self, FeltError> { Felt::parse(s) }
+}
+
 //------------------------------------------------------------------------------
 // internal implementations
 
+#[allow(clippy::should_implement_trait)]
 impl Felt {
 
   #[inline(always)]
   pub fn to_bigint(felt: Felt) -> Big {
-    felt.0
+    Mont::convert_to_big(felt.0)
   }
 
-  #[inline(always)]
-  pub const fn unsafe_from_bigint(big: BigInt<8>) -> Felt {
-    Felt(big)
+  // note: the `const` constructors use the (slower) compile-time conversion,
+  // so these are meant for constants; at runtime, prefer `try_from_bigint`
+  pub const fn unsafe_from_bigint(big: BigInt256) -> Felt {
+    Felt(Mont::const_convert_from_big(big))
   }
 
-  #[inline(always)]
   pub const fn unsafe_make( xs: [u32; 8] ) -> Felt {
-    Felt(BigInt::from_limbs(xs))
+    Felt(Mont::const_convert_from_big(BigInt::from_words32(xs)))
   }
 
   #[inline(always)]
   pub fn from_u32(x: u32) -> Felt {
-    Felt(BigInt::from_u32(x))
+    Felt(Mont::convert_from_u32(x))
   }
 
   pub fn checked_make( xs: [u32; 8] ) -> Felt {
-    let big: Big = BigInt::make(xs);
+    match Felt::try_make(xs) {
+      Ok(felt) => felt,
+      Err(_)   => panic!("Felt::checked_make: not in range"),
+    }
+  }
+
+  pub fn try_make( xs: [u32; 8] ) -> Result<Felt, FeltError> {
+    Felt::try_from_bigint( BigInt::from_words32(xs) )
+  }
+
+  pub fn try_from_bigint(big: Big) -> Result<Felt, FeltError> {
     if BigInt::is_lt_prime(big) {
-      Felt(big)
+      Ok(Felt(Mont::unsafe_convert_from_big(big)))
     }
     else {
-      panic!("Felt::checked_make: not in range")
+      Err(FeltError::NotInRange)
     }
   }
 
   pub fn is_valid(felt: Felt) -> bool {
-    BigInt::is_lt_prime(felt.0)
+    Mont::is_valid(felt.0)
+  }
+
+  // uniformly random field element
+  pub fn sample(source: &mut (impl RandomSource + ?Sized)) -> Felt {
+    Felt(Mont::sample(source))
   }
 
   pub fn to_decimal_string(input: Felt) -> String {
-    BigInt::to_decimal_string(input.0)
+    Mont::to_decimal_string(input.0)
   }
 
   pub fn to_hex_string(input: Felt) -> String {
-    BigInt::to_hex_string(input.0)
+    Mont::to_hex_string(input.0)
+  }
+
+  pub fn from_decimal_str(s: &str) -> Result<Felt, FeltError> {
+    Felt::try_from_bigint( BigInt::from_decimal_str(s)? )
+  }
+
+  pub fn from_hex_str(s: &str) -> Result<Felt, FeltError> {
+    Felt::try_from_bigint( BigInt::from_hex_str(s)? )
+  }
+
+  // decimal, or hexadecimal if it starts with `0x`
+  pub fn parse(s: &str) -> Result<Felt, FeltError> {
+    Felt::try_from_bigint( BigInt::parse(s)? )
   }
 
   //------------------------------------
   // conversion to/from bytes
 
   pub fn to_le_bytes(felt: Felt) -> [u8; 32] {
-    BigInt::to_le_bytes(felt.0)
+    BigInt::to_le_bytes(Felt::to_bigint(felt))
   }
 
   pub fn unsafe_from_le_bytes(buf: [u8; 32]) -> Felt {
-    Felt(BigInt::from_le_bytes(buf))
+    Felt(Mont::unsafe_convert_from_big(BigInt::from_le_bytes(buf)))
   }
 
   pub fn to_be_bytes(felt: Felt) -> [u8; 32] {
-    BigInt::to_be_bytes(felt.0)
+    BigInt::to_be_bytes(Felt::to_bigint(felt))
   }
 
   pub fn unsafe_from_be_bytes(buf: [u8; 32]) -> Felt {
-    Felt(BigInt::from_be_bytes(buf))
+    Felt(Mont::unsafe_convert_from_big(BigInt::from_be_bytes(buf)))
+  }
+
+  // these check that the input is in the range `[0..p-1]`
+  pub fn try_from_le_bytes(buf: [u8; 32]) -> Result<Felt, FeltError> {
+    Felt::try_from_bigint( BigInt::from_le_bytes(buf) )
+  }
+
+  pub fn try_from_be_bytes(buf: [u8; 32]) -> Result<Felt, FeltError> {
+    Felt::try_from_bigint( BigInt::from_be_bytes(buf) )
+  }
+
+  //------------------------------------
+  // reduction modulo p (these never fail)
+
+  // interpret an arbitrary 256 bit number modulo p
+  pub fn from_bigint_mod_order(big: Big) -> Felt {
+    Felt(Mont::convert_from_big_mod_order(big))
+  }
+
+  // little-endian
+  pub fn from_bytes_mod_order(buf: [u8; 32]) -> Felt {
+    Felt(Mont::convert_from_big_mod_order( BigInt::from_le_bytes(buf) ))
+  }
+
+  pub fn from_be_bytes_mod_order(buf: [u8; 32]) -> Felt {
+    Felt(Mont::convert_from_big_mod_order( BigInt::from_be_bytes(buf) ))
+  }
+
+  // hash-to-field: reduce 64 little-endian bytes modulo p. If the input is
+  // uniformly random, the output is uniform up to a bias of about `2^-258`
+  // (whereas reducing just 32 bytes would be badly biased)
+  pub fn from_uniform_bytes(buf: [u8; 64]) -> Felt {
+    Felt(Mont::from_uniform_bytes(buf))
   }
 
   //------------------------------------
   // conversion to/from Montgomery
 
-  // convert to Montgomery representation
+  // these are free, as the internal representation is the same
+
+  #[inline(always)]
   pub fn to_mont(felt: Felt) -> Mont {
-    Mont::unsafe_convert_from_big( felt.0 )
+    felt.0
   }
 
-  // convert from Montgomery representation
+  #[inline(always)]
   pub fn from_mont(mont: Mont) -> Felt {
-    Felt(Mont::convert_to_big(mont))
+    Felt(mont)
+  }
+
+  // reinterpret an array in place, without copying
+  #[inline(always)]
+  pub fn as_mont_array_mut<const T: usize>(felts: &mut [Felt; T]) -> &mut [Mont; T] {
+    // SAFETY: `Felt` is `repr(transparent)` over `Mont`, so the layouts are the same
+    unsafe { &mut *(felts as *mut [Felt; T] as *mut [Mont; T]) }
   }
 
   pub fn to_mont_vec<const T: usize>(felts: [Felt; T]) -> [Mont; T] {
-    felts.map( |x| Felt::to_mont(x) )
+    felts.map( Felt::to_mont )
   }
 
   pub fn from_mont_vec<const T: usize>(monts: [Mont;T] ) -> [Felt; T] {
-    monts.map( |x| Felt::from_mont(x) )
+    monts.map( Felt::from_mont )
   }
 
   //------------------------------------
   
+  #[inline(always)]
   pub fn zero() -> Felt {
-    Felt(BigInt::zero())
+    Felt(Mont::zero())
   }
 
+  #[inline(always)]
   pub fn one() -> Felt {
-    Felt(BigInt::one())
+    Felt(Mont::one())
   }
 
+  #[inline(always)]
   pub fn is_zero(x: Felt) -> bool {
-    BigInt::is_zero(x.0)
+    Mont::is_zero(x.0)
   }
 
   pub fn is_one(x: Felt) -> bool {
-    BigInt::is_one(x.0)
+    Mont::is_one(x.0)
   }
 
   //------------------------------------
   // basic operations
 
+  #[inline(always)]
   pub fn neg(fld: Felt) -> Felt {
-    if BigInt::is_zero(fld.0) {
-      Felt::zero()
-    }
-    else {
-      Felt(BigInt::sub(FIELD_PRIME, fld.0))
-    }
+    Felt(Mont::neg(fld.0))
   }
 
+  #[inline(always)]
   pub fn add(fld1: Felt, fld2: Felt) -> Felt {
-    let (A, _) = BigInt::addCarry(fld1.0, fld2.0);
-    let  B     = BigInt::subtract_prime_if_necessary(A);
-    Felt(B) 
+    Felt(Mont::add(fld1.0, fld2.0))
   }
 
+  #[inline(always)]
   pub fn sub(fld1: Felt, fld2: Felt) -> Felt {
-    let (big, carry) = BigInt::subBorrow(fld1.0, fld2.0);
-    if carry {
-      let (corrected, _) = BigInt::add_prime(big);
-      Felt(corrected)
-    }
-    else {
-      Felt(big)
-    }
+    Felt(Mont::sub(fld1.0, fld2.0))
   }
 
+  #[inline(always)]
   pub fn dbl(fld: Felt) -> Felt {
-    Felt::add(fld, fld)
+    Felt(Mont::dbl(fld.0))
   }
 
+  #[inline(always)]
+  pub fn mul_small(fld: Felt, k: u32) -> Felt {
+    Felt(Mont::mul_small(fld.0, k))
+  }
+
+  #[inline(always)]
+  pub fn mul_small_add(fld1: Felt, k: u32, fld2: Felt) -> Felt {
+    Felt(Mont::mul_small_add(fld1.0, k, fld2.0))
+  }
+
+  #[inline(always)]
   pub fn sqr(fld: Felt) -> Felt {
-    let mont = Felt::to_mont(fld);
-    Felt::from_mont(Mont::sqr(mont))
+    Felt(Mont::sqr(fld.0))
   }
 
+  #[inline(always)]
   pub fn mul(fld1: Felt, fld2: Felt) -> Felt {
-    let mont1 = Felt::to_mont(fld1);
-    let mont2 = Felt::to_mont(fld2);
-    Felt::from_mont(Mont::mul(mont1,mont2))
+    Felt(Mont::mul(fld1.0, fld2.0))
   }
 
   //------------------------------------
 
   pub fn div_by_2(a: Felt) -> Felt {
-    Felt( BigInt::div_by_2_mod_prime(a.0) )
+    Felt(Mont::div_by_2(a.0))
   }
 
   //------------------------------------
 
+  // panics on division by zero; see `Felt::checked_div`
   pub fn div(a: Felt, b: Felt) -> Felt {
-    let x: BigInt256 = a.0;
-    let y: BigInt256 = BigInt::zero();
-    let u: BigInt256 = b.0;
-    let v: BigInt256 = FIELD_PRIME;
-    Felt( euclid(x, y, u, v) )
+    match Felt::checked_div(a,b) {
+      Some(c) => c,
+      None    => panic!("Felt::div: division by zero"),
+    }
+  }
+
+  pub fn checked_div(a: Felt, b: Felt) -> Option<Felt> {
+    Felt::inv(b).map( |c| Felt::mul(a,c) )
+  }
+
+  // returns `None` for zero
+  pub fn inv(b: Felt) -> Option<Felt> {
+    Mont::inv(b.0).map( Felt )
+  }
+
+  // inverts all elements in place, zeros are left as zero
+  pub fn batch_inv(felts: &mut [Felt]) {
+    let mut monts: Vec<Mont> = felts.iter().map( |x| x.0 ).collect();
+    Mont::batch_inv(&mut monts);
+    for i in 0..felts.len() {
+      felts[i] = Felt(monts[i]);
+    }
+  }
+
+  //------------------------------------
+  // constant time operations (see `Mont`)
+
+  pub fn ct_eq(fld1: Felt, fld2: Felt) -> bool {
+    Mont::ct_eq(fld1.0, fld2.0)
+  }
+
+  pub fn ct_is_zero(fld: Felt) -> bool {
+    Mont::ct_is_zero(fld.0)
   }
 
-  pub fn inv(b: Felt) -> Felt {
-    Felt::div( Felt::one() , b )
+  // returns `fld1` if `choice` is false, and `fld2` if it's true
+  pub fn conditional_select(fld1: Felt, fld2: Felt, choice: bool) -> Felt {
+    Felt(Mont::conditional_select(fld1.0, fld2.0, choice))
+  }
+
+  // zero is mapped to zero
+  pub fn ct_inv(fld: Felt) -> Felt {
+    Felt(Mont::ct_inv(fld.0))
+  }
+
+  //------------------------------------
+  // exponentiation and square roots
+
+  pub fn pow<const N: usize>(base: Felt, expo: BigInt<N>) -> Felt {
+    Felt(Mont::pow(base.0, expo))
+  }
+
+  pub fn pow_u64(base: Felt, expo: u64) -> Felt {
+    Felt(Mont::pow_u64(base.0, expo))
+  }
+
+  // `0` for zero, `1` for nonzero squares, `-1` otherwise
+  pub fn legendre(x: Felt) -> i32 {
+    Mont::legendre(x.0)
+  }
+
+  pub fn is_square(x: Felt) -> bool {
+    Felt::legendre(x) >= 0
+  }
+
+  // returns `None` for non-squares; which of the two roots is returned is unspecified
+  pub fn sqrt(x: Felt) -> Option<Felt> {
+    Mont::sqrt(x.0).map( Felt )
+  }
+
+  //------------------------------------
+
+  // the old binary euclidean inversion (slow, kept for cross-checking).
+  // note: this never terminates for zero input!
+  pub fn inv_euclid(b: Felt) -> Felt {
+    let x: BigInt256 = BigInt::one();
+    let y: BigInt256 = BigInt::zero();
+    let u: BigInt256 = Felt::to_bigint(b);
+    let v: BigInt256 = FIELD_PRIME;
+    Felt(Mont::unsafe_convert_from_big( euclid(x, y, u, v) ))
   }
 
 }
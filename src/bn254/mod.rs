@@ -2,9 +2,15 @@
 mod platform;
 
 pub mod traits;
+pub mod random;
+pub mod error;
 pub mod bigint;
 pub mod constant;
 pub mod euclid;
+pub mod safegcd;
+pub mod unsaturated;
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+pub mod asm_x86_64;
 pub mod montgomery;
 pub mod field;
 
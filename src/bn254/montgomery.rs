@@ -6,21 +6,28 @@
 #![allow(dead_code)]
 #![allow(non_snake_case)]
 
-use std::fmt;
-use std::ops::{Neg,Add,Sub,Mul,RangeFull};
+// the limb loops are written with explicit indices, mirroring the math
+#![allow(clippy::needless_range_loop)]
 
-use std::random::{RandomSource,Distribution};
+use std::fmt;
+use std::ops::{Neg,Add,Sub,Mul};
 
 use unroll::unroll_for_loops;
 
 use crate::bn254::traits::*;
+use crate::bn254::random::*;
+use crate::bn254::error::*;
 use crate::bn254::platform::*;
 use crate::bn254::bigint::*;
 use crate::bn254::constant::*;
+use crate::bn254::safegcd::*;
+use crate::bn254::unsaturated::*;
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+use crate::bn254::asm_x86_64;
 
 //------------------------------------------------------------------------------
 
-type Big = BigInt<8>;
+type Big = BigInt256;
 
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub struct Mont(Big);
@@ -29,6 +36,8 @@ pub const MONT_R1 : Mont = Mont(BIG_R1);
 pub const MONT_R2 : Mont = Mont(BIG_R2);
 pub const MONT_R3 : Mont = Mont(BIG_R3);
 
+pub const MONT_ROOT_OF_UNITY : Mont = Mont(ROOT_OF_UNITY_MONT);
+
 //------------------------------------------------------------------------------
 // display traits
 
@@ -86,6 +95,10 @@ impl One for Mont {
   fn is_one(x: Self)  -> bool { Mont::is_one(x)  }
 }
 
+impl Zeroize for Mont {
+  fn zeroize(&mut self) { self.0.zeroize() }
+}
+
 //------------------------------------------------------------------------------
 // small values
 
@@ -97,18 +110,10 @@ impl From<u32> for Mont {
   fn from(x: u32) -> Self { Self::convert_from_u32(x) }
 }
 
-//------------------------------------------------------------------------------
-// random trait
-
-impl Distribution<Mont> for RangeFull {
-  fn sample(&self, source: &mut (impl RandomSource + ?Sized)) -> Mont {
-    Mont(BigInt::sample_mod_prime(source))
-  }
-}
-
 //------------------------------------------------------------------------------
 // internal implementations
 
+#[allow(clippy::should_implement_trait)]
 impl Mont {
 
   #[inline(always)]
@@ -118,13 +123,18 @@ impl Mont {
 
   #[inline(always)]
   pub const fn unsafe_make( xs: [u32; 8] ) -> Mont {
-    Mont(BigInt::make(xs))
+    Mont(BigInt::from_words32(xs))
   }
 
   pub fn is_valid(mont: Mont) -> bool {
     BigInt::is_lt_prime(mont.0)
   }
 
+  // uniformly random field element
+  pub fn sample(source: &mut (impl RandomSource + ?Sized)) -> Mont {
+    Mont(BigInt::sample_mod_prime(source))
+  }
+
   //------------------------------------
   // to/from bytes
 
@@ -150,6 +160,20 @@ impl Mont {
     Mont(big)
   }
 
+  // checked versions: the input is still the Montgomery representation,
+  // but we check that it's in the range `[0..p-1]`
+  pub fn try_from_le_bytes(buf: [u8; 32]) -> Result<Mont, FeltError> {
+    Mont::try_from_bigint( BigInt::from_le_bytes(buf) )
+  }
+
+  pub fn try_from_be_bytes(buf: [u8; 32]) -> Result<Mont, FeltError> {
+    Mont::try_from_bigint( BigInt::from_be_bytes(buf) )
+  }
+
+  fn try_from_bigint(big: Big) -> Result<Mont, FeltError> {
+    if BigInt::is_lt_prime(big) { Ok(Mont(big)) } else { Err(FeltError::NotInRange) }
+  }
+
   //------------------------------------
   // to string
 
@@ -185,6 +209,9 @@ impl Mont {
   //------------------------------------
 
   pub fn neg(mont: Mont) -> Mont {
+    if cfg!(feature = "constant-time") {
+      return Mont(BigInt::sub_mod_prime(BigInt::zero(), mont.0))
+    }
     if BigInt::is_zero(mont.0) {
       Mont::zero()
     }
@@ -202,14 +229,7 @@ impl Mont {
 
   #[inline(always)]
   pub fn sub(mont1: Mont, mont2: Mont) -> Mont {
-    let (big, carry) = BigInt::subBorrow(mont1.0, mont2.0);
-    if carry {
-      let (corrected, _) = BigInt::add_prime(big);
-      Mont(corrected)
-    }
-    else {
-      Mont(big)
-    }
+    Mont(BigInt::sub_mod_prime(mont1.0, mont2.0))
   }
 
   #[inline(always)]
@@ -217,6 +237,25 @@ impl Mont {
     Mont::add(mont, mont)
   }
 
+  // multiplication by a small integer constant (not in Montgomery form!). 
+  // The representation is linear, so this is just a single-limb scaling 
+  // followed by a cheap reduction
+  #[inline(always)]
+  pub fn mul_small(mont: Mont, k: u32) -> Mont {
+    Mont(BigInt::scale_mod_prime(k, mont.0))
+  }
+
+  // k*x + y
+  #[inline(always)]
+  pub fn mul_small_add(mont1: Mont, k: u32, mont2: Mont) -> Mont {
+    Mont(BigInt::scale_add_mod_prime(k, mont1.0, mont2.0))
+  }
+
+  #[inline(always)]
+  pub fn div_by_2(mont: Mont) -> Mont {
+    Mont(BigInt::div_by_2_mod_prime(mont.0))
+  }
+
   //------------------------------------
   // reduction and multiplication
 
@@ -263,53 +302,442 @@ impl Mont {
 
   //----------------
 
+  // the generic Montgomery reduction (kept for cross-checking `redc`).
   // we can abuse the fact that we know the prime number `p`,
-  // for which `p < 2^254` so we won't overflow in the 17th word
+  // for which `p < 2^254` so we won't overflow in the extra limb
 
+  #[inline(always)]
   #[unroll_for_loops]
-  fn redc(input: BigInt<16>) -> Big {
+  pub(crate) fn redc_generic(input: BigInt512) -> Big {
 
-    let mut T: [u32; 16] = BigInt::to_limbs(input);
+    let mut T: [Limb; 2*NLIMBS] = BigInt::to_limbs(input);
 
-    for i in 0..8 {
-      let mut carry: u32 = 0;
-      let m: u32 = mulTrunc32( T[i] , MONT_Q );
-      for j in 0..8 {
-        let (lo,hi) = mulAddAdd32( m, PRIME_ARRAY[j], carry, T[i+j] );
+    for i in 0..NLIMBS {
+      let mut carry: Limb = 0;
+      let m: Limb = mulTruncLimb( T[i] , MONT_Q );
+      for j in 0..NLIMBS {
+        let (lo,hi) = mulAddAddLimb( m, PRIME_ARRAY[j], carry, T[i+j] );
         T[i+j] = lo;
         carry  = hi;
       }
-      for j in 8..(16-i) {
-        let (x,c) = addCarry32_( T[i+j] , carry );
+      for j in NLIMBS..(2*NLIMBS-i) {
+        let (x,c) = addCarryLimb_( T[i+j] , carry );
         T[i+j] = x;
-        carry  = boolToU32(c);
+        carry  = boolToLimb(c);
       }
     }
 
-    let mut S : [u32; 8] = [0; 8];
-    for i in 0..8 { S[i] = T[8+i]; }
+    let mut S : [Limb; NLIMBS] = [0; NLIMBS];
+    S.copy_from_slice(&T[NLIMBS..2*NLIMBS]);
 
     let A : Big = BigInt::make(S);
     let B : Big = BigInt::subtract_prime_if_necessary(A); 
     B
   }
 
+  //----------------
+
+  // the quotient digit `m = -T[i]/p mod 2^LIMB_BITS`. With 32 bit limbs
+  // `MONT_Q = 0xefffffff = -(1 + 2^28)`, as `p = 1 mod 2^28`, so we don't 
+  // need a multiplication. (with 64 bit limbs the constant has no such structure)
+  #[inline(always)]
+  fn quotient_digit(t: Limb) -> Limb {
+    if LIMB_BITS == 32 {
+      t.wrapping_add( t << 28 ).wrapping_neg()
+    }
+    else {
+      mulTruncLimb( t , MONT_Q )
+    }
+  }
+
+  // the Montgomery reduction, specialized to the shape of `p`:
+  //
+  // - the quotient digit is cheap (see above);
+  // - the low limb of `T[i] + m*p[0]` is zero by construction, so we only need 
+  //   the carry, which is `hi(m*p[0]) + (T[i] != 0)`;
+  // - instead of propagating the carry of each row through the whole upper 
+  //   half, we defer it to the next row: as `p < 2^254`, the partial sums
+  //   stay below `2^511`, so the last carry is always zero.
+  //
+  #[inline(always)]
+  pub(crate) fn redc(input: BigInt512) -> Big {
+    BigInt::subtract_prime_if_necessary( Mont::redc_unreduced(input) )
+  }
+
+  // the same without the final subtraction; the output is `(T + m*p) / 2^256`,
+  // which is less than `T/2^256 + p`. This is correct as long as that fits into 
+  // 256 bits (then the last deferred carry is always zero)
+  #[inline(always)]
+  #[unroll_for_loops]
+  pub(crate) fn redc_unreduced(input: BigInt512) -> Big {
+
+    let mut T: [Limb; 2*NLIMBS] = BigInt::to_limbs(input);
+    let mut deferred: bool = false;
+
+    for i in 0..NLIMBS {
+      let m: Limb = Mont::quotient_digit( T[i] );
+      let (_,hi) = mulExtLimb( m, PRIME_ARRAY[0] );
+      let mut carry: Limb = hi + boolToLimb( T[i] != 0 );
+      for j in 1..NLIMBS {
+        let (lo,hi) = mulAddAddLimb( m, PRIME_ARRAY[j], carry, T[i+j] );
+        T[i+j] = lo;
+        carry  = hi;
+      }
+      let (x,c) = addCarryLimb( T[i+NLIMBS] , carry , deferred );
+      T[i+NLIMBS] = x;
+      deferred    = c;
+    }
+
+    let mut S : [Limb; NLIMBS] = [0; NLIMBS];
+    S.copy_from_slice(&T[NLIMBS..2*NLIMBS]);
+
+    BigInt::make(S)
+  }
+
+  // by default we use the fused CIOS squaring: it needs about 22% fewer 
+  // instructions than `mul_cios(x,x)` on RV32IM (880 vs. 1128), and it's
+  // also faster on x86_64 (about 8% with 64 bit limbs, 30% with 32 bit limbs)
+  #[inline(always)]
   pub fn sqr(mont: Mont) -> Mont {
+    #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+    if asm_x86_64::is_supported() {
+      return unsafe { Mont::mul_adx(mont, mont) };
+    }
+    if cfg!(feature = "unsaturated") {
+      Mont::sqr_u29(mont)
+    }
+    else if cfg!(feature = "mont-sos") {
+      Mont::sqr_sos(mont)
+    }
+    else {
+      Mont::sqr_cios(mont)
+    }
+  }
+
+  // separated squaring: the dedicated bigint squaring (with the halved 
+  // number of limb products), followed by a separate Montgomery reduction
+  pub fn sqr_sos(mont: Mont) -> Mont {
     let large = BigInt::sqr(mont.0);
     Mont(Mont::redc(large))
   }
 
-  pub fn mul(mont1: Mont, mont2: Mont) -> Mont {
-    let large = BigInt::mul(mont1.0, mont2.0);
+  // squaring as a multiplication (for testing)
+  pub fn sqr_naive(mont: Mont) -> Mont {
+    let large = BigInt::sqr_naive(mont.0);
     Mont(Mont::redc(large))
   }
 
+  // note: by default we use the fused CIOS multiplication; 
+  // the `mont-sos` feature selects the separated (full product + `redc`) version,
+  // and the `unsaturated` feature the 29 bit limb version (which wins if both are set).
+  // With the `asm` feature, on x86_64 CPUs supporting BMI2/ADX we use inline assembly
+  #[inline(always)]
+  pub fn mul(mont1: Mont, mont2: Mont) -> Mont {
+    #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+    if asm_x86_64::is_supported() {
+      return unsafe { Mont::mul_adx(mont1, mont2) };
+    }
+    if cfg!(feature = "unsaturated") {
+      Mont::mul_u29(mont1, mont2)
+    }
+    else if cfg!(feature = "mont-sos") {
+      Mont::mul_sos(mont1, mont2)
+    }
+    else {
+      Mont::mul_cios(mont1, mont2)
+    }
+  }
+
   // x*y + z
+  #[inline(always)]
   pub fn mulAdd(mont1: Mont, mont2: Mont, mont3: Mont) -> Mont {
-    let large = BigInt::mulAddShifted(mont1.0, mont2.0, mont3.0);
+    #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+    if asm_x86_64::is_supported() {
+      return Mont::add( unsafe { Mont::mul_adx(mont1, mont2) } , mont3 );
+    }
+    if cfg!(feature = "unsaturated") {
+      Mont::add( Mont::mul_u29(mont1, mont2) , mont3 )
+    }
+    else if cfg!(feature = "mont-sos") {
+      Mont::mulAdd_sos(mont1, mont2, mont3)
+    }
+    else {
+      Mont::add( Mont::mul_cios(mont1, mont2) , mont3 )
+    }
+  }
+
+  //----------------
+
+  // SOS ("separated operand scanning"): full 512 bit product, then `redc`.
+  // note: this works even if the first input is not reduced, see `reduce_big`
+  pub fn mul_sos(mont1: Mont, mont2: Mont) -> Mont {
+    let large = BigInt::mul(mont1.0, mont2.0);
     Mont(Mont::redc(large))
   }
 
+  // note: the input of `redc` here is `x*y + z*2^256 < p^2 + p*2^256`, so its 
+  // output is only guaranteed to be less than `p^2/2^256 + 2p < 2.2p`, and a 
+  // second conditional subtraction is needed
+  pub fn mulAdd_sos(mont1: Mont, mont2: Mont, mont3: Mont) -> Mont {
+    let large = BigInt::mulAddShifted(mont1.0, mont2.0, mont3.0);
+    Mont(BigInt::subtract_prime_if_necessary(Mont::redc(large)))
+  }
+
+  // CIOS ("coarsely integrated operand scanning"): the multiplication and the
+  // reduction steps are interleaved, so the 512 bit product never materializes.
+  // Since the top limb of `p` is less than `(2^LIMB_BITS-1)/2`, we don't need 
+  // the usual two extra limbs of state (the "no-carry" optimization, see 
+  // <https://hackmd.io/@gnark/modular_multiplication>).
+  // note: both inputs must be in the range `[0..p-1]`!
+  #[inline(always)]
+  #[unroll_for_loops]
+  pub fn mul_cios(mont1: Mont, mont2: Mont) -> Mont {
+    let xs: [Limb; NLIMBS] = BigInt::to_limbs(mont1.0);
+    let ys: [Limb; NLIMBS] = BigInt::to_limbs(mont2.0);
+    let mut T: [Limb; NLIMBS] = [0; NLIMBS];
+
+    for i in 0..NLIMBS {
+      let (t0,hi) = mulAddLimb( ys[i], xs[0], T[0] );
+      let mut A: Limb = hi;
+      let m:     Limb = Mont::quotient_digit( t0 );
+      let (_ ,hi) = mulAddLimb( m, PRIME_ARRAY[0], t0 );
+      let mut C: Limb = hi;
+      for j in 1..NLIMBS {
+        let (lo,hi) = mulAddAddLimb( ys[i], xs[j], T[j], A );
+        A = hi;
+        let (lo,hi) = mulAddAddLimb( m, PRIME_ARRAY[j], lo, C );
+        T[j-1] = lo;
+        C = hi;
+      }
+      T[NLIMBS-1] = C.wrapping_add(A);
+    }
+
+    Mont(BigInt::subtract_prime_if_necessary(BigInt::make(T)))
+  }
+
+  // fused Montgomery squaring, in the same shape as `mul_cios`. As 
+  // `x^2 = sum_i x[i]*B^2i * (x[i] + 2*B*floor(x/B^(i+1)))` (with `B = 2^LIMB_BITS`), 
+  // row `i` adds the square `x[i]^2` and the doubled products `2*x[i]*x[j]` for `j>i` 
+  // together, starting at limb `i` of the (already shifted) state. The limbs of the 
+  // doubled part are the limbs of `2x`, except the lowest one, which doesn't get the 
+  // top bit of `x[i]`. So the rows get shorter, and we need `N(N+1)/2` limb products 
+  // instead of `N^2` (plus the `N^2` of the reduction).
+  // note: the input must be in the range `[0..p-1]`!
+  #[inline(always)]
+  #[unroll_for_loops]
+  // the unrolled iterations `i >= NLIMBS` are dead code, but clippy still looks at them
+  #[allow(clippy::out_of_bounds_indexing, clippy::absurd_extreme_comparisons)]
+  pub fn sqr_cios(mont: Mont) -> Mont {
+    let xs: [Limb; NLIMBS] = BigInt::to_limbs(mont.0);
+    let mut ys: [Limb; NLIMBS] = [0; NLIMBS];     // the limbs of `2x`
+    ys[0] = xs[0] << 1;
+    for j in 1..NLIMBS {
+      ys[j] = (xs[j] << 1) | (xs[j-1] >> (LIMB_BITS-1));
+    }
+    let mut T: [Limb; NLIMBS] = [0; NLIMBS];
+
+    // `unroll_for_loops` needs a literal bound (8 = the number of 32 bit limbs); 
+    // unrolling the outer loop too lets the compiler drop the `j < i` branches
+    for i in 0..8 { if i < NLIMBS {
+      let (t0,hi) = if i == 0 { mulAddLimb( xs[0], xs[0], T[0] ) } else { (T[0], 0) };
+      let mut A: Limb = hi;
+      let m:     Limb = Mont::quotient_digit( t0 );
+      let (_ ,hi) = mulAddLimb( m, PRIME_ARRAY[0], t0 );
+      let mut C: Limb = hi;
+      for j in 1..NLIMBS {
+        let (lo,hi) = 
+          if      j <  i   { (T[j], 0) }
+          else if j == i   { mulAddAddLimb( xs[i], xs[i]    , T[j], A ) }
+          else if j == i+1 { mulAddAddLimb( xs[i], xs[j]<<1 , T[j], A ) }
+          else             { mulAddAddLimb( xs[i], ys[j]    , T[j], A ) };
+        A = hi;
+        let (lo,hi) = mulAddAddLimb( m, PRIME_ARRAY[j], lo, C );
+        T[j-1] = lo;
+        C = hi;
+      }
+      T[NLIMBS-1] = C.wrapping_add(A);
+    } }
+
+    Mont(BigInt::subtract_prime_if_necessary(BigInt::make(T)))
+  }
+
+  //----------------
+
+  // unsaturated 9x29 bit limbs, with the carries deferred to the end of each
+  // column (see the `unsaturated` module); intended for 32 bit targets without 
+  // a carry flag (like RV32IM).
+  // note: both inputs must be in the range `[0..p-1]`!
+  #[inline(always)]
+  pub fn mul_u29(mont1: Mont, mont2: Mont) -> Mont {
+    Mont(BigInt::subtract_prime_if_necessary(mul29(mont1.0, mont2.0)))
+  }
+
+  #[inline(always)]
+  pub fn sqr_u29(mont: Mont) -> Mont {
+    Mont(BigInt::subtract_prime_if_necessary(sqr29(mont.0)))
+  }
+
+  //----------------
+
+  /// the same CIOS algorithm in x86_64 assembly, using `mulx`/`adcx`/`adox`
+  /// (see the `asm_x86_64` module).
+  /// note: both inputs must be in the range `[0..p-1]`!
+  ///
+  /// # Safety
+  ///
+  /// The CPU must support BMI2 and ADX, see `asm_x86_64::is_supported()`
+  #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+  #[inline(always)]
+  pub unsafe fn mul_adx(mont1: Mont, mont2: Mont) -> Mont {
+    Mont(BigInt::subtract_prime_if_necessary(asm_x86_64::mont_mul_adx(mont1.0, mont2.0)))
+  }
+
+  //------------------------------------
+  // inversion
+
+  // returns `None` for zero.
+  // The inverse of `x*R` is `x^-1 * R^-1`, so we have to multiply by `R^2`
+  // (and one more `R` to compensate for the Montgomery multiplication)
+  pub fn inv(mont: Mont) -> Option<Mont> {
+    if Mont::is_zero(mont) {
+      None
+    }
+    else {
+      let y: Big = modinv_var(mont.0);
+      Some(Mont::mul( Mont(y) , MONT_R3 ))
+    }
+  }
+
+  // x / y; returns `None` if y is zero
+  pub fn div(mont1: Mont, mont2: Mont) -> Option<Mont> {
+    Mont::inv(mont2).map( |y| Mont::mul(mont1,y) )
+  }
+
+  //------------------------------------
+  // constant time operations
+  // (these are only really constant time with the `constant-time` feature,
+  // which makes the basic arithmetic branch-free)
+
+  pub fn ct_eq(mont1: Mont, mont2: Mont) -> bool {
+    BigInt::ct_eq(mont1.0, mont2.0)
+  }
+
+  pub fn ct_is_zero(mont: Mont) -> bool {
+    BigInt::ct_is_zero(mont.0)
+  }
+
+  // returns `mont1` if `choice` is false, and `mont2` if it's true
+  pub fn conditional_select(mont1: Mont, mont2: Mont, choice: bool) -> Mont {
+    Mont(BigInt::conditional_select(mont1.0, mont2.0, choice))
+  }
+
+  pub fn conditional_swap(mont1: &mut Mont, mont2: &mut Mont, choice: bool) {
+    let a: Mont = *mont1;
+    let b: Mont = *mont2;
+    *mont1 = Mont::conditional_select(a, b, choice);
+    *mont2 = Mont::conditional_select(b, a, choice);
+  }
+
+  // Fermat inversion `x^(p-2)`: the exponent is public, so there is no 
+  // secret-dependent branching. Zero is mapped to zero
+  pub fn ct_inv(mont: Mont) -> Mont {
+    Mont::pow(mont, PRIME_MINUS_2)
+  }
+
+  //------------------------------------
+  // exponentiation and square roots
+
+  // left-to-right binary exponentiation (variable time in the exponent)
+  pub fn pow<const N: usize>(base: Mont, expo: BigInt<N>) -> Mont {
+    let limbs: [Limb; N] = BigInt::to_limbs(expo);
+    let mut acc: Mont = Mont::one();
+    for i in (0..N).rev() {
+      let w: Limb = limbs[i];
+      for j in (0..LIMB_BITS).rev() {
+        acc = Mont::sqr(acc);
+        if (w >> j) & 1 != 0 { acc = Mont::mul(acc, base); }
+      }
+    }
+    acc
+  }
+
+  pub fn pow_u64(base: Mont, expo: u64) -> Mont {
+    let mut acc: Mont = Mont::one();
+    for j in (0..64).rev() {
+      acc = Mont::sqr(acc);
+      if (expo >> j) & 1 != 0 { acc = Mont::mul(acc, base); }
+    }
+    acc
+  }
+
+  // the Legendre symbol: `0` for zero, `1` for nonzero squares, `-1` otherwise
+  pub fn legendre(mont: Mont) -> i32 {
+    let y: Mont = Mont::pow(mont, HALFP_MINUS_1);
+    if Mont::is_zero(y)     {  0 }
+    else if Mont::is_one(y) {  1 }
+    else                    { -1 }
+  }
+
+  // Tonelli-Shanks square root. Returns `None` for quadratic non-residues.
+  // Which one of the two roots is returned is unspecified
+  pub fn sqrt(mont: Mont) -> Option<Mont> {
+
+    if Mont::is_zero(mont) { return Some(mont) }
+
+    let w: Mont = Mont::pow(mont, TRACE_MINUS_1_DIV_2);    // x^((t-1)/2)
+    let mut v: u32  = TWO_ADICITY;
+    let mut z: Mont = MONT_ROOT_OF_UNITY;
+    let mut x: Mont = Mont::mul(mont, w);                   // x^((t+1)/2)
+    let mut b: Mont = Mont::mul(x, w);                      // x^t
+
+    // invariant: `x^2 = mont * b`, and the order of `b` divides `2^(v-1)`
+    while !Mont::is_one(b) {
+      // find the smallest `k` such that `b^(2^k) = 1`
+      let mut k:   u32  = 0;
+      let mut b2k: Mont = b;
+      while !Mont::is_one(b2k) {
+        b2k = Mont::sqr(b2k);
+        k  += 1;
+        if k == v { return None }
+      }
+      let mut c: Mont = z;
+      for _ in 0..(v-k-1) { c = Mont::sqr(c); }
+      z = Mont::sqr(c);
+      b = Mont::mul(b, z);
+      x = Mont::mul(x, c);
+      v = k;
+    }
+
+    Some(x)
+  }
+
+  //------------------------------------
+
+  // inverts all elements in place, using Montgomery's trick (a single
+  // inversion and `3(n-1)` multiplications). Zero elements are left as zero
+  pub fn batch_inv(monts: &mut [Mont]) {
+
+    // prefix products of the nonzero elements
+    let mut prods: Vec<Mont> = Vec::with_capacity(monts.len());
+    let mut acc: Mont = Mont::one();
+    for x in monts.iter() {
+      if !Mont::is_zero(*x) { acc = Mont::mul(acc, *x); }
+      prods.push(acc);
+    }
+
+    // `acc` is a product of nonzero elements, so it's invertible
+    let mut inv: Mont = Mont::inv(acc).unwrap();
+
+    for i in (0..monts.len()).rev() {
+      let x: Mont = monts[i];
+      if !Mont::is_zero(x) {
+        let prev: Mont = if i > 0 { prods[i-1] } else { Mont::one() };
+        monts[i] = Mont::mul(inv, prev);
+        inv      = Mont::mul(inv, x);
+      }
+    }
+  }
+
   //------------------------------------
   // conversions to/from standard bigint representation
 
@@ -322,11 +750,66 @@ impl Mont {
 
   // this does conversion to the standard representation
   pub fn convert_to_big(mont: Mont) -> Big {
-    let mut tmp: [u32; 16] = [0; 16];
-    for i in 0..8 { tmp[i] = BigInt::to_limbs(mont.0)[i] } 
+    let mut tmp: [Limb; 2*NLIMBS] = [0; 2*NLIMBS];
+    tmp[0..NLIMBS].copy_from_slice(&BigInt::to_limbs(mont.0));
     Mont::redc( BigInt::make(tmp) )
   }
 
+  // the same as `unsafe_convert_from_big`, but usable in constant expressions
+  // (like the constructors of `Felt`). This is a plain word-by-word CIOS with
+  // 32 bit words, as the platform primitives are not `const`; at runtime it's 
+  // several times slower, so it's only meant for compile-time constants
+  #[allow(clippy::unnecessary_cast)]
+  pub const fn const_convert_from_big(input: Big) -> Mont {
+    let x: [u32; 8] = BigInt::to_words32(input);
+    let y: [u32; 8] = BigInt::to_words32(BIG_R2);
+    let p: [u32; 8] = BigInt::to_words32(FIELD_PRIME);
+    let q: u32      = MONT_Q as u32;
+    let mut T: [u64; 9] = [0; 9];
+    let mut i: usize = 0;
+    while i < 8 {
+      // T += x * y[i]
+      let mut carry: u64 = 0;
+      let mut j: usize = 0;
+      while j < 8 {
+        let s: u64 = T[j] + (x[j] as u64) * (y[i] as u64) + carry;
+        T[j]  = s & 0xffffffff;
+        carry = s >> 32;
+        j += 1;
+      }
+      T[8] += carry;
+      // T = (T + m*p) / 2^32
+      let m: u64 = ((T[0] as u32).wrapping_mul(q)) as u64;
+      let mut carry: u64 = (T[0] + m * (p[0] as u64)) >> 32;
+      let mut j: usize = 1;
+      while j < 8 {
+        let s: u64 = T[j] + m * (p[j] as u64) + carry;
+        T[j-1] = s & 0xffffffff;
+        carry  = s >> 32;
+        j += 1;
+      }
+      let s: u64 = T[8] + carry;
+      T[7] = s & 0xffffffff;
+      T[8] = s >> 32;
+      i += 1;
+    }
+    // the result is less than `2p`
+    let mut z: [u32; 8] = [0; 8];
+    let mut borrow: u64 = 0;
+    let mut j: usize = 0;
+    while j < 8 {
+      let s: u64 = T[j].wrapping_sub(p[j] as u64).wrapping_sub(borrow);
+      z[j]   = s as u32;
+      borrow = (s >> 63) & 1;
+      j += 1;
+    }
+    if borrow > T[8] {
+      let mut j: usize = 0;
+      while j < 8 { z[j] = T[j] as u32; j += 1; }
+    }
+    Mont(BigInt::from_words32(z))
+  }
+
   // take a small number, interpret it as modulo P, 
   // and convert to Montgomery representation
   pub fn convert_from_u32(x: u32) -> Mont {
@@ -334,6 +817,109 @@ impl Mont {
     Mont::unsafe_convert_from_big( big )
   }
 
+  //------------------------------------
+  // reduction of arbitrary integers modulo p
+
+  // note: `redc` only needs its input to be less than `p*2^256`, so we can
+  // multiply an unreduced 256 bit number by a reduced one (any of `R1,R2,R3`),
+  // and the result will be properly reduced
+
+  // reduce an arbitrary 256 bit number modulo p (standard representation)
+  pub fn reduce_big(input: Big) -> Big {
+    Mont::mul_sos( Mont(input) , MONT_R1 ).0
+  }
+
+  // reduce an arbitrary 512 bit number `lo + 2^256*hi` modulo p (standard representation)
+  pub fn reduce_wide(lo: Big, hi: Big) -> Big {
+    Mont::add( Mont::mul_sos( Mont(lo) , MONT_R1 ) , Mont::mul_sos( Mont(hi) , MONT_R2 ) ).0
+  }
+
+  // interpret an arbitrary 256 bit number modulo p,
+  // and convert to Montgomery representation
+  pub fn convert_from_big_mod_order(input: Big) -> Mont {
+    Mont::mul_sos( Mont(input) , MONT_R2 )
+  }
+
+  // interpret an arbitrary 512 bit number `lo + 2^256*hi` modulo p,
+  // and convert to Montgomery representation
+  pub fn convert_from_wide_mod_order(lo: Big, hi: Big) -> Mont {
+    Mont::add( Mont::mul_sos( Mont(lo) , MONT_R2 ) , Mont::mul_sos( Mont(hi) , MONT_R3 ) )
+  }
+
+  // little-endian, standard representation (NOT Montgomery!)
+  pub fn from_bytes_mod_order(buf: [u8; 32]) -> Mont {
+    Mont::convert_from_big_mod_order( BigInt::from_le_bytes(buf) )
+  }
+
+  // little-endian, standard representation (NOT Montgomery!).
+  // with 64 uniformly random bytes the result is statistically
+  // indistinguishable from uniform (the bias is about `2^-258`)
+  pub fn from_uniform_bytes(buf: [u8; 64]) -> Mont {
+    let (lo,hi) = split_wide_le_bytes(buf);
+    Mont::convert_from_wide_mod_order(lo,hi)
+  }
+
+}
+
+// split 64 little-endian bytes into the low and high 256 bit halves
+pub(crate) fn split_wide_le_bytes(buf: [u8; 64]) -> (Big,Big) {
+  let mut lo: [u8; 32] = [0; 32];
+  let mut hi: [u8; 32] = [0; 32];
+  lo.copy_from_slice( &buf[ 0..32] );
+  hi.copy_from_slice( &buf[32..64] );
+  ( BigInt::from_le_bytes(lo) , BigInt::from_le_bytes(hi) )
+}
+
+//------------------------------------------------------------------------------
+// lazy reduction
+//
+// In the linear layers of Poseidon we compute sums of several products; as 
+// `p < 2^254`, these can be accumulated unreduced, and reduced only once at the end.
+
+// an unreduced sum of products `x*y` and (shifted) field elements `z*2^256`, 
+// which becomes a single Montgomery reduction. The represented value must stay
+// below `(2^256-p)*2^256`: that is, at most 22 products, or 4 field elements 
+// (which count as about 5.3 products each)
+#[derive(Copy, Clone)]
+pub struct MontAcc(BigInt512);
+
+#[allow(clippy::should_implement_trait)]
+impl MontAcc {
+
+  #[inline(always)]
+  pub fn zero() -> MontAcc {
+    MontAcc(BigInt::zero())
+  }
+
+  // the product `x*y`
+  #[inline(always)]
+  pub fn from_mul(mont1: Mont, mont2: Mont) -> MontAcc {
+    MontAcc(BigInt::mul(mont1.0, mont2.0))
+  }
+
+  // the product `x*y` plus the field element `z`
+  #[inline(always)]
+  pub fn from_mul_add(mont1: Mont, mont2: Mont, mont3: Mont) -> MontAcc {
+    MontAcc(BigInt::mulAddShifted(mont1.0, mont2.0, mont3.0))
+  }
+
+  // acc + x*y
+  #[inline(always)]
+  pub fn add_mul(acc: MontAcc, mont1: Mont, mont2: Mont) -> MontAcc {
+    MontAcc(BigInt::mulAcc(acc.0, mont1.0, mont2.0))
+  }
+
+  // acc + z
+  #[inline(always)]
+  pub fn add(acc: MontAcc, mont: Mont) -> MontAcc {
+    MontAcc(BigInt::addShifted(acc.0, mont.0))
+  }
+
+  #[inline(always)]
+  pub fn reduce(acc: MontAcc) -> Mont {
+    Mont(BigInt::reduce_mod_prime(Mont::redc_unreduced(acc.0)))
+  }
+
 }
 
 //------------------------------------------------------------------------------
@@ -0,0 +1,34 @@
+
+// 32 bit limbs
+
+#![allow(dead_code)]
+#![allow(unused_imports)]
+#![allow(non_snake_case)]
+
+//------------------------------------------------------------------------------
+
+pub type Limb  = u32;
+pub type DLimb = u64;     // double-width limb
+
+pub const LIMB_BITS  : usize = 32;
+pub const LIMB_BYTES : usize = 4;
+
+// number of limbs in a 256 bit integer
+pub const NLIMBS : usize = 8;
+
+//------------------------------------------------------------------------------
+
+pub use super::boolToU32     as boolToLimb;
+pub use super::addCarry32_   as addCarryLimb_;
+pub use super::subBorrow32_  as subBorrowLimb_;
+pub use super::addCarry32    as addCarryLimb;
+pub use super::subBorrow32   as subBorrowLimb;
+pub use super::mulTrunc32    as mulTruncLimb;
+pub use super::mulExt32      as mulExtLimb;
+pub use super::mulAdd32      as mulAddLimb;
+pub use super::mulAddAdd32   as mulAddAddLimb;
+pub use super::rotRight32By1 as rotRightLimbBy1;
+pub use super::rotLeft32By1  as rotLeftLimbBy1;
+
+//------------------------------------------------------------------------------
+
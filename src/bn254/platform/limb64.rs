@@ -0,0 +1,34 @@
+
+// 64 bit limbs
+
+#![allow(dead_code)]
+#![allow(unused_imports)]
+#![allow(non_snake_case)]
+
+//------------------------------------------------------------------------------
+
+pub type Limb  = u64;
+pub type DLimb = u128;    // double-width limb
+
+pub const LIMB_BITS  : usize = 64;
+pub const LIMB_BYTES : usize = 8;
+
+// number of limbs in a 256 bit integer
+pub const NLIMBS : usize = 4;
+
+//------------------------------------------------------------------------------
+
+pub use super::boolToU64     as boolToLimb;
+pub use super::addCarry64_   as addCarryLimb_;
+pub use super::subBorrow64_  as subBorrowLimb_;
+pub use super::addCarry64    as addCarryLimb;
+pub use super::subBorrow64   as subBorrowLimb;
+pub use super::mulTrunc64    as mulTruncLimb;
+pub use super::mulExt64      as mulExtLimb;
+pub use super::mulAdd64      as mulAddLimb;
+pub use super::mulAddAdd64   as mulAddAddLimb;
+pub use super::rotRight64By1 as rotRightLimbBy1;
+pub use super::rotLeft64By1  as rotLeftLimbBy1;
+
+//------------------------------------------------------------------------------
+
@@ -1,7 +1,29 @@
 
+#[cfg(feature = "unstable")]
 mod unstable;
+#[cfg(not(feature = "unstable"))]
 mod portable;
 
+// the portable version compiles on stable Rust; the `unstable` feature
+// switches to the `bigint_helper_methods` intrinsics instead
+#[cfg(feature = "unstable")]
 pub use crate::bn254::platform::unstable::*;
-//pub use crate::bn254::platform::portable::*;
+#[cfg(not(feature = "unstable"))]
+pub use crate::bn254::platform::portable::*;
+
+//------------------------------------------------------------------------------
+// limb size selection
+//
+// by default we use 64 bit limbs on 64 bit targets, and 32 bit limbs otherwise;
+// this can be overridden by the `limb32` / `limb64` cargo features
+
+#[cfg(any(feature = "limb64", all(target_pointer_width = "64", not(feature = "limb32"))))]
+mod limb64;
+#[cfg(any(feature = "limb64", all(target_pointer_width = "64", not(feature = "limb32"))))]
+pub use crate::bn254::platform::limb64::*;
+
+#[cfg(not(any(feature = "limb64", all(target_pointer_width = "64", not(feature = "limb32")))))]
+mod limb32;
+#[cfg(not(any(feature = "limb64", all(target_pointer_width = "64", not(feature = "limb32")))))]
+pub use crate::bn254::platform::limb32::*;
 
@@ -13,6 +13,11 @@ pub fn boolToU32(c: bool) -> u32 {
   if c { 1 } else { 0 }
 }
 
+#[inline(always)]
+pub fn boolToMSB32(c: bool) -> u32 {
+  if c { 0x_8000_0000 } else { 0 }
+}
+
 #[inline(always)]
 pub fn takeApart64(x: u64) -> (u32,u32) {
   let lo: u32 = (x & U32_MASK) as u32;
@@ -79,5 +84,109 @@ pub fn mulAddAdd32(x: u32, y: u32, a: u32, b: u32) -> (u32,u32) {
   (lo, hi)
 }
 
+#[inline(always)]
+pub fn u64AddAdd32(xy: (u32,u32), a: u32, b: u32) -> (u32,u32) {
+  let z: u64 = ((xy.1 as u64) << 32) + (xy.0 as u64);
+  let w: u64 = u64::wrapping_add(u64::wrapping_add(z, a as u64), b as u64);
+  takeApart64(w)
+}
+
+//------------------------------------------------------------------------------
+
+#[inline(always)]
+pub fn rotRight32By1(cin: bool, x: u32) -> (u32, bool) {
+  let cout : bool = (x & 1) != 0;
+  let y    : u32  = (x >> 1) | boolToMSB32(cin);
+  (y, cout)
+} 
+
+#[inline(always)]
+pub fn rotLeft32By1(x: u32, cin: bool) -> (bool, u32) {
+  let cout : bool = (x & 0x_8000_0000) != 0;
+  let y    : u32  = (x << 1) | boolToU32(cin);
+  (cout, y)
+} 
+
+//------------------------------------------------------------------------------
+// 64 bit versions
+
+const U64_MASK: u128 = 0x_FFFF_FFFF_FFFF_FFFF;
+
+#[inline(always)]
+pub fn boolToU64(c: bool) -> u64 {
+  if c { 1 } else { 0 }
+}
+
+#[inline(always)]
+pub fn addCarry64_(x: u64, y: u64) -> (u64,bool) {
+  let z: u64  = u64::wrapping_add(x, y);
+  let c: bool = z < x;
+  (z, c) 
+}
+
+#[inline(always)]
+pub fn subBorrow64_(x: u64, y: u64) -> (u64,bool) {
+  let z: u64  = u64::wrapping_sub(x, y);
+  let c: bool = z > x;
+  (z, c) 
+}
+
+#[inline(always)]
+pub fn addCarry64(x :u64, y: u64, cin: bool) -> (u64,bool) {
+  let z: u64  = u64::wrapping_add(u64::wrapping_add(x, y), boolToU64(cin));
+  let c: bool = if cin { z <= x } else { z < x };
+  (z, c)
+}
+
+#[inline(always)]
+pub fn subBorrow64(x: u64, y: u64, cin: bool) -> (u64,bool) {
+  let z: u64  = u64::wrapping_sub(u64::wrapping_sub(x, y), boolToU64(cin));
+  let c: bool = if cin { z >= x } else { z > x };
+  (z, c) 
+}
+
+#[inline(always)]
+pub fn mulTrunc64(x: u64, y: u64) -> u64 {
+  u64::wrapping_mul(x,y)
+}
+
+#[inline(always)]
+pub fn mulExt64(x: u64, y: u64) -> (u64,u64) {
+  let z: u128 = (x as u128) * (y as u128);
+  let hi = (z >> 64)      as u64;
+  let lo = (z & U64_MASK) as u64;
+  (lo, hi)
+}
+
+#[inline(always)]
+pub fn mulAdd64(x: u64, y: u64, a: u64) -> (u64,u64) {
+  let z: u128 = (x as u128) * (y as u128) + (a as u128);
+  let hi = (z >> 64)      as u64;
+  let lo = (z & U64_MASK) as u64;
+  (lo, hi)
+}
+
+#[inline(always)]
+pub fn mulAddAdd64(x: u64, y: u64, a: u64, b: u64) -> (u64,u64) {
+  let z: u128 = (x as u128) * (y as u128) + (a as u128) + (b as u128);
+  let hi = (z >> 64)      as u64;
+  let lo = (z & U64_MASK) as u64;
+  (lo, hi)
+}
+
+#[inline(always)]
+pub fn rotRight64By1(cin: bool, x: u64) -> (u64, bool) {
+  let cout : bool = (x & 1) != 0;
+  let y    : u64  = (x >> 1) | (boolToU64(cin) << 63);
+  (y, cout)
+} 
+
+#[inline(always)]
+pub fn rotLeft64By1(x: u64, cin: bool) -> (bool, u64) {
+  let cout : bool = (x & 0x_8000_0000_0000_0000) != 0;
+  let y    : u64  = (x << 1) | boolToU64(cin);
+  (cout, y)
+} 
+
 //------------------------------------------------------------------------------
 
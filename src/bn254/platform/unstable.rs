@@ -1,5 +1,6 @@
 
-// "unstable" (in rust parlance) version
+// "unstable" (in rust parlance) version, using the `bigint_helper_methods`
+// intrinsics (`carrying_add`, `carrying_mul` etc); enabled by the `unstable` feature
 
 #![allow(dead_code)]
 #![allow(non_snake_case)]
@@ -45,7 +46,7 @@ pub fn mulTrunc32(x: u32, y: u32) -> u32 {
 
 #[inline(always)]
 pub fn mulExt32(x: u32, y: u32) -> (u32,u32) {
-  u32::widening_mul(x,y)
+  u32::carrying_mul(x,y,0)
 }
 
 #[inline(always)]
@@ -96,3 +97,70 @@ pub fn rotLeft32By1(x: u32, cin: bool) -> (bool, u32) {
 } 
 
 //------------------------------------------------------------------------------
+// 64 bit versions
+
+#[inline(always)]
+pub fn boolToU64(c: bool) -> u64 {
+  if c { 1 } else { 0 }
+}
+
+#[inline(always)]
+pub fn addCarry64_(x: u64, y: u64) -> (u64,bool) {
+  u64::overflowing_add(x,y)
+}
+
+#[inline(always)]
+pub fn subBorrow64_(x: u64, y: u64) -> (u64,bool) {
+  u64::overflowing_sub(x,y)
+}
+
+#[inline(always)]
+pub fn addCarry64(x: u64, y: u64, cin: bool) -> (u64,bool) {
+  u64::carrying_add(x,y,cin)
+}
+
+#[inline(always)]
+pub fn subBorrow64(x: u64, y: u64, cin: bool) -> (u64,bool) {
+  u64::borrowing_sub(x,y,cin)
+}
+
+#[inline(always)]
+pub fn mulTrunc64(x: u64, y: u64) -> u64 {
+  u64::wrapping_mul(x,y)
+}
+
+#[inline(always)]
+pub fn mulExt64(x: u64, y: u64) -> (u64,u64) {
+  u64::carrying_mul(x,y,0)
+}
+
+#[inline(always)]
+pub fn mulAdd64(x: u64, y: u64, a: u64) -> (u64,u64) {
+  u64::carrying_mul(x,y,a)
+}
+
+#[inline(always)]
+pub fn mulAddAdd64(x: u64, y: u64, a: u64, b: u64) -> (u64,u64) {
+  u64::carrying_mul_add(x,y,a,b)
+}
+
+#[inline(always)]
+pub fn rotRight64By1(cin: bool, x: u64) -> (u64, bool) {
+  unsafe {
+    let cout : bool = (x & 1) != 0;
+    let y    : u64  = u64::unchecked_shr(x,1) | u64::unchecked_shl(boolToU64(cin),63);
+    (y, cout)
+  }
+} 
+
+#[inline(always)]
+pub fn rotLeft64By1(x: u64, cin: bool) -> (bool, u64) {
+  unsafe {
+    let cout : bool = (x & 0x_8000_0000_0000_0000) != 0;
+    let y    : u64  = u64::unchecked_shl(x,1) | boolToU64(cin);
+    (cout, y)
+  }
+} 
+
+//------------------------------------------------------------------------------
+
@@ -0,0 +1,58 @@
+
+//
+// minimal random number interface, so that we depend neither on the 
+// unstable `std::random` nor on external crates
+//
+
+#![allow(dead_code)]
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher,Hasher};
+
+//------------------------------------------------------------------------------
+
+// anything which can fill a buffer with random bytes
+// (this mirrors the interface of `std::random::RandomSource`)
+pub trait RandomSource {
+  fn fill_bytes(&mut self, bytes: &mut [u8]);
+}
+
+//------------------------------------------------------------------------------
+
+// A simple random source, seeded by the OS (via the random keys of the standard 
+// library's `RandomState`), producing the output by hashing a counter with SipHash. 
+//
+// Note: this is good enough for testing, but NOT intended for cryptographic use;
+// for that, implement `RandomSource` on top of a proper CSPRNG.
+pub struct SipRandomSource {
+  state   : RandomState,
+  counter : u64,
+}
+
+impl SipRandomSource {
+  pub fn new() -> SipRandomSource {
+    SipRandomSource { state: RandomState::new(), counter: 0 }
+  }
+
+  pub fn next_u64(&mut self) -> u64 {
+    let mut hasher = self.state.build_hasher();
+    hasher.write_u64(self.counter);
+    self.counter += 1;
+    hasher.finish()
+  }
+}
+
+impl Default for SipRandomSource {
+  fn default() -> Self { SipRandomSource::new() }
+}
+
+impl RandomSource for SipRandomSource {
+  fn fill_bytes(&mut self, bytes: &mut [u8]) {
+    for chunk in bytes.chunks_mut(8) {
+      let w: [u8; 8] = self.next_u64().to_le_bytes();
+      chunk.copy_from_slice(&w[0..chunk.len()]);
+    }
+  }
+}
+
+//------------------------------------------------------------------------------
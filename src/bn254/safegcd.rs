@@ -0,0 +1,266 @@
+
+//
+// modular inversion using the Bernstein-Yang "safegcd" algorithm
+//
+// this is a port of the variable-time version of `modinv64` from libsecp256k1,
+// see <https://github.com/bitcoin-core/secp256k1/blob/master/doc/safegcd_implementation.md>
+// and the paper "Fast constant-time gcd computation and modular inversion"
+// by Daniel J. Bernstein and Bo-Yin Yang <https://eprint.iacr.org/2019/266>
+//
+// numbers are represented as 5 signed limbs of 62 bits each; this is independent
+// of the limb size used by the rest of the library (we need `i128` products)
+//
+
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+
+// the limb loops are written with explicit indices, mirroring the math
+#![allow(clippy::needless_range_loop)]
+
+use crate::bn254::bigint::*;
+
+//------------------------------------------------------------------------------
+
+// NOTE: the prime is hard-wired into this
+
+const M62: u64 = u64::MAX >> 2;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Signed62([i64; 5]);
+
+// the field prime in signed62 representation
+const MODULUS: Signed62 = Signed62( [ 0x03e1f593f0000001 , 0x20cfa121e6e5c245 , 0x05045b68181585d2 , 0x19139cb84c680a6e , 0x30 ] );
+
+// `p^-1 mod 2^62`
+const MODULUS_INV62: u64 = 0x3d1e0a6c10000001;
+
+// the 2x2 transition matrix `[[u,v],[q,r]]` of 62 divsteps, scaled by `2^62`
+#[derive(Debug, Copy, Clone)]
+struct Trans2x2 {
+  u: i64,
+  v: i64,
+  q: i64,
+  r: i64,
+}
+
+//------------------------------------------------------------------------------
+// conversion to/from the signed62 representation
+
+fn to_signed62(big: BigInt256) -> Signed62 {
+  let ws: [u32; 8] = BigInt::to_words32(big);
+  let mut a: [u64; 4] = [0; 4];
+  for i in 0..4 { a[i] = (ws[2*i] as u64) | ((ws[2*i+1] as u64) << 32); }
+  Signed62(
+    [ (  a[0]                        & M62) as i64
+    , (((a[0] >> 62) | (a[1] <<  2)) & M62) as i64
+    , (((a[1] >> 60) | (a[2] <<  4)) & M62) as i64
+    , (((a[2] >> 58) | (a[3] <<  6)) & M62) as i64
+    ,  ( a[3] >> 56)                        as i64
+    ] )
+}
+
+// assumes the input is normalized, that is, all limbs are in `[0,2^62)`
+fn from_signed62(x: Signed62) -> BigInt256 {
+  let v: [u64; 5] = x.0.map( |y| y as u64 );
+  let a: [u64; 4] =
+    [ v[0]        | (v[1] << 62)
+    , (v[1] >> 2) | (v[2] << 60)
+    , (v[2] >> 4) | (v[3] << 58)
+    , (v[3] >> 6) | (v[4] << 56)
+    ];
+  let mut ws: [u32; 8] = [0; 8];
+  for i in 0..4 {
+    ws[2*i  ] =  a[i]        as u32;
+    ws[2*i+1] = (a[i] >> 32) as u32;
+  }
+  BigInt::from_words32(ws)
+}
+
+//------------------------------------------------------------------------------
+
+// compute the transition matrix and the new `eta` for 62 divsteps,
+// starting from the bottom 62 bits of `f` and `g` (variable time)
+fn divsteps_62_var(eta0: i64, f0: u64, g0: u64) -> (i64, Trans2x2) {
+
+  let mut u: u64 = 1;
+  let mut v: u64 = 0;
+  let mut q: u64 = 0;
+  let mut r: u64 = 1;
+  let mut f: u64 = f0;
+  let mut g: u64 = g0;
+  let mut eta: i64 = eta0;
+  let mut i: u32 = 62;
+
+  loop {
+    // skip over the zero bits of `g` (but not more than what's left)
+    let zeros: u32 = (g | (u64::MAX << i)).trailing_zeros();
+    g >>= zeros;
+    u <<= zeros;
+    v <<= zeros;
+    eta -= zeros as i64;
+    i   -= zeros;
+    if i == 0 { break; }
+
+    // now `g` is odd
+    let w: u64;
+    if eta < 0 {
+      eta = -eta;
+      let tmp = f; f = g; g = tmp.wrapping_neg();
+      let tmp = u; u = q; q = tmp.wrapping_neg();
+      let tmp = v; v = r; r = tmp.wrapping_neg();
+      // eliminate up to 6 bits at once
+      let limit: u32 = std::cmp::min( (eta as u32) + 1 , i );
+      let m: u64 = (u64::MAX >> (64 - limit)) & 63;
+      w = f.wrapping_mul(g).wrapping_mul( f.wrapping_mul(f).wrapping_sub(2) ) & m;
+    }
+    else {
+      // eliminate up to 4 bits at once
+      let limit: u32 = std::cmp::min( (eta as u32) + 1 , i );
+      let m: u64 = (u64::MAX >> (64 - limit)) & 15;
+      let w1: u64 = f.wrapping_add( (f.wrapping_add(1) & 4) << 1 );
+      w = w1.wrapping_neg().wrapping_mul(g) & m;
+    }
+    g = g.wrapping_add( f.wrapping_mul(w) );
+    q = q.wrapping_add( u.wrapping_mul(w) );
+    r = r.wrapping_add( v.wrapping_mul(w) );
+  }
+
+  ( eta , Trans2x2 { u: u as i64, v: v as i64, q: q as i64, r: r as i64 } )
+}
+
+//------------------------------------------------------------------------------
+
+#[inline(always)]
+fn mul_wide(x: i64, y: i64) -> i128 {
+  (x as i128) * (y as i128)
+}
+
+// compute `(t * [d,e]) / 2^62 mod p`, keeping `d,e` in the range `(-2p,p)`
+fn update_de_62(d: &mut Signed62, e: &mut Signed62, t: &Trans2x2) {
+
+  let Trans2x2 { u, v, q, r } = *t;
+  let d0 = d.0;
+  let e0 = e.0;
+
+  // `[md,me]` start as zero; plus `[u,q]` if `d` is negative; plus `[v,r]` if `e` is negative
+  let sd: i64 = d0[4] >> 63;
+  let se: i64 = e0[4] >> 63;
+  let mut md: i64 = (u & sd) + (v & se);
+  let mut me: i64 = (q & sd) + (r & se);
+
+  let mut cd: i128 = mul_wide(u, d0[0]) + mul_wide(v, e0[0]);
+  let mut ce: i128 = mul_wide(q, d0[0]) + mul_wide(r, e0[0]);
+
+  // correct `md,me` so that `t*[d,e] + p*[md,me]` has 62 zero bottom bits
+  md -= ( MODULUS_INV62.wrapping_mul(cd as u64).wrapping_add(md as u64) & M62 ) as i64;
+  me -= ( MODULUS_INV62.wrapping_mul(ce as u64).wrapping_add(me as u64) & M62 ) as i64;
+
+  cd += mul_wide(MODULUS.0[0], md);
+  ce += mul_wide(MODULUS.0[0], me);
+  debug_assert!( (cd as u64) & M62 == 0 );
+  debug_assert!( (ce as u64) & M62 == 0 );
+  cd >>= 62;
+  ce >>= 62;
+
+  for i in 1..5 {
+    cd += mul_wide(u, d0[i]) + mul_wide(v, e0[i]) + mul_wide(MODULUS.0[i], md);
+    ce += mul_wide(q, d0[i]) + mul_wide(r, e0[i]) + mul_wide(MODULUS.0[i], me);
+    d.0[i-1] = ((cd as u64) & M62) as i64;  cd >>= 62;
+    e.0[i-1] = ((ce as u64) & M62) as i64;  ce >>= 62;
+  }
+
+  d.0[4] = cd as i64;
+  e.0[4] = ce as i64;
+}
+
+// compute `(t * [f,g]) / 2^62`, where only the first `len` limbs are used
+fn update_fg_62_var(len: usize, f: &mut Signed62, g: &mut Signed62, t: &Trans2x2) {
+
+  let Trans2x2 { u, v, q, r } = *t;
+
+  let mut cf: i128 = mul_wide(u, f.0[0]) + mul_wide(v, g.0[0]);
+  let mut cg: i128 = mul_wide(q, f.0[0]) + mul_wide(r, g.0[0]);
+  debug_assert!( (cf as u64) & M62 == 0 );
+  debug_assert!( (cg as u64) & M62 == 0 );
+  cf >>= 62;
+  cg >>= 62;
+
+  for i in 1..len {
+    let fi = f.0[i];
+    let gi = g.0[i];
+    cf += mul_wide(u, fi) + mul_wide(v, gi);
+    cg += mul_wide(q, fi) + mul_wide(r, gi);
+    f.0[i-1] = ((cf as u64) & M62) as i64;  cf >>= 62;
+    g.0[i-1] = ((cg as u64) & M62) as i64;  cg >>= 62;
+  }
+
+  f.0[len-1] = cf as i64;
+  g.0[len-1] = cg as i64;
+}
+
+// bring `x` from the range `(-2p,p)` to `[0,p)`, negating it if `sign` is negative
+fn normalize_62(x: &mut Signed62, sign: i64) {
+
+  let M: i64 = M62 as i64;
+  let mut r: [i64; 5] = x.0;
+
+  // add the modulus if the input is negative, and then negate if requested
+  let cond_add: i64 = r[4] >> 63;
+  for i in 0..5 { r[i] += MODULUS.0[i] & cond_add; }
+  let cond_negate: i64 = sign >> 63;
+  for i in 0..5 { r[i] = (r[i] ^ cond_negate) - cond_negate; }
+  for i in 0..4 { r[i+1] += r[i] >> 62; r[i] &= M; }
+
+  // add the modulus again if the result is still negative
+  let cond_add: i64 = r[4] >> 63;
+  for i in 0..5 { r[i] += MODULUS.0[i] & cond_add; }
+  for i in 0..4 { r[i+1] += r[i] >> 62; r[i] &= M; }
+
+  x.0 = r;
+}
+
+//------------------------------------------------------------------------------
+
+// computes `x^-1 mod p` (variable time).
+// The input must be in the range `[1..p-1]`; for zero, the output is garbage
+pub fn modinv_var(x: BigInt256) -> BigInt256 {
+
+  let mut d: Signed62 = Signed62([0; 5]);
+  let mut e: Signed62 = Signed62([1,0,0,0,0]);
+  let mut f: Signed62 = MODULUS;
+  let mut g: Signed62 = to_signed62(x);
+  let mut len: usize = 5;
+  let mut eta: i64 = -1;      // eta = -delta; delta is initially 1
+
+  loop {
+    let (eta1, t) = divsteps_62_var(eta, f.0[0] as u64, g.0[0] as u64);
+    eta = eta1;
+    update_de_62(&mut d, &mut e, &t);
+    update_fg_62_var(len, &mut f, &mut g, &t);
+
+    // we are done when `g` is zero
+    if g.0[0] == 0 {
+      let mut cond: i64 = 0;
+      for j in 1..len { cond |= g.0[j]; }
+      if cond == 0 { break; }
+    }
+
+    // if the top limbs of both `f` and `g` are both 0 or -1, we can shorten them
+    let fn_: i64 = f.0[len-1];
+    let gn_: i64 = g.0[len-1];
+    let mut cond: i64 = ((len as i64) - 2) >> 63;
+    cond |= fn_ ^ (fn_ >> 63);
+    cond |= gn_ ^ (gn_ >> 63);
+    if cond == 0 {
+      f.0[len-2] |= ((fn_ as u64) << 62) as i64;
+      g.0[len-2] |= ((gn_ as u64) << 62) as i64;
+      len -= 1;
+    }
+  }
+
+  // now `f` is +1 or -1 (as the input was invertible)
+  normalize_62(&mut d, f.0[len-1]);
+  from_signed62(d)
+}
+
+//------------------------------------------------------------------------------
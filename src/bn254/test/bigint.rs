@@ -9,11 +9,14 @@ use quickcheck::{Arbitrary, Gen};
 use quickcheck_macros::quickcheck;
 
 use crate::bn254::traits::*;
+use crate::bn254::platform::{Limb,LIMB_BYTES,NLIMBS};
 use crate::bn254::bigint::*;
+use crate::bn254::error::*;
 use crate::bn254::constant::*;
+use crate::bn254::montgomery::*;
 use crate::bn254::test::properties::*;
 
-type Big  = BigInt<8>;
+type Big  = BigInt256;
 type Big7 = BigInt<7>;
 type Big9 = BigInt<9>;
 
@@ -36,36 +39,65 @@ fn one_is_one()      { assert!(  Big::is_one ( Big::one () ) ) }
 
 #[test]
 fn unit_to_decimal() {
-  let x: Big = Big::make( [ 0xff74e7f5 , 0x86ab86c2 , 0x7829f01b , 0x6dff3d9f , 0x7c6194d1 , 0x58fce839 , 0x1c3fc759 , 0x0ee7c7b9 ] );
+  let x: Big = Big::from_words32( [ 0xff74e7f5 , 0x86ab86c2 , 0x7829f01b , 0x6dff3d9f , 0x7c6194d1 , 0x58fce839 , 0x1c3fc759 , 0x0ee7c7b9 ] );
   assert_eq!( Big::to_decimal_string(x) , "6741899990217662167434591118162422674873486558668509109681649862022285027317" )
 }
 
 #[test]
 fn unit_to_hex() {
-  let x: Big = Big::make( [ 0x5d8aa877 , 0x40b543d5 , 0x115812cd , 0x0563e2bd , 0x26c9552a , 0x1890edd6 , 0x803b772b , 0x1a12005c ] );
+  let x: Big = Big::from_words32( [ 0x5d8aa877 , 0x40b543d5 , 0x115812cd , 0x0563e2bd , 0x26c9552a , 0x1890edd6 , 0x803b772b , 0x1a12005c ] );
   assert_eq!( Big::to_hex_string(x) , "0x1a12005c803b772b1890edd626c9552a0563e2bd115812cd40b543d55d8aa877" )
 }
 
+#[test]
+fn unit_parse() {
+  let x: Big = Big::from_words32( [ 0xff74e7f5 , 0x86ab86c2 , 0x7829f01b , 0x6dff3d9f , 0x7c6194d1 , 0x58fce839 , 0x1c3fc759 , 0x0ee7c7b9 ] );
+  assert_eq!( "6741899990217662167434591118162422674873486558668509109681649862022285027317".parse::<Big>() , Ok(x) );
+  assert_eq!( "0x0ee7c7b91c3fc75958fce8397c6194d16dff3d9f7829f01b86ab86c2ff74e7f5".parse::<Big>() , Ok(x) );
+}
+
+#[test]
+fn unit_parse_overflow() {
+  let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+  let big = "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+  assert_eq!( max.parse::<Big>()  , Ok(-Big::one()) );
+  assert_eq!( big.parse::<Big>()  , Err(FeltError::Overflow) );
+  assert_eq!( "0x1ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff".parse::<Big>() , Err(FeltError::Overflow) );
+}
+
+#[test]
+fn unit_try_read_bytes() {
+  assert_eq!( Big::try_read_le_bytes( &[0; 31] ) , Err(FeltError::InvalidLength) );
+  assert_eq!( Big::try_read_be_bytes( &[0; 33] ) , Err(FeltError::InvalidLength) );
+  assert_eq!( Big::try_read_be_bytes( &[0; 32] ) , Ok(Big::zero()) );
+}
+
 //------------------------------------------------------------------------------
 
-fn prop_from_to_bytes_le<const N: usize>(x: BigInt<N>) -> bool where [(); 4*N]: {
-  let bs: [u8; 4*N] = BigInt::to_le_bytes(x);
-  BigInt::<N>::from_le_bytes(bs) == x
+fn prop_from_to_bytes_le<const N: usize>(x: BigInt<N>) -> bool {
+  let mut bs: Vec<u8> = vec![0; LIMB_BYTES*N];
+  BigInt::write_le_bytes(x, &mut bs);
+  BigInt::<N>::read_le_bytes(&bs) == x
 }
 
-fn prop_to_from_bytes_le<const N: usize>(bs: [u8; 4*N]) -> bool {
-  let y: BigInt<N> = BigInt::from_le_bytes(bs);
-  BigInt::<N>::to_le_bytes(y) == bs
+fn prop_to_from_bytes_le<const N: usize>(bs: &[u8]) -> bool {
+  let y: BigInt<N> = BigInt::read_le_bytes(bs);
+  let mut cs: Vec<u8> = vec![0; LIMB_BYTES*N];
+  BigInt::<N>::write_le_bytes(y, &mut cs);
+  cs == bs
 }
 
-fn prop_from_to_bytes_be<const N: usize>(x: BigInt<N>) -> bool where [(); 4*N]: {
-  let bs: [u8; 4*N] = BigInt::to_be_bytes(x);
-  BigInt::<N>::from_be_bytes(bs) == x
+fn prop_from_to_bytes_be<const N: usize>(x: BigInt<N>) -> bool {
+  let mut bs: Vec<u8> = vec![0; LIMB_BYTES*N];
+  BigInt::write_be_bytes(x, &mut bs);
+  BigInt::<N>::read_be_bytes(&bs) == x
 }
 
-fn prop_to_from_bytes_be<const N: usize>(bs: [u8; 4*N]) -> bool {
-  let y: BigInt<N> = BigInt::from_be_bytes(bs);
-  BigInt::<N>::to_be_bytes(y) == bs
+fn prop_to_from_bytes_be<const N: usize>(bs: &[u8]) -> bool {
+  let y: BigInt<N> = BigInt::read_be_bytes(bs);
+  let mut cs: Vec<u8> = vec![0; LIMB_BYTES*N];
+  BigInt::<N>::write_be_bytes(y, &mut cs);
+  cs == bs
 }
 
 //------------------------------------------------------------------------------
@@ -79,21 +111,13 @@ struct ByteArray<const K: usize>([u8; K]);
 
 impl<const K: usize> Arbitrary for ByteArray<K> {
   fn arbitrary(g: &mut Gen) -> ByteArray<K> {
-    let mut bs: [u8; K] = [0; K];
-    for i in 0..K {
-      bs[i] = u8::arbitrary(g);
-    }
-    ByteArray(bs)
+    ByteArray( core::array::from_fn( |_| u8::arbitrary(g) ) )
   }
 }
 
 impl<const N: usize> Arbitrary for BigInt<N> {
   fn arbitrary(g: &mut Gen) -> BigInt<N> {
-    let mut xs: [u32; N] = [0; N];
-    for i in 0..N {
-      xs[i] = u32::arbitrary(g);
-    }
-    BigInt::make(xs)
+    BigInt::make( core::array::from_fn( |_| Limb::arbitrary(g) ) )
   }
 }
 
@@ -106,10 +130,16 @@ fn from_to_bytes_le(x: Big7) -> bool { prop_from_to_bytes_le::<7>(x) }
 fn from_to_bytes_be(x: Big7) -> bool { prop_from_to_bytes_be::<7>(x) }
 
 #[quickcheck]
-fn to_fom_bytes_le(bs: ByteArray<{7*4}>) -> bool { prop_to_from_bytes_le::<7>(bs.0) }
+fn to_fom_bytes_le(bs: ByteArray<{7*LIMB_BYTES}>) -> bool { prop_to_from_bytes_le::<7>(&bs.0) }
+
+#[quickcheck]
+fn to_fom_bytes_be (bs: ByteArray<{7*LIMB_BYTES}>) -> bool { prop_to_from_bytes_be::<7>(&bs.0) }
 
 #[quickcheck]
-fn to_fom_bytes_be (bs: ByteArray<{7*4}>) -> bool { prop_to_from_bytes_be::<7>(bs.0) }
+fn parse_decimal(x: Big) -> bool { Big::to_decimal_string(x).parse::<Big>() == Ok(x) }
+
+#[quickcheck]
+fn parse_hex(x: Big9) -> bool { Big9::to_hex_string(x).parse::<Big9>() == Ok(x) }
 
 //--------------------------------------
 
@@ -151,6 +181,21 @@ fn sub_add_neg(x: Big, y: Big) -> bool { prop_sub_add_neg(x,y) }
 
 //------------------------------------------------------------------------------
 
+#[quickcheck]
+fn sqr_vs_multiply(x: Big) -> bool {
+  BigInt::sqr(x) == BigInt::multiply::<NLIMBS,{2*NLIMBS}>(x,x)
+}
+
+#[test]
+fn unit_sqr() {
+  let m: Big = -Big::one();       // (2^256-1)^2 = 2^512 - 2^257 + 1
+  assert_eq!( BigInt::sqr(m) , BigInt::sqr_naive(m) );
+  assert_eq!( BigInt::sqr(Big::zero()) , BigInt::zero() );
+  assert_eq!( BigInt::sqr(Big::one())  , BigInt::one()  );
+}
+
+//------------------------------------------------------------------------------
+
 #[quickcheck]
 fn add_halfp(x: Big) -> bool {
   let a = BigInt::add_half_prime_plus_1(x);
@@ -159,5 +204,85 @@ fn add_halfp(x: Big) -> bool {
 }
 
 //------------------------------------------------------------------------------
+// constant time versions agree with the variable time ones
 
+fn vt_subtract_prime_if_necessary(x: Big) -> Big {
+  if BigInt::is_lt_prime(x) { x } else { BigInt::subtract_prime(x).0 }
+}
+
+#[quickcheck]
+fn ct_subtract_prime_if_necessary(x: Big) -> bool {
+  BigInt::ct_subtract_prime_if_necessary(x) == vt_subtract_prime_if_necessary(x)
+}
+
+#[quickcheck]
+fn ct_sub_mod_prime(x: Big, y: Big) -> bool {
+  let a = vt_subtract_prime_if_necessary(vt_subtract_prime_if_necessary(x));
+  let b = vt_subtract_prime_if_necessary(vt_subtract_prime_if_necessary(y));
+  let (d, borrow) = BigInt::subBorrow(a, b);
+  let expected = if borrow { BigInt::add_prime(d).0 } else { d };
+  BigInt::ct_sub_mod_prime(a, b) == expected
+}
 
+#[quickcheck]
+fn ct_div_by_2_mod_prime(x: Big) -> bool {
+  let a = vt_subtract_prime_if_necessary(vt_subtract_prime_if_necessary(x));
+  let (half, carry) = BigInt::shiftRightBy1(a);
+  let expected = if carry { BigInt::add_half_prime_plus_1(half) } else { half };
+  BigInt::ct_div_by_2_mod_prime(a) == expected
+}
+
+#[quickcheck]
+fn conditional_select(x: Big9, y: Big9) -> bool {
+  BigInt::conditional_select(x, y, false) == x && BigInt::conditional_select(x, y, true) == y
+}
+
+#[quickcheck]
+fn ct_eq(x: Big, y: Big) -> bool {
+  BigInt::ct_eq(x, y) == (x == y) && BigInt::ct_eq(x, x)
+}
+
+#[test]
+fn unit_zeroize() {
+  let mut x: Big = -Big::one();
+  x.zeroize();
+  assert!( BigInt::ct_is_zero(x) );
+}
+
+//------------------------------------------------------------------------------
+// lazy reduction
+
+#[quickcheck]
+fn mul_acc(acc: BigInt512, x: BigInt256, y: BigInt256) -> bool {
+  BigInt::mulAcc(acc, x, y) == BigInt::add( acc , BigInt::mul(x, y) )
+}
+
+#[quickcheck]
+fn add_shifted(x: BigInt256, y: BigInt256, z: BigInt256) -> bool {
+  BigInt::addShifted( BigInt::mul(x, y) , z ) == BigInt::mulAddShifted(x, y, z)
+}
+
+#[quickcheck]
+fn reduce_mod_prime(x: BigInt256) -> bool {
+  // `reduce_big` goes through a Montgomery multiplication instead
+  let y = BigInt::reduce_mod_prime(x);
+  BigInt::is_lt_prime(y) && y == Mont::reduce_big(x)
+}
+
+#[test]
+fn unit_reduce_mod_prime() {
+  let max: BigInt256 = BigInt::from_words32( [ 0xffffffff ; 8 ] );
+  assert_eq!( BigInt::reduce_mod_prime(max) , Mont::reduce_big(max) );
+  for k in 0..6 {
+    let kp = BigInt::sub( BigInt::scale( k , FIELD_PRIME ).0 , BigInt::one() );
+    if k > 0 { assert_eq!( BigInt::reduce_mod_prime(kp) , BigInt::sub( FIELD_PRIME , BigInt::one() ) ); }
+    assert!( BigInt::is_zero( BigInt::reduce_mod_prime( BigInt::scale( k , FIELD_PRIME ).0 ) ) );
+  }
+  // the quotient estimate is `floor(i * 2^253 / p)`
+  for i in 0..8 {
+    let x  = BigInt::from_words32( [ 0 , 0 , 0 , 0 , 0 , 0 , 0 , i << 29 ] );
+    let q  = (Limb::from(i) * PRIME_QUOTIENT_MUL) >> 8;
+    assert!( BigInt::scale( q   , FIELD_PRIME ).0 <= x );
+    assert!( BigInt::scale( q+1 , FIELD_PRIME ).0 >  x );
+  }
+}
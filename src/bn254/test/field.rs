@@ -10,8 +10,12 @@ use quickcheck_macros::quickcheck;
 
 use crate::bn254::traits::*;
 use crate::bn254::constant::*;
+use crate::bn254::platform::{Limb,NLIMBS};
 use crate::bn254::bigint::*;
 use crate::bn254::field::*;
+use crate::bn254::montgomery::*;
+use crate::bn254::unsaturated::*;
+use crate::bn254::error::*;
 use crate::bn254::test::properties::*;
 
 //------------------------------------------------------------------------------
@@ -42,12 +46,9 @@ struct Masked(BigInt256);
 
 impl Arbitrary for Masked {
   fn arbitrary(g: &mut Gen) -> Masked {
-    let mut xs: [u32; 8] = [0; 8];
-    for i in 0..8 {
-      xs[i] = u32::arbitrary(g);
-    }
-    xs[7] = xs[7] & 0x_3FFF_FFFF;
-    Masked(BigInt::make(xs))
+    let mut xs: [u32; 8] = core::array::from_fn( |_| u32::arbitrary(g) );
+    xs[7] &= 0x_3FFF_FFFF;
+    Masked(BigInt::from_words32(xs))
   }
 }
 
@@ -75,6 +76,122 @@ fn from_to_bytes_be(x: Felt) -> bool {
   let bs: [u8; 32] = Felt::to_be_bytes(x);
   Felt::unsafe_from_be_bytes(bs) == x
 }
+
+#[quickcheck]
+fn checked_from_to_bytes_le(x: Felt) -> bool {
+  Felt::try_from( Felt::to_le_bytes(x) ) == Ok(x)
+}
+
+#[quickcheck]
+fn checked_from_to_bytes_be(x: Felt) -> bool {
+  Felt::try_from_be_bytes( Felt::to_be_bytes(x) ) == Ok(x)
+}
+
+#[quickcheck]
+fn parse_decimal(x: Felt) -> bool {
+  Felt::to_decimal_string(x).parse::<Felt>() == Ok(x)
+}
+
+#[quickcheck]
+fn parse_hex(x: Felt) -> bool {
+  Felt::to_hex_string(x).parse::<Felt>() == Ok(x)
+}
+
+//--------------------------------------
+
+#[test]
+fn checked_rejects_out_of_range() {
+  let p    = BigInt::to_le_bytes( FIELD_PRIME );
+  let ones = [0xff; 32];
+  assert_eq!( Felt::try_from(p)                           , Err(FeltError::NotInRange) );
+  assert_eq!( Felt::try_from(ones)                        , Err(FeltError::NotInRange) );
+  assert_eq!( Felt::try_from_be_bytes( BigInt::to_be_bytes(FIELD_PRIME) ) , Err(FeltError::NotInRange) );
+  assert_eq!( Felt::try_make( BigInt::to_words32(FIELD_PRIME) ) , Err(FeltError::NotInRange) );
+  assert_eq!( Felt::try_from( &p[0..31] )                 , Err(FeltError::InvalidLength) );
+}
+
+#[test]
+fn parse_errors() {
+  let p = "21888242871839275222246405745257275088548364400416034343698204186575808495617";
+  assert_eq!( p.parse::<Felt>() , Err(FeltError::NotInRange) );
+  assert_eq!( "".parse::<Felt>()     , Err(FeltError::EmptyString)  );
+  assert_eq!( "0x".parse::<Felt>()   , Err(FeltError::EmptyString)  );
+  assert_eq!( "12a".parse::<Felt>()  , Err(FeltError::InvalidDigit) );
+  assert_eq!( "-1".parse::<Felt>()   , Err(FeltError::InvalidDigit) );
+  assert_eq!( "0x1g".parse::<Felt>() , Err(FeltError::InvalidDigit) );
+  assert_eq!( "0x10000000000000000000000000000000000000000000000000000000000000000".parse::<Felt>() , Err(FeltError::Overflow) );
+}
+
+#[test]
+fn parse_small() {
+  assert_eq!( "0".parse::<Felt>()    , Ok(Felt::zero())          );
+  assert_eq!( "0x0".parse::<Felt>()  , Ok(Felt::zero())          );
+  assert_eq!( "123".parse::<Felt>()  , Ok(Felt::from_u32(123))   );
+  assert_eq!( "0xff".parse::<Felt>() , Ok(Felt::from_u32(255))   );
+  assert_eq!( "0XFF".parse::<Felt>() , Ok(Felt::from_u32(255))   );
+  let pm1 = "21888242871839275222246405745257275088548364400416034343698204186575808495616";
+  assert_eq!( pm1.parse::<Felt>() , Ok(-Felt::one()) );
+}
+
+//--------------------------------------
+// reduction modulo p
+
+#[derive(Debug, Copy, Clone)]
+struct Bytes64([u8; 64]);
+
+impl Arbitrary for Bytes64 {
+  fn arbitrary(g: &mut Gen) -> Bytes64 {
+    let bs: [u8; 64] = core::array::from_fn( |_| u8::arbitrary(g) );
+    Bytes64(bs)
+  }
+}
+
+// `2^256 mod p`
+fn two_to_256() -> Felt {
+  Felt::unsafe_from_bigint(BIG_R1)
+}
+
+#[quickcheck]
+fn mod_order_in_range(x: Felt) -> bool {
+  Felt::from_bytes_mod_order( Felt::to_le_bytes(x) ) == x &&
+  Felt::from_be_bytes_mod_order( Felt::to_be_bytes(x) ) == x
+}
+
+#[quickcheck]
+fn mod_order_plus_prime(x: Felt) -> bool {
+  let y: BigInt256 = Felt::to_bigint(x) + FIELD_PRIME;
+  Felt::from_bigint_mod_order(y) == x
+}
+
+#[quickcheck]
+fn mod_order_mont(bs: Bytes64) -> bool {
+  let mut lo: [u8; 32] = [0; 32];
+  lo.copy_from_slice( &bs.0[0..32] );
+  Felt::to_mont( Felt::from_bytes_mod_order(lo) ) == Mont::from_bytes_mod_order(lo) 
+}
+
+#[quickcheck]
+fn uniform_bytes_split(bs: Bytes64) -> bool {
+  let mut lo: [u8; 32] = [0; 32];
+  let mut hi: [u8; 32] = [0; 32];
+  lo.copy_from_slice( &bs.0[ 0..32] );
+  hi.copy_from_slice( &bs.0[32..64] );
+  let expected = Felt::from_bytes_mod_order(lo) + Felt::from_bytes_mod_order(hi) * two_to_256();
+  Felt::from_uniform_bytes(bs.0) == expected &&
+  Felt::to_mont(expected) == Mont::from_uniform_bytes(bs.0)
+}
+
+#[test]
+fn unit_mod_order() {
+  let a = "6350874878119819312338956282401532410528162663560392320966563075034087161850";
+  let b = "944936681149208446651664254269745548490766851729442924617792859073125903782";
+  let c = "12013539567687322724563591696141680761088723402739581838264091936971283177716";
+  let cs: [u8; 64] = core::array::from_fn( |i| i as u8 );
+  assert_eq!( Ok(Felt::from_bytes_mod_order( [0xff; 32] )) , a.parse::<Felt>() );
+  assert_eq!( Ok(Felt::from_uniform_bytes  ( [0xff; 64] )) , b.parse::<Felt>() );
+  assert_eq!( Ok(Felt::from_uniform_bytes  ( cs         )) , c.parse::<Felt>() );
+  assert_eq!( Felt::from_bytes_mod_order( BigInt::to_le_bytes(FIELD_PRIME) ) , Felt::zero() );
+}
 //--------------------------------------
 
 #[quickcheck]
@@ -136,6 +253,231 @@ fn halve_definition(x: Felt) -> bool {
 
 //------------------------------------------------------------------------------
 
+#[quickcheck]
+fn mont_sqr(x: Felt) -> bool {
+  let y = Felt::to_mont(x);
+  Mont::sqr(y) == Mont::mul(y,y) && Mont::sqr_sos(y) == Mont::sqr_naive(y) && Mont::sqr_cios(y) == Mont::mul_cios(y,y)
+}
+
+#[test]
+fn unit_sqr_cios() {
+  let m = Felt::to_mont(-Felt::one());
+  assert_eq!( Mont::sqr_cios(m) , Mont::one() );
+  let big = Mont::unsafe_from_le_bytes( BigInt::to_le_bytes( FIELD_PRIME - BigInt::one() ) );
+  assert_eq!( Mont::sqr_cios(big) , Mont::mul_sos(big,big) );
+  let top = Mont::unsafe_from_le_bytes( BigInt::to_le_bytes( BigInt::from_words32( [ 0xffffffff , 0 , 0xffffffff , 0xffffffff , 0x80000000 , 0xffffffff , 0xffffffff , 0x30000000 ] ) ) );
+  assert_eq!( Mont::sqr_cios(top) , Mont::mul_sos(top,top) );
+  assert_eq!( Mont::sqr_cios(Mont::zero()) , Mont::zero() );
+}
+
+#[quickcheck]
+fn mont_mul_cios_vs_sos(x: Felt, y: Felt) -> bool {
+  let a = Felt::to_mont(x);
+  let b = Felt::to_mont(y);
+  Mont::mul_cios(a,b) == Mont::mul_sos(a,b)
+}
+
+#[quickcheck]
+fn mont_mul_add(x: Felt, y: Felt, z: Felt) -> bool {
+  let a = Felt::to_mont(x);
+  let b = Felt::to_mont(y);
+  let c = Felt::to_mont(z);
+  Mont::mulAdd(a,b,c) == Mont::mul(a,b) + c
+}
+
+#[test]
+fn unit_mul_add_sos() {
+  // here the result of `redc` is still not less than `p`, so a second subtraction is needed
+  let a = Mont::unsafe_make( [ 0xe66a81dd , 0x2b7b2b0a , 0x36702717 , 0x6dbec5d1 , 0x1487d155 , 0x79c6668e , 0xa459942c , 0x28db19e4 ] );
+  let b = Mont::unsafe_make( [ 0x57c27572 , 0x11e78a63 , 0xbbf98f46 , 0x02f46094 , 0x47d3064e , 0x0347cad8 , 0x58ad616b , 0x295ab624 ] );
+  let c = Mont::unsafe_make( [ 0x61eb3f4a , 0x36149446 , 0x3e329f87 , 0xc50205dd , 0x10e9c386 , 0x3d86a530 , 0xa0dda6c7 , 0x2f89820b ] );
+  assert_eq!( Mont::mulAdd_sos(a,b,c) , Mont::mul_cios(a,b) + c );
+  assert!( Mont::is_valid( Mont::mulAdd_sos(a,b,c) ) );
+}
+
+#[test]
+fn unit_mul_cios() {
+  // the largest possible inputs
+  let m = Felt::to_mont(-Felt::one());
+  assert_eq!( Mont::mul_cios(m,m) , Mont::mul_sos(m,m) );
+  assert_eq!( Mont::mul_cios(m,m) , Mont::one() );
+  let big = Mont::unsafe_from_le_bytes( BigInt::to_le_bytes( FIELD_PRIME - BigInt::one() ) );
+  assert_eq!( Mont::mul_cios(big,big) , Mont::mul_sos(big,big) );
+}
+
+#[quickcheck]
+fn mont_mul_u29_vs_sos(x: Felt, y: Felt) -> bool {
+  let a = Felt::to_mont(x);
+  let b = Felt::to_mont(y);
+  Mont::mul_u29(a,b) == Mont::mul_sos(a,b)
+}
+
+#[quickcheck]
+fn mont_sqr_u29(x: Felt) -> bool {
+  let a = Felt::to_mont(x);
+  Mont::sqr_u29(a) == Mont::mul_u29(a,a)
+}
+
+#[quickcheck]
+fn unpack29_pack29(x: BigInt256) -> bool {
+  let limbs = unpack29(x);
+  limbs.iter().all( |&l| l < (1 << 29) ) && pack29(limbs) == x
+}
+
+#[test]
+fn unit_mul_u29() {
+  let m = Felt::to_mont(-Felt::one());
+  assert_eq!( Mont::mul_u29(m,m) , Mont::one() );
+  assert_eq!( Mont::sqr_u29(m)   , Mont::one() );
+  let big = Mont::unsafe_from_le_bytes( BigInt::to_le_bytes( FIELD_PRIME - BigInt::one() ) );
+  assert_eq!( Mont::mul_u29(big,big) , Mont::mul_sos(big,big) );
+  assert_eq!( Mont::sqr_u29(big)     , Mont::mul_sos(big,big) );
+  assert_eq!( Mont::mul_u29(Mont::zero(),big) , Mont::zero() );
+}
+
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+#[quickcheck]
+fn mont_mul_adx_vs_cios(x: Felt, y: Felt) -> bool {
+  let a = Felt::to_mont(x);
+  let b = Felt::to_mont(y);
+  // nothing to test on CPUs without BMI2/ADX
+  !crate::bn254::asm_x86_64::is_supported() || unsafe { Mont::mul_adx(a,b) } == Mont::mul_cios(a,b)
+}
+
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+#[test]
+fn unit_mul_adx() {
+  if !crate::bn254::asm_x86_64::is_supported() { return; }
+  let m = Felt::to_mont(-Felt::one());
+  assert_eq!( unsafe { Mont::mul_adx(m,m) } , Mont::one() );
+  let big = Mont::unsafe_from_le_bytes( BigInt::to_le_bytes( FIELD_PRIME - BigInt::one() ) );
+  assert_eq!( unsafe { Mont::mul_adx(big,big) } , Mont::mul_cios(big,big) );
+  assert_eq!( unsafe { Mont::mul_adx(Mont::zero(),big) } , Mont::zero() );
+}
+
+#[quickcheck]
+fn mont_acc_dot(xs: Vec<Felt>, ys: Vec<Felt>) -> bool {
+  let mut acc: MontAcc = MontAcc::zero();
+  let mut sum: Mont    = Mont::zero();
+  for (x,y) in xs.iter().zip(ys.iter()).take(22) {
+    let a = Felt::to_mont(*x);
+    let b = Felt::to_mont(*y);
+    acc = MontAcc::add_mul(acc, a, b);
+    sum = Mont::mulAdd(a, b, sum);
+  }
+  MontAcc::reduce(acc) == sum
+}
+
+#[quickcheck]
+fn mont_acc_mul_add(x: Felt, y: Felt, z: Felt, w: Felt) -> bool {
+  let a = Felt::to_mont(x);
+  let b = Felt::to_mont(y);
+  let c = Felt::to_mont(z);
+  let d = Felt::to_mont(w);
+  let acc = MontAcc::add( MontAcc::from_mul_add(a, b, c) , d );
+  MontAcc::reduce(acc) == a*b + c + d
+}
+
+#[test]
+fn unit_mont_acc() {
+  // the largest allowed inputs: 22 products, or 4 field elements
+  let m = Felt::to_mont(-Felt::one());
+  let mut acc: MontAcc = MontAcc::zero();
+  for _ in 0..22 { acc = MontAcc::add_mul(acc, m, m); }
+  assert_eq!( MontAcc::reduce(acc) , Felt::to_mont( Felt::from(22u32) ) );
+  let mut acc: MontAcc = MontAcc::from_mul(m, m);
+  for _ in 0..4 { acc = MontAcc::add(acc, m); }
+  assert_eq!( MontAcc::reduce(acc) , Felt::to_mont( Felt::from(1u32) - Felt::from(4u32) ) );
+}
+
+// the internal representation is Montgomery, so check the arithmetic against
+// plain bigint multiplication and reduction
+#[quickcheck]
+fn mul_vs_bigint(x: Felt, y: Felt) -> bool {
+  let ls: [Limb; 2*NLIMBS] = BigInt::to_limbs( BigInt::mul( Felt::to_bigint(x) , Felt::to_bigint(y) ) );
+  let lo: BigInt256 = BigInt::make( ls[0..NLIMBS].try_into().unwrap() );
+  let hi: BigInt256 = BigInt::make( ls[NLIMBS..].try_into().unwrap() );
+  Felt::to_bigint(x * y) == Mont::reduce_wide(lo,hi)
+}
+
+#[quickcheck]
+fn const_convert_vs_runtime(mx: Masked) -> bool {
+  let big = Mont::reduce_big(mx.0);
+  Mont::const_convert_from_big(big) == Mont::unsafe_convert_from_big(big)
+}
+
+#[test]
+fn unit_felt_repr() {
+  const TWO: Felt = Felt::unsafe_make( [ 2 , 0 , 0 , 0 , 0 , 0 , 0 , 0 ] );
+  const MINUS_TWO: Felt = Felt::unsafe_from_bigint( PRIME_MINUS_2 );
+  assert_eq!( TWO , Felt::from_u32(2) );
+  assert_eq!( MINUS_TWO , -Felt::from_u32(2) );
+  assert_eq!( Felt::to_bigint(TWO) , BigInt::from_u32(2) );
+  assert_eq!( Felt::to_mont(TWO) , Mont::from(2) );
+  assert_eq!( format!("{:?}", Felt::from_u32(7)) , format!("Felt({:?})", BigInt256::from_u32(7)) );
+}
+
+#[quickcheck]
+fn mul_small(x: Felt, k: u32) -> bool {
+  Felt::mul_small(x, k) == x * Felt::from(k)
+}
+
+#[quickcheck]
+fn mul_small_add(x: Felt, k: u32, y: Felt) -> bool {
+  Felt::mul_small_add(x, k, y) == x * Felt::from(k) + y
+}
+
+#[quickcheck]
+fn mul_small_tiny(x: Felt, y: Felt) -> bool {
+  // these take a different code path
+  (0..6).all( |k| Felt::mul_small(x, k) == x * Felt::from(k) && Felt::mul_small_add(x, k, y) == x * Felt::from(k) + y )
+}
+
+#[quickcheck]
+fn mul_small_mont(x: Felt, k: u32) -> bool {
+  let a = Felt::to_mont(x);
+  Mont::mul_small(a, k) == a * Mont::from(k)
+}
+
+#[test]
+fn unit_mul_small() {
+  let m = -Felt::one();
+  for k in [ 0, 1, 2, 4, 5, 6, 7, 1000, 0xffff_ffff ] {
+    assert_eq!( Felt::mul_small(m, k) , -Felt::from(k) );
+    assert_eq!( Felt::mul_small_add(m, k, m) , -Felt::from(k) - Felt::one() );
+  }
+}
+
+#[quickcheck]
+fn redc_vs_generic(x: BigInt256, y: Felt) -> bool {
+  // the reduction input is any product of a 256 bit number and a field element
+  let large = BigInt::mul( x , Felt::to_bigint(y) );
+  Mont::redc(large) == Mont::redc_generic(large)
+}
+
+#[quickcheck]
+fn redc_vs_generic_sqr(x: Felt) -> bool {
+  let large = BigInt::sqr( Felt::to_bigint(x) );
+  Mont::redc(large) == Mont::redc_generic(large)
+}
+
+#[test]
+fn unit_redc() {
+  let pm1: BigInt256 = FIELD_PRIME - BigInt::one();
+  let inputs: [BigInt512; 4] = 
+    [ BigInt::zero()
+    , BigInt::one()
+    , BigInt::mul( -BigInt256::one() , pm1 )
+    , BigInt::mulAddShifted( pm1 , pm1 , pm1 )
+    ];
+  for x in inputs {
+    assert_eq!( Mont::redc(x) , Mont::redc_generic(x) );
+  }
+}
+
+#[quickcheck]
+fn sqr_mul(x: Felt) -> bool { Felt::sqr(x) == x * x }
+
 #[quickcheck]
 fn twice(x: Felt) -> bool { prop_twice(x) }
 
@@ -192,3 +534,152 @@ fn distributive_div(x: Felt, y: Felt, z: Felt) -> bool { prop_distributive_div(x
 fn div_div(x: Felt, y: Felt, z: Felt) -> bool { prop_div_div(x,y,z) }
 
 //------------------------------------------------------------------------------
+
+#[quickcheck]
+fn inv_agrees_with_euclid(x: Felt) -> bool {
+  Felt::is_zero(x) || Felt::inv(x) == Some(Felt::inv_euclid(x))
+}
+
+#[quickcheck]
+fn inv_mont(x: Felt) -> bool {
+  Mont::inv( Felt::to_mont(x) ).map( Felt::from_mont ) == Felt::inv(x)
+}
+
+#[quickcheck]
+fn checked_div_mont(x: Felt, y: Felt) -> bool {
+  Mont::div( Felt::to_mont(x) , Felt::to_mont(y) ).map( Felt::from_mont ) == Felt::checked_div(x,y)
+}
+
+#[test]
+fn unit_inv() {
+  assert_eq!( Felt::inv( Felt::zero() )          , None );
+  assert_eq!( Mont::inv( Mont::zero() )          , None );
+  assert_eq!( Felt::checked_div( Felt::one() , Felt::zero() ) , None );
+  assert_eq!( Felt::inv( Felt::one()   )         , Some(Felt::one()) );
+  assert_eq!( Felt::inv( -Felt::one()  )         , Some(-Felt::one()) );
+  assert_eq!( Felt::inv( Felt::from_u32(2) )     , Some(Felt::unsafe_from_bigint(HALFP_PLUS_1)) );
+  assert_eq!( Mont::inv( Mont::one()   )         , Some(Mont::one()) );
+}
+
+#[quickcheck]
+fn batch_inv(xs: Vec<Felt>) -> bool {
+  let mut ys: Vec<Felt> = xs.clone();
+  Felt::batch_inv(&mut ys);
+  xs.iter().zip(ys.iter()).all( |(x,y)| Felt::inv(*x) == Some(*y) )
+}
+
+#[quickcheck]
+fn batch_inv_with_zeros(x: Felt, y: Felt) -> bool {
+  let z = Felt::zero();
+  let mut xs: [Felt; 6] = [ z , x , z , z , y , z ];
+  Felt::batch_inv(&mut xs);
+  xs == [ z , Felt::inv(x).unwrap() , z , z , Felt::inv(y).unwrap() , z ]
+}
+
+#[test]
+fn unit_batch_inv() {
+  let mut empty: [Mont; 0] = [];
+  Mont::batch_inv(&mut empty);
+  let mut zeros: [Mont; 3] = [Mont::zero(); 3];
+  Mont::batch_inv(&mut zeros);
+  assert_eq!( zeros , [Mont::zero(); 3] );
+  let mut xs: [Felt; 3] = [ Felt::one() , Felt::from_u32(2) , -Felt::one() ];
+  Felt::batch_inv(&mut xs);
+  assert_eq!( xs , [ Felt::one() , Felt::unsafe_from_bigint(HALFP_PLUS_1) , -Felt::one() ] );
+}
+
+//------------------------------------------------------------------------------
+// exponentiation and square roots
+
+#[quickcheck]
+fn pow_small(x: Felt, e: u8) -> bool {
+  let mut acc = Felt::one();
+  for _ in 0..e { acc = acc * x; }
+  Felt::pow_u64(x, e as u64) == acc && Felt::pow(x, BigInt256::from_u32(e as u32)) == acc
+}
+
+#[quickcheck]
+fn pow_add(x: Felt, a: u32, b: u32) -> bool {
+  Felt::pow_u64(x, a as u64) * Felt::pow_u64(x, b as u64) == Felt::pow_u64(x, a as u64 + b as u64)
+}
+
+#[quickcheck]
+fn pow_fermat(x: Felt) -> bool {
+  let pm2 = FIELD_PRIME - BigInt::from_u32(2);
+  Felt::is_zero(x) || Some( Felt::pow(x, pm2) ) == Felt::inv(x)
+}
+
+#[quickcheck]
+fn legendre_square(x: Felt) -> bool {
+  Felt::legendre(x * x) == (if Felt::is_zero(x) { 0 } else { 1 })
+}
+
+#[quickcheck]
+fn legendre_multiplicative(x: Felt, y: Felt) -> bool {
+  Felt::legendre(x * y) == Felt::legendre(x) * Felt::legendre(y)
+}
+
+#[quickcheck]
+fn sqrt_of_square(x: Felt) -> bool {
+  match Felt::sqrt(x * x) {
+    Some(y) => y == x || y == -x,
+    None    => false,
+  }
+}
+
+#[quickcheck]
+fn sqrt_agrees_with_legendre(x: Felt) -> bool {
+  match Felt::sqrt(x) {
+    Some(y) => y * y == x && Felt::legendre(x) >= 0,
+    None    => Felt::legendre(x) == -1,
+  }
+}
+
+#[test]
+fn unit_sqrt() {
+  let g = Felt::from_u32(MULT_GENERATOR);
+  let w = Felt::unsafe_from_bigint(ROOT_OF_UNITY);
+  assert_eq!( Felt::to_mont(w)                 , MONT_ROOT_OF_UNITY );
+  assert_eq!( Felt::pow(g, TRACE)              , w );
+  assert_eq!( Felt::pow_u64(w, 1 << (TWO_ADICITY-1)) , -Felt::one() );
+  assert_eq!( Felt::legendre(g)                , -1 );
+  assert_eq!( Felt::sqrt(g)                    , None );
+  assert_eq!( Felt::sqrt(Felt::zero())         , Some(Felt::zero()) );
+  assert_eq!( Felt::sqrt(-Felt::one()).map( |y| y*y ) , Some(-Felt::one()) );
+  let two = Felt::sqrt(Felt::from_u32(4)).unwrap();
+  assert!( two == Felt::from_u32(2) || two == -Felt::from_u32(2) );
+}
+
+//------------------------------------------------------------------------------
+// constant time operations
+
+#[quickcheck]
+fn ct_inv(x: Felt) -> bool {
+  Felt::is_zero(x) || Some(Felt::ct_inv(x)) == Felt::inv(x)
+}
+
+#[quickcheck]
+fn conditional_swap(x: Felt, y: Felt) -> bool {
+  let mut a = Felt::to_mont(x);
+  let mut b = Felt::to_mont(y);
+  Mont::conditional_swap(&mut a, &mut b, false);
+  let ok1 = Felt::from_mont(a) == x && Felt::from_mont(b) == y;
+  Mont::conditional_swap(&mut a, &mut b, true);
+  let ok2 = Felt::from_mont(a) == y && Felt::from_mont(b) == x;
+  ok1 && ok2 && Felt::ct_eq(x, y) == (x == y)
+}
+
+#[test]
+fn unit_ct() {
+  assert_eq!( Felt::ct_inv(Felt::zero()) , Felt::zero() );
+  assert_eq!( Mont::ct_inv(Mont::zero()) , Mont::zero() );
+  assert_eq!( -Felt::zero() , Felt::zero() );
+  assert_eq!( -Mont::zero() , Mont::zero() );
+  let mut x: Felt = Felt::from_u32(12345);
+  let mut y: Mont = Mont::from(12345);
+  x.zeroize();
+  y.zeroize();
+  assert!( Felt::ct_is_zero(x) && Mont::ct_is_zero(y) );
+}
+
+//------------------------------------------------------------------------------
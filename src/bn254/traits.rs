@@ -17,3 +17,9 @@ pub trait One {
 pub trait Inv {
   fn inv(x: Self) -> Self;
 }
+
+// overwrite secret values with zeros, in a way which is not optimized away
+
+pub trait Zeroize {
+  fn zeroize(&mut self);
+}
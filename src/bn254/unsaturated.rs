@@ -0,0 +1,190 @@
+
+//
+// Montgomery multiplication with unsaturated limbs
+//
+// RV32IM has no carry flag, so each add-with-carry in the saturated (32 bit limb)
+// code costs several extra instructions. Here the inputs are split into 9 limbs
+// of 29 bits (the top limb having only 24 bits), stored in `u32`-s. A product of 
+// two limbs (`u32 x u32 -> u64`, that is, a `mul` + `mulhu` pair) fits into 58 bits, 
+// and a full column of the schoolbook product (plus the reduction terms) accumulates 
+// into a 64 bit word without overflow: the carries are only propagated once per 
+// column, instead of after every single multiply-add.
+//
+// However, this needs 2x81 limb products instead of 2x64, and the 18 column sums
+// don't fit into the registers. Counting the RV32IM instructions, a multiplication 
+// is about 1780 instructions here versus about 1130 with the saturated CIOS, so 
+// this is not the default.
+//
+// The Montgomery constant is still `R = 2^256` (so this is a drop-in replacement
+// for the other multiplication algorithms, and all the precomputed constants
+// remain valid): we do 8 reduction steps of 29 bits and a last one of 24 bits.
+// Both quotient digits are cheap, as `p = 1 mod 2^28`:
+//
+// - `-1/p mod 2^29 = 2^28 - 1`
+// - `-1/p mod 2^24 = -1`
+//
+// The input/output is the standard saturated `BigInt256`, repacking is just
+// shifts and masks.
+//
+
+#![allow(non_snake_case)]
+
+// the limb loops are written with explicit indices, mirroring the math
+#![allow(clippy::needless_range_loop)]
+
+use unroll::unroll_for_loops;
+
+use crate::bn254::bigint::*;
+
+//------------------------------------------------------------------------------
+
+type Big = BigInt256;
+
+// note: `unroll_for_loops` only unrolls ranges with literal bounds, hence the `0..9`-s
+pub const NLIMBS29: usize = 9;
+
+const MASK29: u32 = (1 << 29) - 1;
+const MASK24: u32 = (1 << 24) - 1;
+
+// the field prime in 9x29 bit limbs
+const PRIME29: [u32; NLIMBS29] =
+  [ 0x10000001 , 0x1f0fac9f , 0x0e5c2450 , 0x07d090f3 , 0x1585d283
+  , 0x02db40c0 , 0x00a6e141 , 0x0e5c2634 , 0x0030644e
+  ];
+
+//------------------------------------------------------------------------------
+// conversion to/from the 9x29 representation
+
+#[inline(always)]
+#[unroll_for_loops]
+pub fn unpack29(big: Big) -> [u32; NLIMBS29] {
+  let ws: [u32; 8] = BigInt::to_words32(big);
+  let mut out: [u32; NLIMBS29] = [0; NLIMBS29];
+  let mut acc:  u64 = 0;
+  let mut bits: u32 = 0;
+  let mut k: usize  = 0;
+  for i in 0..8 {
+    acc  |= (ws[i] as u64) << bits;
+    bits += 32;
+    while bits >= 29 {
+      out[k] = (acc as u32) & MASK29;
+      acc  >>= 29;
+      bits  -= 29;
+      k     += 1;
+    }
+  }
+  out[NLIMBS29-1] = acc as u32;
+  out
+}
+
+// the inverse of `unpack29`; the limbs must be normalized (less than `2^29`)
+#[inline(always)]
+#[unroll_for_loops]
+pub fn pack29(limbs: [u32; NLIMBS29]) -> Big {
+  let mut ws: [u32; 8] = [0; 8];
+  let mut acc:  u64 = 0;
+  let mut bits: u32 = 0;
+  let mut k: usize  = 0;
+  for i in 0..9 {
+    acc  |= (limbs[i] as u64) << bits;
+    bits += 29;
+    while bits >= 32 && k < 8 {
+      ws[k] = acc as u32;
+      acc  >>= 32;
+      bits  -= 32;
+      k     += 1;
+    }
+  }
+  BigInt::from_words32(ws)
+}
+
+//------------------------------------------------------------------------------
+
+// the full product of two limbs: a single `mul` + `mulhu` pair on RV32IM
+#[inline(always)]
+fn mul32(x: u32, y: u32) -> u64 {
+  (x as u64) * (y as u64)
+}
+
+// Montgomery reduction of the (unnormalized) product columns.
+//
+// Each column is the sum of at most 9 products of the input limbs and 9 products
+// of the reduction terms, all less than `2^58`, plus the incoming carry; so
+// everything fits into 64 bits. The output is less than `2p`, but not necessarily
+// less than `p`.
+#[inline(always)]
+#[unroll_for_loops]
+fn redc29(mut T: [u64; 2*NLIMBS29]) -> Big {
+
+  for i in 0..8 {
+    let t: u32 = (T[i] as u32) & MASK29;
+    let m: u32 = ( (t << 28).wrapping_sub(t) ) & MASK29;
+    for j in 0..9 {
+      T[i+j] += mul32( m , PRIME29[j] );
+    }
+    T[i+1] += T[i] >> 29;
+  }
+
+  // the last step only eliminates 24 bits
+  let i = NLIMBS29-1;
+  let m: u32 = (T[i] as u32).wrapping_neg() & MASK24;
+  for j in 0..9 {
+    T[i+j] += mul32( m , PRIME29[j] );
+  }
+
+  // normalize the upper half (relative to the bit position `232`); the bottom 
+  // 24 bits are zero, so shifting those away we get the result
+  let mut acc:  u64 = (T[i] >> 24) & 0x1f;
+  let mut bits: u32 = 5;
+  let mut carry: u64 = T[i] >> 29;
+  let mut ws: [u32; 8] = [0; 8];
+  let mut k: usize = 0;
+  for j in 1..(NLIMBS29+1) {
+    let x: u64 = T[i+j] + carry;
+    carry = x >> 29;
+    acc  |= (x & (MASK29 as u64)) << bits;
+    bits += 29;
+    if bits >= 32 && k < 8 {
+      ws[k] = acc as u32;
+      acc  >>= 32;
+      bits  -= 32;
+      k     += 1;
+    }
+  }
+  BigInt::from_words32(ws)
+}
+
+// `x*y/2^256 mod 2p`, assuming both inputs are less than `p`
+#[inline(always)]
+#[unroll_for_loops]
+pub fn mul29(big1: Big, big2: Big) -> Big {
+  let xs: [u32; NLIMBS29] = unpack29(big1);
+  let ys: [u32; NLIMBS29] = unpack29(big2);
+  let mut T: [u64; 2*NLIMBS29] = [0; 2*NLIMBS29];
+  for i in 0..9 {
+    for j in 0..9 {
+      T[i+j] += mul32( xs[i] , ys[j] );
+    }
+  }
+  redc29(T)
+}
+
+// `x^2/2^256 mod 2p`, assuming the input is less than `p`
+#[inline(always)]
+#[unroll_for_loops]
+pub fn sqr29(big: Big) -> Big {
+  let xs: [u32; NLIMBS29] = unpack29(big);
+  let mut T: [u64; 2*NLIMBS29] = [0; 2*NLIMBS29];
+  for i in 0..8 {
+    let ys: u32 = xs[i] << 1;
+    for j in (i+1)..NLIMBS29 {
+      T[i+j] += mul32( ys , xs[j] );
+    }
+  }
+  for i in 0..9 {
+    T[2*i] += mul32( xs[i] , xs[i] );
+  }
+  redc29(T)
+}
+
+//------------------------------------------------------------------------------
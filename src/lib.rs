@@ -1,13 +1,7 @@
-
-#![allow(incomplete_features)]
-#![allow(stable_features)]
-
-#![feature(random)]
-#![feature(bigint_helper_methods)]
-#![feature(trait_alias)]
-#![feature(generic_const_exprs)]
-#![feature(unchecked_shifts)]
-
 pub mod bn254;
 pub mod poseidon;
 pub mod poseidon2;
+pub mod parallel;
+
+#[cfg(test)]
+mod testing;
@@ -0,0 +1,81 @@
+
+//
+// splitting independent work between threads (for the batch hashing functions)
+//
+// We use `std::thread::scope`, so there is no thread pool and no external
+// dependency; the threads are spawned for each call, which is negligible
+// compared to hashing a large batch. On targets without threads (`wasm`,
+// bare metal) everything runs on the calling thread.
+//
+
+#![allow(dead_code)]
+
+//------------------------------------------------------------------------------
+
+// below this many items per thread, it's not worth spawning a new thread
+const MIN_ITEMS_PER_THREAD: usize = 64;
+
+// whether the target supports spawning threads
+pub const HAS_THREADS: bool = cfg!( not( any( target_family = "wasm" , target_os = "none" ) ) );
+
+// the default number of threads: the available parallelism, or 1 if unknown
+pub fn default_thread_count() -> usize {
+  if HAS_THREADS {
+    std::thread::available_parallelism().map( |n| n.get() ).unwrap_or(1)
+  }
+  else {
+    1
+  }
+}
+
+//------------------------------------------------------------------------------
+
+// applies `f` to each element of `xs`, writing the results into `out`, using
+// at most `threads` threads (`0` means the default). The thread count is also 
+// clamped to one per `MIN_ITEMS_PER_THREAD` items. The slices must have the 
+// same length.
+pub fn map_into<A: Sync, B: Send>(threads: usize, xs: &[A], out: &mut [B], f: impl Fn(&A) -> B + Sync) {
+  assert_eq!( xs.len() , out.len() , "parallel::map_into: length mismatch" );
+  let n: usize = xs.len();
+  let threads: usize = if threads == 0 { default_thread_count() } else { threads };
+  let threads: usize = threads.min( n / MIN_ITEMS_PER_THREAD ).max(1);
+
+  if !HAS_THREADS || threads == 1 {
+    for i in 0..n { out[i] = f(&xs[i]); }
+    return
+  }
+
+  let chunk: usize = n.div_ceil(threads);
+  let f = &f;
+  std::thread::scope( |scope| {
+    for (xs_chunk, out_chunk) in xs.chunks(chunk).zip( out.chunks_mut(chunk) ) {
+      scope.spawn( move || {
+        for i in 0..xs_chunk.len() { out_chunk[i] = f(&xs_chunk[i]); }
+      });
+    }
+  });
+}
+
+// the same as `map_into`, but updates the elements in place
+pub fn map_in_place<A: Send>(threads: usize, xs: &mut [A], f: impl Fn(&A) -> A + Sync) {
+  let n: usize = xs.len();
+  let threads: usize = if threads == 0 { default_thread_count() } else { threads };
+  let threads: usize = threads.min( n / MIN_ITEMS_PER_THREAD ).max(1);
+
+  if !HAS_THREADS || threads == 1 {
+    for x in xs.iter_mut() { *x = f(x); }
+    return
+  }
+
+  let chunk: usize = n.div_ceil(threads);
+  let f = &f;
+  std::thread::scope( |scope| {
+    for xs_chunk in xs.chunks_mut(chunk) {
+      scope.spawn( move || {
+        for x in xs_chunk.iter_mut() { *x = f(x); }
+      });
+    }
+  });
+}
+
+//------------------------------------------------------------------------------
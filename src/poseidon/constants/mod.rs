@@ -1,5 +1,32 @@
-
 pub mod t2;
 pub mod t3;
 pub mod t4;
+
+// the tables for `t >= 5` exceed clippy's size limit for `const` arrays; they are
+// generated code, and only ever used by reference
+#[allow(clippy::large_const_arrays)]
 pub mod t5;
+#[allow(clippy::large_const_arrays)]
+pub mod t6;
+#[allow(clippy::large_const_arrays)]
+pub mod t7;
+#[allow(clippy::large_const_arrays)]
+pub mod t8;
+#[allow(clippy::large_const_arrays)]
+pub mod t9;
+#[allow(clippy::large_const_arrays)]
+pub mod t10;
+#[allow(clippy::large_const_arrays)]
+pub mod t11;
+#[allow(clippy::large_const_arrays)]
+pub mod t12;
+#[allow(clippy::large_const_arrays)]
+pub mod t13;
+#[allow(clippy::large_const_arrays)]
+pub mod t14;
+#[allow(clippy::large_const_arrays)]
+pub mod t15;
+#[allow(clippy::large_const_arrays)]
+pub mod t16;
+#[allow(clippy::large_const_arrays)]
+pub mod t17;
@@ -0,0 +1,1512 @@
+
+// circomlib's Poseidon constants for t=10
+
+use crate::bn254::montgomery::*;
+
+
+//------------------------------------------------------------------------------
+
+// round constants (t for external, 1 for internal rounds; flattened)
+pub const CONST_C: [Mont; 140] = 
+  [ Mont::unsafe_make( [ 0x08294d86 , 0xcfdcb7a4 , 0xcf75b142 , 0x7cd3aa1b , 0x7f9bde14 , 0x53e448e0 , 0x86f968bf , 0x258e3231 ] )
+  , Mont::unsafe_make( [ 0xbc72144e , 0x3691f0f6 , 0x58b8aaaa , 0xf13ae016 , 0xde28f058 , 0xe5304ad1 , 0xa4d88ac4 , 0x0af6f86b ] )
+  , Mont::unsafe_make( [ 0xdcc2695b , 0xc43a26bf , 0x0a83fb67 , 0x46fba292 , 0x557f4380 , 0x078b6129 , 0x1bf64bff , 0x098c74a2 ] )
+  , Mont::unsafe_make( [ 0xc06ecf08 , 0x8e6710e5 , 0x63736328 , 0x93e3390a , 0x3233b82a , 0xadba442c , 0xcbc371fa , 0x0febfd0a ] )
+  , Mont::unsafe_make( [ 0xcdb35c71 , 0x196bb920 , 0xed6a62e7 , 0x33238e8f , 0x71027340 , 0x9cf65a35 , 0x55b0cc1c , 0x18850792 ] )
+  , Mont::unsafe_make( [ 0xb58a413c , 0x9ff94d0c , 0x682f276f , 0xc289496b , 0x65288e3a , 0xa6f655b0 , 0xbdd8cbce , 0x05a8f02d ] )
+  , Mont::unsafe_make( [ 0x99d40e33 , 0x2a747fc5 , 0xa694a107 , 0x4bdcab88 , 0x2f6348d2 , 0xbd05e040 , 0x7854c296 , 0x0cf159fd ] )
+  , Mont::unsafe_make( [ 0x7f52f564 , 0xc5b14532 , 0xb3eb63a0 , 0xf0ed17c1 , 0x0420eed9 , 0x6ba5c777 , 0x78be2bb6 , 0x0bb601a5 ] )
+  , Mont::unsafe_make( [ 0x55d2ff0c , 0xca1bf3ae , 0xa2343e8d , 0xcd39eb8f , 0x5ddcb1d9 , 0x5626d462 , 0x328f00da , 0x1651b8b1 ] )
+  , Mont::unsafe_make( [ 0x30daa7fd , 0xf1154943 , 0x1192837e , 0x10d7844e , 0xcb5a6927 , 0xeb2c2b77 , 0x6e6d18dd , 0x0baebbf6 ] )
+  , Mont::unsafe_make( [ 0x316058c6 , 0x30e44c1c , 0x4bcb86e1 , 0x02c01c07 , 0x7f60d56d , 0xd087d719 , 0x1a0cc8db , 0x1e6647d4 ] )
+  , Mont::unsafe_make( [ 0x1db5ea84 , 0xef4cecc0 , 0x49200d31 , 0x45434bab , 0xab89e139 , 0xce0bce59 , 0x046ff0ad , 0x0cb221ca ] )
+  , Mont::unsafe_make( [ 0x1fd9c67e , 0x07bcd4af , 0x55b33626 , 0x0efdcd0a , 0x8788db9a , 0x2e2a0130 , 0x2e7ea159 , 0x0d7c529e ] )
+  , Mont::unsafe_make( [ 0x3473a3f9 , 0xf6d1168c , 0xe1c0b78c , 0xf0f014c5 , 0x814cc3d5 , 0x5991c3df , 0x9df044ea , 0x11bdd73f ] )
+  , Mont::unsafe_make( [ 0x2bcfbdc5 , 0xdbf83754 , 0xe353e708 , 0xc5f4eb9c , 0x768bd0fc , 0x9f534a5b , 0x912105fb , 0x232a4e1b ] )
+  , Mont::unsafe_make( [ 0x14eff782 , 0xccac6599 , 0xbb5329fa , 0xc23d8264 , 0x740636c5 , 0x43073392 , 0x0b8fc1ab , 0x29a1f109 ] )
+  , Mont::unsafe_make( [ 0x9320c596 , 0x199b39fb , 0x3c899025 , 0x35e06802 , 0x55093a2c , 0xbbf9b561 , 0x2e485f6b , 0x00ce157d ] )
+  , Mont::unsafe_make( [ 0xc477d5e0 , 0x7685e1b9 , 0xfe031784 , 0x16f8357f , 0x5be6fd8d , 0xa38c7fa4 , 0x2c58bd5b , 0x01f79827 ] )
+  , Mont::unsafe_make( [ 0xb2125ab6 , 0x22f07011 , 0xe5cc9e39 , 0x678bdcf9 , 0x42ca3b8f , 0x4f74c17e , 0xf995ac5a , 0x2e49d567 ] )
+  , Mont::unsafe_make( [ 0xa3324adb , 0xa3485fa3 , 0xca2659d9 , 0x2d669590 , 0xf377c9ae , 0x8e86fc4b , 0x45961ce6 , 0x085a367a ] )
+  , Mont::unsafe_make( [ 0x16f2493c , 0xa83f9cee , 0x767cd07b , 0x0a3a0eb0 , 0x8a1ef5a1 , 0xb583038c , 0x08906482 , 0x2733b928 ] )
+  , Mont::unsafe_make( [ 0x4cb7253d , 0x3dbbf4f3 , 0x72c300a6 , 0x01f462bd , 0x192b3862 , 0x7ad7447c , 0x0d04a442 , 0x0bd98087 ] )
+  , Mont::unsafe_make( [ 0x01217262 , 0x844aaac8 , 0x73415e8c , 0xfb29a5fa , 0x4f3461b4 , 0xb17e2707 , 0xab1f2a0e , 0x2c4b8f5c ] )
+  , Mont::unsafe_make( [ 0x7766a1fb , 0x2cde79cf , 0x7c99907b , 0x5944d6c3 , 0x0ed084b8 , 0x2f75df32 , 0x85fc5ae4 , 0x1dc6fd6c ] )
+  , Mont::unsafe_make( [ 0xcb8418e3 , 0x52e3f7d3 , 0xc650735f , 0x46b35225 , 0xd2608e0b , 0x9fa9abd2 , 0x716a7b06 , 0x0025a824 ] )
+  , Mont::unsafe_make( [ 0x9f5e5cdc , 0x8b90e79c , 0x6501b0d0 , 0x1f82aafd , 0xa79f46b1 , 0xb66ae6a3 , 0xc8000876 , 0x2758006d ] )
+  , Mont::unsafe_make( [ 0x90114770 , 0x176f7a08 , 0xf57cc560 , 0x92861182 , 0xae771341 , 0x48b1cd4b , 0x9b8ba4f7 , 0x025624fc ] )
+  , Mont::unsafe_make( [ 0x5fef278a , 0xbfeec37a , 0xa7153969 , 0x84a5f428 , 0xa2d727c7 , 0x15f774c9 , 0xeba7a471 , 0x1ec049f4 ] )
+  , Mont::unsafe_make( [ 0x07bd9a38 , 0x0302c40a , 0xf8dfdd96 , 0x858077e2 , 0x8dbc5fdf , 0x447a9439 , 0x7863c6a8 , 0x2b1b20fb ] )
+  , Mont::unsafe_make( [ 0x4432f895 , 0xa08e1c22 , 0x21cc4c0d , 0xe0d9b29e , 0x42c73dec , 0x3d81de9f , 0x98dabad2 , 0x07fcbb9a ] )
+  , Mont::unsafe_make( [ 0x771f9718 , 0x1dab4cd5 , 0xcc149f68 , 0xf45064c0 , 0xe6f97ba5 , 0x51919f92 , 0x9264a0b5 , 0x0a4bdb3c ] )
+  , Mont::unsafe_make( [ 0x5636205d , 0x7169fcc6 , 0x38765baa , 0x3f85eed8 , 0x34971a45 , 0x1d768afc , 0x61acb02e , 0x0e311806 ] )
+  , Mont::unsafe_make( [ 0xf5327db8 , 0x4c31713b , 0x7f7e19a6 , 0xffef5305 , 0xc2118668 , 0x1be6cb62 , 0xe1bee4ae , 0x1c3d543b ] )
+  , Mont::unsafe_make( [ 0x5bc8147c , 0x00d4d5d5 , 0xea37817e , 0xdb4eba82 , 0x474600a7 , 0xb31ebc0c , 0xca351e41 , 0x1af71275 ] )
+  , Mont::unsafe_make( [ 0x714926d4 , 0x59037ec7 , 0xddcf85fc , 0x16c6f97e , 0x1b9b3565 , 0x1e8aa845 , 0x6157ff80 , 0x106f3b87 ] )
+  , Mont::unsafe_make( [ 0x3188abf0 , 0x61dc48e8 , 0x56de5499 , 0x6d88c20b , 0x7d08cf7f , 0x80d50220 , 0xdb23fedf , 0x0a1cfa6a ] )
+  , Mont::unsafe_make( [ 0x7d456cb8 , 0x5b5fb246 , 0x8984dea3 , 0x78204892 , 0xd3f71c02 , 0x16066f9d , 0x7c7a7151 , 0x1f4d7700 ] )
+  , Mont::unsafe_make( [ 0xf55a74ea , 0x1b05d991 , 0x05649256 , 0x1789e359 , 0xc42021ff , 0xcd77fb40 , 0xdee948a9 , 0x114b938f ] )
+  , Mont::unsafe_make( [ 0x2e03c93a , 0xb71a1858 , 0x7fd1edeb , 0x8e549f68 , 0x96538e8c , 0x1863bce5 , 0x7753a95b , 0x14039b39 ] )
+  , Mont::unsafe_make( [ 0xedace2b2 , 0xfc21676a , 0x32525f7f , 0x48faf2c8 , 0x1ac34942 , 0x5d77a5cc , 0x033404cd , 0x0dc68639 ] )
+  , Mont::unsafe_make( [ 0x0f2f506a , 0x913fb895 , 0x315809a3 , 0xf22206a8 , 0x025d18d6 , 0x8c4c6be7 , 0xe8433e2f , 0x0bd1fe25 ] )
+  , Mont::unsafe_make( [ 0x08ea3133 , 0xc5d5ac45 , 0x934a2b48 , 0x96f1f910 , 0xab2fa626 , 0x9137eeba , 0x2a3e6e42 , 0x1b8bda7f ] )
+  , Mont::unsafe_make( [ 0x98dd1207 , 0x52bdec6c , 0x55ed3b1e , 0xce8147e8 , 0x8540647a , 0x28a0c03a , 0x3148564c , 0x201eee35 ] )
+  , Mont::unsafe_make( [ 0x92eda119 , 0xf13bce88 , 0x27de97b4 , 0x3a792041 , 0x7c88e531 , 0x3cb7b505 , 0x1bbfee85 , 0x08895f7b ] )
+  , Mont::unsafe_make( [ 0xe8800ccd , 0x950db90c , 0x373604f1 , 0xd669509d , 0x67720717 , 0x89e7bd97 , 0x4b72d6a8 , 0x24c86de9 ] )
+  , Mont::unsafe_make( [ 0xab418456 , 0x162c6976 , 0xc87c6c08 , 0x6d86bc1c , 0xb835dfcd , 0x250b05d4 , 0xcfd817fc , 0x2138990a ] )
+  , Mont::unsafe_make( [ 0x9a4049ea , 0xde1f0834 , 0xe2f34884 , 0xc5177fd9 , 0x1f3cce15 , 0x0dfc575e , 0x9552fe92 , 0x117575f4 ] )
+  , Mont::unsafe_make( [ 0x50c61f00 , 0x12464909 , 0xb8d8eb99 , 0xa6a57aae , 0x6ef6b38b , 0x466a50a9 , 0x23ce19e1 , 0x19046d86 ] )
+  , Mont::unsafe_make( [ 0xd14a96fb , 0xc20027cf , 0x5e052817 , 0x4123da3a , 0x0d6a4e63 , 0xb3970176 , 0x3f812c90 , 0x20205ce1 ] )
+  , Mont::unsafe_make( [ 0x92a4d38e , 0x81cd0cb7 , 0xa69cc71d , 0xc050af12 , 0x5e5d13ba , 0xbe9ecc20 , 0x0da8c509 , 0x000046cf ] )
+  , Mont::unsafe_make( [ 0xc87e8227 , 0x3ed7d560 , 0xf244f993 , 0x6a66fce0 , 0x88e141a0 , 0x77a2932d , 0x9959a987 , 0x05592d85 ] )
+  , Mont::unsafe_make( [ 0x220a2ec6 , 0xc1b80ac6 , 0xd850aff3 , 0xcdda7b95 , 0x48dfb7e4 , 0x15fa2848 , 0x3c625e94 , 0x1ccfe4fc ] )
+  , Mont::unsafe_make( [ 0x0aef901e , 0x7f162e64 , 0x0beda872 , 0x2896c8e2 , 0xa755608b , 0x05d09f03 , 0x4c7a9b77 , 0x245fe6f6 ] )
+  , Mont::unsafe_make( [ 0x7754494c , 0x30816c3c , 0x606f8f87 , 0xbbc9b620 , 0x650462be , 0xd1b8d257 , 0x6632faa8 , 0x29164ade ] )
+  , Mont::unsafe_make( [ 0x9bc6132b , 0x48a3fd94 , 0x842c3312 , 0x50a5d146 , 0x4548478a , 0x31223bcc , 0xf36ee488 , 0x1ea772e4 ] )
+  , Mont::unsafe_make( [ 0x7f933351 , 0x676ac09f , 0xd1a6d620 , 0x1c5bd930 , 0x8bee2bca , 0xa310cb51 , 0xb1b0efaf , 0x1f3d014d ] )
+  , Mont::unsafe_make( [ 0x094f8820 , 0xb72fca03 , 0x34bb2b0b , 0x3b0eebac , 0x00c41f41 , 0x7ed388f0 , 0x32efc13d , 0x10069a3f ] )
+  , Mont::unsafe_make( [ 0x9a1dd075 , 0xc30f3e47 , 0x5e98b107 , 0x7b3af32c , 0x51db189c , 0xcdea1a76 , 0x16d2e450 , 0x1aeeeeed ] )
+  , Mont::unsafe_make( [ 0x76371d1f , 0x0cb731e9 , 0x62cd088c , 0xc0164a7d , 0x426276ea , 0x04a5c135 , 0x3be945c6 , 0x2bbb04ad ] )
+  , Mont::unsafe_make( [ 0x31e53b4a , 0xb838c5ba , 0xe2c9e9ab , 0x4ee5ba84 , 0x480bcd92 , 0xc3268156 , 0x56f97249 , 0x07429f0a ] )
+  , Mont::unsafe_make( [ 0x575b6594 , 0x07145f61 , 0x30df3db7 , 0x40b14095 , 0x411555b2 , 0x5e9d1014 , 0x3db38faa , 0x0a77606c ] )
+  , Mont::unsafe_make( [ 0x029a52de , 0x304fe145 , 0xe814a25f , 0x49ff84d3 , 0xe761a6ec , 0xb1575f04 , 0x408468b7 , 0x23149ce6 ] )
+  , Mont::unsafe_make( [ 0xa17fcb72 , 0xfa6c1af9 , 0x360dc6db , 0x2305f1d3 , 0x98b178d8 , 0x5b4642da , 0xae35df1d , 0x18e35678 ] )
+  , Mont::unsafe_make( [ 0xbb1648c6 , 0xf8c3004a , 0xecf13361 , 0xeb6985b4 , 0x5b4cf7d6 , 0xa2ac894c , 0x85e4b90e , 0x237e9d28 ] )
+  , Mont::unsafe_make( [ 0x5b70a6cd , 0x65293586 , 0x0ac48697 , 0x8e46cbd7 , 0x5ff4b454 , 0x9bb78fea , 0xb0413226 , 0x01c571be ] )
+  , Mont::unsafe_make( [ 0xe83e68a7 , 0xacf498f3 , 0x5cd0d198 , 0xadf80f3b , 0x852d66c7 , 0xeb3f78a1 , 0xe23c9197 , 0x241c3fbd ] )
+  , Mont::unsafe_make( [ 0xe29bdf54 , 0xccc575bd , 0x30795871 , 0x5187f654 , 0xbc77e53c , 0x31225c1d , 0x5e578d78 , 0x28b877a8 ] )
+  , Mont::unsafe_make( [ 0xd6c25762 , 0x10dd297e , 0x259e73ae , 0xa3446204 , 0xad13136b , 0xfa6b3d91 , 0x7e29aa22 , 0x133f732b ] )
+  , Mont::unsafe_make( [ 0x1f450a1b , 0x2b48b6f2 , 0xd410abea , 0x4702b8b8 , 0x4717fc36 , 0x32bb8ce1 , 0x543d7e23 , 0x20ed6a96 ] )
+  , Mont::unsafe_make( [ 0x3738dd3b , 0xe5c80b67 , 0x117dfa44 , 0x1aa05cef , 0x740eaaaa , 0xd5c9d319 , 0x9a2c7a17 , 0x268aa48c ] )
+  , Mont::unsafe_make( [ 0xe556e925 , 0x28965f7f , 0x7f8d9fd3 , 0xf45791d3 , 0x7cb205a8 , 0xe5d8393b , 0x0c6d14be , 0x0d93a5ca ] )
+  , Mont::unsafe_make( [ 0x7313be9f , 0x412292ec , 0xed67d815 , 0x787dd88b , 0x0b201d77 , 0xdd72c0f6 , 0xe93e13cc , 0x2417c8a9 ] )
+  , Mont::unsafe_make( [ 0xe30d375a , 0xab7c4412 , 0x947a480c , 0x5f9c0925 , 0xa62cf528 , 0x76625cdd , 0x79a4df42 , 0x28acfdec ] )
+  , Mont::unsafe_make( [ 0xa5aeba20 , 0x4242692a , 0x0001f3b6 , 0xf1bbd144 , 0x9ddbc008 , 0xa4944a35 , 0x52083103 , 0x23f39543 ] )
+  , Mont::unsafe_make( [ 0xd7d3a9d2 , 0xeb566521 , 0x617db629 , 0x153b23b4 , 0xdb9c3b05 , 0xa9580fa9 , 0xbe00d837 , 0x13ce8ef3 ] )
+  , Mont::unsafe_make( [ 0x6965266f , 0x4e6613d9 , 0xd351ad9d , 0xd55e7919 , 0x97fe358c , 0x6e33fe84 , 0xeca1203a , 0x0807720a ] )
+  , Mont::unsafe_make( [ 0x2f78db00 , 0x23ecf10a , 0x33ee7bda , 0x6c693f61 , 0x397f60c7 , 0xca21c170 , 0xcbd375a7 , 0x14e5887e ] )
+  , Mont::unsafe_make( [ 0x498eb75d , 0xcca25999 , 0x88aae32b , 0x66baebca , 0x32291b9f , 0x1f513876 , 0x2dbd2f19 , 0x29c4cf30 ] )
+  , Mont::unsafe_make( [ 0xf40a2dbb , 0x99fa1601 , 0x7e612ef7 , 0x6f350520 , 0xf2021663 , 0x639cf2d1 , 0xc4f3461d , 0x173cbb07 ] )
+  , Mont::unsafe_make( [ 0x52d1911a , 0x21846b5d , 0x9e139ec4 , 0x1334dae5 , 0x9635da21 , 0xfd20d3b3 , 0x57dd8e67 , 0x0b99d9ad ] )
+  , Mont::unsafe_make( [ 0x6719aa5b , 0x2cccda3f , 0xacfc2e53 , 0x8629e4d2 , 0xa8cce8ed , 0x612c0539 , 0x1ee5a5c9 , 0x1c48380b ] )
+  , Mont::unsafe_make( [ 0x635f4a47 , 0x75e97d47 , 0x05f5a0db , 0xc58cb527 , 0x1eeb53a0 , 0xcfd91913 , 0xe3b7564f , 0x29e9d242 ] )
+  , Mont::unsafe_make( [ 0x82d66541 , 0xcbf7c1b9 , 0x6fda241a , 0x8c142e3a , 0x991705de , 0x5a206ac5 , 0x0dfc6032 , 0x2a5b3984 ] )
+  , Mont::unsafe_make( [ 0x0a4928ab , 0x2bd2974a , 0xadd64195 , 0xd1c83dc8 , 0x417a09a4 , 0x792a3ecd , 0xd3ed97e8 , 0x14a8b6e3 ] )
+  , Mont::unsafe_make( [ 0x5e4f125b , 0x3512850c , 0x7b8d6e71 , 0xae0e6712 , 0x4d329df1 , 0x16c66d49 , 0x28efd014 , 0x0b510863 ] )
+  , Mont::unsafe_make( [ 0xde956e8e , 0xe3db128e , 0xaca4447d , 0x80ba4863 , 0x081d7781 , 0xe5b15e50 , 0xf1493e6d , 0x29fee116 ] )
+  , Mont::unsafe_make( [ 0x0dddfd5b , 0x25f790ad , 0x5fe5fc8a , 0x444cd9d2 , 0x6ee90ef0 , 0x93dd27a8 , 0xf8fbee91 , 0x2b6fa9bd ] )
+  , Mont::unsafe_make( [ 0xc71786a6 , 0x2c81fa38 , 0x73f298b2 , 0x2e1ad7ec , 0x870b02a9 , 0xf14be093 , 0x45c6ac09 , 0x18e33335 ] )
+  , Mont::unsafe_make( [ 0x2f46d0d6 , 0x138e751e , 0x46f96fc8 , 0x21848fe5 , 0x170dfcae , 0xcf2a9168 , 0xd5c9713f , 0x05f661b4 ] )
+  , Mont::unsafe_make( [ 0xde04419b , 0x273ef3fb , 0x748f5c8e , 0xa7eebe68 , 0x644ba63d , 0x9aeee10c , 0x04c58aed , 0x09025a60 ] )
+  , Mont::unsafe_make( [ 0x99fcd690 , 0x2d181f8d , 0xaec3c585 , 0xe39abc26 , 0x17501cdd , 0x258d6581 , 0x13236559 , 0x164332bd ] )
+  , Mont::unsafe_make( [ 0xae3e69bc , 0x31f4a696 , 0x8ff8db6b , 0xb4c86c44 , 0x0ae1f69f , 0xde2e79ed , 0x06bb8bcf , 0x1d47f78b ] )
+  , Mont::unsafe_make( [ 0x132c083b , 0xb1aa788a , 0x7342fd7d , 0x567622fc , 0x72b5bae0 , 0x7bbc079b , 0x0ed789cc , 0x043f7bff ] )
+  , Mont::unsafe_make( [ 0xfde150f9 , 0x04bb99cc , 0xfd500e09 , 0x68505bb1 , 0x00ae9a65 , 0xbc2eaa55 , 0x39578556 , 0x269fd06b ] )
+  , Mont::unsafe_make( [ 0xb9c006f6 , 0x3d2fdf16 , 0xdeb82ef5 , 0x1d9719d4 , 0xc5457eb1 , 0x6f49bfb3 , 0x2ca40aef , 0x1acde521 ] )
+  , Mont::unsafe_make( [ 0xd595722a , 0xfcf78cea , 0x97ab500b , 0x8d6e6ac1 , 0xdcf1dfd9 , 0x64b38557 , 0xf8d4392d , 0x07a97622 ] )
+  , Mont::unsafe_make( [ 0x2ef8d46f , 0xac934dd0 , 0xe524e1b7 , 0x177bcd47 , 0xad02d33a , 0x126ce521 , 0xdb7d7f86 , 0x175afb9a ] )
+  , Mont::unsafe_make( [ 0xe89a8ff4 , 0x49539a5b , 0xc010776c , 0xa0854a47 , 0x51fed035 , 0x5ab55bc1 , 0xf7bfeac6 , 0x2ab29793 ] )
+  , Mont::unsafe_make( [ 0x011df998 , 0xe4a26997 , 0x5ccd1b8c , 0x46b114d8 , 0x6fe511d9 , 0x6a96bd15 , 0xb1645f57 , 0x239b3b97 ] )
+  , Mont::unsafe_make( [ 0xd24b47ed , 0xe8b8c95d , 0x393ccf99 , 0x30a454ba , 0x99832420 , 0x546b9e8c , 0x9142be19 , 0x2a926589 ] )
+  , Mont::unsafe_make( [ 0xa0543adc , 0xe086cc5c , 0xfcd664ba , 0x082ca5ed , 0xba024639 , 0x585654ee , 0xe70c0c13 , 0x0283c641 ] )
+  , Mont::unsafe_make( [ 0x01a4f22f , 0x2cdc22f8 , 0xb753c9c7 , 0x60044a04 , 0xe0e7237d , 0x24e4c499 , 0x09c6223d , 0x2fe87f4d ] )
+  , Mont::unsafe_make( [ 0x9a97f3bd , 0x60154326 , 0x8ed788de , 0xba2e3a37 , 0x5c8468ea , 0xe719b50c , 0x414b5855 , 0x00f40e7d ] )
+  , Mont::unsafe_make( [ 0x6bbc694b , 0xa792fb29 , 0x68c1574b , 0x6d5517c7 , 0xb10d3b56 , 0x1c519d4b , 0xfa3e32f8 , 0x2fdc4235 ] )
+  , Mont::unsafe_make( [ 0xa0158b4e , 0xb66860d1 , 0xaecbd008 , 0xd922acdf , 0x30e74bde , 0x53ce7750 , 0x5754d6fd , 0x13d31cd7 ] )
+  , Mont::unsafe_make( [ 0x4ec833dd , 0x9c929560 , 0x54d0166b , 0x5bee1f75 , 0x1a945cdb , 0x7b7a53f8 , 0x0ad82b6b , 0x29f26e27 ] )
+  , Mont::unsafe_make( [ 0x9a503cf0 , 0x73823943 , 0x61dd7db2 , 0xf9dcce66 , 0x6d24e383 , 0x6a35a96f , 0xbbd38fff , 0x112d0784 ] )
+  , Mont::unsafe_make( [ 0x9be06b4b , 0xc4f5a397 , 0x078cc44e , 0xe95203af , 0x5522330a , 0xe0370cd9 , 0x55ca9972 , 0x17aa44d4 ] )
+  , Mont::unsafe_make( [ 0x38dded48 , 0xabeaadaf , 0x9f086e4f , 0x9cf2f960 , 0x73c6d47d , 0xce0ca6a0 , 0xfd3d3cd8 , 0x272c7b38 ] )
+  , Mont::unsafe_make( [ 0x3443a64e , 0x1adffce3 , 0x7ce3cc9b , 0xb2ae727d , 0xa098bb8e , 0xdc849711 , 0xa9c0af49 , 0x23736a9e ] )
+  , Mont::unsafe_make( [ 0xcfc1f777 , 0x0b4866b8 , 0xb3166b6b , 0xd3d8f7f7 , 0x05e3f613 , 0xa1ac1b9a , 0x396322a3 , 0x09e194c0 ] )
+  , Mont::unsafe_make( [ 0xbf495dcb , 0x3948508a , 0x9a1e8c0d , 0xec73bc6c , 0x73a6d838 , 0x453cab23 , 0x5c36aec2 , 0x16b5d0dc ] )
+  , Mont::unsafe_make( [ 0x285959fa , 0xaedeeed4 , 0xd9ca5883 , 0x68a45d6e , 0x637681f0 , 0x341488d9 , 0x9d773cba , 0x2674ba4b ] )
+  , Mont::unsafe_make( [ 0xa98d2ff7 , 0x64db2d66 , 0xf7f19ad0 , 0x35d188a0 , 0x0b9a7e66 , 0x72ad0894 , 0x318aab8a , 0x18d418a7 ] )
+  , Mont::unsafe_make( [ 0x980dfe19 , 0x0bd6dff3 , 0x012b2439 , 0x94008784 , 0xe9f26db1 , 0x49c14a4a , 0xa85db807 , 0x13a7780c ] )
+  , Mont::unsafe_make( [ 0x83b1a064 , 0x4217a127 , 0x5adce545 , 0xbbe78974 , 0xf49152b9 , 0x99b8db8f , 0x4843eeaf , 0x0792a401 ] )
+  , Mont::unsafe_make( [ 0xca3f60bf , 0xadf7d89c , 0xfbe4a7f6 , 0xdbb26aa6 , 0xa6aa9b74 , 0xb06c94d2 , 0x625856f7 , 0x16e34f95 ] )
+  , Mont::unsafe_make( [ 0xfdac054a , 0xdb21a565 , 0x52a6f265 , 0xd2b5beec , 0x22fa54b5 , 0x0a126d21 , 0x3c0b6e19 , 0x29342da8 ] )
+  , Mont::unsafe_make( [ 0x3c3394c5 , 0xa0b75f89 , 0x6630bfb2 , 0xe3c7d2c0 , 0x42fa4c71 , 0xccc99f24 , 0xa9ec457f , 0x16fe1851 ] )
+  , Mont::unsafe_make( [ 0x13e29307 , 0x0907c1da , 0x4903822e , 0x579b06e1 , 0x033e048a , 0xe1ea492e , 0x2ca3b541 , 0x2da07f31 ] )
+  , Mont::unsafe_make( [ 0x1cbd09e6 , 0x888c241a , 0xc312ef03 , 0x5ad2ff79 , 0xbbcdff3a , 0xffcb14d0 , 0x916591f3 , 0x0a48a969 ] )
+  , Mont::unsafe_make( [ 0x278ad8af , 0x96c098ab , 0x95e238f7 , 0x21b0bd14 , 0x64c77387 , 0xb8f6c875 , 0xfee98f4c , 0x2b84d464 ] )
+  , Mont::unsafe_make( [ 0x53fe905d , 0x1badc103 , 0xedae517a , 0x032f1b9e , 0x7d456676 , 0xc8616072 , 0x2d919146 , 0x21cb7959 ] )
+  , Mont::unsafe_make( [ 0xbf4c863e , 0x135efd22 , 0xa3785526 , 0xcf86cf39 , 0xd423dd76 , 0x6a7229b0 , 0x0f1d8d35 , 0x1786561e ] )
+  , Mont::unsafe_make( [ 0xee844c27 , 0xaeb662fd , 0xc63342df , 0x7d6e3c4b , 0xaef779b8 , 0x075d1ce6 , 0x039b39f7 , 0x06451578 ] )
+  , Mont::unsafe_make( [ 0xb72b1948 , 0xb794b82c , 0xc131f175 , 0x35d1c36b , 0x5569d54c , 0xd2a9ee5e , 0x9f98a81e , 0x26e9e459 ] )
+  , Mont::unsafe_make( [ 0xdc4823fc , 0x34410603 , 0xd6c7aa46 , 0x7095e550 , 0xec5966db , 0x208b10d3 , 0xf315f2c9 , 0x2438b176 ] )
+  , Mont::unsafe_make( [ 0xa9a7be0d , 0x473b08f3 , 0xec9de673 , 0xabb396d6 , 0x56c276b6 , 0x8d5a32f0 , 0xb409788b , 0x16a92ebe ] )
+  , Mont::unsafe_make( [ 0xae927855 , 0x49c33f1a , 0x94c9ab9e , 0xe7a9c521 , 0x5169bc1e , 0xcf2a6fd6 , 0x61c0c8ca , 0x12be4982 ] )
+  , Mont::unsafe_make( [ 0xb6781a07 , 0xa747e229 , 0x3d66ba1e , 0x8d041383 , 0x6db0fba5 , 0x5ea49b80 , 0xb9c0d734 , 0x00b4e10c ] )
+  , Mont::unsafe_make( [ 0xb3a4bf9d , 0x488df4c2 , 0x7e875060 , 0xd7ed8690 , 0x3cb16f42 , 0x6a8b6fc3 , 0x84137cec , 0x2b5b78c3 ] )
+  , Mont::unsafe_make( [ 0xf7b6d73b , 0x2625ea92 , 0x0d8d47b3 , 0x4f17e3ad , 0xdb364462 , 0x9870098b , 0xc659f66f , 0x266b1c08 ] )
+  , Mont::unsafe_make( [ 0x8c0aec60 , 0x1b04658c , 0x9cf6c68b , 0x702b794c , 0x62f4c1ed , 0xb9ad57c9 , 0xcd36d395 , 0x1584a16e ] )
+  , Mont::unsafe_make( [ 0xc755d650 , 0xfa4016b0 , 0x8ab92667 , 0x26cb0934 , 0x6a06c8bb , 0xb77ec844 , 0xf1aa6ee3 , 0x1977c21b ] )
+  , Mont::unsafe_make( [ 0xae3b1cbd , 0x85d4b0c5 , 0xeab53121 , 0x432a9663 , 0x8f2c6d0b , 0x4a8c8018 , 0x0a62414c , 0x272eea2d ] )
+  , Mont::unsafe_make( [ 0x58f4a9c4 , 0x3e464e85 , 0x17bba94a , 0x93b32cce , 0xa69bf768 , 0xa23efa12 , 0x1bed0c63 , 0x219733ff ] )
+  , Mont::unsafe_make( [ 0xc2a0cd66 , 0xf3817bfb , 0x6ba5f4f4 , 0xe07da941 , 0x4c1d52e5 , 0x3290d3b8 , 0x4af833cd , 0x2b4a07d3 ] )
+  , Mont::unsafe_make( [ 0xa91e756c , 0x2816deb9 , 0xbcb2f431 , 0xa6db8dab , 0x184cf55f , 0x148cfa5e , 0x19ea6176 , 0x2c163669 ] )
+  , Mont::unsafe_make( [ 0x712fd50b , 0xe9cdc3f4 , 0x1ca4950e , 0x8ba3580b , 0xefa3cf89 , 0x286d0124 , 0x00a8fb21 , 0x136f0ab9 ] )
+  , Mont::unsafe_make( [ 0xea337cfb , 0x42dd06ee , 0x2f9f450d , 0x180f9491 , 0x40d1a9f3 , 0xbd446ead , 0x6e5eda99 , 0x0a3caf78 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// external mixing matrix (`t x t`, flattened)
+pub const CONST_M: [Mont; 100] = 
+  [ Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xd875fb22 , 0x372f2323 , 0x92812c17 , 0xb4ef2c39 , 0x89d9be07 , 0x871ba831 , 0x2c2e6979 , 0x24588e72 ] )
+  , Mont::unsafe_make( [ 0x01bf2c50 , 0x00bc7b5d , 0xf0cc2e81 , 0xc86b7f68 , 0x17b3cb53 , 0xca25bf44 , 0x8071c26e , 0x1b11d441 ] )
+  , Mont::unsafe_make( [ 0x48be060d , 0xb83b552c , 0x4da5fe06 , 0x2e718fca , 0x79766130 , 0x68415b34 , 0x37f02a28 , 0x2189bc24 ] )
+  , Mont::unsafe_make( [ 0x5c96863a , 0xdf63b236 , 0xcb7bff29 , 0xce3cb53d , 0x834ed903 , 0x3a3ac1eb , 0xc3e290d6 , 0x28451bdf ] )
+  , Mont::unsafe_make( [ 0xbc3815e8 , 0xc49850d4 , 0xb1935a0f , 0xd5599594 , 0x035a56b8 , 0x61359db3 , 0x0e9d27fa , 0x05509f7c ] )
+  , Mont::unsafe_make( [ 0x2eaed9fc , 0xb96da9f3 , 0xa021891f , 0x5a0b00ac , 0xd1f5c38f , 0xa97071bf , 0xaec0cf4d , 0x14079885 ] )
+  , Mont::unsafe_make( [ 0x5e701ac5 , 0x25dff87e , 0xefb95a87 , 0x530ac173 , 0x614e2da1 , 0xfc42aee5 , 0x874d220b , 0x0530451b ] )
+  , Mont::unsafe_make( [ 0x3b7d8f46 , 0x8bb19281 , 0xf61d3aed , 0xb787c384 , 0xe2aec775 , 0x30a0ec55 , 0xd41e2c1c , 0x0d15d245 ] )
+  , Mont::unsafe_make( [ 0x20407978 , 0x73c50cf7 , 0xfa48429a , 0x4ee1f71c , 0xb06c1e32 , 0x72c1d149 , 0x998301f6 , 0x2971e89f ] )
+  , Mont::unsafe_make( [ 0xd956f40d , 0xd9533b43 , 0x366c40f4 , 0xb1063b7f , 0xd598ba9b , 0xce833899 , 0xa86c5a6a , 0x2005d175 ] )
+  , Mont::unsafe_make( [ 0x9983a771 , 0xf6e4e3e6 , 0xffcd6ec9 , 0x6798e214 , 0xa36a9cd4 , 0x4b7c7488 , 0xfca240e1 , 0x0c57bec8 ] )
+  , Mont::unsafe_make( [ 0x63daae51 , 0xc499c39e , 0x3f14da0f , 0x61f10317 , 0x5f698466 , 0xbdd51305 , 0x0e3bf37a , 0x0c0d4ea3 ] )
+  , Mont::unsafe_make( [ 0xbbcd7917 , 0x784bf3a1 , 0xafbaeebe , 0x295ad952 , 0x3833c264 , 0x90d7e8f2 , 0xa1c1e1d5 , 0x28f094ff ] )
+  , Mont::unsafe_make( [ 0xa5c1c4e2 , 0x3758531e , 0xda4fe5b7 , 0x26861d50 , 0x81f208a2 , 0x809e1bdd , 0xbdb05672 , 0x00d45b31 ] )
+  , Mont::unsafe_make( [ 0xdbfc350e , 0x68a4af5e , 0xee9eccda , 0xbdeb82c5 , 0xe27e9329 , 0x6cd1ee0a , 0x12cbd69d , 0x0f12d708 ] )
+  , Mont::unsafe_make( [ 0x25b9b7cc , 0xa9891290 , 0xd667cbc6 , 0xe98c3176 , 0x50af2a81 , 0xa74419e8 , 0xaeaae883 , 0x12b2073c ] )
+  , Mont::unsafe_make( [ 0x192d3bf4 , 0xd4ce2237 , 0x821c2b18 , 0x8c71538a , 0xde88a8bb , 0xe6f97276 , 0x896641e8 , 0x15e8242f ] )
+  , Mont::unsafe_make( [ 0x1b4ea066 , 0x4b30ce0b , 0x20329dcf , 0x24840eb9 , 0x057c5f5b , 0x5c2274e8 , 0xfd19f45d , 0x04ebdc27 ] )
+  , Mont::unsafe_make( [ 0xe5a57d58 , 0xc29d01fd , 0xab4a4966 , 0x8af1d2d9 , 0xf74b2b52 , 0x8bd9610e , 0xee65a583 , 0x1cffd170 ] )
+  , Mont::unsafe_make( [ 0x2b8b458d , 0x13f686c5 , 0xa025f2c8 , 0xe366f395 , 0x08ee7b88 , 0x3455b274 , 0x25e98a83 , 0x2d309603 ] )
+  , Mont::unsafe_make( [ 0x1180014a , 0x90c24a96 , 0xa1bc1155 , 0xc9f87fac , 0x6f863882 , 0x9e17e595 , 0x8c1867a5 , 0x01a29ee7 ] )
+  , Mont::unsafe_make( [ 0xdad50a13 , 0xa1195d1c , 0xc9a1a7db , 0xab07e591 , 0x61a85b7c , 0xbe12af46 , 0x352c5e98 , 0x0e73b483 ] )
+  , Mont::unsafe_make( [ 0xcb2ee4dc , 0x41d3fe57 , 0xcdbd18ea , 0xbcd20e26 , 0xd3a97749 , 0x64649236 , 0x0abd1555 , 0x0a4eb4d0 ] )
+  , Mont::unsafe_make( [ 0x7cef0438 , 0x0bb6c52f , 0xd6636179 , 0xf2ba9c09 , 0xf66337f7 , 0xa7d7ba2a , 0xd0ba89f5 , 0x0e1876a5 ] )
+  , Mont::unsafe_make( [ 0xcc7945ad , 0xb692a8af , 0x9980cc1d , 0x44003f6b , 0xf3b46c7c , 0x449bfc6c , 0x329a71a9 , 0x0d7a6f21 ] )
+  , Mont::unsafe_make( [ 0x0c4f9809 , 0x8a07d859 , 0x825efe2b , 0xfc6558ae , 0xf27c03b3 , 0xa55e2677 , 0x140dd9f2 , 0x17cdc7b4 ] )
+  , Mont::unsafe_make( [ 0xda95b849 , 0xa77e9ec7 , 0x1942e4fc , 0x08cd5974 , 0xc7416b4f , 0xde6db21a , 0xc1a488db , 0x059ab3df ] )
+  , Mont::unsafe_make( [ 0x63be0a3e , 0xc79ecb68 , 0x9c2aaaed , 0x7bfb4e71 , 0x43883512 , 0x9b66e3f9 , 0x7e3bc567 , 0x05e43a47 ] )
+  , Mont::unsafe_make( [ 0x7872fc25 , 0x20bf1706 , 0xd6e3121f , 0x9443b00f , 0x53baafd2 , 0x9fe110be , 0x01208038 , 0x2c76a3fd ] )
+  , Mont::unsafe_make( [ 0x18e51fc0 , 0xe6be830c , 0x35f4727e , 0x83c28bda , 0x34963ce3 , 0xf6807175 , 0xea8d6bd2 , 0x1861b3ae ] )
+  , Mont::unsafe_make( [ 0x20f9246a , 0x55d5f69d , 0x56945e19 , 0x56fc1ae4 , 0xc08646d5 , 0x7d85d065 , 0xbbf3a8ad , 0x288d1a46 ] )
+  , Mont::unsafe_make( [ 0x6350242a , 0xd4d7bd66 , 0x7dd489d7 , 0x2aa094cc , 0x4b5a736a , 0x81cca5a3 , 0xda2ea345 , 0x25e354b4 ] )
+  , Mont::unsafe_make( [ 0x04d52829 , 0xd3be436c , 0xd1d403dd , 0xc9acb448 , 0x4b4a7006 , 0xea040d4f , 0x2914d237 , 0x1ecc574f ] )
+  , Mont::unsafe_make( [ 0xeeb48f1c , 0x68fe3bed , 0x28d3ad82 , 0x2234dfe2 , 0x90427cfb , 0x2e910729 , 0x1f354083 , 0x00edcf5f ] )
+  , Mont::unsafe_make( [ 0xbed6bdfd , 0x6a4de08e , 0x7a32cc38 , 0xe89cd637 , 0xf1b4d5b4 , 0x5e2d27da , 0x6a1817c1 , 0x02295006 ] )
+  , Mont::unsafe_make( [ 0x3b64d57e , 0xae1a26f0 , 0x56aad075 , 0x018a8d25 , 0xf4c9c477 , 0xb58af95a , 0x4b469d37 , 0x1a14799a ] )
+  , Mont::unsafe_make( [ 0x914a2076 , 0xb1d417c0 , 0xcf23b769 , 0x4aa2116e , 0x9fb9a5d8 , 0x60ead53b , 0x1088fc52 , 0x1f28da87 ] )
+  , Mont::unsafe_make( [ 0xc94b1b7e , 0xf43160cc , 0x982309bb , 0xa1397e0c , 0xb4853cb7 , 0x7cb06f0c , 0x15258557 , 0x212e38cc ] )
+  , Mont::unsafe_make( [ 0x9bab100d , 0xceff8b35 , 0x99e20171 , 0x359d1e6e , 0x6e4f8ad1 , 0x99c5443c , 0x2f3e1096 , 0x1fdd949a ] )
+  , Mont::unsafe_make( [ 0x045e73ac , 0x49a790f7 , 0xa3403e46 , 0x3f10b6c9 , 0x36b02605 , 0x7da32a03 , 0x61ebc2b5 , 0x11586125 ] )
+  , Mont::unsafe_make( [ 0xd0a68fdf , 0x8a4e396c , 0xc7e7fd3d , 0x25da024e , 0x83a2defc , 0xb75794bc , 0x50894e0e , 0x077a6783 ] )
+  , Mont::unsafe_make( [ 0x5b8dec6d , 0xa2139a5e , 0x8c41edea , 0x2461214c , 0x99ca47f9 , 0x4fb371b1 , 0x09f41a40 , 0x0ca11be8 ] )
+  , Mont::unsafe_make( [ 0x02438be3 , 0xf7b73f1a , 0x36865fcf , 0x439bbef1 , 0x12290816 , 0x65b9f674 , 0x037d7fb5 , 0x22e89c61 ] )
+  , Mont::unsafe_make( [ 0x4e70b0df , 0x68beed63 , 0x93a9e93c , 0xc5172a58 , 0xe7c7fceb , 0x632e4f84 , 0x2db579ba , 0x0b678388 ] )
+  , Mont::unsafe_make( [ 0x3b1e23b5 , 0xc27cbf8e , 0xa6eac357 , 0xae76c456 , 0x3b9fc055 , 0xd07304bb , 0x610732d9 , 0x1d72d753 ] )
+  , Mont::unsafe_make( [ 0x4f0ea535 , 0xe4480b16 , 0x3dd55e9f , 0x032601d6 , 0x7423465c , 0x0b16cf6a , 0x96625031 , 0x18735d6c ] )
+  , Mont::unsafe_make( [ 0xe3baa8a2 , 0x898b1f08 , 0x14b37a52 , 0xa6f94a8c , 0x1829003c , 0x5a410253 , 0x2a773740 , 0x2a298ce8 ] )
+  , Mont::unsafe_make( [ 0x2a652b5f , 0xa92f5af6 , 0x3883deea , 0x3a5580c0 , 0x8b884f26 , 0x6e3fac22 , 0xce37d19e , 0x14d54bfc ] )
+  , Mont::unsafe_make( [ 0xbfa64b6b , 0x4e26b5ce , 0xa37aad74 , 0xed6d87cb , 0xcfc28326 , 0xec607771 , 0x1979959a , 0x07531eb4 ] )
+  , Mont::unsafe_make( [ 0x15598ea0 , 0xdd124fcb , 0x010a5ec1 , 0x194b2e74 , 0x42bcd5f6 , 0xfabc0a2a , 0x87abf288 , 0x0efcd581 ] )
+  , Mont::unsafe_make( [ 0x4e86e33f , 0x37f20f69 , 0xb0cd07f3 , 0x2aa4b326 , 0x44d4b3e7 , 0xc3c9a7fe , 0xe879296c , 0x14fc82e1 ] )
+  , Mont::unsafe_make( [ 0xee43112a , 0x49906d09 , 0x44e47b37 , 0x3b34fa7f , 0x237b2a77 , 0xe1a8ba8e , 0x4ff7ee38 , 0x1a0d1b35 ] )
+  , Mont::unsafe_make( [ 0x2b5e1621 , 0xa73ed4c0 , 0xf771a151 , 0x07c7d3fb , 0x3cb4a309 , 0x4b3a72bb , 0xd394508e , 0x2687ac3b ] )
+  , Mont::unsafe_make( [ 0x00534523 , 0x6b1e7e7e , 0x386b1974 , 0x8f23b6fa , 0xf5b3e43c , 0x76196583 , 0x1894fe12 , 0x0f29cf8f ] )
+  , Mont::unsafe_make( [ 0xd7900fce , 0xbad67583 , 0x942d0144 , 0x6a7edac0 , 0x822a7ece , 0x39f1243e , 0xaabdd3c5 , 0x10920370 ] )
+  , Mont::unsafe_make( [ 0xd213f808 , 0xce9a3146 , 0x5cb09672 , 0x71f5ccf4 , 0xb4557d8e , 0x7116b151 , 0x1788029e , 0x1da96fe0 ] )
+  , Mont::unsafe_make( [ 0x1a893c37 , 0xc816c186 , 0xb2ae71a7 , 0xd706b008 , 0x7caa3060 , 0xab0daa86 , 0xcca38692 , 0x122ddf6e ] )
+  , Mont::unsafe_make( [ 0x444dd2e6 , 0xf07d372f , 0x8e916cba , 0x34dcfe4c , 0x356cb0af , 0xbb832dbf , 0xa02e681b , 0x177c79d7 ] )
+  , Mont::unsafe_make( [ 0x159b24df , 0xb1eaa1b8 , 0xe05d53be , 0xedf04462 , 0xe933fa10 , 0x4c0e4f2d , 0xe8d53a45 , 0x05279a2e ] )
+  , Mont::unsafe_make( [ 0xaccbf573 , 0x3532dcf9 , 0xf8d8a66a , 0x83a4cea4 , 0x13df3f6a , 0x753b242e , 0xc5c5b929 , 0x0b3e7b43 ] )
+  , Mont::unsafe_make( [ 0x706f9049 , 0x44a34f11 , 0xfa7dff10 , 0x596ef913 , 0x41d7b34d , 0xd23d9b53 , 0xc21af902 , 0x0af070c3 ] )
+  , Mont::unsafe_make( [ 0xc4641cb3 , 0x37c41d97 , 0x2ff29873 , 0x2f8603af , 0x484690e9 , 0x05551393 , 0x163fe953 , 0x0f78ba0f ] )
+  , Mont::unsafe_make( [ 0x7f390c76 , 0x1118f91d , 0xa1732468 , 0x8ab4798e , 0xe3bd1439 , 0xb4f925e7 , 0xd59c0593 , 0x0736da89 ] )
+  , Mont::unsafe_make( [ 0x65c0013b , 0xc6d3d442 , 0xf14d158d , 0x89e36f3d , 0x931f2a50 , 0xdb81a97b , 0x8dfe6c88 , 0x23434cf9 ] )
+  , Mont::unsafe_make( [ 0x08da5b8d , 0x21dc2bc4 , 0x4645f18e , 0xee9c114f , 0x2486c1a8 , 0xaadd2fe9 , 0x925a887f , 0x24e51c00 ] )
+  , Mont::unsafe_make( [ 0xb27d46df , 0xbf6a1c0a , 0x60ec023d , 0x7cd5d572 , 0xb0fd27a5 , 0xd29a0716 , 0xe3b4a8c2 , 0x1bfccea9 ] )
+  , Mont::unsafe_make( [ 0x505f54ea , 0x237d5595 , 0x84d24072 , 0x2369aa03 , 0x7a83da9d , 0x8f7dd270 , 0x168bd88f , 0x02d540c6 ] )
+  , Mont::unsafe_make( [ 0xfcc973f4 , 0x0ac337c4 , 0x91c39a47 , 0xa698e6c7 , 0xfda58893 , 0x9c695765 , 0xd52ced90 , 0x082c4da1 ] )
+  , Mont::unsafe_make( [ 0xfef67487 , 0xd361d4e6 , 0x6bf1e22f , 0x4f06b8f2 , 0x29739e16 , 0xf74d15f8 , 0xe96effb3 , 0x141ff392 ] )
+  , Mont::unsafe_make( [ 0xb0313427 , 0x1f8dfcac , 0xd3fbe630 , 0x7ffcd155 , 0x720b19c4 , 0xebab2742 , 0x922ac7a9 , 0x11a6354b ] )
+  , Mont::unsafe_make( [ 0x337045a9 , 0xd9f7fc4f , 0xf261168f , 0xe0a89d5e , 0xa88f7070 , 0x1921e578 , 0xa14d449a , 0x147a6d59 ] )
+  , Mont::unsafe_make( [ 0x5c1f5d56 , 0x8762d49e , 0xa9615af9 , 0x9432928b , 0x2a677258 , 0xa34f504d , 0xfcfb669f , 0x0b4a666a ] )
+  , Mont::unsafe_make( [ 0x211984a5 , 0x9a6ed32e , 0x588b10fd , 0x14073396 , 0xeeeea679 , 0x9b6ebb77 , 0x0f5045b6 , 0x0bf6c1d2 ] )
+  , Mont::unsafe_make( [ 0xd9b7101d , 0x9fb49ae3 , 0x70bc31bc , 0x7d9ccd4c , 0x0ca4029e , 0xf54a7969 , 0x499ac714 , 0x25a56d94 ] )
+  , Mont::unsafe_make( [ 0x137db9c4 , 0x2209949e , 0x7a741a8e , 0x7e2887e2 , 0xc414c479 , 0xed5a0fc4 , 0x789d882b , 0x1cc0aebf ] )
+  , Mont::unsafe_make( [ 0x4bf36f90 , 0x282442d0 , 0xc07a0d0e , 0x56f85992 , 0x1a3abe0b , 0x928181f7 , 0xb0df1ee5 , 0x1bbe224a ] )
+  , Mont::unsafe_make( [ 0x5a5f0a0e , 0x1625d5a2 , 0x9578b050 , 0x4761b6af , 0xd2cf0b0c , 0x88280091 , 0xeffede29 , 0x02153246 ] )
+  , Mont::unsafe_make( [ 0x9f2f0209 , 0x9842e5f5 , 0x00d0edba , 0xb2b0f8ca , 0x31cbd2c4 , 0x8b99bf70 , 0x11e860e4 , 0x1394c56f ] )
+  , Mont::unsafe_make( [ 0x6ba7cd8f , 0x931b4793 , 0xd5300d59 , 0xc6f4177b , 0x97aa0023 , 0x90a0919e , 0x20ff173c , 0x128fc090 ] )
+  , Mont::unsafe_make( [ 0xdba82121 , 0x050d5497 , 0x9f8a94eb , 0x73eeef0e , 0x4cbf122a , 0x897e65fa , 0x555fd0b9 , 0x2adf55e0 ] )
+  , Mont::unsafe_make( [ 0x583a513f , 0x7ecddb72 , 0x211f40f4 , 0xbee7ccd9 , 0x4c4cbc44 , 0xfab3d7be , 0xc8fee20b , 0x2804110b ] )
+  , Mont::unsafe_make( [ 0xd394422e , 0xff7d3da9 , 0x406cd130 , 0x46cf06fa , 0x0886b17f , 0xe49bc464 , 0x404ce43b , 0x1aaa0b53 ] )
+  , Mont::unsafe_make( [ 0xdb0337e3 , 0xa6f0dfcf , 0xb7425470 , 0xafbe2245 , 0xfe1cf1ba , 0x96579b37 , 0x1db049ad , 0x2109f450 ] )
+  , Mont::unsafe_make( [ 0x5a5bf40a , 0x6200a4b2 , 0x7b17480f , 0x62c9c471 , 0x383fcb86 , 0x5e4e2bd1 , 0xeb31d422 , 0x1c6a5ae1 ] )
+  , Mont::unsafe_make( [ 0x287462a4 , 0x5ac36fa4 , 0x478ee9cd , 0xb06193df , 0x9926a83f , 0x2d758e08 , 0xc6da5814 , 0x095c9853 ] )
+  , Mont::unsafe_make( [ 0xec93827e , 0xccbef3b6 , 0x45df515a , 0x5744e3ca , 0x35dc45a0 , 0x973bdf9f , 0xfbd7dba6 , 0x2b26d622 ] )
+  , Mont::unsafe_make( [ 0xc5b3c5ce , 0x7dde72de , 0x2704330b , 0x31b84caa , 0x24da34b8 , 0xd6a31b0b , 0xa59cb916 , 0x2055d62e ] )
+  , Mont::unsafe_make( [ 0x29c53c45 , 0xd67e5788 , 0xa22354bf , 0xc9344d35 , 0x59e65f95 , 0xbe5672b7 , 0x52e99adb , 0x0e104498 ] )
+  , Mont::unsafe_make( [ 0x3a1f968b , 0x00408ab2 , 0x9aa56e44 , 0xaa61495d , 0x24cc5ee4 , 0xa27b2462 , 0x888fd4d3 , 0x1bd9b471 ] )
+  , Mont::unsafe_make( [ 0xfbb2fc88 , 0xcbd9ea6f , 0x4c80e144 , 0x6743057e , 0x50f7e32b , 0xc743f26e , 0x6b14f6b7 , 0x1d5e77a3 ] )
+  , Mont::unsafe_make( [ 0xe6c0c001 , 0x15fca76b , 0xd66ce222 , 0x68f6ad52 , 0xb241d571 , 0x55fa3e81 , 0xa708eeef , 0x1f306421 ] )
+  , Mont::unsafe_make( [ 0xe7747c51 , 0xe232dab9 , 0xc1cb9a22 , 0x1a78fbf4 , 0x155d6f16 , 0x33e522ae , 0x50f2ce5f , 0x121ae1a1 ] )
+  , Mont::unsafe_make( [ 0xf3c85079 , 0x5e28e749 , 0x9f25cb11 , 0x7cf246aa , 0x605a74a0 , 0xd1500cb5 , 0x1434c774 , 0x121c19df ] )
+  , Mont::unsafe_make( [ 0xab2ef6e9 , 0xfb695822 , 0x7bef9f34 , 0x6d41fb9d , 0x2218bc0d , 0x772f0ff6 , 0xb255d287 , 0x1722dfd3 ] )
+  , Mont::unsafe_make( [ 0xe9c2bc24 , 0xcd5e2b3d , 0x1c671123 , 0x495721d8 , 0x3694f009 , 0x96ee20c7 , 0x7e16bfd2 , 0x0e76d17a ] )
+  , Mont::unsafe_make( [ 0x761f4450 , 0x7e321c12 , 0xcab1baa9 , 0x27063a3e , 0x94298bd9 , 0x925d04c6 , 0xc20a2ec7 , 0x2e44842b ] )
+  , Mont::unsafe_make( [ 0xa604abe3 , 0xba7e0017 , 0xdd8fb3c8 , 0xff556717 , 0xa222ffc2 , 0xadd39983 , 0xfe5cc4c3 , 0x250583a2 ] )
+  , Mont::unsafe_make( [ 0xd54c8e9e , 0xa242df48 , 0xc7138b1c , 0x377130f6 , 0x02e7653c , 0x02006e15 , 0x4f79b392 , 0x0cc448dd ] )
+  , Mont::unsafe_make( [ 0xa8daaf84 , 0x8a9f31c4 , 0x722ced2c , 0xa83cf155 , 0x54c2fcf8 , 0x401339b0 , 0xed3229ae , 0x054b2fdb ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// internal mixing matrix (`t x t`s, flattened)
+pub const CONST_P: [Mont; 100] = 
+  [ Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xcf4cf29c , 0xde992cd2 , 0xdca491c0 , 0xcb326295 , 0xcf2d4218 , 0x0b19f419 , 0xb6eaf1a3 , 0x1eee5d57 ] )
+  , Mont::unsafe_make( [ 0x9a7bb42d , 0xe2dd2efa , 0x46120960 , 0x4ec1d779 , 0xbc6ac0f0 , 0x6151b360 , 0xe14affe3 , 0x04851d7f ] )
+  , Mont::unsafe_make( [ 0x5ba531fa , 0x2309e3b6 , 0x623856c7 , 0x520f25d6 , 0x983886c0 , 0x5e9b9aa0 , 0x2eeffd8e , 0x1df3f6d2 ] )
+  , Mont::unsafe_make( [ 0x983292f9 , 0x3b8f189b , 0x6390d056 , 0xc50e4d83 , 0x8b3d8c71 , 0xa5b0fa44 , 0xc4763edb , 0x11646057 ] )
+  , Mont::unsafe_make( [ 0x271dbc9e , 0x85718e1f , 0xfcee98a3 , 0x8a385fbf , 0x94d42ba4 , 0xab834381 , 0xb1fd82cd , 0x1551baeb ] )
+  , Mont::unsafe_make( [ 0x6341c861 , 0x9f9f1791 , 0x3724b386 , 0xacc2ceab , 0x4b27e346 , 0x54b617ff , 0x7e5e42cb , 0x22d62b2c ] )
+  , Mont::unsafe_make( [ 0x9d13ef91 , 0x3ea410be , 0x6f3d76a0 , 0xdee7c8c3 , 0xc0e0228a , 0x32f320d4 , 0x43d986a5 , 0x2e921656 ] )
+  , Mont::unsafe_make( [ 0x6e2e01d9 , 0xe4d97b11 , 0x61e92f65 , 0xab4aa139 , 0x9f8bd948 , 0x693cdb67 , 0xe50cc616 , 0x09a62b90 ] )
+  , Mont::unsafe_make( [ 0xde156854 , 0xdcc9e8e6 , 0x8c294004 , 0x5c34ed26 , 0xa5007cfe , 0x7ea7dfd7 , 0x2d133c73 , 0x187c70fc ] )
+  , Mont::unsafe_make( [ 0xd956f40d , 0xd9533b43 , 0x366c40f4 , 0xb1063b7f , 0xd598ba9b , 0xce833899 , 0xa86c5a6a , 0x2005d175 ] )
+  , Mont::unsafe_make( [ 0xaa638aaa , 0xe966e969 , 0x6f458f18 , 0xca0ba260 , 0x76c576a6 , 0x474dc63e , 0x673ec874 , 0x022e4574 ] )
+  , Mont::unsafe_make( [ 0x90a3272e , 0x177ca39f , 0x3db0803a , 0x37910814 , 0x5080abdf , 0x628f7519 , 0xd35ed186 , 0x1f6006ef ] )
+  , Mont::unsafe_make( [ 0x9d57c568 , 0xb7e08b51 , 0x9f2c7555 , 0xa0257a84 , 0x59c5fe20 , 0x456ccca5 , 0x3249e137 , 0x1fadc66e ] )
+  , Mont::unsafe_make( [ 0xcb0543d9 , 0xea177999 , 0x2ead1398 , 0x0b87acee , 0x96e3a12f , 0xf017ed4f , 0xf64a69a2 , 0x1ec9e967 ] )
+  , Mont::unsafe_make( [ 0x49f5420b , 0x2e9f38ab , 0x2978abbe , 0xe882584a , 0xd419d7fc , 0x1512debb , 0x867d0e37 , 0x2034bf65 ] )
+  , Mont::unsafe_make( [ 0xd4100d8c , 0xcd000383 , 0xa906a7b3 , 0x3fb7fbdb , 0xf515d4d1 , 0x6cd246c5 , 0xdacff0bc , 0x15588e34 ] )
+  , Mont::unsafe_make( [ 0xa58dab4e , 0xbe213f42 , 0x474c6ceb , 0x443a466a , 0x02d7a910 , 0x8a3a0138 , 0x51384e42 , 0x1dcc4095 ] )
+  , Mont::unsafe_make( [ 0xf327051a , 0x0c7fd191 , 0x188e25a9 , 0xa9362225 , 0xe3ba7192 , 0x4beb76fd , 0xfe1c5fd7 , 0x192c9960 ] )
+  , Mont::unsafe_make( [ 0xd06400da , 0xc7d04cba , 0x3bdcc0ed , 0x4909f3fa , 0xf532dfa9 , 0xbb59ea07 , 0xf4b3af97 , 0x2540ca59 ] )
+  , Mont::unsafe_make( [ 0x2b8b458d , 0x13f686c5 , 0xa025f2c8 , 0xe366f395 , 0x08ee7b88 , 0x3455b274 , 0x25e98a83 , 0x2d309603 ] )
+  , Mont::unsafe_make( [ 0x8098b8da , 0xd20505d1 , 0x03966019 , 0x7ad59c9d , 0xb77ba42c , 0x459c0513 , 0x57844809 , 0x07cb8f5a ] )
+  , Mont::unsafe_make( [ 0x9ed190a2 , 0x141bae15 , 0x18064868 , 0x05e68b6f , 0x58d27473 , 0x42a8edac , 0x076c2009 , 0x1355152b ] )
+  , Mont::unsafe_make( [ 0xc57adefb , 0x3a81f447 , 0x92610f57 , 0xe22f9fd7 , 0x5647498b , 0x05305b56 , 0xcc32b945 , 0x1f03d43b ] )
+  , Mont::unsafe_make( [ 0x9c9ad753 , 0xc481f0f7 , 0xb60955cb , 0xb5142ba2 , 0x40bf760c , 0x26af8dd3 , 0x3982a8e9 , 0x18ad4893 ] )
+  , Mont::unsafe_make( [ 0xe5b72196 , 0xe75b9089 , 0x8eda4707 , 0x5f9b2dfe , 0xdd391334 , 0xcef2a814 , 0x4c1b0618 , 0x219d33a5 ] )
+  , Mont::unsafe_make( [ 0x683ed34e , 0x2a35e73b , 0x796d47b3 , 0x816c5fc2 , 0x14db3036 , 0x5929666f , 0x0ed75ec5 , 0x18888a6f ] )
+  , Mont::unsafe_make( [ 0xbf845d42 , 0x0f6fe3bc , 0x5beac75a , 0xe5ae91c6 , 0x3ea24d24 , 0x52226b48 , 0x6a0cba45 , 0x22b70749 ] )
+  , Mont::unsafe_make( [ 0x274318cb , 0xe3edd865 , 0x0a825692 , 0x21c5c04c , 0xd5d915ee , 0x36b8558e , 0x09b25db0 , 0x29d882be ] )
+  , Mont::unsafe_make( [ 0xa5e614c2 , 0x8d8c3421 , 0x2899732a , 0x286d69b7 , 0x920fb7bb , 0x90ff5957 , 0xf5a2605f , 0x2f391962 ] )
+  , Mont::unsafe_make( [ 0x18e51fc0 , 0xe6be830c , 0x35f4727e , 0x83c28bda , 0x34963ce3 , 0xf6807175 , 0xea8d6bd2 , 0x1861b3ae ] )
+  , Mont::unsafe_make( [ 0xd854581d , 0x8b52a4e3 , 0x26a30443 , 0xa895b3c2 , 0x17de56aa , 0xacf61ce9 , 0xb96784fd , 0x23d20096 ] )
+  , Mont::unsafe_make( [ 0x0a616962 , 0xb2be4c60 , 0xb3d1b05b , 0xca7df242 , 0xf86b2246 , 0x9ce24c10 , 0xb0f73ef0 , 0x02ad8942 ] )
+  , Mont::unsafe_make( [ 0x56df4c09 , 0xee30812a , 0x8f4ff4e2 , 0xf715a228 , 0x94396827 , 0x9c560daa , 0x2df760c3 , 0x0cd0b5c3 ] )
+  , Mont::unsafe_make( [ 0x369774e7 , 0xda3963c3 , 0x6a960968 , 0x621d8ba2 , 0x5f1c2de3 , 0xd5af8e34 , 0xfae24fed , 0x178b7896 ] )
+  , Mont::unsafe_make( [ 0x0b91ea32 , 0x1ec01f93 , 0x89c770aa , 0xc05cd111 , 0xf77308b0 , 0x4151ef40 , 0xaa1de7e6 , 0x14e5c9c8 ] )
+  , Mont::unsafe_make( [ 0x3df79019 , 0x5ff41079 , 0xcb6c48a0 , 0xb9225239 , 0x6fd707af , 0xd5db989f , 0xb40328b7 , 0x0e3d59d9 ] )
+  , Mont::unsafe_make( [ 0x72f076e7 , 0xc681e627 , 0xf8810309 , 0x592d391f , 0x6fc6c492 , 0x308e6324 , 0xcfd32e96 , 0x21566c42 ] )
+  , Mont::unsafe_make( [ 0x57c3ffde , 0xccc15184 , 0xb3a9b4cd , 0x71c29d55 , 0xf7ad6fcc , 0xeb685398 , 0x4c0d4bf7 , 0x2b7965fb ] )
+  , Mont::unsafe_make( [ 0xc2b31045 , 0xd46efc23 , 0xa15bb31f , 0x649b33f7 , 0x2956d9f5 , 0xaf2ad962 , 0x4053377e , 0x21a431a5 ] )
+  , Mont::unsafe_make( [ 0x045e73ac , 0x49a790f7 , 0xa3403e46 , 0x3f10b6c9 , 0x36b02605 , 0x7da32a03 , 0x61ebc2b5 , 0x11586125 ] )
+  , Mont::unsafe_make( [ 0x1fe89c06 , 0x704d3560 , 0x2f934010 , 0xb09e18d4 , 0xa76ec2c0 , 0xfb9d9b31 , 0xc76f5e09 , 0x1dcca6c0 ] )
+  , Mont::unsafe_make( [ 0x649e11c1 , 0x2ed77626 , 0x6a4e3c8b , 0xd672ed53 , 0xf7a38c71 , 0xddf62b00 , 0x68c97605 , 0x2b2eb481 ] )
+  , Mont::unsafe_make( [ 0x7719b9eb , 0xcb8b1e9f , 0x6958f6d7 , 0xd29fc70d , 0xfb76c095 , 0xa959dacc , 0x710ca595 , 0x2856bc0f ] )
+  , Mont::unsafe_make( [ 0x3becc3d1 , 0xd353f12e , 0x0547cc83 , 0x41282388 , 0x95007171 , 0x081017d6 , 0x5066e774 , 0x17b7ccbd ] )
+  , Mont::unsafe_make( [ 0xf32dc0ae , 0xad7e9e50 , 0x6c87789b , 0xe551b6bb , 0x8edcf996 , 0x90e19878 , 0xf6c0de11 , 0x03d8ee1d ] )
+  , Mont::unsafe_make( [ 0x26622b48 , 0xcf62bd0e , 0x20b79e69 , 0x9f3f726f , 0xd7d04a6a , 0x2568261f , 0x2ae74757 , 0x2063fdb4 ] )
+  , Mont::unsafe_make( [ 0xc88291d3 , 0xdac55275 , 0xc02a83fa , 0x894765d3 , 0xb994e97e , 0x06d1eb95 , 0x48b64bad , 0x0e91a0bb ] )
+  , Mont::unsafe_make( [ 0xef6df597 , 0xf63889b1 , 0x0f642e85 , 0xbe1a436b , 0x2504671f , 0x65a9b47d , 0xae01d598 , 0x2d50affa ] )
+  , Mont::unsafe_make( [ 0x90a332de , 0xaecde011 , 0x1948da64 , 0x30316b5d , 0x0a7cdf27 , 0xeb0d69a4 , 0xda94510e , 0x2f13a445 ] )
+  , Mont::unsafe_make( [ 0x15598ea0 , 0xdd124fcb , 0x010a5ec1 , 0x194b2e74 , 0x42bcd5f6 , 0xfabc0a2a , 0x87abf288 , 0x0efcd581 ] )
+  , Mont::unsafe_make( [ 0x640fe09a , 0x87270e1e , 0x45f23544 , 0xf8e24360 , 0xb3191f37 , 0x6dfbc0e9 , 0xd29baa57 , 0x0788830e ] )
+  , Mont::unsafe_make( [ 0xbeb570c4 , 0xf878b9c5 , 0xc0711183 , 0xb7cf0499 , 0x7d0c257c , 0x2c0d6111 , 0xa959f45e , 0x097cadb9 ] )
+  , Mont::unsafe_make( [ 0x78b1fe17 , 0x9d4cc193 , 0xbe113e76 , 0xa96dd77d , 0x8700e847 , 0xbbc4874c , 0x2829d090 , 0x154e3723 ] )
+  , Mont::unsafe_make( [ 0x50062b42 , 0x4a3424e6 , 0xdb383316 , 0x3e3735e5 , 0xc4a5df56 , 0xc7e4d0f5 , 0x8de6270f , 0x04d6b497 ] )
+  , Mont::unsafe_make( [ 0xd1c9cfaa , 0x58057395 , 0x011718e5 , 0x72836703 , 0xe31b72aa , 0x169c28f4 , 0x2cc1fb3a , 0x1d55fe20 ] )
+  , Mont::unsafe_make( [ 0xec0e243a , 0x22ae80b7 , 0xb4925a70 , 0x5443d515 , 0x5fb5b51b , 0x55b9a326 , 0x2de95fef , 0x0bd799f2 ] )
+  , Mont::unsafe_make( [ 0x55d191de , 0x2c29e2f9 , 0x2a587157 , 0xd329d2bc , 0x66512da5 , 0x96cf10b5 , 0xcc54b208 , 0x17bd8cbe ] )
+  , Mont::unsafe_make( [ 0xffac0318 , 0x62f7425a , 0xe1fe4973 , 0xa2e8326f , 0xfb2c1239 , 0x6d1d7e72 , 0x59ebc1ce , 0x0f317073 ] )
+  , Mont::unsafe_make( [ 0x22cd3c8c , 0x87125706 , 0x786db0c4 , 0x9ad1aec4 , 0x0c800110 , 0x92b6ffb8 , 0x3b0d8018 , 0x222578a5 ] )
+  , Mont::unsafe_make( [ 0xaccbf573 , 0x3532dcf9 , 0xf8d8a66a , 0x83a4cea4 , 0x13df3f6a , 0x753b242e , 0xc5c5b929 , 0x0b3e7b43 ] )
+  , Mont::unsafe_make( [ 0xeaab75bd , 0x2276d350 , 0x264d8313 , 0x752b4e5c , 0x98eca6ac , 0x18d8640b , 0xcafb142c , 0x05f44671 ] )
+  , Mont::unsafe_make( [ 0xe995fdbe , 0x2a5d42f6 , 0xc876e645 , 0x31770436 , 0x486d9a2d , 0x1d0a3b58 , 0xb9d5d9ac , 0x28f0736c ] )
+  , Mont::unsafe_make( [ 0x322372e0 , 0xc9da7111 , 0x3d21e982 , 0xd029fa2f , 0x78cb54dc , 0xd51b3db9 , 0xe872ddea , 0x14142258 ] )
+  , Mont::unsafe_make( [ 0xeb496a74 , 0x07fbc915 , 0x425e28e1 , 0x4bbc7e4c , 0xc8aa7a3e , 0x813a2f0c , 0x2706e8e4 , 0x25cf5ba0 ] )
+  , Mont::unsafe_make( [ 0xcaa85340 , 0x0fb8c6bc , 0x7efcfda6 , 0xbc5c6fdf , 0x8a2bcee0 , 0x10c0bd23 , 0x070fbba6 , 0x06120abe ] )
+  , Mont::unsafe_make( [ 0x7bc133a8 , 0xc1547bc8 , 0x6f09bafd , 0x4741d9a9 , 0xafecff6c , 0xc6119a4b , 0xabddb497 , 0x136b4674 ] )
+  , Mont::unsafe_make( [ 0x54fabbe4 , 0x9baf9e68 , 0x7a7804d9 , 0xb1950f0f , 0x2497ef22 , 0x0034ebef , 0x1a32f9a8 , 0x0e638de5 ] )
+  , Mont::unsafe_make( [ 0x5405b87b , 0x3d5966b9 , 0xfafc3a0b , 0x31fdac7f , 0x19bede0d , 0x2899a34b , 0xf2ddaf4f , 0x10153578 ] )
+  , Mont::unsafe_make( [ 0xf4e12b7d , 0xf1549a88 , 0xd735c987 , 0x9cd7e666 , 0x2a5a2281 , 0xdd0d011d , 0xfa3152b3 , 0x09e3533a ] )
+  , Mont::unsafe_make( [ 0xb0313427 , 0x1f8dfcac , 0xd3fbe630 , 0x7ffcd155 , 0x720b19c4 , 0xebab2742 , 0x922ac7a9 , 0x11a6354b ] )
+  , Mont::unsafe_make( [ 0x46dd8286 , 0x2c6c26c6 , 0x9f1db835 , 0xc9d5a122 , 0x31907690 , 0x3b9d740a , 0xc8ced20b , 0x17bd575d ] )
+  , Mont::unsafe_make( [ 0x32afcbe5 , 0x4e7a0254 , 0x64a6e266 , 0xb6c03dbd , 0x3ce8c2e6 , 0x6f31c969 , 0xdce42ecd , 0x28f0e446 ] )
+  , Mont::unsafe_make( [ 0x54f0511c , 0x6aee1d42 , 0xce51177c , 0x8b587477 , 0x5de9fca7 , 0x702099ff , 0x7b62c4a0 , 0x13e2379b ] )
+  , Mont::unsafe_make( [ 0x5dccf752 , 0xe352134f , 0x6998be18 , 0x235a8f69 , 0xe50188fc , 0x982fa905 , 0x4de15904 , 0x239a2785 ] )
+  , Mont::unsafe_make( [ 0x8f14ec2a , 0xc6574a57 , 0x37d74f91 , 0x09182fa2 , 0x4fdea00b , 0x85ca153a , 0x0f7dda56 , 0x1e8e175e ] )
+  , Mont::unsafe_make( [ 0x43bceb6d , 0xafe30e47 , 0x7c6c0028 , 0x99bd3037 , 0xd8b8e6cb , 0x79f9c99a , 0x3fcad759 , 0x1888fc9f ] )
+  , Mont::unsafe_make( [ 0x1e41df58 , 0x67683477 , 0x95796b29 , 0xc6a626ab , 0xfb2dba63 , 0x617f1aaf , 0xfd9d8c05 , 0x0eb24128 ] )
+  , Mont::unsafe_make( [ 0x19746931 , 0x68fdb3ca , 0xc0ca305f , 0x84553393 , 0x88dcb880 , 0x26c604a9 , 0x2cc7b006 , 0x24ccf993 ] )
+  , Mont::unsafe_make( [ 0xd70d684f , 0xc2de0b9b , 0xec8dcac1 , 0xb333b680 , 0xa1a9b6de , 0x0ea1d05e , 0xb0eb6356 , 0x254c549e ] )
+  , Mont::unsafe_make( [ 0xdba82121 , 0x050d5497 , 0x9f8a94eb , 0x73eeef0e , 0x4cbf122a , 0x897e65fa , 0x555fd0b9 , 0x2adf55e0 ] )
+  , Mont::unsafe_make( [ 0x20cb034d , 0x1cfd298a , 0x034c34a1 , 0x513574a1 , 0x07b4fa44 , 0x3d6830f4 , 0x4cee0fb1 , 0x28c07b31 ] )
+  , Mont::unsafe_make( [ 0x7fb07cf9 , 0xf204cfd0 , 0xedb8716d , 0xcd30897c , 0x584ce108 , 0x856e03d7 , 0x3f9f1b93 , 0x0c46eda9 ] )
+  , Mont::unsafe_make( [ 0xbba8c363 , 0xb640eb7e , 0xdd1de89b , 0xc82682e6 , 0xc42d2576 , 0x479277c9 , 0x2a79b435 , 0x29c3f0b6 ] )
+  , Mont::unsafe_make( [ 0x4f696446 , 0x39371017 , 0x6bf69a7a , 0x06b60ebf , 0xa017c0e4 , 0x8c986f32 , 0xcfd5a590 , 0x25d38573 ] )
+  , Mont::unsafe_make( [ 0x57ee6d28 , 0xcdff2ac8 , 0x81ca1573 , 0x7388b50e , 0x366159ee , 0x55a3d122 , 0x67bb5bb4 , 0x0313d9bf ] )
+  , Mont::unsafe_make( [ 0x5c71415f , 0xdc39add7 , 0x026d0c70 , 0x73e5db06 , 0x679f60dc , 0xeb2e88e6 , 0x43098d9c , 0x2538c777 ] )
+  , Mont::unsafe_make( [ 0x46a32040 , 0x371538ee , 0x62e2bc79 , 0x9ccdd02f , 0xe7bae45c , 0x26ff6d1e , 0x882eb257 , 0x102a3193 ] )
+  , Mont::unsafe_make( [ 0xa9b4ca35 , 0xacd7d029 , 0x6b2131b7 , 0xa1128da4 , 0x8a819aaa , 0x1e72aa5b , 0x703c2ec1 , 0x1df23999 ] )
+  , Mont::unsafe_make( [ 0x17bdab8b , 0xf11064e7 , 0x36459359 , 0x214eb720 , 0x7f6c60a1 , 0x9377d826 , 0x3e10d247 , 0x1837ddcb ] )
+  , Mont::unsafe_make( [ 0xfbb2fc88 , 0xcbd9ea6f , 0x4c80e144 , 0x6743057e , 0x50f7e32b , 0xc743f26e , 0x6b14f6b7 , 0x1d5e77a3 ] )
+  , Mont::unsafe_make( [ 0x74f5fd11 , 0x273641f8 , 0x2b530798 , 0xccca1460 , 0x68c1067a , 0x6de1072b , 0x07940ad7 , 0x1465e7ed ] )
+  , Mont::unsafe_make( [ 0x120a5553 , 0x31f0a147 , 0x75bf37ec , 0xbafba4e3 , 0x5b6ed056 , 0x4cdb5429 , 0xd8c045e8 , 0x151bfcde ] )
+  , Mont::unsafe_make( [ 0x0c416b56 , 0xa0c7480f , 0x362bdb99 , 0x4094abf7 , 0x831bd67b , 0x0404d76a , 0xbafea540 , 0x12da5f23 ] )
+  , Mont::unsafe_make( [ 0x93d1c590 , 0x71dcdd60 , 0x76e47d2c , 0xa521223a , 0xaa43ec81 , 0x702614e3 , 0x71f8841e , 0x28912782 ] )
+  , Mont::unsafe_make( [ 0x86bd8a87 , 0x238af860 , 0x00ae1094 , 0x7a0b20cb , 0x105ccf6b , 0x5a2f65b7 , 0xa85f423a , 0x2fde2ef5 ] )
+  , Mont::unsafe_make( [ 0xef92b3a9 , 0x5232480e , 0xd7260c60 , 0xf953398a , 0xfd76c2fd , 0x8fbccf4d , 0x819b4bcd , 0x01f4938a ] )
+  , Mont::unsafe_make( [ 0x46bfe953 , 0x99da92c8 , 0x78571d42 , 0xaf952fde , 0x085fd222 , 0xc5bf003b , 0x63e798e3 , 0x030ea7f9 ] )
+  , Mont::unsafe_make( [ 0x36981b82 , 0x8a00a17b , 0xee52e507 , 0xfde3f1b8 , 0x0a4f06ee , 0x527a9fe8 , 0x353b5411 , 0x17cb81d0 ] )
+  , Mont::unsafe_make( [ 0x07efc8ba , 0x5b1f4d47 , 0x014d435c , 0xdc88951c , 0x46fa317b , 0x17e669c8 , 0x8c558910 , 0x1a284faf ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// circomlib's optimization whatever
+pub const CONST_S: [Mont; 1140] = 
+  [ Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x44ee2684 , 0x6cd2f6be , 0x1398c801 , 0x2bb9402a , 0xd7fbc498 , 0xd02fe7a2 , 0xe405ccfc , 0x0ac2c9a0 ] )
+  , Mont::unsafe_make( [ 0x9c27186f , 0x2f54819b , 0x7fa98d7c , 0x7ca4bdab , 0x77dcd529 , 0xfc1a42cd , 0x07d16be1 , 0x29ca3a0d ] )
+  , Mont::unsafe_make( [ 0x9c875657 , 0x65402416 , 0x03abae39 , 0x660e1f15 , 0xb3f0d183 , 0x2f2771d4 , 0xf1f80d55 , 0x03cc79dd ] )
+  , Mont::unsafe_make( [ 0x171e73ea , 0x99025459 , 0xc5632c62 , 0x2ed49674 , 0x7b57363f , 0xfa01535c , 0x01903b0d , 0x0a91ae84 ] )
+  , Mont::unsafe_make( [ 0xb37b6173 , 0xeaff90a2 , 0x937a46aa , 0x37b1e4a5 , 0x31f8158c , 0x0bd52a39 , 0x2e3343f4 , 0x1035fddd ] )
+  , Mont::unsafe_make( [ 0xd03808fa , 0x5b6e81be , 0xdfb3b1ae , 0x15f4ad21 , 0xd4e6aac7 , 0xd1c81768 , 0x12974ce3 , 0x17fe03a2 ] )
+  , Mont::unsafe_make( [ 0x9adce8f7 , 0xb4773370 , 0x508af310 , 0xfe692692 , 0x84c5279d , 0xe8b28754 , 0x545f9e49 , 0x27239567 ] )
+  , Mont::unsafe_make( [ 0xafaaa064 , 0x698e8153 , 0x8b1b47e5 , 0x7ffedc2f , 0x9133a334 , 0xaaf06834 , 0xd6e6523a , 0x094399a8 ] )
+  , Mont::unsafe_make( [ 0x16f46b39 , 0xccbac801 , 0x9a0b2196 , 0x347c3214 , 0x1cfaf64e , 0xa5eedd3c , 0x092c9ee4 , 0x1d75c339 ] )
+  , Mont::unsafe_make( [ 0x84037f9b , 0xa7b62c5a , 0x1f026264 , 0x31308544 , 0xfd9685e4 , 0x1cba584b , 0x63ae1269 , 0x01b98d4b ] )
+  , Mont::unsafe_make( [ 0x99371695 , 0xe4711952 , 0xbbeb7649 , 0x16dbd51d , 0x9af65000 , 0xdcbe00cd , 0x6c45003a , 0x11d4bfc2 ] )
+  , Mont::unsafe_make( [ 0xcc8e980f , 0xa965d714 , 0xb83e7f64 , 0xaa608e57 , 0x6c0ca12c , 0xae3d1233 , 0xd7f51b94 , 0x0ef13dac ] )
+  , Mont::unsafe_make( [ 0x8ef56da9 , 0xda09dd1a , 0x71af0611 , 0x9bdfdb31 , 0xa279f432 , 0x9fac5c60 , 0x57ebcd7d , 0x161c0ded ] )
+  , Mont::unsafe_make( [ 0x2631ed11 , 0xbb317462 , 0x393fe12d , 0x2262c28b , 0xddcfab01 , 0x01f14747 , 0xab01adbd , 0x2a1125ee ] )
+  , Mont::unsafe_make( [ 0x8143259e , 0x937ada5d , 0x983613ec , 0x7a61d925 , 0x261ab023 , 0x4b9db277 , 0xcfa9426b , 0x099ddefd ] )
+  , Mont::unsafe_make( [ 0xe9f30ed4 , 0x5fdb1e00 , 0x592953ca , 0x866c429d , 0x7d694e61 , 0xb1675bc6 , 0x3d15f4fe , 0x20e6569b ] )
+  , Mont::unsafe_make( [ 0x2dd3b924 , 0xe4af71b6 , 0x4881181b , 0x86f22cf3 , 0x824df785 , 0x998a0969 , 0x47e4f0b7 , 0x19b20c3b ] )
+  , Mont::unsafe_make( [ 0xbf4a5ba8 , 0x2383f844 , 0x2cf5c910 , 0x82004d93 , 0x21e47ebd , 0x1300b3cd , 0x33b17544 , 0x0db2bf99 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x9f1ba5db , 0x8d0bb3df , 0xae22f05f , 0x0b544261 , 0x5b8d7cee , 0x7ca13044 , 0x7b8b544e , 0x048f0ade ] )
+  , Mont::unsafe_make( [ 0xc1e1f00e , 0x30d7edae , 0x525e0634 , 0x552fb574 , 0xefa16b3d , 0x570c6a89 , 0x331bba04 , 0x197c5454 ] )
+  , Mont::unsafe_make( [ 0xbd21cee4 , 0x51476fb3 , 0xe77fa1ad , 0x14e01d6f , 0xe56c8901 , 0xd8e625a8 , 0x0403dc88 , 0x2c1ace18 ] )
+  , Mont::unsafe_make( [ 0xee2e2b96 , 0x0f370fbe , 0x309deb81 , 0xcac8868f , 0x26392c03 , 0x837b1283 , 0x7bdafd5f , 0x0d5d6c0f ] )
+  , Mont::unsafe_make( [ 0xf1910c2f , 0xd5015c9f , 0xb9a9fcd2 , 0xb9d17413 , 0x9267d20b , 0x0447e6f0 , 0xf59c7101 , 0x1a2d20ad ] )
+  , Mont::unsafe_make( [ 0x835d3f96 , 0x544c22f9 , 0x18110824 , 0x959ab268 , 0xa9ff3f21 , 0xd25b9c83 , 0xbce80df9 , 0x12a7d2e7 ] )
+  , Mont::unsafe_make( [ 0xbe12a47d , 0x386ff2ba , 0x36f9a5f7 , 0x5a485a34 , 0xc5369874 , 0x87eeafa9 , 0xd16d3502 , 0x079a2203 ] )
+  , Mont::unsafe_make( [ 0x09d6e97e , 0xa945e7e8 , 0x84f6a2d1 , 0x058a252b , 0x47b05eb1 , 0x9550915e , 0xe215d93e , 0x155a116f ] )
+  , Mont::unsafe_make( [ 0xaa13701a , 0x500b8103 , 0xf811b8a5 , 0x3d73db87 , 0x5898e8af , 0x15de0a95 , 0x06a5fa84 , 0x19949746 ] )
+  , Mont::unsafe_make( [ 0x7060d623 , 0xbd24c387 , 0x4581af9f , 0x3a16cab9 , 0x54e9fffe , 0xcf304a06 , 0x3787e80e , 0x29b336d8 ] )
+  , Mont::unsafe_make( [ 0x5a4c8750 , 0x2c105b7f , 0xd3293c8f , 0xf3b9d7fd , 0x3fdb205d , 0x458fd788 , 0xb3214ddd , 0x12206e75 ] )
+  , Mont::unsafe_make( [ 0x58368f2e , 0x6a97829f , 0xeb2c4b07 , 0x54e031b0 , 0xfe27e3a1 , 0x85afc577 , 0x6e735471 , 0x298f955d ] )
+  , Mont::unsafe_make( [ 0x94c22437 , 0x30f6b184 , 0x5874f1f8 , 0x8452efbf , 0x6b837c24 , 0x5312ee35 , 0xfb125697 , 0x00244d8c ] )
+  , Mont::unsafe_make( [ 0xc52023a3 , 0xc5f1a1a3 , 0x8a33209a , 0x6e695fb0 , 0xe510c214 , 0x0ad67ed4 , 0x814d1043 , 0x0604f25d ] )
+  , Mont::unsafe_make( [ 0x3a117251 , 0x14ea0d13 , 0x74c6f167 , 0x7c5913b9 , 0xb94b5c0e , 0x5eea3079 , 0xd1af9c77 , 0x24997b78 ] )
+  , Mont::unsafe_make( [ 0x261a761b , 0x068c46a0 , 0xef51ecb5 , 0x59ff69fb , 0x37588104 , 0xd93c1353 , 0x36d74639 , 0x1428519d ] )
+  , Mont::unsafe_make( [ 0xdfbc749c , 0x2d401820 , 0x541e8954 , 0xba0b4f99 , 0x0573788d , 0x234f7c0b , 0xc086e16e , 0x2be84370 ] )
+  , Mont::unsafe_make( [ 0xeb539d1b , 0x8b806763 , 0x46a1b18b , 0xedcdecec , 0xeed46bb9 , 0xce34ab8a , 0xe906cf92 , 0x07b9ffea ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x76c4b0e0 , 0xf858bb88 , 0x9f13a14b , 0x16d41347 , 0x1229729e , 0x4a3cf64e , 0x9d821612 , 0x0141719a ] )
+  , Mont::unsafe_make( [ 0xdaf52069 , 0x932b9ebe , 0x7ba5dcd5 , 0x53885880 , 0x0bd2ce56 , 0x85282ba3 , 0x613976f1 , 0x0c822fc2 ] )
+  , Mont::unsafe_make( [ 0xa9d4acb4 , 0x1f43e02a , 0x795e7342 , 0x2a61cc5b , 0xd07c57fd , 0x6869e82f , 0x6e7bcec7 , 0x24937998 ] )
+  , Mont::unsafe_make( [ 0xd688a82d , 0x4f243848 , 0x7e5a768b , 0xfcfa9383 , 0x0bc27867 , 0x510ae231 , 0x8b24c387 , 0x20569933 ] )
+  , Mont::unsafe_make( [ 0xbeb79954 , 0x67fa85ce , 0xca16ca9e , 0x3a52576a , 0x8830b018 , 0x4c85b0f4 , 0x1db7452c , 0x2aa179ce ] )
+  , Mont::unsafe_make( [ 0x22bcbbc2 , 0x699e6426 , 0x785dd29f , 0xb1bc9f18 , 0xfa6e1ff3 , 0x5d3edf3b , 0xb48ce52f , 0x05ecc6ec ] )
+  , Mont::unsafe_make( [ 0xcabe270e , 0xd380de2c , 0x27dcdc26 , 0x962dc7c8 , 0xab290569 , 0xa2246ee5 , 0x095f9e39 , 0x244df554 ] )
+  , Mont::unsafe_make( [ 0x3c540051 , 0x43e709e6 , 0x135fa345 , 0x837c5ed2 , 0xe4000d1c , 0x75d5893a , 0x0c22679a , 0x195c5af6 ] )
+  , Mont::unsafe_make( [ 0x25bfdff0 , 0x2daa12f1 , 0x04392f1e , 0x1fdede58 , 0xb82fd6d4 , 0x400c0b41 , 0x468fd63b , 0x02e0c615 ] )
+  , Mont::unsafe_make( [ 0x8835b222 , 0x1338cd69 , 0x4c977be3 , 0xebac42eb , 0x9c34764b , 0xb46d013f , 0x14207d8a , 0x03226f00 ] )
+  , Mont::unsafe_make( [ 0x5a4e57b6 , 0xd2c771f5 , 0x8985026b , 0xd3410adf , 0xec53eae5 , 0xc429b502 , 0x9b68d389 , 0x14585a15 ] )
+  , Mont::unsafe_make( [ 0xe7693919 , 0x65144969 , 0x02813e0f , 0x052f9088 , 0xcd95ac1f , 0xe0492177 , 0xaa6d0e01 , 0x06f352c2 ] )
+  , Mont::unsafe_make( [ 0x7e1e1953 , 0x83ab7671 , 0x960fefd1 , 0xf42b148d , 0x48f5f69f , 0x27a98aba , 0x28894cdc , 0x0a51bb68 ] )
+  , Mont::unsafe_make( [ 0x89ca2f5f , 0xd2acd8b0 , 0xfbc84e4d , 0x459d9df1 , 0xcb20dc3a , 0x24b3946d , 0x5d7e0c44 , 0x0e4dde2c ] )
+  , Mont::unsafe_make( [ 0x12ecc1f4 , 0x9a6577e9 , 0x50d32985 , 0x5a0b0260 , 0x5bb83506 , 0xacac1167 , 0x6f3b10f6 , 0x19740eae ] )
+  , Mont::unsafe_make( [ 0x60cdec28 , 0x40f1b517 , 0x3ed4f6d0 , 0xa6c3ae03 , 0xea3f2cae , 0x57ea2e53 , 0x19c7468a , 0x2b91442e ] )
+  , Mont::unsafe_make( [ 0x5176f415 , 0x19ca87e7 , 0x599fdc8e , 0xdc53fbbe , 0x9b5e7070 , 0x2c68e7e7 , 0xea43d78e , 0x2f7d0288 ] )
+  , Mont::unsafe_make( [ 0x3a5db8ba , 0xf2a1ddbb , 0x3df91ba6 , 0xe9a788b4 , 0xfab790e6 , 0x614389df , 0xc1ff5226 , 0x1ea28db1 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xa87bd11a , 0xd75c4347 , 0xe7223412 , 0x03eb15cc , 0x43640e69 , 0xcf3629ea , 0x19ce0ed0 , 0x003a0d94 ] )
+  , Mont::unsafe_make( [ 0x4590c90c , 0xaffff68c , 0x1a4f23aa , 0x1074233a , 0x96cf8e53 , 0xb8ea970d , 0xc886641b , 0x0f58b888 ] )
+  , Mont::unsafe_make( [ 0x835395e2 , 0xb5b819e9 , 0xdeddfd13 , 0xb6b3348d , 0x58e8332d , 0xc31f82d6 , 0x0fcd2168 , 0x2f871359 ] )
+  , Mont::unsafe_make( [ 0xa65a5f1a , 0xa50ae1ce , 0xf4f50f3d , 0x3118a39a , 0x75dba70b , 0x0cf9e0e0 , 0xbfc062fc , 0x1e6e0d0e ] )
+  , Mont::unsafe_make( [ 0x76b40b81 , 0xc32d577f , 0x5cdf9a1e , 0x448a510c , 0xcaa1b372 , 0x0de178da , 0xeeedcbbf , 0x21329094 ] )
+  , Mont::unsafe_make( [ 0x51442665 , 0xc0469143 , 0x7eaf46ee , 0xc36a582b , 0xac8b77dd , 0xb8e02306 , 0xa3b1b32e , 0x13473489 ] )
+  , Mont::unsafe_make( [ 0x0f275ea8 , 0xaa14c24a , 0x8eb2b987 , 0x07defd24 , 0x4caa5843 , 0x4addc7b1 , 0x55e3b2e2 , 0x2f3278fa ] )
+  , Mont::unsafe_make( [ 0xa32e4aad , 0xc56ccb16 , 0xac7222dd , 0x92d74ed3 , 0xdfe542ed , 0x8b039781 , 0xc34b1673 , 0x28b3e096 ] )
+  , Mont::unsafe_make( [ 0x2bf09028 , 0x0937d361 , 0xcf5a3cca , 0x8be6dc17 , 0x2101bd0a , 0x30349007 , 0x57c98172 , 0x1435aef5 ] )
+  , Mont::unsafe_make( [ 0xd076926f , 0xe2613d63 , 0x0e2b9711 , 0xcf1a8e33 , 0x98157817 , 0x8633998e , 0x994d16fa , 0x2e03493a ] )
+  , Mont::unsafe_make( [ 0xb9e5fa44 , 0xb243c1fb , 0x4794b0d3 , 0xec5e16c7 , 0x3be76815 , 0xbe29711f , 0xd44f6c28 , 0x005de5fb ] )
+  , Mont::unsafe_make( [ 0xebddeb83 , 0x18614cdb , 0xc15e4928 , 0xcebae593 , 0xb1475635 , 0xdc614b08 , 0xc05ad13f , 0x1ca9a8ec ] )
+  , Mont::unsafe_make( [ 0x0bdb8db2 , 0x72e3122e , 0xce033932 , 0xcf4f393f , 0xf74c9d69 , 0xd1da71be , 0x230b743a , 0x240722c1 ] )
+  , Mont::unsafe_make( [ 0x9a6e6765 , 0xc72f1a83 , 0x732d3f2c , 0x553bda1c , 0x563eec38 , 0x26ccbb8a , 0x13ace742 , 0x2469e111 ] )
+  , Mont::unsafe_make( [ 0xf9b03808 , 0x2d0fe774 , 0x2fc44bad , 0xa3ebfd6a , 0xa51ab9d8 , 0x3259ab65 , 0x25579e3b , 0x187f2b77 ] )
+  , Mont::unsafe_make( [ 0x41b63082 , 0x262fe34c , 0x5fd19900 , 0x353af6dc , 0x062e0a57 , 0x6841addc , 0x9cf2d597 , 0x10a8c129 ] )
+  , Mont::unsafe_make( [ 0x588fcedb , 0x876d7681 , 0xc199dd78 , 0x287d728c , 0x5bb2d53d , 0x7930b2e7 , 0xdba24f1e , 0x21af3cab ] )
+  , Mont::unsafe_make( [ 0x67ad2be0 , 0x004390e3 , 0x7fc0958d , 0x630be2e7 , 0x3f9bf63e , 0xac1b3ee9 , 0xd6362d0e , 0x018e2d1f ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xd7d37bf7 , 0xe3d8856d , 0xd646f1f2 , 0x550fbc27 , 0xbaecb942 , 0x2cec8dcf , 0x77d5deb3 , 0x162e7903 ] )
+  , Mont::unsafe_make( [ 0x36b5dc47 , 0x89853ec2 , 0x266de7a7 , 0x9459597f , 0x29c0f96c , 0xc9810714 , 0xdd680f3c , 0x16188bdd ] )
+  , Mont::unsafe_make( [ 0xf3dd108e , 0xe0bb4916 , 0x5d3edc40 , 0x8c0c7100 , 0xcd7f262c , 0x610c5ff3 , 0xcb0c3834 , 0x2c7e9bed ] )
+  , Mont::unsafe_make( [ 0x6b7c3561 , 0x8e2a4b93 , 0xa925dba7 , 0x610ac486 , 0x5dca8c87 , 0xc078c77f , 0x75a08618 , 0x2def331f ] )
+  , Mont::unsafe_make( [ 0x81f7b481 , 0xac0ab13c , 0xb468f75f , 0x68d1e5a2 , 0x00c54fea , 0xa343ed9b , 0xa28cac26 , 0x0a07af73 ] )
+  , Mont::unsafe_make( [ 0xea84c3f3 , 0x8923dffa , 0x84f94d84 , 0x0981bd32 , 0x03549ae6 , 0x8f670689 , 0x7f68e4c4 , 0x1dd100d9 ] )
+  , Mont::unsafe_make( [ 0x20384d17 , 0xd0d7ff3c , 0xc52a9087 , 0xccec0f78 , 0xa48f3bed , 0x9f51374c , 0x0d1804ad , 0x1656f13d ] )
+  , Mont::unsafe_make( [ 0xd88c16fb , 0xffb7a278 , 0xc86fab5e , 0x1445b1fc , 0x2469852f , 0xcf93bf1f , 0x00a7d211 , 0x157a7e44 ] )
+  , Mont::unsafe_make( [ 0xaa95994e , 0x9a2687c8 , 0xe3c93675 , 0x97555d0e , 0xfd3ffc42 , 0xa78912ff , 0xa09af69a , 0x282bb9d0 ] )
+  , Mont::unsafe_make( [ 0x38db0d0b , 0xf2f0583b , 0xfc80230e , 0x61661013 , 0xccae84e2 , 0xd148bf5e , 0x0014c1e8 , 0x122f2559 ] )
+  , Mont::unsafe_make( [ 0x54ca8573 , 0x5f0c31bd , 0x078da547 , 0x4a346212 , 0x6d55c376 , 0x34152829 , 0xa1043055 , 0x0e7c4623 ] )
+  , Mont::unsafe_make( [ 0xea274915 , 0x6465aebf , 0x3e743a80 , 0x82bf72df , 0xa601ae4a , 0x0f2a6d16 , 0x2a699245 , 0x0aaac746 ] )
+  , Mont::unsafe_make( [ 0xb346bf97 , 0x896ebdc0 , 0xd8ab5973 , 0xf1bd89c4 , 0xe5bf57c8 , 0x7c0fe945 , 0x67c698cf , 0x0a9c25e6 ] )
+  , Mont::unsafe_make( [ 0xe8f8b591 , 0x8ed0047f , 0x69ce198f , 0x5f9e785f , 0xceae255c , 0x8acc9afb , 0xe198b70f , 0x0254c2b0 ] )
+  , Mont::unsafe_make( [ 0xc8faec32 , 0x2474138c , 0x109f324c , 0x77a0e98d , 0x098f7e2d , 0x5a21da57 , 0x10383fc1 , 0x24f4c724 ] )
+  , Mont::unsafe_make( [ 0xf3d8d6bd , 0xac51b6f4 , 0x9ef569c4 , 0xe2aa60c1 , 0x1fc61a7f , 0xbb91ae23 , 0x7a7536fe , 0x1dc02e23 ] )
+  , Mont::unsafe_make( [ 0x44227979 , 0x7c68c957 , 0xd58fd7ee , 0x6c133ca4 , 0x1005d694 , 0xbf2ae760 , 0xd264d3c4 , 0x0545326c ] )
+  , Mont::unsafe_make( [ 0x3c67fbb7 , 0x7a9299c6 , 0x688a5020 , 0x9e7c7c2e , 0xc33f81a9 , 0xe52ac2c2 , 0x88fe3ffc , 0x25358880 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xf16168ed , 0x9829a119 , 0x5efba2fb , 0xbd0f8d3d , 0xa00c4154 , 0x6f31e96e , 0x124abc72 , 0x252028eb ] )
+  , Mont::unsafe_make( [ 0xe1c4ffc3 , 0xb107abe2 , 0x0668d482 , 0xd1968934 , 0x01827cfe , 0x6d1b42f3 , 0x579dfc6c , 0x1504e53e ] )
+  , Mont::unsafe_make( [ 0x3c6fc005 , 0x868d1297 , 0x457fcabb , 0x1990c0af , 0x8a961cb3 , 0x212a5bf0 , 0x2043af73 , 0x2191e856 ] )
+  , Mont::unsafe_make( [ 0xd095ed38 , 0x7ff29696 , 0x02ed20b0 , 0xa0ee3621 , 0xc22a6323 , 0x6abbb409 , 0x475fddbb , 0x07aa57c9 ] )
+  , Mont::unsafe_make( [ 0x2f9f3c0e , 0xec9f68e0 , 0x31ea9074 , 0x54bdff15 , 0x9b713237 , 0xc9d218da , 0x72de734e , 0x2e2dbd2f ] )
+  , Mont::unsafe_make( [ 0x86e4131a , 0x4d8bea52 , 0x4e12833c , 0x34235424 , 0xd6966c96 , 0x69745ed1 , 0xcbd455f7 , 0x11d281f4 ] )
+  , Mont::unsafe_make( [ 0xeb2eb999 , 0x75ec0ab3 , 0x72476012 , 0x9808e572 , 0xd59f7c77 , 0xa024f8ff , 0xe4927195 , 0x09e39ccc ] )
+  , Mont::unsafe_make( [ 0xe338d1c3 , 0x2ce67edf , 0x86da5fe5 , 0x728db5e2 , 0xb9ddbd62 , 0x3f0bcb53 , 0x5d05a121 , 0x1a60532b ] )
+  , Mont::unsafe_make( [ 0xc1473104 , 0xce5c4d5d , 0xcc3f1fb9 , 0x8585a9b3 , 0xebf3122b , 0xde3940db , 0xdcb660e8 , 0x0548331f ] )
+  , Mont::unsafe_make( [ 0x46f34e5e , 0xb0cae244 , 0xeb976ae6 , 0xe5016b80 , 0x23bcfad1 , 0x0645f7b0 , 0xa423b2f4 , 0x1a654f11 ] )
+  , Mont::unsafe_make( [ 0x91c6f5e0 , 0xfb99553e , 0x23686165 , 0xf7e12fd4 , 0x40f56a8f , 0x8816a65f , 0xcb0c8b55 , 0x106bfb6b ] )
+  , Mont::unsafe_make( [ 0xe4e82d20 , 0xe21351c9 , 0x7c770bed , 0x59e7e1f4 , 0x7cf92534 , 0xef022018 , 0x59ec6dd3 , 0x26b79440 ] )
+  , Mont::unsafe_make( [ 0x0a890886 , 0x68b5cf81 , 0x0a89eb5f , 0x79b5d6e0 , 0x921a7d1a , 0x9988739f , 0xcf9af8e8 , 0x25a733f2 ] )
+  , Mont::unsafe_make( [ 0xefb62285 , 0xa6d14023 , 0xdecbbb49 , 0x8a825c1d , 0xee47dd20 , 0xe2a8cb39 , 0x622c6ba0 , 0x2c113dd8 ] )
+  , Mont::unsafe_make( [ 0x16c7cbc4 , 0x1f546680 , 0x38f669f7 , 0x9edaae96 , 0xf68cf09c , 0xbac9021a , 0x684007bd , 0x2eb067b2 ] )
+  , Mont::unsafe_make( [ 0x461c36cc , 0x068e3586 , 0x60baaf6e , 0xd1168fb4 , 0x6ad4755b , 0x381c9c16 , 0x0e5520e0 , 0x0ece9a61 ] )
+  , Mont::unsafe_make( [ 0xecf1118e , 0xcc5e8727 , 0x28386a14 , 0xc429adb9 , 0xc411b1d0 , 0xa3efc122 , 0x8ed4e9e2 , 0x0f5636b0 ] )
+  , Mont::unsafe_make( [ 0x1893ad0d , 0x525519c1 , 0x55b65378 , 0x431c7414 , 0xe1b52ef9 , 0xaf242695 , 0x23c1b088 , 0x18665c2a ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xee10f37e , 0x98ab0b7b , 0x668e6397 , 0x12bc7005 , 0xe23450f1 , 0x67d1a6b7 , 0xa19b879e , 0x08ff6d18 ] )
+  , Mont::unsafe_make( [ 0x46fbb0c2 , 0xc314e569 , 0x282f8fc8 , 0x6b54da6e , 0x66d24324 , 0x280b1f05 , 0x19df6fc4 , 0x01396cfe ] )
+  , Mont::unsafe_make( [ 0x265dfb19 , 0x7e903913 , 0x3e5e87cf , 0x5aeec3dc , 0xa5b7b2d7 , 0x8e6528c1 , 0xccda1f60 , 0x292710fe ] )
+  , Mont::unsafe_make( [ 0xe4661c4a , 0xd894f9ec , 0xf8a07740 , 0xc16d8941 , 0x8d764c3d , 0x6f038ed0 , 0x7124dd0a , 0x212db624 ] )
+  , Mont::unsafe_make( [ 0x33ad3f45 , 0xadf32f8d , 0x748fbd92 , 0x0d9e672e , 0xd276ee93 , 0xf53f19b0 , 0x5e155a3e , 0x0f3d94f4 ] )
+  , Mont::unsafe_make( [ 0xc3b36f3b , 0x5677b212 , 0x8ba73485 , 0x1c70c0c0 , 0x299a6205 , 0xad01a376 , 0x47934f5a , 0x17708c44 ] )
+  , Mont::unsafe_make( [ 0x75ff6c8f , 0x66ca0b92 , 0x7e83d9a2 , 0xbb125ce1 , 0x72f3a700 , 0x3c37b3a2 , 0x9cd4b5a5 , 0x1587cf15 ] )
+  , Mont::unsafe_make( [ 0xace4355d , 0x747a4445 , 0x3dfd4ca2 , 0x10970a00 , 0x9e1b08d8 , 0x81f850e7 , 0x994f4cef , 0x1a003748 ] )
+  , Mont::unsafe_make( [ 0x22ad1086 , 0x617d0631 , 0xb4803d7f , 0x7abaa8d7 , 0xcd7d55ea , 0xda765313 , 0x03cc545b , 0x2fca4371 ] )
+  , Mont::unsafe_make( [ 0xc06a3235 , 0x1572fd5c , 0x13f88fb2 , 0x9848f55d , 0x15f0cdb1 , 0x32a7face , 0x0dd62928 , 0x2c269ad7 ] )
+  , Mont::unsafe_make( [ 0xd0e5cad8 , 0x1f7c7b5f , 0xd479e496 , 0x6afc33cb , 0x936179d1 , 0x862eaed4 , 0x076aaa00 , 0x1ff7bbd6 ] )
+  , Mont::unsafe_make( [ 0xb9b2c948 , 0x6b62c5d2 , 0x7b5ae503 , 0xc1db72ac , 0x91c949d3 , 0x909f5e47 , 0x56e2a77f , 0x04ac9da1 ] )
+  , Mont::unsafe_make( [ 0xff17b25a , 0x49b7f6ee , 0x1017cd98 , 0xd5ebecf4 , 0x9a82c859 , 0x60db0ac4 , 0xb952ffcb , 0x16b76e65 ] )
+  , Mont::unsafe_make( [ 0x3956e12b , 0x3dbf1c34 , 0x47ae499e , 0xebbe18ce , 0xc9747b39 , 0x7188036e , 0x4e71c6a0 , 0x1976eeaf ] )
+  , Mont::unsafe_make( [ 0x07d4ce8d , 0xc069e2f1 , 0x2a1a988d , 0x3f1eb789 , 0xea3d88ba , 0x8f2650f3 , 0x6903ab96 , 0x142f1ae3 ] )
+  , Mont::unsafe_make( [ 0x61d4a157 , 0xe48ac422 , 0xa919b49c , 0x1252d2c6 , 0x97d611d3 , 0x8d73e736 , 0xa336c09a , 0x267daf37 ] )
+  , Mont::unsafe_make( [ 0x8962df02 , 0xfa226bc4 , 0x797861a0 , 0xb5c0a529 , 0x5aefe4b8 , 0x79b39fdb , 0x0ed9d51a , 0x2fcc945b ] )
+  , Mont::unsafe_make( [ 0xc51af116 , 0xbda23dbd , 0xea2701a8 , 0xfe0a21c8 , 0x1bbc16e6 , 0x17ff0b83 , 0xcabe78fe , 0x2b580137 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xe4582910 , 0xf84d5415 , 0xfea1d88d , 0x013ca3d9 , 0xcac7f92c , 0x60a9e8f5 , 0x9276d515 , 0x1ade908e ] )
+  , Mont::unsafe_make( [ 0x661393da , 0xa9bd73f1 , 0x7d9b069a , 0xd642e6e1 , 0x262fcd8d , 0xf06cd46b , 0x61da3c47 , 0x256a2619 ] )
+  , Mont::unsafe_make( [ 0x6871b3f2 , 0xeebcc469 , 0x3ea6bf0a , 0xf86ba918 , 0x1423c874 , 0xa99c2a5d , 0x2d43c459 , 0x02fc8162 ] )
+  , Mont::unsafe_make( [ 0x223437cb , 0x6746a6e5 , 0x4e3d0857 , 0x617e916a , 0x2d0b2fb3 , 0xb307850f , 0x5989af3c , 0x15c79c8f ] )
+  , Mont::unsafe_make( [ 0xf13d48f9 , 0x95b99312 , 0xf50007d0 , 0x80c12e55 , 0x8a88b318 , 0x2e1244c7 , 0x047e3664 , 0x0d61a468 ] )
+  , Mont::unsafe_make( [ 0x8223b0f3 , 0xb21c30f2 , 0x10f3fe23 , 0x9164d21d , 0x073b239c , 0xfaa8316f , 0xb88b6ca7 , 0x12df2729 ] )
+  , Mont::unsafe_make( [ 0x69e786a0 , 0xe18da69d , 0x4d2c0f94 , 0x3aa6f68d , 0x729b6b6b , 0xe52a0ee5 , 0xf7eb1164 , 0x1a373187 ] )
+  , Mont::unsafe_make( [ 0x9cd0325c , 0x087df4a7 , 0xf73301dc , 0x497efe33 , 0xe108e093 , 0x31d19e26 , 0xaf724db8 , 0x230f865a ] )
+  , Mont::unsafe_make( [ 0x55daf32c , 0x13cf6179 , 0xc0f74358 , 0x7afbd92a , 0xf37d0ee3 , 0xa6d362cd , 0xa5e60132 , 0x28280eb5 ] )
+  , Mont::unsafe_make( [ 0xdef66707 , 0x11942562 , 0x0c10c3c7 , 0xd0b65b1f , 0x4a481a94 , 0x9eba8389 , 0xf64663a4 , 0x290b7691 ] )
+  , Mont::unsafe_make( [ 0xe0e222b5 , 0x41c6fc5a , 0xa8c82943 , 0xe6b391e7 , 0xe14d6251 , 0x3b145c46 , 0xb9fdaf16 , 0x09b9665e ] )
+  , Mont::unsafe_make( [ 0xc66efbb8 , 0x46fc6d20 , 0x6bd6f3a7 , 0x7b21c4dd , 0xfd8d4ae7 , 0xf85e540e , 0x62fc7ddd , 0x1be03693 ] )
+  , Mont::unsafe_make( [ 0x9d89bb44 , 0x64131049 , 0xdeaf1562 , 0x90e32b61 , 0x9f766ece , 0x34b1546d , 0xbd5db050 , 0x1039c989 ] )
+  , Mont::unsafe_make( [ 0x195c868e , 0x0eeb6f31 , 0xa2ae3428 , 0x9e4d847b , 0x152b1603 , 0xf202d29d , 0x0189ac59 , 0x10de5476 ] )
+  , Mont::unsafe_make( [ 0xc1041934 , 0x7ef5ce93 , 0x8b9fe15c , 0x8cc477aa , 0xf8a1b698 , 0x2e853b90 , 0x0625d924 , 0x15f55910 ] )
+  , Mont::unsafe_make( [ 0x5a5ee551 , 0x8191b3e6 , 0x11efabea , 0xfd66093c , 0x163ef514 , 0x10330652 , 0x15b5ce0b , 0x16b29989 ] )
+  , Mont::unsafe_make( [ 0x0b9bf2e5 , 0x1af8fc3d , 0xaa49a012 , 0x1c85c3cc , 0x0dc0f52a , 0xa047ad46 , 0xbf33723d , 0x2e25c647 ] )
+  , Mont::unsafe_make( [ 0xb1a7ef14 , 0xb5835fd2 , 0x85f60481 , 0x7b73c35c , 0xb07bd02c , 0xc3a16942 , 0x9fd5478e , 0x2e8d0d54 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x5a816291 , 0x79a1271f , 0x4d483b1c , 0xc22c6eae , 0x88f1b0bc , 0x68735f39 , 0xa8fd0e50 , 0x024e21b1 ] )
+  , Mont::unsafe_make( [ 0x951099b9 , 0xb9f11e7b , 0x2da2dc8c , 0x64fb15cf , 0x37dbbb6e , 0xd866dbfc , 0x5059fc88 , 0x1599bf85 ] )
+  , Mont::unsafe_make( [ 0x7fac9f20 , 0x0e8d6b64 , 0x8edd99b2 , 0x697bc5d2 , 0x79d23cb9 , 0x62ec2f56 , 0xa4c1d2b7 , 0x080802d6 ] )
+  , Mont::unsafe_make( [ 0xc19f5a74 , 0xf63d696e , 0x10c531ff , 0x84f4c368 , 0x123add72 , 0x91f9a3fd , 0x78400043 , 0x037e65d9 ] )
+  , Mont::unsafe_make( [ 0x41618701 , 0x32f3f7dc , 0x99c79a34 , 0x608af24a , 0x0b3bbead , 0x7bd6395d , 0xc632c7a1 , 0x2155643d ] )
+  , Mont::unsafe_make( [ 0xc0e70a89 , 0x18367d08 , 0xd39c33fd , 0x80bedc42 , 0xec687605 , 0xaac30a87 , 0xcf5a660d , 0x17ac8aa8 ] )
+  , Mont::unsafe_make( [ 0x23380371 , 0x34a8cf6f , 0x8ae1cfb7 , 0x7b065ad4 , 0x9b701d20 , 0x5feafb46 , 0xa94982fc , 0x2f6f68e7 ] )
+  , Mont::unsafe_make( [ 0xe75a5413 , 0x4d2b21f1 , 0xa895ce8c , 0xc0fd29cd , 0x61bbe714 , 0x8e2d10a3 , 0x0c297b64 , 0x1be2adbb ] )
+  , Mont::unsafe_make( [ 0x950200a5 , 0xafb2e3d4 , 0xa1f2f6f9 , 0xc52af1f7 , 0xdef845b4 , 0x05a4b21d , 0x0d204b38 , 0x00225248 ] )
+  , Mont::unsafe_make( [ 0x7e9bf989 , 0xcb1b0434 , 0xf1949e8c , 0xba817e12 , 0x8fcf2413 , 0x66b30e56 , 0x7842e091 , 0x03cacf15 ] )
+  , Mont::unsafe_make( [ 0x799ea709 , 0x031a98dd , 0x1b3c2386 , 0xb29ee4e9 , 0x5dfd439b , 0xca34c8ed , 0x7de8e715 , 0x20f55941 ] )
+  , Mont::unsafe_make( [ 0xde2dac9b , 0xe9650865 , 0x5225ddf3 , 0xa3d73597 , 0xb6ed14a6 , 0x3ccca81a , 0x71a2a528 , 0x069b3b17 ] )
+  , Mont::unsafe_make( [ 0xe15d2439 , 0xa46def51 , 0x5b2d3634 , 0x015652f7 , 0x5e69de69 , 0x5191d9d2 , 0x01c2f1f8 , 0x0b402c8c ] )
+  , Mont::unsafe_make( [ 0x86c6bc9c , 0xe49bdcda , 0x96503c9a , 0x0e8fa888 , 0x6cd0c8f7 , 0xc13b7ac7 , 0xf1be4235 , 0x04e9f845 ] )
+  , Mont::unsafe_make( [ 0x367c8ffe , 0x377f57d3 , 0x104d9637 , 0x32546a8c , 0x8bf529a8 , 0x59099b1f , 0x952ea954 , 0x0001fb24 ] )
+  , Mont::unsafe_make( [ 0x21789358 , 0x56bdb76a , 0xd3bfc1e6 , 0x29242549 , 0xd0126e66 , 0x934eced7 , 0x49bc2e01 , 0x2157121a ] )
+  , Mont::unsafe_make( [ 0x89de4427 , 0x33153b8d , 0xd04dc0e8 , 0x5f467a52 , 0x10c1e538 , 0xfcf03f0b , 0xef89eddc , 0x26d7679f ] )
+  , Mont::unsafe_make( [ 0x8984f1d2 , 0x7730fae1 , 0xa462bc4c , 0x1b7fce93 , 0x63cdd539 , 0x9fdae5e3 , 0x1268ba5b , 0x07567602 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xded76626 , 0xc65ff7d1 , 0x2aeb3b54 , 0xe82096ca , 0x6fdedf3b , 0x9a2ece93 , 0xf839b8d9 , 0x17d338ea ] )
+  , Mont::unsafe_make( [ 0x989e8333 , 0xd3ba4a49 , 0xfab3afbe , 0xfd726328 , 0xa0954eae , 0x930f309c , 0xc5f0a981 , 0x18a50a80 ] )
+  , Mont::unsafe_make( [ 0x27553589 , 0x18b3cea2 , 0x84fc6ec8 , 0xc6374485 , 0xfeda4675 , 0xf35f1925 , 0x8492788c , 0x299b7595 ] )
+  , Mont::unsafe_make( [ 0x9daedca6 , 0xec9c0f5b , 0x968bdb7f , 0x4a7778f2 , 0x8ceb6195 , 0x0719152b , 0x1c98cbcc , 0x15cd57bd ] )
+  , Mont::unsafe_make( [ 0x14d2393b , 0xacbb9b88 , 0x062e812c , 0xab34dc61 , 0xbcd1218e , 0xf28735df , 0xf6571d08 , 0x1e0cf6be ] )
+  , Mont::unsafe_make( [ 0x88bef7df , 0x7f9f0867 , 0x8fc88607 , 0xd984c205 , 0xf180d273 , 0x45b8e5da , 0x2766b545 , 0x2bea6196 ] )
+  , Mont::unsafe_make( [ 0xc8bc1dab , 0x3b8eda0e , 0xe08aebff , 0x12bd476c , 0x0604a7c2 , 0x1e765082 , 0x983ba841 , 0x2d8ff6e2 ] )
+  , Mont::unsafe_make( [ 0x6f8767fc , 0x89b03d40 , 0x4e166e70 , 0x912f10a4 , 0x1d00174a , 0x8ea8cf3a , 0x01e4015f , 0x15b70981 ] )
+  , Mont::unsafe_make( [ 0xd0bf0b36 , 0xb143459d , 0x599263aa , 0xf0d1639a , 0x1d788ecb , 0xe8d5aafa , 0x97d0a707 , 0x20281480 ] )
+  , Mont::unsafe_make( [ 0xc6536e1c , 0x81260385 , 0x0a0d2754 , 0xe18aafd6 , 0xbf8fabe9 , 0x72a27c68 , 0x3e925c06 , 0x2b7a83b2 ] )
+  , Mont::unsafe_make( [ 0x5b51d44c , 0x04b90dec , 0x89a01083 , 0xdf31b8e8 , 0x195cc976 , 0x4a062c33 , 0xc09c0092 , 0x2b5ff207 ] )
+  , Mont::unsafe_make( [ 0xee7f6a81 , 0xb1a1f3ed , 0x1f4a18fb , 0xf4150f82 , 0x0081067b , 0x225209c4 , 0xd600435d , 0x2d1fccb7 ] )
+  , Mont::unsafe_make( [ 0xbc347bee , 0xf8868952 , 0x41f78ce6 , 0x0b938713 , 0x77c7d0f7 , 0x7d1b711d , 0x684e3e92 , 0x13b09b0d ] )
+  , Mont::unsafe_make( [ 0x41890c6a , 0xe787e2ef , 0xc3873027 , 0x69490b56 , 0xee4727db , 0xbe5023ab , 0xafb8942f , 0x0572e6a2 ] )
+  , Mont::unsafe_make( [ 0x8572ef76 , 0x5492344c , 0xb2cdfcf6 , 0x2b1a4618 , 0x759771cd , 0x5257ed7d , 0xc4b86111 , 0x18d33b6a ] )
+  , Mont::unsafe_make( [ 0x9df73c5b , 0xae9eedf1 , 0x84ded4b1 , 0xc5e7aebb , 0xc8199599 , 0x7229fde1 , 0xe5b71a7f , 0x15b88918 ] )
+  , Mont::unsafe_make( [ 0xef508b4c , 0x8a4b6f6d , 0x512fe6e5 , 0xf0e3a5da , 0x948f0968 , 0x9f73a6d1 , 0xc18c79d4 , 0x2424c6d6 ] )
+  , Mont::unsafe_make( [ 0x1ab1b144 , 0xc9260339 , 0xe8ad5858 , 0xa235d44a , 0x399ea28b , 0x4948bfa3 , 0x516e8b8e , 0x27621466 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xbf5b437f , 0x11e290f1 , 0xf20cd0a4 , 0xc2617adf , 0xe496ee13 , 0x7b07fd46 , 0xcd2d12bd , 0x0cce9ba8 ] )
+  , Mont::unsafe_make( [ 0x2dafca56 , 0xb4f4bacf , 0x22f53851 , 0xf5867a6b , 0x35a6ddff , 0x62ccb35d , 0x0ea4f4b3 , 0x2eed4722 ] )
+  , Mont::unsafe_make( [ 0x81f10787 , 0x6c4511c1 , 0xbabc76b7 , 0x29f6af92 , 0x1b2569b8 , 0x3f869e8d , 0xd7567100 , 0x1bd6c4e0 ] )
+  , Mont::unsafe_make( [ 0xfc28c0f8 , 0xb32a4753 , 0x857280e0 , 0xd8671f57 , 0xc4b37d73 , 0xae766eff , 0x826234b4 , 0x12b175cb ] )
+  , Mont::unsafe_make( [ 0x8efb4dfb , 0xe7cab100 , 0x138eef66 , 0xee5d1437 , 0x3a210a24 , 0x20f64634 , 0x1cb4c017 , 0x2129d06f ] )
+  , Mont::unsafe_make( [ 0xa4c947bf , 0xbf144cd5 , 0x468d1097 , 0x8119df37 , 0xdd1ae442 , 0x8b127bc3 , 0x1c828e8c , 0x14da3fa7 ] )
+  , Mont::unsafe_make( [ 0xa899746c , 0xf6c2c682 , 0x78e4a982 , 0xab024c19 , 0x22400a25 , 0xd0d5df26 , 0x5817abb3 , 0x08c16b64 ] )
+  , Mont::unsafe_make( [ 0x0d4418af , 0xa8e1b541 , 0xdd7683c6 , 0x86938970 , 0x89b22864 , 0x8ff2627f , 0xa15f329a , 0x2007da88 ] )
+  , Mont::unsafe_make( [ 0xea68125f , 0xf6c2acec , 0xbb76e745 , 0xfab47b8f , 0x56db3122 , 0xe63ddda3 , 0x79bbf3f6 , 0x03f1d87a ] )
+  , Mont::unsafe_make( [ 0x15d852ce , 0xfcf73a16 , 0x772a6058 , 0x145a9ebe , 0x1c6c2d83 , 0xabb1f1ad , 0xcc550d85 , 0x0a95f3e1 ] )
+  , Mont::unsafe_make( [ 0x9f92f7cf , 0x4da681de , 0x14d656c9 , 0x063362a4 , 0x639da3ba , 0x8401e348 , 0x1a6d3955 , 0x1cf21e2b ] )
+  , Mont::unsafe_make( [ 0xa9b66ff2 , 0x991647c9 , 0x4e296ca7 , 0x7b514e34 , 0xe901b505 , 0xe57e0652 , 0x38af8261 , 0x1aff5e45 ] )
+  , Mont::unsafe_make( [ 0x986f0c90 , 0xe087c39d , 0xbba1d06b , 0x41d16a38 , 0x84df422a , 0x078aa826 , 0xc3990fd3 , 0x2a97efa0 ] )
+  , Mont::unsafe_make( [ 0x9fe0c3bb , 0x618d74eb , 0x40a10e40 , 0xe8dc8e96 , 0xd1863326 , 0xa88a5435 , 0xf059b3f9 , 0x06ba4292 ] )
+  , Mont::unsafe_make( [ 0x92ea7e82 , 0x78675578 , 0xd2f9bda1 , 0xdb76ba2d , 0x509a39e4 , 0x34a8f38f , 0xe80c4249 , 0x01de79d5 ] )
+  , Mont::unsafe_make( [ 0x329a6714 , 0x0b872c27 , 0x6f78d6b1 , 0x9dd4197e , 0xa23b2adf , 0x00de09cb , 0x460d5d96 , 0x289f5928 ] )
+  , Mont::unsafe_make( [ 0x0fd8e52b , 0x8019fd56 , 0x09c8499a , 0xb67f063c , 0x47f7408f , 0x840b21a6 , 0x6bb75fe9 , 0x126890d1 ] )
+  , Mont::unsafe_make( [ 0x29b7a42b , 0xdbbe21a1 , 0x4a728f6c , 0x4dae7def , 0xcd43fcde , 0x7a60d3e2 , 0x72504ff6 , 0x1bb6646d ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x612d0892 , 0xfff905ea , 0xc370e2e9 , 0xdb71af44 , 0x88b139ce , 0xa99460ee , 0xfd58ea60 , 0x1d68795b ] )
+  , Mont::unsafe_make( [ 0x503db4a8 , 0x160c1102 , 0x1c313e22 , 0x0402dd8d , 0xeb576f33 , 0x6c6b8ae3 , 0xb7c404cf , 0x1adab062 ] )
+  , Mont::unsafe_make( [ 0xccf09ce9 , 0xf31af376 , 0xbb4a8caa , 0xe18a91cc , 0x8cc717c9 , 0x7f2bc008 , 0xc9eda306 , 0x1777b361 ] )
+  , Mont::unsafe_make( [ 0x9d83f492 , 0xb4f05226 , 0xff3882a5 , 0x07b3d1cb , 0x2cda1873 , 0x2bcf3852 , 0xb9589442 , 0x13679e02 ] )
+  , Mont::unsafe_make( [ 0x84b53111 , 0x40d19894 , 0x3e6a9122 , 0xf674cc54 , 0x4fa479c3 , 0xe2ddb224 , 0x7746d90d , 0x2bc30307 ] )
+  , Mont::unsafe_make( [ 0x0332b71c , 0xaee9ded4 , 0xea90ded4 , 0xfcb65c89 , 0x0c1aa7c5 , 0x99ea508d , 0x81d425f8 , 0x160d533c ] )
+  , Mont::unsafe_make( [ 0xd19d2fa2 , 0xde830684 , 0x8d176cdc , 0xd7f06309 , 0x19c74200 , 0xab0ff7c6 , 0xff5c5851 , 0x0d1c6874 ] )
+  , Mont::unsafe_make( [ 0xcc2540db , 0x3637ea3c , 0xb40eb49c , 0x541bf29d , 0xca1fb9e4 , 0x48d9cc3a , 0xeb059cff , 0x232e04ad ] )
+  , Mont::unsafe_make( [ 0xb8892e02 , 0x10e39dbc , 0x58d8e6a9 , 0x283c2b4f , 0x87b8d568 , 0xdd54e6e2 , 0xcec8b76b , 0x17aa3100 ] )
+  , Mont::unsafe_make( [ 0x5e6db635 , 0x92dd1151 , 0x12e6fc6a , 0x88733a41 , 0x83650d5e , 0xdf9617ef , 0x2aa08d9e , 0x2bde49f0 ] )
+  , Mont::unsafe_make( [ 0x817bd68c , 0x317739ee , 0x0e6358df , 0x4d3351b6 , 0x55adf6cf , 0x1b91a81e , 0xf581ba98 , 0x02387a5b ] )
+  , Mont::unsafe_make( [ 0x26dfbc84 , 0x37fbbaf5 , 0xb380a2eb , 0x262e4e24 , 0x5db05221 , 0x767602f5 , 0x52307b84 , 0x24731404 ] )
+  , Mont::unsafe_make( [ 0x0bd72209 , 0x5ead80e4 , 0xe3129cd6 , 0x7acf8ef0 , 0x558801af , 0x12ab6d27 , 0x409793ce , 0x24a2d203 ] )
+  , Mont::unsafe_make( [ 0x596ae716 , 0x8b0d7d5b , 0xf38a2e14 , 0x509938de , 0x65cc1ec3 , 0x976e911b , 0x8040b840 , 0x29e3e812 ] )
+  , Mont::unsafe_make( [ 0x78ec29db , 0xd8e437f4 , 0x24d1d257 , 0xcbb7b874 , 0xe51d51e8 , 0x9496d43d , 0xb9050ffc , 0x150a6e95 ] )
+  , Mont::unsafe_make( [ 0x7339d76b , 0x15f7f510 , 0x26ba41af , 0x9110e33d , 0x0d4f3531 , 0x710a7670 , 0x35ea0800 , 0x241dbd10 ] )
+  , Mont::unsafe_make( [ 0x53abc2b6 , 0x9710a210 , 0x2f515152 , 0xec3a9c40 , 0xc15e1026 , 0x056e4c8b , 0xd04f3250 , 0x028f450c ] )
+  , Mont::unsafe_make( [ 0x7438831f , 0x29bf4c16 , 0x6ba7ca08 , 0x1f827907 , 0x09261023 , 0x8c23f09c , 0xc5aafea9 , 0x26010720 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xcd682ef2 , 0x4e8bd77c , 0x051d1f89 , 0x3d1f6941 , 0x74b971cd , 0x64c4ebd6 , 0x61ead376 , 0x2ac8e5c0 ] )
+  , Mont::unsafe_make( [ 0xae6134de , 0x1705601b , 0xe734273d , 0x8dae8c97 , 0x1ad39555 , 0xdbce0a8b , 0x3d3ffbf9 , 0x0fe5e5db ] )
+  , Mont::unsafe_make( [ 0x1cce37e9 , 0x05747cf8 , 0x90ba2662 , 0xb7facbe6 , 0x4aff59ec , 0xbba068f9 , 0x5ac576bf , 0x29fbcd83 ] )
+  , Mont::unsafe_make( [ 0xf0eb6ba5 , 0xfaf1cd31 , 0x2b3bce7b , 0x133cb168 , 0x7ebc6b10 , 0xe3603f73 , 0xa330ba94 , 0x305b2fa1 ] )
+  , Mont::unsafe_make( [ 0x4d36a440 , 0xb15e0c77 , 0x3b6959bd , 0x1c9186bb , 0xe890c826 , 0x39c4d427 , 0xa7e28bf7 , 0x2888650a ] )
+  , Mont::unsafe_make( [ 0x2ed0b253 , 0x415ea2d1 , 0xa54db8c0 , 0xf62e95d0 , 0x62615eaa , 0x385c8634 , 0xeec42638 , 0x016deaba ] )
+  , Mont::unsafe_make( [ 0x074a77c4 , 0x81c60ebb , 0xa70d9b06 , 0x98ecb598 , 0x2997af42 , 0xa154c0b4 , 0x177a77aa , 0x0278f42b ] )
+  , Mont::unsafe_make( [ 0x85d81dba , 0x09c40c41 , 0x27ac5768 , 0xbf9c0e3c , 0x73a9420a , 0x4d869e76 , 0x93c6edfe , 0x0fa40b55 ] )
+  , Mont::unsafe_make( [ 0x99753b85 , 0xdb8a3c9c , 0x750dab2a , 0xc1d962af , 0xddfd554f , 0xc5267fd6 , 0xa47a69c7 , 0x230b9a37 ] )
+  , Mont::unsafe_make( [ 0x66063e4a , 0x6bc81eba , 0x83458b7c , 0x9d0fb3b9 , 0xaf6cf814 , 0x293dca26 , 0xb173f8d7 , 0x23f38376 ] )
+  , Mont::unsafe_make( [ 0x870a051d , 0x62da53fc , 0xc1d400a9 , 0xd18f482d , 0x182f4877 , 0x2c6f1ad2 , 0xb36d8c0b , 0x2fda812b ] )
+  , Mont::unsafe_make( [ 0xe312932b , 0x342d0708 , 0xd6c09238 , 0xc40e8a81 , 0x0a018d32 , 0x4a4281c5 , 0x51fbaca4 , 0x1440799d ] )
+  , Mont::unsafe_make( [ 0x77814d37 , 0x9622713d , 0x475d1d94 , 0x0900cbfa , 0x1b654106 , 0x38f5eed1 , 0x66808b5b , 0x08027360 ] )
+  , Mont::unsafe_make( [ 0x3fea686a , 0x1834ef88 , 0xcee4f4a7 , 0xa8a52481 , 0x9ad77c29 , 0xc7075720 , 0x864e1714 , 0x21478e40 ] )
+  , Mont::unsafe_make( [ 0x7751ca98 , 0x0bd5de77 , 0x0b882932 , 0x37f7e63a , 0x26935bba , 0xb13cc356 , 0x587b123e , 0x1a4b6566 ] )
+  , Mont::unsafe_make( [ 0x92d5494e , 0xd4f9431f , 0xa12b56cc , 0xb6ecaf31 , 0x7bc87bd4 , 0x2ac70d3f , 0xa11cc6e8 , 0x0da80df5 ] )
+  , Mont::unsafe_make( [ 0x7afde923 , 0xf43244a8 , 0x1dc6f1c7 , 0xd6ff2f81 , 0xfb6704b0 , 0x5c40a763 , 0x9557abcf , 0x04095606 ] )
+  , Mont::unsafe_make( [ 0x547cc0a3 , 0xcbdb2573 , 0xcc341f54 , 0x6f2ca3da , 0x7d1dd75e , 0xc4781d5f , 0x718b9276 , 0x1a188653 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x702f4d4c , 0xcdcf7fd5 , 0x72442d40 , 0x4efc6ac5 , 0xb8fc7fc4 , 0x2bec7230 , 0xa327393a , 0x037d9158 ] )
+  , Mont::unsafe_make( [ 0xb3dab0aa , 0x4f6be636 , 0xaee54fb3 , 0x45a76eda , 0x979b23e5 , 0xd59aa46a , 0x534dd316 , 0x07ac8d7e ] )
+  , Mont::unsafe_make( [ 0x4488659d , 0x45a8e804 , 0x6adb6481 , 0x88e31864 , 0xbe751cd8 , 0x0eb4d0a6 , 0x21fe10f7 , 0x2c8bab0c ] )
+  , Mont::unsafe_make( [ 0xfd495936 , 0x346cccc3 , 0x4ba1e151 , 0xbe5a555b , 0x0e4ffc03 , 0xace29852 , 0xb32f219e , 0x11eea8d2 ] )
+  , Mont::unsafe_make( [ 0x4ca53adb , 0x60eef61a , 0x2ccd7cee , 0x5bc86e14 , 0x6ecb30bd , 0xd7ad2c3a , 0x9d4b34ef , 0x2272bb75 ] )
+  , Mont::unsafe_make( [ 0x6890da5b , 0x11ccf0e6 , 0xc046def1 , 0x710a8ed3 , 0x5f14c481 , 0x3653912e , 0xf07dd510 , 0x01cc8d8e ] )
+  , Mont::unsafe_make( [ 0x89a59b7a , 0xc95722ca , 0xd63c0c59 , 0x93647f86 , 0xcf58aaab , 0x66744426 , 0x69c8169a , 0x24f4be9c ] )
+  , Mont::unsafe_make( [ 0xd51c3f30 , 0x99816acf , 0xcb47c616 , 0xbd4c31d5 , 0x4604af26 , 0x14639ea3 , 0x17a84569 , 0x20329178 ] )
+  , Mont::unsafe_make( [ 0xcd24fc4e , 0x04bb48c8 , 0xd9619a7b , 0x1b3190ec , 0xb032c5d0 , 0x88ef2a30 , 0x6077b5c1 , 0x2a358a77 ] )
+  , Mont::unsafe_make( [ 0x6cb70d7c , 0x5060bde5 , 0x2e08b64f , 0xfc308819 , 0x6aa75f4c , 0xe65e83e0 , 0x951c0ab7 , 0x23ce0533 ] )
+  , Mont::unsafe_make( [ 0x9fddeebc , 0x4fcc8707 , 0x3dbc8109 , 0xc767cd2c , 0x15f9fb92 , 0xe2a4347e , 0xbce5e0fd , 0x226f64df ] )
+  , Mont::unsafe_make( [ 0xdfabdbc1 , 0x70c5acb9 , 0xed093b2a , 0x3517a7b5 , 0xaedbe0be , 0xb5a04948 , 0xefb19ef0 , 0x0531a8de ] )
+  , Mont::unsafe_make( [ 0x09d5288d , 0xabdc7fd3 , 0x9da6ca8d , 0x67725212 , 0xdafaab75 , 0x8f07cab7 , 0x6c9095cd , 0x1b68bccf ] )
+  , Mont::unsafe_make( [ 0x25cb81a3 , 0xa12ccfe9 , 0x31a0cb0d , 0x118dcb69 , 0xa6a4eed9 , 0x71663a40 , 0x34a17857 , 0x201a75cc ] )
+  , Mont::unsafe_make( [ 0x95a596d5 , 0x3d289ccc , 0xda612f65 , 0x7b970361 , 0xc5d2d355 , 0x545d564e , 0xc9b2e58b , 0x11f168a3 ] )
+  , Mont::unsafe_make( [ 0x711bc2be , 0xbfd849b1 , 0x5d9ef409 , 0xcad878fb , 0xf655a8d1 , 0xd7b601f9 , 0xe2d35020 , 0x21fae5eb ] )
+  , Mont::unsafe_make( [ 0x18f6ffb4 , 0xebe9c360 , 0x4c66f541 , 0x2cceee78 , 0x70f632a7 , 0xcb3e3eb9 , 0x3f983fd8 , 0x280c1f58 ] )
+  , Mont::unsafe_make( [ 0x5eb02998 , 0x786a8922 , 0x37eccd5a , 0xe8284323 , 0x1ba23544 , 0x11d10d19 , 0xcbeade5a , 0x1adb5e25 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x8276f031 , 0x677876e5 , 0x0afbde12 , 0x44fcd75a , 0x603408a2 , 0xb477f02a , 0x41caa564 , 0x2ece8579 ] )
+  , Mont::unsafe_make( [ 0x2b8ab817 , 0x84e9a269 , 0x97a34614 , 0x66836eab , 0x57c0a8ea , 0x9ea5e279 , 0x1d8fcadf , 0x00b1128d ] )
+  , Mont::unsafe_make( [ 0x40935aaa , 0xdd7d407e , 0x4abd21f9 , 0x691fd7a1 , 0x888f03e1 , 0x503dbd81 , 0xd14069d7 , 0x12ba6d4c ] )
+  , Mont::unsafe_make( [ 0x07dc452f , 0xb2f7aa70 , 0xdf809048 , 0xe2c67991 , 0x60294ce9 , 0x31de771d , 0xa7afa8b9 , 0x2eff58e5 ] )
+  , Mont::unsafe_make( [ 0xd68d8ea2 , 0xd79a1748 , 0xe50b1aa8 , 0x5f513335 , 0xe86d797c , 0xfd1af343 , 0x3a2cc92a , 0x26facda6 ] )
+  , Mont::unsafe_make( [ 0x5aecb3ca , 0x008acb40 , 0x84d84414 , 0xe0e1571a , 0x9a3e84ba , 0x5fac0c4c , 0x79416c44 , 0x28989392 ] )
+  , Mont::unsafe_make( [ 0xf691dbcb , 0xa4224e67 , 0x77e3e727 , 0x40073d58 , 0x98e0ae83 , 0xf538b9be , 0x932dd114 , 0x11a90e31 ] )
+  , Mont::unsafe_make( [ 0x62f112c4 , 0x3f7d5bec , 0x0850640b , 0x9d6fabff , 0xaf5afd27 , 0xa4082dc8 , 0x25222331 , 0x07ab3374 ] )
+  , Mont::unsafe_make( [ 0x1ecd90a5 , 0x1c175e40 , 0x42655cef , 0xbabaebb6 , 0x30ba7bff , 0x27833d46 , 0xc7805670 , 0x15718e2e ] )
+  , Mont::unsafe_make( [ 0x02d93a84 , 0xa21f50a7 , 0xbc0ebf49 , 0x8350c941 , 0xb72805e1 , 0x974ff3c1 , 0xd639f393 , 0x126cae6d ] )
+  , Mont::unsafe_make( [ 0x4bf3ab8c , 0x0ba9714e , 0xaf98e4fa , 0x94532705 , 0x8a447a08 , 0x293ccade , 0x4b9acbaa , 0x1b4cad97 ] )
+  , Mont::unsafe_make( [ 0x3fedc336 , 0x2aa1916b , 0x1ab37b60 , 0x8e6f30ee , 0xf0b43565 , 0xb3688504 , 0x18a52949 , 0x237d38cf ] )
+  , Mont::unsafe_make( [ 0x1cb042bc , 0x734c2972 , 0xeb19393c , 0x1fb8f38d , 0xa27f173f , 0xe5d6d386 , 0xd8f51fb3 , 0x1840f9d0 ] )
+  , Mont::unsafe_make( [ 0x8ee35694 , 0xad17609a , 0xbf849a37 , 0x41395c3f , 0x75f576c8 , 0x204533e5 , 0x897f5c5e , 0x10772722 ] )
+  , Mont::unsafe_make( [ 0x94443a82 , 0xd5451317 , 0x0962dbcf , 0xa5342419 , 0xffb9e72f , 0xb7f71523 , 0x18bc2f7a , 0x225e24e0 ] )
+  , Mont::unsafe_make( [ 0x790f853b , 0x5c95a28f , 0x369dcce6 , 0x0f58cd3d , 0x3241232d , 0x4fbb3012 , 0xc7bf4deb , 0x02846c12 ] )
+  , Mont::unsafe_make( [ 0x5cbf93b3 , 0xf6597c3d , 0xb4ff2ac6 , 0xe128dd6a , 0x741edb18 , 0xb55721e2 , 0x05cd3b75 , 0x1abd6a4f ] )
+  , Mont::unsafe_make( [ 0xa3abff34 , 0x730f2dd8 , 0xc5545657 , 0xabe351a0 , 0x7a880a30 , 0xfda1e056 , 0xa487fb27 , 0x2815f9e7 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xdcd8df6d , 0x9034f25d , 0x3ce8cd9c , 0xe115b6f5 , 0x1c92dcd6 , 0xfcc3e73a , 0x70a45189 , 0x221559d7 ] )
+  , Mont::unsafe_make( [ 0xaccbe5bc , 0x09d03061 , 0x83eebb5e , 0x0289a309 , 0xc7cd7d0c , 0x92a4025f , 0x54df7741 , 0x004442ca ] )
+  , Mont::unsafe_make( [ 0x0e3d05d0 , 0xebea7b5f , 0xcea74945 , 0x07f6aec8 , 0xb2bbe852 , 0x653a3bba , 0x5e4d5046 , 0x2e2129f7 ] )
+  , Mont::unsafe_make( [ 0xc1f747da , 0x729b5e82 , 0xa9928c2a , 0x1b0856ae , 0x582aa013 , 0xd4c6aae9 , 0xc08b847d , 0x1827eeb7 ] )
+  , Mont::unsafe_make( [ 0x2414714b , 0x68b6ec5c , 0xe9961ef8 , 0x346e5c75 , 0xcdf9c589 , 0x33e57cfb , 0xc26e1f3a , 0x1b4e7428 ] )
+  , Mont::unsafe_make( [ 0x3483168c , 0xfecccdd4 , 0xb9edc620 , 0x4590dee5 , 0x73c6d784 , 0xc99daf0a , 0x12f1610d , 0x032cbb64 ] )
+  , Mont::unsafe_make( [ 0x2c5f12d8 , 0x6b0df057 , 0x3fbdc715 , 0x0f03ccef , 0x62e49be7 , 0xa944fc5e , 0xbca97cf6 , 0x20d15464 ] )
+  , Mont::unsafe_make( [ 0x02f39fdb , 0x7c74f2c7 , 0xc5f7fa42 , 0x26143143 , 0xf8b3e87c , 0x65dd6449 , 0xc29ab93e , 0x24198592 ] )
+  , Mont::unsafe_make( [ 0x6b816a0f , 0x4b7351d7 , 0x636e0218 , 0x313c0ce6 , 0xe5c6009c , 0x792fc597 , 0x8c3a8916 , 0x27623bb5 ] )
+  , Mont::unsafe_make( [ 0xc89216ad , 0x57cacebc , 0xa615554b , 0x74c8ea23 , 0x70f87cbf , 0x138740b2 , 0x6ef93c52 , 0x0d728578 ] )
+  , Mont::unsafe_make( [ 0x00e4d996 , 0xd5bf1890 , 0x5e767b3e , 0x18ee03cb , 0x5c34cf7c , 0xc2cccd5f , 0x579571f5 , 0x0c10c857 ] )
+  , Mont::unsafe_make( [ 0x15014849 , 0x2405bffc , 0x3168d0e1 , 0xabced457 , 0x0ef922f8 , 0x0719aafc , 0x975e0c79 , 0x2c0a4fc1 ] )
+  , Mont::unsafe_make( [ 0x79d80c5a , 0x257808ad , 0xe62a2133 , 0x760919e4 , 0x1c58f308 , 0xdda94571 , 0x4bd78d5a , 0x23d582bd ] )
+  , Mont::unsafe_make( [ 0x65828ccc , 0x23b19a13 , 0x34bb3d91 , 0xd63cc705 , 0xa5280ca1 , 0x03787d0e , 0x22b6895d , 0x04c2e7af ] )
+  , Mont::unsafe_make( [ 0xc62e5e17 , 0x22be348d , 0x1e54dbf9 , 0x0a3e3b1e , 0x89870878 , 0x756f5e08 , 0x63d9876d , 0x06ae6e2d ] )
+  , Mont::unsafe_make( [ 0x6f2b5d3c , 0xf9f40a76 , 0xe8451daf , 0x4be890dc , 0x83bf270f , 0x2aa8ccde , 0x04416ddb , 0x158e8558 ] )
+  , Mont::unsafe_make( [ 0x6f39a0fc , 0xce64ff8c , 0x6d1e61e2 , 0x4012daca , 0xb941f954 , 0x41bc2cae , 0x60a2fda7 , 0x1e1b0f45 ] )
+  , Mont::unsafe_make( [ 0x211ea56d , 0x791c0382 , 0x86081dc6 , 0xdf302e88 , 0x8173a707 , 0x8e7c7394 , 0xe689be3f , 0x19b11866 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x5e44c569 , 0x3930090f , 0x0cbd5a67 , 0x2b2905f7 , 0xbb525e20 , 0xdbab436e , 0xea43becf , 0x2ae3c3f4 ] )
+  , Mont::unsafe_make( [ 0x8ea566ec , 0xd23e1c69 , 0x9ae4c933 , 0x49601292 , 0x3b3391d5 , 0x2ba4acef , 0x63d7aa90 , 0x0cf51b71 ] )
+  , Mont::unsafe_make( [ 0xac75158c , 0x366e9418 , 0x7b7736ca , 0x1730242b , 0xfa9417a9 , 0x6057245d , 0x8563c2c3 , 0x2db5150d ] )
+  , Mont::unsafe_make( [ 0x17eaaa78 , 0x2ec73a5f , 0x679f0a05 , 0x1f7aeac3 , 0xd884175d , 0xe0220549 , 0x84521b2f , 0x05d3fb96 ] )
+  , Mont::unsafe_make( [ 0xff701ab9 , 0x4db8d374 , 0x65c50c73 , 0x353d62d7 , 0xed1dc6b8 , 0xfbf01c01 , 0xfffcff2a , 0x1cc93b6b ] )
+  , Mont::unsafe_make( [ 0x36f48119 , 0x5ff38194 , 0xbbb282a3 , 0xd8c16670 , 0xda7119c3 , 0x1ed45ad7 , 0xf9339ff9 , 0x2bbb298b ] )
+  , Mont::unsafe_make( [ 0x7f4bd57a , 0x648ecd8e , 0x3c1b4f83 , 0x91f78158 , 0x26c20dc1 , 0xa9ad30fc , 0x6919224a , 0x28568411 ] )
+  , Mont::unsafe_make( [ 0xc22af89d , 0xec6821e6 , 0x27435c1b , 0xd836dd92 , 0x4ac47455 , 0x26f4d05c , 0x4c2a0fe5 , 0x09d0c3e3 ] )
+  , Mont::unsafe_make( [ 0xec1b0e99 , 0x836af49c , 0xaa8ae265 , 0xe3c58c7b , 0xda815ee2 , 0x7c4a1a23 , 0x074f143b , 0x0019c5fa ] )
+  , Mont::unsafe_make( [ 0xe843904b , 0x54a27f8e , 0x0febec75 , 0xd0654118 , 0xb69fc205 , 0x409bed90 , 0x9bd6174e , 0x12375241 ] )
+  , Mont::unsafe_make( [ 0xc0057224 , 0xa4dfb48a , 0xb93900d2 , 0xe281cbd7 , 0x8413f77a , 0xb31dff4a , 0x597830d9 , 0x208ee553 ] )
+  , Mont::unsafe_make( [ 0x9c1e3d90 , 0x454992bd , 0xc031a6a9 , 0x40ea9355 , 0x334d4422 , 0xc0738772 , 0xa7d87121 , 0x01d9ec9a ] )
+  , Mont::unsafe_make( [ 0xad505de4 , 0xceef0abc , 0xa5128cd9 , 0xca57ffe1 , 0x564e708d , 0x96686f47 , 0x3f4acf6f , 0x1e19917a ] )
+  , Mont::unsafe_make( [ 0x29ab526e , 0x23222116 , 0x511f25a1 , 0x84210b37 , 0x76cc4769 , 0x43760286 , 0xa018fae1 , 0x0ba8977a ] )
+  , Mont::unsafe_make( [ 0x334158b2 , 0xe71004b7 , 0xe7b2df87 , 0x6ff62ab7 , 0x43c6c1d0 , 0xaa275002 , 0x60034b05 , 0x150d7d29 ] )
+  , Mont::unsafe_make( [ 0xd2a7e6bb , 0x3ec41090 , 0x7f5cb674 , 0x8c3a6e39 , 0x48e28403 , 0x2e6338ef , 0x3f40aa0f , 0x0b1db84d ] )
+  , Mont::unsafe_make( [ 0xaa5ec155 , 0x5d821e0f , 0x02902e37 , 0x877e4fd4 , 0x184d3076 , 0x0f1276da , 0x4276ba50 , 0x049402d9 ] )
+  , Mont::unsafe_make( [ 0xd061bd44 , 0xa86ee5aa , 0x7fa05d16 , 0x62555f0a , 0x6bc08ef8 , 0x41a8e2dc , 0x20b979fe , 0x27f45790 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x2bacaa23 , 0x82b20547 , 0xfd0dc2ce , 0x2c797a20 , 0x2d65dea0 , 0xa29a47b3 , 0x1b5a5de1 , 0x123e3e88 ] )
+  , Mont::unsafe_make( [ 0x39431db3 , 0x760b7e5f , 0xdc866240 , 0xec66604c , 0xba1bc493 , 0xad456a00 , 0x7d88efdf , 0x0f71507a ] )
+  , Mont::unsafe_make( [ 0x00477c08 , 0xa0bf5a51 , 0x3fcd6f15 , 0x0d8eb861 , 0x308d0f16 , 0x51a30eeb , 0xb3befe66 , 0x0e60dd8e ] )
+  , Mont::unsafe_make( [ 0x41e00774 , 0x9d4ae1a2 , 0x56ecea28 , 0x55b6c23a , 0xe397a353 , 0xaf3a54bc , 0x2d87c48b , 0x090a9c9a ] )
+  , Mont::unsafe_make( [ 0x3e5a0e0d , 0x5598bd54 , 0xd7208753 , 0xc44752d8 , 0x32ca9340 , 0x698c3e1d , 0x11525cf8 , 0x1ea19c6b ] )
+  , Mont::unsafe_make( [ 0xc2d6f64e , 0x1a24654f , 0xac3d0b11 , 0x583cdeed , 0x167e51e2 , 0x8938c2b3 , 0xd7607a05 , 0x171d9776 ] )
+  , Mont::unsafe_make( [ 0x45413fb3 , 0x08c3d800 , 0x467bdc16 , 0x75f80c92 , 0x4522febd , 0x52b7522e , 0xedcf6d2f , 0x0bb0dad2 ] )
+  , Mont::unsafe_make( [ 0xa91c53a0 , 0x7203fd30 , 0xf78a7d5a , 0xb4ae2266 , 0x49542d43 , 0xd989b7dd , 0x4841afc3 , 0x169cfbde ] )
+  , Mont::unsafe_make( [ 0x5f766786 , 0x7879b989 , 0xb963c9e6 , 0xfda06af0 , 0xf5549885 , 0xe7d79ad3 , 0x03aa1266 , 0x157f4304 ] )
+  , Mont::unsafe_make( [ 0x4f3b2764 , 0x967f5005 , 0x4ec904bc , 0xd21d9856 , 0x3d04836a , 0xcf12faaf , 0x12df0e6a , 0x276e9635 ] )
+  , Mont::unsafe_make( [ 0x09f848c1 , 0xef9c401b , 0x1db6f2ad , 0x66ac06d5 , 0x5262271f , 0x9d20072b , 0xf57ef8ae , 0x22b6c902 ] )
+  , Mont::unsafe_make( [ 0xaf73f363 , 0xcd15365a , 0x0f2bb342 , 0x9753bb4b , 0xa4143f82 , 0x2232158a , 0x709cd963 , 0x003321d4 ] )
+  , Mont::unsafe_make( [ 0xa3510ef5 , 0x2b35dbee , 0x5823ab0b , 0x304a261a , 0xf73049ac , 0x8b1a80bc , 0x1a907fe8 , 0x1c6ee724 ] )
+  , Mont::unsafe_make( [ 0x45ef4ae0 , 0x7dad7bb8 , 0x6b9a852f , 0x94145f15 , 0x7054845e , 0x08b2289b , 0x219dfebc , 0x1b594e97 ] )
+  , Mont::unsafe_make( [ 0x4e6ac9f7 , 0xde52a752 , 0xff8706f7 , 0xa28d3ded , 0x339fa936 , 0xc66653ba , 0xf624e8a4 , 0x216c4418 ] )
+  , Mont::unsafe_make( [ 0x68423e0a , 0xaffbd118 , 0x8778a42d , 0x02e463a5 , 0xee832572 , 0xd1854b01 , 0x3c2e1a8a , 0x22d94709 ] )
+  , Mont::unsafe_make( [ 0xe8c9f050 , 0xd86b7e1f , 0x3e20b164 , 0x92ec3403 , 0x2276bb11 , 0x552f2410 , 0xeaa759e8 , 0x0edec41d ] )
+  , Mont::unsafe_make( [ 0xa810cfeb , 0x0f0ead30 , 0x293cd391 , 0x9c10bfe2 , 0x688542b5 , 0xe49b9840 , 0x7b1c9e82 , 0x1ed5fb18 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x63a76a5f , 0x0cb38a21 , 0x91251d6d , 0xe437dffd , 0x26cb3f31 , 0x4823f8c0 , 0xcf4e84e7 , 0x0c10937b ] )
+  , Mont::unsafe_make( [ 0xd638af0a , 0xa139397c , 0x8582f54b , 0xd01fc3bc , 0xed6cadc2 , 0xa6f331f1 , 0x7e43eb2f , 0x044e0892 ] )
+  , Mont::unsafe_make( [ 0xd3b23234 , 0x773c2969 , 0xed5a94d1 , 0xaa63041a , 0xf2808b56 , 0x46bb474c , 0xa0d3c531 , 0x2ff038f3 ] )
+  , Mont::unsafe_make( [ 0x95eb75dd , 0x56963ac3 , 0x030f357c , 0xc550ed4e , 0xc0fe6e24 , 0xf27d1ab4 , 0x3fbd15eb , 0x20419bb7 ] )
+  , Mont::unsafe_make( [ 0x8a603c4e , 0xe9123f84 , 0xaa498095 , 0xabf16447 , 0x5febd9d2 , 0xc6f57e7c , 0x5e50d342 , 0x0f133b81 ] )
+  , Mont::unsafe_make( [ 0x8bb25103 , 0x4960d256 , 0x9b271a5b , 0xb9548ca5 , 0x35e6daaa , 0x500d3342 , 0x6b207347 , 0x2c56e0f0 ] )
+  , Mont::unsafe_make( [ 0xd9fa3303 , 0x50966109 , 0xeff6d1f4 , 0x03eb8fa9 , 0xa5427866 , 0xef1f4c8a , 0x0d28237d , 0x0c9c8f5c ] )
+  , Mont::unsafe_make( [ 0x4f0b4813 , 0x5549e4d9 , 0x31955892 , 0x8dc9c69f , 0x5250b744 , 0x65ca1be4 , 0xfb5aa4af , 0x1673d1c6 ] )
+  , Mont::unsafe_make( [ 0x040dfee1 , 0x78227c7e , 0x229106c9 , 0x3bc442ee , 0x4273bc1b , 0x5c4b115f , 0x0b863baf , 0x272b7b51 ] )
+  , Mont::unsafe_make( [ 0x834b1200 , 0x90d60b48 , 0x8021d1b8 , 0xbf5eeb61 , 0x7cb551a3 , 0xb70a25f0 , 0x710379e7 , 0x28df6c65 ] )
+  , Mont::unsafe_make( [ 0x341ff942 , 0x28c5b73c , 0x82163fbe , 0x9c9db775 , 0x8de7ae74 , 0xd3022589 , 0x3e7187ab , 0x00c7626e ] )
+  , Mont::unsafe_make( [ 0xbef51d27 , 0x100909e2 , 0x24aa2704 , 0x6b101eda , 0x567146ee , 0xf4fcea69 , 0x4354c4b2 , 0x1c88f760 ] )
+  , Mont::unsafe_make( [ 0x9cb0c4b2 , 0x5df4583b , 0xb4f42ab6 , 0x4229b933 , 0x18e24648 , 0x897444a8 , 0x5dd93822 , 0x23470273 ] )
+  , Mont::unsafe_make( [ 0x17342285 , 0x8e6a4888 , 0x6d4fbede , 0xe5bf2e65 , 0xf9ce3fb2 , 0x771e4114 , 0x6dfddd98 , 0x0134e7e8 ] )
+  , Mont::unsafe_make( [ 0xe547c01b , 0x482ab6ef , 0xdb3c8157 , 0xa69325c7 , 0xc38c94d1 , 0x77a0882f , 0x07704b8a , 0x089d031d ] )
+  , Mont::unsafe_make( [ 0x9f773067 , 0x6adca56a , 0x24a9c8f4 , 0xc5b3f747 , 0xc1b160ad , 0x0949c517 , 0x288f385e , 0x29af148f ] )
+  , Mont::unsafe_make( [ 0xb863b8b4 , 0x6544cd70 , 0x65c222b5 , 0x84535d89 , 0xccbde5f9 , 0xe99e8cee , 0xf8b19b5e , 0x256ddace ] )
+  , Mont::unsafe_make( [ 0xb705ac37 , 0x3e25804a , 0x6e60b779 , 0x75fe2262 , 0xf17a55ee , 0xcb09297b , 0x43144923 , 0x14f1f366 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xdacd6486 , 0xcec92d79 , 0x3c5173f0 , 0xd3b6d782 , 0x5233e80f , 0x7a387bf0 , 0x7b816ced , 0x0fe261d0 ] )
+  , Mont::unsafe_make( [ 0x461652d8 , 0x091dfe2d , 0x346b03bf , 0xa986069a , 0xaed55c1d , 0x2686a88a , 0x2b32e831 , 0x02143e69 ] )
+  , Mont::unsafe_make( [ 0x20a273ac , 0xac5ca617 , 0xe9ad96d2 , 0xc0e97f16 , 0x3a776d2e , 0x4e2a4082 , 0xa0277881 , 0x03eedcf6 ] )
+  , Mont::unsafe_make( [ 0xd9172924 , 0x1c8f434c , 0x912a4d92 , 0x94c98afa , 0xd016e43a , 0x60b11e8d , 0xc95a169d , 0x07525286 ] )
+  , Mont::unsafe_make( [ 0x66db1dc8 , 0x8a87de78 , 0x478d89db , 0x64036bc0 , 0xbf32beb3 , 0xe820ea07 , 0x12782144 , 0x065a7c5b ] )
+  , Mont::unsafe_make( [ 0x9985efb2 , 0x71ac520f , 0xd83bc7af , 0x6d11d37f , 0x7cc3912c , 0x13c58cc4 , 0xa9b60901 , 0x19e7daf7 ] )
+  , Mont::unsafe_make( [ 0xdec4cdf3 , 0xe4b8b679 , 0x3874a8f6 , 0x8efec797 , 0x0df9bc28 , 0xab14c654 , 0xfd092a54 , 0x006d297f ] )
+  , Mont::unsafe_make( [ 0x64f5ba0e , 0xb477b5b6 , 0xee587f2a , 0xc6b07781 , 0x0eea2544 , 0x34e17e60 , 0x55afad8d , 0x01dadff2 ] )
+  , Mont::unsafe_make( [ 0x5663f42b , 0xf48f37c5 , 0x0cea7645 , 0x2c704373 , 0x2cc76b90 , 0x4fbed002 , 0x200f7efe , 0x2f8102de ] )
+  , Mont::unsafe_make( [ 0x3210be48 , 0x5b102381 , 0x3261041e , 0x99397571 , 0x7a3547f3 , 0xae604cbc , 0xe4f1f215 , 0x22e4c8a8 ] )
+  , Mont::unsafe_make( [ 0x28a11f20 , 0x50efea6f , 0x31b06ca6 , 0x97a4c3a8 , 0x3a14a560 , 0xd01e31d2 , 0xb2e6bf1d , 0x28261f09 ] )
+  , Mont::unsafe_make( [ 0xd590cbba , 0xb88008a3 , 0xbf2b8cf5 , 0x3162a4b1 , 0x54aaabc5 , 0x178bfd90 , 0x8f10e093 , 0x242cc18d ] )
+  , Mont::unsafe_make( [ 0x09204a7d , 0x2c7bbcbc , 0x8743414b , 0xf2167ac7 , 0x8b78a29e , 0x71f6f47c , 0x4f814205 , 0x09cb74e6 ] )
+  , Mont::unsafe_make( [ 0x3d8bff73 , 0x0d6e9b4d , 0x8c16781d , 0x74718ec9 , 0x001069f3 , 0xbf7d4d1f , 0x594ea752 , 0x1fd7defe ] )
+  , Mont::unsafe_make( [ 0x25ccff96 , 0xeb3adc7e , 0x2787de2a , 0x62fa2d0a , 0x661290f6 , 0xcb65c235 , 0xee8342a9 , 0x15c8c704 ] )
+  , Mont::unsafe_make( [ 0x378c0378 , 0x31c59437 , 0x4542d5c9 , 0xa18fd3ee , 0xfb16a088 , 0x09c57351 , 0x386eafcc , 0x22e5b634 ] )
+  , Mont::unsafe_make( [ 0x48c24cb8 , 0x13e9fd29 , 0x40c48424 , 0x2fa22e6c , 0x2ddc79bd , 0xb1c27928 , 0x0cec3067 , 0x168f8fc6 ] )
+  , Mont::unsafe_make( [ 0x2f7717f2 , 0x872e5d5f , 0x520d2724 , 0x8caf0044 , 0x3b4f57d4 , 0x78e23d2b , 0xe0452928 , 0x0fcc8ba2 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xaef0f683 , 0x3b04e7b3 , 0xdfe580d2 , 0x32fe2f10 , 0xb24edf22 , 0xcf5d93bc , 0xe2695560 , 0x0f5e3853 ] )
+  , Mont::unsafe_make( [ 0x35a2b3fc , 0xc23141c3 , 0xf265ab6d , 0x9df3c847 , 0x1738c951 , 0x17fbb973 , 0x5e7facd0 , 0x15325e19 ] )
+  , Mont::unsafe_make( [ 0x711d73b8 , 0x26ca64e5 , 0x9ddf2b59 , 0x94ab2913 , 0x484f11d0 , 0x2e33c560 , 0xe74ea7c4 , 0x215d557b ] )
+  , Mont::unsafe_make( [ 0x5cb83b4e , 0x80463832 , 0x02b1f919 , 0xe51ca00c , 0x3182e117 , 0x7c1923da , 0x9bf2c38f , 0x10afaf2f ] )
+  , Mont::unsafe_make( [ 0x27a7b311 , 0x28f03633 , 0x50bbebbe , 0x0ee02c6a , 0x668d1b1e , 0x014d5e3c , 0x9d272c95 , 0x118c1a62 ] )
+  , Mont::unsafe_make( [ 0xd3408dca , 0xc35619c0 , 0x79bb5a23 , 0x99b04515 , 0x514fb66f , 0x849a1c8f , 0xfa2f62ec , 0x2345efaa ] )
+  , Mont::unsafe_make( [ 0xabc58e2d , 0x7108ec27 , 0x6c691ae7 , 0x3ccac997 , 0xdc8c4e1b , 0x4147c56f , 0x5aa66a1c , 0x01f0ba42 ] )
+  , Mont::unsafe_make( [ 0x0ce6719b , 0xc8f40d9b , 0xd48cdd46 , 0xa950e74b , 0x516c8401 , 0x1a23fee6 , 0x8bf63d93 , 0x09c5d27c ] )
+  , Mont::unsafe_make( [ 0xb325e5db , 0x2e8837c7 , 0x93d9a7ca , 0x3a815238 , 0x5d47ac5e , 0x9c106a94 , 0x48c1f20b , 0x0c06425c ] )
+  , Mont::unsafe_make( [ 0xd1e77bdb , 0xeac24cfe , 0x64d360f9 , 0xb6f1106b , 0xb8802783 , 0x49caa543 , 0x0317f3a1 , 0x1ece1ca8 ] )
+  , Mont::unsafe_make( [ 0xa971c09f , 0x66a4f81c , 0xef19ee5d , 0x48dc4e24 , 0x354b182a , 0x2a2e7347 , 0x2a659073 , 0x24b5fc29 ] )
+  , Mont::unsafe_make( [ 0x47cda2cb , 0x2249a7b0 , 0xa6b70ba9 , 0x0bfd9b27 , 0x97d03cff , 0x1df2cfa7 , 0xaaf1acc3 , 0x0b4f55ed ] )
+  , Mont::unsafe_make( [ 0x1aff2aca , 0x714bc081 , 0x7d9fa37d , 0xf370a6d0 , 0xbe86c7ce , 0xc954ad64 , 0x0ec99650 , 0x22c7101c ] )
+  , Mont::unsafe_make( [ 0x82af9024 , 0xf3fc269a , 0x2a17ecf0 , 0xe8d572ad , 0x7a589ab7 , 0xd3928807 , 0x3f8d59ff , 0x275b1ba7 ] )
+  , Mont::unsafe_make( [ 0x2d999da6 , 0x6dc5088b , 0x75cdb693 , 0xe62ca933 , 0x61a6e173 , 0x15874e89 , 0x9f783064 , 0x22136e5b ] )
+  , Mont::unsafe_make( [ 0x057e5a81 , 0x632c4a64 , 0x50054695 , 0x6b056a62 , 0xbe51ffb0 , 0xbaa8bfad , 0x48aa9283 , 0x1b6a169b ] )
+  , Mont::unsafe_make( [ 0x823d0308 , 0x016dc243 , 0x0b78f6a7 , 0xf7a88ce1 , 0x93484517 , 0x02d0e183 , 0x20bd6e53 , 0x13253575 ] )
+  , Mont::unsafe_make( [ 0x31bd693f , 0xba26ac0c , 0x4f317b9e , 0xb0c1d0d4 , 0xceedec9a , 0x8fd31297 , 0x2d09d05c , 0x241b0a63 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xf40e5d70 , 0x1adf146e , 0xa7290a85 , 0x1c4316cc , 0x3b7b44f6 , 0x14045fde , 0x0e4cc4cb , 0x159dd7e8 ] )
+  , Mont::unsafe_make( [ 0x9d67393c , 0x5417bcc0 , 0xf9622653 , 0x0dfe2030 , 0x290b128c , 0xfb4ed3c9 , 0x9797eb71 , 0x11cdbf22 ] )
+  , Mont::unsafe_make( [ 0x6475c83d , 0x5b3b1078 , 0x57ab748a , 0xef40c227 , 0x415f8ec3 , 0xf618c5ee , 0xcfdc2714 , 0x27487dc3 ] )
+  , Mont::unsafe_make( [ 0x03940c66 , 0xf4737c5b , 0xf72a2652 , 0xab147365 , 0xdd4b44f8 , 0x25c11662 , 0x56d1a7d5 , 0x2858f3c8 ] )
+  , Mont::unsafe_make( [ 0x0ee3d862 , 0x4aa7f4d1 , 0x44f70eab , 0x65f133b7 , 0x8e516299 , 0x1bbbfe75 , 0xaab70473 , 0x2eab1ca1 ] )
+  , Mont::unsafe_make( [ 0x191f7b77 , 0xa9dc72d8 , 0x45760f1e , 0x3ad7be33 , 0x21a61a4e , 0x1eb33d20 , 0x572e22c0 , 0x16b7238a ] )
+  , Mont::unsafe_make( [ 0x08442aad , 0x10d37e00 , 0x73610499 , 0x7b9536ed , 0xa7234386 , 0xa7c929db , 0x5c01c42e , 0x122483c5 ] )
+  , Mont::unsafe_make( [ 0x484a4068 , 0x425130b2 , 0x7ae1c1c1 , 0x7073274d , 0x3c3c5abd , 0x08e82b85 , 0xb3142151 , 0x03eae50e ] )
+  , Mont::unsafe_make( [ 0x84f1148c , 0x9c4e3ac0 , 0xd63a01c2 , 0xa8e9a176 , 0x0e15604d , 0xda1cf0ec , 0x3d44b838 , 0x14e37603 ] )
+  , Mont::unsafe_make( [ 0xf50e8352 , 0x9d44a548 , 0xf101c026 , 0x14038c3a , 0x1f39ab24 , 0xa08dc2a0 , 0xb350b1dc , 0x29249a3e ] )
+  , Mont::unsafe_make( [ 0x63890f73 , 0xc1281c97 , 0x57255731 , 0x353a8067 , 0x6c763cf8 , 0xb92969ad , 0x69320673 , 0x24910cf3 ] )
+  , Mont::unsafe_make( [ 0x7976c212 , 0x7b55a315 , 0xeea561ad , 0xfaffcb64 , 0xa5c78c7b , 0xc01de123 , 0x515ac7c1 , 0x26cbb4f5 ] )
+  , Mont::unsafe_make( [ 0xf09f59eb , 0xdcfceb5f , 0xbd53ea43 , 0x17d828b7 , 0xcbb65aca , 0x68330ad4 , 0x932096b9 , 0x107560f5 ] )
+  , Mont::unsafe_make( [ 0x9aa50ae9 , 0xfbe5ce19 , 0x6fbb6612 , 0xf080b68c , 0x62b7d763 , 0x2a95cc12 , 0x4ed181d8 , 0x04fbe1fa ] )
+  , Mont::unsafe_make( [ 0x3297d856 , 0x816488d7 , 0x081023e4 , 0xa5191ef1 , 0x9c894d34 , 0xf6200757 , 0xea4d99e9 , 0x0b992aa1 ] )
+  , Mont::unsafe_make( [ 0xdf0c05ad , 0x1bf169f4 , 0xcc3b8b6c , 0xcfa55f02 , 0x45ec1fbc , 0x670c054e , 0x3d5232d6 , 0x2e26859e ] )
+  , Mont::unsafe_make( [ 0xe71308a9 , 0xd16941d8 , 0xe6439182 , 0x6ff7543a , 0xeaafdd7b , 0x8e037d85 , 0x4ab4fd2a , 0x29be5403 ] )
+  , Mont::unsafe_make( [ 0x91a72def , 0x2e66e40e , 0x1ec9f029 , 0xfdc1a8f7 , 0xfde4b78a , 0x7bd4e399 , 0x632f6df0 , 0x168c387b ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x7c7936f8 , 0xaeecade2 , 0xd2222764 , 0x66765c10 , 0x1c9f89c7 , 0xb201b828 , 0x80e960bf , 0x0b6bac72 ] )
+  , Mont::unsafe_make( [ 0x4c94fa48 , 0xebfb907f , 0xbab07d53 , 0x4d51740a , 0x12dafcc5 , 0x39936357 , 0xcc48f4ee , 0x1a27bf6e ] )
+  , Mont::unsafe_make( [ 0x597a3cd5 , 0x95f59abe , 0x45bfbbba , 0x76eaa82f , 0x753ed1fe , 0xe16e38f6 , 0xd2cff2fc , 0x0d58df88 ] )
+  , Mont::unsafe_make( [ 0x7ef6b8f2 , 0xe0413e45 , 0xafb06dc7 , 0x5799913e , 0x902f60c9 , 0xea81ffd1 , 0xacfbd7e0 , 0x2eda6bdf ] )
+  , Mont::unsafe_make( [ 0x0ee08941 , 0xd6bf39ed , 0x28809c82 , 0xf9114668 , 0x123aa754 , 0xe46f3f55 , 0x1e41edd2 , 0x2c422edf ] )
+  , Mont::unsafe_make( [ 0x67d19a4b , 0x74c9b9fc , 0xff53d7ef , 0xca2a4c4f , 0x361327f2 , 0x1ec38e13 , 0x9c8a5dba , 0x28b22f5c ] )
+  , Mont::unsafe_make( [ 0x27057329 , 0xec664b5e , 0xd6435885 , 0xf18ecc78 , 0x3838c7c0 , 0x052727eb , 0x33823511 , 0x2ef5fa2a ] )
+  , Mont::unsafe_make( [ 0x465d76ee , 0x7a872ae1 , 0xd48b19fc , 0x4669b68c , 0x15d9781d , 0xf7f40fe0 , 0x3598bfa4 , 0x06819d5a ] )
+  , Mont::unsafe_make( [ 0x24fc2547 , 0xf08f6161 , 0xe5cd4453 , 0xbee78777 , 0x11d57198 , 0x02a360e7 , 0xaaf000ba , 0x303ae173 ] )
+  , Mont::unsafe_make( [ 0xea3217ee , 0x7e8b3afe , 0x96d94e8e , 0x0fb19ab8 , 0x9fc51adf , 0x7b44fe87 , 0x5c493788 , 0x0a593b5f ] )
+  , Mont::unsafe_make( [ 0x4a23a6c8 , 0x7f046aed , 0x083604f7 , 0xa869d00c , 0x95bb6a29 , 0x30546450 , 0xe154df4a , 0x22e78555 ] )
+  , Mont::unsafe_make( [ 0xe9b94036 , 0x96b98043 , 0x851c089e , 0x7dfdcf84 , 0x6fcee87c , 0x5ed8a96b , 0xe987eea9 , 0x046e4ede ] )
+  , Mont::unsafe_make( [ 0x4c15a284 , 0x4292bf23 , 0x6b89fd78 , 0xc82df4d1 , 0x0767c3ee , 0x5135634f , 0xcff5867a , 0x07ae9403 ] )
+  , Mont::unsafe_make( [ 0x24188f11 , 0x81c3e8c6 , 0x16cbd0cb , 0xe8eee079 , 0x17bebb16 , 0x975fbb9f , 0x7953df39 , 0x2f8354c7 ] )
+  , Mont::unsafe_make( [ 0xb4da4264 , 0xc442eec7 , 0x1d2903e6 , 0xa4bab5c2 , 0x58c71990 , 0x0554dd0c , 0x491af8e5 , 0x138bdb37 ] )
+  , Mont::unsafe_make( [ 0x2205ab84 , 0x66e82b14 , 0xa3c2f6d8 , 0x19957166 , 0x6981b40f , 0x3da12124 , 0x97e10d89 , 0x1c351a61 ] )
+  , Mont::unsafe_make( [ 0x1899b4ee , 0xd33ab705 , 0x9e0373b5 , 0x389e13a0 , 0x3cc27966 , 0x1d65ae18 , 0xa5108595 , 0x0946b3c3 ] )
+  , Mont::unsafe_make( [ 0x3927fdd5 , 0x341c7abb , 0x41370400 , 0xa36a42e0 , 0x863458ca , 0x2912fba4 , 0x49947a53 , 0x227a59c0 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x49d42d7c , 0x7bdd9a2c , 0xe6324f3a , 0xab2838e6 , 0xd690babe , 0x4cb92e07 , 0xfbbe037f , 0x2dc2abf9 ] )
+  , Mont::unsafe_make( [ 0x81a00ba6 , 0x3d3f0608 , 0x3fd3f083 , 0x1638ec92 , 0xc2310f85 , 0x7f6072a2 , 0xcc67f61f , 0x115274c3 ] )
+  , Mont::unsafe_make( [ 0x4db9927d , 0xc20a9857 , 0xbd6b3e56 , 0x1cc34d6e , 0x8be1f701 , 0x5d380db4 , 0x4b20bdd2 , 0x0939512a ] )
+  , Mont::unsafe_make( [ 0x901835a8 , 0x3d95031e , 0xe3d387c4 , 0x9c31e803 , 0xdf87508b , 0x2daa1ccb , 0x22dac9ec , 0x2641e4f2 ] )
+  , Mont::unsafe_make( [ 0x3ce0e81c , 0xd9019ea4 , 0x5927299f , 0x9e5a1d8e , 0x0ec32861 , 0xf5f37831 , 0x994e96ee , 0x0153f5cd ] )
+  , Mont::unsafe_make( [ 0xd982ec54 , 0xa240f69e , 0xe9d672b2 , 0x2348178f , 0x28bd5d83 , 0xf8f2d9ef , 0x259bba26 , 0x29482ae2 ] )
+  , Mont::unsafe_make( [ 0xf5f6612d , 0xab9b8c35 , 0x59e4f3fb , 0x27f3780d , 0x07900eee , 0xa539ea87 , 0xb6b61485 , 0x0150f169 ] )
+  , Mont::unsafe_make( [ 0x82c6ff8a , 0x439a4133 , 0xfbbefdcd , 0x446f08d9 , 0xb68db24a , 0x10105821 , 0x16546ed4 , 0x09bf672b ] )
+  , Mont::unsafe_make( [ 0x732297cc , 0x698ca96a , 0xe0a2a38e , 0x51fae4af , 0x2ce82302 , 0x33d10b87 , 0x3cbe431f , 0x148e4b7e ] )
+  , Mont::unsafe_make( [ 0x5d8d01c1 , 0x43f70df1 , 0xb3f7c446 , 0xb281e5bc , 0xfd4d4fea , 0xfa3c5763 , 0xd1716022 , 0x2c68d211 ] )
+  , Mont::unsafe_make( [ 0xf89d9eca , 0x4be37d18 , 0x13905bea , 0x94321603 , 0x5c6cb76f , 0x602f2771 , 0x3568c4bb , 0x0e89e3e0 ] )
+  , Mont::unsafe_make( [ 0x8fc988d6 , 0x51489d0a , 0x82b75c07 , 0xe9aa7097 , 0x69688776 , 0x2fd6310b , 0xb09d5166 , 0x260441fc ] )
+  , Mont::unsafe_make( [ 0x6cd51ffa , 0x2d92a710 , 0xdd868700 , 0x73c54534 , 0xe7dbbe9c , 0xaccf22b1 , 0x7887057e , 0x1f8f4090 ] )
+  , Mont::unsafe_make( [ 0xeb3e3021 , 0xf3072875 , 0x2a4b5079 , 0x5c32e554 , 0x8b3d05da , 0xce1c453a , 0xdd81876e , 0x00151bd5 ] )
+  , Mont::unsafe_make( [ 0x0ac77e8f , 0x21aacac6 , 0x5f7244b9 , 0x5f61e780 , 0x30b48958 , 0x8040cb27 , 0xfd2c7554 , 0x2fb2ec7f ] )
+  , Mont::unsafe_make( [ 0x7b1f9a1b , 0x5a5024a6 , 0x376968a5 , 0x30436fe7 , 0x11149fd0 , 0x4ac2dfff , 0xad02e1f2 , 0x271c8c46 ] )
+  , Mont::unsafe_make( [ 0xe905d30a , 0x96977f00 , 0xb282a458 , 0x27293dcb , 0xb84b6ca7 , 0x4c2316cf , 0xaa76c456 , 0x0824a46f ] )
+  , Mont::unsafe_make( [ 0xecd361c9 , 0x497ba9ee , 0xf43f7d67 , 0x9eda9dfc , 0x8d546da3 , 0xd4cedbf5 , 0xa5f6bc3a , 0x1680d52a ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xd95a7efb , 0xad3c5b35 , 0x0e736e8e , 0xa345ae54 , 0x232bb7ce , 0xaf14d74b , 0xbc9058d8 , 0x2b205912 ] )
+  , Mont::unsafe_make( [ 0xc397716a , 0xf3a8b2f9 , 0x9286206d , 0xfbcd17f7 , 0x7065155b , 0xf3794600 , 0xeafc05f4 , 0x17f4ddf2 ] )
+  , Mont::unsafe_make( [ 0xa78470af , 0xf28fcc67 , 0x2cd298e1 , 0xd1038e1b , 0xee61e6b1 , 0xa8ebc4ee , 0xccd59e0b , 0x2f7bbd29 ] )
+  , Mont::unsafe_make( [ 0xca3f2b5b , 0x6dd246a6 , 0x349ecc27 , 0x7af1bee6 , 0xe53e630f , 0xbc7346e4 , 0xdcc4c8ce , 0x1545f54a ] )
+  , Mont::unsafe_make( [ 0xa68e5c85 , 0x21a5b92a , 0xbb707534 , 0x525ad89f , 0x4cdcf3bf , 0xf655abdd , 0x96773e4e , 0x2cd702ae ] )
+  , Mont::unsafe_make( [ 0xd6d5efea , 0x31181fcb , 0x6294a418 , 0x5391f220 , 0xc430ed48 , 0xd9b98742 , 0x84e15a9c , 0x1d7b3fd7 ] )
+  , Mont::unsafe_make( [ 0xa2862f2a , 0x1a9ad05e , 0xe213ff6b , 0xa7942288 , 0x5aeaae1b , 0x55e37a44 , 0xb4e3c6d2 , 0x2a67cd56 ] )
+  , Mont::unsafe_make( [ 0x9049de39 , 0x91db3d0d , 0x3e53adcd , 0xb4e5684c , 0xbdb3f7e3 , 0xd64c18a5 , 0x19e6e572 , 0x10d1c3e0 ] )
+  , Mont::unsafe_make( [ 0x0045acff , 0xd4c874b0 , 0x1f7f2b77 , 0x1fb8573f , 0x4aa3807c , 0xf3462a96 , 0x8b2ca594 , 0x19e82e4f ] )
+  , Mont::unsafe_make( [ 0x119237c6 , 0xb399afcf , 0x89057806 , 0x9c26742d , 0xeea30ba7 , 0xdd9d0c0e , 0xbe89a6aa , 0x110885bf ] )
+  , Mont::unsafe_make( [ 0x5eb8043c , 0xfe8d1d5a , 0x3543cecf , 0xf4f830b0 , 0x149cf005 , 0x39454b21 , 0xaa4efe8b , 0x249d7343 ] )
+  , Mont::unsafe_make( [ 0xb36c76bc , 0x55c7e68d , 0xf18a654d , 0x35341081 , 0xb9d34a4a , 0x3493e5c4 , 0x2ac1316d , 0x1e566797 ] )
+  , Mont::unsafe_make( [ 0x70a80566 , 0x6f1fba0d , 0x13562687 , 0x47874579 , 0x8dd3110a , 0xc31337a0 , 0x72b47b9a , 0x0ba56889 ] )
+  , Mont::unsafe_make( [ 0x63d7fd5d , 0xa2be534d , 0xa5ceff36 , 0xbd6ba506 , 0xd018befb , 0xe00f1eb9 , 0x66438133 , 0x2222fa35 ] )
+  , Mont::unsafe_make( [ 0x69e16945 , 0x320cb578 , 0xb8c0e30f , 0x5ee0584c , 0x48c3da43 , 0x855d6e9e , 0xafe6bef6 , 0x1b912b2b ] )
+  , Mont::unsafe_make( [ 0xaa2f7798 , 0x947d8aa0 , 0x27984da7 , 0x2a2c7499 , 0x85075da3 , 0x6530a919 , 0xfe62ea2f , 0x20f038dd ] )
+  , Mont::unsafe_make( [ 0x8ba0bf00 , 0xc59617a0 , 0x5006ece8 , 0xa60ec2b5 , 0x32285da7 , 0x493605f1 , 0xe1764f5a , 0x1a36909d ] )
+  , Mont::unsafe_make( [ 0x484e1325 , 0xb3f44f52 , 0x36bc6ebc , 0x47e9fb93 , 0x57b6f7d2 , 0x60444296 , 0x4b0f6f41 , 0x1dc3f980 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x98906a9b , 0x21739e15 , 0xbea93b04 , 0xaa4fa9ac , 0xddd5513a , 0xd80c830f , 0x1af3b22f , 0x25e603fb ] )
+  , Mont::unsafe_make( [ 0x0a55565f , 0xe2d58c8d , 0xd3e64ff6 , 0xf21b25f4 , 0x8fa12ab9 , 0xc8133ebc , 0x587825e0 , 0x052803fa ] )
+  , Mont::unsafe_make( [ 0x26fe0f62 , 0xbadd5bcc , 0xbb8f5f33 , 0x41f7f1be , 0x40e3800d , 0x8ce675b7 , 0xf8def3db , 0x0ad7817a ] )
+  , Mont::unsafe_make( [ 0x21637559 , 0xe16642b7 , 0xb493ad8c , 0x2a6f01ac , 0xc983f1b8 , 0xcbadd17a , 0x60657879 , 0x17f02376 ] )
+  , Mont::unsafe_make( [ 0x16c7bcea , 0xc92e7bbd , 0xe3f08939 , 0x3f49ee5b , 0x69654936 , 0x05565fd2 , 0x478a4c54 , 0x1c888947 ] )
+  , Mont::unsafe_make( [ 0x2082d29d , 0xe138c131 , 0x1d9bfa63 , 0x3bd41194 , 0x1b9f94cd , 0xcc5c59c0 , 0xa72e3be2 , 0x03e3e7f5 ] )
+  , Mont::unsafe_make( [ 0xb5e45f84 , 0x72d6b9b7 , 0x23b6c0c5 , 0x77962218 , 0xf90e5ff6 , 0x24817bc4 , 0x0880c4c6 , 0x14469189 ] )
+  , Mont::unsafe_make( [ 0x182dd72d , 0x79729eec , 0x7f75a21b , 0x8db921ba , 0x9ac6fa37 , 0x3a95ff19 , 0x03c0c44f , 0x03a35c48 ] )
+  , Mont::unsafe_make( [ 0xe95f3fb6 , 0x9c38471e , 0x8d23dacb , 0xe46f6122 , 0x41dc679e , 0x46b25f52 , 0x102c1fd7 , 0x29357b6d ] )
+  , Mont::unsafe_make( [ 0x8d51333d , 0xb24f0263 , 0x2bb10e95 , 0xefbd13b5 , 0xcd221869 , 0xcdf94cc3 , 0xec62fc1d , 0x098a0baf ] )
+  , Mont::unsafe_make( [ 0xf9f020bd , 0xe4437140 , 0x0b23923d , 0x96797a01 , 0x5df09fd4 , 0xf83da860 , 0xa1ac9756 , 0x05e1f85c ] )
+  , Mont::unsafe_make( [ 0xf58df975 , 0x7956b2ed , 0x62ca6964 , 0xfa4da15f , 0xcc5b0307 , 0xba745d0d , 0x358a6e60 , 0x0602e7d4 ] )
+  , Mont::unsafe_make( [ 0xd97caaa3 , 0x37663cc0 , 0xa01c4ed8 , 0x2e387257 , 0x701ea987 , 0xe49a6a80 , 0x94355bb8 , 0x0e93ee95 ] )
+  , Mont::unsafe_make( [ 0x9886de9a , 0x287697e7 , 0x343f788e , 0x9c80e852 , 0xc9714b02 , 0x3288c6b5 , 0x5782af44 , 0x0f885718 ] )
+  , Mont::unsafe_make( [ 0xa4dc4cb2 , 0xab351757 , 0xb4ef9b7a , 0x63936860 , 0xc3d10409 , 0x7129a405 , 0x632eacbf , 0x0444ffb3 ] )
+  , Mont::unsafe_make( [ 0x80583e04 , 0x7d0a867b , 0x91e22011 , 0xb53252ba , 0xcd725cb1 , 0x51b99064 , 0x9d2e2fc8 , 0x0d5f4e33 ] )
+  , Mont::unsafe_make( [ 0x5c1a9cc8 , 0x870c3009 , 0x8dd9ecf1 , 0x16bcf47e , 0x0778e9c9 , 0x19a9f1e8 , 0xe32744c1 , 0x0c07cf7a ] )
+  , Mont::unsafe_make( [ 0xeb773aa9 , 0x17904634 , 0x75791cbb , 0x3e1d5350 , 0x2a05732d , 0x5f99a0de , 0x57e27681 , 0x04965222 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x1c358b27 , 0xeca6f363 , 0xff69900a , 0xbd1fd0f8 , 0xc1b9c4ce , 0x1b2b0325 , 0xa429ba00 , 0x1717e2a3 ] )
+  , Mont::unsafe_make( [ 0xee8fa0c5 , 0x9b1a895a , 0xc6b365cf , 0x4661d32f , 0x78ca67d6 , 0xc0947b4e , 0xb714a822 , 0x154490c0 ] )
+  , Mont::unsafe_make( [ 0xe49d611b , 0xf9c321e2 , 0x641e12e3 , 0x5b275d13 , 0x36791c15 , 0xda7dfa36 , 0xc6e5168e , 0x06f7c32e ] )
+  , Mont::unsafe_make( [ 0xf549c941 , 0x3dffe45c , 0x8eccf0bb , 0xb791f083 , 0x8b306dd7 , 0x3a402f05 , 0x2174187a , 0x055231b3 ] )
+  , Mont::unsafe_make( [ 0x83390f34 , 0x73bed71b , 0x5aae7e2a , 0xbe4cc793 , 0xeb3e2d9a , 0x3b476677 , 0x37484e7c , 0x056fa036 ] )
+  , Mont::unsafe_make( [ 0xcca33a8b , 0xcc55cf0f , 0x8d1e716d , 0xd29ce787 , 0xac46531e , 0x315bad7e , 0x63c436b0 , 0x0eabf991 ] )
+  , Mont::unsafe_make( [ 0xa5e35abe , 0x29fc583a , 0x7f21f714 , 0xc4f0a1b3 , 0xa1dcac64 , 0xa5f4cf3d , 0x5ade658d , 0x2f7c2bb4 ] )
+  , Mont::unsafe_make( [ 0x0fc96b5e , 0x5c6ffc3a , 0xa6a5cf48 , 0xc5a0ce82 , 0x71f53581 , 0x03953fb2 , 0xf96fabab , 0x0d955e73 ] )
+  , Mont::unsafe_make( [ 0x66904497 , 0xc1d9f7db , 0x04fa384f , 0x1ea3dd57 , 0x1ab20f95 , 0x7ba21336 , 0x181cfc77 , 0x01016aa4 ] )
+  , Mont::unsafe_make( [ 0x10e1412e , 0xeb377dc4 , 0x17a1e5bd , 0xb039ae1d , 0xc3088652 , 0xf75a7f30 , 0xdc3f249b , 0x1d73dc3b ] )
+  , Mont::unsafe_make( [ 0x3e915025 , 0xef82fe20 , 0x61cdf6a0 , 0x0b6a1734 , 0xf496ac65 , 0xd9d638c3 , 0x2037c0bb , 0x11eaa02c ] )
+  , Mont::unsafe_make( [ 0x823ada70 , 0xd187ac56 , 0xeb895d30 , 0x51a35da1 , 0x7287e3a4 , 0x7e250f96 , 0xef91eb99 , 0x003ac18a ] )
+  , Mont::unsafe_make( [ 0x80a98ddf , 0x4834af89 , 0xa4c9a735 , 0x2eb1d357 , 0x737f16da , 0xf560f812 , 0x3fb8408c , 0x17068e79 ] )
+  , Mont::unsafe_make( [ 0x28c745c3 , 0x111ed915 , 0x696f3edc , 0x7c855f6f , 0xeb21adc1 , 0x971cde04 , 0xfabe9040 , 0x24db9413 ] )
+  , Mont::unsafe_make( [ 0x0effa3b7 , 0x7c44a7a1 , 0x0183b332 , 0x29ed0561 , 0xce497a27 , 0x5628216c , 0xb6edff85 , 0x209572da ] )
+  , Mont::unsafe_make( [ 0xb3964262 , 0x977e7598 , 0xbdeacf3f , 0x69a0641b , 0xb7e25efc , 0xa5b03fd1 , 0xec01c39b , 0x1a889cea ] )
+  , Mont::unsafe_make( [ 0xf9214240 , 0x25ce9e37 , 0xec78055e , 0xaffba2a8 , 0xe6c6f1ed , 0x0b291be4 , 0x9a1941af , 0x19537418 ] )
+  , Mont::unsafe_make( [ 0x43dca127 , 0xc48dd7cb , 0xf3681334 , 0x20d3365b , 0x0e72b7bb , 0xa0198d63 , 0x4fb5b5a6 , 0x115ed20c ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xba195ee8 , 0x50ca9616 , 0x2b4db893 , 0x5c8500c7 , 0xdef6b7d3 , 0xfec3ad4f , 0x9bffc612 , 0x0bfdd872 ] )
+  , Mont::unsafe_make( [ 0x2a6696fc , 0x2f273922 , 0xfbb0a14f , 0xe04e8c28 , 0x7d5d4122 , 0x3adcd91d , 0xdab2a7a3 , 0x2e6127b1 ] )
+  , Mont::unsafe_make( [ 0xd2329c06 , 0x80774634 , 0x45e076c3 , 0x009c9a2e , 0x74f41702 , 0xe416fb2b , 0x3bb47d04 , 0x2b875f17 ] )
+  , Mont::unsafe_make( [ 0x0a7b995c , 0xa9d9f41e , 0xb033b36b , 0xbedbf38c , 0x62f94952 , 0x225d496e , 0xf5c9c81b , 0x05a39230 ] )
+  , Mont::unsafe_make( [ 0x4025dcb7 , 0x8450cab0 , 0x21ae6fc3 , 0x1996cb23 , 0x3450dd39 , 0xdb34f25d , 0xa00ef0d6 , 0x2989b1cf ] )
+  , Mont::unsafe_make( [ 0x8e557887 , 0x466d591f , 0x0082470d , 0xdf2b6923 , 0x1bc52db1 , 0xaff867a4 , 0x17960b7d , 0x0becf18f ] )
+  , Mont::unsafe_make( [ 0xa0c42848 , 0x2fa95486 , 0x3de99810 , 0xc63d9657 , 0x4998a006 , 0x709665f5 , 0x77de33d3 , 0x16afce1b ] )
+  , Mont::unsafe_make( [ 0xd5c65812 , 0x422fa57f , 0x7c8b0750 , 0x93c90dda , 0x2da56a47 , 0xe3faa4c5 , 0x3ea1e6b3 , 0x207b6547 ] )
+  , Mont::unsafe_make( [ 0xc90e8251 , 0xfed719f6 , 0x775a6c34 , 0x449f9ee6 , 0x360e71e1 , 0x03233d00 , 0x39ca267e , 0x1890c749 ] )
+  , Mont::unsafe_make( [ 0x1f735141 , 0xc6aa6950 , 0x14ebf133 , 0x9d527ae3 , 0x09e3ef74 , 0xb15d6c44 , 0xd0eca233 , 0x2804d608 ] )
+  , Mont::unsafe_make( [ 0x65ede0fe , 0x57525575 , 0xb683eb38 , 0xb71d8117 , 0xad6c153c , 0xf9a64948 , 0x76a810fd , 0x02312f23 ] )
+  , Mont::unsafe_make( [ 0xa6a5a266 , 0x935ccb7a , 0x7c895fc4 , 0x0f3176c6 , 0xa0acf08c , 0xe13461e2 , 0xfcd9dff2 , 0x11535073 ] )
+  , Mont::unsafe_make( [ 0x3f1647d7 , 0xf50689bb , 0xe8a3375d , 0x96f07a9f , 0xaad8dc97 , 0x8bab817d , 0xc8fb4f60 , 0x16fc46e5 ] )
+  , Mont::unsafe_make( [ 0x39249d22 , 0x3a7bda2f , 0x7c8a1d94 , 0x753d152f , 0xd338c650 , 0x80674c23 , 0xe7ff2e73 , 0x267b7ed2 ] )
+  , Mont::unsafe_make( [ 0xb03ec299 , 0xf9917a86 , 0x0ff66ad7 , 0x0ad11732 , 0x4adfc4e8 , 0x2ecc8ab1 , 0x80ba0766 , 0x156172a2 ] )
+  , Mont::unsafe_make( [ 0xeecfb780 , 0x5aff85ea , 0xd8e9b4c3 , 0xb4e6851f , 0x615d3467 , 0x5cc4e702 , 0x3179b457 , 0x20f92ca8 ] )
+  , Mont::unsafe_make( [ 0x65f925dd , 0xe2db5b6b , 0x77c00d16 , 0x50b0f080 , 0x1afc1eed , 0x3c6799ae , 0x9b865ce2 , 0x0384cbdf ] )
+  , Mont::unsafe_make( [ 0x8e9c2f86 , 0xe85672c5 , 0x4d8c4027 , 0x902e9c23 , 0x76b72439 , 0x3a0ba166 , 0x2b7b35a3 , 0x0008d867 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xefdbf3ba , 0x28555097 , 0x447129a1 , 0xa06493e1 , 0x48275f36 , 0x113835fb , 0x642fd31b , 0x0ab6b859 ] )
+  , Mont::unsafe_make( [ 0x531da9a9 , 0xc426bb7d , 0x2fcc9f40 , 0xcf4c94ed , 0x9f15cb5a , 0x282ecf32 , 0x115cc43f , 0x1d3ec473 ] )
+  , Mont::unsafe_make( [ 0x3b08bf26 , 0xf6da5457 , 0x3ee94a03 , 0x4a8a9f71 , 0xced882d7 , 0x30a4a8f1 , 0xee03724a , 0x2a35deb5 ] )
+  , Mont::unsafe_make( [ 0xd4aac30c , 0x8cbbd466 , 0x7cdfefff , 0x4817ab3f , 0xbda130dc , 0x0a5a2214 , 0xdcf3f323 , 0x20782eba ] )
+  , Mont::unsafe_make( [ 0xfc4e94d3 , 0xc4dd0013 , 0x195911c2 , 0xa78dfffe , 0x32234d87 , 0xb42390b5 , 0x88dcc483 , 0x1266f551 ] )
+  , Mont::unsafe_make( [ 0xc81b0f48 , 0xca884c1f , 0x115f635d , 0x787d18f0 , 0xb52db0f7 , 0x3483bf24 , 0xe4c50c24 , 0x29fb8004 ] )
+  , Mont::unsafe_make( [ 0x95bb72bd , 0xa96f287e , 0xdc515853 , 0xe1b4f487 , 0xdb34333e , 0x4a434d8c , 0x8b6a1b86 , 0x053a31a5 ] )
+  , Mont::unsafe_make( [ 0x9c89abbc , 0x62f45bdd , 0xe4392696 , 0x574c6990 , 0x41fdfa1e , 0xdb02ec34 , 0x0c4db635 , 0x053898d7 ] )
+  , Mont::unsafe_make( [ 0xa6ba2ad9 , 0x3bb119c7 , 0xb1ae19ea , 0x6ce63ec3 , 0x140daeae , 0xd31a6bc7 , 0x2a18f26a , 0x1169b8fb ] )
+  , Mont::unsafe_make( [ 0xb1a0dac4 , 0x08f77783 , 0xa9e2b03d , 0x8035f9bf , 0xc72224ac , 0xe9a222ce , 0x9cbc6b5f , 0x168cb4f5 ] )
+  , Mont::unsafe_make( [ 0x99ffa30d , 0x4717fc16 , 0x733d9831 , 0x039fcfa2 , 0xf4e28032 , 0x7c5c8a8e , 0xa0a671d4 , 0x0e852430 ] )
+  , Mont::unsafe_make( [ 0x07aa93e0 , 0xdc566c65 , 0xf9388065 , 0x141b73a1 , 0x4bf11fca , 0xa37f601a , 0x57ba869c , 0x2f26e8a4 ] )
+  , Mont::unsafe_make( [ 0x9d6df745 , 0x0ce2c858 , 0x819595f4 , 0x87cd96da , 0x2498d452 , 0x79a4fa7f , 0xbdc126c1 , 0x0f9e3c60 ] )
+  , Mont::unsafe_make( [ 0xfd01c8c3 , 0xd609fcfa , 0x1db2f20f , 0x8ebd506c , 0xac491e59 , 0x19ce60f7 , 0x8cab9f84 , 0x297351a6 ] )
+  , Mont::unsafe_make( [ 0x071cb7dc , 0xa174b9d3 , 0x00682269 , 0x362431af , 0xba818638 , 0xeb304ece , 0x45d230af , 0x1d3796cb ] )
+  , Mont::unsafe_make( [ 0xbf0b0415 , 0xba712020 , 0xce493a75 , 0x3b25de37 , 0xa97df801 , 0x5806c7ca , 0xed14908e , 0x08ecd37d ] )
+  , Mont::unsafe_make( [ 0xea2fb892 , 0xf4933f71 , 0x19d55f2f , 0x2d0a77f9 , 0x888d373a , 0x2558ceb7 , 0x7714b182 , 0x1cc1fc7d ] )
+  , Mont::unsafe_make( [ 0x155f228d , 0x2f96ae9a , 0x07542a1b , 0xb7acb3ed , 0x4a1c84c5 , 0x4ee43e84 , 0xa9c48cea , 0x2cc847cc ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xf4020697 , 0x05c9b71f , 0x015eb0c1 , 0x3279ecca , 0x2c190041 , 0x509df008 , 0x52f500d8 , 0x248f2529 ] )
+  , Mont::unsafe_make( [ 0x4da40aa9 , 0xe168dee6 , 0xdd764c96 , 0x5e05ee81 , 0x55451542 , 0xca18cf4d , 0x8e48e9f8 , 0x255f6f21 ] )
+  , Mont::unsafe_make( [ 0x9eff1c5a , 0x1d09f884 , 0x3cd81c1d , 0x0554109b , 0x2104781d , 0x80bd4485 , 0xf26f7c75 , 0x28e7b225 ] )
+  , Mont::unsafe_make( [ 0x7dccca0e , 0xde7ffb1b , 0x77f174dc , 0xbde3f544 , 0xdb6fedc5 , 0xed5c4d80 , 0xfb7e7223 , 0x2d161bd2 ] )
+  , Mont::unsafe_make( [ 0x96df8492 , 0x3e23b991 , 0xcdb69712 , 0x87ecfb7e , 0x2589d260 , 0x59d54cd0 , 0x7913f78a , 0x10f139ea ] )
+  , Mont::unsafe_make( [ 0x837911ff , 0x671ff553 , 0x9c629336 , 0xa2847bb6 , 0x8f088c3b , 0xc260da17 , 0x7d56e3fd , 0x287694cb ] )
+  , Mont::unsafe_make( [ 0xbe2ec58f , 0x20f1a629 , 0xf6b73055 , 0x8760ad8c , 0x71c97925 , 0xfd0d9b54 , 0x9e3944a4 , 0x2e479f5d ] )
+  , Mont::unsafe_make( [ 0x766b9b5b , 0x1468e71e , 0x9d56861f , 0xea250bdc , 0x7b762421 , 0xea8974f5 , 0x57e6f078 , 0x2ef66821 ] )
+  , Mont::unsafe_make( [ 0x8d6bd953 , 0x9bc8642e , 0x043016fc , 0x796739d6 , 0x363ed5f5 , 0x3aa6242a , 0xe36d04f4 , 0x11d02cc7 ] )
+  , Mont::unsafe_make( [ 0xa77752b0 , 0x3310c595 , 0xe8190623 , 0x958b1bf6 , 0xcf10348d , 0x92a293cd , 0x67244b6c , 0x2989ffa9 ] )
+  , Mont::unsafe_make( [ 0xbb02522d , 0x01e7dccb , 0x6b0d5e74 , 0xa93286e2 , 0x70632f05 , 0x67b9483e , 0xc5b53a42 , 0x2b8fefb7 ] )
+  , Mont::unsafe_make( [ 0x31f32ce6 , 0x637bf2f9 , 0x37da63dd , 0x3c8a3f97 , 0x26f20a0c , 0x45bf1f80 , 0xabdd2381 , 0x0bbb9396 ] )
+  , Mont::unsafe_make( [ 0xd91ac642 , 0x259b2fe1 , 0xde36eae8 , 0xa3210a6e , 0xb90ac96c , 0x124145c0 , 0x0aa2da6b , 0x2e63ed25 ] )
+  , Mont::unsafe_make( [ 0xf8bf70a3 , 0x2718eebb , 0xb63e87ea , 0x6247603d , 0x31d9f7db , 0x03062f64 , 0x0d9ec905 , 0x2a888d4b ] )
+  , Mont::unsafe_make( [ 0x46bf64bf , 0x8d19f700 , 0x99ead5b9 , 0x19c30c3d , 0x548436b4 , 0x272ab220 , 0x2f74acd2 , 0x30316d3d ] )
+  , Mont::unsafe_make( [ 0x8eb9c1ae , 0xeff2b21e , 0x50161af8 , 0x0faa63a0 , 0xa80b2b0e , 0x63ea555b , 0x41b1b9fd , 0x0d5e1fa3 ] )
+  , Mont::unsafe_make( [ 0xc0a61daa , 0x853c2785 , 0x050aae7d , 0x838bf8b7 , 0xf5fcbfd3 , 0x1d05e28f , 0x93d54062 , 0x002fb7e9 ] )
+  , Mont::unsafe_make( [ 0xe114bbbb , 0x5a1cc3b6 , 0x098892c8 , 0x550cbb9b , 0xe61e5616 , 0x1d009e4b , 0x70b4210f , 0x27b2f16f ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xc60a36dc , 0x5e29dfc8 , 0xa69f1f56 , 0x6a44ad9d , 0x8b89e749 , 0xb8a3f91b , 0xd2b4f2b0 , 0x00fd9830 ] )
+  , Mont::unsafe_make( [ 0x93fc9a1b , 0xfee5882b , 0x60eab50c , 0x03ad3332 , 0xfa71ffd4 , 0x15555558 , 0x5a3f08e9 , 0x11336b01 ] )
+  , Mont::unsafe_make( [ 0x3cac6725 , 0xd035162c , 0x1980bb84 , 0xa135fb79 , 0x1719ac0e , 0x133d9873 , 0x03dcf76d , 0x303d0027 ] )
+  , Mont::unsafe_make( [ 0x2e90c61d , 0xfbc2bb3a , 0x8f2d9da0 , 0xc37c5eb1 , 0xc7cf73ad , 0x5f02fbd5 , 0x53236c29 , 0x1336d631 ] )
+  , Mont::unsafe_make( [ 0xd796b040 , 0xe6177fa7 , 0x7ccb67bb , 0x68d753a2 , 0xdbbc7f5b , 0xf393b177 , 0x11301fda , 0x16aca674 ] )
+  , Mont::unsafe_make( [ 0xad33a2e2 , 0x34f7c983 , 0xf6656c42 , 0x1271d4bf , 0x013c9702 , 0x2e626f00 , 0x11b219e1 , 0x277d51bd ] )
+  , Mont::unsafe_make( [ 0xe7e6c679 , 0xd7da81b8 , 0x36e8d109 , 0xb3ee6e1c , 0x6470fa75 , 0x53f7a7a9 , 0x0157d2f2 , 0x0b9d09e1 ] )
+  , Mont::unsafe_make( [ 0x373bc9a2 , 0x6ef9cb97 , 0x57073edb , 0x77d8f39e , 0x9dec9ead , 0x808c0626 , 0x8bab87d9 , 0x23255dec ] )
+  , Mont::unsafe_make( [ 0x191718da , 0x570311e1 , 0x7473655d , 0x6541920e , 0x0ff6199e , 0x04ea6770 , 0xcaf0b63f , 0x16c47b37 ] )
+  , Mont::unsafe_make( [ 0x5e7a8b3a , 0xb7f20cff , 0x92df8d80 , 0x275c6de2 , 0x802d68a6 , 0x20ef7548 , 0x85eab569 , 0x0b56d157 ] )
+  , Mont::unsafe_make( [ 0xf8bdf211 , 0x55faed94 , 0x088f1a0f , 0x6a8a2819 , 0x0cbae0da , 0xfb0cdb32 , 0x4b54b924 , 0x1c664183 ] )
+  , Mont::unsafe_make( [ 0x0f80e2fb , 0x631a600e , 0x82f86797 , 0xaa2d88d2 , 0x4495fb3f , 0xf9157896 , 0x061ff9db , 0x1a846755 ] )
+  , Mont::unsafe_make( [ 0xc897730f , 0x4e96ff9e , 0xe0629132 , 0x01f90944 , 0xe06990c4 , 0x5fb46dce , 0x967c4aa4 , 0x19016318 ] )
+  , Mont::unsafe_make( [ 0x227393a7 , 0x2a0d4882 , 0xcf6e83c4 , 0xfb9bda6a , 0xe18f201d , 0x01907cac , 0xe662c681 , 0x135c94f5 ] )
+  , Mont::unsafe_make( [ 0xfa012dda , 0x17aecc0b , 0xbb8a974f , 0x8d9aa145 , 0xb26e2018 , 0x484369a7 , 0x2a5130a4 , 0x09b89c7b ] )
+  , Mont::unsafe_make( [ 0x06e50e33 , 0xda01ad54 , 0x83d9eb59 , 0xe10cf4ea , 0xf4ee6ebf , 0x6654f1bf , 0x7058e2e6 , 0x1f10a5c9 ] )
+  , Mont::unsafe_make( [ 0x1fe1fc3c , 0x2b1bed24 , 0x21650629 , 0x8fdc23fb , 0x58758560 , 0x5292c59e , 0xd9e13286 , 0x288a21bd ] )
+  , Mont::unsafe_make( [ 0x754a3ae9 , 0x86402180 , 0x803c9f9f , 0x3f17b2b7 , 0x6246c814 , 0xf61c709f , 0xc883a0ba , 0x2cc55c5d ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x93b1c7dc , 0xacc2d65c , 0x8f0083df , 0xaeb0c1ed , 0xc4f0cb17 , 0x865daba5 , 0xed2782d3 , 0x020f2270 ] )
+  , Mont::unsafe_make( [ 0x9e94648d , 0xe6b4a03d , 0xc87154da , 0xc79340bf , 0xd10969f0 , 0xdc78a1bc , 0x3089f930 , 0x075e3ac2 ] )
+  , Mont::unsafe_make( [ 0xc8b99490 , 0x22290a09 , 0xc872cfa0 , 0xda00ae4f , 0x5dbd71dc , 0x2dee985a , 0x3387d4bf , 0x0aa0b192 ] )
+  , Mont::unsafe_make( [ 0xe9e289ab , 0xa5b0c6f7 , 0xfb2c0124 , 0xed366a86 , 0xc562f12b , 0xa630e3ce , 0x94d084d4 , 0x1e17da5c ] )
+  , Mont::unsafe_make( [ 0x6895da54 , 0x71ccba7a , 0x6f334265 , 0xba830805 , 0xb7e2ec91 , 0x510df08c , 0xefeb0a64 , 0x13da6a39 ] )
+  , Mont::unsafe_make( [ 0x554811c2 , 0xc8738c85 , 0x3e1ed837 , 0x19dbb0af , 0x07679383 , 0x141b0134 , 0x07baef17 , 0x011e2916 ] )
+  , Mont::unsafe_make( [ 0x5ba795cf , 0x0dd230f9 , 0x7095e460 , 0xd2b17a6f , 0xd8299abc , 0xfb0cb634 , 0x8ed323ae , 0x084b48e0 ] )
+  , Mont::unsafe_make( [ 0x5eb5a79b , 0xb2c1d9c1 , 0x93e00543 , 0x5c818677 , 0x775a3db2 , 0xdf9b022b , 0xd6934b91 , 0x233899a3 ] )
+  , Mont::unsafe_make( [ 0xfcfc3a17 , 0xaf735606 , 0x60ad0a43 , 0xf075e5b9 , 0x55651f78 , 0x752a159f , 0x9c0e56ff , 0x1829d21a ] )
+  , Mont::unsafe_make( [ 0x27270ac5 , 0x0ad54501 , 0xd98ad5a2 , 0xc4feef65 , 0xd0807d87 , 0xeb9274e6 , 0x6191722c , 0x08f5ce5d ] )
+  , Mont::unsafe_make( [ 0x63a6d9c8 , 0x97bc6840 , 0x48c982b4 , 0x983af4d0 , 0x969a65e4 , 0x77b74bd4 , 0xdfe0a69b , 0x048ac73f ] )
+  , Mont::unsafe_make( [ 0x908a0b7d , 0x39918627 , 0x33696440 , 0x9300b39a , 0x6b672416 , 0xae837f1d , 0x32b3bb56 , 0x2ada450f ] )
+  , Mont::unsafe_make( [ 0xeda5387e , 0x6550356a , 0x761468d9 , 0x4e6102c8 , 0xb9c006e2 , 0x406c28b2 , 0xbe930aef , 0x1c25abf6 ] )
+  , Mont::unsafe_make( [ 0x71147117 , 0x275ba01d , 0xc9f937a7 , 0x24eefc21 , 0x1416e068 , 0x3d2667c9 , 0x7f216c3c , 0x0c72dd83 ] )
+  , Mont::unsafe_make( [ 0x24452abf , 0x010d4f7f , 0x2784cda0 , 0xfe6ad75b , 0x4885ef5b , 0x6e5e540b , 0xa066c1e5 , 0x2fbb9b1e ] )
+  , Mont::unsafe_make( [ 0xc4452db2 , 0x1217ed5b , 0x48412e37 , 0xed8db701 , 0xe1436584 , 0x9db6c253 , 0x7faa4a2e , 0x0bb4e94f ] )
+  , Mont::unsafe_make( [ 0x4d99cd38 , 0xedfc8222 , 0x9c1c684d , 0x09ac044e , 0xec74291d , 0xb8883eac , 0xd6d5c6a4 , 0x0e82ca9e ] )
+  , Mont::unsafe_make( [ 0x2308699d , 0x2b272058 , 0x7f67fcab , 0x819c268f , 0xc3d99833 , 0xa12f92d5 , 0x59337e52 , 0x25ea10f4 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x9dbea2c9 , 0xd5f83196 , 0x0f41509b , 0x6f45ba2d , 0xb9d3da25 , 0x24fc8130 , 0x44a5da8f , 0x27cbae41 ] )
+  , Mont::unsafe_make( [ 0xa65a1f0d , 0xb3f40dce , 0xef393434 , 0x2759ab2c , 0x6dcae319 , 0xa764652c , 0x9cf1b177 , 0x11a6fcf4 ] )
+  , Mont::unsafe_make( [ 0x3d7a87d2 , 0x50668eaa , 0xdbef0e2c , 0x4386321d , 0x94da50af , 0x1c3703a7 , 0x1adf06fe , 0x193c2dfd ] )
+  , Mont::unsafe_make( [ 0x14b3f53e , 0xc002999f , 0x10ac2b54 , 0x7df78162 , 0x0acf592b , 0x2dd2c70e , 0x17e7f4cf , 0x1cb9bccc ] )
+  , Mont::unsafe_make( [ 0xa95b42ad , 0xfd7d1134 , 0xdb642d31 , 0x2fed9a34 , 0xa8929710 , 0x57ab5b41 , 0x0426e798 , 0x0ebaa9fe ] )
+  , Mont::unsafe_make( [ 0xe4a6cac0 , 0x584c352e , 0x78453766 , 0xfae9a880 , 0xd035ad98 , 0x3703b645 , 0x985d873b , 0x1f7386ea ] )
+  , Mont::unsafe_make( [ 0xb285ed03 , 0xf710c230 , 0xf543ba1b , 0x661fb5b7 , 0x3f9900b4 , 0x93b9c320 , 0x6036ec70 , 0x20b87d3d ] )
+  , Mont::unsafe_make( [ 0x38694286 , 0xee6a6c59 , 0xf30c9825 , 0xfe56b536 , 0xb25e4757 , 0xc27485a1 , 0xb87e665a , 0x2b7abb99 ] )
+  , Mont::unsafe_make( [ 0x249d610b , 0x81b7b4b7 , 0x451cf177 , 0x8ecfdbac , 0x2f4e5337 , 0x7bee6d0c , 0x0da25abe , 0x16028765 ] )
+  , Mont::unsafe_make( [ 0xe4d265a0 , 0x75556397 , 0xb481b24c , 0x0b02b569 , 0x52d2d37f , 0x2990bac1 , 0x63a25f3b , 0x1e5e43e3 ] )
+  , Mont::unsafe_make( [ 0x2c942d04 , 0x04ec3196 , 0xb8e38509 , 0xcf605e28 , 0x38b6db01 , 0xe4211328 , 0x54252ada , 0x0973ba27 ] )
+  , Mont::unsafe_make( [ 0xa7b61fc8 , 0x6bdd190c , 0xbbe72c12 , 0x75262bba , 0x6c3c5085 , 0xa359762c , 0x99b2b66b , 0x1fae299a ] )
+  , Mont::unsafe_make( [ 0x0173f152 , 0xdece5e5f , 0x4e506eba , 0x91814388 , 0x3c3aeac1 , 0xc3d6c212 , 0x60146615 , 0x004e9a6d ] )
+  , Mont::unsafe_make( [ 0x7e863712 , 0xeaa4303d , 0x11066776 , 0x57975e5a , 0xaeee15db , 0xb473092f , 0x66ea425b , 0x2fc2c171 ] )
+  , Mont::unsafe_make( [ 0x0243340f , 0xa0f79623 , 0xde749f19 , 0x1349f554 , 0x0315439b , 0x5f5c9db7 , 0xc560b693 , 0x00928e8d ] )
+  , Mont::unsafe_make( [ 0x7a517295 , 0xbfe2c1bd , 0xa65e9817 , 0x36ce478d , 0x85d02e77 , 0xc450b911 , 0x886e444b , 0x26da5e17 ] )
+  , Mont::unsafe_make( [ 0x0631299f , 0x0536bbf2 , 0xb3379eb8 , 0xc3b63f52 , 0x3a1a11a7 , 0x574bad0f , 0xf82e6ea7 , 0x0b810f9f ] )
+  , Mont::unsafe_make( [ 0x7629de2a , 0xe2f0136f , 0x275e73cf , 0x3c516dff , 0xcd7d4ccf , 0x40c8c091 , 0x6cdd38b7 , 0x0de3a0ff ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xe4821c16 , 0x25b054db , 0x4ff2a668 , 0xa0d4d39a , 0xafae6c65 , 0x44c02700 , 0x0cc04ad3 , 0x0b55f2fe ] )
+  , Mont::unsafe_make( [ 0x6606e29b , 0x955dc77b , 0x45fe6d20 , 0xc2209150 , 0x71a70d0a , 0xf94ce2a9 , 0x4fe06113 , 0x02f605fb ] )
+  , Mont::unsafe_make( [ 0x28f8adc1 , 0x052424b1 , 0x291264a3 , 0x5439c0c3 , 0xd8bc00e6 , 0x69d0411f , 0xb66adc78 , 0x2c0dfc66 ] )
+  , Mont::unsafe_make( [ 0x580859c7 , 0xe8901635 , 0x6e40daeb , 0x9f09c5dc , 0x15a39d57 , 0x90ed4612 , 0x44058946 , 0x26d28d66 ] )
+  , Mont::unsafe_make( [ 0x16e1fb65 , 0x66ed81f4 , 0xa2ea7b07 , 0x2d2ae507 , 0x8dd6138a , 0xb4c89fcb , 0x0e2340a1 , 0x2b5042e7 ] )
+  , Mont::unsafe_make( [ 0xddf4d95d , 0xc762f53d , 0x1b637d8a , 0x081adf02 , 0x39de1285 , 0x9cea2336 , 0x60fb493b , 0x2a8dcb74 ] )
+  , Mont::unsafe_make( [ 0xef835877 , 0x19ae5e38 , 0x885e6266 , 0x8d86ceeb , 0xced9d3f4 , 0x4ae52a40 , 0x9033b76d , 0x1713c36f ] )
+  , Mont::unsafe_make( [ 0x48b70d50 , 0x3bf41248 , 0x1048c2a4 , 0x8130a94e , 0xa4522e3c , 0x971d2ec1 , 0xb2054dce , 0x0cb5a1b3 ] )
+  , Mont::unsafe_make( [ 0xe2c8d8d7 , 0xc2029659 , 0x1421857c , 0xead14fc6 , 0x85c3ed25 , 0x6342af9e , 0x3a06a00c , 0x2cb29ff0 ] )
+  , Mont::unsafe_make( [ 0xb71e69b6 , 0xb7a8ce2f , 0x8f346cf3 , 0x2ee2bd46 , 0x0bc23417 , 0xf017a4c6 , 0x1125643d , 0x21a02343 ] )
+  , Mont::unsafe_make( [ 0x3d2a644e , 0x8c2846c6 , 0x0f82b3b4 , 0x71a3fd0b , 0xf405628f , 0xc0e172d2 , 0x57589710 , 0x0521994b ] )
+  , Mont::unsafe_make( [ 0x4d8fad80 , 0x10459e05 , 0x533cfeee , 0xc99c3646 , 0x7cfd6268 , 0xb921d1e3 , 0x996d3506 , 0x2f333f28 ] )
+  , Mont::unsafe_make( [ 0x593e1c9f , 0x8bda4b23 , 0x494cc51e , 0x78cb2c11 , 0xd219921a , 0x18411cef , 0x9041008b , 0x0d464e2e ] )
+  , Mont::unsafe_make( [ 0x4b5af89b , 0x76f3da62 , 0x42ad0c9d , 0xd58943a1 , 0x059c3384 , 0xcbb2e85b , 0x880b955d , 0x28a43d00 ] )
+  , Mont::unsafe_make( [ 0x3b193dda , 0x32c4eaf8 , 0xf9c03225 , 0x2b63933c , 0xe297bd9c , 0x0002eeb1 , 0xd9836b9f , 0x096ae948 ] )
+  , Mont::unsafe_make( [ 0xbe9448ed , 0xedcdf629 , 0x0f81b350 , 0x5c096f33 , 0xefd115b3 , 0xcba5dc1b , 0x01068537 , 0x2e929b64 ] )
+  , Mont::unsafe_make( [ 0xfb47387a , 0xa879898a , 0x408d1b45 , 0x83ffab21 , 0x1cf0c715 , 0xbd138e23 , 0xa4f89af8 , 0x12624047 ] )
+  , Mont::unsafe_make( [ 0x8486376f , 0xd7c2b183 , 0x48999034 , 0xd4516339 , 0x889d2f03 , 0x1aac017b , 0xd5ef1609 , 0x053421ff ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x56405738 , 0x8982ad68 , 0xfaadda9b , 0xab711aa9 , 0xa3e9b0c3 , 0xf4b467cf , 0x502cb1cb , 0x02ed4053 ] )
+  , Mont::unsafe_make( [ 0xefdb2400 , 0xe5f607d6 , 0xe72b6d77 , 0x268be843 , 0xbe900ad3 , 0xfb3eb18e , 0x9c273623 , 0x2ac210a3 ] )
+  , Mont::unsafe_make( [ 0x141ce089 , 0xf24a4b40 , 0x288350ed , 0xa36ff47d , 0x3130e57e , 0x2c82d88c , 0xfb85c706 , 0x1c0a45d4 ] )
+  , Mont::unsafe_make( [ 0xcb49612e , 0x25ae3fc4 , 0xcf43fd90 , 0x4172c8a7 , 0xf2f5daa6 , 0xae0b8b1b , 0xd0e1839a , 0x07c7b2bf ] )
+  , Mont::unsafe_make( [ 0x399b5db9 , 0x27492bbc , 0xfa4ac333 , 0x3529685e , 0xd688a121 , 0xb681f2a4 , 0x95013390 , 0x1574c869 ] )
+  , Mont::unsafe_make( [ 0x259301ee , 0xa72f87b2 , 0x06fe72ee , 0xa443e951 , 0x00d2388d , 0x4742a65c , 0xd799b9ee , 0x1d465d37 ] )
+  , Mont::unsafe_make( [ 0x6268fdac , 0x2415814f , 0xe901a5b0 , 0x8c0bdeee , 0xa6a319f1 , 0x3b5d31a0 , 0xd88ac5ba , 0x007dd744 ] )
+  , Mont::unsafe_make( [ 0xb761fe79 , 0x2feacb02 , 0x2de5a361 , 0x81b75fbc , 0x172cc707 , 0x13b0061b , 0xe4707e7f , 0x0b1dfba2 ] )
+  , Mont::unsafe_make( [ 0xa06341da , 0x687f7304 , 0xd8b02720 , 0x332e3132 , 0x2709ea24 , 0xbf638103 , 0x5cdeaf4f , 0x0a2b3aa8 ] )
+  , Mont::unsafe_make( [ 0x942506f6 , 0xd422851a , 0x4b92bbc3 , 0x51411d02 , 0x2349b8b5 , 0xf9658bf2 , 0xd6fdeb8b , 0x187f30fe ] )
+  , Mont::unsafe_make( [ 0xf2a041c0 , 0xb7d77f44 , 0x0fb7101e , 0xbf29bf5c , 0xaf9bdec5 , 0x666d6196 , 0x7e323856 , 0x0ede1e9c ] )
+  , Mont::unsafe_make( [ 0x0fb55ce0 , 0x27a17b9d , 0xe46d87ba , 0xd75f7ea5 , 0x6365a262 , 0xd35fe5a7 , 0x849abf3b , 0x26694319 ] )
+  , Mont::unsafe_make( [ 0xf5c192fa , 0xe070f0f2 , 0x244ff4d6 , 0xc4d29bfa , 0x4651c890 , 0xd48fd3c1 , 0x6f05a18a , 0x264a127c ] )
+  , Mont::unsafe_make( [ 0xe51e4b6f , 0x64ba7667 , 0x5931ffc7 , 0x10b1ed85 , 0x0f5eef6b , 0xad73e8e3 , 0x71470d25 , 0x00409685 ] )
+  , Mont::unsafe_make( [ 0xa8ddc149 , 0x963b0004 , 0xd32009a0 , 0x98bf3fcb , 0x44af30ab , 0xa6687c05 , 0x6e0cee2f , 0x10d1caec ] )
+  , Mont::unsafe_make( [ 0xb7b9033b , 0xe8b322b5 , 0x23e0be11 , 0x084eb879 , 0x41b9ea2a , 0x936f4f91 , 0xddeaec5a , 0x2d4562fb ] )
+  , Mont::unsafe_make( [ 0xa0f346d6 , 0x9c531144 , 0x3f61b61a , 0x9aee0c21 , 0x0abd7777 , 0x4ad6186d , 0x52f4be83 , 0x263275c4 ] )
+  , Mont::unsafe_make( [ 0x98c03ebf , 0xa79397e9 , 0x25bdca24 , 0xe6dd0b40 , 0xbf4dbbd3 , 0x510ebc90 , 0xa6724f28 , 0x2dc8a173 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x4b9177b5 , 0x7f7933ea , 0xdc615a9e , 0x5c230100 , 0xcae82611 , 0x910000d5 , 0x0d10e428 , 0x29d2e565 ] )
+  , Mont::unsafe_make( [ 0x342ee248 , 0xfd0f8963 , 0xde6530f1 , 0x0fa85530 , 0x45ee449f , 0x47199be2 , 0x8395dbb9 , 0x28cf3fae ] )
+  , Mont::unsafe_make( [ 0x50d27ff7 , 0x3f90190c , 0x93030ce9 , 0x5d494dab , 0x801dd597 , 0x01858e76 , 0x5b57bf75 , 0x11c7ed0d ] )
+  , Mont::unsafe_make( [ 0xd60cea1f , 0x7259e755 , 0x473f6167 , 0x20691944 , 0xf7f99cc8 , 0x09f5bbf6 , 0x71d1b96c , 0x11d8b810 ] )
+  , Mont::unsafe_make( [ 0x7665bf97 , 0x4ef2584a , 0x599531ab , 0xaedde64c , 0x69f4795a , 0x3fb51cb3 , 0xc6728a7d , 0x0eeeb3eb ] )
+  , Mont::unsafe_make( [ 0x9b42ab21 , 0x7c84c6c9 , 0x32747403 , 0xf2391682 , 0x9b5701a3 , 0x81c30820 , 0xc8d5b7a2 , 0x2d9bb64d ] )
+  , Mont::unsafe_make( [ 0x3a888a29 , 0xdb6ab95d , 0xa3d6d7de , 0xa2cf4430 , 0xe8724eea , 0x21a89338 , 0xebd05538 , 0x26096106 ] )
+  , Mont::unsafe_make( [ 0x8c444e01 , 0xd2268185 , 0x77dc459f , 0x68f2e80f , 0x621d5453 , 0x3ddc0502 , 0xc7b632fb , 0x1d7cc607 ] )
+  , Mont::unsafe_make( [ 0xc1261b09 , 0x360d4d13 , 0xe0f38ef8 , 0x928da8df , 0xb33a1bb0 , 0x550ba69c , 0x41564279 , 0x2391f21a ] )
+  , Mont::unsafe_make( [ 0x3ad860d3 , 0x284218c7 , 0x649df127 , 0xb293d560 , 0x64752509 , 0x931df4ac , 0x30fc0dcd , 0x0d6a42f7 ] )
+  , Mont::unsafe_make( [ 0x90ed57db , 0x93a9c69f , 0x40114d9a , 0xcf22b118 , 0xac988ad6 , 0xfd9cd4a0 , 0x83e2cbdd , 0x2a20a2a2 ] )
+  , Mont::unsafe_make( [ 0xd09a1f90 , 0xfe5effdc , 0xcbc2a591 , 0x04809723 , 0x6719330c , 0x8758971a , 0xcb0f8323 , 0x2f15eb3b ] )
+  , Mont::unsafe_make( [ 0xdb0c546e , 0xda1b265e , 0xcb805d78 , 0x130fd1c5 , 0x0d38cbd0 , 0x141f06a7 , 0x0e1bfb1a , 0x1cb205ca ] )
+  , Mont::unsafe_make( [ 0x1c27c21f , 0x2cad9de8 , 0x8f047935 , 0x1bea7034 , 0xc1d94411 , 0x243ea73a , 0xba8eb22e , 0x29736d13 ] )
+  , Mont::unsafe_make( [ 0x18fd79b4 , 0x1368ce2d , 0xf6d1551f , 0x61f1270b , 0x3b620b42 , 0xdd6332dd , 0x05ee8f8f , 0x1672e2f0 ] )
+  , Mont::unsafe_make( [ 0xc138073b , 0xaafd5932 , 0xc0298386 , 0xb126239d , 0xc5f7f584 , 0x63425f7b , 0xe5304bb9 , 0x190889d6 ] )
+  , Mont::unsafe_make( [ 0xa80e8537 , 0x2f4e787c , 0xf4ceaf03 , 0xf9bb36f2 , 0x65f794fd , 0xe7de6a6b , 0xb9153f43 , 0x2eeea748 ] )
+  , Mont::unsafe_make( [ 0x8b88ec27 , 0x674db4b9 , 0xe205cca7 , 0x94af44ac , 0x2f5009cd , 0x7aa3cff9 , 0x11c8876b , 0x2aa948c4 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x314ee9aa , 0x2ee7f458 , 0x65ce2292 , 0x9eefa2a8 , 0x306afc2c , 0xa2af2325 , 0xe360dc56 , 0x2887fc95 ] )
+  , Mont::unsafe_make( [ 0x90d02c8f , 0xcffeb1ac , 0x872f8e5f , 0x411acf56 , 0xb26241da , 0xfd72fe76 , 0x75231410 , 0x0ccebd5b ] )
+  , Mont::unsafe_make( [ 0x13efdd41 , 0xab46a948 , 0x3efef188 , 0xf4fd2bb5 , 0xc714a2fc , 0x48893307 , 0x13c196e2 , 0x1bb880a9 ] )
+  , Mont::unsafe_make( [ 0xfa854298 , 0x6ae45b8e , 0xc7dbb07e , 0xc4f17425 , 0x1f7eae58 , 0x6b36053b , 0xb97add60 , 0x2a0272fb ] )
+  , Mont::unsafe_make( [ 0x7a8466dd , 0xfd36f8d3 , 0x40b1df93 , 0x76a0c177 , 0x6000fbb2 , 0xad6a6395 , 0x4a706afd , 0x0c2cd002 ] )
+  , Mont::unsafe_make( [ 0x9957544c , 0xc9d2bac4 , 0xe431ed91 , 0xd84d965a , 0x3b7da372 , 0x5d186d07 , 0x1ee9458f , 0x29e708e2 ] )
+  , Mont::unsafe_make( [ 0xcac885e1 , 0xf2e47ae9 , 0xe09e96e9 , 0xc78cfd5e , 0x5e1e938c , 0xf8b0575f , 0x5e1516df , 0x0d9f103f ] )
+  , Mont::unsafe_make( [ 0x8c120039 , 0x16b627ce , 0x6bafcea0 , 0xed949801 , 0x2efd9383 , 0x5d4b6506 , 0x10936ae5 , 0x0eb136f0 ] )
+  , Mont::unsafe_make( [ 0x77ea6fbe , 0xd6578138 , 0x639a777d , 0x439a250e , 0x48680c81 , 0xd7ee88ac , 0xa2b44883 , 0x097aceef ] )
+  , Mont::unsafe_make( [ 0xae612334 , 0x51038e01 , 0x43a87ee2 , 0xe787e6c1 , 0xa1560485 , 0x1a6aad1b , 0xb5b6b832 , 0x0b1ca62f ] )
+  , Mont::unsafe_make( [ 0x61af1070 , 0x29ca24fb , 0xa2841c9d , 0xd5eb8ffa , 0x3c8488fb , 0xd2b41e65 , 0xc6679dd3 , 0x0812e9a1 ] )
+  , Mont::unsafe_make( [ 0x0bcfc1c2 , 0xa68f1156 , 0xe3c38e53 , 0x49b9028c , 0x3958f835 , 0x1a29fd2e , 0xb1994696 , 0x24ddeef4 ] )
+  , Mont::unsafe_make( [ 0xfb7855c9 , 0x5d26a12d , 0x961fa486 , 0x80cec207 , 0x747834c9 , 0xe2d8df0a , 0x5e8e19f6 , 0x1ec13460 ] )
+  , Mont::unsafe_make( [ 0x27e672ca , 0x68c5897f , 0x050e03c2 , 0x459576ec , 0x9e272780 , 0xba81a447 , 0xe0e96a12 , 0x1189cfaa ] )
+  , Mont::unsafe_make( [ 0xc06909a5 , 0x39d252af , 0x81f272b1 , 0x07f4235d , 0x017982b4 , 0xc48f0617 , 0x3bb75354 , 0x13f0fa93 ] )
+  , Mont::unsafe_make( [ 0x32fd12c8 , 0x772671f6 , 0xb502256b , 0x8190e20c , 0x99ce2303 , 0x65883466 , 0xad8253c1 , 0x0218bf9c ] )
+  , Mont::unsafe_make( [ 0xe326964f , 0xfb851ee9 , 0x0dc5fc41 , 0x18bd867d , 0x42fc7289 , 0x08597c8e , 0x7f643609 , 0x23537153 ] )
+  , Mont::unsafe_make( [ 0xe2d14bee , 0x797c875a , 0x670a2468 , 0xc335fec7 , 0xeb1dd8d0 , 0x73ae0557 , 0x2ade2fbb , 0x13b53e40 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x98c9fd2d , 0x3e95c47e , 0x9b67c70d , 0xf99d9b64 , 0xf7d651ed , 0x0c522d01 , 0xd761b5d6 , 0x2b35f2d3 ] )
+  , Mont::unsafe_make( [ 0x792c6ddf , 0xab72f2f5 , 0x092cbb94 , 0x378bad04 , 0x684649f8 , 0xf0cfc0ab , 0x04c1bf04 , 0x16935a5f ] )
+  , Mont::unsafe_make( [ 0xd3e9f139 , 0xde0c5f02 , 0xb120dfe9 , 0x17a1a452 , 0x0c58be7f , 0x2a0359c1 , 0x307d8b7c , 0x0a560ce4 ] )
+  , Mont::unsafe_make( [ 0xcb0c1e4e , 0x632c87d6 , 0x0f7d48bf , 0xbd2951c0 , 0x3a26e4fe , 0xf4d5a0b8 , 0xa3dcc39c , 0x0b9b3d04 ] )
+  , Mont::unsafe_make( [ 0xa0327cf3 , 0x2f310a54 , 0x1f68dc14 , 0x9e001cb7 , 0x05e8ccfa , 0x1bed96fd , 0xf788ea42 , 0x2c6086ce ] )
+  , Mont::unsafe_make( [ 0xe2eea9d1 , 0x7154077e , 0x3aed7a5d , 0x1202a8b9 , 0xf64b31bf , 0x3cf66618 , 0x6f4e1eff , 0x1d6fc779 ] )
+  , Mont::unsafe_make( [ 0x842b9c18 , 0x7c84dc3d , 0xa5e5e4cc , 0x2a01eb58 , 0xc289bb73 , 0x0cf68ef9 , 0x21ca2614 , 0x040ac95b ] )
+  , Mont::unsafe_make( [ 0x090d8468 , 0xf1e7042b , 0x0acbd6d8 , 0x303fcec6 , 0xc176f5bc , 0xc0d4414f , 0x72ee659f , 0x0d007d0a ] )
+  , Mont::unsafe_make( [ 0x59e5d4e7 , 0x59bc8595 , 0x6ae089a3 , 0x24182cb9 , 0xa580f2d5 , 0x7a796b0b , 0x592e1e4a , 0x275be2d0 ] )
+  , Mont::unsafe_make( [ 0x14fc9b1f , 0x0e054abf , 0x3c703622 , 0xa3755e9b , 0xf00062c9 , 0x56fa2e54 , 0x52ae052f , 0x1231ee5f ] )
+  , Mont::unsafe_make( [ 0x9977f7fc , 0xdb731bed , 0xd43bb417 , 0x21ef34c7 , 0xde65e8b3 , 0x9f6058b9 , 0x2f45df0f , 0x238592d5 ] )
+  , Mont::unsafe_make( [ 0x5ed0cbb2 , 0xeb0b7050 , 0x135f25cb , 0x01b2b22c , 0x6f65aa98 , 0x187efe8e , 0x7226a5fc , 0x1981861b ] )
+  , Mont::unsafe_make( [ 0x1df57087 , 0x4c7da1c8 , 0x81587df7 , 0x91747792 , 0xc1d241bc , 0x563ff4b3 , 0x66693c12 , 0x2858d408 ] )
+  , Mont::unsafe_make( [ 0x02b8285a , 0xc9cc2ac3 , 0x49ccd1ad , 0xb64f4bea , 0x304f6b60 , 0xc11d8438 , 0x76836d08 , 0x10ecbb0b ] )
+  , Mont::unsafe_make( [ 0xaf9502d8 , 0x110f2797 , 0xa5517d54 , 0x630419b7 , 0x0ae9e2ea , 0x5f71d159 , 0xffef7907 , 0x2f4bdce0 ] )
+  , Mont::unsafe_make( [ 0xb85e7599 , 0xee9f6c64 , 0x294969fb , 0xb033d41b , 0xbc4341e8 , 0x77e80875 , 0x4b0813a9 , 0x2bcb7ebc ] )
+  , Mont::unsafe_make( [ 0x522097eb , 0xf04c6ae3 , 0xa047645e , 0xd3d2bf58 , 0x785c021a , 0xfd5d57e8 , 0x185629ba , 0x2e653d8b ] )
+  , Mont::unsafe_make( [ 0x8cb374b2 , 0x2ded9219 , 0xd267083d , 0x5e31e564 , 0x7c082552 , 0xe13e4663 , 0x7b9eef0c , 0x241a1296 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x05f7efbb , 0xa4619f5b , 0x89257397 , 0xdd1a2ec7 , 0xfeaa78e2 , 0x25859d09 , 0x3af71100 , 0x0f16737e ] )
+  , Mont::unsafe_make( [ 0x167cdd2a , 0x1677dea8 , 0x66d912d0 , 0x8d06fe73 , 0xfd5e63c7 , 0x1e249c7c , 0x5a06526d , 0x0bb10e69 ] )
+  , Mont::unsafe_make( [ 0xeb962ee8 , 0xc2c30b97 , 0x02928eb9 , 0x032258ef , 0xb9a4f4e4 , 0x689738b0 , 0x900a115b , 0x0fda9fe3 ] )
+  , Mont::unsafe_make( [ 0x316cc0ad , 0x907bd28e , 0xdd586d36 , 0x001c2c4b , 0xda4197ec , 0x0361b244 , 0x74144123 , 0x29efcc00 ] )
+  , Mont::unsafe_make( [ 0x3e1891ca , 0xe1defad0 , 0xa1cb1bf3 , 0x8ea06ef2 , 0x1358f7f5 , 0x3d16a00e , 0x8a6c928b , 0x3004d725 ] )
+  , Mont::unsafe_make( [ 0xb1022f7d , 0xa8eaa43f , 0xdfc2eaec , 0x068ab7c7 , 0x4bcda330 , 0xfd7b10fb , 0x3c3d15e4 , 0x0b7d49bf ] )
+  , Mont::unsafe_make( [ 0xf82555e6 , 0x203ea26f , 0x5bf024fe , 0x12c2da5f , 0xfb1edde2 , 0x50835f8d , 0xf562b160 , 0x03575e90 ] )
+  , Mont::unsafe_make( [ 0x578e0445 , 0x16da0a8c , 0x2a78094a , 0x5f73a0e6 , 0x79adecef , 0x5f37cd7f , 0x36d61c02 , 0x179db37c ] )
+  , Mont::unsafe_make( [ 0x06c99bca , 0x2ca933c6 , 0x8827ebd9 , 0xe2ecdcf0 , 0x29612040 , 0x2521eae2 , 0xce42f2fb , 0x1ca9ecb3 ] )
+  , Mont::unsafe_make( [ 0x4cfd9e14 , 0x6458642f , 0x475c111d , 0x80f1f987 , 0x347c50d8 , 0x667f49b5 , 0x573bacc4 , 0x1d2ab5c0 ] )
+  , Mont::unsafe_make( [ 0x6789e423 , 0x4c07aaec , 0xffb5aeec , 0x8abaac5c , 0x224880c3 , 0x03757c97 , 0x15ba2f79 , 0x15ae49d4 ] )
+  , Mont::unsafe_make( [ 0x8ccc9432 , 0xdb483720 , 0xc79ace53 , 0x1e887f9b , 0xd563a5fb , 0xffbbcf46 , 0x13b46bd5 , 0x16a7ba99 ] )
+  , Mont::unsafe_make( [ 0x830e7527 , 0xcb81b767 , 0x66c63182 , 0x4da47d23 , 0x52ce1124 , 0xbd643b0e , 0x66ceb824 , 0x0915a749 ] )
+  , Mont::unsafe_make( [ 0xecb2f742 , 0x1d21b288 , 0xfd688f70 , 0x4270dc1c , 0x817e0e7b , 0x0d9b3388 , 0xd270c2af , 0x07312a56 ] )
+  , Mont::unsafe_make( [ 0xed63660a , 0xc73383e9 , 0xa47869f2 , 0x9b310d87 , 0xb4e8dbda , 0x7990ba32 , 0x3e371629 , 0x1682cde8 ] )
+  , Mont::unsafe_make( [ 0x063e69ac , 0x4a95332c , 0x17a24cb1 , 0xd1d822cf , 0x3e4d6b23 , 0x6cbddac7 , 0x93c107fe , 0x2ec57f2b ] )
+  , Mont::unsafe_make( [ 0x0ba2fe2a , 0x5add59e5 , 0xcfbe8689 , 0x7cffcf19 , 0xda082640 , 0xb686b2d8 , 0x9cc00a43 , 0x0b69590b ] )
+  , Mont::unsafe_make( [ 0x6cc847b7 , 0x2afef2f7 , 0x2544fc69 , 0xfe778704 , 0xb9a2b66d , 0xc070b964 , 0xe5c46cd1 , 0x2eb747e1 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x49a845e7 , 0x11408c02 , 0x427f8ad4 , 0x9a1cfcc3 , 0x0858f662 , 0x17356272 , 0xa2fe9209 , 0x290edd0b ] )
+  , Mont::unsafe_make( [ 0xf8405af9 , 0x035a3b50 , 0x6daf226e , 0x6917e2c2 , 0x7fbbd116 , 0x5ed190fd , 0xeb7e3014 , 0x0919a705 ] )
+  , Mont::unsafe_make( [ 0xe641337c , 0x7a7733a1 , 0x1f63f74c , 0x449e6d1d , 0x9d883754 , 0x400bcdd8 , 0xd2bcbb2d , 0x24547088 ] )
+  , Mont::unsafe_make( [ 0xcd903370 , 0x836ca0b6 , 0xd9942aa1 , 0xa18db646 , 0x5afaf694 , 0x2ad9fea3 , 0x28fb4df0 , 0x26dda357 ] )
+  , Mont::unsafe_make( [ 0xc4f51ed1 , 0x4972047b , 0x87723dcd , 0x766e7023 , 0x3569674c , 0xd9b43256 , 0x0718aa4d , 0x0bf64478 ] )
+  , Mont::unsafe_make( [ 0xc4986f7e , 0xaa8ad064 , 0xe3026dcd , 0xacf44d54 , 0xe24963b1 , 0x84b4fa30 , 0x142dbd6a , 0x1a1e4c37 ] )
+  , Mont::unsafe_make( [ 0xbc364dde , 0x1df28881 , 0x61ff138d , 0x9545c33f , 0x67120e10 , 0x9491b539 , 0xf7f559cd , 0x1cf85061 ] )
+  , Mont::unsafe_make( [ 0xd2d4033b , 0xccaea3a4 , 0x49f15acb , 0x4243db68 , 0x79072ae1 , 0x181fd346 , 0x0821cd98 , 0x0ee40290 ] )
+  , Mont::unsafe_make( [ 0x236df4be , 0x28ed7a48 , 0x86cbdecc , 0xce229c84 , 0xcaa45f9f , 0x6a48527c , 0x65f3318f , 0x205dbe14 ] )
+  , Mont::unsafe_make( [ 0xb63846e4 , 0x6711a2ce , 0xfb067644 , 0x5805bbbd , 0xdd62ed73 , 0x851f5726 , 0x4d6d63f8 , 0x18287b28 ] )
+  , Mont::unsafe_make( [ 0x1efdb85b , 0xa5bbad7f , 0xdeaa5667 , 0x210732eb , 0x5f290c16 , 0x314431d7 , 0x90337938 , 0x0fbfa6e8 ] )
+  , Mont::unsafe_make( [ 0xaceef5b7 , 0x07deea31 , 0x60459a8c , 0x177e27f7 , 0xb1bdc640 , 0x07f5c118 , 0x4a03cfaf , 0x0d0095e9 ] )
+  , Mont::unsafe_make( [ 0x59845578 , 0x771274c3 , 0x9f3fad78 , 0x0c4dcf00 , 0x6cca6886 , 0x1d0d1580 , 0xf86b30a9 , 0x08cb7f2f ] )
+  , Mont::unsafe_make( [ 0x6d5baf05 , 0xd1e75b15 , 0x3ed14d7c , 0x37db47f6 , 0xf982243c , 0xb18deea1 , 0x991f16c1 , 0x2a9a1818 ] )
+  , Mont::unsafe_make( [ 0x2d8907bd , 0xc77888d5 , 0x883279f0 , 0x1cbaf667 , 0x429f324a , 0x8cf23b2e , 0x25257309 , 0x07b8778f ] )
+  , Mont::unsafe_make( [ 0x0e13488b , 0x463996ad , 0xce9a16f7 , 0x20d53fee , 0x4236160e , 0x20104159 , 0x8de10587 , 0x256c7e88 ] )
+  , Mont::unsafe_make( [ 0x74cfba30 , 0x89b6e82b , 0x4affdf39 , 0xd7b1d004 , 0x02c64c48 , 0x0a815e2b , 0x94939266 , 0x03332946 ] )
+  , Mont::unsafe_make( [ 0x3fc171e9 , 0x5b4978fd , 0x5a8684e8 , 0x1f0187bb , 0xcf4bc2f0 , 0x48b6fc47 , 0xbba0d9ea , 0x11459bd8 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x28750159 , 0xa36a247c , 0x3bcc1581 , 0x97ebccb7 , 0x19047950 , 0xa4596307 , 0x3df337ea , 0x05e82043 ] )
+  , Mont::unsafe_make( [ 0x56e0b6aa , 0xc3dc70ff , 0x559a948b , 0x0507677b , 0xa37196b3 , 0xbf98c3d8 , 0x7b9f04c8 , 0x113a5a97 ] )
+  , Mont::unsafe_make( [ 0x050ae9b7 , 0x0bc42017 , 0x14e01e17 , 0x9139c028 , 0xe74a6742 , 0xa204b651 , 0x732904ac , 0x2c533650 ] )
+  , Mont::unsafe_make( [ 0xbcdd440f , 0xa8a6d19b , 0x22d1c5f2 , 0xc2bc9fb5 , 0x5faa3784 , 0x6bdb7cb2 , 0xb7d8431c , 0x0a388485 ] )
+  , Mont::unsafe_make( [ 0x7a269240 , 0xc9d8254d , 0xc120e499 , 0x3ad9c6b2 , 0x1dff5bb8 , 0x975a3570 , 0x09c36835 , 0x2ddb8205 ] )
+  , Mont::unsafe_make( [ 0x4b7a74f2 , 0xb7afd0fc , 0x319e68db , 0x09241998 , 0xb2f7eaa6 , 0x2f0bfbb5 , 0x26b9d177 , 0x0a5912ec ] )
+  , Mont::unsafe_make( [ 0x64e49c82 , 0xfa7745eb , 0x45198796 , 0xe5969d39 , 0x18435700 , 0xf5b97eb6 , 0xe958b453 , 0x142e250e ] )
+  , Mont::unsafe_make( [ 0xc5ccde47 , 0x585e6432 , 0x749a0b8a , 0xfead2f5c , 0xf1b1e8f4 , 0x4d2b845c , 0xdfd0b69f , 0x1b65bc4f ] )
+  , Mont::unsafe_make( [ 0x64bbd0cd , 0x61842316 , 0x9b259da6 , 0x654f7683 , 0x85491602 , 0x663d5db5 , 0xa2591138 , 0x06ad092e ] )
+  , Mont::unsafe_make( [ 0xe1573915 , 0x2304a5fc , 0x79636bcf , 0xf258ee95 , 0xd1a83113 , 0x10364d7f , 0x24045071 , 0x2285cc05 ] )
+  , Mont::unsafe_make( [ 0xc6dd98e3 , 0xf61623df , 0xe95461df , 0xeb503636 , 0x37cb9b4f , 0x19f9a986 , 0x05b23c1f , 0x2570bded ] )
+  , Mont::unsafe_make( [ 0x479ca942 , 0xdde14102 , 0x6f9f8207 , 0x3e476b9a , 0xa09a3bd2 , 0xf2352c6d , 0xebcbd60b , 0x18729cf5 ] )
+  , Mont::unsafe_make( [ 0xf92340e6 , 0xb5bccb49 , 0xf2f26820 , 0x73d085d9 , 0x027fe639 , 0x03e1ffa6 , 0x40f11c6e , 0x0297a8d9 ] )
+  , Mont::unsafe_make( [ 0xa57cb4da , 0xd079fadf , 0x3c791a9a , 0xac4bdd8e , 0xd94121ef , 0x7c0dc94e , 0xfa394bda , 0x259a27e8 ] )
+  , Mont::unsafe_make( [ 0x99e223ac , 0xc7e4f1b9 , 0xe131a0f5 , 0x6a18a5fb , 0x0ae9510b , 0xe5b26f36 , 0x819533bb , 0x0db2e118 ] )
+  , Mont::unsafe_make( [ 0xf8bf4bca , 0x86b9ea94 , 0x9fc82f1f , 0x8e8a0ad5 , 0x71494623 , 0xdded4453 , 0x77099f83 , 0x080068df ] )
+  , Mont::unsafe_make( [ 0x21e2e3b6 , 0x4dc9ef0e , 0x8111d707 , 0xb2a7937f , 0xf46aa1bd , 0x18e6ae74 , 0x845f8a17 , 0x0f0c5755 ] )
+  , Mont::unsafe_make( [ 0x15067352 , 0x16501248 , 0xc4635147 , 0x8a991e86 , 0xd4adfce4 , 0x3a8d4fb2 , 0x752ce134 , 0x0751b6cf ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x1337a44e , 0x2d661276 , 0xe94aa13d , 0xb4c67d31 , 0x11063ec3 , 0x1d98984d , 0xacbc19cb , 0x21251771 ] )
+  , Mont::unsafe_make( [ 0xe2775648 , 0x2df7ccc8 , 0x5ffd4ffa , 0x4dfb1449 , 0xdc1c4816 , 0xf0a8f673 , 0x92d687b5 , 0x1657606e ] )
+  , Mont::unsafe_make( [ 0xd8a78813 , 0x6b5b0ebe , 0x3a331388 , 0xe09684db , 0xf00e521a , 0x96ee4106 , 0x89354f1e , 0x118012bd ] )
+  , Mont::unsafe_make( [ 0x35427495 , 0xa224bda6 , 0x7aef4e60 , 0x2902147a , 0x16fe2064 , 0x82dc187f , 0xf9030716 , 0x056f255e ] )
+  , Mont::unsafe_make( [ 0x5bbb635b , 0x639444ea , 0x7a9c107c , 0xdfcba046 , 0x1f2a1634 , 0xf459fc9e , 0x41b75e07 , 0x12b6eaec ] )
+  , Mont::unsafe_make( [ 0x07c55c5d , 0x1bb7a378 , 0x9687d820 , 0x6a5ca5c0 , 0x832894e3 , 0x6b0c225a , 0xe31d946c , 0x2306040d ] )
+  , Mont::unsafe_make( [ 0xc78fee0e , 0x4df17b69 , 0x8fafbe60 , 0x8c6bdd42 , 0x3799147d , 0xcc7c8326 , 0xf70dc3e9 , 0x19c43ca8 ] )
+  , Mont::unsafe_make( [ 0xa5b5c377 , 0x6d9b7f42 , 0x1d758721 , 0x4120ecd1 , 0x4ff460e9 , 0xe79342c0 , 0x25123067 , 0x2cbf0b8c ] )
+  , Mont::unsafe_make( [ 0x7136d6dd , 0x6f3a547e , 0x2052a45d , 0xf6200df3 , 0x5da8e50c , 0x09741e25 , 0x55d18327 , 0x13a79eaf ] )
+  , Mont::unsafe_make( [ 0x062ff854 , 0x9316752f , 0xe10a4444 , 0x0430b39a , 0xa619f6f4 , 0x2c952fcb , 0x31c7ffd2 , 0x1a92479a ] )
+  , Mont::unsafe_make( [ 0x5aae97a6 , 0xc7948321 , 0x840afe94 , 0x73d3a0d7 , 0xa4ec1007 , 0xeac91a53 , 0x24a6af67 , 0x128abef2 ] )
+  , Mont::unsafe_make( [ 0x2508bfdf , 0x4faf7fcb , 0xfa730bfe , 0x8dd6892b , 0x87570f71 , 0x315ebec5 , 0x263e9903 , 0x043ec7b1 ] )
+  , Mont::unsafe_make( [ 0xc73f250e , 0x3a83617e , 0xc9c93f36 , 0xf3e20056 , 0x9ae3efc3 , 0x606abb11 , 0xbfbc4f86 , 0x0405405f ] )
+  , Mont::unsafe_make( [ 0x6d2af1c2 , 0x9ab98ca3 , 0xdd778b44 , 0xbf5bbcec , 0xa3846d11 , 0xb7d587bc , 0x808b1a56 , 0x16758c5e ] )
+  , Mont::unsafe_make( [ 0x06ae1d0a , 0x3e2b8678 , 0xa1cc0103 , 0xf67ede01 , 0x3941c686 , 0x24655278 , 0x5e6a90ea , 0x2c244267 ] )
+  , Mont::unsafe_make( [ 0x6fad87f9 , 0x9c2f47ca , 0x4076eb9b , 0xd3ede5cf , 0x7b2b2d63 , 0xd0e10295 , 0x42af369d , 0x108238cc ] )
+  , Mont::unsafe_make( [ 0x4e32eccb , 0x1e12b198 , 0x151e01e4 , 0x8ede9649 , 0xaecc0fe1 , 0xc8a1cb5f , 0x52b7abec , 0x1858eff3 ] )
+  , Mont::unsafe_make( [ 0x2662c5e6 , 0xd999cb46 , 0x92868307 , 0x038e8bc3 , 0xa2a8612c , 0xb010dc2a , 0x202b2a72 , 0x26e990ed ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xa566d4cb , 0x3cc2118d , 0xc8549ea9 , 0x7d18f643 , 0xc97475a2 , 0x29ce3e80 , 0x7bf423bc , 0x1ec0ed06 ] )
+  , Mont::unsafe_make( [ 0x340e81fd , 0x29367881 , 0xb2d05169 , 0x19288ed3 , 0x8db8a612 , 0xc99473aa , 0x1521c067 , 0x18cf0a01 ] )
+  , Mont::unsafe_make( [ 0x2bc1be51 , 0x67016148 , 0x9809294f , 0x6c755d21 , 0x7c4ffe11 , 0x7e62d033 , 0xc58f72ae , 0x0e882418 ] )
+  , Mont::unsafe_make( [ 0x96519402 , 0x77d82b59 , 0xe1117649 , 0x6a5518aa , 0x452ffe5c , 0x064e8e65 , 0xd250af30 , 0x157c44b6 ] )
+  , Mont::unsafe_make( [ 0xad76a403 , 0xbfdb267b , 0xc2df9c55 , 0x6bba35f1 , 0x0bf099ce , 0xa2753c36 , 0x9f2d2347 , 0x10ba25e2 ] )
+  , Mont::unsafe_make( [ 0xc873bee4 , 0xd7e43649 , 0x04c54e80 , 0x2312d376 , 0x739d1c56 , 0x3203cae6 , 0x77dd5745 , 0x0b8b2e44 ] )
+  , Mont::unsafe_make( [ 0xb1727951 , 0xd1ab03bf , 0xe4ebd3a0 , 0x1e3b0061 , 0x1ffca9b6 , 0x83f95bfd , 0xde82b3cc , 0x27908c9e ] )
+  , Mont::unsafe_make( [ 0xc40e9b7b , 0x29235a32 , 0xd86ba09c , 0x218b54f4 , 0x6e9fabfd , 0x343a064c , 0xe13360b3 , 0x0e96f921 ] )
+  , Mont::unsafe_make( [ 0xd9e1bafa , 0xfeedca38 , 0x6b1b609d , 0x279371f9 , 0x8bf64c86 , 0x7b9b1888 , 0x472777c2 , 0x2743d405 ] )
+  , Mont::unsafe_make( [ 0xec9b873e , 0xe25180d6 , 0x26876a14 , 0xb16e0dc7 , 0x957305d1 , 0x5594364a , 0xf70043d1 , 0x295fbb22 ] )
+  , Mont::unsafe_make( [ 0x4a3cc4d7 , 0x1d274d71 , 0xe5a704f5 , 0x3b596d03 , 0x1557ce1d , 0x8395dc22 , 0xca830871 , 0x02100ff1 ] )
+  , Mont::unsafe_make( [ 0xd2798acd , 0xacb57149 , 0x617bacb7 , 0xd6d61b06 , 0x4f7308dc , 0x07b33e75 , 0x3cfd2560 , 0x17ee41f7 ] )
+  , Mont::unsafe_make( [ 0x4e276e6d , 0xfd1bcaed , 0xef8c14fb , 0x45b991da , 0x6488c782 , 0x93dac0e9 , 0x429b0854 , 0x2e89dfc1 ] )
+  , Mont::unsafe_make( [ 0x7b46b80e , 0x9a9344d7 , 0xd8f30c24 , 0xccc560c2 , 0x9dbfc059 , 0x956d99f5 , 0x75656391 , 0x1cf813ff ] )
+  , Mont::unsafe_make( [ 0x6113fd9e , 0xe5cc8149 , 0x94ccb50b , 0x773aceab , 0x0494564d , 0x751fa632 , 0x5f6fba6b , 0x09d198a8 ] )
+  , Mont::unsafe_make( [ 0x3c745604 , 0x806761be , 0x36901fe2 , 0x6152d8d4 , 0x84db195b , 0xf1743379 , 0xdfc6a152 , 0x1bcdef58 ] )
+  , Mont::unsafe_make( [ 0x5abbc8e5 , 0xe1758666 , 0xfa6c1e02 , 0x5fbe7a65 , 0x77d75add , 0xb97931f3 , 0x32c02ee6 , 0x0fc21a35 ] )
+  , Mont::unsafe_make( [ 0x61d3ab74 , 0x16731aa8 , 0xff0d869a , 0xf67c653f , 0x521f24ad , 0xcc0a2968 , 0x94be9b50 , 0x216779a8 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x0a567b25 , 0x581ec615 , 0x08ffd02d , 0xefee1c66 , 0x6ae7d07d , 0xed7376ba , 0xa3b1d1a4 , 0x2b471d8a ] )
+  , Mont::unsafe_make( [ 0x33aab3e7 , 0xe503a145 , 0x10bc2065 , 0x35534043 , 0x85978209 , 0x71a5ad3d , 0x2fade9ad , 0x04ff0937 ] )
+  , Mont::unsafe_make( [ 0x0dfaa942 , 0xdeb2444a , 0x7ce1b649 , 0xf3c2e6ae , 0x8bb3f4d7 , 0x434dd919 , 0x20b16377 , 0x0b03ba89 ] )
+  , Mont::unsafe_make( [ 0xee03b70a , 0xc74e9ead , 0xb2876f00 , 0x7809665a , 0x51bc627c , 0x43cc74dc , 0xe42680d1 , 0x20c5a173 ] )
+  , Mont::unsafe_make( [ 0xeadab5d5 , 0x91a256ff , 0x555c7642 , 0x7d5b6461 , 0x07a8a536 , 0x544aac77 , 0xc736d791 , 0x118e7c63 ] )
+  , Mont::unsafe_make( [ 0x03bba770 , 0x8bc6d986 , 0xf3c070f3 , 0x995b6320 , 0x4c8a8b77 , 0xe55ed172 , 0xc9aa8c51 , 0x04246697 ] )
+  , Mont::unsafe_make( [ 0xafcdae09 , 0xc1915ddb , 0x8ae86041 , 0xb2a30206 , 0xde956d19 , 0x4338b7c9 , 0xba083205 , 0x2b57e936 ] )
+  , Mont::unsafe_make( [ 0xbdfd266f , 0xd7a36017 , 0x458ef22c , 0xd45e97eb , 0x439015b2 , 0x78cfa2a0 , 0xbba2e1ed , 0x0485569b ] )
+  , Mont::unsafe_make( [ 0xfdda663b , 0x38438d76 , 0xaae18261 , 0x6a885be4 , 0x3fdc9726 , 0x6105176d , 0xdc74d6a2 , 0x172fc350 ] )
+  , Mont::unsafe_make( [ 0x91629126 , 0x7f6cbb54 , 0xa68cef9a , 0x56ee652f , 0xdf89292f , 0x68705967 , 0x078ae4d6 , 0x1b9789c8 ] )
+  , Mont::unsafe_make( [ 0x73dcbb52 , 0xcc7b68ba , 0x670d8ebc , 0x747933ff , 0x90541d9f , 0x6c7f2339 , 0xd33b9800 , 0x20437b20 ] )
+  , Mont::unsafe_make( [ 0xc0b6286c , 0x3ea984c7 , 0xbf069de9 , 0xdcba5087 , 0x7d3fdd7b , 0x058e3b32 , 0x470272e2 , 0x058ad1cd ] )
+  , Mont::unsafe_make( [ 0x806db5bc , 0xb6d3b4e2 , 0x222dd8d8 , 0x348afb13 , 0x6f16617b , 0x3d1291e3 , 0x093837d2 , 0x1655e872 ] )
+  , Mont::unsafe_make( [ 0x15000067 , 0x20b8954f , 0x9bab9eb2 , 0x25eca209 , 0xa2bec209 , 0xd1ccefac , 0xf4bdeef5 , 0x0093c88b ] )
+  , Mont::unsafe_make( [ 0xcca6efb0 , 0xafbe99fd , 0xc9472062 , 0x37b3c6e7 , 0x659102ff , 0x9ca8e3de , 0x7fc83ba6 , 0x3026b7fc ] )
+  , Mont::unsafe_make( [ 0x64cf0348 , 0x7d87b819 , 0xa35b3d5d , 0x55e41352 , 0xd6efa4b4 , 0x499e0c7d , 0x8582232c , 0x1b4b31f3 ] )
+  , Mont::unsafe_make( [ 0xe7616f1b , 0x9e296114 , 0x199c22d6 , 0x1f98b9f5 , 0x7761b099 , 0xc353d31b , 0xee4bafb9 , 0x0d69e115 ] )
+  , Mont::unsafe_make( [ 0x96721ab3 , 0x33978f60 , 0xbc5df4a0 , 0x04df14b1 , 0xb990a059 , 0xabe90fb4 , 0x5795df1e , 0x085e1434 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x60fb950c , 0x9c9197a0 , 0x35318ebe , 0xfc58bd4a , 0x2d40a540 , 0x0affa9bb , 0xab4f6909 , 0x03938e1d ] )
+  , Mont::unsafe_make( [ 0x10dd0699 , 0x6f14063a , 0x01043cca , 0xec70826c , 0xe4b021c9 , 0x5f04b132 , 0x7a9a5265 , 0x12dc33c0 ] )
+  , Mont::unsafe_make( [ 0x5a5bd8cb , 0x285bc97f , 0x2eef75a3 , 0x0ba10791 , 0xd61bd161 , 0x05e19f89 , 0xd444414b , 0x240e48bd ] )
+  , Mont::unsafe_make( [ 0xc3f5175d , 0x5c03fb1c , 0xcb473d7a , 0xe92e6dbf , 0x52476bdc , 0xd24fdefa , 0x67627722 , 0x0db240d2 ] )
+  , Mont::unsafe_make( [ 0x7d418260 , 0xa2c5cc81 , 0xb8369a77 , 0xb1d5d2ba , 0x312aba88 , 0xe1a7203c , 0xa33213d3 , 0x01b7ce65 ] )
+  , Mont::unsafe_make( [ 0xaac3167f , 0x40a94e78 , 0x45e1aa6a , 0xe0299981 , 0xf1178cd2 , 0x1f7013ca , 0xd51d6e46 , 0x2cebc733 ] )
+  , Mont::unsafe_make( [ 0x79bef912 , 0x45ff56c0 , 0x9c890a05 , 0x11efa835 , 0xaa976c3f , 0x6098e21e , 0xfe76a61f , 0x18a3987d ] )
+  , Mont::unsafe_make( [ 0x70b6ebea , 0x68de4fda , 0x7f971fc7 , 0xb3456f0c , 0xd71316d4 , 0x3d5139db , 0xff3a6e70 , 0x18c8277a ] )
+  , Mont::unsafe_make( [ 0x83ceb817 , 0x929549c8 , 0x46f933b0 , 0x585b8f9e , 0xe2b8762d , 0xc0106013 , 0x6de736aa , 0x0aabc11c ] )
+  , Mont::unsafe_make( [ 0x708d1a05 , 0x5ef3c80a , 0xb9ab7970 , 0xd7266fba , 0x5741371f , 0xd8825c6c , 0x8eb07c5f , 0x2318147a ] )
+  , Mont::unsafe_make( [ 0xaa30e5a5 , 0x4c4d559e , 0xd463a47c , 0xa4752856 , 0xe27de052 , 0xf76e4a9d , 0x53793d05 , 0x078b78c0 ] )
+  , Mont::unsafe_make( [ 0x2f5cf751 , 0x3b534c66 , 0x12661139 , 0xcd0cd99c , 0x085f981b , 0xddb38143 , 0x48942bb0 , 0x24efe165 ] )
+  , Mont::unsafe_make( [ 0x332a803a , 0xee6ccdb6 , 0x13d46695 , 0xffa90a4a , 0x9894c253 , 0x02f0ea05 , 0xdcaa74bc , 0x287b93e0 ] )
+  , Mont::unsafe_make( [ 0x7f09327b , 0x8e6d3ba2 , 0x71966a09 , 0xa8f3073e , 0x0dea9cee , 0x932da1d6 , 0x79d556c6 , 0x01cd0bb4 ] )
+  , Mont::unsafe_make( [ 0x7ab78152 , 0x6ad435ca , 0x3bd4d8e4 , 0xd2dea320 , 0xfe11ce6e , 0xd85ddfbb , 0xfc296565 , 0x024cac07 ] )
+  , Mont::unsafe_make( [ 0xbb3d0937 , 0x758488b7 , 0xc938b952 , 0x9550119f , 0x567889ef , 0xf2c2127d , 0x3ba4ae23 , 0x02379deb ] )
+  , Mont::unsafe_make( [ 0x90d164bb , 0x339ab3bd , 0x4f648804 , 0xa271af35 , 0xcbc041ac , 0xf739eecf , 0xc3eefdcf , 0x2467d6a0 ] )
+  , Mont::unsafe_make( [ 0xc076be98 , 0x16692228 , 0xb18f664c , 0x0471f7ec , 0x583dade0 , 0x07d90a16 , 0xedd6e7da , 0x112b8c1f ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x366087f3 , 0x29416d11 , 0x83943102 , 0x14e5c6f2 , 0x3ef072cc , 0x77bee55d , 0x59187a85 , 0x178bf812 ] )
+  , Mont::unsafe_make( [ 0x5c34f905 , 0x93f79381 , 0x6724baf5 , 0x7604c766 , 0x948adf28 , 0x3992a62c , 0xfa290a72 , 0x2d1f854b ] )
+  , Mont::unsafe_make( [ 0x4e02aff6 , 0xee29263b , 0x37cb9b13 , 0xfc1cb93a , 0x73b2cd5f , 0x6c16719d , 0x7cdb36f4 , 0x2eefdaf4 ] )
+  , Mont::unsafe_make( [ 0xcfa9bcaa , 0x6d07f724 , 0x5f9fca1c , 0x06c092b0 , 0xbd45dbb2 , 0xc865844e , 0xa5ccb2aa , 0x2f761fa3 ] )
+  , Mont::unsafe_make( [ 0x46bb16ff , 0xbfc28943 , 0x64581cd5 , 0x044e03c7 , 0xba10e904 , 0x7addc5bd , 0x00ec89a4 , 0x0e14545d ] )
+  , Mont::unsafe_make( [ 0xdf60e8d0 , 0xf0da78be , 0xb4803729 , 0xc254ec90 , 0x62fcb5ab , 0xd43ee505 , 0xff6ea619 , 0x1fb665cf ] )
+  , Mont::unsafe_make( [ 0xdbb8cd97 , 0x0f6525c5 , 0x9d926a35 , 0xc0a134f9 , 0x1751e06d , 0xb2a04f9d , 0xd082f0bf , 0x210b8e04 ] )
+  , Mont::unsafe_make( [ 0x754f4646 , 0x65ce93bf , 0x77e5dd89 , 0xe9548ca4 , 0xed871b13 , 0xf4477bc6 , 0xaaf83d7b , 0x000320f5 ] )
+  , Mont::unsafe_make( [ 0x2f3c40cd , 0xbab09586 , 0x2d3c5dc5 , 0x51238020 , 0x16173558 , 0xb0004da7 , 0x1cf44e40 , 0x28c3221c ] )
+  , Mont::unsafe_make( [ 0x53a52d46 , 0xaeb9748d , 0xb958fe72 , 0xcc513caa , 0x9fa4e95f , 0xf2b210f7 , 0x1a43ede0 , 0x175644ff ] )
+  , Mont::unsafe_make( [ 0x0143b9ce , 0xe1f19411 , 0x42bb6eb2 , 0xa77beb9f , 0xa7a01c25 , 0xd4fbdcc2 , 0xd80a994d , 0x12a29c60 ] )
+  , Mont::unsafe_make( [ 0x888e0d7d , 0xf1d05ed7 , 0x5c6c4096 , 0x28ef2aec , 0x08253712 , 0x85cf7e4c , 0x42b662ba , 0x2d70ac15 ] )
+  , Mont::unsafe_make( [ 0xc05c6906 , 0xfd6371e4 , 0xc44e3a6e , 0xebeed7f0 , 0xf97b9cd9 , 0xd32e0bcc , 0xc27e42d3 , 0x251a54ee ] )
+  , Mont::unsafe_make( [ 0x1d711c60 , 0x1ef78516 , 0xc24258df , 0x55be5821 , 0xa8ae28f7 , 0x403c8410 , 0x1da4ba9c , 0x0bc9a1fd ] )
+  , Mont::unsafe_make( [ 0x9550b0e1 , 0x910e3dae , 0x5f260efc , 0x242540d0 , 0x8e05cc1d , 0x5b8edd0e , 0x53d4eed6 , 0x0e81d1ca ] )
+  , Mont::unsafe_make( [ 0x4b30910b , 0x74643aa7 , 0x34a90257 , 0x51b44c4b , 0x0cd65d8c , 0xcc758060 , 0xa897ecb8 , 0x2248e25b ] )
+  , Mont::unsafe_make( [ 0x1711265f , 0xad72a127 , 0xcd17b1e0 , 0xa1d0a1b2 , 0x9740cfb1 , 0xcd282f82 , 0x4487a2e1 , 0x0d65825f ] )
+  , Mont::unsafe_make( [ 0xc8316195 , 0xcb807768 , 0x2390b3bb , 0xb2c01841 , 0x4a152bc6 , 0xaca3a65a , 0xa7cdcfa5 , 0x2615008a ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x387757b6 , 0x8783b43f , 0x1d3d5fa5 , 0x310f2cf2 , 0x728288cb , 0xd0d0acee , 0x24fa1162 , 0x2860da0c ] )
+  , Mont::unsafe_make( [ 0x939e4b5d , 0xf1ab66c4 , 0x586e6df6 , 0x87fbb216 , 0x086dca49 , 0x9a2552f2 , 0x03b3683a , 0x2f4d56cb ] )
+  , Mont::unsafe_make( [ 0x43b1da97 , 0x0f79f947 , 0x9f4a1058 , 0x19bec5e4 , 0x03a5b54a , 0x2e542d95 , 0x708fe8b4 , 0x18f6dd1a ] )
+  , Mont::unsafe_make( [ 0xa46469b9 , 0xdcd56140 , 0x7824f68a , 0xba072973 , 0x0f5e978e , 0xdc3f3122 , 0x99567a9b , 0x173bbbd2 ] )
+  , Mont::unsafe_make( [ 0x7f170a5f , 0xfb0e8c9e , 0x418165ce , 0x16c0c48d , 0xf34feec2 , 0x3eb16c08 , 0x49bca4c6 , 0x0f5c9370 ] )
+  , Mont::unsafe_make( [ 0xfd0bc097 , 0x26ac4ff4 , 0x47664415 , 0x0480c53b , 0xb2d52db3 , 0x1d69cc33 , 0xe089ceb3 , 0x2cccf8df ] )
+  , Mont::unsafe_make( [ 0x783ab391 , 0xdbc54c89 , 0x0625ea46 , 0xfa279b18 , 0xac32b0df , 0xbedce0ac , 0x5a261c3e , 0x122cd18c ] )
+  , Mont::unsafe_make( [ 0xba5bdbd1 , 0xee424c37 , 0x170b28e7 , 0x2f850a6f , 0xed94ea4b , 0xcc46482b , 0x074c8a26 , 0x206c6bd0 ] )
+  , Mont::unsafe_make( [ 0xe123590b , 0x52035587 , 0xc7f1ab04 , 0xa4dcb3a9 , 0x99154b6e , 0xf5cedd19 , 0x4b1cdc66 , 0x0ab17b36 ] )
+  , Mont::unsafe_make( [ 0xa6db278d , 0xd3a2f285 , 0x50dd1ef7 , 0x66ca0769 , 0xd8111bdb , 0x4773a390 , 0x35e2a76c , 0x28522f75 ] )
+  , Mont::unsafe_make( [ 0xe997ce74 , 0x6d5508f4 , 0x24748775 , 0x4dc71480 , 0xace73efd , 0xdc4a1feb , 0x8ed93e8c , 0x0dacd8e0 ] )
+  , Mont::unsafe_make( [ 0xe740a60b , 0xc2da3ab2 , 0x2f3f96da , 0xdce6e179 , 0xdf77b8cc , 0x8c7592b7 , 0x1a954710 , 0x16916eb6 ] )
+  , Mont::unsafe_make( [ 0xdc70bed9 , 0x7f314f98 , 0x04d8ed67 , 0x244807db , 0xab5d433a , 0x5d2f5b0c , 0x72f61a05 , 0x10b43ab6 ] )
+  , Mont::unsafe_make( [ 0xd01fabcb , 0xa7c1fa36 , 0x08f91770 , 0xcb2c6c24 , 0x6e8a1257 , 0xaa1a6536 , 0xc13db399 , 0x1d695970 ] )
+  , Mont::unsafe_make( [ 0xf89070c8 , 0x0e20fa62 , 0x0bc34174 , 0xaae25912 , 0xbe32800b , 0xe67cda9d , 0x78e02f5c , 0x09269713 ] )
+  , Mont::unsafe_make( [ 0x2cdd965c , 0xe61f5bb7 , 0x0d2a8ebe , 0xa08f2e62 , 0xbe2c1c38 , 0xcea927af , 0xb55cba2b , 0x2472b969 ] )
+  , Mont::unsafe_make( [ 0xbb8aaaf8 , 0x07494553 , 0x8983ddc3 , 0x53a00a02 , 0x4574d5f5 , 0xee81324f , 0x857a1931 , 0x246fe4cd ] )
+  , Mont::unsafe_make( [ 0xc9c39de6 , 0xe6ad76c2 , 0xe5a01352 , 0xb15703b9 , 0xfc23a01c , 0xf60516e1 , 0x483ef7a0 , 0x072478cd ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x4c2fe0d5 , 0x2a5ffb0a , 0xaf7d33cf , 0xd6162024 , 0x6fd61f9c , 0x0bfee8d6 , 0x292dc556 , 0x04ba9d30 ] )
+  , Mont::unsafe_make( [ 0x9e347e82 , 0x539369f8 , 0x0361fb22 , 0x004b45ef , 0x3012eea1 , 0xb1b6e7f8 , 0x6c429d1c , 0x0bcc3334 ] )
+  , Mont::unsafe_make( [ 0x77605701 , 0xc231eda9 , 0x9d54ef35 , 0x935b336b , 0x49037cb9 , 0xbd69ea02 , 0x4da44437 , 0x1cb95c9e ] )
+  , Mont::unsafe_make( [ 0x0cee3c74 , 0x0f366117 , 0x0c302b22 , 0x2903d4e3 , 0xb13e79cf , 0x216c590a , 0x2820efb6 , 0x03fc20d3 ] )
+  , Mont::unsafe_make( [ 0xe57aad61 , 0x0c18403e , 0x27071893 , 0xb0031960 , 0x05a69f22 , 0xfa0e9c86 , 0x430177d5 , 0x14e7cf6e ] )
+  , Mont::unsafe_make( [ 0x6badd407 , 0x210ceac2 , 0xc9e72790 , 0x35dbf51f , 0x5654a664 , 0xb9448c6a , 0x8d804cc6 , 0x26c4cce0 ] )
+  , Mont::unsafe_make( [ 0x63c73b83 , 0xb9ff3784 , 0xf4032698 , 0x275eb9e8 , 0x3675b133 , 0x01f92ab7 , 0x049e2add , 0x2a728a03 ] )
+  , Mont::unsafe_make( [ 0x32335bc3 , 0xaf5097d0 , 0x18b50f86 , 0x858512be , 0x66764f5d , 0x94dd496c , 0x74a8d2d7 , 0x13467d45 ] )
+  , Mont::unsafe_make( [ 0xd728432a , 0x42aa450f , 0x0bae747e , 0xcf9eb0c0 , 0xb69953b7 , 0x889a826e , 0x85a9bc74 , 0x1da273f5 ] )
+  , Mont::unsafe_make( [ 0xb48f909d , 0xea960ee9 , 0xc5dbe750 , 0x93b4d626 , 0x57ac25d3 , 0xe5498126 , 0x59d81ed4 , 0x189dc21f ] )
+  , Mont::unsafe_make( [ 0x89533dd9 , 0x2c3c7e53 , 0xcf187b56 , 0x8d884131 , 0x1c3b9f73 , 0x8c6b7699 , 0x5a45be44 , 0x2fb1cfc6 ] )
+  , Mont::unsafe_make( [ 0x1a0bee84 , 0x2a748e0c , 0xd56c8c4d , 0x2fbe2722 , 0xadaed14c , 0xe55e9308 , 0xba492517 , 0x2957f8f6 ] )
+  , Mont::unsafe_make( [ 0x402b8760 , 0x9af2555d , 0x3ea9fec1 , 0xb5185d9d , 0xb50a234a , 0xdd0396b2 , 0xff58785d , 0x1f02e471 ] )
+  , Mont::unsafe_make( [ 0x92f0e435 , 0x2de59971 , 0xdf337179 , 0xec42e7ce , 0x9222889a , 0x59bdee2d , 0x453dcd94 , 0x247743ac ] )
+  , Mont::unsafe_make( [ 0xcfceb870 , 0x3a774069 , 0x19f6cb21 , 0x2c866b3d , 0x6938419e , 0x0b9c0c99 , 0xa88a8aae , 0x051aef36 ] )
+  , Mont::unsafe_make( [ 0xa52aaabe , 0xea76cc48 , 0x678d3d0b , 0x4e290060 , 0x8db5df8c , 0x0d73802d , 0xa4baf232 , 0x041919fd ] )
+  , Mont::unsafe_make( [ 0xeb0e9daf , 0xe1ba31a2 , 0x6554d50c , 0xa56183cc , 0x5aeefdb8 , 0x6dccbc3d , 0x123fb552 , 0x032f9e45 ] )
+  , Mont::unsafe_make( [ 0xada5a4ad , 0x7bacaa2b , 0x94a69304 , 0x0f9b0a5a , 0x2e57be1b , 0x85f06df6 , 0x2d963426 , 0x1f357176 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x24fe2a61 , 0x6eac53da , 0x1fbb3fbd , 0x6f747b1c , 0x5a13f142 , 0xf239ff4d , 0xfa29f31e , 0x1acc70e3 ] )
+  , Mont::unsafe_make( [ 0x033d8ded , 0x5e719361 , 0xe685115e , 0x13ebeb7c , 0x95f2a10b , 0xff4fe2e2 , 0x81e95c73 , 0x0517b6a0 ] )
+  , Mont::unsafe_make( [ 0xb363644f , 0x02a69bc1 , 0xf6fd1c3b , 0x33f28205 , 0x96d51e2c , 0xb129302a , 0x7bf215a1 , 0x246563ed ] )
+  , Mont::unsafe_make( [ 0x507e7432 , 0x64b8bd7d , 0x2ac3df6e , 0x34008cac , 0x532b98fe , 0x13e479c7 , 0xe216ee72 , 0x06466fe6 ] )
+  , Mont::unsafe_make( [ 0xa11ccf0d , 0xf566bc02 , 0xc441498f , 0xd407bb0b , 0x17a1e608 , 0x0aab7960 , 0x2e0e1ac6 , 0x102b1836 ] )
+  , Mont::unsafe_make( [ 0xee216472 , 0xa8447937 , 0x283abec7 , 0xbe26a76b , 0x22f30db4 , 0x375b9f6c , 0xf7b59898 , 0x02f8f606 ] )
+  , Mont::unsafe_make( [ 0x9383c3a7 , 0xbc7d46ba , 0x3ed3236a , 0x53cbf61a , 0x2da4d7c9 , 0x6c70eaa5 , 0x4c44485b , 0x1b4b6a61 ] )
+  , Mont::unsafe_make( [ 0xbb3d4425 , 0xbf5bdeca , 0x13d80286 , 0x45bb612a , 0xdd643e64 , 0x7970e5b2 , 0xf43b939f , 0x1698fb50 ] )
+  , Mont::unsafe_make( [ 0x2b539b2c , 0x9822922e , 0x43c9ad13 , 0xd1f85091 , 0x33a1d093 , 0x153ef869 , 0x69576c38 , 0x2bcb5871 ] )
+  , Mont::unsafe_make( [ 0xdd985a8f , 0xa43380b9 , 0xac525dc9 , 0x72c3f605 , 0x23690960 , 0x7891c9db , 0xe0ca0f02 , 0x04ee46a4 ] )
+  , Mont::unsafe_make( [ 0xa436f0d7 , 0x4c5a41d8 , 0xaeb2fb65 , 0x6c4e5cdb , 0xe1046e86 , 0xb4ea2926 , 0x4786176e , 0x2117e412 ] )
+  , Mont::unsafe_make( [ 0x74ac5e6e , 0xf404bbc6 , 0x41751d27 , 0x8e59da22 , 0x288c467a , 0x2f31afa4 , 0xd5c61d09 , 0x0343395d ] )
+  , Mont::unsafe_make( [ 0x55177684 , 0x84131fee , 0x52807291 , 0xa9691e30 , 0x63b98cb6 , 0xde9c1975 , 0x9c81e961 , 0x29ed6829 ] )
+  , Mont::unsafe_make( [ 0x95797997 , 0x169e1206 , 0x577cb7b8 , 0x65397b86 , 0x5dc33c1a , 0xd32ae221 , 0x58a7e86b , 0x18d74d59 ] )
+  , Mont::unsafe_make( [ 0x6962da29 , 0x536e2b77 , 0x8dde4cc2 , 0xb266b69c , 0xee268c27 , 0xe63e3d5a , 0xbd10bf6d , 0x26dd76ae ] )
+  , Mont::unsafe_make( [ 0x2dafcf26 , 0xc7d19cdb , 0xfe8dfd6f , 0x2b81ea3e , 0x78efe32f , 0x4109ad01 , 0x650a820e , 0x21bb93bb ] )
+  , Mont::unsafe_make( [ 0xc64c6894 , 0x83a92d45 , 0x49bd3a51 , 0x48a9545d , 0x21f4a3fb , 0xc9286218 , 0xe387e02f , 0x2122ff63 ] )
+  , Mont::unsafe_make( [ 0x58b7ec4e , 0x5ef9c472 , 0x01283e76 , 0xa13ba2e3 , 0xd533a8fb , 0xe273f469 , 0x96b42e66 , 0x0c1dd027 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xcad59562 , 0x91e5fd53 , 0x038aec0f , 0x19de6b08 , 0xa804bab4 , 0x6ef72949 , 0x3e79ded0 , 0x28c9c6c1 ] )
+  , Mont::unsafe_make( [ 0xecd42b77 , 0x62633006 , 0x98e85749 , 0x09b57bca , 0xbe12529a , 0x7fb7e42d , 0x6b5f3aa4 , 0x0a76fc39 ] )
+  , Mont::unsafe_make( [ 0xd281fad7 , 0xe02909e1 , 0xb71c4c88 , 0xc7bb1092 , 0x3e2312aa , 0xcb6635dc , 0x697a5430 , 0x177c68a5 ] )
+  , Mont::unsafe_make( [ 0xccaa3e3e , 0x635651b0 , 0xdb4b1e06 , 0xf951fef4 , 0x3c8ddad6 , 0xe97fbd75 , 0x3c25a1d9 , 0x1837146d ] )
+  , Mont::unsafe_make( [ 0x9f522312 , 0x97c06adf , 0xdbba41f7 , 0xdc903e30 , 0x813bfca4 , 0x53fe50ea , 0xf7fbc46c , 0x2f2ef3bd ] )
+  , Mont::unsafe_make( [ 0x45e194f6 , 0x68d78ece , 0x8d7882f8 , 0xf22fa5b1 , 0x8d121757 , 0xc746170a , 0xbdedd616 , 0x14cc1cca ] )
+  , Mont::unsafe_make( [ 0x32674009 , 0x43e4bd76 , 0xcb3cdf37 , 0xa05578e0 , 0x77e0579d , 0xe52fe3da , 0x215df4a3 , 0x0d114959 ] )
+  , Mont::unsafe_make( [ 0xc83a6d9c , 0x6a3b4462 , 0x041fb985 , 0xafb656ca , 0xee19318f , 0xa0b0536b , 0x4f223db7 , 0x0ea4f75d ] )
+  , Mont::unsafe_make( [ 0xb289bef2 , 0x74fa2acf , 0x90e2817e , 0x5de38f2c , 0xeaa16bca , 0xc3125526 , 0xef1b868c , 0x0070dfaf ] )
+  , Mont::unsafe_make( [ 0xb1a6a7e5 , 0x54991bc9 , 0x34f19f57 , 0x45ec8877 , 0x58a2fdac , 0xd445eedc , 0x19ce5ddf , 0x0bc9d42a ] )
+  , Mont::unsafe_make( [ 0xcc5f8a20 , 0x91f44473 , 0xe78ae0ce , 0xecf7112a , 0x16863321 , 0x3ff81876 , 0x56727617 , 0x223ae9c3 ] )
+  , Mont::unsafe_make( [ 0x5a890d3d , 0x152be868 , 0x6cf5d81e , 0x325b501d , 0x1ee88acd , 0xbcbc7824 , 0x34937797 , 0x13520477 ] )
+  , Mont::unsafe_make( [ 0x97b00730 , 0x090c549f , 0x11923e34 , 0x7aa72e26 , 0xb3ddfa5f , 0x63c77c66 , 0x805fc2ab , 0x0515de52 ] )
+  , Mont::unsafe_make( [ 0xdc1c8dfb , 0xc5dd6d95 , 0x9b5c490d , 0xe0f82ee2 , 0xb8d7723e , 0x68181f2d , 0xa7b02a66 , 0x16291397 ] )
+  , Mont::unsafe_make( [ 0x65554d27 , 0xd444bb19 , 0x11197835 , 0xde28fd53 , 0x65af51a1 , 0xc6d648ec , 0xd06981fe , 0x2a99b56d ] )
+  , Mont::unsafe_make( [ 0xdbb27f3a , 0x065c4080 , 0x1be2c5b4 , 0x4aa4f1ca , 0x7d7fda41 , 0x639c9a28 , 0x01c8887c , 0x278b7435 ] )
+  , Mont::unsafe_make( [ 0xb1090fa4 , 0xf6d57f08 , 0x95b52d45 , 0x3d601889 , 0x1722c185 , 0xeda6ce26 , 0x433e4948 , 0x0753e2a7 ] )
+  , Mont::unsafe_make( [ 0x92f8da4e , 0x0f531784 , 0xbf275af8 , 0xe30db2bd , 0x0f35a3e8 , 0x7524b18f , 0x3650d374 , 0x2e0cebbd ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x417f4ac1 , 0xe849713e , 0xc5c2d47b , 0x478a42af , 0x5b9e1473 , 0xc9821b79 , 0xd00895b7 , 0x143c8291 ] )
+  , Mont::unsafe_make( [ 0xaae2877c , 0xd57dad9f , 0xeb248c2c , 0xa2d3d5e0 , 0xb3ba1f21 , 0x85d58f03 , 0x0f5e6d8e , 0x1d8282d2 ] )
+  , Mont::unsafe_make( [ 0x3f0b1187 , 0x1322ae6c , 0x1bdaa484 , 0xc2f48a01 , 0xde6d3f16 , 0x5daa740b , 0x7948cc99 , 0x13e16ea8 ] )
+  , Mont::unsafe_make( [ 0x6fe395a1 , 0x3a44416e , 0xd5945a4c , 0x86775f40 , 0xba337e61 , 0x0fb8a54e , 0x0ef7660a , 0x11660d60 ] )
+  , Mont::unsafe_make( [ 0xe8d2ccd7 , 0x322f5119 , 0x949b70d7 , 0xe6970a98 , 0x618b8f00 , 0xafcb9c38 , 0x88b5a879 , 0x0672dae3 ] )
+  , Mont::unsafe_make( [ 0xad7f2a65 , 0xa31852c8 , 0x7a5adb5b , 0xdbbdf373 , 0x48109c7b , 0x50e904a9 , 0x95838434 , 0x09dd5ba8 ] )
+  , Mont::unsafe_make( [ 0xfaf7d9df , 0x5d9eca7f , 0xf4b4ca84 , 0x3fe87f6c , 0x34b5d2dd , 0x465b4def , 0xe52785ed , 0x1395d8d3 ] )
+  , Mont::unsafe_make( [ 0x9138616c , 0x66620499 , 0x74c01bdf , 0x6cb95bd7 , 0xf31b25e9 , 0x8f74dbb5 , 0xcf2b325a , 0x24f6ef1d ] )
+  , Mont::unsafe_make( [ 0x89e03337 , 0x4da1d547 , 0x4fc1a1d8 , 0x545a3c68 , 0xee47c47c , 0x9f2f1135 , 0xb4da41ae , 0x1da243d7 ] )
+  , Mont::unsafe_make( [ 0x06b66406 , 0x418b39dd , 0xb0282ee9 , 0x48ec5b43 , 0xa4bf48f7 , 0x8ea3206f , 0xefec69cd , 0x1c1e15b3 ] )
+  , Mont::unsafe_make( [ 0xa0a4e275 , 0x7fc5bb90 , 0x0e6b9342 , 0x9ab0e1f4 , 0x0ab6983e , 0x00700d45 , 0x96bd99f3 , 0x21dcd378 ] )
+  , Mont::unsafe_make( [ 0xd741dd57 , 0xd730e425 , 0xff848add , 0x03225ba2 , 0xf837263e , 0x007d7f9e , 0x597ea449 , 0x03f52302 ] )
+  , Mont::unsafe_make( [ 0x85c52cc8 , 0x5346148c , 0x814071f1 , 0x4fd25828 , 0x187e3b96 , 0x2efaf20c , 0x316db8d0 , 0x1104f418 ] )
+  , Mont::unsafe_make( [ 0x4ac352c8 , 0xeef7ecdb , 0x136ddc53 , 0x24eb235a , 0x1f823cfc , 0xce1aa179 , 0xf8e37d27 , 0x2dab4c91 ] )
+  , Mont::unsafe_make( [ 0xb40492d3 , 0xd8a8150f , 0x2d06c36b , 0xd8f97167 , 0x54d63809 , 0x94d5bb30 , 0x9f947deb , 0x19eee685 ] )
+  , Mont::unsafe_make( [ 0xc014b51c , 0x341e6bec , 0xfddf0fe9 , 0xfa9df49a , 0xc0070f37 , 0xd8397977 , 0x7d1a76f5 , 0x021fd683 ] )
+  , Mont::unsafe_make( [ 0xa7f81b81 , 0x14bec61f , 0xa70fb1f9 , 0x8437a870 , 0x28f95a0e , 0xb15bf9d9 , 0x657db865 , 0x2870e5ac ] )
+  , Mont::unsafe_make( [ 0x61473daf , 0xed5996ba , 0x281d2c37 , 0x8d5175ec , 0xf8646a4c , 0xeec618d8 , 0x34e8843a , 0x30078fb6 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xd02200ce , 0x2fc19976 , 0x99c94ee9 , 0x11d4db4b , 0x97bac0b3 , 0x79f11c77 , 0x9ce5e7eb , 0x269ae811 ] )
+  , Mont::unsafe_make( [ 0xa903e9d7 , 0x37929cb9 , 0x6844c769 , 0x9ee92cc9 , 0xbca25d4a , 0xea1de25b , 0xb2fb160f , 0x2c0addfe ] )
+  , Mont::unsafe_make( [ 0x1c684add , 0xb39c54c4 , 0x03d6a29e , 0x0a53e8e1 , 0x25ee7d74 , 0xea92f050 , 0x81348d9f , 0x15473b80 ] )
+  , Mont::unsafe_make( [ 0xce788f6c , 0xc3c413ad , 0x6916cb76 , 0xbfd7be93 , 0x77eea763 , 0x704c6108 , 0xf336b2d7 , 0x01f219c9 ] )
+  , Mont::unsafe_make( [ 0x6b733bba , 0xe69ae591 , 0x65a294bb , 0x43f0fbf2 , 0x2f425174 , 0x3bb320d2 , 0xb98a953d , 0x0cddc588 ] )
+  , Mont::unsafe_make( [ 0x4b63db18 , 0xd9c3585c , 0xf31de718 , 0x8aa4fe93 , 0xad811718 , 0xddda8d03 , 0x8d4e1dae , 0x24d190ed ] )
+  , Mont::unsafe_make( [ 0xdfd76bf2 , 0x73c0b4e7 , 0xe1936613 , 0x9dc0d25a , 0xdafaebc1 , 0x59865d2e , 0x881e676c , 0x071f35ab ] )
+  , Mont::unsafe_make( [ 0x9728a8ea , 0x0f548b66 , 0xdbc95eed , 0x4dc42632 , 0x11e14e1a , 0xa4ed9aac , 0x757ce815 , 0x1ff615ad ] )
+  , Mont::unsafe_make( [ 0x3eec5db2 , 0xf84ffd6e , 0x29657c4e , 0xcb8967ac , 0x471d8149 , 0x6e08cc79 , 0xa6520e30 , 0x184daed8 ] )
+  , Mont::unsafe_make( [ 0xaebaa258 , 0xb9c041cf , 0x89125c28 , 0x24005358 , 0x0d06c781 , 0x959dca3e , 0xa2d81423 , 0x2fa2985c ] )
+  , Mont::unsafe_make( [ 0x81411121 , 0xc263ec4d , 0xbbf81f45 , 0xb4b4f182 , 0xf655e0db , 0x996580d0 , 0xf91d2f13 , 0x18217bb0 ] )
+  , Mont::unsafe_make( [ 0x17d24f35 , 0x288b98cc , 0x2ac751ec , 0xb7ab954b , 0xd1a77a7d , 0x03440d3a , 0x3decea1c , 0x0c708525 ] )
+  , Mont::unsafe_make( [ 0x0bb32143 , 0x774bfe42 , 0xe20b3615 , 0x46836007 , 0xb8296f9c , 0x3eb63b00 , 0x7f1ecd20 , 0x19ad91e4 ] )
+  , Mont::unsafe_make( [ 0x6b791726 , 0x36104f9e , 0xa97fa160 , 0xc7827ae4 , 0x875689bd , 0x2b261903 , 0x93012411 , 0x22a97dc6 ] )
+  , Mont::unsafe_make( [ 0xab2eacf2 , 0xcdfa3760 , 0xabe24036 , 0x14075a39 , 0x9d57bfef , 0x2e86e803 , 0xd4f3d545 , 0x07137304 ] )
+  , Mont::unsafe_make( [ 0xb0ad6555 , 0x0de1e1ca , 0x4a82b818 , 0xb89e8d4e , 0x43d56e81 , 0x98c6602f , 0x1eb2dea6 , 0x2a863226 ] )
+  , Mont::unsafe_make( [ 0x626f05cc , 0xe05136c5 , 0xb485ac30 , 0x55654187 , 0x3650504f , 0x9a07e410 , 0x19fbb611 , 0x2e09aacf ] )
+  , Mont::unsafe_make( [ 0x7679e528 , 0xcc9d225d , 0x6394ffd0 , 0x468e53a7 , 0x71037da2 , 0xd191a7ac , 0xeb95a659 , 0x0789c735 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x34fbf773 , 0x470579f3 , 0x5df0980c , 0xf077e784 , 0x58ffb6e7 , 0x90f36a81 , 0xee2f066c , 0x0685bf6f ] )
+  , Mont::unsafe_make( [ 0xe20bbe81 , 0xe9a4e4bd , 0xc83306fd , 0x4f8fe0c2 , 0x267c7b2e , 0xcff53a14 , 0x0d71709d , 0x0f45f57c ] )
+  , Mont::unsafe_make( [ 0x6aa54419 , 0x3043dc46 , 0x49e84251 , 0x083edcb8 , 0x12cde14f , 0xdddd1b5e , 0x60bc21db , 0x1dc3bce8 ] )
+  , Mont::unsafe_make( [ 0xebb22bdd , 0x961cf42e , 0x2c017e86 , 0xa68b7295 , 0x3c09f9c9 , 0x3cb931d7 , 0xc66f5ed9 , 0x23bb1c4c ] )
+  , Mont::unsafe_make( [ 0x4c6f3738 , 0x15cce58f , 0xe84c8829 , 0x2be4db6c , 0xf2c16271 , 0x9eb4778b , 0x1f25869e , 0x009b317c ] )
+  , Mont::unsafe_make( [ 0x6277c2b7 , 0x9fbae35d , 0x6aacaff8 , 0x69a630d9 , 0x29d1ca66 , 0x9df9f198 , 0x41fdf6ad , 0x000dd3c3 ] )
+  , Mont::unsafe_make( [ 0x6f699148 , 0xd1d6f68c , 0x1e93b4bf , 0x99e79ef4 , 0x3a4f76b3 , 0xc2f6de7e , 0xc9b3ddd3 , 0x1202be75 ] )
+  , Mont::unsafe_make( [ 0x78a9811c , 0x444bd063 , 0x8b186626 , 0x25c51f55 , 0xcf4e068c , 0x4db52626 , 0xe1fe8c35 , 0x251b150b ] )
+  , Mont::unsafe_make( [ 0xf8b96104 , 0x542a1ca4 , 0x2a0ef233 , 0x83fe805b , 0x87adf8d3 , 0x6cae9d69 , 0x8f2f4c82 , 0x1e7fdf73 ] )
+  , Mont::unsafe_make( [ 0x0c15236c , 0x228b8344 , 0x09677496 , 0x86ecd168 , 0x71e89b8c , 0x90f0ca4e , 0x9292ce7f , 0x00faf63c ] )
+  , Mont::unsafe_make( [ 0x6b4017dd , 0x426f832a , 0x7b3933fd , 0xf38e3082 , 0xcbcc1dc4 , 0x46ed02ee , 0x3ce1de3d , 0x24f7e07c ] )
+  , Mont::unsafe_make( [ 0x1ac56464 , 0x6b2dd3ad , 0x2757e29c , 0xb4e6d450 , 0xffe0d1dd , 0x6b2fe250 , 0x9ed4a2b6 , 0x0704da36 ] )
+  , Mont::unsafe_make( [ 0xfb2ef57d , 0x9775c334 , 0x547b4348 , 0xcd6599ef , 0x7069043a , 0x23ae0b24 , 0xdcf3f444 , 0x0b055780 ] )
+  , Mont::unsafe_make( [ 0xc52b8b66 , 0x080f92a8 , 0x28d31763 , 0x0961f2e0 , 0xb0217a2c , 0xb6c8c394 , 0x782df382 , 0x1ee1c29c ] )
+  , Mont::unsafe_make( [ 0xe3542cdd , 0x872cf6aa , 0xf35b6716 , 0x46af61ca , 0xe871e282 , 0x99bde4e6 , 0xdb0b7451 , 0x246bd995 ] )
+  , Mont::unsafe_make( [ 0x2c3dba4a , 0x87c3ac61 , 0x415e0607 , 0x0daf45a8 , 0x1cd30b83 , 0xbcb0dba0 , 0x81478052 , 0x04cfca34 ] )
+  , Mont::unsafe_make( [ 0x813c5fee , 0x2145021d , 0xa37b639d , 0x3e2a41a1 , 0xae907cde , 0x1805c264 , 0xa05354a8 , 0x141c0ba4 ] )
+  , Mont::unsafe_make( [ 0xc1a4921d , 0x204c4e1f , 0x848ae96a , 0x228dcdf1 , 0xdbc53d45 , 0x39e79bd9 , 0xa2d83184 , 0x0aba4c46 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xebde6a35 , 0x3c3302cc , 0xc0132985 , 0x9bcca25d , 0xbff13f81 , 0x42b30be7 , 0x899489c5 , 0x29213845 ] )
+  , Mont::unsafe_make( [ 0xb665ba59 , 0x65312cb9 , 0x632e7075 , 0xfa257aff , 0xbd7c98fe , 0xf204ff77 , 0x4c5d06a5 , 0x0cde3f62 ] )
+  , Mont::unsafe_make( [ 0xd557a3cf , 0x898d3a35 , 0x843f7d08 , 0x10c55b43 , 0x4f04d62d , 0x9d12c6d6 , 0x3f062805 , 0x2f76f69c ] )
+  , Mont::unsafe_make( [ 0x2936a72c , 0x6212f6ad , 0x95734b64 , 0x1a5f028f , 0xa04a0d7b , 0x3ec4ee70 , 0x79e8df15 , 0x28785ba0 ] )
+  , Mont::unsafe_make( [ 0xa48b7e77 , 0x3fc0122e , 0x086e8f16 , 0x4c05ccec , 0x09e2719a , 0x8947125d , 0x695cd9f2 , 0x022ec720 ] )
+  , Mont::unsafe_make( [ 0x2e5c33b9 , 0xca4cf4e9 , 0x52771c6a , 0x9d7e5f9f , 0x421c345f , 0xe396411a , 0xea9bf0e4 , 0x099298ee ] )
+  , Mont::unsafe_make( [ 0x1d2ca338 , 0xbaff9620 , 0x748e75a8 , 0xb8de28ac , 0xae466687 , 0x86a62aa4 , 0xde5d1f10 , 0x1df3c65d ] )
+  , Mont::unsafe_make( [ 0xcf16cbbc , 0xb382fd12 , 0xdbda3115 , 0xd3fabb73 , 0xe0304770 , 0x47682b66 , 0xa5acc39b , 0x1e488096 ] )
+  , Mont::unsafe_make( [ 0x0c0c30b2 , 0x80716223 , 0xc4a25ef1 , 0x9ba4b84e , 0xd66992c8 , 0x009a4b03 , 0x3395fc38 , 0x0a15d275 ] )
+  , Mont::unsafe_make( [ 0x3eaacf3a , 0x1388298b , 0x36c1decd , 0x72ac3118 , 0x96275f23 , 0x2ca6d3f6 , 0xa06b9980 , 0x07818e55 ] )
+  , Mont::unsafe_make( [ 0x034a7cad , 0xad1c9d93 , 0x1a696202 , 0xf75269f6 , 0x7362ef08 , 0xd061785b , 0x11e38f03 , 0x224cd88f ] )
+  , Mont::unsafe_make( [ 0x73069c28 , 0xa4418feb , 0x7f61c635 , 0xd6607358 , 0xb804ee53 , 0xcc5797ff , 0x31dc6b22 , 0x0e99b1d4 ] )
+  , Mont::unsafe_make( [ 0x6d670957 , 0x850de208 , 0x70b322bf , 0x47124d61 , 0xc74d69dd , 0xdc27a6df , 0x39ac55f9 , 0x23a1be45 ] )
+  , Mont::unsafe_make( [ 0x322c9c41 , 0x2c1e3895 , 0x59a05b4f , 0x94b9bdb7 , 0xd4073a1a , 0xfb390f12 , 0x6b174746 , 0x119a080d ] )
+  , Mont::unsafe_make( [ 0x1bbbe7c8 , 0x62c1726e , 0xd4c5cf14 , 0x4d7a1045 , 0x3e6b5952 , 0xe8e45c2f , 0x78748491 , 0x28018268 ] )
+  , Mont::unsafe_make( [ 0xf2b50b31 , 0x3469dd53 , 0x66fd653b , 0x16be0719 , 0x002fe615 , 0xd7fc0c6e , 0xb8653bda , 0x216fc19e ] )
+  , Mont::unsafe_make( [ 0x74777a23 , 0x85d70304 , 0x98e8a6bd , 0x5b70a7c0 , 0xed53a6af , 0xb7bfbedd , 0x24d74d67 , 0x1d7aee8e ] )
+  , Mont::unsafe_make( [ 0xcc414ace , 0x853be8f2 , 0x5f3a9c88 , 0x52b003f9 , 0xbb13f736 , 0xc989b5f0 , 0xa90574da , 0x2b3ee482 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x13e1f041 , 0x0c604410 , 0xf579e974 , 0x4bd1801a , 0x634f7a97 , 0x585589ba , 0x437c1751 , 0x02f9e417 ] )
+  , Mont::unsafe_make( [ 0xd5d0beb0 , 0xf305b8dc , 0x8851e999 , 0xdbc351b7 , 0xa8bc3f1c , 0x3ae352cf , 0x8659d014 , 0x099c6979 ] )
+  , Mont::unsafe_make( [ 0x5144138b , 0x9cf9992a , 0xb6e934e8 , 0x326f4cf9 , 0x7621f53f , 0x62190593 , 0x56f40bb2 , 0x03575572 ] )
+  , Mont::unsafe_make( [ 0x96507f09 , 0x2d6f9edc , 0xb9e6d041 , 0xad176267 , 0x40caffff , 0x74bd1e5c , 0x90cc7707 , 0x025c71ee ] )
+  , Mont::unsafe_make( [ 0x4f8ec141 , 0x3e17ab04 , 0x65ee7cd0 , 0x5b30a756 , 0x4e433d7c , 0x15ad3d75 , 0x78dc5996 , 0x1f696304 ] )
+  , Mont::unsafe_make( [ 0xfb98f335 , 0xd223f2e3 , 0x6b1ef2c8 , 0xfce94ed1 , 0xc2cc7551 , 0xdff55079 , 0x9253c6cc , 0x066771c6 ] )
+  , Mont::unsafe_make( [ 0x88381058 , 0x04c8e278 , 0xf4127c33 , 0xca609412 , 0x000b2c74 , 0x74868afb , 0xb2b52996 , 0x158cf7d4 ] )
+  , Mont::unsafe_make( [ 0x546bce96 , 0xb4c49821 , 0xcbb6d2cb , 0x29b32e5d , 0x93ec533f , 0x140f7d74 , 0xad30daa5 , 0x0abc9f02 ] )
+  , Mont::unsafe_make( [ 0x102cf904 , 0xb527edeb , 0xe1e36b5d , 0x20b28e79 , 0xd2d8b63e , 0x4d5fce69 , 0x1ab0e408 , 0x1def0277 ] )
+  , Mont::unsafe_make( [ 0xf60317b6 , 0x0413ef3c , 0x1b311a5c , 0x20adbda8 , 0x4cda8433 , 0x97cd920f , 0x0c0c82ff , 0x0e54eb6e ] )
+  , Mont::unsafe_make( [ 0x6b0d4dd6 , 0xfccc50c1 , 0xb106eacc , 0x741931e0 , 0xa4fc62d9 , 0x235a24e8 , 0xc27e2aff , 0x0ff0d47a ] )
+  , Mont::unsafe_make( [ 0x45f55a9a , 0x01834bb4 , 0x77043c24 , 0x5f54a3ca , 0x861d8e63 , 0x20da2f7b , 0xb7e86365 , 0x04ba0b1b ] )
+  , Mont::unsafe_make( [ 0x74d5b5c4 , 0x6fd11489 , 0x30680099 , 0x438b4a5b , 0x7157ee51 , 0xb0b0351d , 0x57cd833b , 0x2e70d47d ] )
+  , Mont::unsafe_make( [ 0x2a8e2b85 , 0x39ec9e03 , 0x48684999 , 0x9fb8c7c3 , 0x8a71d4e4 , 0xc23e9fe8 , 0x3202f17a , 0x2d5fd82f ] )
+  , Mont::unsafe_make( [ 0x57d014f4 , 0xc4f44e11 , 0x0fe3f0d1 , 0x05f921cc , 0x1ef82e6d , 0x415f811c , 0xbafee188 , 0x26830ae8 ] )
+  , Mont::unsafe_make( [ 0x4d266dc8 , 0x83037d6a , 0xa0be3c04 , 0x0bfcbd21 , 0x87ac437c , 0xd234dc4c , 0xb8f7cdcf , 0x138e9be0 ] )
+  , Mont::unsafe_make( [ 0xada27155 , 0x5acad384 , 0xa63cdf69 , 0x4cb3ccbf , 0xdde1db1d , 0x60177f82 , 0xaabac438 , 0x009f400e ] )
+  , Mont::unsafe_make( [ 0x93c459bd , 0x83ae2de7 , 0x7d3ffc4a , 0xc6f869cb , 0x7f64469c , 0x7ea52958 , 0x67fb5c2c , 0x251fcb98 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xfa11e79a , 0x07377f2d , 0xfa85a41a , 0x732787bb , 0x14eb18b6 , 0x67c3d540 , 0x4d434625 , 0x000f81bd ] )
+  , Mont::unsafe_make( [ 0xf4e7bbd3 , 0xba120518 , 0x5ab75e73 , 0xd7493b5a , 0x1338ae7d , 0xd7211f87 , 0xdf564307 , 0x0d537218 ] )
+  , Mont::unsafe_make( [ 0xbcdc5dbb , 0xb248e802 , 0xbd77dc4f , 0x63073418 , 0x5cd02462 , 0xe156b6cf , 0xbccfc7eb , 0x2582d337 ] )
+  , Mont::unsafe_make( [ 0xd4132356 , 0x133b1e7c , 0x3be534d7 , 0x0d6b0837 , 0x1944e03d , 0xbde50e83 , 0x1141f9d8 , 0x012b57ee ] )
+  , Mont::unsafe_make( [ 0xc7cab65f , 0x4c150806 , 0x6bec076c , 0x81ad2850 , 0xbb805385 , 0x05c9d9f9 , 0xd1530e56 , 0x23b2a689 ] )
+  , Mont::unsafe_make( [ 0xcd3e7c82 , 0xb18f2c62 , 0x9326e0cc , 0x6384d0ba , 0x6becdc94 , 0xc987c23e , 0x34abafdc , 0x2071b06e ] )
+  , Mont::unsafe_make( [ 0x1e4615af , 0xf2c795ac , 0x9da2f008 , 0x312a211e , 0x19e98eb5 , 0x83e25cf4 , 0xe9f0fb94 , 0x2286551c ] )
+  , Mont::unsafe_make( [ 0xed219ee1 , 0x9c9a2fcf , 0x9f8c1835 , 0x87733109 , 0x23c7a004 , 0xe6031cfe , 0xc50bcbda , 0x1de31d4b ] )
+  , Mont::unsafe_make( [ 0x3254d3c8 , 0x84e64aa3 , 0x112d0a4f , 0xb945fb97 , 0x8316d13e , 0x6553471c , 0xd6d19411 , 0x0fbe2d51 ] )
+  , Mont::unsafe_make( [ 0xc783ced6 , 0x0b9329c1 , 0x861f565e , 0x3a1f6b73 , 0x103b123e , 0x2049c151 , 0xd5653ede , 0x0cc82b4c ] )
+  , Mont::unsafe_make( [ 0x68ee1f58 , 0x654aa1c1 , 0x27310cef , 0xf170d548 , 0xc1c2dce4 , 0x6f59cadf , 0x3a9d33d5 , 0x0f3e9e40 ] )
+  , Mont::unsafe_make( [ 0xc8b402c6 , 0xec533b4f , 0x802093cc , 0x41cacc3f , 0x9ff04952 , 0x0aa21e68 , 0x4d095692 , 0x259ec7b6 ] )
+  , Mont::unsafe_make( [ 0xcd712737 , 0x3299f34c , 0xa0b8bd4b , 0xdb8b245e , 0x725bdeb6 , 0xe32cc845 , 0x34378c41 , 0x2e9deaed ] )
+  , Mont::unsafe_make( [ 0xc1f3989d , 0x2e4a913b , 0x4244f5ff , 0xc65e6f5b , 0xde1124d5 , 0xb077f319 , 0x979792ea , 0x10017674 ] )
+  , Mont::unsafe_make( [ 0xdb558a89 , 0x63d1aeb8 , 0xc4f95278 , 0x45db04fd , 0xe5adca4f , 0x426aa6aa , 0x62e7b951 , 0x1915452b ] )
+  , Mont::unsafe_make( [ 0x1b74f029 , 0xef497b70 , 0x50151d41 , 0x670a51ea , 0x173827d4 , 0xf46de1ec , 0x3002a4c0 , 0x2c1e1c0b ] )
+  , Mont::unsafe_make( [ 0x9da30650 , 0x78daad25 , 0x1c325532 , 0x482f1a0e , 0xd0663941 , 0x98ab5546 , 0xc89b1d78 , 0x1d5fe985 ] )
+  , Mont::unsafe_make( [ 0x289cd807 , 0xf4d1bbb5 , 0x1060d6b1 , 0x776ce80a , 0x035b1de1 , 0x352d5f60 , 0x0fb41c73 , 0x1404d6d5 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0x4ae70dbb , 0x900bf163 , 0x46711b2b , 0x0e5dde9a , 0xa30ac0f6 , 0x437f12ba , 0x552511a3 , 0x02b0734c ] )
+  , Mont::unsafe_make( [ 0x4dc9c075 , 0xb6227694 , 0x32c1cba2 , 0x4c8ed66e , 0xaeb6e390 , 0x01f304d8 , 0xe31c35f0 , 0x2c24780b ] )
+  , Mont::unsafe_make( [ 0xead6fffa , 0x9f323aa7 , 0x89c266cd , 0x53fa48be , 0x349b4349 , 0x5a4727b1 , 0xe9d7ba57 , 0x16a9e428 ] )
+  , Mont::unsafe_make( [ 0x62eccaae , 0xeb4a6f8c , 0x13463674 , 0xe26f62aa , 0x750b8441 , 0x2964d7c8 , 0xa6a2574b , 0x194a3080 ] )
+  , Mont::unsafe_make( [ 0xe6d8e3b3 , 0x24ea5237 , 0x081477a9 , 0x1f10802c , 0x026c6bbf , 0xa162f4d3 , 0x82cb97bf , 0x2cc84fdd ] )
+  , Mont::unsafe_make( [ 0x9e28b246 , 0x7b6669ac , 0x2e5a90c5 , 0x3a9d9340 , 0xbdb6bb5e , 0xe57f80bb , 0x378b7ff3 , 0x002ee1cc ] )
+  , Mont::unsafe_make( [ 0x609c6f95 , 0x0a5f7dc0 , 0x9a577da4 , 0xb54f87aa , 0x57e74416 , 0x1a6c6b0a , 0x010d6175 , 0x0108baba ] )
+  , Mont::unsafe_make( [ 0xf140e624 , 0xf1feb461 , 0x27ce126d , 0x4ff4c8d8 , 0xd1ac686e , 0x8e221d89 , 0xcadac2ee , 0x25caccd5 ] )
+  , Mont::unsafe_make( [ 0xb4cc4173 , 0x8796a9f3 , 0x6e769726 , 0xedea6669 , 0x25d61c10 , 0x490e7e70 , 0x21ed90c4 , 0x300c38b3 ] )
+  , Mont::unsafe_make( [ 0x99dd91cd , 0x816e45d4 , 0xfde267ef , 0x2323d2d1 , 0x2d8ba495 , 0xacb721dd , 0x4725eff0 , 0x2401ae92 ] )
+  , Mont::unsafe_make( [ 0x3902ee7e , 0x5998d7d1 , 0x59dbda9e , 0x0970c308 , 0xa5fe4af2 , 0x25f86d33 , 0xfd190f0f , 0x1f786dc0 ] )
+  , Mont::unsafe_make( [ 0x86097504 , 0x76a70435 , 0xbaf1c61c , 0x8fdfdf67 , 0xcf13ba34 , 0xf412ebcc , 0x2a98f869 , 0x12a49f7e ] )
+  , Mont::unsafe_make( [ 0xe5522f15 , 0x2b5f3bbe , 0xc3e5c03f , 0x3be26250 , 0x51cf2f46 , 0xd53bfca6 , 0x76a7e583 , 0x137b5062 ] )
+  , Mont::unsafe_make( [ 0x48c05298 , 0x6e535b03 , 0x59226c02 , 0xe36a68d1 , 0x712bd9c8 , 0x3d6bd712 , 0x7cf8e541 , 0x282389d3 ] )
+  , Mont::unsafe_make( [ 0xeb3932b7 , 0xa7896ef9 , 0xf33472ee , 0x5d0acf1e , 0xd4f55d8a , 0x5687e2e0 , 0x4f887eb6 , 0x2e367741 ] )
+  , Mont::unsafe_make( [ 0xc8cd9548 , 0xb82b603c , 0xd54aa234 , 0x39ec1948 , 0x7d0dbdb3 , 0x2e83ab98 , 0x5fee6362 , 0x2a6e94ce ] )
+  , Mont::unsafe_make( [ 0x91e76e22 , 0x6eddf195 , 0x97b4085c , 0xd70066f3 , 0x6423f687 , 0x100de556 , 0x27f976d5 , 0x0b68eafa ] )
+  , Mont::unsafe_make( [ 0xbeae84e3 , 0xb9de9974 , 0xf7291d9a , 0x16a0fa7f , 0x3b8a5a40 , 0xecf9c2ad , 0xf4a71124 , 0x1c4d50c5 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xc62e2aea , 0x262db2c6 , 0x601848ef , 0xe7301723 , 0xd3cdb927 , 0xc10b2812 , 0x05d728bc , 0x0668e4ce ] )
+  , Mont::unsafe_make( [ 0x97a30a37 , 0x2f6a7137 , 0x42af5a0b , 0xbcc7124f , 0x2d70a67f , 0x2590509e , 0x96ad540f , 0x1ca364b0 ] )
+  , Mont::unsafe_make( [ 0xac663c18 , 0xb8eb2645 , 0x79fe8dc5 , 0xc713323d , 0xf69c3c5e , 0x5410c5dd , 0x279ba8e7 , 0x132ff136 ] )
+  , Mont::unsafe_make( [ 0x5f779677 , 0xdae99c34 , 0x5991c737 , 0x8fabadbc , 0x0fae92da , 0x7ddb08e2 , 0xe8651384 , 0x181f36fc ] )
+  , Mont::unsafe_make( [ 0xd7f784b0 , 0x072b5b51 , 0x65ca04d0 , 0x22ba6781 , 0x99a9ec70 , 0x645615f2 , 0x72a1b288 , 0x2138862d ] )
+  , Mont::unsafe_make( [ 0x5b3389fa , 0x874c7202 , 0xdc425954 , 0xbd9a6d9a , 0x76557cc2 , 0x632a5c54 , 0x667bf82f , 0x125e05ae ] )
+  , Mont::unsafe_make( [ 0xa8e094e9 , 0xac6ade29 , 0xd9cba0c5 , 0xe9a4681e , 0x85a969af , 0xa189b91e , 0x43f90050 , 0x2826ed13 ] )
+  , Mont::unsafe_make( [ 0xd318000e , 0xde7b7115 , 0x874628be , 0x7f37836e , 0x4450665f , 0x2511fa55 , 0x2a7d5d3d , 0x301391f9 ] )
+  , Mont::unsafe_make( [ 0x7c3bbffa , 0x17addac9 , 0x51a7c672 , 0x582ec858 , 0x4e5444dc , 0x09d56333 , 0x03dad2fe , 0x1b7b9372 ] )
+  , Mont::unsafe_make( [ 0x30cca7ba , 0x74138626 , 0x6662e467 , 0x0f067a0b , 0x9267e47b , 0xb8c07872 , 0xcd757d23 , 0x25311de6 ] )
+  , Mont::unsafe_make( [ 0x5df3277d , 0x3ba8a98b , 0x93bafba5 , 0x956fc736 , 0xb924aea3 , 0xec08a6d4 , 0xb692a0ca , 0x15d2aa78 ] )
+  , Mont::unsafe_make( [ 0xea1bc16f , 0x971176db , 0x65a6f544 , 0xe2dda728 , 0xe97eed3e , 0x48ea68c3 , 0xf0d44b26 , 0x2e4dc769 ] )
+  , Mont::unsafe_make( [ 0x1aec8856 , 0x06e5eceb , 0x7312b8e0 , 0x50188905 , 0xb165aa0a , 0x2dccb145 , 0x8744be3e , 0x0a75c6cd ] )
+  , Mont::unsafe_make( [ 0xbb1b3d8d , 0xc43cc27e , 0x26232dd9 , 0x41cbbb2b , 0x6078f717 , 0xe984132c , 0xab3f4f4a , 0x0dc075c4 ] )
+  , Mont::unsafe_make( [ 0x0f29f9b9 , 0xc469b5d5 , 0xd0b35773 , 0x59e13ce6 , 0xd84b7351 , 0x856f9356 , 0xf4a2eb73 , 0x2e2f4416 ] )
+  , Mont::unsafe_make( [ 0x7368efa2 , 0xead5cbac , 0x0e051b6a , 0xd1c0f6cb , 0xe927272a , 0x617e3866 , 0x8a45e53e , 0x11f4d56b ] )
+  , Mont::unsafe_make( [ 0xfd4d757a , 0x166d2c58 , 0xd9ae907f , 0x04351c2f , 0x6c6013f4 , 0x7734a240 , 0xa7b419c0 , 0x2c1d9ad4 ] )
+  , Mont::unsafe_make( [ 0x3ddbc38a , 0x3d663ac4 , 0xff7667d9 , 0x42ab2c22 , 0x5231f8e0 , 0x9c82406e , 0x940926d1 , 0x143a409f ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xb555f5c3 , 0xefd76ac6 , 0x96145bfd , 0xb51f52b1 , 0xb5f6207d , 0x56dac070 , 0x666fa0d2 , 0x1d03bc13 ] )
+  , Mont::unsafe_make( [ 0x4ee465f7 , 0xe7a1ba11 , 0x047936ac , 0x6c47a16b , 0x5321379b , 0xb4aabdd1 , 0x84f2d3a2 , 0x1a9dff8a ] )
+  , Mont::unsafe_make( [ 0x53fc47ce , 0x43cac95d , 0x4b04a472 , 0x4dab1c36 , 0x22444fae , 0xc19b1e6e , 0x64b8d245 , 0x260d5604 ] )
+  , Mont::unsafe_make( [ 0xa44a5ed8 , 0xd5776c39 , 0xd006dece , 0x84773a5f , 0x7f9a57cb , 0x0e46c84d , 0x87b9fbec , 0x0cc3a81c ] )
+  , Mont::unsafe_make( [ 0x0a61d087 , 0x6d5f6e03 , 0x99831af8 , 0x171b4596 , 0x07552deb , 0x29e63ef4 , 0x411ea08f , 0x129e41be ] )
+  , Mont::unsafe_make( [ 0xe1007e96 , 0x644750e9 , 0x9064e984 , 0xc7e64f77 , 0x95571bf5 , 0x26d3d826 , 0x7ded1f37 , 0x0c507972 ] )
+  , Mont::unsafe_make( [ 0x237f8167 , 0xfbc05309 , 0xe94c4706 , 0x5aebc486 , 0x49a8ca55 , 0x97253dbe , 0x6d67ff2d , 0x20a21c0f ] )
+  , Mont::unsafe_make( [ 0x335731f0 , 0x5f0a217b , 0x887a24a7 , 0x8c8f3d8a , 0xd5f1c3e7 , 0xa4106bdb , 0xdcf05988 , 0x04a6d992 ] )
+  , Mont::unsafe_make( [ 0x6b89bec1 , 0x6416e44c , 0xef3e1943 , 0xfd36d946 , 0x7dbe462a , 0x0beca87d , 0x403968a5 , 0x27156c21 ] )
+  , Mont::unsafe_make( [ 0x3a8c5f64 , 0x6a5c3dbc , 0xa5f2fd2d , 0x8e304b0f , 0xf782408c , 0xb1897560 , 0x0c7cb19c , 0x04239f52 ] )
+  , Mont::unsafe_make( [ 0xc4567452 , 0x2ca3311f , 0x8868c737 , 0x874dd34e , 0x8c2a71ef , 0x7e9212e7 , 0x7df56eba , 0x00bfe6ef ] )
+  , Mont::unsafe_make( [ 0x0fad28fe , 0x48fc9643 , 0x226cee77 , 0x1f57f9ad , 0x3e2a10dc , 0xbf6e0a22 , 0x0b199924 , 0x1c9242b3 ] )
+  , Mont::unsafe_make( [ 0x989335fe , 0x55870abf , 0x0dfc5c6e , 0x289a2b50 , 0xcc2e0014 , 0x5f836575 , 0xf073975c , 0x071b78a8 ] )
+  , Mont::unsafe_make( [ 0xd6225fbd , 0x20310d65 , 0xd3dbe3a7 , 0x54eb9eb2 , 0x17188ffc , 0x5a6ab0e3 , 0xa4a2ea15 , 0x29c989d2 ] )
+  , Mont::unsafe_make( [ 0xace8da95 , 0x077670b9 , 0x0c743704 , 0xc45aa0b6 , 0x11092eae , 0x230dcd52 , 0xc8aae278 , 0x20ff520a ] )
+  , Mont::unsafe_make( [ 0x1f61b496 , 0x0ad4d638 , 0x5a47d657 , 0xa3deb3a4 , 0xe0a1acf6 , 0x924614ec , 0xb08be787 , 0x082c0b67 ] )
+  , Mont::unsafe_make( [ 0x77992fce , 0x0bf1fa1a , 0xba74de5f , 0xd371a2c4 , 0xba9241a9 , 0x84c0501c , 0xb0ba74bf , 0x2598be4c ] )
+  , Mont::unsafe_make( [ 0x09cf8830 , 0x296fabb7 , 0x3a370d42 , 0x521bd8d6 , 0xea194ada , 0x3fadfe60 , 0xe9d13b28 , 0x273730d8 ] )
+  , Mont::unsafe_make( [ 0x6d90b4ce , 0xe6614a3e , 0x5113f396 , 0x5200488a , 0x224eea8f , 0xdb53de6d , 0x6b0d68bb , 0x2b291d66 ] )
+  , Mont::unsafe_make( [ 0xdcec9cbc , 0x1d84e1c1 , 0xec49c2ff , 0x84ad8d50 , 0x588d1c1a , 0x740457e2 , 0xe8bb25a6 , 0x21d36c3d ] )
+  , Mont::unsafe_make( [ 0x84c23384 , 0x5d51a77a , 0xaee97017 , 0x0bbdf9a5 , 0x8459fc6f , 0xa0778306 , 0xc3ae27db , 0x0f850c38 ] )
+  , Mont::unsafe_make( [ 0xfbda1240 , 0xcb6bb361 , 0x1de280b0 , 0xb357cea6 , 0x8160fa1f , 0xa9acc2ac , 0x2a8e1288 , 0x06ffa65e ] )
+  , Mont::unsafe_make( [ 0xf74b1804 , 0x90e87108 , 0xa0e65001 , 0xa68439af , 0xb00daddb , 0xa77db3bc , 0x70c0c5d1 , 0x110f5d7e ] )
+  , Mont::unsafe_make( [ 0x02828740 , 0x625786f9 , 0x1377b4b2 , 0xf23f9c47 , 0xfd092950 , 0xd987d1bd , 0x7122c677 , 0x230c28f0 ] )
+  , Mont::unsafe_make( [ 0xd60465fb , 0xc0779e21 , 0x798cf0ed , 0x302dea3e , 0x84f8b5f3 , 0x28393603 , 0xbe655192 , 0x0b9eda7f ] )
+  , Mont::unsafe_make( [ 0x7181820f , 0x5994fc6b , 0xb3417cea , 0xc41e2e0b , 0x16ea5cdd , 0x861094e4 , 0xb8a120f4 , 0x054538aa ] )
+  , Mont::unsafe_make( [ 0x7610ec9d , 0xdf88dd81 , 0xffc42c25 , 0x9e6b0cba , 0x6a661ad4 , 0x6c10e756 , 0x66f47042 , 0x11cadedb ] )
+  , Mont::unsafe_make( [ 0x8c4b4ef9 , 0x829a533d , 0x03fab4b1 , 0x029c2aea , 0x6546b852 , 0xd317ff7f , 0x10560fe9 , 0x138ada9d ] )
+  , Mont::unsafe_make( [ 0xd875fb22 , 0x372f2323 , 0x92812c17 , 0xb4ef2c39 , 0x89d9be07 , 0x871ba831 , 0x2c2e6979 , 0x24588e72 ] )
+  , Mont::unsafe_make( [ 0x01bf2c50 , 0x00bc7b5d , 0xf0cc2e81 , 0xc86b7f68 , 0x17b3cb53 , 0xca25bf44 , 0x8071c26e , 0x1b11d441 ] )
+  , Mont::unsafe_make( [ 0x48be060d , 0xb83b552c , 0x4da5fe06 , 0x2e718fca , 0x79766130 , 0x68415b34 , 0x37f02a28 , 0x2189bc24 ] )
+  , Mont::unsafe_make( [ 0x5c96863a , 0xdf63b236 , 0xcb7bff29 , 0xce3cb53d , 0x834ed903 , 0x3a3ac1eb , 0xc3e290d6 , 0x28451bdf ] )
+  , Mont::unsafe_make( [ 0xbc3815e8 , 0xc49850d4 , 0xb1935a0f , 0xd5599594 , 0x035a56b8 , 0x61359db3 , 0x0e9d27fa , 0x05509f7c ] )
+  , Mont::unsafe_make( [ 0x2eaed9fc , 0xb96da9f3 , 0xa021891f , 0x5a0b00ac , 0xd1f5c38f , 0xa97071bf , 0xaec0cf4d , 0x14079885 ] )
+  , Mont::unsafe_make( [ 0x5e701ac5 , 0x25dff87e , 0xefb95a87 , 0x530ac173 , 0x614e2da1 , 0xfc42aee5 , 0x874d220b , 0x0530451b ] )
+  , Mont::unsafe_make( [ 0x3b7d8f46 , 0x8bb19281 , 0xf61d3aed , 0xb787c384 , 0xe2aec775 , 0x30a0ec55 , 0xd41e2c1c , 0x0d15d245 ] )
+  , Mont::unsafe_make( [ 0x20407978 , 0x73c50cf7 , 0xfa48429a , 0x4ee1f71c , 0xb06c1e32 , 0x72c1d149 , 0x998301f6 , 0x2971e89f ] )
+  ];
+
+//------------------------------------------------------------------------------
+
@@ -0,0 +1,1814 @@
+
+// circomlib's Poseidon constants for t=11
+
+use crate::bn254::montgomery::*;
+
+
+//------------------------------------------------------------------------------
+
+// round constants (t for external, 1 for internal rounds; flattened)
+pub const CONST_C: [Mont; 154] = 
+  [ Mont::unsafe_make( [ 0xdda2f521 , 0xcf05a300 , 0xfb138550 , 0xb189a788 , 0xf7027cca , 0x0019e7e8 , 0x98f0df22 , 0x18040259 ] )
+  , Mont::unsafe_make( [ 0xf312d760 , 0x18a6f413 , 0x43c57c05 , 0xe60f98bb , 0xedf49583 , 0x2db57f0c , 0x0adcc8b3 , 0x2ca9e8a9 ] )
+  , Mont::unsafe_make( [ 0x07ea9479 , 0x97a418c2 , 0x23689e9c , 0x1b7ad3fc , 0x6d290f79 , 0xf17a4f3a , 0x199e5633 , 0x2fcc7cf6 ] )
+  , Mont::unsafe_make( [ 0xae212a24 , 0x71774852 , 0x68f952a4 , 0x4a790881 , 0x7bd0fd2f , 0xa8f809f9 , 0x343e0a68 , 0x18f23c94 ] )
+  , Mont::unsafe_make( [ 0x6b2579ec , 0x04750f1c , 0x30de7042 , 0xae948502 , 0x600294be , 0x5c82f337 , 0x605a080a , 0x2155d02c ] )
+  , Mont::unsafe_make( [ 0xf41a7422 , 0x96565b2d , 0x8c2abbea , 0xa45d198e , 0x53de2cec , 0x845f9b11 , 0xf64f2dea , 0x0d1e3da6 ] )
+  , Mont::unsafe_make( [ 0x03828444 , 0x8f3fc337 , 0xcb1e7ed9 , 0x115f8983 , 0x08889f6e , 0x16d2e3d7 , 0x16fbd82e , 0x2ec1cb2c ] )
+  , Mont::unsafe_make( [ 0x9c6f0fdb , 0x0bdc2638 , 0xcf5713f8 , 0x3a72d4f1 , 0xf552b2df , 0x96912237 , 0x192c61d7 , 0x2799840d ] )
+  , Mont::unsafe_make( [ 0xe8d4de2a , 0xaf9e89d9 , 0x08ad98c8 , 0x21739740 , 0x397b677a , 0x92043bb4 , 0x05e970b6 , 0x00cf4adb ] )
+  , Mont::unsafe_make( [ 0x02972d1d , 0x1beb6e1f , 0x2fcb97a6 , 0xb0bb1a1f , 0x3d480280 , 0x155b3b70 , 0xd886c54f , 0x0e83f923 ] )
+  , Mont::unsafe_make( [ 0x2ac6cd5d , 0x842bfd0e , 0x4477b0e5 , 0xf33de353 , 0x7946219e , 0xface3a0d , 0x99712afa , 0x1a7e2930 ] )
+  , Mont::unsafe_make( [ 0x56d4e0a4 , 0x5d5d44d8 , 0x8ca74dcb , 0x415f45d1 , 0xdf1b343d , 0x42beaf34 , 0x4fb1628f , 0x0292bea7 ] )
+  , Mont::unsafe_make( [ 0x63e808ba , 0x9d3816a9 , 0x016f2bbe , 0x67afe8af , 0x12fd9dda , 0x373ae6d0 , 0x2cbfeb20 , 0x298b9994 ] )
+  , Mont::unsafe_make( [ 0xf7e05dd5 , 0xcd0e90aa , 0xa33db54d , 0x1a1aab4b , 0xc5cee1e1 , 0x37459cef , 0x891cfb47 , 0x00a46a57 ] )
+  , Mont::unsafe_make( [ 0x12a15ec4 , 0xd8815708 , 0x8067206b , 0x062c04ea , 0xc4fd21d0 , 0xdc200e0d , 0xa6fe35b3 , 0x0e6a0edb ] )
+  , Mont::unsafe_make( [ 0x0e627c31 , 0x73858aab , 0x0aae367c , 0x6d432f03 , 0x7018aba3 , 0xa7c061f3 , 0x182849cd , 0x26b6c680 ] )
+  , Mont::unsafe_make( [ 0x3cbf2be1 , 0x1a06b0b9 , 0x4776c5f6 , 0x4c3a15e3 , 0x711a950b , 0xd4a7bf7f , 0xfb7c282e , 0x02d6e17c ] )
+  , Mont::unsafe_make( [ 0xc102f9da , 0xdace408b , 0x963abe9b , 0x8f61a7d2 , 0x97081e10 , 0x609a2a75 , 0x9212ffd9 , 0x2639dea3 ] )
+  , Mont::unsafe_make( [ 0x327dcce3 , 0xbd1f19af , 0x477f11d7 , 0x7800e247 , 0x4c837131 , 0x8e0c3b1b , 0xb0452a77 , 0x1f88f966 ] )
+  , Mont::unsafe_make( [ 0x50bc2abb , 0x7964b95b , 0x75ab0436 , 0x083b6dd9 , 0x39198271 , 0xe136974d , 0x77fcc3e4 , 0x06f4f423 ] )
+  , Mont::unsafe_make( [ 0xc9b51ca0 , 0xbe0ab5b3 , 0xaefba267 , 0xa3630666 , 0xd02ca3e0 , 0x2a54a303 , 0x9ab4b5bf , 0x1fe57ae2 ] )
+  , Mont::unsafe_make( [ 0xb03d5d94 , 0x30dfe0f1 , 0x4603f764 , 0xd088a287 , 0x8eca1c50 , 0xc2d35348 , 0xcce8ee04 , 0x1ed6a496 ] )
+  , Mont::unsafe_make( [ 0x9f254fc1 , 0x8123f733 , 0x3abf88d4 , 0x7cde4934 , 0xa14e6e39 , 0x007651fa , 0x141309eb , 0x20fd4497 ] )
+  , Mont::unsafe_make( [ 0xf83f2b24 , 0xeb5ae583 , 0x7a428261 , 0x1e4a7fbd , 0xcb82e0c1 , 0x6d27bb29 , 0xf48acb55 , 0x105c59f9 ] )
+  , Mont::unsafe_make( [ 0x20578ed1 , 0x29d60c90 , 0x4a4164db , 0xdfacae2f , 0x667c20ae , 0xd46c4b57 , 0xe4f8bfb2 , 0x084608ff ] )
+  , Mont::unsafe_make( [ 0x3574ce61 , 0xb5cd5893 , 0x2df70a6d , 0x4ca3decf , 0x27f997e6 , 0xde8b05fd , 0x2c148c64 , 0x02b6aa30 ] )
+  , Mont::unsafe_make( [ 0x4893af82 , 0x4362e744 , 0xc57de9c1 , 0xe9f9bdf3 , 0x5f67f0ed , 0xa82d2d2a , 0x97a6957e , 0x16bda459 ] )
+  , Mont::unsafe_make( [ 0xf0b5eb34 , 0x9777b205 , 0x2cbb9d33 , 0x11a139db , 0xd2764f79 , 0x8f1504b8 , 0xc62752e5 , 0x1e6482f0 ] )
+  , Mont::unsafe_make( [ 0xc505ee1c , 0x1e242b82 , 0x78b159b1 , 0x7465549a , 0x47417306 , 0xe8bf1d49 , 0x5c02bd3f , 0x065b909e ] )
+  , Mont::unsafe_make( [ 0x89808b3d , 0x32f19131 , 0x937c7afa , 0x880d7d37 , 0x226e7ca5 , 0xd8bce84b , 0x373b2384 , 0x15813860 ] )
+  , Mont::unsafe_make( [ 0xdba541cd , 0x3f51f408 , 0xf62a1cf5 , 0x4792bad0 , 0x9ee2e987 , 0xbf93ba6b , 0x829c1bae , 0x0ce8509a ] )
+  , Mont::unsafe_make( [ 0x7a7dc68e , 0x0f1bc63c , 0xfd6f7526 , 0x9238a61d , 0x9b2db301 , 0x8e9b9bce , 0xc74b32d0 , 0x189cefb5 ] )
+  , Mont::unsafe_make( [ 0x663c1313 , 0xe26e453e , 0xd88cdfc9 , 0x983a7667 , 0x51f5f930 , 0x20a6edff , 0x1afabb73 , 0x1374af33 ] )
+  , Mont::unsafe_make( [ 0x338f2d3d , 0x054d863d , 0xb40b82a5 , 0x33162f11 , 0xeb7d5753 , 0x47151c8d , 0x7029493a , 0x023f4805 ] )
+  , Mont::unsafe_make( [ 0xec7302e7 , 0xac9a22a1 , 0xc70e0de3 , 0x3e654e1d , 0xb33a7082 , 0x8ad6b20d , 0xe5acb499 , 0x01e8e59e ] )
+  , Mont::unsafe_make( [ 0xde18ebc3 , 0x35b302e3 , 0x457c23a3 , 0x0718e150 , 0x2a12e841 , 0xdc7936d8 , 0x325f8c9d , 0x21edb9e4 ] )
+  , Mont::unsafe_make( [ 0x6bc4071e , 0xa559cf0b , 0xa0d9c867 , 0x60b94ced , 0x0cdd94b3 , 0x6ed6e206 , 0x533890a9 , 0x1fafcf6e ] )
+  , Mont::unsafe_make( [ 0xf15a0858 , 0x0dbe5f37 , 0xd389c854 , 0x509e13fb , 0x12bcc8e6 , 0x21dcfe0f , 0xbfb1a063 , 0x114c574f ] )
+  , Mont::unsafe_make( [ 0xd0db26fa , 0x477ca947 , 0xce54a3c1 , 0xcbe1b9dd , 0x1b7c9ef5 , 0x8a54b80e , 0x971e95a0 , 0x20d77152 ] )
+  , Mont::unsafe_make( [ 0xa63c36d3 , 0xcb7321f8 , 0xe076da7e , 0xb981f935 , 0xee360329 , 0x9703d693 , 0xb5292b3f , 0x13f52b60 ] )
+  , Mont::unsafe_make( [ 0xbaa21651 , 0x0b296b56 , 0x164f7a5e , 0xb4c621aa , 0xd3112d51 , 0xabab7618 , 0x17da0264 , 0x218976b4 ] )
+  , Mont::unsafe_make( [ 0xddd0d30d , 0xcb4e2f73 , 0x0c07dc37 , 0xc5da500c , 0xd9b86116 , 0x2ee2a13c , 0x0cdb40c9 , 0x2070f8e7 ] )
+  , Mont::unsafe_make( [ 0xa23a01c1 , 0x36925c94 , 0x75b3ef93 , 0x1a526445 , 0xecb906e5 , 0x3ce59f4f , 0x63e73517 , 0x2f755a46 ] )
+  , Mont::unsafe_make( [ 0xb2bc5c62 , 0xc2c2ecd0 , 0x1cdd765e , 0xb3664ae5 , 0x770d38e2 , 0xa70ef168 , 0x65d1202e , 0x125b6eb6 ] )
+  , Mont::unsafe_make( [ 0xf9b2f851 , 0x0f4826c8 , 0x142cad64 , 0x4a371fd1 , 0xa3c5269b , 0x563fba84 , 0xb302d99f , 0x0b62ed1f ] )
+  , Mont::unsafe_make( [ 0x15f4c2e4 , 0xa8559532 , 0xe37cf71a , 0xf078fe14 , 0x92c5ba53 , 0x3d504cdb , 0xdfc5cd0c , 0x08a3faf9 ] )
+  , Mont::unsafe_make( [ 0xec5ea6f3 , 0xe86c2953 , 0x55ebd155 , 0xc9370fc1 , 0xc79b7667 , 0xa3b60f83 , 0x37e2bee5 , 0x0f7828cf ] )
+  , Mont::unsafe_make( [ 0xbdf606a8 , 0x7bef34e7 , 0x6185f8b1 , 0xc908db4b , 0x13d3bd6b , 0x5c8cf91e , 0x0da6ff7e , 0x15d587df ] )
+  , Mont::unsafe_make( [ 0x93486c70 , 0x1fa13047 , 0x099bcd8e , 0xa450793b , 0xafecea15 , 0xfb3a135b , 0x9e926b2f , 0x0f573ef8 ] )
+  , Mont::unsafe_make( [ 0x9b8cd842 , 0x790c636b , 0x71741fc5 , 0x980a576c , 0xa68c72ce , 0xa065d1b1 , 0x5acf0c5e , 0x1138bc41 ] )
+  , Mont::unsafe_make( [ 0x5efdeb58 , 0x80847dc1 , 0x43d50dc5 , 0xeb2749ad , 0x3859a5b5 , 0xb5cdb328 , 0x88c9d94d , 0x076ab1b4 ] )
+  , Mont::unsafe_make( [ 0xddc53741 , 0xb2f700bf , 0x36042d34 , 0x33f254af , 0x8605506b , 0x67b5a14a , 0xda9b5815 , 0x227cfbcb ] )
+  , Mont::unsafe_make( [ 0xf803be99 , 0xe8e3e737 , 0x7fa094ae , 0xb1015257 , 0xae81d937 , 0xd485306a , 0xe7189c3b , 0x218a5863 ] )
+  , Mont::unsafe_make( [ 0x2dc10d0e , 0x36593dc4 , 0x334bb10c , 0xc97f7766 , 0x5a1979d7 , 0x84d79e44 , 0x802e70f8 , 0x2920275f ] )
+  , Mont::unsafe_make( [ 0xae521809 , 0xd6655eb9 , 0x1e31b8d3 , 0x91041d3a , 0xa4c3c3fe , 0xdfd2abdb , 0xa58f1428 , 0x09fdbc2d ] )
+  , Mont::unsafe_make( [ 0x3f683c30 , 0x4a6265ba , 0x3fc11aa1 , 0xac3c30fb , 0xe1d11d94 , 0xc1c18858 , 0xbfe0e60a , 0x161630f4 ] )
+  , Mont::unsafe_make( [ 0x79e11ad2 , 0xfd101d38 , 0x2c94989a , 0x882026f5 , 0x7de99769 , 0xe1a96e16 , 0x57a95fb3 , 0x28195626 ] )
+  , Mont::unsafe_make( [ 0xadacd8eb , 0xa22b03ac , 0xdb5d8414 , 0x264bf567 , 0xb97f3161 , 0xf8c363eb , 0xf4b303c2 , 0x1305a658 ] )
+  , Mont::unsafe_make( [ 0x2514b1cd , 0xec08895f , 0xf1c3c2be , 0xc71ab5c3 , 0x56683579 , 0xa8ae66e6 , 0xd87853a8 , 0x2ac06045 ] )
+  , Mont::unsafe_make( [ 0xe454ab19 , 0xfeb23899 , 0x6510da97 , 0xf7b1dea8 , 0xce42e143 , 0xace63ebd , 0x8a958655 , 0x21be8361 ] )
+  , Mont::unsafe_make( [ 0x8da94e0b , 0xb22eb269 , 0xafc48681 , 0x972ef148 , 0x92de5d7e , 0x7f40af4e , 0xd7dca997 , 0x0b2df5b3 ] )
+  , Mont::unsafe_make( [ 0xc8cc2696 , 0xe75f2f67 , 0x63efa12d , 0x942ef68a , 0xba3f3bbb , 0xbeb02ca4 , 0x0ff92eea , 0x1fc78dd5 ] )
+  , Mont::unsafe_make( [ 0x15d85b21 , 0x0eb2f8d8 , 0xf9f9595f , 0xa642c333 , 0xbbc1deac , 0x82fbef59 , 0x5fe4cc66 , 0x125d37c3 ] )
+  , Mont::unsafe_make( [ 0x46dbb73b , 0x5bf291e7 , 0x5ef1654d , 0x668eb562 , 0x73828913 , 0x4bee351c , 0xc4acd676 , 0x275b4a17 ] )
+  , Mont::unsafe_make( [ 0x0e017e27 , 0xdd561248 , 0x11e2b28e , 0xc123850b , 0x118d59a5 , 0xd6f1b6e9 , 0xc026fe75 , 0x2007bbbc ] )
+  , Mont::unsafe_make( [ 0x656b7e2c , 0x8d0be708 , 0x7f42a87d , 0xeefefca8 , 0x3bd2a918 , 0xb00cf71b , 0x2f49f998 , 0x0b0a2559 ] )
+  , Mont::unsafe_make( [ 0x978748b6 , 0x931535c3 , 0x5cb6edaa , 0xd01be8c6 , 0x60456a81 , 0xc2cab00e , 0x60a9734e , 0x037f32e6 ] )
+  , Mont::unsafe_make( [ 0x48cdd37b , 0x587b1748 , 0xcb0e4a26 , 0xcddf4f62 , 0xf1088e1c , 0x7dd19634 , 0x6b99653d , 0x0ddeb5aa ] )
+  , Mont::unsafe_make( [ 0x0c63d8c8 , 0xae6ee431 , 0xcf43db2d , 0xf731aa14 , 0x70b54070 , 0xbad891b0 , 0xc869b85b , 0x2a3d5ef9 ] )
+  , Mont::unsafe_make( [ 0x9530495c , 0x76af5d3e , 0x9a37244a , 0xdf78df08 , 0x7cad6f6f , 0x7d02b56e , 0xda57e750 , 0x03cb448f ] )
+  , Mont::unsafe_make( [ 0x05c1373b , 0xdc37707c , 0xfb2ce29d , 0xe04365e4 , 0x46559d92 , 0x692932d2 , 0xd4e279f6 , 0x077fea47 ] )
+  , Mont::unsafe_make( [ 0x07584f1c , 0x0395b50b , 0xf2673378 , 0x775ae786 , 0xa383682c , 0xfa565847 , 0x393861bd , 0x1176a216 ] )
+  , Mont::unsafe_make( [ 0x73d7bb17 , 0x91dd125d , 0x686a8133 , 0xfe2d9aed , 0x5a758c5d , 0x6235f196 , 0x2b795dc3 , 0x1eb4fca1 ] )
+  , Mont::unsafe_make( [ 0x534c50ba , 0x9aee913b , 0xe4f66f32 , 0x8eb23655 , 0xf93b0e66 , 0x879c8be5 , 0xb0536553 , 0x0c6e5611 ] )
+  , Mont::unsafe_make( [ 0x8dd5ccbd , 0xc9a240cb , 0xfaa78b68 , 0xeac5771a , 0x6036a96d , 0xa6dd2b35 , 0xa75b87d6 , 0x21caf1f6 ] )
+  , Mont::unsafe_make( [ 0x0b78f633 , 0xd3ed8f6b , 0xb13d65ff , 0x76da55dd , 0x339c4cbc , 0x767a58ea , 0x713be0c8 , 0x2e683456 ] )
+  , Mont::unsafe_make( [ 0x330e77d3 , 0x82698280 , 0x4213d380 , 0x1185d5e2 , 0x8a4eec9b , 0xedb6260d , 0xf9835ffa , 0x1dde6319 ] )
+  , Mont::unsafe_make( [ 0x99ae7bda , 0x7ee0c145 , 0x9c71266b , 0x9bfca4db , 0x8002e7b0 , 0x21c1168c , 0x92a8a8f6 , 0x1521e8c1 ] )
+  , Mont::unsafe_make( [ 0x48cc2940 , 0x7dea6e78 , 0x82f49b23 , 0xec071413 , 0xff83fe4c , 0x09c7d750 , 0x40ed9c05 , 0x2720cce3 ] )
+  , Mont::unsafe_make( [ 0xfdba351d , 0x32471935 , 0x5243552e , 0x04d9f336 , 0x00feaca8 , 0x19f50b72 , 0xcace8389 , 0x233c688c ] )
+  , Mont::unsafe_make( [ 0x3f2fe5ad , 0x656c985e , 0xb73877d0 , 0x00a21a4f , 0x18b16fcc , 0x30534b61 , 0xc1b6a1c6 , 0x2971be48 ] )
+  , Mont::unsafe_make( [ 0xdd15f002 , 0x6bd649a3 , 0xbc816a6d , 0xe27af75b , 0xc3261f1f , 0x9c3fc5e0 , 0x82e6ef68 , 0x134c7345 ] )
+  , Mont::unsafe_make( [ 0x1c9a5920 , 0x32f63cf2 , 0xf0e1a936 , 0xbcbb6c96 , 0x28fe318b , 0x1963ed6a , 0xe26661b5 , 0x15401e65 ] )
+  , Mont::unsafe_make( [ 0x8b1c3e3a , 0x2074e5e7 , 0xa4a018bf , 0x79793722 , 0xba087e6d , 0xe5574eae , 0x91cb8d09 , 0x0ebd94d9 ] )
+  , Mont::unsafe_make( [ 0x3e18b4dd , 0xbaf14944 , 0x0db2f24d , 0x4cc52aff , 0xb836026e , 0xa0428bef , 0x9d808570 , 0x14f84e95 ] )
+  , Mont::unsafe_make( [ 0xaee6bf31 , 0xb460f8ec , 0x2c266e32 , 0x91e65da8 , 0x7da8822b , 0xac000b6c , 0xbdc3cde7 , 0x110627e9 ] )
+  , Mont::unsafe_make( [ 0x8dbcee99 , 0xb20a189a , 0x23ab145b , 0x79f1e495 , 0x52976503 , 0x3d011263 , 0x6b7283ee , 0x26956756 ] )
+  , Mont::unsafe_make( [ 0xbcf3a59d , 0x061996be , 0x6a9050f8 , 0x7a853f79 , 0x419695df , 0x74e57ee3 , 0x308c47a5 , 0x2b044515 ] )
+  , Mont::unsafe_make( [ 0xe6a6095e , 0x027df96a , 0x30b21edd , 0xd765a28e , 0xc7ca13c6 , 0xf2ae5606 , 0x4191fd24 , 0x2cf520fd ] )
+  , Mont::unsafe_make( [ 0x9040d222 , 0xf305af77 , 0xdae97a84 , 0xbb52b852 , 0xa024f2c0 , 0xf9d90dcb , 0x3cf7bc48 , 0x0b70b2f6 ] )
+  , Mont::unsafe_make( [ 0xa6e2d10d , 0x2847a849 , 0x99587e66 , 0x02fa1c96 , 0x734b875c , 0x94002850 , 0x0704b604 , 0x17dbc091 ] )
+  , Mont::unsafe_make( [ 0x7218cd0f , 0x3dbcacea , 0xc94232ef , 0x4f372775 , 0x3d584f60 , 0x3b4a96d6 , 0xa23ebdab , 0x02594205 ] )
+  , Mont::unsafe_make( [ 0xe0a50200 , 0xa6a48497 , 0x32d112e1 , 0xdaeaad88 , 0xe870ed62 , 0xd8794ae7 , 0xfa114c35 , 0x0aa1463a ] )
+  , Mont::unsafe_make( [ 0xd541564e , 0x5112fb6c , 0x3f9a7dc6 , 0xa8550a29 , 0x45039fd4 , 0x12a2368f , 0x11b9f2c9 , 0x1d45fe13 ] )
+  , Mont::unsafe_make( [ 0x146c9412 , 0xd4bb91c1 , 0x1bde24b0 , 0xaa825530 , 0xf27d00c5 , 0x7dd32c6b , 0x90213e7e , 0x1ecc3eda ] )
+  , Mont::unsafe_make( [ 0x70354067 , 0x34c83dcf , 0xcd4f01fd , 0x45e8d95e , 0xce9dbfb1 , 0x9dbeba95 , 0xe77a9d95 , 0x01194ea6 ] )
+  , Mont::unsafe_make( [ 0xd6970d88 , 0x6df06a6e , 0x1244cc9f , 0x13a173c5 , 0x957ee192 , 0x770abff9 , 0xdec521b7 , 0x21dbbfbc ] )
+  , Mont::unsafe_make( [ 0x9e187bfd , 0x7e995e2d , 0x9fd8942d , 0xa4aa9d63 , 0x5bb42d20 , 0xf1de1ba6 , 0xc93c8092 , 0x00f6ae2d ] )
+  , Mont::unsafe_make( [ 0x11969731 , 0x7a1b3337 , 0xca53e580 , 0xb0706c28 , 0x9d509129 , 0x07b6e6e6 , 0xcb3b7ff0 , 0x111c4c40 ] )
+  , Mont::unsafe_make( [ 0x156762f4 , 0x6ed0fac8 , 0x448c7c97 , 0x175d5599 , 0x78903a2f , 0x4dcf4aa8 , 0xebd8969a , 0x0af16113 ] )
+  , Mont::unsafe_make( [ 0x76cb313d , 0x3b667971 , 0xd0eff59f , 0xac3f493b , 0xed07a8f8 , 0x4b1577fb , 0x6abb5029 , 0x0fd31ce4 ] )
+  , Mont::unsafe_make( [ 0x086b1c6e , 0x755081a7 , 0x71bf15e3 , 0x325ecf29 , 0x6dfabe9a , 0x39e04fb3 , 0x5dcbe193 , 0x2cbf3395 ] )
+  , Mont::unsafe_make( [ 0x0cd8fdec , 0x9dd6d0af , 0x607319c0 , 0x702e7191 , 0xb723eb9f , 0x01d43d4b , 0x9b2c1fd9 , 0x2f09eabe ] )
+  , Mont::unsafe_make( [ 0xb18a7eab , 0x306fa9fc , 0x8756660a , 0x73b1cf97 , 0x47287d82 , 0xdd0653d4 , 0x602d0117 , 0x0d737091 ] )
+  , Mont::unsafe_make( [ 0x23a534e3 , 0x11f3c522 , 0x73533276 , 0x3e20e4d2 , 0x93807493 , 0x83790185 , 0xc22e0ba9 , 0x09e5e8de ] )
+  , Mont::unsafe_make( [ 0x86bcc4f2 , 0xb296b73c , 0x07b7f249 , 0x2d144327 , 0xc85e1d46 , 0x3ff3d9f4 , 0x48d81355 , 0x23bdcfa1 ] )
+  , Mont::unsafe_make( [ 0x964f6fad , 0xc61095dc , 0xeb737176 , 0xe1fed246 , 0x47a61609 , 0xed7c4630 , 0xed43edb2 , 0x06db16d7 ] )
+  , Mont::unsafe_make( [ 0x755ba49e , 0x52c80813 , 0x238b735b , 0x94d6f288 , 0xa8253872 , 0x95c3ee22 , 0xb4e0b1a6 , 0x2515965b ] )
+  , Mont::unsafe_make( [ 0x210593e4 , 0x66e7666f , 0x71d74d73 , 0x6aeca256 , 0x58676ad7 , 0x252ca0e4 , 0x9aae3a56 , 0x278ba3e0 ] )
+  , Mont::unsafe_make( [ 0x7cba7579 , 0xf3a37f80 , 0x9f2c6f4a , 0x01512cc5 , 0x823315e1 , 0x12bb802c , 0x68556f0b , 0x080434d7 ] )
+  , Mont::unsafe_make( [ 0x06990f32 , 0xc01820eb , 0xea56aec4 , 0xdf4c9b8d , 0xef2d153a , 0xb239c17f , 0x2f39cb7b , 0x2779b755 ] )
+  , Mont::unsafe_make( [ 0xdf43429b , 0x0b6f4317 , 0x1cf3809d , 0x7fb0231f , 0x2b9e88f0 , 0x5c503fde , 0x4f318d4b , 0x1e1ac4cd ] )
+  , Mont::unsafe_make( [ 0x1c86df42 , 0x34817d78 , 0xf337a64d , 0x9623fe5a , 0x0e60bda4 , 0xce71d9ad , 0x468fc52a , 0x2f49c054 ] )
+  , Mont::unsafe_make( [ 0xb944cbb3 , 0x6769966e , 0xdec2f19f , 0xbf938bae , 0xf400d825 , 0xb38a5314 , 0x53eab905 , 0x104c28ff ] )
+  , Mont::unsafe_make( [ 0xfdce7ced , 0x050cb70e , 0xc2d1ea51 , 0x2c4affaa , 0x11c34945 , 0x83940ab4 , 0x0bbf1924 , 0x23c78e2b ] )
+  , Mont::unsafe_make( [ 0x0e2a7215 , 0x87118cae , 0x765820b8 , 0x091c59f5 , 0xa69222fb , 0x6f6cb2d7 , 0xac82522e , 0x0f0c05e0 ] )
+  , Mont::unsafe_make( [ 0x571bea95 , 0xfa8b72ef , 0x67125401 , 0x6a1fc91f , 0x5f9bdfb6 , 0xf1973ff3 , 0x226184d1 , 0x1560f9c3 ] )
+  , Mont::unsafe_make( [ 0xf2bdadf4 , 0xf5c3726d , 0x016d12b7 , 0xb21f71d7 , 0x3d59e0e5 , 0xf0a6e076 , 0xa164c1cc , 0x07515160 ] )
+  , Mont::unsafe_make( [ 0x1a198d03 , 0xcab94271 , 0x66a3bfd1 , 0x20bcbe55 , 0xa01f4b7e , 0xd3a04387 , 0x7b9fe55d , 0x2f97fd14 ] )
+  , Mont::unsafe_make( [ 0x71a82593 , 0xc2577fb9 , 0x23cdb666 , 0xe4b9f1da , 0x95d5f4d7 , 0x81fa3241 , 0x7a8aeac1 , 0x07954003 ] )
+  , Mont::unsafe_make( [ 0x8de592f7 , 0x9f6ec126 , 0xd5cf9445 , 0x38d53382 , 0x3c5ca25d , 0x2c4bb056 , 0x8504ab79 , 0x1c175c39 ] )
+  , Mont::unsafe_make( [ 0x2f5a1061 , 0x2269f6e6 , 0x9208e3bb , 0x0038b0c9 , 0x75c8f17d , 0xffe5e393 , 0xe29b311b , 0x1dfd154b ] )
+  , Mont::unsafe_make( [ 0x841d5182 , 0xa1b4508b , 0x786314dc , 0x87c044d9 , 0x07277af4 , 0x949e94a5 , 0x1eea8902 , 0x11206406 ] )
+  , Mont::unsafe_make( [ 0x61a488dd , 0x3ad0dbd1 , 0x86b8114f , 0x07be97ec , 0x2e72234c , 0x3dd75644 , 0x69ca6fb8 , 0x1f5f5e3b ] )
+  , Mont::unsafe_make( [ 0x66a09e77 , 0x0d019c58 , 0x44881d39 , 0xd249a724 , 0x0a2d2cf8 , 0x0e151298 , 0x4010cb08 , 0x0f2458c6 ] )
+  , Mont::unsafe_make( [ 0x8e5764f1 , 0x76d6648d , 0xfab727be , 0xb07758a9 , 0x742836b8 , 0x4f3f4b32 , 0x80c4f0f8 , 0x2606ee2d ] )
+  , Mont::unsafe_make( [ 0x153c0d03 , 0xd134ef55 , 0xa0f5c777 , 0xcdd94e69 , 0x9af6a501 , 0xfaf5c928 , 0x00471166 , 0x048bff32 ] )
+  , Mont::unsafe_make( [ 0x6f285b3b , 0xa5c7d2d3 , 0x9621ef70 , 0xd31ff767 , 0xa921f8e0 , 0xa985d71e , 0xaf3a88f9 , 0x1eb345bb ] )
+  , Mont::unsafe_make( [ 0x29dd0bf1 , 0xc2c00301 , 0xb1317017 , 0x82653f8c , 0xd3411fba , 0xa0f418a5 , 0x92be86c4 , 0x06973589 ] )
+  , Mont::unsafe_make( [ 0xa0142a1c , 0x6a2ee5ba , 0x7c602664 , 0x79624574 , 0x75ad873f , 0x3fcbde7c , 0xa482c33a , 0x06bf442b ] )
+  , Mont::unsafe_make( [ 0xb511c601 , 0xa2b0b2db , 0xacfe4e21 , 0xb55b1d7a , 0x08e01687 , 0x42eb0f86 , 0x2407b666 , 0x0dcfe5fe ] )
+  , Mont::unsafe_make( [ 0x86d60579 , 0x6a924d03 , 0xa39c9642 , 0xcb29cc16 , 0xf4837d62 , 0x14064e90 , 0xbc3c35df , 0x0990ab1e ] )
+  , Mont::unsafe_make( [ 0x3d3b85e5 , 0x02c061eb , 0x3be58547 , 0xb419f270 , 0x8943b405 , 0xaea989eb , 0xe578e754 , 0x2a66d0f2 ] )
+  , Mont::unsafe_make( [ 0xdcc85f1f , 0xf77de480 , 0xc77e5231 , 0xa3fd380d , 0x45bc1e43 , 0x941a2304 , 0xca672a06 , 0x2cc433ff ] )
+  , Mont::unsafe_make( [ 0x9c775e0d , 0xffea6369 , 0x594be817 , 0x31bb8da9 , 0x5b238506 , 0x65e62878 , 0x54991f71 , 0x10c52111 ] )
+  , Mont::unsafe_make( [ 0x514c199f , 0x1beca13d , 0x915617f7 , 0xeb268f7e , 0xafac8813 , 0x5345b69b , 0x10363fbf , 0x1e51a296 ] )
+  , Mont::unsafe_make( [ 0x1c26b818 , 0x6695170b , 0x16c39226 , 0xbe55956a , 0x1b5ee7fa , 0x1446e9fd , 0xdea5194c , 0x047fcea4 ] )
+  , Mont::unsafe_make( [ 0xc9c85f21 , 0x10025d22 , 0x7e6025a3 , 0xfb0895fb , 0x87632047 , 0xf5df1977 , 0x5e72ec67 , 0x1243ef36 ] )
+  , Mont::unsafe_make( [ 0xc4aa576a , 0xab135c60 , 0x65a55e23 , 0x1b8c3c0d , 0xac745f26 , 0x93065751 , 0xda7dd73a , 0x2723b584 ] )
+  , Mont::unsafe_make( [ 0x1661062f , 0x7cfc753e , 0x6588e0a1 , 0xfb6feb76 , 0xff75feae , 0x611dd8d9 , 0x770264c5 , 0x1857b00f ] )
+  , Mont::unsafe_make( [ 0xfdafcece , 0x64e43255 , 0xca8bfd17 , 0xedcb28be , 0xdf7edb98 , 0xd00981a8 , 0x80b7ff30 , 0x06c307a6 ] )
+  , Mont::unsafe_make( [ 0x8240a3a6 , 0x2aec31e0 , 0x987f1578 , 0x098a764a , 0x6d43498b , 0x79090c48 , 0x8815bbef , 0x1a620e38 ] )
+  , Mont::unsafe_make( [ 0x0e90a315 , 0x874268d4 , 0x57282760 , 0x8219291b , 0x7958662d , 0x83c0a22b , 0x102b8d0a , 0x0a2d2c3e ] )
+  , Mont::unsafe_make( [ 0x4ac11435 , 0xb2226521 , 0xf33daed5 , 0xadaf9514 , 0x21f441c0 , 0x4e59c498 , 0x0a943908 , 0x263b3609 ] )
+  , Mont::unsafe_make( [ 0x5dea8d6e , 0xe1840ec2 , 0x7bb8a45e , 0xcf3b41a9 , 0x505c5864 , 0x4842a9a0 , 0xb842b846 , 0x199f2fd1 ] )
+  , Mont::unsafe_make( [ 0x8cfd3222 , 0x6fbc953f , 0xe82f9593 , 0xe90a0459 , 0x2b2ecf97 , 0xb24ef5eb , 0x2fdc70b1 , 0x1bb4dc94 ] )
+  , Mont::unsafe_make( [ 0x97c665da , 0xb99fb2cf , 0x7877bec3 , 0x2578694f , 0xe3978abe , 0x07a28ec9 , 0x92167fee , 0x1393adeb ] )
+  , Mont::unsafe_make( [ 0x815b461b , 0xbd2a33de , 0xf5dc06c9 , 0x4bba3e10 , 0x8de1516b , 0x1141a3a1 , 0xfb9a6dda , 0x01227fc5 ] )
+  , Mont::unsafe_make( [ 0xb77a45b2 , 0xb49b7f77 , 0x03abdcfd , 0xbb0037f5 , 0xaab354c9 , 0x46b1e0d5 , 0x3dc1b938 , 0x21217db1 ] )
+  , Mont::unsafe_make( [ 0xbed5d67f , 0x6125fb94 , 0xa3511d91 , 0x7827fd7d , 0x246d9325 , 0x0c54c43e , 0xc83c4dbb , 0x06e3d487 ] )
+  , Mont::unsafe_make( [ 0x11947405 , 0x9cbf72ba , 0x002f8ad5 , 0x97bfd439 , 0x632e1643 , 0x546b2d55 , 0x8b848026 , 0x104865ff ] )
+  , Mont::unsafe_make( [ 0x630af1da , 0xb2971633 , 0xe534e929 , 0xd2d1b820 , 0xd650fb5f , 0xc82bb3d1 , 0x6b7945f0 , 0x149464d7 ] )
+  , Mont::unsafe_make( [ 0x4005e47d , 0x550bca38 , 0xb2b95e0f , 0x27b5ee39 , 0xc892ea83 , 0x4355c138 , 0xadfbd8a8 , 0x24009c9c ] )
+  , Mont::unsafe_make( [ 0x371e0bf0 , 0xba478fb1 , 0xd8699e1a , 0x0c7f5e9d , 0x33a65af0 , 0x14fdadf1 , 0xf38830e8 , 0x27cbcb4d ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// external mixing matrix (`t x t`, flattened)
+pub const CONST_M: [Mont; 121] = 
+  [ Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xf9f9751a , 0x2b1049b9 , 0x7b3f8231 , 0x76743e1c , 0x83aa4ccc , 0x9ee6897f , 0x7b6955f4 , 0x1ec889d0 ] )
+  , Mont::unsafe_make( [ 0x533acb09 , 0xc4690c6e , 0x828597ad , 0x9e0ec418 , 0x5fb63e9a , 0x216bd6ef , 0xea127695 , 0x0ce7d631 ] )
+  , Mont::unsafe_make( [ 0x7d5802ba , 0xc7cbb672 , 0x68c7e02b , 0xc3445334 , 0x22af1a34 , 0x53d25571 , 0x65f41c24 , 0x2b5dec52 ] )
+  , Mont::unsafe_make( [ 0x67d4802d , 0x49b36bfd , 0x6248034d , 0xb47bf01c , 0x739f5d08 , 0x572c4c45 , 0x7b663c57 , 0x0ce98f34 ] )
+  , Mont::unsafe_make( [ 0xc45f5da7 , 0x96a42e6e , 0xa4128910 , 0x2b0a142e , 0xd07cd271 , 0x17d58f95 , 0xaa2ded20 , 0x0a47d9bf ] )
+  , Mont::unsafe_make( [ 0x7a9cc845 , 0xbe3a07e2 , 0xe4848d50 , 0xc134408b , 0x5366daa2 , 0x0ea4f794 , 0xc519db4b , 0x301d90e4 ] )
+  , Mont::unsafe_make( [ 0x0443a904 , 0x20cdf3e7 , 0x1381f2c1 , 0x7b5e01f6 , 0x7cd24c45 , 0x5b159d1a , 0xbdc831d6 , 0x0c6b8614 ] )
+  , Mont::unsafe_make( [ 0x0d476290 , 0x8c812d16 , 0x1e3afee5 , 0x1c1d60d7 , 0x383055c4 , 0xa09b69f9 , 0x6dfd67e8 , 0x05fd1261 ] )
+  , Mont::unsafe_make( [ 0x37e222f9 , 0x347ad3bc , 0x6e4358ed , 0xa703b868 , 0xf7bc2938 , 0x5252d25c , 0x942169a4 , 0x09a5c063 ] )
+  , Mont::unsafe_make( [ 0x885e3181 , 0xfa0f7997 , 0x1937979e , 0x3696fdac , 0xce55f885 , 0xb8c35bb8 , 0x94fcfd4e , 0x13239e98 ] )
+  , Mont::unsafe_make( [ 0x07563283 , 0xfca676d2 , 0x89b71d25 , 0xac7f6f04 , 0x4851c9b2 , 0x1c124264 , 0x1465b569 , 0x2a23558b ] )
+  , Mont::unsafe_make( [ 0x49f547a8 , 0xf94d66a7 , 0x0937d3f7 , 0x875744fc , 0xb505e0c1 , 0x1d2071d2 , 0xabd72856 , 0x019c3ba8 ] )
+  , Mont::unsafe_make( [ 0xeff96343 , 0x034bfb01 , 0xf8920ea9 , 0x048fc437 , 0x6b7db8ff , 0x5c448319 , 0x23fe3888 , 0x06e3fffc ] )
+  , Mont::unsafe_make( [ 0x99807586 , 0x8e5969f1 , 0xd62406f5 , 0x8e071455 , 0xf2b12e35 , 0x23049bd6 , 0x9fce961e , 0x1782bca9 ] )
+  , Mont::unsafe_make( [ 0x0b014392 , 0x50b14f7e , 0xec4efaf7 , 0xc860728f , 0x389a986c , 0x472a27c1 , 0x2408e83f , 0x18545266 ] )
+  , Mont::unsafe_make( [ 0x282ba190 , 0xecb0fe70 , 0x66538750 , 0xfa4c4884 , 0x5f324117 , 0x149a8579 , 0xf4040b8f , 0x21afe8e1 ] )
+  , Mont::unsafe_make( [ 0xccc4f805 , 0xa1e815be , 0xc86d131c , 0x1cd8c091 , 0xe1120abc , 0x96e7daac , 0xa90424c2 , 0x08bc3b75 ] )
+  , Mont::unsafe_make( [ 0x1c5ac25a , 0x293b0cef , 0xb18b162a , 0x9df4d19b , 0xfa1f93bb , 0x135e825b , 0xf1cde751 , 0x17fcbc39 ] )
+  , Mont::unsafe_make( [ 0x757d5de5 , 0xac1d8e93 , 0x2f92da4d , 0x309082a7 , 0xfe631415 , 0x57374a0b , 0x6970d282 , 0x06e8e21b ] )
+  , Mont::unsafe_make( [ 0xb923813f , 0x8b38e493 , 0x756ac6db , 0xadebee70 , 0x1f202f84 , 0x3730422d , 0xdd0c674b , 0x079c4457 ] )
+  , Mont::unsafe_make( [ 0xd3843ea7 , 0x91bda864 , 0xb793f51f , 0x093a9519 , 0xbca18734 , 0xd1a91751 , 0xcc0ddbe8 , 0x29867a7f ] )
+  , Mont::unsafe_make( [ 0xe896d763 , 0x2e63e859 , 0x65c9283f , 0x3cafe9ff , 0x1f294628 , 0x7ced0481 , 0xe93f536a , 0x2539f116 ] )
+  , Mont::unsafe_make( [ 0x191268a7 , 0xfc0c2c92 , 0xa0233ed2 , 0xb19d8fe6 , 0x88faf2fa , 0xa8f8ba39 , 0x1b717ce2 , 0x2a81590a ] )
+  , Mont::unsafe_make( [ 0x7517fd1d , 0xa503a1af , 0x5225c11a , 0x7e0d524c , 0x0fa756c6 , 0x7ff5775c , 0xecd2a2ae , 0x0a2d7fa7 ] )
+  , Mont::unsafe_make( [ 0x3ced2ac0 , 0x8fcfd01e , 0xe4ebd021 , 0x2fc8bd2a , 0x936df306 , 0xfc0ff20e , 0x2f5816d2 , 0x032f3ecd ] )
+  , Mont::unsafe_make( [ 0xc1916eba , 0x502dc688 , 0xf0c9c331 , 0xa229ed1f , 0x5c87f8b5 , 0x7cd83848 , 0x5a11bf0c , 0x1c0accde ] )
+  , Mont::unsafe_make( [ 0xbd937243 , 0x1ada9183 , 0x50318e21 , 0x9c4582cd , 0x29fe1438 , 0xa931c6fb , 0x51c85654 , 0x150e7c53 ] )
+  , Mont::unsafe_make( [ 0x254fb145 , 0x23a54851 , 0xc91a71ad , 0x58556699 , 0xdcca1e4d , 0x9f72c64e , 0x91720b4a , 0x09d3cbeb ] )
+  , Mont::unsafe_make( [ 0xaac10d1a , 0x6cb771de , 0xd08fb6b1 , 0x27fe03e6 , 0x4768fa81 , 0x555c8063 , 0xa57a1ef1 , 0x25f90a51 ] )
+  , Mont::unsafe_make( [ 0x4b918fc5 , 0x1d86ae35 , 0xba566ca2 , 0xd655b53f , 0x84eaf91a , 0xf6e6997f , 0xa15ea5c1 , 0x1a8b79c5 ] )
+  , Mont::unsafe_make( [ 0x57e92c7b , 0x0a623060 , 0xbce9fd0c , 0x0db1e5fe , 0x45dc313c , 0x41d59cf0 , 0x675426e3 , 0x16e22f7a ] )
+  , Mont::unsafe_make( [ 0x15ba7952 , 0xdbc5c349 , 0x2aabda6b , 0x9d63001a , 0xfaeaf1b9 , 0xbadb5427 , 0xe33ceb3a , 0x09786189 ] )
+  , Mont::unsafe_make( [ 0xa2f3f0ca , 0xdc5fb3fd , 0x04321438 , 0x81b95613 , 0xa4998dd9 , 0x270f1822 , 0x47db9033 , 0x273ab9fa ] )
+  , Mont::unsafe_make( [ 0xc6c2f783 , 0x2049015a , 0x58eefff8 , 0xc96e8a53 , 0x5fbe1413 , 0xd7426224 , 0x4551698f , 0x0539751a ] )
+  , Mont::unsafe_make( [ 0x907fd20f , 0xdca2ee3c , 0x14df80bd , 0xc1a65eeb , 0xf6f1ac1f , 0xf5ac2632 , 0x96787fb2 , 0x0340eef6 ] )
+  , Mont::unsafe_make( [ 0x647ec77b , 0xc6a5053c , 0x50d63aa2 , 0x4449c23b , 0x2fa4de75 , 0x51430610 , 0xc15f6836 , 0x1280e733 ] )
+  , Mont::unsafe_make( [ 0x39a8cc3d , 0x5d952015 , 0xcadb4655 , 0xd94ddd25 , 0xaf22f052 , 0x51511942 , 0x1ae8478d , 0x1e12dcd3 ] )
+  , Mont::unsafe_make( [ 0x47a1fde5 , 0x6906e5d1 , 0x1a0cbde4 , 0x6fd9597a , 0x76d28e29 , 0xaedc2bf3 , 0xadcb9b12 , 0x0cdcd53b ] )
+  , Mont::unsafe_make( [ 0x7c673410 , 0x6031a598 , 0xd7a53497 , 0xc2bafec6 , 0xc5ef9169 , 0xfb7193a7 , 0x72500025 , 0x27e10205 ] )
+  , Mont::unsafe_make( [ 0xfcd16f0d , 0x6b3256b8 , 0x7aa78f5a , 0xb7ba98cf , 0x09d4a49c , 0xd01010a8 , 0x7c5533ed , 0x03761c62 ] )
+  , Mont::unsafe_make( [ 0x18fa052f , 0x969339d3 , 0x13ed98fd , 0x68ebc04a , 0x7f4830ca , 0x12fc5f95 , 0xde6a45f2 , 0x1c22284e ] )
+  , Mont::unsafe_make( [ 0x27954508 , 0x55d5373c , 0x7e8b358d , 0x93385d6f , 0x0d2bcbeb , 0xdc127482 , 0x38cecd0b , 0x0f6a4154 ] )
+  , Mont::unsafe_make( [ 0x72a3610b , 0xc43e945f , 0x49193c0e , 0x799a9919 , 0x0a7302d6 , 0x2be40344 , 0xb8e4b2d3 , 0x203fd027 ] )
+  , Mont::unsafe_make( [ 0xc8b037d1 , 0x25d624ec , 0x1c8ac3cd , 0x020f0416 , 0x2d839309 , 0x69c6a4ba , 0x99da1af3 , 0x0a940902 ] )
+  , Mont::unsafe_make( [ 0x273796da , 0xc140144c , 0x3f284d63 , 0x3670492a , 0x39461e74 , 0xd18055b5 , 0x28e4cec8 , 0x1cd94e28 ] )
+  , Mont::unsafe_make( [ 0xd1ce048e , 0xd9b5134d , 0x48254609 , 0xbe48fbc3 , 0xe70cd0ef , 0xfac8f54d , 0x06ea9ebe , 0x0494d068 ] )
+  , Mont::unsafe_make( [ 0x1fa791f0 , 0x072d8809 , 0xa7b91dfc , 0xb86e54dc , 0xf8cd669c , 0x9a3abcf3 , 0xc42f8422 , 0x24c5c553 ] )
+  , Mont::unsafe_make( [ 0x5b92127a , 0x80a90e2d , 0xb1e3a06b , 0x4d4124da , 0x3a9d8648 , 0x25501841 , 0xb09a6c79 , 0x1b64fe7c ] )
+  , Mont::unsafe_make( [ 0xca11f46a , 0x98ca4ec0 , 0x59a17039 , 0xbf5797d5 , 0xa9fac4d5 , 0xab26b967 , 0x96d5cabf , 0x21195008 ] )
+  , Mont::unsafe_make( [ 0x84bc25fa , 0x93672031 , 0xe0cc51dc , 0x408ad3f1 , 0xd889cab6 , 0x314a9bc3 , 0x8c84a327 , 0x02587ea7 ] )
+  , Mont::unsafe_make( [ 0xb3dfddd6 , 0x7f1e7cce , 0xe7bf732a , 0xcf63d2f2 , 0x1c699443 , 0x188efdc7 , 0x0645f38c , 0x1818b43c ] )
+  , Mont::unsafe_make( [ 0xe3393e4a , 0x60268eb6 , 0xe8c37020 , 0xba50b623 , 0x61621cb2 , 0x13e61fec , 0xfbc4ca6d , 0x1ef2f0fa ] )
+  , Mont::unsafe_make( [ 0xf8b9bb6a , 0x91856705 , 0xe6c676b3 , 0xd6004d71 , 0xf602977b , 0x099c4712 , 0x098fc3bf , 0x25c2c26f ] )
+  , Mont::unsafe_make( [ 0x86642414 , 0x3116b7bd , 0x5b105231 , 0x4a976e0d , 0x0eae6fb7 , 0x3758f091 , 0x0c3e928b , 0x23d30468 ] )
+  , Mont::unsafe_make( [ 0xf0bb623e , 0x26ab76aa , 0xcaca2340 , 0xb00c082a , 0xe9e16030 , 0xd9cce4db , 0x66e6e9e6 , 0x23c05eb2 ] )
+  , Mont::unsafe_make( [ 0x30995c7a , 0x72c621ce , 0xd773aac6 , 0xa1c009dc , 0xa5279e30 , 0xc84b1999 , 0x354d05c9 , 0x13ecc165 ] )
+  , Mont::unsafe_make( [ 0xee1fbde7 , 0xc28ed9c0 , 0x5d2cbbb4 , 0x07d5af69 , 0x7eb413b9 , 0x8c6c8d9d , 0x5baa33b3 , 0x0ead4461 ] )
+  , Mont::unsafe_make( [ 0x64140289 , 0xe872d180 , 0x8c83a79c , 0xd2e8b358 , 0xdabfe664 , 0x34a3ce61 , 0x4f0ccf11 , 0x0825f5f4 ] )
+  , Mont::unsafe_make( [ 0xc7a62ec1 , 0xbcb5b567 , 0xc13b635b , 0xab2a29ca , 0x39f499bb , 0x03954fb3 , 0xb629fec6 , 0x057e4352 ] )
+  , Mont::unsafe_make( [ 0x58354ddc , 0x26474ab8 , 0x592102b4 , 0x32fbba74 , 0xccf6cbd3 , 0x55c2f97d , 0x5f411e70 , 0x15bc5ebe ] )
+  , Mont::unsafe_make( [ 0x02b9a454 , 0x1b5d8510 , 0x72eaa498 , 0xb80824bd , 0x7579cb80 , 0xc3cc3f0c , 0xa528be9f , 0x0288e3d4 ] )
+  , Mont::unsafe_make( [ 0xf522fb82 , 0xece943b1 , 0xf5606312 , 0x10de4d68 , 0x293d61ee , 0x528cc7f7 , 0xb5ea7f55 , 0x1df2148a ] )
+  , Mont::unsafe_make( [ 0xf4dfbec4 , 0xb9844605 , 0x08c0f48b , 0x71f63fb7 , 0x8bf66881 , 0x81357e92 , 0xd4a6ad6c , 0x083fe77b ] )
+  , Mont::unsafe_make( [ 0xb9c9a2a0 , 0x0612d665 , 0x2b932ce5 , 0x905f2cf0 , 0xbd7e1485 , 0x3cb53b9e , 0x1144004e , 0x1464f5b9 ] )
+  , Mont::unsafe_make( [ 0xd79601b5 , 0x33be9f14 , 0x3344a161 , 0x397e3c82 , 0xb248ecc2 , 0x05cb1441 , 0x62852d0d , 0x19480987 ] )
+  , Mont::unsafe_make( [ 0x8d95a173 , 0x7d18d2ed , 0xfd6a5396 , 0x4fb9906c , 0xeebda845 , 0xce889d2e , 0x5f16e36c , 0x0e5c2690 ] )
+  , Mont::unsafe_make( [ 0xf2b5c2ee , 0x1a244087 , 0x422c4549 , 0x090a801f , 0xf6f85b01 , 0x1295e785 , 0xae4eefab , 0x13d50d5a ] )
+  , Mont::unsafe_make( [ 0x67c4a5c2 , 0x40093cda , 0xec84ad8a , 0x7050bc44 , 0x9a44ffc4 , 0x564319b7 , 0x05804fe7 , 0x037be1d9 ] )
+  , Mont::unsafe_make( [ 0x4b09ed30 , 0xcde06cc7 , 0x7612a35f , 0xf84f5da9 , 0x7336f5cc , 0xadd23e71 , 0xe001e69a , 0x2ebcd07b ] )
+  , Mont::unsafe_make( [ 0xc4e5f9ed , 0x82de5bbf , 0xa774bc44 , 0x34c916d3 , 0x2471c84d , 0x602ef7e8 , 0xcae83608 , 0x0e31d465 ] )
+  , Mont::unsafe_make( [ 0x8067c807 , 0x137a9a73 , 0xdc3f30c1 , 0x2033a879 , 0x1428871a , 0x840ab1a9 , 0x32ffccfb , 0x11d97d2e ] )
+  , Mont::unsafe_make( [ 0x2c936031 , 0x165c9f20 , 0xa3a9eeb4 , 0xdd83fb2e , 0xc068b144 , 0xee222d33 , 0xfb06fc4a , 0x0c4d8941 ] )
+  , Mont::unsafe_make( [ 0x09a566e0 , 0xafd30d5f , 0x9e160f13 , 0x90908c8e , 0xb51f4f92 , 0x078028d4 , 0x844f20c9 , 0x1e93c631 ] )
+  , Mont::unsafe_make( [ 0x49e92450 , 0x060613ba , 0xb6e2b932 , 0x63922773 , 0x0b350f87 , 0x7f8680a8 , 0x3fd77198 , 0x2afeea99 ] )
+  , Mont::unsafe_make( [ 0xc9672712 , 0x75663fca , 0x7e131f52 , 0x38165886 , 0x7b8fc5bd , 0xde4cd599 , 0x50b3320a , 0x19880719 ] )
+  , Mont::unsafe_make( [ 0x4432c700 , 0xaaea3d00 , 0x67fc6711 , 0xa80de34b , 0x0c7c5f6a , 0x36f650f2 , 0x449296bd , 0x1b3061f5 ] )
+  , Mont::unsafe_make( [ 0x2716d6e8 , 0x8bd8751e , 0x77c3aa40 , 0x50e94016 , 0x91d523a5 , 0x667c04bf , 0xf32eb492 , 0x1f36e83d ] )
+  , Mont::unsafe_make( [ 0x899653cb , 0x8d98f91b , 0xc329aa0b , 0x4eda4e0d , 0xbd6ee153 , 0x8a109159 , 0x9ce3166a , 0x2cd3f57f ] )
+  , Mont::unsafe_make( [ 0x8bd1e60d , 0x34989b5a , 0x2e442af1 , 0xd98b1bbf , 0xe2eb9484 , 0x0714d912 , 0x52c17197 , 0x023c0714 ] )
+  , Mont::unsafe_make( [ 0x3f431bec , 0x3ee3997c , 0xce268339 , 0x6b2d2830 , 0x5cd5656a , 0x8ca47a1e , 0x98499d21 , 0x165e0178 ] )
+  , Mont::unsafe_make( [ 0xa5511d9b , 0xf48aa6e8 , 0x07a36cd1 , 0xba145f4f , 0x176e2a34 , 0xf9c6d258 , 0xa5958a7a , 0x07d10d6b ] )
+  , Mont::unsafe_make( [ 0x7805c784 , 0x970a4c4e , 0x804b723c , 0xc9e96d11 , 0xedb94244 , 0x94046de7 , 0x7cbed3fb , 0x026e13ff ] )
+  , Mont::unsafe_make( [ 0xfed5e2fb , 0x0ae493a8 , 0x0d7cbfa1 , 0xcab2002e , 0xd65263a7 , 0x112db1d9 , 0x0eb1c752 , 0x1ebfd576 ] )
+  , Mont::unsafe_make( [ 0x75810a20 , 0xcabbf12e , 0x5a622db4 , 0x41c82546 , 0x770fee88 , 0x8e88337b , 0x0ef8e6ab , 0x2c2ee651 ] )
+  , Mont::unsafe_make( [ 0x7f8d062a , 0xac83fd70 , 0x8de900a7 , 0x3c159ac7 , 0xf38fec5d , 0x6b501916 , 0xfe2d92d6 , 0x02d923d6 ] )
+  , Mont::unsafe_make( [ 0xb0fd1131 , 0xd96a8ebd , 0x9db7cc9a , 0xef41c217 , 0x7206e249 , 0x9a59dfaa , 0xe7e5bb8d , 0x0e2a581b ] )
+  , Mont::unsafe_make( [ 0xb2b2fb65 , 0x0dc19f89 , 0x754567b8 , 0x9dc527b2 , 0xe16aaf89 , 0x29b3af22 , 0xb30c97a7 , 0x212a610b ] )
+  , Mont::unsafe_make( [ 0x0ad58a5a , 0x5d0f25a8 , 0x283333e8 , 0x68704bf7 , 0x3b45287a , 0x3308308a , 0x6e43f9c1 , 0x0e0902f8 ] )
+  , Mont::unsafe_make( [ 0x10bf1574 , 0xb3757293 , 0xc649c4e1 , 0x1cb0573d , 0xd73ccd8a , 0x7ad0d5ba , 0x15aa0bce , 0x2ade4023 ] )
+  , Mont::unsafe_make( [ 0x06374e29 , 0x0415a649 , 0x2777e012 , 0x9fd1e315 , 0xbb7479f7 , 0xcd4fb786 , 0xca93b07d , 0x2ce135a7 ] )
+  , Mont::unsafe_make( [ 0x9e261b21 , 0xd88c878a , 0x9755af6c , 0x61f123c9 , 0xf33982cc , 0x5b442aa9 , 0xf4eac79c , 0x0694fdad ] )
+  , Mont::unsafe_make( [ 0x60435134 , 0x81f84f7a , 0xc676b108 , 0x29e4fc16 , 0x32d609da , 0x8faccbb2 , 0x23553fa7 , 0x045f3de7 ] )
+  , Mont::unsafe_make( [ 0xe286be36 , 0x005c054f , 0xc357ad7b , 0x028c0f36 , 0x1241da67 , 0xa99466c4 , 0x92d01f5f , 0x1c231bc7 ] )
+  , Mont::unsafe_make( [ 0x439ee4e5 , 0x55f5b362 , 0x03d47512 , 0x9df1b4c5 , 0xbf274b34 , 0xfc33eef9 , 0xb24cf23a , 0x089485a2 ] )
+  , Mont::unsafe_make( [ 0x303f08d0 , 0x310d2a94 , 0xa9feb4ae , 0x7e3f820d , 0x1dbb4237 , 0x97b09936 , 0x9d3ab197 , 0x047e10c5 ] )
+  , Mont::unsafe_make( [ 0xa367375d , 0x4757c7a4 , 0x78d52382 , 0xcb929a84 , 0x09b5a30f , 0x95067ec7 , 0xd9450448 , 0x0d2a9fd3 ] )
+  , Mont::unsafe_make( [ 0x6d3fd546 , 0x1e9b4abc , 0x3e38c0d5 , 0x03d72ea7 , 0xe1ccab87 , 0x5712505a , 0x1c29a279 , 0x1ae240d4 ] )
+  , Mont::unsafe_make( [ 0xcaf7a027 , 0x31ecee97 , 0x8404a59b , 0x4ed24060 , 0x727e1030 , 0x9d8d1254 , 0xc556cc17 , 0x1059e0b9 ] )
+  , Mont::unsafe_make( [ 0x6a082750 , 0x51b85f90 , 0x2d421b79 , 0x18493a4f , 0xf6bf729d , 0x950337f9 , 0xf8152700 , 0x2fc56f86 ] )
+  , Mont::unsafe_make( [ 0xf3264f6f , 0x87c50d8b , 0x03718fd3 , 0xb695d0e1 , 0x144de1d3 , 0x1bcd418a , 0xc63d326a , 0x05b6f7ef ] )
+  , Mont::unsafe_make( [ 0x55f1d032 , 0x494f434e , 0xfabaed4d , 0x61feaa4a , 0xd5ac513e , 0xa71fac77 , 0xebfe0906 , 0x2238fb2e ] )
+  , Mont::unsafe_make( [ 0x023030f5 , 0x69cb4a03 , 0xdb10bbb1 , 0xe60bbf72 , 0x770ccaf1 , 0x78e98e4a , 0x9cb843d8 , 0x2c117c88 ] )
+  , Mont::unsafe_make( [ 0xc671189b , 0x67d87c9e , 0x31481c95 , 0x86dd8e7d , 0xcd0525a7 , 0x871eacf2 , 0x79f834e1 , 0x1bc5c5b8 ] )
+  , Mont::unsafe_make( [ 0x8bf97980 , 0xb1a821fc , 0x1800148a , 0x4349f85e , 0x64f01016 , 0x7517283b , 0x4ea966f2 , 0x15550f7b ] )
+  , Mont::unsafe_make( [ 0xd661dd88 , 0x5ac8a917 , 0xd60d706a , 0xfa14065e , 0x7a86417f , 0xa9288be0 , 0x0a2a56e5 , 0x016750f5 ] )
+  , Mont::unsafe_make( [ 0xa7a40f9f , 0x4d1a0651 , 0xf8388f57 , 0x61e7caa1 , 0x152a7587 , 0x6d57bbce , 0xda14e61e , 0x2515fdaa ] )
+  , Mont::unsafe_make( [ 0x4755bc1f , 0xdf21ddff , 0x4bef23ad , 0xd933e04a , 0x10b009b4 , 0x123f5f46 , 0x547a731c , 0x0549f9c6 ] )
+  , Mont::unsafe_make( [ 0xbe609914 , 0xe9c203d0 , 0x1fa4ae4f , 0x3c8c319c , 0x095dd5ed , 0xf4badc30 , 0xd2c33eab , 0x12951088 ] )
+  , Mont::unsafe_make( [ 0xe9d366ab , 0x8bd41963 , 0x7fa68294 , 0xa7ac622a , 0xcae27036 , 0xf94d7271 , 0xcc183524 , 0x2f852a6b ] )
+  , Mont::unsafe_make( [ 0x5cef91ae , 0x199b49f6 , 0x4686f61b , 0x22376324 , 0x25be8a1f , 0x4affb67c , 0xa47b7aa2 , 0x0664bc3a ] )
+  , Mont::unsafe_make( [ 0x28c8d6e9 , 0x0fdcb74d , 0x0aef7592 , 0xeb7e4eee , 0xdf36f2b5 , 0xb75c2800 , 0x701e1bca , 0x280af98f ] )
+  , Mont::unsafe_make( [ 0x26e5c746 , 0xce89d3e6 , 0x32ff7c46 , 0xb20a6011 , 0x9d5e78c3 , 0x03293387 , 0x8e3b0e33 , 0x2e3e855c ] )
+  , Mont::unsafe_make( [ 0x9ad82dfe , 0x28dbca9a , 0x9f47cfae , 0x11db59e9 , 0x15dc6986 , 0x566b9ced , 0x46a9db74 , 0x248527b8 ] )
+  , Mont::unsafe_make( [ 0x7fe2054d , 0x1feb9929 , 0x8af13e16 , 0x441ce809 , 0xade16fe0 , 0x21157e55 , 0x74942c03 , 0x23d3e181 ] )
+  , Mont::unsafe_make( [ 0x99239639 , 0xdac37550 , 0xe1f91f6f , 0xddbb101e , 0xe3e283b8 , 0x36154794 , 0x6975d60c , 0x27a70456 ] )
+  , Mont::unsafe_make( [ 0xe2d7b386 , 0x9078f1f1 , 0x3b3b98e8 , 0x6c25aef0 , 0xca428a16 , 0x303e9740 , 0x4efa0187 , 0x2fbb717f ] )
+  , Mont::unsafe_make( [ 0x70bdb534 , 0x689db4c2 , 0xa7fffe98 , 0x5fc60ba1 , 0xd7cd971f , 0xacf99514 , 0x12e8851b , 0x1994f03c ] )
+  , Mont::unsafe_make( [ 0xcb3cd399 , 0x089ac0e1 , 0xfbf6cf04 , 0x582f60c4 , 0x7e944e77 , 0x9cb49565 , 0xc492e83e , 0x254f3a2e ] )
+  , Mont::unsafe_make( [ 0xa05cff7e , 0xb004d659 , 0x4d55b8b2 , 0x797910f5 , 0xc59fc5e8 , 0x10e3db64 , 0xc70b3689 , 0x0ae5b1c5 ] )
+  , Mont::unsafe_make( [ 0xab310b65 , 0x1722b19c , 0xd7bc4036 , 0x6f0e8c9a , 0x532abe01 , 0x952d2c30 , 0x5406382a , 0x03d127a6 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// internal mixing matrix (`t x t`s, flattened)
+pub const CONST_P: [Mont; 121] = 
+  [ Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x5c4c6b68 , 0x11d1e16a , 0x7083e191 , 0x71f6dfad , 0x788a08f6 , 0x41507213 , 0xacd48caf , 0x2666dee0 ] )
+  , Mont::unsafe_make( [ 0x99ed3c69 , 0x490ede90 , 0xc0998d0f , 0xc84a17ca , 0x7d444337 , 0xcfbda4f5 , 0xd5e4048f , 0x186c39b6 ] )
+  , Mont::unsafe_make( [ 0xdc03002b , 0x752784a2 , 0xa16958d4 , 0xc542688e , 0xc5c1765e , 0x2dc923b9 , 0xc849b5b4 , 0x08fd2929 ] )
+  , Mont::unsafe_make( [ 0x877285eb , 0x09cc4f6b , 0x7c868c64 , 0xdf265299 , 0x41e7e28b , 0x5359a238 , 0xe2f533aa , 0x08948886 ] )
+  , Mont::unsafe_make( [ 0x0a37a979 , 0x663f9e74 , 0x3482fc32 , 0xc9c24a63 , 0x607dcde4 , 0x3f2ca73e , 0xac44830e , 0x17474e89 ] )
+  , Mont::unsafe_make( [ 0xee7dba10 , 0x67ea720e , 0x7bcb4846 , 0x07daa4a4 , 0xcba26964 , 0xfa120222 , 0xac074d7a , 0x1f8a10ff ] )
+  , Mont::unsafe_make( [ 0x8a108def , 0xbc54fe39 , 0x3170face , 0xbf4638f4 , 0x8a0853cc , 0x27ed6c57 , 0xc891d293 , 0x10c7fa63 ] )
+  , Mont::unsafe_make( [ 0x66bd3989 , 0xe2a420bd , 0xe5e79392 , 0x937541a7 , 0xd62a4e1e , 0xe2c63544 , 0xa1506b74 , 0x2a3c3f57 ] )
+  , Mont::unsafe_make( [ 0x6f1cc401 , 0x15266158 , 0x87e79c5a , 0x94be6044 , 0xeea46a23 , 0x9a1fe574 , 0xc32a1ef5 , 0x04eb1a77 ] )
+  , Mont::unsafe_make( [ 0x0c454447 , 0x6bf43bae , 0x62e17f7a , 0x6c5a93b3 , 0xd0e60a8b , 0xe7ffd100 , 0x2998a2ed , 0x0ef21224 ] )
+  , Mont::unsafe_make( [ 0x07563283 , 0xfca676d2 , 0x89b71d25 , 0xac7f6f04 , 0x4851c9b2 , 0x1c124264 , 0x1465b569 , 0x2a23558b ] )
+  , Mont::unsafe_make( [ 0x8e14ad2b , 0x950ddc3e , 0xdfb1558c , 0x3f42789e , 0x4628197d , 0x221829b1 , 0x336ec74e , 0x29497e22 ] )
+  , Mont::unsafe_make( [ 0xe82b8083 , 0x5a98a0b5 , 0x4d25e0a6 , 0x07de481a , 0x47547738 , 0x04b19be2 , 0x9276a44e , 0x1d666bcb ] )
+  , Mont::unsafe_make( [ 0x889ef700 , 0x2ce93e4c , 0xeb3e6e4b , 0xfae72332 , 0xcec5a7df , 0xc836e23e , 0x5b40ccc4 , 0x28a1be5a ] )
+  , Mont::unsafe_make( [ 0xe76a40bc , 0x29fe085f , 0xc20f6069 , 0x1bdcfeac , 0x8861fc5c , 0xca9ee622 , 0x815f1149 , 0x03edeaac ] )
+  , Mont::unsafe_make( [ 0x7ddd7350 , 0xe21e1964 , 0x394362fb , 0x1c28517b , 0x6f6e4276 , 0x0c0a30ec , 0xd94b43ff , 0x2ee4e2a6 ] )
+  , Mont::unsafe_make( [ 0xd3523e7d , 0xbe3e76ac , 0xccd3ac12 , 0xae63fdd9 , 0x2a88b910 , 0x64e03514 , 0xfd357055 , 0x1de2cc98 ] )
+  , Mont::unsafe_make( [ 0xab137ef6 , 0x4c6e2540 , 0x5e4910a9 , 0xcbadae84 , 0x10759869 , 0x50fb5d30 , 0x1fb55ec0 , 0x146d9bb8 ] )
+  , Mont::unsafe_make( [ 0xd751e879 , 0xee0261b8 , 0xb17d35b9 , 0x30a7a0ce , 0x07951fca , 0xfad4329e , 0x6201c500 , 0x01b1d545 ] )
+  , Mont::unsafe_make( [ 0xec6ebd91 , 0x8da6abb3 , 0x4f7d83fe , 0x8ecdf225 , 0x4b874ad7 , 0x5b55411c , 0x12806c45 , 0x0d108009 ] )
+  , Mont::unsafe_make( [ 0xd870eb2c , 0x283227b6 , 0x90c50042 , 0xc63d46c7 , 0x800dc147 , 0x53027c30 , 0x409bc7dd , 0x0976449f ] )
+  , Mont::unsafe_make( [ 0xe896d763 , 0x2e63e859 , 0x65c9283f , 0x3cafe9ff , 0x1f294628 , 0x7ced0481 , 0xe93f536a , 0x2539f116 ] )
+  , Mont::unsafe_make( [ 0x9ca7d5f3 , 0x2ace8319 , 0xcde6c3b3 , 0xf5d6f592 , 0x741ca2ed , 0x07534680 , 0x81bde8c7 , 0x2b26587b ] )
+  , Mont::unsafe_make( [ 0xef184a10 , 0xaaa84f71 , 0xaf806101 , 0xb750888c , 0x20a59179 , 0x76cb03f6 , 0x2cbf4d53 , 0x23e6feaf ] )
+  , Mont::unsafe_make( [ 0x343b6fa6 , 0x40f3df2d , 0xad4d2c6a , 0xf452d3cb , 0x8f8c4dc4 , 0x1016e8c9 , 0xe5353250 , 0x19df698d ] )
+  , Mont::unsafe_make( [ 0xc3b0af0b , 0x0834b1a7 , 0x5808feeb , 0x25350776 , 0xb854677a , 0x4084973f , 0x407ad987 , 0x2a8f45cc ] )
+  , Mont::unsafe_make( [ 0xd3a6bbef , 0x6f9fef76 , 0x86931f9f , 0x7612b66d , 0x2b947555 , 0x26563129 , 0x93095381 , 0x20eb6be1 ] )
+  , Mont::unsafe_make( [ 0xf969bfd2 , 0xc6a2c839 , 0x75a732e6 , 0x084cec84 , 0xffff3e33 , 0x861bf395 , 0x995d5066 , 0x2f150e58 ] )
+  , Mont::unsafe_make( [ 0xa31e8a99 , 0xa31feb62 , 0x01abf4e7 , 0xe2580995 , 0x77ab436e , 0x669c5179 , 0x96dec7b9 , 0x0f0b1090 ] )
+  , Mont::unsafe_make( [ 0x6587e2e2 , 0x4b50a380 , 0x7d931407 , 0x962bea60 , 0xcd463b26 , 0x2eb9e5f2 , 0x2b9b6862 , 0x04ae80a2 ] )
+  , Mont::unsafe_make( [ 0x6672d02a , 0xa9697edf , 0x9a07d8c9 , 0x50209de7 , 0xecd824a9 , 0x13d6f433 , 0x3f4ffc5b , 0x14a0a7c5 ] )
+  , Mont::unsafe_make( [ 0x1b25105f , 0x782f9346 , 0xf69a60ef , 0x53bcc23e , 0x15f1f425 , 0x152096ba , 0x89f54fea , 0x25099fa0 ] )
+  , Mont::unsafe_make( [ 0xa2f3f0ca , 0xdc5fb3fd , 0x04321438 , 0x81b95613 , 0xa4998dd9 , 0x270f1822 , 0x47db9033 , 0x273ab9fa ] )
+  , Mont::unsafe_make( [ 0xc5f31a1e , 0x06430ce1 , 0x17126d64 , 0xd790ddc3 , 0x4230a296 , 0x9eb95e0f , 0x91e8731a , 0x0c934e88 ] )
+  , Mont::unsafe_make( [ 0x0770c920 , 0x38f9beb7 , 0xf15732fa , 0xd919e4ac , 0x6c9ba016 , 0xbd2011cd , 0x6ddf9113 , 0x2bd698c1 ] )
+  , Mont::unsafe_make( [ 0x14062747 , 0x9681d78d , 0xd7f5baf4 , 0x5a4a237a , 0x66fcb69d , 0xf37808b0 , 0xc9052947 , 0x0dcb2617 ] )
+  , Mont::unsafe_make( [ 0x8bd04612 , 0x43be9998 , 0xc0dd664b , 0x1fa410d3 , 0xfe947b56 , 0xaf3482b2 , 0x5c040758 , 0x0dbb91f8 ] )
+  , Mont::unsafe_make( [ 0x57124bb9 , 0xf3805828 , 0x8d4ec3f8 , 0x45caa98c , 0x8d541879 , 0x6ba5dd03 , 0x5b37dbe2 , 0x0eed2ed6 ] )
+  , Mont::unsafe_make( [ 0x34cbf236 , 0xa1e65f3a , 0x7ce02c1e , 0x01e10de6 , 0xee18b2bc , 0x00c8766e , 0x6cd6050a , 0x2feef3e1 ] )
+  , Mont::unsafe_make( [ 0xbb84f06c , 0x14080d4a , 0xed218c40 , 0x98cbff04 , 0x208e394e , 0x16a1aff7 , 0x6a3bce7a , 0x16a359ec ] )
+  , Mont::unsafe_make( [ 0xeea20718 , 0x91b6734d , 0x72d5310e , 0x7384ec57 , 0x6652573b , 0x1cad4860 , 0xbe2e7b19 , 0x0ce3201c ] )
+  , Mont::unsafe_make( [ 0x064dd9f5 , 0x7284d291 , 0xac6cde9f , 0x0d748f86 , 0x45b1a5e0 , 0x8bb63dae , 0xe054840c , 0x256ab7fd ] )
+  , Mont::unsafe_make( [ 0x7e7b9bdf , 0xc0d5cac3 , 0x26121951 , 0x330f5909 , 0x0df31408 , 0xfcb305a3 , 0x64c21012 , 0x0ecdb9c2 ] )
+  , Mont::unsafe_make( [ 0xc8b037d1 , 0x25d624ec , 0x1c8ac3cd , 0x020f0416 , 0x2d839309 , 0x69c6a4ba , 0x99da1af3 , 0x0a940902 ] )
+  , Mont::unsafe_make( [ 0x593d0f44 , 0xde9b22ce , 0x5b09892c , 0x00a6b413 , 0xac937a64 , 0xcd608e50 , 0x6643fd61 , 0x2660ada4 ] )
+  , Mont::unsafe_make( [ 0x52261530 , 0x0dea87c7 , 0x4ff4eb4b , 0xe176a8fc , 0x06c690be , 0x72e0929b , 0x788994ab , 0x245412fa ] )
+  , Mont::unsafe_make( [ 0x6c18eb90 , 0x31e9e628 , 0x49b23274 , 0xaf87aa20 , 0x18908d02 , 0x2a8b3b87 , 0xe8b19a2e , 0x1f8460b7 ] )
+  , Mont::unsafe_make( [ 0x1f12ba27 , 0x3e24a501 , 0x7920c026 , 0x39fff529 , 0x6788154a , 0x41ea0d3d , 0x138c0fe0 , 0x05c52f8b ] )
+  , Mont::unsafe_make( [ 0xd5211f99 , 0x0b4de422 , 0x3f04445f , 0xff06e953 , 0x65bde19e , 0x527cf15a , 0xb7339bf4 , 0x1f09177e ] )
+  , Mont::unsafe_make( [ 0x79f699fe , 0x3bf1426f , 0x089efec5 , 0xe0d8629c , 0xb8c304e9 , 0xcd329920 , 0xbb09109f , 0x2c966013 ] )
+  , Mont::unsafe_make( [ 0xef4b5eb3 , 0x7d98ce07 , 0x0bb52667 , 0xefa37b59 , 0xb4b167e5 , 0x73757e1d , 0x253e2989 , 0x1c4deb96 ] )
+  , Mont::unsafe_make( [ 0x6e17a3c5 , 0x4f663933 , 0xbd97d6a2 , 0xb5c4f08c , 0xa945bc78 , 0xd7684422 , 0xb2ce37f6 , 0x08cd0c63 ] )
+  , Mont::unsafe_make( [ 0x16fd1c1f , 0x9aa541ae , 0xd5ef872f , 0xeb0b9310 , 0x85e20c36 , 0x35bf99fd , 0xcc2e17d7 , 0x2658b3f6 ] )
+  , Mont::unsafe_make( [ 0x1532da93 , 0x4facb408 , 0x9d58a253 , 0x680f3578 , 0x6eda24b3 , 0xe17750ad , 0xb607f9a4 , 0x035aff04 ] )
+  , Mont::unsafe_make( [ 0xf0bb623e , 0x26ab76aa , 0xcaca2340 , 0xb00c082a , 0xe9e16030 , 0xd9cce4db , 0x66e6e9e6 , 0x23c05eb2 ] )
+  , Mont::unsafe_make( [ 0xa0c98e5d , 0x01b9453d , 0x41eeeffd , 0xfd4533c5 , 0x488c41c6 , 0x55b76c5c , 0x46c3b7d0 , 0x0709d933 ] )
+  , Mont::unsafe_make( [ 0x31583b74 , 0xedd65aa3 , 0xf949e281 , 0x14d127fd , 0x00de75e9 , 0xdac8798b , 0xa8b08ceb , 0x2354d02e ] )
+  , Mont::unsafe_make( [ 0x3f465bf7 , 0x23579df6 , 0x957895ed , 0x5eb0880d , 0x9515b568 , 0x9b050822 , 0x4bcdac10 , 0x2b204c31 ] )
+  , Mont::unsafe_make( [ 0x5b97a576 , 0xac8c49ec , 0x99fabb75 , 0xb8ca90e5 , 0x2b4b37a0 , 0xcd71e510 , 0x1d79e49b , 0x1bdb6e09 ] )
+  , Mont::unsafe_make( [ 0xf1dc6709 , 0x068aa83f , 0x201babbd , 0x2326615e , 0xa90aa9c9 , 0x7f92bdda , 0xff565230 , 0x05dc7a55 ] )
+  , Mont::unsafe_make( [ 0x24711588 , 0x4f35ca33 , 0x77d3185d , 0xe3d1f0e1 , 0x2ae27ab0 , 0x2462b87f , 0x7e134a33 , 0x1c29db45 ] )
+  , Mont::unsafe_make( [ 0x6aae57c9 , 0xb0d687a5 , 0x6f452bc4 , 0x11b072e0 , 0x011e037f , 0xaca456b5 , 0x28a9b3f5 , 0x059d58d8 ] )
+  , Mont::unsafe_make( [ 0xfe9aa009 , 0x8637608c , 0x51613215 , 0xfe94aa44 , 0x660b790e , 0xfe2e1176 , 0xe23d4b55 , 0x1877553d ] )
+  , Mont::unsafe_make( [ 0xf09e80cd , 0x07475419 , 0x2a72f9b5 , 0xd68b9f47 , 0xb2955bca , 0xd902adab , 0xda725078 , 0x07a1944e ] )
+  , Mont::unsafe_make( [ 0xb5352489 , 0xbea0c9da , 0x3357d3e0 , 0xef69d827 , 0x98a0c09d , 0x05a4d415 , 0x0a9f855e , 0x0953f299 ] )
+  , Mont::unsafe_make( [ 0x8d95a173 , 0x7d18d2ed , 0xfd6a5396 , 0x4fb9906c , 0xeebda845 , 0xce889d2e , 0x5f16e36c , 0x0e5c2690 ] )
+  , Mont::unsafe_make( [ 0x02f1c731 , 0x73592023 , 0xd4c083f3 , 0x2d29ae68 , 0x93322ac5 , 0xe65078d4 , 0xf92c2fd1 , 0x0c5026ca ] )
+  , Mont::unsafe_make( [ 0x7098a0ec , 0x7224ecec , 0x2fb70523 , 0xea38e81e , 0x2659a0ef , 0x88ab79c2 , 0x7c3223b7 , 0x23fa471a ] )
+  , Mont::unsafe_make( [ 0x119376bb , 0xd70408b3 , 0xf4ad69a3 , 0x805c4095 , 0x371a8396 , 0x9e1c0c5d , 0x84400460 , 0x22a5f352 ] )
+  , Mont::unsafe_make( [ 0x069af7d1 , 0x6a1541c6 , 0x171441cc , 0xa5656fae , 0x9854e3d5 , 0x39105deb , 0xcab91771 , 0x0f52beb8 ] )
+  , Mont::unsafe_make( [ 0x90887663 , 0x63b0fa9b , 0x3e2b0fd6 , 0xac2ad93e , 0x58cb2e1a , 0x51e69203 , 0x6df95cbe , 0x2efa1aba ] )
+  , Mont::unsafe_make( [ 0x0e366e32 , 0x4769223e , 0x05e29cf7 , 0x46e3477c , 0xa2b460c3 , 0xa0c21903 , 0x42d7de01 , 0x212539ca ] )
+  , Mont::unsafe_make( [ 0x53ba929c , 0x8fac792f , 0x7f3808f1 , 0x78587079 , 0x921ac02c , 0xd1579557 , 0x0d3a32d2 , 0x1be25450 ] )
+  , Mont::unsafe_make( [ 0x1ab333fa , 0xc1706622 , 0x0c156489 , 0x76cae92b , 0x3bee5316 , 0x43c10724 , 0x6fb5fda3 , 0x007e8143 ] )
+  , Mont::unsafe_make( [ 0xec1826dc , 0x6823a044 , 0xba02fdcb , 0x16cd2a77 , 0xb432e85e , 0xa9884963 , 0x0424f99b , 0x2d61ea5e ] )
+  , Mont::unsafe_make( [ 0x170e4eca , 0x0816d2dd , 0x118ee155 , 0x817206e8 , 0xd424e6b7 , 0xb7fdc331 , 0x56335874 , 0x0b10439b ] )
+  , Mont::unsafe_make( [ 0x2716d6e8 , 0x8bd8751e , 0x77c3aa40 , 0x50e94016 , 0x91d523a5 , 0x667c04bf , 0xf32eb492 , 0x1f36e83d ] )
+  , Mont::unsafe_make( [ 0x501e086e , 0xe8904b4c , 0x567d12bd , 0x0a24942a , 0x17c5b6f9 , 0x10b08790 , 0xb37074f2 , 0x01679098 ] )
+  , Mont::unsafe_make( [ 0xdf805d94 , 0x71f44e84 , 0x15bdf460 , 0xe89ca5ab , 0xbaef9154 , 0xab44391c , 0x86cab689 , 0x0fef6c41 ] )
+  , Mont::unsafe_make( [ 0x738aa61a , 0x880d3f4b , 0x9a592d4e , 0xf8be6708 , 0xc19f5b43 , 0xa54abd84 , 0x49e8128e , 0x0a522f62 ] )
+  , Mont::unsafe_make( [ 0xea523eb0 , 0xa5867f69 , 0x5f13531f , 0x7b802fcb , 0x171d9d03 , 0x53a7e33a , 0x6bc70e13 , 0x0dcfc880 ] )
+  , Mont::unsafe_make( [ 0x8b66dc5b , 0xeab41eee , 0x30025b93 , 0x55a7e9d6 , 0x2e754094 , 0x1ae7ce8c , 0x34b10389 , 0x01d135d4 ] )
+  , Mont::unsafe_make( [ 0x12d2761e , 0x11b1cbc9 , 0xec3db717 , 0x58aecfa0 , 0x75165b08 , 0x92e11168 , 0x1c5b2c49 , 0x270eb5b4 ] )
+  , Mont::unsafe_make( [ 0x2b083fd7 , 0x58f78aec , 0x3aef712c , 0x93719aab , 0x76ed628e , 0xcdb59ce3 , 0x7b425e79 , 0x1c85ff4e ] )
+  , Mont::unsafe_make( [ 0xa0591f64 , 0x4f23ee9a , 0xf8e45f82 , 0xd03992c7 , 0xc2378ab3 , 0x8e33212a , 0xe9b966f6 , 0x0891413f ] )
+  , Mont::unsafe_make( [ 0xdb6192f5 , 0x91290899 , 0xff2ca723 , 0x0478ece3 , 0x65c8b03c , 0xb68c7a4a , 0x74f0f4ad , 0x2073bd64 ] )
+  , Mont::unsafe_make( [ 0x13b90f73 , 0x65d5a451 , 0x28380ae1 , 0x3a68127e , 0x23b15c71 , 0x47cce093 , 0xfdbc90ef , 0x144f4049 ] )
+  , Mont::unsafe_make( [ 0x0ad58a5a , 0x5d0f25a8 , 0x283333e8 , 0x68704bf7 , 0x3b45287a , 0x3308308a , 0x6e43f9c1 , 0x0e0902f8 ] )
+  , Mont::unsafe_make( [ 0x0a8cc619 , 0x7d0ca93a , 0x1f458a77 , 0xbf8304e1 , 0xe989668c , 0x060e83cc , 0xb444f3f0 , 0x2852fc82 ] )
+  , Mont::unsafe_make( [ 0x2c77b93e , 0x20151aa6 , 0x0a6dce39 , 0x03f98a98 , 0xb4a5e3d8 , 0xa334593c , 0x312856a2 , 0x01e6c33d ] )
+  , Mont::unsafe_make( [ 0xd79ce6e3 , 0x20620dff , 0x5f84d34d , 0xf840a1bb , 0x6bb503d0 , 0xb562e02a , 0xec7380af , 0x222280aa ] )
+  , Mont::unsafe_make( [ 0x8021473f , 0x3be89ae2 , 0x609b53e6 , 0x4ae71f3f , 0x47a4836a , 0x132e17d4 , 0x20f7e018 , 0x1132b3cd ] )
+  , Mont::unsafe_make( [ 0xc325c37c , 0x48cb4d2b , 0x2a90aca6 , 0xe847e2c7 , 0x112a6231 , 0x4d6ac7e9 , 0xd3fe2a1a , 0x033d89aa ] )
+  , Mont::unsafe_make( [ 0x177e569f , 0x9830e8e8 , 0xf1f103c3 , 0x36191874 , 0x054cea9e , 0xc329ea66 , 0xabe6e1c8 , 0x01568f5f ] )
+  , Mont::unsafe_make( [ 0x80793fa7 , 0x3c8616a1 , 0x7457cee9 , 0xcbde1eb8 , 0x48c2e2e6 , 0xc1682111 , 0x14b6fac2 , 0x1009f88c ] )
+  , Mont::unsafe_make( [ 0x6c1243e1 , 0x84803aa2 , 0x3294b68f , 0xb846dbd2 , 0xe6bdf132 , 0x546cc751 , 0xbf639452 , 0x1dd32c47 ] )
+  , Mont::unsafe_make( [ 0x05a140bd , 0x2f48d414 , 0x775128d4 , 0xc8cdf047 , 0xf97ec04d , 0xc0e215e2 , 0xb2475b0e , 0x0fb56109 ] )
+  , Mont::unsafe_make( [ 0x238f9445 , 0x6bac9652 , 0xec00177b , 0x3aeeef6d , 0x049c952f , 0x080be965 , 0x064d46ef , 0x036fa4d6 ] )
+  , Mont::unsafe_make( [ 0x6a082750 , 0x51b85f90 , 0x2d421b79 , 0x18493a4f , 0xf6bf729d , 0x950337f9 , 0xf8152700 , 0x2fc56f86 ] )
+  , Mont::unsafe_make( [ 0xa736d1b0 , 0x0a6be3d6 , 0xbf83cee2 , 0xf57ff4c5 , 0xe91f5dc8 , 0x788326e4 , 0x8ea15366 , 0x0d932177 ] )
+  , Mont::unsafe_make( [ 0xbae2cef3 , 0x959088a5 , 0x8489a93e , 0x4e383453 , 0x88be32d3 , 0x5514c3cd , 0x6af55d25 , 0x22e42098 ] )
+  , Mont::unsafe_make( [ 0xad13c34f , 0x2aa08294 , 0x9e6758f6 , 0x3b970cc0 , 0x4a62efd7 , 0x0fdb25a9 , 0xbedbd2e6 , 0x2f9bc386 ] )
+  , Mont::unsafe_make( [ 0x8f758262 , 0xd82591e5 , 0x3be27028 , 0xe2aed246 , 0xab298e7d , 0x91360f7a , 0x6daaa80e , 0x26225bc0 ] )
+  , Mont::unsafe_make( [ 0x30478da3 , 0xc1d5808e , 0xc39ec70f , 0xfe4f9c67 , 0x1a4709a2 , 0x21da8577 , 0x432e2564 , 0x1af04235 ] )
+  , Mont::unsafe_make( [ 0x49cee836 , 0x225ea1b6 , 0x19be574b , 0x60ad813a , 0x33777ea9 , 0xa7f94dea , 0x54393d41 , 0x0465c883 ] )
+  , Mont::unsafe_make( [ 0x988e7aa5 , 0xa179ffeb , 0x71c02df3 , 0x94e82602 , 0xefcda89b , 0xf34c2678 , 0xf3390732 , 0x25fb447c ] )
+  , Mont::unsafe_make( [ 0x751085ff , 0xb88f9fda , 0x5f822ace , 0xc12b6c5c , 0x1577abe6 , 0xa11bf0d0 , 0xfc4840f7 , 0x1d8e2d50 ] )
+  , Mont::unsafe_make( [ 0x0150ace5 , 0xc559ff5b , 0x3885df14 , 0xf44df63c , 0xd1d8052d , 0xeb280b6b , 0xfe37baf0 , 0x1ba5cd04 ] )
+  , Mont::unsafe_make( [ 0x07c5fc43 , 0xe64fba7b , 0x0e944a1c , 0x916fe7e5 , 0xa99296ef , 0x28afa261 , 0xadad756a , 0x2b617b61 ] )
+  , Mont::unsafe_make( [ 0x5cef91ae , 0x199b49f6 , 0x4686f61b , 0x22376324 , 0x25be8a1f , 0x4affb67c , 0xa47b7aa2 , 0x0664bc3a ] )
+  , Mont::unsafe_make( [ 0x116d8a26 , 0xd71147e3 , 0x92b608ab , 0xe4d84dbf , 0x0a90bd1d , 0x86c0cddd , 0x829fe61c , 0x1694fdb1 ] )
+  , Mont::unsafe_make( [ 0xa2d2d4ca , 0x2ba3d5e4 , 0xd4bc618b , 0xf0586b50 , 0x2586db8a , 0x1ee33531 , 0xa3df46a0 , 0x2372a4c5 ] )
+  , Mont::unsafe_make( [ 0x8edeedb6 , 0x89e3c3a7 , 0x7fb7349d , 0xfd4835bd , 0x5ad162d8 , 0x0289c868 , 0xc315b7c0 , 0x2e9c7e7d ] )
+  , Mont::unsafe_make( [ 0xc501ab85 , 0xd04d5c96 , 0x29ba29bd , 0xd9f734c5 , 0xd8079fe3 , 0xef30b068 , 0xf8cdb83f , 0x19578044 ] )
+  , Mont::unsafe_make( [ 0xfb4e363b , 0x7927f8f2 , 0xfebad6c6 , 0xf0107f21 , 0x3d050688 , 0x198532f8 , 0x6fa9fa17 , 0x24e9cafd ] )
+  , Mont::unsafe_make( [ 0x9ef8eff2 , 0xd9baceb5 , 0x34469f41 , 0x8c401042 , 0x32efe8c3 , 0xffe91bda , 0x5081840e , 0x2f9be666 ] )
+  , Mont::unsafe_make( [ 0xf03ff3de , 0x0a1a3797 , 0x6c36a034 , 0x57b9ca87 , 0x4e1a1bfc , 0xba87e800 , 0x7c2bc543 , 0x08867a41 ] )
+  , Mont::unsafe_make( [ 0x370b5662 , 0x6a9982f6 , 0x849c10be , 0x8df466f8 , 0xe6dc449a , 0xda09f7af , 0x7f13a62c , 0x18df9a21 ] )
+  , Mont::unsafe_make( [ 0xe8b50399 , 0xe9e700a5 , 0xd5385a37 , 0x5765b742 , 0x15b1db42 , 0x941dff01 , 0xe17f14bc , 0x30291da3 ] )
+  , Mont::unsafe_make( [ 0x74ec84be , 0x9331df23 , 0x0887e782 , 0x133e213d , 0xbacb97b0 , 0x117efda0 , 0xff98ca5d , 0x07da12c6 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// circomlib's optimization whatever
+pub const CONST_S: [Mont; 1386] = 
+  [ Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xcc2d0648 , 0x62e8c272 , 0xb7b12808 , 0x438bbb8f , 0x68d6aba7 , 0xeb10a9b7 , 0x8a71cad4 , 0x04d4a215 ] )
+  , Mont::unsafe_make( [ 0x7b0450bc , 0xacb89c56 , 0x9a2353ef , 0x917efbb0 , 0x38ed18d0 , 0x3222f2c1 , 0x56eaec60 , 0x2646248c ] )
+  , Mont::unsafe_make( [ 0xc0b686f4 , 0x6e010997 , 0xf3617194 , 0x789a0721 , 0xc5997e25 , 0x50381a2b , 0x97d2ce47 , 0x01eac65c ] )
+  , Mont::unsafe_make( [ 0xf4e75100 , 0x59a0472b , 0xe9931119 , 0x5cc4f8b1 , 0x23452522 , 0x1884e3cc , 0x595611e7 , 0x28853acf ] )
+  , Mont::unsafe_make( [ 0x9c527122 , 0x73943a9c , 0x8dcc22d3 , 0xf39a2c8f , 0xdf1c5f04 , 0xc351379f , 0x1c183a10 , 0x03a21636 ] )
+  , Mont::unsafe_make( [ 0xfb37a605 , 0xfa545cc4 , 0x3f696081 , 0x598dccba , 0xaed6eef9 , 0x40f6de04 , 0x832f77d8 , 0x01045ab3 ] )
+  , Mont::unsafe_make( [ 0x439bd376 , 0x63ee3d45 , 0xac530c54 , 0x6a3655a1 , 0x4b917924 , 0x5e68eb9c , 0xd53cd8cf , 0x0ec9eb72 ] )
+  , Mont::unsafe_make( [ 0x70890bac , 0x6258c137 , 0xc78724a2 , 0xe3792542 , 0x344ee72b , 0x73882925 , 0x392b7ace , 0x098cdcb6 ] )
+  , Mont::unsafe_make( [ 0xa6d72bd4 , 0xb1ce2d7a , 0x8be9f1d0 , 0xa7c5cdf6 , 0x5868c05d , 0xa10dcc0b , 0x2fb803eb , 0x07aabbbd ] )
+  , Mont::unsafe_make( [ 0x854da128 , 0xd13424d7 , 0xc7c5aee9 , 0xf4e56d2a , 0x45f32535 , 0x7674c487 , 0x3d49027f , 0x16882ece ] )
+  , Mont::unsafe_make( [ 0x2c04d868 , 0xa4df96d3 , 0x15e1cc43 , 0x4149128c , 0x3527be25 , 0xe8a62341 , 0x8efe427a , 0x06d4ea2c ] )
+  , Mont::unsafe_make( [ 0x60aa3534 , 0x7d957d4b , 0x8b608937 , 0x3898b046 , 0x5e8d03b9 , 0x9860dc14 , 0xbc26f203 , 0x05f75008 ] )
+  , Mont::unsafe_make( [ 0x6924a8d2 , 0xc1062585 , 0x843da0b6 , 0x5cec6789 , 0x446986aa , 0x63a8828c , 0x008e8621 , 0x0fb7cae2 ] )
+  , Mont::unsafe_make( [ 0x6fb4eb87 , 0xd8484bc8 , 0xef884003 , 0xe3d24f6f , 0xdb12bdfe , 0xcb69bd62 , 0x34b1a611 , 0x0de3feca ] )
+  , Mont::unsafe_make( [ 0x165dff77 , 0x04eca6a5 , 0x08ba134c , 0x10acfe19 , 0x560c8691 , 0x82cf3e20 , 0x4f524721 , 0x1bc87e8f ] )
+  , Mont::unsafe_make( [ 0xec274fce , 0x1c3cd6d9 , 0xf04c89db , 0xe61c73f6 , 0x41c7ecfb , 0x6bcc6487 , 0x2575235c , 0x16d9fc12 ] )
+  , Mont::unsafe_make( [ 0xc7e9d579 , 0x36526f7b , 0xee03f595 , 0xf17b144b , 0x85ad2e6d , 0x51ecc058 , 0x6fefdadb , 0x14f73dcb ] )
+  , Mont::unsafe_make( [ 0x2a4985e8 , 0x1ba72356 , 0xea86b572 , 0xb73dce1f , 0xd5201f0e , 0xb7318500 , 0xdcf2b0f3 , 0x0735dc00 ] )
+  , Mont::unsafe_make( [ 0x80ffc9a9 , 0x1921bf32 , 0x83be0e13 , 0xfb2d5e01 , 0xe2ad69b1 , 0xde37f055 , 0xeb7c5aff , 0x13a8ce24 ] )
+  , Mont::unsafe_make( [ 0x7fa360d6 , 0x6e6a05da , 0x03209139 , 0x2221d930 , 0x875c46ff , 0x58d5bb56 , 0xee3bb0e3 , 0x0ac42d7d ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x2028fb4c , 0xe50de33d , 0xfb27ee2c , 0x9d2b8ddf , 0xe641a991 , 0x1097bf5c , 0x58b88236 , 0x28dba138 ] )
+  , Mont::unsafe_make( [ 0x6694328e , 0x3a816182 , 0x8e7377c9 , 0x50697753 , 0xdeac0009 , 0xb119d404 , 0xfbd272bd , 0x039cda16 ] )
+  , Mont::unsafe_make( [ 0x346be323 , 0x909c37dd , 0xa25d6072 , 0x2fd224dd , 0x5a215680 , 0xcd8f07c3 , 0x1ebae4e3 , 0x2a94b756 ] )
+  , Mont::unsafe_make( [ 0x33c32d3a , 0x1bbeb78e , 0xf809c9e6 , 0x4951ed38 , 0x81abfb35 , 0x8120330b , 0xec7dc8a6 , 0x16d20b9b ] )
+  , Mont::unsafe_make( [ 0xadd423f1 , 0x2d5ef42a , 0xdbcf87bb , 0x7b873a87 , 0xdab223e6 , 0x3e1912cd , 0x19e0af74 , 0x10219e2c ] )
+  , Mont::unsafe_make( [ 0x6f490fb3 , 0x26ddf459 , 0x01fbaa89 , 0x3177a5d7 , 0x980630db , 0xd1681a14 , 0x2fc0c317 , 0x099838c4 ] )
+  , Mont::unsafe_make( [ 0x4976f1ee , 0x619d7919 , 0x0f0bc395 , 0x0a012215 , 0x02a05929 , 0x1f9f4583 , 0xab8d8284 , 0x04099566 ] )
+  , Mont::unsafe_make( [ 0xdffa926c , 0x5fb9928f , 0x54465a5f , 0xa829261f , 0x2508487c , 0xeae7aad5 , 0xb826c96e , 0x1f0a82b4 ] )
+  , Mont::unsafe_make( [ 0x9dd89053 , 0x3510f2c7 , 0x7879fbfc , 0x9a087f4b , 0x935a877c , 0xb9405ebf , 0x22c108ea , 0x0d76e22d ] )
+  , Mont::unsafe_make( [ 0x4243842b , 0x5f99e9f8 , 0xedef7c6f , 0x83be9c5f , 0x7042b6d7 , 0x891d5f0d , 0x2cdc8afa , 0x1e7559db ] )
+  , Mont::unsafe_make( [ 0xf15b28f5 , 0x88b5aa82 , 0x0d81b001 , 0x4b01c487 , 0xeead3a6c , 0xbdeabab2 , 0x7a7267f2 , 0x0013715a ] )
+  , Mont::unsafe_make( [ 0x9c63a8cc , 0xaff7e9b7 , 0xa6cd16a7 , 0x78e1e5fc , 0x96d50cbe , 0x94463a19 , 0xf0ab5423 , 0x18819d12 ] )
+  , Mont::unsafe_make( [ 0x604d5809 , 0x41784e3c , 0xef2e084e , 0xe2842631 , 0x07e60355 , 0x23a954a0 , 0xf986cf0f , 0x15a5b895 ] )
+  , Mont::unsafe_make( [ 0xf7d32c07 , 0xe1fffcd7 , 0x194dee0f , 0x153a1c16 , 0xe99ddb24 , 0x8bc67040 , 0x94c54d06 , 0x1b41429e ] )
+  , Mont::unsafe_make( [ 0x62cf6c98 , 0x6b0a6b68 , 0x6a2a9b14 , 0x534bd6f5 , 0x306dbf13 , 0x0c8d43e5 , 0x9ac3bb8a , 0x078039c8 ] )
+  , Mont::unsafe_make( [ 0x639a1845 , 0x6c9ee0e5 , 0x97acfbad , 0xc8c59272 , 0x13cd2b86 , 0x9644d4db , 0x362dc251 , 0x279347c9 ] )
+  , Mont::unsafe_make( [ 0x6ae42f22 , 0x517034b7 , 0x00fc8302 , 0xefa23fcf , 0x9dd38509 , 0x9a3171c4 , 0x5f21ab11 , 0x00c18887 ] )
+  , Mont::unsafe_make( [ 0x9f0ca647 , 0x3babbf88 , 0xd6b15119 , 0xcc511806 , 0x3287845d , 0xb06cd45c , 0x81b66c7d , 0x13424fd4 ] )
+  , Mont::unsafe_make( [ 0xe5a5c86a , 0x99b1bfe3 , 0x09af8abe , 0x3f3359e6 , 0x9c11544b , 0x6a4495cc , 0xcf4efefc , 0x08ff53d3 ] )
+  , Mont::unsafe_make( [ 0xec134864 , 0x64c0daab , 0xbf236721 , 0xcc915a2d , 0x2ddcff59 , 0x6475bc2b , 0x18cab58e , 0x17c723fc ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x79281e38 , 0xa3c14909 , 0x575bb565 , 0xeda18807 , 0xf26c25fc , 0x030f40df , 0x0520b8bf , 0x0a4e75c5 ] )
+  , Mont::unsafe_make( [ 0x11cf5344 , 0x10bad4f9 , 0x552154fa , 0x9f6f6cc9 , 0x27e105fd , 0xdbe09867 , 0xf047a2a2 , 0x1c3fb468 ] )
+  , Mont::unsafe_make( [ 0xa8745d0e , 0xa6cc1789 , 0x35a9c991 , 0xe9273a08 , 0xc87797ef , 0x0dac9e6d , 0x8bd39d0c , 0x0a0c3fae ] )
+  , Mont::unsafe_make( [ 0xe53c73ce , 0x4125a066 , 0x766a6a01 , 0x2953355d , 0xd331c6a3 , 0x3e7c2f91 , 0x1b882016 , 0x2d449237 ] )
+  , Mont::unsafe_make( [ 0xf4faba79 , 0x65e98241 , 0x8b6c0198 , 0x4d082bd9 , 0x75425d2a , 0x2141584f , 0xe4f66665 , 0x20d80078 ] )
+  , Mont::unsafe_make( [ 0x279962c8 , 0xc9dee282 , 0x76b6a169 , 0xf6372bae , 0x184f65f9 , 0x3f4bd9da , 0xf0bbab3a , 0x1f2e36b2 ] )
+  , Mont::unsafe_make( [ 0x0d25e1bd , 0xe7ef00e1 , 0x92ff8736 , 0x23e3d336 , 0x821cec52 , 0x70bd11a4 , 0xfc020ddf , 0x10a10044 ] )
+  , Mont::unsafe_make( [ 0x1703f329 , 0x43462a7c , 0x3b6db254 , 0x0529bc44 , 0xac0b9b4a , 0x88fce193 , 0xb6891a99 , 0x0706daab ] )
+  , Mont::unsafe_make( [ 0x3a86e33c , 0x379e5b4e , 0xf872b23a , 0xe6ec582c , 0xb7be8605 , 0xa36bfffd , 0x82c1386c , 0x1d3c1916 ] )
+  , Mont::unsafe_make( [ 0xc3e66b50 , 0x5329f000 , 0x7d31a1ba , 0x00d3973a , 0x59c4e455 , 0x1781a698 , 0x4a14df58 , 0x207a7dd5 ] )
+  , Mont::unsafe_make( [ 0x697556ad , 0x7b3b638b , 0x281fbcce , 0xf715dd5b , 0x2530643b , 0xf35e5138 , 0x695b3004 , 0x0f1e9a33 ] )
+  , Mont::unsafe_make( [ 0xfc3919d0 , 0x590395dc , 0x52e84c43 , 0x0590f0be , 0x6b0dd479 , 0x26eada45 , 0xe51bf0b6 , 0x02096f1b ] )
+  , Mont::unsafe_make( [ 0xdbb1deda , 0xb3edb2fc , 0x947e8a4e , 0xfdef168e , 0xef2bcd54 , 0x88f2c463 , 0x24096287 , 0x29b93c43 ] )
+  , Mont::unsafe_make( [ 0x73adba8b , 0xc68e1b55 , 0x0af3ebef , 0x3ab33080 , 0x1ad9121c , 0xa3b1df4a , 0xad9317c3 , 0x0d0dc1e3 ] )
+  , Mont::unsafe_make( [ 0x8e5f6e2a , 0x2a813635 , 0xcbc8b606 , 0xb974a2ad , 0x11be7467 , 0x5887d657 , 0x21c150a8 , 0x0a94fece ] )
+  , Mont::unsafe_make( [ 0x9e808797 , 0xd5ead05a , 0x8218a1bf , 0xd2fdc338 , 0x4fdd55fe , 0xc308a5ed , 0x5ea5992c , 0x29c88e97 ] )
+  , Mont::unsafe_make( [ 0x605d4976 , 0x4efe4a1e , 0xd72ecbf1 , 0x59c3645b , 0x45f67fa9 , 0x5336054f , 0xc67280d7 , 0x2df2e453 ] )
+  , Mont::unsafe_make( [ 0xa1e835f7 , 0x8b2f926e , 0xe26d3f1b , 0x42aae424 , 0x54bf3518 , 0x2a467b7f , 0xf8741baf , 0x0ffa891a ] )
+  , Mont::unsafe_make( [ 0x326fae4c , 0xdfd5ff30 , 0xee114057 , 0x4a16edf9 , 0xeeb46510 , 0x58c84597 , 0x3d85ea74 , 0x171c74a3 ] )
+  , Mont::unsafe_make( [ 0x2840bb86 , 0xc56f86d9 , 0xd359fcba , 0x1799f4cd , 0xb8e25c96 , 0x92edc45e , 0x25bbc989 , 0x1cce09c3 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x167fd01d , 0x69c6846e , 0x5d600edd , 0xcbe69ff4 , 0x025adcd7 , 0x25a8a258 , 0x696b3f8f , 0x209d348c ] )
+  , Mont::unsafe_make( [ 0xff8d9446 , 0xda62c7fe , 0x8090c674 , 0xad43c7b3 , 0x099cbf87 , 0x9b48a0d2 , 0x00903a09 , 0x15e885ac ] )
+  , Mont::unsafe_make( [ 0x910e5c15 , 0x69fe35b4 , 0x9a427416 , 0x3e636c73 , 0x10616601 , 0xfdca3646 , 0xe9179235 , 0x244aced2 ] )
+  , Mont::unsafe_make( [ 0xf4cca071 , 0xb32d5ad5 , 0x7c718f1b , 0x9f1f03d0 , 0xbbd9c9d1 , 0x14909c0f , 0x26a533da , 0x302c5e2c ] )
+  , Mont::unsafe_make( [ 0x147750a8 , 0xc491861c , 0x26566bbb , 0x160d1bc7 , 0x8cb317db , 0xe1645877 , 0xece7641d , 0x0e08f2ce ] )
+  , Mont::unsafe_make( [ 0xd8a0cef4 , 0xe36ec798 , 0x337e5569 , 0x8386d234 , 0x2665a399 , 0xc652f0dd , 0x42cbb202 , 0x02169446 ] )
+  , Mont::unsafe_make( [ 0xef91c404 , 0x0344b6e9 , 0x111a17b1 , 0xf3ed523e , 0x0ad45786 , 0x3e997df7 , 0x081dbcaa , 0x03f69fd8 ] )
+  , Mont::unsafe_make( [ 0x0da9bc17 , 0x22a65f90 , 0x78e096b2 , 0xf5be1637 , 0x6bf60329 , 0x63e00e5a , 0xeeb3fc23 , 0x251211c1 ] )
+  , Mont::unsafe_make( [ 0xcf809194 , 0x75784ca6 , 0xcca9dc5f , 0xf1e33b43 , 0x1ce56c52 , 0xc0051ce0 , 0x2da40d3e , 0x19b2965d ] )
+  , Mont::unsafe_make( [ 0xf9bd7b21 , 0x73f97a68 , 0xb038cbbc , 0x8a713b84 , 0x91abc8ff , 0x15509f0b , 0x53e90321 , 0x15c3464c ] )
+  , Mont::unsafe_make( [ 0xb40d9f79 , 0x68869c7a , 0x67b6cf79 , 0xc43d6531 , 0xfda90ab0 , 0x699731a4 , 0x38447a20 , 0x0bbe28d2 ] )
+  , Mont::unsafe_make( [ 0xdbde200c , 0xc789625d , 0x982751ab , 0x646a0525 , 0xe32f1656 , 0x81e4cd5d , 0x9aee860e , 0x21ad5448 ] )
+  , Mont::unsafe_make( [ 0x6024a194 , 0xb4365902 , 0xea87aa59 , 0xee687097 , 0x39127514 , 0xa717ebfb , 0x3d041b74 , 0x27d9528d ] )
+  , Mont::unsafe_make( [ 0x120aa322 , 0xce2a044c , 0x8c3eebd0 , 0x6c45e7ce , 0x808c8235 , 0xeeb70ba0 , 0x9b3d5348 , 0x27e2defc ] )
+  , Mont::unsafe_make( [ 0xc5addae1 , 0x784d2296 , 0x608558af , 0x8d82eeea , 0x5470fd60 , 0x287d42cc , 0xa6ea0319 , 0x2bc85ce1 ] )
+  , Mont::unsafe_make( [ 0xf9afb9c0 , 0x9f0b9d29 , 0x44f1606b , 0x2cd3a025 , 0xea91d500 , 0xafb5ecbf , 0x5e7ec128 , 0x2b14d147 ] )
+  , Mont::unsafe_make( [ 0xe39ed365 , 0x969fbeb1 , 0x90dfb4a5 , 0x4b2327f3 , 0xd76fef89 , 0x51dfb3c9 , 0xd612a931 , 0x2aaa25ff ] )
+  , Mont::unsafe_make( [ 0x84219183 , 0xa9018b29 , 0x3f4f231f , 0xc324e62d , 0xd9cde381 , 0xcab9f607 , 0x88baa539 , 0x2497369b ] )
+  , Mont::unsafe_make( [ 0xe56a472a , 0xcfe557f7 , 0xd1ec1481 , 0x5ab82dfb , 0x41e6abc8 , 0x8c262652 , 0xd9240334 , 0x165a08ce ] )
+  , Mont::unsafe_make( [ 0x07947d6d , 0x127c9fcf , 0x75749022 , 0x6713cbd4 , 0x9ae274e7 , 0xa6df37cf , 0xf124df00 , 0x2cd6bcba ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x09d18a67 , 0x4cf10c4f , 0x95f318a8 , 0x950b4368 , 0x7177fb82 , 0x266f1f5d , 0x319895ef , 0x2942a9a6 ] )
+  , Mont::unsafe_make( [ 0xc62212e8 , 0x1c01e80d , 0x87e36864 , 0xdfb6a40d , 0x02afc51d , 0xd9d2053c , 0xbf020aa4 , 0x17c89921 ] )
+  , Mont::unsafe_make( [ 0x5cb31f81 , 0x0eac4614 , 0xffbe6a42 , 0x5189ed85 , 0xe559f8cb , 0x55a8f39d , 0x38705ce9 , 0x01b9cea6 ] )
+  , Mont::unsafe_make( [ 0x0bdf7679 , 0xdd39aad3 , 0x816dc55d , 0x44887959 , 0x0039b1a3 , 0x51d582c4 , 0x7512790f , 0x15dc514b ] )
+  , Mont::unsafe_make( [ 0x613f6b58 , 0x3d5fd15a , 0x425de16d , 0xdb9bf20e , 0x3e8498f6 , 0x7d9132a2 , 0x3396d3a7 , 0x24eefc7f ] )
+  , Mont::unsafe_make( [ 0x3f294eaa , 0x443b15b6 , 0x8f58af7e , 0x6638e16c , 0x40c6003c , 0x331655dc , 0x2f09f2bd , 0x1fa3b3c3 ] )
+  , Mont::unsafe_make( [ 0xbfda6a61 , 0xd5d301f3 , 0xd9b19aa7 , 0x866d40d7 , 0x6e7d04a9 , 0x5c65b0db , 0xe98c9604 , 0x1929bcbf ] )
+  , Mont::unsafe_make( [ 0xe564a460 , 0xf821af62 , 0xac169cf5 , 0xf09c54f6 , 0x892c4c37 , 0xfc391670 , 0xec02c6d8 , 0x1a83e7d6 ] )
+  , Mont::unsafe_make( [ 0xd0818551 , 0x139f836e , 0xe1ddae9b , 0x1947eaf2 , 0xc0ac8ce5 , 0xfceb01a4 , 0x69049594 , 0x28d18b8d ] )
+  , Mont::unsafe_make( [ 0xe9ab7854 , 0x95ab6c54 , 0x5c55f020 , 0x4be6f7fa , 0x2aee786e , 0xe954682a , 0xc96e6f6b , 0x27ac2e54 ] )
+  , Mont::unsafe_make( [ 0x7f4dee19 , 0x5525cacb , 0xf9613f6a , 0xc027ce54 , 0x72dc7d58 , 0xed5fa67e , 0xddc1c746 , 0x133eae51 ] )
+  , Mont::unsafe_make( [ 0x4c9b74e5 , 0x14dbdd0c , 0xb2d98dfc , 0xb8f6c54b , 0xa1cbac7c , 0x271896ed , 0xebc1ed30 , 0x30520966 ] )
+  , Mont::unsafe_make( [ 0x92a06096 , 0x3d898e86 , 0x69c7c692 , 0x18eb9ae5 , 0x85843464 , 0x4fe3904f , 0x18c04d64 , 0x24a31535 ] )
+  , Mont::unsafe_make( [ 0x51ee676a , 0x6f43bc14 , 0xe09a1f29 , 0xd905de98 , 0xb32c7287 , 0x6d67895c , 0xb2a4a022 , 0x0fea6691 ] )
+  , Mont::unsafe_make( [ 0xb47a9ba4 , 0x542c7acc , 0x09e3d69a , 0xe0f59128 , 0xd9d643e3 , 0x9c8f43f2 , 0xcf04beef , 0x06bc76ca ] )
+  , Mont::unsafe_make( [ 0x07449f76 , 0xcd52fd34 , 0x07877aa8 , 0xe8fe8961 , 0x662f039d , 0x3287834d , 0xa1204b54 , 0x057974fb ] )
+  , Mont::unsafe_make( [ 0x240bea8f , 0xeebec431 , 0x22b70671 , 0x8ef1dcf6 , 0x148c3449 , 0x6d47b922 , 0xfc2447eb , 0x0977bef1 ] )
+  , Mont::unsafe_make( [ 0xba411e06 , 0x2a72bb27 , 0xfbf3f165 , 0xe23454eb , 0x21cf7610 , 0xd476e48b , 0x50a40f0d , 0x102bfd9b ] )
+  , Mont::unsafe_make( [ 0xbdf71813 , 0xa3a07101 , 0xef092778 , 0x89c35070 , 0xca670db8 , 0x27e23d60 , 0x22470651 , 0x2654437a ] )
+  , Mont::unsafe_make( [ 0x19ba5dba , 0x1f62e0c0 , 0xe9ecbe5e , 0x8b7c058c , 0x49b30387 , 0x345a5839 , 0x79bacb18 , 0x16bdeb01 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x338524aa , 0xd65772e7 , 0xdbec4029 , 0x5cacc0e6 , 0x5db3fa20 , 0xbc1ae641 , 0x85c2083d , 0x286da052 ] )
+  , Mont::unsafe_make( [ 0xb7f45976 , 0xf61af44c , 0x23f8657c , 0x5ceec1d3 , 0xbb1870cb , 0xbbb3bab6 , 0x12ca4aa9 , 0x2b66558e ] )
+  , Mont::unsafe_make( [ 0x18d7ce98 , 0xa333f6d8 , 0xea043637 , 0x1ee4c133 , 0xcbc81693 , 0x64ed586b , 0x761aeec5 , 0x2bda0a3b ] )
+  , Mont::unsafe_make( [ 0x792783af , 0xd1b1053a , 0x68214002 , 0x6fb23ba6 , 0x529660f5 , 0xd9431f54 , 0x2cf58873 , 0x08fe90f3 ] )
+  , Mont::unsafe_make( [ 0xf5d0df97 , 0xa0ee04de , 0x47833117 , 0x40b1e7ae , 0xa4d054dc , 0xf54e60bc , 0x4c0519ab , 0x0b382448 ] )
+  , Mont::unsafe_make( [ 0x0b741e9f , 0x7a2d8e65 , 0x07cd34bf , 0xc569d8df , 0xf58939d0 , 0x9759e3e8 , 0x5a8a5b1c , 0x2050a4b2 ] )
+  , Mont::unsafe_make( [ 0x0d7728b5 , 0x7c036f36 , 0x908a1cf1 , 0xc690b8c9 , 0x08109d8c , 0x1c8eb576 , 0x0b406723 , 0x182a5aac ] )
+  , Mont::unsafe_make( [ 0x7f329945 , 0x41038dd7 , 0x4136ced7 , 0x682db1ba , 0x56f14617 , 0x4f4d12a8 , 0xe7e3acf5 , 0x0d4869c7 ] )
+  , Mont::unsafe_make( [ 0x416ad8be , 0x59f3ca80 , 0x89f3b432 , 0x8e1d324c , 0xdd39eabd , 0xb830dcb7 , 0x0d3d1011 , 0x1ffa38ca ] )
+  , Mont::unsafe_make( [ 0xc547f601 , 0xdce2ecdf , 0x9a73a58f , 0xc4dbe5a0 , 0xdb1690c9 , 0xe2a23cb1 , 0x7063a740 , 0x23b4e246 ] )
+  , Mont::unsafe_make( [ 0x8014fd44 , 0x79146802 , 0x1ec81a53 , 0xe926be46 , 0xe62aff9b , 0x8c9f3650 , 0x2e79d6a2 , 0x1cec9296 ] )
+  , Mont::unsafe_make( [ 0x1d029090 , 0x0396ee5b , 0xd8159d28 , 0xe545b65f , 0x94305225 , 0x6ca8ab68 , 0x1bbd3531 , 0x009705dd ] )
+  , Mont::unsafe_make( [ 0xfae3eac6 , 0x9bce3c59 , 0x42731d16 , 0xf6c36d95 , 0xa45fc766 , 0x9bf0d539 , 0xf70edf3d , 0x119ab9f9 ] )
+  , Mont::unsafe_make( [ 0xb4fa985a , 0x19799518 , 0x98f02da5 , 0xcc558720 , 0xd0ae2132 , 0xed5022d3 , 0xd8b85cac , 0x2e7165e9 ] )
+  , Mont::unsafe_make( [ 0x03af33cb , 0xa4bfc3bf , 0x18020fdb , 0xa0d144c5 , 0xf16dee7f , 0xd0a5d09c , 0x846d0afb , 0x1f54983d ] )
+  , Mont::unsafe_make( [ 0x03c391b2 , 0xc85b6da8 , 0x99dcd826 , 0x04bd53cb , 0x56f5ee44 , 0x4af6beaf , 0x2aec8ec9 , 0x269fcacd ] )
+  , Mont::unsafe_make( [ 0x13f9a692 , 0x5975bb38 , 0xb6215e52 , 0x9f4664d5 , 0x30093ff0 , 0x08e15383 , 0xb5c33589 , 0x0c65a597 ] )
+  , Mont::unsafe_make( [ 0xb364989d , 0x4a825ca1 , 0xcd060491 , 0x362abe7a , 0xc99a5da9 , 0x73a2d5bc , 0x27146b97 , 0x13ad7082 ] )
+  , Mont::unsafe_make( [ 0x39177550 , 0x4f66348e , 0xb7c3f333 , 0x2138479a , 0x68cbf9ad , 0x0b7f2ce6 , 0x6a2ca2ab , 0x2c2c35ef ] )
+  , Mont::unsafe_make( [ 0x83f0d9ba , 0x950068ef , 0x1966b6c5 , 0xf15d11c1 , 0xfc5758d2 , 0xce884c89 , 0x47bf61ed , 0x301fb672 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x5aaa33b5 , 0x3b782786 , 0x6ad408e8 , 0x802e3d0a , 0xf6ce528c , 0xd1bbdbac , 0xce29bb2a , 0x28560e9b ] )
+  , Mont::unsafe_make( [ 0xbbe943f3 , 0xdd1ef7d4 , 0x47ee84cf , 0x287aee8c , 0x22a295ee , 0x9204bea4 , 0xb101946b , 0x031ec7d5 ] )
+  , Mont::unsafe_make( [ 0x2b4f7827 , 0x719b6f63 , 0x956f3c3a , 0x3fdbc17a , 0x964ba180 , 0xd137e33a , 0x1fbf8292 , 0x27d18edc ] )
+  , Mont::unsafe_make( [ 0x51ae6f60 , 0x3dc358f8 , 0x47b84d75 , 0xe169d0af , 0xde8f870f , 0x427c650b , 0x01690850 , 0x04cea779 ] )
+  , Mont::unsafe_make( [ 0x4c6366b9 , 0x910f5f68 , 0xed6eec68 , 0x25e2e6be , 0x07034e4d , 0x2d541208 , 0xa654edc3 , 0x2d456d65 ] )
+  , Mont::unsafe_make( [ 0xe6afe017 , 0xe937def0 , 0x86bc9594 , 0x0a0f289a , 0x2e65a9f2 , 0x218182b8 , 0x0e2b7906 , 0x2fb53ec0 ] )
+  , Mont::unsafe_make( [ 0xb6a40ec6 , 0x55eefee3 , 0x6b13576f , 0x8cdf4196 , 0x3264fc6d , 0xff9a9e39 , 0x53574864 , 0x0bcd0302 ] )
+  , Mont::unsafe_make( [ 0xc9d1dac7 , 0x2bdaf624 , 0xc78a0b6f , 0x807d0fb9 , 0x27ac78d2 , 0x2bebd724 , 0xaee10a5c , 0x02baf762 ] )
+  , Mont::unsafe_make( [ 0x46338f91 , 0x3175d47c , 0x67947fba , 0x58cf3b98 , 0x014061fc , 0x82759da8 , 0x88171c32 , 0x1d51fc50 ] )
+  , Mont::unsafe_make( [ 0x5ab02902 , 0x165f4a59 , 0x3b6b3022 , 0x4732b0f4 , 0x9ddfaee8 , 0x87af85c9 , 0xef28e2d7 , 0x08f9ad45 ] )
+  , Mont::unsafe_make( [ 0x9f869abf , 0x95ce15f3 , 0xc8851a33 , 0x414fafa6 , 0x584a9d69 , 0xbd1c2ce6 , 0x38a8a4a6 , 0x24741036 ] )
+  , Mont::unsafe_make( [ 0xc82348cd , 0xae2ddf48 , 0x8acd9a3d , 0xedcb5da9 , 0x00cc43fd , 0xa340b8fb , 0xd095baaf , 0x16dc78f6 ] )
+  , Mont::unsafe_make( [ 0x0b325663 , 0x43bf7cdc , 0x8564d125 , 0x8ed2d8ee , 0xf7431871 , 0x5e7b1c5d , 0x7f45172a , 0x03a758df ] )
+  , Mont::unsafe_make( [ 0x591ef0fc , 0xa73c3cff , 0xf21e9faa , 0x9db17035 , 0xcf5b741c , 0xa952df45 , 0x2cdc52df , 0x034039e3 ] )
+  , Mont::unsafe_make( [ 0x43ee5078 , 0xdb39e8e7 , 0xe377b7c2 , 0x3f4be2a6 , 0xcbe8900a , 0x4ca3a1be , 0xab5e1408 , 0x0135246f ] )
+  , Mont::unsafe_make( [ 0x4d07eb22 , 0xf729dc6b , 0xc35393de , 0xb5271ed2 , 0x648b1342 , 0x0e9e448b , 0x5ef1c83c , 0x1f55b638 ] )
+  , Mont::unsafe_make( [ 0x99009471 , 0x1038e7f6 , 0xf1a0e8dc , 0x8e532d68 , 0xe36ee05a , 0x2ae82fe9 , 0xc2f39164 , 0x188f3770 ] )
+  , Mont::unsafe_make( [ 0xc979ea37 , 0x51c4ee25 , 0x9326eb7d , 0x2c7e6a2a , 0x92edea81 , 0x612471a1 , 0x32c6afec , 0x279f6ebb ] )
+  , Mont::unsafe_make( [ 0x17c1af95 , 0x9dae2a5f , 0x06cf27c0 , 0x938b91a9 , 0xabf596f2 , 0xec0954cb , 0xe10b447c , 0x06f9e7f5 ] )
+  , Mont::unsafe_make( [ 0xeedb8d6d , 0x257257e9 , 0x1679bd03 , 0xed0d4248 , 0x90e03edb , 0xbccfe1ec , 0x4f9fda82 , 0x07618fa6 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xf74538ce , 0x2a1aad96 , 0x3e4490bf , 0x5c5a6c27 , 0x193235c8 , 0xc405d9dd , 0x18b906ef , 0x025e7de4 ] )
+  , Mont::unsafe_make( [ 0x07ece5f0 , 0x05d70f23 , 0x9a1ce353 , 0x1b64cf5f , 0x4975752a , 0xff7b007c , 0x16e68ea4 , 0x0bb2ee01 ] )
+  , Mont::unsafe_make( [ 0x4df2448f , 0x8895a30b , 0x513b3b1c , 0x7e6d0389 , 0xc5b64909 , 0xaa335be4 , 0x40db207e , 0x0b1dfdee ] )
+  , Mont::unsafe_make( [ 0x3887af55 , 0x07758005 , 0x0bf403a0 , 0xe5d703f8 , 0x49108b82 , 0x3d1dadf7 , 0xd402040d , 0x2b442e75 ] )
+  , Mont::unsafe_make( [ 0xb21c226e , 0x08cc12c1 , 0x462ea559 , 0xc96bf117 , 0xad68fffc , 0x82f02bfd , 0x5bc78565 , 0x21ad2b33 ] )
+  , Mont::unsafe_make( [ 0x54af4260 , 0x781cd15d , 0x65e830f5 , 0xeb5bac31 , 0x443bea70 , 0xf97e933e , 0xdde2a656 , 0x10b4f771 ] )
+  , Mont::unsafe_make( [ 0xdf37d918 , 0x1b29fe1f , 0x09485edd , 0x5ea250fd , 0xbc9d7ed5 , 0x6b68b670 , 0xef7e5285 , 0x228fc637 ] )
+  , Mont::unsafe_make( [ 0x68c0c7d0 , 0x8f39f879 , 0xa5494876 , 0xf9337940 , 0xa3437c29 , 0x89ef6dbb , 0xdffb57b6 , 0x16094b5f ] )
+  , Mont::unsafe_make( [ 0xaa7e583e , 0x69bf5af0 , 0xd5dd7659 , 0x25935061 , 0xb61d9e36 , 0x744abd46 , 0x41ea582d , 0x0210893d ] )
+  , Mont::unsafe_make( [ 0xba3411be , 0x33973195 , 0xc33fda10 , 0xb2405899 , 0x217cb318 , 0x8b547379 , 0x57ad65fe , 0x0ecf35a3 ] )
+  , Mont::unsafe_make( [ 0x09bd679e , 0x38170201 , 0xf6f0622b , 0xc24258b4 , 0x44ed8353 , 0x334aef48 , 0xc1844ac8 , 0x05134e35 ] )
+  , Mont::unsafe_make( [ 0xbc2e154c , 0xe5393fc5 , 0x7b137796 , 0x96bbc0b1 , 0x34ffe90b , 0x0f81eb42 , 0x263ff16a , 0x06673be6 ] )
+  , Mont::unsafe_make( [ 0x5438a3b4 , 0x1094c878 , 0xe9d4d732 , 0x57cf5e3d , 0xe7d44782 , 0x4bb65a29 , 0x08b1f807 , 0x25806e47 ] )
+  , Mont::unsafe_make( [ 0x98b03e37 , 0x9cc823a8 , 0xd3b22790 , 0xb3134dc0 , 0x0094077e , 0xdb51d771 , 0x8986fef4 , 0x245556c9 ] )
+  , Mont::unsafe_make( [ 0xa2fb2b09 , 0x68050bd3 , 0xb00c3330 , 0x62bb57d1 , 0x3e53e4df , 0xd794efe0 , 0xe5fa0660 , 0x1cdd1af3 ] )
+  , Mont::unsafe_make( [ 0xebc20c30 , 0xdee48ece , 0x38d3558a , 0x7ad81372 , 0x2ef3b190 , 0xca14a74e , 0x7ea6a2c9 , 0x25af0dfc ] )
+  , Mont::unsafe_make( [ 0x05c86f56 , 0x67df1013 , 0x8a6bcf17 , 0x6dd7fc03 , 0xcd309d0b , 0x576637bf , 0xb1dc8670 , 0x1fecfa35 ] )
+  , Mont::unsafe_make( [ 0xacbe0094 , 0x5e839ec9 , 0xe2f2b3d0 , 0x7313c901 , 0xcc644f72 , 0x80e5ae5a , 0xd7565cad , 0x0dacbb87 ] )
+  , Mont::unsafe_make( [ 0x2f8e76ae , 0x4de5487e , 0xca49b374 , 0x58730007 , 0x7d7648a9 , 0x8802a5f3 , 0xf8cbad26 , 0x20d20949 ] )
+  , Mont::unsafe_make( [ 0xeab368eb , 0xa0389b54 , 0x41725b46 , 0x82fa225e , 0xe5721ee7 , 0xc76717e1 , 0x47f82f5f , 0x2c06e385 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xfed77642 , 0xa94b4fb9 , 0xe5468a86 , 0xd62f7c06 , 0xf1f23c1f , 0x556a2831 , 0x7c71982d , 0x223860c0 ] )
+  , Mont::unsafe_make( [ 0xb344aa00 , 0xaa3e564e , 0xaf05c2b1 , 0x86cb0441 , 0x85b85fed , 0x86526cbf , 0x6fa1fdfc , 0x1fd5d246 ] )
+  , Mont::unsafe_make( [ 0x54eeacc2 , 0x5f8d32b1 , 0x4ccdd38a , 0xadcacb05 , 0xad3f4ee2 , 0x8f2400b5 , 0xace202d1 , 0x20e2613b ] )
+  , Mont::unsafe_make( [ 0x920b90ba , 0x1db7cb09 , 0x4c62419b , 0x32cbe8e4 , 0xce08eab7 , 0x1b88560d , 0xccb3cd49 , 0x1720138c ] )
+  , Mont::unsafe_make( [ 0x92650644 , 0xc8a66e58 , 0x9568e68d , 0x852ffa97 , 0x20faa7bd , 0xf0302de4 , 0xac516ee1 , 0x04dd7393 ] )
+  , Mont::unsafe_make( [ 0x53272f25 , 0xc755f9cb , 0x08ccb149 , 0x85821b08 , 0xf2d4772f , 0x5016e984 , 0x56bf44bd , 0x1af79989 ] )
+  , Mont::unsafe_make( [ 0xf749237d , 0xd7653ad7 , 0x725530a4 , 0xd49f4c19 , 0xe55d12b6 , 0x2c23c795 , 0x74052027 , 0x05e40643 ] )
+  , Mont::unsafe_make( [ 0x8b90a2b5 , 0x91f02d3e , 0xb68c5f2c , 0xc354ed1f , 0xaca1468b , 0x6c28e7fe , 0x3c424a37 , 0x15308d6f ] )
+  , Mont::unsafe_make( [ 0xd77211ad , 0xd3400704 , 0xffd68c23 , 0x5d67c4c8 , 0x46642d2f , 0x94f19a22 , 0x06f838a5 , 0x2d19c468 ] )
+  , Mont::unsafe_make( [ 0x5f36bfb9 , 0x61a3ad19 , 0x33c6189c , 0x933daad0 , 0x523ea639 , 0x4bac8146 , 0xc06077f3 , 0x0f5ee79a ] )
+  , Mont::unsafe_make( [ 0x928b5743 , 0x0a6c6d87 , 0xc98db33b , 0xef8f0061 , 0x81b23feb , 0x48a04fed , 0xb104e9c9 , 0x12c220c8 ] )
+  , Mont::unsafe_make( [ 0xff88eb68 , 0x4dddf279 , 0x5caf7768 , 0x3d3607e3 , 0xb780bdd7 , 0xbe4e8a84 , 0xc13c2427 , 0x225a7dbd ] )
+  , Mont::unsafe_make( [ 0x3515beea , 0xaf5af732 , 0x882516e0 , 0x09110062 , 0x4ab9a11c , 0xf9be8fc7 , 0xa316a138 , 0x0418979b ] )
+  , Mont::unsafe_make( [ 0xd2dc4fde , 0xb3347831 , 0xfafd31e4 , 0x39ab3b09 , 0x3150544b , 0x12c354f7 , 0xda76a7b7 , 0x1df8560b ] )
+  , Mont::unsafe_make( [ 0x7a3a28b7 , 0x9d114e60 , 0x72e59bb7 , 0x6151d3ab , 0xd13f211f , 0x35a870a3 , 0x85fb5ff0 , 0x025737d8 ] )
+  , Mont::unsafe_make( [ 0x760a8197 , 0xf286fe34 , 0x02efc52b , 0x1f0f129c , 0x7f890894 , 0x9b475ae5 , 0xedefb9f8 , 0x2f976de7 ] )
+  , Mont::unsafe_make( [ 0xc94b927c , 0x7e169b5b , 0x8ed9a826 , 0x98c7ac3d , 0x9d218ff2 , 0x1b250f39 , 0x6c3e92de , 0x0e02792f ] )
+  , Mont::unsafe_make( [ 0xac3257a2 , 0xdf62d3ef , 0xf2764b3e , 0xd4d55557 , 0xac6bec77 , 0xbdde5333 , 0x4c6125f0 , 0x289e5044 ] )
+  , Mont::unsafe_make( [ 0x0024333b , 0x2c79ef1d , 0x080da269 , 0x77aa9168 , 0x7751556b , 0xfa210d32 , 0x97a66d3a , 0x168cccd7 ] )
+  , Mont::unsafe_make( [ 0x349d5383 , 0x4963f1e4 , 0x7a3e55b6 , 0xb658ce84 , 0xa1a4d16b , 0xc3099f30 , 0x88578c6f , 0x2a35cc19 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xe340d04c , 0xb0393427 , 0x75853108 , 0x9c6edfb1 , 0x04a5cf35 , 0x3131ec28 , 0x6f972d85 , 0x0e05fc91 ] )
+  , Mont::unsafe_make( [ 0x2697bf67 , 0x9bcd3fdc , 0x8bd7037b , 0x60c77b3e , 0x91736380 , 0x27b715e5 , 0x72993c07 , 0x23f41b8f ] )
+  , Mont::unsafe_make( [ 0x24dfef82 , 0x87449eaa , 0x8a514f50 , 0xc64ae5b4 , 0xc5cc6770 , 0x3ac5d497 , 0x76e0f9f3 , 0x2a861329 ] )
+  , Mont::unsafe_make( [ 0xc75fc8e3 , 0x5bc6695b , 0xfb675628 , 0x7a469708 , 0x3d124197 , 0x1c4ebb5e , 0xd6114574 , 0x0f0d913a ] )
+  , Mont::unsafe_make( [ 0x724e3ad0 , 0x8e35eade , 0xdde205a3 , 0x3485e0ba , 0xa2c8ec05 , 0x8636f6d0 , 0xf69367e1 , 0x17f492ee ] )
+  , Mont::unsafe_make( [ 0xe4811c3e , 0x2f33a0ea , 0x0d88fdb0 , 0x901068c4 , 0x1e3bd8c3 , 0xacf355eb , 0x92b31289 , 0x1263ed54 ] )
+  , Mont::unsafe_make( [ 0xae2b12f6 , 0x5cfa1bbe , 0x5b3d7087 , 0xe50a6b51 , 0xf47e2495 , 0x5db01a03 , 0xf8e6aedc , 0x2ae26cbb ] )
+  , Mont::unsafe_make( [ 0xdb6603fa , 0x3f675875 , 0x6d787dde , 0x8890fcfc , 0x7cfb2e14 , 0x057c35a3 , 0x62449e44 , 0x09d967b9 ] )
+  , Mont::unsafe_make( [ 0xa13c7ba3 , 0x626b342a , 0xe688b1c8 , 0xb58bda31 , 0x5628af10 , 0xb9714125 , 0x33a5be4f , 0x23a1f3b2 ] )
+  , Mont::unsafe_make( [ 0xf51c0c80 , 0xe0e5167e , 0x4a8bfe38 , 0xd35d52c6 , 0x78f24c17 , 0x5a72e8b4 , 0x5e8a6964 , 0x20c4b28a ] )
+  , Mont::unsafe_make( [ 0xb89e0044 , 0xb0764592 , 0xea789438 , 0xff4bc0a0 , 0x7d849b50 , 0x658846e2 , 0x6eb8e23f , 0x03f43433 ] )
+  , Mont::unsafe_make( [ 0x24308943 , 0xe52d5312 , 0x35110100 , 0x0ba65ad7 , 0x0538ef0e , 0x35da948f , 0x030d383d , 0x0135674c ] )
+  , Mont::unsafe_make( [ 0xde1224cd , 0x69578742 , 0x095cfac7 , 0x3a3a0c4f , 0x21d3b5b0 , 0xbcbce4c4 , 0xc85475e3 , 0x0580dc8b ] )
+  , Mont::unsafe_make( [ 0x073d4dd7 , 0xf293d5f6 , 0xe581d2e5 , 0xe270c036 , 0xd5f32c66 , 0xe82b3f07 , 0x55f980e5 , 0x2ae7342a ] )
+  , Mont::unsafe_make( [ 0x57b110d9 , 0x1e091bc4 , 0x67804e19 , 0x936490d8 , 0x503924ce , 0x8652f4a2 , 0xa239a69d , 0x12387be6 ] )
+  , Mont::unsafe_make( [ 0x66b1b40a , 0x7bec6683 , 0x77395905 , 0x9bc9a895 , 0x471f7523 , 0x83ab1c7f , 0x7230ebe9 , 0x1ee90646 ] )
+  , Mont::unsafe_make( [ 0x46bde530 , 0xd6de4faa , 0x413f7dc9 , 0x68a9a4dc , 0xf5512ba4 , 0xa524a4b8 , 0x4b2c0b79 , 0x2689ad4a ] )
+  , Mont::unsafe_make( [ 0x8d031ae5 , 0xa268d2fd , 0x132c1433 , 0x135a9090 , 0x4e89d34e , 0x23905791 , 0xffb97100 , 0x11d3aa8e ] )
+  , Mont::unsafe_make( [ 0x229b523e , 0xd0247dbd , 0x47c540fa , 0x4f5012fc , 0x6e06cd96 , 0x44caae4b , 0xf361ecc7 , 0x2d4eaceb ] )
+  , Mont::unsafe_make( [ 0x9ed339f7 , 0x6673e62b , 0xecf48f99 , 0x2082f5c3 , 0x54b5a361 , 0x7bd3afe1 , 0x2cba6f30 , 0x08cb8b68 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x8a431811 , 0x54ccd30e , 0xde302c53 , 0xb99bcc9a , 0x5dea759f , 0x20e60dd5 , 0xed65635e , 0x1cc688f9 ] )
+  , Mont::unsafe_make( [ 0xa8aa1d59 , 0x8df107c5 , 0x972e20e1 , 0x0ee41e7c , 0x5b53e024 , 0x7f2b22ae , 0xfa68442e , 0x18b04c7c ] )
+  , Mont::unsafe_make( [ 0x5d6af4c4 , 0x1bf14414 , 0xea56e88c , 0x92394e8b , 0xf00af9ec , 0xc7fe11d8 , 0x61ba617b , 0x0d32d2f0 ] )
+  , Mont::unsafe_make( [ 0x904bc06d , 0x932fc82d , 0xcdc4fb3a , 0x210c930c , 0x8beb56e6 , 0x893903d5 , 0x10c16dcc , 0x0c83f234 ] )
+  , Mont::unsafe_make( [ 0x6aa80ee5 , 0x7f53e87f , 0x8b96e649 , 0xa6c05880 , 0xcae5d0d1 , 0x258a6ec0 , 0x3c494931 , 0x0f37815e ] )
+  , Mont::unsafe_make( [ 0x277ada0b , 0xede2ed7d , 0x6e009362 , 0xaa3d9dfd , 0x2f07485e , 0x83ff4ff9 , 0x908af3c9 , 0x0bb0c739 ] )
+  , Mont::unsafe_make( [ 0xe7793839 , 0x67e21c48 , 0x25dfd39f , 0x22090d81 , 0x9684eb75 , 0x8ba3ec8d , 0xd36bb78d , 0x0c6e9111 ] )
+  , Mont::unsafe_make( [ 0x0205a1d5 , 0xf514ef00 , 0xd9f9da9b , 0xbc2d349f , 0x12454460 , 0x372868f3 , 0x8a4b18b6 , 0x255fe896 ] )
+  , Mont::unsafe_make( [ 0xa3f4018f , 0xc3bfb208 , 0x02273e3f , 0x0d06e215 , 0x93ce1f3a , 0xd30d8c16 , 0x204d505b , 0x2e081568 ] )
+  , Mont::unsafe_make( [ 0x9d48e13d , 0x2b818cbb , 0xed7738a7 , 0xdd09569a , 0x3f858c99 , 0x3e9717bb , 0x89a70bc2 , 0x23fc3523 ] )
+  , Mont::unsafe_make( [ 0xd6ce85a3 , 0x0d7ddae2 , 0x97b7574c , 0xed841b33 , 0xa7784d39 , 0xbe19f747 , 0x12a18a44 , 0x182500c3 ] )
+  , Mont::unsafe_make( [ 0x04ee657e , 0x5c982a6e , 0xf0bfa00e , 0xe7d36d11 , 0x190219b6 , 0x65c4ad96 , 0x1ec7aac7 , 0x00bd008e ] )
+  , Mont::unsafe_make( [ 0x5d3ca313 , 0x1ed1f07e , 0xc80a6a13 , 0x9068666d , 0xfe58ac55 , 0xd000d472 , 0x66128aaf , 0x1434ebe6 ] )
+  , Mont::unsafe_make( [ 0xa982f105 , 0x88ba11b7 , 0x22e8c941 , 0x2605bc6a , 0x7004f1a9 , 0x644de6cc , 0x66bce1ab , 0x1125c43b ] )
+  , Mont::unsafe_make( [ 0x749ba619 , 0xce8259a8 , 0x7f005146 , 0x9bfecd6e , 0x890dc201 , 0xb8060ac8 , 0x18639b1f , 0x02fffd7c ] )
+  , Mont::unsafe_make( [ 0x17d800d9 , 0x7de5938d , 0x20164557 , 0xbda2d07c , 0x8b7943a1 , 0x088f72e7 , 0xe5a77306 , 0x2ac988a3 ] )
+  , Mont::unsafe_make( [ 0xbc6774a7 , 0xb0528454 , 0xc4556eae , 0xf70fd906 , 0xf93dfec8 , 0x88ca86a2 , 0x3b8cec82 , 0x035c9cdc ] )
+  , Mont::unsafe_make( [ 0x1e1a5292 , 0x09703b9a , 0x761c728c , 0xa95789ec , 0xfb1318d9 , 0xd289d671 , 0xe1cb0d44 , 0x02fe68d2 ] )
+  , Mont::unsafe_make( [ 0x3adc85f7 , 0x63b1c5d0 , 0x90ced5b1 , 0xa8d737c9 , 0x54294344 , 0x14cec008 , 0x019ba133 , 0x11a28909 ] )
+  , Mont::unsafe_make( [ 0x025adc49 , 0x2b8a0afb , 0x13450b5e , 0x845ccb34 , 0x619d3dbb , 0xa69248b4 , 0x3c6aa87b , 0x0f3ed26c ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x82985133 , 0x4fde2c1c , 0x8a339c64 , 0xbfbaaf96 , 0x4f63e9ac , 0x647880c5 , 0x4bb57b2a , 0x0637b7c2 ] )
+  , Mont::unsafe_make( [ 0x183fad70 , 0x328f2d56 , 0xb0a54462 , 0x5847f843 , 0xd323768d , 0xb416472d , 0xea45b717 , 0x1f4fe448 ] )
+  , Mont::unsafe_make( [ 0xe2ce3865 , 0x13eff6f6 , 0xef125725 , 0xd9087747 , 0x93d4106e , 0xcf4ce575 , 0x45fb94ad , 0x2f9a8c0b ] )
+  , Mont::unsafe_make( [ 0x2e852e54 , 0xdc3bb5ab , 0x536b72a8 , 0x472cba5e , 0xdeab5006 , 0xdcfbec61 , 0x48760dd8 , 0x161a7d2b ] )
+  , Mont::unsafe_make( [ 0xf890a4bd , 0x0847d912 , 0xb702cfd2 , 0x18cb1068 , 0x00a9178a , 0xeff08243 , 0xe8771c45 , 0x124baf34 ] )
+  , Mont::unsafe_make( [ 0xbed90b3e , 0x2893300a , 0x50275704 , 0x24c884a4 , 0x5a126eb8 , 0x9d7374c7 , 0x19f95fa9 , 0x14e468a1 ] )
+  , Mont::unsafe_make( [ 0xeb13f66b , 0x651232df , 0xbde1d769 , 0x963d4b34 , 0x392a86e5 , 0xc3614435 , 0x2dc43363 , 0x021f2ba8 ] )
+  , Mont::unsafe_make( [ 0x74f1bf6a , 0xf0a321d1 , 0x5caf3e3e , 0x8314a2fd , 0xb296742b , 0x67a3e02e , 0x1cd84db9 , 0x0d7191d2 ] )
+  , Mont::unsafe_make( [ 0x0e1f6983 , 0x36140e5a , 0x53c7234b , 0x1b8e6aa7 , 0xe41ac258 , 0x170d99a6 , 0x637dc2ee , 0x0bdd3ebf ] )
+  , Mont::unsafe_make( [ 0x6ab52dc1 , 0xeebe5ee9 , 0xbf62fdb7 , 0x62252fad , 0x303b19db , 0x198be5df , 0x02c9e1a7 , 0x1f7224ed ] )
+  , Mont::unsafe_make( [ 0x766fdfeb , 0x6559bc7d , 0xdb71aa9a , 0x0d562ec6 , 0xab3485fa , 0x0ded2b89 , 0x21fff9ce , 0x18bd8528 ] )
+  , Mont::unsafe_make( [ 0x25c07b77 , 0xbb750798 , 0xff6ca17a , 0xb6028434 , 0x4c8b460e , 0xf0e38e02 , 0x80e4161e , 0x2fe150ad ] )
+  , Mont::unsafe_make( [ 0x290de5d1 , 0x06c79fb8 , 0x829bfef4 , 0x28dcfc76 , 0x294f0588 , 0x7c61cdd0 , 0x27539c5d , 0x137833ec ] )
+  , Mont::unsafe_make( [ 0x13532f49 , 0x01b07f0f , 0x8dbc92aa , 0xf5c9021b , 0xcfd84d3e , 0x9510176e , 0x794bed4d , 0x054642d2 ] )
+  , Mont::unsafe_make( [ 0x6f0358b0 , 0x5370497a , 0x0a0e3d4d , 0x2ead854c , 0x2fdeeb23 , 0x9a3e8bb8 , 0x48e91b3b , 0x0f95071b ] )
+  , Mont::unsafe_make( [ 0xdb75ee7c , 0xcefd92c9 , 0x4bab57b4 , 0x6c0218b9 , 0x03999ef2 , 0xc72763e3 , 0xb23b00b2 , 0x0676392f ] )
+  , Mont::unsafe_make( [ 0xed93e6ef , 0x5b71970f , 0xc456903f , 0x27b0095f , 0x78596193 , 0xa7bd6732 , 0xc9d9a7ef , 0x274998db ] )
+  , Mont::unsafe_make( [ 0x731b12ec , 0xd1d41f3a , 0x2660bc18 , 0xd02dab97 , 0x4e0480fe , 0xb4fde7ba , 0x49e042d1 , 0x19a82460 ] )
+  , Mont::unsafe_make( [ 0xe1a6a696 , 0x2915938b , 0xab75841c , 0xd3e2c7c1 , 0xe4ac205a , 0xa8a00023 , 0xdf343620 , 0x1029ece0 ] )
+  , Mont::unsafe_make( [ 0x2b799021 , 0xe55de873 , 0x314a4f7f , 0xc54934fe , 0x882b900e , 0x7710b277 , 0xb3876eb6 , 0x0160aafa ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xb4068c79 , 0x53928edc , 0xc4d953b6 , 0x465a8960 , 0x9c7a1ead , 0x799f6aab , 0xf9a3c9f1 , 0x06a2e3db ] )
+  , Mont::unsafe_make( [ 0xb99d152e , 0xac0d6424 , 0x3a6193cf , 0x831bbd7b , 0x4de1a137 , 0xea29fcac , 0xa8931840 , 0x116035c9 ] )
+  , Mont::unsafe_make( [ 0x17390b0b , 0xed77e074 , 0x18224716 , 0xdd27b2c0 , 0xc900fa97 , 0xb4963bc1 , 0xd68dba37 , 0x059b13c7 ] )
+  , Mont::unsafe_make( [ 0xb6dec274 , 0x1291296c , 0xc0244855 , 0xa3300aef , 0x00c903ba , 0x1f4eb309 , 0x79ed5eac , 0x2aab0d1a ] )
+  , Mont::unsafe_make( [ 0x77627649 , 0x408bf409 , 0x71aa7358 , 0x3efaea19 , 0x29deba16 , 0xe1cdcc37 , 0xff6555b4 , 0x0608f77e ] )
+  , Mont::unsafe_make( [ 0x4c12b649 , 0x88da6bea , 0x25a32637 , 0x0ce5886d , 0xb80b61ad , 0x5122a30c , 0x1540f27a , 0x10736783 ] )
+  , Mont::unsafe_make( [ 0xd32137e1 , 0xf009a338 , 0x9e3881fe , 0x02e27255 , 0xed9d7ebc , 0x1b9b2438 , 0xaffeda74 , 0x06e364b2 ] )
+  , Mont::unsafe_make( [ 0xe44497bc , 0x38ef0af6 , 0x96904767 , 0xa3a8f1b6 , 0x6bd519d8 , 0x4206070d , 0xd14ce4b9 , 0x259374a1 ] )
+  , Mont::unsafe_make( [ 0xc2d55fac , 0x97cb853f , 0x9d1517ed , 0x0bc6408c , 0x8c2858f9 , 0xe3b27ae9 , 0x5d808651 , 0x03eb0fb3 ] )
+  , Mont::unsafe_make( [ 0x62efe818 , 0x6b526be9 , 0xd6da4023 , 0x0714c3b0 , 0x366cbe25 , 0x4f1f264e , 0x05866fe1 , 0x0ec8fcdb ] )
+  , Mont::unsafe_make( [ 0x799fa3ca , 0x2206a33c , 0xb579ef0e , 0x402d72cf , 0x47b8b98d , 0x30d74772 , 0xc3232645 , 0x11e5d0a0 ] )
+  , Mont::unsafe_make( [ 0x9ae71422 , 0xc99efd99 , 0xef6cfec8 , 0x768971a7 , 0xde89e07d , 0x9e1233f4 , 0xc2353f1a , 0x208fea18 ] )
+  , Mont::unsafe_make( [ 0x082b87b1 , 0x814a3661 , 0x8ca535f0 , 0x3e007f37 , 0x09a2590c , 0x6f112a37 , 0xa3c2abb3 , 0x0e35c153 ] )
+  , Mont::unsafe_make( [ 0x3221a640 , 0xb4906a6d , 0x1fc7dd05 , 0x88061ceb , 0xb606ce52 , 0x7b954485 , 0xf5e44e20 , 0x0373dadc ] )
+  , Mont::unsafe_make( [ 0xdce7f354 , 0xc8dfc80c , 0xeccabef0 , 0xd7890b26 , 0x76df9409 , 0xcb68711a , 0x0e25f1a8 , 0x00be5450 ] )
+  , Mont::unsafe_make( [ 0xacb18b97 , 0x3956dacf , 0x2db60878 , 0x07baa304 , 0x59a976f8 , 0x17375bec , 0xe8352b5c , 0x1a172245 ] )
+  , Mont::unsafe_make( [ 0x04dbea8b , 0xe6b062ff , 0xb7b8f37f , 0x477cca99 , 0xc78b3f72 , 0x3ec3c431 , 0x3fc11987 , 0x0cb683cf ] )
+  , Mont::unsafe_make( [ 0x7e6b25bf , 0x7e28d3ab , 0x50dab785 , 0x7f083037 , 0x2c27f448 , 0xeadfc187 , 0xf27962a1 , 0x13fe59d5 ] )
+  , Mont::unsafe_make( [ 0xd94f4437 , 0xb7f6ed13 , 0x33ec9ea8 , 0xab2fcb28 , 0x9db0afbc , 0xf2922487 , 0xe7c713a0 , 0x1a0694c4 ] )
+  , Mont::unsafe_make( [ 0x3cf3ad54 , 0xbe84c5b2 , 0x2328baa7 , 0xfeb40268 , 0xe5a3b62e , 0xbbb01fd7 , 0x5d651678 , 0x2ca1c138 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xe9a66b52 , 0xd5711b3e , 0x08932077 , 0xb6d2cdb8 , 0x1f8a39f9 , 0x0e8e0b49 , 0x7b7446d6 , 0x15998d53 ] )
+  , Mont::unsafe_make( [ 0x160429e6 , 0xba057655 , 0xbd117d4f , 0x8d8ea609 , 0x458ddda1 , 0xfdfc0b91 , 0x0d1e4720 , 0x23722ad9 ] )
+  , Mont::unsafe_make( [ 0x0d771143 , 0xd9859014 , 0xce90d687 , 0xff7da8f5 , 0x1e0b1da3 , 0xbc6b8852 , 0x24387397 , 0x1701290e ] )
+  , Mont::unsafe_make( [ 0x9b307ca0 , 0xf422fbed , 0x7f6d040f , 0xb35cbb70 , 0xbce3d614 , 0x8d8a296b , 0x1dd30686 , 0x119f3eb7 ] )
+  , Mont::unsafe_make( [ 0x9d7c0000 , 0x3ba3128e , 0xca58c346 , 0x4ba7432b , 0xa5d1be66 , 0xcbc861f1 , 0x74b298b8 , 0x1fa64529 ] )
+  , Mont::unsafe_make( [ 0x1b0fb0c8 , 0x294792b5 , 0x193a4b7f , 0x895cdfa3 , 0xd811eb9e , 0xb12265b2 , 0x39c48015 , 0x040817ce ] )
+  , Mont::unsafe_make( [ 0x2a7f132b , 0x8933294b , 0xe94e9083 , 0x941099dd , 0x89c63f6a , 0x2e622db6 , 0xd495d5d4 , 0x271f2ff4 ] )
+  , Mont::unsafe_make( [ 0x7b264141 , 0x737f542e , 0x653411f9 , 0x514a89ba , 0xba38fdf2 , 0xbc16638c , 0xae1cb6ee , 0x04aaa797 ] )
+  , Mont::unsafe_make( [ 0x43bcd64c , 0xd680a547 , 0x03cb0e24 , 0x04a1e425 , 0x1b34a2a4 , 0xd0525b29 , 0x8f6adf68 , 0x19816b86 ] )
+  , Mont::unsafe_make( [ 0x8912c472 , 0xad4bd08f , 0xbe1e2790 , 0x7b2c2c30 , 0xb8d39052 , 0xe6dc3dcb , 0xb962c642 , 0x1918dac2 ] )
+  , Mont::unsafe_make( [ 0x6715a412 , 0xd644e738 , 0x850fbb5a , 0x0284c6a9 , 0x508e35ec , 0x068ba8ef , 0x6484d7b9 , 0x2c68113a ] )
+  , Mont::unsafe_make( [ 0xd48cd2d8 , 0xa662b112 , 0xaac028f9 , 0x5d7026ce , 0xb8ab1629 , 0x2fadfea1 , 0x0626f9a3 , 0x18dba009 ] )
+  , Mont::unsafe_make( [ 0x0aa038ae , 0xd326d85a , 0x34f18b20 , 0x52001dff , 0x83de654b , 0x3a6fd0a8 , 0xbef2c06e , 0x0869ef73 ] )
+  , Mont::unsafe_make( [ 0xf8de4dd2 , 0x19cb3a25 , 0xb51fde6a , 0x68b5e68a , 0x07e72275 , 0xaf5ea6e5 , 0xd48a5363 , 0x21b1b156 ] )
+  , Mont::unsafe_make( [ 0x43ac4bdb , 0x54a154b5 , 0xe7305387 , 0xc4f8a85a , 0x756f3880 , 0xd6655cde , 0xb2ae7c78 , 0x05eb2a99 ] )
+  , Mont::unsafe_make( [ 0xf5016723 , 0x8b800bfa , 0x213b3fd0 , 0xec0ca6a5 , 0xec7d0a44 , 0x12a638aa , 0xc3752216 , 0x23ef14a4 ] )
+  , Mont::unsafe_make( [ 0x93c16df8 , 0x4a875fe9 , 0x94248683 , 0x1e26b811 , 0x38a039e5 , 0xfc8cf512 , 0xc3bfbbfa , 0x2e0ef3b7 ] )
+  , Mont::unsafe_make( [ 0xc2290532 , 0x56bfb080 , 0x9ab85603 , 0x77f5413e , 0xf526cc3a , 0x1d9aa66b , 0x9f076776 , 0x01abf3fc ] )
+  , Mont::unsafe_make( [ 0x6213ed46 , 0x3d221b03 , 0xc3e7d9aa , 0x553be843 , 0x6366da5d , 0xf852f8aa , 0x436e7b92 , 0x22c57a82 ] )
+  , Mont::unsafe_make( [ 0x167453b4 , 0x5816fd34 , 0x7d03692d , 0xe914a017 , 0x81b3a487 , 0xcf9103b3 , 0xefaf8dd1 , 0x20c53b43 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xbde32b9f , 0x75a7da5a , 0xcf6c6732 , 0x9cb1fcaa , 0x61ef3c5c , 0xc8b7fa39 , 0xe6f0ea56 , 0x0dccd7b8 ] )
+  , Mont::unsafe_make( [ 0x953c1340 , 0x38f8a588 , 0x4cedf8d0 , 0xf9aff16a , 0xcaf8dd20 , 0xfbe79fdf , 0x33628b8e , 0x1fb5e95d ] )
+  , Mont::unsafe_make( [ 0xfddfd1a3 , 0xd410e46b , 0x73511e05 , 0xdcab096e , 0xdc27c0c2 , 0x7934af5e , 0xc12d3db4 , 0x1eced4fa ] )
+  , Mont::unsafe_make( [ 0x5dce8080 , 0x9f6c82d6 , 0x2c2e0aac , 0x72163235 , 0xba285ef8 , 0x2b9a4fff , 0x38373c4f , 0x239f1849 ] )
+  , Mont::unsafe_make( [ 0x0ae7b3d2 , 0xddd2d320 , 0xb6b999f5 , 0x212a4b64 , 0x057b4b4d , 0xc59386c7 , 0x635d9e7e , 0x253b8a94 ] )
+  , Mont::unsafe_make( [ 0xd6026805 , 0xc1b7a5d1 , 0x1c62e78f , 0xcb0e4be9 , 0x572e35cb , 0x8ef4f3b4 , 0xdc047a12 , 0x1e8decf9 ] )
+  , Mont::unsafe_make( [ 0xe73a8a68 , 0xbe2c29bc , 0x3bb215e7 , 0xe08c22ab , 0x372c838e , 0x5bb8a0e0 , 0x6a0cb180 , 0x1f3dfb68 ] )
+  , Mont::unsafe_make( [ 0x9d05feb1 , 0xcac6bedc , 0x335d58e9 , 0x53560132 , 0x43a875a7 , 0xc49439a4 , 0xbef45293 , 0x082b4689 ] )
+  , Mont::unsafe_make( [ 0x9c03196b , 0xcbcf8760 , 0xdc2a6bc4 , 0x68013fd4 , 0x900fad82 , 0xa7bde39a , 0x60d265c1 , 0x0dca7a59 ] )
+  , Mont::unsafe_make( [ 0xaea0930b , 0x6fc0d525 , 0x1472943d , 0xafb479a7 , 0xd9056d4b , 0x4aa4660e , 0x129c11d0 , 0x2a0b632a ] )
+  , Mont::unsafe_make( [ 0xf7edd45b , 0xb400ab26 , 0xe9a3d122 , 0xeb5382fb , 0x19473e57 , 0x998554d6 , 0x4ae1ae99 , 0x1d410504 ] )
+  , Mont::unsafe_make( [ 0x8344a815 , 0x3222c144 , 0x1145dae3 , 0x66da01cf , 0x4e26ef6b , 0xa2823b46 , 0xe11eb17a , 0x1e0662a9 ] )
+  , Mont::unsafe_make( [ 0x2c2d3401 , 0xf34842c9 , 0x1d7785bc , 0x61afe8ec , 0x7942d972 , 0x4f808f54 , 0xc5949fa5 , 0x186dbcf8 ] )
+  , Mont::unsafe_make( [ 0xd4ae2b25 , 0x3f0808e4 , 0x07fe1b28 , 0x44d8c226 , 0xe66ea708 , 0x810787cb , 0x98f78a8f , 0x09e82aa1 ] )
+  , Mont::unsafe_make( [ 0xef4613d4 , 0x82babf15 , 0x65d5841c , 0x6b375275 , 0xd1851232 , 0xdd959182 , 0xd066d929 , 0x1ad6f1d4 ] )
+  , Mont::unsafe_make( [ 0x65e6ab8e , 0x558e4a99 , 0xc579536f , 0x5bb1da42 , 0xd2c090aa , 0xde00e969 , 0x711c85a9 , 0x0fbd8dbb ] )
+  , Mont::unsafe_make( [ 0xdb4de52f , 0xfb327071 , 0xef8236e7 , 0xe5172ab0 , 0xcb888cc8 , 0xf284ac26 , 0x2cdb3a8b , 0x0eb8108f ] )
+  , Mont::unsafe_make( [ 0x78c864c8 , 0x88276461 , 0x1a83bd12 , 0xb0f53bc6 , 0x96d8dd63 , 0x55ec149f , 0x2b24f144 , 0x015b8d06 ] )
+  , Mont::unsafe_make( [ 0xde93b289 , 0x68831b5d , 0x8075f38f , 0x3acbff06 , 0xd4f76a7a , 0xfd226723 , 0x875b2fdc , 0x14150389 ] )
+  , Mont::unsafe_make( [ 0x753bc928 , 0x5cde1c75 , 0xd68e6d14 , 0x9592e0d6 , 0x650a279d , 0xba0b58eb , 0x00f34d37 , 0x1603c1cb ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x9d14f2d3 , 0xa97fdd4f , 0xf600690f , 0x77d7f7bf , 0xc28bcfd0 , 0x4d09b60b , 0x9a37cb2e , 0x02ebbb71 ] )
+  , Mont::unsafe_make( [ 0xca6c3b81 , 0xcdfdb456 , 0x005297d4 , 0xf20e911e , 0xa1ce5c1b , 0x66dba405 , 0x8e0e3d50 , 0x0a925561 ] )
+  , Mont::unsafe_make( [ 0x580b42f4 , 0x072a0600 , 0xa9a13acb , 0x4beacaa6 , 0x88051a03 , 0xd6df9755 , 0x7a4749f9 , 0x2cffdadf ] )
+  , Mont::unsafe_make( [ 0x18beb48b , 0x4adbd36f , 0xaa6117b4 , 0x800a4ef8 , 0xc410c789 , 0xd81c2a07 , 0x3a855da4 , 0x2c4f21b2 ] )
+  , Mont::unsafe_make( [ 0xf3aab5d6 , 0xd5f3ce7f , 0x21341758 , 0x20d549eb , 0xedae6556 , 0x8654df50 , 0xcd803477 , 0x0c14bf8e ] )
+  , Mont::unsafe_make( [ 0x3673b5b8 , 0x16b05dab , 0xf4143461 , 0x6b0128d2 , 0x5b930154 , 0xecef426e , 0xcc6a8902 , 0x1da1e16c ] )
+  , Mont::unsafe_make( [ 0x809f44ed , 0x990a1b53 , 0xb74fe368 , 0x4442b1ae , 0xdc3af754 , 0xe8f1058f , 0xd37ad994 , 0x0710f0f8 ] )
+  , Mont::unsafe_make( [ 0xa2748e6f , 0xf5974b03 , 0xb07f37d5 , 0xca1bcc66 , 0xd0439812 , 0x527bd4cb , 0xb9b91a06 , 0x1860d3ce ] )
+  , Mont::unsafe_make( [ 0x9cc0dc89 , 0x82e62e8e , 0x32e467c3 , 0x4f812ba3 , 0x65c56144 , 0x858f8bc9 , 0x1978fe28 , 0x142ebd42 ] )
+  , Mont::unsafe_make( [ 0xe77282c1 , 0x8373f9b2 , 0xd2d4c2e9 , 0x7e277190 , 0x7fd04ad9 , 0x78c58d1e , 0xf7c68779 , 0x26f81d83 ] )
+  , Mont::unsafe_make( [ 0x32fbb995 , 0x6ceda08b , 0xf3fded32 , 0x10359539 , 0x3d6b154f , 0x406890d1 , 0x93a4da8f , 0x138ab163 ] )
+  , Mont::unsafe_make( [ 0xb6eb7db6 , 0x7b08aaab , 0x0e692d3f , 0x596ec8af , 0x00897358 , 0xf4c2af01 , 0x5d1b4269 , 0x294002ce ] )
+  , Mont::unsafe_make( [ 0x6e35c28e , 0xc138f7d7 , 0x4cd69043 , 0x187f5465 , 0x914208f1 , 0x1c9dcc57 , 0x79c0917e , 0x21f7b3c8 ] )
+  , Mont::unsafe_make( [ 0x1084ec1e , 0xc514848c , 0x974b096c , 0xb62bab79 , 0xdc678507 , 0xba7de59e , 0x17eab201 , 0x1b9760b3 ] )
+  , Mont::unsafe_make( [ 0xb073fd77 , 0x71ad607e , 0xffb9baa5 , 0x5b070a84 , 0xcaf4d294 , 0x6fdf4eb7 , 0x2a87e23c , 0x10a397cb ] )
+  , Mont::unsafe_make( [ 0xe2d7fcd7 , 0x0f1691e8 , 0xb48e66d5 , 0x2af6e28f , 0x5ca366ef , 0x569b572e , 0x9c8fc346 , 0x0323051b ] )
+  , Mont::unsafe_make( [ 0x7ceee83b , 0xa5151d1f , 0xee22288a , 0x33934a05 , 0x3a752fa9 , 0xa4647482 , 0x244add0e , 0x1edb01a5 ] )
+  , Mont::unsafe_make( [ 0x1d50054c , 0x386181b8 , 0x800534d0 , 0xc3c3e0f9 , 0x0b7de583 , 0x91b01aaf , 0x207a6cc3 , 0x246e7a0a ] )
+  , Mont::unsafe_make( [ 0x95ce39c2 , 0x8ca9a5ee , 0xc6f759ff , 0xe647da80 , 0x4622b18a , 0x1675102c , 0xf1ee1054 , 0x12b5b52f ] )
+  , Mont::unsafe_make( [ 0x3017bdc3 , 0xb34e961b , 0xa64b2a7f , 0xa6f7ccce , 0xe6424ea1 , 0xba99140b , 0x39e8abae , 0x0ad76482 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x1bc20342 , 0x02eea69c , 0x560c624e , 0x0740383f , 0x82cd7e04 , 0xdd412211 , 0x943d05ff , 0x2a3fc49a ] )
+  , Mont::unsafe_make( [ 0x93ed6d02 , 0xb37fd784 , 0xec1ba629 , 0x9bbfb3ee , 0x8815aadf , 0x32075915 , 0xa345583c , 0x052d8c55 ] )
+  , Mont::unsafe_make( [ 0x6163a9a2 , 0x88dc9b0e , 0x4a2da5e7 , 0xba845c7d , 0x855cc349 , 0xfc67dd02 , 0x8039c15f , 0x06636062 ] )
+  , Mont::unsafe_make( [ 0xf11c7ee7 , 0x7650c772 , 0x581ef6ce , 0x5c78699e , 0x48cf6ec7 , 0x71158182 , 0xa4e31bc0 , 0x2ece758d ] )
+  , Mont::unsafe_make( [ 0x0d4c0617 , 0x2e474f6b , 0xd25d5aea , 0xb5f7a02f , 0xe765b1d9 , 0x38d3c5fa , 0xff16e47a , 0x118c30b7 ] )
+  , Mont::unsafe_make( [ 0x18d61aa0 , 0xfbe9a125 , 0x1e04bd3e , 0xbc2baa2d , 0x0b4a5e6f , 0x751fa4d1 , 0x7cc279d5 , 0x2a04bb3c ] )
+  , Mont::unsafe_make( [ 0x9753eae1 , 0x7388ef30 , 0x4b107305 , 0x5343a3cb , 0x5c8c68bd , 0xd76b908a , 0xa349e1be , 0x0a919938 ] )
+  , Mont::unsafe_make( [ 0xdc5fbb4e , 0x026c8364 , 0x45c03e68 , 0x0705a597 , 0x572cf40e , 0xcd0fdd32 , 0x38d7ba6f , 0x289fa151 ] )
+  , Mont::unsafe_make( [ 0xb5a52b6d , 0x7353f76e , 0xa324f18d , 0xc7907112 , 0x94ef58b8 , 0xfac5168b , 0x477c3ff5 , 0x1a5ea0f5 ] )
+  , Mont::unsafe_make( [ 0xbc2780b9 , 0xbd9fba81 , 0x442e3635 , 0xe17d039b , 0x45d6a4ce , 0x179a8413 , 0x5089978f , 0x0ee5a02b ] )
+  , Mont::unsafe_make( [ 0x528f595c , 0x79c0b808 , 0x7bf5ba44 , 0x52a775f3 , 0x60ba6e8a , 0xc2857919 , 0x97f46132 , 0x2975fcb1 ] )
+  , Mont::unsafe_make( [ 0x766c0069 , 0xccdb755b , 0xefada2c9 , 0xf6d38642 , 0x62f746ff , 0x0ea555a5 , 0x6e5380e1 , 0x175023b1 ] )
+  , Mont::unsafe_make( [ 0x2280354c , 0xa4778b0e , 0x3c2516d8 , 0xa6088f26 , 0x8bd4bee6 , 0xc9604392 , 0xd67a97d0 , 0x047116a7 ] )
+  , Mont::unsafe_make( [ 0x8fd98204 , 0x27d03bfa , 0xf838f859 , 0x5d8afdd5 , 0xb88b6246 , 0x6dafe727 , 0x4bbe74c3 , 0x2edd86e3 ] )
+  , Mont::unsafe_make( [ 0x6c93d11f , 0xab505bfc , 0x4021a3c9 , 0xa0a444be , 0x24aa59ee , 0x162550e4 , 0x82f35f1d , 0x1739bf04 ] )
+  , Mont::unsafe_make( [ 0x5fe1521b , 0x8092c07e , 0xa3ce3cdd , 0x1855ec54 , 0x37ffa420 , 0x3b60a1b5 , 0xce56a94c , 0x1177ae58 ] )
+  , Mont::unsafe_make( [ 0x20218066 , 0xbd7c71cc , 0x996ae907 , 0xa7dc8ba3 , 0xcc022ee2 , 0xc3e94dc6 , 0xca73b7d4 , 0x22c8d7e0 ] )
+  , Mont::unsafe_make( [ 0x5b53916d , 0x4da27d21 , 0x995200bb , 0xfc9eff6a , 0x6785533d , 0x2b95b9b8 , 0x560b2313 , 0x07f25893 ] )
+  , Mont::unsafe_make( [ 0x5d5e0895 , 0xd655de3d , 0x87a073ee , 0x7b758475 , 0x5d99264a , 0x9f589381 , 0xb2d33ac8 , 0x2a28f310 ] )
+  , Mont::unsafe_make( [ 0xd1dd7666 , 0x1bd28f39 , 0x5b626c61 , 0x09bff009 , 0x76b0c9c4 , 0xcbb0d19b , 0xe6c8f7e1 , 0x0c0ee5fb ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x39721a57 , 0x8b601549 , 0x4e1bc5b9 , 0xbb177795 , 0xbcd1f989 , 0xf6c5cae3 , 0x93cb825d , 0x100c73f9 ] )
+  , Mont::unsafe_make( [ 0xd3d340f7 , 0x07b88c52 , 0x472ad59f , 0x0ef39271 , 0xfb3a4ca5 , 0x2cf6e4bb , 0x9a04a001 , 0x0fb3da99 ] )
+  , Mont::unsafe_make( [ 0xc700c651 , 0xabdc169c , 0x9cadeaf3 , 0xe707a390 , 0x946cbe07 , 0x2ec136b4 , 0x2c0625bb , 0x15154ba3 ] )
+  , Mont::unsafe_make( [ 0xcc519557 , 0x4bdc90e5 , 0x1a6f9c52 , 0x4f0251ef , 0xecb2a2cd , 0xb0b1030b , 0xe8363338 , 0x0d474566 ] )
+  , Mont::unsafe_make( [ 0x2bb91ce7 , 0x403d180a , 0x6806e5d2 , 0x6ec00c74 , 0x54dd1f44 , 0xa03b7a38 , 0x178db497 , 0x16fa7f52 ] )
+  , Mont::unsafe_make( [ 0x106b477a , 0x7a411bc5 , 0x2e6d1ba6 , 0xb4473572 , 0x584ba340 , 0xfc767abc , 0x41cfbf55 , 0x21e7165c ] )
+  , Mont::unsafe_make( [ 0x174431e6 , 0x985fddb3 , 0xae14a992 , 0x3a54825c , 0x3db8f23c , 0x2f63bef6 , 0x9ce00691 , 0x27949387 ] )
+  , Mont::unsafe_make( [ 0x226d7a09 , 0xe7607f80 , 0xf915fe4c , 0xa44617dc , 0xc28f0e6c , 0x2a9f3e49 , 0x1c05288a , 0x206a8aa2 ] )
+  , Mont::unsafe_make( [ 0x71c0fc7c , 0x5f3cd352 , 0x1ce26832 , 0x6dfd2e42 , 0x56949fdc , 0xbc5c59f5 , 0x6185ced6 , 0x04104695 ] )
+  , Mont::unsafe_make( [ 0x344da94d , 0x52fc8d4b , 0x7ef5abf7 , 0xa56ab103 , 0x36c98a2c , 0xcc77b001 , 0x768499f5 , 0x1ee136ae ] )
+  , Mont::unsafe_make( [ 0x27a4a285 , 0xdc2a4cc8 , 0x1441a6a5 , 0xf8d9e45b , 0xa50dae0b , 0xd353b46e , 0x2d276dff , 0x02a08973 ] )
+  , Mont::unsafe_make( [ 0xd208649c , 0x2f8ca622 , 0xcf29c220 , 0xff535a53 , 0x74df16b9 , 0xb3a8b27f , 0xc719738c , 0x07f5b398 ] )
+  , Mont::unsafe_make( [ 0x03f60d0b , 0x928fb4eb , 0x71902003 , 0xd71eecd1 , 0x7af9019c , 0xe03e13b1 , 0x67929bb9 , 0x25e3d9f6 ] )
+  , Mont::unsafe_make( [ 0x17ef912f , 0x6aed4f67 , 0x58ff3506 , 0xa291b693 , 0x4b353919 , 0x527d1ea9 , 0x18606116 , 0x0af15ee3 ] )
+  , Mont::unsafe_make( [ 0x635603cf , 0x76bf75de , 0xe627bcce , 0xcd915539 , 0x6579749f , 0x8a22e4a2 , 0x2c68aa6b , 0x2e383e8e ] )
+  , Mont::unsafe_make( [ 0x222f0a50 , 0x74dcb372 , 0x371bf2a3 , 0xd450aca3 , 0x98b21b8b , 0xcf4f345a , 0x01393f7a , 0x0f1e7ab5 ] )
+  , Mont::unsafe_make( [ 0xb2f468f4 , 0xcc53e505 , 0x7596cfe1 , 0xa6196446 , 0xf7285190 , 0xd235955d , 0xd3558cfe , 0x02863e9e ] )
+  , Mont::unsafe_make( [ 0xaad0d383 , 0x16b04ae1 , 0x09fb3da1 , 0x67b7972d , 0x328ef432 , 0x6928e992 , 0x97ab1142 , 0x2fb9b2c1 ] )
+  , Mont::unsafe_make( [ 0xad4c47ca , 0xb62f27b4 , 0xb5b29efe , 0x5a92e85c , 0x40508eba , 0xb6b0f04a , 0xbdac2d07 , 0x100e1247 ] )
+  , Mont::unsafe_make( [ 0x6ac7284f , 0x6e2e0e48 , 0xce6d459a , 0x84a7f699 , 0x7c13620e , 0xf4e234a7 , 0x15f0f32d , 0x2d354f5c ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x63f631eb , 0x8f037051 , 0x1045ab26 , 0x99f7c373 , 0x71b0e516 , 0xbd5ffb57 , 0x36a2ba18 , 0x0cac49f0 ] )
+  , Mont::unsafe_make( [ 0x991c9261 , 0x9c09bc02 , 0x468ff636 , 0x9a3cd6b9 , 0x7b1e1584 , 0xd37170bb , 0xd478f974 , 0x0792c818 ] )
+  , Mont::unsafe_make( [ 0x90fd5c3d , 0xa19168e1 , 0x66d726ef , 0x2837c6e4 , 0xc66595cf , 0xfddb3c09 , 0x3a4350bd , 0x2f9b958f ] )
+  , Mont::unsafe_make( [ 0x1de6caac , 0x0802035f , 0xe432d3fe , 0xbd610833 , 0x5414ae72 , 0xa7b2cb39 , 0x031c74e5 , 0x1c0e730c ] )
+  , Mont::unsafe_make( [ 0x28c32a10 , 0xd03feacd , 0x88460779 , 0x493c772c , 0xb69d0265 , 0x81b2f48c , 0xf1a7abcd , 0x0ed28756 ] )
+  , Mont::unsafe_make( [ 0x609c6e4f , 0x64db3c0e , 0x8f64a44a , 0x8f785d83 , 0xce996956 , 0x36df929c , 0x6ae0ad9b , 0x1ba946b0 ] )
+  , Mont::unsafe_make( [ 0xc348cf62 , 0x143689fe , 0x1892c885 , 0x67db397c , 0x425669c1 , 0x36917891 , 0x93ae32b5 , 0x1dc3cecb ] )
+  , Mont::unsafe_make( [ 0x9333dbf0 , 0xc0ccadde , 0xf0204358 , 0xe971013d , 0xc2d303f1 , 0xb6eac3c6 , 0x7764080a , 0x1d349b5f ] )
+  , Mont::unsafe_make( [ 0xb1f04cae , 0x11e35c0f , 0x81878a21 , 0xac2e47ca , 0x729dc17c , 0x5ac7a26a , 0x03966c3f , 0x0e5a564f ] )
+  , Mont::unsafe_make( [ 0x36f9203c , 0x09cfca24 , 0x2eb709df , 0xd8836ced , 0x51cc940a , 0x3be751c9 , 0x049e85d2 , 0x2adc4d7e ] )
+  , Mont::unsafe_make( [ 0xf8e5337b , 0x5b2f3784 , 0x47d8ded0 , 0x5ece2f5f , 0xb36c1128 , 0x061e63bb , 0xec7e9d86 , 0x21dceb1d ] )
+  , Mont::unsafe_make( [ 0xdb8f30df , 0xbb797efc , 0xb609b51e , 0xd68c86d3 , 0x0232f053 , 0x16af46a7 , 0xf4908eb6 , 0x137f7ab8 ] )
+  , Mont::unsafe_make( [ 0xb2992197 , 0x325439de , 0x2cc9a4df , 0x08e72b21 , 0x29e61675 , 0x15988e69 , 0x48569f08 , 0x1970af20 ] )
+  , Mont::unsafe_make( [ 0x3ae7feb9 , 0x9ec0e053 , 0xa8d459bd , 0x8c85ec01 , 0x2f05f0c1 , 0x45d92319 , 0x766ab2b6 , 0x0e4cc45e ] )
+  , Mont::unsafe_make( [ 0x16d2f530 , 0x21561969 , 0x1878743f , 0x4d0d8281 , 0x79216c57 , 0x3d7f217c , 0x82730856 , 0x2b6aae4f ] )
+  , Mont::unsafe_make( [ 0xe76710d1 , 0x61b6a68c , 0xf44ad742 , 0xc9dbddf6 , 0x50711ca3 , 0xe0acdee8 , 0xb5b72047 , 0x000ea7b2 ] )
+  , Mont::unsafe_make( [ 0x20d59d8c , 0xadf1e6e0 , 0xdf5c0652 , 0x2c8a4781 , 0x6bdda790 , 0xfc5b0b14 , 0xdea73a7a , 0x06d21168 ] )
+  , Mont::unsafe_make( [ 0xc3aaf479 , 0x5e3e032f , 0xe2f5da74 , 0x9e33f958 , 0xc644ce8e , 0x8e192b6a , 0x0292628d , 0x06d28c90 ] )
+  , Mont::unsafe_make( [ 0x718a523b , 0x12c3d2e0 , 0x29424f69 , 0x69725913 , 0x183c8cf0 , 0xfb0ade29 , 0x49ff81e2 , 0x00681089 ] )
+  , Mont::unsafe_make( [ 0x3c4b091d , 0x2eccf354 , 0xe6affaec , 0x57787b8e , 0xf8e3c7c6 , 0xd241dc55 , 0x6527cb77 , 0x2d8a320d ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xab5639d1 , 0x70793f33 , 0x1c6018b2 , 0x7f05a5f3 , 0x505a0990 , 0x0e034363 , 0xc6f161ec , 0x044c6570 ] )
+  , Mont::unsafe_make( [ 0xa3eb702b , 0x0d106b83 , 0x6d0015f3 , 0xce53c748 , 0xa3590825 , 0x83c56b66 , 0x41615b4d , 0x07aa39d9 ] )
+  , Mont::unsafe_make( [ 0xf1cd2aca , 0x71d86461 , 0x94b6e550 , 0x253b156e , 0xe585e969 , 0xae108496 , 0x9713e0e3 , 0x049ebd24 ] )
+  , Mont::unsafe_make( [ 0xf53f19e5 , 0xe9fa31d8 , 0xa02ec66e , 0x59161d15 , 0xa6c01994 , 0x2ab6fa5f , 0x1f53e115 , 0x1a0ff62d ] )
+  , Mont::unsafe_make( [ 0x4ba44d8f , 0xe1c3ab31 , 0x1ba748a3 , 0xd75bd20d , 0x7986e8b6 , 0x74e85856 , 0xc4f23dc8 , 0x14c61c06 ] )
+  , Mont::unsafe_make( [ 0x508fe08a , 0xeaf76742 , 0x4b6fd179 , 0x6c35e4d1 , 0x18b93b87 , 0xa5587dd6 , 0xc77a56ac , 0x220dcc19 ] )
+  , Mont::unsafe_make( [ 0xd8c0f5d2 , 0xdf6ca358 , 0x6f973e91 , 0x9e4fdd39 , 0xa7e3e902 , 0x31287979 , 0xb6affef7 , 0x216a396b ] )
+  , Mont::unsafe_make( [ 0x7192389d , 0xdd677e9e , 0xcd2046a1 , 0x443a2c0f , 0x17f42352 , 0x8153a278 , 0xe14761f1 , 0x15fd75a2 ] )
+  , Mont::unsafe_make( [ 0x8395e0e8 , 0x314b3e26 , 0xa3611556 , 0x727ac00b , 0x5032640a , 0x339c9bd5 , 0xad294663 , 0x1ee8d44c ] )
+  , Mont::unsafe_make( [ 0x7d0cb767 , 0xd080127c , 0xc1a4a256 , 0x7bb9796f , 0xae4ad99c , 0x4dec492d , 0x316b2e40 , 0x143ddfab ] )
+  , Mont::unsafe_make( [ 0xfec73662 , 0xd9446b30 , 0x84f8a734 , 0xc173094e , 0x3428a472 , 0x99d731ae , 0x3539f3e4 , 0x200fd49e ] )
+  , Mont::unsafe_make( [ 0x907a1319 , 0x09a06c63 , 0xc0ed84c7 , 0xa7025dc1 , 0xfa771635 , 0x6ae518dd , 0xe5ef580e , 0x192da3f5 ] )
+  , Mont::unsafe_make( [ 0xbb74980c , 0x81277366 , 0x1d5b44a0 , 0xb14736a8 , 0xe68eb0f4 , 0x0d244a87 , 0xf0f718a4 , 0x071e0157 ] )
+  , Mont::unsafe_make( [ 0xa795ce62 , 0x39c1fd1c , 0xe2de4e13 , 0x572b92a2 , 0x0882ff68 , 0xdfa2a74f , 0x82781a1c , 0x2fb59da2 ] )
+  , Mont::unsafe_make( [ 0x71a1beb6 , 0x80ca1baa , 0x210dd4d8 , 0x13bbe5f9 , 0xf7bd0969 , 0x2d24708c , 0x622e08b0 , 0x1c1e9678 ] )
+  , Mont::unsafe_make( [ 0x70d64144 , 0xde9b69cb , 0xee4546f3 , 0xc0ca11a9 , 0x8f000288 , 0x4969660e , 0x9c9c6cb8 , 0x2d5f1feb ] )
+  , Mont::unsafe_make( [ 0x9e0dc7f9 , 0x02b82028 , 0x2ac2a4b0 , 0x33b3ed4f , 0x452dae35 , 0x8700069f , 0x972da9b1 , 0x088e28ee ] )
+  , Mont::unsafe_make( [ 0x45ffdda7 , 0x46d9275f , 0xa461c48f , 0x399d99f9 , 0x983e946f , 0x1ecc298a , 0xa56a89bc , 0x0dbed1dc ] )
+  , Mont::unsafe_make( [ 0xe5781d8e , 0x6891adc7 , 0xd6f3b719 , 0xd764b036 , 0xb5059e10 , 0xa09bbbe3 , 0x82b6e6e2 , 0x1478770b ] )
+  , Mont::unsafe_make( [ 0x4d08422b , 0x004ffd6d , 0xa4fa190d , 0x305f52b0 , 0x644e2a29 , 0x00b0ee29 , 0x1c2c99f4 , 0x1c934694 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x4153574e , 0xfbc10f8a , 0xb98b9027 , 0x44bec7bf , 0x096124f0 , 0xd6c0f5da , 0x4108c1ed , 0x1bbd16d1 ] )
+  , Mont::unsafe_make( [ 0x46f91744 , 0xcc14fb2d , 0x509456af , 0x4a3410db , 0x3cce5a4f , 0xafb979b4 , 0x7becb38e , 0x1eabb088 ] )
+  , Mont::unsafe_make( [ 0xa0b38165 , 0xa83a9013 , 0x43371fd9 , 0xd150e97d , 0x546bbe65 , 0x8eadf051 , 0x31936978 , 0x23ac5d28 ] )
+  , Mont::unsafe_make( [ 0x3a1d1293 , 0x33780d77 , 0x18ae0892 , 0x2448e9bb , 0xf9ec6ea0 , 0x57050424 , 0xe128d39d , 0x1892fe06 ] )
+  , Mont::unsafe_make( [ 0xcf5541bf , 0xae2004a3 , 0x2f7bec39 , 0x0859f619 , 0x6b5e8c1b , 0xdd3576f9 , 0x53a0810f , 0x0432090f ] )
+  , Mont::unsafe_make( [ 0xe2e15d8c , 0xc8814827 , 0x847a5881 , 0x559d9cca , 0x66434125 , 0xd4c9b819 , 0x9a99b96c , 0x05ff719f ] )
+  , Mont::unsafe_make( [ 0xa8e8a84d , 0x8ef6a873 , 0xe531db59 , 0xf4305045 , 0xe966be92 , 0x6c015c6f , 0x0d5214de , 0x0ca0cafa ] )
+  , Mont::unsafe_make( [ 0x5e875c36 , 0x752c6fb4 , 0x543ba185 , 0x062308e6 , 0xd8b994f9 , 0x90cb5283 , 0x9dd4439c , 0x24b64f83 ] )
+  , Mont::unsafe_make( [ 0xdf0c63df , 0x35c5873e , 0x26274c48 , 0x622b36fd , 0x89f464d0 , 0x473b3ba1 , 0xe1238369 , 0x17ae66a5 ] )
+  , Mont::unsafe_make( [ 0x0d635ede , 0xc6e026f9 , 0x8332de13 , 0xa2b4883a , 0x1ed679f7 , 0xf13a6fc0 , 0x57bebafb , 0x26eca267 ] )
+  , Mont::unsafe_make( [ 0xa64b3d55 , 0x833cf016 , 0x83dd17e4 , 0xac4b90f4 , 0xda3fcaaa , 0x854002d6 , 0xe6f8aefd , 0x25d91677 ] )
+  , Mont::unsafe_make( [ 0x53283922 , 0x773a6393 , 0xeac05744 , 0xbe1901db , 0x30b713e1 , 0x4fe45faa , 0x7b08848e , 0x2eda6de6 ] )
+  , Mont::unsafe_make( [ 0x27283fef , 0xe7728345 , 0x37e29674 , 0x548405d0 , 0x3d73ae59 , 0xaaaa78f2 , 0x8fbb0231 , 0x10b7eec1 ] )
+  , Mont::unsafe_make( [ 0xe14c3731 , 0x7558591a , 0x692de039 , 0xbda20e2f , 0xd7da7978 , 0x7500789f , 0x8ba87706 , 0x19baab46 ] )
+  , Mont::unsafe_make( [ 0x14c14c4a , 0x956047ac , 0x736bb90a , 0x04a23255 , 0x540407d9 , 0x7706b457 , 0x7f13f764 , 0x23408460 ] )
+  , Mont::unsafe_make( [ 0x13d4a44f , 0x1d795009 , 0x70bc81d5 , 0x20ed8514 , 0x476b2e4c , 0xabf48745 , 0xd7c4bb56 , 0x2a17f9bb ] )
+  , Mont::unsafe_make( [ 0xf10dda43 , 0x9c7e5c33 , 0x2bdb9bae , 0x3522ac68 , 0x99918113 , 0x66fda825 , 0x6ce8e1c8 , 0x11e64798 ] )
+  , Mont::unsafe_make( [ 0xa38ab85a , 0xf6164b0f , 0xca11e369 , 0x5fbc1c8e , 0xd4bfd9d8 , 0x2bc05b22 , 0x57cd7024 , 0x109907be ] )
+  , Mont::unsafe_make( [ 0xfbe22e2e , 0xc672e3d0 , 0x0936239a , 0x4468355f , 0x558d2fec , 0xfcc70610 , 0xba9eae27 , 0x064c41da ] )
+  , Mont::unsafe_make( [ 0xf8ab3819 , 0x9e717978 , 0x827d3cc4 , 0x20ee8644 , 0xe8614793 , 0x05f49758 , 0x152844e9 , 0x16b237ee ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x9af48c34 , 0x101f5ad0 , 0xdc169957 , 0x4be8b4f2 , 0x2399a3c2 , 0x51f6a370 , 0x6fc21362 , 0x12671575 ] )
+  , Mont::unsafe_make( [ 0xb1ada3d1 , 0x5d9a0e9f , 0x3339e628 , 0x55ccbf16 , 0x7180492f , 0x1e827d3a , 0x7b37b7ae , 0x0faed846 ] )
+  , Mont::unsafe_make( [ 0xb4a12e17 , 0x6a74e0f7 , 0x03a8b8c5 , 0x09b31468 , 0x6e35aac9 , 0x1756bc53 , 0x80fce698 , 0x2947bc39 ] )
+  , Mont::unsafe_make( [ 0x1f807af5 , 0x23c2184c , 0x08ebefd2 , 0x6f6e37a8 , 0xb2de7dd8 , 0x5e6a692d , 0x690b7ca5 , 0x05b1bc5d ] )
+  , Mont::unsafe_make( [ 0x2290f462 , 0x2ae50cab , 0xd00807d7 , 0xd0aa95d3 , 0x4cc10b89 , 0x2819de13 , 0x47ca6b31 , 0x28793a86 ] )
+  , Mont::unsafe_make( [ 0x571d70ec , 0xafbbf617 , 0xfc5fb2e0 , 0x50e8e505 , 0x11290c50 , 0xfa07ed59 , 0x4c460b60 , 0x1f355e40 ] )
+  , Mont::unsafe_make( [ 0xcb3a7fdb , 0x5b2e11bf , 0x6f3c2579 , 0xcd133651 , 0x8a537971 , 0x72b176ed , 0x61ddf6b0 , 0x06c00701 ] )
+  , Mont::unsafe_make( [ 0x0c86294e , 0xacc67a04 , 0x898ef206 , 0xf852620b , 0x2f646252 , 0x58812865 , 0x090179e1 , 0x19c22858 ] )
+  , Mont::unsafe_make( [ 0xf6e39019 , 0xa5e58852 , 0xa0cfe3b6 , 0xb411c0ae , 0x82d2fe82 , 0x01ac3121 , 0x13de74e3 , 0x28b5d914 ] )
+  , Mont::unsafe_make( [ 0x64fbe29d , 0xf4224e5d , 0xb1d19eec , 0x3e03ecc1 , 0x3bf63b78 , 0x8a7df9c2 , 0x420d0cd7 , 0x2c6bcde8 ] )
+  , Mont::unsafe_make( [ 0x284655df , 0xae0a3cf2 , 0x87f4764c , 0xebbee655 , 0x6cbc014b , 0x56063cb4 , 0x11549ba3 , 0x1fa690e0 ] )
+  , Mont::unsafe_make( [ 0x3eafc0fa , 0x176b2c02 , 0xd46a41a1 , 0x76651dda , 0x296c8db2 , 0xccc646f4 , 0x355d057c , 0x1f1e2d0f ] )
+  , Mont::unsafe_make( [ 0x4c53e600 , 0x4ffcc877 , 0x9a66f1b8 , 0x5dc3fd7c , 0x371d9530 , 0xa97161a5 , 0x9cfef887 , 0x2ec74755 ] )
+  , Mont::unsafe_make( [ 0x11437049 , 0xe6ff637f , 0xa8203508 , 0x932d13b4 , 0x24da089f , 0xa4936460 , 0xd3d9110e , 0x29617a12 ] )
+  , Mont::unsafe_make( [ 0x7bf615d9 , 0xca423aa2 , 0x5ec232df , 0xc036e5b8 , 0x5e7c6023 , 0x5f81f41e , 0x0b723e1f , 0x1ba42aa1 ] )
+  , Mont::unsafe_make( [ 0x2917b2d0 , 0x34b0549a , 0xf83d408c , 0x22d26fd9 , 0x64e3217e , 0xe60f6425 , 0x553f7f14 , 0x2cfef7af ] )
+  , Mont::unsafe_make( [ 0x8156f6ee , 0xbb588b79 , 0xbbc0994f , 0x004297b1 , 0x84c2a0c4 , 0x0ebedc49 , 0xd65ac0bb , 0x1f7cd8a6 ] )
+  , Mont::unsafe_make( [ 0xadc0aabc , 0xccab59ef , 0x3cacd080 , 0xc2b75d7c , 0xb4acb02f , 0x44772abe , 0x9657fe54 , 0x00315ac0 ] )
+  , Mont::unsafe_make( [ 0x276a5644 , 0x0215c57e , 0x1aba6307 , 0x4cc1d32f , 0xca2f1809 , 0x6a1c668f , 0xa9f23e98 , 0x268f541b ] )
+  , Mont::unsafe_make( [ 0xe73fb766 , 0x538a3879 , 0xfad72aa1 , 0xbf628bd3 , 0xe85e4ad9 , 0x05a58504 , 0x83d733ff , 0x026f9e10 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x48f8dc93 , 0x9972ddf0 , 0x4da8c6e5 , 0xc658b5f1 , 0x66896631 , 0x5d0da0dd , 0x0a5352db , 0x1f1bcbbf ] )
+  , Mont::unsafe_make( [ 0x46e1885f , 0xde6d5623 , 0x8b75bc10 , 0xe2edba4e , 0x92a42fb3 , 0x68f88859 , 0x55868be5 , 0x1ac93814 ] )
+  , Mont::unsafe_make( [ 0x5f10a42b , 0xc74fd174 , 0x458b49a1 , 0x38312de3 , 0xae191736 , 0x132ea0d0 , 0xb9cdd994 , 0x0c42647c ] )
+  , Mont::unsafe_make( [ 0x5a06ba11 , 0x9aad975d , 0xd656832c , 0xd6e7802a , 0xc5c43e3e , 0xcc20dec3 , 0xdbc5287b , 0x20aa5587 ] )
+  , Mont::unsafe_make( [ 0x8e989097 , 0x0cb1371e , 0xbe2c5809 , 0x5dec3187 , 0x58ca2d90 , 0x99273357 , 0x8f689266 , 0x24543145 ] )
+  , Mont::unsafe_make( [ 0x6b1415d0 , 0xc6f80a31 , 0x1883bd0f , 0x5ef77ca8 , 0x1fce32f0 , 0x6dcc478b , 0x647f39a1 , 0x0cf104de ] )
+  , Mont::unsafe_make( [ 0x08eba1f8 , 0x3312ce18 , 0x350c3c5d , 0x26521b9c , 0xf30523f6 , 0xeb172de1 , 0x84d61ba4 , 0x189661fc ] )
+  , Mont::unsafe_make( [ 0x0341791d , 0xb13a6734 , 0xe639133f , 0x5471567b , 0xe724a7d5 , 0xa44217b3 , 0x215cad7b , 0x1f9d03e3 ] )
+  , Mont::unsafe_make( [ 0x015a3104 , 0xec8a84db , 0xa06e6ede , 0x6eecf70c , 0xdf2d3276 , 0x68e3d97d , 0x911dd619 , 0x26c85930 ] )
+  , Mont::unsafe_make( [ 0xbeaa3602 , 0x45ed490e , 0x017008f8 , 0xe1142289 , 0x6eeb6d9c , 0x43db37bb , 0x45d3e692 , 0x22383d25 ] )
+  , Mont::unsafe_make( [ 0x5b804bb8 , 0xb81c4e8b , 0x48c4ed16 , 0x5922d604 , 0x4f0885e8 , 0xbd617919 , 0xefca5db7 , 0x0ee55408 ] )
+  , Mont::unsafe_make( [ 0xaa1f2560 , 0xc0cfaaf7 , 0xfb4f8ca8 , 0x209d9893 , 0x0937325f , 0xeba35568 , 0x792de090 , 0x028d073d ] )
+  , Mont::unsafe_make( [ 0xfb74c875 , 0x4598bca9 , 0x96777691 , 0x0eaa7c0a , 0xd124d9a9 , 0x1afae9ce , 0xac59fa36 , 0x2976e5fa ] )
+  , Mont::unsafe_make( [ 0xe4f6518f , 0x439946a6 , 0x9666414b , 0x42f3efcd , 0x1dfc661f , 0x2d93ed06 , 0x28d7b24f , 0x04b9a5c6 ] )
+  , Mont::unsafe_make( [ 0xe6fc8fe5 , 0xfe6ed83f , 0x7df3f4a0 , 0x13b89db0 , 0x83b589a4 , 0xfcd6fcd1 , 0x2ee06efe , 0x2fb940a2 ] )
+  , Mont::unsafe_make( [ 0x965ceff6 , 0x4ccaff00 , 0x0dd8fecc , 0xecb5ecab , 0x9a85af47 , 0x9b4e0695 , 0xab47c066 , 0x282bfc9c ] )
+  , Mont::unsafe_make( [ 0x7384160d , 0xc1432737 , 0x4eeacba3 , 0x0be166fc , 0x9ac108a1 , 0xea1435a5 , 0xc1a0139f , 0x1ad9b8b9 ] )
+  , Mont::unsafe_make( [ 0xabf2755d , 0xb2cdd4ab , 0x1dcd88ef , 0x2c01d68a , 0x7fc0ca8b , 0x196c4e55 , 0x525491ef , 0x00c94a8d ] )
+  , Mont::unsafe_make( [ 0x487f4d97 , 0x30ce9520 , 0x290f247e , 0x77198fd5 , 0x1c3b29b1 , 0x8c3b091a , 0x81e8cd13 , 0x0a53c14e ] )
+  , Mont::unsafe_make( [ 0x63a2aee8 , 0xebe994c7 , 0x589a142f , 0x9e35b31a , 0xa9f4d561 , 0x8caf9e0e , 0x804f0497 , 0x274b7a97 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xdd091698 , 0xae971dcd , 0xee5c13cd , 0xb1c5e6c2 , 0xbb77429c , 0xe1e88be2 , 0x90d3b6a3 , 0x026d5d9c ] )
+  , Mont::unsafe_make( [ 0xf0dede6d , 0x4f785e4e , 0xafc3cffa , 0x30f84c8a , 0x57fd96f7 , 0x09c2ed8e , 0xc9dc0e2a , 0x2f20e32a ] )
+  , Mont::unsafe_make( [ 0x2c7f41df , 0xb52d020f , 0xf12e5157 , 0x1315ab10 , 0x5c26b720 , 0x47f8159c , 0xb625d264 , 0x01522b0a ] )
+  , Mont::unsafe_make( [ 0xe29e0d48 , 0xc37d306d , 0x781f77d5 , 0xfe764848 , 0x0f8ac358 , 0x62c535e8 , 0x835ebdd3 , 0x12394d5d ] )
+  , Mont::unsafe_make( [ 0xe5057548 , 0x880d663a , 0x999a9e95 , 0xf47ba4d4 , 0x9f184f47 , 0x92398f74 , 0xe3706929 , 0x165040d8 ] )
+  , Mont::unsafe_make( [ 0x93eb6edc , 0x8354d033 , 0x6ce0d14a , 0x111abf80 , 0xab6c857b , 0xf2f102ae , 0x1cfa6030 , 0x162dc1af ] )
+  , Mont::unsafe_make( [ 0xfe5a41c1 , 0x5d1680a0 , 0xf62d3540 , 0xfa3fb111 , 0xa911f535 , 0xbba082fd , 0xaa68d5a2 , 0x08b1f2ff ] )
+  , Mont::unsafe_make( [ 0xca64c824 , 0x6eb5a03e , 0x465d1cf7 , 0xc555d6c4 , 0x79783127 , 0x1b47d77c , 0xa0da6323 , 0x066aaef4 ] )
+  , Mont::unsafe_make( [ 0x2b96fa20 , 0x60cd8cec , 0xb459fb04 , 0xf766141d , 0x2b423107 , 0x943a7045 , 0x9d9c4c76 , 0x15990ea4 ] )
+  , Mont::unsafe_make( [ 0x00e1deb4 , 0x663394d8 , 0x5c7e6fcc , 0x91f8a092 , 0x1a8c667e , 0x52440609 , 0x760f89f9 , 0x19c91297 ] )
+  , Mont::unsafe_make( [ 0x82b2364e , 0x55bbbab2 , 0xfda43114 , 0x89dc5eaf , 0xeb6a44a5 , 0x871f54b2 , 0x82e41212 , 0x14ec7964 ] )
+  , Mont::unsafe_make( [ 0x85bd742b , 0x16a304cd , 0x927f5fd8 , 0x84ae61ef , 0x2eb8d073 , 0x076b5b1c , 0xae6cd087 , 0x24f880d9 ] )
+  , Mont::unsafe_make( [ 0x0afd0694 , 0xb84e83f6 , 0xb31a4476 , 0x2ec4dd34 , 0x8f605c4e , 0x4527ac27 , 0x56483e0f , 0x2bdf6751 ] )
+  , Mont::unsafe_make( [ 0x1416f2f8 , 0xe190368a , 0x2ca17833 , 0xbb6f805e , 0xba9309e6 , 0x6d346c32 , 0xe209a4c3 , 0x05c368c3 ] )
+  , Mont::unsafe_make( [ 0x50824e7b , 0x84b5af99 , 0xc1da4d68 , 0xc84e6615 , 0x1000b27e , 0xdeb86791 , 0x6ea622e9 , 0x1f6a6251 ] )
+  , Mont::unsafe_make( [ 0xecf20f77 , 0x3f2fcc8c , 0xadfaae38 , 0xa36deae7 , 0x88b51370 , 0x76303a72 , 0x6e58e738 , 0x23373cd3 ] )
+  , Mont::unsafe_make( [ 0x0722f8f7 , 0x6695b8c9 , 0x99ce6fc6 , 0x62df4cc9 , 0xec6211cb , 0x73272d9e , 0x7784513b , 0x2ae6cc94 ] )
+  , Mont::unsafe_make( [ 0x1d82ff9c , 0x1ad9266b , 0x7f6f33a6 , 0x1adb0c32 , 0xf27d4b6e , 0x13cffc87 , 0xf2f4d9f0 , 0x1b7c80d4 ] )
+  , Mont::unsafe_make( [ 0xb961a3be , 0xbaa01094 , 0x6099c792 , 0x9ba59206 , 0xc3bae259 , 0x792d8d45 , 0x21bf40e9 , 0x0feb79c8 ] )
+  , Mont::unsafe_make( [ 0xd1d4a688 , 0xa5d634e9 , 0x39ef6d95 , 0xe9254c5c , 0x29792c5d , 0x1e78b174 , 0xd75b8e60 , 0x155dc24b ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xf6731074 , 0x35c68040 , 0xc5158537 , 0x5c6fba77 , 0x91526a81 , 0xdcdab823 , 0x63ef7dd0 , 0x24c22afa ] )
+  , Mont::unsafe_make( [ 0xd814ab04 , 0xcfaa679c , 0x85e73b41 , 0x8cf82c70 , 0x0231d758 , 0xbde69222 , 0xfbeb4f94 , 0x108d54a3 ] )
+  , Mont::unsafe_make( [ 0x9af2a7fe , 0xb426b319 , 0x5e7abc43 , 0x048e2668 , 0xc6d88a4d , 0x35f3100f , 0x82dea444 , 0x2c14f800 ] )
+  , Mont::unsafe_make( [ 0xabad3dbb , 0xf4c36ffc , 0x6432d5de , 0x87b573ea , 0x588b8a2a , 0x29f2ec4f , 0x98e49e54 , 0x2785ace8 ] )
+  , Mont::unsafe_make( [ 0x8110e2cc , 0x72ee40a7 , 0x12d8a594 , 0xffc47060 , 0xa8e77fa9 , 0x2938e595 , 0xb209bf1f , 0x25a1137a ] )
+  , Mont::unsafe_make( [ 0xecb7651e , 0xa4200c5c , 0x642d2a35 , 0x5e2f6ab7 , 0x19442919 , 0x5203fde6 , 0x843f20fd , 0x284dc029 ] )
+  , Mont::unsafe_make( [ 0x4a57908e , 0xb430f156 , 0x96d5c672 , 0x9ac93e8a , 0x84876b22 , 0x505e9882 , 0x22b1ea26 , 0x2973b5c8 ] )
+  , Mont::unsafe_make( [ 0x96acf8d1 , 0xb6dd26a5 , 0x57290a48 , 0x993ec232 , 0x2d43a494 , 0x6d3f9685 , 0xa07c4cd2 , 0x2080d593 ] )
+  , Mont::unsafe_make( [ 0x363aeb14 , 0x21b38eed , 0x71840e1c , 0x7e6d8966 , 0xf1bc439c , 0x0787e0b5 , 0x6742080e , 0x2d85999f ] )
+  , Mont::unsafe_make( [ 0xd61445b9 , 0x49731112 , 0x1c7fd6a6 , 0x2cfa4a53 , 0xda3f9a5e , 0x04b7c56b , 0x5f3f3be1 , 0x281fd492 ] )
+  , Mont::unsafe_make( [ 0x91e08ba9 , 0xde3f8103 , 0x1f1288ff , 0x11111582 , 0xc7a540f7 , 0xbd7053c6 , 0xb1e58b4a , 0x2f554f74 ] )
+  , Mont::unsafe_make( [ 0xe6cbc777 , 0x78fa6c32 , 0xfa1a2986 , 0xe2e279d1 , 0x844ef7e7 , 0x48198a57 , 0x46e4f886 , 0x14590216 ] )
+  , Mont::unsafe_make( [ 0x3ceaeaf3 , 0x98bb1651 , 0xb508d36e , 0x61970f4f , 0x8e44774e , 0x4d829002 , 0xca666af9 , 0x0695ed88 ] )
+  , Mont::unsafe_make( [ 0xcddd203a , 0x3eb9b685 , 0xb89fe70d , 0x7db84ae3 , 0xcabda148 , 0x8cd24cdd , 0x009100a9 , 0x1a1f5e33 ] )
+  , Mont::unsafe_make( [ 0x3518e8ec , 0xa3ef4e31 , 0xf8bd44c6 , 0x41193f50 , 0x71f4555a , 0xa15d12f9 , 0x31ae824c , 0x119759f0 ] )
+  , Mont::unsafe_make( [ 0x87e41b38 , 0x87fa3bb9 , 0x79d7b875 , 0x76da564c , 0x7e40302c , 0x77ee7fc8 , 0x0de4f5bd , 0x2ca22f06 ] )
+  , Mont::unsafe_make( [ 0x5ee9f816 , 0x61b1dff3 , 0x4419bb05 , 0xab89aa51 , 0x14e0eacb , 0x2de418f1 , 0x8a09d04b , 0x1b30ed3f ] )
+  , Mont::unsafe_make( [ 0x07ec6852 , 0x0cef1f8b , 0x5c6fa531 , 0xa7f6b77d , 0xf0c3214a , 0x8323d6b9 , 0xbd5f585c , 0x015ad905 ] )
+  , Mont::unsafe_make( [ 0x989dba08 , 0xe5aa179f , 0xf5be675c , 0x26869e4b , 0xf85e3a04 , 0x685d5430 , 0xbdb8ad93 , 0x26d7487f ] )
+  , Mont::unsafe_make( [ 0x8ec94875 , 0x8dde46ab , 0xcdac75aa , 0x83bd3119 , 0x691161b5 , 0x7e3bba9b , 0x4d27772f , 0x2db1346f ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xf31910a4 , 0xa311a967 , 0x4a826612 , 0xa1f6cd61 , 0xbf53510e , 0xd1c5bca4 , 0x7768816d , 0x061cae10 ] )
+  , Mont::unsafe_make( [ 0x65ddbaaf , 0xd89cbe31 , 0x47bf4cf0 , 0x59e9ed88 , 0x2cec4bae , 0x0c4b311c , 0x66b2149a , 0x03ab0d66 ] )
+  , Mont::unsafe_make( [ 0x35edd0e9 , 0x6cb40cb1 , 0x22f92b75 , 0x4e0f1a0f , 0xe950e9e8 , 0x1bec1a01 , 0x64ad09a6 , 0x02c1b2d8 ] )
+  , Mont::unsafe_make( [ 0x13f6e02c , 0xa0353aa1 , 0x95aa964e , 0x7c250f62 , 0xdf8ec416 , 0x57cb1a2d , 0x8361fabf , 0x2175a1b1 ] )
+  , Mont::unsafe_make( [ 0xf25084d8 , 0x5af2fbb0 , 0x57b2021e , 0x13363c83 , 0x77d758ae , 0xe2839c93 , 0xed17c486 , 0x2d667c02 ] )
+  , Mont::unsafe_make( [ 0x24c4036c , 0x7a7577a9 , 0xda2327c4 , 0x82cfd0cb , 0x88769073 , 0x37addd71 , 0x942a02d4 , 0x301a8806 ] )
+  , Mont::unsafe_make( [ 0x5c41c14f , 0x164bbab8 , 0xd25a9050 , 0x4dd1ab6f , 0xe92a7a3d , 0x5d0eee79 , 0x64201e25 , 0x01e721ac ] )
+  , Mont::unsafe_make( [ 0x46a150a3 , 0xaa81b295 , 0x0bbe7ef8 , 0x77c44ff3 , 0x3222592c , 0x236b0662 , 0x1fd7067e , 0x27d51ab5 ] )
+  , Mont::unsafe_make( [ 0x02a26557 , 0x471a1ac7 , 0xaf850edb , 0x43f8db86 , 0x49023396 , 0x2d9c4486 , 0x38c99c2e , 0x2f79d03e ] )
+  , Mont::unsafe_make( [ 0x77afabef , 0xecd08d5d , 0x7a1f370f , 0x983a6e0a , 0x52e36618 , 0x7e110a96 , 0xabf66bc3 , 0x2640591e ] )
+  , Mont::unsafe_make( [ 0x5a13b92b , 0x07c88f88 , 0x0343f2e4 , 0x7c366d7c , 0xe757758e , 0xe784c6e2 , 0xa7e823f1 , 0x15507fd5 ] )
+  , Mont::unsafe_make( [ 0xee04bcf7 , 0x2416495e , 0xf1c5f554 , 0xbb0b59d2 , 0x274cfb11 , 0x7725aad2 , 0x0e49837b , 0x025d596b ] )
+  , Mont::unsafe_make( [ 0x0ba36474 , 0x47473b72 , 0xa5dc1385 , 0x6ddcd370 , 0x76341d24 , 0x332dd615 , 0x25f052b6 , 0x05887741 ] )
+  , Mont::unsafe_make( [ 0xa46eafbf , 0x05b1641e , 0xa78663b8 , 0xa1615e21 , 0x7430f5f2 , 0x8a266ef5 , 0xf379e4f3 , 0x1f4bc40c ] )
+  , Mont::unsafe_make( [ 0x5f6483e9 , 0x4ef7771f , 0xcba1482e , 0x01e4faf4 , 0x3b6398f3 , 0x16421f43 , 0xb097d25d , 0x11609d98 ] )
+  , Mont::unsafe_make( [ 0x64f00212 , 0xb9267b04 , 0x9d547c88 , 0x615b56da , 0xa925bb54 , 0xc68ffa54 , 0xdffd0c82 , 0x2188c90f ] )
+  , Mont::unsafe_make( [ 0x1b7b51d7 , 0x604c4d72 , 0xa57e5c15 , 0xd5610bd7 , 0x1bf424d4 , 0xd79a799c , 0x9e1f2c91 , 0x1ff16f5e ] )
+  , Mont::unsafe_make( [ 0x52163054 , 0x26f9b85c , 0xb893e1a6 , 0xc6f4e383 , 0xd76426fb , 0x1fddeb58 , 0xb1261f01 , 0x15a67299 ] )
+  , Mont::unsafe_make( [ 0x031dd3ff , 0x98a0dbd3 , 0x97f02891 , 0x42d003c4 , 0xea0dda9a , 0xb50d42e7 , 0x201479df , 0x0ff2a6e5 ] )
+  , Mont::unsafe_make( [ 0x981c5afe , 0x1836dd32 , 0x210cbced , 0x079cbb40 , 0x9665a02c , 0xcc72ef91 , 0x1b6944b8 , 0x0cfe7e71 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xb909ceec , 0x6c407649 , 0xa75aa9de , 0x306c7a9b , 0x496e970c , 0xd3b40552 , 0x7889e34f , 0x0e779a63 ] )
+  , Mont::unsafe_make( [ 0x175d0c59 , 0x43423d9b , 0xc7a12096 , 0xe1e63189 , 0xb878ab54 , 0x038e15c5 , 0x55fd4cca , 0x262d79f8 ] )
+  , Mont::unsafe_make( [ 0xaf6a58c4 , 0x65883de2 , 0xd5eb9fde , 0x099d322e , 0x15813095 , 0x6298786b , 0x8833002e , 0x02b9f6f8 ] )
+  , Mont::unsafe_make( [ 0x254010f3 , 0x577580aa , 0xb0cf3e59 , 0xffeb1738 , 0x94c428a4 , 0x120cde09 , 0x1ef0b82c , 0x0bc8f0f8 ] )
+  , Mont::unsafe_make( [ 0xda43cba5 , 0x5f609041 , 0x9832260f , 0x87b84e3f , 0xde71d5aa , 0x2e7fb727 , 0x60832363 , 0x1738e9a4 ] )
+  , Mont::unsafe_make( [ 0x868e15e1 , 0xee8aa7c0 , 0x2dbad7c5 , 0x2f6de002 , 0x7058dc6f , 0x1c6dd34a , 0x785b025f , 0x04cdf6fd ] )
+  , Mont::unsafe_make( [ 0x903dfc93 , 0x68ec0c76 , 0x664ff2b9 , 0xcb46fd5e , 0xfd7ffde1 , 0x884196b8 , 0x37e6601e , 0x1a27f7d3 ] )
+  , Mont::unsafe_make( [ 0xa710751e , 0x44016080 , 0xe300d66d , 0xa5a88066 , 0xb99d3f91 , 0x5fec3d7b , 0x727a601c , 0x1b786389 ] )
+  , Mont::unsafe_make( [ 0x9d04987f , 0x8c9da4f2 , 0x0912fb6c , 0x2409a826 , 0xe3c9cf0b , 0x50d38f1f , 0xfd0e8970 , 0x02ce76e1 ] )
+  , Mont::unsafe_make( [ 0x7ca91c96 , 0xc3f1aa02 , 0xcb6bb6a9 , 0x3f1bf3a9 , 0x13db1bbb , 0x3ae882df , 0xa5915aa6 , 0x200b06ec ] )
+  , Mont::unsafe_make( [ 0x36398980 , 0xcc69a46b , 0x82380f34 , 0x8f6b5575 , 0xb04e560f , 0x6e109b72 , 0x86ce55fc , 0x05c6a813 ] )
+  , Mont::unsafe_make( [ 0x0134fdb9 , 0x9ffd9226 , 0x2c9e0d2f , 0x4809c9f9 , 0xc81bb92a , 0xbb32ba99 , 0xb2e4f8a9 , 0x0b5c8ab4 ] )
+  , Mont::unsafe_make( [ 0x189d5e07 , 0x8b5164f0 , 0x0ded235a , 0x95677f44 , 0xa3448a5d , 0xcbe625ab , 0x2d51c9f4 , 0x08271e02 ] )
+  , Mont::unsafe_make( [ 0x2fc54536 , 0x1cb6b041 , 0x6fdf9666 , 0x81a6ded8 , 0x8dd19169 , 0x3686786d , 0xe870e25c , 0x2ef8236b ] )
+  , Mont::unsafe_make( [ 0xe8ba6633 , 0xf1ae44e2 , 0x6ac09339 , 0x8fbcd121 , 0x947d10f6 , 0xc501a790 , 0xb3c5608c , 0x100ac4ac ] )
+  , Mont::unsafe_make( [ 0xed26a6a8 , 0xc5d26aca , 0x67fe4607 , 0xa4472647 , 0x226cbe9b , 0x85aa5edf , 0xc34c00c2 , 0x1199b990 ] )
+  , Mont::unsafe_make( [ 0x00a9e3b3 , 0x45663388 , 0xaaf4d316 , 0xa02b5d39 , 0x2028a20e , 0xf54a3a7d , 0x3acc6d8e , 0x1256767b ] )
+  , Mont::unsafe_make( [ 0x6d145b0f , 0xc8e265b6 , 0x621b9464 , 0xdea15004 , 0xb10771e1 , 0x19424ac5 , 0x40a9287e , 0x020cae9e ] )
+  , Mont::unsafe_make( [ 0x62da3a7c , 0x04c508ec , 0xbc99561f , 0x2214726a , 0x2507d2de , 0x0722d771 , 0x54c09811 , 0x0299ca51 ] )
+  , Mont::unsafe_make( [ 0xf6fb6a55 , 0x07d5010d , 0xdf82c782 , 0x15c03c14 , 0xfaaa036a , 0xdcc79e43 , 0xae2f8f1d , 0x267e9eb8 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x8c79e05e , 0xb69bcf1a , 0xc874ec4c , 0xceaa8ba1 , 0x0b31951e , 0x272b3f56 , 0xd64daef1 , 0x10b9da25 ] )
+  , Mont::unsafe_make( [ 0xa42d9dcf , 0x43fdb8e3 , 0xabc77d89 , 0xea464864 , 0xb9441aa5 , 0xd0d65c0b , 0x41c07d0e , 0x2f7685ce ] )
+  , Mont::unsafe_make( [ 0xfad43f16 , 0x4b192155 , 0x7e5a89fe , 0x667cc12d , 0xb1038e5c , 0x1bb29e21 , 0x4a5cbbc2 , 0x2b5986bd ] )
+  , Mont::unsafe_make( [ 0xca512acf , 0xa13f9ff8 , 0xac37041c , 0xcdc027cb , 0x7189451e , 0xce28f77d , 0xbaa92a68 , 0x2d2cb6f7 ] )
+  , Mont::unsafe_make( [ 0xa7e1b84b , 0x9e94e3b9 , 0xd60bf966 , 0x88f035db , 0xd8898c3a , 0xbe737ed3 , 0xc03c2b7c , 0x0f88d15d ] )
+  , Mont::unsafe_make( [ 0xbf80e956 , 0x7bcbed83 , 0x28349a5a , 0x1671a9bd , 0x4c52207a , 0xe8b7b0ae , 0xade5de5f , 0x23ba18e4 ] )
+  , Mont::unsafe_make( [ 0xf2283d70 , 0x76c088c4 , 0x68f3cfc4 , 0x3714d497 , 0x6e09577b , 0xc18c51df , 0x23ef44b3 , 0x1b674f3b ] )
+  , Mont::unsafe_make( [ 0x65ac0bab , 0xbbad853a , 0xab055489 , 0xefd20086 , 0x5a80050f , 0x45a18668 , 0x514ee644 , 0x29fdfe7a ] )
+  , Mont::unsafe_make( [ 0x71ed2198 , 0xec5155a0 , 0x2914e952 , 0x7410070e , 0x551a6210 , 0x477c0e40 , 0xe0413ef6 , 0x14a9c61c ] )
+  , Mont::unsafe_make( [ 0x7be800c2 , 0x43232e14 , 0x12b84c89 , 0x864bc826 , 0x6d33032c , 0xa0e744d5 , 0x97217ef2 , 0x124f715d ] )
+  , Mont::unsafe_make( [ 0x6403e11b , 0x834214ab , 0x5cb255da , 0x0fe08000 , 0x0b2bc814 , 0x5dc35ee3 , 0x2aa5aee8 , 0x110a002c ] )
+  , Mont::unsafe_make( [ 0x5227f713 , 0xd51cd681 , 0xf5b0fbc9 , 0x2ad37615 , 0x7a10a52c , 0x0d4f2acc , 0xb24ce461 , 0x0e7bda1d ] )
+  , Mont::unsafe_make( [ 0x1707a49b , 0x4971a9dc , 0x5506c358 , 0x79928766 , 0xc28cf3b2 , 0x034ff9c3 , 0x2a9c3eb8 , 0x17f16c6b ] )
+  , Mont::unsafe_make( [ 0xb50a39b0 , 0x5a6d9259 , 0x2e1fa993 , 0x940e0159 , 0x4d8d9160 , 0xc3dcfece , 0x7eb38765 , 0x1892a650 ] )
+  , Mont::unsafe_make( [ 0x182b3cd1 , 0x9fe78d7c , 0x82d1dfc8 , 0xf11de945 , 0xd772cf04 , 0x5adb7e19 , 0x3b9868e6 , 0x1143551d ] )
+  , Mont::unsafe_make( [ 0xd8099eea , 0xb6d19173 , 0xf1887fcb , 0xb1bdc560 , 0x4fa6528b , 0x7b406775 , 0x0321e572 , 0x13763f26 ] )
+  , Mont::unsafe_make( [ 0x341148cb , 0x5e18d977 , 0x6edb0b88 , 0xcd5677fc , 0x3722b3d9 , 0xe39f7eaa , 0x90342edf , 0x0ea6b4be ] )
+  , Mont::unsafe_make( [ 0xf990221d , 0x72ac3303 , 0x117b0cb4 , 0xde1dfa04 , 0x72a332e9 , 0xb4bd13eb , 0x112718fa , 0x16d8d3be ] )
+  , Mont::unsafe_make( [ 0x606a4f21 , 0xaf311774 , 0x50b30798 , 0x250ea87d , 0x6798f211 , 0x363955ad , 0x5ee2dcba , 0x2ad49cf6 ] )
+  , Mont::unsafe_make( [ 0xa52f40e1 , 0x1a141487 , 0x201cff61 , 0x67e0ac6f , 0xa9029a3f , 0x3d600bdc , 0x4214882f , 0x07235912 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xa15f7f16 , 0x8426cad4 , 0x6f55042f , 0xf4393074 , 0x185ebf29 , 0xc9b8e18c , 0x2c055365 , 0x135441d2 ] )
+  , Mont::unsafe_make( [ 0x4c43ef63 , 0xf87b8b48 , 0x48b89fd9 , 0xfa70e46a , 0x162d4849 , 0xbb0292ce , 0x817e0ee3 , 0x21a72e31 ] )
+  , Mont::unsafe_make( [ 0xde2712f3 , 0x08118f41 , 0x6cf2f718 , 0x840a674e , 0xc873a3c4 , 0x09e69419 , 0x4619c548 , 0x052ce91f ] )
+  , Mont::unsafe_make( [ 0x53dee43b , 0x52f48636 , 0xc9418b70 , 0xabebea2c , 0xea0adc36 , 0xd8c02809 , 0xb67cda52 , 0x266339a8 ] )
+  , Mont::unsafe_make( [ 0xaa862e3d , 0x380f2ded , 0x3f411fb2 , 0x0de6a737 , 0x096ddc58 , 0x0a53636a , 0x1d10beb8 , 0x2b5a2a79 ] )
+  , Mont::unsafe_make( [ 0x5599decd , 0x65718023 , 0x1deaa698 , 0xd5b1757c , 0xea6b983c , 0x0ae37a07 , 0xfc0e59c0 , 0x0d840b1e ] )
+  , Mont::unsafe_make( [ 0x8a88fad7 , 0xfc8e9689 , 0xe0afe138 , 0x4e3d99c4 , 0x7f2b6c85 , 0x9fbb03ff , 0x8abfa08f , 0x23d4ecdc ] )
+  , Mont::unsafe_make( [ 0x1befdd5e , 0xf7a76bd8 , 0x4e7d5316 , 0x7370dc9a , 0x1c6159f7 , 0xfc84f749 , 0x57927096 , 0x0ea476c6 ] )
+  , Mont::unsafe_make( [ 0x56ad8c05 , 0x364e9ad1 , 0x99a531a7 , 0xa78ab39c , 0xdf2a07d2 , 0x2bb769c3 , 0x49bd8c20 , 0x1a95f872 ] )
+  , Mont::unsafe_make( [ 0xce4b515a , 0x17796f9c , 0x39e876b8 , 0x44a01700 , 0x48f95b08 , 0x4b9ff0ef , 0x91bf4b55 , 0x14c2d9c3 ] )
+  , Mont::unsafe_make( [ 0x229730f5 , 0x02d6dde3 , 0x16c853de , 0x7b6456ab , 0x8a279754 , 0x6f90a941 , 0x1e4b3bc2 , 0x165db3dd ] )
+  , Mont::unsafe_make( [ 0xa9a5cbad , 0xf7355e1d , 0x62971297 , 0x5e369bb9 , 0xec24d1f0 , 0x78e6ca2c , 0xde316579 , 0x24ebc0a5 ] )
+  , Mont::unsafe_make( [ 0x7f79e13f , 0x6092c9f9 , 0x0123949f , 0x6e59badc , 0xcdf9f20a , 0xaaebd64d , 0x5a404336 , 0x2776912d ] )
+  , Mont::unsafe_make( [ 0x70b31d64 , 0x72f42d2f , 0x52587967 , 0xbbf8d182 , 0x41333345 , 0x823ee084 , 0x8d7999c0 , 0x2dd48ba7 ] )
+  , Mont::unsafe_make( [ 0xf65ade09 , 0x5e0b05a3 , 0xa08996f9 , 0x648fad2c , 0x9e42e106 , 0x14e0006e , 0x29fd02ca , 0x2590945a ] )
+  , Mont::unsafe_make( [ 0x46d2483a , 0x29ac8a95 , 0x1bb18272 , 0x9cd5257a , 0x605286e7 , 0xcdef467d , 0xaa46823f , 0x145831ff ] )
+  , Mont::unsafe_make( [ 0x8fda0b09 , 0x325fbecb , 0x95653442 , 0x26fa0e81 , 0x52ee5e1c , 0x6b7d84d9 , 0x88db6143 , 0x12b0e933 ] )
+  , Mont::unsafe_make( [ 0xb25f98b1 , 0x6b9dab36 , 0x14f77e55 , 0xf3d909db , 0x57beb166 , 0xd12b95e8 , 0xc0e6c3b6 , 0x0e49f802 ] )
+  , Mont::unsafe_make( [ 0x950d929f , 0xd6a0ebe1 , 0x928be801 , 0x90b25845 , 0xe4f2ce12 , 0x469ce7bd , 0x137ce793 , 0x0815dce9 ] )
+  , Mont::unsafe_make( [ 0x96cb4fa5 , 0xa159f879 , 0xb561061c , 0x22e49211 , 0x0f841bab , 0x046664eb , 0xb036474e , 0x25400c8b ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x0f1db641 , 0xb9c65549 , 0x175ec515 , 0x4430b2dd , 0x97b741c2 , 0xd11931a3 , 0x223b5e00 , 0x07d3d539 ] )
+  , Mont::unsafe_make( [ 0x1eaf7493 , 0xfac773bd , 0xb3e67313 , 0xf0bc8349 , 0xe4a84e73 , 0x4f5f1b5d , 0xad4704e3 , 0x026035f1 ] )
+  , Mont::unsafe_make( [ 0x7647223f , 0x76034d3d , 0x6ca51474 , 0x10fa9032 , 0xe34975f8 , 0x2d33a9d7 , 0xf5f3fab6 , 0x076aa21a ] )
+  , Mont::unsafe_make( [ 0x3e45fa88 , 0xb820c6e1 , 0x1e8461e5 , 0xb6746508 , 0xb6f3a76a , 0x2343acb1 , 0x1ca3c4ec , 0x2833369f ] )
+  , Mont::unsafe_make( [ 0x19e8ea4c , 0x115b595c , 0xe6c2878a , 0x2f0c6417 , 0x544db3a1 , 0x9c1757a6 , 0xd5e892f0 , 0x297780dc ] )
+  , Mont::unsafe_make( [ 0xcb2da1f4 , 0xf8948815 , 0x41e1b169 , 0x4bbed912 , 0x1fc99ac1 , 0x79034f8c , 0xf66cd956 , 0x1839f76f ] )
+  , Mont::unsafe_make( [ 0xd2541a84 , 0x878a189b , 0x4a9a00ac , 0xf65c61d3 , 0x02706d48 , 0x563b7e0c , 0x0bb0dde6 , 0x0aed6080 ] )
+  , Mont::unsafe_make( [ 0x7b1f2d8d , 0x90d0daab , 0x11ca1dc4 , 0xe3f17813 , 0x46f0b0ec , 0x9c1900a0 , 0xa9cfb5a1 , 0x18b9afa2 ] )
+  , Mont::unsafe_make( [ 0x38d98956 , 0x973a2295 , 0x4f00854a , 0x819272fb , 0x4c89e39c , 0x593d9757 , 0x6eee7c62 , 0x0e088db7 ] )
+  , Mont::unsafe_make( [ 0x71564498 , 0x1807f07a , 0x12448ed1 , 0x697774a8 , 0x81c04a60 , 0xf53ca492 , 0xb2737cdc , 0x0aa81232 ] )
+  , Mont::unsafe_make( [ 0xb4858a81 , 0x33cdb914 , 0xf76c6f6a , 0xcb78c533 , 0x3eabed28 , 0xf876e078 , 0x033c304a , 0x29b2f95b ] )
+  , Mont::unsafe_make( [ 0x856ab2b8 , 0x7c7a63be , 0x1575b9fb , 0x5ff4359b , 0xbbbd0f64 , 0x22e760f5 , 0xacbcbc8b , 0x28f0213c ] )
+  , Mont::unsafe_make( [ 0xeba479e5 , 0xc66c4e01 , 0xedb92f2e , 0x2c7e3abd , 0x0703ec90 , 0x72442aa8 , 0x479a1019 , 0x137fc67d ] )
+  , Mont::unsafe_make( [ 0x0bbd6fd2 , 0xd1e28421 , 0x9cd1cd76 , 0xd59ecb0c , 0x8698a757 , 0xbd8a169f , 0xde71c608 , 0x132770cc ] )
+  , Mont::unsafe_make( [ 0x30f822cf , 0x66f0fde1 , 0x54a29bee , 0x56175a6c , 0x172f99c7 , 0xa4b5878c , 0x251373fd , 0x23981ce7 ] )
+  , Mont::unsafe_make( [ 0x90a6c49f , 0x603b11d9 , 0xc1da62f1 , 0x219e7025 , 0xbd6df440 , 0x314224db , 0x905b8f4e , 0x1820bb7f ] )
+  , Mont::unsafe_make( [ 0x3550fbc3 , 0xa35a7185 , 0xa8698b59 , 0x503a8bce , 0x3ca7a72a , 0xe4addb09 , 0x0d7a1bf7 , 0x2ca9ea9b ] )
+  , Mont::unsafe_make( [ 0x78be59ad , 0xc65c7f50 , 0x576fc3f8 , 0x2387537c , 0x49a7ed2d , 0xba32faec , 0xd421e6f4 , 0x1b04201a ] )
+  , Mont::unsafe_make( [ 0x8cd94e6a , 0x2d04fff7 , 0x6500b863 , 0x5653ad4b , 0xfe06ba22 , 0xeba34dcc , 0x294086d2 , 0x2f1fe1cb ] )
+  , Mont::unsafe_make( [ 0xa5e6aff7 , 0x2267e356 , 0x2a554746 , 0xd6cc256a , 0xf5ef8221 , 0x814fb432 , 0xc9119aa6 , 0x183f54c9 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xc8d1d846 , 0xb077516d , 0x51d7027d , 0x2a2080ea , 0xb342dba9 , 0x1e6c41c3 , 0xf4eb03ec , 0x0a8a0896 ] )
+  , Mont::unsafe_make( [ 0xe8aaa8a1 , 0x8902a5b0 , 0xac84c382 , 0x8ffa2419 , 0xaac55dd0 , 0xdbf3950a , 0xd35d4d1e , 0x2c8c97c3 ] )
+  , Mont::unsafe_make( [ 0x2cbcbc38 , 0xa063f394 , 0xf8d027c1 , 0x4de71f84 , 0x383136ba , 0x633e71d2 , 0xdfeef35c , 0x02844f67 ] )
+  , Mont::unsafe_make( [ 0xddf27776 , 0x79a4f558 , 0xc31e4d87 , 0xe2844e94 , 0x7c27a3b7 , 0x540c7631 , 0x41f2e54c , 0x1e680378 ] )
+  , Mont::unsafe_make( [ 0x24a0b43d , 0x2543540a , 0xe9d8883f , 0xc638661b , 0x36d90c90 , 0xd1077c14 , 0xd4d4290e , 0x270b7eff ] )
+  , Mont::unsafe_make( [ 0x6ccbf3ea , 0x25bd2470 , 0x501612f6 , 0x36018b4c , 0xc49a3618 , 0xf0827585 , 0x99bae866 , 0x280aa764 ] )
+  , Mont::unsafe_make( [ 0x7d6644f2 , 0x54146a0b , 0x243b6731 , 0xe1347fca , 0x2d069347 , 0x3f08b9f2 , 0xedd2c39f , 0x25d5f8f9 ] )
+  , Mont::unsafe_make( [ 0x19a6cb7e , 0x9be763e6 , 0xa03f22ab , 0x22c67f06 , 0xc9da2c52 , 0x21f86919 , 0xc5ff3d94 , 0x03dce604 ] )
+  , Mont::unsafe_make( [ 0x55b27789 , 0x8dc84d26 , 0x1f44fe4c , 0xdb27f4b3 , 0x4df9fe82 , 0x5e461ce6 , 0xd62a6040 , 0x2b1a1b2c ] )
+  , Mont::unsafe_make( [ 0x2daed63f , 0x71e514bb , 0x1b410046 , 0x7ea3f736 , 0x88b4dd12 , 0xe27d0210 , 0xb408bafb , 0x0122e37f ] )
+  , Mont::unsafe_make( [ 0xf9981d88 , 0x97856f53 , 0xb1eeec11 , 0xe0230309 , 0x7f0aaa2a , 0x787e9fb2 , 0x2c57fbcc , 0x2e1e909b ] )
+  , Mont::unsafe_make( [ 0x42763613 , 0xe99e7629 , 0x9c6f49b4 , 0x104e9095 , 0x47631ea2 , 0x5c3338e6 , 0x4b4a8351 , 0x076ce14a ] )
+  , Mont::unsafe_make( [ 0xabc93c6a , 0x4e9513d7 , 0x7c381afc , 0x02de6416 , 0x16c08a28 , 0x74f938a0 , 0x73cd1f55 , 0x2527a8b8 ] )
+  , Mont::unsafe_make( [ 0xacb2f40e , 0x8dc6be04 , 0xc6955ad4 , 0xb443ea42 , 0x5ad3632e , 0x1904c005 , 0xfb94717d , 0x1b2f5904 ] )
+  , Mont::unsafe_make( [ 0x46a261ba , 0xb4f56dcc , 0x29f583f5 , 0xa60ca5d5 , 0x2b580a06 , 0x929a53a2 , 0xcb8ec3b0 , 0x1ab6b5cc ] )
+  , Mont::unsafe_make( [ 0xd99216e1 , 0x60f2946f , 0xb624b009 , 0x44918ee4 , 0x56a8941e , 0x691e993d , 0x81873f3e , 0x2c7f5f90 ] )
+  , Mont::unsafe_make( [ 0x6b55c3b9 , 0x34d21459 , 0xa468f847 , 0x627fab3c , 0x3bed3315 , 0xcc34a5bb , 0xdd1c94d3 , 0x150682aa ] )
+  , Mont::unsafe_make( [ 0x6c083ab2 , 0x80e4f4d2 , 0xcbdf1adf , 0x54937782 , 0xe32c9913 , 0xd59690fc , 0x5ed7d5cf , 0x12ce187c ] )
+  , Mont::unsafe_make( [ 0x44e0d8d0 , 0x0773bbd5 , 0x33da3fef , 0x5ab2c44e , 0x2d585c64 , 0xdbb2166f , 0x0cbbfd85 , 0x23d05534 ] )
+  , Mont::unsafe_make( [ 0xfaccd40c , 0x30be0fde , 0x708442a0 , 0x50b3635e , 0x07cdb859 , 0x7f4e87f2 , 0x6c949b95 , 0x23554426 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x37d9fafd , 0xa7530733 , 0xc8f656a1 , 0x7c8b2c3c , 0x2d333c7d , 0x9285743d , 0x768d41f4 , 0x1af4888f ] )
+  , Mont::unsafe_make( [ 0x6f8de26c , 0x247c28fb , 0x364c70ba , 0x0e24f630 , 0x88e84627 , 0x438cc8c3 , 0x9dac6e17 , 0x26b4c731 ] )
+  , Mont::unsafe_make( [ 0x1c16e2dc , 0x50b09434 , 0x06aac474 , 0x9dd78a6e , 0x9ac4b25e , 0xefe143a0 , 0x018e741d , 0x1c7aa726 ] )
+  , Mont::unsafe_make( [ 0x6d3c32b6 , 0x5acdd2b4 , 0x858a67ab , 0xdf91f139 , 0x738f9029 , 0xfd3fa367 , 0x67749f5f , 0x190ae9bc ] )
+  , Mont::unsafe_make( [ 0xad329c35 , 0x5bc1f4b2 , 0x0ed4c582 , 0x3ee33fff , 0x45c340bd , 0x3637d009 , 0x0337a0dc , 0x15cf71f0 ] )
+  , Mont::unsafe_make( [ 0x12a53009 , 0x0bded615 , 0xe0121310 , 0x109f6105 , 0x89d797b8 , 0x75a4645e , 0x97c49c98 , 0x214992da ] )
+  , Mont::unsafe_make( [ 0x7abe5a39 , 0x1346b0d3 , 0xc9ac2f83 , 0xe5add08e , 0xafd61e53 , 0x568e9e00 , 0x9659ef79 , 0x07b1c53d ] )
+  , Mont::unsafe_make( [ 0x066ab633 , 0xa0cc9d06 , 0x76e03d35 , 0x8bd53d81 , 0xec3fb3c8 , 0xe3ca3de9 , 0xae3d1e4f , 0x2b033d50 ] )
+  , Mont::unsafe_make( [ 0xd6fe70e9 , 0xaa730ab9 , 0xede25eb0 , 0x7cf28b92 , 0x9b5f58cf , 0xaae145ad , 0xae4cfd63 , 0x2219a0b6 ] )
+  , Mont::unsafe_make( [ 0x2ba603f1 , 0x8c9d685d , 0xe47b678b , 0xab56434e , 0x1731bfc0 , 0x61d5e14e , 0x83349696 , 0x0f33247e ] )
+  , Mont::unsafe_make( [ 0x9102dc19 , 0x3df45080 , 0x92fd571e , 0xa60f8412 , 0x78438b46 , 0x50b53dab , 0xa4571276 , 0x0571d559 ] )
+  , Mont::unsafe_make( [ 0xb628af17 , 0x3a5a78c5 , 0x2d1fbd8c , 0x3abd93fc , 0x47f39614 , 0x0ecc82f0 , 0x21bbcbb9 , 0x2af8cc2e ] )
+  , Mont::unsafe_make( [ 0x3054a0fe , 0xde113df5 , 0x02584deb , 0xbb035a67 , 0x09900973 , 0xa01c8582 , 0xac2f6d29 , 0x0c1e53ff ] )
+  , Mont::unsafe_make( [ 0x1f97145d , 0x85a2c0cd , 0xd337126c , 0x82f4f7ae , 0xfdb86249 , 0x2d5df49e , 0x56515013 , 0x085b7eaf ] )
+  , Mont::unsafe_make( [ 0x675d511a , 0x5b167c64 , 0xcb51b1d5 , 0x9ab03437 , 0x49c0e6e4 , 0x8a992892 , 0x5d1113dd , 0x116b12d3 ] )
+  , Mont::unsafe_make( [ 0x0e6bb28a , 0x07cffe2e , 0x44327ddb , 0x6841c890 , 0x2ee3430e , 0x670771ed , 0x4ec3da70 , 0x0e34d1a8 ] )
+  , Mont::unsafe_make( [ 0x616f061e , 0x935fe13a , 0x48106fae , 0xda7366c0 , 0x1fead372 , 0x9d025005 , 0x1612f0bb , 0x126c431d ] )
+  , Mont::unsafe_make( [ 0xb6f57469 , 0x3d21767a , 0xb8fe6929 , 0xe616fdcd , 0x13df5707 , 0x3729acd6 , 0x7baff8ea , 0x19b01a9e ] )
+  , Mont::unsafe_make( [ 0x61d3f71b , 0xf92b947c , 0xf3a64a87 , 0x01cb40dd , 0x33e89ccf , 0x951a4d81 , 0x32759ece , 0x02134136 ] )
+  , Mont::unsafe_make( [ 0x4f89bdcc , 0xcc8641ba , 0xd9eb6927 , 0x0b3688ce , 0xf7495fe7 , 0x9c244fda , 0xbecf6185 , 0x14d03c25 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x46a88394 , 0x1afb8478 , 0x14faaaea , 0x0618d023 , 0x7d32091e , 0x5ccbf0c0 , 0xf5c80544 , 0x2b1a32dc ] )
+  , Mont::unsafe_make( [ 0xee79156b , 0x38b92ad9 , 0x2f65395f , 0xbf50a3f3 , 0x6afd7859 , 0xdfe9530c , 0x4463cbe5 , 0x0fbd0ec7 ] )
+  , Mont::unsafe_make( [ 0x61f94e84 , 0x0e70731b , 0xc7a841ec , 0x0b45db5c , 0x752f2b21 , 0xb149ea2d , 0x81d62465 , 0x2dd06a95 ] )
+  , Mont::unsafe_make( [ 0xac150844 , 0xc76cb798 , 0x7d637080 , 0x9d9be086 , 0x929629ce , 0x5fe4015b , 0x828619b2 , 0x0963250e ] )
+  , Mont::unsafe_make( [ 0x1e46b35e , 0x229c42ad , 0x81f46a5f , 0x99ee1ac9 , 0x4e37d4f7 , 0x3bacfd37 , 0x7346e55b , 0x23450010 ] )
+  , Mont::unsafe_make( [ 0xdb850567 , 0xa234af9e , 0x3d722e64 , 0xe90acdbd , 0xfeb39721 , 0x7f6e5d26 , 0x46461630 , 0x0c52412d ] )
+  , Mont::unsafe_make( [ 0xe0866fe9 , 0x137416ff , 0xf07eeeb0 , 0xfa6c712d , 0xfccc4630 , 0x09023ef7 , 0x8c339e55 , 0x1898ba49 ] )
+  , Mont::unsafe_make( [ 0x4c89b75a , 0x30925294 , 0x41ce9c45 , 0x13cab0ce , 0x03b1f259 , 0x9eb5bc51 , 0x62892aca , 0x290671e8 ] )
+  , Mont::unsafe_make( [ 0xab79ab1b , 0x5c3dcd08 , 0x31bda983 , 0x03e8c556 , 0x596e7974 , 0x9f19d556 , 0x66a5c959 , 0x16a8796f ] )
+  , Mont::unsafe_make( [ 0x65bc9782 , 0x807c88f3 , 0x955f4b29 , 0xd791bf46 , 0xf77f11ec , 0x25165061 , 0xb6c48696 , 0x23ea35ca ] )
+  , Mont::unsafe_make( [ 0x2bbad52b , 0x83361f65 , 0x4f277f7e , 0x45ad8047 , 0x0ea05a1a , 0x54d0378b , 0x36af9a12 , 0x19dd30a6 ] )
+  , Mont::unsafe_make( [ 0xca4e9cd2 , 0x723ada58 , 0xcb95a37a , 0x3d3be668 , 0x42b9053e , 0xe61516e9 , 0xec273dd0 , 0x2e6f5b87 ] )
+  , Mont::unsafe_make( [ 0x51805ab4 , 0x0557e0eb , 0x2831591c , 0x95ba6ff7 , 0x87d2398d , 0x79d6e42d , 0x85aa8bd9 , 0x27dfee41 ] )
+  , Mont::unsafe_make( [ 0x6ed326cc , 0x67519d47 , 0x3f833a4b , 0xc6102d1c , 0x7e422017 , 0xbece3465 , 0x9c285890 , 0x26a26640 ] )
+  , Mont::unsafe_make( [ 0xcb710ba0 , 0x4dbf6d0e , 0x6f2e56b8 , 0xca858037 , 0xc5484081 , 0xed07f85c , 0x39ff5314 , 0x005c471e ] )
+  , Mont::unsafe_make( [ 0x1c160bda , 0x2ae3efc8 , 0x655ee6d3 , 0x15c64b29 , 0xe8f79ba5 , 0xe13b84c0 , 0x590c0fe6 , 0x25229893 ] )
+  , Mont::unsafe_make( [ 0x75f5c031 , 0xed6ce822 , 0xf49d3397 , 0xd68eb99f , 0x9650bffe , 0x2fa89aad , 0x4e2747f9 , 0x1459db0e ] )
+  , Mont::unsafe_make( [ 0xbd7dda88 , 0xdb7319d3 , 0xe13d0835 , 0xd6fcc502 , 0xb15bf9ae , 0xabf5e7ad , 0xfe0bfc1c , 0x00e060d6 ] )
+  , Mont::unsafe_make( [ 0x676456d3 , 0x72c60d10 , 0x09ce1f91 , 0xcd125f6f , 0x3c54a716 , 0x3f02af36 , 0x1d68445a , 0x1811055f ] )
+  , Mont::unsafe_make( [ 0x56545d7f , 0x59e8b927 , 0x17ced22d , 0xb3f6ca36 , 0x4174c253 , 0x3f1e45c0 , 0x528979f8 , 0x0ad843f0 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x7a78ae2b , 0x36368935 , 0x85287aaf , 0x6e8a5ed3 , 0xa7d8bf51 , 0x24ab9c98 , 0x4eec9e37 , 0x151b674a ] )
+  , Mont::unsafe_make( [ 0x9f20511a , 0x7bb5534a , 0xfc255102 , 0xc12ee386 , 0x414db7a2 , 0xde13a0c1 , 0xc7695048 , 0x1131301c ] )
+  , Mont::unsafe_make( [ 0x27717229 , 0xeab05981 , 0xb6778ded , 0xaaafa460 , 0xf8de4a8a , 0x91882e33 , 0x14eb47fc , 0x224f1fdc ] )
+  , Mont::unsafe_make( [ 0x30623012 , 0xbbc44537 , 0x901c224d , 0xc67fabff , 0x39781639 , 0x4800225f , 0x0c89d2c2 , 0x1e5c6a02 ] )
+  , Mont::unsafe_make( [ 0x9607ef33 , 0x759a93cc , 0xdbcb15b5 , 0x1e471e31 , 0xc33dabcd , 0x2b75a100 , 0xb5ac749f , 0x2c14faa6 ] )
+  , Mont::unsafe_make( [ 0xfb717c10 , 0xe1aae139 , 0x3152e39f , 0x27b1065c , 0x51e8ce92 , 0x3860c417 , 0x93217eef , 0x2bbf2cf8 ] )
+  , Mont::unsafe_make( [ 0xfd756722 , 0xd1e3dfd1 , 0xa4d71fe5 , 0xcd8e28a3 , 0x72fe7572 , 0x6d693772 , 0xd5b5838b , 0x207d31c7 ] )
+  , Mont::unsafe_make( [ 0xc57f351c , 0x80fbc9cf , 0xea048da9 , 0xb05f6614 , 0x47073acf , 0x5d4059d9 , 0x7e411659 , 0x00f07636 ] )
+  , Mont::unsafe_make( [ 0x885cbf19 , 0x91f0bdfd , 0x160bae7b , 0xd8959648 , 0x4e9dabbf , 0x7f9c7f80 , 0xed6936c4 , 0x133e64b9 ] )
+  , Mont::unsafe_make( [ 0x5c7860c1 , 0x133f431c , 0x9e256d54 , 0xef476e88 , 0x294e02cc , 0xd948fb6b , 0xf5b76e78 , 0x2106007c ] )
+  , Mont::unsafe_make( [ 0xb6ba1c30 , 0xcdddfbba , 0xc9beb343 , 0xd3744a34 , 0x4c25d66f , 0x03eac371 , 0x6231513b , 0x218a9a89 ] )
+  , Mont::unsafe_make( [ 0xe567a167 , 0xa166d533 , 0x75a42ea0 , 0xee03090f , 0x1111483e , 0x2d9e1bea , 0x4be72b14 , 0x24375f2c ] )
+  , Mont::unsafe_make( [ 0xa980b2d8 , 0x7dbd57c1 , 0x42354bd3 , 0x653c0936 , 0xaa935a77 , 0x562ab240 , 0x19a4840e , 0x2217fd51 ] )
+  , Mont::unsafe_make( [ 0xcbc2967f , 0x2000ad02 , 0x31fd84f7 , 0xc73f88f8 , 0x62c6f85a , 0x425a4c1d , 0x6fb27bfe , 0x2cdb44d1 ] )
+  , Mont::unsafe_make( [ 0x323816b4 , 0x61c90bc4 , 0xd90987d7 , 0x0cb12445 , 0x9cf57dd0 , 0xb2cd6fe5 , 0xe6ffc7ee , 0x04e3173c ] )
+  , Mont::unsafe_make( [ 0x9d082d21 , 0x107e7e6f , 0x81fca39d , 0x54e396a7 , 0x077cba39 , 0x4a6e2e9b , 0x25275e92 , 0x0dab29c1 ] )
+  , Mont::unsafe_make( [ 0x6683b0ae , 0xe762edce , 0xad5c716d , 0xdf238158 , 0xc771ad0b , 0x999eebf2 , 0x35616d49 , 0x0b03e683 ] )
+  , Mont::unsafe_make( [ 0x0243b066 , 0x90918334 , 0xfcb8638b , 0x11bb3ed3 , 0xd3f99500 , 0xaf280fef , 0x1595918b , 0x097c1682 ] )
+  , Mont::unsafe_make( [ 0xe8322361 , 0x6623c5de , 0x56235410 , 0x953e5947 , 0x9b1a546c , 0x59cf827a , 0xf602b057 , 0x2a9bd002 ] )
+  , Mont::unsafe_make( [ 0x6d882886 , 0xe76772d3 , 0x2fe33585 , 0x7f6e2cd1 , 0xc0e72135 , 0x5f9609c8 , 0x5651a745 , 0x26ecc65d ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xd97fe317 , 0x7498d6ce , 0xbe740a6c , 0x14ee3e62 , 0x6b50b1c5 , 0xf2283291 , 0x7af1ac8a , 0x06932438 ] )
+  , Mont::unsafe_make( [ 0xb8460ea6 , 0x7e06d2e5 , 0x14857399 , 0x3deaf326 , 0xfe9c6852 , 0xac50199f , 0xa6ac69c7 , 0x00ec29b1 ] )
+  , Mont::unsafe_make( [ 0xd0c73a57 , 0xb4d384ec , 0x4599acf9 , 0xe72435c1 , 0x11c86a41 , 0x72b3ceec , 0x7530922e , 0x1784d444 ] )
+  , Mont::unsafe_make( [ 0x16a474a0 , 0xfe81555c , 0xc09dac7c , 0x1bf00c5b , 0x4aee3945 , 0x3f0457f1 , 0x3fc005c7 , 0x13dba9ff ] )
+  , Mont::unsafe_make( [ 0x841a4f66 , 0x46ec28cd , 0x0c130c75 , 0xdb4f1edd , 0x5e2e1e77 , 0x82c26f03 , 0x0c8e4274 , 0x01d340c8 ] )
+  , Mont::unsafe_make( [ 0x0f92e228 , 0x8e9ca846 , 0x6819fe52 , 0xdd8a8222 , 0xf1b93b3a , 0x24573eac , 0x052c546d , 0x171826d4 ] )
+  , Mont::unsafe_make( [ 0xf6c5f646 , 0x54ccb1ff , 0xba7cad99 , 0x7352aa9c , 0x7e38d052 , 0xa81a9072 , 0x59056a3d , 0x01d462cf ] )
+  , Mont::unsafe_make( [ 0x3eb054c1 , 0xf49329b2 , 0x45b14eba , 0x55a5b788 , 0x4b8e8f22 , 0x75e64bae , 0xcf568a12 , 0x1097df24 ] )
+  , Mont::unsafe_make( [ 0x4701142d , 0x5c06805e , 0xceda5bb8 , 0x48bfa2c7 , 0x4669263c , 0xd6422c7c , 0x8d005971 , 0x183817d9 ] )
+  , Mont::unsafe_make( [ 0xf74f6e14 , 0x8d8025e7 , 0xca8a506a , 0xaa4c7096 , 0x0a9351b2 , 0x73ecec9d , 0x59c6df25 , 0x0ccbf3fd ] )
+  , Mont::unsafe_make( [ 0x4c81c519 , 0xfeaaa77d , 0xebfc7f0e , 0x547b8985 , 0xff6c9bb8 , 0x01e7d4bc , 0x355b6d89 , 0x16480d62 ] )
+  , Mont::unsafe_make( [ 0x64475e34 , 0xaacc50f5 , 0xe3011d99 , 0xb1c541e8 , 0x2015ebf6 , 0x176611fa , 0x03902375 , 0x24ff0216 ] )
+  , Mont::unsafe_make( [ 0x4d32c8f7 , 0x6cb7fbbd , 0x4a00c4fb , 0xe991d970 , 0x74d41061 , 0xfefe04bf , 0x435001ff , 0x07836bbb ] )
+  , Mont::unsafe_make( [ 0xeac877ef , 0xa9946a40 , 0x54f655a0 , 0xf4fe961a , 0x65cebfed , 0x1b3a59f7 , 0xcbaf274e , 0x080a8c8f ] )
+  , Mont::unsafe_make( [ 0xbaaede48 , 0x44886e7f , 0x75ec4be4 , 0xff22a686 , 0xd3fb0fcc , 0x2bbe4436 , 0x843d9518 , 0x1f588fa3 ] )
+  , Mont::unsafe_make( [ 0xd3862003 , 0x7d0ffe0b , 0x5f9b172f , 0xa57c4b6a , 0xd56c2f97 , 0xb89d7b1d , 0xc2f60b0d , 0x000832f8 ] )
+  , Mont::unsafe_make( [ 0xdc041771 , 0x3ee052c0 , 0x9f43dd53 , 0x32351ee0 , 0xd31448f8 , 0xf44ac9c9 , 0x1418f2c8 , 0x1318d26b ] )
+  , Mont::unsafe_make( [ 0xadebaec3 , 0x60993971 , 0xe0f712af , 0xcc8c47d9 , 0x68738935 , 0xc6b016d2 , 0x3cba788c , 0x24751de3 ] )
+  , Mont::unsafe_make( [ 0xd66ac422 , 0x36b82e99 , 0xfe7f1732 , 0xb52efee4 , 0x222b7170 , 0xe39ef09e , 0x3bbff2f5 , 0x26a425ec ] )
+  , Mont::unsafe_make( [ 0xe66299ca , 0x73dd24bc , 0xc1cbc854 , 0x61e3538d , 0x572fe12b , 0x1447e2d0 , 0x79b5188e , 0x2a673ed4 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x6d7711d3 , 0xe95d95fb , 0xa3305710 , 0xbfd66883 , 0x7d3dc86e , 0x114d1e53 , 0x0645ed1b , 0x0e689965 ] )
+  , Mont::unsafe_make( [ 0x3a59f457 , 0x85082022 , 0x8f43a1ef , 0xb7880ce0 , 0x98298b70 , 0x9a8c54d0 , 0x03766149 , 0x0123b5e7 ] )
+  , Mont::unsafe_make( [ 0x90f34c51 , 0x221d1904 , 0x85bb72cc , 0x9be4c3f3 , 0xc7787fc9 , 0x30a0fdd3 , 0x1e7a2f4b , 0x2e6d4606 ] )
+  , Mont::unsafe_make( [ 0x5f6b4d1f , 0xcfbaabb2 , 0xeafff897 , 0xb3655302 , 0x31bd46f5 , 0xea52311f , 0x7d487f6d , 0x28b4295c ] )
+  , Mont::unsafe_make( [ 0x7427f197 , 0x9714933d , 0x54d508e2 , 0xa2f23769 , 0x1f2aedc2 , 0x57e2cfd8 , 0xba0896d8 , 0x0f786b8b ] )
+  , Mont::unsafe_make( [ 0xd4a92aba , 0x1970ad6e , 0x8adcda84 , 0xded91e17 , 0x84ca6cb6 , 0x05f7baa6 , 0x2cfdc173 , 0x1f067550 ] )
+  , Mont::unsafe_make( [ 0x7337f70d , 0x9e6961b2 , 0x4c99607b , 0x91c3ccf2 , 0x4472a84b , 0x812dfbed , 0x79a716da , 0x2a5b5a36 ] )
+  , Mont::unsafe_make( [ 0x2662c252 , 0x29824bae , 0x0516c372 , 0x3e0ce2e5 , 0x9f708d04 , 0xbd96518b , 0x6fe384b8 , 0x14ec78a0 ] )
+  , Mont::unsafe_make( [ 0xee63c990 , 0xffa988db , 0xadda1756 , 0x319cc098 , 0x447a31c9 , 0x04ce3b1c , 0xb31e19e0 , 0x28aefa1b ] )
+  , Mont::unsafe_make( [ 0xb79e45d4 , 0x77aca6d9 , 0x05d07eed , 0xd25cdb61 , 0x4a8d1a63 , 0x73a068e2 , 0x0a73c79e , 0x1f8a3e5c ] )
+  , Mont::unsafe_make( [ 0x0ce0dcdf , 0x7b1c1780 , 0xaa258fd6 , 0x54d7e524 , 0x3a331a16 , 0x1aa57cc8 , 0x86e744c2 , 0x09524dcd ] )
+  , Mont::unsafe_make( [ 0xc24ab99d , 0x114c2a53 , 0x0921c163 , 0x65164070 , 0xfe26b578 , 0x28727f8f , 0xb72d0108 , 0x02cb7d27 ] )
+  , Mont::unsafe_make( [ 0x4d2124e7 , 0xef6e36d4 , 0x8a8a462e , 0x996acfe2 , 0x169164e4 , 0x9a80b9ec , 0xa35f69fb , 0x272f67af ] )
+  , Mont::unsafe_make( [ 0x30078a98 , 0x11fa41b5 , 0xdc9f4e53 , 0xf14a75ee , 0x142238e0 , 0x16ccc280 , 0x4b321e10 , 0x1ca8aae8 ] )
+  , Mont::unsafe_make( [ 0x289d9afc , 0xad448f59 , 0x0e775072 , 0x6f8cf591 , 0x043402c4 , 0xcc24374c , 0x03ee60c0 , 0x1ebb4e6f ] )
+  , Mont::unsafe_make( [ 0x983af064 , 0xd0c2ac6c , 0xc6c4c3e0 , 0xd66c567a , 0x127cf432 , 0xbb1d6fb1 , 0xaf77a209 , 0x2c248947 ] )
+  , Mont::unsafe_make( [ 0xa7797143 , 0x67ac47a4 , 0x73a2b78a , 0x97fcf775 , 0x4c0cc821 , 0x51274ee2 , 0x24ba7c6b , 0x1d9da4a2 ] )
+  , Mont::unsafe_make( [ 0xc9cd7114 , 0x395ad06d , 0x4201b1ec , 0xb1739aab , 0x5b4a2e51 , 0x25aded86 , 0x822e3c32 , 0x0751a5f9 ] )
+  , Mont::unsafe_make( [ 0xb1d30389 , 0x18d4e3df , 0xd045e12a , 0x39f73810 , 0xd1f41187 , 0x4bba388b , 0xfbbef07e , 0x03acab6c ] )
+  , Mont::unsafe_make( [ 0x8fd10311 , 0x69e30747 , 0x13126701 , 0x0b5ebc65 , 0xe09752df , 0x0c0643d3 , 0x6cfcbbe6 , 0x302db87a ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xfdd2d6d9 , 0xa184e7bf , 0x12195e7a , 0x000a9216 , 0xf24a8e3e , 0xb23ce26d , 0x8a5842ac , 0x03c2a209 ] )
+  , Mont::unsafe_make( [ 0xbdd805a5 , 0xae767c4e , 0x25a57115 , 0x71959928 , 0xc3d9012b , 0x575022d9 , 0x656213de , 0x30148793 ] )
+  , Mont::unsafe_make( [ 0xa25fd25f , 0x3ded2bca , 0x62c62ae9 , 0x477b4c5c , 0x0d93b2e9 , 0xe8c320f7 , 0x34e8eb20 , 0x0e006697 ] )
+  , Mont::unsafe_make( [ 0x70ad6262 , 0xdcc82c6c , 0x157142f4 , 0x09fc99cc , 0x540abaf3 , 0x65918663 , 0x4f7573e3 , 0x0bfc4d0f ] )
+  , Mont::unsafe_make( [ 0x81d281a5 , 0xeb4cb469 , 0x41509589 , 0xd7b4b6ab , 0x25deef65 , 0x3d572216 , 0x43cca0b2 , 0x14c46b0f ] )
+  , Mont::unsafe_make( [ 0xc7393a27 , 0x4f4c1616 , 0x99de6cd5 , 0xdb840990 , 0x97bf5d38 , 0xe2bbd49e , 0x86d7c4d2 , 0x214a0ee0 ] )
+  , Mont::unsafe_make( [ 0x5e5abc99 , 0x4d9c169d , 0x83f48aa8 , 0x1fd63365 , 0xb6b4a544 , 0xf0906f68 , 0x45c0602a , 0x29b25581 ] )
+  , Mont::unsafe_make( [ 0xc5c2f55a , 0x81e7f5ee , 0x13e0fa8a , 0xef1fdf2e , 0x39bac7a2 , 0x7f25607a , 0x87501c54 , 0x24021025 ] )
+  , Mont::unsafe_make( [ 0xec8278fe , 0xba2a3a2b , 0x7355878e , 0x511e42e6 , 0xd6e5bb28 , 0x53204565 , 0x2d6275da , 0x21719cc2 ] )
+  , Mont::unsafe_make( [ 0x3f35068f , 0x38e988d0 , 0xe2e4cda8 , 0x9eb41338 , 0x47b4b8f8 , 0x2d4b796c , 0xadb37f48 , 0x27e4946d ] )
+  , Mont::unsafe_make( [ 0xa72091c0 , 0x4c67d43d , 0x8b8dd5f8 , 0xaa986596 , 0xe1dcbacf , 0x22cdc144 , 0xb12e230d , 0x020d9029 ] )
+  , Mont::unsafe_make( [ 0xdbb667eb , 0x2191f171 , 0x5f8a6c64 , 0x115583c6 , 0x8c11508c , 0x9c024ecb , 0xdee35a70 , 0x09a4a43f ] )
+  , Mont::unsafe_make( [ 0x29a9307f , 0xa2ea3316 , 0x3506de64 , 0xc7a42da6 , 0xf2616ef4 , 0xa85cc0c1 , 0x5fb11693 , 0x093fb1ce ] )
+  , Mont::unsafe_make( [ 0xae0f4aee , 0x5e7ba876 , 0x5bd2fc6f , 0xe7a98b25 , 0x53778fba , 0x4e08956d , 0x1000e766 , 0x27f35c8c ] )
+  , Mont::unsafe_make( [ 0x13e942b3 , 0xd7e51879 , 0xcbd39f85 , 0x7df27d39 , 0x22cae88e , 0xa3141210 , 0x2b2eb613 , 0x2e05c24b ] )
+  , Mont::unsafe_make( [ 0xdad98d1c , 0x127f550a , 0xaba0dfc5 , 0xa2ebd573 , 0x7defaea9 , 0xbb2580e5 , 0xae836f22 , 0x29312b9d ] )
+  , Mont::unsafe_make( [ 0x743826c7 , 0xea8681fa , 0x758a3420 , 0x65d8eaa7 , 0xe904c4d8 , 0xecd136f0 , 0x8713d2a2 , 0x1bb37c93 ] )
+  , Mont::unsafe_make( [ 0xd299b6f9 , 0x50cead0a , 0x40a71b8f , 0xb613e74f , 0xb09aa8f4 , 0x22fe11ee , 0xbffa70ad , 0x18c1d299 ] )
+  , Mont::unsafe_make( [ 0xc0749cba , 0x16257e0e , 0x561703ff , 0xd7117128 , 0x3c487c10 , 0xf9748fca , 0x366c5c31 , 0x1c424f32 ] )
+  , Mont::unsafe_make( [ 0x6ded33ea , 0xe0d42baf , 0x763ef26d , 0x20a9e68b , 0x8c4b8de2 , 0x87707341 , 0xf794beb8 , 0x10f8b472 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x31d7f88d , 0x11b949f4 , 0x937bb433 , 0xdddf242a , 0x65729924 , 0x8d0b8da3 , 0x8371e18a , 0x0787c5bf ] )
+  , Mont::unsafe_make( [ 0x33a7ccdb , 0x0c142a5c , 0x0881b9e0 , 0xdc8d6852 , 0x5218d9fd , 0x0075a980 , 0x002321bf , 0x1f713df8 ] )
+  , Mont::unsafe_make( [ 0xf09f2bc2 , 0x0f79fb28 , 0xeec6bfd4 , 0x89e44994 , 0x63ad73bc , 0x351ada86 , 0xcd60fed9 , 0x28ff1b94 ] )
+  , Mont::unsafe_make( [ 0x94dfacb6 , 0xb35915b9 , 0x04bc139a , 0x00cf8efd , 0x21936b0b , 0x486ad3b3 , 0x550458a9 , 0x070405f7 ] )
+  , Mont::unsafe_make( [ 0x0851dd2c , 0x5bf8923e , 0x644700b5 , 0xfdf58c0d , 0x4262d868 , 0xa9219d48 , 0xaeb7eb14 , 0x2b0753e7 ] )
+  , Mont::unsafe_make( [ 0xb9f9bd20 , 0xeebf9ae1 , 0x59897600 , 0x21b3367a , 0xb68848c3 , 0x41b79f75 , 0xbccf0b34 , 0x2306d846 ] )
+  , Mont::unsafe_make( [ 0xb84be6fe , 0x3a96708e , 0x63e4330b , 0xd199ee15 , 0x7c63260a , 0x598accc6 , 0xa455529a , 0x009bf736 ] )
+  , Mont::unsafe_make( [ 0x82783040 , 0x14f7985e , 0x7d072de7 , 0x19253464 , 0x92ff59ed , 0x9dec1fda , 0xe2089426 , 0x27f69e5c ] )
+  , Mont::unsafe_make( [ 0x2ddb7d10 , 0xbb8df6df , 0x63342f03 , 0x5a927458 , 0xca058201 , 0x6de975be , 0xe8e90b90 , 0x2740738b ] )
+  , Mont::unsafe_make( [ 0xbe9424dc , 0xe3221646 , 0xf9db9555 , 0xab973e3f , 0xc2de7002 , 0xb4d4cef4 , 0x5720744e , 0x06ad4e6a ] )
+  , Mont::unsafe_make( [ 0x9afcdd16 , 0x8571f6d6 , 0x3c499354 , 0xc9e2a626 , 0x8c5cf1d5 , 0x6ffe945d , 0xca072606 , 0x1a092bb7 ] )
+  , Mont::unsafe_make( [ 0xb5218520 , 0x45cdd987 , 0xedd90888 , 0x1ff9c7e3 , 0x2a701b96 , 0x9240950b , 0xf554f40d , 0x2758d665 ] )
+  , Mont::unsafe_make( [ 0x46fa7dba , 0xd6e5e9d0 , 0x9b5eb6d3 , 0x1bed81a8 , 0x45295f8f , 0x0d41d6af , 0x9f6b98ba , 0x2728dfa6 ] )
+  , Mont::unsafe_make( [ 0xecd7f77e , 0xedb3fd74 , 0x65230d03 , 0x9531f0d7 , 0x6e1263c5 , 0x9559ceab , 0xbff0fc1c , 0x2b40df5d ] )
+  , Mont::unsafe_make( [ 0xe4e3e9e7 , 0xbc24a52b , 0x6fbb0df6 , 0x07485f90 , 0x981ba197 , 0x0b4479c5 , 0x2a80e797 , 0x0ee3d4a5 ] )
+  , Mont::unsafe_make( [ 0xfc806bbd , 0xf8c33b51 , 0x95a9d5e3 , 0x9c8bc682 , 0x5216ad36 , 0x410df306 , 0x4a752180 , 0x16d21a0e ] )
+  , Mont::unsafe_make( [ 0xd59abda5 , 0x5960e7b6 , 0x3c8b5f1f , 0x3feaf715 , 0x097386b1 , 0x100b2ddd , 0x1060475b , 0x0177b41c ] )
+  , Mont::unsafe_make( [ 0x3a3c77e6 , 0xe76ca168 , 0x795fed80 , 0xcd36dd81 , 0xb5468239 , 0x114c145c , 0x4a983aa9 , 0x082aed8e ] )
+  , Mont::unsafe_make( [ 0xb68d4432 , 0x7c589f4b , 0xdee29fd4 , 0x73d91669 , 0x333ef29f , 0x6881546e , 0xafb07d6d , 0x0fc1b565 ] )
+  , Mont::unsafe_make( [ 0xbb5dec39 , 0x50177962 , 0xbcca73db , 0xb848df66 , 0x4fd3b91b , 0x6b7d08b9 , 0x2f8d9c88 , 0x1bbaa807 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x5b16685e , 0x27df565b , 0x4552552e , 0x4e143559 , 0x2b532455 , 0x23588693 , 0xb1750949 , 0x30393714 ] )
+  , Mont::unsafe_make( [ 0xf177ad4a , 0xe688edaf , 0xe260e85f , 0xfc4d2ba1 , 0x08cb482f , 0xc6ec1f86 , 0xe84eab87 , 0x0a175d89 ] )
+  , Mont::unsafe_make( [ 0x6a0736b3 , 0x80b8b491 , 0xf57543e2 , 0x5558c300 , 0x80e9fe8a , 0x29b65b35 , 0x6bd29b41 , 0x1ffb2393 ] )
+  , Mont::unsafe_make( [ 0x10e79137 , 0xdc07bebc , 0x0682ac89 , 0x8fa249ef , 0x3c2e1c8e , 0x441a751f , 0x1307a0c6 , 0x0e452f58 ] )
+  , Mont::unsafe_make( [ 0x8eb8df09 , 0x02eef4d9 , 0xb10ceeca , 0xb7130249 , 0x189e8836 , 0x2b5a8736 , 0x691f8555 , 0x1a821caa ] )
+  , Mont::unsafe_make( [ 0x9e08fb9e , 0xdb28679e , 0xdd97ccf7 , 0x789c17aa , 0x2bc8e124 , 0x7033394e , 0x82763aa1 , 0x2e978907 ] )
+  , Mont::unsafe_make( [ 0xf2408882 , 0x2526a0b6 , 0x35a97404 , 0xd51cfcaf , 0x2ef44531 , 0x07a8a4c7 , 0xa9a77347 , 0x2a1e4204 ] )
+  , Mont::unsafe_make( [ 0x11edfb38 , 0x528747f4 , 0xb51323d8 , 0xe7aec0f4 , 0x3b61004b , 0xcfbc2a2d , 0xe54268c3 , 0x054f2d67 ] )
+  , Mont::unsafe_make( [ 0x3153c6d1 , 0xcd8fafcf , 0x5a8d516a , 0xebccec47 , 0x8adea74f , 0x9616fb7d , 0x29f40263 , 0x1a99ff01 ] )
+  , Mont::unsafe_make( [ 0x71fb313b , 0xf5b6e235 , 0xaa6231e1 , 0xe9b9d81d , 0xda797d39 , 0x3b7704be , 0x17dc1bb0 , 0x1982ffc7 ] )
+  , Mont::unsafe_make( [ 0x562048ba , 0xf7e55e7b , 0xd65e6ead , 0xed2f1ecc , 0xb90a4104 , 0x69af6063 , 0xe0f6903d , 0x2b8f48e5 ] )
+  , Mont::unsafe_make( [ 0x0618c84a , 0x156f53d4 , 0x316b2c8b , 0xeefcc15a , 0x4e218ff5 , 0x4aa6ab03 , 0xf8a70801 , 0x15556f99 ] )
+  , Mont::unsafe_make( [ 0x31d3c3a5 , 0x07f14fe6 , 0x90b8fb93 , 0x7aa444d3 , 0x67b4b13c , 0x6c4fc5bd , 0x4a24042c , 0x096f08c0 ] )
+  , Mont::unsafe_make( [ 0xef402850 , 0x2a35cc7b , 0xe62a38fc , 0xbc321481 , 0x95020b09 , 0x9bd81ed2 , 0xc7a1ce1a , 0x06a88b9f ] )
+  , Mont::unsafe_make( [ 0x8bc75c8e , 0x4462aa0d , 0xe5277925 , 0xbd89374c , 0xb9be9daa , 0xd4604f54 , 0xb0ef91c2 , 0x0dab27d1 ] )
+  , Mont::unsafe_make( [ 0x802a33c7 , 0x5238f4b9 , 0x323a1477 , 0x2b8ac97b , 0x0e049ef2 , 0x8eda5367 , 0x544d2157 , 0x22232e81 ] )
+  , Mont::unsafe_make( [ 0x63d24840 , 0x830b0069 , 0x0c352ee8 , 0xedc42933 , 0x5355aedc , 0x17d677ed , 0xdc9c9b16 , 0x08cd8676 ] )
+  , Mont::unsafe_make( [ 0x359cca7f , 0xa9199306 , 0x66a1ea3b , 0xad91f872 , 0xd85535b8 , 0x48e5f6d8 , 0xd1c00c31 , 0x2814b68b ] )
+  , Mont::unsafe_make( [ 0xdf70d311 , 0x382d65e3 , 0xada8b72f , 0xfc41fbc1 , 0xa8f323ed , 0x03ef457f , 0x28fa593e , 0x219f4cf0 ] )
+  , Mont::unsafe_make( [ 0x8c5238d1 , 0xc763ed87 , 0x437bb147 , 0xce7256dc , 0xce814c28 , 0xb9caafab , 0xe5f8adb7 , 0x03bd8e43 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xfc9a9ec2 , 0xa8ed0de3 , 0x9671f561 , 0x2835c11b , 0xdbf177d3 , 0x24bd01d9 , 0x03302632 , 0x2f1646e5 ] )
+  , Mont::unsafe_make( [ 0x9ca7642f , 0xec63fa62 , 0xd722a5bd , 0xa0834bd1 , 0x1a7d93d6 , 0xa57ccd0e , 0x8c1a5455 , 0x0bcf4700 ] )
+  , Mont::unsafe_make( [ 0x995fd111 , 0xfc55f006 , 0xff68327a , 0xd5ac3675 , 0xfdfa786d , 0x0f38931d , 0x652104e9 , 0x2ad80639 ] )
+  , Mont::unsafe_make( [ 0x41dbc032 , 0x867899a0 , 0x8f673ddf , 0x71c504ad , 0xfa1a5a7f , 0xc5aca758 , 0xb2ef3412 , 0x085cdb6e ] )
+  , Mont::unsafe_make( [ 0xce78b354 , 0x8bb7109e , 0xe5eaebbb , 0x55cbe15e , 0xa481b1d5 , 0xf65e64e2 , 0x8e1ecb7b , 0x1b9a3f6e ] )
+  , Mont::unsafe_make( [ 0x993cd897 , 0xed18b4db , 0x985b6d1e , 0x17e2e4df , 0xd29f6f21 , 0x32fe10fe , 0x8ce46eab , 0x2639439d ] )
+  , Mont::unsafe_make( [ 0x8919b3b0 , 0x844474f4 , 0x07667de2 , 0x1422fc17 , 0xb2447c8d , 0xb8f174cb , 0xb6c1381d , 0x2a26a352 ] )
+  , Mont::unsafe_make( [ 0x080f9a31 , 0x0f76f6ac , 0xa3cbf12f , 0xef6b68cf , 0xe2afdda3 , 0xdd6a10e2 , 0xc813ca26 , 0x2ace941c ] )
+  , Mont::unsafe_make( [ 0x35d87d51 , 0x44211a33 , 0x1201cc4e , 0xded19031 , 0xd7bdc71c , 0xa0528659 , 0xd65b3ec1 , 0x18e6b300 ] )
+  , Mont::unsafe_make( [ 0xb680c994 , 0x5e663608 , 0x25043b39 , 0x1bbfb491 , 0xc4ed6730 , 0xcfa10d3c , 0x46ca8474 , 0x01128ab9 ] )
+  , Mont::unsafe_make( [ 0x94f1f562 , 0x24f65810 , 0x2e9d266c , 0xecad262c , 0x17a71876 , 0x63e5b53d , 0x483e2d03 , 0x0adef328 ] )
+  , Mont::unsafe_make( [ 0xdf739e42 , 0x1c201bb9 , 0x79a83f69 , 0x5e9d0da5 , 0x5ac7eeb7 , 0xff986b25 , 0xa790733e , 0x28bf6f0b ] )
+  , Mont::unsafe_make( [ 0xf2e29728 , 0x66625daf , 0xc9418341 , 0x079245a3 , 0x5cc5283b , 0x7f19f33a , 0x7248abe0 , 0x20ae569e ] )
+  , Mont::unsafe_make( [ 0x9d55ce23 , 0x75d2e706 , 0x0a1984a9 , 0xee5a123e , 0x477e4736 , 0x863c896d , 0xbddb0352 , 0x08fb4f7e ] )
+  , Mont::unsafe_make( [ 0x398e2c4e , 0x5c759668 , 0x1e30a830 , 0x51c3228e , 0x156a57ca , 0xc3d4e0d0 , 0x9debe6c1 , 0x0eb15a94 ] )
+  , Mont::unsafe_make( [ 0x832e390f , 0xcdb557b6 , 0xc6e9aa3b , 0x6137603e , 0x95f79510 , 0x8963ec29 , 0x9ff2d1ed , 0x00f8f6cc ] )
+  , Mont::unsafe_make( [ 0x0645b226 , 0xcaf45707 , 0xf5642166 , 0x4b3a6483 , 0x0eede2c9 , 0xb8c0d6ba , 0xfaf88384 , 0x25820857 ] )
+  , Mont::unsafe_make( [ 0x13ecef8c , 0xcf53508b , 0x4ef87a85 , 0x6ba5dea0 , 0x610c05fb , 0x774f2754 , 0x40c79fc3 , 0x1416749c ] )
+  , Mont::unsafe_make( [ 0xa8638952 , 0x27e9e22c , 0x15100fdd , 0xb0f25f06 , 0x749ff76a , 0xbab07e34 , 0xb6649e34 , 0x25405b5c ] )
+  , Mont::unsafe_make( [ 0x19e5bb80 , 0x50f014a2 , 0x2d457de1 , 0x64d36ab6 , 0x8c408746 , 0xeced9db9 , 0x16713cfd , 0x2c384a04 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x069d4401 , 0x18a31244 , 0x25a172b3 , 0x3fec6d3d , 0x938aadbd , 0x766dd158 , 0xea4d8907 , 0x2a49fe19 ] )
+  , Mont::unsafe_make( [ 0xdfafb7d7 , 0xa69b67d6 , 0x50863f5f , 0x66617414 , 0xde2ee823 , 0xabb56253 , 0xbd934afa , 0x0dc828a7 ] )
+  , Mont::unsafe_make( [ 0xa77a0c77 , 0xfa388956 , 0xebfbc247 , 0x1c66f4ff , 0xb1ceeba7 , 0xeff59c0b , 0x6ec05673 , 0x20000802 ] )
+  , Mont::unsafe_make( [ 0x8633f7c0 , 0x4a17fc08 , 0x6033c49e , 0x6f0e5655 , 0xc384fd0e , 0xe53a40ac , 0x53ef63f9 , 0x01c1c980 ] )
+  , Mont::unsafe_make( [ 0x3bc02b00 , 0xace9d76f , 0x4c6a6098 , 0x791def1f , 0x4e3e908e , 0x25cb8227 , 0x97817964 , 0x189bb9f5 ] )
+  , Mont::unsafe_make( [ 0x09282499 , 0x889dde4a , 0x1dc7c4d9 , 0xfe535c97 , 0xf6b864bd , 0x52cc71f5 , 0x8561ce71 , 0x2fed9a4e ] )
+  , Mont::unsafe_make( [ 0xadfcdb33 , 0xe436406a , 0x4ce7668b , 0x13ebb280 , 0x560e036a , 0x09241a18 , 0xd8504621 , 0x0183e9b1 ] )
+  , Mont::unsafe_make( [ 0xeac7ade6 , 0x73d7b91a , 0xfb6d0250 , 0x4bbc09db , 0x6036fede , 0x7aa7df21 , 0x7dbba59c , 0x1d55401d ] )
+  , Mont::unsafe_make( [ 0x22c3b882 , 0xd9661c46 , 0x8c807598 , 0x673ab1a5 , 0x6b7bbf52 , 0x9e850077 , 0xc93d4bfc , 0x2c972da5 ] )
+  , Mont::unsafe_make( [ 0x0b642429 , 0xd7d59e58 , 0x1bdb935b , 0x11f441a7 , 0x28317c70 , 0xab24af0a , 0xff7040fb , 0x13295d43 ] )
+  , Mont::unsafe_make( [ 0x688e31e8 , 0x304306b8 , 0x15887bca , 0x6ae7b008 , 0xc5870929 , 0x42599ca3 , 0x0942f3bf , 0x2f168188 ] )
+  , Mont::unsafe_make( [ 0x79b81e39 , 0x7607de5f , 0x859b502e , 0x451392db , 0x50ccd354 , 0x3c54bc47 , 0xa6d7148f , 0x1eb86622 ] )
+  , Mont::unsafe_make( [ 0x0f4ebec4 , 0x628471bf , 0x8b801079 , 0xacb6de37 , 0x50bd0267 , 0xed58610f , 0x265bf65d , 0x20080b97 ] )
+  , Mont::unsafe_make( [ 0x8f45840d , 0xb8157bea , 0x4c8fc7f7 , 0x8f52055a , 0x3a3a8a35 , 0xf825361c , 0xafd49119 , 0x1360c4ac ] )
+  , Mont::unsafe_make( [ 0xdea6d33f , 0xc830008a , 0x9fea58e9 , 0xde6a7cd5 , 0xc570669d , 0x6b914719 , 0x5f9fc8fb , 0x1763c5d3 ] )
+  , Mont::unsafe_make( [ 0xd1f59fce , 0x56a60878 , 0xc7496d98 , 0x888af0a6 , 0xd5d911ca , 0x2c4377bd , 0x7fa74e8a , 0x2de8f18c ] )
+  , Mont::unsafe_make( [ 0x01cd0dff , 0xcb8ba4cd , 0x7ae79f8f , 0x73884294 , 0xef97c888 , 0x0e5153a5 , 0x0af905d8 , 0x0d393f8e ] )
+  , Mont::unsafe_make( [ 0x785f9a65 , 0xda2aa57f , 0x3d7ce1c1 , 0x836f0fc8 , 0xccb9efe7 , 0x7d80afb8 , 0x21447ba2 , 0x0057daff ] )
+  , Mont::unsafe_make( [ 0xca520f0c , 0x000b1ea2 , 0x7ae40670 , 0x9c480c4b , 0x5562f7ac , 0x719111f9 , 0x8d54f8a0 , 0x300e7b09 ] )
+  , Mont::unsafe_make( [ 0x4e2fd9ee , 0xc2d9a262 , 0xefdfeae2 , 0xc8fc5fb3 , 0xf398ffea , 0x98a40d3d , 0xcdc96d10 , 0x2900eee8 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xf3efa7c7 , 0xcb7c2c79 , 0xf9f9056d , 0x10ea727b , 0xcf52ace5 , 0xcc0da168 , 0x6f7e356e , 0x2a4ba94d ] )
+  , Mont::unsafe_make( [ 0x8de45b12 , 0x5c4fd819 , 0xa1ec85f0 , 0xa67c89fa , 0xf96ea239 , 0x4ba81ff9 , 0xdf6e94ac , 0x0ef3b5a8 ] )
+  , Mont::unsafe_make( [ 0x3588bc2a , 0x764c5d64 , 0x8329c6ae , 0xd2e1bab1 , 0x1de2b3d0 , 0x6064dd4c , 0xfa874a03 , 0x14ed79c2 ] )
+  , Mont::unsafe_make( [ 0x524b8adf , 0xb030962c , 0xa01a9ccd , 0x2db93d1c , 0x30e6a52d , 0xf6dd8173 , 0x87abfca5 , 0x0c0c3eed ] )
+  , Mont::unsafe_make( [ 0x08ff435c , 0xb60c1546 , 0x64e1dcdc , 0x45dc0c33 , 0x543fa4ae , 0x269405da , 0x1ab2a3f1 , 0x1fdc9471 ] )
+  , Mont::unsafe_make( [ 0x27a67720 , 0x331ce9f3 , 0x02210a16 , 0xe6db6343 , 0xb16961fc , 0x5d7920bc , 0x87017640 , 0x20d07b79 ] )
+  , Mont::unsafe_make( [ 0x42599bef , 0xf33b81ef , 0xed606557 , 0x822a4cdf , 0xa899f83d , 0xb8c62b3e , 0x18549749 , 0x188b1602 ] )
+  , Mont::unsafe_make( [ 0x95645d2d , 0x9bc7c7f1 , 0x1484c87a , 0xd81df817 , 0xe8a87e7e , 0x7756f4e1 , 0xfca5d62c , 0x048be4c4 ] )
+  , Mont::unsafe_make( [ 0x662a3e98 , 0xf7aaa42e , 0x37ba800b , 0x028a7129 , 0x45e9dd9a , 0x1b59f670 , 0xf83b517f , 0x0a509648 ] )
+  , Mont::unsafe_make( [ 0x6a1ac3b6 , 0xede2537b , 0x2d6cf88f , 0x5fc4ebdf , 0x2933263e , 0x7ec85385 , 0x43eba29d , 0x06571571 ] )
+  , Mont::unsafe_make( [ 0xdf3275eb , 0x6afc58a6 , 0x08e1bef7 , 0x856e62fb , 0x904ec8a3 , 0x59f8b6cd , 0x5be72e4f , 0x0fca28de ] )
+  , Mont::unsafe_make( [ 0xdcf6716f , 0x088c3cd1 , 0x0327d2f1 , 0x3e256315 , 0xa9dd40b9 , 0x3eb83459 , 0x00df1192 , 0x17a98be6 ] )
+  , Mont::unsafe_make( [ 0xf49bd11a , 0x797cca99 , 0x7d0d16e1 , 0xd7799c47 , 0x6f798403 , 0xc592cbad , 0x45a937af , 0x2095cc17 ] )
+  , Mont::unsafe_make( [ 0x0374e194 , 0x86caae25 , 0xb29b6c9e , 0xa3ec9ed7 , 0x660ae703 , 0x18bdf504 , 0xacd45345 , 0x02e1d53d ] )
+  , Mont::unsafe_make( [ 0xae2bef9f , 0x86c662a5 , 0xad67c84f , 0x22f3a7a6 , 0x53414c1a , 0x71786193 , 0xfed19b02 , 0x0ba72229 ] )
+  , Mont::unsafe_make( [ 0xa5fcadac , 0x122d4d07 , 0xd118f93e , 0x93a504c3 , 0x1004dc4f , 0x5f3df6c2 , 0xefe36457 , 0x2d64aef4 ] )
+  , Mont::unsafe_make( [ 0x2146fb63 , 0x24092270 , 0xfabe7b15 , 0xf91f0bd2 , 0x35a18a31 , 0xe4e0d820 , 0x32b2f43f , 0x198411e0 ] )
+  , Mont::unsafe_make( [ 0x2966a780 , 0x1cc353af , 0xb8f17dae , 0x5147085a , 0x52d4610c , 0x71acef99 , 0x4eb491b5 , 0x28089382 ] )
+  , Mont::unsafe_make( [ 0xcb7b30ca , 0x662a1e2a , 0x5869b2ee , 0x8a231f22 , 0x90113ba4 , 0x999e943e , 0x81d2b8f4 , 0x305956ba ] )
+  , Mont::unsafe_make( [ 0xdb90b26c , 0x949d8052 , 0xc4a24826 , 0xb28dda45 , 0xc7838478 , 0x3235016f , 0x7efc9961 , 0x30310e5e ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x6efc0ba9 , 0xc2ec469d , 0x591d2f8f , 0xb69c2775 , 0xdd3491fe , 0x7d49546e , 0xa1b4a56a , 0x05274307 ] )
+  , Mont::unsafe_make( [ 0x6e2a6d8b , 0x3d1110ed , 0xc7905c87 , 0xefbeda84 , 0xfd724d05 , 0x07ed6be2 , 0x3217afd5 , 0x2ada0757 ] )
+  , Mont::unsafe_make( [ 0x81bacf6a , 0x92cc61be , 0xd1e3a797 , 0xcf90b105 , 0xbfea4f1c , 0xcb7154dd , 0xf78f1fc8 , 0x145e0f51 ] )
+  , Mont::unsafe_make( [ 0x9182cd8e , 0x2148828e , 0x3b06b2fc , 0x643e4481 , 0x5a1cb32a , 0x595b56fb , 0x0d5b1966 , 0x0135a219 ] )
+  , Mont::unsafe_make( [ 0x00a0aa4d , 0xaf7219ee , 0x4af79b54 , 0x71b467dc , 0x221dbb6f , 0xf879c4fa , 0x811b47c0 , 0x0ef0c237 ] )
+  , Mont::unsafe_make( [ 0xbd6b0989 , 0x8d124303 , 0xe0ffe598 , 0xcddbf057 , 0x3f5cac07 , 0xa1dc695a , 0xa3d6d48e , 0x012d62e7 ] )
+  , Mont::unsafe_make( [ 0x836bebb7 , 0x2d659bb8 , 0x49357871 , 0x9f7c6f0d , 0x075f3fd4 , 0x4335dd98 , 0x728884f8 , 0x1f83259f ] )
+  , Mont::unsafe_make( [ 0x4438f81e , 0xf1bff8f6 , 0xebd19aa4 , 0x8fda0dea , 0xb9d2bd4c , 0x8ff4bd02 , 0xe532f890 , 0x194f2865 ] )
+  , Mont::unsafe_make( [ 0x5e61847d , 0x8838f9e9 , 0xa460aad0 , 0x3aff5c39 , 0x7bbba33d , 0x296fabc1 , 0x29de49bd , 0x24caf5d3 ] )
+  , Mont::unsafe_make( [ 0x00dd7472 , 0xb52f2926 , 0x35171f06 , 0xe86be800 , 0x551ce14c , 0x2da115aa , 0xcb96910a , 0x0eeadf96 ] )
+  , Mont::unsafe_make( [ 0xf2ee20e5 , 0x13fb1ecb , 0xcf7a437e , 0x1c05b5a3 , 0x6be3852a , 0x4fc9a56c , 0x92548625 , 0x1516e565 ] )
+  , Mont::unsafe_make( [ 0x97ef3ed8 , 0xe05dedbe , 0x60d66d9f , 0x9a94f826 , 0x6e11c242 , 0xe4c3314f , 0x2c733d94 , 0x029ea425 ] )
+  , Mont::unsafe_make( [ 0x73faef33 , 0x55d5b735 , 0xc5d46402 , 0xbb4e1bad , 0x86bf3ea1 , 0xd35e108b , 0x3a26ed7a , 0x1a163015 ] )
+  , Mont::unsafe_make( [ 0x16b30c61 , 0x6d0d59b9 , 0x1d452ce3 , 0xf89d66a8 , 0x30f7d778 , 0x5f1e7689 , 0x60c9a15f , 0x144f9e2d ] )
+  , Mont::unsafe_make( [ 0x76bd2f20 , 0x9854443e , 0x6d710b63 , 0xb905d75b , 0xe92dead8 , 0x4b36571c , 0xc3bd098b , 0x177cd461 ] )
+  , Mont::unsafe_make( [ 0x228285d9 , 0x82321119 , 0x5190c34a , 0xfe9e792e , 0x17ff02c3 , 0xd1c14561 , 0xad6eb6ab , 0x2d1ed628 ] )
+  , Mont::unsafe_make( [ 0x4a972df2 , 0x3d0210dd , 0x94703395 , 0x2192fed3 , 0xe10322e8 , 0x62949681 , 0xd67852bb , 0x05151729 ] )
+  , Mont::unsafe_make( [ 0x0bad1157 , 0xfed9fb1a , 0xc3e714dc , 0xdf185307 , 0x435e3bff , 0x54c34417 , 0x56f11758 , 0x175d642d ] )
+  , Mont::unsafe_make( [ 0x30f63510 , 0xf4418759 , 0xa8e1872d , 0x14459cb2 , 0x0b374eae , 0x7353442a , 0x72770593 , 0x0e330ed1 ] )
+  , Mont::unsafe_make( [ 0x27b4be19 , 0x5935a031 , 0x823e61f1 , 0x59d28486 , 0x1322cc01 , 0x45b8ecf9 , 0x61c90c3c , 0x238f8ef4 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x87f7c183 , 0x1b7532e7 , 0x97549c87 , 0x37d3d452 , 0x8649fc4e , 0xcd4936f3 , 0x8a61d992 , 0x0b546e81 ] )
+  , Mont::unsafe_make( [ 0xa492d810 , 0xa299f221 , 0x08e7bb68 , 0x676433c8 , 0xf4bab131 , 0x6bd9a120 , 0x0ed332ea , 0x13ef94fb ] )
+  , Mont::unsafe_make( [ 0x6384dc41 , 0xa78d75f8 , 0x1be8cbb4 , 0x34de3f78 , 0x30633eea , 0xa3f8098c , 0x42a60356 , 0x24093d17 ] )
+  , Mont::unsafe_make( [ 0x2a4734f2 , 0x06d25a14 , 0xfd85387f , 0xfc98f0af , 0x0a06823f , 0xdaf6c401 , 0x0fa84893 , 0x1aa3dc3a ] )
+  , Mont::unsafe_make( [ 0x3b0f8682 , 0x50251a35 , 0xd7d440a4 , 0xea7c6249 , 0x43fd51af , 0x3af8513a , 0xbc750f42 , 0x0cce7be9 ] )
+  , Mont::unsafe_make( [ 0xc44190b6 , 0xe48acd71 , 0xe820c377 , 0x7c1df47c , 0x576ca832 , 0x48714bec , 0x87b4226f , 0x1d844a1b ] )
+  , Mont::unsafe_make( [ 0xa0168e25 , 0x26b35ceb , 0x3d751258 , 0x5228ab9d , 0x4fe81c51 , 0x399a4a19 , 0xb42c4504 , 0x17f28a9f ] )
+  , Mont::unsafe_make( [ 0xd6efb874 , 0x2e4b9c64 , 0xe94f2889 , 0x006b981a , 0xf308b3b5 , 0xdf27e848 , 0x2adfacfc , 0x168ecaf7 ] )
+  , Mont::unsafe_make( [ 0x7d968b11 , 0xc0fbfc2d , 0x310717e6 , 0x36be69d5 , 0xc622737b , 0x0ed3204c , 0xa1254513 , 0x0f508158 ] )
+  , Mont::unsafe_make( [ 0x43a406d6 , 0x06cab8da , 0x6e7f619a , 0x7a4abab8 , 0x892298d4 , 0xcd6bffe6 , 0x2648cf97 , 0x0a32f090 ] )
+  , Mont::unsafe_make( [ 0xe503834a , 0x8bd4af40 , 0x586b86e9 , 0xa7fc004b , 0xef33ea1f , 0x49bb4426 , 0xcd48ccce , 0x0f798b55 ] )
+  , Mont::unsafe_make( [ 0x3be3ece2 , 0xc02be9b3 , 0x2ce9d114 , 0x7ba5a08e , 0xe9e57c6a , 0x260deffd , 0x2704767a , 0x0672e759 ] )
+  , Mont::unsafe_make( [ 0xb3dd48b8 , 0xa6fb64e9 , 0x79ba58d7 , 0x571dd0af , 0x6cfb1b05 , 0x8495a25d , 0xa72ebc85 , 0x17902a7c ] )
+  , Mont::unsafe_make( [ 0x935e2727 , 0x96a2619d , 0x1f426dac , 0x6a4a5835 , 0x9006e3e3 , 0x4639a6bb , 0x3afde755 , 0x1266d398 ] )
+  , Mont::unsafe_make( [ 0xbaebb810 , 0x0b76addc , 0x8fd65c30 , 0xcf7831ff , 0x2bb538e1 , 0x8d0bfef6 , 0x72a54d7f , 0x2be43322 ] )
+  , Mont::unsafe_make( [ 0xc12bfdf8 , 0xe9b40dd6 , 0xf7133908 , 0x20c580bb , 0x4386ef5b , 0x6252d91c , 0xf9c40ff8 , 0x08374d95 ] )
+  , Mont::unsafe_make( [ 0x03b5f89b , 0xfd4dc45a , 0xe97cc61f , 0x7c93a29f , 0x947f7772 , 0x9876e7d0 , 0xc6ff5884 , 0x199a4a6e ] )
+  , Mont::unsafe_make( [ 0x2d007f3a , 0xda418906 , 0x2f6df826 , 0x7927531b , 0xe0a2bada , 0x0f0bc8a1 , 0x39b7cdf8 , 0x2edc20db ] )
+  , Mont::unsafe_make( [ 0x0d9deaac , 0xd3d64df4 , 0x992fa091 , 0xbcdfee34 , 0x0128fb57 , 0xaf5a21d1 , 0x741b22cd , 0x24ed451b ] )
+  , Mont::unsafe_make( [ 0x5c333dd6 , 0x33ce878a , 0xbf4a6801 , 0xa5edcfa5 , 0x7a4085ba , 0xe532ac02 , 0x3dc3ce64 , 0x1fcc4060 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xad5e1529 , 0xc5c84bca , 0x57b369b4 , 0xf667865f , 0x724af8d8 , 0x1e52078e , 0x3a75cb3d , 0x1683567d ] )
+  , Mont::unsafe_make( [ 0x83e0d2c5 , 0x611842d9 , 0x8405a0e8 , 0x374b2f1d , 0xd731c0bc , 0x86209131 , 0xa3993d41 , 0x172c96c4 ] )
+  , Mont::unsafe_make( [ 0x2ec66371 , 0x769d89a9 , 0x71a64498 , 0xd9252a1e , 0x35eccbb3 , 0xb6a18e21 , 0x684cd3bf , 0x017251b3 ] )
+  , Mont::unsafe_make( [ 0xc23c7f2e , 0x92031f06 , 0x7bdaea7a , 0x61341c21 , 0x91cd2d58 , 0x0f3a56b3 , 0x82af7a52 , 0x27721c10 ] )
+  , Mont::unsafe_make( [ 0x5d2877c2 , 0x0a87eb93 , 0x4d512e45 , 0x3f21abf6 , 0x52fd1b00 , 0xf189ac3f , 0xd73fa63d , 0x2384696c ] )
+  , Mont::unsafe_make( [ 0xb9330dc9 , 0x7c4a0066 , 0x02e4feb0 , 0xcdf6a53d , 0xfe39c800 , 0xd3cbf117 , 0xc8b017e3 , 0x12fc0e35 ] )
+  , Mont::unsafe_make( [ 0x2894ddd3 , 0x6270af0a , 0xd94e157c , 0x6afe1ddd , 0x88661f84 , 0x40d358c6 , 0xb9b394f5 , 0x136525a4 ] )
+  , Mont::unsafe_make( [ 0xb31dd4f6 , 0x3fa18875 , 0xb5f34197 , 0x9812dc51 , 0x7154b74c , 0x2ee40194 , 0x66cc9fb4 , 0x154eb65f ] )
+  , Mont::unsafe_make( [ 0x1520553e , 0x7318b3c4 , 0xc6178abb , 0x9f507bfb , 0xc0873ff6 , 0xc24c46eb , 0x12534e72 , 0x1fc9e9cc ] )
+  , Mont::unsafe_make( [ 0x0bfa6612 , 0x0704a937 , 0x893a9b11 , 0xf374640b , 0x2bc656eb , 0xc1cdbab5 , 0x113febbe , 0x19453f81 ] )
+  , Mont::unsafe_make( [ 0xd6fbbf9a , 0xaebcc617 , 0x368bf428 , 0x05c62c11 , 0xa358bd74 , 0x6b041ae4 , 0xee687a08 , 0x2cc99705 ] )
+  , Mont::unsafe_make( [ 0x17edb033 , 0x02a331ac , 0x75d18aab , 0xbd8a1e07 , 0x11947d07 , 0xc3b369b0 , 0x42616ebb , 0x07b36c90 ] )
+  , Mont::unsafe_make( [ 0x0db0bbf8 , 0x985cf68a , 0x7f68eaad , 0xee7a1672 , 0x9309860a , 0x813e5685 , 0xa7fd4e08 , 0x0af5595d ] )
+  , Mont::unsafe_make( [ 0xa78eadb6 , 0x67b1c18d , 0xfb00bf32 , 0xe4baa4f7 , 0xa3f05173 , 0x1015cb7b , 0x010b08a5 , 0x1fc3191b ] )
+  , Mont::unsafe_make( [ 0x8fdc9828 , 0xd62d5e72 , 0x0911374d , 0x36013f3f , 0xb8347e70 , 0x6fa536da , 0x887868fd , 0x20dcab98 ] )
+  , Mont::unsafe_make( [ 0x9f7cc577 , 0x8d32a8fc , 0xf16f349f , 0xb4fe5750 , 0x776a16a2 , 0xbdd9bb69 , 0x89697c48 , 0x022f8748 ] )
+  , Mont::unsafe_make( [ 0x9974ef7e , 0xe767049b , 0x5a1708bf , 0xb404528f , 0x9ad72a21 , 0x095aec6e , 0x1aac33fd , 0x2438f9dc ] )
+  , Mont::unsafe_make( [ 0x90fbd18a , 0x1584c78a , 0xbd68eb4d , 0x386bc419 , 0xd73bf64b , 0xc4eb33b2 , 0xb68af33f , 0x24842320 ] )
+  , Mont::unsafe_make( [ 0x3bbe50fb , 0x831bbf26 , 0x7cad21cf , 0x86b0a8b7 , 0x5f432f0e , 0x58b75e0f , 0x4789416e , 0x1e1d953f ] )
+  , Mont::unsafe_make( [ 0x73e06fed , 0x21737e14 , 0x6f5c5808 , 0xf758ba79 , 0xce5ef25a , 0x1268276c , 0x4c5c2e86 , 0x2d4b7646 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x62d54fb3 , 0x4474e8cd , 0x2b27eb1f , 0xd940790c , 0x7c172bcb , 0x62c9e19e , 0xf013c1c3 , 0x2d0be79b ] )
+  , Mont::unsafe_make( [ 0xc42662d3 , 0x9e46c7e9 , 0xca695200 , 0x5b07b7ca , 0xa0d19003 , 0x1e166758 , 0x23f37f3f , 0x1ab86e1a ] )
+  , Mont::unsafe_make( [ 0x6c5c8fae , 0xb71eaf72 , 0xc8200fca , 0x4e73420c , 0x36583f60 , 0xab77de18 , 0x3baa2a1e , 0x12f26530 ] )
+  , Mont::unsafe_make( [ 0x0b44e4b5 , 0x59f29a42 , 0x5f935c24 , 0xebb7fd70 , 0x7953f111 , 0xbb85b041 , 0x25454235 , 0x2e0d3e9f ] )
+  , Mont::unsafe_make( [ 0x96d874de , 0x1dd56a68 , 0x5c104f95 , 0x7727329b , 0x4048d1fc , 0x60a1758a , 0x75adc5dc , 0x1f50e8ff ] )
+  , Mont::unsafe_make( [ 0x3e61037d , 0xf3798a50 , 0x0900a4da , 0x830ce712 , 0x488d9bd2 , 0xd122500b , 0x6ca309fc , 0x1ca4cb7d ] )
+  , Mont::unsafe_make( [ 0xf17486d2 , 0x525ab94b , 0xc8ffb8a2 , 0x0d12f5dc , 0xcae2438b , 0x1784a0c1 , 0xbbeafdda , 0x02efb4cf ] )
+  , Mont::unsafe_make( [ 0x3229b72a , 0x2864c7d8 , 0xe7ed5710 , 0x8e181a56 , 0x20c6f4be , 0x1ce76d4a , 0xd804539f , 0x169400c8 ] )
+  , Mont::unsafe_make( [ 0x999ddcee , 0x829c8a5c , 0x7e877b9d , 0xd3923641 , 0xea9c7daa , 0x2d15f725 , 0x5469fca5 , 0x14b82d0a ] )
+  , Mont::unsafe_make( [ 0x4b4c7dbb , 0x4db37d36 , 0xd7fdb0f1 , 0x54aa4052 , 0x9f309b70 , 0xd717cd7b , 0xb2149e6b , 0x064e19a4 ] )
+  , Mont::unsafe_make( [ 0x70abf168 , 0x3f83335d , 0x06a61bce , 0x6b77d3f9 , 0x50356995 , 0xe145c325 , 0x62f8d75f , 0x257be1e0 ] )
+  , Mont::unsafe_make( [ 0x8da80516 , 0xef7f477d , 0x689cbdce , 0x1ee34dce , 0x69005dbc , 0x406c85a6 , 0xe3dc79f6 , 0x2302ef00 ] )
+  , Mont::unsafe_make( [ 0x47d15af6 , 0x88fe58f2 , 0x203e08a0 , 0x5e0068e3 , 0x3edd5655 , 0x5aa08e6c , 0x1436cccd , 0x27cb28ac ] )
+  , Mont::unsafe_make( [ 0x18004d3b , 0xb8f1dca0 , 0xb618c09b , 0x53e0962f , 0x7989dd4f , 0x44629b4f , 0x0e5f6956 , 0x13456786 ] )
+  , Mont::unsafe_make( [ 0x89decdbf , 0xbc050e2e , 0xa18dc789 , 0xed14b61c , 0xa8ee68e2 , 0x22f0f4bd , 0xc3745779 , 0x2b7c28d9 ] )
+  , Mont::unsafe_make( [ 0xd2d66fe0 , 0x70ec76fe , 0xc65812a1 , 0x697b88da , 0x941fbc6a , 0x8aac67c0 , 0xce7788f9 , 0x0aeb5a1f ] )
+  , Mont::unsafe_make( [ 0xa5696f22 , 0x8f53ce4e , 0x38ae187b , 0x3f4c27d8 , 0xb94a158b , 0xbe82509d , 0x14dc14ae , 0x0630d61c ] )
+  , Mont::unsafe_make( [ 0xbf5f0d48 , 0x892aff28 , 0x0149d159 , 0x8160d696 , 0x76b440e2 , 0x608b937a , 0x7d607b5d , 0x0e4f479d ] )
+  , Mont::unsafe_make( [ 0x3439eda9 , 0x2a591db4 , 0x58cbbdf0 , 0x9d0351c7 , 0xcb5f777b , 0x5a3f9c35 , 0x81a3ce91 , 0x08162918 ] )
+  , Mont::unsafe_make( [ 0x72253471 , 0x3d039b1e , 0xfe0d3230 , 0x78e51535 , 0x2ce1f9e9 , 0xd808dbe0 , 0xeb747363 , 0x094375c1 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x95cf55bd , 0xb27711ee , 0x7180743f , 0x3c75816f , 0xe560cc0e , 0xa814ce31 , 0x3f16feb3 , 0x1ef15ca8 ] )
+  , Mont::unsafe_make( [ 0xab5c6338 , 0xdeb8b6a8 , 0xf4a29394 , 0xe1abf770 , 0xd0becb48 , 0xa1838827 , 0xe7f566c4 , 0x0c20d1ad ] )
+  , Mont::unsafe_make( [ 0x5d681e9e , 0x6b102430 , 0x5c80843a , 0xafa316d5 , 0xc52269e4 , 0xf02ea2e6 , 0x78511761 , 0x26171202 ] )
+  , Mont::unsafe_make( [ 0x3c5da0c6 , 0xebb05092 , 0x32789d55 , 0x5e7f2c31 , 0x20e54661 , 0xfe87165b , 0xfb4e72a7 , 0x2fd8ab47 ] )
+  , Mont::unsafe_make( [ 0xa72fc04e , 0xb92b8aa2 , 0xf43a32fd , 0x95b5043d , 0x6d480809 , 0x008a82ca , 0x33dcfc8e , 0x00cd177c ] )
+  , Mont::unsafe_make( [ 0xd8939561 , 0x4ca21198 , 0x25f1923b , 0x79d65275 , 0xf4c1632a , 0x497d0eb6 , 0x3a732adc , 0x2198c4b6 ] )
+  , Mont::unsafe_make( [ 0x3c430cf8 , 0xc55d6de3 , 0x801870f4 , 0x118e0690 , 0xb4f4ea9b , 0x3aa7c9d6 , 0xdd1d756c , 0x1e986ce6 ] )
+  , Mont::unsafe_make( [ 0xd0b7c452 , 0x8c5d4643 , 0xf99d8d90 , 0xe7e25745 , 0x3b96617a , 0x02948ad2 , 0x32d23e79 , 0x08009320 ] )
+  , Mont::unsafe_make( [ 0x7ef353ab , 0xeb432319 , 0x84112f25 , 0xe918cc16 , 0xd7f960f4 , 0x146c10b8 , 0xa9309d7e , 0x03bb25d5 ] )
+  , Mont::unsafe_make( [ 0xb9ee5a6d , 0xb450ac79 , 0x7223ca99 , 0x0791ff41 , 0x2f324a8c , 0x1033e5b5 , 0xe6778219 , 0x1371b2db ] )
+  , Mont::unsafe_make( [ 0xaafd33fd , 0x6c822782 , 0x54fd790a , 0x2d4b095f , 0xce7984e6 , 0xc3a1cebd , 0xfc1c6bcd , 0x177495cd ] )
+  , Mont::unsafe_make( [ 0x605e5ba0 , 0x06776c9f , 0x90d030a0 , 0x137d582b , 0x74792c52 , 0x9fc1bf82 , 0x278e2941 , 0x0e953a6b ] )
+  , Mont::unsafe_make( [ 0x11b333bf , 0x400435b4 , 0x61fa319f , 0xd6fabac7 , 0x4dbfd3a0 , 0xf0bbe807 , 0xc8bc1f9e , 0x29ebda21 ] )
+  , Mont::unsafe_make( [ 0x90c3bce2 , 0x404c1896 , 0xec1a162f , 0x35f95a7e , 0x51fc92da , 0x62c6c3a7 , 0x852b48b0 , 0x2f5fcfab ] )
+  , Mont::unsafe_make( [ 0x24dc9fbe , 0xf8a8e45a , 0x5f6488b4 , 0x565fedae , 0x76e29517 , 0xbb22c698 , 0x3a805a15 , 0x23d3c741 ] )
+  , Mont::unsafe_make( [ 0xc6b39e1e , 0x306bddbc , 0x775bd4a6 , 0x922ff0d0 , 0x705bc552 , 0x875abe0f , 0xa7641c45 , 0x2209fc9d ] )
+  , Mont::unsafe_make( [ 0xed7d598c , 0xf87901ea , 0xede3f5aa , 0x9164fb04 , 0x423d016c , 0x862bb29b , 0xa1e90637 , 0x24fc008c ] )
+  , Mont::unsafe_make( [ 0x95fb051c , 0xfda7e45b , 0x66181e36 , 0x9734a613 , 0xaf0009f1 , 0x28b7174d , 0x3a53b616 , 0x0bc31229 ] )
+  , Mont::unsafe_make( [ 0x331ac5c3 , 0x9dd7e2b0 , 0x80645d28 , 0x9c269ad5 , 0x026b8331 , 0x4923c231 , 0xc3338e93 , 0x1dfe4f98 ] )
+  , Mont::unsafe_make( [ 0xee13614d , 0x8a6e68f7 , 0xce973823 , 0x8bb67b05 , 0xc5ce0110 , 0x6857e324 , 0xaf81bbdf , 0x0309af94 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x3be6d603 , 0x97e96b9e , 0xcaf9967a , 0xffe6284b , 0xf175d0c1 , 0xc6d20ad7 , 0xf5f0c92f , 0x237fce69 ] )
+  , Mont::unsafe_make( [ 0x7edc0f3f , 0x8d222439 , 0xb47bc98b , 0x9bc4604d , 0x0d06bef0 , 0xcc5cbced , 0x60a66482 , 0x30277843 ] )
+  , Mont::unsafe_make( [ 0xb6bdbae2 , 0xa7160b83 , 0x214b0301 , 0x3b43e6ce , 0x6bd381fe , 0xc03722f3 , 0xf3bd5ee5 , 0x195002c6 ] )
+  , Mont::unsafe_make( [ 0xd26293fe , 0x52d05fe7 , 0x040e0099 , 0x2bcbc924 , 0x224963c8 , 0x985d3dbe , 0x6824f94c , 0x2b375d25 ] )
+  , Mont::unsafe_make( [ 0x4cb41d71 , 0xd9e19479 , 0x382c054c , 0xabd15ae1 , 0x1113b45b , 0xe2180356 , 0x633ae023 , 0x28d7d6d0 ] )
+  , Mont::unsafe_make( [ 0x89443602 , 0x867d88b9 , 0x49f0bf80 , 0xf5decd89 , 0x3c0f4d1e , 0xb791fae0 , 0x52a7e553 , 0x0d63656f ] )
+  , Mont::unsafe_make( [ 0xb3b17674 , 0xd5838c70 , 0xa8adf146 , 0x83c7de64 , 0xafd1fccb , 0xba697583 , 0x7ba0dd04 , 0x037d7f23 ] )
+  , Mont::unsafe_make( [ 0xbb97d5f4 , 0xdd295dbe , 0x079bbcbd , 0xad5855d9 , 0xf36ac096 , 0x9581c8ea , 0x4e4fac80 , 0x215f42d3 ] )
+  , Mont::unsafe_make( [ 0x040f05a0 , 0xcb9af6d9 , 0xeb4d6bb8 , 0xd321966e , 0x3b2bc83c , 0xa566a0d5 , 0xa2b88ef2 , 0x2df9e685 ] )
+  , Mont::unsafe_make( [ 0xef7a5854 , 0xb8502ea6 , 0x9a8e1dca , 0x680a61ec , 0x65a6ecab , 0xaac08f06 , 0xd200cc8f , 0x035cefed ] )
+  , Mont::unsafe_make( [ 0x5251c7b6 , 0x1eb0e177 , 0x16e4c552 , 0x6f1bd4ef , 0x18213dac , 0x4a802f36 , 0x2cf39d66 , 0x05922d77 ] )
+  , Mont::unsafe_make( [ 0x854134b5 , 0x066daded , 0x70f4dfa2 , 0x73f75e5e , 0x77810bdd , 0xac98dddc , 0xc1ce5392 , 0x09094016 ] )
+  , Mont::unsafe_make( [ 0x0b55c8dd , 0xa856ce70 , 0x47fc2934 , 0x72cd9308 , 0x0c3ce37f , 0x49c4c9b2 , 0x3e3f06e5 , 0x1e4aa175 ] )
+  , Mont::unsafe_make( [ 0x0be22e9d , 0xfe3c1fa8 , 0xbd9991c4 , 0x20bf4ec8 , 0xe7e28612 , 0x2d68af3c , 0x33de5996 , 0x280bfd09 ] )
+  , Mont::unsafe_make( [ 0xda339c09 , 0xada396f8 , 0x56a47cc3 , 0xe81514ec , 0xc65df79e , 0x791565e3 , 0xe14642ec , 0x0892f99b ] )
+  , Mont::unsafe_make( [ 0xdb2ef550 , 0x8399f2b2 , 0x9f42961c , 0x318107b0 , 0x8ee35dea , 0x2d7289d5 , 0xc310a937 , 0x0ed25de0 ] )
+  , Mont::unsafe_make( [ 0x645c2e3b , 0xa6f7c905 , 0x1fba9802 , 0x99fb886a , 0xed633a85 , 0x77f9583b , 0xa861bd87 , 0x1e00af3a ] )
+  , Mont::unsafe_make( [ 0x3e5a295b , 0x638d8ece , 0x17c0ed15 , 0x80bd00a3 , 0xe1eacbb1 , 0x63e3b205 , 0x138f0afb , 0x0777e1aa ] )
+  , Mont::unsafe_make( [ 0x8113d406 , 0xabd54b94 , 0x98a9dcf3 , 0x12ca5a3b , 0x223c5552 , 0x50132c6d , 0x0fb962ae , 0x027c1409 ] )
+  , Mont::unsafe_make( [ 0xbff6457e , 0xf4c889e2 , 0x98259b15 , 0x4ecb97f7 , 0x4f81c900 , 0xf0fcc648 , 0xb753dc16 , 0x1128a4b3 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x547fd040 , 0xf8241e63 , 0x8bed2a06 , 0xe80df42d , 0x81c9a5e3 , 0x0ab1be4d , 0xce55aa6d , 0x1cbd162d ] )
+  , Mont::unsafe_make( [ 0xe1fc09ca , 0x1f4af925 , 0xc5d17368 , 0xf73010b7 , 0x8ea44c7c , 0xbb9aedf9 , 0xf6d390a0 , 0x163ddf02 ] )
+  , Mont::unsafe_make( [ 0x3854a875 , 0x0bf37a16 , 0xddf11ed3 , 0xbe186268 , 0x22bbeb85 , 0xabac4b90 , 0x8c7e28e1 , 0x165cc179 ] )
+  , Mont::unsafe_make( [ 0xe55b78f1 , 0x7cfae413 , 0x9016da02 , 0x87018fb2 , 0x9c042bde , 0x97a4750b , 0x2816a0f5 , 0x2c4a8be8 ] )
+  , Mont::unsafe_make( [ 0x8eaaffea , 0xe786e1ff , 0x9f8b4080 , 0xd2f39024 , 0xecf48b9e , 0x5e7722c6 , 0x5c86f0cb , 0x1925d0f2 ] )
+  , Mont::unsafe_make( [ 0xc0499f04 , 0x8d69abc5 , 0x035e014a , 0xf9232806 , 0x745f75a8 , 0x54ae47d4 , 0xb6bc6ab5 , 0x0c39bc7f ] )
+  , Mont::unsafe_make( [ 0xd96317b7 , 0x4ce303f9 , 0x14b49e83 , 0x3bc3af0c , 0x66aa902a , 0x29e750fe , 0xb8d895c4 , 0x01c686f7 ] )
+  , Mont::unsafe_make( [ 0x988047d1 , 0xaf5cd931 , 0xaba8f03f , 0xf29c72e6 , 0xa98580ba , 0x4fce68d4 , 0xe5161850 , 0x22cf91ba ] )
+  , Mont::unsafe_make( [ 0xa3395488 , 0xa901b27e , 0x7e40a2e5 , 0x1559d37e , 0x96408e74 , 0x43109abb , 0x8e2b6eb9 , 0x2e11f2af ] )
+  , Mont::unsafe_make( [ 0xd75b41ad , 0x2543b56b , 0xe9243915 , 0x0dc730e3 , 0x7c1764fc , 0xfda2e3b7 , 0xcaf3160c , 0x1789d419 ] )
+  , Mont::unsafe_make( [ 0xc3af74ba , 0x70e5e647 , 0xb23e88d3 , 0xb85cac9f , 0x401f5e79 , 0xc4a6f15f , 0xa6aa2d68 , 0x1c102f1a ] )
+  , Mont::unsafe_make( [ 0xf5777f58 , 0x87e307f4 , 0x6729024a , 0x4238e086 , 0xffb7703b , 0x1560e8de , 0x45c45369 , 0x199b4b8b ] )
+  , Mont::unsafe_make( [ 0x008cfb5b , 0x64a2e169 , 0x0056d976 , 0xb00f6cd1 , 0xbf70899e , 0xa4e83502 , 0xc31d4b89 , 0x1c5143d8 ] )
+  , Mont::unsafe_make( [ 0xa9f9466a , 0x01af80ee , 0x0bee4946 , 0xd73ed167 , 0x9545e6ab , 0x1f1796da , 0xf1a50d06 , 0x12cb82a3 ] )
+  , Mont::unsafe_make( [ 0x2b7af455 , 0x9aeee323 , 0x692370f5 , 0x5da21d50 , 0x751721bf , 0xb2ba0381 , 0xcde1ea4f , 0x062a4e10 ] )
+  , Mont::unsafe_make( [ 0x380ae37c , 0x3c8e9b32 , 0xca19a857 , 0x627cee98 , 0xa7c675c1 , 0x5d98a9dd , 0x0a42613c , 0x230e8875 ] )
+  , Mont::unsafe_make( [ 0x69bc47ed , 0xd3907634 , 0xbe39ee9f , 0xb3904268 , 0xb381b82c , 0xb2c5e493 , 0x71a7166c , 0x1eb5b94e ] )
+  , Mont::unsafe_make( [ 0x55975864 , 0x296d5881 , 0x31d07acc , 0xcd197bc7 , 0x52cf3442 , 0xf3c0c657 , 0xc7b379f8 , 0x14f74ca0 ] )
+  , Mont::unsafe_make( [ 0xe096fb65 , 0xef71a767 , 0xd9a52dfb , 0xec92b9db , 0x41c70d08 , 0x71a71a1c , 0x8d64ddde , 0x05c5457d ] )
+  , Mont::unsafe_make( [ 0x6bda6fc2 , 0xa9ab71a2 , 0x270d4c13 , 0xf4130c85 , 0x14ecd65c , 0xf9c52267 , 0x774333ff , 0x0bfa1259 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x98ac1e75 , 0x7a2ecda5 , 0x340276c1 , 0xea85fc3f , 0x1b0f609e , 0x50c1a9cc , 0xd4eff709 , 0x0e45e328 ] )
+  , Mont::unsafe_make( [ 0x8a5faff7 , 0x2575248e , 0xd44a8110 , 0xd01a3018 , 0x1e22cb15 , 0x97b6860c , 0x0b213b03 , 0x2c30f8c0 ] )
+  , Mont::unsafe_make( [ 0x2422d69b , 0x4a7e05e6 , 0x232d3526 , 0x49cafaf0 , 0x7bfa3d1f , 0x39c6b823 , 0x2229cb66 , 0x1b3e1e67 ] )
+  , Mont::unsafe_make( [ 0xaa5a8827 , 0xb2b63272 , 0x8c313dbd , 0x2b3e10fe , 0x3f14aadd , 0xfcfc404c , 0xe03557e1 , 0x11417a0f ] )
+  , Mont::unsafe_make( [ 0x75bba4a6 , 0x043b990f , 0xab04a92b , 0x089e34ae , 0xb81db88e , 0x0ffe9935 , 0x11190c5b , 0x04a4643a ] )
+  , Mont::unsafe_make( [ 0xfaa119c8 , 0xed15b651 , 0x7d127b2a , 0xf65401e6 , 0x7f61fe20 , 0xe1d13430 , 0x66d1e9bd , 0x2850c72a ] )
+  , Mont::unsafe_make( [ 0xd375d3d1 , 0x3dd30bc6 , 0x97f686df , 0x70d93cff , 0x0e9b7ca4 , 0xba4d8903 , 0x9cc804cf , 0x10df361f ] )
+  , Mont::unsafe_make( [ 0xd8e22b9a , 0x01a0f3f4 , 0x8bf2ec02 , 0x15ef7e8b , 0x69b936dd , 0x87b441ff , 0xa3dc4d5e , 0x04b181f0 ] )
+  , Mont::unsafe_make( [ 0x1c3bf3b7 , 0xfe0239ad , 0x8fc9179c , 0xa23222fe , 0xf726ce57 , 0x96cdfa82 , 0x205ad01e , 0x2831fe78 ] )
+  , Mont::unsafe_make( [ 0x6551e461 , 0x92280e85 , 0x3219b59b , 0xa003ac6e , 0xa7232f2c , 0xb23eb4b3 , 0xd50d809e , 0x04239a47 ] )
+  , Mont::unsafe_make( [ 0x66dd3747 , 0xfcdd5c70 , 0x42f4d6f8 , 0x1ac4fd0a , 0xc1537fa6 , 0x8e5bf263 , 0xa4d57795 , 0x13772ee2 ] )
+  , Mont::unsafe_make( [ 0x4761bd92 , 0x3c0fe8dc , 0x70a5410c , 0xad2fa6ef , 0x31784773 , 0x0154f2d7 , 0x7c72175e , 0x03231aea ] )
+  , Mont::unsafe_make( [ 0x9b179fc7 , 0x61e081c0 , 0xfe68611e , 0xe0053f70 , 0x3939dc24 , 0x797337e5 , 0x46060fab , 0x14a6dc72 ] )
+  , Mont::unsafe_make( [ 0x02bc0321 , 0x947d4939 , 0xe4cc6b0d , 0xe37fba85 , 0x899d7ed4 , 0x0d12b72b , 0x267d1955 , 0x20a88528 ] )
+  , Mont::unsafe_make( [ 0x0194fc5f , 0x9aff2ed4 , 0x6fce7a73 , 0x974fbadb , 0xa2c7e79b , 0x3c32d298 , 0x933d3e85 , 0x0e4b4011 ] )
+  , Mont::unsafe_make( [ 0xa24ff9d8 , 0xff0b425b , 0xd36dbeef , 0x0a21c4ae , 0xd33baa2f , 0x3249d43d , 0xfdb57dfa , 0x02f308bf ] )
+  , Mont::unsafe_make( [ 0x6c5bf594 , 0xb13e0e01 , 0xf9418746 , 0x5d649156 , 0x273e856d , 0xd8d16fc9 , 0x4ba90418 , 0x060519ad ] )
+  , Mont::unsafe_make( [ 0x1012a898 , 0x1609e7ef , 0xfaae7798 , 0x486323e0 , 0x7126abac , 0x6e8f8cfe , 0x1aa288f5 , 0x26631718 ] )
+  , Mont::unsafe_make( [ 0xeb8f0855 , 0xc6e62ff6 , 0xd56a3116 , 0xc1b9e62f , 0xf2da8447 , 0x070d5d4d , 0x881cbcb4 , 0x28ac440b ] )
+  , Mont::unsafe_make( [ 0xc1dab436 , 0x7b9cfacd , 0x68871053 , 0xcff3b848 , 0xc41f3882 , 0xa39936d7 , 0x429aac7d , 0x2512acb8 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xcdb2994d , 0x3b9fcc78 , 0x48d4b9e7 , 0x0a25a2b8 , 0x6c5f61a6 , 0x0618055f , 0x34c9ff69 , 0x2b6c14ae ] )
+  , Mont::unsafe_make( [ 0xfb6eb69a , 0x0243e99b , 0xbcafcc6a , 0x70268b81 , 0xfce40d24 , 0x4c0137ba , 0x8e938f68 , 0x0f48f244 ] )
+  , Mont::unsafe_make( [ 0x5906fa14 , 0x4b3ab58f , 0x7c09eddb , 0xcb0f4ff3 , 0x2a3cb5c7 , 0x56b9c4d2 , 0x906f0e45 , 0x1388c098 ] )
+  , Mont::unsafe_make( [ 0x13492f47 , 0x5e7cdf94 , 0x59b1542f , 0x8f9a641a , 0xb2046e31 , 0xc7cc199b , 0x605fe7d2 , 0x2f768675 ] )
+  , Mont::unsafe_make( [ 0x86a49dcd , 0x71b23ced , 0x49847017 , 0x471fe8d0 , 0x00034bb4 , 0xf3c5fd7b , 0xdaf76c57 , 0x01f2d999 ] )
+  , Mont::unsafe_make( [ 0x5800f81c , 0x9aceb70c , 0xbbd1859c , 0x5d717447 , 0xfdc0bb8a , 0x6d9967aa , 0xd23c98c8 , 0x13887184 ] )
+  , Mont::unsafe_make( [ 0x92627070 , 0xb9d79947 , 0x3ef83488 , 0x0f516a29 , 0x43ca6ca0 , 0x6a3b91fa , 0x7d8d408d , 0x107cea40 ] )
+  , Mont::unsafe_make( [ 0xd2752e5d , 0xf16e4f53 , 0x79ea4ddc , 0x3569d7cb , 0xe75db76e , 0xa7e8cb26 , 0x1d4684e3 , 0x0cbaa10a ] )
+  , Mont::unsafe_make( [ 0x9faea559 , 0xcc798933 , 0x94307444 , 0x5278fb71 , 0xfda4c4bd , 0x280223e7 , 0x0aaeb2c9 , 0x08f2c216 ] )
+  , Mont::unsafe_make( [ 0x31a29d77 , 0xde1550a9 , 0x195157d3 , 0x002c4845 , 0xf95d75a0 , 0x68062057 , 0x833f9908 , 0x081a9ee3 ] )
+  , Mont::unsafe_make( [ 0x5842803e , 0x30cc51aa , 0xbb58b46a , 0x444ed2c1 , 0x6c268e4d , 0x4e5c5aff , 0x629c6cf7 , 0x118b7dc3 ] )
+  , Mont::unsafe_make( [ 0x9323398a , 0x1981c4d7 , 0x29f66041 , 0xe7d20fa8 , 0x336bcb80 , 0x6864d83e , 0x29204d96 , 0x03822ad1 ] )
+  , Mont::unsafe_make( [ 0x3ad12b02 , 0x072e4199 , 0x76b21840 , 0x72d4fe77 , 0xc9949452 , 0xfdad0df7 , 0xe692f1b9 , 0x21980b56 ] )
+  , Mont::unsafe_make( [ 0xdf24ebc9 , 0x5822ee4e , 0x7da36ddc , 0xc2f59e67 , 0xef16c419 , 0xb252461d , 0xcf3dbb56 , 0x0ade3a3c ] )
+  , Mont::unsafe_make( [ 0x282267db , 0xe6b9a371 , 0x9df25e45 , 0xc35064ed , 0x49bcec80 , 0x1e302b77 , 0x151938a0 , 0x0d362dfd ] )
+  , Mont::unsafe_make( [ 0x310b56a8 , 0xd0e2e0ce , 0x5e731f5e , 0x31696096 , 0xaa55957f , 0xb0b4d2dd , 0x863ae5ac , 0x14f230aa ] )
+  , Mont::unsafe_make( [ 0x1cfb84a4 , 0x91b8f0de , 0xae512e4c , 0x37166364 , 0xf9a9617e , 0x40f60010 , 0x046f79c8 , 0x0d227555 ] )
+  , Mont::unsafe_make( [ 0xe1728b70 , 0xbe9b472d , 0xa463d903 , 0x1e361775 , 0x1cf16baa , 0xd03aed8f , 0xdc83c5bd , 0x1d2f7666 ] )
+  , Mont::unsafe_make( [ 0xd6a38c21 , 0xdac83e7a , 0x50d6e3b6 , 0xdd9711b8 , 0xb96e0cf8 , 0xda00b2f5 , 0x16402a00 , 0x0f4a9648 ] )
+  , Mont::unsafe_make( [ 0xb6d46d5d , 0x2dd0405c , 0x9dd57be4 , 0xad50b19f , 0xaf653531 , 0x88ab50d5 , 0x0a32ec7d , 0x11c193c3 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x5bc8fbbf , 0x2897d028 , 0xea9add53 , 0x47ef68c0 , 0xeae0403c , 0x40223c5e , 0x6b265d88 , 0x0fa2c220 ] )
+  , Mont::unsafe_make( [ 0xb7669d2e , 0x83fa46f0 , 0xafb7d707 , 0x8e32155d , 0x58293a12 , 0x03e1559b , 0xbba3dd43 , 0x26af523e ] )
+  , Mont::unsafe_make( [ 0x70422d73 , 0xc0fce627 , 0x49c064bb , 0xef1a9589 , 0x0b13a153 , 0x470992fd , 0x51625794 , 0x0588b102 ] )
+  , Mont::unsafe_make( [ 0x8f7b8504 , 0x3c13f4e8 , 0xa2f03df6 , 0xed7a3292 , 0x1b3e47d9 , 0xda4b65a0 , 0x656af0ba , 0x2f7d9f71 ] )
+  , Mont::unsafe_make( [ 0xdb9bb178 , 0x59b016b4 , 0xe0f89f34 , 0x0f4b4c50 , 0x33e2dc74 , 0x3180653a , 0x2bc38f9a , 0x2cd11229 ] )
+  , Mont::unsafe_make( [ 0xe07f6e84 , 0xe25c5754 , 0x644c6a56 , 0x1868cf6b , 0xf00a3677 , 0xeb5ca991 , 0xd6891cbf , 0x25cb7a17 ] )
+  , Mont::unsafe_make( [ 0x6bdde6c7 , 0xeea64ea3 , 0xf90b8892 , 0x1a268afc , 0x97c86bb4 , 0xc029b799 , 0x732c2bbf , 0x0e7b3ac7 ] )
+  , Mont::unsafe_make( [ 0x4d5a52a2 , 0x9333436e , 0x00329ddd , 0x7597cf84 , 0xbb65c971 , 0x5fcb5ab6 , 0x896f5db0 , 0x122a6c30 ] )
+  , Mont::unsafe_make( [ 0xabbc464b , 0xa451646e , 0xc2bc7363 , 0xd2688427 , 0xc654cf7b , 0xf1d89549 , 0x690afd99 , 0x1c08d120 ] )
+  , Mont::unsafe_make( [ 0x55261bc5 , 0xc4e434a6 , 0x9102736b , 0xe886c011 , 0x757ca278 , 0x72c9a464 , 0x889448b2 , 0x29ebe7b4 ] )
+  , Mont::unsafe_make( [ 0xbd5f15f8 , 0x6fcb544b , 0x59d04cb1 , 0x7a1cc3f8 , 0x48d64fa0 , 0x45d4edb1 , 0x6602680a , 0x22e321b6 ] )
+  , Mont::unsafe_make( [ 0x81a5cc67 , 0x5240c8c8 , 0xa3817617 , 0xacd547ea , 0xdbef9efe , 0x6cec4058 , 0x59540816 , 0x0e36c53a ] )
+  , Mont::unsafe_make( [ 0xca554ed2 , 0x619e5620 , 0x4f0c8cd2 , 0x0f524c21 , 0xf753dba9 , 0x572eb044 , 0xa641266d , 0x296972b0 ] )
+  , Mont::unsafe_make( [ 0xfff500a2 , 0x3d7dfa33 , 0x02b63d4a , 0x85da759a , 0x079794d6 , 0x67569b8f , 0x43ecdd58 , 0x1168f35c ] )
+  , Mont::unsafe_make( [ 0x8f992045 , 0x3538debf , 0xab54bd6b , 0x28644f43 , 0x534b448d , 0x74000637 , 0x1b8240e9 , 0x0649ea25 ] )
+  , Mont::unsafe_make( [ 0xdd962d5d , 0xd2ac4b70 , 0xc5924a48 , 0xce1bd621 , 0x38f9a148 , 0xf07aab1d , 0x79741d56 , 0x0643c109 ] )
+  , Mont::unsafe_make( [ 0x972a825a , 0x39cf9c5a , 0x6b23fc20 , 0xbc459042 , 0xbe9a6ea4 , 0x4d9a1726 , 0xc2e27b96 , 0x00e6d2f1 ] )
+  , Mont::unsafe_make( [ 0xeaa28150 , 0x21e7a8b3 , 0x5fbd5a95 , 0xab1e7f3e , 0x278b3992 , 0x4e2a40d2 , 0xff36de21 , 0x190af36e ] )
+  , Mont::unsafe_make( [ 0x36b09a25 , 0xe7060ef0 , 0x14da6e33 , 0x7fe5c895 , 0xe07196fc , 0x061aa45a , 0x8dedc3d7 , 0x1f074f8f ] )
+  , Mont::unsafe_make( [ 0x261fc821 , 0x1377aa27 , 0x778740d0 , 0x7afb83db , 0xd5a3a66d , 0xca7f67c8 , 0x552e9722 , 0x229dba54 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x2114e017 , 0x45da3576 , 0x8e6483f1 , 0x6ea98e62 , 0x1349e0a7 , 0x6272ecc8 , 0x64e4772e , 0x2ac5cd10 ] )
+  , Mont::unsafe_make( [ 0x92c5b450 , 0xfe86822b , 0xab24868c , 0x554c1a3a , 0x8f31e09a , 0x913a12ba , 0x27ec158c , 0x0d598260 ] )
+  , Mont::unsafe_make( [ 0x93938ecc , 0x61b74f21 , 0xdaffda89 , 0x94f31130 , 0x8b9c25a9 , 0xcd5f8ac4 , 0x96a192dd , 0x084fea1e ] )
+  , Mont::unsafe_make( [ 0x04c1e0d5 , 0xce3236b7 , 0x0c82580a , 0x8dca3106 , 0x36885f7b , 0xfb11bcab , 0x132e53a4 , 0x1b64640e ] )
+  , Mont::unsafe_make( [ 0xda52481a , 0x392a28cb , 0x93295603 , 0x557df4ee , 0xa1b8c28b , 0x82e34ad5 , 0x02302167 , 0x0c688390 ] )
+  , Mont::unsafe_make( [ 0x3ee856a0 , 0x4b84a7cf , 0xff231f40 , 0x2b0d200c , 0x0a0e2759 , 0x80024125 , 0x72453a35 , 0x122b72df ] )
+  , Mont::unsafe_make( [ 0x98d6b00d , 0x032b74f6 , 0xebb14437 , 0x0e6e66f3 , 0xada3b54b , 0x7e395633 , 0x5d7e327e , 0x27eeb2a8 ] )
+  , Mont::unsafe_make( [ 0x3c004f9c , 0xcd5a9f6b , 0x76788722 , 0x0db53200 , 0xb77ae1be , 0x22256a03 , 0xb782dc82 , 0x2bc496b0 ] )
+  , Mont::unsafe_make( [ 0x514bba43 , 0xedfaaacb , 0x9f0e85f6 , 0x12837dcd , 0xc37b65e0 , 0xd6a333a0 , 0xf94ba03e , 0x0b274342 ] )
+  , Mont::unsafe_make( [ 0x7c3f8d7a , 0xbecf6c5d , 0x07c9f50f , 0x6148aec4 , 0xb27cab81 , 0x3fbdb03e , 0xc3e74a64 , 0x03c93f24 ] )
+  , Mont::unsafe_make( [ 0x1682faa2 , 0x6f59d6ea , 0x34fa17c8 , 0x41098bb7 , 0xd9891899 , 0x21c4a8a6 , 0x9ea194cf , 0x01349bc5 ] )
+  , Mont::unsafe_make( [ 0xd614fa45 , 0x194c6c99 , 0xff89319e , 0x7bdd573b , 0x61c994af , 0x0ade7aaf , 0xb35a37ff , 0x0da30df7 ] )
+  , Mont::unsafe_make( [ 0x8c56c9ab , 0x7f8d1467 , 0xe6567f63 , 0x0a2fdac0 , 0x5b7787cc , 0x731032b7 , 0xcd546618 , 0x1e6d7784 ] )
+  , Mont::unsafe_make( [ 0x6bd60cbf , 0xd7bf494c , 0xd03ab197 , 0x9ce92c51 , 0xc3eb40aa , 0xb6dca773 , 0x05f7bd2e , 0x067a140b ] )
+  , Mont::unsafe_make( [ 0xf9576c15 , 0xa2e750ba , 0x4df1772f , 0x3d947eda , 0x8177cbce , 0x972186df , 0x29180e79 , 0x26fa5ebf ] )
+  , Mont::unsafe_make( [ 0x8b737391 , 0xc6af0f20 , 0xd09dc0e8 , 0xe62792f3 , 0x4112b550 , 0xdb37e62c , 0xe302f75e , 0x0798e988 ] )
+  , Mont::unsafe_make( [ 0xd6d0235a , 0xfa5d7e6b , 0xd14a1157 , 0xcd4e7a9c , 0xbc800eaf , 0xf3fd11f9 , 0x0bb08210 , 0x1b36cab9 ] )
+  , Mont::unsafe_make( [ 0x3a85120c , 0x63c8a34b , 0xc2f72a4a , 0xdd58b49d , 0x10acab27 , 0x481021f8 , 0x7a15fc20 , 0x237a650e ] )
+  , Mont::unsafe_make( [ 0x71e6725b , 0xb9027169 , 0x2aa651ee , 0x3451bb3b , 0x85066bc6 , 0x5b7677fb , 0xe4fcaefa , 0x01971683 ] )
+  , Mont::unsafe_make( [ 0x6c0d7754 , 0x6c4fac6f , 0xd1373c65 , 0x8ac055a7 , 0x6a8665f8 , 0xa9366483 , 0x751fdddf , 0x1cd205b0 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xfecd9433 , 0xc0f79ef6 , 0x836b3797 , 0x32961a29 , 0x6ddc6c75 , 0xe803566f , 0xaec3f6d7 , 0x19fd02c7 ] )
+  , Mont::unsafe_make( [ 0x728c36e2 , 0xf7e76189 , 0xd7e27f0a , 0xf06e525f , 0x77711fb3 , 0x91cd80a8 , 0x602f8a8f , 0x137d655d ] )
+  , Mont::unsafe_make( [ 0x3ed49fe7 , 0x2914de6d , 0x1d92a101 , 0xc793c4db , 0xcea8b50f , 0x8f9232e2 , 0x1ba609f4 , 0x260dd07c ] )
+  , Mont::unsafe_make( [ 0xddf9a8f4 , 0x48f2b746 , 0xc0c0f502 , 0x4a3a5a72 , 0x536f0c28 , 0x2038fa3a , 0x659be4a4 , 0x27431103 ] )
+  , Mont::unsafe_make( [ 0xa3ab9935 , 0x7979e600 , 0x13ba425e , 0xe65da911 , 0xa92be399 , 0xf3bf09d6 , 0xa4f0558a , 0x1c59b6cb ] )
+  , Mont::unsafe_make( [ 0x28aabfa3 , 0xbbc4e940 , 0x32261e1a , 0xb39c29ce , 0x8315c536 , 0xef5fcb9a , 0xd2537bcd , 0x20289aa7 ] )
+  , Mont::unsafe_make( [ 0x14e6735d , 0xda422075 , 0x3e715993 , 0xdf3bae11 , 0x9de08964 , 0x88b6ceee , 0xfca76cd8 , 0x2962e09d ] )
+  , Mont::unsafe_make( [ 0xe52952a4 , 0xa727e52c , 0x1dece2f5 , 0x19d0efe8 , 0x9dcabaea , 0x493d5fc9 , 0xddf1d86f , 0x10e94779 ] )
+  , Mont::unsafe_make( [ 0x0aa43d9d , 0x7d11c758 , 0xea9f26dc , 0x22999351 , 0x118a4c27 , 0x57dd2ed9 , 0xb2637b67 , 0x261a8132 ] )
+  , Mont::unsafe_make( [ 0x02ff3743 , 0x11beda3c , 0x9c61192b , 0x47fb2e50 , 0xc9833582 , 0xa45e2f00 , 0x877075fc , 0x1aee83a8 ] )
+  , Mont::unsafe_make( [ 0x22f880e9 , 0x8dfa0850 , 0x59704fb0 , 0xaab6255f , 0x67a7b0d0 , 0x5b3deff5 , 0x7f0d1bf4 , 0x13e30277 ] )
+  , Mont::unsafe_make( [ 0xe43f5f50 , 0x3f286922 , 0xcd6780fc , 0xb32a026b , 0x7c8cd95b , 0x8fc29fc4 , 0x448ca283 , 0x21b69ac4 ] )
+  , Mont::unsafe_make( [ 0x1dd90489 , 0xcafa9b80 , 0x755c234e , 0xfa6a5523 , 0x52f9ce9a , 0xd4619724 , 0x99b7e87f , 0x12caaee0 ] )
+  , Mont::unsafe_make( [ 0x61ef221d , 0xf921fa7e , 0xee5fe53c , 0x17968be2 , 0xf22d18aa , 0x3de34a29 , 0x6efb2e96 , 0x157cc196 ] )
+  , Mont::unsafe_make( [ 0xa902a2b4 , 0x76e1dfbd , 0x5d851d86 , 0x4617cc1e , 0x2c73eb1e , 0xd62f765a , 0x388c98d7 , 0x2b0d19e9 ] )
+  , Mont::unsafe_make( [ 0xb05ef7b8 , 0xe73ee17d , 0x964ee028 , 0x88f1f2fe , 0xc8d5c561 , 0xcb5781cb , 0x56f633cc , 0x1091bb87 ] )
+  , Mont::unsafe_make( [ 0x2b485a18 , 0x55692515 , 0x98c00ca2 , 0x19ba354f , 0xaf24ad09 , 0xf48e65dc , 0xdfb1b95a , 0x1bede234 ] )
+  , Mont::unsafe_make( [ 0xafb656dd , 0xe75173da , 0xc3df8b5a , 0x6f9852ae , 0x3d9461f2 , 0xeec01b37 , 0x688e3906 , 0x0f5821fc ] )
+  , Mont::unsafe_make( [ 0xc01c5afe , 0xa031d98f , 0xfe77bbfa , 0x544b4981 , 0x77ae129c , 0x5ce46963 , 0x31479e4d , 0x0e3ec2aa ] )
+  , Mont::unsafe_make( [ 0x2e538708 , 0x538fdac2 , 0xed1f360f , 0x66fd7ff6 , 0x56677ecc , 0x462d94b5 , 0x01a621d2 , 0x09714544 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x50e1a52d , 0xe0e73e7c , 0x87fe0b86 , 0x464aaaa4 , 0x00d78eb8 , 0x641257b5 , 0x8f441fce , 0x067104df ] )
+  , Mont::unsafe_make( [ 0x53a57965 , 0xf1219fc3 , 0x846baf25 , 0x79f1c2b9 , 0xf2edbf4a , 0x27c40f75 , 0xe6d835ea , 0x2434d7c2 ] )
+  , Mont::unsafe_make( [ 0x81613613 , 0xf833c0aa , 0x2d81c89c , 0xffa18c48 , 0x607fb7fc , 0xd86dfe4b , 0x24acc2fd , 0x10de8972 ] )
+  , Mont::unsafe_make( [ 0x365275e8 , 0xb74351c4 , 0x13030897 , 0x4165a827 , 0x0da9fc89 , 0x51f0546b , 0x4c10bbbd , 0x0e036b30 ] )
+  , Mont::unsafe_make( [ 0x2015e487 , 0x912238d8 , 0x338caa78 , 0x7132d76d , 0x6d48546e , 0x885f067f , 0x195e8f32 , 0x0dc54567 ] )
+  , Mont::unsafe_make( [ 0x65725a03 , 0x1151edc7 , 0x3cfba2d7 , 0xc5095ced , 0x8aa7f2ab , 0x028d99eb , 0x03fa8aaa , 0x162bb9e0 ] )
+  , Mont::unsafe_make( [ 0x3b2bbac4 , 0xb907e5d3 , 0x293cd2c3 , 0xe7985474 , 0x0dab8d3f , 0xfe74d076 , 0x91a7dd82 , 0x01721ffc ] )
+  , Mont::unsafe_make( [ 0xda972422 , 0x1ed9b504 , 0x7209a0c7 , 0x944edb80 , 0x4b980951 , 0xb5bfc705 , 0xa2218129 , 0x12567fa2 ] )
+  , Mont::unsafe_make( [ 0x905feb4b , 0x546b8f39 , 0x324cda33 , 0x38eb7c4c , 0x598ef377 , 0x3b256c42 , 0x6a326a7a , 0x17ed5696 ] )
+  , Mont::unsafe_make( [ 0x4a58a28b , 0xec39b096 , 0x02075a64 , 0x8954cabc , 0xd1a5430b , 0xe9631449 , 0x49af92b2 , 0x1bdd8920 ] )
+  , Mont::unsafe_make( [ 0xa9aa14f8 , 0x5cebabf6 , 0x09344be8 , 0x5ec3a112 , 0xc9760797 , 0x519c2483 , 0x1825eee0 , 0x23ea051e ] )
+  , Mont::unsafe_make( [ 0x711e9d5f , 0x67f85faa , 0x7e1a954f , 0x44f917f6 , 0x3ae6089c , 0xc59cb03f , 0xf053ae38 , 0x1bccdd5a ] )
+  , Mont::unsafe_make( [ 0x1b2805ac , 0xa75f60be , 0xaf419580 , 0xf3a8d66d , 0x18794120 , 0x9339076f , 0x07b7d74c , 0x2d14c556 ] )
+  , Mont::unsafe_make( [ 0x9da256b1 , 0xba07858e , 0x12ffde64 , 0xbf95f2d9 , 0x949a9ac6 , 0xf11a91cc , 0x6c0aba20 , 0x1ddd5466 ] )
+  , Mont::unsafe_make( [ 0x00423659 , 0xb7f5e915 , 0x7c939c05 , 0x1620d211 , 0x5bad485e , 0x3bebf2eb , 0x8c1633f5 , 0x08603cdf ] )
+  , Mont::unsafe_make( [ 0x45350a49 , 0xdb62eb1d , 0x54e514b8 , 0x7586887f , 0x39c44ddc , 0x0b6d816e , 0xbfb0436a , 0x1afada21 ] )
+  , Mont::unsafe_make( [ 0x78564864 , 0x2f4d4b5a , 0xc0af1e7c , 0xd15be200 , 0xcac6ce69 , 0xaca00db6 , 0x6d9bfa0d , 0x0730a2fe ] )
+  , Mont::unsafe_make( [ 0x4929a0e6 , 0x98683506 , 0x058471f9 , 0x5e580a05 , 0xe3262d55 , 0xbedd1937 , 0xd8829807 , 0x1ad07b42 ] )
+  , Mont::unsafe_make( [ 0xb85e349d , 0x3e8c0149 , 0x6a3cda41 , 0xb20a43fb , 0x72536d23 , 0x1d2aa5fa , 0x6853c6e1 , 0x16d904dd ] )
+  , Mont::unsafe_make( [ 0xe1a8c198 , 0xd5d78961 , 0xfd970e84 , 0x1861ff4b , 0x8d24dea3 , 0xe60a8318 , 0x4b7ac119 , 0x106fb18a ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x76f4f0cf , 0x6948aec7 , 0x172b8b0c , 0xedcf0ca3 , 0xd58d78ac , 0xc8c1d9f3 , 0x6f6a869d , 0x21b3c3da ] )
+  , Mont::unsafe_make( [ 0x132e0ae2 , 0x3005b43e , 0xce196f3a , 0xf14ffee9 , 0x3532b76f , 0x2845c6d4 , 0x36490b21 , 0x13de07e1 ] )
+  , Mont::unsafe_make( [ 0x9b705a99 , 0x528ff27a , 0x693c862f , 0x7deb85e6 , 0xc2fafd2e , 0x3d1cc904 , 0xdd1416bd , 0x18991dba ] )
+  , Mont::unsafe_make( [ 0x1fe1df4a , 0x181c5e06 , 0x4c9e9744 , 0x047e6a8d , 0x03459694 , 0xeeec6593 , 0x072b78e7 , 0x144f5ada ] )
+  , Mont::unsafe_make( [ 0x5084cfcc , 0xbe1879e6 , 0xfc7ab5a8 , 0x390ed007 , 0xdd35f9f7 , 0x75a15d52 , 0x40c89cf2 , 0x285ec2c0 ] )
+  , Mont::unsafe_make( [ 0xeba1e420 , 0x080fb893 , 0x1675d6e3 , 0x50494df7 , 0x63496771 , 0x292d54de , 0x97adba20 , 0x2cdc3d6e ] )
+  , Mont::unsafe_make( [ 0x5ff528d4 , 0xfe2b0506 , 0xb40041cd , 0xca364f20 , 0x36f24f51 , 0x90c11284 , 0xf447fd8b , 0x0f6acffb ] )
+  , Mont::unsafe_make( [ 0x5d37f62a , 0x5c3ce9ab , 0x0556218b , 0xe3b2d04f , 0x8304ad2e , 0xe851f763 , 0xc764b41c , 0x0d2ee24f ] )
+  , Mont::unsafe_make( [ 0x095e8df8 , 0x5668796e , 0xa8171db1 , 0x3c758426 , 0xef38f645 , 0x3c0b9a25 , 0xd75c8b1d , 0x10c1bd8f ] )
+  , Mont::unsafe_make( [ 0x6042cb59 , 0x018e9e9f , 0x5716ec27 , 0xbd3a1bfa , 0x97655822 , 0x5e51382e , 0x3746c115 , 0x0aeba0c1 ] )
+  , Mont::unsafe_make( [ 0x49a0007e , 0xe7fc29af , 0x10ea5518 , 0x7d1a0b2a , 0xcaa3d586 , 0x74aded34 , 0x9e54846d , 0x117dd198 ] )
+  , Mont::unsafe_make( [ 0x74019754 , 0xc6ff2e08 , 0xef794534 , 0x50d4ec3a , 0x42d77cb8 , 0x31484052 , 0x9f82fb44 , 0x109ccff1 ] )
+  , Mont::unsafe_make( [ 0xb39082ed , 0xc97f3cad , 0xc3617d74 , 0x3dad7872 , 0x78538863 , 0x8474d51e , 0x8e14a8d9 , 0x2dd9601e ] )
+  , Mont::unsafe_make( [ 0xa46172cb , 0x40adb2a5 , 0x274ca7e9 , 0x7b81ee49 , 0x28bea4eb , 0x9f7836eb , 0x43b84be5 , 0x079049a4 ] )
+  , Mont::unsafe_make( [ 0x3d10a607 , 0x161a70b2 , 0xe6c1ab47 , 0xa192186e , 0x02503db6 , 0xba9573be , 0xbd545950 , 0x2676d281 ] )
+  , Mont::unsafe_make( [ 0x1b223192 , 0xf6a7bb9a , 0x570a7df1 , 0x7c95bf4a , 0x1c397eb4 , 0xb00ebe18 , 0x4d236535 , 0x22db3f95 ] )
+  , Mont::unsafe_make( [ 0xef4c2778 , 0x0024ea68 , 0xc37289bf , 0x6d42bab5 , 0x2da21f0a , 0x843aaee6 , 0x188dd37e , 0x1fab9fc5 ] )
+  , Mont::unsafe_make( [ 0x30a23704 , 0x68df9cd4 , 0x4f8bb376 , 0xf5d404ab , 0x432d09a0 , 0x6621086e , 0x083dda72 , 0x16076ef4 ] )
+  , Mont::unsafe_make( [ 0x1f072145 , 0xa385ef0c , 0x1f9b5dbd , 0x8b34b76f , 0x11ca05e5 , 0x3db6f18a , 0x46ac39cb , 0x079dfc18 ] )
+  , Mont::unsafe_make( [ 0x76eb8f26 , 0x68f98747 , 0xec50c3df , 0x4f53d1e5 , 0x9c36c820 , 0x5b8704e3 , 0xdc1b057e , 0x0a24d226 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x71fe4511 , 0xff7c5388 , 0x89dcf7d4 , 0x0df8795f , 0x8386e0cd , 0x2f2ad1a1 , 0x82f479ab , 0x2b7c846d ] )
+  , Mont::unsafe_make( [ 0x2e55d73f , 0xf5d1195b , 0xe7e35a1b , 0x810c4b11 , 0xebbdcf72 , 0xe0ffc617 , 0xfab313ad , 0x1f25db10 ] )
+  , Mont::unsafe_make( [ 0x252e5138 , 0x45ae8f08 , 0xfa7f9878 , 0x259d7df8 , 0x32c013c4 , 0xadf5b835 , 0x0b9f199c , 0x2d133914 ] )
+  , Mont::unsafe_make( [ 0x5d4b0eae , 0xfc6eb674 , 0xd6db4202 , 0xc0145dcc , 0x83be9dd3 , 0x5a934323 , 0xbc9ba105 , 0x145678a3 ] )
+  , Mont::unsafe_make( [ 0x54e6c826 , 0x604cc734 , 0xedcf004c , 0x5d73a98e , 0x282e358d , 0xe65f59fe , 0x30bbfe4c , 0x070f909b ] )
+  , Mont::unsafe_make( [ 0x6503c3eb , 0x4ca80bf1 , 0x997e7be1 , 0x2aa119fd , 0xbbbe90ad , 0xe5314c18 , 0xe0c8e143 , 0x2b99b8a6 ] )
+  , Mont::unsafe_make( [ 0x95cb6783 , 0x5fb6d6c8 , 0xb5be21f1 , 0xd09525bd , 0x80bb1d47 , 0x322d91a2 , 0x45191569 , 0x19121603 ] )
+  , Mont::unsafe_make( [ 0xa1661842 , 0xe73e1ee7 , 0xcc399c5c , 0x7c8c43e1 , 0x7d1e6f1c , 0xe1ae1621 , 0xd8d5d90e , 0x211dc21c ] )
+  , Mont::unsafe_make( [ 0xc5328e20 , 0x023866d5 , 0x658a59c0 , 0x89cb8d52 , 0x6f000ce4 , 0x1ae64f35 , 0x393d750d , 0x207ca4f1 ] )
+  , Mont::unsafe_make( [ 0xec02b6a7 , 0x8d556a5a , 0x477f087b , 0xd8073911 , 0x3909dc18 , 0xa7589c43 , 0xb92cd322 , 0x27894dcc ] )
+  , Mont::unsafe_make( [ 0x972f2951 , 0xe431d7c1 , 0x70f4f195 , 0x1a9118fd , 0xc29c3c16 , 0xdaf93e4f , 0x0e27df0f , 0x1d8a0722 ] )
+  , Mont::unsafe_make( [ 0xac95f0ae , 0xd2e09ef4 , 0xd16dc139 , 0x52180a02 , 0x8d79342e , 0x77da6ef1 , 0x1311a183 , 0x2e8fbd58 ] )
+  , Mont::unsafe_make( [ 0x7b1b1dd0 , 0xda8b4504 , 0xcda2baec , 0x96ce9a22 , 0x2306e607 , 0xf2b1ac46 , 0x0b32c414 , 0x2ecc258b ] )
+  , Mont::unsafe_make( [ 0x701b4458 , 0xd4595f61 , 0xa9cf46f8 , 0x8a4b0b19 , 0x7e1152d9 , 0x5f7e7d59 , 0xefe06632 , 0x1c530302 ] )
+  , Mont::unsafe_make( [ 0x4a109e0e , 0xa542219c , 0x90a02aa7 , 0xefb18fac , 0x68ddfb46 , 0x3e31fdb9 , 0x0ac393f4 , 0x11f7870f ] )
+  , Mont::unsafe_make( [ 0x52431f62 , 0x48d97ab4 , 0xd90bbd24 , 0x758adb03 , 0x91578a9c , 0x773d826d , 0x94df7b5b , 0x28b04786 ] )
+  , Mont::unsafe_make( [ 0xedbfb589 , 0x8df03c17 , 0x83f43cf0 , 0x0b85bb41 , 0x969219ff , 0x044fd78a , 0x7d1728df , 0x1576cc23 ] )
+  , Mont::unsafe_make( [ 0xd766a677 , 0xb0976e1a , 0xfb79362f , 0x06171963 , 0x5936e804 , 0xf7370b56 , 0x8bf1fb36 , 0x076e0fb2 ] )
+  , Mont::unsafe_make( [ 0x4acda3a1 , 0x45ee6d20 , 0x2c4bd649 , 0xcab0ff09 , 0x3e67368c , 0xe059ce87 , 0xb1e1106f , 0x18d1b25e ] )
+  , Mont::unsafe_make( [ 0x145b069a , 0x3e7d4d0d , 0xa793ff91 , 0xdcd63d06 , 0x2479bcdd , 0x475eab34 , 0xcb839554 , 0x226a7f60 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xc5974c24 , 0xf8c836c4 , 0x6e31013d , 0x7f348e8e , 0x75f93cfd , 0xc103e03b , 0x0b18cb0b , 0x0dd1ed4c ] )
+  , Mont::unsafe_make( [ 0xbc7bdf30 , 0xb953604d , 0x0d654f14 , 0xd807ae1e , 0xd4b1c6df , 0x35fdd98e , 0x8e2286af , 0x276780f2 ] )
+  , Mont::unsafe_make( [ 0x3c3a4fd8 , 0xcb0964e3 , 0x2d9d37c0 , 0x06b5c63e , 0x20db42f4 , 0x1916d1a0 , 0x9f444b78 , 0x0092240d ] )
+  , Mont::unsafe_make( [ 0x51ba7356 , 0xfa76411d , 0x56f8099d , 0x0b8fa26a , 0x50a957d7 , 0x74482f7b , 0x1d36ad2e , 0x139ba78d ] )
+  , Mont::unsafe_make( [ 0x0dd204a6 , 0x45415e7b , 0x4e8dacc2 , 0x4455cde2 , 0x77aa0710 , 0x6457118b , 0x45ac476e , 0x1d086d7b ] )
+  , Mont::unsafe_make( [ 0x89c424b1 , 0x86c04542 , 0x52548700 , 0x2cf68380 , 0x621e1aa2 , 0x920772ee , 0x7e79aea5 , 0x08fe6321 ] )
+  , Mont::unsafe_make( [ 0xc09099d5 , 0xc7754414 , 0xe6a15165 , 0xfa311171 , 0x101d5a46 , 0x850dd068 , 0x9dc57eaa , 0x19c7563e ] )
+  , Mont::unsafe_make( [ 0x584767f9 , 0x4e7cb02c , 0x2e633991 , 0x9969852e , 0xff7c9ca9 , 0x4fd95b4e , 0xd29bf229 , 0x2d1c590c ] )
+  , Mont::unsafe_make( [ 0x359377b8 , 0x47d1d755 , 0xecbd5381 , 0x9d861eff , 0xa46a7ce7 , 0xb32d118b , 0xe5d86036 , 0x1fcb45b0 ] )
+  , Mont::unsafe_make( [ 0xa86dd7c4 , 0xbb9ccb04 , 0xeae62141 , 0xae39682f , 0x9bbfc56b , 0x105cecbf , 0xac12c144 , 0x0b1dee04 ] )
+  , Mont::unsafe_make( [ 0xbedb09ef , 0xcfed5544 , 0x9abbab77 , 0xfff5c503 , 0x763a1c6f , 0x126812db , 0xfbaade55 , 0x170b90e8 ] )
+  , Mont::unsafe_make( [ 0x515ecbd5 , 0x193986e4 , 0xaca27038 , 0xd9846e27 , 0xe4dd1388 , 0xecce644a , 0xcf7905e9 , 0x07df7944 ] )
+  , Mont::unsafe_make( [ 0xefe8ad52 , 0xe814ad74 , 0x98adf796 , 0x46aa10dd , 0x570e03dc , 0xd4d5d00e , 0xde609af0 , 0x260b3adb ] )
+  , Mont::unsafe_make( [ 0x4c7ec08a , 0x9d849af8 , 0xd71698f6 , 0x9140ceb3 , 0xfbc09b96 , 0x33836e2c , 0x4e2154a6 , 0x117e1982 ] )
+  , Mont::unsafe_make( [ 0x8eb195c3 , 0x1221673a , 0x3c9d3362 , 0x447d49a7 , 0xa98f5865 , 0xd2c0b101 , 0x30e70a4d , 0x2a40ba3a ] )
+  , Mont::unsafe_make( [ 0x5729d1cd , 0x0b954832 , 0x3ab41f85 , 0x348480ce , 0x66ed0f7f , 0xbb434a1a , 0x983af8c2 , 0x0b53e6ea ] )
+  , Mont::unsafe_make( [ 0x03778d0b , 0x2a5250e7 , 0xf731640a , 0xa4d7bdf6 , 0x73f5b134 , 0x5cd223e1 , 0x97ebddee , 0x17a6e8cf ] )
+  , Mont::unsafe_make( [ 0xeb9dbe09 , 0xd4eb17fb , 0x482d3167 , 0x9365ea1d , 0xb3fec646 , 0xcdf3b61a , 0x9b710e3c , 0x04f00773 ] )
+  , Mont::unsafe_make( [ 0x7e428992 , 0xa3a5781f , 0xe4db8df1 , 0x7756bce6 , 0x4d602556 , 0x02f71c97 , 0xa7fdd3d8 , 0x10490824 ] )
+  , Mont::unsafe_make( [ 0x0dd96867 , 0x50a068f0 , 0x8254be68 , 0xbb971d32 , 0x03af1f67 , 0xbb2e289e , 0xbd6a6e09 , 0x2022a050 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xea12a051 , 0x941c2b64 , 0x23af9cc4 , 0x63e5856a , 0x149ac1cc , 0x4bbd3488 , 0xcf74890d , 0x21eb2e7b ] )
+  , Mont::unsafe_make( [ 0x0c3e2222 , 0xf0275a79 , 0xda1a4e25 , 0xeef6f937 , 0xde597a28 , 0xefd4a144 , 0x63426f21 , 0x19a3db39 ] )
+  , Mont::unsafe_make( [ 0x33c4b7bd , 0xe5b4de5d , 0x704c8750 , 0xab5223bf , 0x42850d55 , 0xd84c3a8d , 0xc104986a , 0x272bb2e2 ] )
+  , Mont::unsafe_make( [ 0x669db79f , 0x4359312e , 0x40e4172c , 0x70775b64 , 0xa0a733ba , 0xd3ad4598 , 0x14d9861d , 0x265bed57 ] )
+  , Mont::unsafe_make( [ 0xd7f0b447 , 0xa41deede , 0xa0f5eac9 , 0x78b9b767 , 0x6b29e2ae , 0xa81052ec , 0xa0ff298a , 0x0070ed9d ] )
+  , Mont::unsafe_make( [ 0x17f5acfa , 0x35e79dc5 , 0xe0b39a24 , 0xbe6d3c1a , 0xa04b5c28 , 0xb717b3af , 0xe6cb44df , 0x06f46b6a ] )
+  , Mont::unsafe_make( [ 0xa27b0205 , 0x9b46515d , 0xfd55e085 , 0x9abb7ad3 , 0x489eaaa6 , 0xdd86274f , 0x040e1a1e , 0x1864e097 ] )
+  , Mont::unsafe_make( [ 0x9c5e1fb2 , 0xbdceb49b , 0x4d5e6388 , 0xa58bb6ff , 0x726f68b8 , 0x04090782 , 0x8409fa5d , 0x02b1c7ca ] )
+  , Mont::unsafe_make( [ 0x3e70e51b , 0x36cc71ca , 0x8947d3d5 , 0xc5cd8f95 , 0x0e06d050 , 0x8b9bde50 , 0xaecffd62 , 0x0db62c97 ] )
+  , Mont::unsafe_make( [ 0x276d7749 , 0x772be6f2 , 0xf3cabbf3 , 0x561849af , 0xf2c08bab , 0x915b66d1 , 0x5a9011fb , 0x046901e0 ] )
+  , Mont::unsafe_make( [ 0xc915cadf , 0x9e84c735 , 0x7585f347 , 0x9b9a000c , 0xf384a7af , 0x798f38ce , 0xda183033 , 0x0e17c88e ] )
+  , Mont::unsafe_make( [ 0xc0096e5e , 0x00650e33 , 0x7c5a9e4c , 0xe1a42f58 , 0x0b27fd05 , 0xae9177be , 0xb0eead21 , 0x26f6c640 ] )
+  , Mont::unsafe_make( [ 0x2296e741 , 0xd0191b8e , 0x1d98ed8d , 0xa14bae13 , 0x0ca7e2c4 , 0xf74c2b9c , 0xa7a99e6f , 0x2afc7e06 ] )
+  , Mont::unsafe_make( [ 0x022162c9 , 0x093190c9 , 0xc2e0a428 , 0x8aac4c2e , 0xd1af9104 , 0x0950589e , 0x1dd8ad1b , 0x2fd0ea94 ] )
+  , Mont::unsafe_make( [ 0x1434eade , 0x300e1979 , 0x7f670d14 , 0xa708d1a4 , 0x4f314394 , 0xc6f2aaf8 , 0x690284a3 , 0x23236dff ] )
+  , Mont::unsafe_make( [ 0xa52b2dff , 0x211fdb84 , 0x7ed9ff63 , 0x1816ca18 , 0xd3b0659f , 0x2e182022 , 0x65b4de8b , 0x074bc41f ] )
+  , Mont::unsafe_make( [ 0xebbbbd4d , 0x757278ca , 0xcf3dd459 , 0x5f62cfcc , 0x5dca5182 , 0x7a6a611a , 0x5cb7d197 , 0x2c8d3f4f ] )
+  , Mont::unsafe_make( [ 0x3bdde970 , 0x0c385fcf , 0x224f127f , 0x61dd7a95 , 0x937b0508 , 0xf006663b , 0x7f39620b , 0x25f3fe1a ] )
+  , Mont::unsafe_make( [ 0x14c7498c , 0x2e509247 , 0xa382a875 , 0x42c444e5 , 0xbaf0f976 , 0xe3de4a4b , 0x98385953 , 0x28b4aed8 ] )
+  , Mont::unsafe_make( [ 0xd668f205 , 0x192db859 , 0x1522c545 , 0xf6611b1a , 0x0421ce01 , 0x2821b74f , 0x1be55e34 , 0x23247e5c ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x7c782ed0 , 0xb5a03d13 , 0x906592e0 , 0x715b08ad , 0x085cf0e3 , 0xc06de75a , 0x0ea419d8 , 0x1b95dc41 ] )
+  , Mont::unsafe_make( [ 0x858aefe1 , 0xecf5ee1c , 0x843ba5a3 , 0xbe60dd49 , 0x4dda8011 , 0xb48eb153 , 0xd7e695e6 , 0x18e9ada8 ] )
+  , Mont::unsafe_make( [ 0xd336180b , 0xf20ee9a8 , 0xd28971e8 , 0x4c03c92e , 0xffd1bea4 , 0x0eb6b147 , 0x08f96c0e , 0x2812ab26 ] )
+  , Mont::unsafe_make( [ 0xd7b0f2b6 , 0x5b903d4d , 0x919559a3 , 0x1e359832 , 0x7cde1b5e , 0x62a479e6 , 0x8c9cbb46 , 0x147a91ac ] )
+  , Mont::unsafe_make( [ 0xc5572fe3 , 0x55885f32 , 0x04f87776 , 0x1b18255b , 0x60992383 , 0x28379ff6 , 0x1bcae977 , 0x0ca8a98d ] )
+  , Mont::unsafe_make( [ 0x5b2063c8 , 0x8b41ef7a , 0x481cc807 , 0xe68bff60 , 0xd45f8a33 , 0x102dada8 , 0x9a01b9b7 , 0x1fbb9f47 ] )
+  , Mont::unsafe_make( [ 0x264ac54d , 0xc80d602c , 0x4daa1400 , 0xa232b788 , 0x0073f2f1 , 0xf936fcc1 , 0x84e9ee62 , 0x09149fc9 ] )
+  , Mont::unsafe_make( [ 0x8d990dcd , 0xc12bc016 , 0xbf2188b0 , 0xce155daa , 0x38df2503 , 0x47451d9b , 0x8dfeb54f , 0x0f3f3aef ] )
+  , Mont::unsafe_make( [ 0xdcbd6591 , 0x375a9598 , 0x5f967d0c , 0x6a17c7c4 , 0x1824ea83 , 0x584e7a8c , 0xcc1dde65 , 0x10fe6866 ] )
+  , Mont::unsafe_make( [ 0xd84f8285 , 0x1f5406a9 , 0x00838f37 , 0x818f17b9 , 0x8fb3cc47 , 0xae7be19a , 0xa01fa1f3 , 0x145a8ae8 ] )
+  , Mont::unsafe_make( [ 0x70e29b79 , 0x9025f2ec , 0x7659bf39 , 0xd5e759fe , 0xbd3f68bb , 0x8d64204f , 0xffa02da1 , 0x19433206 ] )
+  , Mont::unsafe_make( [ 0x63709181 , 0x3dbb2732 , 0xd3e5ddcb , 0x4e8cad26 , 0xcfbdcd2b , 0x4ff14548 , 0x2a8c1671 , 0x0ebc3ab6 ] )
+  , Mont::unsafe_make( [ 0x7cbbaf2d , 0x51dd32e1 , 0xfac8d566 , 0x79e24f22 , 0x3de092ae , 0x3d913c03 , 0x17eb19e3 , 0x21196a6c ] )
+  , Mont::unsafe_make( [ 0xf8db5149 , 0xc2dd8d10 , 0x7b0791f6 , 0xc999aa5e , 0x15de542a , 0xe6f807f2 , 0xd52f4acc , 0x1ded295e ] )
+  , Mont::unsafe_make( [ 0xe6204a21 , 0xa079614d , 0x95bcf8c1 , 0x24de23d4 , 0x09f77673 , 0xd8983b9e , 0x35927b36 , 0x05b45ed2 ] )
+  , Mont::unsafe_make( [ 0x6b12b487 , 0xd90b62a0 , 0x020c5aea , 0x5145c5e9 , 0x743a3308 , 0xb693d70c , 0x9816b4ab , 0x07c7783b ] )
+  , Mont::unsafe_make( [ 0x24629cb7 , 0x56ebfde1 , 0x62511396 , 0x53c25f7d , 0x608d0ef5 , 0x5b68b679 , 0x83f5ce11 , 0x2e295637 ] )
+  , Mont::unsafe_make( [ 0x2b2667fe , 0xc3d8e882 , 0x486e8cf5 , 0xa96cfabe , 0xd6cdd9e9 , 0x00515b98 , 0x74db6e45 , 0x11d131f7 ] )
+  , Mont::unsafe_make( [ 0xeb119361 , 0x94e56b17 , 0x55d0073c , 0x6c3cc181 , 0xef038700 , 0xf9194ba0 , 0xf2935145 , 0x0dc215fe ] )
+  , Mont::unsafe_make( [ 0xbd670ae8 , 0xce4ba839 , 0x93879d01 , 0x6d155ba8 , 0xdcbdcd81 , 0x2344aa72 , 0x1266a83f , 0x16aa8007 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xd0d2b0a8 , 0xa3de3313 , 0x7eca2e76 , 0xbc168d13 , 0x5da3b6ed , 0x3612acb9 , 0xf5e2aeac , 0x13fea62a ] )
+  , Mont::unsafe_make( [ 0x88b3af03 , 0x80ba410a , 0x96a0ae8f , 0x254c680d , 0x07e2951e , 0x5dc4e1ba , 0x9e181f63 , 0x26311cf8 ] )
+  , Mont::unsafe_make( [ 0xd838afa2 , 0xf3b85680 , 0xadf8dce2 , 0x3f4dac78 , 0x4197d2cd , 0x895ff45a , 0x28ce6e44 , 0x013b0dbb ] )
+  , Mont::unsafe_make( [ 0x9270a5f7 , 0x7a44214a , 0x109b9b4a , 0x991a81f4 , 0x39c204d6 , 0x9dd20bf7 , 0x0d57a235 , 0x1a8b581b ] )
+  , Mont::unsafe_make( [ 0xe0e25324 , 0xe4085210 , 0xf48fef1e , 0xec891a20 , 0x3af2aad4 , 0x945eee74 , 0x4d494561 , 0x06e4c48c ] )
+  , Mont::unsafe_make( [ 0x41d2e74f , 0x939d7742 , 0x6c1b3557 , 0x8b7e2078 , 0x63bc7ab6 , 0x3f2f2cdc , 0xd78bb031 , 0x264d6479 ] )
+  , Mont::unsafe_make( [ 0x6ca065a2 , 0x0eafb7bd , 0xbd7ca1eb , 0x727eedf5 , 0x305ebcfc , 0xe9b5bccf , 0xb49593a5 , 0x106bea1d ] )
+  , Mont::unsafe_make( [ 0xb3107959 , 0x0687ce48 , 0x564243b7 , 0x59c1cc1a , 0xe533cce0 , 0x59d12fd9 , 0xca229b0e , 0x133ced13 ] )
+  , Mont::unsafe_make( [ 0x00d3bd59 , 0xb8eb57ca , 0x7683017b , 0xb4b92c71 , 0x482ddb77 , 0x3bc36d0c , 0xb4b6b254 , 0x1a8fc274 ] )
+  , Mont::unsafe_make( [ 0x3b193964 , 0x4499c3fa , 0x1bbcb4f9 , 0xc4d81731 , 0x9b9bd526 , 0x7ee17c20 , 0x2721d8cc , 0x08764b9a ] )
+  , Mont::unsafe_make( [ 0xeaea05f2 , 0x2500b1fc , 0xd39355e3 , 0xe583e402 , 0x805416ed , 0xcd9f25cb , 0xc6089cc6 , 0x184a860e ] )
+  , Mont::unsafe_make( [ 0x73a93ac1 , 0x70ccf4f0 , 0x9c58c33a , 0xe893b3c6 , 0x7b3b2f8d , 0x58f42a28 , 0x0d9c8a61 , 0x2a96da86 ] )
+  , Mont::unsafe_make( [ 0xc9d4b0b4 , 0xe869f8e3 , 0x59454425 , 0x8362e167 , 0x1fb4963e , 0x94d13ff8 , 0xd58cfa11 , 0x22d4e91f ] )
+  , Mont::unsafe_make( [ 0xfd8298f7 , 0x30889c02 , 0xa13c08a8 , 0xfc991433 , 0x863e111c , 0x9e116fd8 , 0xaffcada5 , 0x16e024a9 ] )
+  , Mont::unsafe_make( [ 0xbdb52c80 , 0x31f29127 , 0x3f20a0e9 , 0x85861d83 , 0xb4d52e34 , 0x62088f9c , 0xec228218 , 0x2a7f843f ] )
+  , Mont::unsafe_make( [ 0xbfaeb61f , 0x44f345cd , 0x52eac41a , 0x78ec1e94 , 0x1e108bcc , 0xcf493008 , 0xbe698232 , 0x264150d2 ] )
+  , Mont::unsafe_make( [ 0xc3f60ea9 , 0x420bf29c , 0x0fb35ac4 , 0x14699dc3 , 0x29e43124 , 0x1f75f159 , 0xf9edaca6 , 0x17dda8c2 ] )
+  , Mont::unsafe_make( [ 0xe3fd530d , 0x1bf90dea , 0xa7df540b , 0x719a55ca , 0xb71f03d7 , 0x3ce85a77 , 0x2c02b20f , 0x06267eb2 ] )
+  , Mont::unsafe_make( [ 0xb18338e9 , 0xb66f6827 , 0xb16fe126 , 0x3a838697 , 0x702c2b2b , 0xaeb04d40 , 0x596cb35b , 0x155abc78 ] )
+  , Mont::unsafe_make( [ 0xe3e74b37 , 0xc29dd0d9 , 0x69c30377 , 0xc01d3287 , 0xc9e3dcdc , 0x38c76309 , 0xdd4b148e , 0x26501404 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x8d55243a , 0xdefe7e44 , 0x50950477 , 0xf72d4749 , 0xa00882a9 , 0xe73c0203 , 0x15482ffb , 0x1ec96823 ] )
+  , Mont::unsafe_make( [ 0x2c8a718d , 0x2869c1fc , 0x73762521 , 0xde91ad01 , 0x7ff68508 , 0xa05e4a44 , 0x1e1f2999 , 0x1ba33a08 ] )
+  , Mont::unsafe_make( [ 0x652dc86e , 0x784b4d64 , 0x217c7338 , 0x25531a8d , 0xda4e8d7a , 0xe3402d5e , 0x1cfcbc5e , 0x16c2929d ] )
+  , Mont::unsafe_make( [ 0x58cc3261 , 0xb4c2d80f , 0x188bab68 , 0xdb87ca13 , 0x8057443e , 0x2e17cefb , 0x87f204a0 , 0x1b24313b ] )
+  , Mont::unsafe_make( [ 0x16f29316 , 0xdb9dd62e , 0x30b79bfb , 0x48f036a8 , 0x56562027 , 0xb2773a89 , 0xc6a80548 , 0x22d10d42 ] )
+  , Mont::unsafe_make( [ 0x26f5221f , 0xca011922 , 0x7e7a5cb5 , 0x9f6b5529 , 0x327eef91 , 0x769aec85 , 0x1dc8701a , 0x229a199f ] )
+  , Mont::unsafe_make( [ 0xc94260a4 , 0xaa6ba1d7 , 0xbc8b3280 , 0x1b4d1368 , 0x3ef105ec , 0xb4b3cef7 , 0x6595be19 , 0x0376fccc ] )
+  , Mont::unsafe_make( [ 0xa34e8d85 , 0x064badba , 0xb0a6b7dc , 0x9827d793 , 0xdd29795b , 0xbda71702 , 0xecaea2fb , 0x2591a53e ] )
+  , Mont::unsafe_make( [ 0x7fa2f9b8 , 0x9a4558bb , 0xb5753f11 , 0x6e0dea07 , 0xf7f4a933 , 0x049b99ef , 0x387670d1 , 0x2f518a16 ] )
+  , Mont::unsafe_make( [ 0x168a9d05 , 0xfa3c8ce3 , 0xbce4f2a8 , 0x732f2239 , 0x01b1fa41 , 0x47d463c7 , 0xb4307f6d , 0x2e4bc12f ] )
+  , Mont::unsafe_make( [ 0xe0a79bae , 0x2ca297d8 , 0x13c467eb , 0xff21658f , 0x9afe3881 , 0xfb4749d8 , 0xdcf089cd , 0x20dfbd10 ] )
+  , Mont::unsafe_make( [ 0xd9577a23 , 0x2be3c97f , 0xef9fd564 , 0x444fd9ea , 0x67304c24 , 0x97214305 , 0xf9a4af44 , 0x1a7396f1 ] )
+  , Mont::unsafe_make( [ 0x4aaddaab , 0xd819adf3 , 0x1d7b105e , 0xd189a9a6 , 0x95792e47 , 0x48d36305 , 0x84ee6ddd , 0x270b8aee ] )
+  , Mont::unsafe_make( [ 0x7e433279 , 0xf3664356 , 0xefac337f , 0x75625659 , 0x79cf8343 , 0x9bc041bd , 0x0d7fcc14 , 0x0f10d4ea ] )
+  , Mont::unsafe_make( [ 0x70908da6 , 0xe9b85bcd , 0x94ef55b1 , 0x64490edc , 0xa07192fb , 0x72e92e9a , 0xd8726c71 , 0x0ebcabd0 ] )
+  , Mont::unsafe_make( [ 0x35a6fbf1 , 0xd197c054 , 0x95b9990b , 0x9751e815 , 0xdeb1c049 , 0x7e2f7d31 , 0x6d07a447 , 0x2842ad21 ] )
+  , Mont::unsafe_make( [ 0x97448dfe , 0xa8b6c9aa , 0x58a581ff , 0xc3d45d1b , 0xbe7ec955 , 0x41357775 , 0xb6843481 , 0x057405db ] )
+  , Mont::unsafe_make( [ 0x6844855e , 0x3dc3fd59 , 0xbb4f7b14 , 0x4a2ad0c4 , 0x07498fa7 , 0x38cbe3c1 , 0x263b5cdf , 0x272a87d8 ] )
+  , Mont::unsafe_make( [ 0xdc94d57a , 0x01c30b16 , 0x41dbedba , 0xf40f303a , 0x54627654 , 0xc67aa42a , 0x3840b6ee , 0x2c1ae315 ] )
+  , Mont::unsafe_make( [ 0x5c47c4e7 , 0x43cc351d , 0xc5086e5b , 0x7d67f1aa , 0x7ebde805 , 0x03204663 , 0x1d4d87e4 , 0x1ff9454a ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xb705872f , 0x33535196 , 0x2f6ddca1 , 0x1463d733 , 0x09bfcb98 , 0x6299eec0 , 0x19a3b1ea , 0x2affbd00 ] )
+  , Mont::unsafe_make( [ 0x53c24e1a , 0xe3551af7 , 0x0f433170 , 0x4b9afbf7 , 0x5c4577b1 , 0xa9b5759b , 0x96eb73f9 , 0x0dd9b751 ] )
+  , Mont::unsafe_make( [ 0x4b7428c9 , 0xf2609e67 , 0xb54469e3 , 0xaaf23d5a , 0x731c622a , 0x280f8ff8 , 0xcb563394 , 0x23a0de24 ] )
+  , Mont::unsafe_make( [ 0x9f463f5a , 0x22383ab6 , 0xa2e88548 , 0x738fd096 , 0x90af6616 , 0x3a63e632 , 0x6540ffbe , 0x11f1b9b1 ] )
+  , Mont::unsafe_make( [ 0x51ba4490 , 0x58c67f58 , 0x176cfe08 , 0x3b3f7767 , 0x1555f8cd , 0x1829a343 , 0x2f40419a , 0x17f500bf ] )
+  , Mont::unsafe_make( [ 0xed47c7a4 , 0x301602bf , 0x1535f4fd , 0x2c514c52 , 0x63f7953b , 0xc50a0544 , 0x32532e07 , 0x14bde2ec ] )
+  , Mont::unsafe_make( [ 0xb8d76e17 , 0xdec56de1 , 0x42e29136 , 0x29ea45ff , 0x558dbc5e , 0x89546c61 , 0x4eeb3557 , 0x01d84905 ] )
+  , Mont::unsafe_make( [ 0xc7fd4fe5 , 0x8a52b0b3 , 0xf8c65207 , 0x8304dd88 , 0xf143e7b1 , 0x1ffca15f , 0x6e0be0d7 , 0x21264f36 ] )
+  , Mont::unsafe_make( [ 0x90ee476e , 0xf517a43f , 0xf14a31d3 , 0x7b766a63 , 0x1f615d45 , 0xa91d6fd2 , 0x21319af9 , 0x1228a75e ] )
+  , Mont::unsafe_make( [ 0x6891fbbc , 0xe1491412 , 0xf7ab13e0 , 0xb1e32eb9 , 0xd1ab1993 , 0xc2c20f8b , 0x2b198c78 , 0x1c6e1612 ] )
+  , Mont::unsafe_make( [ 0x4cdd7027 , 0x05bc3e2f , 0x347d6430 , 0xb10d8193 , 0x57039823 , 0x614cdf4b , 0x614b86e7 , 0x06e4375d ] )
+  , Mont::unsafe_make( [ 0x051a265d , 0x8524c7c0 , 0x194377bb , 0xb9032c94 , 0xd55b3c01 , 0xd56b6b82 , 0x232861ae , 0x273e5172 ] )
+  , Mont::unsafe_make( [ 0x40cbe222 , 0x7db71d54 , 0x5dc05e67 , 0xa335c4ca , 0xb6ac00d3 , 0x2562d45a , 0xecb55ae2 , 0x2c42ac0c ] )
+  , Mont::unsafe_make( [ 0xf8053350 , 0x4dbb582c , 0x2296c1f5 , 0x0b06d318 , 0x9a227fec , 0x799ea552 , 0xfd8dcb34 , 0x2c87d876 ] )
+  , Mont::unsafe_make( [ 0x637802d8 , 0x881d2396 , 0xfbf9aa68 , 0x19e91389 , 0xb6c01f6a , 0xd2da3387 , 0x0430e8dd , 0x105a7794 ] )
+  , Mont::unsafe_make( [ 0xb8e3b4af , 0x6e3690c4 , 0x933aed94 , 0x2da836ae , 0x6d1d787f , 0x5a387a1a , 0x37cdc250 , 0x2e66311c ] )
+  , Mont::unsafe_make( [ 0x62383790 , 0xfe2a645d , 0x44a181df , 0x20242d02 , 0x4865ab1c , 0x5a0020f0 , 0x25de3d5a , 0x09d507bd ] )
+  , Mont::unsafe_make( [ 0x0aa8b6f6 , 0x4842f901 , 0xc26a3f97 , 0x1360ba2d , 0xd180af72 , 0x341af463 , 0xfc91359d , 0x2717a8f1 ] )
+  , Mont::unsafe_make( [ 0x851d6c9d , 0x1ab3769b , 0xb02421cc , 0x36b1ff1b , 0xe87f0973 , 0x773a3b7a , 0x66cea291 , 0x2e0254bd ] )
+  , Mont::unsafe_make( [ 0xbe45f48b , 0x4432885e , 0x673d5eb7 , 0x4fb6ebd6 , 0x158e9e55 , 0x3a36f190 , 0xb9d9c4a8 , 0x2d8ba4d2 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xc26324fa , 0x2b4a7832 , 0x45f1c3c4 , 0x05c47dc2 , 0x0e1cb482 , 0xcb5f27b9 , 0x781ec0d8 , 0x2a460cb9 ] )
+  , Mont::unsafe_make( [ 0x67814ace , 0xd3d1ce27 , 0x78263100 , 0x6b2b12b4 , 0x2fcf29dd , 0xf1730794 , 0x06cc39eb , 0x1e483ddf ] )
+  , Mont::unsafe_make( [ 0x548c849f , 0xd4ef70a5 , 0xf8cdcdfe , 0x39961eb5 , 0x7047044d , 0xd803d766 , 0x9aa56738 , 0x162f9520 ] )
+  , Mont::unsafe_make( [ 0xd6a3ce69 , 0x7c7f30c0 , 0xa11a0fa9 , 0x0eb263be , 0x47c804e3 , 0x45321f6f , 0x01afc55d , 0x22a88201 ] )
+  , Mont::unsafe_make( [ 0x3b88e2ec , 0x1e2eb77b , 0x7995c45b , 0x44db6930 , 0x835c85de , 0x4e0e9167 , 0xcb722f9c , 0x2ddcf627 ] )
+  , Mont::unsafe_make( [ 0xc91629a3 , 0x5a79982b , 0xd26d3035 , 0x25a58032 , 0x610a3d12 , 0xd18ca2d7 , 0xdaf7c3b9 , 0x1180912e ] )
+  , Mont::unsafe_make( [ 0x9b95646d , 0xde15cc9e , 0xcf49f3c5 , 0xaded2a48 , 0x80cc70b2 , 0x3d44411d , 0x383af71c , 0x0aa29303 ] )
+  , Mont::unsafe_make( [ 0xcea3312b , 0x7a2830ad , 0xf453d8cd , 0xbf06e74f , 0xfebb305f , 0x3a45a43e , 0x367d5251 , 0x11ba5e59 ] )
+  , Mont::unsafe_make( [ 0x5b3fe98a , 0x098c0107 , 0xf94cbfdf , 0x39a98f1f , 0xe9339e93 , 0x51ce2750 , 0x072c4f70 , 0x077289bc ] )
+  , Mont::unsafe_make( [ 0x2fd6111e , 0x5ebfa44d , 0x8b2b7d73 , 0x62f2a886 , 0xa5ebc814 , 0x9b23d070 , 0x6b78e6d7 , 0x0c3ce318 ] )
+  , Mont::unsafe_make( [ 0x5ae54220 , 0x52ceebdf , 0xee55605a , 0x25c8841b , 0x225e6a09 , 0x0ec863c0 , 0x0ad252a5 , 0x1c17e5bd ] )
+  , Mont::unsafe_make( [ 0xd3cde922 , 0x28574531 , 0x8746ca4f , 0x2b88ac3c , 0x51b4db67 , 0x9dea19cf , 0x27eb92b9 , 0x2549f2b4 ] )
+  , Mont::unsafe_make( [ 0x3ef9e516 , 0x6335e790 , 0x45433cd2 , 0x8346e541 , 0x7cc17158 , 0x7e520745 , 0x2abf822d , 0x2926e0f8 ] )
+  , Mont::unsafe_make( [ 0x66cbc83a , 0xfe9a5d32 , 0x6edbb652 , 0xb6ff65b5 , 0xc9bcf338 , 0x948e11b8 , 0x1d50e12f , 0x2a47cdb5 ] )
+  , Mont::unsafe_make( [ 0x03866f26 , 0xd3829acc , 0x9350b3e8 , 0x87d3743d , 0x9799906c , 0x2b0c9cbd , 0x4ecf77a4 , 0x2786f667 ] )
+  , Mont::unsafe_make( [ 0x241f96a0 , 0x276e2d68 , 0xbb5d3ce0 , 0xa9e239ff , 0x88f853a9 , 0x06825294 , 0x400c1c8e , 0x2d585d15 ] )
+  , Mont::unsafe_make( [ 0x19eb3155 , 0xd457bd3d , 0xdd4d0d7a , 0xbd78af69 , 0x105a455f , 0x28547e6f , 0x0b2d3b14 , 0x20427960 ] )
+  , Mont::unsafe_make( [ 0xd2e54a9b , 0x6752fec3 , 0x5e76fd72 , 0x27f56e5d , 0x81411c83 , 0x3ce4f3a8 , 0xb2294ca0 , 0x1932abf0 ] )
+  , Mont::unsafe_make( [ 0xe7500fa1 , 0x47a9dc3b , 0x47663597 , 0xa1874ba8 , 0x59f3ba61 , 0xe4f4957c , 0x592b5f2c , 0x148f164e ] )
+  , Mont::unsafe_make( [ 0x08dee8e8 , 0x0abf939d , 0xa8d036aa , 0x5a4319e2 , 0x633dca31 , 0xefb77be5 , 0x6f250168 , 0x11284d80 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0x9e5975f6 , 0x356d9ee9 , 0xbcd0fe9e , 0xcaa65bd4 , 0x1e9a031e , 0x93a533e8 , 0x6b7cef9b , 0x3019ce2b ] )
+  , Mont::unsafe_make( [ 0xdce62727 , 0xb303929b , 0x1a251c39 , 0x296cfd04 , 0x69b89f08 , 0x90c7dc7f , 0xd4645478 , 0x05847637 ] )
+  , Mont::unsafe_make( [ 0xa33baa78 , 0x60467abf , 0x4f4171a8 , 0x80a71378 , 0x8396cf8a , 0x2d4e604e , 0xd2b3f819 , 0x15df614b ] )
+  , Mont::unsafe_make( [ 0x695e59e3 , 0xedca6e0d , 0x4367965b , 0x1b51c4f3 , 0xa85486b2 , 0x4bd5d797 , 0x0687c533 , 0x0724e2a3 ] )
+  , Mont::unsafe_make( [ 0xcf6cac29 , 0x66e06cee , 0xba337a81 , 0x87f89a09 , 0x5665a74f , 0xda667689 , 0x3db8a84c , 0x14f66432 ] )
+  , Mont::unsafe_make( [ 0xd447697c , 0xbea05117 , 0xa168b093 , 0x29532815 , 0xfe56ed38 , 0x5c6d1f0e , 0xf625ccc1 , 0x2ca11356 ] )
+  , Mont::unsafe_make( [ 0xcbc7ef0e , 0xa018b8be , 0x42f274fa , 0x5f4153f3 , 0x18c90811 , 0xdaf9f77c , 0xc88b7c1f , 0x20c6d52b ] )
+  , Mont::unsafe_make( [ 0x803a6628 , 0x8d2ee8af , 0x2645851f , 0xe6fb794c , 0xb4905be0 , 0x145d9e1f , 0x4467c6ca , 0x1223a46b ] )
+  , Mont::unsafe_make( [ 0xa747e30a , 0x3d895e38 , 0xa9cdef84 , 0x5c38802d , 0xead6add6 , 0x4456227e , 0x5e00e9f1 , 0x079c4266 ] )
+  , Mont::unsafe_make( [ 0xc7fc05b0 , 0xb96d63ad , 0xec2ad3ac , 0xa5f62c63 , 0x61d780d2 , 0x87f0d2bc , 0xbab5aaff , 0x1e9b8128 ] )
+  , Mont::unsafe_make( [ 0x839054fb , 0x9b09499d , 0x82e68cfe , 0x02beca55 , 0x6ff80d8f , 0xd34b09b9 , 0xba37d2ed , 0x1cd1ad64 ] )
+  , Mont::unsafe_make( [ 0xce3f7823 , 0x1b013218 , 0x904b1066 , 0x3db8fa63 , 0x647a7046 , 0xd1c323a0 , 0x5f8f1462 , 0x1a679a7b ] )
+  , Mont::unsafe_make( [ 0x9a14fb88 , 0x44405008 , 0x285b7bce , 0x027e3bf1 , 0x636cce22 , 0x34f3a6cb , 0x93219be7 , 0x0e0169d1 ] )
+  , Mont::unsafe_make( [ 0x287425f5 , 0xc800275d , 0xb17c68de , 0xe9dacede , 0x3de8275c , 0x8a41dac6 , 0xf0ddb6e2 , 0x0b944cca ] )
+  , Mont::unsafe_make( [ 0x445b0304 , 0x19c0d805 , 0x8bf1404a , 0x1236941b , 0x3456f010 , 0x28f68365 , 0xe124bbf6 , 0x0782f404 ] )
+  , Mont::unsafe_make( [ 0x20950f56 , 0xb6914e13 , 0x091012ca , 0x3273d138 , 0x7450a333 , 0xe86217e6 , 0xfe80e354 , 0x2d83c1a6 ] )
+  , Mont::unsafe_make( [ 0xf0609e77 , 0xfe8b3ed6 , 0x3d955d61 , 0x67056918 , 0x9f15e83f , 0x3a3e3211 , 0xd604df33 , 0x19296a5a ] )
+  , Mont::unsafe_make( [ 0xaa6ab7ac , 0x4715e74b , 0x4f5e84d3 , 0x33f85ae1 , 0x32e90ae0 , 0x2ffe036b , 0xe186b662 , 0x0ba3f87e ] )
+  , Mont::unsafe_make( [ 0xd2881246 , 0x22257296 , 0xe765bfd8 , 0x6dceb773 , 0x91676d8f , 0xf22ccdca , 0x64d4180b , 0x26f638fc ] )
+  , Mont::unsafe_make( [ 0x2e8a4bcd , 0xdda4bcfc , 0x415d50cc , 0xb90b1141 , 0x501c6dea , 0x10a31dbf , 0x990f98ef , 0x295a6448 ] )
+  , Mont::unsafe_make( [ 0xac4b23e3 , 0xbf35d343 , 0xf3cf3279 , 0x32869ab9 , 0xdd96c8cc , 0x5a21a53a , 0xe6536aec , 0x2aec2b24 ] )
+  , Mont::unsafe_make( [ 0xaae16e26 , 0x652c72b2 , 0xbd67cae4 , 0x4f44a043 , 0xed196729 , 0x1544711e , 0x65e36084 , 0x2cef99d2 ] )
+  , Mont::unsafe_make( [ 0xc3af9a04 , 0x5e00280d , 0xd0129037 , 0xbe3c438d , 0xaad082ba , 0xcac73dc2 , 0x9b15bffb , 0x1de89db4 ] )
+  , Mont::unsafe_make( [ 0xd30642cf , 0xf1847122 , 0x3151e966 , 0x333e51ed , 0xcc51afc1 , 0xb4c0c238 , 0xeda9e0f8 , 0x26b27977 ] )
+  , Mont::unsafe_make( [ 0x1bc13ae6 , 0x2d33b9cf , 0x63b25100 , 0xcbf512ea , 0x3f32c053 , 0xdc8f0361 , 0x46932f9a , 0x01577ecf ] )
+  , Mont::unsafe_make( [ 0x2dbd7402 , 0x2c2a454f , 0x53e21ef0 , 0xb3e113b6 , 0x40b58f6c , 0xe0dde2a0 , 0x3e904fec , 0x11144a08 ] )
+  , Mont::unsafe_make( [ 0xbe98a5b9 , 0x0f209ac4 , 0x38402118 , 0x8ae72aaa , 0xa6382aca , 0x6e167dce , 0xd56b5bff , 0x17581df3 ] )
+  , Mont::unsafe_make( [ 0x9be40677 , 0x4a8ffd0c , 0x45d1c7a0 , 0x2ee90ae3 , 0x741c279f , 0x9d93c55c , 0xb22331ff , 0x07b4c34c ] )
+  , Mont::unsafe_make( [ 0xcc341057 , 0x5ce00a54 , 0x5cedeebd , 0xee301f91 , 0xf2d423a0 , 0xc1f3758b , 0x2c84bc53 , 0x2291a6ad ] )
+  , Mont::unsafe_make( [ 0xa79dcb2a , 0x3cd646df , 0xa348dcb1 , 0xd418f0f1 , 0x02dc2b7d , 0x22957614 , 0x3af3bba5 , 0x1a2ea03e ] )
+  , Mont::unsafe_make( [ 0xc7fe8217 , 0x953f94a0 , 0xa1b8a97c , 0x6f90692a , 0x15fce38c , 0xabae0d66 , 0x14193c8c , 0x15604f4a ] )
+  , Mont::unsafe_make( [ 0xf9f9751a , 0x2b1049b9 , 0x7b3f8231 , 0x76743e1c , 0x83aa4ccc , 0x9ee6897f , 0x7b6955f4 , 0x1ec889d0 ] )
+  , Mont::unsafe_make( [ 0x533acb09 , 0xc4690c6e , 0x828597ad , 0x9e0ec418 , 0x5fb63e9a , 0x216bd6ef , 0xea127695 , 0x0ce7d631 ] )
+  , Mont::unsafe_make( [ 0x7d5802ba , 0xc7cbb672 , 0x68c7e02b , 0xc3445334 , 0x22af1a34 , 0x53d25571 , 0x65f41c24 , 0x2b5dec52 ] )
+  , Mont::unsafe_make( [ 0x67d4802d , 0x49b36bfd , 0x6248034d , 0xb47bf01c , 0x739f5d08 , 0x572c4c45 , 0x7b663c57 , 0x0ce98f34 ] )
+  , Mont::unsafe_make( [ 0xc45f5da7 , 0x96a42e6e , 0xa4128910 , 0x2b0a142e , 0xd07cd271 , 0x17d58f95 , 0xaa2ded20 , 0x0a47d9bf ] )
+  , Mont::unsafe_make( [ 0x7a9cc845 , 0xbe3a07e2 , 0xe4848d50 , 0xc134408b , 0x5366daa2 , 0x0ea4f794 , 0xc519db4b , 0x301d90e4 ] )
+  , Mont::unsafe_make( [ 0x0443a904 , 0x20cdf3e7 , 0x1381f2c1 , 0x7b5e01f6 , 0x7cd24c45 , 0x5b159d1a , 0xbdc831d6 , 0x0c6b8614 ] )
+  , Mont::unsafe_make( [ 0x0d476290 , 0x8c812d16 , 0x1e3afee5 , 0x1c1d60d7 , 0x383055c4 , 0xa09b69f9 , 0x6dfd67e8 , 0x05fd1261 ] )
+  , Mont::unsafe_make( [ 0x37e222f9 , 0x347ad3bc , 0x6e4358ed , 0xa703b868 , 0xf7bc2938 , 0x5252d25c , 0x942169a4 , 0x09a5c063 ] )
+  , Mont::unsafe_make( [ 0x885e3181 , 0xfa0f7997 , 0x1937979e , 0x3696fdac , 0xce55f885 , 0xb8c35bb8 , 0x94fcfd4e , 0x13239e98 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
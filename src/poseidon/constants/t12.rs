@@ -0,0 +1,1856 @@
+
+// circomlib's Poseidon constants for t=12
+
+use crate::bn254::montgomery::*;
+
+
+//------------------------------------------------------------------------------
+
+// round constants (t for external, 1 for internal rounds; flattened)
+pub const CONST_C: [Mont; 156] = 
+  [ Mont::unsafe_make( [ 0x7e9999ec , 0xc206ab5b , 0x48207108 , 0x695b8ad1 , 0x23e0e1e2 , 0xad8c370a , 0x93731ecc , 0x0376bc09 ] )
+  , Mont::unsafe_make( [ 0x54028e07 , 0x2841f2ca , 0x1ae9b944 , 0x8b921e06 , 0x15cd7ae9 , 0x1d7723ac , 0x680293c0 , 0x1016b5bf ] )
+  , Mont::unsafe_make( [ 0x9b82bdc7 , 0xeca055ca , 0xa276f303 , 0x1b252e7b , 0x49684730 , 0xc82151c1 , 0x81751601 , 0x0fb98a74 ] )
+  , Mont::unsafe_make( [ 0xc9c3cd7e , 0x603d383e , 0x549a464d , 0x7273c3a2 , 0x946ce148 , 0x4adb115d , 0x9fc53e56 , 0x04fbff9e ] )
+  , Mont::unsafe_make( [ 0x73cb24b6 , 0x9569bff7 , 0xaaa7ef1a , 0xf3b4a076 , 0xd21580b7 , 0x615aa200 , 0xc88a0cf5 , 0x2b85c2ea ] )
+  , Mont::unsafe_make( [ 0xa1fb4645 , 0xbf5bd329 , 0x9034d515 , 0xbbee81a8 , 0x190a425d , 0x4a8ed992 , 0x58302192 , 0x244c843e ] )
+  , Mont::unsafe_make( [ 0x6e85c225 , 0x0d2407dd , 0x7b4eb9cc , 0x58fc7961 , 0x0b258d9a , 0xd00161a7 , 0xdfc4f6ec , 0x1963a517 ] )
+  , Mont::unsafe_make( [ 0xd0349d0c , 0x9edcd6d3 , 0xf8472c55 , 0xb7104fe6 , 0x02a9dfe5 , 0x3541cdf2 , 0x2aec5380 , 0x23482ec3 ] )
+  , Mont::unsafe_make( [ 0x163ee343 , 0x18b636ea , 0x0b972da2 , 0xedad31c5 , 0x3f2ed1fa , 0x202f9901 , 0x8e8e3e31 , 0x2bff6b50 ] )
+  , Mont::unsafe_make( [ 0x890bd992 , 0xb5341429 , 0xc56e16e8 , 0x8f96c5ce , 0x47fab27b , 0xd390d548 , 0x6a9917e1 , 0x12e47a2d ] )
+  , Mont::unsafe_make( [ 0xca149353 , 0x2a1abf55 , 0xf7fbe6b6 , 0xa89e42bc , 0x785cac3d , 0x3d2514f4 , 0x040f33b2 , 0x181af6dc ] )
+  , Mont::unsafe_make( [ 0x0ce26fc0 , 0x9976552b , 0x4b43fc4d , 0xb74ac065 , 0x0b2c7aa8 , 0x1f712bfb , 0x576493e8 , 0x047aee2c ] )
+  , Mont::unsafe_make( [ 0x8e889fc0 , 0x714db37e , 0xe6b43702 , 0xa5710a34 , 0x6516ce35 , 0xa0b2f274 , 0x31d1d498 , 0x2095d2dc ] )
+  , Mont::unsafe_make( [ 0x81f19f14 , 0x606cc0e0 , 0x4770f276 , 0xf6110bb5 , 0x4c7e8f73 , 0x648a11f9 , 0x1b7110aa , 0x1bc5f276 ] )
+  , Mont::unsafe_make( [ 0x39318f09 , 0xe15f6414 , 0xe019fc54 , 0xc3de4c20 , 0xaae7a6eb , 0xa902183d , 0x7e8d8347 , 0x29963d44 ] )
+  , Mont::unsafe_make( [ 0x1ccdcedb , 0xe79b7bb1 , 0xfeefc29c , 0xdae51efa , 0x5951c91a , 0x0139206c , 0xb44a5120 , 0x0954355f ] )
+  , Mont::unsafe_make( [ 0x16f10d9b , 0xc5443f8e , 0xbd005f0e , 0x21b9c3b9 , 0xe7e07369 , 0x48b7f5d9 , 0x3c59d563 , 0x030f5c8e ] )
+  , Mont::unsafe_make( [ 0x6a89a554 , 0x1046a23b , 0x1161b886 , 0x90388a36 , 0x9db58ff4 , 0x8bb6003d , 0xabd4b02c , 0x0f670977 ] )
+  , Mont::unsafe_make( [ 0xa042f318 , 0xc40998da , 0x2714a2fb , 0xda0a9df6 , 0x9c0ed180 , 0xe14d89a8 , 0x6e6169af , 0x179881d4 ] )
+  , Mont::unsafe_make( [ 0xf2496f7c , 0x26bd0bc7 , 0x686fabc6 , 0x35815f4d , 0x988b2243 , 0x106baffb , 0xf8b7fe3f , 0x2261302b ] )
+  , Mont::unsafe_make( [ 0x71e827b2 , 0x77120b08 , 0x3a68e56e , 0xc54c402e , 0x872e5f68 , 0x21eb8f81 , 0x84cbf6ce , 0x084be1cf ] )
+  , Mont::unsafe_make( [ 0x8cdd096f , 0xf4e6911f , 0x71aa1d11 , 0xe4db486f , 0xdeb44f59 , 0xa990d279 , 0x01deeff9 , 0x24d43153 ] )
+  , Mont::unsafe_make( [ 0x3777ebb6 , 0xa28820d4 , 0xb00c615e , 0x5ba47721 , 0x6d4543b4 , 0x5b2e3e98 , 0x85c688be , 0x09ffe9ef ] )
+  , Mont::unsafe_make( [ 0x6eb3db56 , 0x6b6690ab , 0x158bb668 , 0x38db387a , 0xaf9ceb08 , 0xde21ff0d , 0x55411ea4 , 0x1348f44a ] )
+  , Mont::unsafe_make( [ 0xff04e7a5 , 0x999b74c5 , 0xd34ad947 , 0x1679ab7c , 0xef21825d , 0x1436d56f , 0x566e8b6e , 0x23bd69b7 ] )
+  , Mont::unsafe_make( [ 0xb8873c0d , 0x070c4e28 , 0xf150e150 , 0x2a52dee4 , 0x84b46a99 , 0x88117a1e , 0xe176011c , 0x1cb01a4c ] )
+  , Mont::unsafe_make( [ 0x8cf139dc , 0xba48d3df , 0xb3af74f6 , 0xcfdcb536 , 0x15c376b0 , 0x06c09ef2 , 0x2ef06461 , 0x252e7d85 ] )
+  , Mont::unsafe_make( [ 0x638cceb2 , 0x7224ee8d , 0xa35f9d13 , 0x4a6e8bcb , 0xe36b9ad2 , 0x20212cab , 0xcab50318 , 0x20328229 ] )
+  , Mont::unsafe_make( [ 0x5fa662a9 , 0x57f84b4c , 0x4ec0ea8f , 0xe3d3d448 , 0x52adc350 , 0x81c14de3 , 0xb75c4288 , 0x21b7abce ] )
+  , Mont::unsafe_make( [ 0x4e115508 , 0x850821dd , 0x185b28ac , 0x28d422a2 , 0xc1835277 , 0x518e8a5f , 0x5fe84a60 , 0x24cddea1 ] )
+  , Mont::unsafe_make( [ 0xe88e24af , 0x159d1926 , 0x1c20b4be , 0x61a32cac , 0xf528656e , 0x4fba87de , 0x42ab2bfc , 0x2fdeaf18 ] )
+  , Mont::unsafe_make( [ 0x1d9052b2 , 0xc4439860 , 0x98151734 , 0x8eac06b2 , 0x67e3dbb0 , 0x10dd8595 , 0x24d2ee4a , 0x1e61292b ] )
+  , Mont::unsafe_make( [ 0x794eae31 , 0xb4c196a4 , 0x00c0e375 , 0xb5bcaf62 , 0x4a267517 , 0x5a29a589 , 0x07ccac87 , 0x0d2182fb ] )
+  , Mont::unsafe_make( [ 0x33011ee0 , 0xc4c1165d , 0xdfbe9bf5 , 0x92752fae , 0x19a1a6f9 , 0x85390ba7 , 0x5d6dfc3a , 0x08044d23 ] )
+  , Mont::unsafe_make( [ 0x438935ef , 0x438e79e3 , 0xdfbf3c62 , 0xf19c277f , 0xeb9a0bb2 , 0xf4d536e3 , 0x57578a66 , 0x1d7c752a ] )
+  , Mont::unsafe_make( [ 0xeffdd6ce , 0x05a2419f , 0xd5d93590 , 0x1bb2ebd2 , 0x3f3a154d , 0xce2d24c4 , 0x9b26889c , 0x285e4e54 ] )
+  , Mont::unsafe_make( [ 0xe33cfb08 , 0x838cf7c6 , 0x1a9663cd , 0x30fc26ac , 0x6fe6e6a7 , 0x6a6ac639 , 0xa0f1fd26 , 0x0de06fd6 ] )
+  , Mont::unsafe_make( [ 0x352d4d9c , 0xe67a5138 , 0x0496eb2f , 0x131de08c , 0x32f2a80b , 0x52c86ea3 , 0x6e45466f , 0x2ea75297 ] )
+  , Mont::unsafe_make( [ 0xf0002eda , 0x17692e77 , 0xc042a076 , 0xaf95e2e2 , 0x7ca74f8e , 0xab4d12e9 , 0x0466b4eb , 0x0485ab8e ] )
+  , Mont::unsafe_make( [ 0x73594aa7 , 0xb44a8347 , 0x93373ace , 0xa97185e5 , 0x666ec129 , 0x6ad7e3c1 , 0x1580032c , 0x1f6c624c ] )
+  , Mont::unsafe_make( [ 0x9d1e54ec , 0x27da1705 , 0xdecf9fa5 , 0xeb1f9fcf , 0xd90e193c , 0xf371e528 , 0x7e1893ff , 0x2970408b ] )
+  , Mont::unsafe_make( [ 0xebd913cd , 0xaef159d9 , 0xbabdf409 , 0xd33b89ba , 0x5ff5fc04 , 0x53db7125 , 0xc1ed0951 , 0x028ad373 ] )
+  , Mont::unsafe_make( [ 0x7398ff1a , 0x91890b19 , 0x17b88985 , 0xc0338792 , 0xafbbbc39 , 0xcab4a4b9 , 0x125ccf04 , 0x1dd21603 ] )
+  , Mont::unsafe_make( [ 0xe2c7ee66 , 0x118f01f1 , 0xbf3763a9 , 0xee0aa9d8 , 0xa2852169 , 0xc8a3cc0d , 0xc5ca9dca , 0x264942a8 ] )
+  , Mont::unsafe_make( [ 0xeaf9bf4e , 0xb4b42a7c , 0x4981c6ca , 0xd2781170 , 0xea20ccbf , 0xc2c1b1bf , 0xe539af59 , 0x08ff9aa2 ] )
+  , Mont::unsafe_make( [ 0x2f62cc03 , 0xe5e2d6cb , 0xe4f34ea3 , 0x3674a2ba , 0x52a51967 , 0xf9435178 , 0x9d972b05 , 0x26e7f29e ] )
+  , Mont::unsafe_make( [ 0x96f47732 , 0xa23877a5 , 0xd430bfc2 , 0xb297721f , 0xbfb62e98 , 0xc3dee870 , 0xf4c42035 , 0x11d64948 ] )
+  , Mont::unsafe_make( [ 0x73039856 , 0xbb90660e , 0x0c632a02 , 0xff25658d , 0xf081c650 , 0x504b2cbb , 0xe325b75a , 0x2765f2c3 ] )
+  , Mont::unsafe_make( [ 0x4cdb5a01 , 0x3274de08 , 0xc033b097 , 0x93a77a66 , 0x02c87792 , 0xf0b60616 , 0xdb1ffba6 , 0x05f4a20c ] )
+  , Mont::unsafe_make( [ 0x9a6e5b71 , 0xbfe1d83a , 0x91fab063 , 0xf5822674 , 0x46766922 , 0x9ffbdf2f , 0x8792bf76 , 0x06636d48 ] )
+  , Mont::unsafe_make( [ 0x2b1db830 , 0x41c5077c , 0xae9de257 , 0x6de0b5ed , 0x8ead7c8c , 0xcc5a756e , 0xbf28cd0e , 0x16231a3d ] )
+  , Mont::unsafe_make( [ 0x8d7385b0 , 0xf793f43b , 0x92822c7a , 0xc166529b , 0x4f73aa9f , 0xe9104f99 , 0x39e30caa , 0x11ece7c5 ] )
+  , Mont::unsafe_make( [ 0xd4a0a20c , 0xa645d856 , 0xd20d6b2d , 0x0cd8f02c , 0x7b5bc63a , 0x3652ae67 , 0x9d3da0c7 , 0x0e47f83b ] )
+  , Mont::unsafe_make( [ 0xe34a457d , 0x6531a965 , 0x4234a01a , 0xea9fce99 , 0x05c0d3a1 , 0x6b4f9385 , 0xa1ef4811 , 0x0728c268 ] )
+  , Mont::unsafe_make( [ 0xbf9aa02a , 0x065b8069 , 0x1eeb4448 , 0x04c276ce , 0x011099d2 , 0x5744f10c , 0x0569c582 , 0x23d89c31 ] )
+  , Mont::unsafe_make( [ 0xd5f7c04f , 0xf8ddfdb2 , 0x4ff6b671 , 0xe32b084c , 0xb8636651 , 0xe5ce4fb7 , 0x2f1957fc , 0x1d4d9b1a ] )
+  , Mont::unsafe_make( [ 0xaf2c61c2 , 0x98ac52db , 0x8837a26f , 0x62fdc86b , 0xc550a5ba , 0xd535aa2c , 0x01cf6fd2 , 0x0900bbff ] )
+  , Mont::unsafe_make( [ 0x90d9df11 , 0xbb6e8dbf , 0x57d8da23 , 0x149171f5 , 0x13fee2bb , 0xbd60db20 , 0xb79ef538 , 0x1593ea2f ] )
+  , Mont::unsafe_make( [ 0x74cd0108 , 0x3deb8ece , 0x4a554c0b , 0xcb2f5888 , 0x10b2d96f , 0xb706684c , 0x019c54cd , 0x27bceba4 ] )
+  , Mont::unsafe_make( [ 0x07fe1be0 , 0x2015604d , 0xdebd0940 , 0x103af32f , 0xcca96350 , 0xec0bb359 , 0x4e7b5b33 , 0x21423a37 ] )
+  , Mont::unsafe_make( [ 0x8a57fc58 , 0xdda43038 , 0x73b0ac72 , 0x4ea6f2bd , 0x3e141f94 , 0x049782ba , 0x4e90179a , 0x1c435c04 ] )
+  , Mont::unsafe_make( [ 0xb886de57 , 0x92232ca3 , 0x651d659b , 0x4f4637c5 , 0xcc4494a3 , 0x5c03a23c , 0x0d1b98d1 , 0x0b7658fa ] )
+  , Mont::unsafe_make( [ 0xf568e9b8 , 0xd9ebcb5a , 0x6f9054a9 , 0x72718de5 , 0x34b1ea2c , 0x0a73ebc9 , 0x21136c87 , 0x1396a244 ] )
+  , Mont::unsafe_make( [ 0x1296e74e , 0x27420659 , 0xbd808850 , 0xe67315d6 , 0x7b40b557 , 0xd2a4d04c , 0xa62ef4ed , 0x29ffe188 ] )
+  , Mont::unsafe_make( [ 0x38893d2e , 0xe2d6f310 , 0xedf40502 , 0x4cc1ddba , 0xb9490bc0 , 0xfc297883 , 0x3e144cd0 , 0x07ca153b ] )
+  , Mont::unsafe_make( [ 0x89b80c7b , 0x3e75fea1 , 0xf5feec19 , 0xd3edaf3b , 0x787f643e , 0xaf59632a , 0xe93ab0f1 , 0x168e25c0 ] )
+  , Mont::unsafe_make( [ 0x659b9d9b , 0xbb35948c , 0xe62a033a , 0xc831530e , 0x4debce80 , 0xf6cc500d , 0xa98d873c , 0x0dfeb16f ] )
+  , Mont::unsafe_make( [ 0x5068f2a8 , 0x9d0baa4b , 0x4df783c5 , 0xb004b53b , 0x25aa2a70 , 0xc9340a6a , 0x4fdcd32f , 0x1002551a ] )
+  , Mont::unsafe_make( [ 0x129a62b3 , 0x501a8cb9 , 0xb6c33f64 , 0x6eca71ca , 0xcbf5c0b4 , 0x3b3bae14 , 0x7fe2fd00 , 0x2f38970f ] )
+  , Mont::unsafe_make( [ 0x823bada5 , 0x27a31d64 , 0x09d71c65 , 0xa2c3ab1f , 0xe2175ba5 , 0x4ad08d27 , 0x94221d33 , 0x27585645 ] )
+  , Mont::unsafe_make( [ 0xf593a9d7 , 0x42581d53 , 0x0f93f2f7 , 0x677382f8 , 0x3b5fe94f , 0x0e635fc5 , 0xbddc0173 , 0x0191916f ] )
+  , Mont::unsafe_make( [ 0xcb5d770d , 0xb346b226 , 0xb5747e8f , 0x43610b56 , 0xd748a1c1 , 0x1f4c2bcf , 0xdbb2dfb9 , 0x1c57368f ] )
+  , Mont::unsafe_make( [ 0x28c34413 , 0x5b42be06 , 0x15c45975 , 0x295b1661 , 0x63f70437 , 0x59146b0a , 0xdbacf0dc , 0x2c5d144d ] )
+  , Mont::unsafe_make( [ 0x0d5324c5 , 0x898218ed , 0x756b94e0 , 0xa58fa7f6 , 0xcc031c73 , 0x268ff0a1 , 0x67d0f691 , 0x06534f1c ] )
+  , Mont::unsafe_make( [ 0x8fb50bf6 , 0xa30aa6c0 , 0x253853ae , 0xf2a8b9e5 , 0x1925b629 , 0x5e75aaf2 , 0x3a144fc4 , 0x292ce518 ] )
+  , Mont::unsafe_make( [ 0xa138ce72 , 0x0f0a9a38 , 0x0f390f4d , 0xfd2733e7 , 0x75c7a19a , 0xc4e1c949 , 0x507ca854 , 0x0b7bd2a7 ] )
+  , Mont::unsafe_make( [ 0x18b686aa , 0xdde50135 , 0x86768d66 , 0x8a2133d9 , 0xcc84b063 , 0x403c6baa , 0x77228de7 , 0x0c1fda5c ] )
+  , Mont::unsafe_make( [ 0x08b48e5f , 0x9b6dd563 , 0x6591936d , 0xfec58adc , 0x86b51aed , 0x066ddeed , 0xe0b53dd5 , 0x0d9cd5ab ] )
+  , Mont::unsafe_make( [ 0xb153e5c0 , 0x5df3f3a9 , 0x30220052 , 0x35a8107b , 0xc6f98491 , 0x6c20f45e , 0xe02719b0 , 0x050d6d38 ] )
+  , Mont::unsafe_make( [ 0x8e32f60e , 0xc2f5c2be , 0xc2ec18b4 , 0x150c40d5 , 0x21770f18 , 0x12d7e2e8 , 0xa0c22320 , 0x25097cc6 ] )
+  , Mont::unsafe_make( [ 0xda6287cb , 0x5b54626a , 0xdd6df8a5 , 0xa8e4b974 , 0xc0e4f70e , 0x1811ed3c , 0xf615f6f2 , 0x0d4f1150 ] )
+  , Mont::unsafe_make( [ 0xd5923e46 , 0x50b9fff5 , 0x6c261073 , 0xb6105c7f , 0x36f163e8 , 0x2b715eda , 0x91c0fdd5 , 0x0727e585 ] )
+  , Mont::unsafe_make( [ 0x73c033ad , 0xbdd1a003 , 0x8469a34e , 0xf4311afe , 0x72b7f4d6 , 0x3bd33728 , 0x97ff8adf , 0x06e0df0e ] )
+  , Mont::unsafe_make( [ 0x07cd5c2f , 0xb08e32cc , 0x355d8f25 , 0x8210e42a , 0xb89f816d , 0x5f08c324 , 0xde31b93e , 0x2b930cd3 ] )
+  , Mont::unsafe_make( [ 0xea918180 , 0xa270f22b , 0x3cb9c2f1 , 0x0a347847 , 0x19ab5a17 , 0xf491e56a , 0x36816996 , 0x224ad2ba ] )
+  , Mont::unsafe_make( [ 0x025b218b , 0xb0efe123 , 0x468c3374 , 0x72f8769b , 0xd8d0f062 , 0x2c810033 , 0x5948ac2c , 0x27233587 ] )
+  , Mont::unsafe_make( [ 0x708ba636 , 0xcaef5446 , 0xf937fbdd , 0xd5d24f6d , 0x80f6a784 , 0xf99b6fa4 , 0x948b256d , 0x1db1fb88 ] )
+  , Mont::unsafe_make( [ 0xac6a8019 , 0xdcaecdf0 , 0x58590cb6 , 0x07faacbe , 0xa03a72fa , 0xa9e18c16 , 0xe23e196e , 0x0be502ff ] )
+  , Mont::unsafe_make( [ 0x8544e513 , 0xb95d0193 , 0x99b19a75 , 0x26a63e60 , 0xd3b17daa , 0xe3e1e99a , 0x929decfa , 0x025d97b6 ] )
+  , Mont::unsafe_make( [ 0x2ab0a4bd , 0xebb78f10 , 0xddf13c0e , 0x4d898e39 , 0x3d195970 , 0x591b52ff , 0xd53d1eb3 , 0x1f4426df ] )
+  , Mont::unsafe_make( [ 0xd9cc5401 , 0xebbd132b , 0xb09027fa , 0x22fe9225 , 0x5722ea34 , 0xc24f55fa , 0xa5f88ac6 , 0x27c37808 ] )
+  , Mont::unsafe_make( [ 0x7ee8c431 , 0x96a407c2 , 0x3aac5edb , 0xf915ee7d , 0x49d0741d , 0xb1941cad , 0xf5070aad , 0x262d2def ] )
+  , Mont::unsafe_make( [ 0x58ff1ee9 , 0x2d0b7be0 , 0x2950d2c4 , 0x63fa641e , 0x5970d777 , 0x5037296b , 0xa62f3ef9 , 0x0317b182 ] )
+  , Mont::unsafe_make( [ 0xb3ea7097 , 0xde21dcf0 , 0x3e33db83 , 0x05847368 , 0x4c1aee57 , 0x9a7e1abc , 0x1eb87575 , 0x21f70c28 ] )
+  , Mont::unsafe_make( [ 0x4354945e , 0xb9445b7c , 0x3aff1535 , 0x3a422039 , 0x5a4c4688 , 0x8e2a8aa7 , 0x5ee66e53 , 0x2bf9d825 ] )
+  , Mont::unsafe_make( [ 0xfb41fae6 , 0xe5c568c6 , 0x19af1b6e , 0xcbd1d009 , 0xa5b8bf59 , 0x2cf81faf , 0x4ce6dd12 , 0x154521a3 ] )
+  , Mont::unsafe_make( [ 0xf4588b9c , 0xc6389eb7 , 0xbb1ad1b1 , 0x96fdbcc3 , 0x8a1ab823 , 0x89951732 , 0x7d6a39b4 , 0x01063b15 ] )
+  , Mont::unsafe_make( [ 0xdc1112d2 , 0x8a3b8248 , 0xf2f3db82 , 0x579a30fd , 0x9edc7872 , 0x954abb89 , 0x98d041d9 , 0x1da13d47 ] )
+  , Mont::unsafe_make( [ 0xd91bb41a , 0x97f007b6 , 0x79a42f18 , 0xbd891613 , 0x2851f983 , 0x4cb8bb12 , 0x32de506e , 0x01615fbc ] )
+  , Mont::unsafe_make( [ 0x40492887 , 0xd2f00706 , 0xfb2893ae , 0x235dd8e4 , 0xe225e913 , 0xa43dd7b5 , 0xfe12b522 , 0x2d15bd8f ] )
+  , Mont::unsafe_make( [ 0xa1a82aac , 0xf3efad81 , 0xd6c7b57b , 0x4efe522f , 0x20c74dbd , 0x65925d60 , 0x0f8ed3df , 0x1e955f6e ] )
+  , Mont::unsafe_make( [ 0x132ebe82 , 0x59b988e0 , 0x8da41aab , 0xa21c269c , 0x9dae7543 , 0x53a0f34c , 0x821efdd3 , 0x13381173 ] )
+  , Mont::unsafe_make( [ 0x9d825368 , 0xbb30ccf4 , 0x67518289 , 0x61ffd918 , 0xaa909627 , 0xccc3f61c , 0x0c783af5 , 0x072a448e ] )
+  , Mont::unsafe_make( [ 0xcefde1b4 , 0xbf765b8f , 0xb15a9e3d , 0x8ca6b494 , 0x2b8b27ef , 0xc90b5a6e , 0x20a2cf0a , 0x2ffd121e ] )
+  , Mont::unsafe_make( [ 0x23d5699d , 0xf5d0456f , 0x8b6fd583 , 0x2c76d793 , 0x7b146c57 , 0x4dc12786 , 0xf31bf8f7 , 0x2b07d906 ] )
+  , Mont::unsafe_make( [ 0x98b77838 , 0xae086244 , 0xaa97109d , 0xe6fdddb5 , 0x0ef117ef , 0x3cb3ceee , 0x2ec1e21d , 0x18a100bc ] )
+  , Mont::unsafe_make( [ 0x9c50c037 , 0x254c5757 , 0xa358512c , 0xd5049b54 , 0xee7d4ae4 , 0xcb205596 , 0xdaa08ed3 , 0x090f074e ] )
+  , Mont::unsafe_make( [ 0x3dd659db , 0x3b855b95 , 0xac9285b0 , 0xdb293bde , 0x4a97d316 , 0x68b15517 , 0xaa1ab659 , 0x1b3945bd ] )
+  , Mont::unsafe_make( [ 0x12742da0 , 0x227989d2 , 0x32631db5 , 0xed6c65d2 , 0xa2f06c3e , 0xf53ff4be , 0xa8fcddd0 , 0x14948ff2 ] )
+  , Mont::unsafe_make( [ 0x7a256a90 , 0x16ea9420 , 0xa955f21f , 0xfdaa8c76 , 0x9338c978 , 0xc4c98d85 , 0xa08ffc35 , 0x08026726 ] )
+  , Mont::unsafe_make( [ 0xf165a723 , 0x201ce7ea , 0xc42488fb , 0x57fb1481 , 0x153f1dd6 , 0xe11b3767 , 0xb9a0beb1 , 0x18334658 ] )
+  , Mont::unsafe_make( [ 0xcd12dcbe , 0x0217a814 , 0x1fa2eb59 , 0x6a237d43 , 0x1ec4e336 , 0xa1ba4ba7 , 0x6a42464e , 0x106fb116 ] )
+  , Mont::unsafe_make( [ 0xf3594eee , 0xaa3e07b8 , 0x918d4418 , 0x15fb2462 , 0xf41ab436 , 0x85da0f51 , 0x5cb6accb , 0x255ea082 ] )
+  , Mont::unsafe_make( [ 0x4dc74f1c , 0xfa92bc88 , 0x4ae69533 , 0xf3f294c2 , 0xa14e3667 , 0x27ff5ac4 , 0x1452ebc4 , 0x0653775b ] )
+  , Mont::unsafe_make( [ 0x6b63d31d , 0xbce5a539 , 0xd7d43e12 , 0xfb2fa2f1 , 0x5ec132ac , 0xa1bd5da3 , 0x8462a8be , 0x204fd421 ] )
+  , Mont::unsafe_make( [ 0x4d401596 , 0xcd1fb3c7 , 0xb4750636 , 0x6a78f276 , 0x254a65c2 , 0xa52b1e94 , 0x7dcbb0bc , 0x102b4794 ] )
+  , Mont::unsafe_make( [ 0x4c593eb4 , 0x266640ee , 0x66255be0 , 0xb61c3f6e , 0xbc29dcb3 , 0x32cfb2ec , 0xd068f287 , 0x02c2c5ee ] )
+  , Mont::unsafe_make( [ 0x7b2bfe62 , 0x84e75786 , 0xfcc2eab6 , 0x96422ac6 , 0x3585b35b , 0x8499e858 , 0x72e29b26 , 0x22d5a6d2 ] )
+  , Mont::unsafe_make( [ 0x8c458dbd , 0x00c9f843 , 0x2e5f129b , 0xcfdd5068 , 0x8c22987b , 0x974b1720 , 0xcd456a47 , 0x070c145a ] )
+  , Mont::unsafe_make( [ 0x4452da3a , 0x60500a97 , 0x17b71d95 , 0x2c0409b5 , 0x2eeb8bfa , 0x394e62c7 , 0xec6df337 , 0x27c739ee ] )
+  , Mont::unsafe_make( [ 0x809cc5e6 , 0x6308664f , 0xff94ac01 , 0x4dc34320 , 0x726e6a00 , 0x79cd9259 , 0xea741119 , 0x2c02d127 ] )
+  , Mont::unsafe_make( [ 0x71fb8245 , 0x2d73a1b6 , 0x18916687 , 0x7b9ed396 , 0x4eae4a8e , 0x7108847a , 0x93bcbc5f , 0x037dcd5b ] )
+  , Mont::unsafe_make( [ 0x20379583 , 0x2c0f3d09 , 0x7b35213a , 0x9753aedf , 0x98d772f9 , 0x94e8f327 , 0x605e61c2 , 0x253292db ] )
+  , Mont::unsafe_make( [ 0xa21ffa50 , 0xc5912904 , 0x012bf6cc , 0xfccba577 , 0x4d9070b4 , 0x0cd0c0c8 , 0x6e9bec54 , 0x2757a1b8 ] )
+  , Mont::unsafe_make( [ 0x40c00686 , 0x7a2ce048 , 0x421b563d , 0xb05deeff , 0xbcd6b35f , 0xdd41e9d3 , 0xf7f6b571 , 0x119c151a ] )
+  , Mont::unsafe_make( [ 0x2bddd81c , 0xaa3918ad , 0x7a53507c , 0xec06b4fc , 0x1204b049 , 0x2377d160 , 0x236d8ad5 , 0x0f767769 ] )
+  , Mont::unsafe_make( [ 0x7a373800 , 0x59db0d72 , 0xf9c6900f , 0x1ecb631f , 0x9aff07b8 , 0xd75d6694 , 0x9dfa53c2 , 0x10316854 ] )
+  , Mont::unsafe_make( [ 0xf71223e2 , 0x2935d997 , 0x927f7068 , 0xc852557c , 0x985b3c42 , 0x72847084 , 0x6d4b9efd , 0x0708089e ] )
+  , Mont::unsafe_make( [ 0xa3c60704 , 0x1f079bcd , 0xb901ac20 , 0x0d2de389 , 0xc0f69202 , 0x76c811d4 , 0x20602984 , 0x13cc38a4 ] )
+  , Mont::unsafe_make( [ 0xcd665333 , 0x76722142 , 0xac0512de , 0x734c6507 , 0x44921724 , 0xe51a9d21 , 0x4168a008 , 0x0a0f7546 ] )
+  , Mont::unsafe_make( [ 0x35ab5e4d , 0x37b74db1 , 0x07567abf , 0x81237ba4 , 0x3b7c50fb , 0xf4b53561 , 0x9811d938 , 0x0da812e8 ] )
+  , Mont::unsafe_make( [ 0x8916b32d , 0xaa6647bf , 0x2bd041d4 , 0xfc31877f , 0x424db96f , 0x7da6de1e , 0xc3bd4a30 , 0x14e13e62 ] )
+  , Mont::unsafe_make( [ 0x0a327793 , 0x3175828a , 0x0e2e20ef , 0xfba32836 , 0x37b6e399 , 0x242e42da , 0x04105731 , 0x10b51ea6 ] )
+  , Mont::unsafe_make( [ 0xf1895a1a , 0x22ea5100 , 0x547e557a , 0x0ba6b835 , 0xae77db82 , 0x35f2dd98 , 0x4138d064 , 0x052be890 ] )
+  , Mont::unsafe_make( [ 0x4150e2e6 , 0x9e1fecda , 0x1d762eeb , 0x4eb9be0e , 0x7227b3b6 , 0x9ecd3383 , 0xb388216b , 0x20f2e01b ] )
+  , Mont::unsafe_make( [ 0x5ce86467 , 0x3af7bd3d , 0xc740fcb7 , 0x6453c346 , 0x8ef787aa , 0x727c5607 , 0x8ac6ace4 , 0x1692582b ] )
+  , Mont::unsafe_make( [ 0x5c3bf797 , 0xa09f37e8 , 0x44419def , 0xe46a0166 , 0x5edbcb94 , 0x14ff0d6d , 0x4b4b7e46 , 0x005fa4ee ] )
+  , Mont::unsafe_make( [ 0x9733704b , 0xfabc1dc4 , 0x35d99a40 , 0x03323add , 0x371177b1 , 0xbc57fb3a , 0x5749929e , 0x28ef4b05 ] )
+  , Mont::unsafe_make( [ 0xa2aff8c5 , 0xd379669b , 0x893823c4 , 0x61580b25 , 0xf0b3aa5e , 0xf197660d , 0xfcec18d0 , 0x0a7f988d ] )
+  , Mont::unsafe_make( [ 0xe7d97c27 , 0x9a51d661 , 0x37ff1f1e , 0x6c7d4b60 , 0x30f46848 , 0x47a6fe78 , 0xd4363356 , 0x26915a4c ] )
+  , Mont::unsafe_make( [ 0x3af1ac32 , 0x12b9df9f , 0x138f317f , 0x28c336fa , 0xf8cc9c42 , 0xb5c6c501 , 0xf6c951b7 , 0x02f7329c ] )
+  , Mont::unsafe_make( [ 0x19cddc30 , 0x47923fef , 0x5b22a299 , 0xcd2f0ace , 0x1d7d31d9 , 0x56f0ead6 , 0x1069e3cb , 0x20b06903 ] )
+  , Mont::unsafe_make( [ 0xc97e3059 , 0x0167ce0c , 0x0c22b178 , 0x603d4761 , 0x52385b4b , 0xd039373e , 0x9b1d7c21 , 0x1b87d35b ] )
+  , Mont::unsafe_make( [ 0xb98574b8 , 0x21c61ba9 , 0x0eb974ca , 0x552b5351 , 0x1187af3c , 0xb6aacd8d , 0xa9193173 , 0x1431a70f ] )
+  , Mont::unsafe_make( [ 0x0f896626 , 0xc5ab7c8f , 0xdd80041f , 0xefee2732 , 0xc0b30b83 , 0xc1f764a9 , 0x64441f00 , 0x1014cd9f ] )
+  , Mont::unsafe_make( [ 0x11448b57 , 0x5b979956 , 0x9f929745 , 0x37a7dc56 , 0x4a926e04 , 0x5387a49e , 0xf2783dd5 , 0x1f128b6b ] )
+  , Mont::unsafe_make( [ 0xa4f8b71d , 0xc30497c9 , 0x28642d88 , 0xac2d3c3a , 0x5905e5d9 , 0x074bc595 , 0xf6778dbe , 0x27f089b8 ] )
+  , Mont::unsafe_make( [ 0x415e531c , 0xc2b3a7ad , 0x52702c35 , 0xb9ca6369 , 0xb5cdc970 , 0xce8c3498 , 0x534c19b6 , 0x0f1548df ] )
+  , Mont::unsafe_make( [ 0x3b539275 , 0xe7d24fcc , 0x66797589 , 0xbb3ef436 , 0x61bbb49e , 0xdb6e7b5d , 0x9185e002 , 0x2ae537d5 ] )
+  , Mont::unsafe_make( [ 0x3225ebd0 , 0x3edeae93 , 0x51cdbf1e , 0xf5cf3edf , 0x41f6b9b6 , 0x6e45a36b , 0xae41c74d , 0x0902958e ] )
+  , Mont::unsafe_make( [ 0xd1646863 , 0xb3113bdc , 0x0786f921 , 0xcddb8556 , 0xecc43152 , 0x309be2c2 , 0x16dd7af2 , 0x0552fbb1 ] )
+  , Mont::unsafe_make( [ 0x9ab9e21c , 0x27f67eaf , 0x8cae9edf , 0xaabab483 , 0x1ca41d86 , 0xd110d77f , 0x8680d43f , 0x13258b29 ] )
+  , Mont::unsafe_make( [ 0x25667fb5 , 0xff65efc5 , 0xbaacb74a , 0xabddc413 , 0x01515e3d , 0xe92b58c0 , 0xdf9213db , 0x0f11bd53 ] )
+  , Mont::unsafe_make( [ 0x17a2ed48 , 0x7ac4325c , 0xb8f3adec , 0x918c3f14 , 0xe7015709 , 0xe18755fd , 0xb527c046 , 0x19c6a843 ] )
+  , Mont::unsafe_make( [ 0x3cd5dbc2 , 0x052a2fbd , 0xd9e3ed5a , 0x3b295119 , 0xd7bfcb26 , 0xd36a1dbc , 0x9b73c18f , 0x11621e4f ] )
+  , Mont::unsafe_make( [ 0x340fbb09 , 0x12e6b301 , 0xdbf5ca57 , 0xa99c52e5 , 0x1922ab14 , 0x9669ee40 , 0x36a94d54 , 0x143b7753 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// external mixing matrix (`t x t`, flattened)
+pub const CONST_M: [Mont; 144] = 
+  [ Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xde84f76a , 0x75542d43 , 0xc48f6126 , 0x26dff199 , 0x67dee28e , 0x4fe8e1e3 , 0x05e643eb , 0x2b1be9b5 ] )
+  , Mont::unsafe_make( [ 0x4b9cd1be , 0xc9dcf8e6 , 0xbb5dde16 , 0xd570b765 , 0x10d783a1 , 0x07ecbd03 , 0x8f766d17 , 0x1f985854 ] )
+  , Mont::unsafe_make( [ 0xf2e49cf6 , 0x273cb309 , 0x498dc284 , 0x0278160b , 0x9f1c04cd , 0xdf91ae37 , 0xf9b22fba , 0x17488694 ] )
+  , Mont::unsafe_make( [ 0xf421756a , 0xbe584c0d , 0x8ee9c150 , 0x549f813d , 0xf62638f1 , 0x151c8a36 , 0x75c55a52 , 0x165d60e6 ] )
+  , Mont::unsafe_make( [ 0x5f7ee48d , 0x59bfecd4 , 0x6c7212eb , 0xddcd3a15 , 0x59e25e7c , 0x3504ff52 , 0x036ef6e3 , 0x1ffb6fbe ] )
+  , Mont::unsafe_make( [ 0xbee933df , 0x85673300 , 0xef37bbbe , 0xc95fbc10 , 0x0cccc415 , 0x79035de6 , 0x476bc15a , 0x0fcea4af ] )
+  , Mont::unsafe_make( [ 0x5978dcf6 , 0xd6500337 , 0x5b987783 , 0x84ffca04 , 0x78d64ec2 , 0x1cd76824 , 0x16e077cc , 0x0e8c9e8e ] )
+  , Mont::unsafe_make( [ 0x375d37ab , 0x660034f2 , 0xbc8d8e00 , 0xc37c9ca8 , 0xae758fa4 , 0x03fbaca4 , 0x4c5a18ec , 0x0927245d ] )
+  , Mont::unsafe_make( [ 0x755d557c , 0x79480f68 , 0x8c8d8611 , 0xd524c362 , 0xa313d030 , 0x1136d8b8 , 0x38454f2b , 0x2127edf6 ] )
+  , Mont::unsafe_make( [ 0x2fd3ae59 , 0xc1bb7b5d , 0xb2e4f9f1 , 0xfb50f2c6 , 0x9816fe94 , 0x0a75acad , 0x4f32c9a0 , 0x1b451b03 ] )
+  , Mont::unsafe_make( [ 0xbfe8d129 , 0x6880f298 , 0x7084e50d , 0x90acc76d , 0x95c64b10 , 0xe202fd19 , 0x34bde382 , 0x16055ece ] )
+  , Mont::unsafe_make( [ 0x257659b8 , 0x1cfadf5b , 0x6e6ba845 , 0xdcda1b1c , 0x82c499b4 , 0xe5a6676c , 0xb5b040f7 , 0x23001c50 ] )
+  , Mont::unsafe_make( [ 0x256f5aec , 0x0683b296 , 0x4124f057 , 0xd7b2d3b5 , 0x76fbc9f5 , 0xde3b32a3 , 0x64424498 , 0x073bf71e ] )
+  , Mont::unsafe_make( [ 0x57054540 , 0x081dced2 , 0xa0776ec2 , 0x7faceb29 , 0x4803a9e9 , 0x595c02bf , 0x19c73019 , 0x0757d84a ] )
+  , Mont::unsafe_make( [ 0x91350e82 , 0x771ecb95 , 0x60a1036c , 0x67a7d271 , 0x7c050a41 , 0x982318bf , 0xad6c50f9 , 0x21f41496 ] )
+  , Mont::unsafe_make( [ 0x4699291f , 0xcbb4364b , 0xd9cdbadd , 0x245ef687 , 0x65b93936 , 0xaf7db5f1 , 0xdd825253 , 0x10a81acb ] )
+  , Mont::unsafe_make( [ 0xf58be7f6 , 0xc0cfc903 , 0x461ebfe1 , 0x2f10970d , 0xd68cc430 , 0x3cab580f , 0x27be3dfa , 0x118a71da ] )
+  , Mont::unsafe_make( [ 0x9534ff38 , 0x64d9acef , 0xc7332c4f , 0xd640734e , 0x36d0b196 , 0x4d2d68ed , 0x6980d1a7 , 0x1ce16906 ] )
+  , Mont::unsafe_make( [ 0x5ddb89a0 , 0xe59024cf , 0x69f9702e , 0x5933b843 , 0xe7b81b5e , 0xe3679fcb , 0xebf2895c , 0x2cd81cf4 ] )
+  , Mont::unsafe_make( [ 0xc0fbd9c3 , 0x0ad3c548 , 0x4da5a427 , 0x4c40a2ef , 0x636f0ff8 , 0x5d972a0e , 0x1e75a15e , 0x0d64c7d7 ] )
+  , Mont::unsafe_make( [ 0xe36a0a62 , 0xe4994d5a , 0x08b8bc6f , 0xbbebde32 , 0x96b7bf3f , 0x0b61167e , 0xf216da99 , 0x187b9d1e ] )
+  , Mont::unsafe_make( [ 0x600176f0 , 0x623db34b , 0xc981f094 , 0xc7eac88e , 0xa0d0719b , 0x93dafcb3 , 0x89ddada0 , 0x170beefe ] )
+  , Mont::unsafe_make( [ 0x07b9e1d2 , 0x8ebc7ce9 , 0x47f3ea10 , 0x947c42ed , 0x00f9804c , 0x5d298e15 , 0x8811d078 , 0x09477dea ] )
+  , Mont::unsafe_make( [ 0x63dc2a4f , 0x10ec045d , 0x5d9c9681 , 0x9439bdd0 , 0x31358ff7 , 0x4b3d0376 , 0x173078cf , 0x174afa6d ] )
+  , Mont::unsafe_make( [ 0x6060bf8b , 0x65f2187b , 0x806b85c1 , 0x71800745 , 0x113a8d7f , 0x3a04e475 , 0xa4ba676c , 0x1b82ff9b ] )
+  , Mont::unsafe_make( [ 0x44e9c7a7 , 0xdf81ad1c , 0xcd2a8af1 , 0xe510b20a , 0x0ba9066a , 0xf0eaf4f6 , 0x6f50b550 , 0x2bd9c407 ] )
+  , Mont::unsafe_make( [ 0x212bd96c , 0xf5ad9f36 , 0x91dcb10b , 0x9aef24f8 , 0xc7ccfa63 , 0x5d9a174a , 0x23011b61 , 0x29ac4cf0 ] )
+  , Mont::unsafe_make( [ 0x0f5ed482 , 0x75caf5e4 , 0xf80263e1 , 0x21744d88 , 0x08518c1b , 0x1c4b0e04 , 0xed389e58 , 0x0972139a ] )
+  , Mont::unsafe_make( [ 0xac57e456 , 0x7fe2197e , 0x67d7dd4e , 0x1542a11b , 0x281449aa , 0x701d56e4 , 0x6f6a6e03 , 0x07d36901 ] )
+  , Mont::unsafe_make( [ 0x488fc004 , 0x99fd27db , 0xaa2952ff , 0x7fa4eb46 , 0x1eff34a9 , 0xd0e57d3d , 0x03bf4aa6 , 0x2df898ea ] )
+  , Mont::unsafe_make( [ 0x93410b9d , 0x4761c18d , 0xdb8931c8 , 0xba796354 , 0x74132950 , 0x724ecafb , 0x05bb13a6 , 0x2e840d47 ] )
+  , Mont::unsafe_make( [ 0xf46f59f3 , 0x3bc4d4bf , 0x7aa89449 , 0xfc9dab4b , 0xcea0fdc0 , 0xb310d1d2 , 0x05516c79 , 0x2dd12bce ] )
+  , Mont::unsafe_make( [ 0xb6769ed9 , 0x399e204b , 0x79fc37d8 , 0x778f69ae , 0x3e2407af , 0xd252ed19 , 0xb9201e3b , 0x03f73793 ] )
+  , Mont::unsafe_make( [ 0xe3e500a1 , 0x54bbcc0a , 0xf9492dac , 0xd77622a9 , 0x7608fbb5 , 0x226be8b1 , 0x9b362a39 , 0x2aaac56a ] )
+  , Mont::unsafe_make( [ 0x7082ecb2 , 0x48acc79c , 0xc1f4db12 , 0x90d35f80 , 0xbc1c9114 , 0x239164ed , 0x126c7550 , 0x0f28d5c4 ] )
+  , Mont::unsafe_make( [ 0x04731915 , 0xa654a342 , 0x4dce98bf , 0xd7b92642 , 0x55369ec5 , 0xc772dbd6 , 0x75fed3b0 , 0x198dd1ad ] )
+  , Mont::unsafe_make( [ 0xbf622ccf , 0x1bcf5007 , 0x1321e76b , 0x6522ee2a , 0xeed6774f , 0x52b4a62f , 0x38edbe2e , 0x20079d75 ] )
+  , Mont::unsafe_make( [ 0x15d54bef , 0xa3d24d80 , 0x42f747eb , 0x63b929b0 , 0x59e992e9 , 0x86fe149b , 0x2c419ed5 , 0x1db6d9c9 ] )
+  , Mont::unsafe_make( [ 0xa97983b1 , 0x5a3a8189 , 0xa2ed33ce , 0x232d3d4f , 0x1f247f16 , 0x446a0c8e , 0x0f9e0910 , 0x21ada142 ] )
+  , Mont::unsafe_make( [ 0x32c5a242 , 0x73721cc1 , 0xc8ab6204 , 0xb47fc9a3 , 0xe4c40307 , 0xe30c4cde , 0x8b1f543d , 0x1a4f7c7c ] )
+  , Mont::unsafe_make( [ 0x0540e6ba , 0x784cede6 , 0xf824eaf0 , 0xa04a2940 , 0x3eece8ad , 0x1acb043e , 0x1b58d9d2 , 0x0b5c454f ] )
+  , Mont::unsafe_make( [ 0xe59af8ee , 0x1851298c , 0x05fb5b1e , 0x922946c5 , 0xe71263eb , 0x530db75d , 0x52fc4df8 , 0x2e21e1ff ] )
+  , Mont::unsafe_make( [ 0x3154eeac , 0x90604889 , 0x02a5b581 , 0x8d2352cb , 0xce2e3340 , 0x0af2bf05 , 0x82d80650 , 0x28056a82 ] )
+  , Mont::unsafe_make( [ 0x8bc68a10 , 0x6e166e7d , 0x7b1a2aa0 , 0x45d0894c , 0x50391fec , 0xe538ec38 , 0xd5ef31f0 , 0x2fed118a ] )
+  , Mont::unsafe_make( [ 0x481e9ea6 , 0xc00efb64 , 0x6cd6594f , 0xf75f22fe , 0x1b28f8ff , 0x1b27fcd3 , 0xd886eef4 , 0x141c4b5b ] )
+  , Mont::unsafe_make( [ 0x74e92e1d , 0x95e35fa2 , 0xf798cc68 , 0xac390bc1 , 0x86e3a1d1 , 0xa07e5b87 , 0x62aa2a31 , 0x1f987c0f ] )
+  , Mont::unsafe_make( [ 0x89a08ab4 , 0x6761a0c7 , 0xf89f1ea5 , 0xd89391f2 , 0xd02f76c5 , 0xf380f582 , 0xc8574850 , 0x14e4412b ] )
+  , Mont::unsafe_make( [ 0x45461ac0 , 0x247529f6 , 0xfd11b3e7 , 0x8a126c0a , 0xe4e66ed4 , 0x3756198d , 0xbcc19d6c , 0x06705349 ] )
+  , Mont::unsafe_make( [ 0x53ad3d3a , 0x385d31dc , 0x8d462674 , 0x3313aaf9 , 0xb8754df4 , 0x8b84982c , 0x41baa33a , 0x2a188509 ] )
+  , Mont::unsafe_make( [ 0x6652a540 , 0x8d737364 , 0x48353494 , 0x93e4670a , 0x456f68c9 , 0x18ab7652 , 0xbacb196c , 0x0add6e87 ] )
+  , Mont::unsafe_make( [ 0xf6a5bc9d , 0xd70bd27a , 0x1b64e2e3 , 0xfe26576c , 0x4a904683 , 0xbae01381 , 0xc44aee15 , 0x13d922b3 ] )
+  , Mont::unsafe_make( [ 0xc6a4f17f , 0xecf7d4a3 , 0xf425691b , 0xe0b5409f , 0xb51518cb , 0x30197c56 , 0x5f9b87bb , 0x12babe2c ] )
+  , Mont::unsafe_make( [ 0xd87aabfd , 0xf9e1cd2c , 0x42ef29da , 0xcceaa3ef , 0x8b8df357 , 0x5d9c3577 , 0xd3a2de7b , 0x05071e21 ] )
+  , Mont::unsafe_make( [ 0x638c4de3 , 0x9e979a21 , 0x87d94e8e , 0xa4e7f2f6 , 0xe24cde8e , 0x9a2777fc , 0x904c47b9 , 0x00fac977 ] )
+  , Mont::unsafe_make( [ 0x9ebe715b , 0x9af2b253 , 0x37d1e2a5 , 0x86a7b050 , 0x16b36f94 , 0xad3834ba , 0xd0e7d889 , 0x281ab387 ] )
+  , Mont::unsafe_make( [ 0x3dca5c7f , 0xddc077cf , 0x323c9d80 , 0xdd1d29c6 , 0x23d6b1d4 , 0x2981bbe2 , 0x9a02eb27 , 0x0985a6a3 ] )
+  , Mont::unsafe_make( [ 0x1c7faadd , 0x4ea0ca09 , 0x8a5d44c0 , 0x54962ba7 , 0xa6faae8d , 0x27826e89 , 0xfa613cb4 , 0x1e6d8011 ] )
+  , Mont::unsafe_make( [ 0x69dab890 , 0x1ec95540 , 0xc963d5df , 0x965fc256 , 0xb10a1712 , 0x77725f8c , 0xc9d53d5a , 0x1510403c ] )
+  , Mont::unsafe_make( [ 0x8201ade0 , 0xa8c01f69 , 0x6bb0849c , 0x19767f16 , 0xc69ecc9b , 0xfdac469a , 0x15f2cd3e , 0x0f09d5fe ] )
+  , Mont::unsafe_make( [ 0xd6cb42e4 , 0xd22b90a7 , 0x0c4d8c9c , 0x5a040d32 , 0x14d2ea73 , 0x89488541 , 0xb422efc1 , 0x1ccb49c0 ] )
+  , Mont::unsafe_make( [ 0xd3eb0dfe , 0x514a6e7a , 0x7a4ac723 , 0x6f84ecba , 0xde9b91aa , 0xea436ca2 , 0xfec1c1f9 , 0x13c3ae6b ] )
+  , Mont::unsafe_make( [ 0xee6677a6 , 0x414f64ce , 0x4e84220e , 0x67fca981 , 0x5dec5792 , 0xd195cb4e , 0x70ac8147 , 0x2103d683 ] )
+  , Mont::unsafe_make( [ 0x06dd2adc , 0xe8dd8726 , 0x779d3390 , 0x01242081 , 0x3411d58d , 0x844a4d27 , 0xc96e574b , 0x27fc9ef7 ] )
+  , Mont::unsafe_make( [ 0x01fffa03 , 0xedf8d0b2 , 0xbaf2d517 , 0xff3061fd , 0xc777d426 , 0x2bb7c444 , 0x79e184b6 , 0x052469c8 ] )
+  , Mont::unsafe_make( [ 0x8b820f48 , 0xca27341c , 0xfda818a9 , 0x060a2cb0 , 0xca6d0231 , 0x067039a3 , 0x85761fc5 , 0x0107ad7b ] )
+  , Mont::unsafe_make( [ 0x5301529d , 0x97354823 , 0x136fb557 , 0xe9b4b7ea , 0xcae9613a , 0x31014dfc , 0x40f15a10 , 0x22b8fb5d ] )
+  , Mont::unsafe_make( [ 0xa9ed7ab5 , 0x035c660a , 0x38145b8e , 0xa45a8e4c , 0x0afe0599 , 0xc1574197 , 0x3f1d31bc , 0x16afe8d7 ] )
+  , Mont::unsafe_make( [ 0xd812bd7b , 0x88e7e745 , 0x9a684ac1 , 0x20c803f6 , 0x13ae31d2 , 0xf41100e0 , 0xa0bb77fb , 0x19b4c58d ] )
+  , Mont::unsafe_make( [ 0xa41443fb , 0x462018e6 , 0x01cd9253 , 0x5d5371f7 , 0xc93f9470 , 0x9095b8cb , 0xe7d0a3f0 , 0x16aeb37d ] )
+  , Mont::unsafe_make( [ 0x23814ff2 , 0xffb73bb7 , 0x4af27cea , 0xe079b236 , 0x10038d60 , 0xea64130d , 0x82838c88 , 0x18584155 ] )
+  , Mont::unsafe_make( [ 0x19feb39a , 0x76ea758f , 0x10817049 , 0xe61fdb62 , 0x40c95950 , 0x7107df16 , 0x5145ab9c , 0x21c2fb99 ] )
+  , Mont::unsafe_make( [ 0xc5b4050a , 0x2caa235e , 0xffe6722d , 0x6e6b80a6 , 0x2192d179 , 0x1dd179d5 , 0x073a4e5d , 0x153eb938 ] )
+  , Mont::unsafe_make( [ 0x97ee3495 , 0x933b247a , 0x96991f8d , 0x0168c4d2 , 0x46ce41b6 , 0xdfd54bc4 , 0xc294c2cf , 0x28ea0644 ] )
+  , Mont::unsafe_make( [ 0xb848c45c , 0x40850045 , 0x7d77ccf0 , 0xbf29e1ff , 0x5b579dae , 0x9f9b66dd , 0x0a0fabdb , 0x045b3bee ] )
+  , Mont::unsafe_make( [ 0xe58b39da , 0x8a8aa070 , 0xab85e674 , 0xf600540c , 0x713af7a7 , 0x2903e95c , 0xcc33e6f9 , 0x14982f3b ] )
+  , Mont::unsafe_make( [ 0xae2eacae , 0x091f87af , 0x848c9e9c , 0xaca9132f , 0xd0d77a01 , 0xf94e0f9f , 0x41e1e45d , 0x09f03cac ] )
+  , Mont::unsafe_make( [ 0xaa9c597a , 0xe16f4445 , 0x3202ee67 , 0x3b303990 , 0x5bf7da24 , 0x276263ae , 0xf24713bf , 0x10b18ec7 ] )
+  , Mont::unsafe_make( [ 0x9e71a3fe , 0x8deff800 , 0x35ddc963 , 0x97644e95 , 0x0cf6bd69 , 0xe6eb07cc , 0xaf93ac68 , 0x10e0e7a9 ] )
+  , Mont::unsafe_make( [ 0x5fd01ce0 , 0x7cf60f96 , 0x081ec767 , 0x948dacce , 0x92068f99 , 0x9233ea49 , 0x32bc3861 , 0x1fda7cbf ] )
+  , Mont::unsafe_make( [ 0x567d1645 , 0xe043fa36 , 0xdffcb908 , 0xb1ba3465 , 0x5fa7a965 , 0x89c53903 , 0x763dbc9d , 0x2a1b57fd ] )
+  , Mont::unsafe_make( [ 0xde891af2 , 0x4ef4c82d , 0xd51db46c , 0xfd42a231 , 0x33af917f , 0xe7036307 , 0x6843c1f8 , 0x06fa2701 ] )
+  , Mont::unsafe_make( [ 0x56125e2a , 0x140d825c , 0x365e2f4c , 0x3a4cb3ef , 0xb4e8b491 , 0xab184f59 , 0xe64e4fd4 , 0x24c4a9c0 ] )
+  , Mont::unsafe_make( [ 0x310fd2d7 , 0xc405d1e0 , 0x4ca344ef , 0xf7e5dad6 , 0xae84b654 , 0x04b68eda , 0xbef4e032 , 0x140c2991 ] )
+  , Mont::unsafe_make( [ 0x99decaac , 0x47f74d87 , 0x3e665a67 , 0xa619ed66 , 0x13cdcc70 , 0x82b34de5 , 0x9e782a62 , 0x16fb366c ] )
+  , Mont::unsafe_make( [ 0xde1f4212 , 0x4e2a28c7 , 0xb96c8132 , 0x9ace694e , 0xc0cfc76b , 0xd009d74b , 0x2741d0d9 , 0x17ad4182 ] )
+  , Mont::unsafe_make( [ 0x1c7197c5 , 0x402a24e1 , 0x64296a95 , 0x4b9a6b52 , 0xa2a0a91d , 0xd335d937 , 0x92840756 , 0x25b40a39 ] )
+  , Mont::unsafe_make( [ 0x0c8f2ff6 , 0x26365182 , 0xe6835559 , 0xf73b048e , 0x1c663d73 , 0x09bacdbc , 0xe05fe19a , 0x0765279c ] )
+  , Mont::unsafe_make( [ 0xae5a679e , 0xfcffc669 , 0x801c938c , 0x502f747a , 0xcda7a793 , 0x45187023 , 0x8bd2b994 , 0x276e3b3b ] )
+  , Mont::unsafe_make( [ 0xeaa843e9 , 0x63ca9e7e , 0x91801dd5 , 0x69eec7c0 , 0x7b6522ad , 0x039be0a2 , 0x49fe00af , 0x2dc0c44a ] )
+  , Mont::unsafe_make( [ 0xc74c5950 , 0xe2b53a83 , 0x0dd15472 , 0xf40f7af3 , 0x724cda6b , 0x367345e3 , 0xac648e87 , 0x1da1a9f0 ] )
+  , Mont::unsafe_make( [ 0xbc6995f6 , 0x39dabb28 , 0xa97c8879 , 0xc995df79 , 0xcd05281a , 0xdfd3790d , 0x775789ee , 0x2c337a41 ] )
+  , Mont::unsafe_make( [ 0x6ecdb83e , 0x1e6e4810 , 0xc1930f13 , 0x7e887cc5 , 0xaba0d315 , 0x7c6add14 , 0x2793f211 , 0x007ecd10 ] )
+  , Mont::unsafe_make( [ 0x63af5f12 , 0xa48354fc , 0xaabd2cfe , 0x1080df0e , 0xb3eff489 , 0x3d84720e , 0x4eb4fa59 , 0x2929e2dd ] )
+  , Mont::unsafe_make( [ 0x5985f635 , 0x65ae0d9f , 0xb471b118 , 0x4037c546 , 0x3a149ff4 , 0x89499e1b , 0xa69515ee , 0x0848d6b8 ] )
+  , Mont::unsafe_make( [ 0x1c9ae0da , 0xf08e5145 , 0x8146ac89 , 0xb5771a8b , 0xbef3e7bd , 0xe4688898 , 0x4d94d4fb , 0x17b39fd4 ] )
+  , Mont::unsafe_make( [ 0xf279c589 , 0xf1046df1 , 0x07f6bacb , 0x8902e8c3 , 0x5db6fe92 , 0xa51e8f88 , 0xcde7bfb8 , 0x300bf752 ] )
+  , Mont::unsafe_make( [ 0xb0a49d2a , 0xbf422ebf , 0x562895d6 , 0x3538ecb1 , 0x3f82f79b , 0x92d77375 , 0x5e910f13 , 0x0f2558a1 ] )
+  , Mont::unsafe_make( [ 0x42d6ba82 , 0x2d734fd1 , 0x61d18cb6 , 0xd34df128 , 0x78fc4892 , 0x5b6e83fc , 0x6642cc64 , 0x1e246d22 ] )
+  , Mont::unsafe_make( [ 0x27b1b8c2 , 0x900dd3f9 , 0xdd1e546e , 0x1b83239a , 0xe7602ee6 , 0x1a4d4bcd , 0x62be914f , 0x1b117bcd ] )
+  , Mont::unsafe_make( [ 0x5e76ab48 , 0x96de496b , 0x1f504268 , 0x07eea93c , 0xb9c47ec6 , 0x1d25ec39 , 0x92299fd3 , 0x0339836e ] )
+  , Mont::unsafe_make( [ 0x466a1a57 , 0x101aaebd , 0x4237a3f4 , 0x64f52534 , 0x30e8b232 , 0x7786e2fe , 0x50d9db98 , 0x234a8609 ] )
+  , Mont::unsafe_make( [ 0xc2415e56 , 0x2e4f16ae , 0xeb442ac7 , 0xaa812a87 , 0xfa20828d , 0xa6f40a9f , 0x67ea3b2b , 0x1cc27615 ] )
+  , Mont::unsafe_make( [ 0x67e87985 , 0x714a68bf , 0x7972ea57 , 0x061df44b , 0xd96000d1 , 0x885f2b20 , 0x6b204684 , 0x1e58806a ] )
+  , Mont::unsafe_make( [ 0x59d1fd34 , 0xcba9a9e4 , 0xd4d24d52 , 0x51198f7c , 0x1b8c9760 , 0x7e52c8d8 , 0x8e919b44 , 0x0e9cfe46 ] )
+  , Mont::unsafe_make( [ 0xa97d40c4 , 0x70f40092 , 0x6d7385db , 0xc367289d , 0x8b7b53fd , 0x08a3dd92 , 0x10c3d702 , 0x08111d6f ] )
+  , Mont::unsafe_make( [ 0x006e0fcf , 0xec9ac6a1 , 0x1f99a783 , 0xc093fa14 , 0xa1b009c0 , 0xc07d520d , 0x1c5dbb4f , 0x04e9f87d ] )
+  , Mont::unsafe_make( [ 0x448c2f6d , 0x415bd6ea , 0x2bf1b458 , 0x65b2683d , 0x2fc76642 , 0xfaa4d46b , 0xe8def63b , 0x0080dfa6 ] )
+  , Mont::unsafe_make( [ 0xdaf3a7d6 , 0x47fde28c , 0xdc3c4bc2 , 0x1f7e582d , 0x36c3e984 , 0x7da9405a , 0xa1cc1fd9 , 0x24c99f52 ] )
+  , Mont::unsafe_make( [ 0xd8a74eda , 0x61a6cddd , 0xb5a4d89e , 0xf7e5502c , 0xcc37da66 , 0x867a7e04 , 0x1c4ce57a , 0x2eed1e7f ] )
+  , Mont::unsafe_make( [ 0x52f11e6c , 0xe4a7a67b , 0x8dd87861 , 0x914bb7a4 , 0x4fc64d7c , 0x05a452c0 , 0x072204f7 , 0x1e6c6070 ] )
+  , Mont::unsafe_make( [ 0x8bd02f46 , 0x48f5e62d , 0x6cd564c1 , 0xca8dd9bf , 0x6d96d4e1 , 0xac3b1104 , 0xef652c86 , 0x27f427e5 ] )
+  , Mont::unsafe_make( [ 0x82cc1cae , 0xa1afa8f2 , 0x371e0f95 , 0x8323b5ba , 0x2d2f740e , 0x5ea6ee0b , 0xb0eec63e , 0x0389639f ] )
+  , Mont::unsafe_make( [ 0xf6cb1b42 , 0x5606bc6b , 0x53d7bed7 , 0x1c099c7e , 0xc828479d , 0x8a791319 , 0x324edfc2 , 0x2ef40cd4 ] )
+  , Mont::unsafe_make( [ 0x5e3572d5 , 0xcfc2086d , 0x5c113d59 , 0x185a8876 , 0xaf054b98 , 0xfbf6510b , 0x14833055 , 0x08dd6ae5 ] )
+  , Mont::unsafe_make( [ 0x7d3c101a , 0x7bbc910b , 0xaed336a0 , 0x7d46fff0 , 0xa74ba5ea , 0xe602b734 , 0x800efae5 , 0x2ccdb7e4 ] )
+  , Mont::unsafe_make( [ 0xda0afe8e , 0xd603d25d , 0x01208021 , 0xe36323ad , 0xd317eb67 , 0x56ac34ae , 0xf7f02fc0 , 0x00e9ea49 ] )
+  , Mont::unsafe_make( [ 0x03b34479 , 0xf66dedac , 0xc1813301 , 0x384a0fce , 0x74c34712 , 0xae4f8bd8 , 0x95050a6f , 0x0ea22833 ] )
+  , Mont::unsafe_make( [ 0x068210d8 , 0x9d44307f , 0xed6f72ab , 0x8f528a42 , 0x1ea6c0ee , 0x93ad7df8 , 0x5f71297e , 0x0fb00df9 ] )
+  , Mont::unsafe_make( [ 0x5b5640e7 , 0xe641a5c4 , 0xac751786 , 0x7039a093 , 0x98b9f61e , 0x6283ba4d , 0xeba4de1c , 0x1393f73b ] )
+  , Mont::unsafe_make( [ 0x645cd44f , 0x924fa636 , 0xf35d4096 , 0x72e54b8c , 0x0c2e61d3 , 0x6a950e00 , 0x2887e4d8 , 0x2c246ab0 ] )
+  , Mont::unsafe_make( [ 0x14e1a132 , 0xc6c4f0e5 , 0x4841d027 , 0xb610b722 , 0x6892d0e1 , 0x41727198 , 0xb6699735 , 0x1eff2623 ] )
+  , Mont::unsafe_make( [ 0x5db2a4de , 0x95cb43ed , 0x88d4b245 , 0x9159d834 , 0x73c9b27a , 0x16a8d1f3 , 0x12f40b2b , 0x06ee7d4c ] )
+  , Mont::unsafe_make( [ 0xbe7499cd , 0xadd278b4 , 0xabe9136f , 0x4e8c77fd , 0xf918da23 , 0xd824fada , 0xcf73d19b , 0x203f062b ] )
+  , Mont::unsafe_make( [ 0x1b65dc00 , 0xd4334552 , 0x1cc74901 , 0xdfd92891 , 0x64bc370a , 0x1a7df227 , 0x3d2ff931 , 0x06ef1477 ] )
+  , Mont::unsafe_make( [ 0x1ddc56ba , 0xa7a71d0d , 0x6d781c95 , 0x33dc716a , 0x3dc355cb , 0x124ce269 , 0xadf9a51f , 0x20541519 ] )
+  , Mont::unsafe_make( [ 0x12fe912a , 0x8b6546f0 , 0x92b11920 , 0x35001fe2 , 0x04d87f8a , 0xbcd13f7d , 0xd1402f5e , 0x0198d03f ] )
+  , Mont::unsafe_make( [ 0x9de31a0f , 0x0a10edff , 0xcc94b0af , 0x13d8b6ee , 0xd2e4f6fc , 0xa2652839 , 0xc5ef9102 , 0x15660fe8 ] )
+  , Mont::unsafe_make( [ 0x83cfb2da , 0x6ccbef6f , 0xdc171b67 , 0x704d6ec3 , 0x1d2cebf0 , 0x76b3466b , 0x0eecd935 , 0x033f0fed ] )
+  , Mont::unsafe_make( [ 0x76e2d074 , 0xbfcf9155 , 0x2144ec81 , 0x4539111f , 0xecf29e18 , 0xc6bb32eb , 0xb2b1cafa , 0x1d69e81c ] )
+  , Mont::unsafe_make( [ 0x50379edd , 0xf768c0bd , 0xc6a1f718 , 0x2b4780e4 , 0x8b347c9d , 0xd4afdd22 , 0x4910cd7e , 0x2633a748 ] )
+  , Mont::unsafe_make( [ 0x92075a77 , 0xd1900262 , 0x1f2b4b48 , 0x9f028e8f , 0x7a3e1bb6 , 0xca0e058f , 0x76286b56 , 0x030ea81e ] )
+  , Mont::unsafe_make( [ 0x96b38e97 , 0xfb01689d , 0x8a57e63c , 0xc4b53ee3 , 0x7719459d , 0xba15719d , 0xedc0d514 , 0x0749bb1f ] )
+  , Mont::unsafe_make( [ 0x5e3f0cb8 , 0xc715279f , 0xfd549b2f , 0xc3754dea , 0xaad08dc3 , 0x4d598977 , 0xfa71a90b , 0x12acf267 ] )
+  , Mont::unsafe_make( [ 0x5224a8a9 , 0x40161953 , 0x5ef9b44c , 0xe6ba5baa , 0x12defeda , 0x65cde552 , 0x0ab9692b , 0x04a4efae ] )
+  , Mont::unsafe_make( [ 0x0f7343f2 , 0x866b1f1c , 0xdb4ab083 , 0xaf440f5a , 0x7dbc2cdc , 0x9c6933ad , 0xd26eb5c2 , 0x2befae73 ] )
+  , Mont::unsafe_make( [ 0xc3137359 , 0x652dc363 , 0x6bb0774e , 0x50d2294c , 0xd91d46ff , 0x90aeb10f , 0x9a8f2adf , 0x014600f3 ] )
+  , Mont::unsafe_make( [ 0xa54a2bb2 , 0xa66bc57a , 0xe2eec5ba , 0x728da37b , 0x1935e652 , 0x51086dbc , 0x9b2fc8fe , 0x059b1bf2 ] )
+  , Mont::unsafe_make( [ 0xe54d0506 , 0xbe2fb15a , 0x06388bb4 , 0xcbe3f99f , 0xe9dbf4d7 , 0x9de51874 , 0x86894c64 , 0x0c72f0ee ] )
+  , Mont::unsafe_make( [ 0x9e28cb44 , 0x99cbaeea , 0xce970329 , 0xbb373450 , 0x2ded0ed3 , 0x100a6e8d , 0xc5cca304 , 0x14b173a6 ] )
+  , Mont::unsafe_make( [ 0x5becd6d1 , 0x625f6f07 , 0x1c4e0a2b , 0x3dc6f6d8 , 0xad4f0e57 , 0x511b2d81 , 0x8b963262 , 0x1858c33a ] )
+  , Mont::unsafe_make( [ 0x07c46ff5 , 0xb15ddf2c , 0x65e6a123 , 0x1e8f8ef5 , 0x7173453d , 0x57152682 , 0x34208234 , 0x14aaf5d5 ] )
+  , Mont::unsafe_make( [ 0x297b5bc5 , 0x57d43446 , 0x6c3e2599 , 0xfe20ec90 , 0xe560d70e , 0x94ce10b1 , 0x62866b63 , 0x0dccd5b1 ] )
+  , Mont::unsafe_make( [ 0x2297995f , 0x0fe23be6 , 0x52231709 , 0x5d475d53 , 0xfc3b7d80 , 0xb2800e80 , 0xedcc6fac , 0x0aeac23b ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// internal mixing matrix (`t x t`s, flattened)
+pub const CONST_P: [Mont; 144] = 
+  [ Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x9a5d9e08 , 0x8db50763 , 0x60f037d8 , 0x2b7e6e03 , 0x33799f0e , 0x323dcc2c , 0xe6576699 , 0x0e92a3c0 ] )
+  , Mont::unsafe_make( [ 0x44e19e1b , 0x6178c267 , 0x1103f8a1 , 0x1454007f , 0x74406ad4 , 0xaa88a627 , 0xa628a7e4 , 0x2039b2e3 ] )
+  , Mont::unsafe_make( [ 0x67e0bff2 , 0x59e2b452 , 0xa8ccb929 , 0x459e31f7 , 0xa83b9185 , 0x92cb9797 , 0x26b1bf3a , 0x06f03c46 ] )
+  , Mont::unsafe_make( [ 0xa044a88f , 0x0ee97390 , 0xd1b6f221 , 0x7f740cf3 , 0xfa3c384f , 0x01c9f3df , 0xd6d4b83a , 0x0e7b2b4f ] )
+  , Mont::unsafe_make( [ 0x1b94af9c , 0x8e423f93 , 0x764df451 , 0xd46cd71a , 0xb258b125 , 0x975752f3 , 0xe9a2f924 , 0x166ff7ef ] )
+  , Mont::unsafe_make( [ 0x1e9cc2e4 , 0x6b380244 , 0x35f65a30 , 0x14a1516b , 0xa34dd2bc , 0x8b6d46ec , 0xe119a77e , 0x0ab2fa89 ] )
+  , Mont::unsafe_make( [ 0xcef38526 , 0x0e742f13 , 0x8e435cfd , 0xac6f9274 , 0x40394de7 , 0xd9ae9fe8 , 0x0489e610 , 0x035f8b5e ] )
+  , Mont::unsafe_make( [ 0xc4625391 , 0x5fcae587 , 0xb7133c42 , 0xa5eae81b , 0x63336ff1 , 0xe48e2f01 , 0x9adeaf00 , 0x0f4efe95 ] )
+  , Mont::unsafe_make( [ 0x6a274002 , 0x9253a56f , 0x5ee61c03 , 0x07293e8e , 0xcc96e517 , 0xa8925836 , 0x494b5471 , 0x24dcaaf0 ] )
+  , Mont::unsafe_make( [ 0xb3571bb7 , 0xf87b21fc , 0x78a5c239 , 0x2831c732 , 0xc26f4338 , 0xcb3e5b46 , 0x96e99d71 , 0x11a69b56 ] )
+  , Mont::unsafe_make( [ 0x9106ba77 , 0x9b070178 , 0x7abb1728 , 0x4660344e , 0xd15351f4 , 0x70c4717d , 0xfbadd05f , 0x238ceb26 ] )
+  , Mont::unsafe_make( [ 0x257659b8 , 0x1cfadf5b , 0x6e6ba845 , 0xdcda1b1c , 0x82c499b4 , 0xe5a6676c , 0xb5b040f7 , 0x23001c50 ] )
+  , Mont::unsafe_make( [ 0x815a853a , 0x5d13f3f6 , 0x1091bf5d , 0x5f68d556 , 0x5ac32b61 , 0xdc874608 , 0x4737e33b , 0x17fd1f03 ] )
+  , Mont::unsafe_make( [ 0x2181d714 , 0x17e2a0b3 , 0xdc84382d , 0x523efc60 , 0x2f11b897 , 0x4cece7f0 , 0xc0813682 , 0x2b3f43be ] )
+  , Mont::unsafe_make( [ 0x9e783b50 , 0xc03248cb , 0x6f427764 , 0xe77e6a28 , 0xda680fd5 , 0x1f0f19e7 , 0x14bf2e63 , 0x112938a8 ] )
+  , Mont::unsafe_make( [ 0xadd3e691 , 0x0c4697c7 , 0x34092a41 , 0x74db527a , 0x3e30ef8b , 0x9bcb0257 , 0xa6a74ec4 , 0x1d6e81d1 ] )
+  , Mont::unsafe_make( [ 0xd69ab478 , 0x91035cc7 , 0xdfc08200 , 0x385af903 , 0x8ebb2e9a , 0x6ca31964 , 0x2c3b8ad0 , 0x19d3c29a ] )
+  , Mont::unsafe_make( [ 0xc3320146 , 0x22c3aaf4 , 0x1d281cb4 , 0x45b8d909 , 0xa294d8aa , 0xd8018468 , 0xe0b55ad6 , 0x086f4e29 ] )
+  , Mont::unsafe_make( [ 0x1ee18805 , 0xfeef7cab , 0x51295df9 , 0xad2691e5 , 0x01b71d78 , 0x0b1b2fa5 , 0x2718fc15 , 0x251c74d4 ] )
+  , Mont::unsafe_make( [ 0x220d215f , 0x1a35041f , 0xf4b7f5c1 , 0x83598e70 , 0x0abb7880 , 0xc88de228 , 0x3511a911 , 0x250f7821 ] )
+  , Mont::unsafe_make( [ 0xbba2f4e7 , 0xc7658397 , 0xb6ca596d , 0xbb0eef46 , 0xb3abffe4 , 0xa5782869 , 0x2dc573f5 , 0x02e087b9 ] )
+  , Mont::unsafe_make( [ 0xaf0a75a1 , 0x007fc8c3 , 0x0077125d , 0xa0f6614c , 0xcacdfde9 , 0xc17f42e3 , 0x6166b2a2 , 0x01d48042 ] )
+  , Mont::unsafe_make( [ 0xfcc8657f , 0x158fa972 , 0x10a85d33 , 0x24e40c48 , 0x6c5f1ec1 , 0x3df7e2bc , 0xbe9aa523 , 0x189b0351 ] )
+  , Mont::unsafe_make( [ 0x63dc2a4f , 0x10ec045d , 0x5d9c9681 , 0x9439bdd0 , 0x31358ff7 , 0x4b3d0376 , 0x173078cf , 0x174afa6d ] )
+  , Mont::unsafe_make( [ 0x03320f84 , 0x0b8b1c5a , 0x5465bc02 , 0xdd1f3489 , 0x96db61fa , 0xb9dd42ff , 0x6263df38 , 0x2498e65d ] )
+  , Mont::unsafe_make( [ 0xb34427d1 , 0x98bdae00 , 0x6cecc298 , 0x7edec1b7 , 0x33a240c1 , 0xe968eff6 , 0xe4208643 , 0x29fe3701 ] )
+  , Mont::unsafe_make( [ 0xa544677d , 0x24eef825 , 0xc929b410 , 0xa31d77b0 , 0x2c9c82d9 , 0x164f92cb , 0xbcc23a52 , 0x2bbd981a ] )
+  , Mont::unsafe_make( [ 0x3f0a2fe1 , 0x037a624f , 0xce067d4e , 0xe443b673 , 0x4c4f2ce9 , 0x383f26c7 , 0x118b8330 , 0x18142fa1 ] )
+  , Mont::unsafe_make( [ 0xf39cab53 , 0x63b2cff7 , 0xf0744523 , 0x55985e26 , 0x68cfa58d , 0x595a55dc , 0xf1485429 , 0x071c093e ] )
+  , Mont::unsafe_make( [ 0xc9ecddbc , 0x09b27930 , 0x9723faed , 0xa595d290 , 0x12345ba6 , 0x05c5c898 , 0x374e1511 , 0x18cb6b06 ] )
+  , Mont::unsafe_make( [ 0x0a64ced7 , 0x862fb646 , 0xaa3405fc , 0x6e071df1 , 0x021cd511 , 0xdec261c8 , 0x82e25b81 , 0x13d9325e ] )
+  , Mont::unsafe_make( [ 0xc3534e42 , 0xdf5e4383 , 0x0aea0dbd , 0x55d07a3e , 0x6ee2d67f , 0xde3359fd , 0x822cf1b9 , 0x17d0a97f ] )
+  , Mont::unsafe_make( [ 0x89d6cc43 , 0x5097d214 , 0xec80e318 , 0xd923e844 , 0xae1adb8a , 0xf49905d8 , 0xb2d5146a , 0x18f039c2 ] )
+  , Mont::unsafe_make( [ 0xbbd4d8b0 , 0x1ab79b9e , 0xad896917 , 0x3921b3b7 , 0x898cb666 , 0xf8c351f1 , 0xe07464c2 , 0x2251010d ] )
+  , Mont::unsafe_make( [ 0xbb180ab9 , 0x83ecb41e , 0x1ace4bfa , 0x5759554c , 0x3bebac00 , 0x56e11776 , 0x2a2997bc , 0x25433bd3 ] )
+  , Mont::unsafe_make( [ 0x04731915 , 0xa654a342 , 0x4dce98bf , 0xd7b92642 , 0x55369ec5 , 0xc772dbd6 , 0x75fed3b0 , 0x198dd1ad ] )
+  , Mont::unsafe_make( [ 0xed4bb0b2 , 0x28b1aee2 , 0x6ed13cef , 0xb13ae998 , 0x58608573 , 0x3d96f58a , 0x1b2f0dce , 0x2245736d ] )
+  , Mont::unsafe_make( [ 0x34771e60 , 0x93544d19 , 0x92fb5e8f , 0x8c76f92a , 0xcbab7bb5 , 0xa4883d63 , 0x252e996a , 0x174e9922 ] )
+  , Mont::unsafe_make( [ 0xc343fc5a , 0x1ead343a , 0x2e68a7e7 , 0xdb2ec466 , 0xa14c2d36 , 0x06812dd7 , 0x7f2ce97c , 0x145fcc4c ] )
+  , Mont::unsafe_make( [ 0x1c388668 , 0x4184817e , 0x5a0970e0 , 0xc6b3a384 , 0xe7d3bdf8 , 0x43dd944c , 0xf1853a2e , 0x02f041a0 ] )
+  , Mont::unsafe_make( [ 0xf537da85 , 0xb9cd3e54 , 0x2d665723 , 0x37832dc6 , 0xc031b128 , 0x6884bf54 , 0x62cb2a76 , 0x1e1b2a85 ] )
+  , Mont::unsafe_make( [ 0xa9ab6dd1 , 0xb0d302c8 , 0xb33a94e0 , 0x1c162efc , 0xea4f83f7 , 0x897abe71 , 0x7a7db99f , 0x10d18d8b ] )
+  , Mont::unsafe_make( [ 0xa340d225 , 0xd9c2b6c8 , 0x6bc19443 , 0x782b71d3 , 0xf916d196 , 0xd6a83acd , 0x6560da75 , 0x0ce6e527 ] )
+  , Mont::unsafe_make( [ 0x71a231d3 , 0xb055f79d , 0x314bb604 , 0xfe2e0885 , 0x464200ed , 0x8fb61375 , 0x3d61b48c , 0x1a9139e5 ] )
+  , Mont::unsafe_make( [ 0x5f43fe26 , 0xb54fbf7b , 0xf2159200 , 0xfaf3f22b , 0xa0bae0c1 , 0x6027fc24 , 0x66013565 , 0x1cab1799 ] )
+  , Mont::unsafe_make( [ 0x973d0876 , 0x72056cbc , 0x028f0941 , 0x66e11319 , 0x47cf02c1 , 0x87038b85 , 0xebeac9ae , 0x1620df7d ] )
+  , Mont::unsafe_make( [ 0x9c590c57 , 0x872c1e4a , 0x1f847e48 , 0xf5eed0a4 , 0x834d8613 , 0xc1ba58f6 , 0x349b7e62 , 0x1bccfa42 ] )
+  , Mont::unsafe_make( [ 0x45461ac0 , 0x247529f6 , 0xfd11b3e7 , 0x8a126c0a , 0xe4e66ed4 , 0x3756198d , 0xbcc19d6c , 0x06705349 ] )
+  , Mont::unsafe_make( [ 0x27552d0f , 0x8854e5c1 , 0xfdbf89e4 , 0x8f0a2402 , 0x2107bc3e , 0x844d47e0 , 0x0ea7a8fb , 0x14dfb95f ] )
+  , Mont::unsafe_make( [ 0x09b330ab , 0xccd537b3 , 0xb7237d24 , 0xcaf90e88 , 0x10531c9d , 0xad4e6fff , 0x81609c08 , 0x1a2fa08e ] )
+  , Mont::unsafe_make( [ 0x32b0bfe4 , 0xbd5eacb4 , 0x42c02d09 , 0x225a2412 , 0x02b13043 , 0x65eb2464 , 0x9c7ea7b1 , 0x1afc9e85 ] )
+  , Mont::unsafe_make( [ 0x77e52aab , 0x396444ff , 0x1b5b19bb , 0xf1725212 , 0x46036dc3 , 0xd55dc5ed , 0xa44b2196 , 0x28585cd1 ] )
+  , Mont::unsafe_make( [ 0xd68960f1 , 0x84da2cdd , 0x06f1cb9c , 0xbf24d746 , 0xcf959e92 , 0x2508b3a2 , 0x61090dd4 , 0x0ce1bc9b ] )
+  , Mont::unsafe_make( [ 0x365b90b3 , 0x2a887963 , 0x0612a776 , 0xe184a170 , 0xf4ba61fc , 0x752a4ada , 0x3bd89ca4 , 0x2eaf6337 ] )
+  , Mont::unsafe_make( [ 0x46305167 , 0x198c410c , 0x92181d82 , 0x4bb1cb22 , 0x60906f86 , 0x9158fa26 , 0x09d3cba4 , 0x292184a1 ] )
+  , Mont::unsafe_make( [ 0x2b9833cd , 0x9e0cbd8b , 0x1b9d874f , 0x3b69e388 , 0x783f69df , 0xc40df743 , 0x1e12e407 , 0x12e94422 ] )
+  , Mont::unsafe_make( [ 0x29b75373 , 0xaed424be , 0xed838838 , 0x935027e2 , 0xd6e32875 , 0x648ef8cd , 0x23fb1da1 , 0x0c759c5e ] )
+  , Mont::unsafe_make( [ 0xe1b178ec , 0x5f617d00 , 0x6e9493f9 , 0x0f1aa072 , 0xf60e2fd7 , 0x17828556 , 0xd0c30abb , 0x09b51258 ] )
+  , Mont::unsafe_make( [ 0xba48d2ea , 0x3f96def8 , 0x7e2a9322 , 0xe8314144 , 0xfcff298f , 0xaf30e9c8 , 0xe9543525 , 0x2774875f ] )
+  , Mont::unsafe_make( [ 0xd6cb42e4 , 0xd22b90a7 , 0x0c4d8c9c , 0x5a040d32 , 0x14d2ea73 , 0x89488541 , 0xb422efc1 , 0x1ccb49c0 ] )
+  , Mont::unsafe_make( [ 0xbebb7654 , 0x29b72009 , 0xaf0249d4 , 0x1d18adc4 , 0x287962d8 , 0x28786566 , 0x8db37be4 , 0x0872d08b ] )
+  , Mont::unsafe_make( [ 0x249ea556 , 0x422487e2 , 0x511d91a8 , 0x8bf68a4b , 0x935515be , 0xf75c7576 , 0xcf6ffde7 , 0x08f4e2c4 ] )
+  , Mont::unsafe_make( [ 0xc0473f71 , 0x2512f57b , 0x07efc65c , 0x9cfa8401 , 0x43a6e86c , 0x02840637 , 0xfd09c5ed , 0x003d4b75 ] )
+  , Mont::unsafe_make( [ 0xc547d560 , 0xc63e73e8 , 0x6603e35f , 0xcf03354a , 0x896a89f3 , 0xe8460fa9 , 0x1ab26b3c , 0x2794d684 ] )
+  , Mont::unsafe_make( [ 0xaedf64e5 , 0x8a812c2b , 0x7621efa1 , 0x475c5b85 , 0x4ee9ba09 , 0x473b8c6a , 0x211ada7c , 0x101a9511 ] )
+  , Mont::unsafe_make( [ 0xcf5dd212 , 0x56b0cd0d , 0x497ac320 , 0xa713649a , 0x9dbce04c , 0xd5976466 , 0xee9fcabc , 0x1c83a7c0 ] )
+  , Mont::unsafe_make( [ 0x2504c137 , 0x4f232cab , 0x62a2b014 , 0x4df88b1a , 0x2b405df1 , 0x6eb76053 , 0xf8227e3a , 0x1b3c0114 ] )
+  , Mont::unsafe_make( [ 0x35c0c935 , 0x25c6b204 , 0x42051fbd , 0x720b6d9f , 0x878cff6d , 0x7913ff6b , 0x9c578d2f , 0x0a4b2ed1 ] )
+  , Mont::unsafe_make( [ 0x8f87551b , 0xfef529c9 , 0xc3ff26df , 0x14f9d8e6 , 0xeed63bc7 , 0x6deb54e7 , 0x0e968a74 , 0x1a5eb348 ] )
+  , Mont::unsafe_make( [ 0x82bddf8a , 0x01f5f0f8 , 0xaa167198 , 0x608db9e3 , 0x2134428b , 0x3d586af7 , 0xc1b8b5bc , 0x1f91f0fc ] )
+  , Mont::unsafe_make( [ 0xd76f8a8f , 0x7db48d64 , 0x13448b83 , 0xca34692e , 0x9236a0bb , 0xd250e59f , 0x68fffd44 , 0x2f239240 ] )
+  , Mont::unsafe_make( [ 0xc5b4050a , 0x2caa235e , 0xffe6722d , 0x6e6b80a6 , 0x2192d179 , 0x1dd179d5 , 0x073a4e5d , 0x153eb938 ] )
+  , Mont::unsafe_make( [ 0xb49bcbf8 , 0xebed2289 , 0x6a926f00 , 0x5051170d , 0x124b18f2 , 0x90514369 , 0xa8190ea1 , 0x210e888d ] )
+  , Mont::unsafe_make( [ 0x52a3e51a , 0xf4277d15 , 0xcf63d61d , 0x9a1ab116 , 0xb4919fa9 , 0xe37bfc2b , 0x4d800db7 , 0x2e0ee160 ] )
+  , Mont::unsafe_make( [ 0x9f6035d7 , 0x14379728 , 0xe0077d16 , 0x50b46d4a , 0xe8288157 , 0xafe53e4f , 0x9698b2dc , 0x18d6643d ] )
+  , Mont::unsafe_make( [ 0xe54f7463 , 0x62f55dcb , 0xd5856bff , 0x1c9e629a , 0xe2f02373 , 0x0a5e49b3 , 0xf24006d4 , 0x175bb980 ] )
+  , Mont::unsafe_make( [ 0x3647b779 , 0x4caf4a5b , 0xa4dbbf52 , 0x01957143 , 0x822a8c2f , 0xa8221fe4 , 0xb0766861 , 0x09cd47d2 ] )
+  , Mont::unsafe_make( [ 0x27606a49 , 0xe20c6f4c , 0xa2b93dcd , 0xfa363239 , 0x25b468e9 , 0x5c6926da , 0xdca98370 , 0x2ed8fadb ] )
+  , Mont::unsafe_make( [ 0x41ff9221 , 0x67c053d6 , 0xc62dcac5 , 0x486b246f , 0x80355b09 , 0x8be3c25f , 0x94b3e243 , 0x24838df9 ] )
+  , Mont::unsafe_make( [ 0x8893239b , 0xab19d739 , 0x2748b59f , 0x34c40c5d , 0x0cb377fd , 0x2fe113cb , 0x4c47d993 , 0x2aef3a55 ] )
+  , Mont::unsafe_make( [ 0x29e1e4d6 , 0x20887bf8 , 0x600e6dc9 , 0x04617695 , 0x61752eb7 , 0x99ccd5a6 , 0x8b77a83c , 0x1445ce39 ] )
+  , Mont::unsafe_make( [ 0x0d6c7b71 , 0x654d8670 , 0xeac4ad3f , 0x07bc0d3a , 0xe0325882 , 0x0f8ebb49 , 0x23551bd0 , 0x1a76e31d ] )
+  , Mont::unsafe_make( [ 0x3ec4fcff , 0x1fe90c0e , 0x9bdaabba , 0x9f65b56c , 0x975dd506 , 0x1bf4b4d8 , 0x4e72254b , 0x2a5a63b5 ] )
+  , Mont::unsafe_make( [ 0x99decaac , 0x47f74d87 , 0x3e665a67 , 0xa619ed66 , 0x13cdcc70 , 0x82b34de5 , 0x9e782a62 , 0x16fb366c ] )
+  , Mont::unsafe_make( [ 0x7be39c87 , 0x8d61ea1a , 0x72759b75 , 0x345066b5 , 0x784edd69 , 0x8adb93f9 , 0xb060c1f8 , 0x24cda7b2 ] )
+  , Mont::unsafe_make( [ 0xb8812387 , 0x4d6c145c , 0x7e556e3b , 0x2a139881 , 0x6d722e52 , 0xc9363d41 , 0xc7915cf0 , 0x0e1edcfd ] )
+  , Mont::unsafe_make( [ 0x026070ad , 0x8e886626 , 0x89e546a8 , 0xe3580538 , 0xfb2a74fe , 0xd6ead0d1 , 0xc142f311 , 0x110f7ccb ] )
+  , Mont::unsafe_make( [ 0xc3b9f371 , 0x68517243 , 0x2d7b3a2c , 0xf4dce0ca , 0xd0c3715b , 0xe34d2c69 , 0x2f5ec3b1 , 0x0ad189ca ] )
+  , Mont::unsafe_make( [ 0x1f2d3fd3 , 0xfe73edd8 , 0xc2db5b7b , 0x356c2b6f , 0xe9f80756 , 0x7b9d8329 , 0x5e3366ce , 0x23283d35 ] )
+  , Mont::unsafe_make( [ 0xb414fa9e , 0x12ec8e6a , 0x4ff6e837 , 0x4466fc66 , 0x338c7d4e , 0x57870f18 , 0x8ed94c0c , 0x292e2441 ] )
+  , Mont::unsafe_make( [ 0xeb3b8034 , 0xc24c05c7 , 0xf878f4e9 , 0x139fefff , 0x0a4766fc , 0x3447fa6e , 0xdf13bbed , 0x292b3ac9 ] )
+  , Mont::unsafe_make( [ 0x51b841d2 , 0xb18d8d7d , 0xd4e2947f , 0xa06218a5 , 0x7a23bfe2 , 0x45db3715 , 0xb36c7e01 , 0x0b1ef4ba ] )
+  , Mont::unsafe_make( [ 0xe43eb2bb , 0x10151afe , 0x11018735 , 0x8893ca5c , 0xa1d7afd4 , 0x6f3bbabc , 0x2b5a5c8c , 0x2d7d96af ] )
+  , Mont::unsafe_make( [ 0xd17a0f85 , 0x42ab73a0 , 0xb9a2246c , 0x69028823 , 0xd21748ba , 0xb7aa903c , 0x47e167cf , 0x1d475055 ] )
+  , Mont::unsafe_make( [ 0x5e3c65b9 , 0x701fd935 , 0x4f28c0b6 , 0x5b68863c , 0xeb1a554a , 0x5a61244e , 0xfdf1ea6b , 0x2d0ce752 ] )
+  , Mont::unsafe_make( [ 0xf279c589 , 0xf1046df1 , 0x07f6bacb , 0x8902e8c3 , 0x5db6fe92 , 0xa51e8f88 , 0xcde7bfb8 , 0x300bf752 ] )
+  , Mont::unsafe_make( [ 0x0d09bc4f , 0x6e4aab01 , 0x90d60ac1 , 0x33633acf , 0xc1748fd2 , 0x6b43c655 , 0x6fb252d4 , 0x22895e5f ] )
+  , Mont::unsafe_make( [ 0x07be196c , 0x34b4f1af , 0x04cf0cfb , 0x7f6b0812 , 0x3fe9862a , 0x28957d39 , 0x0d7242bd , 0x02571333 ] )
+  , Mont::unsafe_make( [ 0x4be0cfde , 0x23e582e6 , 0xa03cb3c5 , 0x5f5d1d97 , 0xe51ed402 , 0xcd6f9f46 , 0x66ae5093 , 0x23ef85ba ] )
+  , Mont::unsafe_make( [ 0x27abfd77 , 0xe2e53ba3 , 0xc4e511fa , 0x46578da9 , 0x379f6443 , 0x103f8a12 , 0x0a79647d , 0x2c42a471 ] )
+  , Mont::unsafe_make( [ 0x58c07752 , 0xb824931f , 0xeb7d906b , 0xf4eaa1f4 , 0x9c42917e , 0x6bd203cd , 0x604279dd , 0x0d68327b ] )
+  , Mont::unsafe_make( [ 0x809a63b8 , 0xd6cb4243 , 0x32be209b , 0x28b297c4 , 0x92c61415 , 0x12811a77 , 0x8120a9ac , 0x2aec2fef ] )
+  , Mont::unsafe_make( [ 0xb49e0d16 , 0xc4f532c7 , 0x919b22f0 , 0x0d4ea309 , 0x107e3516 , 0x2abf483f , 0x0ab326ff , 0x17d0c3c3 ] )
+  , Mont::unsafe_make( [ 0xa7be3869 , 0x821686d3 , 0xf8a949c0 , 0x01bb133a , 0x90076caa , 0x91dd801b , 0xab5f502d , 0x1983cbfb ] )
+  , Mont::unsafe_make( [ 0x5cd966c2 , 0x793c6de2 , 0x4bfced2f , 0x05ab8ef3 , 0x10d473a3 , 0x11f59967 , 0x2ba9f7ef , 0x038fe715 ] )
+  , Mont::unsafe_make( [ 0x1ca17ee1 , 0x26ab9b88 , 0xababca36 , 0xa366658d , 0x41588297 , 0xbd3f2877 , 0xa2556dbe , 0x25811abc ] )
+  , Mont::unsafe_make( [ 0x9f56a40a , 0xc94cedba , 0x56f9ccf1 , 0xf33c4227 , 0xccc5dd7a , 0x60d05e0f , 0x482717c0 , 0x0ec45916 ] )
+  , Mont::unsafe_make( [ 0xdaf3a7d6 , 0x47fde28c , 0xdc3c4bc2 , 0x1f7e582d , 0x36c3e984 , 0x7da9405a , 0xa1cc1fd9 , 0x24c99f52 ] )
+  , Mont::unsafe_make( [ 0x2c5a04f6 , 0xbb08da29 , 0x07b6a71c , 0x7db951f6 , 0x7bae817f , 0x03190af8 , 0x9dfd9e4d , 0x1ed6b0e3 ] )
+  , Mont::unsafe_make( [ 0x79ef2701 , 0xecd05274 , 0x953e6d10 , 0xe5dd80ea , 0x6dd4f7f2 , 0x64fcfe84 , 0x6af274d5 , 0x2ff57e6e ] )
+  , Mont::unsafe_make( [ 0x1a0042cc , 0x4c329da2 , 0x1be0e888 , 0xc9354b30 , 0x57bb2d05 , 0x1620bb13 , 0x1de11c57 , 0x2c3573f4 ] )
+  , Mont::unsafe_make( [ 0x68dd1d8c , 0xd1ea80d6 , 0x8795cdac , 0x8a3e0643 , 0xb04e1d6f , 0x1cc12014 , 0x58e54f81 , 0x146a9697 ] )
+  , Mont::unsafe_make( [ 0x91b780d3 , 0xf9c733f1 , 0x3101872c , 0x872e9d86 , 0x083e9d88 , 0xccf28c07 , 0xfbf000df , 0x0970f0b9 ] )
+  , Mont::unsafe_make( [ 0xfecf7a19 , 0x4688f860 , 0x0e4a267f , 0xb32c825a , 0xec448de7 , 0x826bdbd8 , 0xf54b5b13 , 0x11232090 ] )
+  , Mont::unsafe_make( [ 0xad0dbe24 , 0xafd147a9 , 0xa3575513 , 0x8ce0a970 , 0x02d3b10b , 0xd684406a , 0x274b2333 , 0x15535bc2 ] )
+  , Mont::unsafe_make( [ 0x6f5568b6 , 0x2a918d45 , 0x7a49d0bd , 0x54a3dde4 , 0x4930168c , 0xf2160457 , 0x8c63ba49 , 0x06941eb4 ] )
+  , Mont::unsafe_make( [ 0xbc5de15f , 0x1103704a , 0x5a16b90c , 0x6975ef54 , 0xb30e9401 , 0x943ed6e1 , 0x5ce51889 , 0x28440b56 ] )
+  , Mont::unsafe_make( [ 0x791be76c , 0xe4aadeae , 0xa84152c0 , 0x8e071f9e , 0x91ca4c20 , 0xe23d0a64 , 0xa18fb923 , 0x241afa3e ] )
+  , Mont::unsafe_make( [ 0xb52a2e17 , 0x83cd6aab , 0x291e4851 , 0x324060aa , 0x805beb2b , 0xce4265d5 , 0x63248ef7 , 0x2942299c ] )
+  , Mont::unsafe_make( [ 0x645cd44f , 0x924fa636 , 0xf35d4096 , 0x72e54b8c , 0x0c2e61d3 , 0x6a950e00 , 0x2887e4d8 , 0x2c246ab0 ] )
+  , Mont::unsafe_make( [ 0x9d49024a , 0x4891321c , 0x3cc2b5ee , 0x7afdcf65 , 0xc43fc86d , 0x98a00328 , 0x595bbc8f , 0x28ca59b5 ] )
+  , Mont::unsafe_make( [ 0xef697e30 , 0x479b619f , 0xd31900c4 , 0x8bf5494e , 0x4cd512c3 , 0x6e8e3c76 , 0x00d6c99f , 0x21c13415 ] )
+  , Mont::unsafe_make( [ 0x86d3d1d2 , 0x0abcbb78 , 0x19ae11e0 , 0x9e84f183 , 0x01d945fd , 0xea2ade27 , 0x99372fcd , 0x0b3829c7 ] )
+  , Mont::unsafe_make( [ 0xde3d4b14 , 0xec935d77 , 0x41f59f80 , 0x155427ad , 0x547ae156 , 0xac646ecc , 0x0f1883a2 , 0x1678c652 ] )
+  , Mont::unsafe_make( [ 0x3d5a5410 , 0x8e3979b9 , 0x95c8ac97 , 0x334b149d , 0x167d7c40 , 0x890c9f2d , 0x66e3c6d6 , 0x2156ee0a ] )
+  , Mont::unsafe_make( [ 0xbcd4f7fb , 0x0c596337 , 0xaaaf7257 , 0x7f78f072 , 0xdcff8805 , 0xea3d033c , 0x0b9029ac , 0x18274ddc ] )
+  , Mont::unsafe_make( [ 0xa58b3b63 , 0x57990ebf , 0x5f96c154 , 0x7732f617 , 0x98b29ca5 , 0x5131b3db , 0x18af85ef , 0x27fcee2c ] )
+  , Mont::unsafe_make( [ 0x57e2815f , 0x3d1ab6e0 , 0xad53c7b8 , 0xf424e275 , 0x0eefb02e , 0x01a202f1 , 0x41e00a0a , 0x09f43d0b ] )
+  , Mont::unsafe_make( [ 0x0ea00345 , 0x0c786eb1 , 0xd7515c15 , 0xe1a14df4 , 0xd33f2f50 , 0x88837a55 , 0x8a411bb2 , 0x0403e40d ] )
+  , Mont::unsafe_make( [ 0xc5601edb , 0x3a0e451c , 0xd780a4f2 , 0xb330dd38 , 0x36a74d81 , 0xe8e9c834 , 0x51b42038 , 0x22de970f ] )
+  , Mont::unsafe_make( [ 0x66de9867 , 0x861f4773 , 0xe2a86949 , 0xb46bba8a , 0x31a347b9 , 0xae63ae80 , 0xd08351ce , 0x1d752852 ] )
+  , Mont::unsafe_make( [ 0x96b38e97 , 0xfb01689d , 0x8a57e63c , 0xc4b53ee3 , 0x7719459d , 0xba15719d , 0xedc0d514 , 0x0749bb1f ] )
+  , Mont::unsafe_make( [ 0xfa09c2ec , 0x2435cc61 , 0x0a1e0885 , 0xf8a9cdeb , 0x413defef , 0x0b3ba73b , 0x3005864e , 0x03b0b918 ] )
+  , Mont::unsafe_make( [ 0x349e1db5 , 0x79e8edb1 , 0xfefdd5ed , 0x88d0268f , 0xa16b235d , 0xba493115 , 0xb8ba1bfa , 0x1961105d ] )
+  , Mont::unsafe_make( [ 0x5e0bd48f , 0x1e72e4c8 , 0xe93c0ca0 , 0x570e5c8d , 0xcc65e56a , 0xcdf1c5fe , 0x13107cf7 , 0x2530e69a ] )
+  , Mont::unsafe_make( [ 0x45b43403 , 0x6643a3c1 , 0x08ed9777 , 0xf1d06463 , 0xd5a719e3 , 0xfcc9bb00 , 0xe5419c1d , 0x1dd834db ] )
+  , Mont::unsafe_make( [ 0x3d2d97f0 , 0xe4a4b45d , 0xd4a2c948 , 0x80b9189d , 0x66386266 , 0x72deb86c , 0x69c1bff5 , 0x04180b16 ] )
+  , Mont::unsafe_make( [ 0x339265eb , 0x1781fc64 , 0x12e50b11 , 0xe4b03580 , 0x65f7bae9 , 0x4141a60b , 0xf7bb9c0a , 0x125854fd ] )
+  , Mont::unsafe_make( [ 0x534759cc , 0xfb3fa397 , 0x4d7b34c2 , 0x95c42676 , 0xff2c61d1 , 0x95dd0e13 , 0x3dccbd91 , 0x04f74b2b ] )
+  , Mont::unsafe_make( [ 0x83febed9 , 0x8bff51a2 , 0x6dfe6b9b , 0x38574006 , 0x7739afb2 , 0x659bd62d , 0x08dd97a9 , 0x1c43e291 ] )
+  , Mont::unsafe_make( [ 0xa8db6bd8 , 0xc93c968a , 0xb07880b1 , 0x3366eaf3 , 0xdbb1c65e , 0xbe279cee , 0x7babae9f , 0x27ad139a ] )
+  , Mont::unsafe_make( [ 0xea5c4049 , 0x205ecde6 , 0x326bf603 , 0xd7cd5552 , 0x192759e5 , 0x4119576e , 0x48f3a4a1 , 0x148a8bbc ] )
+  , Mont::unsafe_make( [ 0x40608bac , 0x0f36579c , 0x2cde44a1 , 0xedf12e51 , 0xa198c086 , 0x8a29a195 , 0xe47aca01 , 0x0299acde ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// circomlib's optimization whatever
+pub const CONST_S: [Mont; 1380] = 
+  [ Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x5a238497 , 0x5087b9d0 , 0x99f8df6b , 0xab12873b , 0x37c6b923 , 0x1969b371 , 0x716b051b , 0x0afe9201 ] )
+  , Mont::unsafe_make( [ 0x55f10f83 , 0xfbd5dcac , 0x11492386 , 0x58387ebf , 0xc36e59bc , 0xc3e5725b , 0x2ae98a55 , 0x2cd3d6aa ] )
+  , Mont::unsafe_make( [ 0xcd7de287 , 0x66b924c7 , 0x0a5ae433 , 0x65bcefc3 , 0x698de0c6 , 0x2a9eb625 , 0x911e1e4e , 0x12529c8d ] )
+  , Mont::unsafe_make( [ 0xaa4f070a , 0x18dd21ad , 0xc4629c4b , 0x9c436b88 , 0x5ee6179e , 0xbe2aa73d , 0x4604137d , 0x1e5cb422 ] )
+  , Mont::unsafe_make( [ 0xc548c9df , 0x167806be , 0xd27e0e62 , 0x43419d1d , 0xafe59e37 , 0x5c98e967 , 0x0ecd631c , 0x2586abc4 ] )
+  , Mont::unsafe_make( [ 0x25189e97 , 0x2c5bbc9b , 0xf922b7b5 , 0xd411580d , 0xe9c523c0 , 0x8b9fd101 , 0x38cb82bf , 0x23322849 ] )
+  , Mont::unsafe_make( [ 0x4db73559 , 0x5b65ce60 , 0xe96483b6 , 0xb0208139 , 0xcef69c41 , 0x4d33d918 , 0x90ee9243 , 0x1f131c25 ] )
+  , Mont::unsafe_make( [ 0xdf3101ba , 0xccb9f607 , 0x76ba12ae , 0xfe286f7c , 0x46b48300 , 0x226d6223 , 0x2852f6bd , 0x087145ea ] )
+  , Mont::unsafe_make( [ 0x6ef75090 , 0x2e472677 , 0x69a02239 , 0x71f77e03 , 0xa32222f3 , 0xcf75c1e0 , 0x46f62d44 , 0x0b6e1add ] )
+  , Mont::unsafe_make( [ 0xcd527456 , 0x2d60bd4d , 0x9119ece1 , 0x2ada9921 , 0xd8ba4f19 , 0x375375eb , 0x8b1d3b15 , 0x0e847edc ] )
+  , Mont::unsafe_make( [ 0xb6a781eb , 0x73bfe8f7 , 0x6504f2bd , 0x300a4675 , 0x184733cc , 0xbb3f8ef2 , 0xda6543e6 , 0x0d3e2555 ] )
+  , Mont::unsafe_make( [ 0x40d8db41 , 0x0b97f2f3 , 0xe6674795 , 0xa0dd7c24 , 0x4168b0c5 , 0xd7319a07 , 0xbfc92c85 , 0x2cad301c ] )
+  , Mont::unsafe_make( [ 0x74f83604 , 0x5ccfd151 , 0x63f8e2dd , 0xb6ad6214 , 0xa9208062 , 0x70eafdaf , 0x9b5ec7e3 , 0x1b202535 ] )
+  , Mont::unsafe_make( [ 0xdb69c8a6 , 0xfdfdfc88 , 0xb3ccf2cd , 0x4eef8435 , 0xf81e283d , 0xeb4845e9 , 0x55133eb9 , 0x229cd211 ] )
+  , Mont::unsafe_make( [ 0x04ef223d , 0xe5ce3828 , 0xba77deaa , 0x9ebed45f , 0xf08a0fae , 0x91547e5a , 0xb34a3e2d , 0x125bc3b5 ] )
+  , Mont::unsafe_make( [ 0xbcc3f6d3 , 0x089a6b4f , 0x59409600 , 0xffde9ad2 , 0x9da42768 , 0x5c4e7144 , 0xcca0906c , 0x0bdf8973 ] )
+  , Mont::unsafe_make( [ 0x30990f50 , 0x09b3d32d , 0x73202bf5 , 0xce4a8ad9 , 0x583b6be9 , 0x2fe16d68 , 0xaeeb7a77 , 0x0e3674ac ] )
+  , Mont::unsafe_make( [ 0xab91802d , 0xffe383ef , 0xe32520a4 , 0x834bdbb2 , 0x86efb318 , 0x270cff87 , 0x67787f6c , 0x0eec527e ] )
+  , Mont::unsafe_make( [ 0x3e0e2195 , 0x31bb782e , 0x84f8ebf0 , 0xfc52c928 , 0x587c5a0f , 0x2a7deacd , 0x24b2c492 , 0x1add07d2 ] )
+  , Mont::unsafe_make( [ 0x4c65661c , 0x2007b71f , 0xe1b090c2 , 0x3514b89d , 0x601c12a9 , 0xf390cbd5 , 0x34fbb363 , 0x132e7041 ] )
+  , Mont::unsafe_make( [ 0xd4b4e8f4 , 0x3ed08319 , 0xc5d0ef40 , 0x16587af4 , 0xbf38c322 , 0xc217cd37 , 0xf2ee2d15 , 0x0b746dcc ] )
+  , Mont::unsafe_make( [ 0xcdff55b3 , 0x5b529e35 , 0xf9fce71b , 0x0d6a0972 , 0xf2461c9c , 0x8131baa0 , 0x8e129d28 , 0x26294960 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x53e1ee69 , 0x64c9807b , 0x46bf6b74 , 0xa4011556 , 0x7708687d , 0x07eef22a , 0x8e2c0617 , 0x2451d021 ] )
+  , Mont::unsafe_make( [ 0x35b8aa41 , 0xaa1a8090 , 0x24083adb , 0x5e2f1f5c , 0x70c2e390 , 0x9979abff , 0xd7b596dc , 0x2a53f5a2 ] )
+  , Mont::unsafe_make( [ 0xaf822e67 , 0x32803929 , 0x7cf1880e , 0xa7ed62c8 , 0x7eb720ba , 0x3628a70a , 0xa37d5e75 , 0x00b0f614 ] )
+  , Mont::unsafe_make( [ 0x93164c23 , 0xb3e44972 , 0x21ed4c67 , 0x9d9b9e1f , 0xc37e0db6 , 0x15ffce9e , 0xcf34ffa3 , 0x1a6c9df9 ] )
+  , Mont::unsafe_make( [ 0x5511bf14 , 0xaa8d0939 , 0x5a44b2df , 0x2c1123a9 , 0x84391843 , 0x2ef492da , 0x3168cb14 , 0x17286461 ] )
+  , Mont::unsafe_make( [ 0xbe457b0c , 0x2c2e6e89 , 0xe2208347 , 0x2d5a8b17 , 0x94773bf3 , 0xa137838c , 0x7233176f , 0x1a9fdd4d ] )
+  , Mont::unsafe_make( [ 0x47ce7e5e , 0xcec01334 , 0x20ede876 , 0x039c582f , 0x0a795aa8 , 0xf1177cb8 , 0x1e621afa , 0x0d7c88c7 ] )
+  , Mont::unsafe_make( [ 0x266ef344 , 0xee458cd0 , 0xe7cb5042 , 0x12699581 , 0xffa80d6a , 0x518e3327 , 0xcef42d2b , 0x2524c995 ] )
+  , Mont::unsafe_make( [ 0x39221819 , 0x6922f48d , 0x2c38dd01 , 0x3edc66cc , 0x694dd89e , 0x36138848 , 0x1171dc92 , 0x23e30891 ] )
+  , Mont::unsafe_make( [ 0x97107dd4 , 0x05490cef , 0xb10f7d1d , 0xaae142e8 , 0xf2f6313a , 0xa0f8fa41 , 0x73e864dd , 0x0112a52b ] )
+  , Mont::unsafe_make( [ 0xc1e57f62 , 0x12ffb3ab , 0xfadb647f , 0xd7d4523b , 0xec280e30 , 0xc6667539 , 0x40b33b74 , 0x1d0e5ef5 ] )
+  , Mont::unsafe_make( [ 0xe9fd7ed9 , 0x6341958f , 0x4f8580e0 , 0xefde168d , 0xa669fb7f , 0x4be9e27d , 0x6cd533a5 , 0x1afcd6be ] )
+  , Mont::unsafe_make( [ 0xa75e878c , 0x16b3c5a7 , 0x378e50f6 , 0xcb721bd7 , 0x6e2b8178 , 0xdc2be108 , 0x037c8de2 , 0x0569bb5e ] )
+  , Mont::unsafe_make( [ 0x057a566e , 0xb52a3b38 , 0xf7c408ba , 0xf897c3f7 , 0x03419684 , 0x1fdd5bb9 , 0x5a2b936f , 0x0c32f3b9 ] )
+  , Mont::unsafe_make( [ 0x57e98478 , 0x1ce5a878 , 0x45e615c5 , 0x7fa733cb , 0x4011600e , 0x31e33507 , 0x762e9121 , 0x2903a15a ] )
+  , Mont::unsafe_make( [ 0xf88a1a6a , 0xc7d68ecb , 0xe8af6775 , 0xd7478a0e , 0xccfdc8b0 , 0xbb52d44e , 0x06653e87 , 0x186bf67a ] )
+  , Mont::unsafe_make( [ 0x894574dc , 0x388e19c4 , 0xb1d206fd , 0xb1cf901a , 0x264d6c48 , 0x633a9bdc , 0x3c43d6ed , 0x2fab8745 ] )
+  , Mont::unsafe_make( [ 0x9f208ea7 , 0xa4de1ca9 , 0x7bcabfcc , 0x271c48d2 , 0xcd31bdc5 , 0x28565f60 , 0x83cc5759 , 0x01e6a771 ] )
+  , Mont::unsafe_make( [ 0x43acf617 , 0x1b759207 , 0xf65eb471 , 0x5a6e124d , 0xec615707 , 0xa515491a , 0x3e066df3 , 0x2c113ebc ] )
+  , Mont::unsafe_make( [ 0xf207964f , 0x201ad386 , 0xc886d9ea , 0x60872bbb , 0xc615ffb5 , 0x88adf1eb , 0x666210c7 , 0x2bbf819f ] )
+  , Mont::unsafe_make( [ 0x45b88a5e , 0xb9a8b5d9 , 0x4940c59b , 0xee2e55dc , 0x6dac4e97 , 0xe4621fdc , 0x87a4f465 , 0x2b83aee3 ] )
+  , Mont::unsafe_make( [ 0xbd16de5d , 0x167f7ec6 , 0x9db65a61 , 0xbb4a6b2a , 0x96f04c8f , 0x78ecb9b1 , 0x280045ac , 0x142a6aa0 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x91d0cd93 , 0x9de8254f , 0xca7a8d23 , 0xcdce1a57 , 0xbc1e84ef , 0x4cc49bfa , 0x4226683d , 0x274c75e1 ] )
+  , Mont::unsafe_make( [ 0xafd91172 , 0x82b0d879 , 0xa913b42a , 0xeff25a71 , 0x3009e17d , 0x94e9b9ad , 0xdffa0288 , 0x1ca67f1a ] )
+  , Mont::unsafe_make( [ 0xa9af7f04 , 0x323d51c6 , 0x774677d0 , 0x3e263234 , 0xd372ccde , 0x3e526000 , 0xa8b94328 , 0x07a138a7 ] )
+  , Mont::unsafe_make( [ 0x8b414b1f , 0x735e7ec4 , 0x24b827f2 , 0x0479c17c , 0xb548b170 , 0x9662948c , 0xce192305 , 0x01fda873 ] )
+  , Mont::unsafe_make( [ 0xb719df45 , 0x48abd998 , 0xfd993eff , 0x9cced087 , 0x6f1e95b1 , 0x3fa14229 , 0x5999804f , 0x1db14af6 ] )
+  , Mont::unsafe_make( [ 0x74b67c45 , 0xabd252b4 , 0x4ab7db93 , 0x9f49b273 , 0x1e757ab8 , 0x7bcabec1 , 0x9dabcb9c , 0x0473c1ec ] )
+  , Mont::unsafe_make( [ 0xe920ba09 , 0x93503cfb , 0x53da6442 , 0x02c962c5 , 0xa0244f66 , 0x4691b3cb , 0x11f5afed , 0x28bc5bb3 ] )
+  , Mont::unsafe_make( [ 0x3c77a8de , 0xad9bb101 , 0x21a6654b , 0x314ddb9c , 0x232936ec , 0xa28b2297 , 0x6842e38d , 0x15234ea3 ] )
+  , Mont::unsafe_make( [ 0x3484fad8 , 0x2bee8b7d , 0x193e46ec , 0x82ed2697 , 0xef6e6f11 , 0xfd4f0d29 , 0xf0e560c8 , 0x0d1bbaf3 ] )
+  , Mont::unsafe_make( [ 0x111796bc , 0xb56d4ef7 , 0xe0cd22db , 0x5e9f563b , 0x2ed8822e , 0x3f9e435d , 0xc28ffc2d , 0x117db184 ] )
+  , Mont::unsafe_make( [ 0x16af9deb , 0x978b7cda , 0x440f7f9d , 0xf8fcd3a1 , 0xe55d49a8 , 0x3ed5019e , 0x1e77e6e1 , 0x161b9c55 ] )
+  , Mont::unsafe_make( [ 0x0e354c26 , 0x795045d1 , 0x4623b6ab , 0xa2b1529a , 0xa8e77317 , 0xa1b6b627 , 0xf5d26522 , 0x1d0202b5 ] )
+  , Mont::unsafe_make( [ 0x2e45c02a , 0xd0bc15d5 , 0x6d2d5524 , 0xa3dfc16a , 0xd5b2182c , 0x4ff56435 , 0xa7cebeac , 0x2ffd5532 ] )
+  , Mont::unsafe_make( [ 0x62f7f8bb , 0x55d35b60 , 0xa48b70b6 , 0xd098df99 , 0x491c3298 , 0xb0b82330 , 0x64e2eed4 , 0x111a970e ] )
+  , Mont::unsafe_make( [ 0x1b16bd87 , 0x39de0c9b , 0xfde4df16 , 0x2d147fb6 , 0xe7f35167 , 0xdcfcd69c , 0x9f1f5bdf , 0x0e41bd57 ] )
+  , Mont::unsafe_make( [ 0xfb0887cb , 0x9fb32fc3 , 0x5d4bfdce , 0x5c0b1be3 , 0x55a742db , 0xce15376a , 0xff38183b , 0x2447c587 ] )
+  , Mont::unsafe_make( [ 0x623dde24 , 0x063d3521 , 0x2175ab84 , 0x9909d9d7 , 0x52d2186f , 0xf954dc3c , 0x15240454 , 0x12062c1d ] )
+  , Mont::unsafe_make( [ 0x457d09aa , 0x5db6690a , 0xa0a689dc , 0x11484506 , 0x03a7dceb , 0x3d7dd187 , 0x5b2e44b5 , 0x07e33636 ] )
+  , Mont::unsafe_make( [ 0x229485fb , 0x8b476ceb , 0x5a174b98 , 0x79478bcd , 0xaeb6d4ca , 0xa0dc36b6 , 0x48f768a7 , 0x12863e90 ] )
+  , Mont::unsafe_make( [ 0xc40f98ab , 0xa2ed5cd3 , 0xed85757c , 0x65c497af , 0x9b4f72bc , 0x1ac4c5e7 , 0xf19eca45 , 0x077bb29e ] )
+  , Mont::unsafe_make( [ 0x5aba7a8b , 0xe02aba0c , 0x2f299540 , 0x14e01369 , 0x9db12ee9 , 0x16fa628f , 0xd0c5b87b , 0x2ae99a7b ] )
+  , Mont::unsafe_make( [ 0x70b130cb , 0x74f8553f , 0xe9be4d2a , 0x57f3003b , 0x1b701768 , 0xaef7286c , 0xe25858b4 , 0x205e5571 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x48414cd7 , 0x1f604995 , 0xa8293f7b , 0x6299010f , 0x94adb88d , 0x91ae4420 , 0x4851559c , 0x302ac953 ] )
+  , Mont::unsafe_make( [ 0x8327ccdc , 0xb886303d , 0x847c9509 , 0x002a857f , 0x3bdf2689 , 0xad5528b3 , 0x1a4a7ccd , 0x025e73dd ] )
+  , Mont::unsafe_make( [ 0x41ba7845 , 0x7263ef2b , 0xcc808d82 , 0x6c9fd751 , 0x24e1bab6 , 0x479d2a6b , 0x516e5057 , 0x007cf6ad ] )
+  , Mont::unsafe_make( [ 0x3a318b52 , 0xd5bbc41c , 0x1156973f , 0x34412cb3 , 0x76f7deed , 0xc602278d , 0x947f95ed , 0x16f3d2a9 ] )
+  , Mont::unsafe_make( [ 0x90c6a0fa , 0x74f39b3d , 0x3309afb9 , 0xe95c2cff , 0x2db56a59 , 0x31817198 , 0x24f50aad , 0x30117fc7 ] )
+  , Mont::unsafe_make( [ 0xbab98f1c , 0xd00c68ea , 0x1575939e , 0x515bf1cf , 0xef02c19d , 0x0a314ab6 , 0xb6bedf8d , 0x06ab398d ] )
+  , Mont::unsafe_make( [ 0x18205bf9 , 0xb7d8c27b , 0x5614ffa2 , 0x9c4664ff , 0x1dc60715 , 0x85c5f7f4 , 0x25a47095 , 0x1428b14c ] )
+  , Mont::unsafe_make( [ 0x4839252a , 0x0ce3b88c , 0x1a517647 , 0xa8df9907 , 0xffe95e3f , 0x67b98cc8 , 0x97175bf4 , 0x03c2c522 ] )
+  , Mont::unsafe_make( [ 0xaf5a454d , 0x513d7e62 , 0xfd04c6b8 , 0xf6ddad87 , 0x1ebe32a4 , 0x2ad76b5b , 0xad73b267 , 0x16288a4a ] )
+  , Mont::unsafe_make( [ 0x7595f077 , 0x0b3dcea2 , 0x8776e922 , 0x4aad0b50 , 0xc6ba1bd8 , 0xa9d7bf7d , 0x490ffa05 , 0x2b39eccd ] )
+  , Mont::unsafe_make( [ 0x820707d7 , 0x13a44da1 , 0x32dd6ff6 , 0x47aac586 , 0x81679c11 , 0x0fd19a7e , 0x482c7bff , 0x0653d9d6 ] )
+  , Mont::unsafe_make( [ 0x98642978 , 0xc26c7140 , 0xf09054c4 , 0x504cb297 , 0x52bfaecd , 0xa096ba2c , 0xc4ac2896 , 0x2f467432 ] )
+  , Mont::unsafe_make( [ 0x06611377 , 0x5e283141 , 0x085f6b1e , 0x4bc95678 , 0x4d19ae59 , 0xd9ad7469 , 0x80dcd5ec , 0x1d6412e8 ] )
+  , Mont::unsafe_make( [ 0x806fac42 , 0x98781253 , 0x4e07a60b , 0xae06f58f , 0x9a8cb1fe , 0x3819acec , 0x7b36f2d6 , 0x2ad4ed73 ] )
+  , Mont::unsafe_make( [ 0xac982364 , 0xa9e3f224 , 0xcb2dd268 , 0x2abec792 , 0x35e63ae7 , 0x760085df , 0xddeb9e54 , 0x1492cb15 ] )
+  , Mont::unsafe_make( [ 0x9fe765aa , 0xd810f737 , 0xb3178ea3 , 0x61bb03fb , 0x3450834c , 0xcf8d13e3 , 0xbb6e30bf , 0x11e74e47 ] )
+  , Mont::unsafe_make( [ 0x69c192f7 , 0x08af9dc6 , 0xd2cd101e , 0xe1e7075e , 0xdae3d0d0 , 0x3c832fb6 , 0x3f489c27 , 0x14856efa ] )
+  , Mont::unsafe_make( [ 0xddd5636a , 0x028f6188 , 0x79149604 , 0x984d4d64 , 0x29ba8052 , 0x62d7cdde , 0x5b98507f , 0x21379c06 ] )
+  , Mont::unsafe_make( [ 0xd0af0eed , 0x7e5978dd , 0x74b611c2 , 0x5114c3f6 , 0x541326fb , 0x29c6d8db , 0x19c689ff , 0x28424b52 ] )
+  , Mont::unsafe_make( [ 0x7508def5 , 0x6f6147a0 , 0x484d632f , 0xab74a047 , 0x22d6d035 , 0xf937f620 , 0x01db1523 , 0x24ea3aeb ] )
+  , Mont::unsafe_make( [ 0x9a5cd923 , 0x0b2d7edf , 0xd7d4460c , 0x74ab14f6 , 0x83874e5a , 0xd1f5dc10 , 0xbc7ca61c , 0x176980d6 ] )
+  , Mont::unsafe_make( [ 0xdf269636 , 0xc89c000f , 0xe00f6bc1 , 0x7313e6a8 , 0x0bc5c6de , 0x01727880 , 0x1a03b246 , 0x24838c3d ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xa6f581dc , 0x83d5f5a3 , 0xd9556ad5 , 0x11ae0250 , 0xfca22f3f , 0xb366cb6d , 0xcfa839d7 , 0x21527a33 ] )
+  , Mont::unsafe_make( [ 0xc40bbe49 , 0xe64f42f9 , 0x71bd3abd , 0xae5b5a77 , 0x5af6778c , 0x4624afeb , 0x3bb31d79 , 0x1ec5003f ] )
+  , Mont::unsafe_make( [ 0x9c395d29 , 0x60b47e3d , 0xf92ae56c , 0x38dbbf7e , 0x4967503b , 0xee339856 , 0x84e412b6 , 0x002dc31f ] )
+  , Mont::unsafe_make( [ 0x9ec91f30 , 0x3c5c24bb , 0x5238b214 , 0x99beef8c , 0x9504a979 , 0xb1f441e1 , 0xd891ea04 , 0x0040a8d8 ] )
+  , Mont::unsafe_make( [ 0x5585a30b , 0xd1e4b0ae , 0x357b011a , 0xe9ba0246 , 0x5e3c0fff , 0x8155aee6 , 0x8f3c700f , 0x1de47adc ] )
+  , Mont::unsafe_make( [ 0x19d934cf , 0xf56361e3 , 0x6f4345f9 , 0x5cd065be , 0x1885dab4 , 0xf756fa9d , 0x546f770b , 0x0e205429 ] )
+  , Mont::unsafe_make( [ 0x0efeb641 , 0xad3278e5 , 0xbdd724d2 , 0x43b66b06 , 0xab3bdc30 , 0x780ecb29 , 0x473fb27d , 0x0b31dd58 ] )
+  , Mont::unsafe_make( [ 0xad008556 , 0xa40668b2 , 0x79b4e1cd , 0x8026b466 , 0x4110e007 , 0x906c9cfb , 0x846b41d8 , 0x02fea880 ] )
+  , Mont::unsafe_make( [ 0xd3c2a900 , 0xe0653036 , 0x8464d253 , 0x6a6aa555 , 0xaaf57feb , 0xaeda5b2f , 0x4b5866d2 , 0x0544f7c9 ] )
+  , Mont::unsafe_make( [ 0xc8d202c6 , 0x25700983 , 0x2e6c9935 , 0xdf8c995d , 0xf2f4fc81 , 0xea56da25 , 0x858493c7 , 0x0bdeccf5 ] )
+  , Mont::unsafe_make( [ 0x3e14078e , 0x42a89b1f , 0xe1451f9a , 0x3c2d3c85 , 0x6d647eaa , 0xc3a5a19e , 0xa98522da , 0x264d373e ] )
+  , Mont::unsafe_make( [ 0x307a0ded , 0xa2334624 , 0xac66d4d1 , 0x6efa167d , 0x14c7d6d6 , 0xeca17b4c , 0x8763baab , 0x1cddfe4f ] )
+  , Mont::unsafe_make( [ 0xf3dc2a39 , 0xfd5a4629 , 0x2526b502 , 0x8d5ab790 , 0x8aea0b29 , 0xfef5e0d6 , 0x91301748 , 0x0e23a84e ] )
+  , Mont::unsafe_make( [ 0x71e33684 , 0x60945117 , 0x8eced6f4 , 0x0f59d082 , 0xb6931efe , 0x207656d1 , 0x7c1233d9 , 0x1f88c6ab ] )
+  , Mont::unsafe_make( [ 0x09c3a23c , 0x233297e0 , 0xf1f51c5c , 0x6130ccd6 , 0xdc7e95c7 , 0xd9fa94d7 , 0xe7231262 , 0x01636a8b ] )
+  , Mont::unsafe_make( [ 0xd9686ce6 , 0xedaf3f60 , 0x96d90df9 , 0xcc90e1bb , 0x8f22174c , 0xf5664ae9 , 0x37fe7df1 , 0x2e43448f ] )
+  , Mont::unsafe_make( [ 0xcde55b38 , 0xc9f05993 , 0x3bcb3caf , 0x304f6de2 , 0x81f5e00f , 0x92c91f55 , 0xa9c08aa6 , 0x27c34224 ] )
+  , Mont::unsafe_make( [ 0x83e96043 , 0x008938a3 , 0x62567403 , 0xdc183c75 , 0xbcfa1b13 , 0x7f260045 , 0x30ac1ed4 , 0x26227a15 ] )
+  , Mont::unsafe_make( [ 0x74088b21 , 0x91e24ce0 , 0x3753c31a , 0xf74d1a97 , 0xdadb7efa , 0xceea32f4 , 0x63a16c89 , 0x201964a6 ] )
+  , Mont::unsafe_make( [ 0x5e207841 , 0xbbce2091 , 0xcd906401 , 0x8547a8cb , 0xb0f0a275 , 0x468556b3 , 0xea8b0610 , 0x15a8f453 ] )
+  , Mont::unsafe_make( [ 0x78edf4c6 , 0x06b23b0d , 0xac3d33d8 , 0x31d52603 , 0x0ccad44d , 0x3510c77f , 0xf03b2dc0 , 0x1f369f62 ] )
+  , Mont::unsafe_make( [ 0x2fb4e9d8 , 0x882913c3 , 0x8747ac7d , 0x0d75b8c8 , 0x15b47436 , 0x4c79d10f , 0x5cc20f25 , 0x2762f6e7 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x5ee1ce34 , 0x98bb6531 , 0xc84e74ab , 0x07153d59 , 0x9daafd89 , 0xeb81e316 , 0x1d93d737 , 0x253aed77 ] )
+  , Mont::unsafe_make( [ 0x3763af77 , 0xd0f5e910 , 0x5df19a8b , 0xe7517d39 , 0xd4579298 , 0x03ea2fc0 , 0x2493b660 , 0x265f5acf ] )
+  , Mont::unsafe_make( [ 0x112f8377 , 0x51d725d9 , 0xfffbbde2 , 0xc774807f , 0xabe8ca1b , 0x36446db9 , 0x0cad73c4 , 0x29c8c871 ] )
+  , Mont::unsafe_make( [ 0x5110f765 , 0xd470ec0b , 0xb29834f7 , 0x4bd85d26 , 0x2768ee7f , 0x436cdec4 , 0x16ac283d , 0x2d5d7ac0 ] )
+  , Mont::unsafe_make( [ 0xcec3b6e3 , 0xaa69f1ae , 0x1a977653 , 0xf61fe9ba , 0x618525b4 , 0x56332387 , 0xfa8d6740 , 0x08dac2bf ] )
+  , Mont::unsafe_make( [ 0x6a7dd674 , 0x3a78a67a , 0xb7153fd6 , 0xb6e335c5 , 0xd11c73b9 , 0xa332bcf4 , 0x40d8eb6b , 0x2a03fc7c ] )
+  , Mont::unsafe_make( [ 0x9ba33730 , 0x8f4a54fb , 0x4af036ef , 0xc4f77d66 , 0x20c731e9 , 0xb2da5732 , 0x19fe942a , 0x01603362 ] )
+  , Mont::unsafe_make( [ 0x4fa86bac , 0xc9d3cc58 , 0x992e1f64 , 0x5c5d9c52 , 0x46f7724e , 0x463b0c2e , 0x01075f33 , 0x1831172a ] )
+  , Mont::unsafe_make( [ 0xf0a5b360 , 0xf30fbce8 , 0x4bcd25d8 , 0x84531bb8 , 0x5f456e2b , 0x34a325a4 , 0x824df429 , 0x1c17ace4 ] )
+  , Mont::unsafe_make( [ 0xfa9f2528 , 0x97ed44df , 0x42ed6ebe , 0xd9168ad2 , 0xc12eadaf , 0xbfde7f4c , 0xe5ba675b , 0x10f14ea3 ] )
+  , Mont::unsafe_make( [ 0xee6a6eac , 0x59cfc9f8 , 0x6f119445 , 0x4fe071e3 , 0xe02a5867 , 0xe0ab54dc , 0x5adc9ac7 , 0x009674ce ] )
+  , Mont::unsafe_make( [ 0x5c91c605 , 0x58a09383 , 0x307a81a7 , 0x03685393 , 0xde304cc1 , 0x139d7604 , 0xcf2d09a3 , 0x1fcfb94d ] )
+  , Mont::unsafe_make( [ 0x4aa0ab0e , 0x86f5e525 , 0x96a26345 , 0x37b775f2 , 0xa2b53a51 , 0xe172e2c5 , 0xa3870c97 , 0x09679e4b ] )
+  , Mont::unsafe_make( [ 0x7ac1bc7d , 0x1ba4a7f4 , 0xc78d33f1 , 0xd20bfd6f , 0x4379475c , 0x18ffb84d , 0x8fb88366 , 0x025facc9 ] )
+  , Mont::unsafe_make( [ 0x212c56a9 , 0xe4a8fe7f , 0x0889794f , 0xa8006957 , 0xaa440720 , 0x95d7bf8e , 0x7f395776 , 0x13aa29e3 ] )
+  , Mont::unsafe_make( [ 0x664de5ae , 0x7c81a400 , 0x6acd596e , 0x78b4d764 , 0xa07ea780 , 0x0ecb294f , 0x63b1594e , 0x1a666a43 ] )
+  , Mont::unsafe_make( [ 0x6c9bee50 , 0x7411f5aa , 0x784b8266 , 0x1e7e17ba , 0x3ef80bfd , 0x17c97f6c , 0x171b2082 , 0x292226fa ] )
+  , Mont::unsafe_make( [ 0xa4b0de3b , 0xc1c54c6b , 0xa1a253b5 , 0xace3c33b , 0x9881762e , 0xf9555ccc , 0x53a8d583 , 0x30343c12 ] )
+  , Mont::unsafe_make( [ 0x864b1228 , 0x357c625d , 0xb089d011 , 0x74be8c2f , 0x68574c86 , 0xde3cd254 , 0x8d2c0923 , 0x00b167fc ] )
+  , Mont::unsafe_make( [ 0x6dee8d25 , 0x935ea5cd , 0x22d865ac , 0xef59495b , 0x10a66b2a , 0x1c1105f3 , 0xe8945c16 , 0x0f8c7c63 ] )
+  , Mont::unsafe_make( [ 0x1f95cdf0 , 0x5d959434 , 0x6224905e , 0xd619c8db , 0x48e3aef7 , 0x9e67ea9c , 0xde5860ab , 0x2a1c4f17 ] )
+  , Mont::unsafe_make( [ 0xcccb8d40 , 0x7f77242c , 0x1a290bca , 0xa8dc2e87 , 0xee9c783f , 0x8153a0e6 , 0x247c633c , 0x15c9e421 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xf60b0a49 , 0x6a46ee09 , 0x0e4008e2 , 0x6f5cf608 , 0x2650914a , 0x9c6302a7 , 0x3e33b80c , 0x24e73953 ] )
+  , Mont::unsafe_make( [ 0xb20fae3c , 0x77bfe2b3 , 0xfdff3669 , 0x8aa781e4 , 0x513ba3fb , 0x3ae43d3a , 0xe595447a , 0x28f3ee7c ] )
+  , Mont::unsafe_make( [ 0xab5968af , 0x093279b4 , 0x7c9efa81 , 0x74b4322f , 0xbb96ffd9 , 0x32673456 , 0x09851675 , 0x04ca465f ] )
+  , Mont::unsafe_make( [ 0x5edff149 , 0xdb5de674 , 0x11429b0f , 0x8443eea7 , 0x314fd61b , 0xa4279ab7 , 0xf6eeed5e , 0x222aa944 ] )
+  , Mont::unsafe_make( [ 0x6eb0e19d , 0xe11ae3c6 , 0x44915d08 , 0xd714778d , 0x28952a6f , 0x02c53bbf , 0x195a93cb , 0x0098e2e9 ] )
+  , Mont::unsafe_make( [ 0xc7d3254c , 0x7fbe8819 , 0x42d2dffa , 0xf939d532 , 0xf4d57e11 , 0x7551f087 , 0x5ff2e238 , 0x2b64eacb ] )
+  , Mont::unsafe_make( [ 0x9a5b006f , 0x06a64939 , 0x2e502d80 , 0x74b78fb6 , 0xdbe4ea28 , 0x4560cba4 , 0x83eda163 , 0x11550df5 ] )
+  , Mont::unsafe_make( [ 0x2f09646d , 0x15feb430 , 0x3ac643e1 , 0x7703f9c7 , 0x91e9d405 , 0xa17e65fa , 0x787747af , 0x149f36c5 ] )
+  , Mont::unsafe_make( [ 0xaffe333b , 0x3c46d3e1 , 0xef78d5a4 , 0x5d092467 , 0x0a784f6d , 0x4b4de39e , 0x99098885 , 0x274d8a03 ] )
+  , Mont::unsafe_make( [ 0x5ddc04a4 , 0xaac7edbd , 0x08919436 , 0x3fa95e08 , 0xe94a611f , 0xa218a67e , 0x1ffb2f58 , 0x1a4cbd62 ] )
+  , Mont::unsafe_make( [ 0x04efe9df , 0xc1a4f8a7 , 0x31a280f9 , 0xa1723256 , 0x702123e9 , 0xbe5c648e , 0x4e9e7493 , 0x0a551563 ] )
+  , Mont::unsafe_make( [ 0x795fa742 , 0xcccabccc , 0x0ea3289f , 0x211f7c11 , 0xfbaa9c26 , 0xfa559a3a , 0x501be838 , 0x0568a197 ] )
+  , Mont::unsafe_make( [ 0x70c4327e , 0x011003b3 , 0xbd061252 , 0x6c866f39 , 0xd1806505 , 0xd8a7b29a , 0xa6e320ec , 0x142bec3b ] )
+  , Mont::unsafe_make( [ 0x3c03f476 , 0xf4a888c5 , 0x1727d4c8 , 0x8b8744ef , 0x1579117f , 0x530618ad , 0xb39bf47d , 0x0ec81737 ] )
+  , Mont::unsafe_make( [ 0xfa1e6083 , 0x4d68350f , 0x4c80e791 , 0x39dfe99d , 0xd57a8343 , 0x75b1aabb , 0xf22150d6 , 0x300bba25 ] )
+  , Mont::unsafe_make( [ 0x675820cb , 0xb8a6ecf2 , 0xea59a37d , 0xb628eba6 , 0x7e3bc6ca , 0xb8b917e4 , 0xa3d70a69 , 0x1aaa518d ] )
+  , Mont::unsafe_make( [ 0x532b9610 , 0x5ef2b825 , 0xd51aa425 , 0x4adae683 , 0xff94a7c9 , 0x712871ec , 0x4de102f1 , 0x03aa925c ] )
+  , Mont::unsafe_make( [ 0x2b7b25ea , 0xbb39c68a , 0x3e7f8560 , 0xbeaf274b , 0x5255b024 , 0x32682f34 , 0x03b92098 , 0x038250fd ] )
+  , Mont::unsafe_make( [ 0x293cc9de , 0x4a1f6455 , 0x7806d1b0 , 0x47e1c4bb , 0x83bd85a8 , 0x9ba659ff , 0x267d8f76 , 0x088dc8df ] )
+  , Mont::unsafe_make( [ 0x6e543222 , 0x05550dd7 , 0xd721ebf1 , 0xc1bad02e , 0x6f84e392 , 0x0b6522c7 , 0xf2d4154d , 0x239a1e2b ] )
+  , Mont::unsafe_make( [ 0xf7fe2c79 , 0xf9f2249e , 0xf432cd8d , 0x77126c70 , 0x498f986c , 0xe42170af , 0xffd7bbf5 , 0x2d77ad8a ] )
+  , Mont::unsafe_make( [ 0x0b7435fd , 0x940967ae , 0x655770f4 , 0xb9ae53c5 , 0x3e42d515 , 0xcdc2d6e8 , 0x4555b561 , 0x2696518f ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x3a37ae05 , 0x7f796d09 , 0xdcc8312d , 0xda610175 , 0x2aaf4d90 , 0x64c359c4 , 0x3c4f998e , 0x287121dc ] )
+  , Mont::unsafe_make( [ 0xdf3b8236 , 0x0f265156 , 0x54612d15 , 0x49814faa , 0x6f4a96b6 , 0x011e2732 , 0x4a2e4190 , 0x15a360ee ] )
+  , Mont::unsafe_make( [ 0xcfa1726a , 0xf5099409 , 0x24fea674 , 0x75f17f88 , 0xd93a479f , 0xea5eab20 , 0x2df85af1 , 0x008ca005 ] )
+  , Mont::unsafe_make( [ 0x5463dc87 , 0xe278ed55 , 0x78844ab8 , 0xf805f63c , 0x22693b3b , 0xd6044a7b , 0x3e36bc22 , 0x018704d1 ] )
+  , Mont::unsafe_make( [ 0x6b405f74 , 0xcba41cc9 , 0x83f759be , 0x68f43f7b , 0x75444bfe , 0xa7a18810 , 0xea09df35 , 0x017c4f4e ] )
+  , Mont::unsafe_make( [ 0x7c5d52b2 , 0x5d48aebd , 0x046c6f2f , 0x2e8f97dd , 0x7bbef5c6 , 0x4add19d6 , 0x063a8226 , 0x2bf9282b ] )
+  , Mont::unsafe_make( [ 0xa62acca3 , 0x07784832 , 0x2db44cf0 , 0x13e4b898 , 0x1ee3f440 , 0x1256e23c , 0x4262b127 , 0x07f7e36a ] )
+  , Mont::unsafe_make( [ 0x98b26d7b , 0x6e44f6a0 , 0xed607ec7 , 0xf27542fe , 0x8db3f28a , 0xa31c739f , 0x134845f8 , 0x17c9c809 ] )
+  , Mont::unsafe_make( [ 0xcf9e7bcc , 0x3bdafc54 , 0x629272df , 0x96c48607 , 0x39946886 , 0xbf37b982 , 0x9bcf462d , 0x20bde924 ] )
+  , Mont::unsafe_make( [ 0x0ef10fab , 0x8b9bcc36 , 0x39934edb , 0x330a2eb8 , 0xecdc59de , 0x7e59819e , 0x93b3d576 , 0x23db5be9 ] )
+  , Mont::unsafe_make( [ 0xdec34a0d , 0xfaa782bb , 0xd5a2cf2b , 0x6f42f0d4 , 0x22dcd70c , 0xdb6f53bb , 0x5071983e , 0x0ce87089 ] )
+  , Mont::unsafe_make( [ 0x4708a68c , 0x7aab23d7 , 0x52af4b21 , 0xdddef260 , 0x6318c486 , 0x1b8bf7c0 , 0x2477d8a1 , 0x1de262b8 ] )
+  , Mont::unsafe_make( [ 0xac577a07 , 0x02e2982d , 0x1b6e64a7 , 0xfd132ad6 , 0x9b4ae8df , 0x3adeb83c , 0xb4f7aca8 , 0x0e6db422 ] )
+  , Mont::unsafe_make( [ 0x13dcba22 , 0x9a0577bc , 0x47ff7c74 , 0x24ac5be7 , 0xae0a9308 , 0xf03bcda3 , 0xb25df17e , 0x128068ab ] )
+  , Mont::unsafe_make( [ 0x591c32b7 , 0x8f4c0aff , 0x735ece39 , 0xc03b5e34 , 0x44d95ed3 , 0xa5a6e2e9 , 0xa4deee24 , 0x21ba52f8 ] )
+  , Mont::unsafe_make( [ 0x4b0381a7 , 0x33739535 , 0x980e8857 , 0x6bceada4 , 0xf6f8bdec , 0x61898da9 , 0x5db77dcf , 0x2bc20913 ] )
+  , Mont::unsafe_make( [ 0x8d59de28 , 0x953a4933 , 0x398b642b , 0x6e1983d4 , 0xf2b29c78 , 0x10575168 , 0xe977e26e , 0x01e93210 ] )
+  , Mont::unsafe_make( [ 0xf5a3b047 , 0x1b3110a0 , 0x5100d309 , 0xe95fd19a , 0x861c1aac , 0x04e77d1a , 0x99d024a4 , 0x1c2212f4 ] )
+  , Mont::unsafe_make( [ 0x7cd57ddb , 0x8d241801 , 0x006cbfbc , 0x996e4225 , 0x9060bf37 , 0x70236753 , 0x066a59d9 , 0x2ce64bed ] )
+  , Mont::unsafe_make( [ 0xf500c08b , 0x43c407b7 , 0x3d70afa2 , 0x5ef714be , 0x54c1db85 , 0x93d7a865 , 0x0ad6976e , 0x01f5a48b ] )
+  , Mont::unsafe_make( [ 0x5f7ab377 , 0x7a47317e , 0x301a78f0 , 0xb81ffb00 , 0xdab0cc8b , 0x1552a570 , 0x517be39a , 0x1bedf27e ] )
+  , Mont::unsafe_make( [ 0x74b99341 , 0x45358845 , 0xa75f5778 , 0x04bffbd4 , 0x689db7df , 0x49a2e8e7 , 0x4135934c , 0x146d8d49 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x2f2f4bb1 , 0x9370cf4b , 0xda8a293b , 0x0d1e0361 , 0x1e414b9c , 0x81086b49 , 0xc1640164 , 0x236edbaf ] )
+  , Mont::unsafe_make( [ 0xa5f3e3b6 , 0xe09bd62e , 0x21cc0899 , 0xb500060e , 0xff48b965 , 0xd0bb5149 , 0xa7e318f5 , 0x0acb700c ] )
+  , Mont::unsafe_make( [ 0xdc73988a , 0xe9440c0a , 0xb492722e , 0x7535d49b , 0x6b7129a3 , 0x386d6a4e , 0x6ac8d1e4 , 0x03f9ece6 ] )
+  , Mont::unsafe_make( [ 0x567c308e , 0xc24e9be5 , 0xa98a0b06 , 0x2bd4350b , 0x41878aab , 0xf2bb54b9 , 0x9f544688 , 0x28687f6e ] )
+  , Mont::unsafe_make( [ 0x9de01640 , 0x5ba9c183 , 0x81646f8f , 0x00f0d6ea , 0x61ea1f37 , 0x56818181 , 0x0e85c8c3 , 0x0d1d2b44 ] )
+  , Mont::unsafe_make( [ 0x9dec203a , 0x3e48698c , 0x917ef4b9 , 0x3765f1f6 , 0x5814df5a , 0xd68088a6 , 0x2bce2317 , 0x06c62d6c ] )
+  , Mont::unsafe_make( [ 0xa525f721 , 0x5d53ce84 , 0x337c3e68 , 0x8a21304a , 0x325bcc8c , 0xd11aae70 , 0x75d528d1 , 0x02024a63 ] )
+  , Mont::unsafe_make( [ 0x32d05a5c , 0x96bb2d2c , 0xffde76de , 0x2722ca8a , 0xe2dccbd3 , 0x24a83ba9 , 0x30273544 , 0x2c72d976 ] )
+  , Mont::unsafe_make( [ 0x89d0d774 , 0x9a58ef93 , 0x241d44c4 , 0x71f138c4 , 0xd5129649 , 0x1a3af83a , 0x7f53daba , 0x27d5f7eb ] )
+  , Mont::unsafe_make( [ 0xdb51504d , 0x450e4d8a , 0x27539066 , 0x15e9f06d , 0xdac8ec39 , 0x9b8ff64c , 0x02f2f592 , 0x0ef068ac ] )
+  , Mont::unsafe_make( [ 0xf79389b7 , 0x53c45c5c , 0xda33b860 , 0xe33e0f86 , 0xfd9b9d71 , 0x8e6e509d , 0x766b7563 , 0x12bac9f3 ] )
+  , Mont::unsafe_make( [ 0xc7640b09 , 0x7c563b73 , 0xaf80d9e0 , 0x89c2daf3 , 0x6ac56d29 , 0x1832a247 , 0xb2f8a827 , 0x07de622c ] )
+  , Mont::unsafe_make( [ 0x8a288899 , 0xb1b72575 , 0xc0933085 , 0xb3f9fa9a , 0x7bb70cd2 , 0x8caa5116 , 0x31dc67d8 , 0x029e3bb0 ] )
+  , Mont::unsafe_make( [ 0xa184dcd4 , 0x23bfba3a , 0x16dd36b6 , 0x9b191267 , 0x83925bdb , 0xf386612b , 0x733af4e5 , 0x019d5899 ] )
+  , Mont::unsafe_make( [ 0xe2ac4978 , 0x654299e1 , 0x892d6f6b , 0x5219bb5c , 0xccb196f0 , 0x92191df9 , 0x0672698c , 0x17ec1055 ] )
+  , Mont::unsafe_make( [ 0xb379c46b , 0xfdc4a69f , 0xa209b028 , 0x619553cc , 0xfaed71d2 , 0xfa9216c7 , 0x9ca56fe1 , 0x114500aa ] )
+  , Mont::unsafe_make( [ 0xd2dc3512 , 0x8ac39113 , 0x4d853c76 , 0xc975e56d , 0xe3fba809 , 0x5cf66a91 , 0x871bd222 , 0x29b9cc47 ] )
+  , Mont::unsafe_make( [ 0xadb4f3a9 , 0x5cc2307a , 0x55a8d69b , 0x0dbe731c , 0xe87ef516 , 0xd99b77a0 , 0x09137cff , 0x0170556d ] )
+  , Mont::unsafe_make( [ 0x834d5519 , 0x3ab0d44b , 0x9ebcf64c , 0xea2da418 , 0x6062d601 , 0xe6053e73 , 0x93630b82 , 0x261304bc ] )
+  , Mont::unsafe_make( [ 0xde8026a3 , 0x4dbacbe9 , 0x332d2438 , 0xd43b2431 , 0x786263e0 , 0xe8fb0dd7 , 0x5faed84b , 0x08158be7 ] )
+  , Mont::unsafe_make( [ 0x7c2573dc , 0x130a1d80 , 0x056ab957 , 0x701b3094 , 0xebacaebe , 0x6a00f93e , 0xf8ccf970 , 0x1436948b ] )
+  , Mont::unsafe_make( [ 0xaf3db826 , 0x16879896 , 0xc0f1e923 , 0xda0f59ba , 0x7c0736be , 0x8e5196e4 , 0xfd67227b , 0x2dc51284 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x2bace154 , 0xdf4aab5d , 0x930fdc58 , 0x53018917 , 0x6a8cc4bd , 0x61204491 , 0x8cb95f97 , 0x074a748a ] )
+  , Mont::unsafe_make( [ 0x2f506874 , 0x2963e6b1 , 0x164473cb , 0x3d7e70e8 , 0xf8707d6f , 0xb133c728 , 0x04142ebb , 0x11dae4ce ] )
+  , Mont::unsafe_make( [ 0x8dfac7db , 0xd54c57af , 0x21a6f993 , 0x13f2b5f9 , 0xca0a3273 , 0x392ea734 , 0x4a31c03b , 0x03a20497 ] )
+  , Mont::unsafe_make( [ 0x1d483e16 , 0x380688c0 , 0x4f2bdc0f , 0x31a07be3 , 0x4939cc1d , 0x03631006 , 0x36447a85 , 0x10a4c7a6 ] )
+  , Mont::unsafe_make( [ 0x746e37a2 , 0x75dc794a , 0x9abddcd5 , 0x4921e8cc , 0xa1e0b442 , 0x6aaa7d8d , 0xa5da74ae , 0x152b0818 ] )
+  , Mont::unsafe_make( [ 0x5151e0b0 , 0x1e323243 , 0xf20a677b , 0x36bd404f , 0xe16b96fb , 0xbae2c645 , 0x24342eae , 0x247cf31c ] )
+  , Mont::unsafe_make( [ 0x520f24c4 , 0xc7d87cd3 , 0x13ec7352 , 0xe5f806cc , 0xfe3613af , 0xc2b0be89 , 0x1433a649 , 0x20c10402 ] )
+  , Mont::unsafe_make( [ 0x7ae9d8b1 , 0x74c8d4de , 0x7f211225 , 0xd11056aa , 0xfbf6b0ef , 0x68344961 , 0x92d8f14d , 0x0fff9743 ] )
+  , Mont::unsafe_make( [ 0x2b5c8b72 , 0x377bb2ae , 0xba8faa5c , 0x6beffd97 , 0x08dc9719 , 0x5fb13bc6 , 0x44f149c1 , 0x1319ecc1 ] )
+  , Mont::unsafe_make( [ 0xf3e85829 , 0xa081a9fb , 0x87efaf45 , 0xc8e1a1e6 , 0xe1aaad09 , 0x378aa3ed , 0x7e0ffeed , 0x1a9993f2 ] )
+  , Mont::unsafe_make( [ 0x7cea90d4 , 0xa45ca09a , 0x3880fcb5 , 0x38c5f1a9 , 0xac1d99cd , 0x6416dde4 , 0x2f6cfa2e , 0x19f3a3b1 ] )
+  , Mont::unsafe_make( [ 0xd332d2ba , 0xf2ec24dc , 0xb45797ea , 0x17e7282c , 0xeac71d50 , 0xb5674078 , 0x187a67aa , 0x17f4aa50 ] )
+  , Mont::unsafe_make( [ 0xd98a7c20 , 0xd5ab89db , 0x955b8aca , 0xba8dfd32 , 0xe979ce8b , 0x902bcbee , 0x0d7e6533 , 0x0d59b097 ] )
+  , Mont::unsafe_make( [ 0xcdfbc152 , 0xc8baa3f7 , 0x06b9972c , 0x136149fc , 0x2362e2d0 , 0x09cf3e57 , 0xc4564884 , 0x14101af4 ] )
+  , Mont::unsafe_make( [ 0xfdfc2a7b , 0x7c74d3ee , 0x6bf1d837 , 0x7f661806 , 0x7a35b43c , 0x91fe3b86 , 0xcf0f3aa2 , 0x2f95d660 ] )
+  , Mont::unsafe_make( [ 0xfbff1fcd , 0x5d392430 , 0x93fc3f2e , 0xdcaa408f , 0x6e0d1190 , 0x9b7a8cad , 0x6afbe225 , 0x22d5754b ] )
+  , Mont::unsafe_make( [ 0x59e611bc , 0x946dc265 , 0x5cb243ea , 0x9cf1fdaf , 0xb7274e9c , 0x451cc5c5 , 0xb8a73fd1 , 0x1e8b9e68 ] )
+  , Mont::unsafe_make( [ 0x08f81366 , 0x0a99975b , 0x49bb66b9 , 0x9a5e77b7 , 0xe69502a5 , 0x7127c041 , 0x74aa484f , 0x13304ba3 ] )
+  , Mont::unsafe_make( [ 0xef252a3a , 0x2489c692 , 0xc78f143b , 0xb745ec72 , 0x1d525d25 , 0x2634d026 , 0x7b3b6ed0 , 0x2d9ad88f ] )
+  , Mont::unsafe_make( [ 0x41ca55d2 , 0x74a21d80 , 0xb112dea6 , 0xc2ad7e6f , 0xd983622a , 0x2e0a73ce , 0xfe017fe9 , 0x1e8d81ab ] )
+  , Mont::unsafe_make( [ 0x3ad4e280 , 0x7c315076 , 0xa4000d33 , 0x853947a5 , 0x2006a3c4 , 0x4ed0a60d , 0xd5324984 , 0x2c6efb08 ] )
+  , Mont::unsafe_make( [ 0xe9592c1d , 0x2c98530c , 0x6565d534 , 0x617d009e , 0xe52f5c0c , 0x6ee2d938 , 0x7990028b , 0x130ea0ef ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x213e9c07 , 0xc9b13605 , 0x73c0858e , 0x83565b81 , 0xa8e4c693 , 0xf7b86070 , 0xb7b3490e , 0x24250c75 ] )
+  , Mont::unsafe_make( [ 0x7966c07c , 0x1118fac0 , 0x5954fada , 0xf20c4b82 , 0x74fffb75 , 0x96a32a5b , 0x991e7795 , 0x2bb9d7d4 ] )
+  , Mont::unsafe_make( [ 0x5468e0a0 , 0x139f1866 , 0xddcac765 , 0x48ca6173 , 0x4970742a , 0x8da6fbcd , 0x8054cbc9 , 0x1a7a21c7 ] )
+  , Mont::unsafe_make( [ 0x67654a2d , 0x25645659 , 0x943c7686 , 0x4defbee6 , 0xed7eee30 , 0x01c918e0 , 0x7fbd9db0 , 0x03252262 ] )
+  , Mont::unsafe_make( [ 0xbedee0b7 , 0xa539d66a , 0x7cb6f130 , 0xf1cdb7ec , 0xfb22d8fd , 0x446640b6 , 0x973a3421 , 0x08053f67 ] )
+  , Mont::unsafe_make( [ 0xadf5e8c0 , 0x16b23f0b , 0xa369ae85 , 0x2c7e8f34 , 0x21acd7f5 , 0x56e0264b , 0x929df309 , 0x1e53af2e ] )
+  , Mont::unsafe_make( [ 0xcabfac29 , 0x266d190e , 0x3991f5bd , 0xb0cc142c , 0x31ef0ab6 , 0x4a6e619c , 0xa7cbc92a , 0x2320c73d ] )
+  , Mont::unsafe_make( [ 0x91e9540a , 0x4ae0db93 , 0x1ccb5d27 , 0x02e92361 , 0x910311b5 , 0x83b88937 , 0xa1448c02 , 0x2cd685ba ] )
+  , Mont::unsafe_make( [ 0x460e2fa9 , 0x1be69178 , 0x821fb3c6 , 0x9cd9b001 , 0x2173d17c , 0x5842614a , 0xac4bcce4 , 0x20778286 ] )
+  , Mont::unsafe_make( [ 0xa66c0b17 , 0xe67eea63 , 0x8ff047b2 , 0x9dd654a5 , 0x75eeb6aa , 0x520de9a9 , 0xe72ae986 , 0x1564be69 ] )
+  , Mont::unsafe_make( [ 0x79634aac , 0xf82c61d9 , 0x5a0bf5ea , 0xcd3a0c48 , 0xd8fba4a4 , 0x9528f073 , 0xf56de071 , 0x21a1a000 ] )
+  , Mont::unsafe_make( [ 0xe8e33ddf , 0x14932e08 , 0xa5995a64 , 0x0c5f1716 , 0xd4c330ef , 0x15ac8a24 , 0x6f05d770 , 0x06bc27f1 ] )
+  , Mont::unsafe_make( [ 0xbb7e3b1d , 0x396c7929 , 0xc2b9381f , 0x2019c468 , 0xe12ea59d , 0xe0e295f1 , 0x844df223 , 0x1eebdbec ] )
+  , Mont::unsafe_make( [ 0xdd0637e7 , 0x9879991e , 0x5b5efad7 , 0x0ebc8745 , 0xf277e8ad , 0x5d745c41 , 0x4b6bff3c , 0x17209143 ] )
+  , Mont::unsafe_make( [ 0xf44e2849 , 0xc6762e96 , 0x345a2aa8 , 0x4077a4a0 , 0x64fc99b2 , 0x2eb1ed33 , 0x6597c1a7 , 0x06be6689 ] )
+  , Mont::unsafe_make( [ 0x3403576a , 0x90b9f8d6 , 0xa83c334d , 0xa3e9c9a0 , 0xc20c3a3d , 0xe2adbade , 0xd7b5536a , 0x0f35d4d4 ] )
+  , Mont::unsafe_make( [ 0x8c88cca5 , 0x8f1470a3 , 0x9b4656ae , 0xe7b77614 , 0x8b88d170 , 0xa68d4070 , 0x893114ed , 0x0c552e37 ] )
+  , Mont::unsafe_make( [ 0x4ac1beb7 , 0xf3872f7f , 0x4284375b , 0xab95bf45 , 0x629875a6 , 0x4f8d233b , 0xb22a9e5b , 0x0a664638 ] )
+  , Mont::unsafe_make( [ 0x2513ad18 , 0xcf4801c9 , 0xac17b748 , 0x17e4ab04 , 0xe6e66270 , 0xc7af18ad , 0xf27b7595 , 0x0013824b ] )
+  , Mont::unsafe_make( [ 0x82b48201 , 0x05ce193d , 0xe5b1b4d6 , 0xd801646c , 0x74a4dbd5 , 0x7c660311 , 0x79733235 , 0x2106400a ] )
+  , Mont::unsafe_make( [ 0xd5090393 , 0xda6addd0 , 0xd9042c47 , 0x29e07389 , 0x9a32d246 , 0xb5948c2c , 0xf7c54399 , 0x2f3082b0 ] )
+  , Mont::unsafe_make( [ 0x08860e08 , 0x8aa2d232 , 0xe7191d08 , 0xc787b242 , 0x62c57597 , 0xa4d0d6a3 , 0xbc6464ad , 0x0b8dc3f3 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x4666e41a , 0x6cee5d21 , 0x368357cc , 0x9f5fe804 , 0xb8a378a1 , 0x9ee5cee5 , 0x90744988 , 0x1ef91010 ] )
+  , Mont::unsafe_make( [ 0x886bfafd , 0x027b7d68 , 0xc342c358 , 0xd9dcccb3 , 0x813a525d , 0xd7798a29 , 0x99e15d00 , 0x06cfaf98 ] )
+  , Mont::unsafe_make( [ 0xbe7acd7d , 0xe04c3498 , 0xf286d22e , 0x4ffb7905 , 0x646f8bc2 , 0xfa7147f8 , 0x02a09f32 , 0x1b2b4549 ] )
+  , Mont::unsafe_make( [ 0x486df2e1 , 0xe54bb5e1 , 0x18c21c10 , 0x04ca006c , 0xd80f8bf8 , 0x27d70607 , 0x7b0244b4 , 0x28f556c2 ] )
+  , Mont::unsafe_make( [ 0xb6d37c53 , 0x4be91fb0 , 0x5024e63a , 0x99362371 , 0xe6c4f2d4 , 0xa7d65798 , 0xc54921a0 , 0x122e125d ] )
+  , Mont::unsafe_make( [ 0x72c08f62 , 0x96e9e19c , 0xe36455c4 , 0x1c066d29 , 0x9f4535dd , 0x3b435e39 , 0xa8e05910 , 0x011325b3 ] )
+  , Mont::unsafe_make( [ 0x980d9842 , 0xf01f2622 , 0x6880a464 , 0x37b6cf5c , 0x8fa98b85 , 0x5aaa691f , 0xfe8ca5e3 , 0x1dd84a6b ] )
+  , Mont::unsafe_make( [ 0x80c56e95 , 0xd753385a , 0x592e04c1 , 0xb6b49c73 , 0x513d5c58 , 0x58a84121 , 0xf8a2c78e , 0x2c6aa36a ] )
+  , Mont::unsafe_make( [ 0x2c8c184d , 0xd2544cd7 , 0x1b01acba , 0xcc640ebf , 0xc5475ee4 , 0x6107f9ed , 0x3b490c65 , 0x087a9e34 ] )
+  , Mont::unsafe_make( [ 0xf54ec4f6 , 0x959249c0 , 0x9167ea3c , 0x2bf75694 , 0x92414a0d , 0xc92c4527 , 0xd82a24d8 , 0x09a62dd8 ] )
+  , Mont::unsafe_make( [ 0xc8bf8ede , 0x5588df5d , 0xd154e167 , 0x6d73ec79 , 0xb016455a , 0x3628415e , 0xdb738d04 , 0x049640bd ] )
+  , Mont::unsafe_make( [ 0xb31d2f80 , 0xdfbcda1c , 0x927d39b1 , 0x88c88c6e , 0x7d8492e5 , 0xce2f203f , 0x6345c5b1 , 0x2a80f6b0 ] )
+  , Mont::unsafe_make( [ 0xd0aa9611 , 0x330dd1e9 , 0x487f7156 , 0xb1f635df , 0xe190a464 , 0xa8647b36 , 0x7631a6b8 , 0x143f1ff4 ] )
+  , Mont::unsafe_make( [ 0xbf618d50 , 0x6abf4b38 , 0x532075ed , 0x183c2272 , 0x40ef9a09 , 0x3409a8a5 , 0x138f5382 , 0x2a61649b ] )
+  , Mont::unsafe_make( [ 0xdaad9ea9 , 0x7028ef47 , 0x3955bb1e , 0x614c066c , 0x8a88f91d , 0xa8f1439a , 0x309e98b6 , 0x2ef18dc6 ] )
+  , Mont::unsafe_make( [ 0x0296dcc6 , 0x710a87f3 , 0x6bc000ba , 0x8b66e46f , 0x8fd4ee42 , 0x21e8ce73 , 0x7c8c01e6 , 0x08a1e63e ] )
+  , Mont::unsafe_make( [ 0xd817a8ac , 0x9c08f515 , 0xc1b1603a , 0x518621e0 , 0x29396e2c , 0x51dd8c77 , 0xd28953d5 , 0x00f6d4ba ] )
+  , Mont::unsafe_make( [ 0x07f1e8c6 , 0xc54c3c11 , 0x7dd32a79 , 0x586b2b14 , 0x32c2fc40 , 0xe47194e9 , 0x0a9cc19b , 0x1f2cc1bd ] )
+  , Mont::unsafe_make( [ 0x26fc283e , 0xd41e642e , 0xa994d116 , 0xc031e17f , 0xadf11ba5 , 0x61085d05 , 0xedb4a11c , 0x2e55b6fe ] )
+  , Mont::unsafe_make( [ 0xed9ac16f , 0x7e97055b , 0xaab03bc8 , 0x55cf91de , 0x80174f49 , 0xcd3414d5 , 0x371c7f02 , 0x0fb64feb ] )
+  , Mont::unsafe_make( [ 0x5cb74ddd , 0xfb73962d , 0xee280e59 , 0x0b2e0993 , 0xd4058fb1 , 0x3ee30756 , 0xbaa9f50c , 0x1acb7037 ] )
+  , Mont::unsafe_make( [ 0xb2a9762e , 0x1e620756 , 0xf513b2d3 , 0x71772e5e , 0xe0c7f535 , 0x52d99aff , 0xd261e80e , 0x09e6e4b1 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xb9ee697e , 0xd93ccc0d , 0xd083a82b , 0xd36edbbb , 0x7ecd8057 , 0xedd17965 , 0x4ba64577 , 0x1cbc122a ] )
+  , Mont::unsafe_make( [ 0x31d37bfe , 0x5bbca008 , 0x719ee33b , 0xfec05215 , 0xb05958bb , 0x301f2118 , 0x4d4d0c11 , 0x181f1622 ] )
+  , Mont::unsafe_make( [ 0x619df38e , 0x264f7db8 , 0xbb30a30b , 0x85dcfb0e , 0x988d9ac6 , 0x7e363e0f , 0x73e92ed7 , 0x0354a313 ] )
+  , Mont::unsafe_make( [ 0x62f68a60 , 0x2935174f , 0x3dfbb9c5 , 0x371526c3 , 0xe71887c7 , 0xf6bed493 , 0x0ad3b351 , 0x1cfbfb1e ] )
+  , Mont::unsafe_make( [ 0x904a8a3c , 0x2386551d , 0xff7155da , 0xf34ff391 , 0x67a4f14c , 0x1be0c978 , 0x4e422e53 , 0x1f528919 ] )
+  , Mont::unsafe_make( [ 0xdcd00e30 , 0x4ea8409a , 0x4b9a9d51 , 0x8eef9757 , 0x270d8a5f , 0xf4095b1b , 0x55723e7e , 0x20063680 ] )
+  , Mont::unsafe_make( [ 0xe60e40ba , 0x2a066cbd , 0x0035e698 , 0xecc2491d , 0x3b90066f , 0x699f4365 , 0x0ff7729e , 0x1039f33d ] )
+  , Mont::unsafe_make( [ 0x446345ef , 0xe8486968 , 0xffb30a2f , 0x6b6d84d7 , 0xbc9dc18c , 0xb3f1c181 , 0x875dc91f , 0x1281b0c8 ] )
+  , Mont::unsafe_make( [ 0xd596b6fe , 0x1d34f558 , 0xebb96ee4 , 0x364c14c4 , 0xd3b27ae0 , 0xed46e3e6 , 0x3526e14b , 0x1daf5994 ] )
+  , Mont::unsafe_make( [ 0xe5e3e8ca , 0x9ba16bc3 , 0x6444a9e4 , 0x6448ded6 , 0x04a242f8 , 0xb8d4b7d9 , 0x57a525e1 , 0x181108f7 ] )
+  , Mont::unsafe_make( [ 0x2941eb2e , 0x7c374ccc , 0x760ab88d , 0x81d5bdbd , 0x321e4720 , 0xcd7d0456 , 0x7351def9 , 0x2d31b6b0 ] )
+  , Mont::unsafe_make( [ 0xc1dd985a , 0x03fb109e , 0x13fdd3d3 , 0xb08d5f10 , 0xa96e1195 , 0x9ce05093 , 0xfee1d891 , 0x29e591ce ] )
+  , Mont::unsafe_make( [ 0x54ed1a14 , 0xdb91a860 , 0xc77d6d89 , 0x475bfeaa , 0xa042808f , 0x91fe2e11 , 0xc30dc0e4 , 0x06eb1bc6 ] )
+  , Mont::unsafe_make( [ 0xe318eb6f , 0xc664b674 , 0x49d61a75 , 0xc0d6769d , 0x931c7ae9 , 0xacc35f41 , 0x53cb7758 , 0x16a73ee5 ] )
+  , Mont::unsafe_make( [ 0xfbab2f20 , 0x0d86848a , 0xb6dd6387 , 0x32e4b474 , 0xcd3d94fc , 0x63eb809f , 0x36e5a5b8 , 0x14f6815b ] )
+  , Mont::unsafe_make( [ 0x42d9dd91 , 0xb1670731 , 0xb6159815 , 0x08f90e9e , 0xbe1853e4 , 0x78076eb9 , 0x1d915612 , 0x061b5ec6 ] )
+  , Mont::unsafe_make( [ 0xc0f24f12 , 0x5ce1c19d , 0xd1fe0f23 , 0x9393b2a9 , 0xb34b7fae , 0x157b64af , 0x088ada95 , 0x2470ce1d ] )
+  , Mont::unsafe_make( [ 0x4a6a3db6 , 0x8e0b2e19 , 0xa7cefb56 , 0x4bf6c0bf , 0xe662df62 , 0x14376aa9 , 0x1ca6b588 , 0x06416eb6 ] )
+  , Mont::unsafe_make( [ 0xebb19a43 , 0xfd67a5f7 , 0xfb4bc24d , 0x47ed680c , 0x330b265a , 0xe85d2406 , 0x0c238fd3 , 0x1210cf0d ] )
+  , Mont::unsafe_make( [ 0xb7c8adf8 , 0x60eef97d , 0x4a6c69b9 , 0xaf8f9ddf , 0x0c246708 , 0x1c7b263e , 0x60bcb9c7 , 0x2d7ad778 ] )
+  , Mont::unsafe_make( [ 0x4a511048 , 0x3ad19a51 , 0x19d5f3b1 , 0x9cc5f15b , 0x06eedec7 , 0x3ac4d2fb , 0xf541a655 , 0x09871a07 ] )
+  , Mont::unsafe_make( [ 0xa0e09c8f , 0xcbac3867 , 0x5f8333dd , 0xfbcf1762 , 0xe5a177c7 , 0xf24b0b1f , 0x3fc45973 , 0x05194cd5 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xde2e4376 , 0xe73189a9 , 0x3e5f86f0 , 0x715e2901 , 0x46e14623 , 0x58167889 , 0x6c3e17ce , 0x0eacd91f ] )
+  , Mont::unsafe_make( [ 0x11c7ba94 , 0xe8336d52 , 0xa0ca7e03 , 0x2fb9d8a6 , 0x1a294f40 , 0xb35e616e , 0xcf80966b , 0x014d1d81 ] )
+  , Mont::unsafe_make( [ 0xd25ff8cf , 0xed93131e , 0x92b0bb9d , 0xd6920fbf , 0xac0c9766 , 0xbc307e96 , 0x1df3cc54 , 0x06724e73 ] )
+  , Mont::unsafe_make( [ 0x72125555 , 0x94dd42a6 , 0x37640a2a , 0x2cd7eca3 , 0x1f4ae764 , 0xf5a5c825 , 0xb84ca543 , 0x23d41ad8 ] )
+  , Mont::unsafe_make( [ 0xc64c3432 , 0x9287e4d7 , 0x0fd0ce49 , 0x41023870 , 0x9001367a , 0x6ae8945d , 0x75636573 , 0x009342b0 ] )
+  , Mont::unsafe_make( [ 0x885ec829 , 0xa0d08cbb , 0xcf8b06be , 0x2c2663a0 , 0xaa447f50 , 0x10c2a945 , 0xfbee5a4a , 0x2c782c62 ] )
+  , Mont::unsafe_make( [ 0x37cf6b04 , 0x878c9018 , 0x07cefcd1 , 0x2dfc3d58 , 0x0ecf754f , 0xb0e304af , 0x40bfbd29 , 0x0b584710 ] )
+  , Mont::unsafe_make( [ 0x165cc6c7 , 0x078e6c32 , 0x73ba7ca4 , 0xf6b7dce9 , 0x6dd50799 , 0x84e3f9b4 , 0xf21c4442 , 0x1ce4b6a9 ] )
+  , Mont::unsafe_make( [ 0xfd5eb876 , 0x39fb8fc3 , 0x5ab3bff9 , 0xa958e8c2 , 0xe6e2b08d , 0x56401171 , 0xa3310666 , 0x1743ca03 ] )
+  , Mont::unsafe_make( [ 0x765e3395 , 0xffd569e6 , 0xfe924671 , 0x07d99b03 , 0x3ca40922 , 0x150e1db2 , 0x71d2f243 , 0x143c0c0f ] )
+  , Mont::unsafe_make( [ 0x3356ca69 , 0x549da467 , 0xbbc9b4d4 , 0xe980b51e , 0xebaa6b97 , 0x1853b4ff , 0x506a666c , 0x2903c150 ] )
+  , Mont::unsafe_make( [ 0x79e5fcd9 , 0xd4540682 , 0xb5d739b9 , 0x10630d1b , 0x05bb958f , 0xd43b200d , 0x290365ed , 0x13fd8a71 ] )
+  , Mont::unsafe_make( [ 0x1836d9db , 0xf9f3c275 , 0x701bcc75 , 0x84841da0 , 0x7c1cfb68 , 0xeb83e280 , 0xca95221b , 0x1a576243 ] )
+  , Mont::unsafe_make( [ 0xdcbc6774 , 0x01bd5c68 , 0x9f186d30 , 0xbed8ec37 , 0x8337a688 , 0x2f1b4806 , 0x8ee99404 , 0x22a9197b ] )
+  , Mont::unsafe_make( [ 0x60278b47 , 0x147eb882 , 0x18110469 , 0xba04c911 , 0x0d828417 , 0x58d88bbe , 0x987b3f01 , 0x18608523 ] )
+  , Mont::unsafe_make( [ 0xebe561d5 , 0x73d08b97 , 0xfe67b382 , 0x917d5ea0 , 0x3a56af09 , 0x409b5dc6 , 0xfa4b87a8 , 0x303da996 ] )
+  , Mont::unsafe_make( [ 0xbfb4edeb , 0x136ca00c , 0xfc867d7e , 0x5150e417 , 0xac230ad5 , 0x6b8492ee , 0x4e5782c1 , 0x04683436 ] )
+  , Mont::unsafe_make( [ 0x4856b5cd , 0xb7826199 , 0xaf85bf2d , 0xbe934c4f , 0xc664f659 , 0xad28bd59 , 0xb6596e6d , 0x182ef17b ] )
+  , Mont::unsafe_make( [ 0x4c804cec , 0x85c2e358 , 0xaa2d8ef2 , 0xd6cc858a , 0x903c8a03 , 0x2b1c45b6 , 0xe486c7db , 0x294951fa ] )
+  , Mont::unsafe_make( [ 0xb9dfdf3c , 0x704ec303 , 0x8fbd0a61 , 0x804c0a29 , 0xd8c5fc6a , 0xe2a35860 , 0x3bfd1722 , 0x00abc616 ] )
+  , Mont::unsafe_make( [ 0xb09c5f30 , 0x865b51c1 , 0xa0389544 , 0x0292e204 , 0x74e5825e , 0x49f88cad , 0xfee153ec , 0x2318a429 ] )
+  , Mont::unsafe_make( [ 0x1c526516 , 0x15621d8e , 0x52c5db9d , 0xedb69747 , 0xfd8d14c5 , 0x32f0960c , 0x1ad72240 , 0x01db10f3 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xb3141109 , 0x3b2ecd2e , 0x52082fa2 , 0x722cf0d2 , 0x1f6909aa , 0xde5bcb6d , 0xae0ed47e , 0x10cdcf53 ] )
+  , Mont::unsafe_make( [ 0x804bbc6d , 0x789d167a , 0xfe189ace , 0x4f241e72 , 0xe61e421d , 0xc8d33c20 , 0x804912a3 , 0x0ffd5588 ] )
+  , Mont::unsafe_make( [ 0x45cec7c9 , 0xafcebaae , 0x43614edd , 0x36000e1a , 0xf9a2cb93 , 0xd53e1371 , 0x275da82b , 0x18f74768 ] )
+  , Mont::unsafe_make( [ 0x770c63f1 , 0x95867ffa , 0x1f56674a , 0xe38ed7d5 , 0x5506895d , 0x754ed872 , 0xb1889732 , 0x29954aae ] )
+  , Mont::unsafe_make( [ 0xf91b2629 , 0x5dc19770 , 0x037bf42e , 0x0041cb8f , 0x1bf25af9 , 0x52f61f6c , 0xbac621fa , 0x1d31d895 ] )
+  , Mont::unsafe_make( [ 0xed80c83a , 0xa855bf72 , 0x8c29a32a , 0x459ede1e , 0x179330f7 , 0xfcf7f368 , 0x246686fb , 0x1f120859 ] )
+  , Mont::unsafe_make( [ 0xe404ca01 , 0x2b5cf855 , 0x739ace8c , 0xd798fd29 , 0x9eaf2104 , 0x93a47679 , 0x81de1d56 , 0x0c1e3ad2 ] )
+  , Mont::unsafe_make( [ 0xddd22c8f , 0x9281c6f1 , 0xda82ba62 , 0x18d31bb0 , 0x17616ff5 , 0xc33903cf , 0xddf61e17 , 0x2d5b579c ] )
+  , Mont::unsafe_make( [ 0xd5051449 , 0x98e8575a , 0xeedc928d , 0x3c3ffc5a , 0xbbe75b5c , 0xdb1f0353 , 0x18857f41 , 0x0a6f4913 ] )
+  , Mont::unsafe_make( [ 0x87af67bd , 0x89508dee , 0x9c2d2ec6 , 0x1087a860 , 0x41afd387 , 0x91debb1c , 0xcc1ae22a , 0x289811f4 ] )
+  , Mont::unsafe_make( [ 0xba08d36b , 0x7c6498ba , 0x50ec4f4a , 0xf82f4fa9 , 0x15d1e32b , 0xb8eeb7ab , 0x47e88c7c , 0x0d3676a5 ] )
+  , Mont::unsafe_make( [ 0xbaaa1658 , 0x6e9ab530 , 0xcb7db561 , 0x3bb5b8af , 0x67e90607 , 0x9b10af65 , 0x5b4d1f7e , 0x302cc6b9 ] )
+  , Mont::unsafe_make( [ 0xde62f3fc , 0x622a4833 , 0x2d6c98ef , 0x040f66ac , 0x4e48d9b2 , 0x8ea6c660 , 0xb68f98b8 , 0x2efba976 ] )
+  , Mont::unsafe_make( [ 0xfb8a97cc , 0x24429ef3 , 0x31917ad7 , 0x866de110 , 0xa794d91b , 0x15cc4218 , 0x143b8b0c , 0x2d9e3afe ] )
+  , Mont::unsafe_make( [ 0x854e13d2 , 0x01ba58a6 , 0x53beafec , 0x2d63df15 , 0xfaf7c497 , 0x19e01eb3 , 0xf58e70f6 , 0x20c1407d ] )
+  , Mont::unsafe_make( [ 0x9831efbd , 0xb30a4ba7 , 0x3aa432d1 , 0x9c5656cc , 0x33ae7ee5 , 0xd566c3bd , 0x9661d4c2 , 0x2679456d ] )
+  , Mont::unsafe_make( [ 0xaf61664b , 0x409df397 , 0x59a2b349 , 0x3e6c3dd1 , 0xfc7de159 , 0x80e0fed0 , 0xcc859d30 , 0x1585300c ] )
+  , Mont::unsafe_make( [ 0x8bf4e81d , 0x289cb383 , 0x89a5d3c4 , 0x2956dbdc , 0x78befa25 , 0x9eb55cbc , 0x5d5f0a98 , 0x2f65d7cc ] )
+  , Mont::unsafe_make( [ 0x8b44f524 , 0x625b4910 , 0xecbbbaf2 , 0x2e508330 , 0x39ba1537 , 0xd563a2bb , 0x405eea1f , 0x0d9fdcea ] )
+  , Mont::unsafe_make( [ 0xbf81c51c , 0xc6d7bc19 , 0xe843ff0f , 0x74433222 , 0x389f067d , 0x4e7ca25a , 0xa3051c05 , 0x06e30e26 ] )
+  , Mont::unsafe_make( [ 0x86b100c3 , 0xbf869b3d , 0x5039b1cd , 0xb2f7bdd1 , 0xedb5007d , 0x6b7980cd , 0x3d6fd270 , 0x03bd4049 ] )
+  , Mont::unsafe_make( [ 0xce54a509 , 0x55507ad9 , 0xe2106f53 , 0xaea42929 , 0x58baacdb , 0xfa4f9b17 , 0x030e821d , 0x06f92521 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x57e7cc72 , 0x9ab188c5 , 0xf5fc9831 , 0x371cf290 , 0xce24c8a5 , 0xcec16a9f , 0x91717de0 , 0x2d13675a ] )
+  , Mont::unsafe_make( [ 0x6741fc2e , 0xd0f89efb , 0x05b900aa , 0x9fe6ec7b , 0xe931ca24 , 0x64cecbb5 , 0x17648779 , 0x2f72f471 ] )
+  , Mont::unsafe_make( [ 0x0684d40a , 0x92e423fe , 0xfe9aede0 , 0x57590d1e , 0xe0e8abd5 , 0xc6c6f5bd , 0xe28342c5 , 0x011b4260 ] )
+  , Mont::unsafe_make( [ 0x53e48a18 , 0x6648c5b3 , 0xf0fc9279 , 0xb1d3281a , 0xc8f06bb3 , 0xacacba11 , 0x34ddc5d5 , 0x051f2e7c ] )
+  , Mont::unsafe_make( [ 0xbd7774d9 , 0xeb673d36 , 0xb952fdff , 0x35e4157c , 0x59f58012 , 0x9f1bc9f7 , 0x805151e6 , 0x211d9cd3 ] )
+  , Mont::unsafe_make( [ 0x8d043404 , 0x2f78537b , 0xc3a75503 , 0x9097d343 , 0x76159027 , 0x701475f1 , 0x7060d147 , 0x095cd35a ] )
+  , Mont::unsafe_make( [ 0x0f58d549 , 0xdbd4a3ff , 0x98a190ef , 0x23d3bdf2 , 0x13cc3737 , 0xf2f98965 , 0xf732a9e8 , 0x20c3b138 ] )
+  , Mont::unsafe_make( [ 0xe9c6e75a , 0xe203285a , 0x584cff84 , 0xb14b0ec8 , 0xd1831cfc , 0x5cc59bca , 0x07381e08 , 0x1a725929 ] )
+  , Mont::unsafe_make( [ 0x64964752 , 0xe3f90dba , 0x8030015f , 0xecf6ee5c , 0x8bac4aac , 0x531e32b3 , 0x95eaf460 , 0x0552716d ] )
+  , Mont::unsafe_make( [ 0x47444af0 , 0x4ce37e5b , 0xebeb1c23 , 0x598b45d7 , 0x7a8da688 , 0x2aba80f3 , 0x9cafa4b6 , 0x058c1d37 ] )
+  , Mont::unsafe_make( [ 0x8215fbf5 , 0xd968ed09 , 0x1976c19f , 0x36634da5 , 0x402de049 , 0xf598c9cd , 0x214f339a , 0x1e5c7345 ] )
+  , Mont::unsafe_make( [ 0x6e494ea0 , 0xd6b93d9f , 0xd99c9988 , 0x088b17b5 , 0xd41fedb2 , 0x99ba880a , 0xeb63f408 , 0x0f408a6e ] )
+  , Mont::unsafe_make( [ 0x89d8661c , 0xed6affe0 , 0xcc194af6 , 0x02755ec4 , 0xca7875e6 , 0xcbb197f8 , 0xcc91e157 , 0x1d0d4748 ] )
+  , Mont::unsafe_make( [ 0x88d50a60 , 0xc67697b5 , 0xa679d486 , 0x96f75308 , 0x8e90e1c1 , 0x1019f320 , 0x796e837d , 0x1f749ad5 ] )
+  , Mont::unsafe_make( [ 0xe42c5dd3 , 0x702dbeab , 0x892a15c9 , 0x5b1a3eca , 0x632981ce , 0xa456816c , 0xead73a3b , 0x19edbf9d ] )
+  , Mont::unsafe_make( [ 0x67ecb82c , 0xf79a6817 , 0x9c511e4a , 0x6f948f68 , 0x1295af41 , 0xe3e42b34 , 0x4a405c9b , 0x2bb6f2e3 ] )
+  , Mont::unsafe_make( [ 0x51853b5c , 0x67767374 , 0x10e8f62e , 0x06bdcd9c , 0xb0484449 , 0x78da3e02 , 0xe3138083 , 0x21a5f838 ] )
+  , Mont::unsafe_make( [ 0xe601ffb1 , 0xb61a3cdc , 0xb4b21454 , 0x732bd97d , 0xaa14ac57 , 0x8e261cd5 , 0x4eb2c3a1 , 0x0cfcc46f ] )
+  , Mont::unsafe_make( [ 0x2adfd00f , 0x392e4b5c , 0x60b6e130 , 0x8c5a52e9 , 0x474a7004 , 0x753a3684 , 0xeeea36c8 , 0x006ac74e ] )
+  , Mont::unsafe_make( [ 0x59a3c740 , 0x390dbcaa , 0x976ba44e , 0x05e73444 , 0x20d05b67 , 0xb7bd6fb0 , 0x1589983d , 0x2d2c2ddb ] )
+  , Mont::unsafe_make( [ 0x60fb521b , 0xa471893b , 0x1a7258e1 , 0xd09b3424 , 0x22954020 , 0x418fbeca , 0xac277425 , 0x2b3f70f0 ] )
+  , Mont::unsafe_make( [ 0x5ea176c7 , 0x2e107ec9 , 0x357364ed , 0x7c186260 , 0xf8269364 , 0x3225ec5b , 0x7dc48982 , 0x02265989 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x85782d52 , 0x0d2180be , 0x6e9eebb6 , 0x38e9d6ac , 0xf1027e36 , 0x8d3da796 , 0x569bf176 , 0x2cf2a779 ] )
+  , Mont::unsafe_make( [ 0xeeb01a10 , 0x9d8e005d , 0x0572b77e , 0x58b43d56 , 0x7d937613 , 0xdddbea1c , 0xb126a9a5 , 0x2d847c2e ] )
+  , Mont::unsafe_make( [ 0x49521f62 , 0x5fe5c358 , 0x4b7220b7 , 0xfb6d76be , 0x695cf9bc , 0x3a8bcff7 , 0x56de2c4f , 0x0d1b02bb ] )
+  , Mont::unsafe_make( [ 0x0e5c039b , 0x85eeebef , 0xe8a3a26e , 0x1f345d7e , 0x05e4269c , 0xc3f582df , 0xcc6f41fa , 0x055433e5 ] )
+  , Mont::unsafe_make( [ 0xe7ef3b2a , 0xf214235a , 0x66304670 , 0x91dc5fb0 , 0x824e689e , 0x8b11a523 , 0x1cc7eefa , 0x160c7cc6 ] )
+  , Mont::unsafe_make( [ 0xd704d41d , 0xeb3d8f34 , 0x589698ac , 0x8203a4aa , 0xf530bef3 , 0x16237d1c , 0x1cfb3324 , 0x0ec43f67 ] )
+  , Mont::unsafe_make( [ 0xaa7a72be , 0xffa7e2c0 , 0x0c2edaa3 , 0x02f11593 , 0x083e7d49 , 0xc4f093e2 , 0x8c6c16a7 , 0x02787123 ] )
+  , Mont::unsafe_make( [ 0x3c5cc9c5 , 0x670b336c , 0xb3dc3170 , 0x83dc6bd3 , 0x12e296dd , 0x77ac5939 , 0x91cb18bd , 0x180f2066 ] )
+  , Mont::unsafe_make( [ 0x8ad0de58 , 0xf979de4a , 0x5f778311 , 0x168b2a92 , 0xd5d80835 , 0x6a186525 , 0x92a55919 , 0x1c74e0dc ] )
+  , Mont::unsafe_make( [ 0xde6df70e , 0x1cedc85f , 0xc01c7146 , 0x663d55ea , 0x9e52b90a , 0x94408e49 , 0x936faf6f , 0x1017a1a9 ] )
+  , Mont::unsafe_make( [ 0xda6b08bf , 0x8998beaf , 0xfc3099c7 , 0x063a4d28 , 0xa5090efb , 0x08598cbd , 0xcf4c20f9 , 0x2f47d651 ] )
+  , Mont::unsafe_make( [ 0x76fbfae7 , 0x3b7a6759 , 0xfee82bee , 0x29646b8e , 0x17e059ed , 0x77606a62 , 0x5cdd32e9 , 0x2954ab19 ] )
+  , Mont::unsafe_make( [ 0xceb25583 , 0xb98675ea , 0x0d934290 , 0x6056bfac , 0x137d0448 , 0xe11b94f6 , 0xd1517216 , 0x18c76e25 ] )
+  , Mont::unsafe_make( [ 0xd5acb2e6 , 0xd437a843 , 0x9eff415f , 0xc88052e7 , 0x03ea934c , 0x144d4131 , 0xea269694 , 0x1b3c695b ] )
+  , Mont::unsafe_make( [ 0xdb0b7b7a , 0x46fd17fb , 0x7eff17f7 , 0x613899ca , 0xf415d1e9 , 0x52c878d0 , 0x61ea2130 , 0x18035e69 ] )
+  , Mont::unsafe_make( [ 0xc10d7ac0 , 0x07ee3d9f , 0x04f43c4f , 0x6a388b8b , 0xbc0ccf2e , 0xb250debe , 0x341c6cb6 , 0x127dfc18 ] )
+  , Mont::unsafe_make( [ 0x98f49562 , 0x5484aca4 , 0x3d204b3c , 0xd1bb52c7 , 0x8c09c4e9 , 0xa9660dd8 , 0x4f62a8c5 , 0x13f2ca5c ] )
+  , Mont::unsafe_make( [ 0xbfc30acb , 0xeaa56126 , 0x1be67369 , 0xb6358d34 , 0xdb08a46a , 0x6141b0be , 0xd24854b0 , 0x02a016ab ] )
+  , Mont::unsafe_make( [ 0x7ae52cff , 0xba4119ce , 0xf851a3f2 , 0xc8424eea , 0xbab204eb , 0x4ee59aab , 0x62a31f6f , 0x24a984b4 ] )
+  , Mont::unsafe_make( [ 0x612285b7 , 0xebf0e179 , 0xf703cf1d , 0xe7f1e973 , 0xaaa04e30 , 0x40c3d7bc , 0x42be959c , 0x0b9a2823 ] )
+  , Mont::unsafe_make( [ 0xebd03e68 , 0x865d0ce3 , 0xc8e21a85 , 0x63308cbf , 0xaa38c193 , 0x746adfc0 , 0x7674ad62 , 0x2654572e ] )
+  , Mont::unsafe_make( [ 0x86dee93c , 0xbc433b48 , 0x71029818 , 0x0589a45e , 0x83d98c6a , 0x4620d777 , 0xbbd3b5be , 0x25f167f9 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xf5378745 , 0xcf202122 , 0x108f9622 , 0xcaee2097 , 0x45b6d0c3 , 0x7b6d6f03 , 0x6fb39d73 , 0x014c468d ] )
+  , Mont::unsafe_make( [ 0x80dcf68e , 0x6e85a859 , 0xddfce55b , 0x2df7467c , 0x71f37b69 , 0x5e6d43f0 , 0xb2e27458 , 0x203ded77 ] )
+  , Mont::unsafe_make( [ 0xfb2975ac , 0x90de4667 , 0x15139abb , 0x01bb6b55 , 0xdc317789 , 0xde882f6f , 0xd2aa17db , 0x07a738f2 ] )
+  , Mont::unsafe_make( [ 0x3ada5bf3 , 0xf3e90350 , 0xf4946d8b , 0xd9c8c858 , 0x738e41a6 , 0x09c46290 , 0xa84fb4af , 0x03f659a0 ] )
+  , Mont::unsafe_make( [ 0xd2eebf6c , 0xbeb0444d , 0xa0e12f93 , 0x304a4086 , 0x15a73716 , 0xaddc58aa , 0x2cad946a , 0x087db45e ] )
+  , Mont::unsafe_make( [ 0x1ef9ee05 , 0x725955b7 , 0x006a72e6 , 0x1b8efe34 , 0x8a8888d9 , 0xdb804bef , 0x7e45fce5 , 0x287bad0b ] )
+  , Mont::unsafe_make( [ 0x9c48734e , 0xa19f088a , 0x36280d2f , 0x37cb174a , 0xaca4a193 , 0x51c1c250 , 0xc8faf03b , 0x2f01a4d9 ] )
+  , Mont::unsafe_make( [ 0x61f8a67a , 0xa2015dbe , 0x15ad546d , 0x739d5d23 , 0xed767ce9 , 0xd0c76b70 , 0x6df218f2 , 0x0696b6c1 ] )
+  , Mont::unsafe_make( [ 0x3b5019e0 , 0x38a366d3 , 0xc5253758 , 0xe91515b0 , 0x8a0a3552 , 0xb7fecb0d , 0x6d69d9a4 , 0x09c0be31 ] )
+  , Mont::unsafe_make( [ 0x8ca5915c , 0xcb87609b , 0xce7f7a7d , 0x158e6523 , 0x27a00bbd , 0x6b39fc76 , 0xc93eab9b , 0x133a662f ] )
+  , Mont::unsafe_make( [ 0xbb7f2bca , 0x405a6460 , 0xed78ac66 , 0xdfcc455f , 0x12c75855 , 0x5e484368 , 0xd9d38d95 , 0x11c42d0e ] )
+  , Mont::unsafe_make( [ 0x2908a992 , 0x2d02d4b7 , 0x717c39e0 , 0xa58b7f1e , 0x58c4c508 , 0x2fd8f99c , 0x98e04d3e , 0x02fabd6b ] )
+  , Mont::unsafe_make( [ 0x3992226f , 0x5e38d8f6 , 0x4ac184dc , 0x4b5fc063 , 0x4c6c0b9d , 0x153d3ef3 , 0x769c74c0 , 0x01e7f2bf ] )
+  , Mont::unsafe_make( [ 0x563ccfb5 , 0x146cb78c , 0xcd52fe76 , 0xe46f9ad1 , 0x88ae2d63 , 0x8955403f , 0x61d62140 , 0x24fce966 ] )
+  , Mont::unsafe_make( [ 0xd7cfa2d4 , 0x56457fce , 0xf6edf097 , 0x88dcd3e7 , 0xdc1441f8 , 0xec84e845 , 0x05649968 , 0x268ab176 ] )
+  , Mont::unsafe_make( [ 0xd93b517b , 0xbff58483 , 0xfc130660 , 0x761f3de6 , 0x926e4674 , 0xd51f8348 , 0xd58026e5 , 0x1460ad3c ] )
+  , Mont::unsafe_make( [ 0x65be7e51 , 0xba2b5a28 , 0x692ffcfc , 0xe2bfb2e4 , 0xcd769ad6 , 0xb6df5b34 , 0xa5ffbeae , 0x2af37f0a ] )
+  , Mont::unsafe_make( [ 0xe2d6adb6 , 0xf767b6b3 , 0xb0265749 , 0x38a309d5 , 0x70cd843a , 0xb2f79e66 , 0x8eec6708 , 0x0404efc7 ] )
+  , Mont::unsafe_make( [ 0x07cd37d5 , 0x76ae1369 , 0xfc6e41c8 , 0xdd0b98a4 , 0x4c63e765 , 0x0bfefca0 , 0x2a07a5f0 , 0x239265e0 ] )
+  , Mont::unsafe_make( [ 0xadb2026b , 0x1a2e3c82 , 0x8e23469f , 0x42e26319 , 0xe8bc6f13 , 0xf2c9c5e6 , 0xe11a31ce , 0x06e7c841 ] )
+  , Mont::unsafe_make( [ 0x29c28f9a , 0x1a2e2ceb , 0xa71d71c3 , 0x942d79ee , 0x453069ac , 0x58fc89be , 0xace10b9c , 0x0e76756b ] )
+  , Mont::unsafe_make( [ 0x67af9a2d , 0x09b900e5 , 0xfacb5395 , 0xea5f34a5 , 0x9cd40b9f , 0x4fbe45e6 , 0xb2bcc9ff , 0x0f49ed59 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xb84358b9 , 0x525c3dcc , 0x88d50b6c , 0x92d7d41c , 0x8cdd6ad6 , 0x35861306 , 0x71902217 , 0x1b98ceab ] )
+  , Mont::unsafe_make( [ 0xe801e613 , 0xcfb05497 , 0x30c3e271 , 0xd68697d8 , 0x910685f3 , 0x066776d2 , 0x9802ab5b , 0x2b2ec355 ] )
+  , Mont::unsafe_make( [ 0x18c50563 , 0x4acd055c , 0xd6bbc1d8 , 0x3f40fa47 , 0xae6be7f6 , 0xff88a95b , 0xe3e07ebb , 0x1c638742 ] )
+  , Mont::unsafe_make( [ 0x1f35432f , 0xff35c30e , 0xf92cfde2 , 0x9529584c , 0x37f65fba , 0xbedb55a8 , 0x0a0f03ad , 0x1de712cc ] )
+  , Mont::unsafe_make( [ 0xbb6d9a6a , 0x23380bae , 0xaec30292 , 0x94ccd8de , 0x15d61525 , 0xe22f975c , 0x8240790d , 0x1f21d477 ] )
+  , Mont::unsafe_make( [ 0x3f7a8b4a , 0x359a8e20 , 0xe61cbbb0 , 0x8c364ae7 , 0x45346c6e , 0xdc01b799 , 0xef516bb3 , 0x1e405298 ] )
+  , Mont::unsafe_make( [ 0x11839730 , 0x15988fe2 , 0xb0ef6b37 , 0xffa02594 , 0xbf905664 , 0x29a01237 , 0x5c62fc3e , 0x2e9beaa2 ] )
+  , Mont::unsafe_make( [ 0x2f4e7888 , 0x6d606de2 , 0xf19294ce , 0x8eb64986 , 0x56578116 , 0x6f2fd813 , 0x88dd78be , 0x1500c1cc ] )
+  , Mont::unsafe_make( [ 0x0bfccdd0 , 0x70e9cd91 , 0xc5fee913 , 0xd69b0f09 , 0x41c50f07 , 0xaca8c0e3 , 0xd28d8263 , 0x2b56fbae ] )
+  , Mont::unsafe_make( [ 0x7159720f , 0xc5d50791 , 0x12d73c2a , 0xc3649d8d , 0xd26d9cf4 , 0x6d3158bb , 0x20d6ab56 , 0x009d9bd4 ] )
+  , Mont::unsafe_make( [ 0x26e053a2 , 0xc1f55be8 , 0x9a0136e9 , 0x1a87dcbf , 0x9c8eb6ca , 0xf19d77ed , 0xf44368d9 , 0x00035a95 ] )
+  , Mont::unsafe_make( [ 0x16d714c8 , 0x7f8d2066 , 0x7f3fc729 , 0x57f47d4d , 0x110f7a33 , 0x1b9055d5 , 0x83cf7a34 , 0x2b87aaa5 ] )
+  , Mont::unsafe_make( [ 0xa4456a6a , 0xb1733ec2 , 0xc7891292 , 0xb625ba35 , 0xe8edd967 , 0xf64c1ef0 , 0xd6218e15 , 0x05250c9d ] )
+  , Mont::unsafe_make( [ 0x05a2b8fd , 0x782af516 , 0x7575b4c3 , 0x74d0a861 , 0xafc35caf , 0x39b1e076 , 0xe902cc0d , 0x07e90d89 ] )
+  , Mont::unsafe_make( [ 0x58a06b5d , 0xf389571d , 0x935dd543 , 0xed71b1bc , 0xeb6b8cf2 , 0x0e570c2b , 0x2ed3d7ce , 0x0e96f346 ] )
+  , Mont::unsafe_make( [ 0xd169a334 , 0xad2f5b08 , 0x84caff3b , 0x5d4f5189 , 0x650f1ed1 , 0x9cca9961 , 0xc1c7985a , 0x0150f9a8 ] )
+  , Mont::unsafe_make( [ 0x3c11ffd6 , 0x2c73bfa5 , 0xce47174b , 0x93746d86 , 0xe26b79c3 , 0x3903563d , 0x36c42dc8 , 0x05e8de54 ] )
+  , Mont::unsafe_make( [ 0x01846de3 , 0xc403756a , 0x1ce6835f , 0x0886e911 , 0xab6577d2 , 0x727023c9 , 0x9574ece0 , 0x0cc912b9 ] )
+  , Mont::unsafe_make( [ 0xdaff5a29 , 0x35ad3d12 , 0xdf502783 , 0xadd36eab , 0xebe853d5 , 0x466db3ff , 0x53558f7d , 0x0b0fa219 ] )
+  , Mont::unsafe_make( [ 0x08253d63 , 0xcb20142a , 0xbe272914 , 0x08588780 , 0xa939fa0b , 0x8f61a460 , 0x1caabcbb , 0x0612b55a ] )
+  , Mont::unsafe_make( [ 0x0e2e48e6 , 0x67a0f7ef , 0x9a9527b5 , 0xd1d84a22 , 0x58762add , 0x940ddb5e , 0x21991d5c , 0x23b26ee0 ] )
+  , Mont::unsafe_make( [ 0x9782cc65 , 0xac1a2cae , 0xc81c998c , 0x2b1406fb , 0x263bef69 , 0xabfee18e , 0xddf0a041 , 0x08d175c4 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x985569f8 , 0x92b1304b , 0x28259438 , 0xa7b68a51 , 0x2e1392e4 , 0x79feeebb , 0xf6e422ba , 0x0ede355c ] )
+  , Mont::unsafe_make( [ 0x86855b7d , 0x9ae529cf , 0xc55333ad , 0xfbae1bd7 , 0x41d0d107 , 0xecf410d9 , 0x2ffe5ca0 , 0x0d6ed612 ] )
+  , Mont::unsafe_make( [ 0xeb31e760 , 0x3f17f665 , 0x2ae32623 , 0xb1adfacd , 0x7b9c2789 , 0x9e1aae72 , 0x0838b180 , 0x150ea4ae ] )
+  , Mont::unsafe_make( [ 0xeaefa667 , 0xcdfc8904 , 0xea6fa22c , 0x70e2c391 , 0xaf8108f8 , 0xf67da7e1 , 0xb471a44e , 0x139301bc ] )
+  , Mont::unsafe_make( [ 0x6e1ca9db , 0xf60d0f8d , 0xe6771e96 , 0x6d6aacaa , 0xc3177df7 , 0x08ae7048 , 0x0df74a8a , 0x20c41aeb ] )
+  , Mont::unsafe_make( [ 0xa90ab341 , 0x3cd835ce , 0x466d0adc , 0x1e4c843a , 0xd53bb4d5 , 0xc7dc20fe , 0x6915e2ff , 0x175fd5cf ] )
+  , Mont::unsafe_make( [ 0xce848f29 , 0x6a4c2004 , 0x3ea0fe5b , 0x3827b98c , 0x78df847f , 0xd9e96d55 , 0x3b79c94d , 0x0c61fbc4 ] )
+  , Mont::unsafe_make( [ 0x0b03e036 , 0x0a804b73 , 0x52ff6e0e , 0x50cb29a9 , 0x0c441430 , 0x89326739 , 0x46b61936 , 0x2e408ae1 ] )
+  , Mont::unsafe_make( [ 0x37627900 , 0x96628a1a , 0x0392be1d , 0x740824b2 , 0x2d85a7c2 , 0x739922ca , 0xee60cfaf , 0x1454cf5d ] )
+  , Mont::unsafe_make( [ 0xfa51db37 , 0xc92d45e5 , 0x87755486 , 0x10648a38 , 0xea49b7ca , 0x4b03ffa0 , 0xcb78770a , 0x19fbd2f6 ] )
+  , Mont::unsafe_make( [ 0x6415c704 , 0x81a153ba , 0xa39eb62e , 0xb1c83979 , 0x65b00a8d , 0xbdb4b37f , 0x6d0315c9 , 0x1e69cf11 ] )
+  , Mont::unsafe_make( [ 0xb7b23e1b , 0x32d9111d , 0x387d5856 , 0x0450a879 , 0xae30d9ad , 0x3010c15c , 0x7c7e91bf , 0x0a2ea9b4 ] )
+  , Mont::unsafe_make( [ 0x20dab4f4 , 0x468b99c2 , 0xb0a1fe04 , 0xd05c6ef9 , 0x6202194f , 0x72564918 , 0x097f93df , 0x1cbbd496 ] )
+  , Mont::unsafe_make( [ 0x92f21765 , 0x067cd31f , 0x3e569a8f , 0x6077f18d , 0x6dd3ee34 , 0x54ff111c , 0xe8c43651 , 0x237b7ec9 ] )
+  , Mont::unsafe_make( [ 0x9265aa61 , 0x022a6fa1 , 0x04969ae9 , 0xf52c758d , 0x1179cda9 , 0x8edbf47e , 0x6b8ab9ae , 0x1a4ed851 ] )
+  , Mont::unsafe_make( [ 0xb6e1486d , 0x600f1ff8 , 0x448b1367 , 0x6bbaca12 , 0x82e1be41 , 0xf87551fe , 0x7f35d17a , 0x0e5928b3 ] )
+  , Mont::unsafe_make( [ 0x076df578 , 0x13e5f8f5 , 0x10aefd7c , 0x5ec1bfda , 0x278babe5 , 0xbc24892b , 0x84230e81 , 0x0e3edb6e ] )
+  , Mont::unsafe_make( [ 0x47056d5b , 0xcd908896 , 0x17e1d935 , 0x5d599bf9 , 0x4dd4ec55 , 0x6a659b3d , 0xde66b3ac , 0x000e445f ] )
+  , Mont::unsafe_make( [ 0x32599729 , 0x3841f63c , 0x64e53b70 , 0x30d5cdc1 , 0x5b0786a4 , 0x8f8a4f49 , 0xbcf24293 , 0x122f061c ] )
+  , Mont::unsafe_make( [ 0x2357bbe4 , 0x825f38ce , 0xf8dc1e4e , 0x7e560414 , 0x388b4cb2 , 0x15376275 , 0xcd1462c2 , 0x0fb8ccd1 ] )
+  , Mont::unsafe_make( [ 0x3c72a58f , 0x403a7e59 , 0x03f1ff1a , 0x2183277e , 0xae4853db , 0x89f4a3e1 , 0x5f6142f5 , 0x059036e4 ] )
+  , Mont::unsafe_make( [ 0x1b31e264 , 0x20fa157d , 0xca95ce50 , 0x57ce4b64 , 0x006babe0 , 0xbdf339c9 , 0x3c878608 , 0x1fd683ea ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x1c8c1197 , 0xa0732626 , 0xba0b63c6 , 0x750ab853 , 0xe5a900ee , 0x8f99d739 , 0x7130f74b , 0x1e0c4681 ] )
+  , Mont::unsafe_make( [ 0x51a1c106 , 0xc9233a98 , 0xc5b8afc2 , 0x98bbe9a6 , 0x28b64fb9 , 0x853f1806 , 0x898464b2 , 0x180da93c ] )
+  , Mont::unsafe_make( [ 0x542a9cc3 , 0x440527fd , 0x4471340c , 0xfbd28b63 , 0xb5e612c0 , 0x01e1d4fe , 0xc4e0bee5 , 0x1ecac7da ] )
+  , Mont::unsafe_make( [ 0xb6477799 , 0x63aa3a98 , 0xd6eb1615 , 0x27d3d1cf , 0xbc4929e8 , 0x4113b4ea , 0xda2f6d8b , 0x00a9787b ] )
+  , Mont::unsafe_make( [ 0xd4d01389 , 0x20bee485 , 0xc03c1ba6 , 0x349ea18a , 0x4cbf53f0 , 0x23474b56 , 0x4534834e , 0x0937e20a ] )
+  , Mont::unsafe_make( [ 0xa3b26505 , 0x290a2997 , 0xa9f3ee06 , 0x6ce710a6 , 0xc01822ae , 0x626f6a30 , 0xf15c2561 , 0x258b7404 ] )
+  , Mont::unsafe_make( [ 0xefa0dbbb , 0x13dcdbc5 , 0x98aaa8eb , 0xe5364e6d , 0x3094bb85 , 0x2603a80c , 0xf2ea4d7f , 0x2b854ecd ] )
+  , Mont::unsafe_make( [ 0x459b0815 , 0x3a0e8a80 , 0xed2600bf , 0x2135c91f , 0xbc548770 , 0xfd17328c , 0xcbc866a5 , 0x2ffad445 ] )
+  , Mont::unsafe_make( [ 0x44934101 , 0xdb000abf , 0x733546b2 , 0xbfaa28ae , 0x66fa8878 , 0x93dfe8a4 , 0x1f2b2dca , 0x11fd4194 ] )
+  , Mont::unsafe_make( [ 0x736d3c27 , 0x81857d79 , 0x22f0391f , 0x0273c6dc , 0x6fabefa4 , 0xd5186cd7 , 0x11f194bf , 0x03237290 ] )
+  , Mont::unsafe_make( [ 0xda2a33f2 , 0x656e4810 , 0xd82f17f0 , 0xb7bdf20f , 0x8194f6e6 , 0x9873a106 , 0x198eef8a , 0x1db237ad ] )
+  , Mont::unsafe_make( [ 0xa969d81d , 0xc3216ff2 , 0xeeb7a7ad , 0x9b11c7ef , 0xbe85ccad , 0x08c1463b , 0xfec3356a , 0x04945439 ] )
+  , Mont::unsafe_make( [ 0x67c070e3 , 0xffbc36cc , 0x6efdebf9 , 0xe5d5a3ba , 0x8931e658 , 0x5f85689f , 0x5c059912 , 0x0f0f66cd ] )
+  , Mont::unsafe_make( [ 0xe15d0d1c , 0x49a4202d , 0x201896a8 , 0xfb2d4c8f , 0x7773bf68 , 0x177978bc , 0xdc4bbc66 , 0x251fab0c ] )
+  , Mont::unsafe_make( [ 0x6a6dafdf , 0x25e2181c , 0x27a2a90f , 0x1fd5b4f2 , 0xddbcd5e2 , 0x1ca6173d , 0xd63d0a8b , 0x01f58e23 ] )
+  , Mont::unsafe_make( [ 0x5d6101eb , 0x6422a5de , 0xd1429b18 , 0x2df9c5bc , 0x6260be92 , 0xcb9a2d92 , 0xc8a8c683 , 0x28770fcf ] )
+  , Mont::unsafe_make( [ 0x225f8446 , 0xa1d3b477 , 0x42ab81c3 , 0x92c04d39 , 0x715e5b92 , 0xaf4207d0 , 0x4b47761f , 0x25a9c178 ] )
+  , Mont::unsafe_make( [ 0x8abeb7b7 , 0xe6214892 , 0x8140ad16 , 0x20bc7757 , 0xaa5f861d , 0x94c0d1ff , 0x3372a54c , 0x24c454d1 ] )
+  , Mont::unsafe_make( [ 0xce59ffa6 , 0x10991ad8 , 0xd64686af , 0x41154538 , 0xf8294db1 , 0x5dbfe002 , 0x04670457 , 0x23ea3d92 ] )
+  , Mont::unsafe_make( [ 0xf7b22618 , 0x9dc62e8b , 0x365f0b98 , 0xaab7e295 , 0x3c9190d1 , 0x52fa0d52 , 0x1af296ac , 0x0f65663a ] )
+  , Mont::unsafe_make( [ 0x54e37e8d , 0x36a08ecb , 0xa572422f , 0x947d4157 , 0xf26948bf , 0xeccb1a78 , 0xd0cb6778 , 0x220dd98a ] )
+  , Mont::unsafe_make( [ 0x9691fbb6 , 0xa87bdffe , 0xb30a09d8 , 0xcb46ce0b , 0x5a8ae319 , 0x59a25ab0 , 0xb99b2f48 , 0x14b5a9e6 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xd0937b54 , 0xbdaf0500 , 0x250168b7 , 0xb666af5d , 0x9486f204 , 0x89837259 , 0x36d9d4d8 , 0x203fa7c0 ] )
+  , Mont::unsafe_make( [ 0xe17c54d7 , 0x5d45120d , 0x5606809c , 0x9d046f79 , 0xdfd97e18 , 0xb211c9a8 , 0x63bbd71a , 0x112b692d ] )
+  , Mont::unsafe_make( [ 0x4059f013 , 0x1eb36c22 , 0xa4acbb19 , 0x09522f37 , 0x11ba39ba , 0x8f0b818c , 0xcdb9e891 , 0x240db29d ] )
+  , Mont::unsafe_make( [ 0xb3aa6889 , 0xe379cc3c , 0x6926c501 , 0x46648bc9 , 0xf115b793 , 0x1457a750 , 0xb7f6693a , 0x301125cd ] )
+  , Mont::unsafe_make( [ 0x66fd4f4f , 0xa5ee2069 , 0x247ccf94 , 0xc3e1112f , 0x455837e2 , 0x17000641 , 0xb79f8866 , 0x1e78109e ] )
+  , Mont::unsafe_make( [ 0xd46e1ad6 , 0x6a6ecfd9 , 0x80fd6f4f , 0x41c67c55 , 0xa4f9b7f7 , 0x45e6bba9 , 0x9f1894c2 , 0x22f98094 ] )
+  , Mont::unsafe_make( [ 0xbe4eb742 , 0x8639eeab , 0xae1e4334 , 0x3167eea8 , 0x48f0d31b , 0xc8da70d1 , 0x848ce2b4 , 0x2fc901d7 ] )
+  , Mont::unsafe_make( [ 0x670af19c , 0x7f79ce15 , 0x1389c4a3 , 0x396d56d2 , 0xf5c63342 , 0x77e45184 , 0xb9d48ad4 , 0x07b264d0 ] )
+  , Mont::unsafe_make( [ 0xf268ad12 , 0x34398cd6 , 0xc28fb695 , 0x7f21c96f , 0x2cf70b3f , 0xd3531dd6 , 0xd1e67923 , 0x0da1ea89 ] )
+  , Mont::unsafe_make( [ 0x60f5b46b , 0x2fc2c705 , 0x325be4a9 , 0xd7a0035e , 0x095bc4f8 , 0x2add9ced , 0x49339f3f , 0x0a3e4f38 ] )
+  , Mont::unsafe_make( [ 0x05344457 , 0xbe70c43a , 0x4ab56bf3 , 0xea5f18e0 , 0xe62cb7ee , 0x78353258 , 0x0ba9f678 , 0x2ee51c64 ] )
+  , Mont::unsafe_make( [ 0x9be4eea5 , 0xaea7e193 , 0xa7d891e0 , 0xccd2843e , 0xc1e66476 , 0x330f9dbe , 0xcfdcf275 , 0x1d62a69d ] )
+  , Mont::unsafe_make( [ 0x6efe9849 , 0xaaf81e5f , 0xf797222d , 0xfb2256fa , 0xda34ce80 , 0xeae72819 , 0xdc33e223 , 0x127de9e5 ] )
+  , Mont::unsafe_make( [ 0x068de73d , 0xa48d76f4 , 0x51838020 , 0xa2ffe5e4 , 0x321e7451 , 0xc7038dc8 , 0x0d24d361 , 0x1ff6e51e ] )
+  , Mont::unsafe_make( [ 0x70f243cb , 0x587ebb37 , 0x502e5735 , 0x7fd5876f , 0x275c61d2 , 0xe5d81f1f , 0xd108a260 , 0x1dfe51e0 ] )
+  , Mont::unsafe_make( [ 0x48b059f8 , 0x8365f0d0 , 0x7475a6a8 , 0x3b6cc6b1 , 0x1257a034 , 0x543d26f4 , 0x248e73d8 , 0x13e4c046 ] )
+  , Mont::unsafe_make( [ 0x95dcb15d , 0x8af51f2f , 0xc23c9581 , 0x4de62dfa , 0x6b01f1d2 , 0x7f6365ed , 0x8d72964b , 0x05354593 ] )
+  , Mont::unsafe_make( [ 0x5b4850fc , 0x356688bc , 0x6730359a , 0xf47246be , 0x45aebb29 , 0x01b5edc7 , 0x196d8b5e , 0x0700337a ] )
+  , Mont::unsafe_make( [ 0xf12b3242 , 0xcb864d70 , 0x4e1b9dde , 0x4392241a , 0x486fde5e , 0x33b67c9d , 0x8b300bf4 , 0x1fae2f22 ] )
+  , Mont::unsafe_make( [ 0x3900ffe9 , 0x2053ef2c , 0x350223c0 , 0x19164f8f , 0x255df9ae , 0xd9e56a0c , 0x574b6612 , 0x08d36627 ] )
+  , Mont::unsafe_make( [ 0x3005e66b , 0x04adca02 , 0x317777b7 , 0x434a0e5d , 0xf6318d28 , 0x50f0b5f8 , 0xc3c29bb3 , 0x1a4bc2e7 ] )
+  , Mont::unsafe_make( [ 0x9e886d13 , 0x44fd901e , 0x23ceb5be , 0x2242e4b1 , 0xe2a0d51d , 0x97598735 , 0x61e0cfa0 , 0x08f5d997 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x5000ac39 , 0x94b97a03 , 0xc60ef716 , 0x3f82ba07 , 0xe8a60a03 , 0x453893d4 , 0xa8660ffa , 0x2df404d8 ] )
+  , Mont::unsafe_make( [ 0x27f3eea1 , 0x8098b8a5 , 0xcd66f0e9 , 0xe72df5d2 , 0x773240f0 , 0x65175a20 , 0x64964398 , 0x0bd13b97 ] )
+  , Mont::unsafe_make( [ 0xfa07d103 , 0x8abff273 , 0x897741a8 , 0x4b1acafc , 0x78de7922 , 0x67b17c0c , 0x93c70a94 , 0x11b4df2a ] )
+  , Mont::unsafe_make( [ 0x8edcd520 , 0x1acca0bc , 0x2d6ebf9b , 0x0a77cef6 , 0xbc2ec51d , 0xa8eaab91 , 0x6d776962 , 0x07184aa7 ] )
+  , Mont::unsafe_make( [ 0x08b1afb4 , 0xc85da069 , 0xfef2ecba , 0x06d1384b , 0x3bba31aa , 0x1de5ad8d , 0x5a69eec4 , 0x27f88490 ] )
+  , Mont::unsafe_make( [ 0xbfaa7cb5 , 0x1b934808 , 0xcec5c776 , 0xf15839d2 , 0xd5d090b2 , 0x8da3ad54 , 0xbdaa1885 , 0x28dad43b ] )
+  , Mont::unsafe_make( [ 0x8bd65d7b , 0x9888e3ff , 0xe3ddb297 , 0xefe6c566 , 0x6fca1c80 , 0xf3a1b768 , 0xafbbec85 , 0x2c314372 ] )
+  , Mont::unsafe_make( [ 0xf6f7a2eb , 0x5938413e , 0x4574b173 , 0x99d70d53 , 0xb9711bfd , 0x575925c9 , 0xf54282e0 , 0x01cf7f66 ] )
+  , Mont::unsafe_make( [ 0x7b2663ba , 0x4aff8ee8 , 0x8b6b690c , 0xf32d19e5 , 0x9e327134 , 0x31396e90 , 0xa37ca5d8 , 0x0ddf7b25 ] )
+  , Mont::unsafe_make( [ 0x55a72928 , 0xc1ac955b , 0xfd4bfec8 , 0xc40d5733 , 0xe470c78e , 0x0e782aa4 , 0x94a3b64d , 0x211252a7 ] )
+  , Mont::unsafe_make( [ 0xab9473cc , 0x8a03806d , 0x5212e92b , 0xfa873993 , 0xb5480adb , 0x0c022a93 , 0xaf81dd81 , 0x01ed7585 ] )
+  , Mont::unsafe_make( [ 0x1b2a2d64 , 0x070fb753 , 0x32def60d , 0xc5307ba2 , 0x92d8149e , 0xfe6087a4 , 0x0d6662ab , 0x19f48a0f ] )
+  , Mont::unsafe_make( [ 0x57dd6188 , 0x0ee0c832 , 0x654dcc1f , 0xba95d385 , 0x95510fef , 0x2cae12d3 , 0x1bb92fce , 0x0891b865 ] )
+  , Mont::unsafe_make( [ 0xfc299222 , 0x8a5ddec7 , 0x6680a8c7 , 0x6730f84c , 0xfd575ee1 , 0x03f79574 , 0xe25d12de , 0x13331816 ] )
+  , Mont::unsafe_make( [ 0x1bb9d525 , 0x3c30b5cc , 0x3700211b , 0x291c6a37 , 0x2c29891f , 0xf47fe8c1 , 0x5a38b623 , 0x2079dfaa ] )
+  , Mont::unsafe_make( [ 0xe3ebb1af , 0x653758ab , 0xba88c95e , 0xb16f735e , 0x1435f87d , 0x2338adf6 , 0x5965427b , 0x27e9d7f8 ] )
+  , Mont::unsafe_make( [ 0xd935ff64 , 0x9d4efd76 , 0xaf95b384 , 0xf1227a27 , 0xf4499166 , 0x383925fc , 0x7d1269bb , 0x19483da1 ] )
+  , Mont::unsafe_make( [ 0x87680fe8 , 0x02aec82c , 0x34934617 , 0x4bb457d9 , 0x21407386 , 0xb601084d , 0x6b53d0a4 , 0x184af1e4 ] )
+  , Mont::unsafe_make( [ 0xaaeff380 , 0xf6bfc980 , 0xb56b71f7 , 0x259079af , 0xf5268b36 , 0xf5b858c9 , 0xa672b589 , 0x23890a3d ] )
+  , Mont::unsafe_make( [ 0xa72d923a , 0xb720090f , 0x54a0bc70 , 0x94da5bda , 0xebe6278c , 0x86e5818b , 0x645f3820 , 0x1a861a1f ] )
+  , Mont::unsafe_make( [ 0xbdca50c2 , 0x5a3116a5 , 0xd5601e33 , 0x5b80d830 , 0xbd3320d6 , 0xc6722d31 , 0x3d5e09b6 , 0x2c405020 ] )
+  , Mont::unsafe_make( [ 0x3da267fc , 0x42330ab1 , 0x0575ee68 , 0xaa3b9556 , 0x0f87a309 , 0x6754b5df , 0xe9170c1f , 0x08261151 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xed75f44f , 0xd8685399 , 0xf5adbc1c , 0xa7faf413 , 0xdfdf647f , 0x1de837a3 , 0x214a5960 , 0x06ba28ab ] )
+  , Mont::unsafe_make( [ 0x74007154 , 0x853da6cf , 0xdf7b310c , 0xe13f6fb4 , 0xd5f0da5c , 0x13c3529f , 0xc7b9efe3 , 0x0c2d7a5b ] )
+  , Mont::unsafe_make( [ 0x335c638d , 0xe7802bdd , 0x547cd956 , 0x111d4fbb , 0xcd4741c2 , 0xf42b14e1 , 0xd5750f16 , 0x166e31f8 ] )
+  , Mont::unsafe_make( [ 0xd424d704 , 0x8edc371d , 0x57f097f7 , 0x4869d8fa , 0x2f0b1079 , 0x273cc8dd , 0xfec8dd7e , 0x17431c17 ] )
+  , Mont::unsafe_make( [ 0x6bb4fa5f , 0x34cda2af , 0x02c6bb11 , 0x73dbe187 , 0xc5eeca4b , 0x4df5d1fd , 0xd7bfc637 , 0x18145c60 ] )
+  , Mont::unsafe_make( [ 0x23223a6e , 0x51f3c636 , 0x64157244 , 0x1bd83690 , 0xe4ce77ca , 0xa389d68b , 0xc2073f3e , 0x11d1cad3 ] )
+  , Mont::unsafe_make( [ 0xd49c73b5 , 0xbaa8cab7 , 0x0dd9b85b , 0x3f47267e , 0xf61957c1 , 0x33f7301a , 0x4967ab8d , 0x217cbece ] )
+  , Mont::unsafe_make( [ 0xc39ab8bb , 0x0f97092c , 0xf5e918f8 , 0x5bfc9a14 , 0x8d0c4778 , 0x0c6fbc28 , 0x5b641659 , 0x25c04015 ] )
+  , Mont::unsafe_make( [ 0xcd46e1cb , 0x57633c0f , 0x6a7f2d9d , 0x4acab14e , 0xaebd3aa3 , 0x75529975 , 0x900cf66c , 0x260dfbc8 ] )
+  , Mont::unsafe_make( [ 0xc5b7295d , 0x41ed33c1 , 0x78ba3aed , 0xd2ce6a8a , 0x4b1ed2cc , 0x493a103a , 0xf4bf7dd6 , 0x2584762e ] )
+  , Mont::unsafe_make( [ 0xd63c93cb , 0x84260fdc , 0x080a1c88 , 0x88c8fc29 , 0xad60f51c , 0x73ad9a4d , 0x2623bd7a , 0x04651ee2 ] )
+  , Mont::unsafe_make( [ 0x3469d945 , 0x553fbce6 , 0xe72331fa , 0xefa2b236 , 0x61a94867 , 0xe7525bfe , 0x5902a914 , 0x0b27a2d4 ] )
+  , Mont::unsafe_make( [ 0x88c529e0 , 0xde03b3d2 , 0x027ab29b , 0xd9ca9716 , 0x1e3603f7 , 0xd608a28f , 0x4fae5eb4 , 0x21e99a49 ] )
+  , Mont::unsafe_make( [ 0xede896c5 , 0x2cf71eed , 0x1a2fb892 , 0x2f3e518a , 0x8862cea0 , 0x14a20a75 , 0x22d95e50 , 0x118b82c1 ] )
+  , Mont::unsafe_make( [ 0x81d342d8 , 0x5ec13e0b , 0x9bf1177f , 0x9fa930d5 , 0xc9dd2dac , 0x3909ea6d , 0xadf4fa99 , 0x23f8a2f2 ] )
+  , Mont::unsafe_make( [ 0x76519f61 , 0x47241064 , 0xb44b1184 , 0x8f342d1d , 0xe653569e , 0x5f0aff96 , 0x7d67e73d , 0x1e2148e2 ] )
+  , Mont::unsafe_make( [ 0x32c90acf , 0xbdb19c6a , 0x653e9132 , 0xdba54280 , 0xb3dd0d31 , 0x4c16ed12 , 0xd63f4e3a , 0x11148d9a ] )
+  , Mont::unsafe_make( [ 0xa9fb9b04 , 0xbfb7150b , 0x6fccaba6 , 0xf2a0ed3d , 0xa2e255f9 , 0xaaf21a36 , 0x1695613c , 0x14dc691d ] )
+  , Mont::unsafe_make( [ 0x837709df , 0x10b08403 , 0x9a6ef016 , 0xfbf6bc78 , 0xc60cbee9 , 0x1d445b90 , 0x01c023f8 , 0x073e6348 ] )
+  , Mont::unsafe_make( [ 0x4271e0df , 0x36852585 , 0x08ee2465 , 0x476a061b , 0x4a56ef0d , 0x48dbd0b2 , 0x857ab187 , 0x11c439a3 ] )
+  , Mont::unsafe_make( [ 0x930c45b0 , 0x2532c707 , 0xfe29653d , 0xf9dfb546 , 0x2090823c , 0xc695babe , 0x2e786339 , 0x2debe652 ] )
+  , Mont::unsafe_make( [ 0x9a7b880d , 0xedf2e04d , 0xa2a2266b , 0xaa4540a7 , 0xbbe790e5 , 0xc8d71d9a , 0x1a7f9352 , 0x17ea3a06 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x5eb3a4be , 0x9cc29b07 , 0xd64224b2 , 0xaae372ba , 0x77fb73da , 0x78b67b53 , 0xb236a8c4 , 0x0a414a22 ] )
+  , Mont::unsafe_make( [ 0x1f532a25 , 0x68871903 , 0x899491dd , 0x6d1c5160 , 0xa57e08ba , 0xf2a2307f , 0xfa6ce7c6 , 0x2de2074c ] )
+  , Mont::unsafe_make( [ 0x3f92438e , 0x1d3a66b1 , 0x7684c3d1 , 0x3c3fe0ce , 0x669469a9 , 0x7fbc984a , 0x0ddbfc1e , 0x2e39341f ] )
+  , Mont::unsafe_make( [ 0x0b3761db , 0xb68db429 , 0x5293ffc9 , 0xb65ee8a7 , 0xac6f2c51 , 0xa9657bd3 , 0x50cefafc , 0x1ef7a2c4 ] )
+  , Mont::unsafe_make( [ 0x7044d547 , 0xf0cd6337 , 0x45f14e37 , 0x407fe0c4 , 0x4168ff4e , 0x9766f038 , 0x9545ca18 , 0x0106a3ce ] )
+  , Mont::unsafe_make( [ 0xabd59a43 , 0x1659b964 , 0x6c272e39 , 0x86a071fa , 0x4d7a547c , 0x6b3d1c87 , 0x883112a5 , 0x0cfd6d2a ] )
+  , Mont::unsafe_make( [ 0xb03f47b6 , 0xc675756c , 0x56623c5c , 0x4f813d06 , 0x1a1fe99a , 0x794d0b59 , 0x3b81f409 , 0x19f1dbd4 ] )
+  , Mont::unsafe_make( [ 0xf500cf99 , 0xae7200b8 , 0x55a24872 , 0xf6e18e81 , 0x2547b6e3 , 0x08f4dc2d , 0x0c6db17c , 0x2e09c0a5 ] )
+  , Mont::unsafe_make( [ 0x878d62de , 0xd5ce02c7 , 0x5e5b4288 , 0x8bb16c21 , 0xbb3d9482 , 0x99e12c63 , 0x65af02f0 , 0x0caa3a35 ] )
+  , Mont::unsafe_make( [ 0x0d04bfb9 , 0x6da24b1b , 0x3b6da9d5 , 0x19a9e2db , 0x9fdceb82 , 0xc5fd768b , 0x7eb42060 , 0x010bb3c0 ] )
+  , Mont::unsafe_make( [ 0xe1a9c239 , 0x242f94e8 , 0x783d93ab , 0x5b914b41 , 0x2488a778 , 0xabd306cc , 0xe9791718 , 0x0f2fe159 ] )
+  , Mont::unsafe_make( [ 0xbe9c3302 , 0xd7168071 , 0x7d287d5a , 0xef512c9c , 0xbb8c8fa7 , 0xed598310 , 0xa78f3b21 , 0x22e4e5d0 ] )
+  , Mont::unsafe_make( [ 0xd74d8b14 , 0xfca0c0ed , 0xd5f30ee4 , 0xe564a28a , 0x696ec8b9 , 0xfb329917 , 0x599006fb , 0x1173d403 ] )
+  , Mont::unsafe_make( [ 0x3bd3142d , 0xf183e37d , 0x84c16b95 , 0x40a1dee2 , 0x158feb26 , 0x6c66f963 , 0xebacde95 , 0x096221c4 ] )
+  , Mont::unsafe_make( [ 0xc71b6728 , 0xd3c30d24 , 0xf0b048cf , 0x9a843b29 , 0xfa113c83 , 0xe6376072 , 0x45da7f66 , 0x263571f8 ] )
+  , Mont::unsafe_make( [ 0xca527a3a , 0x0f209a64 , 0xf09776b5 , 0xbf3dd3f5 , 0x5b6864ea , 0x69390d82 , 0x90bdbd72 , 0x2607c5a7 ] )
+  , Mont::unsafe_make( [ 0x0e1f0c78 , 0x3c7c3fb9 , 0x4af9c94c , 0xbbab7550 , 0xfc8f2d28 , 0x874a35e1 , 0xd1eafa60 , 0x0dc8fb0c ] )
+  , Mont::unsafe_make( [ 0x8579573a , 0x4325b6da , 0xf2cdfd19 , 0x75db2717 , 0x62947925 , 0x3298fbb3 , 0x2471c4fa , 0x19e8eee9 ] )
+  , Mont::unsafe_make( [ 0x3d4a4872 , 0x1d8d558f , 0x1b4b0fd1 , 0xee0c3228 , 0x8d53a643 , 0x7bcacc80 , 0x50bd848e , 0x1b0b5ac8 ] )
+  , Mont::unsafe_make( [ 0x63ca7784 , 0x8c6e2f6e , 0xbdd31ccb , 0xef2523ee , 0x33839ba4 , 0xf8fa9457 , 0xd74a1353 , 0x3037e398 ] )
+  , Mont::unsafe_make( [ 0xbd3bf5f0 , 0x4a417536 , 0x3930137a , 0x989fb999 , 0xee843a7f , 0xe78e846b , 0xa36e412e , 0x0ed2b121 ] )
+  , Mont::unsafe_make( [ 0xd78eb925 , 0x719e97bc , 0x82f84f01 , 0x730e3c95 , 0xd8535d4f , 0x128836c2 , 0x52091291 , 0x2ff8ec49 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xa9b83b77 , 0x7c8a3dd2 , 0x091c1965 , 0x5e5bd4d9 , 0x5932b751 , 0xe46f90bd , 0x21c17ea2 , 0x0c7010a4 ] )
+  , Mont::unsafe_make( [ 0xceac3197 , 0x99c908c9 , 0xd39e28a2 , 0xc80921b6 , 0x107ee733 , 0x694fe231 , 0x0ca5191b , 0x1ae4e53f ] )
+  , Mont::unsafe_make( [ 0xedbd85d0 , 0x7930cc78 , 0x30c1f99b , 0x11b29774 , 0x81e8156d , 0x2b8f3d7b , 0xf9116ecc , 0x172001fc ] )
+  , Mont::unsafe_make( [ 0x2e53bf01 , 0x864c6c4c , 0x1e4b777c , 0x8f98f8cc , 0x02e6034d , 0x6a8613eb , 0xd92f3f49 , 0x1c37170c ] )
+  , Mont::unsafe_make( [ 0x9c1e3993 , 0x238da607 , 0xe181fecb , 0x0fcc1acd , 0xfc8189e9 , 0x86867489 , 0xca54927b , 0x210f01c3 ] )
+  , Mont::unsafe_make( [ 0x4b3c1bb5 , 0xa90a986c , 0x4ef7811c , 0xd2b0c7a4 , 0xe884fb3b , 0x67664556 , 0xb901c465 , 0x1f96a524 ] )
+  , Mont::unsafe_make( [ 0x6fcd1f54 , 0x7e4c98dc , 0x40d84655 , 0x79d9908e , 0xc5651361 , 0xf167f794 , 0xe8046223 , 0x270e6091 ] )
+  , Mont::unsafe_make( [ 0x0fdc03a7 , 0x20854396 , 0x9b0b1e96 , 0xebf1ca53 , 0xe8f324a4 , 0xab2224db , 0x7eef3444 , 0x2a828834 ] )
+  , Mont::unsafe_make( [ 0x8b20c17b , 0x80fc4267 , 0xd2caf5a6 , 0xa41e0a2c , 0x43fb045c , 0x44daa0e0 , 0xa0318e83 , 0x24c8917c ] )
+  , Mont::unsafe_make( [ 0x7b3a9e30 , 0x52bf348e , 0x9a4369ea , 0x22803b21 , 0x8dd953f3 , 0xea95a8f5 , 0xba79f607 , 0x0eb58b81 ] )
+  , Mont::unsafe_make( [ 0xa8c12d52 , 0xcc54b97a , 0xe9439773 , 0xa9b5b758 , 0x4cbd9236 , 0x1032123c , 0x330ec37f , 0x1e860109 ] )
+  , Mont::unsafe_make( [ 0x8608a265 , 0xff2bd414 , 0xf7479ed8 , 0x8c9dfa9b , 0x6a71a487 , 0x6d942afd , 0x8013ff32 , 0x17699bd9 ] )
+  , Mont::unsafe_make( [ 0x61b8e66d , 0xc16ce2f8 , 0xf2acc39c , 0xa87c7827 , 0xc6828cc4 , 0x644abb83 , 0xfe013ff0 , 0x0a979343 ] )
+  , Mont::unsafe_make( [ 0xd59cec83 , 0xdab7fe5a , 0x3cb8b69c , 0x627d5cd4 , 0x98b703f3 , 0x70271f00 , 0x3b074d43 , 0x122f43c6 ] )
+  , Mont::unsafe_make( [ 0xc756dc8a , 0xff87c10f , 0xcceee7c8 , 0xbc5dea81 , 0xf4a07a7b , 0x5727ce0c , 0x6acee730 , 0x20b8fec2 ] )
+  , Mont::unsafe_make( [ 0xe354da72 , 0xf9dcee0e , 0x41e01535 , 0x775ca940 , 0x49bf0ea6 , 0x144591f5 , 0x4ea4f552 , 0x2e349317 ] )
+  , Mont::unsafe_make( [ 0x07b00bb4 , 0x3a1bc73d , 0x6974a5b9 , 0x9f633739 , 0x60098ae8 , 0xd9158e36 , 0xc5044af0 , 0x235b2840 ] )
+  , Mont::unsafe_make( [ 0x18a654d3 , 0xa684a9be , 0x1cb1c79a , 0x0719dad0 , 0x16b3e898 , 0x8be6efb4 , 0x39b46ee8 , 0x089ec049 ] )
+  , Mont::unsafe_make( [ 0x1c01de7f , 0x4b929a19 , 0xe57ac774 , 0x4e353f89 , 0x42d673b3 , 0x46ccf316 , 0xf9b56bab , 0x03287cd1 ] )
+  , Mont::unsafe_make( [ 0x51a8230d , 0x2ad547d3 , 0x0f73814e , 0xe2a57688 , 0xeda54204 , 0x61f6abce , 0xbbbd955b , 0x2656db1b ] )
+  , Mont::unsafe_make( [ 0x48352717 , 0x8d1fb1cf , 0x43c8bcfb , 0xa6bbad73 , 0xc8c79520 , 0x6dd14c39 , 0xf97e8311 , 0x2233e49a ] )
+  , Mont::unsafe_make( [ 0x7b5808c8 , 0x736cf9b6 , 0xb64aacf1 , 0x56e8d41d , 0x78a4a647 , 0xfcf90785 , 0xb9448376 , 0x28120031 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x4a637ecd , 0x481132b9 , 0xa52c3f51 , 0x309011e9 , 0x286fb549 , 0xd65393a3 , 0x0f5deb91 , 0x2157a291 ] )
+  , Mont::unsafe_make( [ 0x74c50b90 , 0xc4ee4b70 , 0x7270aac6 , 0x87c7c77f , 0xa8857f49 , 0x080ce5eb , 0x49abf5a8 , 0x114563f5 ] )
+  , Mont::unsafe_make( [ 0xda128f05 , 0x1b5a2e5a , 0x8c20ff1f , 0x189d1548 , 0x12ee4537 , 0x5458464a , 0x03cad95d , 0x1fd143a2 ] )
+  , Mont::unsafe_make( [ 0xed0055cc , 0xed109368 , 0xbebfe59d , 0x941ee3be , 0x70e71730 , 0x48446efa , 0xbff720f8 , 0x08f946bf ] )
+  , Mont::unsafe_make( [ 0x5c13585d , 0x8d4dcd57 , 0x8cdd8e3a , 0x3f0358c6 , 0x87748d14 , 0xdf9bbbf0 , 0x455a08c0 , 0x08cb52b2 ] )
+  , Mont::unsafe_make( [ 0x2a6503c7 , 0x13ddec03 , 0x01e1dd83 , 0x2d5c2787 , 0x74a806b8 , 0x0bb5e4b8 , 0x36c0f2a6 , 0x1f90b5d8 ] )
+  , Mont::unsafe_make( [ 0xac80268c , 0x69f3463a , 0x004c23c3 , 0x4b060cde , 0xfe43ad45 , 0x9ade73a8 , 0x0d81afc3 , 0x021d31ab ] )
+  , Mont::unsafe_make( [ 0xe4aec3e2 , 0x595e2bb4 , 0x5ff4efa6 , 0xd927d2b0 , 0xf06da150 , 0xfa1f629f , 0x64bef39a , 0x1df2dba6 ] )
+  , Mont::unsafe_make( [ 0xb228680a , 0xecb73410 , 0x6c0bfce8 , 0x3f899a9f , 0x4bbaf5e2 , 0xb921e0d5 , 0x94cb3048 , 0x21fd4067 ] )
+  , Mont::unsafe_make( [ 0xa9282b52 , 0xac7f96fc , 0xc6bae7ce , 0x3a9d6e87 , 0x1d57a8da , 0x81a9431d , 0x82915d9b , 0x0d91197b ] )
+  , Mont::unsafe_make( [ 0xe478b35c , 0x9b4782c7 , 0x11417806 , 0x745c9995 , 0x6312b608 , 0x74d16ac3 , 0xd338adf1 , 0x0b3c8808 ] )
+  , Mont::unsafe_make( [ 0xec5254d5 , 0x622a3802 , 0x7f4dc427 , 0x5868e6f8 , 0xfeeac020 , 0x7b0e7f57 , 0xd4e145a0 , 0x23880a27 ] )
+  , Mont::unsafe_make( [ 0xc4f1839b , 0x02317872 , 0x42c21fa3 , 0xfa74da0c , 0xeafcbe8c , 0x75e482c4 , 0x4d27db43 , 0x0f850917 ] )
+  , Mont::unsafe_make( [ 0x34fa6899 , 0xb1ad1c4e , 0xd136ab8e , 0x4cb356fc , 0x3cd5d4bc , 0x6e0aa369 , 0xdc8f4f61 , 0x036f0e54 ] )
+  , Mont::unsafe_make( [ 0x79a72263 , 0x0e475852 , 0x24c562fb , 0xac4b905c , 0x531b77a1 , 0x57e3f62b , 0xa7083b13 , 0x253db611 ] )
+  , Mont::unsafe_make( [ 0x1e2bfdbd , 0xa5f9b499 , 0xf1dfa3c8 , 0xe289fd75 , 0x93177654 , 0xd2e62f1e , 0x27e46b4b , 0x098b8af4 ] )
+  , Mont::unsafe_make( [ 0xeadf0335 , 0x80145b4b , 0xb9fa3338 , 0x783ca156 , 0x334772c1 , 0x39a9d69a , 0x6030caa2 , 0x0579fc86 ] )
+  , Mont::unsafe_make( [ 0x59b38e04 , 0x7e3b62e6 , 0xc15dcf33 , 0xa2a5a874 , 0x4c7fb629 , 0x7e2a4bee , 0x75266bac , 0x21240fc1 ] )
+  , Mont::unsafe_make( [ 0x36c63735 , 0x8e03771a , 0xe2115133 , 0x80e0d611 , 0x5f8fb6f0 , 0xb807dadd , 0x0bad0e92 , 0x0340fb1e ] )
+  , Mont::unsafe_make( [ 0x6253ab47 , 0x7ef2740e , 0xb57ad7d6 , 0x511b4b8f , 0x0dc5a9e7 , 0x7912daae , 0xef329d63 , 0x01ea1e27 ] )
+  , Mont::unsafe_make( [ 0xd94caf72 , 0x09ce5c31 , 0x69c5c18f , 0xe638eb4e , 0x4325bec9 , 0x65a3e56a , 0x9fc1ae29 , 0x2279f049 ] )
+  , Mont::unsafe_make( [ 0x0867c1f3 , 0x7a524aa6 , 0x90d20593 , 0x6e62f915 , 0xb7a5d51c , 0xaf9deb74 , 0x8f5385fa , 0x246b5775 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x77601e8a , 0x84f0ff7c , 0xc9726778 , 0xbf216f4c , 0xc418fe74 , 0x5a4e6b86 , 0xa2a22200 , 0x0248b352 ] )
+  , Mont::unsafe_make( [ 0x6e5d4c1c , 0x8540eb00 , 0xb6c5e1f3 , 0x1ae85f96 , 0xc929cd39 , 0xc25b4918 , 0x412ec042 , 0x157ebbda ] )
+  , Mont::unsafe_make( [ 0xf1b39b00 , 0x09289b30 , 0x60fdbe53 , 0x916c44a0 , 0xd6008a98 , 0xd83ad65c , 0x3cb81870 , 0x0efcbefc ] )
+  , Mont::unsafe_make( [ 0x4643a80e , 0x4d0aeadf , 0x0b246883 , 0x2ac2b7fd , 0x470ec219 , 0x3a6ed3f7 , 0x71264b86 , 0x0bf093d6 ] )
+  , Mont::unsafe_make( [ 0x269b4643 , 0x967c1051 , 0xd728bcf9 , 0x7f0a2d84 , 0x2b92afab , 0x3574a31d , 0x39a4f71b , 0x207fd6d1 ] )
+  , Mont::unsafe_make( [ 0x4122cb1e , 0xbab7ea65 , 0xcc114e4a , 0xaa465da5 , 0x92ff655f , 0x8f131053 , 0xfba4a3a5 , 0x1889c780 ] )
+  , Mont::unsafe_make( [ 0x5bd3f86a , 0x6aeca443 , 0xc51a34d5 , 0xb5e73889 , 0x25218ef6 , 0x8b091b3d , 0x814ba6ae , 0x0a97fcc0 ] )
+  , Mont::unsafe_make( [ 0xd43bce7b , 0x15af39bf , 0x5d91af0c , 0x5b78fb11 , 0x17688bbc , 0x88142cb0 , 0x19184dd5 , 0x2589ed63 ] )
+  , Mont::unsafe_make( [ 0x44ab9dbe , 0x2f175156 , 0xb8a0f31b , 0x05063874 , 0x655fbbf0 , 0xffb3816c , 0x0b48daf4 , 0x041c0cd6 ] )
+  , Mont::unsafe_make( [ 0x58a0733c , 0x1d8ea0f4 , 0xf52aec2c , 0xe37600a7 , 0xe58e34d4 , 0x7295e931 , 0x444132ec , 0x01df7b0f ] )
+  , Mont::unsafe_make( [ 0xfa2d8ab1 , 0x33470e26 , 0x5aaa14fd , 0xde5b8369 , 0x622a85e1 , 0x60cc27a9 , 0xc823dd0f , 0x2b703d9c ] )
+  , Mont::unsafe_make( [ 0x33f5b3c3 , 0x9025126f , 0xc5f82c3d , 0xaef4064b , 0x8b14e80c , 0x8c7a08cf , 0x655084a8 , 0x2468b2de ] )
+  , Mont::unsafe_make( [ 0x8c91d07a , 0x08424f27 , 0xa04ac422 , 0x10ea61a6 , 0x7626b641 , 0x06c0776f , 0x1aa8b2ce , 0x2bd3190b ] )
+  , Mont::unsafe_make( [ 0xd297a39a , 0x5ef15149 , 0xf6473afb , 0x5d4dae70 , 0xc0b15484 , 0xd967037f , 0xa0728336 , 0x2c67d399 ] )
+  , Mont::unsafe_make( [ 0x67ae9f88 , 0x4596b4a1 , 0xe7fb01f0 , 0x5f73704e , 0x41a16d09 , 0x83ac08c2 , 0x0de02e44 , 0x24352861 ] )
+  , Mont::unsafe_make( [ 0xba85283e , 0xbd027663 , 0x2ba745e6 , 0x4386d5bb , 0x4c5c89e0 , 0x734e1653 , 0xfc10dcc5 , 0x1c76526a ] )
+  , Mont::unsafe_make( [ 0xb3be0101 , 0xd5f00188 , 0x4cee5760 , 0x5cff3ba3 , 0xdbb92322 , 0xdd1fa32f , 0xc56b63a5 , 0x2d242346 ] )
+  , Mont::unsafe_make( [ 0xce00d798 , 0x157d3917 , 0xd6955eb0 , 0x9d5f14ff , 0x0c74a0f2 , 0xd916d693 , 0xb24da803 , 0x1bb05ef6 ] )
+  , Mont::unsafe_make( [ 0x7cf29648 , 0x2e92a15e , 0x73279e21 , 0xf0b6609a , 0x4a349b0f , 0x9a2de51c , 0x98c1a7c4 , 0x0197722b ] )
+  , Mont::unsafe_make( [ 0xced30308 , 0x9c06dc26 , 0xea8092fa , 0x24406c31 , 0x08f365b3 , 0x16453848 , 0xcd3a87c3 , 0x1cedba90 ] )
+  , Mont::unsafe_make( [ 0xd5d07ce1 , 0x620120aa , 0xf2d34cd7 , 0x4833d4fc , 0x8a02d6ee , 0x70c63db2 , 0x850ae9d5 , 0x2e7ad20d ] )
+  , Mont::unsafe_make( [ 0x89cd7944 , 0xea749710 , 0x97179be5 , 0xe7b07bee , 0x49f506d7 , 0x914d2791 , 0x006a84dc , 0x0cf54440 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xc6aefdca , 0xa22e5f37 , 0x5f1c8587 , 0xab9f497a , 0xb0e8d818 , 0x06addfd1 , 0x53fda9f3 , 0x20d5b270 ] )
+  , Mont::unsafe_make( [ 0x4878d87b , 0x7aba46e3 , 0xfaa3fa86 , 0xff23ea8e , 0xa3f2b4b9 , 0xc2539731 , 0xc2bea163 , 0x085f7bdd ] )
+  , Mont::unsafe_make( [ 0x108e25e6 , 0x9a9fe8ac , 0x05c08a85 , 0xa5699750 , 0xb45a9ecf , 0x809d19e6 , 0x426c4d7f , 0x0b07f4de ] )
+  , Mont::unsafe_make( [ 0x54a42e1a , 0xbdb14ca9 , 0xea04c0eb , 0x4c47c760 , 0xd448c1d2 , 0xd7514729 , 0xb3302416 , 0x100e6c00 ] )
+  , Mont::unsafe_make( [ 0x24046df4 , 0xa33e5480 , 0x6d48d15c , 0x8c0d1d82 , 0x660a95c1 , 0x667ac16d , 0xc509bfb1 , 0x2e06ab21 ] )
+  , Mont::unsafe_make( [ 0xde9433ec , 0x7d8c3377 , 0xcbb7260a , 0x33f29437 , 0xf0477851 , 0x1272e996 , 0x34a8bce5 , 0x122ea978 ] )
+  , Mont::unsafe_make( [ 0xbbddae27 , 0x0a7a6347 , 0xa3c49520 , 0x6a234a35 , 0xbe4d7c02 , 0x47d727a5 , 0x8df53f3d , 0x0bf4255a ] )
+  , Mont::unsafe_make( [ 0xbdb02f11 , 0xc7dd4599 , 0x0b43a13b , 0xfa2c8db6 , 0xc8d44abb , 0x57c4537b , 0xae079b0a , 0x1e1d0c95 ] )
+  , Mont::unsafe_make( [ 0xb73acbe6 , 0xdde439de , 0x2ec6492c , 0xc7146c53 , 0x52488d18 , 0x86861fd4 , 0xaae61a54 , 0x11ecc7f3 ] )
+  , Mont::unsafe_make( [ 0xf4218bcd , 0xaf612c5f , 0xb92fa609 , 0xcb471af2 , 0x131dd719 , 0x1a1251eb , 0x0f72678c , 0x184729a8 ] )
+  , Mont::unsafe_make( [ 0xb8bfa536 , 0xe774f01d , 0x7f062020 , 0xddb4cb58 , 0x79206a58 , 0x0efc8ea0 , 0xaaab02b4 , 0x18a958a4 ] )
+  , Mont::unsafe_make( [ 0x2648de3f , 0x1134e847 , 0xd5959956 , 0xf4ffe80d , 0xb3b134bf , 0x7eacd204 , 0x47eaf9b4 , 0x2ce86396 ] )
+  , Mont::unsafe_make( [ 0xfd738826 , 0x750d6e0a , 0xb525c1ef , 0x80588f4d , 0x9ae87104 , 0x5e2963b3 , 0xdf89a25f , 0x17d58a3d ] )
+  , Mont::unsafe_make( [ 0x71f7d799 , 0x98547211 , 0x73916a87 , 0x061a7d7f , 0xc368cbea , 0xf0db9138 , 0xf246cfae , 0x27d4fe36 ] )
+  , Mont::unsafe_make( [ 0x719bbad2 , 0xf593c420 , 0xddd31c35 , 0x3e63d183 , 0x4ce2f11c , 0x6b95b24c , 0x84f005b4 , 0x07e81ce4 ] )
+  , Mont::unsafe_make( [ 0xf620bb92 , 0xd13d3279 , 0x8e046ab2 , 0x8332b891 , 0x3216ea7d , 0xdf7a0410 , 0x0f22925e , 0x0033356c ] )
+  , Mont::unsafe_make( [ 0x6a4388d6 , 0x0dfa19bd , 0x45be7ed9 , 0xb2243d7f , 0xf47efe1a , 0x7a2788de , 0x5653abc5 , 0x0b54a783 ] )
+  , Mont::unsafe_make( [ 0x74f1d5bc , 0x79679779 , 0xe639f349 , 0xf7a501b6 , 0x98824eb4 , 0x01167dfd , 0x83c03df9 , 0x045f108f ] )
+  , Mont::unsafe_make( [ 0xb7d12e6e , 0x8fc2f85c , 0x8744c675 , 0x593bcaa3 , 0x9a53ecab , 0xe1be9f3f , 0x11c56e59 , 0x2ad18666 ] )
+  , Mont::unsafe_make( [ 0xde977c88 , 0x479e2586 , 0x4a87c49d , 0xb887c2a3 , 0xa0c85674 , 0x8a8398f6 , 0xf237927d , 0x08775966 ] )
+  , Mont::unsafe_make( [ 0x91f25e34 , 0xa4baf60a , 0x5134b4be , 0x3b6b7c98 , 0x545911cb , 0x0114e800 , 0x87e94bcf , 0x2c90c636 ] )
+  , Mont::unsafe_make( [ 0xeada5021 , 0xc36f1792 , 0x97065f86 , 0x28e4e9ea , 0x5f3433ae , 0x76406306 , 0x1f89117d , 0x30441445 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x859051bd , 0x5e7e2b1a , 0x4d966ffd , 0x0b20cc4c , 0x20e4881a , 0x20efb9b2 , 0xf8895cf6 , 0x234fdb81 ] )
+  , Mont::unsafe_make( [ 0xbfae12b7 , 0x5396568e , 0xd3828ee0 , 0xf5f923c7 , 0x8327b47b , 0xd2548ac5 , 0x5b20f073 , 0x0a58d99c ] )
+  , Mont::unsafe_make( [ 0x9a65b521 , 0x85028d9b , 0xd72bd3fd , 0x5b97c3f9 , 0x72122074 , 0x8a8e73cf , 0xda3d7a5a , 0x07771f31 ] )
+  , Mont::unsafe_make( [ 0xe33f27d6 , 0x7849afaf , 0xae9f919e , 0xf50866a6 , 0x07020e48 , 0x14910bf6 , 0xae51f9b8 , 0x0da9bce2 ] )
+  , Mont::unsafe_make( [ 0x4bbc49b5 , 0x7fd7b30e , 0xe75e0749 , 0xa5d71ef5 , 0x8986b28b , 0x16614d0d , 0xc7001b53 , 0x1f630fdf ] )
+  , Mont::unsafe_make( [ 0xa99514e8 , 0xed70363d , 0x3a5cca2a , 0x6fbed117 , 0x35612fec , 0xa10c7444 , 0x8cafbece , 0x0fcf1a18 ] )
+  , Mont::unsafe_make( [ 0xb17eb499 , 0x29c5e8ef , 0x8680c5a2 , 0x9b9ed6f4 , 0x03e733ee , 0xb2085f47 , 0x94e76250 , 0x2be2abd3 ] )
+  , Mont::unsafe_make( [ 0xe7e7b43e , 0x06e902a8 , 0x3ee8ab27 , 0x345f52e0 , 0x41fde818 , 0x46bcb7ee , 0x86bcbde2 , 0x1746fb84 ] )
+  , Mont::unsafe_make( [ 0x254b8838 , 0xe0c203cb , 0x2277bfc6 , 0xd8f57965 , 0x5b574487 , 0xac0dd7c5 , 0xb191777a , 0x0b5d88f9 ] )
+  , Mont::unsafe_make( [ 0x5d6e0aa4 , 0xcb9fa8bc , 0x31932335 , 0xc7577fe0 , 0x0c8f7945 , 0x509a2989 , 0xcba52b3f , 0x18a85e18 ] )
+  , Mont::unsafe_make( [ 0xf436f744 , 0xb677f62f , 0x758ccc0e , 0x1d02266e , 0xafebc660 , 0x4aaf1c18 , 0xc4d900cf , 0x265ad102 ] )
+  , Mont::unsafe_make( [ 0x33330ccc , 0xa794c08d , 0x857043c9 , 0x8a7601a3 , 0x2d072974 , 0x679ccf8c , 0x261f5d7a , 0x0f585dde ] )
+  , Mont::unsafe_make( [ 0xe42e4a29 , 0xe276a1f4 , 0xdd462e12 , 0x4df1a37a , 0x3e5d6ea1 , 0x76a1a77b , 0x1ce528e8 , 0x0d3bef0e ] )
+  , Mont::unsafe_make( [ 0x03a5b6be , 0x34ef56a4 , 0xa2550d79 , 0x85a4d5fd , 0x495516ac , 0xfa99e4d8 , 0x61716514 , 0x07af476b ] )
+  , Mont::unsafe_make( [ 0x23036308 , 0x0fd400f5 , 0x08088f4c , 0x2a74fe08 , 0xd9851fb1 , 0x50c436bc , 0xea1ed2ed , 0x2c487996 ] )
+  , Mont::unsafe_make( [ 0x1b0840f5 , 0xcee4b12b , 0x5ab39dff , 0x54e3e9b7 , 0x19a0ceac , 0xf21cc7c0 , 0x5f0a898c , 0x1e5ea562 ] )
+  , Mont::unsafe_make( [ 0x948d33d5 , 0x0c3da167 , 0x53edd687 , 0x8735a3f5 , 0x2ffeff0c , 0xbb8450c4 , 0xfffd0299 , 0x0c94cc1e ] )
+  , Mont::unsafe_make( [ 0x9032f208 , 0xa99f54d1 , 0x7c2307a0 , 0x37df621c , 0xf154af03 , 0x07940fd1 , 0xf7fb43fc , 0x12e8c253 ] )
+  , Mont::unsafe_make( [ 0xf7dd0afc , 0xbc111098 , 0xd0a88fa5 , 0x9b4b84a8 , 0x669a76e8 , 0x882b37da , 0x2edd73ef , 0x1b12ce83 ] )
+  , Mont::unsafe_make( [ 0xec10bf13 , 0x45fdf64f , 0x1ae62a16 , 0x7ece1de6 , 0x88599b8a , 0xca7564cc , 0xa347e0a2 , 0x09f8356f ] )
+  , Mont::unsafe_make( [ 0xbdcb5b53 , 0xcc7a4cc1 , 0x7d5ac386 , 0xf232fc19 , 0xa9a2e746 , 0x9a1364ba , 0xd8e6960c , 0x27395519 ] )
+  , Mont::unsafe_make( [ 0xfcafba22 , 0x70f88cc6 , 0x09d11d7a , 0x2854f715 , 0x8177fc69 , 0x8d70f4a8 , 0x7e06c4dd , 0x0218f44f ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xb772a70f , 0x54511c94 , 0x72932bdd , 0xeba80c17 , 0xb3ff5b29 , 0x0a3a898a , 0xe9596599 , 0x06c9ddfa ] )
+  , Mont::unsafe_make( [ 0xf9b4dd0d , 0x281dea7b , 0x5a0ccc11 , 0x14f9c43b , 0xed47655a , 0x59431fb8 , 0xb4ccfee0 , 0x07271213 ] )
+  , Mont::unsafe_make( [ 0xc3578cf3 , 0x534f0942 , 0x24503396 , 0xb5f95880 , 0x3c70765e , 0xadd3f46d , 0x635c22fe , 0x0dfb4b18 ] )
+  , Mont::unsafe_make( [ 0xc1b76834 , 0xc247ff8e , 0x41351d10 , 0xf9f8d462 , 0xa75e5b34 , 0xc0cd45b9 , 0x4ca807f8 , 0x02d180c0 ] )
+  , Mont::unsafe_make( [ 0xf66b203f , 0x1b12b42b , 0xeac925b0 , 0x1e8ca212 , 0xc1eb456f , 0xa0c8ba43 , 0x5de6f080 , 0x13489b07 ] )
+  , Mont::unsafe_make( [ 0xdecea43a , 0xdcd4ce88 , 0x39c6ee94 , 0xbd0198f8 , 0xcf8fdc6a , 0x03eb33d7 , 0xeeb4c9ef , 0x1424886e ] )
+  , Mont::unsafe_make( [ 0x8b4ee708 , 0xdf54dbaa , 0xbb764fd1 , 0x802837b0 , 0x25b133d8 , 0x71a05c3a , 0x4a9dbbdb , 0x29c94ab6 ] )
+  , Mont::unsafe_make( [ 0x51e310fc , 0x2b85433a , 0xbd619555 , 0xf3a52d7f , 0x531b42ec , 0x24550754 , 0x67df0626 , 0x09f3b2f4 ] )
+  , Mont::unsafe_make( [ 0x379ec1c3 , 0x3fd8e331 , 0x8399d718 , 0xdfeae334 , 0xfd6dc80a , 0xd94254f8 , 0x36152f87 , 0x18a43596 ] )
+  , Mont::unsafe_make( [ 0xe4f54389 , 0x73a0aaf6 , 0x0a79c7e1 , 0x44e4e44d , 0x8c22b777 , 0x78795834 , 0x807bb7a2 , 0x051b4aa6 ] )
+  , Mont::unsafe_make( [ 0x1f927014 , 0xdb279324 , 0x491ba2bf , 0x5e7632ca , 0x69cb71e5 , 0x97dffe82 , 0x4f3f2f91 , 0x2ed89aa9 ] )
+  , Mont::unsafe_make( [ 0x530a2f7a , 0xf2ab22e1 , 0xd8d42d9d , 0x72dc3469 , 0xfec8254b , 0x2e6ac77e , 0x47af7c2c , 0x043e1ddd ] )
+  , Mont::unsafe_make( [ 0x527d3c1e , 0xbcae4039 , 0xae420d12 , 0x2a07df07 , 0x24ee91dd , 0x391a3fe7 , 0x9934f505 , 0x242538f4 ] )
+  , Mont::unsafe_make( [ 0x9a0489f0 , 0x693548eb , 0x86d4416d , 0x4db4dbe8 , 0x323d24c4 , 0x3af4a9d4 , 0xfa29707e , 0x2f2eff25 ] )
+  , Mont::unsafe_make( [ 0xcfac416f , 0x77c7b365 , 0x86ec5f16 , 0x669c5c01 , 0x77429705 , 0xe75b1231 , 0x9786b554 , 0x20b0b540 ] )
+  , Mont::unsafe_make( [ 0x389ff257 , 0xe64400fc , 0x31356f0f , 0x4309224c , 0x411e5940 , 0x8c9ea5cc , 0xffd5d115 , 0x09b35804 ] )
+  , Mont::unsafe_make( [ 0x1d60913f , 0x377c1b26 , 0xab46d806 , 0xc42d2efc , 0x4666f0a0 , 0xc1935aa1 , 0xd65280c6 , 0x13fef9a1 ] )
+  , Mont::unsafe_make( [ 0x8d2b2b73 , 0x1483ccad , 0xa92d1e01 , 0xff3f3216 , 0xc92cca1c , 0xf6f08b62 , 0x990f17ac , 0x00669a5d ] )
+  , Mont::unsafe_make( [ 0xac44952f , 0x7d7d9cc0 , 0x347c19ad , 0x11793bd6 , 0x13adf687 , 0xab074d45 , 0x089983f4 , 0x0ae9cc65 ] )
+  , Mont::unsafe_make( [ 0xaf30a6f7 , 0x9c04a0c9 , 0x13373d9f , 0x0696604a , 0xc6b01961 , 0x4b2bcaf1 , 0x7fa62ad4 , 0x20d22b8e ] )
+  , Mont::unsafe_make( [ 0xb63f062b , 0x2ec7bc2d , 0xa74542d1 , 0xde0339df , 0xfef153d3 , 0x9e52c601 , 0xe2f7755f , 0x2e99ba57 ] )
+  , Mont::unsafe_make( [ 0x712c8232 , 0x6c68566f , 0xa10e6941 , 0x524addaf , 0xb945238f , 0x9942b9f6 , 0x5399b4a3 , 0x06ec9f82 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x7057c0e2 , 0x5336dbd7 , 0x46214484 , 0xdc8b434e , 0x8d636716 , 0xca0cb36e , 0x50d5f093 , 0x013721f1 ] )
+  , Mont::unsafe_make( [ 0x6ba768d4 , 0xf5ae21f6 , 0xfbe2e61e , 0xdf281b31 , 0x8ce0afe5 , 0x7161fc28 , 0xed526071 , 0x0a68c906 ] )
+  , Mont::unsafe_make( [ 0xca128244 , 0x4cc40d22 , 0x1f4551d4 , 0xff1f134c , 0x46e273cd , 0xd6e41849 , 0x781a364c , 0x21ba59f8 ] )
+  , Mont::unsafe_make( [ 0x001ce1de , 0x4677cce7 , 0x8d423baa , 0x69abf370 , 0xf142827a , 0x95d9612a , 0x6b5600e5 , 0x006bde07 ] )
+  , Mont::unsafe_make( [ 0xf58a1e2b , 0xbbca21e7 , 0xcb9032d3 , 0x3a397d77 , 0xa3283a93 , 0xa4edeb46 , 0x09597d2c , 0x07c6323f ] )
+  , Mont::unsafe_make( [ 0xfa65e030 , 0xf86f375b , 0x2625c301 , 0xe7962c5c , 0x891d955c , 0xb76c3e3b , 0x23b8acba , 0x0df03cc0 ] )
+  , Mont::unsafe_make( [ 0x1445ee76 , 0x527e83ce , 0xf6a4f7f4 , 0xa81ad09b , 0x0f943118 , 0x80d37b55 , 0xafd4e52f , 0x21d1dd66 ] )
+  , Mont::unsafe_make( [ 0xad13ccf3 , 0x2a8f8c04 , 0xc62e4f50 , 0x82c9dac2 , 0xea723c47 , 0x48a47d5e , 0xf5320608 , 0x2d989bb4 ] )
+  , Mont::unsafe_make( [ 0xf835fadc , 0x22bf3dcd , 0x96722695 , 0x3147803b , 0xb1412bbd , 0x7ed0531d , 0x7df0afa1 , 0x1cae8986 ] )
+  , Mont::unsafe_make( [ 0x65323234 , 0xb8a9186b , 0x66067c44 , 0x02af54e9 , 0x978f95dc , 0x53591a3d , 0x1161afb3 , 0x29d0e1e9 ] )
+  , Mont::unsafe_make( [ 0x47f21638 , 0x2669852b , 0x25a7a7bd , 0x1f07cf60 , 0x1aca7774 , 0x1a1aa59e , 0xd67bc391 , 0x04a47dfc ] )
+  , Mont::unsafe_make( [ 0xab01679b , 0x3b71419a , 0x6656e0e2 , 0x08d105af , 0x0933cf52 , 0x71753686 , 0x60208a11 , 0x1563dd0d ] )
+  , Mont::unsafe_make( [ 0x9123e6bc , 0x659150a8 , 0x0d186b9f , 0xe4cb06e6 , 0x78e2c664 , 0x4f17cd96 , 0xf2fdda49 , 0x2adf6d68 ] )
+  , Mont::unsafe_make( [ 0xb6e8aa30 , 0x16799632 , 0x46bf2403 , 0x656543d9 , 0x928398e4 , 0xe07dba9e , 0x10d85335 , 0x16adae77 ] )
+  , Mont::unsafe_make( [ 0x51c3cd37 , 0x431422ba , 0xc6b1af86 , 0xd26de8d9 , 0xd71db359 , 0x12836d09 , 0xbee93279 , 0x289c0292 ] )
+  , Mont::unsafe_make( [ 0x67ca8612 , 0x98a64ea5 , 0x6865483d , 0x9524f9eb , 0x429cfc5f , 0x3ba400f6 , 0x004d3ee4 , 0x0b113ba3 ] )
+  , Mont::unsafe_make( [ 0x055a5147 , 0x7309f49d , 0x3832c5ad , 0x64e8f9f0 , 0x6e4a11d3 , 0xa182354b , 0x0efb0f41 , 0x20a7d0a0 ] )
+  , Mont::unsafe_make( [ 0xeaeb871e , 0xc895f4ef , 0x7ad53545 , 0xb5dc7971 , 0x30d1cc7f , 0x196cf856 , 0xe1998d8b , 0x2df53d2d ] )
+  , Mont::unsafe_make( [ 0x3cdfb8fe , 0xed28011a , 0x7c528e6e , 0x156ed542 , 0xaae06c4e , 0x72ae2b13 , 0x68d0fda5 , 0x18f71458 ] )
+  , Mont::unsafe_make( [ 0x48e1341e , 0x6ab47a11 , 0xd2739b54 , 0xa48e06e3 , 0xf8f21f5b , 0xe4cc301d , 0x2ee6f410 , 0x17e08da4 ] )
+  , Mont::unsafe_make( [ 0xc77cfdda , 0x2fd36ece , 0x31df634e , 0xafd09c7d , 0x5d53dfc4 , 0x0e10e03c , 0x0f6cc518 , 0x07f4ef23 ] )
+  , Mont::unsafe_make( [ 0xa67db845 , 0xe28ecefc , 0xfb03fb91 , 0x55127a46 , 0xa5c2f8bf , 0x5785faa8 , 0xc27f6007 , 0x2ca1a671 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xccb450df , 0xe5d83f37 , 0x7cc2ca58 , 0xd6b33b98 , 0xbc7f7396 , 0x82afe344 , 0x05a99673 , 0x1e868a54 ] )
+  , Mont::unsafe_make( [ 0xe26541b9 , 0xb6f607bb , 0x14beb3f9 , 0x4024ecd9 , 0x6aab196b , 0x0c924580 , 0x6ef8062c , 0x1703880a ] )
+  , Mont::unsafe_make( [ 0x3b45747c , 0xf62412f2 , 0x3dc477bc , 0x640c6bfe , 0xc0dbce3a , 0x0b601ab2 , 0x9d2e00a2 , 0x0be240b0 ] )
+  , Mont::unsafe_make( [ 0xff17be3a , 0x63ea245d , 0x4645f58c , 0x9da43b86 , 0xbea5ed8d , 0x50c3ac18 , 0xda875bb9 , 0x103abbca ] )
+  , Mont::unsafe_make( [ 0x5bc3d5f1 , 0xf6d004ce , 0x9b6bb95c , 0xd2e91653 , 0x5c0c7f10 , 0x643a391a , 0x1813db58 , 0x185ad7ae ] )
+  , Mont::unsafe_make( [ 0x0844ba60 , 0xc86da2aa , 0x8e20017d , 0xadef415c , 0x38668698 , 0x23e27892 , 0xee1d541c , 0x24ecac7f ] )
+  , Mont::unsafe_make( [ 0x222d4dc3 , 0x9d2cd0ba , 0x7bd0542b , 0x331d4fc1 , 0x3e303994 , 0xc8aad324 , 0xd38e821f , 0x288104ca ] )
+  , Mont::unsafe_make( [ 0x27d4346b , 0x0471221a , 0x3e57048d , 0x559c1647 , 0x7ed6e4b7 , 0xe49525ac , 0x0c0c5b5e , 0x26f8aa0b ] )
+  , Mont::unsafe_make( [ 0x121e039c , 0x72dfd923 , 0xfe2295ac , 0xfe369796 , 0x03104034 , 0x28b41463 , 0x4684e8d3 , 0x0aaaa6f4 ] )
+  , Mont::unsafe_make( [ 0xd74b3a9c , 0xbc9c7811 , 0xbde78404 , 0x2894cbe3 , 0xaf5fcf6b , 0xc4e78964 , 0x18c6ab59 , 0x2c5e60de ] )
+  , Mont::unsafe_make( [ 0xbe688e5c , 0xde76b6d9 , 0x18ae959a , 0xcb78a31e , 0x94db309f , 0x6f4acbae , 0x76de0f8e , 0x1b431458 ] )
+  , Mont::unsafe_make( [ 0x3f45bf93 , 0xa152e21b , 0x9385ca7e , 0xbabe9e02 , 0xa258007a , 0xc31d2dc9 , 0x9b68510a , 0x1b9d7349 ] )
+  , Mont::unsafe_make( [ 0xe1ec1919 , 0xa8ff50c2 , 0xb8c93cf6 , 0xab1226db , 0x1793f145 , 0x0e1c55a9 , 0xdd3e9312 , 0x2b7379d5 ] )
+  , Mont::unsafe_make( [ 0x34883af3 , 0x9c390edd , 0xfc677508 , 0x98dc933e , 0x510772b8 , 0x1839bae2 , 0xe5026e7c , 0x23fa64f9 ] )
+  , Mont::unsafe_make( [ 0x3889a0fe , 0xc11eff12 , 0x6fe11799 , 0x4808a69d , 0x7f124a60 , 0x28f2d01d , 0x69622945 , 0x1e792f1c ] )
+  , Mont::unsafe_make( [ 0x05cc7cea , 0x71b48396 , 0x53fb7c8f , 0x3eb74df3 , 0xa2602cab , 0x0b7a5b20 , 0x70a9097a , 0x2e1f5e32 ] )
+  , Mont::unsafe_make( [ 0xadc4690a , 0x06a2e15c , 0xcae121eb , 0x0bbfcbf4 , 0x966ed49b , 0x7073ce59 , 0x9043a710 , 0x12802bf9 ] )
+  , Mont::unsafe_make( [ 0xa2a988d3 , 0xcdf31e7b , 0xd6d72a52 , 0xa2a6aaf0 , 0x4a7d10e9 , 0x7ee32e97 , 0xd6df18f0 , 0x02b0f9fc ] )
+  , Mont::unsafe_make( [ 0x30703950 , 0xe44e73e2 , 0x2724233f , 0x374fddd4 , 0xa86ae5a5 , 0x3d9fda19 , 0x43665848 , 0x24a05589 ] )
+  , Mont::unsafe_make( [ 0x3aad79d0 , 0x5cd0eb06 , 0x4eab9c1b , 0x986fc67f , 0x57e82362 , 0x0a2b09db , 0x4237e080 , 0x08ecd011 ] )
+  , Mont::unsafe_make( [ 0x47b99703 , 0xcf257dac , 0x2865440d , 0x7f81cc5e , 0x0a93e15b , 0x044d9d88 , 0xfefaa97f , 0x04b6da5b ] )
+  , Mont::unsafe_make( [ 0x2fbb0981 , 0x1ac4d113 , 0xa26b3e11 , 0x11750a93 , 0x0d7188b3 , 0x3ebe31c9 , 0xb2530f8c , 0x05e1818a ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x3696a441 , 0x1b96f506 , 0xfe8e35b4 , 0x9230e0c9 , 0x38f4961a , 0x41a0adea , 0x0f977cf6 , 0x1ba8059a ] )
+  , Mont::unsafe_make( [ 0x56ded46b , 0x5f93bde7 , 0x7663286c , 0x13a8a43d , 0x17164383 , 0x4f9af545 , 0xffcaea54 , 0x238234a6 ] )
+  , Mont::unsafe_make( [ 0xc2320fb8 , 0xe6713f4c , 0x29838f63 , 0x62ab3896 , 0xa4ff05db , 0x075f2742 , 0x23db4c7d , 0x10e8611c ] )
+  , Mont::unsafe_make( [ 0x8e36aa90 , 0xb98167d5 , 0x4b1afabb , 0xd7f6980f , 0x38c2e9f0 , 0xee61bcf9 , 0x06815cdd , 0x031e3b06 ] )
+  , Mont::unsafe_make( [ 0x5d282d8a , 0x9ad0d008 , 0x95b177c8 , 0x0b409cb1 , 0x103cb499 , 0x3edd223f , 0x79ef3de1 , 0x12a6055c ] )
+  , Mont::unsafe_make( [ 0x7af6d9c8 , 0x9ecc994e , 0x33407d54 , 0x8697f387 , 0xef7ae989 , 0xc806b54e , 0x6a04282c , 0x21d123b3 ] )
+  , Mont::unsafe_make( [ 0xea50927a , 0x3e19e3cd , 0x2d836275 , 0xc593ba1a , 0x6cf5e489 , 0x337b639e , 0x9cfb6c6f , 0x2edc896d ] )
+  , Mont::unsafe_make( [ 0xd7737937 , 0x7cfe51c2 , 0xec027c42 , 0x4dff0cdf , 0x7461f21d , 0x4aff5134 , 0x5903861a , 0x2a9158ee ] )
+  , Mont::unsafe_make( [ 0x73e9c411 , 0x1a21306e , 0xf64a6b76 , 0xe24b3513 , 0xa029dc22 , 0xe2d96f0f , 0xdd2991aa , 0x1eabebe4 ] )
+  , Mont::unsafe_make( [ 0x667ea39d , 0x4485424f , 0xfafaef83 , 0xd2396ff8 , 0x1101e664 , 0x73a343c3 , 0xfbe1c17a , 0x029d095c ] )
+  , Mont::unsafe_make( [ 0x5c72666f , 0x50fe691b , 0x8fbbfdd2 , 0x4c680928 , 0xffa16386 , 0x1067700f , 0xc85e2904 , 0x271ab47a ] )
+  , Mont::unsafe_make( [ 0x8ad6583e , 0xcdc1bc72 , 0x274d4cd6 , 0x6b89cbcf , 0xf29f0c85 , 0x4d4ac296 , 0x35ec271a , 0x0d858b0f ] )
+  , Mont::unsafe_make( [ 0x262efbdc , 0x0b8820e7 , 0xd6ded035 , 0x368563e1 , 0x59d07506 , 0x553a9d5e , 0x2819d1f5 , 0x205ab6f9 ] )
+  , Mont::unsafe_make( [ 0x279670a4 , 0x6dd8679d , 0xee8e54ae , 0xa9ee2b55 , 0xf3e6ed41 , 0xbce03d05 , 0xe6985f38 , 0x235dd4ef ] )
+  , Mont::unsafe_make( [ 0x566fc85a , 0xb6140a4f , 0x3f9da23a , 0x7ffad7b0 , 0x8d838a09 , 0x458c3c5d , 0x988de12e , 0x0624e1b3 ] )
+  , Mont::unsafe_make( [ 0x5cd9be02 , 0x5178a132 , 0x74279237 , 0x9a6ad96a , 0x63f3fe4f , 0xe6b44756 , 0x10a27e45 , 0x2f6f5e2c ] )
+  , Mont::unsafe_make( [ 0xd72a4b58 , 0xcf92800a , 0xa1ee23cb , 0x39da4ad2 , 0x5d79e655 , 0x15af05c5 , 0x2b636c99 , 0x04de7295 ] )
+  , Mont::unsafe_make( [ 0x4d21323f , 0x18bb1448 , 0xbf667555 , 0x02d8c713 , 0x21cb1a05 , 0xf4d30c01 , 0x616912bd , 0x114df521 ] )
+  , Mont::unsafe_make( [ 0x287528f7 , 0x88f8e0cc , 0x34e9aa46 , 0xfc480925 , 0x6fc49660 , 0x61b834c6 , 0xbc356790 , 0x2750edf1 ] )
+  , Mont::unsafe_make( [ 0x9a0849e4 , 0x5f28d11a , 0x453b3107 , 0x9ff947cd , 0x602c60a9 , 0xa4f4d6b0 , 0x6a7fdaf1 , 0x18d42901 ] )
+  , Mont::unsafe_make( [ 0xae92aa72 , 0xfe6fb5d2 , 0x833cea5a , 0x61ab1af1 , 0x1cc1a17c , 0xd407c815 , 0x930bd120 , 0x19ff0846 ] )
+  , Mont::unsafe_make( [ 0x6483d8c4 , 0x05acf047 , 0x4c3cea2d , 0x178f2383 , 0xcad4ef01 , 0x4af06cbe , 0x70bea5ea , 0x197edacd ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x6ff70959 , 0x3991a391 , 0x345ece4a , 0xde7b0219 , 0x0d193115 , 0x82317d11 , 0x724bd665 , 0x29dc85fa ] )
+  , Mont::unsafe_make( [ 0x87e66aef , 0x9047b388 , 0x0743e40c , 0x1347c126 , 0x2cb6e214 , 0x4254b33b , 0xa12f4e66 , 0x1fb925bb ] )
+  , Mont::unsafe_make( [ 0x039783d4 , 0x03c48538 , 0x92d88bd9 , 0x0018da68 , 0x29ca4dbe , 0xed6a3f21 , 0x186bd708 , 0x02a0ef0b ] )
+  , Mont::unsafe_make( [ 0x55ce54a2 , 0x4bbe7f0c , 0xb84e556c , 0x409f70d7 , 0xa98fd25e , 0xc515fd99 , 0x9ac1d083 , 0x0ec597a7 ] )
+  , Mont::unsafe_make( [ 0x19cec009 , 0x02159e1b , 0x6956df5c , 0xb71bfb36 , 0x5fb0f1ae , 0xd0a759a2 , 0x05adeb2e , 0x24484db4 ] )
+  , Mont::unsafe_make( [ 0x8c97a7f0 , 0x5a087da0 , 0x57df1498 , 0x2a7641a7 , 0xb894cd22 , 0x97bd9ac8 , 0xc23ceb4e , 0x145d25e6 ] )
+  , Mont::unsafe_make( [ 0xaee91562 , 0xb22e1075 , 0x3e171c2f , 0x3a0ad01f , 0x4bb72dda , 0xbbe62e0c , 0x1d2958a0 , 0x15152445 ] )
+  , Mont::unsafe_make( [ 0x33eddb0a , 0xa52e08a9 , 0x00b212b7 , 0xdf38e8d1 , 0x5b76c607 , 0xca94a0ce , 0x4d0f74a4 , 0x041c2ff7 ] )
+  , Mont::unsafe_make( [ 0xc9aabab8 , 0xe9e48440 , 0x95485330 , 0xd9baa31b , 0x4cf22b75 , 0x8a12413a , 0xc1dac800 , 0x131d28c3 ] )
+  , Mont::unsafe_make( [ 0x231f2c43 , 0x84007f4c , 0x8f6a3a4c , 0x0c0edeea , 0xece2370a , 0x470d95b6 , 0xac405ba7 , 0x15f9238f ] )
+  , Mont::unsafe_make( [ 0x45c9f592 , 0xe5b9ac26 , 0x0d9651db , 0xbb6b6dc3 , 0xcb57fa47 , 0x79181923 , 0x3a35f8a5 , 0x0b94f17c ] )
+  , Mont::unsafe_make( [ 0x550c36f3 , 0xcd53c98c , 0xe1350648 , 0x55b3890a , 0x094cfc60 , 0xd577ff69 , 0x0d6d2e29 , 0x192577a5 ] )
+  , Mont::unsafe_make( [ 0xf9bd9fef , 0x0400692d , 0xfefa2ee8 , 0x82936bbd , 0x4ef8754c , 0xa49c6238 , 0x3a22ea47 , 0x0bfbbba9 ] )
+  , Mont::unsafe_make( [ 0x4cb5edcc , 0x396277fa , 0x1755c832 , 0x24ff2cd1 , 0x62ca243a , 0x65b2b359 , 0xf14bd8c7 , 0x1cc7a5c8 ] )
+  , Mont::unsafe_make( [ 0x6eacc678 , 0x276cbc99 , 0x3daf5881 , 0xcc350715 , 0x3834a1d0 , 0xaea84a6a , 0xb123bc33 , 0x00dc04a2 ] )
+  , Mont::unsafe_make( [ 0xaccaecf3 , 0x9f5c9ddb , 0xfe288223 , 0x668a3ae9 , 0x7da7bf49 , 0x8cb39277 , 0x2d399321 , 0x0960a757 ] )
+  , Mont::unsafe_make( [ 0x7c16dae4 , 0xac426d2e , 0x9c525b23 , 0x1a6fb8aa , 0x1791c025 , 0x4638c910 , 0xcb3a5755 , 0x151bcc7e ] )
+  , Mont::unsafe_make( [ 0x3ada9577 , 0xf52bc6eb , 0xbb804b6d , 0x2a3009b5 , 0xb9098268 , 0xf48d75df , 0x4747b3e5 , 0x1ea0f30c ] )
+  , Mont::unsafe_make( [ 0x93f10154 , 0x9cd566c2 , 0x405057cc , 0x343987eb , 0xa005b3fb , 0x497a04a4 , 0x3d6c384f , 0x26f956bb ] )
+  , Mont::unsafe_make( [ 0x58c712e8 , 0xf561c293 , 0x9252cabb , 0x7ce3561b , 0xdc66a31d , 0xb096381f , 0x5fc70817 , 0x2329461e ] )
+  , Mont::unsafe_make( [ 0xc10c5e41 , 0x8ff5a475 , 0x374e2602 , 0x445fcf33 , 0xccb774ba , 0x76de75bf , 0x536950a3 , 0x28be1a3f ] )
+  , Mont::unsafe_make( [ 0xdaca3240 , 0x7f56d2ae , 0xc68e8347 , 0x7cf7d19a , 0xed9f6fec , 0x5e7d8100 , 0x49c25437 , 0x015f22f2 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x6ac2fe93 , 0xcb61b016 , 0x8c4fb2a7 , 0x4e430d07 , 0xa9d72629 , 0x1e0f1698 , 0x4a46b6cb , 0x1b98cf02 ] )
+  , Mont::unsafe_make( [ 0x917fc343 , 0xded791e3 , 0xd201bc96 , 0x018be31e , 0xcaa5fbd3 , 0x2f753471 , 0x196d84db , 0x0529e3c7 ] )
+  , Mont::unsafe_make( [ 0x9a816f43 , 0x8bf3891e , 0x0fc0a6b0 , 0xc0a93adc , 0x73bffd45 , 0xe1c0eb8e , 0x6270ffd6 , 0x10aa61c6 ] )
+  , Mont::unsafe_make( [ 0xb5188918 , 0xbd9b7b7e , 0x099beb64 , 0x9b34869a , 0x7ef574b1 , 0xf916319e , 0x25f1ca33 , 0x23580529 ] )
+  , Mont::unsafe_make( [ 0xf0ee7229 , 0xa9276227 , 0x7181a91f , 0x7d466f78 , 0x338195d4 , 0x668a7640 , 0x7a41bc86 , 0x069b7a5e ] )
+  , Mont::unsafe_make( [ 0xa1baa643 , 0x07786007 , 0x52f15681 , 0x3fad7f7d , 0x7743c283 , 0x72f5254f , 0xc55c94ea , 0x108d7533 ] )
+  , Mont::unsafe_make( [ 0xc73a1fac , 0x01d33f07 , 0x8bbecd87 , 0xbd95cd04 , 0x556bc790 , 0x9735e9d6 , 0x953e6254 , 0x0fef8412 ] )
+  , Mont::unsafe_make( [ 0xb723435e , 0x6102cd6b , 0xcc47f376 , 0x7a3ab42c , 0xcd3082dd , 0x8d581d6b , 0x5dff444d , 0x2d6b30ed ] )
+  , Mont::unsafe_make( [ 0x7f338ce3 , 0x04630358 , 0x3a652929 , 0xfad71b3d , 0xca03ef4d , 0x98491e17 , 0x9f8e2146 , 0x21394b71 ] )
+  , Mont::unsafe_make( [ 0x78d4be28 , 0x63799d9c , 0x1afb3f66 , 0xcef3b84a , 0x3da15b4d , 0x59566acd , 0xf8fa067e , 0x1e57b040 ] )
+  , Mont::unsafe_make( [ 0x4269b2e1 , 0xf3db467d , 0x187471f5 , 0x94df3978 , 0x39be3c08 , 0xf9bce1c4 , 0x906ac19c , 0x0743c23c ] )
+  , Mont::unsafe_make( [ 0x0a464b8d , 0x0ef6445a , 0xf352a01d , 0xa7961abd , 0x3e3cc17f , 0x76a4dd92 , 0x42077d73 , 0x0b22faa5 ] )
+  , Mont::unsafe_make( [ 0x3d978c21 , 0xe115fdc3 , 0xb1a41231 , 0xb97701d6 , 0xd59bbf64 , 0x6b95e8ae , 0x51b2ec4e , 0x2943dd72 ] )
+  , Mont::unsafe_make( [ 0x9cbf1d2a , 0x3469a251 , 0xaa2055b5 , 0xd939912a , 0x7d90a6ce , 0x3a3d5cd0 , 0x82dbdb2d , 0x20cbd83a ] )
+  , Mont::unsafe_make( [ 0xbbbe977b , 0x3a1869e3 , 0x86cc29c7 , 0x1b31aa4f , 0x88fe727c , 0x12ad0378 , 0xd98f7aeb , 0x12e5e317 ] )
+  , Mont::unsafe_make( [ 0x97341bf5 , 0xd20d7e6f , 0xd3d0f6f2 , 0xcda71d33 , 0xb4b2a299 , 0x8095edfb , 0x51acf4aa , 0x1dc1ca24 ] )
+  , Mont::unsafe_make( [ 0xb08a5cc9 , 0xc03e3c7f , 0x3c179750 , 0xa410c1e9 , 0x018682f0 , 0x1733bcdb , 0xf9718a33 , 0x06606861 ] )
+  , Mont::unsafe_make( [ 0x1bd649ab , 0xd2505bc7 , 0x3519ef2c , 0xbbf9be8a , 0xb8b7e641 , 0xe531957f , 0x568ee843 , 0x2290a2c8 ] )
+  , Mont::unsafe_make( [ 0xf43818d5 , 0xab6eb558 , 0x2b5e0e85 , 0x50933f8d , 0x660834db , 0x3fbfd0e6 , 0xd627aea3 , 0x064eb735 ] )
+  , Mont::unsafe_make( [ 0x4a40f1bf , 0x5a5b0822 , 0xfb4eb1ab , 0xf9059f0f , 0xbaa00e45 , 0xd5aaefd3 , 0xbfead722 , 0x2e72756c ] )
+  , Mont::unsafe_make( [ 0x37f16ffa , 0xc2abe001 , 0xc586c484 , 0x8e322a0f , 0xdf93d881 , 0xd275d355 , 0x0d740f6b , 0x2fefb31a ] )
+  , Mont::unsafe_make( [ 0x48918c38 , 0x90285da6 , 0x92f18db0 , 0xb4046a0e , 0x9549a973 , 0xa81d187e , 0x963759e2 , 0x2e5f1e06 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x604c98b6 , 0x1da17297 , 0x88fde841 , 0x2a462c85 , 0xccd665ed , 0x5fcca893 , 0xbb3a09a8 , 0x04ca3231 ] )
+  , Mont::unsafe_make( [ 0xeffd9701 , 0x47eebc6f , 0xd79877e8 , 0x7400577e , 0xb4ba9e73 , 0x5c85b445 , 0x574c7a11 , 0x09855701 ] )
+  , Mont::unsafe_make( [ 0x43e2ea43 , 0x859af296 , 0x4fe0ec20 , 0x3f79c9f4 , 0x0d510f47 , 0xcec4b169 , 0x73d1d4ff , 0x093a5ac6 ] )
+  , Mont::unsafe_make( [ 0x4ae372f4 , 0x1a553b8c , 0x7fef75ec , 0x9710d409 , 0xe6af08e7 , 0x8a2d4fe2 , 0x6bf903db , 0x03b63f3f ] )
+  , Mont::unsafe_make( [ 0xe6ee51b8 , 0x91db8872 , 0x9312d40c , 0x110678d1 , 0x0164eca6 , 0xbae0e184 , 0x9c6ddb78 , 0x16340a07 ] )
+  , Mont::unsafe_make( [ 0x6786d2e3 , 0x3d6a31e2 , 0x9fdf0ce1 , 0xe7364ae0 , 0x2e456f7f , 0x73f56ad7 , 0x7b264d5a , 0x2db72778 ] )
+  , Mont::unsafe_make( [ 0xa52b220b , 0xf761f398 , 0xeac454f8 , 0x86fa544b , 0xd4d62300 , 0x4e8ed82f , 0xb9f68382 , 0x06fb4d97 ] )
+  , Mont::unsafe_make( [ 0xaa5bcf34 , 0x3befafa1 , 0x13e6aef4 , 0x488bc8d7 , 0x1a5e1d24 , 0xd837f01f , 0x279dc062 , 0x2f9088b0 ] )
+  , Mont::unsafe_make( [ 0x9eef3702 , 0x45c73e7b , 0xcc5cc877 , 0x8f95dd0b , 0x54b0adfd , 0xb8c516ac , 0x2d5cc3a3 , 0x011a5be7 ] )
+  , Mont::unsafe_make( [ 0x37232696 , 0x2a0a6c39 , 0xde8ee404 , 0x01c93a19 , 0xb6c1375e , 0xf573ef1a , 0xdf88d372 , 0x28db97ed ] )
+  , Mont::unsafe_make( [ 0xdf9e3c75 , 0xce35ab24 , 0xa3acdf6b , 0x9c84ce34 , 0x0fb91298 , 0x736b8812 , 0x1a73417e , 0x0b694f6a ] )
+  , Mont::unsafe_make( [ 0x623fc5d3 , 0x00d3f4cd , 0x68bd8bf5 , 0xf3fc60fe , 0x4535e140 , 0xa78c6c24 , 0xfee7ec7b , 0x12592ad0 ] )
+  , Mont::unsafe_make( [ 0xc232fd7a , 0x5059eb00 , 0x82a8dc70 , 0x06fc9c3d , 0xc1fea3c3 , 0x5239d5c4 , 0x9e12ef70 , 0x09dd657b ] )
+  , Mont::unsafe_make( [ 0x3bcea66a , 0x40d42512 , 0xfe1869cf , 0x79ee3a48 , 0x6728b55e , 0xd12d7c5f , 0x79f46019 , 0x10b22c5c ] )
+  , Mont::unsafe_make( [ 0xe211b1c9 , 0xdcfef401 , 0xf2dbeedb , 0x663d28d8 , 0x5154d423 , 0xfb9ec78d , 0xcc4d2804 , 0x02dc6ba2 ] )
+  , Mont::unsafe_make( [ 0x9dfc9882 , 0xa76df96f , 0xd50cba8d , 0x66bac872 , 0x5d9b7cd4 , 0xf91dc6aa , 0xf86b30e6 , 0x1e9bbfb8 ] )
+  , Mont::unsafe_make( [ 0x39e56512 , 0x1db000e5 , 0x5a68322e , 0xdcfaf63f , 0xe1709b72 , 0x212a8dfc , 0xf1f3b57d , 0x0164b507 ] )
+  , Mont::unsafe_make( [ 0xf2ddb858 , 0xeb8b05cb , 0x33669442 , 0x68313f2c , 0xf01ba98c , 0x21c61edd , 0x955a114a , 0x24a477c5 ] )
+  , Mont::unsafe_make( [ 0x23dd75e8 , 0xa05cc22c , 0x43c85937 , 0x990abbd9 , 0x27138b81 , 0xcdacca77 , 0x4d1032c6 , 0x1ef5a177 ] )
+  , Mont::unsafe_make( [ 0x0e529304 , 0xe6c24b35 , 0xe3413d28 , 0x82830b0f , 0xda64ed20 , 0xf7b01448 , 0x97874b76 , 0x0fd853cd ] )
+  , Mont::unsafe_make( [ 0x0a29dbc8 , 0x35897ffc , 0xa62608b7 , 0xb7a63d33 , 0xc48fac90 , 0xe2292d61 , 0x7e41e505 , 0x2cbdc854 ] )
+  , Mont::unsafe_make( [ 0x8fe9d8db , 0xc90e415a , 0xa2cce456 , 0x82f430ac , 0x882d3516 , 0x94ef9834 , 0xb0188bfb , 0x0495d627 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x42ae1ce4 , 0x6057dac4 , 0x22ea4a35 , 0x853b8cbf , 0x75664305 , 0x2c9e36ac , 0x6e7afb1e , 0x26d8b0f4 ] )
+  , Mont::unsafe_make( [ 0x650c2810 , 0x600310f2 , 0xf7b3a582 , 0xbb1b6d9c , 0x069ccd94 , 0x993d0ce7 , 0xb466065a , 0x11c39777 ] )
+  , Mont::unsafe_make( [ 0x8d138654 , 0x2a3b7f2c , 0xf81acd43 , 0x0afe7dcb , 0x33fae555 , 0xb383fcc3 , 0x297ae4fb , 0x03382ada ] )
+  , Mont::unsafe_make( [ 0x62333974 , 0x558307f1 , 0x74a3b36c , 0xbabb5a55 , 0x2b2cace2 , 0xf5bf5127 , 0x0bd5e21c , 0x1b4d8c5f ] )
+  , Mont::unsafe_make( [ 0x7119781a , 0xb1b08f3f , 0x8cff9fbd , 0xa766b079 , 0xccd3f7e6 , 0x477efe4b , 0x8de9f5b1 , 0x0929e1e1 ] )
+  , Mont::unsafe_make( [ 0xb1c11957 , 0x46a6cc1f , 0x05ec4e75 , 0x11c07dd4 , 0x62ec605b , 0x899d43e5 , 0xb111e53b , 0x1c2492b9 ] )
+  , Mont::unsafe_make( [ 0xfb1490c9 , 0xa7068756 , 0xbee32c1f , 0xd6a022b5 , 0x37a85803 , 0x7c4046b9 , 0x063532e8 , 0x2b86f1fb ] )
+  , Mont::unsafe_make( [ 0xc6400bb0 , 0x4f11bd91 , 0xc8bdaa72 , 0xfeb788af , 0xff7bdca0 , 0x70895c30 , 0xa1edb7ea , 0x1e24a1a6 ] )
+  , Mont::unsafe_make( [ 0x7d9ce2fb , 0x821beffc , 0x75dd2139 , 0x3f8f77e9 , 0xbea2db16 , 0xd122ac84 , 0x3cde324c , 0x2d3f45f8 ] )
+  , Mont::unsafe_make( [ 0x798cd140 , 0x007ac2bf , 0x5d7e324e , 0x4bd146cc , 0xed1bb6e1 , 0xb22d4183 , 0xc3acf439 , 0x15de19da ] )
+  , Mont::unsafe_make( [ 0x5f97d4ac , 0x002dc373 , 0x5784af5a , 0x88542b5d , 0xe6724655 , 0x602713a2 , 0x7abe787a , 0x0b4eeda7 ] )
+  , Mont::unsafe_make( [ 0x36f08c83 , 0xb52bd10a , 0xc8f65e9c , 0x0730105a , 0xc4afe839 , 0x331def0d , 0xbc37e209 , 0x02044284 ] )
+  , Mont::unsafe_make( [ 0x6f9eb009 , 0xb5a6dc4a , 0x10cb257d , 0xb390ec13 , 0xcda7ae3a , 0x3b469dac , 0xf21c9e75 , 0x10ae4240 ] )
+  , Mont::unsafe_make( [ 0x97d443fe , 0x06cf32c0 , 0xa8d90a7a , 0xc4e4aa4f , 0x36ca0170 , 0xcb7e2ffe , 0x753bead2 , 0x284db3c1 ] )
+  , Mont::unsafe_make( [ 0xbdab8635 , 0x67844427 , 0x475e412a , 0x32584422 , 0x44b3d976 , 0xe43aa7ae , 0x4c963b1d , 0x0351e364 ] )
+  , Mont::unsafe_make( [ 0xf5ab5b71 , 0x3f5468f1 , 0xa6e4efe8 , 0x56a10892 , 0x59cb866a , 0xb98c74a9 , 0x5ef97f3a , 0x183e0f4b ] )
+  , Mont::unsafe_make( [ 0x2850d8bb , 0xfd725d3c , 0xd7620988 , 0x027c4301 , 0x8884f10d , 0x2cd33d0b , 0x3dccaea5 , 0x16d8c371 ] )
+  , Mont::unsafe_make( [ 0xbe93996c , 0xd59bc65a , 0xa254a010 , 0x639b1e55 , 0xc22694d6 , 0x489c9c8b , 0xa8b85cf8 , 0x0c5368fe ] )
+  , Mont::unsafe_make( [ 0xb87d7eeb , 0x96b42144 , 0xe3c6c6de , 0xf6e35e2b , 0xa0a37603 , 0xc44ab869 , 0xa1a7bd1d , 0x057fb239 ] )
+  , Mont::unsafe_make( [ 0x35227f9b , 0x06c751e3 , 0xaabf6971 , 0x76c231c4 , 0xabd7e36e , 0x2f171324 , 0xa003c6db , 0x1dd42ec5 ] )
+  , Mont::unsafe_make( [ 0x97c9b9be , 0x99ec0410 , 0x2d09b807 , 0x5338b995 , 0x434aad87 , 0x1696b635 , 0x1977263d , 0x214d4ad1 ] )
+  , Mont::unsafe_make( [ 0x77a29a61 , 0xc9cf2ead , 0x134e8532 , 0x649cf32a , 0xe559e365 , 0x2d6ca01c , 0x38e92ca7 , 0x04a6cf68 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x3279ddf9 , 0xce5f7e52 , 0x9db3b9af , 0xdfd0ffc0 , 0x69cf7152 , 0x0ca802fb , 0x490fd1a6 , 0x1c254a0e ] )
+  , Mont::unsafe_make( [ 0x9a8f55d6 , 0x2de8e68a , 0xca38205c , 0x13977215 , 0x032338bc , 0x728e29e7 , 0x897b36fe , 0x16c5c277 ] )
+  , Mont::unsafe_make( [ 0x9d8cef82 , 0x703b0a6b , 0xdb2a8410 , 0x8f9a458a , 0x1131e655 , 0x4e484f17 , 0x8eb9a395 , 0x2e1f7b3c ] )
+  , Mont::unsafe_make( [ 0xedf21de0 , 0x2360c1f7 , 0xbf923299 , 0x5b64a869 , 0xbe6563e6 , 0x32f1aa8e , 0xd765fab0 , 0x30394d8b ] )
+  , Mont::unsafe_make( [ 0xd36aad07 , 0x5b2e56d2 , 0xf77eb5ef , 0x9b5b6e9a , 0x3ecb79ef , 0xfcddb20c , 0xaf16760f , 0x0ca1fb13 ] )
+  , Mont::unsafe_make( [ 0x1a37929e , 0x61529afc , 0x6ecce7fa , 0xbefac57c , 0x892c311a , 0x9d7af7fe , 0xea994e4b , 0x0133a458 ] )
+  , Mont::unsafe_make( [ 0x9cc876cc , 0xa6e4a9ac , 0xb1bb12de , 0xcb6356dc , 0x5b5597a0 , 0xa2b107f6 , 0x3d07d9eb , 0x0dce4270 ] )
+  , Mont::unsafe_make( [ 0x68e1103f , 0x472c7b73 , 0xa53dfde3 , 0x3a29406a , 0x2a3e675f , 0x8e0ffb82 , 0xb8a7a749 , 0x1b7260cf ] )
+  , Mont::unsafe_make( [ 0x95613eb5 , 0xe0287917 , 0x57fd150f , 0x00fe2708 , 0xc651c38e , 0x0807ef55 , 0x210fedc0 , 0x2ee7ad70 ] )
+  , Mont::unsafe_make( [ 0xfb066d11 , 0x56ee0b77 , 0x4d8df3d5 , 0xcd792ca4 , 0x43afc0fc , 0x03e305dc , 0xfd263f2b , 0x1561d84f ] )
+  , Mont::unsafe_make( [ 0x53c46023 , 0x76d009c4 , 0x386dd446 , 0xe278cffd , 0x6bcd3bef , 0x75d7aed7 , 0xfdaeb0a4 , 0x0b075fdd ] )
+  , Mont::unsafe_make( [ 0xf25dfac5 , 0x079d7b1e , 0xae081adb , 0xded969a4 , 0xa0a53d68 , 0x26973b19 , 0x678c30df , 0x281260c7 ] )
+  , Mont::unsafe_make( [ 0xdaf67892 , 0xb3abdd31 , 0x431ddd41 , 0xdcc6e0f0 , 0x50a9a34a , 0x6f886e53 , 0x261d10bd , 0x081f886f ] )
+  , Mont::unsafe_make( [ 0x2718a95b , 0xfe5bc0e3 , 0x6c9e8939 , 0xc2f183eb , 0xea6a4110 , 0x1ff2599b , 0xb8fd3615 , 0x2378b4db ] )
+  , Mont::unsafe_make( [ 0x8cb0a1a2 , 0x7edd268e , 0x7ebd432d , 0xda36a0cd , 0xb7885f5a , 0xcef9b573 , 0x6e628de3 , 0x1b72db74 ] )
+  , Mont::unsafe_make( [ 0x1d037a8b , 0x083ec2ca , 0xfc590eb3 , 0x956dd713 , 0xc3d27cee , 0x30c7d6dc , 0x714519d5 , 0x058d1cc9 ] )
+  , Mont::unsafe_make( [ 0x1685928c , 0x6e77858b , 0x8e5dd5c7 , 0x9bddc2b2 , 0xa90bad86 , 0x8f716a98 , 0xab799085 , 0x141b4e7c ] )
+  , Mont::unsafe_make( [ 0xaaec4ef3 , 0x25c58443 , 0x99e8ee7d , 0xe44e6b2a , 0x3cae2c0e , 0xec7e48f0 , 0xe8106707 , 0x023a9045 ] )
+  , Mont::unsafe_make( [ 0x8cb6fa61 , 0x9f07e170 , 0x740775b1 , 0x4015a33c , 0x84cff1c2 , 0x7bb73488 , 0x4678016c , 0x18a4636f ] )
+  , Mont::unsafe_make( [ 0x827783e9 , 0xee9e2f1f , 0x5f9bf634 , 0x121d9345 , 0x8c8940df , 0x4917466f , 0x554fa1af , 0x0f6a7058 ] )
+  , Mont::unsafe_make( [ 0xa1b00c6c , 0x4318c0e9 , 0xc7e01595 , 0xa9a3f7c6 , 0x613406ae , 0x2edbda7f , 0x2a27209b , 0x021303be ] )
+  , Mont::unsafe_make( [ 0x96b1b2d8 , 0x344aad86 , 0xc4e13ebf , 0x771f66ce , 0xe9fc2177 , 0x50bd6330 , 0xaed9e0f5 , 0x2b9f3708 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xfc1abd32 , 0x15646a65 , 0x1845b0ab , 0x0ff3b3f3 , 0x711b1c0f , 0x58944c91 , 0x4e7cdd6a , 0x125ee0b0 ] )
+  , Mont::unsafe_make( [ 0xa2c9c011 , 0x1ace94d6 , 0x0947abf2 , 0x91cba8c9 , 0x1ee98751 , 0xe4153201 , 0x3aa045bd , 0x22868fc7 ] )
+  , Mont::unsafe_make( [ 0x25a3cac4 , 0x93e8dce5 , 0x87268712 , 0x4168b3f0 , 0x480036d2 , 0xdb48c0f3 , 0x60da19dc , 0x19fda301 ] )
+  , Mont::unsafe_make( [ 0x8840ffa5 , 0xbdd3b0f4 , 0x1a526723 , 0x8e63006d , 0x977b18c8 , 0xe0231f23 , 0x698428d9 , 0x281cffa6 ] )
+  , Mont::unsafe_make( [ 0xfb975eeb , 0x69b6d563 , 0x3370d8a9 , 0xf809d925 , 0xcd1ba71e , 0xce57b7e4 , 0xecb2be86 , 0x06ecf5eb ] )
+  , Mont::unsafe_make( [ 0x6f7ce6a9 , 0xfa6bd91e , 0xfb4d0c13 , 0x90b38685 , 0x575d8a0b , 0x7d503dc7 , 0x8c9fb9f1 , 0x1052cf18 ] )
+  , Mont::unsafe_make( [ 0x94146078 , 0x84743668 , 0x3f509a7e , 0x60dc7e27 , 0x12179bed , 0xfc545406 , 0xc9935621 , 0x0ba47840 ] )
+  , Mont::unsafe_make( [ 0x778ef64a , 0x9e7733cb , 0x846831b3 , 0x6933e5c8 , 0xc51d572c , 0xf288937c , 0xc49fb141 , 0x20f82b30 ] )
+  , Mont::unsafe_make( [ 0xadeeaeb2 , 0xcde8f9e3 , 0x506e43f3 , 0x51ac6170 , 0x8c6eb684 , 0xcea58a88 , 0xf456cff4 , 0x111216cc ] )
+  , Mont::unsafe_make( [ 0xbe2f3170 , 0x2bc4dc2d , 0x77ca0948 , 0xab2ab30f , 0xdfb39e98 , 0x01545c15 , 0x6079b3fd , 0x0e6dfbf6 ] )
+  , Mont::unsafe_make( [ 0x8e6c6a63 , 0x764b1b33 , 0x772e751e , 0xbe8e082e , 0xc0ba800c , 0xd692b556 , 0xb8dfbd28 , 0x1ab15d7c ] )
+  , Mont::unsafe_make( [ 0x59dfbfc6 , 0xdae610e8 , 0xc753b9ab , 0x9ad8e01b , 0xc12de26e , 0xa34403e9 , 0x72b781fd , 0x1f81ed4b ] )
+  , Mont::unsafe_make( [ 0x735e0dd6 , 0x48b5f6d6 , 0x3b48d917 , 0xf6aa2a99 , 0xb13e29ac , 0x5d9aad49 , 0x60cd8b24 , 0x086f3bb0 ] )
+  , Mont::unsafe_make( [ 0xe850fd50 , 0x65f268de , 0x1d1ab43a , 0x22fa0075 , 0xb6dafccf , 0x6daf9a70 , 0x8a3eb36f , 0x08410ca4 ] )
+  , Mont::unsafe_make( [ 0x9b74b103 , 0x483abd08 , 0x6ee3a9f5 , 0x1d064fae , 0x4a71c001 , 0x409ea992 , 0x8a84c469 , 0x068f1be7 ] )
+  , Mont::unsafe_make( [ 0x4e53ac72 , 0x0e18f914 , 0x79f593c5 , 0x61020c73 , 0xe7a57dd9 , 0x2e41faa0 , 0x15a98b0e , 0x240bc8bc ] )
+  , Mont::unsafe_make( [ 0xab20367e , 0x35f8ad4f , 0x50f7c61e , 0xeb072bf3 , 0x09f9cee4 , 0xfa671ad7 , 0xea1013aa , 0x297aea10 ] )
+  , Mont::unsafe_make( [ 0x31376634 , 0x2c6e8471 , 0xdf4146ed , 0x3d163a41 , 0xa299227f , 0x612f6b07 , 0x91d72514 , 0x08f467cc ] )
+  , Mont::unsafe_make( [ 0x604bad4d , 0xb606952d , 0xc854dc3e , 0x8c881e90 , 0x08a0e4c0 , 0xb7cd344e , 0xb701b339 , 0x0db119dd ] )
+  , Mont::unsafe_make( [ 0x8cca12e7 , 0x39efc149 , 0x81c16c19 , 0x4eef21ed , 0x4cb4a490 , 0x3059b5aa , 0x8220da1d , 0x1aecda17 ] )
+  , Mont::unsafe_make( [ 0xd8cb487f , 0xdd65fb40 , 0x73b4e097 , 0x1eb0dc0b , 0xd07e7ec0 , 0xbccb8ed3 , 0xd1a11542 , 0x01a3291d ] )
+  , Mont::unsafe_make( [ 0xa4cbec65 , 0xf92693d2 , 0x42a27829 , 0x03da400b , 0x429f20b7 , 0x06daa627 , 0x8c9a2622 , 0x0dcde14e ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x937acf68 , 0x195f56c7 , 0x922e939e , 0xf88191e9 , 0x301b0552 , 0x851e1683 , 0xb92365af , 0x2494bb5d ] )
+  , Mont::unsafe_make( [ 0xc6cba788 , 0x5ac9195e , 0x17eb39d8 , 0x46344055 , 0x384c0f18 , 0x21c071b0 , 0xeb3f02a6 , 0x1516433f ] )
+  , Mont::unsafe_make( [ 0xf08535f5 , 0x4f9fba97 , 0x81ed33f3 , 0xd6525f0a , 0x2fe34641 , 0xb5413ffb , 0x426b41c7 , 0x26398032 ] )
+  , Mont::unsafe_make( [ 0xa7e3b4ca , 0x86d9500e , 0x1cadda63 , 0x7511ef09 , 0xbbb44b3f , 0x0bd44b4b , 0x647af37a , 0x04602159 ] )
+  , Mont::unsafe_make( [ 0xedb99052 , 0xb9b6646b , 0x3537c50b , 0xc5e4fa45 , 0x56c240a1 , 0x585b7d20 , 0x6f3f5c4f , 0x23857cfe ] )
+  , Mont::unsafe_make( [ 0x365d5a36 , 0x76530c88 , 0x42aa0ccf , 0x5fcdb22a , 0x04324ae7 , 0x16ba3b6c , 0x3880bd90 , 0x071a806e ] )
+  , Mont::unsafe_make( [ 0x56126afb , 0x78643ab6 , 0xf7057a18 , 0x78e146a3 , 0x22818255 , 0x5ed931bd , 0xe502e540 , 0x175de698 ] )
+  , Mont::unsafe_make( [ 0x8481115e , 0x413d75fd , 0x100f62dd , 0x5e7049a2 , 0xc14b9cbd , 0x010352c2 , 0xb09d330c , 0x18af7e2a ] )
+  , Mont::unsafe_make( [ 0xe256ca82 , 0x891a4e3d , 0x35296a36 , 0xe207364a , 0xebd6fb1d , 0xaf0faa49 , 0x9851a739 , 0x2ff74cbc ] )
+  , Mont::unsafe_make( [ 0x48b337ca , 0x4c9dc040 , 0xc9e274f0 , 0x0bd0c615 , 0x2a022805 , 0xfc3c0566 , 0xee0788b2 , 0x28b0c987 ] )
+  , Mont::unsafe_make( [ 0x5a82431a , 0x7a2d2e33 , 0xabca1145 , 0x49a4e9a5 , 0x690ef451 , 0x16f5fefc , 0x55096930 , 0x1213c824 ] )
+  , Mont::unsafe_make( [ 0x7c2f4276 , 0xa0905c5d , 0xe1532c59 , 0xc67f7a4e , 0x5a22be5d , 0x8b3ecfeb , 0x460ff74f , 0x2fa52195 ] )
+  , Mont::unsafe_make( [ 0xed4efd9d , 0x2f7e2470 , 0x5b040081 , 0xd25ab073 , 0xab4b8a6e , 0x1c2230fb , 0xa2777b34 , 0x19bad22f ] )
+  , Mont::unsafe_make( [ 0x0d5c7d19 , 0x98502462 , 0xcdc343ef , 0x96c4d92e , 0x11952e49 , 0x3e696761 , 0x25cc2bcc , 0x1beb1fe3 ] )
+  , Mont::unsafe_make( [ 0x73bddd39 , 0xcbecd648 , 0x0e96ce43 , 0x06e5bf1b , 0xcd390bf6 , 0xeae49ebe , 0xe172f769 , 0x1a2fdd4e ] )
+  , Mont::unsafe_make( [ 0x0bcf5c4f , 0x1ac79b14 , 0x6bfe9420 , 0xa1d95de4 , 0x02d299ef , 0x796f5097 , 0x56c67bb4 , 0x06341e5b ] )
+  , Mont::unsafe_make( [ 0xfae2b3c3 , 0x4dbbcff6 , 0xfc27a6f0 , 0xfd7adaba , 0xf19b2c2e , 0xbe93de01 , 0x9db5b835 , 0x1e420d9b ] )
+  , Mont::unsafe_make( [ 0x761ba4dc , 0x7bb05a6c , 0x51d40d25 , 0x1c18f433 , 0xdbefaf50 , 0x7e2736f9 , 0x8cdf3329 , 0x13cba47d ] )
+  , Mont::unsafe_make( [ 0x657c116b , 0x6e01dfbb , 0xc9a3ff0b , 0x3a319e44 , 0xca800433 , 0x2381c179 , 0xcae88d08 , 0x1b2b0fc8 ] )
+  , Mont::unsafe_make( [ 0xa88bbba4 , 0xf4fe9959 , 0x2b6835de , 0x089d1c42 , 0x85ff144b , 0x3afafd5f , 0x70659330 , 0x2b698937 ] )
+  , Mont::unsafe_make( [ 0xfc50c39a , 0x679188bb , 0xf4453f3f , 0x4a60db44 , 0x915fc990 , 0x5fb79bb5 , 0x5535167f , 0x23b01910 ] )
+  , Mont::unsafe_make( [ 0x940a67ff , 0xcca5ed40 , 0x76058039 , 0x1aac257f , 0x2de003d9 , 0x0aca6912 , 0x0d2ec844 , 0x06b3197e ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xae74ff07 , 0x89e0b5c9 , 0x6d912e18 , 0x8ff36e48 , 0x5ebad30f , 0x7a7d03f1 , 0x6a9462c7 , 0x0029212d ] )
+  , Mont::unsafe_make( [ 0x79487c49 , 0x042585ec , 0x49033de8 , 0x89ebb60f , 0xbedf3e2d , 0xec8ec3de , 0x965f40ee , 0x268fd6ba ] )
+  , Mont::unsafe_make( [ 0xea78a28c , 0x66d9af07 , 0x52551900 , 0x0d331f83 , 0x0254b543 , 0x8e1aa71a , 0x01c8424b , 0x0db93dd1 ] )
+  , Mont::unsafe_make( [ 0x3c513026 , 0x721cef2f , 0x01fd9219 , 0x98563171 , 0x0b37b11c , 0x2f2ef2e4 , 0x084dd8de , 0x132ec7ae ] )
+  , Mont::unsafe_make( [ 0xe3a61dec , 0x7d436d7a , 0xd9f6856f , 0x15fd3a33 , 0x96fd6891 , 0x338f6035 , 0x9be9a445 , 0x1eae19b2 ] )
+  , Mont::unsafe_make( [ 0x387ccc10 , 0xde8b542d , 0x0a510459 , 0x6b060008 , 0x16239869 , 0xc688331e , 0xd7b3dedf , 0x137697bc ] )
+  , Mont::unsafe_make( [ 0x0da80a08 , 0x9dcc74b3 , 0x3a96cd6d , 0x196f4fcc , 0x8f0d2bf6 , 0x1c79eafa , 0x3ebac2a8 , 0x1e32356d ] )
+  , Mont::unsafe_make( [ 0x9f7bf0ae , 0xb3c6173a , 0x952281a2 , 0x9e6c6c14 , 0x86687ed7 , 0x2fd1531e , 0xd7a05f20 , 0x0bd6f27d ] )
+  , Mont::unsafe_make( [ 0x107f7eba , 0xec5dcc57 , 0x61a11b63 , 0x7ff38f6f , 0x0a9bbe92 , 0x0417af43 , 0xbe83ad2c , 0x2e404688 ] )
+  , Mont::unsafe_make( [ 0xb375d724 , 0xc6c63c82 , 0xb304248c , 0xd19805c1 , 0xa49ccf23 , 0x3fa3376e , 0x62fbeac9 , 0x2ba1919f ] )
+  , Mont::unsafe_make( [ 0x9058a9ca , 0x1e0cee80 , 0xfa7ecd51 , 0xb3401e66 , 0xd108ccc0 , 0x4a42b7f7 , 0x94c61eb3 , 0x04f66b70 ] )
+  , Mont::unsafe_make( [ 0x61022393 , 0x5c5ac72d , 0xa86b8e8c , 0x7d99c9a5 , 0x88f2b95f , 0xde22a83c , 0x73f86b66 , 0x0f1c3120 ] )
+  , Mont::unsafe_make( [ 0xcaab6f7f , 0xc0fea524 , 0xe616cba8 , 0xe02cdfa1 , 0x3c20f4ac , 0x8dc5b696 , 0xcee7e169 , 0x235ff1db ] )
+  , Mont::unsafe_make( [ 0xd2bb7fb9 , 0x1eadfe2a , 0x5ccdf0c8 , 0xbfb7e6c8 , 0x5e3fbf8c , 0x16d2e17a , 0xc460e640 , 0x0c6cc7af ] )
+  , Mont::unsafe_make( [ 0x3a670a52 , 0x36143161 , 0xda5b443e , 0x0597184a , 0x64835586 , 0x36819e7a , 0x10ef5b2e , 0x1ac0bc96 ] )
+  , Mont::unsafe_make( [ 0x18167d15 , 0x56b0ac79 , 0xaaa4d8a6 , 0xf6680c6f , 0x59f62bf3 , 0xe48c54b4 , 0x8dc49bd6 , 0x17b18fb0 ] )
+  , Mont::unsafe_make( [ 0x36e9b05f , 0x0b3d1386 , 0xcff287af , 0xd35c90a9 , 0xe0b954f1 , 0x47317a00 , 0x1cdc2b1e , 0x1fcf91da ] )
+  , Mont::unsafe_make( [ 0x972062c6 , 0x1c1d1289 , 0x0d41f703 , 0xb6b27fa3 , 0x236bcf03 , 0xdf241312 , 0xec5965a9 , 0x002bc91a ] )
+  , Mont::unsafe_make( [ 0xac041720 , 0x14358845 , 0x945e0b70 , 0xf1538c68 , 0x9cb4deb4 , 0x42423b88 , 0x464a1ae8 , 0x025ea19b ] )
+  , Mont::unsafe_make( [ 0x8f3dd563 , 0xd0874797 , 0xe0414b8a , 0xde6fd748 , 0xdc25f7d5 , 0x5e1811c2 , 0xcfd0f594 , 0x137e4a40 ] )
+  , Mont::unsafe_make( [ 0x58664732 , 0xfd21f26b , 0xdb3f6742 , 0xebe1696c , 0x602d6707 , 0x6b7dbde3 , 0xd824cb08 , 0x11eebcdf ] )
+  , Mont::unsafe_make( [ 0xda7f9783 , 0x94400b9f , 0x519270da , 0x6284d834 , 0x79cd39c2 , 0x1c4c1577 , 0x910cfea0 , 0x2ccad46d ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x40490e64 , 0x041521d4 , 0x9369d46c , 0x7c77b1b3 , 0xfaa19bb8 , 0x05eee3bb , 0x21365d36 , 0x0fcd210a ] )
+  , Mont::unsafe_make( [ 0xc4892518 , 0x20eef8ad , 0x04c379ae , 0x02ab1863 , 0x7dc876e8 , 0xf70c0699 , 0x4cf7e769 , 0x21784e44 ] )
+  , Mont::unsafe_make( [ 0x7b13b92c , 0x95a53a78 , 0xdbae7b4f , 0xf290a35f , 0x0ff28417 , 0x31cee693 , 0x59e4fa26 , 0x0c040b0e ] )
+  , Mont::unsafe_make( [ 0x7b92f52a , 0x24e9d94e , 0x53f34e89 , 0x7df42f15 , 0x922e6e30 , 0x9b657679 , 0xf777e300 , 0x159557e6 ] )
+  , Mont::unsafe_make( [ 0x8124f64c , 0xaf9beae4 , 0x8eaef6b5 , 0x78026f81 , 0x0108587b , 0x0b5a5436 , 0x8ec71b12 , 0x13f96da6 ] )
+  , Mont::unsafe_make( [ 0x44e210ef , 0xe70afbdc , 0x69f7a76c , 0xd34b4319 , 0xe9078b61 , 0x3f9bb586 , 0x46dbc30a , 0x2419d159 ] )
+  , Mont::unsafe_make( [ 0x1aff6da3 , 0x73697a87 , 0x21006a4f , 0xbcbd9d25 , 0xcccda000 , 0x79f37a83 , 0xfc08558c , 0x22896157 ] )
+  , Mont::unsafe_make( [ 0x9c725ee8 , 0x44b35f04 , 0x234b9196 , 0x6d827a16 , 0x86e85b2f , 0x67869036 , 0xcd852902 , 0x2c48d2a5 ] )
+  , Mont::unsafe_make( [ 0x405c5fc2 , 0x52dec47b , 0x0234eff6 , 0xe8650fde , 0x654b196f , 0x5fcd502f , 0x4b13a7e3 , 0x1bee5fcc ] )
+  , Mont::unsafe_make( [ 0xe47ee3b4 , 0x0e93c99a , 0xa5e56704 , 0xc3971791 , 0xa894a3ee , 0x86a195bc , 0x04fb71ba , 0x073ed9a2 ] )
+  , Mont::unsafe_make( [ 0x0307cfe1 , 0x00842171 , 0x99839961 , 0x8427e8bd , 0x3457d216 , 0x1793abee , 0x430706c0 , 0x1ade445d ] )
+  , Mont::unsafe_make( [ 0x40952900 , 0x327cdd95 , 0x0b95e498 , 0x8ecd74b2 , 0xf4d2a4e4 , 0xccedbdef , 0xb767b40f , 0x132d305f ] )
+  , Mont::unsafe_make( [ 0x121f5f9c , 0x889f674f , 0x99397070 , 0x7cc5e42a , 0x28554b78 , 0x52ad3837 , 0x7a18c632 , 0x1bb15263 ] )
+  , Mont::unsafe_make( [ 0xb87672d7 , 0x90755687 , 0x8e07cdd6 , 0xcf870b7a , 0xb684fdc4 , 0xfd89642e , 0xeaa804f4 , 0x11bdec66 ] )
+  , Mont::unsafe_make( [ 0x19850e46 , 0x79ed9552 , 0xaabe2c3f , 0xadeb61eb , 0xfc3a6b4b , 0x19c3b77e , 0xf984e0de , 0x034a6d8f ] )
+  , Mont::unsafe_make( [ 0xf192f799 , 0x651ac433 , 0xa83ef1fb , 0xeef3532a , 0xc2b43a4d , 0xe457572f , 0xe41c8504 , 0x1c977a81 ] )
+  , Mont::unsafe_make( [ 0x0a04f8a4 , 0xbde5f053 , 0x49b74795 , 0xd44a9278 , 0x2278a43d , 0xf2f72aff , 0x99d01f54 , 0x19ca4060 ] )
+  , Mont::unsafe_make( [ 0x666f6cab , 0x3f08f096 , 0x794789ef , 0x3d4018d5 , 0xee5f1189 , 0x77ff58a8 , 0x191b7987 , 0x0c375205 ] )
+  , Mont::unsafe_make( [ 0x8eb5cf0c , 0xaac4ec58 , 0xc381702b , 0x114a137e , 0xb81b592a , 0xc45c8fcd , 0xdc468354 , 0x152812a1 ] )
+  , Mont::unsafe_make( [ 0xb7e6246c , 0xefe95108 , 0x472b39bc , 0x13132b08 , 0x1535bcb6 , 0x65222f48 , 0x982b90ca , 0x1ce7f2e3 ] )
+  , Mont::unsafe_make( [ 0xe470afb5 , 0xcedd1960 , 0xf01a76c1 , 0x7b502104 , 0xd0b637a8 , 0x2d18ee9f , 0x82ece57d , 0x1eab85d3 ] )
+  , Mont::unsafe_make( [ 0x02ef9a73 , 0xeaa8629c , 0xd222ff17 , 0x5fabc53b , 0xdfaedf27 , 0x866c5f89 , 0xb6e9b853 , 0x2817c229 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x4e65fb03 , 0xd8fb9caf , 0xc22b20a1 , 0x24a0bef3 , 0x64b619c6 , 0xf2bc342e , 0x75eff347 , 0x158ac683 ] )
+  , Mont::unsafe_make( [ 0x70de7158 , 0xacc69425 , 0xbab3ac82 , 0x59f834ee , 0xd8383794 , 0x77aef313 , 0x0d66b7ba , 0x261d2d97 ] )
+  , Mont::unsafe_make( [ 0x713040ac , 0x85c37d97 , 0xf223fadf , 0xa749ad1c , 0x877d7118 , 0xa45d468f , 0xa89c8592 , 0x1a52b4fb ] )
+  , Mont::unsafe_make( [ 0x1b410ab1 , 0x35d46649 , 0xee3aa1fc , 0x6f9ad023 , 0xc0518575 , 0x2f12d22a , 0x0c084872 , 0x22e86012 ] )
+  , Mont::unsafe_make( [ 0x1dd192fa , 0xfb16b2da , 0xcd744169 , 0x70eb7207 , 0x6a95a4a4 , 0x6e44cbb8 , 0x58f0e1fd , 0x2772204b ] )
+  , Mont::unsafe_make( [ 0x7fce8ddf , 0xe91db4c8 , 0xede69577 , 0x25eda53b , 0xacb26756 , 0x5e1ff794 , 0x79a8dfff , 0x1030861a ] )
+  , Mont::unsafe_make( [ 0x5e075e67 , 0x7e187da0 , 0xb6dfde2f , 0xccbf279c , 0x88ff7f46 , 0x8fed6af6 , 0x34edc6ca , 0x1fe13963 ] )
+  , Mont::unsafe_make( [ 0xfbb4e0d8 , 0x6c41842d , 0x85d99d70 , 0x4ec1caf6 , 0xdc159936 , 0xe16dbc7f , 0x47343885 , 0x0f0fe40b ] )
+  , Mont::unsafe_make( [ 0x45cc9050 , 0x56722580 , 0x350e85c6 , 0xdc13126e , 0x6afe0887 , 0xd28463cd , 0xc8979582 , 0x0454b07e ] )
+  , Mont::unsafe_make( [ 0x3170751b , 0xce74b663 , 0x0eddc7dc , 0x4fb3b78a , 0xd440fb8c , 0x9a067357 , 0x53ae2c3c , 0x00c78409 ] )
+  , Mont::unsafe_make( [ 0xd6246b6d , 0x2b0cf4ed , 0x375d8f2a , 0x3dff9b23 , 0x9797f453 , 0x8f84bb63 , 0x9dabe6de , 0x26806c0e ] )
+  , Mont::unsafe_make( [ 0xc103fba0 , 0x62941871 , 0x49c25305 , 0x2ecdd8b9 , 0x7c583293 , 0x8f32e841 , 0x90248b90 , 0x07c4a5ee ] )
+  , Mont::unsafe_make( [ 0x58652e13 , 0x24f40005 , 0xd432db93 , 0x33de4ac9 , 0x0e564919 , 0xedf29f30 , 0x4fae5b6a , 0x20c2c22c ] )
+  , Mont::unsafe_make( [ 0xe787574a , 0xec83b4c7 , 0x50e3b524 , 0x54e7fc99 , 0x2ffe986f , 0xf24ca944 , 0xba463205 , 0x1045a9d9 ] )
+  , Mont::unsafe_make( [ 0xc9e4fcdb , 0x59517024 , 0x765b7a33 , 0x5d2a5f33 , 0x24e223f2 , 0x597ea80c , 0xa468e2eb , 0x0661e0fd ] )
+  , Mont::unsafe_make( [ 0x9166da23 , 0x6da3f332 , 0x19789e71 , 0x72d69f33 , 0x49d28563 , 0xfcbf5040 , 0x9bbdd1e8 , 0x2c97b13d ] )
+  , Mont::unsafe_make( [ 0x7cf75b87 , 0x4ed49f74 , 0x30106e0f , 0x972f14fd , 0xe21a4ebe , 0x3ee5cf1b , 0x5da46878 , 0x19217f68 ] )
+  , Mont::unsafe_make( [ 0x73783c15 , 0x297102a1 , 0xfecf1076 , 0x9976db60 , 0x332f4dd8 , 0xe6b15ea5 , 0x575abcc8 , 0x2db4b547 ] )
+  , Mont::unsafe_make( [ 0xbb0a6b26 , 0xee598670 , 0x6ef8d2e1 , 0xfc7d4132 , 0x370c3632 , 0x3d79b983 , 0xafc5c580 , 0x1e608b53 ] )
+  , Mont::unsafe_make( [ 0xd14b7272 , 0x2b9383ba , 0xfc4564a6 , 0xdfe5b4c8 , 0xacabeef8 , 0xe54f3eeb , 0x223ae8d1 , 0x2de60677 ] )
+  , Mont::unsafe_make( [ 0x22d0fd96 , 0xa9820286 , 0x0b054ea4 , 0xe8c6fb97 , 0x99a35522 , 0x7c21557a , 0xdc6b4c6f , 0x18d58bd9 ] )
+  , Mont::unsafe_make( [ 0xb252801d , 0x4d262c51 , 0xafd5417f , 0xa52d87af , 0x385d2fae , 0x55b62097 , 0xa65eee0c , 0x2d16eecf ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xa84d1e43 , 0x9d76387a , 0xc7facdbd , 0x50d2f7ea , 0x17b48a07 , 0xd0aaecbd , 0xbda0f117 , 0x27d40045 ] )
+  , Mont::unsafe_make( [ 0xbdc3aee3 , 0x4f90efb7 , 0x9e4337a3 , 0x4c13903e , 0x99a56d9a , 0x68c76a5a , 0x31f9e122 , 0x25561c17 ] )
+  , Mont::unsafe_make( [ 0x9301879a , 0x4f210eb0 , 0x96128e2b , 0x4ba3759b , 0x0a09f649 , 0x73656033 , 0xb5977ab4 , 0x2fcfa675 ] )
+  , Mont::unsafe_make( [ 0xe50e0306 , 0xc90eeb7f , 0x5e215713 , 0xf9db599c , 0xa23063f8 , 0x81b1ebf5 , 0x829c7854 , 0x005b2721 ] )
+  , Mont::unsafe_make( [ 0x3b52fb7c , 0xba5b21b4 , 0xc2f761d5 , 0xc702c2ee , 0x0955e4dc , 0x504f774d , 0x09306697 , 0x0de30f3d ] )
+  , Mont::unsafe_make( [ 0xdf7dcd82 , 0xe71739ae , 0x5ed2bd94 , 0xae890673 , 0x132b0f1e , 0x5c6e3ea8 , 0xd49b0d30 , 0x29ce0299 ] )
+  , Mont::unsafe_make( [ 0xf329f33f , 0xed9cd6c7 , 0xf98a7c7e , 0xcf17ad62 , 0x80c84348 , 0x0982eb47 , 0x9d8e8217 , 0x0ea906a7 ] )
+  , Mont::unsafe_make( [ 0xd000fe79 , 0x416f3cd8 , 0x8b7098cd , 0x8eeccef8 , 0x711511a4 , 0x91e6400f , 0x28358387 , 0x082c7b4d ] )
+  , Mont::unsafe_make( [ 0xe6d3679c , 0x877c400e , 0xfefde771 , 0xa9d3e6a8 , 0x882bbcd8 , 0xf0c5ebe6 , 0xd42e87e4 , 0x2873655b ] )
+  , Mont::unsafe_make( [ 0x77067ee7 , 0x7ed8fbbb , 0xf4c7109d , 0x3a3fb573 , 0x05f584cd , 0xc65e8ae4 , 0x4db1b1a7 , 0x199d041f ] )
+  , Mont::unsafe_make( [ 0xd9223489 , 0x32b35d1c , 0x1e544e5a , 0xc0bc261f , 0x472ffa8f , 0x471fa934 , 0x5810e65c , 0x29c7f5ce ] )
+  , Mont::unsafe_make( [ 0x5696a9a3 , 0x6b115d22 , 0x0c855846 , 0x2bfb440b , 0xc6598ba6 , 0xa9b79a72 , 0x523a7baa , 0x07911099 ] )
+  , Mont::unsafe_make( [ 0x60e397de , 0x61c77762 , 0x9bf43182 , 0x13767626 , 0xb029e8fe , 0xfa728890 , 0x080c88bd , 0x21933c9a ] )
+  , Mont::unsafe_make( [ 0x0a6b0c50 , 0x0832fd1b , 0x84287fc3 , 0xe97ba092 , 0x23dfe2d1 , 0x50f0a396 , 0xa651540d , 0x1939bd46 ] )
+  , Mont::unsafe_make( [ 0x54fcb0aa , 0x36f66102 , 0x14fb532a , 0x0456c793 , 0xa307ea33 , 0x6c13996c , 0xedb0aef4 , 0x0b82b1ee ] )
+  , Mont::unsafe_make( [ 0x78f93dbc , 0x55a2d38a , 0xb9095f50 , 0xb97f6ada , 0x3dad3844 , 0xfd5476ed , 0x5f6f326d , 0x289e1ee9 ] )
+  , Mont::unsafe_make( [ 0x3753dc6f , 0x12addd60 , 0xfbc95c53 , 0x0ded8e18 , 0x14f9e124 , 0xeeb065d5 , 0x0f5c94fb , 0x1cd19238 ] )
+  , Mont::unsafe_make( [ 0xfdeb8bea , 0x7f18f65a , 0xfcc81337 , 0x73f097bb , 0x93ab6b0a , 0x73a73c36 , 0xfa403e04 , 0x0452c722 ] )
+  , Mont::unsafe_make( [ 0xcdcf2fc3 , 0xd8ceb213 , 0xa978897c , 0x36106214 , 0xe8ca1d09 , 0xce6bda29 , 0x6b26e531 , 0x2f23dc35 ] )
+  , Mont::unsafe_make( [ 0x9e643bfb , 0x07cc5262 , 0xa5531606 , 0x3088ae0b , 0x4d680ee9 , 0x183f2e3c , 0x5348d27c , 0x1f96fee8 ] )
+  , Mont::unsafe_make( [ 0xe95408ec , 0x9de9c111 , 0x89989990 , 0xa35fbaad , 0x50282ef6 , 0xdac5598b , 0xef7c3938 , 0x2501400a ] )
+  , Mont::unsafe_make( [ 0x47bca724 , 0x56608b8c , 0x705c6981 , 0x084e2ffc , 0x684f2069 , 0x2454b5e8 , 0x11bc0d0a , 0x1cb38885 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x8bcb4335 , 0xc0261426 , 0x6dbfb231 , 0x593ef717 , 0x5baa53e0 , 0x2f9debbd , 0x51ad3c23 , 0x016f0b5a ] )
+  , Mont::unsafe_make( [ 0xf521a1d6 , 0xb2b50816 , 0x33de37d1 , 0xf9b72e95 , 0x905c57fb , 0x37a66c41 , 0x8abee777 , 0x02e09425 ] )
+  , Mont::unsafe_make( [ 0xab78a3d1 , 0x19ed866c , 0xc445dc8a , 0xfe6bb5da , 0xf33f77f7 , 0x65523f3b , 0x1bb0868b , 0x2cdb9ae0 ] )
+  , Mont::unsafe_make( [ 0xb382c82a , 0x04a3b0b2 , 0x86b6c510 , 0x4c3a16bf , 0xfa497c47 , 0x7a5d3ebe , 0x3dd40494 , 0x00400bd3 ] )
+  , Mont::unsafe_make( [ 0x0dd10e88 , 0x7b5d8ff7 , 0x69b9ac91 , 0x05320d78 , 0x3c079fca , 0xb6f321e1 , 0x8e9c483a , 0x04f68161 ] )
+  , Mont::unsafe_make( [ 0xfb2f1da1 , 0x6be98682 , 0xe957ccfd , 0xc80681f6 , 0x2220b2da , 0x3ae8df06 , 0xdfbf9e6c , 0x2c01b1f6 ] )
+  , Mont::unsafe_make( [ 0xb1d53426 , 0x09c2348d , 0x7fa31376 , 0x318a5bfc , 0xb3818dde , 0x218b7f83 , 0x5c2c72dc , 0x0c6e73ff ] )
+  , Mont::unsafe_make( [ 0x6f58b43f , 0x4bb096dd , 0xffd0eb53 , 0x64b78476 , 0xf1e1c61c , 0xfca37681 , 0xcc5a1a43 , 0x0d4143ae ] )
+  , Mont::unsafe_make( [ 0x3e68e248 , 0x940716ca , 0xb9ad81f5 , 0x4e09eece , 0x76e245d6 , 0x9e891bf0 , 0x161b3da4 , 0x006ea58e ] )
+  , Mont::unsafe_make( [ 0x5afc2e69 , 0x19afed24 , 0x43e8233f , 0x32402145 , 0x017bf120 , 0xec03bd7d , 0x4b207c69 , 0x1e9e3a0d ] )
+  , Mont::unsafe_make( [ 0x8ff6286a , 0xdfc986c4 , 0x1f80e60e , 0x01dca859 , 0x8d35f40a , 0x5d6737f8 , 0x0a2818d3 , 0x204c4734 ] )
+  , Mont::unsafe_make( [ 0xc400be31 , 0x3e1acaa8 , 0x759580c8 , 0xf2f9f07b , 0x27ae023a , 0x0f14eea1 , 0x3464106d , 0x0f6f1f24 ] )
+  , Mont::unsafe_make( [ 0xd20a1b9a , 0x2c54b90a , 0x8cd756f5 , 0xe7e42442 , 0x0a650887 , 0x74077fff , 0x50422c90 , 0x10af8e53 ] )
+  , Mont::unsafe_make( [ 0xc4677341 , 0x14beda16 , 0xc86350b0 , 0x3cf57b5d , 0xa44e150e , 0x605cb4ee , 0x9e2ee071 , 0x256df3a8 ] )
+  , Mont::unsafe_make( [ 0xcbd72b52 , 0xc8f3f012 , 0xad3cb708 , 0x94415ad8 , 0x31101684 , 0xf1d302a1 , 0x89601913 , 0x220747d4 ] )
+  , Mont::unsafe_make( [ 0xb665c156 , 0xafe20edd , 0x5fa50a37 , 0x91ab290f , 0x59960e59 , 0xe81bd175 , 0x841ba3b5 , 0x1db131ca ] )
+  , Mont::unsafe_make( [ 0x1623abdc , 0x039ca512 , 0xd316773e , 0x8dac8840 , 0x6589ecc0 , 0x05b228e5 , 0x30c7f1dd , 0x2c143534 ] )
+  , Mont::unsafe_make( [ 0x735c3fa0 , 0x4f68e99c , 0xb2e9d5b2 , 0x7d01ac92 , 0x76d2d042 , 0x7e0ff1be , 0xc0193930 , 0x0beca97e ] )
+  , Mont::unsafe_make( [ 0x1ef73563 , 0x35fded35 , 0xdc15c029 , 0x58d8e9b7 , 0x8b196bfe , 0x081106b5 , 0x20e4b4de , 0x215b7ad5 ] )
+  , Mont::unsafe_make( [ 0x8cce656c , 0x64b8ffb1 , 0x6d5700f1 , 0x08e39965 , 0xe975688d , 0x16c00bea , 0x132acc5c , 0x1168f2b7 ] )
+  , Mont::unsafe_make( [ 0x6d5100a2 , 0xe5732e1b , 0x8ec7b5f9 , 0xabae5e92 , 0xc939fd7d , 0x8155ee80 , 0x1363b093 , 0x043ceb2f ] )
+  , Mont::unsafe_make( [ 0x150299df , 0x6ca461ff , 0x2aa2eb6f , 0x9a9c5498 , 0x88fd28d7 , 0x067a5d0e , 0xb3f8239e , 0x2366d59f ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xf3ef65aa , 0xa5d2a12b , 0x6d17ab59 , 0x2ca3350a , 0x9df09daa , 0xc8d16185 , 0x7e583b2d , 0x0ca4b54d ] )
+  , Mont::unsafe_make( [ 0x4e34a1d7 , 0xd6f75cae , 0x1e1e4acb , 0x31ddc6ad , 0x62e8f5b3 , 0x731e9545 , 0x9c3f6cef , 0x2db8288f ] )
+  , Mont::unsafe_make( [ 0x0a8729cc , 0xb2d69b77 , 0x39f5d86b , 0xf8a97095 , 0xf8f67e30 , 0xd9df359e , 0xa5c9d5d3 , 0x0f1c3988 ] )
+  , Mont::unsafe_make( [ 0x0b3f7628 , 0x5f38768e , 0x80fc9382 , 0x23bd9208 , 0x1c1b9f93 , 0xe48962bd , 0x6e2fa48a , 0x03a8b8ce ] )
+  , Mont::unsafe_make( [ 0x23fdea22 , 0x700f4ed4 , 0xf6f0a9d6 , 0xe6d6a424 , 0x9fb855c6 , 0xbbf9c2ba , 0xff5bc44e , 0x047ae570 ] )
+  , Mont::unsafe_make( [ 0xc15b39ef , 0x4a507432 , 0xf20e4a55 , 0x2f4841de , 0x318f406a , 0xb57916fb , 0x0965fe99 , 0x2eb13ab3 ] )
+  , Mont::unsafe_make( [ 0xbbd30338 , 0x994c948b , 0x272272ae , 0x1186e62a , 0xda5eb123 , 0x5ea2f233 , 0x10ceb074 , 0x09093ca6 ] )
+  , Mont::unsafe_make( [ 0x6c3ac01c , 0xadee7c11 , 0x45259924 , 0x7f88348e , 0xfe62fbdb , 0xdc4e3f86 , 0x096146e4 , 0x16b86c47 ] )
+  , Mont::unsafe_make( [ 0xe2e3045f , 0x184084a2 , 0xfe2a973d , 0xbf7dc341 , 0x93cc8e8f , 0xfcc6ead9 , 0xde1364cb , 0x06c0f539 ] )
+  , Mont::unsafe_make( [ 0xc0036669 , 0x85a888cb , 0xbd10328c , 0x1497a292 , 0x3151822f , 0xf9e08f97 , 0x03c81915 , 0x0234948e ] )
+  , Mont::unsafe_make( [ 0x454c2ebe , 0xc06281c4 , 0x4a2eca4d , 0xb53ca25f , 0x61f3a756 , 0xf260dae7 , 0x085531ce , 0x0eb32f88 ] )
+  , Mont::unsafe_make( [ 0xf9f4423e , 0x36765ca9 , 0xd03938b0 , 0xb68e7ed9 , 0xd96b9731 , 0xf1c5d6e3 , 0x3145cf85 , 0x0f08f0d3 ] )
+  , Mont::unsafe_make( [ 0x4699696f , 0xe1b97201 , 0x99f5eb13 , 0x511a0cd7 , 0xdd9da758 , 0x42575ad7 , 0x12b6a07c , 0x2f8bd24e ] )
+  , Mont::unsafe_make( [ 0x531acde6 , 0xcf61de5b , 0xcd0e47c8 , 0x4ecc0b48 , 0xa90704f0 , 0x74fb64f5 , 0x68f97af7 , 0x117d04f1 ] )
+  , Mont::unsafe_make( [ 0xff5f24f8 , 0x67240049 , 0x684c3df2 , 0x2934e6c7 , 0xcb9adb4a , 0x35472569 , 0x4b279209 , 0x11c6aeaa ] )
+  , Mont::unsafe_make( [ 0x00c066d7 , 0xb2cb66d1 , 0x29183f06 , 0xc4803575 , 0x5c7c86cc , 0xab5c973e , 0xad39d5d9 , 0x2bbf20cc ] )
+  , Mont::unsafe_make( [ 0xc64bfafe , 0x55ea1a8f , 0x5dfe65f7 , 0x20c3a209 , 0x13c58874 , 0xac8cf585 , 0x35ebcfb8 , 0x06ff8446 ] )
+  , Mont::unsafe_make( [ 0x13b9a0d9 , 0x3e196dea , 0xf8ece239 , 0x30a8bd03 , 0x3b507b45 , 0xc923f93f , 0xbdb777eb , 0x30234af3 ] )
+  , Mont::unsafe_make( [ 0xf7d7185c , 0x604fc4ee , 0x80c7b513 , 0x26ea045e , 0x38e83a75 , 0x7be38c07 , 0x32d910a5 , 0x12e4e1da ] )
+  , Mont::unsafe_make( [ 0x48cdeed5 , 0xd06c05eb , 0x99ad4c7b , 0xeec6ec8f , 0xe645958a , 0x92afbe84 , 0xdf9fcbcb , 0x226cf4e7 ] )
+  , Mont::unsafe_make( [ 0x65abf816 , 0xf1ec97c5 , 0x629b8156 , 0xb0dffa14 , 0xd2d91d1d , 0x5f33035d , 0xc53db963 , 0x27daccc2 ] )
+  , Mont::unsafe_make( [ 0xe068a27e , 0x1a9b2299 , 0x6de79be7 , 0x59136303 , 0x85817a1b , 0xa5b1ea0f , 0x1b7d00ce , 0x207f179b ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x5a6cb25a , 0x5fc1f240 , 0x9d80e7c5 , 0x092e867b , 0xf11fe952 , 0x99a94cb6 , 0x2c933e34 , 0x2e4c034b ] )
+  , Mont::unsafe_make( [ 0x42b42336 , 0xca7f86e6 , 0xd942d072 , 0x37393a24 , 0x0534a16d , 0x0dfe3089 , 0x8677feb9 , 0x2fcf9827 ] )
+  , Mont::unsafe_make( [ 0x19266611 , 0xb0d72bd9 , 0x5428bd05 , 0xda9131b5 , 0xfc1802af , 0x18e8ded9 , 0xa25981e0 , 0x003785c8 ] )
+  , Mont::unsafe_make( [ 0x546e08cb , 0x1487ac2f , 0x280d2dbe , 0xd89c0ab3 , 0xb400a625 , 0x4a4a3d91 , 0x5ae6868c , 0x2ffd88fb ] )
+  , Mont::unsafe_make( [ 0x2b27fef2 , 0xf5df934e , 0xf8eaf590 , 0x91e1c92e , 0x695e2bfc , 0x6940f7f4 , 0x03164113 , 0x304e1c72 ] )
+  , Mont::unsafe_make( [ 0x83008745 , 0x36b6589e , 0x0c4c8611 , 0xd04b6ca5 , 0x8975d4d0 , 0x77432d65 , 0x2bffc8e8 , 0x1e5c6eb5 ] )
+  , Mont::unsafe_make( [ 0x3cf79b65 , 0xae496e4e , 0x2c808f1e , 0xd162c252 , 0xddea2357 , 0x48ac7ae1 , 0x703498dc , 0x2e8225fc ] )
+  , Mont::unsafe_make( [ 0x40921e3a , 0x64b1cd4c , 0xaa343d48 , 0x8ed71c33 , 0x3cad0b97 , 0x4b1825c7 , 0x5d58672a , 0x2ee6369a ] )
+  , Mont::unsafe_make( [ 0x9dd04cb2 , 0x080adbdf , 0x5cd1906a , 0x932df55a , 0x2e136715 , 0xf016c8c4 , 0x93a05be7 , 0x23e9a98e ] )
+  , Mont::unsafe_make( [ 0xaa96c911 , 0x4c1c1437 , 0xe744d594 , 0x39ffc7fe , 0x477ca28c , 0x0862a863 , 0xd03a748a , 0x156af7f8 ] )
+  , Mont::unsafe_make( [ 0x7c4f630e , 0x32268040 , 0xa050b9f0 , 0xa739c179 , 0x89fd4e36 , 0xbbb00828 , 0x8deda616 , 0x2d549fac ] )
+  , Mont::unsafe_make( [ 0xc9062de5 , 0x80972681 , 0x020ebfed , 0xfa66ccca , 0x2170d7e8 , 0x8328ce8f , 0xb8a1d2c0 , 0x047a7ce4 ] )
+  , Mont::unsafe_make( [ 0x19adf7c8 , 0xdf4211a8 , 0xfee9240c , 0xd97d77a3 , 0xda043532 , 0xd793d399 , 0xce7cb6ff , 0x251123eb ] )
+  , Mont::unsafe_make( [ 0xe4d0b9e7 , 0x2eb00ef9 , 0xa0ee97b4 , 0xabf5e4d5 , 0x9b4ca538 , 0x55b7994d , 0xba03aa94 , 0x1733153a ] )
+  , Mont::unsafe_make( [ 0xff3bb4e0 , 0xf0904d87 , 0x76dd60fb , 0x4dd9b903 , 0x03e99cf0 , 0xcd4d102e , 0xfe176c83 , 0x25ce4d8e ] )
+  , Mont::unsafe_make( [ 0x1d2f42cd , 0x46f2fc1d , 0x3fa228c5 , 0xd97ab088 , 0xb240876a , 0x8a27ec6f , 0x55e1df0f , 0x280e9d2c ] )
+  , Mont::unsafe_make( [ 0x740d960a , 0x13f27b8c , 0x50537762 , 0xeac66234 , 0x5d61ca5d , 0x34add2b1 , 0x479fadb8 , 0x1dc54603 ] )
+  , Mont::unsafe_make( [ 0x59ed6181 , 0x6afb4797 , 0x8f72cca3 , 0x33ab19ca , 0xc21817ca , 0x80b4c1c4 , 0x5091444d , 0x2422f962 ] )
+  , Mont::unsafe_make( [ 0xd90ebbb1 , 0x80b28f42 , 0x12507f2c , 0x05a3064c , 0xc1845e32 , 0xa3464847 , 0x8ad65d62 , 0x136dbbfa ] )
+  , Mont::unsafe_make( [ 0x05a99e3e , 0xfef252a0 , 0x4332a3ec , 0xabf317a4 , 0x66e5d6f7 , 0x4e6ce189 , 0xaf03e5f9 , 0x101585e8 ] )
+  , Mont::unsafe_make( [ 0x929b5bbd , 0x9bec9ca0 , 0x758f3a95 , 0x0506bc5a , 0xd841f288 , 0x2f40189c , 0x93556f1b , 0x0f12e791 ] )
+  , Mont::unsafe_make( [ 0xd5ea9526 , 0x890c9fd2 , 0xc0f0bd0c , 0x4aedc11e , 0x073caafe , 0xc8e6f131 , 0x404b279d , 0x08d3ffdf ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x04842cf2 , 0x82edbe0e , 0x80ee3723 , 0xfe67badd , 0x1d17af78 , 0x854ebde4 , 0x590a6d4a , 0x2a06c751 ] )
+  , Mont::unsafe_make( [ 0x6286afd0 , 0xa54ce331 , 0x3978f5be , 0x31a18990 , 0xb09ec71d , 0xc1527ad6 , 0x9ec4336e , 0x0ef04ba8 ] )
+  , Mont::unsafe_make( [ 0xf52437c6 , 0xa32c0acc , 0x681e8c9f , 0xfb19413a , 0xda7fa0b7 , 0x4e474bc5 , 0x4d067b0c , 0x05ce55c6 ] )
+  , Mont::unsafe_make( [ 0x8817750b , 0xde2b8e08 , 0xb26e0546 , 0x247df599 , 0xef615b6e , 0x730773bf , 0xc12c4822 , 0x1cb00130 ] )
+  , Mont::unsafe_make( [ 0xea9bd8e9 , 0xb87a334d , 0x313e7b44 , 0x54eeaf7f , 0x0a03b0bd , 0x8d27ec5a , 0xa7b0e0e0 , 0x2a176dec ] )
+  , Mont::unsafe_make( [ 0x6c5331cc , 0xd8929ade , 0xc7eae5f1 , 0xad0c6466 , 0x09c0bdb5 , 0x02ee041d , 0xe6fd325d , 0x00ca56a8 ] )
+  , Mont::unsafe_make( [ 0xc7e9c868 , 0x4198c8f8 , 0x07291525 , 0xfbb15448 , 0x2e08a596 , 0xc49a28bb , 0x59a94380 , 0x0b4feef2 ] )
+  , Mont::unsafe_make( [ 0xb1abaac8 , 0x44004ddb , 0x66477b04 , 0x09cbed72 , 0x08dfb798 , 0x9ee48a71 , 0x87a442a1 , 0x106a6c80 ] )
+  , Mont::unsafe_make( [ 0x52785fe5 , 0x13c22c5e , 0x3297f576 , 0xa276e444 , 0x5a86c9cb , 0x005b9009 , 0x9f34f61c , 0x0f6e3e5c ] )
+  , Mont::unsafe_make( [ 0x5ef15e02 , 0xb8818080 , 0x735506e2 , 0x1fcd4371 , 0x01bef7c1 , 0x347def51 , 0x491a4981 , 0x14995e4f ] )
+  , Mont::unsafe_make( [ 0x1b14088e , 0x97742470 , 0xa845e369 , 0xd33d7ae3 , 0x1241cfea , 0xff3f8422 , 0x51dde55a , 0x041835e5 ] )
+  , Mont::unsafe_make( [ 0xcda38143 , 0x67b1ce5b , 0xb1070007 , 0x12f73a03 , 0x7c558814 , 0xd5d9cadc , 0xf5abbe85 , 0x2433a654 ] )
+  , Mont::unsafe_make( [ 0x51216053 , 0xc9a41993 , 0x4166b162 , 0x9918b4c5 , 0x5d261654 , 0x0d78317f , 0x5c373f1b , 0x24ca8a73 ] )
+  , Mont::unsafe_make( [ 0x5882eafa , 0x63d699d8 , 0x06064bdd , 0x3cbf9c7c , 0x7f2139ad , 0x070774a6 , 0xafb78272 , 0x11e977e5 ] )
+  , Mont::unsafe_make( [ 0x947e8977 , 0x461e57f8 , 0xec617513 , 0x967aa9d6 , 0xb8b687a6 , 0xe796f440 , 0x8d3000e3 , 0x0eb1898c ] )
+  , Mont::unsafe_make( [ 0xbb8731a1 , 0x3c91492f , 0x9435d4e6 , 0xa2ca510b , 0x889f0185 , 0x5273169d , 0xc722b297 , 0x14d5984f ] )
+  , Mont::unsafe_make( [ 0xfd5cdddd , 0xec3795eb , 0x8c5e554c , 0x35967c79 , 0x2e0ff18c , 0x4eb7ee7c , 0xbaf31f9d , 0x2fd02fed ] )
+  , Mont::unsafe_make( [ 0x37bbf03d , 0x8d8d81c0 , 0x58ae4ff1 , 0x33cf36c2 , 0xeb6e33e5 , 0x64c986bd , 0x04a819ba , 0x031ebb75 ] )
+  , Mont::unsafe_make( [ 0x7a922530 , 0xe9dd1624 , 0x2c0ffe51 , 0xcf6f7fa0 , 0x5a52c69f , 0x23fe9a82 , 0x8e67f353 , 0x22e79a69 ] )
+  , Mont::unsafe_make( [ 0xeadf2e4d , 0xd3c02e9c , 0xba40a3cd , 0x9f4d92b0 , 0x5a3ee9b8 , 0x9a3cb7c7 , 0x1d932e14 , 0x2b103ce4 ] )
+  , Mont::unsafe_make( [ 0xae527947 , 0x37cabc9c , 0x6db603fb , 0xfa7cd636 , 0xe0408349 , 0xfaaad73c , 0x7f7fbbd9 , 0x30215a6c ] )
+  , Mont::unsafe_make( [ 0x684f8670 , 0xa2ea77bd , 0xfb5511fb , 0x65545f8b , 0xb8a84101 , 0x29ebf56f , 0xd59481f9 , 0x2a6aeaff ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x60bfb3e4 , 0x7acec018 , 0x0084b882 , 0x6d208f69 , 0xccf3f253 , 0x2e579f35 , 0x874521f5 , 0x2da3ce5e ] )
+  , Mont::unsafe_make( [ 0x67bc1a7a , 0x108d2968 , 0x8ed40702 , 0xc00677a5 , 0x1aa4c583 , 0x9b088f7c , 0xb71798a9 , 0x2109cc79 ] )
+  , Mont::unsafe_make( [ 0xb1ed8022 , 0x29f60e45 , 0xb0b67f1c , 0x3eb7ea68 , 0xe8a83353 , 0x80b33b2b , 0xdf52af35 , 0x076baa02 ] )
+  , Mont::unsafe_make( [ 0x70860637 , 0xf3ac58c5 , 0x330f1331 , 0x6f14b48b , 0x087e0074 , 0x189c07c2 , 0xcf55737a , 0x003461de ] )
+  , Mont::unsafe_make( [ 0x55bcdbc3 , 0xc5124b01 , 0xece000d6 , 0x4ea2efbc , 0xf1afb5c6 , 0x81c3911d , 0x52c316a9 , 0x21fc6ea1 ] )
+  , Mont::unsafe_make( [ 0xc12a28ae , 0x0d49db5f , 0x4503daa7 , 0xd681b80f , 0xdd728977 , 0x5300873a , 0xf8424a40 , 0x003bea3c ] )
+  , Mont::unsafe_make( [ 0x14831629 , 0x590154c7 , 0x4b8afc69 , 0x84009197 , 0x32eaa4f1 , 0x1f7cacbe , 0x2e129156 , 0x23640aa8 ] )
+  , Mont::unsafe_make( [ 0x3a885466 , 0x6c43805f , 0x329135a9 , 0x2c67b973 , 0xde334b77 , 0x3a82d318 , 0xd844b895 , 0x2de806c1 ] )
+  , Mont::unsafe_make( [ 0xaa0ec988 , 0x89d1d182 , 0x83ddef42 , 0x15c5b63c , 0x52977c7c , 0x3f818eeb , 0xa5446cc6 , 0x26da37ae ] )
+  , Mont::unsafe_make( [ 0x4931c76f , 0xe356409d , 0x472ba319 , 0x93e6a9d2 , 0x9e8073c9 , 0x2864c14c , 0x699de4ec , 0x0e39217a ] )
+  , Mont::unsafe_make( [ 0x8b6a17b2 , 0x4bd67aa3 , 0x14426f74 , 0x57160b85 , 0xd0f468d6 , 0x0d494070 , 0xf01b62d5 , 0x1a66ea07 ] )
+  , Mont::unsafe_make( [ 0x3e8a18ff , 0x790900c1 , 0x14f1e7cc , 0x61ed9fdd , 0x6f58c834 , 0x76bda715 , 0xba1ff65f , 0x00005bfe ] )
+  , Mont::unsafe_make( [ 0xc96591f0 , 0xaecdc3e0 , 0x7a3b90c8 , 0xc7c61e7d , 0xff4bf018 , 0x6f6157e9 , 0xe6a6952c , 0x1a0deba0 ] )
+  , Mont::unsafe_make( [ 0xeb70243e , 0xbdf2363e , 0xc9e9711f , 0x68281c6e , 0x29b4cd13 , 0x12f729fb , 0x7e7c9c09 , 0x0c1b21b7 ] )
+  , Mont::unsafe_make( [ 0xe0f5f62f , 0x1063f31b , 0x432b5cdb , 0x1a4d6102 , 0x4e17c1c2 , 0x20d0eaec , 0x4676aa33 , 0x1d48ecb2 ] )
+  , Mont::unsafe_make( [ 0xc74b8073 , 0x78749839 , 0xa50bc767 , 0xdd16d164 , 0x38ca637f , 0xb1762a89 , 0x0a39f3e1 , 0x2a7997f2 ] )
+  , Mont::unsafe_make( [ 0x275240aa , 0x43350e5f , 0x1f284c5c , 0x9feb9a60 , 0x703aade0 , 0x133527a7 , 0x3b41ec48 , 0x1739d846 ] )
+  , Mont::unsafe_make( [ 0xc5460ee1 , 0xd7bcbe7b , 0x7d97d4e7 , 0x15646624 , 0xbc3f9f58 , 0xda9aaf66 , 0x7242e388 , 0x1f6ce4d7 ] )
+  , Mont::unsafe_make( [ 0xbf7d0c39 , 0xe7f3b45a , 0x26b75701 , 0x857e3398 , 0x84463c78 , 0xf5da9b03 , 0xdf4e151d , 0x1a1b77fa ] )
+  , Mont::unsafe_make( [ 0xb698dbff , 0x2c3e9273 , 0x4e6f3622 , 0x4cd604c3 , 0x8f9d06ae , 0xe08cd3a0 , 0x3071cfbe , 0x0ba9770b ] )
+  , Mont::unsafe_make( [ 0x0ed31d67 , 0x149a44b5 , 0x4897d8eb , 0x926278d2 , 0x873404c2 , 0xe0370f7e , 0x5d11aaad , 0x109104b7 ] )
+  , Mont::unsafe_make( [ 0x1ac1adbb , 0xbd8950f6 , 0x1267d992 , 0xd685a647 , 0x01ee012a , 0x0a53cd2c , 0x9d378399 , 0x0d80d889 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x72a0e89b , 0x79db7814 , 0x09705b5b , 0xa38904c0 , 0x1dbe1636 , 0x65541ac1 , 0x52ede03e , 0x2c2e1809 ] )
+  , Mont::unsafe_make( [ 0x3fad46c6 , 0xf7ff9eb4 , 0xe045a818 , 0xc555124e , 0x55de7ca0 , 0x584993e8 , 0xfc47a442 , 0x13dc0363 ] )
+  , Mont::unsafe_make( [ 0xb011e6fd , 0x3304dac4 , 0x04bda3c8 , 0xd953f40e , 0x6e67a93b , 0x20904dbd , 0xfab3263a , 0x154905eb ] )
+  , Mont::unsafe_make( [ 0xe383c146 , 0x59979cdb , 0xccc7d99c , 0x6c3bbef6 , 0x7334bfe1 , 0x3ef9ea74 , 0x6e595bd8 , 0x1f334bbc ] )
+  , Mont::unsafe_make( [ 0x17660a60 , 0xb6348485 , 0x01e6d80a , 0x3a27a1d3 , 0xbe1afbf4 , 0x9fdff973 , 0x7429daab , 0x26554bde ] )
+  , Mont::unsafe_make( [ 0x27a2242b , 0x1d228f77 , 0xbb242027 , 0x14b6310c , 0xb1614f1b , 0xe55339e4 , 0x4c7e1c34 , 0x1938bb20 ] )
+  , Mont::unsafe_make( [ 0x15e3b89a , 0xd3a3fab5 , 0x497a8b2a , 0x2a3f3a97 , 0x3caffe85 , 0x2b546162 , 0x56506ae8 , 0x300cc73e ] )
+  , Mont::unsafe_make( [ 0x8adb7853 , 0xe1c75849 , 0x636b5077 , 0xe7153fc8 , 0x778e09ca , 0x2ed1b259 , 0x61e48b11 , 0x047cd82b ] )
+  , Mont::unsafe_make( [ 0x30b2c82b , 0x088a6c0c , 0xa355ea3c , 0xab628036 , 0x240e85d4 , 0x73bcefe7 , 0x3373fa04 , 0x03fa7795 ] )
+  , Mont::unsafe_make( [ 0x53fd2f31 , 0x23797a44 , 0xb8ac62fd , 0x4ccb81aa , 0x1909273a , 0xa739a86d , 0x67f326d3 , 0x28dcd7d4 ] )
+  , Mont::unsafe_make( [ 0x9ed2fb9c , 0xf120ec68 , 0x927184e8 , 0x728f4a35 , 0x09a72a16 , 0xdc7aca13 , 0xb07d3156 , 0x2c1359ed ] )
+  , Mont::unsafe_make( [ 0xc38961b0 , 0x93f8fbb3 , 0x34b21de1 , 0x0c347c24 , 0xf4436030 , 0xa240dc8f , 0x9da07c28 , 0x1c378b9c ] )
+  , Mont::unsafe_make( [ 0xfb9f0e64 , 0x4c78e29a , 0x3a29a6d1 , 0x0809cfd7 , 0x344eafc2 , 0xa2a1897c , 0xaf54ecf2 , 0x0dff3a74 ] )
+  , Mont::unsafe_make( [ 0x30a07d75 , 0x315d3b08 , 0xc53722a1 , 0xb3bd578f , 0x3551f340 , 0x59b4e9d6 , 0xdd27ee41 , 0x00278d8c ] )
+  , Mont::unsafe_make( [ 0x34ca7a83 , 0x89f3c532 , 0x0e5840d9 , 0x29f9a5fe , 0x3833ab02 , 0x2b04cc73 , 0x84c3a9c6 , 0x0691fa14 ] )
+  , Mont::unsafe_make( [ 0x815491a6 , 0x437b0713 , 0xc722a310 , 0x5fcb44ab , 0x56806a88 , 0x614329f8 , 0x6226adef , 0x05a40f0c ] )
+  , Mont::unsafe_make( [ 0xe70c93ff , 0xd8ce514e , 0x1a595e26 , 0xf681e339 , 0x9b140ac1 , 0xebd6836c , 0x61e038fb , 0x08fa1cde ] )
+  , Mont::unsafe_make( [ 0xb3cb906d , 0xd9da28b6 , 0x66d3b049 , 0x450d0022 , 0xed46ddec , 0xb1e760d2 , 0xa96ddbd8 , 0x1dcd52b5 ] )
+  , Mont::unsafe_make( [ 0x3d6de8e3 , 0x088aa8af , 0xe332b605 , 0x11f28542 , 0xa4c66346 , 0x83a0d857 , 0x5aa11b0b , 0x1b0e3fe3 ] )
+  , Mont::unsafe_make( [ 0x7358051c , 0x596f552b , 0xa9ad6fb1 , 0x8fe0a0c3 , 0xc190f272 , 0x24e8f5e4 , 0xde0e4336 , 0x033626f1 ] )
+  , Mont::unsafe_make( [ 0xf4f01ca6 , 0xb65783fd , 0xe2e66835 , 0xae7f0859 , 0x363c22ed , 0x465d9b5f , 0x64c814df , 0x14f9f793 ] )
+  , Mont::unsafe_make( [ 0xde56cc34 , 0x6b9a6cc2 , 0x5108748a , 0x95865006 , 0xc9d0fe70 , 0x90dc86c0 , 0x0558f0dd , 0x0efecbed ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xb79841bc , 0xa057ccd5 , 0x7f3dcb5a , 0xd77ffec4 , 0xe74a5446 , 0x39690a21 , 0x30d2e982 , 0x11a522c5 ] )
+  , Mont::unsafe_make( [ 0xffa4433f , 0xcfc46c1d , 0xa49f777d , 0x22b3beb2 , 0x55a939c1 , 0xf87c711e , 0x4d8d457f , 0x2e67c056 ] )
+  , Mont::unsafe_make( [ 0xa533e373 , 0x696d24fb , 0xed381640 , 0x976ca1ed , 0xf8713262 , 0xd82210be , 0x1d621cdc , 0x2726e036 ] )
+  , Mont::unsafe_make( [ 0xed7e1ad1 , 0xdedac65c , 0xb6b08857 , 0x8c705f14 , 0x6e171966 , 0x69a6932b , 0x5670d69e , 0x20acc991 ] )
+  , Mont::unsafe_make( [ 0x57777a0f , 0x4f17b5f5 , 0x00014c81 , 0xb1ad7127 , 0x9838aa57 , 0xe0d9aab4 , 0x5f660466 , 0x0755936a ] )
+  , Mont::unsafe_make( [ 0x6a398473 , 0x6425ba60 , 0x3e4023d0 , 0x123b92b3 , 0x5fb04cb4 , 0x30eda55b , 0x9948aa52 , 0x1f8493b6 ] )
+  , Mont::unsafe_make( [ 0xa51c340d , 0x7e7050d9 , 0x8e851374 , 0x510487cd , 0x407eec74 , 0xb29912df , 0x32cb9092 , 0x198aaf4a ] )
+  , Mont::unsafe_make( [ 0x5c576e69 , 0x05a28619 , 0x7cdff363 , 0xa59dc9cb , 0xcc448ccd , 0x5df1d1b6 , 0xb5d037fd , 0x298c2e9a ] )
+  , Mont::unsafe_make( [ 0x2c030dad , 0x2d8862b7 , 0x4cf5173e , 0x462e1219 , 0x2d058e60 , 0xdce6a62b , 0x0c96975b , 0x1c8626eb ] )
+  , Mont::unsafe_make( [ 0xa807cba0 , 0x9da77c29 , 0x6af0a290 , 0xed32731b , 0x9a13c760 , 0xf39fc319 , 0xfa185811 , 0x2f51e0af ] )
+  , Mont::unsafe_make( [ 0x41a2bee9 , 0xc711eb10 , 0xe01cedb5 , 0xab6b4f71 , 0x3fef23dc , 0x1fa33c7a , 0x91b62250 , 0x0cf030f6 ] )
+  , Mont::unsafe_make( [ 0x4d7d715c , 0x993cba89 , 0x867c1a23 , 0x3a9e94b2 , 0x01d6757a , 0x242c4c39 , 0xd348b5eb , 0x0718bd46 ] )
+  , Mont::unsafe_make( [ 0xd4ea6d44 , 0x507ae1d9 , 0x1d98f45d , 0x74b8d9a2 , 0x2293a54c , 0xd503f112 , 0x4d1231e0 , 0x0d482ec9 ] )
+  , Mont::unsafe_make( [ 0x5c1b5ef4 , 0xa8afac1d , 0x65d03c3d , 0xb9b0e5b8 , 0x62f77df9 , 0x9eee2d6d , 0xfbfca9b1 , 0x1e1403c9 ] )
+  , Mont::unsafe_make( [ 0x775a3a86 , 0xa9c6c2db , 0x7b47a284 , 0x5e9ffe5e , 0xa4a739e6 , 0x2fe1a836 , 0x125fb121 , 0x026dcf6f ] )
+  , Mont::unsafe_make( [ 0xed5fec91 , 0xe8edd038 , 0xbc04793e , 0xb3d818f8 , 0xe0cd230b , 0xdbc9791b , 0x30f971d2 , 0x29e0a583 ] )
+  , Mont::unsafe_make( [ 0x74ea4e29 , 0x801bf94a , 0x9401057f , 0x94d9176b , 0x591124c7 , 0x0c14be9b , 0x2a9e2e9b , 0x2e930de2 ] )
+  , Mont::unsafe_make( [ 0xdbf411b7 , 0x48714965 , 0x83b56305 , 0xbc8958f7 , 0xd65dafdd , 0x1a4523e6 , 0xe0ec932a , 0x207b5710 ] )
+  , Mont::unsafe_make( [ 0x24ac806e , 0x5b9e204a , 0x1e88ab37 , 0x36084f33 , 0xf53e3024 , 0x86b00884 , 0xdb27f750 , 0x181a55ca ] )
+  , Mont::unsafe_make( [ 0x9e90af48 , 0x9c34ca1c , 0x5fb71786 , 0xe4ce31f3 , 0x01634bdd , 0x97d23957 , 0x69bfe699 , 0x25788e57 ] )
+  , Mont::unsafe_make( [ 0x3ec8bb1c , 0x75a8a0fc , 0x35aec5f6 , 0xba54684c , 0xa8625c17 , 0xd4628ea5 , 0xcb28b030 , 0x17836219 ] )
+  , Mont::unsafe_make( [ 0x5c0e1b9c , 0x43424cc4 , 0xc554132d , 0x25fb25b7 , 0xa78fab8e , 0x5ca5ae4a , 0x6aa021b7 , 0x227fb22c ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xe3786542 , 0xe7869520 , 0x1f0681ef , 0x775c7334 , 0x0d3e397a , 0x9ac45a5a , 0x26d979cc , 0x18139e07 ] )
+  , Mont::unsafe_make( [ 0xd422afb8 , 0xaa746f94 , 0xca15a5b5 , 0xb96aab4b , 0xd3bfb429 , 0x2ee12ba9 , 0x723fb402 , 0x2ffa1bb6 ] )
+  , Mont::unsafe_make( [ 0x63e0560f , 0x1c4b74d3 , 0x68abfadf , 0x05eb6ea8 , 0xe36bbe0c , 0xe49e53b0 , 0x751da239 , 0x2cbc25c8 ] )
+  , Mont::unsafe_make( [ 0x084fbea7 , 0xdbf3b482 , 0xa2d9eaaf , 0x8f4074f0 , 0xbbeecd14 , 0x2fdcdb78 , 0x741b3d0e , 0x1f7d348c ] )
+  , Mont::unsafe_make( [ 0x5ea6e40c , 0x1ce7a4fd , 0x65cc7b74 , 0xc4c3f25f , 0xef0f73f3 , 0x87f60470 , 0xf96dd5b7 , 0x0c970570 ] )
+  , Mont::unsafe_make( [ 0x3ecbdf98 , 0x4d34d0bf , 0x5ac814d3 , 0x769a926b , 0x9a238939 , 0xfb0f3cac , 0x6cd7156a , 0x27e77b70 ] )
+  , Mont::unsafe_make( [ 0x88439f98 , 0x30cc4ab7 , 0x121ad669 , 0x4c5c6051 , 0x99c292ac , 0x51a06474 , 0x237b669c , 0x155b2568 ] )
+  , Mont::unsafe_make( [ 0x77b731ae , 0x44943dec , 0xf0251b1c , 0xb79e995b , 0xd1d89e6a , 0xd146bfee , 0xc6c95083 , 0x2b7f2b97 ] )
+  , Mont::unsafe_make( [ 0xb2fb77c2 , 0x11f2fd54 , 0xeca71604 , 0x116b59af , 0x0975c919 , 0xe1a01ddc , 0x965b7f4b , 0x2538ae58 ] )
+  , Mont::unsafe_make( [ 0x8c9002a2 , 0x1ec30c29 , 0x5cc2c9a5 , 0x7692a969 , 0xfbdbf039 , 0x11615ed6 , 0x4a4d11a8 , 0x2fdd1484 ] )
+  , Mont::unsafe_make( [ 0xaec70635 , 0x35d50e66 , 0xd85b58e3 , 0x9822bd82 , 0x3b73a1e9 , 0xc28c5767 , 0x2f9d75c3 , 0x1c3756c5 ] )
+  , Mont::unsafe_make( [ 0x5dcae828 , 0xd688dc3e , 0xcc68647f , 0x51d5b1e6 , 0xcc2dc09d , 0x590dca48 , 0x34ad17af , 0x1fa91e57 ] )
+  , Mont::unsafe_make( [ 0xe93387f2 , 0x6fc80bed , 0x6482ef57 , 0xfa4bca77 , 0xca4954ac , 0x9b48536a , 0xab443dfb , 0x2e30caf9 ] )
+  , Mont::unsafe_make( [ 0xa721fc76 , 0x755f8f18 , 0xb3b1db00 , 0x0ffeac15 , 0x57094081 , 0x5a0fbffd , 0xb4315523 , 0x07a0b8a3 ] )
+  , Mont::unsafe_make( [ 0x1f1a5cbb , 0x902c67e0 , 0xc2fe98f0 , 0xa0160b7d , 0x06530492 , 0x0c5f2244 , 0x87dd1b81 , 0x2c1f9a91 ] )
+  , Mont::unsafe_make( [ 0x67a62ea8 , 0x5292c3f6 , 0x3f047fad , 0xc13d7e53 , 0xd900874f , 0xf5246246 , 0x4766fd12 , 0x22ad9851 ] )
+  , Mont::unsafe_make( [ 0x151b8a00 , 0xc7283886 , 0x9c25a541 , 0x172d58af , 0x736b159b , 0x245366b7 , 0xf28c8112 , 0x164ad84f ] )
+  , Mont::unsafe_make( [ 0xcc08bb13 , 0xb77a6fea , 0x82e1852b , 0xb13ba9ff , 0x2ed6c1b6 , 0x9ef58d36 , 0xb0c3e1f7 , 0x1ee03eff ] )
+  , Mont::unsafe_make( [ 0xe5628a24 , 0xeb424925 , 0x562f25dd , 0x03debbe7 , 0xf92a8a3d , 0x51df48f9 , 0xd9eee0b6 , 0x23f76b18 ] )
+  , Mont::unsafe_make( [ 0x3b64632e , 0xe9b4c60c , 0xa8d6d48e , 0xcf22bfe3 , 0x9d21c5fc , 0xdea487a0 , 0x4aaf0547 , 0x27e316e8 ] )
+  , Mont::unsafe_make( [ 0x74cf380e , 0xca995ec1 , 0x44113995 , 0xa4bd1411 , 0xb2bed751 , 0x9715a8d5 , 0x9d80efce , 0x2093a0d2 ] )
+  , Mont::unsafe_make( [ 0x7bf9fab4 , 0xd4bf5503 , 0xfa568bf8 , 0x4213d2e7 , 0xcc95b86b , 0xa2ed188e , 0x537735e9 , 0x19cce5e7 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x8e83f39b , 0xe1f59fb1 , 0x4a48e6f8 , 0xca1dae4d , 0xa0cd1a35 , 0x62f03423 , 0x3a3681a4 , 0x1c378741 ] )
+  , Mont::unsafe_make( [ 0x740e8049 , 0x0f469710 , 0x2108c796 , 0x2743daa7 , 0x769e4499 , 0x51085061 , 0x0c6b5846 , 0x18e0f11a ] )
+  , Mont::unsafe_make( [ 0x70321a9a , 0x39b13c6a , 0x5d10e3d5 , 0xdb28909f , 0xc6de5559 , 0x8a31f0ee , 0x5167929d , 0x0a6bc894 ] )
+  , Mont::unsafe_make( [ 0x8534675f , 0xc05359c1 , 0xcd80f942 , 0x26ba5f25 , 0x252dfa7b , 0xf42b8853 , 0xbeff87bf , 0x27a276a1 ] )
+  , Mont::unsafe_make( [ 0xd2b929d8 , 0x899079e6 , 0x486c4fc6 , 0x14e3dc77 , 0x5f816f86 , 0x3d6fd1aa , 0xe9ea277e , 0x013d4c92 ] )
+  , Mont::unsafe_make( [ 0x5bb737c6 , 0xfa698d5f , 0xe43ee9fb , 0x7f35dd42 , 0x823ec22b , 0xe2edf1aa , 0x8af9c052 , 0x2f0b0365 ] )
+  , Mont::unsafe_make( [ 0xf5a2648a , 0x35fafe3a , 0x5a7f90b8 , 0x8ebf412b , 0xf9663952 , 0xa78b2321 , 0x45feb4c7 , 0x12d8479c ] )
+  , Mont::unsafe_make( [ 0x72e12814 , 0x8240cdca , 0x9e20a25d , 0x50cffb23 , 0xdde114d8 , 0x57334923 , 0x1efc392c , 0x0989ec63 ] )
+  , Mont::unsafe_make( [ 0x62061e29 , 0x5c6dd2fc , 0x0d844dc7 , 0xeefa4068 , 0xb765daf2 , 0x3503f114 , 0x7cc11bcd , 0x1cf90cb2 ] )
+  , Mont::unsafe_make( [ 0x84f7302d , 0x2ad5cdd8 , 0xd0e416c1 , 0x366bbc1d , 0x7dbbf7be , 0x558b8b94 , 0x27a7f85c , 0x21f2b810 ] )
+  , Mont::unsafe_make( [ 0xb82fdf23 , 0xf1a9c98c , 0x812a4ca4 , 0x1ecb71f5 , 0x7a448e2e , 0xca771e9f , 0xfb3b1ed1 , 0x284877d9 ] )
+  , Mont::unsafe_make( [ 0x77703b09 , 0x8f1d0ed4 , 0xfedfde6e , 0x309e5c35 , 0x0e977666 , 0xff2811b7 , 0xadadbb90 , 0x1636ae73 ] )
+  , Mont::unsafe_make( [ 0x17ee78a5 , 0xb8e5be29 , 0xd8e629fa , 0xe52de0ab , 0x7f0c7390 , 0x45758ba0 , 0xbaee32c0 , 0x2e8f7686 ] )
+  , Mont::unsafe_make( [ 0x237ac929 , 0x61934d55 , 0x32c00b3f , 0xe26badbb , 0x6f254ad1 , 0x4bbc0a40 , 0x35d7de10 , 0x0cf52f7a ] )
+  , Mont::unsafe_make( [ 0xe97a62af , 0x226b3ff0 , 0x97934cd3 , 0x7dc65120 , 0x86b2e305 , 0x5d5029d4 , 0x369b34ed , 0x1af991bf ] )
+  , Mont::unsafe_make( [ 0x0778bf32 , 0x6b309c40 , 0x88be40a4 , 0x804e1a37 , 0x930a67e8 , 0x22fb5f84 , 0x7b6a5e8e , 0x0850cd3d ] )
+  , Mont::unsafe_make( [ 0x20c3832d , 0x69058f3e , 0x2b76a807 , 0x07bca052 , 0x2c327908 , 0x100eef56 , 0x10c3329e , 0x1c1f089a ] )
+  , Mont::unsafe_make( [ 0xd7457229 , 0xa213c796 , 0x9f7a534b , 0x5b31e098 , 0x0d9f8c93 , 0x5fec5634 , 0x92a4bffb , 0x2536434b ] )
+  , Mont::unsafe_make( [ 0x197a9e85 , 0x050e0dc1 , 0xf9a02ac5 , 0x13c1b42c , 0x1f238224 , 0x21ec4e9c , 0x35a43a18 , 0x1795704e ] )
+  , Mont::unsafe_make( [ 0x98af335d , 0x9ccca2fc , 0x7b250afd , 0xc80f7467 , 0x08b61935 , 0xd0103f51 , 0x2eecac00 , 0x0d353b2c ] )
+  , Mont::unsafe_make( [ 0x4092a07f , 0x5478dc6e , 0x334d284d , 0xf5aae0fc , 0x916c5534 , 0xd8c0bc5a , 0xcc128c5a , 0x14e6b994 ] )
+  , Mont::unsafe_make( [ 0x2ae568cd , 0x42d58510 , 0x57ed4594 , 0x0ec07c0a , 0x4fca2f62 , 0xf4737fa5 , 0xfbfaff19 , 0x2d8a159d ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xaf16d6cb , 0x218990d9 , 0x38087957 , 0xafb09ca9 , 0x95fc6309 , 0x4f001856 , 0x56dae7fa , 0x280cdbbe ] )
+  , Mont::unsafe_make( [ 0xbb0609ee , 0x2ee662d7 , 0x6df949ce , 0x0431ea9a , 0x59df2785 , 0xfabccb43 , 0x44914931 , 0x1466a063 ] )
+  , Mont::unsafe_make( [ 0x0f3f1fc7 , 0x707819e3 , 0x7dce65d9 , 0xa7be7ade , 0x7baadcc4 , 0xf001408a , 0x5c591218 , 0x081787fc ] )
+  , Mont::unsafe_make( [ 0xed1aae25 , 0xc29dbd74 , 0x19390ef2 , 0x11b14223 , 0xd39e492c , 0xac8dc344 , 0x8dca1488 , 0x0012624f ] )
+  , Mont::unsafe_make( [ 0x46d78978 , 0x500703aa , 0xf4fe7f9d , 0x0d6abed1 , 0x4428640f , 0x6e7cc6c5 , 0x188d7f99 , 0x2c412389 ] )
+  , Mont::unsafe_make( [ 0xe26f336b , 0xda3f82b9 , 0x3f4e6e68 , 0x8f9938b9 , 0x8e67ec1f , 0xf9961a32 , 0x0fc9fcbd , 0x0b988990 ] )
+  , Mont::unsafe_make( [ 0xe17133cc , 0x2370c3ca , 0xabef6471 , 0x96986604 , 0xccfafac8 , 0x7528434f , 0xa3b005b0 , 0x1a70fcbc ] )
+  , Mont::unsafe_make( [ 0xaa66b2ae , 0x663ddcc9 , 0x68d13606 , 0x63018cff , 0x84870507 , 0x2965c240 , 0x17dfb246 , 0x1e1f1451 ] )
+  , Mont::unsafe_make( [ 0xc6d5023f , 0x76c3a60c , 0x8b0497ff , 0xf61b2bc9 , 0x3cec0500 , 0x897b3cf7 , 0xf39f258f , 0x1679564e ] )
+  , Mont::unsafe_make( [ 0x9db028f0 , 0x4b8cbf5e , 0x902a13c2 , 0x4dfe656a , 0xb8c7af5c , 0x08173341 , 0xe32bc4ef , 0x2a00beb4 ] )
+  , Mont::unsafe_make( [ 0x3dd6c21e , 0x6550c9d1 , 0xf41bd3f7 , 0xc09394da , 0xa08367a4 , 0x2603e810 , 0x5d22e67e , 0x2d7d53c5 ] )
+  , Mont::unsafe_make( [ 0x5d1f983a , 0xf8b76a16 , 0xc9e603c1 , 0x6ce9d6d4 , 0xf9a8b740 , 0x89fbe217 , 0x46e2bd43 , 0x1b4bf3a0 ] )
+  , Mont::unsafe_make( [ 0x090da5e0 , 0x7949a87d , 0x63f3799d , 0x30167ee0 , 0x52d2cf96 , 0x582ccdd9 , 0x1a4922e6 , 0x2bd61fe6 ] )
+  , Mont::unsafe_make( [ 0xa635fcb4 , 0xb2473503 , 0xcf6c47d3 , 0x95e61184 , 0x9d5def5f , 0xe6d3a0ca , 0xec995466 , 0x1ef07490 ] )
+  , Mont::unsafe_make( [ 0xb91b62e1 , 0xcf5bd5e6 , 0x256390e8 , 0x18a4c12c , 0x34df55fd , 0x710f9eb8 , 0x61456963 , 0x1de2262a ] )
+  , Mont::unsafe_make( [ 0xa09b3484 , 0x99406f6c , 0x20443ada , 0x64bf42e4 , 0xa5a5ceb2 , 0x8d6493b2 , 0x9a59b34c , 0x25a96ed5 ] )
+  , Mont::unsafe_make( [ 0xfb35f871 , 0xd6e1bb48 , 0x2dc84be0 , 0x9090e504 , 0x1e7f2e8e , 0xd1e1a514 , 0xe2459b14 , 0x1565731e ] )
+  , Mont::unsafe_make( [ 0x301ecc95 , 0xf7c9b0a4 , 0xfd994e74 , 0xcb602620 , 0xe1d35868 , 0x1bbb2d10 , 0xe81a84a4 , 0x10179631 ] )
+  , Mont::unsafe_make( [ 0x44154299 , 0x781597ed , 0xb1dd06ba , 0xec7c957d , 0xadc1e49d , 0xd7b6dc0d , 0xa6122b5d , 0x045a0eb4 ] )
+  , Mont::unsafe_make( [ 0x7ccf300c , 0xd4e3e3d8 , 0x89522479 , 0x5dfe6e47 , 0xe625e183 , 0x211e0067 , 0x10dd79c5 , 0x1b1099da ] )
+  , Mont::unsafe_make( [ 0xaad959fe , 0xe4c3eeb8 , 0x700b9f35 , 0x10a9b64e , 0x656a0db2 , 0xeb7df57f , 0x61ab9700 , 0x115de8db ] )
+  , Mont::unsafe_make( [ 0x089ab5f6 , 0xda7dc473 , 0xabd4b3d4 , 0x5c05d3f8 , 0xe1436192 , 0x611fd04a , 0xc50b8197 , 0x2b2e49b3 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xcdf2d751 , 0x225ad1fd , 0x8ce95d12 , 0xcb8b4147 , 0x7d52e1b9 , 0xf6077a18 , 0xf5926812 , 0x00c21b4c ] )
+  , Mont::unsafe_make( [ 0xa3fa01b6 , 0x64aa9a43 , 0x64c2b9f9 , 0x50065cac , 0x8cb70a78 , 0x76bce3ff , 0x2b2581f7 , 0x16ffaac0 ] )
+  , Mont::unsafe_make( [ 0xce93296e , 0x3ee9c0e3 , 0x071eaedf , 0x4a5c044b , 0x903599f0 , 0xf1b50137 , 0x82fbe0d6 , 0x062ec761 ] )
+  , Mont::unsafe_make( [ 0xcc9f0a5b , 0x7ccd7459 , 0x4e96bb5e , 0x0015980f , 0x257b39b8 , 0x945fa372 , 0x6b7d31e7 , 0x1c75683e ] )
+  , Mont::unsafe_make( [ 0xa6098931 , 0x65057fb6 , 0xa7830a16 , 0x5c58173a , 0x817d0c92 , 0xe99909de , 0x25578aa6 , 0x0000186a ] )
+  , Mont::unsafe_make( [ 0x00711bd8 , 0x47955e4a , 0x05522dd9 , 0x28806c27 , 0xc9e36301 , 0xd7357578 , 0x35dac317 , 0x0a705c86 ] )
+  , Mont::unsafe_make( [ 0x6f37dd91 , 0xe20d9178 , 0x988f0040 , 0xdb534ace , 0xba59dbfd , 0x5feba34a , 0x33da39fe , 0x0bac9cfc ] )
+  , Mont::unsafe_make( [ 0x5c281cb8 , 0xa9488f3c , 0x3780452f , 0xa78d5fc3 , 0xc669bbe7 , 0xfbfda6da , 0xca91fee0 , 0x1cceddb7 ] )
+  , Mont::unsafe_make( [ 0x946d30a4 , 0xa8f0a412 , 0x0be49304 , 0xbc61a3c9 , 0xb8fcc842 , 0x9b58a63e , 0xeba8eae2 , 0x20748113 ] )
+  , Mont::unsafe_make( [ 0x1ea1b62a , 0xbef5e4af , 0xe8c8172a , 0x3a8911ad , 0x3a8da526 , 0xc6d63cfe , 0x2f493c3b , 0x2688cace ] )
+  , Mont::unsafe_make( [ 0x7d5854b2 , 0x396c560f , 0x8a71e75a , 0xd2cc951c , 0x21924887 , 0x460a33c2 , 0xd28881e3 , 0x25b75fae ] )
+  , Mont::unsafe_make( [ 0x94244d33 , 0x32ed4b15 , 0x91a4f78c , 0xcdf6012a , 0x47636d1b , 0x203d9cec , 0xbe4daf09 , 0x28b4c0d2 ] )
+  , Mont::unsafe_make( [ 0xf49a6e01 , 0x4e5cc924 , 0x937461ae , 0x0221705c , 0xe786bf24 , 0x82f289e9 , 0xfdb02a24 , 0x24e9b0ce ] )
+  , Mont::unsafe_make( [ 0x37ceb0c7 , 0x651721c5 , 0x69128b8f , 0x4e7ba956 , 0x1cd705d4 , 0xf61eeeb6 , 0xdbe54920 , 0x27dcc006 ] )
+  , Mont::unsafe_make( [ 0x59dbf962 , 0xa077a9a6 , 0x90b56084 , 0x2c1f2d83 , 0x16535ec3 , 0x762c4a64 , 0x266d313d , 0x197e50d5 ] )
+  , Mont::unsafe_make( [ 0x74d2d6d7 , 0x749698f4 , 0x8a2a4f8f , 0x9eb473d3 , 0xe9b0a529 , 0x0b6722c0 , 0x7615a349 , 0x09c3cd83 ] )
+  , Mont::unsafe_make( [ 0xa8946527 , 0x3ed6e388 , 0xbfb0375b , 0x2cf2a585 , 0xdc3fe23d , 0x0bbbf5ec , 0xd38980ac , 0x27dc895d ] )
+  , Mont::unsafe_make( [ 0xd174dd30 , 0x13522790 , 0xf0db2b24 , 0xf6a7ce25 , 0x521883d2 , 0x9c41be7d , 0x4cbc9e30 , 0x0ee2f51b ] )
+  , Mont::unsafe_make( [ 0x70c0e5ad , 0xd0508716 , 0xfb438512 , 0x2aafd49b , 0x5e1e34e0 , 0xc1c2010e , 0xb8977cd4 , 0x1104fa37 ] )
+  , Mont::unsafe_make( [ 0x62d23311 , 0x3766579e , 0x260d0b43 , 0x71230e0b , 0x57673bf7 , 0x5b935b4a , 0xd5ca497d , 0x26e421fb ] )
+  , Mont::unsafe_make( [ 0x20ed3999 , 0xd70e8dee , 0x60469be5 , 0x99e2258e , 0x52935878 , 0x427e9e0c , 0x115ee913 , 0x1dd1bea8 ] )
+  , Mont::unsafe_make( [ 0xdce518c7 , 0xb30702f7 , 0x832db5a0 , 0xce67ac83 , 0xb9cfae09 , 0x584fcbb7 , 0xb487ea1f , 0x0b426a81 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x208de478 , 0xc8cbc895 , 0x9afa5e44 , 0xc2b0be02 , 0x01603415 , 0x9bc154d3 , 0xd1fa3046 , 0x144bc230 ] )
+  , Mont::unsafe_make( [ 0x0631b169 , 0xad43a022 , 0x569e60eb , 0xcf7fb4bc , 0x5533e4c0 , 0x3ecfca80 , 0x2044ef5f , 0x222b785f ] )
+  , Mont::unsafe_make( [ 0x68cbb796 , 0x1bdc2647 , 0x009e9e66 , 0x2665530a , 0x9616a298 , 0x3635002d , 0x1b3ab1f9 , 0x24a33590 ] )
+  , Mont::unsafe_make( [ 0x69895b5a , 0xeb207a4a , 0x5e01e544 , 0x1fb4d71f , 0xb52706d6 , 0x197e97db , 0xfc6985e6 , 0x0049582c ] )
+  , Mont::unsafe_make( [ 0xc4002e71 , 0xdffc2b23 , 0xf2a3ab56 , 0xf2610a16 , 0xaaa36fe1 , 0xff8cc52e , 0x075013ff , 0x2ec2c4be ] )
+  , Mont::unsafe_make( [ 0x1c9e0678 , 0x83f87fee , 0x8daac758 , 0xbfa14582 , 0xf08fd195 , 0xad652407 , 0x9c947837 , 0x106c9650 ] )
+  , Mont::unsafe_make( [ 0x1bf13490 , 0x4d39f584 , 0x775fa154 , 0x1f9ad287 , 0xc7828d02 , 0xd23966bc , 0x910ef943 , 0x065dbaa3 ] )
+  , Mont::unsafe_make( [ 0x6d58b627 , 0x4e6389d8 , 0xff34d255 , 0x51f57bf2 , 0xb2d9e6f9 , 0x6e465f97 , 0x474fdaa3 , 0x2744e12e ] )
+  , Mont::unsafe_make( [ 0x4a90038c , 0xe2a92914 , 0x40476fe0 , 0x7462f538 , 0xe2e9a5f3 , 0x3c88a410 , 0x7c9d6269 , 0x083945e1 ] )
+  , Mont::unsafe_make( [ 0x8e7e80dc , 0x6a4af4a9 , 0x0cdab592 , 0x9e80ca04 , 0x910b4c62 , 0xf13d0be3 , 0x1cb16fbe , 0x044fffc1 ] )
+  , Mont::unsafe_make( [ 0xfe175b59 , 0x36751955 , 0x1ae95e20 , 0xaf2f59ab , 0xbde8ef95 , 0x08db810c , 0xb7744300 , 0x05d33a44 ] )
+  , Mont::unsafe_make( [ 0x7e6e1975 , 0x385f10be , 0xc0baeeb9 , 0x4c03e08c , 0xd4987388 , 0x72fee0c6 , 0xb574c43f , 0x06b182fc ] )
+  , Mont::unsafe_make( [ 0x608f67c9 , 0xa89dbdd3 , 0x8afc38dd , 0xdcb31698 , 0xe3a57ecb , 0x2a87ebf9 , 0xb7619515 , 0x23f6677d ] )
+  , Mont::unsafe_make( [ 0xd8161fa0 , 0x4af860bc , 0xc752bdea , 0x9bc1d31f , 0x06ead8aa , 0x760ac984 , 0xfd00fd4d , 0x3003a44a ] )
+  , Mont::unsafe_make( [ 0xe8ce9880 , 0xd27f32b7 , 0x23cb758b , 0x77c111b4 , 0x9ee8aeb8 , 0x3e9f269d , 0x27ff8697 , 0x1c8925e0 ] )
+  , Mont::unsafe_make( [ 0x904f7640 , 0xb18e46c0 , 0x53b1a758 , 0x6954315c , 0x0d34ce84 , 0x7b56cb08 , 0xb7dba307 , 0x0e2aec6a ] )
+  , Mont::unsafe_make( [ 0xa38f199d , 0x0b4230fb , 0x155b0c57 , 0xdcc5bce8 , 0xc61de451 , 0xe075ef76 , 0xd2153691 , 0x253b69f6 ] )
+  , Mont::unsafe_make( [ 0xd44d3b18 , 0x7088d520 , 0xeda1a5ee , 0xf76ef402 , 0xacf13823 , 0x8883c14c , 0xfc4ec179 , 0x22603368 ] )
+  , Mont::unsafe_make( [ 0x2c89236b , 0x2165a5c6 , 0xe3ad3ad0 , 0x3ffa1115 , 0x8605bdef , 0x4664ba13 , 0x494437ef , 0x226e9e14 ] )
+  , Mont::unsafe_make( [ 0x0a411819 , 0x5520a95a , 0x3dae9191 , 0x33f60657 , 0x90f33b86 , 0x6b3c123b , 0xfc9791fe , 0x21294f87 ] )
+  , Mont::unsafe_make( [ 0x87ce4645 , 0xafb773fc , 0x63bf98b5 , 0xdfdd77a6 , 0x8e9e4f04 , 0x76b96ce4 , 0x0b8f38cc , 0x0c69af24 ] )
+  , Mont::unsafe_make( [ 0xbda5becf , 0x070018d5 , 0x68300732 , 0x497a4b96 , 0xf81a981b , 0xa1b4ea13 , 0xafb257e8 , 0x243dcbf0 ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xe869c4eb , 0x7773f98c , 0xb481a8d3 , 0xdbd091af , 0x7bccf931 , 0x7c82c364 , 0x784f3f6c , 0x297769a5 ] )
+  , Mont::unsafe_make( [ 0x9f00fcaa , 0xa3b88d20 , 0x8f94e272 , 0x0689a7a4 , 0x5aa980f6 , 0x737c30df , 0x4e4fcd8e , 0x01936cf9 ] )
+  , Mont::unsafe_make( [ 0x300a9926 , 0xab61681d , 0x34bbf93b , 0x151c8cbf , 0xbda1f748 , 0x60ff9d8e , 0xb814df6e , 0x0012ab19 ] )
+  , Mont::unsafe_make( [ 0x6f6462ed , 0x6f3c74bf , 0xbc571e2a , 0xdc75a5b6 , 0x9abf5c1b , 0x535a89cc , 0x6cff620c , 0x08457a1f ] )
+  , Mont::unsafe_make( [ 0x3e8b839c , 0xfe297294 , 0x774f4e43 , 0x9178bc2e , 0x58d84b81 , 0x9824df4a , 0x04c99875 , 0x0a3f8eb8 ] )
+  , Mont::unsafe_make( [ 0x086ccd88 , 0xd0328164 , 0xc94b4db2 , 0xca60edca , 0x51276bb8 , 0xd9eaf9c8 , 0x71fd1c3a , 0x11069184 ] )
+  , Mont::unsafe_make( [ 0x8fe5db82 , 0x43ed8a97 , 0x8370e741 , 0x561f9783 , 0x8539cd01 , 0x2e8e2acc , 0xbd8a2a31 , 0x1afdba88 ] )
+  , Mont::unsafe_make( [ 0x37e7562f , 0xb98b2bfd , 0xe197e264 , 0xfb064f64 , 0xc792070b , 0x8c81bd94 , 0xa52a5914 , 0x10832441 ] )
+  , Mont::unsafe_make( [ 0xd567617a , 0x3f1ac74e , 0x0dc6f23e , 0xefbf2bd3 , 0xcfa8bf74 , 0xf1d7bbf5 , 0xfea9377d , 0x23838177 ] )
+  , Mont::unsafe_make( [ 0xf6d57c7a , 0x6ac6f44f , 0x563400a7 , 0x451e8f19 , 0x09f56e14 , 0x6f5e79ee , 0x1d72c04d , 0x162065fd ] )
+  , Mont::unsafe_make( [ 0x640cd5ac , 0xbc01ca93 , 0x780a680c , 0xe94dfdfb , 0x0d75c1d3 , 0xd9c3c890 , 0xdd902fd8 , 0x00a48dc7 ] )
+  , Mont::unsafe_make( [ 0xc8d3b97c , 0xc3cb71a1 , 0xb9516fc0 , 0x95e4b990 , 0x4a7333b5 , 0xdfffcdc0 , 0x0c66302c , 0x1168dcbd ] )
+  , Mont::unsafe_make( [ 0x2b3f558f , 0x7d5c1858 , 0xc6294474 , 0x8471fca1 , 0xc3cf2898 , 0x031480ef , 0x7547f2d0 , 0x276c7ca0 ] )
+  , Mont::unsafe_make( [ 0xdc7a13e1 , 0xb28b6e05 , 0x1b2579ea , 0x0d1c7da0 , 0x190a06a5 , 0x1eeae4b9 , 0x34202461 , 0x16772151 ] )
+  , Mont::unsafe_make( [ 0xe3a72299 , 0xf585ab77 , 0xf1715302 , 0x43df4115 , 0x371a183a , 0x79afc0e3 , 0x1aeb3b35 , 0x16fc685e ] )
+  , Mont::unsafe_make( [ 0x3d4fca8c , 0x8b25852f , 0x16335f92 , 0xa2036b25 , 0xc8b0aef4 , 0xb109c5cc , 0x3adad7c3 , 0x1303041f ] )
+  , Mont::unsafe_make( [ 0x0cbabf1a , 0x53248095 , 0x2b358906 , 0xa913ff38 , 0xbfddf537 , 0x7b7e870a , 0x9c5e33ef , 0x1aa94beb ] )
+  , Mont::unsafe_make( [ 0xaceb245e , 0x58ff39fb , 0x70dae61d , 0x145985c6 , 0xcde2039e , 0x364a84b8 , 0x795d353e , 0x1727b8bd ] )
+  , Mont::unsafe_make( [ 0xac8570a9 , 0xfb458bd5 , 0x4274f11b , 0x56d3e971 , 0xb0043819 , 0x973973ca , 0xf87d3059 , 0x2afcf298 ] )
+  , Mont::unsafe_make( [ 0x6421f610 , 0x3470725a , 0xfaa8c70e , 0x014b9ef4 , 0x0d1d1a47 , 0x5b8e9ed9 , 0xfae7f802 , 0x10d96769 ] )
+  , Mont::unsafe_make( [ 0xfacf1961 , 0xd1db651f , 0x6c2d4bc5 , 0x4116732c , 0xd48242d1 , 0x85cbd9a2 , 0x9cbba664 , 0x0b852b4e ] )
+  , Mont::unsafe_make( [ 0xdb873802 , 0xa8be354e , 0x28df00de , 0x7d0d9514 , 0x31a1b774 , 0xda90b119 , 0x880068f8 , 0x0391c9cc ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0x10384fbe , 0x8e5d4989 , 0x13f7a02f , 0x4174681c , 0x9102c9ec , 0x9e35abe8 , 0x5db522af , 0x03d82d72 ] )
+  , Mont::unsafe_make( [ 0xc7902b2e , 0x13307f46 , 0xef9d6d26 , 0x9fac2556 , 0xa3fc8141 , 0x72d3e56f , 0xf26f7ac0 , 0x2b60b7ee ] )
+  , Mont::unsafe_make( [ 0x505c452e , 0x4002c4fa , 0x068ce776 , 0x94a1420a , 0xf6f9bf79 , 0x45513fe3 , 0xa46e76d9 , 0x2dbb5161 ] )
+  , Mont::unsafe_make( [ 0xc6850f10 , 0xff25c8cc , 0xe4af8baf , 0x3450eb28 , 0xe150a179 , 0x08c36737 , 0xee5c5772 , 0x23e2af1a ] )
+  , Mont::unsafe_make( [ 0x80fc3d51 , 0xb6d4706b , 0xc050cf6e , 0xf5c1594d , 0x589ea81d , 0x3e9ca585 , 0x40920e7f , 0x28e377ad ] )
+  , Mont::unsafe_make( [ 0x37956c17 , 0x5d73186d , 0x5d638372 , 0xc77f1c43 , 0xbe12e7a1 , 0x6d3d2827 , 0xa0304ec9 , 0x2ac1d6e7 ] )
+  , Mont::unsafe_make( [ 0x6e14aaea , 0x6ce21190 , 0x818b0bf0 , 0x29b53c14 , 0x3c3f1a61 , 0x7b4e9115 , 0x7559a0a4 , 0x28bd8654 ] )
+  , Mont::unsafe_make( [ 0xe5d0cb95 , 0x5888199a , 0x8d11ef2a , 0xdc9f0894 , 0xca0d648e , 0x54527624 , 0x8d86176b , 0x178dd97b ] )
+  , Mont::unsafe_make( [ 0x5db59b06 , 0x8dacd49a , 0x193456b4 , 0x81c4417f , 0x6fc23f6b , 0xa5c82c17 , 0xfce95109 , 0x2d2cf025 ] )
+  , Mont::unsafe_make( [ 0x4430405d , 0x21c243a2 , 0x5c88e010 , 0xf73d27e1 , 0x739b9ab4 , 0x1d63863f , 0x0fd32b8b , 0x0e6f4675 ] )
+  , Mont::unsafe_make( [ 0x3547f341 , 0x7b4a3b62 , 0xec7c8a10 , 0x4a502418 , 0xcfa82f18 , 0x6df2e03d , 0x01e959a1 , 0x27ae5a79 ] )
+  , Mont::unsafe_make( [ 0x6b8c3cf8 , 0xdd5cdf73 , 0xafd1fa59 , 0x85324809 , 0x3279edff , 0x93ac0fbd , 0xfeb79470 , 0x162703be ] )
+  , Mont::unsafe_make( [ 0xe93f22fc , 0xa3757d64 , 0x1ba721f0 , 0x298408fc , 0x55aed5f8 , 0x0e37d6d9 , 0x85cab539 , 0x2cd0b1fb ] )
+  , Mont::unsafe_make( [ 0x2ff693d3 , 0x67c21ce8 , 0x75ffea8b , 0xdccc46b6 , 0x681cb9b9 , 0x3b2edf65 , 0x2ae9ebc5 , 0x15f32760 ] )
+  , Mont::unsafe_make( [ 0x92a352a8 , 0xc225a32a , 0xf7c07b12 , 0x5ae84434 , 0x8619dac5 , 0xe9569e99 , 0xefccb5e5 , 0x2b002b43 ] )
+  , Mont::unsafe_make( [ 0x6b4f8527 , 0x9ab2339b , 0x1ac73c5c , 0x0fc1ea03 , 0xdf0bff87 , 0xdb3c33cd , 0xa51d28da , 0x1fbf3f65 ] )
+  , Mont::unsafe_make( [ 0x24ff499d , 0xd4e58c53 , 0x2d22d0e4 , 0x700fff70 , 0x983feec1 , 0x2e6b7638 , 0x9caf6e8f , 0x09daabe6 ] )
+  , Mont::unsafe_make( [ 0x4045a048 , 0xdf319afa , 0xf09d0852 , 0xe416e106 , 0xc6622754 , 0xa9638a80 , 0x56c14031 , 0x0cf5a9c0 ] )
+  , Mont::unsafe_make( [ 0xb94b2710 , 0xc45a51e9 , 0xb3cbf46f , 0x7b6749c4 , 0xd9212f28 , 0xede3ca9e , 0x01b54139 , 0x1a30dc60 ] )
+  , Mont::unsafe_make( [ 0xe9549e8e , 0xc266d4ba , 0xbf09fff8 , 0xd1370941 , 0xf3484eb6 , 0x666fecf9 , 0x1f19b90a , 0x2cdbfe32 ] )
+  , Mont::unsafe_make( [ 0xca6f3253 , 0x2a2d3225 , 0x8bbf4a46 , 0x5bbdeeb8 , 0x982a1baa , 0xc182e5f9 , 0xf83c5834 , 0x28d021e1 ] )
+  , Mont::unsafe_make( [ 0x22e8b5d1 , 0x1781200d , 0x4d3c9bde , 0x67c8341d , 0xda773133 , 0x41674435 , 0x08581e59 , 0x2dde0cdb ] )
+  , Mont::unsafe_make( [ 0x8bc62723 , 0x7be15e9c , 0x6f6dfe8c , 0x96ed328f , 0xe0d04cdf , 0xaa1c1d5f , 0x24afa03f , 0x1def002d ] )
+  , Mont::unsafe_make( [ 0xce0e9e37 , 0x3da682be , 0x56ab90be , 0x59a4cfaf , 0x2231d58c , 0xc5a435fb , 0xe0fa394d , 0x214195f5 ] )
+  , Mont::unsafe_make( [ 0x179d8ee0 , 0x38b1c448 , 0x4b093e4f , 0x074ad9dc , 0xe097e5b7 , 0xc98f03bf , 0xa99f276e , 0x2a33a83d ] )
+  , Mont::unsafe_make( [ 0xde2eec78 , 0xdf88e9f5 , 0x0a61699c , 0x20cdf3b9 , 0xa3b9a19b , 0x87b2c0dc , 0xd2aac7c8 , 0x0a9eadf5 ] )
+  , Mont::unsafe_make( [ 0x04d67576 , 0x6c023690 , 0x0cf77663 , 0x4efe13be , 0xe36191ab , 0x64219235 , 0x8ab91d3a , 0x08fd23fd ] )
+  , Mont::unsafe_make( [ 0x64d1399c , 0x82ce30eb , 0x594ce254 , 0x8ba99861 , 0xad9cfd13 , 0x62dec0ce , 0xc9b502fb , 0x198158f6 ] )
+  , Mont::unsafe_make( [ 0x894ee7cd , 0xde2bcb28 , 0xce1589d4 , 0x66846cfd , 0x3835d487 , 0x835b3160 , 0xb5d671a5 , 0x189b1dc9 ] )
+  , Mont::unsafe_make( [ 0xf9d14e91 , 0x4d34b104 , 0x51a9e6f3 , 0xbd64d81f , 0x28dfeaa3 , 0x6495ee39 , 0x71dc87ad , 0x0d238db7 ] )
+  , Mont::unsafe_make( [ 0x729f751a , 0x918b9ed2 , 0xfca51324 , 0x0a4950f9 , 0xd0c6d9a8 , 0x10ce6464 , 0xd093bfa3 , 0x031f29ed ] )
+  , Mont::unsafe_make( [ 0x83aed0a7 , 0x802e9878 , 0xdc7b71e0 , 0x44cca0f1 , 0x6e876065 , 0xb89cd846 , 0xbacf4095 , 0x000947b1 ] )
+  , Mont::unsafe_make( [ 0xdb35cb4c , 0x93a946a9 , 0x89ed377f , 0x801b744d , 0xfd236f31 , 0x8d0e334b , 0x15a7fd5b , 0x2de62c04 ] )
+  , Mont::unsafe_make( [ 0x1803f8b0 , 0x23a6f0a7 , 0xd9e755e4 , 0xe88ea983 , 0xbbab17d9 , 0xa048ffb7 , 0xd2809512 , 0x25ad03b5 ] )
+  , Mont::unsafe_make( [ 0xde84f76a , 0x75542d43 , 0xc48f6126 , 0x26dff199 , 0x67dee28e , 0x4fe8e1e3 , 0x05e643eb , 0x2b1be9b5 ] )
+  , Mont::unsafe_make( [ 0x4b9cd1be , 0xc9dcf8e6 , 0xbb5dde16 , 0xd570b765 , 0x10d783a1 , 0x07ecbd03 , 0x8f766d17 , 0x1f985854 ] )
+  , Mont::unsafe_make( [ 0xf2e49cf6 , 0x273cb309 , 0x498dc284 , 0x0278160b , 0x9f1c04cd , 0xdf91ae37 , 0xf9b22fba , 0x17488694 ] )
+  , Mont::unsafe_make( [ 0xf421756a , 0xbe584c0d , 0x8ee9c150 , 0x549f813d , 0xf62638f1 , 0x151c8a36 , 0x75c55a52 , 0x165d60e6 ] )
+  , Mont::unsafe_make( [ 0x5f7ee48d , 0x59bfecd4 , 0x6c7212eb , 0xddcd3a15 , 0x59e25e7c , 0x3504ff52 , 0x036ef6e3 , 0x1ffb6fbe ] )
+  , Mont::unsafe_make( [ 0xbee933df , 0x85673300 , 0xef37bbbe , 0xc95fbc10 , 0x0cccc415 , 0x79035de6 , 0x476bc15a , 0x0fcea4af ] )
+  , Mont::unsafe_make( [ 0x5978dcf6 , 0xd6500337 , 0x5b987783 , 0x84ffca04 , 0x78d64ec2 , 0x1cd76824 , 0x16e077cc , 0x0e8c9e8e ] )
+  , Mont::unsafe_make( [ 0x375d37ab , 0x660034f2 , 0xbc8d8e00 , 0xc37c9ca8 , 0xae758fa4 , 0x03fbaca4 , 0x4c5a18ec , 0x0927245d ] )
+  , Mont::unsafe_make( [ 0x755d557c , 0x79480f68 , 0x8c8d8611 , 0xd524c362 , 0xa313d030 , 0x1136d8b8 , 0x38454f2b , 0x2127edf6 ] )
+  , Mont::unsafe_make( [ 0x2fd3ae59 , 0xc1bb7b5d , 0xb2e4f9f1 , 0xfb50f2c6 , 0x9816fe94 , 0x0a75acad , 0x4f32c9a0 , 0x1b451b03 ] )
+  , Mont::unsafe_make( [ 0xbfe8d129 , 0x6880f298 , 0x7084e50d , 0x90acc76d , 0x95c64b10 , 0xe202fd19 , 0x34bde382 , 0x16055ece ] )
+  ];
+
+//------------------------------------------------------------------------------
+
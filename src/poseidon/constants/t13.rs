@@ -0,0 +1,2164 @@
+
+// circomlib's Poseidon constants for t=13
+
+use crate::bn254::montgomery::*;
+
+
+//------------------------------------------------------------------------------
+
+// round constants (t for external, 1 for internal rounds; flattened)
+pub const CONST_C: [Mont; 169] = 
+  [ Mont::unsafe_make( [ 0xee856c88 , 0xba41adff , 0xfd71cc53 , 0x9921fe55 , 0x82a86723 , 0x9733baca , 0x2c5e06b8 , 0x0595cab6 ] )
+  , Mont::unsafe_make( [ 0xe3e6343c , 0x94db7429 , 0xceaa7739 , 0xb359eaf7 , 0xbde2339a , 0x9a6f882f , 0xdac580e6 , 0x02d76ff5 ] )
+  , Mont::unsafe_make( [ 0x5578279e , 0x2f35e3b7 , 0xd2e15ad2 , 0xde73f63f , 0xf03b9c58 , 0x9bed6bda , 0x4e2f00f1 , 0x1c8b9f2e ] )
+  , Mont::unsafe_make( [ 0x1d020efb , 0xfc13ffd4 , 0x862f27f2 , 0x7c0f089d , 0xe21208ed , 0x4a335024 , 0xecda3585 , 0x1cfdd553 ] )
+  , Mont::unsafe_make( [ 0xbca71fa4 , 0xf4890e73 , 0xd7b35b83 , 0x562264cc , 0x54c81dc1 , 0x2ae9ddae , 0x9790e80a , 0x08247345 ] )
+  , Mont::unsafe_make( [ 0x9cc9016c , 0x1d8929f9 , 0x80a5f843 , 0x9f332895 , 0x9dfdbf29 , 0x557948cf , 0x0b52d498 , 0x253a5eae ] )
+  , Mont::unsafe_make( [ 0xdfd8459c , 0xed559aea , 0x54b25cc0 , 0x1e1c9842 , 0x404bf881 , 0x6b3e49ca , 0x0b1c3c98 , 0x1ff5d55d ] )
+  , Mont::unsafe_make( [ 0x4126ff4e , 0xadd4e65d , 0x9ba3aebd , 0xa0398bf3 , 0x07c9b371 , 0xbb9f15ad , 0x22ea0477 , 0x0bd578b8 ] )
+  , Mont::unsafe_make( [ 0x0773cf0c , 0x54ceabeb , 0xb863fda5 , 0x9beb5709 , 0xe168477a , 0x0f1d34eb , 0x09722f71 , 0x0c4d110e ] )
+  , Mont::unsafe_make( [ 0x55ad3198 , 0x460042a2 , 0x81625a17 , 0x41c50d55 , 0x5f0dfa52 , 0x91a9b906 , 0x1c4c1d51 , 0x28fc27bc ] )
+  , Mont::unsafe_make( [ 0x576b5f97 , 0x396b0040 , 0xa72ed771 , 0x756a37dc , 0x561420b6 , 0x98a893ab , 0x9e1c0813 , 0x1798f591 ] )
+  , Mont::unsafe_make( [ 0xbffcb332 , 0x381d54b7 , 0x41e1711b , 0x1e38fbcc , 0xece580af , 0x2cf98a09 , 0x688cb979 , 0x17ac34e5 ] )
+  , Mont::unsafe_make( [ 0x8ada0c74 , 0x2767b222 , 0xba635dfc , 0x803515e3 , 0xde01d62d , 0x467f5529 , 0x07b8ffa6 , 0x14c2798c ] )
+  , Mont::unsafe_make( [ 0x901bb334 , 0xd2c9dcc0 , 0x3975f223 , 0xd6c2af5c , 0xd2721a61 , 0x330f28b8 , 0x60c3b9a1 , 0x08e43e06 ] )
+  , Mont::unsafe_make( [ 0x03c124af , 0x1267c7f5 , 0xdefaf167 , 0xc148ccd7 , 0xea774c13 , 0x46d0ee03 , 0xee9d7a2e , 0x19887e5a ] )
+  , Mont::unsafe_make( [ 0xb61ea719 , 0x218715b4 , 0xf9164976 , 0x015711e4 , 0xaee04960 , 0xa24c4d1f , 0x38cd04cd , 0x2d3ba199 ] )
+  , Mont::unsafe_make( [ 0xf8430bc3 , 0x7a672e1d , 0x5b498ebf , 0x212a0119 , 0xbb072027 , 0x8afa056b , 0x0b57cc9f , 0x0d185e15 ] )
+  , Mont::unsafe_make( [ 0xa8b11469 , 0x0e143334 , 0xadc7868e , 0xfc73efe1 , 0x3202b24c , 0x6a1d9d9a , 0x8e3821fc , 0x13c71dfe ] )
+  , Mont::unsafe_make( [ 0x83be7c41 , 0xd4decba2 , 0xcbdc4ed1 , 0x8fb02fa2 , 0x9fce9b41 , 0x3b38162c , 0x34273bc8 , 0x28b60357 ] )
+  , Mont::unsafe_make( [ 0xb7e01600 , 0xbbca45b1 , 0x38f2ee4b , 0x518be966 , 0x61949767 , 0xd5ae1bd9 , 0x85eb6afc , 0x078976c2 ] )
+  , Mont::unsafe_make( [ 0x9d622783 , 0xaec0c917 , 0x18bf9c20 , 0x07809fac , 0x8511ba2d , 0x2aacd8d3 , 0x5ff0a0ad , 0x248fd469 ] )
+  , Mont::unsafe_make( [ 0xf151fb5f , 0x8e0342d8 , 0xf5424696 , 0xa6619b74 , 0xe7ebcf94 , 0xfef75625 , 0x4d6d0573 , 0x2835b6e1 ] )
+  , Mont::unsafe_make( [ 0x7ab02da4 , 0xee01d537 , 0x1131e221 , 0xcdac7fd4 , 0x9ec44e2a , 0x161d1b41 , 0xc0839052 , 0x15bbe1d8 ] )
+  , Mont::unsafe_make( [ 0xcd6fd5cb , 0xa8e7c06a , 0xd6630816 , 0x8fc83075 , 0x8ad5806c , 0x1c4c3983 , 0xb40aea21 , 0x3039cd5b ] )
+  , Mont::unsafe_make( [ 0x98b2d656 , 0xb0062bc3 , 0xc237f1c3 , 0x3356b689 , 0xf6030d8d , 0xb793509a , 0xb3962aa8 , 0x0b25a8a6 ] )
+  , Mont::unsafe_make( [ 0x5ad03e5f , 0x3057dfcb , 0x282f9714 , 0x3cf4882e , 0x6f78f8bc , 0xc771485c , 0x51bcacc2 , 0x16e54a1a ] )
+  , Mont::unsafe_make( [ 0x5f9f8af4 , 0x3bd138b1 , 0x473140e0 , 0x8a192948 , 0xc171d21f , 0x51797212 , 0x32e12a81 , 0x01be5c47 ] )
+  , Mont::unsafe_make( [ 0x88692906 , 0xc7f2b58f , 0xe78a5549 , 0x2aa4801e , 0xee82d6a2 , 0x8378bc75 , 0x83597632 , 0x2aef26ec ] )
+  , Mont::unsafe_make( [ 0xe71208dd , 0xa85ba8fd , 0x29c6728b , 0xa388736c , 0x9650d2c3 , 0x5f47d672 , 0x8017ff26 , 0x1563ab38 ] )
+  , Mont::unsafe_make( [ 0xa199e8ab , 0x20aefa6d , 0xc4eca88f , 0x74094c7b , 0xb5e52060 , 0xd0ed1a03 , 0x769cc236 , 0x0f494cdf ] )
+  , Mont::unsafe_make( [ 0x695f3aac , 0x44169ef0 , 0x69f68b93 , 0xb469cc63 , 0x9120d203 , 0xd07f3521 , 0xf1172753 , 0x17be7cdd ] )
+  , Mont::unsafe_make( [ 0xba4d4cb9 , 0x6700323d , 0x21684f8b , 0xc357fbed , 0x21299828 , 0xdc3cf67e , 0xda46bc00 , 0x2c6dacfc ] )
+  , Mont::unsafe_make( [ 0x157adb1c , 0xcad17748 , 0x1a1a04a9 , 0x63bad190 , 0xb5da7ce6 , 0x5bab5ee8 , 0xdf464740 , 0x0666d126 ] )
+  , Mont::unsafe_make( [ 0xecac4c79 , 0x83ca47ed , 0x31412999 , 0xd9d22100 , 0xa4ce85ad , 0xeb45d0b8 , 0x17fb4335 , 0x21ad2705 ] )
+  , Mont::unsafe_make( [ 0x8de451db , 0x55f58e51 , 0x673f696a , 0x29741890 , 0x60684c89 , 0x5a2805a7 , 0xfee726e4 , 0x2872ca05 ] )
+  , Mont::unsafe_make( [ 0xe704208e , 0x48784c53 , 0x67367d12 , 0x4e687069 , 0x2177ee48 , 0x48a3e382 , 0xa2428670 , 0x19405dda ] )
+  , Mont::unsafe_make( [ 0x3926c9ec , 0xd9b6d649 , 0x727a833f , 0xc863dc19 , 0x087ba555 , 0x13fdd840 , 0x5134420c , 0x25cb5f86 ] )
+  , Mont::unsafe_make( [ 0x8a48615b , 0xa2ab279e , 0xb2101340 , 0x52a6ba3c , 0x35b776ae , 0x6d34a18c , 0xca28bd1c , 0x29c7eed0 ] )
+  , Mont::unsafe_make( [ 0x58ce5bb8 , 0x8ba1b07b , 0xd8c9853e , 0x90bfef07 , 0x359e29fc , 0xada5dbd7 , 0x8cfe04e8 , 0x09d8252d ] )
+  , Mont::unsafe_make( [ 0xd74bbe17 , 0xe179892a , 0xbc852bb5 , 0x2c83b9f7 , 0x371de82d , 0x5ec25fa1 , 0x4ffdcb5b , 0x070f75f3 ] )
+  , Mont::unsafe_make( [ 0x493a7327 , 0x0d2b0866 , 0x6945d195 , 0x119a6b9f , 0xb4d6ba3b , 0xf85ddb63 , 0xbc002e45 , 0x25433da5 ] )
+  , Mont::unsafe_make( [ 0xae8782b1 , 0x985f538f , 0x67b6e5ca , 0xcf1dce4e , 0xbbcda474 , 0xf27aff5a , 0xf804e845 , 0x1515b0bc ] )
+  , Mont::unsafe_make( [ 0x43f7d951 , 0xe4d38144 , 0x587758ff , 0x96c831f4 , 0xd9e0e750 , 0x7259aacd , 0xa3ac4953 , 0x1f9cbe96 ] )
+  , Mont::unsafe_make( [ 0xca5e8463 , 0x79135fed , 0xcb4e0a3c , 0xf05faa1f , 0x11f61ce0 , 0x00a609ad , 0xeeb7b88c , 0x21c71b58 ] )
+  , Mont::unsafe_make( [ 0x94801e2a , 0x4360853b , 0x3cfa4203 , 0x5a4c5df1 , 0x5b164b04 , 0xaad5f6d6 , 0xea5d30e0 , 0x12548e02 ] )
+  , Mont::unsafe_make( [ 0xfc57bf51 , 0x28d7efb5 , 0xcf56138e , 0xf6b8282e , 0x1c78ef69 , 0xe3b5095f , 0x920cc7b3 , 0x258eaadf ] )
+  , Mont::unsafe_make( [ 0x3fba940f , 0x7ad75589 , 0xa8c90e6b , 0x0ad5744a , 0x52673e0b , 0x3560738a , 0x42880f32 , 0x1d204f50 ] )
+  , Mont::unsafe_make( [ 0x02ae46d1 , 0xac82b83c , 0xfbca37d6 , 0xbd0c9c76 , 0xd1138b4c , 0xf38330fc , 0x38c167cc , 0x2c48de71 ] )
+  , Mont::unsafe_make( [ 0x4e5da227 , 0xc629d7db , 0x9d5736db , 0x8ea632d6 , 0xb9bb3c14 , 0x7f61ffc0 , 0xf7160e6b , 0x1dbcca32 ] )
+  , Mont::unsafe_make( [ 0x1db28121 , 0x5877c2d1 , 0x16695a69 , 0x36c9c0b8 , 0x4fc1e104 , 0xd1df4c59 , 0x23253792 , 0x2475b7c1 ] )
+  , Mont::unsafe_make( [ 0xf7a86292 , 0x6c16f7ea , 0x81dd0c13 , 0xc21aa19a , 0x57aed095 , 0x65bc7bc3 , 0x7c465237 , 0x204a958b ] )
+  , Mont::unsafe_make( [ 0x3c84f690 , 0x094423ca , 0xda1855e3 , 0x8e2afbf8 , 0xe2b8d946 , 0x3b8720cd , 0x27624570 , 0x2836582a ] )
+  , Mont::unsafe_make( [ 0xf085b5bb , 0xb130eea1 , 0xd3927b39 , 0x80f4a10e , 0x2a6e7153 , 0x460a4349 , 0x352d79e9 , 0x087d9d34 ] )
+  , Mont::unsafe_make( [ 0xcce5149f , 0x7a004d56 , 0x71b620fc , 0x07e79468 , 0x69ec854e , 0xd8dbab94 , 0x2f3bbeb8 , 0x062ae3af ] )
+  , Mont::unsafe_make( [ 0x41796b78 , 0xecf94635 , 0xff78dee7 , 0xbf6e03b5 , 0x377af610 , 0x96daaa8e , 0xb6758dfb , 0x2a55ba27 ] )
+  , Mont::unsafe_make( [ 0xe6038f29 , 0x37e46308 , 0x7355ab55 , 0xe91a434d , 0x72784363 , 0x09dc7fd3 , 0x5fe19270 , 0x15a743a6 ] )
+  , Mont::unsafe_make( [ 0xea86a147 , 0x4d0f5964 , 0x96419285 , 0x4c5f6435 , 0x29e51a16 , 0xe75fdd1c , 0x89763da2 , 0x2c3b0b66 ] )
+  , Mont::unsafe_make( [ 0x1b4ffb3a , 0x806e0e7f , 0x8802fe57 , 0xe9eaeffb , 0x06a71b52 , 0xb0016755 , 0xc047b20d , 0x246f79fc ] )
+  , Mont::unsafe_make( [ 0x0069312a , 0x3ccf906f , 0x3eb148f8 , 0xffaac74e , 0x3c14a082 , 0xe434fdbb , 0xc1a3b4e7 , 0x1edcb856 ] )
+  , Mont::unsafe_make( [ 0x1c681a3a , 0x5b04f539 , 0x5bae06a1 , 0x9bf68263 , 0x24c3fae4 , 0x997abf36 , 0xfdf5bc46 , 0x27d8ef97 ] )
+  , Mont::unsafe_make( [ 0xc4865992 , 0x5afaf969 , 0xfb64d20f , 0x6affff7b , 0x431a39b2 , 0x483dc0c7 , 0x926fba22 , 0x0e2f8661 ] )
+  , Mont::unsafe_make( [ 0xbc196ada , 0x2d3d21a5 , 0x4c85cbdd , 0xa202cab5 , 0x9ed9fe5a , 0x603ea682 , 0x9ac00a81 , 0x124eb525 ] )
+  , Mont::unsafe_make( [ 0xc1de05d8 , 0x7b1f2e69 , 0x9c87304a , 0x4a004d9b , 0x7ad8c901 , 0x142a95bf , 0xeddb23d6 , 0x1fff223c ] )
+  , Mont::unsafe_make( [ 0xfc53d318 , 0xcc9cd014 , 0xa5734730 , 0x80fe8a2b , 0xb13cee33 , 0x5ba26208 , 0x4daad0c0 , 0x17f36ca2 ] )
+  , Mont::unsafe_make( [ 0x4ecf9764 , 0xc7422a24 , 0xb020fee4 , 0x07b928b6 , 0x5775f9ec , 0x1c68f000 , 0xff7db155 , 0x0b255242 ] )
+  , Mont::unsafe_make( [ 0x37a2b6b3 , 0xa26d25a1 , 0x584bbd22 , 0x7e10844b , 0xfebfb202 , 0x25d66951 , 0xb9f159d4 , 0x2706b062 ] )
+  , Mont::unsafe_make( [ 0xf0182d4f , 0xfbee8551 , 0x9b2b1456 , 0xffd9cf01 , 0x0b463a4e , 0xe5bbc9b6 , 0xf153c293 , 0x18421c91 ] )
+  , Mont::unsafe_make( [ 0xce599a62 , 0xf6f221ee , 0x889d5762 , 0xe7c823f5 , 0xba952848 , 0x6300166b , 0x526fd34a , 0x0562ba7b ] )
+  , Mont::unsafe_make( [ 0x14ff8d82 , 0x415c7d98 , 0xdc9daa52 , 0xcb006f3d , 0xa3430763 , 0x339401cf , 0x751ee080 , 0x008596cf ] )
+  , Mont::unsafe_make( [ 0xb77144ed , 0x500839a4 , 0xfc8b052d , 0x30bb827c , 0xccf2623b , 0x5ec9557c , 0x4b4f01c5 , 0x20b0d4ae ] )
+  , Mont::unsafe_make( [ 0xaf810657 , 0x1ef35d7a , 0x2709a409 , 0xa69e8530 , 0x43ad7add , 0x65b90ce0 , 0xbbd26f2f , 0x29be9a65 ] )
+  , Mont::unsafe_make( [ 0x28575151 , 0xa6333816 , 0x4caef82f , 0x4a662e85 , 0xadd32771 , 0xa80a7a08 , 0x25d798a6 , 0x0b864423 ] )
+  , Mont::unsafe_make( [ 0x31782ecb , 0xcba4dae9 , 0x4c42e05e , 0xf99e3284 , 0xe5a19f10 , 0xc22d4acc , 0xd600705b , 0x142f58e7 ] )
+  , Mont::unsafe_make( [ 0x540ada1b , 0xda468ba8 , 0x891e0de7 , 0xad697588 , 0x380fd64f , 0xdab5c3f3 , 0x1ca94a32 , 0x17ec2e1e ] )
+  , Mont::unsafe_make( [ 0xbc175b27 , 0xd138e3fc , 0xac83e961 , 0xdbd1f775 , 0xcba838cc , 0xa1da1647 , 0xa2a0f79f , 0x0c5374d9 ] )
+  , Mont::unsafe_make( [ 0xc1c396b4 , 0x58141d08 , 0xf3cbc276 , 0xc736b6d2 , 0xd90d5620 , 0xea5f907d , 0xf512a820 , 0x23da946d ] )
+  , Mont::unsafe_make( [ 0xe6f9c424 , 0x48d411dd , 0xe9ef8408 , 0x3bc33e3a , 0xfa16ed74 , 0x2b78869a , 0xdcdcf26e , 0x20723588 ] )
+  , Mont::unsafe_make( [ 0x4fd12987 , 0xc278b585 , 0xc2df06f5 , 0x46ba54ad , 0x867eb053 , 0x71d9cf92 , 0x6d5e5c96 , 0x25026360 ] )
+  , Mont::unsafe_make( [ 0x375f0e6c , 0x73a8bfd2 , 0x098b90d5 , 0x43a3c3e4 , 0x1e1358f2 , 0x0a4b834c , 0x99c96228 , 0x29870191 ] )
+  , Mont::unsafe_make( [ 0xb7124486 , 0x45156735 , 0x2b198d44 , 0x2c02c01d , 0xf4262574 , 0x56b70d15 , 0x0a341052 , 0x180e06dc ] )
+  , Mont::unsafe_make( [ 0x2b45f3e4 , 0xc11ae2a7 , 0xbc9760f0 , 0x048b3fef , 0xca6363de , 0x27e76f10 , 0x42ce8229 , 0x23c6411e ] )
+  , Mont::unsafe_make( [ 0xe5fb385b , 0xd0fe8d9a , 0x0cf12edc , 0xfc7d20cd , 0x4fd360ef , 0x86d74541 , 0x98e52fef , 0x2c86b303 ] )
+  , Mont::unsafe_make( [ 0x3cb67d33 , 0x4d9946ea , 0xfbb29b75 , 0x76cf2080 , 0xf3254262 , 0xb7e24d90 , 0x080e1b0c , 0x219ff519 ] )
+  , Mont::unsafe_make( [ 0x161987c4 , 0xf5972591 , 0xaf1c2023 , 0x4d287957 , 0x75aa8c7d , 0x37b9057b , 0xc32ed545 , 0x26b2363c ] )
+  , Mont::unsafe_make( [ 0x6c927302 , 0x4e94a164 , 0x4fa0043f , 0x375df7dd , 0x4bca30ef , 0xd6fd9432 , 0x677117c7 , 0x0d22098a ] )
+  , Mont::unsafe_make( [ 0x2bc0d7eb , 0x29f7f900 , 0xf53699bd , 0x5f76c7fd , 0xd825a5d6 , 0x4e77e14e , 0xee7fc159 , 0x0dd4f30e ] )
+  , Mont::unsafe_make( [ 0xb1d20522 , 0x93ab485c , 0xb2056dd2 , 0x60312349 , 0x94fbf924 , 0xd96d7258 , 0x2454217d , 0x057ad8d8 ] )
+  , Mont::unsafe_make( [ 0xa3b88bc6 , 0xddfc57de , 0x68f63999 , 0x399b7ff7 , 0xfb7003bb , 0xfb15302b , 0x9ef3f770 , 0x0506b7cb ] )
+  , Mont::unsafe_make( [ 0xa48ed32c , 0x8f4499d3 , 0x996d2958 , 0x08263898 , 0x99d182d2 , 0x3d9a58ae , 0xca07429a , 0x276a3daa ] )
+  , Mont::unsafe_make( [ 0x758ba23d , 0xd9c9df73 , 0xd0546c54 , 0xe7664b8b , 0x020368d9 , 0x0cd223d6 , 0xabb907d9 , 0x023d0a27 ] )
+  , Mont::unsafe_make( [ 0x93a6105c , 0x9c2bd358 , 0x73271eaf , 0xc148408a , 0x3aa87f60 , 0x4f375f14 , 0x1dc5c3cc , 0x046a2699 ] )
+  , Mont::unsafe_make( [ 0xa43d0394 , 0x07b6900f , 0x7a9496d6 , 0x98a22aaa , 0xe9c30bf0 , 0x62647331 , 0x804dd0fb , 0x063c7ff8 ] )
+  , Mont::unsafe_make( [ 0xf5fd60dd , 0x5aabe59a , 0x05751202 , 0x239ea2c6 , 0xad07c475 , 0x6b92f01f , 0xc6213d2c , 0x2bfef3c3 ] )
+  , Mont::unsafe_make( [ 0x94e38f90 , 0xf33b906d , 0xa10be4b3 , 0xf79ffb6d , 0x61f885e5 , 0x9f6e57db , 0x11185339 , 0x2354d7ac ] )
+  , Mont::unsafe_make( [ 0x8f74e9fc , 0x8e769009 , 0x2470648e , 0x9e943de4 , 0x32c17521 , 0xc059b4be , 0x8c8a74f7 , 0x2737aed6 ] )
+  , Mont::unsafe_make( [ 0x5dd81122 , 0x6c50cccc , 0x6b7a35a0 , 0x04a9a38b , 0x886dcaa5 , 0x8f3a5b3a , 0xf9c041d6 , 0x2e46c272 ] )
+  , Mont::unsafe_make( [ 0xc2c7ca86 , 0x680f5750 , 0xcf010206 , 0x3c82d1a1 , 0x3f55a559 , 0x2230a029 , 0x0a539d3c , 0x00772984 ] )
+  , Mont::unsafe_make( [ 0x82ae41db , 0x2164faaf , 0xb801faed , 0xd5ba930f , 0x9c4bb206 , 0x3709c697 , 0x0a78fc5f , 0x246f11d8 ] )
+  , Mont::unsafe_make( [ 0x1b2bbb1f , 0x47a722cd , 0xca60fd79 , 0xc54c6fb8 , 0x22649b8e , 0x07afbab6 , 0xcd5ac32e , 0x1f207eab ] )
+  , Mont::unsafe_make( [ 0x83431b86 , 0x16aca1f4 , 0x81db799a , 0x24398afd , 0x1d389869 , 0x44b6a174 , 0xa3693c38 , 0x21e43080 ] )
+  , Mont::unsafe_make( [ 0x4d395b0f , 0x9cf6542a , 0x8f47516a , 0xc7cecc9c , 0xcceb2a53 , 0x5fb5ccf8 , 0x7df03f9c , 0x2dea35bb ] )
+  , Mont::unsafe_make( [ 0xe7fc7a08 , 0x93d32338 , 0x1c25bba2 , 0x3980f3f1 , 0x5bf6ee41 , 0x14338794 , 0xeb86f1dc , 0x2e80eebf ] )
+  , Mont::unsafe_make( [ 0x2c000099 , 0xdf7d1f8b , 0xf57e0988 , 0x473424df , 0x4668f32f , 0x1085e055 , 0x8558842e , 0x078c9154 ] )
+  , Mont::unsafe_make( [ 0xc7c477c9 , 0x39264a80 , 0x2bdd3626 , 0x2ecf7c38 , 0xddfbeaca , 0x73a84db9 , 0x4982d6d6 , 0x1e0d7d1f ] )
+  , Mont::unsafe_make( [ 0x45c5bd53 , 0x2f9bc7f1 , 0xa1d947ae , 0xa0c88fad , 0xd16023f7 , 0xfc191543 , 0xc0e18fde , 0x0468ae65 ] )
+  , Mont::unsafe_make( [ 0x707a7ca4 , 0xf7e13b7b , 0xcbd76eff , 0x27f169fc , 0x479eeeaa , 0xc088c6d3 , 0x9699f332 , 0x08899bd3 ] )
+  , Mont::unsafe_make( [ 0xd4463008 , 0xa5569def , 0x00b1af15 , 0xcd531d93 , 0x85b80790 , 0xba41240c , 0xdd13f1dc , 0x035aab08 ] )
+  , Mont::unsafe_make( [ 0x737951a1 , 0x6f0474df , 0xc06451cd , 0xd35b38d0 , 0x4eee6771 , 0x94ecae0a , 0xd652c99b , 0x1e2bdb50 ] )
+  , Mont::unsafe_make( [ 0xa4232bb7 , 0x34370194 , 0x4aa40b87 , 0xb4e1ab9d , 0x85554e37 , 0x858c7d9d , 0xdfc5839c , 0x0d755c3e ] )
+  , Mont::unsafe_make( [ 0xa2066684 , 0x313a411b , 0xa60aa171 , 0x62f4f029 , 0x9c6e26b1 , 0xba066f1e , 0xe0c0cefd , 0x17633295 ] )
+  , Mont::unsafe_make( [ 0x133c1b3d , 0x9ac85977 , 0xf322c5c9 , 0x420ed479 , 0x152eda8e , 0x5f456882 , 0x7f8528bc , 0x12e5ff06 ] )
+  , Mont::unsafe_make( [ 0x95239640 , 0xd9f1e8f6 , 0x8bb3b016 , 0x60b29132 , 0x93bfc703 , 0x4d8fd9c5 , 0x1f1f5b76 , 0x1e18bce7 ] )
+  , Mont::unsafe_make( [ 0xa71a8ddd , 0x33d57cac , 0x0bec9d35 , 0xdf59e8f3 , 0x1736e40e , 0x3c3cb10d , 0x1534f2a0 , 0x21b845be ] )
+  , Mont::unsafe_make( [ 0xad78f415 , 0xece4a61e , 0xca43e562 , 0x800ccb44 , 0x109ddeab , 0x8b8e179f , 0x4bb68a89 , 0x1847653a ] )
+  , Mont::unsafe_make( [ 0xc1160169 , 0x31b57557 , 0x9c820586 , 0x0ddf6c0c , 0x8b2516c9 , 0x3a08a3c5 , 0xcf988db5 , 0x27d0a714 ] )
+  , Mont::unsafe_make( [ 0x8aa69b2d , 0x40ab540a , 0xab70728c , 0xa1afd682 , 0x832f6c54 , 0x3abc506e , 0xf0d9c3d4 , 0x1124dd41 ] )
+  , Mont::unsafe_make( [ 0xa97e8796 , 0x387df133 , 0xd711481b , 0xc39c9f2b , 0xec89a561 , 0x4c9d94d1 , 0xf3481634 , 0x0847ef18 ] )
+  , Mont::unsafe_make( [ 0xf9e73881 , 0xec1214d7 , 0x19cc811a , 0xbba20699 , 0xf31e7720 , 0xb46f4bea , 0x2a58f3c9 , 0x288ab330 ] )
+  , Mont::unsafe_make( [ 0x42add4ae , 0x8aa9ee3a , 0xd7ff11bf , 0x80025745 , 0x038cbc0b , 0x259b703b , 0xff0749a3 , 0x1606ed9d ] )
+  , Mont::unsafe_make( [ 0x9a8a1db0 , 0x381fab9b , 0xfcbd6c23 , 0xafacf793 , 0x7417712f , 0x4d7ec564 , 0xac2ca1be , 0x0c5b36d0 ] )
+  , Mont::unsafe_make( [ 0x5db68cf9 , 0xcde1b7cb , 0xcb90c965 , 0xf6287a95 , 0x652e9929 , 0x10729ace , 0x22f2b436 , 0x1fe4f9d9 ] )
+  , Mont::unsafe_make( [ 0x81724203 , 0x09d136b9 , 0xeeb6d375 , 0x2c28119d , 0xbf66c6dc , 0x1a493e88 , 0x08043f1c , 0x2d419da6 ] )
+  , Mont::unsafe_make( [ 0x0437e454 , 0x1885b1b9 , 0x3a1771cf , 0xd1eae22c , 0x335b1018 , 0xf17eb73e , 0x195535ff , 0x2bd02c05 ] )
+  , Mont::unsafe_make( [ 0x34e306a1 , 0x775907a2 , 0xe5c262c2 , 0xdcabc730 , 0xc9f515ac , 0x2cc7ce38 , 0x79945a44 , 0x2316ed16 ] )
+  , Mont::unsafe_make( [ 0x0eda0956 , 0xa4b02c6a , 0x0ddb9fd5 , 0x0daa2305 , 0xbcce8c50 , 0x9ba987d7 , 0x71c87776 , 0x06de2697 ] )
+  , Mont::unsafe_make( [ 0x5b972b23 , 0x0f3ab936 , 0x5469f39f , 0x0a381cda , 0x0db35281 , 0x6c16f321 , 0xbcd8b827 , 0x0fa246f4 ] )
+  , Mont::unsafe_make( [ 0x2de0fd2c , 0x75f76e2e , 0x6f793e09 , 0x01196763 , 0x1a9bdf98 , 0x640cba4a , 0xd1c14d43 , 0x0a73cd83 ] )
+  , Mont::unsafe_make( [ 0xed7c6fa9 , 0xd1668daf , 0x59d51af3 , 0x3697dff3 , 0xf45186bd , 0x143d18cd , 0x2b78b813 , 0x146708fd ] )
+  , Mont::unsafe_make( [ 0xd6e6a435 , 0x5de47789 , 0x36be8aec , 0xf3d38444 , 0xf35a9e80 , 0x3f4e6e8f , 0xb04793a1 , 0x16f71d5c ] )
+  , Mont::unsafe_make( [ 0xdc32926a , 0x9c1d60ac , 0x4aca8d79 , 0xedbc1c66 , 0xa8a71efb , 0xc6866987 , 0x3d1e27a7 , 0x1e3a7074 ] )
+  , Mont::unsafe_make( [ 0x1846ab1a , 0x73efd776 , 0x11a83cc3 , 0x823bb387 , 0xe96dbb6f , 0x562fd899 , 0x729663d8 , 0x1c41a237 ] )
+  , Mont::unsafe_make( [ 0x51016eff , 0xf13c90e0 , 0xae02ad64 , 0x0a2cdffc , 0x6987256e , 0xbbfb3d89 , 0xc3420c8f , 0x2d4c911d ] )
+  , Mont::unsafe_make( [ 0xe925cda3 , 0x02606775 , 0xb6a517fe , 0x6b125340 , 0x2b91f623 , 0x5928bc0d , 0xdfac0951 , 0x07b8218f ] )
+  , Mont::unsafe_make( [ 0xe38bc3f1 , 0x3b695db0 , 0x85e97fd3 , 0x699e3bcd , 0xbb3157f5 , 0xa960ff09 , 0x9b864208 , 0x0946bd07 ] )
+  , Mont::unsafe_make( [ 0x2a39af6f , 0x8730ff4b , 0x9fdba866 , 0xf48bb529 , 0xacb8d8c8 , 0x5117a615 , 0xbd7bc696 , 0x14b34434 ] )
+  , Mont::unsafe_make( [ 0xdcb983f4 , 0xf2bcd1c3 , 0x417d0a98 , 0x9af0e1e0 , 0xc188640c , 0x6bf0091c , 0xbbffc848 , 0x2d208841 ] )
+  , Mont::unsafe_make( [ 0x74139ff5 , 0xb2c73f7d , 0x2deb2307 , 0x98782172 , 0x6272cb00 , 0x65df26e0 , 0x5c128ec0 , 0x2807ec11 ] )
+  , Mont::unsafe_make( [ 0x9489bbaf , 0x60f09127 , 0x19046a41 , 0xc20dd08e , 0x744498be , 0xb4737368 , 0xa4eb64c5 , 0x089308f7 ] )
+  , Mont::unsafe_make( [ 0x1aea9398 , 0x3c29ad94 , 0x810a8b58 , 0x10b4d99a , 0x4f273e8e , 0xe82c82e3 , 0x1c4de287 , 0x23dceb19 ] )
+  , Mont::unsafe_make( [ 0xede4a4b7 , 0xe37508e1 , 0xf47ea120 , 0x9f90c10d , 0x2a15f1be , 0x7a39e210 , 0x70dd010c , 0x23f1566f ] )
+  , Mont::unsafe_make( [ 0x4ee96c58 , 0xbabd0071 , 0xfa142746 , 0x5cd6b231 , 0x16c4656c , 0x5fc2e032 , 0x10572da3 , 0x28417a54 ] )
+  , Mont::unsafe_make( [ 0xdbc89eae , 0x94a36257 , 0x761f73bc , 0x840ebe36 , 0x40b620c9 , 0x0218cee2 , 0xd889a77d , 0x1c8821ce ] )
+  , Mont::unsafe_make( [ 0x1cf1d45e , 0xeb94fdd5 , 0x3d0e05ed , 0xcc300bf8 , 0x0a1446c4 , 0x60d19b7b , 0xb9ca7f41 , 0x133217e4 ] )
+  , Mont::unsafe_make( [ 0xab080a0b , 0x4556a91a , 0xb75fe0da , 0x005eeea8 , 0x62af922a , 0xd7d4d510 , 0xca70a547 , 0x16e8680f ] )
+  , Mont::unsafe_make( [ 0xc338fd1b , 0x294af499 , 0xc5f71c3d , 0xec767305 , 0xf31a17e9 , 0x97ca35a4 , 0x3c977ceb , 0x0f377adc ] )
+  , Mont::unsafe_make( [ 0x242f2dfb , 0xe449dd47 , 0x90befa07 , 0xa17e0972 , 0x08068662 , 0xf298e4b1 , 0x570767b8 , 0x25278896 ] )
+  , Mont::unsafe_make( [ 0xd780b3ad , 0xeb3edec7 , 0x5fd7bd7b , 0x135af911 , 0x6e50a24b , 0x509512ad , 0xb371ec68 , 0x1d956564 ] )
+  , Mont::unsafe_make( [ 0x9ad71633 , 0x459405d0 , 0x21e372b4 , 0x832f530d , 0x93d7dd6c , 0xe7727acd , 0xf0536286 , 0x10f21563 ] )
+  , Mont::unsafe_make( [ 0xb3400ac0 , 0xb23f7fc6 , 0x99ff2550 , 0x66430302 , 0xde6cb659 , 0x37827f38 , 0xb335892e , 0x1913dc73 ] )
+  , Mont::unsafe_make( [ 0x642a1958 , 0x7afa3562 , 0x10a37c56 , 0x3ff11966 , 0xb53c6976 , 0x91b4d947 , 0xe9a2c515 , 0x11d84a9b ] )
+  , Mont::unsafe_make( [ 0xeeb86983 , 0x89a2e325 , 0x11289ef4 , 0xef154175 , 0x474fa8b1 , 0xa32ab4ac , 0x58d0afe3 , 0x06a6fbc5 ] )
+  , Mont::unsafe_make( [ 0x8cb6ca13 , 0x8a91b445 , 0x19ddd20f , 0x80e3217d , 0x740b1815 , 0x30d64f8b , 0x0071f6f5 , 0x1c81b0f5 ] )
+  , Mont::unsafe_make( [ 0x8526388f , 0x4eb73790 , 0xedc003b4 , 0x38b0b960 , 0x5a8d1591 , 0x85b41fe4 , 0x3cd30274 , 0x00733ff0 ] )
+  , Mont::unsafe_make( [ 0xf940178e , 0xdf81374c , 0xb138f907 , 0xf2c8eb51 , 0xd8c5d736 , 0xede85b35 , 0xe89f6418 , 0x06c2acd4 ] )
+  , Mont::unsafe_make( [ 0x665cc3dc , 0x42e5fe42 , 0x838459c7 , 0xe4a6c2e8 , 0xb951a61a , 0x893fb384 , 0x53f2c3d3 , 0x304695ac ] )
+  , Mont::unsafe_make( [ 0xfa0a4d9e , 0x38d61d5c , 0x4f71ac45 , 0x2dac34e4 , 0xe258e2fb , 0x2a671c29 , 0x55a5cc7f , 0x2273003f ] )
+  , Mont::unsafe_make( [ 0x3e927cf5 , 0x3e8f37d5 , 0x01d84212 , 0xf2e601dc , 0xfeb44d16 , 0x543675b7 , 0xcee63442 , 0x0a9803be ] )
+  , Mont::unsafe_make( [ 0xbb951bc0 , 0x78d86a47 , 0xb1bdc350 , 0x1a8f8d5e , 0xcb5d4a25 , 0xd6c716db , 0x724f1571 , 0x1c42ed5b ] )
+  , Mont::unsafe_make( [ 0x009f1640 , 0xe6bacbd8 , 0x2a743ae6 , 0x651e82ce , 0xa5faec91 , 0xeb83a509 , 0x6b2ad081 , 0x2b2aeb9f ] )
+  , Mont::unsafe_make( [ 0x99a48936 , 0x33e1d07f , 0x3cdbaf49 , 0xc76c2b15 , 0x60c15598 , 0x96e1fdb5 , 0xd8c51344 , 0x1c86fe40 ] )
+  , Mont::unsafe_make( [ 0xa63c6a01 , 0xcafd0697 , 0x3c0efab4 , 0xa8e9a0a1 , 0x36892316 , 0xcfb3d20f , 0xe7183c53 , 0x162a73f4 ] )
+  , Mont::unsafe_make( [ 0x54209a24 , 0x702bba05 , 0x0bfd631e , 0x198deeac , 0xd9a9ce72 , 0xf9a7e3f8 , 0x82987efe , 0x0dac5a44 ] )
+  , Mont::unsafe_make( [ 0xc6dfa433 , 0x7091cb0d , 0x2b59822d , 0x047cde08 , 0xeb223bfb , 0x6a41c1e9 , 0xf8ae352e , 0x0178e524 ] )
+  , Mont::unsafe_make( [ 0xb6d6cc71 , 0x7d4edd0e , 0x7b0f494d , 0x4a128ed3 , 0x6ae4515c , 0x9c01e959 , 0x548b8887 , 0x194b15cb ] )
+  , Mont::unsafe_make( [ 0x16d7201a , 0x82f02512 , 0x11cda957 , 0x8631ec42 , 0x326f8673 , 0x03edd178 , 0x4096f07a , 0x1f5a8831 ] )
+  , Mont::unsafe_make( [ 0x9cca1792 , 0x5be447eb , 0x765a3f72 , 0xe3f2910f , 0x8cbbc482 , 0xebecabd2 , 0x0ca9bea9 , 0x08202476 ] )
+  , Mont::unsafe_make( [ 0xc467a6a3 , 0xe98d4b70 , 0x0bf9ecc3 , 0xf4796563 , 0x4a7b5c0c , 0xf1d3ef81 , 0x3f166a3b , 0x25363781 ] )
+  , Mont::unsafe_make( [ 0x13477e95 , 0x7f5b9e7d , 0xb557e51c , 0x5481fcd2 , 0x61dcecb3 , 0x799c689e , 0xab56a46c , 0x073ace69 ] )
+  , Mont::unsafe_make( [ 0x45971fd8 , 0x51f2bde5 , 0xf4aad43f , 0xff138187 , 0xe718c7d6 , 0xc4d0bbaa , 0x7beda2d6 , 0x0e0e1a67 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// external mixing matrix (`t x t`, flattened)
+pub const CONST_M: [Mont; 169] = 
+  [ Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x7470a19a , 0x6e2c382d , 0x261c0889 , 0x3a9b8f27 , 0x7e17eaa1 , 0x683726b4 , 0xebaca0f3 , 0x2dfa2c16 ] )
+  , Mont::unsafe_make( [ 0x4a9d807a , 0xf4fd4d29 , 0x79d476da , 0x87b812c6 , 0x0ccdf503 , 0x4de5f7c2 , 0x9b59622a , 0x19383376 ] )
+  , Mont::unsafe_make( [ 0xdd3dd65d , 0x52015f64 , 0xc0baf5d3 , 0x3efbb7b8 , 0xa6819324 , 0x30a5fdae , 0x5303b583 , 0x27a171fd ] )
+  , Mont::unsafe_make( [ 0x81dbb720 , 0x4e5ea42c , 0x201e33f2 , 0x4df045a1 , 0x93da5107 , 0xa923daf8 , 0x40bfa422 , 0x1dc357be ] )
+  , Mont::unsafe_make( [ 0xfb6a77e2 , 0x76e0fa82 , 0xbf87c46e , 0x79e000b2 , 0x468ae34f , 0x501c0473 , 0x4d9c8cc1 , 0x01ceb8bc ] )
+  , Mont::unsafe_make( [ 0x554eec8e , 0x028f7198 , 0x739494dd , 0xf735edcb , 0x3e12e431 , 0xcd017da0 , 0xfc399531 , 0x1852b4c7 ] )
+  , Mont::unsafe_make( [ 0x76f842a2 , 0x66e8a290 , 0xd9a88016 , 0x9cad939f , 0x6221e239 , 0xffddb3b7 , 0xedeacf3c , 0x26862c2c ] )
+  , Mont::unsafe_make( [ 0xbf1a7b75 , 0x24568b1f , 0x9367a232 , 0x0279709c , 0x1e81ca61 , 0xf7ea9875 , 0x7b941190 , 0x2e2b5c8f ] )
+  , Mont::unsafe_make( [ 0x863f1898 , 0xd4291d59 , 0xc6d56829 , 0x39bc5bea , 0x77dd0b02 , 0xf074662f , 0x04070d22 , 0x0915424c ] )
+  , Mont::unsafe_make( [ 0x9f819048 , 0xdfdcaa4f , 0x47ad13f6 , 0xa524edd5 , 0xcd8adaee , 0xc7fd533e , 0xd151e729 , 0x04899704 ] )
+  , Mont::unsafe_make( [ 0x23b4794f , 0x07866d37 , 0xf05232df , 0xb7e8e0ee , 0x35438453 , 0xcd4032da , 0x709d059a , 0x16256ed4 ] )
+  , Mont::unsafe_make( [ 0xaf3bfb3f , 0xf1537145 , 0x8826d2a6 , 0x1ba793c3 , 0x76398639 , 0x2600b676 , 0x015777f5 , 0x093d4f42 ] )
+  , Mont::unsafe_make( [ 0x433a61f2 , 0x2f8b9f63 , 0x1a78e4ad , 0xd47677ec , 0xa3d001e3 , 0xabaf00d1 , 0x70095274 , 0x102cb982 ] )
+  , Mont::unsafe_make( [ 0xf731a445 , 0xe56b1b75 , 0xb216e7c2 , 0xda4d7950 , 0xcf17103a , 0x566e4107 , 0x7ecef5fa , 0x1a057672 ] )
+  , Mont::unsafe_make( [ 0x2a2c38c9 , 0xf49ca324 , 0x76c6ea45 , 0x71ed631a , 0x45febc09 , 0x171d7b2d , 0xab3e618e , 0x04607447 ] )
+  , Mont::unsafe_make( [ 0xb6ff8e45 , 0x5ad206f4 , 0xda051d61 , 0xa28e8344 , 0x636a4daa , 0x58dbefdd , 0x673ee177 , 0x24c8d4d3 ] )
+  , Mont::unsafe_make( [ 0x8b1238de , 0xe15a1fb7 , 0x1b0600ac , 0x973e21b3 , 0x3782bbdd , 0x6f753583 , 0x4aec1023 , 0x2e72a2af ] )
+  , Mont::unsafe_make( [ 0x9e7210cd , 0xc8c49835 , 0x5500b83c , 0x49fbf89b , 0x61e04833 , 0x633d8310 , 0x95ef933b , 0x0ea462cb ] )
+  , Mont::unsafe_make( [ 0xf07b0e4d , 0x5c515187 , 0xd42eb638 , 0x443df090 , 0x7e6367e5 , 0xfcf72430 , 0xe9b71a37 , 0x1dd36cc0 ] )
+  , Mont::unsafe_make( [ 0x40227d47 , 0xc9a14d51 , 0x5f4bf5fa , 0x861d561d , 0xa2adb4a7 , 0x2dadbd80 , 0xf84a09c5 , 0x05a3a1b0 ] )
+  , Mont::unsafe_make( [ 0x5fe44173 , 0x2c918eb5 , 0x193d05c5 , 0x2d2e1ae5 , 0x3dd71b5f , 0x07763350 , 0xf95f2117 , 0x04f1e9fe ] )
+  , Mont::unsafe_make( [ 0xad860429 , 0xb2c7b45e , 0xb950737a , 0xf71e86c2 , 0x62cf5436 , 0xdea537a6 , 0xee60df92 , 0x29f07e33 ] )
+  , Mont::unsafe_make( [ 0x4a8c8962 , 0x289ae970 , 0xebf8b5cb , 0x5a1a4014 , 0x581543c6 , 0xc59338c4 , 0xed039eef , 0x00258d5d ] )
+  , Mont::unsafe_make( [ 0xb9d642d5 , 0x010c7c84 , 0x3543fbea , 0x559dce01 , 0xc13bf817 , 0x3c7b922c , 0xe786f2d6 , 0x20a3226e ] )
+  , Mont::unsafe_make( [ 0x497f9fcb , 0x7ddddfb9 , 0x503ee590 , 0x116d8426 , 0x9b7276f0 , 0x045d7bc5 , 0x3f5c7977 , 0x15f30ec1 ] )
+  , Mont::unsafe_make( [ 0x3e3a4fba , 0xc4cf7394 , 0x3098e1b9 , 0x1594d67c , 0xb9ab0acb , 0xac7a2d3f , 0x74c4786f , 0x032e60b1 ] )
+  , Mont::unsafe_make( [ 0xea1761d1 , 0xe49a8e83 , 0xed90dd05 , 0x1880d387 , 0x3eafcde6 , 0x55aa4e63 , 0x4a771b99 , 0x2af2ca8f ] )
+  , Mont::unsafe_make( [ 0xd48a8bd0 , 0x7f75f1d6 , 0xb3f40c23 , 0xc5bf534c , 0xa7da9c61 , 0x877022da , 0x336e8b78 , 0x2bd07713 ] )
+  , Mont::unsafe_make( [ 0x15d506d1 , 0xe1cbb284 , 0x59acba69 , 0xf873de53 , 0xf33f7ff6 , 0xf5767af9 , 0xe8cb4e90 , 0x040d64fe ] )
+  , Mont::unsafe_make( [ 0x158d7332 , 0x4846953e , 0x267b1d0c , 0x99543ba4 , 0x181b9214 , 0x7da78422 , 0x901fd2f7 , 0x0c43ed63 ] )
+  , Mont::unsafe_make( [ 0x2252abc4 , 0xa376fd01 , 0x0b6ef342 , 0x22785f6d , 0xf69468cf , 0xd83d71d6 , 0xfcc1e379 , 0x1ac8ae31 ] )
+  , Mont::unsafe_make( [ 0xbc163406 , 0x54376aad , 0x251047da , 0x20fdd31e , 0xdacd8140 , 0xf1c28a7f , 0xf9c184d7 , 0x0cf45533 ] )
+  , Mont::unsafe_make( [ 0x1bf0aa38 , 0x143f774e , 0xb20779b9 , 0xbe7fb0ff , 0x6104127d , 0x25792107 , 0x580d2222 , 0x08b74865 ] )
+  , Mont::unsafe_make( [ 0x6847be2e , 0xf8a853eb , 0x7df2407e , 0xc5afe97b , 0x49c86213 , 0x9e76bbb0 , 0x66bcc07a , 0x1e4e10ce ] )
+  , Mont::unsafe_make( [ 0xdaf5884d , 0x0f4439ce , 0x594f4062 , 0x90ee089e , 0xcd0a9fa9 , 0x229b6227 , 0xca497f99 , 0x28033841 ] )
+  , Mont::unsafe_make( [ 0x6d9925d6 , 0xc37ae221 , 0xa747655a , 0xd8dad0bc , 0xac7acfa3 , 0x558383e8 , 0x16ae0e07 , 0x0979bb1d ] )
+  , Mont::unsafe_make( [ 0x23b48e68 , 0xa0d32679 , 0x7cba1e8d , 0xeb43f622 , 0x785fed4e , 0xacae023a , 0x923bbb4a , 0x08175321 ] )
+  , Mont::unsafe_make( [ 0x29c69269 , 0xd5938db3 , 0x8590e48c , 0x0a05a365 , 0x9bf752e3 , 0xde3fb554 , 0xf4cab051 , 0x18de7b3c ] )
+  , Mont::unsafe_make( [ 0x7b9faa51 , 0xced4c52d , 0x0e4037f8 , 0x52b922bb , 0xfede794d , 0x84ca34a4 , 0x560cbdfa , 0x1540ed67 ] )
+  , Mont::unsafe_make( [ 0x6ef12c23 , 0x47e06afe , 0x3af4ae10 , 0x1a5d6075 , 0x89b6d825 , 0x3d6be10d , 0x03f279c6 , 0x2354b7cf ] )
+  , Mont::unsafe_make( [ 0xf0f58ca0 , 0x2e9d93d7 , 0x0ab79e92 , 0x3514b386 , 0x42c09c4e , 0x47ba9bb6 , 0x5061467c , 0x152ee98c ] )
+  , Mont::unsafe_make( [ 0xdd8a6f05 , 0xe8346c19 , 0x8acf8d4b , 0x94548467 , 0x1ef92c79 , 0x98da17b2 , 0x30df37b7 , 0x2cc2598f ] )
+  , Mont::unsafe_make( [ 0xc1a9a31f , 0xd4840b46 , 0x8b9b0fa1 , 0x112c86bf , 0xe6b499cd , 0xe1f30dfd , 0x8e9aca3a , 0x26c8fd09 ] )
+  , Mont::unsafe_make( [ 0x00482528 , 0x68d66893 , 0x142d6cda , 0x6e796fcd , 0x40223ae0 , 0xbce8d434 , 0x36fc8d25 , 0x08291806 ] )
+  , Mont::unsafe_make( [ 0x64c81beb , 0x60e87746 , 0xf3988073 , 0xa0aff791 , 0xe88b8027 , 0x17318a84 , 0x7e459bda , 0x2f4e81ef ] )
+  , Mont::unsafe_make( [ 0x4f7fe885 , 0xaff9b9e1 , 0x69fb6802 , 0xe39652ff , 0xf33ef77f , 0x8fa7fc4f , 0x505603b6 , 0x0a509f82 ] )
+  , Mont::unsafe_make( [ 0x3ba0a324 , 0xc267583f , 0xe5fa0f30 , 0xbfc95376 , 0xe929bc85 , 0x25779dfa , 0x7836f934 , 0x0cfe2d91 ] )
+  , Mont::unsafe_make( [ 0x92c024e4 , 0x5c3d62a9 , 0xc00b5c14 , 0x62df301b , 0xaa829c7f , 0x10c522fd , 0x28b38f3a , 0x14dacbf6 ] )
+  , Mont::unsafe_make( [ 0x0fed96d5 , 0xaa0878a7 , 0xac589623 , 0x871a23fa , 0x5ca60907 , 0x0e77f15f , 0x823053dc , 0x237b7522 ] )
+  , Mont::unsafe_make( [ 0x7c2d70bd , 0xbc1da646 , 0xb66ba9a4 , 0x584d4ee8 , 0x6ae8e067 , 0xc7647711 , 0x034bf02e , 0x214c9c99 ] )
+  , Mont::unsafe_make( [ 0x6ad53836 , 0xaacbf6dc , 0x24c41cf6 , 0x27ee4eb0 , 0x04ac3e80 , 0x58f5a577 , 0xe6326990 , 0x09395b31 ] )
+  , Mont::unsafe_make( [ 0x7244a44d , 0x3e759544 , 0x35d48363 , 0x650eabd5 , 0x2eee166f , 0xf75ed5ae , 0x6161c652 , 0x006d50f4 ] )
+  , Mont::unsafe_make( [ 0x94268868 , 0x18e10e9a , 0x308b5c18 , 0x3405a8a8 , 0x20a7d340 , 0x541aea92 , 0x8599a43c , 0x09575c69 ] )
+  , Mont::unsafe_make( [ 0x443b2b48 , 0x46ab490b , 0x6842912a , 0xeb045b8d , 0x68a75199 , 0xd2677b6e , 0x093a7d75 , 0x302b8e4d ] )
+  , Mont::unsafe_make( [ 0x6e6efd06 , 0x6c31b2a0 , 0xd2bc353e , 0xf25ad4f2 , 0x854dc00a , 0x0a167258 , 0xda6995fd , 0x0d75851e ] )
+  , Mont::unsafe_make( [ 0x116c10c5 , 0xeb2aae20 , 0xb905bccf , 0x52798569 , 0xd2a5dfc3 , 0x443bb23f , 0x52ad4066 , 0x2ea4d803 ] )
+  , Mont::unsafe_make( [ 0x9a784f0e , 0x20513abb , 0xa717f639 , 0x2ac19a75 , 0x24351839 , 0xefe722e8 , 0xee392dc3 , 0x07d3890c ] )
+  , Mont::unsafe_make( [ 0xafb78089 , 0x6acc3d8e , 0xcfd274fc , 0x650ffb48 , 0x044d624a , 0x3e385be7 , 0xbe2a09c3 , 0x1b7ff1d4 ] )
+  , Mont::unsafe_make( [ 0x70508b73 , 0x1cdda871 , 0x8f319234 , 0x8a97853f , 0x50591ecd , 0x0f8abd7d , 0x0603330d , 0x165392fd ] )
+  , Mont::unsafe_make( [ 0xaf1971b3 , 0x921b7216 , 0x6d3df511 , 0x790a0125 , 0xa40d81f5 , 0xe9a0c447 , 0x07acdc6d , 0x0f3c9f84 ] )
+  , Mont::unsafe_make( [ 0x743b9fa2 , 0x82d9af4a , 0x9736b338 , 0x88d59412 , 0x8ad5bff1 , 0xf2878ae1 , 0xcd554c88 , 0x057b0a80 ] )
+  , Mont::unsafe_make( [ 0x85262236 , 0x24671c73 , 0x38ec644c , 0x8b249ebf , 0x98d8eed6 , 0x53ff56a0 , 0xe45e6293 , 0x2ff9a4ea ] )
+  , Mont::unsafe_make( [ 0xaad9e156 , 0xd93d4c41 , 0xac53b5c3 , 0x3ebca0c5 , 0xdd4be222 , 0x72d38696 , 0x0fd0f1fc , 0x1b89cc42 ] )
+  , Mont::unsafe_make( [ 0x73eb57eb , 0xe508ed59 , 0xfb38074b , 0xfc684341 , 0x4399cc32 , 0x7352614a , 0x1e608107 , 0x0af0940a ] )
+  , Mont::unsafe_make( [ 0xd362341a , 0x189ecbc0 , 0x901c277a , 0x21beaa62 , 0xb78ab48c , 0x3e04c7ce , 0x57845765 , 0x1f0da8fe ] )
+  , Mont::unsafe_make( [ 0xa08dd032 , 0x5fe639d5 , 0x7801c64e , 0xbc0ecbb7 , 0x5f8fc904 , 0xb06c0dc0 , 0x7f2063e6 , 0x14b8a217 ] )
+  , Mont::unsafe_make( [ 0x25281329 , 0x76757200 , 0x8114b3e8 , 0xecf450e6 , 0x5dd2dec9 , 0x8a88c5bb , 0xa4ae435f , 0x1fd3bcf8 ] )
+  , Mont::unsafe_make( [ 0xa011eecf , 0x54dd2265 , 0x71f45e75 , 0xf965713a , 0x4ad42ff0 , 0x9e605b5d , 0xace49fa9 , 0x0c656afd ] )
+  , Mont::unsafe_make( [ 0x9f4ae95b , 0xa1abdb29 , 0xe666d752 , 0x69ff25a8 , 0xaa67a282 , 0x687d3904 , 0x99adba45 , 0x077f8c1c ] )
+  , Mont::unsafe_make( [ 0x06e35d40 , 0xa646f2d2 , 0xbd406b88 , 0x5c292d5c , 0x3d8fd0b4 , 0xf83ab2bd , 0xf7ce51c0 , 0x0be11b68 ] )
+  , Mont::unsafe_make( [ 0x83b6a207 , 0xda84929b , 0x051870c1 , 0x7e4681c1 , 0xdd7de599 , 0x718ffd18 , 0x26042aee , 0x1d5d3f7b ] )
+  , Mont::unsafe_make( [ 0x1966b370 , 0x4b1ca2ff , 0xdc57b6f8 , 0x8225d174 , 0x1e3ee204 , 0xd247dd3e , 0x5a425e39 , 0x17c5ff03 ] )
+  , Mont::unsafe_make( [ 0x3b7716ff , 0x7fce3261 , 0xb21b6b5d , 0xf31eed8c , 0x8d285f41 , 0xeabfbb58 , 0xf5627e82 , 0x026d01ca ] )
+  , Mont::unsafe_make( [ 0xf67f287d , 0x4bae284c , 0x196f4295 , 0x0c1eb291 , 0x6f64390a , 0x5248aaf2 , 0x9839c2b8 , 0x01892218 ] )
+  , Mont::unsafe_make( [ 0xd987340e , 0x1d727148 , 0x5da8e34e , 0xcaf8655c , 0xddb4f717 , 0x68e30496 , 0x15a35708 , 0x28cbf240 ] )
+  , Mont::unsafe_make( [ 0x10c5713d , 0x845fce0f , 0x5da94a34 , 0x8901d7db , 0x03a1a42d , 0xdf251f96 , 0xf64e5861 , 0x1a6fc5a6 ] )
+  , Mont::unsafe_make( [ 0xe1b9185f , 0x9ba463f3 , 0x25c2572a , 0x0408fa4f , 0x11a905bd , 0xdb7a46d3 , 0x220bfbf8 , 0x24ef9921 ] )
+  , Mont::unsafe_make( [ 0xe64bd8b3 , 0xde06baf9 , 0x75c50d6f , 0x3c0355a5 , 0x4d4db69e , 0x017fedb1 , 0x2f186012 , 0x1b4d2f28 ] )
+  , Mont::unsafe_make( [ 0x1987646b , 0xf368d04c , 0xb77ffce5 , 0x74f794de , 0x29ecfefc , 0xc420f4ee , 0x255d2060 , 0x0fefb11b ] )
+  , Mont::unsafe_make( [ 0x3d4e0421 , 0x8630d43d , 0xd00bb634 , 0x55e95f8e , 0x4576dfae , 0x93f04f45 , 0x108fba54 , 0x0051943c ] )
+  , Mont::unsafe_make( [ 0x6c716edf , 0x618609db , 0x63b9d448 , 0x15bc8abe , 0x44227c79 , 0xe145f527 , 0x003695b7 , 0x0f83fa7f ] )
+  , Mont::unsafe_make( [ 0xf2fec31f , 0xbb9cf8f4 , 0xa0cb1f19 , 0xe767bc56 , 0x2d82d150 , 0x754f9eb4 , 0x3fc6d264 , 0x2b645566 ] )
+  , Mont::unsafe_make( [ 0x786b0eaf , 0x79d92015 , 0x4948d590 , 0xb242408f , 0x1c8b28fd , 0xecb8711f , 0xecfdfb67 , 0x2686af99 ] )
+  , Mont::unsafe_make( [ 0xaeba6155 , 0x2b58af2f , 0x6a099cca , 0x1c496064 , 0xd675ca5c , 0xce1c1002 , 0xaa2dff8a , 0x08401fc9 ] )
+  , Mont::unsafe_make( [ 0xe94a1e5c , 0x2925bf4d , 0xd813c837 , 0x977e58f6 , 0x816639c6 , 0x6d0165da , 0xcf9466f6 , 0x1535dc6a ] )
+  , Mont::unsafe_make( [ 0xbcdf5fe5 , 0xf0ec951c , 0x3c0b6458 , 0x876dad8b , 0x1235ca2c , 0x32f1ebdf , 0xb05eabb5 , 0x1e374c2e ] )
+  , Mont::unsafe_make( [ 0xd5dd9548 , 0x833b679c , 0xb115dc95 , 0x5ba1afb2 , 0x870a879c , 0xc7882f23 , 0xac96b844 , 0x12d95a12 ] )
+  , Mont::unsafe_make( [ 0xed762c73 , 0x9fa22695 , 0x1fa8358b , 0x7e3bc9b4 , 0x0827aab0 , 0x9c491fd1 , 0x5ac5122d , 0x0ed9e5a6 ] )
+  , Mont::unsafe_make( [ 0xa2213652 , 0xd29527f1 , 0x13118c5e , 0xb0871a7f , 0xe384ca8a , 0x62d485e7 , 0x8481ae2b , 0x177fe3b9 ] )
+  , Mont::unsafe_make( [ 0x3c9af1d8 , 0xf0bd7133 , 0x98a57133 , 0xd5d4b307 , 0xcbd2dd1d , 0xa24942fe , 0xbe57dcdd , 0x260f7f14 ] )
+  , Mont::unsafe_make( [ 0x4ea63924 , 0x826e4da2 , 0x9aa1955e , 0x16b8bf6b , 0xd96bae0d , 0xeae08d7e , 0xe10a3650 , 0x23826acb ] )
+  , Mont::unsafe_make( [ 0x2a6bb97d , 0x7d16c518 , 0x5e8f445b , 0xe7078f63 , 0xd4090714 , 0xa23d3174 , 0x77b4f974 , 0x1ac73945 ] )
+  , Mont::unsafe_make( [ 0x9d3e5a59 , 0xb4b4b9e4 , 0x6bcf1a5e , 0x3459d803 , 0x59be3ffc , 0xbe1e3706 , 0xd0001c12 , 0x028c9432 ] )
+  , Mont::unsafe_make( [ 0x6a129cbf , 0xfa934143 , 0x4f38cfe0 , 0x56f3f176 , 0x307115ea , 0x5576fcbc , 0xd88d5376 , 0x05b49bfb ] )
+  , Mont::unsafe_make( [ 0x43bc8b4c , 0x837483ca , 0xce5e29b2 , 0x28a064a2 , 0x02b7a3f5 , 0xeee8857a , 0xa335247f , 0x0f56a59f ] )
+  , Mont::unsafe_make( [ 0x5f9dc54d , 0x5b69131f , 0x1743fecd , 0x4f43066f , 0xf73ae826 , 0x6c7593cc , 0xcfc801e5 , 0x0dc3a5e6 ] )
+  , Mont::unsafe_make( [ 0x26e30ff2 , 0x6615152e , 0x0281077b , 0x44a56552 , 0x9129ada7 , 0x79a07328 , 0xd8826788 , 0x1119aed7 ] )
+  , Mont::unsafe_make( [ 0xbe080646 , 0x6e8a07ef , 0x4285c0cc , 0xc3a3b7c2 , 0x1675b881 , 0x73b67f9e , 0xf2a2bbc7 , 0x2724ec38 ] )
+  , Mont::unsafe_make( [ 0x94068b38 , 0x652ef442 , 0x00d42fc7 , 0xbd553f0a , 0x7efdb9b2 , 0x14148006 , 0x1ab29529 , 0x26016127 ] )
+  , Mont::unsafe_make( [ 0xd1312f21 , 0x04d106f1 , 0x227e79a7 , 0xcc440caa , 0xb21e6c2e , 0x171184ca , 0xa1230040 , 0x10b75ec2 ] )
+  , Mont::unsafe_make( [ 0xfbb1bd21 , 0x5cfd5b69 , 0x66408e6a , 0x61047d49 , 0x875f4398 , 0xce3b1eb6 , 0x1d075ed8 , 0x02246019 ] )
+  , Mont::unsafe_make( [ 0x0a4b59af , 0x64d4b342 , 0x26219f4e , 0xbbc0024c , 0x52a08a70 , 0x0f328c94 , 0xe5cf7b79 , 0x1a2915d7 ] )
+  , Mont::unsafe_make( [ 0xa8de8aef , 0xbb28a287 , 0x83528528 , 0x0a3fd736 , 0xbd1b52b4 , 0x5279ce37 , 0xf8cf436a , 0x19bb63cf ] )
+  , Mont::unsafe_make( [ 0x43335172 , 0x4636ca74 , 0xe9c3d01c , 0xe2fb924b , 0xd9928c72 , 0x7b4e74dc , 0xa98fd182 , 0x1f8db2b6 ] )
+  , Mont::unsafe_make( [ 0x76b94a86 , 0x7ab54279 , 0x3b0e3942 , 0x6b6cab1c , 0x47a2485b , 0x2bde1a5c , 0x66175898 , 0x19149c51 ] )
+  , Mont::unsafe_make( [ 0x51bff78e , 0xe7dd445d , 0x6267c15b , 0x4113d157 , 0x2db5646c , 0x9d5e0687 , 0x14328b47 , 0x276b5d2d ] )
+  , Mont::unsafe_make( [ 0xa61b01c0 , 0x47a1700c , 0xf8a2d921 , 0x984157d9 , 0xfa53ef85 , 0x7ad60ced , 0xf24ffeb6 , 0x2c1d6619 ] )
+  , Mont::unsafe_make( [ 0x59a46e7d , 0x4d5c1cc9 , 0x8c925e65 , 0xfdb3aca6 , 0xf835fb65 , 0x0424b39b , 0x54cde49a , 0x28548ff1 ] )
+  , Mont::unsafe_make( [ 0xe5a2e904 , 0xfb145d16 , 0x3fc37631 , 0xdaf201ef , 0x4a96d99e , 0xab3817c2 , 0xb35fdb5b , 0x034ed4fa ] )
+  , Mont::unsafe_make( [ 0x9133e351 , 0x5cd2c152 , 0x3ce0b660 , 0x4dfa8617 , 0xe268c2a5 , 0xeee84c17 , 0xe4b99a11 , 0x084ccf57 ] )
+  , Mont::unsafe_make( [ 0x0ddad11b , 0x5522a3b9 , 0x80430ef0 , 0x93f170b9 , 0x9cd600fb , 0xb77c61c7 , 0xea0b732b , 0x20ff4e83 ] )
+  , Mont::unsafe_make( [ 0x46ccd875 , 0xf29fac8d , 0x874272fa , 0x48f4ef55 , 0x9e005584 , 0x8bc044d4 , 0x49b93cce , 0x256c0506 ] )
+  , Mont::unsafe_make( [ 0xcde81d0d , 0x5d352058 , 0x2fa59cc6 , 0xddf44900 , 0xb4ecfca7 , 0x78cba729 , 0x98590341 , 0x0b0bdebe ] )
+  , Mont::unsafe_make( [ 0xf1fa7415 , 0x7e6741ff , 0x97a4d3a5 , 0x2e6cfc8f , 0x45b778d9 , 0xb19bfdf2 , 0x4df59e99 , 0x1c0e9694 ] )
+  , Mont::unsafe_make( [ 0x65d3109e , 0x46a034c8 , 0x1eaa8aa7 , 0xd0ea08a8 , 0xbe9216bd , 0x89d83419 , 0x3c3e278b , 0x0eff6ab0 ] )
+  , Mont::unsafe_make( [ 0x672f7977 , 0x718fb23e , 0xb087337b , 0x33730aee , 0xda25e565 , 0xa87ef765 , 0x0ee81a63 , 0x2c80ce77 ] )
+  , Mont::unsafe_make( [ 0x5a13629b , 0x32e4bc56 , 0x5b652020 , 0xeac7802a , 0x4e44383f , 0x7e2ae44e , 0xdaa8e55c , 0x1e06c149 ] )
+  , Mont::unsafe_make( [ 0xf75019bc , 0xabec949a , 0x1bea8a9c , 0x0a093e25 , 0x4c78e713 , 0xd9865d65 , 0x43aaf4ab , 0x060bd4a9 ] )
+  , Mont::unsafe_make( [ 0x0e71722d , 0xc89bff1c , 0xcb0bf99a , 0xdbfab595 , 0xf6ab09aa , 0x81a667a0 , 0xf4fdff1e , 0x0138b7ee ] )
+  , Mont::unsafe_make( [ 0xa44959e4 , 0x6b138fe9 , 0xcd5f5c23 , 0x067da56d , 0x4d007af5 , 0x90a5e538 , 0x95aa57a5 , 0x054114de ] )
+  , Mont::unsafe_make( [ 0x7e27fffe , 0x10bb5602 , 0x2cdda089 , 0x006edef8 , 0xd260b513 , 0xc4d3d37c , 0x256c1bce , 0x191e2959 ] )
+  , Mont::unsafe_make( [ 0x2c103bdc , 0xc24a4ac6 , 0x73ea1f14 , 0x95f2833a , 0x09031e31 , 0xb6953986 , 0x067b006e , 0x06878956 ] )
+  , Mont::unsafe_make( [ 0x935d8769 , 0x5ecafffc , 0x13e0e3a3 , 0x1ee16136 , 0x7523314c , 0xa3039e15 , 0x59b0ef92 , 0x15c7032c ] )
+  , Mont::unsafe_make( [ 0x699070c7 , 0x1c9864af , 0x82bb6ec3 , 0xf8d60241 , 0x29275512 , 0x018a3b86 , 0x72e2bf11 , 0x22081e95 ] )
+  , Mont::unsafe_make( [ 0x9efa7668 , 0xbcb44a20 , 0x403056ae , 0x29024b35 , 0x034c314d , 0x42a300fa , 0x4eb01d5a , 0x24c207e8 ] )
+  , Mont::unsafe_make( [ 0x55c03db2 , 0x9bca3074 , 0xc43110ec , 0xdc8f1c8e , 0x4fdd8acd , 0x6e8a2a3b , 0x33d4807b , 0x0c2dfb77 ] )
+  , Mont::unsafe_make( [ 0x219d196e , 0xd8c93320 , 0x6e9b38f3 , 0x8221755b , 0xf2074228 , 0xeb5fc8bf , 0x1739dd79 , 0x2119410b ] )
+  , Mont::unsafe_make( [ 0x2b175ff3 , 0x90714738 , 0x81fad896 , 0xfe165465 , 0xb8922a38 , 0x4cd166fe , 0xe7ec6256 , 0x17d56891 ] )
+  , Mont::unsafe_make( [ 0xc4e03fe3 , 0x542c5333 , 0x733f144c , 0xe48d141f , 0x547c6ec0 , 0x9962d322 , 0xb314f6fb , 0x07d4bded ] )
+  , Mont::unsafe_make( [ 0xd5d7e78d , 0x051fb0ef , 0x6c946827 , 0xecb45f5c , 0xf3a12b08 , 0x4b2b765b , 0x09f5fe4f , 0x27cc8b76 ] )
+  , Mont::unsafe_make( [ 0x2a7f10a0 , 0xfb193871 , 0x86b8701a , 0x2b6ecd11 , 0xd632ebba , 0x39c29ee6 , 0xb96b0aa5 , 0x227b11f8 ] )
+  , Mont::unsafe_make( [ 0x9fa29bdd , 0xb1a62ec8 , 0x4387dfee , 0x84a85926 , 0xa137dc28 , 0xdf78c74a , 0x2cc14171 , 0x0b3bfd47 ] )
+  , Mont::unsafe_make( [ 0x96fef86f , 0xcf3a66dc , 0x9900f3ed , 0xf2e68d16 , 0x74fd2b35 , 0x60c87652 , 0x1e1dc7cb , 0x05e17f32 ] )
+  , Mont::unsafe_make( [ 0x83521606 , 0x672e66e8 , 0x03e2b204 , 0xeb74a50f , 0xd0cee017 , 0x823f1e9f , 0x93ee87ae , 0x08ae5789 ] )
+  , Mont::unsafe_make( [ 0x12ccbcd5 , 0x2cbc4cd2 , 0x35d8f68b , 0x3e6cb8a8 , 0x188f688b , 0xa2068f9d , 0x3d8a1e69 , 0x2f048902 ] )
+  , Mont::unsafe_make( [ 0xcea59db0 , 0xf9985a34 , 0x634d7dec , 0xa80a1c9a , 0x0912bd0d , 0x0eb371fa , 0x2167093e , 0x21fdf308 ] )
+  , Mont::unsafe_make( [ 0x24b48700 , 0x48b73853 , 0x5546b807 , 0x1dc30558 , 0x9186a7a6 , 0x7a3873c8 , 0xac971389 , 0x1c79d5f7 ] )
+  , Mont::unsafe_make( [ 0x8c58c8af , 0x668708ce , 0xea8d1c17 , 0xebbf19de , 0x68437041 , 0xbb3b70a6 , 0x7471cf6c , 0x0d23f7fd ] )
+  , Mont::unsafe_make( [ 0x31e9e350 , 0x5dd8c939 , 0x66a5601b , 0x8abf9ece , 0xb2dc1cb7 , 0xb3224247 , 0x71dcd1da , 0x2e786348 ] )
+  , Mont::unsafe_make( [ 0x8d40cf30 , 0xd4f0983d , 0xcca5f054 , 0x35ae48c7 , 0x8ceb6191 , 0x0e01ee42 , 0xa32d2dcd , 0x24ee5635 ] )
+  , Mont::unsafe_make( [ 0x4cce370a , 0xeb05ed6e , 0x3ec4f044 , 0xea5fcb35 , 0xff21b1c2 , 0x9afaf5dc , 0x0e819e67 , 0x17121213 ] )
+  , Mont::unsafe_make( [ 0xcda5282b , 0x361dd199 , 0xdb72e2a8 , 0xf2f42eb8 , 0x4f070582 , 0x5a6edef9 , 0x30c68eb0 , 0x0481df45 ] )
+  , Mont::unsafe_make( [ 0x289308ad , 0x9698157e , 0xbe68a1e0 , 0x589b5b8d , 0x2e0f1f31 , 0xb3ec1feb , 0x8b2c096e , 0x00b8b7e4 ] )
+  , Mont::unsafe_make( [ 0x1fc77460 , 0xfb8eec4e , 0x8cced1dd , 0xfa958af7 , 0x8d36690b , 0x1af15cd3 , 0x6cd6029a , 0x156604c2 ] )
+  , Mont::unsafe_make( [ 0x7b81cd9d , 0xb17fe654 , 0x33986b35 , 0x4f17b074 , 0x6cda700d , 0x9d616bc4 , 0xf0b54f07 , 0x29f1f749 ] )
+  , Mont::unsafe_make( [ 0xae9bd513 , 0xa64ae2af , 0xe7972246 , 0x0126a7ce , 0x6849d6ca , 0x97a00fa0 , 0x23225828 , 0x28e69ec6 ] )
+  , Mont::unsafe_make( [ 0x9edd3664 , 0x8f152237 , 0x5c1e5226 , 0x89df4623 , 0xb8990e37 , 0xc2bfa797 , 0xdad402c1 , 0x0dc66cea ] )
+  , Mont::unsafe_make( [ 0x88c21004 , 0x5cfc66e5 , 0xc5bfc785 , 0x6ef3b1b5 , 0x28416e28 , 0x9ee2d272 , 0xf57b32fd , 0x219334be ] )
+  , Mont::unsafe_make( [ 0x328030b1 , 0x086e2d5d , 0x5a45fdca , 0xc8247cd6 , 0x672c82d4 , 0xc824da4c , 0x7ed4478e , 0x1639586e ] )
+  , Mont::unsafe_make( [ 0x6dc6a49b , 0xba248920 , 0x7511e9fa , 0x7038bc77 , 0xabbc3130 , 0x4e836e7c , 0x250f2773 , 0x18376588 ] )
+  , Mont::unsafe_make( [ 0x3394e3ee , 0x455ed09f , 0x2519c8b4 , 0x58e2a2c5 , 0xdfe14050 , 0x5af65dbf , 0xb0ac721d , 0x0c878227 ] )
+  , Mont::unsafe_make( [ 0x883d901b , 0x233a99c8 , 0x3ba64de4 , 0x1015c602 , 0x01bd1678 , 0x77c27f6f , 0xb8123348 , 0x209458b7 ] )
+  , Mont::unsafe_make( [ 0x5dfa208d , 0x528492a7 , 0x0aba9d4c , 0x75e4e50c , 0x8c2a2245 , 0x66c9fa16 , 0xfcab1ef1 , 0x123b8dc6 ] )
+  , Mont::unsafe_make( [ 0xc3d57c4c , 0xd0c655a3 , 0x5cb778cf , 0x98d7a633 , 0x77d8279d , 0xeabce70d , 0xfc9d0a8d , 0x2af5f0a1 ] )
+  , Mont::unsafe_make( [ 0x13979b24 , 0x52f57561 , 0x36b928aa , 0x870525f9 , 0xe9804c9d , 0x0cfc082f , 0x68a4de1d , 0x1c819c41 ] )
+  , Mont::unsafe_make( [ 0xce2e06ce , 0xff36ffd5 , 0x347972fe , 0x1ebfa4a2 , 0x1220871f , 0x227ac647 , 0x2ae2bcf1 , 0x16e74b79 ] )
+  , Mont::unsafe_make( [ 0xcc37ccac , 0xa86ba5f1 , 0x378251ae , 0xb09e470a , 0x49e68234 , 0xe431064f , 0xf574a7af , 0x07680e28 ] )
+  , Mont::unsafe_make( [ 0x5ef026c5 , 0x0f158d07 , 0x345f1eda , 0x15c3d339 , 0x6e45693a , 0x970c7d50 , 0xa21ff412 , 0x06d7f267 ] )
+  , Mont::unsafe_make( [ 0x4007d287 , 0xc3acf11b , 0x7438399e , 0x788ad56b , 0x697e3e64 , 0x550eb386 , 0xafd42a6c , 0x09dbc3c2 ] )
+  , Mont::unsafe_make( [ 0x00c19927 , 0x87b720dc , 0x7a297672 , 0xa088396a , 0xc1491dab , 0x78753a1c , 0x515a489a , 0x18c32da9 ] )
+  , Mont::unsafe_make( [ 0x21ec138a , 0x9f7777a0 , 0xb5c802f8 , 0x4d541a9f , 0x3e576575 , 0x716ae85c , 0xbd2e271d , 0x1b53e074 ] )
+  , Mont::unsafe_make( [ 0x080ff0a2 , 0xf8bc5d8a , 0x4f2146a4 , 0x8565e8c7 , 0x02090c5c , 0x5eec5c14 , 0x630b66c4 , 0x1933ad06 ] )
+  , Mont::unsafe_make( [ 0xaf23f993 , 0x0d6da10b , 0xc5a3b118 , 0x2f6e008e , 0xde0110e8 , 0x79752134 , 0x93fabfea , 0x1f04771a ] )
+  , Mont::unsafe_make( [ 0x1d20c984 , 0x98c0ec76 , 0xfc52124e , 0x7f5f9092 , 0x7400da17 , 0xcf957975 , 0xe7d45d9f , 0x01754c74 ] )
+  , Mont::unsafe_make( [ 0xf9f39721 , 0x8a9d6b8d , 0x9a22e8a8 , 0xd2bafcec , 0x8e8e8441 , 0x97a71724 , 0x8f0691fb , 0x1c7967b8 ] )
+  , Mont::unsafe_make( [ 0xc482ecf1 , 0xff1a52a8 , 0x3888d91c , 0xb96b7a43 , 0x6331f618 , 0xdf8a84c4 , 0x732898f3 , 0x092a57cb ] )
+  , Mont::unsafe_make( [ 0xf59b5d76 , 0xe2da0d07 , 0xa0215677 , 0x035ad992 , 0x05bcf090 , 0x5de9657c , 0xd6da4c60 , 0x1c882d89 ] )
+  , Mont::unsafe_make( [ 0xbdcd6ecd , 0xfbae83e0 , 0xfbf72541 , 0x57eb6692 , 0x69c3322d , 0x0d2d09c4 , 0x12535684 , 0x0e4883bd ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// internal mixing matrix (`t x t`s, flattened)
+pub const CONST_P: [Mont; 169] = 
+  [ Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x57d1ed72 , 0x6cbe909f , 0x1fe0118d , 0x27c9aa51 , 0x7b6ad70d , 0x14fe1060 , 0x8c6bb61b , 0x0257e71d ] )
+  , Mont::unsafe_make( [ 0x1416c9bb , 0x9213422b , 0x8f3fbfda , 0x809dfbc1 , 0x2a469616 , 0xd319f8cc , 0xb204d3d7 , 0x27138ef5 ] )
+  , Mont::unsafe_make( [ 0xd511bde6 , 0x1fc38a7a , 0xb9070807 , 0xee8d16d8 , 0x09e77d73 , 0xcce72af9 , 0xa8517948 , 0x03f0fc6a ] )
+  , Mont::unsafe_make( [ 0x8762becf , 0x32b921a9 , 0x0e41036d , 0x31d7cc15 , 0x4773bf1a , 0x632a2938 , 0x363fb231 , 0x07493622 ] )
+  , Mont::unsafe_make( [ 0xadcbfc7d , 0x902d10cf , 0x9fede430 , 0x513fe6ca , 0xf7f6dc8d , 0x78da09e0 , 0x56bb4367 , 0x2b182686 ] )
+  , Mont::unsafe_make( [ 0xfdc332a3 , 0x1c320d68 , 0x446cf6fc , 0x083ef013 , 0xb5bfc6ae , 0x5924bec3 , 0x937b22a8 , 0x25abcac6 ] )
+  , Mont::unsafe_make( [ 0x2a158aac , 0xe3a686fb , 0x938c275f , 0xb34fd72d , 0xafe6aa1a , 0x766b165e , 0x0c6136a4 , 0x25160479 ] )
+  , Mont::unsafe_make( [ 0x27730c19 , 0x4f1cd9f2 , 0x92ee6e32 , 0x0adfbc4a , 0xeec0d52b , 0x10a2c351 , 0xe25a7598 , 0x07068a60 ] )
+  , Mont::unsafe_make( [ 0x0b0a81c3 , 0x3f70f744 , 0x8a263438 , 0xe1394b01 , 0x17f6d9c1 , 0xc15c9e52 , 0x8db7e676 , 0x22be52bf ] )
+  , Mont::unsafe_make( [ 0x96e196af , 0x3a2b09c9 , 0xdd2b9da2 , 0x337290ec , 0xaf2154c4 , 0x1d402e0c , 0x408a5625 , 0x1901d9f2 ] )
+  , Mont::unsafe_make( [ 0xfaa9759d , 0x33dd5732 , 0xb9180400 , 0x25716694 , 0x6e0966a2 , 0x48be41d1 , 0x4a67fb24 , 0x2c7c79b3 ] )
+  , Mont::unsafe_make( [ 0xfd651fff , 0x85d9599c , 0x393c5ff3 , 0xd6d9f381 , 0xeec3c379 , 0xb41aab22 , 0x5ea20902 , 0x0d84a0e0 ] )
+  , Mont::unsafe_make( [ 0x433a61f2 , 0x2f8b9f63 , 0x1a78e4ad , 0xd47677ec , 0xa3d001e3 , 0xabaf00d1 , 0x70095274 , 0x102cb982 ] )
+  , Mont::unsafe_make( [ 0x33a16363 , 0xdd80404c , 0x69a442af , 0xcdccf217 , 0xdadb641b , 0x89b8787b , 0x326f167a , 0x22de6ca4 ] )
+  , Mont::unsafe_make( [ 0xf8ce6a41 , 0x6f5ce940 , 0x718df363 , 0x92bf9577 , 0x215b7a11 , 0x9d5b6056 , 0x925bfcde , 0x2a188f1d ] )
+  , Mont::unsafe_make( [ 0x27d0e214 , 0xaa90ec6f , 0xbe68f5ef , 0x9056303c , 0xd3c95709 , 0x5d9e2e20 , 0xd71aaaf0 , 0x02e8f39a ] )
+  , Mont::unsafe_make( [ 0x701e950a , 0x38ca94bf , 0x772020b2 , 0xdeb145ba , 0x06471c81 , 0xfa4920af , 0x8dcc0742 , 0x067360ba ] )
+  , Mont::unsafe_make( [ 0x35bc6cdf , 0xa6baaffe , 0x455daf1b , 0xb56805c9 , 0x17dfc0e7 , 0x48658ba2 , 0x7191f50d , 0x04535d93 ] )
+  , Mont::unsafe_make( [ 0xeee2503a , 0xb82ef3af , 0x8401b7d2 , 0x812aa2f6 , 0xd17aec98 , 0xcb44f80e , 0x8110b4fd , 0x13d3ad4d ] )
+  , Mont::unsafe_make( [ 0xced30746 , 0xa6402e04 , 0x030ce9ad , 0xe4e492ba , 0x232755bb , 0x591ecd5f , 0xbce3ca47 , 0x27a98e40 ] )
+  , Mont::unsafe_make( [ 0x9807190e , 0xdd1e29b0 , 0xe65dcda3 , 0x93a64e35 , 0xc98edcd6 , 0xab3c0ad1 , 0x1c68b33a , 0x1c0712de ] )
+  , Mont::unsafe_make( [ 0x26d457d1 , 0x32926dc9 , 0x6def16cc , 0xf2163b21 , 0x68b621c4 , 0xffe624d1 , 0xb4ffe4bd , 0x0d9a5fdd ] )
+  , Mont::unsafe_make( [ 0x40bcf890 , 0x3f64390b , 0xec69cc83 , 0x4ab180bd , 0x788a18e7 , 0x8cee8436 , 0xc3dfb858 , 0x0f65ad9a ] )
+  , Mont::unsafe_make( [ 0xfd296e39 , 0xdac23593 , 0x115d8f10 , 0xd0aa9efb , 0x80923ecb , 0x01717601 , 0x1fa70c9f , 0x1a17f2f1 ] )
+  , Mont::unsafe_make( [ 0x2f8de80b , 0x970fa084 , 0x1097340e , 0x217296a5 , 0xbfdc7126 , 0x9649f411 , 0x25b612a6 , 0x01c896b9 ] )
+  , Mont::unsafe_make( [ 0x3e3a4fba , 0xc4cf7394 , 0x3098e1b9 , 0x1594d67c , 0xb9ab0acb , 0xac7a2d3f , 0x74c4786f , 0x032e60b1 ] )
+  , Mont::unsafe_make( [ 0x93f6f4ae , 0xe254dbfe , 0x97051e89 , 0x0f35e1cd , 0xec878e34 , 0xe9ea43bd , 0x1de41d42 , 0x13f8a4fc ] )
+  , Mont::unsafe_make( [ 0x310f6c78 , 0x92babcbc , 0x05fefca3 , 0x1e61c06a , 0xfabe0947 , 0x43597b68 , 0x4d600a1b , 0x28bbc675 ] )
+  , Mont::unsafe_make( [ 0x1408632c , 0x9cfc9a3b , 0x09692831 , 0x3993d2c3 , 0xcd50ee7f , 0x203e0995 , 0x28a8221f , 0x20365e80 ] )
+  , Mont::unsafe_make( [ 0x27a17eb7 , 0x7857832b , 0xf965d785 , 0xff2b74cb , 0xce23e455 , 0x75f09d9a , 0xa79b01ab , 0x2cbc0324 ] )
+  , Mont::unsafe_make( [ 0xc6f40bb8 , 0x3295a237 , 0xda7f3b42 , 0x43f187c1 , 0xf9a4862b , 0x2013dacd , 0x7f85eea1 , 0x0b50e924 ] )
+  , Mont::unsafe_make( [ 0xbb68c5d2 , 0x5c481bd9 , 0x1a567c2f , 0xabd61251 , 0xc4be285b , 0x44a7ce70 , 0x4a168b4b , 0x0d54fee7 ] )
+  , Mont::unsafe_make( [ 0xf8d9d111 , 0xb7ed8a46 , 0x23e8a87c , 0x309d6989 , 0xed3557fe , 0x7669414d , 0x131ea800 , 0x029e2618 ] )
+  , Mont::unsafe_make( [ 0xddb1ce54 , 0xa2c4ea69 , 0xdda985bd , 0xe129ec95 , 0xd5c12635 , 0xb083e74a , 0xee3f2506 , 0x1c8e6dc4 ] )
+  , Mont::unsafe_make( [ 0x535cdb84 , 0x57ade919 , 0xeca074db , 0xf153541f , 0xf2ba83de , 0x91ebcd2b , 0x42e3ec91 , 0x1985e172 ] )
+  , Mont::unsafe_make( [ 0x5874db6c , 0x277b70c5 , 0x55d8ec1e , 0x18954950 , 0xffaa48ac , 0x434b5681 , 0xdf6b998c , 0x3035d608 ] )
+  , Mont::unsafe_make( [ 0x08af4edb , 0x8a7bc50b , 0xaffec9c6 , 0x87d8e685 , 0x7a4eac71 , 0xa87d3e38 , 0xc15b4967 , 0x0c94b387 ] )
+  , Mont::unsafe_make( [ 0xa3fff784 , 0xc54519b4 , 0xc95286e8 , 0xe38717b1 , 0xccfcccc4 , 0x2f45ee0c , 0xaac9f0ac , 0x16fed0fa ] )
+  , Mont::unsafe_make( [ 0x7b9faa51 , 0xced4c52d , 0x0e4037f8 , 0x52b922bb , 0xfede794d , 0x84ca34a4 , 0x560cbdfa , 0x1540ed67 ] )
+  , Mont::unsafe_make( [ 0xab401b75 , 0x949dd702 , 0x35b7dc3b , 0xb1259476 , 0x84a572f1 , 0xe212736b , 0x5a1d0514 , 0x20e03ca5 ] )
+  , Mont::unsafe_make( [ 0xc4c2b070 , 0x1c6df74f , 0x014a5969 , 0xe43bc192 , 0x3f7f1ab7 , 0xfbf5179f , 0xdf8bbe2b , 0x11d37599 ] )
+  , Mont::unsafe_make( [ 0xa2af89c6 , 0x3454558c , 0xb4cc6953 , 0x32da7ce7 , 0xf85f5c01 , 0xb09fcf1a , 0xf017eb2e , 0x2724404d ] )
+  , Mont::unsafe_make( [ 0x1ed4e855 , 0xae578369 , 0x05a23c0c , 0xb3356d13 , 0x6651037b , 0x65a0cf61 , 0x7f6b7524 , 0x1050ef16 ] )
+  , Mont::unsafe_make( [ 0xa03181d1 , 0xbaeeabeb , 0x41dabcd1 , 0xa8de5513 , 0x3804600e , 0xfd4c81c6 , 0x8d6affb0 , 0x2a485149 ] )
+  , Mont::unsafe_make( [ 0xe550f745 , 0x81332633 , 0x2c8302b4 , 0xa4cac56e , 0xb34107a3 , 0x070c4c88 , 0xc3919d74 , 0x0aeb6f18 ] )
+  , Mont::unsafe_make( [ 0x591089bd , 0xc94d5b13 , 0x71186604 , 0x265a93a4 , 0xc1c1b758 , 0x247aee91 , 0xf2438cb5 , 0x0a236fb8 ] )
+  , Mont::unsafe_make( [ 0xa2ac6b7c , 0x336335aa , 0x1a101eb5 , 0x9bda6571 , 0x231e53ed , 0xd94aa114 , 0x49113f84 , 0x21688ad0 ] )
+  , Mont::unsafe_make( [ 0x62955a8c , 0x6d415b81 , 0xb8f38903 , 0xd62f4c9f , 0xae3d7e7b , 0xac34bf51 , 0x49708b72 , 0x1d4db942 ] )
+  , Mont::unsafe_make( [ 0xd4b58343 , 0x789b0f67 , 0x7710c157 , 0x8b952395 , 0x520bf200 , 0xaeefdbf8 , 0xbb63ab6d , 0x0545e1b5 ] )
+  , Mont::unsafe_make( [ 0xbf23bf65 , 0xa2184432 , 0x062a7051 , 0x941053b0 , 0xad69847a , 0x36503093 , 0xc9b61668 , 0x0cdce7f9 ] )
+  , Mont::unsafe_make( [ 0x36a71196 , 0x71aef0ba , 0xae51c0a9 , 0x32046dce , 0xfb8d68c7 , 0x8e9e2772 , 0x972d1974 , 0x24e121c8 ] )
+  , Mont::unsafe_make( [ 0x7244a44d , 0x3e759544 , 0x35d48363 , 0x650eabd5 , 0x2eee166f , 0xf75ed5ae , 0x6161c652 , 0x006d50f4 ] )
+  , Mont::unsafe_make( [ 0x0030199b , 0x97d0011a , 0xa79a778f , 0x56a9f1e9 , 0x9d60ad45 , 0x7723b30d , 0xa12ec972 , 0x139659d2 ] )
+  , Mont::unsafe_make( [ 0x3a8b51d3 , 0xad2cdf87 , 0xccd877cf , 0x84e2c952 , 0xef90349f , 0xedf2ca7b , 0xbd8404b0 , 0x00d4b964 ] )
+  , Mont::unsafe_make( [ 0x2f5944c8 , 0x34fb5c59 , 0xe31719a1 , 0x3ea4deef , 0x29eeefe3 , 0x3a6db253 , 0x1380ee2e , 0x101da1fa ] )
+  , Mont::unsafe_make( [ 0x6fbade3c , 0xdfd4869f , 0xc4545ad8 , 0x6c302f55 , 0xb6bd047f , 0x1e2b047c , 0xdb905e41 , 0x0e3e9799 ] )
+  , Mont::unsafe_make( [ 0xa4b8e5d7 , 0xc1bd80b1 , 0xf236eb03 , 0xcfb5e812 , 0x9b390bd9 , 0x8a32dfa1 , 0x6ef96aef , 0x0e834f67 ] )
+  , Mont::unsafe_make( [ 0x1206a53a , 0xabb7570a , 0x455690cf , 0x82b657a9 , 0xec9abb13 , 0x544419d4 , 0x20bd4479 , 0x0964e052 ] )
+  , Mont::unsafe_make( [ 0x07ea402d , 0x1a84ef2b , 0x096fbd7d , 0x3332a494 , 0x00e0c58a , 0x8dbe4003 , 0x3a9c5934 , 0x2fa14a8e ] )
+  , Mont::unsafe_make( [ 0xccb36e67 , 0x5be07dd5 , 0x6a227f7f , 0x8d0c580d , 0xd08e836d , 0x659c2549 , 0x919eec84 , 0x03733ead ] )
+  , Mont::unsafe_make( [ 0x41e57505 , 0x9d61fab8 , 0xa4284a25 , 0x9e570103 , 0x03cabe48 , 0x39f097d8 , 0x5f4d366f , 0x0a07d639 ] )
+  , Mont::unsafe_make( [ 0xc9262b24 , 0xb1448566 , 0x8841972c , 0x17e4d720 , 0x96a1d4be , 0xfbeeb19c , 0xc9828eda , 0x195d0741 ] )
+  , Mont::unsafe_make( [ 0x04e487e9 , 0xdbe2aa76 , 0x26330127 , 0x1af9f6ad , 0xf5828a76 , 0x60e9a6af , 0x9be9bd99 , 0x1df92759 ] )
+  , Mont::unsafe_make( [ 0x5d2afda0 , 0xb50a33cc , 0x88b2b9d7 , 0xba822ed9 , 0xf08b348b , 0xc6d6597e , 0x1a0f29a4 , 0x15684d15 ] )
+  , Mont::unsafe_make( [ 0xd362341a , 0x189ecbc0 , 0x901c277a , 0x21beaa62 , 0xb78ab48c , 0x3e04c7ce , 0x57845765 , 0x1f0da8fe ] )
+  , Mont::unsafe_make( [ 0x5cf4263b , 0x7737ceab , 0x60a03b5e , 0x0a9ce074 , 0x2ce985a9 , 0x9653c880 , 0x6a3b825c , 0x2b290560 ] )
+  , Mont::unsafe_make( [ 0x73dcae43 , 0xbfc21dfa , 0x81299688 , 0x747e06d9 , 0x3eec1bf5 , 0x2ee69090 , 0x5f664a98 , 0x0a02dbfd ] )
+  , Mont::unsafe_make( [ 0xf660825d , 0x57efa3e4 , 0x4f09abcd , 0x2deddcd9 , 0x628f47a0 , 0x326146f1 , 0x9b05acf8 , 0x280acbd5 ] )
+  , Mont::unsafe_make( [ 0x02bcd7e8 , 0x955884d4 , 0x5baada9a , 0x463ff90e , 0x43816dd9 , 0x923bc1d4 , 0xbc977744 , 0x04125881 ] )
+  , Mont::unsafe_make( [ 0xcfecc7c6 , 0xf41f77ac , 0x3ea6e5b3 , 0xe5da30ca , 0x826c3a90 , 0x89966c29 , 0x74425574 , 0x29df1207 ] )
+  , Mont::unsafe_make( [ 0x62b5f28c , 0xcfa18e00 , 0xcc90bf60 , 0x5946563c , 0x5b16f9e3 , 0xb97af723 , 0x4b612e39 , 0x0a483157 ] )
+  , Mont::unsafe_make( [ 0x95a16315 , 0x9d96a6ca , 0x88dcb906 , 0x870cbbad , 0x5cc352bb , 0xe889de86 , 0x67c0a056 , 0x0ac91e84 ] )
+  , Mont::unsafe_make( [ 0x96898e24 , 0xe500d3f6 , 0xf4f2366d , 0xc9e5c9eb , 0xd5b98218 , 0x934cb1c3 , 0x81c1f3cc , 0x1e282fca ] )
+  , Mont::unsafe_make( [ 0xb63f1935 , 0x19444bba , 0x402a3c36 , 0x67db6d14 , 0xd1a997dc , 0x9e276e93 , 0x92d53730 , 0x2e75aadd ] )
+  , Mont::unsafe_make( [ 0xfc42501b , 0x199d3705 , 0x7614fc5a , 0x3f8ca18d , 0x589ae593 , 0xf6e89679 , 0xf8d61f6e , 0x0d3c4616 ] )
+  , Mont::unsafe_make( [ 0xffacf311 , 0xe79bf531 , 0x271b12ef , 0x015b9430 , 0xff924d65 , 0x8d0fdfd4 , 0x1f70f3c9 , 0x212bff82 ] )
+  , Mont::unsafe_make( [ 0x736fa748 , 0x7e801b33 , 0x65be09cb , 0x32965c82 , 0xdde3aa31 , 0x349a30ba , 0xe5cae9b2 , 0x18ec80fe ] )
+  , Mont::unsafe_make( [ 0xe64bd8b3 , 0xde06baf9 , 0x75c50d6f , 0x3c0355a5 , 0x4d4db69e , 0x017fedb1 , 0x2f186012 , 0x1b4d2f28 ] )
+  , Mont::unsafe_make( [ 0xd237385c , 0x81d89fc5 , 0xb233d894 , 0xcbd7b48a , 0x1666bc38 , 0xa222a970 , 0x69094208 , 0x1d776b85 ] )
+  , Mont::unsafe_make( [ 0x431e1f0c , 0x9b8a9fc2 , 0x0316c8dc , 0x3444d518 , 0xbfff79d7 , 0x24359cbf , 0x0db64745 , 0x1d4d95be ] )
+  , Mont::unsafe_make( [ 0xab8992cf , 0xc1973fd7 , 0x739cb5bf , 0xe21efbc7 , 0xed505a83 , 0x85c6b069 , 0x8fc9ba73 , 0x2a577973 ] )
+  , Mont::unsafe_make( [ 0xe2e28d66 , 0x4ba27cb7 , 0x9c54b47f , 0xfb58107a , 0x93a1ef1f , 0xe94d09ef , 0x4ed97e94 , 0x1e0bc6d7 ] )
+  , Mont::unsafe_make( [ 0xb1749069 , 0x086a4a91 , 0x4da7466c , 0x0274e3e4 , 0x6693d3be , 0x93771232 , 0x9354f560 , 0x0c1b5a96 ] )
+  , Mont::unsafe_make( [ 0x2a44f95a , 0x90bf08c9 , 0x27863231 , 0x5f28600d , 0xb8f33d4a , 0x4a6ee7c2 , 0x016e9d9d , 0x115740d7 ] )
+  , Mont::unsafe_make( [ 0xb76e13ea , 0x5e7402ac , 0x74adc6b2 , 0x4c607c70 , 0x28592bfe , 0xd2b31eff , 0x4906581b , 0x27b2b4a3 ] )
+  , Mont::unsafe_make( [ 0x9b02a88f , 0x14f010ff , 0x64b1d9ff , 0x7cfc964c , 0xe53af5d9 , 0xf12e963c , 0xfd2edeec , 0x0ae4cd3c ] )
+  , Mont::unsafe_make( [ 0x5175f240 , 0x5a6785eb , 0xc3e5b838 , 0x3a92cacb , 0xeef7dc00 , 0x4c76f0a1 , 0x7689228d , 0x17d02524 ] )
+  , Mont::unsafe_make( [ 0x2484fc45 , 0x3530ebfa , 0xde86e8ea , 0xcf1887ed , 0x7e08ea59 , 0x60b76d23 , 0x5feb267d , 0x036f3062 ] )
+  , Mont::unsafe_make( [ 0xfc3f25e9 , 0x311164a5 , 0xaa9d9cb6 , 0xd2371a5e , 0x4511da12 , 0x62af3ebf , 0x42d4153c , 0x0932d3af ] )
+  , Mont::unsafe_make( [ 0xcd5bba8a , 0x7cf4b7fc , 0x73cb5bd9 , 0x29052dad , 0x57729bab , 0xa917764c , 0xf6a38752 , 0x2b665fdb ] )
+  , Mont::unsafe_make( [ 0x4ea63924 , 0x826e4da2 , 0x9aa1955e , 0x16b8bf6b , 0xd96bae0d , 0xeae08d7e , 0xe10a3650 , 0x23826acb ] )
+  , Mont::unsafe_make( [ 0x8d93a1bd , 0x4bcc25a1 , 0x67f5ce87 , 0xf5695ea6 , 0x0a0fa89c , 0xcd63d018 , 0x1b554b0b , 0x2ac613d3 ] )
+  , Mont::unsafe_make( [ 0xe5866fe7 , 0x1a78bbcf , 0x9649a5d0 , 0x27564d6e , 0x8e3cdc31 , 0x5809965c , 0x220a427b , 0x1c1aebd8 ] )
+  , Mont::unsafe_make( [ 0xe08a56f8 , 0x617d2ef4 , 0x4b4518cf , 0x51096917 , 0x7c000ab5 , 0x76edd0b3 , 0x3566210d , 0x00268957 ] )
+  , Mont::unsafe_make( [ 0xe02fb5d0 , 0x31a7d056 , 0x4647734b , 0x9ec6748a , 0x3137c664 , 0xf4dbf319 , 0x0742ecbf , 0x097eb2c1 ] )
+  , Mont::unsafe_make( [ 0xe774d7b9 , 0x60f347a8 , 0x8af9d55d , 0x21d00910 , 0xb6e71031 , 0x4c2b8227 , 0x41437ee9 , 0x225191e3 ] )
+  , Mont::unsafe_make( [ 0x064b97e1 , 0x890ac07f , 0xeafca1e0 , 0x50123d0c , 0xfc889805 , 0x18577774 , 0x4373db67 , 0x27694672 ] )
+  , Mont::unsafe_make( [ 0xbcceac6d , 0x47517979 , 0xfe7203a7 , 0x35854c61 , 0x7d37fc4e , 0xb05b1bd7 , 0x4a53425c , 0x14c58152 ] )
+  , Mont::unsafe_make( [ 0x80d10b8f , 0xd84381ed , 0x48651046 , 0x55f87614 , 0x26d80070 , 0x6f4de8bc , 0x2852bf13 , 0x2511bc54 ] )
+  , Mont::unsafe_make( [ 0x70f965db , 0x500adff2 , 0xab5110cc , 0x0f064724 , 0xb31514d8 , 0xf4c00dda , 0x57e8c374 , 0x08a3a77a ] )
+  , Mont::unsafe_make( [ 0xee244e59 , 0xd013eefa , 0x6ddd5738 , 0x4599d46e , 0xebaa6efc , 0x9cd172ea , 0xf3fe4dae , 0x109cb15a ] )
+  , Mont::unsafe_make( [ 0xf6497919 , 0x0a515c34 , 0x68f92fbd , 0x3a9d1fbd , 0x6940fbe5 , 0x792a4c5e , 0xdd9a1e30 , 0x1719a18a ] )
+  , Mont::unsafe_make( [ 0xb42a7b1f , 0x32854877 , 0x64510adb , 0x4ef41cf7 , 0x78020f75 , 0x1507884c , 0x317e5595 , 0x164061ad ] )
+  , Mont::unsafe_make( [ 0x43335172 , 0x4636ca74 , 0xe9c3d01c , 0xe2fb924b , 0xd9928c72 , 0x7b4e74dc , 0xa98fd182 , 0x1f8db2b6 ] )
+  , Mont::unsafe_make( [ 0xcbc803f5 , 0x1bf94a0b , 0x86e250e1 , 0x9665fa76 , 0x2d4aabff , 0x0419c244 , 0x4d766107 , 0x157757e0 ] )
+  , Mont::unsafe_make( [ 0x1f4739f7 , 0xe6c78970 , 0x0ce0cb9c , 0xc7881220 , 0xbb33d72a , 0x5bedba10 , 0x78c2f283 , 0x03f8b196 ] )
+  , Mont::unsafe_make( [ 0xdd52aab0 , 0x38447d19 , 0xb364a372 , 0x29ace0b8 , 0x9450e7dd , 0x70148ef6 , 0xc69676c7 , 0x00a725cd ] )
+  , Mont::unsafe_make( [ 0x46852a56 , 0x630fcf22 , 0x8901bcd5 , 0xdd546007 , 0x0301afc1 , 0xf38438d1 , 0xc21ad163 , 0x00de097e ] )
+  , Mont::unsafe_make( [ 0xae038107 , 0x83d844af , 0xce0301d6 , 0x9c2f3742 , 0xacdf2248 , 0x958c97fd , 0xc767d6eb , 0x151803f8 ] )
+  , Mont::unsafe_make( [ 0xf0babf50 , 0xb2ecd0a7 , 0x05f3c7bd , 0x40e08911 , 0x91400511 , 0x30dc058f , 0x5f78e310 , 0x23f0a1a3 ] )
+  , Mont::unsafe_make( [ 0xc10b98e9 , 0x9771a6f3 , 0xb320b333 , 0x91f4edc9 , 0xe7196da1 , 0xefd65a8a , 0x10261eb7 , 0x14c1f289 ] )
+  , Mont::unsafe_make( [ 0x81f5df67 , 0x8a2065a3 , 0xbae418e3 , 0x504b288a , 0x35a49f9e , 0x5607b2a8 , 0x96f6f882 , 0x0be07b3c ] )
+  , Mont::unsafe_make( [ 0xce6042e9 , 0x104061fc , 0x050e5b23 , 0xdc4167ec , 0xda9f99dd , 0x754793a1 , 0x652251dd , 0x1de26886 ] )
+  , Mont::unsafe_make( [ 0x488730ad , 0x56634362 , 0x17a90a95 , 0x27fe482a , 0x590bd683 , 0x0674dbc6 , 0xc8326327 , 0x0e8aceca ] )
+  , Mont::unsafe_make( [ 0x2c0fcf9c , 0xed691e74 , 0x14956590 , 0xf5f9b7f7 , 0xae5f53d2 , 0xf15cc153 , 0xfc096a56 , 0x038541d8 ] )
+  , Mont::unsafe_make( [ 0x3b4f7349 , 0x92150316 , 0x5927400d , 0xfdfa81ca , 0xd74295eb , 0xc8b188cb , 0xe23b6252 , 0x04242dcd ] )
+  , Mont::unsafe_make( [ 0x5a13629b , 0x32e4bc56 , 0x5b652020 , 0xeac7802a , 0x4e44383f , 0x7e2ae44e , 0xdaa8e55c , 0x1e06c149 ] )
+  , Mont::unsafe_make( [ 0xeb98c4ef , 0x2fb13c9a , 0x2979f270 , 0x3e6785a5 , 0x05157e24 , 0x5d7bea4a , 0x4abbb63e , 0x10461372 ] )
+  , Mont::unsafe_make( [ 0x6a9359bd , 0xb1071137 , 0x5179fc0f , 0x3cc8fc2b , 0xa2f52ad0 , 0x869eaed7 , 0xf11f0a26 , 0x1d0d0a4e ] )
+  , Mont::unsafe_make( [ 0x0982cfeb , 0xce71ca93 , 0xf1b7dc1c , 0x0c7060d0 , 0x1c235f18 , 0x3f26d5c5 , 0x443831f6 , 0x24d8b264 ] )
+  , Mont::unsafe_make( [ 0x69d57344 , 0xddf050a2 , 0x2e5eab79 , 0x5fa5695f , 0x124e15d2 , 0x5a5eadf8 , 0x600a5797 , 0x21220a10 ] )
+  , Mont::unsafe_make( [ 0x7ca1f8f9 , 0x72b3c860 , 0xbc54ea2b , 0x686de6e5 , 0xaf6e8457 , 0x123cb90b , 0x0b5c6ca0 , 0x1229e332 ] )
+  , Mont::unsafe_make( [ 0x16fa3122 , 0x7cc096d4 , 0x5fa63b16 , 0x7c3de420 , 0x8f347eac , 0x709c0846 , 0x285fc1e9 , 0x179b0b0f ] )
+  , Mont::unsafe_make( [ 0x341b024e , 0x5614e671 , 0xde999469 , 0x750c150f , 0xfc8883aa , 0xe3f89b9c , 0x71c2632e , 0x0c77c854 ] )
+  , Mont::unsafe_make( [ 0xe39d4dd6 , 0x3d120292 , 0xea055e24 , 0x95412708 , 0xd18c22c7 , 0x34e1223d , 0xf20746b5 , 0x10b7e2ed ] )
+  , Mont::unsafe_make( [ 0xd2e8659c , 0x8b92454a , 0x4e2cf12c , 0xa65727db , 0x4fa6e157 , 0x23b08db5 , 0x859921a2 , 0x18c6c104 ] )
+  , Mont::unsafe_make( [ 0x6d05bb07 , 0x0cdfcb10 , 0xf58d6be8 , 0x4ac6b49f , 0x3b5750b4 , 0xebedf586 , 0x2f8123fb , 0x2411f5cc ] )
+  , Mont::unsafe_make( [ 0x1b76de79 , 0x812910c0 , 0x04c4b616 , 0x4e80f0df , 0xd8270675 , 0xc8bc0eea , 0xe8b02448 , 0x0edf8ef7 ] )
+  , Mont::unsafe_make( [ 0x0f1522ef , 0xd9cc83e5 , 0x1ef4a03c , 0xc68a1d5c , 0xed701904 , 0x88f7742a , 0x7abbff0b , 0x28ab8c1d ] )
+  , Mont::unsafe_make( [ 0xd5d7e78d , 0x051fb0ef , 0x6c946827 , 0xecb45f5c , 0xf3a12b08 , 0x4b2b765b , 0x09f5fe4f , 0x27cc8b76 ] )
+  , Mont::unsafe_make( [ 0xd76889d9 , 0x919376f3 , 0xa54d530d , 0xc9ea9aea , 0x395ef7ca , 0xbd401b47 , 0xcdad1954 , 0x1b0cb4ae ] )
+  , Mont::unsafe_make( [ 0xa1db30e6 , 0x2498e374 , 0x582a2be9 , 0x0ad48259 , 0x0a64a5e7 , 0x668fa17b , 0x44e1c993 , 0x2c9147e7 ] )
+  , Mont::unsafe_make( [ 0x2152dbad , 0x6ee9f7cf , 0x8540f06d , 0x93731410 , 0x79ac3f62 , 0xcade3fd9 , 0x03890dc7 , 0x28b1aa00 ] )
+  , Mont::unsafe_make( [ 0x9512d677 , 0x8c212a89 , 0x2f016fb9 , 0x505ae281 , 0x2b791150 , 0x005fe44c , 0x3c695a11 , 0x225129e7 ] )
+  , Mont::unsafe_make( [ 0x8b897095 , 0xe28029a4 , 0x2730537e , 0x3a0c0622 , 0xcf66e8c3 , 0x42f7f0c8 , 0xbc046986 , 0x05238f6d ] )
+  , Mont::unsafe_make( [ 0x0d48b316 , 0x64e3fd53 , 0xcaa32229 , 0x7cea665f , 0x279ec907 , 0x9539c13f , 0xc87d9e07 , 0x2f06f3d6 ] )
+  , Mont::unsafe_make( [ 0xe57c68ef , 0x6adf7fd8 , 0x5c1df8fc , 0xd31a3d48 , 0xaefa6d1c , 0x78158d3c , 0xdf44d4f8 , 0x2c9ed8d2 ] )
+  , Mont::unsafe_make( [ 0x6c9c3ae4 , 0xa29f8416 , 0xfa545d00 , 0xb58012cb , 0x9ee6e028 , 0x93d03315 , 0x313ec7b7 , 0x155e930c ] )
+  , Mont::unsafe_make( [ 0x3b79d75e , 0xad932f45 , 0xbddfd34e , 0x7ecc6c3b , 0xb1c93402 , 0xf332bc69 , 0x74c08928 , 0x26e7fa48 ] )
+  , Mont::unsafe_make( [ 0x15f1a4ca , 0x84b925a7 , 0xaf27d5c8 , 0xd943f01d , 0xf6f10517 , 0xce2a445e , 0x18de64a1 , 0x0a5663eb ] )
+  , Mont::unsafe_make( [ 0x9ab8a744 , 0xf2f26d7b , 0x013288ad , 0x2f705b67 , 0x6e378263 , 0xfa76ba94 , 0xb1c0cc47 , 0x211d15ab ] )
+  , Mont::unsafe_make( [ 0x25acdf9e , 0xfd428ec9 , 0x10602886 , 0x10a6ffe8 , 0xf6e630a8 , 0xd726b9bd , 0x50002a65 , 0x202b1575 ] )
+  , Mont::unsafe_make( [ 0x289308ad , 0x9698157e , 0xbe68a1e0 , 0x589b5b8d , 0x2e0f1f31 , 0xb3ec1feb , 0x8b2c096e , 0x00b8b7e4 ] )
+  , Mont::unsafe_make( [ 0x6ca78378 , 0x93b41a1e , 0x79d927a6 , 0x6d9223ce , 0x26024487 , 0xd363fbc1 , 0x9660e7d8 , 0x04b9f1df ] )
+  , Mont::unsafe_make( [ 0xa856dd01 , 0x564071fb , 0x868c48cc , 0xaef36362 , 0x314441a1 , 0xa82093da , 0x0ee19262 , 0x2bd92c39 ] )
+  , Mont::unsafe_make( [ 0x9ec52e76 , 0xceb00b6a , 0x5f7cce73 , 0x18148f30 , 0x78da4f61 , 0x5adc0b20 , 0xbe7b6897 , 0x25ca1d8b ] )
+  , Mont::unsafe_make( [ 0x75ec21e9 , 0x02bd756b , 0x02782f5d , 0x5777067c , 0x02661fba , 0x1fb5a1d6 , 0x7ec648c8 , 0x0366c535 ] )
+  , Mont::unsafe_make( [ 0xb5fd9019 , 0xecc0eeb9 , 0xd94ec3f4 , 0x93f5fe35 , 0x726a94fa , 0xba973518 , 0x7e002857 , 0x2c714465 ] )
+  , Mont::unsafe_make( [ 0xe37f4ccb , 0x05dc1d11 , 0x359fd0bc , 0x6e88d1e9 , 0xe6f62fc3 , 0x955da295 , 0xf7e6f48e , 0x2b6de761 ] )
+  , Mont::unsafe_make( [ 0x33167126 , 0x1420a318 , 0x703575b0 , 0x6ec1c911 , 0xc598a276 , 0x6b46288d , 0x342ddc63 , 0x188853e3 ] )
+  , Mont::unsafe_make( [ 0xd6cf84db , 0x18566113 , 0x611710af , 0x992c57e7 , 0x16487151 , 0x046d18ff , 0x5f9908e4 , 0x26f2d54f ] )
+  , Mont::unsafe_make( [ 0x71c59421 , 0x897f0b31 , 0x400c1c4d , 0x8bfc8764 , 0xcaf36066 , 0x3d020319 , 0xa904b60c , 0x24cedf36 ] )
+  , Mont::unsafe_make( [ 0x73c4271c , 0x9b7c724d , 0x2b606e22 , 0xfc5e6c90 , 0x5482b699 , 0xa6d9babf , 0xa11bd23c , 0x157557bd ] )
+  , Mont::unsafe_make( [ 0x2c224a30 , 0xddccdc2e , 0x03c1eafe , 0xe573d894 , 0x1a981d1f , 0x94ff68df , 0x420756d6 , 0x1f3a9eec ] )
+  , Mont::unsafe_make( [ 0x704c78ba , 0x320a2b63 , 0x8c2355bb , 0x1b39a877 , 0xce6e9094 , 0x189a59e7 , 0xcbf44223 , 0x0afec5cb ] )
+  , Mont::unsafe_make( [ 0xce2e06ce , 0xff36ffd5 , 0x347972fe , 0x1ebfa4a2 , 0x1220871f , 0x227ac647 , 0x2ae2bcf1 , 0x16e74b79 ] )
+  , Mont::unsafe_make( [ 0xae4aefe6 , 0xf6b78c1f , 0xa059e5b5 , 0xb51837f0 , 0x53bd24c4 , 0x5499cb52 , 0x8aed3479 , 0x145f549f ] )
+  , Mont::unsafe_make( [ 0x7b917585 , 0xfdfb31d1 , 0x3b45145d , 0x5e47ce57 , 0x5d47ea43 , 0x2553e230 , 0xf643e18b , 0x0dc40ad7 ] )
+  , Mont::unsafe_make( [ 0xde5ba752 , 0x746b52ea , 0x34497ab1 , 0x023cf179 , 0xbaf7bf55 , 0xb6caa532 , 0xa42a734c , 0x20e5107e ] )
+  , Mont::unsafe_make( [ 0xb26bc39b , 0xc6092dde , 0xd57b63c4 , 0x685108a2 , 0x35d297dd , 0xae8e2bd0 , 0x0e2769e4 , 0x1c77f9ce ] )
+  , Mont::unsafe_make( [ 0xacf0266e , 0x4d91e819 , 0x00391fb1 , 0x8f624185 , 0x00079ac3 , 0x26d4b9cb , 0xbc015fab , 0x03f936d1 ] )
+  , Mont::unsafe_make( [ 0xbbd8e3a2 , 0x540848f1 , 0xf9de0f1e , 0xa1d6bf0e , 0xc8fc4b54 , 0x6ddd5449 , 0x1a26c1b5 , 0x26f2d014 ] )
+  , Mont::unsafe_make( [ 0x50e031f0 , 0xe125bfee , 0x9d50a2a8 , 0x573c985f , 0x1250a560 , 0xf3b05810 , 0x3bd4ce5c , 0x018f4de8 ] )
+  , Mont::unsafe_make( [ 0x6a647bcd , 0x6d5a4dc4 , 0x8690a261 , 0xbbf2eee4 , 0x13501986 , 0xb51077f9 , 0xbb469d92 , 0x22a952b7 ] )
+  , Mont::unsafe_make( [ 0x0fac05a4 , 0x23378037 , 0xb837f0fe , 0xbb4d11d0 , 0x0f27ecac , 0x10764901 , 0xcb2d9345 , 0x2cec45c1 ] )
+  , Mont::unsafe_make( [ 0xf5e49a5d , 0x33b5599b , 0xf1eb24be , 0x07f904fa , 0x120749cf , 0x3fab9a86 , 0x6f4913e0 , 0x071a2b27 ] )
+  , Mont::unsafe_make( [ 0x4a8d8655 , 0x0a604194 , 0x37fa1773 , 0xfd075cf5 , 0xe3afbf70 , 0x8021f4e7 , 0x666d0d1b , 0x22a64e3e ] )
+  , Mont::unsafe_make( [ 0x406cd8d2 , 0x322d37b0 , 0xc41d5ce7 , 0xbd679721 , 0x0eb39e69 , 0x6826180d , 0xe73b12e9 , 0x1226d6db ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// circomlib's optimization whatever
+pub const CONST_S: [Mont; 1625] = 
+  [ Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xba807bd5 , 0x16364f28 , 0xd2827763 , 0x76a684b4 , 0x2f4b50b2 , 0x8bb339de , 0xbbd71d24 , 0x302dd576 ] )
+  , Mont::unsafe_make( [ 0xe42d571a , 0x1be26c10 , 0x69ca384b , 0x46ffc903 , 0x358f8d79 , 0x94dc7d8f , 0x2aa98f3b , 0x30053d0e ] )
+  , Mont::unsafe_make( [ 0x5d97a833 , 0x60435e10 , 0x6feeb578 , 0xd9a34e2f , 0x37f11884 , 0x4ca0a2e9 , 0x70523aa2 , 0x0a9da78a ] )
+  , Mont::unsafe_make( [ 0x016fd2cd , 0x2ed89231 , 0x2087e5b6 , 0x30b9c252 , 0x297fd7d7 , 0xa1329e86 , 0xb897a6af , 0x1b2bf9b8 ] )
+  , Mont::unsafe_make( [ 0x041516f8 , 0xb48e0734 , 0x234922a5 , 0x7e507cb4 , 0x9447b9dd , 0xc2e45a4b , 0x5be44ac4 , 0x259b8b62 ] )
+  , Mont::unsafe_make( [ 0xe86d82d5 , 0x6c5fdaf6 , 0xccbe6f8d , 0x00e2ad92 , 0x787a129f , 0xb82dfd62 , 0x471b28db , 0x2d1ba8c9 ] )
+  , Mont::unsafe_make( [ 0x5c38f26c , 0x58db1bef , 0x01a64dc6 , 0xec38fdf3 , 0x6ac379ea , 0xbc8ae241 , 0x42b57400 , 0x19fdaf40 ] )
+  , Mont::unsafe_make( [ 0x28b4c871 , 0x07688be2 , 0x4df14e5a , 0x2a0e0cf5 , 0x8c56da65 , 0xd6d6153f , 0xbc7646b4 , 0x216dbb8d ] )
+  , Mont::unsafe_make( [ 0x3e33a6e7 , 0x508b05e7 , 0x49eb24ea , 0xa78a5bef , 0x844b616c , 0x46f3e2c8 , 0x1a9cb5a1 , 0x0fc06322 ] )
+  , Mont::unsafe_make( [ 0xf7566445 , 0x01061d2c , 0x6283525b , 0xe87032c8 , 0x2e484c50 , 0x3dace030 , 0xdc17d6b4 , 0x205692b0 ] )
+  , Mont::unsafe_make( [ 0x36f14e11 , 0x57ee9d0d , 0xb2492e06 , 0x59b1c2a8 , 0x1c5c0399 , 0x0dda4fdf , 0xbab6faf2 , 0x0dbcba53 ] )
+  , Mont::unsafe_make( [ 0x734dc740 , 0x56477475 , 0xb96796a0 , 0xd9cbfac4 , 0x4d3440e6 , 0xccfa7ab1 , 0xe1c89106 , 0x05ad9994 ] )
+  , Mont::unsafe_make( [ 0xcc83953f , 0x0f9b44cb , 0x4ea94c25 , 0xec42c827 , 0x02ed262f , 0xba97eb1d , 0xdc0e4f4a , 0x1ad48d03 ] )
+  , Mont::unsafe_make( [ 0x271bc5a3 , 0xa4f1afce , 0xaa8cf60a , 0x454ab29d , 0x6074a3fa , 0x06d4d3d6 , 0x43b80d3e , 0x263f83bd ] )
+  , Mont::unsafe_make( [ 0xad343592 , 0x9effa353 , 0x42b565d1 , 0x4f496cb6 , 0x79ade63c , 0x51dbeaf2 , 0x8d7738a5 , 0x1366d174 ] )
+  , Mont::unsafe_make( [ 0xa42b48f8 , 0x468bea3e , 0xdbe19de3 , 0xaf4bfe01 , 0xa8541281 , 0xdeecd1c6 , 0xfb875e93 , 0x03094d6b ] )
+  , Mont::unsafe_make( [ 0x6e9ed2f9 , 0x0537e0a2 , 0xf0df4392 , 0xd4dcf6c3 , 0xd3a5b64b , 0xc915df92 , 0xa356c4d5 , 0x20b5d988 ] )
+  , Mont::unsafe_make( [ 0x6eb5ba3b , 0x2ad7d480 , 0x89fec2df , 0xd768207b , 0x6929a104 , 0xe4f9c6c1 , 0x4739e5f2 , 0x14229a29 ] )
+  , Mont::unsafe_make( [ 0x450ed90e , 0xe5282065 , 0xb7306e42 , 0xac89d9e0 , 0xb50272f1 , 0x673df934 , 0x355e63c7 , 0x2f9d2f3c ] )
+  , Mont::unsafe_make( [ 0xeb8fd97c , 0xf747aa21 , 0x80a54b1a , 0x0e8c1b0e , 0xa5ee8206 , 0x21cc6523 , 0x6b469e92 , 0x211c39a4 ] )
+  , Mont::unsafe_make( [ 0x5c044e53 , 0xa3862171 , 0x298b638a , 0xa588d98d , 0x6b209232 , 0xfebd42ef , 0x0a047bae , 0x02a12775 ] )
+  , Mont::unsafe_make( [ 0x3b58b265 , 0xd2fe9e8b , 0xe67159f0 , 0xe0c2ec31 , 0x84a9b8ee , 0xa0ac7e3b , 0x59286c77 , 0x2386d0ce ] )
+  , Mont::unsafe_make( [ 0xb4388ad6 , 0x6c8ed2d1 , 0x1fcbeb7d , 0x26179db2 , 0x161c2730 , 0xd10683a3 , 0x95103039 , 0x12da9a96 ] )
+  , Mont::unsafe_make( [ 0x4cf3c00e , 0x4f794851 , 0x72fc0ba5 , 0x74adccde , 0x146574c8 , 0x78172284 , 0x54de5e89 , 0x29b05738 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xb52d2e1c , 0x62fe117c , 0xf5b71f97 , 0x5b8ea77f , 0xfa267634 , 0x9c51dc99 , 0xd77304bd , 0x033dc7cd ] )
+  , Mont::unsafe_make( [ 0x7b28a4f1 , 0x8df814f7 , 0x0aff3820 , 0x83bdf395 , 0xcb755b96 , 0xe91acc1a , 0x2e8bb6fa , 0x2caea376 ] )
+  , Mont::unsafe_make( [ 0x92748fc6 , 0x6d00cf63 , 0x81ae7097 , 0x41d2e981 , 0x50e78f8c , 0x4669fd97 , 0xe3e2aa4c , 0x2086202a ] )
+  , Mont::unsafe_make( [ 0x9ca6b932 , 0x77fbaaa3 , 0x8574c6e9 , 0x95f6a267 , 0x13fbcfe1 , 0x2d9f366b , 0x21d2f0d5 , 0x07deae31 ] )
+  , Mont::unsafe_make( [ 0xcdbd1656 , 0xaa382c85 , 0xdb23f571 , 0xe5adf8e0 , 0x7ab418ee , 0xa2c32ce2 , 0x4448a32c , 0x0e0fad7c ] )
+  , Mont::unsafe_make( [ 0x78e1f7df , 0xc3779874 , 0x76f4ff10 , 0x6e6707cf , 0x1ecc9f02 , 0x291b97c9 , 0x1e008a31 , 0x103af653 ] )
+  , Mont::unsafe_make( [ 0x4e71437f , 0x8d9be84c , 0x1ff8300b , 0x05b1f870 , 0xc611e17d , 0xeb4611c1 , 0x20e7def4 , 0x23e394bc ] )
+  , Mont::unsafe_make( [ 0xde2f276d , 0x486a2792 , 0xcb58af84 , 0x21b8424a , 0x5287b225 , 0x877a6db3 , 0xdd656d0d , 0x142df672 ] )
+  , Mont::unsafe_make( [ 0xb5954f8b , 0x53d6138e , 0xef1214cd , 0x8674ead1 , 0x75489757 , 0x354f9d45 , 0xbed298f6 , 0x2fbdedfc ] )
+  , Mont::unsafe_make( [ 0x1387b794 , 0xf7906b66 , 0x8c1d71f2 , 0xb0a6c738 , 0xe48deb9a , 0x2a622ae9 , 0x9b988513 , 0x273cc725 ] )
+  , Mont::unsafe_make( [ 0x3ed16df8 , 0x57bb1365 , 0xde16bd21 , 0x8730674b , 0x633b2bd6 , 0x4d9506cf , 0x75193fc2 , 0x267abe2c ] )
+  , Mont::unsafe_make( [ 0x59b07d6a , 0xbd621b9a , 0xe612ab1c , 0x31be9ba3 , 0xe64717a7 , 0x0ad6ee5e , 0x762d0eea , 0x2b0b2089 ] )
+  , Mont::unsafe_make( [ 0x6c9575bb , 0xd6536e19 , 0x36781f48 , 0x15e3786d , 0x6171ac5d , 0x556324f6 , 0x825ef289 , 0x17ac16af ] )
+  , Mont::unsafe_make( [ 0x5f739d5d , 0x65517318 , 0x06bb561f , 0xb576bdd2 , 0x4d9010ca , 0xed10f636 , 0x8f6506c5 , 0x1b9e4902 ] )
+  , Mont::unsafe_make( [ 0x55ac5303 , 0xec0d8566 , 0xe3731603 , 0xfc185df3 , 0x4b50e2fd , 0x7a10fe59 , 0xe13e95e9 , 0x1f9d32e0 ] )
+  , Mont::unsafe_make( [ 0x25ef6861 , 0x58e2ce14 , 0xdd5e6489 , 0x923449c2 , 0x9961f5b3 , 0x40222605 , 0xbf1f3922 , 0x2ae93bb6 ] )
+  , Mont::unsafe_make( [ 0x5a128664 , 0xef86ded8 , 0x0712ed75 , 0x968af0b5 , 0x3d0224a1 , 0xa5c8e989 , 0x3803293f , 0x22700494 ] )
+  , Mont::unsafe_make( [ 0x24682fbb , 0x3e0b61a2 , 0x3aabc477 , 0x0bf9ef74 , 0x4dbb4157 , 0xd9ede083 , 0xc57fcfd7 , 0x14eb20fd ] )
+  , Mont::unsafe_make( [ 0xbf134ab0 , 0x792a7e62 , 0x2dc38e4a , 0xb160dc66 , 0xc902f6ca , 0x44c1615d , 0x264a27ca , 0x0a0fcabc ] )
+  , Mont::unsafe_make( [ 0x990fdd60 , 0xa24b27fd , 0x0be7912d , 0x0b8d62d6 , 0xa4621886 , 0x927a7687 , 0x8cc86bc3 , 0x25ae2701 ] )
+  , Mont::unsafe_make( [ 0xa9d2b511 , 0x53ab121f , 0x2ee74864 , 0x5a313ac0 , 0x4a120cf9 , 0x96f3aebf , 0xa300a41e , 0x088e168c ] )
+  , Mont::unsafe_make( [ 0xb2731be1 , 0xf2c7eb61 , 0x8ba293e6 , 0xce586b9c , 0x42df8a2c , 0x1208c256 , 0x1578026d , 0x20428941 ] )
+  , Mont::unsafe_make( [ 0x96ee7115 , 0x003a42ed , 0xd0d04c7a , 0x30bf3c29 , 0xfe466e22 , 0xb5f16fb8 , 0x1803f257 , 0x10146a5c ] )
+  , Mont::unsafe_make( [ 0x9a6926f1 , 0x88a36745 , 0xa879e2d4 , 0xfa1bbe0b , 0x1aa74db7 , 0x0a30eeea , 0xf2f15f0a , 0x1f2e2fcf ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x6f8e0bfe , 0xf391f8fe , 0x9747b94c , 0x65a2b5df , 0x9ecbeba8 , 0xf28c3a33 , 0xe54b7d5a , 0x203f9d11 ] )
+  , Mont::unsafe_make( [ 0x946125a0 , 0x7994085e , 0x85ddc118 , 0x0e05f7ca , 0xb245d17e , 0x26e31610 , 0xa12024f8 , 0x25024589 ] )
+  , Mont::unsafe_make( [ 0x6b01fde7 , 0x5c199880 , 0xef7f16ec , 0xdf4ff680 , 0x0b203b83 , 0xb42bab09 , 0x55b785e5 , 0x29fdd0c2 ] )
+  , Mont::unsafe_make( [ 0x606fb217 , 0x054798ff , 0xc6af2e19 , 0x0b88a6e5 , 0xe810b21a , 0xc1614a08 , 0x2a092574 , 0x0ef0ae88 ] )
+  , Mont::unsafe_make( [ 0x58cd41f0 , 0x5d77819c , 0xf332b624 , 0xdadb944a , 0xe43f7fff , 0xc9198f82 , 0xdc1821f8 , 0x24d57598 ] )
+  , Mont::unsafe_make( [ 0x45fd6119 , 0xbd818f3b , 0x16035fb4 , 0xe8705ffe , 0x321072d6 , 0xb7b3f05e , 0x411a04d2 , 0x0ca214c0 ] )
+  , Mont::unsafe_make( [ 0x74b4c82e , 0x6dc2cbe5 , 0x50b1f89f , 0x16269891 , 0x72471e7a , 0x23b887df , 0x45ed84de , 0x08ca78f7 ] )
+  , Mont::unsafe_make( [ 0x80e56a33 , 0x570dc083 , 0xeead6176 , 0xc8ad1603 , 0x27f6761c , 0xef50337c , 0x78910050 , 0x11d0ccaf ] )
+  , Mont::unsafe_make( [ 0x660a1863 , 0x00c36056 , 0x18bec954 , 0x7a6b0bbc , 0x8a279f5b , 0xcb681644 , 0x476728d7 , 0x0505dd29 ] )
+  , Mont::unsafe_make( [ 0xfe4113c9 , 0x5187b03a , 0xc192f59e , 0x70decc11 , 0xde0cf748 , 0x78abcacc , 0xc86fee9e , 0x011b9665 ] )
+  , Mont::unsafe_make( [ 0x0e9e44bb , 0x15ddbbca , 0x31bdb7e0 , 0x85514498 , 0xcdfc89b0 , 0x637b70d7 , 0x673998b3 , 0x026f9446 ] )
+  , Mont::unsafe_make( [ 0x632122c0 , 0x7beaa551 , 0xfcb0ef11 , 0x5f937e67 , 0x458d21ec , 0x53f96889 , 0x3e987873 , 0x0bae711f ] )
+  , Mont::unsafe_make( [ 0xe52168ab , 0x00bab4de , 0xac68b3a8 , 0x1207ff84 , 0x62e510c2 , 0x7489a6ce , 0x82d7f051 , 0x2df7ec27 ] )
+  , Mont::unsafe_make( [ 0xc1985d8e , 0x7d959a76 , 0x3e3a77f6 , 0xfa688953 , 0x8db671a7 , 0xfcfcdde8 , 0x2326a79a , 0x118db8c4 ] )
+  , Mont::unsafe_make( [ 0x12200f1c , 0x07b469e3 , 0x21668706 , 0x1f65784d , 0x5d161f3f , 0xafe364e5 , 0xa12ff3af , 0x05e90979 ] )
+  , Mont::unsafe_make( [ 0x4073f98b , 0x32122f6b , 0xb8d6ffdc , 0xd6609c33 , 0x18e25e96 , 0x705ff660 , 0xb8bb539d , 0x1e7cc5ba ] )
+  , Mont::unsafe_make( [ 0xced9632a , 0xc4e80a7d , 0x9cc4daf1 , 0x109fb398 , 0x4dd47d36 , 0x3134cb94 , 0xa7501e7a , 0x097f830d ] )
+  , Mont::unsafe_make( [ 0xe72ed156 , 0x5f084741 , 0xf14e6b97 , 0x9f2be17c , 0x77c4298b , 0xae95912b , 0x83828e5f , 0x2c4dbec3 ] )
+  , Mont::unsafe_make( [ 0x0464349b , 0x95b3c903 , 0xa41fe5d3 , 0x101c15a8 , 0xafc38450 , 0xdbcf270d , 0xd6834729 , 0x1a6ecc07 ] )
+  , Mont::unsafe_make( [ 0x21b2fb9d , 0x44dd9a56 , 0x7687b4bb , 0x02267de9 , 0x0b263627 , 0xe11e6d5c , 0x25ae12e0 , 0x2eaf4054 ] )
+  , Mont::unsafe_make( [ 0x3fbf1dfa , 0xec65c9b2 , 0x55b64235 , 0x88e077ad , 0x9e3a1497 , 0xccc53e33 , 0xae7953fc , 0x022a470e ] )
+  , Mont::unsafe_make( [ 0xe42fc837 , 0x7c7c2f66 , 0xd5597754 , 0xfd3c741a , 0xce89833c , 0x748e30cc , 0xd6792ac7 , 0x0328ea6a ] )
+  , Mont::unsafe_make( [ 0x3382cf09 , 0xf79f8ffe , 0xed245d74 , 0x1c028f9d , 0x2c2ddd25 , 0xb856bf82 , 0x3a68ed28 , 0x114d7426 ] )
+  , Mont::unsafe_make( [ 0x0b33c33c , 0x9831ffcd , 0xedf2fba8 , 0xc14edab9 , 0xc3fb402f , 0x6f8c8997 , 0xadd3de31 , 0x2abce08c ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xc09bca02 , 0xe31fa9c0 , 0x10aee9ad , 0xb315e84e , 0x6e0ce69d , 0xa179a6df , 0x706509e5 , 0x241209cd ] )
+  , Mont::unsafe_make( [ 0x17b1fbaa , 0xe76388f6 , 0x4af304af , 0x1504db18 , 0xe88e2836 , 0xd76dbf69 , 0xfb43abb1 , 0x0bea9073 ] )
+  , Mont::unsafe_make( [ 0x5d5b4268 , 0x670a82f3 , 0xf06c2da8 , 0x33fb5c1d , 0x4d20e1e1 , 0xd90d192c , 0x9eb2b57e , 0x00d44f27 ] )
+  , Mont::unsafe_make( [ 0x50091fdf , 0x3954e916 , 0x7dc51b0d , 0x2400010e , 0x5c373e1b , 0x99ed4f16 , 0x1e17f756 , 0x039b1874 ] )
+  , Mont::unsafe_make( [ 0xbba8679a , 0xb182175f , 0xcf123d3f , 0xa6c54976 , 0xe7442ab6 , 0x23ab7bd7 , 0x3d7c00c3 , 0x0be29b09 ] )
+  , Mont::unsafe_make( [ 0x49c48b2d , 0x4fc41fb8 , 0xe869b76d , 0x44f0012d , 0xae4aaf76 , 0x34bbf09a , 0x227e757a , 0x188995f6 ] )
+  , Mont::unsafe_make( [ 0x0c7b9d1a , 0x0af80111 , 0xf943b1c8 , 0x9b4b1321 , 0x1671cad3 , 0x5720bff5 , 0x6bd1d425 , 0x14807f1b ] )
+  , Mont::unsafe_make( [ 0xb8404e98 , 0x88871313 , 0xc963f350 , 0xaef2d0db , 0xf87c0b8e , 0x5568ec8e , 0x503d3724 , 0x2f615a7e ] )
+  , Mont::unsafe_make( [ 0x1d48dcf8 , 0xe2160c1a , 0xa20f6a6d , 0x938f858f , 0xe94abcad , 0x9c7c5d79 , 0xda6aee51 , 0x273b45ef ] )
+  , Mont::unsafe_make( [ 0x4eed6c72 , 0x62142a6a , 0x29268b60 , 0x46fb7470 , 0x519303de , 0x79c32156 , 0xcf759acc , 0x27710155 ] )
+  , Mont::unsafe_make( [ 0x2b92d42b , 0x8c799f3b , 0x652927db , 0x4a65f034 , 0x48b54612 , 0x3e7ee63b , 0xaaa0f4fa , 0x0fe77bdc ] )
+  , Mont::unsafe_make( [ 0x4200df4c , 0xfaeec92c , 0x439d56f8 , 0x4b3e4f1e , 0x08125f55 , 0xffd6b10f , 0x160bab3a , 0x1c288131 ] )
+  , Mont::unsafe_make( [ 0xbc65be19 , 0xdaab2725 , 0x8b61b706 , 0x74a19eb2 , 0x4176f3ae , 0xe5b6729c , 0xefd8c9b0 , 0x0ff3a9ac ] )
+  , Mont::unsafe_make( [ 0xf00eef76 , 0x03e63e77 , 0xad791687 , 0x9b4a2f83 , 0xa695ca0e , 0x2bc9cc5e , 0x7b52799d , 0x2290faa0 ] )
+  , Mont::unsafe_make( [ 0x044afc23 , 0x19700481 , 0x14f965b7 , 0x5c3e79ef , 0xb4a15a8b , 0xb3459f0b , 0x45fc211a , 0x2df8af2f ] )
+  , Mont::unsafe_make( [ 0x8adf92d6 , 0x7e256191 , 0xfcb9b7d9 , 0xe5982df3 , 0x422e76f6 , 0x9ea82cd0 , 0x641bbd9f , 0x0bd5448a ] )
+  , Mont::unsafe_make( [ 0xb0208717 , 0x82fbef89 , 0x0b45773b , 0x7087cc1f , 0x99d48511 , 0xee501cdf , 0xa9e681f9 , 0x0aff4e2f ] )
+  , Mont::unsafe_make( [ 0x1e5e07fd , 0xe92d2211 , 0xb43bb27f , 0x9cda25dd , 0x5be84c33 , 0xd205e942 , 0x17d89dae , 0x2458da99 ] )
+  , Mont::unsafe_make( [ 0xd22b8309 , 0xf76e3a5f , 0xbe5fb7ac , 0xb7963b39 , 0x26122ea4 , 0x3b130bae , 0xe937d862 , 0x24773c9a ] )
+  , Mont::unsafe_make( [ 0x39068981 , 0xb1e2199e , 0xdbcbf7c2 , 0x84f5ec1e , 0x48bc080a , 0xbe828344 , 0x4d8e000e , 0x0d3c067e ] )
+  , Mont::unsafe_make( [ 0xfaedf2fb , 0x2bc0beba , 0x90a48139 , 0xa611a9a9 , 0xe34b355f , 0xd226c9c4 , 0x934c4358 , 0x26a541cd ] )
+  , Mont::unsafe_make( [ 0x9abb5235 , 0x7a8e8162 , 0xe809ecac , 0xfa25839e , 0xcd673bff , 0x399c8dde , 0x414b3083 , 0x28f241d2 ] )
+  , Mont::unsafe_make( [ 0x7e299ae5 , 0x0976bbfc , 0xed20969d , 0x420e632b , 0x2a29b22f , 0x90bff5d9 , 0xdc282753 , 0x15dba687 ] )
+  , Mont::unsafe_make( [ 0x518c78f0 , 0x23399bc3 , 0x4a112554 , 0x7dd8f901 , 0x47a8aeb1 , 0xa07bf959 , 0xa442b2ac , 0x21f0028e ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x19e670e8 , 0x38874129 , 0x586f9aad , 0xcd8c051c , 0xec9fcc3c , 0x07478356 , 0x1f9fd59f , 0x0e96ee89 ] )
+  , Mont::unsafe_make( [ 0x30d1fa2d , 0x4a33e3ae , 0x4e35038c , 0x67d11558 , 0x36b226e7 , 0x7f387bcb , 0x3b8c6411 , 0x0ef61780 ] )
+  , Mont::unsafe_make( [ 0xc93ec0f0 , 0xdf0eb6be , 0x96548208 , 0xe5089dad , 0x731bf8bd , 0xb7e23915 , 0x5ad94d7e , 0x17d43db8 ] )
+  , Mont::unsafe_make( [ 0x937e36dc , 0xb2b4e237 , 0xd98eb219 , 0xfe084519 , 0x786bb3d1 , 0x3446a48f , 0x459ceb84 , 0x2cff1ebb ] )
+  , Mont::unsafe_make( [ 0xc915b3b3 , 0x0885b5a4 , 0x21c59287 , 0xccfc572a , 0x3f3973cf , 0xb4f499ce , 0x1886be34 , 0x2d946e2d ] )
+  , Mont::unsafe_make( [ 0x58ae6722 , 0x730d3910 , 0x9469603b , 0xd176a1a6 , 0x93295d9c , 0xe179dd92 , 0x76fb2339 , 0x1a5dbe89 ] )
+  , Mont::unsafe_make( [ 0xe885d48b , 0xb058e316 , 0xe4f5878e , 0x245c9a7e , 0xac43f3e1 , 0xda31e730 , 0x1053fbcd , 0x1fd7f677 ] )
+  , Mont::unsafe_make( [ 0x3060c3a6 , 0xaa01d593 , 0x3f6034ff , 0xf683200e , 0xc6da1aad , 0x7cc0717c , 0xa57deeda , 0x12714f93 ] )
+  , Mont::unsafe_make( [ 0x1e521e3b , 0x94003000 , 0x2e9c7936 , 0xca3fac5c , 0x61d46a7e , 0x6e3f9728 , 0xeb6add78 , 0x0537765e ] )
+  , Mont::unsafe_make( [ 0xb18976a7 , 0xe69a8101 , 0x3350fa7c , 0x3ec9200a , 0x896e1ea1 , 0x302b8504 , 0x0a0deaa3 , 0x1242e271 ] )
+  , Mont::unsafe_make( [ 0xfa440f9e , 0x9c657d73 , 0x3d7341d2 , 0xf4948003 , 0x972e8a92 , 0x321a65d6 , 0x48b49cfc , 0x0bcc1a96 ] )
+  , Mont::unsafe_make( [ 0x8ba45453 , 0x49d2a899 , 0x9a74c8f5 , 0xf292e759 , 0xcc149a32 , 0xc3d9deb1 , 0xcbbc6273 , 0x2ea53734 ] )
+  , Mont::unsafe_make( [ 0xc383f1f2 , 0xe59ef487 , 0x1b7fee3a , 0x576ddaad , 0x497720c4 , 0x3a42ae67 , 0x8cf1762f , 0x2b559f23 ] )
+  , Mont::unsafe_make( [ 0x413c80ca , 0x3d40f7e9 , 0xab477e4f , 0x8a7558d8 , 0x3e5533d3 , 0xd15f0b61 , 0x5676ed75 , 0x1186a58d ] )
+  , Mont::unsafe_make( [ 0x665ef6c7 , 0xf1d0ef86 , 0xf62ed4b3 , 0x88ead75a , 0xfec73c21 , 0xf6b8e5f9 , 0xcaa21a48 , 0x0a304472 ] )
+  , Mont::unsafe_make( [ 0xf8d55259 , 0x2b86aab2 , 0xb530d726 , 0xf9f9e69e , 0xac4920be , 0xbf42c54c , 0xe0f73818 , 0x083d23e6 ] )
+  , Mont::unsafe_make( [ 0xf41ee382 , 0xbf705643 , 0xacfcbef5 , 0x5e60981d , 0x83fb3c18 , 0xe0228ad0 , 0x7f6f1d0b , 0x211709d1 ] )
+  , Mont::unsafe_make( [ 0xf9b7b0f2 , 0x7376bfcf , 0x2ca3860f , 0xe4d74378 , 0x970f0deb , 0x250b8632 , 0x9af1552d , 0x27749be4 ] )
+  , Mont::unsafe_make( [ 0x92f3332b , 0xe6520894 , 0x6bb29c56 , 0xe3d96531 , 0x3cc2a6ad , 0x06d4818f , 0xae17aa54 , 0x2e224fd8 ] )
+  , Mont::unsafe_make( [ 0x1265835c , 0x4e560f5b , 0x468c6739 , 0x494e0eff , 0xedac6839 , 0x2021678c , 0x2f9296b7 , 0x0f33789d ] )
+  , Mont::unsafe_make( [ 0x23d24c40 , 0x0ef2f5af , 0xe45ada9d , 0x53a8ea2c , 0xc22131d1 , 0x069890b0 , 0xb81edf77 , 0x17ac9108 ] )
+  , Mont::unsafe_make( [ 0x81a58bac , 0x84b67e1c , 0x11112a5f , 0x421b7a58 , 0x792e299a , 0x958e8923 , 0x4f346936 , 0x1f94aa01 ] )
+  , Mont::unsafe_make( [ 0x816d6e3d , 0x09fe5d01 , 0x1044fcc4 , 0x1b358629 , 0xd7a08f21 , 0x60f41783 , 0xfe2e993f , 0x0e69853f ] )
+  , Mont::unsafe_make( [ 0x0a7e712f , 0x06da9225 , 0x2b3d4392 , 0x105a3b41 , 0x5450de57 , 0xacffe6c1 , 0x2ae355d3 , 0x1ea8ba7b ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x71e3b3b0 , 0xc110275c , 0x415b8b6e , 0x2e901f50 , 0x624cbc60 , 0x7152cb60 , 0x75bd6670 , 0x2bea9f79 ] )
+  , Mont::unsafe_make( [ 0x6cbcff19 , 0xdd4636a8 , 0x311d6c66 , 0xce9238f0 , 0x007300e4 , 0x437a4d56 , 0x81a31cf7 , 0x09024744 ] )
+  , Mont::unsafe_make( [ 0x851f6e08 , 0xcf4e94f2 , 0x2e54dac0 , 0xc02bd228 , 0x37a9562e , 0xc848b74d , 0x00d2ca99 , 0x0cfc42be ] )
+  , Mont::unsafe_make( [ 0xb5d6938d , 0xf46ca0e3 , 0xb570f388 , 0x43b3a7a9 , 0x2d9d4b1b , 0x680819b2 , 0xde1540b1 , 0x25b8aad1 ] )
+  , Mont::unsafe_make( [ 0x54c9c517 , 0xb7562f05 , 0x7014f0f4 , 0x8e33e45c , 0x0632d347 , 0xafe8833f , 0xb0eac200 , 0x181612d5 ] )
+  , Mont::unsafe_make( [ 0xd185e22c , 0x7c897b49 , 0x82aa1d59 , 0x16a394c1 , 0xdc25bd5d , 0x480bf865 , 0xcdb29b5c , 0x25a5c9ef ] )
+  , Mont::unsafe_make( [ 0xcb9834c5 , 0x18d5c168 , 0xb24f72c9 , 0x07ec299e , 0xbedd5507 , 0x002f484d , 0xb76e8c01 , 0x1e0fdc6c ] )
+  , Mont::unsafe_make( [ 0xb1f4b26f , 0xa2428d67 , 0x6c720f59 , 0xa82458b4 , 0xacd12ba1 , 0x13a8d25e , 0xc6afe7e6 , 0x19f4f0f9 ] )
+  , Mont::unsafe_make( [ 0x6830a8b8 , 0x4a1f59d1 , 0x08dc028a , 0x2e5c6e60 , 0x9911ec2c , 0x6ff60f60 , 0xdbee441c , 0x01084423 ] )
+  , Mont::unsafe_make( [ 0xadcf9dc2 , 0x74352627 , 0xe4b3d1ca , 0x45c7eab0 , 0x68dd1922 , 0x1b3f637c , 0xd2dfa901 , 0x221fa31e ] )
+  , Mont::unsafe_make( [ 0x713f2719 , 0x7f6f23b6 , 0xf3d6cae8 , 0x4ca028da , 0x12c076fd , 0x65363e3a , 0x6c5f89d6 , 0x033653af ] )
+  , Mont::unsafe_make( [ 0xeb160ba5 , 0x21a12a1c , 0x651df06c , 0xa7f8b044 , 0xc50e11c7 , 0x297b2737 , 0xf3c1562a , 0x27157e40 ] )
+  , Mont::unsafe_make( [ 0x383641c1 , 0xf5a37242 , 0xdc028dac , 0x081147dd , 0x5e22a435 , 0x795910ff , 0x52261254 , 0x1dc05523 ] )
+  , Mont::unsafe_make( [ 0x639783c3 , 0x7715d0e9 , 0xcd55e290 , 0x6deb4e96 , 0x3495a3b5 , 0x7f622398 , 0x7e3ef999 , 0x1fca2f96 ] )
+  , Mont::unsafe_make( [ 0x21b68d4a , 0x7bd2df7e , 0xd362cc88 , 0xa6ae96cb , 0xf247524c , 0x4a87949c , 0xaa2b7486 , 0x06c90569 ] )
+  , Mont::unsafe_make( [ 0x75b31e22 , 0x7fbecd53 , 0x8176f5ee , 0xc6415e76 , 0x8e08488a , 0xb85a88b5 , 0xb96d4a71 , 0x14ca82c2 ] )
+  , Mont::unsafe_make( [ 0x0a8ab1dc , 0xae6da01d , 0x41270be3 , 0x1b53a4ad , 0xa4d5acca , 0x877f621f , 0x01e98bbc , 0x226b7e6a ] )
+  , Mont::unsafe_make( [ 0x40a72f95 , 0xcb072534 , 0x0e351dac , 0xb5a415d1 , 0xbb96a4af , 0xf218e5cf , 0xd40fa04a , 0x29468e67 ] )
+  , Mont::unsafe_make( [ 0x58b19ada , 0x2a261731 , 0x8e31c7bd , 0xf7756e84 , 0x9ddf3c1e , 0x0da51f76 , 0xdf1e52aa , 0x10ee01ea ] )
+  , Mont::unsafe_make( [ 0x56f54e0b , 0x6e30ae6d , 0xa220edd6 , 0xf35a718a , 0xdf8839b0 , 0x8fd9193f , 0xcdfcd156 , 0x17b13b6b ] )
+  , Mont::unsafe_make( [ 0xe2b3f435 , 0x11edb8ab , 0x32f58d14 , 0x258dd6db , 0xd7b6715e , 0xfbbbbc0a , 0x0efa96e5 , 0x0f20e838 ] )
+  , Mont::unsafe_make( [ 0xb555862b , 0x92100181 , 0xb377fe14 , 0x294c3c42 , 0x23869e11 , 0xd59456bc , 0x488105d3 , 0x0b9bfa27 ] )
+  , Mont::unsafe_make( [ 0xc2508e4b , 0x2a403ea6 , 0xb8135559 , 0x04af5069 , 0x1236b995 , 0x5e3e5233 , 0x067a78a9 , 0x1988c237 ] )
+  , Mont::unsafe_make( [ 0xa98ceb39 , 0x66b179e1 , 0x8f356041 , 0x38e4eb5d , 0xba403044 , 0x45b555d4 , 0x2392ed2c , 0x2109d7bb ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x1ae8da9c , 0xe65d84bf , 0x660b666e , 0x43ec74be , 0x0fdd4ada , 0x44fbfbae , 0xcabd5c0d , 0x11311756 ] )
+  , Mont::unsafe_make( [ 0xac4cfebb , 0x176c2320 , 0xb9ac0ba1 , 0x58a64076 , 0xb32b308a , 0xcef6ef13 , 0xe055f6d7 , 0x2ea8b8af ] )
+  , Mont::unsafe_make( [ 0xaae6ac3d , 0x1de48bdc , 0x94ce0ab9 , 0xf68ab6e3 , 0x73c7e532 , 0xa6c6e574 , 0x03832c0b , 0x2104c65f ] )
+  , Mont::unsafe_make( [ 0xb5573f32 , 0xf8d184e6 , 0xb9a3a429 , 0xd3a44d97 , 0x565b996c , 0xbcca194e , 0xac88c31f , 0x2674a569 ] )
+  , Mont::unsafe_make( [ 0x8772a760 , 0xecf6dd81 , 0x7c131d52 , 0xd87d42bd , 0x5da586de , 0xbf1f6ec1 , 0xdc5f5042 , 0x247cd3b8 ] )
+  , Mont::unsafe_make( [ 0xb05f7986 , 0xb425feef , 0xfd4fc077 , 0xdf9017e1 , 0xf52cc6e6 , 0xf29df956 , 0x7c3bc60d , 0x23c4b4f3 ] )
+  , Mont::unsafe_make( [ 0xf0bf0ea9 , 0x25a12b0b , 0xb58f160f , 0xea2148ce , 0x915bece0 , 0x698563ff , 0x2f8fb80d , 0x27d9e063 ] )
+  , Mont::unsafe_make( [ 0x16c59328 , 0xc97a4bfe , 0x764ce3e7 , 0xb564a7bc , 0xf74b827c , 0x1ad9c542 , 0xd9902bb3 , 0x1efa4ccb ] )
+  , Mont::unsafe_make( [ 0xd035271e , 0x8935193d , 0x7d9b5798 , 0x4d3aadf7 , 0xeeb7ff66 , 0xd2914941 , 0x9030032c , 0x068d6fc5 ] )
+  , Mont::unsafe_make( [ 0x13844eff , 0x116cdbe9 , 0x28acf48d , 0x5daad7ec , 0xc1136ce0 , 0xd981ad92 , 0xb63724fe , 0x06bb89e7 ] )
+  , Mont::unsafe_make( [ 0x2840c875 , 0x5a6b1aaf , 0x6899f579 , 0x527d2251 , 0x54367792 , 0xee366eb6 , 0x7b2d415d , 0x10df42e6 ] )
+  , Mont::unsafe_make( [ 0x56ad9357 , 0x933fdd9a , 0xa6a985da , 0x97e65e5b , 0x3bb3370d , 0x935a1767 , 0x728dca65 , 0x001aff87 ] )
+  , Mont::unsafe_make( [ 0x7e529d58 , 0xd6c169c8 , 0x11e0c247 , 0x28deda5b , 0xdaa9373f , 0x56f20089 , 0x9607ff01 , 0x17e9f7d9 ] )
+  , Mont::unsafe_make( [ 0xe2aa72a6 , 0x5939d24b , 0xf2739701 , 0x3c00bb69 , 0xc22c152c , 0x439d46ad , 0x1623f672 , 0x2ab676cd ] )
+  , Mont::unsafe_make( [ 0x0e939502 , 0xbe3db2e2 , 0xee142e1c , 0xdbc17830 , 0xd90c2b68 , 0xfc51e76a , 0xb9c23c77 , 0x2eeed4fb ] )
+  , Mont::unsafe_make( [ 0x35ced4d2 , 0xd17c7303 , 0x637d9530 , 0xb6123515 , 0x82918718 , 0xc9768431 , 0x09dccbab , 0x2e7919a1 ] )
+  , Mont::unsafe_make( [ 0x20f12e6e , 0x0935bca5 , 0xb0902583 , 0x38bba0cd , 0x4a5e5c58 , 0xe76cb471 , 0xbffe07d8 , 0x04e8596d ] )
+  , Mont::unsafe_make( [ 0x13fef920 , 0xb9d3e97d , 0xf4dc29c5 , 0x8add5485 , 0x5eee4d0a , 0xc6245094 , 0x43c858d1 , 0x02a506ac ] )
+  , Mont::unsafe_make( [ 0xc198f865 , 0x81859059 , 0x68f7b625 , 0xea5aefd5 , 0x75b4c4a6 , 0x6d056eb5 , 0xf383b2dd , 0x2048dfd5 ] )
+  , Mont::unsafe_make( [ 0xe4beba83 , 0xa52d239e , 0x96f4ac1d , 0x19065db6 , 0x08877c85 , 0x0026e48d , 0x32f00bc3 , 0x2a7b9090 ] )
+  , Mont::unsafe_make( [ 0x47678a64 , 0x3c42a782 , 0x33b2958c , 0x2845c37c , 0x46a5dc31 , 0x6f884803 , 0x19a87efc , 0x16d2b788 ] )
+  , Mont::unsafe_make( [ 0x8441e208 , 0xd6c5ff4b , 0x1dc509e6 , 0xe3d4124e , 0xe7d1c7d5 , 0xe64e6c97 , 0x34d53c88 , 0x125a9ac0 ] )
+  , Mont::unsafe_make( [ 0xaac12f6e , 0x9a3891c0 , 0x09776043 , 0x0cc95548 , 0xcbc4e2df , 0x97c38012 , 0xe6308e93 , 0x23a99de1 ] )
+  , Mont::unsafe_make( [ 0xfe37f4ce , 0xdbd02b9d , 0x1bb31e9e , 0xa71b7075 , 0x75b75cb0 , 0x874cabee , 0x97c6d2e2 , 0x14ca05f5 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xcc544fdc , 0x00300f4d , 0xf8f22da8 , 0x1ed368fe , 0x653c0fe2 , 0x7e3ce4e8 , 0x1950fc0b , 0x2da40ad9 ] )
+  , Mont::unsafe_make( [ 0x421ebfd2 , 0xc4afaff7 , 0x59fd111c , 0x3df8bdaa , 0x83f0bb56 , 0x70dfb002 , 0x71cf53c0 , 0x2c53f743 ] )
+  , Mont::unsafe_make( [ 0x9966584b , 0xf1347e33 , 0x36b3425f , 0x1614ed9e , 0x648035ec , 0x0e8e3aa5 , 0x864b9bfd , 0x0be57e24 ] )
+  , Mont::unsafe_make( [ 0x1275e942 , 0xc1717665 , 0xeac78a98 , 0xf6cc22af , 0x57f1fb94 , 0xca1fe45f , 0x58b84065 , 0x0626e8cc ] )
+  , Mont::unsafe_make( [ 0xa2af4161 , 0x0ab3b9fb , 0xe1618058 , 0xce64c8ed , 0xf7b90f30 , 0xf79a4389 , 0x724de146 , 0x1dcf95ff ] )
+  , Mont::unsafe_make( [ 0x8017fee1 , 0x37f2cc56 , 0x21d05051 , 0x165e51f0 , 0x5ee66cb5 , 0x5820ef42 , 0x6719120d , 0x1e3966ae ] )
+  , Mont::unsafe_make( [ 0x4481cb2b , 0x643a4b75 , 0xd7da2793 , 0x7e70a4b0 , 0xa7a55a4f , 0xefd1735e , 0xecf76d21 , 0x2c5eb488 ] )
+  , Mont::unsafe_make( [ 0x794a6f03 , 0x53942d3f , 0x2a67975d , 0x7d28eabb , 0x557fbaeb , 0x8370b4fa , 0xfffb12db , 0x1b081006 ] )
+  , Mont::unsafe_make( [ 0x4c4663ae , 0x513a2a0e , 0x6871ee43 , 0x9e261920 , 0x383926b3 , 0x8831e03b , 0x53012296 , 0x1734312d ] )
+  , Mont::unsafe_make( [ 0xc8ed9a75 , 0x10e649d0 , 0xa058e5c6 , 0x4968adac , 0xaee2537b , 0x3946aaec , 0x251ea700 , 0x032b3c6b ] )
+  , Mont::unsafe_make( [ 0x77c6a0ec , 0xaeb60f99 , 0x4bafd754 , 0x404887f8 , 0xcd336d84 , 0xdebafcbd , 0x12f4e3c7 , 0x24ac85e5 ] )
+  , Mont::unsafe_make( [ 0x33797fb9 , 0xfb1c2637 , 0x48f7d482 , 0x5a8ed618 , 0xeb459a93 , 0x56e5dce2 , 0xf288df76 , 0x0d5b4650 ] )
+  , Mont::unsafe_make( [ 0x9c5fcd71 , 0x76f34aef , 0x92dc8a22 , 0x85fe5107 , 0x6fed1801 , 0x76050b2b , 0x09d699e3 , 0x22035a1a ] )
+  , Mont::unsafe_make( [ 0x01e09812 , 0xb9934fe0 , 0x6a0aa8cb , 0x65994367 , 0xb18aece8 , 0x061081c7 , 0xd9778f40 , 0x2042dbe1 ] )
+  , Mont::unsafe_make( [ 0xa769ea5c , 0xe8ea5e60 , 0x2acb6fa1 , 0x9af95020 , 0x15d8b42c , 0x501b30ba , 0x2a60c269 , 0x01259147 ] )
+  , Mont::unsafe_make( [ 0xf843e5ad , 0xcc4fd5bd , 0x08806adf , 0x7e725f3d , 0x7a728e9a , 0xf0ecdccf , 0xeec588de , 0x0c2fd6a9 ] )
+  , Mont::unsafe_make( [ 0xb679d3c0 , 0x581f0399 , 0xf4056796 , 0x299115ab , 0x4dce5c5f , 0x968c54c0 , 0xd8dc03e5 , 0x12a789f1 ] )
+  , Mont::unsafe_make( [ 0x5700a478 , 0x933af9b4 , 0xa610afc6 , 0x92ca996d , 0x24ee6da0 , 0x6c06c59a , 0x28349fe6 , 0x0da02852 ] )
+  , Mont::unsafe_make( [ 0x9685d16d , 0x90f1cc6b , 0x00c3e74e , 0x99ea2f45 , 0x316bbf8d , 0x2e0e310c , 0xdb4ab603 , 0x1752f77d ] )
+  , Mont::unsafe_make( [ 0xefe8f756 , 0x146845dd , 0x85705f14 , 0xd8f12178 , 0xe3078cd5 , 0x7cc22f87 , 0xf70939ac , 0x210ef700 ] )
+  , Mont::unsafe_make( [ 0x81f171c3 , 0xd83a8e7e , 0x6c5d6816 , 0xbbf5ed67 , 0x47fff8a6 , 0xb059a575 , 0xa0d597da , 0x1bd9779c ] )
+  , Mont::unsafe_make( [ 0x51b54a4c , 0x6a9275ab , 0x9950ef50 , 0x999ead3d , 0x9dc0aaac , 0x6f023065 , 0x54345e1f , 0x182497f1 ] )
+  , Mont::unsafe_make( [ 0x8ec017a1 , 0x25d2dfe1 , 0xc5886fbb , 0x57b4ee79 , 0x2971084b , 0xb8ee8fcb , 0xcaf4d4cd , 0x1b87feea ] )
+  , Mont::unsafe_make( [ 0x511ed8a0 , 0xc4ca114b , 0x3aaeee1f , 0xe72e7bae , 0xa8c01dc5 , 0x8c82301c , 0x6d48330a , 0x0299c05d ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x4a49abed , 0x00975c8f , 0x7c3a8364 , 0x94b729c1 , 0x8644ce88 , 0xdfefaac2 , 0x258557ab , 0x11ee852b ] )
+  , Mont::unsafe_make( [ 0x46799b61 , 0xf749904c , 0x43f497db , 0x51cf80e1 , 0x50758d64 , 0x3e919c33 , 0x7dff8cb9 , 0x0224d5e0 ] )
+  , Mont::unsafe_make( [ 0xe9272336 , 0x226eb3d1 , 0x0b6a46f7 , 0x82bf8b9d , 0x48a418a5 , 0x8b8e241a , 0x73762932 , 0x12b909e4 ] )
+  , Mont::unsafe_make( [ 0xe5ddc9ab , 0x35754a55 , 0xa150fdcb , 0xeb601da2 , 0x3bc8dc1d , 0x3694dfeb , 0xe4c80cc9 , 0x2e37e766 ] )
+  , Mont::unsafe_make( [ 0xadd04a94 , 0xe04176e5 , 0xa1a76d64 , 0x2e0519b1 , 0xcb1bf37e , 0x5b759163 , 0x015ad267 , 0x268026ed ] )
+  , Mont::unsafe_make( [ 0xb6b3bdb5 , 0xaa27090e , 0x84b9dea4 , 0x6cf7cfa4 , 0x7fab3522 , 0x557fa882 , 0xb4352e2b , 0x24bc9437 ] )
+  , Mont::unsafe_make( [ 0xb47bfe02 , 0xfb1d138c , 0x3cf5d0af , 0x57787923 , 0x7cc66021 , 0x31a9dc0e , 0xeb553a05 , 0x158def00 ] )
+  , Mont::unsafe_make( [ 0xb285d238 , 0xd0c1d833 , 0x4c2b34f0 , 0x98d753c9 , 0x8efa7789 , 0x0f9b394f , 0x13e806d8 , 0x1e3f4911 ] )
+  , Mont::unsafe_make( [ 0x3909e768 , 0xe09b0d22 , 0x177ec0b9 , 0xf07b882d , 0x6056fc8a , 0xf959872b , 0x2ccf8825 , 0x11e40e94 ] )
+  , Mont::unsafe_make( [ 0xe028fe7d , 0xd3f6f861 , 0xfbd0123b , 0x5aff016b , 0xca5c621b , 0xa84288df , 0x521255fb , 0x26e8b587 ] )
+  , Mont::unsafe_make( [ 0x343541e2 , 0x08992361 , 0xf5f85a75 , 0x0b88ee2a , 0xbe0eb468 , 0x44b94b1b , 0x1afdb960 , 0x0bc9501f ] )
+  , Mont::unsafe_make( [ 0x13e5fad7 , 0x039f1eb3 , 0xa957ce6e , 0xc3b08cf3 , 0x84293e49 , 0x9e0718b5 , 0x8f3779ac , 0x015a4001 ] )
+  , Mont::unsafe_make( [ 0x3a9aeff8 , 0x6a276d18 , 0xe92da142 , 0xffaf3989 , 0x3d60ea42 , 0x4578495b , 0x3ac24d85 , 0x1dc405c4 ] )
+  , Mont::unsafe_make( [ 0x481f4fc3 , 0x86a62292 , 0x4320db49 , 0xf12fd2f1 , 0x7c1eb131 , 0xce01865e , 0x2aa0c888 , 0x090d419a ] )
+  , Mont::unsafe_make( [ 0x1a03cb00 , 0x5cf5f43f , 0xa3f9b4a5 , 0x332e26d1 , 0xabc8a488 , 0x98724acc , 0x423f4d7a , 0x04329a6d ] )
+  , Mont::unsafe_make( [ 0xc5b07ae1 , 0x1402de19 , 0x1c606c08 , 0xb014739f , 0xe629cfb3 , 0xe338a8f1 , 0x740a492d , 0x120a4364 ] )
+  , Mont::unsafe_make( [ 0x81c3d76c , 0x4e6dd697 , 0xa83a5c31 , 0x0852dd95 , 0xdc508414 , 0x9e2c34ea , 0x2eaadc52 , 0x01a12251 ] )
+  , Mont::unsafe_make( [ 0xac256dad , 0x35c42afb , 0xa81985d1 , 0x9e747ded , 0x573d5c6d , 0x3e9b686e , 0x979f46de , 0x13cc9dae ] )
+  , Mont::unsafe_make( [ 0x6a0a317c , 0xa0a70205 , 0xe3645cf9 , 0xbb751b5b , 0xb3e25f42 , 0xa4328230 , 0xfa2274f6 , 0x276edf00 ] )
+  , Mont::unsafe_make( [ 0xdd6b252d , 0x9047552c , 0x03a4c4c8 , 0x9a835c99 , 0x94fb37f3 , 0x52b77347 , 0x28908435 , 0x2b2771d4 ] )
+  , Mont::unsafe_make( [ 0xe1a2a124 , 0xee034e4b , 0x2489d63c , 0xe4600e99 , 0x07a0c2f0 , 0x6dd26c42 , 0xe418fcd4 , 0x2c351601 ] )
+  , Mont::unsafe_make( [ 0x7b80ce6e , 0xf9cbab90 , 0x1d1e4e51 , 0x26d3e289 , 0x68a56d4a , 0x8f785670 , 0x82e624a9 , 0x29ca8113 ] )
+  , Mont::unsafe_make( [ 0xa5027857 , 0x9e57fcc1 , 0x93685639 , 0x3308bb45 , 0x41faf25c , 0x7033b347 , 0x7f529099 , 0x0b711960 ] )
+  , Mont::unsafe_make( [ 0x5ad78c42 , 0x8bf6acd0 , 0x85bce689 , 0x74439c5b , 0x23f9fded , 0x6c2bb55b , 0x4f5a5781 , 0x2a7c5810 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xd504b433 , 0x3ab7356d , 0x96837f51 , 0xd0baf570 , 0xe799ba2a , 0xcd4b2aef , 0x1346b1bb , 0x0c530246 ] )
+  , Mont::unsafe_make( [ 0x9697ce7a , 0xf315ed4c , 0x6095a200 , 0xdbe29b79 , 0xec6ac9f7 , 0x1a4e797d , 0x47c10fa6 , 0x06645d1a ] )
+  , Mont::unsafe_make( [ 0x13baac3b , 0x65db45e3 , 0xb65656cc , 0x7ea9c011 , 0xd6b422b1 , 0xebe32719 , 0xa365a816 , 0x300d448a ] )
+  , Mont::unsafe_make( [ 0x29e6a5f6 , 0x4ac9eaa6 , 0x009edd9b , 0xcd52f5d5 , 0xceedb824 , 0x7406c902 , 0x8d4d0d7f , 0x2293d281 ] )
+  , Mont::unsafe_make( [ 0xf032f8c5 , 0xaf094855 , 0x1467ddd4 , 0x926be3b9 , 0xdc6a874c , 0x9d4e82d1 , 0x235a881f , 0x100b7dd5 ] )
+  , Mont::unsafe_make( [ 0x5ab6e39d , 0x54f7a650 , 0x115c6833 , 0xd7b8d699 , 0x55a471c9 , 0xc8aeeffd , 0xbff65ba6 , 0x28f57e93 ] )
+  , Mont::unsafe_make( [ 0xba0c1ec0 , 0xd5f595f1 , 0xe744cf6e , 0x33a1e14b , 0x1e9fbd9f , 0x8c5004ba , 0x82bfc08b , 0x1df07248 ] )
+  , Mont::unsafe_make( [ 0x7d61ec30 , 0x6cbc98fb , 0xe38cabd4 , 0xa6005cdf , 0xf40ec50d , 0xcca95a75 , 0x7d629bc7 , 0x03024ef3 ] )
+  , Mont::unsafe_make( [ 0xb47c40d2 , 0x6064b644 , 0x148a5608 , 0xb45fcd4a , 0xc7c2bf03 , 0x3f36666d , 0xb0b30a41 , 0x1c37cfa8 ] )
+  , Mont::unsafe_make( [ 0xc8437e22 , 0x67ec1ece , 0x43e19a34 , 0x213ade1e , 0x06825e14 , 0xc5863643 , 0xe541bb76 , 0x190198a9 ] )
+  , Mont::unsafe_make( [ 0x890a10aa , 0xae3ea02b , 0x75d2e19c , 0x7458a5f2 , 0x6bbe51b7 , 0x48f941f9 , 0xd4e0cca8 , 0x198a0353 ] )
+  , Mont::unsafe_make( [ 0x8dd866d8 , 0x6a3212bd , 0x1946f761 , 0xa5282ece , 0x0e8f0cb1 , 0x6b8d366c , 0x8805cef4 , 0x1cb12086 ] )
+  , Mont::unsafe_make( [ 0x26a7379d , 0xfa266ed8 , 0xca97ea18 , 0x8ce8ea5c , 0xe110804c , 0x479c429c , 0xb7b0034f , 0x1a33662b ] )
+  , Mont::unsafe_make( [ 0xe37f69a0 , 0xdb1aeeb1 , 0xa0647682 , 0xd9028265 , 0x5f7463a9 , 0x4a8b71ed , 0x07ae6f56 , 0x2ce14c12 ] )
+  , Mont::unsafe_make( [ 0x391c61df , 0xc8c63fec , 0xf7bef99b , 0x59aa5ceb , 0x439bfaaa , 0xbe37990d , 0xea607dde , 0x17942f16 ] )
+  , Mont::unsafe_make( [ 0x9380f200 , 0x9c734922 , 0x7c8e795b , 0xbeb0a30e , 0x333e8288 , 0x6848a769 , 0x4d55f2f6 , 0x15d2f748 ] )
+  , Mont::unsafe_make( [ 0x97d13f26 , 0x6fd4fa1e , 0xbc1173ca , 0x882bbe05 , 0xfd811894 , 0xa82011be , 0xc690f2e3 , 0x2f85dab4 ] )
+  , Mont::unsafe_make( [ 0x7eadbf23 , 0xc570b5d7 , 0xd2beb51b , 0x609c0cd0 , 0x3dab7cf6 , 0xf12143d0 , 0xcf96fb29 , 0x2fccb427 ] )
+  , Mont::unsafe_make( [ 0x27c8113a , 0xfd80a74b , 0x5b31c5c7 , 0x1321f0cb , 0x6b3c8a03 , 0xc20d78be , 0x80ef7b88 , 0x282780e6 ] )
+  , Mont::unsafe_make( [ 0xd420dd4b , 0x9cc8603a , 0x30974aa1 , 0x07d6e645 , 0x75c4e25c , 0xa825f5ee , 0x5036ef2c , 0x0f6495ca ] )
+  , Mont::unsafe_make( [ 0x9c4565bd , 0x474434a9 , 0x8f53c154 , 0xde714cc2 , 0x81b76d43 , 0x1e42ec4e , 0x7ea517b3 , 0x2d279559 ] )
+  , Mont::unsafe_make( [ 0x202c4c69 , 0xce1d8ba1 , 0x62a92672 , 0xc68abdf7 , 0x043f1687 , 0x0f837df9 , 0xce68b4e2 , 0x0f61d710 ] )
+  , Mont::unsafe_make( [ 0x91710d20 , 0xb2a6946f , 0x237151cc , 0x3d4facda , 0xff18e915 , 0x2c9ae23e , 0xd572a25b , 0x0cc9151b ] )
+  , Mont::unsafe_make( [ 0xe99d66e2 , 0x4337ddca , 0x1adeed40 , 0xc408b646 , 0x7df8652f , 0x912f0b0a , 0x05540a74 , 0x05e5282b ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xace61c00 , 0xa5ead27a , 0x35fb4a6c , 0x2bfa7f60 , 0xfa30b201 , 0xb8296b26 , 0xcfccfb51 , 0x22b25230 ] )
+  , Mont::unsafe_make( [ 0xadc2c091 , 0x7eb3db36 , 0x2ceb85e8 , 0x4e8b1ba4 , 0x7649f9ac , 0x5413b1d5 , 0x5e023d71 , 0x1b1f3c1c ] )
+  , Mont::unsafe_make( [ 0x26f0ce64 , 0x7249297d , 0x7ef60d54 , 0xd369a546 , 0xa0b402f8 , 0x702e2808 , 0x9581642e , 0x294eb504 ] )
+  , Mont::unsafe_make( [ 0x7b637d76 , 0xbf7b7e17 , 0x9d207d5e , 0x91d2b63d , 0xe843d8d3 , 0x1bea96f9 , 0x6af83e6a , 0x1185aa06 ] )
+  , Mont::unsafe_make( [ 0x932fe642 , 0xd90fc627 , 0x0fb34e3d , 0x6e03fda5 , 0x3b37a9b7 , 0xbf17c180 , 0x01be657b , 0x046c2d47 ] )
+  , Mont::unsafe_make( [ 0x9fbfff10 , 0xc944af58 , 0x6e1ecc4b , 0xbd443581 , 0x5cf74cf4 , 0xd3f1f66b , 0x70314c9c , 0x23b01074 ] )
+  , Mont::unsafe_make( [ 0x78b42fae , 0x6f671735 , 0xbac31798 , 0x259b074a , 0x54470013 , 0xa535d052 , 0x663fe877 , 0x0e538289 ] )
+  , Mont::unsafe_make( [ 0xbf277941 , 0xa4ef9a41 , 0x97a10855 , 0x2635f972 , 0x802ca92c , 0x9207bca7 , 0x1a723b85 , 0x20e3348b ] )
+  , Mont::unsafe_make( [ 0xa12792f3 , 0xe5cc64e4 , 0x1c8a5e18 , 0x552feae5 , 0xb4f30f04 , 0x4b7e0337 , 0x7ca6799e , 0x2b2eabb8 ] )
+  , Mont::unsafe_make( [ 0xf78c696f , 0xb1051ff2 , 0x54b78910 , 0x0ab0c1d4 , 0x2e6f334e , 0x76a7a7aa , 0xcfdf5eae , 0x1ff62412 ] )
+  , Mont::unsafe_make( [ 0x9b114c58 , 0x120feeb7 , 0xa0b39b02 , 0x53e27e7d , 0x62ae09e7 , 0xc8cf5c4e , 0x9f385bff , 0x1cf0763d ] )
+  , Mont::unsafe_make( [ 0xe89e7534 , 0xbbe38d15 , 0x826c7beb , 0xbaaac3b8 , 0x232996e3 , 0x27fe4e15 , 0xbedf6c22 , 0x0028db4f ] )
+  , Mont::unsafe_make( [ 0x943f3dd0 , 0xd8b6971a , 0xb4b631c8 , 0xf03af0e4 , 0x1ddc7674 , 0x4378c248 , 0x67859282 , 0x0d77c1c1 ] )
+  , Mont::unsafe_make( [ 0xc61196de , 0xe7bb2dac , 0x4e99e4d1 , 0x05dccf13 , 0x57fe72e9 , 0x2bc765ff , 0xb3f6d9ac , 0x0ead1119 ] )
+  , Mont::unsafe_make( [ 0x1ad04671 , 0x43b489e4 , 0xfe7d0d21 , 0xb48ea4e5 , 0xb496a50b , 0xb93d78af , 0x63f52826 , 0x01296e02 ] )
+  , Mont::unsafe_make( [ 0x30bcf356 , 0xaf79449f , 0x32e50642 , 0x46b9a888 , 0xe775c170 , 0x05b7a60e , 0xf6e9c0c5 , 0x0a1f1f96 ] )
+  , Mont::unsafe_make( [ 0x9ce21b0e , 0x1dd53f3e , 0x83a03232 , 0xa99db322 , 0xb4510530 , 0x5bc0fb24 , 0x85901fda , 0x2497f341 ] )
+  , Mont::unsafe_make( [ 0xfdf7509f , 0x9bf8612e , 0xb6fbaa2a , 0x3c3e46fe , 0xd55f1717 , 0xf5e42d05 , 0x082ba9b8 , 0x2fd77316 ] )
+  , Mont::unsafe_make( [ 0xab74c8ac , 0x4248afb4 , 0x52de5d4c , 0xe705ff2f , 0xae91b32a , 0x32cb6dd0 , 0x88e81543 , 0x302fce49 ] )
+  , Mont::unsafe_make( [ 0x13c1dc7a , 0xcedcfe00 , 0x9e8170c5 , 0x1348f83f , 0xd399d802 , 0xe0ddf0aa , 0x54df5e82 , 0x2223818e ] )
+  , Mont::unsafe_make( [ 0x314743f7 , 0x5274ae58 , 0x94b44630 , 0xa7f65a01 , 0x7da7b767 , 0xc64e39f4 , 0xb11c97eb , 0x09f21ec5 ] )
+  , Mont::unsafe_make( [ 0xc0ae92b5 , 0x1146e50d , 0x47c092fc , 0x9ca91880 , 0x4f0ae2d1 , 0x7fb64792 , 0xe026d6c8 , 0x127d0582 ] )
+  , Mont::unsafe_make( [ 0xc15d127e , 0x17124232 , 0xb74163de , 0x9949c197 , 0x9e83f1a2 , 0xa8b9b5cb , 0x0a131fb3 , 0x18a81f95 ] )
+  , Mont::unsafe_make( [ 0x867062e2 , 0xeb282f21 , 0x3504aaaf , 0x351f410d , 0x85429834 , 0xf754ab5e , 0xca0d7e70 , 0x0817f666 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xf8e8d93b , 0xa6d04335 , 0xfab4cbd3 , 0x73ec591a , 0x4aeffbbf , 0xc79e0e9e , 0x7e2a56d8 , 0x2cfa7ccf ] )
+  , Mont::unsafe_make( [ 0x61aa01d7 , 0xf415c781 , 0xf2ddbaed , 0x55b18c84 , 0x100daf6a , 0x08b76574 , 0x4751104d , 0x1ec4d22e ] )
+  , Mont::unsafe_make( [ 0x982bf7b3 , 0x55f91e8b , 0x7af7da83 , 0x203454b9 , 0x25a1d2fb , 0x8a42178a , 0xa4fd66da , 0x244dec39 ] )
+  , Mont::unsafe_make( [ 0xb305a763 , 0x2682dde8 , 0xb25a9d2a , 0x801b415e , 0x3b2cbfdc , 0x62b852e3 , 0x271cbf41 , 0x1f37409f ] )
+  , Mont::unsafe_make( [ 0xa905f3fe , 0x55d61063 , 0x568ffd6f , 0x6c20701f , 0x352e205b , 0x9de40070 , 0x90a25c15 , 0x2588f424 ] )
+  , Mont::unsafe_make( [ 0x97b17577 , 0xb76da301 , 0x994f423b , 0xac202c4e , 0xdfa66648 , 0x35b6fd73 , 0x2d2bb176 , 0x3036e79a ] )
+  , Mont::unsafe_make( [ 0xa5ad9ea5 , 0x79028dca , 0x736e377f , 0x494d133f , 0x6a285558 , 0x492f31b9 , 0x82fb19fa , 0x0a1154e9 ] )
+  , Mont::unsafe_make( [ 0x9e675b21 , 0x3e73488d , 0xa8e85b93 , 0x118c294d , 0x46b19919 , 0x6ce15118 , 0x8a8836c7 , 0x14c48a75 ] )
+  , Mont::unsafe_make( [ 0x2a9d9a4a , 0xfe0b8cd2 , 0x2c1e332b , 0x5030b7f2 , 0xb425c2d3 , 0x1d87b46f , 0xa6732df3 , 0x0ec4a82b ] )
+  , Mont::unsafe_make( [ 0x530d0ff7 , 0x2e226c7f , 0x9e69d22f , 0x515c6a9a , 0x47a3cdae , 0x14ef5b26 , 0x12cfce1a , 0x21b9fbc2 ] )
+  , Mont::unsafe_make( [ 0x6bc7d595 , 0x240bd928 , 0xc7a9e7be , 0x1b8bbe26 , 0x8529a2d6 , 0xb5579409 , 0x6d5230dc , 0x2e6897fd ] )
+  , Mont::unsafe_make( [ 0x02813364 , 0x18ebceac , 0xc3ab03b4 , 0xab8663d8 , 0xaa2b1bc5 , 0x78911b7d , 0x6ff9ab5b , 0x00c9d0b1 ] )
+  , Mont::unsafe_make( [ 0x09ab0f56 , 0xfb4e6ea1 , 0xd67c2bbe , 0xec58bc24 , 0xd56dfdb7 , 0xe2fec393 , 0x48a8d5a7 , 0x1193025e ] )
+  , Mont::unsafe_make( [ 0xb671c05f , 0x18bf27f7 , 0x34422088 , 0xea6a9f97 , 0xae9061a4 , 0xbba8f9b4 , 0x25bca6c7 , 0x13602d8d ] )
+  , Mont::unsafe_make( [ 0x912b8579 , 0xab53a1e1 , 0x9f03d8f8 , 0xf12f8a63 , 0x179fac2b , 0x09e9fe6c , 0x1e130086 , 0x23fb544e ] )
+  , Mont::unsafe_make( [ 0x869413e1 , 0x3493f9a7 , 0x9d4f9837 , 0xe34c4690 , 0x0ab3a6de , 0x5d8cbba7 , 0x678ae873 , 0x23e9c997 ] )
+  , Mont::unsafe_make( [ 0x71f8af63 , 0xe5dacab4 , 0xe9025f82 , 0xb796ffcc , 0x4553b7f7 , 0xd34169a2 , 0x0e86f694 , 0x2b7fb5e2 ] )
+  , Mont::unsafe_make( [ 0x811aaaef , 0x8061e3d5 , 0xd93ef577 , 0x78d4020a , 0x49c7cf02 , 0xc18b3aba , 0x4cabce76 , 0x0b98eeee ] )
+  , Mont::unsafe_make( [ 0x7a53e99c , 0xc9cfba03 , 0x66505f82 , 0xecdf5eaf , 0x58343a99 , 0x2179ef9a , 0x19834598 , 0x0c1f95f7 ] )
+  , Mont::unsafe_make( [ 0x9d934d7a , 0xcdc3b5e0 , 0x104c2f25 , 0xd1b58ef8 , 0xed410689 , 0x16597d40 , 0xe58720ee , 0x1e6fa44e ] )
+  , Mont::unsafe_make( [ 0xa54e83f8 , 0x83e9f189 , 0x9dce09fc , 0xcdf5ef3f , 0xd972b14d , 0x35cf1ea9 , 0x2de3c228 , 0x0d2b0494 ] )
+  , Mont::unsafe_make( [ 0x0ac65536 , 0x685cd838 , 0xcc71d2e9 , 0xbe04cc49 , 0x29f6ed09 , 0x46750693 , 0x3a3ad94c , 0x0627711e ] )
+  , Mont::unsafe_make( [ 0x40b5f933 , 0x027b2b31 , 0xa37c58f2 , 0xe566c78f , 0xa48ec4b4 , 0xbcec4444 , 0x9a746a9b , 0x2e014a71 ] )
+  , Mont::unsafe_make( [ 0xb58f3d74 , 0x0d708b9b , 0x37ce39ed , 0x344a986c , 0x997df458 , 0xe46f98c4 , 0x2bb1ffd1 , 0x140dbec1 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x23d76fbc , 0xd5e281a1 , 0x277062a5 , 0x01939edf , 0x1fdfb941 , 0xda369e2d , 0xb7bb4cb1 , 0x27cd9a32 ] )
+  , Mont::unsafe_make( [ 0xcd7bc734 , 0x1fd71faf , 0x6efd1317 , 0x16627a97 , 0x1f739cd8 , 0x227ef060 , 0x46dc62cf , 0x2c6a178d ] )
+  , Mont::unsafe_make( [ 0x1d1337d7 , 0xe2bb2b01 , 0xfc3f12b5 , 0x02623f19 , 0xda014d64 , 0x5cf964a5 , 0x04d8c0a8 , 0x156aa28d ] )
+  , Mont::unsafe_make( [ 0xcdc7d04f , 0x83d4544a , 0x0b4c4c8f , 0x96174a15 , 0x0f8640d7 , 0xa5bb6ee2 , 0x2ed4affe , 0x05b77ebe ] )
+  , Mont::unsafe_make( [ 0xc28ec8b4 , 0xb8e215b7 , 0x106717fa , 0xcb63589f , 0x99d78ed0 , 0x86a7a647 , 0x37477692 , 0x000eaddb ] )
+  , Mont::unsafe_make( [ 0x3722c9f2 , 0xa39c9070 , 0xa9efb175 , 0xaa299965 , 0x70c9b786 , 0xb95224f5 , 0xc5ccbd7d , 0x13fa1b08 ] )
+  , Mont::unsafe_make( [ 0x74137a6c , 0x655f4bfb , 0xacd09309 , 0x5c0311b5 , 0x47cd7293 , 0xa9c99137 , 0x86cc07bb , 0x17cecc40 ] )
+  , Mont::unsafe_make( [ 0xfd90b478 , 0x4cad8783 , 0x6e9a1962 , 0x10d05fb2 , 0x5c313bdd , 0x93386372 , 0x8fe1fb28 , 0x1b828c2a ] )
+  , Mont::unsafe_make( [ 0x0f259cc2 , 0x20d6e94e , 0x980c53fd , 0x737b157f , 0xf050cb92 , 0x2df9a596 , 0xfddff75d , 0x197c4f85 ] )
+  , Mont::unsafe_make( [ 0x01531f09 , 0x80f17374 , 0x8170130f , 0x1cba266b , 0xb1bff51a , 0x751750c0 , 0x2e01de28 , 0x1f7cedc7 ] )
+  , Mont::unsafe_make( [ 0xdfbbb5f8 , 0x12dacadf , 0x06c880e4 , 0x44d68bb2 , 0xce1020ea , 0xfe075144 , 0x95ada44d , 0x22cd3215 ] )
+  , Mont::unsafe_make( [ 0x546b2099 , 0x58dd7fd3 , 0xf35817bf , 0xd90e2ad2 , 0x27a6c4b6 , 0x36bb3f7c , 0x43ef9c2d , 0x151e0482 ] )
+  , Mont::unsafe_make( [ 0xeadaae53 , 0x6f15c5e1 , 0xf19721dd , 0x1f650e99 , 0xec0c9b56 , 0x6e579f55 , 0x1416994e , 0x2053d868 ] )
+  , Mont::unsafe_make( [ 0x79a3150e , 0x0c713ca4 , 0xdf6ce490 , 0xa66e8107 , 0x252771cc , 0x72860ecb , 0xf1cdb6f9 , 0x0291bab3 ] )
+  , Mont::unsafe_make( [ 0x7f192bad , 0x1692a823 , 0x6e520f14 , 0x0aafc81f , 0x826c10eb , 0x46e5d915 , 0x734bf752 , 0x0668f795 ] )
+  , Mont::unsafe_make( [ 0xad6e30b8 , 0x13708368 , 0xa808afff , 0xe24c34dd , 0x92593013 , 0xc6eb0c40 , 0xc5207ee7 , 0x1297a4b6 ] )
+  , Mont::unsafe_make( [ 0x81b601c9 , 0xa854baf9 , 0x81197fdf , 0xbf9a11b2 , 0xb5fb447d , 0xb89edb1b , 0x644b27d6 , 0x1b9ff590 ] )
+  , Mont::unsafe_make( [ 0x4f60509c , 0x6e346705 , 0x54a98f4b , 0xb557b3e1 , 0x442d5e98 , 0x76dd3684 , 0xc4377c0a , 0x21a3d2a6 ] )
+  , Mont::unsafe_make( [ 0x06e52749 , 0xa9271486 , 0x44c36848 , 0xdccf238a , 0xf4f68b89 , 0xc6a255ca , 0xb0e8b44b , 0x04045084 ] )
+  , Mont::unsafe_make( [ 0xf9d26d8c , 0x851f131f , 0x2b56102e , 0xd099e61b , 0x735cb10a , 0xa3a6b146 , 0xe38a9d5a , 0x10d7978b ] )
+  , Mont::unsafe_make( [ 0x9d1b235b , 0xd1ad8acb , 0x87b324e1 , 0x8ce30266 , 0xb70fa847 , 0x18a76736 , 0x0e986208 , 0x2ffb414f ] )
+  , Mont::unsafe_make( [ 0x2b693378 , 0x2b21b48b , 0x5a0da820 , 0xf544109d , 0xb48cfac1 , 0xa01ccb95 , 0x1c1281ab , 0x104b1ff3 ] )
+  , Mont::unsafe_make( [ 0xf9a988b6 , 0x5e960332 , 0x074f57a4 , 0x787fc821 , 0xf22937a0 , 0xe97d8be4 , 0x001baf58 , 0x1d947b0b ] )
+  , Mont::unsafe_make( [ 0xce1e1be7 , 0x9437105a , 0xa1178d4b , 0x36ad32dc , 0x3cb6d322 , 0x5575cd32 , 0xcd58786d , 0x0381d31c ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xd8ccd58a , 0x2bb15bf9 , 0xf47ba137 , 0x1c19806a , 0xbe75f0f2 , 0x45dc7d3e , 0x6ef90682 , 0x17d6ef9c ] )
+  , Mont::unsafe_make( [ 0x6da62e41 , 0x6779bfd8 , 0x075d930d , 0xb2f2cdb0 , 0x3b29c36b , 0x7239281c , 0xc646149e , 0x1c91ab9a ] )
+  , Mont::unsafe_make( [ 0x4261b691 , 0xa086ab30 , 0x962b92ab , 0x9d350a74 , 0xfb9fabb6 , 0x90da83e3 , 0x36cde6cb , 0x1768f55d ] )
+  , Mont::unsafe_make( [ 0xd25d9f4e , 0x90be20ea , 0xc5df821a , 0x2033d0d1 , 0xec1bc235 , 0xb12af7ec , 0x6dd7d26c , 0x277f3aa2 ] )
+  , Mont::unsafe_make( [ 0x743a5e08 , 0x463e4c35 , 0xe5af99d7 , 0x62771622 , 0x352eaee3 , 0x6985fc0b , 0xd3ae83f0 , 0x19e69291 ] )
+  , Mont::unsafe_make( [ 0xde4b79e4 , 0x01d3b41c , 0xf16895df , 0x9fcd82ac , 0xf8ec897c , 0x1a3dbdaf , 0x53cedbb2 , 0x0a2189eb ] )
+  , Mont::unsafe_make( [ 0x50b0cf6f , 0xc218059d , 0x49a9b7f9 , 0x2a651824 , 0x11c6df73 , 0x96bd41fc , 0x88f811f1 , 0x14f3aa96 ] )
+  , Mont::unsafe_make( [ 0x08dfe765 , 0x029441cc , 0xafca6de9 , 0x92a22bd6 , 0x403a9094 , 0x3e4207d8 , 0x9a4d72a8 , 0x21e7b52f ] )
+  , Mont::unsafe_make( [ 0x15d4e36d , 0xe700cd93 , 0xb4502351 , 0x2982d076 , 0xd6722fc2 , 0x9bb02f16 , 0x130561b1 , 0x2686e4c3 ] )
+  , Mont::unsafe_make( [ 0x8153e24b , 0x82b327a4 , 0xa83df244 , 0x5d29931e , 0xe4cebc74 , 0x916ce04e , 0x007aa0b7 , 0x03ed7aeb ] )
+  , Mont::unsafe_make( [ 0xe5bace73 , 0x95ea50e5 , 0xc77c5a74 , 0xf4c392bd , 0x083544ee , 0xcea13f29 , 0xb48b237f , 0x2933db7b ] )
+  , Mont::unsafe_make( [ 0xfe8b3554 , 0x5dda94c2 , 0xa9d3c10c , 0x0e657202 , 0x3cc238b2 , 0x9ec29704 , 0x728d2cd0 , 0x10935fed ] )
+  , Mont::unsafe_make( [ 0xeaae5551 , 0x28ce080a , 0x69342de9 , 0x08ff568d , 0xdfc7a95e , 0x9749d124 , 0xeebae14d , 0x1b61f930 ] )
+  , Mont::unsafe_make( [ 0x2ee806b0 , 0x7fc667af , 0x1e631c27 , 0x44aa6640 , 0xf51f956d , 0x6e47304e , 0x675fa8a0 , 0x2dccd629 ] )
+  , Mont::unsafe_make( [ 0xd03df782 , 0x3f43ef44 , 0xe6338835 , 0x0d37fe3a , 0xa212f3a4 , 0x17055a97 , 0x5f420f39 , 0x150a5552 ] )
+  , Mont::unsafe_make( [ 0x09e8e045 , 0x4a1477b2 , 0x0370c3e0 , 0x4e2d529d , 0x904762da , 0x19dd3bf1 , 0xb255f016 , 0x2ebe176a ] )
+  , Mont::unsafe_make( [ 0x404f3ec3 , 0xd478be6b , 0x7eb7cc35 , 0xb782f76d , 0x3306c985 , 0xefccfe0b , 0x2fea3447 , 0x2c43b063 ] )
+  , Mont::unsafe_make( [ 0x9c825bd8 , 0xb0c6b612 , 0xe0350e20 , 0x48081dc3 , 0x39634d0c , 0x8ed0d94f , 0x11e9e25f , 0x20e27bb9 ] )
+  , Mont::unsafe_make( [ 0xe6b30fa3 , 0xc0cca488 , 0x1a9b234a , 0x454fd14f , 0xa8212a5b , 0x16d8a6f8 , 0xcb430f54 , 0x239ffde0 ] )
+  , Mont::unsafe_make( [ 0x2d68f5f0 , 0xf0805f89 , 0x59959c08 , 0x7f72277c , 0x6b51c281 , 0xc0c1aee5 , 0x0d06bd65 , 0x0338e940 ] )
+  , Mont::unsafe_make( [ 0x1c0ff6c6 , 0x22d010f9 , 0xf2ea4d10 , 0x4d0ef05c , 0x8b848134 , 0x4843920c , 0xf9c3bc19 , 0x165f3e47 ] )
+  , Mont::unsafe_make( [ 0x61d81c5c , 0xc66fdc6c , 0xfdc87613 , 0x450f2858 , 0x08a2ac24 , 0xfb51b8c1 , 0x38cc8cdc , 0x0dc6b580 ] )
+  , Mont::unsafe_make( [ 0xbc1e70d7 , 0xef42dbd1 , 0x2316969c , 0x715c1d10 , 0x5840f893 , 0x568a9eb8 , 0x7a64791b , 0x1cc34931 ] )
+  , Mont::unsafe_make( [ 0x07d8acf5 , 0xa4792973 , 0x2f88da68 , 0x12080cec , 0x63b7626d , 0x0b29b8e9 , 0x04c5ea1c , 0x03c41254 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x120b0966 , 0x7ae669a8 , 0x066a383b , 0xd557c8fd , 0xa332a3c2 , 0xefa5e7dc , 0x422adc63 , 0x0ca49c56 ] )
+  , Mont::unsafe_make( [ 0x3845dee3 , 0x00268428 , 0x7d87a109 , 0x00ac974b , 0x2e737e18 , 0x2e696f4c , 0x206676fd , 0x0a928c63 ] )
+  , Mont::unsafe_make( [ 0xe1b2af2b , 0x06eaa877 , 0xccaa8a0e , 0x4b425d66 , 0x691b5da8 , 0xba5398af , 0x586598e1 , 0x17699f02 ] )
+  , Mont::unsafe_make( [ 0x04774692 , 0x12ef2e05 , 0xb073c548 , 0x38f0b0de , 0x5fcb28a0 , 0x2c5bcfb6 , 0x763aafd9 , 0x07372f10 ] )
+  , Mont::unsafe_make( [ 0x48146934 , 0x7625a146 , 0x3c017410 , 0x7369bb2d , 0x3eb1d066 , 0x49cab772 , 0xc81e3f73 , 0x16dc91c0 ] )
+  , Mont::unsafe_make( [ 0x3fc415fd , 0x8a0aed87 , 0x01c9d481 , 0x39c474fc , 0x4d5f13a0 , 0x0076bf79 , 0x6789ef78 , 0x0b659dab ] )
+  , Mont::unsafe_make( [ 0x11a1f194 , 0xd536661d , 0x9684ccec , 0x8a253226 , 0x1e1b3a03 , 0xc898251f , 0x654245a6 , 0x1101f802 ] )
+  , Mont::unsafe_make( [ 0x0c4b2d53 , 0x216d1a23 , 0xe8d17e4e , 0x6fa257fe , 0x329439bc , 0x6d2c4605 , 0xcbc70517 , 0x29761852 ] )
+  , Mont::unsafe_make( [ 0x3f6be304 , 0x5d89d4d5 , 0x6e82caca , 0x11936dd8 , 0xb6576f11 , 0xde2ad407 , 0xee73e4dd , 0x00bf8589 ] )
+  , Mont::unsafe_make( [ 0x49066b3b , 0xe9a2a16e , 0x63842b07 , 0x11c080cd , 0x431b5dc8 , 0xfcc57079 , 0x0e2a9a5a , 0x25143d4e ] )
+  , Mont::unsafe_make( [ 0xcc6b39e0 , 0xb4cd153c , 0xecc1c700 , 0x9178ab19 , 0x816b2203 , 0x94a4bc38 , 0x2682d76c , 0x1201453a ] )
+  , Mont::unsafe_make( [ 0xaff3f055 , 0xddb29dfe , 0xd5f52e10 , 0xaff39df3 , 0xdc76d7ec , 0xad7d0bbe , 0x6956e24d , 0x2e965149 ] )
+  , Mont::unsafe_make( [ 0x2006b93f , 0x5eedf9e6 , 0x4c33069b , 0x98c39806 , 0x27910f18 , 0xe299cf8e , 0x68d4d2bf , 0x157acf9f ] )
+  , Mont::unsafe_make( [ 0xf9a72ec8 , 0x58d10c9d , 0x9ac5b79c , 0xda3917c8 , 0x667044c1 , 0x6c1d62ba , 0xe0ce3a5d , 0x1f62f564 ] )
+  , Mont::unsafe_make( [ 0x979334dd , 0x0e5d8abb , 0xa455ace0 , 0x230b112f , 0x5c91a860 , 0xd8b40684 , 0xecc85ea5 , 0x21488b4c ] )
+  , Mont::unsafe_make( [ 0x4cc94483 , 0xc2875604 , 0x42292c95 , 0x60628cd7 , 0xa7258e43 , 0x5c211181 , 0x1bc8c9d8 , 0x1ee99dd5 ] )
+  , Mont::unsafe_make( [ 0x68aec485 , 0x7d1cfd72 , 0x241c889b , 0x04f43a3d , 0xd53c9bc4 , 0xbe58aab6 , 0xc9cfd820 , 0x1f8763c1 ] )
+  , Mont::unsafe_make( [ 0xb2c94cac , 0x41b84f13 , 0x461f8afe , 0x52f6fe53 , 0xb0e09d76 , 0x923a8808 , 0xfc754d2c , 0x28669fc8 ] )
+  , Mont::unsafe_make( [ 0xc2d177d8 , 0xa2a89407 , 0xaed9d2e4 , 0x404d027b , 0x538955c1 , 0x205e4900 , 0x4fc6e663 , 0x2d02af5f ] )
+  , Mont::unsafe_make( [ 0xb5c8162a , 0xf3bcef7d , 0x11ff784b , 0x893524d4 , 0x7b89e9bb , 0x6a9cd67c , 0x911c6c43 , 0x0975f682 ] )
+  , Mont::unsafe_make( [ 0x227bdafb , 0x9d6d93e9 , 0x6f7e5b64 , 0xf9ee7e28 , 0x4bb98cb4 , 0x7c1e33b1 , 0x2f9e4d05 , 0x105814a7 ] )
+  , Mont::unsafe_make( [ 0x6dd1deaf , 0xcb50ddc0 , 0x6793af84 , 0xfbdbaf3f , 0x47ed6cf2 , 0xa17ea148 , 0x7255c597 , 0x2731c5fc ] )
+  , Mont::unsafe_make( [ 0xe26c7aee , 0x7436b13a , 0xb40b3270 , 0xfcaccd11 , 0x9490e46d , 0xcd246d82 , 0xb0c4f9b4 , 0x020d8ea3 ] )
+  , Mont::unsafe_make( [ 0xe93d56cc , 0xa330dfe8 , 0x7b52370f , 0x2be1b2cd , 0x2e1e1acc , 0xa0d9984e , 0xbf50ebd2 , 0x2e209e17 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x2a9c7e6d , 0xcfbfea18 , 0xe0fa380f , 0xf5f11d3a , 0x7f0dbec9 , 0xc6d85424 , 0x8676c448 , 0x11e8dafc ] )
+  , Mont::unsafe_make( [ 0x68186157 , 0xa253c6e3 , 0xbfd33251 , 0xb733648b , 0xb8c09848 , 0xd42d4a33 , 0xa02fe99f , 0x2a038265 ] )
+  , Mont::unsafe_make( [ 0xec4b4e6a , 0x73827d90 , 0x325b8bda , 0x9a61b6d7 , 0xd9cd04ed , 0xf62162f4 , 0x709d3443 , 0x07dc9c7c ] )
+  , Mont::unsafe_make( [ 0x3f599b40 , 0x11ba0add , 0xd5658d5e , 0x6781e209 , 0x2c9f299a , 0x2dac0b8a , 0xb48e909c , 0x19b0b851 ] )
+  , Mont::unsafe_make( [ 0x73b491f4 , 0x56f8a301 , 0x7724645c , 0xe5e8a5aa , 0x92cd8eb2 , 0x4efc3f18 , 0x0dcbbb78 , 0x1d64a1c2 ] )
+  , Mont::unsafe_make( [ 0xdc0dac9b , 0xb92452d6 , 0x617af59b , 0x27fbeb1c , 0x16ad1937 , 0xfef8eba5 , 0x3e5f3025 , 0x211cedf9 ] )
+  , Mont::unsafe_make( [ 0x3400f7ce , 0xfcb904e0 , 0x565adfab , 0x589fdde3 , 0xac3c6e42 , 0x9914e1f8 , 0xeca07253 , 0x23e67e74 ] )
+  , Mont::unsafe_make( [ 0xf4d81b4f , 0xc266e585 , 0x682de530 , 0xd50fd38e , 0x8caea86a , 0xb62af061 , 0x4725c68a , 0x20cf9fdc ] )
+  , Mont::unsafe_make( [ 0x91c6555a , 0x48ce3c33 , 0x9887f46b , 0x88fa8db7 , 0xd7ee0f3e , 0x1b79c95e , 0x488091a1 , 0x1e16b7c0 ] )
+  , Mont::unsafe_make( [ 0x54aac1ec , 0x03366d1f , 0x7a5c1be0 , 0x06124283 , 0x7af771a5 , 0xf6c86887 , 0xdf1f414e , 0x24307f8b ] )
+  , Mont::unsafe_make( [ 0x80f0be5d , 0x99f57908 , 0x08054881 , 0x2275aa22 , 0x5ef31d20 , 0x238a1a63 , 0x3d908bc6 , 0x241dd146 ] )
+  , Mont::unsafe_make( [ 0x715a7b54 , 0x27076f19 , 0x3435b0c4 , 0x5d6c80af , 0x6f5cb313 , 0xabf20a21 , 0xe7901102 , 0x0f424a2c ] )
+  , Mont::unsafe_make( [ 0x6274898f , 0x58b4a105 , 0x838eee65 , 0x4a88f7b2 , 0xbf8cd354 , 0x652d2eb6 , 0x6f21047a , 0x268d5eaa ] )
+  , Mont::unsafe_make( [ 0x233d6202 , 0x8c8a35cb , 0x6c92ecdc , 0xfa359b34 , 0x44819fc1 , 0x1669625f , 0xaf33ea27 , 0x15150b3d ] )
+  , Mont::unsafe_make( [ 0xa2223c04 , 0x293492bb , 0x19975488 , 0x565b9c80 , 0x22834ea7 , 0xa87f1279 , 0xa7ddeced , 0x2008aa2f ] )
+  , Mont::unsafe_make( [ 0xd2b666b1 , 0x651e803f , 0xc209b95d , 0xb2540a3f , 0xe972fe54 , 0x33249058 , 0x0ecda95d , 0x0ea9e780 ] )
+  , Mont::unsafe_make( [ 0x683eaada , 0x47761c3a , 0x403ab246 , 0xdf48897b , 0xb9e90103 , 0x43fc1aad , 0xcc510c65 , 0x10a88767 ] )
+  , Mont::unsafe_make( [ 0xd7b3db08 , 0xfdd352e1 , 0xea088510 , 0x0e56ba17 , 0x79b08375 , 0x03881dd7 , 0x15e1bb99 , 0x19500e4f ] )
+  , Mont::unsafe_make( [ 0x293f2e4d , 0x3e3fd5bf , 0x7f11c01f , 0x9f84d9a2 , 0x2374df9f , 0x55d5e110 , 0x4ee7a2db , 0x08ae04dd ] )
+  , Mont::unsafe_make( [ 0x97d63c3c , 0x72083b29 , 0xef5146a8 , 0x70fd4a7d , 0x2c8216f9 , 0x3291421d , 0xf96878fc , 0x2232c3e1 ] )
+  , Mont::unsafe_make( [ 0xf8b7ac20 , 0x6fee911e , 0x63877c9c , 0x4731e992 , 0x1b25f0ba , 0x5386cea8 , 0xba1db137 , 0x2ff9d5df ] )
+  , Mont::unsafe_make( [ 0x7c1ad0aa , 0xee52ce18 , 0x0dc764ac , 0x056c864e , 0x274dd998 , 0xbe65a2f0 , 0x68826b6a , 0x0fc0c801 ] )
+  , Mont::unsafe_make( [ 0x89c3d402 , 0xbbf7c205 , 0xade0e7dd , 0xc0cd7603 , 0xc0c3aa36 , 0xb71781cd , 0x7c9ab00c , 0x0a788395 ] )
+  , Mont::unsafe_make( [ 0x8ce52b67 , 0x1b4b3e31 , 0x4a2c7439 , 0x77dbaaf4 , 0xb2824f2d , 0x050f92b0 , 0xd3f8f880 , 0x28672670 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x2ba14219 , 0xe69a18d3 , 0x67e62410 , 0x03b34811 , 0xfbe25f8c , 0x4538ba91 , 0xf06faba5 , 0x0586ef46 ] )
+  , Mont::unsafe_make( [ 0x2990b4ba , 0xe2f17fff , 0x631077d5 , 0x3b541d44 , 0x91dc03c2 , 0x469a3b9f , 0x18c68b24 , 0x1e9077e1 ] )
+  , Mont::unsafe_make( [ 0xaf50da3a , 0xf9058b18 , 0x27ffc02c , 0x6f69bd87 , 0x97b03404 , 0xe470f13d , 0x768426fe , 0x26252e76 ] )
+  , Mont::unsafe_make( [ 0x721f82d9 , 0xaa87c0f7 , 0x4669728d , 0xe0a3f90f , 0xc3b49f9d , 0x23ae64d0 , 0xe408ab8b , 0x2af82a78 ] )
+  , Mont::unsafe_make( [ 0xfebb7cf2 , 0x766c124e , 0x8c56cbe4 , 0x24067edc , 0xb318a3ea , 0x2053b9c8 , 0x75a31ade , 0x1a2eeb80 ] )
+  , Mont::unsafe_make( [ 0x0dde1f3d , 0x07f0b3cd , 0x3b3740d5 , 0x72fc6515 , 0x3365eda7 , 0x75e184d7 , 0xa588a611 , 0x2888dcce ] )
+  , Mont::unsafe_make( [ 0xc6588d3f , 0xab653117 , 0x711dcacd , 0x651f7844 , 0x826380aa , 0xe4d55e22 , 0x154f79cf , 0x03418846 ] )
+  , Mont::unsafe_make( [ 0xc31313b5 , 0x591d8729 , 0x8535d4b4 , 0xcb73d7ce , 0x0e322655 , 0xe0bd76d4 , 0x04f3bf66 , 0x116a8e74 ] )
+  , Mont::unsafe_make( [ 0xd417849f , 0x55f0d458 , 0x50f8cb09 , 0x78853653 , 0x24918543 , 0x63132c54 , 0x9c0afe5a , 0x1b11e1ab ] )
+  , Mont::unsafe_make( [ 0xb242b43f , 0x173fe6f5 , 0x4e56fe6a , 0xdeab6a3f , 0xd83e04ee , 0xedbadd28 , 0x0090e5fd , 0x2a854d1d ] )
+  , Mont::unsafe_make( [ 0xe12edc89 , 0x45810157 , 0xe640d23f , 0xecb24441 , 0x6ade5176 , 0xe6c7ec61 , 0xd4a80de0 , 0x180d00f4 ] )
+  , Mont::unsafe_make( [ 0xd3bb0db6 , 0x48b232b0 , 0x67ff76c9 , 0x2712e02a , 0x03621efb , 0x830ce4c2 , 0x53f99550 , 0x189761e5 ] )
+  , Mont::unsafe_make( [ 0x55c2b2ae , 0x5ccc50ef , 0xffeb0daf , 0x60933e2b , 0xb4b73e4c , 0x42c9c71b , 0x374af34e , 0x0579a68d ] )
+  , Mont::unsafe_make( [ 0x83f36a7d , 0x84aa00b8 , 0x78b0119e , 0xcc15b9a2 , 0x62a96551 , 0x850e91f2 , 0xe3fdf078 , 0x01cb36fa ] )
+  , Mont::unsafe_make( [ 0x6b927e34 , 0x489a4c7d , 0x71353fa5 , 0x786f76c0 , 0x05e25df9 , 0x45d12ba9 , 0x5a152a00 , 0x1dba6f7e ] )
+  , Mont::unsafe_make( [ 0x7a94ca01 , 0x3d438c76 , 0xc252846f , 0x572983a1 , 0x39f09ebb , 0x17002986 , 0xb8fe60a9 , 0x0f25db7f ] )
+  , Mont::unsafe_make( [ 0x2119136e , 0x3ceac320 , 0x97213bbd , 0x0bc6cc88 , 0xe65ce2fd , 0x696d0d87 , 0xedffb893 , 0x0d61ce0f ] )
+  , Mont::unsafe_make( [ 0xe5c40b37 , 0x624517e0 , 0x3d69ae71 , 0x693c71fa , 0x6252e400 , 0x559917a0 , 0xbb1bd291 , 0x115c24c4 ] )
+  , Mont::unsafe_make( [ 0x86e3c6e2 , 0x0d1269b5 , 0x23051d7e , 0x9970be0f , 0x524fca8f , 0x00a46358 , 0x2367794c , 0x2f204f18 ] )
+  , Mont::unsafe_make( [ 0xbedb5f05 , 0x8eaac4a0 , 0x6924345e , 0x4b2cd5c9 , 0xcc628000 , 0x933ea4c8 , 0x2e299e58 , 0x1819a21f ] )
+  , Mont::unsafe_make( [ 0x60d54267 , 0xe360b009 , 0xebbfdc5d , 0xade1d187 , 0xad9d3013 , 0x1d862391 , 0x76081bc6 , 0x0accf152 ] )
+  , Mont::unsafe_make( [ 0x46e7b970 , 0x0d44b26f , 0x90dff4e1 , 0x32c803cf , 0x4f4db544 , 0x7debcfef , 0x4f590844 , 0x067ecffc ] )
+  , Mont::unsafe_make( [ 0xc6c7e4c7 , 0xb27643a0 , 0x40ef1743 , 0x2b21080d , 0xfe9f2e08 , 0x9cec6b4e , 0xbd5b28d4 , 0x133d881b ] )
+  , Mont::unsafe_make( [ 0x7372f1de , 0xf2b20f12 , 0x11e9cdfe , 0xc6bac9af , 0x0480dd15 , 0xc10b7ad6 , 0x7f259c9a , 0x290fd9e9 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x71f52ffa , 0xf26dcbd5 , 0x9e119321 , 0x24e91a3d , 0xf13acfe6 , 0x9ae8946a , 0x0c3384b5 , 0x1eeae5c2 ] )
+  , Mont::unsafe_make( [ 0x7c364626 , 0x5ec853d8 , 0xa9b2cb66 , 0x700127e6 , 0x5c688807 , 0x94e5a1ad , 0xb56bdcd2 , 0x0eac1499 ] )
+  , Mont::unsafe_make( [ 0xf2578e17 , 0xf7a0ae66 , 0x205a5813 , 0xee88354f , 0x26e04b45 , 0xa19ac698 , 0xa77bb559 , 0x0e7f6c11 ] )
+  , Mont::unsafe_make( [ 0xaa299bb7 , 0x34e69b31 , 0xda86fef8 , 0xacb89119 , 0x7abda873 , 0x67e4db97 , 0x63d4440f , 0x128bdf02 ] )
+  , Mont::unsafe_make( [ 0x85ea510a , 0x0511421a , 0x9e88c6ff , 0x7aec7453 , 0x02468f8c , 0xb89b440a , 0x24bff161 , 0x10a46812 ] )
+  , Mont::unsafe_make( [ 0xa3aac455 , 0x171a596b , 0xebe584e4 , 0xa3992800 , 0x191833da , 0x4cdebbb8 , 0x677dba3b , 0x0ce2ea0b ] )
+  , Mont::unsafe_make( [ 0x584460c3 , 0x324227fb , 0xb065befd , 0x95c4a8cd , 0x39622253 , 0xa1e7c0d3 , 0x51c54d1e , 0x03a4e0d3 ] )
+  , Mont::unsafe_make( [ 0x0817bd20 , 0x3b499f3b , 0x5359370e , 0xdcf7ecf6 , 0xb7f5bf3a , 0x47781e12 , 0xf8fdfd24 , 0x15117b52 ] )
+  , Mont::unsafe_make( [ 0xc91b01e6 , 0x15eeb94f , 0x3d72450b , 0x045f14bc , 0x3d0d6627 , 0x00a4a5fb , 0x48c6e43b , 0x1dcf8e5b ] )
+  , Mont::unsafe_make( [ 0xe9c79319 , 0xf52b6768 , 0x0edd770d , 0x2aeadc73 , 0x16476e8a , 0x1f40424f , 0x27e06b7f , 0x0fee549a ] )
+  , Mont::unsafe_make( [ 0xf9d5fb21 , 0xa812bf2f , 0x621b6275 , 0x66b99435 , 0x95e6c01b , 0x95ac3d79 , 0xde9576b1 , 0x06d1b1a8 ] )
+  , Mont::unsafe_make( [ 0x316d203c , 0x8a1dad0b , 0x81731707 , 0x73cd3a33 , 0x26c324d6 , 0x1ef2d960 , 0x0624820f , 0x0bef605c ] )
+  , Mont::unsafe_make( [ 0x892a8435 , 0xe798de12 , 0x7839641d , 0x4b3dae7e , 0x2211de3b , 0xa83c357b , 0x4aa217b5 , 0x16cff4c2 ] )
+  , Mont::unsafe_make( [ 0x3619f800 , 0x7d2dc9e1 , 0xb320ab2b , 0x7286894c , 0x9ae940cb , 0x4ae20503 , 0x88839460 , 0x0f52aa52 ] )
+  , Mont::unsafe_make( [ 0xdf98137f , 0x8a590618 , 0xe14132ef , 0x6235586b , 0x14f7be1a , 0x83989c24 , 0x738f1a66 , 0x15d2615f ] )
+  , Mont::unsafe_make( [ 0xc3bfa50e , 0xa399ca9a , 0x3712301b , 0x365af9bd , 0xe821cddf , 0xaac6e670 , 0x2e9f4b95 , 0x2f0eabe1 ] )
+  , Mont::unsafe_make( [ 0x88878f71 , 0x0dd253de , 0x4a7d692e , 0x6e829a09 , 0xad7dece1 , 0xaf45e735 , 0x0d0d8bd3 , 0x252c76e5 ] )
+  , Mont::unsafe_make( [ 0x72eaab4a , 0x83f855da , 0x288d6404 , 0x9c0e7ee4 , 0x349e3591 , 0x942a1450 , 0x337e67a3 , 0x111183a5 ] )
+  , Mont::unsafe_make( [ 0xe50c8b3c , 0xb51b30fe , 0xc69aa43d , 0x97fb157a , 0x4b634452 , 0xfda3ab7b , 0x58465f77 , 0x28b841e1 ] )
+  , Mont::unsafe_make( [ 0x1fa7e955 , 0xefb33985 , 0xfd78d08e , 0x079383f9 , 0x3f585091 , 0x34e97410 , 0x7f13a8fa , 0x148b3ed7 ] )
+  , Mont::unsafe_make( [ 0x7953599f , 0x4887fa9b , 0xcea9f6d9 , 0x606e8716 , 0x10a19a75 , 0x2e186bda , 0x9a515de8 , 0x1d6b90f5 ] )
+  , Mont::unsafe_make( [ 0x44dc7ad0 , 0xf3ed7ac8 , 0x089e9a02 , 0xd13425c8 , 0xc97cba0d , 0x36f8e305 , 0xb9a6ebad , 0x017b9f50 ] )
+  , Mont::unsafe_make( [ 0xac7bf41e , 0x12fedf63 , 0x4f66a642 , 0x16d0a104 , 0x2cea8e11 , 0x755aa6d6 , 0xdbae509a , 0x2496de7d ] )
+  , Mont::unsafe_make( [ 0x90f8096b , 0x3f16b23f , 0x1d09ff3a , 0xb2cf3aa8 , 0x24170bff , 0x70f090e4 , 0x812cc413 , 0x2954178f ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xd13c4b14 , 0x20cc579e , 0x8a6214b9 , 0x959fa2bd , 0xa4cf9b32 , 0x31822af4 , 0x920fe11a , 0x2a181611 ] )
+  , Mont::unsafe_make( [ 0x1fd3ac52 , 0xafd62696 , 0x76edc285 , 0xbd73390d , 0x149683c4 , 0x1ce53d6c , 0x0e4cb088 , 0x22271d95 ] )
+  , Mont::unsafe_make( [ 0xcfe423c9 , 0x2231e9ee , 0x7576b0ac , 0x922f56c4 , 0xece5b136 , 0xf3dcbffb , 0x557eb52c , 0x153f32b6 ] )
+  , Mont::unsafe_make( [ 0x5cd386c2 , 0x27220e79 , 0xa68d8209 , 0xc17311a8 , 0xea874577 , 0xfe4c6bf1 , 0x7408e422 , 0x16acaa85 ] )
+  , Mont::unsafe_make( [ 0xc81ac787 , 0xf340ee4b , 0xef9623fa , 0xf9a726a5 , 0x5811d1a8 , 0xb7844767 , 0xeb98ee73 , 0x1d68f0c3 ] )
+  , Mont::unsafe_make( [ 0xe2d9075b , 0x5b67e05d , 0xc84179b4 , 0x23f6422d , 0x0beb832d , 0x94743df1 , 0xd21e3467 , 0x0e7f1b1d ] )
+  , Mont::unsafe_make( [ 0x222fdd57 , 0xbde99b77 , 0xfc021175 , 0x560a0da6 , 0x5ed21eb1 , 0xf188c36a , 0x4e3c2670 , 0x01d15fb0 ] )
+  , Mont::unsafe_make( [ 0x7d7f2553 , 0x6579b496 , 0xc4b92a61 , 0x82a11ca8 , 0x1f91254f , 0x180fd6c5 , 0xc2c5cad4 , 0x16ba4196 ] )
+  , Mont::unsafe_make( [ 0x6f3157ba , 0x61fca9b5 , 0x1aa71e7c , 0xf1789a4b , 0x28f593ea , 0xa3bb9e87 , 0x24f2d9e2 , 0x25271a50 ] )
+  , Mont::unsafe_make( [ 0x477cb1ef , 0x1cf18955 , 0x173fb94d , 0xfa86fcfb , 0x5fc36d5c , 0x03347680 , 0x2199edee , 0x01f4f9e3 ] )
+  , Mont::unsafe_make( [ 0x4ed432d3 , 0xf94f6f64 , 0x5df4e3c7 , 0xd4506954 , 0x209b8547 , 0x8649a43b , 0x031732a4 , 0x28c39858 ] )
+  , Mont::unsafe_make( [ 0x8444e5ee , 0x412c0cac , 0x2c60f834 , 0x0ff34591 , 0x36e188ae , 0xb99b77e8 , 0x73675bb7 , 0x09feec4c ] )
+  , Mont::unsafe_make( [ 0x24cfeb45 , 0x1e56512b , 0xf1154710 , 0x93b1bbfb , 0xd8d3962b , 0xbaf15e67 , 0x16920e04 , 0x24bb7dbc ] )
+  , Mont::unsafe_make( [ 0x53ee4fe1 , 0xc7d50d93 , 0x2b147c2a , 0xc8822e6f , 0x31cbec77 , 0x98547f39 , 0xee8ba4d5 , 0x1b21de07 ] )
+  , Mont::unsafe_make( [ 0x6125291f , 0xe248b6c2 , 0x57d00ead , 0xcd058447 , 0x64b5aec0 , 0xd10733d7 , 0x17b28281 , 0x27420288 ] )
+  , Mont::unsafe_make( [ 0xa7a8b68b , 0x8be729ba , 0xd7621c09 , 0x40d088e5 , 0x2cd2849c , 0x94c427e8 , 0xb7265b32 , 0x055b7d32 ] )
+  , Mont::unsafe_make( [ 0x5e4e6655 , 0x5610a47c , 0x76f6e968 , 0xc3e22798 , 0xdaf5a3c5 , 0x6dd34aa9 , 0x84a953ea , 0x2a2ca1ce ] )
+  , Mont::unsafe_make( [ 0xbf96036d , 0x5b74d2f1 , 0x8bcf9203 , 0x5eaedb3f , 0x5810521d , 0xc2fc6e03 , 0x23e19fe2 , 0x0d8a7c90 ] )
+  , Mont::unsafe_make( [ 0x9e417e6c , 0x05a6b8f3 , 0xec19fdb3 , 0x20418012 , 0xe114370f , 0x48a3ce83 , 0xdf917ced , 0x035b4221 ] )
+  , Mont::unsafe_make( [ 0x4a1072c2 , 0x932b2be6 , 0xf50896d8 , 0xa771d9d5 , 0xd31059d8 , 0x96b804c6 , 0x38f0de7f , 0x0f84b6e9 ] )
+  , Mont::unsafe_make( [ 0xf59886c2 , 0x1fce7564 , 0x8ba858c2 , 0x60240409 , 0x9593ff03 , 0x29935791 , 0x05521aed , 0x2465fb2f ] )
+  , Mont::unsafe_make( [ 0x24de3e27 , 0x5ba2d173 , 0x94cde576 , 0x9e7b142d , 0xb37f4e2e , 0xf01e971c , 0x132e6c17 , 0x28ba5869 ] )
+  , Mont::unsafe_make( [ 0x59480cfb , 0xe8b682f2 , 0x60cdfb29 , 0xe7514334 , 0xca16bf1d , 0x5f05fca8 , 0x5e28b076 , 0x0ea151bc ] )
+  , Mont::unsafe_make( [ 0x4d0ab4e5 , 0xfc975777 , 0x0696454c , 0x61901e11 , 0x29048210 , 0xddf10b26 , 0xe56f46b3 , 0x03918c92 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x0275cf7b , 0x30770de5 , 0xf18a5cda , 0xc5bc3c11 , 0x8440a03d , 0xfa7c728d , 0x83ff24ff , 0x10f5c65a ] )
+  , Mont::unsafe_make( [ 0x12280750 , 0x03fcbcd1 , 0x4f187d88 , 0x373c28a8 , 0x28c7d5fb , 0xee3c247a , 0x0c545f3e , 0x0dcea119 ] )
+  , Mont::unsafe_make( [ 0x0bfb092b , 0xfd187a55 , 0xc773604a , 0xabe1d8ea , 0x561773fc , 0x069c1100 , 0xa8757293 , 0x19f4f92d ] )
+  , Mont::unsafe_make( [ 0xf58cf134 , 0x9b50465f , 0xdc26cb9c , 0x2bb74a91 , 0xf3ce7a51 , 0x9f01515d , 0x2642993a , 0x1de3d348 ] )
+  , Mont::unsafe_make( [ 0x350b7f3c , 0x9fe1d4e3 , 0xc2e2c41c , 0x5129b91c , 0x33c5dfe2 , 0x059ef8a0 , 0xfe638a70 , 0x10b05cac ] )
+  , Mont::unsafe_make( [ 0x96b81402 , 0xc844207e , 0x38e0eade , 0x6b9c6e12 , 0xcdf6ab21 , 0xbdcbb9d4 , 0x3c0b5fd3 , 0x1c4c06c4 ] )
+  , Mont::unsafe_make( [ 0x6e2d2b51 , 0xdeaffc64 , 0xeae53f20 , 0x73ddf825 , 0x9335e1e2 , 0x0ceae454 , 0x48f7e728 , 0x22761263 ] )
+  , Mont::unsafe_make( [ 0x3e8c2608 , 0x7f5fab18 , 0xdc7b3d85 , 0x7751f864 , 0xa9685117 , 0x8431b55c , 0x1f8c18dd , 0x2a96ccc0 ] )
+  , Mont::unsafe_make( [ 0x38df5abb , 0x7c0d6bd0 , 0x10b6b3b2 , 0xb4f0ac7d , 0x9b1cdab5 , 0x10cd1833 , 0x113f9510 , 0x18d38903 ] )
+  , Mont::unsafe_make( [ 0x00298eeb , 0x034cff73 , 0xe7da11f5 , 0x6bb0e515 , 0x87af5881 , 0xd2dcbac2 , 0xc858c982 , 0x2049730d ] )
+  , Mont::unsafe_make( [ 0x843ae8f6 , 0xa0883089 , 0xef45ee16 , 0xbdc0c9e3 , 0xb063df8f , 0x3c07daf2 , 0xd36a5bba , 0x11f63f50 ] )
+  , Mont::unsafe_make( [ 0x5c64f149 , 0xffdc2c14 , 0x1c0ed24d , 0x1f530526 , 0x27a601db , 0x48dd64e9 , 0x19854227 , 0x1d58064b ] )
+  , Mont::unsafe_make( [ 0xc743091f , 0x2ead7bae , 0x17433812 , 0x23b37d3d , 0xc6053233 , 0xc955eb3c , 0x8837c04b , 0x056005b7 ] )
+  , Mont::unsafe_make( [ 0x22f04c44 , 0xb8b00d66 , 0xe3f79382 , 0x481f1d48 , 0xe61a8404 , 0xeb2d6e4a , 0x90256dbe , 0x026b8c69 ] )
+  , Mont::unsafe_make( [ 0xbdad81aa , 0x90f4db41 , 0x39a12e53 , 0x1050e799 , 0x2e5dd42b , 0x6fafe6ba , 0xa0c6c8ca , 0x2be541ec ] )
+  , Mont::unsafe_make( [ 0xdea6c50b , 0xc89e456f , 0x30c22265 , 0xb2ac9ae6 , 0x3be4a947 , 0xb7445c44 , 0x55e2d461 , 0x116eac40 ] )
+  , Mont::unsafe_make( [ 0x0dc1d436 , 0xd691fc77 , 0xbeaa7caf , 0x78c1b3b0 , 0x27b81357 , 0xddf1b638 , 0xbbb936b2 , 0x07121468 ] )
+  , Mont::unsafe_make( [ 0x4af3da79 , 0xb155ef8a , 0xc5fb9dcf , 0x6cb1d7f7 , 0xd9073e2e , 0x02e2d74c , 0xb018bc72 , 0x2db0ecd9 ] )
+  , Mont::unsafe_make( [ 0x21fc42ed , 0xfd140500 , 0x5023d722 , 0xf1b1b3e0 , 0x8ce74758 , 0xaa30515e , 0x9a641b3a , 0x0f397800 ] )
+  , Mont::unsafe_make( [ 0xfa506d8b , 0x02430f35 , 0xaec0b80b , 0x8a709d95 , 0xde067d7a , 0x22bf6137 , 0x97af9176 , 0x03a8754c ] )
+  , Mont::unsafe_make( [ 0x495166e6 , 0xe37e8d22 , 0xa6d29a0b , 0x0a0378b9 , 0xab5c8a58 , 0x906eb7d4 , 0x6884bc49 , 0x1bb6b58e ] )
+  , Mont::unsafe_make( [ 0xb8386a31 , 0x8178ce7b , 0xba9375ad , 0x961cc7f6 , 0x5c9c6ed1 , 0xf9d5397e , 0xb79c0db9 , 0x2f434534 ] )
+  , Mont::unsafe_make( [ 0x05301e13 , 0x04a13aa7 , 0x003a8f7d , 0x5ee563e4 , 0x18f9f7a5 , 0xe1fccde3 , 0xa2cc4b95 , 0x27648507 ] )
+  , Mont::unsafe_make( [ 0x76235422 , 0x5fc9fac4 , 0x3d99f463 , 0xcd2fc636 , 0x9da10959 , 0x740292f9 , 0x3494cfc3 , 0x2fb5723f ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xd5e67c3b , 0x2efbf395 , 0x2c3cddf6 , 0x19f07edd , 0x69610198 , 0xfe0281ab , 0x069ebdb5 , 0x00039099 ] )
+  , Mont::unsafe_make( [ 0x7b72c2f3 , 0x61729464 , 0xb9b11d0c , 0xd452d9f5 , 0xd1bb9f78 , 0x37581dd2 , 0xd8dc70bd , 0x2df4953c ] )
+  , Mont::unsafe_make( [ 0x07eed524 , 0xf9f19ccf , 0x792278dc , 0xdbe10e56 , 0x7b1646e2 , 0x88102ebf , 0x73997a40 , 0x15289939 ] )
+  , Mont::unsafe_make( [ 0x34057f45 , 0xfda9b939 , 0x3a850598 , 0xfec415b1 , 0x5d768d10 , 0x67d86381 , 0xd96fe49d , 0x128c8422 ] )
+  , Mont::unsafe_make( [ 0xb43b112c , 0x3809728f , 0x258bce18 , 0x5f6927cf , 0xb5dbdbcc , 0x0af19ab5 , 0x05156940 , 0x0a80fd87 ] )
+  , Mont::unsafe_make( [ 0x5277d04c , 0xb7998120 , 0x7833be47 , 0x934e7d59 , 0x55ab1f7c , 0x08965559 , 0x41ba3bb9 , 0x0d01493b ] )
+  , Mont::unsafe_make( [ 0xb1f7ff8b , 0xbf073198 , 0x586c35da , 0x9c54849b , 0x8ed804f8 , 0x85eaee6e , 0x84216a67 , 0x0176bd1a ] )
+  , Mont::unsafe_make( [ 0x0467408a , 0xee836b6a , 0xe65035f9 , 0x0be14f87 , 0x8a6b9e7c , 0x29094c24 , 0xb1922dab , 0x0f2cb820 ] )
+  , Mont::unsafe_make( [ 0xb9abce90 , 0x8994c827 , 0xa898db24 , 0x2c203272 , 0x87e75a63 , 0x1bc0d54a , 0x2a10de8d , 0x11879186 ] )
+  , Mont::unsafe_make( [ 0xeaff56df , 0xaa562bf5 , 0xa1a6d9d8 , 0xe9222e33 , 0x34708d8b , 0x52715c79 , 0x84bab9cc , 0x233c7110 ] )
+  , Mont::unsafe_make( [ 0x0d2efc92 , 0xed1c8878 , 0xbcabf871 , 0xbb81f1ff , 0x4d1bdbe7 , 0xaa366576 , 0x5d7b924a , 0x1473f7eb ] )
+  , Mont::unsafe_make( [ 0xed4d44e8 , 0xd6847d6a , 0xf8c5b9f2 , 0x5b09b1d1 , 0xdf3d038a , 0xa5a26fad , 0xa37d81a4 , 0x07bfc50d ] )
+  , Mont::unsafe_make( [ 0x8c174645 , 0x6992a55d , 0x26dbb1cd , 0x5ce31dc8 , 0xc05271ad , 0x48e9b2ce , 0xfe41bc6d , 0x2e7a1f79 ] )
+  , Mont::unsafe_make( [ 0x914f4553 , 0x9d7e95b7 , 0xd636e4fa , 0x3b6c4bef , 0x2ff09150 , 0x72d67053 , 0xd275b941 , 0x305bbd69 ] )
+  , Mont::unsafe_make( [ 0x5288ba92 , 0x9bbf8a76 , 0x16de0e7b , 0x876bd597 , 0x698fcaff , 0xb5ebb110 , 0xa9da7ccb , 0x0eb03bad ] )
+  , Mont::unsafe_make( [ 0x1909783b , 0x34e0e502 , 0x032cbc95 , 0x418f6f6a , 0xa2490f5c , 0x76d6a04a , 0x08bb88a9 , 0x2471d656 ] )
+  , Mont::unsafe_make( [ 0x1f0b8f07 , 0xff11d5c7 , 0x89b5ea27 , 0x316b112b , 0xa83f2f80 , 0x3556fc4c , 0xa20336b3 , 0x0ee046b9 ] )
+  , Mont::unsafe_make( [ 0x31897caa , 0x0f20c36d , 0x26fce215 , 0xc1f19fca , 0x7d5f4f19 , 0x872d431c , 0xd447dcd0 , 0x0cb7b359 ] )
+  , Mont::unsafe_make( [ 0x3a12b362 , 0xa50eecbe , 0xac0bf49b , 0x60e4c49b , 0x103bef47 , 0xc8921b12 , 0x3a8488db , 0x1103cb8e ] )
+  , Mont::unsafe_make( [ 0x09a611e1 , 0xdfe0a8ee , 0x31f76f27 , 0xb0313ed2 , 0x90b5a10c , 0x8523dc58 , 0xb6ab0722 , 0x013170ef ] )
+  , Mont::unsafe_make( [ 0x50d81cdd , 0x85fadab7 , 0x0bb99f67 , 0xd075fa82 , 0x32e51257 , 0xa38ecf85 , 0x70cf8031 , 0x011c4898 ] )
+  , Mont::unsafe_make( [ 0x30bd8f87 , 0x2d450d49 , 0xd20ed58f , 0x6f5aecd2 , 0x30a47898 , 0x9f6d06dd , 0xc1400953 , 0x2a4074a4 ] )
+  , Mont::unsafe_make( [ 0xebfc256a , 0xf03e33d7 , 0xcaaa6991 , 0xe911db0c , 0xf7ecc316 , 0x68e962c9 , 0x17f351ef , 0x2aacb5d8 ] )
+  , Mont::unsafe_make( [ 0x8bce636a , 0x6acb3253 , 0x2b2498dc , 0xd52f0494 , 0x70557abf , 0xc9ac068b , 0x6be4f93a , 0x0e35bdd3 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x28f1e1c8 , 0x169ae264 , 0xa6ed84f8 , 0xc5411020 , 0xfaab1f8a , 0x87e19a65 , 0xa08b9a44 , 0x1c799b2e ] )
+  , Mont::unsafe_make( [ 0x2aa779a9 , 0x6470a67f , 0x4dbcceb3 , 0x3894195e , 0xc1eef339 , 0x784eb0b0 , 0xe73196e2 , 0x1aab0c90 ] )
+  , Mont::unsafe_make( [ 0x2b6d005a , 0x41eb7253 , 0x3bdfc2d7 , 0xfbca2b1d , 0x9a4aaf53 , 0x9589e3ed , 0xd0a05350 , 0x21ae79f8 ] )
+  , Mont::unsafe_make( [ 0x9505b232 , 0x5b7baf42 , 0xfab01218 , 0xb97162a0 , 0x3faf1799 , 0x1de558cd , 0x08c8ef04 , 0x139e0dd3 ] )
+  , Mont::unsafe_make( [ 0x1b5c8e14 , 0x11b71783 , 0xdda7ae62 , 0xd00d2e64 , 0xecb1d5f7 , 0x9994d78f , 0xf6870ffb , 0x2a6b06ce ] )
+  , Mont::unsafe_make( [ 0x58713399 , 0x31aca8aa , 0x876c6dfa , 0x423c236e , 0xecef9351 , 0x4767e85d , 0x843ca009 , 0x1d43be4a ] )
+  , Mont::unsafe_make( [ 0xf9c90e3c , 0xe5b52cb3 , 0xb88abf26 , 0x4841190d , 0x50a84378 , 0x0c39367b , 0x079679e4 , 0x1342a07a ] )
+  , Mont::unsafe_make( [ 0x92477861 , 0x92387365 , 0x54a7041d , 0xf36bd1d4 , 0xb62976d0 , 0xcb895456 , 0xe3f37590 , 0x2f71f2be ] )
+  , Mont::unsafe_make( [ 0x83195f10 , 0x217d8708 , 0xf8dfe8dc , 0x2405d456 , 0xb13aaab0 , 0x2257435b , 0xb98c588e , 0x0207d077 ] )
+  , Mont::unsafe_make( [ 0x5a7eaaf2 , 0x03e95f62 , 0xb755074b , 0x1e847968 , 0xc724acdd , 0x205d30ae , 0x39be9136 , 0x2ca1062e ] )
+  , Mont::unsafe_make( [ 0x0654f9a8 , 0x9a236ee3 , 0xb13eb914 , 0xdbf8e2ce , 0x94d7d150 , 0x9eadee2e , 0xd49714a6 , 0x2dab25e4 ] )
+  , Mont::unsafe_make( [ 0xe8faf54d , 0xa464663a , 0xd75bf18a , 0xb2c92af7 , 0xe43b80a4 , 0xcae828a3 , 0xb78827ca , 0x1256d48c ] )
+  , Mont::unsafe_make( [ 0xafaa4b78 , 0xc1ca04c9 , 0x4e8e8237 , 0xf9fe968e , 0x5365d160 , 0x0282f9ae , 0x29bcaba1 , 0x0b16e62c ] )
+  , Mont::unsafe_make( [ 0x9e99b4cd , 0xde96f2fa , 0x0f892142 , 0x9b1b0ce1 , 0x7493f689 , 0x3ccae439 , 0x642c0a87 , 0x0d27f4f8 ] )
+  , Mont::unsafe_make( [ 0x70da8a25 , 0x11293cba , 0x4c52c9a5 , 0xf27e02bb , 0x014b8d39 , 0x5af85feb , 0xa5102077 , 0x24289251 ] )
+  , Mont::unsafe_make( [ 0xd08ed313 , 0x6a1de70f , 0x91cc01de , 0xefcd7bcd , 0x8a74fc0f , 0x1331caaf , 0x8a746176 , 0x05e13dad ] )
+  , Mont::unsafe_make( [ 0x7d547475 , 0x8c8c48f3 , 0x50bba16a , 0x0e93fee7 , 0x304cea1c , 0xd362465c , 0xb57720bd , 0x0e52f19d ] )
+  , Mont::unsafe_make( [ 0xeca096e1 , 0xa2002392 , 0xd6faa7ac , 0x46ed8ce7 , 0x11cd303a , 0xf22e5965 , 0xae0ad425 , 0x0cd65c4c ] )
+  , Mont::unsafe_make( [ 0x2c538741 , 0x1a84043b , 0x5ba57406 , 0xd4e35988 , 0xf1e57fc5 , 0xab5f5bbc , 0x2e207557 , 0x2cdf9e17 ] )
+  , Mont::unsafe_make( [ 0x00b5e59b , 0x9d031340 , 0x77019afc , 0x05fdd45f , 0x954ea568 , 0xc74ec69f , 0x3100ea9f , 0x1dc7815b ] )
+  , Mont::unsafe_make( [ 0xf8129b20 , 0x81b8502f , 0x771b6892 , 0xb0218bd2 , 0xd9a73d82 , 0xd1aed402 , 0xdd3bf599 , 0x12907033 ] )
+  , Mont::unsafe_make( [ 0x9f6e67cf , 0x02f4d81f , 0xc9798ae3 , 0x920c7b42 , 0xb6a4753f , 0xc78d400e , 0xe204925b , 0x1342d65f ] )
+  , Mont::unsafe_make( [ 0xe292bc34 , 0xea35ec43 , 0x4c8859ae , 0xdc2fcd6e , 0x55d7979f , 0x5bc106dc , 0xb9716f14 , 0x0dd52503 ] )
+  , Mont::unsafe_make( [ 0x4b13f08f , 0x53b1c34a , 0xe8939e5e , 0x2296198d , 0xfa5e7e48 , 0x92231fef , 0x590cc055 , 0x13d2f1ec ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x13adec9d , 0x28b2ae84 , 0xc6c5e7d9 , 0x4d62572f , 0x05ef7c66 , 0x29939fcc , 0x4e89b5e0 , 0x01cdc3f1 ] )
+  , Mont::unsafe_make( [ 0xc3cb30f9 , 0x32606c6f , 0x7d9e8715 , 0x338aa9bb , 0x333a34aa , 0x0a218ded , 0xc19bb018 , 0x2ec609da ] )
+  , Mont::unsafe_make( [ 0xfd3ca6e5 , 0x6f91ba78 , 0xd2929ad3 , 0x64c0be81 , 0xc9cade26 , 0xaf4f5e80 , 0x36822158 , 0x186d63a4 ] )
+  , Mont::unsafe_make( [ 0x732e44ed , 0xf989f928 , 0x8bf174ab , 0xeb223bea , 0x993065a5 , 0x6dd63239 , 0x81e61af1 , 0x2d5f307b ] )
+  , Mont::unsafe_make( [ 0xdae6adb8 , 0x72a3614c , 0x8e172105 , 0x12fa3ce6 , 0x3fdb0961 , 0xcf1dd05d , 0xe0b8faa2 , 0x02e9b2f0 ] )
+  , Mont::unsafe_make( [ 0x369c002d , 0x094592c9 , 0x3d88ec0b , 0x17a234fc , 0x4060a68a , 0xe36a394b , 0xb75f3d97 , 0x2a4a98b5 ] )
+  , Mont::unsafe_make( [ 0xa19f0f25 , 0xfdad3dce , 0xf192e11f , 0x5a9fd632 , 0x5fabbcc3 , 0x8d3cbb6a , 0x6937f2c5 , 0x1f8fdc9b ] )
+  , Mont::unsafe_make( [ 0x1189b76d , 0xbb829c6d , 0x9fd03af0 , 0x2997ef33 , 0x2092318a , 0x76a3b4cc , 0xe7fefba2 , 0x2b817753 ] )
+  , Mont::unsafe_make( [ 0x79576434 , 0xaa20df2e , 0x82db86a5 , 0xc65b2809 , 0xce1d454d , 0x0ab2d115 , 0xcb902953 , 0x1745f3ae ] )
+  , Mont::unsafe_make( [ 0x7022c6c4 , 0x6f37a8ba , 0xf245ccad , 0xc8bfa0e1 , 0x00eed64e , 0x25a23372 , 0x0aea37c1 , 0x00193de7 ] )
+  , Mont::unsafe_make( [ 0x2df4d381 , 0xc84c1403 , 0x6596a74a , 0x3c2ab769 , 0x4603aa6b , 0xe9f6406d , 0xaa11c541 , 0x0b789d8e ] )
+  , Mont::unsafe_make( [ 0x33f738cf , 0x62878d74 , 0xc94bbb58 , 0x7e24a886 , 0x09b017bd , 0x9596b581 , 0x1232019a , 0x1da28076 ] )
+  , Mont::unsafe_make( [ 0xe230078f , 0x09cf8bb1 , 0xfc94b59c , 0xa0d338d7 , 0x1b4d584b , 0xc7ee33bc , 0x5458e2f8 , 0x2a2ecf83 ] )
+  , Mont::unsafe_make( [ 0x275120cb , 0x0acc63fd , 0xa23cb396 , 0x7e77e463 , 0xb4f0de59 , 0x0f4e38c0 , 0x843392ae , 0x1a76f1e7 ] )
+  , Mont::unsafe_make( [ 0x020e3176 , 0xde6c1b4d , 0x1ab359bb , 0x1bf570b4 , 0x66a321a5 , 0x58acb5db , 0x2939f654 , 0x1ea9c465 ] )
+  , Mont::unsafe_make( [ 0xbf395e80 , 0xbce9919f , 0xebee6689 , 0x3a3ef758 , 0xaa990be9 , 0xdcdc5858 , 0x28a8808c , 0x1ca09a4b ] )
+  , Mont::unsafe_make( [ 0x724799e8 , 0x43918bf5 , 0x4a3842a6 , 0x5407919f , 0x8e62166a , 0x8509877c , 0x5dd820b7 , 0x19128121 ] )
+  , Mont::unsafe_make( [ 0xd987671e , 0x5574cb10 , 0xf3641078 , 0x235fcc0c , 0x5c9b1521 , 0xe90a37a2 , 0x2b6ab108 , 0x07a78a5a ] )
+  , Mont::unsafe_make( [ 0xbbdc8843 , 0xa7f3b0c0 , 0x038ac9e8 , 0xf7e4f51f , 0xf431ed38 , 0x2d5e4a25 , 0xddb25442 , 0x1c2f221b ] )
+  , Mont::unsafe_make( [ 0xc390c395 , 0xe0af4c1c , 0xc662932f , 0x7138f0bd , 0xb0c90a05 , 0x0b2d68ee , 0x5de675b5 , 0x0b446aef ] )
+  , Mont::unsafe_make( [ 0x370f5085 , 0x83d31f2f , 0x4dec131a , 0x12ba5f44 , 0x152ac80c , 0xa1b1cb68 , 0x192ca98e , 0x15ca1f9d ] )
+  , Mont::unsafe_make( [ 0x39410ac4 , 0xa80119fb , 0x53f317d5 , 0x90ddd0ae , 0x83877dd0 , 0x3dc0000d , 0xe99bf102 , 0x2ae6fdc4 ] )
+  , Mont::unsafe_make( [ 0xf788fc8b , 0xe058da2f , 0x90dbe308 , 0xbb809c59 , 0x0d6e7cf9 , 0x6ab1f5b2 , 0x7ad1b606 , 0x2673b722 ] )
+  , Mont::unsafe_make( [ 0xa5ec5629 , 0xc9e05af8 , 0xb94c40d1 , 0x66e476a9 , 0x9c3be0cc , 0x01f734df , 0xb8592dbb , 0x210b7c20 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xb07b4b95 , 0x3371a500 , 0xcbef84be , 0xa96b8490 , 0xeecfdc75 , 0xcfb5f9ed , 0x6db8d9e1 , 0x0e7534c8 ] )
+  , Mont::unsafe_make( [ 0xa834c3e2 , 0xea618f0c , 0xede03e2d , 0x8c07aba0 , 0xd368a7c3 , 0x90cdeb4e , 0xb3ab25e3 , 0x09af16f1 ] )
+  , Mont::unsafe_make( [ 0xed21614e , 0xf704b18d , 0x1ed299ab , 0xc6528231 , 0xe6f90ff5 , 0x3e96e8a2 , 0x8e659a82 , 0x136a8952 ] )
+  , Mont::unsafe_make( [ 0xd64ab705 , 0x7c5f36a4 , 0x65d0bf64 , 0x7649ff34 , 0x4c33abab , 0x588ce962 , 0x24423bcf , 0x0693604f ] )
+  , Mont::unsafe_make( [ 0x2052f123 , 0x7c5ff999 , 0x82832092 , 0xdc34dde8 , 0xea3ad648 , 0xe670de0d , 0x80e3b3e3 , 0x145150fb ] )
+  , Mont::unsafe_make( [ 0xfe3b8093 , 0x38a34c80 , 0x6024096a , 0x21c54b74 , 0x2cb2d5af , 0x4dc429a4 , 0x4dcffbb2 , 0x2692e2e4 ] )
+  , Mont::unsafe_make( [ 0x4c515ad3 , 0x9b7a0757 , 0xb6fbe894 , 0x95e6ca2e , 0x5ceaf394 , 0xdfaac0b1 , 0x322ce82a , 0x118ca7d9 ] )
+  , Mont::unsafe_make( [ 0x4f7b2f69 , 0x890c7381 , 0x81e52d9d , 0x0d80a1d1 , 0x27f6c7f7 , 0x96bd9a00 , 0xd03fc130 , 0x227268ef ] )
+  , Mont::unsafe_make( [ 0x8edae420 , 0x382249b0 , 0x3bb172f6 , 0xd334dd51 , 0x7ab0ac7f , 0x9835e08f , 0x075a43db , 0x030d46dd ] )
+  , Mont::unsafe_make( [ 0x24116842 , 0xc23a560a , 0xe8918997 , 0xe41c145c , 0x2da0cdc0 , 0x4a538d09 , 0x591f3120 , 0x0f79ca6c ] )
+  , Mont::unsafe_make( [ 0xc56974a0 , 0x916d0547 , 0xf35f7f51 , 0x8f48d421 , 0x5d89d7f3 , 0x2fc34ff3 , 0x5ca4959f , 0x2a1722f0 ] )
+  , Mont::unsafe_make( [ 0x5e99327d , 0xa952be81 , 0x41145a3d , 0x53a4087d , 0xbc695588 , 0x4507ee19 , 0x14a3db77 , 0x181fbf61 ] )
+  , Mont::unsafe_make( [ 0xa8a2ae00 , 0x2625c496 , 0xe31eaf74 , 0x3d5b2043 , 0xb52b5fe3 , 0x48da7f93 , 0xbd4d8a33 , 0x1cf3d3e9 ] )
+  , Mont::unsafe_make( [ 0x0cc926ed , 0x02a0b584 , 0x209c2fa9 , 0x09620423 , 0x03227765 , 0x7298b7fb , 0x3a5693fe , 0x1dad1260 ] )
+  , Mont::unsafe_make( [ 0xfad493ee , 0xbd9bd302 , 0x945fa6e8 , 0xb9b34095 , 0xa262f837 , 0x648c87c1 , 0x34e99eaa , 0x27a35b3a ] )
+  , Mont::unsafe_make( [ 0xe6799a68 , 0x0704016d , 0x6eafa322 , 0x72dfda02 , 0xbd1f77a4 , 0xbc238110 , 0xf99509c3 , 0x136e3964 ] )
+  , Mont::unsafe_make( [ 0xed0a4b4b , 0x17284705 , 0x531ade3f , 0xab445992 , 0x866ceb25 , 0x41cb5528 , 0x3966f648 , 0x29c32d61 ] )
+  , Mont::unsafe_make( [ 0xc2e35c18 , 0xb62bde9b , 0xe727d2a9 , 0x714bd936 , 0x5533f4e5 , 0x47f05260 , 0x2d556379 , 0x08657e79 ] )
+  , Mont::unsafe_make( [ 0x4512eb14 , 0x55e5ef8c , 0x61f081ae , 0xcb05d03c , 0xafc44083 , 0x76236020 , 0x37d0b98c , 0x07c908f9 ] )
+  , Mont::unsafe_make( [ 0x22fcba08 , 0x83f5fe21 , 0xfa9334e0 , 0xc8d27b43 , 0xbe5243df , 0x71f81768 , 0x23da9bd0 , 0x2b219035 ] )
+  , Mont::unsafe_make( [ 0x59e69ac5 , 0xe1511361 , 0xe4adfc1a , 0x2b3968b2 , 0x281d8895 , 0x607ea5e0 , 0x5770c8dc , 0x213df144 ] )
+  , Mont::unsafe_make( [ 0xd07b0b36 , 0xaf715438 , 0x5b02d355 , 0x37a3f0a5 , 0x0449316f , 0x0fe76bf0 , 0xefd802a0 , 0x16ce2a32 ] )
+  , Mont::unsafe_make( [ 0x392ea27b , 0xab57c25e , 0x63709f4c , 0xe2a318d3 , 0x8e660d0f , 0x8ce2a8c3 , 0x32242a6c , 0x1fb8872d ] )
+  , Mont::unsafe_make( [ 0xb3826dd5 , 0xc0a37b42 , 0x897fbffd , 0x63c41950 , 0x77ae8e84 , 0x63d76987 , 0xb8c0559d , 0x0f16079b ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x62049b7a , 0x9de7dc18 , 0x7c09620b , 0x382d9b44 , 0x5199e273 , 0x21b189d3 , 0x8748dd81 , 0x21781c0f ] )
+  , Mont::unsafe_make( [ 0xf62ab5a7 , 0xdcf010b8 , 0x7d158960 , 0x8d95f66c , 0x8664fc47 , 0x80d63996 , 0xe83840f5 , 0x0c9383c2 ] )
+  , Mont::unsafe_make( [ 0xdf6ad815 , 0x105d742b , 0xe9f0cf0c , 0xc5b43c6f , 0xb2940dab , 0xa47af0fe , 0x75bb4b6e , 0x1c00f17d ] )
+  , Mont::unsafe_make( [ 0xa17e9747 , 0x3c3507f3 , 0x0fbc3333 , 0x446d39d2 , 0xaac2840d , 0x31556ce3 , 0xa470b609 , 0x23e61b21 ] )
+  , Mont::unsafe_make( [ 0x5cfbd135 , 0xaae706f3 , 0x8ac6472a , 0x89965b55 , 0x223c57a8 , 0x53e19111 , 0x4b49b3fc , 0x012878af ] )
+  , Mont::unsafe_make( [ 0xa722c650 , 0x26ca46f0 , 0xeda8306a , 0x34a03073 , 0x09e169e0 , 0x3fceb2cb , 0x4bfe9a1b , 0x2f5a5664 ] )
+  , Mont::unsafe_make( [ 0xfd6e8e10 , 0x6d140e47 , 0xe946afae , 0x8c0b3ed4 , 0xfeb7c6a3 , 0xc3079e3e , 0x58b332a3 , 0x1ea0eebc ] )
+  , Mont::unsafe_make( [ 0xd9f07ae8 , 0x6b289ee2 , 0xce651d64 , 0x341fb486 , 0xaacbb4bd , 0x375b3cb7 , 0x854ae434 , 0x2021146f ] )
+  , Mont::unsafe_make( [ 0x8e527cd7 , 0x44256198 , 0x0582a85f , 0x9135bcb6 , 0x5bbfb008 , 0x4ce8d7d8 , 0xf7befe14 , 0x2af9655e ] )
+  , Mont::unsafe_make( [ 0x87500008 , 0x895e3780 , 0xc0988cde , 0x1fc85af8 , 0xd6fbfd1f , 0x1a8598e3 , 0x66c668dd , 0x1ac9013b ] )
+  , Mont::unsafe_make( [ 0xd89b482d , 0x7e3770fa , 0x8268c79d , 0xb1536058 , 0x39dcf22a , 0x86c68cde , 0xfe175d68 , 0x249924a4 ] )
+  , Mont::unsafe_make( [ 0xf3a3d5f1 , 0x5894f9fa , 0xff8c9e0f , 0x432a555e , 0x89ad6874 , 0x519ecc5c , 0x46e60b30 , 0x2e441fae ] )
+  , Mont::unsafe_make( [ 0x91bf066f , 0x6543089b , 0xe62c7345 , 0xe6913227 , 0x39d94a59 , 0xb6f3f9a0 , 0xea724318 , 0x28048354 ] )
+  , Mont::unsafe_make( [ 0x35938bf4 , 0xdba245c1 , 0x83a4b915 , 0xd160fa30 , 0xbbf337a1 , 0x05dec113 , 0x9149d953 , 0x26777207 ] )
+  , Mont::unsafe_make( [ 0x01e2fb73 , 0x18da0716 , 0xe546ff10 , 0xfa185109 , 0xf6ba2d29 , 0xc5a0bcdd , 0x61823539 , 0x12535a0e ] )
+  , Mont::unsafe_make( [ 0x8af0e0ee , 0x85ac75d0 , 0xcb198135 , 0x9af3d49f , 0x7e17e737 , 0x29caa2e2 , 0xd89dccf3 , 0x217a2404 ] )
+  , Mont::unsafe_make( [ 0xae5d2c43 , 0xa49ca149 , 0x85919edd , 0xebd01287 , 0xf807f99b , 0x1d186985 , 0x4f2ad7cf , 0x04d74f43 ] )
+  , Mont::unsafe_make( [ 0x5a390c02 , 0xa4ebaa8e , 0x22b795a8 , 0xd4aa93d4 , 0x7190921d , 0x5d71a375 , 0x9525b257 , 0x29f59a18 ] )
+  , Mont::unsafe_make( [ 0x156ad684 , 0xc6f13c7b , 0x5c1ac4c7 , 0x8760507c , 0x9e9ecd9b , 0xa6b979a8 , 0xc1098c9e , 0x1a2c2980 ] )
+  , Mont::unsafe_make( [ 0x7f4a0c3c , 0xc7ad34ed , 0xf7fa1388 , 0x729746aa , 0x32946801 , 0x9c292dba , 0xede8b012 , 0x10e34a1c ] )
+  , Mont::unsafe_make( [ 0xb8c02ec7 , 0xb75dcc7e , 0xa114ef6f , 0xf5614c96 , 0x50b2c663 , 0x85ef03df , 0x30a25990 , 0x15d33a4a ] )
+  , Mont::unsafe_make( [ 0x03c586c1 , 0x8b74dfe0 , 0x49420211 , 0x7f9e4fad , 0x2b58a43f , 0x8a18804d , 0xde7b7a39 , 0x1221875a ] )
+  , Mont::unsafe_make( [ 0x20d78d69 , 0xbdaaf91e , 0x2ec5543f , 0x43f1dab0 , 0xf765f39e , 0xa65f7e89 , 0x60d48b84 , 0x258dc49e ] )
+  , Mont::unsafe_make( [ 0xeb991914 , 0x006a0b65 , 0x073e1c70 , 0x3120e4e6 , 0x12186666 , 0x5cb0bf25 , 0xeb25144c , 0x107d5834 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xb50b2dfa , 0xb14f31fb , 0x10049f61 , 0x638b4f79 , 0x70cba991 , 0x48df3830 , 0xb87429fd , 0x243f14fd ] )
+  , Mont::unsafe_make( [ 0x25bb5371 , 0xcfdee1a1 , 0x68324551 , 0x1f1bea8a , 0x8217fd0a , 0xe3e2843c , 0xf0ffc0fb , 0x06d22981 ] )
+  , Mont::unsafe_make( [ 0x87a26971 , 0x507a6c79 , 0xdc7b3276 , 0x79eed2d6 , 0x94113d46 , 0x3b817476 , 0xab7f1250 , 0x22540689 ] )
+  , Mont::unsafe_make( [ 0x776364cb , 0xe2b47eb5 , 0xcec1fdf5 , 0xddbf6aee , 0xad04da46 , 0xb15f7875 , 0x648c48a2 , 0x26a8b7de ] )
+  , Mont::unsafe_make( [ 0xfd2dbe99 , 0x230a4a2a , 0x44c90a8d , 0x6347e78a , 0x7507de47 , 0xf2d6ab82 , 0x50746ff4 , 0x08e7c005 ] )
+  , Mont::unsafe_make( [ 0x7b2c1cf5 , 0xb0e621e7 , 0x52b399eb , 0x97c9bca9 , 0xed024105 , 0x993c3eb0 , 0xbce07dbd , 0x2cffd4b6 ] )
+  , Mont::unsafe_make( [ 0x5c7c29bc , 0x36ba9e80 , 0x1a7b6a9d , 0x336ec4c0 , 0x6c34708a , 0x676d5216 , 0x56387e96 , 0x0a2ab95d ] )
+  , Mont::unsafe_make( [ 0x2e72da0f , 0x400551b6 , 0xc37dee04 , 0x7002793f , 0xce9de6a4 , 0x23f3a82f , 0x24f59f59 , 0x183f096f ] )
+  , Mont::unsafe_make( [ 0x6df62942 , 0x210b1095 , 0x1ce3704d , 0x7f0a11e7 , 0x8154c255 , 0x915f9bf3 , 0xa75afc87 , 0x115226ea ] )
+  , Mont::unsafe_make( [ 0xc35e632d , 0xe5544cc0 , 0x81c48b25 , 0x11608daa , 0x743c3143 , 0xe0ada666 , 0x2096da14 , 0x1a42aac3 ] )
+  , Mont::unsafe_make( [ 0x621106f9 , 0xbd7543a7 , 0x819857f0 , 0x8e080340 , 0x955404d3 , 0x3fea7399 , 0x2d28a4da , 0x18427eb7 ] )
+  , Mont::unsafe_make( [ 0xd836fc3a , 0x27c323dc , 0x87cc5e6a , 0x624f212d , 0x0fa480ab , 0xccf09fa2 , 0x11eabde2 , 0x0786a4a4 ] )
+  , Mont::unsafe_make( [ 0x978332f0 , 0x9295af87 , 0x8b65e39c , 0x0c8c68ca , 0x50da8c45 , 0xbf070c4a , 0x94492019 , 0x0d4735c1 ] )
+  , Mont::unsafe_make( [ 0xb1aea4f1 , 0x1b1fc10b , 0xe6d75214 , 0x882d8368 , 0x3fe82aed , 0x12f3a095 , 0xe3026453 , 0x0dbc65e9 ] )
+  , Mont::unsafe_make( [ 0xdfaac3f2 , 0x488acc68 , 0xac3ecb2e , 0x708f33f4 , 0xea0d7149 , 0xb5fc0f19 , 0x71d3f053 , 0x2c825273 ] )
+  , Mont::unsafe_make( [ 0x433ecc5a , 0x00f9d44b , 0x99c711a1 , 0x1eef6c60 , 0x97249d78 , 0x648556a4 , 0x31daa801 , 0x1f7168d3 ] )
+  , Mont::unsafe_make( [ 0x7cc6998f , 0xa7a5ee0b , 0x125ea874 , 0xa3120050 , 0x2fda8d78 , 0x7d6a57ef , 0x99ad4858 , 0x2350b2d3 ] )
+  , Mont::unsafe_make( [ 0xa8a6c034 , 0x9051ee62 , 0x11d61f96 , 0xc320e8cd , 0xdd8a039a , 0x40411691 , 0x1c0bafe9 , 0x224b6b78 ] )
+  , Mont::unsafe_make( [ 0x5b1bc4c6 , 0x74082311 , 0x10dc8bec , 0x782948de , 0x8b059392 , 0x2b74995c , 0xb233b2dc , 0x17a347fd ] )
+  , Mont::unsafe_make( [ 0x5a450c1a , 0x5dac3de0 , 0x5dada2a6 , 0x65de27fa , 0x937a16d2 , 0xb2ca0bb9 , 0x4becedb1 , 0x26391650 ] )
+  , Mont::unsafe_make( [ 0xf8fa6ee2 , 0xa029d87c , 0xc3192d34 , 0x13c32582 , 0x08be0ef0 , 0x90b8de62 , 0xf145a218 , 0x2c455b24 ] )
+  , Mont::unsafe_make( [ 0x6a1e0509 , 0xb5e15714 , 0x05b4144b , 0xeef64e70 , 0xf4ec6bc4 , 0xe177cc65 , 0x13825b55 , 0x0f52cc9b ] )
+  , Mont::unsafe_make( [ 0x0606471a , 0x3ce3872a , 0xe842dc71 , 0xb942ead4 , 0x6a44f3fb , 0x0d85d294 , 0xc2dd5387 , 0x0bc55f6a ] )
+  , Mont::unsafe_make( [ 0xbeb98963 , 0xb748bd8b , 0x69e1e7de , 0x02adccf0 , 0x99c657bc , 0xb770ba6d , 0x4d4f8634 , 0x1451146e ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x5ecadf17 , 0x66db3b29 , 0x39653013 , 0xdacda432 , 0xfbef9819 , 0x9bb211b3 , 0xec9ad62f , 0x03d5c6bc ] )
+  , Mont::unsafe_make( [ 0x61bbd7d0 , 0x60a533f9 , 0xab592f45 , 0x8a9b0966 , 0x907987fb , 0x9cc99023 , 0x0472c17e , 0x258d077e ] )
+  , Mont::unsafe_make( [ 0x8dcf38d4 , 0x88e18dff , 0x27507acb , 0xcc8ca0e8 , 0x1b6ebb9e , 0xb91b28bc , 0x0566cd85 , 0x013d75ed ] )
+  , Mont::unsafe_make( [ 0xf393ef92 , 0xf937b715 , 0x47219f71 , 0x7ab4814c , 0x48c982f5 , 0x3689c5ba , 0x017d2eb2 , 0x134c50a1 ] )
+  , Mont::unsafe_make( [ 0xe45c563b , 0xce5c4abb , 0x4e482c11 , 0x0594d236 , 0x1310669f , 0x5a9a1007 , 0x93d2579d , 0x3046528c ] )
+  , Mont::unsafe_make( [ 0x6d0b4c0b , 0xede92849 , 0x9db9f6a3 , 0xf2927eda , 0x27df93a8 , 0x940b7d8f , 0xa5e23a2a , 0x0b52d586 ] )
+  , Mont::unsafe_make( [ 0xd9e239da , 0xeb367444 , 0xcb2a91aa , 0x7442dc72 , 0xfe12cfa5 , 0x8c030067 , 0xf8c27aed , 0x1b6c251e ] )
+  , Mont::unsafe_make( [ 0x925ab1f0 , 0xa8e3623c , 0xc05ab61d , 0xf93d7def , 0xeb65bc61 , 0xec31de21 , 0x5dea02e9 , 0x179ac110 ] )
+  , Mont::unsafe_make( [ 0xa0e6f0e9 , 0xb905123c , 0x54b7513f , 0x0046c96c , 0x0acd074c , 0xe8218f87 , 0x253f5c01 , 0x11753386 ] )
+  , Mont::unsafe_make( [ 0x57392e81 , 0x98469be3 , 0x332d45c7 , 0x24fe9fd0 , 0x3f3d5b89 , 0x40443556 , 0x6bcda4fe , 0x0cb5fa28 ] )
+  , Mont::unsafe_make( [ 0x908fdd4e , 0x754cfc03 , 0x38476115 , 0x323351b9 , 0xa1f0877a , 0xc9800d43 , 0xad5c49b2 , 0x2b9f2584 ] )
+  , Mont::unsafe_make( [ 0x62e6b184 , 0x99939aaa , 0x55b5b57e , 0x612585bb , 0x0f4768b9 , 0xd63c6353 , 0x680f5ae6 , 0x2d3493ce ] )
+  , Mont::unsafe_make( [ 0xb1ad6813 , 0xad0a72f4 , 0x7d4317e3 , 0xdf722db5 , 0x4a341849 , 0x25a75fa2 , 0x7b35dcbf , 0x181974b9 ] )
+  , Mont::unsafe_make( [ 0xfc44983d , 0xdcd07e40 , 0x8fec8e24 , 0xf3833ce9 , 0x0466812c , 0xf3d3cb58 , 0xbc47b746 , 0x14785cd7 ] )
+  , Mont::unsafe_make( [ 0x8f987507 , 0x6e90a4a8 , 0x677ec671 , 0x9dabeaad , 0x9064fa76 , 0xf4080080 , 0x5b90f56b , 0x0cea21d5 ] )
+  , Mont::unsafe_make( [ 0xfffbba56 , 0xb612d0aa , 0x3c406bb1 , 0x308de844 , 0xf516fb08 , 0xb69d7b3f , 0x6da36bc0 , 0x22762685 ] )
+  , Mont::unsafe_make( [ 0x289e079e , 0x518ebdbb , 0x4146f4d9 , 0xea9d6b06 , 0xb59a0c8c , 0x5a72e8e6 , 0x4a9d78ec , 0x0695d5ea ] )
+  , Mont::unsafe_make( [ 0x89dac6f4 , 0x667204ba , 0x7ad703ac , 0x05f3127b , 0x40127370 , 0xa07d2fe1 , 0x002a637f , 0x1b35bcc9 ] )
+  , Mont::unsafe_make( [ 0xca87596c , 0xfe5310e9 , 0xd238e3d4 , 0x3838cd5c , 0x6d2d80b1 , 0xcb840637 , 0xe3509baf , 0x2824e3f8 ] )
+  , Mont::unsafe_make( [ 0xd9862856 , 0x062c602d , 0x0bf1d8ab , 0xd40c3331 , 0x7c3fa34c , 0x21be0757 , 0x60e8a929 , 0x045070cd ] )
+  , Mont::unsafe_make( [ 0x3df61be8 , 0xf6377ce9 , 0xc0e9993a , 0xb5734ca1 , 0x7b296981 , 0xa2926307 , 0x44a55ede , 0x27116d61 ] )
+  , Mont::unsafe_make( [ 0x6e0f57a2 , 0x2b7485ab , 0x733a3a66 , 0xd1062534 , 0xb19af07a , 0x386e6b39 , 0xcf56e73f , 0x0dcf4318 ] )
+  , Mont::unsafe_make( [ 0xb845c30f , 0x6a8c803e , 0x901da42d , 0xba244040 , 0xaf38a7ab , 0x740b7594 , 0x962b470e , 0x01366703 ] )
+  , Mont::unsafe_make( [ 0x6d021de9 , 0x7fecae30 , 0xf8b9e06d , 0x4e7bb76f , 0xefe7a300 , 0x92ed851b , 0x0a3fb9c6 , 0x2b3387de ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x8b91a325 , 0xa89a40c1 , 0x2f35ba6e , 0x10d1b0b5 , 0x78b3ce25 , 0x173f7944 , 0x76df0ac7 , 0x1f188f28 ] )
+  , Mont::unsafe_make( [ 0x5a6053a9 , 0x411efc4f , 0x2b82a9cf , 0x83f22a52 , 0xd427fec9 , 0x538892ee , 0x4b3c78da , 0x138cfebc ] )
+  , Mont::unsafe_make( [ 0xd867000d , 0x342073db , 0xe5523bc1 , 0x141c98a1 , 0x7186cd7b , 0xc8c0ff51 , 0x12bf2727 , 0x2ecf7764 ] )
+  , Mont::unsafe_make( [ 0x84d15359 , 0x47985b48 , 0x387641a1 , 0xbaf248c4 , 0x3d32ebb3 , 0x2123df45 , 0xf6d7861d , 0x032f951e ] )
+  , Mont::unsafe_make( [ 0x3c8b04f3 , 0xb9160d0a , 0x8feb979b , 0x792a385e , 0x54482e22 , 0x74962b3b , 0x20d397b4 , 0x121aff06 ] )
+  , Mont::unsafe_make( [ 0x70b35b5e , 0xbf7d76fb , 0xf1696e36 , 0xd64e6cd3 , 0xdaeb1a19 , 0x7164cb01 , 0x85d4c18c , 0x1d5184f8 ] )
+  , Mont::unsafe_make( [ 0xeae129f4 , 0x3c5ffe05 , 0x6286b528 , 0x4ad79438 , 0x872bf42c , 0x9beab7cc , 0xd394cabc , 0x1da034d9 ] )
+  , Mont::unsafe_make( [ 0x70096e27 , 0x7fb7064d , 0xca704b5c , 0xe4eec5be , 0x527055e5 , 0x0c591425 , 0xba80e495 , 0x158dccc6 ] )
+  , Mont::unsafe_make( [ 0x77c4fb5b , 0xee9fc08f , 0xdb7a1af6 , 0xa6229689 , 0xe03c0424 , 0x32cd2621 , 0x89bed1ef , 0x08776f75 ] )
+  , Mont::unsafe_make( [ 0x0ba1fe8b , 0xffeab61d , 0xd70ba1a2 , 0x03e3ed66 , 0x2d5d872e , 0x897ebfc0 , 0x59aab40f , 0x2c8325a4 ] )
+  , Mont::unsafe_make( [ 0x523a805e , 0x01cdd41e , 0xd01ffd6c , 0xfeecaf2e , 0x9ae32dcc , 0x21574bb9 , 0xc5ca606d , 0x0595e3df ] )
+  , Mont::unsafe_make( [ 0x7675385a , 0x5a4938aa , 0xb3ecfe58 , 0x86c4567b , 0x92d98152 , 0x08c26d44 , 0x1cd325bb , 0x26d63b23 ] )
+  , Mont::unsafe_make( [ 0x0307a693 , 0xcf2d6dcd , 0xe0d8cc08 , 0x7bee5090 , 0x18ec17bc , 0x689bab91 , 0x67535191 , 0x08d3ff5b ] )
+  , Mont::unsafe_make( [ 0xb1772d28 , 0xfb1df646 , 0xfad1be25 , 0x2220dffe , 0x98e6634b , 0x99dbc0a4 , 0x65d4bf71 , 0x18abbd95 ] )
+  , Mont::unsafe_make( [ 0xee5c6108 , 0xb8d3239f , 0xf64a23a8 , 0xabe7d887 , 0x6fc89156 , 0x6df16503 , 0xae2d81de , 0x25bf46f4 ] )
+  , Mont::unsafe_make( [ 0x7e683be0 , 0x0448efbd , 0xefd13465 , 0x2cead74d , 0x8f24345a , 0x86bd23f2 , 0xcfcef851 , 0x303bbd1d ] )
+  , Mont::unsafe_make( [ 0x2f577201 , 0x4e5e41e7 , 0x3450c5e7 , 0xc55437b7 , 0x109bde04 , 0x766a9c48 , 0xe3cfa09d , 0x2f1ef2d6 ] )
+  , Mont::unsafe_make( [ 0x6486bc4a , 0x6f4504f4 , 0x2940d1b2 , 0xb143e53b , 0xac11add1 , 0x7ff4b873 , 0xc3bcbc7f , 0x0703d4fe ] )
+  , Mont::unsafe_make( [ 0x48998f52 , 0xa3019a5c , 0xa39dade8 , 0x060b8a79 , 0xc20cd2d5 , 0xf3961acf , 0xf68fb650 , 0x03cd8461 ] )
+  , Mont::unsafe_make( [ 0x006afbaa , 0x6c297626 , 0x198538b7 , 0x64969959 , 0x45c17148 , 0x11ad1421 , 0x6ee31047 , 0x0d1738c7 ] )
+  , Mont::unsafe_make( [ 0x0e74e3d0 , 0x65ad1a35 , 0xb636294c , 0xd443602d , 0x591e2198 , 0xc26d18a3 , 0x5eeb9416 , 0x16db4413 ] )
+  , Mont::unsafe_make( [ 0x04789e6c , 0xb0412af0 , 0x42c829bd , 0x4d2cce9a , 0x0c7e1355 , 0x5236cb3d , 0xa80f6b1d , 0x2cd02963 ] )
+  , Mont::unsafe_make( [ 0xb78d4f4a , 0x03b532b5 , 0x51f31d73 , 0x33e478c4 , 0x234a3dff , 0xd0110a12 , 0xab3a967b , 0x17cbfaf0 ] )
+  , Mont::unsafe_make( [ 0xe0a9018d , 0xf2d4b51a , 0x3586cd5e , 0xf149b962 , 0x5a7630bf , 0xbb4cbb8b , 0x072fa021 , 0x0c657223 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x84354001 , 0x7acfdc1b , 0x1755dfab , 0x46a02e00 , 0xbadfb439 , 0xb336d1d9 , 0xafffc120 , 0x2dd082cb ] )
+  , Mont::unsafe_make( [ 0xf7e33968 , 0xc0a7f772 , 0xca4217e2 , 0x28ec21b6 , 0x0e8fc1eb , 0x59886004 , 0x1b372c2a , 0x0a234b82 ] )
+  , Mont::unsafe_make( [ 0x8e60b4bf , 0xa19b33aa , 0x99eb0416 , 0xf1cf1ee2 , 0x40abb666 , 0xae90ec2f , 0xe77e90f0 , 0x26e79b05 ] )
+  , Mont::unsafe_make( [ 0xfd2ec9dd , 0xfa031033 , 0xec4eb6c8 , 0xb2d14c3b , 0x01519a45 , 0x4d77dabd , 0xf40f7db0 , 0x1da56fc5 ] )
+  , Mont::unsafe_make( [ 0x3436edac , 0xeaaa7dc1 , 0xcacc4af5 , 0x17a41bcb , 0x1f9e46e5 , 0xe7d06a2f , 0xc21826a3 , 0x2d58a4c7 ] )
+  , Mont::unsafe_make( [ 0xf02fb500 , 0x9bf49f43 , 0x7787688e , 0x3d8b4f79 , 0xfcf3a514 , 0x733058e1 , 0x4ec50d4e , 0x0575ff73 ] )
+  , Mont::unsafe_make( [ 0x92d3a1be , 0xd0448510 , 0xcbf9308e , 0x57ec7139 , 0x5393c9ed , 0x797522ec , 0x29a85edb , 0x0d928ea5 ] )
+  , Mont::unsafe_make( [ 0x028928f3 , 0x36b41c34 , 0x3fe06603 , 0xef26c3d4 , 0x46a1fea5 , 0x7d08cce3 , 0xaf4f0af7 , 0x2cb6a82d ] )
+  , Mont::unsafe_make( [ 0xf243bf15 , 0xbee8d2fa , 0x7f0f7783 , 0xb154e77f , 0x7905b2bc , 0x05eaef5b , 0x7f23edb4 , 0x2523ccf1 ] )
+  , Mont::unsafe_make( [ 0xb638d6fe , 0x87af6688 , 0x9ec163b7 , 0x4aa3bd96 , 0x483f3a31 , 0x6d98a126 , 0xc186ec66 , 0x0b8a8893 ] )
+  , Mont::unsafe_make( [ 0xaac8d84d , 0xeefc53ee , 0xe44cfe0b , 0x737b8b8c , 0x7a70317e , 0xdf775943 , 0x73c9dc65 , 0x206a2dfa ] )
+  , Mont::unsafe_make( [ 0x4e4d0432 , 0xb2c06e34 , 0xefb552a2 , 0x0b730b66 , 0xc05e9881 , 0x48fa2ef7 , 0xf31f8f31 , 0x0ed39554 ] )
+  , Mont::unsafe_make( [ 0x5e254be9 , 0x6e77a8dd , 0x5b8ebdc1 , 0x0849b33f , 0xf04675b2 , 0x88c63383 , 0xd3b0b059 , 0x1527a6ff ] )
+  , Mont::unsafe_make( [ 0x4227b414 , 0x33308aa8 , 0xf2beeeff , 0xed4d346c , 0x7ca033c8 , 0xf66ae74d , 0xad8d760a , 0x0908ac7d ] )
+  , Mont::unsafe_make( [ 0x6e4f9132 , 0x24552d32 , 0x51dbc00c , 0xb2823d39 , 0xc7cde775 , 0xed88e356 , 0x7b9a162c , 0x16015c01 ] )
+  , Mont::unsafe_make( [ 0xb40f8146 , 0xa6c1ebdd , 0xcb72d405 , 0x08de0d68 , 0xa54877e6 , 0xdabdf832 , 0x6cf3e8f9 , 0x305f3c02 ] )
+  , Mont::unsafe_make( [ 0x0e737c89 , 0x827f1df7 , 0x748f5728 , 0xd0ea0f55 , 0x66b4b372 , 0x8566a29b , 0x111a57cc , 0x0d7d708f ] )
+  , Mont::unsafe_make( [ 0xaec86377 , 0xe3d67d10 , 0xd17ccb8a , 0xeb1e375b , 0x445d951c , 0xa8255317 , 0x46f6ccb1 , 0x2e3ab1a9 ] )
+  , Mont::unsafe_make( [ 0x63a20f4d , 0xb577d33e , 0x2ee11a0a , 0xf03bf776 , 0xb2ee9607 , 0x4e26e5c3 , 0x05c3aa8a , 0x0ea52d46 ] )
+  , Mont::unsafe_make( [ 0x266149fd , 0x572d90d7 , 0xd4b5e2c5 , 0x7f7aeff8 , 0xbb300045 , 0x1e0f8d20 , 0xa899da9f , 0x2c50a7dc ] )
+  , Mont::unsafe_make( [ 0x91c22c43 , 0xe2dd0c06 , 0x7cfe88c7 , 0x8900d0ef , 0xe717543b , 0xee4291d4 , 0x7588e5cd , 0x11023468 ] )
+  , Mont::unsafe_make( [ 0xbc079ac3 , 0x67e8c883 , 0xe109986c , 0xf6f66ad9 , 0x0e9ce8ad , 0xf0e9fabb , 0xb55cc8c6 , 0x282d673b ] )
+  , Mont::unsafe_make( [ 0x96e3fde0 , 0xe039e015 , 0x91d0f72a , 0xfadc4515 , 0xf450a3a5 , 0x15032483 , 0x5df26aef , 0x28346d7a ] )
+  , Mont::unsafe_make( [ 0x044dc51a , 0x3ae8e996 , 0xe2c9b859 , 0x57f4a8e3 , 0x89915c47 , 0x21bc1cde , 0x0d1deb24 , 0x0293c845 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x01b5cb53 , 0x1ec946c1 , 0x8c2c93b8 , 0xf9304ce0 , 0x54da478f , 0xd6736339 , 0x6a62404a , 0x1ece7ec0 ] )
+  , Mont::unsafe_make( [ 0xcad52a44 , 0x83d5ed75 , 0xeef7a1bd , 0x2b98ec9e , 0x60996d8d , 0x295eddac , 0xae226f0d , 0x10878398 ] )
+  , Mont::unsafe_make( [ 0x694e9e21 , 0xb2adbaff , 0xc28820e9 , 0xd409e94a , 0xb8f5868f , 0xb4bb4e24 , 0x69432cb8 , 0x26f792f8 ] )
+  , Mont::unsafe_make( [ 0x0d777109 , 0x8244677e , 0x3d331da7 , 0xe4c74f4d , 0x502d78c9 , 0xb4a93eda , 0x9b8c5098 , 0x0c2e5bbc ] )
+  , Mont::unsafe_make( [ 0xc55bcab8 , 0x30ea1705 , 0x89468196 , 0xff98b7ef , 0x966c2caf , 0xb4c6046b , 0xe3dcd149 , 0x093c0aef ] )
+  , Mont::unsafe_make( [ 0xe6b33706 , 0x81d5f345 , 0x523733ab , 0x6d1bf5bc , 0x646b04b8 , 0xa6d49157 , 0x0e48c7fd , 0x05a184cf ] )
+  , Mont::unsafe_make( [ 0xcc60e528 , 0x9773b582 , 0xd54ebbcb , 0x0e726a1b , 0xc1f2e041 , 0x980d4b25 , 0xceb37847 , 0x27764468 ] )
+  , Mont::unsafe_make( [ 0xf688e48e , 0x02d5a1eb , 0xeb809600 , 0xb0bf4ac9 , 0xfa6ced7b , 0x0ebdcd62 , 0x395375d8 , 0x0adc6c21 ] )
+  , Mont::unsafe_make( [ 0x27efabd9 , 0x11e38828 , 0x90ee035c , 0x7829b74d , 0x5f8d4164 , 0xc805a5a4 , 0x0cb9afef , 0x10451c31 ] )
+  , Mont::unsafe_make( [ 0x5fce83bb , 0x86f67a6f , 0xa6470621 , 0xdb21c489 , 0xfb76a3fe , 0x01f5641b , 0xe401f515 , 0x0a789a68 ] )
+  , Mont::unsafe_make( [ 0x04c423fc , 0x9223fd7e , 0x09c5d4e8 , 0xd6666c91 , 0xf6660b57 , 0xf7e838c0 , 0xb5afd3c2 , 0x1802ab4b ] )
+  , Mont::unsafe_make( [ 0xbe5af0c6 , 0x26f6b69b , 0x045b81ef , 0x5e488667 , 0xaf62a482 , 0x127da1d9 , 0xcbd58540 , 0x03b751c4 ] )
+  , Mont::unsafe_make( [ 0x507b86b4 , 0xf78893dd , 0x1fe414d6 , 0x6d1753cd , 0xdca29771 , 0xcd0f1910 , 0xe80e7f50 , 0x00997e9d ] )
+  , Mont::unsafe_make( [ 0x6a5a2e0c , 0xecf5eac6 , 0x0730c89a , 0xe3cbdbdc , 0x22c8cbec , 0x9b19b94e , 0x94aba6a3 , 0x23c0ea8c ] )
+  , Mont::unsafe_make( [ 0x18d7ff67 , 0x1133c490 , 0x35473de6 , 0xb3c8143a , 0x0d52bf36 , 0x6edf645a , 0x97342291 , 0x1f1bc781 ] )
+  , Mont::unsafe_make( [ 0x5c757667 , 0x292cfcbd , 0x35d63458 , 0x431ab0ae , 0x3f56ccb8 , 0x407768a5 , 0x17097099 , 0x2ea32b62 ] )
+  , Mont::unsafe_make( [ 0xa257be73 , 0x841c2a98 , 0xc3e156f6 , 0xf1f955d8 , 0x2f867009 , 0xd4193aa1 , 0x770a4a78 , 0x1b9d9041 ] )
+  , Mont::unsafe_make( [ 0xde188dad , 0x9515870d , 0x132493d4 , 0x748a7b51 , 0x0380dd7b , 0x89382b60 , 0xd277e938 , 0x170da5a6 ] )
+  , Mont::unsafe_make( [ 0xd3f88434 , 0xea870914 , 0x954a46aa , 0x6be1d31d , 0x0f5b503f , 0xdaa0d98b , 0x88d302ca , 0x2381db27 ] )
+  , Mont::unsafe_make( [ 0x87397d10 , 0xcde6e425 , 0x1b571152 , 0xa2124446 , 0x27801962 , 0xfed83ea3 , 0x84d05af1 , 0x20a86260 ] )
+  , Mont::unsafe_make( [ 0xdfa790f0 , 0xdebd2bfc , 0xdb4404ff , 0x8dd44982 , 0x09fedddb , 0x6b7009cc , 0x9e31e5e6 , 0x12911f59 ] )
+  , Mont::unsafe_make( [ 0x548b1803 , 0x78f74897 , 0xe0d37f95 , 0xe7f5fbea , 0x29383814 , 0x2d26680b , 0xbdf112fc , 0x1a181ccc ] )
+  , Mont::unsafe_make( [ 0x42ef9b6c , 0x95d42d68 , 0x3264fe9e , 0x3e2b05e8 , 0x240bfbc6 , 0x380bf4ce , 0xb11b56af , 0x2ed4705a ] )
+  , Mont::unsafe_make( [ 0xeb3e73b4 , 0x5be7e4a9 , 0xd6f026a4 , 0x00fac5e6 , 0x93b6d16c , 0x13914971 , 0xbc26575b , 0x1713dcbc ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x163b2013 , 0x1bd029be , 0x99b1e0a5 , 0xa2c0e993 , 0x30f17348 , 0x3024cdf7 , 0xe84f2bac , 0x10b8fd1f ] )
+  , Mont::unsafe_make( [ 0xa08c851b , 0x9071862e , 0xa1af110c , 0xf9846a37 , 0xcb9a0f89 , 0x986d6a0a , 0xbf9c4333 , 0x09a8b3eb ] )
+  , Mont::unsafe_make( [ 0xdb47228e , 0xd38bb466 , 0x0561fe2c , 0xb38a635a , 0xf642f1df , 0x8c8e9297 , 0x83d823a1 , 0x0fd85c73 ] )
+  , Mont::unsafe_make( [ 0x1e881038 , 0x00077aea , 0xb16bd013 , 0x11de76fd , 0x84dc0b0b , 0x3817a71b , 0x23a6c5d7 , 0x2e0bf486 ] )
+  , Mont::unsafe_make( [ 0x94ec3ec9 , 0xdd451777 , 0x2fafa75f , 0x9f0b0513 , 0xcfa5335a , 0xa9ff6d24 , 0x7103b4b9 , 0x2e840dda ] )
+  , Mont::unsafe_make( [ 0x378e63a4 , 0xf032ade1 , 0x4158b09e , 0xbfd86351 , 0x947ae94a , 0x58e32566 , 0x7fe4e7a6 , 0x16baa6dc ] )
+  , Mont::unsafe_make( [ 0x5e189cef , 0x84473627 , 0x75d7af21 , 0x0db39fa4 , 0xd004225a , 0xe9c47fbc , 0xbb39d3e0 , 0x2d8699f3 ] )
+  , Mont::unsafe_make( [ 0x88a02fce , 0x7e13fc55 , 0xb8af4e59 , 0xd40df680 , 0x24f27882 , 0x0acdbb16 , 0x790dd1ba , 0x232d77f7 ] )
+  , Mont::unsafe_make( [ 0x7f8062b7 , 0x16596a08 , 0x644c7c1c , 0xb48d53d6 , 0x497966df , 0x7b8465d5 , 0xed1adbff , 0x2afc169b ] )
+  , Mont::unsafe_make( [ 0x0431996e , 0x4e85773a , 0x37645806 , 0x34e2e087 , 0x3e53e65e , 0x4e1a02e7 , 0xc2bd696f , 0x19879fde ] )
+  , Mont::unsafe_make( [ 0x62937ee1 , 0xa61a8615 , 0x500851c6 , 0x57bf3b1c , 0xd057a962 , 0x99556824 , 0x8041ff35 , 0x1a7637b3 ] )
+  , Mont::unsafe_make( [ 0xe0748010 , 0xd89627d2 , 0x3a37c29c , 0xf9d21632 , 0xc2af6e90 , 0x763a8222 , 0x6d9b6db1 , 0x1644eca7 ] )
+  , Mont::unsafe_make( [ 0xdf68b0db , 0x94c8277b , 0xef65abe0 , 0x4d17206a , 0x49a9ec91 , 0x6c5b6906 , 0x6c8e1891 , 0x060d0163 ] )
+  , Mont::unsafe_make( [ 0x9d37e09c , 0x8b7c4e84 , 0x663d7bfe , 0xd134154b , 0x701b9dd1 , 0x00e66c23 , 0x363e5d74 , 0x10a0cb5e ] )
+  , Mont::unsafe_make( [ 0x74e9d793 , 0x0778cb67 , 0x05dac5a8 , 0x1a1d5311 , 0x9fbfbf6c , 0x933028b4 , 0xb67edd88 , 0x19ef2f4e ] )
+  , Mont::unsafe_make( [ 0x33730cab , 0xce652ea7 , 0x836c4c8c , 0x9729dc23 , 0x30e622b7 , 0x283c1472 , 0xb3e5e262 , 0x24cc7405 ] )
+  , Mont::unsafe_make( [ 0x9993936a , 0xac5f984e , 0x4e68929d , 0x5739d67c , 0x68df5083 , 0x778e784c , 0x20a88c0a , 0x183d4132 ] )
+  , Mont::unsafe_make( [ 0xf8ac1114 , 0x973d215e , 0x53aa119a , 0xfe9e15f9 , 0xd0069974 , 0x2e46ef1c , 0x19cf5c9e , 0x0d31d6a0 ] )
+  , Mont::unsafe_make( [ 0x27bd6b7c , 0xd6320088 , 0x331a8aec , 0xb864aa8e , 0x0ddd9e94 , 0x1ed59373 , 0x53dd6459 , 0x1e70c880 ] )
+  , Mont::unsafe_make( [ 0x063ba65a , 0xd5c30b84 , 0x92b1a90f , 0x982b5f4b , 0xfb1885fd , 0x578c660c , 0xb6a74a73 , 0x06bd9810 ] )
+  , Mont::unsafe_make( [ 0x43d49f95 , 0x8855a56c , 0xdc20df96 , 0xa1b4b0b1 , 0x86e00df6 , 0x649941e1 , 0x8db1eaa7 , 0x2258a72d ] )
+  , Mont::unsafe_make( [ 0x27926b45 , 0xf1ba530f , 0xcb223b7e , 0x2caf01a0 , 0x751337a3 , 0xf89dd77f , 0x2d74fa6a , 0x096c03b3 ] )
+  , Mont::unsafe_make( [ 0xba3b04fa , 0x67a007c7 , 0x211334f2 , 0x9de9ebb3 , 0x271edfe4 , 0x06d600af , 0x05c8668a , 0x0125d6a1 ] )
+  , Mont::unsafe_make( [ 0xdc66cc35 , 0x6f611109 , 0x2264b474 , 0x16903e77 , 0x68ffa0ee , 0xf1816c02 , 0x2a872a02 , 0x0a90bdb8 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xbc363c9d , 0xf25561ef , 0x8c0428f6 , 0xcb7481e7 , 0x5fcefd4e , 0xba558721 , 0xd155ae08 , 0x1913f2de ] )
+  , Mont::unsafe_make( [ 0xc29000ec , 0x7d33f2a0 , 0xa290232b , 0x60ebb26c , 0x2bc780f6 , 0x1e224d45 , 0x84df4759 , 0x15538215 ] )
+  , Mont::unsafe_make( [ 0x7d0d3559 , 0xadb73b73 , 0x2842c43b , 0x4bec3084 , 0xc16562f9 , 0xd59eb0cf , 0xc4c0319b , 0x1fccb376 ] )
+  , Mont::unsafe_make( [ 0xce7d175f , 0x0ae4a459 , 0x3a611efc , 0xa8274e97 , 0x0cbbbb81 , 0x13863077 , 0x984423a7 , 0x02be7654 ] )
+  , Mont::unsafe_make( [ 0xd76aa770 , 0xec3e2e32 , 0x25c20735 , 0x7c8a6500 , 0x69940583 , 0x13d2ac66 , 0xdf1c6f7a , 0x0674fabb ] )
+  , Mont::unsafe_make( [ 0xdf1560fb , 0xb680c2ed , 0x7255322f , 0xd9ab2639 , 0x7c25c473 , 0xba13f4ab , 0xd2f40db4 , 0x16e2dd0f ] )
+  , Mont::unsafe_make( [ 0x9b538aec , 0xf2e7432b , 0x9277d475 , 0x3923c547 , 0x5f5a74d2 , 0x7d4692cf , 0x7e1c8dee , 0x03332528 ] )
+  , Mont::unsafe_make( [ 0x36750948 , 0x13924afa , 0x67ecef21 , 0xde769488 , 0x38802fb6 , 0x6b88bfa9 , 0x62dc9051 , 0x0c58ea1b ] )
+  , Mont::unsafe_make( [ 0xd74e0b48 , 0x7df73e5e , 0xb2337a2a , 0x2e1ebbf3 , 0x862a1e1b , 0xcfcc4c9c , 0xe9e1accf , 0x29f084f4 ] )
+  , Mont::unsafe_make( [ 0xec5bc9c8 , 0xa11a6dea , 0xe122e65a , 0x5ac4a157 , 0xd28d5e01 , 0x08ba62dc , 0x76c9d8f2 , 0x003fa6e8 ] )
+  , Mont::unsafe_make( [ 0xe5a2eb01 , 0x7b89c089 , 0xb437a120 , 0x6d6cc3ad , 0x44ba0fb1 , 0x20a3db4f , 0x3f9c3e63 , 0x01a46f2a ] )
+  , Mont::unsafe_make( [ 0xa92945c0 , 0xb6bb3e98 , 0xb1fc87fe , 0xaf1546d8 , 0x946502be , 0x179de54f , 0xd6ecc675 , 0x1844a656 ] )
+  , Mont::unsafe_make( [ 0x7cd3727f , 0x2ce9e5e4 , 0xd69ac8ab , 0x524bc05d , 0x5780d0ce , 0x2247cfb2 , 0x920ffa73 , 0x1f55c837 ] )
+  , Mont::unsafe_make( [ 0x52971374 , 0x212f96d4 , 0x95deee87 , 0x93d056a9 , 0x0e12f8bc , 0x3298049a , 0x742d3624 , 0x172fd1ca ] )
+  , Mont::unsafe_make( [ 0xbb0c58aa , 0x7963ede7 , 0xd20b742a , 0x18a42cc8 , 0xac5ff67d , 0x02457513 , 0x3a1eb98c , 0x2c2416eb ] )
+  , Mont::unsafe_make( [ 0xfd48655c , 0xd736d800 , 0xa2e5f2b8 , 0x6868e223 , 0x99e2d005 , 0xef873f25 , 0xe3ff00b9 , 0x0ac8824d ] )
+  , Mont::unsafe_make( [ 0x1dd8d8fa , 0xbaf527a1 , 0x421c144f , 0xd9f266da , 0x26934291 , 0x8f222d2a , 0xb5b32f74 , 0x199c8854 ] )
+  , Mont::unsafe_make( [ 0x14922223 , 0xe5171ff5 , 0x00609459 , 0x89429ec5 , 0x7adead37 , 0xd0683e06 , 0x3a01da53 , 0x1c341626 ] )
+  , Mont::unsafe_make( [ 0xc3849e9d , 0xf7c6b481 , 0xaaa96255 , 0xd8345747 , 0x2c84a691 , 0xcbbbde7f , 0x5664017a , 0x1a3ad191 ] )
+  , Mont::unsafe_make( [ 0xf93e7445 , 0xb7170e2f , 0x744752d5 , 0xb7710d65 , 0xe4be9dbc , 0x7438f12a , 0xd3f395ac , 0x0b118e94 ] )
+  , Mont::unsafe_make( [ 0xed21cae8 , 0x42febe02 , 0xfe87ae27 , 0x57edb00b , 0xe13a77ce , 0x41107f7e , 0x9445dfea , 0x16c5d04d ] )
+  , Mont::unsafe_make( [ 0xc559e85b , 0xd1e39605 , 0x62c1376f , 0x6ba93d4d , 0x52a77555 , 0x32ceeeaa , 0x4c56ffa1 , 0x0ab5d84c ] )
+  , Mont::unsafe_make( [ 0x9428099f , 0x2fae5a5b , 0x414d0dd0 , 0xa9a05d07 , 0x7da762ee , 0xe97389cc , 0x5138a7ff , 0x022c46e8 ] )
+  , Mont::unsafe_make( [ 0x7ae2dd18 , 0xc2d7c88d , 0x2c99bd5f , 0x59e3015b , 0xd543f2a3 , 0x27e856fe , 0x91bdbe73 , 0x00e54f18 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x8367c7cc , 0x846fd45c , 0x654d3eff , 0x5448659a , 0x83fb25bb , 0xaf41a701 , 0x6d6f185d , 0x1437c59c ] )
+  , Mont::unsafe_make( [ 0x228d3164 , 0xf7eb28cf , 0x25394cf7 , 0xdfb2582b , 0x4a844471 , 0xbe8792c8 , 0x2ac32081 , 0x1bbfad18 ] )
+  , Mont::unsafe_make( [ 0xf53961a8 , 0x47196c51 , 0x0d6e80ef , 0xdfc1f79d , 0xb8248f40 , 0x60a2a630 , 0x0be74b02 , 0x28494de5 ] )
+  , Mont::unsafe_make( [ 0x76cfbde3 , 0x927d2ad4 , 0xa20bcd4f , 0x460cde22 , 0x10806a01 , 0x84e61971 , 0x2a4762e0 , 0x26618a89 ] )
+  , Mont::unsafe_make( [ 0x16ee27fd , 0x4079ebee , 0xfc127676 , 0x4d7315ae , 0x185bc70e , 0x489f50fb , 0x535808f1 , 0x0b98c7ea ] )
+  , Mont::unsafe_make( [ 0x26c6f31c , 0x46ad75e5 , 0xa11afefc , 0xb93464c7 , 0xcb6e4ef0 , 0xe635ab2f , 0x429e1dac , 0x015ed548 ] )
+  , Mont::unsafe_make( [ 0x8cc06b44 , 0x5ad4429f , 0xde2965d7 , 0x82714f75 , 0xa24c2212 , 0xc47de835 , 0xc0aa6fba , 0x1ed26056 ] )
+  , Mont::unsafe_make( [ 0x50d547d6 , 0xffcdd903 , 0xc52e7b81 , 0x117b6416 , 0x17f73d90 , 0xa4d6895e , 0xd8e9ccd5 , 0x022ce3bb ] )
+  , Mont::unsafe_make( [ 0xbe8b5802 , 0x87b6d30d , 0x5e706d8f , 0xda618a6a , 0xc1475762 , 0xbedffed0 , 0x589a356a , 0x10ca125e ] )
+  , Mont::unsafe_make( [ 0xbee155e6 , 0xcad6cb3b , 0xbdc97323 , 0x25283e15 , 0x03e2e9b6 , 0xc21ed49c , 0xa3b48e9f , 0x253b2124 ] )
+  , Mont::unsafe_make( [ 0x0d423c43 , 0xf0e9ae3b , 0x03cffbaf , 0x05a95817 , 0x5156a92a , 0xf4f65fbb , 0xd56201a4 , 0x0f65b1db ] )
+  , Mont::unsafe_make( [ 0x1302569b , 0x8f792bdc , 0xb9547115 , 0xe1ae8b34 , 0xe8850370 , 0x3d0c216d , 0x3819b422 , 0x151591f1 ] )
+  , Mont::unsafe_make( [ 0xe546c9cb , 0x9890baf0 , 0x7653e010 , 0xe7d0f898 , 0xfb350a0a , 0xa82e3b4a , 0x842043c2 , 0x000cb91c ] )
+  , Mont::unsafe_make( [ 0xd85592cc , 0xe93c99f8 , 0x43adc346 , 0x1aee4317 , 0x2a0bd89e , 0x59fc9a72 , 0xad41b541 , 0x0b67a40c ] )
+  , Mont::unsafe_make( [ 0x6cdb5f64 , 0xe74bc21d , 0xff47f167 , 0xad5e925b , 0xb0812f9f , 0x2c3136d6 , 0xc186dd54 , 0x2cb75222 ] )
+  , Mont::unsafe_make( [ 0x2f9b85f3 , 0xa0690fcb , 0x8f9ba0b5 , 0x57c41105 , 0x5506aa08 , 0x7bb97ffd , 0x4b7ec110 , 0x2b1dad4a ] )
+  , Mont::unsafe_make( [ 0xe19d2d2d , 0x901288c0 , 0xbeffd2e1 , 0x6ff18fcb , 0x1b6af909 , 0x5bb54974 , 0x83493713 , 0x2e01d022 ] )
+  , Mont::unsafe_make( [ 0x4907495d , 0x0f6660ad , 0xd31c1457 , 0x3e84b3b0 , 0x82f2ab3e , 0xb983de09 , 0xbee276f8 , 0x01cbe39c ] )
+  , Mont::unsafe_make( [ 0x3afa983a , 0x83d758f8 , 0x6f52a905 , 0xdaf69742 , 0x3fa5bdec , 0x75549a8f , 0x4d385b4d , 0x2febbbb1 ] )
+  , Mont::unsafe_make( [ 0x3c799f1a , 0xb6d7cff8 , 0x5b6c69e1 , 0x814dfa65 , 0x43e63e30 , 0x928d3fea , 0x57dec392 , 0x14c720c1 ] )
+  , Mont::unsafe_make( [ 0x40bed77f , 0x27133b46 , 0x243aecaa , 0xcfa58aaa , 0xc4f09c76 , 0x6dd3a446 , 0x0ef2ad05 , 0x04401887 ] )
+  , Mont::unsafe_make( [ 0x184328d0 , 0x19b55a2a , 0x546a7569 , 0x05d369b4 , 0x1e00e3a1 , 0xfb4a4c28 , 0x09cc22ed , 0x273780bf ] )
+  , Mont::unsafe_make( [ 0x64447c48 , 0x31a5642b , 0x929df37f , 0x0ec121d0 , 0x12a5066e , 0xb57832bf , 0x393c9612 , 0x0bad4fab ] )
+  , Mont::unsafe_make( [ 0xaab21e39 , 0xa93fbdee , 0x6f5bcf7f , 0x50457f94 , 0x8fa7bb4a , 0xcc55d2ed , 0x6c459974 , 0x181565c4 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xd6982035 , 0x41dc5c53 , 0x82a446a3 , 0x6a5f4762 , 0x74747f52 , 0xe853e209 , 0x75c90ebe , 0x2cda6571 ] )
+  , Mont::unsafe_make( [ 0x5ca2a4b5 , 0x82176b39 , 0x04fdcf91 , 0xb43f485f , 0xda869f45 , 0x11035e14 , 0xce3df295 , 0x05747a67 ] )
+  , Mont::unsafe_make( [ 0x54739e52 , 0x31c70fa7 , 0x9ebe3b85 , 0x566d7ff3 , 0xae20c2d3 , 0x5cce45bd , 0x78a2737c , 0x143f7500 ] )
+  , Mont::unsafe_make( [ 0x1d63e945 , 0x079ba2de , 0xf593b908 , 0xca154400 , 0x39447813 , 0x2578a213 , 0xf51efa0d , 0x2e2ab57a ] )
+  , Mont::unsafe_make( [ 0xb3fda7dd , 0x22b54122 , 0x7e310e67 , 0x89c72a5f , 0x0017584f , 0x66b40af5 , 0xcdd581ee , 0x0d1a681c ] )
+  , Mont::unsafe_make( [ 0x9751653b , 0xfc68bf06 , 0x0798c0cb , 0xa404d7dc , 0xa840463c , 0x6de1af4e , 0xa89b35da , 0x03ca86fe ] )
+  , Mont::unsafe_make( [ 0x9dcf439b , 0xcdfbda06 , 0x99029539 , 0xd209d3a0 , 0xf3ba37d0 , 0x746c38ad , 0x0a7f34ad , 0x14b2aff4 ] )
+  , Mont::unsafe_make( [ 0x27b4785b , 0x91596ff7 , 0xb8a8f96f , 0x56ccf890 , 0x25c89b98 , 0x25c470b4 , 0x5c427d65 , 0x29f30d5b ] )
+  , Mont::unsafe_make( [ 0x359bd2e0 , 0x4fcd888a , 0xd6c9563b , 0x5908f428 , 0x8ff01461 , 0x060d40ae , 0x1dec812a , 0x139e60d2 ] )
+  , Mont::unsafe_make( [ 0xd709747c , 0x14a62e55 , 0x420d8013 , 0x25dceeaf , 0xc2de7abe , 0x3a697915 , 0x256d35fd , 0x08f762ec ] )
+  , Mont::unsafe_make( [ 0x6366eda2 , 0x2fdb7a3f , 0x6cc52759 , 0xcd5b64d0 , 0x71a10fbb , 0x8c107289 , 0xb27380e4 , 0x1f4391d1 ] )
+  , Mont::unsafe_make( [ 0xce3affc2 , 0x5ce001a3 , 0x935c3ecd , 0x78de6834 , 0x177d4ac6 , 0x27f65a5c , 0xcec1dbdc , 0x17d782b2 ] )
+  , Mont::unsafe_make( [ 0xe9bb7d25 , 0xee1cb870 , 0xc2c5c3ca , 0x6ed06f0d , 0x8ee877dd , 0x7c1b1fe7 , 0x9e405606 , 0x0d86b007 ] )
+  , Mont::unsafe_make( [ 0xdcc71bc2 , 0xcef106ae , 0xd89ee183 , 0xd7b070df , 0x3be6fcfa , 0xa5142843 , 0x32939ebc , 0x0f601c49 ] )
+  , Mont::unsafe_make( [ 0xe08e1840 , 0x217df090 , 0x7a2858e9 , 0xae4de19d , 0xf7f9e09f , 0x7bf95129 , 0x9b917fa2 , 0x01b4432d ] )
+  , Mont::unsafe_make( [ 0x5b3e8c75 , 0x432891d9 , 0xcf117db5 , 0xda88abc8 , 0xb62d9d6c , 0xd75f5c3c , 0xa07a4b87 , 0x255e8b13 ] )
+  , Mont::unsafe_make( [ 0x8caf765a , 0x06e22e0e , 0xa5968335 , 0x0fea6bcb , 0xfa3cdb4e , 0x35cad52b , 0x7099a5f3 , 0x032327a0 ] )
+  , Mont::unsafe_make( [ 0xbee3775e , 0x4150cc9d , 0x6f3a62d5 , 0xb73ee116 , 0x646e9900 , 0xf6c98679 , 0x900682db , 0x2f4a76c2 ] )
+  , Mont::unsafe_make( [ 0xeccaaf91 , 0xc075d089 , 0x0c20d237 , 0xcaa67793 , 0x58e4112c , 0x88a487b7 , 0x3e806d82 , 0x0d509efd ] )
+  , Mont::unsafe_make( [ 0x786442a2 , 0x16b451ce , 0x47ad4018 , 0x699f1b95 , 0x0f48d713 , 0xe69875bd , 0x0a050c91 , 0x2d3f95fb ] )
+  , Mont::unsafe_make( [ 0x3491d308 , 0x185308ca , 0x7b00c0e1 , 0x20e6c47f , 0x5489bc77 , 0xce651c0c , 0x842b4367 , 0x083ff5c3 ] )
+  , Mont::unsafe_make( [ 0x127c5f86 , 0x24d1eeaa , 0x949bd4a4 , 0x56d815c6 , 0xb46dcae3 , 0xe0ca4cf3 , 0x2a915b03 , 0x083c90c3 ] )
+  , Mont::unsafe_make( [ 0x435b681d , 0x0b09290c , 0xeaa0c98d , 0x43fcee0c , 0x7a360400 , 0x38f836b1 , 0x7021ed68 , 0x2edade42 ] )
+  , Mont::unsafe_make( [ 0x93c74120 , 0x2469bff6 , 0x92eac3ff , 0xfe03df0f , 0x3dbe4641 , 0x2f0825fa , 0xf6ecf064 , 0x2fca82da ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xbb3e145f , 0x5c5da53d , 0xa7aeb154 , 0xf073693a , 0x05513859 , 0xab05cd7a , 0x7dcee896 , 0x0b61a4db ] )
+  , Mont::unsafe_make( [ 0x64a74feb , 0x6ad15f73 , 0xa31f34ec , 0x7486f304 , 0x5e0646d4 , 0x54991664 , 0xcebb0d48 , 0x12e96e9f ] )
+  , Mont::unsafe_make( [ 0x0cd0707d , 0xaf5031c5 , 0x1936788e , 0xefe2d8d7 , 0x24dc719e , 0xfdfa0ce2 , 0x4ca891f5 , 0x0d7899d6 ] )
+  , Mont::unsafe_make( [ 0x85273468 , 0xf0ebf1e7 , 0x7e9394f0 , 0x9bed91eb , 0x70c6c9ec , 0xaf7166fd , 0xad46fa0b , 0x0f2e5ed1 ] )
+  , Mont::unsafe_make( [ 0x507b80f4 , 0xabc5dda4 , 0x560fe1cb , 0xdfce06e8 , 0xbdc02bad , 0x55f415da , 0x3f7f303b , 0x1d57c38d ] )
+  , Mont::unsafe_make( [ 0xdafe3958 , 0x92134d28 , 0xf09dcd69 , 0x98d030a9 , 0xfe583f01 , 0x72ba22c2 , 0xdc5ee2e1 , 0x27ba49b5 ] )
+  , Mont::unsafe_make( [ 0x03394c0f , 0x4c0b521d , 0x05bedf34 , 0x6719359e , 0xf4974ac1 , 0x9da4041b , 0x435b3de7 , 0x1a87cce7 ] )
+  , Mont::unsafe_make( [ 0x1727b1dd , 0x7333e1b3 , 0x30d8b99c , 0x6c263ce4 , 0xfe5d2ad2 , 0x056c2fc9 , 0x71e73e4d , 0x12e6f317 ] )
+  , Mont::unsafe_make( [ 0xe63aa943 , 0x51e779a1 , 0xfcfc5ae1 , 0x3782e321 , 0x37a7481a , 0x5b344169 , 0xdb10560f , 0x267ea383 ] )
+  , Mont::unsafe_make( [ 0x844465e5 , 0xbc365199 , 0x9a184e7b , 0xe31a0ef0 , 0xc1069a0b , 0x7eb0ee12 , 0x0792f886 , 0x2c2387ff ] )
+  , Mont::unsafe_make( [ 0x11173a3b , 0x7c2261c8 , 0x46994163 , 0x09671e23 , 0xf2c74e69 , 0x789f0d61 , 0xf67d7fac , 0x1da20e2a ] )
+  , Mont::unsafe_make( [ 0x5b6fcee3 , 0xb3a3e506 , 0x12b2f8f2 , 0xa486e521 , 0x390c0947 , 0x1437971c , 0xf6ce5a19 , 0x2a9553e7 ] )
+  , Mont::unsafe_make( [ 0x150a8455 , 0xbdfc14e7 , 0x22c88ee0 , 0xe0e5cf9e , 0xea590d40 , 0xc77afd47 , 0x572297ef , 0x01cc5f55 ] )
+  , Mont::unsafe_make( [ 0x27b17b6b , 0x4346e783 , 0xcb1dfa93 , 0x6e35e39d , 0x6cea408a , 0x2e32230b , 0x6ca5b3b4 , 0x0192de2a ] )
+  , Mont::unsafe_make( [ 0x311b2a31 , 0xd8af3e01 , 0x0bf44fbf , 0x0e952b12 , 0xebe55ff3 , 0xe0de9ed2 , 0x0760f218 , 0x1bb8de62 ] )
+  , Mont::unsafe_make( [ 0x3e5dd608 , 0x3913275b , 0x5232d85e , 0x6d10c0a2 , 0x05c765bf , 0xddd21a4b , 0x240924c6 , 0x1a39ecb1 ] )
+  , Mont::unsafe_make( [ 0x37305297 , 0x6754987f , 0x544e90b5 , 0x6ff2388f , 0x72a8704f , 0xf0a82ed7 , 0xaa263940 , 0x15b4067a ] )
+  , Mont::unsafe_make( [ 0x6471c764 , 0xdb6f369a , 0x56f607e3 , 0x08593f25 , 0x31d6cd03 , 0x0d55afca , 0x96afad64 , 0x2eee1cf5 ] )
+  , Mont::unsafe_make( [ 0x7743b221 , 0x9166b1f2 , 0x572f16e0 , 0x58287efd , 0x4db43941 , 0xbbc7ee0b , 0xef2f7bc9 , 0x220063c1 ] )
+  , Mont::unsafe_make( [ 0x09e677b0 , 0xd1ad83e9 , 0xf52fe92a , 0xfebaad46 , 0xa16170b1 , 0x3bf4216b , 0x6415859d , 0x11896e85 ] )
+  , Mont::unsafe_make( [ 0x80f9b7ad , 0xf46f4049 , 0xf56eae41 , 0x60a71770 , 0x6eff677b , 0x09753e72 , 0xc89499e5 , 0x2f84959a ] )
+  , Mont::unsafe_make( [ 0x31be21c1 , 0x48c7aab6 , 0xaa95cfb3 , 0xcd52d1ac , 0x444607ca , 0x43f9f73d , 0x22754e7a , 0x1681082d ] )
+  , Mont::unsafe_make( [ 0x61ae9301 , 0x68afc213 , 0xbf09a7d2 , 0x7603ba90 , 0xc7fd5c72 , 0x7f28177b , 0xc7fa7a41 , 0x256a61c4 ] )
+  , Mont::unsafe_make( [ 0x17769d18 , 0x33b04afa , 0x9ee008b8 , 0x289a264b , 0x9f49f0bb , 0x7ea9f1be , 0xaaa36fd3 , 0x26a1b6ec ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x458f8be0 , 0xf3654f51 , 0x795d458c , 0xdcfb92f4 , 0x23d3da5c , 0xd70d2f22 , 0x4fda45d6 , 0x043c13b0 ] )
+  , Mont::unsafe_make( [ 0x89e3408f , 0x974f0add , 0xdccef5bf , 0xe50404c3 , 0x08815db9 , 0x64514b0e , 0x6e6db41a , 0x2195e98b ] )
+  , Mont::unsafe_make( [ 0x8daaf7d0 , 0x1d738df3 , 0xecdf46f5 , 0xd97d6fe7 , 0xce0390ae , 0x16654732 , 0xbb30ff55 , 0x3016158b ] )
+  , Mont::unsafe_make( [ 0x8ea5d2cf , 0xda773d99 , 0x7646d459 , 0x0a1ac0ac , 0x40b843f4 , 0x25574274 , 0x1c749c2e , 0x0d4ecc85 ] )
+  , Mont::unsafe_make( [ 0xd8a13d58 , 0x1132ef27 , 0x4517ce8a , 0x6de4e02c , 0xc18864ec , 0xf4f046b4 , 0x8255fea2 , 0x0c878b5c ] )
+  , Mont::unsafe_make( [ 0x4d8dd9fd , 0x9abdc26b , 0xf40af772 , 0x444e8d76 , 0x0996cbad , 0x4f757cc4 , 0x7fb5fab8 , 0x15439c55 ] )
+  , Mont::unsafe_make( [ 0x2750ab64 , 0x8eef6b33 , 0x85e427a7 , 0xdfd13a7e , 0xcbae30bc , 0xd1004831 , 0x579868e6 , 0x014304ee ] )
+  , Mont::unsafe_make( [ 0xb7f129e6 , 0x7e6703f8 , 0xf266e1ab , 0xb357a5e2 , 0x7cf1d123 , 0x39164408 , 0x876047a2 , 0x1e0fb638 ] )
+  , Mont::unsafe_make( [ 0x5208601b , 0x99fff120 , 0xc6f3003f , 0xe6333e20 , 0x4eedbaa4 , 0xfe52654e , 0x502b03ce , 0x2247b4ab ] )
+  , Mont::unsafe_make( [ 0x55de903a , 0x6c1be38b , 0xb612f6cc , 0xfe897540 , 0x86509a25 , 0x1a4ef8c0 , 0x02a128b3 , 0x14882775 ] )
+  , Mont::unsafe_make( [ 0x5339e98d , 0x516d5337 , 0x8e6d83e4 , 0x24fbfa26 , 0x98dd02c7 , 0x17f73fdc , 0x82c8484d , 0x14409da5 ] )
+  , Mont::unsafe_make( [ 0x2f0ff6ed , 0x59728076 , 0xd588465c , 0xc0317a82 , 0x438b575b , 0x537b60dd , 0x4e02d5a7 , 0x184d92d5 ] )
+  , Mont::unsafe_make( [ 0x8374ce47 , 0xab81e515 , 0x345ee451 , 0x80dad0a4 , 0xb9508bda , 0x5af7de07 , 0xaa44b7be , 0x2bd28952 ] )
+  , Mont::unsafe_make( [ 0x4faef993 , 0x7580a818 , 0x34c92bb1 , 0x8cd74c6f , 0x5b4b8fcb , 0xeca531df , 0x999eba0b , 0x0e172f02 ] )
+  , Mont::unsafe_make( [ 0xb97a25fc , 0xc22d3d82 , 0x940ca40e , 0xc78e238d , 0x173e4c25 , 0xd1ca16d3 , 0xc3430612 , 0x1f5d9af2 ] )
+  , Mont::unsafe_make( [ 0xd71ceee4 , 0x33c573c1 , 0x8cbb238e , 0x6b40f227 , 0x5e5ad60b , 0x4f8f3005 , 0x76b4f5d0 , 0x1c6772ce ] )
+  , Mont::unsafe_make( [ 0xb1d39bab , 0x8329fcab , 0x9e4de690 , 0x8498a2a0 , 0xa50aab09 , 0x78e5332d , 0x7daad0c9 , 0x21a8b564 ] )
+  , Mont::unsafe_make( [ 0xb41ace32 , 0x11825cdd , 0x28ffd89b , 0xb27eec6d , 0x759c9e86 , 0xd4ef9955 , 0x951274dc , 0x1b43e124 ] )
+  , Mont::unsafe_make( [ 0x92269bc4 , 0x1dfe5ea1 , 0x02c5a630 , 0x15d75fd6 , 0x9176bd66 , 0x8af74386 , 0x3728afa7 , 0x256ae955 ] )
+  , Mont::unsafe_make( [ 0xf1b3e762 , 0x3e87fb92 , 0x1f9c3810 , 0x806d8943 , 0x0779acea , 0xdd6d934f , 0x56f2d3bc , 0x210a1332 ] )
+  , Mont::unsafe_make( [ 0x7b1a9760 , 0x255ebba5 , 0x22aa3449 , 0x6385dee4 , 0x5f05e1c9 , 0xfcde6656 , 0x22018d0a , 0x096e3ca1 ] )
+  , Mont::unsafe_make( [ 0xb9e2a70f , 0xf08afab2 , 0x6320042a , 0x0973039e , 0xf40d4740 , 0xebb4b147 , 0x75c751d6 , 0x17e0a5f2 ] )
+  , Mont::unsafe_make( [ 0x14a8c127 , 0xe32323cb , 0xa586664e , 0xbc29d79d , 0x52caafdf , 0x78a6ee06 , 0xf84d1994 , 0x05e66050 ] )
+  , Mont::unsafe_make( [ 0x825df3b6 , 0x959c8151 , 0xe1a0c147 , 0x08437b38 , 0xac66efae , 0x3f7a35de , 0x84aa588a , 0x09a58c45 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xe57d7ce8 , 0x8ee46e4a , 0xbcf79c0a , 0x2a8f527c , 0x72fb3082 , 0x2548d3d3 , 0xb604133e , 0x12adeb64 ] )
+  , Mont::unsafe_make( [ 0x9518549d , 0x1539291d , 0x6d4e6530 , 0x09e4fcb1 , 0x5312a5b9 , 0xed06f65b , 0xfe6098cd , 0x179f22a4 ] )
+  , Mont::unsafe_make( [ 0xac02966e , 0x2a9c3392 , 0x749d4f3e , 0x17ddbbc8 , 0xee7059d2 , 0x5623eefc , 0x8561ec95 , 0x081c41db ] )
+  , Mont::unsafe_make( [ 0x570f9d2d , 0xf7cdc10d , 0xbd3d1c3b , 0xfc019bc8 , 0x8cf23470 , 0x4f159956 , 0x9f4f66ae , 0x221d7356 ] )
+  , Mont::unsafe_make( [ 0xd0ef702b , 0x11bfd82f , 0xa7d16636 , 0xcbc20dd3 , 0x0578a152 , 0x2b189a16 , 0xe75d1f60 , 0x169cc2d3 ] )
+  , Mont::unsafe_make( [ 0x6c03241f , 0xd56eee93 , 0xd7dea1a4 , 0x361bb0c5 , 0x1e700356 , 0xa2d97699 , 0x7d7b91a3 , 0x23dc7959 ] )
+  , Mont::unsafe_make( [ 0xb521b265 , 0xe2a58e89 , 0xbb0d3acf , 0x54b7d99e , 0xbdaafd33 , 0x7acf09e1 , 0x07f9f095 , 0x2a7e8aad ] )
+  , Mont::unsafe_make( [ 0xa6282180 , 0x26f81efb , 0x35c97789 , 0x99219825 , 0xfff00651 , 0xcf22ee7c , 0x8ccb2536 , 0x3058aae0 ] )
+  , Mont::unsafe_make( [ 0xdff1a5cd , 0x0fc0113c , 0xf5b95c9b , 0x69f53bf1 , 0x1fc277c4 , 0x4326decc , 0x1401fdf2 , 0x095b5915 ] )
+  , Mont::unsafe_make( [ 0x2712e064 , 0x994a83bc , 0xee0a3d0a , 0xe8d32141 , 0xefbd5fb4 , 0x1f8205c1 , 0xc224ab97 , 0x12725917 ] )
+  , Mont::unsafe_make( [ 0xf9c956b8 , 0x3764a83a , 0xb5845326 , 0xbc7d0f2f , 0x09f3e852 , 0x6bbf3581 , 0x2114002a , 0x17ae7a06 ] )
+  , Mont::unsafe_make( [ 0x1abbf7c4 , 0xecae9241 , 0x485054f8 , 0x69934e6c , 0x7a5cdec3 , 0xea778b6c , 0x7a7fe65b , 0x04662160 ] )
+  , Mont::unsafe_make( [ 0xa2038949 , 0x09f850fd , 0x4fa970e0 , 0xc79fd19b , 0x4f9f8d68 , 0x6c576a2c , 0x94a3e10c , 0x09c15b5f ] )
+  , Mont::unsafe_make( [ 0xd3d9de1c , 0x72dc63e9 , 0xca88290e , 0x308b4ff3 , 0x08c02171 , 0x749617b5 , 0xfcc5831e , 0x0fa91da9 ] )
+  , Mont::unsafe_make( [ 0xa8e9ad4a , 0xb4b4dd55 , 0x66c1016e , 0x65786ee0 , 0x58939086 , 0xbd3de175 , 0x87c7f1d6 , 0x2d9a297f ] )
+  , Mont::unsafe_make( [ 0xe4f58515 , 0xd68cb1b6 , 0x6413cae2 , 0x8e511509 , 0xb72f13b0 , 0xe3734176 , 0xb10d3f34 , 0x03bc10ed ] )
+  , Mont::unsafe_make( [ 0x6caf1701 , 0x0cab7b12 , 0x503e94a8 , 0x7030598a , 0x1c6fb04d , 0x60fb09d7 , 0x65652162 , 0x14774b51 ] )
+  , Mont::unsafe_make( [ 0x8eb56cf7 , 0x89caba35 , 0x9a61fe41 , 0x807dac4a , 0xf6c46444 , 0x368124fb , 0xc361f568 , 0x22dab1b0 ] )
+  , Mont::unsafe_make( [ 0x6b3654a7 , 0x66de2e33 , 0xe2bea32d , 0xb24afdfe , 0x087edd9f , 0xca153e22 , 0x45d202e1 , 0x0ef87664 ] )
+  , Mont::unsafe_make( [ 0xf5f0657a , 0x0dd14ef6 , 0x86d86c81 , 0x7e11731e , 0xae8af094 , 0x20bba7c3 , 0x3c09a58a , 0x2f011fe8 ] )
+  , Mont::unsafe_make( [ 0xda526443 , 0xee174aef , 0x787be73b , 0x146862a0 , 0xd2db5bb7 , 0xf933af4d , 0xc62038c3 , 0x231d4142 ] )
+  , Mont::unsafe_make( [ 0x427d52bd , 0xa5cde5fb , 0x5b156224 , 0x61c71a5f , 0x79a0c900 , 0x76fae441 , 0x23234519 , 0x21894026 ] )
+  , Mont::unsafe_make( [ 0x2a2f6987 , 0x30205291 , 0x357cff92 , 0x2dac5976 , 0x0fe2ef7c , 0x5ea0cac9 , 0x94a5a937 , 0x1ee9d4da ] )
+  , Mont::unsafe_make( [ 0xd17d6af6 , 0x2f94b94d , 0xfdcf510f , 0x645760f8 , 0xcf7f38e8 , 0x334546af , 0xacb4a0bd , 0x03eb3533 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xc87ddf3a , 0xae80e088 , 0xf70a3d81 , 0xd68d2a32 , 0xd0955a8a , 0xe8c2f441 , 0x285a5748 , 0x0a03a577 ] )
+  , Mont::unsafe_make( [ 0x93d2738e , 0xbad2fe1a , 0xabcdac21 , 0xd8ad2090 , 0x544a0489 , 0x149d4d72 , 0xbe74d45a , 0x2758f87e ] )
+  , Mont::unsafe_make( [ 0x0380533d , 0xc8637341 , 0x67f054f0 , 0xdc51cbe1 , 0xa76b937d , 0x9b035bda , 0xd4ca320c , 0x1f31e195 ] )
+  , Mont::unsafe_make( [ 0xb8f76d83 , 0x09244590 , 0xa8a53a78 , 0x892a8e05 , 0xdb408383 , 0x8e375c57 , 0x75305482 , 0x1f495b2b ] )
+  , Mont::unsafe_make( [ 0x727ad04e , 0x3f93edea , 0xcca48958 , 0x4f0350b1 , 0xbc2c9f8c , 0xbcac6344 , 0xb3c2389f , 0x25e4b9b1 ] )
+  , Mont::unsafe_make( [ 0x777e7c15 , 0x688b7dd6 , 0x5cf05722 , 0x0084e491 , 0x646f349a , 0xcdace6e7 , 0x1670961d , 0x14d077bc ] )
+  , Mont::unsafe_make( [ 0xf43105f3 , 0x72fee8f7 , 0xa213ed6a , 0x97db5b54 , 0x6a1b9208 , 0x526ea7d4 , 0xd41c251d , 0x02eeb866 ] )
+  , Mont::unsafe_make( [ 0x417a1aa1 , 0x197155a2 , 0x2e9b8cce , 0x4f54ef52 , 0xa331846a , 0x2130ce08 , 0x8040bfcf , 0x0719c3ff ] )
+  , Mont::unsafe_make( [ 0x11605c3b , 0x8fdb54b1 , 0xe2a527cd , 0xf3391464 , 0xfd1b9620 , 0x18a01b17 , 0xeeeda232 , 0x26d78b6f ] )
+  , Mont::unsafe_make( [ 0xa5315753 , 0x95477980 , 0xd653e3a5 , 0xa6166999 , 0x5c28d3aa , 0x79b5f96a , 0x747228d5 , 0x2f46b7cc ] )
+  , Mont::unsafe_make( [ 0xeeeb1cda , 0xeed5b09f , 0x5926698a , 0x110dee0f , 0x3f97349b , 0xe503f789 , 0x0e6d4a79 , 0x1714e149 ] )
+  , Mont::unsafe_make( [ 0xfe66bba3 , 0x56412f2d , 0x494771b4 , 0xc07e25eb , 0x4bb19c8e , 0xf0a1986c , 0x94433234 , 0x2a17cf95 ] )
+  , Mont::unsafe_make( [ 0x678bdfc9 , 0x4c64832a , 0xe487b887 , 0x4974a678 , 0xf562d91d , 0xacb3c5af , 0xe57487ec , 0x0931a81f ] )
+  , Mont::unsafe_make( [ 0xa949d028 , 0x22647615 , 0x26df8439 , 0x36ccd06b , 0x384522ae , 0x5fd7c846 , 0x36365b49 , 0x29d24687 ] )
+  , Mont::unsafe_make( [ 0xfa3ba6b5 , 0x19bdbcaf , 0x6ac57373 , 0x9d18e31d , 0xf900cf7a , 0xfa7ff916 , 0x9ceaefb2 , 0x100b945a ] )
+  , Mont::unsafe_make( [ 0xea4d6604 , 0x5b070c9f , 0x01cfbd21 , 0x04ec00dc , 0x7cba1bbb , 0x798b9565 , 0x59e04c08 , 0x17a5dc01 ] )
+  , Mont::unsafe_make( [ 0xbad37e04 , 0x75ae88e8 , 0x753150c5 , 0x2bc94c66 , 0xaf7696bd , 0x9bc057f8 , 0xed51160d , 0x13bd0cb1 ] )
+  , Mont::unsafe_make( [ 0x66d42d43 , 0xfd35380d , 0xd66b9237 , 0x51186a7c , 0x522edf27 , 0x057a9af2 , 0x9e6f7539 , 0x0ac9ce1d ] )
+  , Mont::unsafe_make( [ 0x21147fc8 , 0x93c2ef08 , 0xd3bf8f19 , 0xee83acbd , 0x2a48fbb1 , 0x53b305de , 0x35e69312 , 0x271ebd70 ] )
+  , Mont::unsafe_make( [ 0x91fb9481 , 0x5fd02428 , 0x27882512 , 0xe575f849 , 0xb2befbbf , 0xf57583b5 , 0x7aec8ce4 , 0x0f073525 ] )
+  , Mont::unsafe_make( [ 0xb0711493 , 0x2b5ea3d9 , 0x4eb6b9c2 , 0x0cb71738 , 0x5a33f656 , 0x86af8492 , 0x6d177257 , 0x20c76e4f ] )
+  , Mont::unsafe_make( [ 0x111384c8 , 0x2c1591c9 , 0xd1c41ed1 , 0xe7fa9391 , 0xf118d82a , 0x7a82a137 , 0x4f391c26 , 0x2186185c ] )
+  , Mont::unsafe_make( [ 0x9042e843 , 0x9dd83e3d , 0x1ee3bf1f , 0x18b628e0 , 0x7e1b86ab , 0xefc8c80e , 0xfb408996 , 0x304b8345 ] )
+  , Mont::unsafe_make( [ 0xd5ac476c , 0x4f342ce3 , 0xf0c0fcbc , 0xc1454847 , 0x31f2f037 , 0x5c64dce7 , 0x8165f15b , 0x2e20e737 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x72accc21 , 0x2390a749 , 0x8e8f19c4 , 0xaa7bf67b , 0x2e06cb0d , 0xe7daffda , 0x8a4417a0 , 0x023e3eee ] )
+  , Mont::unsafe_make( [ 0x89a11aac , 0x35486454 , 0x5d18d059 , 0xc33db1bb , 0xeea9803b , 0x39bf61c0 , 0x09d78b07 , 0x23097ee9 ] )
+  , Mont::unsafe_make( [ 0x08f7969d , 0x939959dc , 0x5651110e , 0xcf42084a , 0x92888e64 , 0xe6dddcb8 , 0x1773cc8e , 0x28828b1e ] )
+  , Mont::unsafe_make( [ 0x5035cd72 , 0x94a3d1e2 , 0x412f03c7 , 0xf24fa565 , 0xfe4898d2 , 0x4a80917b , 0xf87486a9 , 0x14e6af58 ] )
+  , Mont::unsafe_make( [ 0xcea4c23f , 0x9411710a , 0xe4bd9ba9 , 0x1960a4fc , 0xec202955 , 0x2f3e57d9 , 0xc46edfd4 , 0x1c50ddee ] )
+  , Mont::unsafe_make( [ 0x04f81ffe , 0x944e571c , 0xd08ddbd3 , 0xc1bfa7f8 , 0xbf7efd47 , 0x000bfe34 , 0xbec2ad64 , 0x15dbb70b ] )
+  , Mont::unsafe_make( [ 0x48518332 , 0x62a5b27b , 0xe46bdec1 , 0x194345f5 , 0x5290de74 , 0x04009855 , 0x065ddb8e , 0x138ed9cf ] )
+  , Mont::unsafe_make( [ 0x69e83d7f , 0x0725f1d9 , 0x46d6a9f2 , 0x166f95b9 , 0x10b75bf1 , 0x6d1a782e , 0x535f86db , 0x0844c6ed ] )
+  , Mont::unsafe_make( [ 0x7c553289 , 0x28d7ac6d , 0x0f72ef75 , 0x30d98bf0 , 0x5c14997c , 0xb8deb3bc , 0xf4f6c709 , 0x2a2ce76a ] )
+  , Mont::unsafe_make( [ 0x5b2dd39f , 0x65a5ac34 , 0x0ecdeb5d , 0x79c556b1 , 0xc8f74543 , 0x579a17e2 , 0x4b329dc3 , 0x019c1417 ] )
+  , Mont::unsafe_make( [ 0xd8850e22 , 0xc89ecb65 , 0xb23658ac , 0xb275e365 , 0x05f40816 , 0x80b1c971 , 0xd896ac27 , 0x01f87610 ] )
+  , Mont::unsafe_make( [ 0xc3a304cb , 0xfe01b9e8 , 0x3c2962ca , 0x1135e268 , 0xde49c738 , 0x8c56b387 , 0xc9a8948d , 0x1d00d891 ] )
+  , Mont::unsafe_make( [ 0x27a2d1c3 , 0xed1a1629 , 0x3a5cf65f , 0x88b412ed , 0x6f7d5fe2 , 0xad55e3b2 , 0xb56b4e2a , 0x2ca65747 ] )
+  , Mont::unsafe_make( [ 0x02471990 , 0xf8646570 , 0x5e48659b , 0xe1d6e0ee , 0x99f23320 , 0x911f45eb , 0x15a604cb , 0x2a74ca55 ] )
+  , Mont::unsafe_make( [ 0xfa70e12b , 0xe1a1036a , 0xc862f3a9 , 0xdfadae31 , 0x451bf139 , 0x1f2415a4 , 0xb8501513 , 0x2677ab1e ] )
+  , Mont::unsafe_make( [ 0xeb7ac1dc , 0x1770e729 , 0x71e002df , 0x4a83a8b5 , 0xf8efb0c6 , 0x487ee3db , 0xce7467a3 , 0x14b49536 ] )
+  , Mont::unsafe_make( [ 0x535bb010 , 0x482f65a0 , 0xdf007bb8 , 0xbb0f7586 , 0xd0f7464a , 0x48ed63c8 , 0xb1d0dbec , 0x1a466b5b ] )
+  , Mont::unsafe_make( [ 0x1d8a2725 , 0xc1d16be7 , 0xdf1736f1 , 0x81da3770 , 0x683f79f3 , 0x41de5184 , 0x4afe07e7 , 0x28222035 ] )
+  , Mont::unsafe_make( [ 0xb34255bb , 0xba99f9a2 , 0x4232b114 , 0xb4aafcd7 , 0x27b2104a , 0x8bcf3807 , 0xa529facd , 0x0c043545 ] )
+  , Mont::unsafe_make( [ 0x03258447 , 0x2ddbf21c , 0x587957ac , 0x92e09dd1 , 0x16930662 , 0x2736f42e , 0x2e10b3c4 , 0x0a72b7fd ] )
+  , Mont::unsafe_make( [ 0x48036f62 , 0xa642e1f2 , 0x0725549d , 0xc7359558 , 0x6302841d , 0x18c89821 , 0x63c372dd , 0x2df6248a ] )
+  , Mont::unsafe_make( [ 0xd14330d4 , 0x1f228c65 , 0xcf77b44f , 0xd8bca5dd , 0x98480a0a , 0xa3bb909c , 0xd2f39636 , 0x26cc1c38 ] )
+  , Mont::unsafe_make( [ 0x32471598 , 0xfcda0198 , 0xf439e716 , 0xc4eeae5a , 0xd316070b , 0xd15ab150 , 0x22785238 , 0x0097ffc6 ] )
+  , Mont::unsafe_make( [ 0x9ef58053 , 0x925b6b64 , 0x2d00989c , 0x3e558781 , 0x2a853efd , 0x7ba9c416 , 0x7a8a4bb9 , 0x2c57a08d ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xb59eacf2 , 0xdf2f6025 , 0x79749a97 , 0x80427da7 , 0xe6360870 , 0xc9fabdf8 , 0x86489f84 , 0x00290cf0 ] )
+  , Mont::unsafe_make( [ 0xa0a0adc4 , 0x83f1f072 , 0x55191b6e , 0x267c08a7 , 0xd233abf1 , 0xd7885673 , 0xb9090923 , 0x174d4d39 ] )
+  , Mont::unsafe_make( [ 0xc252781f , 0x044ab09b , 0xf70f8b5e , 0xe553aeb1 , 0xfc6286ce , 0x16d4c92f , 0xdae026c4 , 0x2649f977 ] )
+  , Mont::unsafe_make( [ 0x2f463dde , 0xe7e719c9 , 0xa0949dc9 , 0x2e71103c , 0xdf061fc5 , 0x7e8735c7 , 0x3a9e9ada , 0x2a0439df ] )
+  , Mont::unsafe_make( [ 0x34080f99 , 0x9a7131cf , 0x2956f6bf , 0xe24c2758 , 0x21d774c0 , 0x205d4107 , 0x78bbd7dd , 0x0e381f70 ] )
+  , Mont::unsafe_make( [ 0x5db7a561 , 0x35b6ca48 , 0x35e742e7 , 0x40060c0d , 0xa7ac819a , 0x91632e46 , 0x21f77f13 , 0x0867da78 ] )
+  , Mont::unsafe_make( [ 0x864a4f9c , 0x208a2a60 , 0x4eb1a40f , 0xe30dff4a , 0xec623d0e , 0xe778a6d6 , 0x77672299 , 0x0cb30621 ] )
+  , Mont::unsafe_make( [ 0xeb7ae0d8 , 0x282f35a8 , 0xb4264d6d , 0x4c2802d8 , 0x97d2f417 , 0xcd15a0d7 , 0xaa612b45 , 0x2ffd6187 ] )
+  , Mont::unsafe_make( [ 0xc0702fa7 , 0xa07fba60 , 0x0b74d5ba , 0x2aca942c , 0x341aeaec , 0xf4ae5274 , 0xc95e10ad , 0x1236e91a ] )
+  , Mont::unsafe_make( [ 0x7b759aea , 0xa05405fa , 0xafa5a8bc , 0xb2745a4c , 0x34331e45 , 0x737c1136 , 0x3497c129 , 0x16bac510 ] )
+  , Mont::unsafe_make( [ 0x09bd35f4 , 0x4c98a63b , 0x75f084ee , 0x9bbdd739 , 0xee258016 , 0x726def95 , 0xe97f528f , 0x0abecd5a ] )
+  , Mont::unsafe_make( [ 0x08725347 , 0xeeafe95f , 0x8f1f2bdb , 0xfaf9aa88 , 0x04cfa660 , 0xecca85fb , 0xe9626a51 , 0x057be0f2 ] )
+  , Mont::unsafe_make( [ 0x879d8e99 , 0x9d4dd36b , 0x73a5d8c1 , 0xaba63c3d , 0x4951db56 , 0xbb210afd , 0x18eeccc2 , 0x2bebf00a ] )
+  , Mont::unsafe_make( [ 0x058f6c9f , 0xe1d53605 , 0x67ba5b5d , 0xaf3b1951 , 0x981af958 , 0xf26d9fcf , 0x12c0eb41 , 0x1891e162 ] )
+  , Mont::unsafe_make( [ 0x08a05882 , 0x96b155c4 , 0x36375237 , 0xa72ab8ec , 0xf640ce93 , 0x98f2bdc9 , 0xade6869c , 0x1b0d0b2a ] )
+  , Mont::unsafe_make( [ 0x8bd5dc11 , 0x27dcb251 , 0xc893de10 , 0x946b3c0d , 0xbaef82bc , 0xa2046133 , 0x99820de2 , 0x099512f5 ] )
+  , Mont::unsafe_make( [ 0xa4c91b5b , 0xa4ceb404 , 0x0847593d , 0x0a72ed6d , 0x7ccba4a5 , 0x4ddadc73 , 0xfd1fc5ed , 0x2e8c9559 ] )
+  , Mont::unsafe_make( [ 0x09b5d5f3 , 0x34679bc6 , 0x5f8027b1 , 0x7e295592 , 0x6debae33 , 0x7cfb4024 , 0x14123996 , 0x043e975b ] )
+  , Mont::unsafe_make( [ 0xc5415016 , 0x080c53cc , 0x394cb7d1 , 0x75dcbdb2 , 0x12c781c8 , 0xefff52c9 , 0x6610c2ce , 0x061c644c ] )
+  , Mont::unsafe_make( [ 0x6d6846e2 , 0x0f38dcae , 0xcab4fbf3 , 0xe97f7181 , 0xeecf9b3f , 0xb662e60f , 0x140540c9 , 0x1b0cf0eb ] )
+  , Mont::unsafe_make( [ 0x2beb8b52 , 0x6311bb3a , 0xf2f816f2 , 0x0b0f20ab , 0x7cb091cc , 0x430b1a30 , 0x5338c092 , 0x057cc8e3 ] )
+  , Mont::unsafe_make( [ 0x8343653c , 0x3fe14336 , 0x34bfe0ae , 0x3c3e8ef2 , 0x1b76855b , 0xf40f33dc , 0x807f1102 , 0x03b31c57 ] )
+  , Mont::unsafe_make( [ 0xcf9c325b , 0x7b3cda78 , 0xdc1f63fd , 0x61eb468a , 0x9e70bf99 , 0x6077fc0c , 0x35ddeb18 , 0x1dcab2fe ] )
+  , Mont::unsafe_make( [ 0xb61245b8 , 0x382f3563 , 0xce10e4cf , 0x64706026 , 0x566a65eb , 0x179fab25 , 0x176c64c3 , 0x1f533ac5 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x1f731998 , 0x1a2bf7be , 0xc236a48d , 0x6ea73e1d , 0xa780e160 , 0xe153b8e8 , 0xd448d221 , 0x12b01907 ] )
+  , Mont::unsafe_make( [ 0x6e70a687 , 0xb8a2637e , 0xd3174728 , 0x93fd30ed , 0x554d0378 , 0x4de4abe8 , 0x626a40a5 , 0x188edc26 ] )
+  , Mont::unsafe_make( [ 0x0968a2c8 , 0x8aca88e2 , 0x7f8b7ba0 , 0x45ce10a3 , 0x59d450b0 , 0xe9ac2ebd , 0x2a3ae048 , 0x21d1ec93 ] )
+  , Mont::unsafe_make( [ 0x6d6be29e , 0x54c4b45c , 0x82a9b450 , 0x313e81e0 , 0x625cca92 , 0x51c6dcb9 , 0x2c44389a , 0x03773fd6 ] )
+  , Mont::unsafe_make( [ 0x94b3fc53 , 0x1fb8bfe6 , 0x273dd927 , 0xd75e8825 , 0x5b0d87f8 , 0xa4610290 , 0x5e9ee433 , 0x2a0afcb6 ] )
+  , Mont::unsafe_make( [ 0x7909a946 , 0x459df651 , 0xf3698d16 , 0x115ea0f8 , 0x334c1b09 , 0xe1cbf665 , 0x0c4fc7d3 , 0x2a4b0628 ] )
+  , Mont::unsafe_make( [ 0x2fcab3af , 0x819e412f , 0x87476b97 , 0xbb4736d1 , 0xdcfa97a4 , 0x1a8be38f , 0x8b2c2307 , 0x0ea68934 ] )
+  , Mont::unsafe_make( [ 0xd704d57f , 0x312f4441 , 0xbc98b336 , 0x6ed04741 , 0xaab1bf26 , 0xde60b8d4 , 0xb84d7336 , 0x09102e6d ] )
+  , Mont::unsafe_make( [ 0x58d7a590 , 0xb8eb1960 , 0xf41fddf9 , 0x2ab3c6f0 , 0x59d5ee46 , 0xbbea2c4c , 0x94cd7f6b , 0x2ebc2078 ] )
+  , Mont::unsafe_make( [ 0x17e51334 , 0x02cec092 , 0xe47d4bf9 , 0xdf87a7e2 , 0x5bbce7a1 , 0x976e805e , 0x01e5dbdb , 0x0730781e ] )
+  , Mont::unsafe_make( [ 0x18a8563b , 0x9008f7f4 , 0xead59212 , 0xba8994e7 , 0x8427ab7b , 0xb210edd3 , 0xe6bb2b43 , 0x1dea8451 ] )
+  , Mont::unsafe_make( [ 0x7a0710bd , 0x07153794 , 0x4990cab4 , 0x6b80b172 , 0xe98ca4b0 , 0xd2eb6ac6 , 0xf8f709e9 , 0x1da53c59 ] )
+  , Mont::unsafe_make( [ 0x55967565 , 0x25868248 , 0x85f0ec6e , 0x25ee56eb , 0xa69e01a8 , 0xc42f1ce6 , 0xb09ea003 , 0x2664a8e4 ] )
+  , Mont::unsafe_make( [ 0x5691b7bf , 0x07cfeb8b , 0xd823e13c , 0x428e0021 , 0x3fd20095 , 0x7bcc1d60 , 0xa7831336 , 0x23d07a86 ] )
+  , Mont::unsafe_make( [ 0xad0a2843 , 0xf8723cab , 0x9b902f91 , 0x995c24c1 , 0x989ab7e5 , 0x8ad8be15 , 0x67a41a3e , 0x1384eb6c ] )
+  , Mont::unsafe_make( [ 0xc9368762 , 0x133f958f , 0x01c4012d , 0x07efa903 , 0x8f17e004 , 0xcf0aed50 , 0x23e1f761 , 0x2a1de0d2 ] )
+  , Mont::unsafe_make( [ 0x01938c51 , 0x77d65ece , 0x3beca9a3 , 0xb8c5417e , 0xf4f85401 , 0x8a2d40ed , 0xe68200f7 , 0x229bdc8e ] )
+  , Mont::unsafe_make( [ 0x48d6efb3 , 0x5d5ae5b2 , 0x6a260031 , 0x697f2374 , 0x9db67c65 , 0x4088802e , 0x8d8f6d10 , 0x1a7423f2 ] )
+  , Mont::unsafe_make( [ 0xb2e288bd , 0x115771d2 , 0xc539b896 , 0x6b7e8852 , 0x3ace1a01 , 0x7f1c7cd1 , 0x952698a5 , 0x12353979 ] )
+  , Mont::unsafe_make( [ 0x3740b428 , 0x814c91ea , 0x580f03fe , 0xd2477720 , 0x49195626 , 0x1a8e9b10 , 0xd746203d , 0x288e2e37 ] )
+  , Mont::unsafe_make( [ 0xc33b1e39 , 0xacc30c19 , 0x1720d304 , 0x7e7c0b31 , 0x9d29453f , 0x0f7b96a1 , 0x2c6d81a9 , 0x0f420a1d ] )
+  , Mont::unsafe_make( [ 0x79760a74 , 0x2b287dc6 , 0xb65942e1 , 0x9d454d3f , 0x3af35ec8 , 0xcee22762 , 0x2e60f4e0 , 0x296d6959 ] )
+  , Mont::unsafe_make( [ 0x4eb4a0d7 , 0xda274519 , 0x8e27716a , 0x7aa664fa , 0xf809e105 , 0xf99666e2 , 0x8c7a27b0 , 0x0f642f2d ] )
+  , Mont::unsafe_make( [ 0x6c7f4847 , 0xbca03084 , 0x4e3950bd , 0xa75f51a1 , 0x98ed583e , 0xe1412db2 , 0xa0c3c14c , 0x179cdbd7 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xc12f670c , 0x4e4d0c1c , 0x4195a174 , 0xa24d2c2e , 0xa6213330 , 0x304d191a , 0x08bbd9e9 , 0x190ca789 ] )
+  , Mont::unsafe_make( [ 0x765be642 , 0x709471b8 , 0x4490dfff , 0x78296412 , 0x247f2e3a , 0x6190b72f , 0x0e07d913 , 0x0d7433f0 ] )
+  , Mont::unsafe_make( [ 0x0f0b5dc1 , 0x4f60eafc , 0xfad389d3 , 0x55ea8938 , 0x7a9b0cda , 0x86e87584 , 0x85eb1bc8 , 0x075d1012 ] )
+  , Mont::unsafe_make( [ 0x19e502c5 , 0x006be9f1 , 0x04685a0c , 0x9984a3dc , 0x666dd6db , 0xa47f667f , 0xf14a2f91 , 0x02670045 ] )
+  , Mont::unsafe_make( [ 0x62571af0 , 0x17134fdc , 0xe3a4aba5 , 0x47301b41 , 0xb65f681a , 0x549199d7 , 0x20d492dc , 0x05573d9f ] )
+  , Mont::unsafe_make( [ 0xf1e3a87b , 0xd33a1ca6 , 0x68e7827f , 0x872bfe60 , 0xfe7b5657 , 0x2e241a3e , 0x23c2438d , 0x2fc99824 ] )
+  , Mont::unsafe_make( [ 0x086d80b0 , 0x4d97dc08 , 0xdf90b20f , 0x4f5db190 , 0x6a96590e , 0xd339f438 , 0xaeb9ca06 , 0x2ce0f37e ] )
+  , Mont::unsafe_make( [ 0x3b8c1b10 , 0xad7e5e2c , 0xa08d8838 , 0x89629fc8 , 0xd2b79ec3 , 0xa04cd8db , 0x344edd37 , 0x12372dd7 ] )
+  , Mont::unsafe_make( [ 0x64f114af , 0xd4c76e3c , 0xb2f5ba0b , 0xb725351b , 0x1f861df7 , 0x4adb4ad5 , 0x6ba88374 , 0x1dfdaac8 ] )
+  , Mont::unsafe_make( [ 0xceef985f , 0x03611d91 , 0x367d2f8a , 0xd3401d06 , 0x183e3245 , 0xdcc7c5c2 , 0x58596a8e , 0x1bb1ce78 ] )
+  , Mont::unsafe_make( [ 0x7f371468 , 0x15e9b989 , 0x656e01eb , 0xefc95036 , 0x394a4500 , 0xa8e5086c , 0xf2eb3648 , 0x1919e96a ] )
+  , Mont::unsafe_make( [ 0x935b5385 , 0xc4815096 , 0xd8ec88cc , 0x6cb74bf6 , 0x35db5b90 , 0x884fecf4 , 0x0fc4bf00 , 0x2af6483d ] )
+  , Mont::unsafe_make( [ 0x60d0ce77 , 0x030fee67 , 0x37a0cdde , 0xe794ae9f , 0x52a08f53 , 0xe9f9c2ee , 0x87c7f9ac , 0x12d9f5c1 ] )
+  , Mont::unsafe_make( [ 0x09c2c985 , 0xcabc8f56 , 0xad6da590 , 0x5c5b43dd , 0xeb22ec45 , 0x71690136 , 0x153750ef , 0x0aad0a23 ] )
+  , Mont::unsafe_make( [ 0xe24aaa52 , 0x3707b959 , 0xda614b80 , 0xa8a7332a , 0xb266c517 , 0xd7900a81 , 0x4f06933c , 0x15c763eb ] )
+  , Mont::unsafe_make( [ 0x12df5b6a , 0x44cecbee , 0xdb6cf693 , 0xa7a0c654 , 0x92107431 , 0xf5114ba6 , 0xec661a42 , 0x089c2ed1 ] )
+  , Mont::unsafe_make( [ 0x6837e259 , 0xcda9b8a6 , 0x41d05b53 , 0xb78b0876 , 0xe0526218 , 0xeb352d72 , 0x1f421042 , 0x1acb6693 ] )
+  , Mont::unsafe_make( [ 0x307077d5 , 0x2e3c4e93 , 0xd25e4422 , 0xa37ebec0 , 0x1f97fba2 , 0x437329b8 , 0xf2e926d5 , 0x1a416ed8 ] )
+  , Mont::unsafe_make( [ 0xe8abd744 , 0x8755daaf , 0x410ffe48 , 0x0f2dcaf0 , 0x9d82d6c3 , 0x622d2a13 , 0xd988fddd , 0x19eb34c0 ] )
+  , Mont::unsafe_make( [ 0x902fa760 , 0x637afba0 , 0xf186c9b0 , 0x6445a840 , 0xdcc08927 , 0xa7fb5177 , 0xee8f5344 , 0x068b7e9b ] )
+  , Mont::unsafe_make( [ 0x55923ac6 , 0xf8ffe385 , 0x29af152f , 0x78447e7e , 0x1efd4d7f , 0xf9d0d28c , 0x33f1fa6a , 0x2c999bd5 ] )
+  , Mont::unsafe_make( [ 0x327f46bb , 0x2055d950 , 0x4689d7f3 , 0x87b06837 , 0x4b0f5c45 , 0x7a2770f6 , 0x8142999a , 0x0d4f766c ] )
+  , Mont::unsafe_make( [ 0xbd64983d , 0x5530094c , 0x78a28620 , 0xaedc5758 , 0xe995bf65 , 0x45e65b17 , 0xd1e828c6 , 0x181e71cb ] )
+  , Mont::unsafe_make( [ 0x8a53f0ab , 0x3b729623 , 0x795f7d52 , 0x46e293de , 0x5ee558a9 , 0xdb015e24 , 0xb1c92346 , 0x19806308 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xc8f70485 , 0x1b6f1900 , 0xddca18b4 , 0xdec8927e , 0xcf81f306 , 0xd68ebce2 , 0x656be168 , 0x2dde0ce2 ] )
+  , Mont::unsafe_make( [ 0x03c4ac3a , 0x0f05e865 , 0xde3de83c , 0x2c037b4e , 0xb17556ed , 0xa1ba240a , 0x933278a7 , 0x16a07b9e ] )
+  , Mont::unsafe_make( [ 0xf7f0086f , 0xdf7f9e86 , 0xaecb5339 , 0x84327e2e , 0xcd945878 , 0x207a56e1 , 0x7ad35da9 , 0x057a27d3 ] )
+  , Mont::unsafe_make( [ 0xe78eee92 , 0xa1f46e8c , 0x466de9ba , 0x7e14eb4f , 0xa5bf4b13 , 0x485e2fe6 , 0x7c62fc3f , 0x06d3a6c5 ] )
+  , Mont::unsafe_make( [ 0x199cf301 , 0x49d2ed69 , 0x2ad52af1 , 0x63d82321 , 0x7dac97d4 , 0x3da483a1 , 0x2ee1532e , 0x1600bfa8 ] )
+  , Mont::unsafe_make( [ 0xa766e2c4 , 0xef4084d5 , 0xb08e5901 , 0x6c9781d3 , 0x2d7517dc , 0x04dfc7bd , 0x52c59423 , 0x198a6f5d ] )
+  , Mont::unsafe_make( [ 0xcbd0c39d , 0x750dcb01 , 0xd77128c9 , 0x531727dd , 0x4480527e , 0x4e8bf639 , 0xf576b252 , 0x05fcfd42 ] )
+  , Mont::unsafe_make( [ 0x8bdc9d60 , 0x4ad83af4 , 0x806d0c74 , 0x5b6ef83a , 0x816cc145 , 0x0eb68532 , 0xd9de88a8 , 0x03d68c3c ] )
+  , Mont::unsafe_make( [ 0x2cbc5127 , 0x22a2d78c , 0x7cff6bb6 , 0x6400539d , 0x708c17a4 , 0x31f82c2d , 0x94057147 , 0x15a0f931 ] )
+  , Mont::unsafe_make( [ 0x61254952 , 0x5a456e74 , 0x28de8077 , 0xd9b17c9a , 0x5dc5fcec , 0xe8f41bde , 0x7e5b2ba7 , 0x2676fb60 ] )
+  , Mont::unsafe_make( [ 0x7f00bb47 , 0x51fa82e5 , 0xbd8e4330 , 0x8283b3de , 0x3ca23ea8 , 0x771a9cfa , 0x3dd9dff3 , 0x1cb51d84 ] )
+  , Mont::unsafe_make( [ 0xedcbeae4 , 0x4e80dfa4 , 0x11fde5cd , 0x82579040 , 0x7ab874c8 , 0xd6248284 , 0x0e66f208 , 0x2637ba3c ] )
+  , Mont::unsafe_make( [ 0x00ce478f , 0x26eb4c5c , 0x7bd52a4c , 0xc760fd20 , 0x435f2a38 , 0x30e013f3 , 0x108449e5 , 0x2bff5ad6 ] )
+  , Mont::unsafe_make( [ 0x5a8714d0 , 0x7a74df18 , 0x143fa74f , 0xb8493871 , 0x29da1fd8 , 0x6667e4fb , 0xf3d94f18 , 0x0c1d8280 ] )
+  , Mont::unsafe_make( [ 0x2a7c63bf , 0xe13bbb0e , 0x36b827cb , 0xdf2bc7ca , 0xf73a67c1 , 0xe7cc866e , 0x3b909fa5 , 0x23944ec4 ] )
+  , Mont::unsafe_make( [ 0x507cba4f , 0x14808db0 , 0x229b55d5 , 0x874a4ddf , 0xaddd257f , 0xfc2c06ab , 0xdc5bd750 , 0x2bc138f4 ] )
+  , Mont::unsafe_make( [ 0x1bb0733f , 0xdb593eae , 0x39e1af67 , 0xbd8273f2 , 0xfd050b29 , 0x9546252f , 0xd87c723b , 0x21db5b99 ] )
+  , Mont::unsafe_make( [ 0xbd5e0b67 , 0x3ec6e74d , 0x27b92462 , 0xc1590dc7 , 0x2eb9c44b , 0x2098675e , 0xf3d5fcd2 , 0x1c53ad08 ] )
+  , Mont::unsafe_make( [ 0xe85bcd41 , 0x9662a309 , 0x419d5d9b , 0x992d6267 , 0x0357b77c , 0x82d173e8 , 0x422c35f9 , 0x2a5d65dc ] )
+  , Mont::unsafe_make( [ 0x78f422b8 , 0x316dc99e , 0x9dadcd11 , 0xe26f3ba9 , 0xc2f2f450 , 0x33f7d123 , 0x875b76ca , 0x08e3f9a1 ] )
+  , Mont::unsafe_make( [ 0xb0ce4ecb , 0x8c51303b , 0xae98512c , 0x27046410 , 0xd37114fa , 0xec4ee9f9 , 0x0b5e8cb4 , 0x2354387a ] )
+  , Mont::unsafe_make( [ 0x4b7c61e6 , 0xb8e663cd , 0x7e7e615e , 0xf92a670c , 0x8d391048 , 0xddb295e3 , 0x65cb80b9 , 0x1306cdd4 ] )
+  , Mont::unsafe_make( [ 0x348db1c6 , 0x734055dc , 0xa7d52ce3 , 0x9317de27 , 0xecc5c181 , 0x0627d414 , 0x89e6415a , 0x1cfc04c2 ] )
+  , Mont::unsafe_make( [ 0x8f6f5c8e , 0x226d481c , 0x1169632c , 0x7c66498c , 0xe7d38bd5 , 0x150f6c3f , 0x35db7a51 , 0x17451b08 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x2463f8a3 , 0xbb83fd57 , 0xbcef863b , 0x932e6909 , 0x4820c357 , 0x09cdc30d , 0xbc7442a5 , 0x2a4425ab ] )
+  , Mont::unsafe_make( [ 0x2ad2bd0c , 0x667945e3 , 0xd5b8cde6 , 0x72f9d743 , 0x65871d48 , 0x6faa7fde , 0x2fb1fd0b , 0x15b5d3d1 ] )
+  , Mont::unsafe_make( [ 0xacdef32d , 0x9381f833 , 0x3c634eec , 0xca92a5fe , 0x1d08619b , 0xc031066e , 0x202c3db4 , 0x033d8374 ] )
+  , Mont::unsafe_make( [ 0x1abbe12b , 0x0d588e05 , 0xa5493afb , 0x7db021ef , 0xd91732e6 , 0xb4681cc7 , 0xedaa6488 , 0x0dea8c68 ] )
+  , Mont::unsafe_make( [ 0x6170b005 , 0xbce72a06 , 0xbbb6715d , 0x46d2f8c8 , 0xe7383f09 , 0x23a779e7 , 0xb2836d3e , 0x29f4034d ] )
+  , Mont::unsafe_make( [ 0x8beb2724 , 0x40d09d04 , 0x6480d10b , 0xe8ac879b , 0x50ad1e5d , 0x9be6d0f0 , 0x0b99547a , 0x2c2b2a20 ] )
+  , Mont::unsafe_make( [ 0x08559093 , 0x8de9c703 , 0x31142f2a , 0x089f618a , 0x416aa90f , 0x92cd563e , 0xf6e30372 , 0x1f629429 ] )
+  , Mont::unsafe_make( [ 0xe4b36770 , 0xaeca77c9 , 0xddb64869 , 0xb775caf7 , 0xde6e5411 , 0x2a916e89 , 0xeab2db7b , 0x0dae9e28 ] )
+  , Mont::unsafe_make( [ 0x8cd95bff , 0x94f4cf6f , 0x23b6dbe8 , 0xb54dd805 , 0x9f9707aa , 0x82ddb803 , 0xee7843e2 , 0x0c54520a ] )
+  , Mont::unsafe_make( [ 0xbf9591f4 , 0x5bfb09c1 , 0xe0318d4c , 0x7d08492e , 0x4e9c3ba4 , 0x3ed095e8 , 0xb605dae6 , 0x1d5e90d7 ] )
+  , Mont::unsafe_make( [ 0xcb0c7441 , 0x0fcfb13a , 0x64cd789f , 0xd7a25ee4 , 0x315bd6f7 , 0xcb315386 , 0x432ff710 , 0x2791ebf8 ] )
+  , Mont::unsafe_make( [ 0x51837bb8 , 0x0e4c1e79 , 0xc2464518 , 0x06b6fe82 , 0x6ab0e5f5 , 0x4c9546e8 , 0x055cc531 , 0x03f03d8f ] )
+  , Mont::unsafe_make( [ 0x841e939f , 0xa1fe7646 , 0xabbeb221 , 0x8302c32d , 0xb86adcf2 , 0x913ff8e0 , 0xfc932ea6 , 0x2034053c ] )
+  , Mont::unsafe_make( [ 0x9ca761e0 , 0x524dae73 , 0xa150e984 , 0xefded1f4 , 0xa8c0653b , 0xb7e3b772 , 0xd56293c1 , 0x2f181126 ] )
+  , Mont::unsafe_make( [ 0xdb9b916d , 0x0ff127e6 , 0x6eeb724d , 0x38586527 , 0xadfe9da5 , 0xae925ae5 , 0xb44f1fcb , 0x1ed6b7d7 ] )
+  , Mont::unsafe_make( [ 0xdc4818b9 , 0x426a3ef6 , 0xd13a3787 , 0x2434004e , 0x0407469b , 0x0bb035f0 , 0x6007efcb , 0x0cc03b0f ] )
+  , Mont::unsafe_make( [ 0x1c9ad132 , 0x8518b068 , 0x196e9ff6 , 0x5def9f15 , 0x2d4fd629 , 0x09b240f9 , 0xc408bd6f , 0x2a8918b4 ] )
+  , Mont::unsafe_make( [ 0x61201cfb , 0x41b1a6da , 0xa95cc1d6 , 0x0c18d6e8 , 0x5e880157 , 0x7af5ec38 , 0x7d93ba4d , 0x17ddeb09 ] )
+  , Mont::unsafe_make( [ 0xe5082068 , 0xb62f9aad , 0x58eea4eb , 0xbb90e8fe , 0xde265880 , 0xd4cf750d , 0x8e881736 , 0x2743c98d ] )
+  , Mont::unsafe_make( [ 0x467854b6 , 0x6f290481 , 0x6a316570 , 0x8d7bc8e6 , 0x23666fa0 , 0xec636c73 , 0x9a5a7f5e , 0x1425a7a9 ] )
+  , Mont::unsafe_make( [ 0x8aa938e8 , 0x5df5b403 , 0x714d1f5d , 0x3b38cd3b , 0x1d2d6609 , 0x152e7fae , 0x17841217 , 0x302d0a64 ] )
+  , Mont::unsafe_make( [ 0xac7f2b5d , 0xd8da29a9 , 0xc13bbdac , 0x2fca5495 , 0x92522668 , 0x3c5dd28e , 0x5e0230e3 , 0x19861994 ] )
+  , Mont::unsafe_make( [ 0x36d3134e , 0x6bed6e67 , 0x5552e2c8 , 0xb4c69495 , 0x2bd1996d , 0x2e5906b5 , 0xa86a6085 , 0x0e1c227e ] )
+  , Mont::unsafe_make( [ 0x4522454b , 0x59b0d0a3 , 0x1d2491e8 , 0xa77c55ec , 0xf11e8c46 , 0xf58850a2 , 0x10c5c537 , 0x20937f5f ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xadc0e893 , 0x3bb5e890 , 0x83653d29 , 0x07af828d , 0x3a257480 , 0xd45dd815 , 0x85b34994 , 0x030071d1 ] )
+  , Mont::unsafe_make( [ 0xe1ea0986 , 0xbd98c250 , 0x1888541f , 0x1ee6a103 , 0xccaed57e , 0x9f5ebf04 , 0x465878a6 , 0x170a18e7 ] )
+  , Mont::unsafe_make( [ 0x695f1970 , 0x42ed3867 , 0x07e64e95 , 0x982dabba , 0xfe69dd87 , 0x8fa375ec , 0xa58a9147 , 0x16636941 ] )
+  , Mont::unsafe_make( [ 0x9861d1ac , 0x01a3b01c , 0x7c0e246a , 0xa3735c0b , 0xf089f439 , 0x0e3a86b0 , 0x96616a62 , 0x02f399fc ] )
+  , Mont::unsafe_make( [ 0xe500b779 , 0x4b2654dc , 0xd57dfe1c , 0x3f0cdf4f , 0x50b3238e , 0x701f85f1 , 0xeafc109a , 0x0eecf320 ] )
+  , Mont::unsafe_make( [ 0x44861082 , 0x379bddab , 0x579a619b , 0x3a1df3d1 , 0x9229e051 , 0xe98cf10e , 0xf50e1c0e , 0x03cf2b61 ] )
+  , Mont::unsafe_make( [ 0xfacda80e , 0x69eb7909 , 0x3655b0d6 , 0x8a97eaeb , 0xe0ff4832 , 0xbf35870b , 0xb212e93c , 0x2de9aa31 ] )
+  , Mont::unsafe_make( [ 0x1583a6c1 , 0x5e305f81 , 0xd320c3f7 , 0xc01bb8f1 , 0x550a2f39 , 0x03e788c3 , 0x032a49aa , 0x1f883eb9 ] )
+  , Mont::unsafe_make( [ 0x5031624d , 0xde25e283 , 0xf18ab53a , 0x095a78bd , 0xd5fc124e , 0x5df52537 , 0x7de058e0 , 0x16cb24af ] )
+  , Mont::unsafe_make( [ 0x51f15dfd , 0xca641ca9 , 0x1e2586e3 , 0x5cadd0f0 , 0xf9d2f8c3 , 0x2cf76828 , 0xc4d19fce , 0x161bbfce ] )
+  , Mont::unsafe_make( [ 0x6eeb088c , 0x9f6f82eb , 0x2c437e60 , 0x3bca2a19 , 0x3adb4c31 , 0xb9b65a9e , 0xa99adc92 , 0x0c954829 ] )
+  , Mont::unsafe_make( [ 0xc4f404a0 , 0xa5149809 , 0x72ee385c , 0x1572bd1b , 0x224a175d , 0x95f0984a , 0x9a465ff7 , 0x065cb2f9 ] )
+  , Mont::unsafe_make( [ 0x4901016d , 0xbdfa42d7 , 0x2a12b179 , 0x57bf1c0b , 0x51d4c18a , 0x339829b5 , 0x4b1276b1 , 0x13facb70 ] )
+  , Mont::unsafe_make( [ 0x2e12ffba , 0xa1d502c9 , 0x3d0df88e , 0x3754aabf , 0x1db0fc0d , 0xe1f10045 , 0x3123241a , 0x0c3dff8f ] )
+  , Mont::unsafe_make( [ 0x828054de , 0x52029259 , 0xe4b54400 , 0xe53efad5 , 0x51ea283d , 0x05a687e7 , 0x712201ec , 0x25546dd6 ] )
+  , Mont::unsafe_make( [ 0x2755771c , 0x6ba858c1 , 0x02264a67 , 0x455dbf0b , 0x57b19e09 , 0xfbecd933 , 0x3c841fed , 0x21314c7f ] )
+  , Mont::unsafe_make( [ 0x9cdadd81 , 0xd022bc99 , 0x649854ff , 0xdb638978 , 0x598d9579 , 0x122f4313 , 0x1b0d93e3 , 0x2c1b63c1 ] )
+  , Mont::unsafe_make( [ 0xd2324b13 , 0xc825cf27 , 0x7df8deb1 , 0x78191867 , 0x081d2b5c , 0x1201e982 , 0xc3ff28ca , 0x16496aa8 ] )
+  , Mont::unsafe_make( [ 0xadd8ec43 , 0xc5b910b2 , 0x33ddd0ec , 0x81bd4afa , 0x60f87cfc , 0x0672cc57 , 0xf92bc358 , 0x283dd168 ] )
+  , Mont::unsafe_make( [ 0xdb74fcc5 , 0x17ae57d9 , 0x965ea237 , 0xed82ae5c , 0x8d7dfe00 , 0xfff25f0d , 0x634b9c80 , 0x11421c18 ] )
+  , Mont::unsafe_make( [ 0x3aeca2d8 , 0xa2ff17b4 , 0x53d92bdd , 0xbf525fbd , 0x8d9928e4 , 0xdc4128f2 , 0x67824e8a , 0x27854568 ] )
+  , Mont::unsafe_make( [ 0x930535a2 , 0x81f07829 , 0x8ae28457 , 0xceb2c550 , 0xf40ae655 , 0x63382de4 , 0x46637cb6 , 0x284417ad ] )
+  , Mont::unsafe_make( [ 0x4b8813fe , 0x8fd99aff , 0x2389aaab , 0x00b15dd7 , 0x63f372ad , 0xda7a968b , 0x5731df3b , 0x02ef011f ] )
+  , Mont::unsafe_make( [ 0x58a77e63 , 0xeccbf85b , 0x07032580 , 0x1223fc95 , 0x6ff0a236 , 0xb9d457d9 , 0x690c6207 , 0x16b780e3 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x5594c6d7 , 0x127545f4 , 0x0e472d08 , 0xabd7534a , 0x50d7b401 , 0x1d60dfcd , 0x2e9de506 , 0x1eeea6e1 ] )
+  , Mont::unsafe_make( [ 0x8a01475f , 0xa2dea411 , 0x793bdb36 , 0xa9f715ef , 0x14d4ecf9 , 0xdd067154 , 0x28469c0a , 0x0e828620 ] )
+  , Mont::unsafe_make( [ 0x58f90afe , 0xa27ec851 , 0xdebab335 , 0x6717d18f , 0xa96c22da , 0x4ad5a631 , 0x23036e38 , 0x18b961b1 ] )
+  , Mont::unsafe_make( [ 0x0e4f39ac , 0x091aab1d , 0xfc79ac14 , 0x38d0d843 , 0x5d8a5cbb , 0xf851c987 , 0x7c90ae9e , 0x24864d05 ] )
+  , Mont::unsafe_make( [ 0x0d9cc1c7 , 0x6de3af01 , 0x1d735d25 , 0x49b8be89 , 0x5a4d77f4 , 0xf6ede0be , 0x19395641 , 0x24f8d25c ] )
+  , Mont::unsafe_make( [ 0x0ad6ff74 , 0x8d0cd266 , 0xb143e42b , 0x0eafaca2 , 0x6752749c , 0xf67bcd9a , 0xf20b6b80 , 0x1860a563 ] )
+  , Mont::unsafe_make( [ 0x4a66fb7d , 0x56916673 , 0x8658f9d8 , 0xb762df83 , 0xd4499d4e , 0xfd0809f2 , 0xf5d420fa , 0x1b8276eb ] )
+  , Mont::unsafe_make( [ 0x907e8793 , 0xc4a9313c , 0x74d7b6b2 , 0xf1c38da9 , 0x97329b93 , 0x68800733 , 0x5d139040 , 0x054a424f ] )
+  , Mont::unsafe_make( [ 0x6771c889 , 0x79af623b , 0xf70be353 , 0xeddaf8b3 , 0xe431b93f , 0x04da4fc4 , 0xa18b12d5 , 0x05a8832f ] )
+  , Mont::unsafe_make( [ 0x91c6445e , 0xd6ae9192 , 0x2cb59b1d , 0xa3bc5e09 , 0x655a6365 , 0xeaacefe9 , 0x64634499 , 0x184b6ab2 ] )
+  , Mont::unsafe_make( [ 0x20a125b9 , 0x19001f77 , 0xadbe980b , 0x79ef404f , 0x798de640 , 0x3ed21b10 , 0xc1c04953 , 0x18af5814 ] )
+  , Mont::unsafe_make( [ 0xcc566ecc , 0x5a832b81 , 0xf1abb4df , 0x69efbcd1 , 0xd7dbb8a2 , 0x7253d210 , 0x72f5f190 , 0x220b4d33 ] )
+  , Mont::unsafe_make( [ 0x945b4588 , 0xb6a73c14 , 0xd767e9ec , 0x94020c40 , 0x53d13772 , 0xfb60ac34 , 0x5d3c5b8e , 0x046d1c1c ] )
+  , Mont::unsafe_make( [ 0x501e731a , 0x61b61c77 , 0x1e2ab2a9 , 0x1de878f0 , 0x0d44bdbe , 0x095938eb , 0x598b8227 , 0x11053902 ] )
+  , Mont::unsafe_make( [ 0x4e323481 , 0x826fa9ea , 0xfd67771c , 0xe3f6e621 , 0x9670dfc0 , 0xe04a896c , 0x08fedb48 , 0x255ca6f5 ] )
+  , Mont::unsafe_make( [ 0xe1265997 , 0xdf69deca , 0x03410ce3 , 0xebd57920 , 0x0d82d8c2 , 0xe08dd34a , 0x7519fefe , 0x1a33b481 ] )
+  , Mont::unsafe_make( [ 0x36f6d384 , 0x8f5c96eb , 0x91db2762 , 0xf9a2d958 , 0x0f0ccd77 , 0xa5ba6325 , 0x4f683185 , 0x2516465d ] )
+  , Mont::unsafe_make( [ 0x18ecee5a , 0x86373bad , 0xe86db651 , 0x4ae026e2 , 0x1f974fb3 , 0xc9da8e72 , 0xc637f896 , 0x1998f877 ] )
+  , Mont::unsafe_make( [ 0x46d83d62 , 0x774d449d , 0x30f9a434 , 0x18961a9b , 0x90d5fcbc , 0x25fc55bb , 0xfc25f940 , 0x22219231 ] )
+  , Mont::unsafe_make( [ 0x3f2c151f , 0x348c0847 , 0xaad100ba , 0xf3284646 , 0x9b67bfe5 , 0x406df397 , 0x499a9b22 , 0x20b77c77 ] )
+  , Mont::unsafe_make( [ 0x35c1143e , 0xb18043b8 , 0xf3c9a593 , 0xfb278cbe , 0xbb21fae3 , 0x396cbe79 , 0x50858461 , 0x26cb5f6e ] )
+  , Mont::unsafe_make( [ 0xbf3c7f22 , 0x20068a9e , 0xbfabffa1 , 0x99e97d81 , 0x4e98e3de , 0x02da9f7b , 0x8e4d24fd , 0x100d1284 ] )
+  , Mont::unsafe_make( [ 0x2d3bb785 , 0xcc5a3e8a , 0xc43b72ec , 0xd7af129b , 0xba69144b , 0xad1bac6c , 0x053cd441 , 0x2a71d4c2 ] )
+  , Mont::unsafe_make( [ 0x4f6bad3a , 0xa1c4e9c6 , 0x4a4c3ad2 , 0x81ae993b , 0xca5a458e , 0x9e4ec085 , 0x58f155c4 , 0x1a549079 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xeb8bc888 , 0xe647eedd , 0x54bb6425 , 0x7bf530c3 , 0xec1d3ffc , 0xefcdf42c , 0x0a823936 , 0x06c97451 ] )
+  , Mont::unsafe_make( [ 0x6dacd26b , 0x00ba5b52 , 0x33ca51bf , 0x3aeb90b9 , 0xe81d5c9f , 0x3e4adeab , 0xb267670c , 0x1f526928 ] )
+  , Mont::unsafe_make( [ 0x4c152d03 , 0xb248d29e , 0x84b6b290 , 0xeb8e029f , 0xbd53b121 , 0x0aa52401 , 0xf03bf215 , 0x1cbb4c3c ] )
+  , Mont::unsafe_make( [ 0xf49b069e , 0xe3fce35c , 0xd9f34f85 , 0xce8960e9 , 0x79139565 , 0x600777f8 , 0xfa8d701f , 0x2e34ae07 ] )
+  , Mont::unsafe_make( [ 0xe0570ea3 , 0x4682cacf , 0x0757cc77 , 0xa84532fb , 0xf9c1dd3a , 0xa8c93db3 , 0x4e5e69fc , 0x280a82e5 ] )
+  , Mont::unsafe_make( [ 0xdcfe3766 , 0x6ff863d1 , 0xff17115e , 0x36674bc8 , 0x51874ffc , 0x0d367aa9 , 0x6d8fc372 , 0x12ecb779 ] )
+  , Mont::unsafe_make( [ 0x2cf99543 , 0x565ac9cd , 0x1f3afc88 , 0x4e63d3bd , 0x73a08b0a , 0x4f2a568e , 0xa91b1edd , 0x2f4b4fe8 ] )
+  , Mont::unsafe_make( [ 0xa7acfc5c , 0x66d00a80 , 0x59f19cd2 , 0xa66e64be , 0xa68ffbd3 , 0x67ee87dc , 0x6f948df8 , 0x05334b23 ] )
+  , Mont::unsafe_make( [ 0x324e3c93 , 0xb7c50b3d , 0x482b90ff , 0x3bd29832 , 0xe870dda7 , 0x6dd78e74 , 0x27383385 , 0x15b13cec ] )
+  , Mont::unsafe_make( [ 0x10927f1d , 0x5f2ca6ec , 0xa30bbd47 , 0xba83aede , 0xb07758a7 , 0x463e1836 , 0xbfad4d83 , 0x13323264 ] )
+  , Mont::unsafe_make( [ 0x0da84b4f , 0x5e6bd695 , 0xb8a2295a , 0x17602699 , 0x050e23d7 , 0xcb9ccf11 , 0x5e712a72 , 0x0a07d218 ] )
+  , Mont::unsafe_make( [ 0x62b6fb85 , 0xdfe9e934 , 0xee090c02 , 0x35f57a16 , 0x17a45db5 , 0x4ce22197 , 0x870cfc72 , 0x2ea577f4 ] )
+  , Mont::unsafe_make( [ 0x9796b6ce , 0xfade434f , 0x082e14b7 , 0xde16facd , 0x286d5ed1 , 0xfee89152 , 0x5a0bcf69 , 0x17bffa2c ] )
+  , Mont::unsafe_make( [ 0x6238af17 , 0xaf793aca , 0xf17f6fe1 , 0x580b7810 , 0x2bdceae2 , 0x8076f815 , 0x1308c4f8 , 0x0a81613d ] )
+  , Mont::unsafe_make( [ 0x1a465b44 , 0x7bb4957f , 0x0ab504d8 , 0x35505196 , 0x11c27d18 , 0x0ad1f6f0 , 0xf68c7fcb , 0x2f6a8ccd ] )
+  , Mont::unsafe_make( [ 0xaf3f9a6b , 0x23bd0384 , 0x9e607584 , 0xb5f73e7a , 0x7c43e645 , 0x2dbbaea2 , 0x1bed1d4d , 0x03247c49 ] )
+  , Mont::unsafe_make( [ 0xa6d0c0bd , 0x449b6de2 , 0xcac4b461 , 0x8d88c677 , 0xe273a832 , 0xdfabeee5 , 0x0cf2c7d9 , 0x0fdd41b1 ] )
+  , Mont::unsafe_make( [ 0xfeb93572 , 0x7ef7c0f3 , 0x27f37017 , 0x5d65500d , 0x9501bdfa , 0xdabd68fe , 0xcddbdb32 , 0x05a115ed ] )
+  , Mont::unsafe_make( [ 0xf63a4553 , 0xf1b687ef , 0xd62000c8 , 0x8a4f93ac , 0x1d3c2f92 , 0x4f7d6fb6 , 0x4c044c92 , 0x025f25ad ] )
+  , Mont::unsafe_make( [ 0xd7c729b5 , 0x454ae0b8 , 0x6df86314 , 0xf9d9c8eb , 0x9b95d56b , 0x92e9cfa1 , 0x9286061a , 0x190bf193 ] )
+  , Mont::unsafe_make( [ 0xf3de2e41 , 0x1a70c097 , 0xc263dde4 , 0xce9cdeb1 , 0x7e300bd8 , 0xba0ae888 , 0xaf480ade , 0x1c1057a6 ] )
+  , Mont::unsafe_make( [ 0x2a4c1257 , 0x9fd5e5ce , 0xd768252e , 0xface63e6 , 0x74382982 , 0x27daa9dc , 0x285fb4b2 , 0x136064f9 ] )
+  , Mont::unsafe_make( [ 0x3354cc3a , 0xffe7080f , 0xea984b1a , 0x6c0efd70 , 0xf8a34ff2 , 0x7a8f4283 , 0xa1b5bc21 , 0x1d44d7b3 ] )
+  , Mont::unsafe_make( [ 0xba30d566 , 0x74a7d11e , 0x5bd3cc12 , 0x0ada9a37 , 0x7521becb , 0x1dafe6e7 , 0xc50787ab , 0x042f004e ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x735d1c19 , 0x8f125eb2 , 0xf54ec379 , 0x2b4b52ab , 0xdf698efd , 0x82e872ed , 0x28ac1db2 , 0x12f2d9c0 ] )
+  , Mont::unsafe_make( [ 0x30e416c2 , 0x4dec84ee , 0xa82fa289 , 0xa00f6d5b , 0x46eaebe3 , 0x42917914 , 0xab64e93c , 0x1489d9ee ] )
+  , Mont::unsafe_make( [ 0x8d4ac734 , 0x33e1f277 , 0x14a2b19f , 0x5dc67850 , 0x1dfe8e79 , 0xcbb6d99f , 0x1420c565 , 0x20c48db8 ] )
+  , Mont::unsafe_make( [ 0xd6e9820b , 0xcbcc98f7 , 0x39f75c25 , 0xcc3c4e80 , 0x9425dfb5 , 0x2dbc52ed , 0x68c08128 , 0x159c636a ] )
+  , Mont::unsafe_make( [ 0x4d96bd7f , 0x45b5ec0f , 0x6215383b , 0xf13db0c6 , 0x85016ce2 , 0x08278943 , 0xf38df909 , 0x0559eb65 ] )
+  , Mont::unsafe_make( [ 0xd6992ce7 , 0x0ec87738 , 0x3817cb40 , 0xe2293e07 , 0x0783e9c2 , 0x42777564 , 0xf272d142 , 0x1b0dbe05 ] )
+  , Mont::unsafe_make( [ 0x1ea9da74 , 0xb74407b6 , 0xb532fd99 , 0xa1e55ab0 , 0xdfb6fef4 , 0x25cc94d0 , 0x14465e2c , 0x07b36e64 ] )
+  , Mont::unsafe_make( [ 0x163fecf0 , 0x91e3fc83 , 0x3c40febf , 0x1ca74d3f , 0x702ce7cd , 0x7951e9a6 , 0x2c62011e , 0x0eb0c900 ] )
+  , Mont::unsafe_make( [ 0x08f70b09 , 0x93f34656 , 0xc0826038 , 0x82cfbe77 , 0xdb70bf44 , 0x6621e43c , 0xcf53eb9e , 0x17ab3b47 ] )
+  , Mont::unsafe_make( [ 0x04a42100 , 0x6ead0dd1 , 0x08097d97 , 0x3388bf87 , 0xc106dd1c , 0xf3599ecb , 0x8c638a4f , 0x0514ab6b ] )
+  , Mont::unsafe_make( [ 0x95fc2af2 , 0x3bf02863 , 0xc64e00c7 , 0x25cf11bc , 0xca3c7135 , 0x6b049ec6 , 0x56408615 , 0x0a316c0a ] )
+  , Mont::unsafe_make( [ 0xef2c95f0 , 0x246a697e , 0x494f2fb8 , 0x488863a3 , 0xa3fec33a , 0x9d99863e , 0xe943b952 , 0x1c4a4b60 ] )
+  , Mont::unsafe_make( [ 0x565b9bd2 , 0x5d00749b , 0xa5498f58 , 0xd6242b72 , 0x5b0b6efb , 0x4979fbd6 , 0xfa531118 , 0x05341ab9 ] )
+  , Mont::unsafe_make( [ 0x49c9b371 , 0x609770ff , 0x2c78e184 , 0xce588a7c , 0xad03f778 , 0x59948e1b , 0xe7dc7821 , 0x049e130b ] )
+  , Mont::unsafe_make( [ 0xa12bc343 , 0xce351a48 , 0x2a9a03ba , 0xb35278ae , 0xbe64bcbb , 0x201b77e5 , 0xe432ff8e , 0x22a8f729 ] )
+  , Mont::unsafe_make( [ 0xe8dccab7 , 0x51d19d27 , 0x186998bc , 0xfd262222 , 0x7d4ffde7 , 0x0f25e587 , 0x5e118f8e , 0x1b8c116e ] )
+  , Mont::unsafe_make( [ 0x75852a88 , 0x79fc434d , 0x30e4661c , 0x63c25a7c , 0x404dec7b , 0xe7d147f2 , 0x494f3f17 , 0x22cadf1f ] )
+  , Mont::unsafe_make( [ 0xf2f1f319 , 0x759576dd , 0xcc2fe5c9 , 0x4c8b1945 , 0x3ebad33e , 0xce6e2b9e , 0x147b6761 , 0x0378bb11 ] )
+  , Mont::unsafe_make( [ 0x0b42ae08 , 0x5f6536b6 , 0xcee6276e , 0xfdd29c44 , 0x88aa7cd0 , 0xc3e22830 , 0x8ba3aec3 , 0x2a396c52 ] )
+  , Mont::unsafe_make( [ 0x4219bc5d , 0xdb12967e , 0xf298bfb9 , 0xe054c84e , 0x8d9fe16d , 0x864dcbb1 , 0xbb0e9010 , 0x0a6501b0 ] )
+  , Mont::unsafe_make( [ 0x39747a65 , 0x1978f4e7 , 0xa9d0619b , 0x1a95aa15 , 0x67bdc4dc , 0x4f897040 , 0xdd8d9746 , 0x02907eed ] )
+  , Mont::unsafe_make( [ 0xe72a64b6 , 0x8d976476 , 0x981a2c52 , 0x7173a183 , 0xd991e355 , 0x2a86786f , 0xfe92c0dc , 0x2fafa2f1 ] )
+  , Mont::unsafe_make( [ 0x85f12171 , 0x8603c8da , 0x29fa9988 , 0x5f0ea5ef , 0x484efe09 , 0x6023a903 , 0x9c9ca948 , 0x046a85c3 ] )
+  , Mont::unsafe_make( [ 0x254faf46 , 0xaf140d4c , 0x4088ffa1 , 0xff5adaa6 , 0xce9f28ea , 0x574cdcd8 , 0xb17e407c , 0x29c6e88a ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x5b97ee84 , 0xfbf390c9 , 0xde4adf91 , 0x62d703c0 , 0x4c87740e , 0x5102cd40 , 0x3bd1ad04 , 0x0df6f1f0 ] )
+  , Mont::unsafe_make( [ 0xc0f9daf7 , 0xdefcba8d , 0x959a8783 , 0x2343c218 , 0x0c6ed43d , 0x76f7b63a , 0x55d83f59 , 0x093a063a ] )
+  , Mont::unsafe_make( [ 0x6746b1e8 , 0x681bdd33 , 0x018e80b2 , 0x22f04529 , 0xc381fea7 , 0x63e24e4e , 0xe5b160c6 , 0x124afd14 ] )
+  , Mont::unsafe_make( [ 0x6a5eea6f , 0x9a01c0e4 , 0xdf7734ca , 0x806125ed , 0xa87577e6 , 0x20091fca , 0x80ddfd15 , 0x0fda64a5 ] )
+  , Mont::unsafe_make( [ 0x08635d5d , 0x9466338d , 0xe2f043d4 , 0x8e6f2f2f , 0x913241b3 , 0x5b0402bd , 0x5036c135 , 0x134f8a69 ] )
+  , Mont::unsafe_make( [ 0x9d28353f , 0x554b235c , 0xfc328110 , 0x9b90a7c8 , 0x45fdd87b , 0xbd0c3e6d , 0x9ac9559a , 0x07deff38 ] )
+  , Mont::unsafe_make( [ 0x279f2b07 , 0xdd9490ef , 0xb8e132ee , 0x8e2d6e49 , 0x979d0ea6 , 0xa0324277 , 0x6c40f0fa , 0x02f6761a ] )
+  , Mont::unsafe_make( [ 0x931a39a8 , 0x8229df5f , 0x06449732 , 0xc92e79d6 , 0xc676c00e , 0xf78b8974 , 0xb5845df1 , 0x292bfc98 ] )
+  , Mont::unsafe_make( [ 0x8d8c346e , 0x7830696d , 0x5b8c371e , 0x669b7ecb , 0x268fd7f5 , 0x59ba6725 , 0x6e8e52b3 , 0x1fc88f1f ] )
+  , Mont::unsafe_make( [ 0xf671e8fa , 0x5db1cae6 , 0x2c266e25 , 0x90a5b15f , 0x8eafccea , 0xda27c30f , 0xc98712ac , 0x1a4e8bcf ] )
+  , Mont::unsafe_make( [ 0xd346c908 , 0x2f5f6844 , 0x6b3ce87a , 0x686bbd33 , 0x2e75dc0e , 0xfe6a18b7 , 0x48d9e010 , 0x15a613df ] )
+  , Mont::unsafe_make( [ 0x63dc2dce , 0xf893d6d7 , 0x602e27c0 , 0xafd53790 , 0x93a7e3b2 , 0x0ed0902c , 0xd7002fd8 , 0x27782fe9 ] )
+  , Mont::unsafe_make( [ 0x4d51330d , 0xe1bcd219 , 0xb00d7ed7 , 0xa5ca5211 , 0x9bfc7153 , 0x60d6fe4c , 0x536efd47 , 0x0ab8f4e0 ] )
+  , Mont::unsafe_make( [ 0xf253c4c8 , 0xbc67e26f , 0xe931ffaa , 0x1ff32a3f , 0xfbaa82ab , 0x7bb29293 , 0xeb175b58 , 0x22ca83f5 ] )
+  , Mont::unsafe_make( [ 0x4f93f996 , 0x2f9e134b , 0xfe46d3b7 , 0xbb8635c2 , 0x75fc51e9 , 0x1f2fd6ab , 0x5fdc06ac , 0x22b5154d ] )
+  , Mont::unsafe_make( [ 0x41fb1ba6 , 0x1d085355 , 0x80e93bfd , 0x7b3b38a8 , 0x4a80d18e , 0x2a3484d2 , 0x8e1ca66a , 0x16991c4b ] )
+  , Mont::unsafe_make( [ 0x43982326 , 0x7b3babfb , 0x48a26d78 , 0xe07e6c5b , 0x074138e0 , 0x806833bc , 0x6c2bfe5d , 0x295a5246 ] )
+  , Mont::unsafe_make( [ 0x74f75f8d , 0x80946106 , 0x839eb80a , 0x043c37f1 , 0xd1f7cad0 , 0x36f26ce1 , 0x76a6985e , 0x248d120f ] )
+  , Mont::unsafe_make( [ 0xa46f68cf , 0x4ceb8840 , 0x7b81586c , 0x3efbbad8 , 0xe5272882 , 0xcb10b8bd , 0x5e61865d , 0x28cee14e ] )
+  , Mont::unsafe_make( [ 0x0c58f8a3 , 0x2486daf2 , 0x32231ca7 , 0x938b0951 , 0xf020021b , 0xfece5b5c , 0x12a1a226 , 0x2af0366f ] )
+  , Mont::unsafe_make( [ 0xe4cc0e5b , 0x6d142ac6 , 0x77618e89 , 0xeed88b36 , 0xe7280668 , 0x27ee5b80 , 0x0405e9db , 0x207b283a ] )
+  , Mont::unsafe_make( [ 0x2d2ee844 , 0xf79b8b27 , 0xcfad740e , 0x0fba30c1 , 0x7be6fbd1 , 0xb26c5c0f , 0xa92bff94 , 0x2414344d ] )
+  , Mont::unsafe_make( [ 0x54c277d4 , 0x3c537032 , 0xbf3575d7 , 0xf0bc7498 , 0xa0355a49 , 0xa2e0cbdd , 0xb07826db , 0x2aa50e25 ] )
+  , Mont::unsafe_make( [ 0x9e80fb48 , 0xcceec7cc , 0x60e098a2 , 0xfeda6faa , 0x08d4029b , 0xa44ebe25 , 0xcf7a4f1c , 0x0735eeb9 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xaabd7002 , 0xbb20a9a9 , 0x6f84f35d , 0x03e42179 , 0x2f6e0e3f , 0xe563ed71 , 0x7e1bea62 , 0x1a4f4989 ] )
+  , Mont::unsafe_make( [ 0x33c6e2d6 , 0x88024310 , 0x111af240 , 0xd577490d , 0xcb6bc8d3 , 0x846c2965 , 0x57d6ded2 , 0x167deb28 ] )
+  , Mont::unsafe_make( [ 0x11a26dd6 , 0x50975894 , 0x79a05b9b , 0xc6b37c1b , 0xd55f34ae , 0xe127d662 , 0x6a1c8166 , 0x226440cf ] )
+  , Mont::unsafe_make( [ 0xb6982ee4 , 0xd0069745 , 0x55833cbc , 0xdd2e7915 , 0x9bd01067 , 0x01cb5889 , 0xae939bc3 , 0x0d18236a ] )
+  , Mont::unsafe_make( [ 0x587d12cd , 0x83b8af4d , 0xa0f091e4 , 0xf1f42fe1 , 0xbf9cbfd2 , 0x2dbd6cc3 , 0x005cd9c2 , 0x04e0c5c5 ] )
+  , Mont::unsafe_make( [ 0x3bf9b79b , 0xd537cac3 , 0x4666d04f , 0x7919880f , 0xbe0638cf , 0xebc36c37 , 0xe7d8a7de , 0x0bed2599 ] )
+  , Mont::unsafe_make( [ 0x4c9eb0cd , 0x036f6567 , 0x2088a915 , 0xdaf95a7f , 0x223756c2 , 0x11c23a8c , 0x96380734 , 0x15d8e668 ] )
+  , Mont::unsafe_make( [ 0xd77c4a8d , 0x93208773 , 0x90f53c5e , 0x77a77e91 , 0xb37e2431 , 0x02fd88e1 , 0xd399e0ca , 0x202f8c9b ] )
+  , Mont::unsafe_make( [ 0xe29af4ac , 0x3825f6d5 , 0x0bcd7528 , 0x7cb2dedc , 0x1b950123 , 0xdcb98be8 , 0xc23bdbcc , 0x066f4213 ] )
+  , Mont::unsafe_make( [ 0x125dcfe6 , 0x05078d3d , 0xc43ab240 , 0x9ca6a4f7 , 0xd67339ba , 0x9151c967 , 0x2c103849 , 0x16c4986c ] )
+  , Mont::unsafe_make( [ 0x7836948b , 0xb8aecc10 , 0xd3c6082c , 0xce49a5f2 , 0x657b8663 , 0x2b4c3784 , 0x23bc2518 , 0x272d4dc4 ] )
+  , Mont::unsafe_make( [ 0x82aaa44e , 0xae6d49f9 , 0x0569ff50 , 0x9965e261 , 0xa1c4f4c2 , 0x5eebfd83 , 0x3672ab54 , 0x1da4441f ] )
+  , Mont::unsafe_make( [ 0x3e9518ea , 0xfe6591be , 0xf0413856 , 0x6069844d , 0xd914c0ff , 0x24b4a0d6 , 0xced19b99 , 0x1e088f3e ] )
+  , Mont::unsafe_make( [ 0x365d07d8 , 0x0f0b34b7 , 0x1d59a493 , 0x06b37784 , 0x56bd3840 , 0xedbb0d67 , 0xb5f0e4c4 , 0x2654a96f ] )
+  , Mont::unsafe_make( [ 0x0e532a73 , 0xad09090a , 0xfe1d6e00 , 0xb71d113d , 0xd0ab14ce , 0x6ae3d5f7 , 0x98bc445f , 0x207cf65c ] )
+  , Mont::unsafe_make( [ 0x2d66161b , 0x0c0ceedf , 0xfb048568 , 0x00aa4fd7 , 0xcde06197 , 0xc474fd16 , 0x01871f40 , 0x0b4bb3f1 ] )
+  , Mont::unsafe_make( [ 0xed16a3c1 , 0x83c639e8 , 0xd441381f , 0xb60412be , 0x83076a93 , 0x6cf5d39a , 0x0a1d3f3d , 0x25616da9 ] )
+  , Mont::unsafe_make( [ 0xfe1bded6 , 0x6369474e , 0x25c78688 , 0xe8b1ceef , 0x488958d1 , 0x0f12b739 , 0x3770cf9e , 0x1da52fbf ] )
+  , Mont::unsafe_make( [ 0xad1c897a , 0x166e667f , 0x7a91d89c , 0x4a88da62 , 0x630142c8 , 0x90da7178 , 0x0c8563e9 , 0x14a37318 ] )
+  , Mont::unsafe_make( [ 0xae4efaa3 , 0xba903d43 , 0xcbc8ef05 , 0x4b8af94d , 0xc5b95fce , 0xe12f49c9 , 0xdf229c1c , 0x013b4ba3 ] )
+  , Mont::unsafe_make( [ 0x6e48d91e , 0xbc3126e1 , 0x049b3015 , 0x4f66f265 , 0x37c19c53 , 0xe54ee117 , 0x2789f657 , 0x2d052757 ] )
+  , Mont::unsafe_make( [ 0x7faaa3e2 , 0xd6a3d7e5 , 0x52fa62a7 , 0x1c12c2fe , 0xdce8c00a , 0x2c30200d , 0x8f6c3e0b , 0x13e9daba ] )
+  , Mont::unsafe_make( [ 0x23054e7d , 0x6e4ea87c , 0x4ce2c7bf , 0xa3b5cf13 , 0x42b6de04 , 0xe385c142 , 0xf14ed216 , 0x1b23294d ] )
+  , Mont::unsafe_make( [ 0x120c9a0f , 0x87492799 , 0xf308ba1d , 0xe5f750b8 , 0xcb37e53d , 0x3eb4b740 , 0xae857fa6 , 0x197a0f42 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x093a647a , 0x2f2d2c8f , 0xdc46f016 , 0xa54c801a , 0x51573ead , 0x2134991e , 0xb10c17d1 , 0x0184c3dc ] )
+  , Mont::unsafe_make( [ 0x779144c8 , 0xdebc93d9 , 0xb3f030cb , 0x2b428b0b , 0x0eaaf51a , 0x156cca75 , 0x96565008 , 0x25e4a7e3 ] )
+  , Mont::unsafe_make( [ 0x1669c155 , 0x42bb67fe , 0x0a96a335 , 0x65b2d36c , 0x35132ff3 , 0x23dd771d , 0xca7b71af , 0x2de26169 ] )
+  , Mont::unsafe_make( [ 0x4c8794a3 , 0x560377e3 , 0x61911c42 , 0xd4397c53 , 0x296a4233 , 0x613f028e , 0xc56e665b , 0x0600aa45 ] )
+  , Mont::unsafe_make( [ 0x191a03de , 0xab0edd02 , 0x5d4893bf , 0xcff76a9e , 0xe1f111ef , 0x91390fb3 , 0x5b439178 , 0x15ba62b6 ] )
+  , Mont::unsafe_make( [ 0x544f5583 , 0xdb35952a , 0x57facfba , 0x322e49a4 , 0xfffbfeaa , 0xc6360b30 , 0x76f46f01 , 0x275e4e35 ] )
+  , Mont::unsafe_make( [ 0xbbd4f666 , 0x560043b3 , 0x2621ea19 , 0x35d2b107 , 0x9c362ee4 , 0xdaef93d9 , 0x864f5c7b , 0x1fc5ee74 ] )
+  , Mont::unsafe_make( [ 0x176301a3 , 0xd5eb0d5f , 0xdbee12e8 , 0x4462304e , 0xf5afbac0 , 0x3a380b20 , 0x0c5d8682 , 0x23799443 ] )
+  , Mont::unsafe_make( [ 0x3f97ef64 , 0xa4769f04 , 0x01187362 , 0xae6c3a4c , 0x6f3a98ae , 0xbd44ea9d , 0xf61ea62e , 0x0b6af53f ] )
+  , Mont::unsafe_make( [ 0x70560f00 , 0x6faf057b , 0x116b409c , 0x01edaf47 , 0xcfedda68 , 0xb62886c1 , 0xbaa8ae8c , 0x09706bd7 ] )
+  , Mont::unsafe_make( [ 0xc7435bee , 0xcac42b4a , 0x73bc0ff0 , 0xe3db0e57 , 0x981649f1 , 0x7279dc9c , 0x0aeaf74c , 0x12a20cec ] )
+  , Mont::unsafe_make( [ 0x95de644a , 0x38f0f4e9 , 0x61537c81 , 0xe25ff432 , 0x7b6b9d69 , 0x9a2d42d5 , 0x68caa5da , 0x0d3a4818 ] )
+  , Mont::unsafe_make( [ 0x177b1643 , 0xbe56ab7b , 0xfb9dc93c , 0xfc1abef3 , 0xc880b3fd , 0xd584a54b , 0x27c14415 , 0x24e6da69 ] )
+  , Mont::unsafe_make( [ 0x666f0f11 , 0xb21c5abd , 0xe919144a , 0xff239967 , 0xabcafd3f , 0xf2a8e8dd , 0x9209cf51 , 0x1d66248b ] )
+  , Mont::unsafe_make( [ 0x02da9e6f , 0x4be69c29 , 0xdb9dec9e , 0xc71a4868 , 0xb6c04128 , 0x10e4e0b5 , 0x3d5c78b4 , 0x2ee8fbcd ] )
+  , Mont::unsafe_make( [ 0xf564ba75 , 0xdb14f9be , 0x9d8414cc , 0xe1921e3e , 0x07ed24db , 0x5a33e63d , 0x744d4593 , 0x0e031787 ] )
+  , Mont::unsafe_make( [ 0xe0b4a407 , 0x369ea5ed , 0xe87d3dab , 0xdb38ed10 , 0xbf244a10 , 0x5987989d , 0x862a8463 , 0x1ba0e8cf ] )
+  , Mont::unsafe_make( [ 0x885d354f , 0x9d5bd061 , 0xd967a046 , 0xa66485fa , 0xe4aca4e5 , 0x1e7c7d21 , 0x4be32273 , 0x181a0c4d ] )
+  , Mont::unsafe_make( [ 0x30f2eb01 , 0x847d8689 , 0x22ccf42d , 0x354660e2 , 0xc1b436c2 , 0xcc76e99f , 0xe918480f , 0x2f2cb9c4 ] )
+  , Mont::unsafe_make( [ 0xf947e688 , 0x739d0d55 , 0x56f0db07 , 0xeb722c04 , 0x0897d626 , 0xca89ac5d , 0xf9ef5d72 , 0x2d592471 ] )
+  , Mont::unsafe_make( [ 0xec5c727f , 0x1e14ab10 , 0x360daaad , 0x26d0f604 , 0x9ed86615 , 0x19b0a187 , 0xcc233b50 , 0x19107c93 ] )
+  , Mont::unsafe_make( [ 0xc03dccc9 , 0xac4cbf5a , 0x6da9f8fe , 0xec257f88 , 0x242db84e , 0xa7eade74 , 0x40353a59 , 0x0ff3fa06 ] )
+  , Mont::unsafe_make( [ 0x22349443 , 0x698523d5 , 0x32158bc0 , 0x92cf9c1a , 0x26275bbc , 0xa3d6fc25 , 0xdf55059e , 0x1051d10f ] )
+  , Mont::unsafe_make( [ 0xecc3db09 , 0x579f2d98 , 0xd189c51d , 0x155a3219 , 0xeab427f9 , 0xd73acc9a , 0xe3dd20e9 , 0x2dbc574f ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x9f8e0e6c , 0x793b8ec9 , 0x56ae32a9 , 0x76e90699 , 0x86a193ba , 0x55a5c57a , 0x3a0d020e , 0x26f8f414 ] )
+  , Mont::unsafe_make( [ 0x7de34ebd , 0xb9e9d50a , 0x4808190e , 0xd681ead9 , 0x6f33b651 , 0xc16fa68d , 0x88cc9244 , 0x10d0d964 ] )
+  , Mont::unsafe_make( [ 0xe2035355 , 0xd70b4ab7 , 0xee07ff20 , 0x5de3ad86 , 0x30a0f680 , 0xce0f8988 , 0x0e94e7c4 , 0x0c38e24e ] )
+  , Mont::unsafe_make( [ 0xebb667b2 , 0xb9ba4656 , 0xf63be4b0 , 0xa427fcca , 0xde90a8d2 , 0x9f5db07f , 0xebc19066 , 0x1fb9583d ] )
+  , Mont::unsafe_make( [ 0xd4c2f828 , 0x8fc11e66 , 0x04131794 , 0x1ef3fbbd , 0xcba566a6 , 0xed50dfdb , 0xeddcd134 , 0x24f27656 ] )
+  , Mont::unsafe_make( [ 0x24dd8f31 , 0x32491f36 , 0xb4a7b852 , 0xf59028bc , 0x28d25585 , 0xa1fce59c , 0xf2b7babe , 0x13832881 ] )
+  , Mont::unsafe_make( [ 0xb7e94089 , 0xbab4f781 , 0x41bfb835 , 0x454e2362 , 0xb67279ee , 0xe7eae541 , 0xc8513c77 , 0x0b650d2e ] )
+  , Mont::unsafe_make( [ 0xc88185d3 , 0xd55d1810 , 0x16cc8b40 , 0x20a0e3d9 , 0x46eadff7 , 0xcff23cf9 , 0x0f5f3df7 , 0x2f18399a ] )
+  , Mont::unsafe_make( [ 0xc6f84777 , 0x56988b32 , 0x37cbbb85 , 0x2bb1237c , 0xa1cccb3f , 0xeb465e05 , 0xd7a6ed6a , 0x30361486 ] )
+  , Mont::unsafe_make( [ 0x4675fa0b , 0xe8af6876 , 0x3049ec4f , 0x2d268b62 , 0x8a20c0ef , 0x2921654f , 0x76ae8cb8 , 0x157f51bc ] )
+  , Mont::unsafe_make( [ 0x519a0620 , 0x62f8bb06 , 0xb1b04dd4 , 0x5e6d3fc1 , 0x5a928110 , 0xab8d39d1 , 0xf72d214c , 0x2a10e2df ] )
+  , Mont::unsafe_make( [ 0xd00c1b5a , 0xed2714c1 , 0xde78904b , 0xd4ba4eff , 0x16a78a1d , 0x62d3d6c3 , 0x86eb1302 , 0x1c591099 ] )
+  , Mont::unsafe_make( [ 0xc58d427e , 0x1402e1e1 , 0x467000af , 0x252ef531 , 0x76ce8c26 , 0x91e93233 , 0x4ef23323 , 0x20de2f0d ] )
+  , Mont::unsafe_make( [ 0x30903332 , 0x3a46c3ac , 0xdad6163a , 0xb4fddf71 , 0x3a2987fd , 0x99efa937 , 0x1f85ced9 , 0x26faebac ] )
+  , Mont::unsafe_make( [ 0x5d26f13d , 0xbb780336 , 0xb1519c76 , 0x0810ac80 , 0x9f51f009 , 0xfe64ee86 , 0xab3d120f , 0x026f04da ] )
+  , Mont::unsafe_make( [ 0x7b152f82 , 0x66d5abc9 , 0x6eb2f6c4 , 0x6b1c2add , 0x62a422d8 , 0xb6c1ce8d , 0xe3fe98d9 , 0x2dddb38d ] )
+  , Mont::unsafe_make( [ 0x130cafb2 , 0xb8f6d9d4 , 0xfeb9dceb , 0xa398a3ac , 0x52844702 , 0x0ef69d1c , 0x0b56943e , 0x233f68cc ] )
+  , Mont::unsafe_make( [ 0x9b537dea , 0x83aa5d01 , 0x94da85c7 , 0xc136f128 , 0xd7324aee , 0xbbc79e7c , 0xe78a2c8c , 0x29f1e737 ] )
+  , Mont::unsafe_make( [ 0x73b337dc , 0x216a6ec9 , 0x7da7a3f1 , 0xe30eaced , 0xb292d494 , 0xe97779c2 , 0xd5cfd2ca , 0x080e9c55 ] )
+  , Mont::unsafe_make( [ 0xf0f44a8d , 0x7e43f1c2 , 0xc3abd90f , 0x4eac5d0a , 0x1b012191 , 0x345339e1 , 0x9e4bbfa1 , 0x07ccccb3 ] )
+  , Mont::unsafe_make( [ 0xc29f822e , 0x81b50505 , 0x76443bb5 , 0xbcc863fe , 0x2794e3c7 , 0x5bd2c4d1 , 0x96d2b6d7 , 0x24f7397d ] )
+  , Mont::unsafe_make( [ 0x29c89f5a , 0x5051e687 , 0x6f90c0e5 , 0xc8273b99 , 0x54a1d2be , 0x0d37d404 , 0xfad7b50d , 0x23a9598c ] )
+  , Mont::unsafe_make( [ 0x91a28312 , 0x4747a602 , 0x2def21f9 , 0x1d28fd41 , 0xbe2b5a9b , 0x3fd83210 , 0xe6fe235d , 0x0f1abae3 ] )
+  , Mont::unsafe_make( [ 0x55869b19 , 0x56f7325e , 0xb902318b , 0x8827c7d3 , 0x13414c85 , 0x45ad7408 , 0xeba04883 , 0x02c13a4b ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xfbd4226d , 0x01058a99 , 0xcbc0d9d4 , 0xcfb3e9ef , 0x113768af , 0x8efd0ab8 , 0xb9c78802 , 0x070f97d6 ] )
+  , Mont::unsafe_make( [ 0x91116cbf , 0xd13e8fdb , 0x92a50ef7 , 0xb357eceb , 0x348787f4 , 0x9dcef9c0 , 0xdc50ba7e , 0x19fd5973 ] )
+  , Mont::unsafe_make( [ 0x0e68865c , 0xa4972907 , 0x5fb79761 , 0x1c87fb6b , 0xf8d0417e , 0x8b3c6858 , 0xf9285d4c , 0x21fef4e9 ] )
+  , Mont::unsafe_make( [ 0x253eeeb2 , 0x99349c66 , 0x77be46e0 , 0x3e151dba , 0xc90fcc40 , 0xeeb528d6 , 0xc9d241b0 , 0x1dcbc0f6 ] )
+  , Mont::unsafe_make( [ 0x5e1106e7 , 0x74f5a8d7 , 0xe02f0754 , 0x0457d21e , 0xd3f82501 , 0x5b5abad4 , 0xe57f600e , 0x136db9ec ] )
+  , Mont::unsafe_make( [ 0x635a1054 , 0x88d51d5a , 0xf0340d68 , 0x390de4f9 , 0x93603842 , 0x1c9e606f , 0xd3a03016 , 0x1dbab9d0 ] )
+  , Mont::unsafe_make( [ 0x06427a8d , 0x33dd630a , 0xb9091ebb , 0x664f7c51 , 0x637208e7 , 0x253056dd , 0xfa092579 , 0x2b6e27ee ] )
+  , Mont::unsafe_make( [ 0xf5bb90d9 , 0xe5b75acb , 0x1a3eaeba , 0xafac619f , 0xd6e311fd , 0x7c17ff44 , 0xd3d5d060 , 0x2e4a6ff1 ] )
+  , Mont::unsafe_make( [ 0x4376392f , 0xb4e8124e , 0x0759a7d2 , 0x71a8eff5 , 0x80f06e56 , 0xf8041c70 , 0x0f21fae8 , 0x1eacab01 ] )
+  , Mont::unsafe_make( [ 0x5833b3f4 , 0xcbf1abe5 , 0x6aa8e500 , 0xd9b87f08 , 0xf43b9b46 , 0xacac3c14 , 0xb3cc3d00 , 0x14b58c4e ] )
+  , Mont::unsafe_make( [ 0xa96ee9fb , 0x98378b8e , 0x8698f835 , 0xcc5dfb7c , 0xd7dcd5e5 , 0x45dce4ef , 0xdcad557f , 0x1e01f09b ] )
+  , Mont::unsafe_make( [ 0x3f3df34d , 0x295ee42c , 0xdcdbcc4f , 0x3d29cebe , 0x07c9d5a8 , 0x5d44d8db , 0x7d2ed409 , 0x1d081a6e ] )
+  , Mont::unsafe_make( [ 0x816e0dfe , 0xbf62d802 , 0x4f5b8946 , 0x77e165f7 , 0xabb3f3a9 , 0xaaa077ef , 0xbc45b7cb , 0x2e972387 ] )
+  , Mont::unsafe_make( [ 0x21322c7d , 0x088a432d , 0xbe64f701 , 0xe6871428 , 0x06009bf5 , 0xa350b6e5 , 0x5d3e1ca9 , 0x159a982e ] )
+  , Mont::unsafe_make( [ 0x6dbaff58 , 0x8d844732 , 0xf4871173 , 0x48aae82e , 0xbfd38b46 , 0xb457db14 , 0x0a0be29c , 0x232aae4f ] )
+  , Mont::unsafe_make( [ 0x1e43fb4b , 0xde5c63a9 , 0x325b9850 , 0x8dc39a46 , 0x48d61388 , 0x2d209bdd , 0xab6a008e , 0x14956236 ] )
+  , Mont::unsafe_make( [ 0x495fe7f3 , 0x03bfcbd6 , 0x122ee260 , 0x55b1e8f6 , 0xf74be58c , 0xf9e28a1c , 0x9c7a6637 , 0x030b458c ] )
+  , Mont::unsafe_make( [ 0x7abbca46 , 0x9feb1452 , 0xaee4428e , 0x4714ea25 , 0x0e4be008 , 0x2c1c178b , 0x9dce2343 , 0x17b6efc4 ] )
+  , Mont::unsafe_make( [ 0xbb3dac01 , 0x90fd73ba , 0xd42588b9 , 0xbab18ce6 , 0x8687ce77 , 0xc090597b , 0x5bce25bf , 0x1ff18a93 ] )
+  , Mont::unsafe_make( [ 0x89ec7712 , 0x60eb0d71 , 0x69eb21f4 , 0x77ac1489 , 0x6173a6c7 , 0x4ba968e7 , 0xb4fa2318 , 0x0dd77c85 ] )
+  , Mont::unsafe_make( [ 0x09366721 , 0x7ecea3b2 , 0xa514a89c , 0x3ea095aa , 0x26ca167a , 0xca5d9fcd , 0xcd592d36 , 0x10228ae6 ] )
+  , Mont::unsafe_make( [ 0x9ce66a32 , 0x35b29f72 , 0x199abfd7 , 0x04575849 , 0x8854ebc3 , 0x3f245371 , 0xb222b575 , 0x28b4f84c ] )
+  , Mont::unsafe_make( [ 0x22c2f3b9 , 0x896f7fce , 0x19783303 , 0xe01258bc , 0x0813a0c0 , 0xa8698aac , 0xc7e21422 , 0x2569ba5c ] )
+  , Mont::unsafe_make( [ 0xd5de9231 , 0x13ddb48c , 0x1af99db8 , 0x5c6568d0 , 0x210188d7 , 0x9a50b98c , 0xfcfdcc14 , 0x1a94e556 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xe004a588 , 0xf2f672db , 0x4364c62a , 0xb1b39c53 , 0x5a71d5da , 0xa6bf54fd , 0x375d13d6 , 0x2f0b22de ] )
+  , Mont::unsafe_make( [ 0xa980ac7d , 0x292bcb01 , 0x24c5b262 , 0xaefbe63a , 0x467edf0b , 0x69d27226 , 0xcaed2305 , 0x0e4ec024 ] )
+  , Mont::unsafe_make( [ 0xff854fc0 , 0xffe77ff0 , 0x987e8fcf , 0x46f5d3cf , 0x86cb53c2 , 0x248dd259 , 0x32b38234 , 0x0ba7f24e ] )
+  , Mont::unsafe_make( [ 0x79c8d147 , 0x953f1da3 , 0x2b60a918 , 0xdfce2b74 , 0x6a863ebb , 0xf1b20c3c , 0x94554e05 , 0x275f4c9f ] )
+  , Mont::unsafe_make( [ 0xbe3bd651 , 0x79dcef61 , 0xdcc4818c , 0xd0d303f4 , 0xf7ce0431 , 0xd38bd1b0 , 0x5870e4b4 , 0x27733b8c ] )
+  , Mont::unsafe_make( [ 0x020ba2e5 , 0xc59e597b , 0x2db71f29 , 0xe7b562a1 , 0x3b9bb355 , 0x997f16f9 , 0xb464fbb2 , 0x02919804 ] )
+  , Mont::unsafe_make( [ 0x04675458 , 0x3761967b , 0xc205a16b , 0xcd6b8045 , 0x7b287f42 , 0xbcc37b30 , 0xa975a123 , 0x0fa8a8a2 ] )
+  , Mont::unsafe_make( [ 0x5c88921e , 0xe2b31fed , 0xbe1f0876 , 0x6cd313b2 , 0x55690001 , 0x4be76032 , 0xcbc430e1 , 0x17f7d57a ] )
+  , Mont::unsafe_make( [ 0xbd00d8c1 , 0xc9c583e3 , 0x5dda5139 , 0x44d898ad , 0x979adf67 , 0x1150fbd7 , 0xddd470e9 , 0x2d679272 ] )
+  , Mont::unsafe_make( [ 0xb68d4cbf , 0x7b54f0e3 , 0x13e5bfec , 0xba1ba594 , 0x9972b260 , 0xca1be97e , 0xb7b821f5 , 0x2c51caae ] )
+  , Mont::unsafe_make( [ 0xedd6d087 , 0xfadf10a3 , 0xe4172df9 , 0xa49e6438 , 0xdd2cd447 , 0x0f2e6fe9 , 0x3357c092 , 0x08214ea3 ] )
+  , Mont::unsafe_make( [ 0xea1a9762 , 0x77e94b4b , 0x07e4f23a , 0x16d9f43c , 0x9bcdb281 , 0x16e845d3 , 0x2082527a , 0x2ee3e235 ] )
+  , Mont::unsafe_make( [ 0xe73d3099 , 0xb6172ec5 , 0xd6897485 , 0xceb87c8d , 0xb75a93eb , 0x8ec35c65 , 0x4043d276 , 0x24c0f931 ] )
+  , Mont::unsafe_make( [ 0xe306cc8a , 0xb6f1de7e , 0xfa34a463 , 0xa23d6dd5 , 0x397c0277 , 0xde563395 , 0x9eeab496 , 0x2ebf7689 ] )
+  , Mont::unsafe_make( [ 0x467194b6 , 0x632182e6 , 0x22ecbea3 , 0x9f4aac99 , 0xc3a5741f , 0xa7cdb4e4 , 0x5ef418ac , 0x17b8348d ] )
+  , Mont::unsafe_make( [ 0xd9216b63 , 0x08d40adf , 0xef6e9d94 , 0x921e1611 , 0x80330ea0 , 0xc9f85da0 , 0x4996b4e2 , 0x04be929c ] )
+  , Mont::unsafe_make( [ 0xbc76fbf1 , 0x83699616 , 0x12e29b62 , 0x199378ee , 0x80074f7b , 0xc5264afb , 0x1030314b , 0x0b74554f ] )
+  , Mont::unsafe_make( [ 0x399d0567 , 0xb21e0ca2 , 0xd1a45815 , 0x31e43cb0 , 0xc8d5fa20 , 0x39d91e5f , 0xfd9fed0e , 0x02e032dc ] )
+  , Mont::unsafe_make( [ 0x7b162946 , 0x6fbc6a38 , 0x5e7721ae , 0xa340a0cc , 0x5d826fda , 0x73e6f77a , 0x0e1d32a6 , 0x01cf738a ] )
+  , Mont::unsafe_make( [ 0x766ae1e2 , 0x3626fc50 , 0x9dbda8ba , 0x8946b08e , 0xb83f96b5 , 0xb0939475 , 0xf70dafb0 , 0x0e23bddf ] )
+  , Mont::unsafe_make( [ 0xf38dc035 , 0x18daa0bc , 0xe351b454 , 0xb22151f5 , 0xab17482e , 0x60f2a10d , 0x47ba85ac , 0x1c21dd17 ] )
+  , Mont::unsafe_make( [ 0x1219af74 , 0x11f81a5b , 0x3220ed5c , 0x12a2085d , 0xde83e1fd , 0x7f8f9a4d , 0x9d8fa000 , 0x19a2422b ] )
+  , Mont::unsafe_make( [ 0xbfd18dff , 0x9af29cbb , 0x16ad62f4 , 0x4fd7b5f1 , 0x75b8a5d9 , 0x5cc59fa4 , 0x2a489780 , 0x26b7b252 ] )
+  , Mont::unsafe_make( [ 0xe8e3b183 , 0x0b909956 , 0xda9c7bb2 , 0x53ef93d5 , 0xa53706a6 , 0xce4d6da9 , 0xfc756255 , 0x0ab5b9b5 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x30b5d4c5 , 0xad6af903 , 0xb22527f5 , 0xabaf6da5 , 0xcd166634 , 0xfa599e75 , 0xf1278478 , 0x1e0ae96c ] )
+  , Mont::unsafe_make( [ 0xf051726c , 0xb85f0aed , 0x5c752097 , 0x42b502c3 , 0xc3503b18 , 0x5306880a , 0xa95b1a28 , 0x2fb3b1fa ] )
+  , Mont::unsafe_make( [ 0xec0f8d6b , 0xeaf98282 , 0xaec4124e , 0xf974aa66 , 0x7b2e1ce2 , 0xe3064058 , 0x29bf67a9 , 0x2a2e0611 ] )
+  , Mont::unsafe_make( [ 0xc6d70f9c , 0x01742723 , 0x3a0c5f5f , 0x9e70294b , 0x024718cd , 0x510b2822 , 0x3308aa1d , 0x1d7c153b ] )
+  , Mont::unsafe_make( [ 0x315b0061 , 0x7321cd7d , 0xf5da2a7e , 0x27a12ad2 , 0x5bce2f5c , 0xf43b1558 , 0xa9e210e8 , 0x16f2f529 ] )
+  , Mont::unsafe_make( [ 0xb8fe7813 , 0xbb4e1872 , 0xc187a31c , 0x8654cb80 , 0x69301ea7 , 0xec1f038c , 0x5856f02c , 0x0569a1da ] )
+  , Mont::unsafe_make( [ 0x3bfe9614 , 0xd3909320 , 0xb6e9e0f8 , 0xe7a9a2a7 , 0x5e649c55 , 0xa50f97da , 0x87208813 , 0x1afc8954 ] )
+  , Mont::unsafe_make( [ 0xa2d0f002 , 0x1b8fb295 , 0xf3c182d8 , 0x845d29eb , 0xbf9e7630 , 0x2f4e78ea , 0x5de8de7d , 0x01f68a4c ] )
+  , Mont::unsafe_make( [ 0x0bac8d22 , 0xd3bb273b , 0x96571c26 , 0x91c7a732 , 0xe1a5d4df , 0x5150430a , 0x00bd21ac , 0x0bd610b4 ] )
+  , Mont::unsafe_make( [ 0xb9a42f11 , 0xc3c9c92b , 0x548d9e46 , 0x5733844e , 0x0a126b03 , 0xc9aaa9ca , 0x0108eb77 , 0x01eea669 ] )
+  , Mont::unsafe_make( [ 0xdb5331da , 0xfe370d9f , 0x664363b7 , 0xe1eabbd4 , 0x9310eb8b , 0x853ba0d5 , 0x9a414d19 , 0x1d1466d9 ] )
+  , Mont::unsafe_make( [ 0x46713344 , 0x36cfe0c3 , 0x83280630 , 0x1736a179 , 0x19a0c6a4 , 0xbd19f6d0 , 0x03bad1b8 , 0x0d9ec095 ] )
+  , Mont::unsafe_make( [ 0x6cf95599 , 0xec1f5d70 , 0x30ad70ce , 0x26f9cf42 , 0x3bf6724f , 0xf0b94227 , 0x3b717cf5 , 0x236987f4 ] )
+  , Mont::unsafe_make( [ 0x68b177c4 , 0xeb0827c4 , 0xd1b6b9b1 , 0x9d6d15c0 , 0x7339f7ed , 0x74fa2faa , 0x28fe08e8 , 0x29740571 ] )
+  , Mont::unsafe_make( [ 0x86d3454c , 0x3dce6a0f , 0xe4a60008 , 0x3f96e3ed , 0xd41281fa , 0x17c0be4e , 0x901d6a6c , 0x262ebf73 ] )
+  , Mont::unsafe_make( [ 0xa6f4aa97 , 0xa1ac54e4 , 0x33d3eed3 , 0xa3eac5d1 , 0xe42dd9c8 , 0xeb6add4c , 0xac762a94 , 0x02a7062d ] )
+  , Mont::unsafe_make( [ 0x9414c651 , 0x6647db3d , 0x088bae2d , 0x515368d0 , 0xa90128d4 , 0x5d3e04a9 , 0xc6e05bc8 , 0x244e951a ] )
+  , Mont::unsafe_make( [ 0xffc94155 , 0xb50f6a8e , 0xdddf44ae , 0xdf54f779 , 0x5f63110a , 0x99f0db63 , 0x99821993 , 0x0b18b53f ] )
+  , Mont::unsafe_make( [ 0x2350a56f , 0x36649243 , 0xaf5fa1fa , 0xb63e8bbb , 0x5c642e49 , 0x66c766b6 , 0x29767a0c , 0x17005d58 ] )
+  , Mont::unsafe_make( [ 0x756192ed , 0xf3ca9c54 , 0x09f3c067 , 0x5a811472 , 0x42ebe59a , 0x6abe9245 , 0x5a3c76d9 , 0x1fb2e43c ] )
+  , Mont::unsafe_make( [ 0x87c8cf17 , 0x8845812d , 0x072366b9 , 0xd46a3629 , 0x788f29f5 , 0x49589a2f , 0xe1487e95 , 0x21854c1c ] )
+  , Mont::unsafe_make( [ 0x6fd0b05f , 0x298a23b6 , 0xf82d61e4 , 0xc7997e38 , 0xd1649d83 , 0xe114687b , 0x902a1093 , 0x031deb97 ] )
+  , Mont::unsafe_make( [ 0xbbdd9568 , 0x35134bea , 0x20913267 , 0x26389f0c , 0xace30879 , 0xdf76b0a3 , 0xd4c41934 , 0x26cf2b0f ] )
+  , Mont::unsafe_make( [ 0x2e5c688b , 0xd7cf7543 , 0x3616f34a , 0x315ccd62 , 0x94bcd050 , 0x5d65b478 , 0x5aaeb5a8 , 0x2e9a7f69 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xb6af8af8 , 0x71f349e8 , 0x4cd65bd0 , 0x82fee74c , 0x3a5190fc , 0x0a7242c7 , 0x7541537a , 0x0a50d91e ] )
+  , Mont::unsafe_make( [ 0xd162d680 , 0x07469b8d , 0xc2b37692 , 0x0cd90361 , 0x053c46dc , 0xcadb066d , 0x8e9ec106 , 0x2f03dd27 ] )
+  , Mont::unsafe_make( [ 0x1512f0e6 , 0x79dfdd5e , 0x448b55db , 0x637b706c , 0xde1c9b53 , 0x33740141 , 0xc3455a41 , 0x1f5253b8 ] )
+  , Mont::unsafe_make( [ 0xff5a4670 , 0x138a13db , 0xcdffccf8 , 0x5b8667ef , 0x57bdd392 , 0x69d8ad57 , 0x9f72b158 , 0x01bacadf ] )
+  , Mont::unsafe_make( [ 0x1d7c5184 , 0x56ff92bc , 0xef5c04a1 , 0x2f8a00ed , 0xaf75b280 , 0x5ec7dfd9 , 0xef5becf6 , 0x25671605 ] )
+  , Mont::unsafe_make( [ 0xb8900a4f , 0x385b7c78 , 0x12a3d702 , 0xe3430095 , 0x6b0ac22e , 0x760c4098 , 0x52d50866 , 0x1b557069 ] )
+  , Mont::unsafe_make( [ 0xdbb32d54 , 0xeba66c10 , 0x171c8100 , 0xe95a85f9 , 0x5e565147 , 0x4def395c , 0x4e35c5d8 , 0x0281c51d ] )
+  , Mont::unsafe_make( [ 0x3d2fbef4 , 0xe465a006 , 0x4c4fe13d , 0x58c5b9f6 , 0x665927a8 , 0x16a78c6e , 0x0f176dd7 , 0x1207ddf5 ] )
+  , Mont::unsafe_make( [ 0x47553dfa , 0x960cae5f , 0x9e155210 , 0x7194709e , 0xee08fd83 , 0x9af5e8c2 , 0x99921ce9 , 0x22781344 ] )
+  , Mont::unsafe_make( [ 0x1879b76b , 0x52d69622 , 0x9354dca4 , 0x60b8f581 , 0x6d71ff32 , 0x4e2b7d2c , 0x5760270f , 0x263b7447 ] )
+  , Mont::unsafe_make( [ 0x6c51bef4 , 0xab379fd3 , 0xc9989012 , 0x464c3cc5 , 0xf04d643a , 0xc2b9e320 , 0x50b08f18 , 0x16833f8e ] )
+  , Mont::unsafe_make( [ 0xb5093d5b , 0x3540dde0 , 0xab06c55a , 0x610f0f88 , 0xb600776f , 0x979909cd , 0x7d051066 , 0x2a3487e3 ] )
+  , Mont::unsafe_make( [ 0x098a2bee , 0x240c5b81 , 0xfe472d05 , 0x31f50625 , 0x6b4cd3e6 , 0x83b4ff27 , 0x50aebc24 , 0x173adec8 ] )
+  , Mont::unsafe_make( [ 0x5b1ff73a , 0x8e991f2e , 0x0e69e1f2 , 0xf83d066e , 0xfa6e6b87 , 0x3ffc7f3b , 0x8c20c5dd , 0x2f20c989 ] )
+  , Mont::unsafe_make( [ 0x5cab041d , 0x793c0790 , 0x03d73ee9 , 0xbb157dec , 0xbfc75843 , 0x4c6790d4 , 0x8f902c5a , 0x16a1c2b2 ] )
+  , Mont::unsafe_make( [ 0x9849f1c2 , 0xa8bc2757 , 0xfc428967 , 0xdeb875df , 0xfa2ac1ea , 0xcc0ddcfd , 0xbc5ba6e1 , 0x073b93f5 ] )
+  , Mont::unsafe_make( [ 0xb9f3c2a1 , 0xf6345d3c , 0xb6700566 , 0x57e39829 , 0x1043cf5a , 0xafe39eb6 , 0x5f02d0e4 , 0x0dbb34e3 ] )
+  , Mont::unsafe_make( [ 0x00eadb26 , 0xbd216ce6 , 0xcc6439cd , 0x66ef130c , 0xcf799551 , 0x619540da , 0xc6a49d34 , 0x289f9813 ] )
+  , Mont::unsafe_make( [ 0x38819561 , 0xe1201f13 , 0x41978c1f , 0x630b5d4e , 0xc9075560 , 0xe18fb3ed , 0xc42664a1 , 0x0f5d2254 ] )
+  , Mont::unsafe_make( [ 0x809e92ba , 0x52855ecd , 0x32f6b969 , 0x13c29a57 , 0xefa63626 , 0x9c77dc47 , 0x965d2303 , 0x070e832d ] )
+  , Mont::unsafe_make( [ 0x0f0bee73 , 0x92897805 , 0xcbfd3c50 , 0xc66b1857 , 0xb29b407c , 0xfce075ca , 0xe13e69c4 , 0x04ae6415 ] )
+  , Mont::unsafe_make( [ 0x79df2050 , 0x8c17cc4d , 0x8ba5bdbc , 0xf7237c1e , 0x1d1aaa8c , 0x785c83e8 , 0x0901ae81 , 0x0b28525f ] )
+  , Mont::unsafe_make( [ 0x8750dad4 , 0x5c0cbcba , 0x28f480d1 , 0x54c772b8 , 0xa992f561 , 0x872c0a22 , 0x27f0876a , 0x056198cc ] )
+  , Mont::unsafe_make( [ 0x41579505 , 0x4badd1be , 0x4b9cf2cb , 0x121c2b94 , 0x79fb489b , 0x6700d428 , 0x53999724 , 0x2775b63f ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x781459c4 , 0x62e6520f , 0x364ebced , 0xa21bb290 , 0x1b444488 , 0x6f21c281 , 0x6c410061 , 0x1db9d391 ] )
+  , Mont::unsafe_make( [ 0x3ff5328b , 0x209c987a , 0x72687b84 , 0xb53fcca7 , 0x3ed3ca4e , 0x7301b877 , 0xf88fb25f , 0x2c0353ba ] )
+  , Mont::unsafe_make( [ 0x18060ee1 , 0x4be75cf5 , 0x0f9b7c62 , 0xad18e301 , 0x5b3e0b54 , 0xec8b48fe , 0x040c72b4 , 0x188b4262 ] )
+  , Mont::unsafe_make( [ 0x6ba7755d , 0xdc58ad38 , 0x4f1bfdee , 0x687ff5d5 , 0xa463f60c , 0x8f0242df , 0x73166e64 , 0x072c8c23 ] )
+  , Mont::unsafe_make( [ 0x22097535 , 0xf23eb61a , 0xdf335311 , 0xfea17801 , 0xc26813db , 0x536cdb9e , 0x26ba6fc1 , 0x167fabb8 ] )
+  , Mont::unsafe_make( [ 0xc90583f5 , 0x39889d53 , 0xaec58758 , 0xf97d1cce , 0x8a484e95 , 0xdb0ff25b , 0xad744922 , 0x1363ba3f ] )
+  , Mont::unsafe_make( [ 0x754f6cc5 , 0x5161819f , 0x0f166ba5 , 0xbbdfa6f4 , 0xdfa78f1d , 0x2b6d48e4 , 0x6ed612fe , 0x0d78f9b2 ] )
+  , Mont::unsafe_make( [ 0x34e66097 , 0x5d198171 , 0x076a6f13 , 0x6d603f9f , 0x9c1904b4 , 0x59138dba , 0xa3a7248a , 0x19e04df6 ] )
+  , Mont::unsafe_make( [ 0x75c089a7 , 0xc27a6e40 , 0x9d4ae3e7 , 0xf3567563 , 0xe6f22297 , 0x34b650a4 , 0x4b49d5b8 , 0x0c0d8f97 ] )
+  , Mont::unsafe_make( [ 0x6e9597f1 , 0x2ea3470f , 0xca96879e , 0x8a24d6fd , 0x7783e769 , 0x4a937984 , 0x53b56b59 , 0x1bea2967 ] )
+  , Mont::unsafe_make( [ 0x57b1498a , 0x1221cb26 , 0x27d09793 , 0xe044cd60 , 0xadcf67d3 , 0x445f624d , 0x5f29c0a2 , 0x1c198b10 ] )
+  , Mont::unsafe_make( [ 0xc5cb2012 , 0x12ddc7e9 , 0xf48c25eb , 0x928e30fc , 0xdf1ad465 , 0x79cac2c3 , 0x4524390e , 0x143138eb ] )
+  , Mont::unsafe_make( [ 0x161d25b1 , 0x71c450c0 , 0x041f182c , 0x2f121f5b , 0xd4497c84 , 0x6de5a2f3 , 0xa41a69bb , 0x136eab9a ] )
+  , Mont::unsafe_make( [ 0xe9e4c044 , 0xad5713bf , 0xad9df5c0 , 0xb75be28f , 0x4cec115b , 0xf33e57e6 , 0x053a8911 , 0x2a33d53a ] )
+  , Mont::unsafe_make( [ 0xf2d05e5e , 0xde3390e4 , 0x3473ccd6 , 0xd269c09c , 0xe895f7ac , 0xa6bd20e3 , 0x78a7bb25 , 0x2a7886ff ] )
+  , Mont::unsafe_make( [ 0x1a82de06 , 0x2d014a72 , 0x3a038801 , 0x62e9c76e , 0x8d85031e , 0xcc4adc9e , 0x21bf4499 , 0x23faf295 ] )
+  , Mont::unsafe_make( [ 0xdf560470 , 0xbcb74171 , 0xe2215d53 , 0x7b4c1d56 , 0x941cea3c , 0xa0b5087a , 0x95bc9f0f , 0x0b8f361c ] )
+  , Mont::unsafe_make( [ 0x93dba4a8 , 0x5517987c , 0x12ecfe57 , 0x2fb170a2 , 0x93da3b37 , 0x083fdc54 , 0xf14f3ad4 , 0x1b12bbea ] )
+  , Mont::unsafe_make( [ 0x74c58217 , 0x989a8317 , 0xc2403bee , 0x6a065397 , 0x84c7c760 , 0xd1fb3600 , 0xcd7b34c9 , 0x1eaaf585 ] )
+  , Mont::unsafe_make( [ 0x455a0f60 , 0xecb252ab , 0xd0b5a67b , 0x30776c4a , 0xf3d51cd0 , 0x143795d2 , 0xf6cb0b26 , 0x21c08a00 ] )
+  , Mont::unsafe_make( [ 0xa5e93e3a , 0x67fac334 , 0xfdfb4779 , 0x9e7aeba5 , 0xa2b36ba7 , 0x468f8120 , 0x7b37057a , 0x1291bece ] )
+  , Mont::unsafe_make( [ 0xca98f9a5 , 0xb0c651d5 , 0x1a2c3432 , 0xbdbb842e , 0x327741b9 , 0xbeb09f72 , 0xcdf66bb3 , 0x1870ce1e ] )
+  , Mont::unsafe_make( [ 0x08fdd979 , 0x84dd56a3 , 0xdceecd1b , 0x4d24554c , 0xac1cdb16 , 0x628b27c4 , 0x48c726fb , 0x27183c17 ] )
+  , Mont::unsafe_make( [ 0x7ca4b837 , 0x0ba30495 , 0x552d2e38 , 0xd627aa34 , 0x964e464e , 0xa3fff355 , 0xe59b0f5e , 0x090c165b ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x4d91a845 , 0xc24cfea9 , 0x6d454b33 , 0x4cd0dad8 , 0x7cf70354 , 0xb7b6cbf7 , 0x83216168 , 0x0a32be0b ] )
+  , Mont::unsafe_make( [ 0xf8c267d1 , 0x2f93b063 , 0xc0bfb057 , 0x7e4fb16d , 0xb1c8e1a9 , 0xce74babe , 0x7783abb0 , 0x2f4b425e ] )
+  , Mont::unsafe_make( [ 0x4a18bd92 , 0xe7f5ed76 , 0xeb74754e , 0x981a8fb6 , 0xecde0bee , 0x36c2cd9a , 0x2a8e6efd , 0x29a3325e ] )
+  , Mont::unsafe_make( [ 0x928e47ce , 0x799e0d40 , 0x9cecd692 , 0xc928ac5d , 0x84bfd00d , 0x4bd86f81 , 0x4279b2cd , 0x03d75ae9 ] )
+  , Mont::unsafe_make( [ 0x6c876632 , 0x6998e631 , 0x9422c922 , 0x44838700 , 0x5b639e04 , 0xbd9fa289 , 0x7445e5f6 , 0x2b6b6a7e ] )
+  , Mont::unsafe_make( [ 0x1d75d04a , 0x98fb39bc , 0xd2acf61c , 0x6b8a99a1 , 0x7fbf04c1 , 0xc973d149 , 0xc8c0cb6a , 0x15cf9a2f ] )
+  , Mont::unsafe_make( [ 0x92b6db48 , 0x19040fe5 , 0xf6b69866 , 0xa87724ec , 0xf97992ff , 0x721fef6a , 0x541b60c4 , 0x25e5fee9 ] )
+  , Mont::unsafe_make( [ 0x0be71529 , 0xdac81efb , 0x7c2b6433 , 0xc9bc7f6c , 0xdcfa452f , 0x792f8790 , 0xf80abb54 , 0x27577516 ] )
+  , Mont::unsafe_make( [ 0xcbc72191 , 0x66254aff , 0xcef6d2ce , 0xc615c5d4 , 0x3a46aec9 , 0x4ce05f63 , 0xb759fa1a , 0x1044783a ] )
+  , Mont::unsafe_make( [ 0x49587d49 , 0xe8f5e97c , 0xbfb6ed2d , 0x23a88987 , 0x47a9a467 , 0xf7a4eecc , 0x79078edb , 0x2f5cc759 ] )
+  , Mont::unsafe_make( [ 0xcd3fd4df , 0xa79492c3 , 0x512d7d41 , 0x2b8498d2 , 0x941688eb , 0x0e5ac570 , 0x68ca2e71 , 0x0ada5f29 ] )
+  , Mont::unsafe_make( [ 0x4960532c , 0xb3f23f63 , 0xadf01672 , 0xee60df74 , 0x3885644a , 0xfacfd72e , 0x2b8e7d11 , 0x01536f85 ] )
+  , Mont::unsafe_make( [ 0x67f23600 , 0xffc47c5d , 0x752d44be , 0x2b015c72 , 0xc386e8ff , 0xc9b28e70 , 0x1a568748 , 0x2e0d411b ] )
+  , Mont::unsafe_make( [ 0x9a0bd7be , 0x97ae54f6 , 0x91a3b41e , 0xc845832a , 0xa99bb58c , 0xa05b09b6 , 0x5aaab544 , 0x1e5c6bc3 ] )
+  , Mont::unsafe_make( [ 0xf97c3883 , 0x480dccdd , 0x0b454319 , 0x7bcdcf62 , 0x88326d1a , 0xe0faba9c , 0x12141597 , 0x0f2418df ] )
+  , Mont::unsafe_make( [ 0x5a338923 , 0x26b3c37a , 0x503fca0d , 0xae8a3c27 , 0xb7933607 , 0x73116f1a , 0xc2db7882 , 0x12b5e346 ] )
+  , Mont::unsafe_make( [ 0xef48c162 , 0xa9c68b0b , 0xafb086f3 , 0xbf8f9569 , 0x70b3840e , 0xf9448ed4 , 0x23c5ed52 , 0x06d46a80 ] )
+  , Mont::unsafe_make( [ 0x768f24d0 , 0x30751dfa , 0xbaf71af7 , 0x632d341e , 0x9973c78f , 0x1c365697 , 0x4e2fad7c , 0x24b55dc0 ] )
+  , Mont::unsafe_make( [ 0x72726b7c , 0xdc0d226e , 0x6f0d142c , 0x6e66921f , 0xb40cf545 , 0x94a04e8a , 0x05186267 , 0x1af2a55d ] )
+  , Mont::unsafe_make( [ 0xe0f6de0e , 0x67f4a195 , 0x05da8536 , 0x72068df5 , 0xb98a0f41 , 0xa1cac56f , 0x743be821 , 0x0fdfe38e ] )
+  , Mont::unsafe_make( [ 0x9463942b , 0xa7ebeeec , 0xbccfdda9 , 0x94ee5087 , 0x136757b4 , 0xc0d4d0c6 , 0x3498dd3c , 0x1af015bf ] )
+  , Mont::unsafe_make( [ 0x00c769d7 , 0x462f5ce9 , 0xed9b5120 , 0xcf0588bc , 0xfa0109f4 , 0x3fd21460 , 0x51d435ce , 0x19936596 ] )
+  , Mont::unsafe_make( [ 0x13f62fa2 , 0xb596559a , 0xe17a525a , 0xd8efc505 , 0x5c18f30c , 0x7d04c10c , 0xccf6f2fc , 0x23c4e117 ] )
+  , Mont::unsafe_make( [ 0xbe804db0 , 0xbe8e28dd , 0x9860799c , 0x36a477e5 , 0xdeb299fc , 0x3e4ae5d8 , 0x1015ee89 , 0x0c660d9e ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x502f4e37 , 0x3b469ae9 , 0xf06a23f3 , 0xddbb0b5a , 0x1a150b05 , 0x6e2480b1 , 0x845e0b58 , 0x146a10f4 ] )
+  , Mont::unsafe_make( [ 0x04f9c7a2 , 0xc5e4d7ca , 0x4ef80a0e , 0xc812225c , 0x613b20e7 , 0xfc23302b , 0x3d441602 , 0x18566929 ] )
+  , Mont::unsafe_make( [ 0x008e1e51 , 0xefd23746 , 0xa1397194 , 0xe34e0859 , 0x78016027 , 0x6e8ef1f0 , 0xe5e106bc , 0x273361e4 ] )
+  , Mont::unsafe_make( [ 0x6a4ec34b , 0x5cf90e06 , 0x35e738d9 , 0x9d892e81 , 0x8e52ee77 , 0xb1bd6cf9 , 0x5626150a , 0x200960a1 ] )
+  , Mont::unsafe_make( [ 0x9a71b51a , 0xbaddf00f , 0x1a668afc , 0x92ee0ec4 , 0xe0038a11 , 0xe7b338ea , 0x13b53071 , 0x2791e447 ] )
+  , Mont::unsafe_make( [ 0xa2d09037 , 0x34179b47 , 0xc9e75694 , 0xa591644a , 0xed62e32e , 0x6da6e2c1 , 0x844d9716 , 0x10aa1c4f ] )
+  , Mont::unsafe_make( [ 0x6538e86b , 0x221ee695 , 0x02e6389f , 0xa7e55f6d , 0x4b211d16 , 0xeff24990 , 0xda01917b , 0x0b7da8c9 ] )
+  , Mont::unsafe_make( [ 0xb821f171 , 0xe762d031 , 0x21399e08 , 0x6caf1f02 , 0x1edf6319 , 0x999e1901 , 0xe34a6062 , 0x26f9c9a8 ] )
+  , Mont::unsafe_make( [ 0xc4f4b689 , 0x63448284 , 0x7a05d695 , 0xec622068 , 0x8d8c567b , 0xb00a9c61 , 0x067af5d3 , 0x086441b0 ] )
+  , Mont::unsafe_make( [ 0xaf5da5cc , 0xb23d8e9b , 0x1fbd7b2e , 0x9e48cff0 , 0x866f67a9 , 0xdb082f0c , 0xaa2c8a74 , 0x256ec5fa ] )
+  , Mont::unsafe_make( [ 0x457981f6 , 0x23f5b143 , 0xb6323e95 , 0x294852f1 , 0xee6b570d , 0xc72e0eee , 0xc3e739c1 , 0x00e7639b ] )
+  , Mont::unsafe_make( [ 0x9757e1f0 , 0xde12692a , 0x9816d85b , 0xfacf434c , 0x1109ed51 , 0x234f843a , 0x736b5331 , 0x131d600c ] )
+  , Mont::unsafe_make( [ 0x7453033c , 0x670a83fb , 0xe19d08c5 , 0x01e5a593 , 0x59bd3c20 , 0xf6d3a0d5 , 0xaab947e6 , 0x19116d39 ] )
+  , Mont::unsafe_make( [ 0xdcf7fad9 , 0x1b9e4b06 , 0x16f39e3e , 0x16f3cac1 , 0xb18d31a9 , 0x2939c0aa , 0x48feded3 , 0x1a3b4128 ] )
+  , Mont::unsafe_make( [ 0x8aa797e6 , 0x033028ef , 0x4ec3c163 , 0x3f4bcae6 , 0x649d623b , 0xb35b9266 , 0x00a40550 , 0x18ae8b61 ] )
+  , Mont::unsafe_make( [ 0x8d9bc3d6 , 0x25edbd71 , 0x94767870 , 0xbd3a37f1 , 0xb0a622cb , 0x65a6f19e , 0xf7e8f158 , 0x2f7cd882 ] )
+  , Mont::unsafe_make( [ 0xf914f95c , 0x6f408153 , 0x54d06a67 , 0x56f39374 , 0x1164e1e7 , 0xe17a4bf9 , 0xc067eda4 , 0x0e8f2a1f ] )
+  , Mont::unsafe_make( [ 0x2783ca53 , 0x7cd5f88e , 0x2472ab6b , 0x1ff8eda3 , 0x0d922fa8 , 0x28e50022 , 0x98976846 , 0x26681595 ] )
+  , Mont::unsafe_make( [ 0xb602f989 , 0x32ae76bc , 0xbd0de51f , 0x4b96feff , 0x6edfbc74 , 0x653f7afb , 0x617084da , 0x097cac3c ] )
+  , Mont::unsafe_make( [ 0x4af4521b , 0x2556d48c , 0x5950f2a3 , 0xa0c365b7 , 0x48a05549 , 0x99e8b95f , 0x0f28f769 , 0x1168ad88 ] )
+  , Mont::unsafe_make( [ 0x5cb160a5 , 0x3a8244c6 , 0x58a74c0e , 0xe0813761 , 0x0e02b127 , 0xf79e313b , 0x32edad82 , 0x1e41cd49 ] )
+  , Mont::unsafe_make( [ 0x7e68e5f5 , 0x58b01820 , 0x60d9b13a , 0x905fca9e , 0xdb03dbf2 , 0x408af398 , 0x7c4a6423 , 0x0639388f ] )
+  , Mont::unsafe_make( [ 0x1d0d2b44 , 0x0319126e , 0xc900b26b , 0xa6da8fa8 , 0x75883381 , 0x5133f33d , 0xfa886666 , 0x177d51ae ] )
+  , Mont::unsafe_make( [ 0xeabc23d1 , 0x2886f9dd , 0x2f89c305 , 0xf4686d7b , 0x0731b860 , 0x1439c95b , 0x71152511 , 0x178c802f ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x99d8c8ec , 0xe07193dc , 0xe10d4f1e , 0xe260a99c , 0x55a6ed0f , 0xee85838a , 0xf3ed613c , 0x1907aa8e ] )
+  , Mont::unsafe_make( [ 0x15ef413f , 0x5569c91a , 0xcc037098 , 0xed508e9d , 0x5f1607c6 , 0xbeb0e00b , 0x237ccbbd , 0x02f86ccf ] )
+  , Mont::unsafe_make( [ 0xdd4ec970 , 0x9217b4ed , 0x07d8c341 , 0xc76e6901 , 0xd48dfc3f , 0x8c99fdcc , 0xdd2cecfa , 0x24fb507b ] )
+  , Mont::unsafe_make( [ 0xbd4676b0 , 0xa0636b10 , 0x89333511 , 0xa92eb28b , 0xfee20f76 , 0xa4017227 , 0x896678c3 , 0x0e59192b ] )
+  , Mont::unsafe_make( [ 0xa9c38bd8 , 0x657eaeb3 , 0x09b1e72c , 0x03b4f6b6 , 0xd2ba1dac , 0x747540f2 , 0x6a42f9c1 , 0x1a461bda ] )
+  , Mont::unsafe_make( [ 0x05c7789e , 0x6dca7f57 , 0x332a0356 , 0x02df1ca7 , 0x901751fa , 0x04ef4fbc , 0x9d4fcf78 , 0x2b254ca6 ] )
+  , Mont::unsafe_make( [ 0x4c033fbb , 0xf58a5d2c , 0x68d3b313 , 0xc3d62b93 , 0xd228e809 , 0xcfe4a020 , 0x9ea731f5 , 0x066982ce ] )
+  , Mont::unsafe_make( [ 0x220a620c , 0xc7d0945a , 0x381e51a4 , 0x302914d6 , 0x61acb21c , 0x41737fcc , 0x62a53825 , 0x08bf6403 ] )
+  , Mont::unsafe_make( [ 0x16881fae , 0x4fac9889 , 0x6281ebb3 , 0x91f7979e , 0x0067123d , 0xb761676a , 0xd214c7aa , 0x2bbd84de ] )
+  , Mont::unsafe_make( [ 0x162dd0fb , 0x795bc557 , 0x15a5efe7 , 0xac09f544 , 0x6e628f19 , 0x49aa716e , 0x03396a54 , 0x10ad5557 ] )
+  , Mont::unsafe_make( [ 0xb4fa634c , 0x256cc477 , 0x0776e48e , 0xea36bb35 , 0x091df79f , 0xf07dcc95 , 0x924500ef , 0x0d8a6ad7 ] )
+  , Mont::unsafe_make( [ 0xa61b26bb , 0x8a0aba46 , 0x644cff17 , 0x834ab2ea , 0x3c8acfbf , 0xaadfbd30 , 0x0555cb6b , 0x1b96c074 ] )
+  , Mont::unsafe_make( [ 0xb6a95ef8 , 0x5b048a96 , 0x8a67762f , 0xdec668a1 , 0x56c0add3 , 0x7250a11b , 0x6b8f7cfd , 0x17eb72e0 ] )
+  , Mont::unsafe_make( [ 0x1c12d95a , 0x91770381 , 0x1efa13f4 , 0x00648ddf , 0xbff20718 , 0x6dfd151c , 0xaf90c217 , 0x0193de9f ] )
+  , Mont::unsafe_make( [ 0x0ab1dee8 , 0x710ab91c , 0x55c7cdea , 0x614dbfc6 , 0x63ffad14 , 0xbdb864e4 , 0xb53f99e5 , 0x1bc1bde6 ] )
+  , Mont::unsafe_make( [ 0x00ad8009 , 0x40eb91d5 , 0xc5ae9ed4 , 0x567da7d6 , 0x97afdd51 , 0x836e7f92 , 0xf961033a , 0x1eeb368c ] )
+  , Mont::unsafe_make( [ 0x1d4fc35c , 0xec4b1b2b , 0x01ed1b78 , 0xd8f2a55f , 0xc6307cd7 , 0x6c6ba3f3 , 0xba621165 , 0x2e98d525 ] )
+  , Mont::unsafe_make( [ 0xff0e86ae , 0x8fc529b0 , 0xfed665b7 , 0xfac08042 , 0x138d2ba0 , 0xa3926244 , 0x067672c8 , 0x10e3fa16 ] )
+  , Mont::unsafe_make( [ 0x0b3e6162 , 0x7031cb1b , 0x0773c0d3 , 0xfebaa6fd , 0x99ae4559 , 0x87593eb1 , 0x6d4bb0e2 , 0x2fd44afa ] )
+  , Mont::unsafe_make( [ 0x34b815a6 , 0xf89b26d3 , 0xf488f256 , 0xb89b6113 , 0x05e9a0ee , 0x69d0a327 , 0x4ebe7935 , 0x23fbcc5b ] )
+  , Mont::unsafe_make( [ 0xff2b0761 , 0x5abdf7b1 , 0x3ae2474b , 0xd31cd85c , 0x9645a9f6 , 0x18b713cc , 0x1af4a136 , 0x29df729c ] )
+  , Mont::unsafe_make( [ 0xdecf79fa , 0xc762d7e3 , 0xbfa7ba5c , 0x125e3ce1 , 0x9309432f , 0x13706d68 , 0x030de79a , 0x11ad9eef ] )
+  , Mont::unsafe_make( [ 0x00bd1d00 , 0x16e47fb7 , 0x4d02621f , 0x8224c410 , 0x1f26cbc9 , 0xe9e74509 , 0xb57211e8 , 0x1b149b70 ] )
+  , Mont::unsafe_make( [ 0xabc3d515 , 0xd6a7a115 , 0xcafd9124 , 0x7d5d616a , 0x7f4dab23 , 0xaafc7597 , 0xd44cfe2d , 0x137281f1 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x273f81b8 , 0xfe2f6704 , 0x96c4e06d , 0x16e6a137 , 0xe8d9dd47 , 0x633956ae , 0x0c4b0213 , 0x1884d5d9 ] )
+  , Mont::unsafe_make( [ 0x3e135234 , 0x767089c8 , 0x3ad8dc3e , 0xf64ed551 , 0xc753b647 , 0x73c424b3 , 0x78aaa29d , 0x2503f28a ] )
+  , Mont::unsafe_make( [ 0x1f3fb9f9 , 0x25dfdb5d , 0x13708358 , 0x68a934af , 0x5b3b6b6b , 0xa7b80a87 , 0x60b0e003 , 0x06b95c68 ] )
+  , Mont::unsafe_make( [ 0xe3f9c030 , 0x7b9304ca , 0x3b7b4fd3 , 0xe1a6d2ed , 0x8c41d9d0 , 0x32ed7f7c , 0xcf9f74b9 , 0x28d734cd ] )
+  , Mont::unsafe_make( [ 0xd06fc0fd , 0xb52d2720 , 0x167b6fc4 , 0xbd63bf33 , 0x14a83a43 , 0x7764e3eb , 0xf43ec867 , 0x2d6412db ] )
+  , Mont::unsafe_make( [ 0xe40c10f5 , 0x43805cd7 , 0x73a31cb8 , 0x5e8ea832 , 0x861bae6e , 0x78766a6e , 0xc3d0ecb3 , 0x1c064e9d ] )
+  , Mont::unsafe_make( [ 0x5d602b54 , 0x774079d9 , 0x269798c3 , 0x4e9c451d , 0x9efd157b , 0x5f946d33 , 0x162024bf , 0x03d7c331 ] )
+  , Mont::unsafe_make( [ 0xbcdbb7e6 , 0x1a38cf8d , 0xe5c48213 , 0x4935983b , 0x970b133f , 0x9e293efb , 0x912f7b99 , 0x1bdce0fe ] )
+  , Mont::unsafe_make( [ 0xe4b49004 , 0xaa5c5077 , 0x975f1f5f , 0x2e51e4ca , 0x52ba6892 , 0x9a6afab6 , 0x13468f9c , 0x01dd2336 ] )
+  , Mont::unsafe_make( [ 0xb9d7b4ed , 0x63251155 , 0x13d5b264 , 0xe81f4482 , 0xf2805b14 , 0xab0ac065 , 0x7fbc5d68 , 0x2fd58e49 ] )
+  , Mont::unsafe_make( [ 0xf22e0e84 , 0x099848eb , 0x093e1d35 , 0xe5f76dbb , 0x88e0cbe1 , 0x5bac5399 , 0x8dc4afa5 , 0x2404e50a ] )
+  , Mont::unsafe_make( [ 0x127913d1 , 0x6d5faa7a , 0x8392010f , 0xa71bebec , 0x0cdea920 , 0xdcbe8b1f , 0x0e5d36a3 , 0x170796a9 ] )
+  , Mont::unsafe_make( [ 0xfc2cd73f , 0x616f59df , 0xbe3a79c0 , 0xe4012bea , 0x2ea3c4f8 , 0x054bc773 , 0xa669437a , 0x10b288c8 ] )
+  , Mont::unsafe_make( [ 0xa04cf170 , 0xde9289cf , 0x4ff1c57e , 0x60d3b5c1 , 0xb93ba15d , 0x8463a420 , 0x5bcf93e1 , 0x16b8d5da ] )
+  , Mont::unsafe_make( [ 0xa331ee18 , 0xb0c2a798 , 0x5b81dc83 , 0xdb9fc9fc , 0xbe22a3bc , 0x95789a45 , 0xc012fb87 , 0x11ff2bdd ] )
+  , Mont::unsafe_make( [ 0x8c47292d , 0x92fa4286 , 0x68f57e29 , 0x0a7273ec , 0x0529a40c , 0x1186f48a , 0x6a4f0a0d , 0x2e8fe20c ] )
+  , Mont::unsafe_make( [ 0xed97e1fd , 0x21382406 , 0xf24f61a1 , 0xc722c0ab , 0x54fc6886 , 0xf8c0f963 , 0xe4d1cd36 , 0x03b2e8a7 ] )
+  , Mont::unsafe_make( [ 0x304a3b9b , 0x904eadab , 0x81ce68a4 , 0xb48707d4 , 0x6b371848 , 0x80abd51a , 0xf36e315f , 0x2ef6cc30 ] )
+  , Mont::unsafe_make( [ 0x8d5aec85 , 0x531a10d8 , 0x9c40861c , 0xa4ff7b5e , 0x989996d5 , 0x4a864072 , 0x8798d3a9 , 0x27f98274 ] )
+  , Mont::unsafe_make( [ 0xcb930d38 , 0x3da858c7 , 0x56282691 , 0x8d2ec3fe , 0xbd18b65b , 0x3fba3609 , 0x7678189e , 0x1da1c23d ] )
+  , Mont::unsafe_make( [ 0x33989153 , 0x87f92afa , 0x948db2c8 , 0xfe934029 , 0x2083e68a , 0x56d38496 , 0x2ceca12a , 0x163c8215 ] )
+  , Mont::unsafe_make( [ 0xf541a3cc , 0x90e10105 , 0x118bf9b2 , 0x6d39b6ef , 0x40033c77 , 0xc3ff0135 , 0x98e58f09 , 0x2428e464 ] )
+  , Mont::unsafe_make( [ 0x7acbd693 , 0x37346e4e , 0xe4e2a4b5 , 0x4fed0f7c , 0x223f74d4 , 0xea5ce8eb , 0x1760a358 , 0x0bb884c4 ] )
+  , Mont::unsafe_make( [ 0x1bf9d517 , 0xb24b052c , 0x7b061d89 , 0x6917dd74 , 0x0c277ab2 , 0xb14819bb , 0x66372c7e , 0x21ccccbc ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x46e0633d , 0x70045418 , 0xefc5e7b9 , 0x32411f8d , 0x63b6cdcb , 0xa609c509 , 0x632113f9 , 0x245b5477 ] )
+  , Mont::unsafe_make( [ 0x15c615f8 , 0xa69bba9b , 0xbf682b2d , 0xbaf6bf38 , 0x97915cc3 , 0x1ad1ebf6 , 0x9014079e , 0x1a369d23 ] )
+  , Mont::unsafe_make( [ 0x85998f9c , 0xa53d248b , 0x524deed3 , 0xb8ff9469 , 0x3d4fd9f2 , 0x0ca0351e , 0x6238665d , 0x01cbd1c0 ] )
+  , Mont::unsafe_make( [ 0x2151291f , 0xad8cdf2b , 0x57e3ff10 , 0x471885c9 , 0x38ce5821 , 0xfc158024 , 0xaa2d0a7a , 0x18ff556c ] )
+  , Mont::unsafe_make( [ 0xea9f582e , 0x7d001570 , 0x74e0b0d6 , 0x4b39ef65 , 0xe5de770d , 0x2c230474 , 0xf5bdcd2f , 0x24f77c0a ] )
+  , Mont::unsafe_make( [ 0x7f989beb , 0x97e532d9 , 0x958702e2 , 0xcf1a55c7 , 0xf717167c , 0xb42d9a89 , 0xf49cd6a9 , 0x1db8a81c ] )
+  , Mont::unsafe_make( [ 0x24029bf6 , 0xde450031 , 0xb3c4bd22 , 0x17a8b528 , 0x41781ccc , 0x11f7f2b7 , 0x43b463bd , 0x03bdb4c6 ] )
+  , Mont::unsafe_make( [ 0x818a519f , 0xae081f66 , 0x42b9cda7 , 0x1a2b0cb6 , 0x62d1e10a , 0x85043c22 , 0x9a579c88 , 0x1835a3c7 ] )
+  , Mont::unsafe_make( [ 0x891e6ead , 0x9f79a0dc , 0x0b4d0eb3 , 0x11c8b64e , 0xf785a795 , 0x4a62c80c , 0x938d0f83 , 0x2ebf4e4b ] )
+  , Mont::unsafe_make( [ 0x86f8caf2 , 0xb98e6965 , 0x9347a2c8 , 0x83c5f948 , 0x47665176 , 0xb97f78f9 , 0xbfe5da4a , 0x06b29771 ] )
+  , Mont::unsafe_make( [ 0xe0e8013f , 0xe6bb1d65 , 0x70a61f6b , 0xa224d8a3 , 0x04109819 , 0xf77cd8c6 , 0xdf39fa4f , 0x2a99a930 ] )
+  , Mont::unsafe_make( [ 0x21be424b , 0x8a3f75bc , 0xdfaf0568 , 0xa21a5fc8 , 0x68cbb0cc , 0x9148a67b , 0xdbc43408 , 0x1bf8a97a ] )
+  , Mont::unsafe_make( [ 0x29106ad6 , 0x4040ae7c , 0x1faf5fb0 , 0x50ebf1b2 , 0x6b1dc682 , 0x9e20fd58 , 0xc6840d51 , 0x2d570bd5 ] )
+  , Mont::unsafe_make( [ 0x970d84d6 , 0xb38a7283 , 0x2827fc2f , 0x005afb61 , 0xb7555d80 , 0x760df588 , 0x5c55a2a4 , 0x235a96dc ] )
+  , Mont::unsafe_make( [ 0x03cb5a14 , 0xbfa859cb , 0x9610751a , 0xde6d8cdb , 0x98234aab , 0xb9d8db06 , 0x22736b40 , 0x140c1c92 ] )
+  , Mont::unsafe_make( [ 0xccb7ccde , 0x3b39ffa3 , 0xd00134ef , 0x66c31f6b , 0xb01d3e57 , 0x05cf0baf , 0xd50b1fcd , 0x2d00b88f ] )
+  , Mont::unsafe_make( [ 0xa2aa583a , 0x67bb1639 , 0x95ee7c46 , 0x19b1e2b6 , 0xe8a6cd4e , 0xc5216e71 , 0xac113456 , 0x05e1fae2 ] )
+  , Mont::unsafe_make( [ 0xb6f2fd4e , 0xd0056685 , 0xada661df , 0x078ef186 , 0xa917fbfb , 0xb18ef632 , 0x1a143495 , 0x262e83f4 ] )
+  , Mont::unsafe_make( [ 0xa1d1c8fe , 0x3492ed17 , 0xe30afae9 , 0xc596bd0e , 0x780f8cf7 , 0x58789b76 , 0xebd45a76 , 0x09a58119 ] )
+  , Mont::unsafe_make( [ 0x6a6df291 , 0xa8203671 , 0xe892eb13 , 0x2f80c128 , 0x83750d52 , 0x28e170ae , 0x2d639ae8 , 0x2f92c22c ] )
+  , Mont::unsafe_make( [ 0x0516e1fe , 0xd1c5e26b , 0x76284cce , 0x1284d2ae , 0x6b6c9160 , 0xce410862 , 0xf96f063f , 0x19cdc666 ] )
+  , Mont::unsafe_make( [ 0xa7d72b6e , 0xc9eecc88 , 0x34452b30 , 0x53426bb8 , 0x82008557 , 0x693ce346 , 0x841adad5 , 0x01862d13 ] )
+  , Mont::unsafe_make( [ 0x914dbc58 , 0xf1fa0c52 , 0xc3f533ca , 0x36bef2fd , 0xef7ecbe5 , 0x8bc2f9b2 , 0x646595ab , 0x1bca38aa ] )
+  , Mont::unsafe_make( [ 0xbe8ee857 , 0xd4ddb6ca , 0x388cd4f9 , 0x8822dc0a , 0x0315898e , 0xfaf1064a , 0xfb8d94e2 , 0x1dead782 ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0xa11d361f , 0xbc5922bc , 0x895e79fc , 0xe5d5e899 , 0xf5308eaa , 0xe8f8997e , 0x25a9812d , 0x2c449559 ] )
+  , Mont::unsafe_make( [ 0x3e317950 , 0x3fd7f1f2 , 0xa2cc6d00 , 0x75cba543 , 0x76813f65 , 0x26cb81d4 , 0xa06e56f2 , 0x0e16b8b8 ] )
+  , Mont::unsafe_make( [ 0xce07e9ee , 0x16b117a1 , 0x29a72a0c , 0x128f3a90 , 0xb4139c8a , 0x820aae3e , 0xdebbdf65 , 0x02e75592 ] )
+  , Mont::unsafe_make( [ 0xe88074b6 , 0xeb244146 , 0x9ab52a0d , 0x9d9e4554 , 0x46f99364 , 0x3999eb9f , 0xcd747c0c , 0x0cb9efbe ] )
+  , Mont::unsafe_make( [ 0x7c570c25 , 0x5c604bdf , 0x551e7de6 , 0x66b3338a , 0x80d9c1a7 , 0x9a2b4f06 , 0x7598f0ec , 0x0c06814f ] )
+  , Mont::unsafe_make( [ 0x1b4ba57b , 0x791531c8 , 0xf21763bc , 0x3195df9e , 0xc4997b5b , 0x03944aac , 0xadd4fea6 , 0x23a224de ] )
+  , Mont::unsafe_make( [ 0x224cf4c6 , 0xe95f3576 , 0x254b77a1 , 0xf2498c39 , 0x0bdf0069 , 0xe5c6d908 , 0x9c1d5d15 , 0x1da842ac ] )
+  , Mont::unsafe_make( [ 0x17c9327e , 0x83d8c44b , 0xb19bae74 , 0x6a55fd73 , 0x06c081e6 , 0xccac7d8f , 0x7db28853 , 0x3042e680 ] )
+  , Mont::unsafe_make( [ 0x778b141d , 0x804e2448 , 0xf5fff9d1 , 0x26b8dfac , 0xbaec89e7 , 0x30b7cdb2 , 0x63bf52c9 , 0x12e61b78 ] )
+  , Mont::unsafe_make( [ 0xab441d92 , 0x68180c2f , 0xc589c0d0 , 0xc797828c , 0x063f5980 , 0x0985b581 , 0x3f6628a2 , 0x123014ad ] )
+  , Mont::unsafe_make( [ 0x7156354d , 0xfb0af735 , 0x73afaa96 , 0x8d63dd1c , 0x9a549b54 , 0x26ae076f , 0xf49768f7 , 0x0145560d ] )
+  , Mont::unsafe_make( [ 0xe4a427e1 , 0xe7724b90 , 0x9e4fb3df , 0xb1684df1 , 0xc5cbcee6 , 0xe8fc26e1 , 0x0ced5004 , 0x16435492 ] )
+  , Mont::unsafe_make( [ 0x0a73390a , 0xd671561d , 0xae2ce65e , 0x04439127 , 0x75cca26b , 0x2129490d , 0xff3192c0 , 0x2b93c8e2 ] )
+  , Mont::unsafe_make( [ 0xa3399aff , 0x51fca18e , 0x64bba7bd , 0x0fc2442e , 0x19168537 , 0xa73b58b8 , 0x29812c5d , 0x1c9e3499 ] )
+  , Mont::unsafe_make( [ 0x46f125d1 , 0xfc1707ab , 0xc9cbe87b , 0x312388ff , 0x24aa0ff4 , 0x2e33bdc1 , 0x0539002e , 0x10cb1e0c ] )
+  , Mont::unsafe_make( [ 0x067bb660 , 0x4b5a52ff , 0x5e3cea1c , 0x34602a01 , 0x9ca59663 , 0xd650b3d5 , 0x3a2e11b1 , 0x2eb3b548 ] )
+  , Mont::unsafe_make( [ 0xcc4be800 , 0x445307eb , 0x0773b1b0 , 0x8b377d0f , 0x293d32b0 , 0x93c072f8 , 0x75416e48 , 0x05d9d768 ] )
+  , Mont::unsafe_make( [ 0x39163dac , 0x13df3aa1 , 0xad37dca1 , 0x7088af43 , 0x6b0c861c , 0x407f5995 , 0x1d62e985 , 0x061cf59f ] )
+  , Mont::unsafe_make( [ 0x8bc97892 , 0x00710ae1 , 0xf248ab1e , 0x287cd7c3 , 0x756ac8a7 , 0x942a93fd , 0xd4d20d28 , 0x1143ee6a ] )
+  , Mont::unsafe_make( [ 0x8287626c , 0xc07abb56 , 0x994929a5 , 0xd084236f , 0xe777eafe , 0xf626f312 , 0x5eddb704 , 0x29997758 ] )
+  , Mont::unsafe_make( [ 0x696aa392 , 0xd21e678e , 0xc590a5f2 , 0xd9650fb9 , 0xa11aff30 , 0xfce0d132 , 0x91f91fdb , 0x0f015660 ] )
+  , Mont::unsafe_make( [ 0x7de9a65f , 0xb8cf5306 , 0xf8443ae3 , 0xa70f6522 , 0x74c16a43 , 0xe94a1655 , 0xd0b56e7d , 0x11271637 ] )
+  , Mont::unsafe_make( [ 0x5cb97d73 , 0x56453617 , 0xeb41fa7c , 0xdb9d4902 , 0xe613c3bf , 0x84e61e2a , 0xea56c82c , 0x07d7ca84 ] )
+  , Mont::unsafe_make( [ 0x04c97303 , 0x1fff6784 , 0x1a2f8a14 , 0xeadd2f4e , 0xa054c72a , 0x56a39c39 , 0xffc964b6 , 0x30006ffe ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x668261bf , 0x9b5f72ed , 0x0e7047c5 , 0xf3cc6513 , 0x2008832d , 0x4f7e9a0c , 0xb7fe77ab , 0x02230209 ] )
+  , Mont::unsafe_make( [ 0xe106cea5 , 0x423d3ba8 , 0x2a999184 , 0x6ef8c402 , 0x8219f5c1 , 0x04f57e5e , 0xde047e60 , 0x0837833f ] )
+  , Mont::unsafe_make( [ 0x43d8e42a , 0x9c734a2c , 0xc7a05b96 , 0xb077ab3e , 0xeb5b7575 , 0x7999b371 , 0xfab4b1e2 , 0x27ff25df ] )
+  , Mont::unsafe_make( [ 0xdfa3c23b , 0xec760f4c , 0xaf7f8a64 , 0x224b83af , 0x65a834e8 , 0x16af95d8 , 0x7031f8ef , 0x2d114d21 ] )
+  , Mont::unsafe_make( [ 0x3ab9520c , 0xf2177e58 , 0xcb5d7819 , 0x27ddae16 , 0xdb770485 , 0xaa7198bf , 0x83245bb0 , 0x2490ef0d ] )
+  , Mont::unsafe_make( [ 0x23e80e35 , 0x8dcc8896 , 0xc10688c1 , 0x8b86dead , 0xf921a75e , 0xca286464 , 0x3b7f164b , 0x216e5b4c ] )
+  , Mont::unsafe_make( [ 0xc8c0e15e , 0xa363bd1f , 0x1bcf7c74 , 0x8e3f9da6 , 0xca3ed85c , 0xfd132e59 , 0xeeafb6a2 , 0x09484016 ] )
+  , Mont::unsafe_make( [ 0x22438fc1 , 0x4c7295c4 , 0x39b91d84 , 0x812a6635 , 0x1ed8267b , 0x901fd44e , 0x28e9dbae , 0x155583c2 ] )
+  , Mont::unsafe_make( [ 0xcb670244 , 0x7f6f6d1e , 0xc37c5a83 , 0xe937b6a7 , 0x79d21101 , 0x58771a87 , 0x2c3d005c , 0x2e17995c ] )
+  , Mont::unsafe_make( [ 0xc3d1a18e , 0xa6913906 , 0x69703125 , 0x14b3bb25 , 0x931f6cf6 , 0x62bca3cf , 0xee7ec83f , 0x231a2528 ] )
+  , Mont::unsafe_make( [ 0x1af51b65 , 0x84616c28 , 0xf450ee5d , 0xad441d92 , 0x99bf572d , 0x5fa6b99a , 0x6a20572e , 0x0e468513 ] )
+  , Mont::unsafe_make( [ 0xe1878ec1 , 0xb3693399 , 0x5d2db493 , 0x877cca65 , 0x27ccd54d , 0x5bf252c5 , 0xc08d35d2 , 0x27c9e2ea ] )
+  , Mont::unsafe_make( [ 0xd08c2c81 , 0x8a05e166 , 0xa80eed2c , 0x52482108 , 0x55e7aace , 0x0b4fc491 , 0x62d43239 , 0x1b5f306e ] )
+  , Mont::unsafe_make( [ 0x80727b49 , 0x6ed84e0d , 0x42053b15 , 0x4f965680 , 0xf76b23be , 0x4889e582 , 0x3892b6a8 , 0x2ddc5103 ] )
+  , Mont::unsafe_make( [ 0x213d0616 , 0xe8dad128 , 0xa3f940bf , 0x8aedaa3e , 0x0923ffe4 , 0x43281eff , 0xb9e766d2 , 0x22ef75e2 ] )
+  , Mont::unsafe_make( [ 0x188d0fa5 , 0x6788f676 , 0x7c76adbe , 0x109f2883 , 0xa2a07339 , 0xd8682605 , 0xc158945f , 0x05e580e0 ] )
+  , Mont::unsafe_make( [ 0x368cd027 , 0x527612e4 , 0xa1e1bdca , 0x8413c12e , 0x5b14332e , 0x0d630d9b , 0x383aa93b , 0x062d1282 ] )
+  , Mont::unsafe_make( [ 0xcd3fd056 , 0x97179f67 , 0x52e653a7 , 0x05bb5b53 , 0x9a92a67e , 0xe1f8b608 , 0x292162f5 , 0x304a6570 ] )
+  , Mont::unsafe_make( [ 0x4dbb2a30 , 0xae2fd7aa , 0xfdefaa3c , 0xb61e5d96 , 0xa5f8b207 , 0x253b47d6 , 0x6f9593a9 , 0x003b43e6 ] )
+  , Mont::unsafe_make( [ 0x9c11a74c , 0x551638b6 , 0x8ca68a5d , 0xc9b26e46 , 0xcf4016a2 , 0x5c3bcdfc , 0x5cdfd4d5 , 0x1dcffbbd ] )
+  , Mont::unsafe_make( [ 0xa06e3a9f , 0xb2d637a6 , 0x410a82de , 0x34dca86c , 0xad7c1fb5 , 0x3d091111 , 0xe336e9a6 , 0x2f4ba0d6 ] )
+  , Mont::unsafe_make( [ 0x9aa58cb4 , 0x822222ae , 0x619bd333 , 0x743b058b , 0xe7aef8c7 , 0x215d8839 , 0xeb738976 , 0x0b631f6e ] )
+  , Mont::unsafe_make( [ 0x6d549426 , 0x87305a93 , 0xaf7d053f , 0xae872638 , 0x586fe697 , 0x8e778c29 , 0x611a7683 , 0x2dc77226 ] )
+  , Mont::unsafe_make( [ 0x2c9c1fd9 , 0x2ef5412f , 0x705182c3 , 0x5e84753c , 0x0a4a326a , 0x3e9e8cfc , 0x443e4300 , 0x09e277bf ] )
+  , Mont::unsafe_make( [ 0x53effc0e , 0x260f226f , 0x8ca823bf , 0x69685029 , 0x9e9b8b29 , 0xcf41d3be , 0x75cf79fa , 0x2aca1e16 ] )
+  , Mont::unsafe_make( [ 0x5bce75e3 , 0x689fb724 , 0x5cc49743 , 0x223796c2 , 0xe0035a33 , 0xd3cca8db , 0x8ad9ee7a , 0x2fffb60f ] )
+  , Mont::unsafe_make( [ 0x72ca4b40 , 0xe7caaba5 , 0x964d5e28 , 0x1eea41fa , 0x4de1dd30 , 0x68c4c545 , 0x566940d7 , 0x135b8fd2 ] )
+  , Mont::unsafe_make( [ 0x18d2cfd3 , 0x683c6291 , 0x3f070969 , 0x213a4e1b , 0x1bba12e8 , 0x41114472 , 0x677e07e0 , 0x161ba5dd ] )
+  , Mont::unsafe_make( [ 0x5d8ea6c8 , 0x32fef369 , 0xf0f6731e , 0xcc2da73f , 0xc9451c1d , 0xd3e79f09 , 0xbbf6a15e , 0x17ce3bfa ] )
+  , Mont::unsafe_make( [ 0xa4e69aa9 , 0xf66664c9 , 0x2a04ceae , 0xdb6c65d4 , 0x99efb271 , 0x9699247f , 0x08eaf74e , 0x074a297b ] )
+  , Mont::unsafe_make( [ 0x10b213fc , 0xcfbca888 , 0xd6f29e44 , 0xcad2b827 , 0x66526a0e , 0x0ccab1e0 , 0x93784345 , 0x01aa4ac1 ] )
+  , Mont::unsafe_make( [ 0x6fb455e4 , 0x71ebb6b5 , 0x51bc9ed5 , 0xdc9e4bac , 0x161e5ccd , 0xf8742bb0 , 0x56fc744e , 0x0940358d ] )
+  , Mont::unsafe_make( [ 0x7988aec6 , 0x6547ebe6 , 0xfdb7430a , 0xb217d442 , 0x9d313174 , 0x9b83f7b5 , 0xa892dca3 , 0x19e9f642 ] )
+  , Mont::unsafe_make( [ 0xc72e628a , 0xb65476b1 , 0x37e68ac2 , 0xaf9c0c9d , 0x33f7d39b , 0x0dfbe40d , 0xfafdf6a5 , 0x1017e459 ] )
+  , Mont::unsafe_make( [ 0xe065e055 , 0xcc08f5a4 , 0xd0be742b , 0x661ca663 , 0x8fd7fe8c , 0xaf6d6267 , 0x0fbe2fd7 , 0x07d19512 ] )
+  , Mont::unsafe_make( [ 0xf97e3f7d , 0x6a541fd4 , 0xefb7643b , 0xdd188d3d , 0xb08fa29c , 0xdffc095f , 0xdcd78fe6 , 0x29e995d6 ] )
+  , Mont::unsafe_make( [ 0x65daadb3 , 0x65976211 , 0xd805d59f , 0x47a22828 , 0xef3c3802 , 0xf968db07 , 0x5c2f4b08 , 0x13d9629d ] )
+  , Mont::unsafe_make( [ 0x7470a19a , 0x6e2c382d , 0x261c0889 , 0x3a9b8f27 , 0x7e17eaa1 , 0x683726b4 , 0xebaca0f3 , 0x2dfa2c16 ] )
+  , Mont::unsafe_make( [ 0x4a9d807a , 0xf4fd4d29 , 0x79d476da , 0x87b812c6 , 0x0ccdf503 , 0x4de5f7c2 , 0x9b59622a , 0x19383376 ] )
+  , Mont::unsafe_make( [ 0xdd3dd65d , 0x52015f64 , 0xc0baf5d3 , 0x3efbb7b8 , 0xa6819324 , 0x30a5fdae , 0x5303b583 , 0x27a171fd ] )
+  , Mont::unsafe_make( [ 0x81dbb720 , 0x4e5ea42c , 0x201e33f2 , 0x4df045a1 , 0x93da5107 , 0xa923daf8 , 0x40bfa422 , 0x1dc357be ] )
+  , Mont::unsafe_make( [ 0xfb6a77e2 , 0x76e0fa82 , 0xbf87c46e , 0x79e000b2 , 0x468ae34f , 0x501c0473 , 0x4d9c8cc1 , 0x01ceb8bc ] )
+  , Mont::unsafe_make( [ 0x554eec8e , 0x028f7198 , 0x739494dd , 0xf735edcb , 0x3e12e431 , 0xcd017da0 , 0xfc399531 , 0x1852b4c7 ] )
+  , Mont::unsafe_make( [ 0x76f842a2 , 0x66e8a290 , 0xd9a88016 , 0x9cad939f , 0x6221e239 , 0xffddb3b7 , 0xedeacf3c , 0x26862c2c ] )
+  , Mont::unsafe_make( [ 0xbf1a7b75 , 0x24568b1f , 0x9367a232 , 0x0279709c , 0x1e81ca61 , 0xf7ea9875 , 0x7b941190 , 0x2e2b5c8f ] )
+  , Mont::unsafe_make( [ 0x863f1898 , 0xd4291d59 , 0xc6d56829 , 0x39bc5bea , 0x77dd0b02 , 0xf074662f , 0x04070d22 , 0x0915424c ] )
+  , Mont::unsafe_make( [ 0x9f819048 , 0xdfdcaa4f , 0x47ad13f6 , 0xa524edd5 , 0xcd8adaee , 0xc7fd533e , 0xd151e729 , 0x04899704 ] )
+  , Mont::unsafe_make( [ 0x23b4794f , 0x07866d37 , 0xf05232df , 0xb7e8e0ee , 0x35438453 , 0xcd4032da , 0x709d059a , 0x16256ed4 ] )
+  , Mont::unsafe_make( [ 0xaf3bfb3f , 0xf1537145 , 0x8826d2a6 , 0x1ba793c3 , 0x76398639 , 0x2600b676 , 0x015777f5 , 0x093d4f42 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
@@ -0,0 +1,2496 @@
+
+// circomlib's Poseidon constants for t=14
+
+use crate::bn254::montgomery::*;
+
+
+//------------------------------------------------------------------------------
+
+// round constants (t for external, 1 for internal rounds; flattened)
+pub const CONST_C: [Mont; 182] = 
+  [ Mont::unsafe_make( [ 0x6b8ed122 , 0x70ddd0b1 , 0xb8b662f1 , 0x732d246e , 0x00319450 , 0x00e576b2 , 0xbd9020b6 , 0x211da55e ] )
+  , Mont::unsafe_make( [ 0x707fea56 , 0xd598b7e4 , 0x50f2813a , 0xdf6810de , 0x89042f43 , 0x18f1dfce , 0xe288662d , 0x2fbc04e4 ] )
+  , Mont::unsafe_make( [ 0x372860d1 , 0xa882a8bd , 0xddfbf9e7 , 0x2d51f69c , 0x56ae8f14 , 0xada52f4e , 0xa11b679a , 0x1251bac3 ] )
+  , Mont::unsafe_make( [ 0x380a1caf , 0xdaa49fda , 0x8a2c8dfb , 0x5346bc20 , 0xa07f14e5 , 0x5fad4934 , 0x2d33a5b9 , 0x26f251f3 ] )
+  , Mont::unsafe_make( [ 0xbe690428 , 0x21e2016a , 0x770c6e29 , 0x7df7b7ab , 0x3cca2c2e , 0x1f2b0c96 , 0xca2ad51a , 0x1403ef34 ] )
+  , Mont::unsafe_make( [ 0x5ecc42a8 , 0x088216a7 , 0x0ca7fdcd , 0xcafcaae0 , 0x359f5141 , 0xbe8c326e , 0xbe39afbb , 0x2d6b3087 ] )
+  , Mont::unsafe_make( [ 0x20868294 , 0x65ab3cfb , 0x1a6f61c5 , 0x231ed4be , 0xf26a6c57 , 0x6204308c , 0x7a1fefb4 , 0x185ec828 ] )
+  , Mont::unsafe_make( [ 0x596a023f , 0x3b591a85 , 0xc375afba , 0x796e2edc , 0xae6f2fd3 , 0xbf398ef1 , 0x56bea794 , 0x265fd4ec ] )
+  , Mont::unsafe_make( [ 0x591d6688 , 0x2cd8f84a , 0xf9b97062 , 0x29ffefff , 0x3233cc43 , 0x785d2b16 , 0x941e1722 , 0x1afe65e1 ] )
+  , Mont::unsafe_make( [ 0xcdf17565 , 0x4fd009a7 , 0xdf0f1e93 , 0x7f39c5a9 , 0x197bd9a0 , 0x8cff6407 , 0x410eecb0 , 0x23fcb578 ] )
+  , Mont::unsafe_make( [ 0x81185b7d , 0x8debf35a , 0x73248fbf , 0x7fbb1780 , 0xdbbd1966 , 0xd1714568 , 0x6f3605e8 , 0x28f595c2 ] )
+  , Mont::unsafe_make( [ 0x20d87205 , 0xe859f6c0 , 0x033f89e0 , 0x613d958a , 0x96e44c7e , 0x83d75dcc , 0x7dc0148d , 0x1eeab5bc ] )
+  , Mont::unsafe_make( [ 0xd66863fb , 0xa80a9d13 , 0x9a52ed2e , 0xcca9e203 , 0xd7c6bbfa , 0xf6c099a8 , 0x42f88ddd , 0x017c8713 ] )
+  , Mont::unsafe_make( [ 0x7d8df168 , 0x41c6bfe6 , 0x9611b672 , 0x4c8950f2 , 0x474068d9 , 0x1ea4c8f6 , 0x497049fb , 0x11daeed0 ] )
+  , Mont::unsafe_make( [ 0x3103fb34 , 0x7b78efd1 , 0xb5ab654c , 0x817e4677 , 0x780272a3 , 0x87312eb7 , 0x944e8bb7 , 0x24ebc1e9 ] )
+  , Mont::unsafe_make( [ 0xf4a8234b , 0xeef39b4c , 0x1fbac4f2 , 0x1e1aaede , 0x9068b64e , 0xa98b5a4d , 0xd4757ec1 , 0x22184312 ] )
+  , Mont::unsafe_make( [ 0x72e1fa7f , 0x26415942 , 0xb45dd238 , 0x07a68307 , 0x89163332 , 0x8ff716de , 0x927f719a , 0x1abbc0f3 ] )
+  , Mont::unsafe_make( [ 0x6727a68d , 0xe32d531f , 0x7ccb4d2b , 0xb3061ad8 , 0xb73d45a7 , 0x492512ba , 0x331db1eb , 0x10a04eec ] )
+  , Mont::unsafe_make( [ 0x74ad5b72 , 0xe503e6df , 0xbe596bd5 , 0xab4405fb , 0xd0bffaa5 , 0xb558c644 , 0x8f9de9f3 , 0x13e01e1d ] )
+  , Mont::unsafe_make( [ 0x6cf6a1fd , 0x6d794c02 , 0x27db57a6 , 0x36670049 , 0x6f36475f , 0x4df74077 , 0xee89db8e , 0x18a0cec6 ] )
+  , Mont::unsafe_make( [ 0xe3139e0f , 0xebc6f906 , 0xd1be26ac , 0x3634a527 , 0xce26e61d , 0x4b6a05ea , 0x71912209 , 0x0dc41e3d ] )
+  , Mont::unsafe_make( [ 0xb3e7d254 , 0x398532ea , 0xc523ba39 , 0x8607239e , 0xd17dfd6e , 0x45771030 , 0x515f4d57 , 0x21ac0435 ] )
+  , Mont::unsafe_make( [ 0x6b020008 , 0x0f77d7ad , 0x58df1b70 , 0x11a297da , 0x23b4246b , 0x91eaa2a2 , 0xc005e01d , 0x1c58cde0 ] )
+  , Mont::unsafe_make( [ 0xe5442fd7 , 0x7fce0b81 , 0x36590ded , 0x27356e13 , 0xc9f37e47 , 0x9b62b7bc , 0x3a74a2e5 , 0x120345c3 ] )
+  , Mont::unsafe_make( [ 0x0cab138f , 0xbfeac7ae , 0x11bf2d0a , 0xa79846a0 , 0xabc9ef66 , 0x37232e6e , 0x0baace10 , 0x15e8c383 ] )
+  , Mont::unsafe_make( [ 0x9ef75568 , 0xc98ff6dd , 0x9e2c65fd , 0x0fe3e968 , 0x68cbe7c0 , 0x528ee0dc , 0x4431c06f , 0x22c473f4 ] )
+  , Mont::unsafe_make( [ 0xba8b57a8 , 0x872dc1d4 , 0x213f860b , 0x143485ab , 0x63d3f933 , 0x51ab6b0a , 0x6727baa2 , 0x083a1951 ] )
+  , Mont::unsafe_make( [ 0xd762e1f7 , 0x347a2b95 , 0xa9fff078 , 0x7ea51a88 , 0x551b034d , 0xb18a69cd , 0x65947ab1 , 0x2336c93a ] )
+  , Mont::unsafe_make( [ 0xe1708f81 , 0x5f592890 , 0x42985ee5 , 0x262b27fe , 0x7e970884 , 0xb00372ca , 0x526c7625 , 0x174b2610 ] )
+  , Mont::unsafe_make( [ 0xb9c36bbf , 0xfe7199db , 0x552d28bd , 0x40f52002 , 0xf5d3f078 , 0x126db77a , 0xa152d639 , 0x0670e395 ] )
+  , Mont::unsafe_make( [ 0x039fe0c9 , 0xc891b45f , 0x46c24bd8 , 0x87d8f95d , 0x4d8bd707 , 0x6d461075 , 0xea62b1c6 , 0x29da110f ] )
+  , Mont::unsafe_make( [ 0xf3024cb5 , 0x7c84a8d0 , 0x96037123 , 0xd328626c , 0x2ba78bfb , 0x1729287f , 0xcd898a92 , 0x2fd76b08 ] )
+  , Mont::unsafe_make( [ 0x87463f72 , 0x9459462f , 0x5e33b580 , 0x63d3e2af , 0xf50d9512 , 0x909a3d5f , 0xd3f9f9db , 0x24617740 ] )
+  , Mont::unsafe_make( [ 0x1bd35c54 , 0x11ef1991 , 0x7b0b6373 , 0x81399ef8 , 0xb36aee49 , 0x735ae057 , 0x5253ad79 , 0x0b103ca1 ] )
+  , Mont::unsafe_make( [ 0x9cd33e76 , 0x3cf51ab7 , 0x7a3973fc , 0x3747ac2a , 0xaa1164df , 0x0a33c97c , 0xa67e90b7 , 0x005c37f0 ] )
+  , Mont::unsafe_make( [ 0xdf9d1d5a , 0xc8855dfe , 0x72e7af7d , 0x2596b737 , 0x0fecf793 , 0x309fc850 , 0x356bda64 , 0x1af4a467 ] )
+  , Mont::unsafe_make( [ 0xda3d8a11 , 0xda461654 , 0x5dcf389c , 0x9fc2238a , 0xc71d7c14 , 0xa9bd7499 , 0xa6da086c , 0x078059b7 ] )
+  , Mont::unsafe_make( [ 0x6c2e311c , 0x44ded09d , 0x14850159 , 0x7823273a , 0x19356136 , 0x5b72aa76 , 0xd3f70196 , 0x240342ae ] )
+  , Mont::unsafe_make( [ 0x0d10f66c , 0xb659d5c6 , 0x8a7df30a , 0xae31cb9d , 0x5df249c4 , 0x48713c1b , 0x8fee5e0e , 0x258b2a43 ] )
+  , Mont::unsafe_make( [ 0xeac1cba2 , 0xea6a0ec6 , 0x6ba428dc , 0x3389dd57 , 0x9250ee59 , 0xfa711c64 , 0x88919488 , 0x26d550c7 ] )
+  , Mont::unsafe_make( [ 0x70c3d10f , 0xcadbb61f , 0x12c191f4 , 0x081c8206 , 0x0e93d52a , 0x43914652 , 0xec58c296 , 0x1b47810a ] )
+  , Mont::unsafe_make( [ 0xa72aa239 , 0x9b4c3b09 , 0x9d20c0b9 , 0x29c23023 , 0xc3e04322 , 0xf3c2930e , 0x56523a3c , 0x25c49c66 ] )
+  , Mont::unsafe_make( [ 0x1d0e551f , 0xe11a0215 , 0x0f2a564d , 0x6cea2544 , 0x024e2bd6 , 0x752045f1 , 0xa37f93bb , 0x15acb688 ] )
+  , Mont::unsafe_make( [ 0xa0d81fb8 , 0x25f3356b , 0x1afa2031 , 0xcb04f2fa , 0x44c75ce8 , 0xed7c9084 , 0x5ed3a1fa , 0x28b20040 ] )
+  , Mont::unsafe_make( [ 0xa2f51435 , 0x825d23a9 , 0x38cb87e8 , 0x54dc9652 , 0xd5285c00 , 0x881f78dd , 0x2e99a13e , 0x0964c81d ] )
+  , Mont::unsafe_make( [ 0xa49a7826 , 0xebd72448 , 0x1b0d5642 , 0xb39de369 , 0x1014d989 , 0xccd04054 , 0xa583007b , 0x2cb0d53b ] )
+  , Mont::unsafe_make( [ 0x68282f7d , 0xf37924d6 , 0x885f2ad1 , 0x4acab2a8 , 0x2d611024 , 0x865c6918 , 0x40cb76a6 , 0x14f39bfa ] )
+  , Mont::unsafe_make( [ 0x41196b69 , 0x65aafdbb , 0x9f020efc , 0xfe94abf3 , 0xb12c50bf , 0x591f02c9 , 0xdad67d66 , 0x040096d4 ] )
+  , Mont::unsafe_make( [ 0x2fccdb6e , 0x8559986f , 0x27903a7a , 0x3b002352 , 0x1c3a5402 , 0xf98459bc , 0x045bcc44 , 0x173e3c4a ] )
+  , Mont::unsafe_make( [ 0x81b1be30 , 0xb666dc53 , 0x18e2d5c1 , 0x5c2f082b , 0x54d8eea1 , 0x8e8a5d09 , 0xccafbb8b , 0x293c1c59 ] )
+  , Mont::unsafe_make( [ 0xcb8fe35e , 0xe9a166d6 , 0x28dffa0c , 0xa2a79d7c , 0xa3b092af , 0xd26b2860 , 0x3e3d19e0 , 0x1ac8f31d ] )
+  , Mont::unsafe_make( [ 0xd1eed8a3 , 0x7b903230 , 0x69c4d32a , 0x69d1d4b0 , 0xa8d29688 , 0x1c65b192 , 0x0dc9c1cd , 0x05435acc ] )
+  , Mont::unsafe_make( [ 0x0733d5f0 , 0x8238b25f , 0x5dbe9a7c , 0x6716945f , 0x3d284cda , 0x292e716e , 0x22cebbce , 0x25414f8e ] )
+  , Mont::unsafe_make( [ 0x55bf84de , 0x168915b0 , 0x8d1da8f9 , 0xc3c51cc8 , 0xa7ed5dac , 0xdba95d72 , 0xc29841a0 , 0x1529bbea ] )
+  , Mont::unsafe_make( [ 0x0b7154b9 , 0xc26410a1 , 0xbe6b01a8 , 0xe00bce02 , 0x00f6191b , 0x7b730ecd , 0x18fb8c64 , 0x07f897f1 ] )
+  , Mont::unsafe_make( [ 0x69e8a4bc , 0x9bb767f1 , 0x20df5a1e , 0x972fc873 , 0xb7aeef4a , 0xa74d5def , 0x27a8ecdb , 0x00c4c400 ] )
+  , Mont::unsafe_make( [ 0x82c29059 , 0x1a6501ee , 0x6ffa0ffd , 0x2f999494 , 0x03680bd4 , 0xd9509b6b , 0x06d7df4b , 0x02f040fa ] )
+  , Mont::unsafe_make( [ 0xebbac3fb , 0xacee35a6 , 0x35c964ac , 0x444ba5c2 , 0xa0c4a1c8 , 0xc9646868 , 0x2039f941 , 0x0f1507a1 ] )
+  , Mont::unsafe_make( [ 0x76d5dcf7 , 0x483be6bf , 0x1311b061 , 0x20abb4cd , 0x7bf49cae , 0xa0027114 , 0x93a65563 , 0x2f336de8 ] )
+  , Mont::unsafe_make( [ 0xb4d56404 , 0x7979688b , 0xd5c8e59e , 0xb9006f8d , 0x7b057197 , 0x9d698818 , 0x401bf8b3 , 0x05a2b39e ] )
+  , Mont::unsafe_make( [ 0x272a49e5 , 0x594ecae3 , 0x8f7fe7c8 , 0x23f76e00 , 0x615bfedc , 0x6e2cef30 , 0x0d5049b1 , 0x2f646f62 ] )
+  , Mont::unsafe_make( [ 0xaec3b9f7 , 0xbe3843b4 , 0x4f6adc13 , 0x850d6fe7 , 0x64639972 , 0x3ec097dd , 0x497b3ff2 , 0x1ec3d5b0 ] )
+  , Mont::unsafe_make( [ 0x54025464 , 0xf8eed75b , 0x082ada31 , 0x7142eba5 , 0x5a35bba6 , 0x884d55ed , 0x63e47391 , 0x0d350f2a ] )
+  , Mont::unsafe_make( [ 0x5d6a0d04 , 0x00c572f6 , 0x50534b8a , 0x698ec817 , 0xd0d9a5b3 , 0x99bb940f , 0xe2d8369a , 0x2b1240df ] )
+  , Mont::unsafe_make( [ 0xbd07dcd8 , 0x1894f87a , 0x16eac67f , 0x5e0c015e , 0x647918c9 , 0x6e9be48b , 0xebb950d5 , 0x02618e57 ] )
+  , Mont::unsafe_make( [ 0xc738791e , 0xe6c9aefd , 0x31f6ac64 , 0xda907b3b , 0x03a1187b , 0x495d2b08 , 0xc9da61fa , 0x24446772 ] )
+  , Mont::unsafe_make( [ 0x24a5d7da , 0xeaa88d59 , 0x8fadaa9c , 0x919ed6b8 , 0xab4a7423 , 0x514c606b , 0x83d66274 , 0x2dc2d82c ] )
+  , Mont::unsafe_make( [ 0x822be484 , 0xe96d1cd0 , 0xdbdc43a7 , 0x7397747f , 0x5eebe592 , 0x819ba9a6 , 0x557310fb , 0x2e7be6e6 ] )
+  , Mont::unsafe_make( [ 0xe789bf9c , 0x3cd19332 , 0x22144b26 , 0x54859798 , 0x21684710 , 0x7429c246 , 0xe44620b5 , 0x1304f465 ] )
+  , Mont::unsafe_make( [ 0x3ae494e6 , 0xca202125 , 0xd1f2013e , 0x27f37246 , 0x9ab51463 , 0xc430a1f9 , 0x5b8bee4a , 0x1aaeff49 ] )
+  , Mont::unsafe_make( [ 0x9b8746a2 , 0xe0904acf , 0x68118bf8 , 0x9017cabb , 0x8cce5c6b , 0x1ae3e6b2 , 0x197ac857 , 0x191be0ed ] )
+  , Mont::unsafe_make( [ 0xb06c2aed , 0xade0743a , 0x545ba7fc , 0xb40d0bbb , 0x0ca72419 , 0x4d083d3c , 0x7ff9921f , 0x044f946f ] )
+  , Mont::unsafe_make( [ 0x53993246 , 0x85de6985 , 0x146c2116 , 0xc6dd0120 , 0x71b99e43 , 0x37f9b201 , 0x26e67b20 , 0x1feaa0f9 ] )
+  , Mont::unsafe_make( [ 0xb5a14560 , 0x48032bbf , 0xaa7e9a21 , 0x799e9f9f , 0xf91ce21a , 0x77f1bd8c , 0xb17b0e0c , 0x2e1b5a33 ] )
+  , Mont::unsafe_make( [ 0x5161863b , 0xee68d04c , 0x0166c346 , 0x43294af4 , 0x3d35d2b4 , 0x6d09f273 , 0x4d759aaf , 0x2f8ae815 ] )
+  , Mont::unsafe_make( [ 0xc408add6 , 0x55f5dd9a , 0x787db7e3 , 0x3a2aaa67 , 0xcd687d56 , 0xf280b83a , 0x4bb9a90d , 0x25e3cc28 ] )
+  , Mont::unsafe_make( [ 0x1959de5d , 0xdb4327b9 , 0x5d379eed , 0xd58fc8d3 , 0x086ecb49 , 0x9dbdf1f4 , 0x6a05af9d , 0x0e3c07b3 ] )
+  , Mont::unsafe_make( [ 0xcf5ab442 , 0x3c078bab , 0xac8a11ae , 0xc2b9d92c , 0x650bb220 , 0x8bada39c , 0xac4ba1e9 , 0x23cb91d0 ] )
+  , Mont::unsafe_make( [ 0x9f8f2ab6 , 0x569942ea , 0x7c0d717e , 0x3783c560 , 0xf7d342b7 , 0x1a24a58f , 0xb6e317a4 , 0x305eb0f3 ] )
+  , Mont::unsafe_make( [ 0xb752e02d , 0x1ed00bbb , 0x83149bb9 , 0xd89633c6 , 0x23ca27bc , 0xaf553368 , 0xdc94d344 , 0x0dfbe598 ] )
+  , Mont::unsafe_make( [ 0x9cfb2907 , 0x5d201093 , 0x99ab6463 , 0x5aae2427 , 0x194804ed , 0xe398966b , 0x77758bbd , 0x28f4b756 ] )
+  , Mont::unsafe_make( [ 0x370389cf , 0x3ab9d114 , 0x53563c1d , 0x59965101 , 0xac65cac2 , 0x2921d156 , 0x5295e99f , 0x235a9548 ] )
+  , Mont::unsafe_make( [ 0x3e2432dc , 0x71779ebf , 0xd2e2ea08 , 0xf7d001ba , 0x8399b364 , 0x4370ed4b , 0x2a9fed07 , 0x2f136ec3 ] )
+  , Mont::unsafe_make( [ 0x784816fc , 0xecfbe27a , 0x600623f3 , 0x699fff3c , 0x2fb57aa3 , 0xc47b425a , 0xb0ef9d6d , 0x229d22e8 ] )
+  , Mont::unsafe_make( [ 0xf2e3b2d3 , 0xc6f26f01 , 0xab64f038 , 0xaf4aa0bb , 0x45c0e8ef , 0x31f803ff , 0x7a64d6c8 , 0x088efad8 ] )
+  , Mont::unsafe_make( [ 0x681419c9 , 0x7fc9f7c9 , 0x87e4dcd3 , 0xa038ce83 , 0x4034540d , 0xba0531bb , 0x15d753bd , 0x19fb1f07 ] )
+  , Mont::unsafe_make( [ 0x344c97eb , 0x3513504a , 0xe8bd8cf2 , 0xf4836acb , 0x1724e92a , 0x62aad930 , 0x37fb04cd , 0x2ddc2505 ] )
+  , Mont::unsafe_make( [ 0xb158c710 , 0xb89e50d6 , 0x1d960ee6 , 0x688628f0 , 0x58e3080e , 0x132e930d , 0x392c9f2e , 0x0299c54a ] )
+  , Mont::unsafe_make( [ 0x19d72ee6 , 0x0177d2ee , 0x3a2afee0 , 0x2a00ef11 , 0xc817d544 , 0x0bce932d , 0x4fce5a8e , 0x031bd22a ] )
+  , Mont::unsafe_make( [ 0x0d01c9e6 , 0x44cd2562 , 0xdf405c28 , 0xe24497f1 , 0xb98c6744 , 0x276f051f , 0x83c201a7 , 0x25a5c53a ] )
+  , Mont::unsafe_make( [ 0x7a483dea , 0xd7943ac2 , 0x3411de2f , 0x50e1504e , 0x35d699b0 , 0x901139ce , 0x655ee7a4 , 0x1c0c1f97 ] )
+  , Mont::unsafe_make( [ 0x6eb699f7 , 0xb49b5f8c , 0xfd7c068c , 0xa7205540 , 0xe944d91a , 0x002cc412 , 0x1a5e6acd , 0x299ad010 ] )
+  , Mont::unsafe_make( [ 0xa7b5e95c , 0x3c1259bb , 0x4808cb65 , 0x861d3bec , 0xeb0b9478 , 0x511bf871 , 0x43f15271 , 0x037fa7c8 ] )
+  , Mont::unsafe_make( [ 0x235f8909 , 0x1140564f , 0x6afe20fb , 0xb100268e , 0xdb7bd877 , 0xba96bd62 , 0x0d805ed6 , 0x282d0af9 ] )
+  , Mont::unsafe_make( [ 0x2adb7637 , 0xfcc5b110 , 0xbd877277 , 0x949d8dde , 0xdef2c90b , 0x97c27692 , 0x64072126 , 0x234bef8a ] )
+  , Mont::unsafe_make( [ 0x4e1bec6d , 0xf68c1dc7 , 0x0e32b2ab , 0x8256ca7e , 0x0d1b49c3 , 0x5091491a , 0x93607fb6 , 0x07b23a98 ] )
+  , Mont::unsafe_make( [ 0xde84256d , 0xdd706d5b , 0xacf0b226 , 0x5a90ae23 , 0x57cab37a , 0x3792f6ad , 0x6403320d , 0x1dccd1f8 ] )
+  , Mont::unsafe_make( [ 0x5d0aad67 , 0x90eb51ee , 0xdaf2cfa2 , 0x61b85aa7 , 0x78e54f18 , 0x1458d756 , 0xb337600d , 0x055a9452 ] )
+  , Mont::unsafe_make( [ 0x11839b09 , 0x11bca791 , 0xeec106e7 , 0x354c7c6a , 0xf82eab10 , 0xb86685c3 , 0xe81f933b , 0x2bd15b05 ] )
+  , Mont::unsafe_make( [ 0x008842a3 , 0xf6e1b16f , 0x8e80594b , 0xf79f0d1f , 0x68fecb0f , 0x82cc26f2 , 0xa1bb3726 , 0x1de26de3 ] )
+  , Mont::unsafe_make( [ 0xf55997fe , 0xf7da559f , 0x20d72e72 , 0x3d494dd6 , 0xa4a4e8b7 , 0x1917363a , 0x5b3562d0 , 0x0e11dae9 ] )
+  , Mont::unsafe_make( [ 0x3f335083 , 0xaa13e9c8 , 0x238f3f77 , 0x7124bdf1 , 0x1b3fbdde , 0x73c137db , 0xf50f3820 , 0x103aa2e7 ] )
+  , Mont::unsafe_make( [ 0xf5067a76 , 0x53e8b638 , 0xa54de5af , 0x2b42e6d4 , 0xd8b3cf02 , 0xbe51d9af , 0xe7c627e6 , 0x0212aab5 ] )
+  , Mont::unsafe_make( [ 0x28f4ac27 , 0x88faadc1 , 0xa11f9051 , 0x5d1c9b65 , 0x0e256810 , 0x9261a55b , 0x6bca26a2 , 0x12a0000a ] )
+  , Mont::unsafe_make( [ 0x14d25521 , 0xf05231ea , 0x01ed39ae , 0xf5324a46 , 0xf722556c , 0x532eb61f , 0xfa0bee20 , 0x08d45da7 ] )
+  , Mont::unsafe_make( [ 0x2855e3b7 , 0xb0516ab7 , 0x0a7b1011 , 0x5419bd31 , 0xebc85d23 , 0x704d6154 , 0xfba0026a , 0x1cad8eba ] )
+  , Mont::unsafe_make( [ 0xc0c49c0d , 0x35f58145 , 0xd7b1971b , 0x078ea7ff , 0x08e90307 , 0xa2c4a4d9 , 0x29e8e02f , 0x2c8cce01 ] )
+  , Mont::unsafe_make( [ 0x6aa8d984 , 0xd107ed7a , 0x7fcc7a79 , 0x73662d09 , 0x56301778 , 0x737b2542 , 0x54898a60 , 0x17be6505 ] )
+  , Mont::unsafe_make( [ 0x2c343649 , 0x57394aae , 0xd0d60ad8 , 0x683a86f5 , 0x73c22e82 , 0xec70b8f6 , 0xacc44ebe , 0x22182170 ] )
+  , Mont::unsafe_make( [ 0xf7a4182b , 0x1e513205 , 0x2ce91730 , 0xde4821d0 , 0x502998a9 , 0x438fce75 , 0x082ef6f2 , 0x07b7c26d ] )
+  , Mont::unsafe_make( [ 0xf56462f3 , 0x17fc69f9 , 0x768f4c6e , 0xe26b6843 , 0x7d17db6d , 0xada27db7 , 0x60aead93 , 0x052f31dc ] )
+  , Mont::unsafe_make( [ 0x5591aae4 , 0x3caab0e0 , 0x21f0f0ec , 0xbe124eb5 , 0x19e164d0 , 0x5930614a , 0x532b6475 , 0x0b6de43c ] )
+  , Mont::unsafe_make( [ 0x13b4859d , 0x79cdc06f , 0x86049044 , 0x4f538e23 , 0x38f64656 , 0xc1ee9d77 , 0x1c4b9100 , 0x16f2f9cf ] )
+  , Mont::unsafe_make( [ 0x509da95b , 0xdf6dfbd1 , 0xc6b4f89c , 0x11f30e18 , 0xa0424f0d , 0x684aaecd , 0x190c5780 , 0x210129a3 ] )
+  , Mont::unsafe_make( [ 0x20a21c97 , 0x6e2b72ab , 0xe5729fb8 , 0xe48f83d4 , 0x41fe3ffb , 0x3ab0c940 , 0x32ceaddf , 0x284958f2 ] )
+  , Mont::unsafe_make( [ 0x95f2470d , 0x0ef11f5a , 0xea4bef53 , 0xf9639e7a , 0xa7ad577d , 0xb2d982c4 , 0xba6819ac , 0x2de4fd2a ] )
+  , Mont::unsafe_make( [ 0x7d313dc0 , 0xa87a93d7 , 0xfa63eeeb , 0xe947ef5b , 0xf0ec27ec , 0xac93aee6 , 0x8e9b1781 , 0x038bf3bc ] )
+  , Mont::unsafe_make( [ 0xa00d76a6 , 0xf9cfb198 , 0xcb2a06a6 , 0x39804075 , 0x3ae9403c , 0x1a49ef18 , 0xc5923004 , 0x2eebf305 ] )
+  , Mont::unsafe_make( [ 0xf3e1c087 , 0xdc386be2 , 0x50d750eb , 0xd92f4cb2 , 0x0144c493 , 0x9ccf6152 , 0x23f95afa , 0x2b4f2a4c ] )
+  , Mont::unsafe_make( [ 0xf2e87079 , 0xd05c26f0 , 0x202bb306 , 0xb95082be , 0xec65e161 , 0xfd58ef79 , 0xe06931ec , 0x1860de3c ] )
+  , Mont::unsafe_make( [ 0x02a3af1a , 0xff43db3b , 0xd27bab1a , 0x0b448934 , 0x3559ec21 , 0x3c154a84 , 0x13bd4f12 , 0x251c18c7 ] )
+  , Mont::unsafe_make( [ 0x395023fb , 0x926af5e3 , 0x89b0a57f , 0x4f01a1b2 , 0x099a76c2 , 0x11f0414e , 0x2cafc8c7 , 0x2eb6091f ] )
+  , Mont::unsafe_make( [ 0xcf6df27d , 0xb59c633c , 0x3bb9225f , 0xf65c9a06 , 0x2f41c31b , 0x5c3c91f3 , 0x365be2de , 0x0606e59d ] )
+  , Mont::unsafe_make( [ 0xd2f11287 , 0x6cb96059 , 0x1c44965c , 0x69a4a953 , 0xc82ad1d4 , 0x00983e64 , 0xb36e7842 , 0x261f4180 ] )
+  , Mont::unsafe_make( [ 0xe67f5f05 , 0xbf4b94bc , 0x1a008354 , 0xb26534c8 , 0x2925cd21 , 0x8b1aba78 , 0xca32ffa6 , 0x17e4813f ] )
+  , Mont::unsafe_make( [ 0xcc0143be , 0xc9e9cbda , 0xd9fc12e0 , 0xe73ff9b2 , 0x713c63a4 , 0x2a098212 , 0x34dfe9e2 , 0x0f367514 ] )
+  , Mont::unsafe_make( [ 0xdd82eb4c , 0x7da5a81d , 0xbc1c2536 , 0x06b567e1 , 0xd144f913 , 0xa1ac410b , 0xdb9fd609 , 0x2adc07a2 ] )
+  , Mont::unsafe_make( [ 0x62c29fa7 , 0xa53f896e , 0xdcc14178 , 0x6a80e075 , 0xe0482478 , 0x743a9d8e , 0x076a814b , 0x16075680 ] )
+  , Mont::unsafe_make( [ 0x58b0ccb4 , 0x3dcfedd4 , 0xa48a996b , 0x3dc31d93 , 0xdf7065e8 , 0x728d5de7 , 0x4fdccb4b , 0x21e93617 ] )
+  , Mont::unsafe_make( [ 0x1ad14489 , 0xc7991c37 , 0xe1112345 , 0x97289047 , 0x19723cea , 0x1df25c9e , 0x40a521cf , 0x214beb9e ] )
+  , Mont::unsafe_make( [ 0xb2007a58 , 0x673b797e , 0xfa22b41a , 0x361f1bf0 , 0xc60f12fb , 0xe33dccfd , 0x0bd8adf9 , 0x03b9ef54 ] )
+  , Mont::unsafe_make( [ 0x141e53af , 0xc831e0bf , 0x7ad658e9 , 0xaa64d850 , 0x90fe87de , 0x9c2d6f69 , 0x29659f85 , 0x21978504 ] )
+  , Mont::unsafe_make( [ 0xde38a44f , 0x442ccff7 , 0x47955def , 0x5a41ae50 , 0xd6f7c919 , 0x768e7837 , 0x1fa79e14 , 0x26a1328a ] )
+  , Mont::unsafe_make( [ 0xad411b81 , 0x7581915c , 0x35616f22 , 0x674c5dc0 , 0xcb717132 , 0x16f27086 , 0x1071d973 , 0x2031c5d7 ] )
+  , Mont::unsafe_make( [ 0xb33eccf6 , 0xa61cd08b , 0x38035021 , 0x39c1822f , 0xc6fcc4f7 , 0x73535bb0 , 0x942e9ff0 , 0x1f755255 ] )
+  , Mont::unsafe_make( [ 0xdbc8cd46 , 0xaf985822 , 0xabf1506e , 0x39985163 , 0x3c43c2ae , 0xd1fd21d3 , 0x49c4ae23 , 0x13da9ae6 ] )
+  , Mont::unsafe_make( [ 0x19479b2a , 0x21bd4f8b , 0xe3536648 , 0x0a738340 , 0xc9fb5205 , 0x37552b8d , 0x43566ae5 , 0x093f5cbb ] )
+  , Mont::unsafe_make( [ 0x620fddd7 , 0xb992fca2 , 0x65d8194e , 0xe478d9db , 0x77f4b069 , 0xf41a2cec , 0x350dfb91 , 0x18a425e5 ] )
+  , Mont::unsafe_make( [ 0x89d91f84 , 0xa71f549f , 0x15dc7e40 , 0x6fbfd64b , 0x88890d8e , 0xfbe59eda , 0x7af0fecc , 0x18cdbbf9 ] )
+  , Mont::unsafe_make( [ 0x3ebb3246 , 0xe30cd998 , 0xe3bc2751 , 0x19614ac7 , 0x2e4fc5f5 , 0x37ad0b94 , 0xa9b6432c , 0x19eb3835 ] )
+  , Mont::unsafe_make( [ 0xbb328404 , 0x021bab43 , 0x7a17ba94 , 0x64b04309 , 0x618c04e5 , 0xa905136e , 0x422f4690 , 0x137854ef ] )
+  , Mont::unsafe_make( [ 0x8bf29c74 , 0x4547c583 , 0x2135b42c , 0xa7b8a1f9 , 0xa6a210b3 , 0x7e1c59e0 , 0xbc758c67 , 0x1095e7ae ] )
+  , Mont::unsafe_make( [ 0x8881223e , 0x500137b3 , 0xe561a356 , 0x766cd9cc , 0x628425ca , 0x44062b0e , 0x9ac8bb89 , 0x0963d36a ] )
+  , Mont::unsafe_make( [ 0x54f70eac , 0x7d748e40 , 0xc33a372d , 0xeb6831f9 , 0xb8b30130 , 0x357b5af9 , 0xcc3ae191 , 0x2a4dd354 ] )
+  , Mont::unsafe_make( [ 0x0adf6265 , 0x3d425641 , 0x5db0f250 , 0x64c5eb64 , 0xbae6e3c0 , 0x62225fe3 , 0x9aad3ac5 , 0x2a254497 ] )
+  , Mont::unsafe_make( [ 0xa934106c , 0x7a3915a7 , 0xcd7888e2 , 0xa4188546 , 0x71017a21 , 0x1217f1fa , 0xbc078e55 , 0x2c67f5d2 ] )
+  , Mont::unsafe_make( [ 0x433d53cf , 0x9c0809a6 , 0x1e5efe3f , 0x14be5b9d , 0x3eca367c , 0xc2054c24 , 0x7942b768 , 0x0d9abdc3 ] )
+  , Mont::unsafe_make( [ 0xffcf3b0c , 0xaaaa29f5 , 0x17682d32 , 0x9fcb1233 , 0xa241377e , 0x4860569c , 0x767b195e , 0x0b805785 ] )
+  , Mont::unsafe_make( [ 0x73a92e94 , 0xe4ed4bc1 , 0xdf2db47d , 0x60bba1c1 , 0x9acdfebc , 0x9d4d7617 , 0xe42147e3 , 0x0536a7b5 ] )
+  , Mont::unsafe_make( [ 0xcdc926d0 , 0xffdcce62 , 0x295ffc78 , 0x69a8cd9d , 0x712bef42 , 0x74d44319 , 0x4ee7db60 , 0x21855eb3 ] )
+  , Mont::unsafe_make( [ 0x363bc94f , 0xd38f2b9c , 0x3501bf30 , 0x6d2de533 , 0x4aa3a6e3 , 0xe9a02f97 , 0x563d4fc5 , 0x062cd502 ] )
+  , Mont::unsafe_make( [ 0x81fd56f3 , 0xc95ba34b , 0x3ad8bc35 , 0x9fe2c373 , 0xf3a65330 , 0x8e360180 , 0x8e444a0c , 0x182befc2 ] )
+  , Mont::unsafe_make( [ 0x0000d7b1 , 0xe2ba8310 , 0x97b077c0 , 0xaf15ee3a , 0xe4619190 , 0x1b5a2391 , 0x964cd124 , 0x1a781916 ] )
+  , Mont::unsafe_make( [ 0x5fac7e66 , 0x145c9d5b , 0xabe2c1ee , 0x8583ba56 , 0x948028c8 , 0x2cad94fd , 0x5f2e57d5 , 0x2d342559 ] )
+  , Mont::unsafe_make( [ 0x63a8b45c , 0xba2d4850 , 0x36e97ca1 , 0x5e7c5fcd , 0xe7dfa8eb , 0x2e5326b8 , 0x914b4df4 , 0x2c2733b8 ] )
+  , Mont::unsafe_make( [ 0xdef6b952 , 0x29f767ae , 0x86fdeb37 , 0x5383d847 , 0xa8153ef7 , 0xcb31726e , 0xac219491 , 0x0eb1a665 ] )
+  , Mont::unsafe_make( [ 0xceb916ca , 0xc579b900 , 0x74691c27 , 0xb3845c40 , 0x1a78d36b , 0xd8d05a67 , 0x2ed12b38 , 0x12cc9178 ] )
+  , Mont::unsafe_make( [ 0x9461998f , 0xc39d220a , 0x399ce62c , 0xe852fb5b , 0x1b83913d , 0x2752ab8b , 0xe5a34f73 , 0x2cd239d1 ] )
+  , Mont::unsafe_make( [ 0xc8ea32ac , 0xdcfe81f6 , 0x24fc2a87 , 0x5e070a0e , 0x9d24395d , 0x3f9f803a , 0x414f6c34 , 0x13d456d1 ] )
+  , Mont::unsafe_make( [ 0xc72d17c7 , 0xe005510c , 0xd9d1e28e , 0x0fac2c8d , 0xc605dff1 , 0x7d919013 , 0x76ea967d , 0x2a960845 ] )
+  , Mont::unsafe_make( [ 0x3b7edc7d , 0x2cd8bc3f , 0x453cbb00 , 0x0fcf291f , 0xd4346a6f , 0x15a395bf , 0xa50a0da0 , 0x1ebfeeb6 ] )
+  , Mont::unsafe_make( [ 0x9e00d840 , 0x835d61a9 , 0x0e4c4288 , 0x7a6479bb , 0xbc883009 , 0xc2d6b0dc , 0xcfb71b38 , 0x01f1babd ] )
+  , Mont::unsafe_make( [ 0xe10815f9 , 0x97d157e3 , 0x5aadabdb , 0x52153138 , 0x83605d3c , 0x046bd63e , 0x5441fd59 , 0x2c21ded8 ] )
+  , Mont::unsafe_make( [ 0xafe980e5 , 0x4b4f0def , 0x90f1e778 , 0xc972540c , 0x502c704c , 0xb6ba724f , 0xba2149c8 , 0x286061fa ] )
+  , Mont::unsafe_make( [ 0x9eaa5119 , 0x047a234b , 0xf170ef51 , 0x7b7192eb , 0x6881a489 , 0xf856c37a , 0xefe5a026 , 0x0ddc6ad6 ] )
+  , Mont::unsafe_make( [ 0x9cf24a87 , 0x3b4ac15b , 0x62bada88 , 0x85614ea7 , 0xa6b282ce , 0xfaa16660 , 0xb5d09aae , 0x07445aac ] )
+  , Mont::unsafe_make( [ 0x6ad1f947 , 0x8cb51621 , 0xced33f8e , 0xed94061c , 0xe505c85d , 0x545f1e35 , 0xd07b9093 , 0x01bbc621 ] )
+  , Mont::unsafe_make( [ 0xb100f538 , 0x9671d230 , 0x999ec8a4 , 0x43f83571 , 0x54758c6b , 0x8029bd68 , 0x23ccdabd , 0x13ab1178 ] )
+  , Mont::unsafe_make( [ 0x96b82525 , 0xb50d1fcb , 0x2d1097c3 , 0x6972202b , 0x6d23ec59 , 0xebab292d , 0xd55f455e , 0x1f97330c ] )
+  , Mont::unsafe_make( [ 0xe449f0c0 , 0x3c1670df , 0x8558b003 , 0x5c6ee002 , 0xd468c7b6 , 0xf056d3e8 , 0xec17b9aa , 0x20feecb1 ] )
+  , Mont::unsafe_make( [ 0x7c17b0c8 , 0x102c622c , 0xf0de0156 , 0x96ada2b9 , 0x7355d95b , 0x3b69aff0 , 0xdf21123a , 0x241ad481 ] )
+  , Mont::unsafe_make( [ 0x430d3790 , 0xee6b0ed5 , 0x2b0b623c , 0xc1e1726a , 0x5e1c422f , 0x744c1185 , 0x7375d1b0 , 0x18fa110b ] )
+  , Mont::unsafe_make( [ 0xb9dedbb5 , 0x3d7d582b , 0xde637679 , 0xe6914c45 , 0xba90a30b , 0x088a37e0 , 0x12a66ae1 , 0x294d8bca ] )
+  , Mont::unsafe_make( [ 0xbaaee6d2 , 0xc03b0903 , 0xca056b99 , 0x917cedfc , 0x0f8671a6 , 0xe2e978f1 , 0xbf80fb8f , 0x1b61ff8c ] )
+  , Mont::unsafe_make( [ 0xfa656113 , 0x0e7bb101 , 0xcbf8821a , 0x98cbdaae , 0x8464aee9 , 0xd104c6c9 , 0xb6525ea0 , 0x26459d61 ] )
+  , Mont::unsafe_make( [ 0x428a8e04 , 0x7c29c348 , 0xfe0e059b , 0xead2e84a , 0xf485c1e9 , 0xdb999883 , 0x2165dc12 , 0x023fc4ec ] )
+  , Mont::unsafe_make( [ 0x87a98f07 , 0x4f7b64f4 , 0x9ec1be90 , 0x1660879c , 0x5f78aa44 , 0xc0b30219 , 0x3b809020 , 0x152a732a ] )
+  , Mont::unsafe_make( [ 0xa6b48ab6 , 0x2c745fb4 , 0xf7b854d6 , 0x44088c1b , 0x05505af6 , 0x03e9c2c2 , 0x6dc34979 , 0x1bb580a6 ] )
+  , Mont::unsafe_make( [ 0xda665632 , 0xf1214af6 , 0xe03323bc , 0x04028e42 , 0xb86f2b36 , 0x3396090e , 0x84d91f85 , 0x039acdc4 ] )
+  , Mont::unsafe_make( [ 0xcb3fdbf3 , 0x0096b8ec , 0xcfe9b1df , 0xe91543dc , 0xcea049e3 , 0xc38b01c4 , 0x42142ba2 , 0x13065c76 ] )
+  , Mont::unsafe_make( [ 0x233f3f08 , 0x5afe76fb , 0x74311020 , 0x6a8da1ae , 0xa0005c62 , 0xfdc0374b , 0x75170200 , 0x167b47e9 ] )
+  , Mont::unsafe_make( [ 0x5cb9de3c , 0x361077e4 , 0x616a9392 , 0xf101becb , 0xc71faebb , 0x4d46d75d , 0xe21e3aba , 0x01722e02 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// external mixing matrix (`t x t`, flattened)
+pub const CONST_M: [Mont; 196] = 
+  [ Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x459c64f8 , 0x62ecb358 , 0x381a1d35 , 0x6fe0f31a , 0xf604cb7c , 0x12a6da2a , 0xda2a001b , 0x0d23775a ] )
+  , Mont::unsafe_make( [ 0xbfa128d7 , 0x4f3071eb , 0xc1b28428 , 0x63e78851 , 0x89600481 , 0xf2fafc70 , 0x6ce552f3 , 0x1fb5164a ] )
+  , Mont::unsafe_make( [ 0xa22dd018 , 0x7a3204f1 , 0xa0eb3c0e , 0x24332225 , 0xa79a88e2 , 0x8304d1e8 , 0xca1b98f6 , 0x15c94b9c ] )
+  , Mont::unsafe_make( [ 0x3d83cf09 , 0x26185c66 , 0x3936c4f3 , 0xc3235d4d , 0xb565eddd , 0x4596a3ec , 0x04ab8e1a , 0x28543e07 ] )
+  , Mont::unsafe_make( [ 0xeb230a89 , 0xd361e103 , 0x28fe161e , 0xcafd0b48 , 0xb8fbeef8 , 0x187a61b9 , 0xadcc4908 , 0x26ec7636 ] )
+  , Mont::unsafe_make( [ 0x75cf46dc , 0xfdab0562 , 0x65b3dbfb , 0xb96d8b3a , 0x8a9001fe , 0x0dd02a73 , 0xe80c7fc6 , 0x15bc63da ] )
+  , Mont::unsafe_make( [ 0x586b460d , 0xd8a47313 , 0xf0074d4a , 0x8d2c3d90 , 0x950805c1 , 0x60de293b , 0x4066783d , 0x21b27989 ] )
+  , Mont::unsafe_make( [ 0x4a44ca58 , 0x459b5e89 , 0xe648ad4b , 0x32c99035 , 0xaa4a2e5b , 0xec96ba5c , 0x3f44cfe0 , 0x29c0b4a5 ] )
+  , Mont::unsafe_make( [ 0xb349aff9 , 0xa7bf7e85 , 0x4743ffe6 , 0x72d35d2f , 0x047a04b9 , 0x3da5ea9e , 0xee51ce90 , 0x2ba740af ] )
+  , Mont::unsafe_make( [ 0x5b99d70a , 0xfaa503a5 , 0x33a5ae43 , 0x4e0a1e93 , 0x42029e72 , 0x2c160d36 , 0x5b7ca4da , 0x27e3fd9a ] )
+  , Mont::unsafe_make( [ 0xc57cb818 , 0xf9bebfc2 , 0xbcdfd050 , 0x41ed2ba3 , 0x3f3bc4c4 , 0x5c9c2480 , 0x4d27a458 , 0x303642a2 ] )
+  , Mont::unsafe_make( [ 0x3c22a883 , 0xf3bc3d6c , 0xd971ac84 , 0x0953751d , 0x8eb852e7 , 0x551f2502 , 0x2ad6332a , 0x06328ead ] )
+  , Mont::unsafe_make( [ 0x8deb80d5 , 0x35a29d29 , 0x949d98a2 , 0xa2ad0752 , 0x22a1bb24 , 0xe6d02575 , 0xe432594e , 0x10a8e117 ] )
+  , Mont::unsafe_make( [ 0x189f36ee , 0x63c3bfed , 0x8356e19c , 0x43fbdcb5 , 0x2d5f31f0 , 0xa2d7f23a , 0x4b4883f2 , 0x1134c2ec ] )
+  , Mont::unsafe_make( [ 0x57253220 , 0xfa0659ee , 0xb6e50338 , 0x36a5bc56 , 0xd8d59d07 , 0x81ef5c98 , 0xbe355d52 , 0x1de0d714 ] )
+  , Mont::unsafe_make( [ 0xdc11b74c , 0x2d5023e1 , 0xc58043cf , 0x02ef5dbd , 0x6f5e0b80 , 0x99582116 , 0xa353443f , 0x08e2d0e8 ] )
+  , Mont::unsafe_make( [ 0x50235f54 , 0x15a34237 , 0x0e8f2314 , 0x55913482 , 0x2e5c5166 , 0xb5e99098 , 0x7f6b67b3 , 0x305975de ] )
+  , Mont::unsafe_make( [ 0x4f059985 , 0x69ba563a , 0x84aef042 , 0x27ab2c44 , 0x4f6980c2 , 0x9cbcfd5e , 0x9ec26708 , 0x1572267c ] )
+  , Mont::unsafe_make( [ 0x870bf1eb , 0xecdbc964 , 0x01fa6b04 , 0x6f3aaee0 , 0x54b97ac5 , 0x3697c98f , 0x007736ee , 0x21326f36 ] )
+  , Mont::unsafe_make( [ 0x3c0c598e , 0x560097e4 , 0x0d59fb3b , 0xc634e41a , 0xbc238883 , 0x735f7574 , 0x1e27ca06 , 0x0a2f74ba ] )
+  , Mont::unsafe_make( [ 0x698234d1 , 0x275781f1 , 0x22a1565b , 0x22946eeb , 0x973d61bb , 0x912ba9ee , 0x117da5eb , 0x14ba1905 ] )
+  , Mont::unsafe_make( [ 0xd3a5683b , 0xe4b825f9 , 0x16f8d8be , 0x2ddfb508 , 0xf8e57ecb , 0xa6204d6e , 0x3c232aa0 , 0x00f637e1 ] )
+  , Mont::unsafe_make( [ 0x83b2ff4c , 0xc6448c7c , 0x173b7eb6 , 0xe8bd2d26 , 0x698fad52 , 0x15bcc282 , 0x504b43b9 , 0x08613002 ] )
+  , Mont::unsafe_make( [ 0x27bf670c , 0xe52731cb , 0xc495c8a2 , 0x003f7009 , 0xdb452891 , 0x2deb424b , 0xcb4f4964 , 0x2692b88c ] )
+  , Mont::unsafe_make( [ 0x11fb0220 , 0x60662556 , 0x32eb6d33 , 0x5ea66b1d , 0x4695e06e , 0xed001e50 , 0x1154ea58 , 0x04de0e3b ] )
+  , Mont::unsafe_make( [ 0xf322ec06 , 0xc465df7c , 0xddcc31bd , 0x22d49a97 , 0x41db4696 , 0xd0c1cea5 , 0x9c832673 , 0x2daff9f1 ] )
+  , Mont::unsafe_make( [ 0x8211ae81 , 0xa9656ae7 , 0x7330858e , 0x83f4fc28 , 0x8789e0a5 , 0x232e01dd , 0x56ea43fd , 0x1d4f4d1c ] )
+  , Mont::unsafe_make( [ 0xc283a0d3 , 0xea3aaa72 , 0x319fa8c4 , 0xfa340eab , 0xaa9fe330 , 0x106b9147 , 0x7c49e98f , 0x0255c95b ] )
+  , Mont::unsafe_make( [ 0xb6d2e5b4 , 0xfc69db8d , 0x516bbe83 , 0xd080fde5 , 0x5ddc47ea , 0xdc45c101 , 0xcd8df588 , 0x1d90b611 ] )
+  , Mont::unsafe_make( [ 0xb48454b3 , 0x6849428b , 0xfe9946b5 , 0x58bef024 , 0xef07e07e , 0x6035748f , 0xf0e355cf , 0x230256e4 ] )
+  , Mont::unsafe_make( [ 0x0eedfc3a , 0x97097fc9 , 0x5f0ac024 , 0xd458ef6c , 0x52a581bf , 0x5df35bc1 , 0x18f150be , 0x10b21b27 ] )
+  , Mont::unsafe_make( [ 0xc3dd4b59 , 0x49ebdf4d , 0x5f58136b , 0x9605f491 , 0xaa8af1dc , 0x151dafb5 , 0xf25c2454 , 0x0ba8ed66 ] )
+  , Mont::unsafe_make( [ 0xb0e1e336 , 0xabb27c9f , 0x874f3bc0 , 0x32819388 , 0x9278bf83 , 0x4f4fc731 , 0x2f726a10 , 0x260a7664 ] )
+  , Mont::unsafe_make( [ 0xa6dee0d7 , 0x3c99d33a , 0xe56fed6e , 0x81301da2 , 0x4983c9c5 , 0xcb4872d0 , 0xee6fb2bc , 0x14a8f15c ] )
+  , Mont::unsafe_make( [ 0x756a77c8 , 0x638ff76b , 0x5b1f0883 , 0x67df329b , 0x3644cbb3 , 0xd2f76037 , 0x73c12280 , 0x27c3905a ] )
+  , Mont::unsafe_make( [ 0x0e2ef94f , 0x424ad280 , 0x1dc345f3 , 0xee0999f9 , 0xdae3b4be , 0x6a5b25ce , 0x1ee3134d , 0x2e1d1e54 ] )
+  , Mont::unsafe_make( [ 0x3ef4df9a , 0x75080c34 , 0x5c2232c5 , 0xf8668ef6 , 0xb6d39db5 , 0x212dea60 , 0xe926e68f , 0x249814c6 ] )
+  , Mont::unsafe_make( [ 0x85e4f4d0 , 0x7db0ea50 , 0xe883305a , 0x86bdd307 , 0x7f8ec4d4 , 0xfc672234 , 0x1b55fb03 , 0x2daa9852 ] )
+  , Mont::unsafe_make( [ 0x2dd036c3 , 0x0d72e5d8 , 0x8c1f808e , 0x9bc1265b , 0x0db4a408 , 0x15683ed4 , 0xc9f3c7bc , 0x25c78c15 ] )
+  , Mont::unsafe_make( [ 0x26c3c921 , 0xa52907c2 , 0xd0079e90 , 0x965ae2a4 , 0x0644cc1d , 0x1cab590b , 0xd670faf0 , 0x1228ae04 ] )
+  , Mont::unsafe_make( [ 0x365076b7 , 0x76e8fc6d , 0xac516e00 , 0x77793224 , 0x00a828fd , 0x36b469e5 , 0x82123124 , 0x179d2cc2 ] )
+  , Mont::unsafe_make( [ 0x86eca867 , 0x95de9854 , 0x8b5e26e4 , 0x3e113680 , 0x0f4f830b , 0x4c317b46 , 0x1e744a9e , 0x073f7a9e ] )
+  , Mont::unsafe_make( [ 0xd19d784f , 0xf26cd14e , 0x129ac034 , 0x7a57d7cf , 0xab694d4c , 0x34f5eb2a , 0x46577506 , 0x23ccfa05 ] )
+  , Mont::unsafe_make( [ 0xcd559887 , 0x9785f2da , 0xa40d7090 , 0x9094ae22 , 0x87fc6795 , 0x794c6827 , 0x9ed391b2 , 0x231add41 ] )
+  , Mont::unsafe_make( [ 0xf253401c , 0x179c4d55 , 0xe2ecd28b , 0x2a69633a , 0xedc965b2 , 0xf649efd8 , 0xcc2df8a0 , 0x2df25491 ] )
+  , Mont::unsafe_make( [ 0xaa18705d , 0x2d37195f , 0x372e745f , 0xdb8f913c , 0xfafe0c88 , 0x88171980 , 0x515e3691 , 0x28c75ae3 ] )
+  , Mont::unsafe_make( [ 0x8d8f3cae , 0xd9a7a89a , 0xa9cfaae7 , 0xa10b3360 , 0x0a370282 , 0xee89f8d2 , 0x8e5914f6 , 0x0e4be324 ] )
+  , Mont::unsafe_make( [ 0x6ebd5f15 , 0x814dbca6 , 0x5b9a8f82 , 0x3a0b4020 , 0x4a5d2ba9 , 0x99e79179 , 0x230a6cf5 , 0x08e6d0ec ] )
+  , Mont::unsafe_make( [ 0xc9770d2c , 0x310f9c2f , 0xfce6c3de , 0xd2ed9f88 , 0x30f2929e , 0xaedca004 , 0xaa13f647 , 0x1872f9ef ] )
+  , Mont::unsafe_make( [ 0xe403566c , 0x1c1d805e , 0xa029cfe3 , 0x86523ab8 , 0x16758aa6 , 0xd940266e , 0x9547529d , 0x0570db63 ] )
+  , Mont::unsafe_make( [ 0xa63c4185 , 0xe5633fc5 , 0xbce763fd , 0xc033ad0f , 0xb59fab98 , 0x89b2f4e6 , 0xc3d2dad4 , 0x10dba1fc ] )
+  , Mont::unsafe_make( [ 0xc5e9f37f , 0xf4c4dbc0 , 0xafd440fa , 0x02bdddbf , 0xb5296842 , 0x9c89d747 , 0xc0296cac , 0x087e937b ] )
+  , Mont::unsafe_make( [ 0x8b86d852 , 0x49b08832 , 0xfca404db , 0x685116ed , 0x272671c3 , 0x55fa2ae8 , 0x03fd8b4c , 0x0c314122 ] )
+  , Mont::unsafe_make( [ 0x36d5268b , 0xb899c7b4 , 0xa2ca8a87 , 0x01ba10ee , 0x23b99c03 , 0x42e659ae , 0x9e8a2cee , 0x0058943f ] )
+  , Mont::unsafe_make( [ 0x4897bffb , 0x8fb7a98b , 0x968fa28c , 0x4ebc974d , 0x8053729b , 0xc71e993b , 0x84a6e67f , 0x1d191b35 ] )
+  , Mont::unsafe_make( [ 0x90ea51f4 , 0x0b3f2166 , 0x171048f6 , 0xf5515e76 , 0xd28cec62 , 0xc24ca920 , 0x489e5425 , 0x232bdd9a ] )
+  , Mont::unsafe_make( [ 0xd6a8bce2 , 0xd13589bd , 0x80751eff , 0x6ecef270 , 0xf4f22e4b , 0xd369643e , 0xbcfc8f52 , 0x22e4e4fc ] )
+  , Mont::unsafe_make( [ 0x7473fab6 , 0xfb519e19 , 0x6a861d8b , 0x3967ff1a , 0x78b853d7 , 0x8c0cb993 , 0x46969774 , 0x1283390b ] )
+  , Mont::unsafe_make( [ 0xb50cbfb6 , 0x213d4138 , 0x023d69c5 , 0xfe2b81de , 0x3cc8d000 , 0xe0415cf1 , 0x0d7938ec , 0x09d88b55 ] )
+  , Mont::unsafe_make( [ 0xa3147df9 , 0x6ff785af , 0xee39b239 , 0xf2da30ec , 0xfe83cf97 , 0x8b2df9c4 , 0xa18924f8 , 0x242bfdf0 ] )
+  , Mont::unsafe_make( [ 0x6b58a561 , 0x3430ce0a , 0x8b4bec3d , 0x8bb70495 , 0x208d2139 , 0x011d3d22 , 0x023538c3 , 0x01e509e8 ] )
+  , Mont::unsafe_make( [ 0x4c33f967 , 0x1aff8cf9 , 0x39be4fea , 0xae5cc0db , 0xe3b7600e , 0x4c8c8fab , 0x751ced89 , 0x03dd0b82 ] )
+  , Mont::unsafe_make( [ 0x854eb2dd , 0x63f63a8c , 0xafaa1a77 , 0x08259254 , 0xfb83af07 , 0x630a273e , 0xbd935268 , 0x120775d8 ] )
+  , Mont::unsafe_make( [ 0x20f9bc99 , 0x503ef9f6 , 0x505862e2 , 0xab1bcdb3 , 0x024d81e7 , 0xd423eb86 , 0xa6c556c2 , 0x02045cf6 ] )
+  , Mont::unsafe_make( [ 0x46b7f9a7 , 0xe7266189 , 0xee5582ec , 0xfceeba35 , 0x8462479a , 0x0b4eb37a , 0x47c20826 , 0x080945fe ] )
+  , Mont::unsafe_make( [ 0x1d64b6fd , 0xa2bf7dd2 , 0x9b33abb2 , 0x8fed7952 , 0xa5266aa4 , 0xea2647aa , 0x81b0cf21 , 0x15bee0c6 ] )
+  , Mont::unsafe_make( [ 0x918f488a , 0xdc44952e , 0x71883c4b , 0xdf9e9fc5 , 0xe0acedd9 , 0xf370bf4c , 0x601ed3ca , 0x11e0446a ] )
+  , Mont::unsafe_make( [ 0xb7065d2b , 0xb4d71304 , 0xa0e7e8b8 , 0xb3c88fab , 0xdbfd1f71 , 0xa05c044c , 0xa75bc923 , 0x114fb19f ] )
+  , Mont::unsafe_make( [ 0x8bcf62ab , 0xd17222a1 , 0x262777b8 , 0x22e8d4ce , 0x2b3f5319 , 0x8d9e6ac6 , 0x08a03895 , 0x086aed55 ] )
+  , Mont::unsafe_make( [ 0x7f489df2 , 0x40b17ffb , 0x54529aca , 0xfdf4f3ec , 0x49bda912 , 0xa6e160f8 , 0x68b1a775 , 0x0399c36b ] )
+  , Mont::unsafe_make( [ 0x8d2875d2 , 0x880c1309 , 0x06bf1981 , 0xc78aef1b , 0x8a417ee2 , 0x8ccce821 , 0xf5144d10 , 0x23c43c59 ] )
+  , Mont::unsafe_make( [ 0xed8594ee , 0x28010876 , 0xdb059dce , 0x480b7db3 , 0x5f3bbbb1 , 0x9608701a , 0x631ac4ac , 0x05244adb ] )
+  , Mont::unsafe_make( [ 0x1efed930 , 0x88ca307f , 0x6574359d , 0x6d499072 , 0x81021223 , 0x10a31997 , 0x7447ea31 , 0x12443d7c ] )
+  , Mont::unsafe_make( [ 0x24791d67 , 0xcf65557d , 0x906a5acf , 0x3d3b587b , 0xd36f7217 , 0xe093625f , 0xfb123c49 , 0x22a75cc1 ] )
+  , Mont::unsafe_make( [ 0x5fb2b6e3 , 0x6643cd2c , 0xa0c893f2 , 0xaff1bd69 , 0x6796c50e , 0x873f16fa , 0xdda115ec , 0x21b29f92 ] )
+  , Mont::unsafe_make( [ 0x1ffefb9c , 0xc7fd25c1 , 0x720f44b1 , 0xe093f622 , 0xc7a857fe , 0xd34b4c1f , 0x0a9d6412 , 0x11996be6 ] )
+  , Mont::unsafe_make( [ 0x0dfc1a57 , 0xa1ef4152 , 0x321e4585 , 0x476c2a5f , 0xfcfcd381 , 0x803c763d , 0xf94bf459 , 0x2210da0f ] )
+  , Mont::unsafe_make( [ 0x86c5c2e0 , 0x4b858c2d , 0x32505df4 , 0xdfa1ff78 , 0x804d6421 , 0x2818209a , 0x4ba310d1 , 0x214fe30d ] )
+  , Mont::unsafe_make( [ 0x78ad3847 , 0x42f48b35 , 0xc866e6c3 , 0xa0c2d2a8 , 0xe73d1d28 , 0x8e17c032 , 0x7cba3c0d , 0x2fc88548 ] )
+  , Mont::unsafe_make( [ 0xdc79439a , 0x1e08f67f , 0x1db3bd28 , 0xf457b5bb , 0x8faeb8f8 , 0x48ddb3b2 , 0xfef25932 , 0x19c7b480 ] )
+  , Mont::unsafe_make( [ 0x8ecf9887 , 0x14cded9f , 0xc289a074 , 0xeccdc79e , 0x79c6f5e6 , 0x7a5ed7c9 , 0xcdd2b408 , 0x1c38dd45 ] )
+  , Mont::unsafe_make( [ 0x0312dbd8 , 0x4bc9a690 , 0xb622292f , 0x0c473f1d , 0x2d706d69 , 0xdf1ff0ae , 0x93804c42 , 0x23925082 ] )
+  , Mont::unsafe_make( [ 0x3cf8dfc0 , 0xb350c6f4 , 0x53960507 , 0x330a85be , 0xc6213a55 , 0x8ab9427f , 0x4e463f30 , 0x0479be9c ] )
+  , Mont::unsafe_make( [ 0x4c633da6 , 0xf5929f38 , 0x580d1baa , 0x883ed254 , 0x8d6c27c4 , 0x0d9567be , 0xcb2228b0 , 0x10251999 ] )
+  , Mont::unsafe_make( [ 0x1b7e2a78 , 0xedcab995 , 0xc0b5567b , 0x133c6e53 , 0xa15433ac , 0x5df65143 , 0xd3ff829c , 0x184b5297 ] )
+  , Mont::unsafe_make( [ 0x1acddba9 , 0x55ba6035 , 0xd20a002d , 0x1d61c79f , 0x5151b4bf , 0xa505fc63 , 0x4218866e , 0x08e31739 ] )
+  , Mont::unsafe_make( [ 0xb9b84c8b , 0x43c8d97c , 0x93d96c72 , 0x91dd5dff , 0xd9cb3ce0 , 0x2cafd32a , 0x4e81be73 , 0x1cfd9a89 ] )
+  , Mont::unsafe_make( [ 0xc57c6f1a , 0x532256dc , 0xe5f8beb6 , 0x5ac94d40 , 0xbc975618 , 0x81515dbf , 0xaf59a1ec , 0x228edafb ] )
+  , Mont::unsafe_make( [ 0x9c0d5268 , 0xa2ae6b84 , 0x63b144dc , 0x0bfb67a7 , 0xa1d0d3ae , 0x77b943d2 , 0x15bb826e , 0x300e1050 ] )
+  , Mont::unsafe_make( [ 0xae327f98 , 0x43031730 , 0xb0d0a927 , 0xc8f653a7 , 0x9304ac0f , 0x54a87b2e , 0x22e6afe8 , 0x1acc8123 ] )
+  , Mont::unsafe_make( [ 0x3a54b457 , 0x39cd2f6c , 0x04e38000 , 0x8c742b40 , 0xf55d846c , 0xaa1589fd , 0x4b960a5f , 0x1517e913 ] )
+  , Mont::unsafe_make( [ 0xc3299197 , 0x9a3086ee , 0xe626d231 , 0x07420c1b , 0x8e9131b1 , 0x48508688 , 0x7c10cc3c , 0x03d3cb09 ] )
+  , Mont::unsafe_make( [ 0x38ac5558 , 0xa9c62c66 , 0xde82f4e9 , 0x7952269b , 0x01468ad9 , 0xa1879f6a , 0x3468497a , 0x2b82fb85 ] )
+  , Mont::unsafe_make( [ 0xf9f530ef , 0xe7b29fd3 , 0xe0b7daaf , 0xa10a3ae5 , 0x36d4e253 , 0x443b35b5 , 0x409573f9 , 0x0fc71ed4 ] )
+  , Mont::unsafe_make( [ 0x9bd9156a , 0xd52e3f20 , 0x0194a4d9 , 0x44e8bad3 , 0x46ab3644 , 0x57f08ed6 , 0x610450cc , 0x0935f089 ] )
+  , Mont::unsafe_make( [ 0x37067ad0 , 0xcc9d9261 , 0x10c149ff , 0xde74d6b0 , 0xfe6842bb , 0x0129bc87 , 0xb01087c5 , 0x21408118 ] )
+  , Mont::unsafe_make( [ 0x96216925 , 0x93d18327 , 0x4e2bd43b , 0x6c962d4f , 0x88f10a08 , 0xdcf6c71e , 0x22e36b64 , 0x01b9d0ec ] )
+  , Mont::unsafe_make( [ 0x88910fcd , 0x68212944 , 0x0da625ea , 0x0dc22e6f , 0xd4418641 , 0xc6bd2319 , 0x4788d657 , 0x08af4fc1 ] )
+  , Mont::unsafe_make( [ 0xc2a9d9ac , 0x559b01a4 , 0x23af707d , 0xc168c0bc , 0xa098da61 , 0x587c8105 , 0xd662daca , 0x19ef116c ] )
+  , Mont::unsafe_make( [ 0xa97d0b0c , 0x14fdaf1d , 0xc9b86fe0 , 0x9420a0d2 , 0xf77ec2f4 , 0x7a68f3f6 , 0x62d62065 , 0x225f5f67 ] )
+  , Mont::unsafe_make( [ 0xa4ccbe52 , 0xf2788919 , 0x24828438 , 0x8c2ce982 , 0xde5aeb9c , 0xa22d429c , 0x22d2cc19 , 0x249f9519 ] )
+  , Mont::unsafe_make( [ 0xb9fe11d1 , 0xa0b6a05a , 0xea825614 , 0x47de37b2 , 0x1e14fd8d , 0x25e3fbe2 , 0xd1715615 , 0x177b9ceb ] )
+  , Mont::unsafe_make( [ 0xd2ba71f5 , 0x291644c3 , 0x94a3ed15 , 0x74f877b2 , 0x016764ec , 0x5509ddd2 , 0x354dfaab , 0x01c8073e ] )
+  , Mont::unsafe_make( [ 0x7703cc8d , 0xb9003054 , 0xb794300b , 0xa410efa4 , 0xdfaf2936 , 0xdb438681 , 0xe32200f1 , 0x141a8e2f ] )
+  , Mont::unsafe_make( [ 0xf26a41e7 , 0x0e4b8c01 , 0x44a07dab , 0x50891654 , 0x2257e9aa , 0x888fb2fc , 0xe81be982 , 0x1dcf0895 ] )
+  , Mont::unsafe_make( [ 0x7ca15ee0 , 0xf48eed8d , 0x4a15097d , 0x30bb4ac3 , 0x2b55b451 , 0xd897a84d , 0xe7f439de , 0x2ad94898 ] )
+  , Mont::unsafe_make( [ 0x8e35958f , 0x64ea3561 , 0xbec7ddb9 , 0xe62df8d2 , 0x84e4ba7a , 0x74d42fe6 , 0xc336d058 , 0x1f77ef8e ] )
+  , Mont::unsafe_make( [ 0x6dc201ec , 0x5375ea9d , 0x1af2788c , 0x090c2594 , 0x9dc48fc5 , 0xc84f4b85 , 0x8553b101 , 0x20d45781 ] )
+  , Mont::unsafe_make( [ 0x34a1bcf3 , 0x01e8af7d , 0x4976ad08 , 0xc2e7cc36 , 0xda61bfa9 , 0xcd3bd5a2 , 0x6e854be8 , 0x2f832cef ] )
+  , Mont::unsafe_make( [ 0xb8cbda28 , 0x2eca0480 , 0x3316c705 , 0x4681b9bf , 0x99a14dae , 0xa850bb2a , 0x826291db , 0x25272083 ] )
+  , Mont::unsafe_make( [ 0x9b0c9318 , 0x2e461e0e , 0x859eeb8a , 0x79af380e , 0x6866a1ea , 0xb715049b , 0x7b359323 , 0x2953260f ] )
+  , Mont::unsafe_make( [ 0xb12e4a93 , 0xf1e5af28 , 0xbfeb2466 , 0x3bb62193 , 0xaaade673 , 0x3a56dbb3 , 0xa025bd0b , 0x128d6043 ] )
+  , Mont::unsafe_make( [ 0x3677d801 , 0x3decea1f , 0xc58f0193 , 0x19741247 , 0x2e4fd617 , 0x1d91422f , 0xab69d2b4 , 0x1545cd20 ] )
+  , Mont::unsafe_make( [ 0x2404569b , 0xabe5a778 , 0x3d8cb07b , 0x37dbb53a , 0xdc9d853c , 0x35399fec , 0x04a51090 , 0x0699f0d2 ] )
+  , Mont::unsafe_make( [ 0xbb170ab4 , 0xd450b0c3 , 0x92d050f9 , 0xd22f3e2a , 0x465a9b57 , 0xd751c538 , 0x77a2f446 , 0x2fdcdece ] )
+  , Mont::unsafe_make( [ 0x49e43ddf , 0xcc0a8a14 , 0x4adef5bb , 0xd2845265 , 0x6b2a899a , 0x5cb323a6 , 0xebd56fb5 , 0x2a85a164 ] )
+  , Mont::unsafe_make( [ 0x0530ddc4 , 0xa4989ad0 , 0x70959e67 , 0xe75ad6c2 , 0x017def6c , 0xb5b57105 , 0x3abb1206 , 0x04e8d6bc ] )
+  , Mont::unsafe_make( [ 0x4f93ddc3 , 0x72b80a6e , 0x301cb5d5 , 0x574d56eb , 0x1e021903 , 0x8761c485 , 0xf1dcab17 , 0x19a58f06 ] )
+  , Mont::unsafe_make( [ 0x9f238c8e , 0xbd0b0cb2 , 0x8c97a7f5 , 0x80a57721 , 0xe2f697d3 , 0x94853f2f , 0xc9860793 , 0x1718c67c ] )
+  , Mont::unsafe_make( [ 0x3f5c3c46 , 0x5ca7fda0 , 0x6a867da1 , 0x17645a45 , 0xf267221e , 0x1aaccb80 , 0x2d7dab06 , 0x0e89e9ff ] )
+  , Mont::unsafe_make( [ 0xf023fdca , 0xb43df2c9 , 0x935c57fa , 0xc34f676d , 0x0358145a , 0x2c2ac0a9 , 0xe02587cd , 0x22ecab86 ] )
+  , Mont::unsafe_make( [ 0x92deb57a , 0x333b8a6c , 0x16f77cef , 0x4ed1ad5f , 0x376f7a8f , 0x09e9ed50 , 0x85d5150a , 0x0198a61a ] )
+  , Mont::unsafe_make( [ 0x22f28504 , 0xbaa09331 , 0xa33a54f8 , 0x832c4d14 , 0x0d98c23d , 0x41cf66d2 , 0x8a447240 , 0x135ad5f5 ] )
+  , Mont::unsafe_make( [ 0xcfe1906f , 0xa3e641bf , 0xcccb3025 , 0x63161947 , 0x37a906e9 , 0x634da558 , 0xc1267130 , 0x1f98449b ] )
+  , Mont::unsafe_make( [ 0x100ea09c , 0xb363582b , 0xd4c75fe9 , 0xc74c71af , 0xf96a86a2 , 0xe80d53c4 , 0x22fb0d95 , 0x075e2326 ] )
+  , Mont::unsafe_make( [ 0x842f71b4 , 0x676d0d63 , 0xf51bc66e , 0x8d14d764 , 0x4b6ab4e1 , 0xc4012264 , 0xb1991d1a , 0x246c996e ] )
+  , Mont::unsafe_make( [ 0xdc6970c4 , 0xb7d8f759 , 0x9fa2478a , 0xf0924438 , 0x228fa628 , 0x83b84603 , 0xba25bf05 , 0x2eb17097 ] )
+  , Mont::unsafe_make( [ 0xff042baf , 0x02a0f325 , 0x9e9aabb2 , 0x7f4348a7 , 0xa8c38827 , 0x5c169493 , 0x3e468511 , 0x096ceda4 ] )
+  , Mont::unsafe_make( [ 0x0aa920a9 , 0x3a596cfa , 0xcbb93b1b , 0xbb342610 , 0x15be19ab , 0x3b6b4e96 , 0xbccfcdfb , 0x20f07892 ] )
+  , Mont::unsafe_make( [ 0xdfb25cf1 , 0x3c40669a , 0xc33b2b16 , 0xf03a9d36 , 0x5bd007a1 , 0xad514147 , 0x52c6faef , 0x15bd4895 ] )
+  , Mont::unsafe_make( [ 0x5c73eb23 , 0x673c91fb , 0x4f54cd2c , 0x05a64dd4 , 0x25c1b393 , 0xddd7d468 , 0x8a68eddc , 0x0a3ace29 ] )
+  , Mont::unsafe_make( [ 0xec0d1fd7 , 0xf4a069dd , 0xaa326c98 , 0x94eaf7e4 , 0x1b83d90b , 0x7d490fca , 0x7f60cc56 , 0x18efd03f ] )
+  , Mont::unsafe_make( [ 0x6dccebc4 , 0x674ace72 , 0x1d471669 , 0x733d2b96 , 0x4aeae410 , 0x6e0e451d , 0x25ef2c77 , 0x2746f257 ] )
+  , Mont::unsafe_make( [ 0xcc2857f5 , 0x403537de , 0x37cc0213 , 0x210fa8a8 , 0x31fd06d4 , 0x306f71e3 , 0x7c36a77e , 0x17cd644f ] )
+  , Mont::unsafe_make( [ 0xc03f11f2 , 0x0eb8f38f , 0xa35195d9 , 0xc3c78c71 , 0xb67233af , 0xa1c0e845 , 0xd6920208 , 0x05b3e306 ] )
+  , Mont::unsafe_make( [ 0x63071003 , 0x6b0487d1 , 0xbfb5978a , 0x52946143 , 0x4658efcb , 0x32336e5c , 0x0a9dac7c , 0x0cd51dc5 ] )
+  , Mont::unsafe_make( [ 0x124067d5 , 0x94d5b153 , 0xdc295e43 , 0x7ee45a0b , 0x3ad30adb , 0xbf11c089 , 0xdee16459 , 0x10ad7304 ] )
+  , Mont::unsafe_make( [ 0x8e25ba17 , 0xaad3150a , 0x72463980 , 0xe48f54a2 , 0x9de13c41 , 0xae2d882d , 0x51b3d23f , 0x29208e4e ] )
+  , Mont::unsafe_make( [ 0x68a36760 , 0x48372f30 , 0x163d2d1f , 0xb0b3adf3 , 0x681bb75e , 0x5e6aaae6 , 0x66728009 , 0x2ecab9f1 ] )
+  , Mont::unsafe_make( [ 0x21e97653 , 0x35b4deae , 0xc4db5074 , 0x19038de1 , 0x770034f7 , 0xa376f869 , 0x75e15b3a , 0x1bb7ba0f ] )
+  , Mont::unsafe_make( [ 0x809714c1 , 0x0c3fbde7 , 0xc8c60bd0 , 0xcc357981 , 0xb1c3a4ad , 0x1b926b12 , 0xe3ec02a0 , 0x1fec0fca ] )
+  , Mont::unsafe_make( [ 0x96106b5a , 0xd2600c2e , 0xc026255f , 0x7c156256 , 0x4b63ba5a , 0xc4a1867a , 0x63f8d35f , 0x1bbf0332 ] )
+  , Mont::unsafe_make( [ 0xf9514437 , 0xc97cb4b5 , 0x5b7ea059 , 0x143790d4 , 0x35d8a025 , 0xd52275bf , 0x2d08e86a , 0x254d74db ] )
+  , Mont::unsafe_make( [ 0x66127d20 , 0x12450110 , 0x03ce001c , 0xb6f6ba40 , 0x8ead716d , 0xaa380505 , 0x60cad3d6 , 0x07c9b08b ] )
+  , Mont::unsafe_make( [ 0x101631f8 , 0xc6547638 , 0xaefae87d , 0xd20309bb , 0x4273f17e , 0x639d090b , 0x05629f02 , 0x2e8066bd ] )
+  , Mont::unsafe_make( [ 0x276f5376 , 0xb36522db , 0xf45bd893 , 0xb621bb32 , 0x344f529a , 0xd30db40a , 0x21f61af3 , 0x20739715 ] )
+  , Mont::unsafe_make( [ 0x282e5480 , 0xb1e637ef , 0xda9c0648 , 0x82eda6a0 , 0x70b365b1 , 0x1324d34b , 0x615db557 , 0x1c39fdab ] )
+  , Mont::unsafe_make( [ 0x2196d06a , 0x143885c5 , 0x150b0683 , 0xaa659d0b , 0x579e232b , 0x0590f39a , 0x7aeebe33 , 0x210f01e2 ] )
+  , Mont::unsafe_make( [ 0xb1ea7920 , 0x691222e7 , 0xd1854a56 , 0xcda1c060 , 0x4f89fb05 , 0x9c5ac8f2 , 0x03ea6c89 , 0x001c34f5 ] )
+  , Mont::unsafe_make( [ 0x12869589 , 0x672702f7 , 0xc14caf94 , 0xc094e259 , 0x08ec98a0 , 0xa2cb944f , 0x9ac6b7d3 , 0x1a0f8e38 ] )
+  , Mont::unsafe_make( [ 0xd172bc54 , 0xb8f9c816 , 0x4006b86e , 0x8919c431 , 0x893c394c , 0x7860abda , 0xf9211338 , 0x17436732 ] )
+  , Mont::unsafe_make( [ 0x707694c2 , 0xafe49d01 , 0xa5672a10 , 0x2eab5b53 , 0xd583f5f0 , 0x883c1b4b , 0x814f1736 , 0x2b0815a3 ] )
+  , Mont::unsafe_make( [ 0xb1f58428 , 0x469d17fa , 0x651881dc , 0x4924a47a , 0x29f4073c , 0xac8482f8 , 0x1d0ffa51 , 0x231ea842 ] )
+  , Mont::unsafe_make( [ 0xfb98ff57 , 0x42a28a04 , 0xd3c3a9e0 , 0xea133581 , 0xa80c6311 , 0x47bf90ab , 0xc3193978 , 0x1e8c1d12 ] )
+  , Mont::unsafe_make( [ 0x74a8d60b , 0x0b7f8cb2 , 0xb7cc5dd0 , 0x5222d3f7 , 0xc2b8b851 , 0xb66debb7 , 0x0802051b , 0x27f4c15e ] )
+  , Mont::unsafe_make( [ 0xa7a26b61 , 0x48adc8b5 , 0x12119db7 , 0xa395d1a5 , 0x4367f6ea , 0x5a2e232c , 0xe4a58311 , 0x101938d9 ] )
+  , Mont::unsafe_make( [ 0x60ec1583 , 0xb1ce8c42 , 0x63340f5a , 0x90a5481b , 0xe1c4a480 , 0x42904f66 , 0x41415fe4 , 0x1e6bb166 ] )
+  , Mont::unsafe_make( [ 0x32b6f6dc , 0xeb8cf363 , 0xe07c2360 , 0xcf033226 , 0x7ec668b9 , 0xdc09a539 , 0xe24c477d , 0x2e1c8b37 ] )
+  , Mont::unsafe_make( [ 0x520a1368 , 0x4328e5db , 0x02c3fdc4 , 0x3599ea15 , 0xf92f6f7d , 0x9396d003 , 0x95ee31e0 , 0x11162581 ] )
+  , Mont::unsafe_make( [ 0xfe809374 , 0x7cb078e6 , 0x00f4491e , 0x6cd74d21 , 0x9826f566 , 0xdf8ec6da , 0xa906fc5b , 0x20e63a1e ] )
+  , Mont::unsafe_make( [ 0x26c82079 , 0x4439f91a , 0x2a22aadf , 0x93109bdf , 0x415e5805 , 0x2d2cfe71 , 0xfe4eb0fb , 0x1eaf291a ] )
+  , Mont::unsafe_make( [ 0x2a409274 , 0xf135b75f , 0x953ffa88 , 0xcf248509 , 0x42c939bc , 0x35f776c8 , 0xc95b5607 , 0x09b86fef ] )
+  , Mont::unsafe_make( [ 0x10699369 , 0x27a4e38e , 0xdcf0901f , 0xe61c9acf , 0xa93bc003 , 0xcd8d57c7 , 0x7422f69e , 0x00471211 ] )
+  , Mont::unsafe_make( [ 0x65bf7d36 , 0xbbf1e97b , 0xd8b1cc24 , 0xb5bde744 , 0x8eb80143 , 0x2ea109fd , 0x214af92c , 0x0cd3ebed ] )
+  , Mont::unsafe_make( [ 0x9c00f268 , 0x878e4224 , 0xf80c045f , 0xeb717a78 , 0x63152a9e , 0xc8529e9b , 0x83bf0699 , 0x10f6401d ] )
+  , Mont::unsafe_make( [ 0x9c4f36ad , 0xd36a1daf , 0x3c815f51 , 0xbb388be1 , 0xab06ffa8 , 0x5acab720 , 0xed958ab7 , 0x11908d92 ] )
+  , Mont::unsafe_make( [ 0xa9d42356 , 0x560e7d3a , 0x08f821e0 , 0xed725339 , 0x9a3a5c6b , 0x29861ad6 , 0xa7248bb6 , 0x2723e8d1 ] )
+  , Mont::unsafe_make( [ 0x5bb82785 , 0x4194a846 , 0x6c7197f0 , 0x605b3b1e , 0x55c2f6aa , 0xcc14e69f , 0xceb15f5b , 0x08a1e363 ] )
+  , Mont::unsafe_make( [ 0x01e7e312 , 0x59246811 , 0x960aa06c , 0xf1c5f558 , 0xf866427f , 0xddc95e26 , 0x8313c316 , 0x0068e903 ] )
+  , Mont::unsafe_make( [ 0x283f2ac2 , 0x258db86a , 0xf3d3f622 , 0xeb95c524 , 0x32a86980 , 0x6d8cbcbe , 0x31cef84f , 0x29d07f81 ] )
+  , Mont::unsafe_make( [ 0xcd928ddf , 0xb01c792b , 0x636a6e2d , 0x688eb4d0 , 0x602618c4 , 0x6d7f8211 , 0x0f31dd34 , 0x15a72b13 ] )
+  , Mont::unsafe_make( [ 0x8bb13ee1 , 0xfc7570ce , 0x47ec23cd , 0x2b3a3e2d , 0x2583505d , 0x4147c68d , 0xc04e734c , 0x09a8be6a ] )
+  , Mont::unsafe_make( [ 0x037f173a , 0x7b69ae75 , 0x37b12bdb , 0x657a5caf , 0xfb062a41 , 0xebf66b91 , 0xdc71b1ef , 0x07b86c03 ] )
+  , Mont::unsafe_make( [ 0x5ad352a3 , 0xe483b528 , 0xb24c08d5 , 0xadd323ad , 0x8d26866b , 0xf512bedf , 0x4f113a8a , 0x2792aa76 ] )
+  , Mont::unsafe_make( [ 0xa0ba041e , 0xb7543a75 , 0x5530a17a , 0x701d515c , 0xf4ab9d04 , 0x35f5b788 , 0xc0a1644f , 0x05f2e7f8 ] )
+  , Mont::unsafe_make( [ 0xdc70abc8 , 0x54924b36 , 0x99aca324 , 0x69cc9bdf , 0x3ca95e00 , 0x49c941d4 , 0x7e881a58 , 0x056898d0 ] )
+  , Mont::unsafe_make( [ 0x1b34c1ce , 0x840a372d , 0x48cd0772 , 0x8bf49e83 , 0x4298d839 , 0xb6fa6ebe , 0x74581fa6 , 0x009573d4 ] )
+  , Mont::unsafe_make( [ 0x4f0132e2 , 0xa3c18c2b , 0x21fc1e0d , 0xa43f4a1d , 0x9ffe6308 , 0x94f20432 , 0x81358777 , 0x1ed0d786 ] )
+  , Mont::unsafe_make( [ 0x0a074320 , 0x3060eb09 , 0x877269d3 , 0x0d14a534 , 0xba3aa8a6 , 0x4dcde443 , 0x334de2c4 , 0x09de656d ] )
+  , Mont::unsafe_make( [ 0x622a119a , 0x560106de , 0xd59485ea , 0x8f907d22 , 0x3a6dd7fb , 0x3a6638bd , 0x11832ebb , 0x0fac373c ] )
+  , Mont::unsafe_make( [ 0x2e4b236c , 0x867659ee , 0x478d8a45 , 0x06fc9828 , 0x6ca6054f , 0x28fe25ff , 0x74c86598 , 0x220424eb ] )
+  , Mont::unsafe_make( [ 0x1edfd6bd , 0x8a452051 , 0xead8fabb , 0x1deb429a , 0x6f2bca2d , 0x06ae5d88 , 0x1159128f , 0x21c94775 ] )
+  , Mont::unsafe_make( [ 0x6a47c822 , 0xad99e0c0 , 0xebdd47db , 0x7a4bb32f , 0xb2b6cc61 , 0x619ace4f , 0x3213fe4d , 0x1ecf811d ] )
+  , Mont::unsafe_make( [ 0xef3c0c05 , 0x9fa13313 , 0x67ed2e9a , 0x5b3164aa , 0xdb2bd00f , 0x658f8cce , 0xee969183 , 0x1946c81d ] )
+  , Mont::unsafe_make( [ 0xa34758b7 , 0x2050c882 , 0x818b2c9e , 0x9b61639a , 0x9985646e , 0x56edace1 , 0x028e7930 , 0x1899c172 ] )
+  , Mont::unsafe_make( [ 0x9641cf18 , 0x4ea1e69f , 0x4e2297ea , 0x431197da , 0x33218294 , 0xe31ff16f , 0x9e04f247 , 0x14735c0f ] )
+  , Mont::unsafe_make( [ 0x8d9d825e , 0x0b140f45 , 0x83dea78a , 0x522ee655 , 0xe1d1df99 , 0x93172d1f , 0xc591c200 , 0x2f49010f ] )
+  , Mont::unsafe_make( [ 0x94f7e4e2 , 0x29ed6724 , 0x3ae36659 , 0x5e90fb2e , 0x899e1399 , 0x97b3d375 , 0x7b2289b4 , 0x208dd514 ] )
+  , Mont::unsafe_make( [ 0x119ae3d1 , 0xc72024e6 , 0x45c1b342 , 0xa995b090 , 0xb6b1c2af , 0xec93c155 , 0x090c6a0d , 0x102d7e59 ] )
+  , Mont::unsafe_make( [ 0xd6c90258 , 0x4cba789e , 0x8ae3dbe0 , 0x410f8f88 , 0x4eddbc4c , 0x4f8a3821 , 0x2704e536 , 0x1a14243f ] )
+  , Mont::unsafe_make( [ 0x1460e530 , 0x7d89274a , 0xda397711 , 0xb347430b , 0xe81e4643 , 0x71654069 , 0x76cb17ea , 0x19bccbda ] )
+  , Mont::unsafe_make( [ 0xba505982 , 0x4840cdc6 , 0x9c0a0313 , 0x2b47af7f , 0xa0c1a69e , 0x8195f60d , 0xa0d2abf7 , 0x269103fd ] )
+  , Mont::unsafe_make( [ 0x3cb44797 , 0xe83aeb14 , 0x7feb4f3d , 0x3d5a3b71 , 0x99bf0529 , 0x83ee9327 , 0xf0b4a83a , 0x0469cdf1 ] )
+  , Mont::unsafe_make( [ 0x9c75c164 , 0x039cdf07 , 0x5ba35f9d , 0xff00bf15 , 0xe3547c8b , 0xe0219e37 , 0xb6babc03 , 0x1c19f682 ] )
+  , Mont::unsafe_make( [ 0x8df5975f , 0xd93863c3 , 0x26ee371c , 0xe2ea72ed , 0xe745b04e , 0x7c216f73 , 0x82e97d31 , 0x261c4013 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// internal mixing matrix (`t x t`s, flattened)
+pub const CONST_P: [Mont; 196] = 
+  [ Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x18cb7cfb , 0x97bc3e6e , 0xfa776e80 , 0x0c371059 , 0x7cec4aa0 , 0x8c8ff921 , 0xfa871dba , 0x1ae9740e ] )
+  , Mont::unsafe_make( [ 0x926a32ff , 0xab258da1 , 0xa67fb4fc , 0xe8c0ef90 , 0x6e4cad91 , 0xf12b857f , 0x8a15f834 , 0x04f938bf ] )
+  , Mont::unsafe_make( [ 0x7c68e7e3 , 0x424fdd86 , 0x0e4c3cc7 , 0xc07facae , 0x285d9b22 , 0x2d2bff64 , 0x8e5ad083 , 0x305dd570 ] )
+  , Mont::unsafe_make( [ 0x13512041 , 0xd8b2c0e4 , 0x6b9d97b6 , 0xc0d2b74e , 0x7b563e74 , 0xa82e5975 , 0xb597c84f , 0x11ad681f ] )
+  , Mont::unsafe_make( [ 0xcdca7ff2 , 0xbfb62f9e , 0x7e7f268b , 0x93727115 , 0x676e35df , 0x32319c51 , 0xcc76242d , 0x2388cbf8 ] )
+  , Mont::unsafe_make( [ 0xac8a7d0a , 0x409dc445 , 0xf0ef4eba , 0x94bbec17 , 0x4186d883 , 0x8b97d65b , 0xec9b0acb , 0x1c540cd0 ] )
+  , Mont::unsafe_make( [ 0x0e47bc83 , 0x451c7e2f , 0x8448143e , 0xfbade1a4 , 0xf45b2c98 , 0x061ed1e2 , 0x5f746add , 0x290057b1 ] )
+  , Mont::unsafe_make( [ 0xc475f259 , 0x51a0a60b , 0x6145d594 , 0xb543be43 , 0xb4a3becb , 0x6a2d8708 , 0x8a7ef79a , 0x1257981a ] )
+  , Mont::unsafe_make( [ 0x35a4a74e , 0x657cfd12 , 0x79f6d302 , 0xed259031 , 0x65ca5c70 , 0x1e293c26 , 0x1f3c0c95 , 0x2de417ef ] )
+  , Mont::unsafe_make( [ 0x632f3e94 , 0x2122406b , 0xebfb1959 , 0x49158724 , 0xc2ed0a6b , 0xf34c2680 , 0x6b8ec939 , 0x13a1273f ] )
+  , Mont::unsafe_make( [ 0xb1681802 , 0x1f82ef27 , 0xbee5a850 , 0x6b91578b , 0xdf2fa3ce , 0x3b57c86b , 0x50bca95b , 0x282714d6 ] )
+  , Mont::unsafe_make( [ 0x926f2fc2 , 0x3ba92268 , 0xfef5d01b , 0xe45b5634 , 0x845b0ac0 , 0x11dabc8a , 0xdf6cc618 , 0x0ac12b65 ] )
+  , Mont::unsafe_make( [ 0x2f9c74b9 , 0x437826e3 , 0x644af729 , 0xcad51acb , 0xf4cd26d2 , 0x19205d77 , 0xf4547206 , 0x0e17d4b1 ] )
+  , Mont::unsafe_make( [ 0x189f36ee , 0x63c3bfed , 0x8356e19c , 0x43fbdcb5 , 0x2d5f31f0 , 0xa2d7f23a , 0x4b4883f2 , 0x1134c2ec ] )
+  , Mont::unsafe_make( [ 0x23c93c4d , 0xe5477060 , 0x98698305 , 0x6ea1b8df , 0xf1d1d60b , 0x9182ecb5 , 0xfea7a3fa , 0x12396a75 ] )
+  , Mont::unsafe_make( [ 0x100b0ef1 , 0xcf962196 , 0x203753a3 , 0x7d71cf66 , 0xdedc3890 , 0xbd1eae91 , 0x3fd23fa5 , 0x10bac3c3 ] )
+  , Mont::unsafe_make( [ 0x20ecb32c , 0xcbcc0feb , 0xc427d1e0 , 0xc2a09748 , 0x5d9013a2 , 0x23426755 , 0xb2a12dd3 , 0x0a8be934 ] )
+  , Mont::unsafe_make( [ 0xc3a08a12 , 0xbdfc8f03 , 0x500f8045 , 0x1ac8dc34 , 0xd2203f0a , 0xa78f1b2d , 0x7076af08 , 0x0142d08a ] )
+  , Mont::unsafe_make( [ 0x12d53c16 , 0x840ee54a , 0xfee38684 , 0x8abbf2b8 , 0xf4a8308c , 0x821429bf , 0xa1c76d70 , 0x163d2433 ] )
+  , Mont::unsafe_make( [ 0xc1e5a4cd , 0xb6f4e142 , 0x045708ea , 0x7d2ce1df , 0x3b89778d , 0x47d3614a , 0x4d2104f8 , 0x1c620d24 ] )
+  , Mont::unsafe_make( [ 0x1874e7dd , 0x3c29f4f6 , 0x6de7b879 , 0x37ac302d , 0xfa4192ed , 0x6d3c91b6 , 0x13c02047 , 0x125b8a05 ] )
+  , Mont::unsafe_make( [ 0x1054f665 , 0x0ae2ffef , 0xa6384209 , 0x9662f387 , 0x7fc7049c , 0x4ece62ae , 0x57f9741a , 0x13ed2ff6 ] )
+  , Mont::unsafe_make( [ 0xf05f62dd , 0x75d9982c , 0x9eefa1be , 0xe6c4b7fa , 0x3e86c216 , 0x1044a556 , 0x73b446dc , 0x048a2a3d ] )
+  , Mont::unsafe_make( [ 0x713a22cf , 0x4db064d6 , 0xb01d0b5b , 0xf3274235 , 0x6df6c830 , 0xc9e40fbe , 0xe012b9d8 , 0x2207bf5e ] )
+  , Mont::unsafe_make( [ 0xe72d2dba , 0x1ac0ad76 , 0x9e557bc4 , 0xcc154ef1 , 0xda8dc592 , 0xabac0a6a , 0x74a01d32 , 0x1f5c7844 ] )
+  , Mont::unsafe_make( [ 0xf3a5c44c , 0xe3c2d6a6 , 0x5c0523ca , 0x71f1c733 , 0x04d3d862 , 0xd6e47d0c , 0xf18ef00c , 0x219ae49b ] )
+  , Mont::unsafe_make( [ 0x2dc38d2b , 0xec53cd1c , 0x2b9a3ccd , 0x69fb4f49 , 0xe1a7c996 , 0xd4f19f75 , 0xaa1ce388 , 0x169548ec ] )
+  , Mont::unsafe_make( [ 0xc283a0d3 , 0xea3aaa72 , 0x319fa8c4 , 0xfa340eab , 0xaa9fe330 , 0x106b9147 , 0x7c49e98f , 0x0255c95b ] )
+  , Mont::unsafe_make( [ 0xeabe1373 , 0x9efc2cc9 , 0xe82fff47 , 0xe13412e5 , 0xdae438d8 , 0xa28b16b8 , 0x4143d39a , 0x19fdea76 ] )
+  , Mont::unsafe_make( [ 0x8f8f9d7c , 0x347ca92a , 0xf02b2d0d , 0xf9851c72 , 0x82af7e2e , 0x6a39e0b5 , 0xe2245560 , 0x21544744 ] )
+  , Mont::unsafe_make( [ 0x7bb6b99b , 0xde093761 , 0x7da883c2 , 0x420bbb65 , 0x55ca08d8 , 0x65214128 , 0xb73aeb51 , 0x14eb6e56 ] )
+  , Mont::unsafe_make( [ 0x78800cc4 , 0xa8073ce5 , 0xdd794529 , 0xfa393a61 , 0xb0cd5848 , 0xe13fc3b6 , 0x18f44d48 , 0x08945c19 ] )
+  , Mont::unsafe_make( [ 0x30827fdf , 0xab631db6 , 0x4e8324a2 , 0x3d142751 , 0xbd698182 , 0xf3a3d529 , 0xdf8cd5b1 , 0x2399d2b0 ] )
+  , Mont::unsafe_make( [ 0xbdec7eb2 , 0xecd8d58e , 0x3ca59169 , 0x57d93316 , 0xe9c2510d , 0x3347f25f , 0x6a314885 , 0x0bbcb445 ] )
+  , Mont::unsafe_make( [ 0x23b87d61 , 0x8f913978 , 0x330e6761 , 0xcdb4c930 , 0xbd83d2c2 , 0x5a54aaf9 , 0x41fd9f82 , 0x278f285d ] )
+  , Mont::unsafe_make( [ 0x8c7b76bf , 0xd0775908 , 0x4a308dfe , 0x9fd39f80 , 0x038fb5ec , 0x828dfccd , 0x100e4ab2 , 0x2a0d74f4 ] )
+  , Mont::unsafe_make( [ 0x8da6c6f6 , 0xaedaf3b5 , 0x2cf64552 , 0x2135e4ee , 0xce3ee5c5 , 0xb6ca08c3 , 0xd40f2d6b , 0x0c07f732 ] )
+  , Mont::unsafe_make( [ 0xdcd7e76f , 0x824c7501 , 0xb4508612 , 0x5b949c66 , 0xa779d664 , 0x248dcfa3 , 0x0fe67a45 , 0x0614170f ] )
+  , Mont::unsafe_make( [ 0x8372dfa5 , 0x9b3051fe , 0x97aa724d , 0x2b4e749e , 0x825ad443 , 0x917c4934 , 0x36b5b38a , 0x273ab52e ] )
+  , Mont::unsafe_make( [ 0xa7f3932a , 0x47ce0e1c , 0xf4dacf73 , 0x7827fa0d , 0xd51644d5 , 0x0aed7ad3 , 0x9a27a62f , 0x2a28d0a9 ] )
+  , Mont::unsafe_make( [ 0x14fe16b8 , 0x20eec4f3 , 0x9f616cb8 , 0xca8d22ba , 0x7e0d0748 , 0x96e7c008 , 0x26645a6b , 0x17b0e74f ] )
+  , Mont::unsafe_make( [ 0x86eca867 , 0x95de9854 , 0x8b5e26e4 , 0x3e113680 , 0x0f4f830b , 0x4c317b46 , 0x1e744a9e , 0x073f7a9e ] )
+  , Mont::unsafe_make( [ 0xb4586049 , 0x04467b99 , 0x3640081f , 0x962fdd6d , 0xf99b535a , 0xbacf8f80 , 0xf4216067 , 0x16e791e6 ] )
+  , Mont::unsafe_make( [ 0xd25a7bd1 , 0xd284da1c , 0x8cd8b29a , 0x6f40e6a4 , 0xb18582fa , 0x644e98a6 , 0xfae4438e , 0x265f7661 ] )
+  , Mont::unsafe_make( [ 0x3b67439e , 0xafbbd04f , 0xee6944a9 , 0xaf43aa23 , 0x5410fcdf , 0xe4e120d7 , 0xe8136968 , 0x2a3cad0b ] )
+  , Mont::unsafe_make( [ 0x9d8f4f0d , 0xf4ed67b6 , 0xc8e9e03d , 0x3195589e , 0x3c73284a , 0x0534fb35 , 0x5a823838 , 0x0db4d6ad ] )
+  , Mont::unsafe_make( [ 0xbb2c91e1 , 0xc61b0f16 , 0xda4343bb , 0x23762b35 , 0xc1f50389 , 0x4c16bab0 , 0x4963b3f4 , 0x0b88d286 ] )
+  , Mont::unsafe_make( [ 0x06798d98 , 0x22ff4ed7 , 0x7568b1a8 , 0xecc6bbf5 , 0xbe61787a , 0x2241f15d , 0x085d1055 , 0x07ce3183 ] )
+  , Mont::unsafe_make( [ 0xcead86bd , 0x661f6381 , 0x4896843d , 0xab047eec , 0x0c5f89a3 , 0xb00bb921 , 0x1ac123da , 0x2d2d54c6 ] )
+  , Mont::unsafe_make( [ 0x4719b4e5 , 0x58e747fd , 0x2da5a09f , 0x74f50640 , 0xf92433f1 , 0xfe4340fb , 0xda15bed3 , 0x11746b15 ] )
+  , Mont::unsafe_make( [ 0xeb85651c , 0xfa94942c , 0x577de8b9 , 0xeda89d0a , 0xff89fe3b , 0x7c09589f , 0x5cf815a0 , 0x0c9cbf36 ] )
+  , Mont::unsafe_make( [ 0x92e21d9c , 0x88a3140c , 0x10aacdfa , 0x0ea39a03 , 0xf8b980ed , 0x176befc1 , 0x1f5ee465 , 0x146be9cd ] )
+  , Mont::unsafe_make( [ 0x2e652ccd , 0xa1f0d690 , 0x0cad2829 , 0x339d07b7 , 0x61c95874 , 0xcb5556fc , 0x87f95176 , 0x2002c908 ] )
+  , Mont::unsafe_make( [ 0x33cac074 , 0x5a8948d9 , 0x56fa57c1 , 0x0c38b7de , 0x7d4451a8 , 0xc182022c , 0xade868e9 , 0x014fb731 ] )
+  , Mont::unsafe_make( [ 0x9128a838 , 0x7aa0f5ea , 0x314447ba , 0xe8fdd796 , 0xce52d2bb , 0x676634d7 , 0xe47914b1 , 0x1d9dab95 ] )
+  , Mont::unsafe_make( [ 0x90ea51f4 , 0x0b3f2166 , 0x171048f6 , 0xf5515e76 , 0xd28cec62 , 0xc24ca920 , 0x489e5425 , 0x232bdd9a ] )
+  , Mont::unsafe_make( [ 0x46a26fe0 , 0xec89c042 , 0x96bc64a3 , 0x41e30d33 , 0x437cb8e5 , 0x094c4092 , 0x15d6f75a , 0x2fc8ed4d ] )
+  , Mont::unsafe_make( [ 0x9bd207ff , 0x3abeb01c , 0x22fe4547 , 0xa15d74f1 , 0x0b77e6c6 , 0x7e909de8 , 0x95836255 , 0x2f1b3271 ] )
+  , Mont::unsafe_make( [ 0xdbc1672d , 0xe7766006 , 0xe04cab58 , 0x1756bd97 , 0x6df7fd74 , 0xea74e870 , 0xb844ebf9 , 0x13c7b92a ] )
+  , Mont::unsafe_make( [ 0x58d89c7c , 0x65d4fb4c , 0xc4e573ae , 0x5ba0fea7 , 0x5fa93d98 , 0x71101398 , 0x2c2485f6 , 0x1674f748 ] )
+  , Mont::unsafe_make( [ 0x819efb08 , 0xea284648 , 0xfba4de47 , 0x2153e3f1 , 0x12d29197 , 0x4cc65443 , 0x1372ea3f , 0x2cc68029 ] )
+  , Mont::unsafe_make( [ 0x63547589 , 0xc78ddc06 , 0x0b1b056c , 0xe2ad66df , 0x3d764e79 , 0x7442c565 , 0x321e0bab , 0x27500ca9 ] )
+  , Mont::unsafe_make( [ 0xd51b0f61 , 0x69ab8bb3 , 0xda7607c3 , 0x2a7b8b97 , 0xd4031ddb , 0xc975d8a1 , 0x83ee30b8 , 0x223850ef ] )
+  , Mont::unsafe_make( [ 0x917d688c , 0xd26ab9c8 , 0x84ff037a , 0xe2673370 , 0x8c6439be , 0xff4d014c , 0x8779ca7d , 0x2995214a ] )
+  , Mont::unsafe_make( [ 0x3a30695e , 0x586752d2 , 0x1905c73b , 0x98e1df57 , 0x482be2a6 , 0x0e370ca5 , 0xbfecf59a , 0x2d38cce6 ] )
+  , Mont::unsafe_make( [ 0x8689cb9a , 0x35072d07 , 0xd5b52548 , 0x4657f9c5 , 0x6e1af0ee , 0x4a65c1f8 , 0xca1ceaeb , 0x2cd20fd0 ] )
+  , Mont::unsafe_make( [ 0x5995a9a6 , 0x9b77590e , 0x6a5fb455 , 0x6c750865 , 0x498d8129 , 0x8ca2d458 , 0x0370fc96 , 0x1d32397f ] )
+  , Mont::unsafe_make( [ 0x1ab58609 , 0xc1a26181 , 0xe8d21d05 , 0x2a0dc809 , 0x9902c581 , 0x706822e4 , 0xbceb55e9 , 0x081ed401 ] )
+  , Mont::unsafe_make( [ 0x1c183280 , 0x4f61c529 , 0x43a07b12 , 0xfa169c96 , 0x452c9fad , 0x53e26376 , 0x7fce231f , 0x033fcb76 ] )
+  , Mont::unsafe_make( [ 0x7f489df2 , 0x40b17ffb , 0x54529aca , 0xfdf4f3ec , 0x49bda912 , 0xa6e160f8 , 0x68b1a775 , 0x0399c36b ] )
+  , Mont::unsafe_make( [ 0x514d2f3f , 0xfbe9220b , 0xb1b2b20d , 0x8d8cf6ad , 0xa417459f , 0x56ee4830 , 0x10805af2 , 0x1d2ab26a ] )
+  , Mont::unsafe_make( [ 0x0a4d904d , 0x60e5e44a , 0x376b87cd , 0x5cba46a3 , 0xf6d4fd02 , 0x8a5af037 , 0x08aa51bd , 0x18147dd5 ] )
+  , Mont::unsafe_make( [ 0x828ec5ef , 0x98b702c0 , 0x41f5781a , 0xbebf2e78 , 0x8501422a , 0x66e4f04d , 0xfa8a7042 , 0x075468d5 ] )
+  , Mont::unsafe_make( [ 0xdc0fda89 , 0x744c257e , 0xb994e601 , 0x03a61d12 , 0x099968ef , 0xa8edf654 , 0x315a2631 , 0x0c2819f2 ] )
+  , Mont::unsafe_make( [ 0xcfba9844 , 0x7152414b , 0x13119ca9 , 0x66ea438c , 0xf258dee0 , 0xec83e35a , 0xc973b8c5 , 0x026ac360 ] )
+  , Mont::unsafe_make( [ 0x3d069788 , 0x33412494 , 0xd814cb1b , 0xa0fa2244 , 0x9cbc294f , 0xcb4282cb , 0x8b75066e , 0x2ba2d2a7 ] )
+  , Mont::unsafe_make( [ 0x21335aa5 , 0xc1c1947b , 0xaac84209 , 0x315d7de8 , 0xfbf079b5 , 0x8df1f8e9 , 0xf3bec50b , 0x2fb4d5bb ] )
+  , Mont::unsafe_make( [ 0x653a4865 , 0xdf12aeb9 , 0xbddcf04b , 0xc0646546 , 0xdffb3614 , 0x1e4ae9c7 , 0xf211ba25 , 0x12bbd7a0 ] )
+  , Mont::unsafe_make( [ 0x1c8b74e4 , 0xe84e83a2 , 0xa6599cfa , 0x36ada820 , 0xf85cd1d2 , 0x596242b5 , 0xd9e60a13 , 0x2e5f4eeb ] )
+  , Mont::unsafe_make( [ 0xdd07e01e , 0xd2c01b91 , 0x91614c06 , 0xa80256e8 , 0x64697c5f , 0x4934d4ea , 0x8a48680c , 0x1f53e5cc ] )
+  , Mont::unsafe_make( [ 0x306b5868 , 0xde1f004f , 0xe4896dd1 , 0x39d4da8b , 0xfb951660 , 0x0efc23d0 , 0x9e0c62e2 , 0x13900be9 ] )
+  , Mont::unsafe_make( [ 0x2eec1f5f , 0x38f67b87 , 0x9e6ee3c6 , 0x610b49b1 , 0x91846826 , 0x9c6aada8 , 0x62f6f923 , 0x27380d7b ] )
+  , Mont::unsafe_make( [ 0x79590293 , 0x8b8b2091 , 0x503a95ce , 0xe627b8a8 , 0xfb972a08 , 0x8f7d2e27 , 0xfb554402 , 0x264b94e5 ] )
+  , Mont::unsafe_make( [ 0x4c633da6 , 0xf5929f38 , 0x580d1baa , 0x883ed254 , 0x8d6c27c4 , 0x0d9567be , 0xcb2228b0 , 0x10251999 ] )
+  , Mont::unsafe_make( [ 0x25b708a1 , 0x0a070d16 , 0x53000070 , 0x6268458c , 0xc5639e77 , 0x37f5b597 , 0xca619df8 , 0x04f856b1 ] )
+  , Mont::unsafe_make( [ 0x6cab45a9 , 0x847ad0f4 , 0xebe7fba0 , 0xf95f3ffb , 0xd95a6688 , 0x6a6a345a , 0x2e364548 , 0x14351aad ] )
+  , Mont::unsafe_make( [ 0xe01b0e6e , 0x3e614e6c , 0x27f7ed37 , 0xeb5bb9cc , 0x0688c99c , 0x0f5b6d9b , 0x9fe7dc91 , 0x043724e6 ] )
+  , Mont::unsafe_make( [ 0x3350c58e , 0x74b02c8d , 0xad221e46 , 0xd0f3be6d , 0x95f81696 , 0xc009bf01 , 0x06a87a9d , 0x0ef12b4f ] )
+  , Mont::unsafe_make( [ 0x04da2fba , 0x1b9255dc , 0x9d7e5416 , 0x330169a9 , 0x4cbea981 , 0x43552802 , 0xb0c601e2 , 0x26b48886 ] )
+  , Mont::unsafe_make( [ 0xd5f71504 , 0xd23d6dc5 , 0x890cf547 , 0x010e57f6 , 0xc3916aa5 , 0x6ce5423e , 0x4fff01cd , 0x245b7ee2 ] )
+  , Mont::unsafe_make( [ 0x7a37528a , 0x31b44ffd , 0xdec7e90b , 0x045ddc86 , 0xef13e549 , 0x931b6900 , 0xd1bf0e9b , 0x1fdd736e ] )
+  , Mont::unsafe_make( [ 0x471f52d0 , 0x721d9998 , 0x3537ad27 , 0xc0350b0c , 0xac813e69 , 0x2441ccec , 0x87c680f3 , 0x0921b413 ] )
+  , Mont::unsafe_make( [ 0x3fd972d6 , 0x8eec39d5 , 0x62dc73ab , 0x29ead38e , 0xabad437b , 0x590acffb , 0x647b3cc7 , 0x2d179de0 ] )
+  , Mont::unsafe_make( [ 0xb035a50d , 0x9f2a00d6 , 0x0dea96e2 , 0x18508303 , 0xe521a4d2 , 0xe9e29c01 , 0xa5baa4c9 , 0x231e86cd ] )
+  , Mont::unsafe_make( [ 0xa6253ed2 , 0xca629a63 , 0x4b465954 , 0xf7d8610c , 0xb2263fa2 , 0xbc4c0d7f , 0x8b7928af , 0x1c40b50c ] )
+  , Mont::unsafe_make( [ 0xa6f56ba3 , 0xe1354b6b , 0xc5752156 , 0x36402c0e , 0xbe4a5aa8 , 0x14a03fd6 , 0x63ce3397 , 0x184564d0 ] )
+  , Mont::unsafe_make( [ 0xe7b7a762 , 0x3a207b41 , 0x9cb6250a , 0xd8aca1fb , 0x4da8a3d1 , 0x78e07872 , 0x730aa56f , 0x0ec4b363 ] )
+  , Mont::unsafe_make( [ 0x88910fcd , 0x68212944 , 0x0da625ea , 0x0dc22e6f , 0xd4418641 , 0xc6bd2319 , 0x4788d657 , 0x08af4fc1 ] )
+  , Mont::unsafe_make( [ 0x7acac4af , 0x081fee41 , 0xebd3056c , 0xcc5760a6 , 0xf7d7f374 , 0x253b81e0 , 0x3b3f63a9 , 0x08419eed ] )
+  , Mont::unsafe_make( [ 0xbb55a08a , 0xaa61d081 , 0x8f8b7117 , 0x22c3d799 , 0x807f5144 , 0xf4a4238a , 0x07f7efa4 , 0x277c7298 ] )
+  , Mont::unsafe_make( [ 0xbb68877f , 0x7b93c122 , 0x498da934 , 0xfa75f099 , 0x04458d1c , 0x241fad2c , 0x68a6ec7d , 0x1675107a ] )
+  , Mont::unsafe_make( [ 0xe1e21aac , 0xc2af149e , 0x9d6362e3 , 0xed3e17a7 , 0x8dc003ab , 0x9a1bce56 , 0x15b89c18 , 0x080cc2f1 ] )
+  , Mont::unsafe_make( [ 0x82de27c9 , 0xb25c2383 , 0xdb23ebae , 0x83040b77 , 0x6e120a58 , 0x3c5670a0 , 0x2a189dcf , 0x27ce0a15 ] )
+  , Mont::unsafe_make( [ 0x855b4b77 , 0x63805a6f , 0x6cdc34a4 , 0xb55bd584 , 0xa745be3a , 0xf9c08c12 , 0x7aee8c11 , 0x0bd13084 ] )
+  , Mont::unsafe_make( [ 0xe4e0db64 , 0x9aae9803 , 0x09c852c0 , 0xeb93b032 , 0xb6be9cae , 0xe97b433a , 0x978e702a , 0x0b7a3022 ] )
+  , Mont::unsafe_make( [ 0x1ca256e8 , 0xda4f674b , 0x73c5cdef , 0x306b998d , 0xdaaba14f , 0x1bbead27 , 0xc5e51e87 , 0x17dd9037 ] )
+  , Mont::unsafe_make( [ 0x87978559 , 0xd4684122 , 0x38169b9a , 0x62d0276b , 0xefa92f5e , 0x892f6c8d , 0xd24bd49b , 0x2e831f52 ] )
+  , Mont::unsafe_make( [ 0x49ecd767 , 0xc2c2a8de , 0x1320cfe5 , 0xe90bf3f3 , 0x7bce5953 , 0xa7d4ff3c , 0x88d4b8ae , 0x27a58cce ] )
+  , Mont::unsafe_make( [ 0x498b18d9 , 0xf788f9ad , 0x41abe8ca , 0xfaa7a85c , 0x814ffb3c , 0x8c10b42d , 0x0fc2ff6d , 0x2bcfba20 ] )
+  , Mont::unsafe_make( [ 0x02160b31 , 0xf6e7a4c5 , 0x48347292 , 0x29350fa4 , 0xa954b74d , 0x247c4d96 , 0xead8ca93 , 0x0874a0b7 ] )
+  , Mont::unsafe_make( [ 0x24974b64 , 0x415b0da1 , 0xd27e3884 , 0xbac4b25d , 0x407e5d21 , 0x1b2b7b4b , 0x5f1bc845 , 0x300aecb3 ] )
+  , Mont::unsafe_make( [ 0xb12e4a93 , 0xf1e5af28 , 0xbfeb2466 , 0x3bb62193 , 0xaaade673 , 0x3a56dbb3 , 0xa025bd0b , 0x128d6043 ] )
+  , Mont::unsafe_make( [ 0x1460bbef , 0xc9dec028 , 0xbb82a3b5 , 0x5edbcd25 , 0x4c56d579 , 0x4bccc74e , 0x02bcd6b8 , 0x259c6999 ] )
+  , Mont::unsafe_make( [ 0x0b2861cc , 0x562a6cf7 , 0x49fcda3d , 0x6144e084 , 0xeef1cfd8 , 0x506e367e , 0x10de981b , 0x0ec05f04 ] )
+  , Mont::unsafe_make( [ 0x5d01fa09 , 0x3e312911 , 0x6ec0de85 , 0xa6846b72 , 0xc1c0a2f2 , 0xd199618e , 0xd2060865 , 0x2d81350e ] )
+  , Mont::unsafe_make( [ 0x8c33afdf , 0x096d1198 , 0x368a7ae3 , 0xa90379b0 , 0x902d376d , 0x441eec8e , 0x497fb2d5 , 0x11375045 ] )
+  , Mont::unsafe_make( [ 0x974fc776 , 0x6f9e7cf1 , 0x5c30cace , 0x1d985edf , 0x95d85009 , 0x961d0fc3 , 0xdc4b4445 , 0x0107e301 ] )
+  , Mont::unsafe_make( [ 0x68d8b4b7 , 0xa7d8b1a3 , 0xf9d24ca5 , 0x356b0cfa , 0x4b4f8c12 , 0x2238a60e , 0x84adf685 , 0x039836f5 ] )
+  , Mont::unsafe_make( [ 0xd84db10b , 0x34347206 , 0x4a245707 , 0x80ebf42c , 0x755b29b2 , 0xa6ec4a84 , 0xca04d920 , 0x1f7d4112 ] )
+  , Mont::unsafe_make( [ 0x85e9874c , 0x4f1b9b48 , 0xce0817f9 , 0xa87bd3a4 , 0x28d1a927 , 0xd42da6b5 , 0x92a317a0 , 0x2b5200bd ] )
+  , Mont::unsafe_make( [ 0x2696b6e6 , 0x592619b8 , 0xdc9dd4aa , 0xed623fdf , 0x3f6b3018 , 0xc1ed320c , 0x84cf45a0 , 0x28a8722d ] )
+  , Mont::unsafe_make( [ 0x15adc6c2 , 0xe61f931f , 0xdd1b28de , 0xbc56ca47 , 0xaaa3ac29 , 0x2a31a106 , 0xe1132c5a , 0x0d6e90a5 ] )
+  , Mont::unsafe_make( [ 0x59710d3e , 0x72577e5a , 0xc9db24c3 , 0x2b2f7752 , 0xab0c0a2e , 0xb65dfa17 , 0x7ca2e108 , 0x2eec69fc ] )
+  , Mont::unsafe_make( [ 0x7b9edea9 , 0x6a52f922 , 0xba2304e8 , 0x234f461c , 0x3c7c8515 , 0x098d8300 , 0xe5e11222 , 0x15fd2269 ] )
+  , Mont::unsafe_make( [ 0x656332e6 , 0x5bfe9097 , 0x25ba4257 , 0x2e8b7736 , 0x7f5e700f , 0x2953b109 , 0x78906aed , 0x2f4ff2e3 ] )
+  , Mont::unsafe_make( [ 0x842f71b4 , 0x676d0d63 , 0xf51bc66e , 0x8d14d764 , 0x4b6ab4e1 , 0xc4012264 , 0xb1991d1a , 0x246c996e ] )
+  , Mont::unsafe_make( [ 0xc1f39dee , 0x32a2f082 , 0x998ae8ff , 0x07aed5a5 , 0x563041b4 , 0x1147867f , 0x1e896ba3 , 0x015ce0e6 ] )
+  , Mont::unsafe_make( [ 0xbb276518 , 0xf3f49209 , 0x61f1c83d , 0x02a1e552 , 0x7dbd94cc , 0xd13f7f63 , 0x391b7888 , 0x1fb91d59 ] )
+  , Mont::unsafe_make( [ 0x539473bf , 0x49e6d8e0 , 0xb2428613 , 0x2e099eca , 0x6a92e156 , 0x45b2cc39 , 0x6cda8fce , 0x0bbbe238 ] )
+  , Mont::unsafe_make( [ 0x45582b27 , 0x48dfbc0a , 0x432d97aa , 0x2a68ded1 , 0xe963216e , 0x81fb11e0 , 0x7d2ca1aa , 0x0408cabc ] )
+  , Mont::unsafe_make( [ 0x33579e0e , 0x34aab7f0 , 0xe0608f57 , 0x9356b2f1 , 0x1483acc1 , 0x2b25d5cf , 0x7b7f0834 , 0x1ea07534 ] )
+  , Mont::unsafe_make( [ 0x877d9e50 , 0x61165082 , 0x17beb57e , 0x183f109f , 0x4064204a , 0xb08a0b8c , 0xe1178f9f , 0x113605f6 ] )
+  , Mont::unsafe_make( [ 0xf8b919b8 , 0x8c6ae7e3 , 0x94f33708 , 0xb714cc22 , 0x52429f43 , 0xa0627c5d , 0xea2f8c05 , 0x29f1bfa2 ] )
+  , Mont::unsafe_make( [ 0x8377bf5e , 0xa1f88ed6 , 0x78a54319 , 0x3f119eb1 , 0x460b17c7 , 0x80cdb8d8 , 0xe08270db , 0x2a022321 ] )
+  , Mont::unsafe_make( [ 0x40c21122 , 0x6ce58682 , 0x20e51052 , 0x303a25f3 , 0x801ceae0 , 0x9358fec5 , 0x9a86a1d9 , 0x28d5c0f8 ] )
+  , Mont::unsafe_make( [ 0xa85381fd , 0x7cb4cbf4 , 0x43937fec , 0x4fe10305 , 0xb530e9a1 , 0x3129ad10 , 0xfe746ada , 0x065487fa ] )
+  , Mont::unsafe_make( [ 0x0a5a0a63 , 0x1a17c0ed , 0x3b3c8e39 , 0x3407a2fa , 0x1b9c842f , 0x2c0b8592 , 0x44ddb68f , 0x08c32c42 ] )
+  , Mont::unsafe_make( [ 0x51a2c2ca , 0x438f93e0 , 0xc2ac286b , 0x2abceac5 , 0x792b6965 , 0x8430d127 , 0x6f631d01 , 0x22fc81d4 ] )
+  , Mont::unsafe_make( [ 0x40bd0ff5 , 0xea971132 , 0xacb91e7d , 0x0895b27c , 0x36cd7c77 , 0x4f5d72d0 , 0xe6628de9 , 0x019feae5 ] )
+  , Mont::unsafe_make( [ 0x21e97653 , 0x35b4deae , 0xc4db5074 , 0x19038de1 , 0x770034f7 , 0xa376f869 , 0x75e15b3a , 0x1bb7ba0f ] )
+  , Mont::unsafe_make( [ 0x107025df , 0xb90447a4 , 0xb757dbf0 , 0xa4d0a55a , 0x92fd074b , 0xb80708a7 , 0xdedeb60c , 0x00384d81 ] )
+  , Mont::unsafe_make( [ 0x305f9418 , 0x6fac2137 , 0x879413b8 , 0xe8477855 , 0x044a43b9 , 0xae043d43 , 0x93944301 , 0x020da57c ] )
+  , Mont::unsafe_make( [ 0xb01d5647 , 0x1868c8d8 , 0x9c81a82c , 0x654b4b62 , 0x56155357 , 0x9554a89c , 0x30cfe1a6 , 0x29346bed ] )
+  , Mont::unsafe_make( [ 0x915e6b20 , 0xd7d1c21a , 0x007392bf , 0x3c7ded12 , 0x5c365e32 , 0x4c87ffb8 , 0xcb752963 , 0x27bd1c0a ] )
+  , Mont::unsafe_make( [ 0x9ad61ca3 , 0x903c714b , 0xe26ffa4b , 0xdd4850f9 , 0xa1b637dc , 0x5e738ca4 , 0xa2dd41bc , 0x2ee2ef0f ] )
+  , Mont::unsafe_make( [ 0x403fe81d , 0x3c78273e , 0x51be9677 , 0x6c59e4ef , 0xb6050aa3 , 0xa9c458bc , 0xbfd34316 , 0x1f2fac27 ] )
+  , Mont::unsafe_make( [ 0x1bfc2bba , 0x44565b14 , 0xc35a752d , 0x877329e8 , 0xf305c017 , 0xd107ec0c , 0x4d656921 , 0x18002354 ] )
+  , Mont::unsafe_make( [ 0x8311fefe , 0x7ce3490b , 0x4d376070 , 0xd1d4a98e , 0xcdd401d2 , 0xee26dab1 , 0x643118ec , 0x02f764af ] )
+  , Mont::unsafe_make( [ 0x955d0f6f , 0xed18ea02 , 0x591f18b8 , 0x08283435 , 0xe59d1c0b , 0xb1b1364f , 0x4d34d6b4 , 0x1e9868ac ] )
+  , Mont::unsafe_make( [ 0xe59a6609 , 0x04f94656 , 0xd63422a3 , 0x0185149b , 0x74143a78 , 0xb497aa99 , 0xd33ad5b9 , 0x0d20f294 ] )
+  , Mont::unsafe_make( [ 0x5fa4487d , 0x5539f1af , 0x82db149a , 0x58404e0b , 0x40b9bba8 , 0xbf959a9a , 0xd621db3c , 0x0a35b724 ] )
+  , Mont::unsafe_make( [ 0xb6a741d2 , 0x4a157769 , 0xe4076436 , 0x8b4c0d5d , 0x3c390fb4 , 0x9509efe5 , 0xfad4ceed , 0x208636c3 ] )
+  , Mont::unsafe_make( [ 0x65370ff9 , 0xde3314d0 , 0x7cd95e44 , 0x8e198e93 , 0x51837579 , 0x73bd51ef , 0x79588a3b , 0x2f7b4974 ] )
+  , Mont::unsafe_make( [ 0xfb98ff57 , 0x42a28a04 , 0xd3c3a9e0 , 0xea133581 , 0xa80c6311 , 0x47bf90ab , 0xc3193978 , 0x1e8c1d12 ] )
+  , Mont::unsafe_make( [ 0xf2ed3d9e , 0x8d7d74e5 , 0xa66053ab , 0x627c4846 , 0xf02a9dd8 , 0x408df88c , 0xf7c90eb4 , 0x171fac3b ] )
+  , Mont::unsafe_make( [ 0x71e99ab7 , 0xd7312239 , 0xe5eaaf6b , 0xdf3aaa0e , 0xe9063c5a , 0xddfd0cb0 , 0xc8895598 , 0x035c9126 ] )
+  , Mont::unsafe_make( [ 0x499c6466 , 0x6ba01ab6 , 0x5ca811fe , 0x68600ae5 , 0xf498d721 , 0xe84281c2 , 0x91a64446 , 0x0e2dc8d0 ] )
+  , Mont::unsafe_make( [ 0x32ca9c38 , 0xc04fa86a , 0x92a51c75 , 0x68bbf756 , 0xf1a44e81 , 0x78ed9970 , 0xf662fb3a , 0x14bbf139 ] )
+  , Mont::unsafe_make( [ 0x093f2924 , 0x1431f65e , 0x6be516af , 0xcc297b4d , 0x15f57151 , 0xae703f96 , 0xd23a9780 , 0x17fb8e5d ] )
+  , Mont::unsafe_make( [ 0xb3d18dd9 , 0x92d9b433 , 0x901a0d1e , 0xd7ccf413 , 0x615cc3c8 , 0x873d9e61 , 0x2eb59d39 , 0x1d9f66f3 ] )
+  , Mont::unsafe_make( [ 0xcc06659c , 0xf224926e , 0x765d6fee , 0x18052c46 , 0xde40ed21 , 0xd722ae59 , 0xe36a1794 , 0x1c44bb11 ] )
+  , Mont::unsafe_make( [ 0x1ff82a20 , 0xc9c6d263 , 0x6fa9e723 , 0x2aec2edf , 0xfcbc07b5 , 0x7ae3ca5f , 0xba66b342 , 0x10aee705 ] )
+  , Mont::unsafe_make( [ 0x45681ff7 , 0x0babb8ed , 0xd68d0379 , 0x45920435 , 0x3bf0021d , 0x08db2ec3 , 0x9158d885 , 0x1d8048b8 ] )
+  , Mont::unsafe_make( [ 0x0557c5a3 , 0x05e5ba98 , 0x45341716 , 0x3002b19e , 0xe903b350 , 0x07992552 , 0x900fe5af , 0x0b2f802d ] )
+  , Mont::unsafe_make( [ 0x448cc752 , 0xbc445fa1 , 0xb2600049 , 0xf1f83876 , 0xb80d0e4a , 0x75906a0f , 0x607c88f1 , 0x1dae2774 ] )
+  , Mont::unsafe_make( [ 0xa77fad25 , 0x13e0aa91 , 0x3c73f63a , 0xe91e0980 , 0x6f9b7853 , 0x14a79ddc , 0x6f242985 , 0x251be4ad ] )
+  , Mont::unsafe_make( [ 0xee08060d , 0x15a7fd86 , 0x74328d70 , 0x42382912 , 0x56ab4e7e , 0x09b1efb1 , 0x0b151274 , 0x2294b638 ] )
+  , Mont::unsafe_make( [ 0x5bb82785 , 0x4194a846 , 0x6c7197f0 , 0x605b3b1e , 0x55c2f6aa , 0xcc14e69f , 0xceb15f5b , 0x08a1e363 ] )
+  , Mont::unsafe_make( [ 0x95c17203 , 0x131b8abc , 0xc85317c8 , 0x04b15dd7 , 0xfa84ae6b , 0xce3a5146 , 0xcadd9b8a , 0x0450991d ] )
+  , Mont::unsafe_make( [ 0x02d562e6 , 0x7962ff43 , 0xfd5acad8 , 0x1f62d230 , 0x3b32b9f1 , 0x07649366 , 0x17255720 , 0x23d9a5e5 ] )
+  , Mont::unsafe_make( [ 0x2c436f08 , 0xc1576092 , 0x11cde13a , 0xa68ffd95 , 0x92695ee7 , 0xa841c723 , 0xb956f8f0 , 0x21609798 ] )
+  , Mont::unsafe_make( [ 0x512f7d78 , 0x56698f92 , 0x2919916a , 0x79196a0e , 0xae64143d , 0x3a91d16e , 0x922d4f3d , 0x2f8a55e2 ] )
+  , Mont::unsafe_make( [ 0x94796012 , 0x7abfc902 , 0x78076bef , 0x9d3e53be , 0x75380d37 , 0xd5c2f009 , 0xbf0dd29c , 0x232017ba ] )
+  , Mont::unsafe_make( [ 0x70ef7776 , 0x7fd78b08 , 0x3dac4087 , 0x36be8a5b , 0x582c6f2c , 0x37ddc4b2 , 0x8df0e9e1 , 0x1c25a8da ] )
+  , Mont::unsafe_make( [ 0x648704da , 0x4fa9f7e8 , 0x75ca73f5 , 0x8c43f358 , 0xdbb1f5e4 , 0xf788036c , 0xf21b16c2 , 0x014a11fd ] )
+  , Mont::unsafe_make( [ 0xd1f146ee , 0x05ae853b , 0xcf48d876 , 0x9ee0a185 , 0x4b83ee97 , 0x1a819be2 , 0x4cbb52bc , 0x2d4b9dd6 ] )
+  , Mont::unsafe_make( [ 0xbe2af722 , 0x7d38f267 , 0x92abc58c , 0xbbf09178 , 0x25c8c765 , 0xe3e5925c , 0xe2004295 , 0x04d84bcd ] )
+  , Mont::unsafe_make( [ 0x09b3e86b , 0x6ea5e002 , 0xdc1b0344 , 0xfeb288a2 , 0xc6e85b5c , 0x7d409702 , 0x4f2574ba , 0x2eea9b2e ] )
+  , Mont::unsafe_make( [ 0x896abf05 , 0xfed1ee6e , 0x83aa5422 , 0xf7c96129 , 0x02306fde , 0x40a80ccf , 0xecffc768 , 0x2543a77d ] )
+  , Mont::unsafe_make( [ 0xd88e6ffb , 0x9d8fac88 , 0x03731e79 , 0x30bde79e , 0xe53d9da3 , 0xa6ea6650 , 0x1576b95e , 0x2c67a08f ] )
+  , Mont::unsafe_make( [ 0x826514be , 0x49ac2532 , 0x4f79c09b , 0xd70cb61b , 0x910789ad , 0x516bac91 , 0xd96cfffd , 0x1adfcffb ] )
+  , Mont::unsafe_make( [ 0x1edfd6bd , 0x8a452051 , 0xead8fabb , 0x1deb429a , 0x6f2bca2d , 0x06ae5d88 , 0x1159128f , 0x21c94775 ] )
+  , Mont::unsafe_make( [ 0xa4cfb4a9 , 0xc78131d5 , 0xa8d9ec81 , 0x7d851ebf , 0x53106156 , 0x94cda992 , 0x546dff0b , 0x0aa6bdfc ] )
+  , Mont::unsafe_make( [ 0xd45a1944 , 0xefa6b06c , 0x16df0ac4 , 0x821252df , 0x52969554 , 0xebe556d0 , 0x8d602375 , 0x23cab58c ] )
+  , Mont::unsafe_make( [ 0x1dbb65fb , 0x1242d1f3 , 0xc6479733 , 0xd4a7de32 , 0x2e996630 , 0x069d11aa , 0x6aca6835 , 0x154d72f1 ] )
+  , Mont::unsafe_make( [ 0x092f1584 , 0x56d43c0b , 0x9ca47579 , 0xa1fedc8b , 0x503e928f , 0x19ff5013 , 0x264aef94 , 0x27178fb3 ] )
+  , Mont::unsafe_make( [ 0x21db1c09 , 0x5d7025f7 , 0x22322401 , 0x93f68b11 , 0x3c262cf6 , 0x1a579d09 , 0x50ed542e , 0x266dadc1 ] )
+  , Mont::unsafe_make( [ 0x7726e4af , 0x80603e79 , 0xd99b5b84 , 0xd4d06acf , 0x43e2ff7e , 0xe557c287 , 0x7f0b24cd , 0x2865d382 ] )
+  , Mont::unsafe_make( [ 0xffeb3f2d , 0x7bcd4d1e , 0xa93dbaf3 , 0xe26125a2 , 0x84009f63 , 0xfafeee72 , 0xfe902748 , 0x058a2042 ] )
+  , Mont::unsafe_make( [ 0xd54895b2 , 0xce247571 , 0x63203958 , 0xba8d996d , 0x319c55b0 , 0xc858e3c9 , 0xaa0de451 , 0x00fe05ec ] )
+  , Mont::unsafe_make( [ 0x956414ff , 0x17d9647b , 0xa75e8a67 , 0x00eb100f , 0x2708f94d , 0xcb3b36e4 , 0xf26414a0 , 0x1e122670 ] )
+  , Mont::unsafe_make( [ 0x42d88116 , 0xcb6bc190 , 0x7a929102 , 0xe76cf155 , 0x25174912 , 0x8e16ba86 , 0x49249fd0 , 0x1c4c1935 ] )
+  , Mont::unsafe_make( [ 0x33464784 , 0xbb377ec5 , 0xb853b3a6 , 0xeefa6eee , 0x8b6130e4 , 0x39366a93 , 0xdd2ab2ab , 0x0ced2c9b ] )
+  , Mont::unsafe_make( [ 0x6eacc7e3 , 0x6a540501 , 0x7a248763 , 0xbef76a9e , 0xdcc0fbc2 , 0x63bf8a6b , 0xca5c088a , 0x202a83f8 ] )
+  , Mont::unsafe_make( [ 0x986551be , 0xd9e18166 , 0x60e8176c , 0x1c826082 , 0x056c9e79 , 0x382a8f27 , 0xa40ba0bd , 0x26e204a3 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// circomlib's optimization whatever
+pub const CONST_S: [Mont; 1890] = 
+  [ Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x9b2575f7 , 0x042a7cec , 0xc5109a39 , 0xfb7a4012 , 0x38df2130 , 0x12046cb7 , 0x7efd9cb9 , 0x239b3802 ] )
+  , Mont::unsafe_make( [ 0x69e59ce6 , 0xa82ec3c2 , 0x0eb072a6 , 0xa3cd165c , 0x7979763a , 0x13c92a66 , 0x86afea74 , 0x2c891df4 ] )
+  , Mont::unsafe_make( [ 0x49b197fc , 0x5cb321dc , 0x9f2891bb , 0x5e91fbab , 0x21efc925 , 0x69a18e38 , 0x2864fd62 , 0x0b2bccd9 ] )
+  , Mont::unsafe_make( [ 0x655c1dcd , 0x64676880 , 0x00f5c8e2 , 0xd76349c0 , 0xb03e09aa , 0xdf9c3e76 , 0xf5ef8e97 , 0x2d883466 ] )
+  , Mont::unsafe_make( [ 0x23dbbe1f , 0x02155ee4 , 0xa4b8df75 , 0x17989122 , 0xbcce67b7 , 0xccb85621 , 0x1570e1eb , 0x221ac6be ] )
+  , Mont::unsafe_make( [ 0x85534245 , 0x5948adb9 , 0xa626444b , 0x746b3cb4 , 0x271d6426 , 0x8a238704 , 0x6ec24195 , 0x24024ec7 ] )
+  , Mont::unsafe_make( [ 0x943b2c5e , 0x9d86b35e , 0x9ef57f7d , 0x08cec4dc , 0xaa69bc42 , 0x84b0809d , 0xcd89fa22 , 0x13a2c879 ] )
+  , Mont::unsafe_make( [ 0x823f4185 , 0x60fda5e4 , 0xff125fc2 , 0xfab29b0b , 0x2828bc10 , 0x30e657eb , 0xa2a73df1 , 0x1d0defe9 ] )
+  , Mont::unsafe_make( [ 0xa4935a24 , 0x84ad4e1f , 0xe169b8bc , 0xff0d48ea , 0x7f3e85fd , 0x5deb1bd7 , 0x57f13fae , 0x024b2f0a ] )
+  , Mont::unsafe_make( [ 0x4044a108 , 0xf1f9b66e , 0x7e68bf31 , 0xa1f7073d , 0xb4a9a5e4 , 0x7bc6ae05 , 0xe366220c , 0x26844208 ] )
+  , Mont::unsafe_make( [ 0x71298405 , 0xb0a9d3f5 , 0xf48d6714 , 0xd9d1fdde , 0xb8c284c2 , 0x23d5c966 , 0x6e5dbc42 , 0x0d4568e8 ] )
+  , Mont::unsafe_make( [ 0x2318a331 , 0x73478f21 , 0xd7f2fc17 , 0x979bfb3f , 0x220005ea , 0x5ea50710 , 0x626643eb , 0x12288239 ] )
+  , Mont::unsafe_make( [ 0x699ee538 , 0x878897ec , 0xd69466a9 , 0x76a57cde , 0x6aa165a5 , 0x7a4796e3 , 0x5d9900f4 , 0x05c506a6 ] )
+  , Mont::unsafe_make( [ 0x774b32d0 , 0xb9313ba4 , 0x601f2e14 , 0x56dca693 , 0x1b07ba00 , 0x6cfd1904 , 0x56cc75cf , 0x2a6954f3 ] )
+  , Mont::unsafe_make( [ 0x354a5ad7 , 0x3190695d , 0x5dcc7b52 , 0x07576102 , 0x4ac9a692 , 0x4498ae3b , 0x243bf794 , 0x058e678f ] )
+  , Mont::unsafe_make( [ 0xddb7e244 , 0x7e7631d4 , 0x67f4c202 , 0xe1cb5a5a , 0xfbcca6c4 , 0x9e7eff7b , 0xd5801ab3 , 0x0fb7f896 ] )
+  , Mont::unsafe_make( [ 0x035be8ae , 0x4e507f99 , 0x59ae350b , 0xa80371e3 , 0x90355219 , 0x9bcb4fae , 0x2f600cc4 , 0x0813b724 ] )
+  , Mont::unsafe_make( [ 0x64ed4454 , 0xd859afc9 , 0x6acfba7f , 0x47e7bd28 , 0xab3e7474 , 0x75f385ea , 0xac736797 , 0x159d4066 ] )
+  , Mont::unsafe_make( [ 0x747514e0 , 0x2068e17d , 0x4e695e3f , 0x1f5bdf52 , 0x16a9aedb , 0x77862d82 , 0xcc56500a , 0x000bd716 ] )
+  , Mont::unsafe_make( [ 0x936a3590 , 0xd9c1f9f5 , 0xd8ae8649 , 0x2dcbd87e , 0x8b54babc , 0x1ee33648 , 0xf9022e84 , 0x027b13d0 ] )
+  , Mont::unsafe_make( [ 0xfe168d60 , 0x9e525aef , 0xe62e33fd , 0xa99fa3f6 , 0x9cf4a442 , 0x4a7136c1 , 0x59c24d99 , 0x1c28cc67 ] )
+  , Mont::unsafe_make( [ 0xbec30f65 , 0xe07d7afa , 0x10d1759f , 0x95599f91 , 0x5516d143 , 0xcead186e , 0x86f2ed60 , 0x0c44965e ] )
+  , Mont::unsafe_make( [ 0xcf9eab3e , 0x44a1a026 , 0x7903666b , 0xff3ad526 , 0x187c3cd8 , 0x8081226b , 0x7122c791 , 0x037ca254 ] )
+  , Mont::unsafe_make( [ 0xeb6628e0 , 0xf92222cc , 0x18b8e8a5 , 0xc8b092ec , 0xbe2071d2 , 0xfb322a7e , 0xfedea3cf , 0x15b06073 ] )
+  , Mont::unsafe_make( [ 0xd436c4f9 , 0x96370752 , 0x7401efb5 , 0xf98ae3fb , 0xcd3a2dc3 , 0xbf601cc1 , 0x17463445 , 0x1709ccd5 ] )
+  , Mont::unsafe_make( [ 0x1c51ed69 , 0xd7251ae5 , 0xd6ccb463 , 0x250fc384 , 0xf0e3c117 , 0xab62a951 , 0xf3375702 , 0x214bd8ba ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xccf26580 , 0x673bc48e , 0x7e58f9c0 , 0xa0f19214 , 0x2fef3199 , 0xb6382312 , 0x1a9fe522 , 0x0c677bd1 ] )
+  , Mont::unsafe_make( [ 0xf064424f , 0x55122949 , 0x166e9fef , 0xb7fb97ae , 0x4a1c1c04 , 0x26178b3b , 0x47179ca5 , 0x2b595000 ] )
+  , Mont::unsafe_make( [ 0xd235ef04 , 0x73bddb56 , 0x2a91a90b , 0xd0c6a4dd , 0xa0c953cd , 0xa24cc5dc , 0x433f8235 , 0x0251a3af ] )
+  , Mont::unsafe_make( [ 0xc8e4eed8 , 0xe536222c , 0x7b2b1da3 , 0x9fd53b9a , 0x507e593d , 0xa7d53815 , 0xeffe6771 , 0x0fc37c64 ] )
+  , Mont::unsafe_make( [ 0x0959d56b , 0xcc7ef4be , 0xeecb8c82 , 0x961b625c , 0x1b8956c3 , 0xd8226d76 , 0xa852e841 , 0x0091488a ] )
+  , Mont::unsafe_make( [ 0x8316f9fd , 0xd3d30165 , 0xcc2e46f6 , 0xe9e5c8fc , 0x2bc29d60 , 0x5d92c170 , 0xed61cf86 , 0x1f7fdf98 ] )
+  , Mont::unsafe_make( [ 0x398f9903 , 0x3193b38e , 0x2259aadb , 0xc73d3c50 , 0x8d36f2cd , 0x61337c7d , 0xf3626568 , 0x16a91592 ] )
+  , Mont::unsafe_make( [ 0xeece7d5a , 0x03473853 , 0xf8a295b0 , 0x1343acf5 , 0x77453a63 , 0xba86013a , 0x899b4d0b , 0x0c58568f ] )
+  , Mont::unsafe_make( [ 0x1e752733 , 0x9ad31b88 , 0x2d8738ac , 0xbd080455 , 0x62e08724 , 0x46232f2d , 0x3fe7f8bb , 0x27d64693 ] )
+  , Mont::unsafe_make( [ 0xdd757d15 , 0xad30c340 , 0xaaa1b8f8 , 0xc9b1fca0 , 0x6151e612 , 0xf5a4beb6 , 0x60c2327e , 0x01c60bdd ] )
+  , Mont::unsafe_make( [ 0x06d95680 , 0xe5126758 , 0xe5e84a97 , 0x1967d8f5 , 0xff10ac97 , 0x65f91c7a , 0x6ac452d0 , 0x24b3e98f ] )
+  , Mont::unsafe_make( [ 0x27ce1e04 , 0x0581acd7 , 0xab4f0e71 , 0xdd525c6e , 0x16400f1b , 0x66b8dfc9 , 0x24a03db8 , 0x2636f1d4 ] )
+  , Mont::unsafe_make( [ 0xcfdfdd76 , 0xd806ab4b , 0xf73fd658 , 0x7f235a84 , 0x8c65b67a , 0x3ff68ffe , 0xdef55118 , 0x14c1e542 ] )
+  , Mont::unsafe_make( [ 0xe81ec340 , 0x3035963c , 0x88eb7b2a , 0x59b46a7f , 0x1f913dfb , 0xdfa1257e , 0x0905367f , 0x19dcbc03 ] )
+  , Mont::unsafe_make( [ 0xeb55b4c1 , 0xae2c2862 , 0x9a67454b , 0x0a967a11 , 0xae514f7f , 0x809ee049 , 0xab644951 , 0x10fbb419 ] )
+  , Mont::unsafe_make( [ 0x32fa0e7e , 0x1c6e6f9c , 0x79b5da53 , 0x5fb05990 , 0xeb01f808 , 0x147ee1c9 , 0x70316bff , 0x0983b767 ] )
+  , Mont::unsafe_make( [ 0x1585d9d8 , 0xfc7c49b8 , 0x707b8403 , 0x3e712cf3 , 0x23f5fc5f , 0x95f6ae27 , 0xf95cd84c , 0x0a83b511 ] )
+  , Mont::unsafe_make( [ 0x819c8ebf , 0x31e09c17 , 0xcddd46b5 , 0x4d1c6917 , 0x2e871279 , 0x9e6c6de8 , 0xd4bbb155 , 0x1863b882 ] )
+  , Mont::unsafe_make( [ 0x5d6cef34 , 0x10072446 , 0xe84651d1 , 0x13ede2d2 , 0x97f2463d , 0x9607ca3e , 0x4b4dc27c , 0x2ff196a2 ] )
+  , Mont::unsafe_make( [ 0xc1bf2fef , 0xac30b8eb , 0x4b3a3752 , 0xc0458165 , 0x7145331e , 0x61f47d0a , 0xf08218cd , 0x0d614e49 ] )
+  , Mont::unsafe_make( [ 0x5012cec1 , 0x04dc23c2 , 0xe6b73bcc , 0x847fe2fe , 0xeec5ae46 , 0xe1a009ff , 0x0e5884c0 , 0x097af30a ] )
+  , Mont::unsafe_make( [ 0x66093e5c , 0xc1a3df8e , 0x507575d3 , 0x63a0d373 , 0xe9aa0ec1 , 0xbf0f3027 , 0x57b43c7f , 0x24fa908e ] )
+  , Mont::unsafe_make( [ 0xa866e96e , 0x06d143dc , 0x9881646c , 0x098b485c , 0x56e44707 , 0x784e7be1 , 0x9e1b1306 , 0x043d8e30 ] )
+  , Mont::unsafe_make( [ 0x397c3613 , 0x8195af10 , 0x805bf7fa , 0x47d67387 , 0xe1edd326 , 0x364b8fb8 , 0xfa857a1b , 0x139551f0 ] )
+  , Mont::unsafe_make( [ 0x21a1f03f , 0xe8043ccd , 0xdf8fcf96 , 0xb701460d , 0xd4a8e260 , 0x6c388b5f , 0xef5cae15 , 0x0f518c8f ] )
+  , Mont::unsafe_make( [ 0x71389d6e , 0x752fdc84 , 0xf0db5b70 , 0x055d3dbd , 0xd25f53b0 , 0x6ccd65cb , 0x96095551 , 0x25784e61 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xbfb7b8a6 , 0xea66df7a , 0x9ebeda3b , 0x2f47c100 , 0x8d68b16a , 0x87de03f9 , 0x9da32070 , 0x238ad87a ] )
+  , Mont::unsafe_make( [ 0xa7337819 , 0x6b52f1e2 , 0x820b4d22 , 0x8bdb0ff0 , 0x467b9253 , 0xa982ce50 , 0x96f9bf2c , 0x211c2f84 ] )
+  , Mont::unsafe_make( [ 0xaa13a44d , 0xc059bb1f , 0x0e28aa69 , 0xb6dcc854 , 0xa653cf10 , 0x854a4663 , 0x39023827 , 0x1dff2189 ] )
+  , Mont::unsafe_make( [ 0xb1c7a218 , 0x2baff3e2 , 0xb2fb1c57 , 0x121696f1 , 0x7500832f , 0xfd892869 , 0x33132dea , 0x016738c8 ] )
+  , Mont::unsafe_make( [ 0xfa2ba78e , 0xd92b0853 , 0x022b87b5 , 0x91f51929 , 0x2b8798fd , 0x725abb7a , 0x3ea52799 , 0x29b346c4 ] )
+  , Mont::unsafe_make( [ 0xd9761f0f , 0x3f287e41 , 0xc0c6056d , 0xa566bd05 , 0x4e806837 , 0xaadf241f , 0x78544fc9 , 0x0027b900 ] )
+  , Mont::unsafe_make( [ 0xb035e512 , 0x28517b10 , 0x5441663d , 0xba478eb1 , 0xa00080a2 , 0x2038e7cb , 0x60ad7571 , 0x09a6367a ] )
+  , Mont::unsafe_make( [ 0x3e4ee680 , 0x607fa112 , 0x7509a93d , 0x35a77aab , 0x96adcc1b , 0x68ca5c74 , 0x20297ceb , 0x0f6df333 ] )
+  , Mont::unsafe_make( [ 0x41399870 , 0xd22b7b55 , 0x00bfd953 , 0xde8e9d32 , 0xeb4c8d25 , 0xce6d2b85 , 0xaa111c40 , 0x06968d77 ] )
+  , Mont::unsafe_make( [ 0xa77769ba , 0xf899dfbe , 0x1e085926 , 0x6ab58e83 , 0xb0133c06 , 0x64881048 , 0x7869b743 , 0x0a45d1f2 ] )
+  , Mont::unsafe_make( [ 0xc2656f51 , 0x9c1d0bc4 , 0x11d1ef25 , 0xab1249db , 0xd680e7bb , 0x2b3146c2 , 0xfcf8aac9 , 0x00d96e44 ] )
+  , Mont::unsafe_make( [ 0x3039ec9d , 0xfacf5b68 , 0xe75dd2f3 , 0xa935795f , 0x9162543b , 0x9a84d015 , 0x116c0df5 , 0x18864bfd ] )
+  , Mont::unsafe_make( [ 0xd4a14ade , 0xc6a1044b , 0xc4685616 , 0x11a6c465 , 0xaf070dea , 0x92ba3144 , 0xc368b450 , 0x2a7c755d ] )
+  , Mont::unsafe_make( [ 0x561e836a , 0x6c3d5fc0 , 0x84c13638 , 0x59e31f4b , 0x55a0e3c7 , 0x9946d59e , 0x02f6a818 , 0x2636a775 ] )
+  , Mont::unsafe_make( [ 0x0418950c , 0x428fc4b4 , 0x912d67c9 , 0x57dee627 , 0x5195d3ca , 0x70d3320a , 0x24038371 , 0x2bec3774 ] )
+  , Mont::unsafe_make( [ 0xcedc0026 , 0xf19656cd , 0x50ba80d3 , 0x138b3130 , 0x592f884d , 0x7072a1b3 , 0x1f56531e , 0x2a54eb5a ] )
+  , Mont::unsafe_make( [ 0xdde1f69a , 0xe38b8fe3 , 0x516e7f31 , 0xcac910f9 , 0x8a8503ef , 0x5b35e8f2 , 0x751c2bfc , 0x11d10d8c ] )
+  , Mont::unsafe_make( [ 0x5089496c , 0x4d4d13d6 , 0xf354398a , 0xc188236a , 0x0f79cfc0 , 0x30b2b5d6 , 0x072a84bc , 0x196f3f32 ] )
+  , Mont::unsafe_make( [ 0x18ae32c8 , 0xde50a721 , 0x7fbd3a9c , 0x51c84fa4 , 0x782a211c , 0xbf1f6e48 , 0xb07d471b , 0x0260a448 ] )
+  , Mont::unsafe_make( [ 0x5069cebd , 0x3283e7f6 , 0xb99236fc , 0x93998a1f , 0xa04eec17 , 0x33162d0f , 0xf2a5a5a1 , 0x1c909aa0 ] )
+  , Mont::unsafe_make( [ 0xb409cf49 , 0xd1aad0a1 , 0x628ce061 , 0x09cc706a , 0xc8d62dd6 , 0xe817fdb9 , 0x61f85836 , 0x03cbc828 ] )
+  , Mont::unsafe_make( [ 0x6e013cbf , 0xdb2bf660 , 0xbaa1e22f , 0xbfd2e348 , 0x68837581 , 0x214ec34d , 0xb3423a0e , 0x22fb09d4 ] )
+  , Mont::unsafe_make( [ 0xfc0dce60 , 0x05bd542d , 0x226ac663 , 0x4366df05 , 0xaafb4839 , 0x9c29f03a , 0xf117f51a , 0x2803acdc ] )
+  , Mont::unsafe_make( [ 0x0b60d03e , 0xb4445108 , 0x78f9cab7 , 0xc3ec736c , 0x80a1974d , 0xb177f5f7 , 0x74a548db , 0x11333ec0 ] )
+  , Mont::unsafe_make( [ 0x552b0481 , 0x6f0cc3a9 , 0x5bd601ef , 0x13ac08a4 , 0xa1f6a29d , 0x68d0ac80 , 0xb8ea75f8 , 0x09d1d321 ] )
+  , Mont::unsafe_make( [ 0x099c3be4 , 0xea8cbfd0 , 0xe23ec210 , 0x71e16fc4 , 0xf128bde4 , 0x6968398b , 0x91236d6e , 0x2272f31a ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x81c5c37a , 0x742973d4 , 0x1d146559 , 0x4bc08c74 , 0x284e64ff , 0x6a473b2e , 0x661649fb , 0x184555e9 ] )
+  , Mont::unsafe_make( [ 0x823fd824 , 0x2bf5f5c6 , 0xe9868c3d , 0xfccde3dd , 0x8816f3d4 , 0x190d16cc , 0xea59ed86 , 0x154ac5ed ] )
+  , Mont::unsafe_make( [ 0xfb5b18b1 , 0x4b96b79b , 0xf7ddeac6 , 0xb24407b6 , 0x3f328514 , 0x3d767f01 , 0xb955b8e1 , 0x116e2d87 ] )
+  , Mont::unsafe_make( [ 0x6922b0d8 , 0xb9c701cd , 0xb665b196 , 0x9c8e86c3 , 0x54377979 , 0xd7662a9b , 0x05ceeffc , 0x04995504 ] )
+  , Mont::unsafe_make( [ 0x5285a5b1 , 0xffe8470c , 0x64e53045 , 0x1045879c , 0xf31a864a , 0x6043c9dd , 0xf8c5b216 , 0x1a0cee77 ] )
+  , Mont::unsafe_make( [ 0x9769344c , 0xa9579689 , 0xb0ea607b , 0x254dc6f6 , 0xd6b58293 , 0xb199ed37 , 0xb6c1fa95 , 0x03977104 ] )
+  , Mont::unsafe_make( [ 0xc612e501 , 0x46ca7bc5 , 0x37373542 , 0x5cfe2f31 , 0x845af55b , 0xefa392e5 , 0x238688a0 , 0x2ab6a028 ] )
+  , Mont::unsafe_make( [ 0x343ec574 , 0xeef85f73 , 0xe753bba4 , 0xff0d5de6 , 0x7f8f677b , 0xa1f7e052 , 0x3a420729 , 0x26d17db9 ] )
+  , Mont::unsafe_make( [ 0x72dd7ded , 0xa3f9a234 , 0x39684f30 , 0x53260e13 , 0x40fc0ae4 , 0x978156c4 , 0xa7f6b389 , 0x0693458b ] )
+  , Mont::unsafe_make( [ 0xf81a6920 , 0xb0a57f55 , 0x9cb06b6c , 0x4b2e3c57 , 0x8c9e962c , 0x856f4969 , 0x5b80910e , 0x1f6c8046 ] )
+  , Mont::unsafe_make( [ 0xe9791a06 , 0xad48ebb0 , 0x14b07781 , 0x9dd97459 , 0x71c99362 , 0x21e7ec6d , 0x5ce98c69 , 0x2dd2c25a ] )
+  , Mont::unsafe_make( [ 0x3891f07b , 0x6a266a58 , 0x082bd09d , 0x075c4a5f , 0x4fa6ec4d , 0x55825bce , 0xc4257ddf , 0x24e22620 ] )
+  , Mont::unsafe_make( [ 0xf40ca05d , 0xfa2153cb , 0x76930537 , 0xe22bf19a , 0x7ff17474 , 0xf0befdfe , 0xd3d3fd39 , 0x16b6db22 ] )
+  , Mont::unsafe_make( [ 0xe0f4ca82 , 0x1702ee59 , 0x2040ccde , 0xac7995fe , 0x5fefcc82 , 0xa3433e4e , 0x572652d1 , 0x20cd1ea3 ] )
+  , Mont::unsafe_make( [ 0x8fa32a0a , 0x01b99094 , 0x30ebe8e1 , 0xb0aac91c , 0x2770f4a3 , 0x20dcff80 , 0x0cff65f8 , 0x2fed70d9 ] )
+  , Mont::unsafe_make( [ 0x7275ac12 , 0xe82cdfb3 , 0xaf69b10c , 0x26e6e4b0 , 0xc7d54306 , 0x7ba2dbac , 0xbac44959 , 0x0cdffc24 ] )
+  , Mont::unsafe_make( [ 0xf7c66826 , 0xf2a96054 , 0xffb2f588 , 0x3580e869 , 0xbdd582dd , 0x04d19182 , 0xcbb2b66c , 0x12231735 ] )
+  , Mont::unsafe_make( [ 0x169fea8c , 0xe96b864e , 0xd42f14dd , 0xb672c45f , 0x3f447fad , 0xa28d39ea , 0xac8f273a , 0x1647904a ] )
+  , Mont::unsafe_make( [ 0x98169d91 , 0xa02bc68b , 0xc61e2fb1 , 0xeb75084f , 0xcee2f1a8 , 0x51d178c0 , 0x3ae2be08 , 0x1b2b4f9c ] )
+  , Mont::unsafe_make( [ 0xec7e1fb1 , 0xa08ef0a0 , 0xb10ee7ca , 0x550bba0e , 0x95bc2f7e , 0x2c65e3a7 , 0xb64dc241 , 0x0eca6d61 ] )
+  , Mont::unsafe_make( [ 0xc09ba9b0 , 0x56e5c9d1 , 0x5ec545bf , 0x1fe3e416 , 0x8d7555f1 , 0xebfbd502 , 0x82991dbc , 0x023bea1a ] )
+  , Mont::unsafe_make( [ 0xf12ad464 , 0xd796c1a1 , 0x53ba27f7 , 0xa58bf31a , 0xb82e37fe , 0x1676d84c , 0x9cc3f5e9 , 0x1e01fce2 ] )
+  , Mont::unsafe_make( [ 0x56812e80 , 0xed6bc122 , 0xf8a17550 , 0xf76f477f , 0xa8376788 , 0x5e06a148 , 0x7e7f686a , 0x18dfc240 ] )
+  , Mont::unsafe_make( [ 0xf545a597 , 0x1eb8d35d , 0xea53800d , 0xa9d9fc2e , 0x50e83868 , 0xe2098b17 , 0xe1a086df , 0x06070d5a ] )
+  , Mont::unsafe_make( [ 0xf3f9e18e , 0x39ec7be4 , 0xee405e89 , 0x35bdccc7 , 0x516c20ad , 0xdc83f2c7 , 0x71d6468d , 0x2418bc1d ] )
+  , Mont::unsafe_make( [ 0x2be1a301 , 0x079970be , 0xf7ce7f3a , 0x01546705 , 0x1d23b16e , 0x56f41b9e , 0x534406f4 , 0x1b7b9aff ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x4729fa2f , 0xbc79e5a9 , 0xafcb6dcb , 0xe117a935 , 0xe7aaa6d9 , 0x00faad03 , 0xd60bf04c , 0x1d54fb94 ] )
+  , Mont::unsafe_make( [ 0xbed424c8 , 0x57a3532e , 0xc5afa918 , 0xcfbb3659 , 0xd019e221 , 0x23ec1ddc , 0x0a855389 , 0x10c040c0 ] )
+  , Mont::unsafe_make( [ 0x7ffd701c , 0x46791336 , 0x994c8218 , 0x4cb4aee4 , 0x23af070c , 0xfc0eeb63 , 0xd78bad58 , 0x0d6a7e75 ] )
+  , Mont::unsafe_make( [ 0xdcd3def0 , 0x5e2b6e7c , 0x310eb77e , 0x5c0d4746 , 0xc24e1ba3 , 0x731a6f4d , 0x95fb7cef , 0x2db17d52 ] )
+  , Mont::unsafe_make( [ 0xca7077c0 , 0xd5caac24 , 0xa0b5ba80 , 0x94e05382 , 0xc98681f4 , 0x859f04d8 , 0x9fd8c019 , 0x041c9e96 ] )
+  , Mont::unsafe_make( [ 0xf6b22a1d , 0xecaf971d , 0x78cab5f5 , 0x91375d68 , 0x9f1ba71a , 0x14198d14 , 0x9c9b46fb , 0x03f8e1dd ] )
+  , Mont::unsafe_make( [ 0xce09f438 , 0xdee831a8 , 0xf704ede3 , 0x2e12ca16 , 0x4f4af032 , 0xd15f2d9a , 0x057bbce9 , 0x0b56fbdc ] )
+  , Mont::unsafe_make( [ 0xda67d8c5 , 0x8010399c , 0xd0204227 , 0xf053f493 , 0x00a61802 , 0x00b97ee2 , 0xfadf277f , 0x2b3f05d9 ] )
+  , Mont::unsafe_make( [ 0x678d62d9 , 0x67e52857 , 0xd5eedd86 , 0xdbe1ddfc , 0xf2c86abc , 0x3934f24b , 0x79915064 , 0x23e3d4b7 ] )
+  , Mont::unsafe_make( [ 0x62f09f1b , 0x082f9958 , 0xcd52ddf4 , 0x4cf69fe1 , 0x1adb8ec7 , 0x1dece1d3 , 0x9d919cf1 , 0x2588977e ] )
+  , Mont::unsafe_make( [ 0xb31d7d74 , 0x473a7eaf , 0xc33bf3e8 , 0x8ec848f5 , 0x653a63ac , 0x35be8a5d , 0x0cfca82f , 0x0ccc1754 ] )
+  , Mont::unsafe_make( [ 0x2a74179f , 0xa6121cee , 0x2af912ed , 0x1b295471 , 0x0630de42 , 0x5873e5b0 , 0x6b08c56a , 0x0eab7ccd ] )
+  , Mont::unsafe_make( [ 0xde75c282 , 0x6644b8a6 , 0x7939d022 , 0x97744060 , 0xe3a18662 , 0x0800f49b , 0x9bafd852 , 0x242daa60 ] )
+  , Mont::unsafe_make( [ 0x32d215f8 , 0x12f95fcc , 0x3f2cff64 , 0x5dea737f , 0x5feeaf2a , 0x18ff8999 , 0x3b6d1c8b , 0x26d5bf2d ] )
+  , Mont::unsafe_make( [ 0x867695f4 , 0xedd5f78b , 0x5caa6ac9 , 0x43cceb70 , 0xe0973bde , 0x7d8a069d , 0xe5288159 , 0x070fadb0 ] )
+  , Mont::unsafe_make( [ 0x3f6dabaf , 0x31d55669 , 0x3b3e72b1 , 0xd0e77d9a , 0xb0904f91 , 0x6a91e8fd , 0x287868d1 , 0x0365f80a ] )
+  , Mont::unsafe_make( [ 0xdfeffbce , 0x55dac7a0 , 0x8714d148 , 0x57ab8edb , 0x82c85f04 , 0xc9e8df0a , 0x4ebfba5a , 0x189ef779 ] )
+  , Mont::unsafe_make( [ 0x335abcc6 , 0x8901a542 , 0x48727e91 , 0x71adc15e , 0x779f13ac , 0xf263ebf8 , 0x8e8b0bc0 , 0x0dec65d4 ] )
+  , Mont::unsafe_make( [ 0x8e43bb14 , 0x9568066b , 0x0fe1d944 , 0x9ca219ef , 0x7abdc0a5 , 0x31a7475d , 0xc470e366 , 0x0b5397b1 ] )
+  , Mont::unsafe_make( [ 0x6ea6e832 , 0xf81ac651 , 0x282d9eca , 0x2c58afd5 , 0xac77d048 , 0x082e20c7 , 0x74cc171a , 0x0deb54e9 ] )
+  , Mont::unsafe_make( [ 0xbd91fea2 , 0x86bf34ef , 0xfd42cc23 , 0xf9f97f28 , 0xe0aa6f6c , 0x63204fcc , 0xf77def20 , 0x12e777eb ] )
+  , Mont::unsafe_make( [ 0xb1918f66 , 0x6d9d3d0e , 0x09fc6440 , 0x3fcc9701 , 0x2a12e514 , 0xa3d24f45 , 0x6f8c90e9 , 0x2734c163 ] )
+  , Mont::unsafe_make( [ 0x97fb9ba4 , 0x86bb57f2 , 0x29cc49d6 , 0xa321bd8d , 0xf157b4b4 , 0x2c72b248 , 0xb421d305 , 0x0f849fb1 ] )
+  , Mont::unsafe_make( [ 0x527e78d1 , 0xb00a626f , 0xe9f7dd5e , 0x17a066ea , 0x6b063586 , 0x73aecc98 , 0x39d18fc8 , 0x1032acaf ] )
+  , Mont::unsafe_make( [ 0x75db2033 , 0xbbaa1a0f , 0x9178d5af , 0xa145d35c , 0x971d1bbc , 0x77f643f5 , 0x3e1fabf4 , 0x2abfea38 ] )
+  , Mont::unsafe_make( [ 0xe41f739d , 0x70a23cb6 , 0xdb6db0da , 0x32a4ab73 , 0x36e16512 , 0x2100fd5a , 0xe1c1fa91 , 0x1cab927f ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xcd62ca3e , 0xa81ab3e1 , 0xdddbdbee , 0x1ec1a6b4 , 0x19af5fd1 , 0x207e86b7 , 0xf04ff5f1 , 0x1b4522e7 ] )
+  , Mont::unsafe_make( [ 0x58f98dc8 , 0xd316dc6f , 0xa772a84f , 0x49e05b18 , 0x6a595337 , 0x1616ff8d , 0x12471ff3 , 0x0f59486e ] )
+  , Mont::unsafe_make( [ 0xca7959bd , 0xf3508782 , 0x2e4400c3 , 0x50608eb2 , 0x96257fca , 0xd57500a1 , 0xb32e8863 , 0x1638099c ] )
+  , Mont::unsafe_make( [ 0xc5917131 , 0xd51c07f3 , 0x4d86e81a , 0x8d3d4ba5 , 0xa437d045 , 0xac452a00 , 0xe612d5cf , 0x0479226a ] )
+  , Mont::unsafe_make( [ 0x095d5c3f , 0x8d9e62bb , 0x1abaab3f , 0x26b7aeaa , 0xe2e02138 , 0xda0a5252 , 0x91c56706 , 0x27e77bce ] )
+  , Mont::unsafe_make( [ 0xa678d6c9 , 0x88a42c1e , 0xc3e2fff4 , 0x4e22d486 , 0x04ee7c28 , 0x56a8a1df , 0xc2fd20d7 , 0x0a9abd60 ] )
+  , Mont::unsafe_make( [ 0x6d2ffe7f , 0x25b5bf67 , 0x399a17d4 , 0xce6d2491 , 0x273b8ac5 , 0xc48c0f4e , 0x2a043533 , 0x298779e3 ] )
+  , Mont::unsafe_make( [ 0x2eaae77e , 0x9ab51620 , 0xaa57d23b , 0x59ef1d0d , 0xb26b3972 , 0x0fb460d8 , 0xd7b15ee2 , 0x0c268f89 ] )
+  , Mont::unsafe_make( [ 0xcbbe0996 , 0x0578c0ac , 0x88ac0cbf , 0x1e274b40 , 0xbe57ab35 , 0x639e5873 , 0x0d8eec02 , 0x172e3791 ] )
+  , Mont::unsafe_make( [ 0x5ba633ba , 0x3eeeaeba , 0xb3ce1de2 , 0xb95cc3ab , 0xa0d397c0 , 0xc66c8a18 , 0x712f8175 , 0x01df154d ] )
+  , Mont::unsafe_make( [ 0xbb2eee77 , 0x3bb5d90b , 0x1bcfe04a , 0x3f0385b0 , 0xf2f6ee6b , 0xf769037d , 0x4ca9bac6 , 0x02065ff4 ] )
+  , Mont::unsafe_make( [ 0x4be476be , 0xda19e2d5 , 0xaefe64c5 , 0xcf07d034 , 0x2da7b6e8 , 0x840b5ada , 0x81d8efb2 , 0x06ffe746 ] )
+  , Mont::unsafe_make( [ 0xf104323f , 0x28febbc0 , 0xbf05c2b8 , 0xdbb50603 , 0xd584707f , 0x3bb33209 , 0x05f5da98 , 0x13a7de99 ] )
+  , Mont::unsafe_make( [ 0x759aa6b2 , 0x1d04ba36 , 0x27d6bb45 , 0x1bf60fdf , 0x76e1a663 , 0x6359464d , 0xa0c879f2 , 0x2578e10d ] )
+  , Mont::unsafe_make( [ 0x4955ee70 , 0x4b61f0a8 , 0xc8c8fe80 , 0xf48048e2 , 0x961b36d7 , 0xc272b49a , 0x5c60848b , 0x060853eb ] )
+  , Mont::unsafe_make( [ 0x0bb2e54d , 0x4b191fc9 , 0xc7e6eb94 , 0x83b0cb2b , 0x4dae733f , 0xd1f5afc7 , 0xd40bb046 , 0x0204422c ] )
+  , Mont::unsafe_make( [ 0xec4ab8f6 , 0x6ab260cd , 0x2c8ccfe9 , 0x7965d7f7 , 0xbec6e911 , 0x0ba42563 , 0x6d2e03af , 0x077f123b ] )
+  , Mont::unsafe_make( [ 0xcd31ce8c , 0x58a5dbaf , 0x9522417d , 0x81c968b5 , 0x895634ea , 0x745298a9 , 0x0528e1e0 , 0x08bd3afa ] )
+  , Mont::unsafe_make( [ 0x4dd1c5ab , 0xedeb4fce , 0xbad1fa95 , 0xaab04c69 , 0x147596c3 , 0xcf5b4d04 , 0xe22105e0 , 0x2feac7ac ] )
+  , Mont::unsafe_make( [ 0xc66ea851 , 0x644fb5ab , 0x85448d50 , 0xc3779d10 , 0x7df5efe3 , 0x271e5c23 , 0xd832bc82 , 0x1ca1f9cf ] )
+  , Mont::unsafe_make( [ 0x1531a944 , 0xc39eb676 , 0xc6383a97 , 0x0d4a808d , 0xe2d4fee8 , 0x5c1bff74 , 0xba62f621 , 0x0efeecfd ] )
+  , Mont::unsafe_make( [ 0x4564ae92 , 0x84bdf138 , 0x7aa7d955 , 0xbb67401f , 0xaf081879 , 0x242c9432 , 0xb70b107e , 0x1af47d02 ] )
+  , Mont::unsafe_make( [ 0x28aba8d8 , 0xaa8ead47 , 0xe619b5aa , 0x2f7798aa , 0xeb0efc68 , 0xe485125f , 0x36b4c1b9 , 0x10b69b55 ] )
+  , Mont::unsafe_make( [ 0xbd0560b3 , 0x3f1dd703 , 0x7949ccd4 , 0x1c7125cd , 0x1a42b971 , 0x572d749d , 0x34772e36 , 0x12d7d519 ] )
+  , Mont::unsafe_make( [ 0x32a8b630 , 0x10265a36 , 0xc2d97d68 , 0x7cf07193 , 0xd3d54464 , 0x300b417a , 0xc1737b9b , 0x065841b8 ] )
+  , Mont::unsafe_make( [ 0x6f1c78cd , 0x4a75f7b5 , 0x45c4cfbf , 0xf0d02447 , 0xf07ba4b1 , 0xb741d35c , 0x12630104 , 0x17ee3b83 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x94cd4055 , 0x89fb25c6 , 0xf15e7ead , 0x5aaa7af4 , 0x44f07983 , 0x89228400 , 0x0631f290 , 0x1a594d8e ] )
+  , Mont::unsafe_make( [ 0xfc663813 , 0x9e1564d4 , 0x26b660eb , 0x963a5671 , 0x364dd39b , 0x1b890443 , 0xeca99208 , 0x0a90de47 ] )
+  , Mont::unsafe_make( [ 0x83c80008 , 0xc4be2872 , 0x2f883fcd , 0xbf8d86c5 , 0x2914e928 , 0x24eb7694 , 0x51e80d74 , 0x2339e307 ] )
+  , Mont::unsafe_make( [ 0x484314b7 , 0x781fe604 , 0xef079169 , 0x16c86f75 , 0xe7f4e17e , 0xb96dabbe , 0x80efeab2 , 0x21fc9ad6 ] )
+  , Mont::unsafe_make( [ 0x77823e7c , 0x2ffa5beb , 0x17769dda , 0x37504283 , 0xb3549c9c , 0xb5338aec , 0x91529d59 , 0x0c76272a ] )
+  , Mont::unsafe_make( [ 0xd3ca0c67 , 0x0e2e2783 , 0x7b863e9b , 0x6a46536c , 0xe72cc0ff , 0x40fd4f60 , 0x67d96272 , 0x0300f69e ] )
+  , Mont::unsafe_make( [ 0x372d2f16 , 0x101a52bd , 0x25943173 , 0xf52d3b74 , 0x888f1044 , 0x686b5ba8 , 0x2523601a , 0x1eb24365 ] )
+  , Mont::unsafe_make( [ 0xf574b808 , 0xec897105 , 0xc2287c43 , 0x9f73fc9f , 0xad8c369f , 0xeff54ef3 , 0x24d62d5d , 0x1ce374b5 ] )
+  , Mont::unsafe_make( [ 0xfa27f4e7 , 0x7cea4e12 , 0xa3f1c917 , 0xef4834c1 , 0x1a04cc41 , 0xcfd8d68d , 0x97e36535 , 0x1a95ec03 ] )
+  , Mont::unsafe_make( [ 0x6d61df4e , 0x7c1a5e59 , 0x25586925 , 0x02b66ad3 , 0x21e0f12f , 0xe369fad2 , 0x81364005 , 0x205e19e2 ] )
+  , Mont::unsafe_make( [ 0xbd87a76e , 0xddcb35d8 , 0x6aaee295 , 0x0fd01f8a , 0x51f6a497 , 0x7c03de7d , 0xa95b711e , 0x0f4a40a6 ] )
+  , Mont::unsafe_make( [ 0xd2d15bc1 , 0x19451945 , 0x2a73c0cb , 0xccf0a2b2 , 0xa61e7016 , 0xd7b9a50f , 0x24181c4c , 0x03f149f8 ] )
+  , Mont::unsafe_make( [ 0x8a03db72 , 0x5510f43a , 0xfc352418 , 0x2481ff87 , 0x62f0af13 , 0xc560ae5c , 0x81c13a64 , 0x12cba244 ] )
+  , Mont::unsafe_make( [ 0xc4d86a90 , 0x1c3788b6 , 0x61482b1d , 0xae335ae8 , 0xb0b2c434 , 0xe8123da9 , 0x05ec4597 , 0x07beffae ] )
+  , Mont::unsafe_make( [ 0xfa8abe42 , 0x59202983 , 0x84f6a5b5 , 0xf38e5523 , 0x0dc3a0a7 , 0xb69a475e , 0xe0dbd09e , 0x08c704a2 ] )
+  , Mont::unsafe_make( [ 0x5f85adbe , 0xd5f074f6 , 0x95901030 , 0x5a8b97ae , 0x3db17089 , 0x650391fc , 0xe9f39b6f , 0x27148678 ] )
+  , Mont::unsafe_make( [ 0xbab6ba93 , 0x9e692118 , 0x14aa6f4d , 0xda51db60 , 0xd2f6deac , 0xd802be54 , 0xdb2b9e72 , 0x2b23af46 ] )
+  , Mont::unsafe_make( [ 0xf630bb51 , 0xb898584e , 0x02b31ad3 , 0x524df3e6 , 0x44f02622 , 0xa347789a , 0xb22be101 , 0x28634e2b ] )
+  , Mont::unsafe_make( [ 0x5439041c , 0x830605b8 , 0xfe25fb7a , 0x1bfaa5d2 , 0xa2403d41 , 0x6a170429 , 0x54de40c7 , 0x2a3f821c ] )
+  , Mont::unsafe_make( [ 0x64660a50 , 0x871b1226 , 0x794807b8 , 0x51df13d4 , 0xcd1e4e87 , 0xd6ab8215 , 0xc150f301 , 0x189be001 ] )
+  , Mont::unsafe_make( [ 0x5f98498b , 0xde094558 , 0x7bf54402 , 0x07746020 , 0x0fcc5849 , 0xc325850b , 0xf13bc887 , 0x11203844 ] )
+  , Mont::unsafe_make( [ 0x33594ab4 , 0x42529567 , 0x3808dede , 0xe75c42c3 , 0x9db9a061 , 0x545a37ed , 0x96e78547 , 0x301c8f0d ] )
+  , Mont::unsafe_make( [ 0x89285c59 , 0x2e91e97f , 0xb59292fc , 0x7d0458f7 , 0x7b3e6de6 , 0xb0f83227 , 0xfa61135f , 0x0d2dbaa4 ] )
+  , Mont::unsafe_make( [ 0x4d2c7ae8 , 0x22935d9d , 0x73fcd9ba , 0xd57c36b4 , 0x132539c6 , 0xa97d8eb7 , 0x19cff0f3 , 0x2ec5e4c9 ] )
+  , Mont::unsafe_make( [ 0x10f5ca9b , 0x6e16ec5a , 0x0ff887a9 , 0xd5ba8a65 , 0x6e3361f3 , 0x7ee8a43b , 0xcdb4642f , 0x27da5e85 ] )
+  , Mont::unsafe_make( [ 0xdb6a8421 , 0x1cc36e36 , 0x5ce3e3df , 0x7f1f21d9 , 0xee8fa348 , 0x887fd7fc , 0xf802f0e7 , 0x24fb335d ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xfe6388ed , 0x3ee22c30 , 0x5062b13d , 0xf3d39285 , 0x8cd4410b , 0x6d51982d , 0xc0fdec5f , 0x07d273ae ] )
+  , Mont::unsafe_make( [ 0xe72ef63b , 0x2eccdb0c , 0x81db1087 , 0xc6dce215 , 0xf240c15b , 0x1748fbed , 0xb42f8fad , 0x0bd1d2d8 ] )
+  , Mont::unsafe_make( [ 0x9070c2f6 , 0x7f595021 , 0x17eed790 , 0xa64b5172 , 0xfada89ef , 0x9ab8bfb6 , 0x823cd09c , 0x22c20922 ] )
+  , Mont::unsafe_make( [ 0x1bb8c6cf , 0x0302be3a , 0x4cea4994 , 0xf2195c98 , 0x121e0bad , 0x67b69679 , 0x0c26c70d , 0x1abb3b6f ] )
+  , Mont::unsafe_make( [ 0xf422576e , 0xc87b058d , 0x0d800078 , 0x9b32f86e , 0x7f4aa774 , 0x5e1fa66e , 0xfb73f93c , 0x110b464b ] )
+  , Mont::unsafe_make( [ 0xa71017bb , 0xd1e6bcbe , 0x3a94e8eb , 0x278d24ad , 0x1733f9d7 , 0x7123315d , 0x51694dd7 , 0x1ae9aa12 ] )
+  , Mont::unsafe_make( [ 0x26382f23 , 0x902ed97a , 0x40da22bd , 0xcb04bc81 , 0x5f79b44b , 0x298d7fb7 , 0x7f3ddb41 , 0x1b7d0e70 ] )
+  , Mont::unsafe_make( [ 0x4b5a4777 , 0xa13d97af , 0x7f7179ed , 0x65d43cb3 , 0x227271ed , 0xd2fdafae , 0xab770e45 , 0x15bce0f9 ] )
+  , Mont::unsafe_make( [ 0x587c3d1b , 0xd4916615 , 0x35348070 , 0xc3be4ddb , 0xb02c0bd7 , 0x7194f8a6 , 0x7ceda1d5 , 0x21defa76 ] )
+  , Mont::unsafe_make( [ 0x75d047df , 0x2b20d09b , 0xf88dc6a2 , 0x2bb63c9d , 0x82e961c7 , 0xfcab221c , 0x7a20390e , 0x2c91adef ] )
+  , Mont::unsafe_make( [ 0xd354ba37 , 0xdfaf507e , 0x241ee2ed , 0x2ed38ccc , 0xdc5694c5 , 0x40d7484f , 0x39776654 , 0x2736187d ] )
+  , Mont::unsafe_make( [ 0x85773d34 , 0x59c5de77 , 0xce3cdf72 , 0x848f1459 , 0x15adaaac , 0xa233e60f , 0x4d7963b4 , 0x07c80375 ] )
+  , Mont::unsafe_make( [ 0x6c80105f , 0xe98b40f5 , 0xd8da59b3 , 0x90ed60a0 , 0x4c9df6f8 , 0xb64acbc4 , 0x459f4a33 , 0x28dce60c ] )
+  , Mont::unsafe_make( [ 0x6f70fef3 , 0xcf3a738d , 0x219d29e5 , 0xce2f194d , 0x219b8dce , 0x9a841c47 , 0x9644a7fa , 0x141465d5 ] )
+  , Mont::unsafe_make( [ 0x15d1a836 , 0xbd4cd362 , 0x84e5f549 , 0xa351afed , 0x4c1a3993 , 0xa7047472 , 0xe5df6d6e , 0x224eaa15 ] )
+  , Mont::unsafe_make( [ 0x4a25530e , 0xa61d51c8 , 0x56aa107a , 0x05af5dff , 0x5f4e5ba6 , 0xf6f9bb29 , 0xcfbd7e6e , 0x040adbf3 ] )
+  , Mont::unsafe_make( [ 0xcde40cd2 , 0xe72610a5 , 0xfc3cfd4c , 0x0b725f61 , 0xfc504765 , 0x838ddea6 , 0x339aa174 , 0x05814a7f ] )
+  , Mont::unsafe_make( [ 0xc0cfe2f0 , 0x5560a84f , 0x8a414032 , 0x70a55043 , 0xa795419f , 0xcfc6b0dd , 0xe05a69e2 , 0x0f7a0703 ] )
+  , Mont::unsafe_make( [ 0x3097e076 , 0x33a20a13 , 0x10452be2 , 0x4d42c940 , 0x3cc4273d , 0xc6c6ec1a , 0xbabd206c , 0x0a271cc3 ] )
+  , Mont::unsafe_make( [ 0xefbaf030 , 0x41305eda , 0x4119497d , 0x2ceb4da5 , 0x0b1c711f , 0x44aa5c02 , 0x67cf3e60 , 0x03b9e946 ] )
+  , Mont::unsafe_make( [ 0x27fad6de , 0x0576d68c , 0x444426d9 , 0x5e016830 , 0x40a5c1a6 , 0x194536d1 , 0x0773677c , 0x2e9c7537 ] )
+  , Mont::unsafe_make( [ 0xe4b52a0f , 0x07dca3b6 , 0xa3daf491 , 0x882a4720 , 0xa0efaff9 , 0xe79cecc5 , 0x01f1c484 , 0x07f1776e ] )
+  , Mont::unsafe_make( [ 0x28b86b54 , 0xe1b7a1cb , 0x8e6b80d2 , 0xdbbd6572 , 0x3a80a1ea , 0x8a33a7d1 , 0x29cbd980 , 0x03c0955c ] )
+  , Mont::unsafe_make( [ 0xc486fc70 , 0x03430cb2 , 0x66b05e66 , 0xbd654a10 , 0x78ab5615 , 0x3e00701d , 0x4903e829 , 0x0810a6bd ] )
+  , Mont::unsafe_make( [ 0xcf5b3364 , 0x3e7b92e1 , 0xc2c4bc42 , 0x35ac8266 , 0xd990fc25 , 0xa7155c8d , 0x0c8d7f78 , 0x06ce3994 ] )
+  , Mont::unsafe_make( [ 0xd2cebaa1 , 0x631aed29 , 0x3eca5982 , 0x91774f99 , 0x64d68a68 , 0x8067af0b , 0xa9116aba , 0x23d17337 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x7afe5f42 , 0x9d37e4e7 , 0xb690dfe0 , 0x988fb2b3 , 0xd4c8c47d , 0xf3429c9d , 0xab66a43b , 0x0a1b43b8 ] )
+  , Mont::unsafe_make( [ 0xfd83792e , 0xca8e12d5 , 0x8a4b8744 , 0xd82dc774 , 0x1cc4815b , 0x23ab9fa5 , 0x1acc57e0 , 0x084addf6 ] )
+  , Mont::unsafe_make( [ 0xfb674fd7 , 0x30a25fcd , 0x8b079362 , 0xcd9668ce , 0xa7621219 , 0x3033ac50 , 0x38e35c54 , 0x0c318347 ] )
+  , Mont::unsafe_make( [ 0x060f6ce4 , 0x187de77c , 0xec31bafe , 0x4f0876ae , 0x860a34a5 , 0x53d6a35a , 0xb6718efe , 0x0e31311b ] )
+  , Mont::unsafe_make( [ 0x06694f3f , 0x67b2b940 , 0x3204e6df , 0x6c624a78 , 0x48cb4691 , 0xae08353d , 0x705b5248 , 0x167a7f17 ] )
+  , Mont::unsafe_make( [ 0x9dfa7247 , 0x1b179cb4 , 0x22a13e4c , 0xe67f0a25 , 0xa2f41c07 , 0xbfeaba46 , 0x0ccb8841 , 0x28c12ca3 ] )
+  , Mont::unsafe_make( [ 0x734e6d6b , 0x95c91abf , 0x0985dc03 , 0x3d30dc86 , 0x69b251d2 , 0x658c692c , 0x38cfcae3 , 0x0bf1f9a2 ] )
+  , Mont::unsafe_make( [ 0x2f8d279d , 0x7dd7db4f , 0xcb700c34 , 0x658e516a , 0x5b597954 , 0xafee8ea2 , 0xd42d192d , 0x2121c052 ] )
+  , Mont::unsafe_make( [ 0xd226e6b7 , 0x4a5ae3b7 , 0xa5d5be68 , 0xc66eb5de , 0x21d01252 , 0xe3b75ec2 , 0x020ec3fe , 0x2e4f5502 ] )
+  , Mont::unsafe_make( [ 0xf9e71066 , 0x58d642e4 , 0x9ae51fe9 , 0xdcb25314 , 0xe1d01559 , 0x00856de4 , 0x50165a13 , 0x2a315c34 ] )
+  , Mont::unsafe_make( [ 0x762464fd , 0x9478d358 , 0xc15f4c40 , 0x57e68c47 , 0x7ab810d4 , 0x0118a290 , 0xced3b702 , 0x266142f2 ] )
+  , Mont::unsafe_make( [ 0xce6563fc , 0xb22476d6 , 0xc203dd4c , 0x747913e9 , 0x4e3a9b79 , 0xc2643a88 , 0xc54201a8 , 0x19d2e337 ] )
+  , Mont::unsafe_make( [ 0x6311d08a , 0x5b6b00c7 , 0xa554e116 , 0xff749a7e , 0xc6433afe , 0xabeb7410 , 0x0b40834d , 0x161b5402 ] )
+  , Mont::unsafe_make( [ 0x1cc5a65a , 0xcf40f732 , 0x3760d32e , 0x10f2f4f1 , 0x87145ff8 , 0xdba93e8e , 0x48c72383 , 0x28edbdbe ] )
+  , Mont::unsafe_make( [ 0x6a5560e1 , 0xa2da3375 , 0x6dffd0a3 , 0xfacd50aa , 0x1d8c0e8d , 0x2d5905ef , 0x35ae2552 , 0x2a5e394f ] )
+  , Mont::unsafe_make( [ 0x4f68c195 , 0xbcab6206 , 0x5618e60a , 0x32d6749c , 0x8799d7b8 , 0xef414691 , 0xef6b82d1 , 0x2cc4864d ] )
+  , Mont::unsafe_make( [ 0x71e575c0 , 0x837a8095 , 0xf4339410 , 0xd29aee94 , 0x6f123f65 , 0x1e85a447 , 0x71f6fec4 , 0x2dfda4e0 ] )
+  , Mont::unsafe_make( [ 0x973223bc , 0xd6e8f9a5 , 0x834bd404 , 0x280aa33a , 0x93e4227b , 0x39401bc5 , 0xd6fa964e , 0x00b4d132 ] )
+  , Mont::unsafe_make( [ 0xb1372929 , 0xcd6fdf1b , 0x65816763 , 0xf86ec2d2 , 0xfe5d7bef , 0x4556d249 , 0x9e766425 , 0x14cf6656 ] )
+  , Mont::unsafe_make( [ 0x057ec2e7 , 0x7b81068b , 0x2ab91e3a , 0xb9b6d12f , 0x5b94c1f6 , 0x023002ba , 0xa52c7bb1 , 0x0110ae09 ] )
+  , Mont::unsafe_make( [ 0x690e2a97 , 0x8ae559d9 , 0x1fd8ea1f , 0x09ef7543 , 0x1d3f2393 , 0x58263dd2 , 0x918291ce , 0x000778ad ] )
+  , Mont::unsafe_make( [ 0xf97cefa7 , 0xda8d314b , 0xb2972f6f , 0x5520cf41 , 0xb840fd50 , 0x05c9f152 , 0x0b13dff2 , 0x2d35bc4f ] )
+  , Mont::unsafe_make( [ 0x18fab760 , 0x74be9b9b , 0x04bf568c , 0x5770c768 , 0xbc6f7b96 , 0xef28f566 , 0x4e9314e4 , 0x0ed5e953 ] )
+  , Mont::unsafe_make( [ 0xf6951fef , 0x947820cc , 0xc22989e5 , 0x7014b680 , 0x0585c39e , 0x29f918b0 , 0xa9fe4470 , 0x2f7beacc ] )
+  , Mont::unsafe_make( [ 0x68ab4718 , 0x912b7e68 , 0xb19abec3 , 0x4ad35645 , 0x32cd34e9 , 0xcb555b28 , 0x94f698f9 , 0x1863442d ] )
+  , Mont::unsafe_make( [ 0x35265b1c , 0x4981b83c , 0x485b25ff , 0x5c3150e4 , 0x0b0c2dc2 , 0xc7fb4c0e , 0x76ffc2da , 0x0be4a296 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x96e1f804 , 0x13f118ff , 0x2caf75f1 , 0xc0fa7e9b , 0x9298ca19 , 0x2f6340c5 , 0x030d7288 , 0x2190e2da ] )
+  , Mont::unsafe_make( [ 0xf1c7d1fb , 0x586b202f , 0x1b754856 , 0xf9380386 , 0x8ab4d7e6 , 0x91285394 , 0x3383eff1 , 0x0ccc1a28 ] )
+  , Mont::unsafe_make( [ 0xb16034c7 , 0xbef2ed97 , 0xa855ccd3 , 0xcdd4dc2d , 0x3c208e06 , 0x9ed5c5f0 , 0xa4ff519d , 0x1b48d2f4 ] )
+  , Mont::unsafe_make( [ 0x7c6101ee , 0xc51abbc4 , 0xb6120341 , 0xa91345e5 , 0xffb95a91 , 0x33171983 , 0xf3e8ccdd , 0x1a85c7c7 ] )
+  , Mont::unsafe_make( [ 0xd88a84cf , 0x876ab72d , 0x366c3c0f , 0xee902102 , 0x60a373d5 , 0x7a5ec654 , 0x7bbebae4 , 0x183e25c7 ] )
+  , Mont::unsafe_make( [ 0xd39ed134 , 0x43e3a5b3 , 0xdff129e5 , 0xef1ff047 , 0x3923ebf9 , 0x107149a1 , 0xff66f4f4 , 0x25f8fad8 ] )
+  , Mont::unsafe_make( [ 0xfeb38fac , 0x70157cac , 0xa3b2008e , 0x2f1715f9 , 0xd36c219f , 0x60871c5e , 0x4d8af1d9 , 0x280d27a2 ] )
+  , Mont::unsafe_make( [ 0xb11741b9 , 0xabc16e8f , 0xf1ca3f2b , 0xc3734f0e , 0x9fb09260 , 0x335c8180 , 0x68d3d776 , 0x28661eac ] )
+  , Mont::unsafe_make( [ 0x948441a6 , 0x6505a46f , 0x88052101 , 0x7881bc9f , 0x1a3dae7f , 0x8527fb9c , 0xc3304be8 , 0x2af931ae ] )
+  , Mont::unsafe_make( [ 0x7f5d312c , 0x53aa66da , 0xb6124225 , 0xe3c10120 , 0x66d8b46b , 0x72c13df3 , 0x6e83f792 , 0x1ef9a83d ] )
+  , Mont::unsafe_make( [ 0x4f30d161 , 0x17b9dee0 , 0x9eae6743 , 0x9a4f4439 , 0xa4b410e5 , 0x0d0e5e3e , 0xc44d9cb6 , 0x2ba34558 ] )
+  , Mont::unsafe_make( [ 0x3e3bebf1 , 0x9790d38f , 0x4bd1c623 , 0x5c54a6fe , 0x53ccf350 , 0x831837e0 , 0xeb6d5016 , 0x2efed18d ] )
+  , Mont::unsafe_make( [ 0x93a961eb , 0x4d4bc047 , 0xbd12fc05 , 0xccaacd04 , 0x8a37fad3 , 0x38bab90a , 0x021f3e64 , 0x239b37cb ] )
+  , Mont::unsafe_make( [ 0x7068aeff , 0xa5ba2ac0 , 0x6f75b6a2 , 0xa0422685 , 0x3af40b6b , 0x044ca87a , 0xde9fc5fe , 0x1bf833a6 ] )
+  , Mont::unsafe_make( [ 0x4d90d33e , 0x3cf1b17c , 0x94048f34 , 0x67f8e621 , 0x6d133b8c , 0xfea69546 , 0x8a5af8c6 , 0x06994628 ] )
+  , Mont::unsafe_make( [ 0xf9f16a4a , 0x13a12693 , 0x2dc3b270 , 0x79fc4ab5 , 0xdd8e22db , 0x57447323 , 0xca2dc2b8 , 0x28d1bf4e ] )
+  , Mont::unsafe_make( [ 0x21235ed1 , 0x649f2f0b , 0x13f6c6c9 , 0x0341ffc7 , 0x5d7531cf , 0x220db005 , 0xa8cd8fae , 0x1857cd03 ] )
+  , Mont::unsafe_make( [ 0x39d39300 , 0x123ddcb6 , 0x938b76d3 , 0x8f0f33ef , 0x009d2e94 , 0xa9145903 , 0x0949fc42 , 0x1cf09de8 ] )
+  , Mont::unsafe_make( [ 0x614b469a , 0xaadffdb3 , 0x8b9574cc , 0xd14d44c9 , 0x09456ced , 0xc73097ff , 0x37d01455 , 0x1727c727 ] )
+  , Mont::unsafe_make( [ 0x72133985 , 0x781dd624 , 0xec6823b2 , 0xd3b63caf , 0x3dae29b2 , 0x20b2cb6d , 0xbaf2ed63 , 0x049b736f ] )
+  , Mont::unsafe_make( [ 0x1cb24608 , 0x397d4ac7 , 0x84d663cd , 0x6cd8a21a , 0x49bd4c80 , 0x16e6e804 , 0xd15eea9f , 0x2683e985 ] )
+  , Mont::unsafe_make( [ 0xceb811be , 0xbe7fd520 , 0x22473c39 , 0xfdef8416 , 0xa3581dc7 , 0xd5bdacc9 , 0x447b31fb , 0x2a0f3856 ] )
+  , Mont::unsafe_make( [ 0x3f41237a , 0xd24a7ce8 , 0x27af9afe , 0x746f715a , 0x44bce0b1 , 0x8b070601 , 0xb48bbc84 , 0x0ab1cdc2 ] )
+  , Mont::unsafe_make( [ 0x087bf9af , 0x81c3a85a , 0x9f78fbd2 , 0x25dd91e2 , 0x4cb08bea , 0xf83de46f , 0x75cc607b , 0x30184b65 ] )
+  , Mont::unsafe_make( [ 0xf80de525 , 0x3ed762e6 , 0x173ebfd4 , 0xadf0aa31 , 0x361dd9fe , 0x43446796 , 0x9edc1cc3 , 0x2ba37ded ] )
+  , Mont::unsafe_make( [ 0xdf49db49 , 0x6806ce38 , 0xe73c7f75 , 0x0f4c67ff , 0x2679400a , 0x72f1f7db , 0x589597a0 , 0x280143aa ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x75cd6979 , 0x01bd3a77 , 0x34930dfc , 0x12d3ac22 , 0xf1f6b47d , 0xcc426143 , 0x45f21704 , 0x2497af16 ] )
+  , Mont::unsafe_make( [ 0x15235d36 , 0xdaa11e60 , 0xcdb4cfc0 , 0x1ca7d81c , 0xd4566bf6 , 0x72d8439c , 0xaf1eecf9 , 0x0a13e12f ] )
+  , Mont::unsafe_make( [ 0xbbf17d71 , 0xba4162f2 , 0x173397bd , 0xd8785a61 , 0xcf172484 , 0xe5700734 , 0xc02437dd , 0x0246f350 ] )
+  , Mont::unsafe_make( [ 0xc02006dc , 0x4d2109ae , 0xeb38ad11 , 0xb542dde0 , 0x8cdc09a8 , 0xfe019cda , 0x5472565b , 0x0f201078 ] )
+  , Mont::unsafe_make( [ 0xc07d9665 , 0x0320cbfc , 0x1ac297b6 , 0x1e357fc2 , 0x525f2a8a , 0x693c0280 , 0xa9e6abb0 , 0x19c3d3f9 ] )
+  , Mont::unsafe_make( [ 0x37dfb4d0 , 0x25029abb , 0x4257ce77 , 0x73149065 , 0x9a20ea93 , 0x3afa5fbc , 0x29ae8900 , 0x213cf577 ] )
+  , Mont::unsafe_make( [ 0x09a2931e , 0xdc863488 , 0x81c97ccd , 0xaca867f3 , 0x46b30a9d , 0xa5d27b5f , 0x943fdac8 , 0x07cd6fae ] )
+  , Mont::unsafe_make( [ 0x0c95d5f0 , 0x171a8acf , 0x58c7a435 , 0xf0dc95cf , 0x931b7819 , 0xef6084db , 0xb6d3e3dd , 0x2aaa0cc8 ] )
+  , Mont::unsafe_make( [ 0x74d5e12b , 0x9d07f5c9 , 0x6bc0bf0a , 0xfc030e55 , 0xf27fa124 , 0xc7a236cf , 0x74003c5d , 0x2324e78a ] )
+  , Mont::unsafe_make( [ 0x45030cf8 , 0xa5666133 , 0x846c3f34 , 0xaa9a509c , 0xb036ebcf , 0x062a117a , 0x6d127c18 , 0x02b7cb41 ] )
+  , Mont::unsafe_make( [ 0x96c90d19 , 0xc62dc045 , 0x526aeb05 , 0x37d0c8ee , 0x0ce46120 , 0x9e7807fd , 0x5a3430fc , 0x1dce6d2f ] )
+  , Mont::unsafe_make( [ 0xf2f98bcb , 0x3f107e2c , 0x805aeb75 , 0xbae10449 , 0xa1f921cc , 0x4e363480 , 0xdd73fdab , 0x0730a98a ] )
+  , Mont::unsafe_make( [ 0x07b00fd4 , 0xb6109f2a , 0xb694b583 , 0xa9e76c0d , 0x990a0ad5 , 0xc84f42bc , 0x4591fad9 , 0x177beb92 ] )
+  , Mont::unsafe_make( [ 0x45ed9089 , 0x2626fa8e , 0x26621f12 , 0xd8118511 , 0x9654fdba , 0x4adaed93 , 0xb27c83e1 , 0x13552c01 ] )
+  , Mont::unsafe_make( [ 0x4c0f7831 , 0xa442fea9 , 0x51b200f9 , 0x6df27367 , 0x49e33849 , 0xd727e1b2 , 0xd6132f5d , 0x068a420a ] )
+  , Mont::unsafe_make( [ 0xc7474d81 , 0xf079c430 , 0x0330dbfe , 0x2d3230de , 0xd8b45188 , 0x440dc58c , 0x9088bb9d , 0x01166e4e ] )
+  , Mont::unsafe_make( [ 0xd25e144c , 0xc43b80e8 , 0x7d0ae7a9 , 0xe934fe51 , 0x0604cf2a , 0x6832b9c1 , 0x500f59b3 , 0x28db358d ] )
+  , Mont::unsafe_make( [ 0x25faac08 , 0x3e718698 , 0xd41e6e26 , 0x930f329e , 0x7bbc32cb , 0x7d78a6e2 , 0x55dfa9fc , 0x29b03d8d ] )
+  , Mont::unsafe_make( [ 0x9d0cd83a , 0x2cf32619 , 0xd6eaa7e4 , 0xfea20214 , 0x8785eacf , 0x7197d059 , 0xe47448a1 , 0x143ec30c ] )
+  , Mont::unsafe_make( [ 0x3b5e65b3 , 0xc46fffc1 , 0x821ab025 , 0x4aa9a64b , 0x25b84095 , 0x228bc74c , 0x51ca30dc , 0x0f4e54be ] )
+  , Mont::unsafe_make( [ 0xbe598162 , 0xb2bc303d , 0xdc77794d , 0xbf2cb97b , 0x6b52e961 , 0x38fe6f69 , 0x03795a7f , 0x1879636e ] )
+  , Mont::unsafe_make( [ 0x7cf3ec82 , 0xc25673ed , 0xba638886 , 0x289398bb , 0xb2774699 , 0x667ac46f , 0x65f0f163 , 0x1aa15c58 ] )
+  , Mont::unsafe_make( [ 0xb5a98b29 , 0x983735c8 , 0xc4920b69 , 0x6646ae3e , 0xcc284f06 , 0xde55e79c , 0x75a5b464 , 0x0498ba69 ] )
+  , Mont::unsafe_make( [ 0x9cb144d1 , 0x26ab128d , 0xe312230e , 0xe99de253 , 0x11338041 , 0x2b94a9b8 , 0xb1558946 , 0x087f1f95 ] )
+  , Mont::unsafe_make( [ 0xa6413904 , 0x204b4554 , 0x0d7b987f , 0xd043f08d , 0x2ad24920 , 0x5a295bdb , 0x0d9be95a , 0x27fde6a5 ] )
+  , Mont::unsafe_make( [ 0xdcd8a123 , 0x5376a30d , 0x3565dbca , 0x57d1eca8 , 0x4130a399 , 0xc2d05c20 , 0xac0186a3 , 0x0698a684 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x0b303482 , 0xf3bc295d , 0x6ff580be , 0xf3af7ffa , 0xc5f6056f , 0xd2d75db0 , 0x21e8e46d , 0x248aa7c2 ] )
+  , Mont::unsafe_make( [ 0x417ae206 , 0x22b35ae5 , 0x0a302395 , 0x4c23db83 , 0x744e5778 , 0x8edd77bd , 0x26555331 , 0x24b629f2 ] )
+  , Mont::unsafe_make( [ 0x6b5504d1 , 0x002357fd , 0xcbab7293 , 0x0ffad511 , 0x8ff64c7d , 0xf70b4540 , 0xa247a117 , 0x1db7bba2 ] )
+  , Mont::unsafe_make( [ 0xda5ca0d4 , 0x4e593240 , 0xe04aba5e , 0x9d745788 , 0x5954c742 , 0x542ca533 , 0x37c02317 , 0x154948a8 ] )
+  , Mont::unsafe_make( [ 0x2e2fd516 , 0x921da94a , 0x210a4d02 , 0x990692f7 , 0xda46f5a3 , 0x2ada997c , 0xf984270a , 0x10ec479f ] )
+  , Mont::unsafe_make( [ 0x06f67e23 , 0xc8274e01 , 0x69ee58d4 , 0x2af8f819 , 0x72b89323 , 0xc8932c45 , 0x600ef953 , 0x2390a0b6 ] )
+  , Mont::unsafe_make( [ 0x112f7fb1 , 0xf633930f , 0xa80f506c , 0x8a3b3b40 , 0x71fe9138 , 0xb3fd29fd , 0x6e5fafd8 , 0x0d672802 ] )
+  , Mont::unsafe_make( [ 0x283a2db2 , 0xec4fd442 , 0x83183e6d , 0x82020c58 , 0x48686e7d , 0xf57e5c0b , 0x34e26435 , 0x1d99ddbe ] )
+  , Mont::unsafe_make( [ 0x16e8a6b5 , 0xed7970d0 , 0x906d0504 , 0x0fc80cd1 , 0xdd2e4e17 , 0x33d69fe2 , 0xb50a7ff8 , 0x26e5dea2 ] )
+  , Mont::unsafe_make( [ 0xeffe149a , 0xf2f54b81 , 0x8a1c59e1 , 0xad1be658 , 0x03de8a28 , 0x5e69d7d3 , 0x5cff145d , 0x20bf55d5 ] )
+  , Mont::unsafe_make( [ 0xe5845784 , 0x83f25b94 , 0xcaa489af , 0x93a56fa8 , 0x7eacdd24 , 0x1379caa0 , 0xa8f689f8 , 0x1ba1f684 ] )
+  , Mont::unsafe_make( [ 0xe74db860 , 0x83e7c970 , 0x6a61f853 , 0x405e51f3 , 0x3e98f6da , 0xc5d48938 , 0x2179c0a8 , 0x1477cf31 ] )
+  , Mont::unsafe_make( [ 0x1f369f1a , 0xb03836a5 , 0x7f2453f8 , 0xef70cc1a , 0xeefcd1d7 , 0x65a8f5da , 0xa637e492 , 0x01628b5a ] )
+  , Mont::unsafe_make( [ 0x11ec875e , 0x3f02eb38 , 0xdce465f0 , 0x23e01470 , 0x8fb857bb , 0x1281ed82 , 0xa72d118d , 0x170aa076 ] )
+  , Mont::unsafe_make( [ 0x7eb79bae , 0x36c78801 , 0x621e94bb , 0xc9d0c4f0 , 0x554fdc7d , 0x038eada9 , 0xf1a293c4 , 0x2ae70d26 ] )
+  , Mont::unsafe_make( [ 0xd1ede72b , 0xbd79e5bd , 0x4a6dfd34 , 0x650f6d35 , 0x85feee96 , 0xe6976c49 , 0x3d95913d , 0x2106d69b ] )
+  , Mont::unsafe_make( [ 0x007c23ab , 0x593823ba , 0x689f9cb3 , 0x647a8113 , 0x9d0ae99d , 0x308099ae , 0x7e3b9dad , 0x0ff6b86c ] )
+  , Mont::unsafe_make( [ 0x9aeb66bf , 0x3aba9e0b , 0x836bda65 , 0xb7599bba , 0xcae4f3bd , 0xceb54a9c , 0x7ea0160d , 0x2eaf9eba ] )
+  , Mont::unsafe_make( [ 0xfaf3718e , 0xe5a24b56 , 0xd73168df , 0xb695dea7 , 0xd446794b , 0x97ec6995 , 0xd693c9d0 , 0x29fd95eb ] )
+  , Mont::unsafe_make( [ 0xa964a789 , 0x30ad7447 , 0x320d2e86 , 0x87430d0c , 0x219d1591 , 0xf880ea75 , 0xe9a29ff0 , 0x07bedd44 ] )
+  , Mont::unsafe_make( [ 0xb114807a , 0x35ae6de1 , 0x0b41c8e3 , 0x4ed68efe , 0x25ebcc2a , 0x84fc1f3a , 0x20fc3af5 , 0x0f96a4ff ] )
+  , Mont::unsafe_make( [ 0x9c67dfd5 , 0xd4fc4aaf , 0x8748e768 , 0x30574ed2 , 0xf1092f20 , 0xb87b41b5 , 0xd64ad6f2 , 0x17ccc365 ] )
+  , Mont::unsafe_make( [ 0x391863e0 , 0x3b743caf , 0xef6acd69 , 0x81104fb6 , 0x96992a3a , 0xdf9f4418 , 0x169a7f71 , 0x044a1971 ] )
+  , Mont::unsafe_make( [ 0x6c3cc8ae , 0x5f8736f0 , 0xf00cb701 , 0x7d2fdad8 , 0x6537d1c5 , 0xf098da92 , 0x219cec75 , 0x2f3dde6d ] )
+  , Mont::unsafe_make( [ 0x87e7d92e , 0x01ac80b3 , 0x75065b53 , 0xe27c1b3c , 0x8ecc239e , 0xdf646a64 , 0x379cfe6a , 0x253523f7 ] )
+  , Mont::unsafe_make( [ 0x1e31576b , 0x372c5e33 , 0x706b4448 , 0x27d3dd74 , 0xa285726d , 0xad6d0479 , 0xe54b5ed9 , 0x2588acfd ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xe98fb83b , 0x78072231 , 0xfb741f13 , 0x9e526113 , 0x6fb83dcf , 0x3e5e6ef5 , 0xed080f7b , 0x003cb9da ] )
+  , Mont::unsafe_make( [ 0x32ab7854 , 0x15d6783c , 0x3ed1f8d8 , 0xeefd7fa5 , 0xb69f3841 , 0x3faf2a93 , 0x78005f49 , 0x2dcd32f2 ] )
+  , Mont::unsafe_make( [ 0x14851c49 , 0x3b7d6b04 , 0xbb396fc4 , 0x03dbf7e5 , 0x41e1c92e , 0x009bd43f , 0x92e6e015 , 0x3009cbe6 ] )
+  , Mont::unsafe_make( [ 0xa395b6e8 , 0x8f0575dd , 0xed79e1c6 , 0x13927443 , 0xd8b6722d , 0xbc0cabae , 0xdfbb339e , 0x2e925ccf ] )
+  , Mont::unsafe_make( [ 0xdb3e1f63 , 0x305cd0b3 , 0xf94d61c0 , 0x7813fc60 , 0x308faa34 , 0x8c20b90a , 0xdcd175ab , 0x2aba7cbe ] )
+  , Mont::unsafe_make( [ 0x38e41d75 , 0xfc9ced92 , 0x7974c824 , 0x836f5788 , 0x785b542e , 0x6e538ad7 , 0x582473f5 , 0x2294f27e ] )
+  , Mont::unsafe_make( [ 0x3ef427d1 , 0x0685f7b6 , 0x6f74e2f6 , 0xa3e8f813 , 0x4d8c2167 , 0x21c8b5e3 , 0x9686a909 , 0x10486241 ] )
+  , Mont::unsafe_make( [ 0x3b3b40db , 0xf5490026 , 0x2b6952ab , 0x4abf9305 , 0xc4541b20 , 0xf587df14 , 0xcb64e0d2 , 0x03bde243 ] )
+  , Mont::unsafe_make( [ 0xecf5ae61 , 0x3630596e , 0x02eab4e2 , 0x9a1d3063 , 0x379eae58 , 0xc4deaad0 , 0xc5874c5c , 0x22ad6ac8 ] )
+  , Mont::unsafe_make( [ 0xd9d61375 , 0xf7a7ac0a , 0x7da5a5e7 , 0x71fed38b , 0x60dd3a4f , 0x140e485a , 0x3df63619 , 0x13b3a319 ] )
+  , Mont::unsafe_make( [ 0xb41592a1 , 0x88a016a2 , 0xb6ff581e , 0x6e46720d , 0x18a63ba2 , 0x82f1fffd , 0x47a99ef1 , 0x1874033a ] )
+  , Mont::unsafe_make( [ 0xbef97386 , 0xfaadb58e , 0xb7cc16a2 , 0x04f605bc , 0x09f0a9b9 , 0xa7447437 , 0xff4eb1f5 , 0x1659c5f7 ] )
+  , Mont::unsafe_make( [ 0xfa17d6cd , 0x0c201808 , 0x8f96955d , 0x820cb5e0 , 0xbb520e6f , 0x86ab1718 , 0x727e1cfd , 0x2d060d49 ] )
+  , Mont::unsafe_make( [ 0xc5284f54 , 0x288a6819 , 0xaba9bfa5 , 0x53bd5cd4 , 0xb820d14d , 0xac6a868a , 0xba1d17cc , 0x1fbc8e19 ] )
+  , Mont::unsafe_make( [ 0xbd2199a1 , 0x6e376b34 , 0xb2b955b7 , 0xc7b1c560 , 0xf7e4682c , 0x125c131e , 0xadda48d0 , 0x2529dd21 ] )
+  , Mont::unsafe_make( [ 0x0046f66b , 0xb40b0360 , 0x94a8d522 , 0x271ffde0 , 0x6052c2c7 , 0x79d278f2 , 0x6a95bbca , 0x296106df ] )
+  , Mont::unsafe_make( [ 0x14c689eb , 0xb3559bdb , 0x85510ad0 , 0xe54d8a79 , 0x6d5cb8c5 , 0x24f7f239 , 0x751b4daa , 0x0f4f5a61 ] )
+  , Mont::unsafe_make( [ 0xc8a4dbbf , 0x40afdfe1 , 0x1f964601 , 0x75b1c2bb , 0xb1221dc7 , 0x718f31c3 , 0xa8cd7799 , 0x093e6dfa ] )
+  , Mont::unsafe_make( [ 0xd2123ce3 , 0x953b0f63 , 0x7e3d8390 , 0x0be26273 , 0xef71c791 , 0xabf2da60 , 0xedc74b44 , 0x247b87d2 ] )
+  , Mont::unsafe_make( [ 0x1b31a8aa , 0x0ddad08f , 0xeaf85099 , 0x6ef51965 , 0x4ab8af10 , 0xbbe5a811 , 0x066915c3 , 0x0d42c7c2 ] )
+  , Mont::unsafe_make( [ 0x59e0dc3e , 0xae9fbd25 , 0xcc18a0c4 , 0x1c5af40b , 0x1db71597 , 0x2e11f91a , 0xffb341d9 , 0x0e225a0b ] )
+  , Mont::unsafe_make( [ 0x7111e0fa , 0x6cb14ed9 , 0x4ffe1051 , 0xb097b130 , 0xfa56ff16 , 0x548091ad , 0x990d219b , 0x227c5b1e ] )
+  , Mont::unsafe_make( [ 0x66091fdc , 0x6e0293c7 , 0xd8ad1537 , 0x674c113b , 0x25aa150e , 0xf8a17ff2 , 0xd37ba6ee , 0x2de122c7 ] )
+  , Mont::unsafe_make( [ 0xee6b1410 , 0x0d9fcc92 , 0xb4296e4c , 0x533eb09a , 0x594b85e1 , 0x1fbbd086 , 0x6d4409ef , 0x15aceb4c ] )
+  , Mont::unsafe_make( [ 0xdbe1b4a3 , 0xcf6ae6ce , 0xcc00981e , 0x4689d217 , 0xb424bd94 , 0x312dd65a , 0xd65300bc , 0x06df23ba ] )
+  , Mont::unsafe_make( [ 0x8ec4ceb8 , 0x4104098c , 0x51f1bb8b , 0x9a812c0a , 0x8d036850 , 0x57618d55 , 0x36098c49 , 0x13515831 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xee0f5541 , 0x7bc110d1 , 0x13c0e622 , 0xde4aefdf , 0x3f155dec , 0xa78757ef , 0xc49c1eed , 0x25f9f98c ] )
+  , Mont::unsafe_make( [ 0x38af1d22 , 0x4d9dcc9e , 0xd2ec59d6 , 0xd54cf5fb , 0x75246732 , 0xbaf151a2 , 0x795eb137 , 0x1b6374f1 ] )
+  , Mont::unsafe_make( [ 0x2e5afb54 , 0x1999f099 , 0x7ec51e0c , 0x80a8915b , 0x358e162e , 0xfe9a5ff9 , 0xd83c980e , 0x1008bac2 ] )
+  , Mont::unsafe_make( [ 0xee2ae874 , 0x9fd9a150 , 0x9475fdbc , 0xf5d27281 , 0xf80f937d , 0x347eba02 , 0x885a9633 , 0x2fc66b63 ] )
+  , Mont::unsafe_make( [ 0x09204eb6 , 0xdfe1bad7 , 0x54f2a14f , 0xa2de7fac , 0x3368e4d9 , 0x29c4b354 , 0x79219cc2 , 0x191def43 ] )
+  , Mont::unsafe_make( [ 0xbce21c61 , 0x74b1b80c , 0x36dd94b8 , 0x0a9b7e7d , 0x2dbdeab6 , 0xa2416a85 , 0x2c436d7a , 0x0a2bb6ce ] )
+  , Mont::unsafe_make( [ 0x30bff917 , 0xdb8c9d6d , 0x84bbea0c , 0xfb884429 , 0xbf6ff466 , 0x33efc87e , 0x2851242b , 0x1351bd2a ] )
+  , Mont::unsafe_make( [ 0xd226b984 , 0xef51c9b6 , 0x370beca9 , 0x98b70896 , 0x40c91ec6 , 0x5175dab5 , 0xca5b1bca , 0x2feda9de ] )
+  , Mont::unsafe_make( [ 0x207fa8aa , 0xab9367a7 , 0x8e5cccd7 , 0x1dc85c85 , 0xb3ef5773 , 0xe7ecdfe4 , 0x786b130b , 0x2b36b16f ] )
+  , Mont::unsafe_make( [ 0xa5e6c577 , 0x1f2ec661 , 0xfa57cea8 , 0xa004036a , 0x97033746 , 0xe959d747 , 0x66f3a3f1 , 0x05c4f9ab ] )
+  , Mont::unsafe_make( [ 0x33142e5c , 0xfc673bff , 0xc9e57a38 , 0x821ccb65 , 0x0200a0c3 , 0x1a2cd151 , 0xf08b65d9 , 0x1fc32c28 ] )
+  , Mont::unsafe_make( [ 0x22cc2769 , 0x96ac8fda , 0x0dfdcaf8 , 0xe1addb52 , 0x7800ee43 , 0xa50ba43c , 0xcd8344fd , 0x153f7f8e ] )
+  , Mont::unsafe_make( [ 0xe73cdd72 , 0xde89fa39 , 0x7323f4cb , 0x2676af74 , 0xd9bd7235 , 0xf5fd89ee , 0x43fd8c16 , 0x1ef2f152 ] )
+  , Mont::unsafe_make( [ 0xd8821370 , 0xa696730c , 0x9ff3025c , 0xa2242e1d , 0x2254a133 , 0x57730bfc , 0xf60d1e24 , 0x1a68ea1c ] )
+  , Mont::unsafe_make( [ 0x51b63c7c , 0x3c1ecd35 , 0x334300ff , 0xa9d5173d , 0x36883a75 , 0xf6ac86f3 , 0x2505599a , 0x01cf50f7 ] )
+  , Mont::unsafe_make( [ 0x5ff9dc25 , 0xf583a7e6 , 0x1f28226b , 0x82f69862 , 0x9207dc98 , 0x66ed25e6 , 0x664bea5d , 0x1e09dfee ] )
+  , Mont::unsafe_make( [ 0x6f451a4d , 0x8b0f881b , 0xbd23fb26 , 0xde30f04d , 0xdd5020d3 , 0xe9c6f4cb , 0xca8827eb , 0x1cf48810 ] )
+  , Mont::unsafe_make( [ 0x21c3b0ce , 0x6066ba1c , 0x2ea99588 , 0x7d46fd03 , 0x64bfa779 , 0x77d0fb79 , 0x3b4187de , 0x032fd41b ] )
+  , Mont::unsafe_make( [ 0x01b6aa1f , 0x5fe5d25c , 0xd1fb0194 , 0x07fe7540 , 0x8c1bfbd4 , 0x0bfa7407 , 0xb5ba8062 , 0x0b3482c4 ] )
+  , Mont::unsafe_make( [ 0xba753d7d , 0x1c5ab3d6 , 0x3b566241 , 0xc8ae0184 , 0x8a0b5c44 , 0xeb85adb2 , 0x31f7845b , 0x079872d7 ] )
+  , Mont::unsafe_make( [ 0xbee3733c , 0x7de3dc5f , 0xb3d86e70 , 0x37643c68 , 0x78580e5c , 0x1cb9d93b , 0xfd06fe56 , 0x0eeb6d3d ] )
+  , Mont::unsafe_make( [ 0x22a6f3c5 , 0x99db0f29 , 0xd93e1568 , 0x476b7de6 , 0x28ba26d7 , 0x58ac9f45 , 0x43dfa8c3 , 0x12892790 ] )
+  , Mont::unsafe_make( [ 0xea7cfaf0 , 0xfe59e452 , 0x070af24e , 0x31e8bec0 , 0x51707989 , 0x7609618f , 0x5f9b296a , 0x0dc8ec8c ] )
+  , Mont::unsafe_make( [ 0xf5c68409 , 0x650ecd4f , 0xa7fe50dc , 0x20da469c , 0x43de69fb , 0x7e994bc1 , 0x6860ac5c , 0x1be19fd4 ] )
+  , Mont::unsafe_make( [ 0x99c8a241 , 0x8b4f9e43 , 0xd01a3d4d , 0x160be88e , 0x156b283d , 0x7b33c280 , 0xa6e7e697 , 0x1dc1b709 ] )
+  , Mont::unsafe_make( [ 0x4f69b106 , 0x9c9d3be9 , 0xa4325af9 , 0xe248cfd3 , 0x705ccd23 , 0x24de4275 , 0x33b60f55 , 0x1183b20e ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xe4acc16a , 0x3ae110bf , 0xfdf76641 , 0xde089040 , 0x69df0445 , 0xf6c75405 , 0x063fe38b , 0x20fcf31c ] )
+  , Mont::unsafe_make( [ 0x3fbe9c3b , 0x720eaecf , 0xbc2689a4 , 0x373a12b9 , 0x8067c277 , 0xa61ef34b , 0xfc6b2305 , 0x20133389 ] )
+  , Mont::unsafe_make( [ 0x6eac9098 , 0xdc72ed9e , 0x41741a59 , 0x7b0537ff , 0x8182c421 , 0x4abea6c3 , 0xeef78337 , 0x0001d6fe ] )
+  , Mont::unsafe_make( [ 0x488596a0 , 0x86460144 , 0x63f65ea3 , 0x09105c18 , 0xf7b01df4 , 0xed8d7c49 , 0xe7dcf6a7 , 0x0ed0a952 ] )
+  , Mont::unsafe_make( [ 0xf230340f , 0xe17fc2ad , 0xe2b8b612 , 0x1e7c680d , 0x4b2b02ee , 0xa6b4abb4 , 0xbeca5e05 , 0x01ecade0 ] )
+  , Mont::unsafe_make( [ 0x068f1d35 , 0x5b9d1cf5 , 0x42996118 , 0x06f4049d , 0x5f9aed19 , 0xebc28778 , 0xe5ae65b0 , 0x0c468236 ] )
+  , Mont::unsafe_make( [ 0xded27cfe , 0xe835a8c7 , 0xa21a05cf , 0xc572a47a , 0xfd383f07 , 0x2045c635 , 0x3c67ffcd , 0x0e0c01e9 ] )
+  , Mont::unsafe_make( [ 0x7f24a314 , 0x5b961547 , 0x055b2231 , 0xf762ad24 , 0x5fbcbfe9 , 0xf525172f , 0x14306bc9 , 0x04bc0cac ] )
+  , Mont::unsafe_make( [ 0x87e37d99 , 0xbba6d012 , 0xab10bbc6 , 0x907d9389 , 0xb942eb00 , 0xa45c41b0 , 0xd568e0b2 , 0x2627e4e1 ] )
+  , Mont::unsafe_make( [ 0xd8ca00fd , 0x36aea08c , 0xab9fba32 , 0x8d1ca3d4 , 0xa1b287b9 , 0xa92aa43e , 0x1b8a2c87 , 0x1708e718 ] )
+  , Mont::unsafe_make( [ 0xbb73980b , 0x3de5397b , 0xa80bd4df , 0xa99c3d14 , 0x211212ec , 0x15c4cad8 , 0x3bca688c , 0x210bf1d9 ] )
+  , Mont::unsafe_make( [ 0xa8c46574 , 0xe793c886 , 0x7c7fb041 , 0x64bbfbb5 , 0x3a628edf , 0x405285c5 , 0xf0bbdce2 , 0x1d39b271 ] )
+  , Mont::unsafe_make( [ 0x18884a0d , 0x86bb6584 , 0x663ec1cd , 0xa9603413 , 0x1398b8f6 , 0x217ab04b , 0x3273a5d2 , 0x2d272b0c ] )
+  , Mont::unsafe_make( [ 0xaadd9aa0 , 0xd76f38f1 , 0x6a3dbfcf , 0x351a111b , 0x969fc1e0 , 0x43ce616c , 0x6afeeffe , 0x20b4f9f9 ] )
+  , Mont::unsafe_make( [ 0x75a4a02a , 0x46b02838 , 0x45bc4dc1 , 0xcd0b6e3a , 0x7aa79c4f , 0x29b90bb7 , 0x17d5f7ce , 0x12182f8a ] )
+  , Mont::unsafe_make( [ 0x14d7c117 , 0x7bc6a79d , 0x06c0aca0 , 0x90d2805c , 0x57094e8b , 0x44945b1c , 0xd87c327c , 0x0fd67669 ] )
+  , Mont::unsafe_make( [ 0x8a90befb , 0x09708bfe , 0x3e5c2029 , 0x2622269e , 0x3d48e6c0 , 0xe39552ba , 0x064b87a9 , 0x21d66dd6 ] )
+  , Mont::unsafe_make( [ 0xc57508ea , 0xe6b05a78 , 0xa13024b7 , 0x3adc3ba2 , 0x27cbd011 , 0xf012fe20 , 0x1adc885b , 0x0726add2 ] )
+  , Mont::unsafe_make( [ 0xa1f0f7b2 , 0x07312f36 , 0x8442c0dc , 0xb6766348 , 0x51f5f59a , 0xa59b455e , 0x4789b4f8 , 0x26040d3f ] )
+  , Mont::unsafe_make( [ 0xf2359169 , 0x475e7ade , 0x037a6ba7 , 0x26a6997c , 0x899385b7 , 0x50e6c124 , 0xf291c643 , 0x035e5d88 ] )
+  , Mont::unsafe_make( [ 0x8f4f09de , 0xfba8f519 , 0xb4138ed7 , 0x0935aa56 , 0x1e2e49a7 , 0x559e498f , 0xc5e09bec , 0x08c6e40c ] )
+  , Mont::unsafe_make( [ 0xa548064e , 0xaeff2837 , 0xf6467563 , 0x3e77af90 , 0x9b656261 , 0xfdebdb91 , 0xcd78837e , 0x2b3901e4 ] )
+  , Mont::unsafe_make( [ 0xf2d39bee , 0xf90c738d , 0x6ad9bb65 , 0xe88e3d73 , 0x97bed737 , 0x68017c69 , 0xcfe0b880 , 0x22381588 ] )
+  , Mont::unsafe_make( [ 0x78ebe9ef , 0xc50c864b , 0x914980d6 , 0xe2a1ef19 , 0x85bc6c4d , 0x93655433 , 0x064a75b7 , 0x10fca8f3 ] )
+  , Mont::unsafe_make( [ 0x7746cbb3 , 0xff96070b , 0xb1bb31bf , 0x6a680431 , 0xbfb98653 , 0x09b43f45 , 0x7d335912 , 0x05fdf3ce ] )
+  , Mont::unsafe_make( [ 0x2c4e08d5 , 0xc5019f05 , 0xfd971a51 , 0xefd133c1 , 0x90380035 , 0xe4d120d8 , 0xbc7093cf , 0x2423ff2d ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x59e7d241 , 0xdcb38d6d , 0x9003d0ee , 0x56d8e8a8 , 0x5734a361 , 0x666523c0 , 0x2d49fc87 , 0x07f8a3b2 ] )
+  , Mont::unsafe_make( [ 0xa7130ea4 , 0xfd17beea , 0xbe9bd4e4 , 0xa1b3baee , 0xde35469e , 0x7c5373ab , 0xcd9af3e5 , 0x1443f46f ] )
+  , Mont::unsafe_make( [ 0x5fd25f36 , 0xffd86751 , 0x7837be5d , 0x55168e69 , 0x53065802 , 0xf1c418f6 , 0xd3390a0b , 0x1033799c ] )
+  , Mont::unsafe_make( [ 0x6acbca27 , 0x283eadea , 0x16adf477 , 0x706d39a2 , 0xebe5941c , 0x93c3c91d , 0x7122dccd , 0x046a5e99 ] )
+  , Mont::unsafe_make( [ 0x13b39d2c , 0xa6fc5c06 , 0x05a6e047 , 0xb681c05f , 0x37eea05d , 0x867c278c , 0xd29bb652 , 0x154b08a1 ] )
+  , Mont::unsafe_make( [ 0x5b4d154d , 0xbc5a6692 , 0xb8827efd , 0x49c4e2da , 0x79aee2c9 , 0xdcc9c1d2 , 0x31e45670 , 0x1ec7f8b5 ] )
+  , Mont::unsafe_make( [ 0xcd78ce9d , 0x4b865e91 , 0xde4bdf04 , 0x4bb9fc6a , 0xb77ef9c9 , 0x1e99091b , 0x65bedf34 , 0x0d5d906a ] )
+  , Mont::unsafe_make( [ 0xef78c0c5 , 0x80ecb2e5 , 0x81a98817 , 0x69c09359 , 0xd2f3af01 , 0xff8f99b2 , 0x0cbb7303 , 0x16d2bae1 ] )
+  , Mont::unsafe_make( [ 0x3a97daed , 0x7ce0c64f , 0x92e92fac , 0x22cf127a , 0xff9e56ae , 0x5b514d32 , 0x130e692c , 0x0149e548 ] )
+  , Mont::unsafe_make( [ 0x8aa266be , 0xf18f26a0 , 0x436d92a7 , 0x2d4f7790 , 0xd0a52458 , 0x026d663b , 0xe0653b9e , 0x12f630f3 ] )
+  , Mont::unsafe_make( [ 0x5c03fd3a , 0x302d4748 , 0x93199b1d , 0xf164da69 , 0xea3fcc5b , 0x92c5ecd1 , 0xe15c77e7 , 0x06dca918 ] )
+  , Mont::unsafe_make( [ 0xb4ad9e14 , 0x5c6c5711 , 0xa2ac149d , 0x18aedc1e , 0x79f79ddb , 0xe60b810c , 0x078c663e , 0x0541dcc4 ] )
+  , Mont::unsafe_make( [ 0x80d1433e , 0xcec04b3b , 0x31d8412a , 0x85c1cffa , 0xbbf0bbde , 0x0f789490 , 0xd6c0f28a , 0x144d6db6 ] )
+  , Mont::unsafe_make( [ 0x925447bd , 0x2d663f66 , 0x2c1641a9 , 0x59ee145e , 0xd3914ab5 , 0xf1a15aeb , 0x6d1f9ec5 , 0x2c59390a ] )
+  , Mont::unsafe_make( [ 0xa8611499 , 0x56dde397 , 0x67d4d033 , 0xab85d888 , 0x4a1fe619 , 0x731a5c39 , 0x226cd67d , 0x2e639bd3 ] )
+  , Mont::unsafe_make( [ 0xb810be04 , 0x8a1dbbc2 , 0x31d369d9 , 0xc3959f92 , 0x948b50c0 , 0x218d2871 , 0x276245c4 , 0x062f9989 ] )
+  , Mont::unsafe_make( [ 0x4a347cb1 , 0x9d4e718d , 0xa3756443 , 0x746ff6c9 , 0x68de286c , 0x6ab1ac78 , 0x07463d4c , 0x168fd699 ] )
+  , Mont::unsafe_make( [ 0xbb58201e , 0xecb66acf , 0xb82284b4 , 0xc4cb86bf , 0x003ae2d7 , 0xdee977df , 0x89b662fd , 0x0e5b8d51 ] )
+  , Mont::unsafe_make( [ 0x198d2ea9 , 0xef745c2e , 0x0d32eade , 0x3d05cf02 , 0xc3ec462f , 0xb6c672fb , 0xc8f07112 , 0x2f11eb33 ] )
+  , Mont::unsafe_make( [ 0x64a82edc , 0x8ae2a9a6 , 0xca18eb09 , 0xe1344844 , 0x13c21d08 , 0x9ac62391 , 0xe5868e81 , 0x2b744b1c ] )
+  , Mont::unsafe_make( [ 0xf0982c43 , 0xb3a9aa73 , 0x45506934 , 0x22e81206 , 0xd78cb480 , 0x96c24557 , 0x86f85b9f , 0x022a5800 ] )
+  , Mont::unsafe_make( [ 0xa5732f27 , 0x0fa0fd3a , 0x7ffb8406 , 0x10c85856 , 0x9f044c43 , 0x9e4176df , 0xd0397854 , 0x139d6de1 ] )
+  , Mont::unsafe_make( [ 0x0ef0d5cc , 0x766193a2 , 0x5e6e65e6 , 0xa55a5240 , 0x7a01e438 , 0x592171cf , 0x865afead , 0x0a3b77b4 ] )
+  , Mont::unsafe_make( [ 0xfe1bc029 , 0x779bf3a7 , 0x4451c201 , 0x6bd57e89 , 0x622c7cc2 , 0x088c75eb , 0x9392fb65 , 0x1a7cc59f ] )
+  , Mont::unsafe_make( [ 0x3e74ed77 , 0xdb664442 , 0x4bcc72be , 0xbc0f8098 , 0x807d11d9 , 0xd9f4ee1a , 0x144d69a8 , 0x16ab578c ] )
+  , Mont::unsafe_make( [ 0x91e46116 , 0x70b596fb , 0x4d813763 , 0xc004000f , 0x8c6cd469 , 0x6ec08775 , 0xadf6b9e0 , 0x243561c0 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x75b1a1cf , 0x05755617 , 0x70670d9e , 0x6459bd3e , 0xad6c5f19 , 0xf887d30d , 0xae5b446c , 0x04344016 ] )
+  , Mont::unsafe_make( [ 0xf9a8a47f , 0x933754e3 , 0x342ef1b4 , 0xd46af676 , 0xec6f6bb6 , 0x0e562d06 , 0xd7acce8d , 0x161ba271 ] )
+  , Mont::unsafe_make( [ 0x179af796 , 0xa672e8c1 , 0x34130e20 , 0xbc181dd1 , 0x990ffcc0 , 0x06e9dd70 , 0xd5c4b722 , 0x069b70f3 ] )
+  , Mont::unsafe_make( [ 0x72a120c0 , 0xd3fb3776 , 0xcedf9f7b , 0xc44b1da0 , 0xd77afb49 , 0x8d1e0ec8 , 0xbcd0e568 , 0x031fa98c ] )
+  , Mont::unsafe_make( [ 0x90f30fe1 , 0xda657673 , 0xe16054f7 , 0xc799487b , 0x4d177dfb , 0x96597c2d , 0x36d2f02c , 0x28a27949 ] )
+  , Mont::unsafe_make( [ 0x21e594f5 , 0xcb4f3bbf , 0xdc0dd253 , 0x77b9599a , 0x58eaff6a , 0xffd04103 , 0x106dcdfa , 0x051f5ae9 ] )
+  , Mont::unsafe_make( [ 0xbb350b5f , 0x20f0de80 , 0x6645f805 , 0xa9090f59 , 0x8a984adb , 0x5adb7034 , 0xd12618c6 , 0x188e74e1 ] )
+  , Mont::unsafe_make( [ 0xb49d198e , 0xb385ec18 , 0xdf825139 , 0x90950d63 , 0xf3fda6c6 , 0x5854aded , 0x3a0ce2bc , 0x2d357cb0 ] )
+  , Mont::unsafe_make( [ 0xf018d6f2 , 0x3fb48ce7 , 0x50da7b18 , 0xceaa35b3 , 0xbf2cfb90 , 0xaf982d74 , 0xe3a066c9 , 0x22f56b61 ] )
+  , Mont::unsafe_make( [ 0xf008cc9d , 0xc4633d6d , 0xd8b53710 , 0x6950b8ef , 0xc907fa3d , 0xb886c87a , 0x15bbdf06 , 0x1342dec7 ] )
+  , Mont::unsafe_make( [ 0x55cc1b20 , 0x79386618 , 0xabff2eea , 0xd3cd103f , 0x2fa62703 , 0xedd6d4d4 , 0x1d2f0efa , 0x060fa477 ] )
+  , Mont::unsafe_make( [ 0x6b18f38e , 0x601508e6 , 0x042f64b0 , 0x1b8628dd , 0x28f983cf , 0xf781df8b , 0x32bfb9fc , 0x07061ed2 ] )
+  , Mont::unsafe_make( [ 0x15381f73 , 0x1e7b0ca1 , 0x3a9701c8 , 0x155d95ec , 0xe06af5e0 , 0x1086d2d0 , 0xfd0e6907 , 0x11731c9e ] )
+  , Mont::unsafe_make( [ 0xed627090 , 0xc1fcc113 , 0xefdec995 , 0xd4ff94fe , 0x412b1d1b , 0x48fc1466 , 0xd677318e , 0x07b92bd8 ] )
+  , Mont::unsafe_make( [ 0x926fc3a7 , 0x81970194 , 0xf00bbb71 , 0xbdec9944 , 0xa3876c69 , 0xe4ae9704 , 0x6bb4c901 , 0x2b31eb6c ] )
+  , Mont::unsafe_make( [ 0x7107b70d , 0xf52c5e14 , 0xe90db45d , 0xb8e289f3 , 0x8ec3b431 , 0xeb07b658 , 0x4a99fbbd , 0x0fa08476 ] )
+  , Mont::unsafe_make( [ 0x8b650f7d , 0x35c8e29a , 0xe170b1c2 , 0xca00f0cb , 0x65446c96 , 0xb85980c6 , 0x33510d89 , 0x1b9f8516 ] )
+  , Mont::unsafe_make( [ 0xfcf9fbb7 , 0x603b2e1a , 0x1a4705fc , 0x6a9ed883 , 0x3f63f415 , 0x6b836c21 , 0xe3fccca0 , 0x2080b984 ] )
+  , Mont::unsafe_make( [ 0x95446f37 , 0x3be95770 , 0xb30ab69e , 0xc4495899 , 0xf65f3c4e , 0x7c38e87a , 0x9ea4bd74 , 0x287be675 ] )
+  , Mont::unsafe_make( [ 0x19bdbf36 , 0x2b1c46a7 , 0x1db0d189 , 0x6716bdb1 , 0xc913dc83 , 0xed970a63 , 0xbdc83bcd , 0x27548abe ] )
+  , Mont::unsafe_make( [ 0xfbe29e60 , 0x2e6108ae , 0x5b0ec64a , 0x684fed9e , 0x7b28cc57 , 0x0bc9db61 , 0x36a492b9 , 0x07c2e5b2 ] )
+  , Mont::unsafe_make( [ 0xa3be80de , 0x0f17af50 , 0x84d865e8 , 0xe37ae37d , 0x31762ef7 , 0x256783c9 , 0x0355e325 , 0x093807f3 ] )
+  , Mont::unsafe_make( [ 0x73b6e890 , 0x5a75bb56 , 0xeff56db7 , 0x665bf969 , 0xe3fb3e97 , 0xb198d21b , 0x20e68c18 , 0x12137e27 ] )
+  , Mont::unsafe_make( [ 0x3727ff2a , 0x51498ec5 , 0xccfb709d , 0xcb444aef , 0x922beb3d , 0xeaa452e1 , 0xae4887a9 , 0x1491c28c ] )
+  , Mont::unsafe_make( [ 0x3eb60d42 , 0xf55b8ea1 , 0xda2635d2 , 0xe951b90c , 0x192905ab , 0x87028b13 , 0x6a6474fe , 0x302f1987 ] )
+  , Mont::unsafe_make( [ 0xe54a4950 , 0x9de52574 , 0x44fe2c31 , 0xec537bcc , 0x9ae3748c , 0x8f975b32 , 0xfd66135d , 0x1dae4344 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x877bddb3 , 0xbf96c98f , 0x8fd04f12 , 0x3fef2c16 , 0x2f6ce665 , 0x91b42065 , 0x069b567c , 0x04c3a6f1 ] )
+  , Mont::unsafe_make( [ 0x3b118d65 , 0xb1eacb4a , 0x813c9c20 , 0x0964b01c , 0x713b4ef9 , 0x8c404e8d , 0x545ae6b2 , 0x0552411f ] )
+  , Mont::unsafe_make( [ 0xb7463eca , 0xc0394266 , 0xed8bfb3c , 0x81e92072 , 0x5b817488 , 0x80dc5f93 , 0x624d1a99 , 0x118308ab ] )
+  , Mont::unsafe_make( [ 0x398ee3cb , 0x4eed281a , 0x5dcaf66c , 0x8568b233 , 0xba557ba7 , 0x0cb5b9b3 , 0xe64fa17d , 0x05957ffd ] )
+  , Mont::unsafe_make( [ 0xfc169754 , 0x868b8f70 , 0xd517b5b9 , 0x0166aa03 , 0xdb4f1305 , 0xb5651f3f , 0xda9995c6 , 0x260422d2 ] )
+  , Mont::unsafe_make( [ 0xb6b02ed8 , 0x10239373 , 0x153b9ea9 , 0x5cf84e9f , 0xc2f8b0b8 , 0x25fc5d0f , 0xd9db4a1b , 0x0ecbb8ca ] )
+  , Mont::unsafe_make( [ 0xe0f79edc , 0x6a097817 , 0x053af026 , 0xe2189641 , 0x7665ccde , 0x84f5c9f1 , 0xb17c4bae , 0x2a582d41 ] )
+  , Mont::unsafe_make( [ 0x76dff17a , 0xbcd82e0e , 0xdda1dd34 , 0xda3bc486 , 0x846cc5aa , 0x0d19aaad , 0xffc8e440 , 0x2f69f031 ] )
+  , Mont::unsafe_make( [ 0x9a3cbe4e , 0xbf7ff97f , 0xbcc40cdd , 0xf3429cd3 , 0xcff93c05 , 0xde458004 , 0x0ffd94ba , 0x0d5002de ] )
+  , Mont::unsafe_make( [ 0x8d36cec1 , 0x71e11e4d , 0x2315f730 , 0xe5c6438d , 0x52504642 , 0x2251a992 , 0xd0045be2 , 0x2824c3a3 ] )
+  , Mont::unsafe_make( [ 0xcb86ab55 , 0x1df84a8b , 0x0a92afd9 , 0x4aa93788 , 0x5a545866 , 0x493b4d03 , 0x43cd72fa , 0x290433f0 ] )
+  , Mont::unsafe_make( [ 0x4fa927be , 0xe267d3b9 , 0x65d502a4 , 0x6cc6b040 , 0xbcdae557 , 0x0a4563b5 , 0xb1fef5c3 , 0x244082a1 ] )
+  , Mont::unsafe_make( [ 0xe7cf4505 , 0x66189882 , 0x3d5a06cf , 0xc02f052a , 0x5c478a3c , 0x6fff7041 , 0xb189eb09 , 0x0c579d2c ] )
+  , Mont::unsafe_make( [ 0x29be5330 , 0x1c0dbf84 , 0xbfeb57a2 , 0xadf0acd4 , 0x6cec8681 , 0x0dd65faf , 0x0f97698e , 0x12ab8b74 ] )
+  , Mont::unsafe_make( [ 0x486b20a3 , 0x2a98132c , 0x4e1859a4 , 0x541b73e5 , 0x1c9d2566 , 0x67c1ea63 , 0xdf733c31 , 0x011d22d0 ] )
+  , Mont::unsafe_make( [ 0x4c9199f9 , 0x8cb7101c , 0xb665f8d9 , 0x1e67480f , 0x28673911 , 0x34ae820e , 0x7bf6eabc , 0x0796fd49 ] )
+  , Mont::unsafe_make( [ 0xb8ce31bf , 0x8c515748 , 0x9dd3e3df , 0xa5309bb1 , 0xbab2f6f0 , 0x993f2219 , 0x9c868684 , 0x1a330958 ] )
+  , Mont::unsafe_make( [ 0x6e6f60a7 , 0x4328f023 , 0xca386257 , 0x8c477686 , 0xa4c41cb2 , 0xbd50cc33 , 0xc4531a60 , 0x1fd9d6f5 ] )
+  , Mont::unsafe_make( [ 0xa0ba432a , 0xc42b4b1a , 0xe89572c2 , 0x1c4f4aeb , 0xd8c3da4b , 0xb7fd1f1b , 0x4281fe8d , 0x0474d4ae ] )
+  , Mont::unsafe_make( [ 0x9c5b400b , 0x04d4ebf6 , 0x24d90359 , 0x6d4b24f1 , 0x87aa3f47 , 0xfcf048d9 , 0x8c8680b9 , 0x01a85335 ] )
+  , Mont::unsafe_make( [ 0x3fc1bc44 , 0x0f70c7ad , 0xc85c825b , 0x056442ac , 0xff4814c0 , 0x2211cc30 , 0x891ed84c , 0x15d0e257 ] )
+  , Mont::unsafe_make( [ 0x11737d96 , 0xcd7db790 , 0x2af5fc4a , 0xa7ee12df , 0xb12283d5 , 0xd473af1d , 0x585e4eab , 0x305e4e06 ] )
+  , Mont::unsafe_make( [ 0x9853c13f , 0x47d86693 , 0xd2e8d847 , 0x1e19cf9e , 0xb791a7f3 , 0x5835fb9f , 0x9b54e2c1 , 0x10b8d4c4 ] )
+  , Mont::unsafe_make( [ 0x13a2ec42 , 0x8f7c3445 , 0x4d9379a1 , 0xe3fdc01f , 0xb9ac3cf6 , 0x254b3382 , 0x186c47cb , 0x1a2622a2 ] )
+  , Mont::unsafe_make( [ 0xe68a7cdc , 0xeefe2b5c , 0x6568791e , 0x66404fd2 , 0x72ef234d , 0x43f68682 , 0x0c495128 , 0x29ebc3fe ] )
+  , Mont::unsafe_make( [ 0x498eb695 , 0x947545b4 , 0x4a4e14b3 , 0x61cb5e7f , 0xf6b324cd , 0x06eee501 , 0xbcf1fe32 , 0x1d58364b ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x6a34cd10 , 0x29558175 , 0x1d320f13 , 0xa884a437 , 0x980dcc13 , 0x17c38522 , 0x7e8de1aa , 0x182e6bb8 ] )
+  , Mont::unsafe_make( [ 0x563cd43d , 0x34f277b9 , 0xfee86219 , 0xff7cacdb , 0xe0bd8bbc , 0xbb8545b5 , 0x6979ddc8 , 0x24abd3c4 ] )
+  , Mont::unsafe_make( [ 0xecd28e39 , 0x48c1f57e , 0x82cabf60 , 0x311a24f2 , 0x2dd5fe83 , 0xf66dd664 , 0xa1c4a976 , 0x0b8d5d76 ] )
+  , Mont::unsafe_make( [ 0x0ce26d69 , 0xc9a35be2 , 0xd72a3b91 , 0x006f5c1b , 0x8e11189f , 0x9c501db8 , 0x0c8b301b , 0x275655b8 ] )
+  , Mont::unsafe_make( [ 0x01478c76 , 0xa66521ee , 0xc04ee3ad , 0x38e4bc84 , 0x849d52e4 , 0x5200d517 , 0x567a464a , 0x0d81c73c ] )
+  , Mont::unsafe_make( [ 0x81e8a20c , 0xa0d754f0 , 0x0826e908 , 0x1d82b1c5 , 0x84e615dc , 0xad9636d1 , 0x544e76eb , 0x190bcabc ] )
+  , Mont::unsafe_make( [ 0x4155b7c9 , 0x0034893c , 0xb1569982 , 0x4a035013 , 0x0b3c418e , 0xedb85c5f , 0xe2c9bfb6 , 0x246d768e ] )
+  , Mont::unsafe_make( [ 0xc14746c1 , 0x7fda7670 , 0x3927d96b , 0xe22b5ec8 , 0xfb895bb1 , 0x8e2850a8 , 0x94eb3847 , 0x21bf4777 ] )
+  , Mont::unsafe_make( [ 0xb7faefdf , 0x7277e6e7 , 0x427bafea , 0x211f529a , 0x78509d11 , 0xa38ecb8b , 0xd6a44f2d , 0x0d2a4046 ] )
+  , Mont::unsafe_make( [ 0x267ae48d , 0x96ac81dd , 0x0dce335a , 0x172f7b87 , 0xbfc04fbb , 0x57f12992 , 0xa31586d6 , 0x019d0d3f ] )
+  , Mont::unsafe_make( [ 0xc2dd20a3 , 0x15ded687 , 0xc1f87b5f , 0x298b0994 , 0xc0ee65db , 0x5e13b4b5 , 0xca12bc19 , 0x29f49ef4 ] )
+  , Mont::unsafe_make( [ 0x6e8b1e03 , 0x7115fb43 , 0x56baf682 , 0xbb24e969 , 0x0efc0b9b , 0x7d5cc8af , 0xbae24aca , 0x26f44ea2 ] )
+  , Mont::unsafe_make( [ 0x7bd47976 , 0x45e65b2c , 0xd5047aaf , 0xcb7209d3 , 0xa1508de2 , 0x2cb0c8bc , 0x1b8f9d02 , 0x2825cfd6 ] )
+  , Mont::unsafe_make( [ 0x4ca07c97 , 0x52ef6897 , 0x96610a1f , 0x4d5b2e6e , 0xd9a95928 , 0x3aa263a1 , 0x1887694c , 0x21d43d63 ] )
+  , Mont::unsafe_make( [ 0x954475dd , 0x74ef7dcb , 0xa6de707c , 0xa88ccf3c , 0xd0d5aa56 , 0x07b218e3 , 0x247e4743 , 0x257a927b ] )
+  , Mont::unsafe_make( [ 0x4aa7e6a6 , 0x4cc9c4cb , 0x9b3bc031 , 0x605689d6 , 0x7c4d6b3a , 0x345713f7 , 0x1c77c430 , 0x1d27fbe9 ] )
+  , Mont::unsafe_make( [ 0x6a2f5df0 , 0x84d9430c , 0x3548c62a , 0x243a8d71 , 0x2d2dc8de , 0x7a1d465c , 0x2b8d0c91 , 0x0472d2bc ] )
+  , Mont::unsafe_make( [ 0x6709cd8a , 0x7b48ab21 , 0x37537e76 , 0x506a1b60 , 0x79a276e2 , 0xf8f0cabd , 0x4e7c720e , 0x0e02e521 ] )
+  , Mont::unsafe_make( [ 0xfd17ac43 , 0xedca91fa , 0x203ca0b9 , 0x068bc47b , 0x7ab65e37 , 0x7f279cdb , 0x348547a0 , 0x2b01d9c5 ] )
+  , Mont::unsafe_make( [ 0xb844eda1 , 0xa185abbe , 0xdf0ab13d , 0x03c6c148 , 0xad46902f , 0x313a107b , 0xa1b44d05 , 0x1ab488ed ] )
+  , Mont::unsafe_make( [ 0x040a7856 , 0x1dbca4ae , 0x0ed410f6 , 0xd36c03b7 , 0x1e9c5bbb , 0x0b45f776 , 0xba6dc174 , 0x0a0e78a1 ] )
+  , Mont::unsafe_make( [ 0xc6c27a75 , 0x6d86bd5b , 0x56a9695d , 0x2ac40480 , 0x3c7e5b82 , 0x33a49fda , 0x94e4b41a , 0x04088756 ] )
+  , Mont::unsafe_make( [ 0x42cef665 , 0x347d3478 , 0xc8959dc0 , 0xdd42062b , 0x895c51f0 , 0x262140b9 , 0xb9ea072d , 0x08fdf5e0 ] )
+  , Mont::unsafe_make( [ 0x605dd436 , 0xfe6534d4 , 0x9bcf069d , 0x935595b7 , 0xea942456 , 0xdf4cacc2 , 0x105fa742 , 0x20754e29 ] )
+  , Mont::unsafe_make( [ 0x5dd5e2e5 , 0x889c88ce , 0xe014586c , 0xa0d35f3f , 0xef7fc2c7 , 0x52519a67 , 0xa5d9585c , 0x24339566 ] )
+  , Mont::unsafe_make( [ 0xfa472dda , 0xc82294d4 , 0xd2e7193c , 0x1eab92cc , 0x6f611bb5 , 0xed73e174 , 0x0e9bc74a , 0x2a5502f6 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xd8032ceb , 0x8959ade0 , 0x740a0e94 , 0xe82a02a8 , 0xce9346ff , 0x2ba0487d , 0xe1e9dc25 , 0x12b71961 ] )
+  , Mont::unsafe_make( [ 0x59609b72 , 0xcc7e8b5a , 0x80a48f20 , 0x979a7b25 , 0x62daf23f , 0xb8500b3d , 0x6c15dcd7 , 0x048cc051 ] )
+  , Mont::unsafe_make( [ 0x615a074d , 0x74c8f6bf , 0x38fa39bc , 0x25036e12 , 0x613ca7d1 , 0x71132b1d , 0xcd243e8c , 0x215ff8f9 ] )
+  , Mont::unsafe_make( [ 0xbf8a159d , 0x94f1d13f , 0xb3968484 , 0x94487d3a , 0x130ae9e0 , 0x178700e1 , 0x779e2dc0 , 0x01abdf79 ] )
+  , Mont::unsafe_make( [ 0xb3e1f4ed , 0xc078a0b4 , 0x86386d8a , 0x660e7da0 , 0xc4569406 , 0xf7d024b9 , 0x4105455b , 0x2dd457d9 ] )
+  , Mont::unsafe_make( [ 0x96e67521 , 0xbf22bbb3 , 0x6f5c45c9 , 0xd8368c21 , 0x5d6782cb , 0x788d759a , 0x6c566515 , 0x0771b1c7 ] )
+  , Mont::unsafe_make( [ 0xf0f98648 , 0x69fb934e , 0xa3f878a7 , 0x50b6f52d , 0x76c615d3 , 0x052353b6 , 0x73277c2f , 0x262738f9 ] )
+  , Mont::unsafe_make( [ 0x8f27f64b , 0x561433b0 , 0xe2f638f3 , 0x06145e04 , 0x3bf89049 , 0xfc20d96b , 0x6c7a9e2f , 0x16b6edc4 ] )
+  , Mont::unsafe_make( [ 0xdfab8075 , 0xb5d27488 , 0xb1034d86 , 0xf49d61fa , 0xd235ce57 , 0xc92de42b , 0x346afc03 , 0x02ebc4bb ] )
+  , Mont::unsafe_make( [ 0x16ff9051 , 0x9506c381 , 0x5190a694 , 0x1c740923 , 0xd26c0d2e , 0x0fe3ab5c , 0x060ea566 , 0x0bf4eadc ] )
+  , Mont::unsafe_make( [ 0x5ae9c338 , 0x25b53648 , 0x2cc0dbbc , 0x4534054b , 0x79dc3e22 , 0xb76b5448 , 0x3e93b56c , 0x0ac7006c ] )
+  , Mont::unsafe_make( [ 0x82598312 , 0x378ed29d , 0x7c73f321 , 0xbecc5287 , 0x03b3b320 , 0x2e426d6f , 0x628fd6ec , 0x1eaf94c8 ] )
+  , Mont::unsafe_make( [ 0xea454566 , 0x59b05d43 , 0xb1296839 , 0xbb51fc96 , 0xe20b8858 , 0x82808cb1 , 0x76c37151 , 0x0770e7e6 ] )
+  , Mont::unsafe_make( [ 0x32d745a2 , 0x42952e23 , 0xbb5aea05 , 0xd37a6a51 , 0x4d25d758 , 0xe8b40c51 , 0x499b7d5b , 0x293b35ab ] )
+  , Mont::unsafe_make( [ 0x9a68ed9c , 0xcb90b081 , 0x407644be , 0xf790e0d2 , 0xc8633ba2 , 0x351068fa , 0x8f412857 , 0x18472dd4 ] )
+  , Mont::unsafe_make( [ 0x6d79e27a , 0x6748bdce , 0x8b5342ba , 0xcaafb408 , 0x1e71779c , 0x02b16cdf , 0x34555cbb , 0x0b83e142 ] )
+  , Mont::unsafe_make( [ 0xd0ee96f0 , 0xc16ea8db , 0x315d6ce6 , 0x7a39f39c , 0x3593191d , 0xc95c61d1 , 0x5ced2a1d , 0x1243a4ea ] )
+  , Mont::unsafe_make( [ 0x24af00b5 , 0x7179f967 , 0xe46cc712 , 0xf2947f36 , 0x9c778b66 , 0x9b711b25 , 0xb027d4d1 , 0x10af4a85 ] )
+  , Mont::unsafe_make( [ 0x1742035b , 0x41c2934f , 0xaad5594e , 0x9d5f7b90 , 0xae259ff7 , 0x794ec548 , 0x0dd8b640 , 0x19cbbd69 ] )
+  , Mont::unsafe_make( [ 0x3ad0bc95 , 0xb23258b7 , 0xd2f8e7ea , 0xe6d4e9d3 , 0x393aa9ce , 0x91ba74ca , 0xa1aa8ef6 , 0x13bfe43f ] )
+  , Mont::unsafe_make( [ 0xc389b152 , 0xa2f760e4 , 0x7da18414 , 0xb587c707 , 0x434dfaa2 , 0xa8565ffa , 0x60e36c2a , 0x12e71de2 ] )
+  , Mont::unsafe_make( [ 0x7d1f8e91 , 0x67912b18 , 0xcee5be75 , 0x4d982a42 , 0xae5d0f5d , 0xa63c3648 , 0x8640c651 , 0x0c42f039 ] )
+  , Mont::unsafe_make( [ 0xebf122a7 , 0x710d6a82 , 0x14638563 , 0x88e8fe1a , 0x9062861d , 0xc1f11282 , 0x4efcfe30 , 0x1120fa1c ] )
+  , Mont::unsafe_make( [ 0x50910e3a , 0xc75bbd5a , 0x8a45ccde , 0xa3e9a86a , 0x9e452419 , 0x8ccd8bc1 , 0x190c7d42 , 0x12199f34 ] )
+  , Mont::unsafe_make( [ 0x7ed76db0 , 0xa80ce740 , 0x00b1439a , 0xe2663718 , 0xad159ad2 , 0xeaafc139 , 0x731de5fe , 0x120bedb6 ] )
+  , Mont::unsafe_make( [ 0x95bd42e6 , 0x23171ad7 , 0x39092f00 , 0xbd5999b2 , 0xead83d43 , 0x371733c5 , 0x6234c7da , 0x265dd812 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xe6a53649 , 0x538b7c95 , 0xc5e94898 , 0xd7591bc2 , 0x0f1dd761 , 0x5e58badb , 0xb87e78ec , 0x2810a53f ] )
+  , Mont::unsafe_make( [ 0x5df9f996 , 0x4b026d73 , 0x29df13e7 , 0xbee4c4f1 , 0x4b09d401 , 0xb1a650ae , 0x98539242 , 0x2a19b939 ] )
+  , Mont::unsafe_make( [ 0xb22e9bc2 , 0xcd05fa01 , 0x9f860152 , 0x4d6d5a37 , 0xed4b4bbf , 0xe7f39b46 , 0x75d88ac9 , 0x16436721 ] )
+  , Mont::unsafe_make( [ 0x764eab12 , 0x8bf7081f , 0x3ef75db3 , 0xe40fb33d , 0x82a05367 , 0xff0a2b30 , 0x007fd281 , 0x142ffff1 ] )
+  , Mont::unsafe_make( [ 0xbd70981e , 0xd2893d51 , 0x1a5c3ece , 0xca658195 , 0xd4ef74be , 0x48b6b80b , 0xf35b84e9 , 0x14c0a741 ] )
+  , Mont::unsafe_make( [ 0x8398262c , 0xbe852991 , 0x073f7253 , 0xba8c750b , 0x9352ce92 , 0xefb9f79b , 0xfc588c50 , 0x0c5563ba ] )
+  , Mont::unsafe_make( [ 0x14145e43 , 0x117f782a , 0xdd26992e , 0xa6329ff7 , 0x66f2da69 , 0x0cb082d7 , 0x3823ce32 , 0x02d6ec51 ] )
+  , Mont::unsafe_make( [ 0x88855a8a , 0x7e4f7064 , 0xfa00688a , 0x3ee94fb6 , 0xc26ec744 , 0xd3c558ec , 0x256651aa , 0x2f6df737 ] )
+  , Mont::unsafe_make( [ 0x2214960b , 0x28279fd1 , 0x3cd5da1e , 0x8be45784 , 0x20868679 , 0x3959e6a4 , 0x85c75b37 , 0x20781170 ] )
+  , Mont::unsafe_make( [ 0x2ffbfa75 , 0x543ec5ff , 0x414c03eb , 0x7e0c21e5 , 0x31627d37 , 0x3437d9a4 , 0x4ba94c8a , 0x10888c4c ] )
+  , Mont::unsafe_make( [ 0x7abcbdd7 , 0xe7e60e2a , 0x82dce467 , 0x515dcd8b , 0x2869d1a4 , 0x6039532b , 0xc8ca581e , 0x00e2d28b ] )
+  , Mont::unsafe_make( [ 0x494890fc , 0x27bdbba6 , 0x3db60a54 , 0x648175b4 , 0x82ddc020 , 0x2401f160 , 0x99ddc47e , 0x1dff2cd4 ] )
+  , Mont::unsafe_make( [ 0xa0365bc5 , 0xe66ef01c , 0xc8d6fa42 , 0xcaa8a4f8 , 0x0e3cf73d , 0x7c3d35ec , 0xf96aa302 , 0x2bc19c01 ] )
+  , Mont::unsafe_make( [ 0xca3e38c7 , 0x38e79cca , 0x72d51378 , 0xea2ab0b6 , 0x959eac31 , 0x6af831c2 , 0x59303616 , 0x283e368a ] )
+  , Mont::unsafe_make( [ 0x3ff7244c , 0xd26c339b , 0x68d2ac01 , 0x12088578 , 0xcf27d805 , 0xc2f5de9e , 0xa61baf08 , 0x0627d29f ] )
+  , Mont::unsafe_make( [ 0x6fe27851 , 0x2490d1e1 , 0x405d1e07 , 0x0c2f4aaa , 0xfd8b4abf , 0x88ae90ab , 0x8431173b , 0x238249ed ] )
+  , Mont::unsafe_make( [ 0xd964d3f3 , 0x378bfa41 , 0x3a169f2e , 0x14b533d6 , 0x6f1f9dbd , 0x1f6a203b , 0x12257db8 , 0x0be04d86 ] )
+  , Mont::unsafe_make( [ 0x80eb9814 , 0xe2ab474a , 0x1fb55540 , 0x70849fae , 0xb8daa6a5 , 0x040f6797 , 0x0decdf3c , 0x19423b5a ] )
+  , Mont::unsafe_make( [ 0xae91752a , 0x5e04e59c , 0x6ca17324 , 0x7902f273 , 0x57d1605a , 0x190aa217 , 0x50bf3395 , 0x18582a0e ] )
+  , Mont::unsafe_make( [ 0xb983f6dd , 0xacc5e9ec , 0x46ba6615 , 0x7243bee6 , 0x90a60560 , 0x2b9f835e , 0x38452c48 , 0x25ba1e64 ] )
+  , Mont::unsafe_make( [ 0x09e1bd90 , 0xc56620b4 , 0xb2e64c63 , 0x5386b0b0 , 0x1c596ef0 , 0xad16a342 , 0x1ead4c5c , 0x0e3a88cc ] )
+  , Mont::unsafe_make( [ 0xf4244e02 , 0x300357ae , 0x4b00128b , 0xa8eeb870 , 0x89ca59bf , 0xa737807b , 0x60c35507 , 0x25c9e4c1 ] )
+  , Mont::unsafe_make( [ 0x938d3c94 , 0x116284e0 , 0xf5ac13f6 , 0xf036cf59 , 0x37e72bff , 0x73875b21 , 0xf76f1e45 , 0x1148b485 ] )
+  , Mont::unsafe_make( [ 0x488bc2f7 , 0x67eb3e82 , 0xf71f4e84 , 0xba7de3de , 0xbaed2a00 , 0x2169c25a , 0xe1f71845 , 0x2198356c ] )
+  , Mont::unsafe_make( [ 0xd954d87b , 0x1fd74e63 , 0xf41674ac , 0x8fa7c29d , 0x3427740b , 0xd2257708 , 0xdd8d289f , 0x15b6b9df ] )
+  , Mont::unsafe_make( [ 0x2a2c8818 , 0xbdee3504 , 0xb8976ec0 , 0x0b1db9fb , 0x1d06579e , 0x056967e2 , 0xdfb4261c , 0x2a1750b7 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x6c457d4f , 0x180d19b2 , 0x492f5ca7 , 0x1a395299 , 0x2079bfed , 0x2063deb6 , 0xbec7bec1 , 0x00debccd ] )
+  , Mont::unsafe_make( [ 0xce8aa4e4 , 0x539996e9 , 0x7e0d7790 , 0xd4332c5d , 0xf11cc1ca , 0xd9149d74 , 0xdba376b3 , 0x2a070d8b ] )
+  , Mont::unsafe_make( [ 0x009200db , 0x62a5de10 , 0x966d1394 , 0x88418bac , 0x4699f133 , 0xa1e14945 , 0xeca7f2cd , 0x15e0a690 ] )
+  , Mont::unsafe_make( [ 0x65f869d2 , 0xd4fbb9f6 , 0x4f4e9455 , 0x48575103 , 0xcb62b342 , 0x44dbad82 , 0x43763947 , 0x24aa3390 ] )
+  , Mont::unsafe_make( [ 0x79b6e5b4 , 0x77a7136f , 0x7854c7c1 , 0xf8bb1d3e , 0xf37441a5 , 0x317ee161 , 0x8e1ed09c , 0x2c44075d ] )
+  , Mont::unsafe_make( [ 0x9eb80dfa , 0x8a2618ed , 0xc50e50aa , 0x40d29a8c , 0x16478911 , 0x4c232c2e , 0x901390ea , 0x05bf1090 ] )
+  , Mont::unsafe_make( [ 0x7d7b6e04 , 0x2624484a , 0x82bfe08d , 0xfdf7924b , 0xe73bb9f4 , 0xf3c5e9a6 , 0xbfd209f3 , 0x0116bb10 ] )
+  , Mont::unsafe_make( [ 0x28442f4d , 0xb50bb821 , 0xabe6260e , 0x90f73351 , 0xc3a687bf , 0xd034fea2 , 0xa6886d96 , 0x072bed8a ] )
+  , Mont::unsafe_make( [ 0x37dbd218 , 0x9ccb5682 , 0x6b7800e8 , 0x8b4d308e , 0x50131f3a , 0x0ba18127 , 0x99bc2cf5 , 0x0eac2679 ] )
+  , Mont::unsafe_make( [ 0x4c64bdd5 , 0x412cbad4 , 0x6760b357 , 0x97a6bcb3 , 0x59a4dd80 , 0xd8d3f9df , 0xbc5f5b9f , 0x08e70af8 ] )
+  , Mont::unsafe_make( [ 0x8a7592a6 , 0x30ea9614 , 0x04d2416d , 0x09133fe9 , 0x19f2b61d , 0xbe867a9d , 0x71599cb6 , 0x10226382 ] )
+  , Mont::unsafe_make( [ 0x2b2bbd2a , 0xf7205038 , 0x25933dc3 , 0xd39d15de , 0xb4a184f0 , 0x3d73bdc2 , 0xc2917e93 , 0x0c0b3c7f ] )
+  , Mont::unsafe_make( [ 0xcbae5eec , 0x2b38425d , 0x79427f35 , 0x9ca263ba , 0xa667adf5 , 0x316f1473 , 0x209b6162 , 0x23322bf4 ] )
+  , Mont::unsafe_make( [ 0x78066c40 , 0x158043eb , 0x6e1c35d9 , 0x1aa67f59 , 0x45391b72 , 0x80f98bf3 , 0x379b993b , 0x01c29379 ] )
+  , Mont::unsafe_make( [ 0xfa40c8e3 , 0xf5d0e6a0 , 0x2fb3a49b , 0x1a873d0f , 0x004572b6 , 0xdad88423 , 0xb1a3dc8b , 0x15efbef9 ] )
+  , Mont::unsafe_make( [ 0xf3702b83 , 0x4ea1a5cf , 0x0250d309 , 0x5083033a , 0x3e17e029 , 0x2c043f40 , 0x3a65195d , 0x0ae7e85f ] )
+  , Mont::unsafe_make( [ 0x612f9065 , 0x0e364480 , 0xafa49c6a , 0xe7682c95 , 0xdf757c02 , 0x8e383c05 , 0xddc0244a , 0x06b3debf ] )
+  , Mont::unsafe_make( [ 0xe5525ecf , 0xc693b440 , 0x76263be8 , 0x9477145c , 0xdeeed6eb , 0xe6d55e01 , 0x3df0245c , 0x033de78c ] )
+  , Mont::unsafe_make( [ 0x974b5ee3 , 0x31186df7 , 0x04e93923 , 0x5b836c9f , 0x9b68850b , 0x4b9d5e2d , 0x9dfffd5f , 0x1076909e ] )
+  , Mont::unsafe_make( [ 0xc6a83a76 , 0xb0347edc , 0x13a8f1a3 , 0x27a50f57 , 0x07143907 , 0x6c05739c , 0xb2a25662 , 0x10f86858 ] )
+  , Mont::unsafe_make( [ 0x4c4090ab , 0x69cd978d , 0x8a910c33 , 0xb8886b95 , 0x2d5585a8 , 0x9a4d078e , 0xc38592d3 , 0x111e04b9 ] )
+  , Mont::unsafe_make( [ 0xe0f3fc8b , 0x059a28c3 , 0x140f535f , 0xa469df25 , 0x6a48f91a , 0xed47a1d6 , 0x24e804de , 0x2ceae2b5 ] )
+  , Mont::unsafe_make( [ 0xa6d87b50 , 0x956f4b27 , 0x3b9967b7 , 0x3343ce89 , 0x79a4a8b5 , 0xf1a3137d , 0x53e6733c , 0x29d86c14 ] )
+  , Mont::unsafe_make( [ 0x414ebff3 , 0x4e75730a , 0xbf5b043b , 0x35b7b9a7 , 0xa676a5f1 , 0xea44bdc7 , 0x0cded642 , 0x12d541ea ] )
+  , Mont::unsafe_make( [ 0x291d8b10 , 0x25c47618 , 0x1633e29e , 0x55ba440c , 0xa6b4918e , 0xf0f164c9 , 0xd8a12f5d , 0x10d8fa5e ] )
+  , Mont::unsafe_make( [ 0xc96e4328 , 0x443faf67 , 0x62f57f3e , 0x786abb5d , 0xa82a0acc , 0x6e4b5978 , 0x68b5ba09 , 0x192337e6 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x3009c5f0 , 0x6301fea6 , 0xb980b64a , 0x7210280d , 0x1a006e3c , 0x6bdcbebc , 0x3883c399 , 0x1c87b645 ] )
+  , Mont::unsafe_make( [ 0x4f77e2bb , 0xd7cf86c7 , 0x533e35db , 0x4e8df2bb , 0xe4203eb4 , 0xc1731eff , 0x139a475c , 0x160062ca ] )
+  , Mont::unsafe_make( [ 0x1a592e4b , 0xdb6d76d4 , 0x2ab314eb , 0x94ff4f92 , 0x5ee4c8f7 , 0x1e28ef26 , 0x64b12a23 , 0x2821b0f7 ] )
+  , Mont::unsafe_make( [ 0xa9b47ea6 , 0xcd244654 , 0xf7fce047 , 0x5987565b , 0x7525f8cd , 0xcb6fc200 , 0x593a6453 , 0x1d3cfff5 ] )
+  , Mont::unsafe_make( [ 0x390062a3 , 0x312a71f3 , 0x75ccabd1 , 0x7027477c , 0x2b25cabf , 0x38b5903b , 0x66926d1e , 0x0def09d9 ] )
+  , Mont::unsafe_make( [ 0xeffd7ee8 , 0x8de831b8 , 0x539fb560 , 0x391984c8 , 0x97f9eeb8 , 0xe0ec277e , 0x43bbcfd7 , 0x20bc6b59 ] )
+  , Mont::unsafe_make( [ 0x73d78026 , 0xf6dd4362 , 0xeb5ac58d , 0x1fc1da82 , 0x48c7243a , 0x666910a6 , 0x86269d1b , 0x05f1a0fc ] )
+  , Mont::unsafe_make( [ 0x1fb41430 , 0x7128599a , 0xa3673f0e , 0xc375a681 , 0xcf4c9e2a , 0x067b5c64 , 0x92579f76 , 0x223d2b46 ] )
+  , Mont::unsafe_make( [ 0x6174756e , 0xe860b82c , 0x22b4c06c , 0xc9502c98 , 0x67ba7389 , 0xa45a2c1c , 0x67a03298 , 0x245f55fa ] )
+  , Mont::unsafe_make( [ 0x90a3a8a9 , 0xcccbc7e8 , 0xba6ef978 , 0x664835b0 , 0x16ff0afd , 0x27dd3f1c , 0xdb1476d5 , 0x1f6c7c56 ] )
+  , Mont::unsafe_make( [ 0x3f88c11b , 0x09b38b56 , 0x5dce7657 , 0x9cf74fb9 , 0xdc616963 , 0x26f9e14e , 0xfcb16872 , 0x29034827 ] )
+  , Mont::unsafe_make( [ 0xf81b6164 , 0x42862377 , 0x75b7a45f , 0x9d25aa6f , 0x8ab5da09 , 0xba9300a3 , 0x19243cd2 , 0x13d44f55 ] )
+  , Mont::unsafe_make( [ 0x2bd994c7 , 0x84366b2a , 0x1446e253 , 0x43f20e2f , 0x6d128a10 , 0xbeb11dcb , 0xfd5be6c6 , 0x2b37913f ] )
+  , Mont::unsafe_make( [ 0x04489854 , 0xfe6bc00b , 0xe352ff9f , 0x4bc2ff08 , 0xc5676404 , 0xdd336b4f , 0x2d90f15e , 0x2f4e14ef ] )
+  , Mont::unsafe_make( [ 0xe9be2f08 , 0xbf7d4bd6 , 0x086146b1 , 0x58e42569 , 0xeff938db , 0x036d107e , 0x571dc524 , 0x234e3d82 ] )
+  , Mont::unsafe_make( [ 0xe82b4149 , 0x5caea47c , 0x33d80c40 , 0xfb6992f9 , 0xa1517212 , 0x1207b921 , 0x8b0e11a8 , 0x0fb2260a ] )
+  , Mont::unsafe_make( [ 0x667fef69 , 0x53cee632 , 0x4fa8cb91 , 0x06f6e81c , 0x19aadbbd , 0x2544fa76 , 0xd99af474 , 0x29dd3b65 ] )
+  , Mont::unsafe_make( [ 0xc3ad4b8a , 0x3f77a60d , 0xe4fdccdf , 0x35bea5c2 , 0x3b5c3567 , 0xef5417cd , 0x53d11f04 , 0x1d5842c5 ] )
+  , Mont::unsafe_make( [ 0x79e15e03 , 0x3d9cdddf , 0x9feee8e4 , 0x91bd80af , 0xed85941e , 0x21cb7f7d , 0x030e2134 , 0x2d41d179 ] )
+  , Mont::unsafe_make( [ 0x6ad871b9 , 0x347e985c , 0x38ac09bf , 0x5937977f , 0xee5fcc33 , 0x67166a3c , 0x64907fa9 , 0x20113c2d ] )
+  , Mont::unsafe_make( [ 0x1a5d69b5 , 0x36bb81e8 , 0x675d40fa , 0x2170af63 , 0xa30ec9d8 , 0x4e361a7e , 0xcff164bd , 0x1df61dbf ] )
+  , Mont::unsafe_make( [ 0xe46378e9 , 0xfd4ac248 , 0x66642f59 , 0xcbb9409f , 0x9e488ff2 , 0x57f900d0 , 0x19ffb0d1 , 0x1d92fdde ] )
+  , Mont::unsafe_make( [ 0x8c8e9f8f , 0x52fbcee2 , 0x81618dd3 , 0x2678a622 , 0x336fc298 , 0xbe2ba4ec , 0xf9505810 , 0x2ee66f1c ] )
+  , Mont::unsafe_make( [ 0xb317953d , 0x955a4086 , 0xc6a73c34 , 0x63a7a5b9 , 0xa19ac570 , 0x14133500 , 0x206944a3 , 0x28c01454 ] )
+  , Mont::unsafe_make( [ 0x6f36ba93 , 0x3e94d0ee , 0x03627f48 , 0xa00d13c9 , 0x73f06155 , 0xdfff41ef , 0x265624e8 , 0x2c3ebf1d ] )
+  , Mont::unsafe_make( [ 0x02104a94 , 0xfff53ebe , 0xf8417c96 , 0xe0fccaa1 , 0xf8ce5b4e , 0x017aafca , 0x4fe762c6 , 0x090baf4f ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x3c161a4b , 0x029e5c1f , 0x04485720 , 0xea506487 , 0xf97c2397 , 0xa98bbdab , 0x9030b71c , 0x023478d8 ] )
+  , Mont::unsafe_make( [ 0xe9949e75 , 0xa935e0ca , 0x49576378 , 0x5868ba73 , 0xa2e2d116 , 0x7c5b126c , 0x6870b3b8 , 0x0a841c57 ] )
+  , Mont::unsafe_make( [ 0x60b8fa5d , 0xbd6e8f42 , 0x8a1b1387 , 0xb5a2459e , 0x1571a0d3 , 0x7891b60c , 0x7935bfdb , 0x1ca071a7 ] )
+  , Mont::unsafe_make( [ 0x75978820 , 0x204fed0e , 0x1e96d538 , 0xbfe15e0e , 0xa2e85a95 , 0xb6b527a3 , 0xa3cfdbe6 , 0x2f2d6f4d ] )
+  , Mont::unsafe_make( [ 0x8c9961d3 , 0xa01beaa2 , 0xd11761a9 , 0xc92b3368 , 0x3f1b0596 , 0xda50c4a1 , 0x4798ce1f , 0x13e752ac ] )
+  , Mont::unsafe_make( [ 0xaa3f0c72 , 0x57820c0b , 0x958df24e , 0x35df3fca , 0x095f987f , 0x350bf7d3 , 0xc2f9eda0 , 0x09122ff7 ] )
+  , Mont::unsafe_make( [ 0xac32c882 , 0xdfbcbc48 , 0xc2ddc174 , 0x7b73d509 , 0x52ccb915 , 0x9d3a6b0f , 0x900a6067 , 0x2b4ac824 ] )
+  , Mont::unsafe_make( [ 0x6d972d9c , 0xd83b0cec , 0x5807b99c , 0xb65e3172 , 0x7d769b99 , 0x18943327 , 0x1df8d536 , 0x10c3a2bb ] )
+  , Mont::unsafe_make( [ 0x8c9a1d92 , 0x9a648d59 , 0x50cf29be , 0x8905a63a , 0x5b685161 , 0xc944eeac , 0x98d76eae , 0x1071ba34 ] )
+  , Mont::unsafe_make( [ 0x8db61a16 , 0x16d0518c , 0xfa42621b , 0x10a47553 , 0x7f7cb0f1 , 0x4e3ea44e , 0x48a03c88 , 0x12c3b2a7 ] )
+  , Mont::unsafe_make( [ 0x88ba7a5d , 0xce7f2682 , 0x9f8c137a , 0xc07ae386 , 0x0fbf4c68 , 0xa9318c77 , 0x4242b046 , 0x020faf72 ] )
+  , Mont::unsafe_make( [ 0x78350d77 , 0x8d1fcca1 , 0xcb070507 , 0x113adecb , 0x0eb2da1f , 0x390c605e , 0x099976f3 , 0x07069e0e ] )
+  , Mont::unsafe_make( [ 0x53138e74 , 0x40c26df6 , 0x8d32c0de , 0x192cb422 , 0x9daff31a , 0x83993248 , 0x65fe52e1 , 0x22b640e0 ] )
+  , Mont::unsafe_make( [ 0xd39ea49d , 0xcef8d95f , 0x92d2fcab , 0xc3114e2d , 0x5f71298d , 0xf9d69cf8 , 0x7b09e91c , 0x1c0ce957 ] )
+  , Mont::unsafe_make( [ 0x9a797345 , 0x369f5d7e , 0xd2c9d7c8 , 0x29949148 , 0x4d3f4b11 , 0xd539eb42 , 0xc2f61de1 , 0x1771161d ] )
+  , Mont::unsafe_make( [ 0xc6be8c20 , 0x408d0cd1 , 0x6394a532 , 0x3ebfc22b , 0x60539a3c , 0xe99c7c3a , 0xa82e117c , 0x18222d94 ] )
+  , Mont::unsafe_make( [ 0xb7647ae0 , 0x67050b61 , 0xd6af4677 , 0x28dcb125 , 0x0495fccf , 0x66023f1d , 0xaca26f54 , 0x0955edbb ] )
+  , Mont::unsafe_make( [ 0x8f0953ce , 0x495c36cc , 0xfbc01c90 , 0x8576f6f0 , 0x293be12e , 0x0cdeb00e , 0xf79f09ff , 0x27519bd0 ] )
+  , Mont::unsafe_make( [ 0x801d7016 , 0x2a8bc7ac , 0x9feee25c , 0x37835c1a , 0xe1a38b73 , 0xff5a6948 , 0xb6c6f2ae , 0x05f439ac ] )
+  , Mont::unsafe_make( [ 0x242a9804 , 0x33259b80 , 0x0c947766 , 0x80ec10b3 , 0xeaec0379 , 0x7422c6e7 , 0xd65e2e56 , 0x0e14d382 ] )
+  , Mont::unsafe_make( [ 0x79f2a9f7 , 0x73c0edd0 , 0xe854cda8 , 0x2f05ddab , 0xd4dc8a61 , 0x2e448e25 , 0x0bdeae76 , 0x1d6e6af9 ] )
+  , Mont::unsafe_make( [ 0xa26faec6 , 0xa9a4aaff , 0x8c5c75e3 , 0xf8837d21 , 0x8afafef2 , 0xfeb223bb , 0x825460e6 , 0x1b8af837 ] )
+  , Mont::unsafe_make( [ 0x159814ec , 0x60d3e1bc , 0x613f171c , 0x71a424b8 , 0x07345113 , 0xc016f8e8 , 0x507284d2 , 0x26e4fb99 ] )
+  , Mont::unsafe_make( [ 0x30ca6636 , 0xb1eccfbf , 0x93943029 , 0x3bfc9fe0 , 0x8bb0d9e0 , 0x6bf02679 , 0x1327baf5 , 0x2ddefeb5 ] )
+  , Mont::unsafe_make( [ 0x8c4e3c14 , 0xf42df481 , 0xf48c21a3 , 0x09fe7a35 , 0x89fbae67 , 0x168342ec , 0x795897f4 , 0x0f4d6979 ] )
+  , Mont::unsafe_make( [ 0x1e3c6c25 , 0xcc6b52ce , 0x16815c3d , 0x24247077 , 0x4ae783e4 , 0xf59e99e8 , 0xdf1b4e51 , 0x138b3459 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x2dd8be81 , 0xdbcb7110 , 0x44b9f712 , 0x749a7c76 , 0xd80d0427 , 0xba624b21 , 0x203d62c0 , 0x0d36333f ] )
+  , Mont::unsafe_make( [ 0xfb82f923 , 0x53be6a91 , 0xefeb9a1e , 0x22f2365e , 0xbd250542 , 0x57b04a27 , 0xe0e881ec , 0x28ae444f ] )
+  , Mont::unsafe_make( [ 0xcf79d78b , 0x5965bd6b , 0x88e90cbc , 0x8a5f76b0 , 0x6ff3996b , 0x17dfe526 , 0xf27cf0f7 , 0x08204aff ] )
+  , Mont::unsafe_make( [ 0x5f82a703 , 0x3b30419d , 0x1e0c13de , 0xaa2514eb , 0xb86d45f4 , 0xe4935865 , 0x8b7afcfa , 0x022babcf ] )
+  , Mont::unsafe_make( [ 0x3c421e42 , 0x38afbbe9 , 0xddb679db , 0xd58608a8 , 0x64dc0919 , 0xa373289d , 0x712ac8f4 , 0x1e9586ad ] )
+  , Mont::unsafe_make( [ 0xc5241293 , 0x9dfe6e75 , 0x1fb01585 , 0x83d9f46c , 0xf4a0b80e , 0xdad09b61 , 0xf073fe6e , 0x1678837c ] )
+  , Mont::unsafe_make( [ 0xb19df005 , 0xfab48be2 , 0x1b7b99e3 , 0xa5dacc21 , 0xbe3e339f , 0x4960ec26 , 0x52ae6326 , 0x12684d38 ] )
+  , Mont::unsafe_make( [ 0x2e06d002 , 0x2b363694 , 0x453e732e , 0xd481bae4 , 0xf5f0635d , 0x23391af8 , 0xdbc25eb6 , 0x2beeadeb ] )
+  , Mont::unsafe_make( [ 0xc7d3e992 , 0xa03dbf6b , 0x6dbb4eab , 0x566e34db , 0x9f3701ae , 0xc8657b24 , 0xb71794bb , 0x223f9738 ] )
+  , Mont::unsafe_make( [ 0x2deb032a , 0x91da428a , 0xe992c2d0 , 0xa7b0508f , 0xcbfe403d , 0x421b878a , 0x91fbc238 , 0x2e7af7a1 ] )
+  , Mont::unsafe_make( [ 0x0071e3a7 , 0x0a91c8ca , 0x8d73d0d5 , 0x6fbd5c90 , 0xd44f380e , 0xf11aecc8 , 0x06ab9490 , 0x09af5d26 ] )
+  , Mont::unsafe_make( [ 0x0acdc6c1 , 0x4b5e0cc5 , 0xe8ab2a8f , 0x43ec2a15 , 0x941c7251 , 0x201c5339 , 0xe8120a66 , 0x2f86c57c ] )
+  , Mont::unsafe_make( [ 0x7a626ba8 , 0xb2286f55 , 0x5ae87cfe , 0x76c0afaa , 0x89cf402b , 0x8c1e8d87 , 0x39d56e42 , 0x2fa4e30c ] )
+  , Mont::unsafe_make( [ 0xf1400abc , 0x6336e8e2 , 0x04b6515f , 0x05184560 , 0xa42d21c1 , 0x5271c566 , 0x80358fe1 , 0x14040546 ] )
+  , Mont::unsafe_make( [ 0xe558c724 , 0x4db9af1a , 0x4992642a , 0x5c8feb7c , 0x2b028d14 , 0x1d4032ae , 0xdd7ddbe1 , 0x24bfeeac ] )
+  , Mont::unsafe_make( [ 0xb5c7e716 , 0x45499959 , 0x4ce9bdf3 , 0xb9c7ff6c , 0x5390920a , 0x835864db , 0x3b41f505 , 0x07d32786 ] )
+  , Mont::unsafe_make( [ 0x7727acdf , 0x1544c663 , 0x7b27ad0e , 0xe6fa81c0 , 0x045d76dc , 0x487137b4 , 0x346e35e1 , 0x15c89b25 ] )
+  , Mont::unsafe_make( [ 0x68b76244 , 0x6dd07882 , 0xb5438c68 , 0x00fd315a , 0xad3af0bb , 0xe687e90f , 0x50d0d415 , 0x067cd9fc ] )
+  , Mont::unsafe_make( [ 0xe5c6494d , 0x841d7aa9 , 0xb380aa2f , 0x5915a881 , 0x624c175b , 0xea01edc8 , 0xb408b5f9 , 0x2787bdab ] )
+  , Mont::unsafe_make( [ 0x7ea88907 , 0xe4017e0f , 0x58e4361a , 0xd52dc407 , 0xd3bca0b3 , 0x00a8c67f , 0x78b66a77 , 0x1add3c04 ] )
+  , Mont::unsafe_make( [ 0xa4709998 , 0xf43ad7c3 , 0x36585b3c , 0x3df23a47 , 0xe8fe3113 , 0xfa545f81 , 0x2ef079f2 , 0x2c69dc38 ] )
+  , Mont::unsafe_make( [ 0x6debac01 , 0xe96acfc8 , 0xbe45e446 , 0x46bfa38f , 0x3f1a3b8a , 0x5d53314c , 0x5bc69692 , 0x0e425be8 ] )
+  , Mont::unsafe_make( [ 0xd89179fd , 0x12d48854 , 0x72e43dfa , 0xb0f98569 , 0x0321f39d , 0xf224e8d8 , 0x19e015c6 , 0x0cf5efeb ] )
+  , Mont::unsafe_make( [ 0x613de266 , 0x1c40a8db , 0xb13fb35a , 0x795ef469 , 0xcbc74db3 , 0xb990e0a4 , 0x649f48c1 , 0x294f391d ] )
+  , Mont::unsafe_make( [ 0x30906327 , 0x8366fce3 , 0xa9b6be05 , 0x1eb7492a , 0xaceb1215 , 0xd26a4e68 , 0xa83469a1 , 0x16b2b50e ] )
+  , Mont::unsafe_make( [ 0xeda4864a , 0x42e01e4f , 0x15fd0333 , 0x716c9e1e , 0xafe6ffff , 0xccd7d4c9 , 0x81e51591 , 0x227921f7 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x1bbbce4b , 0xf44341f1 , 0x2a2872dc , 0x3ca19941 , 0x3cb5c2e5 , 0xb4f8786b , 0x8a4d24c1 , 0x1d06c75d ] )
+  , Mont::unsafe_make( [ 0xd003ab14 , 0xd2cef1a1 , 0xae6e74e4 , 0x5f7f6c18 , 0xb9b2a1de , 0x9f6952d4 , 0x4cdebbe6 , 0x161cd449 ] )
+  , Mont::unsafe_make( [ 0x3068b833 , 0x1c4d6bd1 , 0x73a72f2d , 0x85c6fab1 , 0x10de45d7 , 0x754d98e9 , 0xa8368bfa , 0x048989dd ] )
+  , Mont::unsafe_make( [ 0x92a9b86b , 0x63da27b7 , 0x0289ef55 , 0x29c25403 , 0xbbc8b68c , 0xe909b2a9 , 0x9dadf8cd , 0x1de12ac3 ] )
+  , Mont::unsafe_make( [ 0x3e3476a2 , 0xed31a80c , 0x359474ba , 0xbb49def4 , 0x99acc9a6 , 0x367b1bed , 0xac46ba45 , 0x0531f5c7 ] )
+  , Mont::unsafe_make( [ 0xe5f65b12 , 0x0138c96b , 0xb8576baa , 0xdd333ff6 , 0x575b3c21 , 0xa2b72597 , 0x6c9681ad , 0x101d8736 ] )
+  , Mont::unsafe_make( [ 0x954b63e8 , 0xc3be41e1 , 0x9f255b31 , 0x389909b2 , 0xc6574110 , 0x4579bec9 , 0x5e7ee288 , 0x13ad150e ] )
+  , Mont::unsafe_make( [ 0xb8164ebc , 0x89a6301e , 0xaf1252be , 0x3c3a2c09 , 0x8efb11c4 , 0x7da13263 , 0xacd1fc26 , 0x0949b6d0 ] )
+  , Mont::unsafe_make( [ 0x819f7ebb , 0x0ba394de , 0xda6c834c , 0xc81fdc16 , 0x36f7acd7 , 0xdc41294a , 0x5450ca28 , 0x2a6e112a ] )
+  , Mont::unsafe_make( [ 0xbdbce0b8 , 0x122d9022 , 0x9ca367cc , 0x2b20f291 , 0x19c7e4ba , 0x45a24891 , 0xe6a587a6 , 0x24a9fc20 ] )
+  , Mont::unsafe_make( [ 0x011c62c8 , 0xddae1e49 , 0xab6391ca , 0x5abc1306 , 0x00b30e4f , 0xa3361673 , 0xc72f9be0 , 0x2a16d222 ] )
+  , Mont::unsafe_make( [ 0xebee42d9 , 0xdc455535 , 0x0a3a9eaf , 0x025b6711 , 0x7579b924 , 0x8c4b6db5 , 0xf2a5335a , 0x0088c389 ] )
+  , Mont::unsafe_make( [ 0x6681fa51 , 0x64a78a37 , 0xd3e8c52f , 0x011644a7 , 0xe9417a5f , 0x7a037ad4 , 0x0ffe3599 , 0x06b94607 ] )
+  , Mont::unsafe_make( [ 0x622c7f36 , 0xa3005a91 , 0x2739c251 , 0xde6c40a8 , 0x96307d38 , 0xeaf86837 , 0x33b696a8 , 0x0f55c054 ] )
+  , Mont::unsafe_make( [ 0x139a96b0 , 0x236d3637 , 0x88c82601 , 0xbc081547 , 0x4bf5b6c0 , 0xcc22b475 , 0x4ad4b4d2 , 0x11db883a ] )
+  , Mont::unsafe_make( [ 0xe8e08361 , 0xd9a24c59 , 0x4c79ee1c , 0x106919a7 , 0x1d3e7e1c , 0x755612ce , 0xa23d1b04 , 0x16e6ed14 ] )
+  , Mont::unsafe_make( [ 0x605888ee , 0xfdf67b7b , 0x577ceaf3 , 0x5095af4f , 0xd335e792 , 0xceddf0ee , 0x44d62548 , 0x2450f7d4 ] )
+  , Mont::unsafe_make( [ 0xf436cee3 , 0x65a79616 , 0xe7c10153 , 0x8b5b544e , 0x5ed5e98b , 0x56f6ee6c , 0x84430df9 , 0x2d95a87e ] )
+  , Mont::unsafe_make( [ 0x08c50110 , 0x3dd416d1 , 0xf83c1c4b , 0x8c903c17 , 0xac30afcd , 0x96027687 , 0x0b5b3419 , 0x2935ecde ] )
+  , Mont::unsafe_make( [ 0x4148fe31 , 0x70fe9e97 , 0x5447fa9f , 0x3b73506d , 0x9292e50d , 0x61b28129 , 0xe72242e7 , 0x01322067 ] )
+  , Mont::unsafe_make( [ 0x506a0709 , 0x6a9bd3ec , 0xaf2ce526 , 0x13230972 , 0x64e037f7 , 0xf0aebf7b , 0xb4c23eb6 , 0x0799a5e6 ] )
+  , Mont::unsafe_make( [ 0xb21c7c4c , 0xfd5bc4a0 , 0xd3845731 , 0xd8772975 , 0x526d7a99 , 0x6aee6c63 , 0x99a6dfb0 , 0x19a1f6a3 ] )
+  , Mont::unsafe_make( [ 0xc37ec234 , 0xff69f12f , 0xefd8d2f9 , 0x534f6dd7 , 0xbcaa7a13 , 0x6dfd63b3 , 0x8a8195ac , 0x09509c69 ] )
+  , Mont::unsafe_make( [ 0x59ed93fb , 0xcf1f070d , 0x964a6a01 , 0x67837844 , 0x00d01394 , 0x3b8fe055 , 0xdaf64e5e , 0x15be3017 ] )
+  , Mont::unsafe_make( [ 0xe4e520cf , 0x47145d57 , 0xadb72a85 , 0x12470223 , 0xb67dd4e3 , 0xa87883cc , 0x9a5a38b8 , 0x10de22e9 ] )
+  , Mont::unsafe_make( [ 0xbeef5a6f , 0xb527b85b , 0x0205ef9b , 0x5c30b875 , 0xd831ab12 , 0xd07f82be , 0x82883b6d , 0x26a39a42 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xced6bb5f , 0xac647d15 , 0x730ad999 , 0xf45d04ef , 0xf8c23f3a , 0xc18bb7ab , 0xe721eb03 , 0x2fba3969 ] )
+  , Mont::unsafe_make( [ 0x1173d21c , 0x680fb1b8 , 0x9c4591f8 , 0x15e7bad1 , 0x77c67b55 , 0x52cf1c13 , 0x67488ccf , 0x2ef8d0ee ] )
+  , Mont::unsafe_make( [ 0x5fc7bda3 , 0x6a325b1c , 0x425d2f6e , 0x41bca2fc , 0x505bf6d2 , 0x7996fbcc , 0x10617528 , 0x23569604 ] )
+  , Mont::unsafe_make( [ 0xc92ff93c , 0xc0722eb1 , 0x1e46e0ea , 0x2a59e035 , 0x3ad25776 , 0xe07e9c5f , 0xe9e8e934 , 0x1a5de2c1 ] )
+  , Mont::unsafe_make( [ 0x650b030a , 0x678df20f , 0x343e8dd8 , 0x7e849ed1 , 0x4a81d8ff , 0x222eb33e , 0x8b310ca2 , 0x1a06e499 ] )
+  , Mont::unsafe_make( [ 0x9364a9bb , 0x20d75e94 , 0x6abc4096 , 0x125d6ca7 , 0xf6982679 , 0x28ae1803 , 0xde4487d4 , 0x14ac3f56 ] )
+  , Mont::unsafe_make( [ 0x95bc1a82 , 0xec979f32 , 0xcc10cb68 , 0xe23bfc2f , 0xde57b19c , 0xc6236c1a , 0xd4d76b4c , 0x22960bf8 ] )
+  , Mont::unsafe_make( [ 0xd0462f6e , 0x070ad4b9 , 0x66213ac3 , 0x77e53d76 , 0x0764566f , 0x59048dc1 , 0xcfb9e41f , 0x0f0a915d ] )
+  , Mont::unsafe_make( [ 0x9beba0a0 , 0x974c3b87 , 0x6082c988 , 0x27941f85 , 0xf697b255 , 0xd7218227 , 0x0e100f23 , 0x139dcbf6 ] )
+  , Mont::unsafe_make( [ 0x63b0bd92 , 0xc5f3a194 , 0x79d31ab4 , 0xe40830f1 , 0x1e02664f , 0xbaec9cf3 , 0x6f6d0687 , 0x1b3a24e4 ] )
+  , Mont::unsafe_make( [ 0x415c68f2 , 0xffa6299b , 0x7e3844ec , 0xe6e4c73e , 0xde3120dc , 0xd6e81a91 , 0xfe20cec3 , 0x3029da8f ] )
+  , Mont::unsafe_make( [ 0x6d7db8de , 0xca8dfd70 , 0x7216eb63 , 0x4d73fd23 , 0xed201179 , 0xbe356174 , 0x0b3966d8 , 0x1f8e0d34 ] )
+  , Mont::unsafe_make( [ 0x7acba43a , 0x0506485b , 0xfc863f39 , 0x445797a4 , 0xfcb07283 , 0x208f8d6b , 0xa5bcb2bd , 0x21b8f5b4 ] )
+  , Mont::unsafe_make( [ 0x160e886f , 0xcb27022f , 0xd59fef0d , 0x04b00ec5 , 0x04da6196 , 0x1fe0e3fd , 0x3d8e26ca , 0x1ebf3cd8 ] )
+  , Mont::unsafe_make( [ 0xe7212975 , 0x5992ffd7 , 0x4c886ea5 , 0x3d854eb1 , 0x173e2052 , 0xeb2fdb67 , 0xe8525cd0 , 0x20fa8785 ] )
+  , Mont::unsafe_make( [ 0x314dab20 , 0x319ba5db , 0x24ce4fab , 0x9493ee44 , 0x663a1179 , 0x35debc5d , 0x2b3fd7b2 , 0x1068e14d ] )
+  , Mont::unsafe_make( [ 0xf9a6fefa , 0x44622393 , 0xca52978b , 0x5fbf92f8 , 0x559470f7 , 0xe62b16d0 , 0xe31a9236 , 0x178aef04 ] )
+  , Mont::unsafe_make( [ 0xf472cf80 , 0xe042240d , 0xb8538248 , 0x29fd43b3 , 0x371ea66b , 0x2fb659a0 , 0x8a182e84 , 0x025f2605 ] )
+  , Mont::unsafe_make( [ 0xe989ab52 , 0xed9f663f , 0x216255cf , 0x460252c6 , 0x280c6426 , 0x87a788f2 , 0xd2d71594 , 0x242ad9b7 ] )
+  , Mont::unsafe_make( [ 0x46f93fa2 , 0x247018b9 , 0x8f328c9f , 0x6466ba75 , 0x613bc794 , 0x4633d7ee , 0xa9926ff1 , 0x0b1b6629 ] )
+  , Mont::unsafe_make( [ 0xa1c03a89 , 0x7abcc3c3 , 0x914615f0 , 0xa083cab0 , 0x0960f2cf , 0x57d79445 , 0x47ca8ca7 , 0x2abc571f ] )
+  , Mont::unsafe_make( [ 0xdfd10dac , 0x2b08ba49 , 0x5b32816d , 0x712ffdb2 , 0x5306733f , 0xdbec65aa , 0x22defca6 , 0x1701379c ] )
+  , Mont::unsafe_make( [ 0x9e8b1ec5 , 0xadcc5840 , 0x62aff876 , 0x937dc8ad , 0xc5fe85b4 , 0x18d2c347 , 0x14df2161 , 0x2524ec4e ] )
+  , Mont::unsafe_make( [ 0xeada1566 , 0x4b3c4bfc , 0x7079e32f , 0x271f151f , 0x8d5d6c89 , 0x3eab7486 , 0xcdafce27 , 0x0a90b5f5 ] )
+  , Mont::unsafe_make( [ 0xe63ace44 , 0xdf492a21 , 0x7d74863b , 0x8184a864 , 0xbc3053f0 , 0xc931e4fe , 0x67180119 , 0x153ae445 ] )
+  , Mont::unsafe_make( [ 0x2f787e6d , 0x4f83fa49 , 0xcf52c27f , 0xd80294b6 , 0x2c58955c , 0x81c6d9a5 , 0xae50a5ef , 0x0fed290b ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xd560339b , 0xfcbf0b46 , 0xf05a40c0 , 0x7fc8ac34 , 0x478816af , 0x058cc462 , 0xfd95975c , 0x0636579f ] )
+  , Mont::unsafe_make( [ 0x8b40dcbf , 0x33308de4 , 0xb02fab2a , 0x814b68f6 , 0xeca39caf , 0x1fa34e9e , 0xa262e8b5 , 0x2a86f84f ] )
+  , Mont::unsafe_make( [ 0x0b8b5a31 , 0x3f965b2d , 0xf5b5264e , 0xa434bd3a , 0xb8a7167f , 0xd195b5cc , 0xc5ae8cee , 0x1f81ed8f ] )
+  , Mont::unsafe_make( [ 0x16aa97c7 , 0x17f9dc3b , 0x493e5802 , 0x67bb36bc , 0x26de8dc7 , 0x31c511a8 , 0x1c9942a6 , 0x0151c386 ] )
+  , Mont::unsafe_make( [ 0xeaa9875c , 0x38f4f48c , 0x92938649 , 0x30e3a8fb , 0x82558157 , 0x4d367d74 , 0x7f1e0d82 , 0x028beffa ] )
+  , Mont::unsafe_make( [ 0x6f6e6212 , 0xc5b1a0cb , 0x67dc470f , 0x5cc887bc , 0x18a244ed , 0x1708c278 , 0x47aaf7b2 , 0x0d730a89 ] )
+  , Mont::unsafe_make( [ 0xa9df740d , 0x83bb4a3e , 0xa62dd223 , 0x445bf3d7 , 0x40cd2fde , 0xd11994c5 , 0x66041d08 , 0x03dfce41 ] )
+  , Mont::unsafe_make( [ 0x23b9061e , 0xeab3adf0 , 0x1012bf39 , 0x1d406292 , 0x5ac86213 , 0x0acdd009 , 0x9b42a125 , 0x1144e823 ] )
+  , Mont::unsafe_make( [ 0xbcba1aaa , 0xef3045d8 , 0x88bd76d8 , 0xa261cad7 , 0x8c17313b , 0x868fa4a7 , 0x7a5da883 , 0x278f4255 ] )
+  , Mont::unsafe_make( [ 0x622ebbb9 , 0x6210f12d , 0xf29783b7 , 0xbc6b0ddf , 0xbd18cce4 , 0x835da419 , 0x0030a9b9 , 0x237d1f4a ] )
+  , Mont::unsafe_make( [ 0xe931f7c8 , 0x4e4f8c08 , 0x358d5331 , 0x9e794fc8 , 0xd7669b23 , 0xc4476487 , 0x0979b6ef , 0x2239e25f ] )
+  , Mont::unsafe_make( [ 0xe9549a86 , 0x06974ac3 , 0xf8801ddf , 0x68935a47 , 0x7c7c6d8d , 0x880defc5 , 0x15f5e530 , 0x07a096c6 ] )
+  , Mont::unsafe_make( [ 0x7273dce1 , 0xcc2adda7 , 0x3ad2d962 , 0x8f32ae75 , 0x9537eb71 , 0x61b827ff , 0x8e595094 , 0x1fc1445d ] )
+  , Mont::unsafe_make( [ 0x0c4a2cfd , 0x83681b75 , 0x188eca65 , 0xd2de61bb , 0xfe79f78b , 0x757f96b5 , 0xd46510d9 , 0x083d9ea7 ] )
+  , Mont::unsafe_make( [ 0x2f47d17f , 0x24f88582 , 0x90e07023 , 0x377c49df , 0xf71573d6 , 0x2d2eae3b , 0x767d185b , 0x0c4a0ce8 ] )
+  , Mont::unsafe_make( [ 0x529e3196 , 0x82eb6b12 , 0x27178dd7 , 0x2f975437 , 0x0893fbc1 , 0x198c47ce , 0xcd466172 , 0x245fe5e0 ] )
+  , Mont::unsafe_make( [ 0xa33569e1 , 0xcaddc505 , 0x0f55800d , 0x742d768d , 0x71ce8573 , 0x2a88fdff , 0xf25cce78 , 0x0d867696 ] )
+  , Mont::unsafe_make( [ 0xbc7fab76 , 0x68d02a10 , 0x0eb4771e , 0x60258b61 , 0xdfade7c2 , 0x59bc1d0a , 0xb88ac3eb , 0x061a8f9e ] )
+  , Mont::unsafe_make( [ 0x302e9df0 , 0x0b2a0d9d , 0x3ea38738 , 0x98640c75 , 0xff14ffa0 , 0x903b4449 , 0xc770cc2a , 0x18e9c4cb ] )
+  , Mont::unsafe_make( [ 0x2da5b21d , 0x0fdfc92b , 0x2fed34d9 , 0x9cb8134e , 0x6b491581 , 0xdad7d962 , 0x7d5e12a1 , 0x202ecf9c ] )
+  , Mont::unsafe_make( [ 0x401353d6 , 0x1fbf9278 , 0x9dee0151 , 0x25643232 , 0x7950552f , 0x722d4705 , 0xecd4af5b , 0x02fcc7a3 ] )
+  , Mont::unsafe_make( [ 0xba308e78 , 0x30d4f237 , 0xa63652cd , 0x08b1e1c8 , 0xfb0726b1 , 0x0a176c3a , 0x557e92f3 , 0x09ea5bf0 ] )
+  , Mont::unsafe_make( [ 0x50727b1a , 0x1992ff17 , 0xfcdb6afb , 0xa6eecb99 , 0xfa5613cc , 0xb6b540c2 , 0xb3cb0c60 , 0x296a4107 ] )
+  , Mont::unsafe_make( [ 0xed7ed0fc , 0x0c842630 , 0x6f4943d0 , 0xc5734663 , 0x178c71f2 , 0x7bfc2000 , 0xe8af7752 , 0x244d6378 ] )
+  , Mont::unsafe_make( [ 0xa42f0f68 , 0x8f6cc9af , 0xce77ddc4 , 0x37a778ed , 0x8d51bb5e , 0xb5951a87 , 0xf7f2f9e1 , 0x2ddaede1 ] )
+  , Mont::unsafe_make( [ 0x93640fe4 , 0xb760b542 , 0x20f93741 , 0x09edbfed , 0x5250d653 , 0xf41bbd0e , 0x4e9f6196 , 0x00aa67f9 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x3db4fa1e , 0x84f7c0dc , 0x0d65c972 , 0xd124989e , 0xc2ee739d , 0x890b1662 , 0x289fe475 , 0x1dd746e3 ] )
+  , Mont::unsafe_make( [ 0x9c63d3c2 , 0xb21ea3de , 0x5cb94133 , 0x1236e3c1 , 0x47cc22cc , 0xf123f5fb , 0x7ea2aeee , 0x171fd8b3 ] )
+  , Mont::unsafe_make( [ 0x980f7ca5 , 0xd17509e6 , 0x473fbc3d , 0xee69c505 , 0x2da20467 , 0xf627d18e , 0x9f23b354 , 0x055b98cd ] )
+  , Mont::unsafe_make( [ 0xcf4b10b2 , 0x01340684 , 0x886866d2 , 0xc233467f , 0xa7ba9ab1 , 0x04817435 , 0x26f7e937 , 0x1e3b2a00 ] )
+  , Mont::unsafe_make( [ 0xf51ae9eb , 0x56642aa3 , 0x0e9eb03c , 0xa9612464 , 0xfe9c5064 , 0xd07fae1a , 0xc7dd87d3 , 0x2921325f ] )
+  , Mont::unsafe_make( [ 0x8b4d46e3 , 0x9be2d412 , 0x12f79c57 , 0xb26f90bf , 0xf01f9e5d , 0xa49c50a7 , 0x108d8800 , 0x109d9860 ] )
+  , Mont::unsafe_make( [ 0xd17a3336 , 0xa002da92 , 0x07817d8c , 0xf873d896 , 0x4e7ec1a9 , 0xb2658b33 , 0xfffe62cb , 0x19315345 ] )
+  , Mont::unsafe_make( [ 0xacc9ba60 , 0xe2be1fc3 , 0xd5261b58 , 0xf4b41b1c , 0x62921c47 , 0x307f7ecd , 0x09f7c924 , 0x2f97afa7 ] )
+  , Mont::unsafe_make( [ 0xd2acc901 , 0x8917cb1a , 0xe2f1b4cc , 0x4f56ee93 , 0xa5f66b49 , 0x0dbc6210 , 0x410b2bdf , 0x2883ff42 ] )
+  , Mont::unsafe_make( [ 0x156f32de , 0x460a1ffb , 0x9c4c6274 , 0x81ba83a4 , 0xe32634b7 , 0x2c600b8b , 0xe9c003dc , 0x2d03eecf ] )
+  , Mont::unsafe_make( [ 0x09c8fce3 , 0xa8e652d3 , 0x1d817c3a , 0xb80f9a3e , 0x55659282 , 0x304787ae , 0xc517bb21 , 0x02e66b42 ] )
+  , Mont::unsafe_make( [ 0x0ec74163 , 0x35be5ae7 , 0x40fa1510 , 0x7eb068b7 , 0x9b0f9e3f , 0xda1f8449 , 0x9b6022b2 , 0x0fcc7300 ] )
+  , Mont::unsafe_make( [ 0xffd421dd , 0x807e585b , 0xc7643dc4 , 0x856917ea , 0xef537fc2 , 0x4193ea09 , 0xabd57370 , 0x17780d3c ] )
+  , Mont::unsafe_make( [ 0x189704af , 0xe5f3a627 , 0xcd07b404 , 0xf053d2ea , 0x877cef9b , 0x220bd60a , 0x57b56742 , 0x20d6577c ] )
+  , Mont::unsafe_make( [ 0x8754071e , 0xa0796699 , 0x33c159fb , 0xaab12f0d , 0xb391578d , 0xae0a5f1a , 0xdce29be4 , 0x2a8d3064 ] )
+  , Mont::unsafe_make( [ 0xb87c301b , 0x89946b2d , 0xed8dea7f , 0x0c5b5f1d , 0x0e73881e , 0x6259c780 , 0x5d040d3c , 0x080c31c8 ] )
+  , Mont::unsafe_make( [ 0xc13d00f7 , 0xa9ca78cc , 0x1127fb0c , 0xa7e2c09a , 0x332eece0 , 0x6019d152 , 0x00f66317 , 0x09a72f80 ] )
+  , Mont::unsafe_make( [ 0x6c2e10e5 , 0x6415a350 , 0xc2d6d095 , 0x6df14e3c , 0x77a3ebde , 0x9949becc , 0x0db301ef , 0x2294cfbb ] )
+  , Mont::unsafe_make( [ 0xf1064ab4 , 0xa465f064 , 0x0e15699f , 0xb37bb343 , 0x777be3c5 , 0xcffde66d , 0xbada774a , 0x29d8131a ] )
+  , Mont::unsafe_make( [ 0x64fb11a2 , 0x6fb8d266 , 0x7d2ffef9 , 0x7948a9e0 , 0x48f1462a , 0x6d8d1da3 , 0xf556e6ec , 0x001ba754 ] )
+  , Mont::unsafe_make( [ 0xe1642443 , 0x63ab0eec , 0x0fafc3c9 , 0xbbf80eae , 0x13f63284 , 0x07b02d2d , 0x97dad59f , 0x26800f7c ] )
+  , Mont::unsafe_make( [ 0x024a9955 , 0x1d7169c6 , 0x58b727ed , 0xd2c087c1 , 0xecb539eb , 0x7450a582 , 0x75e80bb2 , 0x2650cfcf ] )
+  , Mont::unsafe_make( [ 0xdef2ee82 , 0x48de18d2 , 0x9e4d9264 , 0x4a3cfced , 0x413cefd1 , 0xfb388007 , 0xcb2d205a , 0x1d527a46 ] )
+  , Mont::unsafe_make( [ 0x22847008 , 0x534046ec , 0xabe6c9e7 , 0x3281c675 , 0x78fae3c9 , 0x5511cf92 , 0x897e31c1 , 0x076a4f4c ] )
+  , Mont::unsafe_make( [ 0x0cb2fc71 , 0xb381b23f , 0x35b865cd , 0x5d98f3e6 , 0x780edf00 , 0x49b71b8e , 0xe68b717a , 0x305ac3a0 ] )
+  , Mont::unsafe_make( [ 0x4f0d67b9 , 0xf3b1e329 , 0x3bcb3996 , 0x432fa9e4 , 0xc6f85a06 , 0x7d774e37 , 0x2c4899c4 , 0x2d897781 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x8cf16311 , 0x6facc84f , 0x33a8f27b , 0x16ca21d5 , 0x14febe72 , 0xe5f663c1 , 0xc381cfb2 , 0x02185560 ] )
+  , Mont::unsafe_make( [ 0xb92d8896 , 0x9d0f5013 , 0xe73efc27 , 0xaf598a83 , 0x2b18c173 , 0xa656d0de , 0x3df220bd , 0x28609bf7 ] )
+  , Mont::unsafe_make( [ 0x4b4cdef8 , 0x32c487d7 , 0x041e1af1 , 0x38079590 , 0xd73d08de , 0x0d1411d3 , 0xbd4b7109 , 0x26521e68 ] )
+  , Mont::unsafe_make( [ 0xaffa4ea0 , 0x83852601 , 0xcf15e1dc , 0x8754172b , 0x5f15cb0c , 0x9b868048 , 0xb267fe78 , 0x170976c1 ] )
+  , Mont::unsafe_make( [ 0x3bc4c9dd , 0x60bc9fd1 , 0x1620a3e2 , 0xa93b87d9 , 0x394dcaa1 , 0x00d68429 , 0xcfcd1bd1 , 0x1c7a36b3 ] )
+  , Mont::unsafe_make( [ 0xfb63e080 , 0x3d47cd68 , 0xab70da42 , 0x7fb78648 , 0x5593e39d , 0x0a4a5a0b , 0x13d71f7c , 0x2bc9dba0 ] )
+  , Mont::unsafe_make( [ 0x0bf128b5 , 0x1c8ea887 , 0x8432b2c6 , 0x0a50e75e , 0xbfcc7ff6 , 0x434e3fb4 , 0xd71946cc , 0x2f3e23e7 ] )
+  , Mont::unsafe_make( [ 0x16beb7c8 , 0x2724e58e , 0x7d4f4494 , 0x28971e97 , 0x2f7e284c , 0x1b32e2c3 , 0xa3008f16 , 0x04a5eee4 ] )
+  , Mont::unsafe_make( [ 0xed1f5ece , 0x43cc4627 , 0x9da8444e , 0xf38facff , 0xe2f15c64 , 0x4cf87884 , 0x090df125 , 0x2d74b2a2 ] )
+  , Mont::unsafe_make( [ 0x76847d7a , 0x0c57ab7d , 0x1ee1eada , 0xfa8daf74 , 0xb8a95323 , 0xf50f339b , 0x6e1120ec , 0x0bde54a7 ] )
+  , Mont::unsafe_make( [ 0x853fdcd3 , 0x8a583876 , 0xddf64f67 , 0x94528e3a , 0xd2e4018a , 0x6cc2636b , 0x9ffceeb1 , 0x04ca9421 ] )
+  , Mont::unsafe_make( [ 0xec446c48 , 0x6662cc5c , 0x15c542d5 , 0xf4baee52 , 0xe1ed39c6 , 0xe90f2c53 , 0x14499ce5 , 0x01d34282 ] )
+  , Mont::unsafe_make( [ 0xbc3d1446 , 0x74ffb868 , 0x53e2d6f9 , 0x908208e3 , 0x93e91729 , 0x33473a2d , 0xcd6be960 , 0x2a81ed9e ] )
+  , Mont::unsafe_make( [ 0xb6766c67 , 0x8caea91b , 0x367d74f7 , 0xfa468461 , 0xee963f42 , 0x2bf4177b , 0x4c881272 , 0x2ace4948 ] )
+  , Mont::unsafe_make( [ 0xf31f9637 , 0x76f747b9 , 0x3bcb6520 , 0x2009b2a1 , 0x30675893 , 0x1bdafa71 , 0xef96d465 , 0x1c40a866 ] )
+  , Mont::unsafe_make( [ 0x9efaf2fa , 0x1091ea34 , 0x7d3986b7 , 0xece884cd , 0xc769c39b , 0x7c8563b1 , 0x0258d6cc , 0x2a9369a5 ] )
+  , Mont::unsafe_make( [ 0x62153375 , 0x326fafce , 0x77edeff8 , 0x1462f9ca , 0x4937d44d , 0x1f9daa1e , 0x240b2f62 , 0x13c0055a ] )
+  , Mont::unsafe_make( [ 0x97cd858b , 0x9a0509aa , 0xe9c85c2d , 0xd41e9faf , 0xf72506f8 , 0x4b39136c , 0x122a1248 , 0x06d674d4 ] )
+  , Mont::unsafe_make( [ 0x37da40ec , 0xe145d0b7 , 0xb3ba1e06 , 0xab717e1f , 0x0b946636 , 0x73c1df98 , 0x4fdaec6f , 0x08db88ea ] )
+  , Mont::unsafe_make( [ 0x8a441052 , 0x29cab745 , 0xf1cb576b , 0x179fd1e0 , 0xf5e9ce71 , 0x62aa07a2 , 0x556ed82f , 0x2faeee54 ] )
+  , Mont::unsafe_make( [ 0xd95cff16 , 0xb93e7e82 , 0xf6ac5f55 , 0x26c70b84 , 0xec4f739e , 0xa722ea06 , 0x5e57923b , 0x2f9736e9 ] )
+  , Mont::unsafe_make( [ 0x1ea1952c , 0xf0544a16 , 0xd2d4a288 , 0xa4da5011 , 0x3837a25a , 0xf8858c9d , 0x4c832656 , 0x0bbc160e ] )
+  , Mont::unsafe_make( [ 0x5e16ec9b , 0xa4da2936 , 0x21e979a8 , 0x30dae8d0 , 0x65bc6f4b , 0x17bc7ade , 0xf9f4629e , 0x2dc1dbaf ] )
+  , Mont::unsafe_make( [ 0xe587befc , 0x533d4ec4 , 0x05d1fbc0 , 0xe12204af , 0x3ad78b4b , 0x83554f35 , 0x1f71aaf8 , 0x1ab2d9c4 ] )
+  , Mont::unsafe_make( [ 0x7650e3ce , 0xe63ff053 , 0xa22aef23 , 0x928bbd50 , 0x55b23116 , 0xfdaf9f0d , 0x7b2c02ed , 0x1a8c6568 ] )
+  , Mont::unsafe_make( [ 0x89e4900e , 0x938a09b0 , 0x79d163bb , 0xd8013fbe , 0xb9c12b1b , 0xace1170a , 0x6a424ca5 , 0x23839977 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x5877a1a6 , 0xefbabcc8 , 0x6b0152d8 , 0xa25a3138 , 0x00fe6833 , 0xa11608ce , 0x9bd0cdde , 0x2974574d ] )
+  , Mont::unsafe_make( [ 0x4ced5d65 , 0x2c4d2129 , 0xbc7489e1 , 0x95359c50 , 0xc92c01fd , 0xc7b01a56 , 0x3ba70782 , 0x1cfe2db6 ] )
+  , Mont::unsafe_make( [ 0x3080742c , 0xbfb93d2d , 0x972aa78b , 0xd5f16209 , 0x1557fea5 , 0x49c0fbf6 , 0xdfd16107 , 0x2e39dbb1 ] )
+  , Mont::unsafe_make( [ 0xa5fc6cbd , 0x676ba9e4 , 0x9d37414b , 0x39acdcc4 , 0x46982343 , 0x61d5146e , 0xf7915d92 , 0x246e98cd ] )
+  , Mont::unsafe_make( [ 0x9201f0d0 , 0x0d89689d , 0xbf85b588 , 0x1ee70a2e , 0x561f9714 , 0xb76b4be9 , 0x7f977b69 , 0x0d22cd62 ] )
+  , Mont::unsafe_make( [ 0xd6009a7c , 0xb31ccb4b , 0x8698411f , 0xef53ae2d , 0xd21db191 , 0xec6aabcb , 0x7721ff34 , 0x1943c0f9 ] )
+  , Mont::unsafe_make( [ 0xa7fd9871 , 0xd160ec86 , 0xf95bc2db , 0x4cff1b19 , 0xfc192231 , 0x47a3b0dd , 0x7a567cb3 , 0x236f74d3 ] )
+  , Mont::unsafe_make( [ 0xf5b24a32 , 0x212add62 , 0x8708fbe4 , 0xc9d4034a , 0x8c757429 , 0x24131e86 , 0xc5157cc6 , 0x195f34d4 ] )
+  , Mont::unsafe_make( [ 0x62f77ac9 , 0xa9e86930 , 0x49e69e7f , 0x55868dff , 0x5198ebe6 , 0xe24e1e11 , 0x84ee6fc9 , 0x287e3ac4 ] )
+  , Mont::unsafe_make( [ 0xa723f8d4 , 0x138edb43 , 0x362c43c0 , 0x8cd3b8bd , 0xbf2fdf16 , 0x64a828ee , 0x7defcf3c , 0x1e7d52e8 ] )
+  , Mont::unsafe_make( [ 0x98b5f68d , 0xf27afcd3 , 0xcfabf5af , 0xde6b1b48 , 0x8d6130d7 , 0x99fa70ce , 0x105b88f7 , 0x11d7336c ] )
+  , Mont::unsafe_make( [ 0xeac768af , 0x909d6fea , 0x956272eb , 0x04c928e9 , 0x2ef3ed49 , 0x61fcd3e9 , 0xb9e1747c , 0x243d460b ] )
+  , Mont::unsafe_make( [ 0x9be85d62 , 0x1e23a518 , 0xe68cd9c9 , 0x0bc812bb , 0xf15d8ce5 , 0xbd7b0f4d , 0xd3326176 , 0x08bb2ee4 ] )
+  , Mont::unsafe_make( [ 0xcff29c25 , 0xc58984b5 , 0x12aec8b0 , 0xdbe03de7 , 0xf2d0061d , 0x445ae430 , 0xb48e835e , 0x22523b7a ] )
+  , Mont::unsafe_make( [ 0x72b688e6 , 0x2980c6ad , 0x68aa8981 , 0x64dd3d46 , 0xc03353e9 , 0x64906263 , 0xca793652 , 0x22987f17 ] )
+  , Mont::unsafe_make( [ 0x4d2fd719 , 0x24df4fe7 , 0xac1b31bb , 0xb05d939e , 0xfb3c9ae8 , 0x4d443065 , 0x9c1e5560 , 0x288aa604 ] )
+  , Mont::unsafe_make( [ 0xc4d6c981 , 0x7a594ee6 , 0x2f486b58 , 0xe48a2a26 , 0x44f37a0e , 0xe04a25c6 , 0x4aba9b14 , 0x220462de ] )
+  , Mont::unsafe_make( [ 0x172a3167 , 0x9775679a , 0x5728c1e4 , 0x3c8f5b7c , 0xacb40bc0 , 0x6f08ad10 , 0x8fe65e55 , 0x1f23bde5 ] )
+  , Mont::unsafe_make( [ 0xdfcee250 , 0x35859ab3 , 0xbe75dc72 , 0xc245161d , 0x6d52b082 , 0xf179e0ce , 0x927adc1b , 0x12b0403f ] )
+  , Mont::unsafe_make( [ 0xd42190c2 , 0xe38b94be , 0x2c40ca7f , 0xee459185 , 0x84dff1da , 0xb885c522 , 0x5da9022a , 0x03fc8e80 ] )
+  , Mont::unsafe_make( [ 0x7761941f , 0x2d6ee5a1 , 0xb77b1879 , 0xc6c86245 , 0x2d31f0b3 , 0xe72b433a , 0x83c4436f , 0x27e0c368 ] )
+  , Mont::unsafe_make( [ 0xcb0eaeb3 , 0x08b98128 , 0x3a29f920 , 0x9e5c3d72 , 0x5788dbe5 , 0x619f1056 , 0x7c75041d , 0x2702a882 ] )
+  , Mont::unsafe_make( [ 0xfed8bca3 , 0x450ba253 , 0xad39425e , 0x04ca3b57 , 0xcd64310e , 0xf82c61f3 , 0x89ee2ab3 , 0x021db84f ] )
+  , Mont::unsafe_make( [ 0x36f9c8e5 , 0x1367bffe , 0x90fe560a , 0xfd7d244c , 0xbcd71a6c , 0xd4abdedf , 0x5ffa1654 , 0x0b27a4a6 ] )
+  , Mont::unsafe_make( [ 0xb064a616 , 0x47661e5f , 0x06136d74 , 0x01e11a20 , 0x35e9f8e6 , 0x56b46204 , 0xf31a3276 , 0x1718c0dc ] )
+  , Mont::unsafe_make( [ 0xe34ebd4d , 0xe053869d , 0x3ef471bd , 0x332533de , 0x464d6139 , 0x241fc279 , 0xe6f6c88d , 0x0b1f515f ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x233c57e5 , 0x011fc4fd , 0x45b5570e , 0xa60e5914 , 0x4cefa7ef , 0xeb804acf , 0xa482f045 , 0x2f3bc8a0 ] )
+  , Mont::unsafe_make( [ 0x7fb27d60 , 0x0ff143d9 , 0xce553a00 , 0xe9b3b015 , 0x64b96635 , 0x84f0c289 , 0x8757cc07 , 0x0cc6bcaf ] )
+  , Mont::unsafe_make( [ 0xca0b01b3 , 0x6f6652b0 , 0x9241eaa5 , 0xa230abf2 , 0xb162f003 , 0xab0792e8 , 0x80622fbf , 0x2365b314 ] )
+  , Mont::unsafe_make( [ 0x7643eb91 , 0xc842ac50 , 0xa8c4e2e7 , 0x878d6392 , 0x2499462c , 0xe6ef68bc , 0x97ab3a04 , 0x2d88613a ] )
+  , Mont::unsafe_make( [ 0x9f5cbcb1 , 0x7fb56058 , 0x644ef3a8 , 0x60f9c419 , 0xf46b5e06 , 0x97553895 , 0x359ea0ce , 0x012ffbc7 ] )
+  , Mont::unsafe_make( [ 0x3de02152 , 0x0eeb5d44 , 0x5fa7ae56 , 0x20230602 , 0xffc96d02 , 0x120e8861 , 0xb2ab9889 , 0x1520b917 ] )
+  , Mont::unsafe_make( [ 0x582718d0 , 0xddca3ac5 , 0xa3f90646 , 0x097f0b04 , 0x76b4ee53 , 0x380f3540 , 0xc40d63b8 , 0x19ff6a4d ] )
+  , Mont::unsafe_make( [ 0xef8d0bfd , 0x340daecd , 0x0710c840 , 0xaca3d3b3 , 0x25fafdff , 0xc08df17a , 0xdee239c8 , 0x00dc035a ] )
+  , Mont::unsafe_make( [ 0x8f8b65ae , 0x6835fef8 , 0x965ce975 , 0x17461d93 , 0x3bd32df4 , 0x54e0ac72 , 0x0a40d477 , 0x1e853a35 ] )
+  , Mont::unsafe_make( [ 0x626beaad , 0x78a13d9b , 0xb2bf0087 , 0xc2de9d0f , 0x1571a5fd , 0xd3eee555 , 0x3bc7f469 , 0x182a969d ] )
+  , Mont::unsafe_make( [ 0x35f3bdb5 , 0xa21e2c1b , 0x618c5c35 , 0x397f98ef , 0xfac341fb , 0xd3daf783 , 0x8f2502df , 0x04c8a959 ] )
+  , Mont::unsafe_make( [ 0x77edcfb1 , 0x60007378 , 0x894a4e39 , 0x3a217d6c , 0x8b93a6e6 , 0xb0a5bb15 , 0xbeb966d7 , 0x17d783af ] )
+  , Mont::unsafe_make( [ 0xfe411132 , 0x492857c3 , 0x1c23defd , 0x8c15d1be , 0xe92b887c , 0xe97cad2e , 0x60cf8fb5 , 0x1de91984 ] )
+  , Mont::unsafe_make( [ 0x2d385463 , 0x193a769c , 0x05960e5f , 0x697574e6 , 0x8e210cf5 , 0xb556d123 , 0xd55e42f0 , 0x1237ca6c ] )
+  , Mont::unsafe_make( [ 0x0aa402e9 , 0x40184aa5 , 0xa7529705 , 0xf882e1b1 , 0x3f5522a7 , 0x4d5dad15 , 0x6080835e , 0x0c0968c5 ] )
+  , Mont::unsafe_make( [ 0xc67ffd84 , 0xbe371dbe , 0xded6a444 , 0x923bdc46 , 0x08fd03c6 , 0x7a1393fa , 0x8b24a99b , 0x24df28ac ] )
+  , Mont::unsafe_make( [ 0x9762cfbe , 0x7596024b , 0x0c00fa6b , 0x8c080069 , 0x5e444dc4 , 0x35c37ab5 , 0x44cd8eee , 0x21f24215 ] )
+  , Mont::unsafe_make( [ 0x3cfee00e , 0x7c31760d , 0x36fef0f9 , 0xce85ae6f , 0x943ff858 , 0x6bf1e6a0 , 0x64411c4e , 0x1e093337 ] )
+  , Mont::unsafe_make( [ 0x945d6863 , 0x1d7f5c74 , 0xbb4b90a1 , 0x7de9e039 , 0xb169b610 , 0x25fb55f9 , 0x2815cfc6 , 0x1ae7aa70 ] )
+  , Mont::unsafe_make( [ 0x4208af2d , 0x10f5abcc , 0xc2b10abf , 0xe4955e55 , 0xe8e339cb , 0x310990d6 , 0xf3c8c226 , 0x0e377323 ] )
+  , Mont::unsafe_make( [ 0x7427ae9f , 0x9dc7a8b1 , 0x032dd4a2 , 0xde9806fc , 0xd65fa99d , 0x3094c455 , 0xa7f16b23 , 0x25fcd761 ] )
+  , Mont::unsafe_make( [ 0xa0fb8e6a , 0xb814c3bf , 0x730d8540 , 0xb9d5d3e1 , 0xf2199ca7 , 0xb51a04ac , 0xf74677d9 , 0x2ce28e59 ] )
+  , Mont::unsafe_make( [ 0x0b708de1 , 0xf38734ce , 0x73143a2e , 0xc9d6d49d , 0xa2eeadbd , 0xdf86378b , 0xe7c192b5 , 0x226c23f2 ] )
+  , Mont::unsafe_make( [ 0x4f002fa3 , 0xefb91f71 , 0x5a0ac644 , 0x95e19259 , 0x05688fc2 , 0x8f1c715f , 0xccff2800 , 0x05e6b916 ] )
+  , Mont::unsafe_make( [ 0x48a816c5 , 0xc5d310e3 , 0xd96addfe , 0xa42a3ab1 , 0xf7ca76b5 , 0xbcb75c2b , 0xcbc73e72 , 0x1d5390a0 ] )
+  , Mont::unsafe_make( [ 0xf147a717 , 0x786649bb , 0xccab3717 , 0xab5b36c2 , 0xae6dc8a4 , 0x384c1054 , 0xcb0b2b25 , 0x25059e97 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x57b3084c , 0x265e5841 , 0xa7f2138d , 0x551d98e7 , 0x293598d2 , 0x34c6ecff , 0xe3d2214f , 0x0af28ff2 ] )
+  , Mont::unsafe_make( [ 0xb0afcf64 , 0x6ba53a1d , 0xe318db3e , 0x70c6eca3 , 0x6059fd1e , 0x9eff9261 , 0x1beea9fd , 0x19899906 ] )
+  , Mont::unsafe_make( [ 0x553ffe2e , 0x0e359286 , 0x8fa8a343 , 0x7e29e5b0 , 0x4fb488bf , 0x0c981859 , 0x3b5366b6 , 0x27aee17d ] )
+  , Mont::unsafe_make( [ 0xa6cc2d60 , 0xe344b338 , 0x679f5f10 , 0xce217875 , 0x96a688f7 , 0xbba78c34 , 0xd79c40e0 , 0x03f32315 ] )
+  , Mont::unsafe_make( [ 0xa25f2d03 , 0x773ee8e1 , 0x8b624d34 , 0x6392cac3 , 0x3b381af7 , 0x304d0a4e , 0x3eaca3df , 0x2bfdddcc ] )
+  , Mont::unsafe_make( [ 0x9c59b50f , 0x72375c0c , 0xc39f9bf1 , 0x26870593 , 0x260ea551 , 0xf49a45a4 , 0xb6c751a5 , 0x03943525 ] )
+  , Mont::unsafe_make( [ 0x5fc0401c , 0x3551bca5 , 0x3bfdb6c6 , 0x46a003c8 , 0x7b69ed31 , 0x5e4e6ddf , 0x6e8ef85d , 0x10f89f72 ] )
+  , Mont::unsafe_make( [ 0x41a4cb98 , 0x147ebc31 , 0xe01981fa , 0x83c24e01 , 0x00b7dbe0 , 0x88cc4073 , 0x9e47e2ca , 0x26c17a60 ] )
+  , Mont::unsafe_make( [ 0xbd41e298 , 0xe948ef3a , 0x7f1a0a1b , 0x9006ba92 , 0x62fd61ac , 0x8b229541 , 0xf0f2ea47 , 0x27b5900c ] )
+  , Mont::unsafe_make( [ 0x8659edac , 0x54a6b8fe , 0xea6c98e9 , 0xbb82e4c7 , 0x2b277524 , 0x2c79d22e , 0xda1d7a8b , 0x14b7d814 ] )
+  , Mont::unsafe_make( [ 0xd92784b2 , 0x71f10eb0 , 0x4e0d09aa , 0xc6d7da0f , 0x671c3272 , 0x5d11bc82 , 0x30f4469b , 0x214dbb7c ] )
+  , Mont::unsafe_make( [ 0xff3c8e6e , 0xe76b4b4e , 0x947dfd4d , 0x1b3c0aef , 0x88925ae7 , 0x29f05549 , 0x9aec521b , 0x26187af4 ] )
+  , Mont::unsafe_make( [ 0xffb4507a , 0x26301657 , 0x7da45fcf , 0xd4f6eeb5 , 0x74a068ba , 0x089e1ec8 , 0x0f117094 , 0x048597e2 ] )
+  , Mont::unsafe_make( [ 0x22700a70 , 0x1cb6eb9d , 0x68e45775 , 0x5ed4e983 , 0xfae9e38e , 0x1b923059 , 0x489220f5 , 0x04aada72 ] )
+  , Mont::unsafe_make( [ 0x68662d7a , 0xb249d891 , 0x221850c2 , 0x210e7768 , 0x5a3d10dd , 0x3cc7865e , 0xdbade3f1 , 0x2e1c4c74 ] )
+  , Mont::unsafe_make( [ 0x3f9ee107 , 0x84b61945 , 0x2081c981 , 0x5e4a7473 , 0xcbcfd408 , 0x09a1320d , 0xf65467c4 , 0x27e1292d ] )
+  , Mont::unsafe_make( [ 0xd9e7a2b3 , 0x2349f522 , 0x775c9723 , 0x2077a2ba , 0xebafbaef , 0x16cd8b7a , 0xbab4f9b8 , 0x1cb4282c ] )
+  , Mont::unsafe_make( [ 0xdfc6a67c , 0x3dc694cc , 0xf53dbc31 , 0xc6401b06 , 0xd57751b8 , 0x54234719 , 0x19a51bf2 , 0x07346e09 ] )
+  , Mont::unsafe_make( [ 0x39f211f2 , 0xefaec6dc , 0x64a27a8b , 0xceae9b76 , 0xb8509ece , 0x8a40da36 , 0x36f3011a , 0x0e9f666a ] )
+  , Mont::unsafe_make( [ 0x6cf92f2c , 0xa7e42c16 , 0xf06d3bf3 , 0xaa236a4c , 0xbc3ab8b9 , 0x934e4f58 , 0x68866108 , 0x27ad2c93 ] )
+  , Mont::unsafe_make( [ 0x73bc5e25 , 0x5c8262ff , 0x93559a9e , 0xf54ffaf2 , 0x5aaa81e0 , 0xfdab9685 , 0x9e656ed9 , 0x1c7258bb ] )
+  , Mont::unsafe_make( [ 0xc13964d5 , 0x6d6a6d31 , 0x9d70c3f1 , 0x748c264e , 0xb62a1113 , 0x4d54c3a0 , 0x5afa9d1d , 0x069af75d ] )
+  , Mont::unsafe_make( [ 0x72f0495c , 0xa67a7f1b , 0xab3a447d , 0x9a613d6f , 0xfc816aad , 0x5a800510 , 0x4fabe59f , 0x1854292b ] )
+  , Mont::unsafe_make( [ 0x6d25b274 , 0xd0e83a53 , 0xb4383b7d , 0x62421c05 , 0xeba8c8f1 , 0xcdb3aedd , 0xf6615af4 , 0x25fe98e9 ] )
+  , Mont::unsafe_make( [ 0x341da691 , 0xe2406fec , 0xd7f830a9 , 0x5a5763eb , 0xe3ac83e2 , 0xd3b74bd7 , 0x849d9f53 , 0x2c3b0756 ] )
+  , Mont::unsafe_make( [ 0xb638bc6d , 0xd024d34a , 0x38bf5295 , 0x71a488f6 , 0xdc077169 , 0x27e3ac45 , 0xb5da8301 , 0x2e82d5ef ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x54b5a4a3 , 0x402d74c7 , 0x22a7c713 , 0x582b5eb8 , 0x53d6504f , 0x9281e2b4 , 0xf0b5151f , 0x10d83415 ] )
+  , Mont::unsafe_make( [ 0x1284ac67 , 0x9fad0fc3 , 0xbc25a4df , 0xdd0cc192 , 0x64e1e5b2 , 0xbacef1e9 , 0x375b584c , 0x23ab0043 ] )
+  , Mont::unsafe_make( [ 0xf408e6b9 , 0x66c5c261 , 0x36ebc075 , 0x9ba6bd52 , 0xb4fd00e8 , 0xd0d724dd , 0xa6c69741 , 0x04ddc441 ] )
+  , Mont::unsafe_make( [ 0x90c42977 , 0x34cd7e86 , 0xdf856780 , 0xddb2c064 , 0x419b9721 , 0x46f01393 , 0x604ccd67 , 0x2a312fbd ] )
+  , Mont::unsafe_make( [ 0xbce847f9 , 0x60e30377 , 0x13d03872 , 0xc846d17a , 0x5f9151a5 , 0x50a77293 , 0x6eb3c9a8 , 0x25fb0bd2 ] )
+  , Mont::unsafe_make( [ 0xfc26f52f , 0xc58f4098 , 0x049f50f2 , 0x89af9c76 , 0x1c0c6c44 , 0xaaf34ea2 , 0xe64b45d3 , 0x2b3c597b ] )
+  , Mont::unsafe_make( [ 0x53668892 , 0x0bf9a735 , 0x48c9373d , 0x93dd411f , 0x6e1ef431 , 0x7c0765c0 , 0xe6cdeb7b , 0x060781d3 ] )
+  , Mont::unsafe_make( [ 0x4a15a692 , 0xd125827f , 0x7dd5fdd7 , 0x2acce4db , 0x19029484 , 0xf5b9915a , 0x61783337 , 0x0fdff222 ] )
+  , Mont::unsafe_make( [ 0x5669eb4e , 0x2e842259 , 0xdfdf1093 , 0x56fdba1d , 0xcdf74d5a , 0x51a28004 , 0xa1c5672b , 0x22300c52 ] )
+  , Mont::unsafe_make( [ 0x0d98dd3a , 0x43b1d7ab , 0x77f389d2 , 0x887431ac , 0x06dfc452 , 0x46c3bafd , 0x3021ffbd , 0x21700518 ] )
+  , Mont::unsafe_make( [ 0x5966af67 , 0x9cb52807 , 0x3c3feccb , 0xdbb383a2 , 0xf6ebe209 , 0x9e78d326 , 0x053dd81d , 0x303ba6d3 ] )
+  , Mont::unsafe_make( [ 0x48e0ea22 , 0x1881475a , 0x7b286cde , 0x3bfa119d , 0x7b62de45 , 0xc36873b9 , 0xf0bf2059 , 0x1887f241 ] )
+  , Mont::unsafe_make( [ 0x8892c91e , 0x35e9acf0 , 0x65bef382 , 0x236c2ae5 , 0x99909007 , 0x5be8ffbf , 0x8d471e3b , 0x0f118b44 ] )
+  , Mont::unsafe_make( [ 0x2debf2bf , 0x1141d481 , 0x85734ba0 , 0x9b1171fa , 0x6e9a38be , 0xa12832d0 , 0x3845ff9e , 0x0415f29f ] )
+  , Mont::unsafe_make( [ 0x5cdb218a , 0x9d2d00d5 , 0x208fe9c9 , 0x48789c2e , 0x4b4322b0 , 0x300c30e6 , 0x954e24d2 , 0x19018340 ] )
+  , Mont::unsafe_make( [ 0xd91c49c0 , 0x1a878350 , 0x347ed26a , 0x33e6eb0f , 0x29f3230f , 0x54bc6c91 , 0x3775c99d , 0x1f3a7001 ] )
+  , Mont::unsafe_make( [ 0x9db95e30 , 0xc7931e70 , 0x832674ca , 0xc70efd9c , 0xf1450115 , 0xfc99da9e , 0x11cb7687 , 0x18886c40 ] )
+  , Mont::unsafe_make( [ 0xcab34b3c , 0xa6c8b496 , 0xe42840a9 , 0xc92eaad0 , 0x56ce4258 , 0x0d08a018 , 0x6ae13a57 , 0x2dce8384 ] )
+  , Mont::unsafe_make( [ 0xae699a0e , 0x01a95a34 , 0xf9965af2 , 0xd1bbe910 , 0x4284c056 , 0xca269699 , 0x2dabf665 , 0x25257640 ] )
+  , Mont::unsafe_make( [ 0x0ce023e7 , 0x9f97f3d3 , 0x76e9e3b0 , 0x13f49fcd , 0x059c74bc , 0x5d96b765 , 0x113a6ff8 , 0x0774b880 ] )
+  , Mont::unsafe_make( [ 0xb775af1c , 0x0df33b3f , 0xc82a2a37 , 0x8be92711 , 0x213365da , 0x987c558e , 0x3ee56839 , 0x07e37d12 ] )
+  , Mont::unsafe_make( [ 0x2c6560a8 , 0x1a3f9fe8 , 0x789fe1c8 , 0xa20e8f19 , 0x99fc92ff , 0x7b2ac6a3 , 0xbbbf0c26 , 0x02fd4bcd ] )
+  , Mont::unsafe_make( [ 0x6ab84e79 , 0xa93352d6 , 0x999a3537 , 0xa31a4e2d , 0x371b6e9d , 0x4987282b , 0x2715d0f2 , 0x16674d96 ] )
+  , Mont::unsafe_make( [ 0x741deeb4 , 0x1899cc2b , 0x534659fd , 0x5b3e32d8 , 0xe0f10b03 , 0x357cc81d , 0x7e025873 , 0x127bef1e ] )
+  , Mont::unsafe_make( [ 0x80883502 , 0xd276cb6b , 0x86051fa0 , 0xd50bafda , 0xfbc804ec , 0xe5b8bff2 , 0x31cd6eb9 , 0x1b33b991 ] )
+  , Mont::unsafe_make( [ 0x29f6d306 , 0xda5decf4 , 0xe9ed5b65 , 0xd45d763c , 0xbff8c532 , 0x7cf90660 , 0xf02ab712 , 0x2348aacd ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x0c468084 , 0x28f6953a , 0xc1a2e140 , 0x47a0d033 , 0x5718b637 , 0x2efdb2da , 0xb9515c09 , 0x25b721d9 ] )
+  , Mont::unsafe_make( [ 0x0b31f384 , 0x68759541 , 0x42d7b935 , 0xd588999b , 0x4039ee4f , 0xcf4c7084 , 0xfbd7bd38 , 0x2e5a2078 ] )
+  , Mont::unsafe_make( [ 0x8393194e , 0xd573828d , 0x31e2c3cc , 0x47dff805 , 0x3e0dee06 , 0x5d756f75 , 0xb7f12883 , 0x2acbf0e1 ] )
+  , Mont::unsafe_make( [ 0x7a670c4f , 0x47e9e4da , 0xad354f54 , 0xe448efe4 , 0x33ac6615 , 0xf2b38ab0 , 0xb43a509b , 0x0f526322 ] )
+  , Mont::unsafe_make( [ 0xef1e96b5 , 0xe5386994 , 0x6368706a , 0xa72b00ce , 0xb8517316 , 0x9e8db832 , 0x2722bfdd , 0x09915832 ] )
+  , Mont::unsafe_make( [ 0xae1417d5 , 0x45627baa , 0x43478ee1 , 0xd190eeb0 , 0x8221f640 , 0x636bf56d , 0x6756dbcf , 0x1ffafd2f ] )
+  , Mont::unsafe_make( [ 0x1e5d9a8e , 0xddb98e10 , 0x35197484 , 0xc2f47659 , 0x7b1e0508 , 0x4aa1460d , 0xc4c3f8f2 , 0x07004087 ] )
+  , Mont::unsafe_make( [ 0x51448ca9 , 0xa2cac9c9 , 0xc3e6f891 , 0x975ca90f , 0x32516318 , 0x0ab53915 , 0x7a6a4b0d , 0x281c34a4 ] )
+  , Mont::unsafe_make( [ 0x5e8b013b , 0x6fbbc4d1 , 0xda0a9924 , 0x6c08bbb1 , 0x9b738ebc , 0x4c98d733 , 0xbc9a5eae , 0x02b4543c ] )
+  , Mont::unsafe_make( [ 0x8323b679 , 0x53dadcf2 , 0x6c811950 , 0x465499a6 , 0xae6f9e93 , 0x039e5624 , 0x35f9f5e1 , 0x0829b9ae ] )
+  , Mont::unsafe_make( [ 0xd192ba80 , 0x9c2150f9 , 0x2b551e14 , 0xeff71649 , 0xf899ecfc , 0xc0375cb7 , 0xd3e70c68 , 0x06957229 ] )
+  , Mont::unsafe_make( [ 0xac234865 , 0x218750ce , 0x1a015933 , 0x456b503b , 0x97ad1254 , 0x8565b64a , 0xfb59d9ea , 0x2883a1d1 ] )
+  , Mont::unsafe_make( [ 0x660bc4c1 , 0x6fdf59f0 , 0x0c4ee458 , 0x66bc478d , 0x12fa5b18 , 0x8b3a2740 , 0xdf6f0021 , 0x1ab8d35a ] )
+  , Mont::unsafe_make( [ 0x09f3e2e8 , 0x36d4ae6d , 0x15bbe9cf , 0x76c99889 , 0x889a4579 , 0x92048095 , 0x20520aca , 0x2bbe8146 ] )
+  , Mont::unsafe_make( [ 0x3de1485b , 0x795de6f8 , 0xb15ef64a , 0x7459fe79 , 0x34fb9578 , 0x501d29a8 , 0xe211d5bd , 0x007c673f ] )
+  , Mont::unsafe_make( [ 0x21a36ee0 , 0x6e67e67b , 0xabcc297c , 0x3dfa0e6f , 0x7c7935c1 , 0x594f952f , 0x07fab702 , 0x00c97fa8 ] )
+  , Mont::unsafe_make( [ 0x85c4becb , 0xf12c61c8 , 0xf43c4a55 , 0xcada9039 , 0x7cb99a64 , 0xa340494b , 0x5c9af008 , 0x23438d1e ] )
+  , Mont::unsafe_make( [ 0xbb9ac42e , 0xad4c32a3 , 0x81cd27dd , 0xeae2a4eb , 0xcc3f50ae , 0x5802dfe1 , 0x3ba8c7ae , 0x1ed38fc9 ] )
+  , Mont::unsafe_make( [ 0x4d17ab65 , 0x18a9a8cc , 0x0b06d279 , 0x27f821d8 , 0xb10d506e , 0x00406f83 , 0xffc893f7 , 0x1ca30053 ] )
+  , Mont::unsafe_make( [ 0x5b37eec6 , 0x4fc8f6e6 , 0xa950f518 , 0xc59c1bd0 , 0x701f52c0 , 0x8dd3fbd9 , 0x3194b0ce , 0x0a874a45 ] )
+  , Mont::unsafe_make( [ 0x7f25de84 , 0xa71fcf1c , 0x4c26b956 , 0xa362661c , 0xa93336d8 , 0x43ddc782 , 0x7cde3a12 , 0x06add2bf ] )
+  , Mont::unsafe_make( [ 0xccf32c58 , 0x0ec4802e , 0x887c4930 , 0x83508152 , 0xb5fbb8bb , 0x2d72e2a3 , 0x5e5cbd83 , 0x09537773 ] )
+  , Mont::unsafe_make( [ 0x050fc9d8 , 0x8e103c59 , 0x83f84574 , 0xb64a51e3 , 0x953ff87d , 0x4310c86c , 0xddc5cc79 , 0x2f878e93 ] )
+  , Mont::unsafe_make( [ 0x75efc8c2 , 0xa63efb91 , 0x706b430f , 0x90d868fd , 0x842507f5 , 0x2170f2ce , 0x4deac37d , 0x04f88030 ] )
+  , Mont::unsafe_make( [ 0x1cc52d87 , 0xbb04657e , 0x2f835b18 , 0x5c8214b2 , 0xe53e2c63 , 0x8d0f9e9c , 0xcab3d934 , 0x1626965e ] )
+  , Mont::unsafe_make( [ 0xe87e2554 , 0xf4066e68 , 0xa27b9c7b , 0xc746f5b8 , 0x13dbb540 , 0xd85cc769 , 0xe2d1f2e1 , 0x05fb4ed3 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x3eec62a5 , 0x73557442 , 0x755f2621 , 0x35291e6a , 0x6651ef42 , 0xb640cd2f , 0xa769229b , 0x07356e08 ] )
+  , Mont::unsafe_make( [ 0xf0c04c11 , 0x27a9c4fb , 0xcd9c6dd3 , 0xcaa95ed5 , 0x81783d71 , 0x532520db , 0xef86ccf4 , 0x1ef081d8 ] )
+  , Mont::unsafe_make( [ 0x70a9a4f8 , 0xc3b62c5b , 0x2e5a8d9d , 0x65b089b8 , 0x7d1fbeaa , 0x72d28f30 , 0x35b235d2 , 0x2384b055 ] )
+  , Mont::unsafe_make( [ 0x5b55e583 , 0x3057586c , 0x5f39f775 , 0x113e85d7 , 0xdaa38788 , 0x19fab593 , 0xb2a16501 , 0x27aaf971 ] )
+  , Mont::unsafe_make( [ 0x087c1e5d , 0x4eb9f331 , 0x5def65d2 , 0x7af3705b , 0x78a94f6e , 0x4abddbc0 , 0x225ce31b , 0x1e01c8d2 ] )
+  , Mont::unsafe_make( [ 0x86b2d3c4 , 0xea32c75c , 0x4759d7a3 , 0x0835b8ff , 0x08f621b3 , 0x9925069a , 0x8b135167 , 0x013cdab7 ] )
+  , Mont::unsafe_make( [ 0xeab97514 , 0xee0af39e , 0x3344bdd7 , 0x120e5dd4 , 0x45795d51 , 0xa6bf6150 , 0xea3d94ec , 0x077cd8d5 ] )
+  , Mont::unsafe_make( [ 0x139be1ff , 0x8060c486 , 0xe2cbfd38 , 0x8118103e , 0x46622583 , 0xf5ecd4dc , 0x5551c758 , 0x2ef27928 ] )
+  , Mont::unsafe_make( [ 0x334539f5 , 0x76cdf9c4 , 0x605b1359 , 0x42d4819e , 0x405aa21b , 0xcdbabf45 , 0xa4167be7 , 0x087f9789 ] )
+  , Mont::unsafe_make( [ 0xda71cd5c , 0x04de5350 , 0xea64c7c0 , 0xb68971eb , 0xb8acb36c , 0x5625999e , 0x084444fe , 0x03a6c640 ] )
+  , Mont::unsafe_make( [ 0xbb09feeb , 0x8d5be1db , 0x3b7490d2 , 0x984ad126 , 0x5454d7ec , 0x13862d4d , 0x04a449fc , 0x060f6758 ] )
+  , Mont::unsafe_make( [ 0xb5f79002 , 0xa7f4d3be , 0xb6c042f2 , 0x20f3ce37 , 0xb332089b , 0x1b7f1250 , 0xb805ce2c , 0x19dc2238 ] )
+  , Mont::unsafe_make( [ 0x43fc3eb6 , 0xfa54086f , 0xe1da14fa , 0x31e5fc5b , 0x1cabb5f2 , 0x65c0fcbc , 0x9a09e39b , 0x119d0859 ] )
+  , Mont::unsafe_make( [ 0x1754d48b , 0xcfd2deab , 0x3fc69d2f , 0xfca312f3 , 0xd8049ade , 0x393afb98 , 0xf47e6647 , 0x1f63c1bd ] )
+  , Mont::unsafe_make( [ 0xa4deab29 , 0x2dca9652 , 0x21f80447 , 0xac4512ae , 0x17cc2fe0 , 0x31a4c423 , 0x0bc11c44 , 0x287ee669 ] )
+  , Mont::unsafe_make( [ 0x5ebcd93f , 0x2ce083e9 , 0x1197f5cd , 0x549cf20d , 0x7c202eab , 0x39f75258 , 0x9dda1ee6 , 0x20461e54 ] )
+  , Mont::unsafe_make( [ 0x86f7c387 , 0x96bf58d2 , 0xd401da72 , 0x47e5a8cf , 0x52435571 , 0x399f00bc , 0x1b49aa8f , 0x2398ac75 ] )
+  , Mont::unsafe_make( [ 0xc2adaf65 , 0x4e09f74e , 0x0069b064 , 0x98a29921 , 0x557deb03 , 0x3102b0c4 , 0x08df6087 , 0x27e83839 ] )
+  , Mont::unsafe_make( [ 0x228115f5 , 0x073d3b07 , 0x7eda0856 , 0xb93fdd28 , 0xa482bd56 , 0x59a82815 , 0x7aed2e7a , 0x1ccf300c ] )
+  , Mont::unsafe_make( [ 0xecccca70 , 0x006423e9 , 0x4b556875 , 0x231ade24 , 0xce168426 , 0xb20d95c0 , 0xfd07523f , 0x1579dbca ] )
+  , Mont::unsafe_make( [ 0xf9189b27 , 0x38757eeb , 0x4f4f43d6 , 0x22771281 , 0x4be7b297 , 0xa07a9083 , 0x9a037933 , 0x2ff7032d ] )
+  , Mont::unsafe_make( [ 0x68a1d623 , 0xfa7df0b2 , 0x85663445 , 0x0846222a , 0x50263b02 , 0x2326c069 , 0x9fac1f6a , 0x0bc3acb9 ] )
+  , Mont::unsafe_make( [ 0x66e586de , 0x3176b00f , 0x5555481c , 0x78d96ba5 , 0xfd9b5544 , 0x0611d4f6 , 0xcde870cb , 0x090ca41a ] )
+  , Mont::unsafe_make( [ 0x5d756661 , 0x4a561829 , 0x1ab0eea2 , 0x6d13fda6 , 0xf0eb8f05 , 0xa7d28f3b , 0xe5ddf5dc , 0x2e6ce1cb ] )
+  , Mont::unsafe_make( [ 0x5c970d24 , 0xdd1965c2 , 0x50e068f6 , 0xd7339a4c , 0xc9a52856 , 0xa483b239 , 0x511cd6f3 , 0x28291fe3 ] )
+  , Mont::unsafe_make( [ 0xd9416d7e , 0x1a2ab44e , 0x1040a4b4 , 0x242054ca , 0x07be1bf8 , 0x41374522 , 0x180a7f5e , 0x13fe933a ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xec23b1e7 , 0xe81d0b03 , 0x7fcf7502 , 0x12b5cde8 , 0xe66909b0 , 0xf81dcc6c , 0xe47daf5d , 0x1eefbea7 ] )
+  , Mont::unsafe_make( [ 0x758aebbd , 0xef294aac , 0x6e570781 , 0x28931e6b , 0xa78ca26c , 0x1919741c , 0x9794f1b9 , 0x164f1f23 ] )
+  , Mont::unsafe_make( [ 0xa50f1b49 , 0xe31c2e71 , 0x51c25521 , 0x84f3e949 , 0x7275823f , 0xae5a1791 , 0x5e74fb45 , 0x1d0e7ca9 ] )
+  , Mont::unsafe_make( [ 0x99a67c20 , 0x8aa3ed28 , 0xb47e44d6 , 0x4aec1fbf , 0xbf6be904 , 0x19c43f4a , 0x3fe9f610 , 0x28248acd ] )
+  , Mont::unsafe_make( [ 0x31588c43 , 0x7ca37ccb , 0x52bb805b , 0x169f68e2 , 0x8a0cd576 , 0x3ad59077 , 0x974df394 , 0x145e22dd ] )
+  , Mont::unsafe_make( [ 0xac4e2321 , 0x835f7e5f , 0x3395e111 , 0x2f84dc46 , 0xea7d65c8 , 0x2936436a , 0x9c707371 , 0x0feddae6 ] )
+  , Mont::unsafe_make( [ 0xd86678fd , 0x0b2b019c , 0xe09f5fa5 , 0xd6e2ddbf , 0x3bb501fe , 0x98540a63 , 0x00c2db34 , 0x1c83f808 ] )
+  , Mont::unsafe_make( [ 0xa2fbf3ab , 0x4a91a9dc , 0xd8f798e8 , 0x9270bc88 , 0xb4ff22a3 , 0xddfca856 , 0x55711b49 , 0x2950c1bc ] )
+  , Mont::unsafe_make( [ 0x96d568b6 , 0xdcb1b980 , 0x36683f34 , 0x1eae4a0d , 0x608b6deb , 0x940ba0d6 , 0x88c8cbba , 0x208e59cb ] )
+  , Mont::unsafe_make( [ 0xc9104c27 , 0x90384e5b , 0x806f55cf , 0xbbc7a45f , 0x60627d2b , 0x97189606 , 0x1a78c268 , 0x00680fcc ] )
+  , Mont::unsafe_make( [ 0x8bfd5c00 , 0x264ed399 , 0x2b01c134 , 0xe0e46874 , 0xdb040a41 , 0x43d931fc , 0x7e1d7893 , 0x2735d070 ] )
+  , Mont::unsafe_make( [ 0xc3ebd452 , 0xba7447d1 , 0xd9c5b2ec , 0x160e7f74 , 0x2110cc5c , 0x7df10779 , 0x877ab7ef , 0x21d904c5 ] )
+  , Mont::unsafe_make( [ 0xe3b39689 , 0x8ffce944 , 0x9cd72e26 , 0x35d45549 , 0xc8949f88 , 0xf116d21c , 0x6d1f5bed , 0x2c67f867 ] )
+  , Mont::unsafe_make( [ 0xd02bb67a , 0x9f1d2092 , 0x8c4e9172 , 0x95c67cca , 0x42b32cf4 , 0xcfa1f59d , 0x546d1cfb , 0x0a05b67e ] )
+  , Mont::unsafe_make( [ 0x83fde1d6 , 0x2c21b736 , 0x5003efa3 , 0xc5c805d6 , 0xf1601238 , 0x7992e2a1 , 0xf2b3f084 , 0x1e13b1a4 ] )
+  , Mont::unsafe_make( [ 0xed9cd5b3 , 0x969a9bf9 , 0xded21257 , 0x13f96ae5 , 0x270ace52 , 0x8c4534a6 , 0xf9505165 , 0x27389906 ] )
+  , Mont::unsafe_make( [ 0xd83bb81d , 0x34c8996b , 0x8ec9bd3c , 0x3e9ac78b , 0xeef42ad9 , 0xdd7c5830 , 0x9e9288f0 , 0x0094f46f ] )
+  , Mont::unsafe_make( [ 0xf19c7414 , 0x44d7be6c , 0xfb09ae4a , 0x64c3adec , 0xc74a45d0 , 0x3c65ebf6 , 0x81ca0434 , 0x1992fec0 ] )
+  , Mont::unsafe_make( [ 0xfde10ccb , 0x96717170 , 0xe3a72602 , 0xf9e9a1b2 , 0x78578f7c , 0x99dfcaa4 , 0x9397ae91 , 0x0e5b56ce ] )
+  , Mont::unsafe_make( [ 0xe3316196 , 0xb3996924 , 0x5e1a7183 , 0x77f0c6dd , 0xeec02c91 , 0xff92358d , 0x42391e70 , 0x2b1e659c ] )
+  , Mont::unsafe_make( [ 0x0e2864ae , 0x76ed65c3 , 0xee78f56f , 0xae5f3c42 , 0x61f7ddba , 0x77e70a66 , 0xb62436e3 , 0x01ea8125 ] )
+  , Mont::unsafe_make( [ 0xb3304b60 , 0x64bd0853 , 0x4d919588 , 0x6e6d0109 , 0x29c7416f , 0x76625d9f , 0xaf0e64eb , 0x1ff55c34 ] )
+  , Mont::unsafe_make( [ 0x08362931 , 0x8c6c5431 , 0x3fa26d39 , 0x815df5e4 , 0xdac5f6f6 , 0x5b84412a , 0xd38ab6d5 , 0x2af4e69a ] )
+  , Mont::unsafe_make( [ 0x23cf528e , 0x743dc804 , 0x2fbc992e , 0x9dd30cac , 0xf3093164 , 0x728a44d2 , 0x0be84771 , 0x08c5a4bd ] )
+  , Mont::unsafe_make( [ 0x8943355e , 0xfbf8e7c8 , 0xd8c5bd21 , 0xf4b45774 , 0x77026e85 , 0x5f50d8f6 , 0x078a022c , 0x12361462 ] )
+  , Mont::unsafe_make( [ 0xf630ce2e , 0xefd9063f , 0x392bf086 , 0x7cf2b255 , 0xb76c9a78 , 0x05a758f1 , 0xef639624 , 0x0835b6d2 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x168e252c , 0x6b66fe7f , 0x4e91c615 , 0xe6462a9c , 0x04e69c2e , 0xf3ab28ce , 0xf21a23a5 , 0x0d6204a0 ] )
+  , Mont::unsafe_make( [ 0x830cf0fb , 0x88ec4492 , 0xa52b30af , 0xcf03ce1c , 0xc94376c8 , 0x1e50cc4c , 0xc90120c3 , 0x01f97042 ] )
+  , Mont::unsafe_make( [ 0xbbb33652 , 0x1f5d3c8a , 0x414bbd20 , 0xd9608fb7 , 0xfab9cb64 , 0x07d17d08 , 0x10d7802c , 0x28124ef3 ] )
+  , Mont::unsafe_make( [ 0x5739c014 , 0x24981a01 , 0xb2f1002f , 0x7fef0e19 , 0x9591ba9f , 0xe3b3eaea , 0x99f1db2a , 0x06500186 ] )
+  , Mont::unsafe_make( [ 0xfa44ca88 , 0x944a0a59 , 0xceb804ca , 0xa6f5d752 , 0x12b9a15c , 0xd1503aeb , 0xbf891eab , 0x136e8d7e ] )
+  , Mont::unsafe_make( [ 0x109db0e8 , 0xa6ca1b90 , 0x2760b43f , 0x1cc9a6a4 , 0x9b94e3eb , 0x2575bb04 , 0x469c5d5f , 0x02aed58e ] )
+  , Mont::unsafe_make( [ 0x049cc500 , 0x70b1f998 , 0x12927677 , 0x9336b53a , 0x0bdae6f5 , 0xb4a9354c , 0x4701ec36 , 0x16e4349e ] )
+  , Mont::unsafe_make( [ 0x01a712a6 , 0x578ba85e , 0x1b175d87 , 0x59b0bef5 , 0x6f5e4fc8 , 0x125155ff , 0x3c28ebeb , 0x04aba74a ] )
+  , Mont::unsafe_make( [ 0x91005f05 , 0xc932c002 , 0xae528d0b , 0x9c6db56e , 0x27702261 , 0x22de2f59 , 0xc3d52160 , 0x2b162a71 ] )
+  , Mont::unsafe_make( [ 0x5202af34 , 0x43d7c128 , 0xcfba2fcd , 0x4fbd20e5 , 0x8bac7600 , 0x967b35df , 0x820ebd35 , 0x1d85f1cf ] )
+  , Mont::unsafe_make( [ 0xab33ec6a , 0xee402baa , 0x2c60f27f , 0xd23a0eab , 0xed9c5cca , 0x20b0a7e2 , 0x39f0ed1d , 0x20d5ce29 ] )
+  , Mont::unsafe_make( [ 0x4f5d9e55 , 0x4d3b3c1b , 0xd795fd82 , 0x45a99703 , 0x44289090 , 0xf9adcb4e , 0x7ddea54f , 0x058e1760 ] )
+  , Mont::unsafe_make( [ 0x774200b1 , 0xe28093ba , 0xd6c13c90 , 0x7f6a457a , 0x1e8ea1b7 , 0x7dcd5792 , 0x084b8efa , 0x12261d45 ] )
+  , Mont::unsafe_make( [ 0xfe50b1d0 , 0xb63800eb , 0xac44b682 , 0xcb9b91fc , 0xcc3ca6e5 , 0x76bc75c5 , 0x31ab95d4 , 0x1ab9a7ad ] )
+  , Mont::unsafe_make( [ 0x8e5d44cf , 0x2f5f805b , 0xec8ee579 , 0x77188939 , 0xbced5d92 , 0xe0642283 , 0x92f5ebe9 , 0x1156c45d ] )
+  , Mont::unsafe_make( [ 0xeb94870f , 0x57f6918b , 0xf39b97b7 , 0xb1500b15 , 0x2d0e12b1 , 0xab162d3d , 0x02ecabf0 , 0x1d3fd636 ] )
+  , Mont::unsafe_make( [ 0x7d72dcb3 , 0x5f0246a3 , 0x5787cad8 , 0x9c02c07d , 0x43cdc804 , 0xf9cbdee8 , 0x284b082e , 0x1661a3b9 ] )
+  , Mont::unsafe_make( [ 0xbf3a355f , 0x3d2463fa , 0x7dc27a47 , 0xf079ba23 , 0xfd1d3051 , 0x580c1825 , 0x28091401 , 0x1c04fe45 ] )
+  , Mont::unsafe_make( [ 0x96025a52 , 0x8c193f46 , 0x02d53f39 , 0x047a7003 , 0xacff6d62 , 0x9172c119 , 0xf05539ff , 0x2ad7e54b ] )
+  , Mont::unsafe_make( [ 0x029b03fd , 0x475ff748 , 0xf68e7b6d , 0xcd61e3cc , 0xd1fe9da8 , 0xbd16d759 , 0x4d28919c , 0x1841a8ee ] )
+  , Mont::unsafe_make( [ 0x004ab3de , 0xafa2fcdd , 0xd4520199 , 0x5331afc1 , 0x1cbb68be , 0x95e4b03c , 0x132ee0b6 , 0x0c6636ca ] )
+  , Mont::unsafe_make( [ 0x5a1cebb5 , 0x9480f8b1 , 0xe6889115 , 0xb25b2a43 , 0xa778db58 , 0x862defa1 , 0x94063ea5 , 0x0f6215ac ] )
+  , Mont::unsafe_make( [ 0x2fcc05b9 , 0x1fd62a9c , 0x8b1f3897 , 0xa3604753 , 0xfd8fd8ea , 0x77fa6b56 , 0xc02093ba , 0x19fba51f ] )
+  , Mont::unsafe_make( [ 0x23e4a96e , 0x9e5f2e39 , 0x664e526e , 0xc5a501b0 , 0x16d9863c , 0xded96da6 , 0xebcc04db , 0x00207bcb ] )
+  , Mont::unsafe_make( [ 0xfa7f19f5 , 0x1bcdcf31 , 0xec160557 , 0x47f6c79f , 0xba7be37f , 0x10d954c3 , 0x186911b2 , 0x039cc6de ] )
+  , Mont::unsafe_make( [ 0xff675dd1 , 0x57519627 , 0x15d2e11c , 0xa83a8dda , 0x07a0bd51 , 0x558886ac , 0x4a446acd , 0x26c8f863 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x405e5c13 , 0x94ec80da , 0xda1933c0 , 0xcdcd212c , 0xd29a2cea , 0xf5b3d67b , 0xc7313eeb , 0x16a590ec ] )
+  , Mont::unsafe_make( [ 0x78d106be , 0x14b89431 , 0x79903b72 , 0x4976f1e0 , 0xf1cf6d3f , 0x17fc7705 , 0x746352ff , 0x0b6887cc ] )
+  , Mont::unsafe_make( [ 0xdaadda24 , 0x2fed62c7 , 0x2b7975a2 , 0x9ade2c59 , 0x30b57445 , 0xec4f6b69 , 0x6444a593 , 0x24014613 ] )
+  , Mont::unsafe_make( [ 0xdb36c936 , 0x0507ebd8 , 0xc90bb5e9 , 0xac0ea432 , 0x300a809f , 0x3f41f40e , 0xd01dca90 , 0x06506033 ] )
+  , Mont::unsafe_make( [ 0x9f4ef9b4 , 0x892aae0b , 0x6514932d , 0x9afc612d , 0x5b7f7dda , 0xcb03ab35 , 0x1f7b57e2 , 0x2732b451 ] )
+  , Mont::unsafe_make( [ 0xcdd24ecd , 0x2575b96e , 0x6343e4c0 , 0x812f1aa0 , 0x4479348f , 0x04a99a0d , 0xd7395ae8 , 0x1684c5c5 ] )
+  , Mont::unsafe_make( [ 0xb3c91023 , 0x162b0c42 , 0xe208aa38 , 0x5c2069de , 0x9c0716a6 , 0x0cc1b586 , 0xf0e3ba69 , 0x0061a6bc ] )
+  , Mont::unsafe_make( [ 0xdd8af83a , 0x590566e6 , 0xbcd44a3b , 0xb48e0554 , 0x9fa61cfe , 0xcba12860 , 0xb3da99de , 0x127a11cc ] )
+  , Mont::unsafe_make( [ 0x82479715 , 0x07436a51 , 0xced679e3 , 0x1820a22e , 0x5bf8c7f5 , 0x874bd235 , 0x186bc469 , 0x11f91382 ] )
+  , Mont::unsafe_make( [ 0xbff0f2e3 , 0xb8a40337 , 0xc1c54605 , 0x7bf7e87c , 0x81ac7b08 , 0xb9a21e0a , 0xa5cacdb9 , 0x1eaa690a ] )
+  , Mont::unsafe_make( [ 0xbf2fcda6 , 0xdf8d7f8f , 0x40475d90 , 0x89eb5acf , 0x10c40a10 , 0x87d0d73e , 0x655e33d8 , 0x2761652e ] )
+  , Mont::unsafe_make( [ 0xc8a157f2 , 0xe618ff5a , 0x8b9226d9 , 0x9256e5d3 , 0x8367c341 , 0xce58b155 , 0xfc9f75ed , 0x268cc4f0 ] )
+  , Mont::unsafe_make( [ 0x864971f5 , 0x198a2183 , 0xbac8983d , 0xd2dcf701 , 0x0793bef7 , 0x957e61ff , 0xd9045231 , 0x1b135e72 ] )
+  , Mont::unsafe_make( [ 0x08e0718f , 0xa9915e41 , 0x3a3f5920 , 0x3280a5e5 , 0xee16f3ac , 0x5ed72b1e , 0xbe2e2a6b , 0x15449264 ] )
+  , Mont::unsafe_make( [ 0x4688bae2 , 0x03cd660e , 0x13ce52e1 , 0xabc5cca5 , 0xaecee361 , 0x7db0714b , 0x3c6c3acd , 0x24138ffa ] )
+  , Mont::unsafe_make( [ 0x71f86312 , 0x3c467f13 , 0x84fa8152 , 0xdd581ffe , 0xbb2ec3f8 , 0xc604bcd5 , 0x607cd8e3 , 0x1d716d28 ] )
+  , Mont::unsafe_make( [ 0xc480ad81 , 0x7aa0aa6d , 0x13b05dcc , 0xd02cf5e5 , 0x8d31dc6a , 0xa5fd27bf , 0x0f6969cf , 0x17144463 ] )
+  , Mont::unsafe_make( [ 0x48a1e6e7 , 0xb0f971b8 , 0x843a6163 , 0xe93d1727 , 0x78110b0f , 0xf02a4434 , 0xb8e5db5f , 0x2bab085f ] )
+  , Mont::unsafe_make( [ 0x70ce3bec , 0x21ab195c , 0xed6b8af7 , 0x91882bed , 0xa7582d4b , 0xb43acf51 , 0xc401ced7 , 0x2735f571 ] )
+  , Mont::unsafe_make( [ 0xd9744022 , 0xf8f336ae , 0xf82ffe89 , 0x0fac9e02 , 0xc445759d , 0x9502a49b , 0xd83777a8 , 0x0fd7417e ] )
+  , Mont::unsafe_make( [ 0x9ee6f4a9 , 0x1e468787 , 0x02ecd9a0 , 0xd9aea751 , 0xb55987e1 , 0x60d64f1c , 0x1233d57b , 0x2141c97a ] )
+  , Mont::unsafe_make( [ 0xa2fce146 , 0x6c5f64e3 , 0xee91773a , 0x14adb9ec , 0x4f4e3ad7 , 0x3035c563 , 0xfc3d3cdb , 0x16a42519 ] )
+  , Mont::unsafe_make( [ 0x04a8c4e7 , 0x0e083422 , 0x4dfeb48d , 0xa5e0c94f , 0x001d931e , 0x0c262c7b , 0xeddaa41e , 0x06ebda8c ] )
+  , Mont::unsafe_make( [ 0x1fcce138 , 0x4bd2f52f , 0x3a53446b , 0x50aa2899 , 0x4c59cca2 , 0xe045cc13 , 0x522dfc03 , 0x01ddfc28 ] )
+  , Mont::unsafe_make( [ 0xc1513715 , 0x721b7615 , 0x72c0a701 , 0x36fcc681 , 0x36eaa3fb , 0xfc29f1db , 0x4847bc2d , 0x22110dca ] )
+  , Mont::unsafe_make( [ 0x03091ae1 , 0x3c413a3b , 0x3ae514dd , 0xa6162556 , 0x74f1a099 , 0xc743ba65 , 0xf8254f0e , 0x23227ea5 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x49bf1465 , 0x511a455a , 0x9e75ad21 , 0xb4ac1366 , 0x03e6b9d5 , 0x4f35729a , 0x7d5992b0 , 0x198e8b6a ] )
+  , Mont::unsafe_make( [ 0xf4b539e9 , 0xcab9b597 , 0xb72db969 , 0x1c8510b0 , 0xa32ff013 , 0x3f4e4246 , 0x5b3b611f , 0x167e3c5d ] )
+  , Mont::unsafe_make( [ 0xa6070451 , 0x8a6d25f0 , 0x40c24c14 , 0xba84d792 , 0xd3676d32 , 0x68ff63cb , 0xde4d434b , 0x1150847d ] )
+  , Mont::unsafe_make( [ 0xe51aef77 , 0xc17c6c7d , 0x2e0fb88d , 0xceb0be5b , 0x1541d232 , 0x4f8f876d , 0xf9b573ac , 0x0cefc8f3 ] )
+  , Mont::unsafe_make( [ 0x5fe4ca47 , 0x76b8badd , 0x8649ed53 , 0xd886f6db , 0x2bee4bb7 , 0x9eafeb70 , 0x68287602 , 0x2b9b6758 ] )
+  , Mont::unsafe_make( [ 0x96be5440 , 0xed74a6d0 , 0x8e3af3d6 , 0xf30cdc49 , 0xfd2503ae , 0x8d1c428c , 0x05744bf7 , 0x07238b00 ] )
+  , Mont::unsafe_make( [ 0xbc800cfa , 0x0f9e51b3 , 0xd6173b72 , 0x203ad8ea , 0xcdf54b65 , 0xffaadaa6 , 0xd0335d0b , 0x2e2b26a6 ] )
+  , Mont::unsafe_make( [ 0x1366a0e7 , 0xb4c4fe39 , 0x0562de9a , 0x476df9a3 , 0x51dcc69b , 0x6258c2a0 , 0xeee83cc2 , 0x0253df94 ] )
+  , Mont::unsafe_make( [ 0xb73039ad , 0x44c1e64a , 0x2fc6e794 , 0xc4d21bbd , 0xe3cab61c , 0x3aa4f0a0 , 0x1293ec26 , 0x2d3926ea ] )
+  , Mont::unsafe_make( [ 0xf5e85e7b , 0xc7febf87 , 0xd18ca955 , 0x22eca269 , 0xc395a18e , 0x7e6c1094 , 0xb1e58e28 , 0x0f39fed9 ] )
+  , Mont::unsafe_make( [ 0xb1b44ff8 , 0xe1c0e773 , 0xe4aff501 , 0x97de31cb , 0x87193079 , 0xd46ba9f9 , 0x0bd1613e , 0x120b2e39 ] )
+  , Mont::unsafe_make( [ 0xdad5e2fa , 0x520d01fe , 0xa1ef9c78 , 0xf0708559 , 0x8d3fb9e2 , 0xb459ec46 , 0x4a7628fe , 0x295f4b44 ] )
+  , Mont::unsafe_make( [ 0x92d7d7ba , 0xa315623d , 0xce0a92eb , 0x21305cc3 , 0xc8ffb98e , 0xc69bda10 , 0xea2688ad , 0x0b90f543 ] )
+  , Mont::unsafe_make( [ 0x73f4bc25 , 0x8e04287d , 0xeb96e17d , 0x0fb55516 , 0xfb6164b5 , 0xd548fbdf , 0xd918753a , 0x07fbee12 ] )
+  , Mont::unsafe_make( [ 0x201876af , 0xbcf787ba , 0xe5657a6d , 0xdcdc6198 , 0x720c6972 , 0x424695a2 , 0x49bffc69 , 0x0dc9ac65 ] )
+  , Mont::unsafe_make( [ 0xca269bd5 , 0x78d48691 , 0x80157fc5 , 0x24cf906b , 0x7abb532b , 0x38791051 , 0xe842ea6c , 0x16b8cd7d ] )
+  , Mont::unsafe_make( [ 0x0b71e490 , 0x9ef39978 , 0x4983c5a7 , 0x5cbcd15c , 0xb755ea1c , 0x75a21a69 , 0xc19f6fdd , 0x263a4704 ] )
+  , Mont::unsafe_make( [ 0xbb5ca3ca , 0x3230e369 , 0xf12f9c10 , 0x66cc4c1d , 0xa72ecff9 , 0xe7567474 , 0x70cea972 , 0x1ee3d6bb ] )
+  , Mont::unsafe_make( [ 0x1ba7e070 , 0x63160412 , 0xded8cb24 , 0xe70f66b7 , 0x919839e4 , 0xbab6ff32 , 0x3c3ee744 , 0x13143a4b ] )
+  , Mont::unsafe_make( [ 0x6727c9d9 , 0x5c099477 , 0x2feb3de0 , 0xda63d22c , 0xb863aa6b , 0x25833b12 , 0x9393713f , 0x1ab89380 ] )
+  , Mont::unsafe_make( [ 0xefad06b0 , 0xa02a29c8 , 0x125791c5 , 0x2258221a , 0xfbc21838 , 0xf938e787 , 0x58a53ce7 , 0x09026b3c ] )
+  , Mont::unsafe_make( [ 0x2ac9503a , 0x34bb4ab8 , 0x1c771dc5 , 0x616a493c , 0x0cdd0ffc , 0xd2355569 , 0x25df5bf2 , 0x2bf891e7 ] )
+  , Mont::unsafe_make( [ 0xae0ed7e3 , 0xd4e171e4 , 0xd0dfde57 , 0xcba53fb7 , 0x960f2851 , 0x61bf4d6d , 0xe5408e20 , 0x13230059 ] )
+  , Mont::unsafe_make( [ 0x52981352 , 0xfde7ad5b , 0x342b707f , 0xe0657b2e , 0x390a2f53 , 0x2be564c2 , 0x195d82e8 , 0x0eaba8a9 ] )
+  , Mont::unsafe_make( [ 0x2b4b914d , 0xaa543a89 , 0xc9d93e6a , 0x473132b4 , 0xc43e679a , 0x430c4852 , 0xa37b1788 , 0x20026795 ] )
+  , Mont::unsafe_make( [ 0xb0d7df32 , 0x09396389 , 0x25b5c194 , 0xe83e262d , 0x7cc11eac , 0xeafd2949 , 0x069cf3f9 , 0x22067c7d ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x6f852a4b , 0xe7e21938 , 0x591d17a7 , 0xdee5450f , 0x2306a482 , 0xd21c0fe8 , 0xc23ba9a5 , 0x0f571e62 ] )
+  , Mont::unsafe_make( [ 0xeea55a49 , 0xf3591025 , 0x18e7b928 , 0x356a41d2 , 0x37b2a08f , 0x6a275fb7 , 0xee412ce6 , 0x05d04393 ] )
+  , Mont::unsafe_make( [ 0xb4441fcd , 0xf0fca323 , 0x766fca2b , 0x86350455 , 0x6ffc4bd6 , 0x21a1df92 , 0x4b9da007 , 0x09f72582 ] )
+  , Mont::unsafe_make( [ 0x44050ac4 , 0x12df7874 , 0xe3c8ece8 , 0xc136b0f2 , 0xe543c9c6 , 0xd2779fa5 , 0x8ae8c8af , 0x237d8aac ] )
+  , Mont::unsafe_make( [ 0xf6d64592 , 0x705f0033 , 0x02e88853 , 0xc0a6cafa , 0xff842679 , 0xf85256a0 , 0x0401dc96 , 0x2192d370 ] )
+  , Mont::unsafe_make( [ 0x4a9ee460 , 0x140a2711 , 0xe1276918 , 0x43742c73 , 0x72b8e9fd , 0xeb64a0dc , 0xe1b94256 , 0x070fa407 ] )
+  , Mont::unsafe_make( [ 0x9bd6f80c , 0xe6e7bebb , 0xdb63086e , 0x4407c1da , 0x505839e3 , 0xf05b5bf0 , 0xcca37a4c , 0x030ab996 ] )
+  , Mont::unsafe_make( [ 0x8979f1c9 , 0x7a2e51b0 , 0xcfe81260 , 0x514f46d4 , 0x7fee4cfc , 0x43c44b84 , 0x7c2b45a7 , 0x22a9e72d ] )
+  , Mont::unsafe_make( [ 0xdf47a988 , 0xb5993933 , 0x490e24d8 , 0x189cfa53 , 0x98b370e4 , 0x643b48f7 , 0x63340307 , 0x2222248a ] )
+  , Mont::unsafe_make( [ 0xb3de5782 , 0x2a95284c , 0xb0d86f30 , 0xae46bcd2 , 0xf2fb3768 , 0xeb0953d1 , 0xa3f4b690 , 0x0b9f40ae ] )
+  , Mont::unsafe_make( [ 0xedb3645d , 0xf0c6356d , 0xe21452aa , 0x11b0d85c , 0x7c50aff4 , 0x25adc6c7 , 0x5b72b077 , 0x260b825b ] )
+  , Mont::unsafe_make( [ 0x7b20099c , 0xba1f7a64 , 0x5ce69411 , 0x8b3f0384 , 0x3473f112 , 0x4b65749e , 0xb483b640 , 0x0e971895 ] )
+  , Mont::unsafe_make( [ 0x1f9a17b1 , 0xebbd7cbb , 0x0268c3e4 , 0x2e00e029 , 0x9523a724 , 0x83f65b7d , 0xa44ce50b , 0x29f5452c ] )
+  , Mont::unsafe_make( [ 0x0619d31e , 0x8fd881ef , 0x2d77417d , 0x5bd70594 , 0x4e91b3e0 , 0x15f0dcfa , 0x454dac80 , 0x29172900 ] )
+  , Mont::unsafe_make( [ 0xd2a4c81e , 0x95fda5e1 , 0xaebf96a1 , 0xe17b9ab0 , 0xcff7aee1 , 0x10ab2df3 , 0xdd417ba9 , 0x2f6b5d05 ] )
+  , Mont::unsafe_make( [ 0xfaa87299 , 0x51392683 , 0xc8fec192 , 0x8277c107 , 0xb184fc7d , 0x81a97de1 , 0x9e5d711b , 0x0594381d ] )
+  , Mont::unsafe_make( [ 0x8da9bc93 , 0x7ece8dac , 0xd14c46ba , 0xdc6fdc18 , 0x37c69b1e , 0x83728675 , 0x7401ae42 , 0x2c31943c ] )
+  , Mont::unsafe_make( [ 0x180645a1 , 0xbb6acaf5 , 0x1adb75ce , 0x5e8a08da , 0x37286542 , 0x041f95e0 , 0x01ca1dc1 , 0x1744aac2 ] )
+  , Mont::unsafe_make( [ 0x3f5e78e2 , 0xf658409e , 0x107ce2bf , 0xb54cf623 , 0x80ffcea9 , 0x958003b0 , 0xb754d4c5 , 0x18196edb ] )
+  , Mont::unsafe_make( [ 0x00a5d4dc , 0xfb0362eb , 0x34624b72 , 0xfa5f3381 , 0x32877ee6 , 0x35c4658c , 0x8dd90367 , 0x2fa9e30b ] )
+  , Mont::unsafe_make( [ 0x2eed994f , 0x4e91fba5 , 0x3b379716 , 0x48c27bc3 , 0xe745a301 , 0x5f36a604 , 0x9e9c10e6 , 0x13f30c17 ] )
+  , Mont::unsafe_make( [ 0x9c62a739 , 0x0be8d9be , 0xe168c4d4 , 0x7e92b068 , 0x8be1408b , 0xbbc9ab59 , 0x4decbc67 , 0x010b5a3e ] )
+  , Mont::unsafe_make( [ 0xbb6010a2 , 0x042b0308 , 0xf8da5ae3 , 0x6f31cdf4 , 0x111dfa9c , 0x92562242 , 0x9f97c0e7 , 0x1a2db82c ] )
+  , Mont::unsafe_make( [ 0x294e859c , 0x1300c880 , 0x43cd2527 , 0x773e45bb , 0x8156e237 , 0x889ab838 , 0x6458c0e3 , 0x2e492877 ] )
+  , Mont::unsafe_make( [ 0x71b56a88 , 0x4681db1f , 0x4283d6e0 , 0xc8590864 , 0x79f86996 , 0xfa9b5d24 , 0x41ab0bf0 , 0x0ebdfce7 ] )
+  , Mont::unsafe_make( [ 0x49920ab6 , 0x0d55778d , 0x17125cde , 0xbd7b3878 , 0x9b96fc22 , 0xa0c2b7d8 , 0x1dce7f5e , 0x1bbab015 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x79afa53a , 0x7530e7f2 , 0x2f5861d1 , 0x223e152a , 0x21af3ca6 , 0x6679f918 , 0x7da79293 , 0x263cd6cf ] )
+  , Mont::unsafe_make( [ 0x770d7481 , 0xeace52da , 0x1d188446 , 0xc21e540b , 0xc3bba371 , 0x8ebb4b5a , 0x98a105d7 , 0x06a6a09c ] )
+  , Mont::unsafe_make( [ 0x565d873c , 0xe24d2d7e , 0x6db8e5da , 0x6eec5954 , 0x0010a98f , 0xb3076ca1 , 0xaec057d1 , 0x275e8f5c ] )
+  , Mont::unsafe_make( [ 0xe856b7cb , 0xb5d61d84 , 0xee3d023f , 0xe73f5852 , 0x81ab912d , 0x228e51bd , 0x4693cfdb , 0x010696f0 ] )
+  , Mont::unsafe_make( [ 0x20a4f1ee , 0xa844716c , 0x811bfbed , 0xb7a13e87 , 0x36e972d2 , 0x9c7559bb , 0x5ad06038 , 0x0b45924d ] )
+  , Mont::unsafe_make( [ 0xc6b0495c , 0x651caefa , 0x8628f222 , 0x7d7e1475 , 0xd45e5f53 , 0x17131afe , 0x7203cd41 , 0x1e117183 ] )
+  , Mont::unsafe_make( [ 0x7ee09475 , 0xbc8fdde1 , 0xd7697c88 , 0xde1dcc12 , 0xbecd99c3 , 0x37ed4912 , 0x3e9a01e7 , 0x2a0d6221 ] )
+  , Mont::unsafe_make( [ 0xdf53740c , 0xd07b7f64 , 0xfe8a4097 , 0x34e31c4d , 0x79517ba1 , 0x09601055 , 0x858455a1 , 0x2c0e42ac ] )
+  , Mont::unsafe_make( [ 0xb7157642 , 0xfc598170 , 0xebdb1e7c , 0x2dd375d3 , 0xed24251e , 0xc34fa4ed , 0x3281bd39 , 0x19b93c18 ] )
+  , Mont::unsafe_make( [ 0xf4c0cd27 , 0x5b7d2889 , 0x971653a2 , 0x743188a4 , 0x161dd271 , 0x130dcb6f , 0x64bec0cc , 0x0a668017 ] )
+  , Mont::unsafe_make( [ 0xc66585db , 0xbb5a2c45 , 0x4c3a0495 , 0xdb260a1d , 0x777ceca8 , 0xab7f49f0 , 0x2e98a066 , 0x1b4ba562 ] )
+  , Mont::unsafe_make( [ 0xeaa9c3c7 , 0xf2c849e6 , 0x3e0a5f77 , 0x34dddaa8 , 0xdb96a3a3 , 0xd5acf50e , 0xe6840830 , 0x03a1e69d ] )
+  , Mont::unsafe_make( [ 0x7c29fb21 , 0x04fa0265 , 0xca12911f , 0xba4e9a3e , 0x5fa244e1 , 0x00e7faa3 , 0x91d47745 , 0x094cb496 ] )
+  , Mont::unsafe_make( [ 0xcd97e370 , 0xf8d6e6a3 , 0x90bcba4f , 0xda9b7316 , 0x4fcc446f , 0xef6906bc , 0xa9ed7a78 , 0x03d6efb4 ] )
+  , Mont::unsafe_make( [ 0x155b8d49 , 0x0bf64201 , 0x8276b182 , 0x7d5068e6 , 0x8a00a883 , 0xa2fd85e6 , 0xf182f6d4 , 0x0c666628 ] )
+  , Mont::unsafe_make( [ 0x7edfa8b5 , 0x6bb4fc61 , 0x1cf332ea , 0x6f82dcfb , 0x358f3098 , 0x6cffc320 , 0x12e497ed , 0x2c899ab9 ] )
+  , Mont::unsafe_make( [ 0xe5bc4d0b , 0xf9885c44 , 0x362f8b6b , 0x7516bccd , 0x1f1acf76 , 0xd52010fc , 0xab1653ee , 0x09f0c584 ] )
+  , Mont::unsafe_make( [ 0xc78e2b85 , 0x6feaa234 , 0x248ac2df , 0xc8e6e4d4 , 0x270afcad , 0xd4d6440d , 0x984d8c47 , 0x0f9fbb80 ] )
+  , Mont::unsafe_make( [ 0x26c4db21 , 0x9e7c7cd4 , 0xf38525d7 , 0x944b92be , 0xe328b22e , 0x9bb891ec , 0xfd993bd8 , 0x067afaa3 ] )
+  , Mont::unsafe_make( [ 0x0b60878b , 0xe80da366 , 0xeb38754d , 0x97187ff1 , 0xc104defa , 0x1c1aaa67 , 0x952793e8 , 0x17871438 ] )
+  , Mont::unsafe_make( [ 0x30ce0e3f , 0xdee9e33a , 0x0c87761f , 0x6c19e303 , 0x61a4b1b5 , 0x4dd34ae2 , 0x1c6adeb7 , 0x18038f11 ] )
+  , Mont::unsafe_make( [ 0xcd24aa68 , 0x7c42ef41 , 0x8c103829 , 0x06668425 , 0x0dee0f48 , 0x942d09f3 , 0x521a9a2a , 0x20657965 ] )
+  , Mont::unsafe_make( [ 0x981c09e7 , 0xeafecf3e , 0xd8ee72e4 , 0x145b4277 , 0xb75ce190 , 0x3ed96906 , 0xc28dfeda , 0x0c0e356a ] )
+  , Mont::unsafe_make( [ 0x1b509296 , 0x87626a81 , 0xb2bdd7c3 , 0x56b2e8ef , 0x71b2ac88 , 0x795ac363 , 0xf3551961 , 0x1d47d596 ] )
+  , Mont::unsafe_make( [ 0xa041f524 , 0xd7e8ac43 , 0x00da275c , 0x16a55cba , 0x40cbb376 , 0x48337399 , 0x51c83e7f , 0x0de0e833 ] )
+  , Mont::unsafe_make( [ 0xba26cb0b , 0x430faa25 , 0xd4c5c2f1 , 0x0e8d0f73 , 0xc38a33dd , 0xc7ea62d7 , 0xbbac84db , 0x0bba2d61 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xa43060c5 , 0x5546e469 , 0x922f5c78 , 0xdb29ae67 , 0x78fae106 , 0x33daea06 , 0x694504ed , 0x0ef63ac6 ] )
+  , Mont::unsafe_make( [ 0x9e40476d , 0x187488ba , 0x5acf7447 , 0x9063f223 , 0xeaac1ee3 , 0x5192e5c0 , 0xcd03f8e1 , 0x22f2d9d3 ] )
+  , Mont::unsafe_make( [ 0x92fd6d5f , 0xc94719ff , 0x7247b753 , 0xb68acd80 , 0x5d8d3f36 , 0x5ec84147 , 0x67de9c2c , 0x2fc12781 ] )
+  , Mont::unsafe_make( [ 0xc15744ad , 0x03dd565f , 0xc5767348 , 0xefbd2a82 , 0x37aa031d , 0xed9702a9 , 0x07160155 , 0x2d68d452 ] )
+  , Mont::unsafe_make( [ 0x845ea75f , 0x1a20b240 , 0x1d6d2760 , 0xb644bcbb , 0x7836b62d , 0x515fd6fa , 0xc4023905 , 0x133703d3 ] )
+  , Mont::unsafe_make( [ 0x0e760e4d , 0xbd2cfd25 , 0x55be78e8 , 0xc69fbe3c , 0x646696b4 , 0xcca2f869 , 0x87c6c683 , 0x083c066b ] )
+  , Mont::unsafe_make( [ 0xdbc42bee , 0x019be240 , 0xc22473f4 , 0x2430a072 , 0x03fb515c , 0xce8c2359 , 0xcd9526a8 , 0x29f0b69a ] )
+  , Mont::unsafe_make( [ 0x6fdb5558 , 0xd38e9a8e , 0x7c629aa0 , 0x79650d06 , 0x58ada924 , 0x6a15aaf0 , 0xab928a62 , 0x0e3a0c38 ] )
+  , Mont::unsafe_make( [ 0xce5da329 , 0xbbf67443 , 0xa0006412 , 0x1ad21233 , 0x1d7a4132 , 0x9a734128 , 0xe440781f , 0x1bf0dc2a ] )
+  , Mont::unsafe_make( [ 0xab9787ab , 0xaa6e8ac0 , 0x5736830c , 0x6842d5b3 , 0xeb5fc760 , 0x705e8bce , 0xef2d4ab7 , 0x2080e7e3 ] )
+  , Mont::unsafe_make( [ 0x2b818708 , 0xccf33942 , 0xa3f32f0e , 0x94dd52d0 , 0x2c5d0da8 , 0x4e2eea91 , 0x3f517c8a , 0x18375a4d ] )
+  , Mont::unsafe_make( [ 0xac38e51a , 0xf5eb69a9 , 0xf0c501ce , 0xf42f4390 , 0x414e406a , 0xb743f2e3 , 0x559e6215 , 0x0ce476d9 ] )
+  , Mont::unsafe_make( [ 0xdffeba9c , 0xb9754714 , 0xef59d4b4 , 0xb3b92a63 , 0xa2ee7f74 , 0x454218b7 , 0x77c36ca7 , 0x161038cd ] )
+  , Mont::unsafe_make( [ 0x5090a56a , 0x145294da , 0x7dee9a47 , 0xb3e88c89 , 0x88882c2f , 0xee7d8479 , 0x22602528 , 0x016f63ad ] )
+  , Mont::unsafe_make( [ 0x4ef5e479 , 0x58cd0d89 , 0x5edf0fcb , 0xd5822624 , 0xc708d7c6 , 0x6b54b737 , 0x494a0114 , 0x00dc9a4e ] )
+  , Mont::unsafe_make( [ 0x8693c341 , 0x773d228f , 0xdb5e6d2b , 0xe0522415 , 0xdec52d6f , 0x55f8626c , 0x49dce70d , 0x10571a39 ] )
+  , Mont::unsafe_make( [ 0x415897f3 , 0xe9bff799 , 0x5078034e , 0x792f53ad , 0x7c431fa3 , 0xa4ba7fdd , 0x104059da , 0x079a4ba2 ] )
+  , Mont::unsafe_make( [ 0x4674f3d8 , 0x088f865e , 0x3af20296 , 0x16ba5ed4 , 0xbf0e275f , 0x298b2b2b , 0x82d5c3c6 , 0x1c841f5c ] )
+  , Mont::unsafe_make( [ 0xb21559eb , 0xb6c1d04f , 0xf4975bb2 , 0x80c809cd , 0x37cf418f , 0x1f63fd0d , 0x104b4665 , 0x09ae303c ] )
+  , Mont::unsafe_make( [ 0xda0afa70 , 0x133c3b9c , 0x6bd98bdd , 0xc2d6c5d7 , 0xa2702b5d , 0x0ed57b90 , 0x67a52a09 , 0x1e957860 ] )
+  , Mont::unsafe_make( [ 0x8b1e4f2c , 0x4eb36445 , 0xe700ae63 , 0x65ba6634 , 0x0e9add65 , 0xe9bee0b5 , 0x3ed86516 , 0x24a29e42 ] )
+  , Mont::unsafe_make( [ 0xf7be3d2e , 0xcd2d82a5 , 0x118fd9b7 , 0x1dc10470 , 0x272400ad , 0x3b82e234 , 0xbc79224f , 0x232b216f ] )
+  , Mont::unsafe_make( [ 0x641f73c0 , 0xf2b2a92f , 0x81254ec0 , 0x4ab45a27 , 0xc4263972 , 0x28e058a5 , 0xdab9d874 , 0x267f01de ] )
+  , Mont::unsafe_make( [ 0x5b71d433 , 0x39fe1cb0 , 0x7dd7f3ef , 0x73023ced , 0xbcf58849 , 0x62ebc38f , 0x14870c73 , 0x232a7e48 ] )
+  , Mont::unsafe_make( [ 0x84a05da2 , 0x09235355 , 0x8108cb30 , 0x347570f9 , 0xef401c90 , 0xa68c2f96 , 0x2b3f8e39 , 0x10794fe8 ] )
+  , Mont::unsafe_make( [ 0x863b1b8d , 0xd6636d6c , 0xcf942ec8 , 0x9eb99f1d , 0x4b7308da , 0xb80759c4 , 0x924e90cb , 0x1ab61ce3 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x979e38d4 , 0xd0e6bdb5 , 0xecc8019f , 0x80f244ea , 0x4d705eb3 , 0x200b8f6c , 0x09df6327 , 0x1c1f12a8 ] )
+  , Mont::unsafe_make( [ 0x4feb32e2 , 0xe4afd8c4 , 0x520e9491 , 0x616863c5 , 0x28f85da9 , 0xae552c16 , 0xdcb64afe , 0x0cf2136f ] )
+  , Mont::unsafe_make( [ 0x5d418e45 , 0x80097274 , 0xa6b888be , 0xb60e469a , 0xa460f6f5 , 0xa727dd37 , 0xa702e81e , 0x24d20df4 ] )
+  , Mont::unsafe_make( [ 0x65694e2a , 0xd440218d , 0xc32c7a2c , 0xd883eb88 , 0x810db0c4 , 0x688d7f6f , 0x5b9213a4 , 0x2ffe605b ] )
+  , Mont::unsafe_make( [ 0xb8738035 , 0x1b763589 , 0xecc4f634 , 0x6ffc6756 , 0xe53d1e19 , 0x0379324a , 0x86a90528 , 0x22a7d488 ] )
+  , Mont::unsafe_make( [ 0x2c534e14 , 0x1a3e3255 , 0x2c1d171c , 0x85bda436 , 0x78eacf7a , 0x2453dda6 , 0x4269c628 , 0x1e40ef84 ] )
+  , Mont::unsafe_make( [ 0x1a3a7e49 , 0xfbf20ef2 , 0x83c65efb , 0xef4e09cd , 0xd8fa4339 , 0x29666672 , 0x18876fb6 , 0x0b6abb7f ] )
+  , Mont::unsafe_make( [ 0x8b9c1c64 , 0xa07bdf09 , 0xed2badb4 , 0x3ea8e76b , 0xff0147a7 , 0x052e2859 , 0xa88fb674 , 0x230e2892 ] )
+  , Mont::unsafe_make( [ 0x47693dc6 , 0x2751c27f , 0xb1a525ff , 0x3824e40d , 0x368bbf4d , 0x0f4b310e , 0x21e28e38 , 0x1f12e475 ] )
+  , Mont::unsafe_make( [ 0x48a89249 , 0x953d2052 , 0x192e7bda , 0x872c0f0e , 0xd05ec89d , 0x62f2b05a , 0xe818ef14 , 0x17e096fe ] )
+  , Mont::unsafe_make( [ 0xff4a852d , 0xb4843c8e , 0xa3d038f1 , 0xc173943f , 0x64674bd4 , 0x8804cc06 , 0x794393c5 , 0x196bc772 ] )
+  , Mont::unsafe_make( [ 0xd6d9f57f , 0xda0db33b , 0xc6b3d320 , 0x397c4168 , 0x98dd5c69 , 0x4b49e755 , 0x51fde1cd , 0x1b5b1850 ] )
+  , Mont::unsafe_make( [ 0x02637d3f , 0xbe563558 , 0x58a751f0 , 0x4d96aff5 , 0x21f7fda3 , 0xa0467ba0 , 0xcf479e6a , 0x075c3a05 ] )
+  , Mont::unsafe_make( [ 0x5fdc00fb , 0xf8f06ca5 , 0xb455b9d8 , 0x5365d592 , 0xc3e38910 , 0x04ead1fe , 0x2ad0ed66 , 0x11f0394a ] )
+  , Mont::unsafe_make( [ 0x8bb2b3bd , 0xb4e23781 , 0x4220917d , 0xdd3f0d6b , 0x6d694411 , 0x608437f9 , 0x3a582a29 , 0x0a078904 ] )
+  , Mont::unsafe_make( [ 0xa6e509b5 , 0x116cb21e , 0x57fa48a3 , 0x7dc9eb21 , 0xac371e6d , 0xacc01a55 , 0x77983caf , 0x2eb62c9d ] )
+  , Mont::unsafe_make( [ 0x7953898a , 0xc846cfc2 , 0x4f34032d , 0xe9fea190 , 0x5a093f6f , 0x37206ab5 , 0x473077f9 , 0x1e420166 ] )
+  , Mont::unsafe_make( [ 0x68444f25 , 0xdb0c4758 , 0x433570e3 , 0x2e4f1a03 , 0x853e5f49 , 0xcc70d888 , 0x37b9fc7b , 0x1650a0f6 ] )
+  , Mont::unsafe_make( [ 0xec665f4a , 0x52b90f0e , 0x63ba8adf , 0x7affeb09 , 0x5bb7543e , 0x59183bda , 0x25e0cd96 , 0x0e511b3e ] )
+  , Mont::unsafe_make( [ 0xa60871dc , 0xd4431417 , 0x66e998a5 , 0xe3610791 , 0x8724a155 , 0xbbb3f7f4 , 0x849834bd , 0x0f8c043d ] )
+  , Mont::unsafe_make( [ 0x4be9d8c2 , 0xb44ff5ec , 0x3f7864b2 , 0x5eef14fd , 0x591f5bc7 , 0xcbce3c5b , 0x3a5a80d9 , 0x1cce59ba ] )
+  , Mont::unsafe_make( [ 0xc32c99c4 , 0xcb27743e , 0xabc3511a , 0x6e808ce0 , 0x65238b08 , 0xc9a3814a , 0xcd7b63d4 , 0x01fcaa6a ] )
+  , Mont::unsafe_make( [ 0x84ecb74e , 0xdc8fd57b , 0x33845fa2 , 0xa8368506 , 0x6592f77c , 0xab26b985 , 0xf2f6a9bc , 0x2a9621ae ] )
+  , Mont::unsafe_make( [ 0x9b41cb75 , 0xcc945b00 , 0x387379d4 , 0x0259b5b5 , 0xe106fdfb , 0x316a0be6 , 0x3ca23ce1 , 0x1cd1daa3 ] )
+  , Mont::unsafe_make( [ 0xa0861bd5 , 0xe5b4c465 , 0x46fd1ccd , 0xbb4d8975 , 0x389ac9e1 , 0xba39824a , 0xd59c23cd , 0x18cac925 ] )
+  , Mont::unsafe_make( [ 0x3f172e43 , 0xaeeedd45 , 0x4e4c401e , 0x8d267a55 , 0x863e3dff , 0x9582362e , 0xd94f112d , 0x1bc6695e ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xd3c17206 , 0xef2fab59 , 0x1a78f9b1 , 0xce8367e5 , 0xfcc43585 , 0x0175a72d , 0x876ae99d , 0x1f3da05b ] )
+  , Mont::unsafe_make( [ 0x7fdd7a8b , 0x9f584215 , 0xcdba4460 , 0xb2be9a04 , 0xe5a67d6c , 0xe105973a , 0x698221c8 , 0x18c8c120 ] )
+  , Mont::unsafe_make( [ 0x9aad0955 , 0x806caa41 , 0x621937ab , 0xa84931ca , 0x1f6e443e , 0x2e82d55c , 0x271e0d15 , 0x26cb6dff ] )
+  , Mont::unsafe_make( [ 0x5a83442c , 0x94ca8b04 , 0xea2b7a04 , 0x32cd82b0 , 0x582f246a , 0x1f23a4be , 0x82f0497c , 0x10924d83 ] )
+  , Mont::unsafe_make( [ 0x18c53a29 , 0xd42c7b0b , 0x1d5352db , 0x48a55b72 , 0xb0a1483e , 0x58a39087 , 0xb03c5418 , 0x1a7b377f ] )
+  , Mont::unsafe_make( [ 0x29b4b10b , 0xc70dabe8 , 0x102dd33b , 0x24c2c194 , 0x550a05b9 , 0xd8996a9d , 0x4cf7e72c , 0x019937ab ] )
+  , Mont::unsafe_make( [ 0x7f454025 , 0x102d5a7e , 0x7fd2d2b3 , 0x97b62d78 , 0x0de9ff47 , 0x57c604c6 , 0x27f3d297 , 0x00d23f01 ] )
+  , Mont::unsafe_make( [ 0xf90b785b , 0x77398792 , 0xaf7b2158 , 0xdca79606 , 0xc2ca2ecc , 0xcfc93f72 , 0xaade62ae , 0x00271056 ] )
+  , Mont::unsafe_make( [ 0xfdd2e7c0 , 0x8b41a9df , 0x0a2b73ba , 0x8000fcfc , 0xe1c9c9e6 , 0x32238f77 , 0x9b4efdcc , 0x03113157 ] )
+  , Mont::unsafe_make( [ 0xd0d4f069 , 0xc3eb8270 , 0xe3844608 , 0x44780f7e , 0x7545eb03 , 0x951f1879 , 0xd5cadfa7 , 0x0a2b48e9 ] )
+  , Mont::unsafe_make( [ 0xb196ed70 , 0x54e44e85 , 0x011ecafa , 0x4f94ba9b , 0x1d50b572 , 0xc34ab69b , 0x66b46387 , 0x2536a84b ] )
+  , Mont::unsafe_make( [ 0x44d07f19 , 0x9ab59afc , 0x2f07702f , 0xd8fd5cb2 , 0xd3ce8a97 , 0xd3e89ccd , 0xaa159482 , 0x0320df4e ] )
+  , Mont::unsafe_make( [ 0xcf8e0fd5 , 0x94ae7d7d , 0xb36bd787 , 0xe6e0c803 , 0xfaafc9d3 , 0x84e82875 , 0x0bbd26ec , 0x2f22b703 ] )
+  , Mont::unsafe_make( [ 0x938608cb , 0x151ed7cc , 0x87910452 , 0xd9f3e75e , 0x0b5e505d , 0x19fb6278 , 0x6382357b , 0x180f610f ] )
+  , Mont::unsafe_make( [ 0x4985fac7 , 0x4a9c3de7 , 0x32db76a1 , 0x1ea0a753 , 0x9a57cc8f , 0xa808efde , 0xb5956f8b , 0x0a876d2b ] )
+  , Mont::unsafe_make( [ 0x6b36a11c , 0xc023dae4 , 0xa57dac23 , 0x84a3f3a9 , 0xb4dbfe5c , 0x864e8787 , 0xbd1b3d7b , 0x15fed1de ] )
+  , Mont::unsafe_make( [ 0x9e4e45cc , 0x6fd907f4 , 0x4c3d3bce , 0xbab3c72f , 0x03ee42b9 , 0x410bb941 , 0xa8995fb4 , 0x1219ea06 ] )
+  , Mont::unsafe_make( [ 0xd8758b11 , 0x911f2904 , 0x0fe12b68 , 0xdee744c8 , 0xf528552a , 0xe04fed73 , 0x1d90d977 , 0x12a3b8ff ] )
+  , Mont::unsafe_make( [ 0xb616723f , 0x742ab640 , 0xd48e44d9 , 0xccf35e48 , 0x6f262416 , 0x96a7accb , 0xc3f461b6 , 0x2d141480 ] )
+  , Mont::unsafe_make( [ 0x66af30fa , 0xa8d2f0df , 0xefdcc1c4 , 0x669a842e , 0x767e0348 , 0x2f3504fb , 0xdfc0521b , 0x17bed9bb ] )
+  , Mont::unsafe_make( [ 0xddb69fd6 , 0xe534bc0c , 0x19259575 , 0x8acbb9c7 , 0x6b65c509 , 0xa1ff9645 , 0x42905fe2 , 0x1889f143 ] )
+  , Mont::unsafe_make( [ 0x125ea452 , 0x27630e47 , 0xc98fd26e , 0x4ec559a9 , 0xa0c03b47 , 0xb5178abb , 0xcd87d7f2 , 0x1c3762a5 ] )
+  , Mont::unsafe_make( [ 0x1db521f2 , 0x312d72c5 , 0x99492841 , 0xaced8cb6 , 0x671b7e50 , 0x44da520a , 0xaa1ac0cb , 0x05320072 ] )
+  , Mont::unsafe_make( [ 0xd638c6e8 , 0x3b6fb37d , 0x94f42a5a , 0x305ccaf6 , 0xbc90bc87 , 0x308efef1 , 0x18fd3fdc , 0x11573608 ] )
+  , Mont::unsafe_make( [ 0x544cfcc6 , 0x3849e005 , 0xd30cd0c0 , 0x1f01c347 , 0x422a86d0 , 0x6771dac5 , 0xa8bad38e , 0x05f02c21 ] )
+  , Mont::unsafe_make( [ 0x1c83abd4 , 0x7d685fbf , 0x64dec0d2 , 0xf734ce02 , 0x2ccdb13a , 0xfaa752d0 , 0xff2d418d , 0x01546075 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x44026287 , 0xfa54b513 , 0xaa1134f3 , 0x1d0a6c0a , 0x3cdc64ff , 0xbb70d4d8 , 0x2402667b , 0x12f20a20 ] )
+  , Mont::unsafe_make( [ 0xeb798b73 , 0x7823ab1c , 0x14de42f2 , 0xc648b2cd , 0xf4a2bee0 , 0xc97c62db , 0xe9d6d42c , 0x1c752da6 ] )
+  , Mont::unsafe_make( [ 0xd6244cec , 0x67459022 , 0x9295e466 , 0x0d0f10d2 , 0xf8ccf766 , 0x4a50b987 , 0x7b0a1025 , 0x0fd840bc ] )
+  , Mont::unsafe_make( [ 0xd5b627fc , 0x0bbba444 , 0xc07cc1b2 , 0x47c121bd , 0x7e6855f3 , 0xa81a5baa , 0x36531f8e , 0x24f94335 ] )
+  , Mont::unsafe_make( [ 0x938267a5 , 0x5849b277 , 0x5167e700 , 0xcaec734b , 0xd14377e9 , 0x54af8128 , 0x60b4717b , 0x088d7aa1 ] )
+  , Mont::unsafe_make( [ 0x14028d8b , 0x909edfb2 , 0x7f6dbe93 , 0x5becf2ba , 0xd1e4e3a7 , 0x7f59de36 , 0x487227b7 , 0x25fbc43f ] )
+  , Mont::unsafe_make( [ 0x0a50bb3b , 0x8a497e46 , 0x57a3a2de , 0x780a7b81 , 0x637093be , 0x2976fb02 , 0x1c034c3d , 0x12fb5b3c ] )
+  , Mont::unsafe_make( [ 0x97876390 , 0x5a425bd0 , 0x5df57950 , 0x8c2ffa4c , 0x34d7a7c3 , 0xf00dc706 , 0x8f06dbe4 , 0x259be8b9 ] )
+  , Mont::unsafe_make( [ 0x19f1a87c , 0xae9c6317 , 0x6c5711e7 , 0xb5b011bb , 0x50a36313 , 0xef4678ba , 0xb7a150ed , 0x284b268a ] )
+  , Mont::unsafe_make( [ 0x3d1bd5a9 , 0xc1f57659 , 0xcd5dfe7f , 0xd232dccd , 0x72832e6a , 0x3ce0e8c1 , 0x35371e50 , 0x2cba74ad ] )
+  , Mont::unsafe_make( [ 0x721ec004 , 0x7d7764f1 , 0x8b1fb69d , 0x0046ff77 , 0x24d566c4 , 0xfc84e64f , 0x1b8ef28f , 0x04b84751 ] )
+  , Mont::unsafe_make( [ 0x022226d2 , 0x5c3572ea , 0x73eea67d , 0x7c011e63 , 0x210db751 , 0x1a4537ce , 0x91ae8545 , 0x06ee4e32 ] )
+  , Mont::unsafe_make( [ 0x6a967a7d , 0xf2deb996 , 0x12bc0071 , 0xf5c456b6 , 0xd2a9587e , 0x0b31f540 , 0x24e2fb81 , 0x17793406 ] )
+  , Mont::unsafe_make( [ 0x72653c52 , 0xe5e905b7 , 0x0ceb1ad5 , 0x6aa18e53 , 0x2d9e91f5 , 0xff188028 , 0x45ccfe34 , 0x05cacbd6 ] )
+  , Mont::unsafe_make( [ 0xb1611226 , 0xedbe7db3 , 0xebaa58c7 , 0x7a802a5f , 0x96da8e86 , 0x04bfde8c , 0xd1e0b2f0 , 0x2707b42c ] )
+  , Mont::unsafe_make( [ 0xeecfe3da , 0xb84ec883 , 0x663d7f63 , 0x1def7745 , 0xc9eba547 , 0xe6dbd123 , 0xad6b60f9 , 0x2cc9a4eb ] )
+  , Mont::unsafe_make( [ 0x3be0b418 , 0xe3e91676 , 0x57c792a1 , 0xb634b678 , 0x7c9fa495 , 0x38dd393e , 0x986303c8 , 0x25b4a04b ] )
+  , Mont::unsafe_make( [ 0x724f5509 , 0x73105f61 , 0x1bcf34ef , 0x49b9cca3 , 0x39aa7e50 , 0xe8c888bc , 0xad141a0f , 0x029b2709 ] )
+  , Mont::unsafe_make( [ 0x71d3e91d , 0x603cef0f , 0x8383c5fd , 0xa3469b1b , 0x691ba9ca , 0x68ddb29f , 0x8d055764 , 0x10944110 ] )
+  , Mont::unsafe_make( [ 0xeaa4e6e8 , 0x088b5cad , 0xc510d459 , 0x2395aab6 , 0x3cc66e61 , 0xed66bb70 , 0x318c14d6 , 0x0530ecef ] )
+  , Mont::unsafe_make( [ 0xc0f2ebb5 , 0x5c770174 , 0xaa913400 , 0x018bb92a , 0x532e4aa0 , 0x1619466c , 0xf0c6a06b , 0x01dae37c ] )
+  , Mont::unsafe_make( [ 0x90093340 , 0xbd2fbee2 , 0xa7ec7ef9 , 0xf3c9123b , 0x08980e10 , 0xa3f7ee52 , 0x78c29a52 , 0x0324005f ] )
+  , Mont::unsafe_make( [ 0x0f92c060 , 0xc8099874 , 0xff9ee766 , 0x10cfb389 , 0xd8570f17 , 0x89eec9f1 , 0xc7bb7c4d , 0x234aeb60 ] )
+  , Mont::unsafe_make( [ 0x8e123c2f , 0x0b80a84e , 0x3bb0f84b , 0x4806951c , 0x12034610 , 0x919d5476 , 0xd801a0a7 , 0x0b0a8212 ] )
+  , Mont::unsafe_make( [ 0x7c350739 , 0xf4de0439 , 0x1a67aec1 , 0xf6993c2d , 0xf4183db7 , 0x58c41230 , 0x81e7ddf7 , 0x1dad4a40 ] )
+  , Mont::unsafe_make( [ 0xf0301300 , 0xbe15868f , 0x345d6169 , 0xc8a1d6bd , 0xfa12d712 , 0xd96fa6ed , 0xb6e636c5 , 0x027c5284 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x2a1799c5 , 0x982b6e38 , 0xae41911a , 0x8bc985e6 , 0x8c76e52b , 0xd1831fd7 , 0xed835142 , 0x01208f93 ] )
+  , Mont::unsafe_make( [ 0x2967d27e , 0x49881e37 , 0x829603d5 , 0xb31138a3 , 0xc469b03b , 0x3e9518be , 0x4b2c3388 , 0x1f2ffac2 ] )
+  , Mont::unsafe_make( [ 0x97bcfa58 , 0x2ea2bc30 , 0x7fb000c1 , 0x2981f2d1 , 0xe7d0739d , 0x6edbf1b7 , 0x1dea6af3 , 0x066e8067 ] )
+  , Mont::unsafe_make( [ 0x4e49178a , 0xe8476984 , 0xab3eca72 , 0x32d18cbf , 0xf2927f95 , 0x6ec8743d , 0x22f63455 , 0x2f95e32b ] )
+  , Mont::unsafe_make( [ 0x96408df5 , 0xff083449 , 0xb0bf3bba , 0x3cbd8e9f , 0xf441115e , 0x15065e40 , 0x0ecd566c , 0x01ea8e0c ] )
+  , Mont::unsafe_make( [ 0x75d4af3f , 0x79ae9af7 , 0xa6d3cd8c , 0xd19c0ec0 , 0x7a23445b , 0xb557dbc5 , 0xce57dc5c , 0x225a09be ] )
+  , Mont::unsafe_make( [ 0x7d851968 , 0xdb1ec690 , 0xfd89ff2a , 0x3e0175c4 , 0xcb26eb1e , 0x7ef788b8 , 0xfbda02b3 , 0x0d79ad85 ] )
+  , Mont::unsafe_make( [ 0xedd1e6f8 , 0xb057ebe1 , 0xb87833b4 , 0x7726d7b2 , 0x3d81933d , 0x22e38c39 , 0xaa0f0413 , 0x0a846626 ] )
+  , Mont::unsafe_make( [ 0x2f266d5b , 0x14c7d6f2 , 0x13005302 , 0x750d5173 , 0x03e08fe9 , 0x8949dc5c , 0x1648518b , 0x1465d710 ] )
+  , Mont::unsafe_make( [ 0xb223a739 , 0xfef4ccd4 , 0x8e9a1012 , 0x52f06d17 , 0x54db0db3 , 0x5f30607f , 0xa6bd5a6f , 0x2a1e4f2b ] )
+  , Mont::unsafe_make( [ 0xbea50e6e , 0xfad23b63 , 0x2c4c5516 , 0x8be2c287 , 0x007e2537 , 0x116d28f6 , 0x53c05881 , 0x2aa0bd7e ] )
+  , Mont::unsafe_make( [ 0xf0225452 , 0xf9bd1e92 , 0xa1e46a9d , 0x64c81ea4 , 0x0ae390c1 , 0xe5eba39c , 0x60cd83b7 , 0x02cf67ef ] )
+  , Mont::unsafe_make( [ 0x0d1784a6 , 0xe4a6d30c , 0x6c6d1150 , 0x1a85979e , 0x03aad350 , 0xf6c92bc0 , 0xe7f280a8 , 0x2aee1820 ] )
+  , Mont::unsafe_make( [ 0x2774cdbb , 0xd94f5b66 , 0x17739271 , 0xbd827a59 , 0x50d1db08 , 0xde642052 , 0x70cad38d , 0x28749c47 ] )
+  , Mont::unsafe_make( [ 0x31d0268d , 0xa339dfe5 , 0x38563cee , 0x5e4a5077 , 0xddde36f2 , 0x01d1779f , 0x63252640 , 0x2aa3e96a ] )
+  , Mont::unsafe_make( [ 0x3626d931 , 0x3333254c , 0x544a9a5e , 0x16c597c0 , 0x68a33d41 , 0xc9548b32 , 0x3f285d2f , 0x0aeaadd8 ] )
+  , Mont::unsafe_make( [ 0x464387f8 , 0x6218ae46 , 0xe195f479 , 0xb41073f4 , 0x37b8533a , 0xda42a40c , 0xaa1c6450 , 0x08d4954a ] )
+  , Mont::unsafe_make( [ 0xd87767b8 , 0xe78c7291 , 0xe08be576 , 0xbb8ae692 , 0xf89d8719 , 0x468df298 , 0x3b06af89 , 0x2dcd0019 ] )
+  , Mont::unsafe_make( [ 0x027c8c98 , 0x2847b3db , 0xd821bf5b , 0x397b4d9e , 0x3ff55d2d , 0x0eadb6e4 , 0x8139136b , 0x117aaa69 ] )
+  , Mont::unsafe_make( [ 0x5183c0fd , 0x94c21de5 , 0xcff460da , 0x0950b32b , 0x8bc2be35 , 0x59f57918 , 0x90978063 , 0x2b53308c ] )
+  , Mont::unsafe_make( [ 0x73f45114 , 0xbd3af5e9 , 0x26bb08f7 , 0x56414de3 , 0x493cfafe , 0x53b261e4 , 0x2c97d8fa , 0x0bc9c743 ] )
+  , Mont::unsafe_make( [ 0xcdeb768a , 0xc6123b49 , 0x32c24d18 , 0xaf52c32e , 0x9ee6b598 , 0xe5a113f4 , 0xa28a58b6 , 0x07315c55 ] )
+  , Mont::unsafe_make( [ 0x269c7246 , 0x7c451571 , 0x1951cb28 , 0x275b535a , 0x63450d90 , 0xe6aa8567 , 0x6a951e87 , 0x0f7a10eb ] )
+  , Mont::unsafe_make( [ 0x9daaa4fc , 0x4e6ad081 , 0x52f4324e , 0xbc27d6cd , 0x4b568eff , 0xfdf18f98 , 0x9cb5eabd , 0x12959a4b ] )
+  , Mont::unsafe_make( [ 0x62dd62b4 , 0xf11e9034 , 0xe4818aa6 , 0xf221b9a9 , 0x960605b1 , 0x99745094 , 0x4838f28b , 0x05cece9b ] )
+  , Mont::unsafe_make( [ 0x9bf89119 , 0x87ec64db , 0x04d3016f , 0xaaf61139 , 0x83da7f97 , 0xfcce394e , 0xf385ae87 , 0x11dd9eea ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x41396e79 , 0xdabb0eb3 , 0x8a3fcac6 , 0xcb569a99 , 0xa26f395f , 0x197b85a8 , 0xc734f709 , 0x0aa5d0f8 ] )
+  , Mont::unsafe_make( [ 0x51597282 , 0xba329932 , 0x9af24189 , 0xf6b3c7c9 , 0x95c08134 , 0x2def5e3b , 0x4e39de44 , 0x2c50ba13 ] )
+  , Mont::unsafe_make( [ 0x2793e163 , 0xe5da3195 , 0xd7a0c4ec , 0x28c3976c , 0xe461b960 , 0x753080e9 , 0x71241ada , 0x27dd45f9 ] )
+  , Mont::unsafe_make( [ 0x72d10edf , 0x28fcfd68 , 0xb6ec7574 , 0xabc555ea , 0xf587e619 , 0x47948abe , 0xa555c68a , 0x0053b269 ] )
+  , Mont::unsafe_make( [ 0xd964fa83 , 0x7c01ca57 , 0x03f9eaae , 0xcff48c9c , 0xec4dea7f , 0xa6023801 , 0xddd0f49f , 0x1d93737a ] )
+  , Mont::unsafe_make( [ 0xcb4adc7f , 0xdca971d1 , 0x47090172 , 0xb08b86eb , 0xe8735d88 , 0x72f0dfab , 0x418085aa , 0x0ac7472a ] )
+  , Mont::unsafe_make( [ 0x7634bb13 , 0xa3110398 , 0x6c80798d , 0x8e22170a , 0x0a61040f , 0x1c061b53 , 0x3991192b , 0x1b92d2e9 ] )
+  , Mont::unsafe_make( [ 0xd9f5c78e , 0xf580fc42 , 0x21e34014 , 0x710c4142 , 0x731eda0f , 0xce8243ab , 0x57086067 , 0x2b66090c ] )
+  , Mont::unsafe_make( [ 0xb16206ad , 0x5b847ab5 , 0x865fbf52 , 0x95b3c43d , 0xdd46e843 , 0x88596ebf , 0x3e03287b , 0x2404303c ] )
+  , Mont::unsafe_make( [ 0x98c03d30 , 0x183686c9 , 0x01f6b7fc , 0xbe548643 , 0xc12ece56 , 0x8cb32d42 , 0xb9eb2ebe , 0x00b90612 ] )
+  , Mont::unsafe_make( [ 0xe2506268 , 0xde5814e1 , 0x61f63fce , 0x390e8b26 , 0xcffceb1e , 0xb953f032 , 0x288f79cb , 0x1f799647 ] )
+  , Mont::unsafe_make( [ 0xe0b417b8 , 0x66df757d , 0x41e211de , 0x393debfa , 0x29d1dbba , 0x1dfc960d , 0x36754abc , 0x1d323141 ] )
+  , Mont::unsafe_make( [ 0x60b8bf2f , 0xe997f5a5 , 0xd04c3e11 , 0xf9d76655 , 0xd888838a , 0x6f8236d4 , 0x2e75979d , 0x0ad620c4 ] )
+  , Mont::unsafe_make( [ 0x551047e8 , 0x7f9bf2d5 , 0x4b85ae52 , 0x9f1222c5 , 0x34d774ad , 0x3f451926 , 0x93492596 , 0x02535480 ] )
+  , Mont::unsafe_make( [ 0x3dc32247 , 0xb5968bf3 , 0x86a000a1 , 0x6ec61647 , 0xb555949b , 0x621bf693 , 0x625f855d , 0x0113ca54 ] )
+  , Mont::unsafe_make( [ 0x968b7cba , 0xcb417cea , 0x2a64b74f , 0xc15ecddc , 0xb5e4ea13 , 0xbde217db , 0x4f1f543d , 0x287999d8 ] )
+  , Mont::unsafe_make( [ 0x1d935954 , 0xf6fc8523 , 0x354d7e5b , 0xe0628ac2 , 0x0276d483 , 0x6e6dbe65 , 0xce025427 , 0x0344a942 ] )
+  , Mont::unsafe_make( [ 0x32471fb1 , 0x812ce6d3 , 0xbe539c02 , 0x9e9878e2 , 0x978e0102 , 0x0b28dece , 0x16800129 , 0x2beb72cb ] )
+  , Mont::unsafe_make( [ 0x7ce79847 , 0x1871adda , 0x0f01b5ec , 0x376e4999 , 0x0a116811 , 0x13997617 , 0xb45db87f , 0x2422fb86 ] )
+  , Mont::unsafe_make( [ 0xe0e70b61 , 0x1365ff7b , 0xbc015c6b , 0x18176707 , 0x9232914f , 0xe73665f3 , 0xb92eb4ca , 0x10c13e62 ] )
+  , Mont::unsafe_make( [ 0xd6fce5a0 , 0xbbde1d32 , 0x202df6fc , 0x86ad0e3f , 0x02bf1f8d , 0x1ae666bb , 0x0ca531b3 , 0x204c6aa1 ] )
+  , Mont::unsafe_make( [ 0xaff04d44 , 0xdcb49fa6 , 0x1804680b , 0x9130d36d , 0xace177fb , 0x898d5efd , 0x93a41af6 , 0x0dc06513 ] )
+  , Mont::unsafe_make( [ 0x31a1daad , 0xf0859d51 , 0xbd6f77d1 , 0x83a9814f , 0xe32fcc89 , 0xf1212df7 , 0x12e41234 , 0x1b77b643 ] )
+  , Mont::unsafe_make( [ 0x20e7d37d , 0xed922dff , 0xb44a3b45 , 0x9d0a21e2 , 0xb70e35bd , 0x361814a9 , 0x82474d23 , 0x01f1ee41 ] )
+  , Mont::unsafe_make( [ 0x5b240d2c , 0xe96d0b08 , 0x39a46c6f , 0x278cdac4 , 0x29cf7ae2 , 0x20e6ad2d , 0x69782fb7 , 0x16438c44 ] )
+  , Mont::unsafe_make( [ 0x3c3eff7b , 0xd25db20e , 0x3f865779 , 0x5e6a46d1 , 0xa09207e7 , 0xfe404458 , 0x6427a6b9 , 0x0d18aee0 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x8d52815a , 0xda7af340 , 0x8b6712a5 , 0x1ac90fd6 , 0xd9995fa2 , 0x5551ef6c , 0x95cdb4a5 , 0x1c373b71 ] )
+  , Mont::unsafe_make( [ 0xec7f846f , 0x3b9befc4 , 0xc9e7ff3b , 0xba894a4a , 0x323958a2 , 0x7e726a18 , 0x4bdb3fb4 , 0x024dd091 ] )
+  , Mont::unsafe_make( [ 0x69609ef1 , 0xde6db977 , 0xd953013a , 0xdf732b66 , 0x45b4efb7 , 0xb1a37591 , 0x7443eeb1 , 0x0b834d4c ] )
+  , Mont::unsafe_make( [ 0xae2a2d4a , 0x43dc0f5d , 0x6cbbecd0 , 0xd36b0755 , 0x3688a2d2 , 0x764be3b0 , 0x872d39c5 , 0x01821fd5 ] )
+  , Mont::unsafe_make( [ 0xde7daa87 , 0x0b2dc847 , 0xe28a7caf , 0xa0f8eeb2 , 0xdd7a2b19 , 0x6d0ed02d , 0x01c89cdb , 0x2f4861a9 ] )
+  , Mont::unsafe_make( [ 0xef524548 , 0x7cc40fef , 0xc43e1c33 , 0x2fd39db2 , 0x97239bb9 , 0x5f3327e6 , 0xd04c4a4e , 0x254d6730 ] )
+  , Mont::unsafe_make( [ 0xa82cd514 , 0xf4d5ed37 , 0xc8563144 , 0x8361beda , 0x0cbf8597 , 0x090d7c02 , 0xeab3706d , 0x08dc4e19 ] )
+  , Mont::unsafe_make( [ 0x10599d10 , 0xf5837c15 , 0xe3a7bd08 , 0x061c39e3 , 0xf0b878dc , 0x1cfd7859 , 0xc3638d00 , 0x085a6468 ] )
+  , Mont::unsafe_make( [ 0x55da08cf , 0x1884984f , 0xac7f4e53 , 0x5dfb9ccd , 0x6fec01cb , 0x3bab74a0 , 0x19575774 , 0x0372f0ad ] )
+  , Mont::unsafe_make( [ 0x115b00b3 , 0x149714d5 , 0xbec860d8 , 0x1fcc77f7 , 0xf8c81c08 , 0x06e00cf3 , 0xa207c051 , 0x2c5a66de ] )
+  , Mont::unsafe_make( [ 0x848d2871 , 0x47b081fa , 0x2eb34a4a , 0xaa8bdf8b , 0xc845e494 , 0x20521b68 , 0x938593fb , 0x160301da ] )
+  , Mont::unsafe_make( [ 0xa2ed0983 , 0x290623d3 , 0x63085bb8 , 0x023d941f , 0x3253abc3 , 0x584da749 , 0xd30a69fd , 0x19125c90 ] )
+  , Mont::unsafe_make( [ 0x0c2391ae , 0x052a7009 , 0xf98af3d3 , 0xdc27b13d , 0xcc79c952 , 0x6405cb96 , 0xa6383953 , 0x0c6c5e2a ] )
+  , Mont::unsafe_make( [ 0x96f1aa53 , 0x8ea2a2e4 , 0x54244dff , 0x7decc222 , 0x6f690fa6 , 0x7512fb5c , 0xbd9e4568 , 0x082bd3ab ] )
+  , Mont::unsafe_make( [ 0x102725dc , 0x7cdc6c06 , 0xe432e8ea , 0x4e0c524f , 0xc7ddaec9 , 0xe062411b , 0x684bd8bd , 0x30417468 ] )
+  , Mont::unsafe_make( [ 0xdead8f19 , 0x28a0a883 , 0x10701a03 , 0x9e15a643 , 0x0ec02f96 , 0xf7381482 , 0x4928b895 , 0x22448ea8 ] )
+  , Mont::unsafe_make( [ 0x5959efee , 0x684b806a , 0xce396538 , 0x75cc4909 , 0x9a8cf627 , 0x99a40076 , 0xd0f11105 , 0x1e88b244 ] )
+  , Mont::unsafe_make( [ 0x65feb229 , 0xa15dcaa2 , 0x611c0efb , 0x1939bec1 , 0x842bf391 , 0xe287f43a , 0xa671b7f7 , 0x15ccc7db ] )
+  , Mont::unsafe_make( [ 0x156e9d62 , 0xbbad50d0 , 0x00213cd2 , 0xd1ad5f67 , 0xefb8ee6c , 0xdb027bf6 , 0x632ad229 , 0x0152d19f ] )
+  , Mont::unsafe_make( [ 0x08f49be6 , 0x778f91de , 0xc79b978d , 0x245bf90e , 0xba7bdbcb , 0xed0e46d8 , 0x72d0168a , 0x0d976bdd ] )
+  , Mont::unsafe_make( [ 0x02e785c5 , 0xb0248817 , 0x6658b441 , 0x65cc154b , 0x6371fdc1 , 0x309d7bb9 , 0x27531e3b , 0x0b9947b3 ] )
+  , Mont::unsafe_make( [ 0x8973ccb0 , 0x85f5a0ec , 0x6e490a0f , 0x13c297d5 , 0x2784c354 , 0x37e51860 , 0x3ecc9167 , 0x29b0795a ] )
+  , Mont::unsafe_make( [ 0x66515549 , 0xaada5ebc , 0xea83689e , 0x6cdac55d , 0x78cadc9f , 0x3daa6cc5 , 0x31145157 , 0x258f75c7 ] )
+  , Mont::unsafe_make( [ 0x1bf0efdb , 0x156afa8b , 0xeccbc628 , 0x13d50ff8 , 0x79b3aca4 , 0x22017fec , 0x221211f9 , 0x275ab389 ] )
+  , Mont::unsafe_make( [ 0xdcff5255 , 0x16f1ce78 , 0xf91906a7 , 0x0dbdd9cb , 0x13c0354c , 0xc24ea6ed , 0x08ab787c , 0x1d8088a2 ] )
+  , Mont::unsafe_make( [ 0x3603d9b5 , 0x19b30a9a , 0x43ffff03 , 0x428a1b99 , 0xf55d25e1 , 0x85265a79 , 0xd4469f24 , 0x1c1323e6 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x58a60cca , 0xe27c019c , 0xf0ed76d8 , 0x6dfa60bf , 0x0484c2a1 , 0x3420794e , 0xb5ef03df , 0x1232fc37 ] )
+  , Mont::unsafe_make( [ 0xcc6dd73b , 0x7c95b6e8 , 0x49477508 , 0x4ae5626d , 0x57313a8e , 0xb5795ca9 , 0xdd5ae504 , 0x02fc1917 ] )
+  , Mont::unsafe_make( [ 0xa392c864 , 0x90da62c5 , 0x27fd573c , 0xa7b42e5d , 0xd375f875 , 0xd85fb3b6 , 0x56bec7af , 0x082ea077 ] )
+  , Mont::unsafe_make( [ 0x7eecdadd , 0xf156c682 , 0x916cc54a , 0x087581b8 , 0xaa1e9bd6 , 0xc0d91cf0 , 0x57a0c376 , 0x06c064f0 ] )
+  , Mont::unsafe_make( [ 0x9b6932f2 , 0xdd34adbd , 0xa5734b0c , 0xfeafbc76 , 0xd612ca4a , 0x6577e8ec , 0xe60acfee , 0x13d43d73 ] )
+  , Mont::unsafe_make( [ 0xfd5ce65b , 0x47254831 , 0xf50e411f , 0x5c4a6cfe , 0xe09f76b3 , 0x2daeca78 , 0xee3d8542 , 0x2d1af170 ] )
+  , Mont::unsafe_make( [ 0x0cf0d869 , 0x189a5488 , 0x9aba4603 , 0x58a9c6c9 , 0xab384a9a , 0xa7ea28ee , 0xa04a6471 , 0x2343cf39 ] )
+  , Mont::unsafe_make( [ 0x5da89b48 , 0xc1f40cda , 0xacba18b0 , 0x4a4aa8a8 , 0x0192a4c6 , 0x2c3e6fa2 , 0x69455f90 , 0x0de6f85c ] )
+  , Mont::unsafe_make( [ 0xf995ba80 , 0xafcb3150 , 0xd917fda7 , 0xcf13da91 , 0xea0abb66 , 0xe47462c2 , 0x1443f1ec , 0x21f0b600 ] )
+  , Mont::unsafe_make( [ 0xb72901bf , 0xc70c7409 , 0x564d270c , 0x1d780b86 , 0xcceff34b , 0xc5d1b72a , 0xe2a3723a , 0x1c3d6f21 ] )
+  , Mont::unsafe_make( [ 0xff4d7dbe , 0xd7078f89 , 0x2038cd46 , 0x368b8189 , 0x4119adf0 , 0xcb7d4adb , 0x19197d38 , 0x2dad9dcf ] )
+  , Mont::unsafe_make( [ 0xbd9791ab , 0x81631ea7 , 0x9866ae96 , 0xc26f5919 , 0xcc3b1f4c , 0x63e63378 , 0xe55f7203 , 0x283da8bd ] )
+  , Mont::unsafe_make( [ 0x3158a9d5 , 0xdaa64b83 , 0xf81dd729 , 0x29969101 , 0x499a6d8d , 0xdc164862 , 0xa9866956 , 0x10e31734 ] )
+  , Mont::unsafe_make( [ 0xcde66f77 , 0xe263262e , 0x755b5c4b , 0xbb4a10b3 , 0x83fc9f1f , 0x6f150413 , 0x32377347 , 0x20a9aed8 ] )
+  , Mont::unsafe_make( [ 0x5aa1feca , 0xea21d8e5 , 0xb27c936c , 0x2571ae29 , 0xa1afb48e , 0x22f8861d , 0xe5836f7d , 0x031d2f58 ] )
+  , Mont::unsafe_make( [ 0xb26a77e4 , 0xdcf86858 , 0x4efce35a , 0x131ec9d5 , 0x25354724 , 0x3413b03c , 0x0ca8dcbe , 0x0a493cb4 ] )
+  , Mont::unsafe_make( [ 0x2cbec459 , 0xbadc6c45 , 0x15bdede4 , 0x7f163eb7 , 0x26626fc1 , 0x57e2a379 , 0x5ef1ba68 , 0x28a6f451 ] )
+  , Mont::unsafe_make( [ 0x3bc9d911 , 0xecb5ae76 , 0x84204c75 , 0xfb583a01 , 0x0b05e85c , 0xb0530ce6 , 0x2895bae1 , 0x2fc13787 ] )
+  , Mont::unsafe_make( [ 0xfc298984 , 0xaed29e67 , 0x03016735 , 0xd2e7c0f3 , 0x9fedb8de , 0xf6190cc3 , 0xf47e6a4b , 0x0660ef3f ] )
+  , Mont::unsafe_make( [ 0xffb6e292 , 0x909e6b6b , 0xe77c8cf6 , 0x9a6e6dde , 0xbeda7f59 , 0x05734152 , 0xefa6fd52 , 0x1bb80e5f ] )
+  , Mont::unsafe_make( [ 0x30bbec1c , 0x79762e25 , 0x2ec2b3d2 , 0x1c8a62f8 , 0xe44cd145 , 0xcf248e36 , 0xd684683f , 0x14cdf49a ] )
+  , Mont::unsafe_make( [ 0x40cf16b1 , 0x121055ce , 0x80544838 , 0x444c984d , 0x5cbcf151 , 0xe0a509ca , 0xcaa5957c , 0x202ecca1 ] )
+  , Mont::unsafe_make( [ 0x8e39d981 , 0x30110d77 , 0x78261695 , 0xc3d5bc53 , 0xbd33e73b , 0x6dc3f073 , 0xcb2c832e , 0x2c8047cc ] )
+  , Mont::unsafe_make( [ 0xd7994cf1 , 0x7772c47b , 0xe949dfe6 , 0x99e9e69e , 0x89d824a4 , 0xba89f41d , 0x0caf02bf , 0x24edc7d5 ] )
+  , Mont::unsafe_make( [ 0xaaa4b794 , 0xfda843b2 , 0xe894a5aa , 0x9182726b , 0x55076fe1 , 0xf199d2f2 , 0x10be0f5e , 0x18f2bd38 ] )
+  , Mont::unsafe_make( [ 0xb31c0e2b , 0x59b48755 , 0x245d5de9 , 0xf1fe49e9 , 0xdf1dd766 , 0xef0f3236 , 0x7eea300b , 0x09911f48 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x01250f6a , 0x99409456 , 0xae11f4a4 , 0x75c7144f , 0x44d7a743 , 0xe66787b2 , 0x66576be8 , 0x20d6b203 ] )
+  , Mont::unsafe_make( [ 0x9d138243 , 0xa86e5154 , 0x6dbc7bee , 0x390136c9 , 0x0d11ef2f , 0x1f543e22 , 0x8cfb9400 , 0x15afc865 ] )
+  , Mont::unsafe_make( [ 0x1cd010ee , 0xeaea4580 , 0x2f733c19 , 0xa8a422ab , 0x225db29d , 0xb5298358 , 0xf5030166 , 0x0d0e36e7 ] )
+  , Mont::unsafe_make( [ 0xa2d47315 , 0x8c303f2e , 0x72677654 , 0x70d6a794 , 0xfea53572 , 0x8224f819 , 0xe1c39917 , 0x071d1745 ] )
+  , Mont::unsafe_make( [ 0x2ad18359 , 0x9c1d15e5 , 0x093afa49 , 0x7d5fc529 , 0x3cb83ec0 , 0xac451fa0 , 0x2d1979cd , 0x09b097ee ] )
+  , Mont::unsafe_make( [ 0x81101510 , 0xf540bafb , 0x8be1362e , 0x8ac3b073 , 0x8802ec43 , 0x9a937ce1 , 0x5694afdf , 0x2e2990d4 ] )
+  , Mont::unsafe_make( [ 0x9b1f9a81 , 0xee7b7d8f , 0x69e0c5fd , 0x1340a28d , 0x81a119cb , 0x86662676 , 0xbd02dd16 , 0x0c6160f9 ] )
+  , Mont::unsafe_make( [ 0x0b42f190 , 0x0e156516 , 0x941cf6b1 , 0x83d4e7cc , 0x6276fd4e , 0x91e00150 , 0xd3501d19 , 0x0ac2b96e ] )
+  , Mont::unsafe_make( [ 0x72bc5a8a , 0xfac4fc16 , 0x910eb834 , 0x37e9570d , 0x5c2bd4cc , 0x50479300 , 0x39117c0e , 0x2defcd84 ] )
+  , Mont::unsafe_make( [ 0xbfc01b12 , 0x827bfdcb , 0x3b16a95f , 0x493a2c3f , 0x18d9e41a , 0xde949656 , 0x135b9b8b , 0x2dc92cbe ] )
+  , Mont::unsafe_make( [ 0xc5e2b911 , 0x2f7b86d9 , 0x757cc487 , 0xdf63bcc8 , 0x1a88800f , 0x66a30d32 , 0xe0ba50c4 , 0x0010a08e ] )
+  , Mont::unsafe_make( [ 0x9a054168 , 0xfc2575dc , 0xb44b882b , 0x4f021494 , 0xce8a0f33 , 0xd6d33254 , 0x60bd0125 , 0x246cc0d4 ] )
+  , Mont::unsafe_make( [ 0xe4854cf8 , 0x77f90edf , 0x773872d2 , 0x58071a85 , 0x88cc0776 , 0x427938b2 , 0xa04aff01 , 0x1a8131c7 ] )
+  , Mont::unsafe_make( [ 0xb604fa9a , 0x91166ed1 , 0xf931ec61 , 0xaee4ae44 , 0x0bc93a24 , 0xab7377b1 , 0xe257d04b , 0x0e64932a ] )
+  , Mont::unsafe_make( [ 0x1283afcd , 0x9ad6e603 , 0xd5460adc , 0x921e6eef , 0x08d9a846 , 0x9e25d04c , 0xa5c919b0 , 0x2086ad5c ] )
+  , Mont::unsafe_make( [ 0x1bf6c1e0 , 0xfb806958 , 0xeac9a647 , 0xc24f2435 , 0xfc9398bd , 0x1d37056d , 0xb322d84a , 0x24719aaf ] )
+  , Mont::unsafe_make( [ 0x31b60859 , 0x60d152f3 , 0xb4b856c8 , 0xde89c6ae , 0x0b2671f4 , 0xbf16d6a5 , 0x9b6d1fa7 , 0x2098bd9e ] )
+  , Mont::unsafe_make( [ 0x4b30cbe3 , 0xab24f141 , 0xbba96d1c , 0x7f2fd05c , 0x22133d78 , 0x89d590ac , 0x1a12891d , 0x0bb266bb ] )
+  , Mont::unsafe_make( [ 0xdbdd83ec , 0x82973adc , 0xdacdda97 , 0xee3bd894 , 0xc4a1081a , 0x560e1116 , 0x3a868b4d , 0x2169e3f8 ] )
+  , Mont::unsafe_make( [ 0x8c20de40 , 0xcb08c46e , 0xcf221a50 , 0x6bb7a2de , 0xf9831c38 , 0xfafbe4f1 , 0x738375bb , 0x0e2fd899 ] )
+  , Mont::unsafe_make( [ 0x76186aaf , 0xdbd72e04 , 0x85d4ba91 , 0x3907cf10 , 0x7b051bff , 0xcb9a04cb , 0x2cd7d9cd , 0x28275838 ] )
+  , Mont::unsafe_make( [ 0x82405193 , 0x9380fd25 , 0xe57a4cca , 0x4a898759 , 0x4307e377 , 0xea89bcc4 , 0x2f1441a0 , 0x07a0a2c9 ] )
+  , Mont::unsafe_make( [ 0x6813bd4a , 0x721b071b , 0x44edca57 , 0xb52d947d , 0xb8d30ba0 , 0x292d57e4 , 0x6b30a6a2 , 0x2e4f88f1 ] )
+  , Mont::unsafe_make( [ 0x183e84d0 , 0xb6d6fe19 , 0x7ab4c33c , 0x20d89af3 , 0x6466d362 , 0x67a29ea5 , 0xc93f9c33 , 0x12efb614 ] )
+  , Mont::unsafe_make( [ 0xa527b1cb , 0x83243815 , 0x51d4f335 , 0xf7b6a2bf , 0x6f9c3d61 , 0xf4a7627b , 0x71bd2456 , 0x10a46481 ] )
+  , Mont::unsafe_make( [ 0x7b7d4387 , 0x5eef7152 , 0x95c064e0 , 0x0f848881 , 0xa81108ef , 0x451f5328 , 0x3672c195 , 0x22d81037 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xd5e14cba , 0x3196158e , 0x1ce12e04 , 0x925e9b88 , 0x07909083 , 0xbe8234cc , 0x4e302643 , 0x1b3d30b9 ] )
+  , Mont::unsafe_make( [ 0x5df0f150 , 0xcda00e81 , 0x1f9f0e23 , 0xa84a747e , 0x709f6dc4 , 0xa64dc06f , 0x062d8302 , 0x183c1732 ] )
+  , Mont::unsafe_make( [ 0x7b32c103 , 0x7fa4a081 , 0x3d2cbdab , 0x3cbb46a8 , 0x6f68ae51 , 0xf01d4709 , 0x56a2289e , 0x2e88889b ] )
+  , Mont::unsafe_make( [ 0x20d91380 , 0x7b8b49f9 , 0x29bf95f6 , 0x20d189ee , 0xdff81bb0 , 0x9de6a7a5 , 0x0ff48f6f , 0x10287552 ] )
+  , Mont::unsafe_make( [ 0xe04995d2 , 0xc9c35ccf , 0x22ea6066 , 0x2603bf9a , 0xd5d1b446 , 0x6bb3e183 , 0xc968eca0 , 0x197dae99 ] )
+  , Mont::unsafe_make( [ 0x7aad5b07 , 0x78d710d4 , 0x8339af79 , 0x597f26c2 , 0x4de7049f , 0x0aea047d , 0x32999314 , 0x082b0885 ] )
+  , Mont::unsafe_make( [ 0xd036a8be , 0x222ea814 , 0x9b4dbe0b , 0xdbd7cc1f , 0x83a68a62 , 0x4031f744 , 0x2c9b4d8f , 0x1be8209a ] )
+  , Mont::unsafe_make( [ 0x9c3609ca , 0xa384ddd8 , 0x4b6ff9cd , 0x583cc703 , 0xe28166f1 , 0xd845f31b , 0x49734cc5 , 0x0ee6d627 ] )
+  , Mont::unsafe_make( [ 0x7ede3d72 , 0xb7369b34 , 0xdabad280 , 0x2028f9c3 , 0x3c923356 , 0x6bebf7ab , 0x1b2f9123 , 0x276ec559 ] )
+  , Mont::unsafe_make( [ 0x383bbb5c , 0x5f2463c7 , 0xce287ef9 , 0xb3f687bb , 0xba5baec3 , 0x3d251aad , 0xf4e9581b , 0x06ac03d0 ] )
+  , Mont::unsafe_make( [ 0x1f2bed5a , 0x79a4df0b , 0xdfd1a17d , 0x84b45198 , 0xedd0454e , 0x8a0131a4 , 0xb2031da0 , 0x1818e3a3 ] )
+  , Mont::unsafe_make( [ 0x0e80468f , 0xe369a9ae , 0xe7ee19ea , 0xcceaa53b , 0x0d3b0f61 , 0xe6934bbe , 0x9c484a41 , 0x188b286b ] )
+  , Mont::unsafe_make( [ 0x6caeded3 , 0x1dcd7ed6 , 0x1147934b , 0x4f05f20f , 0x13ed8e46 , 0x1ce1bd4b , 0x2adf2fc7 , 0x2e948aba ] )
+  , Mont::unsafe_make( [ 0xc5ea6409 , 0x38cebd27 , 0xeaa626b8 , 0x9d360280 , 0x23f32cb6 , 0x52c66702 , 0xb9f0d194 , 0x01723c73 ] )
+  , Mont::unsafe_make( [ 0xbeac1ef1 , 0x9bf8db06 , 0xd271b7cc , 0x0ad4f15f , 0x7262c381 , 0xfdf8e617 , 0xcdfb0676 , 0x0973a8a8 ] )
+  , Mont::unsafe_make( [ 0x6b362379 , 0xb0ce258f , 0x6907727b , 0x83922300 , 0xac8f9a57 , 0xdc52ab3d , 0xa78ae4ec , 0x1bcde2f2 ] )
+  , Mont::unsafe_make( [ 0xa82dd4f2 , 0x1822a616 , 0x9b978e9c , 0x1fd8bc63 , 0xb95463e8 , 0xf4fe1ad5 , 0xf795c0fd , 0x21cf5850 ] )
+  , Mont::unsafe_make( [ 0xf41b3fce , 0x42ce7a10 , 0x99329def , 0xb3603330 , 0xecd45598 , 0x8139c78e , 0xc56cd5eb , 0x2c7a6891 ] )
+  , Mont::unsafe_make( [ 0xa4bc27a9 , 0xc6af7060 , 0xca84d908 , 0xc96a6c5d , 0xed7fcc07 , 0x84ea127b , 0x32c33815 , 0x0caae016 ] )
+  , Mont::unsafe_make( [ 0x34246019 , 0xa6d2c7ae , 0x9732b0b3 , 0xb3dac823 , 0xb9d42245 , 0x368640cb , 0x9295d709 , 0x0497d03f ] )
+  , Mont::unsafe_make( [ 0x7eb5aeec , 0x62712c92 , 0x52a7d770 , 0x7d7f6384 , 0x418cb5eb , 0xbdf99ed6 , 0x0e76cf1c , 0x1e262a7f ] )
+  , Mont::unsafe_make( [ 0x7126429b , 0x3a769df7 , 0xedc6f250 , 0xdc5d1945 , 0xcbad15f1 , 0x78098fa4 , 0x2c17af7b , 0x1aa076d9 ] )
+  , Mont::unsafe_make( [ 0x6743e7b4 , 0xfca9c5f4 , 0x1b210ab2 , 0xbc488bd2 , 0xe71464f9 , 0x82515661 , 0x6385ef82 , 0x0595c8fd ] )
+  , Mont::unsafe_make( [ 0x2a18e921 , 0x644331e0 , 0x63efb3e5 , 0x69fe1c2f , 0xd7b515f8 , 0xea57caa6 , 0x9c9f4f9f , 0x0004eeb3 ] )
+  , Mont::unsafe_make( [ 0x4be376bb , 0x7ecedf8c , 0x854fe5b8 , 0xfa3bfabf , 0xae9db414 , 0x5487d01f , 0x5db9e6ab , 0x1e4523f4 ] )
+  , Mont::unsafe_make( [ 0xc233d4d1 , 0x67aeb0f4 , 0x33ef0740 , 0x395834b9 , 0xe95aeb06 , 0xe45dc069 , 0x0f150b33 , 0x2c22c273 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x7099c2c6 , 0xb8c13e2e , 0x644fc4b2 , 0x3f543aa8 , 0x17344ddc , 0xbd76f816 , 0x9c0139f3 , 0x039db53a ] )
+  , Mont::unsafe_make( [ 0x48c9f698 , 0xfedd3d96 , 0x101d3a3a , 0x44e3030d , 0x319e8368 , 0x1dfd662b , 0x06a95220 , 0x25f1b542 ] )
+  , Mont::unsafe_make( [ 0x58bab2e6 , 0x8a2c70e4 , 0xab3c7429 , 0x06f7face , 0x300a7af9 , 0xbe23bd06 , 0xa5835697 , 0x2ef6871a ] )
+  , Mont::unsafe_make( [ 0x408b17d8 , 0x01f559d3 , 0x399800fb , 0xe045149d , 0x322631ae , 0x7f7bc11a , 0x63913ab5 , 0x1bda029c ] )
+  , Mont::unsafe_make( [ 0x42f4569b , 0xfa9d3ec8 , 0x420a7da5 , 0x9ae3a1ff , 0x7f162746 , 0x285b42e5 , 0xcacaeab7 , 0x15f4456d ] )
+  , Mont::unsafe_make( [ 0x1a5dcd74 , 0xcf727d3e , 0x1895078d , 0xe07f63fc , 0x309698a1 , 0x4c36a8f3 , 0xbde4d6a6 , 0x1792a8e1 ] )
+  , Mont::unsafe_make( [ 0xeda9f3bf , 0xa460bbfd , 0x42b490ea , 0x66734b72 , 0x8b827b6e , 0xbdd523b6 , 0xce7e76d5 , 0x051259ab ] )
+  , Mont::unsafe_make( [ 0x30f6c522 , 0x5b6df45e , 0xfa2ad534 , 0x8444da29 , 0xf5597ec4 , 0x94f1ee17 , 0x29f8ce98 , 0x2e44dd30 ] )
+  , Mont::unsafe_make( [ 0x6de4d116 , 0x777c5628 , 0x78be6efe , 0xe52cfaf9 , 0x3e398db7 , 0xd234aa52 , 0x68d94636 , 0x1bffa08b ] )
+  , Mont::unsafe_make( [ 0xef38b75b , 0xfb7a736b , 0x1a873c4b , 0x9b951618 , 0x5b14bb29 , 0x96fab8cc , 0xd7aef8ea , 0x0fd21263 ] )
+  , Mont::unsafe_make( [ 0xc4990228 , 0x16605bd3 , 0x2b11c09f , 0xcf798899 , 0x5ef52d0a , 0x50b8974e , 0x30a16ed7 , 0x08a8a67b ] )
+  , Mont::unsafe_make( [ 0xbebc7de2 , 0x6ac55cf5 , 0x9246785c , 0xbedbdd4b , 0x1192681e , 0xd082989e , 0x2ff758ed , 0x302a808e ] )
+  , Mont::unsafe_make( [ 0xf62b3ea3 , 0xa2d75617 , 0xeab14e71 , 0xa535ee28 , 0x12626f74 , 0x6889e264 , 0x041e86d4 , 0x120c560a ] )
+  , Mont::unsafe_make( [ 0xb3bbe061 , 0xc62c6119 , 0x95ad9937 , 0x24d5e48f , 0xdd312a00 , 0xedc811f9 , 0xe5253ea9 , 0x1a9c5414 ] )
+  , Mont::unsafe_make( [ 0x1635651e , 0x245d852f , 0xd5de1bde , 0xf29c614b , 0x8e8f44e7 , 0x9e304402 , 0xa9b44c6e , 0x170911e2 ] )
+  , Mont::unsafe_make( [ 0xf3dfa9a0 , 0x0990ca26 , 0x42415e5c , 0x24531ce7 , 0x16840f8e , 0x7f0ea176 , 0xbd103102 , 0x26fde117 ] )
+  , Mont::unsafe_make( [ 0x1ffb774d , 0xaa3635b3 , 0x48ef4318 , 0xb68ce7d2 , 0x0d80cdd5 , 0x13427840 , 0xd1bd86f5 , 0x1a80ec9e ] )
+  , Mont::unsafe_make( [ 0x43944326 , 0xd901c739 , 0x7ccdce53 , 0x1dfaf715 , 0x1348ddc3 , 0x1bd07698 , 0x0b0a4c99 , 0x178e25ab ] )
+  , Mont::unsafe_make( [ 0x9c5f8006 , 0xe663e456 , 0x243e89a8 , 0xce0cb176 , 0x9a3b353a , 0x822f13f4 , 0x1451b7eb , 0x009cdca4 ] )
+  , Mont::unsafe_make( [ 0xbcf3a230 , 0xfaf72c9b , 0x2aaed30d , 0x799bcd67 , 0xc6cfde10 , 0x50586c38 , 0x3561d975 , 0x01ba57d9 ] )
+  , Mont::unsafe_make( [ 0x091cf197 , 0x401acc08 , 0xda323a0a , 0x8ae181ec , 0x009df235 , 0x7173b523 , 0x85646d36 , 0x2dd10c4f ] )
+  , Mont::unsafe_make( [ 0xc15a8b01 , 0x5622e3f0 , 0xf12e7ef0 , 0xf5a64cd0 , 0x75192b63 , 0x3e4c512e , 0xdb44bc08 , 0x016ca13c ] )
+  , Mont::unsafe_make( [ 0x90294eff , 0xb06f655e , 0x8436eb0d , 0x61c0b9cc , 0x5d7d3373 , 0x74951bea , 0xd7850396 , 0x2c6fe07e ] )
+  , Mont::unsafe_make( [ 0x95463442 , 0x00dfad0e , 0xe6b133d9 , 0x1650e723 , 0xc0892dc6 , 0x9e2d4ee9 , 0x4f26821e , 0x197ee62e ] )
+  , Mont::unsafe_make( [ 0x4b63ccc4 , 0x7e813baa , 0x4d5f20e4 , 0xa99da9a5 , 0xa5543a92 , 0xd95692c1 , 0x8a6a942a , 0x2417c307 ] )
+  , Mont::unsafe_make( [ 0x0f22c906 , 0xddcf7759 , 0xbd2b7d37 , 0x3e54b954 , 0xad152772 , 0x26045886 , 0xab1f8282 , 0x02f581ac ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xdfe5e31e , 0x93b98570 , 0x685ed973 , 0x24d01a50 , 0x19ddfd3e , 0xa1bf9637 , 0xc1fb3794 , 0x26e2109e ] )
+  , Mont::unsafe_make( [ 0x00151cda , 0x64fea514 , 0x6b9046da , 0xf28f3e75 , 0x66ab4630 , 0x724eaca9 , 0xcd59435c , 0x06b110da ] )
+  , Mont::unsafe_make( [ 0x0599717e , 0xa5f8ca15 , 0x5342ffbf , 0x6a4c3d99 , 0xae192031 , 0x5bd38f91 , 0xf10a2ae8 , 0x05e929f7 ] )
+  , Mont::unsafe_make( [ 0x0ea302cc , 0x91fd25fb , 0x51a4fd18 , 0x6b3798e2 , 0xe88565b5 , 0x5dd9c8f1 , 0xac0b3eda , 0x1258bc84 ] )
+  , Mont::unsafe_make( [ 0x164b433f , 0xc436c39d , 0x7cda058a , 0xe3a31fdd , 0xd6c18925 , 0xf92df4e3 , 0x02dd2fae , 0x047999a0 ] )
+  , Mont::unsafe_make( [ 0x1a719086 , 0x520a36a8 , 0xae0a8bea , 0xa76142b4 , 0xe662b999 , 0xf78f94cd , 0x20cce292 , 0x0a5a7dfb ] )
+  , Mont::unsafe_make( [ 0xb12b7e60 , 0x007f2de8 , 0x0dd086ad , 0xef6b80f9 , 0x76e8058e , 0x562b8b12 , 0x26cb4555 , 0x1763a96a ] )
+  , Mont::unsafe_make( [ 0x3c254cac , 0x7a423dbc , 0x0a19de91 , 0x9d130aaf , 0x084e3020 , 0x4ad1d8a1 , 0x283f62d8 , 0x28014d9b ] )
+  , Mont::unsafe_make( [ 0xfa241af1 , 0x3fd78368 , 0x539c0510 , 0x6d0b1c61 , 0xe46e8546 , 0xa90ab310 , 0x39eab9cc , 0x15adf75f ] )
+  , Mont::unsafe_make( [ 0x56b439ca , 0x2c878877 , 0x8b7d6ecb , 0x263a1a09 , 0xa8ee84ab , 0xcf53bdd1 , 0x6c8d8eb6 , 0x1cf3969e ] )
+  , Mont::unsafe_make( [ 0x03df0cee , 0x24b5a527 , 0x201805af , 0xc08a93ec , 0xc8a5bd2d , 0x526fb462 , 0x6cf102ec , 0x24cb9ebf ] )
+  , Mont::unsafe_make( [ 0x4a549acc , 0xeadaf12a , 0x0dce1aff , 0x0c5c7fb3 , 0xe8a7a30a , 0x7bd4a2f0 , 0xbfb6c2fb , 0x2c713724 ] )
+  , Mont::unsafe_make( [ 0x272e98ec , 0xd5e632ee , 0xf4d67a0b , 0x775272f8 , 0xc2c0f506 , 0xdf9d4d73 , 0x2e7ad136 , 0x230a56b3 ] )
+  , Mont::unsafe_make( [ 0x32004616 , 0xbd6660b7 , 0x1589893c , 0x01f5c329 , 0xdce373a1 , 0x6802eb8c , 0x245029a3 , 0x2dfd3ef9 ] )
+  , Mont::unsafe_make( [ 0xca58bce8 , 0xe5474fa1 , 0xf7a4538f , 0x1f3babbd , 0xb7a8661c , 0xb99920b6 , 0x6a28b2b7 , 0x1e95d999 ] )
+  , Mont::unsafe_make( [ 0x1e20dc9f , 0xced985d2 , 0x87fb2d54 , 0x666d2e49 , 0x916d0878 , 0x8de71e75 , 0x8004b6b9 , 0x1941548b ] )
+  , Mont::unsafe_make( [ 0x56623aeb , 0x7b41b4e1 , 0x180af93a , 0xaab3a195 , 0x127ce4e8 , 0x86e4e105 , 0x3f31ae3b , 0x08d7f50f ] )
+  , Mont::unsafe_make( [ 0x09056981 , 0xd03ef077 , 0x8cc7453f , 0xd632fb00 , 0x978b8576 , 0xdf083a49 , 0x70c21cb5 , 0x17f693c2 ] )
+  , Mont::unsafe_make( [ 0x2f5d92bd , 0x38cd775e , 0xd5639c8d , 0x31d66ee9 , 0x6560385f , 0xdd5c50f4 , 0x821dcc50 , 0x0d742490 ] )
+  , Mont::unsafe_make( [ 0xee753947 , 0x407b73dc , 0x1420ecba , 0x3761fbdb , 0xa1e95b35 , 0x93f2bfa3 , 0x170ca6ec , 0x1d83f9b0 ] )
+  , Mont::unsafe_make( [ 0x509181d4 , 0x8041ad5d , 0x2efacc78 , 0xccae80b1 , 0x8aba1188 , 0x7528679f , 0xad4f2487 , 0x2667a3e2 ] )
+  , Mont::unsafe_make( [ 0x42b5c63f , 0xab214a91 , 0x6ad959d5 , 0x6f382418 , 0x800ce0d1 , 0x6572cfe6 , 0x40e53d88 , 0x0a5f776c ] )
+  , Mont::unsafe_make( [ 0x0294e034 , 0x8611aa70 , 0x3c9a9303 , 0x2bb67d96 , 0x52cec858 , 0x599dcd60 , 0xd0f3b29d , 0x2f2a001a ] )
+  , Mont::unsafe_make( [ 0x4153fbbc , 0x14a43f24 , 0x25da3635 , 0xd3feb322 , 0x41c286f0 , 0x82fdc478 , 0x2032bfc8 , 0x253c1983 ] )
+  , Mont::unsafe_make( [ 0xd8336028 , 0xe7ce781a , 0x2b88fed1 , 0x05f9b8de , 0xf8d7389a , 0xdef86b2f , 0xda0fc5d2 , 0x1733cfcf ] )
+  , Mont::unsafe_make( [ 0x16b6745c , 0xfcf635c7 , 0xed8a42e8 , 0x8a93a998 , 0x5a90a442 , 0x3022e3f4 , 0xaad945d1 , 0x21f9d630 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x60085f94 , 0xa1fcd21a , 0xfb9658ef , 0x38c8114c , 0x365500c2 , 0x6a64d5ec , 0xccab7207 , 0x0b624679 ] )
+  , Mont::unsafe_make( [ 0x44fce7f9 , 0x57cedc38 , 0x43a8a75c , 0x61a37a03 , 0x177e558f , 0x5d271dd2 , 0x06b026ff , 0x1b1fbfe2 ] )
+  , Mont::unsafe_make( [ 0xa032b41a , 0xc9e96a38 , 0x9c9e3158 , 0x73152422 , 0x62ae04ef , 0x32f2c50f , 0xb760bdc7 , 0x1cd7adfa ] )
+  , Mont::unsafe_make( [ 0xbe0a74da , 0xa8b9d44f , 0xe04b3e03 , 0x0f24ae7a , 0x3512bbff , 0x33cf96f8 , 0xcf0648e1 , 0x0c7f658e ] )
+  , Mont::unsafe_make( [ 0xebd6dd63 , 0xa373aee4 , 0x1dbf75b5 , 0xea3a2d1d , 0x6177de0d , 0x2f2e1642 , 0xecf0da6f , 0x2f9b5b87 ] )
+  , Mont::unsafe_make( [ 0xdc84e257 , 0x1e00bbee , 0xa43c37a3 , 0xe9fb15be , 0x391a7428 , 0x13cc4bf5 , 0x81f5a5a2 , 0x0326e55a ] )
+  , Mont::unsafe_make( [ 0x96bde9ba , 0x1801374d , 0xe91c854b , 0x1aa7e512 , 0xd69010c9 , 0xbd49d185 , 0xd472e4a1 , 0x2ac01338 ] )
+  , Mont::unsafe_make( [ 0x6b6016a0 , 0x82fb262b , 0x7fa5f7a1 , 0xc75ebc98 , 0x72d855a2 , 0x6a244106 , 0x2faa0075 , 0x11adcbb2 ] )
+  , Mont::unsafe_make( [ 0x3e76a51b , 0x2a00418e , 0x41a28c09 , 0x6f21b04a , 0xa0f7ccfe , 0xe10f8ec3 , 0x4215e5f9 , 0x14b59ac0 ] )
+  , Mont::unsafe_make( [ 0xd2c99336 , 0xd05e9d1d , 0x1ffcb0e0 , 0xaf8762c6 , 0x8a7ad20b , 0x6daf8a2e , 0xd587dddf , 0x0aa849c7 ] )
+  , Mont::unsafe_make( [ 0xef108e96 , 0xcfc9e544 , 0x6caaed75 , 0xc863c796 , 0x3689ae25 , 0x0ef23f8d , 0xcdb47e8f , 0x24251c4c ] )
+  , Mont::unsafe_make( [ 0xe22f3e0c , 0x3c3b11d1 , 0xa682baa0 , 0xeccfb86d , 0xaad55e1c , 0x257c69a8 , 0x088d2573 , 0x24d3fa9a ] )
+  , Mont::unsafe_make( [ 0x53fd2012 , 0xd3c41385 , 0x1c4128af , 0x7fa17d6f , 0xb612a62d , 0x800a148b , 0x1ef13cba , 0x1fd74590 ] )
+  , Mont::unsafe_make( [ 0x5ed6e78a , 0xd35656da , 0x008e36ba , 0xa39d7a71 , 0xa6c748a6 , 0x2637d8d4 , 0x7c042821 , 0x0b7ad025 ] )
+  , Mont::unsafe_make( [ 0x3f139943 , 0x3b4aa7ea , 0xc061c149 , 0xfdb0cde2 , 0xc25979ec , 0x72cfded3 , 0xa6b9a902 , 0x10629c3e ] )
+  , Mont::unsafe_make( [ 0x5d17521d , 0x8d9357b4 , 0x09c4f2d9 , 0xf369f7c0 , 0x688e5ded , 0xc83f0ed7 , 0x6f962e3e , 0x1a0b9475 ] )
+  , Mont::unsafe_make( [ 0x1d22f3d2 , 0xb5db1f29 , 0xac1f29c2 , 0x3d1405d4 , 0xbfe2d50b , 0xa3e57f1c , 0x7ca9ec83 , 0x1a35c80e ] )
+  , Mont::unsafe_make( [ 0x65716fa9 , 0x4b6800ab , 0x83ea1e26 , 0x1be1eaf6 , 0x782e5fc3 , 0xbf19856b , 0x31bf550a , 0x1156ad7e ] )
+  , Mont::unsafe_make( [ 0xf6eebb8e , 0xf7e16f40 , 0x93e01860 , 0x0d02ac10 , 0x77c4a661 , 0xf37fda54 , 0xd8e6e66e , 0x203d6738 ] )
+  , Mont::unsafe_make( [ 0x03bf5cd5 , 0x7c8c4c48 , 0x3708aa02 , 0x5432acc2 , 0x78a80be7 , 0x967ee30a , 0xf6ad0925 , 0x24b64823 ] )
+  , Mont::unsafe_make( [ 0x56ebd0a5 , 0xac75600f , 0x62667f6e , 0x465fd237 , 0xbd44e031 , 0x2f08ac9f , 0x1d4bc523 , 0x06c8b8da ] )
+  , Mont::unsafe_make( [ 0x909eea95 , 0xc3bbb5af , 0xed481bb3 , 0x6cdfa2e0 , 0x2d362ee5 , 0x10c9a4f9 , 0xb8b19447 , 0x2a72d2c2 ] )
+  , Mont::unsafe_make( [ 0x8642c93b , 0x3f162159 , 0xcae09c9c , 0x9fe84ec0 , 0x7a165a90 , 0x4e352089 , 0x3fafdff4 , 0x0fd62b5a ] )
+  , Mont::unsafe_make( [ 0x75dde54d , 0x3095088a , 0x530f92b5 , 0x1af7e998 , 0x0680e6de , 0xb49be105 , 0x77026833 , 0x229eef68 ] )
+  , Mont::unsafe_make( [ 0xa458d83f , 0xc19feb08 , 0xab34d6ee , 0xe658500f , 0xaf12a373 , 0x805fe076 , 0x716a0daf , 0x28ca5e0d ] )
+  , Mont::unsafe_make( [ 0xd566ac93 , 0xfc4a5df0 , 0x64c6f1d3 , 0xb0173ce9 , 0x6daf58f1 , 0x360911dc , 0x039edb89 , 0x011d814a ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xc40a6681 , 0xc1ae0362 , 0x03157fc1 , 0xa5a0b1e3 , 0x000b2640 , 0x4bc73b6b , 0xfbe745db , 0x28e0af82 ] )
+  , Mont::unsafe_make( [ 0x19835ced , 0x1d408323 , 0xbc7fe66a , 0xd623f14a , 0xb3ec813a , 0x26d6296a , 0x9ff62484 , 0x28c1ef59 ] )
+  , Mont::unsafe_make( [ 0x8c952617 , 0x6568cf02 , 0x7fc2d733 , 0x7dd6ba7e , 0x03b6d8df , 0x61d9c468 , 0x15bf7813 , 0x1622c2f0 ] )
+  , Mont::unsafe_make( [ 0xe334a57e , 0xfdd56c5a , 0x41096dbc , 0x6014fa8a , 0xe85a7214 , 0x227dea82 , 0x8f69ccb9 , 0x2c50598c ] )
+  , Mont::unsafe_make( [ 0x05c97996 , 0xf0266a3e , 0x7da7f69e , 0xa944b008 , 0xfe3bd053 , 0x7b89b70b , 0x116449d7 , 0x0a00e138 ] )
+  , Mont::unsafe_make( [ 0x56063d45 , 0xf5e32825 , 0xfdf0b995 , 0xb431a47b , 0xf521190d , 0x90e68fbe , 0xdf0c89a4 , 0x2ff25dfe ] )
+  , Mont::unsafe_make( [ 0x186c3597 , 0x6f4d2697 , 0x7d965cfb , 0x0a4b4036 , 0xd9f71404 , 0x9cf880e3 , 0x2ba35434 , 0x2ff59fb9 ] )
+  , Mont::unsafe_make( [ 0x8b31835e , 0xe7ef3e3a , 0xa24f2340 , 0x821d6126 , 0xfca6479f , 0xc7c69d47 , 0x282a4f33 , 0x084f3b7e ] )
+  , Mont::unsafe_make( [ 0x2f73fbd2 , 0x268a2d73 , 0x9f583ea9 , 0x22b5ea5c , 0xa535e5a4 , 0x17ec2d84 , 0x2a7beaa4 , 0x2ce80824 ] )
+  , Mont::unsafe_make( [ 0x8e1d2507 , 0x277cca4a , 0xa91aa479 , 0xd2de682d , 0xbc810612 , 0x9077dccc , 0x38da9b47 , 0x17518cae ] )
+  , Mont::unsafe_make( [ 0x3a6ac7ed , 0x4361da00 , 0x520521d8 , 0x4e5f7ef8 , 0x5dcd5b55 , 0xae4c571f , 0x56184344 , 0x220d41c2 ] )
+  , Mont::unsafe_make( [ 0x14ea5e44 , 0xb5d3346a , 0x7e7a11d8 , 0xd18fa5da , 0x87655817 , 0xf09e510a , 0xdbbff1a9 , 0x1836a3f6 ] )
+  , Mont::unsafe_make( [ 0x44c595de , 0xdaf5985b , 0x876f66cb , 0xc55920ab , 0x42c28e39 , 0x985dc976 , 0xf46fdfa5 , 0x1da1bc93 ] )
+  , Mont::unsafe_make( [ 0xb1fc82ce , 0x228383ed , 0x4b0ad0d8 , 0xc2c71c21 , 0xf384c359 , 0x343df958 , 0x9f88e68b , 0x26b11a35 ] )
+  , Mont::unsafe_make( [ 0x5f9e9cd3 , 0xbbbd4817 , 0x0aa70ccf , 0xc704e8af , 0x17f57878 , 0xfecfc11b , 0x8bef2012 , 0x15e161b0 ] )
+  , Mont::unsafe_make( [ 0xb5836884 , 0xee59aca7 , 0xffe16fa1 , 0xc26b2360 , 0x4ed9c16c , 0x7b650002 , 0x2a3fb2d7 , 0x18488289 ] )
+  , Mont::unsafe_make( [ 0x2776f87c , 0xfd0463c1 , 0x9a1fdf80 , 0x23c67bc8 , 0xb3378be1 , 0x9c95cc30 , 0x8bc8776c , 0x28afc2e9 ] )
+  , Mont::unsafe_make( [ 0xc0d1fb16 , 0xac12c825 , 0x0750f1c3 , 0x217ce4e1 , 0x81de5bfe , 0x47c3528c , 0x491719c1 , 0x2c600c9d ] )
+  , Mont::unsafe_make( [ 0x391945a4 , 0x4a857681 , 0xa76fc6f4 , 0x876819a7 , 0x692f438a , 0x1724eb03 , 0x18e45a58 , 0x1ef2f489 ] )
+  , Mont::unsafe_make( [ 0x9aaf74a5 , 0x6ada0159 , 0xf05b858f , 0x4ab63ab8 , 0x49fe10dc , 0x0098dbd6 , 0x6874edeb , 0x19370231 ] )
+  , Mont::unsafe_make( [ 0xc4198db3 , 0xbce3978e , 0x2c28c9df , 0x586b37ce , 0xd21be6af , 0x7f79198f , 0x8e9280b6 , 0x1b8423c5 ] )
+  , Mont::unsafe_make( [ 0x5b441b08 , 0x72cabf19 , 0xb786aff4 , 0x41cfc12e , 0x97ed7906 , 0xbe8b631a , 0x4047b2e8 , 0x0f88f666 ] )
+  , Mont::unsafe_make( [ 0xf298ec02 , 0x5398986e , 0x6b6ffff7 , 0x64f9614f , 0x26eb6cb4 , 0xd60e5c6e , 0xc85d723b , 0x0641a609 ] )
+  , Mont::unsafe_make( [ 0xa980890f , 0x5fdd05db , 0x40162c51 , 0x7c17efbd , 0xa9107f4f , 0x9f03942c , 0xd99b3e29 , 0x179d8e84 ] )
+  , Mont::unsafe_make( [ 0x1e6600a1 , 0x19baf8e0 , 0x147ee16e , 0xf05d4f29 , 0xec8234d1 , 0xf14fd9c5 , 0x5b1ba7e3 , 0x1c4006e5 ] )
+  , Mont::unsafe_make( [ 0xf15a5f18 , 0xd60b477d , 0xe3e7d11f , 0x661bcb60 , 0x199a2ab3 , 0x2a56bbb6 , 0xe70d34ed , 0x290e774b ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xf24cd9cb , 0x37ebb97c , 0xf4b91056 , 0xf0eebf36 , 0x32f34038 , 0x7f5d611f , 0x86cc9204 , 0x088274b0 ] )
+  , Mont::unsafe_make( [ 0x4880152c , 0x293201d6 , 0xbbcff06d , 0x3e14906d , 0x7058ffc4 , 0x279c257f , 0x6e44775a , 0x0f6f0a6a ] )
+  , Mont::unsafe_make( [ 0x7b0e2f3a , 0x524f35c0 , 0xf0931773 , 0x148067a0 , 0x64b916ad , 0xadbcfb88 , 0x6e1ef157 , 0x2347d270 ] )
+  , Mont::unsafe_make( [ 0xf8d494d8 , 0xa06110f2 , 0x70be596d , 0xf75d71f8 , 0x35c3244b , 0xc74441a8 , 0x6287a9e4 , 0x232ce090 ] )
+  , Mont::unsafe_make( [ 0x06eb53e5 , 0x48eb472f , 0x953a04b4 , 0xe5b97247 , 0xb61611b7 , 0x00f9647c , 0xe101e66e , 0x2232935d ] )
+  , Mont::unsafe_make( [ 0x6d6a6d35 , 0x4943dda5 , 0x6bec36d0 , 0xffa0d597 , 0x808f557f , 0xf5d48cdd , 0xa1259173 , 0x00c220ea ] )
+  , Mont::unsafe_make( [ 0xc831f5aa , 0xce97e037 , 0xaf721a2e , 0xae380e0e , 0xa01e63c6 , 0x741f68df , 0x89a311b7 , 0x1026a923 ] )
+  , Mont::unsafe_make( [ 0x68437c0a , 0xddf4d5db , 0xd5f8fcd0 , 0x70ebdad1 , 0x802ada63 , 0xb3aad134 , 0xf2c0b9ea , 0x0a7eb85c ] )
+  , Mont::unsafe_make( [ 0x0300eedb , 0x513453fc , 0x6d852d9e , 0x17eaadea , 0x39500cb0 , 0x9d4e9b40 , 0xa0d2b13e , 0x2764a6f1 ] )
+  , Mont::unsafe_make( [ 0xb025eb5c , 0xdc596aca , 0x72c3cd8e , 0x6a0bc218 , 0x2ed194e1 , 0x425ab723 , 0x333aa775 , 0x214392d3 ] )
+  , Mont::unsafe_make( [ 0xea6a7388 , 0x3186b747 , 0x214edc37 , 0x95e530c6 , 0x4721507d , 0x1973d95b , 0x38b78411 , 0x0e99caba ] )
+  , Mont::unsafe_make( [ 0x540915d3 , 0x35eda402 , 0xa00bb616 , 0xcbe11bc0 , 0xd3e77237 , 0x0d6ae70d , 0x9d9189a6 , 0x06c6024b ] )
+  , Mont::unsafe_make( [ 0x01cea930 , 0x49a63449 , 0x984eb9e2 , 0x44c1a799 , 0x99129bc3 , 0xa45ea8de , 0x631bb975 , 0x089dc37e ] )
+  , Mont::unsafe_make( [ 0xe8e504db , 0x9dd5a49f , 0x8c1fde47 , 0xc7efb774 , 0x98f32615 , 0x3b63ea5e , 0x7d29248e , 0x27c2257c ] )
+  , Mont::unsafe_make( [ 0x0b4d5ee3 , 0xfc2046a1 , 0x5b77d8f4 , 0x0d2ce6a4 , 0xb6674839 , 0x2c7b323a , 0xb1b5d2dd , 0x3035bda9 ] )
+  , Mont::unsafe_make( [ 0x745b3de1 , 0x44cada37 , 0xb513fe1f , 0xcf31d2c1 , 0x6e7947b0 , 0xff7dd0c1 , 0xd7610ae4 , 0x0bc0c65a ] )
+  , Mont::unsafe_make( [ 0x7acb04ac , 0xb72aefae , 0xcba61406 , 0x74b2213d , 0xbc2f87eb , 0x7e4b76a6 , 0x00d83d95 , 0x2bd65454 ] )
+  , Mont::unsafe_make( [ 0x171d1c8d , 0x23b3030a , 0x94f72667 , 0x17b2c280 , 0xb1b91882 , 0x46e02ae4 , 0x6dbf5aa0 , 0x030e40a4 ] )
+  , Mont::unsafe_make( [ 0x5ce2b2a4 , 0xa1d18f5a , 0xec3ed4a7 , 0x75d0e4cd , 0xa29a9c69 , 0x926bfa59 , 0xa361e9dd , 0x09c3c999 ] )
+  , Mont::unsafe_make( [ 0x568ec639 , 0xb144fb4b , 0x586ca25e , 0xb3999668 , 0x804dd060 , 0xefb6f1af , 0xa45b4534 , 0x1957dc0f ] )
+  , Mont::unsafe_make( [ 0xafe5a9f5 , 0x236c1440 , 0x8087b599 , 0x24c674fb , 0x7765c613 , 0xbd230286 , 0xabc83b4a , 0x21054c59 ] )
+  , Mont::unsafe_make( [ 0xdcf46d55 , 0x0e235994 , 0x1f6569d9 , 0x021d3154 , 0x42bdec5a , 0x5555ebc5 , 0xb7426f4a , 0x14f57db7 ] )
+  , Mont::unsafe_make( [ 0x3122c070 , 0x8e0c1831 , 0xd6fb3491 , 0x1425ba62 , 0xb4d9764d , 0x28f89242 , 0x89914540 , 0x29bb3d33 ] )
+  , Mont::unsafe_make( [ 0xf735729d , 0x099c1349 , 0xa04e8787 , 0x85504d9c , 0x06494c0f , 0xd7966e1e , 0x71065a6a , 0x236c6044 ] )
+  , Mont::unsafe_make( [ 0xba8af533 , 0xdd235a2f , 0x2b849240 , 0x494f0f7e , 0x21c34ed6 , 0xe11e6136 , 0x5f153c8a , 0x259de003 ] )
+  , Mont::unsafe_make( [ 0xeb26826c , 0x97fa9b71 , 0xa6fb4464 , 0x0a8f22bc , 0xaf55b832 , 0x609f5469 , 0x285ba064 , 0x125417b2 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xec9130bd , 0x58a454bb , 0xc4d893fb , 0xb212542b , 0xb1784c88 , 0xf4fda8b1 , 0xe209157b , 0x14f4db76 ] )
+  , Mont::unsafe_make( [ 0x4858e467 , 0xabfd4707 , 0xdc5255ec , 0xcac05947 , 0x64f49d05 , 0x193dd480 , 0xc63aba2c , 0x28e3ccf3 ] )
+  , Mont::unsafe_make( [ 0xb91bf646 , 0x3105cee0 , 0x1f20aa12 , 0x7c1a8614 , 0xa793c620 , 0x4907db7f , 0xa85fca2e , 0x2be46f4f ] )
+  , Mont::unsafe_make( [ 0x20a94f6e , 0xba0f1dce , 0x484ba2fc , 0x9ab191d7 , 0x53f715d4 , 0x5e410ec9 , 0x82aba5f0 , 0x04411fd1 ] )
+  , Mont::unsafe_make( [ 0xedd3681f , 0xd4461878 , 0x02e57c94 , 0x4de3e533 , 0x59638dfe , 0x4d5a44aa , 0x13e33673 , 0x1eb95eaf ] )
+  , Mont::unsafe_make( [ 0xa0c493b2 , 0x53c4e0f9 , 0x5095c650 , 0xdca47f7b , 0x3d1bde05 , 0x3ab34147 , 0x5e5ddfe2 , 0x005df3c3 ] )
+  , Mont::unsafe_make( [ 0xe5ca974b , 0x94319ebf , 0x2fc5aa96 , 0x12bc7467 , 0x7923f2a5 , 0xa8a6c315 , 0x431f787c , 0x2a07ee33 ] )
+  , Mont::unsafe_make( [ 0x2e62ee0b , 0x66c42c4c , 0xc5d8c84e , 0x140435d7 , 0x82a76f0e , 0xd07945fd , 0x7d2ab718 , 0x24c73dee ] )
+  , Mont::unsafe_make( [ 0x6d860c6a , 0x62f748f1 , 0xcbf4a2c7 , 0x41759ac4 , 0xe2cc3a78 , 0x291d915f , 0xc7dea888 , 0x2733ee76 ] )
+  , Mont::unsafe_make( [ 0xea6d2a3f , 0xea61882b , 0x39796935 , 0x7a938790 , 0x7318ce81 , 0x6a44ab2c , 0xa7a57c6d , 0x0bacb496 ] )
+  , Mont::unsafe_make( [ 0x609cfcae , 0xd9bb3784 , 0x7fad5906 , 0x0ce7131f , 0xe21e8bf3 , 0x6a666f76 , 0xe466c738 , 0x227cd55f ] )
+  , Mont::unsafe_make( [ 0x5fb77a71 , 0x38369be8 , 0x44a3ea6e , 0xec71322e , 0x752bf670 , 0x01cf47dd , 0x28ebf848 , 0x2a346a27 ] )
+  , Mont::unsafe_make( [ 0x6c365a83 , 0x451b88de , 0xad76b747 , 0x4b4eb5c3 , 0xbb2a0a78 , 0xe87ca888 , 0x5d5f247b , 0x12df1cb1 ] )
+  , Mont::unsafe_make( [ 0x68d6a22f , 0x07031594 , 0x3a12608d , 0x43a06bb3 , 0xd5b66775 , 0x72cb6cae , 0x247e6dae , 0x2d49e834 ] )
+  , Mont::unsafe_make( [ 0x4b69ea01 , 0xdd6ecc40 , 0x56c14894 , 0xb6a06ca8 , 0x554ae380 , 0x949a257c , 0xc03098f1 , 0x11fc64cb ] )
+  , Mont::unsafe_make( [ 0x10010b16 , 0xd9af9e1d , 0xe9f997c8 , 0xab706787 , 0xf73d098a , 0xe6bcaee2 , 0x427c6402 , 0x15bc07c7 ] )
+  , Mont::unsafe_make( [ 0x80d47f7f , 0x83c0c50a , 0x48965d9d , 0x431b6e29 , 0x9931bbad , 0xd6eda3ea , 0x2b6236f6 , 0x21ccaff9 ] )
+  , Mont::unsafe_make( [ 0x8f958dab , 0x4287653b , 0xb2d53f6d , 0xff8ee0a1 , 0x6c7d04b2 , 0x587c3f02 , 0xbe0b54ee , 0x1212a9fc ] )
+  , Mont::unsafe_make( [ 0x2b7d18cd , 0xb9a9100b , 0x80588821 , 0xb8b7c9e9 , 0x9da5e7db , 0xe75c640b , 0x753bb8c6 , 0x2b11857f ] )
+  , Mont::unsafe_make( [ 0xf45b0c03 , 0xbc294475 , 0xf985587a , 0x2b2f67df , 0xaa007cae , 0xe2d1226e , 0xe6539a47 , 0x1142525a ] )
+  , Mont::unsafe_make( [ 0xd698b851 , 0x12054676 , 0x5b495818 , 0x2494e900 , 0x6a99713d , 0x0e8d06b0 , 0x2c9d2b98 , 0x09a00998 ] )
+  , Mont::unsafe_make( [ 0xe6530f7b , 0xc5394d77 , 0xefb05e46 , 0xc242f199 , 0xb8a8d376 , 0x2b666638 , 0x52ab051e , 0x2a4768bf ] )
+  , Mont::unsafe_make( [ 0x4b7b90d1 , 0x2b0f491e , 0xdfc90150 , 0x82b7c78d , 0xfd91f26a , 0x33311553 , 0x5a656c95 , 0x271f79a8 ] )
+  , Mont::unsafe_make( [ 0xa5495a71 , 0x20de35e9 , 0x03ad162a , 0x1e068295 , 0xe0e8c08c , 0x9ab49548 , 0xa0314706 , 0x15647969 ] )
+  , Mont::unsafe_make( [ 0x0903b60b , 0xd056fe7b , 0xbeaf766e , 0x3fa7620d , 0x0d6aeb33 , 0x1bf4edb6 , 0x444ff2ec , 0x0c73fdab ] )
+  , Mont::unsafe_make( [ 0x0afe48dd , 0x863eb4c6 , 0xf5bb49a1 , 0x892d9662 , 0x1c9de38f , 0xa8959bbc , 0x98a77582 , 0x151be757 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x0d28cdb1 , 0x7c2224bd , 0x300c83d3 , 0x71855be4 , 0x1cc4c2d2 , 0xa11bafa9 , 0xfb6bde74 , 0x0b4492f6 ] )
+  , Mont::unsafe_make( [ 0xa1ca6e3c , 0xe73ba155 , 0x8c18507b , 0x9f2684cf , 0x7b6620ba , 0xd45d4f81 , 0x7ca6f211 , 0x2449a944 ] )
+  , Mont::unsafe_make( [ 0xc3c26b20 , 0xa4f67962 , 0x476e98ee , 0xb6ab0532 , 0x60cc0669 , 0x60bf069b , 0x413f2ccd , 0x17ab8aaf ] )
+  , Mont::unsafe_make( [ 0xf72a03fe , 0xfc49a9b5 , 0x23da58da , 0xbeb3ed48 , 0xdf8dc177 , 0xa0cf4cb6 , 0x91c603c6 , 0x304f95a5 ] )
+  , Mont::unsafe_make( [ 0xcb9c3c3a , 0x6931a284 , 0x5efe77df , 0x69ce7cb6 , 0x6435f987 , 0xf681f526 , 0x0013b48f , 0x077f9ffd ] )
+  , Mont::unsafe_make( [ 0x27d49e43 , 0x6af1084f , 0xbc8fc460 , 0x597e70d1 , 0xa289d537 , 0xe930eb22 , 0xb74a8fac , 0x0f0c9662 ] )
+  , Mont::unsafe_make( [ 0x2f8c5e85 , 0x0da7f89c , 0xe3c45d0b , 0x36852bee , 0x7881f286 , 0x769379b5 , 0x8379ee6b , 0x29b19159 ] )
+  , Mont::unsafe_make( [ 0x17af75ad , 0x20ee316d , 0x6a4386f2 , 0xc0d2ead5 , 0x1fe631d9 , 0xbe682d18 , 0x7fb4329c , 0x2cfd3ac7 ] )
+  , Mont::unsafe_make( [ 0xd19bc6c4 , 0xbfcb0d06 , 0x5eb2bb12 , 0x9071f322 , 0xbdfae9ba , 0xf06732c2 , 0xfe67e074 , 0x281b2c9b ] )
+  , Mont::unsafe_make( [ 0xe085109d , 0xc84d4ba0 , 0xbc81bb4d , 0xcd354c3b , 0x8bba0b9d , 0x49ce7b59 , 0xcf3c80f3 , 0x13953a58 ] )
+  , Mont::unsafe_make( [ 0x23087727 , 0xae5e6424 , 0x31d26ea9 , 0xa540ea02 , 0xffae011d , 0xa5492f44 , 0x710dc968 , 0x0389bd0a ] )
+  , Mont::unsafe_make( [ 0x2cc76e2d , 0xee1b1e08 , 0xea72c91c , 0x58a8ea35 , 0x68d869a2 , 0xbeb218f9 , 0xc8dc306d , 0x2b5eb4ca ] )
+  , Mont::unsafe_make( [ 0x6a4347ef , 0x93749708 , 0xffe9b54e , 0x4f519674 , 0x6b23ca20 , 0x3d190825 , 0xdb5fc609 , 0x1e856058 ] )
+  , Mont::unsafe_make( [ 0x73d1368f , 0x583ca8ab , 0x824cac31 , 0xc6bf7450 , 0x6b5b3692 , 0xe9d8a565 , 0xdc414216 , 0x0ea025fb ] )
+  , Mont::unsafe_make( [ 0xd47bf3b3 , 0x9c4dc7af , 0xc39d2907 , 0x55c131cf , 0x53a0e2bd , 0xa09c6200 , 0x1614ac7a , 0x1ba32a7d ] )
+  , Mont::unsafe_make( [ 0x17ca11ed , 0xab4d2909 , 0xf988b19a , 0xd1f31a17 , 0xbf9115b8 , 0xf7d5d5e5 , 0x50eb1b46 , 0x1aff9b53 ] )
+  , Mont::unsafe_make( [ 0x1dd4e5c4 , 0x134b0970 , 0x6d1c992a , 0x9fbf7632 , 0x92359d29 , 0x2b41fd40 , 0xca7870fe , 0x1275a6ae ] )
+  , Mont::unsafe_make( [ 0xf4b859d3 , 0xf422da7a , 0x68ae96d6 , 0xc433255b , 0x2b42ed07 , 0xd48dda69 , 0x242afa62 , 0x09af2df0 ] )
+  , Mont::unsafe_make( [ 0x14f6e438 , 0xe08e3066 , 0x129a6ec4 , 0xd901a6e0 , 0x5ca17e43 , 0x50178bde , 0xc4910e71 , 0x2a87b522 ] )
+  , Mont::unsafe_make( [ 0x9a90a75e , 0xe2cbecfd , 0x2c13b44d , 0x417adba5 , 0x6eb3ae9c , 0x06a32a5d , 0x06d4ad31 , 0x24c23ca2 ] )
+  , Mont::unsafe_make( [ 0x87abdb61 , 0xf8b9c329 , 0x3cd0ed17 , 0xbe7628f3 , 0xb29f2221 , 0xce4321f8 , 0x63e6e9e7 , 0x2f7d71d0 ] )
+  , Mont::unsafe_make( [ 0x7bad5d67 , 0x8f572fc0 , 0x7b7dbf7c , 0xcfd6620f , 0x7388c001 , 0xc0b84ca6 , 0x2e587a18 , 0x22c88750 ] )
+  , Mont::unsafe_make( [ 0x7c5b6c37 , 0xd7aca540 , 0x3badd249 , 0x0f0b08cf , 0xbbcfd976 , 0x5f407af2 , 0x6f68aa4b , 0x2009b6ea ] )
+  , Mont::unsafe_make( [ 0x7eb47671 , 0x7158ba98 , 0xe873e046 , 0xa017a7dd , 0xcb6d9734 , 0xfa81d45a , 0xbf5a10d6 , 0x04635b58 ] )
+  , Mont::unsafe_make( [ 0x020df3af , 0x0534f3aa , 0x58125091 , 0x9ef406b1 , 0x76bb5895 , 0xd549ee79 , 0xe3983fc6 , 0x1e5daa7e ] )
+  , Mont::unsafe_make( [ 0x3b74d161 , 0xbf3e5dbe , 0x73510d8d , 0xa049e695 , 0x122871e3 , 0x8d1ae73d , 0x58799cd8 , 0x0f6450cd ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x0985348a , 0xa833d4c8 , 0x8c8c0aa0 , 0xc4d237f9 , 0x3f6fdbfd , 0x46d7801b , 0x624a16bb , 0x1294310d ] )
+  , Mont::unsafe_make( [ 0x05a445de , 0xde00bcc2 , 0x50b9cfc7 , 0xfde61186 , 0x0d40e77b , 0x620ff118 , 0x8fa6ad4c , 0x2240bcd9 ] )
+  , Mont::unsafe_make( [ 0x9630e7b0 , 0xc046b2b1 , 0x5abc2d24 , 0xea0fb6fd , 0xbbedee30 , 0x2862fec9 , 0x1661d530 , 0x1017aa3c ] )
+  , Mont::unsafe_make( [ 0xcd884414 , 0xd2d479dc , 0x65b5c85f , 0xec97c33b , 0x0daac9f6 , 0x19b8e15d , 0x835ac5bc , 0x24cd9a1f ] )
+  , Mont::unsafe_make( [ 0x4445dd32 , 0xcb29356e , 0x2c9620e6 , 0xcaa66225 , 0x196d767b , 0x97df4bab , 0xa39b2592 , 0x2ab395c3 ] )
+  , Mont::unsafe_make( [ 0x093516f8 , 0x63b3674d , 0x42dde35d , 0xc739cbb7 , 0x2cae9d4b , 0x4d64674a , 0xb6499b1d , 0x1f86a4e2 ] )
+  , Mont::unsafe_make( [ 0xffbaf360 , 0xed87de66 , 0x64aa29ea , 0xbc37473c , 0x64e433e3 , 0x7fa85aae , 0x2ba93095 , 0x0f9d0eb9 ] )
+  , Mont::unsafe_make( [ 0x14910ab2 , 0x5b3449cf , 0x373bf950 , 0x9f16b90d , 0x62a6b785 , 0x7961149b , 0x8874ea39 , 0x2a0de0e1 ] )
+  , Mont::unsafe_make( [ 0xcf093eaf , 0x83bc8817 , 0xa8db7b4c , 0xc6f91f49 , 0xf7ef2859 , 0xa689b00a , 0x9f975256 , 0x2fcc63ec ] )
+  , Mont::unsafe_make( [ 0x4907fd94 , 0xc0146c6d , 0xfae10f0e , 0x9fe6f429 , 0x08459183 , 0x5f317d9a , 0x009d18a7 , 0x02412b44 ] )
+  , Mont::unsafe_make( [ 0xb89beb2d , 0x3e220c27 , 0x0416b973 , 0x9ea601bf , 0xf1af45d0 , 0x4595f6bc , 0x5e937a4d , 0x2f0c1a0b ] )
+  , Mont::unsafe_make( [ 0x1f547dcd , 0xe62b332a , 0x214578da , 0xcc25e723 , 0x678eb0ef , 0x43c4cf38 , 0x5eb0f051 , 0x3058b533 ] )
+  , Mont::unsafe_make( [ 0xaaf1d22a , 0xa160493e , 0x8e422979 , 0xc8ab92b0 , 0xf4766c74 , 0x7c788cab , 0x806d2114 , 0x200a8cfe ] )
+  , Mont::unsafe_make( [ 0xe14176a1 , 0x1318e65f , 0xa0d4042b , 0x3b506aa2 , 0xca84f4f8 , 0x9ee79e52 , 0xf1bc2443 , 0x0fdd8cc9 ] )
+  , Mont::unsafe_make( [ 0x4e4e60b8 , 0x95ce6385 , 0x7f55000d , 0x7fedf47d , 0x5bb3d28f , 0x800847e0 , 0xb30177f4 , 0x24bfaf42 ] )
+  , Mont::unsafe_make( [ 0x4f74e746 , 0xefd35a72 , 0x903ea865 , 0x4c6f3222 , 0x860dd89e , 0x54877e61 , 0xf100e976 , 0x2d797b07 ] )
+  , Mont::unsafe_make( [ 0xb63e1022 , 0xe40a41a1 , 0x4676b549 , 0xf751c3e9 , 0xd42498b1 , 0xab2ffa3d , 0x6ea9206b , 0x0e5842bd ] )
+  , Mont::unsafe_make( [ 0x9bcc769e , 0x1175cd43 , 0x8b516092 , 0xf71063ee , 0x742a4c94 , 0x0c332c21 , 0x6966c938 , 0x1b543aa8 ] )
+  , Mont::unsafe_make( [ 0x6a1e79f5 , 0xcb50b05a , 0x45f818d0 , 0x8d94d420 , 0x38022ac8 , 0x96a19b5d , 0x16827a6a , 0x1eee3042 ] )
+  , Mont::unsafe_make( [ 0xe36621d8 , 0x8da23ac5 , 0xf3bad8a8 , 0x4964e81a , 0x3963c3e2 , 0xb4959056 , 0x71c0f5e7 , 0x11cd2ce6 ] )
+  , Mont::unsafe_make( [ 0xb5037dae , 0x74279293 , 0x3ed9b677 , 0x04a93d95 , 0xc0863302 , 0xeef07240 , 0xb3afcbbc , 0x2a6e7ee4 ] )
+  , Mont::unsafe_make( [ 0x42f66ad4 , 0x0d97f265 , 0xd03014c1 , 0xfc40ba6a , 0xfafecbc0 , 0xda7331a0 , 0x0fa7b0ce , 0x1cb002d5 ] )
+  , Mont::unsafe_make( [ 0x5cdf5dc9 , 0x8dca878c , 0x2c656f37 , 0x6832c5df , 0x9bcc716d , 0x3dadbc5d , 0x97bb9d81 , 0x18ac2de4 ] )
+  , Mont::unsafe_make( [ 0x973019e6 , 0x407de414 , 0x07277f3a , 0x13dcc41a , 0xd94e2758 , 0xbd87dfa2 , 0x83047c86 , 0x15bce2ab ] )
+  , Mont::unsafe_make( [ 0x311d7fd7 , 0xa7baf78a , 0xce6584d6 , 0x5134db57 , 0x35e0c27b , 0x8f77a284 , 0x0a7b475e , 0x19e24af5 ] )
+  , Mont::unsafe_make( [ 0xc10835a3 , 0xb539b67f , 0xa5d6082d , 0xd49dcb6d , 0x6d23cd32 , 0x081b2d09 , 0x2e62161a , 0x2f020313 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x11a171d0 , 0xe718d04d , 0x1c258b97 , 0x1802a84c , 0x8099e56e , 0x80a53dcc , 0xc6cdd281 , 0x1466a824 ] )
+  , Mont::unsafe_make( [ 0x9da0bba8 , 0xe4ed0285 , 0x5003d82a , 0x4f53624c , 0x20f808d7 , 0x9d932c60 , 0x5f75c71e , 0x30076457 ] )
+  , Mont::unsafe_make( [ 0x3229bc82 , 0x72a84e8c , 0xddad064a , 0x8ebe16cf , 0x807a4acb , 0xbd22d374 , 0x24445e7d , 0x12251457 ] )
+  , Mont::unsafe_make( [ 0xc37d3952 , 0xcf888b8f , 0x4c71899c , 0x0fabc502 , 0x78c7906e , 0x82d9ae5c , 0x591ae3d9 , 0x2de34b04 ] )
+  , Mont::unsafe_make( [ 0x55403bb5 , 0x121b1a76 , 0x440ce180 , 0x84dba961 , 0xf0bc4c2c , 0x480005dd , 0x1f3476a5 , 0x08faa4fd ] )
+  , Mont::unsafe_make( [ 0x9109071f , 0x74ba23ff , 0x5a3d2d0c , 0x748c5de3 , 0x8cfdb4dd , 0x54a08b89 , 0x838c50fe , 0x2b7d56a6 ] )
+  , Mont::unsafe_make( [ 0x76d6eeb5 , 0x5f87ef42 , 0x6b9f0518 , 0x93e77fda , 0xbcbe6d02 , 0x591e1365 , 0x6cabe9ae , 0x1198c5e4 ] )
+  , Mont::unsafe_make( [ 0x6cfb71ca , 0x5b0db634 , 0x857461be , 0x0b5a4dbf , 0x27dd4332 , 0xddeb268b , 0x7f93aead , 0x2e5e575d ] )
+  , Mont::unsafe_make( [ 0x605b13cf , 0x74ffd3b6 , 0x2ddcadc9 , 0xcad6c384 , 0x9d1ea238 , 0xdbe99d05 , 0x81f90b82 , 0x0694b350 ] )
+  , Mont::unsafe_make( [ 0x2d0a6b0d , 0xe9290b97 , 0x4739ce53 , 0xff49188d , 0x0f02108e , 0x112d60a7 , 0x4eaa4acf , 0x2d5281f5 ] )
+  , Mont::unsafe_make( [ 0x11ade9bd , 0x2188951d , 0xfcfc94cd , 0xef74be39 , 0x20372c26 , 0x8d970749 , 0x3f57a8a7 , 0x155fd11b ] )
+  , Mont::unsafe_make( [ 0xe46212e8 , 0xd5fefa85 , 0xf815f306 , 0xfb9ec1c1 , 0x81fe91a7 , 0xbd74fe78 , 0xeb194541 , 0x2a862fdf ] )
+  , Mont::unsafe_make( [ 0x771c784b , 0x6e5bffd9 , 0x40fbaa8e , 0xc78c03f3 , 0x40d89a98 , 0x069bacee , 0x2d736e8e , 0x1a43bc50 ] )
+  , Mont::unsafe_make( [ 0x7c73ec07 , 0xe9441c2c , 0x8934ca36 , 0xa8dc3f68 , 0xc4971650 , 0xd18ea505 , 0x4eacbbb1 , 0x150776a8 ] )
+  , Mont::unsafe_make( [ 0xd5d19255 , 0xe4272c19 , 0xd4636e3c , 0x10bfda95 , 0x8adb244a , 0x1fe8a163 , 0x7f16ca75 , 0x183c1793 ] )
+  , Mont::unsafe_make( [ 0x2b354130 , 0xa5054d7e , 0x686b55d9 , 0xcd11d81e , 0xe6b575f3 , 0x43927e0f , 0x74455393 , 0x141df4fb ] )
+  , Mont::unsafe_make( [ 0x71782010 , 0x12ee2dee , 0x8e96a705 , 0x12d4fc32 , 0x4724052b , 0x30c92aab , 0xf9f7d9d8 , 0x196cd5aa ] )
+  , Mont::unsafe_make( [ 0xd349a9c1 , 0x7349fd3e , 0x90500c1e , 0x307baa4b , 0x8e4886c6 , 0x3c1e10f1 , 0x958131fa , 0x3012d8a1 ] )
+  , Mont::unsafe_make( [ 0xfc05e54f , 0xbac46753 , 0x1e81e786 , 0xb38b9142 , 0xb3814f64 , 0x2693bbe1 , 0x8accfde0 , 0x1b666061 ] )
+  , Mont::unsafe_make( [ 0x15c95049 , 0xd4aa5a3d , 0x00e366ae , 0x6cd575ae , 0x02048d7e , 0x41f6ce57 , 0x064cada3 , 0x2610a0b5 ] )
+  , Mont::unsafe_make( [ 0xe417f9fb , 0x547d76f7 , 0xf48114f6 , 0x85cc7fd6 , 0x79114f82 , 0x928be73a , 0xe9ffa8ff , 0x012ac86e ] )
+  , Mont::unsafe_make( [ 0x67c9c002 , 0x39cb8802 , 0xecfabc6a , 0xfee45016 , 0x61f4cd18 , 0xc8d8b096 , 0xfd0a062a , 0x2d088470 ] )
+  , Mont::unsafe_make( [ 0x7544353e , 0xc1bbfa9c , 0xd0c8c111 , 0x16883859 , 0x067d3e8b , 0x72030a33 , 0xc53c46e1 , 0x170878f7 ] )
+  , Mont::unsafe_make( [ 0x558282b3 , 0x36e3c4b9 , 0xe4630298 , 0xe8b75787 , 0x21bba980 , 0x96f77c32 , 0x52ec90d4 , 0x127e6b58 ] )
+  , Mont::unsafe_make( [ 0x0fb62bf3 , 0x0cc3f7de , 0x4e288b67 , 0x761f7896 , 0xd231997f , 0x42bb3afb , 0x652b3ece , 0x0c04f919 ] )
+  , Mont::unsafe_make( [ 0xd96c1eda , 0x0ed957a5 , 0x5a3b6fd4 , 0x76e93311 , 0x28a3f530 , 0x68885efb , 0x0684b216 , 0x1921ff21 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xacf36bbd , 0xfd623426 , 0x65d4092d , 0x078905f0 , 0xdb353201 , 0x3219e6a3 , 0xfa6b7882 , 0x1a2159af ] )
+  , Mont::unsafe_make( [ 0xf8e2cf80 , 0xededb331 , 0x88cba0ed , 0x9abc2259 , 0xb4b65698 , 0x03c50886 , 0xaef87561 , 0x1b086b73 ] )
+  , Mont::unsafe_make( [ 0x2dc65949 , 0x04557386 , 0x6742de75 , 0x6dd78336 , 0x7f75a84d , 0x784a98fb , 0x344eec03 , 0x1438b52d ] )
+  , Mont::unsafe_make( [ 0xd9da40ef , 0xf654cbd8 , 0x7d81d167 , 0xa7a62a34 , 0x7f773446 , 0xc1ea7506 , 0x4a830932 , 0x00c9a439 ] )
+  , Mont::unsafe_make( [ 0xb9324cb1 , 0xaaa51795 , 0x8c9ca4b0 , 0x172ee252 , 0xea6a60e2 , 0x27217a98 , 0xe68b436a , 0x012349fe ] )
+  , Mont::unsafe_make( [ 0xe46a8e78 , 0x7f6ebc04 , 0xbfd09bce , 0x4c358f8a , 0x90b28210 , 0x896b717e , 0xf6fe3f44 , 0x0f203cac ] )
+  , Mont::unsafe_make( [ 0x4c789f55 , 0x937d6ded , 0x76bca186 , 0xe4031178 , 0x2edf35dd , 0x138287ef , 0xb1863fd1 , 0x205505be ] )
+  , Mont::unsafe_make( [ 0x238020a1 , 0xfc48c473 , 0x28af6125 , 0x4896d5a0 , 0x6fc87bac , 0xc248b69f , 0x2fe3c04d , 0x15ca1a33 ] )
+  , Mont::unsafe_make( [ 0x415d93e8 , 0x92c6554c , 0x945f2f8b , 0x784e53a4 , 0xbf65e5c6 , 0xcaf95696 , 0x7979f845 , 0x1d702aed ] )
+  , Mont::unsafe_make( [ 0x36662127 , 0xf0bd04e2 , 0x4bbefb34 , 0xa1cddad3 , 0x0c58605f , 0xe923a2b9 , 0xe6fe7ad5 , 0x116b24d6 ] )
+  , Mont::unsafe_make( [ 0x61a20af6 , 0x4f9dc267 , 0xcbe77234 , 0xb343bdbe , 0xcb1b07d6 , 0x97aebc49 , 0xc7e2fe36 , 0x24b5bb7f ] )
+  , Mont::unsafe_make( [ 0xb3f30920 , 0xf129ac68 , 0x5b28ea76 , 0xcc0f90ba , 0xa220bcc6 , 0x8ee174f6 , 0x2fbdbacc , 0x15e05c29 ] )
+  , Mont::unsafe_make( [ 0x5a1ece32 , 0xf6efa104 , 0xc586e00e , 0x991127ab , 0x5b55d641 , 0xa09a89e6 , 0xafaa8117 , 0x00babb02 ] )
+  , Mont::unsafe_make( [ 0xaea67990 , 0xee3e49cf , 0x7220e04d , 0x70fc3a7f , 0x38595029 , 0xacf344cb , 0xb979570d , 0x2570ba6e ] )
+  , Mont::unsafe_make( [ 0xfce8c39e , 0x5e10160c , 0x2b7d9c01 , 0x326d8c05 , 0xc55d1403 , 0x847831e1 , 0x1786ab95 , 0x01464450 ] )
+  , Mont::unsafe_make( [ 0x76a44e81 , 0xb0a428a9 , 0x950e2393 , 0x55f2663e , 0x559c1385 , 0xcc849141 , 0x1d425aa7 , 0x010bf47c ] )
+  , Mont::unsafe_make( [ 0xb1f07d50 , 0xf129afe2 , 0x83213182 , 0x417fda10 , 0x614e4810 , 0x2f8cff0c , 0xd0ff6592 , 0x04fb97cb ] )
+  , Mont::unsafe_make( [ 0x1627757a , 0xa422d699 , 0x308841e7 , 0xeccba079 , 0x110abd70 , 0x1e6335c3 , 0x8797d170 , 0x19d8862a ] )
+  , Mont::unsafe_make( [ 0x1783adcc , 0x2fc5fc84 , 0x26e6eba2 , 0x24c39390 , 0xfb36360b , 0xbf90cd5a , 0x4e763b3b , 0x12506afb ] )
+  , Mont::unsafe_make( [ 0xa5ba6483 , 0x5428373d , 0xe88af924 , 0x2c1b7ee2 , 0x7b492197 , 0x86a5fae0 , 0x65447e81 , 0x2150f316 ] )
+  , Mont::unsafe_make( [ 0x2a2eb03d , 0x76053a8c , 0xf1246c43 , 0x631aa892 , 0x5b47a4c8 , 0x2194f3e3 , 0x9b3c191b , 0x07360197 ] )
+  , Mont::unsafe_make( [ 0xa6578393 , 0x2a2f1e71 , 0x07ec936b , 0x71b84ebd , 0xb9d0f1b0 , 0x74007356 , 0x3288c842 , 0x09796556 ] )
+  , Mont::unsafe_make( [ 0x66fcb9f6 , 0xabb3192e , 0xacc150a1 , 0x1df1f4e5 , 0x6b77dfd3 , 0x50ddf9f0 , 0x518c2ffc , 0x00c1413f ] )
+  , Mont::unsafe_make( [ 0xb6d74c28 , 0x3513d0e9 , 0x7d477697 , 0x24453e87 , 0x51722d51 , 0xcc3a6028 , 0xa404513e , 0x092cb71a ] )
+  , Mont::unsafe_make( [ 0x983b45e4 , 0x9a0dcda6 , 0x0f8843fb , 0xbf9faadf , 0xd2c0d201 , 0xc677ae73 , 0x45da0c48 , 0x0b55344b ] )
+  , Mont::unsafe_make( [ 0x67fc2de8 , 0xaf9dbfdd , 0x365bf499 , 0x430fe5e3 , 0x6b90168d , 0xc033d1e6 , 0x5052de82 , 0x01c24007 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x099b899b , 0xe11c27cf , 0x6b207c02 , 0x2505dcea , 0xb450b732 , 0xca8e67ee , 0x45995979 , 0x1546c9c6 ] )
+  , Mont::unsafe_make( [ 0xebc49672 , 0xbc2b47c0 , 0x24f77455 , 0x6d7f7696 , 0xe21db141 , 0xe8b4139a , 0xb49a4e31 , 0x142d9b83 ] )
+  , Mont::unsafe_make( [ 0xbffe174e , 0x3f480b89 , 0xf83c10fc , 0x4f162abf , 0xe2af3e14 , 0xd490fedb , 0xfc7a0367 , 0x16dfd60c ] )
+  , Mont::unsafe_make( [ 0xb393fa48 , 0x76e09b56 , 0x8facae09 , 0x4b0c55fe , 0x0955c0c0 , 0x5243c6e1 , 0x6d35e81e , 0x136d8df0 ] )
+  , Mont::unsafe_make( [ 0x895636d4 , 0x7481cdd7 , 0x3dbf6476 , 0x74f2ce42 , 0xe69c7a5c , 0xadc00751 , 0x6137f24c , 0x1ebe292b ] )
+  , Mont::unsafe_make( [ 0xee50db27 , 0xe1618d6f , 0xfa52ac01 , 0xdd1b626e , 0x91cf87bd , 0xc23f3e0a , 0xa1f8c2cd , 0x2ec0035e ] )
+  , Mont::unsafe_make( [ 0x432acb89 , 0x8ea0be02 , 0xbe401e6c , 0x1157f195 , 0x031fb0aa , 0xe288f15b , 0x5e9ac70f , 0x1f2346a8 ] )
+  , Mont::unsafe_make( [ 0x7706a261 , 0x112dc20a , 0x2162a871 , 0xafd1303c , 0xd55f4e24 , 0x76937554 , 0xcb7542b7 , 0x17dc5959 ] )
+  , Mont::unsafe_make( [ 0xe7ba2e11 , 0x7ba3780c , 0xc611c59b , 0x2090f8f5 , 0x1db0df42 , 0x2290f450 , 0x2c8c26c1 , 0x005e70b6 ] )
+  , Mont::unsafe_make( [ 0x04b6c1c7 , 0xb5e4d4d4 , 0x765375ba , 0xc53838ed , 0x80859361 , 0x782abfc5 , 0x302f77dc , 0x12fd994a ] )
+  , Mont::unsafe_make( [ 0x945a4f9d , 0x763e7dcc , 0x55a0fc0d , 0x4555fb44 , 0x1eb89d54 , 0x130511a0 , 0x2d7ba31e , 0x02748684 ] )
+  , Mont::unsafe_make( [ 0xa27d63bc , 0x1a31681d , 0x92cc8a29 , 0xda8c7a5f , 0x9a3786c7 , 0x1efa052c , 0x5edbc255 , 0x1af9bf3d ] )
+  , Mont::unsafe_make( [ 0x12026632 , 0x634d313c , 0x68550ed3 , 0x68771aac , 0x7dc01076 , 0x260dc7ce , 0xd8b9f194 , 0x0cfc9a8f ] )
+  , Mont::unsafe_make( [ 0x2dbbc0f2 , 0x6f98e007 , 0xef2ae039 , 0x3ce928ed , 0x43c2b93b , 0x4c839670 , 0x41636ac9 , 0x2d6ab6cd ] )
+  , Mont::unsafe_make( [ 0x69f549b3 , 0x2988c11d , 0x32bb1ec1 , 0x0404f5cb , 0x568a9221 , 0x2e6bb338 , 0xeb1cf985 , 0x02bfa57d ] )
+  , Mont::unsafe_make( [ 0x25553a7f , 0x52a6cdad , 0xfab831ab , 0x4d30517e , 0x4869fa57 , 0x7d05a56b , 0xadb0f87d , 0x2f24c333 ] )
+  , Mont::unsafe_make( [ 0x4ef22555 , 0x87d02cea , 0x42be224a , 0x2945e195 , 0x28282c68 , 0x42bd2376 , 0x2ab294f3 , 0x0da12bd6 ] )
+  , Mont::unsafe_make( [ 0xd3b8c083 , 0x0b075bbb , 0x449d5d4f , 0xd94e10ef , 0x2e04a482 , 0x2a2813f5 , 0xcd585f7f , 0x1373002a ] )
+  , Mont::unsafe_make( [ 0x21264c01 , 0xc3ad041a , 0x4317b62f , 0x3fc7a93b , 0x4cbf106d , 0xe6f7e8a9 , 0xbdc0d176 , 0x01f6e80f ] )
+  , Mont::unsafe_make( [ 0x07099c14 , 0xce275be3 , 0x78e3137d , 0xfca4cf4b , 0xd84515ea , 0xb9cf326a , 0x9fee9642 , 0x2f40360d ] )
+  , Mont::unsafe_make( [ 0x9130a4fc , 0xcd5c0c2f , 0x7c85537a , 0x8c0e9983 , 0xb35f4a3e , 0x1c773d64 , 0x4da7583d , 0x2d732830 ] )
+  , Mont::unsafe_make( [ 0x6530442d , 0x72da08c0 , 0xb02fdb7c , 0x64b19521 , 0x09d77b4f , 0x6b71da64 , 0xfe371681 , 0x0c64eff7 ] )
+  , Mont::unsafe_make( [ 0x737e12f0 , 0x95a0f8ce , 0x7fb4eef3 , 0x61d19471 , 0x749fe265 , 0xef00b2a2 , 0xb0961757 , 0x115b6c4b ] )
+  , Mont::unsafe_make( [ 0x657e893b , 0x81cb538d , 0x554d5f6d , 0xa375d969 , 0x083890b1 , 0xde8deb6f , 0x55999721 , 0x1ec5626f ] )
+  , Mont::unsafe_make( [ 0x8792e435 , 0xbff7d0f2 , 0xe90cf197 , 0x77b8ccd7 , 0xdadc708d , 0xf16e9ccc , 0x610c4b40 , 0x0e9fc272 ] )
+  , Mont::unsafe_make( [ 0x80a4e65e , 0xc50a0bbe , 0x87843f96 , 0xc69c72fd , 0x295e5d6c , 0xb5138178 , 0x35ffb8e3 , 0x169ed4af ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x6227b77f , 0x3ebf9090 , 0x4c5fab0e , 0xb6fbe075 , 0x142d43dd , 0x72db4dc0 , 0x0b1bb09e , 0x028d5283 ] )
+  , Mont::unsafe_make( [ 0xf99fabff , 0x00923663 , 0xe671c92d , 0x43ac1587 , 0x3155f4a1 , 0x25fbbcce , 0xa098ea90 , 0x23473a05 ] )
+  , Mont::unsafe_make( [ 0xbcc87101 , 0xda7b6882 , 0x476e7763 , 0x0d2edbcf , 0x8580242f , 0x2d69e8ec , 0x27305ea1 , 0x08dd22c0 ] )
+  , Mont::unsafe_make( [ 0x5df35416 , 0x3588ab54 , 0x7569f16d , 0xe54c0cc3 , 0xaa6e6017 , 0x3592bc92 , 0xc80d44d3 , 0x00e824c4 ] )
+  , Mont::unsafe_make( [ 0xb9d572d4 , 0xb0ec2294 , 0x55257dbe , 0xad9b421b , 0x66e30b57 , 0x79e9ebf9 , 0x66d398e3 , 0x048bdb53 ] )
+  , Mont::unsafe_make( [ 0x32b2926b , 0x968ab96d , 0xb864d913 , 0x579625ea , 0x9a0e5c2c , 0x1470da16 , 0x80097732 , 0x2af7bb3b ] )
+  , Mont::unsafe_make( [ 0x16ba12f4 , 0xf81889e5 , 0xc2da0d75 , 0x5da73360 , 0xba79b3f0 , 0xb38204bf , 0xd74b082c , 0x2a773002 ] )
+  , Mont::unsafe_make( [ 0xb3db612d , 0x7ee83a86 , 0x84843110 , 0x8880cca8 , 0x01c2d2f0 , 0x35098dd4 , 0x57be148d , 0x06911595 ] )
+  , Mont::unsafe_make( [ 0x835879d8 , 0x06bf79c3 , 0x1a7a54ea , 0x73c0c1a3 , 0x8152bb74 , 0xa92c3c6b , 0xb286c6bf , 0x084a45d5 ] )
+  , Mont::unsafe_make( [ 0x5e258eb0 , 0xf509349b , 0xf4dea758 , 0x7bb30348 , 0xf47c5a86 , 0x2152d5f7 , 0xf1ff5a7c , 0x04f242cc ] )
+  , Mont::unsafe_make( [ 0x29d5413d , 0x3902e6c1 , 0x62ffcf23 , 0xc396d9da , 0x8ae63aa0 , 0x679f4a52 , 0x4195cf04 , 0x1d750280 ] )
+  , Mont::unsafe_make( [ 0xd0829295 , 0x4288256b , 0x23d27267 , 0x52ef6762 , 0xff577f77 , 0x41ecb0a8 , 0x66ba7cf9 , 0x12f0460d ] )
+  , Mont::unsafe_make( [ 0x07237f99 , 0xa0d0f49c , 0x479d8a7a , 0x1203cfc1 , 0x454f047f , 0x212c51bc , 0x5ad4402d , 0x03671456 ] )
+  , Mont::unsafe_make( [ 0xcc2262b9 , 0x49a4a73e , 0xdcc59103 , 0xfdabaa37 , 0xd27432e5 , 0xb187d243 , 0x65316a9a , 0x12ea1e6f ] )
+  , Mont::unsafe_make( [ 0xd2f9c036 , 0x0c33d5dd , 0xf89e5e27 , 0x8d238de5 , 0xec1c0e51 , 0xcc444f0b , 0xf54e055e , 0x142fa2df ] )
+  , Mont::unsafe_make( [ 0x495bb462 , 0x8473030e , 0xf3abed75 , 0xefda364d , 0x7f14726d , 0x10999047 , 0xc2b3a395 , 0x129c68fa ] )
+  , Mont::unsafe_make( [ 0x578a2f4d , 0x889deaa9 , 0x0c2517cb , 0xf2fd2b90 , 0x5a3dbcd9 , 0x31c5933f , 0xf5c087d3 , 0x24f12c6f ] )
+  , Mont::unsafe_make( [ 0xef660a8f , 0x3494f1ad , 0xeb894f9f , 0x8eb96033 , 0x7ae9a3aa , 0x9eb13074 , 0x82223866 , 0x07e9d214 ] )
+  , Mont::unsafe_make( [ 0xda41040a , 0xa4eb302f , 0x97250e3a , 0xa52b5f3d , 0x90c1ad76 , 0x35f70c99 , 0x6db15235 , 0x112d8989 ] )
+  , Mont::unsafe_make( [ 0x27415b28 , 0xbc3bdffa , 0x9e677322 , 0x40b98c1e , 0xbf5881c9 , 0x6feb2ee3 , 0xffdd321e , 0x1c78bc4a ] )
+  , Mont::unsafe_make( [ 0xe0d92584 , 0xfef9142a , 0x521f19bb , 0x990f2e8a , 0x0e9fd48c , 0x9a1e92da , 0xd8f34b22 , 0x1d96e773 ] )
+  , Mont::unsafe_make( [ 0x238bad43 , 0xd87c37f2 , 0x96a3de2f , 0xe4840901 , 0x5fd1fac7 , 0x8fc20b1f , 0x75304ea7 , 0x26fbdb6e ] )
+  , Mont::unsafe_make( [ 0xf4daa789 , 0xd7dff8ad , 0x824975bd , 0x1d9ce43f , 0x0c72bbbd , 0xa252cdfa , 0xc03ee690 , 0x2a339ab2 ] )
+  , Mont::unsafe_make( [ 0x8c0bc9fa , 0x06204877 , 0xbfef0603 , 0x8ffbd1aa , 0x7169c8d8 , 0x823205a0 , 0x53f64de5 , 0x01950107 ] )
+  , Mont::unsafe_make( [ 0xdf391436 , 0x31d90f22 , 0xf90880cb , 0x572498ba , 0x4e543aec , 0xd589bfd7 , 0x103beff7 , 0x00dc696c ] )
+  , Mont::unsafe_make( [ 0x0e6455c0 , 0x36c2a415 , 0xe869fa3f , 0x6f04745d , 0xc32164dc , 0x17d531ef , 0x358e41c1 , 0x175880ff ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x53495080 , 0xeb742083 , 0x040ce060 , 0xda14b3e9 , 0xca31d72f , 0x5af1cb35 , 0x24087dcb , 0x0dd7b80a ] )
+  , Mont::unsafe_make( [ 0x473bcac5 , 0x7f5bc09d , 0xeced7917 , 0xb3cea8e2 , 0xf70d8a60 , 0xb0520246 , 0xabb38240 , 0x1c823bcd ] )
+  , Mont::unsafe_make( [ 0xc16876ff , 0xbcbb4d99 , 0x10afee56 , 0xbf755985 , 0x501211ba , 0x98eaa70a , 0x4726b69f , 0x1da1c094 ] )
+  , Mont::unsafe_make( [ 0x5c84bd01 , 0x2dfa2483 , 0x70313101 , 0xb16de42f , 0xd307a99b , 0x0fbdec28 , 0x0e078618 , 0x2cea7a52 ] )
+  , Mont::unsafe_make( [ 0xecac2b34 , 0x1ee09986 , 0xe7f020f1 , 0xd585075a , 0x2bf2435d , 0x517c7af4 , 0x15fe7943 , 0x19b50306 ] )
+  , Mont::unsafe_make( [ 0x99f4520a , 0xf2700081 , 0xc69ed658 , 0x0610e892 , 0xb226bacd , 0x93b2190b , 0x88de85bb , 0x0fc8728d ] )
+  , Mont::unsafe_make( [ 0x14adcc37 , 0xd0c61cc4 , 0xdb2e56cc , 0xfe9e5752 , 0x5cb967a8 , 0x0e71eba0 , 0x2d255df5 , 0x161d1df7 ] )
+  , Mont::unsafe_make( [ 0x17582809 , 0xb6290c3d , 0xc40bb452 , 0xff09dcfb , 0x50da9aa4 , 0x7722b804 , 0xa32c1aac , 0x02c31a3e ] )
+  , Mont::unsafe_make( [ 0x14d4118e , 0x661dc064 , 0xf353cad9 , 0x2f946415 , 0x60e48ce6 , 0x18557a46 , 0x66dc54ed , 0x04255592 ] )
+  , Mont::unsafe_make( [ 0x15812726 , 0x8d548b94 , 0xdacaa7ad , 0x062ccab4 , 0xd73cb5ed , 0x770a5fe4 , 0x8254a47d , 0x0aa28db1 ] )
+  , Mont::unsafe_make( [ 0x4478a8b3 , 0x617b8a0e , 0x655c0605 , 0x23180343 , 0x2f2bd7e3 , 0xfb53005d , 0x82e5b606 , 0x11af881c ] )
+  , Mont::unsafe_make( [ 0xe60fc1da , 0x4c61ca77 , 0xc9a95279 , 0x701c8ced , 0xc4c534a0 , 0x9d28d4bc , 0x899520b7 , 0x1c560a65 ] )
+  , Mont::unsafe_make( [ 0xc18571cc , 0x7dd48ed3 , 0x2c31feb3 , 0xc027372d , 0x5b06f10e , 0x4629a6a3 , 0xd81cb312 , 0x0355ff5c ] )
+  , Mont::unsafe_make( [ 0x52d156e7 , 0xd1b17040 , 0x1944b49a , 0x3a5c9d4a , 0x1731ab7a , 0xe028bb8b , 0x5af4ebda , 0x27ff2888 ] )
+  , Mont::unsafe_make( [ 0x079ca833 , 0xa9b42a4c , 0x161ea69a , 0xf16496bf , 0x6a5de176 , 0xe4b74ab0 , 0x38ec4686 , 0x17b88275 ] )
+  , Mont::unsafe_make( [ 0x48e69ba2 , 0x4fab2ce3 , 0xfa2872c8 , 0x00b3333d , 0x99d3b71a , 0x55099e11 , 0xfc47bfc6 , 0x0376a42e ] )
+  , Mont::unsafe_make( [ 0xbbee7c2a , 0xd44e899e , 0x3b186b95 , 0xb2f62e79 , 0x6158c543 , 0x15ac4640 , 0xe7e2f9e4 , 0x2af8316e ] )
+  , Mont::unsafe_make( [ 0xb7fe7a49 , 0x4177b6e3 , 0x06855604 , 0xea6fde13 , 0xb390b378 , 0x4ccb4767 , 0xab599302 , 0x02d2ca38 ] )
+  , Mont::unsafe_make( [ 0x995c0fad , 0xb3dfd34a , 0xcf6a24d5 , 0xf6b77a96 , 0x4039a234 , 0x58f89f18 , 0x6b6cc685 , 0x07c4f433 ] )
+  , Mont::unsafe_make( [ 0x4b6136fa , 0x7cf422ed , 0xab6c4ae5 , 0xceb36b8a , 0x4393b14c , 0xb57fd253 , 0x1ac0b40a , 0x120c4946 ] )
+  , Mont::unsafe_make( [ 0x62dcf9c7 , 0xaf00d18d , 0xbac602d3 , 0x2fd8a586 , 0xdd210855 , 0x287297c3 , 0xd5a81169 , 0x20123b56 ] )
+  , Mont::unsafe_make( [ 0xf965933c , 0xef55fe61 , 0x3d9b09f3 , 0x709122ac , 0xe2531fd0 , 0x243e6d9a , 0x603e8231 , 0x204bbb3b ] )
+  , Mont::unsafe_make( [ 0xc185ae3b , 0xd07c5c71 , 0x8d8c5f69 , 0x8cf7f83b , 0xb696eb01 , 0xc8bcbf72 , 0xd719fd9c , 0x2078522d ] )
+  , Mont::unsafe_make( [ 0xf6c7adc8 , 0x9b911d10 , 0x5bb9c1eb , 0x42fb0102 , 0xe06692de , 0xf291f61e , 0x1c8ee914 , 0x2ae47bd2 ] )
+  , Mont::unsafe_make( [ 0x8f104783 , 0x3f2c0aa8 , 0x444db42e , 0x6388b11f , 0x04cc4f1a , 0xdb27c827 , 0x7d58a209 , 0x07e5d898 ] )
+  , Mont::unsafe_make( [ 0xcba50237 , 0x4d82ffa8 , 0x45d6de0e , 0xbd9f05c4 , 0xb0facde5 , 0x525fee7d , 0x964d5ae9 , 0x1a2a63f8 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x0254aedc , 0x507a6dc4 , 0xbac691ce , 0x19351612 , 0xd7f2519e , 0xe80151db , 0x668bc988 , 0x2ea138ef ] )
+  , Mont::unsafe_make( [ 0x97e988a9 , 0x521ce80b , 0x00443598 , 0x7ed44ea6 , 0x2556753e , 0xbea41d88 , 0x4b6f33ac , 0x2e4e42f3 ] )
+  , Mont::unsafe_make( [ 0x03cd150f , 0x24dd02f9 , 0xbdf47ed2 , 0xa06e467b , 0x0ba0a858 , 0xf725a05f , 0x04a86cef , 0x11acc514 ] )
+  , Mont::unsafe_make( [ 0x34803169 , 0xf99ef160 , 0x87229f69 , 0x872929b5 , 0x4235f2b4 , 0xbbf275f4 , 0xd69c09a2 , 0x2c8c739f ] )
+  , Mont::unsafe_make( [ 0x5a3638ce , 0xe1903bf0 , 0xd98be643 , 0x6dbd8451 , 0x1c4eda1a , 0x4f4fc8d0 , 0xef7c1e5e , 0x12aafdd1 ] )
+  , Mont::unsafe_make( [ 0x657d5fa9 , 0xdd10184e , 0x67e407a1 , 0xc656e5b9 , 0x9f7147f2 , 0xcf2a749b , 0x6900d29e , 0x2365796c ] )
+  , Mont::unsafe_make( [ 0x2648972a , 0x0dbaae1f , 0x5e6aba46 , 0x72fd7651 , 0x580da061 , 0x7ead6b91 , 0x24fc540a , 0x2527dabe ] )
+  , Mont::unsafe_make( [ 0x94e6e613 , 0x51a462da , 0xcc94188b , 0x61b871b3 , 0x56ca2fb6 , 0x8f2dfadf , 0x48ffc795 , 0x24680ccd ] )
+  , Mont::unsafe_make( [ 0xd1e96044 , 0x7a8df32d , 0xf5553e43 , 0x60723a99 , 0xf118e47b , 0x11dbf5d1 , 0xd3e1d71f , 0x1631b1cb ] )
+  , Mont::unsafe_make( [ 0xf9683db2 , 0x80f66f2a , 0x521dadf9 , 0xa09a0ed4 , 0x7bac2084 , 0x87917f07 , 0x815abea8 , 0x26391c45 ] )
+  , Mont::unsafe_make( [ 0x8602a83b , 0x99d18ebd , 0x04600a19 , 0x3f38b70b , 0xb7a952a3 , 0xd62cad72 , 0x8a770e90 , 0x1db320d7 ] )
+  , Mont::unsafe_make( [ 0x231197d9 , 0xca99a5a2 , 0x5ce03a5d , 0x8552751f , 0xd28c346d , 0xa64ca1fe , 0x5b4d0b28 , 0x008c7e1f ] )
+  , Mont::unsafe_make( [ 0x4bb51b60 , 0xeeb6d107 , 0x28c191d7 , 0x0a3d519f , 0x587a3d5f , 0xa1bd2cd8 , 0x62a13b49 , 0x18cae281 ] )
+  , Mont::unsafe_make( [ 0xc9c3a06a , 0xc6f42efe , 0x006d36bc , 0x5f3519c5 , 0x1f168335 , 0x05a7441a , 0xba701955 , 0x24892af0 ] )
+  , Mont::unsafe_make( [ 0x93b339b8 , 0x69ee01c0 , 0x46e69d81 , 0x62cb7663 , 0x7eb3b662 , 0x5afa8e68 , 0x6a268947 , 0x099e68af ] )
+  , Mont::unsafe_make( [ 0x75d23182 , 0x94cc71f1 , 0x32462170 , 0x13e6ad32 , 0xfc749caa , 0x2992defb , 0x6193105b , 0x2a671a86 ] )
+  , Mont::unsafe_make( [ 0x90f55da6 , 0x99780971 , 0xf72399f7 , 0x2f5ebfe7 , 0x77f9cd40 , 0x3e5e4e6f , 0x8790bf8b , 0x015ff3c6 ] )
+  , Mont::unsafe_make( [ 0xec74fc8e , 0x17980bab , 0x85781eac , 0x7b5d2aa3 , 0x6bad09a9 , 0x6e84e094 , 0xeb541663 , 0x1b3e3cf8 ] )
+  , Mont::unsafe_make( [ 0x7cb0508e , 0x5cee3037 , 0x14656bb4 , 0x728e363f , 0xed418e08 , 0x72e075e9 , 0xddc66d9c , 0x10c4ae77 ] )
+  , Mont::unsafe_make( [ 0x1feb0971 , 0xddd21f44 , 0x982cee12 , 0xcdeec822 , 0xba707d9a , 0x6f70e5f1 , 0xbcd87b2e , 0x291acdea ] )
+  , Mont::unsafe_make( [ 0xba0b11b2 , 0x9cd4c539 , 0xb5248cf4 , 0xcf9481f9 , 0x1ad3a3fb , 0xe4165bec , 0xf320f043 , 0x0e227ea4 ] )
+  , Mont::unsafe_make( [ 0x65c871bc , 0xd624a699 , 0x7d65098c , 0x0be9ca07 , 0xf2d13a97 , 0x340dd738 , 0x8626f93f , 0x12974414 ] )
+  , Mont::unsafe_make( [ 0x61ce2c08 , 0x6eb53274 , 0xd1275b3f , 0xd24b04dd , 0xbd96be7c , 0x31e68ffe , 0x5580146d , 0x265eb63e ] )
+  , Mont::unsafe_make( [ 0x612fe14c , 0xd4bdddda , 0x218a238a , 0x4725cf1f , 0x20b2d47d , 0xf4176684 , 0x62246c58 , 0x0997aee2 ] )
+  , Mont::unsafe_make( [ 0x5bc1d67d , 0x63e62cba , 0x5abc8a29 , 0x7c683eb2 , 0x4c095d90 , 0x31d8fb1b , 0xe4d81d81 , 0x294cdea7 ] )
+  , Mont::unsafe_make( [ 0xf7c5bd57 , 0x30219ec7 , 0xf87b0d7a , 0x43e4c04c , 0xa3c73784 , 0xc7c9bec5 , 0xe4d31c1d , 0x221553c4 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xfc902902 , 0x4da9e829 , 0xa1e6fe7a , 0xb554bbc7 , 0x743092bc , 0x04ea29df , 0xf74ff79c , 0x23af1257 ] )
+  , Mont::unsafe_make( [ 0xccdee873 , 0x635460fc , 0x4bfd997e , 0x032b86df , 0xaeb93876 , 0xcdf31821 , 0x90217806 , 0x15243e3a ] )
+  , Mont::unsafe_make( [ 0xcff6f5f3 , 0xb38511a8 , 0xce4be8fa , 0x0cbbdf81 , 0x8354824c , 0x67f338ea , 0x52816b41 , 0x2dc06858 ] )
+  , Mont::unsafe_make( [ 0xbe15dfb1 , 0x464f2200 , 0x0a1604bb , 0x1ec1a6f7 , 0xd86ba2e6 , 0xb9876116 , 0x8ec609f0 , 0x22267e70 ] )
+  , Mont::unsafe_make( [ 0x4c382f9a , 0xdeb2a2d8 , 0xca49067e , 0x393227cb , 0x4f44fdf7 , 0xc5acd2d1 , 0xe2368ee2 , 0x0401ff53 ] )
+  , Mont::unsafe_make( [ 0x42126971 , 0x4ba41ce8 , 0xc9bd0a98 , 0xbaca7c39 , 0xba18a9e7 , 0xc354329d , 0x92771279 , 0x1a42713d ] )
+  , Mont::unsafe_make( [ 0xd5f73d59 , 0xaa89a709 , 0x9b58b1f0 , 0x7e33b9ba , 0x33da69a6 , 0xbcc4500b , 0x71de8de9 , 0x1fbb2521 ] )
+  , Mont::unsafe_make( [ 0xd40ab0eb , 0xfb39818d , 0x13f2a0cb , 0x73aff458 , 0x007ddba2 , 0xc82611c7 , 0xb19b0467 , 0x2dca1347 ] )
+  , Mont::unsafe_make( [ 0x3b252415 , 0x0722274d , 0x1a3526e0 , 0x59e82cb6 , 0x37c28335 , 0xa45d5a27 , 0xe2640e0f , 0x16ca1bd9 ] )
+  , Mont::unsafe_make( [ 0xfe6a7b2a , 0xfb062f17 , 0x084ab937 , 0xeef15b05 , 0x67eb5875 , 0xcb1868b3 , 0x0b0f6dca , 0x28827f91 ] )
+  , Mont::unsafe_make( [ 0x91b62730 , 0x893910f8 , 0xba7ae6f3 , 0x1e729869 , 0x53249a32 , 0xae97d5e4 , 0x8b3b4561 , 0x2802aedb ] )
+  , Mont::unsafe_make( [ 0x2f8c8b5d , 0x94a1e634 , 0x51d37c04 , 0xa50e9b7e , 0x439f2f81 , 0x0bafcee9 , 0x0ea09d4b , 0x02ad4d2a ] )
+  , Mont::unsafe_make( [ 0x910c5bc5 , 0x53157108 , 0x4408d3cc , 0x0f45a4cd , 0xe4ed50e5 , 0xbe2faed8 , 0x353d6782 , 0x187a13b0 ] )
+  , Mont::unsafe_make( [ 0xbdff180e , 0x72d45c57 , 0xa1346fb2 , 0xaa6f2e47 , 0x5f011684 , 0xcce9fc4b , 0xa75602a9 , 0x250868b0 ] )
+  , Mont::unsafe_make( [ 0xfcebdee9 , 0xbd6ea1af , 0x2edd0661 , 0xe4e40356 , 0x005b4836 , 0x2e946029 , 0x112d3cc7 , 0x1c752c21 ] )
+  , Mont::unsafe_make( [ 0x36f8a0ae , 0xb206cf59 , 0xb9723626 , 0x999c9b94 , 0x1dcdf12c , 0x01800d7b , 0xd563c7b8 , 0x196db8a2 ] )
+  , Mont::unsafe_make( [ 0x3fe69892 , 0x3c299b6b , 0x21cd5367 , 0xdc14d6da , 0x075f8de9 , 0xee316b72 , 0x34772eed , 0x2542bb6f ] )
+  , Mont::unsafe_make( [ 0xe2c83712 , 0x099f3ea1 , 0x569881c6 , 0x4de673d5 , 0x0f61ce77 , 0xadd7dcba , 0x85790c47 , 0x146b571c ] )
+  , Mont::unsafe_make( [ 0x751bd806 , 0xd06adf4e , 0x504d3bfd , 0xd0d4b3d3 , 0xc3771333 , 0xd4a1194d , 0x21768b74 , 0x1833d210 ] )
+  , Mont::unsafe_make( [ 0x1c6eae07 , 0x074bbb1a , 0x0c53e9d7 , 0x85d97a12 , 0xcec4c585 , 0xdf0017b3 , 0xa88c9973 , 0x28b1543d ] )
+  , Mont::unsafe_make( [ 0xcd241b3b , 0xf4167542 , 0x25491cad , 0x47590031 , 0x87f9022d , 0xcf021fd3 , 0x5e32c788 , 0x300be275 ] )
+  , Mont::unsafe_make( [ 0xc750412b , 0x1c987c73 , 0x463dc6f8 , 0xa82a97e6 , 0x1e4a3a2e , 0x6a333e31 , 0x1d8db2fb , 0x016504c3 ] )
+  , Mont::unsafe_make( [ 0xdae53c53 , 0x337aba2e , 0x6364a4b7 , 0xd3e782ef , 0x1c831614 , 0xa256ef01 , 0x3b9d80a1 , 0x17a05d28 ] )
+  , Mont::unsafe_make( [ 0xdbea316b , 0x0407afc1 , 0x79abe6e4 , 0x25a5e4e7 , 0x0ba9e277 , 0x05f3b422 , 0x5cb1576c , 0x108ae53a ] )
+  , Mont::unsafe_make( [ 0x9174a75d , 0x547d82e1 , 0x768e336f , 0xd7fffd98 , 0x8939c83d , 0xd3556735 , 0xbb67381b , 0x2a53ba2d ] )
+  , Mont::unsafe_make( [ 0x982dfe9c , 0x1ab55ffc , 0xea5998b4 , 0x252f6644 , 0x8af8986b , 0xb741c822 , 0x2056d8a0 , 0x2439aee9 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0x145846ec , 0xbbc79c02 , 0xe8e0fc05 , 0xdb2c2adc , 0x4febe74a , 0xf9cf2a35 , 0x1e15937d , 0x2c524b5b ] )
+  , Mont::unsafe_make( [ 0x0a76c984 , 0x1ea1719b , 0x65abddfb , 0xa0d57e10 , 0xbce438fa , 0xaa5441f3 , 0x3141b3ec , 0x0bce4997 ] )
+  , Mont::unsafe_make( [ 0x5f6540b8 , 0xf9e49229 , 0x55b3f15d , 0x344b6db9 , 0xa00a3a7c , 0x98c031a0 , 0x6dabf936 , 0x28600a6a ] )
+  , Mont::unsafe_make( [ 0x897b3e42 , 0x0dd438ce , 0x71cd7cca , 0x923b5bec , 0x7525af4b , 0x6b72a117 , 0xa3002be4 , 0x16030f0a ] )
+  , Mont::unsafe_make( [ 0xf83859fe , 0x745bac20 , 0xac2961dc , 0x58f26be6 , 0xf2e39e39 , 0x71815e8e , 0x88317952 , 0x0a0e0532 ] )
+  , Mont::unsafe_make( [ 0x7a99fc3a , 0x0e1b0e6a , 0xd008e7b5 , 0x6cbd69eb , 0xd13d2ec0 , 0x740cdb0f , 0xa6583dcc , 0x1bde0fc2 ] )
+  , Mont::unsafe_make( [ 0x48effaf9 , 0x5c94b3be , 0x2248618b , 0x267d43da , 0x567fba73 , 0xd3278703 , 0x53845c3a , 0x1ebddd55 ] )
+  , Mont::unsafe_make( [ 0xdeddd654 , 0x6d4dd932 , 0xcf7ab32e , 0x7aa2d731 , 0x96c52bb4 , 0x5cabd32b , 0xa7afc5f3 , 0x1f6c9bf4 ] )
+  , Mont::unsafe_make( [ 0x4a710731 , 0x011eb5ae , 0xd2169e30 , 0xb1b69365 , 0xa4c6d8f7 , 0x53201d0e , 0x29fe56aa , 0x23b2cf4f ] )
+  , Mont::unsafe_make( [ 0xaecc0129 , 0xeed03e82 , 0x9ab10db1 , 0xd77ba374 , 0xf4ed11a8 , 0x16fc0d20 , 0x89103964 , 0x1c7c0362 ] )
+  , Mont::unsafe_make( [ 0xbf418bd1 , 0x3b63dd88 , 0x5afc484e , 0x824d1e1b , 0x0ab8b05f , 0x9dcf7079 , 0xf7352762 , 0x00cbf8fc ] )
+  , Mont::unsafe_make( [ 0x2e8c67c4 , 0x7d75c043 , 0x3a6cfee1 , 0xa887e94e , 0xb03513ee , 0x908d0f9f , 0x46cc209e , 0x30186c10 ] )
+  , Mont::unsafe_make( [ 0xa0c58da8 , 0x57cc6246 , 0xb2adec18 , 0x901d1f13 , 0x371eccce , 0xc2e73ff6 , 0xd93c02cd , 0x008640a1 ] )
+  , Mont::unsafe_make( [ 0x2c398b16 , 0xa502a189 , 0xf83d56ae , 0x985bab20 , 0xf5b245f9 , 0xca10bc25 , 0x409e77a4 , 0x200d849d ] )
+  , Mont::unsafe_make( [ 0x864d28d9 , 0x32937045 , 0x03b12441 , 0x4aa83c54 , 0x02835aa3 , 0xe0d57151 , 0xb699a944 , 0x0139815a ] )
+  , Mont::unsafe_make( [ 0xc43ea449 , 0x1478a0bc , 0x1f2303c5 , 0x45dc0e3a , 0x9b45fb31 , 0xb05056f2 , 0xa370ad41 , 0x2afe9c5b ] )
+  , Mont::unsafe_make( [ 0xc242a930 , 0xc40faaab , 0x4925f049 , 0xc577bd0b , 0xac5f946c , 0x1c0357f1 , 0x914a949e , 0x06b6e8b1 ] )
+  , Mont::unsafe_make( [ 0x0944d52a , 0xafab176b , 0xba22c4ab , 0x316a59ac , 0xb7164e25 , 0x61ec17d2 , 0x44f856ef , 0x132a9cd4 ] )
+  , Mont::unsafe_make( [ 0xcdb5d661 , 0x517d8ba1 , 0x16f1984f , 0xbc320128 , 0x346f0f41 , 0xbb61652c , 0x78b09d95 , 0x24fc670e ] )
+  , Mont::unsafe_make( [ 0x3f36cdc1 , 0xa6b4edcc , 0x125de1ca , 0xb566f42a , 0x523abef0 , 0x39af4ffd , 0x1e305d58 , 0x1dacb3c5 ] )
+  , Mont::unsafe_make( [ 0xe869664e , 0x67f590be , 0x968488f4 , 0xce867a10 , 0xd43ae08f , 0x17b74b2d , 0x2f7234cd , 0x149ce436 ] )
+  , Mont::unsafe_make( [ 0x45b0bc70 , 0x1ce6e757 , 0x331f600b , 0x8b1140bd , 0xa7884bf6 , 0x28b48390 , 0x54756e00 , 0x18c3fae4 ] )
+  , Mont::unsafe_make( [ 0x7971fb02 , 0x90886458 , 0xd089e53f , 0xe0f0f89a , 0x4ca4bab6 , 0x8cf6739b , 0x3e007151 , 0x10696a28 ] )
+  , Mont::unsafe_make( [ 0xedc82d6f , 0xc437ab8f , 0xe5396a02 , 0x112daffa , 0x280af20e , 0x36a491a3 , 0xb6164c95 , 0x1d5c6ecc ] )
+  , Mont::unsafe_make( [ 0x70db9fb7 , 0xe246934c , 0x4c750230 , 0x530a545c , 0x801062d7 , 0x476a71e2 , 0xd45859f7 , 0x054017fa ] )
+  , Mont::unsafe_make( [ 0x1b588bca , 0x7433be0b , 0xc370b84b , 0x7341b5a8 , 0x7321acdf , 0x3f168d35 , 0x94f9a4ee , 0x16ef173f ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xfe7ba523 , 0x8c4494fb , 0xfae52aeb , 0x9cc4aea8 , 0x95dd43d5 , 0x9c0a59c8 , 0x7cc540af , 0x167f873a ] )
+  , Mont::unsafe_make( [ 0x6e7e2461 , 0xebbe821b , 0x641cdc00 , 0xee204202 , 0x7209f90d , 0xddb38779 , 0x82a46e1b , 0x11bcfdfe ] )
+  , Mont::unsafe_make( [ 0xb213cf45 , 0x6d2c5612 , 0x9114622d , 0x85a2ff85 , 0xa7562d5f , 0xed7e3823 , 0x53d0432e , 0x0a5a30db ] )
+  , Mont::unsafe_make( [ 0x36603dea , 0x3dd6f0a2 , 0xb9a9fbd4 , 0x80e82fb0 , 0xb96241b7 , 0x70b4efbc , 0x9f20c5f2 , 0x2895ab9a ] )
+  , Mont::unsafe_make( [ 0xb0343e6b , 0x49b954d0 , 0xef1fc714 , 0xb34534b2 , 0xdb793f02 , 0x660de914 , 0xfb07520f , 0x13ca9a02 ] )
+  , Mont::unsafe_make( [ 0xf0dfd1ea , 0x39463ab0 , 0x6b6fac64 , 0x5fb3a7d0 , 0xab43b767 , 0x8d0675e3 , 0x13e75c9e , 0x196bf444 ] )
+  , Mont::unsafe_make( [ 0xf289313f , 0x95603d41 , 0x35ebee7a , 0xab325ac2 , 0x856a25ff , 0x08520741 , 0x8e48d7b4 , 0x06a8ad96 ] )
+  , Mont::unsafe_make( [ 0x3b83d0e5 , 0xe4673e96 , 0x2ad9b66f , 0x007e985f , 0x8c3e886e , 0xcc928388 , 0x5c51b733 , 0x000dfe7f ] )
+  , Mont::unsafe_make( [ 0x7441d750 , 0xe36fc996 , 0xc5018343 , 0xef9ff16f , 0x37e1c1e9 , 0x5fe1782a , 0x33927482 , 0x2bd47730 ] )
+  , Mont::unsafe_make( [ 0xe0991616 , 0x3b0f59c2 , 0xdd9771fe , 0x8d4e430f , 0x100fd190 , 0x6749176b , 0x1ec484fd , 0x18f48a73 ] )
+  , Mont::unsafe_make( [ 0xc68384a3 , 0xbc5050b0 , 0x7d802747 , 0x6ad6fc91 , 0x02856140 , 0xd3624558 , 0xcfafee3e , 0x21aeeed4 ] )
+  , Mont::unsafe_make( [ 0x048a5420 , 0x40ed94e2 , 0x7109adaa , 0x785c5e29 , 0x9bed6e9c , 0x5bccc34b , 0xa4c80e75 , 0x19b7793c ] )
+  , Mont::unsafe_make( [ 0xff6c3680 , 0x3c6f262b , 0x1d06d3b5 , 0x25d61953 , 0x203036b4 , 0xfaf958b6 , 0x6ba8f353 , 0x20e32e64 ] )
+  , Mont::unsafe_make( [ 0xec2dc784 , 0x1b10d3a6 , 0xd6ce9b46 , 0x7c334891 , 0x39bd67ec , 0x69ec8751 , 0x456ab563 , 0x2fdfda21 ] )
+  , Mont::unsafe_make( [ 0xcc635a16 , 0x81078ce9 , 0xbad448c2 , 0xf16441ce , 0xa3064645 , 0x37d9247f , 0x6fc6d8b6 , 0x08ce5a28 ] )
+  , Mont::unsafe_make( [ 0x67a12094 , 0xbaff7674 , 0x76f55d55 , 0xa851f9bd , 0xe30114b1 , 0xb03f5155 , 0xb533756e , 0x2ee479cb ] )
+  , Mont::unsafe_make( [ 0x10943d80 , 0x16d967c4 , 0xb8fc139d , 0x57787d4c , 0x91193c56 , 0x1fa0eb62 , 0x19bed1a8 , 0x15a54d6f ] )
+  , Mont::unsafe_make( [ 0x738c85a9 , 0x4e4aa858 , 0x0b2adf7e , 0x3a456d84 , 0x61948141 , 0xe2c93b7b , 0x52f7a937 , 0x1b61515d ] )
+  , Mont::unsafe_make( [ 0x90b3f31c , 0xcf79b1c5 , 0x851eba51 , 0x9f3390f6 , 0xa85e85a3 , 0x6bf37e9b , 0x266961c4 , 0x297d40c9 ] )
+  , Mont::unsafe_make( [ 0xe1e724b3 , 0x13a81626 , 0x3ba60dff , 0x5b8b5316 , 0xf699e7f0 , 0xf5f6bb2b , 0x55a5c489 , 0x1c6b087f ] )
+  , Mont::unsafe_make( [ 0x799e7e74 , 0x079fda9a , 0x70b634f4 , 0xdb9d4ade , 0xeeda3d1d , 0x149b348e , 0xefa7ee28 , 0x1d7c9910 ] )
+  , Mont::unsafe_make( [ 0x4d5f851f , 0xa0ab03bb , 0xec56b6fc , 0x4c6151c8 , 0xffdb5923 , 0x3e0a2eeb , 0x2335f46a , 0x23aba827 ] )
+  , Mont::unsafe_make( [ 0xbe34847e , 0x4aa91a21 , 0x79d9a9d8 , 0xde11c36f , 0x5fbf6bc3 , 0x4550db9e , 0xf4e125fd , 0x26d6d0fa ] )
+  , Mont::unsafe_make( [ 0xbd48bce2 , 0x4753b2f1 , 0x8f4232eb , 0x3f7ae964 , 0xb0935849 , 0x40b5abe6 , 0xfd8ae9d3 , 0x0b266de4 ] )
+  , Mont::unsafe_make( [ 0x31df2a1d , 0xa5939600 , 0x47001fe6 , 0x609dcddd , 0x27f1e954 , 0x6908212b , 0xc90de5c6 , 0x12bd589e ] )
+  , Mont::unsafe_make( [ 0x128b81d0 , 0x4dabbb06 , 0xa13aea11 , 0x09a4c73e , 0xc15b41d5 , 0xe16ea588 , 0xa05a55c4 , 0x11ab3238 ] )
+  , Mont::unsafe_make( [ 0xd025bc23 , 0xda33a31b , 0xfa4609da , 0x7c228b75 , 0xde205da5 , 0xb6ba4043 , 0x15271bb8 , 0x1ab1a824 ] )
+  , Mont::unsafe_make( [ 0xfd84eab0 , 0x87fe9eb7 , 0x219b1786 , 0x119989c2 , 0x7b210f07 , 0xbdc13196 , 0x3cc2aa14 , 0x1ae30673 ] )
+  , Mont::unsafe_make( [ 0xb35d13f4 , 0xa28fe4e4 , 0x8ed368b2 , 0x9000fa2c , 0x5ae32688 , 0xd4e0e461 , 0x00f0efc7 , 0x12ea1a5f ] )
+  , Mont::unsafe_make( [ 0x6780f53a , 0xe77e52c1 , 0x33df13e7 , 0x7b204d5c , 0x6abbf51c , 0x8f4090b5 , 0x7b590188 , 0x1e71c1d4 ] )
+  , Mont::unsafe_make( [ 0x5637c7d2 , 0x3a944885 , 0xe5e04767 , 0x687e10eb , 0x9a2f2395 , 0x815c4af8 , 0xd8be59ea , 0x010ed53c ] )
+  , Mont::unsafe_make( [ 0xb5e883f9 , 0x429beafe , 0x3c9e8e8e , 0xa17e291b , 0xb0d3697b , 0x35d09666 , 0x890f49d4 , 0x244f12cb ] )
+  , Mont::unsafe_make( [ 0x42f0cfb9 , 0xe6c1b7fb , 0x86316ed3 , 0x813aa859 , 0x039e9eec , 0x2837e226 , 0xec182419 , 0x2db8e96f ] )
+  , Mont::unsafe_make( [ 0x1a854417 , 0xbef27231 , 0x1527eafd , 0xfabd4593 , 0x49b73f35 , 0x97cafe65 , 0x0afba58f , 0x116396ad ] )
+  , Mont::unsafe_make( [ 0x9d47c1b7 , 0xd9612e5a , 0xbc8e529c , 0xfe7ef9d6 , 0xcfe2a612 , 0xbc3897fd , 0xd76f3f88 , 0x0ce2fbdb ] )
+  , Mont::unsafe_make( [ 0x02243315 , 0xc2c799fe , 0xaa7dca3f , 0x6e42b367 , 0x001020ef , 0x1b8a8f21 , 0x0c829dc0 , 0x1627ce38 ] )
+  , Mont::unsafe_make( [ 0x39898f0d , 0x26f0958e , 0xa396119e , 0xad5ac3a5 , 0x6dc919cf , 0x762e6cb9 , 0x50ba4703 , 0x03be9329 ] )
+  , Mont::unsafe_make( [ 0x361249e8 , 0x09dd0e38 , 0xf7c73bae , 0xe1728ef7 , 0x42b8504c , 0x2dd039c7 , 0x395e92fe , 0x20a82441 ] )
+  , Mont::unsafe_make( [ 0x637acee0 , 0x95a81135 , 0x442fe0cf , 0xfb55ce15 , 0xbd73541f , 0x64acf5c1 , 0xb6be2510 , 0x1ba3a90a ] )
+  , Mont::unsafe_make( [ 0x3b794e21 , 0xf7502d9e , 0xf2148f4b , 0x87b2ce5f , 0xe2272165 , 0x058a991d , 0xc29b53e1 , 0x13cdbd96 ] )
+  , Mont::unsafe_make( [ 0x459c64f8 , 0x62ecb358 , 0x381a1d35 , 0x6fe0f31a , 0xf604cb7c , 0x12a6da2a , 0xda2a001b , 0x0d23775a ] )
+  , Mont::unsafe_make( [ 0xbfa128d7 , 0x4f3071eb , 0xc1b28428 , 0x63e78851 , 0x89600481 , 0xf2fafc70 , 0x6ce552f3 , 0x1fb5164a ] )
+  , Mont::unsafe_make( [ 0xa22dd018 , 0x7a3204f1 , 0xa0eb3c0e , 0x24332225 , 0xa79a88e2 , 0x8304d1e8 , 0xca1b98f6 , 0x15c94b9c ] )
+  , Mont::unsafe_make( [ 0x3d83cf09 , 0x26185c66 , 0x3936c4f3 , 0xc3235d4d , 0xb565eddd , 0x4596a3ec , 0x04ab8e1a , 0x28543e07 ] )
+  , Mont::unsafe_make( [ 0xeb230a89 , 0xd361e103 , 0x28fe161e , 0xcafd0b48 , 0xb8fbeef8 , 0x187a61b9 , 0xadcc4908 , 0x26ec7636 ] )
+  , Mont::unsafe_make( [ 0x75cf46dc , 0xfdab0562 , 0x65b3dbfb , 0xb96d8b3a , 0x8a9001fe , 0x0dd02a73 , 0xe80c7fc6 , 0x15bc63da ] )
+  , Mont::unsafe_make( [ 0x586b460d , 0xd8a47313 , 0xf0074d4a , 0x8d2c3d90 , 0x950805c1 , 0x60de293b , 0x4066783d , 0x21b27989 ] )
+  , Mont::unsafe_make( [ 0x4a44ca58 , 0x459b5e89 , 0xe648ad4b , 0x32c99035 , 0xaa4a2e5b , 0xec96ba5c , 0x3f44cfe0 , 0x29c0b4a5 ] )
+  , Mont::unsafe_make( [ 0xb349aff9 , 0xa7bf7e85 , 0x4743ffe6 , 0x72d35d2f , 0x047a04b9 , 0x3da5ea9e , 0xee51ce90 , 0x2ba740af ] )
+  , Mont::unsafe_make( [ 0x5b99d70a , 0xfaa503a5 , 0x33a5ae43 , 0x4e0a1e93 , 0x42029e72 , 0x2c160d36 , 0x5b7ca4da , 0x27e3fd9a ] )
+  , Mont::unsafe_make( [ 0xc57cb818 , 0xf9bebfc2 , 0xbcdfd050 , 0x41ed2ba3 , 0x3f3bc4c4 , 0x5c9c2480 , 0x4d27a458 , 0x303642a2 ] )
+  , Mont::unsafe_make( [ 0x3c22a883 , 0xf3bc3d6c , 0xd971ac84 , 0x0953751d , 0x8eb852e7 , 0x551f2502 , 0x2ad6332a , 0x06328ead ] )
+  , Mont::unsafe_make( [ 0x8deb80d5 , 0x35a29d29 , 0x949d98a2 , 0xa2ad0752 , 0x22a1bb24 , 0xe6d02575 , 0xe432594e , 0x10a8e117 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
@@ -0,0 +1,2402 @@
+
+// circomlib's Poseidon constants for t=15
+
+use crate::bn254::montgomery::*;
+
+
+//------------------------------------------------------------------------------
+
+// round constants (t for external, 1 for internal rounds; flattened)
+pub const CONST_C: [Mont; 180] = 
+  [ Mont::unsafe_make( [ 0x07607649 , 0x513f5511 , 0x39dbbd06 , 0x7090b248 , 0xeb217ae4 , 0x1e00524d , 0x0fdb522d , 0x07c85596 ] )
+  , Mont::unsafe_make( [ 0xb700ac02 , 0x267539c3 , 0xe69f3908 , 0x5625dbab , 0x9fb449be , 0x97b99f0b , 0xf94a957a , 0x09fece2c ] )
+  , Mont::unsafe_make( [ 0x52f1235a , 0xe5a26990 , 0x64647ad4 , 0x22bb7004 , 0xf6187286 , 0x59cd0c0c , 0x6c820ef7 , 0x06377fff ] )
+  , Mont::unsafe_make( [ 0x8fedc893 , 0x05c0a69c , 0x1f6d9f37 , 0x19aab122 , 0xf3867645 , 0xd4d5f7d0 , 0x8553bf3b , 0x29b4ed1b ] )
+  , Mont::unsafe_make( [ 0x73e13d96 , 0x602ad2e4 , 0x2d877085 , 0x70bb71d2 , 0x9e95d138 , 0x59238357 , 0x817d29dd , 0x27f01b88 ] )
+  , Mont::unsafe_make( [ 0x0c128a94 , 0xcbdebfaa , 0xf43051c0 , 0xb0e839f4 , 0x8e6a02cf , 0x66375be1 , 0x12d71edc , 0x078d6449 ] )
+  , Mont::unsafe_make( [ 0x9f88f1c8 , 0x8b6121cb , 0x283d9f1d , 0xe7e6afc4 , 0xa7c9c49d , 0xdce3a255 , 0xd0baa469 , 0x2fbf7d17 ] )
+  , Mont::unsafe_make( [ 0x231b10cc , 0x1ce4348d , 0x7c5ee9d5 , 0xd29a74fc , 0x06402abc , 0x65c4a673 , 0x56d6a537 , 0x165dcb4a ] )
+  , Mont::unsafe_make( [ 0x70b684d4 , 0x3a9ecf6e , 0x0279d77d , 0x0e1632b7 , 0x085f3f78 , 0xc8d765e4 , 0xb2cec78a , 0x043e4bb8 ] )
+  , Mont::unsafe_make( [ 0x93497c29 , 0x721d708d , 0xd1ea6836 , 0x5685dd63 , 0xefdeed10 , 0x4f85b16e , 0x94345947 , 0x265532fa ] )
+  , Mont::unsafe_make( [ 0x20c823a0 , 0x84ba22d8 , 0x7d24c3f7 , 0xc13a0943 , 0xbe4bd07a , 0x44a4ffe4 , 0x66b3a4aa , 0x144ed025 ] )
+  , Mont::unsafe_make( [ 0x288d12db , 0x22a158a7 , 0x47e74a31 , 0xdab3d1e8 , 0xd86606ac , 0x76a85f5c , 0x3eb5f298 , 0x239db560 ] )
+  , Mont::unsafe_make( [ 0xda9f2d35 , 0x11fcabf8 , 0xcb3ffb98 , 0x9ec7b250 , 0x0147f38c , 0x82be003f , 0x46384891 , 0x0e54eabd ] )
+  , Mont::unsafe_make( [ 0x87be8cef , 0xc61036a1 , 0xf7b01100 , 0x2b55526c , 0xda152050 , 0xf4f665bb , 0xde692e3b , 0x054e6f35 ] )
+  , Mont::unsafe_make( [ 0xfaf9fbb8 , 0xf54a55c7 , 0x65a74067 , 0x6e710316 , 0x92c02e6f , 0x76453c9b , 0x6dca7cf0 , 0x1bc480b3 ] )
+  , Mont::unsafe_make( [ 0x6e25ff22 , 0xf2f84766 , 0x45c18b27 , 0xb9bfa243 , 0x4c1c8cbd , 0x18dcfa18 , 0xb25776ac , 0x21591524 ] )
+  , Mont::unsafe_make( [ 0x6f04b0e2 , 0x8ff955d9 , 0xf98b6d18 , 0x19378182 , 0xb30735d9 , 0x9f2e9397 , 0xf3bb30d1 , 0x2f16fb43 ] )
+  , Mont::unsafe_make( [ 0xe7e59510 , 0x32c61399 , 0xffb12040 , 0x334e1078 , 0x06d4c2dc , 0x7b241c62 , 0x5f56e302 , 0x12b72176 ] )
+  , Mont::unsafe_make( [ 0xbc9f4220 , 0xf275200e , 0xaf81eae8 , 0x8730243f , 0x6968d806 , 0xcf06a3d1 , 0xf3f10926 , 0x169f2529 ] )
+  , Mont::unsafe_make( [ 0x9db0a68d , 0x6759cf50 , 0x2a5090e8 , 0x1e9df57b , 0xdde2c4d4 , 0xf570799b , 0x758f2ca6 , 0x0c7f5fae ] )
+  , Mont::unsafe_make( [ 0x8463b047 , 0x55bbd47d , 0xb4c58a3e , 0x5e493801 , 0x7257ca82 , 0xe86d1ce2 , 0x31371f04 , 0x24e7f811 ] )
+  , Mont::unsafe_make( [ 0xbf25e7ad , 0x1aee98f1 , 0xd2a9e786 , 0xc5566c93 , 0x7e5c4f77 , 0xbaadf4e4 , 0x863cf4e4 , 0x14dba144 ] )
+  , Mont::unsafe_make( [ 0x92f7d828 , 0x4e46e28d , 0xad092ec6 , 0x8127ec28 , 0x6f8f9b95 , 0x9613c695 , 0x9e7c9a9f , 0x2ca3c9ff ] )
+  , Mont::unsafe_make( [ 0xfbd52474 , 0x477aeed2 , 0xecd25488 , 0xed97d241 , 0x84b7c123 , 0x554241fb , 0x87c9b797 , 0x0513cbaa ] )
+  , Mont::unsafe_make( [ 0x66c32631 , 0xd7223dad , 0x33e58762 , 0xb3c9d959 , 0x0f1abef0 , 0x96aaa596 , 0xe25ce78d , 0x1ab6d46f ] )
+  , Mont::unsafe_make( [ 0x4181985a , 0x1d5239be , 0x0e429834 , 0x11c320e4 , 0x8556984f , 0x1da62255 , 0x7cb89018 , 0x2a514495 ] )
+  , Mont::unsafe_make( [ 0x6f025a93 , 0x2e79be67 , 0x3922f7ce , 0x7c8245bc , 0x7fe147b6 , 0x7c3add22 , 0x9033e6a9 , 0x084b3b9f ] )
+  , Mont::unsafe_make( [ 0xe562eb32 , 0x543217d0 , 0xeb58a573 , 0xe4ffdc21 , 0x62ca2091 , 0x20ffb9bb , 0x76e971a3 , 0x0c1a3995 ] )
+  , Mont::unsafe_make( [ 0xe035a87f , 0x8c123ea9 , 0xb60c54e9 , 0xc8606f69 , 0x3cef4600 , 0x1e519ff6 , 0x4bab7dab , 0x23375f5e ] )
+  , Mont::unsafe_make( [ 0xc451f2d2 , 0x51b53fb1 , 0xa5e036d7 , 0x4323b2df , 0xfa4b78eb , 0x2860e032 , 0x6b085625 , 0x148549ac ] )
+  , Mont::unsafe_make( [ 0x4ed3d3d4 , 0x21126cd9 , 0xd9c01967 , 0x3ac81e0d , 0x4b1fa047 , 0x6a611ed6 , 0x90101d78 , 0x07a10518 ] )
+  , Mont::unsafe_make( [ 0x45525997 , 0x5949e820 , 0xad03a555 , 0x54d9a54e , 0x04e72f17 , 0x3dfa2eb3 , 0x1c1e7174 , 0x1bc516cd ] )
+  , Mont::unsafe_make( [ 0x7e39cf3d , 0x9ac1b83d , 0x6e8137d8 , 0xfd6b290a , 0x764f1ecc , 0x1a5fb793 , 0xd56eb498 , 0x27b414de ] )
+  , Mont::unsafe_make( [ 0xed10e5cb , 0x910181cd , 0x9c28d5ff , 0xc250c817 , 0x9628287e , 0xb6ea1c99 , 0xd92118fb , 0x29417e49 ] )
+  , Mont::unsafe_make( [ 0x49ce3c7d , 0xd38de573 , 0x32def37e , 0x7e5cca31 , 0xa47eb780 , 0x62c2e1f8 , 0x21ea144f , 0x1658454a ] )
+  , Mont::unsafe_make( [ 0xa3aa95dd , 0x515e57f8 , 0xbd3d009c , 0x38b8ca33 , 0xf18974f0 , 0x7cfc7cbc , 0x205b0f27 , 0x247542e1 ] )
+  , Mont::unsafe_make( [ 0xd1281346 , 0x87020daf , 0xf6944791 , 0x0ec0d452 , 0x7366757d , 0x77b270b8 , 0x11165a3c , 0x09ec81b2 ] )
+  , Mont::unsafe_make( [ 0xaf60f629 , 0xd96dee0a , 0x2d8d1a06 , 0x6ed6e31c , 0x9255265e , 0x5313ef02 , 0x78deecc5 , 0x0f4d2cb2 ] )
+  , Mont::unsafe_make( [ 0xa8dfd543 , 0xa57714bf , 0x3bc58c04 , 0xdc577f73 , 0x9658916c , 0xeefb79b5 , 0x671d44f5 , 0x18fcc0f2 ] )
+  , Mont::unsafe_make( [ 0xe631d3e3 , 0x810bbda2 , 0x700e560a , 0x4f705dbf , 0x43d91457 , 0x6d149a13 , 0x8ee2204c , 0x2461420b ] )
+  , Mont::unsafe_make( [ 0xac8ca61d , 0xbd8fa1ff , 0x46874a49 , 0xfb1da791 , 0x7d195057 , 0xfe537360 , 0xd16c8b54 , 0x2cda8b99 ] )
+  , Mont::unsafe_make( [ 0x50b23501 , 0x53539171 , 0x86648de5 , 0x0c921d83 , 0xb2be0b71 , 0x894d3870 , 0x8f4e5362 , 0x0b177638 ] )
+  , Mont::unsafe_make( [ 0x10abccf5 , 0x135ec038 , 0xea526406 , 0xe067fa17 , 0xa2898617 , 0x8dd6a950 , 0xa0ec6c04 , 0x1cec4c47 ] )
+  , Mont::unsafe_make( [ 0x6679d500 , 0xe7a81b82 , 0x2a81446f , 0xa5da9c95 , 0xb970a0ef , 0x432b43aa , 0xc9c74b3d , 0x198fdae7 ] )
+  , Mont::unsafe_make( [ 0xa911c370 , 0x67e94daf , 0xfba10f40 , 0x80a4cf52 , 0x4c44c72b , 0x95c14ac2 , 0xc829bc89 , 0x2dc8f1f8 ] )
+  , Mont::unsafe_make( [ 0xba550e14 , 0x0de5c6f9 , 0x55e64d98 , 0x0882f723 , 0x8b91f526 , 0x0ff8af79 , 0xf27ddd49 , 0x1763a811 ] )
+  , Mont::unsafe_make( [ 0x8938cb6b , 0x0dfccf19 , 0x282ae053 , 0xd95ade56 , 0x3e191dbb , 0xe3eb83f5 , 0x1ba535a3 , 0x0356916d ] )
+  , Mont::unsafe_make( [ 0x9d95c8a3 , 0x5ac76711 , 0xd39afbf6 , 0xea12b6d1 , 0x1fa51b94 , 0xe1ff460e , 0x53f6c20c , 0x05482965 ] )
+  , Mont::unsafe_make( [ 0x2021cdb4 , 0xe11bff3b , 0xf7a3442c , 0xea4a2f42 , 0xde23cadf , 0x956dbc68 , 0x75bb12aa , 0x0a423bd1 ] )
+  , Mont::unsafe_make( [ 0x6370d957 , 0xd016003f , 0x34023ce5 , 0x2953c24c , 0xd8f1c82b , 0x2d631a92 , 0xe2a09008 , 0x2c57a298 ] )
+  , Mont::unsafe_make( [ 0xf8e71619 , 0xeafbea96 , 0x7708d018 , 0x985703cd , 0xbb4e4671 , 0xc4be84e8 , 0x980ee978 , 0x103c7a0e ] )
+  , Mont::unsafe_make( [ 0x5ae9465f , 0x0afea519 , 0xc622efeb , 0x58eaee07 , 0xa5b6fd12 , 0xed369b4a , 0x3f3495e3 , 0x0bd96ddf ] )
+  , Mont::unsafe_make( [ 0xff3cbe4f , 0x2455675b , 0x5d05dc32 , 0xf46c8e77 , 0x3579a2c4 , 0xc889e395 , 0x9e06390f , 0x07781d8d ] )
+  , Mont::unsafe_make( [ 0x1776d14f , 0x2eb531bf , 0x94778067 , 0xb601803e , 0x21139d59 , 0x660fc649 , 0xdc75db1f , 0x227d6256 ] )
+  , Mont::unsafe_make( [ 0xb1a6b370 , 0x171cf2aa , 0xce1b9151 , 0xb52b3558 , 0xce908ba6 , 0xdb089480 , 0x3d4d296a , 0x08e3d603 ] )
+  , Mont::unsafe_make( [ 0x54c0a528 , 0x40fb61b7 , 0x9047fc80 , 0xfa444456 , 0x000a422c , 0xd9045f08 , 0x90ad760f , 0x1b72af4c ] )
+  , Mont::unsafe_make( [ 0x9f664c40 , 0x2b11e172 , 0x07f4d8ae , 0xca00ea25 , 0xcccf4be3 , 0x69b0fcbd , 0x659f5bf6 , 0x21eddfb8 ] )
+  , Mont::unsafe_make( [ 0x08165663 , 0xd0a011f1 , 0x74c174cf , 0x44b06536 , 0x3f2ed950 , 0x37be53d9 , 0x077396a2 , 0x2bcc5222 ] )
+  , Mont::unsafe_make( [ 0x7b27368b , 0x90c6b0c3 , 0xbc08c86e , 0x3110821b , 0x8c6f598c , 0xb72cba14 , 0x2b575a44 , 0x11c765c7 ] )
+  , Mont::unsafe_make( [ 0xedb11b18 , 0xd44afb81 , 0x270374ae , 0xdff3b314 , 0x2a34cace , 0x7462d57e , 0xadbd1812 , 0x12a24d89 ] )
+  , Mont::unsafe_make( [ 0xeab1b61a , 0x7d82e068 , 0x526a0f60 , 0xd32d189e , 0x1e18dbbf , 0x19ed0d75 , 0xfc8662e8 , 0x02cf40d1 ] )
+  , Mont::unsafe_make( [ 0x8c8ff04e , 0x52311eff , 0x3d91d9d4 , 0x15d8bdd3 , 0xd3f624c2 , 0x345e8c86 , 0xaa7e0629 , 0x047d9681 ] )
+  , Mont::unsafe_make( [ 0x8adcc796 , 0xd33d54d8 , 0x5e112c15 , 0xb7beee11 , 0x63223b0d , 0xc1d143f9 , 0x29adc017 , 0x1caaf45a ] )
+  , Mont::unsafe_make( [ 0xf69b5939 , 0x4ec72ced , 0x68094320 , 0x622a97be , 0x136f2e67 , 0x2854bea7 , 0xec46cee2 , 0x028308a5 ] )
+  , Mont::unsafe_make( [ 0x47a90933 , 0x12caf64f , 0x74d1be10 , 0x5f9ba027 , 0xb4129b04 , 0xd3993d03 , 0x15b4a195 , 0x2395e8c6 ] )
+  , Mont::unsafe_make( [ 0xffcbdcf1 , 0x0cb06830 , 0xac6f9275 , 0x6ea4ede1 , 0x7eed9201 , 0xc53fa705 , 0xeceac103 , 0x292e8cfe ] )
+  , Mont::unsafe_make( [ 0x17a57ef6 , 0x90ace77d , 0xde47947d , 0x7111a6cc , 0x71aef7fe , 0xc6fdc159 , 0x5863b01e , 0x1cb86984 ] )
+  , Mont::unsafe_make( [ 0x23ea8826 , 0x8aca6fa5 , 0xdc47746e , 0x3e731f82 , 0x4f921670 , 0x966f756d , 0xd80fb885 , 0x0b9dd024 ] )
+  , Mont::unsafe_make( [ 0x69786600 , 0x7204e1ba , 0xd8d71064 , 0x4daaff7f , 0x11e3b001 , 0x77d0ce58 , 0xfdcf62e9 , 0x269c1b15 ] )
+  , Mont::unsafe_make( [ 0xca0b8c47 , 0x0e0275f0 , 0xcd68eb43 , 0x006f60ac , 0x0c4d9ea0 , 0x6d649fc3 , 0xbb185559 , 0x0fd7c380 ] )
+  , Mont::unsafe_make( [ 0xdcdf80b8 , 0x13d7d183 , 0xb074c937 , 0xa0db1738 , 0x2a323e82 , 0x117c0d27 , 0xef6849e2 , 0x0b9cbfa4 ] )
+  , Mont::unsafe_make( [ 0x62077761 , 0xb4a1cc59 , 0x7d6da0af , 0x546982ac , 0x672a19b0 , 0x3b4c699b , 0xa1de6dfb , 0x0b0a7da5 ] )
+  , Mont::unsafe_make( [ 0x92aabff7 , 0xe15a822b , 0x70cb6a87 , 0x79354f8d , 0xb944deb9 , 0x71ecc720 , 0x7c44988a , 0x261601a3 ] )
+  , Mont::unsafe_make( [ 0x3f01d1ec , 0x8f7866db , 0xa05b1c4a , 0x0a428841 , 0xec9dcd37 , 0x6d97d297 , 0xe13f1325 , 0x0a7899a8 ] )
+  , Mont::unsafe_make( [ 0xce9a4278 , 0x04bd6908 , 0x6e1f7622 , 0x3aca5686 , 0x810846eb , 0x07f146a5 , 0x9ce39c51 , 0x0ddeb361 ] )
+  , Mont::unsafe_make( [ 0xf92e3f20 , 0x2ee17e00 , 0xa8204db8 , 0x4563e401 , 0x36e12edf , 0xb472d52d , 0xe9000f55 , 0x2f81a9fb ] )
+  , Mont::unsafe_make( [ 0x280c2902 , 0x59f5221d , 0x26f8ff0a , 0xba64cd1e , 0xb0742c89 , 0x2cf26f3f , 0x6cd15ec1 , 0x016a29ce ] )
+  , Mont::unsafe_make( [ 0xf7709ac9 , 0x016f5552 , 0x3856fe07 , 0x939bb3fc , 0x0575775b , 0x8303766b , 0xb26a1384 , 0x1ce0a1a6 ] )
+  , Mont::unsafe_make( [ 0xa588c642 , 0x8d75ce4e , 0xa621c710 , 0x8d91231a , 0x99ad170a , 0x2e223f42 , 0x68c7ad13 , 0x1173d391 ] )
+  , Mont::unsafe_make( [ 0x650c7e20 , 0x7763f34c , 0x8caa3565 , 0x186a709b , 0xfd369158 , 0x325ac820 , 0x98975ae7 , 0x1275b26c ] )
+  , Mont::unsafe_make( [ 0x8fb33ef2 , 0x0d39ee9e , 0x5733913d , 0x21edbc45 , 0xff6d9934 , 0x37d57f32 , 0xaa16b8ac , 0x279eb5f8 ] )
+  , Mont::unsafe_make( [ 0xa3bb6dcb , 0x766a28c8 , 0x519a4652 , 0xc29bbe36 , 0xf6d0466b , 0x95443205 , 0x6d776698 , 0x1e3c1e9d ] )
+  , Mont::unsafe_make( [ 0xe95aa96a , 0x8c74108f , 0x019be49f , 0x0dd5ee33 , 0x6d4e8853 , 0x9370cf29 , 0xc70efae8 , 0x291e8f1e ] )
+  , Mont::unsafe_make( [ 0x0fabb6b1 , 0xc3c76673 , 0xeb32a99f , 0xcbc62d28 , 0x0c83c535 , 0x5475a9e8 , 0x3d0942e9 , 0x27272ae4 ] )
+  , Mont::unsafe_make( [ 0xec802079 , 0xc4bf5f6d , 0xcd565bbb , 0x4dc10937 , 0x387634d7 , 0x9b751bec , 0xb63ff89c , 0x2e33dea7 ] )
+  , Mont::unsafe_make( [ 0x8f4165e0 , 0x71962bcc , 0x399d2351 , 0x89577255 , 0xae7d6645 , 0xaabb4acd , 0x117274b9 , 0x0bc5faaa ] )
+  , Mont::unsafe_make( [ 0xb837eccb , 0xcff76d6f , 0x39890b4a , 0x2f49d06a , 0xb20cc49e , 0x7b43da85 , 0xf89daea1 , 0x22b232b9 ] )
+  , Mont::unsafe_make( [ 0x8abb59a9 , 0x24d1e74c , 0x622381a3 , 0xbc58cae1 , 0x9e2aa1d2 , 0x9020d0e6 , 0x13ecb32a , 0x294a7f95 ] )
+  , Mont::unsafe_make( [ 0x91d02ed0 , 0xa2a58616 , 0xc15ee9d7 , 0xcb8c2a27 , 0x0d75afa9 , 0xe2e1ab9e , 0xf43c7317 , 0x01e15aa5 ] )
+  , Mont::unsafe_make( [ 0x1f9edd3d , 0xdd62d24f , 0xedca9323 , 0x66293378 , 0xe4d9ac01 , 0xb22c2e66 , 0x116efc14 , 0x15521996 ] )
+  , Mont::unsafe_make( [ 0x64e73ccc , 0x3effe7de , 0x07b20fab , 0x3ef1f8ff , 0xa915e25f , 0x4ba1dfa2 , 0x7fdf77f6 , 0x27751781 ] )
+  , Mont::unsafe_make( [ 0x890ed0d0 , 0xe1aec72e , 0x6caf605e , 0x81b2368a , 0xe08b5e34 , 0x15962ba1 , 0x21a45600 , 0x0bb63737 ] )
+  , Mont::unsafe_make( [ 0x2103917a , 0x63b568f0 , 0x91b0e13f , 0xc556634e , 0xc5823970 , 0x921e4bf5 , 0xbf7ef8bb , 0x1e12f562 ] )
+  , Mont::unsafe_make( [ 0xc906b20d , 0x2f09e489 , 0x8bfc8256 , 0x8a23f5e0 , 0x9826d604 , 0xe11061bd , 0x4a9c7797 , 0x2a64e77c ] )
+  , Mont::unsafe_make( [ 0x7a5a7c8b , 0x89b689f1 , 0x6ff94f5f , 0x30fa8063 , 0xacab2a03 , 0xec7da014 , 0x023ab64c , 0x11e21af5 ] )
+  , Mont::unsafe_make( [ 0x7d496dfd , 0xbc28e9db , 0x3c7f3387 , 0x4774e6be , 0x0381781d , 0x7891717f , 0x6f4f63df , 0x2ab9654f ] )
+  , Mont::unsafe_make( [ 0xedd21fab , 0x459ac5fa , 0x2098eecb , 0x968160f6 , 0x79ee2b23 , 0x425b2020 , 0xe2056ca0 , 0x120d4358 ] )
+  , Mont::unsafe_make( [ 0xc0f74dcb , 0xf3c4403b , 0xc92593e3 , 0x84c43b8d , 0x44dc8f4b , 0x66dc4a1e , 0x5875cd93 , 0x0ee2dd05 ] )
+  , Mont::unsafe_make( [ 0xdd2ccda9 , 0x66b53874 , 0x2edcafa9 , 0xc62c40c5 , 0x63fa884f , 0x178610a6 , 0x71506c59 , 0x109803fc ] )
+  , Mont::unsafe_make( [ 0x904766d9 , 0x8bca51ff , 0xac6bec94 , 0xc1876139 , 0xbad7531b , 0x01382cf5 , 0xd2a7841b , 0x1a5d89ae ] )
+  , Mont::unsafe_make( [ 0x523b2f02 , 0x939636ba , 0x458090fa , 0x0c4fd843 , 0x7843e313 , 0x1f9874b6 , 0x4d0b8286 , 0x0ed08d34 ] )
+  , Mont::unsafe_make( [ 0x62792b4f , 0x4768113f , 0x30f70190 , 0xc098479b , 0xd5e1cae4 , 0x785ace50 , 0x6d754c58 , 0x018847a6 ] )
+  , Mont::unsafe_make( [ 0x48e46d9f , 0x9485c5ec , 0x39101d9b , 0xe9330d05 , 0x53a23e35 , 0x3a99defd , 0x07a36696 , 0x0232f943 ] )
+  , Mont::unsafe_make( [ 0xdf3f55d5 , 0x648cb666 , 0x396eec4a , 0x773d6784 , 0xcaf2bc43 , 0xd912b707 , 0x2b63a0cd , 0x0a4a5d3c ] )
+  , Mont::unsafe_make( [ 0x8930b42a , 0x883cb093 , 0x9d29ba3b , 0xf8e19821 , 0x6ee9a8cf , 0x886de4e5 , 0xfc4f7d2a , 0x28a4d723 ] )
+  , Mont::unsafe_make( [ 0xe7793ad4 , 0xc810aae6 , 0x8a8ff4ce , 0xb7b5647d , 0x9d5241a5 , 0x792ddbc2 , 0x9071be19 , 0x1148951c ] )
+  , Mont::unsafe_make( [ 0x036b508d , 0x99ae7334 , 0x1f6e1fd2 , 0x73d94beb , 0x73290a23 , 0x3b7317b0 , 0x9107e938 , 0x12bfd4b1 ] )
+  , Mont::unsafe_make( [ 0xc2181a22 , 0xee4de60b , 0x817c83f8 , 0xf80b8093 , 0xd7014213 , 0x392378f9 , 0x722023e9 , 0x0a329086 ] )
+  , Mont::unsafe_make( [ 0x3589ad2e , 0x1f803279 , 0xb58ea29b , 0x56d614cf , 0xc5071457 , 0xb4f637a8 , 0xaf463520 , 0x24818fce ] )
+  , Mont::unsafe_make( [ 0x3c487eea , 0x86690910 , 0xb64b5bf7 , 0x528d5787 , 0xfdac5239 , 0x1beae94a , 0x51af0f25 , 0x2269729e ] )
+  , Mont::unsafe_make( [ 0xb37c6667 , 0x36beac63 , 0x179d7e14 , 0x67284332 , 0x12451177 , 0xca686148 , 0x7c5b5596 , 0x04e1d383 ] )
+  , Mont::unsafe_make( [ 0x671cb9ad , 0x0a1c3388 , 0x5cfa2b59 , 0xcaf5bc4a , 0x695f715f , 0x66aef910 , 0xa1340961 , 0x1579d7d1 ] )
+  , Mont::unsafe_make( [ 0x1899a1a7 , 0xd9277c93 , 0x06584cb7 , 0xd0d6ca8a , 0xddb50272 , 0x38244e10 , 0x08a0d518 , 0x2d8eca05 ] )
+  , Mont::unsafe_make( [ 0x37a273ad , 0x6a7c399f , 0xbcd26c4c , 0x4fd1380c , 0x264adbce , 0xa99de22c , 0x16f89335 , 0x15a6d5f4 ] )
+  , Mont::unsafe_make( [ 0xda4e8d0b , 0x2882ac29 , 0xce9436c2 , 0x5f877d8a , 0xa3bf0587 , 0x7b7493e8 , 0xe4a8226a , 0x033f7b38 ] )
+  , Mont::unsafe_make( [ 0xde1a554f , 0x67d3c055 , 0x8c711975 , 0xeb526232 , 0x1f12c12d , 0x1ff9bc85 , 0x7c595e2e , 0x2cdf0ced ] )
+  , Mont::unsafe_make( [ 0xf29fcd3f , 0x3bb52236 , 0xc5fd6e6c , 0xbc8170e7 , 0x1fa4b7dc , 0xb350f72e , 0x9b81421c , 0x163e5a98 ] )
+  , Mont::unsafe_make( [ 0x70d16194 , 0x2de1ffb0 , 0xc6848cfb , 0x022df91b , 0xd48852cd , 0xa076c218 , 0x3ca16aec , 0x1ea90028 ] )
+  , Mont::unsafe_make( [ 0x59d44e2d , 0xd1d43091 , 0x1621a984 , 0x506c6d5d , 0xedbc479e , 0xa2e56885 , 0x858652ff , 0x088e145a ] )
+  , Mont::unsafe_make( [ 0x59330c45 , 0x40716f50 , 0xbd34b5af , 0x72179793 , 0x0d77046d , 0x5c9c99d3 , 0x6b7a87c6 , 0x28cab658 ] )
+  , Mont::unsafe_make( [ 0x0b331ed5 , 0x97b301ab , 0x452abff9 , 0x27038aba , 0xf84ea3bb , 0xb51204b6 , 0x04d6f26d , 0x24a23e83 ] )
+  , Mont::unsafe_make( [ 0x1fb56041 , 0xb72285ac , 0x902916d1 , 0x09582005 , 0x02b6e2fb , 0xbd2033cc , 0x98cd72fd , 0x0e20017c ] )
+  , Mont::unsafe_make( [ 0xcb25128d , 0x129cbf81 , 0x345e2830 , 0x7529915e , 0xb967b02b , 0xfd748f8c , 0x637ddd4b , 0x05564884 ] )
+  , Mont::unsafe_make( [ 0x38dd248d , 0x8466eb25 , 0xf9988950 , 0xf450e40f , 0x818a7b27 , 0xea6bdbf7 , 0x3949a0da , 0x1df3585c ] )
+  , Mont::unsafe_make( [ 0x5457d56c , 0x0bd0626a , 0xefd9e6f1 , 0x578f6ee1 , 0x6f6a8582 , 0x5a7043a3 , 0x66f035b2 , 0x06e28596 ] )
+  , Mont::unsafe_make( [ 0x5d76284a , 0x8df0304f , 0xda875c01 , 0xac08a930 , 0x0ad5177f , 0x59468fe9 , 0x769baad0 , 0x0052ebfa ] )
+  , Mont::unsafe_make( [ 0x1612e03e , 0x5d2cbebc , 0x1ca1f189 , 0xe34e8ecd , 0x8808fdbe , 0x76d369ec , 0x4ef94bad , 0x0eda27df ] )
+  , Mont::unsafe_make( [ 0xbc8e9cd8 , 0xf2b176fd , 0xca5f2803 , 0x5f66c202 , 0xcbc61fab , 0xf57bf55e , 0xd8e0ea79 , 0x0277deb7 ] )
+  , Mont::unsafe_make( [ 0x861dba4a , 0xc5c84151 , 0x1c29ee57 , 0x865c41df , 0xc30ea206 , 0x5d2cd698 , 0x37fe7fdd , 0x1a4cdec1 ] )
+  , Mont::unsafe_make( [ 0x62f93644 , 0x03cc1d57 , 0x376010d9 , 0x6d18adba , 0x0997720b , 0xfa17707b , 0xc15aa756 , 0x035cabd5 ] )
+  , Mont::unsafe_make( [ 0xb0727b8e , 0xaefbf4c8 , 0xc30878f5 , 0x39d3fff1 , 0xbc3a83a0 , 0x41b45ce7 , 0xe027ac82 , 0x101f0e9c ] )
+  , Mont::unsafe_make( [ 0x02ff4ade , 0xe2464f34 , 0x24d2103c , 0x1390d8d9 , 0x41fa60db , 0x9d1d233c , 0xa3174510 , 0x0391b386 ] )
+  , Mont::unsafe_make( [ 0x370c6777 , 0x362bba3e , 0x4a5e7b5a , 0x6c70226e , 0xb88e5aa4 , 0x8c9d10c1 , 0x00c89474 , 0x274d7f1d ] )
+  , Mont::unsafe_make( [ 0xfc606800 , 0x0ad2e6dc , 0xd67ab746 , 0x0c82ba1f , 0xc9045d35 , 0xa05d6330 , 0x181e5c7a , 0x0ceb81a0 ] )
+  , Mont::unsafe_make( [ 0x298e70ab , 0xeb3f9c09 , 0xfcd4d740 , 0xa7efdbf0 , 0x596a1fce , 0x992d1fd1 , 0x7c6444f4 , 0x29c9f2e5 ] )
+  , Mont::unsafe_make( [ 0x6969d09e , 0x0eac9235 , 0x5838c2e0 , 0xefc73688 , 0x9535484c , 0x4fca432a , 0x3874f8f0 , 0x04addc83 ] )
+  , Mont::unsafe_make( [ 0x4d172132 , 0xe907f3bc , 0xf5277772 , 0x4b4f6d31 , 0x58ca3b8b , 0x07bfa029 , 0xd2f20211 , 0x28e2018a ] )
+  , Mont::unsafe_make( [ 0xe59b0a8e , 0x689a6f18 , 0xe8875af8 , 0xfa5108cb , 0x06052405 , 0xbefedc87 , 0x7af00193 , 0x13f2decd ] )
+  , Mont::unsafe_make( [ 0xf752a9a3 , 0x5f402698 , 0x3cc0c89a , 0x6bff94ab , 0x81cbe7f6 , 0xbcda961d , 0x73a4c544 , 0x18aee205 ] )
+  , Mont::unsafe_make( [ 0x326d055c , 0xa3a604f9 , 0x66995d79 , 0xa0c8acaa , 0xe87b2a45 , 0x13a4b056 , 0x9a0e99ee , 0x23ef0e3b ] )
+  , Mont::unsafe_make( [ 0xad997e9c , 0x679d8028 , 0x9870f120 , 0x208b96fb , 0xe260989d , 0xfd934aeb , 0x940f1d92 , 0x155d0da2 ] )
+  , Mont::unsafe_make( [ 0x0c64842d , 0xa8d0aad7 , 0x896c2467 , 0x788d637d , 0xe17bd025 , 0x20ef06ed , 0x4716d702 , 0x219dd698 ] )
+  , Mont::unsafe_make( [ 0x517ff2a5 , 0x27c62059 , 0x99df4396 , 0x8e810d73 , 0x43dbd507 , 0x4dee6c2e , 0x80d98004 , 0x12cbef0c ] )
+  , Mont::unsafe_make( [ 0xeae8a6e2 , 0xbf63a241 , 0x5ee5bfc6 , 0x967c3409 , 0x5a6e9e0d , 0x7edd635c , 0xfd7d82e1 , 0x2fce5c40 ] )
+  , Mont::unsafe_make( [ 0x16bf93cc , 0x5e6f6252 , 0x840784b1 , 0x89734be0 , 0x29189819 , 0x9b6d0ebb , 0xa10ac23e , 0x1f9d800c ] )
+  , Mont::unsafe_make( [ 0x30476d9a , 0xd5b6b4dc , 0x76e54266 , 0x484154fc , 0x07c75e6b , 0x62371e8a , 0x1e903dc2 , 0x1aac9f2e ] )
+  , Mont::unsafe_make( [ 0x56367d75 , 0xcc9c48df , 0xec41e200 , 0x4f128c4d , 0xe4b64aa2 , 0xc063fabd , 0x2ef72910 , 0x23830bac ] )
+  , Mont::unsafe_make( [ 0x7aa9199d , 0x65104f3c , 0x21d1365d , 0x985a459f , 0x144150e0 , 0xcf886a95 , 0x19fc85ab , 0x0dbc9467 ] )
+  , Mont::unsafe_make( [ 0xc4b7ceff , 0x39c2a4b4 , 0x9d929a77 , 0xabfc14b3 , 0xa07f99f9 , 0x5d6b1c2d , 0x0e2768cd , 0x0929e8eb ] )
+  , Mont::unsafe_make( [ 0xfe0eab32 , 0x9de887d6 , 0x7bfe8bc1 , 0x011f9db6 , 0xdaf3e7e3 , 0xc3e36eef , 0x0a8fc6ef , 0x25e48acf ] )
+  , Mont::unsafe_make( [ 0x927c2846 , 0x6c89d5af , 0x6207b2ff , 0x08c98dba , 0x1bb65f8a , 0x7c5740e7 , 0xe19d6a65 , 0x07a80498 ] )
+  , Mont::unsafe_make( [ 0xb18eb97f , 0xe1a6ec61 , 0x6ffa7c5f , 0x3d1c54f3 , 0x4006fc31 , 0x4e39e182 , 0x01efdbfb , 0x2e6f97d0 ] )
+  , Mont::unsafe_make( [ 0xf8d6f80f , 0x2c8a325f , 0xd67eaa5b , 0xd13d6fc6 , 0x791d69bd , 0x6b54b5a8 , 0x6db41412 , 0x09d7289e ] )
+  , Mont::unsafe_make( [ 0x169a6d80 , 0xac6f23d1 , 0x23922381 , 0x4eac5101 , 0x3b890c48 , 0x0d2f8f91 , 0x062e77f3 , 0x277dd4c9 ] )
+  , Mont::unsafe_make( [ 0xe9dd5d6f , 0x93cac05b , 0x12354b6a , 0xc5fc063f , 0xdd26dc88 , 0x8738f0a3 , 0xf57f1f7e , 0x12e15767 ] )
+  , Mont::unsafe_make( [ 0xca1a8585 , 0x8acf132a , 0xa6d73add , 0xb07e45ee , 0xfc0a5e71 , 0x6f2a8633 , 0x1e43a87a , 0x1ae5576d ] )
+  , Mont::unsafe_make( [ 0xccfd4341 , 0x4520e697 , 0xe4c53884 , 0x5576a465 , 0xdc4e6f20 , 0x2b2c3860 , 0x3ea974b3 , 0x14a86d1d ] )
+  , Mont::unsafe_make( [ 0xbae0808d , 0x9e732ff0 , 0xd54ac97a , 0x4586b4fa , 0xb833fd85 , 0xd7a5f0d2 , 0x9effa167 , 0x19d273d4 ] )
+  , Mont::unsafe_make( [ 0x42fa0467 , 0x3e68e7b0 , 0x1d2cbc09 , 0xc9de3402 , 0x512c0870 , 0x042e593b , 0x458275da , 0x1a16d70f ] )
+  , Mont::unsafe_make( [ 0x32362015 , 0x47f0ff17 , 0x0724d997 , 0xd24f8016 , 0xcb9b6245 , 0xa4dc1ce0 , 0x1e6dbb54 , 0x298b9df4 ] )
+  , Mont::unsafe_make( [ 0x03db2313 , 0x94735675 , 0x120ee5ce , 0x70ccc162 , 0x04f9106b , 0x30b63bf2 , 0xe4decb94 , 0x253f4b94 ] )
+  , Mont::unsafe_make( [ 0xcbc002b9 , 0xec26fe56 , 0x585b837a , 0x57218ffc , 0xd310fd9a , 0x0be02a5f , 0xf48a2e66 , 0x200f2811 ] )
+  , Mont::unsafe_make( [ 0x5dd4f42e , 0x94ee21b6 , 0xcb5b2a52 , 0xce116945 , 0x23b8489d , 0x99118843 , 0xcdfbfcf4 , 0x2d5e65e4 ] )
+  , Mont::unsafe_make( [ 0x65a7a3cd , 0x8b412100 , 0xe99a48c4 , 0x479e713b , 0x109e5f94 , 0x7e6362a4 , 0x0cee16fa , 0x2f25764e ] )
+  , Mont::unsafe_make( [ 0xf4bccade , 0xa89fc06d , 0x8a28d606 , 0xd79f9a41 , 0xbc3057ff , 0xd91834cf , 0xb159641d , 0x131e2cb4 ] )
+  , Mont::unsafe_make( [ 0x8e42cc2c , 0x777085b7 , 0xdb079df7 , 0xdbf77bc6 , 0xe5ad3d9b , 0x8a14f1e3 , 0xdef24be3 , 0x14550bce ] )
+  , Mont::unsafe_make( [ 0xa95708db , 0x52367a4a , 0x84359b09 , 0x6c45dce3 , 0x940ae3b6 , 0xd1951041 , 0xcc898ffb , 0x23c52ac9 ] )
+  , Mont::unsafe_make( [ 0xce173b68 , 0x41e9dc63 , 0x743aa604 , 0xb028b082 , 0xfef0b9cf , 0x4236d65d , 0x62e6951b , 0x077ba5f3 ] )
+  , Mont::unsafe_make( [ 0x9d43ee37 , 0x9bf26b5b , 0x786c9571 , 0x6678c7cb , 0xb1e4ccdd , 0xc99995f9 , 0xd992f4ec , 0x036ea2ae ] )
+  , Mont::unsafe_make( [ 0xe6cd1553 , 0x78fab6ab , 0x0aa64495 , 0x6b41c94a , 0x7af31bd0 , 0xa771bba9 , 0xc760b8a8 , 0x2edbeca8 ] )
+  , Mont::unsafe_make( [ 0x5ffdda42 , 0xf3ca9701 , 0xe58f9e6a , 0x42de9830 , 0xed267495 , 0x9975211b , 0xf1721b12 , 0x1794ef05 ] )
+  , Mont::unsafe_make( [ 0x588d42eb , 0x58043966 , 0xeb808a0a , 0x7bca1bda , 0xc337ac5e , 0x2061ec94 , 0x10d663bc , 0x1392ec9c ] )
+  , Mont::unsafe_make( [ 0xc6189f89 , 0xecb022a5 , 0x235f8190 , 0xe7d501ec , 0xeb147990 , 0x2c7359a7 , 0x7912fe2f , 0x0de4d1ef ] )
+  , Mont::unsafe_make( [ 0x5b0f0803 , 0x58da2d74 , 0x172d0b4f , 0x5831af6d , 0x72f21aee , 0x4c82b7af , 0x3f0442b7 , 0x1b30cc1d ] )
+  , Mont::unsafe_make( [ 0x230cd2ac , 0x5f3319a7 , 0x447f1f6f , 0x2afeca5a , 0x91c9e4fa , 0x2ee8bc91 , 0xffa484fe , 0x073281ce ] )
+  , Mont::unsafe_make( [ 0xc60ab522 , 0x60f24860 , 0x4de4e742 , 0x8176b8cf , 0xc0da308b , 0xa2e6e5e8 , 0x8585c1a7 , 0x239f1961 ] )
+  , Mont::unsafe_make( [ 0x055a3916 , 0xbb8e655c , 0x953e6ed4 , 0x9997102b , 0x706ad184 , 0xbddd86e3 , 0x26bec00c , 0x05ad3468 ] )
+  , Mont::unsafe_make( [ 0xee975192 , 0x05fe6c40 , 0x80492263 , 0x23f17a82 , 0x9e79614d , 0xccc8e10c , 0x887c5921 , 0x085b62dd ] )
+  , Mont::unsafe_make( [ 0x6f4636ae , 0xd4f91ea9 , 0x76649ef4 , 0x3ebf7372 , 0xd2e73c6a , 0x6cb741f5 , 0x8a916b47 , 0x0e8c183b ] )
+  , Mont::unsafe_make( [ 0xc7a21d01 , 0x4677f940 , 0xefcde742 , 0x76892789 , 0x17ed8445 , 0x5821cd49 , 0xd8b3f817 , 0x044cb796 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// external mixing matrix (`t x t`, flattened)
+pub const CONST_M: [Mont; 225] = 
+  [ Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x7b9c844f , 0xa5545205 , 0xe5bb8f4c , 0x91cfde0f , 0xc232826b , 0x83391373 , 0x28e9ee02 , 0x17286264 ] )
+  , Mont::unsafe_make( [ 0x1eca75ce , 0x89d16012 , 0x67c717f2 , 0xd82aef03 , 0x07700aa7 , 0x62f43c87 , 0xb531d675 , 0x0f391e65 ] )
+  , Mont::unsafe_make( [ 0x8fdf6f74 , 0x3593eb92 , 0xcccee151 , 0xf51416b1 , 0xe2b5a809 , 0xf2736540 , 0x3d000c23 , 0x068ea1e2 ] )
+  , Mont::unsafe_make( [ 0x4e90a7dd , 0x04173c50 , 0xa2e8882c , 0x7623435f , 0xc74165cd , 0x64727ecc , 0x3eddf567 , 0x26432d2f ] )
+  , Mont::unsafe_make( [ 0xf04d0d9f , 0x0df5028b , 0xe093406c , 0xd7da9608 , 0x8fbfcbed , 0x47071f7a , 0xe022242b , 0x296916e9 ] )
+  , Mont::unsafe_make( [ 0x73b03c92 , 0x130c6e98 , 0xb13a37c8 , 0x3cb33f16 , 0xd8e47f37 , 0x05a9e461 , 0x2904e690 , 0x2b591c8d ] )
+  , Mont::unsafe_make( [ 0x17db3754 , 0xa78dfdb5 , 0xde35bbb1 , 0x5f4e0a57 , 0xddac926e , 0xc458bc74 , 0x3e6e38b3 , 0x19feee7e ] )
+  , Mont::unsafe_make( [ 0xf94ae02d , 0xd3e7ad4e , 0xf0bb5ffb , 0x076e7d33 , 0x20327346 , 0x063784e5 , 0x3bf84263 , 0x23466f13 ] )
+  , Mont::unsafe_make( [ 0x2bcddd11 , 0x2a3f62bb , 0x48e56e7d , 0xb07be022 , 0x764d5120 , 0x81b782f1 , 0xc9ca2d60 , 0x04f02418 ] )
+  , Mont::unsafe_make( [ 0x0e2a2d36 , 0x9fbde051 , 0x14e5c45f , 0x5bc04519 , 0xa91c8743 , 0xc5cd2be7 , 0x06a6c6af , 0x137af6b5 ] )
+  , Mont::unsafe_make( [ 0x693d2055 , 0x1f0fbe2c , 0xd4d77dce , 0x23612cb7 , 0xd778c249 , 0x4f381e2e , 0x2e36bd15 , 0x18150163 ] )
+  , Mont::unsafe_make( [ 0x6251efac , 0xa79de2cc , 0x18d2d02e , 0x20f5f20b , 0x0aeaf007 , 0x70da1dff , 0x08af89ef , 0x1d08e116 ] )
+  , Mont::unsafe_make( [ 0x2af5af8e , 0xd2114008 , 0x036e131a , 0x8f4de5ba , 0x7ed83675 , 0xfab7cb85 , 0xb22b29e7 , 0x299b31a7 ] )
+  , Mont::unsafe_make( [ 0x2619719e , 0xd6a7897b , 0x093b1a17 , 0x09b26009 , 0xabf40d2a , 0xc6f0a06a , 0x015a8d2e , 0x0cd254bb ] )
+  , Mont::unsafe_make( [ 0xe68c6683 , 0xb27f1e10 , 0xe23087ff , 0x26201202 , 0x4b8978c8 , 0x3cf9ab92 , 0xab47dec8 , 0x03841eb9 ] )
+  , Mont::unsafe_make( [ 0xd5c76af1 , 0x050e865b , 0x3ec3bf57 , 0x3d74fa69 , 0x57ef824c , 0x31db793a , 0x741cd5cf , 0x2b2d5fc6 ] )
+  , Mont::unsafe_make( [ 0x9650d0ed , 0xf7a5a730 , 0x5174277a , 0x5156a1ea , 0x838a9629 , 0x39c910df , 0xceaaf847 , 0x0c137a98 ] )
+  , Mont::unsafe_make( [ 0x56a818fe , 0x128bdf30 , 0x000b5afd , 0x739caf82 , 0xf9010c7f , 0x3cf7bf7d , 0xa26b0e46 , 0x25b0b486 ] )
+  , Mont::unsafe_make( [ 0x296868b0 , 0x73c7fc75 , 0xd5ff83f0 , 0xc38dd093 , 0xd8b88a5e , 0xaffa3d03 , 0x64cf98ef , 0x2dd1251c ] )
+  , Mont::unsafe_make( [ 0xaf05bdb7 , 0xf497133f , 0xf601c653 , 0xf6e727fc , 0x5dca46f5 , 0x26abbf07 , 0x3d676dad , 0x1b0e0092 ] )
+  , Mont::unsafe_make( [ 0x603391b0 , 0x453852d1 , 0xe005293e , 0x068492b9 , 0xe2899a15 , 0x1fe30bb1 , 0x5a948feb , 0x18d9918f ] )
+  , Mont::unsafe_make( [ 0x65111465 , 0xe445b6c2 , 0x47123b4a , 0x71a2041c , 0xe6acf591 , 0xacceddb9 , 0x8a117fe9 , 0x27d2a02a ] )
+  , Mont::unsafe_make( [ 0x8b734684 , 0xfcde105a , 0xc7dca197 , 0x62df9c21 , 0x82031ac3 , 0x508cd375 , 0x90f1ee32 , 0x085d7c9a ] )
+  , Mont::unsafe_make( [ 0xc62cc260 , 0x7d33fe99 , 0x33e8e9dd , 0xb7cc4492 , 0x85a88228 , 0x7cf06671 , 0x8326056c , 0x27d4e9e8 ] )
+  , Mont::unsafe_make( [ 0xcc2e5a14 , 0x4737bf9a , 0xdd7ea521 , 0xf0e2b4d5 , 0xd9512483 , 0x683aac36 , 0x53f1841c , 0x1690287a ] )
+  , Mont::unsafe_make( [ 0xfe482d28 , 0x116201c9 , 0x47c8a703 , 0x36f6ce6f , 0xda34b50a , 0x6446e2f7 , 0xb87d72f4 , 0x0f5d6a08 ] )
+  , Mont::unsafe_make( [ 0xcd1e659c , 0x23597450 , 0xc2015ba0 , 0xaced407f , 0x5c4f22ff , 0xc98bba30 , 0xbbd1af67 , 0x219b8deb ] )
+  , Mont::unsafe_make( [ 0x17787e9f , 0x09cd4853 , 0x0b7ff471 , 0x0fd419d0 , 0x2c4997e8 , 0x90cc8114 , 0x740529eb , 0x083b91fb ] )
+  , Mont::unsafe_make( [ 0x3ad9f773 , 0x3441c7c1 , 0x1e1366ab , 0x112a7dde , 0x6119f2a3 , 0x42f05189 , 0xcc6e0e86 , 0x08f21a23 ] )
+  , Mont::unsafe_make( [ 0x6623373f , 0x1c275d93 , 0x31c6d939 , 0x7a482bcc , 0x719c3e5c , 0xc5e34823 , 0xa520bf16 , 0x13788a3d ] )
+  , Mont::unsafe_make( [ 0xfbd6d7b3 , 0x70b80d6b , 0xff7b3434 , 0xd898c541 , 0x83d4aba4 , 0x226ab9a2 , 0xf1c82c4f , 0x01cefa62 ] )
+  , Mont::unsafe_make( [ 0x2b7ce2d4 , 0x78db7bc6 , 0x298c2594 , 0xc695da11 , 0x84384e78 , 0x9fe09d2b , 0x85f96a7a , 0x202157af ] )
+  , Mont::unsafe_make( [ 0x14fbafd0 , 0xaaa5bbf7 , 0x2d81051e , 0x4876b187 , 0x48cfb557 , 0x40fbbac8 , 0xd5f75e48 , 0x088a7820 ] )
+  , Mont::unsafe_make( [ 0xa06ab1f7 , 0xc051ddf4 , 0x52b7862f , 0x0a1eae83 , 0xfd5bd2f4 , 0x7987de5c , 0x65531c95 , 0x14899e2a ] )
+  , Mont::unsafe_make( [ 0xf79e1311 , 0xdba07ec0 , 0x2013b72d , 0x90b4ca93 , 0x2180efa3 , 0x8a62f366 , 0x48f364df , 0x21854823 ] )
+  , Mont::unsafe_make( [ 0x5e15f3e8 , 0x79649f99 , 0x27289650 , 0xee118a51 , 0x60e25856 , 0x23aaf3e9 , 0xbb4a0aa7 , 0x1b7350d8 ] )
+  , Mont::unsafe_make( [ 0x01709cbb , 0x315baba0 , 0x5571b34e , 0x44a94f0a , 0xba05e7d3 , 0x77543553 , 0xdd4aff90 , 0x0e29361f ] )
+  , Mont::unsafe_make( [ 0x04601275 , 0xce93157c , 0x6794c1e6 , 0xb5343d9f , 0x5ad8635b , 0x831dc67e , 0xb8c63e32 , 0x05fe48f6 ] )
+  , Mont::unsafe_make( [ 0x8e61ebdc , 0x73cf4dee , 0x82dded96 , 0x3b78fdb5 , 0x0d965d63 , 0x3b5e3fc9 , 0x72bb69af , 0x143a550c ] )
+  , Mont::unsafe_make( [ 0x3d0893e7 , 0x542717e0 , 0x2da6532f , 0x8bd3d276 , 0xbb72db67 , 0xeeacf74b , 0xafc58948 , 0x2edb92fd ] )
+  , Mont::unsafe_make( [ 0x0ba863c5 , 0x222ba7d2 , 0xe6f33f40 , 0x4b26356a , 0x5921c870 , 0x864d6f41 , 0x2f846df4 , 0x304fb7d0 ] )
+  , Mont::unsafe_make( [ 0x85220cef , 0xb2a4df12 , 0xde2c0325 , 0x370efc36 , 0x0e048533 , 0x9b652c65 , 0xb3473217 , 0x016b5533 ] )
+  , Mont::unsafe_make( [ 0x1eea4676 , 0x2e784a2c , 0xaface1ec , 0xebcc879a , 0x8bdb23dd , 0x5951d0a4 , 0x1ce1d7d0 , 0x1ffe1b97 ] )
+  , Mont::unsafe_make( [ 0xca1d2621 , 0x7e6d17d0 , 0xf7d06d74 , 0x9f9fd91e , 0xfe9f96e5 , 0x9473cae0 , 0x0d19b903 , 0x22b08e13 ] )
+  , Mont::unsafe_make( [ 0x42c54a96 , 0x380cf827 , 0xea4ca263 , 0xdcf77ec2 , 0x72397554 , 0xb50e636e , 0x03419ed8 , 0x301bb734 ] )
+  , Mont::unsafe_make( [ 0xd278de3a , 0x49aa65fb , 0xbef73278 , 0x3cad99ba , 0xa475f083 , 0x2decc400 , 0x77f0bf09 , 0x09fc3cb9 ] )
+  , Mont::unsafe_make( [ 0xd9753c7c , 0xc9219a9c , 0x2f00a43e , 0xb4268ffa , 0x125f79d7 , 0x63b42eae , 0x62220b8d , 0x294a6110 ] )
+  , Mont::unsafe_make( [ 0x1daff6dc , 0x7d6f1f58 , 0x223ead07 , 0x1320599b , 0xb00bb144 , 0xd1a338f9 , 0x688b2a6f , 0x1ca9d8bd ] )
+  , Mont::unsafe_make( [ 0x88a8fa4b , 0x89c3910c , 0x2fe2c20d , 0x3b6a3c88 , 0xe5bcf506 , 0x1198c38c , 0xcf3d3cc2 , 0x2c16034c ] )
+  , Mont::unsafe_make( [ 0x7ebb8c96 , 0x0a85f5af , 0xc691b9f9 , 0x4908d257 , 0x6f2d2d60 , 0xa0d3656a , 0xd2922336 , 0x06fe0948 ] )
+  , Mont::unsafe_make( [ 0x53e12526 , 0x42bf66c4 , 0x8bf443d2 , 0x539d185c , 0x8ab6805a , 0xc332f3e0 , 0xd8072b2b , 0x2e5296e4 ] )
+  , Mont::unsafe_make( [ 0xf7464a81 , 0x2c250996 , 0xdf15ff3d , 0x4df64b11 , 0x46c15459 , 0xb3c4383d , 0x56916605 , 0x09772574 ] )
+  , Mont::unsafe_make( [ 0xea88eeef , 0xbbbfa464 , 0xb5dc6839 , 0x6ae292ea , 0x862bd0ec , 0xfd31ce7b , 0x85da786b , 0x1cd18839 ] )
+  , Mont::unsafe_make( [ 0x55ce54a7 , 0x542f59eb , 0x3ac98215 , 0x65c0d820 , 0xbf5a4c33 , 0x0d3a6265 , 0xe5be22e1 , 0x107aa08c ] )
+  , Mont::unsafe_make( [ 0x84ec2644 , 0x163ac59c , 0xd985b877 , 0x453656d0 , 0xe47464fd , 0x2babb3eb , 0xc3705145 , 0x0651fae7 ] )
+  , Mont::unsafe_make( [ 0x10e57414 , 0xda07efd8 , 0x5ae7407e , 0x4792a348 , 0x850c703b , 0x3844b2bf , 0x5eb56b1e , 0x0e22f6d7 ] )
+  , Mont::unsafe_make( [ 0xdf1a9d37 , 0x02e55d80 , 0x9c4a84fc , 0x36cdb854 , 0xf66d47d1 , 0xf6ea3a51 , 0xefacf5fe , 0x29b92168 ] )
+  , Mont::unsafe_make( [ 0x2e6b562e , 0x7b918a69 , 0x95ec346c , 0xeeb7675f , 0xae4d79c8 , 0xc39ede86 , 0x79b3e09e , 0x1fb1fbc1 ] )
+  , Mont::unsafe_make( [ 0xf2c0db09 , 0x25b6e303 , 0xfab8e671 , 0xac214a5d , 0x61b5d7ad , 0x1425bc32 , 0xf51684a4 , 0x22255dc4 ] )
+  , Mont::unsafe_make( [ 0x9c9e918f , 0xd9358dbd , 0x33b56f76 , 0xd8ac5a92 , 0x11da762f , 0x88d2682a , 0x7740f1f0 , 0x05604342 ] )
+  , Mont::unsafe_make( [ 0x25f42afd , 0xedd25f22 , 0xa9c52109 , 0x2f07518c , 0xd829d20d , 0x7e708028 , 0x08561781 , 0x1c04db0a ] )
+  , Mont::unsafe_make( [ 0x9fe24a1e , 0xadfe7dfd , 0x16713252 , 0x4aca233f , 0x0d3cda85 , 0x7b486a39 , 0xc36d1833 , 0x0bd0ee84 ] )
+  , Mont::unsafe_make( [ 0x694c3210 , 0xc9ddbc09 , 0xa19e2367 , 0x809168ca , 0xb1bb36cf , 0x94a0bdce , 0x1b21eebe , 0x06933bbb ] )
+  , Mont::unsafe_make( [ 0x8d156232 , 0x01f06f6f , 0x4aac01b4 , 0xeeea5771 , 0x7650f5ba , 0x8cc6b705 , 0xd0d507b6 , 0x20ee37e5 ] )
+  , Mont::unsafe_make( [ 0xe82b83b7 , 0x12b96ee5 , 0x1233ce3d , 0x5587af13 , 0xfc720960 , 0x1d7bf660 , 0x34ade1b3 , 0x0e3d04ca ] )
+  , Mont::unsafe_make( [ 0xbd480b79 , 0xb726b573 , 0xe2459c3c , 0x82bb3c88 , 0x26cd176c , 0x8703f476 , 0xa587b378 , 0x05764781 ] )
+  , Mont::unsafe_make( [ 0x8d322597 , 0x3965b357 , 0x1519143c , 0xe717b5fa , 0x58c7cb6d , 0x0505c4f1 , 0xca3c65d7 , 0x00e9195f ] )
+  , Mont::unsafe_make( [ 0x837d1533 , 0xa919f550 , 0x4284e5b8 , 0x477effb7 , 0x0b27cca3 , 0x53d6dd15 , 0x7cea4b04 , 0x2218381f ] )
+  , Mont::unsafe_make( [ 0xff75a839 , 0x7e034bc9 , 0x1812edd2 , 0x0254168c , 0x28e35384 , 0xa28d8f4f , 0x146848c1 , 0x07467fc4 ] )
+  , Mont::unsafe_make( [ 0xfdab23c2 , 0x5c2c4920 , 0x0601d8e6 , 0xae1a3371 , 0xa73df660 , 0xc1c6520a , 0x06bdd2f8 , 0x2216c413 ] )
+  , Mont::unsafe_make( [ 0xbb363332 , 0xcf1c77f3 , 0x2e839212 , 0x422bd803 , 0x6130d790 , 0x4d75e1f6 , 0x27f39ede , 0x27ba8fb5 ] )
+  , Mont::unsafe_make( [ 0xebf12059 , 0xaf72830f , 0x0bdd0333 , 0x041a30af , 0x52e8da91 , 0xfa9e64c6 , 0x396652d5 , 0x216e12af ] )
+  , Mont::unsafe_make( [ 0xb9551123 , 0xc317aa5e , 0x23a974fb , 0x6626383c , 0x04400bbb , 0x674268d3 , 0x3d4891e2 , 0x0719912f ] )
+  , Mont::unsafe_make( [ 0xe69fe8fe , 0xd70e3270 , 0x3177e839 , 0xbc8214a6 , 0x0b2d4fda , 0x32de35c2 , 0x293d0bf2 , 0x0f0f85c8 ] )
+  , Mont::unsafe_make( [ 0xd1b2e68c , 0x31ed38b8 , 0x36419775 , 0x1286f8ca , 0x6766240e , 0x61feb467 , 0x206ba231 , 0x1d9c78ae ] )
+  , Mont::unsafe_make( [ 0x2a4c6628 , 0xe3afbbb1 , 0xa375fb87 , 0x7c34dd91 , 0xdbd3f428 , 0x2473236c , 0xee16084b , 0x1e372123 ] )
+  , Mont::unsafe_make( [ 0x938fd78a , 0xbd93c746 , 0xb77b2ee2 , 0x919aba45 , 0x0e6e2e99 , 0x815707e1 , 0x93fd666a , 0x077cbed3 ] )
+  , Mont::unsafe_make( [ 0x608375f1 , 0xfb417aa5 , 0x66742e80 , 0x5c5f4434 , 0xd12800ef , 0x2c17be7c , 0x318dae88 , 0x2a7a1bf2 ] )
+  , Mont::unsafe_make( [ 0x266713df , 0x862c32ea , 0xe2eb8638 , 0x27310865 , 0x5bc48f9f , 0x55764776 , 0x34213a7e , 0x0c1c61d7 ] )
+  , Mont::unsafe_make( [ 0xe9608f90 , 0x1f450d77 , 0xb6dd58b1 , 0x544b94bd , 0xe11b819e , 0xab8481c9 , 0xd1ce1dc9 , 0x0525558c ] )
+  , Mont::unsafe_make( [ 0x8b4e92aa , 0xa0f2b2ae , 0xcdd0896d , 0x2dba236f , 0xab89303f , 0xbd3bdb64 , 0x6e233a0e , 0x20ac2923 ] )
+  , Mont::unsafe_make( [ 0x7dc55ee0 , 0x8d683e50 , 0xf843d5c5 , 0xe22bd04b , 0xda69d523 , 0x5268491f , 0x9ccc464f , 0x1c533ee2 ] )
+  , Mont::unsafe_make( [ 0x57a29ce9 , 0xdd023a3a , 0x26504bb5 , 0x49bf21ea , 0x234ffa6e , 0x5bff99f2 , 0x35d0d6a4 , 0x185ceb70 ] )
+  , Mont::unsafe_make( [ 0x741db773 , 0x3a8f765b , 0xfc6e62d6 , 0x15cf3501 , 0x844be7c6 , 0x51e54e39 , 0x984982fd , 0x14c2948b ] )
+  , Mont::unsafe_make( [ 0x0b472ab2 , 0x8b1c8e45 , 0x69526e71 , 0x49dca687 , 0x4ba02b12 , 0x1e4e63a7 , 0x43dabbf4 , 0x0098c7e4 ] )
+  , Mont::unsafe_make( [ 0x0f1e13f1 , 0x8b80bbcd , 0x480610c9 , 0x34b17ca1 , 0x956e8bfb , 0xb95616a8 , 0x51e4f865 , 0x00296c17 ] )
+  , Mont::unsafe_make( [ 0x32705be4 , 0x8edeac9e , 0x122be276 , 0xc34847cd , 0xf4b1e079 , 0x49097867 , 0x2cf527f6 , 0x0b90837c ] )
+  , Mont::unsafe_make( [ 0x369e21a0 , 0x1baf2aca , 0x56a546bc , 0x22c97cc7 , 0x335f8713 , 0x6f8de4ca , 0x86a45dc1 , 0x01c61b21 ] )
+  , Mont::unsafe_make( [ 0x388746a5 , 0x3a2f26bf , 0x61b84065 , 0xc3227141 , 0xde46b7f3 , 0x0832dcd7 , 0x62b305e8 , 0x0621e9a6 ] )
+  , Mont::unsafe_make( [ 0x8533f979 , 0x63cf7dfa , 0x57f7f1f2 , 0xa9df6760 , 0xb6409f49 , 0x136f833c , 0x93e318e6 , 0x1e0bf5fe ] )
+  , Mont::unsafe_make( [ 0x9393d3ea , 0xebff0519 , 0xfec71e93 , 0xace407ed , 0xde5a11a6 , 0xf177a9d8 , 0x8d0bef4c , 0x1095e11f ] )
+  , Mont::unsafe_make( [ 0x0b75bf3d , 0xb1d95423 , 0x89a36318 , 0xe6d09408 , 0x95030040 , 0x8e1a18af , 0xe3e6758c , 0x055589c7 ] )
+  , Mont::unsafe_make( [ 0x185c8f72 , 0x76af5a8d , 0xed4ff1f3 , 0xafbf9a7c , 0x96f6a0dc , 0xc084c8a6 , 0x2e68abb6 , 0x04a93e7a ] )
+  , Mont::unsafe_make( [ 0x43713f31 , 0x896a7fb3 , 0xb33d8ecc , 0x723dade8 , 0xd80e0853 , 0x22b5cf0b , 0x4732e02a , 0x2c5d145e ] )
+  , Mont::unsafe_make( [ 0x373f8208 , 0xf0857403 , 0x62353c18 , 0x054805bd , 0x165935c3 , 0xdb6286e8 , 0x68ff0454 , 0x0eb78615 ] )
+  , Mont::unsafe_make( [ 0x82087a36 , 0x19a9ac00 , 0xe4346301 , 0xe7d5d2e1 , 0x59f3d676 , 0x18ecd8d7 , 0x368c3b14 , 0x2746668d ] )
+  , Mont::unsafe_make( [ 0x2eb4651a , 0x844825a4 , 0xcb718f15 , 0x59e77eee , 0xc759a070 , 0xc5a13010 , 0x359e02db , 0x166f0048 ] )
+  , Mont::unsafe_make( [ 0x857a37b4 , 0xc2651665 , 0x66e1c1a9 , 0xb403d499 , 0xad6944b9 , 0xb22b63a1 , 0x5cdde7f6 , 0x22ba2add ] )
+  , Mont::unsafe_make( [ 0x3a396259 , 0x7bee3424 , 0x3f768702 , 0xdf4eed13 , 0x49f40ff8 , 0xff5f6460 , 0xa2d9c79c , 0x19a019aa ] )
+  , Mont::unsafe_make( [ 0x6a189f9e , 0xd0a7714f , 0x7acf699f , 0xba6df07d , 0xf463b6f9 , 0xfefb3b05 , 0xec7d5e58 , 0x0c0f4c0c ] )
+  , Mont::unsafe_make( [ 0x9c6680db , 0xca3e266a , 0xe2d94cf6 , 0x821039d8 , 0x8e5e0743 , 0xe4857ea2 , 0x639a5bd1 , 0x1c5d02a0 ] )
+  , Mont::unsafe_make( [ 0xe8ed0d7f , 0xd2c3ecd8 , 0x5ef629ff , 0x9bff3f8a , 0xd5f79ac9 , 0x1d6c3aad , 0x1760015e , 0x00c9450a ] )
+  , Mont::unsafe_make( [ 0xdd05af4c , 0xaf5e84ed , 0x7c73d510 , 0x7deba35d , 0x32473c83 , 0xee90a38b , 0xa86941d8 , 0x2896dece ] )
+  , Mont::unsafe_make( [ 0x3e1554c4 , 0xf7b57e99 , 0x748ffffe , 0x79e04dca , 0x064724ba , 0x5d0eff81 , 0xd2b70ecb , 0x097e7d15 ] )
+  , Mont::unsafe_make( [ 0x405a41ab , 0x02379d33 , 0x944f4dc7 , 0x11c41284 , 0x24f67326 , 0x16fcd900 , 0xc4cf79e6 , 0x0872c520 ] )
+  , Mont::unsafe_make( [ 0xc9dd5028 , 0x62acb1bb , 0xb9a79bbd , 0x3c452ad7 , 0x575047de , 0xed9c2f61 , 0x592026a0 , 0x052dbfa5 ] )
+  , Mont::unsafe_make( [ 0x97f8d175 , 0x91c9f89b , 0xa981f4c2 , 0x19ef5184 , 0xf2d549eb , 0xd72ab789 , 0xf0c4c386 , 0x2c780ead ] )
+  , Mont::unsafe_make( [ 0x9c82b011 , 0x6defd594 , 0x58069c29 , 0xc08ad056 , 0x4ae4be4c , 0x41dc4ef7 , 0x9557b567 , 0x18a26fc4 ] )
+  , Mont::unsafe_make( [ 0x2cdc902e , 0xc3c645d2 , 0xf3a4c677 , 0xc72fe4ec , 0xec920d5d , 0xa41b1209 , 0xacb19dd8 , 0x242a1d2d ] )
+  , Mont::unsafe_make( [ 0x8374bfec , 0x512cbee0 , 0x3052aa77 , 0xdf8bb911 , 0x1feae6d8 , 0xd484966f , 0x699575ca , 0x0d8a35d9 ] )
+  , Mont::unsafe_make( [ 0x39713df0 , 0x87adffb3 , 0x2281b515 , 0xbe19a68f , 0x400b105e , 0xfac48617 , 0xe5192dd0 , 0x0b04064a ] )
+  , Mont::unsafe_make( [ 0x9a07d049 , 0x34e5bb47 , 0xdaa096bd , 0xb46ebbc5 , 0xe094a6c8 , 0x8d9c285b , 0x66bc2df3 , 0x07f45949 ] )
+  , Mont::unsafe_make( [ 0x1a17a57e , 0xd4a92107 , 0xffeaae3f , 0xb5473c0c , 0x644c1ef8 , 0x68804145 , 0x31f16a86 , 0x221e8c1f ] )
+  , Mont::unsafe_make( [ 0x51716b95 , 0x09e8d377 , 0x2d9057fd , 0x296d1dce , 0xac352614 , 0xa5466505 , 0x674864f0 , 0x1631d7fe ] )
+  , Mont::unsafe_make( [ 0x4f1d0755 , 0x63e3f638 , 0xae85c08f , 0x0f666a65 , 0x7ef6926f , 0x10885547 , 0xdb40a579 , 0x053c786a ] )
+  , Mont::unsafe_make( [ 0x4253a46d , 0x0f906471 , 0x704d1bed , 0x6b0e318c , 0x29d967ba , 0x1afc9991 , 0x90439876 , 0x061cdb7b ] )
+  , Mont::unsafe_make( [ 0x96ce1c40 , 0x9b581213 , 0x61d541f2 , 0x65b428c8 , 0xaba28ab2 , 0x794b56df , 0x107a0fcf , 0x0d323ab6 ] )
+  , Mont::unsafe_make( [ 0x18d568a5 , 0xd3b57562 , 0xf563fdc9 , 0x4204952d , 0xda8ca080 , 0x5c0adac1 , 0x0fdb63d7 , 0x2ffa2ca1 ] )
+  , Mont::unsafe_make( [ 0xa0971e68 , 0x2751fc0a , 0x91d7deb2 , 0x7309a1bb , 0x3ebcd263 , 0x5cc86779 , 0x9d491887 , 0x0bf8cfea ] )
+  , Mont::unsafe_make( [ 0xfa291244 , 0x8bbb4d96 , 0x68e9c98c , 0xf15fd04c , 0xf5ebe5a8 , 0xebb46e23 , 0x719c5b90 , 0x2db3766c ] )
+  , Mont::unsafe_make( [ 0xc07e5cda , 0xde921c3e , 0xa6518557 , 0xae401918 , 0x46238e20 , 0x728e5e17 , 0x256433fd , 0x1beacf27 ] )
+  , Mont::unsafe_make( [ 0x654d3ec4 , 0x9470ce33 , 0x488c08a1 , 0xe8f3f824 , 0x8296d9b3 , 0xc642ff4b , 0xdd54f996 , 0x1a5be407 ] )
+  , Mont::unsafe_make( [ 0x0d2c91f8 , 0x7aa7531a , 0xdd93eb33 , 0xcd83d2d3 , 0x3047c454 , 0xb4980dbd , 0xad0345f5 , 0x11e5d3b3 ] )
+  , Mont::unsafe_make( [ 0xe3ffc390 , 0x3420cce3 , 0x326c526b , 0x1a2e2767 , 0xb03a2699 , 0xa12ed762 , 0xc7baa9e1 , 0x29dcd9b8 ] )
+  , Mont::unsafe_make( [ 0x117f12f8 , 0x77591391 , 0x66fdbde7 , 0x6358569c , 0x8ce2e0b7 , 0x3a9c1d0b , 0xb5a63fed , 0x04a733e1 ] )
+  , Mont::unsafe_make( [ 0x3e038189 , 0xae765e35 , 0x520a2a31 , 0xb3fec85b , 0x59604021 , 0x5fd19a82 , 0xd0a785dd , 0x16b93831 ] )
+  , Mont::unsafe_make( [ 0xb385b7f2 , 0x18928d1f , 0xab982092 , 0x0d2b9101 , 0x02d78c37 , 0x172e8d85 , 0x3866a59b , 0x22095c1c ] )
+  , Mont::unsafe_make( [ 0x26b53c28 , 0x3f10e91b , 0x514b92e3 , 0x7bcbcb20 , 0xa5382187 , 0x672e741a , 0x6ae84cdc , 0x1486da35 ] )
+  , Mont::unsafe_make( [ 0x81eec3d2 , 0x60ddd12b , 0x855db027 , 0xf42b0593 , 0x38207731 , 0xda1a8aa2 , 0xfca55db5 , 0x0b214135 ] )
+  , Mont::unsafe_make( [ 0x19abf9c7 , 0xca8b61cc , 0x6a0a3aab , 0xf3c0e4c8 , 0x8ed9385c , 0x637edacc , 0x1b1cd8e5 , 0x205b0614 ] )
+  , Mont::unsafe_make( [ 0x6223bbd9 , 0x0bb5ae9e , 0x4ffe27ff , 0x2adbb69e , 0x11c5ae2c , 0x2626c0c3 , 0xb3c4ea32 , 0x26fc0901 ] )
+  , Mont::unsafe_make( [ 0xce733d38 , 0xe1ac5d35 , 0x6f32fc17 , 0x525a5cb6 , 0xa858dd9f , 0x7e532fd3 , 0x18e26705 , 0x09c25a32 ] )
+  , Mont::unsafe_make( [ 0x203f84a4 , 0x264e0227 , 0xad2336ea , 0x080894f1 , 0xff1a12c7 , 0xb01aed2c , 0xb605a050 , 0x1f2f6b6f ] )
+  , Mont::unsafe_make( [ 0x7085937b , 0xf9bab0f3 , 0x898c5247 , 0xc0f1ebb4 , 0xd4c4a4e7 , 0x0fe75943 , 0xc8fe65a7 , 0x0552c77c ] )
+  , Mont::unsafe_make( [ 0x4c96d688 , 0x6a1eb982 , 0x3572fbca , 0xe8e0f020 , 0xc5238444 , 0x80b045a0 , 0xe87146e0 , 0x2e3446cc ] )
+  , Mont::unsafe_make( [ 0xe7b0d8f5 , 0xaf28af01 , 0x3fcfb6c5 , 0xcb91b316 , 0xa3f8ef28 , 0x9ed74f1a , 0x78517dce , 0x0ad16b23 ] )
+  , Mont::unsafe_make( [ 0xa00ba1ad , 0x026c315d , 0x256c0836 , 0x158d02a5 , 0x83b36cca , 0x604ba669 , 0x1a4d644c , 0x242989aa ] )
+  , Mont::unsafe_make( [ 0x7357566b , 0x4f358180 , 0xea3b2433 , 0x12bc832e , 0x3250d465 , 0x8c1aaa4a , 0x3280bc22 , 0x07d3b6a1 ] )
+  , Mont::unsafe_make( [ 0x35c1c269 , 0x5fbd26dc , 0x0731a46b , 0xc5fdde6a , 0x9f9510b0 , 0x9ea8e23d , 0xbfa7e5ce , 0x2b931d8c ] )
+  , Mont::unsafe_make( [ 0xc241c734 , 0x1fbf6de0 , 0x2aa5a90d , 0x19c55e56 , 0x4fb510d7 , 0xd57c0d40 , 0xae8014ae , 0x22bf7a56 ] )
+  , Mont::unsafe_make( [ 0x4ee3bb22 , 0x85dab23b , 0x5e37b10c , 0xfd939bbb , 0x04e680ea , 0x40f0b72c , 0x235afbe6 , 0x1401f9a3 ] )
+  , Mont::unsafe_make( [ 0xb4a31ac4 , 0xf6ed738f , 0x3ec43132 , 0xd0a23415 , 0x7b7c618b , 0x805b0e7e , 0xc1ab0ccc , 0x2a2f576c ] )
+  , Mont::unsafe_make( [ 0x8590471b , 0xe2550133 , 0x98eccf22 , 0x9c15d698 , 0x828eba3e , 0x2f7448a2 , 0x326e6f23 , 0x1b062d35 ] )
+  , Mont::unsafe_make( [ 0x65dd6cad , 0x758b652e , 0x1e084ab9 , 0x63e74770 , 0x55ccb364 , 0x0369b624 , 0xd4b19649 , 0x1ce5311a ] )
+  , Mont::unsafe_make( [ 0xdb69cf0e , 0xd7267c45 , 0xfd3ce05f , 0x6bb9093e , 0x0b357659 , 0x6bdc4274 , 0x942e7d57 , 0x218ba30d ] )
+  , Mont::unsafe_make( [ 0x54ce1f42 , 0x57eceba3 , 0xa90f0537 , 0xfa69012a , 0x322c3d33 , 0xb4f4ee1c , 0x8cdc6296 , 0x1efe3e55 ] )
+  , Mont::unsafe_make( [ 0x1d14bcfc , 0x652b42b1 , 0xa3ecb26b , 0x0392ec66 , 0x3325913c , 0x4fbf0c95 , 0x14b53ea0 , 0x0bf96de1 ] )
+  , Mont::unsafe_make( [ 0x23742cf6 , 0x68945377 , 0xde96685a , 0xbf251c5c , 0xdec9484d , 0xd9c6e9f7 , 0xb5930f42 , 0x1f4bd90a ] )
+  , Mont::unsafe_make( [ 0xb94a92d4 , 0xde03df69 , 0x1521341c , 0x9a5c9c44 , 0xa608087f , 0xf31de1bb , 0xe5c07811 , 0x1851523e ] )
+  , Mont::unsafe_make( [ 0xa339b5a6 , 0xcc6e590c , 0x096eb94b , 0x6c1dfb50 , 0x5d76c2fa , 0x0943c0e5 , 0x3587a275 , 0x057d40df ] )
+  , Mont::unsafe_make( [ 0x50149850 , 0xf2e979bc , 0xe927a4f7 , 0xcb6728fc , 0x4cc978a1 , 0xb977f8ce , 0x5b0c2caf , 0x2be3c9d3 ] )
+  , Mont::unsafe_make( [ 0xc92c0a32 , 0xa7770e1e , 0xababc554 , 0x2c6578d9 , 0x75b9fac7 , 0xa674037b , 0xe4d32b10 , 0x2678e1f6 ] )
+  , Mont::unsafe_make( [ 0x58de0d24 , 0x282b7b3a , 0x5ee211e5 , 0x446eadca , 0x3a5fa6c3 , 0xdc90b098 , 0xe170a783 , 0x1d053e4e ] )
+  , Mont::unsafe_make( [ 0x470529f6 , 0xd69caac3 , 0x167e55b4 , 0x36fec0f6 , 0x288b3c48 , 0xa5eb493e , 0x2951c505 , 0x28683035 ] )
+  , Mont::unsafe_make( [ 0x736ad9f9 , 0xd703852c , 0xbb1b6e0c , 0xaf775004 , 0x59a46ca2 , 0x2a05373e , 0x83c94472 , 0x01b54d1f ] )
+  , Mont::unsafe_make( [ 0xd2add386 , 0x535c4aaf , 0x18368df0 , 0x44f7172f , 0x7297c3b5 , 0xeefa0f2a , 0xea6ae46b , 0x300da254 ] )
+  , Mont::unsafe_make( [ 0x698bcb12 , 0x302ccc4c , 0xc08eca69 , 0xd27c5f35 , 0x4a61180e , 0xd1b28e26 , 0x1d55d1aa , 0x0824e9c6 ] )
+  , Mont::unsafe_make( [ 0x741493d4 , 0xafce3f1c , 0xc7108ca4 , 0xb17a5cb6 , 0x3d472f34 , 0x92ac844c , 0x777520b0 , 0x1b5ca06f ] )
+  , Mont::unsafe_make( [ 0x851c64a3 , 0xcc1b1ab6 , 0x4565d571 , 0xfbb16a6c , 0x945ab602 , 0x1a77b51a , 0xe3ebb2a6 , 0x29dcbf21 ] )
+  , Mont::unsafe_make( [ 0xb5fa0e82 , 0x9a9bd9fb , 0x67b1e106 , 0xb3940eef , 0xae8ddb38 , 0x3225ac58 , 0xc9a74f19 , 0x0355c106 ] )
+  , Mont::unsafe_make( [ 0x23f817a2 , 0x06430fbe , 0x1f830f6d , 0xdb760c12 , 0x1ce6c267 , 0x31adfe28 , 0x1e4c95cb , 0x16d27ca2 ] )
+  , Mont::unsafe_make( [ 0x4749ace6 , 0xfa93013d , 0x1fc1f8cc , 0x5359b626 , 0x311c93eb , 0x3407f558 , 0xd0a03c60 , 0x1d60ec68 ] )
+  , Mont::unsafe_make( [ 0xd297381a , 0x414baa4e , 0x08596464 , 0xae34e867 , 0xb5d94db5 , 0xd3573cb3 , 0x51c11b61 , 0x0ed30bf4 ] )
+  , Mont::unsafe_make( [ 0x57a6dd70 , 0xaabeebb6 , 0x21f33d3c , 0x9a187f57 , 0xf41fbc95 , 0x4d026871 , 0x132d174d , 0x28139f8f ] )
+  , Mont::unsafe_make( [ 0xe037ab15 , 0xfadf5181 , 0x6fb86cd9 , 0xaadf3517 , 0x4acc59e6 , 0xd1d58fec , 0x89e30c4a , 0x282bfa2a ] )
+  , Mont::unsafe_make( [ 0x9a5efc8a , 0x7fbb827f , 0x94d266cb , 0x96f71cbb , 0xb8fe46b9 , 0x4c7b6c89 , 0x9a7efd9f , 0x291a9083 ] )
+  , Mont::unsafe_make( [ 0x3735e848 , 0xc705496b , 0x364baf28 , 0xbc13e016 , 0x96e3fbba , 0x32ecda91 , 0xbc025eef , 0x167f609f ] )
+  , Mont::unsafe_make( [ 0xab1ac001 , 0x09ff6ef9 , 0x9a69d7f4 , 0x1c0ae451 , 0x9b184d00 , 0x59819325 , 0xa91724bf , 0x0d295fa6 ] )
+  , Mont::unsafe_make( [ 0xb91d7c62 , 0x9a1fba5b , 0x45512204 , 0x76de9b1f , 0x02452c4e , 0xa90628d0 , 0x48f20c10 , 0x18741998 ] )
+  , Mont::unsafe_make( [ 0x6b5ce188 , 0xd2762349 , 0xf171ac56 , 0x2a9df147 , 0xf430a111 , 0x0887a543 , 0x3faeec52 , 0x11c07aa8 ] )
+  , Mont::unsafe_make( [ 0x7aa51df9 , 0xc7a66563 , 0x23066ad0 , 0x8be68caf , 0x4e326d43 , 0x07623e53 , 0x51e55b86 , 0x2244c861 ] )
+  , Mont::unsafe_make( [ 0x491c6971 , 0x80b4d182 , 0xef587da9 , 0x31241f17 , 0x40da4c6c , 0x17509a07 , 0x29c21238 , 0x293de7f3 ] )
+  , Mont::unsafe_make( [ 0xb14288d3 , 0x00336bc7 , 0x02c99c56 , 0xa84af560 , 0x9c5ebb95 , 0xdf9c5f11 , 0x0d3cfa2c , 0x13565b7e ] )
+  , Mont::unsafe_make( [ 0x1a4f9893 , 0x2645212c , 0x5bebc579 , 0x0520a689 , 0x0a0c86a6 , 0x8dd95535 , 0x09593da7 , 0x0def013d ] )
+  , Mont::unsafe_make( [ 0xd07a15a0 , 0x26e03b5c , 0x45252a05 , 0xc21d1d3f , 0xc2786578 , 0x210f927f , 0x523edfcc , 0x2f56a6e6 ] )
+  , Mont::unsafe_make( [ 0x668f9f53 , 0xda503d8c , 0x65a476c6 , 0x9063e06e , 0xb630a4d7 , 0xe8d0354f , 0x06221ae9 , 0x1e3ff6e5 ] )
+  , Mont::unsafe_make( [ 0x99c4d4e1 , 0x514a19b7 , 0x8cdec5e9 , 0x1ba7d154 , 0x7ded1fe9 , 0x17a32c07 , 0xc95a9554 , 0x0ef19c72 ] )
+  , Mont::unsafe_make( [ 0x7131d963 , 0x99345c25 , 0xf1cd8301 , 0xe6bc6384 , 0xfc025c50 , 0xd317fdb8 , 0xcb1ea8e7 , 0x05de8f57 ] )
+  , Mont::unsafe_make( [ 0xd7a3784c , 0xbab6f74d , 0x08285c5a , 0x29b27298 , 0xc167abaf , 0x01cc1a6e , 0xba743871 , 0x1da557a0 ] )
+  , Mont::unsafe_make( [ 0x25886439 , 0xa0dc23a7 , 0x8928c20b , 0xb6903cd9 , 0xd5005232 , 0x8084d07f , 0x944de259 , 0x0fb6dd39 ] )
+  , Mont::unsafe_make( [ 0x2c4202f5 , 0x2be51b96 , 0x97908e17 , 0xfc242ea8 , 0xdc0e00f4 , 0x3085d357 , 0x3b98d5a0 , 0x282fd764 ] )
+  , Mont::unsafe_make( [ 0xe63e4c60 , 0xfc9c0350 , 0xa7866d48 , 0x95a2f128 , 0x63695cbb , 0x7921d83a , 0x2397cbb9 , 0x22af0032 ] )
+  , Mont::unsafe_make( [ 0x44eb39dd , 0x180b8c25 , 0x5eeea825 , 0x068c282f , 0x6e4eed52 , 0xb8d291d9 , 0xc1914c92 , 0x185331fb ] )
+  , Mont::unsafe_make( [ 0x173c3d35 , 0xb8cb4804 , 0xa2986328 , 0xff9a87f6 , 0x75e89093 , 0x9c3f34ed , 0x0c46ce0a , 0x1f06e055 ] )
+  , Mont::unsafe_make( [ 0x8c458d7a , 0xccf0d984 , 0x5d128f3a , 0x76794092 , 0xc354f036 , 0x33a7bc26 , 0xf0d5d03b , 0x25373947 ] )
+  , Mont::unsafe_make( [ 0x33564d0a , 0x1011a664 , 0x0f679904 , 0xcfcf15f0 , 0x8fc0eca7 , 0x041d84d9 , 0x1524fdc7 , 0x19bbddea ] )
+  , Mont::unsafe_make( [ 0x08b60f65 , 0xe854f290 , 0x87b2230b , 0x34b4ef5c , 0xecdd4ee1 , 0xa4d907a9 , 0x2fe0f53f , 0x2843398d ] )
+  , Mont::unsafe_make( [ 0xd0852091 , 0xf3c0d12e , 0xd47263ca , 0x494eedd2 , 0x7d1a22a4 , 0x6a13e8ad , 0x7dac57ed , 0x29222c37 ] )
+  , Mont::unsafe_make( [ 0xbf04fb53 , 0x0035afb1 , 0xfa388522 , 0x411f230b , 0xb11bb1d7 , 0x675af745 , 0xc9fd6724 , 0x2a7bd7e2 ] )
+  , Mont::unsafe_make( [ 0x72e7f916 , 0x379d1a6b , 0x392f63bb , 0xd0598a97 , 0x1cf737d5 , 0x36927a9a , 0xf8225b45 , 0x17ee3e67 ] )
+  , Mont::unsafe_make( [ 0x6de55c71 , 0xcede73c3 , 0x259b5026 , 0xde9e3bed , 0x7d77f2a5 , 0xf994c2df , 0x22a38eab , 0x0a038989 ] )
+  , Mont::unsafe_make( [ 0x2a03cca2 , 0x8a0579fe , 0x19ec74d5 , 0x5c654549 , 0x9cff1fac , 0x73a79074 , 0x6ba4438b , 0x210f03c4 ] )
+  , Mont::unsafe_make( [ 0xc410aaf4 , 0xa33e576c , 0x8d708a53 , 0xe37f6235 , 0xb98a3209 , 0x7ab0f7b1 , 0x7167b94f , 0x2d8e2e62 ] )
+  , Mont::unsafe_make( [ 0xc88374f1 , 0xa30fd13a , 0x958603e5 , 0x5f182d73 , 0xebc23d4f , 0x0ac83bd1 , 0x035e464d , 0x0d20ad43 ] )
+  , Mont::unsafe_make( [ 0xb61e0324 , 0x10a418ed , 0xcc5dc1e0 , 0x2c841c62 , 0xfad70f13 , 0xf006ff28 , 0xcf08ffba , 0x0113343d ] )
+  , Mont::unsafe_make( [ 0x21d52a36 , 0xea5e1c54 , 0x9189cc7c , 0xff9c4166 , 0xf1596350 , 0x67d6ee16 , 0xde06ba23 , 0x19a316e7 ] )
+  , Mont::unsafe_make( [ 0x013963c9 , 0x6b4b7343 , 0x4d7395d9 , 0x3300bfa6 , 0x7a9ae34c , 0xd0054962 , 0xd55f9e66 , 0x1aae6f95 ] )
+  , Mont::unsafe_make( [ 0xdb96ff7b , 0xaf911c81 , 0x44db6def , 0x1b423edf , 0x1422aa2b , 0xf08e0e0d , 0x5f882f7f , 0x05c0a8d3 ] )
+  , Mont::unsafe_make( [ 0x3b50e5fe , 0x40842939 , 0xd1a7208f , 0x250558dd , 0xde81ba42 , 0x80d3ec4c , 0xac79c8c6 , 0x0774284b ] )
+  , Mont::unsafe_make( [ 0x51d3a08c , 0x51d46d8e , 0x460b89da , 0xb4900b3e , 0x004be0af , 0xfb5eb21b , 0x817bfcc7 , 0x2bf4facf ] )
+  , Mont::unsafe_make( [ 0xb69b2174 , 0x609810e5 , 0xc7ee259d , 0x79745432 , 0x8ddff2b9 , 0x3b5fa5cf , 0xaad91729 , 0x0bb56e95 ] )
+  , Mont::unsafe_make( [ 0x82f80fa4 , 0x9a06101d , 0xda77fee0 , 0x5289a03f , 0xe8edd85c , 0x52ff487c , 0x2f87ed61 , 0x239bb0e8 ] )
+  , Mont::unsafe_make( [ 0x60a0844b , 0x0d56280c , 0xc80b6e99 , 0x3a8fd473 , 0xce34304d , 0x0238f261 , 0x1810a094 , 0x25d296eb ] )
+  , Mont::unsafe_make( [ 0x80755620 , 0x206f5c16 , 0xc8631b5a , 0x037b7399 , 0x24dd906e , 0xd366cd60 , 0x53005389 , 0x1eadf67c ] )
+  , Mont::unsafe_make( [ 0x84a6ae5e , 0x587f0d08 , 0xcc8ef1d6 , 0xce15093f , 0x1bd92bb8 , 0x16ab0884 , 0xbb040f5d , 0x1ec48245 ] )
+  , Mont::unsafe_make( [ 0x55272cba , 0x60b3976d , 0x7f9d2029 , 0x6bdfe502 , 0x8544ede9 , 0x97dcfe0f , 0x66531530 , 0x2aae4e47 ] )
+  , Mont::unsafe_make( [ 0x05391abf , 0xefec096a , 0x58cba53d , 0x0f19f3e3 , 0x1751501e , 0xd83d4383 , 0x78e637ca , 0x01db3bc2 ] )
+  , Mont::unsafe_make( [ 0x17277f09 , 0xe610d0d6 , 0x788b2295 , 0x3ab53184 , 0xd8073e9f , 0x34a66ada , 0xeb9e10db , 0x03ff24f2 ] )
+  , Mont::unsafe_make( [ 0x5ad5dc6a , 0x939b4a88 , 0x47fe5614 , 0xe45b95f6 , 0x517d4486 , 0xa9c225de , 0x1b3a50f0 , 0x0f09c11f ] )
+  , Mont::unsafe_make( [ 0xcc0cb504 , 0xaf31c938 , 0x26bd07eb , 0x01faa78a , 0xa69d83e4 , 0x87be8fe7 , 0x744f5289 , 0x273308de ] )
+  , Mont::unsafe_make( [ 0x224d05cb , 0xf21d317c , 0xa95a6549 , 0x73962655 , 0x6550bae4 , 0xb0033efc , 0xbf4dbf23 , 0x184ac8c7 ] )
+  , Mont::unsafe_make( [ 0x33424021 , 0xbc571f16 , 0x214bc986 , 0x07a53522 , 0x6f7012a1 , 0x442fc234 , 0x41c17436 , 0x2333d0b1 ] )
+  , Mont::unsafe_make( [ 0x46bbc25a , 0xd7a18f99 , 0x0eb248b0 , 0x90d3c61a , 0xa847b9ed , 0x118cd094 , 0xb90aedb7 , 0x11d03740 ] )
+  , Mont::unsafe_make( [ 0xbf5d6267 , 0xbcc038b1 , 0xa93888d7 , 0xfd53b370 , 0x99c11bd6 , 0x82e70999 , 0xc92e5f7a , 0x2eb460e7 ] )
+  , Mont::unsafe_make( [ 0x2335ac4e , 0x05578cb2 , 0xf456ba7f , 0x3a4bb03b , 0x496953b5 , 0xb2636dc7 , 0xf7ac07c9 , 0x064e8cee ] )
+  , Mont::unsafe_make( [ 0xd4c7bd87 , 0xc0910166 , 0x3fb208a0 , 0xe78f8722 , 0x9081e6b7 , 0xb06a60fe , 0xdc0ddf28 , 0x1c53ec0d ] )
+  , Mont::unsafe_make( [ 0x04aa90de , 0xe7e42dca , 0xaa26ed13 , 0x54e0bc14 , 0xe3e51df0 , 0x7451e8fb , 0x25d27c3e , 0x23ce0e49 ] )
+  , Mont::unsafe_make( [ 0x2210cf45 , 0x72290115 , 0x797559fa , 0xc151ace8 , 0x167dead2 , 0x2add172c , 0xc96ad161 , 0x02d63a36 ] )
+  , Mont::unsafe_make( [ 0x06f16f5f , 0xe60e8eb4 , 0x93fd21b8 , 0x590f91d3 , 0x285415d5 , 0xd230e5fd , 0x5409aa0d , 0x1de1ba2f ] )
+  , Mont::unsafe_make( [ 0x8cb87b06 , 0xbae1903e , 0x7ded464e , 0x0b8d9008 , 0x19c84923 , 0x5cbb64d0 , 0x8c886e66 , 0x21562899 ] )
+  , Mont::unsafe_make( [ 0xff64ef03 , 0x3b3f68ef , 0x46b6935e , 0xf6950100 , 0x294a9812 , 0xf8d7bcc0 , 0x72e9f7ee , 0x13e0fde6 ] )
+  , Mont::unsafe_make( [ 0x3a07f05d , 0xf3755052 , 0xe1b725f1 , 0x94db86c1 , 0x2d32795d , 0xc668c388 , 0xdc661e38 , 0x1486fab6 ] )
+  , Mont::unsafe_make( [ 0x7814dd0d , 0x45faef86 , 0xf30da1b1 , 0xb8e78b00 , 0x3e4cabee , 0x23b9f7cb , 0x623b9707 , 0x25df4147 ] )
+  , Mont::unsafe_make( [ 0x0df05179 , 0xcfb2a192 , 0xae6494b9 , 0x37f8a6d9 , 0x888dc606 , 0x25f5a22e , 0x6957b37c , 0x0c059ed8 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// internal mixing matrix (`t x t`s, flattened)
+pub const CONST_P: [Mont; 225] = 
+  [ Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x3b54b485 , 0x32b17525 , 0xc38e38e6 , 0x84079342 , 0xd9e9a211 , 0x16b75390 , 0x266d3c6e , 0x0ec01e9f ] )
+  , Mont::unsafe_make( [ 0x6d44c587 , 0xde54a0e0 , 0xffddf905 , 0xac44854f , 0xd51f8ac0 , 0xd1cc6cb6 , 0x4956f680 , 0x071afc25 ] )
+  , Mont::unsafe_make( [ 0xb63eba0a , 0x280c4009 , 0x09c1f72e , 0x628d1bd9 , 0x9cc2934d , 0xaf4d7d1d , 0xfeaa80d6 , 0x19fa4340 ] )
+  , Mont::unsafe_make( [ 0xa06a0d39 , 0x0899e76c , 0x5f24f9d0 , 0xfd663fcb , 0x3d7f8995 , 0x57219009 , 0x305e5107 , 0x1a6f9743 ] )
+  , Mont::unsafe_make( [ 0x4d049b49 , 0x8a73cf7e , 0xd6b8e51a , 0xe212c622 , 0x7d512107 , 0x7ee217d6 , 0xad74c1cd , 0x0cb1a208 ] )
+  , Mont::unsafe_make( [ 0xd59f2345 , 0x68057dad , 0xcfeeb92e , 0x771a81b5 , 0x2d266219 , 0x1029c160 , 0x9dce2475 , 0x1002df7b ] )
+  , Mont::unsafe_make( [ 0x2140aca5 , 0x659580b5 , 0x26e6d3b3 , 0x2d49d497 , 0x18c6e72e , 0x72e4b5d6 , 0x3f383d2a , 0x2e1a35a9 ] )
+  , Mont::unsafe_make( [ 0xafd553ae , 0x7b83a596 , 0x94a5d393 , 0xd21b28ea , 0x2e4d6127 , 0x2563379d , 0x99751f8e , 0x2832b902 ] )
+  , Mont::unsafe_make( [ 0x9a242ced , 0x9c8a1b55 , 0x27b776fd , 0xbeb04e03 , 0xdbc0d0e6 , 0xa5fa75f9 , 0x8ecae1d2 , 0x175e3a09 ] )
+  , Mont::unsafe_make( [ 0xfc28a1e4 , 0x40a9b7d0 , 0x09f0cd44 , 0xaaf32861 , 0x9cfccf92 , 0xdb36db74 , 0x706c6eb8 , 0x171bc114 ] )
+  , Mont::unsafe_make( [ 0x86a21c3a , 0xd8517671 , 0xbfb19ba5 , 0x40fd5e21 , 0x610272d0 , 0xae652074 , 0xc3e74a01 , 0x027bf0d0 ] )
+  , Mont::unsafe_make( [ 0x4db2e4ec , 0xf90b623b , 0xa10a2de6 , 0xccd86eb3 , 0xa3ffa830 , 0xbdc17932 , 0xdab5f8dc , 0x2c032da8 ] )
+  , Mont::unsafe_make( [ 0xb437b307 , 0xb8504146 , 0x4f7ffe46 , 0x25de6a9f , 0x1150904d , 0xd2bdd5f1 , 0x2c556c21 , 0x1660ea64 ] )
+  , Mont::unsafe_make( [ 0xb6acf792 , 0xc08efed1 , 0xbd6c2b63 , 0x104eb645 , 0x0f43506d , 0xc31092e4 , 0x3b02c496 , 0x0d5ff1a1 ] )
+  , Mont::unsafe_make( [ 0xe68c6683 , 0xb27f1e10 , 0xe23087ff , 0x26201202 , 0x4b8978c8 , 0x3cf9ab92 , 0xab47dec8 , 0x03841eb9 ] )
+  , Mont::unsafe_make( [ 0xd72ba749 , 0xb2c2174b , 0xc01e3864 , 0x6c0c6861 , 0x1cb39f72 , 0x9ca2862d , 0x961be2f9 , 0x0f092288 ] )
+  , Mont::unsafe_make( [ 0xc0393ca2 , 0xb6c709bc , 0x6fb4a69a , 0xdb77cc76 , 0xfe748553 , 0x0e2b2e3e , 0x3cb2e9fd , 0x2a849282 ] )
+  , Mont::unsafe_make( [ 0x04d7f604 , 0xbce1273f , 0x70cca504 , 0xed11ca60 , 0xdf6b82b9 , 0x685de435 , 0xa7b209ed , 0x26ab468a ] )
+  , Mont::unsafe_make( [ 0x79c28f3a , 0x6b09fcc2 , 0x56c1d82a , 0x2c9b6b2d , 0x45d4ff3d , 0xbc535866 , 0x860b0f71 , 0x01b2280c ] )
+  , Mont::unsafe_make( [ 0xc774ca65 , 0x64678797 , 0x9d6fac70 , 0x6c0e6e6e , 0xb2923940 , 0x9e57d2b6 , 0x69172928 , 0x10ce1ccd ] )
+  , Mont::unsafe_make( [ 0x4605853e , 0x62014436 , 0x6ae7743c , 0x76f80706 , 0x44e7ad32 , 0x028c5cf0 , 0xb8a01d97 , 0x224ac4d3 ] )
+  , Mont::unsafe_make( [ 0xb11fba8f , 0xc869d223 , 0x0867e8a4 , 0x3c7407fd , 0x9f21f52e , 0x4509d94c , 0x4e13d06e , 0x21227d28 ] )
+  , Mont::unsafe_make( [ 0xf932c8c4 , 0x0f9f28fa , 0x300f3e4d , 0xe8c6fd14 , 0xa7a0adae , 0xfaf46173 , 0x2038895a , 0x0580939e ] )
+  , Mont::unsafe_make( [ 0x464c85f8 , 0xb25e4ab0 , 0x69833a12 , 0x5d6ad0c5 , 0xa548057d , 0x8b9b5553 , 0x1f13ee26 , 0x123aede4 ] )
+  , Mont::unsafe_make( [ 0x92e8a6dc , 0x4df4e725 , 0xec42bd0c , 0xf757969c , 0x87c4ca06 , 0xc4cf14b2 , 0x6654208a , 0x2297f55f ] )
+  , Mont::unsafe_make( [ 0xc0323076 , 0x7f53d428 , 0x18c492c1 , 0xbf1c1c60 , 0xf38967ce , 0x3e369b43 , 0xe1031c87 , 0x29271222 ] )
+  , Mont::unsafe_make( [ 0xb6cc815a , 0x64b9f7c0 , 0x322e437a , 0x07d094c1 , 0xb3e3e45a , 0xf45dd087 , 0x43491b55 , 0x1b59495b ] )
+  , Mont::unsafe_make( [ 0x935e98e8 , 0x561a261e , 0x2f9adf66 , 0xc26c231e , 0x80fe2976 , 0x05869623 , 0xfab7348b , 0x0dc1d053 ] )
+  , Mont::unsafe_make( [ 0xd625fb38 , 0xb62f9c11 , 0x27f8d4d3 , 0x1635295d , 0x0d486b4e , 0xe65b37f0 , 0x2aa63147 , 0x2aa2fe6b ] )
+  , Mont::unsafe_make( [ 0x6623373f , 0x1c275d93 , 0x31c6d939 , 0x7a482bcc , 0x719c3e5c , 0xc5e34823 , 0xa520bf16 , 0x13788a3d ] )
+  , Mont::unsafe_make( [ 0x144cc000 , 0xa4aac592 , 0x15b352bb , 0x96404fb5 , 0xa71bd62c , 0xabf3a4e6 , 0x8c8b369b , 0x13ef9b70 ] )
+  , Mont::unsafe_make( [ 0x2ad75715 , 0xd7472d7f , 0x62b9a85e , 0xc7965521 , 0x2e9add2a , 0x93332448 , 0x1ed96464 , 0x03a935f2 ] )
+  , Mont::unsafe_make( [ 0xb7418fd7 , 0xb87824e4 , 0xf37dce8e , 0xc984205b , 0xc812578f , 0xfe5633a2 , 0x9d3dd825 , 0x2d07d996 ] )
+  , Mont::unsafe_make( [ 0x21b4312a , 0xcad9112a , 0x937ae9ab , 0x5ffa9f44 , 0xfd4a9607 , 0xffeffead , 0x38acf193 , 0x07fba323 ] )
+  , Mont::unsafe_make( [ 0x792b620c , 0x5e05c863 , 0x02c78a50 , 0xc32914f6 , 0x14707304 , 0x6403d7d9 , 0xb566123e , 0x035ba1b8 ] )
+  , Mont::unsafe_make( [ 0xd1d8eed3 , 0xbdd5f73a , 0xbcdb20d1 , 0xf44eefb7 , 0x99ef40e8 , 0x53638667 , 0x8993bbeb , 0x17817394 ] )
+  , Mont::unsafe_make( [ 0x273151d1 , 0x7837f165 , 0xd77c3299 , 0x5690939d , 0xa25150ac , 0x4abeb8fe , 0xbbef4b80 , 0x294d4b23 ] )
+  , Mont::unsafe_make( [ 0xb8495026 , 0x1962ab6d , 0x17c76c42 , 0x24a353e1 , 0xbda6ce90 , 0x685c386e , 0x20d7d95d , 0x1a6b5899 ] )
+  , Mont::unsafe_make( [ 0x69a1dc47 , 0xc12f1038 , 0x1a3eb894 , 0x7664757d , 0x48abf2de , 0xfea54036 , 0x6310ef63 , 0x24589d29 ] )
+  , Mont::unsafe_make( [ 0x09f6b718 , 0x4973f7d5 , 0xa87bc8a7 , 0x9c853efc , 0xb36cfb5d , 0xa800abf2 , 0x5200abe1 , 0x09da20c7 ] )
+  , Mont::unsafe_make( [ 0x1ab2c289 , 0x4c52e404 , 0xe6c398d2 , 0xf68ae5fc , 0xe25da9c3 , 0x550b1ceb , 0x8e1d4d86 , 0x18719b93 ] )
+  , Mont::unsafe_make( [ 0x77528d72 , 0xaec08e08 , 0x97c5583a , 0x31f6d6d7 , 0x7963e80f , 0xe917ad66 , 0x6060c9be , 0x05167442 ] )
+  , Mont::unsafe_make( [ 0xe2a92830 , 0xb61f1052 , 0x059a4da7 , 0x50e7c54f , 0xff14e861 , 0xace1bed6 , 0x12590958 , 0x1ec5cd29 ] )
+  , Mont::unsafe_make( [ 0x43bf2a55 , 0xdf2fae9b , 0x03acb78f , 0xcf0df11f , 0x059fd533 , 0x2cb7c2a0 , 0x81189b71 , 0x2d13eb7d ] )
+  , Mont::unsafe_make( [ 0x42c54a96 , 0x380cf827 , 0xea4ca263 , 0xdcf77ec2 , 0x72397554 , 0xb50e636e , 0x03419ed8 , 0x301bb734 ] )
+  , Mont::unsafe_make( [ 0x34432b8b , 0xb44368f5 , 0x3b647421 , 0x791dbaeb , 0x5d02aceb , 0xb2408665 , 0x8fef3c1e , 0x1ddac4f2 ] )
+  , Mont::unsafe_make( [ 0x1960876e , 0xfcebc1c0 , 0x164659cb , 0x0b59a918 , 0x181e9365 , 0x73695ae6 , 0xa3889d74 , 0x0177933e ] )
+  , Mont::unsafe_make( [ 0xb26e7fc0 , 0x8a055a15 , 0xdad20969 , 0x0c0fb4dd , 0x3cc1e863 , 0xd70173b2 , 0xc7c75ae3 , 0x2b2fcec5 ] )
+  , Mont::unsafe_make( [ 0xfac71b5e , 0xbb932af7 , 0x9ccfaaa8 , 0xf3116587 , 0x158d25aa , 0xf1563d64 , 0x72f49fab , 0x0d35f35b ] )
+  , Mont::unsafe_make( [ 0xae82bfe5 , 0xe71670f1 , 0x9313ca79 , 0x57d13e88 , 0x32db67ac , 0xc925b6aa , 0xafa216cb , 0x078215ec ] )
+  , Mont::unsafe_make( [ 0x40efea6b , 0xf1f2119b , 0x979ad655 , 0xab197576 , 0x13439e45 , 0x82b9d0be , 0x237e3674 , 0x2d96d59b ] )
+  , Mont::unsafe_make( [ 0xb257d476 , 0x1a8bd6ef , 0xef1e0e50 , 0xf59a761a , 0x715ec4e0 , 0xc1700249 , 0x50f43c76 , 0x24fd5185 ] )
+  , Mont::unsafe_make( [ 0xea943427 , 0xeb09739f , 0x03d67c1d , 0x74d17807 , 0xbf1cd368 , 0x6d7682ae , 0x2d6e36fd , 0x119d262f ] )
+  , Mont::unsafe_make( [ 0x8ef65148 , 0xf7178a47 , 0xe708ea50 , 0x1cfb5345 , 0xda481029 , 0xa37391e5 , 0x5a7cfd38 , 0x1a9eb7f2 ] )
+  , Mont::unsafe_make( [ 0x9ff4c64f , 0xe32c43ac , 0x19342a25 , 0xb5ba03b1 , 0xe3a60035 , 0x6fada8ed , 0x4b9c6396 , 0x21e7a3a3 ] )
+  , Mont::unsafe_make( [ 0x32b6b94b , 0x0c708bf6 , 0x48b6a6b3 , 0xe763359a , 0x0b8c9d47 , 0x8044a5f5 , 0xd006a107 , 0x1ada59cb ] )
+  , Mont::unsafe_make( [ 0x99eb7cf1 , 0xf24caba8 , 0xc5b7f282 , 0x0abdfc46 , 0x22c28f81 , 0x8bfa9c21 , 0xb11f46a2 , 0x07adf411 ] )
+  , Mont::unsafe_make( [ 0x941adc2f , 0xa8ec660d , 0x94b3c71b , 0xa7afed14 , 0x170b4af0 , 0x5a44f735 , 0x9e673065 , 0x1ca9eb44 ] )
+  , Mont::unsafe_make( [ 0xdc6d21c3 , 0xf5208878 , 0xaed0b887 , 0x09b0ed35 , 0xb0c24e79 , 0xa4db809b , 0x2a709662 , 0x1af68274 ] )
+  , Mont::unsafe_make( [ 0x9c9e918f , 0xd9358dbd , 0x33b56f76 , 0xd8ac5a92 , 0x11da762f , 0x88d2682a , 0x7740f1f0 , 0x05604342 ] )
+  , Mont::unsafe_make( [ 0x556fbef9 , 0x37bb1dce , 0x5b04e46f , 0x882d8970 , 0x24fc906b , 0xde2b524e , 0x4dae87fa , 0x224c63f3 ] )
+  , Mont::unsafe_make( [ 0xfc72f78e , 0x58803ea9 , 0xb0442156 , 0x72501a10 , 0x30bd5222 , 0xac60f555 , 0xe97d4bfb , 0x058ac4ab ] )
+  , Mont::unsafe_make( [ 0xf27b31ad , 0xbba7f5f9 , 0xe251884a , 0x6298b14c , 0x931a0e0d , 0x6ad15a01 , 0x63d595b1 , 0x1b48ace5 ] )
+  , Mont::unsafe_make( [ 0xfb11ff47 , 0x1de43382 , 0x36b0698d , 0xe5d7ccf7 , 0x1227f478 , 0xe21aaeb2 , 0xc8a66659 , 0x0da75662 ] )
+  , Mont::unsafe_make( [ 0xffe9ee6b , 0xb30eb3d6 , 0x28c88290 , 0x53e0adab , 0x73b6d4e0 , 0xf0538c43 , 0x1a87cd4e , 0x1896fa4b ] )
+  , Mont::unsafe_make( [ 0x9393ba09 , 0x65d445d5 , 0x5b1f2ebc , 0x2132e3f7 , 0x8f592737 , 0x20fcce1b , 0x540ffe44 , 0x16cf3a6b ] )
+  , Mont::unsafe_make( [ 0x6816c9cd , 0x8d6e6de0 , 0x5d9ccc3c , 0x2798e007 , 0xe0345774 , 0x32bfa627 , 0xaadd5bb2 , 0x0390a7ff ] )
+  , Mont::unsafe_make( [ 0x1c53622d , 0xc4f03236 , 0x7e0033b7 , 0x1e571a60 , 0x4375eba2 , 0x350398c2 , 0xff661a01 , 0x0ca10710 ] )
+  , Mont::unsafe_make( [ 0x8a357cc0 , 0xec2bfe0c , 0xcbfa999d , 0x6735abf4 , 0xaf1908b4 , 0x41bab46d , 0x9ade6241 , 0x13976c42 ] )
+  , Mont::unsafe_make( [ 0x34367537 , 0xcba3526e , 0x08df2a40 , 0x06b69106 , 0xf0ff380d , 0xf70252d8 , 0x59174af6 , 0x04563b13 ] )
+  , Mont::unsafe_make( [ 0x52dd8bf2 , 0x79135712 , 0xd77fea0e , 0x1a5a98c9 , 0xb67115aa , 0x879dfa1c , 0x6e7ca9a0 , 0x053a930e ] )
+  , Mont::unsafe_make( [ 0x01d0242f , 0xab31c80e , 0xf42c4f90 , 0x08b4220e , 0x0538a295 , 0x0e364e7b , 0xf06c2f07 , 0x0a9eeef4 ] )
+  , Mont::unsafe_make( [ 0x66ec94e9 , 0x37c1e90f , 0x5bb9bc39 , 0xabbeaf0e , 0xd5452781 , 0x482ec638 , 0x2a25239c , 0x206f9eab ] )
+  , Mont::unsafe_make( [ 0x4b49693a , 0x4daf440c , 0x41086a39 , 0x06f6d45a , 0xc2ef53cb , 0x0a4cb8f2 , 0x4b633fc6 , 0x1fc5dabe ] )
+  , Mont::unsafe_make( [ 0xd1b2e68c , 0x31ed38b8 , 0x36419775 , 0x1286f8ca , 0x6766240e , 0x61feb467 , 0x206ba231 , 0x1d9c78ae ] )
+  , Mont::unsafe_make( [ 0x525270dc , 0xf76677a5 , 0x49f3590b , 0x43d1a192 , 0x812aaa77 , 0xc5db1f1f , 0x4417bb43 , 0x01900fcf ] )
+  , Mont::unsafe_make( [ 0x88629789 , 0x4cb34e2f , 0x98e4c6cf , 0x012f084b , 0x9715b506 , 0xb751e546 , 0x8fe4fc2b , 0x082d8483 ] )
+  , Mont::unsafe_make( [ 0xca5d51f7 , 0x9b4f9c86 , 0xf054c597 , 0x5f8b6d27 , 0xd1c0e4d2 , 0xe22fbb1e , 0x8f1e9617 , 0x07510feb ] )
+  , Mont::unsafe_make( [ 0xe7d9be73 , 0x186766a9 , 0x86e85a91 , 0x0ae40c6b , 0x3166b995 , 0x9a38eae0 , 0x42375ecc , 0x1f855eb8 ] )
+  , Mont::unsafe_make( [ 0xce792924 , 0x651174c3 , 0x90dec42b , 0xb2928217 , 0x6dd4669e , 0xf3b39c9d , 0xca242435 , 0x1cc7d352 ] )
+  , Mont::unsafe_make( [ 0x1339cecf , 0x2c11e31f , 0x8e2776ea , 0x4bf37933 , 0xdcaf92f8 , 0xf12a2b47 , 0x545a7582 , 0x0bb08bf7 ] )
+  , Mont::unsafe_make( [ 0x5882ccf4 , 0x92086dde , 0x12e109ac , 0xc4665999 , 0xd5201fa6 , 0xf63f45f8 , 0x414401d0 , 0x16f6fbcc ] )
+  , Mont::unsafe_make( [ 0x191ca5e7 , 0x20b128b5 , 0xafdc5124 , 0x7d2edbf8 , 0x9d663b12 , 0x2ce3a482 , 0x3c2bef8b , 0x30131981 ] )
+  , Mont::unsafe_make( [ 0x6982af69 , 0x627c175f , 0xd0f06f9e , 0xe1ffd623 , 0x92e9bc86 , 0xd48c4050 , 0xfc151aea , 0x07078529 ] )
+  , Mont::unsafe_make( [ 0xe213793b , 0xad698bf0 , 0xed040421 , 0x95437936 , 0x0ef2e43e , 0x99f5360c , 0x93a765eb , 0x301c966d ] )
+  , Mont::unsafe_make( [ 0x204c6eab , 0x73b8f282 , 0x63c0186f , 0x39e05360 , 0x497f90e3 , 0xa3aae50d , 0xb6e923e0 , 0x1c772bf9 ] )
+  , Mont::unsafe_make( [ 0x5bc14f31 , 0x906fa667 , 0xa1d17319 , 0x8db48478 , 0x2e0477e7 , 0x8ff0a8c9 , 0x5ee87c63 , 0x1afcaa5f ] )
+  , Mont::unsafe_make( [ 0x9d450096 , 0x9e945be9 , 0x492578ab , 0x13b3436b , 0xac32f6ed , 0xa79a78ca , 0xd1e13d07 , 0x1faa9a7d ] )
+  , Mont::unsafe_make( [ 0xd9e8e1ae , 0x0091e77d , 0x05fa40c6 , 0xfc5804d1 , 0x843bc278 , 0x66d6c473 , 0x15b9c2cc , 0x2f29278e ] )
+  , Mont::unsafe_make( [ 0x8533f979 , 0x63cf7dfa , 0x57f7f1f2 , 0xa9df6760 , 0xb6409f49 , 0x136f833c , 0x93e318e6 , 0x1e0bf5fe ] )
+  , Mont::unsafe_make( [ 0x37f56d1d , 0x789d5564 , 0x650f4451 , 0xa152158b , 0xa2248d6f , 0xa4441476 , 0xcb4c32b3 , 0x15da4b71 ] )
+  , Mont::unsafe_make( [ 0x1880af4a , 0x73a90796 , 0xa8a915c8 , 0xe131a884 , 0xef0ccb7c , 0x27060f1a , 0xefef6275 , 0x0ff10327 ] )
+  , Mont::unsafe_make( [ 0x6c24ba04 , 0x6f2d3567 , 0xd44b919d , 0x5411db29 , 0xd336c056 , 0x21469eaf , 0xf75e6b66 , 0x191d7327 ] )
+  , Mont::unsafe_make( [ 0x5de2498f , 0x343054e8 , 0x3d66f5ff , 0x63129465 , 0xb729e78e , 0xbc3c94bb , 0x0796696d , 0x0230281b ] )
+  , Mont::unsafe_make( [ 0x0d51b81d , 0xf560dd13 , 0x259b7535 , 0x3427164a , 0x35451789 , 0xfbcb254a , 0x29d5b464 , 0x0c88fcb6 ] )
+  , Mont::unsafe_make( [ 0xa39932d2 , 0xca0cacac , 0x1ac6c585 , 0x28536914 , 0x495a0fcd , 0x3f04d624 , 0x1bba0424 , 0x30581383 ] )
+  , Mont::unsafe_make( [ 0xa5a24687 , 0x4050e396 , 0x2011bd44 , 0x9c0a1534 , 0xfe2033af , 0x1316e75f , 0xd5ca71ff , 0x12305d0d ] )
+  , Mont::unsafe_make( [ 0xe1dbc5bd , 0x7e0dd2f9 , 0xee955a7d , 0x2c669e0b , 0x126455fd , 0x28811f99 , 0x5379360e , 0x0fa5e7bd ] )
+  , Mont::unsafe_make( [ 0x33c4f36f , 0xb44bb2f9 , 0xac2204fa , 0x55f337d0 , 0x99662592 , 0x9908463f , 0x4ee2ce3c , 0x2e32abc9 ] )
+  , Mont::unsafe_make( [ 0xddc22809 , 0x05deae4e , 0x03094da5 , 0x34eb5e4d , 0x7cdc4c3a , 0x840dfd72 , 0x10860e9d , 0x10b779db ] )
+  , Mont::unsafe_make( [ 0xff4f7994 , 0xd6830a62 , 0x838a2b1c , 0x1aec40d1 , 0xd9e896fb , 0xc0f1de6c , 0xe04ccc58 , 0x0c056ea2 ] )
+  , Mont::unsafe_make( [ 0xa18dc9fb , 0x146dbab0 , 0xb64c4ace , 0x31691a9d , 0x44d032c3 , 0xd0f10c95 , 0xd96d6ebb , 0x1a514941 ] )
+  , Mont::unsafe_make( [ 0xcbefc3bf , 0x78fad63f , 0xab763aa9 , 0x3f9730a2 , 0xc54cc565 , 0xc62e8e7e , 0x9405fadf , 0x006b7645 ] )
+  , Mont::unsafe_make( [ 0x4f320380 , 0x34f347fd , 0xebdc8dbb , 0xa6ec882c , 0xdb1d8cef , 0x53e85659 , 0x93948515 , 0x213d42c2 ] )
+  , Mont::unsafe_make( [ 0x405a41ab , 0x02379d33 , 0x944f4dc7 , 0x11c41284 , 0x24f67326 , 0x16fcd900 , 0xc4cf79e6 , 0x0872c520 ] )
+  , Mont::unsafe_make( [ 0xec690cbd , 0xb8d61dbc , 0xcc974984 , 0x97534499 , 0x64535a4b , 0x53a09a02 , 0x9b294d43 , 0x0ba37c20 ] )
+  , Mont::unsafe_make( [ 0x8fd47fdc , 0xda382504 , 0x46333209 , 0x003c4e61 , 0xce381a25 , 0xf5d04a10 , 0xa6651594 , 0x11c2532a ] )
+  , Mont::unsafe_make( [ 0x527eb82e , 0xcd994e5f , 0xf0a2a6c5 , 0x4ab652ec , 0x150eaefb , 0x3e25c657 , 0x6f741e90 , 0x18a1024c ] )
+  , Mont::unsafe_make( [ 0x06f71e03 , 0xb6748bb6 , 0x61240a06 , 0x93429761 , 0xdbc69b46 , 0x9fc7ae17 , 0xe5eb16f2 , 0x16008b0b ] )
+  , Mont::unsafe_make( [ 0xe1051fa1 , 0xd82a4f50 , 0x83b527ac , 0x09f2f601 , 0xdb89db16 , 0x9673a801 , 0xfa2c57b4 , 0x09959bca ] )
+  , Mont::unsafe_make( [ 0x2a0a8ea8 , 0xe9fec26d , 0x86a2ead4 , 0x40c88ce3 , 0x81423c3d , 0xf359ba26 , 0x80ec052b , 0x2b3e7683 ] )
+  , Mont::unsafe_make( [ 0xcd6c78ad , 0xf217fc28 , 0x383cbd14 , 0x1adae8a7 , 0x65e02240 , 0x30a1f4d7 , 0xa8b4945d , 0x186a323e ] )
+  , Mont::unsafe_make( [ 0xfabbe662 , 0x96b6bad1 , 0xcd2b9ed7 , 0x83a56749 , 0xf6d7d495 , 0xdc8189c2 , 0xc3db32b5 , 0x21815f17 ] )
+  , Mont::unsafe_make( [ 0x838f350c , 0x9065d6a8 , 0x747dacfa , 0x6a431428 , 0x672658eb , 0xad433942 , 0x8ec004c3 , 0x22ae31ee ] )
+  , Mont::unsafe_make( [ 0xe4243b99 , 0xb0da90fd , 0xc3039b9b , 0x44129d70 , 0x578fbf70 , 0xfdf48683 , 0xb9a48956 , 0x0af9c3a9 ] )
+  , Mont::unsafe_make( [ 0x935806a0 , 0xd2cf374d , 0x5969ced2 , 0x1caec307 , 0x4c73bfe1 , 0x5e73515e , 0x4f718856 , 0x2de22425 ] )
+  , Mont::unsafe_make( [ 0x53ae59d8 , 0xb69dd83b , 0xbac4a0ea , 0x5cd17897 , 0x0aa883fc , 0x51b7e644 , 0xe2d0f199 , 0x14d12a19 ] )
+  , Mont::unsafe_make( [ 0x394ad6c1 , 0x9278222d , 0x20462c74 , 0x67d71a08 , 0xe20ad66b , 0xf2661a7c , 0x7af5444c , 0x10975ccf ] )
+  , Mont::unsafe_make( [ 0x3a6815ae , 0x6cf85b4e , 0x91ae7526 , 0xbd7eb683 , 0xab8da96e , 0xbcfd036c , 0xf2fd4496 , 0x15e490ec ] )
+  , Mont::unsafe_make( [ 0xfa291244 , 0x8bbb4d96 , 0x68e9c98c , 0xf15fd04c , 0xf5ebe5a8 , 0xebb46e23 , 0x719c5b90 , 0x2db3766c ] )
+  , Mont::unsafe_make( [ 0x97bb165e , 0x9aa06898 , 0x05e52bf3 , 0xbbe5052a , 0x974a4996 , 0xd4315d09 , 0x201aa1b4 , 0x07307097 ] )
+  , Mont::unsafe_make( [ 0x8ae6a784 , 0xdc62a984 , 0x93980735 , 0x2fb39105 , 0x44befe3a , 0x3bebbe17 , 0x79218cba , 0x1492d9d0 ] )
+  , Mont::unsafe_make( [ 0x7ea0b4cd , 0x53f16a37 , 0x73055ce3 , 0xefcfeb2b , 0x5df78410 , 0xd8dbfb97 , 0xdec0dcfc , 0x15d9a15a ] )
+  , Mont::unsafe_make( [ 0x5f13c164 , 0xb615a714 , 0xd21b5be8 , 0x66be8cfd , 0x204c8afe , 0x9c36fc4d , 0x9787e563 , 0x21de8e08 ] )
+  , Mont::unsafe_make( [ 0xc6c6a6d6 , 0x30d033a4 , 0xfefce5af , 0x20a26c8d , 0xc6f618b4 , 0x61527fd5 , 0x25f2973a , 0x29fd234d ] )
+  , Mont::unsafe_make( [ 0x3b7f1c33 , 0x982ed76e , 0x4421e3a5 , 0x533ab6b5 , 0xa3da085c , 0xe6013d15 , 0xef79659b , 0x00380938 ] )
+  , Mont::unsafe_make( [ 0x08170571 , 0x2a57d220 , 0xc65d4912 , 0xdc0ec2de , 0xede9c519 , 0x6a4b2b22 , 0x28ef4289 , 0x12f1c415 ] )
+  , Mont::unsafe_make( [ 0x845f3450 , 0xd31a99ef , 0x2b1274ea , 0x74ea6471 , 0x7c432f83 , 0xc6a73f30 , 0x6cd0cdff , 0x16e65f6f ] )
+  , Mont::unsafe_make( [ 0x8fd97a27 , 0xfcaf6f9e , 0xcd797e30 , 0x0f705f7c , 0x411fae12 , 0x94d1a66c , 0x9135a549 , 0x07ebf27b ] )
+  , Mont::unsafe_make( [ 0xcb13d22e , 0x2620317f , 0x16e9af4c , 0x84d983b1 , 0x938af18b , 0x6ee62c70 , 0x24f84d67 , 0x1ee84e3c ] )
+  , Mont::unsafe_make( [ 0xc91b2a3c , 0x309492de , 0xcac9327c , 0x32229e99 , 0xe594a040 , 0x36f1b84b , 0x4b3f9b87 , 0x13845f14 ] )
+  , Mont::unsafe_make( [ 0xf8a345c9 , 0x6f37931e , 0x4f4f4625 , 0xff79c2f2 , 0xa8d1d763 , 0x043194a2 , 0xd09b9aad , 0x128c41d5 ] )
+  , Mont::unsafe_make( [ 0x36460d28 , 0xd1a53b74 , 0x945fb419 , 0xd2a43df7 , 0x885fb433 , 0xd1ef1e0a , 0xce856b69 , 0x0c4a0594 ] )
+  , Mont::unsafe_make( [ 0xfe38160e , 0x1b38b21c , 0x3bf64774 , 0xd33fe969 , 0x53f1fc1d , 0x7845b8ea , 0xbbad9d7b , 0x0ea23505 ] )
+  , Mont::unsafe_make( [ 0x4c96d688 , 0x6a1eb982 , 0x3572fbca , 0xe8e0f020 , 0xc5238444 , 0x80b045a0 , 0xe87146e0 , 0x2e3446cc ] )
+  , Mont::unsafe_make( [ 0x9fd73878 , 0x3d54d006 , 0x8df049e8 , 0x958aad18 , 0x643bb99f , 0xd6ba65bd , 0x0f345800 , 0x0839ce6b ] )
+  , Mont::unsafe_make( [ 0x7c1472cb , 0x5bc1124e , 0x46244567 , 0x37049fbb , 0x4b3ef996 , 0x9a10e6fe , 0x36208045 , 0x243f08a3 ] )
+  , Mont::unsafe_make( [ 0xb6e45551 , 0xca518882 , 0x0680e445 , 0x0a9e506b , 0xdf0e3d6c , 0x52828775 , 0xa6432df8 , 0x19e31fbe ] )
+  , Mont::unsafe_make( [ 0x7953e25a , 0x2fcf7413 , 0xb9012e5b , 0x34029204 , 0x438a6e71 , 0xc93ff825 , 0x97889c2f , 0x17bfc8cc ] )
+  , Mont::unsafe_make( [ 0x008f7a22 , 0x18122390 , 0x9e2c9361 , 0x35784b01 , 0x47201dab , 0xae75c5b5 , 0xb518b609 , 0x0fcc3acb ] )
+  , Mont::unsafe_make( [ 0x9cf3d2e4 , 0xee60bedc , 0xf825b85b , 0xd805d003 , 0x474043ad , 0x5fb3c09d , 0x027b52d2 , 0x0eb63d4c ] )
+  , Mont::unsafe_make( [ 0xdb143137 , 0xb666ce2b , 0x6b0e0999 , 0x17ff2b4b , 0x75a61041 , 0x1d96a716 , 0x9cb2219e , 0x0097fb46 ] )
+  , Mont::unsafe_make( [ 0x80e41dbe , 0x0c8a2aac , 0x7dbb8f84 , 0x8d0450b7 , 0x2f6be3c7 , 0xc5166056 , 0x8a044512 , 0x08a24621 ] )
+  , Mont::unsafe_make( [ 0x9c91cc1e , 0x1d5bd5c7 , 0x7ef6c19a , 0x571e7efd , 0xfdad3d69 , 0x929f697b , 0xd8774059 , 0x14c1eb96 ] )
+  , Mont::unsafe_make( [ 0x811fa46a , 0x1b7b89a4 , 0xa5eac252 , 0x69e5eed0 , 0xbf1c974c , 0x3beb6e68 , 0x02628683 , 0x2c4426d7 ] )
+  , Mont::unsafe_make( [ 0xaef61ff3 , 0xc2b949bc , 0xdbaa1fa4 , 0x9ed2e6a7 , 0x94c23883 , 0x684fe91f , 0xe10a41ea , 0x10839346 ] )
+  , Mont::unsafe_make( [ 0x96311101 , 0x7534327d , 0x22dbc8b7 , 0x5ffbde4b , 0x20fbb397 , 0x9c0581bb , 0x148d12d8 , 0x0be5302a ] )
+  , Mont::unsafe_make( [ 0xa63334dd , 0x2a7841b3 , 0xb4a454b2 , 0x47947c06 , 0xe62f73d8 , 0xd3391510 , 0xd1c0b67b , 0x098aa1a2 ] )
+  , Mont::unsafe_make( [ 0xae59c0ad , 0xa76c3c4b , 0x5c00cc93 , 0x60604252 , 0x7036cf7a , 0x85c3ed70 , 0x4ab1dfde , 0x2d9ede30 ] )
+  , Mont::unsafe_make( [ 0xa339b5a6 , 0xcc6e590c , 0x096eb94b , 0x6c1dfb50 , 0x5d76c2fa , 0x0943c0e5 , 0x3587a275 , 0x057d40df ] )
+  , Mont::unsafe_make( [ 0xa0825ebb , 0x9e9123fa , 0x50ca212a , 0xec5ecb84 , 0x2900ebdb , 0x66c3198e , 0x4eb6f8d6 , 0x2af4819a ] )
+  , Mont::unsafe_make( [ 0x2ce74029 , 0xfd274cf0 , 0xbd8f653c , 0x0cccff86 , 0x7ae78791 , 0xa55c6cd5 , 0x9393a6c6 , 0x0c1b2ba9 ] )
+  , Mont::unsafe_make( [ 0xc7424e37 , 0xec534eef , 0xdce73d7a , 0x872cbccd , 0x90451dc9 , 0x14302fb0 , 0x0ae2dee4 , 0x0cde1fac ] )
+  , Mont::unsafe_make( [ 0x05585d01 , 0x06660a97 , 0x7c74f7db , 0x20588169 , 0x80d56cb0 , 0x0921f985 , 0x84bb91eb , 0x14a5d329 ] )
+  , Mont::unsafe_make( [ 0x48a58483 , 0x144fd22a , 0xd7661881 , 0x710bb351 , 0x90650cd2 , 0x016065c3 , 0xc9f8e6ae , 0x055c8d20 ] )
+  , Mont::unsafe_make( [ 0xc77f5246 , 0x5d67d990 , 0x1f2cc3df , 0xf1c99813 , 0x8e76e432 , 0x4bb67397 , 0x4615e9ce , 0x288fa6ea ] )
+  , Mont::unsafe_make( [ 0xb6a65c94 , 0x62af8c9b , 0x71be93a1 , 0x2c2686ef , 0x9aee5ab6 , 0x0786acbf , 0x65713016 , 0x282b5603 ] )
+  , Mont::unsafe_make( [ 0xa8bd9961 , 0xacf7a96b , 0xb5cb3807 , 0x2a01f838 , 0xa9de4d33 , 0x2543218c , 0xdb3d95ef , 0x124c1676 ] )
+  , Mont::unsafe_make( [ 0x5df29801 , 0xd8ac3a65 , 0x03374a91 , 0x955056b9 , 0x23db9f5a , 0x8c698548 , 0x0fb6a417 , 0x24185662 ] )
+  , Mont::unsafe_make( [ 0xc350a0de , 0xdd3a04cb , 0xce685569 , 0x5330fed4 , 0x26d3b01e , 0x314e0fc0 , 0xddbbb55c , 0x03268e91 ] )
+  , Mont::unsafe_make( [ 0x43d5485e , 0xcb16a012 , 0x4fbf553b , 0x723eb065 , 0xe2dc25e3 , 0x76e5a1d0 , 0x81c465fb , 0x1d16828f ] )
+  , Mont::unsafe_make( [ 0x1722af1b , 0xf10850c3 , 0x2d68548b , 0xfe7ef35d , 0xd5f8eddd , 0x942a6b84 , 0xa270e6ae , 0x0dfdd5e1 ] )
+  , Mont::unsafe_make( [ 0x0ec526d6 , 0x146af534 , 0x5dd5fcff , 0x630b20cf , 0x25a40c00 , 0x2406eab1 , 0xc6c6cb84 , 0x22dc2dbe ] )
+  , Mont::unsafe_make( [ 0x01fd9668 , 0xb642b69f , 0xf9e55f43 , 0x9d38205d , 0x8c213478 , 0x8cdad3e6 , 0xb8a25e83 , 0x211d547a ] )
+  , Mont::unsafe_make( [ 0xe037ab15 , 0xfadf5181 , 0x6fb86cd9 , 0xaadf3517 , 0x4acc59e6 , 0xd1d58fec , 0x89e30c4a , 0x282bfa2a ] )
+  , Mont::unsafe_make( [ 0xf54e779a , 0x052c1c51 , 0x2bc931e8 , 0xa01fef35 , 0x579e221f , 0xe73f3345 , 0xab222fe5 , 0x0a3ef084 ] )
+  , Mont::unsafe_make( [ 0x4eae931f , 0x8e5115a4 , 0x77dfc4d5 , 0xa0c9e1af , 0x0bfcc386 , 0x1d89532f , 0xae3cb34c , 0x0b759272 ] )
+  , Mont::unsafe_make( [ 0x29324fc6 , 0x3f5abf7e , 0x0a2b8aef , 0x47afcbbb , 0x5188b14e , 0xd1c6e63e , 0x822e9f7a , 0x21308d35 ] )
+  , Mont::unsafe_make( [ 0x66e5a057 , 0xd3c22817 , 0xc1a499c3 , 0xed21b10a , 0x0629b74d , 0x8c87d7b6 , 0x0729064a , 0x084fcc1c ] )
+  , Mont::unsafe_make( [ 0x9d6c7858 , 0x5558eff1 , 0x2b95eea4 , 0x4ddd0442 , 0xe1229bfb , 0x3cd8904e , 0x31d1a8ab , 0x09796e3e ] )
+  , Mont::unsafe_make( [ 0x6a3895a5 , 0xe8ed9f58 , 0xeb70976e , 0x8e26d715 , 0x37ca0e5b , 0x8a1d9ebf , 0xa5561804 , 0x09e24554 ] )
+  , Mont::unsafe_make( [ 0xa0cf782c , 0xc0825972 , 0xe479df98 , 0x6f6fc932 , 0x07302778 , 0xe501317f , 0xe3251b5b , 0x0231fd1a ] )
+  , Mont::unsafe_make( [ 0x62e79ccb , 0x77e4637f , 0x14f969ec , 0x89c9ac33 , 0xc1c4823c , 0x9bbdf77f , 0xd900810d , 0x21019bea ] )
+  , Mont::unsafe_make( [ 0x59b36337 , 0x706070d2 , 0xea7a630a , 0x0f8f6453 , 0xcb70128e , 0xb9b7bc5f , 0xa26d6cbd , 0x16f3626f ] )
+  , Mont::unsafe_make( [ 0xb6c09781 , 0xbb37fe0f , 0x5a7a7593 , 0xde6931f8 , 0xb3d244d4 , 0x59a0d798 , 0x4b7910f7 , 0x0ac42fe9 ] )
+  , Mont::unsafe_make( [ 0x17b31bed , 0x40049d91 , 0xc01f6b9f , 0xd0ebb6ee , 0xa9779def , 0x3bddcbd3 , 0x860a2f87 , 0x126fec80 ] )
+  , Mont::unsafe_make( [ 0x5150d862 , 0x41a43890 , 0x55328991 , 0x1b15c103 , 0x56f824fe , 0x458c4843 , 0x67d8cf43 , 0x28a09a04 ] )
+  , Mont::unsafe_make( [ 0x997e8e06 , 0x48c740cb , 0xb6bcd190 , 0x616c3bd1 , 0x95c75bba , 0xc5201047 , 0x1e235653 , 0x1b70854a ] )
+  , Mont::unsafe_make( [ 0x597505d5 , 0x840a0ab8 , 0x1d85336c , 0xd03c4e51 , 0xe134a9dc , 0xffbd2bc9 , 0x717891e1 , 0x27e19d82 ] )
+  , Mont::unsafe_make( [ 0x25886439 , 0xa0dc23a7 , 0x8928c20b , 0xb6903cd9 , 0xd5005232 , 0x8084d07f , 0x944de259 , 0x0fb6dd39 ] )
+  , Mont::unsafe_make( [ 0xa4701909 , 0xd5ad7f66 , 0x0c1c798e , 0x48e27a7a , 0x78978381 , 0x4f8b0720 , 0x56429d83 , 0x13573c0f ] )
+  , Mont::unsafe_make( [ 0x93bef748 , 0xb5e3db4c , 0xf77f800b , 0x09e1b26e , 0x7febf687 , 0xf13f4d5d , 0x6d6da354 , 0x0f9aed72 ] )
+  , Mont::unsafe_make( [ 0x12d07502 , 0x2b9cb6a9 , 0x89406795 , 0x7c08b62f , 0xeddee1d7 , 0xacfacf04 , 0x8e5f7605 , 0x2c6dab23 ] )
+  , Mont::unsafe_make( [ 0x178729f8 , 0xd6afaffc , 0x0a8f3b21 , 0xe1118de8 , 0xd0a98bc4 , 0x4975db63 , 0x2be12ac4 , 0x2ac13156 ] )
+  , Mont::unsafe_make( [ 0xeaea0ab9 , 0x4bf2b7c2 , 0xed19a977 , 0x1476565f , 0x64ee9bae , 0x94056758 , 0x4b83694f , 0x20b6a798 ] )
+  , Mont::unsafe_make( [ 0x79cf9a4b , 0xb3f6b8d8 , 0x28d4d02a , 0x0075ebe9 , 0xa8fb7bd7 , 0x189834e6 , 0xe261732c , 0x19106afa ] )
+  , Mont::unsafe_make( [ 0x55a4a739 , 0x054c4cba , 0x964345cd , 0x06205647 , 0x9ee681c7 , 0xda067901 , 0xe1ead483 , 0x1dccaafd ] )
+  , Mont::unsafe_make( [ 0x60799810 , 0x9ee8c11e , 0x0d51bd74 , 0xee012167 , 0x9ca0cf0d , 0xc572a296 , 0x5f945657 , 0x1185bcd3 ] )
+  , Mont::unsafe_make( [ 0x030a2d26 , 0xec0a7f6f , 0x164e5a54 , 0x7e011255 , 0x085baad9 , 0xe0a0d688 , 0xcd91d1e9 , 0x1dec9939 ] )
+  , Mont::unsafe_make( [ 0xb181315f , 0x4372d1ba , 0x14112ba9 , 0x99346ce5 , 0x9b75d224 , 0x838a1481 , 0x72d9b042 , 0x2d525308 ] )
+  , Mont::unsafe_make( [ 0xebd9aaf9 , 0xcf08f5b1 , 0x7f60176c , 0x21ad8b65 , 0xaecd96de , 0xd9e3f778 , 0x8a993044 , 0x2633df8f ] )
+  , Mont::unsafe_make( [ 0xe136315b , 0x0b8fe47a , 0xccd3947d , 0xe7762ecd , 0x6b4df00f , 0xbeec837a , 0x8d4ac7c0 , 0x3010cc61 ] )
+  , Mont::unsafe_make( [ 0xf88253a2 , 0xe31b73b1 , 0x9f43247c , 0x916f0847 , 0x9f71a327 , 0x740b83a5 , 0xaf5a7c02 , 0x21cd8391 ] )
+  , Mont::unsafe_make( [ 0x3a1d451d , 0x8f00926f , 0x21c128b0 , 0xc18cd653 , 0x0679e92f , 0x9d3536af , 0x27bf907a , 0x135778ae ] )
+  , Mont::unsafe_make( [ 0xb61e0324 , 0x10a418ed , 0xcc5dc1e0 , 0x2c841c62 , 0xfad70f13 , 0xf006ff28 , 0xcf08ffba , 0x0113343d ] )
+  , Mont::unsafe_make( [ 0x8a30f32b , 0x0df6e705 , 0x9d496784 , 0x354136a9 , 0xf3328d18 , 0x1e098a3a , 0xdb4dbadd , 0x0c01cedb ] )
+  , Mont::unsafe_make( [ 0x85d0c6b3 , 0xd2742801 , 0x8bfeb8df , 0xbfaed4e1 , 0x4e84edc3 , 0x225ff7d9 , 0xc3d27234 , 0x10c38f29 ] )
+  , Mont::unsafe_make( [ 0x1a4b363c , 0x21477bb4 , 0x15ad9bf2 , 0xdf997d29 , 0x0c860445 , 0xd0a9f2db , 0x633f077e , 0x0f4e46bd ] )
+  , Mont::unsafe_make( [ 0x7388aaa6 , 0xd8997c68 , 0x03986b7c , 0xd99abf9e , 0xa5c5d0b0 , 0x6cdb3236 , 0x5f2d34a1 , 0x2766829a ] )
+  , Mont::unsafe_make( [ 0xc8872726 , 0x2c316d9b , 0x6b152d06 , 0x6e7dc780 , 0x64d2943e , 0x355419c5 , 0xa9e62b67 , 0x03b5c144 ] )
+  , Mont::unsafe_make( [ 0xf8af06c9 , 0x6b789b3f , 0x5c9f2cab , 0x8f1cdeb0 , 0xf21e875c , 0xcc74961a , 0x4c6a2f44 , 0x22f54bac ] )
+  , Mont::unsafe_make( [ 0x1be9b23c , 0xd1a71933 , 0xddbfa66b , 0xf24881bc , 0xfb7989ca , 0x39620ad1 , 0xafb8ab4d , 0x21fbb271 ] )
+  , Mont::unsafe_make( [ 0xf0f24ff0 , 0x5980a2ad , 0x43059b18 , 0xc12d0a03 , 0x5a863691 , 0x58b5f0ac , 0x4c0b6ee9 , 0x21cb891e ] )
+  , Mont::unsafe_make( [ 0x0899484c , 0xef942d26 , 0xfee28553 , 0x46776c32 , 0xdf1148c0 , 0xd184f26e , 0xe0656026 , 0x00513251 ] )
+  , Mont::unsafe_make( [ 0x6bf5b99a , 0x841f19f6 , 0x855fa5a0 , 0x9d4e06cb , 0xc696be68 , 0x6801c2bb , 0x279b1a94 , 0x2fd09093 ] )
+  , Mont::unsafe_make( [ 0x795a806b , 0x6d1f8cde , 0xe19275c9 , 0x7cbfc264 , 0x58e467c5 , 0x48bdd41b , 0xb1017502 , 0x2cc50881 ] )
+  , Mont::unsafe_make( [ 0x256f2b8c , 0x507edd0f , 0xfe91f24c , 0x6c220474 , 0xedc64187 , 0xf88a93eb , 0x53fb3235 , 0x0ee43f2f ] )
+  , Mont::unsafe_make( [ 0xe7c7b97a , 0x5d6b540c , 0x3f204c43 , 0x1ba964f0 , 0xd86b804a , 0x1f1f28e2 , 0x9f3771cc , 0x2cf61107 ] )
+  , Mont::unsafe_make( [ 0x26a1ce02 , 0xa9e40cc2 , 0x853c1cb9 , 0x397a2ea6 , 0xe6198d3a , 0x2fb50c9f , 0x3d85cff3 , 0x11259f70 ] )
+  , Mont::unsafe_make( [ 0xcc0cb504 , 0xaf31c938 , 0x26bd07eb , 0x01faa78a , 0xa69d83e4 , 0x87be8fe7 , 0x744f5289 , 0x273308de ] )
+  , Mont::unsafe_make( [ 0x75471478 , 0xd0b516f1 , 0x0ba015e2 , 0x8263dc2e , 0x3ff36dbb , 0x2c1d675c , 0xe005e0d4 , 0x01de5efe ] )
+  , Mont::unsafe_make( [ 0xeb28442b , 0x2bba1a0c , 0x8b807298 , 0x772ba4b0 , 0xbda9b24a , 0xe7028c4b , 0xd1ffbd93 , 0x16d0dad0 ] )
+  , Mont::unsafe_make( [ 0x6d3d201d , 0xc5c1fa08 , 0x69dea3e9 , 0x5ab93a5e , 0x31fcccd4 , 0xb34f9743 , 0x31927a92 , 0x0997d494 ] )
+  , Mont::unsafe_make( [ 0x9fb5570d , 0x19a8524b , 0xad20e38f , 0x4a756dfe , 0x9af8a733 , 0x07462379 , 0x9f73d8cf , 0x0c506748 ] )
+  , Mont::unsafe_make( [ 0xab59d6c2 , 0x20a60a35 , 0x3f4bfa3b , 0xe2c7a7b1 , 0x72632450 , 0x04de28b4 , 0xfe7e0534 , 0x013b769c ] )
+  , Mont::unsafe_make( [ 0x1c37712b , 0xa22dc235 , 0x36149168 , 0x4f4f3555 , 0xcbd25843 , 0x85d8720a , 0x8acfbc1b , 0x2c006ba2 ] )
+  , Mont::unsafe_make( [ 0x9750c903 , 0x37754b25 , 0x3aef6bd6 , 0x68a317d5 , 0x6153c05c , 0x03bf9688 , 0xccc53e82 , 0x2c3b357b ] )
+  , Mont::unsafe_make( [ 0xf169060d , 0xaf071514 , 0x51bb3695 , 0x281bce14 , 0x1bc9712a , 0x7395485b , 0x7abbeae6 , 0x054f10ac ] )
+  , Mont::unsafe_make( [ 0xe3c5ab59 , 0x9405362f , 0x0619a1c2 , 0x411d17eb , 0x092c0bf6 , 0xc5727708 , 0xcc71a462 , 0x170ba9f0 ] )
+  , Mont::unsafe_make( [ 0x1622879e , 0xce59af71 , 0xc8a145f9 , 0xc263f196 , 0xc4ca886f , 0x76924819 , 0x0b71c75a , 0x12b368ad ] )
+  , Mont::unsafe_make( [ 0xa1699680 , 0xd2a59db7 , 0xd9e26784 , 0x304acdd1 , 0xe920af82 , 0x3dc35ae8 , 0x297fd79c , 0x2a650a4b ] )
+  , Mont::unsafe_make( [ 0x71558b46 , 0xebb8957c , 0x478338d5 , 0x764a150f , 0xce151e2e , 0x21485f1b , 0x87221d97 , 0x06d4ee7b ] )
+  , Mont::unsafe_make( [ 0xf940b0cc , 0x10c91ef6 , 0xcb0708fa , 0x4182a353 , 0x528fbfcc , 0x04d0eef8 , 0xdf247fb1 , 0x2ec6b66c ] )
+  , Mont::unsafe_make( [ 0x77055a5c , 0x8dbe3bdc , 0x4c0e3e25 , 0xc594e4f4 , 0xe3ee2bf5 , 0xacb3b3b9 , 0x25253ceb , 0x04f000d9 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// circomlib's optimization whatever
+pub const CONST_S: [Mont; 1740] = 
+  [ Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x27094aef , 0xfe8a0cdd , 0x9ae16094 , 0xd9eb8659 , 0xe4f5e44a , 0x13d1be32 , 0x62e8ee9c , 0x158d84af ] )
+  , Mont::unsafe_make( [ 0xf7c602dc , 0x30db488d , 0x679f7cbe , 0x15d7afdf , 0x0f29787f , 0x88fcbc4e , 0x9f475dfc , 0x26271a30 ] )
+  , Mont::unsafe_make( [ 0x6e6fe5c3 , 0x90b2784a , 0x9808dbb3 , 0x51416553 , 0x3d3ac2a9 , 0xd8ea51ac , 0x0be7b74e , 0x1d406c04 ] )
+  , Mont::unsafe_make( [ 0x03f569b1 , 0x6c42761f , 0x667131e1 , 0x53dda1e5 , 0x168b6865 , 0x9d842d29 , 0x39295e4c , 0x13aef5f8 ] )
+  , Mont::unsafe_make( [ 0x3c0fdf57 , 0xf41af39e , 0xa601f1ad , 0x748fc921 , 0x9e236aa8 , 0x2007f8db , 0x9ddfdc15 , 0x26013792 ] )
+  , Mont::unsafe_make( [ 0xe4672a3f , 0xd9e19383 , 0x2178b036 , 0x56e4dc0f , 0x177e46fd , 0xaea5539d , 0x79a442d4 , 0x2138e930 ] )
+  , Mont::unsafe_make( [ 0xf0b02277 , 0x4785c16c , 0xc27e5c45 , 0xb7055bc4 , 0x84edfca3 , 0xe2f67efe , 0x457d5772 , 0x15e1cfa3 ] )
+  , Mont::unsafe_make( [ 0x5e05b401 , 0x7ed8670a , 0x2b599dcd , 0x6032e807 , 0xa4086354 , 0x1f885835 , 0x3b016071 , 0x25d6c21e ] )
+  , Mont::unsafe_make( [ 0x2fc663d4 , 0x41b44af8 , 0x392e6e68 , 0x6c580968 , 0xf7214df0 , 0x7061edc7 , 0x32ad6996 , 0x24577750 ] )
+  , Mont::unsafe_make( [ 0xa5dc4a52 , 0x054c147d , 0xa96699e3 , 0x7ab8060b , 0xfb5c7f5b , 0x2fdc5fc3 , 0xf27ad4de , 0x06bc33e1 ] )
+  , Mont::unsafe_make( [ 0xbf91a965 , 0xc846aa43 , 0x7df3b5fb , 0x832bc147 , 0xafe9e2bb , 0xe13fe8cf , 0x2e685559 , 0x1b7a7e00 ] )
+  , Mont::unsafe_make( [ 0x6e07ca50 , 0x0b2fe356 , 0x3698d881 , 0xe799320c , 0x09f0463e , 0x63a6ba96 , 0x6294e7bd , 0x033a7686 ] )
+  , Mont::unsafe_make( [ 0x1f0c1330 , 0x4053a2ce , 0xd9b51475 , 0x06d6823b , 0x1a620eb2 , 0x165f0406 , 0x0bd1fca8 , 0x14312e11 ] )
+  , Mont::unsafe_make( [ 0x9aeefb14 , 0x232e0fe4 , 0x88ef82b5 , 0xcb48c73a , 0xc949009d , 0x06ea6815 , 0xcac2c93a , 0x12f00f6f ] )
+  , Mont::unsafe_make( [ 0x2a983106 , 0xf40d64d8 , 0xd1872a0b , 0x531e3c2c , 0x146d448a , 0x7fef0eee , 0x492c224f , 0x0bd967b6 ] )
+  , Mont::unsafe_make( [ 0xfda2a9bb , 0xa8c65ff8 , 0xb6de6ec9 , 0x7d0b23c3 , 0xfdf30e74 , 0xe43dfaca , 0x67b28168 , 0x2fe44a8a ] )
+  , Mont::unsafe_make( [ 0xd300d90b , 0x7c20f152 , 0xfc975c04 , 0xea908b7a , 0x02e3ef41 , 0xf063de25 , 0xd5e34ff1 , 0x1bf4501c ] )
+  , Mont::unsafe_make( [ 0x5e75bf43 , 0xe7bc5656 , 0xfbc633a0 , 0x3b2d0bd4 , 0x62e74a81 , 0x3448ed08 , 0xfe54a662 , 0x2e8a2ed0 ] )
+  , Mont::unsafe_make( [ 0x3e8d7a53 , 0x0ef866ac , 0xf9bb02dd , 0xfca2fa6c , 0xa9d622ac , 0xce96f550 , 0xd50afc2f , 0x2cb983e9 ] )
+  , Mont::unsafe_make( [ 0x19004993 , 0xbddaa0ea , 0xa75ea525 , 0xb288553d , 0x868fbc24 , 0xe56a81db , 0x2590cac0 , 0x019829df ] )
+  , Mont::unsafe_make( [ 0x1eaebb4f , 0xf75db2c9 , 0xc8ce7b20 , 0x7437045b , 0x477348b3 , 0x35c16c41 , 0xd62fa8aa , 0x17417f0f ] )
+  , Mont::unsafe_make( [ 0x339f1f4c , 0xd8dffeb3 , 0x8fc9a6d8 , 0xf04c9aa2 , 0xdbc10e96 , 0x8459c13f , 0xfa83c91b , 0x215c5efa ] )
+  , Mont::unsafe_make( [ 0xa5b0fb4c , 0x43e836fe , 0xcc51b92e , 0xb44283b1 , 0x80f9fdeb , 0x988c9ebf , 0x1e729334 , 0x2eb91422 ] )
+  , Mont::unsafe_make( [ 0xc00c0144 , 0x90744504 , 0xa31fc73d , 0xa29ef0cc , 0x219b59d9 , 0x734a132c , 0xa81ad5b5 , 0x0c9927e4 ] )
+  , Mont::unsafe_make( [ 0xa52577a7 , 0x46825937 , 0x53c2ef8e , 0x1e9c0384 , 0x161953aa , 0x17e31599 , 0xd48b31be , 0x12128259 ] )
+  , Mont::unsafe_make( [ 0x53793c7e , 0xf7db1baf , 0x0e4769df , 0xef2b9d71 , 0x2f2b9401 , 0x0bb7cab7 , 0xa48c8736 , 0x14ffd439 ] )
+  , Mont::unsafe_make( [ 0x44b3a392 , 0xce03870a , 0xe3399649 , 0xf405a79e , 0x92803cff , 0x2775b376 , 0xd1eaf6be , 0x1ac8c9c6 ] )
+  , Mont::unsafe_make( [ 0x534cc3f3 , 0xb6dc1d42 , 0x13c01bc9 , 0x994159d1 , 0x38e40e03 , 0x82172512 , 0xbee78ce8 , 0x1a7359de ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x45a36ad7 , 0xec26e2fc , 0x7ad75546 , 0x5f16506d , 0xe435b785 , 0x9fdd111f , 0x1967c8dc , 0x0f2ee45e ] )
+  , Mont::unsafe_make( [ 0xfedb3983 , 0x427da0d9 , 0x265c82ea , 0xb46d6fc2 , 0x3a210a8e , 0x22fce653 , 0xc32d6bf1 , 0x276d9e5c ] )
+  , Mont::unsafe_make( [ 0xb9250b65 , 0xbeae2569 , 0x13a416fe , 0x3a227d7a , 0xc4aaaf9e , 0x9654e2cf , 0x256b61ae , 0x0e34a334 ] )
+  , Mont::unsafe_make( [ 0xe1324d42 , 0x92770e9b , 0x022c006c , 0x023c02dc , 0x30a2edf6 , 0xe110249c , 0x7f1f26c4 , 0x085b214f ] )
+  , Mont::unsafe_make( [ 0xb98e12d6 , 0xd880f497 , 0xe97e1757 , 0xa3b05e9c , 0x90f25573 , 0x4b790010 , 0x0a23addf , 0x0e764ccb ] )
+  , Mont::unsafe_make( [ 0x981ef8de , 0xa7ad68f3 , 0xdaf06450 , 0x7e1665d3 , 0x5a1a64f8 , 0x88b6100c , 0xfe80fdf8 , 0x0b189afa ] )
+  , Mont::unsafe_make( [ 0x17780415 , 0x2f25593e , 0xd2bb4084 , 0xbbc1d775 , 0xaeddbd4b , 0x25990fb8 , 0x13e60b50 , 0x2389db69 ] )
+  , Mont::unsafe_make( [ 0x843b88b5 , 0xc975cd23 , 0xafc23e2f , 0xae9d152c , 0x3471f86b , 0xbdfb6695 , 0xc9230ea1 , 0x14d760ed ] )
+  , Mont::unsafe_make( [ 0x7b623e2a , 0x1f2cc7a7 , 0xd892b393 , 0x8cdcfc29 , 0x571bb63e , 0x6a5955c6 , 0x0ea1d8f8 , 0x2077255f ] )
+  , Mont::unsafe_make( [ 0x7f534833 , 0x692fb487 , 0x9934dca6 , 0xc8c6b839 , 0x96055df0 , 0x5f5c576f , 0x0db77aef , 0x3019dd36 ] )
+  , Mont::unsafe_make( [ 0xabf10de5 , 0x99f4141f , 0x9d743576 , 0xe9d3fc1f , 0xfd455355 , 0x3ac04132 , 0x7a1b52c8 , 0x1f390c11 ] )
+  , Mont::unsafe_make( [ 0xb02a8cfb , 0x2693dccf , 0x2b006086 , 0x356ad745 , 0xa5640fb2 , 0x5adc993f , 0x7299d8f6 , 0x0266489b ] )
+  , Mont::unsafe_make( [ 0x7a90224b , 0x59eae4d6 , 0x9183d54a , 0x017068cc , 0xd024be89 , 0xf48c7107 , 0x8c5d5b4e , 0x19cd3cd5 ] )
+  , Mont::unsafe_make( [ 0x72ad8e37 , 0x958289a8 , 0xb17f4e80 , 0x16f4a10c , 0xdfe9abe4 , 0x2a15c53b , 0x30a5624b , 0x2efb20fb ] )
+  , Mont::unsafe_make( [ 0x46f7bd0b , 0xbe91e557 , 0x02cdb3b0 , 0x2f058982 , 0x59095d9a , 0xb88c676b , 0x7ce5c614 , 0x07095237 ] )
+  , Mont::unsafe_make( [ 0x6265b34d , 0x971e1905 , 0xa22c0de6 , 0x92f773ab , 0x03300133 , 0x58db1cfa , 0x5263976f , 0x13801e8b ] )
+  , Mont::unsafe_make( [ 0x9a370ed6 , 0x26450c7e , 0xf75a785e , 0xad9539f7 , 0xb4ac479a , 0x0d4ed604 , 0x9926103d , 0x07955372 ] )
+  , Mont::unsafe_make( [ 0x46c96b06 , 0x6126a7a9 , 0x819f79c4 , 0xea09828f , 0x2f798f41 , 0x4f8e3ee2 , 0xb4ad86e4 , 0x17e239f1 ] )
+  , Mont::unsafe_make( [ 0xcc8af13a , 0xc2040844 , 0xe12dc8c5 , 0x36c5bfae , 0x266abf97 , 0x669445a1 , 0xb18aa844 , 0x2238f874 ] )
+  , Mont::unsafe_make( [ 0xada5a253 , 0xce6383a2 , 0x4ea1ad68 , 0x3c2f209c , 0x597fcae1 , 0xaa2eff32 , 0x9f78ebce , 0x1e02d3d1 ] )
+  , Mont::unsafe_make( [ 0xbceeab57 , 0xc7ce327a , 0xc3d97a77 , 0x16a951e5 , 0xee8ea17f , 0x8c6f8f07 , 0xb442dfa1 , 0x1146dd4b ] )
+  , Mont::unsafe_make( [ 0x56d1fd25 , 0x2f4f179e , 0xcebfa55c , 0xecaecb3a , 0x001468ca , 0x7d5574d8 , 0x07ce8f68 , 0x24cf1111 ] )
+  , Mont::unsafe_make( [ 0x949a1229 , 0xae5eea2b , 0x53e7dc3e , 0x34ed118f , 0x23816389 , 0xf2734693 , 0x1b1781a9 , 0x1f1f12b1 ] )
+  , Mont::unsafe_make( [ 0xf04a95a4 , 0xa584479a , 0xdca5a554 , 0xe64b2f2a , 0xa654e81f , 0xb4651c96 , 0xc49b6c3d , 0x157998c2 ] )
+  , Mont::unsafe_make( [ 0xfece9f4c , 0x8df16338 , 0x97b81928 , 0x3c5bb26d , 0x961f44ea , 0x296439a3 , 0xefd57f25 , 0x103c43f9 ] )
+  , Mont::unsafe_make( [ 0xdcfce5fd , 0xb81475a9 , 0x99c0b2a4 , 0x76497c77 , 0x5a02d68a , 0x84da7a00 , 0x8c0940ed , 0x11d117c9 ] )
+  , Mont::unsafe_make( [ 0x571ebda4 , 0x530febc1 , 0x3003153e , 0xa0476711 , 0x7ae68871 , 0x870cbf8f , 0xb2ab7774 , 0x2372564f ] )
+  , Mont::unsafe_make( [ 0xf91bda28 , 0xe45b4926 , 0xc16f3f90 , 0xf5ec7526 , 0x54ea5d7d , 0x0faa0f6b , 0x051a5ae2 , 0x22a2cfd6 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x2819563c , 0x914b088f , 0xf3338f6c , 0x5a23db6e , 0x19e70b1e , 0xed6ee1f0 , 0x2bc0cd58 , 0x226512d2 ] )
+  , Mont::unsafe_make( [ 0x042bb631 , 0x6dd4e937 , 0xe8751df0 , 0x8060c095 , 0xaf54bd7c , 0x14831047 , 0xafa8ddca , 0x09c695d4 ] )
+  , Mont::unsafe_make( [ 0xb6ddd534 , 0x22ab90f2 , 0x6c535500 , 0xea843434 , 0x9900eff2 , 0x6e5eba66 , 0x3b175581 , 0x14595d91 ] )
+  , Mont::unsafe_make( [ 0x71f99724 , 0xfb7be78a , 0x674b5838 , 0x1043a875 , 0xdaa6cc16 , 0x5bfe278b , 0x2c74a720 , 0x21ff037a ] )
+  , Mont::unsafe_make( [ 0x94aa933d , 0xb457a72b , 0xc2d8e140 , 0x691d0dba , 0x1fa13d80 , 0xd20a526d , 0xbcec561b , 0x0f543553 ] )
+  , Mont::unsafe_make( [ 0x5cdc1dbf , 0xadf95f71 , 0xdebe90be , 0x96d0af3a , 0x63234afb , 0x23200d8d , 0x2d8c7aaf , 0x00cf7950 ] )
+  , Mont::unsafe_make( [ 0x67319eab , 0x17af1197 , 0xb6e31eb0 , 0x9d052565 , 0xd9a44afa , 0xf069f58b , 0x83f37223 , 0x0dfd6b36 ] )
+  , Mont::unsafe_make( [ 0x0ce51867 , 0xd1404d79 , 0x01d5b95b , 0x79eb52ea , 0x26138f50 , 0x60ef7819 , 0xb0fdaa9b , 0x2189b77f ] )
+  , Mont::unsafe_make( [ 0x6f0aade9 , 0xcbaf210f , 0xbb9e27ff , 0x7aafe814 , 0x27b7b230 , 0x5494d9ea , 0x4faa1de8 , 0x1b03c193 ] )
+  , Mont::unsafe_make( [ 0x838780c2 , 0x8c1ace68 , 0xc600eb7e , 0x3bbbdd33 , 0x5601beb2 , 0x9d8709c9 , 0xe96ce41e , 0x128e902e ] )
+  , Mont::unsafe_make( [ 0x0660a660 , 0x082019d3 , 0x15119a9a , 0xfa16fdcb , 0x3584f2aa , 0x6d95ed48 , 0x2e8a6d28 , 0x232c7101 ] )
+  , Mont::unsafe_make( [ 0xfe5e4ccd , 0x91fbbe37 , 0x1e9608df , 0x3a29c4b5 , 0x3dced88a , 0x4f0a9a29 , 0x58899dc7 , 0x20308866 ] )
+  , Mont::unsafe_make( [ 0x3ce3f28b , 0xd2587f8c , 0x4ae73670 , 0x5ce4ddfe , 0x6cb442ec , 0xa54f3c7c , 0x9e25b438 , 0x14747301 ] )
+  , Mont::unsafe_make( [ 0xc2043f3a , 0xb805be0c , 0xf12f0572 , 0x169a3206 , 0x810f6bcf , 0x611edba9 , 0xdacd9105 , 0x2a337c44 ] )
+  , Mont::unsafe_make( [ 0x8539b8b5 , 0xcf351060 , 0x9bc4a4a7 , 0x104cdf68 , 0xd173e80f , 0x647d98d6 , 0x017ad99e , 0x0b125cf0 ] )
+  , Mont::unsafe_make( [ 0x68f252d9 , 0x87736bfe , 0xb80f8e2f , 0x7217eedf , 0xa8caa322 , 0x271c2ccf , 0x34e7df40 , 0x1dcaf843 ] )
+  , Mont::unsafe_make( [ 0x72363f4e , 0x6964532a , 0xbb3fff44 , 0x17f27058 , 0x60831272 , 0x597461d2 , 0xcf4d3671 , 0x1e615be7 ] )
+  , Mont::unsafe_make( [ 0xb843ff86 , 0xa6970ac8 , 0x7935ee5e , 0xa3de3d26 , 0x337a9e54 , 0x576ef118 , 0x67612129 , 0x1c255540 ] )
+  , Mont::unsafe_make( [ 0xb5085032 , 0x2197aced , 0xcb9839f7 , 0xaa975cf1 , 0xd8658bb4 , 0x52fb9db8 , 0x49530bab , 0x19d54d20 ] )
+  , Mont::unsafe_make( [ 0xf34f4af5 , 0x9ec172fa , 0x89c2ec6e , 0xfa54f110 , 0xc8c6d169 , 0x3cfb0476 , 0x7e7c5511 , 0x0d19b6ff ] )
+  , Mont::unsafe_make( [ 0x2ec3c6e7 , 0x794c8531 , 0xf2cef047 , 0xd52c1794 , 0x48f9a21c , 0x188ba190 , 0xbe4b1841 , 0x26cde50c ] )
+  , Mont::unsafe_make( [ 0xe73b0c42 , 0xf40737e8 , 0x4c446584 , 0xedf5361c , 0x606235c2 , 0x810fa277 , 0xc75f19a1 , 0x1d4f0612 ] )
+  , Mont::unsafe_make( [ 0xf8d6973b , 0x1985980c , 0xe8edb778 , 0x8bef6c78 , 0x54785010 , 0x0a15c71f , 0xd755f448 , 0x0fb2fc72 ] )
+  , Mont::unsafe_make( [ 0x8bddde7c , 0x7dd2f723 , 0xd82923ef , 0x20f3af9c , 0xbf25f529 , 0x88566858 , 0x9563e4a8 , 0x2db276ef ] )
+  , Mont::unsafe_make( [ 0x3e2f5efc , 0x60ea2a8f , 0x3158a285 , 0x0e0d91ee , 0x465b9c44 , 0xf773f447 , 0xe5d58e20 , 0x125cdd78 ] )
+  , Mont::unsafe_make( [ 0xbf930fd0 , 0x8e85113c , 0x9edd74fb , 0x26305b15 , 0xc1edc385 , 0x275f82db , 0x58d805be , 0x06338c3c ] )
+  , Mont::unsafe_make( [ 0x01d9d954 , 0xeecea220 , 0x7ae9f19c , 0x266b5419 , 0xd5421e07 , 0x58caa3fe , 0x3da3e33e , 0x15d13b80 ] )
+  , Mont::unsafe_make( [ 0x238f3b3e , 0xad85971e , 0xf0872ea3 , 0x3e94037e , 0x082ef718 , 0xfdd9a322 , 0x4d0c0c73 , 0x0fb041d7 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x627118e0 , 0x86542e74 , 0x9e3be627 , 0xebf0124b , 0xb3200342 , 0x24cb1e0c , 0x123e974d , 0x2f95dc4e ] )
+  , Mont::unsafe_make( [ 0x288d9f10 , 0x6bad0e40 , 0x5556d42e , 0x8432c3e3 , 0x2a5a8002 , 0xd38bd421 , 0xfdf31c9a , 0x14ac1dd9 ] )
+  , Mont::unsafe_make( [ 0x2ffc10c2 , 0xf34eee57 , 0xc5f6dcaf , 0xab7c57e0 , 0x51d75809 , 0xe8825e29 , 0xf610b416 , 0x01d35aa4 ] )
+  , Mont::unsafe_make( [ 0x4b7cfe56 , 0x08c87d80 , 0x0f02ba85 , 0x81171ee2 , 0xc543492b , 0xe731fb0d , 0x2aaef795 , 0x1d103e70 ] )
+  , Mont::unsafe_make( [ 0x0ebe9969 , 0xbb2ff572 , 0xe7ddc58b , 0xf775e165 , 0xba47d671 , 0x270d654b , 0x7b152c7f , 0x10cef01d ] )
+  , Mont::unsafe_make( [ 0xa3d9cbc9 , 0xe0180b1d , 0x5373db76 , 0x2e0f5e2f , 0xca64c743 , 0xe43dda5d , 0xe492fbfc , 0x20e0d2b3 ] )
+  , Mont::unsafe_make( [ 0x84223a8c , 0xd4a02242 , 0x0992cb8a , 0xeca4672e , 0x00b65f74 , 0xe5a80106 , 0xd8f300cb , 0x2668bf07 ] )
+  , Mont::unsafe_make( [ 0x498e39bf , 0x26541a83 , 0x7dad99f6 , 0xb907b87c , 0x00938c42 , 0xb5320a56 , 0x177db557 , 0x02641c8f ] )
+  , Mont::unsafe_make( [ 0xdada4c05 , 0x0be3fdfa , 0xd72c3ba3 , 0xeb8c7f44 , 0x01f87efc , 0xb21646f4 , 0x053b3a24 , 0x0dda4bf6 ] )
+  , Mont::unsafe_make( [ 0x4124442c , 0xb26c6f5c , 0x93ae82dc , 0x56d5a81f , 0xa4f0a415 , 0xab233839 , 0x2ea09e7b , 0x0e52ab4d ] )
+  , Mont::unsafe_make( [ 0x394a9d14 , 0xa12ca835 , 0xc3112a7e , 0x79f87659 , 0x4c7d7220 , 0x4d460110 , 0x6159aa53 , 0x0c938f1a ] )
+  , Mont::unsafe_make( [ 0x4da79381 , 0xe37a404a , 0x2481537a , 0x060e1c84 , 0x5dd02e00 , 0x8986b814 , 0xc0e502f2 , 0x222910af ] )
+  , Mont::unsafe_make( [ 0xe747dc8b , 0x92eaff85 , 0xe170329c , 0xf5cbaec1 , 0x92fd13f3 , 0x399f4e92 , 0x36e25a9e , 0x1f245bfb ] )
+  , Mont::unsafe_make( [ 0xb45b7480 , 0x32c82e52 , 0x0d411bb2 , 0x02899730 , 0xa394f0e6 , 0xae56c36a , 0x7b78e5f9 , 0x23fa1901 ] )
+  , Mont::unsafe_make( [ 0x4b4bb8aa , 0x3e794cce , 0x24a695f8 , 0xc709847d , 0x117fd801 , 0x6d20accc , 0xdf4c7087 , 0x0c63c207 ] )
+  , Mont::unsafe_make( [ 0x5a29e0f0 , 0x35178228 , 0x0a221b00 , 0x151b45a9 , 0x753c4e18 , 0x919eaca0 , 0xcdb26628 , 0x2fc3adf1 ] )
+  , Mont::unsafe_make( [ 0xedc1cda8 , 0x0f1c6d5e , 0x78a8a243 , 0x9ed99c67 , 0xa5ebc07f , 0xb37c246f , 0xfdeb9862 , 0x080364e9 ] )
+  , Mont::unsafe_make( [ 0xdb921271 , 0xb296fb70 , 0xce0ecfdb , 0x16a0f419 , 0xa506a00e , 0x30a5c2d0 , 0x2abbe43b , 0x09deaa88 ] )
+  , Mont::unsafe_make( [ 0x1c41d63e , 0x5e87e475 , 0xcaa3214e , 0x74448c9e , 0x71570c9d , 0x54b22f75 , 0x209b265a , 0x0a742e3b ] )
+  , Mont::unsafe_make( [ 0xfdc83ecf , 0x353a979a , 0x4a57b0e5 , 0xe2be6df0 , 0x067c8232 , 0x1d9db9da , 0x964564bf , 0x16914c93 ] )
+  , Mont::unsafe_make( [ 0xc746a6d5 , 0xb849b47f , 0xef86285e , 0x75479429 , 0x2f4fdd95 , 0x18c8dad7 , 0x6b6df391 , 0x07bb6a7f ] )
+  , Mont::unsafe_make( [ 0x24f92450 , 0xb0841dd8 , 0x3f477bfb , 0x497cc308 , 0x63cc3d14 , 0xd644644d , 0x747288da , 0x1f2e6133 ] )
+  , Mont::unsafe_make( [ 0xf9639add , 0x2c8cf670 , 0x431b5c55 , 0xe83320c4 , 0x20072706 , 0x01f2cc35 , 0xe42c03ab , 0x00f8215b ] )
+  , Mont::unsafe_make( [ 0xfb1e94f4 , 0x9a763b52 , 0x2c78f488 , 0xc96d0451 , 0xebdcfd51 , 0x4e1caee4 , 0x426930fe , 0x2291e59c ] )
+  , Mont::unsafe_make( [ 0x0db4174a , 0x595992a0 , 0xdc9c1617 , 0x4e19ba7b , 0x2df93c4f , 0x4eb53309 , 0x52e0bfd8 , 0x188b2eca ] )
+  , Mont::unsafe_make( [ 0xdf03b081 , 0x66e29fed , 0x890c8940 , 0xe5958a60 , 0x13e16fc4 , 0xbad5029b , 0x2521c65d , 0x2045e252 ] )
+  , Mont::unsafe_make( [ 0x076bc64f , 0xf18de04d , 0x8e4e01c7 , 0xe59d7fe9 , 0x63fb6615 , 0x59900d4e , 0xa6061e47 , 0x05117fe1 ] )
+  , Mont::unsafe_make( [ 0x6ab6f8de , 0x1cc9379c , 0xca3c55eb , 0xb78d9675 , 0x31f11509 , 0x2675bffa , 0xe68822d7 , 0x0b801666 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x4e896cb6 , 0x9223bd0a , 0xc3aa5f10 , 0x10b8f1d9 , 0xfcd7ae95 , 0x44ec48c8 , 0xd223ba0b , 0x1a8131a2 ] )
+  , Mont::unsafe_make( [ 0xe19c5b92 , 0x9027949e , 0xca56d4bc , 0x43e25abb , 0x939e69bf , 0x46755232 , 0x060b50cb , 0x2a38e274 ] )
+  , Mont::unsafe_make( [ 0xa47614ee , 0x287527fb , 0xebf1cfa2 , 0xbedceb04 , 0x39a1c691 , 0x712e1c23 , 0xa1ef8439 , 0x0f3671d1 ] )
+  , Mont::unsafe_make( [ 0x74087b06 , 0x11f4328b , 0x5a65ca02 , 0xe66cdfbf , 0x39479aad , 0xf6840556 , 0x4b126669 , 0x126d61c8 ] )
+  , Mont::unsafe_make( [ 0xf6601e74 , 0x4cd6ef1b , 0xb269a947 , 0x7e91067a , 0xf0e7425d , 0x8a4fb2a0 , 0xf3c06f5f , 0x01f80fe1 ] )
+  , Mont::unsafe_make( [ 0x9368c152 , 0xdaf2abb2 , 0x9dc9a9e4 , 0x7a63c0ae , 0x8a825f03 , 0x669dfc9a , 0xd0961398 , 0x1ef3cb57 ] )
+  , Mont::unsafe_make( [ 0x523c6b7e , 0x279a7c52 , 0xa8a00248 , 0x8a1ce102 , 0xffb822e9 , 0x1c375a8a , 0x5b3cce89 , 0x07a1a418 ] )
+  , Mont::unsafe_make( [ 0xc36dd8c3 , 0xed7949b5 , 0x15b89e7e , 0x5298ab4f , 0x5ed05339 , 0x5ff626c7 , 0xbabc1a38 , 0x12089085 ] )
+  , Mont::unsafe_make( [ 0xcc1dfb83 , 0xef1682d7 , 0xa306ea36 , 0xff4ef154 , 0xbfa289a5 , 0x2cd73bf3 , 0xf84f59b1 , 0x2a227d2b ] )
+  , Mont::unsafe_make( [ 0x13b1cfd2 , 0x7a04e042 , 0x80dc6961 , 0xe54a45be , 0xdaf30ece , 0x75cfc7bc , 0x85764d98 , 0x268279f1 ] )
+  , Mont::unsafe_make( [ 0x6ffe0846 , 0x67c6e9de , 0x1ce92fba , 0xe2295e23 , 0x68052895 , 0x967a8bb7 , 0xca2c3001 , 0x0e18dd60 ] )
+  , Mont::unsafe_make( [ 0x789966b6 , 0xfebf609c , 0x391879d5 , 0x28adda42 , 0xc744364d , 0x525d2757 , 0xef291782 , 0x19086eda ] )
+  , Mont::unsafe_make( [ 0x78b13d5e , 0xa65db709 , 0xe81f3c05 , 0x18049b98 , 0xbe102dd6 , 0x6d11dee8 , 0x34be4a55 , 0x2db09db1 ] )
+  , Mont::unsafe_make( [ 0xc0d8b30d , 0xebec36c7 , 0xac494178 , 0x3548d514 , 0x16c35155 , 0xaad4d27a , 0x5aa929a0 , 0x0cc56795 ] )
+  , Mont::unsafe_make( [ 0xfd30af0d , 0x415da152 , 0x8147a63b , 0xb44db57e , 0x600a9483 , 0x5423325c , 0xdb391b7a , 0x0117e9f1 ] )
+  , Mont::unsafe_make( [ 0x34d68496 , 0x1ed4696a , 0x8b3bb4ac , 0x858c85bf , 0x07a35f68 , 0x20c1e70b , 0x4d1fac8c , 0x1b76471e ] )
+  , Mont::unsafe_make( [ 0xde6ce1b7 , 0xe93fa4ce , 0x13a98c6c , 0xf190d662 , 0x5e97ddf5 , 0x00e3218e , 0x5ca191e7 , 0x09b71a47 ] )
+  , Mont::unsafe_make( [ 0xd08c5f90 , 0x31bcac0f , 0x3481569e , 0x83c24faf , 0x0d26bc68 , 0x9a86986e , 0x8544862c , 0x1f425f33 ] )
+  , Mont::unsafe_make( [ 0x22104521 , 0xf4b718fd , 0x6ff824dc , 0xb04df3e8 , 0x2c44bd3b , 0x5ded85f3 , 0x0e44550d , 0x095a57a1 ] )
+  , Mont::unsafe_make( [ 0x59998841 , 0x302514d9 , 0xc8838148 , 0x404f3851 , 0x8e6c2df8 , 0x86ab17b4 , 0x05b82326 , 0x10c349d0 ] )
+  , Mont::unsafe_make( [ 0x5cbc83c9 , 0x10f5bc17 , 0x928ae276 , 0xba3bd276 , 0xc023dcfa , 0xf1a6d21d , 0x3306e7b2 , 0x11cd8c3a ] )
+  , Mont::unsafe_make( [ 0xc757c78d , 0xcc0406b8 , 0xfd0c477d , 0x5a14bc94 , 0xf006e3ed , 0x314afe0c , 0xf85979fe , 0x1c6fd9b1 ] )
+  , Mont::unsafe_make( [ 0x84e3153d , 0x38f329a9 , 0xf9287d5a , 0x56f20e1b , 0xbc3b62f7 , 0xe9376a41 , 0x79719a7e , 0x182e2fc5 ] )
+  , Mont::unsafe_make( [ 0xe6752e64 , 0x9474782b , 0x5ea3defb , 0x4cc442b1 , 0x88fa70e7 , 0x84b7407a , 0xbc0f2f78 , 0x304bcdfc ] )
+  , Mont::unsafe_make( [ 0x64a596f1 , 0xaf62395b , 0x36da5b14 , 0xd9304108 , 0x14f01b8b , 0xb398ef94 , 0x8a228271 , 0x20f44924 ] )
+  , Mont::unsafe_make( [ 0x750a9cc7 , 0x4264a537 , 0xa7f1431f , 0xbe0c0707 , 0x91d106bf , 0xa42ea1cf , 0x97e2da2c , 0x1d0d30c4 ] )
+  , Mont::unsafe_make( [ 0x88b98789 , 0x038b9196 , 0x7c196556 , 0xacc1c7ee , 0xf3e44be5 , 0x76782239 , 0x25c23e27 , 0x13e9e4ed ] )
+  , Mont::unsafe_make( [ 0xa97606d8 , 0xe160d187 , 0x0c550c06 , 0x799954b8 , 0x265fd4e8 , 0x7b985ec1 , 0xcf0c57f5 , 0x06f2120c ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x104e34b6 , 0x1747b761 , 0x32872df3 , 0xdc79c08a , 0xdf92aa1e , 0x0a58923f , 0x12edf9f8 , 0x0d66affb ] )
+  , Mont::unsafe_make( [ 0x883980f6 , 0x4dc472c8 , 0x3e085a36 , 0xbc72cd89 , 0xe12950d4 , 0x3556b3bb , 0xe908cbfc , 0x166d6845 ] )
+  , Mont::unsafe_make( [ 0x1bd33908 , 0x95196664 , 0x7b509f18 , 0x693e9201 , 0x44828041 , 0xa07f0c06 , 0x0d5fde05 , 0x085ca284 ] )
+  , Mont::unsafe_make( [ 0xf39639a9 , 0xeb02e1bf , 0x5719cb58 , 0x895c2706 , 0x0c52deb2 , 0x07ad754c , 0x138884c7 , 0x2183a589 ] )
+  , Mont::unsafe_make( [ 0x2feb09ca , 0x19f17a60 , 0xe61da301 , 0xb0454d66 , 0xa7c72ed2 , 0xecae8e35 , 0x8a18d82d , 0x2a765093 ] )
+  , Mont::unsafe_make( [ 0x1597dab1 , 0x418c1cd4 , 0xe46b9a4b , 0x6c72ed27 , 0xb8a6b067 , 0x628aa5bd , 0xccab89c7 , 0x1f93409e ] )
+  , Mont::unsafe_make( [ 0xca0e3b52 , 0xa0c3c9d3 , 0x5d70f552 , 0xa94b4b4b , 0x8625b1a4 , 0x6ef1bb40 , 0x01f4c5d0 , 0x1c26d6e2 ] )
+  , Mont::unsafe_make( [ 0x44ecdd05 , 0x23bf5ac3 , 0xc83031d0 , 0x941a52b0 , 0x52e1f811 , 0x8fa9f91e , 0x918b7b34 , 0x1d8f6400 ] )
+  , Mont::unsafe_make( [ 0xb7f23a34 , 0x0a416036 , 0x184d8d96 , 0x1badc1ca , 0xe22ef315 , 0x7995c0bf , 0x213fbc35 , 0x273c0f32 ] )
+  , Mont::unsafe_make( [ 0xb78467a9 , 0x77b96729 , 0x23a805a3 , 0x138cd3e8 , 0x96c5a5ca , 0x0bf9125f , 0x0de80182 , 0x0aad1ce1 ] )
+  , Mont::unsafe_make( [ 0xbcaee56b , 0xb4f12145 , 0x0ed19b2e , 0xbc0d84bc , 0xf4ec493c , 0x525c10ef , 0x8b15dfab , 0x1954dd37 ] )
+  , Mont::unsafe_make( [ 0x9062048c , 0x27a032a8 , 0x1258c99a , 0x07746e6f , 0x8072d65a , 0x74c91a10 , 0xf0fa1200 , 0x0f94d547 ] )
+  , Mont::unsafe_make( [ 0x7cbf7b9f , 0xeecf6800 , 0x9b32685e , 0x684a25f7 , 0x2b8c8fe8 , 0x816294c9 , 0x255b7beb , 0x028e309e ] )
+  , Mont::unsafe_make( [ 0x75fa6ea9 , 0x94ba248f , 0x8b6ea1a1 , 0xd3c1af2c , 0x3c5778f7 , 0x15786e0b , 0x676921af , 0x05b0c4a6 ] )
+  , Mont::unsafe_make( [ 0xac128314 , 0x3303b3c5 , 0xc2e90c71 , 0x893dfe18 , 0x31e1b49a , 0xf3c7c407 , 0x82aab70d , 0x0919c845 ] )
+  , Mont::unsafe_make( [ 0x79ee64f6 , 0x9ff3912d , 0x30d9030e , 0x551937d7 , 0xa2f17381 , 0x4a57811d , 0x6bcdd9be , 0x261c8a54 ] )
+  , Mont::unsafe_make( [ 0x0e2d7e0a , 0x196c1c50 , 0x241e798a , 0x376277c1 , 0xcc43adce , 0xe1ea5f0b , 0x629cb7ab , 0x1847658f ] )
+  , Mont::unsafe_make( [ 0x30e4380d , 0x2c944cb2 , 0x4996c177 , 0xc08c5f64 , 0xea99aeb3 , 0x8b98b850 , 0x6d1dabb4 , 0x25b5bdaa ] )
+  , Mont::unsafe_make( [ 0xd18fce57 , 0x2c35e7e0 , 0xb49ebdc5 , 0xf645c444 , 0x1cb53814 , 0x5ca333bc , 0x3f32b7a0 , 0x265e07e8 ] )
+  , Mont::unsafe_make( [ 0x265c8f7a , 0x048f19d0 , 0xcf86bbf9 , 0x3d35dfd2 , 0x4b0db229 , 0xc898041d , 0xcbc525f0 , 0x04801b5d ] )
+  , Mont::unsafe_make( [ 0x3fc80951 , 0xf619ee77 , 0x4daa4a23 , 0x73f8de1a , 0x34f6eca6 , 0x8e4ce7a1 , 0xa35dbb76 , 0x0836e959 ] )
+  , Mont::unsafe_make( [ 0xf1859a8b , 0x8815eb08 , 0xd4a6205c , 0x3a1ed99b , 0x31de7cc7 , 0x7ef86192 , 0x8b123551 , 0x11e62f8b ] )
+  , Mont::unsafe_make( [ 0xa8450cb9 , 0x095702a6 , 0xc28e313a , 0x121bc7aa , 0x3073257b , 0x45d67c73 , 0x654de8dd , 0x19efd92c ] )
+  , Mont::unsafe_make( [ 0x701e931a , 0xcb0dd209 , 0xb834f95b , 0x2c269451 , 0x310085d5 , 0x1b7b569e , 0xffb26357 , 0x29b4883c ] )
+  , Mont::unsafe_make( [ 0x31810e44 , 0x92c21c61 , 0xcaa387c5 , 0x5e2b2976 , 0xb0f29af9 , 0x89a59729 , 0x8e79600e , 0x118d1a0e ] )
+  , Mont::unsafe_make( [ 0xeef382bd , 0xea291a00 , 0x47e99498 , 0x0b67927c , 0xad55210b , 0x06f525bc , 0x7c2bd90c , 0x161cd21d ] )
+  , Mont::unsafe_make( [ 0x62fb24d9 , 0x1793699b , 0x10f0964d , 0x05a1a24d , 0x12122f87 , 0xabea5a4c , 0xff99ccfe , 0x00a7faeb ] )
+  , Mont::unsafe_make( [ 0x6b379982 , 0xddb6bd35 , 0x41ae6ad9 , 0xfbb71dac , 0x0b352a24 , 0xbc6bb49e , 0x8996a9cb , 0x18478d98 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xb38188d5 , 0x91e08ea4 , 0xc03bc821 , 0x079106c1 , 0x1c0b21e3 , 0x9f26fd3f , 0xb712a467 , 0x075479c9 ] )
+  , Mont::unsafe_make( [ 0x49f07457 , 0xcace953e , 0x5b1d61ff , 0x28112e07 , 0xc57c8185 , 0xc4d03074 , 0xfe9143aa , 0x0b00fb42 ] )
+  , Mont::unsafe_make( [ 0x256a5e50 , 0x41ca1ef4 , 0x56982522 , 0x0e749510 , 0xda034d4a , 0xb7683404 , 0x909d05bd , 0x04aa8345 ] )
+  , Mont::unsafe_make( [ 0x7cd4b9b6 , 0xdd27996b , 0x142a1c1f , 0x859568e3 , 0xe3465de5 , 0xba08119a , 0x0c671e86 , 0x1bdbbe2c ] )
+  , Mont::unsafe_make( [ 0x97455c41 , 0x0f50c257 , 0x25cdfbcf , 0xbedac44b , 0x2cd9a819 , 0x3bf6e5b1 , 0xcdc39a64 , 0x2df75097 ] )
+  , Mont::unsafe_make( [ 0x2e34f596 , 0xbd84ca3b , 0x94ab5333 , 0xab67e156 , 0x70613127 , 0x922bf400 , 0xd3e41dc3 , 0x05405252 ] )
+  , Mont::unsafe_make( [ 0xa6d415ed , 0x8121d1f5 , 0x0eb01502 , 0x23bc8e09 , 0x49e1a13f , 0x948d605f , 0x1b70e86e , 0x01da8609 ] )
+  , Mont::unsafe_make( [ 0xde027375 , 0xfdffe923 , 0x4a370955 , 0x50a26824 , 0x49232f00 , 0x88e2d2da , 0xc1faf868 , 0x1c81a917 ] )
+  , Mont::unsafe_make( [ 0xaa7e8dc9 , 0x97f3bcc8 , 0x0b9274a2 , 0x672c8175 , 0x40d6c7d6 , 0x4ef61900 , 0xfd893d61 , 0x26b149f5 ] )
+  , Mont::unsafe_make( [ 0x98805f3d , 0xd63351d6 , 0x72122ff8 , 0xe30f79fa , 0x6abbde04 , 0x25a83a30 , 0x44212075 , 0x1d92adc6 ] )
+  , Mont::unsafe_make( [ 0xd2be1955 , 0x6cfc32d1 , 0xe5974f13 , 0x4127cd00 , 0x4456428d , 0x8e359489 , 0x25aae82a , 0x266bafaa ] )
+  , Mont::unsafe_make( [ 0x2846b4ca , 0x23049256 , 0xbbe79e47 , 0x6c844e6c , 0x0d44ff5e , 0x5f8e6654 , 0xf465bab9 , 0x1a0ff120 ] )
+  , Mont::unsafe_make( [ 0x572c544f , 0x629889c0 , 0x3c551608 , 0x41230f6b , 0xb9d92599 , 0xa7069b2e , 0x5e7fbb33 , 0x29593d48 ] )
+  , Mont::unsafe_make( [ 0x9b5ae2c5 , 0x4977264c , 0x8ca5b5e3 , 0xe7bd0aac , 0xa019d5ee , 0x5e2c5db9 , 0x85a490e7 , 0x2955f157 ] )
+  , Mont::unsafe_make( [ 0xe4d049c8 , 0x9d71e64a , 0xd341a047 , 0xe21c917b , 0xd3f527f2 , 0xc354e177 , 0xab9d8b3c , 0x0505dabf ] )
+  , Mont::unsafe_make( [ 0x0b790ff5 , 0xa03f8a29 , 0x2eebe04b , 0xe261eabd , 0x48d045aa , 0x45ed7c25 , 0x236059dd , 0x23e26652 ] )
+  , Mont::unsafe_make( [ 0xd5e4d099 , 0x713dd736 , 0xd61316cc , 0x359aafb8 , 0xe61695ef , 0x5e8b1411 , 0x4f5b44cb , 0x20adf07a ] )
+  , Mont::unsafe_make( [ 0xfdcca0ec , 0x3e7db6ed , 0x12fd8f8d , 0xa5232818 , 0xefa8d1ac , 0xdf8a85ad , 0xe62be289 , 0x09cb2190 ] )
+  , Mont::unsafe_make( [ 0xb04a9cf3 , 0x4de88a85 , 0x9b8a2d43 , 0xc78d3641 , 0x9085fa25 , 0x36d300fb , 0xad09d235 , 0x10c1cb4b ] )
+  , Mont::unsafe_make( [ 0xb6328a97 , 0xd36e21aa , 0x7c6aac72 , 0xd45d1532 , 0xba325eb3 , 0xeeae3beb , 0xc7b976aa , 0x14c30ae2 ] )
+  , Mont::unsafe_make( [ 0x9d476586 , 0x903e8932 , 0x2aa13946 , 0x8b5c7386 , 0x1cfc9228 , 0x9a68d0cf , 0x9979a3c7 , 0x09c7fd6d ] )
+  , Mont::unsafe_make( [ 0xa59f683b , 0x324b9cfa , 0xab040d87 , 0x5930b010 , 0x52dfd012 , 0x5829745a , 0x5ccfb05d , 0x1ba4bc8f ] )
+  , Mont::unsafe_make( [ 0xc9c9afec , 0xbe05d433 , 0x8e0a3eb7 , 0x694c774f , 0x691419d3 , 0x37171b01 , 0x3fc0699c , 0x1bdc50ea ] )
+  , Mont::unsafe_make( [ 0xd74f035d , 0x8baaeec4 , 0xe0d3516d , 0x25dedb46 , 0x879e5107 , 0x99f2e418 , 0xff3d901b , 0x18c3e388 ] )
+  , Mont::unsafe_make( [ 0x2cd80061 , 0x335efb27 , 0xe8709176 , 0x5f97b60c , 0xd634bbe3 , 0xddd82fe1 , 0x4fe5885e , 0x11bd79b1 ] )
+  , Mont::unsafe_make( [ 0xb6b56aac , 0x26b53e8a , 0x5660e289 , 0xf29a4fa5 , 0x4a4554bd , 0xa0ae74aa , 0x6bad61e4 , 0x0abde3fb ] )
+  , Mont::unsafe_make( [ 0x082a8076 , 0x529a402d , 0x0f81befe , 0x527ebac1 , 0x0158cb5c , 0x7925f6d9 , 0xf7ea18e2 , 0x0a7254d6 ] )
+  , Mont::unsafe_make( [ 0x3a5ddbaf , 0xf9ed49f0 , 0xc1b2dc4d , 0xcd8862cc , 0x66c8aecd , 0xcab04d7e , 0x348c4c47 , 0x14d87873 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x2ecf72e6 , 0xe57072d6 , 0xcfe45a0c , 0xc9a6d753 , 0xe02bd9dd , 0x874e4663 , 0xabb5fe7e , 0x1c5bb640 ] )
+  , Mont::unsafe_make( [ 0xa182ae70 , 0x65f8e9ef , 0x6ad55aac , 0x06a60195 , 0x560cdc50 , 0x7c1aa69c , 0xadb82e74 , 0x047ff205 ] )
+  , Mont::unsafe_make( [ 0xe860deaf , 0x55861833 , 0xbeba969d , 0x618765ce , 0xea48f6a5 , 0x36e20003 , 0x581420cb , 0x26f2df6c ] )
+  , Mont::unsafe_make( [ 0x11f6bf1b , 0xaff2e2ec , 0xd626776b , 0x73f1124e , 0x6896eaed , 0x416eb160 , 0xb79b795e , 0x09d0cea2 ] )
+  , Mont::unsafe_make( [ 0x25aef65c , 0x41084aa3 , 0x76746b40 , 0x3db21ce9 , 0x8caba788 , 0x5835c02c , 0xf1550737 , 0x2f2be19e ] )
+  , Mont::unsafe_make( [ 0x7392a23a , 0x5bcef918 , 0xc2eaa152 , 0xd16e7c76 , 0x2a84d682 , 0x9f327cc8 , 0xf1ea7699 , 0x1c1cf191 ] )
+  , Mont::unsafe_make( [ 0x3f426c33 , 0x525e42d6 , 0xcb1e2fa0 , 0x52737730 , 0xc1280bb8 , 0xac0d4946 , 0xbc442413 , 0x1444d758 ] )
+  , Mont::unsafe_make( [ 0xc2535820 , 0x4f3e028a , 0x3978bddc , 0x66dbc26c , 0x6490581d , 0x3b0b294a , 0xa5afeb4b , 0x0b71b193 ] )
+  , Mont::unsafe_make( [ 0xa729ca55 , 0xc503f63e , 0xe1d41f4b , 0x230e100a , 0xf7caa9ba , 0x9971fb25 , 0xd329e4eb , 0x1b030517 ] )
+  , Mont::unsafe_make( [ 0x4ae6f106 , 0x7ac28061 , 0xb6366bb7 , 0xf96af1fa , 0x771ff7cf , 0x41142a12 , 0x7f2864c4 , 0x2d728501 ] )
+  , Mont::unsafe_make( [ 0xf57e2cb7 , 0xdb7c4519 , 0x23df3597 , 0x47bfff71 , 0x2e03e21e , 0x8aab8061 , 0x57e43689 , 0x02eeaf19 ] )
+  , Mont::unsafe_make( [ 0xb33295e5 , 0x1fb34ae7 , 0x50f5921c , 0x41c6f244 , 0xdda71b14 , 0x4de16e44 , 0xd05ecfa7 , 0x2b8c1248 ] )
+  , Mont::unsafe_make( [ 0x754bc9e5 , 0x12812b4e , 0x3d001ba3 , 0xb77c66de , 0x28245064 , 0xc49d7ad9 , 0x0917f57b , 0x23ce7ec1 ] )
+  , Mont::unsafe_make( [ 0xd166403e , 0xafc2f5a9 , 0x382efabc , 0xe062ac59 , 0x3cbf69de , 0x1008f2b3 , 0x781dda4c , 0x2d48d56f ] )
+  , Mont::unsafe_make( [ 0x01d3820d , 0xdacc32cc , 0x240fcdca , 0x541dd5c6 , 0x5542a877 , 0x19bd0042 , 0x7859d5b7 , 0x235cafab ] )
+  , Mont::unsafe_make( [ 0xbda78eb2 , 0x10f5516a , 0x38d1ae5e , 0x4cec6b09 , 0x83326d11 , 0xe8145ca7 , 0x3d0ca441 , 0x28d779a2 ] )
+  , Mont::unsafe_make( [ 0x52d16107 , 0xb6102a06 , 0x3aeaa24e , 0x0d246ffd , 0xd13445ae , 0x63818c8a , 0x1036eece , 0x01a8093b ] )
+  , Mont::unsafe_make( [ 0xf9bf419b , 0x408602ef , 0x9a5ffd32 , 0x05666bdf , 0xf97769cd , 0x2909daf4 , 0xd62b41cd , 0x01658c85 ] )
+  , Mont::unsafe_make( [ 0xf9baca3c , 0xcd94a543 , 0xcb895526 , 0xf52a8d56 , 0xae1448e4 , 0x99e8aec2 , 0xb45be979 , 0x2e06d337 ] )
+  , Mont::unsafe_make( [ 0xb955e315 , 0xc310f684 , 0x30bcf805 , 0x763dde1d , 0xeea9673f , 0x6ccb91e7 , 0x8c6047e2 , 0x1ec49383 ] )
+  , Mont::unsafe_make( [ 0x95702245 , 0xddff82e3 , 0xa3c454f4 , 0xf0e8fd6d , 0xffa605bc , 0xa5b1473f , 0x57a19a83 , 0x11307804 ] )
+  , Mont::unsafe_make( [ 0x86e9b889 , 0xfd23a90c , 0x03cca35b , 0x001de2ad , 0xe6e4bf11 , 0x0d80dc24 , 0x9de0b755 , 0x2647b52b ] )
+  , Mont::unsafe_make( [ 0xf3e81641 , 0xd58e1fcf , 0x11edf39c , 0x7e8dc187 , 0x60b031f0 , 0x4c50a484 , 0x996a1938 , 0x1519003c ] )
+  , Mont::unsafe_make( [ 0xc152d38d , 0xc1a68739 , 0x1995c1ec , 0x0f038d03 , 0x384cd2d2 , 0x036e02fa , 0x41d6034f , 0x024e86c5 ] )
+  , Mont::unsafe_make( [ 0xa5b9a03f , 0xb54c8872 , 0x0f2a4c26 , 0x6a7157f9 , 0x259fa614 , 0x240c252c , 0x45df6188 , 0x1ec5c936 ] )
+  , Mont::unsafe_make( [ 0x154132ea , 0xd5f6e310 , 0x831c8441 , 0x30245b2f , 0xeb62e126 , 0xacd532e5 , 0xbf2d8970 , 0x005f7d15 ] )
+  , Mont::unsafe_make( [ 0xab2e37f7 , 0xf8fd42b9 , 0x9586debb , 0xe3d745c1 , 0x6579ea62 , 0x8e0c5b7a , 0x8bdaa70f , 0x26990a77 ] )
+  , Mont::unsafe_make( [ 0xae81c215 , 0x9d86211d , 0x1f6b4010 , 0xfef0faec , 0x9e6ae1fd , 0x59ca9e6e , 0x3dc67547 , 0x03e03dbc ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x81f84c38 , 0xfe4a6b3a , 0xeaede7de , 0x46c9443a , 0x8b2852be , 0x1ce9a264 , 0x3cf740f0 , 0x091f96cb ] )
+  , Mont::unsafe_make( [ 0x8df5429e , 0x66835ed5 , 0xa4ffe478 , 0xf7c4da12 , 0xea8ac1a7 , 0x5694d3ab , 0x31368cc2 , 0x18118b81 ] )
+  , Mont::unsafe_make( [ 0x9a58dbd9 , 0xa687ba47 , 0x57c78ad1 , 0x9e025864 , 0x20c7f560 , 0x36dee430 , 0x3e02f1b9 , 0x27e6422b ] )
+  , Mont::unsafe_make( [ 0xd59a65c0 , 0xd7e20210 , 0x47d43fea , 0x28743e24 , 0xf877c9d6 , 0xcc859181 , 0x517f8ca9 , 0x2d0b706a ] )
+  , Mont::unsafe_make( [ 0xd85b1a9e , 0xb5441e63 , 0xba399931 , 0xb1298cbe , 0x64c3b7ee , 0xd45ef638 , 0x588af5c4 , 0x03a7fd9e ] )
+  , Mont::unsafe_make( [ 0x011cfde7 , 0xdb4af6e3 , 0x311c405e , 0x5544b916 , 0x450bcc4a , 0xc428fe77 , 0xaa3e7a08 , 0x11eea6b1 ] )
+  , Mont::unsafe_make( [ 0xe9593703 , 0x16c531f4 , 0x4a2b89d2 , 0xfa7cd1d8 , 0xcf595c79 , 0x420d5d2f , 0x53ed7660 , 0x26c66538 ] )
+  , Mont::unsafe_make( [ 0x753e51ea , 0xbe97a271 , 0x65b651e1 , 0xb5cc3fd9 , 0xffb13c6f , 0x4b4335c0 , 0x5d2d816e , 0x1db733b5 ] )
+  , Mont::unsafe_make( [ 0x13b4dd03 , 0x7f4712ff , 0xab1c2597 , 0x9eb42ca9 , 0x88da6577 , 0xfd62befc , 0xa0f8ba9e , 0x050238d0 ] )
+  , Mont::unsafe_make( [ 0x34ad2f42 , 0x768aab67 , 0x31b39f59 , 0x38ab5e36 , 0x02c49172 , 0x6603facf , 0xd06b106a , 0x0b49f340 ] )
+  , Mont::unsafe_make( [ 0x25fe1f43 , 0xc6b51fc6 , 0x8fbd33bb , 0x554c513f , 0x98e8cf6f , 0x5c3ee855 , 0x0fb79bc2 , 0x27d78a8a ] )
+  , Mont::unsafe_make( [ 0xe3d4ba84 , 0x53df1d99 , 0x8d44babd , 0x96071a63 , 0x756fc931 , 0x21b0f575 , 0x0e106670 , 0x25c29893 ] )
+  , Mont::unsafe_make( [ 0xb5395258 , 0x2a5323e4 , 0x14fd7aa4 , 0xed3fb241 , 0xe9f9e6d5 , 0x16bfa673 , 0xf77c8ec6 , 0x2fd2b920 ] )
+  , Mont::unsafe_make( [ 0x0f5ee0d7 , 0x95201c87 , 0x7385b060 , 0x83285823 , 0x04ef5333 , 0xe9ec8c10 , 0x3d15ebd5 , 0x2fc3f75e ] )
+  , Mont::unsafe_make( [ 0x9a0879eb , 0xddcc8721 , 0x6d346d4c , 0x6f0fd0df , 0x93ee3c1b , 0x7806d6d5 , 0x0a011913 , 0x0baefa0b ] )
+  , Mont::unsafe_make( [ 0x849a9c6b , 0x79f4b2c7 , 0xb45ce25c , 0x701a9fe5 , 0x3dfc5818 , 0xddd4f8db , 0x1f5c1b72 , 0x1f486e83 ] )
+  , Mont::unsafe_make( [ 0xb6952bdf , 0x095ed3cf , 0xcfdcdfaa , 0x71a3ff59 , 0x3fff5878 , 0xf2117ec5 , 0x66ddd28b , 0x18d0d20f ] )
+  , Mont::unsafe_make( [ 0x314befcb , 0x1c3a6ce3 , 0xafa7085a , 0xe5dfc8c8 , 0x3ceae055 , 0xb614010a , 0xb65badcb , 0x29ed6e1a ] )
+  , Mont::unsafe_make( [ 0xd2a7bfe5 , 0x0f195fa6 , 0xf1c9ff43 , 0x9a09572e , 0xb0e8b0a7 , 0x3cc701b0 , 0xdeb0e02e , 0x00d7efbb ] )
+  , Mont::unsafe_make( [ 0x7272c35d , 0x1f34111a , 0x903a1c70 , 0xa4904c10 , 0x16da3e2f , 0x0e8d81b9 , 0x2e86fa4c , 0x036f3f45 ] )
+  , Mont::unsafe_make( [ 0x1632fcef , 0xe302415d , 0xe8c7b277 , 0x0427f696 , 0x508830dd , 0x4fd00811 , 0x593432b1 , 0x20078f08 ] )
+  , Mont::unsafe_make( [ 0x241afa4f , 0x93a6aa02 , 0x04282769 , 0xe9308cec , 0x7af43cb4 , 0x71bfe6d6 , 0x1db788aa , 0x086199a5 ] )
+  , Mont::unsafe_make( [ 0x125b67be , 0xfb2ee68b , 0xde6e8eaf , 0x12965f52 , 0x9f1a6560 , 0xc84cf2c7 , 0x6307365d , 0x0c872b1d ] )
+  , Mont::unsafe_make( [ 0x4dfbea75 , 0xc5b99de3 , 0xee4f8669 , 0xbf780888 , 0x23a6c559 , 0xa74816a4 , 0x6fb32bf0 , 0x2e01816b ] )
+  , Mont::unsafe_make( [ 0xd58bb83a , 0x4a6b02fe , 0xfc1b6c80 , 0xb57c9daf , 0xe1985eb6 , 0x3f87158e , 0xbc300e90 , 0x13bc1254 ] )
+  , Mont::unsafe_make( [ 0x94191293 , 0xf2145ede , 0x3f943806 , 0xa7f05f4e , 0xe4e168dd , 0x008c71a6 , 0xd2e34e59 , 0x02abec0b ] )
+  , Mont::unsafe_make( [ 0x322a81d3 , 0x7e771227 , 0x64aa8cc7 , 0x3b131d13 , 0x82cc32bf , 0xcb85dc5b , 0xa4aa756f , 0x03dcae10 ] )
+  , Mont::unsafe_make( [ 0xb732e898 , 0xb64e0bfb , 0xa9b07713 , 0x81e4956f , 0x9081104f , 0x8b0f8b37 , 0x86a6d919 , 0x0fb50c3b ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x5d3bdd3f , 0x7329e0ef , 0x82d266e4 , 0xc2e52b3c , 0x17ef0752 , 0x63d746c3 , 0x3b8e38c8 , 0x2c7e939a ] )
+  , Mont::unsafe_make( [ 0x501b7add , 0x5a68e3f8 , 0xf77dfb41 , 0x462cf6bf , 0x2cd008a4 , 0x43cf70b8 , 0x3c9e5a78 , 0x26fe3faf ] )
+  , Mont::unsafe_make( [ 0x97440361 , 0x7146b2d3 , 0xcc9b8ffe , 0xd5864cf7 , 0xb5a7a516 , 0x9ceb8fa0 , 0x797f18a7 , 0x259f5af4 ] )
+  , Mont::unsafe_make( [ 0x0b60d5fe , 0x13838bce , 0x5e82a663 , 0x8af377b0 , 0xb9df65e5 , 0x4bc67044 , 0x52d3ad04 , 0x1bda056d ] )
+  , Mont::unsafe_make( [ 0x4afb1de2 , 0x3b39e3b2 , 0x31136a14 , 0x17dc00d7 , 0x49289ba9 , 0x8d1c88c2 , 0x3e8bbe88 , 0x1e88b495 ] )
+  , Mont::unsafe_make( [ 0x74ccecca , 0x41debae0 , 0x9c3d1699 , 0x32e58d9b , 0xa9a3b4d1 , 0xc2a5dc49 , 0xb6dd7462 , 0x122f6ad3 ] )
+  , Mont::unsafe_make( [ 0xedba5460 , 0x466c035b , 0x298449a3 , 0x0add380a , 0xa2736325 , 0x6bb6c601 , 0x5ecdb632 , 0x0ffbbd05 ] )
+  , Mont::unsafe_make( [ 0xc9c03b42 , 0x80a394cc , 0x94544cb3 , 0x05d77093 , 0x41545157 , 0xf8a0acb8 , 0x4c4d308e , 0x25f9d143 ] )
+  , Mont::unsafe_make( [ 0xe3749812 , 0xb6a0f131 , 0xa01b045a , 0xc767be12 , 0x1db63ed7 , 0xc5541aec , 0x0019e9c9 , 0x037ec712 ] )
+  , Mont::unsafe_make( [ 0x1abb85ff , 0xb0c1a732 , 0x8fe1a2c5 , 0x2325d4bc , 0x97d813f6 , 0x1db37d85 , 0x90795e2b , 0x21b2c56d ] )
+  , Mont::unsafe_make( [ 0x5e1922cf , 0x6e40e788 , 0xa876a2b9 , 0x1951a6ff , 0xb977edb3 , 0x9fb5528b , 0x1743c448 , 0x026f93ba ] )
+  , Mont::unsafe_make( [ 0x3df158f0 , 0x6ccd7d87 , 0xfe115685 , 0x1b7dea81 , 0xe53d09d4 , 0xc3cc9e23 , 0x3ec57862 , 0x2a492b24 ] )
+  , Mont::unsafe_make( [ 0x6c4d78df , 0x5dfab987 , 0x038b948a , 0xabc66176 , 0x40566b94 , 0x45166b7e , 0x6ab3e3bf , 0x161366a0 ] )
+  , Mont::unsafe_make( [ 0x906e71af , 0x5721e4dd , 0x988ae47a , 0x2a07fe5a , 0x3fd01940 , 0x0286077c , 0x391e4c5b , 0x04b787b5 ] )
+  , Mont::unsafe_make( [ 0x03207b4d , 0xe70731b9 , 0x739666c2 , 0xc143aa90 , 0x603a2cdb , 0x739ddbe8 , 0xc02bf40e , 0x0d05706e ] )
+  , Mont::unsafe_make( [ 0x364b53a0 , 0x47237398 , 0xdcb98215 , 0x8806349f , 0xf5ccf95d , 0xfda21d64 , 0x5e94b711 , 0x22d2fe27 ] )
+  , Mont::unsafe_make( [ 0x1473854a , 0xe57c87c4 , 0x1495452c , 0x1ee0ffb4 , 0x3919b088 , 0xa63ae17b , 0x7ce9643d , 0x06fe062e ] )
+  , Mont::unsafe_make( [ 0x0ae4bab2 , 0xbb4388b0 , 0x79e6d40a , 0xeff8716a , 0xf2d98ebd , 0xb11a200c , 0xb665f288 , 0x1762311c ] )
+  , Mont::unsafe_make( [ 0xc232fc2b , 0xdfe90c88 , 0x3ec26465 , 0xc9dfc818 , 0xd061f0ce , 0x43a4dba9 , 0xaa47b876 , 0x2f0c46cf ] )
+  , Mont::unsafe_make( [ 0x29c46797 , 0x049e71dc , 0x4c753ad2 , 0x34b389dd , 0x322478f5 , 0xb14dc1e5 , 0x440f2617 , 0x1a53d374 ] )
+  , Mont::unsafe_make( [ 0x161e4059 , 0x55479dd5 , 0xe0a2ff46 , 0x4b10a427 , 0x8c859901 , 0xdd806e36 , 0xa845c89b , 0x11f1718d ] )
+  , Mont::unsafe_make( [ 0xc88bf49e , 0x1e975242 , 0x5574b3b8 , 0x370249a7 , 0xbea95b10 , 0x439b9a5e , 0x068eff90 , 0x1fd89f59 ] )
+  , Mont::unsafe_make( [ 0x5b71195a , 0xd3b62c09 , 0x12d92673 , 0x1b4e6b14 , 0x274bba69 , 0xe5eaddf8 , 0xdc6be33a , 0x1eb37307 ] )
+  , Mont::unsafe_make( [ 0x9b43c673 , 0x2a8d2567 , 0x8b30ddde , 0x7020eff6 , 0x17ba00a3 , 0xdd1a3793 , 0xf6c2cd0e , 0x08ad12ca ] )
+  , Mont::unsafe_make( [ 0x124c534a , 0xd198e237 , 0xa4ce2e96 , 0x0fba7d17 , 0x3532c10b , 0x595783ce , 0x9e77cb0c , 0x296fa1f0 ] )
+  , Mont::unsafe_make( [ 0xfc23739f , 0xe504ede6 , 0xbfeda922 , 0x592f4a59 , 0x15e3f49b , 0xc3692cf9 , 0x93073116 , 0x10bfb406 ] )
+  , Mont::unsafe_make( [ 0x78c58248 , 0x9857d127 , 0x752d86ca , 0xc4f0b129 , 0x3b71f13e , 0x41bd0177 , 0xee7f2ecf , 0x2ee07611 ] )
+  , Mont::unsafe_make( [ 0x24291e81 , 0x91ecb34b , 0x4006814a , 0x7dd2e661 , 0x70fcf771 , 0x459f2830 , 0xc8e179e1 , 0x263992d7 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x96f59f8d , 0xfd65a5d2 , 0xb907c31d , 0x0f401780 , 0xacefb483 , 0xb36d040b , 0x9f009d98 , 0x18600e16 ] )
+  , Mont::unsafe_make( [ 0x60ab47c3 , 0x29787baa , 0x1c1113f3 , 0x30953f2a , 0xad7bc7d3 , 0x9a840d17 , 0x45e91313 , 0x0c273e84 ] )
+  , Mont::unsafe_make( [ 0x5a94dcaf , 0xe7a6aefd , 0xfbae113d , 0xc493b610 , 0x5a964aa2 , 0xd0fc063c , 0x0942483e , 0x1be731bc ] )
+  , Mont::unsafe_make( [ 0x06fec9fb , 0xf79fbc4a , 0x70e7949e , 0xcd88a39c , 0x1be32bb4 , 0xafd1f5cf , 0xad2c9458 , 0x0eb686e0 ] )
+  , Mont::unsafe_make( [ 0xfeff0f32 , 0x2e16b368 , 0xa209a061 , 0xc4677709 , 0xeead5831 , 0x7f03eb08 , 0xa5fb0b27 , 0x1ee9b8b2 ] )
+  , Mont::unsafe_make( [ 0x229f32eb , 0xe702bb9e , 0x236804f7 , 0x182b5683 , 0xa9d5dfdf , 0x44499f20 , 0x7ab6e5b9 , 0x276af7bd ] )
+  , Mont::unsafe_make( [ 0x7888f3e8 , 0x13ec3063 , 0x58aaad87 , 0x6bc86b69 , 0x01aeeaf0 , 0xca9f38a1 , 0x4043a4cf , 0x04bfeca7 ] )
+  , Mont::unsafe_make( [ 0x36b72fce , 0x0fea863d , 0x8918eb24 , 0x10c9350b , 0x1266fb7b , 0x165965c9 , 0x99d00c7d , 0x0513f3d2 ] )
+  , Mont::unsafe_make( [ 0xdaa035fd , 0xdb3951c0 , 0x4e5e0ea8 , 0xe5bc0e34 , 0x62be3253 , 0x76cc0f6a , 0xbd1b37b4 , 0x08a3bde6 ] )
+  , Mont::unsafe_make( [ 0x0dd2c1ec , 0x898c9dc5 , 0x66f36464 , 0x37a716eb , 0x69c5ca90 , 0x7f99efcd , 0x534098d5 , 0x29b17e08 ] )
+  , Mont::unsafe_make( [ 0x8f622a86 , 0x11d7f55b , 0x0bea4e56 , 0x03152b91 , 0x868b2fd0 , 0x17fa43e6 , 0xdd105d74 , 0x08bb9f4d ] )
+  , Mont::unsafe_make( [ 0xc838abe7 , 0xd70a6194 , 0x6d4cef9e , 0x908039ce , 0x62a29bf9 , 0x662253d5 , 0x24c68169 , 0x02f65821 ] )
+  , Mont::unsafe_make( [ 0x2715a94b , 0xa9ff424d , 0x7f2f8209 , 0x812f680c , 0x214b6048 , 0x4688fedd , 0xcfd06d74 , 0x29a3f492 ] )
+  , Mont::unsafe_make( [ 0xb0488b88 , 0xa7463262 , 0xd89d0e1e , 0x54960405 , 0xee93bd95 , 0x0cc3c755 , 0x47ab46c5 , 0x18043653 ] )
+  , Mont::unsafe_make( [ 0x8ac6da13 , 0x849815a1 , 0xfc9fc384 , 0x15670cd7 , 0x25f5c76c , 0x150632a3 , 0xb169345a , 0x2a7e271b ] )
+  , Mont::unsafe_make( [ 0xdae3a288 , 0x493d15a6 , 0xa3915c21 , 0xaab2a7ae , 0xb18c05b2 , 0xc626a7ea , 0xcbfa084e , 0x215d47ca ] )
+  , Mont::unsafe_make( [ 0x3e08c4ce , 0x18cf7690 , 0xa70bdba8 , 0xb932a35d , 0xbb3a40c0 , 0xa7281e90 , 0xdb2d8cf4 , 0x0dfffc33 ] )
+  , Mont::unsafe_make( [ 0x5d14cf94 , 0x9644089c , 0x80b3b3b9 , 0x43eae592 , 0x03566431 , 0x8655fd9c , 0xda061c19 , 0x294abcc4 ] )
+  , Mont::unsafe_make( [ 0x0c66121d , 0xda21158f , 0xaaf61f38 , 0x1a32fca9 , 0xfeb66996 , 0xd72975f6 , 0x658c8525 , 0x28f49eca ] )
+  , Mont::unsafe_make( [ 0x264b724c , 0xbc8a40ec , 0x9e20a338 , 0xd992a43d , 0xaa366941 , 0x8a81a140 , 0xf2fdbba0 , 0x200d2319 ] )
+  , Mont::unsafe_make( [ 0x27b03668 , 0x3cd8c2ae , 0x38d67aab , 0x5fb9f7f0 , 0x609ed147 , 0x2c1bd7fd , 0x5de1186b , 0x2873f05e ] )
+  , Mont::unsafe_make( [ 0xc5efa8ae , 0xadd4e024 , 0xcbef5dd7 , 0x9f6394d3 , 0x5708f578 , 0xe15e04c9 , 0x48084ab9 , 0x2a06cdce ] )
+  , Mont::unsafe_make( [ 0x49e200ee , 0x5daa9e7a , 0x8f513356 , 0x7c50e083 , 0x570fb3ec , 0x13e83aa8 , 0x05229278 , 0x0a3695c8 ] )
+  , Mont::unsafe_make( [ 0xfbac24c4 , 0xc02780d4 , 0xaa75f8e1 , 0x38f29a32 , 0xf18099fb , 0x84c63dbb , 0xb7f295b7 , 0x24d17346 ] )
+  , Mont::unsafe_make( [ 0xd25caf24 , 0x3657626f , 0x2687c9a7 , 0x822638b2 , 0x4b777252 , 0x46a31f45 , 0xca11c82a , 0x19ffadca ] )
+  , Mont::unsafe_make( [ 0xb03d8f0b , 0x0a724cff , 0x6e5512ef , 0xf4cb6221 , 0x4fd52dd1 , 0x65a0629e , 0xfb6c9d51 , 0x2b1cbaff ] )
+  , Mont::unsafe_make( [ 0x701196b3 , 0xf3d64b22 , 0xb316509c , 0x094ad8d8 , 0xc2b81590 , 0xc7eb989c , 0x0d319914 , 0x24f8ea3a ] )
+  , Mont::unsafe_make( [ 0xeb7392fe , 0xacf273d1 , 0x8578ae82 , 0xbfa56b12 , 0x11880587 , 0xc9ef63b2 , 0x8a8ba1db , 0x2972a6a1 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xbaf128d5 , 0xe5e4064a , 0xfd995c2f , 0xa41addc1 , 0x8c7f0e3d , 0xc993f959 , 0x17b1683e , 0x2a840437 ] )
+  , Mont::unsafe_make( [ 0x1c68ad67 , 0x21f49a66 , 0x35235852 , 0x4262b8e5 , 0x7e2bf42c , 0x9bc39acf , 0x505b3458 , 0x244b28bf ] )
+  , Mont::unsafe_make( [ 0x08a87e93 , 0x4f009dc7 , 0x49b1a441 , 0x9921b670 , 0xe2e86663 , 0x4c0ecb14 , 0xde209e5e , 0x02ba7470 ] )
+  , Mont::unsafe_make( [ 0x1f7dca14 , 0xb0654bc4 , 0xedf3d4b8 , 0x227bfabe , 0xf4febcc7 , 0x095c5beb , 0xfaf3cc47 , 0x1501fb0e ] )
+  , Mont::unsafe_make( [ 0xff4ccb3a , 0x2f6e9838 , 0x3b40e5d6 , 0x795dc433 , 0xf449e320 , 0xf5915091 , 0x1b732b80 , 0x29a2796e ] )
+  , Mont::unsafe_make( [ 0x95622294 , 0x9c9131e7 , 0x51707ded , 0x55ee8839 , 0x429dc7ed , 0x60a5096a , 0x0c52ac2e , 0x1cfa361d ] )
+  , Mont::unsafe_make( [ 0xf6bf07bd , 0xaa7ca3e4 , 0xfecd0d8d , 0x1bcea11c , 0x5cf60ebe , 0xa4d60382 , 0x973f1ac8 , 0x0aec2a23 ] )
+  , Mont::unsafe_make( [ 0x2485bb46 , 0x77b12f6e , 0xcb769348 , 0xaefd5dce , 0xab27a6a0 , 0x3bbb5ea1 , 0x973bf203 , 0x169e0622 ] )
+  , Mont::unsafe_make( [ 0xf6dab7c1 , 0x598bbc4e , 0x6792e1b6 , 0xda1e5901 , 0x4d05670d , 0x3a9dae66 , 0x3d5da9f7 , 0x272bb565 ] )
+  , Mont::unsafe_make( [ 0xf03204d2 , 0xc4bc9f13 , 0xf0e5270c , 0xb2351c5f , 0x9ba15fcd , 0xd43bee73 , 0x0ba61525 , 0x04e3284c ] )
+  , Mont::unsafe_make( [ 0x392201e7 , 0x08d81d39 , 0x124af264 , 0xdbf96202 , 0x1501b6cb , 0xa745f04c , 0x3e0e7d36 , 0x2243abc2 ] )
+  , Mont::unsafe_make( [ 0x2f8a7460 , 0xa15c7c77 , 0xe6665110 , 0xd2c5b70b , 0x19c55a0f , 0x191d39f7 , 0xa675aeaa , 0x12aefefd ] )
+  , Mont::unsafe_make( [ 0xc5db346c , 0x90d5b0d4 , 0x46150322 , 0xe0455323 , 0x4a39bc36 , 0x068aa891 , 0xe4b72ceb , 0x2b84cc8e ] )
+  , Mont::unsafe_make( [ 0x1605c585 , 0x220a026b , 0x247af968 , 0xeec27723 , 0x1af2f143 , 0x96876d55 , 0x9541a4b1 , 0x012f33a5 ] )
+  , Mont::unsafe_make( [ 0x0fc86bed , 0x0f29d28b , 0x1d8d568f , 0x0b85740a , 0xdc3bc575 , 0x6b7c988f , 0x4ba5ea10 , 0x28e1147a ] )
+  , Mont::unsafe_make( [ 0x24889ad6 , 0xc105073a , 0x040fa30d , 0x3934b448 , 0xfd0cf308 , 0x104923b4 , 0x1802648b , 0x1a492c46 ] )
+  , Mont::unsafe_make( [ 0xab65272b , 0xd04ab913 , 0x372f29c5 , 0xf6025018 , 0xd32f6dc2 , 0xf4712271 , 0x5b07ac3d , 0x123e05c6 ] )
+  , Mont::unsafe_make( [ 0xd5bddb4d , 0x56add07f , 0xf0484121 , 0x794948f1 , 0x5b254c12 , 0x28d56dbf , 0x67b503dd , 0x0ff28439 ] )
+  , Mont::unsafe_make( [ 0x0cb736dc , 0x61c045e0 , 0xbb1da603 , 0x7992cc30 , 0x78364afe , 0x4b299c14 , 0x6ddc2eab , 0x2130a8f3 ] )
+  , Mont::unsafe_make( [ 0xa710e837 , 0xa5fcaf77 , 0x2bc37244 , 0x4a87685f , 0x5cce92f3 , 0xfb2c1970 , 0x630e4a8b , 0x12a5973d ] )
+  , Mont::unsafe_make( [ 0x5d1cb985 , 0xc51613b8 , 0x950c049b , 0x1b008552 , 0xef5c5d62 , 0xe5270cb9 , 0x70cfa92d , 0x26ca37bf ] )
+  , Mont::unsafe_make( [ 0x0dd89b0b , 0x6a3aeb76 , 0x81f94b4d , 0x7b5f4e0c , 0x788a0010 , 0xf0b33d4a , 0x5828d3b0 , 0x27e4d3b5 ] )
+  , Mont::unsafe_make( [ 0xb4fd40f1 , 0x3cbe96b1 , 0xd3217460 , 0xe6a29b4d , 0x54ecd7b0 , 0xc5db8e75 , 0x4b060823 , 0x22cd4383 ] )
+  , Mont::unsafe_make( [ 0xb5f092f3 , 0xfa0c0fee , 0x948fcdcf , 0x2cf17591 , 0x1806379d , 0x63fb69fb , 0xb43b1360 , 0x079389d4 ] )
+  , Mont::unsafe_make( [ 0x6ebdbd2a , 0xc1a26d81 , 0x001b0768 , 0xdcb395b6 , 0xb9d1e05c , 0x4a426ca4 , 0xb06500a8 , 0x1e546a9a ] )
+  , Mont::unsafe_make( [ 0x495e6987 , 0xb092a9af , 0xf8a4d660 , 0x38d4f2a1 , 0x822f7be7 , 0x5727a474 , 0xa4180e72 , 0x08d6269d ] )
+  , Mont::unsafe_make( [ 0x6ff1c653 , 0xbbecf16a , 0x6d55c0ea , 0x7d9e4c1a , 0x906ab637 , 0xd4e3355a , 0x8eb98ec1 , 0x0fb3365f ] )
+  , Mont::unsafe_make( [ 0x15272192 , 0x72611a79 , 0xa2495885 , 0x8a8f59b2 , 0x670eb79a , 0xb851f10b , 0x4037cba4 , 0x1b857f20 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xdc00bee2 , 0x4de95cba , 0x61502e15 , 0xd5181c79 , 0x0de13052 , 0xa92d546a , 0x215c4093 , 0x2af8e154 ] )
+  , Mont::unsafe_make( [ 0x14f12895 , 0x4ee5b420 , 0xfab1d211 , 0x3a3e13a0 , 0x0e7b6a9e , 0x71b43e2b , 0xe9039efc , 0x14a78946 ] )
+  , Mont::unsafe_make( [ 0xf9fd7888 , 0x1ff8d0c9 , 0xc8835819 , 0xbece60e2 , 0x89f39053 , 0x7b0347a8 , 0x8a427f24 , 0x0f4bcbee ] )
+  , Mont::unsafe_make( [ 0x57dbb96b , 0x5f0c99c7 , 0x3ba5653e , 0xdd6196a8 , 0x71f14d4d , 0x33ae02ef , 0xfc607761 , 0x1533c0c4 ] )
+  , Mont::unsafe_make( [ 0x01dae1a7 , 0xe609828e , 0xe235acf8 , 0x62d15f69 , 0xf57ae6dc , 0x250cbee2 , 0x2247c5f2 , 0x208b0910 ] )
+  , Mont::unsafe_make( [ 0xe7db60e6 , 0x07a4d379 , 0x5fa70c87 , 0x2982d5cf , 0x3a2113ed , 0xa020972f , 0xf1efeac2 , 0x0c1bcb68 ] )
+  , Mont::unsafe_make( [ 0x96229ddd , 0x9e814bea , 0xbd16ba17 , 0x9f0a779d , 0x4448bbad , 0x91f0dd49 , 0xd336b82a , 0x2fd2b76f ] )
+  , Mont::unsafe_make( [ 0xea4765ed , 0xe5859915 , 0xc4763d91 , 0xe1fe4440 , 0x2c22de29 , 0xbb52f7a0 , 0x2df656d4 , 0x0e58317f ] )
+  , Mont::unsafe_make( [ 0x3750e320 , 0x4c47e513 , 0x7ca7addf , 0xaa19cb9b , 0x71feaec9 , 0x96b25816 , 0x9c6db6e3 , 0x29a1dcea ] )
+  , Mont::unsafe_make( [ 0x425ab0ec , 0x3179ca51 , 0x05436e78 , 0x4948fd6a , 0xc12c93d5 , 0xaf11cbbd , 0xb6bd3db7 , 0x05ee363f ] )
+  , Mont::unsafe_make( [ 0x1bb5df41 , 0x4edf448e , 0x45d9ac5a , 0x751ffaf8 , 0x011409fc , 0xe457faaa , 0x6e21f8e4 , 0x10a22cd2 ] )
+  , Mont::unsafe_make( [ 0x850fd41e , 0x241d2baf , 0x178145de , 0x798e3438 , 0x8d7b34bd , 0x32f92961 , 0xa1565b57 , 0x2af81e04 ] )
+  , Mont::unsafe_make( [ 0x4243f335 , 0xb0f9f675 , 0xc0c40a8d , 0xfc63bb92 , 0x50a33a47 , 0xc5ad848d , 0xbf49c0fc , 0x1972702e ] )
+  , Mont::unsafe_make( [ 0xc373b137 , 0x68da73eb , 0x13324aad , 0x0d07d3ac , 0x8d978e88 , 0x193e3c99 , 0xb041c243 , 0x20b009e3 ] )
+  , Mont::unsafe_make( [ 0xef3925a3 , 0xa5b6fa4d , 0x22aee688 , 0x44086256 , 0x7a32f6ae , 0x72151a90 , 0x46abcff2 , 0x0285e96e ] )
+  , Mont::unsafe_make( [ 0x4765bcbd , 0xa2f5c5fd , 0x971bc628 , 0x2e7500cc , 0xbb938b03 , 0x1e8690bf , 0xa1cb052a , 0x12d27e83 ] )
+  , Mont::unsafe_make( [ 0x1fb01812 , 0x94267d55 , 0xe5a13ed7 , 0xcf3bec88 , 0x164adc26 , 0x73b23ab2 , 0x0b6d6695 , 0x1491c25b ] )
+  , Mont::unsafe_make( [ 0x5e9149c3 , 0x030fcdea , 0x7690e73a , 0xf09e6870 , 0x81925335 , 0x3efa45ec , 0x88c6f260 , 0x098d5021 ] )
+  , Mont::unsafe_make( [ 0x1e744515 , 0x361fba42 , 0x2423f223 , 0xf09992b5 , 0xaafa7cff , 0x99d90206 , 0xd2e620cc , 0x0a0ef57b ] )
+  , Mont::unsafe_make( [ 0xbf9f6d50 , 0x7bd463b7 , 0xac4f1cf4 , 0xe228a1e5 , 0x8e2a09d4 , 0x9b3d4ca3 , 0x63140434 , 0x2d245918 ] )
+  , Mont::unsafe_make( [ 0x07105023 , 0x0cfa8ff7 , 0x45bbac59 , 0xd5c60aaa , 0x65640d93 , 0xc2828988 , 0x3bbb41e5 , 0x09af6ceb ] )
+  , Mont::unsafe_make( [ 0xef641b62 , 0x82e53e2b , 0x579ae56d , 0x6782369d , 0x4d4465ab , 0x372b6e7e , 0xff922a5e , 0x2c2c2425 ] )
+  , Mont::unsafe_make( [ 0x951371f5 , 0xcbaadf50 , 0x64a966d6 , 0x4c6dfaef , 0x1160282d , 0x0bcd9727 , 0x8b34ed19 , 0x107e2dfc ] )
+  , Mont::unsafe_make( [ 0x6f4c2abf , 0xb40593a0 , 0x8f2400b0 , 0xcdf02ac6 , 0x480d235f , 0xac59a2d5 , 0x650b8ca4 , 0x18ef8666 ] )
+  , Mont::unsafe_make( [ 0xc7fdae4a , 0x6b0e21b8 , 0xf3d99b9c , 0xefee9a94 , 0x3d4e192e , 0xac5d0e83 , 0xc09fa7bf , 0x12f4d4d9 ] )
+  , Mont::unsafe_make( [ 0x6315c138 , 0xa44cee00 , 0xd87d2819 , 0xc3ce52f5 , 0x9d6ed139 , 0xd5366d0e , 0xdccc2ca0 , 0x0e670f5f ] )
+  , Mont::unsafe_make( [ 0x16e44790 , 0xa6b3531f , 0x95b31ced , 0x3ae5f165 , 0xbf1e8f85 , 0x74ed4809 , 0x0a252eab , 0x0132ecc2 ] )
+  , Mont::unsafe_make( [ 0x501b89a7 , 0xb8b04e60 , 0xb615a535 , 0x48f98adb , 0x26914138 , 0x71042138 , 0x8278a846 , 0x0cc74384 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x710239ff , 0xca58a4b1 , 0x79ede916 , 0x24cb93a5 , 0xdb8c283e , 0x20566b13 , 0xd7d5db8d , 0x2213f045 ] )
+  , Mont::unsafe_make( [ 0xc588ae92 , 0xb973f1e8 , 0x8c0be609 , 0xcd541569 , 0x801cefbc , 0xefe8026e , 0xa2adf165 , 0x2cccaf8b ] )
+  , Mont::unsafe_make( [ 0x7a007894 , 0x3b5421c5 , 0xf333508c , 0xe82fcbed , 0x27c023df , 0x02c67253 , 0xf411ad30 , 0x1199bfaf ] )
+  , Mont::unsafe_make( [ 0x9e9b0d27 , 0x480568c0 , 0xb26ec703 , 0x2730766a , 0x2b642aef , 0x6aac2ce5 , 0xb0cd591c , 0x1c352b4a ] )
+  , Mont::unsafe_make( [ 0x4df51c8d , 0xda133ef0 , 0x630b6506 , 0x2e4649f7 , 0xfb9abb2a , 0x54094f51 , 0x4f184563 , 0x13ef9146 ] )
+  , Mont::unsafe_make( [ 0x3e6e5649 , 0xf4c2a527 , 0xb4efb490 , 0xce4fdfcb , 0x9f230a73 , 0x257f8133 , 0xe1ac9b89 , 0x2c9711e1 ] )
+  , Mont::unsafe_make( [ 0x932a5e8e , 0xdc314d1a , 0x1cc3e9e5 , 0xf2997708 , 0x3784e949 , 0x0524c86a , 0x4e4fcaa1 , 0x2aea50a1 ] )
+  , Mont::unsafe_make( [ 0xf80c625a , 0x3c175fdb , 0x51dcaa47 , 0x35e48d2d , 0x65148632 , 0x479d0137 , 0x4100c085 , 0x2af5eef9 ] )
+  , Mont::unsafe_make( [ 0x846a6197 , 0x38480c74 , 0x3ca68c33 , 0x00f7ae84 , 0x7f6ddd28 , 0x18503ed4 , 0x2c7da3c7 , 0x26682047 ] )
+  , Mont::unsafe_make( [ 0x6d867967 , 0x89962701 , 0xe590647f , 0x08be0bcc , 0x9140ba41 , 0x8cc4571a , 0x8b14ac9a , 0x09f09f21 ] )
+  , Mont::unsafe_make( [ 0xa318069d , 0x1fffb548 , 0xa1dc4ff4 , 0xa5a177fc , 0x4b95670f , 0x23762ff2 , 0xd6fb65cd , 0x1542f998 ] )
+  , Mont::unsafe_make( [ 0x4b440130 , 0x74f87a96 , 0x54f826d9 , 0xf1e68084 , 0x652418ea , 0x53b0e73f , 0x5a8d9a1b , 0x0e38234c ] )
+  , Mont::unsafe_make( [ 0x2cd05b64 , 0x70a36ef0 , 0xfb99d80f , 0x0c135dca , 0xcbc92646 , 0xe1372595 , 0xdf46dfae , 0x1638bfd0 ] )
+  , Mont::unsafe_make( [ 0xcae8cdd9 , 0xb2ead4e6 , 0xd5a88140 , 0xe0c9cd10 , 0x6d1a16ec , 0xd52313d9 , 0xc90c22ca , 0x0f309ceb ] )
+  , Mont::unsafe_make( [ 0x50451fef , 0x95cbde57 , 0xd6061e7a , 0x408c1def , 0x21c5834f , 0xdba3460f , 0xe37cc115 , 0x11b5fdc1 ] )
+  , Mont::unsafe_make( [ 0xdd250845 , 0x3ea88258 , 0xa6e4a640 , 0x697cebdf , 0xfddd38f3 , 0xce9d8dcc , 0x0d02023a , 0x1b8ebee4 ] )
+  , Mont::unsafe_make( [ 0xb889f16f , 0xda6a8a7e , 0xc30af33b , 0x8d1cb000 , 0x3204c769 , 0x390c630e , 0x0ac5e62e , 0x0e2c620b ] )
+  , Mont::unsafe_make( [ 0x3229d92f , 0x9b574b0b , 0x1c9e8dd6 , 0xd94b6278 , 0x98737730 , 0x928181c4 , 0x77973c17 , 0x0d5bcb8f ] )
+  , Mont::unsafe_make( [ 0x90305dd3 , 0x9f7027b8 , 0x3a59a7a2 , 0x76f2caae , 0xf94d7ac5 , 0x9f67d896 , 0x6d03f064 , 0x0c29c2c3 ] )
+  , Mont::unsafe_make( [ 0x8f79c12d , 0x7d9e39cf , 0x8137190e , 0x18d2f8a9 , 0xb921331a , 0x4139f4ba , 0x362e3078 , 0x24d38826 ] )
+  , Mont::unsafe_make( [ 0x54a6eed7 , 0xdfca182c , 0xe5f45418 , 0x66d29579 , 0x4851812f , 0xe86aadea , 0xfb0aeeb4 , 0x0a2f10b8 ] )
+  , Mont::unsafe_make( [ 0x79f7b339 , 0x299f8468 , 0x82116767 , 0xb6cf4f16 , 0xa39d8048 , 0x9a1f89ac , 0x8b24b7bc , 0x2e1376cf ] )
+  , Mont::unsafe_make( [ 0x8b54a384 , 0x9ec11e65 , 0x88518bf3 , 0x3db84c72 , 0xd7fd2d5f , 0x3c46fe31 , 0xcc9d6e4e , 0x296c0e3d ] )
+  , Mont::unsafe_make( [ 0xe0d7cb13 , 0x1fa42888 , 0x4adcb15e , 0x2825e1b8 , 0xe37220a9 , 0x38327c8f , 0x87091e75 , 0x1b90215f ] )
+  , Mont::unsafe_make( [ 0xbfa3fe9f , 0xd640ceb4 , 0xa4696d05 , 0x2173573f , 0x70d00d35 , 0x1f83dd0a , 0xb61563c7 , 0x0b805b26 ] )
+  , Mont::unsafe_make( [ 0x45c303e9 , 0xeca2e9a3 , 0x938dfaac , 0xee9b30af , 0x173f7a29 , 0x55406505 , 0x7012c975 , 0x06acc53a ] )
+  , Mont::unsafe_make( [ 0xd23cbdb5 , 0x98259567 , 0x798436c5 , 0xca380541 , 0x0ed30de9 , 0x72ddccac , 0x17d41ef5 , 0x09db70a0 ] )
+  , Mont::unsafe_make( [ 0x6cd22708 , 0xa3652af1 , 0x47722f7d , 0x036f21be , 0x3779d116 , 0x40e0963d , 0xce689f96 , 0x26614608 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x222d00ed , 0xc049def9 , 0xbd669411 , 0x50b0628b , 0xef0372f5 , 0xf4a11ff5 , 0xa9ce1b24 , 0x18130c88 ] )
+  , Mont::unsafe_make( [ 0x7e807939 , 0xb8037165 , 0x06f7fe50 , 0x6254eee3 , 0xf5b4f035 , 0x79d49e91 , 0x5ca1a3df , 0x24986c8b ] )
+  , Mont::unsafe_make( [ 0x1ccbb299 , 0xe86e85eb , 0x84b8c758 , 0xdd2ea4b3 , 0x866561ef , 0x00680c43 , 0xa86cd8a0 , 0x180138a0 ] )
+  , Mont::unsafe_make( [ 0x05038230 , 0x1441d6a6 , 0x66feb87d , 0x8b41d633 , 0x638e4161 , 0x849175f7 , 0x07425488 , 0x099c6799 ] )
+  , Mont::unsafe_make( [ 0xe8b1fef8 , 0x5e84819e , 0x679e89f8 , 0xb14fa96a , 0x0eada598 , 0x664031ff , 0xd4d8bd81 , 0x1c26e9f7 ] )
+  , Mont::unsafe_make( [ 0xeacc29ef , 0x4cdb03e2 , 0x55878f54 , 0x79888314 , 0xf2a228c8 , 0x89930bd8 , 0x1c71649f , 0x08fa9661 ] )
+  , Mont::unsafe_make( [ 0xcd78df25 , 0x9c999c63 , 0x2a76a389 , 0x2179737d , 0x44434e9e , 0x2d071de3 , 0x1f988119 , 0x05762bce ] )
+  , Mont::unsafe_make( [ 0x6f5519f4 , 0x5a49fe6b , 0x0a4be831 , 0x0cb9dc12 , 0x23faa384 , 0x1080b19c , 0x9800a3ca , 0x165e4732 ] )
+  , Mont::unsafe_make( [ 0x2aa1e372 , 0xffbf82fa , 0xb3479655 , 0xd9dc0ae1 , 0xaa4632b4 , 0x22f96bbe , 0xbaab2f38 , 0x0bd5ea7b ] )
+  , Mont::unsafe_make( [ 0x47aa723c , 0x04b817b5 , 0x1ad35b5d , 0x2ff1d92a , 0xccda823e , 0x6d7856d4 , 0xa246b33e , 0x2fce5d7c ] )
+  , Mont::unsafe_make( [ 0xf894d37b , 0xd6efba4c , 0xab395587 , 0xbf9394a3 , 0x82560047 , 0x1a0e3a20 , 0xc92a2787 , 0x0fe44ca7 ] )
+  , Mont::unsafe_make( [ 0xd1e5c82a , 0x353e4468 , 0x2632984a , 0xdc804945 , 0x788ede30 , 0xa7c73abf , 0xb8850b93 , 0x028bd2d0 ] )
+  , Mont::unsafe_make( [ 0x8dce9f02 , 0x0456503b , 0x77bad766 , 0xfd44ec4a , 0x2c8fe8dd , 0x61d6e2b0 , 0x716b28c4 , 0x05caa065 ] )
+  , Mont::unsafe_make( [ 0xcb7e237b , 0xc922b558 , 0x16287494 , 0xcc127566 , 0x85d9ddb1 , 0x0e2e71e1 , 0x6eb9c622 , 0x2e307796 ] )
+  , Mont::unsafe_make( [ 0x01fca20c , 0xc95270fb , 0x6111a352 , 0x96d27972 , 0x34301e75 , 0x6c8d8964 , 0xcb3c8a53 , 0x11aaca14 ] )
+  , Mont::unsafe_make( [ 0xea4d4290 , 0xeddfbffc , 0xed2d9c38 , 0x9a8d4b3d , 0x0ea64b98 , 0x7c7d7124 , 0x93f824d6 , 0x0e00cbed ] )
+  , Mont::unsafe_make( [ 0x98a19dbf , 0x92a78232 , 0x7faf82ca , 0x2f4e2aad , 0x6e8535be , 0x8ee75a82 , 0x1917ff12 , 0x0ee44d63 ] )
+  , Mont::unsafe_make( [ 0x105e6c51 , 0x22e31a71 , 0xa6d8b664 , 0x6e86d6e2 , 0x5acd00b4 , 0x4e8d0121 , 0xc5feb909 , 0x074cbe0c ] )
+  , Mont::unsafe_make( [ 0xddf78faf , 0x23c6a1d5 , 0x724dceca , 0x452b2043 , 0x5c2bc766 , 0x1553bb3e , 0x7a17b8f6 , 0x1040f6e4 ] )
+  , Mont::unsafe_make( [ 0x2f8f6cda , 0xab9009d2 , 0xbfeb2fb4 , 0x2bdb378c , 0xc816f1a4 , 0x3f7303c7 , 0x8c39ea08 , 0x1ec4b3d2 ] )
+  , Mont::unsafe_make( [ 0x2d9becdb , 0xb579a707 , 0x60782717 , 0x035d4985 , 0x4a7176fa , 0xb1c6e87b , 0x6cf05202 , 0x16e02705 ] )
+  , Mont::unsafe_make( [ 0x9fccbb1e , 0x33499833 , 0x89fcfadc , 0x86b15cd4 , 0x3fd89861 , 0x29547950 , 0xc294fed8 , 0x07145a44 ] )
+  , Mont::unsafe_make( [ 0x74d9201b , 0x999ee842 , 0x5ee03947 , 0xfcf79cb0 , 0x3e1f395d , 0xbf6cc050 , 0xad8ccb09 , 0x24ff4cd4 ] )
+  , Mont::unsafe_make( [ 0x142d8377 , 0xb070b51b , 0xcb24ccb8 , 0xf5047ac1 , 0xc4a62703 , 0xf73026cd , 0xa62dda9b , 0x2f75924b ] )
+  , Mont::unsafe_make( [ 0xf5580c85 , 0x6100897d , 0x4d68b8d7 , 0xea892c58 , 0x8c3df5dd , 0xc68d1d37 , 0x00b64822 , 0x1f4aeab1 ] )
+  , Mont::unsafe_make( [ 0xd2627a15 , 0x75e71325 , 0x0b5643bb , 0x8f596121 , 0xae45d194 , 0x977450f1 , 0x7a391833 , 0x1a9c6ef1 ] )
+  , Mont::unsafe_make( [ 0x771194dc , 0x13d00cc5 , 0x9afdcf94 , 0xf115cb0b , 0x6758f867 , 0x0468cf7f , 0xd68e69fa , 0x252ef269 ] )
+  , Mont::unsafe_make( [ 0x26bb9e9a , 0x78306506 , 0xdb755c58 , 0x21d16979 , 0x116bf4f1 , 0x624d83e6 , 0xec981e22 , 0x1db20c97 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x3d378ccc , 0x3e18f3b9 , 0xa5f545f6 , 0x2d7b6da9 , 0x42eb148c , 0xdd269a5b , 0x29d1de46 , 0x2a3dbb13 ] )
+  , Mont::unsafe_make( [ 0x4b704104 , 0xf91f9420 , 0xfc42e340 , 0x18c1bbc4 , 0x63c8ab13 , 0x2550a7d8 , 0x1731993a , 0x2b5c2cd3 ] )
+  , Mont::unsafe_make( [ 0xc6d8ccc2 , 0x2d5cf05e , 0x2af2b9f0 , 0xd8bc9beb , 0x0fc2c3bc , 0x741c1e66 , 0x0fcc5edf , 0x22bc2718 ] )
+  , Mont::unsafe_make( [ 0x2873bfee , 0x59e5e5d3 , 0x4c9219f3 , 0x0ba583ee , 0x7ee7e8b4 , 0x59a5c17a , 0x4514fc5d , 0x21ba28ff ] )
+  , Mont::unsafe_make( [ 0x86381251 , 0x78666abe , 0xc7075bbe , 0xb10621d7 , 0x61ff77cb , 0x2205b246 , 0xaf987845 , 0x13db64fa ] )
+  , Mont::unsafe_make( [ 0x98fa7bc9 , 0x6b5d8609 , 0x30653665 , 0xcc59b11d , 0x06ee9cc3 , 0x4e01fb74 , 0x22f7b800 , 0x25b00998 ] )
+  , Mont::unsafe_make( [ 0x81449d1a , 0x5df4b35a , 0xc5244124 , 0xfa75b33b , 0xb711850e , 0xa36c477a , 0xbe811072 , 0x0a81c4b1 ] )
+  , Mont::unsafe_make( [ 0x29bbc555 , 0x719a3788 , 0x46ae9b47 , 0xd1f918c3 , 0xe955313a , 0xdf85360d , 0xe538b0d4 , 0x19495bc9 ] )
+  , Mont::unsafe_make( [ 0xb58543f8 , 0x2646dc03 , 0xc3ae88e7 , 0x89e3df9a , 0x9ae4b849 , 0x63ca8fba , 0xd018e3cf , 0x1644e2f0 ] )
+  , Mont::unsafe_make( [ 0x175ced2b , 0xe9541522 , 0x45d0a04c , 0xdecb6101 , 0xe140781c , 0x3f5bc533 , 0x13c45096 , 0x0986b8be ] )
+  , Mont::unsafe_make( [ 0xf965d437 , 0x7da71b1c , 0xd27d795f , 0x6db6b653 , 0x0d15e6fb , 0x025e8d3f , 0x83563a86 , 0x226c1090 ] )
+  , Mont::unsafe_make( [ 0x01bba8c5 , 0x1eacbddd , 0x87a5bb7f , 0x326aa3b1 , 0x239b17d0 , 0x187a953b , 0x28bdf71b , 0x14718dc3 ] )
+  , Mont::unsafe_make( [ 0x74390227 , 0x61ccdfe2 , 0x3cb93292 , 0x601076df , 0x89717f4f , 0x21acdb8a , 0x5f8db416 , 0x1bf59c0f ] )
+  , Mont::unsafe_make( [ 0xe05b9c23 , 0x0dade8be , 0x3169111e , 0xc48f5559 , 0x931d7580 , 0xa40865a5 , 0x47efef4d , 0x0bb583a9 ] )
+  , Mont::unsafe_make( [ 0xdbc2f1e6 , 0x8180e014 , 0x90c39658 , 0x82100688 , 0x79e40614 , 0x5031e918 , 0xdf030a9c , 0x2c486ec6 ] )
+  , Mont::unsafe_make( [ 0xc507afad , 0xd2bfeae2 , 0x271f9b27 , 0x131ec34a , 0x3399d11d , 0xd890d13e , 0x052b2872 , 0x0f2e864c ] )
+  , Mont::unsafe_make( [ 0xb9a98c15 , 0xe0388274 , 0xfca6eae3 , 0x809cdba0 , 0x3e8165cf , 0x35744732 , 0x22ffd257 , 0x2fa35197 ] )
+  , Mont::unsafe_make( [ 0x3dbc5242 , 0xed50fdad , 0x4f4a59d9 , 0x80b4ae0f , 0xf94d4a9f , 0x9bbfe035 , 0x669aabc0 , 0x1a75f2ca ] )
+  , Mont::unsafe_make( [ 0xc303eaf4 , 0x232e1734 , 0xbf8d0f71 , 0xb2bc75c0 , 0x3861b044 , 0x65b14138 , 0xc084be6b , 0x2a76d316 ] )
+  , Mont::unsafe_make( [ 0x743133d4 , 0xcb4fa0fb , 0x759957aa , 0x9bea495f , 0xc00968e1 , 0x77b70957 , 0xff157b34 , 0x0189adad ] )
+  , Mont::unsafe_make( [ 0x0ae7f2b9 , 0x0fe24122 , 0x17981994 , 0x35168b91 , 0x805ac6ea , 0x4e92b52b , 0x743864a4 , 0x08e9bf14 ] )
+  , Mont::unsafe_make( [ 0xae46bb02 , 0x3d71ce5b , 0x1fd7437d , 0xd3198193 , 0x447e91ad , 0xdca0bfb8 , 0x4a4aba95 , 0x089bd407 ] )
+  , Mont::unsafe_make( [ 0x279bb015 , 0xf651eac1 , 0xea7d1c5f , 0xa663dd40 , 0xfe331770 , 0x981ad1a7 , 0x62821bf5 , 0x21f1bd7e ] )
+  , Mont::unsafe_make( [ 0x21fdb57a , 0x089ba450 , 0x4da9b802 , 0x7e44f02c , 0xd46b017b , 0xf0f88c14 , 0x85f3b014 , 0x2721cfe0 ] )
+  , Mont::unsafe_make( [ 0x852d01ad , 0xe8ef02c8 , 0x51c7397c , 0xdc48fad0 , 0xd1aa36c0 , 0xbebf0137 , 0x6ba9d4a9 , 0x0cd2560f ] )
+  , Mont::unsafe_make( [ 0x9f4333f6 , 0x5e600324 , 0x8d6ad222 , 0xe51c27e3 , 0xebaf9787 , 0xa50034de , 0x1009d843 , 0x01d1dd67 ] )
+  , Mont::unsafe_make( [ 0xdc7e0b61 , 0xc968454b , 0x546296d5 , 0xab95e8a9 , 0x02c16b07 , 0xd35f37be , 0xbd8c5392 , 0x23904c0d ] )
+  , Mont::unsafe_make( [ 0x4f1520a0 , 0xe4f9d478 , 0x50f8976a , 0x42ec68bd , 0x850e878b , 0x08987a67 , 0x9d8f4f00 , 0x2708b622 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x9a06d451 , 0x323806cd , 0x262e222c , 0xe34fbdc6 , 0x363e1db2 , 0x851ca5cc , 0x08f59610 , 0x23e75997 ] )
+  , Mont::unsafe_make( [ 0xb0d52a35 , 0x807a5f76 , 0xf1938272 , 0xedeb391e , 0x7ef22237 , 0xb211ee19 , 0x3e9573c3 , 0x0345f36a ] )
+  , Mont::unsafe_make( [ 0xbfc2ad28 , 0x92ccb07f , 0xa5589808 , 0x57e055fe , 0x1d89c9bb , 0xfa504fa3 , 0x4c6846dc , 0x20c241ed ] )
+  , Mont::unsafe_make( [ 0xe9c5ef4a , 0x20ccd18a , 0xb32b0ad6 , 0x951c5bff , 0x565dabc7 , 0x47c97660 , 0x1823d824 , 0x189eaee1 ] )
+  , Mont::unsafe_make( [ 0x2c8beb2b , 0x72a5ecc4 , 0xb129489f , 0x516d6f61 , 0xd544d0c3 , 0x9dff2ff1 , 0xae8ca84c , 0x22fa76c5 ] )
+  , Mont::unsafe_make( [ 0x92188b60 , 0xe2b2eacf , 0xc6ba1e96 , 0x6fd8b9ca , 0x3feeb4cf , 0x06d40fc4 , 0x42c3d0e8 , 0x1c61c23c ] )
+  , Mont::unsafe_make( [ 0xbcfe2715 , 0xe47e0839 , 0xaeaf4be0 , 0x53d37ae6 , 0xe5bb5c51 , 0x2d692fe5 , 0xc24117dc , 0x08740abb ] )
+  , Mont::unsafe_make( [ 0xdc9ce1b0 , 0x4c9b07b8 , 0xfa3160b4 , 0xcb77ff4e , 0x362e125c , 0xa873009d , 0x0bd5b6ca , 0x09299025 ] )
+  , Mont::unsafe_make( [ 0x006417b4 , 0xff6660e4 , 0x62d43262 , 0x68842758 , 0x122bf394 , 0xfc6a997a , 0x252d6a28 , 0x2550a2e2 ] )
+  , Mont::unsafe_make( [ 0xeadd660e , 0xf98085ab , 0x554b5dfe , 0x8419d825 , 0xe0fe723b , 0xa4014421 , 0xc075472f , 0x2703467b ] )
+  , Mont::unsafe_make( [ 0x642f21a5 , 0xf90f8ad1 , 0xc8884dda , 0xfb259820 , 0x5e802d76 , 0xf5312814 , 0x3d48b23f , 0x20b47284 ] )
+  , Mont::unsafe_make( [ 0xd5447d9d , 0xbc721973 , 0x24e5a19a , 0xe405f05c , 0xbc7c772f , 0xf2d3b7ff , 0x6772a763 , 0x0a78a279 ] )
+  , Mont::unsafe_make( [ 0xcf57ab3e , 0x4cf124ff , 0xe3221c2c , 0x47fe1196 , 0xb72ed2ba , 0xbdaf5880 , 0x9d44a165 , 0x08e0d1a2 ] )
+  , Mont::unsafe_make( [ 0xceed9e9c , 0x85b9513a , 0xddf50c6a , 0x9fa17a56 , 0x100d5eb1 , 0x1d548b43 , 0x3b8cef77 , 0x1e2de459 ] )
+  , Mont::unsafe_make( [ 0x83471317 , 0x0759e78f , 0x7ff23c52 , 0x2bf8f861 , 0x1e1e9dbb , 0x898c3cfe , 0x74b54bf7 , 0x2d196e6f ] )
+  , Mont::unsafe_make( [ 0xf7bcd86d , 0x7985c6b8 , 0x389d56a3 , 0xbc0ef93a , 0x4cb68b9a , 0xf24555ff , 0x5aa28fac , 0x1c363190 ] )
+  , Mont::unsafe_make( [ 0x29e69123 , 0x4394123a , 0x173e7c91 , 0x0d7a4086 , 0x641c43ae , 0x5b983d59 , 0x8bd18d00 , 0x1c5b9eb5 ] )
+  , Mont::unsafe_make( [ 0xc4e7d81c , 0x88c3c714 , 0x767b6678 , 0x5d728730 , 0x5736115e , 0x9bbf3d47 , 0xd99e251f , 0x2a038bf8 ] )
+  , Mont::unsafe_make( [ 0x714b2e2a , 0xaa7e94a5 , 0x5f1f8c1c , 0x95367386 , 0x4a209d3d , 0x00319a9d , 0xe27e3d6d , 0x13310336 ] )
+  , Mont::unsafe_make( [ 0x38a27036 , 0x420a659a , 0x43604ce3 , 0xe4df9750 , 0xbc787560 , 0x5b8dd436 , 0xefe2dbb5 , 0x1064b363 ] )
+  , Mont::unsafe_make( [ 0xe11b0de5 , 0x99da61d8 , 0xacd814f8 , 0x401ec753 , 0x489f09de , 0xbbff32c6 , 0x84a15adf , 0x1219737b ] )
+  , Mont::unsafe_make( [ 0x7465f570 , 0xf8d14ab4 , 0x201ca98c , 0x7dcae0cb , 0xaf73b575 , 0x4a892b49 , 0xed05d2cc , 0x0d3b2784 ] )
+  , Mont::unsafe_make( [ 0x440060b9 , 0x054e511f , 0x7812fc3f , 0xc791e494 , 0xa9ac723a , 0x8ff0ee97 , 0x5a329910 , 0x1bbd25e1 ] )
+  , Mont::unsafe_make( [ 0x828a0260 , 0x21d01031 , 0x5fc4ea98 , 0xffa7583b , 0xff4dbf12 , 0x6fdb96bd , 0x67730922 , 0x0a307358 ] )
+  , Mont::unsafe_make( [ 0x09d8a7ea , 0x55ac1dbc , 0xf5cd2f9e , 0x7cc50888 , 0xf705ed25 , 0x698e5201 , 0x0a9f2918 , 0x13d3a1b8 ] )
+  , Mont::unsafe_make( [ 0x081087f8 , 0xc5588116 , 0xd596a4cc , 0x7315a167 , 0x5f48ce23 , 0x200faaac , 0x035305ac , 0x1eb0189f ] )
+  , Mont::unsafe_make( [ 0x25c8608a , 0xb3a8a6e2 , 0x57682084 , 0xf178c3f4 , 0x239b07d5 , 0xf76f342d , 0xdc447c76 , 0x1e093fb7 ] )
+  , Mont::unsafe_make( [ 0x0bfffa63 , 0xad12d2ca , 0xc0bcb7a6 , 0x48104f32 , 0xd73a247b , 0xd8c4da44 , 0x42a17015 , 0x2b5b6432 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xa1f557e5 , 0x3e363395 , 0xcb163780 , 0x7454cef7 , 0x75102c64 , 0xef2fc4c7 , 0x6512c3a8 , 0x1070ca2e ] )
+  , Mont::unsafe_make( [ 0xe1e3e004 , 0x34b64ad2 , 0x400ea5e4 , 0x8facacde , 0x9c45c7a2 , 0x34899431 , 0xded67ef4 , 0x03847bc2 ] )
+  , Mont::unsafe_make( [ 0x1d3ebe15 , 0xf0d8a383 , 0xa92961d8 , 0x4e073110 , 0x2cd49d22 , 0x4f26cf35 , 0x163d6508 , 0x2ed70b60 ] )
+  , Mont::unsafe_make( [ 0x6df0e2ed , 0x1e6fdf6a , 0xae789e5d , 0xea617fd8 , 0x7f0b5929 , 0x7ddf0133 , 0x2cadadaa , 0x1add5f71 ] )
+  , Mont::unsafe_make( [ 0xc49f4d78 , 0x18b01eb5 , 0x4c571219 , 0x4fee6143 , 0x230fb252 , 0xf3bad0d0 , 0x9e8a6acb , 0x0148f9b0 ] )
+  , Mont::unsafe_make( [ 0x95023f6f , 0xb6bd349f , 0xc91535a3 , 0x3c483ad4 , 0xed0b9f8a , 0x9c6d1cf4 , 0x5918f6ff , 0x25e95c7d ] )
+  , Mont::unsafe_make( [ 0x2bc04ae7 , 0x4536409e , 0xd27a6273 , 0x0f6d4a32 , 0x035450cd , 0xd39b3c18 , 0x049599f5 , 0x1c87c53a ] )
+  , Mont::unsafe_make( [ 0x48ddda9a , 0xcc5a7be0 , 0x7a39ad75 , 0x8a289dd4 , 0xe130e35e , 0xee725f69 , 0xa1be7adc , 0x0fdad14f ] )
+  , Mont::unsafe_make( [ 0x3b5e2de6 , 0x5e6cf093 , 0x596d888f , 0x3ac056db , 0x4f46c075 , 0x3d8072f8 , 0x3b370d0e , 0x0cba148d ] )
+  , Mont::unsafe_make( [ 0x9c84e344 , 0xa0908e60 , 0xbeb5b222 , 0x1f288de3 , 0x6d27f309 , 0xece6f8f1 , 0x5a0eb82a , 0x1742a757 ] )
+  , Mont::unsafe_make( [ 0xbe5857ca , 0x42fc509f , 0xba3bff31 , 0x3f386ac4 , 0x11e5b746 , 0x172eb323 , 0x982a95a9 , 0x2968556d ] )
+  , Mont::unsafe_make( [ 0x13397fe5 , 0xc0fa8bc0 , 0x180109d9 , 0xc8d71d21 , 0x03d669a5 , 0xae86ba4b , 0x72623b78 , 0x086be5c7 ] )
+  , Mont::unsafe_make( [ 0x67b6ee8a , 0x78f53ee6 , 0x9463504f , 0x5380e2a7 , 0xad80bcfa , 0x5b4b3a94 , 0x37ce94c4 , 0x2959a1f2 ] )
+  , Mont::unsafe_make( [ 0xa8e60a75 , 0x792179ae , 0x63ced26c , 0x188a220e , 0xa3d320f1 , 0x6c64b1c7 , 0xd86a1243 , 0x08c506d7 ] )
+  , Mont::unsafe_make( [ 0x16370e83 , 0x63a9b463 , 0xb9b7a003 , 0xd541cb63 , 0xca1665c7 , 0x5e930b01 , 0xc2d3dae1 , 0x290bdf04 ] )
+  , Mont::unsafe_make( [ 0x35b107ed , 0x86ca0b81 , 0x7bfa0a1c , 0x48cf895f , 0x85831460 , 0x427c0d50 , 0xed3e6609 , 0x167fbcc8 ] )
+  , Mont::unsafe_make( [ 0x03d4a8b8 , 0x83ee9ed5 , 0x98f1d073 , 0x88d7c8e9 , 0x30137350 , 0x0e43ff93 , 0x68ef3929 , 0x1e3285f6 ] )
+  , Mont::unsafe_make( [ 0x37af2915 , 0x74962782 , 0x816dab48 , 0x421dcedc , 0x2c3b493d , 0x4784461b , 0x2bcd3ae6 , 0x1ca97d4b ] )
+  , Mont::unsafe_make( [ 0x648c347a , 0xd24a70e9 , 0xf0306a53 , 0x856113a1 , 0x94006b4a , 0x63991c32 , 0xc8945b13 , 0x1adec951 ] )
+  , Mont::unsafe_make( [ 0xa68355be , 0x5dfd1ad5 , 0x3949cbf6 , 0x74a2ae5f , 0x616bc3e2 , 0xfada5dca , 0x79eabde7 , 0x2bc4f736 ] )
+  , Mont::unsafe_make( [ 0x8120cb49 , 0x8f037e1b , 0xe8f417f1 , 0x5412886b , 0x939a48aa , 0xb54fb5cb , 0x1b706c53 , 0x0284a7c0 ] )
+  , Mont::unsafe_make( [ 0xa10aec50 , 0x56a519d8 , 0xe564f9dd , 0x3284dc96 , 0x6361fad2 , 0x309a3cb7 , 0x14790a0c , 0x2d64e89f ] )
+  , Mont::unsafe_make( [ 0x5cce6f2f , 0x7a25e0ec , 0x10295b9d , 0x3b3d8059 , 0x857a3a41 , 0x9c8fe1af , 0xb99e27b5 , 0x09e002da ] )
+  , Mont::unsafe_make( [ 0xf3fb91bf , 0x21259288 , 0xad8dd16d , 0x11c1357e , 0xe30f898a , 0x7b56b91d , 0x65f03b13 , 0x19bdd74c ] )
+  , Mont::unsafe_make( [ 0xae57b977 , 0xe069560c , 0x8b09d1cb , 0xf86da7cf , 0x6e4266ae , 0xbbdef4b4 , 0x70e313a7 , 0x2798018a ] )
+  , Mont::unsafe_make( [ 0x18d5b415 , 0xbff4ba54 , 0x70f16110 , 0x8ec304f5 , 0x5ab4b4a8 , 0xc51b07a8 , 0x34c6111b , 0x14404336 ] )
+  , Mont::unsafe_make( [ 0xde1eae85 , 0xac69e765 , 0xc953bf91 , 0x0b2909a6 , 0x87fd8e03 , 0xf69df5a5 , 0xdbb948b2 , 0x16c2d9f1 ] )
+  , Mont::unsafe_make( [ 0xc5a6eacf , 0x3b478f07 , 0x2da2f762 , 0x9baf9af6 , 0x39f517c0 , 0x7de4eb9c , 0x989237b2 , 0x1eb12825 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x64fcb912 , 0x3be0aeff , 0x02e6321c , 0x9b3afac4 , 0xe52c841b , 0x0dd81347 , 0x64e948b0 , 0x0b09bc88 ] )
+  , Mont::unsafe_make( [ 0xebe39394 , 0x14cd8836 , 0x7d1e3ca8 , 0xf499b89b , 0xd38df1ed , 0x018138d4 , 0x3ffcfff4 , 0x0ea50744 ] )
+  , Mont::unsafe_make( [ 0x1aa11d43 , 0xa655e9c7 , 0x4082cc29 , 0x6f32c7ae , 0xb6974255 , 0xea757599 , 0xc02385a8 , 0x1f4f75ee ] )
+  , Mont::unsafe_make( [ 0x3e311601 , 0x25ba30ce , 0x46db6026 , 0xa1ce9291 , 0x0311de45 , 0xfa84f35f , 0xf022c770 , 0x23978d24 ] )
+  , Mont::unsafe_make( [ 0xef1c7c1e , 0x9d020ffc , 0x86455726 , 0xa62c0085 , 0xd6470429 , 0x2cf89e8d , 0xa35659b5 , 0x17dbb163 ] )
+  , Mont::unsafe_make( [ 0x5f4bb337 , 0xacd97d3f , 0xd6f1c099 , 0xcef13e77 , 0xf21c8bf2 , 0xcf95d8b2 , 0xadcd4a77 , 0x05cec324 ] )
+  , Mont::unsafe_make( [ 0xe0f28aca , 0x5f60248e , 0x60a4006e , 0x0dafa428 , 0xe3983d1a , 0xcecb7dad , 0x2796cde3 , 0x2600e158 ] )
+  , Mont::unsafe_make( [ 0x434615d9 , 0x9731438c , 0xa116bba3 , 0x6534de2c , 0x30531b39 , 0xc1c1f052 , 0xfe9d6967 , 0x242bbd16 ] )
+  , Mont::unsafe_make( [ 0xa3a4c8a1 , 0x329e4adc , 0x2cbb55b9 , 0xab32c935 , 0xcfac1cde , 0xe7054379 , 0x14da7b73 , 0x19692d35 ] )
+  , Mont::unsafe_make( [ 0xc811a8be , 0xe45020ec , 0xa8c2bc83 , 0x98589ff3 , 0x38dcd104 , 0x2aae30d5 , 0xcffca736 , 0x0cef182a ] )
+  , Mont::unsafe_make( [ 0xeedee681 , 0x41f33bc0 , 0xc0f612a7 , 0x58b4a2f2 , 0xbe1048a0 , 0x4c3a852c , 0xb6bc48fe , 0x229232d1 ] )
+  , Mont::unsafe_make( [ 0x65a834f3 , 0x66ba4e69 , 0xbb11a284 , 0x8c877a02 , 0x112372db , 0xa6d0f910 , 0xcabe57c4 , 0x14ece315 ] )
+  , Mont::unsafe_make( [ 0x480b16c5 , 0x0db3a843 , 0xde3a0d97 , 0xde1a0dae , 0x4444fc85 , 0x4cf6dabc , 0xb1fbc927 , 0x1ecca678 ] )
+  , Mont::unsafe_make( [ 0xaa0d003d , 0x51bd7586 , 0x692465db , 0xb717a61c , 0x06d712ca , 0xba58c46a , 0xd472e25f , 0x1cfd8eb2 ] )
+  , Mont::unsafe_make( [ 0x7b79a3d4 , 0x7a0592a7 , 0x11bb9825 , 0x8b28f1cc , 0xeb290177 , 0xc3ab8949 , 0x973f9c1e , 0x170777a7 ] )
+  , Mont::unsafe_make( [ 0x25fa31ab , 0xf7380866 , 0x6c0e142a , 0xf2a699ee , 0x8f87531d , 0x24286d66 , 0xccc2f4d8 , 0x2cdec7ef ] )
+  , Mont::unsafe_make( [ 0xf7985389 , 0x49efb3a6 , 0x86ca7c14 , 0x0f46a255 , 0xefc0507c , 0x2f11ba75 , 0x22d21757 , 0x0acee49c ] )
+  , Mont::unsafe_make( [ 0xf735aad5 , 0x1078a609 , 0x8cce69dd , 0x23b4ea4a , 0xab7e7f9a , 0x6099ea28 , 0xfdfe62ec , 0x2492c143 ] )
+  , Mont::unsafe_make( [ 0x165f11c9 , 0x62523c61 , 0xe0574b40 , 0x06ebc042 , 0x0834fe68 , 0xf7fa1717 , 0xe0fc2cde , 0x04a7a2c6 ] )
+  , Mont::unsafe_make( [ 0x8eb7f13e , 0x7ed44a6b , 0x21c1ac67 , 0xfeabeeee , 0x78a50af9 , 0xb8441ed7 , 0x240e6e48 , 0x1dfdd01a ] )
+  , Mont::unsafe_make( [ 0xbdb9a2bb , 0xb976c519 , 0xa3105373 , 0x5a816ce1 , 0x13539dae , 0x4e38c65f , 0x695bd15f , 0x222ff357 ] )
+  , Mont::unsafe_make( [ 0x2ea762ad , 0xb943ee6f , 0xe5541897 , 0x544ca9f1 , 0x3690173c , 0xa9c9ba62 , 0x66d52d8d , 0x1e2802f7 ] )
+  , Mont::unsafe_make( [ 0xfe87ce92 , 0x6b099171 , 0xb00ba7b2 , 0xe639545c , 0x4e74374d , 0xad9be672 , 0xec75736c , 0x18deb9f3 ] )
+  , Mont::unsafe_make( [ 0x981d601f , 0x150f8dd6 , 0x35c2b2d9 , 0xf71708d9 , 0xd6aa3fba , 0xc08bb56e , 0x9a7bb785 , 0x028c159c ] )
+  , Mont::unsafe_make( [ 0x353c41c5 , 0x1bc6707f , 0x425c2dbe , 0x9923f61f , 0x5412664e , 0x4ef13c32 , 0x7b94d29c , 0x0801df7e ] )
+  , Mont::unsafe_make( [ 0x623a44d5 , 0xa0b97737 , 0xbc1d6a60 , 0xb938c8c9 , 0x14d80933 , 0xd9f1de5f , 0x6f3c0c37 , 0x17a1db4a ] )
+  , Mont::unsafe_make( [ 0x4e71ea13 , 0xd8bdc252 , 0xfaaa4555 , 0xff08487e , 0xb5372c0f , 0x72aa9924 , 0x2022df32 , 0x29dafdfc ] )
+  , Mont::unsafe_make( [ 0x1791d28a , 0xb5ff70a7 , 0x39db4459 , 0xc2047802 , 0xb30ceb28 , 0x99f0ea38 , 0x31be716e , 0x264735e6 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x86b7020f , 0xc0b895c9 , 0x9cd6115d , 0xd3f34df8 , 0xb0a967af , 0xa9017d5c , 0xc6d61af7 , 0x22e76f23 ] )
+  , Mont::unsafe_make( [ 0x0516b1bf , 0x0f9d681a , 0x670b5523 , 0x48f111dc , 0xbcc32157 , 0xaab9782b , 0xaa01916b , 0x19812865 ] )
+  , Mont::unsafe_make( [ 0x7b7f2873 , 0x0624c4e0 , 0x12e72521 , 0xbb7f2e04 , 0x2080ae6f , 0x1d4c683d , 0xbd259340 , 0x120b69c3 ] )
+  , Mont::unsafe_make( [ 0x28c1811c , 0xbd1b11a3 , 0x1cf96875 , 0x7bc35f57 , 0xd0650277 , 0xd2ea638d , 0x5421bfc2 , 0x1e4459be ] )
+  , Mont::unsafe_make( [ 0x6a439b8e , 0x70fa42bf , 0x3e4da363 , 0x37c123dd , 0x78891c03 , 0x1f1aaae7 , 0xc4211538 , 0x2b0bbc54 ] )
+  , Mont::unsafe_make( [ 0x1e3a9443 , 0x2462d188 , 0x7f85c017 , 0x57b91e13 , 0x33d290e0 , 0x680eed3c , 0x9582c0ab , 0x1a8d7004 ] )
+  , Mont::unsafe_make( [ 0xeb3e73ad , 0xef8a187c , 0xb2c7b3d9 , 0x87e63187 , 0xfd628606 , 0xc22a37ab , 0xa923b96a , 0x0c1ecfae ] )
+  , Mont::unsafe_make( [ 0x52004e7d , 0xb4cb3641 , 0xc5d09fc8 , 0x4cc094d1 , 0x1f0e14ed , 0xce3294b2 , 0xc140813a , 0x0e3cda5e ] )
+  , Mont::unsafe_make( [ 0x9f390efd , 0x48b64992 , 0x406a23b5 , 0xd91a48d2 , 0x67752669 , 0x3164c258 , 0xa6a444f7 , 0x22f67c16 ] )
+  , Mont::unsafe_make( [ 0xcfd8314d , 0xc3babcb8 , 0x3256ff74 , 0x496dc22d , 0xe3aed392 , 0xf08689ab , 0x26cfbe15 , 0x303db5d8 ] )
+  , Mont::unsafe_make( [ 0x45c9c089 , 0x6803f42c , 0xdbb3739c , 0x90d316fa , 0x64cf52e0 , 0xf527434b , 0x05d0afc7 , 0x2a16c8fd ] )
+  , Mont::unsafe_make( [ 0x8351303a , 0xa2b4d70e , 0x389a9b72 , 0x1cb83607 , 0xf98aec0a , 0x6406d374 , 0x712efc22 , 0x0392752e ] )
+  , Mont::unsafe_make( [ 0x4245de0a , 0x4ce4a013 , 0x35845bdc , 0xcbeb0844 , 0xc61a2f7e , 0x07bd2e98 , 0xc45b21c8 , 0x26d63653 ] )
+  , Mont::unsafe_make( [ 0x216166df , 0xe94abdee , 0x985c75ac , 0xa9e635bb , 0xd66e4158 , 0x0dd7e446 , 0x4d5fa048 , 0x1ba371e3 ] )
+  , Mont::unsafe_make( [ 0xb73c645c , 0x5c337c70 , 0xd9994c29 , 0x0c4bb511 , 0x3f0d04a4 , 0xbff7f349 , 0x4a53a359 , 0x2b689e46 ] )
+  , Mont::unsafe_make( [ 0xb9508aeb , 0x2d624b3b , 0xa93f0ded , 0x962f971d , 0x15a4864f , 0x9b023ccd , 0xb7836636 , 0x018b5bc3 ] )
+  , Mont::unsafe_make( [ 0x2ef64e1d , 0x3354fb6d , 0xe214dce7 , 0x992b388a , 0x3c131de9 , 0xdd7ce761 , 0x99edd000 , 0x05f8d024 ] )
+  , Mont::unsafe_make( [ 0x1fcfa2c3 , 0x409abb92 , 0x2be85ced , 0xf47538b6 , 0x68084193 , 0x24ac2fdd , 0x8f4f3436 , 0x03ec284e ] )
+  , Mont::unsafe_make( [ 0x1752db28 , 0x105c7968 , 0x5aed62c2 , 0x6f716647 , 0x89b4b06b , 0x6bf1ca5c , 0x8d841ca7 , 0x303220b0 ] )
+  , Mont::unsafe_make( [ 0xd59f9404 , 0x3365beee , 0x2e33ab5d , 0x966f747a , 0x3088ddd6 , 0xb51f7834 , 0xc18e12a0 , 0x0c917059 ] )
+  , Mont::unsafe_make( [ 0x2e89fde6 , 0xfbb6bf38 , 0xeda18160 , 0x4a7efb5b , 0x32d96920 , 0x2aeee4e1 , 0xfc1bfdba , 0x073033b3 ] )
+  , Mont::unsafe_make( [ 0xbe21ed08 , 0x347b214d , 0xcba3bab9 , 0x88fc8d4d , 0x2b694c13 , 0xc61fceb8 , 0xed158a57 , 0x0968a5a1 ] )
+  , Mont::unsafe_make( [ 0x26af75b2 , 0xed2d987a , 0xd3c07ce4 , 0x1decb9ae , 0x0d811d99 , 0x4db7329e , 0xca74080f , 0x16cd3833 ] )
+  , Mont::unsafe_make( [ 0x05b54085 , 0xa7362fdc , 0x8fedeca5 , 0x4bbb4296 , 0x7c26f32c , 0xc409cdaf , 0x13273c93 , 0x2074882c ] )
+  , Mont::unsafe_make( [ 0xeaa5fd37 , 0x083df04f , 0x8ca82890 , 0x30be77e0 , 0x714d491e , 0xbfc548b6 , 0xa3b5e42f , 0x24c86765 ] )
+  , Mont::unsafe_make( [ 0x9d376bc9 , 0x31321266 , 0xdce3c437 , 0x65b92583 , 0x2f8975ef , 0x27845518 , 0x78be11be , 0x04de9bc8 ] )
+  , Mont::unsafe_make( [ 0xd8b75959 , 0xf8156167 , 0x50241d3c , 0xbf643954 , 0xbbbfc0f1 , 0x43120e65 , 0x973c5828 , 0x2545e6ac ] )
+  , Mont::unsafe_make( [ 0xe1e93d5b , 0x19b7e98f , 0xac7047de , 0x20806e8e , 0xa32d6699 , 0xdbed33b4 , 0x59ce74a3 , 0x113d18a8 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x1c0d1195 , 0x16b436ad , 0x9da9bffe , 0x9d647209 , 0xcc6b915a , 0xd9b487d0 , 0xd27f1d89 , 0x134a2b0d ] )
+  , Mont::unsafe_make( [ 0xb01b8bdf , 0xdeee9e0d , 0xad3ad599 , 0xc4896994 , 0xa3ebea2d , 0x5309ea03 , 0x5fb0c189 , 0x2ba3c196 ] )
+  , Mont::unsafe_make( [ 0x3a30b6f4 , 0x082c55f6 , 0x14235235 , 0x71ecd740 , 0x2b8b936a , 0x7cfbdd32 , 0x4bafba05 , 0x0abcce5c ] )
+  , Mont::unsafe_make( [ 0x1a3d97f4 , 0x4cfb5660 , 0x68bc379e , 0x189cd401 , 0xf4f67695 , 0xa8634745 , 0xea4d51d8 , 0x2c2c06fe ] )
+  , Mont::unsafe_make( [ 0x56ade0e3 , 0x9f7697be , 0x667da615 , 0x4408d058 , 0x9806b2ac , 0x5f4c0725 , 0xb443c052 , 0x0c220fe2 ] )
+  , Mont::unsafe_make( [ 0x7906338a , 0xc6f81a98 , 0x84fda500 , 0x66565598 , 0x04cf0537 , 0xe9348fd9 , 0xff7e087d , 0x0b936444 ] )
+  , Mont::unsafe_make( [ 0xf778d283 , 0x78017479 , 0x65102fd4 , 0x2b60ecbf , 0xd0788a96 , 0xde923d3f , 0xe0aabece , 0x0009cf98 ] )
+  , Mont::unsafe_make( [ 0xc49e2ca0 , 0xb8f9689a , 0xc7d9d22a , 0x91ee9b28 , 0xc5a82b0f , 0xe6caa561 , 0x02274b67 , 0x0ccbdce2 ] )
+  , Mont::unsafe_make( [ 0xeae8e9fb , 0x2cbc4650 , 0xfdf76cbd , 0x1daae420 , 0x0635be7d , 0x2c2ebe26 , 0x38426f8c , 0x11799ec8 ] )
+  , Mont::unsafe_make( [ 0xa68ade88 , 0x84334301 , 0x8f70dc1a , 0x0fa95350 , 0xba49d67e , 0x88f9d8e2 , 0x945bad0b , 0x2c76b44f ] )
+  , Mont::unsafe_make( [ 0x75ce8004 , 0x6a300861 , 0xc3f8361b , 0xda2537ae , 0x1dc7a0db , 0xaf886528 , 0x5a9fe5a1 , 0x18849519 ] )
+  , Mont::unsafe_make( [ 0xd29e0967 , 0x45435744 , 0xf4f19f5c , 0x8af2bea0 , 0x1fda0e82 , 0xf480b212 , 0xeef3c05f , 0x15e6ae08 ] )
+  , Mont::unsafe_make( [ 0xb27e30bb , 0xeeb23f2e , 0x56b30e54 , 0xfd3d9471 , 0x8c966a41 , 0xeefedf3b , 0xd5777bda , 0x2d98cf57 ] )
+  , Mont::unsafe_make( [ 0xa6088b22 , 0x2c083d4d , 0xd264182e , 0x25da0623 , 0xd62da4fb , 0xa0fdfcd6 , 0x9c883fbf , 0x0fd3eb1c ] )
+  , Mont::unsafe_make( [ 0x9f0fcd1b , 0x246a8a08 , 0x3ef2d7d9 , 0x79507b05 , 0x5e3d28b6 , 0xc7c4ca49 , 0x8e3d3e95 , 0x0c0332eb ] )
+  , Mont::unsafe_make( [ 0x6dbfdbe9 , 0x6b6533b3 , 0x749becf1 , 0xd6476142 , 0x422ecdaa , 0x54b2025c , 0xe002f7b8 , 0x08aa759b ] )
+  , Mont::unsafe_make( [ 0x92fbec80 , 0xf909941c , 0xd01a7a3f , 0x5b2fa7b0 , 0xb3b648c2 , 0x8c75ba8e , 0x9b3d79b8 , 0x2db802d9 ] )
+  , Mont::unsafe_make( [ 0x379352d6 , 0xf83c6f5c , 0x2c38bf23 , 0xf7855c0b , 0x7f57a0b0 , 0xca0bf62e , 0x53271944 , 0x142bee90 ] )
+  , Mont::unsafe_make( [ 0x05eec4a2 , 0xc8ae1de9 , 0x53252115 , 0x6277a672 , 0x8661300a , 0x410fbe69 , 0x8b159104 , 0x2ca13f18 ] )
+  , Mont::unsafe_make( [ 0x3a2bdf13 , 0xa66df361 , 0x1be3af7e , 0xc02cbf3d , 0xef0f6aae , 0x107d3a32 , 0xc65abcde , 0x1c93f96f ] )
+  , Mont::unsafe_make( [ 0xfa4cd591 , 0x0f48e66f , 0xbf4f327d , 0xadb4a570 , 0x188f67e5 , 0xc6d64056 , 0xf8bea608 , 0x089a1de4 ] )
+  , Mont::unsafe_make( [ 0x7e1f161d , 0x2d20a5aa , 0x5ff0cfb4 , 0x359b32ee , 0x8b9db654 , 0x3be1a02a , 0xadbd40fc , 0x11eb56ee ] )
+  , Mont::unsafe_make( [ 0xd4ab8fec , 0x58feda2f , 0xb8e471e2 , 0x84ef9648 , 0xd508f6c6 , 0x783a5de6 , 0x0d43ef5b , 0x043c8533 ] )
+  , Mont::unsafe_make( [ 0x8d01123f , 0x9794c2b2 , 0xdaf72c73 , 0xfdac2eb2 , 0x8e3bb06a , 0x97b8da55 , 0xbcae595b , 0x14ed2047 ] )
+  , Mont::unsafe_make( [ 0x9ff2a221 , 0x2c1eb1b0 , 0x3906a669 , 0xb0e95f6b , 0xd8829202 , 0x6682d220 , 0x6fea3b9f , 0x10041ee5 ] )
+  , Mont::unsafe_make( [ 0xec4b4364 , 0x5b09b020 , 0xcab5b40c , 0xd6bcd202 , 0xfa00e4cc , 0x91279455 , 0x4c0f62a7 , 0x1b756b35 ] )
+  , Mont::unsafe_make( [ 0x772aebd3 , 0x3b05e063 , 0xc9010e59 , 0x33e3afcf , 0x0c7a39d1 , 0xa788d13c , 0xd83b11e9 , 0x2dffe3b1 ] )
+  , Mont::unsafe_make( [ 0x404c892a , 0x800b01e9 , 0x796257b0 , 0x70a7af60 , 0x2612ce84 , 0xed6733c9 , 0xf6235bd8 , 0x0669b1a4 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xc9fb935f , 0xa41ffb36 , 0x2dfb08f6 , 0x4be9fe15 , 0xe9d442d2 , 0xf848357d , 0xf9cd222a , 0x083a94d4 ] )
+  , Mont::unsafe_make( [ 0x8ee667c2 , 0x3aa998c6 , 0x0bcaf85e , 0xdb282ec1 , 0x9f861b28 , 0x9388f139 , 0xf82f3d8d , 0x1936bcf0 ] )
+  , Mont::unsafe_make( [ 0xd2ca3255 , 0xe149702c , 0xfd08624f , 0x076e89fe , 0x8d860843 , 0xf647cde5 , 0xa5f7d6ee , 0x0b2c5f82 ] )
+  , Mont::unsafe_make( [ 0x33502ba1 , 0x1a662869 , 0xd94c8c01 , 0x6490deb0 , 0x27e1ea2c , 0xf69c90b1 , 0xa3db8ec3 , 0x1ee10f0e ] )
+  , Mont::unsafe_make( [ 0x459dc1dd , 0x860340c1 , 0x277cd4fd , 0x1176a0c2 , 0x8919ffe0 , 0x0d4b6355 , 0xcb2b90d9 , 0x19b00869 ] )
+  , Mont::unsafe_make( [ 0xf76a783a , 0xa9358193 , 0x25d1374d , 0xf3114394 , 0x1be844d2 , 0x070196e0 , 0x23d92f55 , 0x2e37ea47 ] )
+  , Mont::unsafe_make( [ 0x2e497f23 , 0x81585421 , 0xe3ca0813 , 0x7640a370 , 0xd017261a , 0xc38cb074 , 0x1076ca6a , 0x1f260a3f ] )
+  , Mont::unsafe_make( [ 0x45246bda , 0x732b94d9 , 0x1bedcdec , 0x5c583212 , 0xb5860daf , 0xb92f11dc , 0xf027a271 , 0x01a7b24e ] )
+  , Mont::unsafe_make( [ 0xf227b1fd , 0xcd1c11a2 , 0x138f90f4 , 0xd8cc1fb1 , 0xb82819f5 , 0x1cf153be , 0x036c4a07 , 0x1f8881ab ] )
+  , Mont::unsafe_make( [ 0xd18b7395 , 0xd6e8c38a , 0xd6cd34df , 0x6fbcb957 , 0xfa31a68c , 0xe42a4753 , 0x98d0b79c , 0x2226bd7f ] )
+  , Mont::unsafe_make( [ 0x8f07fabe , 0xf9283921 , 0xf0cb2d63 , 0xf5a7db17 , 0x92173264 , 0x2f4bfd36 , 0xb61fc5ea , 0x162b3b0c ] )
+  , Mont::unsafe_make( [ 0xd24540c2 , 0x58d2e25b , 0x9d1f6c0a , 0xe7de3c63 , 0x7bdadbb5 , 0x5481f748 , 0xdbe745a2 , 0x07d384f7 ] )
+  , Mont::unsafe_make( [ 0xb6629f01 , 0x3abb19d0 , 0x0cf72af7 , 0xc9f3a44e , 0x6290fd8c , 0x4f8e012e , 0x8db35654 , 0x27e0b72c ] )
+  , Mont::unsafe_make( [ 0xb119910b , 0x28a88483 , 0x24ec19c1 , 0x1cde0bf0 , 0x0b56e815 , 0x6c37dca5 , 0x8c8803b7 , 0x1ed91325 ] )
+  , Mont::unsafe_make( [ 0x78d61b72 , 0x040f762c , 0x562e7b65 , 0xddc78565 , 0x5c4d7c08 , 0x6942c2f7 , 0x3b17368f , 0x0611fbff ] )
+  , Mont::unsafe_make( [ 0x8fd4c198 , 0x841d6159 , 0x2131cd7e , 0x9458bc93 , 0x91ff776b , 0x398d5303 , 0x63d8e96b , 0x22619647 ] )
+  , Mont::unsafe_make( [ 0x91668e1b , 0x0e36bd3a , 0x65be8bc2 , 0xfab5d655 , 0x6fb30610 , 0x30aef258 , 0x3b427b1e , 0x10dce004 ] )
+  , Mont::unsafe_make( [ 0x8b378c43 , 0x20ece222 , 0x2b75ec23 , 0x2113c5bf , 0xc2b0d012 , 0xf2fe51bb , 0x5f019ab2 , 0x15f590cb ] )
+  , Mont::unsafe_make( [ 0x9389027a , 0x2533e6f8 , 0xbd3ac6ea , 0x7ea20190 , 0x3af160cd , 0x714953a0 , 0xeea7f39c , 0x15cc50a0 ] )
+  , Mont::unsafe_make( [ 0x52a88a6d , 0x52f56ee8 , 0x457038d4 , 0xdc2d2145 , 0x8bfeb591 , 0x206fdb34 , 0x9cff6b9b , 0x2601726d ] )
+  , Mont::unsafe_make( [ 0x43b410bb , 0xd895cdc6 , 0xb2fecf91 , 0x9c3d6597 , 0xbac319b8 , 0x086b3817 , 0xa0e741fc , 0x1eb577f9 ] )
+  , Mont::unsafe_make( [ 0xa7ec8826 , 0xf493641a , 0xcbafe9d0 , 0x3e3d1f85 , 0x98d0ab97 , 0x117e3dea , 0xc05ceae4 , 0x0e94ee98 ] )
+  , Mont::unsafe_make( [ 0x049d1456 , 0x247cd35b , 0x97edbd85 , 0x1d1daa2a , 0xade0afb1 , 0x24bf53e4 , 0x2acb0628 , 0x1600b40d ] )
+  , Mont::unsafe_make( [ 0x57271d3d , 0x3c195e9e , 0x7b7380d9 , 0x759d8b32 , 0xdf546efa , 0xe30cfa84 , 0xfd07d671 , 0x10cb624a ] )
+  , Mont::unsafe_make( [ 0xbd9c8e22 , 0x1e456599 , 0xaac2e9b2 , 0xf46be7f6 , 0x99eeec49 , 0x119433f8 , 0x444ca2fc , 0x2a9f19bb ] )
+  , Mont::unsafe_make( [ 0x1b9e1f54 , 0xa4bc090e , 0x9db39c38 , 0x5834c4e7 , 0x993d4fae , 0xff6bc228 , 0xc9d1fb74 , 0x0fb1fb64 ] )
+  , Mont::unsafe_make( [ 0xf0d5e874 , 0x8350d0e7 , 0xe6898896 , 0x5771c865 , 0xa9776e5b , 0x9ce53bdb , 0x07ed803a , 0x0d1ff9f0 ] )
+  , Mont::unsafe_make( [ 0xc59c3ab8 , 0x154a0902 , 0x7aad93cf , 0xba7add61 , 0xd08a3a09 , 0xd1663d03 , 0x9936fa97 , 0x2fe5e1cc ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x8d69b7d6 , 0x95b1d43b , 0xa338ca9d , 0xf836ac65 , 0x050cfce7 , 0xb852fd95 , 0xda4e7887 , 0x06f73e20 ] )
+  , Mont::unsafe_make( [ 0x6e6f98de , 0xe2ace1ff , 0x71c85f90 , 0x04fab646 , 0x379d169b , 0x0bb7c8d6 , 0x9a77cdc4 , 0x0c2beef8 ] )
+  , Mont::unsafe_make( [ 0xab4613a9 , 0xa7b8a730 , 0x3818e0de , 0xe93ec15e , 0x1be6c971 , 0x5cc69ed9 , 0xa5171c98 , 0x2206f470 ] )
+  , Mont::unsafe_make( [ 0xfdde4674 , 0x81e3e5d7 , 0xaf9eadc5 , 0x50ea90dc , 0x9b44e97e , 0xd1c505d4 , 0x2025e5c5 , 0x2da54776 ] )
+  , Mont::unsafe_make( [ 0x42c4b440 , 0x2e113b0a , 0xb1cc5537 , 0x58b3f742 , 0x2b58c936 , 0xa0a245e2 , 0x852b1024 , 0x11113250 ] )
+  , Mont::unsafe_make( [ 0xe4a610d8 , 0x20471f1d , 0x39ee919b , 0x7803bac8 , 0xd2e42f05 , 0xced5ef84 , 0xc9c9d274 , 0x0f2b7b67 ] )
+  , Mont::unsafe_make( [ 0x7d5ad78f , 0xef6f8b10 , 0x65add21b , 0xc3ae0231 , 0x57b0a2b4 , 0xb870586f , 0xffb4aa98 , 0x17e08b5f ] )
+  , Mont::unsafe_make( [ 0x68968517 , 0xfd6fe511 , 0xd12976e5 , 0x7928fd76 , 0xa0c0f21f , 0x0b2930ed , 0x582d6e89 , 0x1ebc5af7 ] )
+  , Mont::unsafe_make( [ 0x6a84f128 , 0xfe58b309 , 0x57334911 , 0xeecc24ac , 0x214fae28 , 0x3a85c010 , 0x28b1300d , 0x0ded4558 ] )
+  , Mont::unsafe_make( [ 0x60496f0e , 0x141dc06e , 0x5af9d13f , 0xeff308f4 , 0x74b0ee93 , 0x9b3f19eb , 0xc8612892 , 0x05bea728 ] )
+  , Mont::unsafe_make( [ 0x41423a86 , 0x91f26beb , 0xb12ff7d9 , 0x95bf968f , 0xa78d77a8 , 0xa8c53776 , 0xda131f71 , 0x118dda0b ] )
+  , Mont::unsafe_make( [ 0xef48a7c9 , 0x7d340324 , 0x1969ea02 , 0xc963d5fd , 0xea1f2d77 , 0x98a1ad5d , 0x9d2944af , 0x140c209b ] )
+  , Mont::unsafe_make( [ 0xffd7ce71 , 0x4a120912 , 0xf761509a , 0xb92868fd , 0x38deeb69 , 0x56c7f510 , 0xd98c929b , 0x074c516e ] )
+  , Mont::unsafe_make( [ 0x2487b6cf , 0xe1460e91 , 0x82cf59f3 , 0x1304138c , 0x0320349c , 0x91169ca0 , 0x45e71282 , 0x1eea1613 ] )
+  , Mont::unsafe_make( [ 0xc4673f95 , 0xea6925a7 , 0xbb988e8a , 0x8bcbaaf4 , 0x9df146cb , 0xddb5c7f0 , 0x31e1ec88 , 0x0bd52b42 ] )
+  , Mont::unsafe_make( [ 0xee090ef4 , 0x72e21d7b , 0x916a4e37 , 0x6a58f701 , 0x5671128c , 0x6158cfaa , 0x410bf37b , 0x0a85079b ] )
+  , Mont::unsafe_make( [ 0xca97488a , 0x8b72816e , 0x57507135 , 0x23f31dfd , 0xd736f0ac , 0x3ed4b9b1 , 0x8a7d87d5 , 0x02aff66e ] )
+  , Mont::unsafe_make( [ 0x35c8452d , 0x953c457a , 0x12719ab4 , 0xf7760062 , 0xb2a92ee8 , 0x887a4f5f , 0x8d6e8e93 , 0x289b2d4a ] )
+  , Mont::unsafe_make( [ 0xfc6ac8df , 0xb47b09c2 , 0xe182cf9b , 0x78e50d95 , 0x2f907eb8 , 0x1abd9dc4 , 0x204f3984 , 0x25cf6c85 ] )
+  , Mont::unsafe_make( [ 0x803e2da4 , 0x50c2a594 , 0x95193576 , 0x0513cd74 , 0x34001966 , 0x5c7c328c , 0x1cfd74be , 0x00190237 ] )
+  , Mont::unsafe_make( [ 0x73cafa2f , 0x198815f9 , 0x98d0683d , 0x1fe6313b , 0xb8ff8ada , 0xcf0a7602 , 0x64c0a0ad , 0x207413a1 ] )
+  , Mont::unsafe_make( [ 0x507b353b , 0x55d417a0 , 0x64715ac9 , 0xe248bc7b , 0xfb807457 , 0xc6a687e6 , 0x9c0918dc , 0x0f1e9e9a ] )
+  , Mont::unsafe_make( [ 0x744bc4f7 , 0xae0dd599 , 0x09770000 , 0x6084396a , 0xa2e56cba , 0xdf95b414 , 0x739c2516 , 0x1816851e ] )
+  , Mont::unsafe_make( [ 0xe918f92b , 0x15dded69 , 0x5d4121ea , 0x7f98f56f , 0x0b824dbc , 0x0cd0ee18 , 0x77213a27 , 0x2d90f0f5 ] )
+  , Mont::unsafe_make( [ 0x61d0adee , 0x7fabe482 , 0xa09be3e3 , 0xb75238dd , 0x272938e3 , 0x5f0e7abb , 0x27c2087d , 0x11b8bdce ] )
+  , Mont::unsafe_make( [ 0x1f9e4902 , 0x24905b34 , 0x9dd9b959 , 0x6e26cabe , 0x455719b0 , 0xc32033d5 , 0x16ff2c1b , 0x271dc481 ] )
+  , Mont::unsafe_make( [ 0xb317fe7c , 0x9ebaba99 , 0x957ffa03 , 0xd1796546 , 0x84660f2e , 0xb24aa5d5 , 0xe906c243 , 0x1516e981 ] )
+  , Mont::unsafe_make( [ 0x3486d645 , 0xa5e9574e , 0x063f77ce , 0xc5a2a55c , 0x01e65731 , 0x22b3a43c , 0x09b574b1 , 0x2475717a ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xe6f76a0a , 0x88486270 , 0xab58e923 , 0x3f8e2466 , 0x9c0dcc27 , 0xd7b2e296 , 0x4e4e384a , 0x022fb55d ] )
+  , Mont::unsafe_make( [ 0x3f1fee2a , 0x8bb338dc , 0x98dd2dfe , 0x89a42959 , 0x7d11447b , 0x7bf64825 , 0xcaadfd26 , 0x2d9e7f08 ] )
+  , Mont::unsafe_make( [ 0xe740e8fa , 0xa6cfa802 , 0x8367a10a , 0x96a7b8e0 , 0x01c268b0 , 0x2c7d8700 , 0x33bb0722 , 0x018d5807 ] )
+  , Mont::unsafe_make( [ 0x63985365 , 0x4d7a4852 , 0xe936ba78 , 0xcd3da95e , 0xdd9472a0 , 0xe4e636dd , 0xd541cad8 , 0x030a40f7 ] )
+  , Mont::unsafe_make( [ 0x84c677ba , 0x4bfab4b5 , 0x7da4aa36 , 0xf3592fec , 0xe2595349 , 0xf19f5b69 , 0xedd961fe , 0x188f83a0 ] )
+  , Mont::unsafe_make( [ 0x1ffd4419 , 0xd78ab35b , 0x1c93f84a , 0x5ee49398 , 0xb9e6fb21 , 0x4b449b59 , 0x2bc7543e , 0x1010abb0 ] )
+  , Mont::unsafe_make( [ 0xf7449fe5 , 0x9efa12e4 , 0xfb14b69c , 0xabb5f608 , 0x5c69eec9 , 0x3ff52e52 , 0xb2232c49 , 0x26087567 ] )
+  , Mont::unsafe_make( [ 0x1641a252 , 0x9da40853 , 0x26f8eda3 , 0x39e3cd5b , 0x7c24a7f2 , 0xac518096 , 0xfc60253b , 0x10a9dfe2 ] )
+  , Mont::unsafe_make( [ 0xd0c4cd95 , 0x7a3e4ea1 , 0x2922992f , 0xa371ca18 , 0x1c9140ea , 0xf872765d , 0x575b7769 , 0x064aee9b ] )
+  , Mont::unsafe_make( [ 0x972c6bd7 , 0x0308ecb1 , 0xcfb2f42a , 0x884f3aef , 0xe1c35c81 , 0x2ed230a0 , 0x6aafb5d8 , 0x13753eed ] )
+  , Mont::unsafe_make( [ 0x209ad008 , 0xa73fe4a7 , 0x9ee26ad7 , 0x5a24b697 , 0x3f09f291 , 0x4037ab46 , 0x14e47db5 , 0x2c37e264 ] )
+  , Mont::unsafe_make( [ 0xf8c1dcf2 , 0xda8bbb27 , 0x8914df04 , 0xeee32c55 , 0xc97c34c3 , 0x40fddec1 , 0x98931d05 , 0x02379037 ] )
+  , Mont::unsafe_make( [ 0x6a2b038e , 0xff62394f , 0xce423411 , 0xdb233d45 , 0xa1bb83ba , 0x8a21ae9f , 0x35d13412 , 0x20a07dc1 ] )
+  , Mont::unsafe_make( [ 0x1be39d61 , 0x8901c5f0 , 0x703c56ec , 0x8ad30290 , 0x856cfd26 , 0x39a1713c , 0x8a565935 , 0x0061cf63 ] )
+  , Mont::unsafe_make( [ 0xdfbf0e59 , 0xfa598f90 , 0xfd343a81 , 0x0f2c2b37 , 0x851a0c69 , 0xe39770a1 , 0xff55549f , 0x2050fb94 ] )
+  , Mont::unsafe_make( [ 0x38554662 , 0xfab99425 , 0x88fe1f6a , 0x561103ff , 0xa2840616 , 0xa24618be , 0x6972f8b1 , 0x04196229 ] )
+  , Mont::unsafe_make( [ 0xb659d6ad , 0x50ef9b6b , 0x884bc6bc , 0xdccde90f , 0xb864b053 , 0x4df6c371 , 0x1c7e88bc , 0x103f2ed2 ] )
+  , Mont::unsafe_make( [ 0x65304d92 , 0x55fa1c74 , 0x924c72dc , 0x06d557fa , 0x164277a1 , 0x5e04087a , 0x7b2f00c9 , 0x2249cf66 ] )
+  , Mont::unsafe_make( [ 0xd3da90f5 , 0xb9c7050d , 0xcb4300b3 , 0x08ff8c11 , 0x86c42e96 , 0xafc9ef37 , 0xb2aef989 , 0x1d15b09e ] )
+  , Mont::unsafe_make( [ 0x964c977e , 0xb831544c , 0x4d830b60 , 0xf66a8892 , 0x49032e6a , 0xffb9f1a0 , 0x16e8a67d , 0x1c95c12b ] )
+  , Mont::unsafe_make( [ 0x0746a23d , 0x699ecec2 , 0x050b595d , 0x6abf0ac8 , 0x97f11557 , 0x910002e6 , 0xc71fca7e , 0x1fa81a05 ] )
+  , Mont::unsafe_make( [ 0xdcda318c , 0x1aa5a258 , 0x0392b4c3 , 0x550afec6 , 0x7b451ea6 , 0x4adae97f , 0x30a5c4b4 , 0x0acb7311 ] )
+  , Mont::unsafe_make( [ 0x3f25a653 , 0x5b0108f9 , 0x2f0045ea , 0xa8de2ace , 0xcf0139fc , 0x9ac27cc4 , 0x20cf4f6d , 0x2192ba24 ] )
+  , Mont::unsafe_make( [ 0xf83f9432 , 0x0a9122cd , 0xfee410d8 , 0x3c429a54 , 0x637c6883 , 0xa6264c5d , 0xd8832855 , 0x20b16205 ] )
+  , Mont::unsafe_make( [ 0x62a77779 , 0x973c05b8 , 0x7d281edb , 0xf84c6820 , 0x9eba17ca , 0x75d278d9 , 0x2e04765d , 0x04001553 ] )
+  , Mont::unsafe_make( [ 0xabd6823c , 0xddda665a , 0x0f3a44c0 , 0xafec4931 , 0x806ea45f , 0x35acd1cb , 0x6013ec36 , 0x18e01176 ] )
+  , Mont::unsafe_make( [ 0x123b8485 , 0x4fd3d57f , 0x8ac30048 , 0x91d15dae , 0x9b78059f , 0x145b9771 , 0x2185b164 , 0x1b9e5bf5 ] )
+  , Mont::unsafe_make( [ 0x54775d63 , 0x8001fdc7 , 0xe5dae230 , 0x126bd7bb , 0x4007962b , 0x49eb2383 , 0x324abba2 , 0x07d94302 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x7617eea8 , 0x014b4880 , 0xe811420d , 0x36f71f58 , 0xe653636b , 0x53317e7b , 0x2301f0ed , 0x21054a81 ] )
+  , Mont::unsafe_make( [ 0x2d042ae6 , 0xd240ecd7 , 0x3b814736 , 0xcd15a64c , 0xe880e16f , 0x49ad8918 , 0xa432bba2 , 0x1fc0e9fb ] )
+  , Mont::unsafe_make( [ 0x0997f9e0 , 0xcbe7fb84 , 0x6592a79d , 0x85b2123d , 0x956dd6c8 , 0xbea5e24f , 0xf3dd8477 , 0x2a19d7f1 ] )
+  , Mont::unsafe_make( [ 0x6e1ce1cc , 0xc9d5b2ac , 0x3c0b4ce4 , 0x6225063b , 0x35c7bc2d , 0xbf5a9098 , 0xec4e782a , 0x23256171 ] )
+  , Mont::unsafe_make( [ 0xb8cfa90e , 0xa8db47c5 , 0x779e7051 , 0xc804b332 , 0x6ee0541c , 0xc60a1b60 , 0x6d04532c , 0x236a5f75 ] )
+  , Mont::unsafe_make( [ 0x4857c205 , 0x2958798f , 0x71042bba , 0x73e3a5c1 , 0x7316c401 , 0x2944e0d9 , 0xdf27cc29 , 0x041d951d ] )
+  , Mont::unsafe_make( [ 0xff197e4e , 0xe8d3fa4b , 0x3e93ec90 , 0x68f249f4 , 0x70e62199 , 0x863c281b , 0x4520aeb8 , 0x195a65ee ] )
+  , Mont::unsafe_make( [ 0x26692e6d , 0x04fda1bf , 0xeae9a7f5 , 0x7ee7bae8 , 0xd6683588 , 0xcfaf5000 , 0x8ed52300 , 0x060d8e11 ] )
+  , Mont::unsafe_make( [ 0xf9cb611a , 0xc7bc6d88 , 0x0a01bc5a , 0x33bbee0f , 0x530387ba , 0xd1a4a00e , 0x26bff6f7 , 0x0663a385 ] )
+  , Mont::unsafe_make( [ 0x63f0a45d , 0xee068975 , 0x4d65ed64 , 0x2408e74a , 0x0533f10f , 0xaf20d55f , 0x497497e8 , 0x2ac11ded ] )
+  , Mont::unsafe_make( [ 0x47243b90 , 0x4aa6c4bb , 0x74673822 , 0xab9ff44b , 0xee677635 , 0x9d529af9 , 0x4a1987a9 , 0x1d74a0cd ] )
+  , Mont::unsafe_make( [ 0x210174cb , 0x8b6c62e3 , 0x6a007277 , 0x481e7207 , 0xbec74743 , 0x9ad33258 , 0x1a47fe79 , 0x2131ba6f ] )
+  , Mont::unsafe_make( [ 0x04527b94 , 0x64da33ed , 0xcee2c5f1 , 0x6a388c59 , 0x345ea3a0 , 0x986d4c5d , 0xf5f3a51d , 0x04941a4b ] )
+  , Mont::unsafe_make( [ 0x6358d248 , 0x1cca4db3 , 0x508c0fb0 , 0x3afa1753 , 0x5a21f061 , 0x4566e7bb , 0xfa8e0b72 , 0x2cdcc3f7 ] )
+  , Mont::unsafe_make( [ 0xe7ab89db , 0xbcd3fdef , 0x72ab8407 , 0xfd5ec858 , 0x5f5f74de , 0xaf833a0c , 0x65af555d , 0x2f938998 ] )
+  , Mont::unsafe_make( [ 0x67c1936d , 0x957a0175 , 0x38e0c256 , 0xb7417a41 , 0xbe7824a7 , 0x42b5b205 , 0x19d40fe1 , 0x06488f2b ] )
+  , Mont::unsafe_make( [ 0x36d45178 , 0x51357b63 , 0x793c2fdb , 0x6e22e5dc , 0x573f8ef4 , 0xbc32dc11 , 0xae5c7211 , 0x1c2e7b94 ] )
+  , Mont::unsafe_make( [ 0x77889374 , 0xe99bcb5c , 0x211541e9 , 0x8faedb11 , 0x69843cfd , 0x51b61ce7 , 0x10c7e119 , 0x03e8a233 ] )
+  , Mont::unsafe_make( [ 0x97b037a8 , 0x06123a38 , 0x240e6a7e , 0xdb14604e , 0xaab5c43e , 0xa46de591 , 0x7dc651dd , 0x0f25fd70 ] )
+  , Mont::unsafe_make( [ 0x0d69e04e , 0x82644826 , 0x3e5ee5ec , 0x494786f7 , 0x1dc45994 , 0xdac798a8 , 0xd1208086 , 0x13265c31 ] )
+  , Mont::unsafe_make( [ 0x178b9016 , 0xade7e03c , 0xe817aec9 , 0x298d388d , 0xedcec117 , 0xa12db294 , 0xbb1aad51 , 0x2046acf0 ] )
+  , Mont::unsafe_make( [ 0x01411e71 , 0x9cd3fc39 , 0x2e82998c , 0x497df9ff , 0xc2e7a9e9 , 0xb989a87c , 0x7e5ee0e7 , 0x087ed958 ] )
+  , Mont::unsafe_make( [ 0x89bd3f4f , 0x74162615 , 0x7254bf64 , 0xd596af62 , 0x102dcf01 , 0xe4c9b138 , 0x04d5776a , 0x2663b8b4 ] )
+  , Mont::unsafe_make( [ 0xe5894b4b , 0x1530dbab , 0x496abf11 , 0x4c632548 , 0xe354197b , 0x518be762 , 0xed1e7b86 , 0x04eaccc1 ] )
+  , Mont::unsafe_make( [ 0x062a084f , 0x8d521d0b , 0xe100967a , 0xc678d06d , 0x003e9c0d , 0x3b2068a0 , 0x4dcbf3c9 , 0x163eac4f ] )
+  , Mont::unsafe_make( [ 0xd73b8262 , 0xb6eabe95 , 0x0b1e4c1d , 0x5fcc14dc , 0xaef68784 , 0x23179f1e , 0x531dd655 , 0x106019ef ] )
+  , Mont::unsafe_make( [ 0xe6f967e3 , 0xdc90e6c0 , 0x63818e37 , 0x9ca2c201 , 0xf640ed05 , 0xa6b597dd , 0x965ae019 , 0x1e5c431c ] )
+  , Mont::unsafe_make( [ 0xece60f90 , 0x5d114a38 , 0x95530dc5 , 0x9d395c15 , 0x6eaeb5f5 , 0x6b5bcb3e , 0x3b344918 , 0x0028fd8d ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x70ef72cd , 0x9becab53 , 0x780a571e , 0xfb972c53 , 0x7f69ad11 , 0x1a8f987d , 0x9c4f853f , 0x26f97395 ] )
+  , Mont::unsafe_make( [ 0x31cee5dc , 0x894a4a16 , 0xc6b059ab , 0x2effc616 , 0x161abe24 , 0x9e1ff9c1 , 0x61f23641 , 0x260405f2 ] )
+  , Mont::unsafe_make( [ 0xb4537e94 , 0x73d96276 , 0x5a389e32 , 0xfa5f93e7 , 0x32c19746 , 0x68b57c23 , 0x26241b08 , 0x1adc8683 ] )
+  , Mont::unsafe_make( [ 0x84f46765 , 0x716d7058 , 0x5e2fd9da , 0x9ae7b3bb , 0xc2e7ffc5 , 0xbb305536 , 0xd5d4a95c , 0x17749b6a ] )
+  , Mont::unsafe_make( [ 0x9ffcc005 , 0x608f73b4 , 0x9921abe1 , 0x1fb8f5bd , 0x2e86c642 , 0x2f230b4e , 0xcf42f5b4 , 0x1e0f80d0 ] )
+  , Mont::unsafe_make( [ 0xfaddc4c1 , 0xec5d47b4 , 0xc19e85fa , 0x90f9d135 , 0x365c1c20 , 0x83d1c0e1 , 0xa02d1de6 , 0x07b87d99 ] )
+  , Mont::unsafe_make( [ 0x099163dc , 0x3e2f9c31 , 0xb83d128d , 0x727b721d , 0x27aba805 , 0x5b7c70b8 , 0x46835867 , 0x096d0e8c ] )
+  , Mont::unsafe_make( [ 0xb45f3482 , 0x7f47da41 , 0x34eaae5c , 0xaaca531e , 0x0760cf3a , 0xef608aee , 0x1116b8aa , 0x2bb835a6 ] )
+  , Mont::unsafe_make( [ 0xadc1406a , 0x4453906a , 0xc203cc46 , 0x4efbad58 , 0x6c5491c3 , 0xbbe03857 , 0x69f72fa9 , 0x0102c4d0 ] )
+  , Mont::unsafe_make( [ 0x1648a874 , 0x33bd5d69 , 0xc6bc00c0 , 0x1f776118 , 0x314fd420 , 0x22666497 , 0xcb094eb7 , 0x1537c036 ] )
+  , Mont::unsafe_make( [ 0xa9ea3717 , 0xc810ba4b , 0x708556fd , 0xe127ca23 , 0xdc7c1a5c , 0x21f0fc2d , 0x30711e74 , 0x12fe81bb ] )
+  , Mont::unsafe_make( [ 0xd099810f , 0x40659235 , 0x21d1a7e7 , 0xb231651e , 0x6a4a598f , 0xa0d5f50f , 0x17854f46 , 0x03ef3aeb ] )
+  , Mont::unsafe_make( [ 0x22c3e95a , 0x310b73ae , 0xb9ec8525 , 0xcf17a772 , 0x0de60bbc , 0xf2fa048b , 0x62f434d2 , 0x22ac21dc ] )
+  , Mont::unsafe_make( [ 0xa43cb3e7 , 0xf6a3aa92 , 0x5193ccf1 , 0x16bd0d4a , 0x4ec3a971 , 0x9c0502a9 , 0x752b71b1 , 0x0591f482 ] )
+  , Mont::unsafe_make( [ 0xbd987976 , 0xaf396bc9 , 0x1ed51631 , 0x3066f54c , 0x3537e1b6 , 0x6a3b661f , 0xe0f1fbc0 , 0x1f190082 ] )
+  , Mont::unsafe_make( [ 0xe4426887 , 0x18213105 , 0xe4e81be1 , 0x51db2493 , 0x441bebd7 , 0xa5ba044d , 0x52f64e00 , 0x19759afd ] )
+  , Mont::unsafe_make( [ 0xa66e0fc3 , 0xf37d479f , 0x8eeb0421 , 0x8bbbd646 , 0x0f4b7172 , 0x4ffc1aba , 0x6dd09c36 , 0x206e7679 ] )
+  , Mont::unsafe_make( [ 0x0a884e4a , 0xce984ce4 , 0x3f58fc84 , 0xb2e15e53 , 0x71fcd842 , 0xd7eacc20 , 0xb4c8b6e7 , 0x1dc142b0 ] )
+  , Mont::unsafe_make( [ 0x9cb65845 , 0x9fab1128 , 0x84101b1d , 0x48269619 , 0x799ee771 , 0x0c801c7a , 0x35e01da7 , 0x23dc36a6 ] )
+  , Mont::unsafe_make( [ 0xcae8b901 , 0x1b163f4e , 0x5740cc1a , 0x0f49f3c9 , 0xc7a23911 , 0x1f9e6fe1 , 0x75fee69c , 0x2a5485f0 ] )
+  , Mont::unsafe_make( [ 0xf6111b59 , 0xfdbea875 , 0x320f6bf8 , 0xc82fc96a , 0x02eb4b31 , 0xe7a2d7c8 , 0x54be46be , 0x0183a31e ] )
+  , Mont::unsafe_make( [ 0xa37fb258 , 0x4aeedddb , 0xee0cb20e , 0x79f94d16 , 0xc481c5cb , 0xb08fd3d1 , 0xe059a072 , 0x042c4963 ] )
+  , Mont::unsafe_make( [ 0x0202225a , 0x9b89e851 , 0x19330e7e , 0x24343d80 , 0x4ba88d68 , 0x722cb0d3 , 0x1831b310 , 0x1790a878 ] )
+  , Mont::unsafe_make( [ 0x83a8898a , 0xe5a49539 , 0xa694dd84 , 0xb9817cc3 , 0xe36ecebe , 0x4a3a2aed , 0xe47bea66 , 0x0a408521 ] )
+  , Mont::unsafe_make( [ 0xd00f8044 , 0xc2072cc0 , 0x9714193a , 0x2a329c59 , 0xac2f7e55 , 0xf32a2dab , 0xbba4aa06 , 0x03881e78 ] )
+  , Mont::unsafe_make( [ 0x7d2c3ffa , 0x486a1688 , 0xcb4e847b , 0x55c53c04 , 0x95031bbf , 0x78d14f37 , 0x7913dbf3 , 0x2717ae98 ] )
+  , Mont::unsafe_make( [ 0xa25e132f , 0x3c4cb831 , 0x9b94426f , 0x5039b1a9 , 0x1cfef372 , 0x21509a58 , 0x93025e74 , 0x2a166469 ] )
+  , Mont::unsafe_make( [ 0xa9bdec21 , 0xaf015cae , 0x7e25fb73 , 0x6ebb1275 , 0x6d5191b8 , 0x17c4030f , 0xb8410953 , 0x2b25876f ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xeb2e0cc0 , 0xb6c5f278 , 0xfbf29b6f , 0x02ed8b7b , 0xbada8635 , 0xbc2af223 , 0x82f06267 , 0x2c66071b ] )
+  , Mont::unsafe_make( [ 0xa1372578 , 0x5b782d4d , 0x93a74246 , 0xb6731e20 , 0x91077f05 , 0x79c82310 , 0xaef39305 , 0x26765d94 ] )
+  , Mont::unsafe_make( [ 0xd3bf8220 , 0x5693af56 , 0x47502646 , 0x809510e9 , 0x870fb4c0 , 0x5df62622 , 0x00aa24c3 , 0x1f7665c3 ] )
+  , Mont::unsafe_make( [ 0xc0b1ee64 , 0x46eb73fe , 0x91aaff76 , 0xc033d888 , 0x1a766749 , 0x95f05d46 , 0x115a3ce3 , 0x12a7f66b ] )
+  , Mont::unsafe_make( [ 0x0a2f4705 , 0x3a707f63 , 0xda1da0f1 , 0x77b7d7d0 , 0xe62e573c , 0x295675c1 , 0x0bc61858 , 0x25eeda6e ] )
+  , Mont::unsafe_make( [ 0x9a67b985 , 0x3ff40014 , 0xf4f45314 , 0xbbd058d1 , 0xaf9b3f88 , 0x8e679bd6 , 0x56b83222 , 0x208e9962 ] )
+  , Mont::unsafe_make( [ 0xae80d34b , 0x941de71b , 0x7ffdc35d , 0x2a8ce97d , 0xafebb89f , 0xc87b1548 , 0x71c2d623 , 0x0230ea0e ] )
+  , Mont::unsafe_make( [ 0xe86c7023 , 0xd4671642 , 0xcc5e6c40 , 0x64187a0c , 0x54ae6218 , 0x7a9d4656 , 0xd3c64460 , 0x0989727f ] )
+  , Mont::unsafe_make( [ 0x74da82db , 0x02a060bd , 0xfd7618b8 , 0xae047537 , 0x057ecf29 , 0xfcf61bf5 , 0xf4287b66 , 0x0d7f71f5 ] )
+  , Mont::unsafe_make( [ 0xee77ed50 , 0x7090da05 , 0xe234615e , 0xace133a4 , 0xcd58306a , 0x0dcacba4 , 0xbb2f5fe3 , 0x2fa8f8f4 ] )
+  , Mont::unsafe_make( [ 0xb02e420f , 0xfcff9031 , 0x40755d07 , 0x3f1dbb5a , 0xc9990c14 , 0xc97ecebf , 0x31648751 , 0x1a25b805 ] )
+  , Mont::unsafe_make( [ 0xff0a8548 , 0x13514d1d , 0x4d8a2829 , 0x176de216 , 0xa8ec531d , 0xaee2bedd , 0x355ca09c , 0x0d39ef78 ] )
+  , Mont::unsafe_make( [ 0xf50fe471 , 0xc4489b59 , 0x0244e47e , 0x0a6145d3 , 0xf6eabfa6 , 0x7a163e5e , 0x4e82d8b6 , 0x17f0b956 ] )
+  , Mont::unsafe_make( [ 0x772d3629 , 0x75fb31d8 , 0x4ca0f71c , 0x6984c094 , 0x96c3170f , 0xf7c2a31b , 0x2b240656 , 0x062d1524 ] )
+  , Mont::unsafe_make( [ 0x5ebec44f , 0xcaab1eaf , 0x798fc477 , 0x807321e0 , 0x40c689c3 , 0x0fc9d2a8 , 0x606faee7 , 0x21a2e769 ] )
+  , Mont::unsafe_make( [ 0x3eb5bde9 , 0x57cdacf1 , 0xd2ee32dc , 0x51c191d8 , 0xfe93be15 , 0x111622cf , 0x15595454 , 0x231360cb ] )
+  , Mont::unsafe_make( [ 0x9420187f , 0x9528b404 , 0x33848de6 , 0xe935ad4d , 0x59a66064 , 0x0c56cbd7 , 0x7483d9a8 , 0x28175378 ] )
+  , Mont::unsafe_make( [ 0xd9d7ac5e , 0x91287edb , 0x728b92b8 , 0x87fa426a , 0x9d47247e , 0x8f59a20a , 0x7e2b8503 , 0x14a8eccc ] )
+  , Mont::unsafe_make( [ 0x7936dbde , 0xe1909b5a , 0x0f942ea7 , 0x9f80e326 , 0x41b32cf4 , 0x6601c734 , 0xc9a4eb62 , 0x21ae5f19 ] )
+  , Mont::unsafe_make( [ 0x3f3eea33 , 0x28293da4 , 0x8c2dc980 , 0xc1051e1c , 0xe2c6e2fe , 0x6217daea , 0xa9ed68c7 , 0x085bb325 ] )
+  , Mont::unsafe_make( [ 0xb9333249 , 0x98a28f23 , 0x71370fd8 , 0xa00b72e3 , 0x4eb86a90 , 0x0bca9beb , 0xa0ebedbb , 0x008deb51 ] )
+  , Mont::unsafe_make( [ 0xee855a1c , 0x7c4a6ed6 , 0x48f97287 , 0xca23039d , 0x28b6f5b2 , 0xf06c2d74 , 0x126cac9d , 0x15db72a2 ] )
+  , Mont::unsafe_make( [ 0x2194f1e6 , 0x0f48e968 , 0x5fe08601 , 0x7a154899 , 0xe8f91998 , 0x795aca11 , 0x92233dd3 , 0x0b71ac24 ] )
+  , Mont::unsafe_make( [ 0xb8c02ad4 , 0xc7e090e5 , 0x786e2956 , 0xbd95b5b6 , 0xdb304bf0 , 0xff3ee6b7 , 0xf14b5764 , 0x0612c65b ] )
+  , Mont::unsafe_make( [ 0x3c249760 , 0x9496ce6e , 0x8b7c8cb0 , 0xbb547e75 , 0xb14040c6 , 0xa22bac09 , 0xa1690a53 , 0x225260be ] )
+  , Mont::unsafe_make( [ 0x3d5824cb , 0x6883d6ce , 0x07162c00 , 0x1f087152 , 0x21350e3f , 0x4b34854f , 0x1eb0f9c6 , 0x0463b088 ] )
+  , Mont::unsafe_make( [ 0x9aa828fb , 0xbe0d2955 , 0x720074ca , 0x65709859 , 0x4e031e76 , 0x835b9735 , 0xbf01f507 , 0x2377488e ] )
+  , Mont::unsafe_make( [ 0x9b1142e8 , 0xe2e7781c , 0xfc02f369 , 0xc00e29ec , 0xb5ebfe70 , 0x26c1a14d , 0xa10fb526 , 0x1a62aea0 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xec910f5a , 0x712d19b9 , 0x3300d248 , 0xd6cc04ab , 0x3c75646e , 0x5ea07cda , 0x4db86c8e , 0x0a93efce ] )
+  , Mont::unsafe_make( [ 0x0dece94a , 0xe18320db , 0x1603796b , 0xda3c557d , 0xb08b53a5 , 0xb753672c , 0xa9b535c2 , 0x1ab391fb ] )
+  , Mont::unsafe_make( [ 0x8b8e341d , 0xd57d1186 , 0xf35d8635 , 0x4e01c2a6 , 0x26e02ac1 , 0x7f276303 , 0x8120bafd , 0x2dd1ceeb ] )
+  , Mont::unsafe_make( [ 0xed45bb14 , 0x65634c09 , 0x32be7ffd , 0x02446bcc , 0x88eddc9f , 0x5fc213cd , 0x79d348e7 , 0x0f047ebc ] )
+  , Mont::unsafe_make( [ 0xbb8bdc5f , 0x73474991 , 0xe1ead8ef , 0xfeac1d1d , 0x73eaaa0d , 0x93a0b5d5 , 0x716b768c , 0x1e7a9f6b ] )
+  , Mont::unsafe_make( [ 0x7cab6d34 , 0x958dfb90 , 0xa59aabf7 , 0x254b71d6 , 0x39cc989a , 0xbd8fa728 , 0x2ff87e42 , 0x009576ab ] )
+  , Mont::unsafe_make( [ 0x0156d78a , 0xe1412488 , 0x30d313ad , 0xe92a8e91 , 0x83411a18 , 0x5adc9c5a , 0x894068db , 0x0b6cc42f ] )
+  , Mont::unsafe_make( [ 0x62ab3a9c , 0x16260ced , 0x93c93809 , 0x4341ebe7 , 0x23fa5f3e , 0xdedd64be , 0x6b2ca984 , 0x0af685b2 ] )
+  , Mont::unsafe_make( [ 0xd76dcc09 , 0x463ed278 , 0x97043457 , 0x1a0d491b , 0x2b8448a5 , 0x941f63b4 , 0x45c6e890 , 0x1c076169 ] )
+  , Mont::unsafe_make( [ 0xff87cfd2 , 0x73b28457 , 0xda1eddee , 0x8b4dccc1 , 0x841cee2f , 0x151e2fca , 0x907a9567 , 0x1b55d03b ] )
+  , Mont::unsafe_make( [ 0x963da015 , 0xedefdea5 , 0x9e9fe2c4 , 0xbd9ce6e6 , 0xf096153d , 0x8914457a , 0xc709c6fc , 0x1532a161 ] )
+  , Mont::unsafe_make( [ 0x162400be , 0x63beb7f4 , 0x91df6129 , 0xd6b503ee , 0xbda5ad0d , 0xcbc3c176 , 0x206dc930 , 0x0d11e78a ] )
+  , Mont::unsafe_make( [ 0xf1a4c88e , 0x74fb5034 , 0x8b4c89cc , 0xf6cf9420 , 0x7794bae5 , 0x742c2a8b , 0x775b7025 , 0x12f13240 ] )
+  , Mont::unsafe_make( [ 0x22adca9a , 0xbf80c5c3 , 0x745bd230 , 0x2e03c2c4 , 0x7f60c596 , 0xc7411679 , 0x98a89e76 , 0x27455144 ] )
+  , Mont::unsafe_make( [ 0x62c9cc64 , 0x7f2a79a3 , 0x500321b1 , 0xd3b02bb0 , 0x3d028a5c , 0xf22d9e74 , 0x834641ff , 0x2860a3e5 ] )
+  , Mont::unsafe_make( [ 0x0ab9aec9 , 0x627a147b , 0x3297b929 , 0xfd9c9220 , 0x114609c1 , 0xb89acf89 , 0x9e24b764 , 0x02d5a7b8 ] )
+  , Mont::unsafe_make( [ 0xc69611db , 0x35d5a57e , 0x836b5751 , 0x4eefa199 , 0x72dc711a , 0xce0bed18 , 0x83e4232f , 0x119e78b0 ] )
+  , Mont::unsafe_make( [ 0x0c845ddb , 0x2b4767ae , 0xe69eded0 , 0xa57f7a41 , 0x48176c16 , 0x91e1f95b , 0xc4f39e56 , 0x2c6aa1fd ] )
+  , Mont::unsafe_make( [ 0xbb0465b2 , 0xf19d4e97 , 0x0633a6a4 , 0x51b25ceb , 0xc3eddc8f , 0x10e89de8 , 0xe278d9b1 , 0x25b9aa14 ] )
+  , Mont::unsafe_make( [ 0x182179fe , 0x8b7f76a5 , 0xa8e1725b , 0x77c4f015 , 0xd35c9452 , 0x3ce9566d , 0xb085b3f1 , 0x24359d1d ] )
+  , Mont::unsafe_make( [ 0xfe98abf6 , 0xa06229cb , 0x0c0ca8f2 , 0xe03f6049 , 0xfa73df85 , 0xc6b51141 , 0x58d28947 , 0x04776068 ] )
+  , Mont::unsafe_make( [ 0xc68c38b0 , 0x7f3a021c , 0xdd18c630 , 0x98f0b313 , 0x1ed644c2 , 0x46e63961 , 0x5255ebb8 , 0x10151c10 ] )
+  , Mont::unsafe_make( [ 0x9e2150fa , 0xa50382b0 , 0x1d4b7020 , 0xe4bcbc95 , 0x14137b3a , 0xff6c7fb0 , 0xf88e87dd , 0x12ae3d6a ] )
+  , Mont::unsafe_make( [ 0x855ba5e3 , 0x7376dba6 , 0x79dd758f , 0x854263d9 , 0xcd10cf6c , 0x47eaac46 , 0xc01f5f4a , 0x0a5768b5 ] )
+  , Mont::unsafe_make( [ 0xb67d2c87 , 0x1ff8614c , 0x2f519e25 , 0x8f522007 , 0xb77faad9 , 0xc961d00b , 0x02fc52c8 , 0x0425e025 ] )
+  , Mont::unsafe_make( [ 0xef82aea0 , 0x4dba3479 , 0x03111be2 , 0xc0ff6d5f , 0xcaedbb57 , 0x28ad9ff0 , 0xdfe19804 , 0x0b4ab10a ] )
+  , Mont::unsafe_make( [ 0xf2dd2c17 , 0x6afc884f , 0xee90a072 , 0xeb36e505 , 0x1ccafae0 , 0x49fa6f7f , 0xa92a5267 , 0x0eb27c2c ] )
+  , Mont::unsafe_make( [ 0x7bd7d4f2 , 0x97a9c984 , 0x6764d74f , 0xc07291ba , 0xe8bea592 , 0xde54c530 , 0xce50a71f , 0x05b53f5a ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xdd43a6ee , 0x831a1417 , 0xa7ddc935 , 0xe2f50fee , 0xf0bf23b2 , 0x29bf7d87 , 0x02924d8f , 0x1a2f9cdc ] )
+  , Mont::unsafe_make( [ 0x87f2be31 , 0xab07690c , 0xdd8b7ab5 , 0x3e38b6ca , 0x78b38202 , 0xc53cf417 , 0x8e1b34bd , 0x04d1eb91 ] )
+  , Mont::unsafe_make( [ 0x7cf9f4b4 , 0x67dfe5c1 , 0x8b1cb7e6 , 0x5c5cba27 , 0x9812b702 , 0x9aa3f8fa , 0x5433eb51 , 0x0e258cbe ] )
+  , Mont::unsafe_make( [ 0xa2513094 , 0xc60b0da7 , 0xf5f5084d , 0xf48e2d16 , 0xa461522e , 0x0cc92d6d , 0x546b21bd , 0x06cc9433 ] )
+  , Mont::unsafe_make( [ 0x7441f8d1 , 0x7028582a , 0xdf8e4b34 , 0xaa330b58 , 0xa34f6ce4 , 0x69d750e2 , 0x80357119 , 0x1d628f37 ] )
+  , Mont::unsafe_make( [ 0x3c2e426f , 0x3824ccc6 , 0xcf1f5a9e , 0xfb3723f6 , 0x57be5f4f , 0xe56197fc , 0xb6c4bfce , 0x20183ab8 ] )
+  , Mont::unsafe_make( [ 0x704fa96c , 0x251d8fda , 0x088de333 , 0x4f781a41 , 0xb2c7cba0 , 0xba1e09c0 , 0x9ae123a6 , 0x02d317cd ] )
+  , Mont::unsafe_make( [ 0xce9ac5e1 , 0xe4bbd196 , 0x1d905faa , 0xb0919b95 , 0x872bcb0c , 0x493d5321 , 0x01a4cd2e , 0x14cae2c5 ] )
+  , Mont::unsafe_make( [ 0xe80b28d3 , 0xa9997400 , 0x5b994664 , 0x74b6925a , 0x9c4f5277 , 0x4a6e412b , 0x1b0b0a36 , 0x0cdc556b ] )
+  , Mont::unsafe_make( [ 0x5e782a43 , 0x1d95376a , 0x04b385ca , 0x64c478c1 , 0x7ef21fb5 , 0x9569a710 , 0x80d660ea , 0x2fc0af01 ] )
+  , Mont::unsafe_make( [ 0x7ba3830c , 0xdc1bcf9b , 0x376a57a9 , 0x0f04032f , 0x77e113fe , 0x3060b07f , 0x737c4b7b , 0x041eacca ] )
+  , Mont::unsafe_make( [ 0x6d5727c4 , 0x6a86f6e1 , 0xc749057e , 0x0c473580 , 0x9815e0bd , 0xb285b0bf , 0x998776e3 , 0x20b503d0 ] )
+  , Mont::unsafe_make( [ 0x3394aa2b , 0x037c4b60 , 0x7bf390dd , 0x09d9ca8c , 0x9493e2d7 , 0x018b8597 , 0x92d2a056 , 0x24252968 ] )
+  , Mont::unsafe_make( [ 0xc38843e9 , 0x717de697 , 0xd6b2ce3f , 0x6aee2d40 , 0x2900f19a , 0x038ec221 , 0xc2255967 , 0x05ae3caf ] )
+  , Mont::unsafe_make( [ 0x4788434b , 0x6cd79330 , 0x174cb250 , 0x96d3b67b , 0x9280d540 , 0x2bb2cc88 , 0xa34186d7 , 0x290b952f ] )
+  , Mont::unsafe_make( [ 0x4c26b4a6 , 0xc8b4e9ed , 0xa4495cb7 , 0xb07cd6df , 0x9eae0587 , 0xcf6f2777 , 0xca30bc22 , 0x1f8e83f7 ] )
+  , Mont::unsafe_make( [ 0x0ccf9766 , 0xc51837e2 , 0xcfcd4c9a , 0x7121570e , 0xc965a548 , 0xde670e3d , 0x5c20b742 , 0x29e08f20 ] )
+  , Mont::unsafe_make( [ 0x3b1b59d0 , 0x4e3371c5 , 0xb421fc37 , 0xfbe11f9e , 0xd025b9ec , 0x1c8442ec , 0x1aaa2614 , 0x2fc3c55f ] )
+  , Mont::unsafe_make( [ 0x5a57e8f7 , 0x61a5483b , 0x75003bc3 , 0x1bd2b577 , 0xc87cbf7c , 0x347feab4 , 0xe03ef3b4 , 0x1fb199eb ] )
+  , Mont::unsafe_make( [ 0x9192e09b , 0x8b6f99d7 , 0x56bd5a35 , 0xb59f169b , 0xb911809d , 0xc373dc94 , 0xc7791dbe , 0x2907895b ] )
+  , Mont::unsafe_make( [ 0x7afc4394 , 0xbd9a7055 , 0xe4ee2ae3 , 0xa796e6d1 , 0x048f218d , 0x30f98c69 , 0x31a55f41 , 0x1a2971ee ] )
+  , Mont::unsafe_make( [ 0x9765d31e , 0xedceef2e , 0xcff5b360 , 0xa5ab78a8 , 0xc6e7f897 , 0x8910e3b1 , 0xe81456c3 , 0x18747891 ] )
+  , Mont::unsafe_make( [ 0xd59db302 , 0x2eeed7d6 , 0x66e75fdf , 0xf9f4562e , 0xb5ed2165 , 0x986d91eb , 0x9aefefc0 , 0x0ea1a7b4 ] )
+  , Mont::unsafe_make( [ 0x082995ef , 0xfeed80f5 , 0xe401739e , 0xc2d67fe5 , 0xa2a987af , 0xb5bfc8c3 , 0xd2dae40b , 0x01e80338 ] )
+  , Mont::unsafe_make( [ 0x3ac95f58 , 0x69539fc5 , 0x549f4318 , 0x30f8ce77 , 0x0db82fc0 , 0x3e765934 , 0x02640f4c , 0x0927477c ] )
+  , Mont::unsafe_make( [ 0x20a5a97b , 0xabc4c031 , 0xb0b87870 , 0x64e475d3 , 0xa128767a , 0x634a8e9a , 0xb5ce2d71 , 0x065e5021 ] )
+  , Mont::unsafe_make( [ 0x8478de32 , 0x8fe70b98 , 0x1c6f945f , 0x8e3d7b8c , 0x1fc30578 , 0x36baa277 , 0x7429cfb3 , 0x16168dd8 ] )
+  , Mont::unsafe_make( [ 0x2be6bac8 , 0x800e7832 , 0xd7acbddc , 0x25e6b5a2 , 0x37211c03 , 0x6fcec4f2 , 0xe7726e2d , 0x1a2b6f14 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x05f3afe3 , 0x4042d133 , 0x78b33068 , 0x653da23c , 0xabbefce1 , 0x2aee5c15 , 0x6f130ac2 , 0x03e84717 ] )
+  , Mont::unsafe_make( [ 0xc0c9d1cd , 0x79794b0f , 0x34be4c99 , 0x9df0fd73 , 0x792cd3a3 , 0x704bfaef , 0x377f91ca , 0x1f207d3f ] )
+  , Mont::unsafe_make( [ 0x9f8efeb8 , 0xedb7663c , 0x17e3ca06 , 0x25482d03 , 0x367fdf9a , 0x211fae7c , 0xddcc1298 , 0x22ff5065 ] )
+  , Mont::unsafe_make( [ 0x76995b67 , 0x30083713 , 0x257967e6 , 0xb344bea6 , 0x1da7b977 , 0xeff44c7e , 0x1bdad808 , 0x01b5bdb8 ] )
+  , Mont::unsafe_make( [ 0x704b051f , 0x5d9b1463 , 0x50c2973f , 0x4af945aa , 0xdc8fb6dc , 0xf961626d , 0xd10e4c6d , 0x2aabf6c1 ] )
+  , Mont::unsafe_make( [ 0xbd7d1696 , 0xbcf3c103 , 0x79beae79 , 0xb57a0647 , 0x1c31ec0e , 0xf0b13893 , 0xcd1fbe44 , 0x0970d8ff ] )
+  , Mont::unsafe_make( [ 0x67f727c9 , 0x4d787a68 , 0x3d918e7f , 0x091f73e5 , 0x42716ec2 , 0xe31e289b , 0x60a4699e , 0x07262db1 ] )
+  , Mont::unsafe_make( [ 0xec7bc94e , 0x1fc16620 , 0x4f0269f9 , 0xc711b8ff , 0xb01a40a0 , 0x31fd7b74 , 0xb4df5025 , 0x1c716be9 ] )
+  , Mont::unsafe_make( [ 0x919d26c7 , 0x363a1d14 , 0x05d2f38e , 0xfd283c85 , 0xb1ec276f , 0x235e5de2 , 0xada04495 , 0x0a3fcb95 ] )
+  , Mont::unsafe_make( [ 0xb1e9acf5 , 0x04cd7677 , 0x6eba79ad , 0xdbc4558d , 0xd5dccb2a , 0x90378783 , 0x413e8f07 , 0x0d2f9518 ] )
+  , Mont::unsafe_make( [ 0x7bcbf7fc , 0xdf69bc91 , 0x217cdf2f , 0x591acfa3 , 0x0ea44c36 , 0x95f714e1 , 0xee547461 , 0x0abf3a21 ] )
+  , Mont::unsafe_make( [ 0xadcd694d , 0x51d99b2a , 0x55561f39 , 0x8f1ba34c , 0xb0171ac8 , 0x81ad90b0 , 0xef18592f , 0x25ea310e ] )
+  , Mont::unsafe_make( [ 0xbb8c06da , 0x699600c8 , 0x6eb13663 , 0x3b42a640 , 0xf3ee7577 , 0x680b9332 , 0x629af259 , 0x21567fae ] )
+  , Mont::unsafe_make( [ 0x5d23918e , 0x30b607e0 , 0xd4d2589a , 0x02d50d87 , 0xa1cc6a42 , 0x77dde2a8 , 0x5dcdb9a3 , 0x13ea4c29 ] )
+  , Mont::unsafe_make( [ 0x307a5754 , 0x1b070be3 , 0xf045e179 , 0x496746ee , 0x188aab85 , 0x3831b646 , 0x9926d822 , 0x1949982f ] )
+  , Mont::unsafe_make( [ 0x45375c05 , 0xfcd59350 , 0x04d295fa , 0x065fce2f , 0x578c7e10 , 0x51060fe8 , 0xa47909b6 , 0x1667bbb9 ] )
+  , Mont::unsafe_make( [ 0xeb4ff505 , 0x78b7abd2 , 0x8fd2f1ee , 0x97bf98f4 , 0xa48ee307 , 0x56bd5172 , 0xf7767187 , 0x22d37ea1 ] )
+  , Mont::unsafe_make( [ 0x1796862e , 0xd5105d14 , 0xebade24d , 0x81c30ac9 , 0xaac9e3f6 , 0xc544928f , 0xfe93e2c5 , 0x015c7f13 ] )
+  , Mont::unsafe_make( [ 0x939dada3 , 0x855a42e3 , 0x536160e8 , 0x61d6c9aa , 0x65eb0384 , 0x13813f5a , 0x0a13ba5f , 0x0ea16855 ] )
+  , Mont::unsafe_make( [ 0x760966fc , 0x803f28c6 , 0xb0ee1b1b , 0xe2e20a0e , 0x7b1d1445 , 0x3f06af3d , 0x42c469f5 , 0x1c3a8c14 ] )
+  , Mont::unsafe_make( [ 0x08c309e2 , 0x3a928218 , 0x509e8f45 , 0x6bd0717c , 0xa51548e3 , 0x78442f6b , 0x252db0c4 , 0x143e9a62 ] )
+  , Mont::unsafe_make( [ 0x76148234 , 0x7fbf5377 , 0x59d5f684 , 0xf26210d3 , 0x6e1ce28c , 0xf0573344 , 0x98e6c805 , 0x2ce0341e ] )
+  , Mont::unsafe_make( [ 0x5ce83cb0 , 0x99a4f86d , 0xbc4a8561 , 0x68d84d7c , 0x7cd06381 , 0x00ca3818 , 0x5c6477dd , 0x056409ac ] )
+  , Mont::unsafe_make( [ 0x54efc648 , 0x985dd7df , 0xbbdb8019 , 0x04f86512 , 0x99ac4988 , 0x402af926 , 0xb0238c2a , 0x25e0a840 ] )
+  , Mont::unsafe_make( [ 0x4d66c9ee , 0x6cf86fbe , 0x759d7761 , 0x951ffbc7 , 0xec000252 , 0xa32e1eb7 , 0x8db59312 , 0x2e9aaa97 ] )
+  , Mont::unsafe_make( [ 0xe5fdc093 , 0x3f6039b3 , 0x9841fa7a , 0xc41aea79 , 0xe2c7fe08 , 0x66eb0298 , 0x36a5db78 , 0x0624f3f9 ] )
+  , Mont::unsafe_make( [ 0x25246341 , 0xb823ab7e , 0x43b10a91 , 0x29ac8a3b , 0x1fda71f5 , 0x88b24126 , 0x08376799 , 0x157d6ce7 ] )
+  , Mont::unsafe_make( [ 0x241ab619 , 0xb2ba6031 , 0x403ef464 , 0x15e6bb0c , 0x98f0092d , 0xf1d26a54 , 0xb7abb4a8 , 0x1607b04c ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x8bbd8e0c , 0x932e0e4d , 0x0dd9e858 , 0x8f9cb184 , 0x5a44425d , 0x7b3209b6 , 0xc7d3d9e5 , 0x2eb3776b ] )
+  , Mont::unsafe_make( [ 0xde422ab7 , 0x5f872cba , 0xdcd53384 , 0x20c6e69b , 0x65aee2d0 , 0x20796cfe , 0x0d5b50ab , 0x15c40dac ] )
+  , Mont::unsafe_make( [ 0x618684b3 , 0x1d709fd0 , 0x09783a63 , 0x25122912 , 0xb0f7d118 , 0x91fd0681 , 0x652ea911 , 0x251547ca ] )
+  , Mont::unsafe_make( [ 0x8b5996e2 , 0x53ec3671 , 0x622abee1 , 0x8d327886 , 0x1054e791 , 0x259459fc , 0x5a1e0a58 , 0x2fcc3419 ] )
+  , Mont::unsafe_make( [ 0xd568ff64 , 0xde98376b , 0xa6dcf44d , 0x51dec439 , 0x6f0ce8cf , 0x4c0cc0a5 , 0xedd88d3f , 0x2ed1e996 ] )
+  , Mont::unsafe_make( [ 0x3031feb9 , 0x97f3d0d6 , 0x4173add1 , 0x313d96f5 , 0xce6dc1ea , 0x52ed5c53 , 0xe13fa67d , 0x19237aa6 ] )
+  , Mont::unsafe_make( [ 0xed34466b , 0x5f455a18 , 0x609a4f75 , 0x85a987c5 , 0x08e7ab64 , 0xc8b34e8f , 0x50eb0709 , 0x2a393fb3 ] )
+  , Mont::unsafe_make( [ 0x0c5f696b , 0x7b16e462 , 0x727d83db , 0xf8725a8e , 0x069f0e1b , 0x7244e12c , 0x0c1f3665 , 0x25c15608 ] )
+  , Mont::unsafe_make( [ 0x3ad11ae7 , 0x768d4029 , 0x6a498f5a , 0x4b2b282c , 0x2b8d1805 , 0xd533c6e0 , 0x7f0c6e84 , 0x12b50b27 ] )
+  , Mont::unsafe_make( [ 0xd9781804 , 0x1c179576 , 0xd6d2f1f1 , 0xc3b4342f , 0x6f9f407b , 0x186facfd , 0x3de0ac30 , 0x2b322193 ] )
+  , Mont::unsafe_make( [ 0x2e9626e5 , 0x51845a9d , 0x3c973320 , 0x350b77bd , 0xe38d3467 , 0x446e7583 , 0x00916490 , 0x2afefe65 ] )
+  , Mont::unsafe_make( [ 0x5ecd7ef0 , 0x3888df85 , 0x10425ce4 , 0xb4e56cfb , 0x3801dcc3 , 0xa83e10d9 , 0x5c5d1f28 , 0x1aacb679 ] )
+  , Mont::unsafe_make( [ 0xb9594e39 , 0xcd23b9ac , 0x32c80d3e , 0x94527708 , 0x8349bd52 , 0x1e72223e , 0xf701c9be , 0x172d7d7f ] )
+  , Mont::unsafe_make( [ 0xd9d84a8c , 0xae326719 , 0x7764311e , 0x2f4867b6 , 0x1cbae802 , 0x1b4d1c5c , 0xb324b5f6 , 0x23fb931b ] )
+  , Mont::unsafe_make( [ 0x135151b9 , 0x0cc8bf76 , 0x40d78de4 , 0x2eddeb76 , 0x91d61490 , 0xdff2552b , 0x1dcf11aa , 0x027c89a5 ] )
+  , Mont::unsafe_make( [ 0x53b84e5f , 0x6e23f97a , 0x34df1d90 , 0xf3f74391 , 0x3c0a5e6a , 0xd943726d , 0xcc3290d4 , 0x18c33ce6 ] )
+  , Mont::unsafe_make( [ 0x6eac4250 , 0xe5570e35 , 0x5dee4cae , 0x3cc45f7a , 0x9e896b75 , 0xf546ec97 , 0xa1bd13cf , 0x107f5e13 ] )
+  , Mont::unsafe_make( [ 0xc49ad208 , 0xf2b933c5 , 0x73d7a975 , 0x0e671e20 , 0x9f1f2257 , 0x609c05f1 , 0x74428d1c , 0x2602edc3 ] )
+  , Mont::unsafe_make( [ 0xc0eeee5a , 0x060bbc9e , 0xc5c0037e , 0x1410647a , 0x2e4dd87c , 0x465998fd , 0x2aa5079c , 0x05e04cfd ] )
+  , Mont::unsafe_make( [ 0x7877bed6 , 0x6852c167 , 0x0554ced7 , 0x82c76bde , 0xe57a490d , 0x21fa522a , 0x4240e8f4 , 0x30586cb3 ] )
+  , Mont::unsafe_make( [ 0xfe696511 , 0x80bb6677 , 0xde7b66a3 , 0xf377bd90 , 0x300d781f , 0x3326de21 , 0xe1c88e8d , 0x115dd266 ] )
+  , Mont::unsafe_make( [ 0xfb979260 , 0x3cd0f78c , 0x7a4dae79 , 0xe9bede65 , 0x0b17c7e4 , 0x9e34f692 , 0x95349228 , 0x0b96b956 ] )
+  , Mont::unsafe_make( [ 0x1dc1476c , 0x52671c01 , 0xb4700cc8 , 0xd0ecb4c9 , 0xa787be86 , 0x558a0eea , 0x453f0a0a , 0x290ea8d1 ] )
+  , Mont::unsafe_make( [ 0xebb176cd , 0x199069d7 , 0x0fb7d4bc , 0x1ad988b2 , 0xf40d7346 , 0x1ad6203b , 0x7e2c490c , 0x288b3b88 ] )
+  , Mont::unsafe_make( [ 0xb568ee5f , 0x07027f9a , 0x093bcabf , 0xd6f4266f , 0xf7baa270 , 0xeac2204c , 0xa42f7617 , 0x265722ce ] )
+  , Mont::unsafe_make( [ 0xde848ed7 , 0x73b6f560 , 0x1e8cbecc , 0x1f1b454e , 0xf07101b4 , 0xb034f7f3 , 0xc904df57 , 0x2e88bf40 ] )
+  , Mont::unsafe_make( [ 0x1bddea5e , 0x9239adf8 , 0x328b3f4c , 0x1d0b2382 , 0xd945078a , 0x3875aa56 , 0xe80c58a1 , 0x29d3f829 ] )
+  , Mont::unsafe_make( [ 0xcd903a7e , 0x8e152ac2 , 0x313a2630 , 0x26a868f4 , 0x2cbfa3f6 , 0xa4292159 , 0xa2113ce4 , 0x02a4b94d ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x9e13c1f8 , 0xff98a0ef , 0x18f84fc2 , 0xd06569af , 0x36d2bc80 , 0x31345741 , 0x865890cd , 0x112111b0 ] )
+  , Mont::unsafe_make( [ 0x56335001 , 0xd21c46a4 , 0x7d10ef1c , 0xa8b6fb96 , 0x226cc4e6 , 0x84192253 , 0x8dfdaf08 , 0x0135ae53 ] )
+  , Mont::unsafe_make( [ 0x3dcefa6e , 0xfcd73544 , 0xbd5ceeb8 , 0x956544cc , 0x9d7401c7 , 0xf5247032 , 0x83763510 , 0x22cbc301 ] )
+  , Mont::unsafe_make( [ 0xa388755a , 0xd026c2b5 , 0xca9e46b1 , 0xd27e11a2 , 0xb700790d , 0x08d88cd1 , 0x096ad74e , 0x07fe339a ] )
+  , Mont::unsafe_make( [ 0x64140e87 , 0xe115f588 , 0x371938e2 , 0xa57da8ea , 0x0621629f , 0xceaa76c4 , 0x5b3e73f8 , 0x2cd90adb ] )
+  , Mont::unsafe_make( [ 0xb26dacda , 0x359b4ec4 , 0x25f9a47a , 0xab5d62a9 , 0x3796e51c , 0xcf7989a0 , 0x06958a7a , 0x186482ed ] )
+  , Mont::unsafe_make( [ 0x78b7dec8 , 0x8ea61645 , 0x03572e67 , 0x8a6f06de , 0x9e4950a7 , 0x64b31269 , 0x2107996d , 0x2759e8ea ] )
+  , Mont::unsafe_make( [ 0xa4d211ad , 0xb44af1db , 0xf36f7010 , 0x6f4e1767 , 0xb05173bf , 0x31f78c85 , 0x8b7629a8 , 0x26369f06 ] )
+  , Mont::unsafe_make( [ 0x6e73f203 , 0xd911b6c7 , 0xf1b04289 , 0x41bbd0c7 , 0xa97a3aa8 , 0x5debc991 , 0x9d81d53e , 0x16f06ccc ] )
+  , Mont::unsafe_make( [ 0x4f8f1a3f , 0xbdad19e0 , 0xe200bc8e , 0xbca61909 , 0xbda49801 , 0x82146bd3 , 0x7e59fdea , 0x264efcb6 ] )
+  , Mont::unsafe_make( [ 0x676f032e , 0xaf346881 , 0x84a4a6ec , 0x8ba093e0 , 0x5b97b144 , 0x00a653c3 , 0x91af7dbc , 0x06031318 ] )
+  , Mont::unsafe_make( [ 0x196c7195 , 0xe9a9df47 , 0xf16eef53 , 0xdfcaf12a , 0x0b427e98 , 0x23a1f224 , 0x843b3595 , 0x0eb1d73a ] )
+  , Mont::unsafe_make( [ 0x60bb5d48 , 0xc0a6f169 , 0x5e597074 , 0x326e57d7 , 0x160abbf5 , 0xcadbc987 , 0xbc7a5da3 , 0x17f4fc80 ] )
+  , Mont::unsafe_make( [ 0x02aab232 , 0x1df2a618 , 0xc90ab47d , 0x54590f92 , 0x82d92dc8 , 0x16185195 , 0x7869d5ae , 0x0ee08d2f ] )
+  , Mont::unsafe_make( [ 0x52ff6ebc , 0x2d36a499 , 0xfd296b13 , 0xc37a5d6f , 0x3821056d , 0x0dba6884 , 0x319cee30 , 0x0a51a59d ] )
+  , Mont::unsafe_make( [ 0x945cd418 , 0x1b1a7934 , 0xeb1f13f5 , 0x7c5700a1 , 0x27be27e4 , 0x2d519248 , 0x90007b15 , 0x01cbfcc1 ] )
+  , Mont::unsafe_make( [ 0xc1bc7f4c , 0x65915831 , 0x3ddf4962 , 0xbc8a5657 , 0x4c6bc307 , 0x3b033935 , 0x4753d11c , 0x0b3db650 ] )
+  , Mont::unsafe_make( [ 0xb9d37275 , 0x13cc3fc0 , 0xde3215d4 , 0xeef2882d , 0x56bb1250 , 0x75d258e3 , 0xe8f53677 , 0x09aa34b6 ] )
+  , Mont::unsafe_make( [ 0x363a88c9 , 0x063aa19b , 0x2f0008ae , 0xa27661e4 , 0xde20cf87 , 0x7f5905db , 0x093325d5 , 0x210ac6f2 ] )
+  , Mont::unsafe_make( [ 0x7bfeb5a7 , 0x61901bde , 0xf9ffe4b5 , 0xcc4f9ab4 , 0x81a562e1 , 0xdcae69e4 , 0xc65c5ec4 , 0x2c284d28 ] )
+  , Mont::unsafe_make( [ 0xf5db86de , 0xb136adc4 , 0xf5c7e3f8 , 0x0c9fbeef , 0x696e8237 , 0x67cb9516 , 0x2d2cbe0a , 0x1b8a5494 ] )
+  , Mont::unsafe_make( [ 0x24a70940 , 0x8e9e969b , 0x25712de1 , 0xfc57882b , 0x66d4f56c , 0xcd27092f , 0x0dd27e28 , 0x08e9caea ] )
+  , Mont::unsafe_make( [ 0xc82ef498 , 0xe4e98923 , 0xab3fcf3b , 0xf41aef21 , 0x26c46314 , 0xf49c191e , 0xd7690165 , 0x2916441b ] )
+  , Mont::unsafe_make( [ 0x7607afa4 , 0x138b4b95 , 0xd79a40af , 0x8522c2be , 0x4a4d6733 , 0x65a9a947 , 0x4a9a6219 , 0x071bdd47 ] )
+  , Mont::unsafe_make( [ 0xa183f479 , 0x07d7013e , 0xc9007042 , 0x736e244a , 0xf64b202a , 0x69b85d5d , 0x64db3d4e , 0x1ba1514b ] )
+  , Mont::unsafe_make( [ 0x620b1344 , 0x568849f4 , 0x2702fc19 , 0xd124f0c1 , 0xd531c0a0 , 0x6e8a8bc4 , 0xea1613df , 0x0f4460a9 ] )
+  , Mont::unsafe_make( [ 0xbee1d6ec , 0x328bc783 , 0x16f16324 , 0x2716aac6 , 0x0929b9ca , 0x84b94d78 , 0x3e4dd023 , 0x2a32802c ] )
+  , Mont::unsafe_make( [ 0x0bc27591 , 0xc63acc48 , 0xbf037d1f , 0x41681a2b , 0x4ab7ca85 , 0x3d8f234d , 0xeedb2d52 , 0x1e20bbf0 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xa8a18c9b , 0xf411e454 , 0x98211db0 , 0xb54088a9 , 0x3377c250 , 0xd40f76b6 , 0x1658ad8f , 0x012289ff ] )
+  , Mont::unsafe_make( [ 0x9239c10d , 0x54aea876 , 0x7542cdf8 , 0xd119e218 , 0x6ec1ef0a , 0x11c2cf46 , 0x94e48bce , 0x1500ad35 ] )
+  , Mont::unsafe_make( [ 0xeb6f6b49 , 0x0230127c , 0x2fef3b37 , 0x9667b41a , 0x89cb40cb , 0x939136c9 , 0x373fe78a , 0x0a7a2c13 ] )
+  , Mont::unsafe_make( [ 0xe83ccbb2 , 0x0f08a8cb , 0x3d451a6f , 0x7d86e75f , 0x0e55af30 , 0x1ff7b62a , 0x592cd3e7 , 0x1d03af44 ] )
+  , Mont::unsafe_make( [ 0xaaa76778 , 0xa3028b39 , 0xdbf0815d , 0x0d830760 , 0xa57d524f , 0x0d719761 , 0xb25eb5fa , 0x177ee9b1 ] )
+  , Mont::unsafe_make( [ 0xbd160f15 , 0xb8c05302 , 0xdbfe8c6e , 0x23c29fdb , 0x87b29a4a , 0xd6b36238 , 0x7c65c21c , 0x03f31d6f ] )
+  , Mont::unsafe_make( [ 0x4784defd , 0xfb29be48 , 0x647a1ae7 , 0x45af6fcb , 0x9bbc79cb , 0x60e5ae9e , 0x35bf359f , 0x24cd91fd ] )
+  , Mont::unsafe_make( [ 0x3bc94296 , 0x99edae72 , 0x5d71a67b , 0xca9e38b9 , 0xc923e3af , 0x21955939 , 0xa680de2d , 0x2f84a6c4 ] )
+  , Mont::unsafe_make( [ 0xe6a1137d , 0x7bf6c20a , 0x79bddecb , 0x89edff4a , 0x73ee5e68 , 0xd9a72b05 , 0x694c50d7 , 0x04893489 ] )
+  , Mont::unsafe_make( [ 0x799a6e1d , 0xfda932f0 , 0x109f0598 , 0x730ef5d0 , 0xfdb365b3 , 0xe494a971 , 0x48ce49f4 , 0x0703d027 ] )
+  , Mont::unsafe_make( [ 0x4c97ecad , 0xfdb7224c , 0xe719a476 , 0x23fcdddd , 0x7e62b57c , 0x19d8642c , 0xd80e0e3a , 0x302cc43b ] )
+  , Mont::unsafe_make( [ 0x1b868ab5 , 0x8eb1cba7 , 0x79944103 , 0xfdff4e10 , 0x9c9ec370 , 0x813924f6 , 0xa9000a4f , 0x0f7646d1 ] )
+  , Mont::unsafe_make( [ 0xb017b66a , 0x9aadd995 , 0xb42e0d98 , 0x262d6323 , 0x077db8b0 , 0xb183055c , 0x25c50075 , 0x073d83a3 ] )
+  , Mont::unsafe_make( [ 0x7877d0d5 , 0xa9a511ca , 0xfd5fb23a , 0x78b7c3c7 , 0xff01cff6 , 0x7e0420f1 , 0xf2cbe488 , 0x2039488a ] )
+  , Mont::unsafe_make( [ 0xd3c94f54 , 0x51e5dacd , 0xfdb11f78 , 0x8bd4b0f3 , 0x2d9afa09 , 0x2b89eac6 , 0xc99762b1 , 0x1048c010 ] )
+  , Mont::unsafe_make( [ 0xbd9685db , 0x771b3dcc , 0x9377c691 , 0x0171e228 , 0xbd7431a2 , 0x2625dfb3 , 0x34412022 , 0x2f9552f5 ] )
+  , Mont::unsafe_make( [ 0x7bbf95b5 , 0x77b6e173 , 0x54ad4f95 , 0xf6426bd1 , 0x641991af , 0x8b8506d0 , 0x7c8fc148 , 0x068468d6 ] )
+  , Mont::unsafe_make( [ 0x3e47c244 , 0x74ce9467 , 0x15f1cf85 , 0x4e547d33 , 0xc6b4a525 , 0x061f1ec1 , 0x0a10e1db , 0x1e84c1f6 ] )
+  , Mont::unsafe_make( [ 0xeee46f38 , 0xa5c722d7 , 0x18abffcb , 0x80bc9bec , 0xeb0c659a , 0xd4390c04 , 0x227915bd , 0x29b83ef3 ] )
+  , Mont::unsafe_make( [ 0x82ea80ca , 0x5c9791fa , 0x94309088 , 0xff2dc181 , 0xf3eb6717 , 0xb04e998b , 0x5f3f3628 , 0x08f8f0fd ] )
+  , Mont::unsafe_make( [ 0xf8fd8f81 , 0x433b4d9d , 0xf079a4b8 , 0xd9e9c836 , 0x86a0f2c2 , 0x4f1500bd , 0x2f67cb2c , 0x0c209f3e ] )
+  , Mont::unsafe_make( [ 0xf0512244 , 0x30e51bb7 , 0x532f3120 , 0xca14c9c4 , 0x4491a9f9 , 0x03fbc9da , 0x3a719e95 , 0x16d7c931 ] )
+  , Mont::unsafe_make( [ 0x3d30b85a , 0xef1a4f2c , 0x583c9c29 , 0x74eb647a , 0x2c561683 , 0xf6378cd2 , 0x477529a7 , 0x04a1f113 ] )
+  , Mont::unsafe_make( [ 0xb415e384 , 0x366ad558 , 0x0a43fd35 , 0x9b4c39c0 , 0xa8423fbe , 0x888c3a30 , 0xd9009413 , 0x0eb0ccd9 ] )
+  , Mont::unsafe_make( [ 0x1df6659d , 0xd806279b , 0x953ea50d , 0xad1f7dd7 , 0xc02cb0e1 , 0x2be7b514 , 0x4fa9a2b8 , 0x1485309d ] )
+  , Mont::unsafe_make( [ 0x5bcd049b , 0x86b8668d , 0xec0d09ae , 0x1f55917f , 0x91d6c5fc , 0xe122f05b , 0x2ee3ec3c , 0x1a60ce05 ] )
+  , Mont::unsafe_make( [ 0x1beaaba6 , 0x4121d0fb , 0x9f252469 , 0x414a1d05 , 0xa6b008f0 , 0x873d2745 , 0xf4a2139c , 0x002aca35 ] )
+  , Mont::unsafe_make( [ 0xb326638a , 0x6011a874 , 0xa08835b0 , 0x2da717c4 , 0x8924aa98 , 0x3f774dc7 , 0x10bf2165 , 0x1d6248ed ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xbe4b80da , 0x0bdab31e , 0xfd1a37d7 , 0xa0dd1755 , 0x9b319d54 , 0xf34f0ee6 , 0x3053fb3a , 0x10cebbba ] )
+  , Mont::unsafe_make( [ 0x6e335db0 , 0x348dd293 , 0xf1d04a70 , 0x38a0e158 , 0x01b2e6b4 , 0x8225fe27 , 0x95b5003e , 0x2f8441f6 ] )
+  , Mont::unsafe_make( [ 0x5018b87e , 0xb964ca73 , 0x9229c10e , 0xf5172c2f , 0x263ad870 , 0xd9c70365 , 0x47da57c2 , 0x24b3ff81 ] )
+  , Mont::unsafe_make( [ 0xc90715b4 , 0xd897e355 , 0x7a07ed80 , 0xd2b3ac22 , 0xaa4a795c , 0x05dbd260 , 0x3aebaaa3 , 0x1e589ba0 ] )
+  , Mont::unsafe_make( [ 0x48b96414 , 0x66b05098 , 0x2d8f1b01 , 0xb85e6837 , 0x37db39fc , 0x4832a760 , 0x6878da70 , 0x061aa7a6 ] )
+  , Mont::unsafe_make( [ 0x72da9b26 , 0xfddc0883 , 0x96ffa1f5 , 0xef8ee3de , 0x26033527 , 0x12e70ca3 , 0xf6b9b7ca , 0x0baf8b1c ] )
+  , Mont::unsafe_make( [ 0x3e133949 , 0x7f95285a , 0x9aaba920 , 0x1eb25a64 , 0xfd7c6587 , 0xbf140743 , 0xab7e9d4e , 0x12d41128 ] )
+  , Mont::unsafe_make( [ 0xafdd6b98 , 0x8ff277e0 , 0x6b7292ef , 0x721849dc , 0xcf6f8f9d , 0x5375aadb , 0xa103a28d , 0x273b1dd5 ] )
+  , Mont::unsafe_make( [ 0x4ab6bb57 , 0xa3bbc320 , 0xe532f365 , 0x325245c1 , 0x455c42a8 , 0x8d8a64d5 , 0xfdf15699 , 0x09979040 ] )
+  , Mont::unsafe_make( [ 0xe5ae46ea , 0x6e0e8fb0 , 0x1e8080a1 , 0x6d4599b5 , 0x5f49ed59 , 0xbf49e3f4 , 0x8473ad0e , 0x05a57722 ] )
+  , Mont::unsafe_make( [ 0x387ccb11 , 0x59b67c33 , 0x68992ef7 , 0xbcd66c7b , 0xbb4f5aa1 , 0x2783bacd , 0xad565a76 , 0x278b7a7e ] )
+  , Mont::unsafe_make( [ 0x3ce9815e , 0xd7b20e5a , 0x2de09513 , 0x536176da , 0xe6a6aa87 , 0x65d5fa2f , 0x556bf71b , 0x17efea52 ] )
+  , Mont::unsafe_make( [ 0xe1155b97 , 0x8a4f1896 , 0xdda94c54 , 0x90c2ebaf , 0x5d98e103 , 0x1bfe6f77 , 0x21210702 , 0x06556cb6 ] )
+  , Mont::unsafe_make( [ 0x07569cd3 , 0x98004e2a , 0xb3a8d393 , 0xd60af9bc , 0x4cd4ea97 , 0x335ccf39 , 0xd47e5bfa , 0x1d26b7d4 ] )
+  , Mont::unsafe_make( [ 0xb11b6fe0 , 0xa7bf5335 , 0xd16e719c , 0x64b93095 , 0x844c936d , 0x437769dc , 0xef1b527e , 0x16e1fad8 ] )
+  , Mont::unsafe_make( [ 0x83a59e97 , 0x1945e325 , 0x66933850 , 0x85f14e76 , 0x525adf93 , 0x2a4fc4a1 , 0xfdb01c52 , 0x273b26cb ] )
+  , Mont::unsafe_make( [ 0x5cd3e7c4 , 0xa444d511 , 0x5ca6f933 , 0xa53bb8da , 0xa0c29ec2 , 0xf895ae8f , 0x5ab1722b , 0x19f4e05d ] )
+  , Mont::unsafe_make( [ 0xe065bf79 , 0x6ce36434 , 0x73bb0a1b , 0x1dfa5eef , 0xd64b729f , 0xf77582d7 , 0x58ecbfb5 , 0x28523dc4 ] )
+  , Mont::unsafe_make( [ 0x9fe8f878 , 0xeb1cc727 , 0xf771365d , 0x45fbc780 , 0xab00347f , 0x4281e039 , 0x83ca7989 , 0x28f0b86d ] )
+  , Mont::unsafe_make( [ 0x835d2971 , 0x2c39a8e1 , 0x5dab544a , 0x91a6bc36 , 0xd082be92 , 0x8916627f , 0x98c30723 , 0x16dba292 ] )
+  , Mont::unsafe_make( [ 0x838e63a9 , 0x0284c373 , 0x676dc7cb , 0x1b3b8ab4 , 0x7c08d6b8 , 0xd72029ab , 0x4a74350e , 0x05988058 ] )
+  , Mont::unsafe_make( [ 0xe3ed5f74 , 0x6cc89dbb , 0xa9623bfb , 0x986e7dc4 , 0xea19dfee , 0x88469628 , 0xa0b81969 , 0x0d2f856c ] )
+  , Mont::unsafe_make( [ 0xce341623 , 0xc7bf45f7 , 0x59297c18 , 0xb3fb9122 , 0xccf99507 , 0xfe861cce , 0xa1a01e2e , 0x1f4a46f6 ] )
+  , Mont::unsafe_make( [ 0xdd724d63 , 0x47cda0b1 , 0xbf9852be , 0xd40d949b , 0xdb80b810 , 0x83fdb1be , 0xa45b52d5 , 0x2d417912 ] )
+  , Mont::unsafe_make( [ 0x67e4e4db , 0x84fdd95a , 0xacd9b35f , 0x53be58c2 , 0x46e5d92f , 0x99fb8557 , 0x148ba053 , 0x15aca3ca ] )
+  , Mont::unsafe_make( [ 0xa01e17bf , 0x47b4e3b3 , 0x44844887 , 0x55c56e0c , 0xa4daf606 , 0xeb6fb162 , 0x36e0d3b7 , 0x14e8eb4c ] )
+  , Mont::unsafe_make( [ 0x6646a405 , 0x80f15bfe , 0x9b3b3ddf , 0xc20a9d25 , 0xe728ffd5 , 0xccde732b , 0x86592422 , 0x29663322 ] )
+  , Mont::unsafe_make( [ 0x78db5a91 , 0x59884418 , 0x00664b58 , 0x291d32c9 , 0xf6934362 , 0xbb708093 , 0xe1a6796a , 0x11446d47 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x3d5b82f4 , 0x97c9b2c9 , 0x33c9dddd , 0x2e5c1554 , 0xcba1a870 , 0x8d8c2aec , 0xef488c22 , 0x044b9787 ] )
+  , Mont::unsafe_make( [ 0x30f59ff9 , 0xf97c2ffa , 0x4facba10 , 0xf025279b , 0x61dd3253 , 0x23ba7054 , 0x7e1250d5 , 0x15ec5df5 ] )
+  , Mont::unsafe_make( [ 0x710b457c , 0xb7c52a6e , 0xe169fbee , 0x4206d6f2 , 0x4e238d6a , 0x0dd07cfc , 0x3ea2c2b4 , 0x0a6df307 ] )
+  , Mont::unsafe_make( [ 0x7d1479fa , 0x4ca1854a , 0xa6cf739f , 0xe12d493b , 0x8b64ba51 , 0x67fd166e , 0x4a9c5969 , 0x0b8e8acf ] )
+  , Mont::unsafe_make( [ 0xeb134cb3 , 0x27be8b50 , 0x7780abb9 , 0x1d54fdd0 , 0xd5b72795 , 0x1a8bfbfb , 0x520d6e83 , 0x153c3777 ] )
+  , Mont::unsafe_make( [ 0x179fdf66 , 0x3043e7f2 , 0x573b8d5a , 0xbcda9be8 , 0x3e8307a4 , 0xedb13125 , 0xe31744c3 , 0x0f153748 ] )
+  , Mont::unsafe_make( [ 0xa98121f8 , 0xe13291c7 , 0xcb25108d , 0xc95ff918 , 0x5ed94878 , 0x11e97b46 , 0xb526dfa2 , 0x0da32af3 ] )
+  , Mont::unsafe_make( [ 0x7c8bedef , 0x019e7663 , 0xaa508d89 , 0x253dc17c , 0xd73c71ce , 0xb4b7f983 , 0x3e795b39 , 0x2f46f700 ] )
+  , Mont::unsafe_make( [ 0xb120029d , 0x9e0fd428 , 0x0c133fed , 0x0dd3343b , 0x8973c164 , 0xe93f9e28 , 0xade54f10 , 0x15b55ecf ] )
+  , Mont::unsafe_make( [ 0x2574dede , 0x118e4661 , 0x71ed0849 , 0x5b490cf0 , 0x60664099 , 0xe0faf3ea , 0x8d2a7506 , 0x16f49ced ] )
+  , Mont::unsafe_make( [ 0x40354b39 , 0xbfc893c7 , 0x4bfecf4b , 0xb819485a , 0x717bf178 , 0xdee09061 , 0x3210ee42 , 0x26744a5c ] )
+  , Mont::unsafe_make( [ 0xef030ea1 , 0x4d6a0bdc , 0xce64c3f3 , 0xc0828435 , 0x0acc5968 , 0xf360c169 , 0xb3cc8027 , 0x170a2594 ] )
+  , Mont::unsafe_make( [ 0xed22f31c , 0x5257fd73 , 0x13f8db0e , 0x074f6eb1 , 0x2f5badd1 , 0xba37e57f , 0x1df194d9 , 0x0677ba48 ] )
+  , Mont::unsafe_make( [ 0xe58adf8f , 0x3bec5202 , 0x2b75aa2f , 0xf480a521 , 0x43502212 , 0x0b844fe8 , 0x35fd1efe , 0x2b45581c ] )
+  , Mont::unsafe_make( [ 0x54acc3fa , 0x8e152ee4 , 0xbc61337f , 0xa78f7520 , 0x6825cc04 , 0x8a0af9f1 , 0xd068e99e , 0x17bf8b3a ] )
+  , Mont::unsafe_make( [ 0x5189d6ce , 0xb03aabd0 , 0xda6eb7f5 , 0xe97abb1b , 0x4c21fa0c , 0x15530574 , 0x5bb683ca , 0x1436feda ] )
+  , Mont::unsafe_make( [ 0xa953ef83 , 0xa6da0a61 , 0xa101bdc3 , 0xe782badd , 0x42fdae61 , 0xe11c1319 , 0x083d8936 , 0x2c7d23cf ] )
+  , Mont::unsafe_make( [ 0x8bf1a7c1 , 0xe7f57d00 , 0x991f799c , 0xe580d74b , 0x2f191d09 , 0xca6980ff , 0xcef615bd , 0x046c70b3 ] )
+  , Mont::unsafe_make( [ 0x1d33fbb8 , 0xc87396c7 , 0x8344ee42 , 0x1883fe0d , 0x9e7e7d86 , 0x354378d4 , 0x504b4059 , 0x0a79d20a ] )
+  , Mont::unsafe_make( [ 0xeba27c4e , 0x0b1fd350 , 0xbaf49fb4 , 0x9b40d800 , 0x8b58e6d4 , 0xbe80298b , 0x9e980029 , 0x180c6cf0 ] )
+  , Mont::unsafe_make( [ 0x93b39b2f , 0x60c35bb0 , 0xef5e13ad , 0xd3af554d , 0xf18f5d13 , 0x72b8780d , 0x37ec19b8 , 0x07607026 ] )
+  , Mont::unsafe_make( [ 0x56fe02a2 , 0x2650f73d , 0x63cb10fd , 0xd5dbdfdc , 0xcd3d653f , 0x238501b2 , 0x2cdbe9af , 0x0ed1c37b ] )
+  , Mont::unsafe_make( [ 0xb8c28d9e , 0xc5f0db1d , 0x8ebae4e7 , 0x0065b4a6 , 0xb568afef , 0x8bd30ac0 , 0xaf78acc1 , 0x1d434322 ] )
+  , Mont::unsafe_make( [ 0x842923da , 0x541968df , 0xefca7efe , 0xd4b8dac5 , 0x5013760e , 0x5869c7f3 , 0x5ebf48ca , 0x15c92180 ] )
+  , Mont::unsafe_make( [ 0x87d63c00 , 0xa18bb81a , 0x2be28d6d , 0x761b0b18 , 0x49caeded , 0x3d643228 , 0xecf5abc0 , 0x2e11a111 ] )
+  , Mont::unsafe_make( [ 0xd006ae2b , 0xa2d25872 , 0x1e51b066 , 0x58382035 , 0x18d046b9 , 0x2ed561a5 , 0xaede8a27 , 0x29975c00 ] )
+  , Mont::unsafe_make( [ 0xb67fbc67 , 0x36b626e2 , 0xf61c541d , 0x02739db4 , 0xb9ba6faa , 0x6d88b8bb , 0xfd8f232d , 0x2a86a98b ] )
+  , Mont::unsafe_make( [ 0x064b99fa , 0x208726d7 , 0x74a5efa0 , 0xf0e7368e , 0xa29cda89 , 0xdff2df07 , 0xc0678ab1 , 0x06b76298 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x23a0e1ae , 0x4a78477b , 0x59be2ccd , 0x00bd997e , 0xdfb30481 , 0x96c255d8 , 0x3909d644 , 0x20fe0f45 ] )
+  , Mont::unsafe_make( [ 0xe5fa2e33 , 0xf5e7da7a , 0xc3eaf14b , 0xbce29024 , 0xc5be68a6 , 0x64cf08a1 , 0x6541e2cf , 0x16ccad37 ] )
+  , Mont::unsafe_make( [ 0x99dde3b6 , 0xf4c45c9c , 0x9b98b113 , 0xaab796bc , 0xf7ac461d , 0x4c3fa88e , 0x26a3ac17 , 0x23ac08a4 ] )
+  , Mont::unsafe_make( [ 0xb052dae2 , 0x3e5ad318 , 0x768b8fb6 , 0x0912b719 , 0x9943cde1 , 0x44e3353b , 0x9e8942ff , 0x0b8e9ce9 ] )
+  , Mont::unsafe_make( [ 0x6914d31e , 0xeea2705b , 0x91bdcc5a , 0x5d1a8473 , 0x3a604abf , 0x0d6dbb93 , 0xf9787454 , 0x2d89f10b ] )
+  , Mont::unsafe_make( [ 0xb73832aa , 0x712b8f7e , 0x907e6213 , 0x11b5b9b8 , 0xd7b5ffb9 , 0xc68564b0 , 0x7b322344 , 0x0420110a ] )
+  , Mont::unsafe_make( [ 0xc7ad9940 , 0x1c033d4a , 0x66a606d9 , 0x0135a2b9 , 0x17f287b8 , 0xb821cbba , 0x8913525f , 0x00522690 ] )
+  , Mont::unsafe_make( [ 0xaea9ea20 , 0xffb951e3 , 0x7fa0dbb2 , 0x1f0a0729 , 0xe41a796e , 0x8f1f1fce , 0xa3ebbd0d , 0x24f8f140 ] )
+  , Mont::unsafe_make( [ 0x9405882a , 0x2ca972a9 , 0xc74ef7b1 , 0xd9231496 , 0x35ddb7a5 , 0x5470bfca , 0xd775f14c , 0x1985bf29 ] )
+  , Mont::unsafe_make( [ 0xbeb28c9a , 0x3ae5b9ff , 0x02d71439 , 0x6ecb9ebb , 0xa842af2f , 0x9fa245bc , 0xffd08134 , 0x1be8cdc4 ] )
+  , Mont::unsafe_make( [ 0x85eb77f8 , 0xb75eebc6 , 0x64a16d59 , 0x67bf28b0 , 0x0696d0a7 , 0x457868bc , 0x3e094326 , 0x0b9c3185 ] )
+  , Mont::unsafe_make( [ 0x2131d89f , 0xbe322b6c , 0x02d74014 , 0x2f405fb1 , 0xfa41384e , 0x1664225a , 0xcf61b79c , 0x088b7d0f ] )
+  , Mont::unsafe_make( [ 0xb732ee4d , 0x7dcea13a , 0x42f5b70b , 0xf3e89dba , 0xcecf24e0 , 0x6a168c1c , 0xd6e4adf5 , 0x06e4c169 ] )
+  , Mont::unsafe_make( [ 0x6fd7359f , 0xf6c37102 , 0x83836536 , 0x5fce3465 , 0xf981f67f , 0x84a2756d , 0x0e4f3534 , 0x13bf7cc6 ] )
+  , Mont::unsafe_make( [ 0xdb1ade24 , 0xdad27193 , 0xa926236f , 0x0f53a721 , 0x3e41cb49 , 0x1ecee9ef , 0xa3f1b074 , 0x004b610a ] )
+  , Mont::unsafe_make( [ 0xcdd9841c , 0xdfe69c81 , 0x3f241119 , 0xc9968ace , 0x1989a63a , 0xa33f256b , 0xe5cfd588 , 0x13317741 ] )
+  , Mont::unsafe_make( [ 0x3be26cfd , 0x9fd97357 , 0x385e1a99 , 0x81885670 , 0x7ecc032f , 0x6dbf5736 , 0xd67e5843 , 0x159cb7cf ] )
+  , Mont::unsafe_make( [ 0xb0fc7a8a , 0x70534817 , 0x149dd5f8 , 0x532fe96c , 0x528e8b28 , 0xd2d6eb75 , 0x0e14bfdc , 0x1f0792c2 ] )
+  , Mont::unsafe_make( [ 0xc347adda , 0x579a449f , 0x03c489f4 , 0x56fa96c0 , 0x6aee24e4 , 0x93de1768 , 0x67654c04 , 0x09019ad7 ] )
+  , Mont::unsafe_make( [ 0x46f2d8c9 , 0xd0fd4a4d , 0x96e4b1ba , 0xb060a9a8 , 0xc6b81971 , 0x2cbf4476 , 0x1fe8d020 , 0x01096ab3 ] )
+  , Mont::unsafe_make( [ 0x5a57bddc , 0x0edfd77d , 0x7b4672d9 , 0x1e1fa507 , 0x1a7b1258 , 0xf1f2e1ec , 0x65e2b84f , 0x2c67ba51 ] )
+  , Mont::unsafe_make( [ 0xdf1ac7f8 , 0xc0c62749 , 0x2e71ad43 , 0x54277ec0 , 0xc0785121 , 0xc1b8a8d0 , 0x893783b6 , 0x1bf62120 ] )
+  , Mont::unsafe_make( [ 0xcdb10c5b , 0x4b01267b , 0x62b76873 , 0x25ad0896 , 0x0ddb39cf , 0x0622b60d , 0xc7af3193 , 0x04260836 ] )
+  , Mont::unsafe_make( [ 0xc7ae94aa , 0x0691fbf8 , 0xf0bdfdda , 0x8dbd96b9 , 0x132e5e0a , 0x8848ac16 , 0x742f6b7b , 0x1a62f05e ] )
+  , Mont::unsafe_make( [ 0x886ed6ba , 0x0de8c84b , 0xf6fea83e , 0x97859c39 , 0xc59f3bba , 0x33c1e656 , 0x216e9937 , 0x2aa9980d ] )
+  , Mont::unsafe_make( [ 0xc39f7258 , 0xcb73d5c1 , 0x8d6da7f6 , 0x9837923d , 0x927ef43b , 0x0fae3ca7 , 0xc247b04b , 0x2f8d0d3b ] )
+  , Mont::unsafe_make( [ 0xda3ad57e , 0xd47e9f89 , 0x3067e6e6 , 0x56fad4e8 , 0xd7394179 , 0xe552ca4e , 0x24bc754b , 0x026a199c ] )
+  , Mont::unsafe_make( [ 0x600d7e5f , 0x75c9c509 , 0x3126c43f , 0x8650b09e , 0x95022d8e , 0x0860e040 , 0x3c25de4a , 0x1fb95a26 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xb2bd1b59 , 0x08852a87 , 0x734021e7 , 0xd5dbfae7 , 0x4d97d13e , 0x7954ee37 , 0xdba1191f , 0x2c5d905c ] )
+  , Mont::unsafe_make( [ 0xe559af77 , 0x4a2b851f , 0xb6bf9c81 , 0x59a5c27a , 0x192d4e96 , 0x2dfc9659 , 0x796a3d4e , 0x12c8fdf0 ] )
+  , Mont::unsafe_make( [ 0xc959d271 , 0x51c0856e , 0x150951b8 , 0x8ad614fc , 0x3b842e36 , 0xaa1e1659 , 0xf3abbefa , 0x1bf431eb ] )
+  , Mont::unsafe_make( [ 0xd1d67024 , 0x39b6e354 , 0xc15fd806 , 0xa9b49861 , 0xd4ddb417 , 0xd7980c63 , 0x864c6103 , 0x052b108e ] )
+  , Mont::unsafe_make( [ 0x8d0488bd , 0x857046c7 , 0x33351a32 , 0x7dbaec30 , 0xf45fc09b , 0x5d74b10f , 0x889e878f , 0x13701a7d ] )
+  , Mont::unsafe_make( [ 0x1f9231a3 , 0x30635aa4 , 0x7c3c60bf , 0x50846ef3 , 0x4b26836c , 0x221e6a26 , 0x057327e4 , 0x01b17612 ] )
+  , Mont::unsafe_make( [ 0x4a6f5653 , 0xf1d83805 , 0xe7546639 , 0x807a3ce1 , 0x94965847 , 0x90b4ffef , 0x0ae4b548 , 0x2b8d34e0 ] )
+  , Mont::unsafe_make( [ 0x60655f63 , 0x39798afa , 0xbf97e325 , 0xd96be550 , 0xefacd047 , 0xa5a04590 , 0x13e1f761 , 0x026abb4b ] )
+  , Mont::unsafe_make( [ 0xb6f4edb4 , 0xced028f0 , 0x563d2bb4 , 0x1022d0b4 , 0x06071431 , 0xcb80167e , 0xde284e42 , 0x0ab2af40 ] )
+  , Mont::unsafe_make( [ 0xf0500779 , 0x818ed05b , 0x5e78a770 , 0x25bc57fe , 0x49923691 , 0x5d19cb5a , 0x3ca0053e , 0x30480121 ] )
+  , Mont::unsafe_make( [ 0xe357f437 , 0x97912189 , 0xfb316881 , 0x2bfb5303 , 0xec4625cb , 0x82967437 , 0xfa0ce370 , 0x0b12b581 ] )
+  , Mont::unsafe_make( [ 0x9afdaee9 , 0x8142a820 , 0xaecdcf47 , 0x8b2727c8 , 0x0e335a26 , 0xfa0d2394 , 0xc7210495 , 0x18e2a196 ] )
+  , Mont::unsafe_make( [ 0x327c74f3 , 0x59df2cd6 , 0x22824e77 , 0x20109129 , 0xef6af872 , 0xf2445d41 , 0x9ded914a , 0x2a8590da ] )
+  , Mont::unsafe_make( [ 0x02b84177 , 0x51e4f271 , 0x7e299176 , 0x0ca7cfbc , 0x8ca8ea2e , 0x0218b101 , 0xaf264d01 , 0x299548c9 ] )
+  , Mont::unsafe_make( [ 0xc8a75e3d , 0xd2eb6917 , 0x0aa70ec5 , 0xdd8cf360 , 0x4f334bc6 , 0x6312f015 , 0x66752740 , 0x05e86ca9 ] )
+  , Mont::unsafe_make( [ 0x045cc123 , 0xe8e79440 , 0x58592a95 , 0x99971006 , 0xd8cbd422 , 0x8675c378 , 0x31267807 , 0x1417e5c2 ] )
+  , Mont::unsafe_make( [ 0xfc5b95ed , 0x70c15280 , 0x911798f0 , 0x0f309d78 , 0xf0c8bb55 , 0x6a52c7b9 , 0xea831d61 , 0x0550d856 ] )
+  , Mont::unsafe_make( [ 0x38383eb6 , 0x5c8f4a50 , 0x68149c46 , 0x8c7db7c4 , 0x214604ac , 0x4f21f739 , 0x084ffcbe , 0x22c4ecab ] )
+  , Mont::unsafe_make( [ 0x262a45cc , 0x15652275 , 0xbd6a43a0 , 0xf478ca0e , 0x256cb57f , 0x89cb3b68 , 0x61440f9c , 0x285a973f ] )
+  , Mont::unsafe_make( [ 0x7361789d , 0xe635182d , 0xb3432499 , 0xfb1eef40 , 0xf668c21c , 0x0c8d580c , 0xe077e670 , 0x1fdc31c5 ] )
+  , Mont::unsafe_make( [ 0x4d8eb0ca , 0x46505c91 , 0xbb5fc2d9 , 0x0eb7d6e3 , 0xfe89baa8 , 0xa317d1f7 , 0xe941e9d5 , 0x09c7f219 ] )
+  , Mont::unsafe_make( [ 0x90e11684 , 0x29e97437 , 0x407189ea , 0xadc39293 , 0xf022c41d , 0x5e24f6c1 , 0x0d67c42d , 0x1b716db1 ] )
+  , Mont::unsafe_make( [ 0x20e4d8da , 0xf5ab4257 , 0xf1b6836e , 0x1a72903c , 0xe2226452 , 0x5acc0759 , 0x2073bdf8 , 0x22b058ec ] )
+  , Mont::unsafe_make( [ 0xd075d64d , 0x3b1ecbb5 , 0xc217fe36 , 0x533f346e , 0xd8c41691 , 0x0b040923 , 0x2fe73cbe , 0x16284625 ] )
+  , Mont::unsafe_make( [ 0x6f7aa6d1 , 0xb72ca057 , 0x8b3199d3 , 0x92d47693 , 0xf1c58162 , 0x8ccdfd58 , 0x82d791c9 , 0x12a9837a ] )
+  , Mont::unsafe_make( [ 0x1de32652 , 0x6f52858d , 0x74983902 , 0x24d14cbe , 0x3d7d4f05 , 0x74f8291e , 0xd4a4eb99 , 0x0d23df36 ] )
+  , Mont::unsafe_make( [ 0x63fe5869 , 0x1b80c343 , 0x26bd1479 , 0x9feb7cb9 , 0xccd775db , 0xd92dd92a , 0xaa643347 , 0x05f2ed19 ] )
+  , Mont::unsafe_make( [ 0x4e1b772a , 0x03182e03 , 0x68a4e53b , 0xf707bc39 , 0x72fa976f , 0x78c9ee91 , 0x2e62ad66 , 0x12d248dd ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x740cb20e , 0x8b961596 , 0x450a61b4 , 0x5faf2b1f , 0x2b1fa08d , 0xf0cddb4e , 0x290d4f26 , 0x1074f857 ] )
+  , Mont::unsafe_make( [ 0x904edb08 , 0x8779b393 , 0x3f9f452b , 0xfd120680 , 0xc8a0259e , 0x5dff4c3c , 0xbaf78e53 , 0x17e89329 ] )
+  , Mont::unsafe_make( [ 0x4a86be42 , 0xba370d58 , 0x4615d765 , 0xc00f6f29 , 0xe7dfdb7f , 0x977a1970 , 0xcff106d6 , 0x13dc5fab ] )
+  , Mont::unsafe_make( [ 0x4a18daaa , 0x4a5275c1 , 0xa77e5877 , 0xfb2f644c , 0x9999593a , 0x095ad138 , 0x2c50772c , 0x231f9ce3 ] )
+  , Mont::unsafe_make( [ 0xfea725c4 , 0x91dff4ab , 0x0735efb0 , 0x347eca32 , 0x9758df4e , 0xea71d433 , 0x73733d29 , 0x252634e4 ] )
+  , Mont::unsafe_make( [ 0xc7013dc3 , 0xa7c1de91 , 0x15324423 , 0xa987fb54 , 0xf7f280bc , 0x37395de1 , 0x3dc9538a , 0x19cf8888 ] )
+  , Mont::unsafe_make( [ 0xd4df5001 , 0xb7de1e19 , 0x5cad174c , 0x40d424a8 , 0x6d7098bc , 0x8fc6ef19 , 0x3a9a719e , 0x28f07bd6 ] )
+  , Mont::unsafe_make( [ 0x2e3fa831 , 0xb220ab27 , 0x42cad45c , 0x44bb6173 , 0x1ce2ee11 , 0xe63e841d , 0x5666039f , 0x2dbd65b4 ] )
+  , Mont::unsafe_make( [ 0xccc6d7af , 0x59d73848 , 0x94d8bb9a , 0x139a3156 , 0xb3cf012e , 0xa0530079 , 0xd94286e8 , 0x0ffcaa3f ] )
+  , Mont::unsafe_make( [ 0xe04c7bde , 0x8606ae03 , 0x54ccb584 , 0x3726e299 , 0x203e8cb1 , 0xd5524832 , 0xc96b8ce0 , 0x12de0dde ] )
+  , Mont::unsafe_make( [ 0xfe6a1586 , 0x156e3cbc , 0x66c149a6 , 0x11d3487d , 0x2689730c , 0xb8df5314 , 0x47f4d53a , 0x20c6fd25 ] )
+  , Mont::unsafe_make( [ 0x84a117dd , 0x3433f773 , 0xc4357cb1 , 0x71223158 , 0x856b7d9b , 0xd374da1f , 0x897deed0 , 0x1ec0bbbb ] )
+  , Mont::unsafe_make( [ 0xc0edb7da , 0xddff3089 , 0x44fe8dc7 , 0x0f089786 , 0xf282531e , 0x89e53494 , 0xecba9eab , 0x3051d41f ] )
+  , Mont::unsafe_make( [ 0x9a6dc02a , 0x4a07e6e9 , 0x5acab352 , 0xbdd822ad , 0xad7eb093 , 0x539c0298 , 0x820f984d , 0x26168b8f ] )
+  , Mont::unsafe_make( [ 0x62b0920a , 0x7e59815c , 0x49f7af1d , 0xeff59d84 , 0x14768c22 , 0x8e2be75b , 0x6378218e , 0x066b79fb ] )
+  , Mont::unsafe_make( [ 0xda8767f2 , 0x4b2b97e8 , 0x674f6cf7 , 0xe69cba30 , 0x9f976536 , 0x124799d0 , 0xad3e5373 , 0x0b25c71e ] )
+  , Mont::unsafe_make( [ 0x875e98e5 , 0x4d279498 , 0xd2e9604c , 0xccbe9143 , 0x9100543b , 0xcedac855 , 0x526d3ecc , 0x08fd885b ] )
+  , Mont::unsafe_make( [ 0x990d3e20 , 0x1c10412b , 0xf5d5d997 , 0xf27b2658 , 0x4ec9e0c1 , 0x327eb3e0 , 0x48d5d1f8 , 0x140c01da ] )
+  , Mont::unsafe_make( [ 0x61d3b511 , 0xd179f5b4 , 0x80fddc5c , 0x2f2087d0 , 0x275e25e9 , 0x5b131b0d , 0x236371cf , 0x10fc7fea ] )
+  , Mont::unsafe_make( [ 0xad3652e5 , 0x078b8c6c , 0xdb433855 , 0xfa0e44e6 , 0x9e8bc2c7 , 0x1e7577ef , 0xeb46c2e4 , 0x2629bec9 ] )
+  , Mont::unsafe_make( [ 0x26c16dd5 , 0x7504868d , 0xe745b50c , 0xdee6ff1e , 0xa42147a0 , 0x7edee095 , 0xca504484 , 0x0d7f1e4e ] )
+  , Mont::unsafe_make( [ 0x2947ccd8 , 0x5b809962 , 0xff20897b , 0xde9a1c02 , 0x7944575d , 0xe8fb368d , 0x3f7b0b69 , 0x20374530 ] )
+  , Mont::unsafe_make( [ 0x72f987a0 , 0xea7b82e9 , 0x9cfe93f0 , 0x401fcbd6 , 0x209b851a , 0xcdf67977 , 0xa67da03d , 0x01943396 ] )
+  , Mont::unsafe_make( [ 0x209ebaa2 , 0xbc16a178 , 0x583a1f19 , 0xca203e27 , 0x7bf7ba2a , 0xde433ac5 , 0x942db3f9 , 0x1ba07a3b ] )
+  , Mont::unsafe_make( [ 0xf9d8e32e , 0xd74e59aa , 0x3ed5e310 , 0xa6b69637 , 0xbe95e349 , 0x2dddf00e , 0x2b3ddbe0 , 0x03b0bb3f ] )
+  , Mont::unsafe_make( [ 0xe4593836 , 0xa26a1588 , 0xabfc63ac , 0x7ac07a54 , 0x284aa913 , 0x13be2da8 , 0x164137ff , 0x2c626d74 ] )
+  , Mont::unsafe_make( [ 0x020a920a , 0xf7da3603 , 0x83fa8f09 , 0x7e995fab , 0xa6ae81e4 , 0xa6d7a9d3 , 0x57ccb2da , 0x05118084 ] )
+  , Mont::unsafe_make( [ 0xae532def , 0x6506c5d6 , 0x3ddd3919 , 0x4ed5306b , 0xc0993dfb , 0xa4562116 , 0xcb3bc160 , 0x154bde23 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xb3add9a8 , 0xa1bfb6e1 , 0x0f67a307 , 0x800c0f22 , 0x88ca8e65 , 0x79c9a252 , 0x3ac96b49 , 0x23ca34fc ] )
+  , Mont::unsafe_make( [ 0x780c2d63 , 0x48fdaa27 , 0xc14fe591 , 0xb176b97e , 0x6bb47197 , 0xabad6490 , 0x43f21229 , 0x1711afca ] )
+  , Mont::unsafe_make( [ 0xed6aa352 , 0xf2382258 , 0x1317bea9 , 0x78d5c9cb , 0x64ed15c7 , 0x89286048 , 0x995d5d50 , 0x27ab455c ] )
+  , Mont::unsafe_make( [ 0x0d57f1bf , 0x94763b3d , 0x1cb8c201 , 0x20bd7235 , 0xb8500e60 , 0x8000522f , 0x9494b597 , 0x064144e4 ] )
+  , Mont::unsafe_make( [ 0xbced4dd5 , 0x2fe2fa50 , 0x3a92ba76 , 0x1804b41b , 0x80e4bdf7 , 0xb44aaaca , 0x4db4b804 , 0x0839ccd5 ] )
+  , Mont::unsafe_make( [ 0x4e03cfca , 0x41ea349e , 0x7e7b72db , 0x36710b5b , 0x7d5c8940 , 0xbe9a2d0f , 0x0ecf716d , 0x21224982 ] )
+  , Mont::unsafe_make( [ 0xdc217c3d , 0xc6593e66 , 0x41cf2297 , 0x0df4df54 , 0x2e41d700 , 0x529e8bd3 , 0x5eab36ad , 0x242938f8 ] )
+  , Mont::unsafe_make( [ 0x1c5d6714 , 0x3f846100 , 0x782996ab , 0x57d27520 , 0x51d628c5 , 0xd5915ea0 , 0x1d2f88a8 , 0x2d7d9c2e ] )
+  , Mont::unsafe_make( [ 0xf8cb34a6 , 0x5dadb49e , 0x3649b931 , 0xbb742b24 , 0x5c42de38 , 0x351ccfd9 , 0xe9143f37 , 0x2c4b9429 ] )
+  , Mont::unsafe_make( [ 0x68165dd4 , 0x5ca9ff9e , 0xe3a046c6 , 0xe74c458f , 0x27ab9b5b , 0xf485bc52 , 0x5f9ece2f , 0x0de182a8 ] )
+  , Mont::unsafe_make( [ 0x3f1bc917 , 0x4345a340 , 0x71d11bb3 , 0xd94ebebb , 0x7d0fa1a7 , 0xcc9f0d46 , 0x6c7348ab , 0x0a1922b7 ] )
+  , Mont::unsafe_make( [ 0xc60fbe48 , 0xdb52aea9 , 0xeba2ced5 , 0x048dcc3d , 0x0ae50d33 , 0x80695c24 , 0xee4dc86d , 0x1a3960b9 ] )
+  , Mont::unsafe_make( [ 0x4d932535 , 0x8046be50 , 0x9e2b61e0 , 0xbb6faf1b , 0xbcfaea77 , 0xb39922fe , 0x396cd1c8 , 0x041dee0a ] )
+  , Mont::unsafe_make( [ 0x1efe1511 , 0x21c4899a , 0x5aa9db64 , 0x7e093900 , 0xec93c829 , 0x379470c9 , 0x31581ffe , 0x17422c28 ] )
+  , Mont::unsafe_make( [ 0x94d721ca , 0x19bf8d3a , 0xd7d5f755 , 0xccd10243 , 0x89a7e14a , 0xf0c1282b , 0xea67588a , 0x01615ef5 ] )
+  , Mont::unsafe_make( [ 0xa55413b6 , 0xf8bde014 , 0xdcb1034e , 0x6bb0054c , 0xa9c86b8d , 0x5cd9590d , 0x88c6c09f , 0x13c86c2d ] )
+  , Mont::unsafe_make( [ 0xb95355a3 , 0xa1193dca , 0xb48df08a , 0x10c418cf , 0x02b258fa , 0x4b770e5b , 0xb7cab0d6 , 0x11c78abe ] )
+  , Mont::unsafe_make( [ 0x669fa20c , 0x369b8136 , 0xeb0fe8f9 , 0xb082582a , 0xde7b1c1e , 0x419e2a78 , 0x29029f1d , 0x2cda874f ] )
+  , Mont::unsafe_make( [ 0x05aa9323 , 0x1183ca3d , 0xf70ea008 , 0x8f1e6d3a , 0x0baecdd8 , 0x744b9342 , 0x8c4aaa9f , 0x0e72ce57 ] )
+  , Mont::unsafe_make( [ 0xbb97be53 , 0x28b54ad4 , 0x7f9e1c21 , 0x0bbe4805 , 0x2247fd4b , 0xa8cd5f5b , 0x4e87deea , 0x174493d1 ] )
+  , Mont::unsafe_make( [ 0xc41fc279 , 0x0b4fb187 , 0xedd30cca , 0xb1d6205d , 0x2c88cb51 , 0x34413238 , 0x2e37fbb1 , 0x0580b057 ] )
+  , Mont::unsafe_make( [ 0x708d1fd4 , 0x176e1a3b , 0xe95e78dd , 0x4016bbd0 , 0x9724cdf5 , 0x9194a836 , 0xf30f8466 , 0x1f945b37 ] )
+  , Mont::unsafe_make( [ 0xbb75c200 , 0xbce5da9e , 0x576bd83e , 0xde081425 , 0xeb204751 , 0xa4ddb4d1 , 0x527887c4 , 0x15b6b625 ] )
+  , Mont::unsafe_make( [ 0x21ce1387 , 0xf155a4cc , 0x1e234b56 , 0x09cfdeb7 , 0xa739dc8b , 0xadfe9483 , 0x82b8c4de , 0x0bfdbdff ] )
+  , Mont::unsafe_make( [ 0xbe55c99d , 0x8de55dcc , 0xbde83e46 , 0xb38e6eba , 0x283683b8 , 0x4a626ac7 , 0x7c5978e3 , 0x27cb0541 ] )
+  , Mont::unsafe_make( [ 0xdd99d1a5 , 0xcd6d3347 , 0x154c01bd , 0x92753500 , 0xaaa6b614 , 0x8e4db337 , 0x59df4920 , 0x02615e3a ] )
+  , Mont::unsafe_make( [ 0x20514507 , 0x5996e5dc , 0xcffed03b , 0x6887a4fc , 0x662f7d69 , 0xa495b221 , 0x592b7aa9 , 0x2899795f ] )
+  , Mont::unsafe_make( [ 0xb292b3e3 , 0x804fb5e5 , 0x0acde4a6 , 0xa7521868 , 0x1771119d , 0xc82ef993 , 0x6f12f8a1 , 0x2d19a04f ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xa0c0d9b3 , 0x1644906f , 0x85348a08 , 0x08ea1e8f , 0x97e7a58a , 0xed590673 , 0x70732a63 , 0x1abf6773 ] )
+  , Mont::unsafe_make( [ 0x73c2c31b , 0xeac472f8 , 0x864d9150 , 0xc5587cb7 , 0xdc3a72ed , 0x7d142df0 , 0x99bf5a8d , 0x1d2c8da5 ] )
+  , Mont::unsafe_make( [ 0x85ba902f , 0x509d1f39 , 0x3f668349 , 0x99123adb , 0x4582eb3b , 0xbb685129 , 0x0d2d0dd3 , 0x20d151e5 ] )
+  , Mont::unsafe_make( [ 0x8ceff717 , 0x37d8aaff , 0x2de9d2a9 , 0xd1a27db8 , 0x0af5c2dc , 0x6d75e3ee , 0x495583b5 , 0x1afc8870 ] )
+  , Mont::unsafe_make( [ 0x37ec098c , 0x5335aa4c , 0x189618b1 , 0xd3761645 , 0x85e33970 , 0x1b22e2f6 , 0x4c82a3b0 , 0x2f96a548 ] )
+  , Mont::unsafe_make( [ 0xc4f72861 , 0x8fce1b6e , 0x16654206 , 0x7919279e , 0x858cd344 , 0xe58c69cf , 0x8dcdc3f6 , 0x1d332b2c ] )
+  , Mont::unsafe_make( [ 0x14e3ee55 , 0xca039a31 , 0xd6320192 , 0xa07a3c30 , 0x6c96edd1 , 0xe895f7d9 , 0xae8fa34c , 0x0c2edad9 ] )
+  , Mont::unsafe_make( [ 0x274a2ae4 , 0xcddf6585 , 0xe06481a1 , 0x65f6af7a , 0x1a4495dd , 0x69c2348b , 0xf8dd22a2 , 0x13c8c393 ] )
+  , Mont::unsafe_make( [ 0xf7cbf954 , 0x2248259d , 0x09da1946 , 0x65bfec83 , 0xc17b5669 , 0x9949133b , 0x61de9842 , 0x2a090ce7 ] )
+  , Mont::unsafe_make( [ 0xb76c9034 , 0xb923a57e , 0x077de03e , 0x1643cd5f , 0xfdaac3a5 , 0x66481a60 , 0x15884449 , 0x0f4e4166 ] )
+  , Mont::unsafe_make( [ 0x51cf241e , 0xdccde2d8 , 0x71fa0d2e , 0xa1a8d98d , 0x3aebcefc , 0x1579277a , 0x79db4c9c , 0x1175163e ] )
+  , Mont::unsafe_make( [ 0x5686af66 , 0x3c43cb51 , 0x81379b20 , 0x83593040 , 0xf4b41962 , 0x8789e76a , 0x7465e64a , 0x29987fc8 ] )
+  , Mont::unsafe_make( [ 0xccdd538e , 0x166c9087 , 0x01ddf46f , 0xe046e1f5 , 0x956dc5c1 , 0x8a1516c2 , 0xdccf1734 , 0x215935d1 ] )
+  , Mont::unsafe_make( [ 0x12409fab , 0xc618f005 , 0xa2608d91 , 0x35bee486 , 0x14feff35 , 0x0ee2f74a , 0xfb1d6af6 , 0x18a436b5 ] )
+  , Mont::unsafe_make( [ 0xfdb61687 , 0x8c2e1b7f , 0x894d2848 , 0xa15ac554 , 0x83e1c872 , 0x11fda944 , 0x9b3cef28 , 0x131f5dce ] )
+  , Mont::unsafe_make( [ 0xb63e5592 , 0x33ca68dc , 0xcc8e1e97 , 0x46825e35 , 0xcf5c221a , 0xc76a52b0 , 0x1db2c7e3 , 0x0ec85239 ] )
+  , Mont::unsafe_make( [ 0xbabb3ff6 , 0x55d44f87 , 0xbb252676 , 0x25ef3140 , 0x76792dff , 0x336ff2fc , 0xbe2dc576 , 0x124d054d ] )
+  , Mont::unsafe_make( [ 0xd6459c46 , 0xfed70733 , 0xc7021635 , 0xca30c065 , 0x4b829390 , 0x95b3261e , 0xe75cb51a , 0x262b640f ] )
+  , Mont::unsafe_make( [ 0x0aac1599 , 0xdcc41107 , 0x9d555463 , 0x1213fa2e , 0x42739355 , 0x9faf6311 , 0x999e7095 , 0x22d1d95e ] )
+  , Mont::unsafe_make( [ 0xb8fe0141 , 0x699cc406 , 0x27905c67 , 0x098a58e0 , 0x763c089a , 0xdb51e9c3 , 0x29301422 , 0x26077bd1 ] )
+  , Mont::unsafe_make( [ 0x0cb46de6 , 0x8784bbef , 0x82bb171c , 0x3ae53df3 , 0xa77cadc0 , 0xc805fa71 , 0x31d55f36 , 0x093d68d8 ] )
+  , Mont::unsafe_make( [ 0xbedd6bbd , 0x1bce1550 , 0xe2ecf740 , 0x2ad82936 , 0xa49eb07d , 0x023a0f57 , 0x77f928e4 , 0x1a6214fa ] )
+  , Mont::unsafe_make( [ 0xed4dc96f , 0xeccdcd7f , 0x6ac8a61a , 0xd546594d , 0xcf94b44a , 0x04b938cb , 0x8c151a22 , 0x171f22b0 ] )
+  , Mont::unsafe_make( [ 0xb67e4cfa , 0xb4dfcdf1 , 0x7ef34752 , 0xac869000 , 0x16a75bf2 , 0x93be86b2 , 0xaafe3930 , 0x0b05b689 ] )
+  , Mont::unsafe_make( [ 0x40773d99 , 0x5618cb47 , 0x2b1b6b8b , 0xb9379669 , 0xa0e49ee8 , 0xc759cf13 , 0xde22cf41 , 0x29fbe0d4 ] )
+  , Mont::unsafe_make( [ 0xb56efeae , 0xd65efbe5 , 0xda39cf73 , 0x2841e7ec , 0x98c71340 , 0xb93f5647 , 0xf412d20e , 0x2ec7cc40 ] )
+  , Mont::unsafe_make( [ 0x0add34b9 , 0x7cbee4bf , 0x06130037 , 0x074b0c96 , 0x1de42578 , 0x3151af66 , 0x414eca25 , 0x000cf781 ] )
+  , Mont::unsafe_make( [ 0xfb3a14b4 , 0x3b0ec741 , 0x7d47b95c , 0x9c251cab , 0x2e124883 , 0x9b6a67b7 , 0xb0ff1756 , 0x2aae8aff ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x5f35e6db , 0xfb955d1c , 0xd5387fc9 , 0xdd3d0c75 , 0x2dcdc7de , 0x22ad15ec , 0x4613cd3e , 0x0e7e427c ] )
+  , Mont::unsafe_make( [ 0x1987d324 , 0xca35a1e3 , 0x569936ff , 0x51582fa6 , 0x22e1fc1d , 0x7023c49e , 0x88269f42 , 0x13b58b5b ] )
+  , Mont::unsafe_make( [ 0x219181f4 , 0x1f7b7b45 , 0x473fe5c3 , 0xe04218d8 , 0xa6c944e7 , 0x30236ff9 , 0xde8abce1 , 0x073f6332 ] )
+  , Mont::unsafe_make( [ 0x76e7c7c6 , 0x5b95d967 , 0xdabcdc4c , 0xf7f4e5ac , 0xeaa8f3c8 , 0xfcfed0fc , 0xbb850e01 , 0x20a81201 ] )
+  , Mont::unsafe_make( [ 0xc78dd94b , 0x091c9c9a , 0x8a14fcb9 , 0xf2e4dcbc , 0x61b7d7f5 , 0xac324248 , 0x5d521292 , 0x277d1add ] )
+  , Mont::unsafe_make( [ 0x5356b7a8 , 0x6915471d , 0xba44fe82 , 0x5649a669 , 0x3fb5c971 , 0x1560835b , 0x3cc5fbad , 0x029828c9 ] )
+  , Mont::unsafe_make( [ 0x5bd233e4 , 0x58a16570 , 0x2c389ba9 , 0xad84ddde , 0xb8a54e00 , 0x860330d3 , 0xab1186d7 , 0x00e7b525 ] )
+  , Mont::unsafe_make( [ 0x1c5f190d , 0xbe2f7283 , 0x4b1e7ad4 , 0x41361244 , 0x4b3ec70d , 0x74adbbbf , 0x2088c3bb , 0x23abc2b8 ] )
+  , Mont::unsafe_make( [ 0xcd65fc1a , 0x80485661 , 0x80bbf230 , 0x6f3c26f5 , 0xa4129dc0 , 0x050b3fb3 , 0xb2c64606 , 0x2e2d5ab6 ] )
+  , Mont::unsafe_make( [ 0x4be42349 , 0xd3b78e0c , 0x99b3261f , 0x1153da71 , 0xba63f888 , 0xeb66e9e7 , 0xe8a1f7ff , 0x207b641e ] )
+  , Mont::unsafe_make( [ 0xe059ed03 , 0xd67d525f , 0x67997f1f , 0xeb731cf1 , 0xfcc746c1 , 0xdd5d6347 , 0xf21245e9 , 0x2e24c56f ] )
+  , Mont::unsafe_make( [ 0xfde1c9dc , 0x7806c99b , 0xab57d351 , 0x45a578dd , 0x03715dbb , 0xd9a77782 , 0xf8ff4915 , 0x10574126 ] )
+  , Mont::unsafe_make( [ 0xb23313a7 , 0x0d172d1e , 0xa15e9f1a , 0x108f1432 , 0x7295e55b , 0xc16eba6e , 0xc22249da , 0x1ff07910 ] )
+  , Mont::unsafe_make( [ 0x8aa945d3 , 0x6c0c5f6c , 0x9d590b66 , 0x58fd8e6d , 0x392b9989 , 0x59e05034 , 0x229e2d91 , 0x3063cf8c ] )
+  , Mont::unsafe_make( [ 0xe1cb375f , 0x543aeede , 0x80e9837e , 0xa6ad3c6c , 0x0f20f5ef , 0x221a417f , 0x87d1b223 , 0x1658db02 ] )
+  , Mont::unsafe_make( [ 0xe89a1892 , 0x9d420f84 , 0x1cdc1856 , 0xbc485d4c , 0xc60bd287 , 0xb7159875 , 0x043b6838 , 0x2f539763 ] )
+  , Mont::unsafe_make( [ 0x4d9bda78 , 0xc7bbda9d , 0x888babd6 , 0x101262d6 , 0x68f8f14b , 0xb4ce1512 , 0x1964c7d5 , 0x197a9ebe ] )
+  , Mont::unsafe_make( [ 0x1b43a569 , 0x5338ec0b , 0x1656e90b , 0x95de8832 , 0xf6cbd9b8 , 0x35805190 , 0x20bdf3a8 , 0x0678fd76 ] )
+  , Mont::unsafe_make( [ 0x5d16559e , 0x98bd8d72 , 0x8ab7c0ca , 0x39770c5d , 0x8195a0b1 , 0x47e35002 , 0xcb4b7879 , 0x016363ca ] )
+  , Mont::unsafe_make( [ 0x1dbe1b87 , 0x5993aa99 , 0x371a7b62 , 0x8f9a80b1 , 0xc7a63e07 , 0x24863627 , 0x26642f27 , 0x0f092fe4 ] )
+  , Mont::unsafe_make( [ 0xadf48985 , 0xbbf6aab2 , 0x49d29cef , 0xcae22a59 , 0x7fcd53fa , 0xbe939846 , 0x19607500 , 0x08153198 ] )
+  , Mont::unsafe_make( [ 0xfee1bd5b , 0x1a1a8bd1 , 0xe1fdd0d7 , 0x39a16cd5 , 0x3cb68cec , 0x1093b919 , 0xa6ce3f7f , 0x03294d42 ] )
+  , Mont::unsafe_make( [ 0x594c2e85 , 0xd29fa2fb , 0x2a2c8780 , 0x5bdae3c4 , 0x5c931351 , 0xc0637fe8 , 0x05c8dd7c , 0x16ea5dbf ] )
+  , Mont::unsafe_make( [ 0x8f4d3d6b , 0xd124c3f2 , 0xdc53b10b , 0x9e68a8e5 , 0x589793f3 , 0xb05a7b4a , 0x325b6c73 , 0x28a14b9f ] )
+  , Mont::unsafe_make( [ 0x6bea2cb8 , 0x0f4e26ac , 0x12eb16a4 , 0x1bf32c0a , 0xaff29dd4 , 0x7a1ea4dd , 0xb7a80b64 , 0x2d031633 ] )
+  , Mont::unsafe_make( [ 0x8cddf7b4 , 0x312fe47c , 0x44bc5a9f , 0xe04d8682 , 0x5bdc9882 , 0x51051803 , 0x00e29523 , 0x07d6dab2 ] )
+  , Mont::unsafe_make( [ 0x6c206812 , 0x9891b6de , 0xb6ee8aa3 , 0xa5835653 , 0x337b6746 , 0x76c1234f , 0x904ca17c , 0x0807196b ] )
+  , Mont::unsafe_make( [ 0xcd16e91a , 0xe042ffee , 0x8315cf4e , 0x6ae60b3d , 0x464fbd7a , 0xfa8895d5 , 0xb068b313 , 0x25b319d1 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xcbefc02a , 0xfaf94a5a , 0xd0782d6e , 0x006b6e85 , 0x4f2d3f6e , 0x19d2cda6 , 0xdb29a185 , 0x19ed736e ] )
+  , Mont::unsafe_make( [ 0x64bd9cd8 , 0x46fc63ce , 0x87e6675e , 0x2e65ce1c , 0xcc5265ea , 0xc70ad27c , 0x6945515b , 0x24cc8d55 ] )
+  , Mont::unsafe_make( [ 0x10c2560f , 0x9b7b6a0b , 0xa42de158 , 0x523b002e , 0x8a6ca0c5 , 0xd7d1fb64 , 0x008c22b1 , 0x2f5c1c5b ] )
+  , Mont::unsafe_make( [ 0xef9f4a8c , 0xd5a1f3b4 , 0x5a519b8b , 0x568456cb , 0xd9b8d203 , 0x31fc9209 , 0xe4958dd6 , 0x28ffb244 ] )
+  , Mont::unsafe_make( [ 0x7769940f , 0xb4fa3880 , 0xefd10556 , 0x9d2afc2c , 0x0065746b , 0x0414e5d9 , 0xa0bf0c3e , 0x2a2574a4 ] )
+  , Mont::unsafe_make( [ 0x30176ed6 , 0xd88b0ea6 , 0xa77b5dae , 0x0b57364c , 0x9e87e875 , 0xe78f3c81 , 0x09fdb630 , 0x12efe047 ] )
+  , Mont::unsafe_make( [ 0xe2b7c89b , 0xed5b70bb , 0x01daa22a , 0x6f881099 , 0xbdc8cf18 , 0x7b56a0df , 0xcea526c4 , 0x20395ed0 ] )
+  , Mont::unsafe_make( [ 0xafdd3a87 , 0xdc906b57 , 0x948db35f , 0x015d6987 , 0xcaa422dc , 0x453a5a96 , 0x6edb8425 , 0x08a79b76 ] )
+  , Mont::unsafe_make( [ 0x2ef24cf5 , 0xed056ae7 , 0x34f2e2aa , 0x0e0a760b , 0x18fd2164 , 0x19e968a1 , 0x6abede65 , 0x0d97f73a ] )
+  , Mont::unsafe_make( [ 0x6e664f25 , 0xc269f978 , 0xda8b852c , 0x6fc1650b , 0x09384821 , 0xbb17b265 , 0xc43d9688 , 0x12358023 ] )
+  , Mont::unsafe_make( [ 0x4fb4b13d , 0xad39f0c4 , 0xc5e1a215 , 0xbc8ef7c9 , 0x7fa4de6e , 0xb3c3aeb7 , 0x900d2f97 , 0x301526f3 ] )
+  , Mont::unsafe_make( [ 0xcd07e3b9 , 0xe4ecd25a , 0x60cceaa0 , 0x9a0d44c0 , 0xd8d5a8fd , 0x10839de2 , 0x7e4bd48a , 0x07f5507c ] )
+  , Mont::unsafe_make( [ 0xd2619264 , 0xb419d306 , 0x2ab068af , 0x774eea03 , 0x564acd4f , 0x2a96285d , 0x65e09258 , 0x13640371 ] )
+  , Mont::unsafe_make( [ 0xd8009173 , 0xd5a960c8 , 0x00536e8c , 0x8a11d391 , 0x6cc651b4 , 0xeff60681 , 0x88a4caa7 , 0x0c21179b ] )
+  , Mont::unsafe_make( [ 0x3ecd45e7 , 0xbfd0f9bf , 0xb2828225 , 0x66748058 , 0x7f2cf334 , 0x444490aa , 0x2d78e835 , 0x15e03f49 ] )
+  , Mont::unsafe_make( [ 0xf480f729 , 0xb7d87ad6 , 0x762c404f , 0x9789e220 , 0xb87050be , 0x42e1e7d5 , 0x3e6ab220 , 0x0ec0b893 ] )
+  , Mont::unsafe_make( [ 0x46b3ab55 , 0x8abacc88 , 0x42282712 , 0x8ea25a19 , 0xe100f7ea , 0x67efa386 , 0x2e8d9c05 , 0x304bac4b ] )
+  , Mont::unsafe_make( [ 0xcb955f3b , 0x8776d8f4 , 0x66809700 , 0xa8965fc0 , 0x3ac4c0c1 , 0xdcb94216 , 0x270276cb , 0x042078e5 ] )
+  , Mont::unsafe_make( [ 0xeb802dbf , 0xca0b20d5 , 0x08ffc779 , 0x21e112fa , 0x85066c3b , 0x7862bb4b , 0xa7f8af72 , 0x2c91707a ] )
+  , Mont::unsafe_make( [ 0xb1d3313a , 0xeefb9f74 , 0x21cd9394 , 0x5f44f5cd , 0x22de7cdd , 0x97726d8c , 0x7206d019 , 0x07580fae ] )
+  , Mont::unsafe_make( [ 0xc346a6f0 , 0x11ad4b63 , 0x14178b2a , 0x9146d190 , 0x2df2fc40 , 0xbb55cebd , 0x4ef97350 , 0x17c4310c ] )
+  , Mont::unsafe_make( [ 0x5ee23c7a , 0x667bb114 , 0x73dc53f2 , 0x10c1ef0d , 0x1a5ab558 , 0xa3476874 , 0x60354a12 , 0x01739385 ] )
+  , Mont::unsafe_make( [ 0xd7a83027 , 0xc7198fb2 , 0x65046af9 , 0xe1886044 , 0x7d025ff8 , 0xef94bb56 , 0x025e9ea2 , 0x0856e12e ] )
+  , Mont::unsafe_make( [ 0x5f4a2c57 , 0xa2b90599 , 0xc7487059 , 0xbd76b200 , 0xda2194d5 , 0x1edc9b11 , 0x31e5de80 , 0x1f00fbfc ] )
+  , Mont::unsafe_make( [ 0x1871386c , 0x3977ee00 , 0x311d3d08 , 0x76e87124 , 0xe13cc67a , 0xcf156108 , 0x33e096a9 , 0x1b4322ad ] )
+  , Mont::unsafe_make( [ 0x4e6d0695 , 0x08edb6d1 , 0x7a653ed4 , 0x29e652cc , 0x53ace946 , 0x00da009d , 0x18acba4e , 0x066d2216 ] )
+  , Mont::unsafe_make( [ 0x0aa437ab , 0x118a44e4 , 0xc988ac9a , 0x91eae0b6 , 0x4d357d23 , 0x301bffe7 , 0x38af7c30 , 0x0c8f49df ] )
+  , Mont::unsafe_make( [ 0x18bd0c0c , 0x40d11346 , 0x0f526549 , 0x30b6d29b , 0xb05b4ead , 0xa85225e7 , 0x657afcf5 , 0x12faccba ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xb0fa69b5 , 0x9f357d08 , 0xa697c514 , 0x7b4032cd , 0xed15fc98 , 0x2432537c , 0xc7d173a5 , 0x005b003d ] )
+  , Mont::unsafe_make( [ 0x4c432240 , 0x30b4869b , 0xaf5c60a2 , 0x75e2e5df , 0xf03e4dad , 0x09624302 , 0x50468874 , 0x04e6dcfa ] )
+  , Mont::unsafe_make( [ 0xa9d280be , 0x9f0ae549 , 0x59f04762 , 0xf0e23f45 , 0x584e5b48 , 0xd1329e3d , 0xe7c4f175 , 0x0875aa45 ] )
+  , Mont::unsafe_make( [ 0x558ad636 , 0xf42ee19d , 0x76ae1a6d , 0xfc190d87 , 0x6cf2a499 , 0x9bc1f7d3 , 0xbd11f0cd , 0x0d66fae0 ] )
+  , Mont::unsafe_make( [ 0xa8041470 , 0xff58aab1 , 0xd5006358 , 0x6b08c719 , 0x34454027 , 0x44e4b867 , 0x9eca5d75 , 0x22a7cb96 ] )
+  , Mont::unsafe_make( [ 0x38f694bf , 0xc5d6d532 , 0x927530a5 , 0x333b8307 , 0x4b18f252 , 0x6a447ce9 , 0x60c0e6dd , 0x24e87d27 ] )
+  , Mont::unsafe_make( [ 0xe878c60b , 0x2f30dab8 , 0xc8b19de0 , 0xca6d6256 , 0xd6b74be6 , 0x73fae00f , 0xdcd18336 , 0x0dfa8ce0 ] )
+  , Mont::unsafe_make( [ 0x2ffb2c3a , 0x119d9c99 , 0x89f4a2da , 0xfee2b7a2 , 0xb2177e43 , 0xef906823 , 0xc7716f60 , 0x07fd5204 ] )
+  , Mont::unsafe_make( [ 0x47060482 , 0xa6bd4ec6 , 0xea6aa501 , 0xcb7a09b5 , 0x0ebe7bfc , 0x366ffc26 , 0x6f51cb8e , 0x0fe331b3 ] )
+  , Mont::unsafe_make( [ 0x878b3a92 , 0x6c676975 , 0x6df7043f , 0xd8f89607 , 0x8df7e638 , 0x94b8f006 , 0xe354151a , 0x27e84f92 ] )
+  , Mont::unsafe_make( [ 0x0eb68c38 , 0x1ec6f418 , 0xfde65cbd , 0x1d2826f7 , 0x11214fbc , 0xffde9366 , 0x4a1c3576 , 0x14c8438e ] )
+  , Mont::unsafe_make( [ 0x1ed6330c , 0x50d08dda , 0x0d56bc06 , 0xa178866f , 0x3c2187c0 , 0xac9e661d , 0x606248cd , 0x2ebe229d ] )
+  , Mont::unsafe_make( [ 0xd648e34c , 0x6ca888a7 , 0xff298883 , 0x902d2437 , 0x79a32881 , 0x450068da , 0x3e9591df , 0x0564399e ] )
+  , Mont::unsafe_make( [ 0x951f17c2 , 0x35246e35 , 0xcd2862eb , 0xef970783 , 0x823a55a7 , 0x04cce12a , 0xf37c4830 , 0x2f2bffe7 ] )
+  , Mont::unsafe_make( [ 0xd405a563 , 0xbb266d3c , 0x6cf37084 , 0x819a7a3b , 0x61d62a25 , 0xd90cb8e2 , 0x4056d860 , 0x284865db ] )
+  , Mont::unsafe_make( [ 0x96892cec , 0x210e720e , 0x77e30bd1 , 0xe4d1a67c , 0x8f751683 , 0x64c6ecf8 , 0xf3b4911f , 0x0b57e7ef ] )
+  , Mont::unsafe_make( [ 0xd158a878 , 0x09285c96 , 0xe122950d , 0x3bad4b2d , 0xc5c2eeb3 , 0xd8cf59e2 , 0xfd281a00 , 0x0e71e047 ] )
+  , Mont::unsafe_make( [ 0x2c062bb0 , 0x4e158c67 , 0x1287a62d , 0x735589ca , 0x9900f0b6 , 0xb7fc6de9 , 0x4497eaea , 0x1e66dd1b ] )
+  , Mont::unsafe_make( [ 0x844bfb8a , 0xfef7a294 , 0x9f958ccf , 0x0fba5232 , 0x56e1ec3b , 0x83787792 , 0x253db531 , 0x1f831214 ] )
+  , Mont::unsafe_make( [ 0x7290068e , 0x21940d1b , 0xac86f09e , 0x6517b5b5 , 0x77567024 , 0xd9092c7e , 0x38c14398 , 0x0ffb6d05 ] )
+  , Mont::unsafe_make( [ 0xf8f4b0bb , 0x270a10db , 0xf94ef80c , 0xb46740c9 , 0x4870745c , 0xcf54ffa2 , 0xbc842cb1 , 0x15cce12d ] )
+  , Mont::unsafe_make( [ 0x4ac68649 , 0x2fe65137 , 0x6abba550 , 0x15f792e8 , 0x75cc195b , 0xc4cf7f94 , 0x0f317ce0 , 0x27368ee9 ] )
+  , Mont::unsafe_make( [ 0x95767469 , 0x4eaa39e9 , 0x64240382 , 0x635470f6 , 0x771ef6aa , 0xcb7d2850 , 0x415892a1 , 0x08bf4887 ] )
+  , Mont::unsafe_make( [ 0xa27c119e , 0x876af19d , 0xea370ede , 0x190fac3f , 0x3cb6e4a6 , 0x3f576e19 , 0x0f191c6a , 0x0ba87a69 ] )
+  , Mont::unsafe_make( [ 0x34bd2e4a , 0x7797759c , 0x2a912e65 , 0xea8cb68b , 0xadb54b24 , 0xe7cb19df , 0xb391b39c , 0x2b417c0a ] )
+  , Mont::unsafe_make( [ 0xf6780035 , 0x546ee96d , 0xe2938d4a , 0x1e46a9c0 , 0x978f05fe , 0x8d9328ad , 0xac8dd620 , 0x1959083f ] )
+  , Mont::unsafe_make( [ 0x6b0e0ea0 , 0xff3d8b6d , 0xc62f1584 , 0x150173fc , 0x489ef481 , 0x83f20ca0 , 0x8e9ab147 , 0x22cbcf9f ] )
+  , Mont::unsafe_make( [ 0x84686f58 , 0x0430cd3f , 0x48312753 , 0xbe527fa9 , 0xbce54db9 , 0x37327e55 , 0xd6ce73fb , 0x20fb210f ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x210e8a5f , 0xe50df47e , 0x9e1d5708 , 0x7779ffd9 , 0x695d6b6a , 0xb042b61b , 0xdd195fb6 , 0x28caf21f ] )
+  , Mont::unsafe_make( [ 0xabba442e , 0x93b9079f , 0x2de4622b , 0x563e2574 , 0x1f339f95 , 0x66433a7c , 0x78f94e80 , 0x13c032a8 ] )
+  , Mont::unsafe_make( [ 0x5f79e9d6 , 0x3363f0e2 , 0x2e37b852 , 0x1186155c , 0xd06f2486 , 0x7dcec130 , 0x64cd4dcb , 0x29902e83 ] )
+  , Mont::unsafe_make( [ 0x02d227ef , 0xee83c6d7 , 0x0bb603af , 0xe3cd81d9 , 0x97249da9 , 0x5304a706 , 0xe98ed89c , 0x2880f44f ] )
+  , Mont::unsafe_make( [ 0x5deb0ec9 , 0x7391c30f , 0xb0f0ded1 , 0x72c70c13 , 0x0038c6e9 , 0x7bcf24b4 , 0x4da49432 , 0x2e244090 ] )
+  , Mont::unsafe_make( [ 0x9bd2fc2c , 0x2fb71832 , 0x7719ec41 , 0x9056b4c7 , 0xdeff6a04 , 0xc589ec14 , 0x999983fb , 0x0ba6a17f ] )
+  , Mont::unsafe_make( [ 0x447de2ef , 0x1679e48a , 0x47ea099b , 0x2d936811 , 0x6e71a680 , 0x402f58de , 0xde4bc6e2 , 0x08c9f261 ] )
+  , Mont::unsafe_make( [ 0xf0da274d , 0xf0a135b8 , 0xc5c8db99 , 0x304df7bd , 0x81f59402 , 0x487652aa , 0x0cfa3385 , 0x046ddf25 ] )
+  , Mont::unsafe_make( [ 0x9f60bf15 , 0x48a080f9 , 0x19393e73 , 0xf57f911b , 0xd36a397b , 0x497c6bcf , 0x1c286705 , 0x045bf62a ] )
+  , Mont::unsafe_make( [ 0x3d215dd9 , 0xe997a333 , 0x44a60ca8 , 0x33e2864c , 0xc37c2dee , 0xcfbeab0d , 0x8607ae2f , 0x149c3c83 ] )
+  , Mont::unsafe_make( [ 0x73825189 , 0x437b117f , 0x8685bb23 , 0x84b24b1a , 0xf304d694 , 0xa55920c2 , 0xe9409663 , 0x1f6ad1bb ] )
+  , Mont::unsafe_make( [ 0x6a8db23e , 0xea026760 , 0xd489decc , 0x0f6d85ac , 0x6af14977 , 0x1cc16f05 , 0x97f39447 , 0x091681bf ] )
+  , Mont::unsafe_make( [ 0x13fe62fe , 0x038a846d , 0xef13a597 , 0x9f97c37e , 0xa31b42d4 , 0xab8a8d7e , 0x51bafff9 , 0x1733ac70 ] )
+  , Mont::unsafe_make( [ 0xcd9bcd6d , 0x284f70f4 , 0x7888befc , 0xca0ddd56 , 0x287f8fff , 0x89d1191e , 0xd57a9dc0 , 0x27795e23 ] )
+  , Mont::unsafe_make( [ 0xae87a1fa , 0xd06867c8 , 0x824c3e88 , 0x0c9c5b06 , 0xd7afbb3f , 0x7cf31605 , 0x6cb37823 , 0x0f125b2e ] )
+  , Mont::unsafe_make( [ 0xbd5daa3d , 0x14d0d9e6 , 0x21d3b8bf , 0x1aa9e5c3 , 0x53d2eb81 , 0x855e8ba6 , 0x1a9c9aca , 0x1f829689 ] )
+  , Mont::unsafe_make( [ 0xbc1abe9c , 0x4690c276 , 0x0053ef12 , 0x695249cb , 0x284c8224 , 0x03c29f37 , 0x3dc2ea5c , 0x111dcdf2 ] )
+  , Mont::unsafe_make( [ 0xbb46cbb9 , 0x14b31603 , 0xe1f7cfdb , 0xe3651d31 , 0x433989f9 , 0xe667179d , 0x86447d7e , 0x04ebe964 ] )
+  , Mont::unsafe_make( [ 0x891f7b22 , 0x354269e8 , 0xbfb77612 , 0xc0f92058 , 0x6b615df9 , 0x9f40226e , 0x7dd0beb0 , 0x1746f30a ] )
+  , Mont::unsafe_make( [ 0x87a203ea , 0x8cccea06 , 0x54a04114 , 0xe4a12a34 , 0x8b55e457 , 0x1c684636 , 0xd15a9726 , 0x1adb18a7 ] )
+  , Mont::unsafe_make( [ 0xb7b0dcc4 , 0xf784ae12 , 0xc57b7573 , 0xaab88a53 , 0x5b9e77af , 0x23982864 , 0x1d9e55f7 , 0x11a786fa ] )
+  , Mont::unsafe_make( [ 0x104793c6 , 0x7b88b8fd , 0x6e9a0332 , 0x8370ffc1 , 0x38be6ecd , 0x08cb49f7 , 0xed83f5e3 , 0x1e960a88 ] )
+  , Mont::unsafe_make( [ 0xc99b38a9 , 0xcabbebca , 0xb025f3fc , 0x644b63f8 , 0x8c2eab15 , 0xe08fd3cc , 0x5b22b97c , 0x1f755601 ] )
+  , Mont::unsafe_make( [ 0xf430a227 , 0x6ca9ca01 , 0x1ab22740 , 0xfe9280d0 , 0x162181a7 , 0x41d54e26 , 0x8673e8f4 , 0x2a704c2c ] )
+  , Mont::unsafe_make( [ 0x1fde3a79 , 0x556b8993 , 0x51d8adcb , 0x1ce93cd9 , 0xc07867f3 , 0xa997ef06 , 0x54adc48d , 0x1cf39339 ] )
+  , Mont::unsafe_make( [ 0x879888f6 , 0x1eb1a637 , 0x32d6c199 , 0xf685b7d5 , 0xc9da8717 , 0x18146537 , 0xfde38799 , 0x158eb545 ] )
+  , Mont::unsafe_make( [ 0xdacc5934 , 0x5d45a820 , 0x2198b2a6 , 0x4dc49985 , 0x2e710761 , 0x3f65b631 , 0xf8eb2011 , 0x2e7c8d25 ] )
+  , Mont::unsafe_make( [ 0xb4aab40d , 0xa3098fa4 , 0x0bc87e27 , 0x50676f7d , 0x5cdf0461 , 0x5fbd89dc , 0x45e237ba , 0x25c0fcd9 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x085db82c , 0xd321e6ff , 0x51268558 , 0xcb52debc , 0x86f88293 , 0x7c927849 , 0x5bb5d5d0 , 0x1330e038 ] )
+  , Mont::unsafe_make( [ 0x587c9dec , 0x878ef959 , 0xffc01d73 , 0x26046db6 , 0x9236b334 , 0x2690056d , 0x7745e2ab , 0x13801c62 ] )
+  , Mont::unsafe_make( [ 0x1ac4c2b8 , 0xe31816d8 , 0xd1dfc473 , 0xc1a5b24a , 0xb5fc6919 , 0x3954d71f , 0x39601386 , 0x04609b3d ] )
+  , Mont::unsafe_make( [ 0x480adc5b , 0x0d8748f5 , 0x815b49ed , 0x62b39b5b , 0xa3387a5d , 0xd0be0406 , 0xadd52a18 , 0x2c8f7515 ] )
+  , Mont::unsafe_make( [ 0xaa48b0cb , 0x6118ea81 , 0x4378f0aa , 0x29221e7b , 0x33c9df47 , 0x19db3748 , 0xda2586f8 , 0x26c9a8e1 ] )
+  , Mont::unsafe_make( [ 0x20cb5457 , 0x2be3e10b , 0x6930578d , 0xb1a6cb93 , 0x1fe57bc9 , 0x3984dd90 , 0x1e6da90e , 0x0ed3e22c ] )
+  , Mont::unsafe_make( [ 0x34abf1cc , 0x4eb0d335 , 0xc15407d4 , 0x6ddb9a8c , 0x46de69d5 , 0x5a21837b , 0x8730c80e , 0x1e82d9ac ] )
+  , Mont::unsafe_make( [ 0xd54b48ea , 0x7cd4c628 , 0xc9dd11ce , 0x89e75276 , 0xe56ef211 , 0x1e74deba , 0x4ecc22d2 , 0x20938a6d ] )
+  , Mont::unsafe_make( [ 0x35a1ed39 , 0xc92c6414 , 0x1d1b2782 , 0xc3238bd0 , 0xd278457c , 0xe7567a8d , 0xff67ccb0 , 0x14269b58 ] )
+  , Mont::unsafe_make( [ 0xbd83a06f , 0xa3250890 , 0xb8d4e6d7 , 0xec3fb6af , 0x2bb4ab84 , 0x4dbaa145 , 0x26caae50 , 0x068b517a ] )
+  , Mont::unsafe_make( [ 0xcbba641c , 0xcd9f2d5f , 0x26c0e17b , 0xd062d1e5 , 0xc8148e7e , 0xaf8e0c16 , 0x83077f80 , 0x0bb8eb4f ] )
+  , Mont::unsafe_make( [ 0x62715ed5 , 0xa28ff3b1 , 0x24e69fe0 , 0xd638de46 , 0xfb2611ad , 0xbe14bab6 , 0x3c950aee , 0x2c59825e ] )
+  , Mont::unsafe_make( [ 0xb7975b04 , 0x3b4b040c , 0xbdbd24d5 , 0xdfca4f4d , 0xbeb7c04a , 0xbf745966 , 0x176a86ba , 0x09c52181 ] )
+  , Mont::unsafe_make( [ 0x375f29a0 , 0xd5682185 , 0x97b514bf , 0x5e97dc48 , 0x30792417 , 0xa94c92f3 , 0xd16744ff , 0x1835d662 ] )
+  , Mont::unsafe_make( [ 0x52a343cb , 0x5a0e3171 , 0xb4f74f75 , 0x95217cd2 , 0x72919445 , 0xf9febe2e , 0xa6934349 , 0x2fd49a43 ] )
+  , Mont::unsafe_make( [ 0xe3cefdcd , 0x83b5c64f , 0x15142e7f , 0x3ba7624e , 0x3a8ece07 , 0xc7738e98 , 0xfe623014 , 0x2715f05c ] )
+  , Mont::unsafe_make( [ 0x2f9cbd69 , 0x63f1ae8b , 0xd629901a , 0x5c334e2e , 0x00b4581c , 0xc015c6ec , 0x4e3a7e80 , 0x14e4047b ] )
+  , Mont::unsafe_make( [ 0xe30d7904 , 0x1137b275 , 0xfef7a596 , 0x72a439ad , 0x8a873925 , 0x4bc944e1 , 0x5efb70e1 , 0x088afb3b ] )
+  , Mont::unsafe_make( [ 0xd576cc8e , 0x03b336ec , 0xcd69b332 , 0x23dab745 , 0xcd4ee492 , 0x35e8f6cc , 0xc289011d , 0x05227603 ] )
+  , Mont::unsafe_make( [ 0x9423c5f3 , 0x1951c57c , 0x6df345f8 , 0x0198cd92 , 0x8efe8ff7 , 0x0cda47cf , 0x6cf9f19b , 0x139735b4 ] )
+  , Mont::unsafe_make( [ 0x5e4eede9 , 0x5378dd0b , 0xf168bffd , 0xdcd57668 , 0x72ef3d0c , 0xe1165dd4 , 0x562dd80e , 0x11b258cf ] )
+  , Mont::unsafe_make( [ 0xba34ae07 , 0xf56dcc8b , 0xa8ae07d6 , 0x2ca118a8 , 0x30725445 , 0x9faac11a , 0xaf99b009 , 0x2c91f466 ] )
+  , Mont::unsafe_make( [ 0x63058947 , 0xe0827c04 , 0xb4964788 , 0x27ac6383 , 0x4ceb104d , 0x0ea26987 , 0xda4cfdbf , 0x04f97572 ] )
+  , Mont::unsafe_make( [ 0xbc86a88b , 0xe87cf1cc , 0xf818c963 , 0x46ba7bba , 0x5a179b91 , 0x09027fb7 , 0x01c00dd6 , 0x19b2eaa6 ] )
+  , Mont::unsafe_make( [ 0x534712c7 , 0x5c012b58 , 0xbb03d8a7 , 0x24307746 , 0xa522d77e , 0xb972d7ae , 0x7e585254 , 0x2f19f6d8 ] )
+  , Mont::unsafe_make( [ 0xc87fdaec , 0x3c5c71d0 , 0xdb139a79 , 0x572961e8 , 0x66c92718 , 0xd994e636 , 0xbd9bad3f , 0x26218edf ] )
+  , Mont::unsafe_make( [ 0xc58c6d25 , 0x0968ebf0 , 0x46480c01 , 0x4b51c9d6 , 0x44ac55b0 , 0x4c614d16 , 0xe7806e05 , 0x0d9c57cf ] )
+  , Mont::unsafe_make( [ 0x0f654c9f , 0x0c5013a1 , 0x4861cadf , 0xa66dbdee , 0x25a70b6a , 0x960b31f4 , 0xe331f652 , 0x03126aa2 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x466092a4 , 0xf1868d44 , 0x5442544f , 0xc5ea9131 , 0x6dd42a80 , 0x04d5ecef , 0xecdf2cb5 , 0x11debef5 ] )
+  , Mont::unsafe_make( [ 0xebdbf7ef , 0xb25441f8 , 0x7bea1bcc , 0x97043e82 , 0x1bcf962c , 0x00be97be , 0x8c22e5c2 , 0x2f47ebcf ] )
+  , Mont::unsafe_make( [ 0xe371356b , 0x779c714e , 0x35533a06 , 0xcd86e01e , 0x979959c6 , 0xc9426632 , 0x5d1d3fa9 , 0x1fa5d20d ] )
+  , Mont::unsafe_make( [ 0x345872ce , 0x26cfa03e , 0x5c05acf5 , 0x0529d19e , 0x8261a8de , 0x9e5bab7e , 0x7bf034f1 , 0x00f12166 ] )
+  , Mont::unsafe_make( [ 0x1c16751f , 0xe6973f45 , 0xdb63f0a4 , 0x06b132be , 0xc2cd21d0 , 0xc5c68b05 , 0x9c63749e , 0x0b835583 ] )
+  , Mont::unsafe_make( [ 0x7e9d9ffa , 0x3d3cfd69 , 0x7d9e71d1 , 0x4a790971 , 0x37f719a6 , 0xb27d8bbd , 0xcaef9d14 , 0x295a6c26 ] )
+  , Mont::unsafe_make( [ 0x83e31b3b , 0x2b6c6b08 , 0x68725e28 , 0xa0734969 , 0x9257dacd , 0x99fb9d84 , 0x58efaf6e , 0x27adc5b0 ] )
+  , Mont::unsafe_make( [ 0xa52fcaa7 , 0xcc66f416 , 0x095be14b , 0x17752fc7 , 0x4437baed , 0xf8982f4e , 0x6d6e32c4 , 0x148366e8 ] )
+  , Mont::unsafe_make( [ 0x8fa9f09a , 0xfa9df81d , 0xbe2b1784 , 0xa615c761 , 0x533b70e3 , 0x2a6f901f , 0x60252e2b , 0x2fe9fa3b ] )
+  , Mont::unsafe_make( [ 0xeb1a8f69 , 0x6732b885 , 0xa9f5e5bf , 0x5e10ba3c , 0x2ff36bfb , 0x1bea615b , 0x8dc0db32 , 0x1b145aae ] )
+  , Mont::unsafe_make( [ 0x238501bd , 0xe270caea , 0x47d170f3 , 0x3fc9dda3 , 0x16670e65 , 0x3388d521 , 0xcaeed3c7 , 0x2f165997 ] )
+  , Mont::unsafe_make( [ 0xa2bda808 , 0x8e1a85c7 , 0x62387137 , 0x3526a74b , 0x834988fe , 0x08389b38 , 0x82e3c9ec , 0x0ec0ef35 ] )
+  , Mont::unsafe_make( [ 0x0c2519fb , 0x3270d1ba , 0x734cdb80 , 0xff12e876 , 0x8ff9891f , 0x90eabd31 , 0x1babed48 , 0x1af4fdd4 ] )
+  , Mont::unsafe_make( [ 0x7f1ae58f , 0xf8639801 , 0xb71dd205 , 0x4281b993 , 0x01c6cb5a , 0xb8137c71 , 0x89b17141 , 0x2067aea2 ] )
+  , Mont::unsafe_make( [ 0xaf3208ca , 0xe7a1ba32 , 0xbf02f740 , 0x71af2585 , 0x0d7bb0f8 , 0x14ccc53b , 0xe83ac718 , 0x02d0ab5c ] )
+  , Mont::unsafe_make( [ 0x047e4e20 , 0x85720a49 , 0xcf3d2901 , 0xca082824 , 0x02bfd3e7 , 0xbf100585 , 0x7b862215 , 0x06ea56f1 ] )
+  , Mont::unsafe_make( [ 0x7f40dff2 , 0x923f676a , 0x3a1fdda8 , 0x478a19e2 , 0x4a96baaf , 0x5164a322 , 0x114577d7 , 0x2ef81d6e ] )
+  , Mont::unsafe_make( [ 0x32ca64b6 , 0xb5d1d594 , 0xab195fa1 , 0x30b4dc3a , 0xb2d22356 , 0x69a50f40 , 0x245a4d66 , 0x2dc0ec35 ] )
+  , Mont::unsafe_make( [ 0xb208b1c6 , 0x68d8f30e , 0xb93d7c1f , 0xcd7e5e8e , 0xc65abcc0 , 0xeb04a52d , 0xa874a684 , 0x0a577a63 ] )
+  , Mont::unsafe_make( [ 0x85ce66f6 , 0x254a8957 , 0x9f0fdb1d , 0xe49c2ff5 , 0xec2cfb1f , 0xca22c5ae , 0x0609a4a9 , 0x2253faf3 ] )
+  , Mont::unsafe_make( [ 0x08ad8905 , 0x81563afc , 0x4dbc793e , 0x21fef300 , 0xf9d62cb3 , 0x722aa9a8 , 0x23fd150d , 0x15f24fb8 ] )
+  , Mont::unsafe_make( [ 0x0e80d643 , 0x6acb3d32 , 0x00321edd , 0x5cda2381 , 0x73ff9171 , 0xcc71036d , 0x42cc04e4 , 0x026812c8 ] )
+  , Mont::unsafe_make( [ 0x8d8dfbda , 0xf4d66af0 , 0x34a4b13d , 0x3811c186 , 0x30b57348 , 0xa8163a02 , 0xe86935f5 , 0x0d31bb7a ] )
+  , Mont::unsafe_make( [ 0x8caf8156 , 0xe95b09f1 , 0xff8f2242 , 0x70442e64 , 0x8a50abfa , 0x93339c23 , 0x6777a477 , 0x1710c1d8 ] )
+  , Mont::unsafe_make( [ 0x8b1313fc , 0x4391c056 , 0x43ffa0b8 , 0x8cab199f , 0xd8e0ba24 , 0x4c584734 , 0xef5821ca , 0x188e9db2 ] )
+  , Mont::unsafe_make( [ 0x9c3d9332 , 0xa8b9de2e , 0x46e95fdd , 0xafaf02cd , 0xf0bfb3a4 , 0x2d40e4ef , 0x484bd564 , 0x1b573345 ] )
+  , Mont::unsafe_make( [ 0x760ee7d9 , 0xe97b41a9 , 0x30fc6aed , 0xc89f6111 , 0x890d2cee , 0xb1928dad , 0xd24392a3 , 0x15bc2eb6 ] )
+  , Mont::unsafe_make( [ 0xa3888098 , 0xd31ce380 , 0xec4e3085 , 0xcf807d35 , 0x78f7c336 , 0x4733ae99 , 0xa9fd6960 , 0x205fb7d3 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x91783464 , 0x815f7e55 , 0x43b34c3f , 0x56038708 , 0x58d132eb , 0x782e3b87 , 0x9fa3fbfe , 0x2117cf65 ] )
+  , Mont::unsafe_make( [ 0x8cdcf401 , 0xdfb60303 , 0x41355ca7 , 0xc765c36c , 0xddfac654 , 0x60953057 , 0x0e96fbb2 , 0x22ab2b6c ] )
+  , Mont::unsafe_make( [ 0xccfc1996 , 0x95376d89 , 0x5d13305c , 0xe5ea8825 , 0xe5fc8f8d , 0xbfb159a6 , 0xa6cf69cf , 0x254168b9 ] )
+  , Mont::unsafe_make( [ 0xa0d83980 , 0x2816255c , 0x4b63370e , 0x8b739a28 , 0x551b8eb4 , 0xada51ba3 , 0x7d86ae76 , 0x10f9e915 ] )
+  , Mont::unsafe_make( [ 0x68307a72 , 0xae25b106 , 0x30a729f3 , 0xf14f8d1b , 0xbffe1a5a , 0x511d7595 , 0xa6d3b87c , 0x028646a5 ] )
+  , Mont::unsafe_make( [ 0x59882219 , 0xb241c124 , 0x73cd4165 , 0xe1a72796 , 0xc07887c5 , 0x29e5e292 , 0xb312cf2e , 0x255f7389 ] )
+  , Mont::unsafe_make( [ 0x93bfc506 , 0x6f9c19a0 , 0xeb1aa58e , 0x362863b8 , 0x944b2eee , 0xda6f8a08 , 0xfc433a5e , 0x041e3780 ] )
+  , Mont::unsafe_make( [ 0x7f83c304 , 0xf7764c52 , 0xc0dea4f6 , 0x525c19b0 , 0x4800a3b1 , 0x18872cf2 , 0xe110aaf9 , 0x02b748ec ] )
+  , Mont::unsafe_make( [ 0xef1cbce6 , 0x06681984 , 0x023df1f4 , 0xa33f751e , 0xedc4952b , 0xd71635c9 , 0x5127247b , 0x2e667d75 ] )
+  , Mont::unsafe_make( [ 0x3a21e7da , 0xa5fd1179 , 0xd2a9b25f , 0xf60578f1 , 0x876403a5 , 0xd6a8d0cb , 0x630da09a , 0x2546e33a ] )
+  , Mont::unsafe_make( [ 0xf0c24104 , 0x40b48dde , 0xf58b5046 , 0x1bf6a736 , 0x63c01430 , 0x08d5af74 , 0x6adab3bd , 0x2de76215 ] )
+  , Mont::unsafe_make( [ 0xd96e382b , 0xd83a8762 , 0x3d91b7e5 , 0xa9846b47 , 0xc5a6e617 , 0x98ffff7c , 0xdb65a1de , 0x06e9f4e6 ] )
+  , Mont::unsafe_make( [ 0x57d08e8a , 0xf000b434 , 0xf46c998a , 0x56697a1a , 0x9af90edf , 0xe9e1b2f7 , 0x4eece619 , 0x0f10116d ] )
+  , Mont::unsafe_make( [ 0x985dfc90 , 0x121437d6 , 0xc0d25857 , 0x19fc1366 , 0xaaedb761 , 0x8b46c6f8 , 0x0efe053a , 0x04d14571 ] )
+  , Mont::unsafe_make( [ 0x46819516 , 0xe4770667 , 0xa416f2c6 , 0x99ac0c1c , 0x8d648ace , 0xdded9e6a , 0xb98c1438 , 0x1b40e6aa ] )
+  , Mont::unsafe_make( [ 0x5e6a26de , 0x026da954 , 0x8bc88499 , 0xf8469b19 , 0x2fde2805 , 0x288190e9 , 0xfa2767e3 , 0x2c51964e ] )
+  , Mont::unsafe_make( [ 0x9d552d5e , 0x677a3fa1 , 0x5f236887 , 0x9b82bd18 , 0x37090b15 , 0x312f0310 , 0xa5f6b406 , 0x1ea27355 ] )
+  , Mont::unsafe_make( [ 0x3864b096 , 0x41965122 , 0xd3e47c40 , 0x2b3ea381 , 0x2a523600 , 0xa6fe3253 , 0x4ae47b4a , 0x0e069c49 ] )
+  , Mont::unsafe_make( [ 0x30f66988 , 0x6f93eb16 , 0x4a854258 , 0x9e8df2b1 , 0x9d2d0283 , 0x85d24f5f , 0xa58891f9 , 0x0d8e6a3a ] )
+  , Mont::unsafe_make( [ 0xed74a4a3 , 0xba062e6f , 0xa28ae693 , 0xc39ee050 , 0xb5cf746e , 0xa907f4ff , 0x37c396e0 , 0x1377a4bd ] )
+  , Mont::unsafe_make( [ 0x6ed1e75d , 0x7fbbbae1 , 0xdda4d5d4 , 0x176da6ce , 0x13cd315e , 0x8ac91826 , 0x32905cb2 , 0x1441c0ce ] )
+  , Mont::unsafe_make( [ 0x195065df , 0xefe56f7e , 0x2bfd3d73 , 0xcce34414 , 0xb29ae7e5 , 0xc6b2a3e6 , 0x16a5adfe , 0x1eef0bef ] )
+  , Mont::unsafe_make( [ 0xa825c8ac , 0xa286eb1f , 0xa1e0ed23 , 0x22629515 , 0x0f573a90 , 0xf153f9ea , 0xb8d81df2 , 0x2f09e7ed ] )
+  , Mont::unsafe_make( [ 0xb77306f2 , 0xfbf194a9 , 0xe47cc71a , 0x61358a67 , 0x4a62643a , 0xaceed2ac , 0x68912c76 , 0x10fc4a3e ] )
+  , Mont::unsafe_make( [ 0xd22a0ec0 , 0x67786925 , 0x8e9bbf0a , 0xb2090bf6 , 0xec1fe7cb , 0x255a5594 , 0x2dcefca7 , 0x17f8d41d ] )
+  , Mont::unsafe_make( [ 0x8e9132dd , 0x20d71822 , 0xbce83d38 , 0xbf891939 , 0xe8c44cab , 0x654ece2d , 0xa028562f , 0x214e34de ] )
+  , Mont::unsafe_make( [ 0x66a67e84 , 0xbf600093 , 0x3a9e58d5 , 0xd29f55bc , 0xa16ad337 , 0xe27cfe7c , 0x1256b440 , 0x1ad2167a ] )
+  , Mont::unsafe_make( [ 0x5cab94ee , 0x7689bb75 , 0x418fbbf4 , 0x0ce0ce1e , 0x2557614b , 0x32596a75 , 0xf58e5946 , 0x2d78853c ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xab771db2 , 0x1342fa53 , 0xa8ba1e7f , 0x05dc0eff , 0x67f1cab2 , 0x6e2a2735 , 0x5231e08a , 0x0acbb3f1 ] )
+  , Mont::unsafe_make( [ 0x897c7a49 , 0xa8a6ed74 , 0x620a4c09 , 0x795cda8d , 0x85a15d47 , 0x0dca0d1a , 0x3e14d0f0 , 0x2af227b2 ] )
+  , Mont::unsafe_make( [ 0xe15e165c , 0x57e60f96 , 0xdb30d33b , 0xa7688357 , 0x52e9c83c , 0x715d7b99 , 0x9e6cb58d , 0x02529b37 ] )
+  , Mont::unsafe_make( [ 0x61969313 , 0x091f3096 , 0xec54b7f8 , 0x8fa02e32 , 0xb7063eaf , 0x5adc7b4b , 0x86a192a2 , 0x211b6359 ] )
+  , Mont::unsafe_make( [ 0xeac41d3d , 0xd4bb1c90 , 0x2ec07d1f , 0x1c39b479 , 0xd0f36ff0 , 0xb217f8be , 0x2cca8897 , 0x265cb356 ] )
+  , Mont::unsafe_make( [ 0x65f20a85 , 0xe12c8830 , 0x9fad69ad , 0x07856a4c , 0x634098c4 , 0x745a3b57 , 0x160dfddc , 0x1d3849b4 ] )
+  , Mont::unsafe_make( [ 0x4cec84da , 0x43aca7cf , 0x0ab3122b , 0xdd51653d , 0xe070ee47 , 0x80fd00e5 , 0x2d19d609 , 0x25d974e7 ] )
+  , Mont::unsafe_make( [ 0x09d3a635 , 0xc1a596a2 , 0xa4d5615b , 0x7ce5cc88 , 0x024aa08e , 0xa3b47f1b , 0xc5f822b6 , 0x19159a8e ] )
+  , Mont::unsafe_make( [ 0x7697bde9 , 0x7eec5f8b , 0x3461d8ef , 0x8baf2669 , 0x9d6d3539 , 0x5c6e1a46 , 0x110dfeb7 , 0x2cfef447 ] )
+  , Mont::unsafe_make( [ 0x45173e77 , 0x14f39fd6 , 0x1088fc8c , 0xc3b32340 , 0x1b7aee15 , 0xcaf43e69 , 0xf1a0121f , 0x13b28aad ] )
+  , Mont::unsafe_make( [ 0x0747f50f , 0x362be7f0 , 0xeaf6f78b , 0xcf8cf948 , 0x23f78f46 , 0xa0252e6c , 0xba04f7aa , 0x043cf97b ] )
+  , Mont::unsafe_make( [ 0x73dabd86 , 0x293334b8 , 0xc6205dd1 , 0x26aaafb6 , 0x9d801057 , 0x04d3a46b , 0x166509d4 , 0x2850d660 ] )
+  , Mont::unsafe_make( [ 0xf2e0e8e3 , 0x29eedf74 , 0x393634cb , 0x636952aa , 0x071c8452 , 0x0ad02cfb , 0xec59c968 , 0x274a33d0 ] )
+  , Mont::unsafe_make( [ 0x9bf5989d , 0x4d4c6914 , 0xea660f22 , 0x535c0a96 , 0x636ee78e , 0x992c87c0 , 0xa227d5ec , 0x05f26762 ] )
+  , Mont::unsafe_make( [ 0x49fcebcd , 0xcd156834 , 0x50562098 , 0x9059ff16 , 0x1c6c0321 , 0x5490c60e , 0x861e7a61 , 0x221b19ce ] )
+  , Mont::unsafe_make( [ 0x3166eabc , 0x03e94019 , 0xa5005324 , 0xbc4b2cf0 , 0x714d6bb9 , 0x90bd6e67 , 0xa1f88ec9 , 0x2c6cd9a4 ] )
+  , Mont::unsafe_make( [ 0xa9199448 , 0xa67e4ca6 , 0x763ce59d , 0x73b48de7 , 0xe4b634ce , 0x527ad569 , 0xf838b351 , 0x0d69e0a2 ] )
+  , Mont::unsafe_make( [ 0x1f060b07 , 0x6aadcfa8 , 0x45f211ba , 0xed6af238 , 0xb2b9765b , 0xb0890cde , 0x904ebd51 , 0x1fda9b91 ] )
+  , Mont::unsafe_make( [ 0x90159f02 , 0x26b20099 , 0xf870595b , 0x8fa59bc4 , 0x1634aa26 , 0xf7503b71 , 0x03f0e86e , 0x1b349804 ] )
+  , Mont::unsafe_make( [ 0x22680d76 , 0xa5103c47 , 0x6771118e , 0x6bcf26e7 , 0x03ff56ee , 0xb50a9955 , 0x1384cd70 , 0x00b74db5 ] )
+  , Mont::unsafe_make( [ 0xee4b4f55 , 0x9c08336f , 0xe000672c , 0xfd7f467b , 0xb6ff2e4c , 0x059c8d24 , 0xa4aff42f , 0x0c5ecd55 ] )
+  , Mont::unsafe_make( [ 0x76fcdadb , 0xcfd0b003 , 0xb1f8e3e4 , 0xa73f7a7f , 0x87e4d653 , 0xaa92a14a , 0x04ffd6ed , 0x0142112b ] )
+  , Mont::unsafe_make( [ 0xd7584b95 , 0x5715a8ee , 0x4cbbe80c , 0xc75ad7b9 , 0xb86be8a6 , 0x7fd0f301 , 0xadb3e9bb , 0x24a5922f ] )
+  , Mont::unsafe_make( [ 0x1c7fc2c4 , 0xe7e06fbe , 0xeed2f372 , 0x97959633 , 0x49d5c17b , 0x6e62c70f , 0x30263975 , 0x29e7e96f ] )
+  , Mont::unsafe_make( [ 0x6e7d91dc , 0xb64ff5f0 , 0x7d3beaac , 0xcf97ca22 , 0x1487bbd0 , 0xfba0f811 , 0x1dd09a24 , 0x063031be ] )
+  , Mont::unsafe_make( [ 0xf7511734 , 0x04437a44 , 0x02edd638 , 0x4a314ee8 , 0x59ee0dc1 , 0x18de658c , 0xef5fe3ba , 0x1f400903 ] )
+  , Mont::unsafe_make( [ 0x1cda007b , 0xab9a822b , 0x15f4e67d , 0xab270273 , 0xd3c3c009 , 0x18e2fd29 , 0x0a8d9f88 , 0x22855acf ] )
+  , Mont::unsafe_make( [ 0x2df99bc5 , 0x0c7262d5 , 0x4db58689 , 0x19a71189 , 0xd9fc653a , 0x3dcd3e6d , 0xdc52848b , 0x28c11b26 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x2f27e915 , 0x9c93329e , 0xf6e0eda1 , 0xc575264b , 0x52739786 , 0x259e074e , 0xe45f2352 , 0x057fc4c3 ] )
+  , Mont::unsafe_make( [ 0x787fbee3 , 0x99b914f8 , 0xd00c6ac5 , 0x2c5248e4 , 0x15ae06fa , 0x41cc069e , 0xa9e0e766 , 0x226a7447 ] )
+  , Mont::unsafe_make( [ 0x89999f22 , 0xc23d710e , 0x34b3dcad , 0x55b4b713 , 0x6c5f3ab6 , 0x4cf03c8c , 0x11a024c8 , 0x2e143d1f ] )
+  , Mont::unsafe_make( [ 0x1d495a8e , 0x07d54246 , 0x371e3723 , 0x566b6dbd , 0xca6cf184 , 0xbc15ea0d , 0x7e82ca9c , 0x1ef9b5fe ] )
+  , Mont::unsafe_make( [ 0xe7bb8cd4 , 0x5d6b7d1a , 0x7240558a , 0x595010ea , 0x2c4d5cb6 , 0xb24a7f98 , 0x33bd4b75 , 0x0174ee83 ] )
+  , Mont::unsafe_make( [ 0x12c6466e , 0x970da480 , 0x574f2b80 , 0x7feb8d8d , 0xddc0a11c , 0x5a44b08d , 0xd7b9ae31 , 0x2b3d9ba7 ] )
+  , Mont::unsafe_make( [ 0x100b0e4a , 0xfae3fb25 , 0x823d72c2 , 0x80d30bbc , 0x14449f06 , 0x806a19f8 , 0xb36639aa , 0x2eeccf5b ] )
+  , Mont::unsafe_make( [ 0xe3696d10 , 0xe676b4c7 , 0x18577b90 , 0x3d04c8af , 0x1c8e96d3 , 0xbd8b5dc3 , 0x7630a06c , 0x0f1560e2 ] )
+  , Mont::unsafe_make( [ 0x3ff14486 , 0xa88a4c3a , 0xab6a896d , 0xeb0704f2 , 0x991866c9 , 0x6f7343a8 , 0x8b03c0dc , 0x1839caf1 ] )
+  , Mont::unsafe_make( [ 0x35f15893 , 0x367fb73a , 0xaf7fbb17 , 0x7b6edd08 , 0x9115552f , 0x2ea79d21 , 0x940d8cca , 0x2466aac2 ] )
+  , Mont::unsafe_make( [ 0xfd892b82 , 0xce63b1c0 , 0x93e06906 , 0xe644003f , 0x2baf426f , 0x0d85b27e , 0x80e9a2a4 , 0x0e652b6b ] )
+  , Mont::unsafe_make( [ 0xe06d1658 , 0x306139ac , 0x4e6a75a9 , 0xb32bfaf1 , 0x3a5c0f1d , 0x4802791b , 0xdb0ffc6d , 0x2bb1722f ] )
+  , Mont::unsafe_make( [ 0x47e44e88 , 0x8a741828 , 0x37da25a7 , 0xe7dae462 , 0x1c7225f3 , 0x4ac4dffb , 0x8c07427f , 0x06bf5df6 ] )
+  , Mont::unsafe_make( [ 0x34de86b8 , 0x343c1b23 , 0xac5f4999 , 0x47b4f5ab , 0x503fa87f , 0x706a4871 , 0x357a856c , 0x0c78a9be ] )
+  , Mont::unsafe_make( [ 0xd24a3560 , 0xef3018e9 , 0x0c7fb33e , 0x23272da3 , 0x868da5c0 , 0xd4968607 , 0xaa7271be , 0x15924b6e ] )
+  , Mont::unsafe_make( [ 0x3c9f2a21 , 0x6131c53c , 0x51a8fffe , 0x4ae8a16a , 0x81ad18e4 , 0x6d7e86ae , 0xd82199a9 , 0x0f02af5c ] )
+  , Mont::unsafe_make( [ 0xb214643f , 0x9bace4f7 , 0x23ec6059 , 0xc4a2582d , 0xd1c0f181 , 0x4a1ebc7a , 0xbefa0083 , 0x0b3e80ef ] )
+  , Mont::unsafe_make( [ 0x23449adf , 0xaee74712 , 0xa6535e6b , 0xb13d8a69 , 0x575ed3e9 , 0x6bebe977 , 0xdccee733 , 0x0fec6e94 ] )
+  , Mont::unsafe_make( [ 0x548646c4 , 0xec3c69cb , 0x11615955 , 0x580bc156 , 0xd4240bf6 , 0x91ec9cb4 , 0xdf679b03 , 0x10241168 ] )
+  , Mont::unsafe_make( [ 0x9d983b78 , 0xa690f3b5 , 0xe8a48830 , 0x15e0b033 , 0x01674a75 , 0x849c1c04 , 0xfdbaf836 , 0x25e50d59 ] )
+  , Mont::unsafe_make( [ 0x596dcbca , 0x41785f88 , 0x95acf353 , 0x65a0d48d , 0xe220c07e , 0xbd016cf5 , 0x92ea2713 , 0x1104baad ] )
+  , Mont::unsafe_make( [ 0x1dc5086f , 0xcd195da2 , 0x55fa21b3 , 0xeb71cdac , 0xa969965b , 0xd6fc555b , 0x76a55121 , 0x2e03d448 ] )
+  , Mont::unsafe_make( [ 0x18008a11 , 0x314cc3db , 0x70db76ad , 0xb4f24d4d , 0x01ebd2dd , 0x5f9e08ed , 0xf113a6d7 , 0x05db806f ] )
+  , Mont::unsafe_make( [ 0xab0dfb83 , 0x06734944 , 0x006e1f74 , 0xd069d4ae , 0x3b56bf5f , 0xda2dc2db , 0xe581b901 , 0x16280f34 ] )
+  , Mont::unsafe_make( [ 0x671d984b , 0xb4a0c840 , 0xe59c7cde , 0x8eef85f6 , 0xaea66304 , 0x2b450b69 , 0x3eb63d3f , 0x0c11b628 ] )
+  , Mont::unsafe_make( [ 0x45aac25c , 0x272f6f12 , 0x0c824e9c , 0xfa2251f9 , 0xdbe75e49 , 0xa092025f , 0x9f804d6a , 0x06f1a6c3 ] )
+  , Mont::unsafe_make( [ 0x7649f7c0 , 0xeb516084 , 0xae36c8b8 , 0xbac7c814 , 0x78cbe7b8 , 0x2d960cb2 , 0xca07bdba , 0x159c559f ] )
+  , Mont::unsafe_make( [ 0xea3774d0 , 0x3ccc7b50 , 0x2de1914a , 0x816265ad , 0xfbce1d8d , 0x73602d21 , 0x37714f3d , 0x0f45f3a4 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xa05a6b7e , 0x367b5197 , 0x36002257 , 0xd92fd698 , 0xffa15885 , 0xbbba9b83 , 0xa9f5088b , 0x218b617d ] )
+  , Mont::unsafe_make( [ 0xc6dde314 , 0x2d52cdfb , 0xe5fe4a00 , 0x6ebdc9cd , 0xfa1134c8 , 0x19e28187 , 0xa8060a71 , 0x0b52db08 ] )
+  , Mont::unsafe_make( [ 0x74ce2edb , 0xf1a9b53b , 0xea838f95 , 0x1a3faf17 , 0xe972f054 , 0x16809754 , 0xd3bd8f7e , 0x13c61e48 ] )
+  , Mont::unsafe_make( [ 0xe992a510 , 0x6551a328 , 0xcc6377f1 , 0x21c17d97 , 0x68983f5c , 0x3a707948 , 0xec14ec05 , 0x26004284 ] )
+  , Mont::unsafe_make( [ 0x6f9039c1 , 0x35c8f756 , 0x85c8e1c0 , 0x825e20be , 0x5df12d75 , 0x679fb139 , 0x5934e12c , 0x1fa92dac ] )
+  , Mont::unsafe_make( [ 0xbba40b4e , 0x0fa1243c , 0xce2481f1 , 0x3bf5990c , 0xb845d6f2 , 0xa4b12e84 , 0xb4f3a52c , 0x2893af8c ] )
+  , Mont::unsafe_make( [ 0xbf557031 , 0x437cfeb1 , 0x64bbca1e , 0xa1d4002c , 0x91b033c8 , 0xe83301e1 , 0xa3b5e835 , 0x28c585c0 ] )
+  , Mont::unsafe_make( [ 0xad76f92c , 0xbb395b0d , 0x2083370b , 0xb89a2d91 , 0x34cbcc4a , 0x64ddbad3 , 0x60e1b8cd , 0x11217a72 ] )
+  , Mont::unsafe_make( [ 0xf82d9124 , 0xc4d1719e , 0x111ecd3b , 0xec275388 , 0xe1ab1bb0 , 0xe4281b00 , 0xb74d3edf , 0x13fc414e ] )
+  , Mont::unsafe_make( [ 0x07c6d5ff , 0x2427fb84 , 0x2877674d , 0x472faea5 , 0x0461dc08 , 0xd9a04212 , 0xc79df84b , 0x233b314b ] )
+  , Mont::unsafe_make( [ 0x17a08cfb , 0x3590499c , 0x71ddbd58 , 0x03907ea1 , 0xa6fea56d , 0x8df3cdaa , 0xa855c9e6 , 0x0e5a7fba ] )
+  , Mont::unsafe_make( [ 0xf6b7fd17 , 0xf01d76cd , 0xc9bbfc8b , 0xfd1e896a , 0x4ec71b43 , 0x28609101 , 0x082cdc93 , 0x224e0ae0 ] )
+  , Mont::unsafe_make( [ 0xf2d18ec2 , 0x1729ebc9 , 0xa677aca8 , 0xe51d9782 , 0xc6a12fc1 , 0x7f0ca9dc , 0x652fae85 , 0x099e0cca ] )
+  , Mont::unsafe_make( [ 0xe67ce3a8 , 0xa2dfcd45 , 0xd7aad76d , 0x9be7392f , 0x21edf9a2 , 0x1bad8b14 , 0x58df98a2 , 0x1081d653 ] )
+  , Mont::unsafe_make( [ 0xd74a8050 , 0x6df301e8 , 0x0f13a5cf , 0xee43f1ff , 0xff5d033f , 0x78779902 , 0xa6b849e1 , 0x25e3bc0a ] )
+  , Mont::unsafe_make( [ 0xc07c4cc4 , 0x6b1b1eee , 0x7bc840c0 , 0x6563ef1e , 0x9b1ffb61 , 0x05b72e8c , 0xa408d751 , 0x13592e90 ] )
+  , Mont::unsafe_make( [ 0x94c8e23d , 0xf1431c87 , 0x2ccbdc59 , 0xbeb9c311 , 0xe3106679 , 0xec89cd42 , 0x2f0b9208 , 0x217032c4 ] )
+  , Mont::unsafe_make( [ 0x388f52a9 , 0xcdc0f6a3 , 0xa194d657 , 0x4b377b2c , 0x851f9ebe , 0x297e10c0 , 0x82e5f88e , 0x24028f18 ] )
+  , Mont::unsafe_make( [ 0xbe43b758 , 0x4094f176 , 0x4b00dfe2 , 0x7da493a3 , 0x06706cde , 0xf51733a7 , 0x36ac5c56 , 0x01638ed3 ] )
+  , Mont::unsafe_make( [ 0xc3965bf5 , 0x493904e5 , 0x3aa6705c , 0x9c8b745d , 0x9563ae4a , 0xd5592150 , 0x80dae80f , 0x22bb70cf ] )
+  , Mont::unsafe_make( [ 0xfa06e832 , 0x3a2d3b32 , 0x1cda8aca , 0xf3e6639f , 0x3b02805e , 0xe4209eaa , 0x9a2b1d6f , 0x28b10fad ] )
+  , Mont::unsafe_make( [ 0x52659111 , 0x36d29f1a , 0x35d1ed10 , 0x418c94b3 , 0x295de46e , 0x45ff844a , 0xc3928c2a , 0x1924bb8a ] )
+  , Mont::unsafe_make( [ 0x69c98779 , 0x8f4d59ad , 0xc1747129 , 0xe2c4286a , 0xf7af3540 , 0xada2d0fb , 0xab01da75 , 0x0c3a8772 ] )
+  , Mont::unsafe_make( [ 0xb4b7c7ba , 0x20599105 , 0xe34d259a , 0x0669ab64 , 0x7183e9ea , 0xcbbae8f8 , 0x476f67f8 , 0x051761be ] )
+  , Mont::unsafe_make( [ 0x99045cec , 0x3a8b188c , 0x67793d08 , 0x33ba4a43 , 0xd2bbc988 , 0x6eedf670 , 0x385b9a97 , 0x2194737e ] )
+  , Mont::unsafe_make( [ 0x4cc5bc3a , 0xb26f0daf , 0xd373767e , 0xfb1ef5cb , 0x2bef0d8c , 0xbbd537b6 , 0x499bf8fd , 0x1e0cdacf ] )
+  , Mont::unsafe_make( [ 0xb548b1b9 , 0x4c06b69c , 0xcff0f171 , 0xc9ea8ab6 , 0xdfa37eee , 0xd781fed9 , 0xf65610d1 , 0x06b391fa ] )
+  , Mont::unsafe_make( [ 0x1ff0b319 , 0xb66f7e7f , 0x548cbcfb , 0xf3ca5180 , 0xb4ec305c , 0x2a9155e8 , 0xa88a20fe , 0x0638b020 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x7af3fcdf , 0x55961af8 , 0xf7b1e005 , 0x17e3bfd6 , 0x76f3db78 , 0xc8f9b518 , 0x8f9b8356 , 0x2feff53d ] )
+  , Mont::unsafe_make( [ 0xe9e87931 , 0xd722bf93 , 0x1ae57fc0 , 0xdcb16ada , 0xccfe323e , 0x60dabba1 , 0x22c31873 , 0x155b691a ] )
+  , Mont::unsafe_make( [ 0x3a0a1547 , 0xad870f92 , 0xc6c6d183 , 0xbcc158c8 , 0x517a24ba , 0x277a0983 , 0xf2521834 , 0x018909db ] )
+  , Mont::unsafe_make( [ 0xde42640e , 0x455e2279 , 0x9342a452 , 0xe9b28811 , 0x696bd8bc , 0x7f89cb3e , 0xdcc1f50b , 0x12fd79e0 ] )
+  , Mont::unsafe_make( [ 0x2b630f24 , 0xf3cf632e , 0xe4bbd643 , 0xee3a4593 , 0xc4d2a2cb , 0xddf29758 , 0x169890d5 , 0x1b86833e ] )
+  , Mont::unsafe_make( [ 0xfa1ca1f5 , 0xd083b785 , 0x8591ba9d , 0x7968266f , 0xefb019b2 , 0x152bfd23 , 0xa6faaca6 , 0x21a55ac6 ] )
+  , Mont::unsafe_make( [ 0x0f00e75d , 0xfa961d88 , 0x06b93f33 , 0x3dfe57f3 , 0xbb7250ad , 0xcf3f9833 , 0xcf1e5ceb , 0x0d5699d4 ] )
+  , Mont::unsafe_make( [ 0x666c5af4 , 0x9713196b , 0x5a9fd19a , 0xb8bef924 , 0xf1943050 , 0x39a25275 , 0xec548941 , 0x140e525c ] )
+  , Mont::unsafe_make( [ 0xdb1077df , 0x8af89466 , 0x837a28ae , 0x4e51e789 , 0x3255306d , 0x70d3dfe2 , 0xdfba75c6 , 0x2c292460 ] )
+  , Mont::unsafe_make( [ 0xaae06f7f , 0x7e8e4d6a , 0xe6abcdc1 , 0x01d75c56 , 0xd3eaa729 , 0x84aae4c3 , 0x21740df5 , 0x16bacfdc ] )
+  , Mont::unsafe_make( [ 0xb63fdb58 , 0x81359a2a , 0xf0a3793d , 0xdfd6f3aa , 0xad178736 , 0xf8a2aaa5 , 0xb1478b30 , 0x2a7d5c13 ] )
+  , Mont::unsafe_make( [ 0xfce15696 , 0xa66aac65 , 0x23d1d6a7 , 0xf8d10947 , 0x086d520a , 0x5308defb , 0x0f9f134a , 0x14dff5d3 ] )
+  , Mont::unsafe_make( [ 0x5a886100 , 0x92b89690 , 0x97b3ccba , 0x0f2ada57 , 0xf5e6cf1d , 0x4758f71b , 0x4f2a516d , 0x0176d2ce ] )
+  , Mont::unsafe_make( [ 0xc0856eca , 0x258186aa , 0x3e8451ff , 0x952f272e , 0x8ace5517 , 0xabf5fd46 , 0x35122456 , 0x2c0de836 ] )
+  , Mont::unsafe_make( [ 0x98a9c43a , 0xef9a1447 , 0xdb32f2cd , 0x19826441 , 0x04055501 , 0x33654b0f , 0xe4ce4dea , 0x2c337366 ] )
+  , Mont::unsafe_make( [ 0xcc8cd1f0 , 0x17327062 , 0x2ddbcb6e , 0xb6dcd9e4 , 0x45d42c8f , 0x5775d4cc , 0xce928484 , 0x28507134 ] )
+  , Mont::unsafe_make( [ 0xb40f058b , 0x247161eb , 0x1f108118 , 0x71b5bc97 , 0xac8c6440 , 0x9732b226 , 0x0cb92c3f , 0x0f95ee3b ] )
+  , Mont::unsafe_make( [ 0xe5679d4e , 0xa9b57fdb , 0x9e467171 , 0xedce6508 , 0x9864b2d8 , 0x6cb7feb7 , 0x71015060 , 0x2061198a ] )
+  , Mont::unsafe_make( [ 0x9a9fb396 , 0xa250b870 , 0x93fa6619 , 0xaba6e694 , 0x1b4de093 , 0x93e22561 , 0x8a7cdec2 , 0x005576d1 ] )
+  , Mont::unsafe_make( [ 0x0f542b55 , 0xc7fb97e4 , 0x08171744 , 0x412b13ee , 0x3bde16fd , 0x61df80a3 , 0x3cdf97ef , 0x2eedec7c ] )
+  , Mont::unsafe_make( [ 0x308982f8 , 0xbe1a374e , 0x9f10b1cb , 0x96b10f05 , 0x6a99629f , 0x99b0293c , 0xa05a276d , 0x1ebca986 ] )
+  , Mont::unsafe_make( [ 0x1601fad4 , 0xebf447d6 , 0xce15a66e , 0x6b0d3e83 , 0x327f4a62 , 0xa1b0087f , 0xcc3e94db , 0x116371d2 ] )
+  , Mont::unsafe_make( [ 0xc9a2b01c , 0x057bc195 , 0x77b94b23 , 0xec1ae477 , 0xefb057b4 , 0x12ffb356 , 0x02e64f11 , 0x256a3e0b ] )
+  , Mont::unsafe_make( [ 0x628fd72d , 0xee1dcb3c , 0xb2d25469 , 0xe29a5005 , 0x94af0542 , 0x87077ddd , 0xd40848c8 , 0x09e0ab24 ] )
+  , Mont::unsafe_make( [ 0x1be2fea1 , 0xa9a03cf4 , 0x23378e2e , 0x85cb28c9 , 0x5b7537d7 , 0x35f240dd , 0x4a5e5d0e , 0x0f1ecf82 ] )
+  , Mont::unsafe_make( [ 0xcbc59667 , 0x4041fcd9 , 0xb59e9f23 , 0xdc507137 , 0xdf2881fb , 0x6f8e8f55 , 0x5c07eb7c , 0x1e24e23e ] )
+  , Mont::unsafe_make( [ 0xe8f703a4 , 0x682cf9e3 , 0x557cb50d , 0xae6c6684 , 0x11c84cdc , 0xfafce223 , 0x57af344b , 0x2caa108a ] )
+  , Mont::unsafe_make( [ 0x98450e16 , 0xbbddf00e , 0xcc2107c2 , 0xbc67aa91 , 0x5d72423b , 0xaeabb358 , 0xf663f035 , 0x204bc45c ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x6b06bc31 , 0x8e951c84 , 0xfb4bf411 , 0xacbd8353 , 0xc604e928 , 0xda1b6feb , 0x344ebdf4 , 0x05e25a21 ] )
+  , Mont::unsafe_make( [ 0x2cb84897 , 0xcca3d45c , 0xabc3da1d , 0x5f19e217 , 0x6aea9a98 , 0xf1d77667 , 0xe89d4dff , 0x05d4eed9 ] )
+  , Mont::unsafe_make( [ 0xfe41a840 , 0x5d678b19 , 0x51d45109 , 0x03ff98d1 , 0x67868801 , 0x33958963 , 0xe916f957 , 0x24557288 ] )
+  , Mont::unsafe_make( [ 0x56139fcd , 0xb9b67490 , 0xae0e7935 , 0xf32a3a0e , 0x0c121477 , 0xf7dc1490 , 0xf111fb75 , 0x28149f61 ] )
+  , Mont::unsafe_make( [ 0x220c2789 , 0xbf351d44 , 0x3e5efa13 , 0xf08b223f , 0x425c40e6 , 0xafd65a5f , 0x8c6470c5 , 0x2d923f7a ] )
+  , Mont::unsafe_make( [ 0x9f23c17b , 0x6e9c2f60 , 0x63388942 , 0x5c50f68f , 0x77dca54a , 0x5778beeb , 0x1b62dbda , 0x1987569b ] )
+  , Mont::unsafe_make( [ 0x012e6ab9 , 0x672a0c05 , 0x59ee2d81 , 0x7e41acfd , 0xbe311e56 , 0x78e3492a , 0x6e3a2bf1 , 0x25af09d0 ] )
+  , Mont::unsafe_make( [ 0xac7caf3b , 0x910186c4 , 0x30993995 , 0x56c1e815 , 0xcd7b026d , 0x1d625b5c , 0xa3f3c960 , 0x19f69536 ] )
+  , Mont::unsafe_make( [ 0x2147bce3 , 0xd781b4d4 , 0x1b2707a0 , 0xfba3223f , 0x65bfe5b2 , 0xdc11f7d8 , 0xc7701a96 , 0x0fb94e4b ] )
+  , Mont::unsafe_make( [ 0x20ce962b , 0x64829ccc , 0xdaa63128 , 0xf2bc2c58 , 0x95bdeea9 , 0xa56f1156 , 0x2d3f0481 , 0x0b651926 ] )
+  , Mont::unsafe_make( [ 0x2935f2d2 , 0xc8e9d341 , 0x96173d94 , 0x414ac52c , 0xf8617898 , 0xce76057f , 0x28277282 , 0x0bca482c ] )
+  , Mont::unsafe_make( [ 0x3a2c1c6b , 0x328baa7d , 0x2f5df887 , 0xe0a00c38 , 0xae15464a , 0x0078a765 , 0x7e79c007 , 0x024b3671 ] )
+  , Mont::unsafe_make( [ 0x7300be2d , 0xafc7b5dc , 0x03700723 , 0xb41766e7 , 0xe9c1360b , 0x16794c3e , 0xb6033dcf , 0x14d51d8c ] )
+  , Mont::unsafe_make( [ 0x629d63de , 0x3d0f9515 , 0x4ee294bc , 0x540fd674 , 0x3e0ff131 , 0x855ae8b1 , 0xb62c4969 , 0x0a21e90e ] )
+  , Mont::unsafe_make( [ 0xf3968ef3 , 0x635694b6 , 0xedde4bef , 0x625a1146 , 0xc526738b , 0xa64d1d13 , 0x2e14a49d , 0x0b0c666c ] )
+  , Mont::unsafe_make( [ 0x20a1a50a , 0xf016a53c , 0x151d08b6 , 0xd5217bfd , 0xc1203dd9 , 0x31c247fe , 0x2322f282 , 0x1b8a5d8e ] )
+  , Mont::unsafe_make( [ 0x263c688e , 0x99f84f59 , 0x3f649393 , 0x8c414272 , 0x6bef7d59 , 0xdbaffe56 , 0xb8c1fcd3 , 0x2b824bff ] )
+  , Mont::unsafe_make( [ 0x60083cb1 , 0xc97b4767 , 0xc47bc9dc , 0xc57c4b5d , 0xdb2f1fbc , 0x84b16f7c , 0x434db0fe , 0x192ade55 ] )
+  , Mont::unsafe_make( [ 0xb18d779e , 0x9dea4d67 , 0xda469f87 , 0x0203a3bc , 0x42414a6e , 0x45aec15b , 0x4b5bb3ab , 0x0ae4b0b4 ] )
+  , Mont::unsafe_make( [ 0x06833670 , 0x0ffe8e85 , 0x7936f465 , 0x7ff483a1 , 0xa2191968 , 0xe678cb3f , 0xb489ad4c , 0x10e4be0f ] )
+  , Mont::unsafe_make( [ 0x07427f82 , 0x49172c1a , 0x7ac21510 , 0x6baece31 , 0xfeb83c1d , 0x419b77c7 , 0x1c47073d , 0x2e9f2305 ] )
+  , Mont::unsafe_make( [ 0x8f67321d , 0xa6d10561 , 0xd0fa8aab , 0x6bd478a6 , 0xf0d1ae9a , 0x69bee538 , 0xba676e80 , 0x003c5abd ] )
+  , Mont::unsafe_make( [ 0x16014bb5 , 0x8530f89b , 0x3f67aa2c , 0xf45c5f5a , 0xc0d76e9d , 0xc2960714 , 0xa15681d8 , 0x09f02804 ] )
+  , Mont::unsafe_make( [ 0x9784e835 , 0x5dcd0ccf , 0x11b9cfaf , 0xc102f768 , 0x71033485 , 0xf8e649ff , 0xd84b7602 , 0x0889869d ] )
+  , Mont::unsafe_make( [ 0x786141b1 , 0x96bf4e6f , 0xb0c83673 , 0x64c5689c , 0xa929b75d , 0xbf44e76f , 0x67593353 , 0x2a439689 ] )
+  , Mont::unsafe_make( [ 0xa1ad78a6 , 0xed9b9613 , 0x6846f27b , 0x2d030489 , 0x53331686 , 0x37993a34 , 0x0a055242 , 0x2fa3fb0f ] )
+  , Mont::unsafe_make( [ 0xa3c5914e , 0x8993e5e8 , 0x400f6fdb , 0x111509f5 , 0x2ea4f967 , 0xb37f04b4 , 0x0959aaa4 , 0x1c6ff248 ] )
+  , Mont::unsafe_make( [ 0x3ce3180e , 0x6d72abb4 , 0xee2368be , 0xabeb05f9 , 0x2ed8d31b , 0x5a062043 , 0x9157f322 , 0x18c0cc51 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x1f2a55d5 , 0x286a06b5 , 0xce58e13a , 0x3c45e11a , 0x3d82e448 , 0xd0dc9a9e , 0x3da998a2 , 0x1fec89c9 ] )
+  , Mont::unsafe_make( [ 0x754c0e04 , 0x82e25f83 , 0x94c34dcc , 0x6aae7712 , 0xef464a29 , 0x573b15d0 , 0x73880305 , 0x24c26090 ] )
+  , Mont::unsafe_make( [ 0x6de6f251 , 0xe4f6980f , 0x4499450c , 0x19ee630c , 0xd1bdfdc4 , 0x70c1a8a3 , 0x43c00fb0 , 0x12a0f61a ] )
+  , Mont::unsafe_make( [ 0x7308f0ca , 0xd734467a , 0x92fb5a6c , 0xec9d38df , 0x6ddb2b50 , 0xac618ba9 , 0x5d359a1e , 0x1d0f351d ] )
+  , Mont::unsafe_make( [ 0xf2738b82 , 0x422e9a09 , 0x949fe63a , 0xf2e63785 , 0x8d034ab8 , 0x9c9eb613 , 0x37788823 , 0x0fb4c735 ] )
+  , Mont::unsafe_make( [ 0x20ad3481 , 0xc214b9f3 , 0x25ec2225 , 0xe62ed201 , 0x0567560b , 0xde677257 , 0xdeeb3ff3 , 0x0e04749f ] )
+  , Mont::unsafe_make( [ 0xf2a0f063 , 0x89125574 , 0x7432f1c6 , 0xe59e5a0f , 0x2c90cdc5 , 0x6ffe4b72 , 0x2c00ec46 , 0x2c7c142f ] )
+  , Mont::unsafe_make( [ 0x1b7fe5b6 , 0x4b6734bb , 0x17b52e2b , 0xcdfdc221 , 0xd8a14cbe , 0x2dbb64e1 , 0x730a2971 , 0x1bf77b92 ] )
+  , Mont::unsafe_make( [ 0x99c51973 , 0xa06c5a9c , 0x8c732675 , 0xda58c0c9 , 0x350236e0 , 0x73b10297 , 0xcd229c5c , 0x1c48f940 ] )
+  , Mont::unsafe_make( [ 0x1eb2db81 , 0xb270e4e4 , 0xc27c266e , 0x98b38f83 , 0x0853bf17 , 0x0f23abc5 , 0xe82a6ad9 , 0x1cfbde04 ] )
+  , Mont::unsafe_make( [ 0xb44a2401 , 0x71a9c190 , 0x1dc4c658 , 0x66bf0262 , 0x4a0b8169 , 0x11dad22d , 0x45a86485 , 0x07bb5ea3 ] )
+  , Mont::unsafe_make( [ 0xb0fb7f9f , 0xff2a8b9c , 0xb7e03e7a , 0xff024aeb , 0x0d17885b , 0x52f79899 , 0xc5c568eb , 0x252e8031 ] )
+  , Mont::unsafe_make( [ 0x64e4ed48 , 0xedaa1311 , 0xb892b0ed , 0xfbad97b1 , 0x182b4f10 , 0x478d1176 , 0xe4636ec7 , 0x0b77ecf7 ] )
+  , Mont::unsafe_make( [ 0x49ad984c , 0x423db72e , 0xfd896055 , 0xa933381d , 0xdd81e206 , 0xa8151948 , 0xc7a935e9 , 0x08629e1f ] )
+  , Mont::unsafe_make( [ 0x5c2204c1 , 0xb54152cf , 0xf58785d4 , 0xce384920 , 0x55d3f4a0 , 0x2184ad82 , 0x1c92b4e5 , 0x04ac86bc ] )
+  , Mont::unsafe_make( [ 0x13a6ad17 , 0xfa113066 , 0x36eca4ba , 0x56ffdd70 , 0x4d2bdf49 , 0xbdbdb8e1 , 0xeef0a811 , 0x0fd834e5 ] )
+  , Mont::unsafe_make( [ 0x9eff1ad2 , 0x63d33c4d , 0xbdaf8c24 , 0x3930d265 , 0x59d14e14 , 0x43fc0765 , 0xd7c73bb1 , 0x262f09bf ] )
+  , Mont::unsafe_make( [ 0x33e3f9d6 , 0x710675aa , 0x42f38e32 , 0x714b74ea , 0x7e02be8d , 0xe15797a5 , 0xc5cab7b0 , 0x051cfa0c ] )
+  , Mont::unsafe_make( [ 0x3434b27c , 0x755d9a07 , 0xb34a1c61 , 0x9329ba1b , 0xdbb77637 , 0xc284ff4d , 0x7dc823e2 , 0x0497d152 ] )
+  , Mont::unsafe_make( [ 0x7ee59dec , 0x7369c1f9 , 0x541e396f , 0x70a981da , 0x48cb7ac1 , 0x0cf7a5bb , 0xf843452f , 0x1ab879c2 ] )
+  , Mont::unsafe_make( [ 0x222baba1 , 0x8a35d2f5 , 0xb424ac5d , 0xa19fdb6c , 0x116dd10a , 0x5e6a8dc4 , 0xf3b48a07 , 0x03cdd369 ] )
+  , Mont::unsafe_make( [ 0x2fbab43d , 0xbab88150 , 0x70d75c31 , 0x63356a29 , 0x45852b9d , 0x22ef90d9 , 0x27189c82 , 0x15193ab8 ] )
+  , Mont::unsafe_make( [ 0x57dcfcf2 , 0x3c260a21 , 0x51068ab5 , 0x5d342ae4 , 0xdb057137 , 0xc57a96b0 , 0xda7c66da , 0x10025f18 ] )
+  , Mont::unsafe_make( [ 0x2f4910c0 , 0xb40fce01 , 0xbd446311 , 0x1ab39a93 , 0xfa5895dc , 0x85781dc3 , 0xf11fb811 , 0x0eee1675 ] )
+  , Mont::unsafe_make( [ 0xfb4f969b , 0x223930fb , 0x4e2d85dd , 0x38bf6c8c , 0x4615581e , 0xbb69f31f , 0xcfc9e537 , 0x2f4fecb4 ] )
+  , Mont::unsafe_make( [ 0x6f95be40 , 0x5d3e1f54 , 0x611004c9 , 0x8af383f3 , 0x1f79a57d , 0xd9f883cd , 0x7a32062c , 0x2c3a5c72 ] )
+  , Mont::unsafe_make( [ 0x55cd8d82 , 0xf716bdc2 , 0xec4a7461 , 0x262e400d , 0x5be285e0 , 0xa2a63a96 , 0x0bd423c9 , 0x1ff05ac5 ] )
+  , Mont::unsafe_make( [ 0xa8f348b1 , 0x6f56aab3 , 0xab4ed300 , 0x3ac2a808 , 0x6101d737 , 0x325f5b93 , 0xf601b0de , 0x28f1928b ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x3da2737c , 0xe79812ea , 0x28387668 , 0x91f7b9dc , 0x27587448 , 0xb67f5ffb , 0x36129630 , 0x0cf9e30d ] )
+  , Mont::unsafe_make( [ 0x48d5304a , 0x174dc1e7 , 0x0657f3d9 , 0xd8620ae6 , 0x6b0e96b7 , 0x8f99db60 , 0xbec22f7c , 0x032562b7 ] )
+  , Mont::unsafe_make( [ 0xa0773fab , 0x0439db6f , 0x3de8395a , 0x11899bf4 , 0x154e7419 , 0x49f8adfe , 0x713ab811 , 0x2c7cc14a ] )
+  , Mont::unsafe_make( [ 0x57cbba31 , 0x13d46717 , 0x276d6120 , 0xecc1d2c5 , 0xf78cb35c , 0x94e20a33 , 0x6bae4d3a , 0x24ed090f ] )
+  , Mont::unsafe_make( [ 0xebbb0324 , 0xda80dd9f , 0x9f78c7de , 0xf0d9774c , 0x08080b65 , 0xbbf858ed , 0x7075ed9a , 0x1254c852 ] )
+  , Mont::unsafe_make( [ 0xc4ea35a8 , 0xd3d50825 , 0x688eadcf , 0xe56c333a , 0xf912612d , 0x1231ba0f , 0xcaf86dc4 , 0x26f52fee ] )
+  , Mont::unsafe_make( [ 0x5e60f580 , 0x5e751131 , 0x87d96bf4 , 0x4f5603ce , 0x70d8e856 , 0xa1faabac , 0xa0729f5e , 0x286b8f01 ] )
+  , Mont::unsafe_make( [ 0xf464ae3a , 0xb57f3a74 , 0xb4af279b , 0x071436b3 , 0x2dbd1cb9 , 0x8053b805 , 0xc982c7fc , 0x18493f8c ] )
+  , Mont::unsafe_make( [ 0x06e11f75 , 0x42aba626 , 0x381c4eeb , 0xd494182b , 0xdd0450f4 , 0x0717a18d , 0x4007210e , 0x2f869f37 ] )
+  , Mont::unsafe_make( [ 0x4b3125e1 , 0xa4d0e123 , 0xd18e4f6b , 0x056c3720 , 0x3c63b0e6 , 0x7158e6a5 , 0xd2ee16eb , 0x02ec7493 ] )
+  , Mont::unsafe_make( [ 0xb6f8fc59 , 0xc2b9065f , 0xd33b8bbc , 0x484e00d1 , 0x35eef314 , 0x62224eb6 , 0xb0dc0032 , 0x121b20b9 ] )
+  , Mont::unsafe_make( [ 0x465d3585 , 0xf95497cc , 0x7241c90c , 0xccf728a9 , 0xd6882b19 , 0x8f6c0151 , 0x3125a712 , 0x0e77fae5 ] )
+  , Mont::unsafe_make( [ 0x0a19c9ec , 0x3a440eac , 0xe07d35f6 , 0x9847a746 , 0x83691b05 , 0x82fe3fb3 , 0x832b9365 , 0x178832b8 ] )
+  , Mont::unsafe_make( [ 0xce746a01 , 0xb0f0c511 , 0xb5be5d80 , 0x6187070f , 0x518b3a5a , 0xbebe2338 , 0xc2e5181d , 0x088569c9 ] )
+  , Mont::unsafe_make( [ 0x0c2d7407 , 0xcb49a488 , 0x5eb6218b , 0x977d54cd , 0x9c76bd19 , 0x679ff409 , 0xbe384ccb , 0x1db2d61b ] )
+  , Mont::unsafe_make( [ 0xb176647d , 0x468fc765 , 0xac11bd7a , 0x870ab901 , 0x7d1d7152 , 0x267327f1 , 0x5ca185f3 , 0x106b68b2 ] )
+  , Mont::unsafe_make( [ 0x8ed01203 , 0xc40929da , 0xa391b20c , 0xd4672db1 , 0x87b620b9 , 0xa1d87274 , 0x0d15b973 , 0x0107652f ] )
+  , Mont::unsafe_make( [ 0x343dfc60 , 0x108eb6c3 , 0x87b13889 , 0x0664ee25 , 0x6dee7a87 , 0x87e15a88 , 0x15300173 , 0x16a32169 ] )
+  , Mont::unsafe_make( [ 0x65fe79a0 , 0x570c2ecc , 0xe382aaa1 , 0xab36ffe6 , 0x788e6288 , 0xbcfc6ecc , 0x93d1c93b , 0x0c647f93 ] )
+  , Mont::unsafe_make( [ 0x76bf2e9a , 0x61778367 , 0xcb7d757a , 0xd44c3b8c , 0xef2220e8 , 0x7f3d2f27 , 0xc04ef5ea , 0x147ce5a5 ] )
+  , Mont::unsafe_make( [ 0xaeaa1727 , 0xe6b41b1d , 0x2d091ded , 0x9aca6725 , 0x269ab158 , 0xe179a905 , 0xfb23813b , 0x0b9e809d ] )
+  , Mont::unsafe_make( [ 0x26ab8204 , 0xf4627a88 , 0x3dd8d919 , 0x9640e329 , 0x7e734567 , 0xe436a812 , 0x93ce2a4c , 0x08c494be ] )
+  , Mont::unsafe_make( [ 0xd809aa96 , 0x2992144d , 0xc2f38da1 , 0x2ba66688 , 0x8822c21d , 0x905fc3ea , 0x74d6f554 , 0x2dc255f7 ] )
+  , Mont::unsafe_make( [ 0xe5cb35bb , 0x7a65ae25 , 0xeffa45c3 , 0xe5c3fa4f , 0xa9172673 , 0xf8bb5318 , 0xf6e7df49 , 0x15fa13b0 ] )
+  , Mont::unsafe_make( [ 0x79787011 , 0x7ad9a8a6 , 0x087612f3 , 0xa7afaf3d , 0xe601be71 , 0x91733e81 , 0x0b4cbf74 , 0x14d3c646 ] )
+  , Mont::unsafe_make( [ 0xaef33570 , 0x6bb73ef6 , 0x73781222 , 0x30441d43 , 0xf1d9b934 , 0xfc660427 , 0x8fe9b9f2 , 0x026874ed ] )
+  , Mont::unsafe_make( [ 0xf99f22bf , 0xffe86f1d , 0x37cc81dd , 0xa80c1844 , 0x8ff33550 , 0x5bccc249 , 0x67253b2a , 0x045e1862 ] )
+  , Mont::unsafe_make( [ 0x539425d6 , 0x697288d4 , 0x1f271f1c , 0xb7af7883 , 0xe57f6158 , 0xea2cf1ab , 0x1d8a9a3a , 0x2c16ee0c ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x2b98379a , 0x34e14382 , 0xa855ec29 , 0x64ead23d , 0x3b2b1e13 , 0x9c14ac0f , 0x0f306e5a , 0x0f4622ad ] )
+  , Mont::unsafe_make( [ 0x3e49c3e3 , 0x42128ac8 , 0xb0f872fc , 0xe354c0cd , 0x5092a4cc , 0x616146d5 , 0xbce35ab0 , 0x0d967025 ] )
+  , Mont::unsafe_make( [ 0xadd8a11c , 0xf780f7fa , 0xfefaf11d , 0xa7ed90f1 , 0x045ce3b4 , 0x579c1aff , 0x2ab3abb2 , 0x0fc10e9c ] )
+  , Mont::unsafe_make( [ 0x2d79a453 , 0x0cd730e7 , 0xefb951a7 , 0x5ef331a6 , 0x3612052d , 0x453ac01e , 0xb2ed5bff , 0x005b53fd ] )
+  , Mont::unsafe_make( [ 0xbf26bf3b , 0x132c2adc , 0xc53a6a1b , 0x69bb80db , 0x11b11f3b , 0x46752fd7 , 0x62a1fae6 , 0x08e312d1 ] )
+  , Mont::unsafe_make( [ 0xe0608c2c , 0x25fcb7ce , 0xf6aca79a , 0xac03f42d , 0x10afd327 , 0x525e88a5 , 0x6a58c140 , 0x2ea6c6f6 ] )
+  , Mont::unsafe_make( [ 0x559da4a5 , 0x7447c031 , 0x68ac044c , 0x55fcf55d , 0x7d65615c , 0xc0f3843a , 0x1e39dd3c , 0x1e247b67 ] )
+  , Mont::unsafe_make( [ 0x9ff70e32 , 0xda879870 , 0x99759d7c , 0x9bc9c098 , 0xff14110a , 0x832cd934 , 0x2ff3da2e , 0x2a5f0f14 ] )
+  , Mont::unsafe_make( [ 0xdeb9613e , 0xf6785d8f , 0x21e65eea , 0xb46292e2 , 0x714d5b41 , 0x268be4a4 , 0xb13edfba , 0x06e178f7 ] )
+  , Mont::unsafe_make( [ 0x271991a9 , 0x6412e1f0 , 0x48f489d2 , 0xf3dcc92b , 0xd07295da , 0x3b4408b2 , 0x614971b6 , 0x0858a130 ] )
+  , Mont::unsafe_make( [ 0xce9902fa , 0x147c366e , 0x42691dff , 0xc90139c6 , 0xd617fda9 , 0x477cff88 , 0x8e7e473f , 0x3041463d ] )
+  , Mont::unsafe_make( [ 0x28740735 , 0x8daf8d10 , 0x43546518 , 0x051f022d , 0xfe5cd3c9 , 0x3e9e269c , 0x6dc0c5e7 , 0x15bf6a31 ] )
+  , Mont::unsafe_make( [ 0x34bc0675 , 0xb07bdcdb , 0x286cd4da , 0x5d80a67d , 0x409be662 , 0x91408295 , 0x7138c88e , 0x25e89047 ] )
+  , Mont::unsafe_make( [ 0xa6782fed , 0xb411e6d7 , 0x2b51f0eb , 0xef2a4e47 , 0x488c0e72 , 0x79b254b1 , 0x07f7c32a , 0x2d028b5f ] )
+  , Mont::unsafe_make( [ 0xb0857f9b , 0x1f59ce4c , 0xb7d841e7 , 0xbe76e68d , 0xdd38ad94 , 0x40fc9e28 , 0x1ce77975 , 0x1a86a362 ] )
+  , Mont::unsafe_make( [ 0x5172ec1f , 0x3a7a4237 , 0x829bdd20 , 0x829c9e6f , 0x5312c63f , 0x98f76e8b , 0xf9701b30 , 0x12aed4d1 ] )
+  , Mont::unsafe_make( [ 0x42a1fdc9 , 0x4677fa64 , 0x358cbe33 , 0xc9b84efa , 0xe2216572 , 0xae124b45 , 0xb599104a , 0x16058925 ] )
+  , Mont::unsafe_make( [ 0x97ab33b0 , 0x1d544f1d , 0x97d6b58a , 0x295d9fec , 0x3631c7f3 , 0xa7637855 , 0xc442bd3b , 0x084ac7e3 ] )
+  , Mont::unsafe_make( [ 0xe9bfe7c9 , 0xf519514f , 0xe5e24c91 , 0x1e44a893 , 0x92462ca4 , 0xf9837ea8 , 0xf0a0fe09 , 0x1cb51fb4 ] )
+  , Mont::unsafe_make( [ 0x9e5df20f , 0xd3d13466 , 0xab4727f3 , 0x99153824 , 0x225f1e03 , 0x0d79838d , 0x86114c0f , 0x2488c947 ] )
+  , Mont::unsafe_make( [ 0xb412855c , 0xb6978595 , 0x2eebfc4b , 0xd776e8ba , 0x26903360 , 0xcd1ac617 , 0x1fb71077 , 0x08fad0b2 ] )
+  , Mont::unsafe_make( [ 0x3d304ea8 , 0xe359eecd , 0x9942ffe0 , 0xb1c7b689 , 0x36f34379 , 0x09d2bcd1 , 0x313124d6 , 0x19084abc ] )
+  , Mont::unsafe_make( [ 0xc4a19e67 , 0x9c1ec872 , 0x1d6212c6 , 0xbd883108 , 0xe7968da0 , 0x97e5cd74 , 0xb7e54f12 , 0x22ba9ae9 ] )
+  , Mont::unsafe_make( [ 0x60eb3de7 , 0x1718f67d , 0x913c2eb2 , 0x98882fac , 0x93f1f111 , 0x6b1bac62 , 0x52213b3b , 0x009d602c ] )
+  , Mont::unsafe_make( [ 0xbc7f9deb , 0xeea13ba1 , 0xa01684f7 , 0x35425f07 , 0x74e732ae , 0x3eddacdf , 0x38d8181e , 0x06e48ee4 ] )
+  , Mont::unsafe_make( [ 0xe5aa55d5 , 0x04e66088 , 0x34d2c3cb , 0xdbfc59aa , 0xe8243324 , 0x6778f0dd , 0x23a92356 , 0x0668c5d4 ] )
+  , Mont::unsafe_make( [ 0x98136d82 , 0x996cc060 , 0xbd9d8a9e , 0xba3d329b , 0x41c11273 , 0xe4bb894a , 0x033f5a64 , 0x26fa4d67 ] )
+  , Mont::unsafe_make( [ 0x0ed1cdfa , 0x3d6878da , 0x35b72902 , 0x9a18c19f , 0x1bbc6acc , 0x019391d0 , 0xfb4aa648 , 0x088ea7ea ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xbd8ac1cb , 0xd42163ba , 0x0523540f , 0x0be94ff2 , 0x27b9b971 , 0x26815594 , 0x622ac7c2 , 0x120761ba ] )
+  , Mont::unsafe_make( [ 0xc18daa52 , 0x6b973dd2 , 0x888b861f , 0xa4befd81 , 0x79b2dd86 , 0x413c5e41 , 0x686429f7 , 0x150959ef ] )
+  , Mont::unsafe_make( [ 0xc77caa03 , 0x552c1820 , 0x859d4d44 , 0x4134f933 , 0x7802a052 , 0x12be9303 , 0xf7c97051 , 0x02aff631 ] )
+  , Mont::unsafe_make( [ 0x02cbc889 , 0x8fd41f08 , 0xee69f2c9 , 0xb53c2ac0 , 0xfae55d16 , 0x2108266b , 0xb9173a46 , 0x0ea2a24a ] )
+  , Mont::unsafe_make( [ 0x01d6e236 , 0xc0b5bdf7 , 0x38296d5d , 0x2c912185 , 0xaf39a736 , 0xfb28c8c8 , 0x606b61c2 , 0x2077f55f ] )
+  , Mont::unsafe_make( [ 0x328130c0 , 0xb90adbf0 , 0xb1932494 , 0x3c882f52 , 0x24cd39df , 0xbc40c5f3 , 0x8c79b1c5 , 0x0b54f7a7 ] )
+  , Mont::unsafe_make( [ 0x95da4fe2 , 0xbd0a336c , 0x87e2b65b , 0xf709e48b , 0x2e78fd99 , 0x3cec5dbb , 0xa3fb5389 , 0x0097a9f2 ] )
+  , Mont::unsafe_make( [ 0x8e5fc85e , 0x3403eb09 , 0x4ae548c0 , 0xee8d69f9 , 0x899b5fbb , 0x0c4d3ba1 , 0xf8843f49 , 0x0c003632 ] )
+  , Mont::unsafe_make( [ 0x76010738 , 0xca01306a , 0x5662ab06 , 0x8414ec87 , 0xee5e9a34 , 0x25693701 , 0x35caefcc , 0x0e078733 ] )
+  , Mont::unsafe_make( [ 0xa6a521c1 , 0x8d58affb , 0xf2e438b9 , 0x8d08fc23 , 0xcfe23ffd , 0xcd81fc01 , 0xf0450cd2 , 0x29644f0b ] )
+  , Mont::unsafe_make( [ 0x00b5bd51 , 0x7cbcfc42 , 0x02646b4a , 0x7673188b , 0x4e07e764 , 0xca303cee , 0x996a4d00 , 0x241dccd4 ] )
+  , Mont::unsafe_make( [ 0x11ddf6b1 , 0x2760ef98 , 0x7cffc856 , 0xfc0ca22e , 0x1145ed54 , 0xb6118eef , 0x491180a2 , 0x294c7319 ] )
+  , Mont::unsafe_make( [ 0xefbfd1aa , 0xfb315be7 , 0x0eb930e4 , 0xacbd687f , 0xcb775e76 , 0xcc738e25 , 0xd0f4e810 , 0x267456f1 ] )
+  , Mont::unsafe_make( [ 0x1ddff6b5 , 0x44f09ac5 , 0x06f0d764 , 0xaab343bc , 0x2b017678 , 0x62ff98fa , 0x2166378d , 0x192b8c1a ] )
+  , Mont::unsafe_make( [ 0x2b6e0a23 , 0xcceff059 , 0xe6a3de37 , 0xfc571e04 , 0x1e0aad1d , 0xd1aae681 , 0x5b6dca5a , 0x2139fca8 ] )
+  , Mont::unsafe_make( [ 0xe9577dcf , 0xfd2a42a4 , 0xf2cce661 , 0x24cde785 , 0x34a3203a , 0x5159871f , 0x728ff65d , 0x2df6d9a0 ] )
+  , Mont::unsafe_make( [ 0x9c874164 , 0x67ea33a8 , 0x4a2517a9 , 0xab57a2db , 0x45858e87 , 0x385eceb5 , 0x4b3a2c09 , 0x2534348c ] )
+  , Mont::unsafe_make( [ 0x554d6d69 , 0x8fd0934a , 0xdb12c882 , 0xd1d31065 , 0x4013ed04 , 0x39c4eb76 , 0x79f82edd , 0x07f6dce4 ] )
+  , Mont::unsafe_make( [ 0xf6e181bf , 0xcdafe4b8 , 0xa86ab24a , 0x7768a1fd , 0x2c618f96 , 0xfa82b45e , 0x3d2efaee , 0x0e4d644d ] )
+  , Mont::unsafe_make( [ 0x1b9015aa , 0xc8a10c6b , 0x1acb2eb5 , 0x7d760d55 , 0x8b85c062 , 0x0ec8a49c , 0x34886fe9 , 0x1432a162 ] )
+  , Mont::unsafe_make( [ 0x609a2dd1 , 0x6ca58023 , 0xd7f5d792 , 0x06d791d4 , 0x579c1ffd , 0x641c8baf , 0xab1b815e , 0x11ef8a7a ] )
+  , Mont::unsafe_make( [ 0x44586098 , 0x8e95d600 , 0xcb41028b , 0xf9a57cf0 , 0x43457f86 , 0xc096124f , 0xc27a370b , 0x0c43b568 ] )
+  , Mont::unsafe_make( [ 0xdf63043b , 0xc4f71850 , 0x8f496311 , 0x19674e34 , 0x2ff5a3ee , 0xd76309b3 , 0x4c3d5c7b , 0x04853cf9 ] )
+  , Mont::unsafe_make( [ 0x18a84b1e , 0x0a503501 , 0x9138cd4d , 0x685c88e9 , 0xf53a6741 , 0x960ec7ae , 0xf08ff79e , 0x1f8ee9d6 ] )
+  , Mont::unsafe_make( [ 0x3910b389 , 0xa3ac91e4 , 0x54a30e41 , 0x39b6665c , 0x6934f7d5 , 0xab4b0644 , 0x11676137 , 0x19fdf7c9 ] )
+  , Mont::unsafe_make( [ 0x322fe409 , 0xc295089f , 0x8be5f36a , 0xf9f43dd0 , 0x3a11bc5c , 0x4fbb5a2a , 0xea5950a5 , 0x02e9a2df ] )
+  , Mont::unsafe_make( [ 0x38e8ace7 , 0xe4a6f6c1 , 0xd48df324 , 0x86384283 , 0xc187d522 , 0x90f2f062 , 0xcf0a8420 , 0x10b63f13 ] )
+  , Mont::unsafe_make( [ 0xf3f27238 , 0x62c92180 , 0x8734222e , 0x96c828e8 , 0x04e8453b , 0xda888f1e , 0x8f58d13f , 0x092c7d85 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x332c32fd , 0x885f4dfa , 0xa041d186 , 0x0d44bf9d , 0x398536cd , 0x981a7341 , 0xea258303 , 0x2bd091dd ] )
+  , Mont::unsafe_make( [ 0x67fcd0dd , 0xae456d1c , 0x9c12039e , 0x6f2985dd , 0x3b67dc94 , 0x46e45f58 , 0x516ea884 , 0x1c8ce053 ] )
+  , Mont::unsafe_make( [ 0x60dce2a7 , 0x72e52e21 , 0x66f0923c , 0x6695e505 , 0x4e48ee8a , 0xd3edee7a , 0x0bf3ab90 , 0x148b899c ] )
+  , Mont::unsafe_make( [ 0x7f64e83d , 0xb095f15b , 0x6f71120d , 0x193809a8 , 0x8ac3c24a , 0xb393961f , 0x12006625 , 0x2544a4de ] )
+  , Mont::unsafe_make( [ 0x3f02f86f , 0x293f78d5 , 0xb1da503a , 0x48fadcc0 , 0xa29bf817 , 0x9c2505a2 , 0x2b21239f , 0x004a520b ] )
+  , Mont::unsafe_make( [ 0x3b111631 , 0x3664fa5e , 0xf0a8353f , 0x4e1fd468 , 0xd6de1e58 , 0xca884970 , 0x40c9224e , 0x2a69a2f4 ] )
+  , Mont::unsafe_make( [ 0x07f7395c , 0x73c9d5e4 , 0x4f86dd74 , 0xa7dac938 , 0xe338471a , 0x5c979b5d , 0x8e2c4c94 , 0x15addf18 ] )
+  , Mont::unsafe_make( [ 0xad991b20 , 0x254fe2b0 , 0xede14976 , 0xd662c8a9 , 0x2cbd6a30 , 0xd1c4ad33 , 0x0afb6359 , 0x1706032f ] )
+  , Mont::unsafe_make( [ 0x577937ab , 0x70239ec3 , 0xbcf69059 , 0x68cd1a8c , 0xa4d45d6a , 0x3c958cb1 , 0x99c467aa , 0x2965248c ] )
+  , Mont::unsafe_make( [ 0x64db7bfb , 0xb3c371ca , 0x983238ee , 0x9e5580c9 , 0x23075998 , 0xaeed8536 , 0xd53d2796 , 0x1e7ea02b ] )
+  , Mont::unsafe_make( [ 0xbe45c5ab , 0xa85a2372 , 0xaf7efbb6 , 0x284b9977 , 0x534ce754 , 0xb130dc83 , 0x5604bef5 , 0x202f8b2e ] )
+  , Mont::unsafe_make( [ 0xaa603ce4 , 0x78d4d55e , 0x12c7b7c2 , 0xcd0cfad1 , 0xa5070e70 , 0xfcf6f33d , 0xcbbc51c3 , 0x2074a3e3 ] )
+  , Mont::unsafe_make( [ 0xce3c497a , 0xaa311cb5 , 0x804cff08 , 0x92fb5c97 , 0x02c66502 , 0x73a36754 , 0x82de924c , 0x2e5fecb8 ] )
+  , Mont::unsafe_make( [ 0xd3ff2997 , 0x13b5ca59 , 0xf57bad75 , 0x57f86cef , 0x36968bd9 , 0x19045e6f , 0xa2e24ed5 , 0x10d759a1 ] )
+  , Mont::unsafe_make( [ 0x0e9ddb6f , 0x133a156d , 0x12054f16 , 0xc9626087 , 0xe2286742 , 0x87ef357a , 0xc2a5e88d , 0x01117d76 ] )
+  , Mont::unsafe_make( [ 0x5ca1b718 , 0x20b77763 , 0x5be0b6f7 , 0xf22d2282 , 0xa971d5a6 , 0x1cc69547 , 0x596d3675 , 0x22f8abe3 ] )
+  , Mont::unsafe_make( [ 0x5a1735c6 , 0xfc931307 , 0x0e98fb8d , 0x11a88328 , 0x805a0189 , 0x1599d328 , 0x7cbc5b7a , 0x21e8071d ] )
+  , Mont::unsafe_make( [ 0x230bdabe , 0x21a4509d , 0xa60dc41e , 0x3709ab2a , 0x94772302 , 0x3337aa8d , 0x03c6c335 , 0x164e090b ] )
+  , Mont::unsafe_make( [ 0xf0214e93 , 0xdbc15487 , 0x34b4bfa3 , 0x4dda9609 , 0x87100fd0 , 0x4927b767 , 0x5f0845e8 , 0x0e88c245 ] )
+  , Mont::unsafe_make( [ 0x52d548b6 , 0x389bdae7 , 0xcc713eda , 0xa1f15407 , 0xf3ea6dab , 0x2d901331 , 0xf2302e1a , 0x12293376 ] )
+  , Mont::unsafe_make( [ 0x8b6df873 , 0x2cc76c97 , 0x77772b1a , 0x0ae79d8e , 0xb37e7133 , 0xda3296a3 , 0x9de27d4c , 0x003fce5e ] )
+  , Mont::unsafe_make( [ 0xc690c1cd , 0x2c1b8ddc , 0x4a7d11ce , 0x42052e08 , 0x9a982a2a , 0xb948cf75 , 0xe3a68f86 , 0x2d617705 ] )
+  , Mont::unsafe_make( [ 0x424ad041 , 0xcaddac3f , 0xdf7599fb , 0xe933a3e7 , 0xba689b16 , 0x0ebd846c , 0x45988c13 , 0x22449a6c ] )
+  , Mont::unsafe_make( [ 0xc7d59c6a , 0x17e8e74f , 0x23c0297f , 0x56bc55b7 , 0xbae4f5ec , 0xd7a52888 , 0xc908cebf , 0x251d1bf3 ] )
+  , Mont::unsafe_make( [ 0x0ebb880c , 0x1b1edc2c , 0x0292a65b , 0x794fdd12 , 0xb46dfb68 , 0x2900d660 , 0xab92430f , 0x2dcea264 ] )
+  , Mont::unsafe_make( [ 0xeb4ffcb2 , 0x70c2c966 , 0xdeaf73c2 , 0x0c818f15 , 0x68a436ae , 0x2dae9ae9 , 0x99739980 , 0x13cd82b9 ] )
+  , Mont::unsafe_make( [ 0xcbf4461d , 0xda225497 , 0x1cdeece8 , 0x825232ba , 0x53302f26 , 0xe1fa2aac , 0x44196181 , 0x0a0cdb8a ] )
+  , Mont::unsafe_make( [ 0x43d7bb34 , 0x1ba527e3 , 0xc28fd327 , 0xd699b776 , 0xd7969022 , 0xd9ac7197 , 0x1be68d4a , 0x17192bc0 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x6a9867d4 , 0x0a03a6d4 , 0xd99c3d8f , 0xaa89c989 , 0xe8efe288 , 0xffa50ca7 , 0x6f8a1f2c , 0x15898b33 ] )
+  , Mont::unsafe_make( [ 0xa197b429 , 0x5d730634 , 0xe1b72ebd , 0xd148892e , 0x2bc0d85a , 0x6cac3d8f , 0x742e7aee , 0x2572b3eb ] )
+  , Mont::unsafe_make( [ 0xa8c8b6c6 , 0x6bd0a631 , 0x120e5930 , 0x1d964237 , 0x20556c7d , 0xf9c80118 , 0x822b698a , 0x20996569 ] )
+  , Mont::unsafe_make( [ 0x4ae45f8b , 0x62bbe90f , 0x0a877a9a , 0x70a2ec71 , 0xa15be2ca , 0x071aa743 , 0x03014461 , 0x248d9174 ] )
+  , Mont::unsafe_make( [ 0xeab4b45e , 0x504b79ae , 0xc3471ad5 , 0x4b8814ca , 0x13c2353c , 0x62800c8e , 0x5e4af243 , 0x2ca84c22 ] )
+  , Mont::unsafe_make( [ 0x2a6fe107 , 0xf89952a2 , 0x63898bbf , 0x3b4aaf72 , 0xa58aa5ec , 0x02b87463 , 0x29fd764e , 0x0f0e5ede ] )
+  , Mont::unsafe_make( [ 0x86c5b056 , 0x46c6b9c6 , 0x8d71866e , 0x3f0aa7f0 , 0x8a03ae95 , 0x2459749a , 0xb185b4f6 , 0x0ce27343 ] )
+  , Mont::unsafe_make( [ 0x69c6d9c3 , 0x723e0341 , 0x33bef31b , 0x3d543ae2 , 0xa8938f1c , 0xa0baae5e , 0x4bbb3578 , 0x0dcf8471 ] )
+  , Mont::unsafe_make( [ 0x4ba1995c , 0x015dffc5 , 0x65a0ab42 , 0xbeb73d51 , 0x0dc1e5d9 , 0x1ec76985 , 0xb091fa8f , 0x214d2a01 ] )
+  , Mont::unsafe_make( [ 0x526a9d92 , 0x16345f6e , 0x31767043 , 0xf08b5e91 , 0x70395aaf , 0x8dbfae99 , 0xb919b26e , 0x1e413407 ] )
+  , Mont::unsafe_make( [ 0x7ecf035f , 0xd98c5c67 , 0x827cfaf1 , 0x2c5c1af7 , 0xea13d22a , 0x2a97f406 , 0xf429aa99 , 0x0504c84a ] )
+  , Mont::unsafe_make( [ 0xac645d1c , 0x38bf198b , 0x152836f3 , 0x8ff51364 , 0xdeb0238a , 0x746f4207 , 0x2efaaa21 , 0x2b5e038b ] )
+  , Mont::unsafe_make( [ 0xa666db76 , 0xad84a300 , 0xe0b1d133 , 0x0acd5f1b , 0xd2fefce5 , 0xe8fb3214 , 0x33160c84 , 0x11fa3aaa ] )
+  , Mont::unsafe_make( [ 0xb2b0fd62 , 0x17751ae8 , 0x34de3d59 , 0x938c69dc , 0x87dc1368 , 0x866ab18e , 0x017264a9 , 0x2a8f455b ] )
+  , Mont::unsafe_make( [ 0xdbfbe907 , 0xcd7e19d5 , 0xfa2f7867 , 0xcbf72ba3 , 0xf200dbc8 , 0x6818d58d , 0xcb2f0d4f , 0x0e9817ca ] )
+  , Mont::unsafe_make( [ 0x8d88af85 , 0x661fb8b2 , 0x35e0d13d , 0x50dec3c5 , 0xf84159d6 , 0x025b808e , 0xd26e1c3a , 0x1f5c1051 ] )
+  , Mont::unsafe_make( [ 0x3b7778d4 , 0xd5ee42b8 , 0x6197bb2b , 0x2fcbcbcd , 0x2b91f9f1 , 0x86b7ccb0 , 0x4c4f4584 , 0x2a1cd2e4 ] )
+  , Mont::unsafe_make( [ 0x14808b6b , 0x04602ae5 , 0xd5ec6950 , 0x7a51df7a , 0x65d49560 , 0x3c269b42 , 0x240eccdc , 0x0284ef93 ] )
+  , Mont::unsafe_make( [ 0x2ddf27e7 , 0xdf05ba61 , 0x135aac2e , 0x5a602729 , 0x23dee884 , 0xb033632e , 0xb019565a , 0x2c391256 ] )
+  , Mont::unsafe_make( [ 0x84964f42 , 0xe58cb2eb , 0x8875beee , 0x46f0d71c , 0x9b543ab7 , 0xa1f8bf46 , 0x1094ceca , 0x1a93fd35 ] )
+  , Mont::unsafe_make( [ 0x1eb570d5 , 0x7dab9952 , 0x95c3e700 , 0x97765308 , 0x26d05ff0 , 0x220ae9dc , 0xeb8fa776 , 0x20e23cac ] )
+  , Mont::unsafe_make( [ 0x2fee565f , 0x944aa5fc , 0x282cb061 , 0x8ab447a4 , 0xc0c74491 , 0x7be589bc , 0xbd965766 , 0x24cf996b ] )
+  , Mont::unsafe_make( [ 0x9669655b , 0x4c65d408 , 0x76981658 , 0x2898805e , 0x27b7700b , 0x7f565f6d , 0xf11d5ded , 0x0fa1f109 ] )
+  , Mont::unsafe_make( [ 0xa8264202 , 0xdd7baadb , 0x72b9a2a0 , 0x17ab2a1d , 0x7c127fd1 , 0xe532a700 , 0x03dbf953 , 0x0484f724 ] )
+  , Mont::unsafe_make( [ 0x47f4d0ae , 0xbe33f8e8 , 0x8a3a0721 , 0x98159b4a , 0x1693125c , 0xa30c3962 , 0xd42adf68 , 0x1444f376 ] )
+  , Mont::unsafe_make( [ 0x42cbb61c , 0x668385dd , 0xe5ddbfba , 0xd57b60e4 , 0x128d409c , 0x2257a761 , 0x3e694d78 , 0x006db01b ] )
+  , Mont::unsafe_make( [ 0xf34ab6de , 0x5c33daf7 , 0x106a9959 , 0x394c59f0 , 0xf1b059c3 , 0x9a112240 , 0x1585a267 , 0x27af6b52 ] )
+  , Mont::unsafe_make( [ 0x0aacd815 , 0xe4cc7af0 , 0xf1ad80e6 , 0x899bfa38 , 0x140150b3 , 0xb485eb3c , 0x891ae6e6 , 0x16430e32 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0xd36908b8 , 0x58f02f96 , 0xb8c1de59 , 0xec0f1cb1 , 0xf5ed3961 , 0x007eae7d , 0xff0e910c , 0x248bc815 ] )
+  , Mont::unsafe_make( [ 0x9e163731 , 0xe8d07c9e , 0x77d2526a , 0x6eabaf7e , 0x2caddabd , 0x246d4889 , 0xa34b9137 , 0x06e03d81 ] )
+  , Mont::unsafe_make( [ 0x68a44943 , 0x59832410 , 0x6c35b8a5 , 0xa8e17683 , 0xc6884396 , 0x70898677 , 0x17caf023 , 0x1a180ee6 ] )
+  , Mont::unsafe_make( [ 0x2036e586 , 0x549eed7a , 0xc069c337 , 0x4f0d0098 , 0xfb9a1086 , 0x820486b4 , 0xaf7c839d , 0x13908dde ] )
+  , Mont::unsafe_make( [ 0xea55f5b3 , 0x6e813ccb , 0x6bd1603c , 0x757bebda , 0xd09839ab , 0x385d7cec , 0x75685b02 , 0x213bd146 ] )
+  , Mont::unsafe_make( [ 0xc41e2a3a , 0x79235cc7 , 0xa755f60a , 0xaf3a8c5d , 0x7c8530a9 , 0x2928dc27 , 0x9921985f , 0x0895a66b ] )
+  , Mont::unsafe_make( [ 0x1b292b9c , 0xb8f4985d , 0x70b1e1af , 0xb66fa814 , 0x119b944d , 0x121091f4 , 0x982e3003 , 0x0eb69409 ] )
+  , Mont::unsafe_make( [ 0x23213ce6 , 0x48aa07a9 , 0x24bc5c22 , 0xc6c01f81 , 0xf98dc128 , 0xccbdb814 , 0x3b438124 , 0x1d063a53 ] )
+  , Mont::unsafe_make( [ 0xa28a317a , 0x50d98bd9 , 0xb61fd67d , 0xbf9f7a45 , 0x282c9f12 , 0x2713ad2c , 0xc531ad46 , 0x260feb8f ] )
+  , Mont::unsafe_make( [ 0x2e1e12bb , 0xfaf74274 , 0x0740d401 , 0x33ed8a06 , 0x69e26a55 , 0xcbcdd368 , 0x1b8191a4 , 0x0135c9a4 ] )
+  , Mont::unsafe_make( [ 0x51af0830 , 0xcae3e85d , 0x055a73bb , 0xbc386f9f , 0xcd078628 , 0x3911c161 , 0xbfd32f78 , 0x1d9c0b63 ] )
+  , Mont::unsafe_make( [ 0x63eea947 , 0x97ff4977 , 0xbccfb8df , 0xb195a0a5 , 0x4a7c18c2 , 0x1c73d126 , 0xd4bf34c1 , 0x15a6b718 ] )
+  , Mont::unsafe_make( [ 0x7065f595 , 0xe4786980 , 0xd060b2c8 , 0x43c83d79 , 0x0e5c971e , 0x84adfb1f , 0x9df7b3ce , 0x05ad60f7 ] )
+  , Mont::unsafe_make( [ 0xb7d44926 , 0x0404ad90 , 0x67ad4d45 , 0xccbc2bd4 , 0xbbd09af9 , 0x691d81a9 , 0xf412ee3a , 0x235584fd ] )
+  , Mont::unsafe_make( [ 0x417a73e4 , 0xaa52866d , 0xb0c05ac4 , 0x77c648a1 , 0x8b7335a1 , 0xc14eeb5a , 0x9a32f722 , 0x0e5a6323 ] )
+  , Mont::unsafe_make( [ 0x0a1ac794 , 0x9b3dc89b , 0x2536e10c , 0xa4e1f92c , 0x1fccb582 , 0x790aaa4f , 0x444678bc , 0x1844fb6b ] )
+  , Mont::unsafe_make( [ 0x8b30ba01 , 0xce10edf4 , 0xf9717140 , 0xbe95f441 , 0xc0aa78e1 , 0xdeccb7ed , 0x6b4a0000 , 0x24301a0c ] )
+  , Mont::unsafe_make( [ 0xeb474795 , 0x33f19d94 , 0x67c43d06 , 0xd3a3a124 , 0x4a1ed388 , 0x04f0f846 , 0x44491a4c , 0x2381b5d2 ] )
+  , Mont::unsafe_make( [ 0xa4d53279 , 0xca994fed , 0x9f555be7 , 0xfccff4aa , 0xe3b02aba , 0xc03f2ba9 , 0xe7a9289b , 0x148551c5 ] )
+  , Mont::unsafe_make( [ 0xd390178b , 0xccae2b6c , 0x6ded9514 , 0x06e68760 , 0x196f7e8e , 0x87d7b664 , 0x63fd5328 , 0x1cbedc41 ] )
+  , Mont::unsafe_make( [ 0x86227e77 , 0x79edd531 , 0x9dd01e1d , 0xf120250a , 0x744b3887 , 0x26573031 , 0xa04a5f2c , 0x0fb2e6c2 ] )
+  , Mont::unsafe_make( [ 0x72095c3d , 0xa31e8184 , 0xf3a9a70d , 0xe8e9d102 , 0x0e3d0969 , 0x02ec5486 , 0x2adf9980 , 0x111e384f ] )
+  , Mont::unsafe_make( [ 0xf15643cf , 0x3f67253a , 0xd0de60ec , 0xbdffdfbe , 0xf5aea936 , 0xe4913e09 , 0xeeb3a529 , 0x0bde5df9 ] )
+  , Mont::unsafe_make( [ 0x8e0fd4d2 , 0xa4ec65cd , 0x3d29ce61 , 0xe4012bfb , 0x0b524397 , 0x334ed8ec , 0xe8e0f940 , 0x26edab65 ] )
+  , Mont::unsafe_make( [ 0x185b60d3 , 0x58ad75a6 , 0x4e58675a , 0xb415e2a5 , 0xdd2b00b6 , 0x964d68cf , 0x90e2d8c2 , 0x0f7815d9 ] )
+  , Mont::unsafe_make( [ 0x8d737889 , 0x6ea00453 , 0xf4c6b570 , 0x32ba5636 , 0xd0206a4e , 0xaf0e3c62 , 0xd91a567c , 0x1da03214 ] )
+  , Mont::unsafe_make( [ 0x178eab03 , 0x7513c642 , 0x540ab9aa , 0x01c1a3b3 , 0xd56ba325 , 0x13cf138e , 0x8f0ba307 , 0x165e022a ] )
+  , Mont::unsafe_make( [ 0x3ba7a4ec , 0x9869cf27 , 0x0628ca05 , 0x402b295f , 0x618a656d , 0x43e85450 , 0xcce8a19a , 0x0cae9453 ] )
+  , Mont::unsafe_make( [ 0x66e9f907 , 0x10c7543b , 0xae9021ba , 0x6e3b37bb , 0x9ec76adf , 0xcb9b3784 , 0x8d0b79fa , 0x22d4448e ] )
+  , Mont::unsafe_make( [ 0x64836391 , 0x61c9b845 , 0xd12788a9 , 0x7636d8af , 0xd6e23dba , 0xf82a48c1 , 0x2752b81f , 0x22d25832 ] )
+  , Mont::unsafe_make( [ 0xa5c852b5 , 0x15aee355 , 0x549840a7 , 0x66de6041 , 0xc540bf3a , 0x0eb9753e , 0x24bade8e , 0x0d971b5c ] )
+  , Mont::unsafe_make( [ 0x76262285 , 0x2abd7ba0 , 0x29b249e1 , 0xcee6bdc9 , 0x1e170f7b , 0xdde0186d , 0x3dfbb626 , 0x2b571c86 ] )
+  , Mont::unsafe_make( [ 0xba74c1c2 , 0xde44c3ec , 0xcc9ebf3a , 0xb271d077 , 0x5a838f18 , 0x752448b4 , 0x719e1299 , 0x1b61163a ] )
+  , Mont::unsafe_make( [ 0xbe641ffb , 0x5254c75b , 0xae546f6f , 0x1aa3d55c , 0xea7e9d6b , 0x0c1784cc , 0x99a1ba1d , 0x1bccbce2 ] )
+  , Mont::unsafe_make( [ 0x9b3dc184 , 0x3985a687 , 0x9369b35e , 0xb607ad44 , 0x3469c369 , 0xcc8d274e , 0xbf1ef139 , 0x03803cc9 ] )
+  , Mont::unsafe_make( [ 0x69f97b17 , 0x10a92c85 , 0x69dff821 , 0x65264dd3 , 0xc70e30d1 , 0xf556ac3b , 0xf5192467 , 0x16d4d4c9 ] )
+  , Mont::unsafe_make( [ 0x50802f5d , 0xbad8ab61 , 0xa4691da3 , 0x84dc8561 , 0x2c2d79f8 , 0x31bed751 , 0x97ab1f2a , 0x282b2bd0 ] )
+  , Mont::unsafe_make( [ 0x09493082 , 0x2fc1070b , 0xd0485955 , 0x4bae62a3 , 0xe99f6e95 , 0x70058279 , 0x05810ebb , 0x04981c21 ] )
+  , Mont::unsafe_make( [ 0x1a4a49e5 , 0x7458ad11 , 0xbc697979 , 0x39067a13 , 0x3f6a455b , 0xfab87ea4 , 0xb3d5279c , 0x2af38035 ] )
+  , Mont::unsafe_make( [ 0xe0396b7c , 0xb54bac72 , 0xc88f4240 , 0x6e9c3644 , 0xb0ea327e , 0x9e92f3ff , 0x37f6ddde , 0x28fbd8de ] )
+  , Mont::unsafe_make( [ 0x07a8fed3 , 0x30e7bf96 , 0x307b4cc1 , 0x1a41c67c , 0x4dcc7976 , 0xf8cb069c , 0x696e2da6 , 0x29826855 ] )
+  , Mont::unsafe_make( [ 0xdfe7e356 , 0x157d51e4 , 0x1935df8b , 0x495af119 , 0x669ab82e , 0x45badbb0 , 0xe460762e , 0x0b02c8a2 ] )
+  , Mont::unsafe_make( [ 0xffcaa6c3 , 0xdf611d1a , 0xccc99fb0 , 0xd3fdc439 , 0x0e5234dc , 0xd4d97fb3 , 0xb14934cc , 0x2810bcb4 ] )
+  , Mont::unsafe_make( [ 0x7b9c844f , 0xa5545205 , 0xe5bb8f4c , 0x91cfde0f , 0xc232826b , 0x83391373 , 0x28e9ee02 , 0x17286264 ] )
+  , Mont::unsafe_make( [ 0x1eca75ce , 0x89d16012 , 0x67c717f2 , 0xd82aef03 , 0x07700aa7 , 0x62f43c87 , 0xb531d675 , 0x0f391e65 ] )
+  , Mont::unsafe_make( [ 0x8fdf6f74 , 0x3593eb92 , 0xcccee151 , 0xf51416b1 , 0xe2b5a809 , 0xf2736540 , 0x3d000c23 , 0x068ea1e2 ] )
+  , Mont::unsafe_make( [ 0x4e90a7dd , 0x04173c50 , 0xa2e8882c , 0x7623435f , 0xc74165cd , 0x64727ecc , 0x3eddf567 , 0x26432d2f ] )
+  , Mont::unsafe_make( [ 0xf04d0d9f , 0x0df5028b , 0xe093406c , 0xd7da9608 , 0x8fbfcbed , 0x47071f7a , 0xe022242b , 0x296916e9 ] )
+  , Mont::unsafe_make( [ 0x73b03c92 , 0x130c6e98 , 0xb13a37c8 , 0x3cb33f16 , 0xd8e47f37 , 0x05a9e461 , 0x2904e690 , 0x2b591c8d ] )
+  , Mont::unsafe_make( [ 0x17db3754 , 0xa78dfdb5 , 0xde35bbb1 , 0x5f4e0a57 , 0xddac926e , 0xc458bc74 , 0x3e6e38b3 , 0x19feee7e ] )
+  , Mont::unsafe_make( [ 0xf94ae02d , 0xd3e7ad4e , 0xf0bb5ffb , 0x076e7d33 , 0x20327346 , 0x063784e5 , 0x3bf84263 , 0x23466f13 ] )
+  , Mont::unsafe_make( [ 0x2bcddd11 , 0x2a3f62bb , 0x48e56e7d , 0xb07be022 , 0x764d5120 , 0x81b782f1 , 0xc9ca2d60 , 0x04f02418 ] )
+  , Mont::unsafe_make( [ 0x0e2a2d36 , 0x9fbde051 , 0x14e5c45f , 0x5bc04519 , 0xa91c8743 , 0xc5cd2be7 , 0x06a6c6af , 0x137af6b5 ] )
+  , Mont::unsafe_make( [ 0x693d2055 , 0x1f0fbe2c , 0xd4d77dce , 0x23612cb7 , 0xd778c249 , 0x4f381e2e , 0x2e36bd15 , 0x18150163 ] )
+  , Mont::unsafe_make( [ 0x6251efac , 0xa79de2cc , 0x18d2d02e , 0x20f5f20b , 0x0aeaf007 , 0x70da1dff , 0x08af89ef , 0x1d08e116 ] )
+  , Mont::unsafe_make( [ 0x2af5af8e , 0xd2114008 , 0x036e131a , 0x8f4de5ba , 0x7ed83675 , 0xfab7cb85 , 0xb22b29e7 , 0x299b31a7 ] )
+  , Mont::unsafe_make( [ 0x2619719e , 0xd6a7897b , 0x093b1a17 , 0x09b26009 , 0xabf40d2a , 0xc6f0a06a , 0x015a8d2e , 0x0cd254bb ] )
+  ];
+
+//------------------------------------------------------------------------------
+
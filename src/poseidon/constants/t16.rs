@@ -0,0 +1,2720 @@
+
+// circomlib's Poseidon constants for t=16
+
+use crate::bn254::montgomery::*;
+
+
+//------------------------------------------------------------------------------
+
+// round constants (t for external, 1 for internal rounds; flattened)
+pub const CONST_C: [Mont; 192] = 
+  [ Mont::unsafe_make( [ 0x85edd4f0 , 0xe54c7724 , 0x681cf7a5 , 0xb99a7257 , 0x470fa20b , 0x9a23aa8c , 0x01e70e9f , 0x091ab842 ] )
+  , Mont::unsafe_make( [ 0x6e651f39 , 0x648d1500 , 0x0f2d8b61 , 0xa91e98ea , 0x477b1a2a , 0x287e5e6c , 0x0957379e , 0x1eee29ed ] )
+  , Mont::unsafe_make( [ 0x04e8f18b , 0xe61cfb74 , 0xb0d399af , 0x392a4b4f , 0x7d6f7d01 , 0xf6f56710 , 0xf1c48f70 , 0x2595c662 ] )
+  , Mont::unsafe_make( [ 0x97b47df2 , 0x35505ce8 , 0xd69df1a9 , 0x8422d1df , 0xf2095efa , 0xb0b8fec8 , 0x8a4b817d , 0x0db81974 ] )
+  , Mont::unsafe_make( [ 0x585b76cf , 0xfaddcc7e , 0x211dc9de , 0xf3ce318c , 0x72c03272 , 0x400ac40a , 0x19509b0f , 0x0e02e1e8 ] )
+  , Mont::unsafe_make( [ 0xfc4a92dd , 0x5a9005e2 , 0xa8706cae , 0xb6eb7fbc , 0x9111a7b7 , 0x90ca7fdd , 0x5c1d82f6 , 0x1484ef87 ] )
+  , Mont::unsafe_make( [ 0x13b9c724 , 0x451a471e , 0x420f5e8f , 0xc087c5af , 0xecbe3771 , 0xcabcf6f7 , 0x3577cdb2 , 0x2a48809c ] )
+  , Mont::unsafe_make( [ 0x7ea95fe5 , 0x2ab247f3 , 0x68be8913 , 0xb57ba13c , 0x4f26c7af , 0xe809981b , 0xf449c8a3 , 0x1f9906ae ] )
+  , Mont::unsafe_make( [ 0xb544d931 , 0xe75c1861 , 0x8ffeef39 , 0x4ec0085e , 0x0d85b74c , 0x504d056a , 0x4fc2a932 , 0x14389d60 ] )
+  , Mont::unsafe_make( [ 0x934ec0d3 , 0x054e811c , 0x3ec8493e , 0x7fdc947a , 0xf0155697 , 0x4b9db67b , 0x1cfb0c0b , 0x200e8ae8 ] )
+  , Mont::unsafe_make( [ 0x383bd4c7 , 0x50917f63 , 0x959c9743 , 0x4c76c23c , 0x76a0e437 , 0x629f547b , 0xbe1e0633 , 0x0347d625 ] )
+  , Mont::unsafe_make( [ 0xdb5d6b0e , 0x799927db , 0xd0b21ff5 , 0x59b699e6 , 0xdd53c5e6 , 0x8b71f14f , 0x651083b4 , 0x058b527e ] )
+  , Mont::unsafe_make( [ 0x17947aa8 , 0x2b61864d , 0x070ed321 , 0xd5c2d966 , 0x84f15838 , 0xfed09b1b , 0xfa077389 , 0x09304693 ] )
+  , Mont::unsafe_make( [ 0x89874680 , 0x6d824b5b , 0x41634eb1 , 0x99d14aed , 0x4b345348 , 0x23f6308a , 0xb118b682 , 0x2e6f21ca ] )
+  , Mont::unsafe_make( [ 0xbd1709cd , 0x8ca3f8be , 0x78e38372 , 0x811c73a9 , 0xcf679abe , 0xc36f50c0 , 0x3b98a330 , 0x26588bcd ] )
+  , Mont::unsafe_make( [ 0xf6bee10b , 0x6b69dee1 , 0xe80faa2c , 0x89aa7f60 , 0xe98e587e , 0x044b30eb , 0x8da49c58 , 0x28c6d1f6 ] )
+  , Mont::unsafe_make( [ 0x40259bb0 , 0x68dcdf77 , 0xf699e6f2 , 0xe5c67937 , 0xbd66e32b , 0xdd927984 , 0x5d59ae4c , 0x2ae0b09f ] )
+  , Mont::unsafe_make( [ 0x97f8befe , 0xbf1ea7da , 0x5ea07426 , 0xc4eed16c , 0x0a22cb80 , 0x3b30fe3d , 0xac6db1cf , 0x27a10dd3 ] )
+  , Mont::unsafe_make( [ 0x4b205036 , 0x9946b29a , 0xcd8ca4dd , 0xd3132474 , 0x07965266 , 0xc5953a7a , 0x67ec885e , 0x15596cae ] )
+  , Mont::unsafe_make( [ 0x6ea19840 , 0x9c8ddc98 , 0xeb8d56b9 , 0x20f87bc8 , 0x6ce1e230 , 0x6695a630 , 0xeb315958 , 0x2370546c ] )
+  , Mont::unsafe_make( [ 0xd309fc7d , 0xab3895ac , 0x7b00b870 , 0x1a6717a8 , 0xe7af1db0 , 0x86ff0aca , 0x11849834 , 0x2856c04c ] )
+  , Mont::unsafe_make( [ 0x04fbd4c6 , 0x0ade8991 , 0x6b427320 , 0xbf1d42da , 0xa37ddf2c , 0xecaf3b25 , 0xccbc4ad5 , 0x2e4070b8 ] )
+  , Mont::unsafe_make( [ 0xbbfd18ab , 0x1996259b , 0xbcb6db2b , 0xfa37ffac , 0x2fe913a9 , 0x8cc2bc51 , 0xad99afb7 , 0x13c270f7 ] )
+  , Mont::unsafe_make( [ 0xe0d3c3d9 , 0xc4e4dd2f , 0xc357da63 , 0x679b4844 , 0x607d88f0 , 0xd698c9b3 , 0x6fbcfab9 , 0x015d786e ] )
+  , Mont::unsafe_make( [ 0x853e78c2 , 0x5fb03478 , 0x871d768f , 0x3c3f6ed9 , 0x315f0348 , 0x0dda27bd , 0x3eaa3da5 , 0x00c6343a ] )
+  , Mont::unsafe_make( [ 0x268a64c2 , 0x6e14f8ad , 0xcd69dc91 , 0xa66558f0 , 0x7297bf53 , 0x246d5853 , 0xdeccbb00 , 0x295bd79e ] )
+  , Mont::unsafe_make( [ 0x0f170ac0 , 0x726b7f95 , 0xd83e4417 , 0x2b161795 , 0xfd6c176f , 0x896f20da , 0xab20a534 , 0x24b965ba ] )
+  , Mont::unsafe_make( [ 0x6fe68c6d , 0x73e809be , 0x133389bc , 0xa12a849a , 0x62a20fae , 0xbe5660f3 , 0x94b90dba , 0x05d13a5e ] )
+  , Mont::unsafe_make( [ 0xd6050e30 , 0x55100801 , 0x26c08d55 , 0x305a39c8 , 0x5d525d94 , 0xb9cbc5b2 , 0xb6997442 , 0x13ad6926 ] )
+  , Mont::unsafe_make( [ 0x6c78d3bc , 0xa3febddc , 0x4fe12a47 , 0x5d9dd107 , 0xc9bf85fa , 0xb4cd66e3 , 0x600d7271 , 0x009590b6 ] )
+  , Mont::unsafe_make( [ 0x866f5b3e , 0x4e453562 , 0xf9241f41 , 0xc44e916c , 0xd4f4dad8 , 0x7d3e992b , 0xb51ee17e , 0x0fd4c04e ] )
+  , Mont::unsafe_make( [ 0x96ef3561 , 0x3fa9a789 , 0xc903dee3 , 0x06ff2f76 , 0xc5d29bc6 , 0x21cbe388 , 0xc9def997 , 0x20406b7c ] )
+  , Mont::unsafe_make( [ 0x1f1dbfac , 0x03dcd8ac , 0x4ff7937f , 0x1ce0d00f , 0xccefa371 , 0x96da7ab9 , 0xc3f7c92a , 0x28d88056 ] )
+  , Mont::unsafe_make( [ 0xb14de511 , 0x04b32dae , 0xdaeb7a7b , 0xead378f2 , 0x324d0639 , 0x676ceb32 , 0xe6eb7f42 , 0x0559e8f5 ] )
+  , Mont::unsafe_make( [ 0xa8ae440c , 0xe4f722ac , 0x174a0598 , 0xe26e9727 , 0x9921bd04 , 0x66defef1 , 0x5e0c359b , 0x0d6a0fff ] )
+  , Mont::unsafe_make( [ 0x1472e684 , 0xbc1675b6 , 0xaa86bc9b , 0xec195937 , 0xb4493639 , 0xa8e17cf1 , 0xb622ec8e , 0x2eb0fa35 ] )
+  , Mont::unsafe_make( [ 0x92b976de , 0xcda44eff , 0x5f5171b3 , 0x3c00a893 , 0xe728c78e , 0x0d060997 , 0x679179c2 , 0x09eeaadd ] )
+  , Mont::unsafe_make( [ 0xe0e6c45b , 0x8a3c5a42 , 0x86d3c2e6 , 0xc088687e , 0x9936c47d , 0x88d18afc , 0xafa0ca78 , 0x0f6b0733 ] )
+  , Mont::unsafe_make( [ 0x63e7ada9 , 0xe94b6126 , 0x71d7901c , 0xc67b72ae , 0xc6c06df6 , 0x4b71c6d4 , 0xa4b28b9d , 0x08eb560b ] )
+  , Mont::unsafe_make( [ 0x10bf1e57 , 0x8a751f6c , 0x93f188ec , 0x8d938784 , 0xd9324539 , 0x84093780 , 0xf13cd466 , 0x02eed3b0 ] )
+  , Mont::unsafe_make( [ 0x36012493 , 0x8ebe98a4 , 0x76ecc8cf , 0x6868a10e , 0x7188ac4a , 0xe61dfb84 , 0xe6af8bf6 , 0x24694d1d ] )
+  , Mont::unsafe_make( [ 0xcfd7a676 , 0x7c0a826e , 0x320769e6 , 0xf47bb29a , 0xa5d23217 , 0x03d3980f , 0x5acc2d7d , 0x2c356ce0 ] )
+  , Mont::unsafe_make( [ 0xb9d500ff , 0x21acae04 , 0x02878ff8 , 0x5af46060 , 0x87d3be7f , 0x203fd1c7 , 0xebb45560 , 0x122e36ec ] )
+  , Mont::unsafe_make( [ 0x1b09ce5b , 0x52f3277d , 0xca2d9aef , 0x40e7a246 , 0x76e6b7d2 , 0x15d9e121 , 0x5b7878c9 , 0x2344ff04 ] )
+  , Mont::unsafe_make( [ 0xdbe369f9 , 0x60e62a47 , 0xca431f1f , 0xc4cd8cb8 , 0xd09b30bd , 0xf80ba1f7 , 0xd77d3ab3 , 0x225fce68 ] )
+  , Mont::unsafe_make( [ 0xf6755b13 , 0xb798dd83 , 0x8465cd8c , 0xd38ad955 , 0xd059c7b8 , 0x6654fa1c , 0xbe26ed64 , 0x1fc4812a ] )
+  , Mont::unsafe_make( [ 0xf936de2b , 0x4ef24534 , 0x78fef40e , 0x39669b98 , 0xa2221359 , 0xc273f07b , 0x4ee7fad5 , 0x21036bc7 ] )
+  , Mont::unsafe_make( [ 0x833f578c , 0xb7d45e4e , 0xd5db822f , 0x576292c4 , 0xadbee21d , 0x3cdc5b36 , 0x27c5f259 , 0x00710d49 ] )
+  , Mont::unsafe_make( [ 0x7572c272 , 0xb07d5b00 , 0x3c71b914 , 0xb0d60b70 , 0x3d75c87d , 0x3b80b788 , 0xfdeb80e5 , 0x22c90873 ] )
+  , Mont::unsafe_make( [ 0x95c95fd0 , 0x163c830c , 0xf23ae0bc , 0x7ad2c98c , 0xeb800aa2 , 0x9787cf4c , 0xd554c6f1 , 0x18be6947 ] )
+  , Mont::unsafe_make( [ 0xcfb2b477 , 0x6abf5ef3 , 0xe66fb554 , 0xcd3d1b60 , 0x039b5d98 , 0x5e27e074 , 0x5120538a , 0x23160d36 ] )
+  , Mont::unsafe_make( [ 0x58a7fbc0 , 0x3519d79e , 0xee2de91f , 0x2714221c , 0x94156086 , 0x427b3104 , 0xcaf68c18 , 0x1b1f615f ] )
+  , Mont::unsafe_make( [ 0xa60e68e2 , 0x3676a115 , 0x9c3a481b , 0xee177081 , 0xdab9751d , 0xc0886bc9 , 0x334bbf08 , 0x0793a46c ] )
+  , Mont::unsafe_make( [ 0xa6036c71 , 0x614bc6c4 , 0xb77bc53d , 0x89582c00 , 0x1a3623b3 , 0x8320c0e4 , 0x044ee0da , 0x0bfe0cc8 ] )
+  , Mont::unsafe_make( [ 0xc5fec3ef , 0x4fe14ad3 , 0x44a37051 , 0xb3f74cd3 , 0x80469ee6 , 0x91dba18f , 0x7190c58f , 0x171b2cec ] )
+  , Mont::unsafe_make( [ 0xa819abdd , 0xe7fefeb7 , 0xb0bb19db , 0x8669bf59 , 0x3ddb7055 , 0x38294316 , 0x75e9ee18 , 0x0cfebe66 ] )
+  , Mont::unsafe_make( [ 0x31021d32 , 0x791980ce , 0xa083b145 , 0x4d3bde97 , 0x5da39742 , 0x154f0fbc , 0x781cd0a4 , 0x14e593b7 ] )
+  , Mont::unsafe_make( [ 0xdfface90 , 0x61d16f6d , 0xfcfadfcc , 0x89a1b024 , 0x25885fe3 , 0x5097a9ba , 0xcb5b1ea9 , 0x02f0871b ] )
+  , Mont::unsafe_make( [ 0x87566977 , 0x525d7aa4 , 0xaad35a01 , 0xf9ff105c , 0x1c5896d4 , 0x45276fea , 0x5f090f13 , 0x2dd2f691 ] )
+  , Mont::unsafe_make( [ 0x659e05ef , 0x82f1bbb2 , 0x705b528c , 0x7ac9d646 , 0x091bab72 , 0x676c6c66 , 0xf6a2ab2f , 0x2da59db9 ] )
+  , Mont::unsafe_make( [ 0xf81f5fa0 , 0x541697c5 , 0xfea68698 , 0x904c335a , 0x42f6f51b , 0x4606b94e , 0x9ee25f42 , 0x11d371d8 ] )
+  , Mont::unsafe_make( [ 0xec517aa1 , 0xba5bb200 , 0x3d308d70 , 0x1154788b , 0x15ccac04 , 0x83a5c202 , 0x36b3249d , 0x147c23c9 ] )
+  , Mont::unsafe_make( [ 0x335320ae , 0x390bf86a , 0x4489c3e0 , 0x1a14db07 , 0x13ee811b , 0x549da025 , 0xe93ef13c , 0x2edbf566 ] )
+  , Mont::unsafe_make( [ 0x4fd10e74 , 0x48f20e5f , 0xcc1691a9 , 0x569e216c , 0xdd147eb2 , 0x407b4332 , 0x32533041 , 0x1d8fab90 ] )
+  , Mont::unsafe_make( [ 0xfdfba18b , 0x78b30ff3 , 0xe7a34f5e , 0x23034ce0 , 0x53efee8c , 0x95508db0 , 0xc1ba95fd , 0x1113a43c ] )
+  , Mont::unsafe_make( [ 0x68c8156e , 0xb1377193 , 0xfab9416f , 0x0cbbb7ec , 0x139b7764 , 0xafc4d308 , 0x9734285d , 0x2d34591b ] )
+  , Mont::unsafe_make( [ 0x71f18357 , 0x5b56dbae , 0x982268a9 , 0x2ddfe319 , 0xa20a5f32 , 0x5d9a243f , 0x0a79ef54 , 0x27a550fc ] )
+  , Mont::unsafe_make( [ 0x8e917bcd , 0x2ffddd22 , 0x7b9720eb , 0xb216c27c , 0xfe5c4d7b , 0x5ae51114 , 0x0bb67323 , 0x1e615175 ] )
+  , Mont::unsafe_make( [ 0xf5fa26a6 , 0xd0747aa0 , 0x99584702 , 0x6a8a25b0 , 0x77e3ad15 , 0xaef044d7 , 0x219cd40a , 0x2b7efeb9 ] )
+  , Mont::unsafe_make( [ 0x58f4d46b , 0xbe0f9c45 , 0xfdda9097 , 0x9035f993 , 0x9df85dd0 , 0x78baccb7 , 0xaa098aab , 0x29e9ea9d ] )
+  , Mont::unsafe_make( [ 0xd720294c , 0xa0700ca7 , 0x617730a2 , 0x19db272a , 0x927584ae , 0xb6cbad3f , 0xeb698d0f , 0x228130a4 ] )
+  , Mont::unsafe_make( [ 0x0c7b2cbe , 0x28c33912 , 0xa48dc8d9 , 0x0582a7fd , 0x841e04f1 , 0x21adb08e , 0xebfd9937 , 0x14ca95ee ] )
+  , Mont::unsafe_make( [ 0x2929d8e6 , 0xdd1d335f , 0x61352e9f , 0x3dec231c , 0xeb652224 , 0x78125570 , 0xfce88004 , 0x11b91212 ] )
+  , Mont::unsafe_make( [ 0x8ab0c4e0 , 0x2e0a42ec , 0x513abd99 , 0x64229ad1 , 0xd00345e6 , 0x330eac01 , 0x2ff7c5f2 , 0x07570d31 ] )
+  , Mont::unsafe_make( [ 0xa3301ce4 , 0x1f798ded , 0x0e44e0b0 , 0x250d9250 , 0x352dffdd , 0x6a26cf97 , 0xefaed7f0 , 0x095e93ab ] )
+  , Mont::unsafe_make( [ 0xd6b0b27a , 0x25c739c1 , 0x36ba56c1 , 0xa468ff07 , 0x6303e29f , 0x6e1b101d , 0xafee9b01 , 0x138167bf ] )
+  , Mont::unsafe_make( [ 0x15dabcc8 , 0x2f2664c4 , 0xdefd611f , 0xc6f10b16 , 0xe8d1d450 , 0x8e9a2041 , 0x5e9c3a0b , 0x2d8409ad ] )
+  , Mont::unsafe_make( [ 0x986c493d , 0x91ea7bc3 , 0x144d8a43 , 0xf6772939 , 0x8855b099 , 0xe4d878e9 , 0x383d6c52 , 0x2fd4bdb3 ] )
+  , Mont::unsafe_make( [ 0x4646f2b4 , 0x782f7f2d , 0x9d438c6d , 0x89053de6 , 0x70377720 , 0xd2286648 , 0x26c8bb72 , 0x1e992f53 ] )
+  , Mont::unsafe_make( [ 0xd7f2e5f7 , 0xf5ca5b9d , 0x09d1153f , 0xdbd55a33 , 0x2c9ec2a3 , 0x1702f533 , 0xbaf8410a , 0x10712d52 ] )
+  , Mont::unsafe_make( [ 0x91ca03b0 , 0xcf59401c , 0x4077e0f1 , 0x2d48d645 , 0xf4b8869b , 0x5510d559 , 0x5db5fe72 , 0x22d3557f ] )
+  , Mont::unsafe_make( [ 0xd1fb7f60 , 0xb8326e66 , 0x227950ad , 0x36b9ab0d , 0x6c760226 , 0x4934c3dc , 0x90407c35 , 0x2dc1a807 ] )
+  , Mont::unsafe_make( [ 0xca4e40ef , 0x54a17783 , 0x6acee449 , 0x3d7ee6f4 , 0xdca2967c , 0x85686f65 , 0x692f929b , 0x009b66da ] )
+  , Mont::unsafe_make( [ 0x4a35e053 , 0x70487572 , 0xa9d693f1 , 0xc9942657 , 0x3d64a9c1 , 0x5d843474 , 0xeb4854e0 , 0x074771ba ] )
+  , Mont::unsafe_make( [ 0x00aab2f9 , 0xd67269c2 , 0x87b12a59 , 0x9eee0a91 , 0xfd05d794 , 0x4f1184a6 , 0xd0ad7c66 , 0x2b09e1b9 ] )
+  , Mont::unsafe_make( [ 0x5d175018 , 0x1f8bdea1 , 0xb39198f6 , 0xbc08d850 , 0xc857c1c7 , 0x3a39636a , 0x07b03bb7 , 0x0e3064d8 ] )
+  , Mont::unsafe_make( [ 0xf17f49e8 , 0xe0e9d1d5 , 0x2aedb596 , 0xd0bea33d , 0x393c8dca , 0x814b1e8b , 0xd3d9b5ea , 0x0eb6913d ] )
+  , Mont::unsafe_make( [ 0x650318f9 , 0x5efcd05c , 0xec05fbcb , 0x96878235 , 0xd995d755 , 0xad047950 , 0xb4654ac3 , 0x11461707 ] )
+  , Mont::unsafe_make( [ 0xcd17e47e , 0x21d4dbca , 0xe732d25f , 0xdd023808 , 0xe1254ba4 , 0xf9986aab , 0x16fa8464 , 0x143c38cb ] )
+  , Mont::unsafe_make( [ 0x39abf2c2 , 0x5b6963b9 , 0x13119197 , 0x5b677cb1 , 0xbfdbdb89 , 0x75763dd8 , 0x5ae9131f , 0x1cb51042 ] )
+  , Mont::unsafe_make( [ 0xf4ef16cd , 0xf710d0ad , 0xaae28acc , 0x7cef8a20 , 0xda422e8d , 0xd3e0ebcd , 0xeba72fc6 , 0x12610138 ] )
+  , Mont::unsafe_make( [ 0xe210e7d9 , 0x2514c37b , 0xb5638624 , 0x50228b87 , 0xe189229e , 0x191a8b69 , 0x01b75215 , 0x02085f38 ] )
+  , Mont::unsafe_make( [ 0x2b61570a , 0xd6e862df , 0xbb8185c0 , 0xe8f853e4 , 0x1ff0eec0 , 0x0a677fa6 , 0x58318709 , 0x2e47fb2a ] )
+  , Mont::unsafe_make( [ 0xa83639ca , 0xcce5f389 , 0x761bbbb3 , 0x0bbb99f3 , 0x465359e9 , 0xddd678d2 , 0x19654eaf , 0x2ba31ac4 ] )
+  , Mont::unsafe_make( [ 0x4a766e83 , 0xbb9b6847 , 0x4b35a226 , 0x436ec117 , 0x5513cb3b , 0x2de4909a , 0x0dc81720 , 0x2bd94563 ] )
+  , Mont::unsafe_make( [ 0x1fc54377 , 0x6d59a261 , 0xe3cc71e9 , 0x5813a3ae , 0xd0f1a89c , 0xc7a3c39f , 0x2ce8bffb , 0x18a1fc5d ] )
+  , Mont::unsafe_make( [ 0x7c26a824 , 0x6bee2617 , 0xc1c53c6c , 0x1099145e , 0x661f3fdd , 0x6d9fbc67 , 0xf4b0e748 , 0x121a7424 ] )
+  , Mont::unsafe_make( [ 0x745fcebd , 0xf9d6f7ec , 0x288a6646 , 0xdb442902 , 0x542cd314 , 0xd40ed8e8 , 0x3566d762 , 0x1dd3c1bc ] )
+  , Mont::unsafe_make( [ 0x6948ebd9 , 0x00da5497 , 0x4c0fc578 , 0x63b8b21f , 0x9feab707 , 0x2bc4d56f , 0x27050e0e , 0x0a6ef2d5 ] )
+  , Mont::unsafe_make( [ 0x06e0ddb5 , 0x8b6b2902 , 0x33cf1e5d , 0x60b50d22 , 0x11de0902 , 0x73122bc5 , 0x7964aeff , 0x2a91031e ] )
+  , Mont::unsafe_make( [ 0xba4210bd , 0x7adc960f , 0xe87d78ef , 0x1e4960a7 , 0xe4266f73 , 0x15c0a380 , 0xfa64cbdc , 0x11bca966 ] )
+  , Mont::unsafe_make( [ 0xcc5cbb54 , 0x963e0a62 , 0xf4c8047b , 0x573f43d0 , 0xd87a75c5 , 0xbd9c9437 , 0xd0b96ad9 , 0x1a3d6e64 ] )
+  , Mont::unsafe_make( [ 0x7da24526 , 0x3b4b2da9 , 0xce751472 , 0x7b1801d8 , 0x5e39c247 , 0x98c1cee8 , 0x2ef5089c , 0x2575d165 ] )
+  , Mont::unsafe_make( [ 0x6dc37af6 , 0x6606cc9a , 0x63149084 , 0xa385f54e , 0xf685ae01 , 0x5a8ae1f0 , 0x2c7a0d73 , 0x03bb40b5 ] )
+  , Mont::unsafe_make( [ 0x72a80a19 , 0x900dd766 , 0x1befaeb2 , 0xd54f231f , 0xd6ca3565 , 0x294ce75d , 0x981fe19a , 0x12931fdf ] )
+  , Mont::unsafe_make( [ 0x7353eec9 , 0x3f17871b , 0x4cac1ef0 , 0xfed7cec3 , 0xe3357d65 , 0xf39e24c0 , 0x449fdde1 , 0x1c6bec56 ] )
+  , Mont::unsafe_make( [ 0x84a34b95 , 0xd44d4360 , 0xa717b504 , 0x42d81150 , 0xf55ce1ff , 0x96efdf08 , 0xd9ee70d4 , 0x240a5429 ] )
+  , Mont::unsafe_make( [ 0x63589645 , 0x577eb689 , 0x29c2497c , 0x8223a34d , 0xab3fef75 , 0xd912639d , 0x73327ace , 0x19a130c2 ] )
+  , Mont::unsafe_make( [ 0x3a9ead34 , 0x6fa0f6d7 , 0xdee889d9 , 0x6d46ca7a , 0xfdb09288 , 0xa25a6c6f , 0xdf6eaeca , 0x1f9d803d ] )
+  , Mont::unsafe_make( [ 0xb92ea8be , 0xd32a4dfd , 0x1a29f934 , 0x1ba1e206 , 0xdcad5687 , 0x017cab28 , 0x78f8d7d7 , 0x163b52ce ] )
+  , Mont::unsafe_make( [ 0x9dbb83b6 , 0xd81098ab , 0xd2fa7754 , 0x496b965a , 0x836808e6 , 0x39cf066c , 0x2070b3cb , 0x04bce695 ] )
+  , Mont::unsafe_make( [ 0x782bb0ee , 0x714c05f1 , 0x51bb8256 , 0x7264c023 , 0x70817c33 , 0xef2131f4 , 0xe0f05b88 , 0x0c2d9ded ] )
+  , Mont::unsafe_make( [ 0xdd1c12cd , 0x310cad98 , 0x85eb6102 , 0xbf7085e0 , 0x697a315a , 0x27486828 , 0x86edbaf9 , 0x0306f58b ] )
+  , Mont::unsafe_make( [ 0x3859d972 , 0x004c92e4 , 0xc5e09531 , 0x50be356d , 0x8988fbcb , 0xe3e536cb , 0x86310571 , 0x17f60b99 ] )
+  , Mont::unsafe_make( [ 0x1476ed9c , 0xc49e3df8 , 0xaa22b11e , 0x9cfd6dc1 , 0xb796a733 , 0x4b12c0c0 , 0xd28b240c , 0x10541c84 ] )
+  , Mont::unsafe_make( [ 0xbfa6b4ce , 0x62fd9d06 , 0x2b706c8b , 0x8f4c2ec4 , 0xb4d7aaa8 , 0x1aa168b1 , 0x58c926a8 , 0x2763679a ] )
+  , Mont::unsafe_make( [ 0xe669fdbf , 0x1caf785f , 0x63017c9c , 0xf0387049 , 0x6d36958c , 0xef89e62b , 0x50fcd877 , 0x1f416143 ] )
+  , Mont::unsafe_make( [ 0xda6d9b00 , 0xc4c81889 , 0x088fe5d3 , 0x915c7602 , 0xcefecf13 , 0x7a5d9b33 , 0x6f69081e , 0x026ee003 ] )
+  , Mont::unsafe_make( [ 0x6e08dc65 , 0x24312c20 , 0x6ab3a20d , 0xaba4919b , 0xfe1fdb52 , 0x748894bf , 0xb0a316f7 , 0x2ecad34f ] )
+  , Mont::unsafe_make( [ 0x7cb4774c , 0xc5f266fc , 0x87b4f9c2 , 0x3b7ece89 , 0x356ac048 , 0x88557fd4 , 0x9c53517d , 0x077a8dec ] )
+  , Mont::unsafe_make( [ 0x2e22f078 , 0xd9432f5f , 0xf5ff38ae , 0x2da824e5 , 0x1bc0c7e7 , 0x776e0725 , 0x1e42e7db , 0x07f8296d ] )
+  , Mont::unsafe_make( [ 0xf1336ebc , 0xa32a1ae8 , 0xa71f28aa , 0xe7e6947d , 0xfd179ac9 , 0xd005fc28 , 0x776e6065 , 0x28987be6 ] )
+  , Mont::unsafe_make( [ 0x405564a2 , 0x12166134 , 0x4f86006a , 0x9be719d6 , 0xd154f6ca , 0x90080e6c , 0x3215ba6f , 0x11182bb2 ] )
+  , Mont::unsafe_make( [ 0xaf9855ad , 0x4a3049b2 , 0x6131a95a , 0x35ead1c7 , 0x38df159b , 0x3923a8da , 0xfd3a8c27 , 0x132d4749 ] )
+  , Mont::unsafe_make( [ 0x7631b617 , 0xc2cad3a7 , 0x9e3646cd , 0x61d70c62 , 0xc021e053 , 0x96203233 , 0xf25f63b7 , 0x108219ff ] )
+  , Mont::unsafe_make( [ 0xeca62b0f , 0x0d236ec3 , 0x1b6c9ca2 , 0x866b4a2b , 0x5ebb8e6e , 0xafb4831f , 0xec712d7b , 0x1b2e1eb9 ] )
+  , Mont::unsafe_make( [ 0xa7de3b2f , 0xce76012c , 0xfb1e5012 , 0x378d26c0 , 0x172f552e , 0x9c1df7c2 , 0x127e01b2 , 0x1864ae43 ] )
+  , Mont::unsafe_make( [ 0x184f9605 , 0x61dcbd51 , 0xd975d004 , 0x48480ec3 , 0xa1f5fd7e , 0xd34cb3e9 , 0x1232947c , 0x2c770483 ] )
+  , Mont::unsafe_make( [ 0xa8961841 , 0x9ed88272 , 0xea518c61 , 0x2853e1b3 , 0x0d7aaebf , 0xe4b683fd , 0xed8caa35 , 0x10a93d48 ] )
+  , Mont::unsafe_make( [ 0x844ad165 , 0x7d63bc11 , 0x910c5696 , 0x8431d114 , 0x5a870aff , 0xcbe570f2 , 0x9eab9ef5 , 0x1e140d5c ] )
+  , Mont::unsafe_make( [ 0x7392552f , 0xaddf8a8c , 0x1654d456 , 0xc02b3502 , 0x9b5a6d86 , 0x704e1cac , 0x7c497d75 , 0x03949e23 ] )
+  , Mont::unsafe_make( [ 0xd72c1896 , 0x7509bd36 , 0x33230efb , 0xf514760a , 0x69b1db38 , 0x5d661768 , 0x6a1f525b , 0x1b7aeb23 ] )
+  , Mont::unsafe_make( [ 0x51043651 , 0xe593599c , 0x1e992194 , 0x18054fac , 0x0704cbff , 0xbcaab4f5 , 0x65208cc1 , 0x2af2f8e0 ] )
+  , Mont::unsafe_make( [ 0x6fedf1a0 , 0x9c5c4817 , 0xe267cc16 , 0x372948d0 , 0x239ee40d , 0x2a04449f , 0x63149572 , 0x2f646846 ] )
+  , Mont::unsafe_make( [ 0x0ddadba3 , 0xf68e3f96 , 0x6aefe8df , 0xe5ae3b2e , 0xa223ef6a , 0x26a54bc6 , 0x35c7380f , 0x19297744 ] )
+  , Mont::unsafe_make( [ 0x2d83e224 , 0xbbb232d3 , 0x77603316 , 0x27f71db3 , 0x8b755a2a , 0x944af798 , 0x1f1d01ab , 0x166f8f89 ] )
+  , Mont::unsafe_make( [ 0x8fefd36d , 0x3b8bf7dc , 0x27166f7f , 0xd4330440 , 0x1f02bc84 , 0xa197bc1c , 0x2a3c00f9 , 0x0961d5c7 ] )
+  , Mont::unsafe_make( [ 0x63fc1bac , 0x0608deb3 , 0xd64264a7 , 0x81b1f5f7 , 0x1ddc77de , 0x86786d6c , 0x7066cb86 , 0x20a34a85 ] )
+  , Mont::unsafe_make( [ 0x1355eceb , 0xe43d5e95 , 0x6e853580 , 0x7b16fa2a , 0x0f045a4d , 0x301a94cd , 0xd2d09197 , 0x20033d50 ] )
+  , Mont::unsafe_make( [ 0xae043d73 , 0x40574f81 , 0xa1fdd29d , 0x5e1b529d , 0x0b0c0000 , 0x3dd71b02 , 0xaef1cc98 , 0x07e78d4f ] )
+  , Mont::unsafe_make( [ 0x7311987f , 0xea907184 , 0x9641d43f , 0xbfa0c3b9 , 0x496ee720 , 0x0967b8e5 , 0xf9241245 , 0x1ae61402 ] )
+  , Mont::unsafe_make( [ 0x8ec085da , 0x72065688 , 0x814fa9e7 , 0xfbe80338 , 0x4086b8ca , 0x1c82772f , 0xf6758a96 , 0x16ee8ed7 ] )
+  , Mont::unsafe_make( [ 0x1d02deb3 , 0x7776ec8a , 0x8bd7ae34 , 0xb8625ed1 , 0x7358b6f3 , 0x547e8568 , 0x54bb5515 , 0x0a3ba485 ] )
+  , Mont::unsafe_make( [ 0x923b32aa , 0xa3dbd621 , 0xb39fcc73 , 0x285d7e0a , 0x6d237407 , 0x2448498d , 0x594baba2 , 0x216a61cf ] )
+  , Mont::unsafe_make( [ 0x8a7ae3dd , 0x49169aaa , 0xb649622a , 0x037951ae , 0x8977aa58 , 0x60e0e784 , 0xf0ae363a , 0x09b0b8c9 ] )
+  , Mont::unsafe_make( [ 0xecedf4f0 , 0xda40e9fd , 0x049b346e , 0xab944dc7 , 0x5b5f3c34 , 0xefed5517 , 0xe0ddc4a1 , 0x14fa76d2 ] )
+  , Mont::unsafe_make( [ 0x207cea78 , 0x92486ed8 , 0x54b4cfa7 , 0xb0b3239a , 0xcc4ff038 , 0x986d4496 , 0x0b9cf938 , 0x2b555464 ] )
+  , Mont::unsafe_make( [ 0x51e0064e , 0xed07ce93 , 0xdd78c3aa , 0xf73763c1 , 0x46718f4d , 0x16181ad9 , 0xa0fcb3be , 0x1904206f ] )
+  , Mont::unsafe_make( [ 0xf654e4bb , 0xec92a63c , 0xb4b8acfe , 0xa4e1989f , 0x562f27d4 , 0x3f9559a4 , 0x102f2d08 , 0x2d9a30d3 ] )
+  , Mont::unsafe_make( [ 0xdb3b9855 , 0xf3a47568 , 0x3ce2d8bd , 0x54cef762 , 0xbef1e811 , 0x3d222b02 , 0x77f49d4f , 0x27e9a58d ] )
+  , Mont::unsafe_make( [ 0x1ab7d85f , 0xb46c6c6b , 0x8d2fa122 , 0xb305b0cc , 0xc6257369 , 0xb67a90ab , 0x13f924db , 0x145e6a4d ] )
+  , Mont::unsafe_make( [ 0x4e8f532b , 0x5aec5512 , 0xc40ecd45 , 0xb48bd453 , 0xe05218e0 , 0x47907af5 , 0xc14d09c3 , 0x0ae50efc ] )
+  , Mont::unsafe_make( [ 0xd3c69e7c , 0x12142f45 , 0x4e60df77 , 0x4125689e , 0x5317d189 , 0x9d9ba87e , 0x92d82780 , 0x0f60cc73 ] )
+  , Mont::unsafe_make( [ 0x59b607e8 , 0x01049aa2 , 0x7fe3dd3c , 0x8119edae , 0x59aba048 , 0xf49aa0cc , 0x3df99f38 , 0x2314a2e5 ] )
+  , Mont::unsafe_make( [ 0x08a80f53 , 0x0872479b , 0xcb91e8fa , 0x2fc416a4 , 0x0f66e552 , 0xb73bdda0 , 0x12e8ba12 , 0x1898e050 ] )
+  , Mont::unsafe_make( [ 0x869d3613 , 0x4a0251c0 , 0x0f886ce0 , 0x2aabb06c , 0xc9f8ef75 , 0xa745e1f6 , 0x4d3c3735 , 0x04196b2e ] )
+  , Mont::unsafe_make( [ 0x99384885 , 0x45c79200 , 0x8deab73e , 0x8a26aff7 , 0xd45e12d4 , 0x3b4173d7 , 0xd37f1569 , 0x10c9335d ] )
+  , Mont::unsafe_make( [ 0x6982ff8f , 0xa2b98b74 , 0x0e8db93f , 0x86fd8b6e , 0xbd2a91ee , 0xec6afdaf , 0x0dc64709 , 0x2a28fd75 ] )
+  , Mont::unsafe_make( [ 0xe1857c14 , 0x0e345583 , 0x8a9541bf , 0xee6d9e66 , 0x41aa4411 , 0x47d162c1 , 0x962c2404 , 0x20a72be4 ] )
+  , Mont::unsafe_make( [ 0x301a0d49 , 0x4f2402f6 , 0x3b0c47c6 , 0x72556581 , 0xf15ee7d1 , 0x98e128e0 , 0x17905a76 , 0x1c5cdfec ] )
+  , Mont::unsafe_make( [ 0xfc8bba7a , 0x77fca49d , 0x9156f581 , 0x7ff0e80c , 0xdecf6e5d , 0xc3aad43b , 0xf84109b0 , 0x08586ef5 ] )
+  , Mont::unsafe_make( [ 0x2935aff0 , 0xfb29cde0 , 0x0a75669f , 0xe0026a91 , 0xe8f26ff8 , 0xc362f48b , 0xccfcdbee , 0x050ae5bb ] )
+  , Mont::unsafe_make( [ 0x5b2bd795 , 0xfb63d040 , 0xdb4e9b06 , 0x9d8f4e81 , 0x3c6ba706 , 0xc641d435 , 0x7236fba5 , 0x1b722a36 ] )
+  , Mont::unsafe_make( [ 0xd21617fd , 0xdf9a97e7 , 0x2a827f71 , 0x8df52e68 , 0x7e5e1a58 , 0xc0672014 , 0x737ba68d , 0x025a37c8 ] )
+  , Mont::unsafe_make( [ 0x53c8331a , 0x11f0a5de , 0x102d4020 , 0x2b717097 , 0x82fa65a6 , 0xed3fe088 , 0xdf139cb2 , 0x01549fea ] )
+  , Mont::unsafe_make( [ 0x4d1d157e , 0x89b9fb83 , 0xfa065e94 , 0xab0e6843 , 0x16054b7f , 0x1ac6c54f , 0x5c16c535 , 0x2185dc80 ] )
+  , Mont::unsafe_make( [ 0x3c8de9a6 , 0xc31151ac , 0xbfe9e76c , 0x1e852014 , 0xe99881f4 , 0x5003d365 , 0xe2a138e9 , 0x0e5f31cb ] )
+  , Mont::unsafe_make( [ 0xfab792b8 , 0xc5e8ea19 , 0x39439fcc , 0xc6079219 , 0x752af6b5 , 0x9f014a43 , 0xc9050a6a , 0x3038db43 ] )
+  , Mont::unsafe_make( [ 0xa9ffab6c , 0x910ed239 , 0x24648d46 , 0x305f261c , 0xc2bf78df , 0x6d6bc1f8 , 0xbe1d07e4 , 0x075c14c8 ] )
+  , Mont::unsafe_make( [ 0x0e7ebbfa , 0x5a1f0de3 , 0x250f606b , 0x07bdf7da , 0xe16953fc , 0xad4be9ca , 0x6adf3f97 , 0x0c37bda7 ] )
+  , Mont::unsafe_make( [ 0x16340011 , 0x2033396e , 0x17c313ed , 0x855b16e1 , 0x161d30e1 , 0x110700d8 , 0xa1900b5b , 0x240b298c ] )
+  , Mont::unsafe_make( [ 0x40737692 , 0x3500af90 , 0xde525114 , 0xa00a6a4c , 0x714937d3 , 0xe039d1a3 , 0x3c23403d , 0x1768b3ed ] )
+  , Mont::unsafe_make( [ 0x41d2ac26 , 0x624b4331 , 0x046ba9a4 , 0xde6a1e0d , 0x9505f5ea , 0x02a6afd8 , 0xd9793369 , 0x2497bded ] )
+  , Mont::unsafe_make( [ 0x4c35f8b1 , 0x5fb213fb , 0x13a74081 , 0x171a5c7f , 0x6b872667 , 0x8bac6645 , 0xbccf90a7 , 0x28e6cda2 ] )
+  , Mont::unsafe_make( [ 0x952920c2 , 0x18fa86f7 , 0x43e26cca , 0xcaf81a09 , 0x6940925f , 0xe76efb94 , 0xa31b3857 , 0x2ce6c1d1 ] )
+  , Mont::unsafe_make( [ 0xe9b92165 , 0x992e7cb7 , 0x97c3bab8 , 0x5b5785a7 , 0x1777c4a5 , 0xcc0d2d84 , 0x3deac721 , 0x24c640bd ] )
+  , Mont::unsafe_make( [ 0xaa21028c , 0xfc139a1c , 0xec3a5c96 , 0x531799c6 , 0xcdb1e6e6 , 0xbabf1cee , 0x98141103 , 0x06946b0b ] )
+  , Mont::unsafe_make( [ 0xb4aa6279 , 0x9554f6db , 0x5b0223bd , 0x1397e90b , 0xe2127883 , 0x9f835fdf , 0xe5a6387d , 0x1c15a08a ] )
+  , Mont::unsafe_make( [ 0x6d56ebe9 , 0xf13b2ba1 , 0x8cc27840 , 0xfca768fa , 0x588a4c8e , 0xbac0ddd7 , 0xddc6ff5d , 0x2275ffd3 ] )
+  , Mont::unsafe_make( [ 0x56d195cc , 0x1a5aced6 , 0xbf2152fa , 0x79e4a9b9 , 0x4e3cd0c8 , 0x2540b6df , 0x66c4e4ad , 0x0f08c6ff ] )
+  , Mont::unsafe_make( [ 0xad52b22f , 0x904a0687 , 0xb2e789b9 , 0x9f240f05 , 0x607a6f6e , 0x19ee6a1b , 0x8d9375a6 , 0x2454bfc4 ] )
+  , Mont::unsafe_make( [ 0x614e3111 , 0x0e7a938c , 0x3599c49a , 0x8e613324 , 0x49301fac , 0x7eb5071e , 0xa2e2bdaf , 0x1d42aa03 ] )
+  , Mont::unsafe_make( [ 0x00fd86f0 , 0x868b4dbd , 0x7628bbf1 , 0x15b10cf5 , 0x73b276b0 , 0xf5ed8366 , 0x9c43e15b , 0x1f419452 ] )
+  , Mont::unsafe_make( [ 0xcd1e9320 , 0xa9da20db , 0x435168aa , 0x517d422d , 0x433f1b21 , 0xd11aa404 , 0xe7ee88b7 , 0x2455ec70 ] )
+  , Mont::unsafe_make( [ 0xd6f2484a , 0xa727f845 , 0x56a1ea47 , 0xe78849a4 , 0xbee5b1ba , 0x534ec14a , 0x27e2c8b5 , 0x2f26cfb7 ] )
+  , Mont::unsafe_make( [ 0xed25e4de , 0x6708683f , 0xb51308d9 , 0x5be0b63d , 0xb4f0b065 , 0x29db0f05 , 0x617669d8 , 0x2be22343 ] )
+  , Mont::unsafe_make( [ 0xd228c428 , 0x0a6ce56b , 0x9c082fb6 , 0xa28ecaa8 , 0x3d46898f , 0x83638914 , 0x5d1256ea , 0x24215a9a ] )
+  , Mont::unsafe_make( [ 0x7188b6b4 , 0xf7791a1a , 0x684cba8f , 0x3c8820fb , 0xba122c0f , 0xe7c3bf07 , 0x78456cff , 0x2f0d166b ] )
+  , Mont::unsafe_make( [ 0xf8fe695c , 0x574d746c , 0x9ffd27c4 , 0xfaa2b9a8 , 0xf3087e36 , 0x1bf0358a , 0x185ca6ce , 0x11c8c8d6 ] )
+  , Mont::unsafe_make( [ 0x1ec8680c , 0x2a234435 , 0x4689b6af , 0x59396320 , 0x22be61f0 , 0xedab722b , 0xc08d9651 , 0x21202da2 ] )
+  , Mont::unsafe_make( [ 0xf1267366 , 0x7bb5a456 , 0x46bd9d68 , 0x87c9c2d9 , 0xb9dbe7bc , 0x0ebf4b24 , 0x99e1787e , 0x1bed7e9e ] )
+  , Mont::unsafe_make( [ 0xc717df4e , 0xee9853dd , 0x7cc7353b , 0x4cd467cd , 0x44f8e2d5 , 0x9e05ef12 , 0xfc9a2455 , 0x0f3d434a ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// external mixing matrix (`t x t`, flattened)
+pub const CONST_M: [Mont; 256] = 
+  [ Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xadb4bb64 , 0xcf003941 , 0x9c00ef62 , 0x0b536aec , 0xfcda8c06 , 0x98e9e7e9 , 0xf19ba2e3 , 0x05e40b24 ] )
+  , Mont::unsafe_make( [ 0xdb41eb8e , 0x086fc9ef , 0x33110c66 , 0x31f89418 , 0x6ebc1440 , 0x4ff33452 , 0x4c3ccc33 , 0x2288c1d3 ] )
+  , Mont::unsafe_make( [ 0xf40a8298 , 0x9508d4c9 , 0xdc434b12 , 0x2f99a8ef , 0xfbe96175 , 0x5c07d023 , 0xdccc6e78 , 0x1bfd22d1 ] )
+  , Mont::unsafe_make( [ 0xfb4769d9 , 0xe43670b9 , 0x5d4661f1 , 0x934350fd , 0xe79f7adf , 0x52d1bd3b , 0x77a938e4 , 0x1c4f9d0d ] )
+  , Mont::unsafe_make( [ 0x4db3558e , 0xb68f3047 , 0xec6788c8 , 0x6abda75f , 0x3a1ae09f , 0x7d159d33 , 0x5bb0418e , 0x209e5dc8 ] )
+  , Mont::unsafe_make( [ 0x22d90dfe , 0x46ad5cb9 , 0x84be067f , 0xa14e95d8 , 0xda24d6a9 , 0x373f9198 , 0x8c72307b , 0x006babe7 ] )
+  , Mont::unsafe_make( [ 0xe9f4d4c9 , 0x6fc78c9c , 0x811ffe0e , 0x743a7e61 , 0x9c03e6be , 0xa65bb545 , 0x24198060 , 0x1c0ced17 ] )
+  , Mont::unsafe_make( [ 0xa33dde37 , 0x2120f422 , 0x7296f13a , 0xda3f2ef1 , 0x884774b5 , 0x8903531f , 0xbd67a346 , 0x22ddbbc9 ] )
+  , Mont::unsafe_make( [ 0x524d51b7 , 0xa5f4c0c6 , 0x1c00e95d , 0x28a6595f , 0xd8257c48 , 0x6947e15c , 0x1b0cef6d , 0x0cdee2e5 ] )
+  , Mont::unsafe_make( [ 0x4bacca35 , 0xb13831b9 , 0xc8779548 , 0x1be2d0e0 , 0x6ee7716f , 0xc6632e87 , 0xc3f1be77 , 0x1f4de9e7 ] )
+  , Mont::unsafe_make( [ 0x255c8fbb , 0xc3692acc , 0x6adabb55 , 0x3890938f , 0x61c35ba2 , 0x245efac2 , 0x9e06b8c1 , 0x1e584b75 ] )
+  , Mont::unsafe_make( [ 0x3a579f4f , 0xddba383b , 0xfbb01c55 , 0x56959efe , 0x61f75005 , 0xfd12bbf5 , 0xbd5f4906 , 0x06043ef1 ] )
+  , Mont::unsafe_make( [ 0x600f2236 , 0x56c8bcbc , 0x569bdbf2 , 0x5e8ed197 , 0x8ea66274 , 0xf22dffc2 , 0x979a0e9f , 0x2e208d89 ] )
+  , Mont::unsafe_make( [ 0x012b8c40 , 0x98107402 , 0x5b9049cb , 0x0aae1f09 , 0xd4185c59 , 0x5cbb088c , 0x5d308528 , 0x14be80b0 ] )
+  , Mont::unsafe_make( [ 0x0a53ec67 , 0xedbd2289 , 0x574f1f90 , 0x5c9a266f , 0x94778748 , 0x2d6734bc , 0xcd7b4a11 , 0x1b3feae7 ] )
+  , Mont::unsafe_make( [ 0x3549abe5 , 0x444be23c , 0xb76ad56f , 0xe13a67c9 , 0x88ea27e0 , 0xbcfe1a94 , 0x8324272c , 0x2ad89c3f ] )
+  , Mont::unsafe_make( [ 0x9316da58 , 0x1ee960d1 , 0x24107ab9 , 0xe1379873 , 0x00fb136d , 0x33f42276 , 0x66e82d11 , 0x29fd2996 ] )
+  , Mont::unsafe_make( [ 0x0c5d5e7b , 0x7d2ec069 , 0x3548e1d4 , 0xa265a2f6 , 0x96767094 , 0xf9fb7545 , 0x2505a380 , 0x28aa8994 ] )
+  , Mont::unsafe_make( [ 0x732a1a1c , 0x647c292e , 0xfdc4743c , 0x82809e7b , 0x9405c48b , 0x888348f0 , 0x4c15ce00 , 0x05865867 ] )
+  , Mont::unsafe_make( [ 0x64c608c3 , 0x9d7f4424 , 0xfe942f70 , 0x3e60cbd6 , 0xf91fe99d , 0x9b9cd084 , 0x7fb4fd12 , 0x02998d32 ] )
+  , Mont::unsafe_make( [ 0x8317dfb1 , 0x58cf2472 , 0x86921490 , 0x2c541f53 , 0x7cdbc8a3 , 0x610f2520 , 0x42e20437 , 0x00f74d1f ] )
+  , Mont::unsafe_make( [ 0x870afb7e , 0x99a71e3c , 0x1eb3aaa8 , 0xe75d970d , 0xedbc1773 , 0x6bbd4e34 , 0x45f1c8c7 , 0x30282d06 ] )
+  , Mont::unsafe_make( [ 0x350542ea , 0x3f84fcab , 0xc245412c , 0x80969aa6 , 0xc4a757eb , 0x99ad2d1f , 0x9a8c426e , 0x0b29b985 ] )
+  , Mont::unsafe_make( [ 0x9c673f61 , 0x7d768fc6 , 0x2b1c91c7 , 0x90ab43c6 , 0x902f60ef , 0x815a7ae6 , 0xce32c6ff , 0x06512027 ] )
+  , Mont::unsafe_make( [ 0x84ad0796 , 0x60b34f1c , 0x4849dafa , 0x717d68ab , 0x04a61a3a , 0x7136738e , 0x12fcecba , 0x1f2f970b ] )
+  , Mont::unsafe_make( [ 0x0c1f9cf4 , 0x86dd0ec1 , 0xaf80fedd , 0xdad31108 , 0xb25a039a , 0x669ae3e3 , 0x1eb688c6 , 0x19881b7f ] )
+  , Mont::unsafe_make( [ 0x0a7bceab , 0x829fd31b , 0x1b655365 , 0xc6e31783 , 0xc9297a7e , 0xefc4c72c , 0xf76d18a0 , 0x112aef77 ] )
+  , Mont::unsafe_make( [ 0x2ec459a3 , 0xf0ad2d33 , 0xb67a5174 , 0x4b9bc6cc , 0xfda74f4b , 0xc3e115c8 , 0xc274684a , 0x00b9db9c ] )
+  , Mont::unsafe_make( [ 0xa6c08aac , 0xe6bd7a53 , 0x0e760f5e , 0x3879fe45 , 0xf27a53ac , 0xc0a770f8 , 0xc5a0d704 , 0x25541cfe ] )
+  , Mont::unsafe_make( [ 0xc763162f , 0x94332167 , 0xcf0cc4a2 , 0x9ee2b327 , 0x07f1871b , 0x9c8e2e80 , 0x13de0fe8 , 0x03ae4028 ] )
+  , Mont::unsafe_make( [ 0x3466831b , 0x8c6e305e , 0x90bebc4a , 0x881d6287 , 0xfa604d8a , 0x48949dc1 , 0xa49d9954 , 0x088fe7f1 ] )
+  , Mont::unsafe_make( [ 0xdddf5d9b , 0x1eee4fab , 0x7c60b1e2 , 0xd40a1486 , 0x05ef6a7d , 0xbb394405 , 0x849e781d , 0x03a4dddf ] )
+  , Mont::unsafe_make( [ 0x793649a9 , 0x48f3b41f , 0x0db8a26e , 0x323d9490 , 0xd4b8ce42 , 0x0b0fb279 , 0xc0d6da03 , 0x0d2ef940 ] )
+  , Mont::unsafe_make( [ 0x98ab2b4f , 0xee956630 , 0x6b15a8d0 , 0xc69f0827 , 0xb64a6cdb , 0xdaef755d , 0xc3ecb6cf , 0x1205d458 ] )
+  , Mont::unsafe_make( [ 0x2088df43 , 0x2780af2d , 0xe91463cd , 0x0a79e9f2 , 0xc5b4a826 , 0x4a050189 , 0xeb245aaa , 0x2866fe48 ] )
+  , Mont::unsafe_make( [ 0x8a85ecce , 0x41e89a81 , 0x431dbe77 , 0xf86649f2 , 0xd2df5ba8 , 0xa7b1ce41 , 0xa461d518 , 0x2aa16291 ] )
+  , Mont::unsafe_make( [ 0x9a45ddf2 , 0x4554417a , 0x5524966f , 0x3492e7dc , 0x07b4f414 , 0x9fdc3dea , 0xd2c1c39f , 0x280b50ef ] )
+  , Mont::unsafe_make( [ 0x1f58419d , 0xb9adbf0d , 0x23cf02e9 , 0xae881efe , 0x074ca79d , 0x5580cdd5 , 0x0e1dedde , 0x11a60213 ] )
+  , Mont::unsafe_make( [ 0x27c8bd40 , 0xbb80ed89 , 0xb81792e3 , 0x3a78e2c2 , 0xde05fc3a , 0x74a04f22 , 0xd75241c7 , 0x0d8083d5 ] )
+  , Mont::unsafe_make( [ 0x98f49b85 , 0x5d9f455e , 0x150aaedf , 0x7d44fe95 , 0x86836e0a , 0x53321a81 , 0x33e2db4b , 0x06e071b1 ] )
+  , Mont::unsafe_make( [ 0xa7571a33 , 0xc006aff4 , 0xc07aef5a , 0xd3942f5f , 0x06a99b82 , 0x0535740e , 0x222a5bcd , 0x253fc816 ] )
+  , Mont::unsafe_make( [ 0x1148d7f5 , 0x46b2336f , 0x08195c8b , 0x851ca417 , 0x2d42d881 , 0x9271e0d1 , 0x43ae9e87 , 0x12452d47 ] )
+  , Mont::unsafe_make( [ 0xbc8867ad , 0x772ef548 , 0x210455f2 , 0x06994aca , 0x8eaf497f , 0x94afb32c , 0x4e809da6 , 0x08223cef ] )
+  , Mont::unsafe_make( [ 0xa10bc2fd , 0x5afa33b0 , 0x75e57aeb , 0x28dde5de , 0x8fe7e319 , 0x5cc3d759 , 0x0406116c , 0x2ee154dc ] )
+  , Mont::unsafe_make( [ 0x9642c7fb , 0x9ef311d9 , 0x19fc9998 , 0x0b52cd0d , 0x37bdc477 , 0x35e1d9a7 , 0xe5c81926 , 0x27b182e2 ] )
+  , Mont::unsafe_make( [ 0xf52bc1c7 , 0x492da7c3 , 0xb7652fa4 , 0x72e96c43 , 0x5d8a7536 , 0xd464b711 , 0x4fcf3f3d , 0x2cad8c71 ] )
+  , Mont::unsafe_make( [ 0xd1839ff5 , 0x47eb07ea , 0x4ee3a1d6 , 0x0ccbc52e , 0xfe0cd6cd , 0x453f448a , 0x51d739f1 , 0x203c4420 ] )
+  , Mont::unsafe_make( [ 0xcad5ebbe , 0x22cf6760 , 0x776f8930 , 0x1c0266ce , 0x1bdcb1a3 , 0xcaa7fd45 , 0xd0643d43 , 0x027620c9 ] )
+  , Mont::unsafe_make( [ 0x0c349cae , 0xb1ee19bd , 0x6d605931 , 0x66984d43 , 0xfb875c70 , 0xd7148ccd , 0x47f694ce , 0x0cff4c25 ] )
+  , Mont::unsafe_make( [ 0x33ec96db , 0xaeac2ea9 , 0x1eb7ed5e , 0x43a7faa8 , 0x22f5179f , 0x1b506479 , 0xb83c3dfc , 0x04125b99 ] )
+  , Mont::unsafe_make( [ 0x2d76a9e1 , 0x86471296 , 0xf47f0f7f , 0x9969f24d , 0x245c5e80 , 0xf29e9fe2 , 0x2762341f , 0x01c3ca65 ] )
+  , Mont::unsafe_make( [ 0xf2856047 , 0xc4efc481 , 0x8a76001b , 0x6e051f8b , 0x515a27c9 , 0xf5fb8c4d , 0x881bdec1 , 0x22008ec1 ] )
+  , Mont::unsafe_make( [ 0xeb4ebe15 , 0x271b380a , 0x7bc35a5f , 0xa1122481 , 0x7a53e2a3 , 0xa559fa41 , 0xb754a201 , 0x2378b762 ] )
+  , Mont::unsafe_make( [ 0xda596a4b , 0xe75b742b , 0x7222f012 , 0xe484aa06 , 0x592aa513 , 0x35aa2c81 , 0xa6049347 , 0x1175b561 ] )
+  , Mont::unsafe_make( [ 0x5d391d86 , 0x5384ceb0 , 0xd8e72128 , 0x4705c360 , 0x86db9930 , 0x8f7abcc9 , 0x88eb8ab0 , 0x0854f137 ] )
+  , Mont::unsafe_make( [ 0xfa4e30f6 , 0xf2ef3455 , 0x63fb8abf , 0xce5ddf45 , 0x3d1c3cef , 0x87b48c73 , 0x770ce53d , 0x28da9153 ] )
+  , Mont::unsafe_make( [ 0x524c7586 , 0x184fddc5 , 0xc37bcf6d , 0x92a41bb4 , 0x63e63f32 , 0x6e4a00d5 , 0xe2bf08fa , 0x1549b257 ] )
+  , Mont::unsafe_make( [ 0xa5193675 , 0x175a90dd , 0xfd306e31 , 0xeba50634 , 0xa903ecad , 0xfe598187 , 0x12baef04 , 0x1e3a5cb3 ] )
+  , Mont::unsafe_make( [ 0x979c3b4f , 0xdd945cd5 , 0x8e6f3404 , 0x29a33dd2 , 0x1c5a1b45 , 0xed3aeaaf , 0x57add230 , 0x29f31a89 ] )
+  , Mont::unsafe_make( [ 0xae531600 , 0x1f1ccba0 , 0x293518b1 , 0xc4b304a2 , 0x33c42f5f , 0x75849821 , 0xc525b030 , 0x15e7f80b ] )
+  , Mont::unsafe_make( [ 0x73ae3d43 , 0x2d2d9284 , 0x452066ea , 0xabf6e484 , 0x78ea5291 , 0x19754bd5 , 0x5c91a770 , 0x0acde5d9 ] )
+  , Mont::unsafe_make( [ 0xd52771cc , 0x0ba45a6a , 0xbde6b7c6 , 0xcaa0390b , 0x665b069f , 0xee27b3f0 , 0xd1f51676 , 0x012a002d ] )
+  , Mont::unsafe_make( [ 0x79fc1bbe , 0x4edacf4a , 0xafcb2886 , 0x18c664b1 , 0x728e0493 , 0x00a1b678 , 0xf59e630a , 0x1f6269e3 ] )
+  , Mont::unsafe_make( [ 0x74fc28d5 , 0xb6d74214 , 0x9cf5e114 , 0x08a1375b , 0xaa420aab , 0x98cc919e , 0xfd60ad0c , 0x04dc8216 ] )
+  , Mont::unsafe_make( [ 0x121f447b , 0x10c7b6e9 , 0xddec9b98 , 0x15ff2c2e , 0xeedf2891 , 0x199b6c03 , 0x994f7b7e , 0x1821de00 ] )
+  , Mont::unsafe_make( [ 0xddf812e5 , 0xe7aac970 , 0xb64a70c7 , 0x91fe2b4c , 0x48d05699 , 0xf2cc4039 , 0x47e47ed6 , 0x109bd6d7 ] )
+  , Mont::unsafe_make( [ 0xab06df05 , 0xbdf486b7 , 0x8d3bbf6a , 0x25b70e31 , 0x70e3e9de , 0x93e3f401 , 0x7082f372 , 0x02b61eb9 ] )
+  , Mont::unsafe_make( [ 0x4a59ea8c , 0xe6fee594 , 0xc264fedb , 0x491b2717 , 0x29b2afd8 , 0xdebeacf0 , 0x0b4dd5cb , 0x0c304a2a ] )
+  , Mont::unsafe_make( [ 0x459659ff , 0xf3d5e428 , 0xe3a12ba2 , 0x1340bb07 , 0xf1b7363f , 0x36524180 , 0x4aef929a , 0x2cc8f47f ] )
+  , Mont::unsafe_make( [ 0xc6da0278 , 0x0af76875 , 0xcfeb8465 , 0x35815d94 , 0xe59e4588 , 0xf495c19f , 0x3f18a7f2 , 0x2ec1b45d ] )
+  , Mont::unsafe_make( [ 0x8d371ea9 , 0xa18cb87d , 0x5e9b368f , 0xc284ff35 , 0x6406a72f , 0x1f4f8150 , 0xe4dfe5bf , 0x29376e3a ] )
+  , Mont::unsafe_make( [ 0xea4a93d0 , 0xecc5bdb1 , 0x418141fc , 0xd3a47e26 , 0x271661e3 , 0xe0c3e469 , 0x6640511b , 0x268edfec ] )
+  , Mont::unsafe_make( [ 0xc0ffbfab , 0xdf423d76 , 0x5bf5171c , 0x77aba437 , 0xf02d1f15 , 0x1af23d12 , 0x15445bd2 , 0x2027ad8c ] )
+  , Mont::unsafe_make( [ 0x4111a73c , 0x6c44fc1a , 0xd06b2925 , 0x258d0126 , 0x10dd4785 , 0x80f06e1e , 0x26545835 , 0x25fd475a ] )
+  , Mont::unsafe_make( [ 0x7775377c , 0x07f28c7e , 0x46e08801 , 0xa10e8db3 , 0xfebf8dfe , 0x5817452d , 0xec479746 , 0x2fb67441 ] )
+  , Mont::unsafe_make( [ 0xd818d269 , 0x94ae025e , 0xab6fa135 , 0x5459ea4c , 0x28be91e8 , 0x6a2f3d54 , 0xf04785e7 , 0x1af2897c ] )
+  , Mont::unsafe_make( [ 0xc3a71f3c , 0x91f78e17 , 0x9b1ee217 , 0x59fa29e0 , 0xbecb331e , 0xabefef3c , 0xfcec7da2 , 0x249c9138 ] )
+  , Mont::unsafe_make( [ 0x34b9d386 , 0x1c077667 , 0xead0e00e , 0x1b06d822 , 0x119fd987 , 0xf72dae21 , 0xf14a784a , 0x1a990775 ] )
+  , Mont::unsafe_make( [ 0xb2baa12b , 0x254f9c34 , 0x8d491344 , 0xadde5c94 , 0x84ffefbd , 0x3319403f , 0xff2ff0c5 , 0x0c49cd79 ] )
+  , Mont::unsafe_make( [ 0x8fc6cffa , 0x00924a03 , 0xf20ca95a , 0x25913bdb , 0x7707bc78 , 0x359282e0 , 0x99ef956d , 0x2d0b9548 ] )
+  , Mont::unsafe_make( [ 0xc3bab31b , 0x702766c4 , 0x4d0bd999 , 0xff379dc7 , 0xf9e687af , 0xb9b61be8 , 0x0fe2e754 , 0x2b0dfb4c ] )
+  , Mont::unsafe_make( [ 0x588b7835 , 0xfbdf8132 , 0xd246d5ba , 0x5c3cdb12 , 0x33900b15 , 0xa2477657 , 0x8981b8d3 , 0x1390aa54 ] )
+  , Mont::unsafe_make( [ 0x79f5aa56 , 0xae8a7690 , 0x53f353ab , 0x683b666f , 0x69b631f3 , 0x1d0a5bfe , 0x2b298ee1 , 0x1415d96a ] )
+  , Mont::unsafe_make( [ 0x69335242 , 0x0e692dcc , 0xd6fbe68d , 0x44e23292 , 0xdc6e39ab , 0x6fd55c26 , 0x894baf8c , 0x005b7295 ] )
+  , Mont::unsafe_make( [ 0xf20156e4 , 0xfc696bed , 0x3a2096f2 , 0xa657a3de , 0x9a92f20b , 0x56513c83 , 0x8d3cf80f , 0x292770e5 ] )
+  , Mont::unsafe_make( [ 0x448b2907 , 0x655738d4 , 0x62122e01 , 0x0d090013 , 0x8ef36b23 , 0x9ea68f68 , 0x6ae39240 , 0x0c8a4ce4 ] )
+  , Mont::unsafe_make( [ 0x7d1c87a4 , 0x42f10c10 , 0x061617f2 , 0x8665d04b , 0x7fef493b , 0xbdc22359 , 0x47f82591 , 0x10c488e9 ] )
+  , Mont::unsafe_make( [ 0x3af8953f , 0xa903ee71 , 0x1d871a04 , 0x83bbb907 , 0xfe252c46 , 0x8bd96885 , 0xa90b79c6 , 0x05057e61 ] )
+  , Mont::unsafe_make( [ 0x9a41245e , 0x2a2bd002 , 0xffc30b77 , 0x434a3fe7 , 0x2982b5a9 , 0xd900492f , 0x7294fabb , 0x006aea0c ] )
+  , Mont::unsafe_make( [ 0xfc39cab0 , 0x5a445e57 , 0x08a9f4f6 , 0xbc73f5f5 , 0xe31af5fa , 0x2e14d563 , 0xff0db52f , 0x2d7170f3 ] )
+  , Mont::unsafe_make( [ 0x30f27430 , 0xa5ff4618 , 0x342f94fa , 0x53330667 , 0x4c3e5877 , 0x635ccb91 , 0x71853b29 , 0x2b1f3927 ] )
+  , Mont::unsafe_make( [ 0x09c236f2 , 0x036be00d , 0x6922d963 , 0x8113de7b , 0xe9842005 , 0x05f02c8c , 0x8aaa349b , 0x0de1dbf3 ] )
+  , Mont::unsafe_make( [ 0x1c0678a1 , 0xb57865a3 , 0xbc565600 , 0x28ed0fbf , 0x4d486068 , 0x622844aa , 0x56e6abae , 0x14f0859f ] )
+  , Mont::unsafe_make( [ 0x40a5b9fd , 0x729dbe73 , 0x32e7b12e , 0x95326e63 , 0x2bfb47d0 , 0xc65aea42 , 0x5515f8c6 , 0x304565cc ] )
+  , Mont::unsafe_make( [ 0xf97bc9f4 , 0xcaf70f7f , 0x10561195 , 0xfe3715ce , 0xaa61354a , 0xdeabf812 , 0xceaf42e7 , 0x14cafbb7 ] )
+  , Mont::unsafe_make( [ 0x4a3e2f4d , 0x01de9c37 , 0x85c7efb2 , 0x70b0ab71 , 0x6e6f4a74 , 0xd85bde59 , 0x8e4c123e , 0x11ae2899 ] )
+  , Mont::unsafe_make( [ 0x60f1a59e , 0x29a51c48 , 0xb8e30fce , 0xb9525239 , 0xc8b90a30 , 0xc7695246 , 0x3e2cc24a , 0x17075914 ] )
+  , Mont::unsafe_make( [ 0xe8ba6590 , 0x1e880ece , 0xb27d06c0 , 0xfa527276 , 0xe0fef3b8 , 0x57eeea7b , 0x706709c5 , 0x2434183e ] )
+  , Mont::unsafe_make( [ 0xbb860aba , 0xf496bdbf , 0x091d5d1c , 0x3e299ad8 , 0x40329cac , 0xf33b2120 , 0xd46c7c24 , 0x12ae379f ] )
+  , Mont::unsafe_make( [ 0x5ca84011 , 0x3f65ecf6 , 0xf327ffcc , 0xc9601aad , 0xe3b51900 , 0x13a1b737 , 0xdb461b22 , 0x1d678d05 ] )
+  , Mont::unsafe_make( [ 0x7ed16d09 , 0x114d1f97 , 0xe136a993 , 0x4d7f5da6 , 0x641a65c5 , 0x11be7fb1 , 0x98e44eb7 , 0x2c592a76 ] )
+  , Mont::unsafe_make( [ 0xc8fb1b0e , 0xeedd1c73 , 0x4ad2e833 , 0x1c4d7ecf , 0xf0325d5c , 0x27562a42 , 0x39125db3 , 0x26f6a06a ] )
+  , Mont::unsafe_make( [ 0x853cfb49 , 0x00192810 , 0x2030cec3 , 0xf83a3064 , 0x929d13c5 , 0xf703d1f5 , 0xce16f21a , 0x1b71f407 ] )
+  , Mont::unsafe_make( [ 0xcb2a591b , 0x14081692 , 0xfd14e55d , 0x0f231fb5 , 0xf4e4d296 , 0xa1c8874a , 0x1a09f83c , 0x00357ded ] )
+  , Mont::unsafe_make( [ 0x5c74b4ed , 0xafcccddc , 0x94dcc584 , 0x77c9f0b2 , 0xbc6a0af2 , 0xc9135e89 , 0xe70f7552 , 0x02d8ed33 ] )
+  , Mont::unsafe_make( [ 0x69062921 , 0x67fb22ad , 0x523a0fdc , 0xc3c2efeb , 0x6deddb11 , 0x2222b66b , 0x915e67c7 , 0x0ace46be ] )
+  , Mont::unsafe_make( [ 0x317cc7a7 , 0x32e38072 , 0xd14895d9 , 0xa9a36f4d , 0x1a86afcf , 0xe6496fce , 0xf441036e , 0x0d94f0bb ] )
+  , Mont::unsafe_make( [ 0x577668b6 , 0xd9c5086e , 0x9330c392 , 0xd6af7b8f , 0xe8ffda46 , 0x4bc689d5 , 0xc469f0fb , 0x033472d5 ] )
+  , Mont::unsafe_make( [ 0x1bf466e5 , 0xa25061b7 , 0x1e2bb009 , 0xe21545d0 , 0x0521cfaa , 0x05e0f4bf , 0xd4750523 , 0x09c18eb9 ] )
+  , Mont::unsafe_make( [ 0xb5b21a1a , 0x28caf91e , 0x8e8bb9ec , 0x092fc4c3 , 0xd2895894 , 0xeeca055e , 0xe08949a8 , 0x06be539c ] )
+  , Mont::unsafe_make( [ 0xe53868f3 , 0xe2780dde , 0x25b612ef , 0x00500d06 , 0x97a176b0 , 0x998c306d , 0xd7bd7270 , 0x2f7cabf3 ] )
+  , Mont::unsafe_make( [ 0x6b1da928 , 0x64c10b39 , 0xff3ff3c5 , 0xb2dd2689 , 0xe8a8924b , 0xaf6652b6 , 0xb6fd2ec3 , 0x0723d647 ] )
+  , Mont::unsafe_make( [ 0xafb6c803 , 0x7f1cdee9 , 0x27bcbe7a , 0xfdedb396 , 0x8076d972 , 0xf905456c , 0x5ed2828d , 0x288b8777 ] )
+  , Mont::unsafe_make( [ 0xab07b0f0 , 0x25ef9574 , 0xc0eda948 , 0xb4db661f , 0x6dad54e5 , 0x4ad8c35a , 0x60e6f74b , 0x2b49fd9f ] )
+  , Mont::unsafe_make( [ 0x55e204e1 , 0x56f7cc58 , 0xb1468d09 , 0xa178c50b , 0x77a4ab48 , 0x5335b6a6 , 0x19f6b216 , 0x0bcd0e37 ] )
+  , Mont::unsafe_make( [ 0xb73f5714 , 0xca0de795 , 0x46a55dd0 , 0x88f28070 , 0xfcd681b6 , 0x2d388058 , 0x72d583ac , 0x13552c9f ] )
+  , Mont::unsafe_make( [ 0xec9f2477 , 0x4689e8d6 , 0xb5200599 , 0x06d064b1 , 0x29d75f61 , 0x591bfe48 , 0xeca19a4c , 0x090345dd ] )
+  , Mont::unsafe_make( [ 0x1fe57fd2 , 0xf357bb77 , 0x0dd227d8 , 0xbf30d681 , 0xd526fed7 , 0xdf29a2c1 , 0x1f571b7a , 0x13272292 ] )
+  , Mont::unsafe_make( [ 0xa44b39c9 , 0x5fa044c2 , 0xe09c58e5 , 0x88e5927d , 0x27e56b6e , 0xd102c292 , 0xe3ac0de5 , 0x00105c32 ] )
+  , Mont::unsafe_make( [ 0xeac4e426 , 0xa5a6e056 , 0xa547d8ff , 0x9993bc59 , 0xc93023dd , 0xc7e53066 , 0xa3cc8ddb , 0x29f2d059 ] )
+  , Mont::unsafe_make( [ 0x0eaa6207 , 0x3792b675 , 0xb8c352ec , 0x7933f47f , 0xb9fe9fc2 , 0xa3a5cc43 , 0x690ea35d , 0x2587cebd ] )
+  , Mont::unsafe_make( [ 0x3dccc8bf , 0x758f2277 , 0x21b48fd2 , 0x880a7647 , 0x8bf58a8a , 0xb650038d , 0x276d3973 , 0x01f665d0 ] )
+  , Mont::unsafe_make( [ 0xa96a502e , 0x6ba8aa1c , 0xcd58f750 , 0xfa22471a , 0x0d0d9927 , 0xe0e7a45d , 0x76117f64 , 0x0cf988c0 ] )
+  , Mont::unsafe_make( [ 0x4818f927 , 0x7a695fdb , 0x8ddac59f , 0xd94a606d , 0xf7c92fab , 0xca60921b , 0xf287d97a , 0x0edfa5f7 ] )
+  , Mont::unsafe_make( [ 0xba63d4fd , 0x862d8081 , 0xdf89c837 , 0x68877b08 , 0x459caf49 , 0x0f16e497 , 0xfaf95c38 , 0x0eba945b ] )
+  , Mont::unsafe_make( [ 0x77ffe250 , 0x1c43a24f , 0x488b30f8 , 0x690dbb76 , 0xe4c15915 , 0x70718d3b , 0x1ad88acc , 0x2d88956c ] )
+  , Mont::unsafe_make( [ 0xf12c9682 , 0xad77e751 , 0xa0636b8f , 0xa7bf95a2 , 0xb7551642 , 0x501998b4 , 0xda4a09fd , 0x1c174280 ] )
+  , Mont::unsafe_make( [ 0x8175a9cb , 0x16d0714a , 0x24feb960 , 0xc886223e , 0x453152e6 , 0x88f000bb , 0xc1bd3982 , 0x294a6f29 ] )
+  , Mont::unsafe_make( [ 0x354c6bbc , 0xbf91ec03 , 0x1bf902fc , 0x3d904b47 , 0xabc59604 , 0x51686f12 , 0x9dc7e0bc , 0x18305fab ] )
+  , Mont::unsafe_make( [ 0x6c1a710d , 0x56824de8 , 0xd56fb5b9 , 0xd2d7c7c5 , 0x176eee9d , 0xc689a6ee , 0x90eb2bba , 0x0415e12c ] )
+  , Mont::unsafe_make( [ 0xefe540b1 , 0x705384c9 , 0xd1777649 , 0x0105f0c7 , 0x451dfbd8 , 0xb1f28693 , 0x84ec4ca7 , 0x147dba73 ] )
+  , Mont::unsafe_make( [ 0xb027da3a , 0xf4e75d6d , 0xcb09e11d , 0x63fa45fd , 0xac7ca749 , 0x08c07137 , 0x34d2d310 , 0x011ae9c2 ] )
+  , Mont::unsafe_make( [ 0xead472f7 , 0x060d23d7 , 0xe9e54d10 , 0x3569e2be , 0x68e17a28 , 0x9c76dad7 , 0x42c20ec6 , 0x0ab38e27 ] )
+  , Mont::unsafe_make( [ 0xe0c6f7d5 , 0xafc4b8cf , 0xb3f011b6 , 0x91743a2c , 0x6062bf7b , 0xaed4272e , 0x05de7b63 , 0x21a17460 ] )
+  , Mont::unsafe_make( [ 0x42d20c04 , 0x1c6d64a7 , 0xaaf9597f , 0xeda74830 , 0xb3f341c2 , 0x12ec0264 , 0x65244557 , 0x1af71197 ] )
+  , Mont::unsafe_make( [ 0x80cdea4d , 0xff21b31b , 0x4166ddde , 0x43e5e0d2 , 0xc8af5c72 , 0x96c70a5c , 0x4d57d68b , 0x079f47f4 ] )
+  , Mont::unsafe_make( [ 0xb9f4a871 , 0x459ba92f , 0x51aafe3d , 0x39493638 , 0x9172e287 , 0xc37085aa , 0x5144f4e0 , 0x20c7c286 ] )
+  , Mont::unsafe_make( [ 0x63b99634 , 0x259ba847 , 0x1b59c9ce , 0x6a84ae96 , 0x6ca35d50 , 0xcdf37f1d , 0xbc9eba6e , 0x0197b19e ] )
+  , Mont::unsafe_make( [ 0x9afccd58 , 0x2affc649 , 0x9ca371c8 , 0x7679dea8 , 0x46ae6685 , 0xdadfa2ef , 0x99ffcb48 , 0x1b396987 ] )
+  , Mont::unsafe_make( [ 0x9daf7cd7 , 0x3dd7a0ce , 0x74730d4e , 0x3843d556 , 0x097ce9fc , 0xd41eaa66 , 0x2c7b30bd , 0x0dc2052a ] )
+  , Mont::unsafe_make( [ 0xe0581824 , 0xffa590bf , 0x2f028708 , 0xa51bb6e0 , 0x62a9d409 , 0x04e6500e , 0x949fde29 , 0x007ff435 ] )
+  , Mont::unsafe_make( [ 0xb2e04bbe , 0xc29d88f9 , 0xd5d6c16e , 0xb6f64975 , 0x2f2c647d , 0xfc73d685 , 0xfce74144 , 0x0eebad55 ] )
+  , Mont::unsafe_make( [ 0x70605a3f , 0xd3db013f , 0x3243d643 , 0x1d2feb66 , 0x201c57dc , 0x62069005 , 0x79fd2baa , 0x13d19801 ] )
+  , Mont::unsafe_make( [ 0x23e97ea4 , 0xdc599d88 , 0xefe79f37 , 0x93e5283a , 0x8bb2e6aa , 0x1c1f5f87 , 0xfb774fb6 , 0x2ba960bf ] )
+  , Mont::unsafe_make( [ 0x4052d07b , 0x29a97a97 , 0x3c07fc20 , 0x8cfa0092 , 0xcfab4309 , 0x4a23a7fd , 0x6f50707e , 0x1f3286d4 ] )
+  , Mont::unsafe_make( [ 0xf636dc34 , 0xb44a7ed3 , 0x4a937709 , 0xd8673f41 , 0x0dddd088 , 0xc8696766 , 0xd8fc14cc , 0x28604c3e ] )
+  , Mont::unsafe_make( [ 0x90131458 , 0x89188de2 , 0x4456dee3 , 0x1b14e81e , 0xb15b6bee , 0x6b9b0413 , 0x3a4df084 , 0x14134ab9 ] )
+  , Mont::unsafe_make( [ 0xa017eb6b , 0xfd886282 , 0xdb5b23b5 , 0x01c77d32 , 0xc4031bcd , 0x5de043dc , 0xc68754e0 , 0x0aa3aa05 ] )
+  , Mont::unsafe_make( [ 0x8d928ef5 , 0x04992d25 , 0x8a1f6dc3 , 0x6c8c2c66 , 0xb00d20f4 , 0x3d7c8488 , 0x585b0f30 , 0x0a4f1f51 ] )
+  , Mont::unsafe_make( [ 0x401b3c91 , 0x875b6bdd , 0x9191c82e , 0x6ebe298a , 0x9b6b790e , 0x502afd98 , 0x1d4913ed , 0x2d85f598 ] )
+  , Mont::unsafe_make( [ 0x0556a0d5 , 0x4db111af , 0x13e63338 , 0x6f997bd0 , 0x5a101c36 , 0x72f7ba90 , 0x9be7b18b , 0x1b1eba45 ] )
+  , Mont::unsafe_make( [ 0x6800277c , 0x5b403f0b , 0x0e970118 , 0xb2f9fe13 , 0xce4ac972 , 0x7371bcb5 , 0x3da68c63 , 0x018d767e ] )
+  , Mont::unsafe_make( [ 0x0fcda067 , 0xe8de02b5 , 0xd56bc996 , 0xe3b60e2e , 0xdc9e016a , 0x45790db1 , 0x90d66053 , 0x25932bfb ] )
+  , Mont::unsafe_make( [ 0x2494702a , 0x227b1cc2 , 0x7dcca216 , 0x2ff92d51 , 0x6f8f9818 , 0x79153c96 , 0x93a65ed1 , 0x06899850 ] )
+  , Mont::unsafe_make( [ 0x6ab6989a , 0x9e50116d , 0xbbb3a87a , 0x79905fbe , 0x9ea495cf , 0xaba31d7a , 0x85b32868 , 0x25bf7f60 ] )
+  , Mont::unsafe_make( [ 0xf3ffe9d7 , 0xa0c0ffbf , 0x8e61b359 , 0xdb8642cc , 0xa1ffa52d , 0xd3de8476 , 0xf87b90f6 , 0x0c5dedbc ] )
+  , Mont::unsafe_make( [ 0xe067786f , 0xa251c26a , 0xaa5b4171 , 0x02a880d2 , 0x52249082 , 0xc356ccea , 0x3ff6fc76 , 0x2d80db31 ] )
+  , Mont::unsafe_make( [ 0x500ecd07 , 0x5445734e , 0xab89e48e , 0x7489671a , 0x3337d769 , 0xbc7cf53f , 0x5f426b27 , 0x03af90e3 ] )
+  , Mont::unsafe_make( [ 0x8c5cd5d4 , 0xe6f1390e , 0x80715f16 , 0x551ee4b3 , 0x05fc2ff3 , 0x95354775 , 0xc700ad4e , 0x020d0f0b ] )
+  , Mont::unsafe_make( [ 0x12fa3519 , 0xf15ba836 , 0x85465d3a , 0x86ababf6 , 0x8314c3ce , 0x60ec69e3 , 0x43d36903 , 0x2f9910c5 ] )
+  , Mont::unsafe_make( [ 0x652b6e45 , 0xfedf4ffb , 0x42a7ff5d , 0xde2cfe5f , 0x62f0c2ba , 0xeb5b995f , 0x7773752f , 0x132f6215 ] )
+  , Mont::unsafe_make( [ 0xba5dbcb5 , 0x1d7bc6dd , 0x3b0b456d , 0xa9942d9d , 0xc91130b4 , 0x0a6d9145 , 0x605561c0 , 0x12670dd7 ] )
+  , Mont::unsafe_make( [ 0xbcd89898 , 0x018fd5b1 , 0xf4af2358 , 0x161e5aeb , 0x805523ef , 0xb4ad1c88 , 0x2a1d283f , 0x08f15e4b ] )
+  , Mont::unsafe_make( [ 0xd99fb04b , 0x50460ddf , 0x28bcc63b , 0x68fa3ad3 , 0x1d33f3f1 , 0xee8e0069 , 0x8b65defb , 0x1c1c29ae ] )
+  , Mont::unsafe_make( [ 0x6b3b42d0 , 0xd916c272 , 0xe091d611 , 0xc7e1c976 , 0x6cd90055 , 0x5bb8c7f2 , 0x5672f85d , 0x1c0aaba6 ] )
+  , Mont::unsafe_make( [ 0x78b5374d , 0x181a8652 , 0x7584d59b , 0x25a16139 , 0x7e5daa30 , 0xe1300d47 , 0x2657ba55 , 0x13c113c7 ] )
+  , Mont::unsafe_make( [ 0x0464df55 , 0x7598b8ae , 0xb9655316 , 0xab8d48cb , 0xc02325d6 , 0x16394e6e , 0x4f0e0783 , 0x1866373d ] )
+  , Mont::unsafe_make( [ 0xb681c803 , 0x4e993070 , 0xee7a1645 , 0x6c173fee , 0x36d66132 , 0x2db1d42d , 0xbdf56571 , 0x24757884 ] )
+  , Mont::unsafe_make( [ 0x3aa93aab , 0x826d7aa9 , 0x39f2f115 , 0xe52c4e22 , 0x5d190e52 , 0x4fa6c46d , 0x1addfc9e , 0x24b26a92 ] )
+  , Mont::unsafe_make( [ 0xff4e0ca0 , 0x36a478dc , 0xbe8cc3b6 , 0x3980788a , 0xd4c850ad , 0x67c88919 , 0xe1cfdc02 , 0x1f8aa184 ] )
+  , Mont::unsafe_make( [ 0x12b0d420 , 0xbd4458b7 , 0xbb4450f2 , 0xbfa2b71b , 0x9ecbb3d6 , 0x30006fbf , 0x3b2e5a4a , 0x249a1411 ] )
+  , Mont::unsafe_make( [ 0x16c624c2 , 0xced355ce , 0xb020854a , 0xc65f1e29 , 0x1426f24f , 0xe883b3a0 , 0x92b0f48f , 0x1298a220 ] )
+  , Mont::unsafe_make( [ 0x6765454d , 0x1b11f095 , 0x7cb0c82b , 0xfc4a7ef0 , 0x59310975 , 0x7e9d3120 , 0x373e7cd9 , 0x2cef972e ] )
+  , Mont::unsafe_make( [ 0x322cb519 , 0xf563b796 , 0x1cca05d8 , 0xfb4c488f , 0xee985b89 , 0x1cd9e0cb , 0x0ec0838a , 0x0d8d13f5 ] )
+  , Mont::unsafe_make( [ 0xcbfa64d5 , 0x7355ad79 , 0x422e0d3a , 0x32f70b00 , 0x7844146b , 0xbf736d9e , 0xfa141458 , 0x0bf2a82c ] )
+  , Mont::unsafe_make( [ 0x15f2a72c , 0x60be9290 , 0x93e373a7 , 0xceb44124 , 0xdbfe8781 , 0x650e63ff , 0x00c16d68 , 0x1b976b4e ] )
+  , Mont::unsafe_make( [ 0x3601b357 , 0xbe3dfe7e , 0x143dc4ab , 0x797b1c20 , 0xc861ef58 , 0xad6c1c85 , 0xd8ec19be , 0x1a9638fd ] )
+  , Mont::unsafe_make( [ 0x75eea0f7 , 0x236a26a5 , 0x4a10692e , 0xa9f19301 , 0xbbc46f12 , 0x029eb191 , 0x104b61fa , 0x1fca3e74 ] )
+  , Mont::unsafe_make( [ 0xf20554b1 , 0x84eac331 , 0xf6f1e599 , 0x6023b631 , 0x3db547d7 , 0xd102a1c5 , 0x946466d4 , 0x0d12baea ] )
+  , Mont::unsafe_make( [ 0x5664988b , 0x13861670 , 0x617f4834 , 0x3b5b9d3d , 0xd0092b80 , 0x35abc562 , 0x91a1353c , 0x13425f42 ] )
+  , Mont::unsafe_make( [ 0x9a17b543 , 0x59b8d6a7 , 0x1a933863 , 0x428b4ae9 , 0xdbc05a15 , 0x75b05c72 , 0x82cc1642 , 0x19d8600f ] )
+  , Mont::unsafe_make( [ 0xb238715a , 0x208c12a4 , 0x0d33392d , 0x7e04944b , 0x76cd069e , 0xfa0c7de7 , 0xb23c7647 , 0x0f056da8 ] )
+  , Mont::unsafe_make( [ 0xa8226947 , 0x736ddbcf , 0x87f2b42d , 0xe5a514da , 0x77b95f0e , 0x1c3cc9ff , 0x8caed950 , 0x146f299f ] )
+  , Mont::unsafe_make( [ 0xfa7d92c2 , 0x2f97141e , 0x89dc2e44 , 0xcee0024b , 0x6130c03a , 0x6833fb5b , 0xbae99f18 , 0x055cd810 ] )
+  , Mont::unsafe_make( [ 0x10ea1318 , 0x11517309 , 0xf6fef1d2 , 0xff11d821 , 0x8ca04182 , 0xa4525a24 , 0xc6db7cfe , 0x30342501 ] )
+  , Mont::unsafe_make( [ 0xe52155e8 , 0x76c2866d , 0x8810d296 , 0xab765e8e , 0x5cb7a4b6 , 0x83bed244 , 0x224f0b9c , 0x2abd2acc ] )
+  , Mont::unsafe_make( [ 0x8aea989a , 0x2e10c466 , 0x5c6a87f1 , 0x9dd75143 , 0xcce974bd , 0x8b949b16 , 0x27f97def , 0x1780be8c ] )
+  , Mont::unsafe_make( [ 0xb1d0dbc5 , 0x836849ba , 0x8ca8f991 , 0x1728ac28 , 0x9771bda3 , 0x5eef5985 , 0xca689519 , 0x26c02dc7 ] )
+  , Mont::unsafe_make( [ 0x07e883b0 , 0xd0d4e004 , 0xb37f4911 , 0xd338222f , 0xf39ca910 , 0x3dd2f737 , 0x95fab317 , 0x17df3812 ] )
+  , Mont::unsafe_make( [ 0x396ea03b , 0x0033c326 , 0xd79e9533 , 0x0053993f , 0x243bd51c , 0x95200164 , 0x573cf5d4 , 0x2ddde233 ] )
+  , Mont::unsafe_make( [ 0x08b84995 , 0x699e4925 , 0x607f5837 , 0xb54c0311 , 0x9a025c33 , 0x5ea7fa52 , 0x8e35a485 , 0x16ce19b5 ] )
+  , Mont::unsafe_make( [ 0x648cbb81 , 0xd7362087 , 0x7f7210a4 , 0xd83f7a29 , 0xce10f3ac , 0x971c307c , 0x55b5efaa , 0x1fb7aaed ] )
+  , Mont::unsafe_make( [ 0x27a493d7 , 0x7be1a91d , 0x723b3a19 , 0x975f1670 , 0x47afefb4 , 0xf213ef9d , 0xd138ce94 , 0x2798c7dd ] )
+  , Mont::unsafe_make( [ 0xcbac49e8 , 0x3ed5ec7b , 0xb92abb3d , 0xaf8e265b , 0x45775b7a , 0xed2cd26b , 0x9efa9bd4 , 0x0498a671 ] )
+  , Mont::unsafe_make( [ 0x15246fdd , 0xbd53336b , 0xe69bb9fb , 0x8f063c36 , 0x24ab309c , 0x25e85e76 , 0x2e8c5cfc , 0x1bf5a5f7 ] )
+  , Mont::unsafe_make( [ 0xecfa2abe , 0x66373d27 , 0x9dd42d0a , 0x56b95091 , 0x7cbe51be , 0xc5807837 , 0x11e3c839 , 0x274e19b8 ] )
+  , Mont::unsafe_make( [ 0x1b157f29 , 0x89c98051 , 0x8cab5863 , 0x23bd70f2 , 0x697375c9 , 0xb30cfb6b , 0x3cf97aac , 0x0fb4c01b ] )
+  , Mont::unsafe_make( [ 0x9542d485 , 0x06d5a56f , 0x81c30e32 , 0x3e2fe931 , 0x8dbb7960 , 0x4930197a , 0x756bd096 , 0x226e2062 ] )
+  , Mont::unsafe_make( [ 0x21a66dc7 , 0x81290236 , 0x7f148829 , 0x3bdf417c , 0x29de9dfb , 0x84cea5cf , 0xa316d073 , 0x09728662 ] )
+  , Mont::unsafe_make( [ 0x1b0a9708 , 0xa4344976 , 0xa429753f , 0xc082a404 , 0x398c4e79 , 0x66dc9935 , 0xc368d433 , 0x108a82a7 ] )
+  , Mont::unsafe_make( [ 0xc4b89868 , 0x746fdd01 , 0xd7a194c3 , 0xc2a5367b , 0x3c5deb78 , 0xddc92284 , 0x6d2fa418 , 0x2fd6bdab ] )
+  , Mont::unsafe_make( [ 0xe902b3c0 , 0x9f6faf1e , 0xfcaadac4 , 0x84365c9d , 0x5e3e3531 , 0xa29b1956 , 0x0fffd3c5 , 0x0f0fc3a7 ] )
+  , Mont::unsafe_make( [ 0x29b47c56 , 0x788d6c36 , 0xcd7f8d64 , 0x13ed044c , 0x035dd346 , 0x8ba35292 , 0x5989321d , 0x2aff2812 ] )
+  , Mont::unsafe_make( [ 0x8dc2ddc3 , 0xaf8c904d , 0xdfdaf96d , 0x60915193 , 0x67095c78 , 0x497ef6d8 , 0x26c29236 , 0x07f9727e ] )
+  , Mont::unsafe_make( [ 0xed1115e8 , 0x5cce7643 , 0x71127c5f , 0xbc544e5b , 0x7198c1e1 , 0x5226e6c2 , 0xb4f93616 , 0x2f972ddb ] )
+  , Mont::unsafe_make( [ 0x65ab2fb7 , 0xb13a2172 , 0x4a4d1d26 , 0xd5414756 , 0x63b4283a , 0x07fa3134 , 0x8e291869 , 0x2d8c0408 ] )
+  , Mont::unsafe_make( [ 0x7a8444a8 , 0x3cc40e28 , 0x40d15f03 , 0x269753fc , 0x41b5ad40 , 0x05535481 , 0x4c8900ad , 0x08d83d49 ] )
+  , Mont::unsafe_make( [ 0xb10cd66b , 0xf081deb9 , 0xd7b08448 , 0x00df3319 , 0x944a9eff , 0xb9ffbe8c , 0x087e0994 , 0x10864f12 ] )
+  , Mont::unsafe_make( [ 0x962f6799 , 0x1e03e302 , 0x2df231de , 0x3d1135c2 , 0x39ef1ca3 , 0x829427ee , 0x2ed32dae , 0x2d3525c3 ] )
+  , Mont::unsafe_make( [ 0xa393084d , 0x43fc4be1 , 0x72e29403 , 0x889d2faa , 0xadfc206b , 0x9b052e95 , 0xea560626 , 0x1a70b3c4 ] )
+  , Mont::unsafe_make( [ 0xac75500e , 0xbbcf50c6 , 0x5c3af462 , 0x233bcace , 0x0fd49a10 , 0x220f3d2c , 0x5637ba01 , 0x1b48aa9a ] )
+  , Mont::unsafe_make( [ 0x68ef0e65 , 0x008c741b , 0xbda3c0f2 , 0x44c3c04c , 0xebc72c3a , 0xf2aaa4df , 0x985d6558 , 0x29c6df48 ] )
+  , Mont::unsafe_make( [ 0xe23be6a4 , 0xcf21353e , 0x721a98f4 , 0xb1d68d98 , 0x99053e8d , 0x8eccbb8c , 0x80df1516 , 0x196f0e3c ] )
+  , Mont::unsafe_make( [ 0x18d4cddc , 0xafb900a9 , 0x97ad1394 , 0x8aa14449 , 0xe9f4d9e7 , 0x3c0663a2 , 0x0e774f1b , 0x23b28590 ] )
+  , Mont::unsafe_make( [ 0x669c8eef , 0x20c142f0 , 0x413cc7f7 , 0x95dc951f , 0xd7af1801 , 0x9fc31e90 , 0x5b047a6f , 0x0d7fb91d ] )
+  , Mont::unsafe_make( [ 0x60550e8b , 0x1601c93c , 0x05041966 , 0x35b0ae5c , 0x10c2b4dc , 0x4a23f75a , 0x86a5b8c9 , 0x2659b04e ] )
+  , Mont::unsafe_make( [ 0x34607405 , 0x10d23b02 , 0x356b9060 , 0x3106b889 , 0x70f3a1e5 , 0x81d87d1c , 0xfd8279b0 , 0x0da3a2ce ] )
+  , Mont::unsafe_make( [ 0xb7ad7ea3 , 0x9439533a , 0x674f8b6c , 0xa9ea2d01 , 0xad9e22c4 , 0x74404edf , 0x6b431866 , 0x2ec16b65 ] )
+  , Mont::unsafe_make( [ 0x53aa580c , 0x0c2bb8bd , 0xe2876c96 , 0x2370a52f , 0x3024f163 , 0xa6633721 , 0xd7a8562d , 0x1fb80878 ] )
+  , Mont::unsafe_make( [ 0xf638a039 , 0x678ec147 , 0x030a556c , 0xce2b4feb , 0x17944463 , 0xf0ccde32 , 0x6cce6d6a , 0x0a70ecd2 ] )
+  , Mont::unsafe_make( [ 0x04de6411 , 0x567a4809 , 0x9fbcbd2a , 0xc575ff60 , 0xd4108340 , 0x4750e1b9 , 0xa005845f , 0x26a64a8d ] )
+  , Mont::unsafe_make( [ 0x77b09fad , 0x2709f40a , 0x03f1e9c4 , 0x1e69fa5c , 0x7e6948c3 , 0x7cde5ee8 , 0xcac3da3f , 0x200490ea ] )
+  , Mont::unsafe_make( [ 0x50966c81 , 0x83f95487 , 0x08500078 , 0x5ae208bb , 0x81ad0cce , 0x2be5abbf , 0xc8b4ad74 , 0x0fe11b3f ] )
+  , Mont::unsafe_make( [ 0x2b4bfc6e , 0x1b92196a , 0xa2237922 , 0x2776da8d , 0x5ff3e28d , 0xc5f4f501 , 0xed1e1c4b , 0x12097cc2 ] )
+  , Mont::unsafe_make( [ 0x148b4552 , 0x2db76674 , 0x585ad352 , 0x5687a455 , 0xb1ef03d7 , 0x45245ab7 , 0x625d6d81 , 0x1ab4f87d ] )
+  , Mont::unsafe_make( [ 0xb0e983c0 , 0x3d2bb18c , 0x613bc70a , 0x02add348 , 0x4b8b96df , 0xf7245e80 , 0xea50dc39 , 0x24aad1da ] )
+  , Mont::unsafe_make( [ 0xe32c5b1f , 0xf68e0918 , 0xdac4e85c , 0x2481e539 , 0xb296e667 , 0x9bb2e40a , 0xfe45f50c , 0x030af560 ] )
+  , Mont::unsafe_make( [ 0x5b74c4f5 , 0xe111ca81 , 0x6b127de2 , 0xb5e1deef , 0xc0f288ab , 0xf7554d60 , 0xb0d964a2 , 0x1d60f5e0 ] )
+  , Mont::unsafe_make( [ 0xba098cc5 , 0xafb5aa1f , 0xa0753bfc , 0x722924cc , 0x1b24e2d7 , 0xdc6d6334 , 0x64f8e27d , 0x2f0ec473 ] )
+  , Mont::unsafe_make( [ 0xbf2e062d , 0x9c3843ef , 0xb525a328 , 0x23231cc4 , 0x17328d8f , 0x43d47424 , 0x4a86a649 , 0x13c6a05c ] )
+  , Mont::unsafe_make( [ 0x5fc279da , 0x87d485dd , 0x65a81772 , 0x621d6c8e , 0x22d74046 , 0xa2c084bb , 0x2c543936 , 0x22f37a14 ] )
+  , Mont::unsafe_make( [ 0x60e9d47d , 0x6f0299e6 , 0xf4e7210d , 0x8489e4d9 , 0x986b435a , 0x45bfdcef , 0x1f64f5d6 , 0x1a465c0b ] )
+  , Mont::unsafe_make( [ 0x71c12f71 , 0x6871d546 , 0x286687f8 , 0x00d0de08 , 0x20e7fb8d , 0x5c9fb1f9 , 0x279d4028 , 0x28b4a0bf ] )
+  , Mont::unsafe_make( [ 0x35951749 , 0xfa1d5eaf , 0xce5d6670 , 0x0ce30129 , 0x073fdd97 , 0x153bc769 , 0xf5d8a1d2 , 0x02302c64 ] )
+  , Mont::unsafe_make( [ 0xbecb0bfb , 0xc868c061 , 0xb85c0062 , 0x0aa428d2 , 0x351f4dae , 0x2f49c630 , 0xf6d52728 , 0x0f49b2e4 ] )
+  , Mont::unsafe_make( [ 0x94a121a5 , 0x639c3f04 , 0x84ae746e , 0x6b55cc53 , 0xaa94743f , 0x5badb3a7 , 0x0cf237cb , 0x1a0ae362 ] )
+  , Mont::unsafe_make( [ 0x05de5aa0 , 0xb612e415 , 0x794ddf62 , 0x374c5b52 , 0x1fa3f7ec , 0x2b4559df , 0x05423908 , 0x10ba3251 ] )
+  , Mont::unsafe_make( [ 0x8d69a636 , 0x2262f13b , 0xa0a4edbb , 0x7b46c66e , 0xa8f02979 , 0xf432c13c , 0xceded698 , 0x006370a8 ] )
+  , Mont::unsafe_make( [ 0xf47a4e19 , 0x890fc40e , 0x08bbb6a9 , 0x60b3e01c , 0xa2cce40e , 0xb776acb4 , 0x7a2ac1ea , 0x241a701a ] )
+  , Mont::unsafe_make( [ 0x2e174d25 , 0x86cc4ee1 , 0x1d979b21 , 0x08cc1697 , 0x64fd8e3f , 0x85ef515b , 0x7ab90701 , 0x15bffa1b ] )
+  , Mont::unsafe_make( [ 0x2aa00ab3 , 0x1384a789 , 0x9520f039 , 0x5f3c6905 , 0x26b355a3 , 0xf4ba1e58 , 0x28db0ebe , 0x300c36bd ] )
+  , Mont::unsafe_make( [ 0xf0e44f4a , 0xef09bd3f , 0xb9d8827f , 0x915b552b , 0x0c9aea90 , 0xe21a4462 , 0x8f39a2a0 , 0x090cac21 ] )
+  , Mont::unsafe_make( [ 0xf966d453 , 0xa5afb582 , 0x8d7e9824 , 0x59bdd436 , 0xd13fc7bf , 0x5ad616c2 , 0xbb068c83 , 0x11ddd89c ] )
+  , Mont::unsafe_make( [ 0xee745bb0 , 0x100d13d4 , 0xe70c0d22 , 0x429c9994 , 0xa5e21512 , 0x26b60143 , 0xc4dd95f8 , 0x2f9a0c7b ] )
+  , Mont::unsafe_make( [ 0xa4a39041 , 0x9b41114c , 0x8e6577a2 , 0x3bb146ef , 0xf1a7f9ed , 0x15349bc8 , 0x20c36969 , 0x25c9ef35 ] )
+  , Mont::unsafe_make( [ 0xb8fa5654 , 0x2397b6e0 , 0x2d100158 , 0xd006b840 , 0x57a0ca21 , 0x6b0f80bf , 0x4fd920e3 , 0x0a8a834f ] )
+  , Mont::unsafe_make( [ 0x6a8461b7 , 0x7ab663e8 , 0xe6f39fb9 , 0x4c83c6ac , 0xa46d9f63 , 0xacc67aed , 0xd27dd751 , 0x257a9aa9 ] )
+  , Mont::unsafe_make( [ 0xdcc860f5 , 0xddcdf8f2 , 0x339f9c31 , 0xe21c774b , 0xa05a183f , 0xbd74084f , 0x1410beae , 0x29e9b3e1 ] )
+  , Mont::unsafe_make( [ 0xfe4adec5 , 0x2fbc1455 , 0x6ab6fd41 , 0xd3ca9f9f , 0x4bcd980d , 0xacc64567 , 0x8810f68f , 0x2b5ed906 ] )
+  , Mont::unsafe_make( [ 0xd12d5583 , 0x43cf919d , 0x56e09150 , 0xd549072d , 0x5c481ba6 , 0x32770a82 , 0x75b68b4b , 0x273fe355 ] )
+  , Mont::unsafe_make( [ 0x020db9f1 , 0xc2b39e25 , 0x7e59f7dd , 0x062a7237 , 0xeedd3563 , 0x9289065f , 0x2a1bc5bb , 0x0716f249 ] )
+  , Mont::unsafe_make( [ 0xb8a5dc42 , 0x862806e1 , 0xcc3e29fe , 0x43b60b89 , 0xdd288c74 , 0x6530a3fd , 0xbe2237ac , 0x30322921 ] )
+  , Mont::unsafe_make( [ 0xbc12bc77 , 0xf9dddbec , 0x1b31da6b , 0x993bd16c , 0x2abebc62 , 0xd4952b7d , 0xa45b29a6 , 0x1681170f ] )
+  , Mont::unsafe_make( [ 0x504bc06b , 0x8700fc88 , 0xb6af4fb6 , 0xe7ebcd43 , 0xf7871a05 , 0x31f5ff22 , 0xeb8e8d0f , 0x3053e95c ] )
+  , Mont::unsafe_make( [ 0x92160032 , 0x83a89050 , 0x56b33136 , 0x1dc90fe4 , 0xa5e8035e , 0x64c11ead , 0x4acfd9ad , 0x1821e8e1 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// internal mixing matrix (`t x t`s, flattened)
+pub const CONST_P: [Mont; 256] = 
+  [ Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xa21aca20 , 0x39e0247e , 0x54e34d8b , 0xcf090c75 , 0xb6fd553f , 0x41380e20 , 0x97eb4ee2 , 0x1727297d ] )
+  , Mont::unsafe_make( [ 0x30890b84 , 0x6386bccd , 0xfba1e269 , 0x7f5da2ff , 0xbd88b362 , 0x57695d87 , 0xb787d363 , 0x032e60e8 ] )
+  , Mont::unsafe_make( [ 0xf5054945 , 0x5a75976a , 0xba52c82a , 0xe7647bfe , 0x565fa867 , 0xe46a4549 , 0x9ba6585b , 0x19c4b964 ] )
+  , Mont::unsafe_make( [ 0x4e0a3bae , 0x99177faa , 0x264998be , 0xce817ee9 , 0xaf1318ff , 0xaea222ae , 0xa6309793 , 0x1561f78c ] )
+  , Mont::unsafe_make( [ 0xe15753c5 , 0xbcc508ad , 0x2f5c7e5f , 0xb3eb00cb , 0x98e9e98c , 0x0c70ef5e , 0x6cd8959f , 0x287eef42 ] )
+  , Mont::unsafe_make( [ 0x23782a66 , 0x84111ab2 , 0x2bfaf5e3 , 0xc1ad8c41 , 0xf117dae1 , 0xa6501b9b , 0x983288a1 , 0x019bc01f ] )
+  , Mont::unsafe_make( [ 0x1555bc1d , 0xe98f0771 , 0x59837bce , 0x788d07a9 , 0x66ab5d37 , 0x62f06bdd , 0x5aa63ec6 , 0x0ede279e ] )
+  , Mont::unsafe_make( [ 0xf0671aa2 , 0xd16b59d5 , 0x3580a295 , 0x474510eb , 0x81691a86 , 0xfd764d53 , 0x59b85631 , 0x23c1c29e ] )
+  , Mont::unsafe_make( [ 0x909dd9b8 , 0x613ba541 , 0xbdcc9673 , 0x09c8a058 , 0x0ae4c2d7 , 0x3a26d913 , 0xaa4afc5f , 0x1d4dcdd0 ] )
+  , Mont::unsafe_make( [ 0x88a06f35 , 0x2c690879 , 0x0c441d6c , 0xd3749350 , 0x4adf10ff , 0xb4465582 , 0xd9d9abdb , 0x2c0a3ee0 ] )
+  , Mont::unsafe_make( [ 0xd5e541a1 , 0x54e7c276 , 0x91432840 , 0x8226d079 , 0x87d98da5 , 0x3b7fa170 , 0xf1da0c88 , 0x30328c95 ] )
+  , Mont::unsafe_make( [ 0xcac0371a , 0xd949355e , 0xb68160ad , 0x58dbbe92 , 0x130ad1c4 , 0xd9d45bbc , 0x89549db7 , 0x06c6ccc5 ] )
+  , Mont::unsafe_make( [ 0x04b8c2ae , 0x0bf8e7b4 , 0xa6408834 , 0x8c02c3ca , 0x4c98b6d1 , 0x4a431648 , 0x7bd5e6c7 , 0x03a26a07 ] )
+  , Mont::unsafe_make( [ 0x2e8f0f9b , 0xe9ca3365 , 0xdc6de17d , 0x170708c9 , 0xe6581a54 , 0x1d05d9e0 , 0x081b67a4 , 0x226f69a2 ] )
+  , Mont::unsafe_make( [ 0x2f008b4c , 0x08d571bd , 0xfb8793b0 , 0xf6ac14ea , 0x0cdd337c , 0xb6a73d06 , 0xca94bbe8 , 0x07cd63e2 ] )
+  , Mont::unsafe_make( [ 0x3549abe5 , 0x444be23c , 0xb76ad56f , 0xe13a67c9 , 0x88ea27e0 , 0xbcfe1a94 , 0x8324272c , 0x2ad89c3f ] )
+  , Mont::unsafe_make( [ 0xcdd18149 , 0x6cbf8087 , 0xe1692c52 , 0x782f0c96 , 0xbeb3845e , 0x8dba399a , 0x6245030e , 0x18f6adf2 ] )
+  , Mont::unsafe_make( [ 0x28c4242a , 0x8a886b8d , 0xe46dd849 , 0xd82ac234 , 0x48f0a1a5 , 0x50bc4a03 , 0x0ac3a9e1 , 0x009f3445 ] )
+  , Mont::unsafe_make( [ 0x20c0ae72 , 0xf3421b3b , 0x10998233 , 0x88ea82fb , 0x3dce471e , 0x337c4a1a , 0xe56ff542 , 0x14a39fdd ] )
+  , Mont::unsafe_make( [ 0xbf5b3d4e , 0x510df938 , 0xa1eea7ac , 0x11c895fa , 0x7a6bc536 , 0x0b5ab7c2 , 0x4351dfcd , 0x08896c8e ] )
+  , Mont::unsafe_make( [ 0x5f6e1826 , 0xd44c4618 , 0x03b64f87 , 0x35f200c7 , 0x42c53960 , 0x2b6ea4c2 , 0x4a4494f0 , 0x230b7d37 ] )
+  , Mont::unsafe_make( [ 0xd650f1c8 , 0x74b72a0a , 0xe0e810f1 , 0x9f330784 , 0x722a1c97 , 0xaf81cad3 , 0xa506fbeb , 0x0b6fac27 ] )
+  , Mont::unsafe_make( [ 0x5a041e21 , 0x14f3f316 , 0x0374795e , 0xceedd3a4 , 0x0e91893a , 0x60d7dd4a , 0x84626177 , 0x20b87c87 ] )
+  , Mont::unsafe_make( [ 0x010c2aa4 , 0xe87b7321 , 0xabefac13 , 0xea1ebcb5 , 0x88d49408 , 0xbcb5fea1 , 0x1dbb5184 , 0x1cef2aac ] )
+  , Mont::unsafe_make( [ 0x550e020c , 0x43d1c82b , 0x7986baaa , 0x3128b37c , 0x2a80fda7 , 0xb4df4857 , 0x3723e2b9 , 0x2cae0e90 ] )
+  , Mont::unsafe_make( [ 0x265b5f47 , 0x212bcbde , 0xb6260f38 , 0xe8c9efaf , 0x6c3028d7 , 0xb048caeb , 0xd5f530f8 , 0x201a87bc ] )
+  , Mont::unsafe_make( [ 0x48f6d2fd , 0x2c3f1f7a , 0xd15bc999 , 0x44905c75 , 0x4fc83820 , 0x6ae03eb1 , 0x1b6543f1 , 0x141977d6 ] )
+  , Mont::unsafe_make( [ 0xd5fea3cf , 0x13f3d464 , 0x73ff0468 , 0x7eeff051 , 0x8f06cf7a , 0xc2e330db , 0xf6c3a704 , 0x1232d31d ] )
+  , Mont::unsafe_make( [ 0xa4f2ec5a , 0x63884fc5 , 0x12f935ee , 0x16da7716 , 0xf6752daf , 0x35f54a9d , 0xcbd61924 , 0x1dfc7142 ] )
+  , Mont::unsafe_make( [ 0x3346a5ff , 0x8cf0e2c3 , 0x2ecb2844 , 0xdea1d994 , 0xd3a90040 , 0x7f1c2dd3 , 0x05dda3f5 , 0x04e38607 ] )
+  , Mont::unsafe_make( [ 0xf5be639a , 0xbfa6e109 , 0x79a48b5a , 0x93b7363e , 0xcf5282c5 , 0xcd4bd4b6 , 0xc421e87c , 0x2491adcb ] )
+  , Mont::unsafe_make( [ 0xdddf5d9b , 0x1eee4fab , 0x7c60b1e2 , 0xd40a1486 , 0x05ef6a7d , 0xbb394405 , 0x849e781d , 0x03a4dddf ] )
+  , Mont::unsafe_make( [ 0x5976e655 , 0x695d1097 , 0xff942e88 , 0x673f0325 , 0xa72ce961 , 0x99cea268 , 0x92ae6728 , 0x119c183a ] )
+  , Mont::unsafe_make( [ 0xc29a9c59 , 0xc6090008 , 0x1bad29f2 , 0x270ff149 , 0xe95791b1 , 0x28ef743d , 0x8c45d041 , 0x1bb76ec9 ] )
+  , Mont::unsafe_make( [ 0x846417b1 , 0xca4e0297 , 0x70c2803c , 0x8317e570 , 0x3ba22bdc , 0x2bf33904 , 0xdfd10964 , 0x04d442ca ] )
+  , Mont::unsafe_make( [ 0x997ae909 , 0x68cdea34 , 0x11e935f1 , 0xe13e1749 , 0xa122e4c9 , 0x3bafc42f , 0x6d4aafaf , 0x24b75d73 ] )
+  , Mont::unsafe_make( [ 0xa3b574e5 , 0xe1d8097a , 0x71e66947 , 0x810e67f2 , 0x25d58d5d , 0xb9a81191 , 0x393c92f6 , 0x2a2d391a ] )
+  , Mont::unsafe_make( [ 0x3e987fd4 , 0x47fabe35 , 0x537d002b , 0xa2397014 , 0xabd10b02 , 0x8eeccf66 , 0xc7081a19 , 0x1c0a9938 ] )
+  , Mont::unsafe_make( [ 0x6e2552e6 , 0x5c2cf2cf , 0xa72b2591 , 0x7af4960b , 0x93a0e01d , 0x2e39aa29 , 0x17f29490 , 0x2c02439e ] )
+  , Mont::unsafe_make( [ 0x78172f4b , 0xe9dd70da , 0xc0ffd558 , 0xe0681f15 , 0xbf6e6b1f , 0x25687170 , 0x1c70107b , 0x2d0b9286 ] )
+  , Mont::unsafe_make( [ 0xbb196eca , 0xc8dfaeb5 , 0xb1377999 , 0xa3c1bc9a , 0xc22b4158 , 0xf05b4be3 , 0x9d0a3b0d , 0x0f7e685c ] )
+  , Mont::unsafe_make( [ 0x248f2cb3 , 0x5b575a45 , 0x54ecb0ac , 0x41d19e32 , 0x11c1a91b , 0x084ab234 , 0x80f74c26 , 0x2fca9164 ] )
+  , Mont::unsafe_make( [ 0x2ad2c9c6 , 0xea231e53 , 0xe0b61692 , 0xb93751b9 , 0x076a07ef , 0x777bbf93 , 0x29c61dd2 , 0x0249445e ] )
+  , Mont::unsafe_make( [ 0x2a5dda45 , 0x625f5f47 , 0xd4ab3f68 , 0xed24635a , 0xd93f9905 , 0xe51d067c , 0x5822e823 , 0x2c7d826d ] )
+  , Mont::unsafe_make( [ 0xd530d949 , 0x8b6677fb , 0x6392b9f2 , 0xbc953e61 , 0xaaaebb61 , 0x71f0989d , 0x6763e10f , 0x209044c3 ] )
+  , Mont::unsafe_make( [ 0xb5684d5e , 0x1dbd20b3 , 0x1a7eae63 , 0x00bf1bb4 , 0x794497f5 , 0x0889980a , 0xf1fd89d0 , 0x0af3a7b7 ] )
+  , Mont::unsafe_make( [ 0x9a25158e , 0x51d2ea19 , 0xa13fabb6 , 0xa8f46c85 , 0xb13d4960 , 0x9eea70f3 , 0x064fd47d , 0x0656d104 ] )
+  , Mont::unsafe_make( [ 0xcad5ebbe , 0x22cf6760 , 0x776f8930 , 0x1c0266ce , 0x1bdcb1a3 , 0xcaa7fd45 , 0xd0643d43 , 0x027620c9 ] )
+  , Mont::unsafe_make( [ 0x3f51b6cd , 0x55aaf4ab , 0x08901fac , 0x36bf0eb2 , 0x677981c6 , 0x099ff590 , 0xbe434289 , 0x1d6eff99 ] )
+  , Mont::unsafe_make( [ 0x15d0f017 , 0xeddce01a , 0xbf66fe04 , 0xe2f6ec70 , 0x51dc20bb , 0x08b7400a , 0xa5270f90 , 0x2a48dd5b ] )
+  , Mont::unsafe_make( [ 0x8ea61c93 , 0x319bfd45 , 0x20d1b0b2 , 0x66d772da , 0x0b18239c , 0x5e52c24d , 0x1ad42980 , 0x034f5fdd ] )
+  , Mont::unsafe_make( [ 0x04fc528f , 0x33052c6d , 0x82782b22 , 0xb704a3ed , 0xe458c669 , 0x8cf14e44 , 0x1629b182 , 0x13f5ab92 ] )
+  , Mont::unsafe_make( [ 0xfc594ea0 , 0xa4519c01 , 0x9882581f , 0xa4d2cbdd , 0x6b591a53 , 0xbe186791 , 0xddc4e858 , 0x2ab7be5b ] )
+  , Mont::unsafe_make( [ 0x4afacf5a , 0xcbaf0acf , 0x45f64d54 , 0x1a6e4fc7 , 0xf67ccb9f , 0x4d87c3db , 0x3d45ad63 , 0x158ca3a1 ] )
+  , Mont::unsafe_make( [ 0x4b891033 , 0xa266fe14 , 0xfb81ce69 , 0x5149b314 , 0xfe7acf84 , 0xead6a08c , 0x22f0fbb2 , 0x02d287d3 ] )
+  , Mont::unsafe_make( [ 0xd19a59e6 , 0x6b250d23 , 0x775cc777 , 0x05e358bd , 0x3f476d06 , 0x63552920 , 0xa0dc9fea , 0x17929a07 ] )
+  , Mont::unsafe_make( [ 0x67c115b3 , 0x7ae51b8c , 0x2c94cc4d , 0x16fbd25f , 0x0f0a3ae6 , 0x6ec90356 , 0xbcef120f , 0x1ff3a1fd ] )
+  , Mont::unsafe_make( [ 0x53ab0be3 , 0x1c20be11 , 0x8edf4cde , 0xf711f57c , 0x22a37250 , 0x38f5ff00 , 0xb4e1dfce , 0x13436fbc ] )
+  , Mont::unsafe_make( [ 0x52b5192f , 0x00467623 , 0xb47f9a95 , 0xbe35c2d8 , 0x3c0d4157 , 0xc1be45fd , 0x434ad3c7 , 0x1e2981d9 ] )
+  , Mont::unsafe_make( [ 0x3d984a88 , 0x684c1b36 , 0x2f134012 , 0xa1da576a , 0xaa6372d1 , 0xd0adf53f , 0x80f28597 , 0x1ccf0411 ] )
+  , Mont::unsafe_make( [ 0xd6d6b15c , 0x51ea56c7 , 0x93981429 , 0xcbec2f6c , 0x40f1094b , 0x4031a476 , 0x9fd1c6d2 , 0x18181b54 ] )
+  , Mont::unsafe_make( [ 0xc694e791 , 0x33f73267 , 0xa7fe669a , 0x528bdaad , 0x88d365f8 , 0x80056128 , 0x4832248e , 0x0d64db85 ] )
+  , Mont::unsafe_make( [ 0xd209418e , 0xbf566d9f , 0xecc57ed5 , 0xce31b35a , 0xbb009a13 , 0x56e54972 , 0x864709d1 , 0x1b3aaaaf ] )
+  , Mont::unsafe_make( [ 0x74fc28d5 , 0xb6d74214 , 0x9cf5e114 , 0x08a1375b , 0xaa420aab , 0x98cc919e , 0xfd60ad0c , 0x04dc8216 ] )
+  , Mont::unsafe_make( [ 0xcffee305 , 0x0ea399d3 , 0xd7e3df01 , 0xc7105b2a , 0xba05cda3 , 0x6a174e86 , 0xcaf490b1 , 0x148bee07 ] )
+  , Mont::unsafe_make( [ 0xc57e149c , 0xc2b86a7c , 0x4514201b , 0xa1969447 , 0x876473a6 , 0x286f4c4a , 0xd3f769de , 0x1f743680 ] )
+  , Mont::unsafe_make( [ 0x232544d9 , 0x7982bec9 , 0xc5313fb4 , 0x38194235 , 0x7dfd6aff , 0x08c626dc , 0xadc144a3 , 0x2a70149f ] )
+  , Mont::unsafe_make( [ 0x8bfe289c , 0x4cb938e2 , 0xb736cd21 , 0x428246e9 , 0x590e3d43 , 0x0248ecb2 , 0x0f17ab55 , 0x09e55005 ] )
+  , Mont::unsafe_make( [ 0x8f255c2b , 0x5e5380c1 , 0x1b187c8d , 0xe7d7ee1f , 0xaaea331d , 0x432acf1a , 0x1fc40c6e , 0x22aec60e ] )
+  , Mont::unsafe_make( [ 0x12c2c336 , 0x46153eaa , 0xb92cadb6 , 0xccfe1ace , 0xb2172ce3 , 0xc8b80fd6 , 0x5498f313 , 0x1506de0f ] )
+  , Mont::unsafe_make( [ 0xdc84731a , 0xc488187a , 0x9a802485 , 0x263a5b60 , 0x9b438c64 , 0x58ba80bc , 0x41e68211 , 0x0243609e ] )
+  , Mont::unsafe_make( [ 0xc6abd4a5 , 0xb4ab6b40 , 0xdf33e78c , 0xafc59efd , 0x28363b18 , 0x601405a8 , 0x37ec1cc3 , 0x176bd3a3 ] )
+  , Mont::unsafe_make( [ 0xa9b6c9fe , 0xcb263d39 , 0x868e58a8 , 0x83e264fb , 0xd1d8f0fb , 0x1b36589e , 0x9b78a783 , 0x029d69b6 ] )
+  , Mont::unsafe_make( [ 0xfc93cef2 , 0x613a112f , 0xce77fd6b , 0x89fa4053 , 0x6ffed3b1 , 0x03c94730 , 0x3bac3078 , 0x217521b4 ] )
+  , Mont::unsafe_make( [ 0xea1f4f55 , 0xd703c8f2 , 0xd90ae474 , 0xec69c052 , 0x3fb181d3 , 0xa92cafad , 0xf24c4496 , 0x05cea5a6 ] )
+  , Mont::unsafe_make( [ 0xc7e68b29 , 0xf05c14ee , 0x6b8f12a5 , 0x17fe96d5 , 0x4871efef , 0xa06b967c , 0x53283e09 , 0x2ff0fe46 ] )
+  , Mont::unsafe_make( [ 0xe0f69a51 , 0x1e157456 , 0x4e99c737 , 0x5c39590d , 0x7cc8b0bd , 0x68b41fb4 , 0xc5d5dae1 , 0x16b1cd4e ] )
+  , Mont::unsafe_make( [ 0xdb8ff702 , 0xfa35c0bc , 0xb413dd14 , 0xce5631a9 , 0xa3de270b , 0x23dad047 , 0x4f3104e4 , 0x1b1e4899 ] )
+  , Mont::unsafe_make( [ 0x071f157d , 0xf27793aa , 0xc3686ecb , 0x61a54b9d , 0xaa3da38e , 0x1d6075f1 , 0xcd3f1f48 , 0x2a88a015 ] )
+  , Mont::unsafe_make( [ 0x8fc6cffa , 0x00924a03 , 0xf20ca95a , 0x25913bdb , 0x7707bc78 , 0x359282e0 , 0x99ef956d , 0x2d0b9548 ] )
+  , Mont::unsafe_make( [ 0xb79f8038 , 0xd5112634 , 0xaaa7bd67 , 0x0408fc1d , 0xf4ad7a51 , 0x2e17005b , 0x8f3c5c9d , 0x25acca13 ] )
+  , Mont::unsafe_make( [ 0xa9e87dea , 0x37aae4c6 , 0x60763c1b , 0xca9652c4 , 0xfe15f6d9 , 0xbe988821 , 0x33ffad76 , 0x2c9330a6 ] )
+  , Mont::unsafe_make( [ 0x394973bb , 0x1ab546f2 , 0x6bb27bcb , 0xf48cd8d1 , 0xcfde933a , 0x409da8f8 , 0xf42a6b73 , 0x01a606bd ] )
+  , Mont::unsafe_make( [ 0xc97262ce , 0x96920f17 , 0x5e9f907d , 0x0eba564b , 0x18213494 , 0x84f973cc , 0x233431a0 , 0x2da864d0 ] )
+  , Mont::unsafe_make( [ 0xf2d6ec27 , 0xc3686cf0 , 0x124b2528 , 0xb9344152 , 0xf0d71656 , 0x950b4733 , 0xf80ef7f7 , 0x2012537b ] )
+  , Mont::unsafe_make( [ 0xa39cb6ff , 0x7ad649dc , 0xea6cecad , 0x7fa50ed7 , 0x6c739ac8 , 0x19525c08 , 0xde1e52d4 , 0x0f3c0124 ] )
+  , Mont::unsafe_make( [ 0x6f199c5a , 0x4f63f4be , 0xed25f18d , 0xf1ec01a2 , 0x3b606b90 , 0x6cc02242 , 0x9aeb151d , 0x193e053c ] )
+  , Mont::unsafe_make( [ 0x767daa2c , 0x61235788 , 0xe9db5da6 , 0x6926ecbb , 0x47c5ea6c , 0xab411e11 , 0x5ea49ba5 , 0x1537913c ] )
+  , Mont::unsafe_make( [ 0x2e5340d2 , 0x54469556 , 0xb1d8c915 , 0xdeafc715 , 0xdaedf87d , 0x32d949c0 , 0xd9058393 , 0x1fb7a3d2 ] )
+  , Mont::unsafe_make( [ 0xbf3c2f64 , 0x80f764a1 , 0x7186d478 , 0x97d854b2 , 0x5fe67116 , 0x1d2abc12 , 0xfa593a8c , 0x1aa3e3f1 ] )
+  , Mont::unsafe_make( [ 0x9ce5eb72 , 0x97475ea5 , 0xfbb9ea30 , 0x774cc106 , 0x630e053e , 0x45f394ae , 0x57b7343c , 0x2fd43c50 ] )
+  , Mont::unsafe_make( [ 0xcf0e2481 , 0x000b656a , 0xf50a9c3f , 0xcf9593c6 , 0xc73c7302 , 0x106703fb , 0x018551b5 , 0x09adce92 ] )
+  , Mont::unsafe_make( [ 0x1ddc0607 , 0xa1bfae4d , 0x2e1ba66c , 0xaa657935 , 0x85bf0e89 , 0xc25cee32 , 0xab7adf3d , 0x23472459 ] )
+  , Mont::unsafe_make( [ 0x69d65c86 , 0xafd04ec5 , 0x9912c489 , 0x1fa6f6f3 , 0x952651ac , 0xd7da4f91 , 0x13bb87fa , 0x0fce8dea ] )
+  , Mont::unsafe_make( [ 0xd9fa2a0c , 0xb3116462 , 0x6c86e194 , 0x642d7a0d , 0x6f6b6a25 , 0x32db072c , 0x83b5ef0a , 0x00173bbb ] )
+  , Mont::unsafe_make( [ 0x4a3e2f4d , 0x01de9c37 , 0x85c7efb2 , 0x70b0ab71 , 0x6e6f4a74 , 0xd85bde59 , 0x8e4c123e , 0x11ae2899 ] )
+  , Mont::unsafe_make( [ 0x8e75ee7b , 0x23a62088 , 0xdc4b438f , 0x6e955520 , 0xb933dc66 , 0xd6290a40 , 0xa951a7ef , 0x2f219df5 ] )
+  , Mont::unsafe_make( [ 0x07b5beda , 0xbde08df6 , 0x429b791a , 0x9548058f , 0x517ced51 , 0xee089f04 , 0x118d7b24 , 0x1823b82e ] )
+  , Mont::unsafe_make( [ 0xfcce604a , 0xe9df847a , 0x453afe9c , 0x3ea73d67 , 0x5caf05ad , 0xb45df1a6 , 0x1bd592ac , 0x2f23f706 ] )
+  , Mont::unsafe_make( [ 0x9db3be7b , 0xfacb31fd , 0x5e1cb59a , 0xec71c1f1 , 0x51ec33f9 , 0x7ac46fe3 , 0x100d7717 , 0x08df6744 ] )
+  , Mont::unsafe_make( [ 0xef2e7f1d , 0x4e8a96d6 , 0xad4123cc , 0xd7c0d559 , 0xc32dc185 , 0xede0b53a , 0x5e28102c , 0x21426fde ] )
+  , Mont::unsafe_make( [ 0xd2b985a3 , 0x0dacdb8a , 0x9ed87e74 , 0x9349af54 , 0x6a565ec3 , 0x6113cb3d , 0x395d3fde , 0x0a958ae0 ] )
+  , Mont::unsafe_make( [ 0x8839b6af , 0xc0251126 , 0x050f2594 , 0x62ea92e5 , 0xb8208212 , 0x4891d770 , 0xf69ea66b , 0x3008f0d5 ] )
+  , Mont::unsafe_make( [ 0x25cb017e , 0x0e66fb29 , 0x46dc2c49 , 0xac8d24fb , 0x79be36aa , 0x0227497f , 0xb1457da1 , 0x08440a55 ] )
+  , Mont::unsafe_make( [ 0x5cc1e0fc , 0x1239b505 , 0xc0ac0d7f , 0xcf22087d , 0x880f1c49 , 0xf88b88ee , 0x74b9262f , 0x0f2262e7 ] )
+  , Mont::unsafe_make( [ 0x3a07025f , 0xd1cbe35e , 0xec5920fd , 0x3a587d53 , 0x723d9a0d , 0x0bc76957 , 0xaf3dc77a , 0x29e31a32 ] )
+  , Mont::unsafe_make( [ 0x0f52fc59 , 0xb70f61fb , 0x577ec30c , 0xc1980aba , 0xb0af0636 , 0x0c70f367 , 0xc13dbb9e , 0x2db14536 ] )
+  , Mont::unsafe_make( [ 0x0536e3ad , 0x5054180d , 0xc3596dfc , 0x63de8fd3 , 0x7c83c287 , 0xb2d75bb0 , 0x3e764a61 , 0x01e24604 ] )
+  , Mont::unsafe_make( [ 0x651310c8 , 0x5d3171c2 , 0x8a3bf651 , 0x94b1bb6a , 0xee44759e , 0xb49efc07 , 0x7b06211d , 0x26571632 ] )
+  , Mont::unsafe_make( [ 0x0ebfe71d , 0x03901faa , 0xf91175f2 , 0x4cd2ca93 , 0xfb3f06c2 , 0x7e65435d , 0x24ca69b1 , 0x2ca7adfd ] )
+  , Mont::unsafe_make( [ 0x35dad555 , 0xd9110398 , 0x7f37743e , 0x1efef675 , 0x56b3f51d , 0xefbe34a0 , 0x8440abb9 , 0x1899b97d ] )
+  , Mont::unsafe_make( [ 0x6b1da928 , 0x64c10b39 , 0xff3ff3c5 , 0xb2dd2689 , 0xe8a8924b , 0xaf6652b6 , 0xb6fd2ec3 , 0x0723d647 ] )
+  , Mont::unsafe_make( [ 0xf61d3fc4 , 0x9010d05b , 0xea158a40 , 0x55b2665a , 0xd13439c5 , 0x75a9fe4e , 0xad76fe09 , 0x14a8ed47 ] )
+  , Mont::unsafe_make( [ 0xbfc85a47 , 0xc8c50e98 , 0xe766ff67 , 0x45cf4d2a , 0x31573a34 , 0x2fc8fede , 0xb4c01622 , 0x2caf19b2 ] )
+  , Mont::unsafe_make( [ 0xd533b68e , 0xad1c0441 , 0x32ac084a , 0xe50f8bef , 0xd76dbd27 , 0x8c4ea1e7 , 0xe8046728 , 0x1439aa60 ] )
+  , Mont::unsafe_make( [ 0xed18a36d , 0x25ee5ca8 , 0x256d625f , 0x75f5dde7 , 0x5a471604 , 0xaef58cbd , 0x7880af73 , 0x294956ad ] )
+  , Mont::unsafe_make( [ 0xf81ffe31 , 0xe2938b45 , 0xfb429019 , 0x8238f39d , 0xaf9deaa5 , 0x57e54bd4 , 0x934d8c71 , 0x278fb1f7 ] )
+  , Mont::unsafe_make( [ 0x98dd8d98 , 0x61674495 , 0x4f680d30 , 0x91ee51b7 , 0xb6f8c270 , 0xa8aa42c7 , 0x454f5b22 , 0x25b2ad7c ] )
+  , Mont::unsafe_make( [ 0x0e8e74db , 0x3df20ee6 , 0x65a2456c , 0x8199f27f , 0x4636dcf0 , 0x8e61b620 , 0xff00e97f , 0x1b4d8b2c ] )
+  , Mont::unsafe_make( [ 0xbfc5b5a9 , 0xa9268f84 , 0x9a0fe96b , 0xf589e65c , 0x22261ebb , 0xd9bfe4b8 , 0x5971dc58 , 0x0871be1a ] )
+  , Mont::unsafe_make( [ 0x61eb91c8 , 0x0594ce21 , 0x570c22bf , 0xe251e057 , 0x6bb694bb , 0x94d44d77 , 0xa7e995ca , 0x224b25ac ] )
+  , Mont::unsafe_make( [ 0x04040853 , 0x3af0652c , 0x7966b36a , 0x5690899b , 0x12136773 , 0xc5361ab3 , 0xf686abfd , 0x04e1a7ae ] )
+  , Mont::unsafe_make( [ 0xde71d6f6 , 0xd799e971 , 0x7c6e6df1 , 0x64de113b , 0x7533c0b4 , 0x777c0024 , 0xc77fb8e2 , 0x02476642 ] )
+  , Mont::unsafe_make( [ 0x599b08a0 , 0xa0925139 , 0x510daedb , 0x07818924 , 0xab57fff1 , 0xfe2ee6d6 , 0xa6b7fb0a , 0x1aa85bef ] )
+  , Mont::unsafe_make( [ 0xfbd623a0 , 0xe56db29f , 0xcafe4c48 , 0x448e8945 , 0xee8ab9a2 , 0xd6812926 , 0x8361137f , 0x15b5ce0b ] )
+  , Mont::unsafe_make( [ 0x127872eb , 0x05c74928 , 0x9c04e308 , 0xc48e286a , 0xf12f7f22 , 0x2d5f5d97 , 0x2923b5e4 , 0x18d2d6c4 ] )
+  , Mont::unsafe_make( [ 0xc096debf , 0x28a96fe8 , 0x912fd6a0 , 0xf296b6df , 0xf256216b , 0xdfa927a5 , 0x157b947c , 0x2d04a588 ] )
+  , Mont::unsafe_make( [ 0x8175a9cb , 0x16d0714a , 0x24feb960 , 0xc886223e , 0x453152e6 , 0x88f000bb , 0xc1bd3982 , 0x294a6f29 ] )
+  , Mont::unsafe_make( [ 0xb4a59632 , 0x7b1005fe , 0x19a72c97 , 0xda17c565 , 0x55914b6b , 0x4cf4377c , 0x92f3cfa9 , 0x120d7662 ] )
+  , Mont::unsafe_make( [ 0x3e819f26 , 0x73042e51 , 0x35de6c2b , 0x3fd58d9a , 0x0afd990b , 0x0de36c03 , 0xa7d631b9 , 0x27aaea3d ] )
+  , Mont::unsafe_make( [ 0x98946372 , 0xb3ee86ad , 0x87dcbf74 , 0xb45998f6 , 0x14d3916a , 0x033353a6 , 0x30bceda9 , 0x13d1d3ff ] )
+  , Mont::unsafe_make( [ 0x44127851 , 0xbe68f22c , 0x087cdbbf , 0xe07927c9 , 0x298f5446 , 0x8b4293ec , 0x7015428a , 0x074b4898 ] )
+  , Mont::unsafe_make( [ 0x52afdda2 , 0xbfdc86b5 , 0x369e6cd0 , 0x073b7634 , 0xe81ebd77 , 0xf233e5a0 , 0x1ad4993d , 0x0a2a9ea8 ] )
+  , Mont::unsafe_make( [ 0x2dea0a2a , 0x59b297b1 , 0xce0410ad , 0xa858dbee , 0xaad2cbef , 0xa4aaed04 , 0x23a1730d , 0x0434f0a4 ] )
+  , Mont::unsafe_make( [ 0xaf967786 , 0x90b28f73 , 0x3b24310b , 0x83afc2d9 , 0xd7d02902 , 0x22722582 , 0x91089db6 , 0x2d654ea4 ] )
+  , Mont::unsafe_make( [ 0x5cddf87e , 0xa7451e85 , 0x39574911 , 0x0a2e9b38 , 0x61037ba6 , 0xb870c367 , 0x387aa049 , 0x0e5b580f ] )
+  , Mont::unsafe_make( [ 0x1423d157 , 0xea27a713 , 0x8a566908 , 0xc9ac792a , 0xcc92c122 , 0x7ae051fb , 0x56f71cbe , 0x151d231e ] )
+  , Mont::unsafe_make( [ 0xcca08537 , 0x43377b7e , 0x4552c723 , 0xa21e0607 , 0xc2c9f8a2 , 0x5f900c7c , 0xcd04c342 , 0x0e7d9f95 ] )
+  , Mont::unsafe_make( [ 0xa3dfe019 , 0x7efd1260 , 0xdb282c07 , 0xc2bb91fd , 0x70a40275 , 0x102a3d7d , 0x3fc365aa , 0x18366634 ] )
+  , Mont::unsafe_make( [ 0x1b854d78 , 0xf5291621 , 0x7b243d49 , 0x8d3d855b , 0x9998b77e , 0x2298a225 , 0xdafbf667 , 0x2acee6db ] )
+  , Mont::unsafe_make( [ 0xb0ce1f2c , 0x979dfabe , 0x7bc794b4 , 0x020480a4 , 0x7d7a616b , 0x895313d4 , 0x5c978df8 , 0x1074d364 ] )
+  , Mont::unsafe_make( [ 0xabc64670 , 0x7abeb026 , 0x2df12680 , 0xd3283e29 , 0xaf739c6a , 0x01783855 , 0x9f12ff29 , 0x2c572e9a ] )
+  , Mont::unsafe_make( [ 0xa6547c97 , 0x30be61ac , 0xe696864f , 0xa78e0613 , 0x9c320313 , 0x62ae2e4c , 0xb8549866 , 0x1cb00817 ] )
+  , Mont::unsafe_make( [ 0x23e97ea4 , 0xdc599d88 , 0xefe79f37 , 0x93e5283a , 0x8bb2e6aa , 0x1c1f5f87 , 0xfb774fb6 , 0x2ba960bf ] )
+  , Mont::unsafe_make( [ 0x97bcafaa , 0x8c8a9b85 , 0x9a520947 , 0xc4f001c0 , 0xe8b9517e , 0xd3bb2fdc , 0x557cd9f7 , 0x1e8fb0a6 ] )
+  , Mont::unsafe_make( [ 0xd927d212 , 0x0d245d65 , 0x634dfd4f , 0xf11d2ce7 , 0xee89b4db , 0x5e92039d , 0x737a46fa , 0x17dee45f ] )
+  , Mont::unsafe_make( [ 0x968ab12c , 0x0ba84fe8 , 0x44429daa , 0xe6fb3137 , 0xb3d02d9c , 0x0f9a1a82 , 0x67d6e8a5 , 0x139b0a1f ] )
+  , Mont::unsafe_make( [ 0xcda8b09c , 0x9d7d1179 , 0xc1772960 , 0xc29d0589 , 0x0d5b144d , 0xf5c0bb83 , 0x74af4ae8 , 0x1e1acaa9 ] )
+  , Mont::unsafe_make( [ 0x59e0f41c , 0xd8cbe721 , 0x01bb3a56 , 0xb3b10b44 , 0x4d669022 , 0x75a03eab , 0xb134b3c9 , 0x251b5a14 ] )
+  , Mont::unsafe_make( [ 0xdf2e9090 , 0xe1d1f8f0 , 0x9d20c9e7 , 0x425e5bc6 , 0x0765c5e1 , 0x9e75fd99 , 0xbe7ac77d , 0x2c4f5e53 ] )
+  , Mont::unsafe_make( [ 0xb8b882f0 , 0xcdeae6e4 , 0xb9f6b7a4 , 0x70587780 , 0x7f1da2a6 , 0xf1b725e1 , 0x1f5b3469 , 0x2681ec5e ] )
+  , Mont::unsafe_make( [ 0x5bde2763 , 0x5c28dc47 , 0x5469261d , 0xd415e8b8 , 0xf4fbaea0 , 0x5d6628c1 , 0xfb78fcf2 , 0x1ba15f64 ] )
+  , Mont::unsafe_make( [ 0xa92a1b87 , 0x5fa0365a , 0xd44167df , 0x29f78ca1 , 0xbe97b6a2 , 0xcb9af065 , 0x457bfe41 , 0x2fd8d884 ] )
+  , Mont::unsafe_make( [ 0xa68ca33e , 0x9b7f67be , 0xe95d24f5 , 0xbad7cc5c , 0x40f14785 , 0xd70b67e5 , 0x8984f5ad , 0x06cf853e ] )
+  , Mont::unsafe_make( [ 0x29e40813 , 0x5cd92e53 , 0xbacf7fdf , 0xfcb2ff7e , 0xef825fc9 , 0x07f612d6 , 0xe0214b0a , 0x1563a2c4 ] )
+  , Mont::unsafe_make( [ 0xf1fde289 , 0x1b916b19 , 0x165d5f50 , 0x9c233079 , 0xcc1a973c , 0x24131922 , 0x77c5c213 , 0x26eb85b3 ] )
+  , Mont::unsafe_make( [ 0x79e96c45 , 0xf92d71b9 , 0xdd43d905 , 0x66356ed4 , 0x047e97b8 , 0x469ad4c1 , 0xf430dde9 , 0x1566a506 ] )
+  , Mont::unsafe_make( [ 0x90157283 , 0x42cb65c3 , 0x27b72af3 , 0x90b66906 , 0x80a62fe6 , 0xd6081d21 , 0xd6082d72 , 0x04c670cf ] )
+  , Mont::unsafe_make( [ 0x7a8ecdc0 , 0x28fa0777 , 0x135c3b0b , 0xafe8852d , 0x496d4c7f , 0x105ce637 , 0x074a49d8 , 0x0881f1fb ] )
+  , Mont::unsafe_make( [ 0x12fa3519 , 0xf15ba836 , 0x85465d3a , 0x86ababf6 , 0x8314c3ce , 0x60ec69e3 , 0x43d36903 , 0x2f9910c5 ] )
+  , Mont::unsafe_make( [ 0xe7350bbc , 0x171bcd96 , 0x813ca582 , 0xa94e10ad , 0xdbefa523 , 0x36ece16e , 0xda088a39 , 0x20ed172b ] )
+  , Mont::unsafe_make( [ 0x492e81c8 , 0xff19c030 , 0x1657c6b1 , 0x55ae7b3e , 0x12603641 , 0xa554869b , 0x81e184c7 , 0x0dc4ee9b ] )
+  , Mont::unsafe_make( [ 0x3a012392 , 0xabf6d656 , 0x222d28cd , 0x52eab2e8 , 0x138f00f2 , 0xe4087544 , 0x93e7cae0 , 0x2e2e10e3 ] )
+  , Mont::unsafe_make( [ 0xb3d99fa1 , 0xece4e105 , 0x5c509b5a , 0x72b88451 , 0x9ef1aa6b , 0xbc7dd2d9 , 0x87be3c5f , 0x24061c6b ] )
+  , Mont::unsafe_make( [ 0x46fd47af , 0x378f0d96 , 0xac9d549e , 0x7652e5e5 , 0x4345d2d3 , 0x38220e76 , 0xe276b07a , 0x271e731a ] )
+  , Mont::unsafe_make( [ 0x03af8e8a , 0xad33471b , 0x94732496 , 0x8decaa85 , 0x155b7a4b , 0xe64b8a6d , 0xbc086d61 , 0x2efd208c ] )
+  , Mont::unsafe_make( [ 0xfc455cea , 0x71a82077 , 0x2043ca0e , 0x585841a1 , 0x080e2d5c , 0xfcba635f , 0xc1f293f9 , 0x251c7c0c ] )
+  , Mont::unsafe_make( [ 0xef54cefb , 0x1ee32f69 , 0x368db721 , 0x97c4feec , 0x41ac5c5e , 0x58bdaff1 , 0x7d77e974 , 0x1306bcce ] )
+  , Mont::unsafe_make( [ 0x24b5e62a , 0xa058eda6 , 0xef7ab440 , 0x69630a81 , 0xea59013c , 0xe9f894ff , 0x4538fc1b , 0x2f642ce0 ] )
+  , Mont::unsafe_make( [ 0x5668875c , 0x9d4f8486 , 0x2b30d624 , 0xc5f277d7 , 0x7f65be43 , 0xd42a78d0 , 0x5454df75 , 0x09f97fda ] )
+  , Mont::unsafe_make( [ 0xacf34d05 , 0xbc7f75fa , 0xd2cbd9a4 , 0x56b0d28f , 0x2f4ddc11 , 0x9983605c , 0xf61972b5 , 0x058d5bdf ] )
+  , Mont::unsafe_make( [ 0x589da3ca , 0xc2d197d2 , 0x385e3701 , 0x84c84b01 , 0xdea62101 , 0x83658720 , 0x46701817 , 0x11824aad ] )
+  , Mont::unsafe_make( [ 0xfb08433e , 0x8a33d7ff , 0x7817e0f3 , 0xacd9d6a6 , 0xba7c0894 , 0xe174fd10 , 0x56cb89a5 , 0x0e155a2c ] )
+  , Mont::unsafe_make( [ 0x3d88b356 , 0x57a883f0 , 0xb8959f8e , 0xa0cfe006 , 0xeff08f0f , 0xe9a9d15f , 0x19147cc6 , 0x2b5d2bcb ] )
+  , Mont::unsafe_make( [ 0x0e5a23c3 , 0xb25cd4b9 , 0x277d01e7 , 0x28381d9d , 0xa7765d31 , 0x363edfc1 , 0xf225d7bb , 0x083c2597 ] )
+  , Mont::unsafe_make( [ 0x15f2a72c , 0x60be9290 , 0x93e373a7 , 0xceb44124 , 0xdbfe8781 , 0x650e63ff , 0x00c16d68 , 0x1b976b4e ] )
+  , Mont::unsafe_make( [ 0xa27b9eab , 0x1d0d2100 , 0xda0248a3 , 0x74172310 , 0xe7082f86 , 0x035e4860 , 0xbcf934aa , 0x12b44821 ] )
+  , Mont::unsafe_make( [ 0x0283e279 , 0x02b8bca0 , 0x41efbba6 , 0xb10b5837 , 0xc9f9c90b , 0x0e311e73 , 0xf078674d , 0x14fd4573 ] )
+  , Mont::unsafe_make( [ 0x165e48f4 , 0x93bf3af2 , 0x9b40dee8 , 0xd2153af1 , 0x31f33c6b , 0xf5b51518 , 0x9b8d8e41 , 0x0a8ba1d6 ] )
+  , Mont::unsafe_make( [ 0x86cecebe , 0x2fa68f11 , 0xe100d250 , 0x32f9b772 , 0x20fd243a , 0x9b57fb3b , 0x34cb28d7 , 0x0f4a0250 ] )
+  , Mont::unsafe_make( [ 0x64d7a965 , 0x3d904fc9 , 0x4b26e448 , 0x8e2b0d81 , 0x2780662a , 0x0b150a47 , 0x3be9c0b5 , 0x1e6f1379 ] )
+  , Mont::unsafe_make( [ 0x503b1c98 , 0xa98506be , 0xc96bd344 , 0xe2a873b8 , 0xda74d84e , 0x457ef0ee , 0x0ba15f86 , 0x26381fcd ] )
+  , Mont::unsafe_make( [ 0x96ce36d3 , 0xb9c6bf7f , 0x2331d96d , 0x0594f985 , 0xd1c638bf , 0xb9ded01d , 0xedf24a98 , 0x1ac2cba6 ] )
+  , Mont::unsafe_make( [ 0x623073fb , 0x777f2a1d , 0x2d1b7ff3 , 0xea4c68f5 , 0x08f9322c , 0xb0aa4a1d , 0xcd79ba66 , 0x1b2d60ff ] )
+  , Mont::unsafe_make( [ 0xffddf7e2 , 0x18685c66 , 0x57cf90cd , 0x598e8ecb , 0xd0cbb947 , 0xaf53af8b , 0x587bb7bb , 0x130eb5c2 ] )
+  , Mont::unsafe_make( [ 0x6a244d5c , 0x7773fa23 , 0x6b5a71d8 , 0x670fa084 , 0x175a343c , 0x296d153a , 0xf218542d , 0x098fb7cd ] )
+  , Mont::unsafe_make( [ 0xa9568149 , 0x6ce8cff8 , 0x17589769 , 0x7f14b24a , 0xd08be0d3 , 0x1547a84b , 0x4801de38 , 0x0774f465 ] )
+  , Mont::unsafe_make( [ 0x2da5009e , 0x52a30523 , 0xf2a48d6c , 0x5609ac58 , 0x312d9b89 , 0x6ee16cd5 , 0xbf6fe396 , 0x1b825a39 ] )
+  , Mont::unsafe_make( [ 0x44b9a4a4 , 0x3a93d92a , 0x6d1a1c0f , 0xa165c477 , 0x83048eff , 0xe1b04c68 , 0x6abb0845 , 0x27c02f70 ] )
+  , Mont::unsafe_make( [ 0xc58b03d4 , 0x26ca9488 , 0xe2317703 , 0x7fcda72c , 0x8e779cb4 , 0xa74a185a , 0xcdc58d6a , 0x241b76b5 ] )
+  , Mont::unsafe_make( [ 0xddd9a2a7 , 0x429b6da0 , 0xf131f0c0 , 0x30d001f1 , 0x934ee824 , 0x525c528c , 0xe910d1fa , 0x13e93655 ] )
+  , Mont::unsafe_make( [ 0x648cbb81 , 0xd7362087 , 0x7f7210a4 , 0xd83f7a29 , 0xce10f3ac , 0x971c307c , 0x55b5efaa , 0x1fb7aaed ] )
+  , Mont::unsafe_make( [ 0x49a514de , 0x85fc1d05 , 0x3df2f172 , 0x16c2a307 , 0x04912d95 , 0x4dbd4a94 , 0xedee4d28 , 0x1d4de670 ] )
+  , Mont::unsafe_make( [ 0x6382507d , 0x8781b6a1 , 0xde2aca88 , 0x6a37bc18 , 0x50d2e7f1 , 0x0a53ba80 , 0xd32fb8da , 0x0732c772 ] )
+  , Mont::unsafe_make( [ 0x4dd79b6a , 0x0abf4140 , 0x40a4f1f5 , 0xe7b4f883 , 0x0e4458bb , 0x78bc08bc , 0xde261fdc , 0x25908613 ] )
+  , Mont::unsafe_make( [ 0x3e51ad4e , 0xfa5fb605 , 0x59f8172a , 0x4315e956 , 0xa118777e , 0xc69b8282 , 0x3ba56459 , 0x122dbd11 ] )
+  , Mont::unsafe_make( [ 0x86459b06 , 0x897d0321 , 0x3d9dede1 , 0xf4e9af58 , 0x83cf1002 , 0x57a500ed , 0xc29ae4da , 0x2ae7fa28 ] )
+  , Mont::unsafe_make( [ 0x09f1de86 , 0x2322b7ed , 0xc8d4543f , 0x07629cf3 , 0x22c8cad2 , 0x57af4aab , 0x3fb10be0 , 0x220784e6 ] )
+  , Mont::unsafe_make( [ 0x5e38ad1f , 0xab224465 , 0x92def517 , 0x0f503ccf , 0xd53ad58d , 0xfe4c8a23 , 0x46186d6a , 0x0af4455d ] )
+  , Mont::unsafe_make( [ 0x9a6d83e0 , 0xc1fef98a , 0x35633d5c , 0xcf544b68 , 0x00addcbd , 0x4d923de1 , 0x1fe3c378 , 0x1ec0ef4f ] )
+  , Mont::unsafe_make( [ 0xece463c1 , 0x7fdfc243 , 0xdf7d198d , 0x3721b4cf , 0x65bba87e , 0x15a2c5fb , 0xcbb951d0 , 0x1cc68a1e ] )
+  , Mont::unsafe_make( [ 0x04860d51 , 0x980a0d20 , 0x5031f1ce , 0x752d1efc , 0x0f314ae5 , 0xb9ba96a0 , 0xe42b101b , 0x1bd1d063 ] )
+  , Mont::unsafe_make( [ 0xf297b09b , 0xa1438077 , 0x71eafae9 , 0xb4697a8b , 0x6a41379b , 0xe9bec42b , 0xc1089669 , 0x1b4e0fa0 ] )
+  , Mont::unsafe_make( [ 0xb7714868 , 0x7f0483f8 , 0x5b0fc3a5 , 0x3c6c4ae7 , 0x8429c668 , 0xfc07c1ec , 0x1b6d6b05 , 0x22d58072 ] )
+  , Mont::unsafe_make( [ 0x7b356c53 , 0xa52e1751 , 0x90d63547 , 0x8bdd3537 , 0xaffb1e51 , 0x3b9d1d3a , 0xcc7731d7 , 0x2408998c ] )
+  , Mont::unsafe_make( [ 0x2893ea8b , 0x76049c25 , 0x7aa8615f , 0x8608cf09 , 0x5b0b9138 , 0xd7b5c845 , 0x02e931d4 , 0x292dd9db ] )
+  , Mont::unsafe_make( [ 0x352e51ff , 0xc5c70a33 , 0x6105524b , 0xde5ffefb , 0xee8ce1bd , 0x0420b1ef , 0x62676699 , 0x20a95d67 ] )
+  , Mont::unsafe_make( [ 0xb10cd66b , 0xf081deb9 , 0xd7b08448 , 0x00df3319 , 0x944a9eff , 0xb9ffbe8c , 0x087e0994 , 0x10864f12 ] )
+  , Mont::unsafe_make( [ 0xe7311859 , 0xcd652b37 , 0x3acc6619 , 0x8becc051 , 0x2c616699 , 0xf70787e8 , 0x3e1f79a1 , 0x27cf79ee ] )
+  , Mont::unsafe_make( [ 0xc3963853 , 0x2282cf41 , 0x534c92e2 , 0x135a9610 , 0x48d08425 , 0xe84b160a , 0x35349015 , 0x044981d6 ] )
+  , Mont::unsafe_make( [ 0x2743eaee , 0xc93383ea , 0x2a8c736d , 0x71a2482c , 0xda55542c , 0xc9b20306 , 0x23ab547a , 0x28a43bff ] )
+  , Mont::unsafe_make( [ 0x312276fd , 0xf8e7793c , 0x2c155c4d , 0x461e9074 , 0x5b3913fa , 0xc180266f , 0xd09d9a4e , 0x1dc6cb53 ] )
+  , Mont::unsafe_make( [ 0x02bfdaa4 , 0x2d6b7fcb , 0xd1e07cf1 , 0xe817f76d , 0x44bbd144 , 0x5a3e70ad , 0x2872f1d4 , 0x27a39d43 ] )
+  , Mont::unsafe_make( [ 0x52be5ca0 , 0x36e992f2 , 0xa19f8824 , 0x72d3ca42 , 0xa998ef21 , 0xfcef7111 , 0xeb242563 , 0x0681c7ba ] )
+  , Mont::unsafe_make( [ 0x6bfe7fb4 , 0x3a664e74 , 0xb9772e80 , 0x1847fc85 , 0x31be8033 , 0x30f20327 , 0xdef47be1 , 0x0eddb30f ] )
+  , Mont::unsafe_make( [ 0xfb99b5cf , 0xf07251ab , 0x647b1b8a , 0xd46a845f , 0xdf8ca286 , 0x865bdcf3 , 0xbf8f0fe4 , 0x2280d79c ] )
+  , Mont::unsafe_make( [ 0x1195360c , 0x4b74d4eb , 0x891f816a , 0xfd86cfe1 , 0x8a4a2fc6 , 0x1343fa3e , 0x4bc129d6 , 0x252e2df5 ] )
+  , Mont::unsafe_make( [ 0x8e36569e , 0x59f35b1e , 0xbd5cbe42 , 0x21d0aef5 , 0x0dbb48a2 , 0x24c0f04d , 0x04f06329 , 0x2a0aea8c ] )
+  , Mont::unsafe_make( [ 0x0ed024f7 , 0x2f58c548 , 0x989b167e , 0x5d90531c , 0x1c0649c1 , 0xcb5fe637 , 0xe413fc9d , 0x148c0e7f ] )
+  , Mont::unsafe_make( [ 0x07e39f89 , 0x65f99d83 , 0x85e2893d , 0x1d4f6566 , 0xe570894c , 0x31e2ac6a , 0xbbdb0a7f , 0x139b2f20 ] )
+  , Mont::unsafe_make( [ 0x30ee59c1 , 0x2a58980f , 0xb6dde5cc , 0xdabf2c36 , 0x5f476d01 , 0x52de4366 , 0x01d418f3 , 0x2e038dfb ] )
+  , Mont::unsafe_make( [ 0x38997c28 , 0x1badd2f8 , 0x4036a815 , 0x51b0699b , 0xfea9783b , 0xab47c87a , 0x01f94e96 , 0x1c646658 ] )
+  , Mont::unsafe_make( [ 0xa2f72b88 , 0x36c0dcdc , 0x66ac70c1 , 0xb2e02d11 , 0x1167d595 , 0x6939a698 , 0x94543759 , 0x115cdb69 ] )
+  , Mont::unsafe_make( [ 0x2b4bfc6e , 0x1b92196a , 0xa2237922 , 0x2776da8d , 0x5ff3e28d , 0xc5f4f501 , 0xed1e1c4b , 0x12097cc2 ] )
+  , Mont::unsafe_make( [ 0x42047121 , 0xa601208b , 0x335263a6 , 0x0b152e54 , 0xe58f2905 , 0x0bb31c61 , 0xb7f6bab7 , 0x30421f6d ] )
+  , Mont::unsafe_make( [ 0x9a619f63 , 0x01e0abc2 , 0xad2e2ea9 , 0xddcf0a82 , 0xd3dfac32 , 0x1814264b , 0xcdc2a7b6 , 0x25b62244 ] )
+  , Mont::unsafe_make( [ 0xc4d182ea , 0x35135a78 , 0xcc9c58a9 , 0x8ca30e51 , 0x943a666a , 0x8f331830 , 0xd50e5249 , 0x0a0212f3 ] )
+  , Mont::unsafe_make( [ 0xec3f0ed4 , 0x24e32ff7 , 0xe6735f7f , 0xc9bdd46f , 0x48ebcbfa , 0xfa29f308 , 0x1a9bac27 , 0x11ba2910 ] )
+  , Mont::unsafe_make( [ 0x605c396a , 0xfd923c4b , 0x1f487973 , 0x5970c08c , 0xf0e651e3 , 0x36f35334 , 0x5a2579e3 , 0x1f65a1f6 ] )
+  , Mont::unsafe_make( [ 0xe193e9be , 0x672ea54d , 0x90bfa541 , 0x70fb582b , 0x4f11a324 , 0x8bdf5327 , 0x5fff2a0d , 0x23e6a813 ] )
+  , Mont::unsafe_make( [ 0x96d6939c , 0x2f9151c4 , 0xeee9e616 , 0xb35b4bcc , 0x2841967d , 0x4bb9432b , 0xb403b1a6 , 0x16caa702 ] )
+  , Mont::unsafe_make( [ 0xb45205f9 , 0xdeb8af09 , 0x53b4a9e8 , 0x40a3ee05 , 0xd8a87fdb , 0x2545a998 , 0x1cc18f90 , 0x2fa67ef0 ] )
+  , Mont::unsafe_make( [ 0x5ec731f8 , 0x3b76a454 , 0x03d4b030 , 0x26ef408f , 0x91c9196c , 0x22406658 , 0xbc38f53c , 0x1d595ea1 ] )
+  , Mont::unsafe_make( [ 0x4f452b99 , 0x9c71e8bc , 0x5d5a358e , 0x08ce1581 , 0xb0e34957 , 0x4bc1136e , 0xb5007edb , 0x2e121eb1 ] )
+  , Mont::unsafe_make( [ 0xe68d8750 , 0x9920989b , 0xf59359cd , 0xffab5e81 , 0x4d62e090 , 0xeb7b7649 , 0x8ea6e027 , 0x053da4a4 ] )
+  , Mont::unsafe_make( [ 0xc28bd68a , 0x27106410 , 0xc2fe54be , 0x05260034 , 0xbae0327a , 0x821b9530 , 0x23e9d703 , 0x18c57940 ] )
+  , Mont::unsafe_make( [ 0xcb15548b , 0x75d0a2e9 , 0xc94a8aea , 0xc68f9f5a , 0xbf06e782 , 0xf9d3e174 , 0xf3e565a5 , 0x1d826bcd ] )
+  , Mont::unsafe_make( [ 0x035a70d3 , 0xf7f17274 , 0x7416fd33 , 0x66728170 , 0x893b90bb , 0x003c9e55 , 0x7ddd1b0c , 0x1fe1c50f ] )
+  , Mont::unsafe_make( [ 0x41b4dbe6 , 0x9ae17e51 , 0x0b69220e , 0xc16c2d03 , 0xe7395d75 , 0x99b3790f , 0xcda29e5d , 0x2460ffa3 ] )
+  , Mont::unsafe_make( [ 0x2e174d25 , 0x86cc4ee1 , 0x1d979b21 , 0x08cc1697 , 0x64fd8e3f , 0x85ef515b , 0x7ab90701 , 0x15bffa1b ] )
+  , Mont::unsafe_make( [ 0xf9a73740 , 0x4071341e , 0xa064dbf5 , 0x53e22cd9 , 0x1d04adf7 , 0xc85029b2 , 0x6fb68c00 , 0x2453f6db ] )
+  , Mont::unsafe_make( [ 0x923709b5 , 0x478d5e7b , 0x368432d5 , 0x5e79eacb , 0x94c29e15 , 0x8c66d782 , 0xf597f830 , 0x0f7b3e4f ] )
+  , Mont::unsafe_make( [ 0xaac809ef , 0x36a6cd5a , 0x05b438ae , 0xe86beceb , 0xfc3ae388 , 0x08740998 , 0xb8a4d592 , 0x110e9b12 ] )
+  , Mont::unsafe_make( [ 0x5eb8c0be , 0x3c51d8d4 , 0x704b7eac , 0xadc73a5b , 0x2608f4bb , 0x77533085 , 0xbc5fffa9 , 0x20c5ce0f ] )
+  , Mont::unsafe_make( [ 0xa641b361 , 0x9383ccb8 , 0x02deefdf , 0xabf0c39c , 0xcd6aa53b , 0x7fcfc399 , 0xccabab19 , 0x1875c5eb ] )
+  , Mont::unsafe_make( [ 0xf08f8bcb , 0x04ecb569 , 0x6aa061da , 0xb25419bd , 0xd0067de4 , 0x0289c611 , 0x03f0c109 , 0x23961b2d ] )
+  , Mont::unsafe_make( [ 0x8a6d02a0 , 0x453ec999 , 0xb8a7d2ce , 0xdfffa7cf , 0x520de8ee , 0xfdab6753 , 0x55cf65ff , 0x1228962a ] )
+  , Mont::unsafe_make( [ 0x203241c9 , 0x0c437ef9 , 0xcf00e33c , 0x8b9543c8 , 0x1b39149f , 0x2b0fb59b , 0x5f09f16d , 0x172817ef ] )
+  , Mont::unsafe_make( [ 0x1a93ce74 , 0x1d8592f1 , 0x4e91ee4b , 0xcf1b2908 , 0x2ff41a5e , 0x1da790a4 , 0x806ceb43 , 0x16839ef2 ] )
+  , Mont::unsafe_make( [ 0xfc041334 , 0x30a57514 , 0xdf1d3384 , 0xc8d6981e , 0x56473c90 , 0xf5b285d4 , 0x8f757af9 , 0x18755191 ] )
+  , Mont::unsafe_make( [ 0x687ab169 , 0x9b9a984a , 0x17ca782a , 0xaea05735 , 0x4f9ad4a9 , 0xbd53cbcd , 0x3ccf8f3c , 0x289b2a7a ] )
+  , Mont::unsafe_make( [ 0x5db9ff24 , 0x5c8ec991 , 0xeea36d95 , 0xc8da3ab1 , 0x2955d6a2 , 0x2d6183fa , 0xd5b1ca82 , 0x24997d28 ] )
+  , Mont::unsafe_make( [ 0x8aaf1acd , 0xa06abd85 , 0xbe35bb11 , 0x8bb21e26 , 0xe4e3abe2 , 0x7127a11e , 0x1b23d0f3 , 0x1c3bd83f ] )
+  , Mont::unsafe_make( [ 0x152f761d , 0x3427d764 , 0x6ebd34de , 0xbf6f2e54 , 0xd47bc088 , 0xb8b4dae7 , 0x8bec3c9d , 0x2a547d5d ] )
+  , Mont::unsafe_make( [ 0x849a11eb , 0xeb1cb5dc , 0x5212b9f8 , 0xe394ebd4 , 0x6eab7bcf , 0xa0477e90 , 0x888ca737 , 0x1b54cce6 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// circomlib's optimization whatever
+pub const CONST_S: [Mont; 1984] = 
+  [ Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xe78cf83c , 0x22acbd0b , 0x60939074 , 0x51801156 , 0x11827d4f , 0x509e6e84 , 0x52a81a1b , 0x00788833 ] )
+  , Mont::unsafe_make( [ 0x83b7c527 , 0x35c793bc , 0x93e78ada , 0x127fca87 , 0x3f69d6d2 , 0xb334ba29 , 0x12283fd8 , 0x2a788ca9 ] )
+  , Mont::unsafe_make( [ 0x9bfcc498 , 0xe8d6323e , 0xa7c7538e , 0x7711cd4a , 0x5564c7c7 , 0x00e1f5b5 , 0x2334aa3f , 0x06ee2f32 ] )
+  , Mont::unsafe_make( [ 0x1153e5a8 , 0x051e261f , 0xac1401a9 , 0xe2bedeb3 , 0x40a31e1c , 0xe19427b8 , 0x52dbadd2 , 0x2fdfffba ] )
+  , Mont::unsafe_make( [ 0x365d66b5 , 0x46962f43 , 0xcbf3b64e , 0x3752dc66 , 0x91f2af1c , 0x319772d0 , 0x23722b46 , 0x028c33b7 ] )
+  , Mont::unsafe_make( [ 0x31f41f9f , 0xaa6b41ed , 0x20ed88ab , 0x3111b3fd , 0xbc395ab0 , 0x8e673abb , 0x8a4f2a13 , 0x23079d40 ] )
+  , Mont::unsafe_make( [ 0x7b781d3d , 0x4407cec9 , 0x76473927 , 0xa80ebf5e , 0x7a55f129 , 0x83805718 , 0xd6f3e342 , 0x13c6e77a ] )
+  , Mont::unsafe_make( [ 0x7fe8a877 , 0xeca371aa , 0xe0302df3 , 0xc409028d , 0xeb27c007 , 0x02db841b , 0x796dcfab , 0x063dd3dd ] )
+  , Mont::unsafe_make( [ 0x37fe0e79 , 0xf0b7147b , 0xc584616f , 0xcacbb6b7 , 0xdae5e40d , 0xb1873bad , 0x6bb88e05 , 0x25bfddcd ] )
+  , Mont::unsafe_make( [ 0x725df284 , 0xd0d49c5b , 0x3ee5eaa5 , 0x9880168f , 0x9bb6ae15 , 0xd4e100a9 , 0xf9d76673 , 0x011a6094 ] )
+  , Mont::unsafe_make( [ 0xddc759d4 , 0x98a1fb1a , 0x41c9552f , 0x9c6ea193 , 0x8812a91a , 0x5335aad6 , 0xb90980b0 , 0x1af001e2 ] )
+  , Mont::unsafe_make( [ 0x0ed804c9 , 0xc0b07a71 , 0x64405c1c , 0xb72e106e , 0x8d593b76 , 0x580d6bd1 , 0x7abae7b5 , 0x01dde1ca ] )
+  , Mont::unsafe_make( [ 0x4ece3944 , 0x9b809655 , 0xfd9bf98a , 0xc7935c14 , 0xca8a3fd8 , 0xb63f0b7d , 0xff917a5a , 0x2f27f89e ] )
+  , Mont::unsafe_make( [ 0x78e9c41d , 0x547d9a6b , 0xca761c4d , 0x10ab76b2 , 0xba971115 , 0x76df1b11 , 0xd2cf8928 , 0x12cd98f4 ] )
+  , Mont::unsafe_make( [ 0x203480fd , 0x2d6cab4b , 0xfd51ff99 , 0x8a0cd8bb , 0xe4e01699 , 0xecf7754d , 0x088f2693 , 0x261293e5 ] )
+  , Mont::unsafe_make( [ 0x447104b3 , 0x003511a8 , 0x958431e3 , 0x42cce205 , 0x1c3605fc , 0x8ed95d1c , 0xc4fc69b4 , 0x0b43f668 ] )
+  , Mont::unsafe_make( [ 0xa99412b1 , 0xe72fab13 , 0xfb151844 , 0xcdae6bf7 , 0x152c2e63 , 0xed00b136 , 0xcfc62e47 , 0x212cc345 ] )
+  , Mont::unsafe_make( [ 0xb8abd809 , 0x7a43b9d3 , 0xcb7c88f9 , 0x6f27ec16 , 0xab5e697c , 0xb572d8a2 , 0x9967b968 , 0x2f387a32 ] )
+  , Mont::unsafe_make( [ 0xc34b0a41 , 0x4b28e8b8 , 0x37b5579e , 0xe9a10ad3 , 0x58e218da , 0x530a0e9c , 0xb8334216 , 0x127fd53a ] )
+  , Mont::unsafe_make( [ 0x2e959b3a , 0xfef2f258 , 0x9bab6f20 , 0xc3f88ca2 , 0x387d7392 , 0x8fc3964d , 0xc01e7b7b , 0x0cb121c1 ] )
+  , Mont::unsafe_make( [ 0x118973ec , 0x9dd8ebc6 , 0x9fc3a59c , 0xe39571f0 , 0x08a14c12 , 0xc888d6a8 , 0xb2d53a9e , 0x225c87b6 ] )
+  , Mont::unsafe_make( [ 0x5e5a8ad5 , 0x9780aef5 , 0x9725219b , 0x787256f2 , 0x57a81f05 , 0x212e565a , 0xfe9a9886 , 0x123925f5 ] )
+  , Mont::unsafe_make( [ 0x7eda20ac , 0xc8deac5c , 0x435396c2 , 0x32f51ae1 , 0x336faa39 , 0x06961e7c , 0x8030859a , 0x033b3221 ] )
+  , Mont::unsafe_make( [ 0xa5506a7e , 0xc4951941 , 0x6d0408e3 , 0x0d37e923 , 0xa24429d5 , 0x4ab82d3f , 0x8a40bb7d , 0x2b2bd32d ] )
+  , Mont::unsafe_make( [ 0x7e592bf4 , 0x19b7e19f , 0x3fe1a3ae , 0x2e3f3ca9 , 0xdeea5c8e , 0x488e8282 , 0x84946090 , 0x276881d3 ] )
+  , Mont::unsafe_make( [ 0xa2ebfcaa , 0x62528fd7 , 0x5ac7a119 , 0x74f2a9b3 , 0xca664f6d , 0xaf49ffef , 0x9c826fc0 , 0x0338bb6e ] )
+  , Mont::unsafe_make( [ 0x26781d34 , 0x3dfd9586 , 0xb0ff9319 , 0xcb6a3d10 , 0xb175c8ae , 0x1691878c , 0xeb9d87aa , 0x2420c69f ] )
+  , Mont::unsafe_make( [ 0x049f2f50 , 0xaba9c734 , 0x556d520f , 0x0760f4c2 , 0x0d4c2104 , 0xa96883bb , 0x3e4f49c9 , 0x0bbb333c ] )
+  , Mont::unsafe_make( [ 0xa2a5783e , 0x7992c673 , 0xb17f5527 , 0xb04c8799 , 0xc8bab42c , 0xe19d6a1c , 0xfa0a9049 , 0x2dfa471a ] )
+  , Mont::unsafe_make( [ 0xfe0d610d , 0x1dabc30b , 0xa1e293e2 , 0x1c42c3db , 0x717861c3 , 0x9b18404a , 0x9318448f , 0x20f37774 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xf9bd7fe6 , 0x40f7827c , 0x6ca83593 , 0xca69e89f , 0x471fa341 , 0xc687d90d , 0x38123535 , 0x13e497d1 ] )
+  , Mont::unsafe_make( [ 0xad16ea1b , 0x8d1cfcf9 , 0x61a6dc10 , 0xeea233b7 , 0x0749116e , 0xed0a56a0 , 0x5b783c49 , 0x15874193 ] )
+  , Mont::unsafe_make( [ 0xeb0c9b9f , 0xcaf07ae8 , 0x3d9e4398 , 0x7225b334 , 0x06be37a7 , 0x52001b11 , 0x96b3e328 , 0x047919a7 ] )
+  , Mont::unsafe_make( [ 0x3dc032a4 , 0x88bbb80f , 0xa8b6acef , 0x2d6f2b45 , 0x893de38d , 0xff90f9ef , 0xdf4f3b5a , 0x0a76e9a6 ] )
+  , Mont::unsafe_make( [ 0x6fee45a9 , 0x612ae715 , 0x391e11c9 , 0x4d2312c9 , 0x965b207f , 0xe7e7c2ef , 0x41d26ebe , 0x12bc5d2c ] )
+  , Mont::unsafe_make( [ 0xfc1a9d1e , 0x089b3b06 , 0x080bdd68 , 0xc48d41c2 , 0x12c66ebf , 0xa645eb39 , 0xb81663e4 , 0x0629a951 ] )
+  , Mont::unsafe_make( [ 0xbf8fe2ed , 0x4fcfdffa , 0x03237574 , 0x1f550307 , 0xe9bff60b , 0xdc7725a5 , 0x1dba081a , 0x1f33ca11 ] )
+  , Mont::unsafe_make( [ 0x3b6a2bc4 , 0xd9123173 , 0x4207441e , 0xe1ef3cf6 , 0xac44ff07 , 0xca911979 , 0xce742750 , 0x1618b074 ] )
+  , Mont::unsafe_make( [ 0xce69a2f5 , 0x44494e11 , 0x0cd17436 , 0x9a661c50 , 0x83e3ae99 , 0x50300947 , 0x8ef7510e , 0x1aa0794d ] )
+  , Mont::unsafe_make( [ 0x1e51d178 , 0xeeeec3f0 , 0xfa64e9ce , 0x4d370847 , 0x2a3fd265 , 0x4a71fb0d , 0xd11b853f , 0x2045d284 ] )
+  , Mont::unsafe_make( [ 0x09bf7d71 , 0x22982823 , 0xcdfa1e0c , 0xb92bb478 , 0x996e6aeb , 0x6e8c21d1 , 0x64aa1d47 , 0x138957a6 ] )
+  , Mont::unsafe_make( [ 0x4dd7cc4a , 0xd8e42986 , 0x92209c0d , 0x3e6bed7f , 0x8f88178c , 0x8dd10a36 , 0xe36d7536 , 0x2fc8b147 ] )
+  , Mont::unsafe_make( [ 0xc451c72e , 0xdf2915ca , 0x6975338c , 0x1f865ecf , 0x86aa3536 , 0x1bac3b2b , 0xf7613190 , 0x146bcc22 ] )
+  , Mont::unsafe_make( [ 0xe2fcf3d0 , 0xe53ce0e7 , 0x311625cb , 0x25b87047 , 0x2d0e0a15 , 0x821248c7 , 0xe0bed959 , 0x0418b624 ] )
+  , Mont::unsafe_make( [ 0x97bef930 , 0x0cfff211 , 0x48dcd16a , 0x592e7d22 , 0xa4372e99 , 0x890606c9 , 0x62a025fb , 0x087dc62b ] )
+  , Mont::unsafe_make( [ 0x6df895f1 , 0x19eb1318 , 0x18f530ea , 0x0cec4d21 , 0x53b2b8d4 , 0x3a736433 , 0x9c6399c0 , 0x179e5b0d ] )
+  , Mont::unsafe_make( [ 0x689d8972 , 0x549bfb76 , 0x1a203286 , 0x82d85025 , 0xddf16a9a , 0x0ac44ec7 , 0x8e3c5560 , 0x0172006e ] )
+  , Mont::unsafe_make( [ 0x434c3796 , 0xcf594b6a , 0x91070af3 , 0x5c3d492f , 0x74421675 , 0x68539a67 , 0x77eeca65 , 0x10812ed6 ] )
+  , Mont::unsafe_make( [ 0xe2a757b2 , 0x906ec287 , 0x19127abe , 0xf4de3d3d , 0xb2b40286 , 0xc03f5a1d , 0x8e7c6ff1 , 0x1dd753e2 ] )
+  , Mont::unsafe_make( [ 0x2ca69d04 , 0x117bf323 , 0x0fd420bc , 0x3b476738 , 0x9776742f , 0xf04004ed , 0xc08b4457 , 0x107d33e2 ] )
+  , Mont::unsafe_make( [ 0x0c9acf70 , 0x0136ac0f , 0x55487180 , 0xccc0311e , 0x97b802eb , 0xa7a7bc3d , 0xafe61862 , 0x1756edc4 ] )
+  , Mont::unsafe_make( [ 0x76772b7a , 0x04b15618 , 0xdc3767f0 , 0x5b69ebc3 , 0xabc38661 , 0xd65701b3 , 0x94a64b21 , 0x089147e5 ] )
+  , Mont::unsafe_make( [ 0xb1a78b2a , 0xab3af499 , 0xfd6314a0 , 0x01f115b4 , 0xd751392d , 0x6b60d1db , 0x44f3874f , 0x1a1f43cd ] )
+  , Mont::unsafe_make( [ 0x9b22b24d , 0x5f3384d6 , 0x165c0f3e , 0x8be62ee9 , 0x98e91efe , 0x43f94bd6 , 0xcda360c0 , 0x08ff7516 ] )
+  , Mont::unsafe_make( [ 0x90de6fb7 , 0x51a25d90 , 0xc2772da1 , 0x6c85c95d , 0x18a5ec8e , 0xdcd7bb0e , 0x24984aea , 0x21d96b89 ] )
+  , Mont::unsafe_make( [ 0x60e778e7 , 0x6461b805 , 0x744a415a , 0x923f00f9 , 0xcd459a73 , 0x9c25441e , 0x55ad7b34 , 0x2e9a2bc4 ] )
+  , Mont::unsafe_make( [ 0x3d6e4139 , 0xb355294a , 0x0d3abd60 , 0x33689b81 , 0xad1144b4 , 0x7e444167 , 0x9c1a0217 , 0x0b8c6e81 ] )
+  , Mont::unsafe_make( [ 0x847a1be0 , 0xc3397985 , 0xb8069198 , 0x292b2fb4 , 0x46affac0 , 0xcc6ce5f4 , 0x4758f10b , 0x08c1507a ] )
+  , Mont::unsafe_make( [ 0x6ec5b368 , 0x363f0379 , 0x860289e1 , 0xcf854af6 , 0x4de59780 , 0x55d36ca7 , 0xc55ddbdd , 0x0cca48ff ] )
+  , Mont::unsafe_make( [ 0xd722d30d , 0xbad87886 , 0xed4812a5 , 0xc441b7f1 , 0xdc6aa554 , 0x028d5e24 , 0xe9ea60dd , 0x26d44d6a ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xa19a2d3e , 0x778eebd7 , 0xdaad571e , 0xe360fb7d , 0xd091ae9e , 0x74deae3a , 0x3d8d61d1 , 0x1dd9c5c0 ] )
+  , Mont::unsafe_make( [ 0x04c707ff , 0x647e7309 , 0xfda13667 , 0x0913ced3 , 0xa96429e9 , 0x102a6701 , 0xfdc3f595 , 0x075892b0 ] )
+  , Mont::unsafe_make( [ 0x1f27aa3b , 0xcc4bb564 , 0x442a0c6f , 0xf08d3373 , 0xbbab3daf , 0x5aea48e7 , 0x681677e7 , 0x2f42da9b ] )
+  , Mont::unsafe_make( [ 0xe901eb44 , 0x11191e3a , 0x547eb824 , 0x89b13b98 , 0xedf00367 , 0x3e2eab30 , 0xe728072d , 0x0f8dff42 ] )
+  , Mont::unsafe_make( [ 0x82322262 , 0x82d0c88f , 0x5ad6dd0d , 0x80e02cb9 , 0xa95bda05 , 0xa0a28c18 , 0xba813f94 , 0x14a00037 ] )
+  , Mont::unsafe_make( [ 0x9c0eba0f , 0x1a0e2e28 , 0xa3b3a115 , 0xbb0095e9 , 0x060b95b5 , 0xe0a9c9b5 , 0x6cfeb678 , 0x22805bb4 ] )
+  , Mont::unsafe_make( [ 0x30935969 , 0x2564b5bb , 0xda1e59b9 , 0x09f8297f , 0x72b26cef , 0xfc7720c3 , 0x27ed59f5 , 0x1b862eeb ] )
+  , Mont::unsafe_make( [ 0x961ba95a , 0x7c9bbe37 , 0x0204cf57 , 0x0252e3ab , 0x8e2bf57f , 0x297481d1 , 0x98dede06 , 0x299443f3 ] )
+  , Mont::unsafe_make( [ 0x601e95dd , 0x0d73822b , 0x87ea309a , 0x5bf5631a , 0x1c2788b3 , 0xcb6ae775 , 0xe4515262 , 0x0a14575b ] )
+  , Mont::unsafe_make( [ 0x96c7a6a0 , 0x2c5b50ba , 0xa525ba38 , 0x626b48cf , 0xc0110ea5 , 0x4046bae9 , 0x8038417a , 0x09c57a8a ] )
+  , Mont::unsafe_make( [ 0x4d4c6987 , 0x4dee80c6 , 0x193cb06f , 0xffb6c407 , 0xae2fbef6 , 0x2cdc535e , 0xded2b6a0 , 0x10bc360d ] )
+  , Mont::unsafe_make( [ 0x885432a1 , 0xac8e55a6 , 0x21011b21 , 0x0789634d , 0x02b666f2 , 0xa688f9a2 , 0x7c3bf098 , 0x094792da ] )
+  , Mont::unsafe_make( [ 0xbc897467 , 0xb949ab33 , 0xd68d65c5 , 0xa7973e17 , 0x164f46f9 , 0xa67e0797 , 0xb20bc840 , 0x2e0d029b ] )
+  , Mont::unsafe_make( [ 0x790ed7ec , 0x8e6b0382 , 0xeb86908d , 0x3705d535 , 0xcbdbbe00 , 0x56ceedab , 0x92ad9930 , 0x0875c855 ] )
+  , Mont::unsafe_make( [ 0xa12c6c61 , 0x5c265579 , 0xb7f1821f , 0x2e7e8ab8 , 0x97dd72f5 , 0xe8de4a1c , 0x4db9e3ba , 0x00ba6cc1 ] )
+  , Mont::unsafe_make( [ 0xea26e22a , 0x0b653adf , 0x4588e561 , 0x65a2a71f , 0x68763110 , 0xb6982b27 , 0x38242ca6 , 0x2d40a2d1 ] )
+  , Mont::unsafe_make( [ 0xc73bd614 , 0xf046efa9 , 0x0b1b791f , 0xbb83c5ff , 0x5bd5e66d , 0x58b96e72 , 0xa0770a69 , 0x08074799 ] )
+  , Mont::unsafe_make( [ 0x7b5d9134 , 0xdf589bfe , 0x4b68949a , 0x3354523f , 0xcf701983 , 0x5b58b2d6 , 0xae9510b8 , 0x2b949e0a ] )
+  , Mont::unsafe_make( [ 0xb6edf83b , 0x9eed6079 , 0x3e792f2d , 0x738e54fb , 0x85bbe07c , 0xc2d578d0 , 0x89692a41 , 0x05eb6241 ] )
+  , Mont::unsafe_make( [ 0x7c884827 , 0xc403b2ec , 0x2213961c , 0x3c0a7176 , 0xc86605c3 , 0xa34bd94a , 0xa267dde1 , 0x11521793 ] )
+  , Mont::unsafe_make( [ 0x50cd4a7c , 0xada9a2dc , 0xa8c84064 , 0xd94e703c , 0xfb43b235 , 0x6faebc83 , 0x3ab5f656 , 0x02da1deb ] )
+  , Mont::unsafe_make( [ 0x05cd1dd2 , 0x2755b65d , 0x431733e5 , 0x5101e1f3 , 0xf1850232 , 0x12949f10 , 0xee5f85a9 , 0x0d418efd ] )
+  , Mont::unsafe_make( [ 0x586ad2cf , 0xcbe48472 , 0x01f37ee8 , 0x0781f4c7 , 0xd02a1b20 , 0x97c4a54d , 0x08667328 , 0x224bab85 ] )
+  , Mont::unsafe_make( [ 0xf104d149 , 0x1fdeaf0e , 0xd123522b , 0xf05d39cf , 0x82f883b1 , 0xd8999748 , 0x7e293317 , 0x05009e98 ] )
+  , Mont::unsafe_make( [ 0x931a9e89 , 0x5c8979af , 0x410a7f52 , 0xa1523565 , 0x2356a4a4 , 0x2f16721c , 0x6d842315 , 0x11bb7045 ] )
+  , Mont::unsafe_make( [ 0x031b3157 , 0xf33fb8c4 , 0xdc4892f6 , 0x75eae4fd , 0xc8a1c7ec , 0x52941aef , 0x32d0633d , 0x25d9f1c0 ] )
+  , Mont::unsafe_make( [ 0x63b71fbc , 0xc0b85c37 , 0x0af199ff , 0x2f11f210 , 0xffd1d866 , 0x7118ea1a , 0xfd8145e6 , 0x29860807 ] )
+  , Mont::unsafe_make( [ 0x4d3a0c01 , 0x56ac9f50 , 0x04046e8b , 0xb2f04dce , 0x9170eb7e , 0xa4d0da71 , 0x32b2df9a , 0x0a57240d ] )
+  , Mont::unsafe_make( [ 0x64d472c5 , 0xbb8b667c , 0x1098ef00 , 0x442fff8a , 0xf6ccb298 , 0xa6eb35f1 , 0x0a4088fe , 0x0ed57fb6 ] )
+  , Mont::unsafe_make( [ 0x22ff4e76 , 0x0e94ed07 , 0xc2f3f4dc , 0x17fea74b , 0x56aae67d , 0xbb837c49 , 0x71a39479 , 0x0578db5b ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x222eca5c , 0x60ceb00c , 0xb05ab497 , 0x69a55eb4 , 0x6dbd367d , 0x963d4c48 , 0x2534aec1 , 0x1ce982b4 ] )
+  , Mont::unsafe_make( [ 0x4ebf66ca , 0x66bcc71b , 0xf1342554 , 0x01da7ebe , 0x411f28b3 , 0xb9dbfd14 , 0xc22727fa , 0x1bc58478 ] )
+  , Mont::unsafe_make( [ 0x2dbbd17e , 0xde1471b5 , 0x1ec1121d , 0x776b8a3a , 0x46931479 , 0x5690d2ab , 0xd4294211 , 0x2bff3377 ] )
+  , Mont::unsafe_make( [ 0x7855345c , 0xdb1ecbc4 , 0x13cc3a02 , 0xd232d58a , 0xf9c30d43 , 0x9ac4c41a , 0xde24934c , 0x1586d1a4 ] )
+  , Mont::unsafe_make( [ 0xd5f1e29a , 0x18ac0b50 , 0xa29394f8 , 0x042cc1b6 , 0x35b52553 , 0x05911985 , 0xef6ebab5 , 0x251c4f4d ] )
+  , Mont::unsafe_make( [ 0x03e70102 , 0xadcf280c , 0xa99d9dc5 , 0xd828d8ee , 0x55224f78 , 0xa557f183 , 0x7ce7b56b , 0x213ce713 ] )
+  , Mont::unsafe_make( [ 0xed436268 , 0x56fa7f2f , 0x8f67be82 , 0xa2d40ad7 , 0xa9d44547 , 0xd24b57d6 , 0x9ad68a11 , 0x064a795c ] )
+  , Mont::unsafe_make( [ 0xabfa53fd , 0x50f15e7c , 0x939ddf4b , 0x7c40f8ee , 0xfbcee971 , 0x97101513 , 0x6972d32b , 0x2f0ac55c ] )
+  , Mont::unsafe_make( [ 0x202a2b19 , 0x2c5aa8c9 , 0x9fc5640f , 0x4f0ced72 , 0xd5f028b5 , 0x8c00cd94 , 0x47e906ad , 0x0318f743 ] )
+  , Mont::unsafe_make( [ 0x2a259c32 , 0xa3c94978 , 0x80ab7111 , 0x443fef72 , 0x0fe8f298 , 0x6b106d2d , 0x1673a932 , 0x2627f51d ] )
+  , Mont::unsafe_make( [ 0xeadc30f6 , 0xc704474f , 0x88131079 , 0xc5b0cbb6 , 0x5d1bbdb7 , 0xe1a15cbc , 0x5bda10e6 , 0x29bab95e ] )
+  , Mont::unsafe_make( [ 0x1a0f05b7 , 0x19c65f9d , 0xb78b04ac , 0xd5a53966 , 0x9c2b1cf1 , 0x194f48c4 , 0x9cf5035b , 0x1a5b9251 ] )
+  , Mont::unsafe_make( [ 0xa6103075 , 0xa76137a8 , 0xbadde9d0 , 0xd9a01bbb , 0xeb70a94a , 0x5c476649 , 0x10cdc914 , 0x266e3f07 ] )
+  , Mont::unsafe_make( [ 0x42db0665 , 0x07e14ff0 , 0x807110ab , 0x8075e2c3 , 0x52ce842e , 0x1c8f391c , 0x01de9eba , 0x21cc2bc9 ] )
+  , Mont::unsafe_make( [ 0xd90d0da3 , 0x8d1b738a , 0x615492b9 , 0x8387d5d0 , 0x94f02fbb , 0xec24ffc2 , 0x09082b5c , 0x2432261e ] )
+  , Mont::unsafe_make( [ 0x42dca045 , 0x211c5223 , 0xf6e1d238 , 0x3389506b , 0x1054c6b8 , 0x7d323f08 , 0x35789ebc , 0x2a30ff24 ] )
+  , Mont::unsafe_make( [ 0xc953136e , 0xead75f53 , 0x2139301f , 0xb4077668 , 0x172c513e , 0xd0e7fabf , 0x6deccedd , 0x0b7e0f38 ] )
+  , Mont::unsafe_make( [ 0x3542d174 , 0x4d94dad5 , 0xfac1213a , 0x86e10be3 , 0x099c287f , 0x2fdfa753 , 0x52da32d2 , 0x1acbf7c6 ] )
+  , Mont::unsafe_make( [ 0x6cd3cde6 , 0x511f4be0 , 0x7adc0173 , 0xf4c85be3 , 0xedb34142 , 0x43b88b03 , 0x588fce8a , 0x122dbfca ] )
+  , Mont::unsafe_make( [ 0x558c4bc6 , 0x33577dfd , 0x21423933 , 0x89de05ce , 0xac464d89 , 0x06c1d828 , 0xd63a899f , 0x0ec29d27 ] )
+  , Mont::unsafe_make( [ 0x16baf74d , 0xc6e16f99 , 0x735c58d6 , 0x5d759256 , 0x0f6819f5 , 0x94359303 , 0xa0c9d57d , 0x0496215c ] )
+  , Mont::unsafe_make( [ 0xcbf30ca3 , 0x96cbc028 , 0x5defc3a3 , 0xe9aeb55f , 0xdb4f66d8 , 0xa39b7218 , 0x9e3c8b3f , 0x0c2f3c86 ] )
+  , Mont::unsafe_make( [ 0x920d6201 , 0x8191189f , 0x9323824e , 0x3ed4da74 , 0x5d3a1269 , 0x38ddcc49 , 0x072104c9 , 0x2da29bc0 ] )
+  , Mont::unsafe_make( [ 0xf7a6896c , 0x61ab3f19 , 0xd7b9eae3 , 0x8274a0f2 , 0x48ee047f , 0x97457678 , 0x63701397 , 0x10f8cecb ] )
+  , Mont::unsafe_make( [ 0x3cf500ef , 0x938d8f82 , 0x034253b3 , 0xa1426dcc , 0x4231f3d1 , 0x12f37cb8 , 0x73567f23 , 0x20787b55 ] )
+  , Mont::unsafe_make( [ 0x8837446e , 0x375decc9 , 0x6808c8da , 0xd2696f0e , 0x188642a1 , 0xeda39bd4 , 0x5e2d10c5 , 0x1046fab0 ] )
+  , Mont::unsafe_make( [ 0xfec0110e , 0x4f3e15e3 , 0xd8c4b5aa , 0x4d4115d1 , 0x0a45457d , 0xf4e326ff , 0x0787bfbb , 0x08b8d899 ] )
+  , Mont::unsafe_make( [ 0xfb18f78a , 0xfb323939 , 0x1b19a14b , 0x0d3bcc40 , 0x1a4d054c , 0x5cf92d82 , 0x801b8348 , 0x16699717 ] )
+  , Mont::unsafe_make( [ 0x3eafdb32 , 0xc2ebc96b , 0x5f94defd , 0x97503575 , 0xa98b3ac0 , 0xe8b78450 , 0x56a263ed , 0x05a31350 ] )
+  , Mont::unsafe_make( [ 0x8f6def0a , 0x98ea6cf5 , 0x085ad487 , 0x86c19248 , 0x2ad5b689 , 0x00d2218e , 0xe9fa2a4b , 0x18dba98e ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x38eaa814 , 0x4fdc6021 , 0xc1f2668d , 0x315c769b , 0xf56228db , 0x45cb3095 , 0x0e0a887b , 0x2a2ec96f ] )
+  , Mont::unsafe_make( [ 0xa5bd6825 , 0xa97414d0 , 0x027672e5 , 0xc8b56fbd , 0x6a2a8a7b , 0x6a646673 , 0xc84f184c , 0x2233abdf ] )
+  , Mont::unsafe_make( [ 0xe20161ee , 0x331fe79c , 0x1285d076 , 0x1143f1d6 , 0x9e338b77 , 0x5e351402 , 0x44838245 , 0x151960a1 ] )
+  , Mont::unsafe_make( [ 0x71250298 , 0x4d6aac16 , 0x1d6f23f0 , 0x3d8a49fd , 0xda27fb58 , 0xd51c3426 , 0x9fa9af5e , 0x2d73818e ] )
+  , Mont::unsafe_make( [ 0x244384ba , 0xa809eb61 , 0xea078b64 , 0xf431358f , 0x8e36d6db , 0x63552af7 , 0x749a233a , 0x14df4cb5 ] )
+  , Mont::unsafe_make( [ 0xf17add6e , 0x83b124fd , 0xdc20ed46 , 0xd5693f1f , 0x57686f23 , 0xdc30a7f6 , 0x1ef2e4c7 , 0x0af81a95 ] )
+  , Mont::unsafe_make( [ 0x34dc00b8 , 0x069d691d , 0xa4f80d20 , 0xfb18a115 , 0x2fa2f2d4 , 0x98a4ee79 , 0x01af878c , 0x0275a707 ] )
+  , Mont::unsafe_make( [ 0x8aaf929c , 0xe16827c4 , 0xce173aeb , 0xbb8e94ac , 0x17e49fe2 , 0x79b8d763 , 0x50ce1eeb , 0x252111c8 ] )
+  , Mont::unsafe_make( [ 0xb71b9972 , 0x69528831 , 0x28af2f3c , 0x71c4a5d8 , 0xe7f0d9c4 , 0xa7df28bb , 0xf3fe9f8e , 0x2c28f8b1 ] )
+  , Mont::unsafe_make( [ 0x5907abb6 , 0x153cb4a8 , 0x4db58a51 , 0x4dde4e46 , 0xcf705a77 , 0xe32e7756 , 0x1f1c4bbf , 0x0eeceae3 ] )
+  , Mont::unsafe_make( [ 0xb5dc8339 , 0xa0012429 , 0x459c204b , 0x4e390801 , 0xca0d27ac , 0xdd4ffcd9 , 0x97760746 , 0x2c241324 ] )
+  , Mont::unsafe_make( [ 0xcfe9eb68 , 0xdbb3042f , 0xad7e6add , 0x96e18ea9 , 0xa80d223a , 0xba416cc4 , 0x8ed69a0d , 0x23842ffb ] )
+  , Mont::unsafe_make( [ 0xff81b958 , 0x2edf7df4 , 0x2f6a41bb , 0xd0fe91f1 , 0x5f79a91e , 0xc3b8a334 , 0x3147244b , 0x0d8984ef ] )
+  , Mont::unsafe_make( [ 0xc019813d , 0x7b555bb7 , 0x112c4032 , 0x5f25ec9e , 0xd52e64f2 , 0xe103beee , 0xc503745b , 0x1b9a8b7c ] )
+  , Mont::unsafe_make( [ 0xcf7a58c9 , 0x6f42e474 , 0xbf4ff4bb , 0x6daa25c0 , 0x5bb86757 , 0x07f1da3e , 0xfcec1836 , 0x0430b118 ] )
+  , Mont::unsafe_make( [ 0x0446e8c2 , 0x94094b59 , 0x33e5fad4 , 0xe3928b44 , 0xb8f9732a , 0x837384fc , 0x319b4c75 , 0x025ec21b ] )
+  , Mont::unsafe_make( [ 0x6d3cff6c , 0x02ce2248 , 0x888931b2 , 0xc7f1ca6f , 0x1e1949bf , 0xeb979a8a , 0x99933e59 , 0x2f21c975 ] )
+  , Mont::unsafe_make( [ 0x066bc268 , 0xefb04e10 , 0xf43147bb , 0x77b1fb63 , 0x067d0af1 , 0xa2c8e2fd , 0x315b1dd8 , 0x1eca7cd5 ] )
+  , Mont::unsafe_make( [ 0xc017104f , 0x7bb5711b , 0x890864a6 , 0x413e6aaf , 0x3b361e9f , 0x3409dcad , 0x9539783c , 0x278e82f6 ] )
+  , Mont::unsafe_make( [ 0x29f21135 , 0x15dcc30f , 0xcbc9c114 , 0xbcc6ad14 , 0xc8b051ce , 0x3f976178 , 0x9d51bc30 , 0x0eb39563 ] )
+  , Mont::unsafe_make( [ 0x0e401cd7 , 0x05c332db , 0x0a129190 , 0xf9a05421 , 0x03d82f3c , 0xeec30603 , 0x66bdf7de , 0x2149648b ] )
+  , Mont::unsafe_make( [ 0xf4be3da8 , 0x31739b92 , 0x9a2cf80a , 0x804ece72 , 0x55e827f2 , 0xac983ba9 , 0x9a445633 , 0x2b453860 ] )
+  , Mont::unsafe_make( [ 0xff076a6e , 0x478444af , 0x74eb2f1a , 0xdd7323b2 , 0x22f402e0 , 0x97a8e1e3 , 0x0da48b69 , 0x097fc9af ] )
+  , Mont::unsafe_make( [ 0xd4d5c0fd , 0x23476bbd , 0x073f1ab0 , 0x0b77e320 , 0x18d3580f , 0x4b0d10c6 , 0x09aae4fa , 0x0eb74103 ] )
+  , Mont::unsafe_make( [ 0x7d03afa0 , 0x9b4cdba3 , 0xcc227f44 , 0x1d7dcbcd , 0x607cd03b , 0x4cfa6904 , 0x97df0f7a , 0x0d800a42 ] )
+  , Mont::unsafe_make( [ 0x6f9e9ca6 , 0xc5e82778 , 0x8d7a52a0 , 0x01faa1f0 , 0x1cfdbc83 , 0x43ff169c , 0xe5e056f5 , 0x033a77bc ] )
+  , Mont::unsafe_make( [ 0xca338d40 , 0x6af5c3e6 , 0xd2e41d6d , 0x28702594 , 0x53bdfb41 , 0x329709df , 0x8d0d99a4 , 0x0991d0d4 ] )
+  , Mont::unsafe_make( [ 0xf46d0465 , 0xfcdb14be , 0xacfadff5 , 0xeb3526f9 , 0x6cf1c944 , 0x866148e9 , 0xdf38fcbc , 0x2cee2681 ] )
+  , Mont::unsafe_make( [ 0xecc9f218 , 0x0c1ec26a , 0xeca9e0e6 , 0xdffb4e5b , 0x0dac4e14 , 0xd0eecdc2 , 0xea119768 , 0x2727dab0 ] )
+  , Mont::unsafe_make( [ 0x69d5e35c , 0xceb029e6 , 0xa35166c8 , 0x8f7bd050 , 0x7d56c1fa , 0x29920f4e , 0x996b3c6d , 0x23bd7d3a ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xe4880e78 , 0x119aea52 , 0x9a3a252f , 0xe07ba6c1 , 0x027da511 , 0x066df1d0 , 0xc0a4e3a4 , 0x146912a8 ] )
+  , Mont::unsafe_make( [ 0xfbcb1781 , 0xb6d3bb45 , 0x8e710fb6 , 0xc8cbd1c4 , 0x016b6096 , 0xe9f65c53 , 0xca36b35d , 0x056bc694 ] )
+  , Mont::unsafe_make( [ 0xc743adfd , 0x1b29f9ec , 0x1543a950 , 0x8d183e0f , 0x3005b88d , 0xc812ef8d , 0x9b15a1ad , 0x0b222506 ] )
+  , Mont::unsafe_make( [ 0x42a13486 , 0xd70eaa48 , 0xf59cbb63 , 0x6e773006 , 0x42e8cec1 , 0x903526b2 , 0x44fbdb65 , 0x28b98cd9 ] )
+  , Mont::unsafe_make( [ 0xc5663bd7 , 0x5bcbb0d8 , 0xa0dac2d6 , 0x759710af , 0xb0b044d7 , 0xba43d52f , 0x5a800371 , 0x14a50312 ] )
+  , Mont::unsafe_make( [ 0x78246d93 , 0x9655a205 , 0xe55577b9 , 0xd8541ec7 , 0x7705d7b3 , 0xa3b48d42 , 0xa952dc7a , 0x2d9305b4 ] )
+  , Mont::unsafe_make( [ 0x6da65124 , 0x9eca7723 , 0x1de22291 , 0x8a8a7f4d , 0x81d59b1e , 0xb133969e , 0xaf08defb , 0x028bd6ea ] )
+  , Mont::unsafe_make( [ 0x0f6a4f41 , 0x2b00d525 , 0xe72204c0 , 0x57eab028 , 0x438f7065 , 0x38f8e9ff , 0x850f280e , 0x139953af ] )
+  , Mont::unsafe_make( [ 0xffffc342 , 0xc585df06 , 0x42237e8c , 0x5ae3434f , 0xdd85c5ab , 0x883d4c5f , 0x40f92ab0 , 0x2bebdae1 ] )
+  , Mont::unsafe_make( [ 0xd803196a , 0x7d5fc586 , 0x5b056df8 , 0x7cbc0d38 , 0xf50508a3 , 0xa0e8729e , 0x8170d159 , 0x06078b40 ] )
+  , Mont::unsafe_make( [ 0xcbfba498 , 0xa1d23a54 , 0xaf385b69 , 0xb3ad17a8 , 0xad0495c9 , 0xc64eee8a , 0xb9aff587 , 0x187964af ] )
+  , Mont::unsafe_make( [ 0x730fbef7 , 0x1c757919 , 0xc2ce8c8b , 0x82e7e2bd , 0x57ca870d , 0x07b407e8 , 0x091bd64b , 0x1de8df33 ] )
+  , Mont::unsafe_make( [ 0x2c2f8ebb , 0xddc6a576 , 0x79b27d2a , 0x1137fb9a , 0xd74cbe23 , 0x9f215a77 , 0x17003e3c , 0x13a902b0 ] )
+  , Mont::unsafe_make( [ 0x900a45e9 , 0x516a7293 , 0x25c02451 , 0xe35f34f7 , 0x20d13322 , 0x862d081a , 0x1fce03c7 , 0x1bdd0948 ] )
+  , Mont::unsafe_make( [ 0xc75cf783 , 0x773f865c , 0x2ec8feaf , 0x6ac24cdc , 0x54190138 , 0x7c1b39c9 , 0x44ea352d , 0x2b316fff ] )
+  , Mont::unsafe_make( [ 0xd142e1ab , 0xaee0819f , 0xb516ab88 , 0xa351dc42 , 0x0cada22f , 0x1d9160bb , 0x91a2db64 , 0x28946af0 ] )
+  , Mont::unsafe_make( [ 0x5e45d990 , 0xcf5ea25f , 0x49d1aa5b , 0xafc92fa5 , 0x3127d120 , 0xbe5e9259 , 0xcaa3628d , 0x12904d6e ] )
+  , Mont::unsafe_make( [ 0x01c6ea90 , 0x1fa01e30 , 0xb71ab5f0 , 0x0ee12bf4 , 0x4ed7d651 , 0x79110572 , 0xfef44d9b , 0x1a67019a ] )
+  , Mont::unsafe_make( [ 0x7bf63337 , 0x1a1b71dd , 0xc6f5dfb0 , 0x8e1a48b9 , 0x1a1a1a14 , 0xf15fb246 , 0xa197b94a , 0x14f76989 ] )
+  , Mont::unsafe_make( [ 0x5d74b409 , 0x7ce30a2b , 0xb99dd873 , 0x7f3fdf5a , 0xd74199e7 , 0x0b2123d7 , 0x8de7b1a4 , 0x1a26fbf5 ] )
+  , Mont::unsafe_make( [ 0x0e26c0bb , 0xd87765b9 , 0x9354b5e1 , 0x69cad3e3 , 0xb77143f3 , 0x8331576e , 0x9a214631 , 0x298ca61f ] )
+  , Mont::unsafe_make( [ 0x9232cdf4 , 0xf155e615 , 0x5efd15bd , 0xf7d93717 , 0x93c3cbb2 , 0x998458e7 , 0x04b5e159 , 0x10dd8246 ] )
+  , Mont::unsafe_make( [ 0x17951f3d , 0xbe220326 , 0xc97190c5 , 0x3e37ef8f , 0x1190a12c , 0xc14c7c09 , 0x1af3ce75 , 0x205f4d33 ] )
+  , Mont::unsafe_make( [ 0xba67852f , 0x7d978add , 0xd8f677c6 , 0xecae6120 , 0x088c455a , 0x07613b67 , 0x67ca415e , 0x1aa42782 ] )
+  , Mont::unsafe_make( [ 0x67facbda , 0xcd4e9a83 , 0xe838fe1b , 0xf8f81cfd , 0x7be050ca , 0x5410b753 , 0xfde5dca8 , 0x0c0f48ab ] )
+  , Mont::unsafe_make( [ 0x1185a749 , 0x723eaf1f , 0x44bac35b , 0xc91e22fa , 0xaa816bed , 0x5f5eb0d1 , 0x87973538 , 0x1247767c ] )
+  , Mont::unsafe_make( [ 0x327ad9eb , 0x7c3de2b1 , 0x5d1f6c57 , 0x87de5674 , 0xa3532e5a , 0x6bc65fbc , 0x1969f676 , 0x06ced744 ] )
+  , Mont::unsafe_make( [ 0x78130948 , 0x13d467ad , 0xfa45ebc4 , 0x73583975 , 0x8405633d , 0xb9247054 , 0x0ade47c4 , 0x1070fbf1 ] )
+  , Mont::unsafe_make( [ 0x07700b56 , 0x8cffc975 , 0x0b6ca20e , 0x6742969a , 0xf585939d , 0x637cdf51 , 0x2b3d08d3 , 0x067ae07e ] )
+  , Mont::unsafe_make( [ 0x04d1aebc , 0x111e83ec , 0xdd41c2cc , 0x74974f42 , 0x2b74eb3f , 0x2de30bc7 , 0xcdd11404 , 0x032f113a ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xf1f98d83 , 0x7328d7ba , 0xc5019d89 , 0x9559bf6c , 0x0838cd13 , 0x521499a7 , 0x2eeed6e8 , 0x26fe2ecf ] )
+  , Mont::unsafe_make( [ 0x3a41452d , 0x6671c377 , 0x07718351 , 0x0ce85920 , 0xbfc60755 , 0xa4018a08 , 0x219b4811 , 0x2c9df919 ] )
+  , Mont::unsafe_make( [ 0x7367d353 , 0xc0afb314 , 0xd80b4d69 , 0xa9ab3c93 , 0x75a46693 , 0x7f826311 , 0x7106033d , 0x2f24882a ] )
+  , Mont::unsafe_make( [ 0x789feee9 , 0x3f277bd8 , 0x9f260391 , 0x2183777d , 0xfad4d260 , 0x34b11e0a , 0xe2aad2ec , 0x2161334d ] )
+  , Mont::unsafe_make( [ 0x7e35cf71 , 0x52b60012 , 0xef74479c , 0xf8307814 , 0x1dfa413c , 0xdc634d3f , 0x70ba3eb8 , 0x287cae11 ] )
+  , Mont::unsafe_make( [ 0x8c658f13 , 0xf27b3fd0 , 0xa27df2cc , 0x8c063a03 , 0x6cf631e2 , 0xd049e371 , 0x27050241 , 0x213d05db ] )
+  , Mont::unsafe_make( [ 0x809daa5f , 0x952524c7 , 0xcc58ecc9 , 0xf24a9744 , 0xe2e11681 , 0xc35cf1ae , 0x7230a98a , 0x251e9489 ] )
+  , Mont::unsafe_make( [ 0xf1e0f020 , 0xd97211d6 , 0xa6c1940b , 0x19f8c1ac , 0x7c9d11f1 , 0xce486148 , 0x50578d9b , 0x09a3b07b ] )
+  , Mont::unsafe_make( [ 0x9408e0b8 , 0xc806d8f7 , 0xc006c862 , 0xe62c2cc4 , 0x4b7320f4 , 0x6980db68 , 0x112249b8 , 0x03b8d794 ] )
+  , Mont::unsafe_make( [ 0x5461b432 , 0x8c38c6c9 , 0xf5ec6d7d , 0x8752c85b , 0x71b56d6d , 0x573276fe , 0x1ee495f9 , 0x214d2ed8 ] )
+  , Mont::unsafe_make( [ 0x5027b506 , 0xd2697b69 , 0x6918ae2f , 0x98594d9d , 0x35c23a41 , 0x5a248491 , 0x8f319633 , 0x143e0dc5 ] )
+  , Mont::unsafe_make( [ 0xc56a59a6 , 0x022b8434 , 0x79dbba78 , 0x8a129b76 , 0xd1493d75 , 0xf4b89b84 , 0x89e49286 , 0x05d9d29b ] )
+  , Mont::unsafe_make( [ 0x2e46c39d , 0x8fdea9e3 , 0xd7a3863d , 0x1fd75269 , 0xe79856ab , 0x41143196 , 0x5b8b85a8 , 0x2766292e ] )
+  , Mont::unsafe_make( [ 0x44adcc9a , 0x7d36036c , 0x09b7543b , 0x6adb57d3 , 0x79829fd4 , 0x1fc58466 , 0x367cbaef , 0x1a47620f ] )
+  , Mont::unsafe_make( [ 0x92233d16 , 0xc059bac9 , 0xd91e166f , 0xb3874122 , 0x94e87e00 , 0x6fc4de9c , 0x3314b39a , 0x2922a6a7 ] )
+  , Mont::unsafe_make( [ 0x488c7f61 , 0x4653ff3c , 0x67624483 , 0x1cb7ee86 , 0x0d01f438 , 0x05424238 , 0x11ba0d68 , 0x01720a52 ] )
+  , Mont::unsafe_make( [ 0x535c0abc , 0xad05b2ca , 0x0ea3e50e , 0xb17f19ea , 0x4b04913b , 0x794b4449 , 0x5cdfeb13 , 0x03bd0081 ] )
+  , Mont::unsafe_make( [ 0x35270cdd , 0x6f79791a , 0x78e5e0b7 , 0x825bb90e , 0x6b49712a , 0x66a76b24 , 0x317bfad4 , 0x163a6c83 ] )
+  , Mont::unsafe_make( [ 0x70372d5c , 0xf6d00fd5 , 0x52cabaef , 0x37ab70d5 , 0xb8187e75 , 0xd8302f83 , 0x226d7546 , 0x2233c401 ] )
+  , Mont::unsafe_make( [ 0xd55b1d58 , 0x8d1b60a1 , 0x313eb675 , 0xa529f80c , 0x4eed1b58 , 0x1c9e0eba , 0xaa6e6fa2 , 0x033a101a ] )
+  , Mont::unsafe_make( [ 0xf5f41208 , 0x98014768 , 0x077f8ab5 , 0x5823d34f , 0x4bf10c2e , 0xbad35235 , 0x98532409 , 0x15f94a88 ] )
+  , Mont::unsafe_make( [ 0x6f91115a , 0xa5186672 , 0xd0615bca , 0x16ded02e , 0xa1c21e96 , 0xcd26b29a , 0x16db2b09 , 0x2b05fb39 ] )
+  , Mont::unsafe_make( [ 0x48ad470a , 0x783c38f6 , 0x4b4d3148 , 0x2cbe44e7 , 0x1efc365f , 0xdfe2cce8 , 0xf7f99a60 , 0x09a02813 ] )
+  , Mont::unsafe_make( [ 0xbf11e772 , 0xbdefeb90 , 0x857add79 , 0x3394682b , 0x620865b7 , 0xe52a6261 , 0xd87255cb , 0x03a9a53c ] )
+  , Mont::unsafe_make( [ 0x04ed09e4 , 0xfe0a4edf , 0xa58a92a3 , 0x8f6f7e08 , 0x3d95fe47 , 0x664ef771 , 0x595e3e7b , 0x0490b457 ] )
+  , Mont::unsafe_make( [ 0x86c7ecf6 , 0x5068a4e8 , 0x2512eb27 , 0x8bf22bd2 , 0x26d96528 , 0xc35811f8 , 0xbf3bbf64 , 0x0c6e68f9 ] )
+  , Mont::unsafe_make( [ 0x508d7a92 , 0xe79e8f0b , 0x2c92d0a9 , 0x93104709 , 0x1fdc035d , 0x881262e3 , 0x4bff875b , 0x252fb202 ] )
+  , Mont::unsafe_make( [ 0x77db0736 , 0x0c93320a , 0x4385adda , 0xeea09dbb , 0x53089759 , 0x2c552436 , 0x3aba8dcf , 0x06c8784c ] )
+  , Mont::unsafe_make( [ 0x75460c38 , 0x8d9c1c17 , 0x210b67b6 , 0xb091cda9 , 0x940baabc , 0x79c30c9e , 0x254367da , 0x1179cfd2 ] )
+  , Mont::unsafe_make( [ 0xc70dd737 , 0x2e7565f8 , 0x105c5c25 , 0xa697f982 , 0xe3422428 , 0xaeb3c237 , 0x32bda54c , 0x0cbe6fe0 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x534aa4f3 , 0x676a1d37 , 0x70616564 , 0x184f92ce , 0x728ab5e1 , 0xdc9d6aa1 , 0xd9c0290b , 0x2e8c6df5 ] )
+  , Mont::unsafe_make( [ 0xd0a98a1f , 0x27808e8b , 0x6707d9ce , 0x513b0bf7 , 0x0b120243 , 0x2f752892 , 0xee043846 , 0x106f5d3e ] )
+  , Mont::unsafe_make( [ 0x8966f252 , 0x4e77f5c8 , 0xfb040f0a , 0x1d68fe08 , 0xf9c492f1 , 0x7c6e0a9d , 0x78cb6e23 , 0x0747b99d ] )
+  , Mont::unsafe_make( [ 0x6c037f65 , 0x5a184ee9 , 0xfc5b3bab , 0xcdd622ed , 0x81cce749 , 0x5d6765df , 0xebd882b3 , 0x0e0056db ] )
+  , Mont::unsafe_make( [ 0xf7e74d61 , 0x0fc81c0b , 0x41f84191 , 0x9ec33d72 , 0xb2f26c3f , 0xa9f35b9e , 0x20a0fb0e , 0x07f443b0 ] )
+  , Mont::unsafe_make( [ 0x0107dd2b , 0xae93b493 , 0x3d8299f8 , 0xa8ee77c4 , 0x3de9efae , 0xd7cb4c6d , 0xd791839b , 0x03f984b0 ] )
+  , Mont::unsafe_make( [ 0xeb8a97b8 , 0xb944abc0 , 0x70588a7a , 0xe2d6b4db , 0x10755eae , 0x70a9195c , 0xd9cc3528 , 0x0e6214ce ] )
+  , Mont::unsafe_make( [ 0x4ae3e4ea , 0xa6bb7d9a , 0xd95cbca5 , 0xd3a1023f , 0xb676af64 , 0x01cfffd5 , 0x2f5931fe , 0x0befd919 ] )
+  , Mont::unsafe_make( [ 0x1053d666 , 0x1f4ece82 , 0xf734407a , 0xdbc8a52c , 0x236747d9 , 0x2ea67cbc , 0x55ebb5d9 , 0x0d614ad5 ] )
+  , Mont::unsafe_make( [ 0x6cb0b083 , 0x2fca0213 , 0x1dd50cdb , 0x84cb7e52 , 0xfd239c72 , 0xa3233ec5 , 0x79c51d0e , 0x0c5a4b06 ] )
+  , Mont::unsafe_make( [ 0x310f477e , 0x01a8cebb , 0x1faba32a , 0x24fbf41a , 0xb0cefc56 , 0x299f5a37 , 0x17b900b3 , 0x26829721 ] )
+  , Mont::unsafe_make( [ 0x174cd5f6 , 0xcfa5ba7f , 0xf6f7a856 , 0x914b863b , 0xf0b29364 , 0x374c71b3 , 0x48aa0e34 , 0x0e0a2c36 ] )
+  , Mont::unsafe_make( [ 0xa5a17049 , 0xa397492f , 0x3aa9ebf4 , 0x53cefe43 , 0x435bd2b2 , 0x610dc243 , 0x83f53d61 , 0x19df268b ] )
+  , Mont::unsafe_make( [ 0x08b906e8 , 0x67c3b270 , 0x70827156 , 0x8aa758e9 , 0xf5cad013 , 0x1472a2ef , 0x86951a1a , 0x03c06fc5 ] )
+  , Mont::unsafe_make( [ 0x7b3071c9 , 0x1d7665c6 , 0x685d1d70 , 0x3596ca4b , 0x1be3b3b3 , 0x88082ea3 , 0x4af74934 , 0x0e3887bf ] )
+  , Mont::unsafe_make( [ 0xb17872a2 , 0x55f5e58a , 0xb4640afa , 0x499d208e , 0xcaae66aa , 0x54db0832 , 0x34cf7504 , 0x2fb4fc51 ] )
+  , Mont::unsafe_make( [ 0x7df59906 , 0x315a6737 , 0xea7fa3f8 , 0x976f11c5 , 0x6e980393 , 0x34d5cc28 , 0x2c0722d2 , 0x01d8899b ] )
+  , Mont::unsafe_make( [ 0x99e0cc85 , 0xfe0b1733 , 0x39091d56 , 0x93f71efb , 0x4f9016dc , 0x06d0f4dd , 0xf986231c , 0x1572b2eb ] )
+  , Mont::unsafe_make( [ 0x0ef144f4 , 0x3baed14f , 0x59aacc12 , 0x80982f75 , 0xa1c8a7a6 , 0x007ff744 , 0x8ebcf902 , 0x11027505 ] )
+  , Mont::unsafe_make( [ 0xa2746f81 , 0x22223e8c , 0x3a62ce80 , 0xef2b6834 , 0xf4893c8d , 0x26049b89 , 0x50eb1d3f , 0x15a34d7b ] )
+  , Mont::unsafe_make( [ 0x0363a91e , 0x79a97d82 , 0x3caf81d3 , 0x23e5e6c1 , 0x8ef5d266 , 0x857064cb , 0x2042decf , 0x25460354 ] )
+  , Mont::unsafe_make( [ 0x9a4ad16a , 0x5c02ca89 , 0xee6990c9 , 0x1050bfb3 , 0x2fefae31 , 0x09f73650 , 0x9da076d8 , 0x0adc5e91 ] )
+  , Mont::unsafe_make( [ 0x28c55bc9 , 0x25806aa8 , 0x5b4155cf , 0x152f3800 , 0x61c20f9a , 0x72c83c02 , 0x054ca31a , 0x0ddf051d ] )
+  , Mont::unsafe_make( [ 0x1915d70b , 0xcfa2d525 , 0x3b69b12a , 0x10cf2dba , 0x5713d764 , 0x21cd8874 , 0x17d7356f , 0x302ef971 ] )
+  , Mont::unsafe_make( [ 0xdb6e41ad , 0xf1ff8852 , 0xa13809b8 , 0xd410da65 , 0xa54f91b8 , 0x5f022b8a , 0x78d853a3 , 0x1a0903ea ] )
+  , Mont::unsafe_make( [ 0xd0963d19 , 0xdac453b0 , 0x05b33242 , 0xd28329b5 , 0x551dd5af , 0x0022c2a9 , 0x28475ef4 , 0x2f6dda09 ] )
+  , Mont::unsafe_make( [ 0x107b0863 , 0x9843f3ad , 0x01b71a4e , 0x5c3139ca , 0x644312a9 , 0x44af1036 , 0xd315c851 , 0x12f3b834 ] )
+  , Mont::unsafe_make( [ 0x3c620f3e , 0xaba292b1 , 0x2c8104c7 , 0x288ec5ca , 0x95890ffc , 0xfe3c79fa , 0xc0071401 , 0x09d5d327 ] )
+  , Mont::unsafe_make( [ 0xac60eefc , 0xe4466f94 , 0x4a58637e , 0x1c7d9e40 , 0x32f721c3 , 0x123e13f4 , 0x7cb169ad , 0x24f71c1c ] )
+  , Mont::unsafe_make( [ 0xf5f5a4b4 , 0xfb2b1d50 , 0x9747cbd1 , 0x3acc2fff , 0xb37fd031 , 0x33984f22 , 0x460f0f45 , 0x1f51a474 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x1e31107c , 0x5ac52f1c , 0xb742a18f , 0xfa0dff51 , 0x510e26cf , 0xd349ef90 , 0xa0afe36b , 0x20003a50 ] )
+  , Mont::unsafe_make( [ 0x297a6b84 , 0x7f295111 , 0x28142745 , 0x44b6cb6d , 0x1c73953d , 0x9f5ce2ee , 0x8f94c6f5 , 0x1aa8ea3f ] )
+  , Mont::unsafe_make( [ 0xb493aa77 , 0x6421f703 , 0x19c8acb5 , 0x5426146a , 0xe8dc8e09 , 0x914e3df0 , 0xf06fa503 , 0x2eae3b5c ] )
+  , Mont::unsafe_make( [ 0xb6d86a9c , 0x319a21cd , 0x9c678f21 , 0x235b91bd , 0x1d19494c , 0xf6816236 , 0xb98f5687 , 0x11d2e7f5 ] )
+  , Mont::unsafe_make( [ 0xc30f1a26 , 0xe40ae150 , 0x5a366c6b , 0x6b5dc332 , 0xcb9a5b4b , 0xaa82772e , 0x9572fe55 , 0x01d25c7d ] )
+  , Mont::unsafe_make( [ 0x6d0b124d , 0x8200425b , 0xeb2f25dd , 0x356af719 , 0xbd272689 , 0x2e348728 , 0x8bfe5dcc , 0x27b1ebe5 ] )
+  , Mont::unsafe_make( [ 0xd506dc5e , 0xae40a664 , 0x2095898c , 0xc2c4c300 , 0x9db9f8a6 , 0x5eadcdbf , 0xab0cf192 , 0x137b08ec ] )
+  , Mont::unsafe_make( [ 0x6ae1f860 , 0x050ff3af , 0x21bab37b , 0xfc4cc903 , 0x8d5a3350 , 0xc3b7003f , 0xad6da05e , 0x075ab142 ] )
+  , Mont::unsafe_make( [ 0x9d309f26 , 0xdb0dc5aa , 0x28894726 , 0x81253ceb , 0x3ef39a84 , 0x52faed79 , 0x67cc2bae , 0x105d427a ] )
+  , Mont::unsafe_make( [ 0xb82940d7 , 0xba72fabc , 0xdd7089d0 , 0x68a9cdd6 , 0x20bec363 , 0xd556b15e , 0xbd8a2ce2 , 0x250851ce ] )
+  , Mont::unsafe_make( [ 0x91e97421 , 0x8b0223da , 0xdee3b653 , 0xf74964b6 , 0x93f05ea5 , 0xd198c93d , 0x2ead68c7 , 0x2dc1283c ] )
+  , Mont::unsafe_make( [ 0x9014c622 , 0x5bc935ea , 0x75ddc2d2 , 0x1569143d , 0x771643bc , 0x1cb4693c , 0xc2ceab16 , 0x00a6a66a ] )
+  , Mont::unsafe_make( [ 0x027fa2f9 , 0x3d3afcbc , 0x960ac148 , 0xb79275e8 , 0xdd1f5829 , 0x31df2bcb , 0x1a6fdb00 , 0x199f1c8f ] )
+  , Mont::unsafe_make( [ 0xb8889de2 , 0x926ee99b , 0xae4894e2 , 0x6743e3e6 , 0xcfd01d0d , 0xc56cc69d , 0x8c597793 , 0x0b600190 ] )
+  , Mont::unsafe_make( [ 0x63f72e4d , 0xa195e57d , 0x6abb688b , 0xd7f8ebfd , 0x9d119222 , 0xc498c10a , 0xdc8d3403 , 0x03240e92 ] )
+  , Mont::unsafe_make( [ 0x7a74c68f , 0xab80ce14 , 0xa7cf7e22 , 0x4faa7701 , 0xadb149b3 , 0xd34f223c , 0xfc9f5f22 , 0x16befb9e ] )
+  , Mont::unsafe_make( [ 0xec73e660 , 0xe4f745d5 , 0xcee0b897 , 0x95c04b09 , 0xd8a536c8 , 0x271e4880 , 0xd7583e22 , 0x26b44ba0 ] )
+  , Mont::unsafe_make( [ 0x978bcdca , 0x86f43b80 , 0x3822e023 , 0x66ce0823 , 0x754a4c3a , 0x970b248e , 0x6cd6ad9b , 0x26971f3d ] )
+  , Mont::unsafe_make( [ 0x28194302 , 0x6a0d299b , 0x90147a9d , 0xf8af81ea , 0x0e383cb3 , 0xfc542f93 , 0x8b5a7c76 , 0x11de8e8a ] )
+  , Mont::unsafe_make( [ 0xb7148e0c , 0x8cbd465f , 0xcc6adac2 , 0x95472739 , 0xec1ac5c9 , 0x5c02f242 , 0x8394915e , 0x2538c552 ] )
+  , Mont::unsafe_make( [ 0x01aa1f17 , 0x59031f8c , 0xeeab53b4 , 0xf250dd55 , 0x023ac698 , 0xc4aa5cd6 , 0xbf7d125a , 0x20b3bdd1 ] )
+  , Mont::unsafe_make( [ 0xfb7f2ade , 0xf17dcaba , 0x472eccc3 , 0x199ed4a6 , 0x66519857 , 0x8ab17ed1 , 0x67d8ecb2 , 0x215aa946 ] )
+  , Mont::unsafe_make( [ 0xe365162d , 0x01610ed6 , 0x7544518a , 0xc26cd72d , 0x2750e8d4 , 0xa727d318 , 0xa114d3a5 , 0x11f9db74 ] )
+  , Mont::unsafe_make( [ 0x4aa57e02 , 0x7c1a763f , 0xaf8a2428 , 0xcb380084 , 0x5e857098 , 0xb299ec74 , 0x935817b0 , 0x2cca7655 ] )
+  , Mont::unsafe_make( [ 0x51d1e6fb , 0x194f0d3a , 0xff1d2477 , 0x0a7902a1 , 0xef729068 , 0x3ea3bce3 , 0xcc54a6fe , 0x29c89673 ] )
+  , Mont::unsafe_make( [ 0x081165f0 , 0x5e41de92 , 0x85242e94 , 0x188e2f0b , 0xd07b48ec , 0x0c79f576 , 0x737562fd , 0x276560c7 ] )
+  , Mont::unsafe_make( [ 0x749567dc , 0x8dd301cf , 0x2b09021d , 0xb3f1a9d4 , 0x337f4a4f , 0xbb8df722 , 0xf49e464d , 0x18ddbe3d ] )
+  , Mont::unsafe_make( [ 0x3c400903 , 0xadd79ac2 , 0x1e25f3be , 0x57f143e7 , 0xc5237f02 , 0x3ee1ddd3 , 0xed07ab67 , 0x15927fad ] )
+  , Mont::unsafe_make( [ 0xc05f9ae4 , 0x78e1b980 , 0xd5eb94e4 , 0xf2dd18bd , 0x55df5deb , 0xaf412b28 , 0x2e243dd6 , 0x0d878c81 ] )
+  , Mont::unsafe_make( [ 0x8b9e990a , 0xa4b6897e , 0x15223fb2 , 0xbf8e00f4 , 0xdce6c5bc , 0xad180d6d , 0xea944604 , 0x2a782afc ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xc42e642b , 0x365ed724 , 0x72516316 , 0xea4548e8 , 0xa6269305 , 0xdca324ce , 0xcc5bd831 , 0x18bac2f8 ] )
+  , Mont::unsafe_make( [ 0x907f307a , 0xd2a4b8f2 , 0x4e40d5c6 , 0x54dd6a7f , 0x765284f6 , 0xefc07e40 , 0x9427be03 , 0x034f943e ] )
+  , Mont::unsafe_make( [ 0x80e3456c , 0x6551d04f , 0x94c02194 , 0x45c55398 , 0x5f6f76a3 , 0x6537092a , 0x5d1d6618 , 0x147606f7 ] )
+  , Mont::unsafe_make( [ 0x5f190169 , 0x48d8e94c , 0x1d3e83d8 , 0x14f5012f , 0x92748da6 , 0xbbb24984 , 0x0cd9444f , 0x2e2b57b5 ] )
+  , Mont::unsafe_make( [ 0x43d56f1d , 0x15fce107 , 0xdd6fcf07 , 0x277a16e5 , 0x42927793 , 0x26f93a8f , 0x7afae6ae , 0x28bc2d3d ] )
+  , Mont::unsafe_make( [ 0xf7542584 , 0x0fad91c4 , 0x28d13940 , 0xae1ba551 , 0x9b378c30 , 0x87d727f0 , 0x2a260e69 , 0x0f2cf431 ] )
+  , Mont::unsafe_make( [ 0x130673a5 , 0x7362517a , 0xedb0d1ba , 0x711b5f3c , 0x215dc087 , 0xeb59ffe4 , 0x940c2a64 , 0x2d5bed05 ] )
+  , Mont::unsafe_make( [ 0x25528fb5 , 0xd361fae5 , 0xcd1c439c , 0x6f1c34cd , 0x0085c2ba , 0xc14208ec , 0x23dd37aa , 0x02b353d3 ] )
+  , Mont::unsafe_make( [ 0xa1575a37 , 0xd4235553 , 0x8501b464 , 0x8a469e38 , 0x7b4bf807 , 0x16bf2709 , 0x6ec492f1 , 0x2d58ab78 ] )
+  , Mont::unsafe_make( [ 0x24e026b3 , 0xf865727f , 0x6d3d5b05 , 0x0bb1689b , 0x3d6df634 , 0x692081aa , 0x1105601d , 0x0e8ad32f ] )
+  , Mont::unsafe_make( [ 0xe448266b , 0x77a0dc2e , 0xa1d0682a , 0xbe7ca777 , 0x2797a632 , 0x3a6f261c , 0xe6069841 , 0x0c9bf686 ] )
+  , Mont::unsafe_make( [ 0x589efb4d , 0xbbcf9ea3 , 0x5fc185aa , 0x18b6240f , 0x6adc349f , 0xde4495ea , 0x9efd625a , 0x12fce309 ] )
+  , Mont::unsafe_make( [ 0xff6a4552 , 0xc3c347b7 , 0x59b8bcf0 , 0x54889f03 , 0x542e8d3d , 0x50848fd0 , 0xfe770055 , 0x1828a613 ] )
+  , Mont::unsafe_make( [ 0x26dd768d , 0x20ccfbf5 , 0xf7edcb5f , 0xd3657a59 , 0xa3d759bc , 0x1940ca6c , 0x67f60954 , 0x0a3160d7 ] )
+  , Mont::unsafe_make( [ 0x33fe2d4a , 0x0461c281 , 0x19f28191 , 0xd2bb08a2 , 0xa09ba378 , 0xd9a0cd2b , 0x0c381362 , 0x24de4055 ] )
+  , Mont::unsafe_make( [ 0x6fc66d47 , 0x315adc92 , 0xb1433b85 , 0x9020afb8 , 0xcc83de83 , 0x4fc68ef6 , 0x02072518 , 0x0b493311 ] )
+  , Mont::unsafe_make( [ 0x4831b73f , 0x70ca0cc8 , 0x78c9212d , 0xd3afd0d3 , 0x8558cd91 , 0x464d2635 , 0x1446ebe3 , 0x2a428cf0 ] )
+  , Mont::unsafe_make( [ 0x4c3c28de , 0x4928014b , 0x2326a9f5 , 0x0c11d4ed , 0xf83345bb , 0x4cf35a99 , 0xafbbbaef , 0x2b389624 ] )
+  , Mont::unsafe_make( [ 0x5ce4c782 , 0x4fbe92db , 0x969be30a , 0xa93afad2 , 0x72a1663a , 0xfea745be , 0x34c1f774 , 0x27d47aec ] )
+  , Mont::unsafe_make( [ 0xaf128579 , 0xa7ad1566 , 0x6655a765 , 0x73b1b494 , 0x0b87d9a4 , 0x41bc7dc6 , 0x75a8fa68 , 0x2062dbb6 ] )
+  , Mont::unsafe_make( [ 0x0cbc2eb9 , 0x0d1308e8 , 0xdf422614 , 0xbda7b9ed , 0xf82fbbde , 0x33c6d98e , 0xcde911b3 , 0x00cecef7 ] )
+  , Mont::unsafe_make( [ 0xb1fe7a1e , 0x3d26408a , 0x6cb03348 , 0xf6bfe2b7 , 0x3fe268fb , 0xdac95f2d , 0x5b416227 , 0x12bcb4b4 ] )
+  , Mont::unsafe_make( [ 0xf68c461b , 0x046c489d , 0xf0201b97 , 0x941984e7 , 0xbcff27d0 , 0xe5a49960 , 0x43faf068 , 0x174d1bc4 ] )
+  , Mont::unsafe_make( [ 0xf158cf5b , 0xdd45fa5a , 0xb1168611 , 0xab048a4d , 0xcc637f76 , 0x261a8148 , 0xc52dd29c , 0x146df19b ] )
+  , Mont::unsafe_make( [ 0xf934e513 , 0xc187f6e3 , 0xfdf9eb69 , 0x108ea9a3 , 0x54473ce0 , 0xac5c896e , 0xeb18d68b , 0x295e23eb ] )
+  , Mont::unsafe_make( [ 0x83016924 , 0x11afd84e , 0x7cb68006 , 0x9e39326f , 0x3000a397 , 0x4fcc1ea1 , 0xeb4e7801 , 0x14977deb ] )
+  , Mont::unsafe_make( [ 0x8fc106ce , 0x1eb5ee7a , 0xdbde345d , 0x98db8202 , 0x7e3ce8f9 , 0xeec08fcc , 0xfd0f1f6e , 0x15c004b5 ] )
+  , Mont::unsafe_make( [ 0x9f2ecc08 , 0xe1d5065b , 0x26a988ec , 0xce639b8a , 0xb94037ce , 0x71b6e2f4 , 0xe6addc78 , 0x073b6de5 ] )
+  , Mont::unsafe_make( [ 0x8c13be67 , 0x42a519fb , 0xa6029451 , 0x5201a240 , 0xa5ff7029 , 0x4221ffed , 0x0078fda5 , 0x13eaaf06 ] )
+  , Mont::unsafe_make( [ 0x819e4791 , 0x3ae457e4 , 0x05b5e437 , 0x2f1e375f , 0x6d2d4b99 , 0x4f04f86b , 0x406fae37 , 0x254f05af ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x2db94d61 , 0xcd7f52b6 , 0x33d0bee3 , 0x9a54a0d5 , 0x4f5cbcc4 , 0x713ebfc9 , 0x31f8046f , 0x0c0fc73a ] )
+  , Mont::unsafe_make( [ 0x0c86226e , 0x86055895 , 0x2e735eba , 0xdcae6ba4 , 0xc4c1ff59 , 0x3225f7a1 , 0x32a62752 , 0x1103a784 ] )
+  , Mont::unsafe_make( [ 0xf48f32d3 , 0x9cff6295 , 0xdad5e80d , 0x8c8a6309 , 0xe8ebfb4e , 0x6c9c7649 , 0x5ba2861b , 0x25b79493 ] )
+  , Mont::unsafe_make( [ 0x0758ccf8 , 0xa3b9b392 , 0x7849df5b , 0xa15a6b03 , 0x90925ba1 , 0xcf61aa29 , 0xf92b7252 , 0x25f41808 ] )
+  , Mont::unsafe_make( [ 0x3dd0d649 , 0x3442e4a1 , 0x3fd7e757 , 0x1d93ab53 , 0xe3a57857 , 0xef63a264 , 0xf2302368 , 0x0b88d073 ] )
+  , Mont::unsafe_make( [ 0x1f526661 , 0x6fbec85f , 0x28fdb511 , 0x28b8fb25 , 0xf124aaf5 , 0xce08f6c6 , 0xe9e11c64 , 0x0a79e63c ] )
+  , Mont::unsafe_make( [ 0xc3457a80 , 0x90b84983 , 0x38a84da4 , 0x98ca445c , 0x5486763f , 0xb3b3bb5a , 0x7283b31b , 0x0e11b57f ] )
+  , Mont::unsafe_make( [ 0xeb77425a , 0x5c4a6715 , 0x81eb6a84 , 0x51a296e1 , 0xae62e5b6 , 0x844a9362 , 0xdb5e2c4d , 0x134ba261 ] )
+  , Mont::unsafe_make( [ 0xb572d65c , 0xd402cce5 , 0x26b5d743 , 0xf0687cdc , 0xf1c8b7a9 , 0xfb76d3d0 , 0x6587c9dc , 0x2889fe2d ] )
+  , Mont::unsafe_make( [ 0x51c0cee2 , 0xea41c982 , 0x23e24a4d , 0x08e831d0 , 0x75304b0c , 0xd0c3fd43 , 0x240ba689 , 0x0a8e76a3 ] )
+  , Mont::unsafe_make( [ 0xcb640b1f , 0x6b87b5bb , 0xf249ad4a , 0x6e6eb591 , 0x7b8137d2 , 0xe92afd8b , 0x2fc642c7 , 0x0177cd6e ] )
+  , Mont::unsafe_make( [ 0x8d5972a9 , 0xd27210ab , 0x709e0110 , 0xff1684db , 0x41beee10 , 0x0ec8a458 , 0x19df2d93 , 0x171b6a16 ] )
+  , Mont::unsafe_make( [ 0x592f5a1b , 0xfa260466 , 0x29e7985c , 0x484db047 , 0x20cb098b , 0x7372ccc7 , 0xd209c3d5 , 0x21d4f079 ] )
+  , Mont::unsafe_make( [ 0x19e292cc , 0xaeeccc51 , 0x20c1aecf , 0xa2be95e9 , 0xc927383a , 0xe58ab769 , 0xc849cf6b , 0x2e9b9392 ] )
+  , Mont::unsafe_make( [ 0xcf86b70e , 0x1b8d914f , 0x59d5c3cb , 0x5ba81914 , 0x00fa698a , 0xf308e64e , 0x292ad838 , 0x0176eb52 ] )
+  , Mont::unsafe_make( [ 0x5b4be291 , 0x71af5681 , 0x18959e50 , 0xc789be9c , 0xb5e05715 , 0xd14644de , 0x67dcb83f , 0x1a2779f2 ] )
+  , Mont::unsafe_make( [ 0xd6229afe , 0x3e5c2d82 , 0x78b5ef46 , 0xecde4528 , 0x0aa979f4 , 0x6dcf393e , 0x369f208b , 0x10686e6f ] )
+  , Mont::unsafe_make( [ 0xee4db080 , 0x9ea529c1 , 0xd24c27e4 , 0x8974a109 , 0x09174ab6 , 0x35a9560d , 0x46544ac0 , 0x18729bc2 ] )
+  , Mont::unsafe_make( [ 0xc72434b2 , 0x1c27beba , 0xce617ca0 , 0x9f3404ea , 0xff07bb4b , 0xfa65a9e2 , 0x74f4087e , 0x214252a9 ] )
+  , Mont::unsafe_make( [ 0xca902cbf , 0xe790657e , 0x247ea0ee , 0x9a88f0ac , 0xfe873c5d , 0x48f92642 , 0x7da02e8f , 0x1c71dbba ] )
+  , Mont::unsafe_make( [ 0xdc20a7c5 , 0x9f0aa705 , 0x7acf306a , 0xb90ce0fa , 0x408f8be8 , 0x6f216327 , 0xea5b9326 , 0x2b7ab4b7 ] )
+  , Mont::unsafe_make( [ 0xb9cce69b , 0x01d1d7cd , 0x3ab6817f , 0xccccd00e , 0xde2f4a29 , 0xd0a71703 , 0x8ac0c339 , 0x0849dc12 ] )
+  , Mont::unsafe_make( [ 0x6e3ed240 , 0xa4a9aff9 , 0xf7a82394 , 0x1b5b14b4 , 0x453a7f73 , 0x847eff78 , 0x517ea9b2 , 0x05dd3ad9 ] )
+  , Mont::unsafe_make( [ 0x4126b912 , 0xe3a074b2 , 0x47e30356 , 0x3189969a , 0x72c1a7a6 , 0x45583a93 , 0x0704faf9 , 0x17f01a2d ] )
+  , Mont::unsafe_make( [ 0x6f89bc19 , 0x3d39e049 , 0x9f45e9fb , 0xf3e1ca7c , 0x93f01c47 , 0x69fa34d6 , 0xe2c7921d , 0x1ba87ebb ] )
+  , Mont::unsafe_make( [ 0xbfc55a5b , 0x2a75a3d8 , 0x5884ca33 , 0x0e90f37b , 0x972ad240 , 0x7ae0224c , 0x27856d4f , 0x2c658fef ] )
+  , Mont::unsafe_make( [ 0xe84b7bea , 0x658ee072 , 0x274d1fab , 0x171f8fc1 , 0x327c5e9e , 0x1b345b20 , 0x4190ee0d , 0x3048508d ] )
+  , Mont::unsafe_make( [ 0x96bdbf20 , 0xd0acf49f , 0xdc7573dc , 0x2279fd8e , 0x0e129418 , 0x0cc96648 , 0xf991655b , 0x18182f5d ] )
+  , Mont::unsafe_make( [ 0x42ecb084 , 0xf3fd0036 , 0xdc9ba862 , 0x93bd6cc4 , 0x3ba2066f , 0x6a97acbd , 0x60013a19 , 0x281856c6 ] )
+  , Mont::unsafe_make( [ 0x57e6bd58 , 0xdc2ff5d7 , 0x2e08dc61 , 0x573c777e , 0x044e1aa6 , 0x9a3ac107 , 0x2db04035 , 0x214ffbfe ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x3e48c063 , 0xffd20eb7 , 0x1ecc864b , 0xd1de6ff9 , 0xaaa4323d , 0xbbabe0a5 , 0x43abeb2d , 0x2fab5eac ] )
+  , Mont::unsafe_make( [ 0xfbf5633c , 0x9da2d54b , 0x5c298bfb , 0x25279970 , 0xee9876cb , 0xd56c1483 , 0x4c71879e , 0x173f960f ] )
+  , Mont::unsafe_make( [ 0x6561094f , 0x388ea2d4 , 0x109715bb , 0x816731a4 , 0xe1332098 , 0xd086eb0d , 0x2a8887f8 , 0x08fc216b ] )
+  , Mont::unsafe_make( [ 0xd38a447f , 0xccc68768 , 0xfcbaa855 , 0xb7813a1a , 0xf1a80f4b , 0x04e25a0b , 0x6f4ffdec , 0x15c7eb66 ] )
+  , Mont::unsafe_make( [ 0x9840acee , 0x6bbfcfcb , 0xcbc5d500 , 0x814704d2 , 0x0f4c5cc3 , 0x88889be7 , 0x2508c7ad , 0x27bf1855 ] )
+  , Mont::unsafe_make( [ 0x03333c51 , 0x5d71cff4 , 0x4e763539 , 0x0d1c76ec , 0x73ac20b3 , 0x60cf829c , 0xf9fd0dc6 , 0x1c9f5a9b ] )
+  , Mont::unsafe_make( [ 0x3d1e22fc , 0x0b8e9c18 , 0xe67bdf4f , 0x47c20a37 , 0x6d5f0cfb , 0x98f4e166 , 0x93c3b35f , 0x07d16925 ] )
+  , Mont::unsafe_make( [ 0x158cacfc , 0x7b873cc5 , 0x9c884aba , 0x1f0881bb , 0x695373d5 , 0xd842ad59 , 0xe7d1737d , 0x10dec69d ] )
+  , Mont::unsafe_make( [ 0xa1d82aae , 0x460dc910 , 0xa2a4dc27 , 0xa3050cb5 , 0xa1cc5ddf , 0xc7ff3822 , 0x7d72ae6b , 0x1910544d ] )
+  , Mont::unsafe_make( [ 0x34217b42 , 0xc996ae44 , 0x79888735 , 0xa3244414 , 0xa49941a9 , 0xe53d706f , 0xd6d33b48 , 0x0d515c03 ] )
+  , Mont::unsafe_make( [ 0x9d55f7e2 , 0xf258fd88 , 0x90df84a0 , 0xc7b6678b , 0xdcb17d16 , 0x03d69865 , 0x28344341 , 0x0c85e233 ] )
+  , Mont::unsafe_make( [ 0x1e81c659 , 0x07d47b4c , 0x102906cb , 0x24ec1929 , 0xe90ba1c3 , 0x3c84fde5 , 0xfd6bef6e , 0x091414a8 ] )
+  , Mont::unsafe_make( [ 0x73f65862 , 0x537da889 , 0x9f0e9f28 , 0xd5312bc3 , 0xdeb2ed77 , 0xf4e9df37 , 0xac81bd31 , 0x167425ed ] )
+  , Mont::unsafe_make( [ 0x0870231c , 0xd5e89930 , 0x8af50f10 , 0xa70eec56 , 0xee8277d6 , 0x3954196f , 0xcc5e9173 , 0x07442008 ] )
+  , Mont::unsafe_make( [ 0xb0448fd1 , 0x0d03c71d , 0xab39176c , 0x6d4f8be1 , 0x2faa534f , 0x763323c4 , 0xe1fec435 , 0x1c35045a ] )
+  , Mont::unsafe_make( [ 0x51189d01 , 0xe0a2d07f , 0x3546a5a7 , 0x8876176f , 0x1ec67449 , 0x3ff2825c , 0x0934e28c , 0x1dbeea85 ] )
+  , Mont::unsafe_make( [ 0xcb907dff , 0x81563836 , 0x482c29d6 , 0x7dd73823 , 0xc6b2111d , 0x9a6da126 , 0xb27d52af , 0x2c32a91f ] )
+  , Mont::unsafe_make( [ 0x7a123338 , 0x833bbe49 , 0x9d74211e , 0xd2890870 , 0xca501081 , 0x34c4aee9 , 0x5c4477db , 0x19907109 ] )
+  , Mont::unsafe_make( [ 0x1c6f5cf4 , 0x78caaa0b , 0x37a6198e , 0xb5f37aaa , 0xd2ff4f41 , 0x85b59944 , 0x8b988a65 , 0x2738fc7e ] )
+  , Mont::unsafe_make( [ 0xa6bd756e , 0xeef3e9db , 0x6ba0ab0e , 0x57718f7d , 0x1e2367c0 , 0x0e1d33d8 , 0xa25ecc1c , 0x1122c466 ] )
+  , Mont::unsafe_make( [ 0x9853e085 , 0x30e7ddc5 , 0x92d61858 , 0x841acfb7 , 0xf1a60b39 , 0x9a5b7d80 , 0xaeef168a , 0x1c1bc0f7 ] )
+  , Mont::unsafe_make( [ 0x98867e91 , 0x22dcb859 , 0x643ff53e , 0xece5c47b , 0x68709011 , 0x5ca1af91 , 0xc5cfff95 , 0x1d926b98 ] )
+  , Mont::unsafe_make( [ 0x43f17fa7 , 0xc6905a09 , 0x030936e8 , 0x784103bf , 0x2bd0888f , 0x87f57aea , 0xf37c98f3 , 0x0c012fa0 ] )
+  , Mont::unsafe_make( [ 0xe28f8249 , 0xe9145dd8 , 0xe7d317ed , 0x11fa0709 , 0x065404cc , 0x0ec5b5ce , 0x8b5ee4cc , 0x2e692f47 ] )
+  , Mont::unsafe_make( [ 0x16223893 , 0xebab1b75 , 0x73ea1d4e , 0xb2e5c80f , 0x4f2db3cc , 0x12dd6b9e , 0xf91dd0cc , 0x09175609 ] )
+  , Mont::unsafe_make( [ 0xe01d17f7 , 0xb4607a69 , 0x732a92b4 , 0x27ef2c59 , 0xac77c25c , 0xcc79a3cf , 0xdfe2b0ba , 0x051f4580 ] )
+  , Mont::unsafe_make( [ 0x9067f11c , 0x7ed52944 , 0x372d22ab , 0xc76bfe32 , 0x73bd3b1a , 0x7cb53069 , 0x1786c729 , 0x1ed9db90 ] )
+  , Mont::unsafe_make( [ 0x7762d072 , 0xbfd48337 , 0x55f9366e , 0x3f1c7d33 , 0x014d50c8 , 0xbf778873 , 0x09d81957 , 0x148861d7 ] )
+  , Mont::unsafe_make( [ 0x3c9ce918 , 0x16bc94a1 , 0xef12a116 , 0xdd268215 , 0xbf459441 , 0x0b881df0 , 0x098778fb , 0x30529b8d ] )
+  , Mont::unsafe_make( [ 0xdf8cef60 , 0x6ee4050e , 0x5aaa1dc3 , 0x834008a3 , 0xd3425e20 , 0x43b37df6 , 0xc9be511a , 0x240d010b ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x39db4c5e , 0x6d14697c , 0x52000d2b , 0xaf77b68c , 0x5e5590aa , 0xec9d065d , 0x5d20c3eb , 0x1c0a5064 ] )
+  , Mont::unsafe_make( [ 0xc14893b6 , 0x3d5e5523 , 0x527a883f , 0xd0610109 , 0xe3357645 , 0x9a5aed67 , 0x339673ae , 0x28e87c4f ] )
+  , Mont::unsafe_make( [ 0x710f628e , 0x28a84735 , 0x3091d6be , 0x5f238247 , 0x2a8a0c42 , 0xfd83e685 , 0xf45544ea , 0x0df16127 ] )
+  , Mont::unsafe_make( [ 0x34405bf0 , 0xe4bd7c8f , 0x0800117d , 0x0282cad7 , 0x201050cf , 0x888ad743 , 0xd4d30adf , 0x17198094 ] )
+  , Mont::unsafe_make( [ 0x4a505c85 , 0xe275d354 , 0x19bee61d , 0xd33ecd0c , 0x3898b395 , 0x06a578e9 , 0xd02bc754 , 0x2071fd0d ] )
+  , Mont::unsafe_make( [ 0xe240de88 , 0xbe84fa6e , 0x6134ffd5 , 0xbdf08351 , 0x8bb927cc , 0xc5027f5a , 0x60afb970 , 0x0f85e877 ] )
+  , Mont::unsafe_make( [ 0x685c6c7a , 0x7ae63009 , 0x44350012 , 0x3ec41925 , 0xf914ac31 , 0xd4d8fe46 , 0x36a6bafb , 0x14942d91 ] )
+  , Mont::unsafe_make( [ 0x72eb9e81 , 0x30494093 , 0x07c12c8f , 0x3b0dacb5 , 0xfd76e83d , 0x5556db04 , 0x17cb0ac1 , 0x04473b28 ] )
+  , Mont::unsafe_make( [ 0x6c03bd58 , 0x2a09511e , 0xdcd5ee61 , 0xf026ab14 , 0xf7869d27 , 0xb8cafa10 , 0xa2131b49 , 0x24ca2f8e ] )
+  , Mont::unsafe_make( [ 0xed3ef8f6 , 0x4c194a9a , 0x54e2cfeb , 0xb8f20ec7 , 0x0d4f4d80 , 0x68a5876c , 0x0bee4b29 , 0x195d268f ] )
+  , Mont::unsafe_make( [ 0xdf907a2f , 0xa46d0499 , 0xe8c96844 , 0xe4e580de , 0xf7978a78 , 0x489930ca , 0x1b3dc185 , 0x2815de74 ] )
+  , Mont::unsafe_make( [ 0xbdd87d17 , 0x66561703 , 0xe6c710a3 , 0x28808a97 , 0x82c7c6fc , 0xe11cc999 , 0xc3c05694 , 0x0b14d0f3 ] )
+  , Mont::unsafe_make( [ 0x61a5fdc0 , 0xa44a28dd , 0x094f4cff , 0x82096887 , 0xc47fecfb , 0x38153b82 , 0xc4005827 , 0x2cd19143 ] )
+  , Mont::unsafe_make( [ 0xaefdae6b , 0x084ec26b , 0x8926584f , 0x75cdd098 , 0x19cb4bc9 , 0x3836371c , 0x38b715d8 , 0x245c208f ] )
+  , Mont::unsafe_make( [ 0xbe3fbf2d , 0x908b6700 , 0xbe6a4108 , 0x20c440ec , 0x69f5e379 , 0xf15a862a , 0x5a0df400 , 0x072e017c ] )
+  , Mont::unsafe_make( [ 0x12eaf793 , 0x27ac3304 , 0x9feecb72 , 0xe0e6c9ae , 0x60b6a420 , 0xa9dc1fe9 , 0x4068321f , 0x2bd4ef64 ] )
+  , Mont::unsafe_make( [ 0xa30037e2 , 0x808507fc , 0x673dcfca , 0x7d9f5b42 , 0xdab5e79d , 0xbcf909ed , 0x9f9c7a93 , 0x0cf2e710 ] )
+  , Mont::unsafe_make( [ 0x99fd8a7b , 0x9c098278 , 0xe6e0d258 , 0xc7f21cbc , 0xab50e07a , 0x86ac8895 , 0x67cfd6b5 , 0x0beef5af ] )
+  , Mont::unsafe_make( [ 0x42487a97 , 0x3ae50421 , 0x6616d91c , 0xb9c3336e , 0x251c72cd , 0xc026cac6 , 0xc61076e5 , 0x1d0ae115 ] )
+  , Mont::unsafe_make( [ 0x04cf1137 , 0xa90bbbcb , 0x48e72ed3 , 0x7d546e36 , 0x3fc650eb , 0xe29ecb17 , 0x9812c6da , 0x273b892b ] )
+  , Mont::unsafe_make( [ 0x46270ada , 0x7c8366be , 0x3b621c18 , 0xd7d6fae9 , 0x586be067 , 0x8f852b8a , 0xbdb50518 , 0x2cb3384a ] )
+  , Mont::unsafe_make( [ 0x147d21a7 , 0xe3cae2c1 , 0x311d243e , 0xd623478c , 0xf5ddbb1d , 0x2819da11 , 0x8b6f79a6 , 0x14a97c08 ] )
+  , Mont::unsafe_make( [ 0xc4e64c00 , 0x39f9b530 , 0x6ccf6e3a , 0x28b0adaa , 0x3bd1c7b3 , 0x2aefa8e3 , 0xba59e431 , 0x13d17c08 ] )
+  , Mont::unsafe_make( [ 0x352eb1f0 , 0x8c1714c7 , 0x37ec7f85 , 0xf4a74aba , 0x70f95ea0 , 0x770a92e2 , 0xc172d971 , 0x2d67bf66 ] )
+  , Mont::unsafe_make( [ 0xd43b2410 , 0xfefd91fc , 0x1719c689 , 0x6143f62d , 0xb08aef20 , 0x27a024cb , 0x48fd17a2 , 0x2905cbde ] )
+  , Mont::unsafe_make( [ 0xc6f7e33d , 0x6c914dd0 , 0x1ce2d550 , 0x5bb04172 , 0x3818101c , 0xbef2674d , 0x3ec15857 , 0x14a68f6d ] )
+  , Mont::unsafe_make( [ 0x78421fea , 0x709e6a3d , 0x38e67642 , 0x3f431c9f , 0x771d8646 , 0x6cca459d , 0x728c9fa1 , 0x1d31888b ] )
+  , Mont::unsafe_make( [ 0xf612541a , 0x8b0d3b4f , 0x3ff887b3 , 0xb1686f94 , 0x417d423e , 0x1bcb32ec , 0x2a1b67fd , 0x0178ca05 ] )
+  , Mont::unsafe_make( [ 0xa590f698 , 0xb7ab7201 , 0xe98699e5 , 0x0065471e , 0x0d61e5a3 , 0xd8867d2f , 0x6594fde0 , 0x15e77694 ] )
+  , Mont::unsafe_make( [ 0xf263bd7d , 0x51f666b7 , 0x250b27b9 , 0x469a7968 , 0xda55bae0 , 0x8fa9e243 , 0x898282ac , 0x1390eb5f ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xb5f3d84e , 0xa3a1ff00 , 0xbf3553ff , 0x08b50c15 , 0xdac9a95f , 0x6156a4d7 , 0xe03d2cd9 , 0x2a4c1c62 ] )
+  , Mont::unsafe_make( [ 0x1f1128a9 , 0x0a9208c3 , 0x7c31ccf4 , 0xafad29ea , 0xac99fcaa , 0xa0a55ffd , 0xb686b125 , 0x070210dc ] )
+  , Mont::unsafe_make( [ 0xbf04b9f6 , 0x830649bf , 0x07866863 , 0xecfc79b0 , 0xd7305a3b , 0x5a916c5f , 0x37348ef2 , 0x2845eb88 ] )
+  , Mont::unsafe_make( [ 0xed5577c4 , 0xff6a9680 , 0x6c07aa1a , 0xdb141154 , 0xc7420b00 , 0x217f6151 , 0x4c100541 , 0x2ec2bddd ] )
+  , Mont::unsafe_make( [ 0x60927ef2 , 0xdc404b4b , 0x3da52209 , 0x0c4521db , 0xdceac587 , 0x58d11fe9 , 0x85be86bb , 0x0f71a487 ] )
+  , Mont::unsafe_make( [ 0x7dabbf4d , 0x6fa6808a , 0xfc890a60 , 0xaed91670 , 0x191e91a8 , 0xfef3313c , 0x98317785 , 0x1b89dcbe ] )
+  , Mont::unsafe_make( [ 0xb8c9480f , 0x95c1063c , 0x68e83513 , 0x4d9a6aee , 0x65535b2b , 0x70952d89 , 0xcd64f462 , 0x1404ae6f ] )
+  , Mont::unsafe_make( [ 0xe8a19d04 , 0x02f73953 , 0x959d3ebb , 0x70dfa28f , 0x2605f855 , 0x5932beb0 , 0x87a51e4a , 0x2f886363 ] )
+  , Mont::unsafe_make( [ 0x9064e457 , 0x17c9cd8a , 0xfa4313b3 , 0x58bf9fed , 0x80bcbdb7 , 0xe3317019 , 0xea464460 , 0x1eceacfa ] )
+  , Mont::unsafe_make( [ 0x5297b8ff , 0x9592d3a4 , 0xf39aa881 , 0xeadd7327 , 0x0692f50f , 0xb382a1b7 , 0x00b109af , 0x268b9e3b ] )
+  , Mont::unsafe_make( [ 0x7d0e5c8f , 0xd2e45b27 , 0x1c7a1949 , 0x754e5be5 , 0x5b78979b , 0xbc5b7f31 , 0x6737fa94 , 0x2162153d ] )
+  , Mont::unsafe_make( [ 0x007bf9f1 , 0xece12452 , 0xb7cc7459 , 0x5cca6164 , 0x3092a1b0 , 0xcad95d97 , 0x3e25e141 , 0x26795bb8 ] )
+  , Mont::unsafe_make( [ 0x81e7179a , 0xb5d5c456 , 0x024b4148 , 0x78dd9465 , 0x9bd6ad87 , 0x56fad11f , 0x07d85496 , 0x10e99238 ] )
+  , Mont::unsafe_make( [ 0x0e7771be , 0xbcc8c5ad , 0xa0c0013a , 0xaa18656a , 0xd3ed7e94 , 0x16e9e96b , 0xdbb52528 , 0x1573d33c ] )
+  , Mont::unsafe_make( [ 0x549e44a1 , 0x614de2f7 , 0xd755697f , 0x3e2b8d57 , 0x75d9e742 , 0xaeb91213 , 0x3bef7b13 , 0x2ee171c9 ] )
+  , Mont::unsafe_make( [ 0x8887ca7c , 0x5f230826 , 0x5e4b3e6b , 0x4431457d , 0x0ff85058 , 0xe7537e01 , 0x485cefbe , 0x288e5f44 ] )
+  , Mont::unsafe_make( [ 0x88f7645c , 0x34f18bba , 0x0fe2e6cc , 0x47cc5b39 , 0xec04621c , 0x5fc1ccc3 , 0x1a452792 , 0x1c9323fc ] )
+  , Mont::unsafe_make( [ 0xdbcb0769 , 0x5c9683fa , 0xd6179734 , 0xd398f141 , 0x0d3afc3c , 0xe33b72a3 , 0xa04b4ad0 , 0x2948ea70 ] )
+  , Mont::unsafe_make( [ 0x370cc60c , 0x6c2850dc , 0x7f2b7cbe , 0x24cf4f75 , 0xab056683 , 0x9070a26c , 0x8d678afe , 0x0ad8285b ] )
+  , Mont::unsafe_make( [ 0x938a7d1f , 0xcfafb27c , 0x7bf3a2c6 , 0x83253564 , 0xe7a8e3f5 , 0x2e2cd4ad , 0x48b2a2a3 , 0x2d002df4 ] )
+  , Mont::unsafe_make( [ 0x3ccbf30c , 0xb4d8118e , 0xb4ee01fe , 0x3f82154b , 0x247aa908 , 0x8961bc66 , 0xe5efec5c , 0x036cfb46 ] )
+  , Mont::unsafe_make( [ 0x825b76d2 , 0x3430886f , 0x45bfa3e2 , 0x50831710 , 0xe21e86f7 , 0x53760d02 , 0xab5e19ec , 0x1a35badb ] )
+  , Mont::unsafe_make( [ 0xe28351d2 , 0xcc8ea2f1 , 0xa6cebea0 , 0x9a14d71a , 0xc404d492 , 0x85ff56bd , 0xb286abc8 , 0x28be1f06 ] )
+  , Mont::unsafe_make( [ 0x31d1ec91 , 0x17b6cf33 , 0x417e0cad , 0x62b7face , 0x1045016f , 0x25004e0f , 0xe4af0d99 , 0x159f3a50 ] )
+  , Mont::unsafe_make( [ 0x0db18b2c , 0x4b70117f , 0x61436c86 , 0x1f345a3b , 0x2f873ee7 , 0x07c76ccd , 0x4e74506f , 0x07426355 ] )
+  , Mont::unsafe_make( [ 0x6add43ca , 0x5be2f334 , 0xc82c9efc , 0xc8234eef , 0xcc539296 , 0x0d91d861 , 0x98707156 , 0x223e1acf ] )
+  , Mont::unsafe_make( [ 0x9e2321e9 , 0x26efbdcf , 0xd082dfb7 , 0xa02e4ede , 0x56ef87a4 , 0xa3cdcab4 , 0xbe809b35 , 0x2e793c86 ] )
+  , Mont::unsafe_make( [ 0xd5c29cc2 , 0x35153ccd , 0x550f8c86 , 0xe5463306 , 0x33d57392 , 0x96132cff , 0x18d5080b , 0x18633753 ] )
+  , Mont::unsafe_make( [ 0xe8b3e792 , 0xdbd46388 , 0xc136030e , 0x53b30279 , 0xc0eee150 , 0x2389a668 , 0x02c2a262 , 0x03e18bf7 ] )
+  , Mont::unsafe_make( [ 0x2ab4fb33 , 0x15ff12d4 , 0x4f57bfa5 , 0x7d7009a6 , 0xae2ce9a0 , 0xebd163db , 0x9d02f315 , 0x0d15d14a ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x55bda612 , 0x6aaaf63f , 0xbb956e7d , 0x2b5adfd1 , 0x2df1ec82 , 0x8705e799 , 0x4b7f8425 , 0x211aef69 ] )
+  , Mont::unsafe_make( [ 0x43dce343 , 0xdab349c7 , 0xeccf5cfc , 0x1a10c485 , 0x1b775559 , 0xa4d90c7c , 0x2374c179 , 0x2efec19f ] )
+  , Mont::unsafe_make( [ 0xce894c41 , 0x2343478c , 0x15d73f2f , 0xfe973daa , 0xeb26f52c , 0xaf004dd5 , 0x59b19e7f , 0x22cbb206 ] )
+  , Mont::unsafe_make( [ 0x4ab1f2ff , 0xe1107cf8 , 0x7e5ce679 , 0xca02d9de , 0xf62caa90 , 0x2fcf121a , 0xd713a223 , 0x0cbcec33 ] )
+  , Mont::unsafe_make( [ 0x4066e13d , 0x625309cd , 0xf59eebc3 , 0x3e26a506 , 0xad157ad6 , 0x809e42d9 , 0x5f0a0ace , 0x1eca5495 ] )
+  , Mont::unsafe_make( [ 0x8ede019f , 0x5f64c1c9 , 0xf8696dd3 , 0x7cbfdef6 , 0x6667196e , 0x48a65c40 , 0x17e1851a , 0x01390768 ] )
+  , Mont::unsafe_make( [ 0x79354edb , 0x4799a1e5 , 0xa98ae01a , 0xa2342717 , 0xfeca07e4 , 0xc154ce54 , 0xc4a748a2 , 0x1fda31fd ] )
+  , Mont::unsafe_make( [ 0x1e22994a , 0xe80ed264 , 0x028e89c9 , 0x8d3c82c2 , 0xcbb73112 , 0x436e916a , 0x565e93ac , 0x161886be ] )
+  , Mont::unsafe_make( [ 0xb5133aa9 , 0x71303526 , 0x035228c7 , 0x78ff344b , 0x5034497d , 0xbaa5866f , 0x01c234aa , 0x1b821617 ] )
+  , Mont::unsafe_make( [ 0x8c8b80e5 , 0x75e827a4 , 0x8d62547e , 0x2649292a , 0xe3ec7980 , 0x13e8fa46 , 0xbb0f5923 , 0x1b2dd5e3 ] )
+  , Mont::unsafe_make( [ 0xf64c0a97 , 0x47b1abb2 , 0x4705f6f2 , 0x9ab733fc , 0xc4df06d1 , 0xf971426d , 0xc6ba6a2d , 0x0ae4db06 ] )
+  , Mont::unsafe_make( [ 0x5f3f9fb7 , 0xbc66a6f0 , 0xf17cb95e , 0x9491383c , 0xabc12c31 , 0x4bd41bb5 , 0x0bb7fe99 , 0x1e21102c ] )
+  , Mont::unsafe_make( [ 0x8f0c26bb , 0xdd073aaa , 0xbd1789dc , 0xe7a3bda3 , 0xd32cffc5 , 0xb554c337 , 0x5d2e3bce , 0x113f6ad0 ] )
+  , Mont::unsafe_make( [ 0x2aa72e6e , 0xc30ac959 , 0x62dff369 , 0x7c5827d1 , 0x4618a08c , 0x7cfb8917 , 0xceda01e6 , 0x1541aea0 ] )
+  , Mont::unsafe_make( [ 0xdcace1df , 0x01aa6ede , 0xb8129f5c , 0xe1ac4285 , 0xbb9fa71a , 0xdffa717d , 0xe020e629 , 0x12bab5b0 ] )
+  , Mont::unsafe_make( [ 0x98f7fabe , 0xcdf40660 , 0x38c65261 , 0x2184056c , 0x0d38b9ea , 0x5f2cd892 , 0x4aa3b30f , 0x1f134f95 ] )
+  , Mont::unsafe_make( [ 0x4e1353c0 , 0xbdfc0b7b , 0xd1271388 , 0xbfc86f8a , 0xe920fb5f , 0x8cd38275 , 0x32f19b05 , 0x2e21f3b1 ] )
+  , Mont::unsafe_make( [ 0x00b42f89 , 0xbf033675 , 0x05c10e9a , 0xffae3c02 , 0x7b75ff13 , 0xc07ccb5b , 0x19195cb7 , 0x1ea9db8d ] )
+  , Mont::unsafe_make( [ 0x0283a2b7 , 0x0f3f2f9e , 0xca7d96e4 , 0xb05b4814 , 0x2d88ca66 , 0xa67e3621 , 0x4d0429a0 , 0x2b0683e3 ] )
+  , Mont::unsafe_make( [ 0x9b1d57e3 , 0xe266dfb4 , 0x137d40c1 , 0x9d29ea63 , 0x0b4ac865 , 0x651dd2c8 , 0xf26ec5c5 , 0x2fcc337e ] )
+  , Mont::unsafe_make( [ 0x958384e1 , 0xf7666cbf , 0x3065cb2d , 0xaace97e4 , 0x5c602a98 , 0xdee0c59d , 0x80a6b665 , 0x0fc1e8e3 ] )
+  , Mont::unsafe_make( [ 0x7bf12e94 , 0xa8478225 , 0x04583791 , 0x1a4bdded , 0x6b42ae6f , 0xf38229ae , 0x39128bbc , 0x0061d8b5 ] )
+  , Mont::unsafe_make( [ 0xd4930dc1 , 0x25baa00e , 0x3f01efb4 , 0x617c3f44 , 0xf85dda2a , 0x467f63b6 , 0x9b4adcb4 , 0x1bb00457 ] )
+  , Mont::unsafe_make( [ 0x5ece9a83 , 0xb4d38221 , 0x71e94a9b , 0x2cbc175f , 0x87c0cc7f , 0x9d70ac6a , 0xc7c5e857 , 0x18408a2b ] )
+  , Mont::unsafe_make( [ 0x77b02b4b , 0x58eddb04 , 0xcce554d8 , 0xa2abe580 , 0xab519ee3 , 0xed7be345 , 0x55ede68b , 0x0ef6db54 ] )
+  , Mont::unsafe_make( [ 0x7d8be6e1 , 0x4121bb55 , 0x53204c9e , 0x0a1a7a51 , 0x33719705 , 0xd03ed265 , 0x75575884 , 0x1517f2e2 ] )
+  , Mont::unsafe_make( [ 0x3345862c , 0x57362343 , 0xf1d12db1 , 0xe5d3d1ef , 0x255888ec , 0xdd89c658 , 0x74914b7e , 0x1ccf001a ] )
+  , Mont::unsafe_make( [ 0xcd92309d , 0x35d59f44 , 0x8cb16a77 , 0x679865a2 , 0x38c7b6f6 , 0xf5530ec2 , 0xb4c3bb0b , 0x162a8940 ] )
+  , Mont::unsafe_make( [ 0xe539b050 , 0xe94e4dff , 0xf6bf97f6 , 0xf0b37937 , 0xa9bc4cc8 , 0x63f70be0 , 0x591c9443 , 0x2b5337b8 ] )
+  , Mont::unsafe_make( [ 0xd203689c , 0x5b6c0fce , 0x278dd933 , 0xa4db97ad , 0x892792d2 , 0x5541b703 , 0x8ee240cd , 0x129532be ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xe91415da , 0x725ec3e6 , 0x9969d3d5 , 0x6ece0d12 , 0x9b3f51eb , 0xf303bdcd , 0x3774bd69 , 0x0844e521 ] )
+  , Mont::unsafe_make( [ 0xa61aead1 , 0x07087f53 , 0x15bba8f3 , 0x61a01529 , 0x2330fb50 , 0x06df4772 , 0x6e775149 , 0x2c31da72 ] )
+  , Mont::unsafe_make( [ 0x11e4ba3e , 0x468b25b1 , 0xfbc4c1b0 , 0xccfefa45 , 0xcfba83ba , 0x70565960 , 0xdefecb10 , 0x20455232 ] )
+  , Mont::unsafe_make( [ 0x9e77668c , 0x9b6558cd , 0xc88781b0 , 0x82af952b , 0xe21c1307 , 0x86cbb083 , 0x20a80e6d , 0x234e5798 ] )
+  , Mont::unsafe_make( [ 0x0e7b35db , 0x01848de7 , 0x754c1995 , 0x1278d176 , 0xf9497b4e , 0xb3ffe1e2 , 0x267af7e1 , 0x27779df4 ] )
+  , Mont::unsafe_make( [ 0x7189a3cc , 0x63127dab , 0x38a796a1 , 0xf8c4d0f5 , 0x473345f3 , 0xbafddefe , 0xc83b7eb8 , 0x1a61974a ] )
+  , Mont::unsafe_make( [ 0x194c102b , 0x50322b9c , 0xe0efff0f , 0x30cb5229 , 0xee4d3569 , 0x293622ae , 0x872aea7d , 0x23465714 ] )
+  , Mont::unsafe_make( [ 0x5ff1e215 , 0x068f3403 , 0x3ea879c5 , 0x82f23faf , 0x5199259c , 0x19c79052 , 0xf957763c , 0x1e4a0d73 ] )
+  , Mont::unsafe_make( [ 0x44f78a61 , 0x39009812 , 0x8de55bbc , 0x26b7309d , 0x3f5671b5 , 0x481caf10 , 0xf58be884 , 0x21cf345a ] )
+  , Mont::unsafe_make( [ 0x74821a53 , 0x53657589 , 0x4e5127b0 , 0x8cc09060 , 0x6fbed455 , 0x9f29f47a , 0x294cf49d , 0x25baa03a ] )
+  , Mont::unsafe_make( [ 0x6fe641b7 , 0x966ec3fb , 0xf4995e6c , 0x9e5a34f5 , 0x4afd7224 , 0x30df5738 , 0x86713ef0 , 0x078bfa52 ] )
+  , Mont::unsafe_make( [ 0x97d9423e , 0x847c42f0 , 0x6e60a331 , 0x9aa8525f , 0xebab1147 , 0xc9230453 , 0xb8f00f65 , 0x08277084 ] )
+  , Mont::unsafe_make( [ 0xbd2b4506 , 0xae4fcc7b , 0x65766bfd , 0xaf1ecc8d , 0x5a3c492f , 0x1c416b12 , 0x572960b8 , 0x0ae05857 ] )
+  , Mont::unsafe_make( [ 0xe4fbd286 , 0x0582efaf , 0xa77271bf , 0x3e53ed48 , 0x891641af , 0x5daef656 , 0x9635bd56 , 0x17af57e1 ] )
+  , Mont::unsafe_make( [ 0x432c76ac , 0xb7a14925 , 0x4ee8cdae , 0x6ab8dd5c , 0xb9fa9c98 , 0xe5c9359c , 0xfa1014c3 , 0x092a3114 ] )
+  , Mont::unsafe_make( [ 0x8dbcb241 , 0x5a0f114a , 0x091f882d , 0x17de7755 , 0x23e79d16 , 0xfbf57f32 , 0xb01fc3e9 , 0x23b969ad ] )
+  , Mont::unsafe_make( [ 0x78d46f03 , 0xf08f342c , 0x431b39a4 , 0xc11a9f51 , 0x8ff23119 , 0xb321ef78 , 0x56728aa0 , 0x14876ced ] )
+  , Mont::unsafe_make( [ 0x6b91f401 , 0x0d00f698 , 0xb936afe2 , 0x233108ba , 0x7b9cd3a3 , 0xd9d09c3c , 0xdba20f9f , 0x13f9dcd4 ] )
+  , Mont::unsafe_make( [ 0xf95d5eb5 , 0xa2a4b6a0 , 0x0131d398 , 0x74af69d4 , 0xacc38609 , 0x6678eebc , 0xc4f66380 , 0x2186ac03 ] )
+  , Mont::unsafe_make( [ 0xa7a6557b , 0x2afbeb8e , 0x2c230c84 , 0xc58c5536 , 0xf41e7560 , 0xf8031d1e , 0x1bb53c55 , 0x2b7e00f2 ] )
+  , Mont::unsafe_make( [ 0xc4889015 , 0x8349d226 , 0x207d536e , 0x081b5a68 , 0x7f193808 , 0xa786790c , 0x66a479e4 , 0x16fbd5d9 ] )
+  , Mont::unsafe_make( [ 0x3047f56b , 0x555a6519 , 0x4da62da3 , 0x176b9b2d , 0x493acfbe , 0x0b7c6ed6 , 0x79e69cbb , 0x03735334 ] )
+  , Mont::unsafe_make( [ 0x2282f852 , 0xdfc2493f , 0x587aba40 , 0x8dc9e698 , 0xd7ceb006 , 0x1120e06d , 0x51618231 , 0x1cc60a6c ] )
+  , Mont::unsafe_make( [ 0xd1b4968b , 0x8cb108c0 , 0xe3cce503 , 0x61fb1f60 , 0x398dee7c , 0x23521019 , 0xe6949d97 , 0x1b708b0c ] )
+  , Mont::unsafe_make( [ 0x4bda3861 , 0xbfd2aaf5 , 0xd43dd524 , 0x07090d76 , 0x025da7bb , 0x4d4dc6c1 , 0x38673282 , 0x10bdc89c ] )
+  , Mont::unsafe_make( [ 0xf5cdd7b4 , 0x153ac5db , 0x72befa9a , 0x3e548939 , 0x7eba7e7a , 0x70eccb55 , 0x3d934051 , 0x103006b0 ] )
+  , Mont::unsafe_make( [ 0xf5732755 , 0xb04c2c72 , 0xf8928e68 , 0x445dc79e , 0x83c14749 , 0x7fd74819 , 0x41d02543 , 0x0408bcec ] )
+  , Mont::unsafe_make( [ 0x12d812c6 , 0xbfe4aec1 , 0xa9136da5 , 0x99414c37 , 0xe12f6417 , 0x13cd8531 , 0xfbd6e47c , 0x1a39db9b ] )
+  , Mont::unsafe_make( [ 0x336b691f , 0xa67dbca7 , 0x0d46348f , 0x40529d5d , 0x71a83d5d , 0x1868f1f6 , 0x96da5285 , 0x01c9ea45 ] )
+  , Mont::unsafe_make( [ 0x78e11ce8 , 0xc44feb79 , 0x5ecd278e , 0x85b1c6ea , 0x0338c20c , 0x1fee0381 , 0xb6b823fa , 0x11dec631 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xf440ad3f , 0x7f05c505 , 0x6ff1237f , 0x8958774b , 0xde3e00b8 , 0x47c82724 , 0x6d86e5f7 , 0x031dbfde ] )
+  , Mont::unsafe_make( [ 0x4c46c21b , 0x6734188d , 0x9625fad8 , 0x08fcd1c8 , 0xed4375e8 , 0xac78cc80 , 0xd2876d28 , 0x2b7c4ead ] )
+  , Mont::unsafe_make( [ 0xf1f36f79 , 0xe151a0df , 0x1a176742 , 0x7375fa10 , 0xaa86f9c1 , 0x4ab5ea0c , 0xa0ab2865 , 0x243da6ff ] )
+  , Mont::unsafe_make( [ 0x732d0672 , 0x3f02b39e , 0x3de03c78 , 0x7f259def , 0xe451e66c , 0xdc692eae , 0xa764677a , 0x2a4de3fc ] )
+  , Mont::unsafe_make( [ 0xf37d9868 , 0x2a4dd048 , 0xb4a6185a , 0xf7ad70a2 , 0x0ca2dee4 , 0xbb78ae78 , 0x6ef3c10f , 0x2189b5d1 ] )
+  , Mont::unsafe_make( [ 0xea25561e , 0xf944d0fc , 0xc007375a , 0x7583c495 , 0x8b565c8e , 0xf4cb5396 , 0x7052abba , 0x1201d3d6 ] )
+  , Mont::unsafe_make( [ 0x2e03cdb1 , 0x3f28d74b , 0xfc96b004 , 0xf44c15d0 , 0x445655ab , 0x80b32fa8 , 0x16b8ffe6 , 0x0cdc1481 ] )
+  , Mont::unsafe_make( [ 0x316b7a81 , 0x4dbceb0e , 0xb0f53123 , 0xf0baa042 , 0x27069d92 , 0x19533569 , 0xacde4126 , 0x18dce414 ] )
+  , Mont::unsafe_make( [ 0x03d652f1 , 0xe7aeaa71 , 0x3a0f0b7d , 0x3c55db37 , 0xc91070dd , 0x833b32c1 , 0x78855007 , 0x1445d0b4 ] )
+  , Mont::unsafe_make( [ 0x9532859b , 0x84b9c6b6 , 0x96dc4ee1 , 0x3539990c , 0x1ba0b8b5 , 0xd046d56c , 0xb4dee942 , 0x2d19a6c5 ] )
+  , Mont::unsafe_make( [ 0xf89fac24 , 0xce6a82b9 , 0xe347ef0e , 0x8812ae30 , 0x8457c007 , 0x7d5fe3fc , 0xdc19285e , 0x2e529335 ] )
+  , Mont::unsafe_make( [ 0x20771d79 , 0xf775c607 , 0xe75349dc , 0xf2e332a0 , 0x9ea5ef4b , 0x0e3f1c10 , 0x483ed683 , 0x05474cc8 ] )
+  , Mont::unsafe_make( [ 0x919c31e6 , 0x56feb47d , 0xf0569c95 , 0xc15f9571 , 0xd1534e17 , 0x06a68b07 , 0xa08cc18e , 0x2ba87b35 ] )
+  , Mont::unsafe_make( [ 0x59cd603b , 0x84006eb9 , 0x13b17b67 , 0x182b9793 , 0xf8eb1382 , 0x2af46840 , 0xc3819546 , 0x06b17c09 ] )
+  , Mont::unsafe_make( [ 0xf58b57c4 , 0x968c845f , 0x78d9a59e , 0xc0d76499 , 0x31cb5117 , 0x660db975 , 0x4e0ea220 , 0x0fc4d615 ] )
+  , Mont::unsafe_make( [ 0x95fbc53c , 0x07a4af24 , 0x9bcef1b5 , 0xd6765c17 , 0x2edd2917 , 0xb16ccd8b , 0xb4dd2625 , 0x0d56840d ] )
+  , Mont::unsafe_make( [ 0x8a8a5c34 , 0x91a84e1d , 0xc8647856 , 0x91f01bce , 0x9df6c42d , 0xe22c5eae , 0xa323c524 , 0x13a79c16 ] )
+  , Mont::unsafe_make( [ 0x699a9a50 , 0xddacadc3 , 0xa190ee05 , 0xba1324d9 , 0x1269e55a , 0x006cb340 , 0xf89ee97f , 0x29b34035 ] )
+  , Mont::unsafe_make( [ 0x5552db73 , 0xd8b0c058 , 0xe80fb445 , 0x1d364989 , 0xe85becb7 , 0xbdd7feb9 , 0xd1a05030 , 0x2aeff44f ] )
+  , Mont::unsafe_make( [ 0x34f4bfd2 , 0xf95717be , 0x6068a398 , 0x07768ceb , 0x5b2dd6e5 , 0x53c97e06 , 0xfabf9f96 , 0x06d17f92 ] )
+  , Mont::unsafe_make( [ 0x6b440370 , 0x395575d9 , 0xb35aceb9 , 0x2bfd30bb , 0xe648bd74 , 0xbdc65e4b , 0x47f89794 , 0x0b95b749 ] )
+  , Mont::unsafe_make( [ 0x5bb8cd9c , 0xeb10be70 , 0x04390123 , 0x38e0bd2f , 0xf217d615 , 0xcce66c95 , 0x76d33705 , 0x1256c65d ] )
+  , Mont::unsafe_make( [ 0xf3685fe5 , 0x07d75d63 , 0x2a52f93c , 0x394ae035 , 0xb87c61d3 , 0x4ca17439 , 0xf12a6c31 , 0x1496c618 ] )
+  , Mont::unsafe_make( [ 0x2514d2ce , 0x29059616 , 0xf41e2eca , 0x1d277d0e , 0xe2c863ca , 0x78615e8e , 0xe6a396a3 , 0x240d82e6 ] )
+  , Mont::unsafe_make( [ 0xe489b8a4 , 0x3d326605 , 0x9f737b7a , 0x55cd91ba , 0x8d128e63 , 0x20fadefa , 0x6e89e97d , 0x27be4992 ] )
+  , Mont::unsafe_make( [ 0x6d136711 , 0x0090eaa7 , 0xd14ffb33 , 0x6d32f9f5 , 0x441aca26 , 0x57e8c4ec , 0x7223f963 , 0x2e0b46eb ] )
+  , Mont::unsafe_make( [ 0xddd43aa5 , 0x5ccdde08 , 0x4524aba2 , 0x1ce548a7 , 0x0c1c563f , 0xdf6e0121 , 0xba3e4f71 , 0x2662f53a ] )
+  , Mont::unsafe_make( [ 0xdf0b4239 , 0x2351785b , 0x90269df2 , 0xed699b84 , 0x2fe3c6b9 , 0x781aa24e , 0xbd745006 , 0x286832a2 ] )
+  , Mont::unsafe_make( [ 0x3deb7ef3 , 0x19fcdf39 , 0x60b272a7 , 0x4e6ac666 , 0x47056d27 , 0xb870f2ad , 0xeb88309f , 0x12b2bd7c ] )
+  , Mont::unsafe_make( [ 0x1efde4dd , 0xd38d1063 , 0xbe7a262b , 0xe7022eeb , 0x8c20706d , 0x2018c635 , 0xa2ad2ad4 , 0x1892da0e ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xa52511ec , 0x49ba7c39 , 0xc9dfddfa , 0x78055b3b , 0xb8fc8831 , 0x18225930 , 0x379422bd , 0x30200cf6 ] )
+  , Mont::unsafe_make( [ 0x752d2aa1 , 0xd36afc29 , 0x4489d18d , 0x122c505b , 0x92722e97 , 0xd4e9f0a6 , 0x001756fb , 0x1297b64e ] )
+  , Mont::unsafe_make( [ 0x02d9ff53 , 0x43cf579e , 0x4ab5170e , 0x3659c460 , 0xf737cdd2 , 0x7989fd18 , 0xa23c0a57 , 0x30257408 ] )
+  , Mont::unsafe_make( [ 0x2ce00bab , 0x0e33403d , 0x2a09948f , 0x7403b1b8 , 0xbac3c8b1 , 0xf22aec98 , 0x81e797e3 , 0x2c37f5ce ] )
+  , Mont::unsafe_make( [ 0x3be36f64 , 0x838fb279 , 0xa76e1466 , 0x3d3af462 , 0x0b620102 , 0x0e31d0b2 , 0x28ef70f4 , 0x13d1e3fa ] )
+  , Mont::unsafe_make( [ 0xab5bffb6 , 0xe35fbe08 , 0x6f1eb89c , 0xe42bfcf2 , 0x1fe8581c , 0x6e05e43a , 0x08703785 , 0x1177dcbc ] )
+  , Mont::unsafe_make( [ 0x5256e3df , 0x97d7bea7 , 0x181904d1 , 0xdb3b257b , 0xf1d77420 , 0x547c1297 , 0x358e512e , 0x2a44f8a5 ] )
+  , Mont::unsafe_make( [ 0xdaf8cd92 , 0x2e5e77ee , 0x3d820fba , 0x9e445960 , 0x6150af8f , 0x573d82b7 , 0xf87df446 , 0x0ac53a2a ] )
+  , Mont::unsafe_make( [ 0xecea3304 , 0x12885fbb , 0xf41efefe , 0xbd963cba , 0x39e619b5 , 0x5e8659cf , 0x7991e64f , 0x05ac7149 ] )
+  , Mont::unsafe_make( [ 0xc214c02e , 0x7515716f , 0x089253b8 , 0xda84eb8a , 0x7063e569 , 0xb3806fed , 0x84b5b6be , 0x0711911d ] )
+  , Mont::unsafe_make( [ 0xf1cc43c0 , 0x2d86bc2c , 0x38de1565 , 0x6404626b , 0x700ed657 , 0xb71c8533 , 0xe4606982 , 0x15546159 ] )
+  , Mont::unsafe_make( [ 0xe3b40bb8 , 0xc6817e54 , 0xfc423773 , 0x9cd2d393 , 0x3597f322 , 0x4025faf6 , 0x8bdfd663 , 0x005260e9 ] )
+  , Mont::unsafe_make( [ 0x5b8a5caf , 0x0e818894 , 0xd9602b44 , 0xb5b8adb4 , 0xc20b7ba3 , 0x2761f3b1 , 0xd6168989 , 0x00a903fc ] )
+  , Mont::unsafe_make( [ 0x198c0c59 , 0xbf8d4fc3 , 0x1337cda7 , 0x4724d2d5 , 0x6851f637 , 0x4821a5b5 , 0x701856f9 , 0x10a37f0b ] )
+  , Mont::unsafe_make( [ 0x1b803791 , 0x5a42b270 , 0x7e776c95 , 0xb891ff53 , 0xdb68fc82 , 0x977f233f , 0x590ad3a2 , 0x129511d1 ] )
+  , Mont::unsafe_make( [ 0xab25aae0 , 0x0549ce0a , 0xfd21eeb3 , 0x05fcf3a0 , 0xccd7d9e5 , 0xe560b499 , 0xb2a22358 , 0x2fc497dc ] )
+  , Mont::unsafe_make( [ 0xa67d6b87 , 0x01db42c3 , 0x958ac437 , 0x5e518ca5 , 0x5e72a65e , 0x7790c072 , 0xbba1a993 , 0x0ea336b6 ] )
+  , Mont::unsafe_make( [ 0x82d6c128 , 0xee9d02c7 , 0xa66fc1ab , 0x1e1c3c11 , 0xc6c20f30 , 0xf242e568 , 0xfaa1ea4b , 0x2149f708 ] )
+  , Mont::unsafe_make( [ 0x5a7bdb83 , 0x5c0ff93c , 0xcb82eb46 , 0xf61d2792 , 0x7bcf909f , 0x72e87662 , 0xeed7773d , 0x2915b75a ] )
+  , Mont::unsafe_make( [ 0xfc13cfac , 0xf903c7ed , 0xf39975fc , 0x6c923147 , 0xab9511e7 , 0x13507743 , 0x581b724e , 0x305f7211 ] )
+  , Mont::unsafe_make( [ 0x3c5676df , 0xc2d364fa , 0x93af8234 , 0xff7a36ca , 0x3e746f3e , 0x937f944b , 0xdc893a42 , 0x1dfe418b ] )
+  , Mont::unsafe_make( [ 0x6d3ca7e9 , 0xb2463593 , 0xe4478bc9 , 0x1c68b926 , 0x6896b125 , 0x5d781e6e , 0x3a287abf , 0x3062d061 ] )
+  , Mont::unsafe_make( [ 0xfa8ec4a4 , 0x44dde89a , 0x46c69c8c , 0x1feb3e70 , 0x41be3fa5 , 0xa233c095 , 0x5df17a39 , 0x2c2e01b9 ] )
+  , Mont::unsafe_make( [ 0x8ed41a56 , 0x964f38f0 , 0x210f6135 , 0xb933250b , 0x55a44296 , 0xb7d4fe95 , 0xc8d40e1d , 0x03b0c2e7 ] )
+  , Mont::unsafe_make( [ 0xafef2085 , 0x0737c589 , 0xb1f7dad7 , 0xa71ba5ef , 0x8e140e76 , 0x41e5ddd2 , 0x1181a912 , 0x1e28903b ] )
+  , Mont::unsafe_make( [ 0x79760065 , 0x7054ebb8 , 0x0135f8bd , 0x4cedcb7b , 0x3a704480 , 0x3f809814 , 0x2bfa48d2 , 0x22371889 ] )
+  , Mont::unsafe_make( [ 0xdcbdd132 , 0x4f1db945 , 0x1fb685d3 , 0xf92498ca , 0x2dff92f5 , 0x5bd8ba4c , 0x8aece409 , 0x15930750 ] )
+  , Mont::unsafe_make( [ 0xa948ff9f , 0xbb3250f0 , 0xf9831e79 , 0x2a3cfe12 , 0xc5ad63fc , 0xb5123255 , 0x8659e330 , 0x0dfc519f ] )
+  , Mont::unsafe_make( [ 0xa68baf79 , 0x06ffa0ea , 0xdb0e09b4 , 0xf1062be1 , 0x56fe0825 , 0x351a21bc , 0x6b4e8ef2 , 0x255bf2df ] )
+  , Mont::unsafe_make( [ 0xf4ad3890 , 0xb2179e53 , 0x6145719c , 0x02d116de , 0x86abef74 , 0x8121b23d , 0x83b0bbc4 , 0x13b16d5c ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x930f1e8a , 0x7f01fbec , 0x3060cfd7 , 0xfd9555a4 , 0x0eac2574 , 0xe2292f3c , 0x39e52ffb , 0x035bd5f2 ] )
+  , Mont::unsafe_make( [ 0x9ac5b4cb , 0xa71fc4b9 , 0x4cf626ab , 0xe9ee1b54 , 0x6b97cb82 , 0x8488ca8f , 0xed7d54c9 , 0x1ebdebb2 ] )
+  , Mont::unsafe_make( [ 0xef9498db , 0x6398754f , 0x06290c7a , 0x177d33dd , 0xd47ac3d9 , 0x07580cd3 , 0x80e1379e , 0x03c9f137 ] )
+  , Mont::unsafe_make( [ 0x3202257c , 0xa7663bd7 , 0x735f4853 , 0x04139cda , 0x2d25285f , 0x7ab14d86 , 0xc28e0387 , 0x08dcbd76 ] )
+  , Mont::unsafe_make( [ 0x85cb1837 , 0x228c934d , 0x477d2f5f , 0x96055093 , 0x812ec803 , 0x8f3e9f10 , 0x6e135d7a , 0x2f1469da ] )
+  , Mont::unsafe_make( [ 0x7594b6d5 , 0xe668003d , 0x010dd77a , 0xb7300e14 , 0x1aa8110f , 0x9b7a721e , 0x2ece98e7 , 0x1b8197a7 ] )
+  , Mont::unsafe_make( [ 0xf95b1a9a , 0xa2fe697d , 0x35341315 , 0xa75b84c7 , 0x5cface08 , 0x4b9fe7bc , 0x36bbbaa6 , 0x2a1ff4ad ] )
+  , Mont::unsafe_make( [ 0xb49c8750 , 0xfa9800c2 , 0xac7c2774 , 0x26a53b1b , 0x6ce136de , 0x3eaa5d92 , 0x472cc261 , 0x1f7dc35e ] )
+  , Mont::unsafe_make( [ 0xbbb57b4c , 0xd31d84a2 , 0x50e2a3bb , 0x6d2b46f9 , 0x19bd3fab , 0x8f5f4e2f , 0xde74debf , 0x004e656d ] )
+  , Mont::unsafe_make( [ 0x31164932 , 0xdb927134 , 0x48a52a1b , 0xcb781323 , 0x70f1cc22 , 0x9a5eca32 , 0x1ed7a6b7 , 0x1098220c ] )
+  , Mont::unsafe_make( [ 0xebe83493 , 0x5d79be0f , 0xea5f51d5 , 0x7031ca41 , 0x2b1189be , 0x2c8c35d9 , 0x3a6675b8 , 0x03ed1c04 ] )
+  , Mont::unsafe_make( [ 0x28a979b0 , 0x7a41ad62 , 0x67001456 , 0xc5a415b9 , 0x2c4604fc , 0xbf19db58 , 0x811728d8 , 0x117b52bf ] )
+  , Mont::unsafe_make( [ 0xe53c2853 , 0x39482409 , 0xd1a9d85d , 0x5fa8a08d , 0xeee10178 , 0x989d9f93 , 0x46673b4e , 0x0a88512b ] )
+  , Mont::unsafe_make( [ 0xca4585ae , 0x5c9d4544 , 0x1cfd6dfa , 0xd1183d93 , 0x244859fc , 0x9b070346 , 0x49688bc5 , 0x26a65b84 ] )
+  , Mont::unsafe_make( [ 0xec317dc6 , 0xaf031333 , 0x622fe9c8 , 0xa7cbd0bd , 0x9bc211fc , 0x9f09f0e5 , 0x3a8d068a , 0x18c12611 ] )
+  , Mont::unsafe_make( [ 0xd5a82763 , 0x4f9ea862 , 0x1d99b930 , 0x8f75d36b , 0x7664963f , 0xdc54cb30 , 0x9a7e33bd , 0x09bb8ada ] )
+  , Mont::unsafe_make( [ 0x1f8d6403 , 0xcdff8418 , 0xc8591bd6 , 0xfe35986c , 0x54ebc3de , 0x6dffaea4 , 0x5ba9741b , 0x02d63fd7 ] )
+  , Mont::unsafe_make( [ 0x5994592e , 0xf8aae2bc , 0xcd3b0ec8 , 0x3ceaf6d4 , 0xb5d93b16 , 0x3f803971 , 0x1adb95c9 , 0x2e8e32c7 ] )
+  , Mont::unsafe_make( [ 0x056f3500 , 0x52085e45 , 0x83f1733b , 0x7d3f1b59 , 0xfbca4142 , 0x32ad095f , 0xd0f585b0 , 0x16ce693b ] )
+  , Mont::unsafe_make( [ 0xc6edd314 , 0x6f4ac283 , 0x5ecff46e , 0x0158a6dd , 0xe9c76953 , 0xa79a17dd , 0x79e9d4ac , 0x24b8aa5b ] )
+  , Mont::unsafe_make( [ 0x91a49b54 , 0xe07ef62e , 0x62082003 , 0xad801978 , 0x3f8466b0 , 0x572e2fa9 , 0xa4e5cf47 , 0x25183c91 ] )
+  , Mont::unsafe_make( [ 0x8e9455b4 , 0x7682ad89 , 0xfaa872fa , 0xcf48987c , 0xf3fb8e10 , 0x04914ade , 0x4d659e5d , 0x04c07af5 ] )
+  , Mont::unsafe_make( [ 0xa734df1a , 0xe6377dea , 0x4f45ee23 , 0xa81b89b5 , 0x0482035b , 0xed98aa1e , 0x35c77ed3 , 0x27339c76 ] )
+  , Mont::unsafe_make( [ 0xc44c29a3 , 0x41cf79ec , 0xee21890f , 0xb6c842dd , 0xba4f0aa9 , 0xfdb57a41 , 0xd92477cf , 0x2bc5f9df ] )
+  , Mont::unsafe_make( [ 0x5a4f3300 , 0x95475bfd , 0xfddf1b9e , 0x80a89c0d , 0xd9eabde0 , 0x21cc700e , 0xa2fe5c76 , 0x219f48b4 ] )
+  , Mont::unsafe_make( [ 0x776e6a78 , 0x272c7970 , 0x1bae5d14 , 0x9871d044 , 0x3762e07e , 0xaab85c81 , 0xe22d9cfc , 0x191c651b ] )
+  , Mont::unsafe_make( [ 0x4bef039b , 0x57a0234d , 0x87a60f13 , 0x3e1a63ec , 0x68780f73 , 0x30ef7362 , 0x83798267 , 0x1eb6b3ef ] )
+  , Mont::unsafe_make( [ 0x3acaed7f , 0x0501e166 , 0x86c8fb0f , 0x6f1c664f , 0xc4d8e793 , 0xcf92eaac , 0x99bf59d6 , 0x1ae3fa18 ] )
+  , Mont::unsafe_make( [ 0x1fc84b72 , 0x375fb01d , 0xb196f570 , 0x02c3f273 , 0xd9c82190 , 0xe95006dd , 0xe6639138 , 0x0874d268 ] )
+  , Mont::unsafe_make( [ 0x554239c8 , 0x068d8ae2 , 0x7f7d5ab7 , 0x07929700 , 0x962c98b0 , 0x71700e16 , 0xb63fd9f5 , 0x0d97f48b ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x69331c03 , 0x04bf0448 , 0xc74c2989 , 0xeb37de07 , 0xf12fedeb , 0x1b6aeb59 , 0xfa36d02f , 0x2f29568d ] )
+  , Mont::unsafe_make( [ 0xb2ddd0bb , 0x3c8a2e0e , 0x85a8c49c , 0x2abec8db , 0x310dcd34 , 0x4f12b1d4 , 0xe705a913 , 0x110128b6 ] )
+  , Mont::unsafe_make( [ 0xd4b1bfcc , 0x28f757e0 , 0x8689b80d , 0x2bb2987b , 0x74ca58db , 0x01e2366b , 0xd4379cfd , 0x25204bfc ] )
+  , Mont::unsafe_make( [ 0x4c888476 , 0x18bd6493 , 0xf124c24d , 0x83c20b7b , 0xdc2cb793 , 0x0e8bda78 , 0xcc40a0f0 , 0x3031ddec ] )
+  , Mont::unsafe_make( [ 0x9217d1c5 , 0x9f9141e0 , 0x627161b0 , 0xca395696 , 0x61c6a3c7 , 0x3f026443 , 0x5977f261 , 0x29dfffc2 ] )
+  , Mont::unsafe_make( [ 0xe91c25b5 , 0x16886b58 , 0x53804f14 , 0x57e6426b , 0x4f63eccb , 0xdd731fbd , 0xe8a45ab6 , 0x19d64f58 ] )
+  , Mont::unsafe_make( [ 0x7546b105 , 0xbe88b4f4 , 0x5a1e6c69 , 0x3657e298 , 0x0b233161 , 0x5345b194 , 0xffa7bf9d , 0x0f95fd44 ] )
+  , Mont::unsafe_make( [ 0x7e21f3cf , 0x8b6ee98b , 0x715a3803 , 0xe8cd56d6 , 0x206f80b4 , 0xe70fbca7 , 0xb21d9103 , 0x2595f3d0 ] )
+  , Mont::unsafe_make( [ 0xc580de59 , 0xec1952de , 0x6311334c , 0xae43e4d9 , 0xbc1aa20e , 0x51062465 , 0x93ed9c6b , 0x1c5ad597 ] )
+  , Mont::unsafe_make( [ 0x579a4d9d , 0x31e26ee1 , 0x9b02a27a , 0xf54657c3 , 0x25142e1d , 0x58d7add9 , 0x54724f9f , 0x1021a045 ] )
+  , Mont::unsafe_make( [ 0x9e277234 , 0xd413027d , 0x0a5824d8 , 0x836ba46d , 0xbd913fa0 , 0x32616c0f , 0x580bffb3 , 0x09f89b25 ] )
+  , Mont::unsafe_make( [ 0xcc3a2b6f , 0x527ad756 , 0x62f7f0de , 0xefa6d08d , 0x2fc21150 , 0x9b720f57 , 0xd7a97a0c , 0x2cac6205 ] )
+  , Mont::unsafe_make( [ 0xae1c8f80 , 0xed40413d , 0x46538bda , 0x3445e67b , 0x82a4813d , 0x1b093c1f , 0xa16db894 , 0x250ee57b ] )
+  , Mont::unsafe_make( [ 0x0001ba69 , 0x58a2a580 , 0x473f85da , 0x4e87a5f4 , 0xa1c5d0aa , 0xf738a40d , 0x6534a15e , 0x1ebf01c0 ] )
+  , Mont::unsafe_make( [ 0x685913a0 , 0x12461fbb , 0xdefd069a , 0x7e6cea1a , 0xe4a67918 , 0x12b46902 , 0x7c61b18b , 0x136d299c ] )
+  , Mont::unsafe_make( [ 0x40e475aa , 0x22546efe , 0x35043c0e , 0x7fbda4ec , 0x017db41c , 0xb23e44cb , 0x3bcb73ad , 0x1fcab337 ] )
+  , Mont::unsafe_make( [ 0xeca4ef6b , 0xa05bdeb1 , 0x46da8d6c , 0xe4e20710 , 0x0b1fe36b , 0x0836db77 , 0x7087fd71 , 0x1aa64652 ] )
+  , Mont::unsafe_make( [ 0xf995cae7 , 0x8d36e456 , 0xff375619 , 0x6b1952a5 , 0x7604f827 , 0x9c671db8 , 0xc2943ce5 , 0x0983e521 ] )
+  , Mont::unsafe_make( [ 0x9ecc25ce , 0x7fea1320 , 0xd28e2db7 , 0x264aee15 , 0x2d73073d , 0xea47726f , 0xe4de7ed5 , 0x220026e6 ] )
+  , Mont::unsafe_make( [ 0xe166f46c , 0x2353100a , 0x89ec3f7a , 0x3828823e , 0xc83cae40 , 0xe30a96f7 , 0xd354f945 , 0x26be6a68 ] )
+  , Mont::unsafe_make( [ 0xf9b57cb7 , 0xa4b662fe , 0xafe1c939 , 0x50abefc2 , 0x1334118e , 0x5ae1fd6d , 0x2d94d5f7 , 0x1edc4449 ] )
+  , Mont::unsafe_make( [ 0x4ca23286 , 0x5a7eb7af , 0x0ee5d016 , 0x0e68a617 , 0x7253b3aa , 0xb7b000ed , 0x767de5a8 , 0x12c84304 ] )
+  , Mont::unsafe_make( [ 0x274b11a5 , 0xc9ce641e , 0x952982cc , 0xc1bd2d83 , 0x6cde6543 , 0xf22c27f4 , 0xfa427008 , 0x10f803aa ] )
+  , Mont::unsafe_make( [ 0x842ecfff , 0x352c97f1 , 0x3335f0ef , 0xa82428b9 , 0x0bfd132f , 0xffa1af2e , 0xc8909776 , 0x11bd42cd ] )
+  , Mont::unsafe_make( [ 0x85358f40 , 0x79014e6d , 0x777fa642 , 0x589dbf25 , 0x7d6290e5 , 0x53a5e1d9 , 0x99591913 , 0x0e3d5485 ] )
+  , Mont::unsafe_make( [ 0xedc1955e , 0xff88df8a , 0x23861f26 , 0x41073466 , 0xb691c1a4 , 0xcd1521a5 , 0x55c9c99f , 0x0bb5fe35 ] )
+  , Mont::unsafe_make( [ 0xe3c77b51 , 0x4ddc4848 , 0x569d5b62 , 0xf021a358 , 0x909a963e , 0x8c3172d1 , 0xf6c6e7df , 0x1e86b71f ] )
+  , Mont::unsafe_make( [ 0x6438c321 , 0x02d30d9e , 0x5b1df1e8 , 0xb18caf3d , 0xb267278f , 0x3af33b11 , 0x334d3f33 , 0x11c64df4 ] )
+  , Mont::unsafe_make( [ 0x3968be3e , 0xd69e6be7 , 0x43179f05 , 0x6aa12770 , 0xa2b69ce1 , 0xdf5d0270 , 0x11f6c839 , 0x2ef84bab ] )
+  , Mont::unsafe_make( [ 0x7cdeaedb , 0xc095995e , 0xbaec3fd5 , 0x20fd9bd1 , 0x23fe533c , 0x969766a2 , 0x59942c73 , 0x0f1f1e5f ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x4cdeecf4 , 0x9977c1fb , 0x202d5903 , 0x0659e3df , 0xf53c8d21 , 0x26ea83d3 , 0x2c786476 , 0x17b82707 ] )
+  , Mont::unsafe_make( [ 0x39c89483 , 0x7ccf8b2a , 0xb2508502 , 0xf01ce2e1 , 0x95483046 , 0x3c10e456 , 0xdb8c1574 , 0x2134cd99 ] )
+  , Mont::unsafe_make( [ 0xb7084b76 , 0x131cee85 , 0x046e9d06 , 0x5478cc76 , 0x47b96d20 , 0x8fcb9f5c , 0x5633fe70 , 0x1ec4538a ] )
+  , Mont::unsafe_make( [ 0x0e97392f , 0x7fca345d , 0x75b8a23b , 0x7561292b , 0xc90015f1 , 0xcac1802e , 0x0a20208c , 0x2d2c4ac7 ] )
+  , Mont::unsafe_make( [ 0xfa664c33 , 0x579372d6 , 0x89982a20 , 0x68da6ce3 , 0xe527ca8b , 0x907f9823 , 0x7d1f6c37 , 0x21336a28 ] )
+  , Mont::unsafe_make( [ 0xe7c22d9b , 0xdb096582 , 0x0d29b339 , 0x94dee4f8 , 0x6fe92ec1 , 0x53795554 , 0x1bd985f1 , 0x15d524e9 ] )
+  , Mont::unsafe_make( [ 0x931fde31 , 0xf693b20a , 0x7b2dc822 , 0x36c24bbf , 0x9cf59cfa , 0x15ccc0ec , 0xd380c2ff , 0x2163c387 ] )
+  , Mont::unsafe_make( [ 0xd7964c91 , 0xc848d52a , 0xfe23ae78 , 0x37c52f8f , 0xcec4bb54 , 0x6f5ffdf1 , 0x7a91ce6f , 0x033b5126 ] )
+  , Mont::unsafe_make( [ 0xdb353b4c , 0x84c24272 , 0xa43826f7 , 0xc3f1b54d , 0x8c3aebaf , 0x86631450 , 0xbf01fce2 , 0x2c6296f0 ] )
+  , Mont::unsafe_make( [ 0x8a633ea7 , 0x6455e33d , 0x72c219ec , 0xcc64b54f , 0xaabb6c08 , 0x6ce70e2a , 0x0cd8dc21 , 0x2f3877fd ] )
+  , Mont::unsafe_make( [ 0x50764923 , 0xfd08a704 , 0xa1034402 , 0x1a8f016f , 0x3709375c , 0xf53318ec , 0x93f4ceb4 , 0x029e2c23 ] )
+  , Mont::unsafe_make( [ 0x07b78a90 , 0xb2a44b2a , 0x012a45c8 , 0x6a5a1c5b , 0x0c6104b9 , 0x066926c6 , 0x3636fa1b , 0x3014b3f5 ] )
+  , Mont::unsafe_make( [ 0xbc5a0fd9 , 0x405205e5 , 0x826b241c , 0xfa74a493 , 0x5df56f5c , 0x57ec1cb8 , 0x10a7347e , 0x2520d88e ] )
+  , Mont::unsafe_make( [ 0xb9b1e4f7 , 0x18262b0e , 0xa0d93823 , 0xde60be64 , 0x7ee48491 , 0xe5bfd06b , 0xb26047a5 , 0x170635c7 ] )
+  , Mont::unsafe_make( [ 0xbb8256ac , 0x4085052f , 0x7b85239d , 0x6b857331 , 0x58d4129d , 0x4be9dac9 , 0x21166bca , 0x24bc4701 ] )
+  , Mont::unsafe_make( [ 0xcae3e2f8 , 0x77ee6734 , 0xf6e9281e , 0xad17f09c , 0x871eac89 , 0xfb8dba85 , 0xbd04e537 , 0x2ceb8160 ] )
+  , Mont::unsafe_make( [ 0xc9049567 , 0x11805a94 , 0x66d48c0f , 0x1e10144e , 0x97833ac2 , 0xd013622e , 0x2b627899 , 0x129dd038 ] )
+  , Mont::unsafe_make( [ 0xcbe0d852 , 0x4e6e834b , 0xcf5d6fa2 , 0x6db52a6d , 0x86379a20 , 0xc386470a , 0xdef9ba77 , 0x1b04a37d ] )
+  , Mont::unsafe_make( [ 0xa3fceb9c , 0x04ee7787 , 0x317bb9f9 , 0x37e49239 , 0xce3136df , 0x93c68a7c , 0xa38ecede , 0x15cf8690 ] )
+  , Mont::unsafe_make( [ 0xf0bd19ba , 0xf873cc2c , 0x1492c2d2 , 0x3c758eb0 , 0x00a81112 , 0xf1b189f9 , 0x96f52b92 , 0x1f8da0b4 ] )
+  , Mont::unsafe_make( [ 0x5073fbfc , 0xdebdfad4 , 0x221e2a36 , 0x44d92ae5 , 0x7c881e16 , 0x034e3d92 , 0x74284c4a , 0x2f609d42 ] )
+  , Mont::unsafe_make( [ 0x9d11c1e1 , 0xd5b50d8e , 0x7b4e3c87 , 0xad13b992 , 0x31990a72 , 0x91b3eb06 , 0x3c4e54c2 , 0x12fa411b ] )
+  , Mont::unsafe_make( [ 0xbfeb51b9 , 0xc61843e9 , 0xfad2c362 , 0xdbaabca2 , 0x0222e6a7 , 0xae8ffcc9 , 0x82331dec , 0x17f83942 ] )
+  , Mont::unsafe_make( [ 0xe997daf5 , 0x2d5bfb76 , 0x11ab9ca3 , 0x4ca0ad99 , 0x0958cb8b , 0x67f6ca8a , 0x33cf8220 , 0x0b8c156f ] )
+  , Mont::unsafe_make( [ 0x2eb46a3d , 0x8a7171e1 , 0x9ea3107c , 0xb92c07e3 , 0xe29361b5 , 0x7a9d35b2 , 0x27970546 , 0x2f6334a1 ] )
+  , Mont::unsafe_make( [ 0x465d545d , 0x0457e5dc , 0xb23c7a6b , 0xbd06eced , 0xf59d89b8 , 0x93b01581 , 0x9808aadf , 0x2ec0320d ] )
+  , Mont::unsafe_make( [ 0xc676b3a8 , 0xaa7dfa79 , 0x6b62db45 , 0x7401874f , 0x45311e91 , 0x3b656bc4 , 0xc43a8630 , 0x1aeff6af ] )
+  , Mont::unsafe_make( [ 0x0ef39968 , 0xd46c3035 , 0x888e2026 , 0x1e622766 , 0x9c9c9f92 , 0xd634b390 , 0x2574bc85 , 0x2a7a878e ] )
+  , Mont::unsafe_make( [ 0x723e5f09 , 0x11a136d3 , 0x49c871de , 0x44cdb7e1 , 0xf799656e , 0x3bf89858 , 0x1ee55fd9 , 0x28a17f06 ] )
+  , Mont::unsafe_make( [ 0x0a8ab614 , 0x81be18b9 , 0x64fa5229 , 0x06450ff8 , 0xff732ee7 , 0x2cdf2ac3 , 0x2d11cf52 , 0x09bc7012 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x70ab015d , 0xbfff3cc5 , 0x685d9ba7 , 0x2134f15e , 0xb8613293 , 0x1e13d94d , 0x4e5fad22 , 0x13dd37a2 ] )
+  , Mont::unsafe_make( [ 0xc4ec3198 , 0xf49ea95f , 0xb2bda900 , 0xc170a31a , 0xb73e043c , 0x51272925 , 0xd47b3843 , 0x2c4e888e ] )
+  , Mont::unsafe_make( [ 0x108cd39f , 0x67524f89 , 0x0747a0a6 , 0x3a81a73e , 0xe4ae987f , 0xc2b71cb5 , 0xaf608082 , 0x0fb6cd94 ] )
+  , Mont::unsafe_make( [ 0x66035ce3 , 0x25c82cc9 , 0x252f1f3e , 0x2333fe40 , 0x98fc57c1 , 0x9f527d6b , 0x7ecbc40f , 0x20415f6a ] )
+  , Mont::unsafe_make( [ 0x45863e1a , 0x2348d2e0 , 0x03358882 , 0x57ec29af , 0xec3000c6 , 0x2d97b42e , 0xafff4839 , 0x2cda8af3 ] )
+  , Mont::unsafe_make( [ 0x9bde28ad , 0x75243e81 , 0xaed264f8 , 0xa09af266 , 0xf89c4c99 , 0x86482a08 , 0xe371bf9b , 0x032a84e7 ] )
+  , Mont::unsafe_make( [ 0x9266858e , 0xdcdc5a42 , 0x5167bade , 0x0770fa40 , 0x611cd18a , 0x287284c0 , 0x4fa5e6c1 , 0x267134be ] )
+  , Mont::unsafe_make( [ 0xeb987ccf , 0x56260b5e , 0xeae0837a , 0x5f55b62e , 0x2d16f6ad , 0x8c8a291e , 0x71f6156a , 0x2377c6bb ] )
+  , Mont::unsafe_make( [ 0x8732adcd , 0xdd94676a , 0xb11fe18c , 0x3f161bbd , 0xf41a525a , 0x0d706429 , 0x7f7582c4 , 0x08759e47 ] )
+  , Mont::unsafe_make( [ 0xe59a4c1a , 0x4d7e5e7f , 0xbe72c6f1 , 0x6e20f4fe , 0x29bfaa77 , 0x7396a01f , 0x686764f1 , 0x046c39a1 ] )
+  , Mont::unsafe_make( [ 0x9ec4aab1 , 0xbd3237a2 , 0x97debdf3 , 0x68098722 , 0x5412965a , 0x61469dfa , 0xd1e6490d , 0x2805fc85 ] )
+  , Mont::unsafe_make( [ 0xef6180dc , 0x35091923 , 0x2b21fe6c , 0x4f90c79d , 0xe818a73f , 0x1a7292ec , 0x69eccc0c , 0x298c819a ] )
+  , Mont::unsafe_make( [ 0xb4a02cbf , 0xf18fff84 , 0xe6e8d20e , 0x68e2d367 , 0x03ecdf42 , 0xeeae476b , 0x5d5fc20c , 0x04d85816 ] )
+  , Mont::unsafe_make( [ 0xffd02a1d , 0x3c43f4d2 , 0xcc7a8c23 , 0x71a11ea8 , 0xb4acd1fd , 0x4f11eea6 , 0xdeb3217b , 0x2fe3231c ] )
+  , Mont::unsafe_make( [ 0x99eea47f , 0xedbfaad7 , 0xe8a9d38b , 0x22b876d9 , 0xd50bc74a , 0x0ef28477 , 0x7328a9f5 , 0x1162d6d7 ] )
+  , Mont::unsafe_make( [ 0x486ba552 , 0x55b7f894 , 0x363c1710 , 0x89dfbf42 , 0xabd68cb9 , 0x9afa6996 , 0x38ae9e0c , 0x2cde0011 ] )
+  , Mont::unsafe_make( [ 0x16e70304 , 0x3e0be6a9 , 0x2af5af2c , 0xd97f4fcc , 0x7c3c0e26 , 0x7ff68b89 , 0x295ace60 , 0x23cb7f33 ] )
+  , Mont::unsafe_make( [ 0x5e1560d0 , 0x1154073b , 0x71f87dfe , 0x4ba56752 , 0x944ef808 , 0x545b336a , 0xeb59710e , 0x02ec376c ] )
+  , Mont::unsafe_make( [ 0x3e39ccc5 , 0x7f227146 , 0xc7533ffa , 0x3fdc3784 , 0x24976c69 , 0xee4840b4 , 0x1d1ba3ea , 0x00e569e9 ] )
+  , Mont::unsafe_make( [ 0x35ae3e43 , 0xcdf0be4c , 0xa4a1b326 , 0x23b9af1f , 0x6f321f8e , 0xd39f2ef3 , 0xe649c963 , 0x17c868d5 ] )
+  , Mont::unsafe_make( [ 0x0116b6c9 , 0xd8b05016 , 0x72cb670b , 0x8f466f20 , 0x3de72979 , 0x3e264059 , 0x740438b5 , 0x0f9edafa ] )
+  , Mont::unsafe_make( [ 0x421301bb , 0x9010e73e , 0xa9850e46 , 0xd627f636 , 0xe7b65231 , 0x0f907d76 , 0x27a9865e , 0x10af2070 ] )
+  , Mont::unsafe_make( [ 0x843da4aa , 0x1ba52cf8 , 0xf1c14ba9 , 0x1594b662 , 0x56fde23e , 0xe516481c , 0x73f50f0f , 0x2a49013b ] )
+  , Mont::unsafe_make( [ 0x2deb11fc , 0x7e869d7a , 0x6899ec51 , 0xe76af000 , 0x613d4f7c , 0xf22c0ef9 , 0x3945f03f , 0x28078c6a ] )
+  , Mont::unsafe_make( [ 0x348d03bf , 0x273444da , 0x11108f1d , 0xef1c433b , 0xc04bc301 , 0xca97c21e , 0xf84f0fbf , 0x00ba3422 ] )
+  , Mont::unsafe_make( [ 0x0a0134a0 , 0xbf3422e9 , 0x1bfeeb66 , 0x7e427f0d , 0xa64f6fd8 , 0xb96ed40f , 0x9ecc7710 , 0x22c6cacd ] )
+  , Mont::unsafe_make( [ 0x02b68c12 , 0xcaefc200 , 0x85b297fc , 0x00987a99 , 0xff52e529 , 0xfcfb599f , 0x7910abae , 0x0fc4ed7c ] )
+  , Mont::unsafe_make( [ 0x5aad9208 , 0x3c30ef16 , 0xb5a80f43 , 0x2955bb18 , 0x693f9b76 , 0x8a64425b , 0xde163f94 , 0x161e18d4 ] )
+  , Mont::unsafe_make( [ 0x86bf8621 , 0x9d3ff799 , 0x6d6bb069 , 0x5fc81ba7 , 0x6ef9f82f , 0x7e955cd3 , 0xab73c967 , 0x28d37ca2 ] )
+  , Mont::unsafe_make( [ 0xa09dc451 , 0x7f35305f , 0x24c83bdf , 0x41f903e9 , 0xf5c277aa , 0xfdf5ebef , 0x6d07dddd , 0x111d8943 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xc332c49f , 0x117deb8f , 0x375e38f4 , 0x1c539d4c , 0xb4b26c1f , 0xb0472c87 , 0xda7a51c7 , 0x2ab35644 ] )
+  , Mont::unsafe_make( [ 0xb9477ac4 , 0x610b136f , 0x985d5db5 , 0xfd2bbb83 , 0x91a0dd0e , 0x66d847d2 , 0x142608f2 , 0x0068dccf ] )
+  , Mont::unsafe_make( [ 0x1e1a6e8d , 0x9109dbfb , 0xa7d465e4 , 0x13f842a7 , 0xe5bcd18a , 0x599bbf77 , 0x07b4892b , 0x1da4eae7 ] )
+  , Mont::unsafe_make( [ 0x40a01707 , 0xb428e48b , 0x9d23548f , 0xcaa3d70c , 0xa32dbc3f , 0x5f11b36e , 0x3b0e0726 , 0x02ff7744 ] )
+  , Mont::unsafe_make( [ 0x537454e2 , 0xcbde6b58 , 0x94ab877c , 0x603958fc , 0xa7ef5d56 , 0x413770d9 , 0xa93d9d00 , 0x04d86459 ] )
+  , Mont::unsafe_make( [ 0x863ee456 , 0xca437f30 , 0x5780b80a , 0x5b3f9d88 , 0xd45f48c3 , 0x49ba1bfc , 0x2cd4ff5a , 0x1fa3b48f ] )
+  , Mont::unsafe_make( [ 0x362e6ddd , 0xdf8406fb , 0xbe0d8be6 , 0xbeb076c7 , 0xf698ab33 , 0x82d3b718 , 0x50772eb5 , 0x14b0acc9 ] )
+  , Mont::unsafe_make( [ 0x3e8d5b78 , 0xb5ffe91c , 0xd7a5ce74 , 0xa5e5efbf , 0xd4ea0ed5 , 0x8cf98601 , 0x992525b8 , 0x303a4a63 ] )
+  , Mont::unsafe_make( [ 0x88c37d6f , 0x50ef0ff5 , 0x17c49604 , 0xbdee35ce , 0xaf108ed4 , 0x6f973687 , 0x90ea7835 , 0x13106a5a ] )
+  , Mont::unsafe_make( [ 0x92911ad5 , 0xb971d764 , 0x8360d635 , 0x55c13865 , 0xcc2fbea2 , 0x176dfb42 , 0xf1b3db6b , 0x1ad40677 ] )
+  , Mont::unsafe_make( [ 0x150229ed , 0x94571e56 , 0x06bb33f9 , 0xc085a4e6 , 0x320f074c , 0x729ff8c8 , 0xa02314e7 , 0x04627bbd ] )
+  , Mont::unsafe_make( [ 0x1b3b64f5 , 0xf8676788 , 0x7f9f8274 , 0x6e1459de , 0x8e8cb23f , 0x575ba7a8 , 0x19b61255 , 0x06cc0eec ] )
+  , Mont::unsafe_make( [ 0x854fdcf3 , 0xe59a32bf , 0xdccf33c5 , 0x06f795c6 , 0xd595c2fc , 0x0a07693f , 0x91cb7904 , 0x0ce241cb ] )
+  , Mont::unsafe_make( [ 0x3139fd8f , 0x731f138d , 0xa2291280 , 0xa71ff912 , 0xc72ad118 , 0xf3b196a8 , 0x923505cd , 0x1b82e748 ] )
+  , Mont::unsafe_make( [ 0xa1c56234 , 0x1ec60e2f , 0x5f0f29b3 , 0x1b8402e1 , 0x2abb77b9 , 0x237d070e , 0x62a1c61c , 0x10b02011 ] )
+  , Mont::unsafe_make( [ 0x4f81227c , 0x6b677055 , 0xb56d641e , 0x06a2f400 , 0x80ac1504 , 0xffd7ed83 , 0x367e600a , 0x07469913 ] )
+  , Mont::unsafe_make( [ 0x3f7aa64b , 0xcefd8581 , 0xed66a32c , 0x90fbeeac , 0xcb8ae602 , 0xbe728b05 , 0x77357846 , 0x00dbb478 ] )
+  , Mont::unsafe_make( [ 0x0f65ff3a , 0x4574d1d0 , 0x91b43013 , 0xb1dc7bcd , 0x387964db , 0xed3c215d , 0xfb5cc6e3 , 0x280f79ac ] )
+  , Mont::unsafe_make( [ 0x30d7efce , 0xdcecef0d , 0xf8d66a79 , 0x598fe38f , 0x686c99cb , 0x493ec732 , 0xdd9f5449 , 0x29d77d1e ] )
+  , Mont::unsafe_make( [ 0x6955a828 , 0x849d0c7d , 0xd2d635eb , 0xda7b626c , 0x609e01c5 , 0x1bdb023e , 0xb213ad73 , 0x27a19268 ] )
+  , Mont::unsafe_make( [ 0xd5635971 , 0xa994f8d1 , 0x329f00ce , 0x7aa932e8 , 0x6e7e3b33 , 0x5cb847c9 , 0xab7cef9e , 0x1cbca179 ] )
+  , Mont::unsafe_make( [ 0x8094ef92 , 0xcfba32cd , 0x9ed1502a , 0x653c6020 , 0xd091d0b5 , 0x3373967c , 0x3b36b5d2 , 0x07445fa6 ] )
+  , Mont::unsafe_make( [ 0x6a59af8b , 0x7d009eeb , 0xbbee17e2 , 0x2a85a607 , 0x2dc1b9fc , 0xa59b0566 , 0x809fefd0 , 0x2bb9b6b7 ] )
+  , Mont::unsafe_make( [ 0xb7f35af9 , 0x68bf9997 , 0x10e07724 , 0x251b8367 , 0xccde3934 , 0x919e07dd , 0xc45181a7 , 0x074bc20e ] )
+  , Mont::unsafe_make( [ 0xfa0ebcc1 , 0x02881ed4 , 0x32d81245 , 0x9afaa658 , 0x04c52e13 , 0xdfe4b784 , 0xa6525db0 , 0x2df30cd0 ] )
+  , Mont::unsafe_make( [ 0x492bbc18 , 0xa3a50bf6 , 0x352e3567 , 0x99960dcf , 0x3f7d87dc , 0x29d1a6c4 , 0x491d61f6 , 0x27bf7c1e ] )
+  , Mont::unsafe_make( [ 0x11219ee5 , 0x46dd9358 , 0xc590dabc , 0xba43fce0 , 0xe8c224a8 , 0x234b603b , 0x519491bb , 0x2ad83a66 ] )
+  , Mont::unsafe_make( [ 0x62b3f4e7 , 0x13d90164 , 0x289fff3d , 0xdfa6c47d , 0xc1de389b , 0x6ade01bf , 0x50ce827c , 0x1466560d ] )
+  , Mont::unsafe_make( [ 0xb670c07d , 0x0095546f , 0xa4db510b , 0x417e0a24 , 0x7c26ba7c , 0x5f35390e , 0x71fab62c , 0x16ea1f67 ] )
+  , Mont::unsafe_make( [ 0x19bd5dde , 0xa6634a64 , 0x6905dc6a , 0xd5bc6086 , 0x1c479acd , 0x04d03ad4 , 0xf0f2c754 , 0x06257f1b ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x4c73f973 , 0x19a022ec , 0x2714830f , 0xb715b14e , 0x1e1bc910 , 0xf3820e3a , 0xae517b9b , 0x095728dd ] )
+  , Mont::unsafe_make( [ 0x008ba580 , 0x93062b57 , 0x3d11ce6c , 0x055019dc , 0x2e0fe629 , 0xe1b30b90 , 0x2fa3e1c1 , 0x26c6700c ] )
+  , Mont::unsafe_make( [ 0xb06364c9 , 0x1d025cf2 , 0x2974339b , 0x1d1f3524 , 0x6ee6d0be , 0x0f7666cd , 0x57c3e8eb , 0x069ed6dd ] )
+  , Mont::unsafe_make( [ 0x7a205615 , 0x2808981c , 0x8798660b , 0x937b41ce , 0x00a9228c , 0xab02551e , 0x693df865 , 0x04cddc9c ] )
+  , Mont::unsafe_make( [ 0xeeb3e3a4 , 0x7b52c831 , 0x70f9d445 , 0x8fb17fb2 , 0xd3a089e9 , 0x3de1a428 , 0xc9eb9c65 , 0x27f1d463 ] )
+  , Mont::unsafe_make( [ 0xcc5076b0 , 0x23413838 , 0xba0b8052 , 0x11eda70c , 0x4d0113c5 , 0x2f310746 , 0x8ccd6a3f , 0x24854499 ] )
+  , Mont::unsafe_make( [ 0x8909e3ba , 0x706983ad , 0x6a8e4618 , 0x10b4b328 , 0xf2720359 , 0xf64d6415 , 0x964c6ca9 , 0x1fcc823e ] )
+  , Mont::unsafe_make( [ 0x2442bbd0 , 0x49c53b12 , 0x9c2b727a , 0xc07a06c6 , 0x8b6ffc78 , 0xb8a69e58 , 0x8bed54a2 , 0x0003d74e ] )
+  , Mont::unsafe_make( [ 0x7d90f50f , 0xf78d9fb7 , 0x3323a860 , 0x223cdf21 , 0xcedd1792 , 0xa5e7460b , 0x4267bdc9 , 0x1760febc ] )
+  , Mont::unsafe_make( [ 0xdc911214 , 0xd21a1faf , 0xf4df6d46 , 0x43a99f1b , 0xf154a9a5 , 0xb594a68d , 0xf46fa6e1 , 0x0e17a8d5 ] )
+  , Mont::unsafe_make( [ 0x9450b94a , 0x51eb4713 , 0x97af603f , 0xca39a933 , 0x5a6ae428 , 0xe79ffac5 , 0xd0dc2a5c , 0x075074d4 ] )
+  , Mont::unsafe_make( [ 0xd80bd82b , 0x2a26473b , 0x001fb67a , 0x86720a10 , 0x2fa9d4ea , 0x8da77be1 , 0x972032c4 , 0x0cd752fe ] )
+  , Mont::unsafe_make( [ 0x9e91913a , 0x11a7562f , 0x7289f8a2 , 0x80cd635d , 0x0d55aa1c , 0xe7b8eaf5 , 0xbbbe5522 , 0x2234533f ] )
+  , Mont::unsafe_make( [ 0xd46dc418 , 0xd47f87c0 , 0x184f21b2 , 0x59c82416 , 0x6a334a2f , 0x03ebb159 , 0x55c18f46 , 0x08d56ba7 ] )
+  , Mont::unsafe_make( [ 0x5fb625fd , 0x94fa1b7e , 0xc4d9a238 , 0x2a13d02e , 0x68017f8e , 0xfcd8da6d , 0xb2cec3df , 0x212f735c ] )
+  , Mont::unsafe_make( [ 0x4afe5a96 , 0x6ed09954 , 0x1bc89673 , 0x4c1a7bb2 , 0x2db54524 , 0xdd83cc5a , 0xf78f9600 , 0x08b393ca ] )
+  , Mont::unsafe_make( [ 0xfe4d8ac7 , 0xd3161122 , 0xeb9d58c6 , 0x2448579b , 0xd2c1b3cd , 0x4a55f571 , 0xe70a1eb0 , 0x25d314f0 ] )
+  , Mont::unsafe_make( [ 0x6f343136 , 0x994ade8f , 0x40758a98 , 0x63bebaf5 , 0x9faf1f8e , 0xee416ecb , 0x2c6beba6 , 0x2854824a ] )
+  , Mont::unsafe_make( [ 0xa5994c40 , 0x6830d8d3 , 0xf481cb6e , 0xef49e5b2 , 0x02456b5d , 0xda83b2c5 , 0x1166ec0d , 0x1213b1c6 ] )
+  , Mont::unsafe_make( [ 0xc9548fcd , 0x116e6592 , 0x1f3aabd2 , 0x0fbdd996 , 0xd0413ede , 0x7b598120 , 0xe54cb586 , 0x2a97ff5f ] )
+  , Mont::unsafe_make( [ 0x9a3fe6dd , 0x9ba44d95 , 0xb176b9ab , 0x6555848f , 0xfa4d2d50 , 0xbc9034a0 , 0x3496e918 , 0x1b0e42f2 ] )
+  , Mont::unsafe_make( [ 0x08afb8f1 , 0xbe74b1ac , 0x0cab68bd , 0xa0daac24 , 0xf0437af5 , 0x9b21069e , 0x069d071c , 0x0144aee0 ] )
+  , Mont::unsafe_make( [ 0xab21f3b7 , 0xf2461174 , 0xca341358 , 0xe63de97a , 0xcc05af1f , 0x48c49f14 , 0x7942b729 , 0x0bbb1525 ] )
+  , Mont::unsafe_make( [ 0x07117154 , 0x3cc31d40 , 0x8a0b9c83 , 0xea486599 , 0xff23e46a , 0xb2d93ced , 0x78c68087 , 0x1793a531 ] )
+  , Mont::unsafe_make( [ 0x11c8b563 , 0x294d519b , 0x447b1470 , 0xa3048ba5 , 0xd65ff7c5 , 0x4f4f97e3 , 0x60707a1e , 0x09792afa ] )
+  , Mont::unsafe_make( [ 0x41943e77 , 0x2202b89b , 0x9bfb8772 , 0x6284e437 , 0x504da1ed , 0x2e6cc390 , 0xe57c3f5b , 0x098758c7 ] )
+  , Mont::unsafe_make( [ 0xffd0b785 , 0xbd93a61d , 0x8f737ad7 , 0x700b180a , 0xbcc3fe4a , 0x994a8a99 , 0x458f2a5e , 0x0ff6fd8e ] )
+  , Mont::unsafe_make( [ 0x0f21dadd , 0x581f4112 , 0x53f209cf , 0x49c7964e , 0x68263c87 , 0x06dc635d , 0x9537a2f8 , 0x07b8dde8 ] )
+  , Mont::unsafe_make( [ 0xc9e7bea7 , 0x8d276f85 , 0x6b7453f5 , 0x0a6d2308 , 0xeac5bfee , 0x25cc9d57 , 0xbee8664d , 0x00880210 ] )
+  , Mont::unsafe_make( [ 0x486e7703 , 0x841d9ddf , 0x51f5b3e3 , 0xbe9cfe69 , 0x803ef091 , 0x7ca51fac , 0xe7a510f5 , 0x1e6544b4 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x921358ee , 0x78b8926d , 0x73830b90 , 0x982dc625 , 0xda238b17 , 0x4c9ab2cc , 0x0797c0b7 , 0x0a5addea ] )
+  , Mont::unsafe_make( [ 0xd02ddd6b , 0xb3ac21cf , 0x4ecde988 , 0x8c6925e4 , 0x3e522548 , 0x8d1d2301 , 0x3c20bfd1 , 0x190c73e5 ] )
+  , Mont::unsafe_make( [ 0x4a9056b8 , 0x8e3947d0 , 0x0a1782e1 , 0x39ab32a2 , 0xb4e222e0 , 0xceb0ca91 , 0x6d3e9918 , 0x296302a3 ] )
+  , Mont::unsafe_make( [ 0x2db6d3c4 , 0xf4f77cb7 , 0xd5308f89 , 0x85850884 , 0x1dc4404d , 0x708f6c95 , 0x465c4d8d , 0x015bf1e5 ] )
+  , Mont::unsafe_make( [ 0x8e1596d3 , 0x7414878d , 0xe9ccce16 , 0x59fa583d , 0x46294c99 , 0x544d05db , 0x4cb2e1b2 , 0x1dcdb77b ] )
+  , Mont::unsafe_make( [ 0xddfdbaf4 , 0xce018ce6 , 0x88ac3578 , 0x699f8c02 , 0xf5c9412f , 0x2e5140ad , 0xeea48b70 , 0x09c02eef ] )
+  , Mont::unsafe_make( [ 0x4c2fa5d3 , 0x933e04df , 0x72f275b2 , 0xe7fbdce9 , 0xff5752f8 , 0xffd5b347 , 0xa436a5bb , 0x15c12e6c ] )
+  , Mont::unsafe_make( [ 0x5c566f3b , 0x556107c4 , 0xdddee7ee , 0xe8efda48 , 0xe6ba3826 , 0x3691e175 , 0xe9f18178 , 0x139bd2f4 ] )
+  , Mont::unsafe_make( [ 0x321c1020 , 0x17b9606e , 0x9efaf0c4 , 0x9844c48e , 0xc08cdacf , 0x3d3b4c3a , 0x50312a1a , 0x063cb572 ] )
+  , Mont::unsafe_make( [ 0x0d299b0e , 0xbeb7b5c1 , 0xd278f472 , 0x6d77d938 , 0xbb02fc4d , 0x47a2af9b , 0x19c9df27 , 0x235819a2 ] )
+  , Mont::unsafe_make( [ 0xb63592df , 0x39b950fb , 0x18599dcf , 0x8ef80e45 , 0x9d9438f7 , 0x0f703e32 , 0x3096a8c9 , 0x0ade41a4 ] )
+  , Mont::unsafe_make( [ 0x49871a4a , 0x963ad65a , 0xdfa5b259 , 0x516ef270 , 0x65ac3705 , 0x7a55efda , 0x3d5f545b , 0x273346fc ] )
+  , Mont::unsafe_make( [ 0x5a7019a7 , 0x8acd8e3b , 0x7f6d37a2 , 0xf792094c , 0x32a3a285 , 0x5cd3f837 , 0xedbd1a75 , 0x14b181c7 ] )
+  , Mont::unsafe_make( [ 0x2f101149 , 0xc8df27cb , 0xb91bdab4 , 0x7dd27f4d , 0x5ea4fdc4 , 0x46cfb2d3 , 0x971066ff , 0x0f2fa9a8 ] )
+  , Mont::unsafe_make( [ 0x7ceab5ff , 0xe6a98c83 , 0x2629998a , 0x0befd47b , 0xeb124d35 , 0x8e39eb4b , 0xb90f5af5 , 0x23ed9fcf ] )
+  , Mont::unsafe_make( [ 0x20740fac , 0x21543085 , 0x1af86559 , 0x92cac0d5 , 0xf544b5c4 , 0x5fde9cbf , 0x9a652651 , 0x161ad1bb ] )
+  , Mont::unsafe_make( [ 0xc6c1a92d , 0xe7194b0e , 0xeb8e918b , 0x8513f321 , 0x8e91db75 , 0xe945006c , 0xc9982cbb , 0x07260888 ] )
+  , Mont::unsafe_make( [ 0xfd368a19 , 0x8e79da40 , 0x042cc7f7 , 0x9e7b6f37 , 0x667b5367 , 0xe69eea0e , 0x46f615fe , 0x0f6809da ] )
+  , Mont::unsafe_make( [ 0xa8a679fc , 0xe93df2ae , 0x921cc481 , 0x931dd109 , 0xee2fcdf2 , 0x3dafcbb4 , 0xaeed577c , 0x07309bfa ] )
+  , Mont::unsafe_make( [ 0x632c8dc6 , 0xf2dcbc57 , 0x0d192058 , 0xa26d4f6f , 0x30d2c81f , 0x1bd4a12b , 0x9344b191 , 0x0a535b6d ] )
+  , Mont::unsafe_make( [ 0x446a7201 , 0x2609fe9f , 0x2ce9330c , 0x186fb92f , 0x020fafed , 0x9481ad36 , 0x779e50dc , 0x0d00e3b5 ] )
+  , Mont::unsafe_make( [ 0x1060483d , 0x0c35900f , 0x2f218c01 , 0x4645fa27 , 0xf4a14925 , 0x4b674e62 , 0x36707611 , 0x04f72618 ] )
+  , Mont::unsafe_make( [ 0x33424bc1 , 0xb05e279c , 0xb51b39d1 , 0x1f4933c5 , 0xe6357354 , 0x19e4ca2b , 0xef437bb7 , 0x241e47e3 ] )
+  , Mont::unsafe_make( [ 0x5ebe1418 , 0x5237ecf6 , 0xf61e25bc , 0xc6bc968c , 0x1958a8dc , 0x2e7c8517 , 0x1d93f4da , 0x2f8f1042 ] )
+  , Mont::unsafe_make( [ 0xc96a276b , 0x7f836176 , 0x83e706bf , 0x797fec70 , 0x6797edb4 , 0xd0182d5b , 0xcb62daa9 , 0x2501cf86 ] )
+  , Mont::unsafe_make( [ 0x43d7eb52 , 0xb38f8523 , 0x7872d9d1 , 0x2f0114d2 , 0x3f8f6463 , 0x127a7029 , 0x03b7ed6b , 0x12ed59ee ] )
+  , Mont::unsafe_make( [ 0x06ccce89 , 0x66a64ec9 , 0xa7ae7afa , 0xe04adc5e , 0x70eafb1d , 0xd610fb56 , 0x72cb8208 , 0x0123e3f6 ] )
+  , Mont::unsafe_make( [ 0x21ae8195 , 0x2b4621ff , 0x3b78230d , 0x62d9897f , 0x237f5ed5 , 0x5d37f429 , 0x92871781 , 0x050412b4 ] )
+  , Mont::unsafe_make( [ 0x0e95cb8e , 0xf0f237f1 , 0x9cff91cd , 0xb21a8ca7 , 0x9907f092 , 0xfdba7519 , 0x4e239e0f , 0x14374dad ] )
+  , Mont::unsafe_make( [ 0xfe80e522 , 0xf10634fe , 0xf5d8d9d6 , 0xed9927ce , 0x15131a28 , 0x1fda1629 , 0xa976e254 , 0x0e1bca93 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xe2125f55 , 0xf73c6a2f , 0x6800f11d , 0x16a8d03c , 0xc81e39a6 , 0xf6d2f6c5 , 0x496ea73d , 0x1191896b ] )
+  , Mont::unsafe_make( [ 0xf4f8600c , 0xc1ff944e , 0xf05ab6bb , 0x5134c400 , 0x8c64dbec , 0x1d8ca77b , 0x2d201b58 , 0x1094b905 ] )
+  , Mont::unsafe_make( [ 0xaa674fdc , 0x2fab5ddf , 0x735c36e6 , 0x709da50a , 0xa6049521 , 0xa452e818 , 0x1669bb0a , 0x0387405e ] )
+  , Mont::unsafe_make( [ 0x42ebcb11 , 0x575ef281 , 0x7a45d3b7 , 0xf0f08023 , 0xe7188ffc , 0xb8538109 , 0x7381ae2f , 0x08ed91dc ] )
+  , Mont::unsafe_make( [ 0x94eec108 , 0x6ce3e2a6 , 0xd9b3bb3c , 0x77ab9580 , 0xc73b6895 , 0x6036a674 , 0x6fe174e1 , 0x07d75cfd ] )
+  , Mont::unsafe_make( [ 0xfffbbab2 , 0x996c11f0 , 0xa1dbf90f , 0x8b033a77 , 0x648e0975 , 0x38a0d3b4 , 0x878e272d , 0x1dac68e8 ] )
+  , Mont::unsafe_make( [ 0xa3f9b080 , 0x671be9e6 , 0x1b831918 , 0x1e85c016 , 0xab58e3f1 , 0x4dc0c0e6 , 0x3998d931 , 0x26cb8665 ] )
+  , Mont::unsafe_make( [ 0x415625e0 , 0xb91e1649 , 0x6bffa45d , 0x906fd60b , 0xf1ca1fb8 , 0x0fde6e50 , 0x176f629a , 0x1395b3a9 ] )
+  , Mont::unsafe_make( [ 0xe623bf09 , 0x875841e1 , 0xbfeddc10 , 0xc32024fd , 0x047eeb42 , 0x8fe82582 , 0x21fd4cf9 , 0x1b72d5fa ] )
+  , Mont::unsafe_make( [ 0xeb577cd9 , 0x235602d1 , 0x8f6e2aaa , 0xdd05fdd2 , 0xf7a22331 , 0xd7f91bc3 , 0x55580f6e , 0x0bd7585e ] )
+  , Mont::unsafe_make( [ 0x1b95b31f , 0x70b93a64 , 0x569dad9d , 0xd20a838b , 0xad3326ee , 0x1018f808 , 0x3f010456 , 0x09b39924 ] )
+  , Mont::unsafe_make( [ 0xc6b69f6f , 0x64244c60 , 0x51407fce , 0xd806e9f8 , 0x826a75fd , 0x48d28a5c , 0xc90846aa , 0x1ac4349f ] )
+  , Mont::unsafe_make( [ 0xabc60930 , 0xd99a3a8f , 0xf4000a3f , 0x05e86187 , 0x2ec8a487 , 0x8b8b4fd8 , 0x843b1f4f , 0x130aa852 ] )
+  , Mont::unsafe_make( [ 0x11ad0938 , 0x1fed63ed , 0x02ac817a , 0x3a6534d4 , 0x0fa42374 , 0x348a1a25 , 0x72f36e1a , 0x0d2f09cb ] )
+  , Mont::unsafe_make( [ 0x9be55323 , 0x902d744b , 0x9b675895 , 0x7e6041f7 , 0xd1d5fc08 , 0xb8089a17 , 0x3626288a , 0x29463c87 ] )
+  , Mont::unsafe_make( [ 0x2a6156ca , 0x39274fa6 , 0x651404f4 , 0xb00745b1 , 0x1383883e , 0x4f7364eb , 0x00ef4ed0 , 0x0406a19b ] )
+  , Mont::unsafe_make( [ 0x559776f6 , 0x57e8a710 , 0x4b2925ee , 0x8fb345ac , 0xe4a5d4ca , 0x7c1558f7 , 0x35a0d005 , 0x0988eaf9 ] )
+  , Mont::unsafe_make( [ 0x171a8b83 , 0x4e4bfb03 , 0x25542801 , 0x9a256aa1 , 0x3cef76fb , 0x54f80f7a , 0xb2ecc1f8 , 0x10f2171c ] )
+  , Mont::unsafe_make( [ 0xfe57b2c4 , 0xc8d57aa6 , 0x0a54261f , 0xfe051820 , 0x9d30abab , 0xf55689c2 , 0x3bcc2f39 , 0x0a0807bd ] )
+  , Mont::unsafe_make( [ 0x37191542 , 0xea574d4b , 0xc1176331 , 0xd9a4f01a , 0x16b39062 , 0x1e5b0ef7 , 0x431742f7 , 0x12fedef1 ] )
+  , Mont::unsafe_make( [ 0x7a5c53fe , 0x94574c31 , 0x725bf11a , 0xaae850fe , 0x47561f77 , 0x182fefbb , 0x712f060c , 0x0b602760 ] )
+  , Mont::unsafe_make( [ 0x6b3cf943 , 0x8748da94 , 0x5261018a , 0x9f43029c , 0x4a429d35 , 0xba55bdb5 , 0xe436dd84 , 0x2de9aca0 ] )
+  , Mont::unsafe_make( [ 0x7f2b7375 , 0x3fc60b91 , 0x1f454185 , 0x86992821 , 0x42df6269 , 0xae056bd1 , 0x58a39070 , 0x0b0b2107 ] )
+  , Mont::unsafe_make( [ 0x47433891 , 0xad2bde4e , 0x1871512b , 0x4e3eac58 , 0xdae84008 , 0x1b31b8b0 , 0xe25c341a , 0x2388ea48 ] )
+  , Mont::unsafe_make( [ 0x3c453fa3 , 0x52684e86 , 0x0263f7c3 , 0x532d2065 , 0x43212c0f , 0x5fb9eb81 , 0xffec8628 , 0x1178fee0 ] )
+  , Mont::unsafe_make( [ 0x379e0a95 , 0x40b08650 , 0x3d4da5c8 , 0xd8bc876c , 0x11fd3145 , 0x8c6f1ba6 , 0x228f17f7 , 0x26096feb ] )
+  , Mont::unsafe_make( [ 0x5f71fc86 , 0x47cdb9aa , 0x0826d7e2 , 0x0a696dd7 , 0x7a8843d1 , 0xba175037 , 0x6451657c , 0x1021b188 ] )
+  , Mont::unsafe_make( [ 0xa757abc0 , 0x05125239 , 0x561e291d , 0xc31eaac7 , 0xebff435e , 0x529ff6a0 , 0x57b7cb89 , 0x00ee62b8 ] )
+  , Mont::unsafe_make( [ 0x8ca3cdbb , 0x2b3a7cfd , 0x2d322891 , 0x704037c3 , 0x4d4058b0 , 0x980d4ba8 , 0x3524c308 , 0x24c123b3 ] )
+  , Mont::unsafe_make( [ 0xc7b73234 , 0xe9689533 , 0x9fae9410 , 0x8f7c62eb , 0x29a5864b , 0x6f37ad82 , 0x6a8f5e43 , 0x1f8d663b ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x1c183536 , 0x2c4e813d , 0xbbe2bacc , 0x47063b4a , 0xd1c5f73e , 0x5a36a999 , 0x510be644 , 0x1d87eff0 ] )
+  , Mont::unsafe_make( [ 0x07639b24 , 0x9c607151 , 0x41ab5e5d , 0xae32830d , 0xe6445895 , 0x932820c4 , 0xb6697504 , 0x1ebf79ee ] )
+  , Mont::unsafe_make( [ 0xf975872e , 0x94409f54 , 0x496a90ed , 0xb4789d4d , 0x0e4d63e6 , 0x6c4baf93 , 0x14d8e3bf , 0x08133a98 ] )
+  , Mont::unsafe_make( [ 0xd9081c17 , 0xcdeab869 , 0xca41b76b , 0x7cf9b094 , 0x8473b604 , 0x7446b154 , 0xb9692023 , 0x1e0225e0 ] )
+  , Mont::unsafe_make( [ 0x498cd85e , 0xa7e7ac1c , 0xc072e12e , 0xf9f27e7e , 0xfb9ab97d , 0xb89300eb , 0x117b0dfd , 0x28b5d27f ] )
+  , Mont::unsafe_make( [ 0x76b0bf39 , 0x38ccd19f , 0x74912501 , 0xf36b07e5 , 0x2b6941d7 , 0xd4fff384 , 0x4deec8bd , 0x0fdbf4c3 ] )
+  , Mont::unsafe_make( [ 0xda826fff , 0x500d4a56 , 0x990762d4 , 0xecdcfe94 , 0xf40f0e0d , 0x49d11edf , 0xd72d0583 , 0x07b19ade ] )
+  , Mont::unsafe_make( [ 0x588893fe , 0x6ac37360 , 0xf9f79761 , 0x103ef05d , 0xc60acb1c , 0xe1fa76ab , 0x7804b756 , 0x1310d867 ] )
+  , Mont::unsafe_make( [ 0xd55357cf , 0x7f54f947 , 0x4a03e8e2 , 0xe67ce9a7 , 0xa5dbfa79 , 0x630d16cd , 0x1a9d30f2 , 0x11689451 ] )
+  , Mont::unsafe_make( [ 0x515b1a57 , 0x68eb7c4e , 0x4e3a3294 , 0xf5257720 , 0x53f808e3 , 0xe038bd49 , 0xf5469870 , 0x12aee621 ] )
+  , Mont::unsafe_make( [ 0x7dede8ca , 0x2a1a7f0a , 0x9953e914 , 0x5a7b4855 , 0xdbd69c68 , 0x6e0c8e61 , 0xa2539702 , 0x073363ce ] )
+  , Mont::unsafe_make( [ 0x97d1c640 , 0xe67e035f , 0x493e0024 , 0x876bc4d4 , 0xf2c5f5ec , 0x4d5107c9 , 0xe4f726d4 , 0x04b72739 ] )
+  , Mont::unsafe_make( [ 0x9002dff8 , 0x7deff3a3 , 0x629c1f72 , 0xf6366b48 , 0x12ff3537 , 0xb42a4672 , 0xc8bc54de , 0x1133841d ] )
+  , Mont::unsafe_make( [ 0xf7a1d671 , 0xb12ba887 , 0x172f3bb3 , 0x70a83d51 , 0x2d2f1d21 , 0xc72e9439 , 0xcc57e8dd , 0x20bfc772 ] )
+  , Mont::unsafe_make( [ 0xde9b5f4d , 0x22e94761 , 0x2246f233 , 0x416287ff , 0xef1d4cb7 , 0xf1c13042 , 0xd23ddb93 , 0x14c6bd0f ] )
+  , Mont::unsafe_make( [ 0x961c1a72 , 0xa91b64a6 , 0xdc662419 , 0x66dd2b24 , 0x1a341dfa , 0x2552b831 , 0xddd77de0 , 0x2bcebe23 ] )
+  , Mont::unsafe_make( [ 0xa66ed8a0 , 0x1ffa7fd7 , 0x7fea5e1b , 0x0dd4d6af , 0x46b8a02d , 0x02dc5d90 , 0x805a577c , 0x00328586 ] )
+  , Mont::unsafe_make( [ 0xda241be8 , 0x870bd7d7 , 0xe3baa62c , 0x85b36a4b , 0x7b7c34c5 , 0x0d6ab8ff , 0xa226f8ff , 0x2f58a588 ] )
+  , Mont::unsafe_make( [ 0x72f7a05e , 0x413b59fd , 0x97d5052e , 0x66618784 , 0x332e1bd8 , 0xf4ba496c , 0x94814472 , 0x106200d5 ] )
+  , Mont::unsafe_make( [ 0x612572bc , 0xc3bd7c18 , 0x491c011a , 0xa09cf44e , 0xc621b0aa , 0x7f79d73d , 0xe06128f8 , 0x126b1951 ] )
+  , Mont::unsafe_make( [ 0x2f1669e9 , 0xd671cc18 , 0xad37222d , 0xc53bf916 , 0xd58c24a3 , 0xf9ea8f0e , 0xc447b8e1 , 0x03f926c9 ] )
+  , Mont::unsafe_make( [ 0x272b77be , 0xb192c9d4 , 0x46fcbc7e , 0x2665f3e0 , 0x17692bee , 0x124c44ff , 0xcd42675a , 0x05fb6880 ] )
+  , Mont::unsafe_make( [ 0xa789a5ae , 0x5eed0189 , 0xf66aeb95 , 0x28c506c7 , 0x7da37969 , 0x4fde0940 , 0x4b00ec5c , 0x07e279d2 ] )
+  , Mont::unsafe_make( [ 0x08254675 , 0x57524d07 , 0xaae6272d , 0x4c896a20 , 0x93890a5f , 0xf8534658 , 0x9efb73cd , 0x2989016a ] )
+  , Mont::unsafe_make( [ 0x66232271 , 0x9fa252e1 , 0x4d517abf , 0x5a2593fe , 0x71682016 , 0x92844b43 , 0x21f4e5b5 , 0x1301a1a5 ] )
+  , Mont::unsafe_make( [ 0x75fc5812 , 0x725c752a , 0xc79603cb , 0x78f6c795 , 0xf414c8b6 , 0x6afe27b4 , 0x8bcb71a0 , 0x1d9e6ba2 ] )
+  , Mont::unsafe_make( [ 0x1fae56c2 , 0xb472f78a , 0xe8079429 , 0x04685896 , 0x556404a2 , 0x1b8c10d4 , 0x8486da53 , 0x06f47f1e ] )
+  , Mont::unsafe_make( [ 0x0f14c37b , 0x7c5e1787 , 0x7f200a95 , 0xc932974d , 0x14da2109 , 0x3f2310a5 , 0xa65a3477 , 0x10f7c288 ] )
+  , Mont::unsafe_make( [ 0xf5c8c462 , 0x522ab2c1 , 0x926500d3 , 0xd8382421 , 0xbe749b20 , 0xc5224bab , 0xd10dfb4e , 0x26f233f0 ] )
+  , Mont::unsafe_make( [ 0xdf54b205 , 0x70a26372 , 0x39f9ba0a , 0xe80b48e1 , 0x7dff1b1a , 0x952b678c , 0xe1e4f861 , 0x1fd36d20 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xbf7cf8e4 , 0xbc367579 , 0xe8c15122 , 0x5ad42263 , 0x68ace1e2 , 0x320f9772 , 0xb52c0f6a , 0x0099f85e ] )
+  , Mont::unsafe_make( [ 0x55e5599a , 0x9b316e0e , 0x9b2e5fd2 , 0x17f235e3 , 0xf6c330b1 , 0x1a912fbe , 0xb7af393e , 0x06edfc12 ] )
+  , Mont::unsafe_make( [ 0x82ba814b , 0x7f9e33e6 , 0x701be2fa , 0xfaf2d46b , 0x80144b76 , 0x19c8d216 , 0xd1880247 , 0x1a0d5bc9 ] )
+  , Mont::unsafe_make( [ 0x307eb038 , 0x3279ba0b , 0x7e568069 , 0x636e9949 , 0xfe1e0781 , 0x6c948e1a , 0x8b1be9f6 , 0x12076987 ] )
+  , Mont::unsafe_make( [ 0x77b6fc54 , 0xeec623a6 , 0xe1bcac79 , 0xb1103c40 , 0xee6356c3 , 0x479f0abd , 0x5845ce7b , 0x17141f61 ] )
+  , Mont::unsafe_make( [ 0x008be9f6 , 0x143970bf , 0x7637acad , 0xa2563ad1 , 0xefc5af9c , 0xc102eb81 , 0xbf9814e0 , 0x11de07cd ] )
+  , Mont::unsafe_make( [ 0x2cb5f89f , 0x060ca0a4 , 0x41c952f1 , 0x6bd7b6ab , 0x7b70d0b9 , 0xea18fb7c , 0xdfbec654 , 0x1cfce2f5 ] )
+  , Mont::unsafe_make( [ 0xa8a151b8 , 0x425d2e49 , 0x04639eaf , 0xb520376a , 0xfc790394 , 0xce30903b , 0xde9deb91 , 0x0871e3c7 ] )
+  , Mont::unsafe_make( [ 0xc1d7c2f7 , 0x492a15db , 0x9a515695 , 0x3ec272a1 , 0x1739eee6 , 0xa8d22b96 , 0x139020ec , 0x0a67710e ] )
+  , Mont::unsafe_make( [ 0xe4530e77 , 0x3086d4da , 0xb12b482c , 0x6f6f1238 , 0x7297f596 , 0xe814d6b1 , 0xc310da92 , 0x017eb0e9 ] )
+  , Mont::unsafe_make( [ 0x9574754f , 0xf0c6137b , 0xc54eb022 , 0x220d6554 , 0x3ff7dcd4 , 0xd368cea5 , 0x149e9202 , 0x07c251bd ] )
+  , Mont::unsafe_make( [ 0x803e0cac , 0x64a5bd8c , 0xe151a639 , 0xbb2f7d8a , 0x53c2abd1 , 0xe31cfab5 , 0xb05fd992 , 0x15b99b83 ] )
+  , Mont::unsafe_make( [ 0xc95b3256 , 0x9baeb8b4 , 0x03549039 , 0x5012d744 , 0x356257b0 , 0x905f0274 , 0xfdb7cf65 , 0x054d1945 ] )
+  , Mont::unsafe_make( [ 0x43dee47e , 0xc095207e , 0x916ac697 , 0xdf29bee8 , 0x50dc1d8a , 0xed589603 , 0xdb327f43 , 0x03cba4b2 ] )
+  , Mont::unsafe_make( [ 0xda2cffff , 0xc9e7c221 , 0x7856d7b7 , 0x25870021 , 0x20ae088b , 0x596baf85 , 0x995bf2b9 , 0x2274e23b ] )
+  , Mont::unsafe_make( [ 0x2be0a93e , 0x331ce3da , 0x23f66f2b , 0x252a72b1 , 0xfd878a8c , 0xc9aadee7 , 0x45df75e5 , 0x2cc8ade0 ] )
+  , Mont::unsafe_make( [ 0x64d6b1b3 , 0x2f58b68d , 0x1881672a , 0x9d7a5277 , 0x310f9ba8 , 0x976f8a67 , 0x4a0d7070 , 0x0208d993 ] )
+  , Mont::unsafe_make( [ 0xb2a8b0bc , 0xe5ba387a , 0x652f651e , 0x9b6981ef , 0x74967798 , 0x5fd08582 , 0x0a2bf112 , 0x0ce5f88f ] )
+  , Mont::unsafe_make( [ 0x979ffb56 , 0x48cef15d , 0x876afa6f , 0xc7090617 , 0xaa208530 , 0x1ace3f5f , 0xfd221fef , 0x2af32896 ] )
+  , Mont::unsafe_make( [ 0x3816a687 , 0x9fdee812 , 0xf2b7b4b7 , 0xe01f3987 , 0x11ef2b08 , 0x8973bebc , 0x18c562f4 , 0x0c373419 ] )
+  , Mont::unsafe_make( [ 0x91022690 , 0x57a1f143 , 0xbdcc2d21 , 0x85776157 , 0x0803f282 , 0x86b4ca30 , 0x1bb5a3a9 , 0x29a54ab6 ] )
+  , Mont::unsafe_make( [ 0x21a2df0b , 0xad656d89 , 0x69933a49 , 0xad6c343b , 0xa249babc , 0x50ca1ea0 , 0x24c1bb4f , 0x2074e893 ] )
+  , Mont::unsafe_make( [ 0x7b9dff45 , 0xd93f96c5 , 0x9d198524 , 0x384c924f , 0xdd26994b , 0x8630358c , 0x8b12ad69 , 0x08371e12 ] )
+  , Mont::unsafe_make( [ 0xd38a4657 , 0xf3b0eac4 , 0x7b33039f , 0xf57e3ce0 , 0xa0279e99 , 0x8f4e7533 , 0xfc6d2369 , 0x00a82196 ] )
+  , Mont::unsafe_make( [ 0x62f5c0be , 0xf5c44d32 , 0x2ac88ecb , 0x1b17a87d , 0xc50f60c9 , 0x61a452d8 , 0x2aaf995a , 0x2aed1f39 ] )
+  , Mont::unsafe_make( [ 0xa94daf65 , 0x7bc3663f , 0xfca4cc92 , 0xe549aa7c , 0x74b0a9e5 , 0x7b8831d4 , 0x2c18f218 , 0x27ab14af ] )
+  , Mont::unsafe_make( [ 0xdfe69499 , 0xaf552222 , 0x4edb8625 , 0x2b4e05bc , 0x341db72e , 0xdd5c8260 , 0xdae13f23 , 0x021f95b1 ] )
+  , Mont::unsafe_make( [ 0x668d075e , 0x4d780387 , 0xd78fa491 , 0xdf49c24d , 0x46e09171 , 0xb63692f2 , 0xdeee8010 , 0x209754c5 ] )
+  , Mont::unsafe_make( [ 0x316863cb , 0xa45d2b42 , 0xf95aaccb , 0xd39035d4 , 0xb34f4a03 , 0xbcb033dc , 0xfd1c8e7c , 0x08b7b82c ] )
+  , Mont::unsafe_make( [ 0xdfcf7e54 , 0xcb7152b2 , 0x8201e47b , 0xfa227fa0 , 0x1c463d0b , 0xc575ba93 , 0x8ddf70c6 , 0x2815bfc0 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x6647abac , 0x0d2ee2b3 , 0x7ec7454b , 0x38a9fccd , 0x8f748c66 , 0x5ba37956 , 0x200d015c , 0x0771f44c ] )
+  , Mont::unsafe_make( [ 0xb9afdfc1 , 0xc9eff4d6 , 0x5491d0fe , 0x8acbcb29 , 0x0889bfc3 , 0xe485bdcc , 0xc3616c6a , 0x16a9cf72 ] )
+  , Mont::unsafe_make( [ 0x5b2cabb8 , 0x56f2a426 , 0x63b7d873 , 0x39ac65d0 , 0xf144ddfa , 0x401e4ca3 , 0x68f5daae , 0x286a2ce7 ] )
+  , Mont::unsafe_make( [ 0x6ac1b1b6 , 0x7413060b , 0x55de8a42 , 0x52e02f47 , 0x7791a035 , 0x2de09228 , 0xe4475bde , 0x1ba71a63 ] )
+  , Mont::unsafe_make( [ 0x3cff47d2 , 0x191646ed , 0xa40dec60 , 0x23b8101a , 0x296fe322 , 0xb9820878 , 0xcee83607 , 0x2bfe43fe ] )
+  , Mont::unsafe_make( [ 0x7943b122 , 0x18a2c0b6 , 0xfba80fe3 , 0xd844d46c , 0x5eb5aeb5 , 0x78c47b4d , 0x8fe9afc1 , 0x2649ee26 ] )
+  , Mont::unsafe_make( [ 0xec755f37 , 0xcc723c78 , 0x365c7470 , 0x0922b5da , 0x1cfa2889 , 0x67a93a9a , 0x11f6902c , 0x091b6bd2 ] )
+  , Mont::unsafe_make( [ 0xa3f606d0 , 0x5dc1ff60 , 0xff4550d1 , 0xfbe1acba , 0x1f0bf6c6 , 0xe69cdd53 , 0x196cc76e , 0x2273578e ] )
+  , Mont::unsafe_make( [ 0xf763a38d , 0x34c561cb , 0xd2f7cce5 , 0xaf91af34 , 0x8d5ca6fa , 0x02d97b99 , 0x49cae4bd , 0x2a7a7d01 ] )
+  , Mont::unsafe_make( [ 0x7be4b238 , 0x7c2494b6 , 0x950c06d7 , 0x492fa915 , 0xae4f2e38 , 0x95add321 , 0x9609ec96 , 0x0bbec937 ] )
+  , Mont::unsafe_make( [ 0x7b525b76 , 0x5dda3c87 , 0xd5e133cb , 0x9c4d752a , 0x2334bf2c , 0x4d8799ab , 0x9abf380c , 0x248b96c1 ] )
+  , Mont::unsafe_make( [ 0x7d99bda4 , 0xd4481db3 , 0x59a5886d , 0x5ca8230b , 0x66334d14 , 0x8814dbb3 , 0x6fee23c5 , 0x03ae9017 ] )
+  , Mont::unsafe_make( [ 0x48bd42f8 , 0xef3c2b53 , 0xd4fa4767 , 0xcc771e94 , 0xa7702562 , 0x718ac3ec , 0x02460a2f , 0x1b58d1d1 ] )
+  , Mont::unsafe_make( [ 0x6c0df90b , 0xe7d7215a , 0xd05a4253 , 0x34ad1bc0 , 0xff684e55 , 0x75645028 , 0x1cfa0a4d , 0x1774cb99 ] )
+  , Mont::unsafe_make( [ 0xa7bc92aa , 0x0df4695f , 0x9daacc38 , 0x30c86402 , 0xbaa7f9b6 , 0xa486faf2 , 0x62f92f39 , 0x1c3a1b62 ] )
+  , Mont::unsafe_make( [ 0xe865c011 , 0x5335973d , 0x2c4f451c , 0xa57e914c , 0x18bae442 , 0xfd48d4bf , 0xf18fe2c2 , 0x1a85441a ] )
+  , Mont::unsafe_make( [ 0xf02dc99a , 0x2455d9f4 , 0xfa40ab4b , 0xf711f132 , 0x77d0bdd8 , 0x09ddf1c3 , 0xb6cfddb2 , 0x26d79eee ] )
+  , Mont::unsafe_make( [ 0xd7ceefe7 , 0x988b278a , 0x48f7ff2c , 0xa2fa917d , 0x51999db3 , 0x72b0f115 , 0xfc4530f2 , 0x0b5f901e ] )
+  , Mont::unsafe_make( [ 0x463cf1e5 , 0x14a155fc , 0xbb123268 , 0xb0e75e68 , 0xd8cad6b9 , 0x7e8e208e , 0xffa1a862 , 0x2a5f7e0a ] )
+  , Mont::unsafe_make( [ 0x03d35fd8 , 0x59a21303 , 0x648df9b6 , 0xbd672d46 , 0xdf43ca04 , 0x5090a02f , 0xf0b8bd6c , 0x11660b3d ] )
+  , Mont::unsafe_make( [ 0x250c7a83 , 0xb7501983 , 0xe7aef3dd , 0xd00db045 , 0x0eb248b8 , 0xa390f36b , 0xd4a9c1b0 , 0x16655221 ] )
+  , Mont::unsafe_make( [ 0x6e27601b , 0x24989f82 , 0x678288f0 , 0x3397ec8a , 0xc29135d5 , 0x8186d9d8 , 0x6987b169 , 0x032e40ac ] )
+  , Mont::unsafe_make( [ 0xe0493391 , 0xf24fcea1 , 0x086052e4 , 0x346b2f78 , 0x11ae9b35 , 0xe554fd89 , 0x18a0dcb2 , 0x29db27f7 ] )
+  , Mont::unsafe_make( [ 0x75a33281 , 0x4f65c436 , 0x470fd2b6 , 0x3fb0890a , 0x805a0d9e , 0x5ee4ad3a , 0xd5657744 , 0x13849a40 ] )
+  , Mont::unsafe_make( [ 0x8910f153 , 0x2f5f0c2d , 0x033d2bc8 , 0xcbf357b1 , 0x171ddf68 , 0xfdace3ee , 0x12398038 , 0x246e2541 ] )
+  , Mont::unsafe_make( [ 0x6fc198e7 , 0xcd2b50f5 , 0x8431abbb , 0x4101b750 , 0xe22d574a , 0x25d193c9 , 0x83aedb02 , 0x141537b3 ] )
+  , Mont::unsafe_make( [ 0x96404ae6 , 0xb78309e1 , 0xcfb9f691 , 0xf51b1ec2 , 0x9e6b804f , 0xb1cc33fd , 0xb883a5b4 , 0x1513d89d ] )
+  , Mont::unsafe_make( [ 0xf4f7b74e , 0x1b98b81b , 0x98565179 , 0xa7b8e787 , 0x8877c8f5 , 0xe69469f7 , 0xcb26f9c4 , 0x0b7fe89e ] )
+  , Mont::unsafe_make( [ 0x6f3d4bc8 , 0x24c66e82 , 0x0b0c5c14 , 0x62b3545a , 0x2650369e , 0x0d2d08d5 , 0x1932f5bc , 0x24879eca ] )
+  , Mont::unsafe_make( [ 0x478181b1 , 0x4f09d357 , 0xe08d3a4f , 0x809482f0 , 0xa4ce68b9 , 0x69200ecb , 0x37b44cce , 0x157a28d6 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xc4002e12 , 0xdc105d61 , 0xcbe14fd7 , 0x435248cb , 0xab7e447c , 0xcb1d0bda , 0x734d8203 , 0x06cf19c8 ] )
+  , Mont::unsafe_make( [ 0x05f9a276 , 0xe87634b5 , 0x464a62a9 , 0x45ed9c11 , 0x43dca0bf , 0xf25e18d6 , 0xc4117491 , 0x1c000227 ] )
+  , Mont::unsafe_make( [ 0xeab393b6 , 0x9a9fe0bc , 0x584c560c , 0xb2409e2a , 0xf8c8fa7f , 0x64683cc6 , 0x357506a4 , 0x1a04bff0 ] )
+  , Mont::unsafe_make( [ 0xba3cd3bc , 0x8aab6781 , 0x57528fab , 0x212d899a , 0x7f82df00 , 0x330e8f40 , 0x2f0b2f76 , 0x185291f3 ] )
+  , Mont::unsafe_make( [ 0x6dfba17e , 0xae530e56 , 0xb2521944 , 0x582c7d22 , 0x2782c24a , 0x35b51737 , 0xbb05a464 , 0x2d0b1b0e ] )
+  , Mont::unsafe_make( [ 0xde0861e6 , 0xf3edb356 , 0x72f8cbb6 , 0xa7b490a5 , 0x61d5d89a , 0xfbd9033f , 0xedaff577 , 0x19a501f5 ] )
+  , Mont::unsafe_make( [ 0xdac8b1ee , 0x1221389e , 0x544fbe71 , 0x151d27fc , 0xbe340ac1 , 0xb1ba6769 , 0xf39e1be4 , 0x0f96b91f ] )
+  , Mont::unsafe_make( [ 0xfa97827e , 0xdfa88c60 , 0xddd85f0c , 0x41c87b73 , 0xa4c81a5d , 0xf5c4cc21 , 0x543a7ce9 , 0x20ac7a06 ] )
+  , Mont::unsafe_make( [ 0xdf449e1a , 0x236ad3e0 , 0x8ee09c8f , 0xb99a1700 , 0x6c9e1908 , 0xcfb70f78 , 0x2e550a06 , 0x2bf5d7ed ] )
+  , Mont::unsafe_make( [ 0x57de4d47 , 0x45a9ff3b , 0xfcaec3aa , 0x251a45dc , 0xdd903d8e , 0xf9ba0dd2 , 0xcc0988fd , 0x1edd730b ] )
+  , Mont::unsafe_make( [ 0xe447a5cf , 0x749d47c5 , 0x837b1203 , 0x3ccce731 , 0x5b2d8c40 , 0x7fb87ea9 , 0x2ed718e7 , 0x01a1fc47 ] )
+  , Mont::unsafe_make( [ 0x0ff4f510 , 0xf12011fa , 0x41e4f61c , 0xdc38a5e0 , 0xe227a3d9 , 0x92d9b9f7 , 0x8eebbe8c , 0x1d1f5e18 ] )
+  , Mont::unsafe_make( [ 0xed13eb9a , 0x6fcb9800 , 0x6f729efc , 0xab638156 , 0xcc153d07 , 0xc9782a6b , 0xc765fbcb , 0x00d4dfbc ] )
+  , Mont::unsafe_make( [ 0xa26304c0 , 0x17815b44 , 0x807421ca , 0xed7a0c4c , 0x087d83f4 , 0xf359dbcd , 0xb6d9d1c1 , 0x19836430 ] )
+  , Mont::unsafe_make( [ 0x007ac09d , 0x1319e193 , 0xa34e0302 , 0xf7e2f485 , 0x080a146f , 0x87bb1680 , 0xff906146 , 0x1054a5e2 ] )
+  , Mont::unsafe_make( [ 0xbe2c8a30 , 0x92736289 , 0x693c2046 , 0xdeb027d5 , 0xae490ae3 , 0x32fbc9f1 , 0x477df40f , 0x1bf0d732 ] )
+  , Mont::unsafe_make( [ 0x1d1f0976 , 0x42f431ca , 0x2b941660 , 0x871d2370 , 0x7700e6fb , 0x3c4e870d , 0xee90b0a5 , 0x1bc1901a ] )
+  , Mont::unsafe_make( [ 0x555d709d , 0x291639fb , 0x2dbd1b9a , 0x59531fa2 , 0xac65adaf , 0x6585784c , 0x02d6a552 , 0x08cf45e8 ] )
+  , Mont::unsafe_make( [ 0xbb2b8867 , 0xf58790ed , 0x4ec39674 , 0xa486b82a , 0x25c716bc , 0xa441b352 , 0xb51585ef , 0x02bb57af ] )
+  , Mont::unsafe_make( [ 0x4cf8f6f8 , 0x0359c840 , 0xbdc0b44d , 0x1a98beca , 0x6189c586 , 0x5778d68c , 0xc9736cd8 , 0x2b3782aa ] )
+  , Mont::unsafe_make( [ 0xe297f36d , 0xe69db8f8 , 0xd8de28ee , 0xf4886a5d , 0xae938712 , 0x2502b574 , 0x05ca49e5 , 0x0b381904 ] )
+  , Mont::unsafe_make( [ 0x5db37f43 , 0xa3bbcaf9 , 0x8ae7f72d , 0x24c40c35 , 0xa53faf75 , 0x0dc39443 , 0xad5f646f , 0x2179572d ] )
+  , Mont::unsafe_make( [ 0xc651d198 , 0x5632290e , 0x43134d3b , 0xd7b78cfd , 0x4c73d2cc , 0xedb96558 , 0x74af356a , 0x22914bb9 ] )
+  , Mont::unsafe_make( [ 0x4e09dbf9 , 0xa5769892 , 0x5c8d6ebf , 0xe4fb890f , 0x4fd60487 , 0x7d92d26a , 0x2bbb2d31 , 0x20468cb9 ] )
+  , Mont::unsafe_make( [ 0x8f38aca3 , 0xebeba7b5 , 0x7a7f91e5 , 0x4f9b5a25 , 0xa860a389 , 0x4f6d5621 , 0x9daf16c0 , 0x12dd8f10 ] )
+  , Mont::unsafe_make( [ 0xd369143f , 0x3be48051 , 0x8ef51df9 , 0xd70111ce , 0x2e2e20c6 , 0x2aa4e22c , 0x597531b1 , 0x2d9efb55 ] )
+  , Mont::unsafe_make( [ 0x0d6db972 , 0xd1284474 , 0xf0d2c7f8 , 0xb1f25fc7 , 0x8a710a9f , 0xb66de946 , 0x17ec97ed , 0x2898c105 ] )
+  , Mont::unsafe_make( [ 0x24937f33 , 0x97fa6456 , 0xcde805ce , 0x39a426dd , 0xca01e9a8 , 0x05364d80 , 0x93d2d41b , 0x0bd14b7b ] )
+  , Mont::unsafe_make( [ 0x17c6534a , 0xc9d1ac79 , 0x0a6b1c2c , 0xd9236958 , 0xac69ceac , 0x4a14914a , 0xedef4bb8 , 0x190be330 ] )
+  , Mont::unsafe_make( [ 0xf758d039 , 0x1453f822 , 0x19470fc0 , 0x2abd2819 , 0xf988f4b8 , 0x97454867 , 0x646d473e , 0x0bcd1f0c ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x799006bd , 0x384de401 , 0x4068baf8 , 0xe0366238 , 0xf0218f85 , 0xa41470bf , 0x053a1c7d , 0x2c3331c6 ] )
+  , Mont::unsafe_make( [ 0x2ebace87 , 0x558ea354 , 0xe86bb812 , 0x2d127e9c , 0x3f396cc5 , 0x08f9edfb , 0xc07e46de , 0x0b8954f3 ] )
+  , Mont::unsafe_make( [ 0x3c5cabc9 , 0xace23df2 , 0x13f0ae50 , 0x85b3f330 , 0xc5b6cd79 , 0xf9569f29 , 0xcebf2d1a , 0x2ecb0b99 ] )
+  , Mont::unsafe_make( [ 0xb67f626a , 0x8a5c6120 , 0xd0081b53 , 0x0b169c8b , 0x8f894051 , 0x50774c78 , 0x9fd05e89 , 0x1001f4bc ] )
+  , Mont::unsafe_make( [ 0x78631a8f , 0xfc367bdd , 0x1dc70244 , 0x5a68df3f , 0x3fdb7066 , 0x02b727ba , 0x0825c4b3 , 0x2167f0c3 ] )
+  , Mont::unsafe_make( [ 0x44825851 , 0x45addbbe , 0x9a40cdd7 , 0xf8eb329b , 0x03e5df9f , 0x4ce84514 , 0x6b214d4e , 0x1b59ff50 ] )
+  , Mont::unsafe_make( [ 0xa11be905 , 0xcd4a8d34 , 0x2385966e , 0xc01ca4f9 , 0x0e2fc4c3 , 0x7822692f , 0xcbceeaa1 , 0x1bea9db1 ] )
+  , Mont::unsafe_make( [ 0x6c67a85c , 0x12a302ec , 0x6659c96f , 0x035fd4a0 , 0xa516de06 , 0x2723f48e , 0x0da6dc9b , 0x2cd27ee4 ] )
+  , Mont::unsafe_make( [ 0xa3a101ef , 0x09de48d7 , 0x651b1480 , 0xedd5ef48 , 0x95e5a513 , 0xbdad5966 , 0x7f56293a , 0x044dea33 ] )
+  , Mont::unsafe_make( [ 0x62092585 , 0x151fc895 , 0xa3513b16 , 0x3f3b3722 , 0x60507537 , 0xf46747de , 0x9590e33a , 0x2aa0a164 ] )
+  , Mont::unsafe_make( [ 0x16e7d922 , 0xdb977026 , 0x6a6178e0 , 0x00471f9f , 0x5fb053d2 , 0x03fab402 , 0x4efde000 , 0x1babab6c ] )
+  , Mont::unsafe_make( [ 0x9c73e3a1 , 0x3dc65222 , 0xac3e1381 , 0xeac2d07e , 0xc9cca411 , 0xa45f37f5 , 0xd1027add , 0x189605e6 ] )
+  , Mont::unsafe_make( [ 0x59dfa586 , 0xa4eada7a , 0xb82c4ba8 , 0x42d183bf , 0x39cbd3a9 , 0x0ce9fd50 , 0x4b7f20ce , 0x089ce9b5 ] )
+  , Mont::unsafe_make( [ 0xefa4c2e3 , 0x9337cfc5 , 0x21f61ac0 , 0xeaaa80c4 , 0xf643a519 , 0x52e2b6ff , 0x5b3a860e , 0x03011445 ] )
+  , Mont::unsafe_make( [ 0x0b7dc7ea , 0x9e2acf7a , 0x7f015eaf , 0x6da39a21 , 0x8339694c , 0x0637a474 , 0x7aa071be , 0x0a18e140 ] )
+  , Mont::unsafe_make( [ 0x4aa738f0 , 0xd4db2104 , 0xa9f33a42 , 0x93594b79 , 0x82581eb8 , 0xffe4225d , 0x8998042a , 0x1dc7d1f0 ] )
+  , Mont::unsafe_make( [ 0xbd59f51c , 0x2937203f , 0xd5e98923 , 0xd24863c4 , 0x2fc7eefa , 0x2be92c73 , 0x2f7a125d , 0x04c886a2 ] )
+  , Mont::unsafe_make( [ 0xbe376f02 , 0x63c27f43 , 0xcf4da66a , 0xc64139df , 0x8b67e100 , 0x0143a367 , 0x5f4ba8e6 , 0x0bdbe123 ] )
+  , Mont::unsafe_make( [ 0x6a6e92cc , 0xca83bda8 , 0x75a8be44 , 0xb26dc5db , 0xa6c9d683 , 0xdf68bae3 , 0x9fb38064 , 0x1f981781 ] )
+  , Mont::unsafe_make( [ 0xd5c674bb , 0x137f0714 , 0x2218c3af , 0xd949dcdd , 0xf83ad5be , 0xc04e56ae , 0x6097719e , 0x2c4caddb ] )
+  , Mont::unsafe_make( [ 0x71f05b58 , 0x0eb702a3 , 0x83f57ad3 , 0x1d366d8f , 0x0febd6d2 , 0xdc39fd99 , 0x61205d9d , 0x04972fab ] )
+  , Mont::unsafe_make( [ 0x298f3e37 , 0x18b6e7a7 , 0xb16fe9ac , 0xfcc17023 , 0x209844b4 , 0x1c5b2fa0 , 0xfaefc667 , 0x2a8456a9 ] )
+  , Mont::unsafe_make( [ 0x592bc71f , 0x7a326855 , 0x96cbe9ce , 0x19343bd9 , 0x88bc370b , 0xec749217 , 0x7e921af9 , 0x0f49503f ] )
+  , Mont::unsafe_make( [ 0xa25c0a6c , 0x2d77e612 , 0xa06dc223 , 0x94cbdd17 , 0x9de060ae , 0x1ea99be1 , 0x526beaa9 , 0x1d52c80d ] )
+  , Mont::unsafe_make( [ 0x9af1cd5f , 0x549ed7e5 , 0x0a72cb5f , 0x60063d43 , 0xa80e918a , 0x2c007b67 , 0xee5200ca , 0x1d49e214 ] )
+  , Mont::unsafe_make( [ 0x43497a97 , 0x7248f83c , 0x29258cfb , 0xa3280c4c , 0x719640a3 , 0x870ddce7 , 0x382da3bd , 0x200a977e ] )
+  , Mont::unsafe_make( [ 0xe38b31a8 , 0x9881a9c0 , 0x7915e3f7 , 0x1f990547 , 0xa43a6cd3 , 0x637e2650 , 0xdb06f0eb , 0x275b60ca ] )
+  , Mont::unsafe_make( [ 0x03f8f900 , 0x0ff268b8 , 0x55fd6a19 , 0x285e2915 , 0x99abf92c , 0xeddaa774 , 0xbbe6c00a , 0x1fbbf55f ] )
+  , Mont::unsafe_make( [ 0xdf001661 , 0x56b7ca18 , 0xac5f1a71 , 0xbcb78635 , 0x47b0ee62 , 0xd1f46c1b , 0x2dcc74f3 , 0x1fad0f70 ] )
+  , Mont::unsafe_make( [ 0xa71ad81c , 0xb7d2e83b , 0x66d468cb , 0xc51baee3 , 0xc974710d , 0xfa688ad4 , 0x3f89a344 , 0x173efa13 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x227ac984 , 0x924248d0 , 0x3776a01f , 0xf84feb37 , 0xc6db7088 , 0x3d98908e , 0x2bd09483 , 0x07875862 ] )
+  , Mont::unsafe_make( [ 0x9a8f2661 , 0x0ed671f1 , 0x33356b5a , 0x9a607427 , 0x749dc3e4 , 0x1fae4c50 , 0xf65c41e8 , 0x11917b4e ] )
+  , Mont::unsafe_make( [ 0xc5b07756 , 0xcb22862f , 0xe01cb293 , 0x5a39d332 , 0x33bd1b2f , 0xb85c68ee , 0x39d9c2b7 , 0x016b8704 ] )
+  , Mont::unsafe_make( [ 0x0a1f1574 , 0xe23c2922 , 0xe9e9673c , 0x5c3037ea , 0x11ded1f5 , 0x7e855321 , 0xa7d61daa , 0x036ef84c ] )
+  , Mont::unsafe_make( [ 0x64952197 , 0x672b1801 , 0x5336965d , 0x626fe8ac , 0x45240d7f , 0x337ea833 , 0x7ce03b8b , 0x24bc924e ] )
+  , Mont::unsafe_make( [ 0x64491d32 , 0xfa89e741 , 0x522cb073 , 0x99af825e , 0x3c308ee7 , 0xf4adae18 , 0x53e9c95b , 0x0d9ace81 ] )
+  , Mont::unsafe_make( [ 0x3535b463 , 0x4901f8b1 , 0x1ea84ba9 , 0x85a67813 , 0x7fde4730 , 0xb8dd5fcd , 0xc74cb06e , 0x0bde033e ] )
+  , Mont::unsafe_make( [ 0xd5ff5cd8 , 0xcbd5cda7 , 0xb350b66c , 0xfaa7cca3 , 0x0b4d16af , 0xa11bfaa8 , 0x353d19cc , 0x1529cdd7 ] )
+  , Mont::unsafe_make( [ 0x4beaa650 , 0x3d5ac0f5 , 0x7d1faf31 , 0xb76659c2 , 0x9ec68787 , 0xf356e306 , 0xa9d37904 , 0x248c9c7e ] )
+  , Mont::unsafe_make( [ 0x10eaf96b , 0x9a3add02 , 0x836bed59 , 0x539a7ca8 , 0xe7b65137 , 0x9fa6369d , 0x4815c22a , 0x2742d29b ] )
+  , Mont::unsafe_make( [ 0xec6fab73 , 0xbd095e43 , 0x6625ad75 , 0xc7d94ea5 , 0x0772aa71 , 0x99877a15 , 0x80529604 , 0x1350cec9 ] )
+  , Mont::unsafe_make( [ 0x014ed2ee , 0x3c30a655 , 0x7f114229 , 0x4933078f , 0x3d771c25 , 0x17776625 , 0x3fe5408c , 0x1ba81d5e ] )
+  , Mont::unsafe_make( [ 0xa0e9153d , 0xbf753bbf , 0xe15b66e5 , 0xdee75fd0 , 0x53805e3e , 0x76b46c77 , 0xb75a45f7 , 0x077f2c31 ] )
+  , Mont::unsafe_make( [ 0xcc6a05e0 , 0x44b831de , 0xcbce5992 , 0x0058f6de , 0xe34538c5 , 0x1b829f70 , 0x859dcd7c , 0x07fc160f ] )
+  , Mont::unsafe_make( [ 0x7466b2c2 , 0x2f0e79ec , 0xf8ba8625 , 0x2dab1152 , 0xe7581bad , 0x9162722e , 0x94d01b60 , 0x1065d282 ] )
+  , Mont::unsafe_make( [ 0x48c68fd1 , 0x6ce313c5 , 0xb40f8fbe , 0xfc09859b , 0xbf8ec28f , 0xd543cac1 , 0xa2bd9c91 , 0x178e0271 ] )
+  , Mont::unsafe_make( [ 0x61fe36fa , 0xda2d52b1 , 0x50845ba4 , 0x0138429c , 0xae8bfdf8 , 0xf51bc7bc , 0x846cb3ca , 0x2abffe58 ] )
+  , Mont::unsafe_make( [ 0x522a56c8 , 0xef9198da , 0xa45a7416 , 0xd9c4167f , 0x077e779b , 0xfea3b041 , 0x2cf3933c , 0x143a515b ] )
+  , Mont::unsafe_make( [ 0x9239128f , 0x4345b331 , 0x5c38daf2 , 0x0b426f58 , 0xf4d882ce , 0x1a45c9b8 , 0xdf282eb6 , 0x01a421e5 ] )
+  , Mont::unsafe_make( [ 0x17d78120 , 0x0752b252 , 0x45d5f2bb , 0xdbfe16c6 , 0x84a3188a , 0x448c2406 , 0x5c3b5320 , 0x11193ce0 ] )
+  , Mont::unsafe_make( [ 0x7015956b , 0x59a46836 , 0xd0008fc3 , 0x5ea26ae1 , 0x50ab48b9 , 0xcb76393e , 0xe4647358 , 0x2850b1b2 ] )
+  , Mont::unsafe_make( [ 0x12b49247 , 0xbb688795 , 0x33928dc2 , 0xf47a44a3 , 0xcace1974 , 0x48709ef7 , 0x5106fe23 , 0x2dacfe67 ] )
+  , Mont::unsafe_make( [ 0x4586ec18 , 0x77ece486 , 0x1a0619b8 , 0xdde1dec9 , 0x1b9105f3 , 0x03237bbf , 0x1c405a77 , 0x2da0dad1 ] )
+  , Mont::unsafe_make( [ 0x6b74e0a4 , 0xcc1c84ae , 0x08313197 , 0xd2edb662 , 0x1de5bab5 , 0x413f6fd0 , 0x9d04f971 , 0x0de9da4e ] )
+  , Mont::unsafe_make( [ 0xce68ef82 , 0xe8e2ca39 , 0xbc5444a1 , 0x1deaec70 , 0xc6f96f89 , 0x13c1340a , 0x3d70df3f , 0x210bfed3 ] )
+  , Mont::unsafe_make( [ 0xd4c0c273 , 0xbb0528f0 , 0xf406d706 , 0x8c752c58 , 0x7543e4b3 , 0x4496076b , 0x3effd36a , 0x0d2e9cc1 ] )
+  , Mont::unsafe_make( [ 0xe2959448 , 0x175f5f98 , 0x84d4ff78 , 0xbaf9465c , 0x750940db , 0x37653d28 , 0xbdb481a7 , 0x003e001f ] )
+  , Mont::unsafe_make( [ 0x51d97015 , 0xfac1d27d , 0x52ce8c68 , 0x9d8ec9e8 , 0x7a82b9a9 , 0xbd4b4198 , 0x04d9aeb3 , 0x2e5da23c ] )
+  , Mont::unsafe_make( [ 0xc9ea5029 , 0x4ca403a4 , 0x96fd2011 , 0xaf816524 , 0xc0f3d42f , 0x0977154e , 0xa983798b , 0x281a2952 ] )
+  , Mont::unsafe_make( [ 0x40d39a8b , 0x94cdc646 , 0xeaedafcb , 0xac0190c8 , 0x6846a7c4 , 0x961cbc44 , 0x88ddea71 , 0x0e3f64b8 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xfb70fedf , 0x096022ce , 0xfd9b2e85 , 0xcb85bf38 , 0x271ffb6d , 0xa35ca4b4 , 0x570576db , 0x2bb2376a ] )
+  , Mont::unsafe_make( [ 0xb6484358 , 0x15506754 , 0x32ffac7f , 0xf315054d , 0xf0e5a930 , 0x476cc66e , 0xa189c214 , 0x2befeaff ] )
+  , Mont::unsafe_make( [ 0xb80815ec , 0x80c4fb7d , 0x334bb665 , 0x88e00ade , 0xd49303c5 , 0x568a61a2 , 0xc550158e , 0x05b571db ] )
+  , Mont::unsafe_make( [ 0x5089d247 , 0x4fbd1975 , 0x34aac05c , 0x6c5ffc13 , 0xff7f2a11 , 0x3799460b , 0x95c38de5 , 0x1a0d58ff ] )
+  , Mont::unsafe_make( [ 0x9ca27446 , 0x04b2899e , 0x4987aedf , 0xcd4124b0 , 0xf064b834 , 0x21ddc48a , 0xaae1731c , 0x27c1557d ] )
+  , Mont::unsafe_make( [ 0x58637c19 , 0x9ba70dfa , 0x457751c1 , 0x4131eef2 , 0x42228ea2 , 0xcf16c936 , 0x6ebe0445 , 0x298d2e57 ] )
+  , Mont::unsafe_make( [ 0x1863da83 , 0xcdb10576 , 0x7348381d , 0x40af4399 , 0x487be496 , 0x6d825209 , 0x0f6f548d , 0x21734e81 ] )
+  , Mont::unsafe_make( [ 0x90460497 , 0x34d8f6ad , 0x83914f72 , 0x5fd73bc8 , 0xaf179685 , 0x90aa6a16 , 0x5d745f74 , 0x0582da9e ] )
+  , Mont::unsafe_make( [ 0x9d20c582 , 0x7a790407 , 0xe9e32437 , 0x17405197 , 0xadf37147 , 0x5087e42c , 0x03e3c35a , 0x211bcfe1 ] )
+  , Mont::unsafe_make( [ 0x977bbbe0 , 0xd22231c0 , 0x024e3bcd , 0xd8bb4283 , 0x2a6c82ca , 0x11638b47 , 0x9f8f4ac6 , 0x1cf55deb ] )
+  , Mont::unsafe_make( [ 0x0e98c91e , 0xbab3bad0 , 0x4e5d0648 , 0x53ab24bf , 0x63f252b8 , 0x821f5703 , 0x348371a7 , 0x099831e5 ] )
+  , Mont::unsafe_make( [ 0x0dfdae60 , 0x02c72f7c , 0x19fdb430 , 0x830afb94 , 0x95937694 , 0xafef84e8 , 0xbd5eea47 , 0x11467bdd ] )
+  , Mont::unsafe_make( [ 0x47445a58 , 0xfb367a1e , 0x36cef935 , 0xd7f872a0 , 0x945eb9b6 , 0x46b742f2 , 0x8e52b194 , 0x1a43ac4e ] )
+  , Mont::unsafe_make( [ 0xfa45b92f , 0x19ced21d , 0x72a4899a , 0x296a24f9 , 0x5cc29482 , 0xb12149ee , 0xf6656179 , 0x29176cd3 ] )
+  , Mont::unsafe_make( [ 0xbf82bec7 , 0x3e879213 , 0xfa0c4bb6 , 0x571f9e46 , 0xe3c810ac , 0x99daa3e2 , 0xa917a829 , 0x050d6f5e ] )
+  , Mont::unsafe_make( [ 0x8d09d6b1 , 0x0cd91f8e , 0x492f44fd , 0x660a4ea4 , 0x61bf50c5 , 0xa7323bea , 0x529a18a6 , 0x0bbf6893 ] )
+  , Mont::unsafe_make( [ 0x4178733e , 0x1e49bc31 , 0x5cb4e622 , 0xe5a6dfec , 0x7e756338 , 0x3c811710 , 0x346499dd , 0x13c310fb ] )
+  , Mont::unsafe_make( [ 0xdc0ac6f3 , 0xe3322d9b , 0xdfb36d69 , 0x602ad072 , 0x1f34ba2b , 0xa26e8409 , 0x447cbae2 , 0x0f7f0838 ] )
+  , Mont::unsafe_make( [ 0xe6f4f27e , 0x562eb1c7 , 0xcc9b7716 , 0x505dd095 , 0x314e9c28 , 0x26458c7b , 0x6b71563c , 0x11a32547 ] )
+  , Mont::unsafe_make( [ 0x50b71c01 , 0xa6755fcb , 0x456b46e6 , 0x0610ba30 , 0x3762c715 , 0x33d29652 , 0x8c5dfcc8 , 0x078e8f94 ] )
+  , Mont::unsafe_make( [ 0x2a19843e , 0x4bf34790 , 0x69d85f67 , 0xae1659a6 , 0x13d0f744 , 0xb38e8b28 , 0x06e00c11 , 0x0483d0b5 ] )
+  , Mont::unsafe_make( [ 0xf9e06b08 , 0x3a108752 , 0x3e76a1d0 , 0xd87222af , 0x9f091713 , 0x5bb87117 , 0xb792c2c4 , 0x1a0f7210 ] )
+  , Mont::unsafe_make( [ 0xb1a541f7 , 0x9345b95f , 0x21558eb4 , 0xa8d8612b , 0xe152420b , 0x1c68a88b , 0x2ee9fa50 , 0x220ad96e ] )
+  , Mont::unsafe_make( [ 0xeb88946a , 0x2ce96da4 , 0x3a851187 , 0xbfdf3865 , 0xc3e1232c , 0x81789c50 , 0x3bdb5e71 , 0x040360f8 ] )
+  , Mont::unsafe_make( [ 0x774a33e6 , 0xd86a4849 , 0xed7d99a6 , 0xdeddfd52 , 0x779b8d8d , 0x58e5f54f , 0xd75eac91 , 0x21077dbb ] )
+  , Mont::unsafe_make( [ 0x0e09871f , 0xcc89bbc3 , 0xa42e82b5 , 0x42f0de89 , 0xb4824025 , 0x4666e6ca , 0x0eb8613a , 0x136a840b ] )
+  , Mont::unsafe_make( [ 0xe6b9938c , 0x91539694 , 0xdeb40887 , 0x981a286a , 0x6e6c47ac , 0xe46bf4be , 0xe65f8b96 , 0x0f050f35 ] )
+  , Mont::unsafe_make( [ 0x525f1ff2 , 0x2f023324 , 0x1c94543a , 0x2a036fec , 0xe963780d , 0x68b1ef99 , 0x329870dd , 0x171fc1ee ] )
+  , Mont::unsafe_make( [ 0xa3244960 , 0x276646c3 , 0xa91a83ae , 0xa1022d83 , 0xebce1dee , 0x432b47e0 , 0x08213e54 , 0x1ae0e7be ] )
+  , Mont::unsafe_make( [ 0xa05b0fc2 , 0x99be799b , 0x64b24842 , 0x7df10239 , 0xb255c128 , 0x2991da24 , 0x77f51429 , 0x1dabf7ea ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xc5b481c5 , 0x2126bc6b , 0x128e20ab , 0x0e328bc4 , 0x030e15e1 , 0x5283b4b2 , 0x0fbe5546 , 0x0eebccf3 ] )
+  , Mont::unsafe_make( [ 0xd3efa32d , 0x6f9a5afe , 0x19eb8581 , 0x3b2243d3 , 0x7a4128c1 , 0x0a13f337 , 0x6464bec1 , 0x19e71900 ] )
+  , Mont::unsafe_make( [ 0x16787872 , 0xb4ef1ceb , 0x1b7695fa , 0x5fbe599d , 0x0b2846b1 , 0x6130291a , 0xd0eede03 , 0x0d657661 ] )
+  , Mont::unsafe_make( [ 0x8957f938 , 0xcdba3418 , 0xe684f6fe , 0x2ab02b73 , 0xf7432769 , 0xf1a0888c , 0x406457d2 , 0x2dcfcd2a ] )
+  , Mont::unsafe_make( [ 0x642486f0 , 0xc636e350 , 0x6af3e357 , 0xd62a18b9 , 0x27862a01 , 0x13c87da8 , 0xfc0b13de , 0x278afdce ] )
+  , Mont::unsafe_make( [ 0x99a94b92 , 0xa0757049 , 0x70f42ac0 , 0x09e8e784 , 0x0d904b32 , 0x72e5b596 , 0x388643bc , 0x1135a548 ] )
+  , Mont::unsafe_make( [ 0xa4c8fbb0 , 0x6ed8f667 , 0x36f94227 , 0xdcbce637 , 0xeae1e42b , 0x351b190e , 0x968ab54d , 0x079547ef ] )
+  , Mont::unsafe_make( [ 0x081fc7e2 , 0x561a3f92 , 0x615f2888 , 0xe32d107e , 0x5e2b8575 , 0x98672218 , 0xd72a75c1 , 0x051ad82c ] )
+  , Mont::unsafe_make( [ 0x1a38f491 , 0x90397b2a , 0xc4c2f893 , 0xe7fd505d , 0x22b1ae5b , 0xcfbfe3ec , 0xc7b3462f , 0x2524fe13 ] )
+  , Mont::unsafe_make( [ 0x80135aad , 0xe3a9ed3a , 0xb0d60346 , 0x75cd3a64 , 0xeb7d4b08 , 0x3ddaf608 , 0x6a4a479e , 0x20f51121 ] )
+  , Mont::unsafe_make( [ 0xf192891a , 0x7e7036ec , 0x13c019cc , 0x4e60540d , 0x29990942 , 0x86fdccf6 , 0xb48406ba , 0x216aa482 ] )
+  , Mont::unsafe_make( [ 0xb9d9c745 , 0xdbce2c4e , 0xec5b311f , 0x59d76ffe , 0x173d20dc , 0x39fc8a8f , 0x30d460b3 , 0x01ba7b70 ] )
+  , Mont::unsafe_make( [ 0xb729e2f5 , 0x528f3f8e , 0x70a2ecdf , 0xf44d7a4a , 0x8ee547a3 , 0x0d5996e7 , 0xcc39dc3f , 0x1f712c08 ] )
+  , Mont::unsafe_make( [ 0x6b91c1bd , 0xc70d2e19 , 0xd9e0a584 , 0x9fbd291c , 0x5ac8a3f5 , 0xdebd06fa , 0xa9fb80ee , 0x1e3657da ] )
+  , Mont::unsafe_make( [ 0x2b53c354 , 0x48a1ce0e , 0x1896ac19 , 0x9171dc96 , 0x1152c1b6 , 0x5fecba56 , 0x804cbf70 , 0x25a4ebaa ] )
+  , Mont::unsafe_make( [ 0x48fd0f5c , 0xb3f20a7a , 0x84995839 , 0x3bd6c8ef , 0x79c33fc1 , 0x81f9b2f7 , 0x352de80e , 0x0a2bbcc9 ] )
+  , Mont::unsafe_make( [ 0x9bbacb59 , 0xba1ed672 , 0x6cac11a7 , 0x4f7b0d01 , 0x1628c00f , 0x8dc351d5 , 0x7292e39b , 0x29771528 ] )
+  , Mont::unsafe_make( [ 0xdce68f77 , 0xcdab3c01 , 0x75e1bebc , 0xa8cc8619 , 0xa6792f34 , 0x45537366 , 0x4c56ee45 , 0x232ae3a5 ] )
+  , Mont::unsafe_make( [ 0x5bf08da7 , 0x927e1de2 , 0x411e1412 , 0x5defeb6a , 0x7a9e337d , 0xeb0839b9 , 0xc32ec3dd , 0x1a36f0a8 ] )
+  , Mont::unsafe_make( [ 0xafe1c34a , 0xddd27c77 , 0x16b91199 , 0x410cd461 , 0x3c8e0803 , 0xc4f0f52b , 0xaa971cab , 0x18aa641a ] )
+  , Mont::unsafe_make( [ 0xc72c267b , 0x5d53b478 , 0xf70c1150 , 0x1108f739 , 0x2c76fe33 , 0xb364d8ff , 0x857ccf72 , 0x0d11f7fc ] )
+  , Mont::unsafe_make( [ 0x89ef2447 , 0x25761b26 , 0x2186d64e , 0x0fef1031 , 0x0f7ce3dc , 0xb98b0e40 , 0xfe1296d4 , 0x17285bbc ] )
+  , Mont::unsafe_make( [ 0x6d9eaca1 , 0xe4d8e65b , 0xa4313ca8 , 0x046cd86c , 0x3ff29f48 , 0xf29b5c54 , 0x7ea66f56 , 0x101ef2e5 ] )
+  , Mont::unsafe_make( [ 0xe0882752 , 0x1afb456a , 0x16322f89 , 0x44307428 , 0x92a3e23a , 0xb7d495d8 , 0xf5035a6d , 0x2561df36 ] )
+  , Mont::unsafe_make( [ 0xe68dd2dc , 0x9d6745fb , 0x84770a41 , 0x873e2a83 , 0x5be50b3e , 0xc2f88486 , 0xd7d08af0 , 0x27301dfa ] )
+  , Mont::unsafe_make( [ 0xfe9136fa , 0xb51e330c , 0x59ad9864 , 0xe094b4e5 , 0x493433b5 , 0xedc3d8c9 , 0xa1ee33a4 , 0x10af2b51 ] )
+  , Mont::unsafe_make( [ 0xb045fbda , 0xca9132c8 , 0x68235818 , 0xd8bb16d6 , 0x95c11706 , 0x6f9ccaf2 , 0xbe2444bf , 0x0e22d704 ] )
+  , Mont::unsafe_make( [ 0x152f2d16 , 0xebf9f24e , 0xef47c368 , 0xe6929864 , 0x84715bc6 , 0x999799fc , 0xdb1d348e , 0x204664e1 ] )
+  , Mont::unsafe_make( [ 0xd74ce218 , 0xa20a9438 , 0x57c47e6d , 0x5851c105 , 0x8b979ae3 , 0x505b49e4 , 0x2dfd24b6 , 0x0e762d5a ] )
+  , Mont::unsafe_make( [ 0x1c78bedb , 0xbd88c259 , 0xbc3f6b5b , 0x68168747 , 0x9805b3ec , 0xd8efdb18 , 0x816d2d2c , 0x2f389b47 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x3dbb1723 , 0xf99128dd , 0x4d4caf44 , 0x765fab36 , 0x96c8d876 , 0x53e31422 , 0x3c832c27 , 0x129b84c0 ] )
+  , Mont::unsafe_make( [ 0xa9cc46a9 , 0x677d6df3 , 0xae5a08ca , 0x1150e4aa , 0x78f3afb0 , 0x66a3da0a , 0xea9a20dc , 0x18dbd544 ] )
+  , Mont::unsafe_make( [ 0x8a7f94d0 , 0x7df063c6 , 0xae5536ec , 0x3d9e6acc , 0x5cb9e3f0 , 0xa2e95fa2 , 0x75d66529 , 0x09d7a2cb ] )
+  , Mont::unsafe_make( [ 0x4933d3af , 0x7ef3f7f9 , 0x08cef1d7 , 0x2393bc41 , 0x4efd4d55 , 0xe8e7c0e7 , 0x897721d9 , 0x06bdb6e2 ] )
+  , Mont::unsafe_make( [ 0x988d65d8 , 0x6a99fcec , 0xf755bd9e , 0xfd26d1f5 , 0x0277266e , 0x3666b606 , 0xf5f26997 , 0x09960a1b ] )
+  , Mont::unsafe_make( [ 0x0669a3fb , 0xfce595b1 , 0xa8e2751d , 0xbaec144d , 0x2e94165d , 0x12cde9ba , 0xfaf51778 , 0x27685a32 ] )
+  , Mont::unsafe_make( [ 0xc9de8306 , 0xda5bb08a , 0x5f42ffc6 , 0xb1506a56 , 0x52fd0012 , 0x3d0c17f6 , 0xa0c81a8c , 0x136cb8a2 ] )
+  , Mont::unsafe_make( [ 0x488588c8 , 0x3aa7a82f , 0x28298354 , 0x1695fad4 , 0x52414d43 , 0x0e73210f , 0x999d08a2 , 0x08707cae ] )
+  , Mont::unsafe_make( [ 0x3483f610 , 0xd6a96f73 , 0x2f14fb03 , 0x6d4301e8 , 0xbeece637 , 0x5d36899a , 0xae48a70b , 0x00cf1c4e ] )
+  , Mont::unsafe_make( [ 0xc6cdef47 , 0x6209a893 , 0xd903c33e , 0x9f660572 , 0x58d33063 , 0x6282bc11 , 0x2d8c8a1e , 0x2c6fcd80 ] )
+  , Mont::unsafe_make( [ 0xb999149c , 0x6cc1c0b6 , 0x7d7d74c9 , 0xf1ddd8b8 , 0x8e077144 , 0xf2a0f8a2 , 0xcc3dad97 , 0x15e06028 ] )
+  , Mont::unsafe_make( [ 0xc5355bd1 , 0x473f455c , 0x1f793835 , 0xc2f8f57b , 0x59e08292 , 0x12d88cf3 , 0x9c35aaca , 0x1810b3a1 ] )
+  , Mont::unsafe_make( [ 0x5fdeea14 , 0xb78220dd , 0x6fc3fbdd , 0xaf9b95b0 , 0xaa605586 , 0xa43934c8 , 0x8c7cd55b , 0x033609ae ] )
+  , Mont::unsafe_make( [ 0x5939f850 , 0xc0ec97c9 , 0xf5a80067 , 0x1bd33d77 , 0x831365eb , 0xe4dead14 , 0xe74ba74b , 0x0769fca2 ] )
+  , Mont::unsafe_make( [ 0xf947e0df , 0xe9c6f374 , 0xe31b73ee , 0x49d77d7a , 0x632ce80f , 0x502cf3ea , 0xa2f8bb08 , 0x255a4984 ] )
+  , Mont::unsafe_make( [ 0x8817f400 , 0xbeb87beb , 0xc845a08e , 0xeaa9310f , 0xc27acc33 , 0x96510bb7 , 0x1fde26ed , 0x21dd7690 ] )
+  , Mont::unsafe_make( [ 0x5a7ab4e4 , 0xdf3e61ff , 0x260e0e94 , 0x1cd7ce09 , 0x44f41b21 , 0x595e07be , 0x5d35da35 , 0x26347caf ] )
+  , Mont::unsafe_make( [ 0x3a201e8b , 0xa2167243 , 0x0aae0225 , 0x581d88a5 , 0x07a7439c , 0x5ed06dbe , 0x7df63111 , 0x2ae17a13 ] )
+  , Mont::unsafe_make( [ 0xb56ce373 , 0x7b71aff2 , 0xe253c0a8 , 0x5a4e98a9 , 0x95bbba11 , 0x9015601f , 0xc94a093f , 0x0ff69f74 ] )
+  , Mont::unsafe_make( [ 0x0a6fd8aa , 0xb86f0e1e , 0x0e95a6f5 , 0x0843bb3f , 0x54a2cfc7 , 0x0c09cddc , 0x95c4d37a , 0x1ab8ed22 ] )
+  , Mont::unsafe_make( [ 0x308e278b , 0xc18f7dcb , 0xf39ed52a , 0xfed883af , 0x6afde25d , 0x2b055cb0 , 0x0a4ecdd2 , 0x264c08d4 ] )
+  , Mont::unsafe_make( [ 0xfbe8eef4 , 0x1b4be960 , 0x58aec9ee , 0x3df35cb9 , 0x8447b985 , 0x3fcd42de , 0x17c4ca32 , 0x14ab6bed ] )
+  , Mont::unsafe_make( [ 0xf38e35ea , 0xc6c58f80 , 0xef5452a9 , 0xba79d5b1 , 0x384fc837 , 0x598ed944 , 0xa8f45e92 , 0x02f620aa ] )
+  , Mont::unsafe_make( [ 0x7fd960fc , 0xa2bd46a7 , 0x1e1f7d60 , 0x36224b63 , 0xc22b10ee , 0x06487db8 , 0xcd1ed2c3 , 0x099dfad5 ] )
+  , Mont::unsafe_make( [ 0xb90dcf8b , 0x14c579d6 , 0x24b2a183 , 0x9aed20b4 , 0x53b85b03 , 0xebd45fc5 , 0xcbc05eae , 0x09c863b7 ] )
+  , Mont::unsafe_make( [ 0xfd3d1dc8 , 0xea97d1ec , 0x473ccf1c , 0xa1eb3bf2 , 0x50e62db8 , 0x0241140b , 0x3d60b2d9 , 0x00e6f0e5 ] )
+  , Mont::unsafe_make( [ 0xd665413e , 0x44eac35e , 0xfcb3ca43 , 0xcfded91b , 0x68053ad6 , 0x8749ee8f , 0xe8765866 , 0x140001ac ] )
+  , Mont::unsafe_make( [ 0xe5c96bb8 , 0x81e779ec , 0xbd16546e , 0x0ef1a804 , 0x5cb5d6a4 , 0x72ac7d24 , 0x5c6effc2 , 0x1b025805 ] )
+  , Mont::unsafe_make( [ 0x2fdffdae , 0x8f34067a , 0xa7c36f41 , 0xad03cefe , 0xe4df2d7a , 0xb99f967f , 0x7755f04e , 0x0f9163f3 ] )
+  , Mont::unsafe_make( [ 0x7f027e81 , 0xa4a2ab96 , 0x855c86d3 , 0xa1a80c8e , 0xc861cf4c , 0x03a82ef6 , 0x3547c714 , 0x0f8022a9 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x877ce1f2 , 0x42b04970 , 0x6d9f7e40 , 0xaa440b9c , 0x549547c0 , 0x9fc8fe76 , 0xe1f8099d , 0x2f19c436 ] )
+  , Mont::unsafe_make( [ 0x23eb09cd , 0xb16e5db2 , 0xaf655d11 , 0x2a777289 , 0x7803ff22 , 0x94db769c , 0x625f0336 , 0x0520e369 ] )
+  , Mont::unsafe_make( [ 0x43394914 , 0xc04a2ad1 , 0x849b8955 , 0x07e92fde , 0x0f0a0acb , 0xa0267c5f , 0xd5a2230e , 0x0a667771 ] )
+  , Mont::unsafe_make( [ 0xcc8ae2c6 , 0xa0d338c9 , 0xf2eb4581 , 0x02d80941 , 0x6bb5a14d , 0x518313f3 , 0x2f272406 , 0x0762f0db ] )
+  , Mont::unsafe_make( [ 0x8ee61a29 , 0x8f23a85c , 0x9b0d27ce , 0xbdf0be2a , 0xbe2c0f41 , 0x1d393876 , 0x69d735f4 , 0x159416e4 ] )
+  , Mont::unsafe_make( [ 0x5408c121 , 0x39fe54b6 , 0xf7ea5bac , 0x59cec909 , 0x98877cc3 , 0xe429fd28 , 0x5b547617 , 0x0b4a190f ] )
+  , Mont::unsafe_make( [ 0xa4e31587 , 0x7e8fd4d0 , 0x548b7e7b , 0x9d4c3018 , 0xa6b8c415 , 0x4c070a75 , 0x2048b37c , 0x278d9476 ] )
+  , Mont::unsafe_make( [ 0xac7bc810 , 0xb02c236e , 0x520b96bb , 0x36c8e493 , 0x393ada04 , 0xf2297723 , 0x3021c91e , 0x10ccc7fc ] )
+  , Mont::unsafe_make( [ 0x541f06a2 , 0x140432fa , 0x23135f10 , 0xe00c999b , 0x29e65a53 , 0x757167af , 0xe5339497 , 0x160be65c ] )
+  , Mont::unsafe_make( [ 0x29265681 , 0x70ac17de , 0xa7e15ba3 , 0x6057d9a3 , 0x26a9503a , 0x1272f3cc , 0x930428bd , 0x1067840d ] )
+  , Mont::unsafe_make( [ 0x0bbc0e81 , 0x5b019fd4 , 0x14a1bfa8 , 0xc6984190 , 0x0bfc5652 , 0x57aced35 , 0x285898a5 , 0x0183d27f ] )
+  , Mont::unsafe_make( [ 0x5d05b94d , 0xf934fe5d , 0x14143683 , 0x6e1fb88f , 0x426b6464 , 0x829a8744 , 0x29ae6c4f , 0x0438bee6 ] )
+  , Mont::unsafe_make( [ 0x68b3c6be , 0xee064eaf , 0xbc2a801a , 0xcf232f06 , 0x78bf3be4 , 0xf6503acf , 0xd8b2d28b , 0x2aa38abc ] )
+  , Mont::unsafe_make( [ 0x9d6ccf0f , 0xeeb78424 , 0x89289b49 , 0xa9283fdf , 0xddbe9751 , 0x091d8e3d , 0x705834c8 , 0x2bcecbed ] )
+  , Mont::unsafe_make( [ 0x5d654476 , 0xac9d89ae , 0xb740c854 , 0xb8fdcfee , 0x3719dcdf , 0x58c61b9b , 0xf10836a8 , 0x0f4205ce ] )
+  , Mont::unsafe_make( [ 0x29646982 , 0x551f04d9 , 0x1b61c80a , 0xfaac822f , 0x780a7e6f , 0x91c32016 , 0xa8bc8d52 , 0x1beec508 ] )
+  , Mont::unsafe_make( [ 0x6312cebb , 0x33942463 , 0xb8af5cb1 , 0x3ad5ce2e , 0xa16da0e9 , 0x1e18aa75 , 0x34285761 , 0x25f0b9dd ] )
+  , Mont::unsafe_make( [ 0xe7c7df5c , 0x2ff9921d , 0x7943f483 , 0x6bd56881 , 0x5e82c689 , 0xfa4f8187 , 0x3a6cba41 , 0x1aa93ea0 ] )
+  , Mont::unsafe_make( [ 0xd6c6f2ef , 0xc9425dca , 0x66ad7666 , 0x9d7fb2e7 , 0x306863f4 , 0x2114a223 , 0x043f5681 , 0x16f682f1 ] )
+  , Mont::unsafe_make( [ 0x4a5b2e72 , 0x510047b5 , 0x51f31c0d , 0xb734daeb , 0x13f10f0c , 0x76e50832 , 0x016ff3bc , 0x154f708e ] )
+  , Mont::unsafe_make( [ 0xeb94185b , 0xce2fcd3f , 0x9972a6dc , 0x34068f61 , 0x50cc4511 , 0x651684bc , 0x9a8c2520 , 0x0038573a ] )
+  , Mont::unsafe_make( [ 0x87a63ee5 , 0x28565940 , 0x03c22480 , 0x66fed2d2 , 0x383b3762 , 0x3ff736fd , 0xe6a992dd , 0x25513b19 ] )
+  , Mont::unsafe_make( [ 0x393ae05d , 0xbf02e021 , 0x39e86b2a , 0xa389ca00 , 0x3b454157 , 0x6cb705c7 , 0x66c7eb2f , 0x24e538c0 ] )
+  , Mont::unsafe_make( [ 0xad00432c , 0x0752aab3 , 0x545511b2 , 0x6b5a49ad , 0x28c83173 , 0x3006d93e , 0xd735b054 , 0x2d00e5ac ] )
+  , Mont::unsafe_make( [ 0x2e9b5a19 , 0xd8d5bc5e , 0x91b278ca , 0x1c89c4fa , 0xd7548e0e , 0xbbc99a0a , 0x71d4823b , 0x0f95276f ] )
+  , Mont::unsafe_make( [ 0x2773a500 , 0x0b802c31 , 0x1d9cd8bb , 0x73c188ca , 0x683a6270 , 0xcd5ea5cd , 0x41f34492 , 0x300a7cde ] )
+  , Mont::unsafe_make( [ 0xcaab734b , 0x85528308 , 0x2b13b899 , 0x3f2e3325 , 0x018b2021 , 0x2110becc , 0xdd57f0e7 , 0x07a288a9 ] )
+  , Mont::unsafe_make( [ 0xf2a465d9 , 0xa75a2230 , 0xdaa2430a , 0x27c33a11 , 0x735beb56 , 0xac0eccd4 , 0x9cda9366 , 0x078d5904 ] )
+  , Mont::unsafe_make( [ 0xf11e410b , 0xe754d734 , 0x0dcadf03 , 0x8c847111 , 0x9900d697 , 0x6135a4da , 0x54e6bd65 , 0x2005fcfa ] )
+  , Mont::unsafe_make( [ 0xe91b86ad , 0xd9a7a626 , 0xe542748b , 0x026b7920 , 0x3f81bd7f , 0xb33b60fd , 0xd883dfef , 0x12f1457c ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xf00ff4c3 , 0xe4127437 , 0xf417502e , 0xcbf69150 , 0x0abda978 , 0x2ac9d94d , 0x959a768c , 0x019f7fb3 ] )
+  , Mont::unsafe_make( [ 0xfdbbfddc , 0xa97e038e , 0x2e4a8cb7 , 0xe1571f77 , 0x7459db4c , 0xebcfe451 , 0xe7461c7e , 0x1462fb5c ] )
+  , Mont::unsafe_make( [ 0x163684dd , 0xaf97f8f2 , 0x57543757 , 0x16457332 , 0xf1ca3453 , 0x07229120 , 0xec9adee2 , 0x1618cd6b ] )
+  , Mont::unsafe_make( [ 0x482b5084 , 0x9fa34114 , 0x3007540c , 0x3194c050 , 0x713f9913 , 0x1b9c57f3 , 0xd07a3610 , 0x1e40c09f ] )
+  , Mont::unsafe_make( [ 0xa2ab472c , 0x50945eff , 0x19d3737f , 0xcb2b6f07 , 0xa15b7393 , 0x65adddf0 , 0x5bf060a3 , 0x10727345 ] )
+  , Mont::unsafe_make( [ 0xe4ac307e , 0xeae99919 , 0xaba02fbd , 0x47ddd370 , 0xc76dc39b , 0x7b395c2d , 0xf238183b , 0x13dc3e7a ] )
+  , Mont::unsafe_make( [ 0xd93006b8 , 0xf73f40b6 , 0xa76c172e , 0xabdf8b0e , 0xa17fcd52 , 0xd4f97b30 , 0xa5cd6fc6 , 0x16451d5e ] )
+  , Mont::unsafe_make( [ 0x14045639 , 0xd6a3117f , 0x78017ce6 , 0x2f252a66 , 0xaa89b127 , 0xbd6106e7 , 0xa1203ac2 , 0x218cf2df ] )
+  , Mont::unsafe_make( [ 0x0db26b80 , 0xe0247b5a , 0x88be25d3 , 0xca78600f , 0x1133a240 , 0xb069106b , 0x6daf0180 , 0x1c05d1ed ] )
+  , Mont::unsafe_make( [ 0x371c3122 , 0x15f8456f , 0x9713fc3e , 0xf5e8056e , 0xc95c6789 , 0x14bffda9 , 0x58239f81 , 0x0a39c8b5 ] )
+  , Mont::unsafe_make( [ 0xf26a8a1a , 0xf3bfe321 , 0x826a08bc , 0x569441cd , 0x48af2063 , 0xda704dc7 , 0x9be3a08a , 0x29b64c59 ] )
+  , Mont::unsafe_make( [ 0xcbe37ee5 , 0x779f659f , 0xe19e11f5 , 0xabf8e1e6 , 0x1630a0d9 , 0xd40165a6 , 0xa7581784 , 0x14ad7bdf ] )
+  , Mont::unsafe_make( [ 0xfb020f9d , 0x96f703ec , 0x5395ecc4 , 0x6faa2e58 , 0x6e68c52a , 0xced04d98 , 0x1d9dd72a , 0x05d36a7a ] )
+  , Mont::unsafe_make( [ 0xd1fe45ee , 0x990d6cc8 , 0x9140ba0a , 0xfbd767e7 , 0x129bce4d , 0x543bc777 , 0xf4230208 , 0x0337af8a ] )
+  , Mont::unsafe_make( [ 0xc306f6a2 , 0xb2848a0e , 0xabde1074 , 0x13edb39a , 0x839e6d54 , 0xe1f635ff , 0x5cc63436 , 0x27094bce ] )
+  , Mont::unsafe_make( [ 0x452d2585 , 0xfa330f8d , 0x757c90de , 0x65ef7f2f , 0x8f455ef1 , 0x00d88ffe , 0xe42816b2 , 0x2ce68786 ] )
+  , Mont::unsafe_make( [ 0x44683830 , 0xe35bba9e , 0x79cd4cf9 , 0xd6f02668 , 0x78824b21 , 0x0e55bad1 , 0x62145b14 , 0x210a1ae6 ] )
+  , Mont::unsafe_make( [ 0xc34c237b , 0xebba9835 , 0x0ab6fbe5 , 0x14991c39 , 0x0af1c56b , 0xda0aa323 , 0x284f3248 , 0x1df405b2 ] )
+  , Mont::unsafe_make( [ 0x30fbde29 , 0x241c4e35 , 0xd23abbd0 , 0xfccb6f80 , 0x3245c488 , 0x9797410a , 0x1b6cff65 , 0x10fd4603 ] )
+  , Mont::unsafe_make( [ 0x89a0006d , 0x95b1c848 , 0x70ee8083 , 0x37a6d013 , 0x8bb22444 , 0x8b43a67a , 0x7e876679 , 0x2972473d ] )
+  , Mont::unsafe_make( [ 0x756e576f , 0xa33487a5 , 0x61c2e264 , 0xe7ec5c04 , 0xc6e3aa02 , 0x9d6414e5 , 0xe3c0b2b7 , 0x2199871f ] )
+  , Mont::unsafe_make( [ 0xffb1d740 , 0x1421d285 , 0x71549f7a , 0x39dfe3b5 , 0x1394549d , 0x948fa052 , 0x2024ba07 , 0x304cd87e ] )
+  , Mont::unsafe_make( [ 0x9f72ed7d , 0x5e8bd7bc , 0x6d5b1c25 , 0x1239ca69 , 0xbe0e07a7 , 0x7e304695 , 0xbc2839a3 , 0x2856c468 ] )
+  , Mont::unsafe_make( [ 0x0b77cf4e , 0xf261e871 , 0x11d14f31 , 0xdd5cdbc8 , 0x9ccf3861 , 0x92bd4dff , 0xb384f631 , 0x222d8910 ] )
+  , Mont::unsafe_make( [ 0x12aab27a , 0x48af8427 , 0xfb1f517c , 0x7c105bb1 , 0x5ddb199a , 0x157f6ccc , 0x1c811926 , 0x1399a4f9 ] )
+  , Mont::unsafe_make( [ 0x373da325 , 0x9014ca1b , 0xf119ccef , 0x6b1578ef , 0x54fb5b80 , 0x1f23d245 , 0x1e40a3eb , 0x1d31d629 ] )
+  , Mont::unsafe_make( [ 0x4da77715 , 0xa23ca358 , 0x7a14faf2 , 0xb0aba93b , 0xef4f33e8 , 0x503f98e7 , 0x9fa32e6a , 0x05ba3885 ] )
+  , Mont::unsafe_make( [ 0x3269758a , 0xa098f4a3 , 0x39a8ddf0 , 0x2b5d7a0d , 0x8b2c6b4c , 0xebbc3ecb , 0xa3e57040 , 0x2374a611 ] )
+  , Mont::unsafe_make( [ 0xbf36e242 , 0x4372ef48 , 0x390c4a57 , 0x50b3e9f4 , 0xb1e84045 , 0xfeaeb19a , 0x4a3d29cd , 0x1eda0c03 ] )
+  , Mont::unsafe_make( [ 0x53f43e9c , 0x3bcfe806 , 0x337c434b , 0x7ca5f3d4 , 0x84b2eab5 , 0xbcbd9fa3 , 0xa2bb8063 , 0x297514ff ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x4bc0fba2 , 0xce8fb927 , 0x625cb277 , 0x46742ef2 , 0xe9b3a748 , 0x10f5dd3c , 0x8786acaa , 0x23a7be93 ] )
+  , Mont::unsafe_make( [ 0xf7b72d84 , 0xe140f2aa , 0x20c64224 , 0x8d8a2341 , 0x83bd9611 , 0x0281822a , 0xb35db1d4 , 0x247a0139 ] )
+  , Mont::unsafe_make( [ 0xc0cd2820 , 0xe0be4703 , 0x56523611 , 0x20b0945e , 0x9beb95e8 , 0xa958b288 , 0xeaaf1d0a , 0x2c6373e0 ] )
+  , Mont::unsafe_make( [ 0x1ada7b75 , 0xc77b5d99 , 0x26ea06bd , 0x5a6b1cca , 0xceb80d22 , 0x3c11fd56 , 0xf7ca33cb , 0x0f5eab39 ] )
+  , Mont::unsafe_make( [ 0x9bebff91 , 0x819928ff , 0x91c0744a , 0x52e72839 , 0x3c426a38 , 0x447978da , 0xc9dcb96d , 0x011c6cd9 ] )
+  , Mont::unsafe_make( [ 0x383222a5 , 0x8e013d0e , 0x01441fcb , 0xeed2c84a , 0x264f818b , 0xd3730075 , 0xa34421a8 , 0x13e1b28b ] )
+  , Mont::unsafe_make( [ 0x391c8411 , 0xd16ad98a , 0x58934e22 , 0x99cb16bf , 0xb7fed8c8 , 0xe75f2369 , 0x5ff51758 , 0x02624c92 ] )
+  , Mont::unsafe_make( [ 0x99bde59a , 0x1324f807 , 0xda40b4ec , 0x0cdf4736 , 0x0afb15ed , 0x1c856c21 , 0xaeb595bb , 0x24bb8304 ] )
+  , Mont::unsafe_make( [ 0x0b491ae1 , 0x5da86e4c , 0xbeb7f386 , 0x1b9f9540 , 0x6b871717 , 0xe025c3ad , 0x9fc4b296 , 0x103c818a ] )
+  , Mont::unsafe_make( [ 0xa2fe5dac , 0x4dbd8f35 , 0xc5a76e85 , 0x1a27a390 , 0x6a1ef18d , 0xc6b5400b , 0x0e0c96bd , 0x28e72cbf ] )
+  , Mont::unsafe_make( [ 0x828b259f , 0x62d57462 , 0x24d58dbf , 0x805dff46 , 0x68a739e0 , 0xc5d4c9d4 , 0x362b4711 , 0x0a29ac33 ] )
+  , Mont::unsafe_make( [ 0x39c77e7e , 0xd0a4f832 , 0x683545ca , 0xa411fccf , 0xfb1ec2b8 , 0xe90b8d5f , 0x11ebd9b4 , 0x076bd3d1 ] )
+  , Mont::unsafe_make( [ 0x6ee6bfd0 , 0x6cf1debb , 0xd7d5d61e , 0x6b87409b , 0x962060a1 , 0xe9727fb2 , 0x2993ef68 , 0x114c3754 ] )
+  , Mont::unsafe_make( [ 0x33ea1e26 , 0x6660d106 , 0x4617fff8 , 0x047a1260 , 0xbd139bee , 0x01105219 , 0x68676e5b , 0x2f6b42f7 ] )
+  , Mont::unsafe_make( [ 0xbe14d5c9 , 0xbe73c08a , 0xe2e8ce6c , 0xc595d95b , 0x2b9f64da , 0x0e2a92fa , 0x08086b49 , 0x203bb730 ] )
+  , Mont::unsafe_make( [ 0xd4d88583 , 0xfaceb603 , 0x7d8466bb , 0x60f9e96a , 0x4a7f5b44 , 0x5aad8f7f , 0x24df7132 , 0x03b4cd28 ] )
+  , Mont::unsafe_make( [ 0x16747a46 , 0xedc937c3 , 0x05105c29 , 0x3c65de31 , 0x0f17992c , 0xe50db505 , 0x93bbd849 , 0x05927f38 ] )
+  , Mont::unsafe_make( [ 0x68f11d38 , 0x46e22ad8 , 0x28ce6792 , 0x340fe573 , 0x7e12a696 , 0x00e430ae , 0xe6a15f84 , 0x1c5a790d ] )
+  , Mont::unsafe_make( [ 0xac85d503 , 0x1aea865a , 0x832f2ff6 , 0x42e0e722 , 0x97a2053e , 0x8ec08185 , 0x63e58882 , 0x14846145 ] )
+  , Mont::unsafe_make( [ 0x850fdb37 , 0xb32ab75e , 0xade383b5 , 0xcd4c11fa , 0xcc8a58cc , 0xa9dca92b , 0xf2f81a47 , 0x1e5efc11 ] )
+  , Mont::unsafe_make( [ 0xd070ecea , 0xdb15d648 , 0x44e586df , 0x58c716d3 , 0x2040556e , 0xe8c1b1dc , 0xa371f4c5 , 0x0f34e53c ] )
+  , Mont::unsafe_make( [ 0x31ead3fb , 0x6e169740 , 0xf7e642b6 , 0x8133c586 , 0x50c746be , 0xd826fa46 , 0xc88e4038 , 0x2dd51017 ] )
+  , Mont::unsafe_make( [ 0xb45dac88 , 0x1efce873 , 0x2ac573c6 , 0x5a106d05 , 0x9e49db9a , 0xb062d7cb , 0x5226237e , 0x22a2d04b ] )
+  , Mont::unsafe_make( [ 0xa6813033 , 0xefb439e7 , 0x4383913e , 0xb7a83841 , 0x8bebe4eb , 0x75fb1d13 , 0xb736ee94 , 0x0030113e ] )
+  , Mont::unsafe_make( [ 0x527045f6 , 0xb2e01262 , 0xcc1b95b4 , 0xec20e6b9 , 0x1051976b , 0xd2945f09 , 0xea672c91 , 0x25b03665 ] )
+  , Mont::unsafe_make( [ 0x2291d86f , 0x981c8991 , 0x7ed26e74 , 0xc27ecc80 , 0x3d46bd5f , 0x01f08db1 , 0xa43086ca , 0x1958c2cd ] )
+  , Mont::unsafe_make( [ 0xf04693e6 , 0x8ffba1c9 , 0xd3fb2e31 , 0x1fbe5e49 , 0x33d88e7a , 0xfeedfcf0 , 0x9d014ffd , 0x2e3a4189 ] )
+  , Mont::unsafe_make( [ 0x05333f82 , 0xae63ca73 , 0x910c9baa , 0x8c3c6191 , 0x1a04f78d , 0xde7ca381 , 0x51eea464 , 0x22db8d48 ] )
+  , Mont::unsafe_make( [ 0x0b0a1071 , 0xcc008b1c , 0xf953854f , 0x830f5c93 , 0x97697064 , 0x220f69a2 , 0xf114a930 , 0x17d00b78 ] )
+  , Mont::unsafe_make( [ 0xf0616b6c , 0xfd657812 , 0xf96370af , 0x22ee6f6f , 0x3b3c5659 , 0x6692f250 , 0x54631b88 , 0x28e5772f ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x559d62cc , 0xfe810f5d , 0xfc8977b1 , 0x1ae0ea0a , 0xd03e8158 , 0x578dee87 , 0x34d85e9f , 0x0ee20fe3 ] )
+  , Mont::unsafe_make( [ 0x9d0eaa7c , 0x6a08edfc , 0x60e71c76 , 0x63479960 , 0x11fe9a29 , 0x22a7bd65 , 0xfd3c4a3b , 0x10ebdd5e ] )
+  , Mont::unsafe_make( [ 0x84f919c0 , 0x8d48410e , 0x218c020c , 0x06302de7 , 0x3932fd25 , 0x74e0c97a , 0x19a890a2 , 0x2cf8d56b ] )
+  , Mont::unsafe_make( [ 0x7f1e23cd , 0xe5f02a40 , 0xe80cfdf2 , 0x6b30205b , 0xd8a0adbd , 0x08f5d920 , 0xe53e6159 , 0x2ecd97d1 ] )
+  , Mont::unsafe_make( [ 0x3887b9c6 , 0x042a2be7 , 0x16011557 , 0x4021fd0f , 0x1cf3e436 , 0x9e127cf6 , 0xcff6d544 , 0x0b661ef4 ] )
+  , Mont::unsafe_make( [ 0xaf0ff0fc , 0xf096f4fb , 0x3cc83fb6 , 0x68767706 , 0x06bb524e , 0xc135e0a3 , 0xcca5e751 , 0x1f6d98de ] )
+  , Mont::unsafe_make( [ 0xaef22ea3 , 0xd5655b14 , 0x96afd922 , 0x0fd5737d , 0x5763cdc6 , 0xca88a45c , 0x68ad2df6 , 0x0a4055aa ] )
+  , Mont::unsafe_make( [ 0x31156ae1 , 0x354f3842 , 0xc740a6cf , 0x04e76d76 , 0x2abeb1ff , 0xd2c73a1e , 0xce406681 , 0x1d6987a5 ] )
+  , Mont::unsafe_make( [ 0x37c77d8d , 0x88ce440b , 0x5c1f4a80 , 0x50a376a6 , 0xd72b191a , 0x65a9465b , 0x4d89e268 , 0x284de763 ] )
+  , Mont::unsafe_make( [ 0x1575365f , 0xe279b661 , 0xc93d4949 , 0x9291b6b4 , 0x58b582d4 , 0x39fd20bb , 0x090c50e7 , 0x26399225 ] )
+  , Mont::unsafe_make( [ 0xd40aea06 , 0x9c153cd9 , 0xeb049310 , 0x602f278f , 0x65c8e687 , 0x75fe293e , 0xac689478 , 0x0d720654 ] )
+  , Mont::unsafe_make( [ 0x6f1c5fdc , 0x07c52106 , 0xa6746b54 , 0x7d83f057 , 0xd0e04890 , 0x9f3652db , 0x5de2df0e , 0x1c498e62 ] )
+  , Mont::unsafe_make( [ 0x30309b61 , 0xd32e563b , 0x9ef02ca1 , 0x5d36ae34 , 0x2292153b , 0x51824c1e , 0x9e271f91 , 0x1c3c41f9 ] )
+  , Mont::unsafe_make( [ 0x04300fef , 0x8fa2dc73 , 0x08212a27 , 0x7903056b , 0x5dde8c71 , 0x68afd9b5 , 0x65fc4999 , 0x0effa2c2 ] )
+  , Mont::unsafe_make( [ 0x736178e2 , 0x266611d7 , 0x3e024a6f , 0xb47d8e35 , 0x5a995473 , 0x6bbf2bd2 , 0x7b560f2a , 0x1dc23de3 ] )
+  , Mont::unsafe_make( [ 0x83683bc9 , 0x18b60530 , 0xcb3fb618 , 0xfddf7f6a , 0x9e3a795b , 0x95ed780b , 0xb19762fa , 0x2d79dafc ] )
+  , Mont::unsafe_make( [ 0xa74f6f10 , 0x9b109769 , 0xfccfa299 , 0xcb3d939a , 0xac8b4f33 , 0x4cae228a , 0x3b8c1941 , 0x18b16511 ] )
+  , Mont::unsafe_make( [ 0x01f04c75 , 0x8e3287bb , 0x0c714dd9 , 0x1ef18a5e , 0x44343bd9 , 0x1b6fb7eb , 0x4f835e8c , 0x247862e4 ] )
+  , Mont::unsafe_make( [ 0xad3584ae , 0xbf39a3c7 , 0xb0a3558d , 0x800a459f , 0x87375e4e , 0x2a8f44db , 0xa8a7112d , 0x24e44c87 ] )
+  , Mont::unsafe_make( [ 0xfbd8bb41 , 0x54e8a0ea , 0xd4c433f4 , 0x30ed15b0 , 0x0905d6f8 , 0xd861e504 , 0x0407812e , 0x074762c3 ] )
+  , Mont::unsafe_make( [ 0x94a0dc99 , 0x772f1eff , 0xa7492619 , 0x7354a387 , 0x7c2e3527 , 0x5caaec61 , 0x65222788 , 0x29086953 ] )
+  , Mont::unsafe_make( [ 0xfee61cd0 , 0x04964fd5 , 0x282de6ed , 0xed9b0014 , 0x6d757406 , 0x741f9fbb , 0xb2118f39 , 0x04011cc6 ] )
+  , Mont::unsafe_make( [ 0xb50c3ec5 , 0x3298a7da , 0x8146982a , 0x255e62d1 , 0x08abf2d0 , 0xb359ca1b , 0x2f6841be , 0x2df83fd6 ] )
+  , Mont::unsafe_make( [ 0xc4bde807 , 0x54f88f47 , 0x0fe55844 , 0xf19c5b6e , 0x909efea9 , 0xca9b7390 , 0xddc3a5b4 , 0x01c1ca3a ] )
+  , Mont::unsafe_make( [ 0x54f461af , 0xb9dcfb05 , 0xa0918b08 , 0x1562d2f4 , 0x5442ffd9 , 0xb3140c26 , 0xa9855861 , 0x1001a6e3 ] )
+  , Mont::unsafe_make( [ 0xaf91d8ee , 0x663dedcf , 0x9fe3b8ec , 0x0e26c356 , 0xd4a888aa , 0x9b75b223 , 0xaf2494b3 , 0x0099435f ] )
+  , Mont::unsafe_make( [ 0x7a733820 , 0x45d069f3 , 0x9333d799 , 0xf41d3a31 , 0xcdb2e41a , 0x682f2ffc , 0x7e413463 , 0x294d1d72 ] )
+  , Mont::unsafe_make( [ 0x8a5b923a , 0x0b167b6d , 0x5cfe5f3e , 0x12aa0f26 , 0xf0d39653 , 0x28c50a09 , 0xf011b38e , 0x0336632d ] )
+  , Mont::unsafe_make( [ 0x0942b4d4 , 0x19c4999e , 0xe38ef13b , 0xc59427b8 , 0xc3b28cb1 , 0xe7260fb7 , 0x5893e610 , 0x1e3c7fb4 ] )
+  , Mont::unsafe_make( [ 0x74ed50c8 , 0xbd7590dd , 0x0bbd08e1 , 0x8f476b27 , 0x1f594f66 , 0x74776cb0 , 0x7880fbb9 , 0x0365cb59 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x2772e61f , 0x465a9a3a , 0x9d01817c , 0xab228a06 , 0x8728a44d , 0x6a6af94a , 0xc877cf62 , 0x067ac385 ] )
+  , Mont::unsafe_make( [ 0xcea11607 , 0x8c73d082 , 0x95d53b3e , 0x6fd8e30b , 0xd10696c0 , 0xb431de71 , 0xab9ea8a4 , 0x19859257 ] )
+  , Mont::unsafe_make( [ 0x1c54d06b , 0x1bf12e9e , 0x3624433c , 0x1203d8e2 , 0x73867e6c , 0xe22719e0 , 0x462e319e , 0x260173b1 ] )
+  , Mont::unsafe_make( [ 0x6b0089ac , 0xb16c7d9d , 0xdf4f5864 , 0x780ee203 , 0x8e613907 , 0xe48be766 , 0x5bba2bf0 , 0x2f063517 ] )
+  , Mont::unsafe_make( [ 0xc6396fd4 , 0x2343d906 , 0xff7dca92 , 0xeca34089 , 0xc0447fb7 , 0xdc4de592 , 0x610756a1 , 0x047439e4 ] )
+  , Mont::unsafe_make( [ 0xd19b7c97 , 0x17415e07 , 0x385d59b9 , 0x43322541 , 0x45211a43 , 0xba603960 , 0x9c3b0e12 , 0x2ba038b4 ] )
+  , Mont::unsafe_make( [ 0xcd2de501 , 0xee1b0538 , 0x91c389e1 , 0x9bf29769 , 0x686e4210 , 0xf68fef6d , 0x5194c361 , 0x2c0ecb25 ] )
+  , Mont::unsafe_make( [ 0x5840e30d , 0xf0d43dc4 , 0x73b77954 , 0x088c7cfb , 0x6e8ac648 , 0x55a4e766 , 0x71a1e719 , 0x12b017ea ] )
+  , Mont::unsafe_make( [ 0x366cb1cd , 0x5ea70818 , 0x412da97e , 0x73bcc82c , 0x9d86f0f5 , 0xadc27e28 , 0x8e6c4049 , 0x27b4162b ] )
+  , Mont::unsafe_make( [ 0x5bbcae74 , 0x4c0af169 , 0x7c0cc255 , 0x1628c604 , 0x5a304353 , 0x410bc5e0 , 0xf7d95e0c , 0x099770ca ] )
+  , Mont::unsafe_make( [ 0x4a41cc67 , 0x2d61b1cb , 0xc568437d , 0xbf721281 , 0xecf5e7b0 , 0x779d92a2 , 0x2175da9a , 0x2c87d6ca ] )
+  , Mont::unsafe_make( [ 0xecc41316 , 0x608ba072 , 0x732ce686 , 0x29a60d28 , 0xe1ff34a8 , 0x65005e16 , 0xd07b5ec2 , 0x2d3c7ef1 ] )
+  , Mont::unsafe_make( [ 0xaea4e1ca , 0x44f3ae62 , 0x0e83b311 , 0x0a9e46b2 , 0x957e732a , 0x21e523bb , 0x6a6d7b00 , 0x2766b0e4 ] )
+  , Mont::unsafe_make( [ 0x4a066d53 , 0x042e5b58 , 0x7f806c44 , 0x7965225a , 0x9e3315ea , 0xddf53f6d , 0xd8d2236c , 0x007574b9 ] )
+  , Mont::unsafe_make( [ 0xf2c7712e , 0x8837100a , 0x53816bfe , 0x595ed401 , 0xc3af6288 , 0xeaea9897 , 0xb8e344e6 , 0x200082f9 ] )
+  , Mont::unsafe_make( [ 0xb5e6d15d , 0x3bde4f59 , 0x22960338 , 0x17ff75a8 , 0x9c3f7012 , 0xae69c89a , 0xb7545df1 , 0x2ee7b93f ] )
+  , Mont::unsafe_make( [ 0xdec532c1 , 0xaf37307f , 0x9add940e , 0x967c7ebe , 0x84380b8f , 0xf118bde8 , 0x9b58801e , 0x1383e656 ] )
+  , Mont::unsafe_make( [ 0x912b75bd , 0xf44f233d , 0xf951ed24 , 0x36441a33 , 0x7b99dfad , 0x11b89c5e , 0x3e091ea7 , 0x1d5fe343 ] )
+  , Mont::unsafe_make( [ 0x1a7c7c4d , 0x7d51a71e , 0x35cef094 , 0x12d4333b , 0xf607a129 , 0x0c89439a , 0xc7970c3d , 0x0e2d8022 ] )
+  , Mont::unsafe_make( [ 0xae5536a2 , 0xe25a1b62 , 0xad2c8dd8 , 0x18b7526b , 0xf638bef3 , 0x60b6c421 , 0x288a3324 , 0x2148235a ] )
+  , Mont::unsafe_make( [ 0x6a9b4690 , 0x8faea294 , 0xeebd6c96 , 0x6727b298 , 0xf0946fee , 0xc56aca04 , 0xcc437207 , 0x0f6e1c30 ] )
+  , Mont::unsafe_make( [ 0x5d7b5b4b , 0x1db94f86 , 0x98f8f65c , 0x62ba62cf , 0x8a52e799 , 0x9a496160 , 0xa0c0f176 , 0x125bf6e5 ] )
+  , Mont::unsafe_make( [ 0x146dc905 , 0x067c826c , 0xdf234a6f , 0x4a22678e , 0x57cae03f , 0x94c0754a , 0xbe28f766 , 0x036764a2 ] )
+  , Mont::unsafe_make( [ 0x1200ffdf , 0xaf6ba35d , 0xe79ec302 , 0x520890e9 , 0xd12e74b5 , 0xcd372e29 , 0x80b4a264 , 0x20490bb6 ] )
+  , Mont::unsafe_make( [ 0x1ce31c23 , 0xb744214b , 0x409a812d , 0x84017acf , 0x118e0bd6 , 0x2bad78a4 , 0x42ee26df , 0x11eea528 ] )
+  , Mont::unsafe_make( [ 0x80554173 , 0xfdb02413 , 0x2aad7be5 , 0xec7f0790 , 0x139add7a , 0x9bf27927 , 0xac18ab71 , 0x1decbb49 ] )
+  , Mont::unsafe_make( [ 0xbc2cdac8 , 0x8cc4ea12 , 0x77858f34 , 0x46679439 , 0x843e8eb7 , 0xc92627ca , 0x44b36262 , 0x1c9c0427 ] )
+  , Mont::unsafe_make( [ 0x16884f0c , 0x5a6d8763 , 0xd189d88b , 0x3e724116 , 0xee5f10e9 , 0xe0a48370 , 0x0d2650b4 , 0x2e7f201b ] )
+  , Mont::unsafe_make( [ 0x568f7fc7 , 0x4e22f0bb , 0x08369cf6 , 0x60199c09 , 0x50a5d773 , 0xd31642a8 , 0x897663db , 0x1c281c97 ] )
+  , Mont::unsafe_make( [ 0xdf71fad6 , 0x9f7eb8e4 , 0x87ee7b4f , 0x80d3ab36 , 0x16639a57 , 0xed672c2e , 0xb1b765f4 , 0x1fd5d101 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xf5e80011 , 0x8ad9d40a , 0x56b036fa , 0xa9b82a51 , 0xaaa7c4c7 , 0x4fea7e9e , 0x4ec55c08 , 0x01fb7837 ] )
+  , Mont::unsafe_make( [ 0x46ff25a0 , 0xc302b7c1 , 0x560cccc5 , 0x241f1496 , 0xb9d79be0 , 0x366f6e65 , 0x0ee74348 , 0x17802c74 ] )
+  , Mont::unsafe_make( [ 0x19593c36 , 0xe24524f4 , 0xc3ff56f3 , 0x06356dd0 , 0xbc80e38f , 0xc906511d , 0x38390964 , 0x22075d47 ] )
+  , Mont::unsafe_make( [ 0x490a1ec9 , 0xf99556b7 , 0xd739d6e5 , 0x040527d7 , 0x2cea9e46 , 0xd3c0c66e , 0x2d8c4654 , 0x00f8315e ] )
+  , Mont::unsafe_make( [ 0x463fe7b0 , 0x4a01ebbf , 0x327027e4 , 0x86f2081a , 0xdbad4236 , 0x62e1ce1a , 0x7b36fa8c , 0x045198b6 ] )
+  , Mont::unsafe_make( [ 0xbf466db3 , 0xfb6ec13a , 0xf09c8599 , 0xfa3f5c57 , 0xd38b700b , 0x6bc0ca58 , 0xf4681927 , 0x0e303466 ] )
+  , Mont::unsafe_make( [ 0x73a0ad5d , 0xd58a3b7e , 0x54e04752 , 0x443bd576 , 0x506646cc , 0xcc60f94b , 0x8e446f36 , 0x2a8ac5e8 ] )
+  , Mont::unsafe_make( [ 0xc81acc79 , 0x89712956 , 0x859122a9 , 0x395d784b , 0x5bc36fcd , 0x8bfcb0ba , 0x70aed386 , 0x2e0d6de6 ] )
+  , Mont::unsafe_make( [ 0xbf61d573 , 0xeb3cc1bd , 0xbe112734 , 0xa1c3bc29 , 0xfa4270f5 , 0x0da26ca0 , 0x76bac4a5 , 0x05b47213 ] )
+  , Mont::unsafe_make( [ 0xe043de7d , 0x78c49299 , 0x276df397 , 0x2abd4d9a , 0x99bceb98 , 0x0dc98939 , 0xce0890dd , 0x1da937fa ] )
+  , Mont::unsafe_make( [ 0xf40a30a4 , 0x0bac205c , 0x9c3d6356 , 0xb0ab4f14 , 0x2cc14647 , 0x0e0bb44f , 0x3a7bd97a , 0x2740d0d3 ] )
+  , Mont::unsafe_make( [ 0x28109a45 , 0xc156b278 , 0x7210d97f , 0x78a890dc , 0xe0b0856b , 0x9576917f , 0x7c873f4f , 0x2de71f10 ] )
+  , Mont::unsafe_make( [ 0xad59bc80 , 0x063f0e57 , 0x2d7b62ec , 0x880e9806 , 0x20341f94 , 0xbca44e8b , 0xfdd45427 , 0x141294eb ] )
+  , Mont::unsafe_make( [ 0x9d0efb1c , 0x5b750464 , 0x5f087fb7 , 0xd2aba8db , 0x675ddebc , 0xd1bd3648 , 0xcf0f3591 , 0x2a0f29e3 ] )
+  , Mont::unsafe_make( [ 0xb5bd59cb , 0x5d43fb9e , 0x8d55d943 , 0xbb0c8cda , 0xd50ee1c5 , 0xa724930b , 0x4af86f42 , 0x09b13f64 ] )
+  , Mont::unsafe_make( [ 0xf0db5c6f , 0x3c5efd99 , 0x24a1ab78 , 0x96cfa778 , 0x5f928f96 , 0x3d1170da , 0xff977944 , 0x038fe50f ] )
+  , Mont::unsafe_make( [ 0x0eefc2fd , 0xec8fe862 , 0x4766bb0f , 0xc3aaecc9 , 0xa2b70c46 , 0x250ac05b , 0x26722fb2 , 0x27b0dfd7 ] )
+  , Mont::unsafe_make( [ 0xf615df81 , 0xd2da74c2 , 0x454aa5a4 , 0xcd87156f , 0x1d0bc5bc , 0xe09d3076 , 0x01498b57 , 0x19795662 ] )
+  , Mont::unsafe_make( [ 0x3da75220 , 0x0d0b0601 , 0x3e594512 , 0xe5e3f9a9 , 0x70b697b6 , 0x6186e9c6 , 0x4a2c7fe4 , 0x102b3e54 ] )
+  , Mont::unsafe_make( [ 0x6e2d45d5 , 0x0a91a828 , 0xc6943d5e , 0x77aedd0d , 0xf6fcb105 , 0x4043dd9b , 0x7a7f38da , 0x1fd62ffe ] )
+  , Mont::unsafe_make( [ 0xeed065ce , 0xbb7d1c0f , 0x869c1c0c , 0xe8c60688 , 0x7f99bf3e , 0xd1ff4d26 , 0xb11a258f , 0x131b54e8 ] )
+  , Mont::unsafe_make( [ 0xc0a1bceb , 0x6b1d5692 , 0xc9933389 , 0x553d592b , 0x29f41355 , 0x4db4dacd , 0x9d736daf , 0x249ae312 ] )
+  , Mont::unsafe_make( [ 0xedf415e1 , 0xcd900b59 , 0xa034c3c5 , 0x2b333200 , 0x828fc5dc , 0xca194e4f , 0xdcda8431 , 0x1d6081e9 ] )
+  , Mont::unsafe_make( [ 0xd91b8bbb , 0x3bc35f80 , 0xb1de2657 , 0x80dbd1d1 , 0x5d2e6c85 , 0xcfdb104f , 0x7c23d31c , 0x27ead3a9 ] )
+  , Mont::unsafe_make( [ 0xe90d4732 , 0x279a6012 , 0xf830c5e3 , 0xbc767ea3 , 0xfb22a61c , 0x71b2e279 , 0x81f0b3e6 , 0x2fa48756 ] )
+  , Mont::unsafe_make( [ 0xb1b6db30 , 0xbfef314b , 0x99d8ea70 , 0x8a9e83ab , 0x728d993e , 0x5cec4b86 , 0x2c4e2ca9 , 0x09734208 ] )
+  , Mont::unsafe_make( [ 0x0787331f , 0x00ed0532 , 0x6a780866 , 0x8001474b , 0xd93a5c85 , 0x4a8ba937 , 0x01baf263 , 0x258e5b61 ] )
+  , Mont::unsafe_make( [ 0x0b459bb1 , 0x2cc97850 , 0x595163f6 , 0xb1013ecc , 0x2fe81575 , 0xe35abc82 , 0x425b6917 , 0x03e1a280 ] )
+  , Mont::unsafe_make( [ 0xb966263e , 0xe98ff253 , 0x24341d02 , 0xd9078342 , 0x03fecd0a , 0x2db51185 , 0x51274abf , 0x1d60dda1 ] )
+  , Mont::unsafe_make( [ 0x0a5b3d2e , 0x9629c15f , 0xdc98f3a9 , 0x53209d30 , 0x95b18312 , 0xc504231e , 0x910c6720 , 0x2dd0a437 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xf7f73a96 , 0xc9655dc8 , 0x9e037674 , 0x5bfc6ff3 , 0xde2a1842 , 0x91d43898 , 0x435cd409 , 0x20ea4afa ] )
+  , Mont::unsafe_make( [ 0xede89fb1 , 0x44306bb9 , 0x9ae57239 , 0xa44cc60a , 0x9970724b , 0x1591b436 , 0xdd61a740 , 0x259dd832 ] )
+  , Mont::unsafe_make( [ 0xd9c97fa3 , 0x958f6adf , 0xaa33a2ce , 0xc846bb39 , 0xede91995 , 0x8a02c347 , 0x4b297a59 , 0x2ebc5eab ] )
+  , Mont::unsafe_make( [ 0x17c70ee6 , 0x4e1668f5 , 0x0c8d0eea , 0x62d1410e , 0xc51cc6b0 , 0x26b47d7c , 0x72268edf , 0x24fe479e ] )
+  , Mont::unsafe_make( [ 0xc9858b71 , 0x09c6df70 , 0x7babbce1 , 0xbbf69b45 , 0x12ca8e59 , 0x15d653c7 , 0xef9ed1ec , 0x16655943 ] )
+  , Mont::unsafe_make( [ 0xac677d26 , 0x07c89086 , 0x1943e350 , 0x17bb613b , 0x0d2eae54 , 0xd612b22d , 0x534ce422 , 0x0400b3c3 ] )
+  , Mont::unsafe_make( [ 0x5525e282 , 0x5c8736fe , 0x5f7a8c96 , 0x45dfc80b , 0x08a7ba54 , 0xd57c47ee , 0x2711a2b8 , 0x088963d3 ] )
+  , Mont::unsafe_make( [ 0x25507f1c , 0x4897c26c , 0x9711f71f , 0xd0acc34b , 0x52d89ef2 , 0x00e8d403 , 0x878c97e3 , 0x2ac1f533 ] )
+  , Mont::unsafe_make( [ 0x6df941b4 , 0xc75e1509 , 0xc9d4fe19 , 0xeefa54eb , 0x289dd1db , 0xc628c380 , 0x0011d6e6 , 0x1e09ebec ] )
+  , Mont::unsafe_make( [ 0xb93acc1b , 0x9aaccc7d , 0x6cca7896 , 0xc9d25561 , 0x97fdec5e , 0x53164623 , 0xbcb276f5 , 0x1f05428b ] )
+  , Mont::unsafe_make( [ 0x02f78030 , 0xc658419f , 0xb1bee4e8 , 0xbc71604c , 0x5db8c4a7 , 0xf7a69cb1 , 0x426dcaa4 , 0x0a6d94b8 ] )
+  , Mont::unsafe_make( [ 0x4dc2d3a7 , 0xb1d8bef7 , 0x19db1be1 , 0x03141bbe , 0x7a56c91e , 0x4a5562fc , 0xa72199f1 , 0x063ad1f0 ] )
+  , Mont::unsafe_make( [ 0x23071783 , 0x7cf37bfe , 0x6725cddc , 0x1b68864c , 0x4ecf8a00 , 0x620cf766 , 0xebaed0dc , 0x0a5cc43e ] )
+  , Mont::unsafe_make( [ 0x86c79a52 , 0x02b07538 , 0x43259186 , 0xaca8cf2c , 0xe28c2b70 , 0x0f5b1987 , 0x90c27ae3 , 0x0c0a031b ] )
+  , Mont::unsafe_make( [ 0x6282925a , 0x2a217ca4 , 0x17fe74c7 , 0x2ab23269 , 0x331e9075 , 0x9dcfc5f0 , 0x23016046 , 0x13ce96cf ] )
+  , Mont::unsafe_make( [ 0xab400d4e , 0x46fca15f , 0xd91f5769 , 0x6168dcf4 , 0x63eac824 , 0xfd64630c , 0x231defc8 , 0x28569e82 ] )
+  , Mont::unsafe_make( [ 0x968c8681 , 0x4c550172 , 0x48b60c0f , 0x3217adaf , 0x7bfdaf79 , 0x6f49fd41 , 0x7cbb9fe3 , 0x1ac6a0d5 ] )
+  , Mont::unsafe_make( [ 0x2b517abc , 0xdcf158f5 , 0x6a7c615d , 0xbb6860b9 , 0x0ba71172 , 0xce4cf4fd , 0xb10b0af7 , 0x2e4ffcd9 ] )
+  , Mont::unsafe_make( [ 0x8179b0c4 , 0x1fb9af7a , 0x24dda95c , 0xdd17dc3b , 0x1ba78087 , 0xa0475d7c , 0xe6679913 , 0x04c28089 ] )
+  , Mont::unsafe_make( [ 0x6d5f639e , 0xe15d2df2 , 0x0646756c , 0x6fa98391 , 0x142cfdbd , 0x6bcbb6cf , 0xbed0be84 , 0x1e8c18a0 ] )
+  , Mont::unsafe_make( [ 0xd1c96583 , 0x0de4e953 , 0x7ed6b890 , 0xb7b6a8bf , 0x93ac0807 , 0x8fe8bef4 , 0x81e118f8 , 0x147f5a05 ] )
+  , Mont::unsafe_make( [ 0x3b26b904 , 0x0015a215 , 0xde89a0ab , 0xb34314a7 , 0x1f3bca92 , 0xd7a42d0a , 0x020bec3c , 0x0dd457b9 ] )
+  , Mont::unsafe_make( [ 0xa2eec313 , 0x693ab497 , 0xab5070ea , 0xf918afd5 , 0x56d7ada2 , 0x3ccf448b , 0x59764669 , 0x1d5659f0 ] )
+  , Mont::unsafe_make( [ 0x3279ff2b , 0x0bb27588 , 0x110ca010 , 0x9d7ea923 , 0x243b51e7 , 0x41558cd9 , 0x3b7d41f8 , 0x20922214 ] )
+  , Mont::unsafe_make( [ 0x748bd100 , 0x616c6641 , 0x683c116c , 0x9fef528a , 0x8df3960a , 0x878bcb71 , 0x4a7b4574 , 0x0e0b9017 ] )
+  , Mont::unsafe_make( [ 0x873323a0 , 0x1fbbf4d4 , 0x195d9435 , 0x1c578404 , 0x91f52baf , 0xe7eaa546 , 0xbd35c1bb , 0x22944116 ] )
+  , Mont::unsafe_make( [ 0x9e4b8961 , 0x3041a6a3 , 0x42911bb5 , 0x3027fd12 , 0x0ac378f7 , 0x4b7939a2 , 0x1148b58e , 0x3049e886 ] )
+  , Mont::unsafe_make( [ 0x55448085 , 0x3e57a3c2 , 0x5520284b , 0x76444786 , 0xdf8945e9 , 0x00107427 , 0xa7455a79 , 0x038a5f32 ] )
+  , Mont::unsafe_make( [ 0xba9a8853 , 0xdaf591ba , 0xf647c1bf , 0x67978f75 , 0xbab43f14 , 0x84864e1a , 0x77ab5080 , 0x08a8ef8e ] )
+  , Mont::unsafe_make( [ 0xb494b953 , 0x536da55b , 0x3ce03d56 , 0x27a23189 , 0x684d3f68 , 0x1ac647f8 , 0xd1bc909c , 0x2d9698b7 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x02b5d2fd , 0x42ba4024 , 0x595baa6b , 0xebb9e792 , 0x42e5367f , 0x39839329 , 0x55e72450 , 0x2646128e ] )
+  , Mont::unsafe_make( [ 0x7c0701c1 , 0x51ef8eff , 0x2920a366 , 0xad4182ea , 0xcf0078a8 , 0x9a0755bf , 0xc8bb2f17 , 0x2c0b247a ] )
+  , Mont::unsafe_make( [ 0xe81a49dd , 0xdacbfb5a , 0x16cdbee7 , 0xab29b619 , 0xa45a8902 , 0x5cbca688 , 0x59b78184 , 0x2c882ea7 ] )
+  , Mont::unsafe_make( [ 0x21b37acf , 0x3c2eeb6c , 0x3e6c87ac , 0x86c7c467 , 0x6cdaa6c9 , 0x89d2f133 , 0x787d6b40 , 0x03301591 ] )
+  , Mont::unsafe_make( [ 0x7452b518 , 0x2ed65bf4 , 0x9fb3b4ff , 0x0f23cb47 , 0xd9b6d7d0 , 0x07509147 , 0x84d26d29 , 0x27f1d692 ] )
+  , Mont::unsafe_make( [ 0x0a96d206 , 0x11471d3c , 0x70815d22 , 0x15f29f41 , 0x1f2da921 , 0x48d2b928 , 0x322a354e , 0x20b50847 ] )
+  , Mont::unsafe_make( [ 0xb7523488 , 0x92912215 , 0x0dcf66c2 , 0xc0e97569 , 0xfdd14efa , 0xc4bdcd7e , 0xe89978b2 , 0x199d7cef ] )
+  , Mont::unsafe_make( [ 0xb78dd289 , 0x6d34857e , 0x8668d099 , 0x72cc4d10 , 0x2d19c827 , 0x089f20d4 , 0xd7e72495 , 0x0a1dd99c ] )
+  , Mont::unsafe_make( [ 0x6942d431 , 0xba3b75ca , 0xd5ebb1a2 , 0x6a072759 , 0x38d602c1 , 0x18e2ef6e , 0xc5ba18d3 , 0x17965940 ] )
+  , Mont::unsafe_make( [ 0x48747f39 , 0xee2a4c5a , 0xc0bcaca6 , 0xda7f8347 , 0x4c35dbee , 0xf8c959ac , 0x827c35ec , 0x06a4d403 ] )
+  , Mont::unsafe_make( [ 0x6cb0ceab , 0x37b4366c , 0x00888b16 , 0x0d1f5fd6 , 0x34fa61d8 , 0xe985da8c , 0xfcff061d , 0x179b70a1 ] )
+  , Mont::unsafe_make( [ 0xe03e54a3 , 0xe875bc52 , 0x24eb750a , 0x6a69fcab , 0xa2959f8c , 0xc2e291a7 , 0x0a8afc44 , 0x2d322ba0 ] )
+  , Mont::unsafe_make( [ 0x8b035ecd , 0xdccc23e6 , 0xe89ddc36 , 0x3462e7a1 , 0x5412f794 , 0x0f3a0348 , 0xcf4bbb54 , 0x0b48d25d ] )
+  , Mont::unsafe_make( [ 0x7bf9ef76 , 0x87a847b1 , 0x2c23fa47 , 0x5ff0c91e , 0x98952b3d , 0x76073d8e , 0x25f2cb57 , 0x2104a9fc ] )
+  , Mont::unsafe_make( [ 0x90fa02bf , 0xfd32fe3f , 0x49480728 , 0xc7c2a637 , 0xcb5b9457 , 0xd7b17cb7 , 0x41544b7b , 0x2fd49088 ] )
+  , Mont::unsafe_make( [ 0xec36992c , 0xad00d865 , 0xc9715f1d , 0x64e7a684 , 0x16b283ad , 0x16c4401b , 0xd6c6e116 , 0x0093a9d9 ] )
+  , Mont::unsafe_make( [ 0xf562995f , 0x0e8a0b96 , 0x94726f21 , 0xa1343b64 , 0xa9caf059 , 0x920be6db , 0x5b7ddf1a , 0x1d1b2a1f ] )
+  , Mont::unsafe_make( [ 0xc597f667 , 0x1ea98a70 , 0x196bd643 , 0x4eff0bf5 , 0x2150024e , 0xc6e3d5a0 , 0x899d2668 , 0x2f1524a3 ] )
+  , Mont::unsafe_make( [ 0xa2605535 , 0x2800e947 , 0x17c72bcc , 0x46dde010 , 0xa4f46acc , 0x20bab3e2 , 0x9d8d4f41 , 0x1b56ce5b ] )
+  , Mont::unsafe_make( [ 0xdce88c7e , 0x9068b9ce , 0xdbd8e907 , 0xc19cb6a4 , 0xa840b206 , 0x6582b4e0 , 0x611b92c8 , 0x038fe3f0 ] )
+  , Mont::unsafe_make( [ 0xa729121e , 0x00d7c058 , 0x4791de73 , 0x6d9a106b , 0x2306eb89 , 0x7134b2e2 , 0x484bcb1b , 0x15dcc6fb ] )
+  , Mont::unsafe_make( [ 0x302082c9 , 0x6730c5e5 , 0xccbbc023 , 0xb84944a7 , 0x42cbee96 , 0x383a9c61 , 0x3bab3099 , 0x1ecb2113 ] )
+  , Mont::unsafe_make( [ 0x6b44c0df , 0xd9e636f4 , 0x4228a962 , 0x33ad3cd2 , 0x170872a0 , 0x3269e009 , 0x79bf7114 , 0x106d6613 ] )
+  , Mont::unsafe_make( [ 0x465dc09c , 0x2721b2cb , 0x8211a2c2 , 0xb9441d63 , 0x5302657f , 0xcfdff31e , 0x2752a789 , 0x1b4fda67 ] )
+  , Mont::unsafe_make( [ 0xc9ac67ea , 0x0d484a3e , 0x44ac0a2a , 0x89e71802 , 0xc7456aa4 , 0x555588c6 , 0x3a1a646e , 0x0b2ee0cf ] )
+  , Mont::unsafe_make( [ 0x9feb1463 , 0x2ebcb3f3 , 0xbaf57579 , 0x02273377 , 0xd1cec367 , 0xb7ae6b96 , 0xc86b84c9 , 0x11b9e6ad ] )
+  , Mont::unsafe_make( [ 0x5ada1dc7 , 0x0fda23ef , 0xf8ec4c13 , 0x1d26ded9 , 0x8e4457bc , 0xd22d1ef6 , 0x7680dfa4 , 0x10d6153f ] )
+  , Mont::unsafe_make( [ 0xd771202e , 0x58b781fd , 0xabbbb716 , 0x7f2aa44f , 0x60f6b95e , 0x70bceaeb , 0x158e5ef8 , 0x23469bfe ] )
+  , Mont::unsafe_make( [ 0x3989355f , 0xe3366029 , 0xa1c95f85 , 0x192820e3 , 0x1bdf4c60 , 0x10076303 , 0x93c881bf , 0x24768c86 ] )
+  , Mont::unsafe_make( [ 0xbb110cc0 , 0xd327eeee , 0xb527e26e , 0x52bcdc31 , 0x3f5e61c6 , 0x4cad91b4 , 0x81e88a25 , 0x00ebb5e5 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x02336328 , 0xa5f85cd4 , 0xa3fbddfa , 0x8e509e0e , 0x1ad04f45 , 0xf5a4979b , 0xea8de922 , 0x0a88277d ] )
+  , Mont::unsafe_make( [ 0x7fe38c30 , 0x88646046 , 0x798eeb9d , 0x5bd321f4 , 0x5721d135 , 0x1a277503 , 0xd7f241a0 , 0x044b9230 ] )
+  , Mont::unsafe_make( [ 0xb50e3f2f , 0x779de6da , 0xe6a3dd3d , 0xe7e81447 , 0x72f1c656 , 0x310eedd7 , 0xf219ca34 , 0x1bd65ca1 ] )
+  , Mont::unsafe_make( [ 0x29cd04ec , 0x3d4fc558 , 0x14998148 , 0x49b7648d , 0xbe94717c , 0x7d9091b1 , 0x366a6077 , 0x29e6f421 ] )
+  , Mont::unsafe_make( [ 0x4ddb4e84 , 0xb7ab6d92 , 0x19a62eae , 0x23697dc3 , 0xbdb30634 , 0x5c7a2776 , 0xff0cd6be , 0x0dc23760 ] )
+  , Mont::unsafe_make( [ 0xf4cecbb4 , 0x2dd4e21c , 0x8146b7c9 , 0x6e48ce71 , 0xa8bf9678 , 0xaf138d15 , 0x08c9c7d2 , 0x19888d07 ] )
+  , Mont::unsafe_make( [ 0x96b5dc14 , 0x8f35e376 , 0x58a8db15 , 0xb8f283dd , 0x5026a555 , 0x7e8bca26 , 0xa3c00574 , 0x00cdd24c ] )
+  , Mont::unsafe_make( [ 0x88e9447a , 0xd586130f , 0xe0b85c22 , 0xd103e62e , 0x45a8a89c , 0x5004e04e , 0xd79e277c , 0x2e017177 ] )
+  , Mont::unsafe_make( [ 0xd4d6a235 , 0x5a9edc22 , 0xc498a710 , 0xe667c85e , 0xf19e388f , 0xb8e32347 , 0xb19fa8af , 0x133b85c1 ] )
+  , Mont::unsafe_make( [ 0x14c834e6 , 0xf9bbd34e , 0x10fda18a , 0xde1c19d0 , 0x599c6811 , 0xbfd1b3bc , 0x7b49a89f , 0x29a1b0f7 ] )
+  , Mont::unsafe_make( [ 0x5cdcadc4 , 0x00564fbb , 0x596ad3dd , 0xbf3671e0 , 0xe1e7639b , 0x7bae9636 , 0x82fd0714 , 0x207bce31 ] )
+  , Mont::unsafe_make( [ 0xadae528b , 0x99da8200 , 0xa6c14e67 , 0xbd79ddce , 0x66cf27ad , 0x46cd6865 , 0xfeec17b3 , 0x2fd00e46 ] )
+  , Mont::unsafe_make( [ 0x88170110 , 0x3b93455a , 0xc59affc3 , 0x34f81792 , 0x8bfa517e , 0x32c25ce8 , 0xcc5b8d0b , 0x07edfad5 ] )
+  , Mont::unsafe_make( [ 0x6d9ee526 , 0x46f35d16 , 0x1a6c5cf3 , 0x9324d4b7 , 0xa17e5f49 , 0xf865c2ca , 0x11e3d688 , 0x2077a0ab ] )
+  , Mont::unsafe_make( [ 0xc7424eb0 , 0xcfc3c292 , 0x27ede750 , 0x295c8f3b , 0xb5561f01 , 0xba1840b5 , 0xb02b1d28 , 0x0984ad6a ] )
+  , Mont::unsafe_make( [ 0x3162972e , 0x1d48befc , 0xbe1aec00 , 0x7796bdf2 , 0x45218543 , 0xbe42b176 , 0x9033a943 , 0x08b78a26 ] )
+  , Mont::unsafe_make( [ 0x27f86cb3 , 0x6a00ae6c , 0xcf11d044 , 0x3b6ef907 , 0xdb75cb61 , 0xda06ff30 , 0x2f37ee68 , 0x001ec7c0 ] )
+  , Mont::unsafe_make( [ 0x4992a418 , 0x9988afc2 , 0x40bdc208 , 0x914ae7c3 , 0x3d773bf8 , 0x2a5bd171 , 0x84e23588 , 0x01cdb1a5 ] )
+  , Mont::unsafe_make( [ 0x413a2a8b , 0xbc19534e , 0x6c7ebb15 , 0x50a779a2 , 0x263951e5 , 0x3f6d23b1 , 0xa850a253 , 0x26b3fe71 ] )
+  , Mont::unsafe_make( [ 0x095470b0 , 0xfd1b415d , 0xdb162b28 , 0xd86fe5bb , 0xa33ae1ad , 0xadceb489 , 0x1c2b3a53 , 0x2b0dd98a ] )
+  , Mont::unsafe_make( [ 0xfa3539ca , 0x26d7b45e , 0xb86fdcb0 , 0xa483ca5a , 0xc513e79e , 0x9e05ddff , 0x14679004 , 0x2648fb90 ] )
+  , Mont::unsafe_make( [ 0x6a03e10b , 0x308a5edd , 0x871d1750 , 0x3182263b , 0x100293b8 , 0xfcf47f72 , 0x3b1e702a , 0x070d5cc8 ] )
+  , Mont::unsafe_make( [ 0x53575f17 , 0x45408fa7 , 0x90e6b605 , 0xddcfbdb9 , 0xd7d52c66 , 0xbf276405 , 0x32cb2894 , 0x20581e1a ] )
+  , Mont::unsafe_make( [ 0x6cd55801 , 0x1ebc1a13 , 0x3367b8b0 , 0xf7328815 , 0xaf0e2728 , 0x56f64bd2 , 0x7288d542 , 0x23b01d3e ] )
+  , Mont::unsafe_make( [ 0xaf6a5374 , 0x2933dd2e , 0x0f619eb0 , 0x2b5f63e0 , 0x255c0058 , 0xbcf2abd8 , 0xe74af66b , 0x2e389dd2 ] )
+  , Mont::unsafe_make( [ 0xb714abf8 , 0x79dbf2a8 , 0xdd405f2c , 0xf6d62b34 , 0xca675627 , 0xff8bdc22 , 0x9a63c44a , 0x065ee068 ] )
+  , Mont::unsafe_make( [ 0x11af127a , 0xaaf2f50d , 0x6cf889de , 0x3a8e9577 , 0x0c4bd4ed , 0x412bf931 , 0x876289eb , 0x005e7a4b ] )
+  , Mont::unsafe_make( [ 0x8982c02d , 0x583f3ae9 , 0xd4ffbd01 , 0x0e46c040 , 0xeb4fea46 , 0x5d535e48 , 0xa057afff , 0x1d619b85 ] )
+  , Mont::unsafe_make( [ 0x65dad983 , 0xc540e8d1 , 0xeb68102a , 0x47dc8b7f , 0xbf810d65 , 0x312e6871 , 0x53a319b6 , 0x17c4ffe8 ] )
+  , Mont::unsafe_make( [ 0x9bffe42f , 0x2334a3ea , 0x93226114 , 0xa0ff5232 , 0x953cbae4 , 0x07f7ed4c , 0x450633a0 , 0x0c6b8e5b ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xea26a0dd , 0xc1942176 , 0x0a86e510 , 0xe12b4ee3 , 0xbb2596ee , 0xd5377490 , 0x831ee457 , 0x2054af8a ] )
+  , Mont::unsafe_make( [ 0x4ebed65a , 0x81404f1a , 0x7aadedf2 , 0x44a74a32 , 0x4893c619 , 0x7d65fcfb , 0x3e5b7bad , 0x0178898d ] )
+  , Mont::unsafe_make( [ 0x9508b0e6 , 0x4091c219 , 0x3f7ff7b3 , 0x05d2db46 , 0x8a8d2ad9 , 0x058f967e , 0x9b7697a2 , 0x05112c93 ] )
+  , Mont::unsafe_make( [ 0xc1c3aca0 , 0x9e6f21e9 , 0x1cc9cb5b , 0x321ae62c , 0x388c16a5 , 0x1165f7f5 , 0xb7ad585e , 0x0cef9ef2 ] )
+  , Mont::unsafe_make( [ 0x1ba823d0 , 0xb9feeb00 , 0xe29e3424 , 0xe24b5581 , 0x6013f139 , 0x9e3b4637 , 0xc5e49154 , 0x15cd907c ] )
+  , Mont::unsafe_make( [ 0x9308cfa4 , 0x7150724f , 0xa1a3877a , 0xcf63f91b , 0xfa3782f7 , 0x79c0e49a , 0x68e325af , 0x21016af4 ] )
+  , Mont::unsafe_make( [ 0xd3ca3234 , 0xd55f892b , 0x16a13a11 , 0xf63f1380 , 0xb35bc4c6 , 0x72b47ac0 , 0xaae5c211 , 0x2f9daa12 ] )
+  , Mont::unsafe_make( [ 0x1e861e3a , 0x95cc2591 , 0x9a4b50c5 , 0xf711c39b , 0x5a72c60a , 0x730737d7 , 0xaf7ff053 , 0x19defae0 ] )
+  , Mont::unsafe_make( [ 0x6a8507d3 , 0x2b1c9cfc , 0xc1c1cbe5 , 0x0520d4d2 , 0x080f34b3 , 0x51a1489d , 0x4ab97a42 , 0x1f073589 ] )
+  , Mont::unsafe_make( [ 0x8170cf4d , 0xd8d32d05 , 0x1c109f9d , 0xbfcbcbd2 , 0xff94ded1 , 0x1b809e0f , 0xcef3ae33 , 0x25a48258 ] )
+  , Mont::unsafe_make( [ 0x6f6566d9 , 0xe465602b , 0xb1e4bbdf , 0x0a301645 , 0xba28576d , 0x95334d86 , 0xa5ccdde6 , 0x27c16cea ] )
+  , Mont::unsafe_make( [ 0x40d44084 , 0x6c067397 , 0x823f6c10 , 0x48c7cc03 , 0x13c8d364 , 0xfa18c264 , 0x5d786ea3 , 0x2fec0c5a ] )
+  , Mont::unsafe_make( [ 0x488ef426 , 0x5a7c99c2 , 0x87af45b1 , 0xa1953df4 , 0xb871446b , 0x4970ee28 , 0x1e4d29ad , 0x07916edf ] )
+  , Mont::unsafe_make( [ 0x2a311b4d , 0x98e91dc9 , 0xa933ef88 , 0x2747ac2d , 0x4b2bd1b2 , 0x7b75b44e , 0x683d0ecd , 0x0e8cba7b ] )
+  , Mont::unsafe_make( [ 0x6474219a , 0xebc3cbc3 , 0xce78a881 , 0x29ec80f6 , 0x013cb8e3 , 0x3ee9c03d , 0x9542be38 , 0x2d5d0814 ] )
+  , Mont::unsafe_make( [ 0xe34619d1 , 0x72eb469b , 0x383c504b , 0x35f4b041 , 0xb2025611 , 0x20f5f34a , 0x895143a8 , 0x04921317 ] )
+  , Mont::unsafe_make( [ 0x571b94b3 , 0x0749a732 , 0x131ade96 , 0xe967faf2 , 0x8d87abf6 , 0x5910ea3f , 0xe41f21d2 , 0x08b4c00f ] )
+  , Mont::unsafe_make( [ 0xd2e78b39 , 0x73050c52 , 0x79adcbd1 , 0x83cac6f0 , 0x37b64bbf , 0x255716f6 , 0x432cf76a , 0x0b077cbc ] )
+  , Mont::unsafe_make( [ 0xc01031d9 , 0x1fa56882 , 0xdd4ddb74 , 0xda92ff93 , 0x82ea4fa3 , 0x11bbc0f0 , 0xf288cab9 , 0x2f62acde ] )
+  , Mont::unsafe_make( [ 0xdcb1e7ad , 0x418da69e , 0xde2e1a1a , 0x2a9d2838 , 0xadf1b2cd , 0x3faf376a , 0xb017c685 , 0x25f994f8 ] )
+  , Mont::unsafe_make( [ 0x672952b4 , 0xf234d692 , 0x3647d5bb , 0xd92a5651 , 0xbcf00154 , 0xf8e3edf2 , 0x1582e74a , 0x26f84fdc ] )
+  , Mont::unsafe_make( [ 0xcb3b7942 , 0x1cca9cc4 , 0x2f80a87c , 0x2fb0007f , 0x24f3ec2d , 0x0a7077c7 , 0x8ddb0725 , 0x24c8d165 ] )
+  , Mont::unsafe_make( [ 0x17b856fb , 0x8fe65e60 , 0xb6807afb , 0xe85606a5 , 0x1522ee6f , 0x7471752c , 0x26ffe632 , 0x029e9314 ] )
+  , Mont::unsafe_make( [ 0xe1dcef76 , 0xa8f8f74f , 0x1bd33dd7 , 0x6dd65039 , 0xf94b0b0c , 0xc6e83f73 , 0xd0b9d3b4 , 0x22b68bca ] )
+  , Mont::unsafe_make( [ 0x01a5dc05 , 0x5bca3398 , 0x11c53839 , 0x7dfb8c52 , 0xf0be2ad0 , 0x958469d4 , 0xef1adec9 , 0x000caa06 ] )
+  , Mont::unsafe_make( [ 0x026fa988 , 0xdb146982 , 0xe5192669 , 0x4ee5197e , 0x66f3c6c5 , 0x285e74bb , 0xa5711a21 , 0x153a088c ] )
+  , Mont::unsafe_make( [ 0x6af9b69a , 0x43bbca24 , 0x576b082d , 0x05b75217 , 0x4c234972 , 0x203e2e25 , 0xaca08a95 , 0x0832a1b3 ] )
+  , Mont::unsafe_make( [ 0x07411969 , 0xb0f14c0f , 0xe6bbd043 , 0x2b289a5f , 0x70277eee , 0x68e84d4a , 0x010d52fc , 0x00b895e6 ] )
+  , Mont::unsafe_make( [ 0x140f5478 , 0xcbe16171 , 0x41fd276b , 0x275b5f0a , 0x785a0bac , 0x1e5401df , 0x404cc1b1 , 0x1c574153 ] )
+  , Mont::unsafe_make( [ 0xcde7ce69 , 0x5b4bf9d1 , 0xa714967c , 0x74e9959a , 0x9bcbb06f , 0x6bba2491 , 0x217dc546 , 0x25417d0b ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xf909b758 , 0xa2f4205e , 0xb8ab34ea , 0x00e4adbe , 0xa8009ace , 0xf99bff29 , 0x76df685c , 0x0fddf1fa ] )
+  , Mont::unsafe_make( [ 0x2ccb46f0 , 0xcf48a078 , 0x38487d7d , 0x8ffa490e , 0xe9747ca4 , 0x81c82d42 , 0x973c1e01 , 0x2859f374 ] )
+  , Mont::unsafe_make( [ 0xdda3fb31 , 0x5c3e1a34 , 0x456e4e9f , 0xcb1c8d00 , 0xff20446e , 0x670effe6 , 0x79538e22 , 0x06455044 ] )
+  , Mont::unsafe_make( [ 0x09d11430 , 0x1c71baec , 0xa8168917 , 0xc3c620a4 , 0x47fee2f1 , 0x508c244d , 0x5cbb196a , 0x260e306d ] )
+  , Mont::unsafe_make( [ 0x1a90e660 , 0x1e5fe6ad , 0x1d6200a8 , 0x9ac63a3c , 0xb6996611 , 0x0ff43063 , 0x0c9b3a47 , 0x25f6dc35 ] )
+  , Mont::unsafe_make( [ 0xa6d30769 , 0x4dba81dc , 0x6da4df24 , 0x4335ae9f , 0x94942424 , 0xb17f97b3 , 0xf8d49ad7 , 0x0652ec78 ] )
+  , Mont::unsafe_make( [ 0xa9f9841f , 0xec6b3c72 , 0x5b921973 , 0xb6e4333b , 0x9983798d , 0x42928aac , 0x51154616 , 0x1b0b282b ] )
+  , Mont::unsafe_make( [ 0xac18ff45 , 0x9cc5aa5d , 0x5e66dfad , 0x39106464 , 0x29505188 , 0x4cf4127a , 0xaf8b6050 , 0x25e2ef94 ] )
+  , Mont::unsafe_make( [ 0x42f0ba59 , 0x4b4b93d3 , 0x9137024d , 0x520dcc05 , 0xd9f36f69 , 0x49680481 , 0xc182a332 , 0x1642dc93 ] )
+  , Mont::unsafe_make( [ 0xa1cc32dc , 0x9e87684d , 0xe953e430 , 0xa24a79ee , 0xebc8b7d2 , 0xedf415fe , 0xedad367e , 0x25b1caa1 ] )
+  , Mont::unsafe_make( [ 0x0f362ad3 , 0x2c80e708 , 0xc28c2a54 , 0xa78016df , 0xcb5e2ba3 , 0x926c4af4 , 0x249fa4df , 0x1557da9d ] )
+  , Mont::unsafe_make( [ 0xb14685d2 , 0x17abe200 , 0x4f203a02 , 0x47a26b56 , 0x70cba151 , 0x0bb1bf3c , 0x1058211c , 0x2e11fb5e ] )
+  , Mont::unsafe_make( [ 0x4b283c23 , 0x07dfdac8 , 0x863f5616 , 0xc3566094 , 0x77a2b893 , 0xb70feb5a , 0x1061362e , 0x17ec2c19 ] )
+  , Mont::unsafe_make( [ 0x8ebf8448 , 0xb517e4c0 , 0xe12c3f25 , 0x268c483a , 0x16850856 , 0xebe4666e , 0x4d028147 , 0x2dc039ab ] )
+  , Mont::unsafe_make( [ 0x1430ac1d , 0xb21e68db , 0x129add74 , 0x09cb9b15 , 0xb562eda7 , 0x0108e714 , 0xb808d193 , 0x14a5ce6b ] )
+  , Mont::unsafe_make( [ 0x68cdecf2 , 0x34064ef7 , 0xef8e2e7d , 0x8835256d , 0x2083443d , 0x2a297191 , 0xbfaf9c92 , 0x056523a3 ] )
+  , Mont::unsafe_make( [ 0x12b86dd2 , 0x0bd472ab , 0x1ad0aa88 , 0x5ebba452 , 0x6890279e , 0xbbfc5012 , 0x74341fcd , 0x1756a0de ] )
+  , Mont::unsafe_make( [ 0x85b44910 , 0xc8a0eee1 , 0xcd5f2b13 , 0x3586d043 , 0x68280756 , 0xb100c76e , 0xb572acd8 , 0x230bb201 ] )
+  , Mont::unsafe_make( [ 0x621cfa42 , 0x72f9e7a3 , 0xd209912e , 0x9ce57b80 , 0x70259d44 , 0xb48b00ef , 0x93a16218 , 0x2708a554 ] )
+  , Mont::unsafe_make( [ 0xa5d4c599 , 0xd9027715 , 0x256c6c12 , 0xfaaace86 , 0xfdfeb8df , 0x1071a2e8 , 0xed798fa3 , 0x100e033a ] )
+  , Mont::unsafe_make( [ 0x980227fe , 0x72080d93 , 0xf86f5512 , 0xd792d544 , 0x6ec334b9 , 0x897eb624 , 0x01ae84e0 , 0x21c6c939 ] )
+  , Mont::unsafe_make( [ 0x8d23b0a8 , 0xdc4b36a7 , 0x4558e0b9 , 0x6ada9b74 , 0x5e4e2a28 , 0x538e4a4a , 0x62f1c135 , 0x27ed8eec ] )
+  , Mont::unsafe_make( [ 0x5e7cddcf , 0x16cc4e88 , 0xc8713473 , 0xa0e51856 , 0xe475bdfa , 0x386dff15 , 0xc65c0446 , 0x019e6698 ] )
+  , Mont::unsafe_make( [ 0xb27bba0d , 0xca6c55fb , 0xa91506e4 , 0x098b13a2 , 0x17f65083 , 0xfbab8c9f , 0x3e86ab47 , 0x12724d66 ] )
+  , Mont::unsafe_make( [ 0xf7a9c81c , 0xba55ffb2 , 0x53b58b85 , 0x3ef9a54d , 0x104cf2ec , 0x1e0d08a7 , 0xd6364de6 , 0x03bd9bec ] )
+  , Mont::unsafe_make( [ 0x0a10e3d4 , 0x0a62dc97 , 0xd148fe2c , 0x812bf039 , 0x9f1667ee , 0xf45486c9 , 0x250646d4 , 0x0c5e1e80 ] )
+  , Mont::unsafe_make( [ 0xcac731d3 , 0xff56cdc5 , 0x7b1bb999 , 0xebce9ab3 , 0x5f617af5 , 0x1d7a4f40 , 0x3b14299a , 0x0c2718be ] )
+  , Mont::unsafe_make( [ 0xd2d13afd , 0x86bb550f , 0xb14dd256 , 0x9ec5274f , 0xbfc9b1bd , 0x29b25f70 , 0x58cf0548 , 0x05f88443 ] )
+  , Mont::unsafe_make( [ 0xfb09f1e3 , 0x8991ca83 , 0x5ebcdc06 , 0x8757ecdb , 0xdd9a131c , 0xd1d078a1 , 0x93e1bab5 , 0x2c4d6fe9 ] )
+  , Mont::unsafe_make( [ 0x5f2a96b7 , 0x098c158a , 0x96dd2f0c , 0x36c6eefd , 0xdb530985 , 0x4e835a05 , 0x7e2be484 , 0x211bedbf ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x26fce6e9 , 0x5028845e , 0x7ed5340e , 0x3e5e6ea6 , 0x56658d00 , 0x0a830267 , 0x3d04d1f1 , 0x13f4c633 ] )
+  , Mont::unsafe_make( [ 0x4f685136 , 0xa43fa8ff , 0xdd9f8065 , 0x0fb8af21 , 0x282ac882 , 0x10bf2fb7 , 0xd787b5fe , 0x0ce80aa0 ] )
+  , Mont::unsafe_make( [ 0x5be435cc , 0x201a769c , 0x91968c3a , 0xf3194805 , 0x12b4657c , 0xf3d35125 , 0xd5509380 , 0x0082fb7d ] )
+  , Mont::unsafe_make( [ 0xa66a9d91 , 0x2294c2d8 , 0x108a1a79 , 0xdcc6728c , 0xd1d651c4 , 0x873d7bc2 , 0x706efbff , 0x22ab0488 ] )
+  , Mont::unsafe_make( [ 0xf742baef , 0xa6b2ad81 , 0x6e4ae13c , 0xf52f1ce5 , 0x1d06c67e , 0x6d2b8508 , 0xf33a167b , 0x23e9930f ] )
+  , Mont::unsafe_make( [ 0x67a95947 , 0xc9f62d9d , 0x5efd2a61 , 0xbd01c0d9 , 0x18654b20 , 0x1c3f60d3 , 0x1b119e89 , 0x0a70f68f ] )
+  , Mont::unsafe_make( [ 0x16e85ede , 0x86ee71a6 , 0x884475e5 , 0xbdcacfd8 , 0xb9bc95f1 , 0xdb18f7a6 , 0xc563d65e , 0x12072856 ] )
+  , Mont::unsafe_make( [ 0x2f1ebbae , 0x4bcfe5a7 , 0xdd22dc1e , 0x5e210d15 , 0xf59103d1 , 0x568c8165 , 0x970517ac , 0x14babfc2 ] )
+  , Mont::unsafe_make( [ 0x1c9ce160 , 0x8429ce2a , 0x01248729 , 0xa15f1447 , 0x9b3ff9aa , 0x222f6282 , 0x0029f2ba , 0x2779ff1c ] )
+  , Mont::unsafe_make( [ 0xc42ccb40 , 0x7c74fba5 , 0x55e1cd93 , 0x8f1ac916 , 0xd0009d26 , 0xa92dad3b , 0x6d80a799 , 0x0310ece8 ] )
+  , Mont::unsafe_make( [ 0xd60ea458 , 0x09491440 , 0x5176b038 , 0x4519af2d , 0xd318d5ee , 0xee878298 , 0x52c2c1aa , 0x114916c5 ] )
+  , Mont::unsafe_make( [ 0xfe42dca5 , 0xac43e0ed , 0xbe677444 , 0xe73a6148 , 0x600c5d5f , 0x92b15faa , 0x4e2a2098 , 0x24f26e9a ] )
+  , Mont::unsafe_make( [ 0x5bda2264 , 0x076f553b , 0x4cbf5519 , 0xc32eac7c , 0x6a5713de , 0x4ae6c356 , 0x1069d808 , 0x1cb69ce4 ] )
+  , Mont::unsafe_make( [ 0xe3bd680c , 0x239e0e13 , 0xe0f2e843 , 0xbaa54a59 , 0xfecdb085 , 0x7a3c16e0 , 0x20c2e7f3 , 0x29c6a02d ] )
+  , Mont::unsafe_make( [ 0x264989fd , 0x0a668e14 , 0x3ee3c688 , 0x0610dcfe , 0x242e6607 , 0x59dbd6ed , 0xd3a517dc , 0x0740f7ad ] )
+  , Mont::unsafe_make( [ 0x302a937c , 0xcd3696c0 , 0x5a5577c6 , 0x9f18fb5f , 0x068ad9d1 , 0xce49b5cc , 0x8c684804 , 0x1eafd9d0 ] )
+  , Mont::unsafe_make( [ 0x3b7da45c , 0x2102a81d , 0xe4a026e4 , 0x9c0dac07 , 0x8090954c , 0xd12ceb23 , 0xd99b138b , 0x0ff8b97f ] )
+  , Mont::unsafe_make( [ 0x97f201b0 , 0xea32e477 , 0x134f58aa , 0x696d6ff1 , 0x5a37dd08 , 0x3dbd8e88 , 0x521582f7 , 0x0441d2ff ] )
+  , Mont::unsafe_make( [ 0xacd69717 , 0x9405c987 , 0x47ea84ba , 0x28f64058 , 0x613fb662 , 0xdb7ac9e2 , 0x0991db96 , 0x25bd4133 ] )
+  , Mont::unsafe_make( [ 0x35eb9944 , 0xce485126 , 0xda50ff86 , 0x5f32957f , 0x3c2225b4 , 0xf6b386e8 , 0x1a64a51b , 0x1f3244ed ] )
+  , Mont::unsafe_make( [ 0x30a087c3 , 0xfcfb73ba , 0x8e2ccef8 , 0x05be55ec , 0x93b6d483 , 0x71068dac , 0x1d2b996d , 0x00996fad ] )
+  , Mont::unsafe_make( [ 0x6736a94e , 0xcacaad54 , 0xb0ab87f9 , 0x095fc6b9 , 0x3f3fd712 , 0x2a92b0d6 , 0x39ffbfc2 , 0x1ba74028 ] )
+  , Mont::unsafe_make( [ 0x13de7a63 , 0xa7c5915e , 0x0933dcf6 , 0xcffdee23 , 0xf88a44a5 , 0xa3138a89 , 0x0bcd85ff , 0x286e4eaf ] )
+  , Mont::unsafe_make( [ 0x0e186468 , 0x94bb7eeb , 0xd89f0208 , 0xe5983e47 , 0xe15f25f4 , 0x851c03e9 , 0x83e5780d , 0x2b19b293 ] )
+  , Mont::unsafe_make( [ 0xbcd79f34 , 0x6a40414a , 0x9bc35b9a , 0xa23aee88 , 0x62bdcf0c , 0xf07b37df , 0x9e171ea7 , 0x03fa7b19 ] )
+  , Mont::unsafe_make( [ 0xef7a0bde , 0x0470bf3c , 0xc2978e4f , 0xcb578ea1 , 0x1c48bccf , 0x26e803d6 , 0x54b1704b , 0x21f02c39 ] )
+  , Mont::unsafe_make( [ 0xe3a08e22 , 0xa11496a7 , 0x0eae88ef , 0x17d9db20 , 0xe2ee37bd , 0xda028310 , 0x2ee49d29 , 0x24bc1e22 ] )
+  , Mont::unsafe_make( [ 0x78e75c54 , 0xc237e209 , 0x9f743140 , 0xb08ad636 , 0x344d9841 , 0x55347862 , 0xbab51766 , 0x294cb7b8 ] )
+  , Mont::unsafe_make( [ 0x0012cadc , 0x7683b5aa , 0xfc67c7a9 , 0x989bfbb3 , 0x946d2461 , 0xc881d7ca , 0xd286cd49 , 0x2c5b7616 ] )
+  , Mont::unsafe_make( [ 0xab13f273 , 0x221660ca , 0x9f048b02 , 0xc9a0123d , 0xf7f59270 , 0xb605e573 , 0x285b12bc , 0x1d1a3e78 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xc3c93052 , 0x6575a673 , 0xa6c4f373 , 0x577d245b , 0xfedd5cda , 0x5c4e2c43 , 0x5030daa9 , 0x173f7599 ] )
+  , Mont::unsafe_make( [ 0x293d66ff , 0x1bb65249 , 0x37ab0858 , 0x776dd142 , 0xafef9469 , 0x62fa797b , 0x0c9c492b , 0x210280a3 ] )
+  , Mont::unsafe_make( [ 0x02ee8889 , 0xc474fbab , 0x3eced23e , 0x417826e1 , 0xf7e474fa , 0x3360dfd8 , 0xce069325 , 0x1be5416e ] )
+  , Mont::unsafe_make( [ 0x0d063a79 , 0xd25899c6 , 0x2eb36753 , 0xa1e1af5c , 0x799cefcd , 0x5733fe54 , 0x5e089cd6 , 0x19221884 ] )
+  , Mont::unsafe_make( [ 0xf850e6d0 , 0xb53926de , 0x0c25bf70 , 0xdeb30553 , 0x96839c09 , 0x0711fcd1 , 0xd5d5c234 , 0x1f8c3511 ] )
+  , Mont::unsafe_make( [ 0x6b2b57bc , 0xc2c0d0db , 0xa25e8ec9 , 0x26a3457f , 0x3c8f2d2b , 0xfd1f261d , 0xcdcc54e2 , 0x0181a052 ] )
+  , Mont::unsafe_make( [ 0x1f1db229 , 0x8f1a6982 , 0x01287a5f , 0x77c76605 , 0xc92d8e4c , 0x0fdc8777 , 0x4d2c1dd5 , 0x1ae5b09d ] )
+  , Mont::unsafe_make( [ 0x8423645f , 0x9eb95cef , 0x1c561a10 , 0xdb4dac1a , 0x164b6b79 , 0x1aa7ed71 , 0x6b7eb642 , 0x1ff14c50 ] )
+  , Mont::unsafe_make( [ 0x3b8d9653 , 0x21de854d , 0xa62af43e , 0x866fabae , 0xb5e46c52 , 0xae9c62e8 , 0x4c024657 , 0x24bf9c11 ] )
+  , Mont::unsafe_make( [ 0xd13bbee6 , 0x43444c7f , 0x20a97de0 , 0xe8249f4a , 0x6904614a , 0xdb4a0abb , 0x972db7a2 , 0x02fb0412 ] )
+  , Mont::unsafe_make( [ 0xc206053e , 0x8b2f30a4 , 0x6dd48c39 , 0x492e1c7c , 0xb72c4a67 , 0x916329e5 , 0x1fe7f5e5 , 0x0e704d14 ] )
+  , Mont::unsafe_make( [ 0x74c16760 , 0x8f116ab5 , 0xa0f7fef7 , 0xdd413035 , 0xf8e4a3c6 , 0x2529ba92 , 0xef73bb47 , 0x2b5f20ac ] )
+  , Mont::unsafe_make( [ 0xe7080514 , 0x308dfbde , 0x9bdc3bfd , 0x975f5e5f , 0x15ce1b15 , 0x04bee543 , 0x135a8bef , 0x2bae54f5 ] )
+  , Mont::unsafe_make( [ 0x25f04190 , 0x77dba0ae , 0x4880ae55 , 0x08e42457 , 0x588bb1ea , 0x1bcf50d0 , 0x82dade46 , 0x09b8a07b ] )
+  , Mont::unsafe_make( [ 0x34e23657 , 0x232b8fbf , 0xd8b5e7cb , 0xb301dba0 , 0x3f10aa26 , 0x5cde45e9 , 0x95089d8a , 0x06a5ec7b ] )
+  , Mont::unsafe_make( [ 0xe44d94a0 , 0x8f873856 , 0x32a28b03 , 0x4c220bfd , 0xa5155814 , 0x4e3a1343 , 0xc108f3c5 , 0x186ae6fc ] )
+  , Mont::unsafe_make( [ 0x7ea27d07 , 0x4ef01ff3 , 0x37e9f3b0 , 0xfe2a6697 , 0x2150fbad , 0x435b9686 , 0xee23b59d , 0x18f3fb39 ] )
+  , Mont::unsafe_make( [ 0x2a44f4da , 0x8d563227 , 0x7059c3f9 , 0x689facc7 , 0x3826cf6c , 0x6fbfe473 , 0xee05bb03 , 0x1e963cb0 ] )
+  , Mont::unsafe_make( [ 0xdcfbf347 , 0x4d3a6896 , 0xd436d0a6 , 0xaf60757d , 0x36fef950 , 0x78129530 , 0x10a77e40 , 0x065bdcde ] )
+  , Mont::unsafe_make( [ 0xce5f3a9f , 0x8f690e85 , 0xbea3bfe9 , 0x850ea1b0 , 0xcab23416 , 0x1ddee053 , 0xa34bba5b , 0x2e35c4a6 ] )
+  , Mont::unsafe_make( [ 0x85bf96c9 , 0x40e69892 , 0xa36d712d , 0x93b68ac0 , 0x719ae63f , 0x5fe4e551 , 0x073dafb7 , 0x08d0be20 ] )
+  , Mont::unsafe_make( [ 0x10798b9d , 0xf9a24df5 , 0xd19e6e4a , 0xefd4da20 , 0x16878603 , 0xc722bc8d , 0xc8cc46d7 , 0x068bcd96 ] )
+  , Mont::unsafe_make( [ 0xdbb84f55 , 0x708d08f9 , 0xa4456377 , 0xb91095a5 , 0x1d50d855 , 0xa9e5e9d3 , 0x486c376f , 0x0d890c2b ] )
+  , Mont::unsafe_make( [ 0x7ee8cf59 , 0x28255000 , 0x1d714461 , 0xe8d8f40f , 0xd1584529 , 0x7d9e5655 , 0x914b893d , 0x081afd0b ] )
+  , Mont::unsafe_make( [ 0xbb18de3d , 0x1f9ce703 , 0x241d2c1e , 0xeefd78aa , 0x91b49671 , 0x7350793a , 0xab0e7ea5 , 0x0181d4f5 ] )
+  , Mont::unsafe_make( [ 0xef09a43d , 0x499a7675 , 0x05945785 , 0x87b53360 , 0xfef28f9e , 0xe02ece8d , 0x0861d816 , 0x07d1e731 ] )
+  , Mont::unsafe_make( [ 0x04ab05a3 , 0x68edd4ac , 0xe1e6c034 , 0xf302b19f , 0x7263c2d7 , 0xd4ec026c , 0x61966a42 , 0x2d08e2e0 ] )
+  , Mont::unsafe_make( [ 0x712382ee , 0x964a8fca , 0x82e3ad27 , 0x777fa932 , 0x3a366667 , 0x2914befd , 0xe3b3d090 , 0x11c2fcf5 ] )
+  , Mont::unsafe_make( [ 0x763364c3 , 0x565b7eb6 , 0xf8fed266 , 0x01224c5f , 0xb126b23f , 0x363a5021 , 0x007b7eb3 , 0x1eb959d7 ] )
+  , Mont::unsafe_make( [ 0x551ae99c , 0x0bcab67f , 0x629c47f8 , 0xa42395f3 , 0x50eb2eff , 0x06445ec5 , 0x60411043 , 0x2adb1482 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xeca44674 , 0xe5175d21 , 0x621ee540 , 0x8e4a2d5d , 0x4d72abd8 , 0x1f3f271f , 0x927d1393 , 0x11ff006e ] )
+  , Mont::unsafe_make( [ 0xa25ad64d , 0x361f847d , 0x5f8f7971 , 0x767453b9 , 0x2473c03b , 0x30958ec1 , 0x945af5d0 , 0x0bad1683 ] )
+  , Mont::unsafe_make( [ 0xb8ad6b38 , 0x7868bd95 , 0x7fd14cb7 , 0x882801da , 0x24c9a4b2 , 0x5348ec31 , 0xbbfbbc23 , 0x0ff4759e ] )
+  , Mont::unsafe_make( [ 0xa86cd23b , 0x6905d9f6 , 0xfaef1f8a , 0x5e84eb3e , 0x6bed3606 , 0xc12b57dd , 0xe384fe69 , 0x17771be1 ] )
+  , Mont::unsafe_make( [ 0x97ac87b2 , 0x42d24b08 , 0xdb437afb , 0xf5d3bd75 , 0x66c8c221 , 0xdffa54a3 , 0xa5637334 , 0x06968a25 ] )
+  , Mont::unsafe_make( [ 0x49cd91ac , 0x59ac4d78 , 0x0d2beb1b , 0x6a8c63a3 , 0x25366237 , 0x291ebaad , 0xf1e1ad6b , 0x135de22d ] )
+  , Mont::unsafe_make( [ 0x6a43cc56 , 0x00fd947d , 0x01d01b40 , 0x41c0defc , 0x674dbfe4 , 0xa82480a0 , 0xed053b22 , 0x26373e53 ] )
+  , Mont::unsafe_make( [ 0xf8babdac , 0x7d95aae0 , 0xd050788c , 0xdf6a3f3b , 0x27901df4 , 0xe55b8413 , 0x40577218 , 0x1190b779 ] )
+  , Mont::unsafe_make( [ 0x78320981 , 0xbd77441b , 0x63a4ba70 , 0xa62d3425 , 0xeecb90fb , 0x5f878b37 , 0xe6d20386 , 0x0820d420 ] )
+  , Mont::unsafe_make( [ 0x1e11ecc2 , 0x0fa8249b , 0x1c39333c , 0x6c6a2362 , 0x8032486e , 0xca6fd2f4 , 0x5eb8ad5d , 0x02d83ea4 ] )
+  , Mont::unsafe_make( [ 0x4a5930da , 0xfa4c6163 , 0xb7346701 , 0xd8d2223e , 0xcb9aff3f , 0x03d36d86 , 0x81dc0201 , 0x164adb40 ] )
+  , Mont::unsafe_make( [ 0x211b7633 , 0xcf6fed27 , 0x5e6059c0 , 0x12199b02 , 0xcb2b0f8d , 0xa6c8fc57 , 0x723a6836 , 0x25507d2b ] )
+  , Mont::unsafe_make( [ 0x489ae96e , 0x84cc734f , 0x2612ac08 , 0x83fbc3c3 , 0x6aa63c7e , 0x2f287bb0 , 0x86e9adae , 0x2638bf37 ] )
+  , Mont::unsafe_make( [ 0x24a957f9 , 0x2d91c6b4 , 0x59b2820c , 0x6c5a3384 , 0xb4d6402d , 0x09a95d71 , 0xd09e7eb3 , 0x01b5395f ] )
+  , Mont::unsafe_make( [ 0x2714b47a , 0x3987b338 , 0x8501679c , 0xd287b5c3 , 0xad94a9b1 , 0x904e1d65 , 0x17d2af3d , 0x26f3fca0 ] )
+  , Mont::unsafe_make( [ 0xb92e791d , 0xe9b89d30 , 0xf762fc58 , 0xfed62d91 , 0xf1e28fbc , 0x632cc512 , 0xa9e5f7bd , 0x0cbcdec4 ] )
+  , Mont::unsafe_make( [ 0x72e5a635 , 0x3aead9c4 , 0xe86e433f , 0x70e2019f , 0xb94417b2 , 0x1c23b077 , 0xbc34b190 , 0x17702580 ] )
+  , Mont::unsafe_make( [ 0xb98042f6 , 0x81b4e8c4 , 0x12c80221 , 0xb6c63b72 , 0x4c45a0cc , 0x72cf6be2 , 0xc2eea513 , 0x2bf22513 ] )
+  , Mont::unsafe_make( [ 0x9aa5fe1c , 0x81695326 , 0x02dce0aa , 0xd08a23bb , 0x55847bab , 0x788c7ce1 , 0xf2062986 , 0x0e751701 ] )
+  , Mont::unsafe_make( [ 0x592c8611 , 0x887cb5e4 , 0x6e6d39ec , 0xf6923dd9 , 0x808cd6cc , 0x1b2f255e , 0xe2e5c4f3 , 0x1b135352 ] )
+  , Mont::unsafe_make( [ 0xbcc3c4f0 , 0x49506e94 , 0x1e8c7fe3 , 0x150b2d4e , 0x45bc3cbc , 0xad963828 , 0x67ab3bf1 , 0x175aefe9 ] )
+  , Mont::unsafe_make( [ 0xea0ef90b , 0x4b5229c7 , 0xf192a70c , 0xf6fd8c3f , 0x53c76d11 , 0xb7a3af2c , 0xb5ee4e7b , 0x1957fce1 ] )
+  , Mont::unsafe_make( [ 0x1f22c3b5 , 0xcb010b06 , 0x1f4d5c26 , 0x1b9a9d51 , 0x8ec7f3e0 , 0x5fc5d568 , 0xb145772a , 0x2b73f27a ] )
+  , Mont::unsafe_make( [ 0x1f1bf6ed , 0xaea531b5 , 0x7ab61211 , 0x04e8dd12 , 0x4bdb2d05 , 0x7ec20304 , 0xbcb55e00 , 0x03da703d ] )
+  , Mont::unsafe_make( [ 0x5c45a15f , 0xa1c10b46 , 0x29db5c27 , 0x2ed911b8 , 0xf96814f4 , 0x1393f703 , 0x9cc2b8cb , 0x1df3bdd4 ] )
+  , Mont::unsafe_make( [ 0xb2b0b03e , 0xa7392df1 , 0x7cb9927d , 0xabf92c8d , 0x3a5151fa , 0xacb023ed , 0xfa635321 , 0x16c84611 ] )
+  , Mont::unsafe_make( [ 0x9300acea , 0xc9c75186 , 0x5163d91f , 0xb55b09f0 , 0x0698a264 , 0x9c8cbd2a , 0xa1115eff , 0x14aa98b3 ] )
+  , Mont::unsafe_make( [ 0x3f50e707 , 0xedfb44a7 , 0xf8299650 , 0xe556d7dc , 0x1d7a3605 , 0xe1f43257 , 0x0cf194e5 , 0x01310e58 ] )
+  , Mont::unsafe_make( [ 0x6e92b786 , 0xa7713edd , 0x1094ef58 , 0xf7f53003 , 0xba66cded , 0xb3543b52 , 0xec02a727 , 0x2d420906 ] )
+  , Mont::unsafe_make( [ 0x08691cce , 0x9eb19941 , 0xeb875951 , 0x25ade1b8 , 0xf22c4049 , 0x7c04cab0 , 0xa9b1adcc , 0x17dba86f ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x34a1e9c8 , 0x9881fc57 , 0x53265f44 , 0xb48ca86b , 0xc6b48a6b , 0x142f304d , 0xdf4db141 , 0x066910ea ] )
+  , Mont::unsafe_make( [ 0xe441aad3 , 0xdd52a9ba , 0x8f12f9a1 , 0x914d0e44 , 0xeffbd535 , 0x7a8ecd6d , 0x53d7f6e5 , 0x19254e04 ] )
+  , Mont::unsafe_make( [ 0x4bf21328 , 0xa9b390f0 , 0xed0255f9 , 0x9a4c364a , 0xe68b17f0 , 0xdc051c37 , 0x0afe385d , 0x0dda7bb5 ] )
+  , Mont::unsafe_make( [ 0x953f8a6c , 0x8b310a60 , 0xdb4ade49 , 0x930d4b6b , 0x0011345b , 0x8ed51e98 , 0x170504f0 , 0x19916726 ] )
+  , Mont::unsafe_make( [ 0x5b9524b1 , 0x6670df06 , 0x82074bea , 0xa916459b , 0xee34c9af , 0xbc96c486 , 0x9fca397d , 0x001110e3 ] )
+  , Mont::unsafe_make( [ 0xaf618e35 , 0xe9c95df9 , 0xc6945edc , 0xa434a382 , 0xa5098d55 , 0x1b73df24 , 0x0214f7ef , 0x06c79ba8 ] )
+  , Mont::unsafe_make( [ 0xa0928287 , 0xaf83039e , 0x0fb310df , 0x9095b88e , 0x53001c54 , 0x94640c22 , 0x4f74eb73 , 0x1d5230df ] )
+  , Mont::unsafe_make( [ 0xfd5b0b20 , 0x3d2fe5f9 , 0x064e4d05 , 0x5dc3274b , 0xd5d090c2 , 0x2d87e84a , 0x8c3573a5 , 0x22b02a78 ] )
+  , Mont::unsafe_make( [ 0xe5538ca1 , 0xc7b2b115 , 0xfbc9cb22 , 0x92f4ab6e , 0xf1e76b5d , 0x7a15db9c , 0xfec1b700 , 0x0b24a519 ] )
+  , Mont::unsafe_make( [ 0xaf4f603f , 0x31875965 , 0x67ed7687 , 0x11ef4f60 , 0xf69269d4 , 0x7168da3e , 0x89dfdb4c , 0x07ebc2de ] )
+  , Mont::unsafe_make( [ 0x2a476fb6 , 0xd8648762 , 0xde351d57 , 0xbb46cea4 , 0xa2ae977a , 0x158c03cf , 0xe999cd93 , 0x305212a9 ] )
+  , Mont::unsafe_make( [ 0xeee5f372 , 0xebdca6c0 , 0x1245c0f3 , 0x6180609e , 0x2071ebba , 0x979fb285 , 0x7000bd31 , 0x02e48143 ] )
+  , Mont::unsafe_make( [ 0xc440497c , 0x7e497bc0 , 0xdbf3fb91 , 0x7a9c3387 , 0xea9807c5 , 0x0f598fbf , 0x144345dc , 0x29ad8e6a ] )
+  , Mont::unsafe_make( [ 0xe61876b0 , 0x2ae78891 , 0x052f1b4e , 0x1203cb48 , 0x38b67099 , 0xd7aa7eff , 0x1626b5db , 0x0fd856cd ] )
+  , Mont::unsafe_make( [ 0xc2955508 , 0x6b3fc933 , 0xa8e46e59 , 0x3cbd0a93 , 0xa99a4c67 , 0xeb282eee , 0x2485a8e1 , 0x0863c8ba ] )
+  , Mont::unsafe_make( [ 0x7a06aec9 , 0xc897de5f , 0x0c187c9d , 0x299439f4 , 0xff823b57 , 0x9a45dd52 , 0xbe158a64 , 0x14e0e286 ] )
+  , Mont::unsafe_make( [ 0xba23487a , 0x9faaa4fb , 0x03bf73c7 , 0xa316acf5 , 0x5da00c8d , 0x63dfcedf , 0xe430479a , 0x0a5a3fc7 ] )
+  , Mont::unsafe_make( [ 0xecd0cc4f , 0x24b46ff1 , 0x6113cdb7 , 0x2548c4dc , 0x7670e962 , 0x91fb2733 , 0xa21538ba , 0x01cf7d2a ] )
+  , Mont::unsafe_make( [ 0x078e31fe , 0x775e0ea5 , 0x6abad3c9 , 0x0141678d , 0x5f63fcc1 , 0x02ba471d , 0xc2846bd8 , 0x2bc1e12f ] )
+  , Mont::unsafe_make( [ 0xa0215249 , 0x54d3fdbc , 0xa2bc23e0 , 0x2419cbe0 , 0x7d416f75 , 0xe4c5f428 , 0x47ca8953 , 0x2cf932e6 ] )
+  , Mont::unsafe_make( [ 0x7e9b4111 , 0xa5443b29 , 0x4213fa73 , 0xfff431bd , 0x07ac6788 , 0xb8278e60 , 0x893c9cd4 , 0x14123b56 ] )
+  , Mont::unsafe_make( [ 0x4b834f1e , 0x4b4256db , 0xfbed62ab , 0x896f25b6 , 0x468be0b4 , 0x2f233fcb , 0x3ebf3940 , 0x143369d7 ] )
+  , Mont::unsafe_make( [ 0x17504d02 , 0xacc1ecb1 , 0xbde3c602 , 0x26c14d96 , 0x3c110f66 , 0xfbb88189 , 0xac893f79 , 0x14eea1d6 ] )
+  , Mont::unsafe_make( [ 0xf6e90f95 , 0x59ec6ab0 , 0x25b4dbf9 , 0xa9ebe910 , 0x0d90d275 , 0xb14f020b , 0xd31751ff , 0x198bdc5d ] )
+  , Mont::unsafe_make( [ 0x9a4fbc20 , 0xc6a6624d , 0x3c7f914a , 0xac40504c , 0x8be79443 , 0xd8077584 , 0x0ba9dd62 , 0x1adc5f3f ] )
+  , Mont::unsafe_make( [ 0x9fd255d5 , 0xd53f3dee , 0x6dd5e9dc , 0x8a5c125f , 0xd4de859c , 0x77555af4 , 0x35ad4f54 , 0x2a420285 ] )
+  , Mont::unsafe_make( [ 0xa3c56e27 , 0xafd73dec , 0xc8bc4b01 , 0x90bcf3b9 , 0xeec74c4b , 0xb27fe58b , 0xbf1eebf7 , 0x130ed3a1 ] )
+  , Mont::unsafe_make( [ 0x5112b43c , 0xb99e3f8c , 0xeb253036 , 0x5013afa7 , 0x45c22598 , 0x54116186 , 0x2ab44262 , 0x3059fe1e ] )
+  , Mont::unsafe_make( [ 0x70ec59ff , 0x1e0f83ba , 0x778569f7 , 0x35ae774a , 0x5e20d3fc , 0xcb874cca , 0xf4c0da2b , 0x07df4c06 ] )
+  , Mont::unsafe_make( [ 0xd4b02a9c , 0x00cf746f , 0x9c5ba6f5 , 0x87d76638 , 0x3fd884b2 , 0x2b6bd456 , 0xff9a55cc , 0x0a398b15 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xb45a166d , 0x58b38198 , 0x3dcc30fc , 0x155f4e03 , 0x0c6571c3 , 0x53ac039c , 0xac64eecf , 0x1c79b124 ] )
+  , Mont::unsafe_make( [ 0x4e9d94fe , 0x10314eaf , 0xb7fd0417 , 0x60d14931 , 0x187afa62 , 0x0985a3fd , 0x0f76cd03 , 0x0619be61 ] )
+  , Mont::unsafe_make( [ 0xb1513832 , 0x1a816cc1 , 0x27d8c95c , 0xf18a6ec3 , 0xd88fd068 , 0x46976900 , 0xbfb220e7 , 0x03175a0d ] )
+  , Mont::unsafe_make( [ 0x1b23bc34 , 0x3647ea3b , 0x2736522c , 0xd482821f , 0x1d021049 , 0x61769c1b , 0x28be4420 , 0x1b93e375 ] )
+  , Mont::unsafe_make( [ 0x47d5818d , 0xea5c6829 , 0x70c64644 , 0xdc897c4d , 0x06b28720 , 0x999ea599 , 0x2af65806 , 0x2117a212 ] )
+  , Mont::unsafe_make( [ 0x7a83de26 , 0x5ef50026 , 0xdd3231fe , 0xaf34939b , 0x48e0a1a4 , 0x976ab51b , 0x1a18c170 , 0x0a6a00e4 ] )
+  , Mont::unsafe_make( [ 0x14160774 , 0x794f8ad6 , 0xb0973b64 , 0xf28818b5 , 0x14c540f5 , 0xb80ecb40 , 0x3cd6bec1 , 0x1e508998 ] )
+  , Mont::unsafe_make( [ 0x3e29bea4 , 0x0ca4c842 , 0x4624f594 , 0x568116fa , 0x29b5f0fe , 0x4bb1aec6 , 0x0905f472 , 0x14f71f68 ] )
+  , Mont::unsafe_make( [ 0xbb926d26 , 0xf905539e , 0xa4f37447 , 0x16af986e , 0x886d0794 , 0xee81631d , 0x7178d4ed , 0x18998637 ] )
+  , Mont::unsafe_make( [ 0x71da4388 , 0x18063755 , 0xe42fb6e4 , 0xea1cecea , 0x10452d6b , 0x05af4388 , 0xebec347c , 0x0f1fa0cc ] )
+  , Mont::unsafe_make( [ 0xb834559f , 0x17cff506 , 0xc49f79cd , 0x36b091b6 , 0x99eab0bd , 0xa8579c3f , 0x03bec16b , 0x0aa6f2ab ] )
+  , Mont::unsafe_make( [ 0xf65fd9e5 , 0x4b76b915 , 0xdfafb79c , 0x788f5a04 , 0x17601b58 , 0x5f8c4604 , 0x91d78c0b , 0x094bac85 ] )
+  , Mont::unsafe_make( [ 0xb7d214ec , 0x294e78bf , 0x1c9343e8 , 0x3208832a , 0x79431f15 , 0x7c9dd2dd , 0xdf552127 , 0x0cec1866 ] )
+  , Mont::unsafe_make( [ 0x0fa431d4 , 0xe4719dbe , 0x98d68f35 , 0xa7088b1a , 0x011d20ec , 0xe3ce6b0a , 0x87dc583d , 0x2f4aa79b ] )
+  , Mont::unsafe_make( [ 0xc50284d6 , 0xaa25c6cb , 0xa9c6b6c0 , 0x838fd0da , 0x78b76470 , 0x37c9e7d7 , 0x93089cc6 , 0x187f3067 ] )
+  , Mont::unsafe_make( [ 0x26ec7811 , 0xa140b128 , 0xb8d85df3 , 0x890be9f5 , 0x422c6696 , 0x7167d39e , 0xd846e555 , 0x09641b9f ] )
+  , Mont::unsafe_make( [ 0x35e1d974 , 0xf9eff88d , 0x04be73f4 , 0xea5e2d1c , 0x3ff59d0a , 0xd7ae9842 , 0x3eaa976a , 0x073c258e ] )
+  , Mont::unsafe_make( [ 0x92f8016b , 0xb6b44a31 , 0x81db5f1a , 0x0d5cae6f , 0x9407c9fc , 0x9c5d0dd1 , 0x42978a21 , 0x19186125 ] )
+  , Mont::unsafe_make( [ 0x25194fa8 , 0x32ed0f22 , 0x5bc44b3f , 0x57d530e4 , 0x1c63f434 , 0x709d9e1e , 0x046b7e6c , 0x2b4bc0bf ] )
+  , Mont::unsafe_make( [ 0x7726340e , 0xa8cf72bf , 0x52cbb436 , 0xfc852f1a , 0xd73b6af5 , 0xe67961b8 , 0x67d142f5 , 0x25d5e1e8 ] )
+  , Mont::unsafe_make( [ 0x4cfb3d2c , 0xcc0d04b4 , 0xf0a7614d , 0xc66d9f08 , 0x9e60dbe5 , 0xf0b7ead4 , 0xe9476392 , 0x2b6159e2 ] )
+  , Mont::unsafe_make( [ 0x58709507 , 0xd94f17ae , 0x4c76df49 , 0x1bae9e7c , 0xdf5aa7e8 , 0xf2bdb8ee , 0x456959eb , 0x27d28fc7 ] )
+  , Mont::unsafe_make( [ 0xe833bc34 , 0x449d011e , 0x3f06283b , 0xa9a9d5c2 , 0x3df58833 , 0x46999520 , 0x470e1b83 , 0x22ef7e5b ] )
+  , Mont::unsafe_make( [ 0xbb9814a4 , 0x3d419bfc , 0x0f56f77b , 0x935672b8 , 0xa5e70b73 , 0xa6cddec4 , 0x70c681c0 , 0x1795aad2 ] )
+  , Mont::unsafe_make( [ 0x0a7218b1 , 0x1f22ab93 , 0x223526e8 , 0x83785efb , 0x0d0b2826 , 0x99f23525 , 0x21acae27 , 0x255cfd2f ] )
+  , Mont::unsafe_make( [ 0xf381b77d , 0xe11a4585 , 0x7f828310 , 0x239fbd3c , 0xf2fb1d81 , 0x1ca8f94c , 0x84d31d43 , 0x0a7185d4 ] )
+  , Mont::unsafe_make( [ 0x03173153 , 0x2e9050a8 , 0xb1e5922b , 0x95ee56f0 , 0x742ef27d , 0x50b01691 , 0xd73eb59d , 0x0fd0ebb6 ] )
+  , Mont::unsafe_make( [ 0xc2625e80 , 0x46cf9fdc , 0x9b6c5c43 , 0x306fdc64 , 0xa1303e9f , 0x0dac00b6 , 0xc3052c7a , 0x0c3921f0 ] )
+  , Mont::unsafe_make( [ 0xb9552418 , 0xd0d857e6 , 0xfc375179 , 0x0ceed832 , 0x1e60ed34 , 0x1bcda950 , 0xd9744877 , 0x2210758b ] )
+  , Mont::unsafe_make( [ 0xe88dc1df , 0xed814f8c , 0x35922f98 , 0x2d70275e , 0x426e99aa , 0xfb03ca61 , 0x5950060e , 0x08bcff55 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x94a9a93f , 0xf2a7fda6 , 0xb26ac178 , 0xd515fccd , 0xfd749578 , 0xea86df52 , 0x61140a46 , 0x2892fabb ] )
+  , Mont::unsafe_make( [ 0x94bc23d7 , 0x0f9d0bdd , 0x42e5078a , 0x8b6baf6a , 0x984eaad3 , 0x54eaadb2 , 0x48b72695 , 0x1670c8d5 ] )
+  , Mont::unsafe_make( [ 0x28f8b32e , 0xc2dc40d2 , 0xb18cf0e8 , 0x29ba6391 , 0x5780378d , 0xc65e072e , 0xc12cb8b6 , 0x26b040c3 ] )
+  , Mont::unsafe_make( [ 0x03363a08 , 0xe017b2ab , 0x8478f55a , 0xf58d2a32 , 0xea32ea4a , 0xcd7e2c56 , 0x5295b87b , 0x0a4c2e25 ] )
+  , Mont::unsafe_make( [ 0xed7482e7 , 0x7227d84e , 0x11a568ef , 0xee2b30bf , 0x18df2499 , 0x9a3aa60b , 0x6dc22cce , 0x0db8d5a8 ] )
+  , Mont::unsafe_make( [ 0xac75bb90 , 0x487e72f8 , 0xf9a38889 , 0x07c77ab5 , 0x751f9954 , 0x55b2e0c0 , 0xac51d705 , 0x1014ee0a ] )
+  , Mont::unsafe_make( [ 0x136bef04 , 0xb39a5a84 , 0xdfab8b25 , 0xb71f7914 , 0x948e4285 , 0xf2440fda , 0x505648db , 0x099feafc ] )
+  , Mont::unsafe_make( [ 0x7b53cd0a , 0x3c72699b , 0xbb12d522 , 0x83a94bf4 , 0x2c1f9049 , 0xe654c423 , 0xf15a5016 , 0x250c7c5e ] )
+  , Mont::unsafe_make( [ 0x07c6fac4 , 0x0a9fa773 , 0x713acf19 , 0xb249765a , 0xad1411f3 , 0x00b5e8a5 , 0x3b70e5b9 , 0x2401e50c ] )
+  , Mont::unsafe_make( [ 0x4bc5fa2b , 0x9df64664 , 0xce026363 , 0x6092999d , 0x4b8e9349 , 0x6ec72fbe , 0x58e1bbcb , 0x186aa5af ] )
+  , Mont::unsafe_make( [ 0x6a05756b , 0x281506a4 , 0x96c5de4c , 0x0c43c8b2 , 0xcdfbe17b , 0x4498856c , 0x7bf21f24 , 0x0c2add5c ] )
+  , Mont::unsafe_make( [ 0x1e13259a , 0xff53c205 , 0x2ebe322a , 0xd1dbc33d , 0x6aa09c7b , 0xfec123fc , 0x825d4dbf , 0x112a7157 ] )
+  , Mont::unsafe_make( [ 0xb314a5f6 , 0x7b3b795b , 0xdb9583be , 0x81f1dea9 , 0x2a1dd77f , 0x331031fa , 0xc0e9c35b , 0x07ca6c25 ] )
+  , Mont::unsafe_make( [ 0x3d50747f , 0x3b5a1149 , 0x30e4da25 , 0xfc816e0f , 0x49cbe9b8 , 0xecff3449 , 0x01eea66a , 0x1e97bbae ] )
+  , Mont::unsafe_make( [ 0xde303882 , 0x4bee596b , 0x9f6ddf1f , 0x841c0481 , 0xd05163ca , 0x56a66151 , 0x7d531816 , 0x1c5cf7ff ] )
+  , Mont::unsafe_make( [ 0xc46105a5 , 0x47ec54a8 , 0x74140868 , 0x812434a4 , 0xaaacde79 , 0x0fc3b53e , 0x9c95c194 , 0x0eed9037 ] )
+  , Mont::unsafe_make( [ 0xe352c421 , 0x47a08654 , 0x97900eab , 0xdfc213c6 , 0x641338cb , 0x5b9beeca , 0x9b8cd687 , 0x14793b5a ] )
+  , Mont::unsafe_make( [ 0xb1933f61 , 0xfa49e864 , 0xa50a50f5 , 0x5c070b73 , 0x9028e3c0 , 0x3e4183a9 , 0x29002f35 , 0x14b53b27 ] )
+  , Mont::unsafe_make( [ 0x03ed2eb7 , 0x972495ef , 0xea684f47 , 0xb54522ad , 0x1875b6b0 , 0x8e88b868 , 0x380c7285 , 0x22bec20f ] )
+  , Mont::unsafe_make( [ 0x27588200 , 0x0729ef1d , 0x0c1f80cb , 0x2c09626d , 0x59e11385 , 0xc16b3069 , 0x95c844a0 , 0x2e5c2539 ] )
+  , Mont::unsafe_make( [ 0xd948fd1c , 0x1fa80f95 , 0xb500254f , 0xcd05f696 , 0xe328c674 , 0xa3698fe9 , 0xf9bef741 , 0x2523a2cc ] )
+  , Mont::unsafe_make( [ 0x5e072cc7 , 0x875ea1a0 , 0xdd438ed9 , 0x0b50a98a , 0x8a4a7fe4 , 0x280ff737 , 0x7113c886 , 0x0641718e ] )
+  , Mont::unsafe_make( [ 0xeeca02cb , 0x0405286e , 0x12208370 , 0x5d790353 , 0x177c2fe9 , 0x60b4bba0 , 0xb0761a81 , 0x2b5d0185 ] )
+  , Mont::unsafe_make( [ 0x973c48fc , 0x68544eb0 , 0x461cf4b2 , 0xb8d1240d , 0xe069d26b , 0x1f5c786c , 0x44d5f611 , 0x12918d96 ] )
+  , Mont::unsafe_make( [ 0x8883d112 , 0xc311741b , 0x26b1c093 , 0xe5a281d7 , 0x0c6efd41 , 0x76b0a611 , 0x17fe8c77 , 0x09ccfe3a ] )
+  , Mont::unsafe_make( [ 0xf6bc475c , 0x9b86ee39 , 0x760d4d3b , 0x29ee836a , 0xcb7c7b9e , 0x42826de1 , 0x2fcf4050 , 0x3046de0f ] )
+  , Mont::unsafe_make( [ 0xbc4e7d5b , 0xf394b53b , 0x93d2610b , 0xea665ac1 , 0xeb274723 , 0x251f3e6a , 0xc245c784 , 0x166eb07c ] )
+  , Mont::unsafe_make( [ 0x83fe8153 , 0x4ced2897 , 0x2ff65dd5 , 0x4c6cdd4e , 0xa36310f1 , 0xa9456658 , 0xe69ebd8c , 0x03724756 ] )
+  , Mont::unsafe_make( [ 0x545ad5bd , 0x75c46080 , 0x346346da , 0x731d900b , 0xe7186718 , 0x80d98011 , 0x2a9f730a , 0x1de8693b ] )
+  , Mont::unsafe_make( [ 0xf6ab2e2c , 0x4f36bc85 , 0x1a0aaa17 , 0xef98af17 , 0x680f40b3 , 0x48f7d923 , 0xbe372107 , 0x2b84d9e9 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xed75c7a1 , 0x065a69e1 , 0x5395158f , 0x8d54d8d2 , 0x421c847d , 0xa607b970 , 0x7e597284 , 0x203a0c7a ] )
+  , Mont::unsafe_make( [ 0xa6b8a643 , 0x8a2ffd7e , 0xd88dfa05 , 0x8a4b77ab , 0x17247a30 , 0xc01e67d8 , 0x3a76d48d , 0x289ff515 ] )
+  , Mont::unsafe_make( [ 0x687e0529 , 0x31017f90 , 0xbe1cd040 , 0x0a04e4e4 , 0x7a37d783 , 0x0f6d8d53 , 0x438684be , 0x257709d3 ] )
+  , Mont::unsafe_make( [ 0xd1ac5fca , 0x55b56fcd , 0x28200b68 , 0x9392b045 , 0xe10aef43 , 0x0d2f5985 , 0x11c02017 , 0x0cab1524 ] )
+  , Mont::unsafe_make( [ 0x942ca41b , 0xfe3cc5b6 , 0x85a78e24 , 0x14f39471 , 0x7ecd2b86 , 0x38247cf5 , 0xa782eada , 0x106fb18d ] )
+  , Mont::unsafe_make( [ 0x9048b139 , 0xeef72b92 , 0xe7dd3ff3 , 0x2cc07db9 , 0x40a6e475 , 0xea1846cc , 0x6938e93b , 0x2cef6153 ] )
+  , Mont::unsafe_make( [ 0x8fca2f52 , 0xd507ad35 , 0x79342240 , 0x7f352ee5 , 0xdeda9a3c , 0x8e3a86fd , 0xa03cc11a , 0x0f0fd604 ] )
+  , Mont::unsafe_make( [ 0xe5f6680a , 0x89b65b54 , 0x8da81769 , 0x4b3bcb8f , 0x3b15b7a7 , 0xa191af8f , 0xb1b830ba , 0x2b6790c5 ] )
+  , Mont::unsafe_make( [ 0xa24bdaac , 0x15277b6e , 0x3182c9d1 , 0x04a4cfb4 , 0x48218c50 , 0xbe63551d , 0x1df35212 , 0x2c28c413 ] )
+  , Mont::unsafe_make( [ 0x28a7c711 , 0x9e2941ab , 0xbb115289 , 0x5070753b , 0x1c78cd31 , 0x82f649d9 , 0x3eaa915f , 0x1c47e577 ] )
+  , Mont::unsafe_make( [ 0x965866cc , 0xcf43b560 , 0xcb32b41e , 0x11b3986f , 0xd73980f0 , 0x852ddec2 , 0xbcc1fb4b , 0x09bf2026 ] )
+  , Mont::unsafe_make( [ 0x65074592 , 0x182716fb , 0x9a8c812d , 0xed004663 , 0x0ca19891 , 0x103241c5 , 0x6d6a3fdd , 0x2ec6831c ] )
+  , Mont::unsafe_make( [ 0x539489bb , 0x50a6e1b5 , 0xaaa10aa0 , 0xdbab73ea , 0xcfb1f918 , 0x8c8da5de , 0x887ce549 , 0x3055cc93 ] )
+  , Mont::unsafe_make( [ 0xe5fe10f1 , 0x89fd7ba7 , 0x2f58b11e , 0xdb38c48d , 0x3371d1f9 , 0x159410e4 , 0x1f784775 , 0x08169a06 ] )
+  , Mont::unsafe_make( [ 0x8088a452 , 0x796ca532 , 0xbd252d47 , 0x135acae4 , 0x21f9a798 , 0x6afb2e03 , 0x16b09c64 , 0x0092732a ] )
+  , Mont::unsafe_make( [ 0x4bbd06c8 , 0xe959c3a3 , 0x5e2295ab , 0xb11d7b44 , 0x335a97b3 , 0x049e3ffd , 0xf03fdb51 , 0x08973a48 ] )
+  , Mont::unsafe_make( [ 0x6c981161 , 0x730d1c2b , 0x7a5d07f1 , 0x02d028b1 , 0x3455ad2b , 0x9c4a35a1 , 0x76834163 , 0x0fc92ff5 ] )
+  , Mont::unsafe_make( [ 0x1b854b2b , 0xaef37254 , 0x25d3a138 , 0x82c469f2 , 0xc0441649 , 0x7403b36d , 0x70cd0863 , 0x110cd275 ] )
+  , Mont::unsafe_make( [ 0x454f883d , 0xa389ea7f , 0x1791dd00 , 0x1f9a7098 , 0xba57ddf3 , 0xc21f9d67 , 0xb50438a9 , 0x073967aa ] )
+  , Mont::unsafe_make( [ 0x4a54950d , 0x3383f20e , 0x0a850926 , 0xf0a790ba , 0xea34938e , 0x769730f0 , 0xe1c0f82c , 0x04fa2598 ] )
+  , Mont::unsafe_make( [ 0x7a0939cf , 0x00359659 , 0x087e7f6b , 0xc05e826c , 0x23b0bc95 , 0xa0e09078 , 0x8dea7776 , 0x1fa2bfb8 ] )
+  , Mont::unsafe_make( [ 0x9021f40c , 0x569f478f , 0x4be17c0f , 0xc5637772 , 0x3219b7ae , 0x30dafd18 , 0x62695f20 , 0x11f4447d ] )
+  , Mont::unsafe_make( [ 0x6269a689 , 0x975ceaec , 0x049c4a34 , 0x789dd8aa , 0x08be8896 , 0x70f39dac , 0x96415f4a , 0x12a10457 ] )
+  , Mont::unsafe_make( [ 0x62d9b8e6 , 0x0013f918 , 0x16ecb0ce , 0x59daaf3c , 0xf5ebc00b , 0xddc72d1a , 0x41acd4ef , 0x12f0c84e ] )
+  , Mont::unsafe_make( [ 0xd8d09d4b , 0xdbecdb92 , 0xad676cf6 , 0xb228cd29 , 0x83318bf0 , 0x4c44798e , 0x98518595 , 0x18d116a1 ] )
+  , Mont::unsafe_make( [ 0x735e92fb , 0xa4d790e5 , 0x8c45f58f , 0x2eda4afe , 0x9fe121d9 , 0x312927ae , 0xa8280535 , 0x0e090530 ] )
+  , Mont::unsafe_make( [ 0x7146a7a3 , 0x7eebf961 , 0xf690df36 , 0x9443a323 , 0x5b7685f1 , 0x3cdf1ae5 , 0xfb717b53 , 0x1a774e67 ] )
+  , Mont::unsafe_make( [ 0xdac86361 , 0xca963474 , 0x1a80208d , 0x5f10f490 , 0xfb927d4f , 0xd7015240 , 0x90e928ca , 0x13a18bec ] )
+  , Mont::unsafe_make( [ 0xfe5d7a1b , 0xfca37e82 , 0xd27a8d4a , 0x6e710d61 , 0x5d8b09d0 , 0xacc38dac , 0x5b609060 , 0x213e4b97 ] )
+  , Mont::unsafe_make( [ 0xcd1fbea7 , 0xa05b2c99 , 0xf51bdec0 , 0xc376b3f6 , 0x6f9534a0 , 0x5698a5f0 , 0x10932578 , 0x136cf60d ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xe53150f3 , 0xbe4a7ea8 , 0x882a9769 , 0x0c4e8e6f , 0xd64c672d , 0x32206095 , 0x642c167f , 0x041e85a6 ] )
+  , Mont::unsafe_make( [ 0xef486d10 , 0xdcaf4d07 , 0x62788496 , 0x6fd62573 , 0x7d36365a , 0x23c0241a , 0x39547560 , 0x0afe0800 ] )
+  , Mont::unsafe_make( [ 0xb6b65e97 , 0x134fb8fe , 0xb420aacf , 0x8dd81914 , 0xc42c0d2d , 0x9322f124 , 0x9058f421 , 0x24d24a8b ] )
+  , Mont::unsafe_make( [ 0x43298790 , 0x5b6592a2 , 0xbfaeb754 , 0x17a1e96c , 0xb2a8d7d6 , 0x6b01b2c5 , 0xa7a4339a , 0x2ef90735 ] )
+  , Mont::unsafe_make( [ 0xa27306c9 , 0x07ce0029 , 0xdaf51282 , 0xdd9759f4 , 0x4384080f , 0x9ff0e871 , 0x7dc66d08 , 0x15e0efe7 ] )
+  , Mont::unsafe_make( [ 0x37848912 , 0x5db4e875 , 0xd802fe91 , 0xe5b7c304 , 0x2428bbac , 0x25f8f67f , 0xf9c6cefe , 0x0aa71f5b ] )
+  , Mont::unsafe_make( [ 0x4feb7490 , 0x97e18ecd , 0x154dd5c2 , 0xebccca8d , 0xc231283d , 0xf82d2466 , 0x17f2f18f , 0x0a53c62c ] )
+  , Mont::unsafe_make( [ 0xe0f90896 , 0xe9a0e595 , 0x1a0c0a73 , 0xec3ef1a2 , 0xc63419cf , 0x505e50f5 , 0x84b63f70 , 0x122a0295 ] )
+  , Mont::unsafe_make( [ 0xc3cefaf8 , 0xa5c1f757 , 0x4fe48867 , 0xbeb413e7 , 0x71ead307 , 0x0b175ede , 0x6b45ef85 , 0x0d36c91f ] )
+  , Mont::unsafe_make( [ 0x55d62799 , 0x76e1e2cd , 0x3506c26a , 0xa2eb36a6 , 0xf1a4cab6 , 0x6e8a748c , 0xa8980615 , 0x201a17a5 ] )
+  , Mont::unsafe_make( [ 0x76f4dc4a , 0x70b9cb6c , 0xf2f6c438 , 0x2abe69fb , 0x2e57013d , 0xdb5cbc94 , 0x0e8289a5 , 0x16b33bfd ] )
+  , Mont::unsafe_make( [ 0xaac007c1 , 0x26b82621 , 0x439bbede , 0xbe437638 , 0x3063def2 , 0xb9688553 , 0x3da53cd1 , 0x24917734 ] )
+  , Mont::unsafe_make( [ 0x5e392ea3 , 0xc7f66d0a , 0x8cf710c7 , 0xc8003c9d , 0xb54c1c2b , 0x1a05c398 , 0x1785edc0 , 0x05e2e495 ] )
+  , Mont::unsafe_make( [ 0x4e62259a , 0x3a752657 , 0x259604c5 , 0x91d74040 , 0x773c2dc9 , 0x4517a566 , 0x3e94a678 , 0x003f6e8b ] )
+  , Mont::unsafe_make( [ 0x78892fc2 , 0x95ae5257 , 0x2d0c886e , 0x209f8694 , 0x8838eed5 , 0x7128c85b , 0x1b6316c8 , 0x17353e72 ] )
+  , Mont::unsafe_make( [ 0x35b60038 , 0xb383500c , 0x587cdd53 , 0xa2032b36 , 0xcbaaf96e , 0x248ee590 , 0x76a99da7 , 0x03df9f5d ] )
+  , Mont::unsafe_make( [ 0xd700a62d , 0xd69e1d2d , 0x807c2524 , 0xd0e78300 , 0xda6bde9d , 0x328e85e5 , 0xf66f2a6e , 0x2fe126c2 ] )
+  , Mont::unsafe_make( [ 0xf1475b6f , 0x3b9d1c6a , 0x722399b5 , 0xfe9dbbe1 , 0x88038b1f , 0x4dfaeb00 , 0xf5c0d08f , 0x05266d63 ] )
+  , Mont::unsafe_make( [ 0x0cf9eb68 , 0x222b1916 , 0x6c257c2e , 0x8261b3f7 , 0x0d874250 , 0x7408e210 , 0x592410b8 , 0x0b68cc16 ] )
+  , Mont::unsafe_make( [ 0xc4cd3f04 , 0x1e4aca37 , 0x5d057568 , 0x08b05d12 , 0x4a657142 , 0xc2093acd , 0xdeb23ead , 0x0a0724b5 ] )
+  , Mont::unsafe_make( [ 0x7853de06 , 0xcf74bc52 , 0xb9b04c10 , 0x9cfbbd43 , 0x00e2bc64 , 0x683a9138 , 0x142862bd , 0x0d2a2088 ] )
+  , Mont::unsafe_make( [ 0xcc0de2a4 , 0xb0023849 , 0x5d6e8a75 , 0xa4e5cea9 , 0x43007416 , 0x76de18c7 , 0xcaaaaf62 , 0x0e468e8d ] )
+  , Mont::unsafe_make( [ 0x9225af9a , 0x65d1082a , 0x4f9980b1 , 0x226f2b61 , 0x63ced64d , 0x33fd0960 , 0x0d598c05 , 0x17ad9cbb ] )
+  , Mont::unsafe_make( [ 0x16f1f7fa , 0xe56e825e , 0xe32dd6ea , 0x4285a915 , 0x80b23a64 , 0x41711023 , 0x6d7566df , 0x10ff9ad0 ] )
+  , Mont::unsafe_make( [ 0x08e0af71 , 0x787b15a6 , 0xf2e0f136 , 0x9f55c09d , 0x28b7a4bd , 0x0dc40237 , 0xf115daad , 0x004d3f38 ] )
+  , Mont::unsafe_make( [ 0x3c600a43 , 0xaee81d1c , 0x9eb271e0 , 0x924bd494 , 0xfab7d4e8 , 0xfbcdeda1 , 0x54ce223a , 0x280e627b ] )
+  , Mont::unsafe_make( [ 0x6530fde5 , 0x83485732 , 0xa4a25ae4 , 0x1b1d6b7f , 0xe4005f14 , 0xff09b2cc , 0x7e4cb6a4 , 0x24c72c79 ] )
+  , Mont::unsafe_make( [ 0xd28731f4 , 0x5f50853e , 0x522cec1b , 0x6e0d5013 , 0xbfcb45b4 , 0x1e6fa9bc , 0xd195ba39 , 0x2595642a ] )
+  , Mont::unsafe_make( [ 0x8cc5849c , 0x1c164399 , 0x625d816a , 0xe34eeb98 , 0x09507e22 , 0x582dbe87 , 0x9d6f28e7 , 0x0a8d5046 ] )
+  , Mont::unsafe_make( [ 0x824b3d58 , 0x881400ef , 0x2c71ec8f , 0x601dfb29 , 0x68415546 , 0x87c55f6e , 0x9c756047 , 0x28175a92 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xe02459f5 , 0xfb6243e8 , 0x84fe7742 , 0x28fab18f , 0x34fcea2f , 0x63dec431 , 0xb1e9dcdc , 0x074eadcb ] )
+  , Mont::unsafe_make( [ 0x0277d919 , 0x884d1007 , 0x941230c9 , 0xf0a7033a , 0x0f1133ed , 0x1433b514 , 0xdf4d29cc , 0x1978c4e2 ] )
+  , Mont::unsafe_make( [ 0xc79f6eec , 0x7e10a604 , 0x38498796 , 0x2d8e518d , 0x6ff304a1 , 0x1ceb8577 , 0x0b66f734 , 0x158d5b0e ] )
+  , Mont::unsafe_make( [ 0x6f3d0f5c , 0x528fbb6c , 0xaf97831f , 0x04b189f8 , 0x9541d154 , 0x1f18c5ce , 0x799e6e80 , 0x1b1790bc ] )
+  , Mont::unsafe_make( [ 0xcb560b7a , 0xf1fdcf5a , 0xc1c8f3b0 , 0xc23be7a9 , 0x36464ac6 , 0xd2f486a6 , 0x8a3c5263 , 0x087bbbbd ] )
+  , Mont::unsafe_make( [ 0xef393ee6 , 0x9594b92c , 0x7dc2edde , 0x22d2958c , 0x92525c18 , 0x68614ed2 , 0x12f0840e , 0x1aa1fae5 ] )
+  , Mont::unsafe_make( [ 0xc8d38d22 , 0x6e4d272a , 0xf7b90990 , 0x9b5ae1dc , 0x7df00dd2 , 0x91c6f914 , 0xa07a3bf2 , 0x0ee7e8c3 ] )
+  , Mont::unsafe_make( [ 0x6482d0fe , 0x6790a0a3 , 0xee196b72 , 0x8f812c62 , 0x62e41861 , 0x507d30ad , 0x7a63a650 , 0x2b76b805 ] )
+  , Mont::unsafe_make( [ 0x471ca53e , 0x5c09e042 , 0x7371aeb8 , 0x2b5b3535 , 0xece43b42 , 0x7c5d4da4 , 0xf4b94f05 , 0x17696940 ] )
+  , Mont::unsafe_make( [ 0x31da3892 , 0x0ed6482b , 0x88a0c272 , 0x4e6c741b , 0xa8cac74f , 0xfc163f5d , 0x5dd87d8e , 0x09756f73 ] )
+  , Mont::unsafe_make( [ 0xc4139763 , 0x74741591 , 0xc87b0012 , 0xc5d74a48 , 0x846c8076 , 0xe6dc9a95 , 0xf1ddf58a , 0x2eefc0fe ] )
+  , Mont::unsafe_make( [ 0xb788df94 , 0x212c8c60 , 0xb3bc0f1d , 0xdca5170e , 0xbcfc3d67 , 0xf5cf7d4b , 0x5b63a02f , 0x29925708 ] )
+  , Mont::unsafe_make( [ 0xa0aba308 , 0x7930866c , 0x20aab622 , 0xb1942b9b , 0x1608c235 , 0xe2e2fb17 , 0xfa0295d1 , 0x2379aa66 ] )
+  , Mont::unsafe_make( [ 0xefaa08e5 , 0x06c05f75 , 0x38365b9e , 0x2277f54d , 0x1803b311 , 0xf8863263 , 0x7d1daef4 , 0x0121a69f ] )
+  , Mont::unsafe_make( [ 0x3c630c4b , 0xa14fde69 , 0x55539592 , 0x8a329a45 , 0xfdfeff2e , 0xa38bcde6 , 0xbbe7fb46 , 0x0ad59940 ] )
+  , Mont::unsafe_make( [ 0xe05e3132 , 0xa4638c49 , 0xf8d99ab1 , 0xf569277b , 0x7f6d4d98 , 0x399b365b , 0x1beb8173 , 0x0a00dcd8 ] )
+  , Mont::unsafe_make( [ 0x15473d68 , 0x27677472 , 0x35c6164a , 0x650f20c6 , 0x6437219f , 0x6a38d6be , 0x56ad9626 , 0x1b829519 ] )
+  , Mont::unsafe_make( [ 0x900636e2 , 0x76751bb0 , 0xfa835013 , 0xf3a98163 , 0xabb821c5 , 0x94629afa , 0x98479c1c , 0x0acfa3b4 ] )
+  , Mont::unsafe_make( [ 0xaf51a935 , 0x7e8bd5fc , 0x1c5fee16 , 0x46c5192a , 0x09d8f834 , 0xe70368a1 , 0x621d038b , 0x1d8fa7b9 ] )
+  , Mont::unsafe_make( [ 0xe0bdcda3 , 0x6c7a70c1 , 0x98039682 , 0x50507741 , 0x1b70e614 , 0x5de1d1ec , 0x4ba42611 , 0x187444be ] )
+  , Mont::unsafe_make( [ 0xcca4dac4 , 0x8278a15c , 0x64c80d92 , 0x386d4b60 , 0x8b4f3f6a , 0xbf94dcd9 , 0x5f55daae , 0x0c6816b4 ] )
+  , Mont::unsafe_make( [ 0x859c788c , 0x28af8ce8 , 0x56390f89 , 0xfe62332e , 0xf8a1ddbb , 0x0739f10f , 0x98144d6e , 0x17f9d29c ] )
+  , Mont::unsafe_make( [ 0x49575da2 , 0xccd79e71 , 0x40eb2870 , 0x25910fab , 0x450fbd1c , 0xf43f6ade , 0x9952c5b9 , 0x238efd29 ] )
+  , Mont::unsafe_make( [ 0x3bbe9bc5 , 0xe6f2e2d5 , 0x25053e1e , 0x22d21b38 , 0xe8d21776 , 0xd5d47086 , 0xa26ecad0 , 0x0b439edb ] )
+  , Mont::unsafe_make( [ 0x39100415 , 0x7d505ff5 , 0xd2f7583e , 0xd7087d9b , 0x5cb5d181 , 0x680028bb , 0x20489405 , 0x1172d958 ] )
+  , Mont::unsafe_make( [ 0x94c77983 , 0x4a9304c7 , 0x57bb3d11 , 0xf53618a1 , 0x7db24a83 , 0x35b191bb , 0x05acb106 , 0x071733fc ] )
+  , Mont::unsafe_make( [ 0xd8fe04ae , 0x1966302f , 0x6730a121 , 0x37076df2 , 0xb25f192a , 0x3e9d9b8b , 0xe556933a , 0x08b85755 ] )
+  , Mont::unsafe_make( [ 0x00b726fd , 0x80da52c3 , 0x3d0f142f , 0xc902d203 , 0x9abe0b48 , 0xa2e76240 , 0xbe5cd89b , 0x2132a53b ] )
+  , Mont::unsafe_make( [ 0x78c852f4 , 0xce8da223 , 0xce87d2d2 , 0x5a264908 , 0xea1f9f98 , 0xd376fd32 , 0x5f15ee40 , 0x1c4e089b ] )
+  , Mont::unsafe_make( [ 0xf4ae8ffd , 0x9275e826 , 0xb0919b12 , 0x67f20f63 , 0xb409cc5d , 0xb24d8f79 , 0x9d5a543d , 0x2b173ef0 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x852da29d , 0x51f8cfdb , 0xd184fab8 , 0x3d2edb2a , 0x1f785255 , 0x0648060b , 0xafedd117 , 0x1ec68a79 ] )
+  , Mont::unsafe_make( [ 0x4ab6f5f3 , 0x6aa37eeb , 0x51efa1b0 , 0xa3612490 , 0xda475f79 , 0x1e84a0da , 0x98c617c0 , 0x270b9df2 ] )
+  , Mont::unsafe_make( [ 0xe63eb8ba , 0x7c1292c9 , 0xfb222577 , 0x61e5e094 , 0x6aa1d8fc , 0x420e5e6e , 0x027e38b8 , 0x018cd5c9 ] )
+  , Mont::unsafe_make( [ 0x4d7fdc2a , 0x2dc4d659 , 0x80b1d03f , 0x619824d2 , 0xcfd66816 , 0x7915eafc , 0x20f75d1b , 0x02601d2b ] )
+  , Mont::unsafe_make( [ 0xddc1bb16 , 0x9e638575 , 0x4464c346 , 0x3d69c058 , 0x331b6b8b , 0x66d1bb50 , 0x9c30cfca , 0x03f9feee ] )
+  , Mont::unsafe_make( [ 0x000e21ee , 0x364263e9 , 0x7930d663 , 0x7a7c60ad , 0x85fe1cd9 , 0x6db65dd9 , 0x8206e3ef , 0x1b3ba09e ] )
+  , Mont::unsafe_make( [ 0xfeafb736 , 0xcd1b3397 , 0xcd659f8a , 0x1cdeea4a , 0x62e394bc , 0xcc9f87ce , 0xb135714d , 0x09b0ec13 ] )
+  , Mont::unsafe_make( [ 0x34c746d1 , 0x4058e7e7 , 0xd64b3f9e , 0xeece1a8f , 0xad7ca6b6 , 0x91b9de73 , 0xedcb0e75 , 0x00f28475 ] )
+  , Mont::unsafe_make( [ 0x08479c9a , 0xdaeaa478 , 0x25a10819 , 0xa033914b , 0x03a50f43 , 0x4aff9bc4 , 0x3ddafeb8 , 0x019d5277 ] )
+  , Mont::unsafe_make( [ 0xbd5c95a6 , 0x9b44b360 , 0xdbb11973 , 0x033a604d , 0xe2d378fb , 0x00dd258e , 0x8b23de6a , 0x1b2dc910 ] )
+  , Mont::unsafe_make( [ 0x16dbc43e , 0xe3899539 , 0x9bbe3627 , 0x319c2f41 , 0x1e5d6629 , 0x84379d1d , 0xca7753ef , 0x0b8ce75b ] )
+  , Mont::unsafe_make( [ 0xaec1bd35 , 0xdd8264f5 , 0xb8b66aec , 0xc4640a3f , 0x50e94045 , 0x24d6b818 , 0x85b39fcb , 0x04ba7203 ] )
+  , Mont::unsafe_make( [ 0x55de3bef , 0x26cc0e7b , 0x0a1289b7 , 0x5c636328 , 0x077e11d1 , 0xc14f4452 , 0x1c970983 , 0x271263cb ] )
+  , Mont::unsafe_make( [ 0xa55b6520 , 0x53f4a385 , 0x1d43ad43 , 0x242f92dd , 0x39dad410 , 0xa4c6a1fa , 0x83a39061 , 0x1195d2bf ] )
+  , Mont::unsafe_make( [ 0xc4fa3de8 , 0xc080efc6 , 0xda620510 , 0xbee0632d , 0x53da0c58 , 0xab6930d5 , 0x19eabc60 , 0x1d8c21fe ] )
+  , Mont::unsafe_make( [ 0x48c474f1 , 0x1e2691fc , 0x013c51ed , 0x572da373 , 0xfa3429a6 , 0x2ecc3842 , 0x27c8f440 , 0x1bdfb59a ] )
+  , Mont::unsafe_make( [ 0x014e0620 , 0x6ed75496 , 0xcd58fe99 , 0xd36157ae , 0x65761f80 , 0x36fbb37a , 0x21267f99 , 0x05b2032f ] )
+  , Mont::unsafe_make( [ 0x79e452fd , 0x9a66a07a , 0x0c9740bc , 0xc8e207c4 , 0x8af5b41b , 0xabfe48cc , 0x0663d5f3 , 0x1627293a ] )
+  , Mont::unsafe_make( [ 0x54685ecf , 0x5628f327 , 0x40cc89aa , 0xbd282bce , 0xb67166f2 , 0xb9382633 , 0xff2d3d2b , 0x025c926e ] )
+  , Mont::unsafe_make( [ 0xba407cc1 , 0x2d8df770 , 0x455ff79c , 0xe80d055b , 0x52f12ecf , 0x50396e71 , 0x9d96292e , 0x077bb727 ] )
+  , Mont::unsafe_make( [ 0xef7feea5 , 0xcaf66bc7 , 0xd63db039 , 0xd19059c9 , 0x8350901b , 0x1dc7ae0a , 0x1ca54fc7 , 0x0ff86b1b ] )
+  , Mont::unsafe_make( [ 0x823f1214 , 0xf16ac99a , 0xdbc18f94 , 0x7fc6751a , 0xc3203dc7 , 0x1b28df38 , 0x3d0ce8e3 , 0x11a62458 ] )
+  , Mont::unsafe_make( [ 0x5ef147dc , 0x0ed9c01b , 0x42ec46a9 , 0x15b9912c , 0x1976b2b4 , 0x836d6d79 , 0x5daa86b9 , 0x1f447358 ] )
+  , Mont::unsafe_make( [ 0x69e2ffc0 , 0xb00d5014 , 0xb22a9468 , 0x447d482f , 0x25a89d18 , 0x93e39689 , 0x6ca0bc29 , 0x2077b3a8 ] )
+  , Mont::unsafe_make( [ 0x7f0393ad , 0x40ad5eff , 0xc214d30d , 0xf8555a85 , 0x3f5a4589 , 0x1c9bda0b , 0x32465d96 , 0x0ec7e4ff ] )
+  , Mont::unsafe_make( [ 0x2f2a4edf , 0x00914d2c , 0xee6d74f2 , 0x8857b6a1 , 0xba3a8061 , 0x84304e5b , 0x7894bb68 , 0x01c24913 ] )
+  , Mont::unsafe_make( [ 0xa58ea5be , 0xd67a679d , 0x7ceaefbd , 0x84f1f864 , 0x8eda0a67 , 0x67e80f99 , 0x7fd96e12 , 0x0b639032 ] )
+  , Mont::unsafe_make( [ 0x18a658d2 , 0x47b3470d , 0x19600d24 , 0x344c6267 , 0x385e17c0 , 0xe2240e6b , 0xd087ad0b , 0x10474984 ] )
+  , Mont::unsafe_make( [ 0x6ba513d6 , 0x4bd9120a , 0x6a7356e6 , 0xab233f77 , 0x687988e0 , 0xeee7ff3c , 0x93924c91 , 0x08186915 ] )
+  , Mont::unsafe_make( [ 0x5954c7c8 , 0xaeceb14c , 0x5a9d18f6 , 0x3d680ea4 , 0x68c67044 , 0xb0faf193 , 0x6a5af3e2 , 0x1f98c334 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xad454b51 , 0xf49f9c97 , 0x6a28626c , 0x189b0fe4 , 0xf56f2a99 , 0xebdcf4e4 , 0xef718ba2 , 0x0d0c7720 ] )
+  , Mont::unsafe_make( [ 0xa8c6187e , 0x0c66cb72 , 0x49e49e8a , 0xc7561f14 , 0x4d0c4427 , 0x188b108a , 0xa270b269 , 0x2172faab ] )
+  , Mont::unsafe_make( [ 0x68d00dc5 , 0x0afb8295 , 0xd83f73fc , 0x0fda8fa7 , 0xbb95cff4 , 0xaea46020 , 0xa45d980d , 0x21d44ec1 ] )
+  , Mont::unsafe_make( [ 0x2c2c673e , 0xf51fdb96 , 0x08fc2e08 , 0x5276b53f , 0x93d25d1a , 0x56ea79b9 , 0xc8cae5b3 , 0x27b22a1c ] )
+  , Mont::unsafe_make( [ 0x541470dd , 0xdcdaa4af , 0xe65de8b5 , 0xb3946dbc , 0x4398a857 , 0x61846c21 , 0x21f07a4d , 0x052b3d46 ] )
+  , Mont::unsafe_make( [ 0x41b2bd6c , 0x403564cc , 0x916634b0 , 0xd6e36a0d , 0xaa274709 , 0x899d99da , 0x33dfbefb , 0x269a497d ] )
+  , Mont::unsafe_make( [ 0xa56fbd01 , 0x8b770221 , 0x44e4cf7e , 0x4542c69b , 0x7eada1af , 0x47578d0a , 0x560fa7ad , 0x28156a88 ] )
+  , Mont::unsafe_make( [ 0xb51cebea , 0x0e8fa9c8 , 0x6a31a785 , 0x2c39211f , 0xeefc3a6c , 0x08e90d79 , 0x8c8a43b1 , 0x1f99dbb0 ] )
+  , Mont::unsafe_make( [ 0x3aa625d5 , 0xddd64a39 , 0x0581289b , 0x58dac1b7 , 0xc3bd5f76 , 0x0e709143 , 0x4849cee4 , 0x07c76705 ] )
+  , Mont::unsafe_make( [ 0xcbbf5f0f , 0x19c4b2d6 , 0xd71663b5 , 0x0bfbab65 , 0xe10b2cc8 , 0xe418d936 , 0x76ab2dfa , 0x1862b447 ] )
+  , Mont::unsafe_make( [ 0xe931acd9 , 0xbe2ce15c , 0x3bd420e8 , 0x3765b28c , 0x41aec783 , 0xc39987d2 , 0x40c6a94a , 0x23c1ed7b ] )
+  , Mont::unsafe_make( [ 0x5f2d26de , 0x27992548 , 0x6545d328 , 0xd3b9b88f , 0xbec29140 , 0xabf38b6e , 0x47c130b8 , 0x0689197a ] )
+  , Mont::unsafe_make( [ 0x354d08ed , 0x28fbccde , 0xf1ca766b , 0x907e7791 , 0x7ee00213 , 0x6cc145c5 , 0x9e5129b1 , 0x267f8302 ] )
+  , Mont::unsafe_make( [ 0x4c71843c , 0x41aab0d7 , 0xaf8b32a1 , 0xed46c24d , 0xe9776514 , 0x0865f7af , 0x12bc3a64 , 0x110d68e6 ] )
+  , Mont::unsafe_make( [ 0x3c0e9a92 , 0x48a5dd8f , 0x58fc8fae , 0x498b71dd , 0x571bddb0 , 0x7cc4d089 , 0xdb78a7c1 , 0x0bffdb4e ] )
+  , Mont::unsafe_make( [ 0xd33fd4a7 , 0x5089c9e7 , 0x478e5712 , 0x0e93c2af , 0x9baed5ba , 0x99ed3d9d , 0x0cc39061 , 0x2b06cd5f ] )
+  , Mont::unsafe_make( [ 0x5854eaaf , 0x850eca07 , 0xb7ec332f , 0x68956528 , 0x77068c64 , 0x4e2d6942 , 0x71766bd5 , 0x0585d5ff ] )
+  , Mont::unsafe_make( [ 0xa338e09c , 0xe439ecc5 , 0xad0aa059 , 0x2410a612 , 0x6361801e , 0x117710f5 , 0x4b5cb1c3 , 0x09e4799e ] )
+  , Mont::unsafe_make( [ 0xe1250a30 , 0x93432f50 , 0xe2200b74 , 0x88b12617 , 0xec539112 , 0x3cee7ae2 , 0xc74818b8 , 0x2f3be234 ] )
+  , Mont::unsafe_make( [ 0x50997510 , 0x68ea580e , 0x459bc9ec , 0xc14c7238 , 0xe80bc6ff , 0xcea1e4a8 , 0x0dd74c07 , 0x1365cd5d ] )
+  , Mont::unsafe_make( [ 0xc95b2441 , 0x793e01d8 , 0x865daef2 , 0x3c8f2c9b , 0x04c06e92 , 0xa8396d25 , 0x167e6df5 , 0x2c50e035 ] )
+  , Mont::unsafe_make( [ 0xc31ad4e3 , 0xb8693cb9 , 0xe01c4ed9 , 0xc6a53e28 , 0x2ec76452 , 0x87755578 , 0x8ec2b864 , 0x1d02c1a3 ] )
+  , Mont::unsafe_make( [ 0x8f81d5c5 , 0x537d49dd , 0x4bd03b7e , 0x769018b1 , 0x23478fe0 , 0x19e711f2 , 0x960a3e47 , 0x1b29dbd2 ] )
+  , Mont::unsafe_make( [ 0x2a4f1d0d , 0xf9a39595 , 0x3e6fcc62 , 0x8e6ae3e3 , 0x40ce15fd , 0x23e81916 , 0x4e787241 , 0x2d798c4a ] )
+  , Mont::unsafe_make( [ 0xc6ac15f8 , 0x6fda5e22 , 0xb2a3c381 , 0xe4745e9c , 0x00e022e6 , 0x49b21379 , 0x2b8fae74 , 0x1f2f764f ] )
+  , Mont::unsafe_make( [ 0x625c0268 , 0x0729b53d , 0x06ae44ad , 0xf9b6dac6 , 0x7ce962dd , 0x9047fd3a , 0x78db7ced , 0x10a77949 ] )
+  , Mont::unsafe_make( [ 0x14d40316 , 0x450beaeb , 0x67293c5d , 0x98d2b719 , 0x7236a97b , 0x6e28f315 , 0xdcf2e1ac , 0x28911bad ] )
+  , Mont::unsafe_make( [ 0xe42ac3ad , 0x4032043f , 0x1027e7ed , 0x4bc21250 , 0xc41d2798 , 0x26f92fac , 0x9dc2c64f , 0x29403bca ] )
+  , Mont::unsafe_make( [ 0x6032476f , 0xb86f407f , 0x59e83c90 , 0xa4923496 , 0x12247102 , 0xa4ea98b5 , 0x34190e06 , 0x0eb87419 ] )
+  , Mont::unsafe_make( [ 0xe6f76427 , 0x9e12984a , 0x399f097d , 0xe868da42 , 0xee343443 , 0x2244b6ca , 0x8e2f617e , 0x05c2de48 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xb1a63ed0 , 0xbbac391b , 0x822730bb , 0x2a9adf44 , 0x143243fa , 0x93293f53 , 0xe0d6ff4c , 0x0019f43b ] )
+  , Mont::unsafe_make( [ 0x42ac86a2 , 0xe9d7e3fd , 0xc5b4aa7b , 0xf1921cbc , 0x11f61afc , 0x9df62a2b , 0xa57d80ed , 0x25576e1f ] )
+  , Mont::unsafe_make( [ 0xf5c43ec6 , 0x526ea92a , 0xd67aa2ff , 0x29ba277d , 0xdde53475 , 0xe1b3005f , 0x86ccdfd5 , 0x201b2f7d ] )
+  , Mont::unsafe_make( [ 0x0198a523 , 0x9081bfdf , 0x251cede9 , 0xbd43fa97 , 0x7d5b9d83 , 0x71c1e79b , 0xb0ad8852 , 0x1cd2b0df ] )
+  , Mont::unsafe_make( [ 0x3b1cae22 , 0xe295f80d , 0x58341611 , 0x669b0ff3 , 0x21aebfc6 , 0xf923e41e , 0xecc10d3f , 0x0e964269 ] )
+  , Mont::unsafe_make( [ 0xab7dae1a , 0x7e90ff55 , 0x6ea60d98 , 0x43138d6f , 0xca5ede99 , 0xaac2e041 , 0xc2888a88 , 0x304d4c6a ] )
+  , Mont::unsafe_make( [ 0xf0b1d5c6 , 0x08b02baf , 0xac4d3afe , 0x54247d9e , 0x3da83cde , 0xc8c51252 , 0x5e819b37 , 0x1de83105 ] )
+  , Mont::unsafe_make( [ 0xbc0c0baf , 0xfee664a6 , 0xb22a80e5 , 0x40566bdd , 0x2bfaf208 , 0x596f4ade , 0xdb7daf64 , 0x2e1c103e ] )
+  , Mont::unsafe_make( [ 0xf7fd3813 , 0xa853e085 , 0x2fefa9a2 , 0x99dd4ab0 , 0x346f802c , 0x0f15eff8 , 0xec19e149 , 0x024250c2 ] )
+  , Mont::unsafe_make( [ 0x7cc95f7d , 0x67ad6a6b , 0xc55bc417 , 0xd7cf336a , 0x5010b4ac , 0x136df26f , 0x398b487e , 0x2239daa3 ] )
+  , Mont::unsafe_make( [ 0x80d0cc03 , 0x19914727 , 0x144e880e , 0x716f4965 , 0xb6b6d855 , 0xa78ed0ab , 0x06ee40ae , 0x16bf1b06 ] )
+  , Mont::unsafe_make( [ 0x9acdc163 , 0xba310b8f , 0xc54a0e75 , 0xecf3d974 , 0xa28fc408 , 0x4ae6ddc1 , 0x0ba00537 , 0x2c439bb4 ] )
+  , Mont::unsafe_make( [ 0x2b163bfe , 0x14bc114f , 0xc75c98e1 , 0xa3c7b5b0 , 0x7c2f436a , 0xdbff6b65 , 0x6c173850 , 0x149d3246 ] )
+  , Mont::unsafe_make( [ 0x9b5c47ab , 0xdff9c053 , 0x1f788b62 , 0x2ac2276a , 0xb513fdb1 , 0x87592252 , 0xeee71d31 , 0x2d1fd67e ] )
+  , Mont::unsafe_make( [ 0xdadf2ea5 , 0xef2d30c0 , 0x9f1d97c8 , 0x15440226 , 0x80344690 , 0x80dc0c75 , 0x67879ada , 0x054c00ae ] )
+  , Mont::unsafe_make( [ 0xf34994f6 , 0xacc24663 , 0x51d48fa3 , 0x98d55e3e , 0x32bdbc3e , 0x6f7b040e , 0xb824f8bd , 0x1bc0dd86 ] )
+  , Mont::unsafe_make( [ 0x3ef405d4 , 0x91f1e090 , 0xcdf422bb , 0x0c203ac9 , 0x1a9cc699 , 0x9a97433f , 0xedc8046a , 0x225168eb ] )
+  , Mont::unsafe_make( [ 0x102fba96 , 0xd55cf297 , 0x3d8e0555 , 0xdf42704e , 0xbd786a68 , 0x0ca74e44 , 0x59054e3d , 0x0d3110b4 ] )
+  , Mont::unsafe_make( [ 0x7e0be182 , 0xd64c5793 , 0x67552bf5 , 0x2b0e7903 , 0x143ec697 , 0xb689f0f3 , 0x9cc50069 , 0x0037de19 ] )
+  , Mont::unsafe_make( [ 0x4c33817e , 0x33e01e7b , 0x67118482 , 0xb46efdab , 0x52a38060 , 0xcb29aebe , 0xd19cfcae , 0x20dcb652 ] )
+  , Mont::unsafe_make( [ 0x4c4cadfd , 0x7eb4f0f7 , 0x6a3943c0 , 0x420bd6f0 , 0x3fe30d51 , 0x8b48085c , 0xae43b75d , 0x0de3f147 ] )
+  , Mont::unsafe_make( [ 0x90d94546 , 0x7978d64c , 0xe14d419b , 0xae3038bd , 0x873f1740 , 0xc4785de4 , 0x7bf79bb0 , 0x0869e4f9 ] )
+  , Mont::unsafe_make( [ 0x4108d8a8 , 0x492b1421 , 0x2298da3b , 0x7090ce90 , 0xdaa3a748 , 0x6d3cc7e5 , 0xe730936f , 0x292f1871 ] )
+  , Mont::unsafe_make( [ 0x62955d66 , 0x1dfee2ad , 0x4fb3a113 , 0x604312c4 , 0x96f11a88 , 0xb7695615 , 0x783e3428 , 0x0d0e2cb3 ] )
+  , Mont::unsafe_make( [ 0xba1b7d32 , 0xac8cc82e , 0xcf253ed2 , 0x203786ac , 0x63b3bf26 , 0xf5838bbd , 0xe9874b9d , 0x25781178 ] )
+  , Mont::unsafe_make( [ 0xc7cefa23 , 0x9fcef599 , 0x2615a9db , 0xba5dd347 , 0x91a0c7d7 , 0xd7a7764f , 0xb049cb1d , 0x10c9b7f9 ] )
+  , Mont::unsafe_make( [ 0x53718fdb , 0xdf17b2f4 , 0x9ae7e1ff , 0xaf0e5075 , 0x20e6e77e , 0x28b83e9d , 0xdf9a3f0f , 0x1fe65d19 ] )
+  , Mont::unsafe_make( [ 0xb9a7ce45 , 0xa861e2eb , 0x03bc5edb , 0x0def5a64 , 0xb1ff0c49 , 0xa22f1016 , 0x1fd5cfc0 , 0x04128416 ] )
+  , Mont::unsafe_make( [ 0xed4c6ec2 , 0x05f832b2 , 0x1fb4d1c6 , 0xb4334255 , 0x0a483f65 , 0x65085173 , 0x5f1b4974 , 0x155546ac ] )
+  , Mont::unsafe_make( [ 0xe348b06d , 0x87c60c71 , 0x21c4e7ae , 0xd93e2584 , 0x247624f0 , 0xe2ae5581 , 0xcb3332d6 , 0x020e9abc ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xa5f07781 , 0x6ef69f7b , 0xea9d3b35 , 0x8b70da6e , 0x5d2714e7 , 0xb3e64ad2 , 0xfa0863a2 , 0x26d4dd55 ] )
+  , Mont::unsafe_make( [ 0x322a7460 , 0x87fd29ae , 0x4667e810 , 0x316768a7 , 0x5ce58793 , 0x549b47cc , 0xc0cf5851 , 0x17b9b816 ] )
+  , Mont::unsafe_make( [ 0x7845df97 , 0x5bf16dbd , 0x98f10c79 , 0x41a15e8e , 0x10371487 , 0x41ee8abc , 0x11506768 , 0x1caeda98 ] )
+  , Mont::unsafe_make( [ 0x95360d21 , 0x8c879d31 , 0x34cd7af0 , 0xc84807d2 , 0xf31a83a6 , 0x6481d5e8 , 0x7097e9f1 , 0x1f6bfca3 ] )
+  , Mont::unsafe_make( [ 0xe8c978fc , 0x03de0d31 , 0x5458de90 , 0x63bdd594 , 0xa13b9840 , 0x29e5a1ca , 0x6edb433b , 0x081036fa ] )
+  , Mont::unsafe_make( [ 0x52823580 , 0xb19ee84c , 0x4de3f07c , 0x4b776b09 , 0x838a68b8 , 0xaa9a4025 , 0x7ca71f66 , 0x0a20cf81 ] )
+  , Mont::unsafe_make( [ 0x2247d557 , 0x5ee40969 , 0x59048e2a , 0x25753613 , 0x6a9ece11 , 0x096206fe , 0x7e444893 , 0x05b5fa88 ] )
+  , Mont::unsafe_make( [ 0x6e138a35 , 0x877c9861 , 0x49b015d9 , 0xddf4a2d8 , 0x9bf80a1d , 0x04509fa6 , 0x19e1898f , 0x141646bb ] )
+  , Mont::unsafe_make( [ 0x4ee07be5 , 0x705fcc67 , 0xf2a1905c , 0xb507f427 , 0x0c74466d , 0x3823ac08 , 0xc5f12366 , 0x242f0d58 ] )
+  , Mont::unsafe_make( [ 0x284fc35e , 0x5db432da , 0x982a114f , 0xabcb75b9 , 0x8aa5b019 , 0x0ec9ec17 , 0xd6da2046 , 0x04a16cc8 ] )
+  , Mont::unsafe_make( [ 0x780aa513 , 0xf65d25d6 , 0x7a2ffc2a , 0xdeda0448 , 0xb9841264 , 0xc4b2a878 , 0xdb43bfce , 0x14e830db ] )
+  , Mont::unsafe_make( [ 0x90797745 , 0x34a443b5 , 0x95ce3ea1 , 0x468469ab , 0x43f7852b , 0x83481cbe , 0x1917709d , 0x17fdf1bc ] )
+  , Mont::unsafe_make( [ 0x7f4403f5 , 0x9528c7bd , 0x3e8d992a , 0x1ffdde2c , 0xd65492cd , 0xd673ce51 , 0x89f4ccd9 , 0x004a35e8 ] )
+  , Mont::unsafe_make( [ 0x74cac508 , 0x6d1fc077 , 0xc51fb03e , 0x34ca9932 , 0xe5d34461 , 0x448b2e60 , 0x513e3acd , 0x13d49b64 ] )
+  , Mont::unsafe_make( [ 0x64e04f47 , 0x797402fb , 0xd6681165 , 0xf276ce69 , 0xdac3a6f8 , 0x87ea0c19 , 0x9e439428 , 0x22ac95fd ] )
+  , Mont::unsafe_make( [ 0xb4a1f4cc , 0xe6966dda , 0x4aa03d46 , 0xf21f5154 , 0xef2db7e1 , 0xbd7db759 , 0x732f3692 , 0x29b00a0e ] )
+  , Mont::unsafe_make( [ 0x8bc22647 , 0x07392e8e , 0x88a04bac , 0x97fa5822 , 0x31acb0be , 0x76fcc761 , 0x52c9a2b1 , 0x0aec3ad8 ] )
+  , Mont::unsafe_make( [ 0xd975f578 , 0x585b44e8 , 0xfca8d1b5 , 0x9a34b2e6 , 0xd2be7d9c , 0xfefa25de , 0xea193b42 , 0x15c0be56 ] )
+  , Mont::unsafe_make( [ 0x12c9f82c , 0x5704b3dd , 0xc87acf96 , 0xf8e09d52 , 0x657122bd , 0x9b9f182f , 0x521e9cca , 0x0b3774e8 ] )
+  , Mont::unsafe_make( [ 0xa480e34d , 0xea8c4af4 , 0xc7d20ef3 , 0x9fecb092 , 0x4e698d06 , 0xc74f8a2e , 0xf4ce2298 , 0x14fd7322 ] )
+  , Mont::unsafe_make( [ 0xddea2998 , 0x1531fa4d , 0x9755675e , 0x16c71f75 , 0x91e328e7 , 0x4bcb5f0b , 0x1141d4ab , 0x1e9cfb55 ] )
+  , Mont::unsafe_make( [ 0xc859bc65 , 0x5fef8039 , 0xa816229f , 0x71fbef22 , 0xaea71738 , 0x7e416fad , 0x28c6689a , 0x0c9239bd ] )
+  , Mont::unsafe_make( [ 0x21fb23e8 , 0x2f2e01c1 , 0x823f78eb , 0x14f955e5 , 0x3bec963a , 0xd6f2017b , 0x0e98a6aa , 0x168faedc ] )
+  , Mont::unsafe_make( [ 0x78904591 , 0x17f60efa , 0x0cb67376 , 0xf3201379 , 0x1774072f , 0x570f2b4f , 0xb7e870f9 , 0x06e398f7 ] )
+  , Mont::unsafe_make( [ 0xdd767a33 , 0x5f0e0445 , 0x62a6d2f5 , 0x2d215b73 , 0x3cdec6ea , 0x40ca38b1 , 0x7e5bf433 , 0x09e01cb9 ] )
+  , Mont::unsafe_make( [ 0x24920d37 , 0xc0079d66 , 0x7cbb99b6 , 0x785c36d6 , 0x0b510618 , 0x92937e91 , 0x0827c3e5 , 0x11187e37 ] )
+  , Mont::unsafe_make( [ 0x1fa77100 , 0xfda5894b , 0x8eec8124 , 0xaa6e8d68 , 0xf48eab31 , 0xd182ccc2 , 0x5598d9d8 , 0x040db08c ] )
+  , Mont::unsafe_make( [ 0xd4c12887 , 0xb908f54a , 0x116e8248 , 0xea90b6ca , 0x333e81f9 , 0x602778e4 , 0xe8a56c2a , 0x076d8503 ] )
+  , Mont::unsafe_make( [ 0x90b7c780 , 0x1c2ab3cc , 0x520f0601 , 0xde72ef3c , 0x15113eb5 , 0x640b94b7 , 0x98d297fb , 0x03153689 ] )
+  , Mont::unsafe_make( [ 0xc911c10f , 0xf1caac6d , 0x5efa46f0 , 0xbf2a7f56 , 0x20328d09 , 0x056a00d7 , 0x83bd608c , 0x0d67ef11 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x0500bf0f , 0x5d84c7a1 , 0x90c4c542 , 0x4469a0f0 , 0xaa4dd319 , 0x8b4eaba1 , 0x7660b19d , 0x2c0643ae ] )
+  , Mont::unsafe_make( [ 0x7b0b39a7 , 0xa01d507b , 0x04a0cbb9 , 0xec8af0ff , 0x5bb20ddc , 0x8f75a023 , 0x62ffb436 , 0x16331350 ] )
+  , Mont::unsafe_make( [ 0xa47a2c78 , 0xc22b44c2 , 0x88fc2b35 , 0x00d6c25b , 0x9641f970 , 0x4ee05b66 , 0xbf9ae658 , 0x1f2a2aae ] )
+  , Mont::unsafe_make( [ 0x63c750d5 , 0xbb054c7b , 0x76bf5d33 , 0x79041f04 , 0xd29f4c45 , 0xbedb213c , 0x1540c940 , 0x075aef6b ] )
+  , Mont::unsafe_make( [ 0x1f2bf63e , 0xb4b6258f , 0xfeded92e , 0xab748ae7 , 0x0a7faefc , 0xeaef8d11 , 0xa93241a9 , 0x2990349b ] )
+  , Mont::unsafe_make( [ 0xd6483cf2 , 0xa71fde34 , 0x06f5acc5 , 0x5d144570 , 0xfb612476 , 0xac399166 , 0xb7945272 , 0x2f107a56 ] )
+  , Mont::unsafe_make( [ 0x3412ad24 , 0x961abe05 , 0xd92302b5 , 0x9131e28b , 0xad83d05e , 0x94b4f263 , 0x8524511c , 0x169d18c5 ] )
+  , Mont::unsafe_make( [ 0x65222943 , 0xd3678e92 , 0x25a35f37 , 0xa86f4015 , 0x29d3d627 , 0xb615b434 , 0xadb6d080 , 0x1bd58ef5 ] )
+  , Mont::unsafe_make( [ 0xb99a25bb , 0x9b356362 , 0xa557c1cc , 0x44f6351c , 0x37dfd80c , 0xcbbd04ac , 0x5d152c8a , 0x014d262a ] )
+  , Mont::unsafe_make( [ 0xb7cc5adf , 0x4efc754a , 0x239ff7ac , 0x1dd8842f , 0xa656a04e , 0xcf09749d , 0x7c87846f , 0x1881dfbf ] )
+  , Mont::unsafe_make( [ 0x848e5d8a , 0x8714162e , 0x6c69906b , 0xad6f9654 , 0xc7c2322a , 0x064569b3 , 0xdef17952 , 0x2a97cc4f ] )
+  , Mont::unsafe_make( [ 0x976f11d0 , 0x17c45f13 , 0x9260acfd , 0xb8d2f1a6 , 0x15fa2b2b , 0x8d386e2b , 0x7a7444f5 , 0x03ce7f45 ] )
+  , Mont::unsafe_make( [ 0xac177e0c , 0x56aa1d25 , 0x4f4b4ded , 0x4c8ca86c , 0x6225305d , 0x796f6157 , 0x95f744a4 , 0x177f5280 ] )
+  , Mont::unsafe_make( [ 0x8d3fa2ee , 0x666dd6ff , 0xf87fc5f1 , 0xc33af27e , 0xd10180a8 , 0xc4628a9d , 0x77b85222 , 0x0784aa3e ] )
+  , Mont::unsafe_make( [ 0x459a0e69 , 0xea3c6336 , 0xb9ed7fcd , 0x6d34884b , 0x978c022e , 0x6ff967c3 , 0x65b83c02 , 0x02eb4535 ] )
+  , Mont::unsafe_make( [ 0xa8e86d25 , 0x9d5fe666 , 0xff013acd , 0x0279fb8e , 0x6facd839 , 0x02e1f682 , 0x55d4b97c , 0x0eb83f25 ] )
+  , Mont::unsafe_make( [ 0xd5be043f , 0xfe000189 , 0xeb2bd28a , 0xbfff6637 , 0xa2e4d058 , 0x98f9bb81 , 0xe9282b97 , 0x0204bc4b ] )
+  , Mont::unsafe_make( [ 0x1b7efddc , 0x2411afe0 , 0x0bdc5734 , 0x91663c0c , 0x0fcbe6eb , 0x60498a8d , 0xc9ab0d40 , 0x1975d5e9 ] )
+  , Mont::unsafe_make( [ 0xf28dd6d2 , 0x197b0c80 , 0x6c966758 , 0xe843f54c , 0x906b6816 , 0xdc6d64b6 , 0xe013e4fd , 0x18e27278 ] )
+  , Mont::unsafe_make( [ 0x949c9828 , 0x4eb6d348 , 0x74f2da0d , 0x3bbcde34 , 0x86007926 , 0xc123a661 , 0x7a985263 , 0x15cd4536 ] )
+  , Mont::unsafe_make( [ 0xf53be274 , 0x027f6180 , 0x4b917048 , 0x475c2315 , 0xc5f16f27 , 0x66a589ba , 0xbd67417a , 0x2e9d2cec ] )
+  , Mont::unsafe_make( [ 0x7b5d6840 , 0x2200e57d , 0x19e19893 , 0x2d95b408 , 0x585f89ee , 0xe6861d5a , 0x6cef72d9 , 0x07d98e1c ] )
+  , Mont::unsafe_make( [ 0x124f5c45 , 0xa8a329ec , 0xfe27be8a , 0xed0e2cd3 , 0x16bdaad8 , 0x5bbc7e3c , 0x34b60d53 , 0x164b7041 ] )
+  , Mont::unsafe_make( [ 0x1bcacd7a , 0xae5f8e18 , 0x7be02c7b , 0xdf26d8a8 , 0xebf4288a , 0xdfc2467e , 0x4be8a4fa , 0x1c2501ad ] )
+  , Mont::unsafe_make( [ 0x076d1a19 , 0xb8252544 , 0x97f6c08c , 0x0b05ca9d , 0x8b512854 , 0x5d669964 , 0x80e9ba02 , 0x28da0c9e ] )
+  , Mont::unsafe_make( [ 0x4eee6a59 , 0x9e5319d4 , 0x4a8672d5 , 0xa627f1a9 , 0x517a5d31 , 0x5ea3a7ad , 0xe11565cc , 0x2eb53ace ] )
+  , Mont::unsafe_make( [ 0x5ab5c47b , 0x7cbcb190 , 0x0a69989f , 0xe2aadf51 , 0x3ad9bf9c , 0xad7d1b0d , 0xfa0b39b7 , 0x250048f1 ] )
+  , Mont::unsafe_make( [ 0x3864c5a9 , 0x56b51c89 , 0xf70c9b2c , 0xd30d7869 , 0x6a820e3f , 0xdd522f7a , 0xff3403f7 , 0x153c43fd ] )
+  , Mont::unsafe_make( [ 0x4dd51c6c , 0x2d61953e , 0xaf32260f , 0x40cc54d9 , 0x960c9051 , 0x3f4bd151 , 0xa155c381 , 0x2c190f0a ] )
+  , Mont::unsafe_make( [ 0xac0a34a3 , 0x4a6bc692 , 0x8f46fa2f , 0x0b63b43b , 0x36c966fd , 0xcbd94acc , 0x7f2df040 , 0x16beea4e ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x58e1c1ef , 0x0abf0ab8 , 0xc2e1d207 , 0xb708520d , 0x846724b1 , 0x29e18ffc , 0x088cced1 , 0x1aee0611 ] )
+  , Mont::unsafe_make( [ 0xf3d3cf2a , 0x2cdc3b92 , 0xa67ab50e , 0x097c4df4 , 0xfb280bc0 , 0xad83004f , 0x999a2ed8 , 0x136cb2da ] )
+  , Mont::unsafe_make( [ 0x581cbf40 , 0x15147835 , 0x3a062e71 , 0xa01e629d , 0xcc92a752 , 0xeb5ba169 , 0xa4b96aff , 0x2f36a8d7 ] )
+  , Mont::unsafe_make( [ 0x473203f9 , 0xdb5ecc08 , 0x9b3739ce , 0x30379389 , 0x6add4e8f , 0x6459f54d , 0x5e3e8b6c , 0x035cc487 ] )
+  , Mont::unsafe_make( [ 0xe99f8b9a , 0xc2e2ee57 , 0x9151be5d , 0x5c79af7c , 0xc286a95d , 0x6d8a3db0 , 0x990b9e83 , 0x1658c8d7 ] )
+  , Mont::unsafe_make( [ 0xc9370981 , 0xcc7977c0 , 0xfaf0b0e9 , 0x4823f963 , 0x6b3d6a3b , 0x3011f1b9 , 0xebf4b2a9 , 0x2ae688dc ] )
+  , Mont::unsafe_make( [ 0xa95740e1 , 0x76bf612f , 0x18dbc6e9 , 0x80d29289 , 0x4aa03dcb , 0xf00a09d2 , 0x50a9b2b7 , 0x0e5c343b ] )
+  , Mont::unsafe_make( [ 0xb41776d1 , 0xb034cbcb , 0x53e381d7 , 0x7154cf9b , 0x9a375939 , 0xb6101767 , 0xe3711b74 , 0x195addd6 ] )
+  , Mont::unsafe_make( [ 0xa369a5e0 , 0x90bfd279 , 0x17bb1790 , 0x54ebaba0 , 0x6378061f , 0xc01702a8 , 0x155ea602 , 0x17886ae8 ] )
+  , Mont::unsafe_make( [ 0x2e37ec0b , 0x4aa44097 , 0x79779bd8 , 0xef68f92d , 0xa75aaac2 , 0x7b31b03e , 0x859af842 , 0x127951e7 ] )
+  , Mont::unsafe_make( [ 0xfa1af3da , 0xa37ead3e , 0x05711a0e , 0x68832cf2 , 0xc585b30e , 0x4ff7f062 , 0x61482ae6 , 0x200e81ca ] )
+  , Mont::unsafe_make( [ 0xe0b9938a , 0x2b51b8b2 , 0x21904f66 , 0x6c55bfc1 , 0xb352b6a0 , 0x9f479eb7 , 0xdbeb879e , 0x1261e0f5 ] )
+  , Mont::unsafe_make( [ 0xea02aaf1 , 0xdc54e50b , 0xfc27af81 , 0x342c391f , 0xdf6db1c4 , 0xe632f169 , 0x7144689b , 0x0f10ad02 ] )
+  , Mont::unsafe_make( [ 0xf8bc8787 , 0xadfa4275 , 0xe5e0b293 , 0x37ab301e , 0x6bcb4e8c , 0x22a37642 , 0x4dd89d99 , 0x0b1c2202 ] )
+  , Mont::unsafe_make( [ 0x44c10b52 , 0x4963a490 , 0x5da69c9b , 0xcbcbb6da , 0x8b04bd46 , 0xeda3ee8b , 0x8205e387 , 0x1da7893f ] )
+  , Mont::unsafe_make( [ 0xabcabf94 , 0xdec8fb92 , 0x491b6507 , 0xa98afe01 , 0x8c162467 , 0x988344f7 , 0x16414c55 , 0x0f5bccf3 ] )
+  , Mont::unsafe_make( [ 0x40992037 , 0x8ea67297 , 0x0dfff7ac , 0xb4ca3dbb , 0x28266f48 , 0xd8ee4b7d , 0x0f2668ab , 0x0fa5459b ] )
+  , Mont::unsafe_make( [ 0x63ad46b4 , 0xe989d2b4 , 0x60ac0f3e , 0x02f1cba7 , 0xc55064b6 , 0xfb1b7a58 , 0x342ad485 , 0x0ce1044a ] )
+  , Mont::unsafe_make( [ 0xf698df2a , 0x67aaa17d , 0x094c1efe , 0xc831c9b1 , 0x1e4f895f , 0xc47c90f8 , 0xa175fd04 , 0x1847aff9 ] )
+  , Mont::unsafe_make( [ 0x41cd5a03 , 0x8a6f70b9 , 0x33e9425d , 0x7056028d , 0xc52eafa0 , 0x5b7a86ed , 0xc890b7e0 , 0x01c916d9 ] )
+  , Mont::unsafe_make( [ 0x32177f1f , 0xca256acb , 0xc5d90781 , 0x8e47d393 , 0xc0a8ab06 , 0x02e99765 , 0xeecbd5bf , 0x0c3f0549 ] )
+  , Mont::unsafe_make( [ 0x6d862be2 , 0xa20dfcce , 0xeb79c35c , 0xfdfa32e3 , 0xa7c3c9ca , 0xac0f0503 , 0x81d30832 , 0x165664e6 ] )
+  , Mont::unsafe_make( [ 0xb05003ca , 0x15729960 , 0xbe42ca8a , 0x915e7745 , 0xf1902eb3 , 0x53c81d05 , 0x83b0962a , 0x07c7c99f ] )
+  , Mont::unsafe_make( [ 0x7b859a3b , 0x06eec013 , 0x35b64ddf , 0xef40cc3d , 0x93db8191 , 0x2c258975 , 0xdfa590a3 , 0x20dc9405 ] )
+  , Mont::unsafe_make( [ 0xcfb36d67 , 0x0fa70bd5 , 0xdf891ffb , 0x26a481e0 , 0xb6ec9526 , 0x9c89c286 , 0x2f6e7433 , 0x23887270 ] )
+  , Mont::unsafe_make( [ 0x2379aa68 , 0xc6c9d039 , 0xf94465b1 , 0x5bf00bfd , 0x8c72a787 , 0x2bb5de36 , 0x2eb1def9 , 0x2524875c ] )
+  , Mont::unsafe_make( [ 0xe0968080 , 0xb9f3e073 , 0x08a81cc2 , 0x6680e4ba , 0x7db5ddb0 , 0xe7bbad18 , 0x29a5a06a , 0x2684686f ] )
+  , Mont::unsafe_make( [ 0x09e6cb61 , 0x998cf25b , 0xc41605e8 , 0xbf423d00 , 0x525921d8 , 0xfa5dc204 , 0xe9a20b1f , 0x0d570ee9 ] )
+  , Mont::unsafe_make( [ 0x8af2e7a7 , 0xc42488ab , 0x90dc4a6f , 0xcb5b21d1 , 0xfd5dfc51 , 0x4dfaf752 , 0x375a5d5d , 0x2c7eb4e9 ] )
+  , Mont::unsafe_make( [ 0x3a24a01a , 0x19aa0f0d , 0x8a5640ff , 0xbde8bb1e , 0xe466500c , 0xc3d45d08 , 0x57562bd6 , 0x2f00cf13 ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xc68e6bb5 , 0x0ef50d20 , 0x69b97245 , 0x1df49233 , 0x595af2aa , 0x798d1e24 , 0x831526b8 , 0x1cf8db90 ] )
+  , Mont::unsafe_make( [ 0xfb039677 , 0x35c8a6ca , 0xa685ad0b , 0x2af16bbd , 0xb36cbc7b , 0xe184921e , 0xa1d36a3c , 0x2675490e ] )
+  , Mont::unsafe_make( [ 0x145b2210 , 0x56fc2711 , 0x1555d097 , 0x77742dd3 , 0x1b1549a6 , 0x2288a555 , 0xa91a2c88 , 0x0caaee4b ] )
+  , Mont::unsafe_make( [ 0x60e67be4 , 0x90b298dd , 0x63bd7e0b , 0xe4e308b4 , 0x23a288bd , 0xcf36b906 , 0x58b888d3 , 0x2d30d80d ] )
+  , Mont::unsafe_make( [ 0x8b29d1c9 , 0x6ec46a32 , 0xf44fdd1b , 0x1d17b117 , 0x27b37c89 , 0x9e954c91 , 0xf39bb4eb , 0x0a8a98db ] )
+  , Mont::unsafe_make( [ 0x560365c3 , 0x889d9615 , 0xf848e397 , 0x87ed18dc , 0xff95bb51 , 0xea0a632c , 0xfa5ac447 , 0x2c871470 ] )
+  , Mont::unsafe_make( [ 0x8458ed17 , 0x2bcb6d1f , 0xde481610 , 0xe126f659 , 0x37eb7960 , 0x75a673ad , 0x3f4b3158 , 0x17981af5 ] )
+  , Mont::unsafe_make( [ 0x50995752 , 0x5b671744 , 0x6383512b , 0x9b2acf4c , 0xe46f16cc , 0x9324dce8 , 0xa257ebdb , 0x0d741a31 ] )
+  , Mont::unsafe_make( [ 0x6a9ed32a , 0x0c91452b , 0xf9328f0e , 0x959d0747 , 0x897ecc6d , 0x2b16f5c3 , 0xfd42cd29 , 0x2209e9a2 ] )
+  , Mont::unsafe_make( [ 0xc760faca , 0x5ecdf791 , 0x3913e407 , 0x09e481d3 , 0x7f0cb0fb , 0xc2ef0893 , 0xdc27ad97 , 0x2bb0d921 ] )
+  , Mont::unsafe_make( [ 0x6fb9a2d7 , 0x3299ecaf , 0x97b91987 , 0xc0108b74 , 0xb3e1b1be , 0x764ca644 , 0x04148333 , 0x14d7398e ] )
+  , Mont::unsafe_make( [ 0x63411bc0 , 0x2fc6ddd3 , 0xacf008de , 0x6a61f012 , 0x9d30fb42 , 0xd26143a8 , 0x1767fe73 , 0x08efbf6b ] )
+  , Mont::unsafe_make( [ 0x354c9d07 , 0x00abc33f , 0x7f031143 , 0xf68433e1 , 0x219b4229 , 0x7ee6ec29 , 0x9ef37cef , 0x21c77d23 ] )
+  , Mont::unsafe_make( [ 0xe2d7d939 , 0x348b04d2 , 0xeb1a8552 , 0xbb43af2c , 0x1c8b1611 , 0x4b58db5e , 0x93ddc860 , 0x065c3e18 ] )
+  , Mont::unsafe_make( [ 0x9f48d069 , 0x1525000e , 0x0be14c7c , 0x89876f8e , 0x40be5b19 , 0xa5865c93 , 0x4d0628c5 , 0x1c345340 ] )
+  , Mont::unsafe_make( [ 0xcdfa40dc , 0x9cfeb70d , 0x00b086d5 , 0x87daa2e0 , 0x2c9800ff , 0xd205b03b , 0x37659df1 , 0x01d22afb ] )
+  , Mont::unsafe_make( [ 0x49a26248 , 0x21e1ffb9 , 0x85357561 , 0x2d80972d , 0x176f2aca , 0xb9f97a93 , 0x0651b1a7 , 0x171e9b3f ] )
+  , Mont::unsafe_make( [ 0xd4a51660 , 0x2f16a3f1 , 0x27945845 , 0xa03d4048 , 0xbbac2cfb , 0xc2b01c46 , 0x0d585277 , 0x0dc33eeb ] )
+  , Mont::unsafe_make( [ 0xf8429454 , 0x7c47e3bc , 0xc9035ac2 , 0x84cfa89d , 0xf5d8c193 , 0xbb827604 , 0xf3a89698 , 0x2ad6469c ] )
+  , Mont::unsafe_make( [ 0x5cee5e47 , 0x8745a2e7 , 0x6df90885 , 0xabd0cb31 , 0x2665fe1e , 0x39b59731 , 0x014cc910 , 0x009bc897 ] )
+  , Mont::unsafe_make( [ 0x90889516 , 0x9aa85ad7 , 0x691c25f0 , 0x41129fbb , 0x5ddbdfe0 , 0x602958ab , 0x4d6cbd9d , 0x1fbb052d ] )
+  , Mont::unsafe_make( [ 0x9af03b3e , 0xf5498183 , 0xb09204b8 , 0xc5d703b4 , 0x65fad29f , 0xb898b85f , 0x847041cf , 0x0bacb09c ] )
+  , Mont::unsafe_make( [ 0x02fed555 , 0xd0132468 , 0xe4bc603d , 0x25d81c79 , 0xa5a47231 , 0xcb913324 , 0x53c7df48 , 0x02d86b4b ] )
+  , Mont::unsafe_make( [ 0xe71817cf , 0xa83a38a5 , 0x4d75e7a5 , 0x34ad9761 , 0x15ab445b , 0x1b261f68 , 0xf88feddc , 0x28eb3c7c ] )
+  , Mont::unsafe_make( [ 0xd0b056fb , 0x013918e3 , 0x669c6c75 , 0xd78fbf47 , 0x27c6eca3 , 0x3d63fe83 , 0x2ac3866c , 0x14107527 ] )
+  , Mont::unsafe_make( [ 0xb8a5c59e , 0xdc193d70 , 0xdccf9aa5 , 0xd4e13a44 , 0x6aa81c38 , 0xaa116da3 , 0x6a8ccff1 , 0x1f031e68 ] )
+  , Mont::unsafe_make( [ 0xb5322532 , 0x8fbde8fb , 0xbfbe1464 , 0x2ff5c3e7 , 0x410c7605 , 0xd993c2f1 , 0x1213b3be , 0x1113ff7c ] )
+  , Mont::unsafe_make( [ 0xec2d5d7f , 0xccc937e2 , 0x7a4853fb , 0x462b4eb6 , 0x900874fe , 0x01d3a426 , 0x2ac8b110 , 0x2e42cfe0 ] )
+  , Mont::unsafe_make( [ 0xbc8b2bc5 , 0xb28d274b , 0xa3c2b85a , 0x62e7a2ac , 0xb12ee805 , 0xd06b38a1 , 0xc83f000a , 0x1bb30993 ] )
+  , Mont::unsafe_make( [ 0x9d74e55b , 0x712c6f00 , 0xabfe4810 , 0x204c11cf , 0xb18b0e31 , 0xdfbfc486 , 0x44c37201 , 0x25fa623f ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0xfd1188ab , 0xb4a5552f , 0x8aaabc54 , 0x7c05c79e , 0xe6e85ccb , 0x9f4b7fd0 , 0x552692d8 , 0x0b2ce8cd ] )
+  , Mont::unsafe_make( [ 0x33f033e3 , 0xdf0e43e6 , 0x14bfce2f , 0x5d571d2d , 0xf2b8132c , 0x6feee126 , 0x3509ddcf , 0x14fbce3f ] )
+  , Mont::unsafe_make( [ 0x73fedf2e , 0xadd7f88c , 0x0fa64c3a , 0x196fe4da , 0x56f0d9d7 , 0x84eb55d7 , 0x57cf3672 , 0x0128c667 ] )
+  , Mont::unsafe_make( [ 0xe64b2b9f , 0xaaacac65 , 0x62dead83 , 0x43a8ec4e , 0x7480e8da , 0x88f0dda9 , 0x37c09b03 , 0x264b026d ] )
+  , Mont::unsafe_make( [ 0x400af9f4 , 0xeaf4b26d , 0x47ece8eb , 0xeaa83aa9 , 0xde71e483 , 0x07f9263f , 0xa9406656 , 0x1afb56bb ] )
+  , Mont::unsafe_make( [ 0xcc1ac731 , 0x3a9dcdd7 , 0x91f034f0 , 0xb3b1fbe4 , 0x43d67196 , 0x3e35dd2b , 0x8af582cf , 0x22cc3770 ] )
+  , Mont::unsafe_make( [ 0x7cc8d251 , 0xed0e3e2a , 0x4d0c4472 , 0x55cbbba3 , 0xcce34055 , 0xdac404e2 , 0x6312d16e , 0x08c23d5c ] )
+  , Mont::unsafe_make( [ 0x89ff4313 , 0x8f553157 , 0x28f31255 , 0x4cb10863 , 0xb159f26b , 0xef840e3b , 0xb1c9da8a , 0x286954fe ] )
+  , Mont::unsafe_make( [ 0xce0a16d6 , 0x2d2c19f2 , 0x37b2689d , 0x81b53da6 , 0x2c922740 , 0x4f62fdb9 , 0xcabb8365 , 0x18ba0ed3 ] )
+  , Mont::unsafe_make( [ 0x41a051a8 , 0x3916c383 , 0x5eabc26b , 0xcff60f65 , 0x9f0343d4 , 0x8342740f , 0x2603a3eb , 0x2758c17f ] )
+  , Mont::unsafe_make( [ 0xa2a3c44c , 0xea977f13 , 0xa92f81e8 , 0xdbe276de , 0xf11aea49 , 0x609e7097 , 0x107be005 , 0x05494693 ] )
+  , Mont::unsafe_make( [ 0x56d03878 , 0x67110c4f , 0x26eff4dd , 0x4e820ec7 , 0x2669e7ba , 0xb0d3df76 , 0x9d120b75 , 0x0f60982e ] )
+  , Mont::unsafe_make( [ 0x4dc2fce4 , 0x3cc0cd55 , 0xa84ec227 , 0x08795106 , 0x7e6a9a94 , 0xd6396bed , 0x42c6bc85 , 0x21564234 ] )
+  , Mont::unsafe_make( [ 0x80b420d5 , 0x0bb90763 , 0x67dc6558 , 0x3022ceb2 , 0x85cbe011 , 0xc7ec58fd , 0x1c37e85c , 0x1961b742 ] )
+  , Mont::unsafe_make( [ 0x00adf8e6 , 0xdbdfe7f2 , 0x219f025c , 0x543b62cd , 0x62e8c806 , 0xcc22cd08 , 0xbbae00ab , 0x29f75241 ] )
+  , Mont::unsafe_make( [ 0x9d079be1 , 0x49a3a254 , 0x33580cc9 , 0x913caeb2 , 0xb8f9f404 , 0x957d7c63 , 0x04987f93 , 0x1b8b328e ] )
+  , Mont::unsafe_make( [ 0x8f7cc34c , 0xa121e4cb , 0xd243ed92 , 0x3883089b , 0x7d55f9c9 , 0x49bbbb9a , 0x26a22b4d , 0x041ac4e9 ] )
+  , Mont::unsafe_make( [ 0x551710ff , 0x4af55d3b , 0xa82953c1 , 0xf9cb0b4f , 0xb8d908c6 , 0x8d4ab677 , 0xe135f1a6 , 0x2e70accb ] )
+  , Mont::unsafe_make( [ 0x4fa24541 , 0x985d1c37 , 0xcb05a296 , 0xb899b58e , 0x4c17c090 , 0x0e68ea46 , 0x9d9c86db , 0x0e7c8878 ] )
+  , Mont::unsafe_make( [ 0xf9f45027 , 0x422ec30e , 0x3241477b , 0x85842cf6 , 0xdeaab547 , 0xb6d32137 , 0xc7a516eb , 0x2a473431 ] )
+  , Mont::unsafe_make( [ 0x488fd23a , 0xfb7e5aa2 , 0xbcdeb745 , 0x2e31f366 , 0x01f9c58c , 0x3b5da5c6 , 0xa09d6391 , 0x1f5f50ac ] )
+  , Mont::unsafe_make( [ 0x9db890d4 , 0xcc20ea3f , 0xbee2f414 , 0xed891f88 , 0x47e8f4ac , 0xd857ca9e , 0x15ba951f , 0x26ac81f5 ] )
+  , Mont::unsafe_make( [ 0x94535412 , 0x8ea9b4e1 , 0xd2d80d10 , 0x82e5b15a , 0xbb499d1d , 0x7b985cbf , 0xc1d368a6 , 0x1d739687 ] )
+  , Mont::unsafe_make( [ 0x7845fe7a , 0xd736ebb0 , 0xa099c3a7 , 0xa074dd50 , 0x6a24d7d4 , 0xe9a8fb46 , 0xe27e75d2 , 0x07853208 ] )
+  , Mont::unsafe_make( [ 0xc84a0c1c , 0xcf782462 , 0xadf7c309 , 0x93a568ad , 0xb2138363 , 0x1a86a770 , 0x6468349d , 0x1886b1d6 ] )
+  , Mont::unsafe_make( [ 0x41d5735c , 0xbbbc7d5c , 0x90537079 , 0x71875dcf , 0x6f1df830 , 0xb0e72fc9 , 0x2f4ebec4 , 0x01d9ec8d ] )
+  , Mont::unsafe_make( [ 0x68608a79 , 0x83dbf4b4 , 0xa3b5806a , 0xedbc50de , 0xd978fc5a , 0xec05b092 , 0x6f1f1abc , 0x09acf196 ] )
+  , Mont::unsafe_make( [ 0xeb4233a0 , 0x68425408 , 0x69542290 , 0x4272c253 , 0x661437f1 , 0x61133fa4 , 0xf3eeb29b , 0x17a9b294 ] )
+  , Mont::unsafe_make( [ 0x0ff01b27 , 0x8a29dfab , 0x73134d64 , 0x240dc6bb , 0x922a17db , 0x840297de , 0xa80daa1a , 0x22c98aae ] )
+  , Mont::unsafe_make( [ 0x7706c0a7 , 0xf932032a , 0x462e078d , 0xd2569dcc , 0xa4cda957 , 0x106fcec9 , 0x68808615 , 0x2f4f241f ] )
+  , Mont::unsafe_make( [ 0xa9554c5a , 0xfcf960e8 , 0xedfb805f , 0x0c67573f , 0x78d84d31 , 0xcf33faab , 0xcdbb37f0 , 0x1ea1faa4 ] )
+  , Mont::unsafe_make( [ 0x71429084 , 0x6f73643e , 0x37edc725 , 0xb716ae20 , 0xe30afe80 , 0x7910f1d6 , 0x33e3a399 , 0x17204549 ] )
+  , Mont::unsafe_make( [ 0xf7a8c7d9 , 0x765169cf , 0xad8bd2c5 , 0xe834525b , 0xc436d73b , 0x3dcbdadd , 0xf9c07068 , 0x1e823610 ] )
+  , Mont::unsafe_make( [ 0x269e3cf8 , 0x7450ffc7 , 0xd82598d9 , 0xb036882d , 0x13a25243 , 0x04e4d639 , 0x77bab7c2 , 0x303a76e1 ] )
+  , Mont::unsafe_make( [ 0x49c81384 , 0x2727d079 , 0x4276ca22 , 0xe1a18b7f , 0x196e40ec , 0xb8d4682f , 0xec738fde , 0x275a8f4a ] )
+  , Mont::unsafe_make( [ 0xe7e4af04 , 0x322dc793 , 0xb71d05e3 , 0xf1ad9c41 , 0x785f3afd , 0xc0ef9b0f , 0xc8e5ee80 , 0x022bf406 ] )
+  , Mont::unsafe_make( [ 0x287458fc , 0x25e2c6e4 , 0x29cee312 , 0x77dfcfbe , 0x4e6af200 , 0xecf80fbb , 0x50e1a736 , 0x1c618c07 ] )
+  , Mont::unsafe_make( [ 0xe0f63882 , 0x3da830f5 , 0x99935de0 , 0xa040a78e , 0xed5b0222 , 0xa56c60c3 , 0x544c5f46 , 0x0366705f ] )
+  , Mont::unsafe_make( [ 0x3186efce , 0xca1a48aa , 0x7ee29c2d , 0x0e8b0d65 , 0x18f20b39 , 0x3620088e , 0xa616e49a , 0x28de3d1f ] )
+  , Mont::unsafe_make( [ 0xbe99c35c , 0x39c66634 , 0x473ec41d , 0x35fadb97 , 0xf1f370a5 , 0xc92b7720 , 0x13ee87b2 , 0x2ae88154 ] )
+  , Mont::unsafe_make( [ 0xc7289367 , 0xefc4b4a9 , 0xfcc2dcfc , 0x7a964554 , 0x223f5f3c , 0x0b023778 , 0xb45b0dfd , 0x2f8183dd ] )
+  , Mont::unsafe_make( [ 0x64f25002 , 0x44644dcc , 0x2d158f73 , 0xe85467be , 0xa020fe95 , 0x6a5e4ecb , 0x68cfe88e , 0x2958e2e7 ] )
+  , Mont::unsafe_make( [ 0xf229175c , 0x39983c61 , 0x9d7bb73a , 0xb6351704 , 0xabfce9ff , 0xa43d8fe4 , 0xc2e1d3f9 , 0x0716c532 ] )
+  , Mont::unsafe_make( [ 0x9c6b184e , 0xe89136b5 , 0x73a5a612 , 0x856f04d5 , 0x003fed6f , 0xfaa8cb44 , 0x8e0c2fc2 , 0x2e8ec4f3 ] )
+  , Mont::unsafe_make( [ 0x43da11df , 0x2790fb7c , 0x3b638564 , 0xcc7570e1 , 0xa26755c5 , 0xbf279bfc , 0x7e892946 , 0x105cd57e ] )
+  , Mont::unsafe_make( [ 0xb9e9b597 , 0x3b48e9bf , 0x77fe90a7 , 0x5bd8299a , 0x71815757 , 0xa6c0490d , 0x53db8756 , 0x0b3204ff ] )
+  , Mont::unsafe_make( [ 0xadb4bb64 , 0xcf003941 , 0x9c00ef62 , 0x0b536aec , 0xfcda8c06 , 0x98e9e7e9 , 0xf19ba2e3 , 0x05e40b24 ] )
+  , Mont::unsafe_make( [ 0xdb41eb8e , 0x086fc9ef , 0x33110c66 , 0x31f89418 , 0x6ebc1440 , 0x4ff33452 , 0x4c3ccc33 , 0x2288c1d3 ] )
+  , Mont::unsafe_make( [ 0xf40a8298 , 0x9508d4c9 , 0xdc434b12 , 0x2f99a8ef , 0xfbe96175 , 0x5c07d023 , 0xdccc6e78 , 0x1bfd22d1 ] )
+  , Mont::unsafe_make( [ 0xfb4769d9 , 0xe43670b9 , 0x5d4661f1 , 0x934350fd , 0xe79f7adf , 0x52d1bd3b , 0x77a938e4 , 0x1c4f9d0d ] )
+  , Mont::unsafe_make( [ 0x4db3558e , 0xb68f3047 , 0xec6788c8 , 0x6abda75f , 0x3a1ae09f , 0x7d159d33 , 0x5bb0418e , 0x209e5dc8 ] )
+  , Mont::unsafe_make( [ 0x22d90dfe , 0x46ad5cb9 , 0x84be067f , 0xa14e95d8 , 0xda24d6a9 , 0x373f9198 , 0x8c72307b , 0x006babe7 ] )
+  , Mont::unsafe_make( [ 0xe9f4d4c9 , 0x6fc78c9c , 0x811ffe0e , 0x743a7e61 , 0x9c03e6be , 0xa65bb545 , 0x24198060 , 0x1c0ced17 ] )
+  , Mont::unsafe_make( [ 0xa33dde37 , 0x2120f422 , 0x7296f13a , 0xda3f2ef1 , 0x884774b5 , 0x8903531f , 0xbd67a346 , 0x22ddbbc9 ] )
+  , Mont::unsafe_make( [ 0x524d51b7 , 0xa5f4c0c6 , 0x1c00e95d , 0x28a6595f , 0xd8257c48 , 0x6947e15c , 0x1b0cef6d , 0x0cdee2e5 ] )
+  , Mont::unsafe_make( [ 0x4bacca35 , 0xb13831b9 , 0xc8779548 , 0x1be2d0e0 , 0x6ee7716f , 0xc6632e87 , 0xc3f1be77 , 0x1f4de9e7 ] )
+  , Mont::unsafe_make( [ 0x255c8fbb , 0xc3692acc , 0x6adabb55 , 0x3890938f , 0x61c35ba2 , 0x245efac2 , 0x9e06b8c1 , 0x1e584b75 ] )
+  , Mont::unsafe_make( [ 0x3a579f4f , 0xddba383b , 0xfbb01c55 , 0x56959efe , 0x61f75005 , 0xfd12bbf5 , 0xbd5f4906 , 0x06043ef1 ] )
+  , Mont::unsafe_make( [ 0x600f2236 , 0x56c8bcbc , 0x569bdbf2 , 0x5e8ed197 , 0x8ea66274 , 0xf22dffc2 , 0x979a0e9f , 0x2e208d89 ] )
+  , Mont::unsafe_make( [ 0x012b8c40 , 0x98107402 , 0x5b9049cb , 0x0aae1f09 , 0xd4185c59 , 0x5cbb088c , 0x5d308528 , 0x14be80b0 ] )
+  , Mont::unsafe_make( [ 0x0a53ec67 , 0xedbd2289 , 0x574f1f90 , 0x5c9a266f , 0x94778748 , 0x2d6734bc , 0xcd7b4a11 , 0x1b3feae7 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
@@ -0,0 +1,3058 @@
+
+// circomlib's Poseidon constants for t=17
+
+use crate::bn254::montgomery::*;
+
+
+//------------------------------------------------------------------------------
+
+// round constants (t for external, 1 for internal rounds; flattened)
+pub const CONST_C: [Mont; 204] = 
+  [ Mont::unsafe_make( [ 0x459669df , 0x653348de , 0x2228a79c , 0x49a80b49 , 0x434a5461 , 0xebd300a5 , 0x3ad6d00f , 0x08b3b62c ] )
+  , Mont::unsafe_make( [ 0xd8fa90a1 , 0x8797c8ae , 0xdba10b66 , 0x4b216274 , 0x14af68a3 , 0xf6b72dad , 0xf3752325 , 0x2f0a8592 ] )
+  , Mont::unsafe_make( [ 0xc1034a46 , 0x72c42698 , 0xed9da5ad , 0xb53cacb4 , 0x80f6b58a , 0xbc1c8465 , 0xad559a07 , 0x1823e5bb ] )
+  , Mont::unsafe_make( [ 0xeb639767 , 0x9d572f7d , 0x97083caa , 0xf41b90e0 , 0x9ba5244c , 0x27aa1b21 , 0x90456b74 , 0x189cdc9a ] )
+  , Mont::unsafe_make( [ 0xe32cb991 , 0x9e0b3fe4 , 0x47b9d821 , 0x9da7c884 , 0x3aeacc22 , 0xf00e4a66 , 0x11b2ea14 , 0x23a83e69 ] )
+  , Mont::unsafe_make( [ 0x1ff96b1b , 0xa6f29fa2 , 0x1e9b2a5b , 0xd90787c8 , 0xd6c2567f , 0x89c8a665 , 0xba1996e2 , 0x2b521ce8 ] )
+  , Mont::unsafe_make( [ 0xe54e9ae1 , 0x5f18e774 , 0x459e8b68 , 0x6bebd53a , 0x4f865646 , 0x62d10b97 , 0xb79dd573 , 0x2f2feaae ] )
+  , Mont::unsafe_make( [ 0x2181f48b , 0x3f758c97 , 0x104e0b09 , 0xf61e5e65 , 0x903a9f88 , 0x7d2ec40e , 0xec5ed563 , 0x1b7c139f ] )
+  , Mont::unsafe_make( [ 0x91804e68 , 0xb8db8f19 , 0x4ef3a5c4 , 0xc19bbebe , 0xd2b4ea9c , 0x59a50a20 , 0x5ebc6933 , 0x3051ddc6 ] )
+  , Mont::unsafe_make( [ 0xd1c7ae83 , 0x22efdd7d , 0xb97edf04 , 0xbf83fe40 , 0xc59a00d2 , 0x351baca8 , 0xfeb5297e , 0x25259ce7 ] )
+  , Mont::unsafe_make( [ 0xe2243fad , 0x24262945 , 0x0dafc151 , 0xfd2e02b5 , 0x53e43d1b , 0x8591f029 , 0x382838ee , 0x2a950913 ] )
+  , Mont::unsafe_make( [ 0x4dcb3673 , 0x63e28a2f , 0x9f94ab99 , 0xdba17482 , 0xbd7e5000 , 0x29aefdb4 , 0x0f390b88 , 0x0bfa6748 ] )
+  , Mont::unsafe_make( [ 0xb31bf956 , 0x8d4adb52 , 0x23fa727a , 0xf97606b6 , 0xc03066eb , 0xdfb3a7df , 0x4865560a , 0x01e68329 ] )
+  , Mont::unsafe_make( [ 0xbb033922 , 0x54448e97 , 0xc467e509 , 0x99fd75e3 , 0x70a4f512 , 0xcb5bdbcc , 0xa69c63b1 , 0x2173eb51 ] )
+  , Mont::unsafe_make( [ 0xb34f4b29 , 0x370abece , 0xca220d08 , 0xd28089ce , 0xa43783e5 , 0x5e00ce07 , 0x9c6d8011 , 0x059974c2 ] )
+  , Mont::unsafe_make( [ 0x054f5fa8 , 0x87627eb9 , 0xd22fa311 , 0x2513813b , 0x37fd5a28 , 0xf388c5fa , 0xf0f01d10 , 0x29212b6d ] )
+  , Mont::unsafe_make( [ 0x2cdcfa2c , 0x3905de18 , 0x59626469 , 0xd0c2a69d , 0x5b8ec932 , 0x508b9480 , 0xc92ede9a , 0x16f87293 ] )
+  , Mont::unsafe_make( [ 0x14e71c7d , 0xfd317428 , 0xfae9d865 , 0x1c829d47 , 0x079999a0 , 0xe71f3201 , 0x4c28b223 , 0x1ba6eb0c ] )
+  , Mont::unsafe_make( [ 0x6a4db9bb , 0xe0850767 , 0x1ea4ed4d , 0x37a1410a , 0x4f6eb005 , 0xa32c4209 , 0x08370ca6 , 0x285547bb ] )
+  , Mont::unsafe_make( [ 0x7969ea36 , 0x9efb62b4 , 0x11f2ff40 , 0x3178ce55 , 0x213d6468 , 0xad53c9ea , 0x2caa8ddd , 0x0b6c10ef ] )
+  , Mont::unsafe_make( [ 0xa2feeffe , 0x848d7de5 , 0x26e157b7 , 0xc13c3db1 , 0xa9e3bf52 , 0x4159ccf6 , 0xf89fd59c , 0x0c5c3273 ] )
+  , Mont::unsafe_make( [ 0x47858571 , 0xf78f9695 , 0x85896798 , 0x8215c5dd , 0x7a1813c5 , 0x7eef5e66 , 0x71c1ec82 , 0x075cfcb9 ] )
+  , Mont::unsafe_make( [ 0x8437b9fc , 0x3937d253 , 0xe758b9e2 , 0xddb9e176 , 0x50245c03 , 0xd5aa0ab9 , 0x5bfccc80 , 0x0e8e3e13 ] )
+  , Mont::unsafe_make( [ 0xb5235c5d , 0x55108273 , 0xc1895fce , 0x6baf696e , 0x8f8d9d88 , 0x1c92adc2 , 0xdae5b3a6 , 0x1c830a8c ] )
+  , Mont::unsafe_make( [ 0x55e0f86f , 0xd04d7f68 , 0x1dbe8335 , 0x2b5bc964 , 0x598f4f30 , 0xe46fc66d , 0x3351463b , 0x09cf8c09 ] )
+  , Mont::unsafe_make( [ 0x6a686591 , 0xd11c8378 , 0x9f52a716 , 0x24aa5761 , 0x10a962e8 , 0x71faa400 , 0x89e76ae9 , 0x27377e07 ] )
+  , Mont::unsafe_make( [ 0xed4b8ac4 , 0xb89fa735 , 0xd0f9790f , 0x8407c407 , 0xe4f60d5f , 0xcd1a0699 , 0x2cac2b4e , 0x0cabf2bc ] )
+  , Mont::unsafe_make( [ 0x25ec4424 , 0x1f4c8f41 , 0x2ddd6f91 , 0xfd2c6e49 , 0x3dcc20b1 , 0xee662544 , 0xca055775 , 0x0802bb94 ] )
+  , Mont::unsafe_make( [ 0x5c3038b2 , 0x66b4fb36 , 0x1d6ed2ae , 0x7b0e2fd6 , 0xb3f4c4e6 , 0x43323ed3 , 0x0ded4847 , 0x2a4fb56a ] )
+  , Mont::unsafe_make( [ 0xf61e096d , 0x6910f726 , 0xf2894226 , 0x51d15143 , 0x551a0754 , 0xb97e5a5d , 0x6ba9c56f , 0x0a070776 ] )
+  , Mont::unsafe_make( [ 0xb2e37ad4 , 0x201c3954 , 0x4c6263de , 0x67b6d457 , 0xe49e2184 , 0x77c0d587 , 0x27b99008 , 0x1df728af ] )
+  , Mont::unsafe_make( [ 0x2d0aecaf , 0x8a50443d , 0xf15371a1 , 0x1d3a8694 , 0xf97ada7e , 0x6cd32451 , 0xf5d112cd , 0x0601ac7a ] )
+  , Mont::unsafe_make( [ 0x572a35e7 , 0x0f047c8e , 0x4ed3ba81 , 0x05860aa0 , 0x3c094b41 , 0x627db775 , 0xaf9fe70a , 0x18fe2029 ] )
+  , Mont::unsafe_make( [ 0xca918a3e , 0x1d00f634 , 0x4018d9ba , 0x75aa5848 , 0xc0e7a510 , 0xb3cca73a , 0xd9fa8ee2 , 0x1f57df3d ] )
+  , Mont::unsafe_make( [ 0x5b69bf1f , 0x1b9c2e90 , 0x00d17acd , 0xaed7f022 , 0x300372ed , 0x9e137157 , 0x7511dfd9 , 0x0231ba51 ] )
+  , Mont::unsafe_make( [ 0x314a8b7b , 0x9b2f8d33 , 0x12c35fd8 , 0xa7932960 , 0x8017a948 , 0x21f95498 , 0x182cd1df , 0x11c60db1 ] )
+  , Mont::unsafe_make( [ 0x44dcdaec , 0x214955d9 , 0x4dc88042 , 0xe2316a6e , 0x4af10688 , 0x9985be33 , 0xa7f4fd68 , 0x118053d0 ] )
+  , Mont::unsafe_make( [ 0x5785627c , 0xa9f7ebfe , 0xb5fea699 , 0x7ffc7612 , 0x973be40b , 0x629a8d1f , 0xed903678 , 0x2a6709da ] )
+  , Mont::unsafe_make( [ 0x24ef2fb4 , 0x2f364b8d , 0xe162f3f0 , 0x8fe217e6 , 0xd843a0e6 , 0x766130b6 , 0x2dcc9eec , 0x17c8df3d ] )
+  , Mont::unsafe_make( [ 0xb51d1736 , 0xd0d509d9 , 0xa72ed726 , 0x1726a148 , 0xf8252183 , 0xd11606e1 , 0xefaca663 , 0x04bb2bbb ] )
+  , Mont::unsafe_make( [ 0x432ea1dc , 0xf9b883b2 , 0xdfbdc998 , 0xfd527f65 , 0x28853406 , 0x6944cfbb , 0xd9de1cf2 , 0x15fb367e ] )
+  , Mont::unsafe_make( [ 0xc0088415 , 0xedb2452b , 0x2412dd85 , 0xf0376ea1 , 0x13897458 , 0x447fc701 , 0xe1c4727e , 0x0f917260 ] )
+  , Mont::unsafe_make( [ 0xa6d6ad84 , 0xef7b7f9a , 0xa14cb497 , 0xdc414cdb , 0x17ca88b2 , 0xf646d85b , 0x143b993e , 0x21a503dc ] )
+  , Mont::unsafe_make( [ 0xfe9de2e3 , 0xa68316cb , 0xaece0f4a , 0x5f297459 , 0x5e12bad1 , 0x86cbceac , 0xb60b9f55 , 0x1a260ed6 ] )
+  , Mont::unsafe_make( [ 0xf8484cc8 , 0x06e04fe4 , 0xd4a934dd , 0x3f193287 , 0x1c87ffe0 , 0xbca9c2b1 , 0xcba55058 , 0x095d9a5a ] )
+  , Mont::unsafe_make( [ 0x0af3f482 , 0xdad78fee , 0x995ab5a7 , 0x62c45907 , 0x510d8686 , 0x8d7d0baa , 0xf0bd559e , 0x0bffc2f8 ] )
+  , Mont::unsafe_make( [ 0xf7da97ca , 0xa626b2c2 , 0x0a1e23ac , 0xf2735a5a , 0x983ad475 , 0xd6526597 , 0xc9f82509 , 0x12c9a670 ] )
+  , Mont::unsafe_make( [ 0x69164b53 , 0x1b8fe512 , 0x63cfb0c8 , 0x25ffe1ab , 0x7126bb13 , 0x943dbc01 , 0x955cf7c0 , 0x0173adc2 ] )
+  , Mont::unsafe_make( [ 0x87359e61 , 0x6a570ac7 , 0x97325d09 , 0xdaaa2a43 , 0xf446d5ec , 0xfee5df2c , 0x35dfb1ef , 0x21ac488c ] )
+  , Mont::unsafe_make( [ 0x2c58b6e2 , 0xb7270492 , 0x09296659 , 0xa33037ff , 0xd127ad6c , 0xba0d985d , 0x71a2ba40 , 0x16ae0e71 ] )
+  , Mont::unsafe_make( [ 0x889edf2a , 0x1327026b , 0x72c94f74 , 0xaafb3aad , 0x4dbdb3ef , 0x60c628ae , 0x09628c5b , 0x0f2bc28a ] )
+  , Mont::unsafe_make( [ 0x0c4e7aed , 0x4e412417 , 0xb797e311 , 0x59675fb7 , 0xb4167b3f , 0x1b969a2c , 0xa67bd526 , 0x2f73b479 ] )
+  , Mont::unsafe_make( [ 0xa39b86d2 , 0x48a778b6 , 0xcd9281e0 , 0x5a8fcbba , 0x9a05802a , 0x22c7020b , 0xa12eee34 , 0x21dc7dcc ] )
+  , Mont::unsafe_make( [ 0x1ec35e4a , 0x57d3c3e2 , 0xb44a57c0 , 0x392e0466 , 0x77d89ce0 , 0x71408fb1 , 0x055b3267 , 0x0d584151 ] )
+  , Mont::unsafe_make( [ 0x39175441 , 0x88e03684 , 0xd8ea91c7 , 0xe854d1ed , 0x4af99758 , 0x287c094f , 0xd09303bb , 0x1ec8b50d ] )
+  , Mont::unsafe_make( [ 0xe9c8ee41 , 0x28af89e6 , 0x942b4985 , 0x9e5cce50 , 0x72298d4e , 0xc1dd2df0 , 0x41c6ec23 , 0x1bf9c9ee ] )
+  , Mont::unsafe_make( [ 0x62e02068 , 0x39e1cde2 , 0xd26f5b46 , 0xbf12ce5f , 0x8031f175 , 0x98973ea6 , 0x8b66243d , 0x0213b645 ] )
+  , Mont::unsafe_make( [ 0x591d42d1 , 0x693dd139 , 0xc6a47edd , 0xd21154ff , 0xcc434176 , 0x286303ec , 0xc700c953 , 0x2b38f0a6 ] )
+  , Mont::unsafe_make( [ 0x18b283e2 , 0x5ecada6c , 0xf126f7b8 , 0xa5a27b36 , 0x94607b9c , 0xfc7c6caa , 0xc213ca7c , 0x11eb1b43 ] )
+  , Mont::unsafe_make( [ 0x02bba072 , 0xe459407f , 0x67eba7d0 , 0x93cef7ba , 0x30cce42a , 0x20d74d5d , 0x0545f2eb , 0x055177f0 ] )
+  , Mont::unsafe_make( [ 0xfe016219 , 0x97f59c6d , 0xeddfc430 , 0x8e31e75b , 0xdba505d1 , 0xe07aa7a3 , 0xea01b1f2 , 0x039f2b7d ] )
+  , Mont::unsafe_make( [ 0x10a8697e , 0xfcd6abad , 0x8e7b87ae , 0xcc5b6ef0 , 0x84700bf6 , 0xd314f54e , 0x3ef7b016 , 0x2d575079 ] )
+  , Mont::unsafe_make( [ 0xe559997c , 0x0001a192 , 0xf8715f39 , 0x9eff7a9c , 0xb7f6b07b , 0xd7688fee , 0x01384841 , 0x0063e017 ] )
+  , Mont::unsafe_make( [ 0x7eac768b , 0x4375b357 , 0xd02d92b5 , 0x8d2acbeb , 0xf7c372f1 , 0xdfd2ffd9 , 0xb6774972 , 0x2fa1954f ] )
+  , Mont::unsafe_make( [ 0xc77d64d9 , 0xcf53f25d , 0xb6c1cd92 , 0xeadbf2d0 , 0x488b185e , 0x47dfd66c , 0xff43cbe3 , 0x1e13fe38 ] )
+  , Mont::unsafe_make( [ 0xe552c43e , 0x0251e71c , 0x3363e969 , 0x7153436c , 0x0aa2256a , 0x47c27521 , 0x076e7cdd , 0x164415fb ] )
+  , Mont::unsafe_make( [ 0xd90351bc , 0x66fa0731 , 0x977b5ea6 , 0x8880c0ef , 0x09cbd32b , 0xe13a5e1e , 0xf32cd314 , 0x01438119 ] )
+  , Mont::unsafe_make( [ 0x15eedddb , 0xbb8d2fcc , 0x9916bfdb , 0xa058ce46 , 0xe96b906b , 0x392ca4c0 , 0xf97c8cd5 , 0x154018a4 ] )
+  , Mont::unsafe_make( [ 0x9680bb85 , 0x8fa6687e , 0x71daae89 , 0xfbbca035 , 0xa6974657 , 0x59a52420 , 0x8bd4eb54 , 0x020b0870 ] )
+  , Mont::unsafe_make( [ 0xd8cfb855 , 0x864a8d3b , 0x4bc07039 , 0xd739764a , 0xd0bd7d27 , 0x0287d0c4 , 0x2adf2ee1 , 0x141f18f2 ] )
+  , Mont::unsafe_make( [ 0xc9d9384d , 0x3551a5dc , 0x981a9c3a , 0x37bd54a3 , 0xa299e298 , 0x7bf27c61 , 0x45d7985d , 0x244dd8b6 ] )
+  , Mont::unsafe_make( [ 0xd0093212 , 0x8ade9c41 , 0xc03f96de , 0x98488c11 , 0xd563c57e , 0x47552a9a , 0xc717a89f , 0x02e7b996 ] )
+  , Mont::unsafe_make( [ 0x4852e95b , 0x1f878dae , 0x466b3692 , 0x0645e672 , 0x11dc2a10 , 0xeb57c53f , 0xf902cebc , 0x084db267 ] )
+  , Mont::unsafe_make( [ 0x56b618e2 , 0x385d8ee9 , 0xcf426cb1 , 0xc8afe2fc , 0x18104eac , 0x801b63e5 , 0xb2414f94 , 0x085724f3 ] )
+  , Mont::unsafe_make( [ 0x7d48b64a , 0xf8342c90 , 0xd26d3e97 , 0x52f5e937 , 0x2470f687 , 0x30e1a8a4 , 0x804622bc , 0x181b7f0a ] )
+  , Mont::unsafe_make( [ 0xc7e15674 , 0x0ab7d927 , 0x3af085bc , 0xf4174bc1 , 0x454eb82b , 0xd0863fed , 0xd3abf901 , 0x1e3cbc6e ] )
+  , Mont::unsafe_make( [ 0x6d60e560 , 0xbf0dda06 , 0xcc793107 , 0xbbb9bfc9 , 0xd54ccee9 , 0x799ff312 , 0x2c20f6d3 , 0x16413ab4 ] )
+  , Mont::unsafe_make( [ 0x458824bb , 0x5c6a66a7 , 0xc322a005 , 0x02a00bee , 0x6f1d09f3 , 0x55ba24a3 , 0x16f35f03 , 0x179f0a36 ] )
+  , Mont::unsafe_make( [ 0xd265e0a5 , 0xb408ee56 , 0x622a5737 , 0x0ca72523 , 0x9410e9bd , 0xa3866a08 , 0xf46f97d1 , 0x082feb0d ] )
+  , Mont::unsafe_make( [ 0x2d97e670 , 0xec209bcd , 0xa71f41e5 , 0xcfb84a60 , 0x900c97a5 , 0xbb80bffb , 0x124ed806 , 0x2bf6992b ] )
+  , Mont::unsafe_make( [ 0xbad339a6 , 0x3c4294a1 , 0x95b1fe67 , 0xce07c98e , 0xa6ca529c , 0xc3bffebe , 0x09f9748b , 0x1f92386e ] )
+  , Mont::unsafe_make( [ 0x47d423e4 , 0xceb40102 , 0xded0e48e , 0x20bc6a63 , 0x01ae64b4 , 0x82690f39 , 0x7a6e09bb , 0x20fa5c0c ] )
+  , Mont::unsafe_make( [ 0x974eab96 , 0x790d75d7 , 0x95169a26 , 0x4ce50ae2 , 0x3e3c071c , 0x2057adb2 , 0x8b83be95 , 0x0188f41c ] )
+  , Mont::unsafe_make( [ 0xd68b1a9f , 0x32163ba4 , 0x32c2971b , 0x7ced022b , 0x0df09aad , 0xf34cd91e , 0x1fd0839e , 0x2b2c17e2 ] )
+  , Mont::unsafe_make( [ 0x5fe84deb , 0xf1c27b6d , 0xd8cb461e , 0x5ea09f23 , 0x92d6b92e , 0xf611cb41 , 0xb90dd1f4 , 0x1e191ebb ] )
+  , Mont::unsafe_make( [ 0xfd7c4d6e , 0xb17c2eec , 0x5ac82ffc , 0x3e3d6652 , 0x8e21e70d , 0x8c94f569 , 0x21377379 , 0x27603d40 ] )
+  , Mont::unsafe_make( [ 0x8228f9aa , 0xded24084 , 0xea203dbd , 0x726bf89a , 0xd72293e9 , 0xa95c35c5 , 0x71e0f130 , 0x094892dd ] )
+  , Mont::unsafe_make( [ 0x7fd4cf27 , 0xc8fc274f , 0x050f6e15 , 0x33d52889 , 0xf677b31e , 0xd396533b , 0x4d832212 , 0x0033791e ] )
+  , Mont::unsafe_make( [ 0x0f4e80c9 , 0x3b0f40ff , 0x60989176 , 0xffae66cc , 0x118ba4ad , 0xe7d12009 , 0x65dc36e0 , 0x2bbbe74c ] )
+  , Mont::unsafe_make( [ 0x2f98e8c3 , 0xde9187e8 , 0x66ccb2d5 , 0x11df984e , 0xe11c56a9 , 0xa854c1a7 , 0x27416efd , 0x03987ecd ] )
+  , Mont::unsafe_make( [ 0xae00070e , 0x65ce73ef , 0xb52920f2 , 0xa3e1aa10 , 0x483f79fe , 0x058db8fe , 0xbb6aba9b , 0x09f0518e ] )
+  , Mont::unsafe_make( [ 0xffb42713 , 0xf3498659 , 0x4cd4cd73 , 0x160003e8 , 0xdb847b15 , 0xce6de21d , 0x0e426cd6 , 0x08355ef7 ] )
+  , Mont::unsafe_make( [ 0x49633214 , 0xb7160b06 , 0xabf1b16a , 0xa3dda4bd , 0x6ca29554 , 0x0b9d8ea1 , 0xb4d43037 , 0x0d517cbc ] )
+  , Mont::unsafe_make( [ 0xc867ce6a , 0x35ec733c , 0x47406f95 , 0x5203813a , 0xe9f42536 , 0x9b90d0b2 , 0xf3e13f71 , 0x12b39df2 ] )
+  , Mont::unsafe_make( [ 0x423b5e8d , 0xacd28a52 , 0x5c4f4787 , 0xde2b3394 , 0x31e846cd , 0x2e9e8c11 , 0xaa70b954 , 0x0b2385af ] )
+  , Mont::unsafe_make( [ 0xb73ea217 , 0x82c65b65 , 0xf19da61d , 0xf6e66261 , 0xf8a8be1b , 0x35c26510 , 0x26222f4b , 0x2d94ab6c ] )
+  , Mont::unsafe_make( [ 0x20f6508f , 0x5e98c7ed , 0x40d1a9d7 , 0x27cae97f , 0x87f30b24 , 0x61999cb8 , 0x73cb6ebc , 0x1a96cc5e ] )
+  , Mont::unsafe_make( [ 0x18bfa269 , 0x3a712a94 , 0x3bf6517b , 0xa5ef35a9 , 0xa563d369 , 0x559adea2 , 0x501dc965 , 0x1cf13961 ] )
+  , Mont::unsafe_make( [ 0xb5cea0ce , 0xa16b352c , 0xebeca01f , 0x2d633acb , 0x548fe882 , 0x57baf379 , 0x0f5b4167 , 0x2cc28101 ] )
+  , Mont::unsafe_make( [ 0x8dd763a4 , 0xdc54998d , 0xf2cee7ca , 0xb2cd6262 , 0xe9713b9a , 0x8a480db4 , 0x524009a4 , 0x178c131d ] )
+  , Mont::unsafe_make( [ 0x98f7dc03 , 0x91984c88 , 0x26c7f4d9 , 0xac54dd7c , 0xc2110892 , 0xd2d5fa23 , 0xfc6bb204 , 0x1157272d ] )
+  , Mont::unsafe_make( [ 0x9023f504 , 0x970e9903 , 0x32b21521 , 0xf73dc2f0 , 0x30654614 , 0x74e857e0 , 0xc6be7e62 , 0x13255754 ] )
+  , Mont::unsafe_make( [ 0x2d4939ab , 0x81499256 , 0xf4bdceb2 , 0x28b1ce2b , 0x99b8f40d , 0x822f3e4e , 0xd9739cd7 , 0x2b694aea ] )
+  , Mont::unsafe_make( [ 0x207b9c2a , 0xe23262b1 , 0xaba12f86 , 0xbe267e99 , 0xadc40f86 , 0x846f139e , 0x85e1c0ee , 0x12aa406e ] )
+  , Mont::unsafe_make( [ 0x35900ea8 , 0xe6da216a , 0x85c7baa4 , 0x20baa8c4 , 0xfe4a8d5f , 0x05974271 , 0xbc339bd5 , 0x08904176 ] )
+  , Mont::unsafe_make( [ 0xb1b2e906 , 0x6e7702ff , 0x39405d65 , 0xbd9af408 , 0xf3b4f941 , 0x0fa9604e , 0xe6d62a33 , 0x2e8372fa ] )
+  , Mont::unsafe_make( [ 0x066a6bdc , 0x14ac313a , 0x2fe17ffd , 0x1a341810 , 0x62c6a8a7 , 0xac20b5cc , 0xcba8d413 , 0x295c30ac ] )
+  , Mont::unsafe_make( [ 0xfcf9f410 , 0x8c862986 , 0xb88f57e5 , 0xc020b77c , 0x0a64d140 , 0xc2f0680e , 0xe85cecb6 , 0x1cf743db ] )
+  , Mont::unsafe_make( [ 0x1875d24a , 0x10c3f431 , 0xfb8e6d11 , 0xd9f59d67 , 0x2d595fb9 , 0x27d5ac75 , 0x410c7469 , 0x206a885e ] )
+  , Mont::unsafe_make( [ 0xef528d33 , 0x9845584d , 0x9091688c , 0x391a2846 , 0x31bfc8bb , 0x20af24e2 , 0xa534d3bc , 0x1680c084 ] )
+  , Mont::unsafe_make( [ 0x647ff3fe , 0x56884bcd , 0xe6fcded9 , 0xd8a8ffa3 , 0x5ca7f4f7 , 0x192458d5 , 0x775219f7 , 0x0f60ab43 ] )
+  , Mont::unsafe_make( [ 0x3ac012e0 , 0x5d59e7a1 , 0xc34b02ba , 0x0aaac564 , 0xa3de50ee , 0x74183b8c , 0xc473550d , 0x05a458e4 ] )
+  , Mont::unsafe_make( [ 0x6180b347 , 0x9368b8b9 , 0xa79af92f , 0x9858d6b6 , 0x49aab188 , 0x9c3b8c5d , 0x5cd62850 , 0x03e0c2b0 ] )
+  , Mont::unsafe_make( [ 0x07504cb3 , 0x9e8f7ec4 , 0xc609fa67 , 0x9822fcd2 , 0x9f8eb606 , 0xbcc47696 , 0x7197e29f , 0x1f9d5021 ] )
+  , Mont::unsafe_make( [ 0x0bb36953 , 0x4c24dd74 , 0x4a1f0c7f , 0xa524908f , 0xbb559606 , 0xaa4e5cdc , 0x913fcb6d , 0x028b558e ] )
+  , Mont::unsafe_make( [ 0x644157e6 , 0x4a401bc4 , 0xde8401b9 , 0x33c122ef , 0x68fa1df2 , 0xd7776e78 , 0x6680fa22 , 0x121f3df0 ] )
+  , Mont::unsafe_make( [ 0xa12081c7 , 0xcef285e7 , 0x19d1edad , 0xf8cdbfd0 , 0x0c7b7df1 , 0xcb9fd580 , 0x103babe9 , 0x121f4b33 ] )
+  , Mont::unsafe_make( [ 0x71c3add7 , 0x719b2e79 , 0x17a82848 , 0xc5001fb6 , 0xedd69d8f , 0xfc8d7ac5 , 0x2ca6792d , 0x1099b5d3 ] )
+  , Mont::unsafe_make( [ 0x36b18cbd , 0x2e20b34d , 0x0cda7007 , 0x6f45d431 , 0x7a3fc38b , 0xb2b5429d , 0x60a3ad6e , 0x2504ab6a ] )
+  , Mont::unsafe_make( [ 0x3acb7402 , 0x9bb14468 , 0x8b433f4a , 0xf86cd434 , 0xce3e72b1 , 0xa0eca2bd , 0xf83a58e0 , 0x1af90835 ] )
+  , Mont::unsafe_make( [ 0xc6061aa5 , 0xe9b42160 , 0xe2bc10b9 , 0xde079b57 , 0x501ba4a3 , 0x8c377b70 , 0xfa3f11c6 , 0x152d1be6 ] )
+  , Mont::unsafe_make( [ 0x20b23ce2 , 0xc4c066ae , 0x0d9188bd , 0x7a429382 , 0x1ad77f9a , 0x16413568 , 0x3c73cf80 , 0x07517446 ] )
+  , Mont::unsafe_make( [ 0xee038c1f , 0x0b5454af , 0xe7497f91 , 0x99c1b508 , 0x73eed64e , 0xccd60145 , 0x53a581d3 , 0x1403ffef ] )
+  , Mont::unsafe_make( [ 0x45626931 , 0x332d8791 , 0x23665ffd , 0xa23b612e , 0xd818147f , 0xa0b1fdf3 , 0xfb02b9a9 , 0x00536f61 ] )
+  , Mont::unsafe_make( [ 0xc2642517 , 0x4af1347d , 0xbc8c28c4 , 0x9ba50dbe , 0x67b868c7 , 0xc55ea2c8 , 0x4fd60f67 , 0x26b5bcd8 ] )
+  , Mont::unsafe_make( [ 0x4d787559 , 0x25e87c00 , 0x27d8661c , 0xa7979235 , 0x423a50cc , 0xccac936c , 0xc6c0b866 , 0x01827a22 ] )
+  , Mont::unsafe_make( [ 0xb28dd09e , 0xfb52531a , 0x418f2852 , 0xf22383b0 , 0x6c454218 , 0x66bc15d2 , 0x4d5e902a , 0x012511b9 ] )
+  , Mont::unsafe_make( [ 0xfa233bc1 , 0x0503c04d , 0xcb6a2a84 , 0x3abb8463 , 0x16958633 , 0x7a8e489f , 0xa26ab715 , 0x08de5674 ] )
+  , Mont::unsafe_make( [ 0x3c8d11e4 , 0x41f41cd0 , 0x85efa087 , 0xc181a165 , 0x054f3449 , 0xc7fec0d3 , 0xb0759702 , 0x2ee4c1a2 ] )
+  , Mont::unsafe_make( [ 0xdc392f7e , 0x7e8dc042 , 0x2fe56153 , 0x7cdd80b8 , 0x1f420740 , 0x0c01a473 , 0xec86f8b0 , 0x2f481198 ] )
+  , Mont::unsafe_make( [ 0x55ea39e2 , 0x80da9c78 , 0xaa001846 , 0xa06279ab , 0xe2f986fe , 0x5b9d7d02 , 0x6176c965 , 0x0d1fbf82 ] )
+  , Mont::unsafe_make( [ 0xdd95c076 , 0xae318552 , 0x77114c7f , 0xb61d34ea , 0x22027559 , 0x32e6aa36 , 0x9edd25b7 , 0x0307cb8a ] )
+  , Mont::unsafe_make( [ 0xe31e4b6b , 0x8a8d501c , 0xdaa6de7a , 0xf672714e , 0x09bcc9ce , 0x068a6e64 , 0x829662a6 , 0x09ce1974 ] )
+  , Mont::unsafe_make( [ 0x22ae1303 , 0xe5342be2 , 0xada5de21 , 0x22c8f6c6 , 0x80beecb0 , 0xa2a89ff6 , 0xed937bc3 , 0x09566ce1 ] )
+  , Mont::unsafe_make( [ 0x00c8a4b0 , 0xe044fbe3 , 0x13443587 , 0x322e75a9 , 0x6c05e13e , 0x64eb4568 , 0x3d3045f2 , 0x2a5e1266 ] )
+  , Mont::unsafe_make( [ 0xa1c00b45 , 0x5dee7c09 , 0x2168214b , 0x55f7ff0f , 0x0d8479cf , 0x3c843f37 , 0x216dc84f , 0x0d1847e9 ] )
+  , Mont::unsafe_make( [ 0xfd21a7ac , 0x26babfca , 0xfe054033 , 0x9183f6b4 , 0x6bd2e2fe , 0xccdfb1e1 , 0x13877de5 , 0x08067432 ] )
+  , Mont::unsafe_make( [ 0x59602146 , 0xb9dfa1a7 , 0x5ada832d , 0x36131e62 , 0x542e2945 , 0x65126759 , 0x76c86db3 , 0x05386f6f ] )
+  , Mont::unsafe_make( [ 0x0547adf1 , 0x90b510ba , 0xfa934351 , 0x977b8a06 , 0x306e1fe7 , 0xd13e7bd0 , 0x4a9e46d1 , 0x2d370584 ] )
+  , Mont::unsafe_make( [ 0xe7d44043 , 0x214f9df0 , 0xe1877a3f , 0x0012fff2 , 0xfb036abf , 0xc0aa7e35 , 0x8dc6a5d5 , 0x15c10419 ] )
+  , Mont::unsafe_make( [ 0x7206074f , 0xeea94ecf , 0x2177467d , 0x08775c91 , 0xe0ca56d7 , 0x84898ab7 , 0x4c687173 , 0x254cdfa3 ] )
+  , Mont::unsafe_make( [ 0xb7a5c936 , 0x371407ff , 0x4b067882 , 0x0767bdf3 , 0xac2b3f20 , 0x05e0ebe5 , 0xc5edbf86 , 0x0c7f1bcf ] )
+  , Mont::unsafe_make( [ 0x02c22077 , 0xdc2a0cfc , 0x6fb2873a , 0x42bbc495 , 0x2d176c6b , 0x01d8aaa2 , 0x0e667eaf , 0x2664c9ca ] )
+  , Mont::unsafe_make( [ 0xc759934b , 0xb8ecf449 , 0x248c7699 , 0x03d0c3c0 , 0x16229f0c , 0x2b049d20 , 0x85a5b2cf , 0x27f781fa ] )
+  , Mont::unsafe_make( [ 0x7f5f84ff , 0xb4a297c1 , 0x2ceee4a1 , 0x1f81882d , 0x39d880fd , 0xc3af51fa , 0xb4f1b29b , 0x1c159c7b ] )
+  , Mont::unsafe_make( [ 0x610d6cb2 , 0x8c644766 , 0x4bbf05b3 , 0x5666f2c9 , 0x9c8b3006 , 0xd75dad9b , 0x1f2dfe69 , 0x14b52234 ] )
+  , Mont::unsafe_make( [ 0x94d13224 , 0x7a6b2936 , 0x19cebdd8 , 0x3f46ef57 , 0xa14e2171 , 0x4242d6d5 , 0xe04bcba5 , 0x12d1a251 ] )
+  , Mont::unsafe_make( [ 0x48d94523 , 0x2d21a988 , 0x5eb82dee , 0x379ff5b6 , 0xd1ec5d6e , 0xf3d164c3 , 0x4af32c51 , 0x2ca00ca8 ] )
+  , Mont::unsafe_make( [ 0x3cba32d9 , 0xd1af8328 , 0x33707e36 , 0x44f30e72 , 0x6079d0ad , 0x1d67a2ab , 0xb25ccbd5 , 0x1f174438 ] )
+  , Mont::unsafe_make( [ 0x85ea220a , 0x59e2896a , 0x610faf88 , 0x207d6c4b , 0x594306a5 , 0x3b20c75a , 0xd88b0ff5 , 0x221cca26 ] )
+  , Mont::unsafe_make( [ 0x7d61b9c6 , 0x04301594 , 0x704ca181 , 0x8da1ffd0 , 0x57296900 , 0x21c1e884 , 0x4ac096c7 , 0x1e6d4d8a ] )
+  , Mont::unsafe_make( [ 0xb6797c90 , 0x7c33589b , 0xa11bb29a , 0x22cddd0d , 0x1ad14e43 , 0x2cde7ce7 , 0x125cc0ac , 0x19a2bb51 ] )
+  , Mont::unsafe_make( [ 0xe83f3ec3 , 0xc9fb060a , 0x81c19999 , 0xd14f2c69 , 0x3afe2fd9 , 0xb59a4c39 , 0xba8a3a43 , 0x1ab87b89 ] )
+  , Mont::unsafe_make( [ 0xfa4e8420 , 0xb140b0b1 , 0x2b9836d8 , 0xdc94206d , 0xe7aad7e6 , 0xe541a6cf , 0x433a8538 , 0x149f00ff ] )
+  , Mont::unsafe_make( [ 0xb3c9cc7a , 0xf600333f , 0x1db80984 , 0xf025bd8c , 0x913ea432 , 0xfed334a7 , 0x8bbc4ddc , 0x1b4651e4 ] )
+  , Mont::unsafe_make( [ 0x83ce68e7 , 0xce76c4fa , 0xa94ad3d5 , 0x77392fff , 0x787705dc , 0xf8c2a3ec , 0xb4d864de , 0x201d5ca2 ] )
+  , Mont::unsafe_make( [ 0x2a0f408a , 0x0753fee9 , 0x61a6b6f4 , 0xb07483e2 , 0x561a4dfb , 0x77084698 , 0xa1fd9177 , 0x2866af12 ] )
+  , Mont::unsafe_make( [ 0x046f3913 , 0xf1b5fe52 , 0xb46d12bb , 0xb27dec10 , 0xf223690b , 0xff8d14a5 , 0xb9315301 , 0x2ff2b25f ] )
+  , Mont::unsafe_make( [ 0x1be69e32 , 0x50e600d2 , 0xef03052c , 0x5d82a586 , 0xc37261e5 , 0x7dd6f018 , 0xd8ef3201 , 0x0264745a ] )
+  , Mont::unsafe_make( [ 0xe3a0fc8c , 0x3618da23 , 0x4ad33c06 , 0xa14f735f , 0xb481504e , 0x94dbce6c , 0x84979580 , 0x297ba0ea ] )
+  , Mont::unsafe_make( [ 0xf3057cdc , 0x18d42f9b , 0x04377ff5 , 0xe199180e , 0x29920bad , 0x8087b8a1 , 0x5e1642dc , 0x1eefb14e ] )
+  , Mont::unsafe_make( [ 0x911cdfff , 0xf9914258 , 0x6608908b , 0x6a789be8 , 0x61a46b75 , 0x8ea7e1aa , 0x2ecaf30a , 0x21b3be46 ] )
+  , Mont::unsafe_make( [ 0x6f961154 , 0x3a576686 , 0x8d5d63b5 , 0xd8c22d0c , 0x12e342ce , 0xae588b7b , 0xbb1bc20b , 0x16f2c01f ] )
+  , Mont::unsafe_make( [ 0xefbe641b , 0x24c0aee6 , 0xbe8783ab , 0xde80f0e9 , 0x32697566 , 0x546a2c64 , 0xe3c3e3d3 , 0x2704be03 ] )
+  , Mont::unsafe_make( [ 0xe85fac76 , 0x1499e5c3 , 0xb7e92d2a , 0x63110bb4 , 0x085a66d5 , 0xc45bede6 , 0xcdd5ceef , 0x0bac4c9f ] )
+  , Mont::unsafe_make( [ 0xd38484e0 , 0x9b6c99ef , 0x910bc523 , 0xaae1481a , 0xe6628313 , 0x753ed9cd , 0x000ef9e5 , 0x07a06975 ] )
+  , Mont::unsafe_make( [ 0xa7427503 , 0xfcf0ea0e , 0xdc6c5788 , 0x561ccfdf , 0xc3ca4a78 , 0x4ec7ba97 , 0x73fc2f8d , 0x282c007b ] )
+  , Mont::unsafe_make( [ 0xbca062f7 , 0x9a3379d0 , 0x3863583c , 0xb922e8c4 , 0xb38be802 , 0x1fdd0541 , 0x1669cbd1 , 0x1a7c9296 ] )
+  , Mont::unsafe_make( [ 0x65b9a53e , 0xdeaba2f2 , 0xb4ceb726 , 0x68891b3a , 0xef55cbbc , 0x88de9668 , 0x361fea8e , 0x03336d36 ] )
+  , Mont::unsafe_make( [ 0x0be378b7 , 0xbee8331e , 0x13160bce , 0x2ebe9a05 , 0xdf80b2b0 , 0xdc3081c7 , 0x2b6ef2a0 , 0x2f87b19a ] )
+  , Mont::unsafe_make( [ 0x3f9c46bb , 0xfe62d5a8 , 0x68de516c , 0xf0d84af9 , 0x0375f2f6 , 0xf1e7667a , 0xbcf3cec7 , 0x0e9cb748 ] )
+  , Mont::unsafe_make( [ 0x68b1ccab , 0x84fd8725 , 0x9bdd6ab7 , 0x9d03e606 , 0xe641cb69 , 0xb262cc0b , 0x863f2815 , 0x2246cf41 ] )
+  , Mont::unsafe_make( [ 0x66c016f5 , 0x6a7104b6 , 0xa41e92da , 0xdcbd879e , 0xc88cb173 , 0x9e7d79e5 , 0x8fb86022 , 0x15e16f5b ] )
+  , Mont::unsafe_make( [ 0xd74fc5b3 , 0x904acd27 , 0x6e74e9b4 , 0xae9d1d05 , 0x12442e4f , 0xefa03ab0 , 0x910378d7 , 0x2891ce5d ] )
+  , Mont::unsafe_make( [ 0x3a73a7d6 , 0x3f926817 , 0xf2c3c613 , 0x611ee3b5 , 0xb140fdf8 , 0x04bf44b8 , 0x32252ca4 , 0x0e8c0a02 ] )
+  , Mont::unsafe_make( [ 0x4d51256b , 0x9704e66e , 0x0e81cc88 , 0xbd5c598a , 0xdb514c22 , 0x2fe53d3a , 0xf2bf7923 , 0x273c5757 ] )
+  , Mont::unsafe_make( [ 0x1368b877 , 0x35b46c7a , 0x125fba4e , 0x4fdb95e0 , 0x23e60a22 , 0x0af9006b , 0x3f4ce51d , 0x01efea3f ] )
+  , Mont::unsafe_make( [ 0x31f89f0b , 0xffd941f0 , 0x273dce92 , 0xa85c3b3c , 0x86ccb8b8 , 0xd6bc8c92 , 0x4b1e8b9b , 0x2f94d10f ] )
+  , Mont::unsafe_make( [ 0x79b8a111 , 0x7a534ac0 , 0x0bbe881c , 0xd4938c85 , 0x220b9300 , 0x0dfaa9a3 , 0x9742ff4c , 0x1f740bbb ] )
+  , Mont::unsafe_make( [ 0x028eb61a , 0x3f811650 , 0xa4a656ae , 0xb5b82460 , 0xc27f98ba , 0x2e95cc71 , 0x2087e042 , 0x0d9fb7d0 ] )
+  , Mont::unsafe_make( [ 0x3a83715f , 0x1621b3e7 , 0xe1c9c4e5 , 0x33341345 , 0x4503326a , 0x3a2309e8 , 0xaaac1346 , 0x11a635fa ] )
+  , Mont::unsafe_make( [ 0xea6b56f8 , 0xc6b31c4e , 0x6bba66c4 , 0xacd8cd98 , 0x749ba48b , 0x84121963 , 0x2f099558 , 0x1ee6a47d ] )
+  , Mont::unsafe_make( [ 0x13486d71 , 0xf7a44777 , 0xfd5ee8ff , 0x58be08eb , 0xed399367 , 0x9d75c8e2 , 0x7b093bf5 , 0x01b2e4e9 ] )
+  , Mont::unsafe_make( [ 0x6a6b58a2 , 0x263d1a70 , 0x33ae4cf4 , 0xd82b8d64 , 0x1853d503 , 0x41d157ce , 0x03615cfe , 0x2984461a ] )
+  , Mont::unsafe_make( [ 0xaab63c1e , 0xf9dcef0c , 0x37f8e7c7 , 0xb3de3f28 , 0xe5e3c36d , 0xd7ab0b49 , 0x8e600abb , 0x0c84c9a2 ] )
+  , Mont::unsafe_make( [ 0x1f52e350 , 0x852fbb18 , 0x538908cf , 0xf20944b1 , 0xa07021ba , 0xc2b7eb72 , 0x2ce4bf65 , 0x2e7a7d0a ] )
+  , Mont::unsafe_make( [ 0x6ea7d032 , 0x34e18968 , 0xa8ba807a , 0x1dd9f7c1 , 0x145291b7 , 0x84a6cd02 , 0xf3f2753b , 0x0eaa07b2 ] )
+  , Mont::unsafe_make( [ 0x8cf1b543 , 0xc2f430cf , 0xa646f308 , 0xa2ffa605 , 0xaf998ef3 , 0x7cbc93d8 , 0x7a7d7789 , 0x11b08d54 ] )
+  , Mont::unsafe_make( [ 0xe57386c1 , 0x3413bfbc , 0x78cf023d , 0xa906dd71 , 0x35d5dc57 , 0x3a87d990 , 0x06ea04b1 , 0x3038bc6c ] )
+  , Mont::unsafe_make( [ 0x2809fd8f , 0xff8896a0 , 0x83986e9d , 0x19dd8082 , 0x80eb761b , 0xf9006410 , 0x7f830e26 , 0x03644e23 ] )
+  , Mont::unsafe_make( [ 0x059400ab , 0x45bc37f3 , 0xb221f701 , 0xbb3c8e7b , 0x724223f0 , 0xf3334a99 , 0xa0865e00 , 0x0ba9d967 ] )
+  , Mont::unsafe_make( [ 0xb2772ef8 , 0xa6a265e5 , 0x518d0e75 , 0xad8c097a , 0x079c2eb5 , 0x91b316b3 , 0xae6d7733 , 0x28a1f64d ] )
+  , Mont::unsafe_make( [ 0xb8742f3a , 0x7a090f0f , 0xb8b28fba , 0x91bfcf46 , 0x123944c5 , 0x004adebd , 0x7d710695 , 0x18acb898 ] )
+  , Mont::unsafe_make( [ 0xe395ccbb , 0xff8e4c6b , 0x2e81d2ff , 0x16ca4ba9 , 0x5d737341 , 0x4009c017 , 0xe82e4e38 , 0x2c4e403a ] )
+  , Mont::unsafe_make( [ 0x2d703958 , 0x8dc4d8d8 , 0x8d360546 , 0x4a7ab9e3 , 0x080df6a6 , 0xaed93b7a , 0x2e953212 , 0x191395eb ] )
+  , Mont::unsafe_make( [ 0x7c3ef5e0 , 0x65e2b2f1 , 0x0f0f25bd , 0xc27e40b0 , 0x217f9990 , 0x6fe433bd , 0xe27c1392 , 0x159ed6cc ] )
+  , Mont::unsafe_make( [ 0xec2f5625 , 0xa56b920a , 0xee8c47d6 , 0xe2957837 , 0xda7c39fd , 0xa496f556 , 0x9fde7a6c , 0x19677564 ] )
+  , Mont::unsafe_make( [ 0x92b017a4 , 0x55389468 , 0x12dde152 , 0x7ed2f564 , 0x46c812df , 0x4e38c331 , 0x951d44ac , 0x269ea76f ] )
+  , Mont::unsafe_make( [ 0x0eb36def , 0xccda39ae , 0xbe6b6141 , 0xab73e5b6 , 0xd2400f96 , 0x8105c2dc , 0xa11b2bd7 , 0x10f40b54 ] )
+  , Mont::unsafe_make( [ 0xc8304ff1 , 0x737b1f2d , 0xdd433f40 , 0x94065dca , 0x7f57bb4c , 0x589fee68 , 0xc01e096f , 0x1de8a02e ] )
+  , Mont::unsafe_make( [ 0x3f87e868 , 0x24ffe737 , 0xac8ca369 , 0x3217f1eb , 0x3e1ba2c4 , 0xe5093b71 , 0x5b146fc8 , 0x101fd714 ] )
+  , Mont::unsafe_make( [ 0x334f746a , 0xce4121d1 , 0x0b982fe6 , 0x734cff93 , 0x61364555 , 0xff171bf9 , 0x13b5fd51 , 0x22104487 ] )
+  , Mont::unsafe_make( [ 0x8f4394f2 , 0x9e8f7388 , 0xf9b4998e , 0x70c7cb0a , 0xe16dcdb8 , 0x057a19dd , 0xd4ae0f27 , 0x3042984c ] )
+  , Mont::unsafe_make( [ 0xbe63ec78 , 0x4ff54328 , 0x32edec67 , 0x59bad237 , 0xa92889e0 , 0x51e50441 , 0xec87c2a1 , 0x23d439f3 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// external mixing matrix (`t x t`, flattened)
+pub const CONST_M: [Mont; 289] = 
+  [ Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x144d605e , 0xadd6db61 , 0x74e7c82b , 0x20bbee6c , 0x7b627e2e , 0xa2a71d61 , 0x75fd8bc5 , 0x14bd5bc2 ] )
+  , Mont::unsafe_make( [ 0xc7a602e9 , 0xe3e1d438 , 0xba5517ff , 0x24ba1543 , 0x80e485da , 0x0b60b8c4 , 0xc7b614f1 , 0x2fb664db ] )
+  , Mont::unsafe_make( [ 0xa54eb27b , 0x87251d38 , 0x6105c773 , 0x518f2c73 , 0x8387f6c7 , 0xed0fb65b , 0x10fbf3d5 , 0x0c7edd45 ] )
+  , Mont::unsafe_make( [ 0x867ee852 , 0xbfecc666 , 0x22a37175 , 0x8dcbb13b , 0xcd684669 , 0xeb82f589 , 0x991b85ce , 0x2638fcaa ] )
+  , Mont::unsafe_make( [ 0x77fd0bfd , 0x69b9d0ac , 0xed93f198 , 0x4ed777ef , 0x5d79ef35 , 0x7d905f08 , 0x56eebcaf , 0x1aa80bc6 ] )
+  , Mont::unsafe_make( [ 0xf0e4f062 , 0xade73be8 , 0x06d579b8 , 0x8f97706e , 0x18f003f4 , 0x770a2ad4 , 0x07389f3c , 0x185cdbb3 ] )
+  , Mont::unsafe_make( [ 0x08e83e6a , 0x367583d8 , 0xdeb2ea92 , 0xcbe55ee5 , 0xb4462849 , 0x57a6dd91 , 0x1291c9f0 , 0x0ed9c011 ] )
+  , Mont::unsafe_make( [ 0xde90f86d , 0xa977d1a6 , 0x1e53776f , 0x856729af , 0xe6ef676a , 0xa6bc20de , 0x4911ea76 , 0x0056a0e7 ] )
+  , Mont::unsafe_make( [ 0x27ed2ffd , 0x28c7de0c , 0x4b9a0d09 , 0x4b4d4ae7 , 0x96b58aa0 , 0xaf39dbb0 , 0xe1a45038 , 0x1317a09a ] )
+  , Mont::unsafe_make( [ 0x21b0a895 , 0x2ab2f07b , 0x1fe99be0 , 0x9b872ef2 , 0x33b1cd08 , 0xcf351661 , 0x45efb142 , 0x0575da2a ] )
+  , Mont::unsafe_make( [ 0x79066b4a , 0x1c163b35 , 0x89581996 , 0x66f1d50c , 0x3c07b220 , 0x57bbd928 , 0xd57c07af , 0x2f984d0c ] )
+  , Mont::unsafe_make( [ 0xf8866607 , 0x90a7899c , 0xc3ae1ff4 , 0xb67b1af1 , 0x136a3c70 , 0x89e64c3d , 0x44f60d4b , 0x087dc521 ] )
+  , Mont::unsafe_make( [ 0xd62a0d1b , 0xd87a41f8 , 0x0ddc5fbb , 0x33eec69b , 0x36a9ae2f , 0xb08618e6 , 0x285da1a9 , 0x07ac3862 ] )
+  , Mont::unsafe_make( [ 0xa76deaf3 , 0x9d839df5 , 0x7de76fd0 , 0x03709cf0 , 0xe6d8b4c3 , 0x60f9f1e4 , 0x50ffa113 , 0x0f7a5748 ] )
+  , Mont::unsafe_make( [ 0xecdbcd8b , 0x95bad511 , 0x3a8fac2c , 0xcc9bd8d2 , 0x588bbbfb , 0xdee9be8f , 0xd6d97429 , 0x19e231af ] )
+  , Mont::unsafe_make( [ 0xb3ba2d06 , 0x21088edc , 0x2f148643 , 0x37ff596c , 0x904c58c5 , 0xc923354b , 0x99d5443d , 0x2caab928 ] )
+  , Mont::unsafe_make( [ 0xaa517fbc , 0xcd7e740a , 0x397f4c67 , 0x688e453b , 0x526b7f1c , 0x5d92ec01 , 0xaee7806c , 0x1d66e6b4 ] )
+  , Mont::unsafe_make( [ 0x2d117256 , 0x9d93343f , 0xc3ce72f1 , 0x1ec2e4bf , 0xcd982b55 , 0xbe1ea97f , 0x538ec229 , 0x069691fd ] )
+  , Mont::unsafe_make( [ 0xfa6fa5ef , 0xf3f7a72c , 0xf0ff2065 , 0x38448894 , 0xf9f5bef4 , 0x741d9581 , 0x6fa2ee8d , 0x2dc4c484 ] )
+  , Mont::unsafe_make( [ 0xbeac8e80 , 0x9c4c555a , 0xfd23f501 , 0x9b7f947c , 0xc45f4782 , 0x5b357e25 , 0x34d95570 , 0x2e303ae0 ] )
+  , Mont::unsafe_make( [ 0x5d004485 , 0xd8a3d29e , 0x3eabd833 , 0x8e3435ab , 0x26abdc07 , 0x566735b9 , 0x59afc5ab , 0x0ebf7900 ] )
+  , Mont::unsafe_make( [ 0x8b0a9143 , 0x3621ca34 , 0xef068a53 , 0xc8fa3c37 , 0x9dc07402 , 0x3c413d45 , 0x9bfa9874 , 0x0772b1a8 ] )
+  , Mont::unsafe_make( [ 0xd28522eb , 0x961c309d , 0xbfaee0ed , 0xf458e73d , 0xa5ee0b33 , 0xeb66e37a , 0x3a7d6d36 , 0x0e275820 ] )
+  , Mont::unsafe_make( [ 0x61527a73 , 0x52484a03 , 0x7eb97fd7 , 0x948cae8b , 0x7db2738b , 0x83ebc7fe , 0x9c51c917 , 0x1ea73f37 ] )
+  , Mont::unsafe_make( [ 0x4438b219 , 0xaa9a37ab , 0xec401dbc , 0x246f3d84 , 0xaf4b91bb , 0xb6d259e5 , 0xeea75d5c , 0x224d6978 ] )
+  , Mont::unsafe_make( [ 0x4b55c640 , 0xbf274509 , 0xa5ce4689 , 0xe1596831 , 0xf811f359 , 0xdea14ae2 , 0x8cdb2d46 , 0x00495d54 ] )
+  , Mont::unsafe_make( [ 0xb0431ef5 , 0x3f611dfd , 0x48706304 , 0x7531dadc , 0xe9055280 , 0x7f5aef3f , 0xbe027397 , 0x15d4856c ] )
+  , Mont::unsafe_make( [ 0x4b10e6e2 , 0xef6d2e7b , 0xd4ab88f4 , 0x6469d3a7 , 0x965d0ae9 , 0x2c1cb197 , 0x6fc6f858 , 0x23505d4c ] )
+  , Mont::unsafe_make( [ 0xa373ef79 , 0x6eb840c2 , 0x57f050a6 , 0x53ce2980 , 0x35a48d21 , 0xb23ebc20 , 0xc869fc62 , 0x04968695 ] )
+  , Mont::unsafe_make( [ 0x9b373bc5 , 0x1fdd1507 , 0x81d9e566 , 0xafcef05d , 0xd2c27cc5 , 0x7970ce75 , 0x9a461b7e , 0x0ba300af ] )
+  , Mont::unsafe_make( [ 0xf0611eee , 0x0061e5dd , 0xbf9d05d5 , 0x8f30ef2b , 0x4a6b09ae , 0x168ae386 , 0xe9c7cfd1 , 0x114a61b0 ] )
+  , Mont::unsafe_make( [ 0x423b8c96 , 0x26b8e112 , 0x5e253ff4 , 0xe700e2fe , 0xccbbef60 , 0x709c313e , 0xfcdb8223 , 0x1890a81b ] )
+  , Mont::unsafe_make( [ 0x15cc7cf8 , 0xd41eae7d , 0xec30d062 , 0x2984b6d9 , 0x3e15cc3f , 0x97f5fa6a , 0xc80da4e5 , 0x0562b18f ] )
+  , Mont::unsafe_make( [ 0xb3e3e11d , 0xc1ccf05b , 0xe46f3646 , 0xbddb2bc5 , 0x7c3c632b , 0x08be6a0e , 0xe7e46993 , 0x1d377f76 ] )
+  , Mont::unsafe_make( [ 0x00d4d17c , 0xd85799d9 , 0xaed94bd9 , 0x6824d2f3 , 0xc15c3311 , 0xcefb6503 , 0xd600f518 , 0x00cae12f ] )
+  , Mont::unsafe_make( [ 0x8e3b5a41 , 0xc0c20276 , 0x2fd5aec1 , 0x61e584ce , 0x876ea7f7 , 0x5698cb25 , 0xa9505f4a , 0x0bba92dd ] )
+  , Mont::unsafe_make( [ 0x6c04957a , 0x3d822f6f , 0x96030a27 , 0xb2f707df , 0x0f06e464 , 0xf1294653 , 0x235db150 , 0x031b7ef8 ] )
+  , Mont::unsafe_make( [ 0x1169745a , 0x47d32c92 , 0x6114c0aa , 0x0a09e3f3 , 0x4ac2669f , 0xe749f564 , 0x755aaeac , 0x1c38f859 ] )
+  , Mont::unsafe_make( [ 0x3f1db2e4 , 0x50caeb88 , 0x06508cb5 , 0x0983288b , 0x4508e001 , 0xbd5418c7 , 0xc3f9f0a5 , 0x1c0f491d ] )
+  , Mont::unsafe_make( [ 0x7bd0e3cc , 0xc1b4a4cc , 0x3a59763a , 0xe4ee79e7 , 0x52a50e8e , 0x3d0c1381 , 0x8ba58164 , 0x0a435c76 ] )
+  , Mont::unsafe_make( [ 0xe276f705 , 0x22998fac , 0xc40948c8 , 0x0e20cfd8 , 0xa0d87599 , 0xe9679319 , 0x70afc830 , 0x0705a256 ] )
+  , Mont::unsafe_make( [ 0x9f29dc21 , 0x517b8209 , 0x63adb71f , 0x1ca73ef0 , 0xa0ad061c , 0xf8449c28 , 0xd3dc9c79 , 0x2d78e251 ] )
+  , Mont::unsafe_make( [ 0x8757917f , 0x614216ed , 0xab011afa , 0x476a2618 , 0x1b4c319e , 0xb9d66cca , 0xc4ff7af7 , 0x199de2a1 ] )
+  , Mont::unsafe_make( [ 0xfbc46b83 , 0xdedf7a4c , 0x61d32475 , 0x20f095c8 , 0x84572632 , 0x13133c87 , 0x8112ee24 , 0x2bec1670 ] )
+  , Mont::unsafe_make( [ 0x5d1925eb , 0xb930258c , 0xf1a7af7c , 0xda451f0f , 0xb4123896 , 0xc78dc651 , 0x8e039b52 , 0x21c38e3c ] )
+  , Mont::unsafe_make( [ 0xf3c33e92 , 0x8820d603 , 0xfd249ffc , 0xa237f553 , 0x38e61d2c , 0x9892d0e3 , 0x71b05bc9 , 0x08787fcd ] )
+  , Mont::unsafe_make( [ 0x8d7a0c82 , 0xf0f243e0 , 0x0b34ec6c , 0xc155d948 , 0x8711b042 , 0x00abeb7d , 0xb233b841 , 0x19aad00a ] )
+  , Mont::unsafe_make( [ 0x36e39951 , 0xdf426105 , 0x9d244d8a , 0xd77b118c , 0xa85df3fe , 0x4db5eb1b , 0x281eba1c , 0x2c5c3fa2 ] )
+  , Mont::unsafe_make( [ 0xd4a360a5 , 0x6ec80aed , 0x56cacaef , 0x0b67a97a , 0xcdb5b5cd , 0x72e26830 , 0xa66ba45e , 0x109ac559 ] )
+  , Mont::unsafe_make( [ 0x099ace78 , 0xff5464f9 , 0x6826e7f0 , 0x4cec4d5b , 0x8260d151 , 0x44cbfeb8 , 0x5c7a5742 , 0x1c0128e0 ] )
+  , Mont::unsafe_make( [ 0x1f8d6cb9 , 0xde2f0c70 , 0xbd8d5687 , 0x7e1d4a7b , 0x474b2b11 , 0x37e1da91 , 0xad0a7980 , 0x073d8f98 ] )
+  , Mont::unsafe_make( [ 0xe1aada87 , 0x337af9cb , 0x12cceb07 , 0x76fc3223 , 0x1adf053a , 0x57d64f0f , 0xf34f2f79 , 0x190f46b9 ] )
+  , Mont::unsafe_make( [ 0xcb49e9e5 , 0x09897340 , 0x889b853d , 0xe63b3c0f , 0x4c1ade16 , 0x0eb59a1a , 0xaffc7101 , 0x131ab822 ] )
+  , Mont::unsafe_make( [ 0x4dbe0f6f , 0xcbd3f5e6 , 0x8bbbc027 , 0x5bd376d9 , 0x9165e653 , 0x9f3ce6d5 , 0x86629f6d , 0x2d9d7a2c ] )
+  , Mont::unsafe_make( [ 0xc977e51b , 0x9013f747 , 0x69704076 , 0x4e57bb3e , 0x260d7b92 , 0xd2a6511d , 0x5e167474 , 0x29b4c46c ] )
+  , Mont::unsafe_make( [ 0x51df8c62 , 0x9bdcbf0c , 0x0d2a7807 , 0xf56cfa1a , 0x2bb62743 , 0xc2964923 , 0x3f1a0b8c , 0x26019854 ] )
+  , Mont::unsafe_make( [ 0x03342bbc , 0x1d2a3354 , 0xc0fac4aa , 0xb6b3ba57 , 0x0c3c2328 , 0xc1dcefc4 , 0xc783b87e , 0x06758b80 ] )
+  , Mont::unsafe_make( [ 0x6991d3b0 , 0xaf3f83a3 , 0xc8b33e34 , 0x26e241e6 , 0x077f26bf , 0x15df5038 , 0xbcc5d334 , 0x2d71b979 ] )
+  , Mont::unsafe_make( [ 0x589253bd , 0x56653261 , 0xcd8a394b , 0x05a7824b , 0xae5280ea , 0xeb4ae802 , 0x919db51c , 0x0e8367ef ] )
+  , Mont::unsafe_make( [ 0xd71d1bc0 , 0x3634539c , 0x2b5dbd42 , 0xf7878e8f , 0x3e73f1fd , 0x59efcc57 , 0x8e20cf9a , 0x224bb2a7 ] )
+  , Mont::unsafe_make( [ 0xa5008e80 , 0xd0b46363 , 0xa3c1d231 , 0x6a6b9941 , 0x62ff61dd , 0x2999fb27 , 0xc0c7803a , 0x04e83c7f ] )
+  , Mont::unsafe_make( [ 0xbf38ba39 , 0x0bc57085 , 0x8d60ecde , 0x73d3a204 , 0x9b8449f7 , 0x0c1b0535 , 0xfcdb3e00 , 0x21c96fef ] )
+  , Mont::unsafe_make( [ 0xa54106e9 , 0x59c328a4 , 0xeeab1123 , 0x107d0d9a , 0x181d7fba , 0x6c5a779a , 0x78ac5ce5 , 0x0c649f5d ] )
+  , Mont::unsafe_make( [ 0x3a67d484 , 0x0127c96c , 0xacd690d8 , 0x282c5f37 , 0xf5026041 , 0x417faeba , 0x2395f252 , 0x2c03dcb4 ] )
+  , Mont::unsafe_make( [ 0xdabf1bc9 , 0xb641c5ff , 0x5dca8404 , 0xd0c46a3a , 0x368f896e , 0xb9a434b2 , 0x38922f98 , 0x2fc837bd ] )
+  , Mont::unsafe_make( [ 0x868b3d50 , 0xd9a752f6 , 0x62a82e59 , 0x8826f123 , 0x358eaac6 , 0xbfc7c5c4 , 0x80370d9a , 0x0fb3b8bd ] )
+  , Mont::unsafe_make( [ 0x39254043 , 0xc6d2d342 , 0x44b1526f , 0x1dfdd5d9 , 0x148d2c96 , 0xbdf96f66 , 0x8550c9bc , 0x2e905297 ] )
+  , Mont::unsafe_make( [ 0x9325936b , 0x003f6500 , 0xd260d2c0 , 0xc4ddf6e7 , 0x4ab73125 , 0x9966325d , 0x0f7e80c1 , 0x284310c9 ] )
+  , Mont::unsafe_make( [ 0x0f9b62ba , 0x4ba744d1 , 0x568bd0e0 , 0x22defd6a , 0x120b50d9 , 0xb843dd4e , 0x6b40cf58 , 0x20315a6c ] )
+  , Mont::unsafe_make( [ 0xcb5751d8 , 0x76e8ad4d , 0x5df74309 , 0xb2aa3263 , 0x30508435 , 0x16db7403 , 0x71f713a9 , 0x1c0751cc ] )
+  , Mont::unsafe_make( [ 0xdd1822fc , 0x121493b0 , 0x3f9fd158 , 0xb649ce4a , 0xcef04ba6 , 0x320f55aa , 0x23c89710 , 0x2c6b62b2 ] )
+  , Mont::unsafe_make( [ 0x16f1258a , 0x4b0fc881 , 0x0158a530 , 0xdc15a480 , 0x8637a41c , 0x2b064cdb , 0x3385e4fc , 0x14f61103 ] )
+  , Mont::unsafe_make( [ 0x817eee0f , 0x23d41025 , 0xf8566a4c , 0xcb65224e , 0x4161135f , 0x69e08201 , 0x335ec4df , 0x1782bf40 ] )
+  , Mont::unsafe_make( [ 0x90342472 , 0x94533d34 , 0x0d5bd386 , 0x635179b1 , 0x1205b3a1 , 0x47c54c5a , 0xbb4cbd2b , 0x18ef322b ] )
+  , Mont::unsafe_make( [ 0x41cba2d8 , 0x306058f9 , 0xdedfa6d5 , 0xa9961526 , 0xdd79a5e9 , 0xe46faae3 , 0x95247d62 , 0x0e6a0b37 ] )
+  , Mont::unsafe_make( [ 0x6727c45d , 0xa2f324bd , 0xaa6ddaf0 , 0x0740e5bd , 0x44f5f708 , 0xf0ef982a , 0xbd4c073a , 0x2d0f4c95 ] )
+  , Mont::unsafe_make( [ 0x8b94c855 , 0x756cdfa8 , 0x0c3c23c4 , 0x125176b9 , 0x8696756d , 0x9d7365cf , 0x04f2cef3 , 0x2aa72689 ] )
+  , Mont::unsafe_make( [ 0x73376a60 , 0x67ea68c9 , 0x318bdafd , 0xf36f2c71 , 0x841bb314 , 0x4486d7b9 , 0xfc7df431 , 0x0d0a2cbe ] )
+  , Mont::unsafe_make( [ 0xd09614d6 , 0x2cb9ffce , 0x393731dc , 0x1a4f2c81 , 0xf854f999 , 0x9b1f0b1c , 0x6d6e993d , 0x1b9486ea ] )
+  , Mont::unsafe_make( [ 0x9c843540 , 0x601aad15 , 0x0813e7c0 , 0x9acfde00 , 0x3fd2eeb1 , 0x65834d24 , 0x250343d7 , 0x2b3b8129 ] )
+  , Mont::unsafe_make( [ 0x504162ae , 0x941207a6 , 0x115ac972 , 0x05ac799d , 0x280cc409 , 0x3b1b5406 , 0x0c31731d , 0x13431615 ] )
+  , Mont::unsafe_make( [ 0x6edb0c6b , 0xf07140cf , 0x7424685f , 0x52f5a4a8 , 0x43243a12 , 0x59fa5547 , 0xcd8de836 , 0x1170701f ] )
+  , Mont::unsafe_make( [ 0x986d593e , 0x49abfe9e , 0xd9ce76a7 , 0x737577cb , 0x6b2a4ea0 , 0x679e4dbd , 0x4fd7e1be , 0x1088ec6c ] )
+  , Mont::unsafe_make( [ 0x845aeaa6 , 0xd3c0f18e , 0xe72d5771 , 0xdd04a248 , 0x5a6237a1 , 0xa1be0ffa , 0xe39fb387 , 0x2828faa1 ] )
+  , Mont::unsafe_make( [ 0x7e6e578f , 0x7c60b2ac , 0xcddcdd98 , 0x0ee1c4e9 , 0x6686a64a , 0x25f1dfe4 , 0x4f503ddb , 0x13c36dce ] )
+  , Mont::unsafe_make( [ 0x9522aedd , 0x6ac1252a , 0xa5d11ede , 0xc80136af , 0x3aa07d65 , 0x9026c37c , 0x047397c3 , 0x270d5910 ] )
+  , Mont::unsafe_make( [ 0xe2814c78 , 0xb091b1fc , 0xba744e66 , 0xa29a94c4 , 0x5cb53c5c , 0x07ad7f64 , 0x62e48260 , 0x29686993 ] )
+  , Mont::unsafe_make( [ 0x8ea5548f , 0x65bbf1d3 , 0x9da9d59d , 0x315f7db1 , 0x46c8397d , 0x7503b441 , 0x7432e7c9 , 0x05c72a19 ] )
+  , Mont::unsafe_make( [ 0x77f80639 , 0x5bc23104 , 0x7251866f , 0x24dbb758 , 0x5b920887 , 0x1328ea3f , 0xc3d534bb , 0x28c84be5 ] )
+  , Mont::unsafe_make( [ 0xc198557c , 0x80d366f9 , 0xafb2ea59 , 0x2aca7db5 , 0xa3e79955 , 0x8f65b9a1 , 0x51de5b9f , 0x1861450a ] )
+  , Mont::unsafe_make( [ 0x90208346 , 0x79f85a57 , 0x29f26248 , 0x4f36449c , 0x1897eba6 , 0x43af0ca2 , 0xdcebdbf6 , 0x1b0d23ca ] )
+  , Mont::unsafe_make( [ 0x6dd52443 , 0xbb21ea9a , 0x4dfb9be4 , 0x1c87a89c , 0x9ad1d787 , 0x986ebe29 , 0x2aa43653 , 0x0bb14e63 ] )
+  , Mont::unsafe_make( [ 0x042ea778 , 0x60401549 , 0x825721c4 , 0xd7161d60 , 0x8b862132 , 0x05950126 , 0xb458edab , 0x08910163 ] )
+  , Mont::unsafe_make( [ 0x14cd0946 , 0x56479d70 , 0x24029108 , 0x85f9fd13 , 0x892c0fbd , 0xac7171c0 , 0x7da5b9ae , 0x1002f088 ] )
+  , Mont::unsafe_make( [ 0x51f4a7cb , 0x15e776ba , 0x16bedfde , 0xb58fcfb4 , 0x086d1987 , 0x067cf72b , 0xeca5e29e , 0x19d5b6bd ] )
+  , Mont::unsafe_make( [ 0x2435866a , 0x1d1e86fb , 0x75628bfc , 0x8b0e432a , 0x025d63f3 , 0xc4c12bb0 , 0x828b22a8 , 0x0f0983e1 ] )
+  , Mont::unsafe_make( [ 0x29df1fde , 0xa6b9587e , 0x2ca00843 , 0x2fbaaa57 , 0x591272eb , 0x031b57df , 0x23344e20 , 0x2499f4db ] )
+  , Mont::unsafe_make( [ 0x69a3da32 , 0x32107f5b , 0xaf1e6a66 , 0xc5072972 , 0xa57445c7 , 0x80d49ba4 , 0x9160b9ee , 0x2272d09f ] )
+  , Mont::unsafe_make( [ 0x96d306f4 , 0xe514a6b6 , 0xb440f6ab , 0x92da4f45 , 0x65c301db , 0x68a32742 , 0x25516d9b , 0x207b5515 ] )
+  , Mont::unsafe_make( [ 0x1dafabfe , 0xab12b3f9 , 0x5e7a1b93 , 0x78f44424 , 0xeb7b2b85 , 0x8769773d , 0x419829da , 0x228819d5 ] )
+  , Mont::unsafe_make( [ 0x6a0214b0 , 0x1442ff7f , 0x1998ce4a , 0x21166daf , 0xb9aada5a , 0xc94619aa , 0xc6b85974 , 0x20417b0d ] )
+  , Mont::unsafe_make( [ 0xc4aa1d43 , 0x8f9aa182 , 0x70ec345d , 0x82c7573d , 0xfbf7497d , 0x34a2ceb2 , 0x0bb89d85 , 0x2b119cba ] )
+  , Mont::unsafe_make( [ 0x0566c1ef , 0x35a9b66c , 0x5541c1cf , 0x6f56d1b9 , 0x3d614be4 , 0x6ec76623 , 0xb7ec532b , 0x20c138c6 ] )
+  , Mont::unsafe_make( [ 0xd49d3183 , 0x09626aa7 , 0xc913c0ff , 0x2ff084f1 , 0x5d842d52 , 0x39b21736 , 0x982e2363 , 0x285c2889 ] )
+  , Mont::unsafe_make( [ 0x8d1176f7 , 0x60ea3a2d , 0x65ab1c41 , 0x8e26b68c , 0x47fa4f2e , 0x5cb78016 , 0x54f49479 , 0x019f98e3 ] )
+  , Mont::unsafe_make( [ 0xd523e6c6 , 0xf47271dc , 0x0e4cb393 , 0x4b61ec02 , 0x4adb0047 , 0x675ef4c4 , 0x641baabf , 0x1007ca80 ] )
+  , Mont::unsafe_make( [ 0xbe4ad644 , 0x114ebd8c , 0x26c72370 , 0x122b91a2 , 0x68ca9992 , 0xd62f95d0 , 0x2848908e , 0x29ea9910 ] )
+  , Mont::unsafe_make( [ 0x7bbb3aeb , 0x9dab9294 , 0xd8a5c664 , 0x11f779ab , 0x39e363df , 0x8a2950b3 , 0x9c250718 , 0x25202f03 ] )
+  , Mont::unsafe_make( [ 0x28f62d29 , 0x9856fa5a , 0xc6fe1b4a , 0x930586a7 , 0x6a09c496 , 0x7283d951 , 0x66a2f32f , 0x19de99bd ] )
+  , Mont::unsafe_make( [ 0xf749ccad , 0xd6087ba5 , 0x7506a3ca , 0x9046b3bf , 0xb056e8f2 , 0xfb45d860 , 0x0ad73777 , 0x0e4cb7b9 ] )
+  , Mont::unsafe_make( [ 0xc2164007 , 0x06fc7986 , 0x8a864db1 , 0x1d90a8c6 , 0x539e6be6 , 0xdcbbd52a , 0x0883c6b4 , 0x19aa5837 ] )
+  , Mont::unsafe_make( [ 0x76fd264d , 0x6951b7e6 , 0x3e0988e0 , 0x3f4f00a5 , 0x71353fbf , 0x0e27ac91 , 0x64a834b9 , 0x1babcee1 ] )
+  , Mont::unsafe_make( [ 0x7be6b34d , 0x1cbb18a6 , 0xc5290760 , 0x4f735efb , 0x28d9101d , 0x0132c4f5 , 0x1f30edbb , 0x1c54daca ] )
+  , Mont::unsafe_make( [ 0x6c687c0c , 0x7c12bf2d , 0x5ddd8e73 , 0xf257dfc7 , 0x955cfcea , 0x01a547e1 , 0xf07388e4 , 0x14b22789 ] )
+  , Mont::unsafe_make( [ 0xa187649f , 0x4e19906e , 0x233b18ad , 0x0e6ddcaf , 0xfcf10dcf , 0x7f8ec863 , 0x2b8351f8 , 0x2a0cb15c ] )
+  , Mont::unsafe_make( [ 0x471a0b81 , 0x1860788b , 0x38705847 , 0x3d87c021 , 0x04ac149c , 0xc2908678 , 0xba65ee9b , 0x2e42e2e7 ] )
+  , Mont::unsafe_make( [ 0xb4e6bbac , 0x7eca5cf0 , 0x24d6072b , 0xda94eeb0 , 0xba499728 , 0xce779a40 , 0x3f7bd284 , 0x1dab1828 ] )
+  , Mont::unsafe_make( [ 0x70829a33 , 0xf452860c , 0x45db3e55 , 0x822b7f40 , 0xc3bdd21a , 0xcec9e544 , 0xeb55969f , 0x1c0aa620 ] )
+  , Mont::unsafe_make( [ 0x5f8e569e , 0x7d4527f0 , 0x9cc500c5 , 0xff8d8821 , 0x4cfe1d51 , 0xaaff720c , 0x0c3a6670 , 0x0c677034 ] )
+  , Mont::unsafe_make( [ 0x3287bf93 , 0x5007c733 , 0xd4c95d45 , 0x8d812dce , 0xebf249ab , 0xb819414e , 0xca09f3d1 , 0x01524ece ] )
+  , Mont::unsafe_make( [ 0x32bdccf9 , 0x01151b04 , 0x4c0d7edf , 0xb7cde57a , 0xa52fa67c , 0x49cf837a , 0xc29f2d13 , 0x29bdec11 ] )
+  , Mont::unsafe_make( [ 0x60e1ed2d , 0xa612c43c , 0xa43683ca , 0xc513102e , 0x8b7fc571 , 0x341dd9da , 0x82d4bbd5 , 0x118b4a8d ] )
+  , Mont::unsafe_make( [ 0x40fa7043 , 0x96c938f5 , 0xf6db57ab , 0xe1093534 , 0xae026f0e , 0x52416bd7 , 0xf7ba289c , 0x21b770f8 ] )
+  , Mont::unsafe_make( [ 0x1b714450 , 0x366da9b9 , 0xd8075664 , 0xb1643b8a , 0x016b167a , 0xb26aa615 , 0x44b596bc , 0x19d6a193 ] )
+  , Mont::unsafe_make( [ 0xf2d7afef , 0xab00c4af , 0x19478147 , 0xcc277a76 , 0xe6d79e2f , 0xf585c078 , 0xaae0ada7 , 0x018e52c1 ] )
+  , Mont::unsafe_make( [ 0x0cb4e011 , 0x6308263d , 0x756e388d , 0x91676cd9 , 0xc1d5a3a0 , 0x60ba24c8 , 0x81c28624 , 0x24d0fd23 ] )
+  , Mont::unsafe_make( [ 0xc9be9508 , 0xceda0bc6 , 0x3a6a694e , 0x8d040566 , 0x74acde24 , 0x26a22694 , 0x19c53f77 , 0x270c22ee ] )
+  , Mont::unsafe_make( [ 0x6c9429ce , 0x1608a90d , 0x91aa1bb7 , 0x000cf33a , 0x8eddf3c7 , 0x495cbb0a , 0xcd17c1e3 , 0x0c6bd3f2 ] )
+  , Mont::unsafe_make( [ 0xa08ed302 , 0xe3240f69 , 0xc09eb6ca , 0x0ed45517 , 0x76c43c74 , 0x38542f7c , 0xdda4aa16 , 0x172e87d4 ] )
+  , Mont::unsafe_make( [ 0xe30e9e04 , 0x57230d16 , 0xb01540ca , 0x97140800 , 0xe174eb39 , 0x8a51a050 , 0x14083a50 , 0x09c45b7d ] )
+  , Mont::unsafe_make( [ 0xaaa55a10 , 0xb4db8c5b , 0x9fca7a6d , 0x1e52f58f , 0xebcee00e , 0x564f7f92 , 0x841d0fba , 0x1d40a6f7 ] )
+  , Mont::unsafe_make( [ 0x5ea6e10c , 0x6e047065 , 0x149b3d6a , 0x8f228777 , 0xbec8cee9 , 0x5de09c09 , 0x2d02f8a6 , 0x205de8e3 ] )
+  , Mont::unsafe_make( [ 0x9506f342 , 0x9c503111 , 0x6623d23c , 0x2a47be1f , 0x17de6298 , 0x0aae6d06 , 0xbf832466 , 0x16f5aeaf ] )
+  , Mont::unsafe_make( [ 0x85a0c896 , 0x7ab29ebb , 0x39c68727 , 0xba75cbb2 , 0x0c760de7 , 0xdc553951 , 0xe78749c4 , 0x18ad35ea ] )
+  , Mont::unsafe_make( [ 0xd31a6d0f , 0x44553471 , 0x39dccc2c , 0xa4ece173 , 0xe85a209d , 0x574f5523 , 0xf3536a67 , 0x01d9a7aa ] )
+  , Mont::unsafe_make( [ 0x83431bc2 , 0x6042fd9f , 0xe590ef7e , 0xfb499410 , 0x5b981e6b , 0xb5798275 , 0x32ed99e7 , 0x304b448c ] )
+  , Mont::unsafe_make( [ 0x4e1db593 , 0x8bbd2459 , 0xbf3e9944 , 0xf02e5cb2 , 0xa306a25b , 0x3a1469a8 , 0x4af5b3ec , 0x0c017dae ] )
+  , Mont::unsafe_make( [ 0x5d5fd7df , 0xe86b78b5 , 0x0aa64abe , 0xb8f2b7b3 , 0x8fe26fb5 , 0x93b5e4d4 , 0xfda0782e , 0x16dc8d16 ] )
+  , Mont::unsafe_make( [ 0xbadb4b74 , 0xc73267db , 0xb17e9bce , 0x1bbb51d0 , 0x7e747de2 , 0x033d221c , 0xc3849447 , 0x22f6371f ] )
+  , Mont::unsafe_make( [ 0x2777053b , 0xdcf5d050 , 0x50f0b5c5 , 0x4b89ffa4 , 0x72161111 , 0xbb3d935e , 0x8fe218fd , 0x29332695 ] )
+  , Mont::unsafe_make( [ 0x5fa45c07 , 0xee5146e7 , 0x8f4e785e , 0x48d0f436 , 0xe25bdea2 , 0x04ebe759 , 0xb6ec8f2f , 0x01c518b0 ] )
+  , Mont::unsafe_make( [ 0xb91bb0c1 , 0x2ab71858 , 0x08f04cbf , 0xe53b305e , 0x665ea6ee , 0x82473d14 , 0x0e7fbae5 , 0x298c7c79 ] )
+  , Mont::unsafe_make( [ 0xf3f0e1b8 , 0x7e5766c2 , 0x0e32e8d1 , 0x365c5b0b , 0x6c7bdb1d , 0xeb70e686 , 0x6558359a , 0x25108e08 ] )
+  , Mont::unsafe_make( [ 0xea20f388 , 0x2ae37e15 , 0x1f04e5dc , 0xd53e6d37 , 0xc821b8fd , 0x7ec35e47 , 0x99a7e0e0 , 0x0312305c ] )
+  , Mont::unsafe_make( [ 0x28d0f2d8 , 0x0f13e1b9 , 0x5dbcc7e8 , 0x3c413852 , 0xf067bf72 , 0x397328ae , 0x3729666d , 0x025cc84e ] )
+  , Mont::unsafe_make( [ 0x9763d05e , 0x6756c10f , 0xc9fc3a3a , 0x36bfff3c , 0xf60c3c43 , 0x96800617 , 0x69e85035 , 0x13e8f3bf ] )
+  , Mont::unsafe_make( [ 0x6fba42bd , 0xd5ee7edb , 0x11ee1666 , 0x2364f2ba , 0x3f670349 , 0x00349a21 , 0x84637dd0 , 0x152150c6 ] )
+  , Mont::unsafe_make( [ 0xc15022b8 , 0x13a1bfea , 0xef361830 , 0x7eff010a , 0xa24503b3 , 0xe8e373d6 , 0x4fa9bace , 0x1c4b0561 ] )
+  , Mont::unsafe_make( [ 0x5d429ae5 , 0x0a360679 , 0xbf595502 , 0x7d41f810 , 0xe753602b , 0xbf463155 , 0x74118a97 , 0x1f5fcd79 ] )
+  , Mont::unsafe_make( [ 0x526c2bf1 , 0x22228d4f , 0x483c8ef3 , 0xb903431e , 0x6cfa2b9a , 0xe9bf6b70 , 0xac4ca555 , 0x0d91bfa8 ] )
+  , Mont::unsafe_make( [ 0xcddf948a , 0x9ed66b78 , 0x49096cde , 0xa252c17e , 0x144ded78 , 0x11e2dc21 , 0x00382fa1 , 0x034aafed ] )
+  , Mont::unsafe_make( [ 0xfed48039 , 0xf1c05a6b , 0x00a90928 , 0xe77c46dd , 0xdc36600b , 0xb542a2a2 , 0xeac27390 , 0x1f180e6f ] )
+  , Mont::unsafe_make( [ 0xcd30fad3 , 0xdc523f9c , 0x869440a7 , 0xacd3ae55 , 0x34544c8d , 0xf26026ef , 0x7351eb96 , 0x16359254 ] )
+  , Mont::unsafe_make( [ 0xdc79febc , 0xa1490615 , 0x273c07df , 0xc191db87 , 0x30796529 , 0x4465d8ac , 0x36117c24 , 0x11896645 ] )
+  , Mont::unsafe_make( [ 0xead9919c , 0xec8b1cb9 , 0x8dcb97f1 , 0x2781f88f , 0x12667feb , 0xa6a5548d , 0xe6b7e48e , 0x2af7cd60 ] )
+  , Mont::unsafe_make( [ 0xf9d538f5 , 0x61531b18 , 0xa713cfa0 , 0x8c2ed78e , 0xba5a57b9 , 0x60ed617a , 0xb683b062 , 0x181fb431 ] )
+  , Mont::unsafe_make( [ 0x769e7e31 , 0xe9603bae , 0x399203bf , 0xddab82b0 , 0x44f37ecd , 0xac886b7f , 0xf1c5ec61 , 0x29e4f2b9 ] )
+  , Mont::unsafe_make( [ 0xc65a2c83 , 0x8c061397 , 0xbd52510a , 0x30dd2511 , 0xd4b602f4 , 0x8f0e2dc4 , 0x8f0248c3 , 0x20b7d453 ] )
+  , Mont::unsafe_make( [ 0xbb7fe64e , 0x33678dd7 , 0xde74f63f , 0x640e9dc5 , 0x80eea76b , 0xb22504bc , 0x0f6e3791 , 0x0a04268e ] )
+  , Mont::unsafe_make( [ 0x0bfd70de , 0x31e9c12e , 0x0bd4662f , 0x50b721f2 , 0xb411b581 , 0xf10fed55 , 0xa6cb35a3 , 0x026e4b49 ] )
+  , Mont::unsafe_make( [ 0xf14c00d9 , 0x3cff0180 , 0xd914a9af , 0x2f26ceb3 , 0xe18242b6 , 0x121262b8 , 0x762be6f6 , 0x1e4a6760 ] )
+  , Mont::unsafe_make( [ 0x5ce49be9 , 0xe04218d8 , 0x8a006996 , 0x7ed079a9 , 0x25d15168 , 0xb28d4787 , 0xb25c1107 , 0x27db8e07 ] )
+  , Mont::unsafe_make( [ 0xc0e3b9e8 , 0x6e2028a1 , 0x4f11c826 , 0x10502a15 , 0xe2736366 , 0x2d440557 , 0x5036fe09 , 0x20ed8218 ] )
+  , Mont::unsafe_make( [ 0x2467e6fe , 0xed0eea6b , 0x010d5d7f , 0xc4790110 , 0x4571cef1 , 0x0ae4406d , 0xbaa0c025 , 0x07926b8a ] )
+  , Mont::unsafe_make( [ 0xd530c6e9 , 0x25e5759e , 0xf7db0b63 , 0xd87c778e , 0x92cc53e9 , 0x3f48aba4 , 0xad44319b , 0x2b9d7833 ] )
+  , Mont::unsafe_make( [ 0x86dd58f5 , 0x80de8516 , 0x6edbe4bc , 0xa8752a9b , 0x02285c95 , 0x28937f92 , 0x24710016 , 0x18e4edc4 ] )
+  , Mont::unsafe_make( [ 0x593b90af , 0xec35e964 , 0xb6e10973 , 0xa8fe4eb7 , 0x235ee29b , 0x8b5dabb6 , 0x09ba229e , 0x0306280e ] )
+  , Mont::unsafe_make( [ 0xaf5a52f0 , 0xcf9b494c , 0xf57835a2 , 0x358f0718 , 0x6cbd138f , 0xf866e0f3 , 0x10f954ea , 0x00fdd67c ] )
+  , Mont::unsafe_make( [ 0xbbccdeda , 0x0aab9f2f , 0x4c327db2 , 0xe898b136 , 0xe3bd304f , 0xf3f1b06f , 0xb5730367 , 0x025fc528 ] )
+  , Mont::unsafe_make( [ 0xd3cdbf2c , 0x69ef7fc6 , 0xb99fab95 , 0x677e7a7a , 0x5c48e6a3 , 0xb021fe36 , 0xbc636686 , 0x18ff9679 ] )
+  , Mont::unsafe_make( [ 0x1740a264 , 0xf7d7a51d , 0x1599fdec , 0x6a5d2596 , 0x3f53d7e1 , 0x97b9b9c2 , 0x7c27bdff , 0x1067bff2 ] )
+  , Mont::unsafe_make( [ 0x25aa4e5a , 0x8522e502 , 0xe738582e , 0xe06207f5 , 0x4f9ed5b6 , 0x5fbf857a , 0xa0cd5fc9 , 0x30159058 ] )
+  , Mont::unsafe_make( [ 0xeab8da4e , 0x5ab36153 , 0x28cfc8ad , 0x228bcb19 , 0x04145e37 , 0xeeb11364 , 0xddbb8f7c , 0x1d614ae5 ] )
+  , Mont::unsafe_make( [ 0x9fb4e64b , 0xdf050396 , 0xadbe0ed5 , 0x2342d1c0 , 0x2e19cd08 , 0x82cc7111 , 0x83bde85a , 0x20d3c57a ] )
+  , Mont::unsafe_make( [ 0xd16bada3 , 0xc179b3b7 , 0x2012824b , 0x0bc53ee6 , 0x009da1be , 0xabc203e4 , 0xb5b4e1ae , 0x25d8c8df ] )
+  , Mont::unsafe_make( [ 0x84083fde , 0xfab4aaea , 0xa7074dde , 0x85b7d1a2 , 0x8164e065 , 0x556344a0 , 0xb6ce2f9a , 0x16f05638 ] )
+  , Mont::unsafe_make( [ 0x3f94ad9f , 0xb2ae8dae , 0x44eb2a27 , 0x7750af9c , 0x76bb3268 , 0x55b1c018 , 0xfc6593ba , 0x1ee7106f ] )
+  , Mont::unsafe_make( [ 0x444b87af , 0x294e70ba , 0xd4c2c66a , 0xe2d93f15 , 0xd5192bd8 , 0xc280247c , 0x32202e92 , 0x0d7fa863 ] )
+  , Mont::unsafe_make( [ 0xfa664375 , 0x4fb4cee2 , 0xdf172c26 , 0x8b4e6208 , 0xba704816 , 0x437b9746 , 0xb53a98f8 , 0x0caa89ba ] )
+  , Mont::unsafe_make( [ 0xfc93f2b7 , 0x028a0c7b , 0x622a8ab1 , 0xc46fac1d , 0x0a13e8d3 , 0x86e40681 , 0x1147cfcc , 0x04a35657 ] )
+  , Mont::unsafe_make( [ 0xadbebe15 , 0x852d5b84 , 0x5ceef554 , 0x2592350b , 0x56051353 , 0x54c261a1 , 0xd80a6a4a , 0x01896b6d ] )
+  , Mont::unsafe_make( [ 0x277ae7dd , 0xebcff8c0 , 0xf1412372 , 0x013fe5f9 , 0x15220011 , 0x1e13544b , 0x2ce84ebb , 0x2dd709cc ] )
+  , Mont::unsafe_make( [ 0x2d96cfe6 , 0x6f7f981b , 0x16321086 , 0x4d239836 , 0x5abcbf45 , 0x6d1dfcff , 0xfceb723e , 0x08898759 ] )
+  , Mont::unsafe_make( [ 0x1feba90c , 0x059480c8 , 0x4ee060be , 0x370a23a0 , 0x2b49cfb9 , 0xdccc619e , 0x5ed23af8 , 0x02f2b88e ] )
+  , Mont::unsafe_make( [ 0xdcc90733 , 0x6a187ae3 , 0x17669134 , 0x0b05cf38 , 0xa3806518 , 0x0b3e4e71 , 0x886f0de0 , 0x1bc98b30 ] )
+  , Mont::unsafe_make( [ 0xe310136f , 0x4ad2ff0e , 0x275fed66 , 0xe36e2a8c , 0x448867eb , 0x5fda3dbe , 0x8e85ef8e , 0x1ee16ab0 ] )
+  , Mont::unsafe_make( [ 0xdc80b4b3 , 0x5bcf60a4 , 0xe5151eac , 0x7dafe117 , 0x56614170 , 0xdcdff261 , 0xd135eab4 , 0x2faf2e1d ] )
+  , Mont::unsafe_make( [ 0x7d809b93 , 0x9f9c9f4f , 0x98852158 , 0x6cce1d7f , 0x3e9220f6 , 0xa3a51dc5 , 0x9754cf5b , 0x21608962 ] )
+  , Mont::unsafe_make( [ 0x195a4c97 , 0x6d0790af , 0xc8754036 , 0x3cc37fb7 , 0xf0a05a0f , 0x3ea734c4 , 0xf50618df , 0x1b9933f8 ] )
+  , Mont::unsafe_make( [ 0xebadb70e , 0xecd45d76 , 0x25916cff , 0x63edeb26 , 0x9a78adbc , 0x79fe2ff9 , 0x3048537a , 0x21fb9b86 ] )
+  , Mont::unsafe_make( [ 0x8ab1f982 , 0x23dab31c , 0x22a85e43 , 0x28b5934c , 0xe8cf1010 , 0xebc7cae7 , 0x011156b2 , 0x060fb1d4 ] )
+  , Mont::unsafe_make( [ 0x8774f941 , 0x28c61bed , 0xc67be94d , 0xf7bc1842 , 0x3f6e787e , 0x42304408 , 0xf8d5e424 , 0x12f01fdf ] )
+  , Mont::unsafe_make( [ 0x85308172 , 0x6f200b83 , 0x32d17e59 , 0xa188be19 , 0x1952c722 , 0x2e1a51d7 , 0x8c490bf6 , 0x14d511d2 ] )
+  , Mont::unsafe_make( [ 0x11ec9be9 , 0x88869b60 , 0x04d7937f , 0xaeb4827b , 0x6a472ea1 , 0xf6d0586d , 0xed865b42 , 0x02d6c0f3 ] )
+  , Mont::unsafe_make( [ 0x7aee4047 , 0x7e8ebff2 , 0x02ae3d29 , 0xe47527c6 , 0x083afdd7 , 0x3505c673 , 0x89b4d45b , 0x2bf5b15f ] )
+  , Mont::unsafe_make( [ 0xcada4baa , 0xb2513b9d , 0xbe15e735 , 0x35facdcd , 0xa9dfc29a , 0xf82e423d , 0x6d570761 , 0x1528947f ] )
+  , Mont::unsafe_make( [ 0x61a5dd24 , 0xb53b7242 , 0xaf773971 , 0xd0c99ce1 , 0xdb88caef , 0xd6a23c71 , 0xdfbee740 , 0x04f8291e ] )
+  , Mont::unsafe_make( [ 0x471de303 , 0x4118792f , 0x3680bcfb , 0x7651867c , 0x45f58b72 , 0x2d4b9a5e , 0xc1cb226a , 0x044ab0ef ] )
+  , Mont::unsafe_make( [ 0xf3d05bd1 , 0x0dc400dc , 0x314bca40 , 0x553c4bc0 , 0xcec61822 , 0x3fd46b7f , 0xdb8b65d1 , 0x18672df5 ] )
+  , Mont::unsafe_make( [ 0x8de9981e , 0xdc7a2cc7 , 0x1dc4a7e7 , 0x1f6b341d , 0xd15751fb , 0x4db23d5c , 0xda2c258d , 0x2134c3e5 ] )
+  , Mont::unsafe_make( [ 0x21279562 , 0xfa669ed7 , 0xcde53491 , 0x03c981a9 , 0xf80fa765 , 0x40a53cc8 , 0xd7175565 , 0x0d9134e6 ] )
+  , Mont::unsafe_make( [ 0x76073899 , 0xaf4d9a71 , 0x9be224f9 , 0x28e9497a , 0x83a7e40f , 0x83f3ef6a , 0x46320390 , 0x19ffc409 ] )
+  , Mont::unsafe_make( [ 0xda123065 , 0xbd71cd26 , 0x39adf114 , 0x49f3aab9 , 0x502f5ac9 , 0x5a7771d1 , 0x077ccd51 , 0x001e3677 ] )
+  , Mont::unsafe_make( [ 0x0cc6a5cb , 0x24c57b76 , 0x71f6f8b0 , 0x2cdd92bf , 0x383cbac4 , 0xada1e638 , 0xffd8b447 , 0x113a39d2 ] )
+  , Mont::unsafe_make( [ 0x954f0a00 , 0xdaa61122 , 0x69d0f80c , 0x287c72be , 0xcdeb4005 , 0x14f8f4a2 , 0xd90c4da8 , 0x0a5f5523 ] )
+  , Mont::unsafe_make( [ 0xa33976cd , 0x0177bdb7 , 0xad86ae04 , 0xf741043a , 0xd14b0243 , 0x078dba32 , 0x7cff6ffc , 0x24ad7320 ] )
+  , Mont::unsafe_make( [ 0xd27e6e3b , 0xeb46a76a , 0x3248aca4 , 0x11ed6e9e , 0xd0d30038 , 0x1bbd86c8 , 0x4ed48002 , 0x2164318b ] )
+  , Mont::unsafe_make( [ 0x4e80fb50 , 0x5c5bf098 , 0x1a1d4108 , 0x93d9dc41 , 0x7c159c3e , 0xa65a4a1d , 0x44b9f829 , 0x0b44f2c4 ] )
+  , Mont::unsafe_make( [ 0x75fca8ca , 0xab862071 , 0x460eb986 , 0xc7626d1f , 0x74de3a81 , 0x5bc822d7 , 0x481e5f72 , 0x145f9984 ] )
+  , Mont::unsafe_make( [ 0xc10b423c , 0xd362468a , 0xb4c7d7eb , 0x8fb6090c , 0xd86beea4 , 0x9327e629 , 0x6757dd96 , 0x283bbd08 ] )
+  , Mont::unsafe_make( [ 0xe16a5222 , 0x27582392 , 0xa3d5a9bc , 0x06e10269 , 0x93e7d10d , 0x6065171e , 0xa2ef04e8 , 0x1877f4ca ] )
+  , Mont::unsafe_make( [ 0x052fd420 , 0xfcba48ae , 0x48d1a9db , 0x0ad7dc50 , 0x29fb8ce8 , 0x94c0d9ce , 0x9adb926e , 0x1c6d4533 ] )
+  , Mont::unsafe_make( [ 0xc523baac , 0xe5a28e74 , 0x73c52465 , 0xc21e0f90 , 0xc3cac2c3 , 0xbf47891d , 0xbd63b1a7 , 0x23c86088 ] )
+  , Mont::unsafe_make( [ 0x022ea42a , 0xcad2d41b , 0x6c18e82c , 0x7931a7d4 , 0xab3fc057 , 0xba563510 , 0xc77d354a , 0x0d38879e ] )
+  , Mont::unsafe_make( [ 0x2272ed32 , 0xb483d73d , 0x74003273 , 0xc43b40dc , 0xf06d07c5 , 0x969a6c90 , 0x150530df , 0x249ab56c ] )
+  , Mont::unsafe_make( [ 0x8981e043 , 0x4e4b7269 , 0xb8e69acc , 0xf0edde08 , 0xc24e8600 , 0x5e9d1329 , 0xff9c2c20 , 0x2c92aabe ] )
+  , Mont::unsafe_make( [ 0x34376515 , 0xd0daa637 , 0xcabe01b5 , 0xcc1f6086 , 0xd81659f1 , 0x8adf8e13 , 0x50f3e9f0 , 0x276d87da ] )
+  , Mont::unsafe_make( [ 0x58de7654 , 0xc1e8e77c , 0xaf952dfa , 0xbbb7a2d8 , 0x552c6d45 , 0xd3ed4713 , 0x81008dbe , 0x10c90ff7 ] )
+  , Mont::unsafe_make( [ 0x050f8e27 , 0x77c03ce7 , 0xd181a734 , 0x29e2b9e2 , 0x0e931e2c , 0x9a11941b , 0x60bc6c78 , 0x06594227 ] )
+  , Mont::unsafe_make( [ 0x36627948 , 0x96e42ca6 , 0x573ae843 , 0xa59936ab , 0x3f5412d4 , 0xb56ca975 , 0xf94a1fed , 0x2d94f6f1 ] )
+  , Mont::unsafe_make( [ 0xf98a64c6 , 0x4919ab11 , 0x271721ad , 0xce52acf7 , 0x7f00fa81 , 0x4697d5b2 , 0x68d0b919 , 0x0e76bbd8 ] )
+  , Mont::unsafe_make( [ 0x74449519 , 0xfd3b2c95 , 0xb0e56ffd , 0x0695d71c , 0xe0b9237d , 0x09e7746f , 0x2fdf02c9 , 0x1eb8d842 ] )
+  , Mont::unsafe_make( [ 0xe90fb141 , 0xdf2d6391 , 0xeaa8e4af , 0x9388fc52 , 0xcce4c6e3 , 0xc9ea2a10 , 0x584287ba , 0x04260ad7 ] )
+  , Mont::unsafe_make( [ 0xfdbc589e , 0x95889d3c , 0xe2fc69a3 , 0x49a3d50d , 0x0e00a339 , 0x4b6f7d96 , 0x56469f4d , 0x0aced4f7 ] )
+  , Mont::unsafe_make( [ 0x010d7c84 , 0xf9212cb5 , 0x718d5d38 , 0x7fb76422 , 0x1025c7b1 , 0xf5a47cf6 , 0xa7da7743 , 0x2dc62029 ] )
+  , Mont::unsafe_make( [ 0x2372f812 , 0x136a948c , 0x58c0dd56 , 0x51df8a56 , 0x98c39774 , 0xb4b3de8c , 0x371aa6ed , 0x1f2aaccc ] )
+  , Mont::unsafe_make( [ 0x991759a5 , 0x78491ab4 , 0xd472e3cd , 0x92711318 , 0xdac97cc8 , 0x756c9a99 , 0xe34e4d4b , 0x070fdeff ] )
+  , Mont::unsafe_make( [ 0xfd39a8c3 , 0x543d92c8 , 0xdb0256eb , 0x9706b9b8 , 0xda8d802f , 0x9aa40e9d , 0x8b811093 , 0x138ecbbc ] )
+  , Mont::unsafe_make( [ 0xc3880410 , 0x391639e1 , 0xe593adbb , 0x8bde1873 , 0x4e3b0aef , 0xa4e1d516 , 0x96248c9d , 0x07d06c2f ] )
+  , Mont::unsafe_make( [ 0xd1aa3058 , 0x2b0afb33 , 0x7eb1d1b7 , 0xcfdadff0 , 0x50bb3fe2 , 0x0b45dfdb , 0x2df8a311 , 0x25532910 ] )
+  , Mont::unsafe_make( [ 0x3b802516 , 0xb7bd09f4 , 0x44dbd2e9 , 0xf674abac , 0x345a7b4b , 0x4484f2be , 0xdfabded2 , 0x1ba764d5 ] )
+  , Mont::unsafe_make( [ 0xd8ca2623 , 0xc6f451bc , 0x2ada7149 , 0x6f156f1e , 0xb3455516 , 0xf27278c9 , 0x7d44693f , 0x084dfe31 ] )
+  , Mont::unsafe_make( [ 0x9ade73e1 , 0xdc416858 , 0x8cf6861e , 0x2d1d1d4b , 0x044ca139 , 0xc508b1cf , 0xdb3e6e61 , 0x270d188f ] )
+  , Mont::unsafe_make( [ 0x4fe37891 , 0xbd15c90e , 0xceb6fc27 , 0xf1fd6907 , 0x5ed629c5 , 0x04edf53f , 0x3e6aad50 , 0x09279c34 ] )
+  , Mont::unsafe_make( [ 0xbf1bfd3f , 0x1bf16d97 , 0xe6ec305e , 0x93e727ea , 0x7e929f0e , 0x2efb69de , 0x34695dc5 , 0x02d30fa3 ] )
+  , Mont::unsafe_make( [ 0x1f2d5241 , 0x30911cbd , 0x192af603 , 0x20f49685 , 0xd73a691a , 0xe78a7bc4 , 0x80d17bda , 0x1c8c463d ] )
+  , Mont::unsafe_make( [ 0x649aaaf6 , 0x21e8eba9 , 0xdfc37ea4 , 0x570301cb , 0x3657d7ba , 0x4062d994 , 0xfbbb4b5e , 0x1ce9c64f ] )
+  , Mont::unsafe_make( [ 0xdbb56d80 , 0xca89514d , 0x4f0c14c0 , 0xad5eb263 , 0xc34c402a , 0x07b49834 , 0x6d49cc3f , 0x04d44c11 ] )
+  , Mont::unsafe_make( [ 0x97f3bef3 , 0xb631de05 , 0xee1863d5 , 0x2dcc4999 , 0xa01946c5 , 0x6485e9c1 , 0x12c7b4d8 , 0x158608a1 ] )
+  , Mont::unsafe_make( [ 0x4f832b6b , 0x24207663 , 0xa20140be , 0x506e3ecb , 0x1e98779d , 0xf66068ea , 0xf2dc0009 , 0x0f265762 ] )
+  , Mont::unsafe_make( [ 0xc92e9091 , 0x0f73671f , 0xbde1acbc , 0xbc78711e , 0x65a18a06 , 0x76f3915f , 0xea7d41b0 , 0x2ac346e4 ] )
+  , Mont::unsafe_make( [ 0xa18f3128 , 0xeb84b38a , 0x0abc6d74 , 0x074a3cc6 , 0x335baa52 , 0x71ccbc83 , 0x0d5b3191 , 0x2be3b7bf ] )
+  , Mont::unsafe_make( [ 0x59ce7ab0 , 0xa7263f5d , 0xb3dec951 , 0xaaa3e209 , 0xb4e8d11c , 0x93117a21 , 0xe1e05364 , 0x142f1fc2 ] )
+  , Mont::unsafe_make( [ 0x0fce01d9 , 0x465d118d , 0xec87bc83 , 0x994b9264 , 0x2b31ec35 , 0x54ca4a50 , 0xee06c1c3 , 0x2650d0a0 ] )
+  , Mont::unsafe_make( [ 0x503dc422 , 0xd9b41874 , 0x54a9094f , 0x6f6e9f97 , 0x60b84306 , 0x00d71d34 , 0x938cb9bb , 0x0a5a4139 ] )
+  , Mont::unsafe_make( [ 0x61b3ba5f , 0xdebc5438 , 0x792d12d7 , 0xd64f88d9 , 0x0a05a003 , 0x78e3944d , 0xe1addb8d , 0x29f139ed ] )
+  , Mont::unsafe_make( [ 0xa9d80c40 , 0x241d07cf , 0x60a7e5d7 , 0xa749f73b , 0x975d1e80 , 0x0568a23e , 0xcaf1cfd4 , 0x18731d12 ] )
+  , Mont::unsafe_make( [ 0xadc39466 , 0xef757841 , 0x1b1448ce , 0x31541df8 , 0xcb815ea8 , 0x95c1801d , 0x67e9141e , 0x2195c8b1 ] )
+  , Mont::unsafe_make( [ 0x5c27e6a3 , 0x4b7913b5 , 0x20289426 , 0xa2d0caa4 , 0xedea683e , 0xd21d2620 , 0xf75d1dcf , 0x0ad402ff ] )
+  , Mont::unsafe_make( [ 0xa341b200 , 0xa9996965 , 0xc032736b , 0x6adacf94 , 0xff7e20ef , 0x9ff140e2 , 0xe17bbd80 , 0x18f29ba7 ] )
+  , Mont::unsafe_make( [ 0x4338ecf9 , 0xd2fbd210 , 0x5a104d96 , 0x7b32531a , 0xd4f85dca , 0xed7b866e , 0x94e0177c , 0x01929135 ] )
+  , Mont::unsafe_make( [ 0xfb2b94f9 , 0xcbea2301 , 0x9cc67e4c , 0xaf9ddfc6 , 0x3629fabc , 0xee031962 , 0x7a0c6486 , 0x264107a5 ] )
+  , Mont::unsafe_make( [ 0x3d51e656 , 0x9a9a95b3 , 0x532cbf90 , 0xe0f45af8 , 0x8fbe6066 , 0x7a42f0e3 , 0x238e3aa8 , 0x00b4d2c4 ] )
+  , Mont::unsafe_make( [ 0xe16bd8ec , 0xf8aafbfb , 0xe83a9ae3 , 0x5e6cbcbc , 0xf406223c , 0x62425594 , 0x8f2b4f17 , 0x11780d10 ] )
+  , Mont::unsafe_make( [ 0x846ca7fc , 0xbbe89b6b , 0x8fb8e766 , 0xd02d2f0e , 0xbfba7730 , 0x51bcc1f6 , 0x0b9c727c , 0x1b733e61 ] )
+  , Mont::unsafe_make( [ 0xf0175902 , 0xfb6c598e , 0x476f871e , 0xf24404a5 , 0xafaf4bc1 , 0x06e54e88 , 0x2f6465c2 , 0x09de2ff5 ] )
+  , Mont::unsafe_make( [ 0xeafb7057 , 0x2d028c0b , 0x57341ca5 , 0xd43033f9 , 0x35a1d27e , 0x10086015 , 0x3f0a1bee , 0x20314ecb ] )
+  , Mont::unsafe_make( [ 0x240952c5 , 0x3c422e72 , 0xa9f3de85 , 0x2b486495 , 0x8846bc8a , 0x8ac15c94 , 0xa98d53a0 , 0x09da15a6 ] )
+  , Mont::unsafe_make( [ 0x316d183e , 0xbc539c8e , 0x0747f2b8 , 0x35ff2fc8 , 0x390ce456 , 0xd0aacb35 , 0x6771aec9 , 0x02b2355a ] )
+  , Mont::unsafe_make( [ 0x5b9846fd , 0x567ca308 , 0x4d56f011 , 0x4d523933 , 0x77806811 , 0x8ddae24d , 0xa16c26f7 , 0x0c3931e9 ] )
+  , Mont::unsafe_make( [ 0x7f8ec838 , 0x7d0fb3eb , 0x66c9bdf1 , 0x155b2462 , 0xf4f48a8b , 0x86c44a2c , 0x1f7c7559 , 0x088d0b07 ] )
+  , Mont::unsafe_make( [ 0x8c001352 , 0xe54e1f11 , 0x987a1222 , 0xee1a678a , 0x0eeb34a5 , 0x75fefdac , 0x76135925 , 0x1e4e115f ] )
+  , Mont::unsafe_make( [ 0xedbb8c5a , 0x96ae18b7 , 0x759e5d5a , 0x2f444b92 , 0x56bd02a5 , 0xe4de3738 , 0x42d8f3e9 , 0x213cbf50 ] )
+  , Mont::unsafe_make( [ 0x4113b43f , 0x36d08026 , 0x437e9d1f , 0xf5896ef4 , 0x3aa3a9fd , 0x308f7ce9 , 0x0ad696c2 , 0x204616ba ] )
+  , Mont::unsafe_make( [ 0x597efe5a , 0x7465f7a5 , 0x125ba47b , 0xaa774815 , 0x237266d9 , 0x8019bd30 , 0x623eb204 , 0x132011b9 ] )
+  , Mont::unsafe_make( [ 0x22c83166 , 0xdec6e965 , 0x9f967bbb , 0x78fc5496 , 0x5b72c230 , 0x043b04d1 , 0xf0fbd724 , 0x108ecb1c ] )
+  , Mont::unsafe_make( [ 0x54249ad6 , 0x7374d292 , 0xe7811cf9 , 0x5059d94c , 0x504306a1 , 0x4115c34d , 0x2998e484 , 0x0c26f1cd ] )
+  , Mont::unsafe_make( [ 0x788bf0bc , 0x40243667 , 0xe79c4840 , 0x5b889037 , 0x6cd24763 , 0xea80bdd6 , 0x3f62028e , 0x084342a8 ] )
+  , Mont::unsafe_make( [ 0xb6a94c5d , 0xa5dcc1d8 , 0xf2df90db , 0xd2fe9c92 , 0xb0775b3a , 0x0ca2421c , 0x7a4c00b0 , 0x280e9546 ] )
+  , Mont::unsafe_make( [ 0x973e6fc5 , 0x172a5a24 , 0x0e24beaf , 0xdee2fecc , 0xcc52f0c1 , 0x16f97217 , 0x162270de , 0x1bade11a ] )
+  , Mont::unsafe_make( [ 0x70826bd3 , 0x6625cf6b , 0x78271488 , 0xf0b0e9e7 , 0xf07d1dc0 , 0x539abb9c , 0x1dd74063 , 0x2728c641 ] )
+  , Mont::unsafe_make( [ 0x69eb7ff0 , 0xf59da8f3 , 0x4872c058 , 0xefd401b1 , 0x2f1ee962 , 0x2d4de919 , 0x9856a2e0 , 0x26e19dce ] )
+  , Mont::unsafe_make( [ 0x8eb55ec9 , 0xa7844c2b , 0x1e8be82f , 0x16faef0c , 0x8fc74580 , 0x0e5636c3 , 0x5dbce1ad , 0x0cc8444c ] )
+  , Mont::unsafe_make( [ 0x6d0c08f8 , 0xd45cc2a4 , 0xee4f2b4e , 0x88848706 , 0x9bf55493 , 0x30d669cd , 0xb4d86f2f , 0x1309772b ] )
+  , Mont::unsafe_make( [ 0xd62754a8 , 0x873decec , 0xa9da8ede , 0x61c655a9 , 0x06372156 , 0x5690e2d5 , 0x306aeb1c , 0x011273ed ] )
+  , Mont::unsafe_make( [ 0x5fb0fea3 , 0xe18908b5 , 0x2a687178 , 0x7785d1a5 , 0xd387b8d9 , 0x8cdd7e12 , 0x9ab3f8c7 , 0x20101d4c ] )
+  , Mont::unsafe_make( [ 0x07ce468f , 0x4eb72c13 , 0x2201686a , 0x4eee4c4b , 0xc9086ca5 , 0x556d77d9 , 0x181a0c7f , 0x2a9e0116 ] )
+  , Mont::unsafe_make( [ 0xd0e35ac6 , 0x14b95091 , 0x354a384a , 0x976646c0 , 0x73ce855c , 0x6e16ba1a , 0x3305c003 , 0x0cb8320c ] )
+  , Mont::unsafe_make( [ 0xa3d9dbd7 , 0xf1c7a372 , 0xe942fa9b , 0x7a24724e , 0xfb1668f6 , 0x50b0be1f , 0x51783e86 , 0x1f411cc3 ] )
+  , Mont::unsafe_make( [ 0xde12c207 , 0xf156fdb3 , 0x0ded3c0b , 0xc41faf6a , 0x4f7e520b , 0xeb5baa6a , 0x8cb762d0 , 0x002bdc1c ] )
+  , Mont::unsafe_make( [ 0x3dceafa2 , 0x5bfcd063 , 0xa8f3ee40 , 0x6af4f152 , 0x477722e6 , 0x7d7dd069 , 0x7c2b8e94 , 0x0cf4dee7 ] )
+  , Mont::unsafe_make( [ 0x7e7c54a8 , 0x6b287d64 , 0x48a64aff , 0xb2033a25 , 0x1c79c68f , 0xe62c9658 , 0x58e199ec , 0x24385f15 ] )
+  , Mont::unsafe_make( [ 0x991c3ef8 , 0x40ec17ee , 0x1aec022f , 0x608be6f1 , 0xb4c1f511 , 0x6aac19ae , 0x4bd68c11 , 0x0ff2dae4 ] )
+  , Mont::unsafe_make( [ 0x71aa23f8 , 0x146516ef , 0x68f36fb2 , 0x2d5888a8 , 0xb493dc47 , 0xdaf92e79 , 0xef56c88d , 0x1d7d426f ] )
+  , Mont::unsafe_make( [ 0xac356f2e , 0x9f50133b , 0x4346f243 , 0x8151a186 , 0x8e917862 , 0x2a2460aa , 0xe0d0d02c , 0x22de81e5 ] )
+  , Mont::unsafe_make( [ 0x3e2eb889 , 0x07a0eb8b , 0xe6f32238 , 0x38a776f0 , 0x6f5e93d0 , 0x22becb15 , 0xea71554d , 0x21ce986e ] )
+  , Mont::unsafe_make( [ 0x95cd27bb , 0x6c50f87e , 0x9f78cc90 , 0x2f71a038 , 0x6278b701 , 0xcffe5849 , 0xfaece381 , 0x06065956 ] )
+  , Mont::unsafe_make( [ 0xfd7e6dc6 , 0x9e4cb6b1 , 0xc2b887d2 , 0xa90715b6 , 0xb3a96452 , 0xa5e20ac5 , 0xbb665859 , 0x033d154f ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// internal mixing matrix (`t x t`s, flattened)
+pub const CONST_P: [Mont; 289] = 
+  [ Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x560d112c , 0xcdf229f2 , 0x8be422b8 , 0x3355c2c8 , 0xa0ba3c2e , 0xe27316a8 , 0xaef5d3c2 , 0x246d2a77 ] )
+  , Mont::unsafe_make( [ 0x089f5b85 , 0x7d860ab3 , 0xc89ad81d , 0x73ac805a , 0x0164f9ef , 0xd011bdd3 , 0x3d37c4c0 , 0x2ac7fc26 ] )
+  , Mont::unsafe_make( [ 0xcd74b9d9 , 0xa0a9aebb , 0x2527be6e , 0x30e0fd2a , 0x696c71c0 , 0x5d5d9f13 , 0xa29eeb75 , 0x251eb2b7 ] )
+  , Mont::unsafe_make( [ 0x3005f1f9 , 0xa6034272 , 0x068e45ea , 0x37f26090 , 0x32e52199 , 0xc6874c06 , 0x9a3ee3a5 , 0x0329e1ed ] )
+  , Mont::unsafe_make( [ 0xa1d5720f , 0xf4016e3e , 0x78e1abb7 , 0x144bda1c , 0xd74f31df , 0x03be8c6f , 0xc65f29c5 , 0x0df454f8 ] )
+  , Mont::unsafe_make( [ 0x921b44fc , 0x167492eb , 0x93d87de4 , 0x224a12a1 , 0x86f8f168 , 0x7e423640 , 0xb696c602 , 0x2b757c8c ] )
+  , Mont::unsafe_make( [ 0x6d93152e , 0x6720ce5a , 0xfed898b0 , 0x77ca5855 , 0x2ae9514e , 0x37dc2d71 , 0x319fb5a4 , 0x1e092c99 ] )
+  , Mont::unsafe_make( [ 0x8265fb8c , 0xd845b7b7 , 0x5515d37f , 0x7cc9875e , 0x9fb5fabb , 0x20de2d24 , 0xa32de919 , 0x0ce985c7 ] )
+  , Mont::unsafe_make( [ 0x5035e7bc , 0x8de3e56c , 0xf0efb0c1 , 0x4288d0a0 , 0x8c18d641 , 0xd30c26bc , 0x07b29822 , 0x285f0d00 ] )
+  , Mont::unsafe_make( [ 0x22686174 , 0x13544040 , 0xed829db4 , 0x0a324b59 , 0x359cb4ec , 0x0353e59d , 0x202233e1 , 0x26721741 ] )
+  , Mont::unsafe_make( [ 0x7cc283af , 0xed065fc7 , 0x2d417a93 , 0x51e9976b , 0xacd7ead9 , 0x5cdea681 , 0x309005de , 0x0242cd42 ] )
+  , Mont::unsafe_make( [ 0xa68afa98 , 0x1326e16f , 0xd64ae890 , 0xda673a4d , 0xa50f24dd , 0x10d12959 , 0x3281dc9e , 0x1f6d68a5 ] )
+  , Mont::unsafe_make( [ 0x7e2248df , 0xcc1251e2 , 0xfe006d5f , 0xd56fed63 , 0xd76a009d , 0xf4beb59f , 0x3df86a19 , 0x06d3ded3 ] )
+  , Mont::unsafe_make( [ 0xb1125f99 , 0xf1f9266e , 0x500ad7c0 , 0xf0f91941 , 0x562f20bc , 0x76af86ed , 0xee31e08d , 0x1736b010 ] )
+  , Mont::unsafe_make( [ 0x9f7a6022 , 0x81224cc7 , 0x9509e719 , 0x583e0e7a , 0x81473f1c , 0xad108fee , 0x892f0140 , 0x1c3eec29 ] )
+  , Mont::unsafe_make( [ 0x3ca684cf , 0x60e79b29 , 0x466877f0 , 0xa542d424 , 0x97c0e660 , 0xa5ab298e , 0xafeb6e13 , 0x2e1075c9 ] )
+  , Mont::unsafe_make( [ 0xaa517fbc , 0xcd7e740a , 0x397f4c67 , 0x688e453b , 0x526b7f1c , 0x5d92ec01 , 0xaee7806c , 0x1d66e6b4 ] )
+  , Mont::unsafe_make( [ 0xd448f8a6 , 0x6d7ea5de , 0x69572fea , 0x92f49a7f , 0x1b60e892 , 0x1c938617 , 0x3f2c0cd8 , 0x0dc87e66 ] )
+  , Mont::unsafe_make( [ 0xac19d3cf , 0xb3bfe631 , 0x22440d1c , 0xd6d4f6dc , 0xe283d0b5 , 0x7d76806b , 0x27528d41 , 0x090a595d ] )
+  , Mont::unsafe_make( [ 0x10c8478a , 0xdb038742 , 0xf111642c , 0xa345ce21 , 0xac098a11 , 0xc3313500 , 0x8131de42 , 0x28eacbdf ] )
+  , Mont::unsafe_make( [ 0x6ec72f09 , 0xb06950c6 , 0x34e48829 , 0x1db488b7 , 0x6dd2bbed , 0x31530505 , 0xda7f44fa , 0x21da81e2 ] )
+  , Mont::unsafe_make( [ 0xb46d1299 , 0x052ed41e , 0x82e8025e , 0xe44429cb , 0x1958e8a6 , 0xed7b701e , 0x4a20db9f , 0x20d2884f ] )
+  , Mont::unsafe_make( [ 0x2fc7694b , 0xc2a1f54b , 0x9b34c1b3 , 0x7f0c73c4 , 0x9161aebe , 0x5e83cb67 , 0x48003ad9 , 0x1158c827 ] )
+  , Mont::unsafe_make( [ 0xcae009dd , 0x413daa52 , 0x2e47dc2d , 0x095d022b , 0xa599bbe2 , 0xd57a7301 , 0xb0ffce5d , 0x2fb5f14a ] )
+  , Mont::unsafe_make( [ 0x53b90281 , 0xc68d3572 , 0xc2baa682 , 0x9e5d1076 , 0x351a8d4f , 0x8a6278c8 , 0x7e94907e , 0x23e18bda ] )
+  , Mont::unsafe_make( [ 0xce038a32 , 0x73a7e509 , 0x18642ec6 , 0xb9bb093b , 0x34aa3c62 , 0x00bacbe2 , 0x9b2452a0 , 0x2b21949d ] )
+  , Mont::unsafe_make( [ 0xebc964a5 , 0xa28a44fe , 0x0873eeb7 , 0xaf2f9005 , 0x81f3a9fb , 0x5fce1572 , 0xdf499206 , 0x2371919b ] )
+  , Mont::unsafe_make( [ 0xec7d398f , 0xc453390c , 0x488637d4 , 0x6444e3aa , 0xe1744f1a , 0xee83b1eb , 0x78cb146b , 0x02ce040c ] )
+  , Mont::unsafe_make( [ 0x55c01bc9 , 0x8cda9db9 , 0xd0fec95f , 0x8cadf680 , 0x3cfb00f4 , 0xc1253159 , 0x23f08ae4 , 0x197c929c ] )
+  , Mont::unsafe_make( [ 0x67722667 , 0xb4b59953 , 0x7e21ff58 , 0xebce4005 , 0x3ed8aaad , 0x61fe50c3 , 0x400aa085 , 0x18449729 ] )
+  , Mont::unsafe_make( [ 0x1f9041d6 , 0x77ac323a , 0x141063a8 , 0xc5f558ed , 0x39fb9704 , 0xdf9e0aff , 0xaebc20f1 , 0x101e71e2 ] )
+  , Mont::unsafe_make( [ 0xc299b012 , 0x89992fce , 0x53df8dae , 0x2889d35f , 0x8a030e85 , 0x8a9a4001 , 0xad259c75 , 0x2f65a2ea ] )
+  , Mont::unsafe_make( [ 0x701c75e6 , 0x617047ab , 0xe2e484c4 , 0x605244d8 , 0x6f6e4f9c , 0x0592eb1d , 0x96492ced , 0x29e34328 ] )
+  , Mont::unsafe_make( [ 0xb3e3e11d , 0xc1ccf05b , 0xe46f3646 , 0xbddb2bc5 , 0x7c3c632b , 0x08be6a0e , 0xe7e46993 , 0x1d377f76 ] )
+  , Mont::unsafe_make( [ 0x9a1cb322 , 0x1510fc03 , 0x9aa30115 , 0x83e05ba3 , 0x112fd197 , 0xa28acac0 , 0x957cd9e7 , 0x1b30e265 ] )
+  , Mont::unsafe_make( [ 0x592f8cce , 0x121adf38 , 0xb3bcdb15 , 0x44e566d3 , 0x75656344 , 0xe099ef23 , 0x446a7020 , 0x0d274f97 ] )
+  , Mont::unsafe_make( [ 0x434cbdb6 , 0x2dbd9391 , 0xf60ecd96 , 0x522b92c0 , 0x5f2906b8 , 0x98d3996c , 0xf9574101 , 0x01a0b04b ] )
+  , Mont::unsafe_make( [ 0x49021acc , 0xd699141c , 0x1b2fe900 , 0x6c9fe393 , 0x2f44e102 , 0xbf2768f5 , 0x04fffb69 , 0x058c64d2 ] )
+  , Mont::unsafe_make( [ 0x1b8c504d , 0xb9da1f36 , 0xf78a4480 , 0x7f668722 , 0xb32a1878 , 0xa3abce05 , 0xa106c991 , 0x24445036 ] )
+  , Mont::unsafe_make( [ 0xab0cdac1 , 0x1cf69239 , 0x045a3b46 , 0xf49f9d5b , 0x3af8ae6d , 0xca665d8b , 0x25526099 , 0x2d36b87f ] )
+  , Mont::unsafe_make( [ 0xf445bfaa , 0x2e00f14b , 0xac46ee06 , 0xdbe3c7ee , 0xf4dff06b , 0x717dca6f , 0x26d46b83 , 0x2372b886 ] )
+  , Mont::unsafe_make( [ 0x858b993d , 0xe0c79edb , 0x0fc5eced , 0xe2843b56 , 0xc812dc3b , 0xbfe28e4d , 0x2ce9f31b , 0x066b53b1 ] )
+  , Mont::unsafe_make( [ 0xbd1770e9 , 0xabb2e38d , 0xd7c20eee , 0x39331717 , 0x1642143b , 0x31e57e23 , 0xe1004e06 , 0x0930b58f ] )
+  , Mont::unsafe_make( [ 0x141c0bbf , 0xec712b16 , 0xa8d1d822 , 0x07f78acc , 0x5409e58d , 0x5d57dfba , 0xc564c807 , 0x18acb1a3 ] )
+  , Mont::unsafe_make( [ 0x51347cf8 , 0x5e52af28 , 0x65f56d97 , 0xbdd284ca , 0x769ff722 , 0x9dad04f1 , 0xb73f46ac , 0x2210cc23 ] )
+  , Mont::unsafe_make( [ 0x196935bc , 0x230b93e8 , 0x3ecdc515 , 0xef1ff2c2 , 0x1677ce6f , 0xa4e1d0b4 , 0x4b612927 , 0x043ca881 ] )
+  , Mont::unsafe_make( [ 0x4d60ad88 , 0x433f84c6 , 0xb962e5b9 , 0xc8fa1b8f , 0x60fc9437 , 0x1ecfb615 , 0x4e101991 , 0x09ae379b ] )
+  , Mont::unsafe_make( [ 0x29d7b4f5 , 0x465dc1ac , 0x2c8b8c26 , 0xf0604da6 , 0xec527f46 , 0xa15e778e , 0x3ddea8a3 , 0x003df149 ] )
+  , Mont::unsafe_make( [ 0x98939ed9 , 0x210f2c80 , 0x3f42e3c4 , 0x25ea9c51 , 0x7a4f0856 , 0x5a3cf7a0 , 0x02c91853 , 0x2c585b4e ] )
+  , Mont::unsafe_make( [ 0x329c49a4 , 0xf005ccd7 , 0x70a465f1 , 0x5e65a3e8 , 0xc8d75f47 , 0x2fb5ba6a , 0x1362c0f0 , 0x2ecdeea9 ] )
+  , Mont::unsafe_make( [ 0x1f8d6cb9 , 0xde2f0c70 , 0xbd8d5687 , 0x7e1d4a7b , 0x474b2b11 , 0x37e1da91 , 0xad0a7980 , 0x073d8f98 ] )
+  , Mont::unsafe_make( [ 0xfe06558e , 0xc02f449c , 0x27edc367 , 0xe9abb11d , 0xd117af76 , 0x303f3e72 , 0x0e388d52 , 0x173ed1d9 ] )
+  , Mont::unsafe_make( [ 0xd62213f7 , 0x98c30ff2 , 0x180a7135 , 0x1be71144 , 0xd00bce6d , 0xbb3f0fb6 , 0xea25ed62 , 0x22087abd ] )
+  , Mont::unsafe_make( [ 0x76f5e737 , 0x5b617adf , 0xf9a5d5f3 , 0xaa74f937 , 0x61de3181 , 0x03401028 , 0x6abbec84 , 0x213367a8 ] )
+  , Mont::unsafe_make( [ 0xd218b952 , 0x65a57659 , 0x92f797b6 , 0xeabc8695 , 0xe5593144 , 0x1a34e7d4 , 0x4995249a , 0x2bafc352 ] )
+  , Mont::unsafe_make( [ 0x18b50185 , 0xaf30952d , 0x77104120 , 0xf494f92e , 0x8dae66be , 0x8731d179 , 0x8182b985 , 0x1760aaf1 ] )
+  , Mont::unsafe_make( [ 0x4c814022 , 0x85e28023 , 0x6cf1257f , 0x889bc96a , 0x4108dace , 0x009dbab9 , 0xb87e8de8 , 0x0bdeae7f ] )
+  , Mont::unsafe_make( [ 0xdb722857 , 0x05b2c568 , 0x3458a714 , 0xd30522b1 , 0xf8df634a , 0x08a723e6 , 0x10a9de4e , 0x2015d85b ] )
+  , Mont::unsafe_make( [ 0x509b904f , 0xdd1e9867 , 0xe27d50d5 , 0xad32570f , 0xb5e510ac , 0xd2f0f225 , 0x50151b15 , 0x16bf4caa ] )
+  , Mont::unsafe_make( [ 0x6aa5cdd5 , 0x318604f8 , 0xcf671e36 , 0x1e0c9599 , 0xae952c63 , 0xaaacb4dc , 0x79c93abd , 0x2f6dc696 ] )
+  , Mont::unsafe_make( [ 0xabc205de , 0xc147244f , 0x3628841a , 0x0f591170 , 0x1d02340f , 0x22a9d800 , 0x1dbf2bd3 , 0x1629e0d9 ] )
+  , Mont::unsafe_make( [ 0x0d7788e8 , 0x176938bc , 0xa911a2f3 , 0xfea97a3a , 0xaf3854de , 0x047096f8 , 0x56eaf194 , 0x0c1a7679 ] )
+  , Mont::unsafe_make( [ 0x513843a5 , 0x98f6edd5 , 0xc2afd621 , 0x907d2068 , 0x7f80488e , 0xa2d896e0 , 0x29620163 , 0x14587502 ] )
+  , Mont::unsafe_make( [ 0x31331430 , 0xaff5d9a3 , 0x32cbd4ee , 0x7cf2a869 , 0x69d3156f , 0x3ae4180c , 0xec5bd43a , 0x1a217bc0 ] )
+  , Mont::unsafe_make( [ 0xfb7185f9 , 0x643694c6 , 0x0d1bd56d , 0xd0b366ef , 0xd70942a3 , 0x1b9fe23c , 0xdbb43d59 , 0x1e54068e ] )
+  , Mont::unsafe_make( [ 0x13227287 , 0xf64a17d8 , 0x2cf40364 , 0xcd5bb1a1 , 0x18babbb8 , 0x5d0d1c77 , 0xb56870e1 , 0x0d265a62 ] )
+  , Mont::unsafe_make( [ 0xca595cc7 , 0x18202efe , 0x6aca80b6 , 0xda542168 , 0x514e94fc , 0xd37f773c , 0x891ff43b , 0x1efd6f44 ] )
+  , Mont::unsafe_make( [ 0x9325936b , 0x003f6500 , 0xd260d2c0 , 0xc4ddf6e7 , 0x4ab73125 , 0x9966325d , 0x0f7e80c1 , 0x284310c9 ] )
+  , Mont::unsafe_make( [ 0xc2535e99 , 0x98a84a1a , 0xd915383a , 0x80b7e689 , 0xee9d9d54 , 0xe5dd11c0 , 0xa35492f3 , 0x1a0a65d7 ] )
+  , Mont::unsafe_make( [ 0xf779ed58 , 0xf73cae4f , 0xcd2dc4ff , 0xe5440e43 , 0xdd2bb171 , 0xc662684e , 0xb36413bb , 0x10f917b9 ] )
+  , Mont::unsafe_make( [ 0x5b92a6fa , 0x1c16c5fb , 0xc97ca9e5 , 0x0e7fe146 , 0xe556ae53 , 0xba6fdd2f , 0xdcd296cb , 0x16090e51 ] )
+  , Mont::unsafe_make( [ 0xa8d86649 , 0x64b062ea , 0x75628bda , 0xfbd047ca , 0x843c282a , 0x1cadd1ba , 0xf5aa0899 , 0x27a76531 ] )
+  , Mont::unsafe_make( [ 0x997e0c6c , 0xd7b83ab1 , 0xc6ba13c5 , 0x2e881059 , 0x0a544252 , 0x7ce598c1 , 0xe845cd79 , 0x1db2613a ] )
+  , Mont::unsafe_make( [ 0xd227c5fe , 0xe7ba311d , 0xfdcc7913 , 0x853bc11f , 0x7b33a87f , 0x08510373 , 0x150eb3d8 , 0x16809e14 ] )
+  , Mont::unsafe_make( [ 0x028bc269 , 0x5862b84e , 0x2e212bb7 , 0x6b984a7c , 0xecfdfcae , 0x21f18304 , 0xffd74e84 , 0x2a75b1c9 ] )
+  , Mont::unsafe_make( [ 0xbd74939c , 0x1b15582f , 0x2e9b0977 , 0xe358444a , 0x0f7f6a1b , 0x527b435d , 0xc7420751 , 0x2d1ad438 ] )
+  , Mont::unsafe_make( [ 0x15eb36bc , 0x55ae999c , 0xa4e86cc7 , 0x3d816bbb , 0xc1c6c691 , 0x482ede11 , 0xfea3770b , 0x29ca1de0 ] )
+  , Mont::unsafe_make( [ 0xf8dd4c9e , 0xf5f3e89b , 0xed33af73 , 0xd3c6e1c9 , 0xe1d96968 , 0x26bdf85a , 0x7f2a68b4 , 0x2d231a75 ] )
+  , Mont::unsafe_make( [ 0x8e94c77f , 0x7a690bf4 , 0xc5313653 , 0x235149f1 , 0x117a2b69 , 0x1ad81d35 , 0x2403a00d , 0x2d9fd81f ] )
+  , Mont::unsafe_make( [ 0x70a48931 , 0x1055bd1d , 0x1634579c , 0x8ed17531 , 0xf6153764 , 0xceae15eb , 0x9b576a8a , 0x0c597a6f ] )
+  , Mont::unsafe_make( [ 0xc3a65269 , 0xd9ff3b1b , 0x1702ac94 , 0x30e8327c , 0xa1f7554c , 0x169453a9 , 0xa3bb698d , 0x222d0d3f ] )
+  , Mont::unsafe_make( [ 0x4574a312 , 0x5e71c4a7 , 0xcd730b85 , 0xd583889b , 0x69971c25 , 0x6f47ed37 , 0xa98f6da7 , 0x1aa43275 ] )
+  , Mont::unsafe_make( [ 0x5ec77485 , 0x3fdfb472 , 0x6362e411 , 0xba4e3fd1 , 0xab61eb5c , 0x628578bf , 0x0677c90d , 0x0c8a940d ] )
+  , Mont::unsafe_make( [ 0xae06cc8c , 0x1b9dbf2c , 0xa2a3c022 , 0xba767e1a , 0x12bf63fd , 0xb576538b , 0x49b22a5e , 0x2f01a0bb ] )
+  , Mont::unsafe_make( [ 0x7e6e578f , 0x7c60b2ac , 0xcddcdd98 , 0x0ee1c4e9 , 0x6686a64a , 0x25f1dfe4 , 0x4f503ddb , 0x13c36dce ] )
+  , Mont::unsafe_make( [ 0x71a55b79 , 0x40336d7f , 0x8acaf714 , 0x9c7945aa , 0x5fc0d085 , 0x343fce29 , 0xf6b6dd4a , 0x18fcf930 ] )
+  , Mont::unsafe_make( [ 0x3e33ee28 , 0x57101786 , 0x1f562ff2 , 0x9b1ae8f1 , 0xe057f860 , 0x1af2f304 , 0x967a5e99 , 0x06717220 ] )
+  , Mont::unsafe_make( [ 0x2aa2c302 , 0xad309af7 , 0x804317dc , 0xaf06ea95 , 0xd0e00039 , 0x90f9f2f5 , 0x9d82531e , 0x2626649c ] )
+  , Mont::unsafe_make( [ 0x2de5e54d , 0x14509764 , 0xefb1e2cd , 0xdcfd55f0 , 0xf50a2fea , 0x4e815b21 , 0xc490b5b1 , 0x2a63a41f ] )
+  , Mont::unsafe_make( [ 0x36b7641c , 0x510c5118 , 0xa509bee7 , 0x623ec82a , 0x59156c42 , 0x48285561 , 0x71afcaa2 , 0x1efbb9ce ] )
+  , Mont::unsafe_make( [ 0x31935fc3 , 0xe1f58a3f , 0xc3c9c2dd , 0xcc77120c , 0xb2d49962 , 0x9774d619 , 0x632bcadf , 0x0dfc950d ] )
+  , Mont::unsafe_make( [ 0xa78f77c9 , 0x190fe4bd , 0x13b47473 , 0xef08b5e8 , 0x7ece0c24 , 0x0ac3f352 , 0x9ea76bc5 , 0x0d394aa5 ] )
+  , Mont::unsafe_make( [ 0xcfb794f5 , 0x3ca80701 , 0xcacce2fe , 0x0e9f8cc8 , 0x9ed67fb5 , 0x363744e6 , 0xf45f20fb , 0x075a7908 ] )
+  , Mont::unsafe_make( [ 0xe0bab3ae , 0x1031df2f , 0x03d59365 , 0xcde72dba , 0xbe164708 , 0x6ee40646 , 0xbef7237c , 0x2f19bd66 ] )
+  , Mont::unsafe_make( [ 0x3e627b44 , 0x5942b47f , 0xac8f9a42 , 0xbb87d1e1 , 0x73a600b2 , 0xfd8f10a6 , 0x252c733b , 0x0effc86a ] )
+  , Mont::unsafe_make( [ 0x775eed55 , 0xa0e016f0 , 0xecfce09d , 0x68995f88 , 0x5aaf6e0c , 0xa33ee2b0 , 0x223a208f , 0x06a6222d ] )
+  , Mont::unsafe_make( [ 0x42d6984a , 0xd86f9a1f , 0xee32b9a0 , 0xe62fc77a , 0xe71842f2 , 0x12d0f7c7 , 0x31417c01 , 0x03a1e6ff ] )
+  , Mont::unsafe_make( [ 0xfa8e300a , 0x910c99b3 , 0x1d4958e7 , 0xb83ec143 , 0x7666846c , 0x4421a9c6 , 0xabb8726b , 0x14ed725d ] )
+  , Mont::unsafe_make( [ 0x5ed5a306 , 0xb39eeb88 , 0x83a2a7e4 , 0x70bb5deb , 0x72eea33a , 0x63ee51af , 0x2982dda7 , 0x0af109d0 ] )
+  , Mont::unsafe_make( [ 0x3de321db , 0x37b71d46 , 0x0ef51729 , 0xf39e5dd7 , 0x41ebcdf9 , 0x73afd272 , 0x778143fd , 0x2e3222f9 ] )
+  , Mont::unsafe_make( [ 0xdd9e135d , 0xe05a3c95 , 0xa0536a4b , 0xf7bd3c05 , 0xdeac028f , 0x4028717c , 0x6272b9f6 , 0x2df4cbdc ] )
+  , Mont::unsafe_make( [ 0xc4aa1d43 , 0x8f9aa182 , 0x70ec345d , 0x82c7573d , 0xfbf7497d , 0x34a2ceb2 , 0x0bb89d85 , 0x2b119cba ] )
+  , Mont::unsafe_make( [ 0xe7b15572 , 0x6f35287a , 0x2801b488 , 0xd0b58824 , 0x1f00e054 , 0x831dec77 , 0x2b9eb4a6 , 0x19f64aa4 ] )
+  , Mont::unsafe_make( [ 0x1c7de760 , 0x1af70585 , 0xee183f7b , 0x33b960d4 , 0x71fd2cbc , 0x5038285a , 0x1094f5f5 , 0x1190cfec ] )
+  , Mont::unsafe_make( [ 0x7fdad139 , 0x0fa0040c , 0x29ddf99d , 0x270060be , 0xc2b1542d , 0x6e94d915 , 0x265a0912 , 0x15fa2e7b ] )
+  , Mont::unsafe_make( [ 0x997b0bae , 0xaa3ae33e , 0x97e62182 , 0x53931cad , 0x041926a6 , 0xa906b234 , 0x898feaaa , 0x2d8b3a63 ] )
+  , Mont::unsafe_make( [ 0xcdc9f851 , 0x828f764b , 0xcddd5619 , 0x3ef425d6 , 0x139ee997 , 0x531a7002 , 0x05d73923 , 0x208ea903 ] )
+  , Mont::unsafe_make( [ 0x900365be , 0x6cc850cd , 0x2688ee4c , 0xa2523cea , 0x6de7efdf , 0x26e9a745 , 0x0099f431 , 0x2183db76 ] )
+  , Mont::unsafe_make( [ 0x97ff493f , 0x90083c37 , 0xe9c68a7a , 0x37043d41 , 0x43c65ed0 , 0x9b58732f , 0x7ab74ff0 , 0x1b16ab14 ] )
+  , Mont::unsafe_make( [ 0x140beed7 , 0x8d7b6a11 , 0xc613ca45 , 0x91f3ec1a , 0xe0e9868c , 0xb37117cc , 0xe9175360 , 0x06bbbdfb ] )
+  , Mont::unsafe_make( [ 0x84a3801c , 0x20b0c623 , 0xf1d4d2b5 , 0x08168aa8 , 0x3faee7ae , 0xcf8b5661 , 0x884b9d42 , 0x0c7de5cf ] )
+  , Mont::unsafe_make( [ 0x695dc603 , 0xe8f61526 , 0xdd3f0969 , 0xaf860d4d , 0x86acb486 , 0xf12d23b5 , 0x56b42a19 , 0x1b7fbf2e ] )
+  , Mont::unsafe_make( [ 0xdd9a954a , 0xce77bc07 , 0x45816c6b , 0x5fb5db22 , 0x7dd58a9e , 0x4a26b182 , 0xec9beac3 , 0x0b2a19c3 ] )
+  , Mont::unsafe_make( [ 0x5b9ee715 , 0xbc2df964 , 0x7983eddf , 0x2e1442b1 , 0x49330f0d , 0x7616d95d , 0x87f7e5b8 , 0x09291d25 ] )
+  , Mont::unsafe_make( [ 0xf7ab9d81 , 0xb558612c , 0x8400534e , 0x9b7eb126 , 0xe011c7f6 , 0x0d589c0d , 0x77c9e4e0 , 0x1218dc63 ] )
+  , Mont::unsafe_make( [ 0xd6d73cbc , 0x96efe7c9 , 0xfb42e54b , 0x678d616d , 0x947c8e0a , 0x19b27200 , 0x3c3e973a , 0x161ef439 ] )
+  , Mont::unsafe_make( [ 0x6bdc737b , 0xd1f26fcc , 0x06f199fc , 0x10edc7d0 , 0xf862dbc9 , 0x1f426ae1 , 0x4ebf8177 , 0x23f6c8ef ] )
+  , Mont::unsafe_make( [ 0x73378931 , 0xca567512 , 0x6897bfae , 0x7ae928fc , 0xfa153aaa , 0xfc8c0d67 , 0x808e25c3 , 0x1787db93 ] )
+  , Mont::unsafe_make( [ 0x5f8e569e , 0x7d4527f0 , 0x9cc500c5 , 0xff8d8821 , 0x4cfe1d51 , 0xaaff720c , 0x0c3a6670 , 0x0c677034 ] )
+  , Mont::unsafe_make( [ 0x864e3a5a , 0xf265faf5 , 0x707d2710 , 0x2671c744 , 0x1cba6397 , 0xd2ce613c , 0x789ab738 , 0x047d15d3 ] )
+  , Mont::unsafe_make( [ 0x39883663 , 0xa0a68ef5 , 0x19897d31 , 0x1b984b26 , 0x8fe1ffed , 0x8b69c9f1 , 0x5119f186 , 0x1cbd1005 ] )
+  , Mont::unsafe_make( [ 0x7a44d8b1 , 0x9861bfb5 , 0xd6946f7a , 0x2dc2c840 , 0x48c98be4 , 0x93e61aac , 0x7c642c53 , 0x1ec2c7e9 ] )
+  , Mont::unsafe_make( [ 0x23b2c4ec , 0x703f54af , 0x2b995240 , 0x98435804 , 0x2c551e0e , 0x3674d88a , 0x1d43167d , 0x23987de6 ] )
+  , Mont::unsafe_make( [ 0xa822e536 , 0xf74572a8 , 0x2dd64d8e , 0x87d83d32 , 0xfeca586f , 0x7da68ee7 , 0xb503842c , 0x2a4f89e4 ] )
+  , Mont::unsafe_make( [ 0x71fb1e61 , 0x4f976b9d , 0x40eb74f6 , 0xc7e78cb0 , 0xd55d6bb3 , 0x11bea4a7 , 0x439da4c3 , 0x1298bebb ] )
+  , Mont::unsafe_make( [ 0x7fb4f3da , 0x0d75c16f , 0x9bc282df , 0x2be72d8c , 0x02c608a3 , 0x731f6ea2 , 0x708f56e1 , 0x1e067369 ] )
+  , Mont::unsafe_make( [ 0x371728c3 , 0xb79b3d0d , 0xb619af68 , 0xf070a1e0 , 0xa52feeb8 , 0xd5409515 , 0x99c75f7d , 0x1b6e0ee4 ] )
+  , Mont::unsafe_make( [ 0x4db25674 , 0x08d3515b , 0x725fa1d2 , 0x1e293702 , 0xf586ba14 , 0x2ede5668 , 0xcfa75e0b , 0x20f37219 ] )
+  , Mont::unsafe_make( [ 0x2ecfbb90 , 0x00723fb2 , 0xc5c4b7a1 , 0x0afd4c22 , 0x5968c4b4 , 0xb4ac4573 , 0xffb8ef9d , 0x181aee77 ] )
+  , Mont::unsafe_make( [ 0x3eb074d6 , 0x5713b6da , 0x17bddccc , 0x75306ace , 0x85638542 , 0xdd628228 , 0xe57587ad , 0x194c9d21 ] )
+  , Mont::unsafe_make( [ 0x621e03a5 , 0x6eab3dac , 0xd8bfe102 , 0xc0100ceb , 0x1522eb18 , 0x3ea2aff0 , 0xa9dfe16d , 0x150af224 ] )
+  , Mont::unsafe_make( [ 0xf28642af , 0x1b5f9b8c , 0xadb19543 , 0x139ab086 , 0x3ae96670 , 0x9492cf19 , 0xfa5359df , 0x075960f2 ] )
+  , Mont::unsafe_make( [ 0x21ac4201 , 0xc93b28cd , 0xec06b8f0 , 0x2ee7e7e1 , 0x3d67a859 , 0x856e7b52 , 0x8c5f37d9 , 0x0cff8454 ] )
+  , Mont::unsafe_make( [ 0x3643831f , 0x20f70c52 , 0xa540c222 , 0xbf139956 , 0x37ed04b3 , 0x862499ea , 0x2f2a2967 , 0x241afc6f ] )
+  , Mont::unsafe_make( [ 0xd51f09e9 , 0xc335e733 , 0xab4cb3e4 , 0x4ed200ae , 0xe0e34907 , 0x73c87820 , 0x13d7bc38 , 0x22d3cfed ] )
+  , Mont::unsafe_make( [ 0x83431bc2 , 0x6042fd9f , 0xe590ef7e , 0xfb499410 , 0x5b981e6b , 0xb5798275 , 0x32ed99e7 , 0x304b448c ] )
+  , Mont::unsafe_make( [ 0x4c360fd6 , 0x263ef312 , 0x9a2caad7 , 0xefd4c33b , 0x1067b0ac , 0x4180e610 , 0xec0d770e , 0x24425a5e ] )
+  , Mont::unsafe_make( [ 0x9a52e69b , 0x7ecb80c7 , 0x5776178c , 0xf1e6402e , 0xc6e3be93 , 0xe306d8c1 , 0x3ea076ce , 0x1e4005b3 ] )
+  , Mont::unsafe_make( [ 0x29250526 , 0x071ffb8c , 0x5404d594 , 0x52265f69 , 0x8b42c18e , 0xbd1ecdbe , 0x79c8686e , 0x24733e5b ] )
+  , Mont::unsafe_make( [ 0xd011a1b9 , 0x45d62c0f , 0xe227c209 , 0xfed9dce2 , 0x876a4b26 , 0x86fbd675 , 0xf018521d , 0x05a4a70b ] )
+  , Mont::unsafe_make( [ 0xeb7483b9 , 0xc2140d46 , 0xe81f9702 , 0x1c39387b , 0xa1d56aec , 0x70cac113 , 0x1dd6eebe , 0x173641ae ] )
+  , Mont::unsafe_make( [ 0xe0142043 , 0x501534e5 , 0xe02d3018 , 0x9ff6b5af , 0x04389bb7 , 0x15037dfc , 0xea178642 , 0x1130f35d ] )
+  , Mont::unsafe_make( [ 0x7ef81db6 , 0x51b918a4 , 0x80c1ad44 , 0x8a6955d9 , 0xd4d97944 , 0xefd06104 , 0x8182b347 , 0x04883d3d ] )
+  , Mont::unsafe_make( [ 0x87fe7d8b , 0x3e040c78 , 0x5def428d , 0x746b6e52 , 0x5f81663d , 0x6e35b81a , 0xfa718ea4 , 0x14ebf547 ] )
+  , Mont::unsafe_make( [ 0x137b0a9c , 0x300b8077 , 0x70786aa3 , 0x51b2cd51 , 0xd3efa624 , 0x330a96f9 , 0xa6157a49 , 0x0e18b3b3 ] )
+  , Mont::unsafe_make( [ 0xd61d4e83 , 0x7ff19ff7 , 0xd1313e15 , 0xf5e1f103 , 0x2a8ab4cc , 0xbc7acc4e , 0x5d6771bd , 0x161c66bd ] )
+  , Mont::unsafe_make( [ 0x415fb28b , 0xe693ac1d , 0x6a8a921e , 0xdd6ed333 , 0xd7a9313a , 0xb1b2fac5 , 0xe3e29643 , 0x2d5d0fba ] )
+  , Mont::unsafe_make( [ 0x2aec0677 , 0x3cda56af , 0x2e924df0 , 0xb8a35de3 , 0x98045a82 , 0x401ce00c , 0x848fc1fc , 0x0f2e4894 ] )
+  , Mont::unsafe_make( [ 0xd2638b10 , 0xc7c52098 , 0x3b993262 , 0x8ade457a , 0x6453edfd , 0xc491764b , 0x349b6652 , 0x298d70b0 ] )
+  , Mont::unsafe_make( [ 0x8b4c44b2 , 0x0c9dc552 , 0x4db51c9e , 0xe0e77314 , 0x063d9ba5 , 0x414ea639 , 0x62d74225 , 0x03eb88f5 ] )
+  , Mont::unsafe_make( [ 0x4d2973a0 , 0x282a0d83 , 0x4bed74d2 , 0x8c8f97d9 , 0x84d4dc0a , 0xbb47b73f , 0x214e2e66 , 0x26ddf06c ] )
+  , Mont::unsafe_make( [ 0x614fe086 , 0xe5c5e8ec , 0x51012b8a , 0x1c867e98 , 0x507fb309 , 0xcd12e823 , 0x55f73a6e , 0x271ffbf8 ] )
+  , Mont::unsafe_make( [ 0xcd30fad3 , 0xdc523f9c , 0x869440a7 , 0xacd3ae55 , 0x34544c8d , 0xf26026ef , 0x7351eb96 , 0x16359254 ] )
+  , Mont::unsafe_make( [ 0x840f18ee , 0x60941727 , 0x58e67caf , 0xb8d7955b , 0xadff664c , 0x12bdece0 , 0xc8c3c685 , 0x162b9d78 ] )
+  , Mont::unsafe_make( [ 0x03199343 , 0x079ce373 , 0x56fa203c , 0x3a58dae3 , 0x53d6a7c7 , 0xdb6b1cb3 , 0xe755cf37 , 0x238b9213 ] )
+  , Mont::unsafe_make( [ 0xd3a4b29e , 0x7c675d5f , 0xc6c7d65c , 0x324d4565 , 0x6203d0b5 , 0xad8f6441 , 0xab16a623 , 0x2d9a5d5c ] )
+  , Mont::unsafe_make( [ 0x5bae8705 , 0x0b863dff , 0x75bf4dff , 0x327ecbfe , 0x696165fd , 0xa2411eda , 0x51673e30 , 0x0ceaabba ] )
+  , Mont::unsafe_make( [ 0xe6bad413 , 0x9ecadda0 , 0x728aff35 , 0x8c488891 , 0x06e99546 , 0x1cc85d75 , 0xa036d8b1 , 0x25b0aefc ] )
+  , Mont::unsafe_make( [ 0x715487e0 , 0xe55e7266 , 0xffaf9b73 , 0x233224f5 , 0xe4f94f17 , 0xb1906d6d , 0x51306d64 , 0x12acb199 ] )
+  , Mont::unsafe_make( [ 0x6fbc6e55 , 0xf5a11557 , 0x402dad38 , 0x6b0d4b99 , 0xd45e2dad , 0x39af6c77 , 0x226e5c4f , 0x1d467c79 ] )
+  , Mont::unsafe_make( [ 0x1f1a76b4 , 0x1ce25a53 , 0x93cf1462 , 0xe8bd03db , 0x01f27afb , 0xcd8f3d69 , 0xd399e0bd , 0x231f5758 ] )
+  , Mont::unsafe_make( [ 0x1092ca50 , 0x56ce6a33 , 0xd61ce29f , 0x65788b06 , 0x92b541d4 , 0xf814e246 , 0x00fa7c12 , 0x1359d904 ] )
+  , Mont::unsafe_make( [ 0xb3d7616f , 0xd426ace5 , 0x0c925e46 , 0x2a64cc52 , 0xe72af735 , 0x468b2e8e , 0xd5cb746a , 0x03bb5fd1 ] )
+  , Mont::unsafe_make( [ 0x13929fa9 , 0x7c00b2f7 , 0x315e4e2e , 0xdcd0e472 , 0xee50b726 , 0x521b81b9 , 0x4c93dea5 , 0x13d82c8f ] )
+  , Mont::unsafe_make( [ 0xdda1f295 , 0x28c4bbcb , 0xa7f255d3 , 0xdda04619 , 0x38fffae9 , 0x20cc33d5 , 0xd124bd65 , 0x17150317 ] )
+  , Mont::unsafe_make( [ 0xb4998951 , 0x848aa77a , 0x185f21f8 , 0x1adfaeea , 0x582a8618 , 0xd9fefe34 , 0xe70d30a2 , 0x1768c54d ] )
+  , Mont::unsafe_make( [ 0x784e3442 , 0x89167a67 , 0x6bcd5ffd , 0x246d7fc2 , 0x6683849e , 0x17500f77 , 0x34d77ef4 , 0x019b8c01 ] )
+  , Mont::unsafe_make( [ 0x5bba5669 , 0x8a9f4212 , 0x5ac2fecd , 0x9dfba2f8 , 0x65de6778 , 0x02cbc653 , 0xf9408651 , 0x05c7dd92 ] )
+  , Mont::unsafe_make( [ 0x293fa993 , 0x279aa2b7 , 0xf9ab68b9 , 0x5059a886 , 0x767c18b9 , 0xc4eabea1 , 0x1c4d5fef , 0x0666c31b ] )
+  , Mont::unsafe_make( [ 0xd3cdbf2c , 0x69ef7fc6 , 0xb99fab95 , 0x677e7a7a , 0x5c48e6a3 , 0xb021fe36 , 0xbc636686 , 0x18ff9679 ] )
+  , Mont::unsafe_make( [ 0x301325b1 , 0xbd846ece , 0x03ef47a1 , 0x933eb25f , 0x9ad21a98 , 0xf0b9a8d3 , 0xb78aba42 , 0x0560334e ] )
+  , Mont::unsafe_make( [ 0xbb62c758 , 0x210dc8d4 , 0xd90d543a , 0x6ab2ef86 , 0x86620002 , 0xa1610a0c , 0x0514fa88 , 0x1042b870 ] )
+  , Mont::unsafe_make( [ 0x10ed0403 , 0xac579d20 , 0x5b5bc841 , 0xf23e10b7 , 0xdd586277 , 0xa087b392 , 0xe04da4e3 , 0x07e0dbd0 ] )
+  , Mont::unsafe_make( [ 0x2796b009 , 0x533f003f , 0xcb22ee6c , 0x21604eae , 0xe8c9bb2b , 0x44cf32a6 , 0xd1503586 , 0x129624f3 ] )
+  , Mont::unsafe_make( [ 0xe2d85ac8 , 0x5dfaa975 , 0x1fcd8123 , 0x8ee1735c , 0xaabf6e08 , 0xc3ab5847 , 0x28acc241 , 0x09247991 ] )
+  , Mont::unsafe_make( [ 0xfa1bb40d , 0xc1a504a3 , 0x243b1957 , 0x7718c56f , 0xd235efa4 , 0x673eca4f , 0x1bd03148 , 0x213b86dd ] )
+  , Mont::unsafe_make( [ 0x4133c367 , 0x7824605d , 0xe04833fe , 0x2681593b , 0xb1918dc3 , 0x1577445d , 0xd49efe48 , 0x292c60fb ] )
+  , Mont::unsafe_make( [ 0x502c255b , 0xa0a44eef , 0xc968ef7a , 0x86861504 , 0x32175705 , 0x3fae3bbd , 0xedfcd179 , 0x271b420d ] )
+  , Mont::unsafe_make( [ 0x738283ec , 0xb961e465 , 0xd2885c3a , 0xae6a0081 , 0x95d3a8bc , 0x99ea0af7 , 0x1568ec02 , 0x1a0bbf66 ] )
+  , Mont::unsafe_make( [ 0xb4b13c62 , 0x47c7e578 , 0x567361f0 , 0xf80635a9 , 0xd7d4e82b , 0xd59a5bd9 , 0x8ff1fa6a , 0x2c255bd6 ] )
+  , Mont::unsafe_make( [ 0xe45e6074 , 0x01df7529 , 0x8a44cde7 , 0xa1c3c59b , 0x48243fb5 , 0x2672f9cb , 0x1d527e90 , 0x2ad938dc ] )
+  , Mont::unsafe_make( [ 0x28e9a5b6 , 0xd91986af , 0x37d82eac , 0x31a49884 , 0xa4393e2c , 0xaca062fd , 0xb86369b7 , 0x273218af ] )
+  , Mont::unsafe_make( [ 0x15ace613 , 0x5156412a , 0xe4c1fec9 , 0xbf65015b , 0x4fc1aa43 , 0x615e567c , 0xb6a3899b , 0x26d52cba ] )
+  , Mont::unsafe_make( [ 0x03a4a5ef , 0x274de6ef , 0x2e36aa98 , 0xfba61881 , 0xae032b77 , 0x2df76eaf , 0x3464eaae , 0x175e0b7c ] )
+  , Mont::unsafe_make( [ 0x4bd8a37e , 0x3496612a , 0xca0fb62d , 0xfe3b172a , 0xe5383424 , 0x573c661d , 0xc299ff8d , 0x0c9fb9ac ] )
+  , Mont::unsafe_make( [ 0x968a07ee , 0x23dff9cd , 0xd34895de , 0xb7c65f82 , 0x48a8dbf1 , 0xdccd0a35 , 0xee0bbaa6 , 0x28e6b51a ] )
+  , Mont::unsafe_make( [ 0xdc80b4b3 , 0x5bcf60a4 , 0xe5151eac , 0x7dafe117 , 0x56614170 , 0xdcdff261 , 0xd135eab4 , 0x2faf2e1d ] )
+  , Mont::unsafe_make( [ 0x387936fb , 0x21bfc9d6 , 0x79595f05 , 0x925b9e14 , 0x4118577e , 0x87817d24 , 0x144d2348 , 0x23cb16da ] )
+  , Mont::unsafe_make( [ 0xc5e39d4e , 0xb492c0ac , 0xf0ace00f , 0x9e555e57 , 0xe2195774 , 0x33c0d441 , 0xcd14b96b , 0x19d31e32 ] )
+  , Mont::unsafe_make( [ 0x9fe1d74f , 0xb6d1ab10 , 0x71e265cd , 0x76000a10 , 0xdb50f8d2 , 0x5ffb205d , 0x8ca2521e , 0x24657153 ] )
+  , Mont::unsafe_make( [ 0xa2dfe340 , 0x6beb6b30 , 0xcda8e6b3 , 0x326ce5b9 , 0x53b8e2e0 , 0x4315958f , 0x34742372 , 0x1ac2d2bb ] )
+  , Mont::unsafe_make( [ 0xb5c5a3aa , 0xfba5a415 , 0x5b9d5a49 , 0x3d504dc2 , 0x153009b7 , 0x8bd82e43 , 0xc197f3b6 , 0x00105557 ] )
+  , Mont::unsafe_make( [ 0x166c3993 , 0x37983324 , 0xe7b38821 , 0xcdc49c1f , 0x22efba6e , 0x021c1e6d , 0x77455bda , 0x05d6d1a7 ] )
+  , Mont::unsafe_make( [ 0x6de24344 , 0xf3d3a44e , 0x0b1bf447 , 0x51629ff5 , 0x09b8f832 , 0xf1d4ed77 , 0x0e746eb4 , 0x08e8d181 ] )
+  , Mont::unsafe_make( [ 0x43ff9ca7 , 0x248a6163 , 0x309c2226 , 0x23cb1b59 , 0x7449f068 , 0x4c4b28ce , 0xf13a789a , 0x0c7ea162 ] )
+  , Mont::unsafe_make( [ 0x61674d19 , 0xb0698c30 , 0xbb37855d , 0x0079ddc8 , 0xf8be77b4 , 0x6e4a2563 , 0x02776876 , 0x146f5049 ] )
+  , Mont::unsafe_make( [ 0xdfcedd97 , 0x9390c09a , 0x107fcacd , 0x08af2d6b , 0x7fdb6eb1 , 0x87bf01ca , 0x7fba174c , 0x1e901714 ] )
+  , Mont::unsafe_make( [ 0x94cef626 , 0x9be7e256 , 0xb3b2fc67 , 0x785fe15f , 0xd9457832 , 0xf24b527d , 0xa6e9b0eb , 0x1c0a878a ] )
+  , Mont::unsafe_make( [ 0x9c9cb9a4 , 0xbcc0380b , 0x85b4f3a2 , 0x875d971c , 0x6e4db0b9 , 0x934ab4d0 , 0x636ca2ef , 0x2fc4f26e ] )
+  , Mont::unsafe_make( [ 0x31109960 , 0x5e17b8d8 , 0x0c814547 , 0x6de2dd7d , 0x6ddf0e1d , 0x64de47ca , 0x40af7a36 , 0x00138470 ] )
+  , Mont::unsafe_make( [ 0x4ee9f9f5 , 0xbab2271a , 0x4ec89b90 , 0xde728e6e , 0xdcbacfeb , 0x1098ff58 , 0x143b2c4d , 0x0709f432 ] )
+  , Mont::unsafe_make( [ 0x724e7955 , 0x2f177fda , 0xa1bfc20d , 0x74832a33 , 0x401206f1 , 0x73a4cfcc , 0x5c8e06ca , 0x07782b3f ] )
+  , Mont::unsafe_make( [ 0x93ca1deb , 0x0d011b06 , 0x520929b0 , 0x867cf3bd , 0xbabb0179 , 0x3f0ad9df , 0x4ea4540d , 0x2cb203b1 ] )
+  , Mont::unsafe_make( [ 0x0cc6a5cb , 0x24c57b76 , 0x71f6f8b0 , 0x2cdd92bf , 0x383cbac4 , 0xada1e638 , 0xffd8b447 , 0x113a39d2 ] )
+  , Mont::unsafe_make( [ 0x20c8ad71 , 0x46d8d845 , 0x0c2fe582 , 0x6e5f29bb , 0xb226fbf3 , 0x8d561d26 , 0xe280b69b , 0x05e98b88 ] )
+  , Mont::unsafe_make( [ 0xdace7964 , 0x7af36442 , 0x39d6b713 , 0x051ab874 , 0xb1b706ed , 0x07b24e18 , 0x117bf46a , 0x27bd2f53 ] )
+  , Mont::unsafe_make( [ 0x050b81d6 , 0xe9ef8e2b , 0x6ad2e3f7 , 0x1f7d9f5f , 0x978798a6 , 0xf5f1b4db , 0xccb9529f , 0x2b7cf1e9 ] )
+  , Mont::unsafe_make( [ 0x2a917c57 , 0x0e436d22 , 0x49f78276 , 0x0ae28947 , 0x1a70bca5 , 0x294c6189 , 0x6535dc1e , 0x2cc61fe0 ] )
+  , Mont::unsafe_make( [ 0xf6d47e58 , 0x604af973 , 0xf8418ea0 , 0x192d8d83 , 0x74b01940 , 0xb82b346a , 0x6cb23648 , 0x1fb70f2b ] )
+  , Mont::unsafe_make( [ 0xf2dec81b , 0x35d8c290 , 0x13f770aa , 0x5bf888cd , 0x0fa1610a , 0xacbe5f9e , 0xe0cc0ad4 , 0x1475519a ] )
+  , Mont::unsafe_make( [ 0x8ac25fd9 , 0x114410af , 0x969def4d , 0xa1a16756 , 0xe2739e4d , 0x549e7ce9 , 0x66130fc3 , 0x259618eb ] )
+  , Mont::unsafe_make( [ 0x76568a36 , 0x738487a3 , 0x4486bdcb , 0x24d80650 , 0xf0fe1413 , 0xe4f0482d , 0x7483608c , 0x270c919e ] )
+  , Mont::unsafe_make( [ 0xc19178cb , 0x7ccfca0d , 0xce1e5966 , 0x5ce25717 , 0xc923d69c , 0x6e1dd63c , 0xfdd36dd1 , 0x0a7651a3 ] )
+  , Mont::unsafe_make( [ 0x570b1a7e , 0x8be8cee6 , 0x5de213ca , 0x524f163f , 0x269b2d05 , 0x49234c10 , 0x93dfa498 , 0x0ecceff1 ] )
+  , Mont::unsafe_make( [ 0xfe2fcd84 , 0x81a4077c , 0x866da7ab , 0x1adc5185 , 0xf98a4d75 , 0x291719b1 , 0x135eb439 , 0x040dda6e ] )
+  , Mont::unsafe_make( [ 0xcf8d9812 , 0x9d08077c , 0xa840e5f0 , 0xda03d5e1 , 0xc2deeba6 , 0xc9ab2a9d , 0xb9ad0c47 , 0x2cded271 ] )
+  , Mont::unsafe_make( [ 0xa3140c01 , 0x3bc67680 , 0xdc482b34 , 0x3fba50e9 , 0xa89b2b72 , 0xba7602a4 , 0x5bfff4c9 , 0x2282fedc ] )
+  , Mont::unsafe_make( [ 0x68d577bc , 0x25cd22dd , 0x168e6b82 , 0x430eaaa6 , 0x6567a2af , 0xb830c166 , 0xa99a3100 , 0x2e08d566 ] )
+  , Mont::unsafe_make( [ 0xd05abc01 , 0xb3057a52 , 0xd8967340 , 0x7ddf9366 , 0xfaecbd5d , 0x2b6b261a , 0x627e4ec1 , 0x1ff8aef4 ] )
+  , Mont::unsafe_make( [ 0x6f0c1f81 , 0x58a1f680 , 0x1aaec4fc , 0xa4f70c6b , 0xe1a94376 , 0x70896b7e , 0x4090079f , 0x2d195cbc ] )
+  , Mont::unsafe_make( [ 0xf98a64c6 , 0x4919ab11 , 0x271721ad , 0xce52acf7 , 0x7f00fa81 , 0x4697d5b2 , 0x68d0b919 , 0x0e76bbd8 ] )
+  , Mont::unsafe_make( [ 0xe4577d6e , 0x95868439 , 0xa5d46339 , 0x884334bc , 0x42236e82 , 0x47293021 , 0xa249bff8 , 0x236eb171 ] )
+  , Mont::unsafe_make( [ 0x500689b8 , 0x6b4a2d6e , 0x8315086b , 0x3d12720c , 0xb831368d , 0x3283b6d3 , 0xc2919d1c , 0x166c302b ] )
+  , Mont::unsafe_make( [ 0xe8075a32 , 0x164ff34e , 0xf1ba085e , 0x396a1c5d , 0x58f13833 , 0xb12aab05 , 0x0d2aa855 , 0x1c20d314 ] )
+  , Mont::unsafe_make( [ 0x48f9f1f5 , 0x4d4b745b , 0xf122e1c6 , 0xece5213c , 0xd5ce1548 , 0xed694268 , 0xd5aa69db , 0x07560a0f ] )
+  , Mont::unsafe_make( [ 0xa55addbe , 0xf0af7daa , 0xd621f9ff , 0x1097c7dd , 0xd024f91c , 0x0cbc9edd , 0xa7f0c4ca , 0x17b11628 ] )
+  , Mont::unsafe_make( [ 0x5e5a50e1 , 0xe155897e , 0xed0af014 , 0xaa83a9bd , 0xa89caff7 , 0x4b3db5b6 , 0xa7a3bb92 , 0x08b02b8e ] )
+  , Mont::unsafe_make( [ 0xf9904919 , 0xa4810330 , 0xa5222c3f , 0x2503c5dd , 0xbfa44240 , 0x0437a66b , 0xe536ecee , 0x1f6cc901 ] )
+  , Mont::unsafe_make( [ 0xd1097e39 , 0x4702dc0b , 0x408091a4 , 0xec55360d , 0x760d124f , 0x9ef664d8 , 0x7b689241 , 0x0ecd446d ] )
+  , Mont::unsafe_make( [ 0x27b6fbf5 , 0x500ba6aa , 0xf22f5fe2 , 0xdc0b913f , 0x3d82e8f2 , 0x01efe3fe , 0xa323b087 , 0x19ded910 ] )
+  , Mont::unsafe_make( [ 0x06aef5a3 , 0x61890872 , 0xcd24f320 , 0x50d7d0c3 , 0x1417ea15 , 0x82f588a5 , 0x4b8f28ce , 0x04da26db ] )
+  , Mont::unsafe_make( [ 0xd9d6540f , 0x76b97f6b , 0x2c9c091e , 0x8dbd17a1 , 0x9f9a95a9 , 0x58b630ee , 0xb2778580 , 0x1335e488 ] )
+  , Mont::unsafe_make( [ 0x4e095594 , 0xcad4f40d , 0x76cda2eb , 0xe2410859 , 0x1e34ba5a , 0x25f0c0b6 , 0xc7018e64 , 0x0b2ab12e ] )
+  , Mont::unsafe_make( [ 0xb15ae980 , 0x01bba971 , 0x61232a7d , 0x256259b7 , 0xa7658b3d , 0x2afd79fc , 0xe72d8cac , 0x06c229b5 ] )
+  , Mont::unsafe_make( [ 0x37fc0f56 , 0x9a5e5272 , 0x2700407f , 0xa14a1750 , 0x74b1bbcf , 0x763e89c1 , 0x364f5e0f , 0x0a592b08 ] )
+  , Mont::unsafe_make( [ 0xfa605725 , 0xbc86a425 , 0xdfc84535 , 0x9a27b184 , 0xe3d947ca , 0x544a140d , 0x1e55bebd , 0x12218299 ] )
+  , Mont::unsafe_make( [ 0x5bdfaf80 , 0x8b3b0016 , 0xc9202043 , 0xc1a4da2b , 0x69122404 , 0x41971343 , 0x972cd0b4 , 0x0ce7384b ] )
+  , Mont::unsafe_make( [ 0xdbb56d80 , 0xca89514d , 0x4f0c14c0 , 0xad5eb263 , 0xc34c402a , 0x07b49834 , 0x6d49cc3f , 0x04d44c11 ] )
+  , Mont::unsafe_make( [ 0x50b76fd9 , 0x14cd66b4 , 0xce98a182 , 0x18575b8c , 0x28bd9d55 , 0x6114ea71 , 0x1d292b01 , 0x20f6e81c ] )
+  , Mont::unsafe_make( [ 0x229326f0 , 0xf8a2b0f0 , 0x6ca5f2df , 0x597e9c9c , 0x55784aba , 0x854cf261 , 0x59906317 , 0x286034d7 ] )
+  , Mont::unsafe_make( [ 0x7fbd15b9 , 0x5391ced5 , 0xf6e956ec , 0xace40291 , 0xb359a0f9 , 0x23028d1c , 0xfc044583 , 0x24d03847 ] )
+  , Mont::unsafe_make( [ 0x9e648478 , 0x7f5537f9 , 0x0e1ce6e5 , 0x40d235f2 , 0x61b4617e , 0x3eb0a528 , 0x6821c408 , 0x00dd9e03 ] )
+  , Mont::unsafe_make( [ 0x00a03836 , 0x3dab760f , 0xa5c699d0 , 0x4bf7fd52 , 0x42551687 , 0xa5c1c726 , 0x026d7a8b , 0x1f42a8f0 ] )
+  , Mont::unsafe_make( [ 0xaf5898ef , 0x2d638937 , 0xbb1ba05c , 0xf974f26f , 0x9730bcee , 0x1a72996e , 0x66ba78eb , 0x1bad83cc ] )
+  , Mont::unsafe_make( [ 0xe4c69456 , 0x59e99974 , 0x26f73849 , 0xfb5885ec , 0x52b1539e , 0xcc15893d , 0x923e6942 , 0x1a005ff1 ] )
+  , Mont::unsafe_make( [ 0xcc4b7727 , 0x778e6d8e , 0x657238bb , 0xdf53b178 , 0x080c35cc , 0xe8255f93 , 0xb2c1454b , 0x13b5b4be ] )
+  , Mont::unsafe_make( [ 0xcf10fd79 , 0x67564a77 , 0x91d00f2f , 0x3ca68f57 , 0xc90b27fc , 0x3f45fe0c , 0xf040bb6d , 0x13c25842 ] )
+  , Mont::unsafe_make( [ 0x6d3a741b , 0x89d0bab8 , 0x4f35e0f0 , 0x11690745 , 0x1981fcc8 , 0x1258cf32 , 0x6b3a50a8 , 0x122980cf ] )
+  , Mont::unsafe_make( [ 0x6d344190 , 0x4d3b9e4e , 0xb0c67864 , 0xc7f769d6 , 0x9bb527a7 , 0xd1fb2564 , 0x61151e1d , 0x1761985b ] )
+  , Mont::unsafe_make( [ 0xe7c6deb1 , 0x74b2e18f , 0x98f0c2a1 , 0x86f05ca8 , 0x445ee426 , 0x2e3d2da1 , 0x941ced78 , 0x141f12be ] )
+  , Mont::unsafe_make( [ 0x9ec9e287 , 0x2c711806 , 0x3f1eb284 , 0x5a8edfa4 , 0xe1b5d377 , 0xeede2135 , 0x231a586a , 0x15782ab9 ] )
+  , Mont::unsafe_make( [ 0x1a95e980 , 0x720bd122 , 0xa1fee43f , 0x075b145f , 0x410a0e82 , 0x2deaf4ab , 0xa2e610f4 , 0x12cda228 ] )
+  , Mont::unsafe_make( [ 0x240c37a2 , 0x90e74f41 , 0xec1b7e5f , 0xb5ca8d3d , 0x5ff36f65 , 0x8e4c3536 , 0x4cd4a346 , 0x0c06f3e7 ] )
+  , Mont::unsafe_make( [ 0x0e0454ba , 0xc17af040 , 0x6aaec7c7 , 0x8db90423 , 0x1d8bb17a , 0x09bd11ab , 0x7ac75d59 , 0x1917644f ] )
+  , Mont::unsafe_make( [ 0x846ca7fc , 0xbbe89b6b , 0x8fb8e766 , 0xd02d2f0e , 0xbfba7730 , 0x51bcc1f6 , 0x0b9c727c , 0x1b733e61 ] )
+  , Mont::unsafe_make( [ 0x1dabc50f , 0x762ae0b7 , 0xabfa33ec , 0x9b40b0b1 , 0x49860c68 , 0xfb8c5b50 , 0x07c9622a , 0x26c4f1e1 ] )
+  , Mont::unsafe_make( [ 0x4e4b3325 , 0x1e4250ae , 0x78b9b7c7 , 0x677a696b , 0xd68f6779 , 0xb0fb625e , 0x74c1faa1 , 0x01ae9d99 ] )
+  , Mont::unsafe_make( [ 0x1b5f16e6 , 0x5761173c , 0x86481178 , 0x40525d2d , 0xa620910b , 0x1c5acfc9 , 0x50a70a66 , 0x25d612ab ] )
+  , Mont::unsafe_make( [ 0x38682fef , 0x4fb2cffb , 0x3cc5c9a2 , 0x7ffbd62d , 0xbecd8f52 , 0xa0837e6d , 0xe69e9eca , 0x00c39c21 ] )
+  , Mont::unsafe_make( [ 0xc29d70a2 , 0xbc76023e , 0x34871201 , 0x30f5212e , 0xcb7d4471 , 0xe4991e2f , 0xf27ff37b , 0x0f9350b0 ] )
+  , Mont::unsafe_make( [ 0x8d6356c5 , 0x467fb515 , 0xc786b8c9 , 0x72bd35cf , 0x6d1cfd09 , 0xe3230578 , 0x5f3af454 , 0x201a4575 ] )
+  , Mont::unsafe_make( [ 0x957f3631 , 0x63ff709a , 0x0cf3f070 , 0x3b9813ca , 0x6ee220e1 , 0x26c573fc , 0xcdbd8235 , 0x02580f16 ] )
+  , Mont::unsafe_make( [ 0x05dea0ad , 0xb61db981 , 0x089c4480 , 0x765186ac , 0x153b9608 , 0xac1750ef , 0x52fbe1d3 , 0x2862f6a8 ] )
+  , Mont::unsafe_make( [ 0x3de382fd , 0x2175fe9e , 0xb7a4c1c9 , 0xddb1beac , 0x7ad2e623 , 0x23a20474 , 0x76127f3b , 0x282794a0 ] )
+  , Mont::unsafe_make( [ 0x782892b0 , 0x4fec4408 , 0xb81513e4 , 0x745577f3 , 0x30d65f10 , 0x7f951aff , 0xbe1657b0 , 0x2049bfed ] )
+  , Mont::unsafe_make( [ 0x6ea26b9d , 0x71002eb6 , 0xb104dbc0 , 0x4a739ff8 , 0x8523e7ae , 0x5a073ffa , 0x16508798 , 0x112fb57e ] )
+  , Mont::unsafe_make( [ 0x65e45c02 , 0x645fa3cf , 0xc1ee1db1 , 0xf7f35658 , 0xc885a0ad , 0xa7522408 , 0xb151c920 , 0x07fa4e66 ] )
+  , Mont::unsafe_make( [ 0xf188629e , 0xfc300128 , 0x264db1ed , 0x72bd6446 , 0x0d082d85 , 0x8cc5dc6f , 0x6d83dddd , 0x158d598b ] )
+  , Mont::unsafe_make( [ 0x3f2fe7c2 , 0x097eda81 , 0x0f1ee1ff , 0x4455ed31 , 0xaea6591e , 0x697818c0 , 0x71832377 , 0x0b10927b ] )
+  , Mont::unsafe_make( [ 0xdd27129a , 0x32e76cd2 , 0xf449b111 , 0xac5687c3 , 0x0a87798b , 0x7934af43 , 0x28254858 , 0x2361acfb ] )
+  , Mont::unsafe_make( [ 0x2fcf0ba0 , 0x54de693e , 0x7f85b1e7 , 0xf23008b7 , 0x0980c53e , 0xd7915e4b , 0x9852f9f3 , 0x1f34b45b ] )
+  , Mont::unsafe_make( [ 0x69eb7ff0 , 0xf59da8f3 , 0x4872c058 , 0xefd401b1 , 0x2f1ee962 , 0x2d4de919 , 0x9856a2e0 , 0x26e19dce ] )
+  , Mont::unsafe_make( [ 0x3b5180e6 , 0xa9389c3c , 0x9a58ec7c , 0xfbf998ef , 0xd1b67ea8 , 0x4adc31c4 , 0x5aa853c9 , 0x147dc82a ] )
+  , Mont::unsafe_make( [ 0x627162c1 , 0x20921804 , 0x7d7a28d8 , 0x494522fe , 0xa218dc62 , 0x9f0fd235 , 0x248399af , 0x10d83324 ] )
+  , Mont::unsafe_make( [ 0x37dbb344 , 0x1e3df9ba , 0xdb596c49 , 0x6eb82c5a , 0x18893669 , 0x877009a8 , 0xfc02914c , 0x2754ac26 ] )
+  , Mont::unsafe_make( [ 0xc807e6be , 0x1f9180da , 0xad51c576 , 0x98c89916 , 0x6fd06766 , 0x1830358f , 0x9589797a , 0x15611576 ] )
+  , Mont::unsafe_make( [ 0x197e48a8 , 0x355d5d10 , 0xfdc38cdc , 0x84ab44b1 , 0xe2030a6e , 0x4a41e4a3 , 0xa060cc9b , 0x041a0354 ] )
+  , Mont::unsafe_make( [ 0x5b639101 , 0x1ff47e18 , 0xb1262f05 , 0x0cdc7473 , 0x9a9d7ea5 , 0xf018bd99 , 0x96bdcd5f , 0x13500848 ] )
+  , Mont::unsafe_make( [ 0x0ae77871 , 0xa29c0155 , 0x05364d9d , 0xa2ada7c5 , 0xb213cc4e , 0xaf55b2e7 , 0x9f50c75c , 0x06c692f8 ] )
+  , Mont::unsafe_make( [ 0x3ff35d2e , 0x6632f776 , 0x00543cc9 , 0xdd56998c , 0xd1ffd695 , 0x133d3b19 , 0x6b219d27 , 0x0c0015c4 ] )
+  , Mont::unsafe_make( [ 0xbeeb8664 , 0xc997e0f2 , 0xa9f2e823 , 0x5c40c5e8 , 0x5446ee0f , 0x7db88096 , 0x6120c0ff , 0x01a4dd0b ] )
+  , Mont::unsafe_make( [ 0x85a842dc , 0xe639de5d , 0xc3787143 , 0x4ed74008 , 0x8ac36ebc , 0x61e73b60 , 0xcefe3c19 , 0x2dddcd94 ] )
+  , Mont::unsafe_make( [ 0xcd7afbff , 0xd25b13c6 , 0x367d1bc4 , 0xda8fcecc , 0xf9c61ef2 , 0x8c5524a9 , 0x46c47f16 , 0x28720817 ] )
+  , Mont::unsafe_make( [ 0x167564fa , 0xee9f2d0d , 0x95bd7cee , 0x37fa61e8 , 0x60afcce8 , 0x1c7312d6 , 0x07da8bb3 , 0x2803cc23 ] )
+  , Mont::unsafe_make( [ 0x1e05241b , 0xeb6f6fb7 , 0xbdfebc65 , 0xf72afa15 , 0xe6132611 , 0x69ab79bb , 0xfc89abca , 0x0b195537 ] )
+  , Mont::unsafe_make( [ 0x5957ef33 , 0x64dea5ab , 0x8b4f819f , 0x0c0884b9 , 0x30786641 , 0xb27a7a2a , 0xd3b4a56a , 0x21043596 ] )
+  , Mont::unsafe_make( [ 0xdced7bfc , 0x62f9ebd3 , 0xde17f782 , 0x4d424ae6 , 0x2b5b7a3d , 0x8ed3663c , 0x1577d1ea , 0x1b228094 ] )
+  , Mont::unsafe_make( [ 0x83dbf961 , 0x331dea80 , 0x0b568a60 , 0x204f544e , 0x3948af27 , 0x158daf6d , 0xfc1162d2 , 0x288bd48e ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// circomlib's optimization whatever
+pub const CONST_S: [Mont; 2244] = 
+  [ Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x312d673b , 0xcbb29426 , 0x4e15c098 , 0xd8cb19a9 , 0x8e06d395 , 0x33924f35 , 0x745933ec , 0x0af4fbf7 ] )
+  , Mont::unsafe_make( [ 0x9a0292a0 , 0xd0dd9075 , 0xb4db63ce , 0xa5651584 , 0xdb37764e , 0xf0b6e267 , 0x062609da , 0x01d1de6c ] )
+  , Mont::unsafe_make( [ 0xaf431d1f , 0x542c948b , 0x44e632d4 , 0x669a2479 , 0xaebf406c , 0x042205ca , 0xaf725ee1 , 0x11bf775f ] )
+  , Mont::unsafe_make( [ 0x1ef4fdcf , 0x1d5694d4 , 0x8522392a , 0x716ede6e , 0xf49085e2 , 0xf98402ad , 0xb5bde659 , 0x0399c155 ] )
+  , Mont::unsafe_make( [ 0x192078c7 , 0xad2e4307 , 0x286e9cd7 , 0x2bd1a15e , 0x6859eb53 , 0xf2d55935 , 0xf216edfe , 0x07d134cb ] )
+  , Mont::unsafe_make( [ 0xe78d9d91 , 0x1546e33b , 0xe9de46b4 , 0xcdcd0d9e , 0x053346a3 , 0x9362a30d , 0xa9bcfd1b , 0x063d08e5 ] )
+  , Mont::unsafe_make( [ 0xe873bc4a , 0x34dd5c1c , 0x738701ea , 0xad87a253 , 0x042d8a24 , 0x56a935de , 0x716c72ff , 0x14c79671 ] )
+  , Mont::unsafe_make( [ 0x259c5e75 , 0x0c0c782f , 0xd461b766 , 0x3819ef88 , 0x8ce24375 , 0x75591d23 , 0x406b6220 , 0x246010d2 ] )
+  , Mont::unsafe_make( [ 0x03bdce78 , 0x5dfe1c9d , 0x277b3d58 , 0x603ea303 , 0xb42fa745 , 0x698031fd , 0x1c375294 , 0x091d0f68 ] )
+  , Mont::unsafe_make( [ 0xb5427dc4 , 0x81154474 , 0xe71aaa6e , 0xf9ab1c6b , 0x7beaede3 , 0x09f849d7 , 0xaaa4595a , 0x2a2e3359 ] )
+  , Mont::unsafe_make( [ 0x24be082e , 0x86b6450e , 0xc426e0e7 , 0xb9aa82a0 , 0x65386dba , 0x91e95b79 , 0x0a6bf78d , 0x1995afb3 ] )
+  , Mont::unsafe_make( [ 0x63e31959 , 0xebf521b8 , 0x33fc259e , 0xb9d7d90c , 0x24908721 , 0xc4acb5ce , 0xed931ead , 0x222734a1 ] )
+  , Mont::unsafe_make( [ 0x64ad633c , 0xa023c48f , 0xe9c3f594 , 0x01db8de8 , 0xce7f9148 , 0x8f0ebd02 , 0xb13e97bb , 0x293ef949 ] )
+  , Mont::unsafe_make( [ 0x830e78e0 , 0xd23b8a21 , 0xeed3737a , 0x320986ab , 0xf72a0713 , 0x73827d15 , 0xc8663dfa , 0x2addcbce ] )
+  , Mont::unsafe_make( [ 0x3363697d , 0x8606658f , 0x2d5c3c17 , 0x4a06cac0 , 0x27ad7181 , 0xfd138c54 , 0x75da846c , 0x1ad49d19 ] )
+  , Mont::unsafe_make( [ 0xcbee2377 , 0xd140eda7 , 0x3e19cd1f , 0x3235cdf7 , 0x48fa9988 , 0x884b9fce , 0xaaf79dc0 , 0x07491128 ] )
+  , Mont::unsafe_make( [ 0x297ef66c , 0x26f6b970 , 0x7b6039f9 , 0x0d4a4812 , 0x1c1c6875 , 0x09368a0d , 0x4170364d , 0x280eeb01 ] )
+  , Mont::unsafe_make( [ 0xaf6218de , 0xe833d7bc , 0x0e19b8ca , 0x2c5d5512 , 0x5644634f , 0x6be7d7e1 , 0x696a708c , 0x113f00cf ] )
+  , Mont::unsafe_make( [ 0x3fa51563 , 0xc8090627 , 0x241c3152 , 0x36fa3e5d , 0x918b4b57 , 0xb6247401 , 0x2717a87b , 0x24426cc1 ] )
+  , Mont::unsafe_make( [ 0xfe484252 , 0x50500526 , 0x52c732b8 , 0x1599e042 , 0x4ffcbcfe , 0x7d7bf33e , 0xd52ee7ca , 0x2e942b70 ] )
+  , Mont::unsafe_make( [ 0xdd37aa89 , 0x89b4dc8c , 0x0f280a9d , 0x78f898af , 0x61c0c023 , 0x1f947ade , 0x209d4b29 , 0x2f9cc4f2 ] )
+  , Mont::unsafe_make( [ 0x54141c26 , 0x90f0e2ec , 0x6775d26a , 0xae3ff2d1 , 0x9cc36cc6 , 0x642df453 , 0x0e79c1f1 , 0x1f70a814 ] )
+  , Mont::unsafe_make( [ 0xf462a10c , 0xe1d4ef66 , 0x9613f66f , 0x42bf9e15 , 0xf5b25e62 , 0x64214ad8 , 0xf914daee , 0x0cc42f15 ] )
+  , Mont::unsafe_make( [ 0x91bfe9dd , 0x64ab8a1e , 0x1d36d9c4 , 0x0c1631ad , 0x84985b9c , 0x7fc1867d , 0xb7bcdcf8 , 0x15204d39 ] )
+  , Mont::unsafe_make( [ 0x8c70b598 , 0xbcafd045 , 0x21f73629 , 0x2d119c00 , 0x1388d1d1 , 0x9cab88a1 , 0x4237532a , 0x2240b201 ] )
+  , Mont::unsafe_make( [ 0x085ca649 , 0x062d86fe , 0x7a110afe , 0x8a284ad2 , 0x34ff9cfd , 0xdab91490 , 0x482f0f91 , 0x19ddce43 ] )
+  , Mont::unsafe_make( [ 0x5670d160 , 0xdd66e992 , 0x6b44928e , 0xf49512ac , 0xd9c40476 , 0xc77524eb , 0x2e185a1f , 0x0c4947a8 ] )
+  , Mont::unsafe_make( [ 0x0388be2b , 0x01d43686 , 0x84f979b7 , 0x6aed5f31 , 0x96db4898 , 0xe5467f35 , 0x08b54567 , 0x1d7d0015 ] )
+  , Mont::unsafe_make( [ 0x4c317df5 , 0x7c8c9010 , 0x67cf8225 , 0xa3ecf744 , 0x0241c111 , 0x974c2bd0 , 0xbb554ff5 , 0x042420a5 ] )
+  , Mont::unsafe_make( [ 0x06f8b38e , 0xc9700685 , 0x2860d5c9 , 0x9218e658 , 0xb82b44f3 , 0x68ffc514 , 0x20edee8a , 0x2541f809 ] )
+  , Mont::unsafe_make( [ 0xfecd5d9d , 0x9ace4ccd , 0x74f931fb , 0x431d5c45 , 0x7205ca5e , 0xb68e8f2e , 0x648b445f , 0x272207c1 ] )
+  , Mont::unsafe_make( [ 0xc018a694 , 0x1c281abc , 0x94f7cb7a , 0x05952a55 , 0x95264f76 , 0x4285bbc3 , 0x1dc4df63 , 0x06ad8ec2 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x92d4e322 , 0x47631e14 , 0x744fe97e , 0x47b33731 , 0xa767dedc , 0x13ae1825 , 0x37c3746c , 0x1ecf2a23 ] )
+  , Mont::unsafe_make( [ 0xb56e6180 , 0x52dbd16c , 0xc70bb618 , 0xfed4a572 , 0x29d47021 , 0x11f26dc0 , 0xbb8c0725 , 0x30273b8d ] )
+  , Mont::unsafe_make( [ 0x31b54adc , 0x0d28655e , 0x3c5d747c , 0x8f4f609b , 0x04869a03 , 0x9ff60cac , 0xe3d15c67 , 0x06ac7e98 ] )
+  , Mont::unsafe_make( [ 0xf9dbe8f8 , 0xa426eb93 , 0x3453b2a3 , 0xe84cd74e , 0xa4a24eb9 , 0x632e6143 , 0xe609037b , 0x0af30dca ] )
+  , Mont::unsafe_make( [ 0x28be0f5b , 0xe2069ede , 0x8fa05f0d , 0xe173f458 , 0xd0392164 , 0x54f523eb , 0xd63de22c , 0x1a34a8fa ] )
+  , Mont::unsafe_make( [ 0xbe2916a9 , 0xa44b1940 , 0xb69f70d5 , 0x8294eeea , 0xd531b08e , 0xec311ec9 , 0xb23d0643 , 0x07d65e8e ] )
+  , Mont::unsafe_make( [ 0x768d83f0 , 0x20504e64 , 0x4b39767f , 0x64ac962e , 0x72580cef , 0xd284c46d , 0x6db5031c , 0x0f3972dc ] )
+  , Mont::unsafe_make( [ 0xe93d8bdd , 0xc7b4d375 , 0x79e5b30d , 0x10c85a09 , 0x27db9396 , 0xe798ddc1 , 0x1030e951 , 0x0294bc0e ] )
+  , Mont::unsafe_make( [ 0xc031e325 , 0x5a1c1126 , 0xbea4304c , 0x80588e03 , 0xb09bef70 , 0x1cb45d98 , 0x2e849d75 , 0x20bd5c0c ] )
+  , Mont::unsafe_make( [ 0x575c1b3d , 0xd9bebc45 , 0x642b3831 , 0x0a74d909 , 0xad2a27af , 0x789910de , 0x46ee0294 , 0x1aecb247 ] )
+  , Mont::unsafe_make( [ 0x4eb31e9e , 0x7bcfa3dd , 0x80b284a4 , 0xf1825e93 , 0xdcceb776 , 0x9747f645 , 0xc1e68a6e , 0x1c0bf3b9 ] )
+  , Mont::unsafe_make( [ 0xd6607c37 , 0xde5fb7dd , 0xa6086599 , 0xbb3f8488 , 0x6220a5bf , 0xb37a2c38 , 0x634e82d1 , 0x283a59d2 ] )
+  , Mont::unsafe_make( [ 0xa1979b81 , 0x35b78276 , 0x16162228 , 0xe28706ae , 0x1ee0aa25 , 0x87edc769 , 0x937eb813 , 0x22764776 ] )
+  , Mont::unsafe_make( [ 0x14eadb50 , 0xa648c22d , 0xfa58b37d , 0x0c8b2ad1 , 0x5ffaa9cd , 0x0c3a4926 , 0x0ac0720e , 0x16b39cf0 ] )
+  , Mont::unsafe_make( [ 0xe4ab8284 , 0x63f3f35d , 0xc6a7300e , 0xe98b8b9c , 0xfa54c330 , 0x9d88dd6e , 0xfc64c686 , 0x1923005b ] )
+  , Mont::unsafe_make( [ 0x3ee1deab , 0xfced1648 , 0x6ca2ed71 , 0x97d4d2bb , 0x01ffdc64 , 0xaf2d8b38 , 0x5a2e7cbe , 0x15652a5e ] )
+  , Mont::unsafe_make( [ 0x716faf56 , 0x93c85df0 , 0x242b6a30 , 0x73770684 , 0x5a06c39d , 0x57e6c0c3 , 0x101bf57d , 0x10b86fe0 ] )
+  , Mont::unsafe_make( [ 0x42db5fdb , 0x34e61fde , 0x797f4642 , 0x503db18b , 0x8435ca81 , 0x2b5085d2 , 0x570d9636 , 0x2f4a14fa ] )
+  , Mont::unsafe_make( [ 0xde7ffd13 , 0xd425e177 , 0xb217b6ab , 0xb7e9ea94 , 0x26e90efa , 0x588bca21 , 0x08c2ec0e , 0x088cd1f7 ] )
+  , Mont::unsafe_make( [ 0x4e8d310c , 0x805021e3 , 0x445367b9 , 0xfe95f68d , 0xc1ca1e63 , 0x51145d36 , 0xa459a263 , 0x0703704b ] )
+  , Mont::unsafe_make( [ 0xa560aee0 , 0xf4131872 , 0xa2fd55df , 0xf1e04a60 , 0xb6553de9 , 0x687b25ac , 0x1ad83c9b , 0x226f5a1f ] )
+  , Mont::unsafe_make( [ 0x2f00d594 , 0x16f0309c , 0x277cf3d9 , 0x59b93a4d , 0x99a5d77e , 0xd2f29174 , 0xce8b5d91 , 0x0c9579cb ] )
+  , Mont::unsafe_make( [ 0xb7ea5ab1 , 0x0cc0e295 , 0xe71c79d5 , 0x22db4d55 , 0x661395a4 , 0xd2958f65 , 0xd4dd1664 , 0x20574a78 ] )
+  , Mont::unsafe_make( [ 0xfb4986d2 , 0xcdbcb23f , 0x0e68b1d1 , 0x1832b1e4 , 0xc4f53585 , 0xe2789a57 , 0x189eb512 , 0x11707770 ] )
+  , Mont::unsafe_make( [ 0x19656b75 , 0x85fdb0f0 , 0xc61f6540 , 0x95f3bf38 , 0x713eb6eb , 0x2c87625f , 0x4f9b643f , 0x2e460d19 ] )
+  , Mont::unsafe_make( [ 0x9938041a , 0x969dacdb , 0x6fa92038 , 0xc23607d1 , 0xb191588d , 0xb976d90e , 0x37c77eb5 , 0x09cc8ca4 ] )
+  , Mont::unsafe_make( [ 0xa42ccbc9 , 0x493ffd98 , 0x16f2d251 , 0x2719ef94 , 0xe006c3c1 , 0xe90ef121 , 0xa5211f5a , 0x2b5f7d63 ] )
+  , Mont::unsafe_make( [ 0x9ea21c26 , 0x654b611f , 0x68b3b44f , 0xd0945935 , 0x956b9ed4 , 0xa7a463c9 , 0x60fa42d9 , 0x1263bfb5 ] )
+  , Mont::unsafe_make( [ 0x473fa057 , 0x044c3108 , 0xb15cd430 , 0xb6dddcb7 , 0xdbbddd1e , 0x0fe3ff53 , 0x73d2882f , 0x2b89537e ] )
+  , Mont::unsafe_make( [ 0x1573c58b , 0x8f90689c , 0x320b2ea7 , 0x213b53fb , 0x3e5f34ab , 0xa1958bc0 , 0x10310b67 , 0x033e4cdf ] )
+  , Mont::unsafe_make( [ 0xbdb3a6dc , 0xbf1ed3ba , 0x15928dbe , 0xb449a8f2 , 0x10389efb , 0x15876b53 , 0x383f60f4 , 0x0798953c ] )
+  , Mont::unsafe_make( [ 0x0e2aa39c , 0x85fd93f1 , 0x0bb6721b , 0x4ee04b48 , 0x7f26b517 , 0xa9a8d6a8 , 0x9b0df855 , 0x10cf9f4d ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x41e2d718 , 0xce01d9c3 , 0x0fc7111f , 0x604902db , 0x4b52e14e , 0xc93e2965 , 0x2621be33 , 0x0fbf8261 ] )
+  , Mont::unsafe_make( [ 0x30852854 , 0x824ce85e , 0xc08c7f24 , 0x0d1b6a83 , 0x685ee947 , 0xe8fa822a , 0xdf8367b5 , 0x03482f57 ] )
+  , Mont::unsafe_make( [ 0x2014233a , 0x24ffa2c4 , 0x07c27f7f , 0xad062089 , 0x2f45c809 , 0x1f8fcbd1 , 0x29561e0a , 0x07f3a20e ] )
+  , Mont::unsafe_make( [ 0x57763f91 , 0xd7befd3f , 0x27c1298c , 0x5be7561b , 0xee2a52a5 , 0x9ef52b04 , 0x80d51d1a , 0x0f650ddf ] )
+  , Mont::unsafe_make( [ 0xe659c5cf , 0xa37c1364 , 0xceec0990 , 0x7c91cd72 , 0xcc7a1a03 , 0x87762ef7 , 0x25031d75 , 0x1b392457 ] )
+  , Mont::unsafe_make( [ 0x0f9b12d9 , 0xe565e4df , 0xf643f74a , 0x9d4bc724 , 0x6bb4024c , 0x1d15b429 , 0xf154a679 , 0x20f72353 ] )
+  , Mont::unsafe_make( [ 0xc2fa6419 , 0xeb0da63b , 0x91699404 , 0x09edd720 , 0x6658acb4 , 0x04e6f4c7 , 0x78843c0b , 0x1aee9cd1 ] )
+  , Mont::unsafe_make( [ 0xd95d46db , 0x87a6380d , 0xe232bde4 , 0x4bb18bed , 0x3282d332 , 0x40691dd3 , 0x34efd341 , 0x12adf85c ] )
+  , Mont::unsafe_make( [ 0xcb879bae , 0x4c4bb1ea , 0xa324ab8a , 0x185de9fc , 0x9612603c , 0xcdbdd538 , 0xa4117013 , 0x085012d2 ] )
+  , Mont::unsafe_make( [ 0xae3f410f , 0xab65716a , 0x37028fd6 , 0x5a16c224 , 0xbe73060b , 0x61ced6d1 , 0xf0ff2d0d , 0x00e18774 ] )
+  , Mont::unsafe_make( [ 0x5782e012 , 0xf0eb5a12 , 0x434a6f04 , 0x025c6fd6 , 0xa80c70a7 , 0xd0470287 , 0xc45c4645 , 0x2320b0e5 ] )
+  , Mont::unsafe_make( [ 0xdb2009e4 , 0x8927a948 , 0xb56ef3f7 , 0x61ccb57c , 0xb0e1be04 , 0x0974c65f , 0x9d75b193 , 0x1f51f41d ] )
+  , Mont::unsafe_make( [ 0xed67095e , 0x3d1187ca , 0x4ef5bab9 , 0xaa69b7e8 , 0x5c88f72d , 0x424c22dc , 0x79811ff5 , 0x24172d6f ] )
+  , Mont::unsafe_make( [ 0x5f58297f , 0xefee31e9 , 0xb898b648 , 0x18693172 , 0x5256f7f8 , 0xaa8200c8 , 0x1182b22a , 0x28355a56 ] )
+  , Mont::unsafe_make( [ 0x8091a8ce , 0x431ab2d6 , 0x23204df9 , 0xab9bb476 , 0x1eb0bb13 , 0x2ace9258 , 0xd4d681a3 , 0x0e268d5a ] )
+  , Mont::unsafe_make( [ 0x0d005c40 , 0x2f09c501 , 0x065e63a1 , 0x82f790f6 , 0x5be478eb , 0x519b9eb0 , 0x76e45593 , 0x15b84905 ] )
+  , Mont::unsafe_make( [ 0xbd68b0fe , 0xadfcf3e5 , 0xcb4daf9a , 0xf7e19871 , 0x99cb77c8 , 0xed445ed8 , 0x5545cb0e , 0x2d5e3845 ] )
+  , Mont::unsafe_make( [ 0x77ab02a1 , 0x852af71d , 0xa4af067a , 0x5e8d7965 , 0xab241103 , 0xdcfc0f69 , 0x0e534afd , 0x2b7e15fb ] )
+  , Mont::unsafe_make( [ 0x56007d1d , 0x4e8d0595 , 0xa37aaf51 , 0xf4a5f847 , 0x51512c5e , 0xd5e06b2f , 0x66cf2e5d , 0x082ed162 ] )
+  , Mont::unsafe_make( [ 0xbf50270d , 0x8e13149c , 0xa71b8912 , 0x25530f86 , 0x541f076e , 0x07004d9b , 0x469be0d0 , 0x19755f44 ] )
+  , Mont::unsafe_make( [ 0x9af50da9 , 0x1943f5e3 , 0xd02b7bb6 , 0x7b72cde0 , 0xffdec57b , 0x551fc1f3 , 0xc7bd4017 , 0x1bcd40d9 ] )
+  , Mont::unsafe_make( [ 0x5566ede2 , 0x497f686e , 0xf6206a5f , 0xf9cd8b6d , 0x6f423aa0 , 0xad50425f , 0x5d8e4cce , 0x2239a39f ] )
+  , Mont::unsafe_make( [ 0x3bf1ee05 , 0xaaf99b24 , 0x9079c1e6 , 0x3b321cf0 , 0xaf919e5d , 0x225b8b1d , 0x274b22d5 , 0x13576e7f ] )
+  , Mont::unsafe_make( [ 0x43b4aea9 , 0x0ee65c18 , 0xb57f0772 , 0x34ea8f0d , 0xea5415f8 , 0xa4c73e0d , 0x145993bd , 0x231fe7c1 ] )
+  , Mont::unsafe_make( [ 0x9e742e45 , 0x1150663d , 0xba91e75f , 0xa98da379 , 0xf2e84e3a , 0xb28c84de , 0xd1d2a4f5 , 0x0d7a2a61 ] )
+  , Mont::unsafe_make( [ 0xc3ca222c , 0x955b2ac9 , 0xd7e5935a , 0xdcddf2db , 0xe110b367 , 0xdb279784 , 0xb40afabe , 0x22508dfd ] )
+  , Mont::unsafe_make( [ 0xbf25846d , 0xc0bef3e7 , 0xf5dbb691 , 0x1d5e386f , 0x1cff524b , 0x7ea07631 , 0x5d885e24 , 0x1fe7c265 ] )
+  , Mont::unsafe_make( [ 0x2fc45fc8 , 0xc34b0036 , 0x7f15fbf5 , 0x17e02740 , 0xf7d302e8 , 0xd7439fbc , 0xff463d19 , 0x04cff439 ] )
+  , Mont::unsafe_make( [ 0x36ba74d0 , 0x4f3ab404 , 0x82f9be9a , 0x04d6f065 , 0xd726f955 , 0xbe598d9c , 0x6b97ae31 , 0x06b8198e ] )
+  , Mont::unsafe_make( [ 0x667d3b40 , 0x7d85d68f , 0x242767da , 0x9826e36c , 0xe332ad31 , 0x3ad25529 , 0x4eaf453f , 0x00218793 ] )
+  , Mont::unsafe_make( [ 0x22d14706 , 0x70b90162 , 0xfda409dd , 0xf0ed47bf , 0x63b22846 , 0x72698bdb , 0xe0cfcef6 , 0x19d39d08 ] )
+  , Mont::unsafe_make( [ 0x7b5be283 , 0xa0c6908e , 0xa5b177a3 , 0xdf6c17e7 , 0x45e5ecb1 , 0xd4e0eeee , 0x91d3a661 , 0x1c536034 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x26006d02 , 0x8d3db6c9 , 0x1534491d , 0x3bf8ae64 , 0x6a57e423 , 0x348e4733 , 0xfbb6468c , 0x26a3ece1 ] )
+  , Mont::unsafe_make( [ 0x79526c0c , 0x5e67e65f , 0x7fd282c2 , 0x57ca3176 , 0x162c9d43 , 0x5087fa7c , 0xbad13bd3 , 0x132ad742 ] )
+  , Mont::unsafe_make( [ 0x07817856 , 0x9d92217f , 0x8fa04989 , 0x17c90a4f , 0x56d71739 , 0x2aab64d5 , 0x16352fa1 , 0x035db4da ] )
+  , Mont::unsafe_make( [ 0x2169948f , 0xa357a8b2 , 0xea0ec6a9 , 0x450769ae , 0x01d39073 , 0x9291baca , 0x2fa06087 , 0x04a738a0 ] )
+  , Mont::unsafe_make( [ 0xae67d5e7 , 0xf0f8f7e1 , 0x8d209c23 , 0x49c7dd86 , 0xbbc70c6e , 0x2ca1b79f , 0xc877d2e8 , 0x117bafec ] )
+  , Mont::unsafe_make( [ 0xa3e1d36d , 0x935b1797 , 0x38d9871d , 0xb4b1f9ea , 0x960733b2 , 0xfa6b5e02 , 0x450b53af , 0x06a19031 ] )
+  , Mont::unsafe_make( [ 0x2bed6eea , 0xf3132f83 , 0x79b50682 , 0x2c2e5229 , 0xa06e3bd7 , 0x28afa7b6 , 0x9073d8a7 , 0x133d54b0 ] )
+  , Mont::unsafe_make( [ 0xb4887642 , 0x3055779f , 0x8d441218 , 0x9e1f2794 , 0x2abba058 , 0xffda4c81 , 0xc348ff7d , 0x04e8f799 ] )
+  , Mont::unsafe_make( [ 0x9cbee070 , 0x24239aa3 , 0xca81eadc , 0xb9f4337a , 0x75d39e01 , 0x071d513b , 0x547399f9 , 0x0750d723 ] )
+  , Mont::unsafe_make( [ 0x08b0a46f , 0x9e2ceb62 , 0x405be9b4 , 0xe262a489 , 0x3f2fb1aa , 0x5d60166c , 0xaa309262 , 0x051b0821 ] )
+  , Mont::unsafe_make( [ 0x47ed80b4 , 0xd13b5232 , 0x910c4091 , 0x870a9b4c , 0x588e39b5 , 0x24b65e9c , 0x6dcf6984 , 0x076c440b ] )
+  , Mont::unsafe_make( [ 0xd7bf9058 , 0x65213797 , 0x21405d3f , 0x91cfc6a6 , 0x1d745a3b , 0x6dedc7ed , 0x212c9a91 , 0x08a1c126 ] )
+  , Mont::unsafe_make( [ 0xaa151b2b , 0xe916df35 , 0xdae7bd45 , 0x6942545e , 0x25bf0a09 , 0x2d43fb47 , 0x5e96f021 , 0x1b0a99d8 ] )
+  , Mont::unsafe_make( [ 0x22e1f453 , 0x70b323a7 , 0x99484d7a , 0x31f6ac79 , 0xd88e719c , 0xd7f6469e , 0xcf212228 , 0x083fdfe5 ] )
+  , Mont::unsafe_make( [ 0x63f2e84b , 0x04450037 , 0xf47da68b , 0xc66b364c , 0x1a60c5f7 , 0x7e1ade54 , 0x6b36aa41 , 0x1c590c46 ] )
+  , Mont::unsafe_make( [ 0x73bc3707 , 0xff8688f8 , 0x9552b16b , 0x4949dd5a , 0x0707a5f0 , 0x619fd404 , 0xc54615d3 , 0x243f8cff ] )
+  , Mont::unsafe_make( [ 0xea0665f7 , 0x7e87bf59 , 0x7c80d4b4 , 0x7adfde1f , 0x067ebb93 , 0x035ae0fc , 0x8817b538 , 0x2f7c4048 ] )
+  , Mont::unsafe_make( [ 0xd27a0c32 , 0xd97f0f1c , 0xcbfea26e , 0x5188e387 , 0x98c03c85 , 0x70392f81 , 0x194f44a5 , 0x254dc8f0 ] )
+  , Mont::unsafe_make( [ 0x73c1d684 , 0x85a5b5af , 0x7bf61cfb , 0x99327fca , 0xf0a1efca , 0x453fd7fd , 0x4ba1e8d7 , 0x0198f82b ] )
+  , Mont::unsafe_make( [ 0xb53af700 , 0x70a52e6a , 0x503b7acd , 0x2c22a93d , 0x39b2481a , 0xc2f719d3 , 0xf51ed5d1 , 0x2610b7ad ] )
+  , Mont::unsafe_make( [ 0xa5fbcc6e , 0x37a922db , 0x28f3aeff , 0xa0b88aff , 0x7cc1ecb3 , 0xa78aa335 , 0xf3632ab3 , 0x2dd5fe0d ] )
+  , Mont::unsafe_make( [ 0x87719437 , 0xdf60957f , 0x83fe3e2f , 0xbbd51967 , 0x93b1de69 , 0xf56c9718 , 0x2e1cc6e9 , 0x2383a883 ] )
+  , Mont::unsafe_make( [ 0x9f491e26 , 0xdb7f0aff , 0x2ea9ab91 , 0x94f8ca87 , 0x81f42c28 , 0x6baef429 , 0x4db68261 , 0x0a8ba4b1 ] )
+  , Mont::unsafe_make( [ 0x0b88d9fd , 0x690a9d86 , 0x8fdf6310 , 0x8e062b46 , 0x4d36f24d , 0x1daa690b , 0x242a7790 , 0x2914f735 ] )
+  , Mont::unsafe_make( [ 0x694d5e56 , 0x2c20410e , 0x8ce8d653 , 0x8cb24b86 , 0xa42b19c2 , 0x879e5f04 , 0xf4db6e74 , 0x2a184574 ] )
+  , Mont::unsafe_make( [ 0x7e84025b , 0x753ff602 , 0x1cc798b3 , 0xc712ec02 , 0x27b9de3d , 0x2aafaddf , 0xa10123f7 , 0x1cc8efc4 ] )
+  , Mont::unsafe_make( [ 0x260ac81c , 0x9bce8d2c , 0xa778425c , 0xede0052d , 0x1f51c59f , 0x4e5230fd , 0x21853498 , 0x29c45838 ] )
+  , Mont::unsafe_make( [ 0x057a88ee , 0xe01fdd91 , 0x6b100688 , 0xb43e023d , 0xcc48acfd , 0xf9d910c8 , 0x25748ce4 , 0x086f9df3 ] )
+  , Mont::unsafe_make( [ 0x64161ec0 , 0xa68e021e , 0xd4e52605 , 0x24138d9a , 0x17992bce , 0x436beece , 0xa6a227dd , 0x172f9d1b ] )
+  , Mont::unsafe_make( [ 0x7aeea667 , 0x1dd3179d , 0xd79ed85d , 0x21b5257c , 0x405f6531 , 0x06c7f8a0 , 0x4afdcf9f , 0x27f5c534 ] )
+  , Mont::unsafe_make( [ 0x6185bc76 , 0xe293e458 , 0x60e1a1fd , 0xad365924 , 0x27033a9e , 0x17f243b6 , 0x657f50c8 , 0x20e96560 ] )
+  , Mont::unsafe_make( [ 0xa833b718 , 0xa63551a0 , 0xa7bd42ca , 0x144f648f , 0x889abf4e , 0x62c853ee , 0xe452b22b , 0x09079bb0 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x0c7f660a , 0x592d4471 , 0xfaddb692 , 0x39e2fdf0 , 0xed7eaa99 , 0xb20a52a8 , 0xd9ecb14d , 0x0ff6be21 ] )
+  , Mont::unsafe_make( [ 0xc1a8b9eb , 0x4252661d , 0xcb717454 , 0x08c045ed , 0x97a9a4d4 , 0x95dada19 , 0x05dfbfd9 , 0x260c9995 ] )
+  , Mont::unsafe_make( [ 0x9d5b80e6 , 0xb1e334f4 , 0xd1948f6e , 0xd702ba53 , 0xe507b4e8 , 0x545e93de , 0x307d107e , 0x2f3c67be ] )
+  , Mont::unsafe_make( [ 0x659cac94 , 0x06316ba5 , 0xe38d1f93 , 0x102daccc , 0xaada3630 , 0xcb52ec3f , 0x680cf34f , 0x2ce42090 ] )
+  , Mont::unsafe_make( [ 0x471df694 , 0xf89ade01 , 0xad23d605 , 0xa10858ed , 0x63be6b7a , 0x089f1ada , 0x7d8db38a , 0x05dd187b ] )
+  , Mont::unsafe_make( [ 0x082dede0 , 0x9968781a , 0x96fbd0ac , 0xb9e4121a , 0x8f1c5d76 , 0x0ed32359 , 0x65121c18 , 0x12451860 ] )
+  , Mont::unsafe_make( [ 0x05ac32a4 , 0x184f9c10 , 0xd160d3b8 , 0x7c0e9529 , 0x02528a3b , 0xccfea7dc , 0xfbfd09a2 , 0x09111799 ] )
+  , Mont::unsafe_make( [ 0xfa6898e3 , 0xc11eebdb , 0x2d59386e , 0x8f390502 , 0x8a73d1db , 0x97ebe90f , 0x14397b9f , 0x21e9b5c7 ] )
+  , Mont::unsafe_make( [ 0xff6e63d6 , 0x3f36178e , 0x2360c5d0 , 0x6b46ed5b , 0x4eb792e2 , 0x65056e9b , 0xdafdb698 , 0x03a15aa7 ] )
+  , Mont::unsafe_make( [ 0x0854709b , 0x95a006b6 , 0xf3c9064c , 0x033e8aa9 , 0x43cf705a , 0x0d242978 , 0xb901a341 , 0x2fc28d67 ] )
+  , Mont::unsafe_make( [ 0xab32e93d , 0x81566fb9 , 0xa6754c0d , 0xbf9515b1 , 0x49774d75 , 0x462744d5 , 0xc804892f , 0x1784048d ] )
+  , Mont::unsafe_make( [ 0xdcc70ea8 , 0x8812f40c , 0x32a49c81 , 0x6ff77c0d , 0x6d1ac04e , 0x4dcd03af , 0x2fd44d17 , 0x1828e14f ] )
+  , Mont::unsafe_make( [ 0x7a179c43 , 0xf25cc7e6 , 0xb28e8b60 , 0x34a6b300 , 0x4f6685a7 , 0xc4678521 , 0xc0d94d5d , 0x104f661a ] )
+  , Mont::unsafe_make( [ 0x1b5527ce , 0x30623044 , 0xed760355 , 0xeb9a9e2b , 0xa00d4e06 , 0x3d3d473b , 0xdefabdd3 , 0x05e3de02 ] )
+  , Mont::unsafe_make( [ 0x080a1f33 , 0x9fbfb8e8 , 0xd4d23c6c , 0xb5da588c , 0x3beaea0c , 0xadecebf6 , 0x5aebb55d , 0x2bc1139e ] )
+  , Mont::unsafe_make( [ 0xcfd51c20 , 0x0bfdec24 , 0x1bbeda4a , 0xaf6b595c , 0x7f0525cf , 0x0090cd0a , 0xf3fcdc26 , 0x1990b5ec ] )
+  , Mont::unsafe_make( [ 0x4e306db7 , 0x1d626c97 , 0xb68ddf0d , 0x6c9025b4 , 0x930af48c , 0x8ce6cf60 , 0x2b84e743 , 0x2294d88a ] )
+  , Mont::unsafe_make( [ 0xfefa3465 , 0xd46e6883 , 0x61131f4c , 0xd4112a38 , 0xdfbe82d9 , 0xf3ee3874 , 0x960d3171 , 0x02375b95 ] )
+  , Mont::unsafe_make( [ 0xc90fde8e , 0xa2d454e4 , 0x64b84f12 , 0x6ed5041f , 0xb1f0a7ea , 0xea4c0b1b , 0x135182e3 , 0x0289eeba ] )
+  , Mont::unsafe_make( [ 0x9d77a03f , 0xeecdf0c3 , 0xfc082762 , 0x9533b09a , 0xd307a29f , 0x89d0ee82 , 0x2a5365f4 , 0x21e52824 ] )
+  , Mont::unsafe_make( [ 0x901d5d40 , 0x3b75a597 , 0xbdf5b4dd , 0x7876dd1b , 0x675b4ffe , 0xdfc5b1af , 0xed0df8af , 0x2b3656b7 ] )
+  , Mont::unsafe_make( [ 0xe0be5405 , 0x65d1212b , 0x24d84628 , 0xf9553cf3 , 0x4e81ccc9 , 0x89f938d9 , 0x347a59ce , 0x1dc8a2ec ] )
+  , Mont::unsafe_make( [ 0x595a8754 , 0x8b99bf0f , 0x397ae6af , 0x4c44dce5 , 0x41b4c307 , 0x3509398f , 0x43ffc5d1 , 0x2c228b71 ] )
+  , Mont::unsafe_make( [ 0x64982ea2 , 0x716c8e56 , 0x76cb6d4e , 0x01d73648 , 0x31960ca0 , 0xe2c94695 , 0x9d8649ee , 0x1d722d59 ] )
+  , Mont::unsafe_make( [ 0x35fca638 , 0x3d6b22cd , 0x730efb5c , 0xda900c2e , 0xc9920adf , 0x9a188e91 , 0xd2ba3aca , 0x2a8fa779 ] )
+  , Mont::unsafe_make( [ 0xe4dfb631 , 0x1eea1a9d , 0x8672eb3c , 0xfa6a53bf , 0x272f38ea , 0x463f8905 , 0x78d1f75f , 0x2b067aea ] )
+  , Mont::unsafe_make( [ 0xfa72740a , 0x5714269f , 0xaa67bba5 , 0x25ca7657 , 0x1ad9f83b , 0xcbe78531 , 0x9c77f3f8 , 0x2e4d883a ] )
+  , Mont::unsafe_make( [ 0x786a2ab6 , 0xa70d543e , 0x604ded8d , 0xadf93bcd , 0x529ed178 , 0xad844e4a , 0x1d76fc20 , 0x11729bd5 ] )
+  , Mont::unsafe_make( [ 0x138ba21b , 0x6ea23f00 , 0x845413f1 , 0x8301a1ee , 0x77f431a9 , 0xddfb8975 , 0xc2be1654 , 0x10bb3184 ] )
+  , Mont::unsafe_make( [ 0x8f2a055b , 0xd994f89e , 0xcb88b86b , 0x062d1016 , 0x2dcd9d23 , 0xcb19b3cd , 0xaba9730f , 0x15135839 ] )
+  , Mont::unsafe_make( [ 0xf8d7ed87 , 0x12e05325 , 0xd4c5019c , 0xf2a0590f , 0x6b20c790 , 0x1c792b01 , 0xa60fd8c1 , 0x1d5043f6 ] )
+  , Mont::unsafe_make( [ 0xaafc61f4 , 0x0a3c2239 , 0x5b32d822 , 0x9f12481a , 0x1078f685 , 0x0709a728 , 0x08bd529c , 0x292c83a4 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x73f202a7 , 0x20122621 , 0x449559d9 , 0xa6315112 , 0x75aa37c1 , 0x3fa5ac1a , 0xa6d56c3c , 0x1d5ae6ac ] )
+  , Mont::unsafe_make( [ 0x7805f164 , 0xfd35f1dd , 0x218acad5 , 0x681b15a7 , 0xbc6ac5c3 , 0x835177f4 , 0x31958874 , 0x00940a48 ] )
+  , Mont::unsafe_make( [ 0x6246b8b2 , 0x382c541e , 0x7ab45c01 , 0x41cc4f65 , 0x824ef394 , 0xf3278853 , 0x2e2c2f47 , 0x28c62785 ] )
+  , Mont::unsafe_make( [ 0x2b4b9c6b , 0x1b022f30 , 0x0ce8431f , 0xc3d94e38 , 0xee0135d0 , 0x91c22a33 , 0x80eb4bd1 , 0x02cd3869 ] )
+  , Mont::unsafe_make( [ 0xb7311107 , 0xd6ea0ff3 , 0x7db7374c , 0xa9dbcf13 , 0xd2bb8cb5 , 0x2e014581 , 0x50b1f9bc , 0x1aaa3086 ] )
+  , Mont::unsafe_make( [ 0x2d2d150c , 0x3605ac03 , 0x209852a2 , 0x0d84abbb , 0x16c6f2e4 , 0x513a99a2 , 0x4f376688 , 0x2f46dc7b ] )
+  , Mont::unsafe_make( [ 0x9d0f3820 , 0x53857a02 , 0x525a0070 , 0xaea9d431 , 0x6b0ba012 , 0xd24dde7c , 0x7f3e1e1b , 0x28526938 ] )
+  , Mont::unsafe_make( [ 0x225ce1fc , 0xb051eb7e , 0x7ff80fbb , 0x86299bb2 , 0xed6b24aa , 0x41d52cae , 0x6b1e52c9 , 0x13c91b76 ] )
+  , Mont::unsafe_make( [ 0xbff525fb , 0x497b42d8 , 0xba2b210c , 0xba75c857 , 0x20c3fea9 , 0xf609283e , 0x23a90cc4 , 0x12eea43b ] )
+  , Mont::unsafe_make( [ 0x9718adc5 , 0x7b8d3f57 , 0xac7b4db4 , 0xb84ba1b0 , 0xc56c1d29 , 0x7feae9a6 , 0x849d1535 , 0x102356b7 ] )
+  , Mont::unsafe_make( [ 0x5ea16b3d , 0x36edcdd0 , 0x77f406c2 , 0x447f3200 , 0x0e9fa23a , 0x68635cd9 , 0x42ee62b3 , 0x1404f5e5 ] )
+  , Mont::unsafe_make( [ 0x5494da90 , 0xf0c575fb , 0x10120a61 , 0x9cfaa42e , 0x25fb5593 , 0xbc5e3cca , 0x73a2c95b , 0x0adc4ca9 ] )
+  , Mont::unsafe_make( [ 0x28f0065d , 0x64740f14 , 0x0bdee5b4 , 0x1e84244e , 0xacb96d4c , 0xee9a4141 , 0xd4fc7339 , 0x15da8425 ] )
+  , Mont::unsafe_make( [ 0x11d0072d , 0x4b2611c7 , 0x9205c1c0 , 0xa89b220b , 0x1d8417ad , 0x1ef0e352 , 0x27537b38 , 0x023f208a ] )
+  , Mont::unsafe_make( [ 0x6f8fa91d , 0x16f4fe6c , 0x25908f49 , 0x604b8072 , 0xea49a463 , 0x8b7354eb , 0xa01bfba2 , 0x078adab2 ] )
+  , Mont::unsafe_make( [ 0x12c896e9 , 0x6c108e88 , 0xe8a514b8 , 0x7931e873 , 0xdc0aa2e8 , 0x0a111a63 , 0x43f35686 , 0x062c40e2 ] )
+  , Mont::unsafe_make( [ 0x69522b03 , 0x030280d3 , 0xddee12fa , 0xa5ff76d1 , 0xf5d7d8fe , 0x1c0dfc7b , 0x69fd78b4 , 0x1304ee93 ] )
+  , Mont::unsafe_make( [ 0xc8453549 , 0xf3af0e75 , 0x46023228 , 0x51b86df5 , 0x14edd2dd , 0xbefd28a7 , 0x239eacab , 0x14ac81de ] )
+  , Mont::unsafe_make( [ 0x17d3600e , 0xdece3f2f , 0x8f870d8b , 0xe59de9ce , 0x76cc9665 , 0xa7c80b39 , 0x23078c0e , 0x2f11e03e ] )
+  , Mont::unsafe_make( [ 0x6b2d07aa , 0x42a72dbf , 0xb67e51b3 , 0x7512c9f4 , 0x7d9935e8 , 0xa2882ab5 , 0x8e592cb6 , 0x17cee39c ] )
+  , Mont::unsafe_make( [ 0xecfd32bc , 0x6663539f , 0xfcda0b04 , 0xf36882ef , 0x8f6f827d , 0xc554452e , 0x2aa47076 , 0x0a2a7c96 ] )
+  , Mont::unsafe_make( [ 0x626944bf , 0xde9f8e30 , 0x75d457eb , 0x8825e80f , 0x67b9a4d4 , 0x4c9c980f , 0x3f805152 , 0x130f55d0 ] )
+  , Mont::unsafe_make( [ 0x1283c6e3 , 0xcc3d42ca , 0x39494f6d , 0x16507843 , 0x9c2b8957 , 0x7d1169af , 0x569bb185 , 0x166b88aa ] )
+  , Mont::unsafe_make( [ 0xdd4045c7 , 0xc1500e3a , 0x1f828e7c , 0x27e2e401 , 0x182821e6 , 0x89da0638 , 0x97c6f002 , 0x303dff36 ] )
+  , Mont::unsafe_make( [ 0x6f267dbd , 0x7f988727 , 0xd51c5642 , 0xd2539911 , 0x3a224aab , 0xa18818c3 , 0xc6af7181 , 0x0b04a220 ] )
+  , Mont::unsafe_make( [ 0x7032cf40 , 0xec84034b , 0xfb864d54 , 0x35118e92 , 0xec01cce6 , 0xa58fb0fc , 0xe7af7913 , 0x0973258d ] )
+  , Mont::unsafe_make( [ 0xde0f3a3b , 0x86e21113 , 0x28744641 , 0x8cdb74e6 , 0x3c5ccc5a , 0xe6b0bcbd , 0x3f24d647 , 0x2f529bdc ] )
+  , Mont::unsafe_make( [ 0xc5c2e5b2 , 0x71327673 , 0xd098cba3 , 0xef70e764 , 0x29b34705 , 0x7394e2db , 0x6c5adc66 , 0x03280075 ] )
+  , Mont::unsafe_make( [ 0x1616330c , 0x4f5074e4 , 0x5b351d15 , 0x3ca1ea09 , 0x6e14dfc6 , 0x7f894e63 , 0x5c069f62 , 0x1a193d6e ] )
+  , Mont::unsafe_make( [ 0x6e7672db , 0xf2cddfc2 , 0x19bb3517 , 0x97b5a30f , 0x17d2f31c , 0x38b4d2f8 , 0x0dc65d7c , 0x2afdc885 ] )
+  , Mont::unsafe_make( [ 0xa810abb7 , 0xee730ef9 , 0xa6747241 , 0xb08017dc , 0xbfc10197 , 0x0cd3dc29 , 0xc227758c , 0x2be540dd ] )
+  , Mont::unsafe_make( [ 0x869a618d , 0x2c7bc6db , 0x23fdd75b , 0x23248e00 , 0x0da9c15d , 0x6096457a , 0x9cc2a468 , 0x122b04a2 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xccd9125b , 0x432ef599 , 0x200ba745 , 0x8f10647f , 0x2568bf60 , 0x45f4a75f , 0xe3a2075a , 0x23cc729d ] )
+  , Mont::unsafe_make( [ 0x87527ecf , 0x20142d3f , 0xdd322514 , 0xcf5745db , 0xf5042a1d , 0x08a372ff , 0x22de6653 , 0x293ad897 ] )
+  , Mont::unsafe_make( [ 0xe961ba93 , 0xff2cc5d9 , 0x1306e651 , 0xf98ba79f , 0xaa7c275d , 0xcf85c91d , 0x9d5fc009 , 0x1caac2e4 ] )
+  , Mont::unsafe_make( [ 0xfd2f3f92 , 0x0da200cf , 0x3f195169 , 0x84b497a2 , 0x22972af0 , 0x9cd52fab , 0x4cb1e80b , 0x2e80a647 ] )
+  , Mont::unsafe_make( [ 0xa04d9c6c , 0x8e991ba3 , 0x05fa23a2 , 0x6f139677 , 0x6ccd8480 , 0x531317f1 , 0x0fc4eb4c , 0x188991c4 ] )
+  , Mont::unsafe_make( [ 0xc9e40379 , 0x90dbe344 , 0xa322b65d , 0xa482916b , 0x7a0077ae , 0x0359dc60 , 0x059b83b0 , 0x1b7e9b0b ] )
+  , Mont::unsafe_make( [ 0xed6b55e0 , 0x78bb6de7 , 0xba7ca304 , 0xfaab12de , 0x1948fc01 , 0xe6035217 , 0x6c11f56f , 0x0762d1dc ] )
+  , Mont::unsafe_make( [ 0xd8ba05fb , 0x7aaee8ea , 0x8273634b , 0xc6f3cc60 , 0xce74ee81 , 0x864e2b2a , 0x321c6abd , 0x03810160 ] )
+  , Mont::unsafe_make( [ 0xfd9f61fd , 0xd296617f , 0x2c9e583e , 0x22053bd1 , 0x6563b5a3 , 0x890180bf , 0xe2cebae7 , 0x0b21b857 ] )
+  , Mont::unsafe_make( [ 0x7e4eb1bd , 0x45ac2aa8 , 0x2c49cd8a , 0x007ce415 , 0x15faefbe , 0xefa7ab40 , 0x687828ac , 0x108625bc ] )
+  , Mont::unsafe_make( [ 0x3779d7d6 , 0xce2e2214 , 0x2b2722bd , 0x430cf603 , 0x755c5fa4 , 0x4cc8e344 , 0x148d15d9 , 0x2606c481 ] )
+  , Mont::unsafe_make( [ 0xe28c688a , 0x77b8218b , 0x414c355c , 0x0d33e279 , 0xa42e3470 , 0x93eeb98e , 0xe6a7c586 , 0x2b158fbe ] )
+  , Mont::unsafe_make( [ 0xdc36d82e , 0x9020dca9 , 0xa63487d2 , 0x8bbd6f72 , 0x9d04cc35 , 0x3dd5a7a2 , 0x0bd794ab , 0x0cd1243d ] )
+  , Mont::unsafe_make( [ 0x5033381e , 0x35be126b , 0xb4cbae04 , 0x4aa93d51 , 0xa9064d95 , 0xc80b74ec , 0x5129a6f0 , 0x192fdf94 ] )
+  , Mont::unsafe_make( [ 0x8bebb4e9 , 0x0dae8ebd , 0xd13dfea0 , 0x89ee4d17 , 0x79da2a4c , 0xdbc3abf4 , 0x5a8fdb3f , 0x3058c23b ] )
+  , Mont::unsafe_make( [ 0xe8be5c26 , 0xd00310cd , 0xddb5ad46 , 0xd1ebdfef , 0xf82d400a , 0xa796e64e , 0xd5249db9 , 0x077c13f0 ] )
+  , Mont::unsafe_make( [ 0x8145d2f6 , 0x953d02a5 , 0xc53ce36f , 0x205523c8 , 0x33b26152 , 0x7ab6889a , 0x9eebc6df , 0x0194e45f ] )
+  , Mont::unsafe_make( [ 0x11c4479d , 0x342f14b8 , 0xcc05725f , 0x4314ae36 , 0xaaa3b88b , 0x4c8f257a , 0x67c2a693 , 0x13db75bc ] )
+  , Mont::unsafe_make( [ 0x636264f4 , 0xc77586d2 , 0xc6db2eeb , 0x49e117c9 , 0x719bc08c , 0x84081656 , 0xe243c93a , 0x1f3f63af ] )
+  , Mont::unsafe_make( [ 0x166a5e52 , 0x6fc97cac , 0x2135ba5e , 0xb1e8ade4 , 0xd6706420 , 0x681a448b , 0xd27a85e0 , 0x1e491141 ] )
+  , Mont::unsafe_make( [ 0x3ca40f07 , 0xd8b637d8 , 0x66f30ff6 , 0x33e33460 , 0xe7fab309 , 0xd94319ea , 0x96e414f1 , 0x243522b7 ] )
+  , Mont::unsafe_make( [ 0x2d72ee51 , 0x77f95ab0 , 0x3a7868b1 , 0xe3b1449d , 0x625075a9 , 0x51046e9b , 0xfef0ef6c , 0x14382ebd ] )
+  , Mont::unsafe_make( [ 0xc9950db0 , 0xa2e2f7d7 , 0x85bfb7d5 , 0xa007dcbe , 0xc9591fc7 , 0xe8a0a763 , 0xa697663a , 0x19cd65e9 ] )
+  , Mont::unsafe_make( [ 0x39ecad6a , 0xdf2ed3b8 , 0x3448ac1d , 0x69589ec3 , 0x2f075ac3 , 0x5388f6da , 0x924f3767 , 0x0c4825fa ] )
+  , Mont::unsafe_make( [ 0xe46c02be , 0x1755118b , 0x092d650a , 0x288fcee6 , 0xa9f3d15e , 0x336c07fb , 0xd18c23cc , 0x1bf3c9a4 ] )
+  , Mont::unsafe_make( [ 0x753ab402 , 0x8dff68ce , 0x382ee51b , 0xac2568a9 , 0xc2a0a68b , 0x55905b97 , 0xce3d087a , 0x0786f320 ] )
+  , Mont::unsafe_make( [ 0xff28b3db , 0x17df2c97 , 0xa2857904 , 0x870cfdf0 , 0xce44c6c5 , 0x93839fc0 , 0x31e0b1d6 , 0x1027e74a ] )
+  , Mont::unsafe_make( [ 0x3f8bb858 , 0x67235d37 , 0xcdc8a131 , 0x9dfbdaec , 0x4c11b226 , 0x495f5bb7 , 0x85afd3d8 , 0x116fe830 ] )
+  , Mont::unsafe_make( [ 0x52dd85c0 , 0xb3279722 , 0xd7c86bf3 , 0x54f1f7c0 , 0x6555f687 , 0x0e161aa4 , 0x6b042b17 , 0x0bac0f77 ] )
+  , Mont::unsafe_make( [ 0x6921b35c , 0x84825b6e , 0xeb6f2c76 , 0xf1467a94 , 0xfba7cc92 , 0x3358a2c0 , 0xaa6bc097 , 0x01ec4bee ] )
+  , Mont::unsafe_make( [ 0x26e94b67 , 0x20ba341a , 0x5779ee10 , 0x806c184e , 0xe63811f5 , 0x078d7c5b , 0x3f24466c , 0x25c5ca69 ] )
+  , Mont::unsafe_make( [ 0xd53ede0b , 0x006c6366 , 0x052021b8 , 0xfb52057c , 0xd77bead9 , 0xf0c928e0 , 0xff107fb6 , 0x28589a5f ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xbcd96044 , 0x8b16af22 , 0xb023bce2 , 0xa2574734 , 0x81c0f971 , 0x79b1ec0a , 0xefce2bdf , 0x261380d3 ] )
+  , Mont::unsafe_make( [ 0x9d76dcef , 0x8f135b89 , 0xad7daee4 , 0xd421361c , 0x6a143967 , 0x5d3f330a , 0x5e34a81f , 0x03ddf06d ] )
+  , Mont::unsafe_make( [ 0x93252cac , 0x970f6b88 , 0x5437ede3 , 0x16f04d70 , 0x3700489e , 0xe69b1733 , 0xd3927696 , 0x2b2ab9e2 ] )
+  , Mont::unsafe_make( [ 0x6e81c3f9 , 0xa9ea4fd6 , 0x6bcbd0bb , 0x6099b8fd , 0xc7e3b21e , 0x4e4a6091 , 0xe0dabbd9 , 0x2758893a ] )
+  , Mont::unsafe_make( [ 0x6200f00c , 0xaf376d76 , 0xd09bf076 , 0x21c7716f , 0x526e57a4 , 0xb18e4399 , 0xfc5fc9f4 , 0x067573b2 ] )
+  , Mont::unsafe_make( [ 0xf7450085 , 0x8f856324 , 0x23a9b8cc , 0x9e851001 , 0xbaeb3f2b , 0x9a84f8c7 , 0x867c3b8f , 0x02ae2835 ] )
+  , Mont::unsafe_make( [ 0xf7f83d60 , 0xab2f558b , 0x52b7e2d4 , 0x4c85a0c9 , 0x497441c7 , 0x2bf14975 , 0x31c84d9f , 0x03a4b421 ] )
+  , Mont::unsafe_make( [ 0x8b04cca9 , 0x07e5bb59 , 0xa300caa8 , 0xea2bb86e , 0x9054d7f6 , 0x3f61cc33 , 0x72fce2a0 , 0x291a210c ] )
+  , Mont::unsafe_make( [ 0xd6b10305 , 0xbe0528f2 , 0xc9b2eb3f , 0x2ceeda35 , 0x493ecdf8 , 0x89fc3ca4 , 0x722e55c3 , 0x0ebd3f62 ] )
+  , Mont::unsafe_make( [ 0x23a7d1bf , 0xe58b4f3f , 0xea97e135 , 0xda66dc49 , 0xab793bab , 0x86f20e39 , 0x866f3ce9 , 0x0d510d50 ] )
+  , Mont::unsafe_make( [ 0x09afd57c , 0xbe31a09b , 0x9d9c1595 , 0xf1e6d1a8 , 0x4158e1f6 , 0x307c3247 , 0x570a4205 , 0x0a16edca ] )
+  , Mont::unsafe_make( [ 0x041c9afc , 0xa01be1bc , 0x5d6659ec , 0x85ee8a46 , 0xbdd02a0a , 0xf997f628 , 0x49683b89 , 0x0fe755d4 ] )
+  , Mont::unsafe_make( [ 0x00604dc1 , 0x9b22894d , 0x82b1d795 , 0x4ef8b48b , 0x69dbf30e , 0xbacee8c8 , 0x61533870 , 0x1681db08 ] )
+  , Mont::unsafe_make( [ 0x025061b6 , 0x818caadb , 0x3fe7b603 , 0xce2f852a , 0x56d31c7c , 0x41064d3a , 0xb2931575 , 0x1adb6478 ] )
+  , Mont::unsafe_make( [ 0x15268eec , 0x1af7d5bc , 0x1c69cbc7 , 0xdca6cf28 , 0x55c27e53 , 0xcc125160 , 0xfbb6dc2e , 0x0ce0bf2b ] )
+  , Mont::unsafe_make( [ 0x82387e2e , 0x8b5dc391 , 0xd89a72aa , 0x94e42144 , 0xb415e31e , 0x9ad2feb3 , 0x71f1030f , 0x0754bb09 ] )
+  , Mont::unsafe_make( [ 0xcd48f99d , 0xb8267765 , 0xcf670c4c , 0x12084f1c , 0x2dbecc8f , 0xb2d982ad , 0x8bfe64d4 , 0x2f331721 ] )
+  , Mont::unsafe_make( [ 0x68b77eff , 0x65bb2ce1 , 0x8e4f63a5 , 0xfd890591 , 0x75f03933 , 0xff8e865b , 0x90665e29 , 0x29ba01d4 ] )
+  , Mont::unsafe_make( [ 0x863099f5 , 0x8213452e , 0x00b690e9 , 0xf2425fdc , 0xf10dff99 , 0xe8c8a9bc , 0x7edc1572 , 0x2ea500d9 ] )
+  , Mont::unsafe_make( [ 0xb21463d9 , 0x58debaa8 , 0x214350b1 , 0x9c9781d1 , 0x7dbb75ca , 0xee18ac63 , 0x8d140370 , 0x03a9fd38 ] )
+  , Mont::unsafe_make( [ 0x7f55b418 , 0xef2a1cf6 , 0x5dfa24cf , 0xa73cc177 , 0xce828830 , 0x07978dce , 0xd0521d68 , 0x2d942e4e ] )
+  , Mont::unsafe_make( [ 0x7313088f , 0xf765aeda , 0x7573dcd4 , 0xdfd733d1 , 0x70207f1b , 0x64fcedc8 , 0xf57b7277 , 0x0ec6ed1c ] )
+  , Mont::unsafe_make( [ 0x58f4200a , 0xb4b0ea89 , 0xc9942ddd , 0xf084b517 , 0xc55c996a , 0x1ec0e0aa , 0xdb7121d8 , 0x24279f43 ] )
+  , Mont::unsafe_make( [ 0x76bc70a7 , 0x215480a1 , 0xf796fccd , 0x2a6c6125 , 0x88bc3df0 , 0xfacf3a4f , 0x7bb5735c , 0x0c271545 ] )
+  , Mont::unsafe_make( [ 0x03a4f5c7 , 0x911ce016 , 0x89c0bb1b , 0x7fa5d80a , 0xafd0ba1f , 0x358b601f , 0x35fbf573 , 0x072e7e2d ] )
+  , Mont::unsafe_make( [ 0xd1158c95 , 0x78d4fa3d , 0xaad0f862 , 0xe73988d4 , 0xfb7ccf44 , 0x5b177c37 , 0x9b51148a , 0x264bc8b8 ] )
+  , Mont::unsafe_make( [ 0x2b519e76 , 0x369f5730 , 0x884ea190 , 0x145695eb , 0xe734ba5f , 0x3bee01d8 , 0x4f6424af , 0x0e09741d ] )
+  , Mont::unsafe_make( [ 0x59e06237 , 0x874ebe61 , 0x1d196a2a , 0xa92e5d71 , 0x958132ee , 0xfcdc63b4 , 0x1151fc6f , 0x0411971c ] )
+  , Mont::unsafe_make( [ 0x0dbacc8b , 0xfc8bb98c , 0xd1dcb029 , 0x0a30615e , 0x7a6e7062 , 0x4e46f69c , 0x53b76b16 , 0x12ae214d ] )
+  , Mont::unsafe_make( [ 0x14a35396 , 0xdf5a1131 , 0x00f87646 , 0x5ea6955e , 0x9cc592db , 0xa93e833c , 0x52214b3d , 0x0779cf8f ] )
+  , Mont::unsafe_make( [ 0x23769dc4 , 0xd50e1cef , 0xce2f5b2f , 0xf3ad4233 , 0xa68ccb22 , 0x40463b7f , 0x0704fda9 , 0x2653af7f ] )
+  , Mont::unsafe_make( [ 0xe83a72dc , 0xf71e852f , 0x4d6ba935 , 0xa2680d5e , 0xf337eeb5 , 0x00da0794 , 0x8163bb14 , 0x2da8cd4a ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xa78a4421 , 0x1d261f7b , 0x5fb564ed , 0x3a900bc9 , 0x36f791e4 , 0x17533947 , 0x3451a62d , 0x2680d7b3 ] )
+  , Mont::unsafe_make( [ 0xc37f0a4d , 0xe7e6fc6a , 0xa131dfd4 , 0xdb4b7b04 , 0x37953969 , 0xd55c5bd1 , 0x20652ee7 , 0x0dbbcd16 ] )
+  , Mont::unsafe_make( [ 0x16cec057 , 0xc80b9121 , 0xb00bcdbb , 0x074876bb , 0xa9c57b87 , 0xece004ba , 0xba9e6693 , 0x1af33da0 ] )
+  , Mont::unsafe_make( [ 0x901b9421 , 0x71a80fc3 , 0x0ab65677 , 0xbd469ca6 , 0x8e3bd96d , 0x7616c56b , 0x107b41e0 , 0x1515f8ec ] )
+  , Mont::unsafe_make( [ 0x3eb76e83 , 0x34243f3a , 0x9decb5dc , 0x5b1c1074 , 0xed9c30af , 0xaf39ec27 , 0x27890433 , 0x22629f28 ] )
+  , Mont::unsafe_make( [ 0x22a2ebbc , 0xc20c3496 , 0x010828f2 , 0xd22431c4 , 0xd986fe5c , 0xbbdbac18 , 0xc70d30f1 , 0x1ff8b5bc ] )
+  , Mont::unsafe_make( [ 0x70c87bb8 , 0x3bf705e1 , 0xbe414c72 , 0xa002cd77 , 0xf03188fa , 0x5b22f66d , 0xdcbc4a84 , 0x148e1f4c ] )
+  , Mont::unsafe_make( [ 0xfa8e1b8c , 0x47ced325 , 0x300bad6a , 0x479b3ae0 , 0x0e480bfd , 0xc190cac9 , 0xf323c491 , 0x188cd53b ] )
+  , Mont::unsafe_make( [ 0x61f40cc5 , 0xbcef2f02 , 0xaf476001 , 0x40816468 , 0xa765fea2 , 0xd656979c , 0x19f684dc , 0x00b62d8c ] )
+  , Mont::unsafe_make( [ 0xac052fc0 , 0xaf830a4e , 0xdb91da09 , 0x82b18377 , 0x3a894e46 , 0x735d21e8 , 0x5d9b54cb , 0x1742e36d ] )
+  , Mont::unsafe_make( [ 0x8f6387c5 , 0xbe479d9f , 0x44949556 , 0x4fa596dc , 0x2da00601 , 0x8cf5e889 , 0xc03ade5f , 0x17ec4593 ] )
+  , Mont::unsafe_make( [ 0x2b88e72d , 0x83eb48c2 , 0x986e284c , 0xae73dfa4 , 0x36bddb07 , 0xc94a8f95 , 0xa60ae5a6 , 0x0e53ae7a ] )
+  , Mont::unsafe_make( [ 0xfde7d21a , 0xd96067da , 0xf56788a2 , 0x21cbf4fa , 0xd2a34b91 , 0x32ff9e2e , 0x99d965cc , 0x0f325087 ] )
+  , Mont::unsafe_make( [ 0x9648fcd6 , 0x07dc526b , 0x671c4ed6 , 0x1c965275 , 0x90217956 , 0x3c806d02 , 0x93997b6d , 0x237c957a ] )
+  , Mont::unsafe_make( [ 0xdbe96292 , 0xbb0804d7 , 0xd54f9d73 , 0x416c1e71 , 0xbbcea4ce , 0x6c2afb6a , 0x9d8aa9fa , 0x0d7d9c2b ] )
+  , Mont::unsafe_make( [ 0x2446c677 , 0xc95662a1 , 0x2858d943 , 0x0863cf78 , 0x0154f321 , 0x5da182bb , 0xeaed7038 , 0x08257f21 ] )
+  , Mont::unsafe_make( [ 0x3b5b5752 , 0x13e2721e , 0x4b7c734f , 0x1b474ea2 , 0x88db8d3b , 0xb780e220 , 0x3dd3a7c5 , 0x105f2ec7 ] )
+  , Mont::unsafe_make( [ 0x55f3fadb , 0x0105f683 , 0x934b4868 , 0x1767532e , 0x2265bb6c , 0xf549e7ae , 0xa1fb08fa , 0x0e826523 ] )
+  , Mont::unsafe_make( [ 0x0341bb76 , 0x44d44f18 , 0x96c2349d , 0x33cd64a0 , 0xf71be178 , 0xea311f90 , 0x20ccd5f3 , 0x07c4431d ] )
+  , Mont::unsafe_make( [ 0x52b66073 , 0x6edc6152 , 0x42a066e2 , 0x1f981817 , 0x0ce54b5e , 0xbb86263a , 0x86a17893 , 0x289d2a00 ] )
+  , Mont::unsafe_make( [ 0x4bb48702 , 0xd7d70127 , 0x03be53b3 , 0xffb38241 , 0x096551ec , 0xd2458209 , 0xd0f6379b , 0x1891f165 ] )
+  , Mont::unsafe_make( [ 0xe0183553 , 0x64961698 , 0xa2444337 , 0xc7f4d2bd , 0xaf667edf , 0x6be4c4d1 , 0x1721d971 , 0x279c22b8 ] )
+  , Mont::unsafe_make( [ 0x213724e9 , 0xba14e8a1 , 0x8097323b , 0x5b390f27 , 0x4cee3d1d , 0x0ac02162 , 0x1ed2a4b4 , 0x2bbba925 ] )
+  , Mont::unsafe_make( [ 0xcf11990f , 0xc112d161 , 0x28918eed , 0xacb1f5bf , 0x769b7c8d , 0x7b09ec6f , 0xfa3c7fd4 , 0x112c39b9 ] )
+  , Mont::unsafe_make( [ 0xa748e177 , 0x57054ff3 , 0xfd5a8d60 , 0x51700831 , 0x13a3166a , 0xfac51ed3 , 0x32de3370 , 0x043210a5 ] )
+  , Mont::unsafe_make( [ 0xe3bdb508 , 0xfe90bae7 , 0xa512e25c , 0x58123db7 , 0x66cbe693 , 0xab2481de , 0x8b257bf2 , 0x183373b9 ] )
+  , Mont::unsafe_make( [ 0x98b7a4d3 , 0xa6895c0f , 0xd1c66045 , 0xa57d0ca4 , 0x671c1b9a , 0x3563361d , 0x1afa714d , 0x1458cbe4 ] )
+  , Mont::unsafe_make( [ 0x4799cbd7 , 0xc74bb911 , 0x2dc610f7 , 0x50df1463 , 0xf8872448 , 0xd5279d8b , 0xc39d86de , 0x184a3ccb ] )
+  , Mont::unsafe_make( [ 0xa9ba31c7 , 0xd8035777 , 0xd7c3ec16 , 0xce0baf78 , 0xb4614617 , 0xa17e02f9 , 0x854befda , 0x1b1b04f9 ] )
+  , Mont::unsafe_make( [ 0x199bff6a , 0xe3cf5dac , 0xbdc59c0c , 0x2900ebc1 , 0x2b6a2e5b , 0xe2d7dd06 , 0x00701177 , 0x26b577aa ] )
+  , Mont::unsafe_make( [ 0x233c4860 , 0x4c44646e , 0xbd07fd2f , 0xba06b3a4 , 0xf1e45c2c , 0xc2b8145e , 0xea706abc , 0x0c13754c ] )
+  , Mont::unsafe_make( [ 0xebc2f81f , 0xc254d54c , 0xb4940c78 , 0x0839ae53 , 0xe2e4361a , 0xfd91e60c , 0x890c1d6f , 0x1c08c602 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xc51f7b11 , 0x15b395fb , 0xf9ea1c1a , 0x0e848a67 , 0x19114bfb , 0x06121c47 , 0xdf4933cc , 0x03198891 ] )
+  , Mont::unsafe_make( [ 0xf0b9bbf4 , 0x19b18d5b , 0x83663a16 , 0xc6bfa9d6 , 0x02de35ac , 0x10653986 , 0xb4566179 , 0x1b136cbf ] )
+  , Mont::unsafe_make( [ 0x05bf9e36 , 0x1b95296d , 0x605c59df , 0xd579fb2b , 0x14c0771e , 0xa63ecd25 , 0x57832913 , 0x2bca81ae ] )
+  , Mont::unsafe_make( [ 0x8b602f59 , 0x5a2a6d3d , 0x0b59ba6c , 0x590e5328 , 0x67878ee0 , 0x1de96631 , 0x4ccdf340 , 0x2a75f18b ] )
+  , Mont::unsafe_make( [ 0x3e4216c2 , 0xec59a891 , 0xe9dd5a65 , 0x94c91d89 , 0xa7a2aefc , 0x29f90cfb , 0x47c5d928 , 0x19c511fa ] )
+  , Mont::unsafe_make( [ 0xa219f310 , 0xe9c28a1e , 0x29e714f4 , 0x747b83ce , 0xbf05278a , 0x99e73fa0 , 0xfc400d0d , 0x241bbd0d ] )
+  , Mont::unsafe_make( [ 0x8939907a , 0x1391eb23 , 0x415f9162 , 0x8aeeeac4 , 0xcf34adfe , 0x8ba23ceb , 0xb464ccb0 , 0x2644c50f ] )
+  , Mont::unsafe_make( [ 0x4a7afb34 , 0xcd50af63 , 0xa87a8b96 , 0x342f9b91 , 0x24aeebae , 0x4c906a9b , 0x0f37cf15 , 0x16dc62d4 ] )
+  , Mont::unsafe_make( [ 0xa46816ff , 0x6aebaa4e , 0xce78a76f , 0x7c3e054f , 0x9612bbf4 , 0xd6a24d09 , 0x1872bcdd , 0x13cd996f ] )
+  , Mont::unsafe_make( [ 0x88310b3c , 0xa812e21b , 0xb249abdc , 0xbd1d5c59 , 0xa75c17e0 , 0x1ecf5813 , 0x04ffe9cf , 0x18d9adee ] )
+  , Mont::unsafe_make( [ 0x62e8c45a , 0xd69ffa51 , 0xa8217623 , 0x212ef373 , 0x8c487799 , 0x0b851307 , 0xc6e295a1 , 0x1c71f6b4 ] )
+  , Mont::unsafe_make( [ 0xf8d8c443 , 0xa133b98f , 0xbe5d7fb0 , 0x64545cd1 , 0x18b78bff , 0x14e0ef60 , 0x462190a2 , 0x0044e4bc ] )
+  , Mont::unsafe_make( [ 0xd03090d0 , 0xfbb02df0 , 0x29a74d32 , 0x9d935c63 , 0x8504556e , 0x5ba0343f , 0xe22ab2f0 , 0x1641e822 ] )
+  , Mont::unsafe_make( [ 0x057f4ed9 , 0x692b49ce , 0x4441569b , 0x294a508d , 0x7900b80d , 0x8e827945 , 0x0c3e6928 , 0x23c4ee0e ] )
+  , Mont::unsafe_make( [ 0x8d7e9c7b , 0xf5722942 , 0xf1cc002d , 0xcdf93c44 , 0x08cd0415 , 0x2c443d00 , 0x78756bb6 , 0x06ab369f ] )
+  , Mont::unsafe_make( [ 0x438a97bf , 0x43b03d01 , 0x1cab044c , 0x425f8226 , 0x2b47b5aa , 0x43080765 , 0xd189654c , 0x0d97af49 ] )
+  , Mont::unsafe_make( [ 0xb60f45a8 , 0xf4405d81 , 0x02aaca74 , 0x2aea5e46 , 0xadb18709 , 0x23231ab9 , 0xd9393911 , 0x2285f482 ] )
+  , Mont::unsafe_make( [ 0xd6f9e384 , 0x0827e968 , 0xa16d2fcc , 0x4675c3c4 , 0xd580f9a9 , 0x7e059ddf , 0x01fe5761 , 0x050a25dd ] )
+  , Mont::unsafe_make( [ 0x7f7ad301 , 0x7796f325 , 0xc6ff3ab8 , 0xc43ee18b , 0xadd0dcf0 , 0x7d8e5cb0 , 0x03653496 , 0x0d2e43fb ] )
+  , Mont::unsafe_make( [ 0xd984e7b4 , 0x2f865f73 , 0x120f360a , 0x63a82cea , 0x737915c4 , 0x23c1ad31 , 0xac991a90 , 0x239daed3 ] )
+  , Mont::unsafe_make( [ 0x2064f6ff , 0x7318ffdd , 0x248573c2 , 0x69778678 , 0x92ae07cb , 0x5678b7be , 0xf9268145 , 0x01b3bba2 ] )
+  , Mont::unsafe_make( [ 0x92fdf87d , 0x5485ed52 , 0x1ee01bde , 0xf9c24332 , 0xa46dc946 , 0x66b23ef3 , 0xa9d8ccee , 0x3053b8aa ] )
+  , Mont::unsafe_make( [ 0xbca592ac , 0xd3c1eef4 , 0x1e4a54de , 0x5a7f622e , 0xc1d82846 , 0x01908897 , 0xa0ec4bbc , 0x1a7d517d ] )
+  , Mont::unsafe_make( [ 0xaa3bf3bf , 0xb5f5c95f , 0x6eb87ae2 , 0x3ccd1cef , 0xa4912c80 , 0x96a94682 , 0x4a4b956e , 0x2913fd5a ] )
+  , Mont::unsafe_make( [ 0x1280c40e , 0xad8c5b86 , 0xd88b137b , 0xa3886f25 , 0xbf81b478 , 0x4475d39e , 0xd13deaac , 0x10028b98 ] )
+  , Mont::unsafe_make( [ 0x54c3163e , 0xfdd411d6 , 0x84fce50a , 0x643e91e4 , 0x89d2bf86 , 0x9c2bb347 , 0x68825e26 , 0x2726f39d ] )
+  , Mont::unsafe_make( [ 0xfda56933 , 0xbb52c6fb , 0x3160e3cd , 0xa64d7af4 , 0xb728555e , 0x17e1a84b , 0x30d4cd2a , 0x256e0656 ] )
+  , Mont::unsafe_make( [ 0x8d89a2bf , 0x67ddf08b , 0x6521b672 , 0xc7cd0477 , 0x6e49374c , 0xa28fed7c , 0xb45dc0aa , 0x0f92770b ] )
+  , Mont::unsafe_make( [ 0x87ea58e4 , 0x0342650a , 0x540cdf9a , 0x9719e803 , 0xc04231b7 , 0xbe90fa34 , 0xca3b0e3a , 0x24334dc1 ] )
+  , Mont::unsafe_make( [ 0x504b7e22 , 0xaa878376 , 0x7f7a7c0a , 0x877e10ef , 0xd0ef68dd , 0x3096e6b0 , 0x3ff54477 , 0x2795ae41 ] )
+  , Mont::unsafe_make( [ 0xbfe28ead , 0x9e127274 , 0x9dff4872 , 0x94e48e1b , 0x734d11a5 , 0x29655ff0 , 0x10941add , 0x142f3939 ] )
+  , Mont::unsafe_make( [ 0xc972648b , 0x16222619 , 0x4a0bb7da , 0xdd5fa673 , 0x89d23243 , 0xbdd35b9b , 0xb49a8c1e , 0x2914d484 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xaa8274be , 0x578fa25a , 0xb9396bd1 , 0x96965b92 , 0xf3ae19e8 , 0xe3870b27 , 0xf7cf2277 , 0x232144c7 ] )
+  , Mont::unsafe_make( [ 0xeb0fdce0 , 0xf32f5f1f , 0x087ec6b0 , 0xf152dd41 , 0x5d3243e3 , 0x6daaa555 , 0xbe9caf0d , 0x0924a972 ] )
+  , Mont::unsafe_make( [ 0x9d850182 , 0xa956dfcd , 0xf73a3113 , 0xa21e9782 , 0x185d4a3a , 0x2a4c0d70 , 0xc171ed23 , 0x27f4014a ] )
+  , Mont::unsafe_make( [ 0xf34f8889 , 0xcf9040f9 , 0x1a7f6760 , 0xe889fcd7 , 0xdb18fe0a , 0xf2e0b45f , 0xa562d831 , 0x2ffbd98b ] )
+  , Mont::unsafe_make( [ 0xf4f41982 , 0xeab65160 , 0x5cb90fcc , 0x17cfb5a5 , 0x4245c4da , 0x57eb0287 , 0x1dc50f56 , 0x08d63fba ] )
+  , Mont::unsafe_make( [ 0x0ec470f0 , 0xd804e651 , 0xe8aad9e7 , 0xd44d15e7 , 0x5ba55192 , 0x51c5a6bd , 0x96c46c51 , 0x12ad0cc2 ] )
+  , Mont::unsafe_make( [ 0xe0306579 , 0xdf1b515c , 0x84d8253f , 0xa6d0a04b , 0x1eb30c9b , 0xf47d2620 , 0x7a74efe0 , 0x1e07ad57 ] )
+  , Mont::unsafe_make( [ 0x78f7bdda , 0x1097ffb9 , 0x0657bd36 , 0x3688ee33 , 0x15b8cde6 , 0xc54163dc , 0x2c2d8fd1 , 0x038f9ca1 ] )
+  , Mont::unsafe_make( [ 0xddac559c , 0xa6757188 , 0x4e602d79 , 0xcac4f5cd , 0x9258ce93 , 0x89022669 , 0xe5d1e610 , 0x096af227 ] )
+  , Mont::unsafe_make( [ 0x1ff481c8 , 0x94f2514d , 0xb5e5c179 , 0xec111a02 , 0xd10d7d11 , 0xd6d04062 , 0x53972194 , 0x2581a8b3 ] )
+  , Mont::unsafe_make( [ 0x373dbb8e , 0xb2765369 , 0x89aaa137 , 0x051484e9 , 0x118d3c4c , 0x839c0d6b , 0x57605f9b , 0x2265dfee ] )
+  , Mont::unsafe_make( [ 0x1669a285 , 0x8ffb4d9b , 0x781e9d79 , 0xcd3c2ea2 , 0xc28ca1db , 0xcfd6c9c9 , 0x326db7d7 , 0x088c3e08 ] )
+  , Mont::unsafe_make( [ 0xfc1e225d , 0x3492ce66 , 0xc518ce2b , 0xd57275b4 , 0x372c36a4 , 0x0843acd8 , 0xb1f908e3 , 0x10a65323 ] )
+  , Mont::unsafe_make( [ 0x6fdaf4ba , 0x8b2206da , 0xfdfad4fe , 0x788d3547 , 0xc2a2fbf5 , 0x0f087d07 , 0x4321c109 , 0x2ea7cadd ] )
+  , Mont::unsafe_make( [ 0x1412da3c , 0x9d647dae , 0xabca5367 , 0x273a1040 , 0x4d0774cf , 0x6247d44d , 0x5a9bb34e , 0x0311389f ] )
+  , Mont::unsafe_make( [ 0xc29a1aee , 0x816ab62d , 0xa7238ebb , 0x445661b0 , 0xf495b6d8 , 0x44b859f1 , 0x46cc124e , 0x00e0f12b ] )
+  , Mont::unsafe_make( [ 0xdfbc1b8e , 0x47ab3875 , 0x97d10c6a , 0xf390326a , 0x8c531f58 , 0x272f16b8 , 0xb06f6772 , 0x2a42bff2 ] )
+  , Mont::unsafe_make( [ 0x2e35df53 , 0x8227d8ef , 0xcaf91892 , 0xe247a515 , 0x1ad161bf , 0x6fb33d97 , 0xaab21eca , 0x072bca18 ] )
+  , Mont::unsafe_make( [ 0x89234494 , 0x952ac950 , 0x49c2fad0 , 0x362add6c , 0xf19beb03 , 0x9427d3f5 , 0x9565bafc , 0x0d9a0242 ] )
+  , Mont::unsafe_make( [ 0x451f0ab7 , 0x9f2910b5 , 0x3fd60461 , 0x80280cb2 , 0xe6b91084 , 0x54c842f1 , 0x8f08a75d , 0x23e71e22 ] )
+  , Mont::unsafe_make( [ 0x94d15f26 , 0x46bcd7a8 , 0xcca72be7 , 0xc17858f5 , 0x6713e20e , 0x4f30e9e4 , 0x29ea1d23 , 0x110033c2 ] )
+  , Mont::unsafe_make( [ 0x1329f654 , 0x22102718 , 0x4f86476e , 0x6fad0332 , 0x5373f375 , 0x137923ad , 0x9f6119e0 , 0x03ca547f ] )
+  , Mont::unsafe_make( [ 0x3b53f283 , 0x20027460 , 0x566761f8 , 0x823dbf53 , 0x7c2d417a , 0xab020024 , 0xd2b2db8b , 0x061e636c ] )
+  , Mont::unsafe_make( [ 0xb8df9392 , 0x2520b072 , 0xa5919b1a , 0xe10dc585 , 0xd5712b58 , 0x4d91b872 , 0x99f1ce6f , 0x12e4c734 ] )
+  , Mont::unsafe_make( [ 0x3ea22555 , 0xd01abddc , 0xf6510bd8 , 0x3c5e56d6 , 0x1af36c4b , 0xe2623b17 , 0xb4f2d3a7 , 0x22e99d83 ] )
+  , Mont::unsafe_make( [ 0xd665c543 , 0x51c078bc , 0xcf9cf29d , 0x4071d19d , 0x40b87797 , 0x0bba72d4 , 0x960e3e78 , 0x0360de2d ] )
+  , Mont::unsafe_make( [ 0x378832fb , 0xad12f6e6 , 0xdbe09f95 , 0x01a7aca9 , 0xda93a737 , 0x18ad5c5b , 0x915d90d1 , 0x0ae7ce44 ] )
+  , Mont::unsafe_make( [ 0xe8c42265 , 0xaa0c2993 , 0x3069d6a7 , 0xa22d5280 , 0x297dc730 , 0xf1f13f87 , 0x91d0d3ab , 0x279e3914 ] )
+  , Mont::unsafe_make( [ 0x0efd195a , 0x9e3a17df , 0x36f3f30e , 0x141577ed , 0x396bfff3 , 0x768127f6 , 0xc54e592a , 0x00718fad ] )
+  , Mont::unsafe_make( [ 0x42dab595 , 0x8708442c , 0xfacaeda4 , 0x6f549e32 , 0xc4367e35 , 0xb604cb3a , 0x449aaf95 , 0x0b53cf21 ] )
+  , Mont::unsafe_make( [ 0x797a72fa , 0x6cdffba1 , 0xc3432cea , 0x08ed6cfc , 0xa09a8231 , 0xb10ef11d , 0xdc079800 , 0x171e9351 ] )
+  , Mont::unsafe_make( [ 0x8d0d2099 , 0x003179e7 , 0xbd433576 , 0xa9d4b6f5 , 0x4cfce5c1 , 0xe8751432 , 0xb8abdee3 , 0x03a32a34 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x02589c6b , 0xf3bdd5ae , 0xee4a6400 , 0xac1dc1bd , 0xa3611a0d , 0xaa450164 , 0xed7eb2b3 , 0x00bd5b80 ] )
+  , Mont::unsafe_make( [ 0x97cfa8f0 , 0x4ef645ab , 0xcacf8984 , 0xc0cf939c , 0xaf16605b , 0xa545e847 , 0xbcf1d4c2 , 0x06503a1f ] )
+  , Mont::unsafe_make( [ 0x010a3da6 , 0x8f31dc72 , 0x9ac75cbe , 0xecf8f768 , 0x3f08f2ff , 0xbe3b7640 , 0x98c534ec , 0x1c9aee1e ] )
+  , Mont::unsafe_make( [ 0x0d3038f0 , 0x2de44e25 , 0x75b7be47 , 0x9b974e18 , 0x93b4a980 , 0x87d43ff0 , 0x6180cf62 , 0x11eb187c ] )
+  , Mont::unsafe_make( [ 0x6727dd46 , 0xc3bcf465 , 0xa8628824 , 0xc8dbd842 , 0x1becf354 , 0x9000a8de , 0x2de979e5 , 0x0d1213ce ] )
+  , Mont::unsafe_make( [ 0x5576b890 , 0x2a533902 , 0x0fa2fd0b , 0x7f07e188 , 0x158a7aba , 0xc9dd3966 , 0x40c9a760 , 0x05a0257b ] )
+  , Mont::unsafe_make( [ 0xf74f8055 , 0x941b6e4b , 0xce9272c9 , 0x1927272d , 0x0562d96d , 0x863907af , 0x7ea02e99 , 0x2d43007b ] )
+  , Mont::unsafe_make( [ 0x14581585 , 0x936e861d , 0x364191ec , 0x85282b20 , 0x7d1513af , 0xb5340c3b , 0x89a1b9e4 , 0x0f2449ae ] )
+  , Mont::unsafe_make( [ 0x5272b9fd , 0x0bc5514b , 0x119202c3 , 0x05556402 , 0xb3806d7f , 0x7a623c2e , 0x869d7b7c , 0x13d69f25 ] )
+  , Mont::unsafe_make( [ 0xb163a298 , 0xfb26c8b0 , 0x51daf51f , 0xc206ba3d , 0x4976324b , 0x913155ca , 0x1df7a470 , 0x1c9cc74e ] )
+  , Mont::unsafe_make( [ 0x195f8abc , 0x4a761b65 , 0x48d3caee , 0x68e3b11c , 0x11f3b264 , 0x477a1638 , 0x6b893f2f , 0x24061a80 ] )
+  , Mont::unsafe_make( [ 0xfc83802a , 0x260f0010 , 0x01bdc6bc , 0x0db12fc9 , 0x83048e05 , 0xb053e8c0 , 0x49be2075 , 0x304e4f1a ] )
+  , Mont::unsafe_make( [ 0x66a88591 , 0x3c621fdf , 0x632b6e58 , 0xb0c9a7e7 , 0x13dd2622 , 0x8e46b57d , 0xae140edf , 0x238080a0 ] )
+  , Mont::unsafe_make( [ 0x196218ed , 0x8ea54676 , 0xcb2ab53e , 0xec3d75e9 , 0x2a555f40 , 0x9a31058b , 0x7c4bf1c0 , 0x29f83771 ] )
+  , Mont::unsafe_make( [ 0xf95067c5 , 0x62e0c990 , 0xa3d175df , 0x485f2e56 , 0xcb5bba3a , 0x799a6140 , 0x00a571fc , 0x2bb11c7d ] )
+  , Mont::unsafe_make( [ 0x9ef1b043 , 0xf67d61d8 , 0x7bf8be5c , 0xda5050e3 , 0x9fd2aa17 , 0xf5bd618e , 0xe435eee0 , 0x0cf1fe7b ] )
+  , Mont::unsafe_make( [ 0xee22fb4a , 0x24683327 , 0x079169c8 , 0xd394ea00 , 0x1e81216b , 0xa0e7bb10 , 0x2f412abe , 0x277b14e0 ] )
+  , Mont::unsafe_make( [ 0xf90fb37d , 0xd2ab1ec6 , 0xe74bf43e , 0x11b2cf17 , 0x207e8be2 , 0x5aa53915 , 0xcb58449c , 0x200c267c ] )
+  , Mont::unsafe_make( [ 0xd7238e37 , 0x5cdc5247 , 0x23b0b896 , 0x8be51ec1 , 0x609d20bc , 0x117f3098 , 0xa727931a , 0x1fe1db67 ] )
+  , Mont::unsafe_make( [ 0x228359b1 , 0x0c296687 , 0x12128f77 , 0x0d09515b , 0x4eff7beb , 0x638fa194 , 0xa4c31d10 , 0x0b4686b9 ] )
+  , Mont::unsafe_make( [ 0x841be30d , 0xa08d0245 , 0xa1b8b838 , 0xe4ae9707 , 0xd58e70bf , 0x3d7339cc , 0x1103240c , 0x12dfedf9 ] )
+  , Mont::unsafe_make( [ 0x97a98e01 , 0xf0e42d91 , 0xbb2663f9 , 0xae8ddee1 , 0xdade2c28 , 0xcc343801 , 0x03757fc1 , 0x101be7e6 ] )
+  , Mont::unsafe_make( [ 0xa693a5de , 0xa52adfbd , 0xe0a603a6 , 0x244bfd41 , 0x73f11a16 , 0xf074737b , 0x2c311092 , 0x22eb67e3 ] )
+  , Mont::unsafe_make( [ 0x56a802ad , 0x0825415c , 0x5650fdc1 , 0x00313cfd , 0x5b48e9f5 , 0x0ed48490 , 0x218e47a7 , 0x2b400fce ] )
+  , Mont::unsafe_make( [ 0xa00f942c , 0xdf76f239 , 0x2602bdee , 0xa7b0f70a , 0x698f41fd , 0x90bcaed9 , 0x34774a97 , 0x2d53c06b ] )
+  , Mont::unsafe_make( [ 0xe017b8c7 , 0x3ab21841 , 0x62cb1d29 , 0xaf8d5b87 , 0x8d93bb5c , 0x0ace70d1 , 0xa7e0b7c4 , 0x233d0733 ] )
+  , Mont::unsafe_make( [ 0xb4a611bb , 0xb0f5cba3 , 0xa5f354db , 0xb58aeeb6 , 0xc3463a95 , 0xf0974a60 , 0x09df0a14 , 0x2f65bb35 ] )
+  , Mont::unsafe_make( [ 0x8eac57d9 , 0xfed52a9f , 0xceeb8e97 , 0x3fba35a4 , 0xa5350503 , 0x218c6317 , 0x05723b48 , 0x2614eb33 ] )
+  , Mont::unsafe_make( [ 0xa5022e4e , 0x97e2d111 , 0xdcbaecf0 , 0xc2a48fcb , 0xa00a4310 , 0x43ef605e , 0x2df73e4f , 0x2a0095e4 ] )
+  , Mont::unsafe_make( [ 0x84735bd7 , 0x3f5460e5 , 0x104c6a90 , 0x7ab64abb , 0x0179ec6b , 0xaed1d42f , 0x4799b527 , 0x10cfeef4 ] )
+  , Mont::unsafe_make( [ 0xa03a80fb , 0xffc74b86 , 0xd1fe9eb1 , 0x1d9a92c2 , 0xe84c666e , 0xe20ccf8e , 0xf9f69ef8 , 0x2a10b8cf ] )
+  , Mont::unsafe_make( [ 0xab848f73 , 0x49de110e , 0x4c4523ec , 0x73c42eea , 0xf2828669 , 0xf3692246 , 0xc32a9742 , 0x0685d177 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x5bc23cf1 , 0xfda4329e , 0x956633fe , 0x24987e26 , 0xc1373c23 , 0xe6d49957 , 0x52a794b3 , 0x0a47942e ] )
+  , Mont::unsafe_make( [ 0x2e83a4d3 , 0x79569d15 , 0x2dcafb6e , 0x50009bd1 , 0x00fc012e , 0xd71801b0 , 0x2b0c735f , 0x24977695 ] )
+  , Mont::unsafe_make( [ 0xfb672bf3 , 0xa94d10ad , 0xd1ded79e , 0x1a415746 , 0x71a29060 , 0x616b04f2 , 0x85d723cf , 0x1cdbc380 ] )
+  , Mont::unsafe_make( [ 0x3d69105e , 0x6bff485f , 0x788644e2 , 0x594f3222 , 0x60d7628a , 0x60c5b306 , 0x586c2ff5 , 0x1d37748b ] )
+  , Mont::unsafe_make( [ 0x7c50b8d1 , 0xefff7bc4 , 0xd34ba61d , 0xf2b88917 , 0x4ec37ee6 , 0xf7a86d42 , 0xc2034198 , 0x28455599 ] )
+  , Mont::unsafe_make( [ 0xb737cb6e , 0x6525856f , 0x5d320e5d , 0xe14b3473 , 0x945f17aa , 0x1323ea0f , 0xa7a5c90b , 0x1c6bbf9a ] )
+  , Mont::unsafe_make( [ 0x2ced2cf9 , 0x742d39dc , 0xd8fdd73f , 0xf66c33d4 , 0xae2be45a , 0xbbf1c289 , 0x34c94c03 , 0x11e25901 ] )
+  , Mont::unsafe_make( [ 0x6f6f9ae4 , 0xb9a4c339 , 0x1a45dd30 , 0x1a78624a , 0x78b629a0 , 0x65923b1b , 0x2899c652 , 0x08777ba0 ] )
+  , Mont::unsafe_make( [ 0xfb140259 , 0x4265f7ea , 0x2d7411ac , 0x36e068a6 , 0x0923bbb7 , 0x3a8b9e61 , 0xaeaa44f7 , 0x06e8010a ] )
+  , Mont::unsafe_make( [ 0x2e265904 , 0xe5dad66d , 0x524787a4 , 0x415a6d5a , 0xb685a435 , 0xb7fa2c57 , 0x3fcb4e4e , 0x01e0f1b7 ] )
+  , Mont::unsafe_make( [ 0x38219744 , 0x2dc6610f , 0xed8df8a5 , 0xca293a27 , 0xab6bacc8 , 0x6110c5f0 , 0x94513511 , 0x09a4be41 ] )
+  , Mont::unsafe_make( [ 0x2c86ff59 , 0xf7287c25 , 0x17b89352 , 0x4968c03c , 0x00beec91 , 0xc69ab9c7 , 0x79289d9a , 0x2fa2d9b4 ] )
+  , Mont::unsafe_make( [ 0xc5855994 , 0xba319eb3 , 0x35d1268f , 0x82d48fa1 , 0x616e62eb , 0x812c0a16 , 0x4ebc2d36 , 0x110b1250 ] )
+  , Mont::unsafe_make( [ 0x945a4370 , 0xda029531 , 0x370e4d02 , 0x2e65a570 , 0x8a10501e , 0xc57801ee , 0xb3176304 , 0x1e4bc109 ] )
+  , Mont::unsafe_make( [ 0xebf6a5e5 , 0xc44e81f9 , 0x3e973fe5 , 0xb9f25a6e , 0x7bf3e2e1 , 0xb83a23a9 , 0x34de399f , 0x248e0683 ] )
+  , Mont::unsafe_make( [ 0xd5d9324f , 0x10785fc6 , 0x24674eb3 , 0x7bfda387 , 0x2399a17f , 0x09d9e2c0 , 0xd88c2295 , 0x008b5798 ] )
+  , Mont::unsafe_make( [ 0x223a22ef , 0xc0d3ab74 , 0x4d99b048 , 0x89271ae8 , 0xb988f9f7 , 0x32607414 , 0x9f5ab95c , 0x04d4f0c6 ] )
+  , Mont::unsafe_make( [ 0x3803c823 , 0xdfb54567 , 0xb9f1a565 , 0x7df949b3 , 0x4e791593 , 0x86a665cd , 0x66fc7ca8 , 0x2cd6307b ] )
+  , Mont::unsafe_make( [ 0x6d0853be , 0x9fa0c92a , 0x2f54d707 , 0x7e14e328 , 0xbb74fb7a , 0xea95b603 , 0xc607fe4f , 0x0f584632 ] )
+  , Mont::unsafe_make( [ 0x6cc90a6a , 0x977f9ac8 , 0x44b9dca8 , 0xbdbb6e05 , 0xbadf4286 , 0x7583685b , 0xdd1eeb64 , 0x087b3af6 ] )
+  , Mont::unsafe_make( [ 0x7e61ffea , 0x999fb115 , 0xff6d722d , 0x1c2b1f51 , 0x58af10e4 , 0xbab1c65e , 0x5ed6469b , 0x1e42f9aa ] )
+  , Mont::unsafe_make( [ 0x85412b1b , 0x1d713cc3 , 0x14197ef6 , 0x50b4a5ef , 0xb77e1067 , 0x999c71a6 , 0x7741ff13 , 0x04920299 ] )
+  , Mont::unsafe_make( [ 0xec817244 , 0xed83366c , 0x0ef8617c , 0x15f6871d , 0x28802c18 , 0xe4117870 , 0x90a21828 , 0x1271bfa0 ] )
+  , Mont::unsafe_make( [ 0xdb8b2135 , 0x03c854df , 0xa80b29ed , 0x5b72f6d0 , 0x7a21122f , 0x557f6393 , 0xce71d6c1 , 0x14953fc0 ] )
+  , Mont::unsafe_make( [ 0x500af613 , 0x3a02e0e0 , 0x3f349cd9 , 0xeb6088b6 , 0xf96cd2a8 , 0x6e1e76bd , 0x68c45f3c , 0x257210ce ] )
+  , Mont::unsafe_make( [ 0xa7357a97 , 0xa826faba , 0x48a57e02 , 0x181b29ab , 0x538c5af7 , 0xb8de8645 , 0xa6c98dbe , 0x1af8198f ] )
+  , Mont::unsafe_make( [ 0xfd292291 , 0xf1e13ecf , 0x54fbeef2 , 0x8cb020b9 , 0x41d97835 , 0x466a9b41 , 0xbe7d1a09 , 0x10951d1a ] )
+  , Mont::unsafe_make( [ 0xb1338d6a , 0x9dc175fe , 0x2ef6e661 , 0x0531179c , 0xfb093a87 , 0x653d4b70 , 0x51c27866 , 0x030e15a5 ] )
+  , Mont::unsafe_make( [ 0xabf83ca7 , 0xdab1cd47 , 0x7d37357d , 0x005183d1 , 0x07235014 , 0x94cffba9 , 0xca65ae74 , 0x1f52e50e ] )
+  , Mont::unsafe_make( [ 0x1350253e , 0xb0af1e3a , 0xc7a0dfcf , 0xe699d1e7 , 0xe7ce3c13 , 0x635c8c15 , 0x413f48bb , 0x17631752 ] )
+  , Mont::unsafe_make( [ 0x64c98994 , 0x0b2844b3 , 0x5ea0d40e , 0x98808585 , 0x71f15282 , 0x97007c66 , 0xd642be84 , 0x20d4b4d9 ] )
+  , Mont::unsafe_make( [ 0x0d54cd18 , 0x0447f33d , 0xe1c4986a , 0x7c74ebaf , 0x6ce4dce3 , 0x138f843e , 0xfda00dbd , 0x1a262dc8 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x44d1c021 , 0x94e4fb28 , 0xbc32833c , 0xd57227a3 , 0x2f8768b0 , 0x8d463ab1 , 0x9ff35674 , 0x14ab5a17 ] )
+  , Mont::unsafe_make( [ 0xb8df0c68 , 0x5068e7e4 , 0xb6d4d59b , 0x3a173c56 , 0xde73b4b7 , 0xf2aaa108 , 0x50e94677 , 0x14d7af71 ] )
+  , Mont::unsafe_make( [ 0xc04be0b2 , 0xa3f57353 , 0xe6332e31 , 0x908347a0 , 0x0571a1e6 , 0x49952499 , 0x88878935 , 0x194d36bc ] )
+  , Mont::unsafe_make( [ 0xa08db3ca , 0x72be9a71 , 0xa705331a , 0xb4048fa4 , 0xe5288eee , 0x7558b533 , 0xabe13ac7 , 0x15ceed20 ] )
+  , Mont::unsafe_make( [ 0x0ecdf9a6 , 0x6510bd99 , 0x36f9a48e , 0x964e6219 , 0xe61da087 , 0x33f64c1b , 0x3a843a46 , 0x1d68c928 ] )
+  , Mont::unsafe_make( [ 0x77e34394 , 0x13a8fa07 , 0xa829e024 , 0x7be9295f , 0xe6421300 , 0x992ef230 , 0xd7d2969e , 0x280997be ] )
+  , Mont::unsafe_make( [ 0x5547e628 , 0xafbf098e , 0x3c6bc313 , 0xc2f42df5 , 0x8f3262b2 , 0x154ee26d , 0x3b7500f8 , 0x160f350b ] )
+  , Mont::unsafe_make( [ 0xd386362e , 0x8067693c , 0xc1545b9e , 0x14f01f8f , 0x4737dfd8 , 0x3e4e2341 , 0x2912c8a0 , 0x1b021815 ] )
+  , Mont::unsafe_make( [ 0xa66e81c4 , 0x5c66992e , 0xbe105747 , 0xcb51aeeb , 0x218b6a6b , 0x5ab6a050 , 0xfebc4c4a , 0x1307136e ] )
+  , Mont::unsafe_make( [ 0xa43926e8 , 0x1a736091 , 0x5e7c8f0f , 0x352b6b21 , 0x771d542e , 0x57be415f , 0x631294b9 , 0x153c16f2 ] )
+  , Mont::unsafe_make( [ 0x256fca56 , 0xe506a3f2 , 0x6c777464 , 0x929d059a , 0x65580659 , 0x9acdb96b , 0x25d61d0c , 0x0e204a24 ] )
+  , Mont::unsafe_make( [ 0xe25b557d , 0x3e67f8fa , 0xa53b1a33 , 0x1911f043 , 0x4edc227d , 0xe53f8734 , 0x01de22bc , 0x25d78b10 ] )
+  , Mont::unsafe_make( [ 0x3bf3c675 , 0x7e53728b , 0x5b28ab63 , 0x87d9d23a , 0x15bfda23 , 0x1f0f0535 , 0x85011539 , 0x06068954 ] )
+  , Mont::unsafe_make( [ 0x4b24cddc , 0x3b2a163d , 0xa3ef11e2 , 0x305667a0 , 0x3a394ae8 , 0xcafe9039 , 0x654c8e59 , 0x30525527 ] )
+  , Mont::unsafe_make( [ 0x1effa263 , 0x0c96ffaa , 0x0358021b , 0xe14cd541 , 0xc5779487 , 0x4af8a369 , 0x48cd9a61 , 0x10a2a39a ] )
+  , Mont::unsafe_make( [ 0x1c05b72f , 0xf0d72237 , 0x63e71f0b , 0x4a69988e , 0x0e1d45ce , 0xaba8650a , 0x3baa2094 , 0x2fe6ddeb ] )
+  , Mont::unsafe_make( [ 0xd3424e8c , 0x3d1c6d54 , 0xa75d5fec , 0xc21a2f1b , 0x0bbb28f5 , 0x5b5c6c29 , 0x2056f3c4 , 0x073aa770 ] )
+  , Mont::unsafe_make( [ 0x6ed4de1c , 0x724a00d8 , 0xfcd7c0f8 , 0xa4f47d7b , 0xac1eb456 , 0xc746a253 , 0x7fcf07c4 , 0x190b5cf0 ] )
+  , Mont::unsafe_make( [ 0x61bed89b , 0x8256533b , 0x4111dbe6 , 0x2ead7665 , 0x556924f7 , 0x41570fa4 , 0x039ec0fc , 0x0f42e534 ] )
+  , Mont::unsafe_make( [ 0xc72f9ea8 , 0x0bc2007b , 0xcd1c76a6 , 0x42d6e915 , 0x6847803b , 0xd16f94e7 , 0x4c2a6e7f , 0x0f250151 ] )
+  , Mont::unsafe_make( [ 0x58a2be94 , 0xb07969a0 , 0x1322ecf6 , 0xb0623000 , 0xf206ef07 , 0x98a83418 , 0x63ba3bae , 0x0248ca24 ] )
+  , Mont::unsafe_make( [ 0x51333b3f , 0x08bfc725 , 0x1b35b749 , 0x1eeec24b , 0x45163712 , 0x46a158e7 , 0x206d0075 , 0x1a97e5a0 ] )
+  , Mont::unsafe_make( [ 0xd5f2ac6a , 0xc7a4131e , 0xc83eb853 , 0x9c36d9d7 , 0x60810844 , 0xa6d5d9c0 , 0x52dbebf2 , 0x299adb57 ] )
+  , Mont::unsafe_make( [ 0xab030789 , 0x05bbe4aa , 0x1d8d401a , 0x949d4b62 , 0x3de1535c , 0x0209d91b , 0x8a4df581 , 0x278d6fe9 ] )
+  , Mont::unsafe_make( [ 0xfaa91f93 , 0x4404fa0f , 0x94cf6a43 , 0x025be235 , 0xf90c706d , 0xa0adf727 , 0x7337f0e5 , 0x28b37778 ] )
+  , Mont::unsafe_make( [ 0xe2373b08 , 0x656fe257 , 0xdac1f096 , 0x92fbaca4 , 0x23739bfe , 0x8f85426a , 0x2e491dc8 , 0x27b995bc ] )
+  , Mont::unsafe_make( [ 0xa9b2104e , 0xd4d37a4b , 0x5a077a22 , 0x30d145a4 , 0xbec67235 , 0x53b351f6 , 0xaa513de8 , 0x0859334b ] )
+  , Mont::unsafe_make( [ 0xc55e83bd , 0x5f94ed37 , 0x10aaf503 , 0x78b0169a , 0x47da874c , 0xac48c3c7 , 0x4f017b4d , 0x0324c3ff ] )
+  , Mont::unsafe_make( [ 0x1b0eec50 , 0x5b23ddd6 , 0xc54383cf , 0xb8c6fce4 , 0x8b972d5f , 0x77371fd7 , 0x9b36e527 , 0x18ea4530 ] )
+  , Mont::unsafe_make( [ 0xec65b298 , 0x3bf9d7f8 , 0xff62e929 , 0xa8f2f7e3 , 0x0aeb6d76 , 0x1e72a1e9 , 0xbebac759 , 0x0f2964d3 ] )
+  , Mont::unsafe_make( [ 0x12f93ac5 , 0xf7c37ab2 , 0x98ac62ae , 0x47caaba3 , 0x65c39d46 , 0x29884858 , 0xa8f86d3e , 0x2d366b11 ] )
+  , Mont::unsafe_make( [ 0x64c122e0 , 0x935546fb , 0x34cdf573 , 0xa2865afb , 0x3da91551 , 0xe0d5732c , 0x822fdbe1 , 0x1bb2b23e ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xdf9da29e , 0x4fbd0bab , 0xbe535468 , 0xc2e1e7f8 , 0x095cf472 , 0xc5d81238 , 0x45bfb6f2 , 0x25cdb4f3 ] )
+  , Mont::unsafe_make( [ 0xdac9e593 , 0x154b9969 , 0x75c064e3 , 0x8bcd4035 , 0x9d5a34f0 , 0xf06da9ee , 0x970a177f , 0x24947bc6 ] )
+  , Mont::unsafe_make( [ 0x719195d5 , 0xcc98970e , 0xc49d3e23 , 0x33721b48 , 0xe181d156 , 0x1b690e84 , 0x72e005c5 , 0x2ab3b360 ] )
+  , Mont::unsafe_make( [ 0x1bc2b445 , 0x982ffc61 , 0x48cf2385 , 0x72cdd38c , 0xf9702164 , 0x8ad6293c , 0xbe8b3a65 , 0x0f1c1a14 ] )
+  , Mont::unsafe_make( [ 0x831c1797 , 0xa0fe5196 , 0x2ba4370c , 0x3eff7788 , 0x47d9b51c , 0xba5e741f , 0x7b02eb41 , 0x14f66e53 ] )
+  , Mont::unsafe_make( [ 0x51cd19eb , 0x47e167b6 , 0xd769ad9a , 0x634b9c55 , 0x98e7becd , 0x9ce6bfa4 , 0xe8b316c2 , 0x0194ab4e ] )
+  , Mont::unsafe_make( [ 0xec87104d , 0x50e0d64d , 0xaff3450a , 0x9203b50a , 0x7499938b , 0xa1f2e5e0 , 0x85d2d502 , 0x09390d91 ] )
+  , Mont::unsafe_make( [ 0x72c431b7 , 0x51af6ee0 , 0xcefef221 , 0xabe39a5f , 0x92d6cd98 , 0x811597f5 , 0x5941ecc1 , 0x0f660a6a ] )
+  , Mont::unsafe_make( [ 0xe5e1712d , 0xb936a6f8 , 0x38d15c3b , 0x5aa53deb , 0x2834856f , 0xaf4d36bf , 0xbe0f5b62 , 0x1f2781ff ] )
+  , Mont::unsafe_make( [ 0x67db8068 , 0x9ba33cfa , 0x059ce3a7 , 0xbcc3738e , 0x9a3c85ed , 0x066c1fd5 , 0x42c64fe8 , 0x281db049 ] )
+  , Mont::unsafe_make( [ 0xe2ec958a , 0x7e31a119 , 0x0fb187ac , 0x6336733b , 0xd787ab7f , 0x44c5a34b , 0xf0d65c52 , 0x139f4c14 ] )
+  , Mont::unsafe_make( [ 0xc2a5a5d1 , 0x3cf1b9a4 , 0x2e61be17 , 0xff0671c9 , 0xa8d8ccf5 , 0x2a639c4c , 0xb9f8d5ce , 0x0cea51ea ] )
+  , Mont::unsafe_make( [ 0xc0e43815 , 0xc5f76418 , 0x4d159a7d , 0xd0e465db , 0x3eef57cf , 0x5384869d , 0x5dd8b614 , 0x0e5b2e7d ] )
+  , Mont::unsafe_make( [ 0xec358100 , 0x3c5131c5 , 0x5a245602 , 0x76bc026e , 0xdc351d3b , 0x8af12228 , 0x8c0502eb , 0x2f92b521 ] )
+  , Mont::unsafe_make( [ 0x8903f016 , 0xe82c5c10 , 0x196caec3 , 0x53387cfe , 0x0ff48ec3 , 0xd511fcb4 , 0x5b8f9930 , 0x098b187b ] )
+  , Mont::unsafe_make( [ 0x8120cc49 , 0x00c8faf4 , 0x877ec072 , 0x0f95bb8e , 0x973c8f34 , 0x427c759e , 0xafce6fa3 , 0x1431fcb7 ] )
+  , Mont::unsafe_make( [ 0x40b6ec76 , 0x30d9ef93 , 0x79a1fba3 , 0xd3cdca1c , 0x133a180e , 0x26b9ce9e , 0x2b5f2499 , 0x2f1e7259 ] )
+  , Mont::unsafe_make( [ 0x028b28fe , 0xb3ca1d03 , 0xbe03d29f , 0x64e3acbc , 0xfb400cdf , 0x4d961ad3 , 0xd6af91ba , 0x0670ed72 ] )
+  , Mont::unsafe_make( [ 0x53c26d41 , 0xfaa2d7b3 , 0xa53e3b17 , 0xa2e1e091 , 0x3c43de60 , 0xd0160b40 , 0x3a5a8e46 , 0x2e8ffb3d ] )
+  , Mont::unsafe_make( [ 0xd0202700 , 0x2d762c75 , 0x9d394c52 , 0xddea08d2 , 0x2e045bca , 0x2321aa84 , 0x6f50fab9 , 0x1b32dc8c ] )
+  , Mont::unsafe_make( [ 0x6e494c62 , 0x22084bed , 0xc99eb5e2 , 0xa7aaf70e , 0xa12f5513 , 0x0c8b2be9 , 0x89a399b1 , 0x165cff88 ] )
+  , Mont::unsafe_make( [ 0x68452977 , 0x19c33b2e , 0x473dd1ab , 0xa2cb3199 , 0x727ab924 , 0x4966ecc1 , 0x46eacbe1 , 0x119ecf25 ] )
+  , Mont::unsafe_make( [ 0xb9f61961 , 0x5e2a17e6 , 0x39c85eef , 0x00fa23f5 , 0x7a26f314 , 0x6c6465be , 0xd541a9b6 , 0x1966aafc ] )
+  , Mont::unsafe_make( [ 0x723d6dc2 , 0x9313920a , 0x399633f0 , 0x704416fb , 0x07bba224 , 0xc55c2d68 , 0x9e8f5817 , 0x0c98a93c ] )
+  , Mont::unsafe_make( [ 0x9dbbfd70 , 0x45284bb6 , 0xfe8dc969 , 0x3bdc6ab3 , 0x68d331d6 , 0xe4e00ec9 , 0x9cad277e , 0x2aab7202 ] )
+  , Mont::unsafe_make( [ 0xdf360a29 , 0x11276b99 , 0x9de3c580 , 0x97661513 , 0x75a91380 , 0x23bbd229 , 0xfc9edde5 , 0x00e9bd5f ] )
+  , Mont::unsafe_make( [ 0x686d62bd , 0x5bc7851d , 0xd249ed04 , 0x7a825190 , 0x73d2e2b1 , 0x830bb3ca , 0xf982994e , 0x0ca2afac ] )
+  , Mont::unsafe_make( [ 0x3c5ce95e , 0xa6f68a5a , 0x568f1805 , 0x9709b89f , 0x77b42023 , 0xc75e23e8 , 0xf44dc854 , 0x26368baf ] )
+  , Mont::unsafe_make( [ 0x698d3f99 , 0xfd2cf036 , 0xcf7d7842 , 0x78705c15 , 0xeadff675 , 0x140e6648 , 0x9fa41015 , 0x1ad902c1 ] )
+  , Mont::unsafe_make( [ 0x5fbb9c64 , 0x1e5888c6 , 0x8bc57bd9 , 0xd256c1c1 , 0x926030bf , 0xee5b4d6b , 0x4146f5f0 , 0x004c1109 ] )
+  , Mont::unsafe_make( [ 0xea5784f2 , 0x916a2835 , 0x8b729034 , 0xa75b1074 , 0x7b47a75e , 0xc149212f , 0x8e77bf46 , 0x11f1638a ] )
+  , Mont::unsafe_make( [ 0x29f6eaa6 , 0x45a9f7d3 , 0x007cba1b , 0xf069f2b7 , 0x46ccdf03 , 0x6cce3bdb , 0xfe48db7e , 0x13541451 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xcd4f5083 , 0x5972ba93 , 0xcb9a4d37 , 0x551fe687 , 0x57552e5e , 0x0606cd4e , 0x4938e7df , 0x0c7b8814 ] )
+  , Mont::unsafe_make( [ 0x135b4bd4 , 0xd8133244 , 0x45ba0b3d , 0x2bde2776 , 0xa925d71a , 0x73ba0562 , 0x45d881f1 , 0x2b1cf94f ] )
+  , Mont::unsafe_make( [ 0x4e5fb839 , 0xfbbd2e48 , 0x5ff24550 , 0x5c3fb209 , 0x9e6fae48 , 0xa3ca62ce , 0x7d224364 , 0x009bdd67 ] )
+  , Mont::unsafe_make( [ 0x5dce0930 , 0xbfce7628 , 0xc2859996 , 0x483573dd , 0x5ba01764 , 0xa05e8c06 , 0x0649543c , 0x13632c3d ] )
+  , Mont::unsafe_make( [ 0x63ecfc29 , 0x87ed5b41 , 0x827b6f6b , 0x5d0973b1 , 0xfd12c674 , 0x8ebcdce5 , 0xad132f08 , 0x09c61ba3 ] )
+  , Mont::unsafe_make( [ 0xbe8a04c6 , 0xced3feb7 , 0x2e26ee9e , 0xa3e22fd5 , 0xa518ce15 , 0xbbc5caec , 0x3d0d461e , 0x2892325f ] )
+  , Mont::unsafe_make( [ 0xec22da69 , 0x464e580e , 0x2a8b0735 , 0x228d4193 , 0x20748476 , 0xf345e766 , 0x32d3e2a9 , 0x03a4ebc4 ] )
+  , Mont::unsafe_make( [ 0xe15922dc , 0x0d4a4e7f , 0x7abc9e78 , 0xd4866313 , 0x647c28f7 , 0x41a34899 , 0xee44e5de , 0x05e8763b ] )
+  , Mont::unsafe_make( [ 0xeb994770 , 0x4fac8562 , 0x1046df00 , 0x95aae58b , 0x5455afd8 , 0x397b611c , 0xba2fb8df , 0x23584c16 ] )
+  , Mont::unsafe_make( [ 0x69c97f38 , 0x533cd9dd , 0x12e8d7fa , 0xb2575d23 , 0xc37a7d98 , 0x73dac388 , 0x17818622 , 0x0b4b9007 ] )
+  , Mont::unsafe_make( [ 0xe35e5865 , 0xa3c16938 , 0x8d023741 , 0x49d6c667 , 0xb609cd79 , 0xc6676708 , 0x01b923a1 , 0x19a12814 ] )
+  , Mont::unsafe_make( [ 0x00a0007c , 0xba43aec8 , 0x8753a88f , 0x1c366fc9 , 0x5bb91109 , 0x9adf82a8 , 0xe93221d4 , 0x2a5a0875 ] )
+  , Mont::unsafe_make( [ 0xd989168b , 0x330ae249 , 0xa8e83922 , 0x9c825e6f , 0xa3c1d5bf , 0x1475fa42 , 0x3dafe189 , 0x1762f0a8 ] )
+  , Mont::unsafe_make( [ 0x041d9a40 , 0x17b66500 , 0xa5d5e371 , 0xc46b09aa , 0x33754eff , 0x8ce6c987 , 0x96fabb6e , 0x05039d53 ] )
+  , Mont::unsafe_make( [ 0x43b6a57d , 0x1d88625f , 0x785ece9e , 0xd64c0783 , 0x5688b5ee , 0xaca9d721 , 0xc794de76 , 0x1edd4226 ] )
+  , Mont::unsafe_make( [ 0x2eefe34d , 0xa6f1088b , 0x8d132f47 , 0x1dcef843 , 0xce6f4ed4 , 0x93941763 , 0x3cb965b7 , 0x2481ecdf ] )
+  , Mont::unsafe_make( [ 0x9fce1801 , 0x8f8834af , 0xdd588a22 , 0x23eff65c , 0x119c710c , 0xfb0b2bdd , 0x1bf849f7 , 0x205e3c09 ] )
+  , Mont::unsafe_make( [ 0x1c255b1d , 0xe6033db2 , 0x67351bc7 , 0xa7e6d8a5 , 0x8658a489 , 0x812d67c3 , 0xca97b00f , 0x2b8da2c6 ] )
+  , Mont::unsafe_make( [ 0xe3047339 , 0x76e25819 , 0x9f5e1133 , 0x74b16f92 , 0x6f04164e , 0x6d8375ba , 0xbe21f92c , 0x22f56afd ] )
+  , Mont::unsafe_make( [ 0xd633eadf , 0xa9e77891 , 0x0a5cadce , 0x322641e4 , 0x89892d11 , 0x0f50603c , 0x23206de6 , 0x245a83d0 ] )
+  , Mont::unsafe_make( [ 0x69d00e5c , 0x2834e1e3 , 0x4b027ba8 , 0x241b991d , 0x070f3491 , 0x687ef520 , 0x0f63ffde , 0x1a346d93 ] )
+  , Mont::unsafe_make( [ 0x3d90627b , 0xf7a95de2 , 0x76f8dabb , 0xb844b6aa , 0xff369305 , 0xa436de6e , 0x46394d31 , 0x0dd46663 ] )
+  , Mont::unsafe_make( [ 0xcc060378 , 0x29def49b , 0xaaf11b79 , 0xa16682d0 , 0xe7359b63 , 0x1a208c12 , 0x0adca6cb , 0x04f485a0 ] )
+  , Mont::unsafe_make( [ 0x676078d1 , 0x029f87d7 , 0xe2f87743 , 0x1717ff85 , 0x1818614a , 0xa82b735b , 0xaf2b2088 , 0x00eda587 ] )
+  , Mont::unsafe_make( [ 0xb9c1e06c , 0xbeeb9509 , 0xcf6b7ca5 , 0x4e8a9b67 , 0xb8504e0d , 0xe79c91f0 , 0x8e6fb49e , 0x269e3a05 ] )
+  , Mont::unsafe_make( [ 0x32f0b29a , 0x20ad050d , 0xd0b8282f , 0xc1426fcb , 0x686cc341 , 0xc4564bfd , 0xb13d48af , 0x2cbef088 ] )
+  , Mont::unsafe_make( [ 0xb1871df8 , 0x3327c962 , 0x1c965691 , 0xf3f472cc , 0x6e69bfc2 , 0xda6ecdcb , 0x1bdb2a48 , 0x05d527a1 ] )
+  , Mont::unsafe_make( [ 0x8920259d , 0xdee6dfd8 , 0xc9a546eb , 0x45e9ea2d , 0xd2c0487e , 0xc29c7006 , 0xbb9a6f28 , 0x194047c7 ] )
+  , Mont::unsafe_make( [ 0x833bef19 , 0x9df30bd6 , 0x1afd9cd3 , 0x06fd9181 , 0x44bba87d , 0xde77f677 , 0xb262a1e1 , 0x012db8e2 ] )
+  , Mont::unsafe_make( [ 0x4d17ead1 , 0xa1e45748 , 0x45726028 , 0x7c279be3 , 0x75f388e6 , 0x0c6e73c4 , 0x338cb892 , 0x248ac0ed ] )
+  , Mont::unsafe_make( [ 0x3d883764 , 0xb15dc6d2 , 0x5a07e0e5 , 0x117d2c03 , 0xf4a4e43b , 0xf71b2688 , 0xb7e675ad , 0x2478d154 ] )
+  , Mont::unsafe_make( [ 0x657f0911 , 0x48677a47 , 0x8cf154fa , 0x780ad04e , 0x980ace10 , 0xbdc74e2f , 0x3651a804 , 0x21b74765 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xd273ac77 , 0xcac5fa3d , 0x7da64054 , 0xe960ce68 , 0x6a1092f1 , 0x1e12d38b , 0x2c6a5470 , 0x0b5fc9ba ] )
+  , Mont::unsafe_make( [ 0x1baa6a1c , 0xa8d6fbfb , 0x5ca26937 , 0xd9dc452e , 0xb420ec1b , 0x6eed4490 , 0x57cca19e , 0x1055f4b5 ] )
+  , Mont::unsafe_make( [ 0xd44468a2 , 0x3ed191cf , 0x428c4c4c , 0x6213dca2 , 0x39bf85c2 , 0x105c7275 , 0x3977ae9d , 0x08178d1f ] )
+  , Mont::unsafe_make( [ 0x645689aa , 0x87cf2864 , 0x5f6ce021 , 0xa81723f7 , 0xdb40cbc4 , 0x37c3cd0d , 0x70ce772a , 0x244900d2 ] )
+  , Mont::unsafe_make( [ 0xaca25fca , 0xdcfecf95 , 0xdeb19279 , 0xe5db1afa , 0x0d4ffa2b , 0x1a4ea678 , 0x651a5539 , 0x18c9ca96 ] )
+  , Mont::unsafe_make( [ 0xcc16d563 , 0x458dc8e0 , 0x7cbbf2f8 , 0x4b4d1c68 , 0xb4b072b7 , 0xf1a0972c , 0x0fdfcd37 , 0x0276e23d ] )
+  , Mont::unsafe_make( [ 0xb186075a , 0x0097c88e , 0xd0e79046 , 0xfc3e9b19 , 0x4cf47b77 , 0xfeb56b4a , 0xb59067c7 , 0x2f99731e ] )
+  , Mont::unsafe_make( [ 0x28ddbfe8 , 0xda2541b3 , 0xd962d36c , 0xd5372550 , 0xfc5a80da , 0x4e85a8b9 , 0x79f5b61f , 0x04539dc4 ] )
+  , Mont::unsafe_make( [ 0x4574d343 , 0x8ff3c52e , 0x658e564f , 0x28963ac2 , 0x8f4ffcd5 , 0x837a1ccc , 0x473153d4 , 0x1195c311 ] )
+  , Mont::unsafe_make( [ 0xb0bf975d , 0x1ae42ddc , 0xe2773d9c , 0x94d2519c , 0x15134025 , 0x9f9ae87f , 0x0a7799b8 , 0x14c33800 ] )
+  , Mont::unsafe_make( [ 0x44c31f47 , 0xc30a76f4 , 0x084587b1 , 0xa5618ab8 , 0xb4a3e832 , 0x4aa1359c , 0x5a9eed13 , 0x2f9e0df8 ] )
+  , Mont::unsafe_make( [ 0x9d6966f9 , 0x13064bf4 , 0x55004118 , 0xa0f3902c , 0xbfa73ac1 , 0x7a022582 , 0x6568cf31 , 0x0e053f5f ] )
+  , Mont::unsafe_make( [ 0x8f71924b , 0xd520178b , 0xd33f3d7e , 0xf6f300b7 , 0xa7908df2 , 0x1676861b , 0xc124cc63 , 0x22390f10 ] )
+  , Mont::unsafe_make( [ 0x679a0b6a , 0x0ac1e351 , 0x26ded4ad , 0xc2006c84 , 0x1325d713 , 0xa18772ec , 0xcbf3fc74 , 0x1e1c7d04 ] )
+  , Mont::unsafe_make( [ 0x2f19b5ea , 0x5a4d9c7b , 0x38f42c89 , 0xf2c201f3 , 0xe17358db , 0x1a9c85e0 , 0x5fc5b62d , 0x1bacd04e ] )
+  , Mont::unsafe_make( [ 0xf7b89de2 , 0xba3be9cc , 0xc9c62913 , 0xe848055b , 0x0cf99c75 , 0xb666fe28 , 0xb4ea43a0 , 0x10565f1b ] )
+  , Mont::unsafe_make( [ 0xa3fdfb9a , 0xf067cbaf , 0x70981f6f , 0x7a443df3 , 0xd2e7948d , 0xb1ec0438 , 0x81bf6def , 0x1cab8478 ] )
+  , Mont::unsafe_make( [ 0xc6522d4d , 0x78dcf278 , 0x3a153fbe , 0xa76be723 , 0x531398ec , 0x568f6880 , 0x4ef966c3 , 0x13275a59 ] )
+  , Mont::unsafe_make( [ 0x3f2ba9ec , 0x62125f7c , 0x6934b71e , 0x6cac4cb4 , 0x52fba796 , 0x7afacb4d , 0x10b56bb5 , 0x095714fc ] )
+  , Mont::unsafe_make( [ 0x8512bb70 , 0xde2a1e91 , 0x8ffd30a4 , 0xe5b56537 , 0xe3ea743b , 0xf1f1dfcd , 0xf0c21ead , 0x17fd82c4 ] )
+  , Mont::unsafe_make( [ 0xdcdb4121 , 0x1efd801d , 0x77613f88 , 0x43dc8f47 , 0xa7a845bd , 0x2858df7c , 0x3d18516f , 0x2dd84412 ] )
+  , Mont::unsafe_make( [ 0xe0dc7130 , 0x99dc3657 , 0xf7a933f7 , 0x8e943d33 , 0x4828c431 , 0x2c94df1c , 0x19dda479 , 0x0f3beb5d ] )
+  , Mont::unsafe_make( [ 0x563e8760 , 0x61d84274 , 0x557c1b23 , 0x9dc777e8 , 0xeb9ae04d , 0xc4f5b9b7 , 0xaea0b85c , 0x080893e7 ] )
+  , Mont::unsafe_make( [ 0x039657cb , 0x22022e58 , 0x8ef05b7d , 0xa6478224 , 0xf1393caa , 0xff65d5ed , 0x6287c774 , 0x13c07f25 ] )
+  , Mont::unsafe_make( [ 0x736d70bf , 0x2495d1d3 , 0x6d8c2ec9 , 0xae4f5e8d , 0x9992889f , 0x268cfa6f , 0x39953a0e , 0x2210f65e ] )
+  , Mont::unsafe_make( [ 0x8bcf59d1 , 0xb0104fa5 , 0x2262bcf6 , 0xfea2fc75 , 0xe9094a61 , 0xf3773314 , 0xda0f9185 , 0x2f5715d8 ] )
+  , Mont::unsafe_make( [ 0x81efe412 , 0x834f614d , 0xbc6f298b , 0xdeeb7ff4 , 0x6ece9992 , 0x6c45a378 , 0x8721d585 , 0x0d9c118a ] )
+  , Mont::unsafe_make( [ 0x559f309f , 0x7b9988de , 0x2de919e5 , 0xaf20b9b2 , 0xae2f3b70 , 0xf368bf84 , 0x584dcd72 , 0x0a1bdc4d ] )
+  , Mont::unsafe_make( [ 0xc3c930c8 , 0x8466a489 , 0x9151d49f , 0x56662ed6 , 0x6efd7342 , 0x365db32c , 0xa79f9b83 , 0x0e03afa0 ] )
+  , Mont::unsafe_make( [ 0xecf6675c , 0xc2f0b21d , 0x3b6166d2 , 0x9ccf5353 , 0x0b240a17 , 0xeefc8830 , 0x0ce052e6 , 0x0f2297d0 ] )
+  , Mont::unsafe_make( [ 0x39a5980f , 0x460b0c32 , 0x17efa955 , 0xfa6b169c , 0x6acac7d3 , 0xde0904c5 , 0x33f1898e , 0x21976d24 ] )
+  , Mont::unsafe_make( [ 0xb99c673b , 0x9acc6f7f , 0x81048f76 , 0xc51d8e10 , 0x68d534d3 , 0x3f1721a3 , 0xe8a99bfd , 0x2cce81b6 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x19127283 , 0x43b7fa39 , 0x4b995b52 , 0xf0562457 , 0x461e1b87 , 0x27f1617f , 0x25d7a6c8 , 0x19771d4a ] )
+  , Mont::unsafe_make( [ 0x618004ce , 0xdc51f2c5 , 0xd4903bcb , 0x84a04418 , 0x5b8ea117 , 0x382bd4f3 , 0x749cc690 , 0x301048f3 ] )
+  , Mont::unsafe_make( [ 0x24b4c036 , 0x00701768 , 0xcfdcda93 , 0xabdd091f , 0x3fd608bb , 0xe242bb5a , 0x7988662a , 0x3044d8c9 ] )
+  , Mont::unsafe_make( [ 0xd7f3fbd4 , 0x04b153bc , 0xf323c19a , 0x14527285 , 0x4e113215 , 0xf714dbd2 , 0x88b1acec , 0x16d1d8bb ] )
+  , Mont::unsafe_make( [ 0x7e075667 , 0x0a058751 , 0x50ddc66a , 0x246b4fec , 0x64cba53e , 0xa272c88d , 0x424c8fd5 , 0x1d8b0b77 ] )
+  , Mont::unsafe_make( [ 0x309cfc48 , 0x7914ff14 , 0x5216904a , 0xda7c54da , 0x4fd5760b , 0x57d0969e , 0x397c6445 , 0x2005ebb8 ] )
+  , Mont::unsafe_make( [ 0xd09a5de3 , 0xb3ad15e6 , 0x3b01ef70 , 0xebdd7c98 , 0x454bf3f8 , 0x45e6f9b7 , 0xdca0da1e , 0x092e1d8b ] )
+  , Mont::unsafe_make( [ 0x4ecd858c , 0xdb82489f , 0x008e38c3 , 0x6e61b207 , 0x4986b2b4 , 0x1a2c1eb0 , 0xbbb3a9d0 , 0x2498648d ] )
+  , Mont::unsafe_make( [ 0x79fc7ddc , 0x9ff79b5f , 0xae7c1fb6 , 0x978f180d , 0xaa7fd909 , 0x12e13052 , 0xc8b1482a , 0x1615c570 ] )
+  , Mont::unsafe_make( [ 0x9876bd17 , 0xdc0f7274 , 0x014e2981 , 0x9d5dc8be , 0x6df41e16 , 0x15a4a52b , 0xbc9ad60f , 0x19a5280d ] )
+  , Mont::unsafe_make( [ 0x9caf2d5f , 0x1d8c43f0 , 0x675835ab , 0x07f12ce7 , 0x4d43f570 , 0xaa85bea9 , 0x09218c88 , 0x1e54d238 ] )
+  , Mont::unsafe_make( [ 0x25334668 , 0xf9a59899 , 0xab3a9644 , 0x00397ae8 , 0xfbeae1e0 , 0x144a6951 , 0xd6de795d , 0x0bc04ab9 ] )
+  , Mont::unsafe_make( [ 0x86dc291b , 0x9d24dcbc , 0x6a570c90 , 0x8f4b6019 , 0xd4ca28c8 , 0x200daf42 , 0x7b5424c1 , 0x2cfb2cad ] )
+  , Mont::unsafe_make( [ 0x1577f6b7 , 0x95e62f73 , 0xf4a20758 , 0x4f5ee320 , 0xaa334a53 , 0x86101ba1 , 0x64e6834a , 0x2785231c ] )
+  , Mont::unsafe_make( [ 0x0739bf3b , 0x6018f617 , 0x34232b8a , 0xdd06f935 , 0xc096e999 , 0xe1f1dcf4 , 0x2d9c7a92 , 0x09b27e19 ] )
+  , Mont::unsafe_make( [ 0x32c4b6fc , 0xee36985c , 0xe4ecc7d5 , 0xdcb5dadd , 0xbbfeb978 , 0x68cd9eee , 0xdebeb97e , 0x2fd8c372 ] )
+  , Mont::unsafe_make( [ 0x9caee197 , 0x4d1f76a2 , 0x5a05a909 , 0x74150ee5 , 0x80a7848f , 0x9c982d40 , 0x70edefd9 , 0x20e2bc01 ] )
+  , Mont::unsafe_make( [ 0x260e218e , 0xa5e7dffe , 0xa23e5ab6 , 0x18470066 , 0xe1dc15f0 , 0xc6d0cdb0 , 0x5b392327 , 0x2d84ed7d ] )
+  , Mont::unsafe_make( [ 0xbcf14383 , 0x73290e61 , 0xbc4da6e8 , 0x9c545d88 , 0xdacaac99 , 0x41b7d29c , 0xf3a7b5a3 , 0x19c3ec0d ] )
+  , Mont::unsafe_make( [ 0xd5ae4a60 , 0x1c0fb6c1 , 0x649fea29 , 0xc9558d83 , 0x120ee12b , 0xf1cc380f , 0x3821067a , 0x0d3333f3 ] )
+  , Mont::unsafe_make( [ 0xd88c7fd7 , 0x70a68ebe , 0x2d0a41e5 , 0x35cd1e85 , 0xfaca65ed , 0xc89fd38b , 0xba26328c , 0x21a924f1 ] )
+  , Mont::unsafe_make( [ 0xd0090a14 , 0x7713c458 , 0xc11f1ce1 , 0x85f4177d , 0xfe269e87 , 0xd546f1fb , 0x092483cb , 0x027cd3f5 ] )
+  , Mont::unsafe_make( [ 0x273e3031 , 0xed626fc2 , 0xb46a4c94 , 0xeee0a5cd , 0x6b8b8231 , 0xed4297cd , 0x54cd33ed , 0x1b825563 ] )
+  , Mont::unsafe_make( [ 0x1a33532b , 0x9a7000ad , 0x04c91f9b , 0xc8605beb , 0x71bb6b14 , 0x00dd1a26 , 0xfc7e96cf , 0x26f7bfd5 ] )
+  , Mont::unsafe_make( [ 0x8403ba21 , 0x1d68aa19 , 0x3e4deed8 , 0x9227e07d , 0x1fafe18a , 0x04f43905 , 0x8fd8a43b , 0x301c59b9 ] )
+  , Mont::unsafe_make( [ 0xf5248fd4 , 0x6e3acfe6 , 0xfe4e3aff , 0x50989861 , 0xf25c574e , 0x6a9930ea , 0xe8bb4440 , 0x23fc96a3 ] )
+  , Mont::unsafe_make( [ 0xe8a4a060 , 0x84b5b365 , 0x69af676a , 0x4721fa94 , 0xb6fbfffa , 0xfe7bde0c , 0xbd6ac20b , 0x1fe13397 ] )
+  , Mont::unsafe_make( [ 0x1002ad29 , 0xe93a4fe5 , 0x0e47297e , 0xea61e1c4 , 0x2e44287f , 0x923017d9 , 0x00892ddc , 0x087f5f9a ] )
+  , Mont::unsafe_make( [ 0x6bcec2e7 , 0xcae8e9d2 , 0x15193740 , 0x92cfe6a7 , 0xa15d5e00 , 0x52407424 , 0xed09b8b3 , 0x22026244 ] )
+  , Mont::unsafe_make( [ 0x451e3eae , 0x2a85f951 , 0x2df227e7 , 0xd790d18c , 0xf07cd9dc , 0xef2282a7 , 0x4fc904ef , 0x1dd76886 ] )
+  , Mont::unsafe_make( [ 0x2f27c97b , 0x4aef5f0f , 0xd46f924b , 0xe527bd47 , 0x22d619b3 , 0xaeea1c90 , 0xad1ed05c , 0x197282f7 ] )
+  , Mont::unsafe_make( [ 0x18e7f82d , 0xdc8d8721 , 0x7233c43b , 0xd926bf16 , 0x1e9243cc , 0xcd8ad6cb , 0x3a2cf789 , 0x16d7bced ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x1cf3c934 , 0xf9219d5d , 0xa22d369c , 0x3d2e499a , 0xbd852e48 , 0x901abef3 , 0x4a26c59f , 0x1b3bcdf3 ] )
+  , Mont::unsafe_make( [ 0x01f26ba7 , 0x2e760942 , 0xd2691e72 , 0x45085e2e , 0xddbda0e9 , 0xfcce00d4 , 0xc79fa518 , 0x055a9d08 ] )
+  , Mont::unsafe_make( [ 0x1da46d53 , 0x6d11d839 , 0x0b61ce77 , 0x6e94eddb , 0x80a08f4b , 0xf8f611e1 , 0xb0368458 , 0x1e2c241f ] )
+  , Mont::unsafe_make( [ 0x6de76144 , 0xd7459c55 , 0x1c529d95 , 0x397f3954 , 0x6e572749 , 0xacc1c229 , 0xc7322142 , 0x2d825763 ] )
+  , Mont::unsafe_make( [ 0xb6af43c1 , 0x754a9911 , 0x38189028 , 0xaac3cb22 , 0x4b1bc01b , 0x1d00d38a , 0x2825597c , 0x1b9f0879 ] )
+  , Mont::unsafe_make( [ 0xd0c2fdd6 , 0xd8f9fc50 , 0x5a53c617 , 0xcff6314a , 0xda332c32 , 0x1b4f21de , 0x8f7e911d , 0x201f3774 ] )
+  , Mont::unsafe_make( [ 0x4bd7ee8b , 0x1d2910e6 , 0x5f0e7db9 , 0xa3b10154 , 0x7bfc518c , 0xc3204214 , 0xdc216276 , 0x1b3777f2 ] )
+  , Mont::unsafe_make( [ 0x00a3a922 , 0xcb7c3c1d , 0x52dc33c7 , 0x7debb76a , 0x5d934594 , 0xaf3cc20f , 0xe84dce35 , 0x104f31f3 ] )
+  , Mont::unsafe_make( [ 0x4efc6331 , 0x03d9de6d , 0x6e3fc06a , 0x62a4a101 , 0x82b6d275 , 0x2dc199bc , 0x80b50199 , 0x0efbdc64 ] )
+  , Mont::unsafe_make( [ 0x57efade7 , 0xd08d6094 , 0x98386df2 , 0x69b5df9a , 0x1bd90f22 , 0x447e8c1c , 0xea5f1eaf , 0x22291b65 ] )
+  , Mont::unsafe_make( [ 0x174700ce , 0xa8fa3e57 , 0xdcae4c6f , 0xa13efbab , 0xd93494bd , 0x83b528d2 , 0xdbe882e7 , 0x24378eb7 ] )
+  , Mont::unsafe_make( [ 0xc6e0d2bc , 0xc16db50f , 0x0b23b2a1 , 0x73a4a870 , 0x17ec0400 , 0x5d56f444 , 0x2db35e83 , 0x1f842e11 ] )
+  , Mont::unsafe_make( [ 0x1ee901f8 , 0x95d80702 , 0x764aa35c , 0x0e61854e , 0xceb45f42 , 0xcf07e803 , 0x886c2e76 , 0x2a53d725 ] )
+  , Mont::unsafe_make( [ 0xa8d6af07 , 0x8b74727f , 0xa1aa2663 , 0x6a44f694 , 0xfaee3348 , 0x65a17326 , 0x8e75c7e0 , 0x0f0edb62 ] )
+  , Mont::unsafe_make( [ 0x4b2cd56a , 0xa51e8dec , 0xe29d05e7 , 0x95ec2cc4 , 0x42dbe3a1 , 0x990773b4 , 0x45cad8cf , 0x0fbf5e98 ] )
+  , Mont::unsafe_make( [ 0x026e3711 , 0x3bda5c5f , 0x800f2a4d , 0xd7692c74 , 0xb8270825 , 0x0abad5a7 , 0xd50cc031 , 0x12fd10b0 ] )
+  , Mont::unsafe_make( [ 0x8b62b82f , 0x00536b87 , 0x98027cf9 , 0xa7a90f54 , 0x0e9dacfb , 0xe43ae53d , 0x7a69c030 , 0x303e324b ] )
+  , Mont::unsafe_make( [ 0x2cd5ee77 , 0x50049b76 , 0xd4f2ee77 , 0xe480d449 , 0xf700889a , 0x790af535 , 0x5af916ef , 0x1f9c58fd ] )
+  , Mont::unsafe_make( [ 0x7916d6ea , 0xacc68621 , 0x707bd8c9 , 0x640790a8 , 0x8de0649f , 0x85780c45 , 0x1fe1b131 , 0x14db1e21 ] )
+  , Mont::unsafe_make( [ 0x06a9c015 , 0x3bca256e , 0x7d1e3e0d , 0x54a910d0 , 0x537f9d58 , 0xc3de057e , 0x27d8a3dd , 0x08dea84f ] )
+  , Mont::unsafe_make( [ 0xa4447508 , 0xf5e84f2c , 0x14acccbb , 0xfd054250 , 0x2480a672 , 0x7c2277f3 , 0xc9e8b8e5 , 0x020916ff ] )
+  , Mont::unsafe_make( [ 0x5f922cf2 , 0x1d2919dd , 0x1fc9e037 , 0x5da71bdc , 0x406b8058 , 0xc0da7e95 , 0xf2bece33 , 0x2137940a ] )
+  , Mont::unsafe_make( [ 0x4ecf2dbb , 0xff08711f , 0x801b902a , 0xc6d82619 , 0xfe11ba06 , 0x3e20ddef , 0x1001a833 , 0x29ff60b1 ] )
+  , Mont::unsafe_make( [ 0xd63b87da , 0xf3a1a654 , 0x5947b99e , 0x41d24e00 , 0xc8f97738 , 0xce03d4f0 , 0x4ca6a02c , 0x24723e6a ] )
+  , Mont::unsafe_make( [ 0x48c27b29 , 0x2bbea914 , 0x421231b2 , 0x5a6c66fe , 0x9f866a32 , 0x3ce86d21 , 0x06c0ec20 , 0x0ab9e9f3 ] )
+  , Mont::unsafe_make( [ 0xdc1863d3 , 0xc24bbbc4 , 0xc34b532d , 0x73d7b19c , 0x29e1c7d3 , 0xac12097b , 0x1a0eafdf , 0x2d262b83 ] )
+  , Mont::unsafe_make( [ 0xff61582b , 0xa6072c79 , 0xcfcffa5a , 0xae3fa0d5 , 0x047eefe6 , 0x108bc208 , 0xa2c0f542 , 0x19c12f57 ] )
+  , Mont::unsafe_make( [ 0x0c663968 , 0x73692add , 0x095b3bf6 , 0x34323c2e , 0x6b50d93b , 0x561d42a1 , 0x2b7871cf , 0x0dc83ce9 ] )
+  , Mont::unsafe_make( [ 0xf4ab3391 , 0xb78c8120 , 0x60980bdb , 0x5e344a3c , 0x593ac596 , 0xa7ed3793 , 0x35d953df , 0x1d1126a4 ] )
+  , Mont::unsafe_make( [ 0xf241f284 , 0x2c8ab976 , 0x338a637d , 0x23427c5d , 0x047c2ab6 , 0x4c7ce051 , 0xa7414c0c , 0x1305caf1 ] )
+  , Mont::unsafe_make( [ 0x37ce13ce , 0x137b2aa6 , 0x9c170ba6 , 0xbc6b47f0 , 0xf2459d85 , 0x6712cf98 , 0xb73d4d78 , 0x2354da5e ] )
+  , Mont::unsafe_make( [ 0xeae5b28c , 0x4e45c914 , 0xf54f5ffc , 0x62598534 , 0x14fd1aad , 0x3cddd582 , 0x8ad7ba77 , 0x167bcab0 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x2536afce , 0x6647bd7f , 0x611866c5 , 0x8511ba68 , 0xd72a15da , 0x29c263ad , 0xb6dbcdf7 , 0x1f93c72c ] )
+  , Mont::unsafe_make( [ 0x62fba646 , 0x95f2a70c , 0xb029919a , 0x0e778a17 , 0xe1655113 , 0x82f7ca07 , 0xb03f4330 , 0x19fa8cb4 ] )
+  , Mont::unsafe_make( [ 0x0efa7e5a , 0xe547ac9c , 0xcd7008f3 , 0x8c62da46 , 0x1fc26f26 , 0x33f516fb , 0xc2ce86c8 , 0x2e080e00 ] )
+  , Mont::unsafe_make( [ 0x4d1dc876 , 0x2965aa24 , 0x533e268e , 0x8cbacb11 , 0x139dc6f0 , 0x4c1bebdb , 0x918cedeb , 0x0c2a61f8 ] )
+  , Mont::unsafe_make( [ 0xa722d65b , 0xfa7119a3 , 0x99022460 , 0x2ef59254 , 0xa80bbf09 , 0x85d07e56 , 0x8b621abb , 0x30230ba5 ] )
+  , Mont::unsafe_make( [ 0x227e699a , 0x6b28cbbd , 0x9d854586 , 0x7d35fd5a , 0x9eb603fa , 0x4771e419 , 0xc81cf550 , 0x0f2c9e81 ] )
+  , Mont::unsafe_make( [ 0xcf08f848 , 0xf7b3c9be , 0x8819c2fd , 0x6950ee18 , 0xdaad6a3a , 0x8dee3241 , 0x4db8ed5a , 0x1100156d ] )
+  , Mont::unsafe_make( [ 0xd97ee337 , 0x7fb59c6a , 0xebb91db8 , 0x3f084254 , 0x3425f9a0 , 0x3c90cf09 , 0x0bb7d32f , 0x08c31d63 ] )
+  , Mont::unsafe_make( [ 0xab1cfae9 , 0x812f4d70 , 0xcbea6041 , 0xc447d07b , 0xe0545bbb , 0x91d7fe86 , 0x75572872 , 0x22657831 ] )
+  , Mont::unsafe_make( [ 0x8be80184 , 0xa5c7ec57 , 0xcc40d3de , 0x94dc30b5 , 0xf3a0fa4e , 0x75363f0c , 0xb4e1b6a0 , 0x293b6bea ] )
+  , Mont::unsafe_make( [ 0x23fe2e92 , 0x38eb7030 , 0x72a48241 , 0x8cce77c7 , 0xb620c74d , 0x00a5a10d , 0xa2e06103 , 0x1ddc07b9 ] )
+  , Mont::unsafe_make( [ 0xb5ee2991 , 0x50d82326 , 0x1b860615 , 0xe9bf18a5 , 0x8daa3450 , 0x691305e8 , 0xe2319762 , 0x2c1377b1 ] )
+  , Mont::unsafe_make( [ 0xbc59d668 , 0x88a84a67 , 0x0f13664e , 0x3ab95930 , 0x439ee04e , 0x170b0772 , 0x98033cfb , 0x061c2a12 ] )
+  , Mont::unsafe_make( [ 0xdc918da4 , 0x3ac94c95 , 0x36cbd571 , 0xc5e1f703 , 0xdbd33df2 , 0xc652195e , 0x4259d7cb , 0x0a33cabe ] )
+  , Mont::unsafe_make( [ 0xecb2e1fa , 0x344485ba , 0x3d1c09f4 , 0x19f4abc7 , 0x7d68bfd7 , 0x9cfae0ac , 0x7f6fc6b8 , 0x251b89aa ] )
+  , Mont::unsafe_make( [ 0x882ff9f9 , 0xa46ed128 , 0x2c2e47d1 , 0x241963c6 , 0x1ed11c26 , 0xc9db292c , 0x67a6a7c6 , 0x2d527f6d ] )
+  , Mont::unsafe_make( [ 0xa95d3356 , 0x89cb220c , 0xdadac6bb , 0x36a60612 , 0xa2eecab5 , 0x9ae9490c , 0x7c71d0ab , 0x2b6ae890 ] )
+  , Mont::unsafe_make( [ 0x88da0aef , 0x239de76d , 0x69ce003b , 0x53fe49d9 , 0x7838ce7a , 0x88fa07f9 , 0x1c5ee3a1 , 0x1521340b ] )
+  , Mont::unsafe_make( [ 0x39b98270 , 0x0a06e192 , 0xcbcc439d , 0x132da89c , 0xcb53695a , 0x41b36714 , 0x1717c380 , 0x2b89880c ] )
+  , Mont::unsafe_make( [ 0x5f095b81 , 0x8c3598fb , 0xfbd22db2 , 0x94f61dc4 , 0x64bf0bfe , 0xab32728c , 0x0d469db0 , 0x23d52579 ] )
+  , Mont::unsafe_make( [ 0xf48faa16 , 0x78acb2c4 , 0x017dcdcc , 0x0363643b , 0xcc7f525e , 0x179cd03b , 0xde474c8c , 0x0d77db8b ] )
+  , Mont::unsafe_make( [ 0x7472fec6 , 0x06267a01 , 0x76f8eab6 , 0xa986844c , 0xe092fd22 , 0xc9a88f69 , 0xc96b398f , 0x28fdf4d6 ] )
+  , Mont::unsafe_make( [ 0x8807c3d2 , 0x6f692e43 , 0x89d7bba7 , 0xaa5baa08 , 0xca5b8e42 , 0xa3eb7099 , 0xb9050ed6 , 0x223f84fc ] )
+  , Mont::unsafe_make( [ 0xda73bb8f , 0x85a63b45 , 0x0686235a , 0x932ef98e , 0xa3ae7c64 , 0xb71cb479 , 0x15ee7ff2 , 0x07828222 ] )
+  , Mont::unsafe_make( [ 0xf3454730 , 0x0b6459fe , 0xdb05537f , 0xfce92941 , 0xc5e6e4c1 , 0x8da375be , 0x0be51c48 , 0x23b620d5 ] )
+  , Mont::unsafe_make( [ 0xf7f2b15c , 0x09329a02 , 0xeae4442b , 0x7b3f0b57 , 0xf999741f , 0x1c9adcef , 0xea2181f9 , 0x0127f008 ] )
+  , Mont::unsafe_make( [ 0x2bd24c5f , 0xd28ab5a4 , 0xe57124af , 0xc893b12e , 0xe5d3523e , 0xb119cdd4 , 0x8af01254 , 0x10a294ab ] )
+  , Mont::unsafe_make( [ 0xe22a8b18 , 0xfa39b12b , 0xbb62cf38 , 0x6f55ced2 , 0xe1927b62 , 0x1a2f91c5 , 0x6d65624a , 0x12e6175b ] )
+  , Mont::unsafe_make( [ 0xdf13a00d , 0xbe053107 , 0x15abc915 , 0x3ec33831 , 0xf6ff9c7d , 0x5d12fbe1 , 0xdf4918b1 , 0x2597f613 ] )
+  , Mont::unsafe_make( [ 0xb021a930 , 0xd0e76cb1 , 0x8646f8b5 , 0x86f57867 , 0xed8022c8 , 0xb1879b0c , 0x148c8da2 , 0x04352357 ] )
+  , Mont::unsafe_make( [ 0x0a3f8cc6 , 0x7c690c86 , 0x215c9aea , 0x296b65b3 , 0xd21cde81 , 0x661279c9 , 0x61726663 , 0x113d39fc ] )
+  , Mont::unsafe_make( [ 0x2f53efeb , 0xdbdc1a12 , 0x6f1b4edb , 0x5770be3d , 0x2697ccb1 , 0x2078f000 , 0xb2ae43d1 , 0x2ead9c64 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x9ea3ca3b , 0x25d4c403 , 0xcfa9f447 , 0x4cae40e4 , 0xfe1d6e03 , 0x1efbd176 , 0x235a5807 , 0x2f29c1d8 ] )
+  , Mont::unsafe_make( [ 0xfbbd93d5 , 0x97fdf2f3 , 0x44278cc0 , 0x2d46ee96 , 0x4a148570 , 0xbbcea82d , 0xe7fa0e58 , 0x08bf857a ] )
+  , Mont::unsafe_make( [ 0x3a1368a7 , 0x0c26fbeb , 0xaf3488c7 , 0x5d0a1880 , 0x7d428159 , 0xd8d1f7fc , 0x0b0d8623 , 0x08ba42bf ] )
+  , Mont::unsafe_make( [ 0xc7f59533 , 0x0debf342 , 0x1578a167 , 0x493e74e6 , 0x5cd3a341 , 0x9cdb6954 , 0x5e7c8c52 , 0x0a743b10 ] )
+  , Mont::unsafe_make( [ 0xf8c8263b , 0x364e75d4 , 0x46ca5b3e , 0x296ae4b7 , 0x74a1838e , 0xce3b2639 , 0x23db7f51 , 0x00562b8d ] )
+  , Mont::unsafe_make( [ 0x87b76566 , 0xd7035b67 , 0xa9272ab6 , 0x77430340 , 0x510330e5 , 0x2744daf0 , 0x654742c9 , 0x0d35fc89 ] )
+  , Mont::unsafe_make( [ 0xf9633ad9 , 0x5295c645 , 0xb33bec08 , 0xf6b32ad8 , 0x4d31f00a , 0xaac38ce0 , 0xe1e193d9 , 0x053d08b7 ] )
+  , Mont::unsafe_make( [ 0x7c941692 , 0xd85a7299 , 0x9d661193 , 0x53e482e1 , 0x247dce5c , 0xe787459b , 0x67cfbab8 , 0x273dc48c ] )
+  , Mont::unsafe_make( [ 0x40913388 , 0xdde4f021 , 0x314a788b , 0x9aa9efbf , 0xc51b1d68 , 0x986b3181 , 0xc4b0c393 , 0x17c8d395 ] )
+  , Mont::unsafe_make( [ 0x8639a332 , 0x34da7f14 , 0x359d2a72 , 0xfbd587ff , 0xb04dfadb , 0x73a4fecf , 0x6c68c13b , 0x26a5cf83 ] )
+  , Mont::unsafe_make( [ 0xd62cd828 , 0x1de9c615 , 0x5e05d306 , 0x4d78ad90 , 0x38259008 , 0xad8b0413 , 0xc1ff1773 , 0x1211966a ] )
+  , Mont::unsafe_make( [ 0x19e73865 , 0x45c894a1 , 0x211d0748 , 0x50c015fd , 0xc8bb0529 , 0x64835d6d , 0xfce1fcf5 , 0x1ad1b421 ] )
+  , Mont::unsafe_make( [ 0x22144a14 , 0x51662be4 , 0x7baa00ee , 0xd5a0300b , 0x7422becf , 0xf53c698b , 0x9f08ee0b , 0x2fdf8734 ] )
+  , Mont::unsafe_make( [ 0xc18436fa , 0x3ec49448 , 0x493d1336 , 0xc9aeb6b6 , 0x403bfc42 , 0x99d8696a , 0x61dbba5d , 0x2b1b20eb ] )
+  , Mont::unsafe_make( [ 0x053c3ec6 , 0xc5cb8db0 , 0x34dd74d3 , 0x56448063 , 0xeead4ee0 , 0x0a697cee , 0xc6db4735 , 0x0853702c ] )
+  , Mont::unsafe_make( [ 0xcf01931f , 0xa7ed7c32 , 0x9d3f0e8d , 0x7daad07d , 0x744e3531 , 0x137adee5 , 0x1ea776cb , 0x225186f4 ] )
+  , Mont::unsafe_make( [ 0x89948d11 , 0xa24220dc , 0x92a3d4bb , 0x02baa099 , 0x56d30b04 , 0x32dca787 , 0xae4eec51 , 0x1f6fecd3 ] )
+  , Mont::unsafe_make( [ 0x87886ecc , 0x82d97c79 , 0x2fc87dab , 0xe45d0a7b , 0x4e5f5038 , 0x0f7680a3 , 0x7d09f815 , 0x18416159 ] )
+  , Mont::unsafe_make( [ 0x8f959b09 , 0x5235db1f , 0xdf0bf22f , 0x805906e8 , 0x357ad9ec , 0xee03e015 , 0xe59f63c6 , 0x04a3a949 ] )
+  , Mont::unsafe_make( [ 0xa5853bb7 , 0x9162aff1 , 0x1f1c3e3e , 0x71fb7521 , 0x744e0af6 , 0x5209f7ef , 0xc88024b2 , 0x2886f626 ] )
+  , Mont::unsafe_make( [ 0xd8dc7dbe , 0xcbb273f0 , 0xe69eb8df , 0xbc4f5b3c , 0x6b2ca176 , 0x23c6d5cd , 0x5679b552 , 0x09407afd ] )
+  , Mont::unsafe_make( [ 0x246d7039 , 0x44d647d9 , 0x80e73bd5 , 0x126655ca , 0x62a2fd4e , 0xd7110dd8 , 0xdc0aff8d , 0x05eb73b1 ] )
+  , Mont::unsafe_make( [ 0xe67e2a90 , 0xe8ea0dbc , 0x19aa4011 , 0x672b1f01 , 0x7885b9a3 , 0x8fcd7c0b , 0x333ade97 , 0x214cac7b ] )
+  , Mont::unsafe_make( [ 0xbf0248c3 , 0x2c96d142 , 0x95b9321c , 0x006f3b63 , 0x104b2131 , 0x8565fbab , 0xa96b77a0 , 0x2ac21547 ] )
+  , Mont::unsafe_make( [ 0xeb2babfe , 0x534581f5 , 0x1d177bb1 , 0x9b27b14f , 0x0b1f856f , 0x0b00d7b8 , 0x15bd6311 , 0x017e3171 ] )
+  , Mont::unsafe_make( [ 0xfc99712e , 0xed5ca7db , 0xb3656520 , 0xecab3518 , 0xaacf9006 , 0x2dd0a95d , 0x2b8eddaa , 0x22b636d7 ] )
+  , Mont::unsafe_make( [ 0x3c1016bb , 0x87320125 , 0xf39d4962 , 0x0d2a2abe , 0x31a46675 , 0xae82ce69 , 0xb6209e83 , 0x0d32c3b4 ] )
+  , Mont::unsafe_make( [ 0x65c08f59 , 0xf788ee53 , 0xcdb1df8c , 0xe0e19d54 , 0x4c03f4f4 , 0x38f2439e , 0xdc7d6b53 , 0x12ab5b56 ] )
+  , Mont::unsafe_make( [ 0x8f0870c1 , 0x9c60b8ba , 0xc55c1695 , 0xa523b3c6 , 0xdcfb9459 , 0x3f1e57f4 , 0x93faf893 , 0x28e6c565 ] )
+  , Mont::unsafe_make( [ 0xfafe0360 , 0x6a8a734e , 0x29ba83f2 , 0xc78ce97a , 0xb85244b7 , 0xd8ebcb29 , 0xbf0fd2d8 , 0x26208a16 ] )
+  , Mont::unsafe_make( [ 0x519784bf , 0xa941b835 , 0x7ee95fdc , 0x1b572bc2 , 0x5b578795 , 0x3b8a16b4 , 0xb7b1d863 , 0x28bff49f ] )
+  , Mont::unsafe_make( [ 0xcb0db6d6 , 0x042aefbf , 0x80d4a591 , 0x7774fa82 , 0xd3541184 , 0x6a1ad9db , 0x4d32b7c2 , 0x07c058dc ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x489be5bd , 0x2c2a0fe4 , 0xadf72ba8 , 0xd5e6641b , 0xf881bc15 , 0x7b482c90 , 0xd70994d8 , 0x122330c4 ] )
+  , Mont::unsafe_make( [ 0x593c7204 , 0x53967063 , 0x7cd85444 , 0x9161ba5f , 0x80795698 , 0x407b748f , 0x3fe6d817 , 0x08776eff ] )
+  , Mont::unsafe_make( [ 0x70aa895d , 0xd690efd2 , 0x095631a4 , 0x7cda2640 , 0x8ce0d2e1 , 0xdaa53ed0 , 0x31b537ac , 0x1068c4db ] )
+  , Mont::unsafe_make( [ 0xc246fbd4 , 0x38d33ee1 , 0x5d07dd8d , 0xbe724440 , 0x2cc71ad0 , 0xbb5449e0 , 0x97a08614 , 0x0a932673 ] )
+  , Mont::unsafe_make( [ 0x2c9e8df7 , 0x5fe76cf1 , 0xd95125fd , 0x19ce530f , 0x39c15425 , 0x6752f2fb , 0x24246d15 , 0x1b8d0325 ] )
+  , Mont::unsafe_make( [ 0xd72b35ae , 0xd020f74a , 0xc7ca80f2 , 0x1addfcf5 , 0xd35f3636 , 0x05045ac7 , 0x0a7c8a1b , 0x2e62ec0d ] )
+  , Mont::unsafe_make( [ 0xec65feca , 0x75b05a10 , 0x73c34231 , 0xde2ba548 , 0x798a6cb6 , 0x2e10a156 , 0x17c3d5db , 0x1bc3e7ef ] )
+  , Mont::unsafe_make( [ 0x9d534d00 , 0xcc99b91c , 0x5d107153 , 0x0ffac74a , 0xf3ecae09 , 0x9e5e62a2 , 0x12955fb0 , 0x28a18d07 ] )
+  , Mont::unsafe_make( [ 0xe3a19730 , 0x760c0f23 , 0x33fafb04 , 0x0c5ffcd0 , 0xd60f1378 , 0xd80af6c9 , 0x6269ab60 , 0x221069f7 ] )
+  , Mont::unsafe_make( [ 0xbf0863db , 0x4ae7c1fe , 0x7341040c , 0x23e815cd , 0xcb1cf1df , 0x498334f0 , 0x0e2d1d91 , 0x0d2ded15 ] )
+  , Mont::unsafe_make( [ 0x20f5051d , 0xdb12d36d , 0x3f6052fc , 0xfb68bbf5 , 0x3062ffc2 , 0xbcf66367 , 0x04169844 , 0x03ba01e0 ] )
+  , Mont::unsafe_make( [ 0xfcb978bc , 0x916c55cf , 0xdc7c5cba , 0xc0e1b329 , 0xd04f560e , 0x9ac425fb , 0x92b16934 , 0x2de08cec ] )
+  , Mont::unsafe_make( [ 0xe325fae5 , 0x3b125da2 , 0x7b59d0fc , 0x9d9cd4dc , 0xf1ad6b47 , 0x47c46804 , 0x837bef96 , 0x1ad4c8fd ] )
+  , Mont::unsafe_make( [ 0x6713cbf3 , 0x62cfeebb , 0x7521ad7b , 0x44efdea3 , 0x5d2b5961 , 0xd739f860 , 0xad20d25c , 0x16bb7b84 ] )
+  , Mont::unsafe_make( [ 0xf8ca0ef1 , 0x36ccae02 , 0xeafb83f1 , 0xddcb8ebd , 0x9ecefafa , 0xfeb14e61 , 0xb2409de4 , 0x2d437575 ] )
+  , Mont::unsafe_make( [ 0x2f0fd288 , 0x6c0b3186 , 0xb9e83b05 , 0x5007d4d9 , 0x914797ac , 0x67677440 , 0xdfab4adf , 0x04286669 ] )
+  , Mont::unsafe_make( [ 0xda387cbb , 0x13c8a608 , 0xfd61222f , 0x4300a23f , 0x28847ab8 , 0xe9684b78 , 0x908f2ece , 0x155e84d6 ] )
+  , Mont::unsafe_make( [ 0xa69b4ee1 , 0x3c4fab8d , 0x244b1b1c , 0x031a55ec , 0x18d4b8e1 , 0xc69ba8f2 , 0x02445923 , 0x14887485 ] )
+  , Mont::unsafe_make( [ 0x7c4c0304 , 0xe437ab95 , 0x4165b0f0 , 0x96537191 , 0x3cffd62f , 0xac4f90cd , 0x3a9182cd , 0x1aea8984 ] )
+  , Mont::unsafe_make( [ 0xa79bafcb , 0x14cf4891 , 0x89207826 , 0xc92d883f , 0x4e15b065 , 0xefaa577c , 0x1a0c68fc , 0x15e2f6dc ] )
+  , Mont::unsafe_make( [ 0x682c460a , 0x69011cb2 , 0x3295056f , 0xfe38e67e , 0xe47d92d8 , 0x179dfa83 , 0x51e32b26 , 0x0ad1351b ] )
+  , Mont::unsafe_make( [ 0x3fb8e566 , 0xc43b4a1f , 0x5a746bca , 0x9dc8e795 , 0xffedf860 , 0xd2ec57d9 , 0x888deeaf , 0x03e05aba ] )
+  , Mont::unsafe_make( [ 0xeef31d6e , 0x0a7c973f , 0x64455c87 , 0x85433f5a , 0x01d20df2 , 0x1fb390db , 0xc34c0b7b , 0x2ad5ec30 ] )
+  , Mont::unsafe_make( [ 0x1c052c3a , 0x5347eaba , 0xd83d2abf , 0xd0be52a3 , 0x6832a5a9 , 0x9c710a98 , 0x5eec74f1 , 0x1eaf0a05 ] )
+  , Mont::unsafe_make( [ 0xb6665bce , 0x162026c3 , 0x3d954bec , 0x054dc38b , 0x0cb9bca9 , 0xd237d79f , 0x1e2d66d0 , 0x2c1ac5ff ] )
+  , Mont::unsafe_make( [ 0xb38a1004 , 0x7b403940 , 0xb826269e , 0x7da42cfe , 0x806b1491 , 0x7525f4c3 , 0x76d80f46 , 0x081f40ba ] )
+  , Mont::unsafe_make( [ 0xa1136925 , 0xcb3513ce , 0x1302a03f , 0x9d3b21eb , 0x39118969 , 0x6901f5f2 , 0xd0653a1d , 0x022e472a ] )
+  , Mont::unsafe_make( [ 0xe06c5d62 , 0xa27cc5cf , 0x29d6f69f , 0xbf41a0cd , 0x4caf577b , 0x5b5f3325 , 0xc8c9dae4 , 0x2aba6777 ] )
+  , Mont::unsafe_make( [ 0x86872401 , 0x63f7a2b0 , 0x2d16a94b , 0x7cc6100b , 0xc730db9f , 0x76cc9fef , 0xb4084e93 , 0x160157c7 ] )
+  , Mont::unsafe_make( [ 0x218b02c7 , 0xe54aeba0 , 0xd8558fa9 , 0x87875956 , 0xf42a9b3a , 0xc959ab7e , 0x7da218bb , 0x24588d57 ] )
+  , Mont::unsafe_make( [ 0xaf50191a , 0x160215f0 , 0xff05b7a5 , 0x07900345 , 0xcc44b6c5 , 0x7fec75e0 , 0x10e69265 , 0x2b00bd1c ] )
+  , Mont::unsafe_make( [ 0x433bdb7f , 0x587deb95 , 0xb4d97098 , 0xa020bf0d , 0x9fb12fff , 0xe0a0cf88 , 0x8dd47f15 , 0x22531b43 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xb7f4a88f , 0xaa0c3069 , 0xc4ec5c4d , 0x80000c90 , 0xf285c84b , 0x29fbcc95 , 0xb0a46111 , 0x11c485f7 ] )
+  , Mont::unsafe_make( [ 0x554af92f , 0x41907059 , 0xce110d33 , 0x26746ced , 0x3a2daab0 , 0x522bb450 , 0xb9a6f113 , 0x20eb91de ] )
+  , Mont::unsafe_make( [ 0x422f024f , 0xe220d90e , 0x13051f8d , 0xf91adc41 , 0xe905d241 , 0xbeaae20f , 0xc777ca14 , 0x2aa52872 ] )
+  , Mont::unsafe_make( [ 0x7c896353 , 0xc4f8a49e , 0x3b0ec0b2 , 0x81c66270 , 0xfd702fb9 , 0x72e71f6f , 0x1072364c , 0x1213e1ad ] )
+  , Mont::unsafe_make( [ 0xdb6502f9 , 0x1585faba , 0x2e0b4e6d , 0x03ef29f3 , 0x8e920033 , 0x0b157a8c , 0xdd7443cb , 0x29ebec83 ] )
+  , Mont::unsafe_make( [ 0xd4c305d7 , 0xf4934885 , 0xf7f7a4dc , 0xce799303 , 0xe6095640 , 0x7c76cb25 , 0x7c0e54c4 , 0x126cb5fb ] )
+  , Mont::unsafe_make( [ 0x26ec462c , 0xd166b8e9 , 0x1a3881a5 , 0xa6a93f25 , 0x6939bfec , 0x7fb5cb55 , 0xd1b103d3 , 0x21f3ea31 ] )
+  , Mont::unsafe_make( [ 0xa69c030b , 0xa2e518e1 , 0x9f462aea , 0x8eb6833a , 0x5aaf6f7b , 0xc9bbc154 , 0x6da98792 , 0x034f5ff6 ] )
+  , Mont::unsafe_make( [ 0x53eb8f61 , 0x8addf3b7 , 0xa581f6ec , 0x754ace11 , 0x55b19196 , 0x21799b8f , 0x8f048827 , 0x13af5710 ] )
+  , Mont::unsafe_make( [ 0xa7618f96 , 0x2fcd77c1 , 0xc83b0a7a , 0x5af2b679 , 0x53bf0601 , 0x3e64b31b , 0x2d2a4214 , 0x170266d5 ] )
+  , Mont::unsafe_make( [ 0x6b887d31 , 0x54714784 , 0x6ee65ca2 , 0x4dab8532 , 0xc6dba909 , 0x2a07e0a2 , 0x7a9ac560 , 0x05642444 ] )
+  , Mont::unsafe_make( [ 0xe21be073 , 0x2dc06711 , 0x917c9160 , 0xb6bf7c73 , 0x953515ec , 0x32ef4198 , 0x878f16ec , 0x091cbab0 ] )
+  , Mont::unsafe_make( [ 0x4f064d02 , 0xd72d6e36 , 0x54d7b4f7 , 0xe8292926 , 0xbd8d28c4 , 0xf8b23bb4 , 0x58d6f210 , 0x11c32df6 ] )
+  , Mont::unsafe_make( [ 0x22c565dd , 0x51511af5 , 0x5f044180 , 0x083c98e2 , 0x2cee6977 , 0xad2f5b64 , 0x481dc44c , 0x2edfe2c1 ] )
+  , Mont::unsafe_make( [ 0xfdf0cd4b , 0xf08b4e55 , 0x5539fc31 , 0x2285cc42 , 0x30469fb0 , 0x8b678dd1 , 0xed1c0268 , 0x24c66506 ] )
+  , Mont::unsafe_make( [ 0xa6bdea59 , 0x6ff280d7 , 0x4b4f9dd5 , 0x6001ec31 , 0x2b36b4d8 , 0x02be4eb1 , 0xe0fe6c1f , 0x09306d9d ] )
+  , Mont::unsafe_make( [ 0x8318ce22 , 0x2433598e , 0x272f52c5 , 0x9cbeec5d , 0x897be016 , 0x00742ec6 , 0x1f44003c , 0x2f365681 ] )
+  , Mont::unsafe_make( [ 0xc743d773 , 0x3d840b89 , 0x48d66230 , 0x714954c4 , 0x2abcce82 , 0xf1682f2f , 0x6c2b8846 , 0x1f269222 ] )
+  , Mont::unsafe_make( [ 0x24883c74 , 0xe2a0d922 , 0x080b6cf8 , 0xfe59973f , 0xa10222af , 0xa5f97e52 , 0x168b67af , 0x1297c5a3 ] )
+  , Mont::unsafe_make( [ 0x1d8c34eb , 0x7874dbc9 , 0xb915c32f , 0xbcffd4ae , 0xd3c10843 , 0xd1c201a5 , 0xd367fea9 , 0x2f19780e ] )
+  , Mont::unsafe_make( [ 0x6be1269f , 0x48050ee9 , 0x691a1870 , 0x1946e9d5 , 0x2e6bc46b , 0xd32c999b , 0x44944650 , 0x11cb227b ] )
+  , Mont::unsafe_make( [ 0xd302bc06 , 0x62cb0d9a , 0xb9c452a9 , 0xd9e9b63b , 0x61ccb50f , 0xe0180cf8 , 0x92fb6628 , 0x053740a8 ] )
+  , Mont::unsafe_make( [ 0x4b2e3e34 , 0x3812af78 , 0x923d0bf7 , 0xde88b92b , 0x03baa27c , 0x63d949fc , 0xc0b91281 , 0x1ff6a673 ] )
+  , Mont::unsafe_make( [ 0x95930bc3 , 0x432ff262 , 0x0a010f3b , 0xaa5a8290 , 0xfce695bd , 0x005aebe5 , 0xbad99f7f , 0x17ebef26 ] )
+  , Mont::unsafe_make( [ 0x73220c57 , 0xf5f0ed2a , 0x2e27662a , 0xa7ab1d9c , 0xc4ba5cbe , 0x7400879d , 0xa0ca1d9e , 0x1a565780 ] )
+  , Mont::unsafe_make( [ 0x5978eb92 , 0xa67c78b5 , 0x8ff7ad31 , 0xbaf8a649 , 0x61a16c59 , 0xe2e627a7 , 0xf1b3d1a9 , 0x27d39ba6 ] )
+  , Mont::unsafe_make( [ 0x3cbd5606 , 0x913b2ed9 , 0x752bc214 , 0x5d7fbeb5 , 0x07b33f40 , 0xf0130220 , 0xa0e2f799 , 0x01f366ba ] )
+  , Mont::unsafe_make( [ 0xa7742c6c , 0x499e798d , 0x7252d715 , 0xe05e77ca , 0xec23b3b3 , 0x79c2aa75 , 0x8560ddde , 0x2aba193f ] )
+  , Mont::unsafe_make( [ 0x6c0fd523 , 0xc2a481f5 , 0x713abab4 , 0x627f6ca6 , 0xa913e97d , 0x6a097f50 , 0xa0669c13 , 0x1bfb9c7f ] )
+  , Mont::unsafe_make( [ 0x69b37c07 , 0xd52b194e , 0x2df0d107 , 0x6f1eba50 , 0x7bd8f3aa , 0x9151514f , 0x6440f888 , 0x23f3b11b ] )
+  , Mont::unsafe_make( [ 0xafd98b18 , 0x587d0383 , 0x696144b5 , 0x265deabc , 0x7d44d2d4 , 0x8bf1146a , 0xda40cd02 , 0x27d89c43 ] )
+  , Mont::unsafe_make( [ 0xe84b8783 , 0x6ec46b21 , 0x1ccbe9e9 , 0x359ee34e , 0x940f9205 , 0x74792693 , 0x0fb8d015 , 0x0be4b792 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xd248a268 , 0x4599c620 , 0xf599f98c , 0xbaced9ac , 0x42010025 , 0x81c9862b , 0x53c6d5e0 , 0x214e5922 ] )
+  , Mont::unsafe_make( [ 0xefaa19f2 , 0x839e63c6 , 0x72ee5e10 , 0x19d5a02e , 0x5209a154 , 0x05ac2d39 , 0x393ad1cf , 0x28db9654 ] )
+  , Mont::unsafe_make( [ 0xd531ebcd , 0x565fd899 , 0x2877bfb5 , 0x93816dd2 , 0xb3302610 , 0xe6af3071 , 0x4aa4efd7 , 0x08b33d46 ] )
+  , Mont::unsafe_make( [ 0xaf944ae9 , 0x805df73a , 0x8023606f , 0x115ef62b , 0x66360e26 , 0xd5160303 , 0xcaa62951 , 0x08b67e37 ] )
+  , Mont::unsafe_make( [ 0xe75653f7 , 0x1a2dc6dd , 0x47b8dfb4 , 0xdf9ab799 , 0xf70fa3d8 , 0x91dade12 , 0x631f89b7 , 0x0fca0966 ] )
+  , Mont::unsafe_make( [ 0x4247b64e , 0x1d89dec0 , 0xcc095adb , 0xe8c37c44 , 0x093ed775 , 0x592b4a81 , 0x69f4aad1 , 0x0867c961 ] )
+  , Mont::unsafe_make( [ 0x6918edda , 0x6435ab96 , 0x0389cad8 , 0xeb70bc72 , 0x7f114cb4 , 0x7553cbd2 , 0x92302794 , 0x2e254807 ] )
+  , Mont::unsafe_make( [ 0x14edcf3e , 0x51a91eb9 , 0x8c48d382 , 0x0041ca68 , 0x4e2138b4 , 0x59a61a91 , 0xf4e35187 , 0x100b4a16 ] )
+  , Mont::unsafe_make( [ 0xf6d71d9a , 0x89cf2960 , 0x39098122 , 0xdcb2caaf , 0xac363ef4 , 0x997f3b7e , 0x9dccbb62 , 0x18c8480a ] )
+  , Mont::unsafe_make( [ 0x8a13665a , 0xab8a1b41 , 0x7ce6eadc , 0x9bdfeb04 , 0xb80b43a1 , 0xa62c0d1c , 0x9dbc7611 , 0x0561a2e2 ] )
+  , Mont::unsafe_make( [ 0x36b2ac1b , 0xc5b79b0c , 0x12f684d4 , 0xc6973888 , 0xad9e01c9 , 0x8b9c5e6e , 0xd265a187 , 0x1bf05d50 ] )
+  , Mont::unsafe_make( [ 0x71c5b4ab , 0xce81fece , 0x67719605 , 0xcb19211d , 0x1345163a , 0xece86ef8 , 0x9397304b , 0x19bcb301 ] )
+  , Mont::unsafe_make( [ 0x4cdc84c9 , 0xd0cbc2c4 , 0x4935c151 , 0xad341f46 , 0xc25224fb , 0xc14d327a , 0x3f79daef , 0x0d7ea12f ] )
+  , Mont::unsafe_make( [ 0xc788c066 , 0x3f5dbd69 , 0xf318e564 , 0xdf4db1ae , 0x3812bd41 , 0x4dfee23f , 0x59c63f7a , 0x27a9c0db ] )
+  , Mont::unsafe_make( [ 0xe092db30 , 0x4cc7065c , 0x6678adc1 , 0x2ea837b9 , 0x08bc2556 , 0x4926ee52 , 0x034a366b , 0x12f8b8a8 ] )
+  , Mont::unsafe_make( [ 0x6148db67 , 0xae266a71 , 0x45b1176d , 0x78dbc7fa , 0xe19ae6ad , 0xc9b2fd20 , 0xddd0f34a , 0x21ef0242 ] )
+  , Mont::unsafe_make( [ 0xac8f4c30 , 0x2a2099fb , 0xf91ceafb , 0xd6460f49 , 0xcb834fbe , 0xe4b5b253 , 0xc088bb30 , 0x2d6ff7f9 ] )
+  , Mont::unsafe_make( [ 0x9e04542d , 0x8d0ccf2d , 0x345d0de4 , 0x5b277ac4 , 0x033b95b8 , 0xab9b984a , 0xa795c709 , 0x0638e3db ] )
+  , Mont::unsafe_make( [ 0x45210142 , 0x8347f1f4 , 0x7c49ffef , 0x0bd61a52 , 0xd00e108b , 0x1dc8ce5d , 0x35a296e2 , 0x0fb21717 ] )
+  , Mont::unsafe_make( [ 0xf077bfc2 , 0xec9b495b , 0x73db7550 , 0x63f7af0d , 0xf4ebac02 , 0x4f6aa499 , 0xf7beb792 , 0x2b2013f8 ] )
+  , Mont::unsafe_make( [ 0x09f4a7b7 , 0xe8e0b04e , 0x5667991d , 0xee3c6a1f , 0x7450411e , 0xbaf28f2c , 0x25367dbb , 0x227ffa71 ] )
+  , Mont::unsafe_make( [ 0xe1052cca , 0x20a9d2e4 , 0xa951c69a , 0x0e2e67b1 , 0x46bd41fd , 0xc5620a85 , 0xb43ad33e , 0x02760797 ] )
+  , Mont::unsafe_make( [ 0xb09f6ac6 , 0x3ec41a72 , 0xdc1b6ee5 , 0x25bf558c , 0x354e8d91 , 0xef353817 , 0x41081eb6 , 0x1c242b6c ] )
+  , Mont::unsafe_make( [ 0x76f5c08c , 0xb0d7bbcc , 0x3a533f23 , 0xcc85e626 , 0x65396598 , 0xf0ab9d58 , 0x6249da9c , 0x0a31ba37 ] )
+  , Mont::unsafe_make( [ 0xb468663f , 0x37395a97 , 0xd285a67c , 0x706c4271 , 0x00bf60d1 , 0xff65f930 , 0x3d773a17 , 0x2bfea2c2 ] )
+  , Mont::unsafe_make( [ 0x071b54fa , 0x406f9a82 , 0x283b3b8e , 0x36d5d981 , 0xcd42c0d9 , 0x28adf1b3 , 0x043d9473 , 0x1b57399b ] )
+  , Mont::unsafe_make( [ 0x9a045f38 , 0x3347b6f6 , 0xeeef0085 , 0x783aa08e , 0xd1198861 , 0x767cc24f , 0x6d8d7844 , 0x1dfe7be8 ] )
+  , Mont::unsafe_make( [ 0x41dcd77f , 0xfd4653fa , 0x32755687 , 0x50d51fda , 0x64df5d67 , 0xc1312f11 , 0x295c4fbb , 0x2ff77f6a ] )
+  , Mont::unsafe_make( [ 0x18e68fec , 0x70b915e7 , 0x92e2551f , 0xb0e8d06e , 0x12056197 , 0xcdd754eb , 0xeaeec1c7 , 0x26c71768 ] )
+  , Mont::unsafe_make( [ 0x2e5ebff6 , 0xf748ffba , 0x42f42b21 , 0x3caba5b5 , 0xf4a93284 , 0xe7ce5881 , 0x8dbe6326 , 0x23c5660e ] )
+  , Mont::unsafe_make( [ 0x8436f66e , 0x453fb12b , 0x5b8e8959 , 0x59a04786 , 0x57440cfe , 0x6fd042f9 , 0xda5693db , 0x0c123818 ] )
+  , Mont::unsafe_make( [ 0xca65c5a6 , 0xc15a4702 , 0x9a81047e , 0x7e2a6f38 , 0x9e431065 , 0x66750b3d , 0x68219a32 , 0x12113786 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x55cddc52 , 0xffce4452 , 0x98e43fd6 , 0x34cc80ed , 0x2a1033ef , 0x0c791cd8 , 0xf63194d7 , 0x2d289354 ] )
+  , Mont::unsafe_make( [ 0x0edff1a0 , 0x0684a67e , 0x1ad34f63 , 0x32819978 , 0x3192b335 , 0xd67d69b1 , 0x2186b7e4 , 0x10691f8f ] )
+  , Mont::unsafe_make( [ 0xfb44cde3 , 0x19fe8693 , 0xedb4afc5 , 0x1decd335 , 0xedc5bb77 , 0x20d3132f , 0xda337c28 , 0x13be92ca ] )
+  , Mont::unsafe_make( [ 0x331770d4 , 0x811564db , 0x414cac2b , 0x4e9425b0 , 0x05965cb6 , 0xc86a1b2c , 0x21e9fd48 , 0x0b606b00 ] )
+  , Mont::unsafe_make( [ 0x8fd7de14 , 0x91e74c57 , 0x94639248 , 0x7752904a , 0x30f2fcff , 0x42638b41 , 0x78dc1864 , 0x231db055 ] )
+  , Mont::unsafe_make( [ 0xb9a75d2c , 0x2b0347eb , 0x1b814671 , 0xff729aad , 0xd205c9a7 , 0x618ac097 , 0x7825b648 , 0x04607d1f ] )
+  , Mont::unsafe_make( [ 0x26a02bdc , 0x69e732ea , 0xec7ff5e0 , 0x412e99c3 , 0x7ab57cd9 , 0x4b27c786 , 0x1f1f5c1b , 0x1e241a87 ] )
+  , Mont::unsafe_make( [ 0xbbdbd6f9 , 0xddf3b514 , 0x08cf0eaf , 0x19bab62e , 0xfc12d1e7 , 0x2c8c45c8 , 0x8c902a19 , 0x05e7e73d ] )
+  , Mont::unsafe_make( [ 0xdc91c752 , 0xeb4a19fa , 0xdb2cc659 , 0x30a82ea1 , 0x5f829bca , 0xc3fed809 , 0x96ba3b13 , 0x2aac38bc ] )
+  , Mont::unsafe_make( [ 0x8686f527 , 0x90646153 , 0x1dffeb1f , 0x5ab55ff9 , 0xfd7571dc , 0x77ee4790 , 0xba662f95 , 0x284c0048 ] )
+  , Mont::unsafe_make( [ 0x85584b42 , 0x2bbaa771 , 0x97fa8e0c , 0x43830c38 , 0x8fcaf3c2 , 0x1c05cf12 , 0x9690c7a3 , 0x179c3bd4 ] )
+  , Mont::unsafe_make( [ 0xbe7e4a97 , 0xa34ebdc4 , 0x403ca6dd , 0x92f5d846 , 0xd72c94aa , 0x74c2868d , 0x562249f0 , 0x0d7ce7fc ] )
+  , Mont::unsafe_make( [ 0xb64ae6e0 , 0x5b1034a9 , 0x91b6e9e6 , 0xfee2928a , 0xee7cddc5 , 0x8d19bd0c , 0x092e7f30 , 0x05e59347 ] )
+  , Mont::unsafe_make( [ 0xb64e0718 , 0xef39c1fc , 0x15907be3 , 0xd23fc6d0 , 0x9733f92e , 0x83bea332 , 0x6cf96805 , 0x2574b534 ] )
+  , Mont::unsafe_make( [ 0xf9d5714b , 0xe4eafe28 , 0xdbf80024 , 0xc08982f2 , 0xb71f2843 , 0xf6dbe32f , 0xef85fa21 , 0x155b6aea ] )
+  , Mont::unsafe_make( [ 0x070fb1ce , 0x0c251908 , 0x5945f19d , 0x00d72357 , 0xf4ec4c78 , 0x153c7900 , 0x5ac0fc1c , 0x077784a1 ] )
+  , Mont::unsafe_make( [ 0x895d81ec , 0xb52a49b0 , 0x2747064f , 0x44bf38f3 , 0xee97b937 , 0x6ceb8f28 , 0x7e80872a , 0x12114ae9 ] )
+  , Mont::unsafe_make( [ 0x1a14938a , 0x200ae419 , 0x326b9adf , 0x92cecaa6 , 0x6201553a , 0x9c3bf1ec , 0xf165416d , 0x2b6c82b5 ] )
+  , Mont::unsafe_make( [ 0x8b3d97a4 , 0xbab9c607 , 0xa275bdc4 , 0xc075815c , 0xd1bcb2f0 , 0x10faec70 , 0x6b657246 , 0x230df3e3 ] )
+  , Mont::unsafe_make( [ 0xa2331499 , 0x9509b123 , 0x2e72ebe1 , 0x6e551c4f , 0x61e85c7b , 0x06068b5e , 0x08c26778 , 0x259cc327 ] )
+  , Mont::unsafe_make( [ 0x6ceca36b , 0xa429cd49 , 0x85578094 , 0xc3dc7f20 , 0xd229d743 , 0x17d5abf6 , 0x0a98c946 , 0x09ee6492 ] )
+  , Mont::unsafe_make( [ 0x884180cf , 0x4a6d2e70 , 0x9a2c7884 , 0xe12f8f8a , 0xd42c325f , 0xa4e41723 , 0xd83eb9e2 , 0x01c25e60 ] )
+  , Mont::unsafe_make( [ 0x743ea0d2 , 0x16ec69e3 , 0x7690310a , 0xe8c7106f , 0x001c7d56 , 0xf628ca80 , 0xdc55069a , 0x19a9a8fc ] )
+  , Mont::unsafe_make( [ 0xe6e8403b , 0x8d031b80 , 0x1d0f574c , 0x67781913 , 0x2c476ab5 , 0x4b2b67c6 , 0x6c43a6e8 , 0x0fa3db0c ] )
+  , Mont::unsafe_make( [ 0x2ceec300 , 0xaed4323a , 0x9a767005 , 0x9223890b , 0xceefc147 , 0x7f65d773 , 0x204121c2 , 0x0aeff842 ] )
+  , Mont::unsafe_make( [ 0x637f9a50 , 0xbd539a5a , 0x3ef09599 , 0xf5136506 , 0xfa7733a7 , 0x2c4c8d6d , 0x8542c336 , 0x1c3cb2d3 ] )
+  , Mont::unsafe_make( [ 0xd779896a , 0x2897f62a , 0x1037c1ff , 0x76b32bf9 , 0x274390e8 , 0x7456d03c , 0xca140f69 , 0x19fa88e4 ] )
+  , Mont::unsafe_make( [ 0x5d4f6e01 , 0x26b5fefd , 0x56b16934 , 0x7538f348 , 0xd2bdd058 , 0x1315cb33 , 0x25f13750 , 0x22aeb946 ] )
+  , Mont::unsafe_make( [ 0x71f492c6 , 0x4d41fb4c , 0xc01fe6f6 , 0x104e600e , 0x20dc99e8 , 0x26072d72 , 0xc939b4da , 0x1a8497e2 ] )
+  , Mont::unsafe_make( [ 0x9e08735a , 0xa14f095a , 0x47bd3b3b , 0xcba12201 , 0xeab29044 , 0x6aff9259 , 0x9e49204f , 0x07224a57 ] )
+  , Mont::unsafe_make( [ 0x1baf32c9 , 0xeafc7f3e , 0xa1a28622 , 0xf83cc964 , 0x3dc9fc8e , 0x262d80bf , 0x146557e5 , 0x1c194328 ] )
+  , Mont::unsafe_make( [ 0x4aee21e8 , 0xf3d94988 , 0xa10a506e , 0xea63a239 , 0x7bf27777 , 0xdb958dfb , 0x5c3a4eeb , 0x21b0c049 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xe306bb5f , 0x94f50135 , 0x7512cf86 , 0x991705e9 , 0x922843b7 , 0x2c368a26 , 0x7bce172d , 0x11e9fa3a ] )
+  , Mont::unsafe_make( [ 0xa0691f92 , 0x436298c2 , 0x25cb7d54 , 0x6a56e40d , 0xa5bfc751 , 0xd44286e2 , 0x4d1ac217 , 0x02ef8cbe ] )
+  , Mont::unsafe_make( [ 0x58c50b2d , 0x440a1552 , 0x39ae37fc , 0x451a518a , 0x7bc423b7 , 0x296fb29f , 0x727cadf8 , 0x27d293b6 ] )
+  , Mont::unsafe_make( [ 0x9b06b51a , 0xc6ef30c4 , 0xda05ceef , 0xf03d07e3 , 0x8ece2424 , 0xe9e5c5b0 , 0x8e0872d9 , 0x0e1121ed ] )
+  , Mont::unsafe_make( [ 0x470d2c2b , 0x3fb83127 , 0x0f5682b0 , 0x21d9c84f , 0x8f1aea0c , 0xf760861f , 0x784e85a6 , 0x01d72181 ] )
+  , Mont::unsafe_make( [ 0x212b2cc4 , 0xa0f9ff31 , 0xcc102ed8 , 0xc2364df4 , 0x40576922 , 0x0f67e261 , 0x6234cc4d , 0x1e8839db ] )
+  , Mont::unsafe_make( [ 0x99155769 , 0x09db954a , 0xecdc84d7 , 0xdcc20581 , 0x40327794 , 0x85111b9e , 0x753bee65 , 0x1cc45edb ] )
+  , Mont::unsafe_make( [ 0xedb8364f , 0x3fd106cf , 0x6dd3affa , 0x5ff42e6c , 0x9d6197c0 , 0x5a87a650 , 0xeac75e39 , 0x00ccb81b ] )
+  , Mont::unsafe_make( [ 0xb5101c58 , 0xdc6be5c6 , 0x8c0918f9 , 0x65549b5c , 0x3743feaa , 0x27a855bb , 0xf5f63993 , 0x0da5a58b ] )
+  , Mont::unsafe_make( [ 0x3c0e39aa , 0x0fcf90ad , 0x6cf60c57 , 0x9b69dca0 , 0xeadadc2a , 0x68818638 , 0xaa4edf13 , 0x0a963784 ] )
+  , Mont::unsafe_make( [ 0x587fede7 , 0xfd2079f7 , 0x28e8f192 , 0xdb24282c , 0xdd3cd9aa , 0x8a813a9b , 0x210d04cd , 0x071cf442 ] )
+  , Mont::unsafe_make( [ 0x475d733d , 0x8f086247 , 0x412d0598 , 0xbce5a1c2 , 0xce09003d , 0x44b816a2 , 0x4973aa95 , 0x13d93f93 ] )
+  , Mont::unsafe_make( [ 0xb46f1a08 , 0xe95ddf52 , 0xe2548cc1 , 0x7d52cb26 , 0x5db0f115 , 0xfcf868f8 , 0x6124f2bd , 0x13ffa65a ] )
+  , Mont::unsafe_make( [ 0x49c39b1b , 0x0bf1c824 , 0xf218cb12 , 0xd1ac6284 , 0xed6c0638 , 0x7736731d , 0xc672fd2d , 0x116b423b ] )
+  , Mont::unsafe_make( [ 0xc23d3297 , 0x2443fba6 , 0xcd831156 , 0x1f3bdccf , 0xaf1652f0 , 0x2e77b0aa , 0xd8d98250 , 0x2058e935 ] )
+  , Mont::unsafe_make( [ 0x99d64250 , 0x3c299c37 , 0x81255dc8 , 0x27e19420 , 0xdf21efe4 , 0xefb6bfc4 , 0x17cf4bc7 , 0x07968a3e ] )
+  , Mont::unsafe_make( [ 0x1b816f7f , 0xdb49d0f2 , 0x6063607b , 0xc883c7a9 , 0x3bda4496 , 0x53bbe516 , 0xe34f5b2a , 0x126882a4 ] )
+  , Mont::unsafe_make( [ 0x96262ddc , 0xf210de91 , 0x7e2ebc5c , 0x1a6a3b44 , 0xb9fce78d , 0xbb4a3957 , 0xeacdfee5 , 0x13c89982 ] )
+  , Mont::unsafe_make( [ 0xf5726eec , 0x5d3d6b20 , 0x42153406 , 0x48d12653 , 0x6db387cf , 0x57dbbfb6 , 0x28a219d8 , 0x1369f810 ] )
+  , Mont::unsafe_make( [ 0x6e985844 , 0xf805e41f , 0xc75affb7 , 0xd3f1c7a9 , 0xab70e496 , 0x99fc2269 , 0x8480d7b2 , 0x028173c3 ] )
+  , Mont::unsafe_make( [ 0x23c8bd7b , 0xe6518bf5 , 0x28578979 , 0xedd77cdd , 0xe99c04d9 , 0x007e4284 , 0xb13b03e4 , 0x23964c2d ] )
+  , Mont::unsafe_make( [ 0xf414dc63 , 0xbe73a5e9 , 0xeb3e8118 , 0x96a2702b , 0x452dd689 , 0x6e0554be , 0xe1e33261 , 0x250aa4bc ] )
+  , Mont::unsafe_make( [ 0x03cfbdc1 , 0xf0020dcb , 0x7d420b35 , 0x87485026 , 0x4b3a4679 , 0x8db9c270 , 0x62147af5 , 0x1f98dcf5 ] )
+  , Mont::unsafe_make( [ 0x4048f0f6 , 0xec453930 , 0xe45144b7 , 0x5bc27a5e , 0xc6252d44 , 0x96869500 , 0x9de346b9 , 0x2041d625 ] )
+  , Mont::unsafe_make( [ 0x61c7e471 , 0x5014d0c0 , 0xe5cf1433 , 0x69ae9db7 , 0x3fdd46c7 , 0xa8b6469f , 0x8b44d27e , 0x00c97237 ] )
+  , Mont::unsafe_make( [ 0x7456d8b4 , 0x103db58c , 0x4eaba68b , 0x99cc4ff1 , 0xc3aa4c05 , 0x637de5f8 , 0xd237f249 , 0x25441337 ] )
+  , Mont::unsafe_make( [ 0x9d508480 , 0x56b11b4a , 0xfd147bdc , 0x3606a618 , 0xd549b5f1 , 0xc9677aa9 , 0xb24c4c5d , 0x2626e87d ] )
+  , Mont::unsafe_make( [ 0x5cd6b194 , 0xb4bf41f4 , 0x90c5c5e2 , 0x003f4b67 , 0x0ffa0b91 , 0xfcce1cc0 , 0xc84ce0ea , 0x1a639324 ] )
+  , Mont::unsafe_make( [ 0x9583b532 , 0x27f94104 , 0x5620af8a , 0x1e4bc1be , 0xa382a351 , 0x940def9b , 0x4f4f9379 , 0x0cf64ad5 ] )
+  , Mont::unsafe_make( [ 0x9bcc2555 , 0x64b2e344 , 0x32daba06 , 0xfc36a466 , 0x456bad5d , 0xc4be94cd , 0x81bf094b , 0x28803c8f ] )
+  , Mont::unsafe_make( [ 0x1e8ee778 , 0xcb9366b9 , 0xc29b81e9 , 0x8490ff3d , 0x74c46d7a , 0x0f531042 , 0x032ba986 , 0x00bc1b23 ] )
+  , Mont::unsafe_make( [ 0x44c97e0f , 0x3fda132f , 0x8baff179 , 0x52afdfe2 , 0xf7fc9271 , 0x2fae787c , 0xd58cff0e , 0x30334b7b ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x2e474803 , 0xb15ebd20 , 0xccd80cda , 0x2bbf5d6b , 0xdda1106c , 0x1bbfb1c2 , 0x11d6e2b8 , 0x196d683d ] )
+  , Mont::unsafe_make( [ 0x9bc61a31 , 0x54587599 , 0x45ce22af , 0x56342934 , 0xb6eddf6c , 0x5833b8bd , 0xc14dda40 , 0x2ee9472f ] )
+  , Mont::unsafe_make( [ 0x63f19495 , 0xe6077ced , 0xa733c715 , 0x54048748 , 0xe1c6e3b0 , 0x269d2390 , 0x1f1a71c7 , 0x03fe4777 ] )
+  , Mont::unsafe_make( [ 0xda16626e , 0xa2005b75 , 0xce7b5be3 , 0xbcd83a65 , 0x766d714c , 0xe8e8c7c9 , 0xd6235dec , 0x285d4d41 ] )
+  , Mont::unsafe_make( [ 0x4f8daf8a , 0x3adb5725 , 0xed5c9dda , 0x4c5b5a04 , 0xb8475ce6 , 0xc70121af , 0xed9ced9e , 0x06b8113c ] )
+  , Mont::unsafe_make( [ 0x29c53716 , 0x541fce20 , 0xe883e02e , 0x75fbd810 , 0x6e8a6b06 , 0x95ceb0c2 , 0x2dfa7c44 , 0x1c32ac09 ] )
+  , Mont::unsafe_make( [ 0xe54eaa7b , 0x82bff1ec , 0x43fac8ed , 0xf3d256e1 , 0xcc7c2e5a , 0x36860138 , 0x88a761ac , 0x1575ee70 ] )
+  , Mont::unsafe_make( [ 0x1a4f44f7 , 0x3886373b , 0xff851aeb , 0xf453f0a2 , 0x9ceaeac9 , 0x62fc6a14 , 0xf0f2009b , 0x081824ed ] )
+  , Mont::unsafe_make( [ 0xe188c536 , 0x2901f49d , 0xef99b9ae , 0xd75388f3 , 0xfc88e4ce , 0x057cd512 , 0xe3ed3258 , 0x1527c28e ] )
+  , Mont::unsafe_make( [ 0xa04ec0a4 , 0x30d7a1df , 0xf04a9626 , 0xaa020a17 , 0x58546988 , 0xabffca08 , 0x9a8f4e23 , 0x21f18d90 ] )
+  , Mont::unsafe_make( [ 0x2ed0f0bf , 0xaa2d6c7b , 0x3ae23956 , 0xcbb2d7a9 , 0x52ffe02a , 0xf7da8bac , 0x3bc9af5f , 0x20dcc1b8 ] )
+  , Mont::unsafe_make( [ 0x367e2519 , 0xa2e50d74 , 0x66757676 , 0xb0019d28 , 0xca79b233 , 0x9189fa33 , 0xdd8efde4 , 0x22ad2f5e ] )
+  , Mont::unsafe_make( [ 0xe1a80fba , 0xfb3b85aa , 0x16a0953f , 0xb90a2ab1 , 0xfd41e95f , 0x1bcebb8e , 0xe6200abe , 0x21912376 ] )
+  , Mont::unsafe_make( [ 0xb4eaa207 , 0x31fbc62f , 0xff64ab49 , 0x8632414d , 0xff8747ca , 0x723f6b1b , 0x4710d24a , 0x15740f5a ] )
+  , Mont::unsafe_make( [ 0x1f496dc5 , 0x06bd1474 , 0x5908f561 , 0x0be2d6a6 , 0x17dc3037 , 0x4248ac12 , 0x76c1059d , 0x26509ed1 ] )
+  , Mont::unsafe_make( [ 0xb350ee31 , 0xd8bf9d70 , 0x552a7248 , 0xd81eaca6 , 0x581493b6 , 0xe608a00c , 0x50fb26c1 , 0x2c9331b6 ] )
+  , Mont::unsafe_make( [ 0x8ca25090 , 0x340592f7 , 0x42faefa5 , 0xe2e3a00b , 0xaf2e5204 , 0x64114204 , 0x4933c7b9 , 0x1993cc53 ] )
+  , Mont::unsafe_make( [ 0x1b70b6d4 , 0x85b03536 , 0xab7ba84f , 0xd6a1cfa9 , 0xb04bbea1 , 0x2cd6f336 , 0xc0fe62b8 , 0x1ce70fc2 ] )
+  , Mont::unsafe_make( [ 0x9af94ed4 , 0x3eaa4e03 , 0xda96c758 , 0x4d1bfc62 , 0x5862a711 , 0xc0490a1b , 0x503de153 , 0x034f094f ] )
+  , Mont::unsafe_make( [ 0xfa780807 , 0x68c487e2 , 0x44078d5f , 0x840c30ff , 0xd05216c8 , 0xb9c02984 , 0xc3b27d6a , 0x0567a623 ] )
+  , Mont::unsafe_make( [ 0x2aa892c2 , 0xc5767bfa , 0x2ddf7e82 , 0xa92760c7 , 0xaf260dba , 0x131db3d7 , 0x5aaca975 , 0x2edae608 ] )
+  , Mont::unsafe_make( [ 0x66239693 , 0xd94059b2 , 0xbe647bdc , 0xf45d2e2a , 0x68ac17d9 , 0x19f816c6 , 0xc0c8912c , 0x2e4922da ] )
+  , Mont::unsafe_make( [ 0xd44406cc , 0x023d0a57 , 0xfd7abda7 , 0x052faaee , 0x20d8988e , 0x1cfcef8e , 0x210e4eeb , 0x0e1f6564 ] )
+  , Mont::unsafe_make( [ 0xf47504d3 , 0xb9ae3221 , 0xf337967d , 0xc804a1aa , 0x37eee9fd , 0xccf6c56a , 0xb1f2c1bd , 0x1a7dcd73 ] )
+  , Mont::unsafe_make( [ 0xb8b85937 , 0x75f9a095 , 0x10b43f14 , 0x1483a6cb , 0xeac61412 , 0x40fd18ee , 0x0d9a302b , 0x1ba2df72 ] )
+  , Mont::unsafe_make( [ 0x39f02133 , 0x7e3546d4 , 0x7c0735c4 , 0x6d46cd04 , 0x612d9723 , 0x07a63075 , 0xb43a7e61 , 0x035c01e3 ] )
+  , Mont::unsafe_make( [ 0x0be91a12 , 0x8ef6ea0f , 0x9ae502a1 , 0x3e4ff26d , 0x2c1a9da2 , 0x4064c42b , 0xa186c3d7 , 0x239344ad ] )
+  , Mont::unsafe_make( [ 0x919d1a8f , 0x135bfadd , 0x5ac0f508 , 0xf335af34 , 0x51b90425 , 0x5c3d6075 , 0x7f5ed858 , 0x15a634d2 ] )
+  , Mont::unsafe_make( [ 0x6fd709d8 , 0x652369fe , 0x6f8d892d , 0x479d75a2 , 0xe6f8b6ab , 0xbcd7befc , 0xa78c6409 , 0x1ab0895b ] )
+  , Mont::unsafe_make( [ 0x61a66449 , 0x338e5819 , 0x28c28dbd , 0x837bb403 , 0xf927f769 , 0xbd187d46 , 0xd6cc1620 , 0x0e5bc073 ] )
+  , Mont::unsafe_make( [ 0xa8544d6b , 0xd68fa35d , 0x681a038f , 0x41596f12 , 0x541dece3 , 0xcb1ac1ab , 0x171f1104 , 0x0cd86d32 ] )
+  , Mont::unsafe_make( [ 0xb89ba58d , 0x03ca8cb7 , 0x34dca49c , 0x125b5cfa , 0x07bc18c5 , 0xce304ba2 , 0xe87b31ca , 0x11d071d7 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xe4c22f7b , 0x830180ae , 0xa1dd13bf , 0x3a263c16 , 0x562f2cc8 , 0xf5316598 , 0x28a4f118 , 0x28bea9a3 ] )
+  , Mont::unsafe_make( [ 0x14b62ca8 , 0xbfa38209 , 0x416b2021 , 0x670f4334 , 0xcabeeafe , 0x949bebd7 , 0x76da2b4d , 0x0123815b ] )
+  , Mont::unsafe_make( [ 0x95e93722 , 0x52b5a3b4 , 0x833e86a2 , 0x268700fb , 0x25593f2b , 0x3c420a27 , 0x4dafc806 , 0x0b00da8b ] )
+  , Mont::unsafe_make( [ 0xf6dc8f75 , 0x943d3e35 , 0xdbd15b9b , 0xf003f77b , 0xfdc381b6 , 0x42dbb333 , 0xe4ea182f , 0x042c844d ] )
+  , Mont::unsafe_make( [ 0x4b1c73bf , 0xc01f259d , 0xc1cb8f5d , 0x3a0fbc2b , 0x45e6bf88 , 0x8dce699e , 0x5ef4a2be , 0x07373a3f ] )
+  , Mont::unsafe_make( [ 0x2af52591 , 0xb2d917c6 , 0x9a6df740 , 0x34eb75a4 , 0x9df98425 , 0x5948098b , 0x965f5e89 , 0x01b13fe3 ] )
+  , Mont::unsafe_make( [ 0x0cc2e87a , 0x1a0fad08 , 0xb98a806d , 0x81d3e038 , 0xc5e1dc12 , 0x072c4d77 , 0xbcf85cbb , 0x07251b38 ] )
+  , Mont::unsafe_make( [ 0xe81d6a0c , 0xfaeb37c3 , 0x7b279654 , 0x643c0c94 , 0x75b86ddc , 0xe79770b5 , 0xc5d3a542 , 0x2bac78ca ] )
+  , Mont::unsafe_make( [ 0x158415c6 , 0x3a3ea639 , 0xe0739236 , 0xd51ac050 , 0xa36e982d , 0x9fb9657e , 0x0e07752c , 0x06fb1a9b ] )
+  , Mont::unsafe_make( [ 0xbd21a928 , 0x2ca17e19 , 0x73ffe6fb , 0x4feffa36 , 0x1e94cf1b , 0x7157e3bd , 0xc1487d95 , 0x043983ea ] )
+  , Mont::unsafe_make( [ 0xf8b604ce , 0x060a6248 , 0x380059df , 0x130a09a5 , 0x735d5300 , 0x221f2fd0 , 0xd9b311a9 , 0x05ec30b3 ] )
+  , Mont::unsafe_make( [ 0xdd80f682 , 0xd2c7d677 , 0xf26ba754 , 0xd15131f8 , 0x41f39678 , 0xc16fdaaa , 0x5ad9df85 , 0x2fc1f31d ] )
+  , Mont::unsafe_make( [ 0x284e13c0 , 0xa42647ed , 0x263c3841 , 0x423bad42 , 0x9ba482c5 , 0x80e088c5 , 0x5643b950 , 0x184c2128 ] )
+  , Mont::unsafe_make( [ 0xd3c6c522 , 0x91929611 , 0xe13d62ad , 0x4a54a7a9 , 0xbd88e2ce , 0x1031bf61 , 0xf967da53 , 0x301ef162 ] )
+  , Mont::unsafe_make( [ 0x98d2f5ec , 0x66e570a1 , 0xa1e22a50 , 0x82b81240 , 0xa53b2aef , 0x072b6886 , 0x0ee22dcf , 0x07e2c1c3 ] )
+  , Mont::unsafe_make( [ 0xf22261d5 , 0x8d41c232 , 0x8d24d531 , 0x20988df2 , 0xe8cf519b , 0xcde7ac65 , 0xbb6c2896 , 0x29672ba9 ] )
+  , Mont::unsafe_make( [ 0xe586b3c5 , 0x880a2bb3 , 0x7141ac1f , 0x1784317a , 0xc1cb180e , 0x39ef9751 , 0xf72c8c1c , 0x0916a310 ] )
+  , Mont::unsafe_make( [ 0x2b7abd7e , 0x638bd056 , 0xbf26a9fd , 0x91892475 , 0xb8df4481 , 0xe3062ff2 , 0xd06325cc , 0x2114f9d0 ] )
+  , Mont::unsafe_make( [ 0xa8308c5c , 0xafef852c , 0x5cccf492 , 0xf8883a3b , 0x7db6e9fd , 0x821da019 , 0xb44791de , 0x08708de9 ] )
+  , Mont::unsafe_make( [ 0x3abde37f , 0xf085fb31 , 0xe2860aee , 0xb0fde50e , 0x6bff5554 , 0xa6c861ce , 0x253ca797 , 0x0ccf81a0 ] )
+  , Mont::unsafe_make( [ 0xde8bb8e5 , 0x7fa0335b , 0x93927750 , 0x359391f5 , 0x883db93f , 0xb52ea7a6 , 0x3d3250dc , 0x2ac75047 ] )
+  , Mont::unsafe_make( [ 0x6549e8d4 , 0xf3d8762d , 0x012cd863 , 0x633dc96f , 0x00e52eae , 0x9f3f71d3 , 0x7e7fba09 , 0x2c284d9a ] )
+  , Mont::unsafe_make( [ 0x9e6e7057 , 0x9b859a10 , 0x9651fb01 , 0xf31d88c5 , 0x4e638d59 , 0xd36d8014 , 0x552cabfa , 0x2dfdff14 ] )
+  , Mont::unsafe_make( [ 0x1dcc14fd , 0xf1a413bf , 0x7fef46c5 , 0x75e683d8 , 0x5887fd83 , 0xcc545b15 , 0x2fb4c48d , 0x14a4facd ] )
+  , Mont::unsafe_make( [ 0xf234d6f5 , 0x64f8944a , 0x6068a6de , 0x545d4e2e , 0xe93cf44c , 0x070113a9 , 0xe4b71ddd , 0x10e2639a ] )
+  , Mont::unsafe_make( [ 0x3add8462 , 0xfe8e9540 , 0xed850aa0 , 0x494cacd8 , 0xa8c9e95f , 0xf0e2e129 , 0xb17ce4dd , 0x1a32611f ] )
+  , Mont::unsafe_make( [ 0xdae50e4f , 0xb5d0d7a6 , 0x764ad142 , 0x5e495e64 , 0x79ffafad , 0x4da56c06 , 0xef940b53 , 0x2dd48de0 ] )
+  , Mont::unsafe_make( [ 0x53d9fda7 , 0x58c26a9c , 0x9e3183b6 , 0x824f9fe7 , 0x8422542a , 0xc3ab864a , 0xcc000a36 , 0x2a7af25d ] )
+  , Mont::unsafe_make( [ 0x841ca178 , 0x16d1676b , 0x563355ef , 0xc186ee08 , 0xeb116272 , 0x88e1431d , 0xa1df3e9d , 0x11b1612b ] )
+  , Mont::unsafe_make( [ 0xf9f9cfb9 , 0xf897518a , 0xe9d54159 , 0x2ce2052b , 0x6b880ca0 , 0x91a00648 , 0xe4c669bb , 0x0a3a3cca ] )
+  , Mont::unsafe_make( [ 0x6c815201 , 0x3fa908c1 , 0x39fe036f , 0x4454d268 , 0x00c1476c , 0xd6d7a8d0 , 0xe65a885f , 0x014cb3d8 ] )
+  , Mont::unsafe_make( [ 0xc1b94338 , 0xa13a2e49 , 0xaddfaf4a , 0x571d29d6 , 0xf3146d9c , 0x1dd1d8ed , 0xe939d6b4 , 0x22ec4cc5 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xab1ba1ae , 0x13e6895c , 0x725b1af6 , 0xc6272945 , 0xfc9e414e , 0x844d7119 , 0xd80835f7 , 0x1b1e40be ] )
+  , Mont::unsafe_make( [ 0x0f2904e1 , 0x0249fb49 , 0xa72df0dd , 0x5688f97b , 0xf897a4da , 0xe84d5957 , 0x0d55e037 , 0x00ca5b5e ] )
+  , Mont::unsafe_make( [ 0x350fe8d2 , 0xd7e789bb , 0x30ef7935 , 0xc0e375eb , 0xb2ff083f , 0xa316d562 , 0x467ed00b , 0x1b43fd48 ] )
+  , Mont::unsafe_make( [ 0xef469644 , 0x770f021a , 0x69571f6f , 0xe054c243 , 0x94e848c9 , 0xcf733bf6 , 0xf3362aaf , 0x170ea21e ] )
+  , Mont::unsafe_make( [ 0x9fcc6ac0 , 0xd4a92a03 , 0x8c4d84d6 , 0xa0705ab2 , 0x6b8bb270 , 0xab5459d9 , 0x1df682ea , 0x27b88c9c ] )
+  , Mont::unsafe_make( [ 0x9bc8c6c9 , 0xef00ec8f , 0x81b4f925 , 0x5eb40b88 , 0xe9e7abfa , 0x90c0d2bf , 0x9e5bf294 , 0x10668cf8 ] )
+  , Mont::unsafe_make( [ 0xa4aaa9a9 , 0x3c3ef9c4 , 0x62e609a7 , 0x826e049c , 0xb2c891a8 , 0xa7ae56ec , 0x27305ebc , 0x265544e6 ] )
+  , Mont::unsafe_make( [ 0x06bc0810 , 0x8e8b286d , 0x56a6036f , 0xd8bfdbb3 , 0xe8b5ea4f , 0xab1ded36 , 0x15448ba7 , 0x0ae7de7b ] )
+  , Mont::unsafe_make( [ 0x045bd40f , 0xc28768b5 , 0xf4f88b50 , 0x33d66457 , 0xa263738b , 0x617371a2 , 0x4f93741b , 0x2873bbf3 ] )
+  , Mont::unsafe_make( [ 0xbd0b11e7 , 0x7c30a589 , 0xf489e9a0 , 0x8ad689fe , 0xc6aecd7c , 0xa046d1c3 , 0x48b83d00 , 0x1b8bf674 ] )
+  , Mont::unsafe_make( [ 0x9fc0b015 , 0xbd2b95a9 , 0x55039b76 , 0xbc79ccbb , 0xa376d218 , 0xfe332220 , 0x560c0c3f , 0x1ba87e15 ] )
+  , Mont::unsafe_make( [ 0x507fbab0 , 0x6107c8d9 , 0x69bb0e3d , 0x4563ec7e , 0xd2f9af89 , 0xa569a9ac , 0xbb46eee8 , 0x2bf68530 ] )
+  , Mont::unsafe_make( [ 0xd8e33a2d , 0x28a8a031 , 0xa5956761 , 0x49c3cd0f , 0x5e7f1a39 , 0xc9fe4b26 , 0x8b198cb0 , 0x2d888be1 ] )
+  , Mont::unsafe_make( [ 0x2a67e1dd , 0xf353cd59 , 0x87fef2ce , 0x22c5c860 , 0xa31f0231 , 0x86efbc84 , 0x4fc16898 , 0x2b53b5cd ] )
+  , Mont::unsafe_make( [ 0x0172e027 , 0x1ee6ec75 , 0x1b9e02c6 , 0x59ddcb7b , 0xabecd798 , 0xdc98f92a , 0x54b4ff48 , 0x2a583608 ] )
+  , Mont::unsafe_make( [ 0x895192d9 , 0x454c75c8 , 0xb18a015a , 0x8df8ca92 , 0x2440d29c , 0xfb47fbc3 , 0x56bf621b , 0x245b747d ] )
+  , Mont::unsafe_make( [ 0x9c8fcdbc , 0x053a0feb , 0x762e2a6b , 0x813644dc , 0xe6eb461e , 0xb6532529 , 0x61c784d1 , 0x23df902f ] )
+  , Mont::unsafe_make( [ 0xc1d6bb82 , 0x9d3f5dd1 , 0x69a108e8 , 0x2b47c250 , 0xd96c1dcf , 0xb567686f , 0x11aaadfd , 0x05878970 ] )
+  , Mont::unsafe_make( [ 0xbed4679f , 0xbad3860a , 0x39ea7850 , 0xbe44ae07 , 0x4774799f , 0x61041599 , 0xa92adb44 , 0x1e24bfa2 ] )
+  , Mont::unsafe_make( [ 0x373a915d , 0x58a24aa5 , 0x6a14de47 , 0x25621738 , 0x70953ba1 , 0x54c2cbb9 , 0x4be17600 , 0x03852c50 ] )
+  , Mont::unsafe_make( [ 0xf7886aaf , 0x1b9dcf27 , 0x738015a0 , 0xebe4fe0f , 0xa1561bab , 0x67e4e4ec , 0x7e1f8cfd , 0x1085c65f ] )
+  , Mont::unsafe_make( [ 0x05c54de4 , 0x7d18ff66 , 0x06911913 , 0x62f56a68 , 0x76c4b7eb , 0xc3b7a0da , 0x035bc692 , 0x2910628e ] )
+  , Mont::unsafe_make( [ 0x7809f740 , 0x5cc475b6 , 0x85afb367 , 0x294ccccb , 0x1b510c8f , 0x3a7a843c , 0x8162cb92 , 0x1e4cc48e ] )
+  , Mont::unsafe_make( [ 0xc3ba3f61 , 0x37586b9d , 0xf95b061d , 0x29806268 , 0x715f4710 , 0x3221c2fe , 0x09f91001 , 0x06150bef ] )
+  , Mont::unsafe_make( [ 0x4e8f53c6 , 0x8ff354e7 , 0xaee5a6ee , 0xac06eb7e , 0xca73bea7 , 0xd36c557f , 0xd23a4839 , 0x1628dccd ] )
+  , Mont::unsafe_make( [ 0x5966a666 , 0x6106d35e , 0xd10cb782 , 0xed13293d , 0x9747de29 , 0x0b10830e , 0xf2704b3a , 0x09a474bd ] )
+  , Mont::unsafe_make( [ 0x1aadb68c , 0x83509c2e , 0x585fa96f , 0xfbf7184d , 0x25cd3a9e , 0xe3db0ed5 , 0x101f306f , 0x2c121ad6 ] )
+  , Mont::unsafe_make( [ 0x604dfa63 , 0x52ca274e , 0x17e6c902 , 0x81c0838d , 0x2f78b5cd , 0xca5ef5fe , 0x03c5e997 , 0x2e2b3acb ] )
+  , Mont::unsafe_make( [ 0x9670a308 , 0x66732fc6 , 0x502f5ad0 , 0xef80614a , 0x37ae7959 , 0x6f2117e4 , 0x3fefdf47 , 0x18240b48 ] )
+  , Mont::unsafe_make( [ 0x6d48786e , 0x66910f0a , 0x9a0acf5f , 0x0013bf3e , 0xada30782 , 0x50b6c80b , 0x4727f923 , 0x188e2059 ] )
+  , Mont::unsafe_make( [ 0x11b3f6c3 , 0x38219263 , 0x095a0461 , 0x2ff05617 , 0x968dd545 , 0x5720e203 , 0xe022f380 , 0x27d16a3f ] )
+  , Mont::unsafe_make( [ 0x09a0f092 , 0x169b00d8 , 0x958d1d77 , 0xb97cc710 , 0x47a1d2be , 0xd363ef40 , 0xad0071bf , 0x0473c2d2 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x5f9f3109 , 0x7827b6f7 , 0x331534b5 , 0xf4d58a8f , 0xc6700ad3 , 0xe77aac21 , 0x8e61604e , 0x28be82cf ] )
+  , Mont::unsafe_make( [ 0xcf210e40 , 0x0656291f , 0xfff997ce , 0x7f2ae64e , 0x4ef7b496 , 0xb3f56988 , 0x7e471bcc , 0x2cd0c2f4 ] )
+  , Mont::unsafe_make( [ 0xe146723b , 0xd2864543 , 0x68dbb1b4 , 0xd859e245 , 0xec7f4a87 , 0x8b906381 , 0xd64602ad , 0x0241ea0b ] )
+  , Mont::unsafe_make( [ 0x3bd5c92f , 0x2a738e31 , 0x04f5f589 , 0x85963b57 , 0x8aa551c8 , 0xb6903657 , 0x102efe1c , 0x1f501949 ] )
+  , Mont::unsafe_make( [ 0xfbb30095 , 0xc45cb0c1 , 0xef95d83a , 0xa3b38528 , 0x936119b2 , 0xaf4d234f , 0xd138c4d2 , 0x1653d690 ] )
+  , Mont::unsafe_make( [ 0x09e7a36a , 0x74ca28ea , 0x52e3f194 , 0xabd8625f , 0xd13f98f1 , 0x51f487a7 , 0xff7b9e64 , 0x2e3dded8 ] )
+  , Mont::unsafe_make( [ 0x515c693d , 0x360a5133 , 0x09c340d9 , 0xad66a7de , 0x4bb7d6c3 , 0x12bfb921 , 0xb7502743 , 0x00edd462 ] )
+  , Mont::unsafe_make( [ 0xf6b409fc , 0x381e17cf , 0xc863fa9f , 0x44b47f8e , 0x4cfcacb4 , 0xf93535f4 , 0xa984ca81 , 0x01a4171d ] )
+  , Mont::unsafe_make( [ 0x2bb4442e , 0x2bf22cf4 , 0x5ca377e2 , 0x7fdffed2 , 0xf69a1236 , 0x7708e372 , 0xb260cf23 , 0x23c4b784 ] )
+  , Mont::unsafe_make( [ 0xa9a9facf , 0xe9907bdb , 0x3e7f5615 , 0x146ed9a2 , 0x75826485 , 0x6b122664 , 0x2c22828a , 0x099de2ab ] )
+  , Mont::unsafe_make( [ 0x5045a80b , 0x462a9ffb , 0xac84da16 , 0x9cbdc722 , 0x95850ff2 , 0x1c6f4f7d , 0x23b2ebb1 , 0x0995c188 ] )
+  , Mont::unsafe_make( [ 0x9bb9f4e3 , 0xec26960e , 0x8824dd38 , 0x690faa4c , 0x88e3ef12 , 0x881ae1fc , 0x4dacefd6 , 0x0fb18f7c ] )
+  , Mont::unsafe_make( [ 0x337e47f8 , 0x65be6f62 , 0x8f323542 , 0x61b2bd7e , 0x46ebcb2d , 0x68b432b5 , 0x27cad204 , 0x04d92897 ] )
+  , Mont::unsafe_make( [ 0x377dc1b0 , 0xde26875c , 0xbc42780a , 0x934556a8 , 0xfb01e4f9 , 0xd1f4903b , 0x3b06f17c , 0x00c0a098 ] )
+  , Mont::unsafe_make( [ 0xf890eab0 , 0x4a1d97f8 , 0x026f46e6 , 0xc0e3dbfe , 0xcb6f5120 , 0x57919153 , 0x14b6e1d9 , 0x0f32def1 ] )
+  , Mont::unsafe_make( [ 0xc8ddd3f4 , 0x0d10990e , 0x76326ed1 , 0x02f66e3c , 0xa787feac , 0x2b31d56b , 0x4fe00c30 , 0x11b0d4e2 ] )
+  , Mont::unsafe_make( [ 0xc805ae30 , 0x06b37c76 , 0xc0a5f42d , 0x8ae857e9 , 0xa25f8ba9 , 0x34ca319d , 0xe79ae743 , 0x2f236812 ] )
+  , Mont::unsafe_make( [ 0x9ce4b905 , 0xb627d67a , 0xcf86eaf9 , 0xd9e0602f , 0xfeb00209 , 0x0adbb846 , 0x163581d5 , 0x20a93182 ] )
+  , Mont::unsafe_make( [ 0x73729651 , 0x52ffcdb2 , 0xec424578 , 0xf0963720 , 0xcfdaa663 , 0xb1618d04 , 0x89190c09 , 0x29181b9e ] )
+  , Mont::unsafe_make( [ 0xf7bfdefa , 0xcd45bbd8 , 0xf8e7ee78 , 0x95878007 , 0x43be935c , 0x90c438f3 , 0x0cf380eb , 0x02512391 ] )
+  , Mont::unsafe_make( [ 0xec0350ec , 0x958d3abe , 0x6fc4f70a , 0x3115c4fc , 0xb45e1125 , 0xf4faafa2 , 0xf4ba9538 , 0x0a75b0a6 ] )
+  , Mont::unsafe_make( [ 0x5a2faa8e , 0xac08e213 , 0x932553db , 0xfcaba795 , 0x08a1b1ef , 0xaa5f1fa6 , 0x3662f6b9 , 0x27836ded ] )
+  , Mont::unsafe_make( [ 0x11fd3334 , 0xcdaa5c07 , 0xcd933a8a , 0xd91609d3 , 0x33a3dc85 , 0x01510724 , 0x421704ff , 0x158d22d4 ] )
+  , Mont::unsafe_make( [ 0x23488c80 , 0x84fc71ad , 0xb00a2117 , 0x4479019e , 0xaf81cdf0 , 0x9c183cd8 , 0xbbccb5dd , 0x274559e7 ] )
+  , Mont::unsafe_make( [ 0x72efcb68 , 0x13c5c64a , 0xb2378eec , 0xf45e3e62 , 0x89f72236 , 0xd7b5b733 , 0xbe78d704 , 0x1f820e76 ] )
+  , Mont::unsafe_make( [ 0xb206e122 , 0x11e7170d , 0x48c10cb0 , 0x779c9181 , 0x00f93de6 , 0xd1a8f23c , 0x46096d04 , 0x068386b2 ] )
+  , Mont::unsafe_make( [ 0xc8bbc6c5 , 0x69f36aed , 0x7a347bb0 , 0x760be3fd , 0x6aab851c , 0xe4d9ddc8 , 0x118b57df , 0x2c3a8987 ] )
+  , Mont::unsafe_make( [ 0x6b4730be , 0x4487a940 , 0xcba496b0 , 0xecef5d5e , 0xefb60740 , 0x039f82ac , 0x53fbfdd5 , 0x08e24dde ] )
+  , Mont::unsafe_make( [ 0x8d5b3988 , 0x2e11abf9 , 0xff3b7cce , 0x19ce390d , 0xc6f741a1 , 0x22cd90aa , 0x789abc9f , 0x2527db17 ] )
+  , Mont::unsafe_make( [ 0x1e3443dc , 0x5ebdf15c , 0x8d88fcb4 , 0x84d7c826 , 0xe0e4c19b , 0xa7133387 , 0x46357a8b , 0x1ffe4190 ] )
+  , Mont::unsafe_make( [ 0xb8138460 , 0x91ba61e6 , 0x42088b0f , 0x3b680155 , 0xe2e9060d , 0x7bf49360 , 0x95fff826 , 0x17e0c4bd ] )
+  , Mont::unsafe_make( [ 0x7da35dda , 0xc7a48288 , 0xd5e7292c , 0x7fc68fc0 , 0xfda5a3d9 , 0x097c4dfa , 0xd1f2fd93 , 0x1bd4aed1 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x81d23d73 , 0xcc2d356a , 0x9c0700c4 , 0xbd1cc538 , 0x43120e85 , 0xa0c4fdb5 , 0x32129325 , 0x1e21dee8 ] )
+  , Mont::unsafe_make( [ 0xddbc8895 , 0x7299bcde , 0x39c368af , 0xf287e91b , 0xced43efd , 0x2721dc0d , 0xce510b05 , 0x074fabe9 ] )
+  , Mont::unsafe_make( [ 0x6007df4c , 0xb14dd573 , 0xd2f541a4 , 0xf07a9386 , 0xea940725 , 0x9ed7bee3 , 0xe8eb8165 , 0x283f3a13 ] )
+  , Mont::unsafe_make( [ 0x88b5cb78 , 0x7f0c6128 , 0x00294452 , 0x07f9c68f , 0x98898503 , 0x8a908c4a , 0x7e7a6e91 , 0x2993a112 ] )
+  , Mont::unsafe_make( [ 0xa97e0f40 , 0xa03a715b , 0xe89d4e94 , 0xd8d3512a , 0xede4c245 , 0x4a7acc15 , 0x94385fa3 , 0x0f8dc6b7 ] )
+  , Mont::unsafe_make( [ 0x0928aa02 , 0x40db7951 , 0x98b401c6 , 0xebb0f837 , 0x86d7f5e7 , 0x52ebf094 , 0x821438a5 , 0x289ee13b ] )
+  , Mont::unsafe_make( [ 0x9dd5b917 , 0x61dcd216 , 0x078450c1 , 0x22011d57 , 0x8b2bb8aa , 0x165c5638 , 0x7cd5fd4a , 0x069d21f5 ] )
+  , Mont::unsafe_make( [ 0x172cf8cd , 0xf5885b79 , 0xaffa29f5 , 0x291728c4 , 0x2bd7c6f3 , 0xecb064c6 , 0x22ca5688 , 0x1242b80c ] )
+  , Mont::unsafe_make( [ 0x5dd645bf , 0x9fdc88b7 , 0x5a96df54 , 0xef0c62b0 , 0x4ddb5918 , 0xa2210345 , 0x3759cea8 , 0x24f9e140 ] )
+  , Mont::unsafe_make( [ 0x6bd2f229 , 0xdb188e8f , 0x3d3605f1 , 0xb246aeb8 , 0xca71385f , 0x03922443 , 0x3365e824 , 0x108c67b6 ] )
+  , Mont::unsafe_make( [ 0xaad78b4f , 0x98ea13c5 , 0x775a95e3 , 0xfc3763c0 , 0xc8686ed3 , 0x5a97f1ab , 0xee28c28b , 0x132dd314 ] )
+  , Mont::unsafe_make( [ 0x9a2ff2c1 , 0x562cc618 , 0xffa65b0d , 0xc20e0286 , 0x11b67d55 , 0x3c992a45 , 0x22e409c4 , 0x0dce6ac3 ] )
+  , Mont::unsafe_make( [ 0x344e09dd , 0x499aabf7 , 0x1f3df1b7 , 0x3e36593c , 0x2ef53555 , 0x10aed85f , 0x88f340c9 , 0x1f25d8ff ] )
+  , Mont::unsafe_make( [ 0x130ac236 , 0xc4f36c1f , 0xfd36ec44 , 0xd50f2075 , 0x38970c80 , 0xcafc1057 , 0xc7954305 , 0x1e60b9cb ] )
+  , Mont::unsafe_make( [ 0x101f48b9 , 0x46fc41cc , 0x081e44a2 , 0x5dcba476 , 0x32e1cdc3 , 0x1dc4eee5 , 0xe33cdb0d , 0x0fcef31c ] )
+  , Mont::unsafe_make( [ 0x2ac4d10e , 0x08294024 , 0x82dfc7cf , 0x1ed1a6e9 , 0x468786e7 , 0xa3b254a6 , 0xac5f0706 , 0x01aa1f87 ] )
+  , Mont::unsafe_make( [ 0xc6fa7ea5 , 0xd7452d05 , 0x7520a760 , 0x038062e3 , 0x28925d7f , 0x3b52e5a4 , 0xf361f521 , 0x0d339866 ] )
+  , Mont::unsafe_make( [ 0xe5d976fa , 0x1a03addf , 0xc931dffe , 0xbfb1fab4 , 0x927570cc , 0x90b3e7ac , 0x8c41e273 , 0x12a1841a ] )
+  , Mont::unsafe_make( [ 0x89158cc8 , 0xcc9e6799 , 0x1382134a , 0xb23154f9 , 0xa9af9220 , 0xdedd5520 , 0xf272d149 , 0x273862be ] )
+  , Mont::unsafe_make( [ 0x6041afb0 , 0x7e040bca , 0x72fc703e , 0xbec0013a , 0xc3b11b59 , 0xb328948e , 0xcec352bd , 0x24a2b7d1 ] )
+  , Mont::unsafe_make( [ 0xa6313f4e , 0x9e2c56e7 , 0xb2072ed9 , 0xe8c71244 , 0x0775e01f , 0x6892ae7f , 0xd40b0bf1 , 0x0c1b9381 ] )
+  , Mont::unsafe_make( [ 0x62fc220a , 0x9e0c71a1 , 0xea3ddd6b , 0x2c3b32a8 , 0xd7e31e60 , 0xb8f46c36 , 0x883de475 , 0x0062aca6 ] )
+  , Mont::unsafe_make( [ 0xcc50419b , 0xc8b4e3de , 0xc1524277 , 0xa2191fbe , 0x2659c86c , 0x71ded792 , 0xf179f23f , 0x23ea43a5 ] )
+  , Mont::unsafe_make( [ 0xf25082d6 , 0x3b0d7176 , 0xb83d133e , 0x7d3ea8fd , 0x2b4881a1 , 0x44a7e628 , 0xf792f336 , 0x00136a0d ] )
+  , Mont::unsafe_make( [ 0x6d0e3439 , 0x82d22bc7 , 0x3089c1bc , 0x92f98260 , 0x724a8626 , 0xdbb98d19 , 0xaa9e913d , 0x19f91b7b ] )
+  , Mont::unsafe_make( [ 0xcee05f94 , 0x90fd674d , 0xf48a2f0a , 0x1862df18 , 0x3560f50c , 0x79ceecbd , 0xa9683a90 , 0x18f8afed ] )
+  , Mont::unsafe_make( [ 0x76a73281 , 0xa1f2a0c2 , 0xa1034cd2 , 0xb93d6fe9 , 0x06b8fead , 0xbf61b685 , 0x27cea839 , 0x0269d545 ] )
+  , Mont::unsafe_make( [ 0x36319e2e , 0x29a92c95 , 0x2d820a5e , 0x18352808 , 0x9de42774 , 0x74b04321 , 0x0a489184 , 0x17ac725f ] )
+  , Mont::unsafe_make( [ 0xe05f1128 , 0x8bb891d3 , 0x40008b61 , 0xdb7e9054 , 0xc1018baf , 0x082d0744 , 0xfdbfb6ef , 0x165ce910 ] )
+  , Mont::unsafe_make( [ 0x9cb4c507 , 0x0fe0ed09 , 0xba1d69c1 , 0xa559d9b9 , 0xb473b6a3 , 0xa449db68 , 0xdf85d730 , 0x19fa75ca ] )
+  , Mont::unsafe_make( [ 0x95e7bbd8 , 0x02440f47 , 0xddeb07cf , 0x53709513 , 0xa38383c7 , 0x82a2423b , 0x3f5f130d , 0x25b17dfb ] )
+  , Mont::unsafe_make( [ 0x1067fbd8 , 0x8c215826 , 0xe12718a4 , 0x315405b0 , 0x5199c3ce , 0x32e2711f , 0xeb4756c0 , 0x2cae1470 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xf0404576 , 0x0e33bab8 , 0x48042e67 , 0x47d38404 , 0xbf5c4f5d , 0x6e04e26e , 0xc8911060 , 0x0a7a8b0b ] )
+  , Mont::unsafe_make( [ 0xb7e23289 , 0xadf9e861 , 0xe9db5273 , 0x32faa2c7 , 0x419cef0d , 0xdcde0e3a , 0xff12c820 , 0x032f8299 ] )
+  , Mont::unsafe_make( [ 0x056de9a5 , 0x665c523d , 0xcef4cca3 , 0x05225c5e , 0x340487e2 , 0x22ee3efa , 0xb9ffbf0d , 0x29a2affe ] )
+  , Mont::unsafe_make( [ 0xef4e3dbc , 0xa6f398d5 , 0x7694d49f , 0x0c1826a8 , 0x31d261a8 , 0x25d0894e , 0xb1bcecd9 , 0x23ff4c52 ] )
+  , Mont::unsafe_make( [ 0x0e06b727 , 0x5dcb334e , 0x151efe4a , 0x0e5f5a58 , 0x0394b896 , 0x819e3f0d , 0x45068ea3 , 0x1ff333c5 ] )
+  , Mont::unsafe_make( [ 0x7abad471 , 0xa03755f3 , 0x182eb31d , 0x35337ba2 , 0x9a87a4e6 , 0xa3b9957d , 0x7270c8d6 , 0x17afdae7 ] )
+  , Mont::unsafe_make( [ 0x049f00dd , 0x9325db61 , 0xa919853e , 0x0491479b , 0x0325d5a1 , 0x6d0c8af7 , 0xdcd03adc , 0x0d5bd078 ] )
+  , Mont::unsafe_make( [ 0x630c6e2d , 0x190ea7dd , 0x0bab9de8 , 0x923871ed , 0x706a45de , 0x73f12a99 , 0x1c6a3bfc , 0x20e77acc ] )
+  , Mont::unsafe_make( [ 0xcb5801aa , 0xb394c595 , 0xf71d0fa9 , 0xdaaa1cc7 , 0xa1cf3eb8 , 0x3895fc4c , 0xb926d6dc , 0x246cc21e ] )
+  , Mont::unsafe_make( [ 0x859493b2 , 0x8269218b , 0xacc3c650 , 0xc10979b3 , 0x32047b29 , 0xa22584e9 , 0x737c639a , 0x1b59b7b7 ] )
+  , Mont::unsafe_make( [ 0x87e5ccc9 , 0x79f81570 , 0xbd08c010 , 0x63c95bcc , 0x9bea9f73 , 0x0e9aa217 , 0x3f04b644 , 0x140ab470 ] )
+  , Mont::unsafe_make( [ 0xb00124ad , 0x8466de55 , 0x041d3138 , 0x4ba85b62 , 0x9f286087 , 0x6cd208c8 , 0x9d07b53e , 0x08d7ae8f ] )
+  , Mont::unsafe_make( [ 0x65dc1d52 , 0x1718c79e , 0xbe096fda , 0x279318c3 , 0xf1221141 , 0x176ee149 , 0x8a896401 , 0x1398bcb0 ] )
+  , Mont::unsafe_make( [ 0x548d0a3d , 0x9f08727d , 0x197fb875 , 0x6b12a224 , 0xaa0ab017 , 0x7d643eba , 0xcf379b9c , 0x1642aeed ] )
+  , Mont::unsafe_make( [ 0x707cfbdc , 0x0c770716 , 0x0ea71f70 , 0xdfffa9f7 , 0x2256206e , 0xf12ef5c7 , 0x5431612f , 0x0aa1ec61 ] )
+  , Mont::unsafe_make( [ 0x0e0a3657 , 0x123d2092 , 0x9867c579 , 0x2a09fd24 , 0x1e1e5578 , 0x94ecb755 , 0x744919e5 , 0x0c23cc5c ] )
+  , Mont::unsafe_make( [ 0x6ac7a5cc , 0xf1f22efc , 0x87758fae , 0x3695ee77 , 0x27c6c55f , 0x8f1d0d6a , 0x3c221887 , 0x0256b4c1 ] )
+  , Mont::unsafe_make( [ 0x6e3a793b , 0xfa22b6ce , 0x4dfae7d5 , 0x2df3c76a , 0x94fe2e8e , 0xa8a7bf7b , 0xeb770b33 , 0x2584514d ] )
+  , Mont::unsafe_make( [ 0x73fc2258 , 0xf6b802d0 , 0xca85e709 , 0xe0decfdf , 0x0fc4c76d , 0x1881ed6b , 0x8640277b , 0x01302bab ] )
+  , Mont::unsafe_make( [ 0xb7628b6e , 0x8ca5efe0 , 0xeeb1aed5 , 0xfba67c82 , 0x7cc263fc , 0xa2132b2a , 0x8b6163e0 , 0x14099172 ] )
+  , Mont::unsafe_make( [ 0x60321552 , 0x8238f401 , 0x6f4ba191 , 0x8a5ddcb9 , 0x757d8d17 , 0xe9105298 , 0x07e22c66 , 0x27428e6d ] )
+  , Mont::unsafe_make( [ 0x65ef36c7 , 0x5bfd0d03 , 0x645e606f , 0x0f7eb606 , 0x32848a90 , 0x730cc1aa , 0xad86d2c1 , 0x27b80e9b ] )
+  , Mont::unsafe_make( [ 0xf774abaf , 0x62e9a859 , 0x8427f55c , 0x25442e0d , 0xd3e16e41 , 0x111a772e , 0x121ae66d , 0x1df656ff ] )
+  , Mont::unsafe_make( [ 0x6f31ace1 , 0x68c5792a , 0x45083e7b , 0x4c9e798c , 0xda169ef7 , 0x3647ccc4 , 0xb3d7d5d8 , 0x2f12a515 ] )
+  , Mont::unsafe_make( [ 0xd28f6447 , 0x8917e911 , 0x64539d8b , 0x133a85a9 , 0x91bb23d6 , 0x68a600cc , 0x555f79ac , 0x0eaab6d0 ] )
+  , Mont::unsafe_make( [ 0x32d0d2f0 , 0xd2f19d74 , 0x6b049879 , 0x5f41d80b , 0xa151cba5 , 0xdedb6504 , 0x55202860 , 0x14d62eac ] )
+  , Mont::unsafe_make( [ 0xd4fc4870 , 0xbf8a013a , 0x0c68426f , 0xc68424e0 , 0xf75bf193 , 0xe0bd47fe , 0xf7417aa2 , 0x208cde30 ] )
+  , Mont::unsafe_make( [ 0x3862cab2 , 0x55240d2f , 0xc56bf9f2 , 0x3a418c2c , 0x0c0a0ab8 , 0x787cabe8 , 0x49d4a836 , 0x0e1e4e56 ] )
+  , Mont::unsafe_make( [ 0xcabab3b5 , 0xdbd621a5 , 0x3355d6e6 , 0x11a20007 , 0xcef5016a , 0x7babb2f1 , 0x429dc665 , 0x1b52b724 ] )
+  , Mont::unsafe_make( [ 0xcda81d59 , 0xe9d3dc6d , 0x0f9e96ba , 0x0cb822e0 , 0xe54fc19f , 0x76e7475b , 0x9f6a3980 , 0x1c7040a0 ] )
+  , Mont::unsafe_make( [ 0x8e20a96c , 0x8b7faa56 , 0x249e4636 , 0xb5a5c339 , 0xef05fbfe , 0x3fd42d79 , 0x111db277 , 0x20dbfeb8 ] )
+  , Mont::unsafe_make( [ 0xee2f464c , 0x3211674d , 0xc4a9a9b6 , 0xc5be7332 , 0xbb47cabf , 0x845e85cf , 0x486d6fca , 0x242368ae ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x8766423e , 0xea2499d1 , 0xdbd9e2a4 , 0x2514e15e , 0xcbcb61d8 , 0x1d5dd71a , 0x8e364dd0 , 0x005880a3 ] )
+  , Mont::unsafe_make( [ 0xa63bb32f , 0x044c9a6d , 0x0eab0e6f , 0x0f2b8835 , 0x46e25753 , 0xbd9c086f , 0x76551fdb , 0x2f6f5da2 ] )
+  , Mont::unsafe_make( [ 0x784bb120 , 0x68725d78 , 0xcc0b1390 , 0x412c2081 , 0x37dc96f2 , 0x05d91bb9 , 0x17f5c1f4 , 0x2972dcb1 ] )
+  , Mont::unsafe_make( [ 0xcecc40b5 , 0x1c1b6d2a , 0xf65fd094 , 0xb0f154ce , 0xcc51e593 , 0x64b1a380 , 0xd0804912 , 0x01a9ffba ] )
+  , Mont::unsafe_make( [ 0x358bfd40 , 0x8c61eff9 , 0xfa3aef01 , 0xb21833ed , 0x57f3b7e6 , 0xc4d56e83 , 0x9b3c75ea , 0x0817fa22 ] )
+  , Mont::unsafe_make( [ 0x24eb1536 , 0xb872cf26 , 0xc6ee2b6e , 0xb0c4cbee , 0x68117da3 , 0xd76723c1 , 0x0942fe3d , 0x08eff939 ] )
+  , Mont::unsafe_make( [ 0x5519751f , 0xc7ecc42e , 0x1929a015 , 0x2116d93c , 0x4602f3a4 , 0x332d0646 , 0x8008cb49 , 0x1a21e481 ] )
+  , Mont::unsafe_make( [ 0x4ecd0638 , 0xa3361baa , 0x899f19bf , 0xa9f6c07d , 0xe5c0ca51 , 0x1067f42c , 0xf8b57344 , 0x24a76851 ] )
+  , Mont::unsafe_make( [ 0xc9ea1184 , 0xbc93dc48 , 0x0f8b6e20 , 0x9a47aea2 , 0xaa7b8c83 , 0xd74c1a6c , 0x39197b55 , 0x13044753 ] )
+  , Mont::unsafe_make( [ 0x03bf1c78 , 0x5a5ef03b , 0xf90fdbab , 0x1abab27d , 0x725c183d , 0x283493dc , 0x3d4e4ca8 , 0x27d35938 ] )
+  , Mont::unsafe_make( [ 0x1fdb101b , 0x4f691131 , 0x629190ab , 0x80b8006b , 0xf1e8393d , 0x89c4398f , 0xed385fdc , 0x1abe6ba3 ] )
+  , Mont::unsafe_make( [ 0x264108ec , 0x9a833a76 , 0xd79427ff , 0x524a7edf , 0xa61e7923 , 0x6c64492f , 0xadd728b5 , 0x172e2211 ] )
+  , Mont::unsafe_make( [ 0x4d3c3e9a , 0x032c5ca2 , 0x1952eb8c , 0x1278fcad , 0xce5a2057 , 0x143542f3 , 0x65865248 , 0x0e73653c ] )
+  , Mont::unsafe_make( [ 0xe91d1584 , 0x78baf80e , 0xa3e69f55 , 0x8857e635 , 0x8bede7ef , 0xc87fc622 , 0x1d7241e2 , 0x071d7ebb ] )
+  , Mont::unsafe_make( [ 0x87c54310 , 0x3b0f426d , 0x2153c9ab , 0x738e0933 , 0x0d51a80a , 0xf1d55567 , 0xc1ecdfed , 0x036de04f ] )
+  , Mont::unsafe_make( [ 0x5b398e17 , 0xb02c65be , 0xe2103ddd , 0x445c2a98 , 0xa6ad4e61 , 0xd2c1d8ff , 0x36fdea23 , 0x29bb89a9 ] )
+  , Mont::unsafe_make( [ 0xe3e42727 , 0xa8e6d813 , 0x81b23072 , 0x48bc59b8 , 0x97bd1937 , 0x06fec676 , 0x8081fccf , 0x1355f780 ] )
+  , Mont::unsafe_make( [ 0x4572daee , 0xdd074236 , 0x8c99b0cc , 0xd5378a85 , 0x8a14f499 , 0x11053f6a , 0x0f967d57 , 0x0e5ca41d ] )
+  , Mont::unsafe_make( [ 0xc7fc6ba8 , 0x21a8edd7 , 0x1c9841fe , 0x18ab4d51 , 0xbb402943 , 0x087c3fa8 , 0x41dab790 , 0x00977a1c ] )
+  , Mont::unsafe_make( [ 0x270a6be4 , 0x9e16b61b , 0x296be8f4 , 0x96a0e5d4 , 0xb1ff7b08 , 0xd9fc771b , 0x90bae7c2 , 0x0e3c3382 ] )
+  , Mont::unsafe_make( [ 0x1cccbc46 , 0x8f2158ca , 0x83406a40 , 0xd0e13592 , 0xbcb9befa , 0xa85479fb , 0xa6540712 , 0x30375775 ] )
+  , Mont::unsafe_make( [ 0x6f350f4d , 0xc7544145 , 0xff48f5da , 0xff096ebb , 0x48585352 , 0xe8a5cdfd , 0xd9022115 , 0x276b0efc ] )
+  , Mont::unsafe_make( [ 0x0be3b083 , 0x69de0bd7 , 0xbda6424a , 0x806dccad , 0xf99bcda1 , 0x1aa434ba , 0x907d0269 , 0x1f93d987 ] )
+  , Mont::unsafe_make( [ 0x48471263 , 0xbf581d58 , 0x783471ae , 0xe5d1607e , 0x9b5fb0ff , 0x3df9a77b , 0x1dd730c5 , 0x1ac3ed1e ] )
+  , Mont::unsafe_make( [ 0x27a46b52 , 0xaee6c099 , 0x6c07e3db , 0x08f53cfe , 0x38d348f9 , 0x3350b749 , 0x018581cf , 0x2f317c6a ] )
+  , Mont::unsafe_make( [ 0xf54da4e7 , 0x72baa727 , 0xd7507606 , 0xecee50cd , 0x16bb32dd , 0xc2251f3b , 0xe0225f2b , 0x1602a6a2 ] )
+  , Mont::unsafe_make( [ 0x9eb1e468 , 0x6f08a38e , 0xd5168454 , 0x601692c6 , 0x590467ed , 0x1635965a , 0xfa172a4f , 0x2387e23e ] )
+  , Mont::unsafe_make( [ 0x720e95bd , 0x25b97cf5 , 0xefae5f05 , 0x76758438 , 0x3beb575f , 0x1a2287f3 , 0xa1ed0772 , 0x26ecc527 ] )
+  , Mont::unsafe_make( [ 0xb2e75929 , 0x6121a16c , 0x53c443f3 , 0x33ef235c , 0xe2bd1e90 , 0xca93d68a , 0xf0a4cd68 , 0x226fc942 ] )
+  , Mont::unsafe_make( [ 0xa4df8dd9 , 0xbc9ad699 , 0x2c4b4f06 , 0x5f61270b , 0x3a071f7d , 0x747fee56 , 0x03057966 , 0x1a82a9c0 ] )
+  , Mont::unsafe_make( [ 0x7c543d62 , 0xbf047551 , 0x77ce5f32 , 0xd5d132f5 , 0x0fa9b3ba , 0x764dd7d7 , 0x78326378 , 0x16b2dc54 ] )
+  , Mont::unsafe_make( [ 0x308d109d , 0x33fb9ee9 , 0xfd84e99a , 0x80f6feb8 , 0x52aa62f9 , 0xd4c864cb , 0x86196b63 , 0x26c74e9a ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xdfbafd5d , 0x992c30ed , 0x310a956a , 0x18b68d2a , 0x90a5d8fa , 0x1bdad20c , 0xe59f549c , 0x0828afb8 ] )
+  , Mont::unsafe_make( [ 0x6645bc2d , 0x538704b6 , 0x8b9597ba , 0x383d784b , 0x96edf259 , 0x75f738be , 0x33245904 , 0x29ecc74a ] )
+  , Mont::unsafe_make( [ 0x285f8c2d , 0xfa968f97 , 0xdc15bc49 , 0x1a1bfa7d , 0x4b501dbf , 0xf27a27c1 , 0x26dd236a , 0x169df259 ] )
+  , Mont::unsafe_make( [ 0x7203644c , 0x1af18ebd , 0xe0f07805 , 0xf227018d , 0x86f9a707 , 0xaa3ea005 , 0x195f348c , 0x205e3973 ] )
+  , Mont::unsafe_make( [ 0xebcb8499 , 0xbacdb954 , 0x2fc1e87d , 0xa428dcfb , 0xc76009b3 , 0xc514616a , 0x3ccceb25 , 0x2eb77552 ] )
+  , Mont::unsafe_make( [ 0xc4165ffe , 0xa4b3cfc0 , 0xf7271b49 , 0x1d650fb4 , 0x75491ebd , 0x4c9bedee , 0x46fcf979 , 0x026cfff2 ] )
+  , Mont::unsafe_make( [ 0xb6758cab , 0x69cc6c3c , 0x3d65a774 , 0xe2c4d62a , 0xdfa60c4d , 0xa8f1058f , 0x42f57d62 , 0x1609e38a ] )
+  , Mont::unsafe_make( [ 0x8108e5da , 0x60c8dc8b , 0x81a34271 , 0x975adcf1 , 0x9cae3927 , 0xe8ee70d4 , 0x50a4169b , 0x0e46cbd2 ] )
+  , Mont::unsafe_make( [ 0xaa56093d , 0x1a1926e5 , 0x935a7be0 , 0x57ecac8d , 0x093c7297 , 0x1842892c , 0x3faec77f , 0x1a776b37 ] )
+  , Mont::unsafe_make( [ 0x076d0856 , 0x77ba90f5 , 0xb2240302 , 0x9303da9a , 0xd1f07765 , 0xc46d3318 , 0x8ddfc0ee , 0x1188e223 ] )
+  , Mont::unsafe_make( [ 0x0002f9a6 , 0x782d9365 , 0xb087d8aa , 0xdb6595af , 0xc335ea82 , 0x8d57a69a , 0xce094e96 , 0x128199c9 ] )
+  , Mont::unsafe_make( [ 0xa21f03ad , 0x81d0c0d2 , 0xb38f026a , 0xd4bf1749 , 0xe9ccf5fd , 0x32cdf80c , 0x106e1c42 , 0x2dfe4b27 ] )
+  , Mont::unsafe_make( [ 0x5d7cd8fb , 0xa112c2c7 , 0xcdd84d16 , 0x5dfa73ba , 0xe61ccbbb , 0x42da72d5 , 0x5c55dfa7 , 0x00a9d172 ] )
+  , Mont::unsafe_make( [ 0xbf8b3d56 , 0xeba050d9 , 0x943722ad , 0x94babc86 , 0x216b7b7f , 0x1b852a50 , 0x0ca1de7e , 0x1fd1fae2 ] )
+  , Mont::unsafe_make( [ 0x01a00c16 , 0xccbe7ce6 , 0xee3c1b5c , 0x86a2af7f , 0x69de2f15 , 0x6f8cd235 , 0x9981a9c9 , 0x2d4d67b9 ] )
+  , Mont::unsafe_make( [ 0x212d019c , 0x4d2e163e , 0x91f45546 , 0xf935b420 , 0xa75e0660 , 0x1819c850 , 0x31223909 , 0x0914289c ] )
+  , Mont::unsafe_make( [ 0x9973ff29 , 0xc6250210 , 0xa537c01e , 0x7f948c09 , 0x092d278b , 0x2abacaf9 , 0x27f6e521 , 0x0498a3c1 ] )
+  , Mont::unsafe_make( [ 0xc03659be , 0x9c06fbfb , 0x75af4606 , 0x37b3dc37 , 0xecd3ed65 , 0xb16923c6 , 0x84a6d5a5 , 0x16c22a53 ] )
+  , Mont::unsafe_make( [ 0xa58a62b6 , 0x432fdb11 , 0xda45bf7b , 0xaa99bc33 , 0xa2a67a5f , 0x0dafcd09 , 0x7a37bc84 , 0x286428a7 ] )
+  , Mont::unsafe_make( [ 0x50d0811c , 0x84922364 , 0xe57f4a97 , 0x6679d6c4 , 0x5908823f , 0xd5ffffe8 , 0x72e83bb4 , 0x02ab8f18 ] )
+  , Mont::unsafe_make( [ 0x787ce4e3 , 0x76a4c4ab , 0x1a68a898 , 0xeae266af , 0xfb210505 , 0xbad0daba , 0x3377d252 , 0x202dc5d1 ] )
+  , Mont::unsafe_make( [ 0xfe87dc9e , 0xab3cd280 , 0xe9a87fe7 , 0x32635aea , 0x7bea85e4 , 0x5dc99b7d , 0x6e80d000 , 0x103b5c77 ] )
+  , Mont::unsafe_make( [ 0x9fd2dd11 , 0x8eaadb55 , 0xf86809bb , 0x690eda40 , 0x22d02d63 , 0xc9d365ea , 0x2a93a74a , 0x14c335c9 ] )
+  , Mont::unsafe_make( [ 0xbc2d5f1b , 0x34ada4b8 , 0x36bb67ea , 0x6b64dd46 , 0x706521e3 , 0x73fe1590 , 0x4e176d94 , 0x0e3d1cc7 ] )
+  , Mont::unsafe_make( [ 0x95792967 , 0xd5ba5614 , 0xfa10104e , 0x0e592649 , 0xefffde1f , 0x94ea1c93 , 0x04546b0a , 0x2addeb59 ] )
+  , Mont::unsafe_make( [ 0xbcff063d , 0x722b2808 , 0xfcd29724 , 0x6d76a806 , 0x12afa470 , 0x064ba559 , 0x128305fd , 0x0f7ce291 ] )
+  , Mont::unsafe_make( [ 0x65f651cb , 0x97817b7a , 0xc30bfdc7 , 0x2be4333b , 0x439ef646 , 0x073a702b , 0x730f5c65 , 0x1a67f84f ] )
+  , Mont::unsafe_make( [ 0x57115f3b , 0xe24e0ffb , 0xc227d656 , 0x7b8c2b95 , 0x9e32daa8 , 0x7bf76084 , 0x5d82b566 , 0x0053aaa7 ] )
+  , Mont::unsafe_make( [ 0x8780be77 , 0x88208dba , 0xe839b3b4 , 0xce8c799e , 0x32e26476 , 0x783c641b , 0x8e9649c9 , 0x03929657 ] )
+  , Mont::unsafe_make( [ 0x55eff631 , 0x6a45c9df , 0x9d0971a9 , 0x7d92b887 , 0x310fc743 , 0x1940c7eb , 0x1f440be1 , 0x17c8fb2b ] )
+  , Mont::unsafe_make( [ 0x4457ab16 , 0xf0a2326b , 0x2b2cd5a4 , 0x8e757c34 , 0x74875220 , 0xc24e384b , 0x877ffc33 , 0x0208c808 ] )
+  , Mont::unsafe_make( [ 0xe55e99bb , 0x650afcea , 0x1a127bbe , 0x1948ed1b , 0x01666774 , 0x1d6b0309 , 0x5acd6022 , 0x03d51152 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x659e57bf , 0x8baae78c , 0x4aed36a4 , 0x4c780f0a , 0xb55ad68a , 0xeebd0041 , 0x8888cbbb , 0x0372b77b ] )
+  , Mont::unsafe_make( [ 0x364ca8bd , 0x387180fc , 0x241ab1ce , 0x4d458f1e , 0xa3062d33 , 0x06a16f9a , 0xafd377d9 , 0x247e7161 ] )
+  , Mont::unsafe_make( [ 0x43244c38 , 0xe43a944c , 0x73a92db6 , 0x6f7593b3 , 0x7ae000a1 , 0x05f19efc , 0x82f7f79a , 0x04ad3687 ] )
+  , Mont::unsafe_make( [ 0xa81ce8f6 , 0xdf614dae , 0x2e4be90b , 0xbc255883 , 0x989c9e8c , 0x56c10d1e , 0xc42ab1a6 , 0x1591fac9 ] )
+  , Mont::unsafe_make( [ 0xa015520d , 0xcc390781 , 0x2c4adfbc , 0x69684e85 , 0xcb1815ac , 0x17229acd , 0x0faa3527 , 0x1d5b93b8 ] )
+  , Mont::unsafe_make( [ 0x358b0f3f , 0x23f66a2c , 0x345410f1 , 0x91689dd6 , 0x470d2dff , 0x9f9d6a0e , 0xe6f496f6 , 0x0529cf88 ] )
+  , Mont::unsafe_make( [ 0x6201858e , 0xea0f9ba2 , 0x6cc4f683 , 0xaa041fe8 , 0x7604d937 , 0x699249f6 , 0x9a7c8af5 , 0x0d6a4cec ] )
+  , Mont::unsafe_make( [ 0x0c1ae1f8 , 0xb82c8325 , 0xf6ec4b53 , 0x9f55d305 , 0x8a50060d , 0xb983006b , 0x376e975d , 0x2e497f19 ] )
+  , Mont::unsafe_make( [ 0x1ce8fc88 , 0xcd870f73 , 0x8141bd3b , 0x2742d8ff , 0xacf27d27 , 0x5c65bbef , 0xf11c3bf3 , 0x1059b5ae ] )
+  , Mont::unsafe_make( [ 0x493e671b , 0x32b6384e , 0x22710280 , 0x374fee30 , 0x2d5022de , 0x0f73b84d , 0x0fc0475e , 0x12d924da ] )
+  , Mont::unsafe_make( [ 0xbdaed186 , 0xc4caaecd , 0xa137daf8 , 0x0295d1b8 , 0x4920e15f , 0x06e9d54e , 0x4a836a74 , 0x0e661c47 ] )
+  , Mont::unsafe_make( [ 0x98b6614a , 0x668fc700 , 0x4aa05d9e , 0x531810f8 , 0xdcf3febe , 0x597a5113 , 0xe01a0aa4 , 0x178b41f1 ] )
+  , Mont::unsafe_make( [ 0x932c0527 , 0x915a7ede , 0x0b901e53 , 0x7a74fefe , 0x2330ea33 , 0xd6873064 , 0x063940d6 , 0x0b5454d5 ] )
+  , Mont::unsafe_make( [ 0x0aa50e42 , 0x6c5b87fb , 0xc85c1156 , 0x98e143f2 , 0xa5a37b62 , 0x51c90caa , 0x62751f5e , 0x0f608261 ] )
+  , Mont::unsafe_make( [ 0x7789a315 , 0x08615b29 , 0x1db9fb15 , 0x5b6b9aef , 0xcf34d3b2 , 0xdef74a00 , 0xd57dccb5 , 0x12fd7498 ] )
+  , Mont::unsafe_make( [ 0x23c360be , 0xb0ab986f , 0x7a77069c , 0x5320906a , 0xd786c7dc , 0x308f0bbb , 0x2d2177ce , 0x099d2c04 ] )
+  , Mont::unsafe_make( [ 0x9eefa684 , 0x5baaa803 , 0xab52235b , 0x985e759a , 0xc32b9a10 , 0x1a212178 , 0x1413cae7 , 0x178d4f2c ] )
+  , Mont::unsafe_make( [ 0xfabc0314 , 0x42ee206d , 0x66074a58 , 0x70b995b7 , 0xd77cea5c , 0x67e0c01c , 0xe571fac4 , 0x2a85005f ] )
+  , Mont::unsafe_make( [ 0xd500b09e , 0x45025284 , 0xaa626113 , 0xd23b2356 , 0x29f9a8c3 , 0x877c3c56 , 0x28090ec0 , 0x1b208ba2 ] )
+  , Mont::unsafe_make( [ 0x726e15fd , 0x3078e13c , 0x891bb10d , 0xd7af0c49 , 0x094e7b62 , 0xfa67bb0a , 0xe03a3791 , 0x24089c17 ] )
+  , Mont::unsafe_make( [ 0x7fc564ec , 0x1927fb40 , 0xd4a72b56 , 0x60ecd253 , 0x43617002 , 0x1fe4b343 , 0xbb76cac1 , 0x075e7868 ] )
+  , Mont::unsafe_make( [ 0x9e9c958a , 0x5074f4aa , 0x69bac703 , 0xa73b62fa , 0x99c83166 , 0xfd839731 , 0x91422d2c , 0x11856b4d ] )
+  , Mont::unsafe_make( [ 0xb0dc779c , 0x10890dd4 , 0xea45f912 , 0x5540e774 , 0x3203053a , 0xaf4c95ce , 0xa142deb1 , 0x23d028fe ] )
+  , Mont::unsafe_make( [ 0x0a1c59c1 , 0x7d3c7c50 , 0x90cd8286 , 0x78310c10 , 0xb8b7d89f , 0xad3c72e5 , 0x329d14af , 0x0a398795 ] )
+  , Mont::unsafe_make( [ 0x506b89fb , 0xc4ed3e01 , 0xad16f8f5 , 0x0e3f5964 , 0x08fce67d , 0xf0af58c0 , 0xe1aeef69 , 0x0b01fd7b ] )
+  , Mont::unsafe_make( [ 0x5f4fca00 , 0x6045e192 , 0x179086ba , 0xe123b048 , 0x089f2621 , 0x6197c93a , 0xb88ed623 , 0x1d0df590 ] )
+  , Mont::unsafe_make( [ 0x8cb7aafb , 0x1a23680e , 0xb66a6ada , 0xe76ea179 , 0xdd683875 , 0xfe48ca66 , 0xc477497d , 0x1a838355 ] )
+  , Mont::unsafe_make( [ 0x166bd2ae , 0xa4851cf9 , 0xb37b5a7b , 0xe6edfae9 , 0xeccd72bb , 0x81e589db , 0xfdbffabe , 0x2d9e31b7 ] )
+  , Mont::unsafe_make( [ 0xdbe966fb , 0xc2b17b42 , 0x5a55ee11 , 0x0b3b3cb5 , 0xe2aeb7e5 , 0x34a51343 , 0xd8e32b18 , 0x13dd12cc ] )
+  , Mont::unsafe_make( [ 0x2d67856d , 0xbbfa8976 , 0x1996008f , 0x3e595ef6 , 0xdc01445d , 0x3d7f8b71 , 0x5ce711bb , 0x2b4c8a99 ] )
+  , Mont::unsafe_make( [ 0x908361ea , 0x2a7655ec , 0x3403a834 , 0xa2102fcf , 0xf3462ade , 0x115c3193 , 0x5045e77d , 0x22d670d6 ] )
+  , Mont::unsafe_make( [ 0x72ed9146 , 0x4ab5062c , 0x61c47db1 , 0x11e89a3a , 0xb4a08a78 , 0x4c0df64c , 0x85f0a552 , 0x26ec0b0b ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x545908bf , 0xb85fc04f , 0x70bf426d , 0x8ada4322 , 0x5abf08da , 0xbc082cbe , 0x90d82751 , 0x2fafceeb ] )
+  , Mont::unsafe_make( [ 0x56c5d76f , 0x28098c69 , 0x91f6e0dc , 0x0deffa4a , 0x1b44e516 , 0x2eef0f93 , 0xc21ea7c7 , 0x1eeaed85 ] )
+  , Mont::unsafe_make( [ 0x2e51a4b0 , 0x0c80e951 , 0xe1c7cdcb , 0x0677603c , 0xffebd0cc , 0x34a3901f , 0x7493f2ac , 0x1b713c53 ] )
+  , Mont::unsafe_make( [ 0x088f4f81 , 0x419f2049 , 0x6417d285 , 0x7e72ede5 , 0x6408bc17 , 0x00d02090 , 0xf2fceb0a , 0x1830b1ed ] )
+  , Mont::unsafe_make( [ 0x179e035f , 0x71b03b07 , 0xb42bc1ec , 0xd73ca24d , 0xbfe356bb , 0x61937524 , 0x66ba34b9 , 0x09f37797 ] )
+  , Mont::unsafe_make( [ 0x14a88fe0 , 0x0b3af465 , 0xea197dc9 , 0x28a3fd1f , 0xdda86b86 , 0x65044139 , 0xfe48e249 , 0x1310a4dd ] )
+  , Mont::unsafe_make( [ 0x7f6492bc , 0xc002737a , 0xd36dae9d , 0x7f70a64c , 0xcd80ec63 , 0xc01a9ace , 0x99f94a38 , 0x2823bdd7 ] )
+  , Mont::unsafe_make( [ 0x820961db , 0xf2825eda , 0x71f840f6 , 0x122d0b0e , 0xa4e1b3f5 , 0x63a77f54 , 0x177ad33c , 0x16070228 ] )
+  , Mont::unsafe_make( [ 0x54fbe4fa , 0x114adab6 , 0xd22cdce1 , 0xb0bdc589 , 0xd5732454 , 0x3d6d97cf , 0xd54ffc2e , 0x0fec8009 ] )
+  , Mont::unsafe_make( [ 0x5d1558f4 , 0xebd459ca , 0x71032f06 , 0x4ea15291 , 0x4cd4c7f8 , 0x67f1f73d , 0x5a505749 , 0x1f6d9230 ] )
+  , Mont::unsafe_make( [ 0x76e19c33 , 0x325f8919 , 0x29b90afe , 0x29006197 , 0xb6b4e5b8 , 0x69857b8d , 0x7a85eda7 , 0x0c63c8df ] )
+  , Mont::unsafe_make( [ 0x64ac0a57 , 0x16e2c81b , 0x30a6f8a7 , 0x34fe640f , 0x0c80b37d , 0xe3d6c945 , 0x9249f007 , 0x29425b5b ] )
+  , Mont::unsafe_make( [ 0x6f5de242 , 0x4050f40d , 0xa29a498d , 0x78d08864 , 0xe70381ce , 0xf1705208 , 0xeb05e051 , 0x26a08e98 ] )
+  , Mont::unsafe_make( [ 0x8833a27d , 0x01f856c7 , 0xdca7a40c , 0x3807a941 , 0x056c5137 , 0x53435565 , 0x2985dfb1 , 0x25997b9e ] )
+  , Mont::unsafe_make( [ 0x468a0056 , 0xe0b1b5d1 , 0xb8c728b7 , 0xb9136ee1 , 0x500909b5 , 0x20d9af07 , 0xab11aa5f , 0x1541f411 ] )
+  , Mont::unsafe_make( [ 0xd2645947 , 0xb7686cf3 , 0xa65490b7 , 0x7d78deb1 , 0x65bb13a2 , 0xcd46cc89 , 0x9f3a6f99 , 0x2dddea37 ] )
+  , Mont::unsafe_make( [ 0x479386ea , 0xfcdee772 , 0x98979798 , 0xb122ab7b , 0x8ecda6af , 0xa57104b2 , 0x2f7fc1e3 , 0x14e29110 ] )
+  , Mont::unsafe_make( [ 0x55ff33f5 , 0xa3d8c09e , 0x803df152 , 0xef6c96e3 , 0x20a6bedc , 0xb3aad859 , 0xf428350b , 0x21cef8aa ] )
+  , Mont::unsafe_make( [ 0xe7d03220 , 0x08cdf492 , 0xb93ed39d , 0x43840bc1 , 0x140bc29b , 0x17f72632 , 0x49bb6d48 , 0x162a1759 ] )
+  , Mont::unsafe_make( [ 0x821ab19d , 0x1c51a3ef , 0x0183fc9f , 0x92aaa5b7 , 0x125e4618 , 0xbfa825e4 , 0x27ae0df8 , 0x264e7696 ] )
+  , Mont::unsafe_make( [ 0xba7a09cd , 0x0bc7789d , 0xfe69d15d , 0x8b8c8424 , 0x764dbbac , 0xeb368f44 , 0x72a82ea2 , 0x26b5016b ] )
+  , Mont::unsafe_make( [ 0xa23f6598 , 0xab4c994b , 0xdedb68fd , 0x1cc4df3f , 0xfe07bd54 , 0xc609af08 , 0x33a1c38f , 0x1f87448f ] )
+  , Mont::unsafe_make( [ 0x3d93b328 , 0x0a14a2fe , 0xbaed2777 , 0xaf38ce08 , 0xc9247809 , 0x36c051e7 , 0xd9a8d3af , 0x1d331855 ] )
+  , Mont::unsafe_make( [ 0x75d27af7 , 0x010fbb96 , 0xae22ebbe , 0xeec1136c , 0x53c7451a , 0x9943eee1 , 0xa9785048 , 0x18bf6f30 ] )
+  , Mont::unsafe_make( [ 0x996f0953 , 0x7660e491 , 0xdc72d5db , 0x7b1e7eda , 0x144e5084 , 0x359d71a2 , 0x69d87476 , 0x23641158 ] )
+  , Mont::unsafe_make( [ 0xbdecb0fd , 0x43e2e820 , 0xacf7181c , 0x2faf7e43 , 0x7921f5c2 , 0xf7e26257 , 0xb967ec40 , 0x086739f3 ] )
+  , Mont::unsafe_make( [ 0xa6dbcfa9 , 0xd4707468 , 0x74ca6a21 , 0xe53f540c , 0x7760c978 , 0xaa9ade64 , 0x66ca1fb4 , 0x143c133a ] )
+  , Mont::unsafe_make( [ 0xbb54b381 , 0xeda70d3e , 0xffca2833 , 0xd1518101 , 0x5b319bdd , 0x7edcd25a , 0xc187ad76 , 0x24075f11 ] )
+  , Mont::unsafe_make( [ 0x4275de96 , 0x55350a8d , 0x4c948a26 , 0xf483dc1b , 0xea4624a4 , 0x4a89943a , 0x9b1e29a0 , 0x0b7eb6c9 ] )
+  , Mont::unsafe_make( [ 0x342650eb , 0x2bd79750 , 0x087b9436 , 0xb2a07b9e , 0xca4a71a3 , 0x5ad330a6 , 0x9f6ad1d1 , 0x1b7f28ab ] )
+  , Mont::unsafe_make( [ 0x00fcfc14 , 0x8f8b89ec , 0xfa958748 , 0x6a2ad869 , 0x87c76e07 , 0x838f1c4b , 0xe1b4cee5 , 0x0ed6fd1d ] )
+  , Mont::unsafe_make( [ 0xdc28fa05 , 0xe71564c6 , 0xc2a675da , 0x7add21b0 , 0xdde53611 , 0x23b80b32 , 0xcef0c355 , 0x00db9101 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x5393012e , 0xde59f4a7 , 0xd0290085 , 0xbe35e612 , 0x1769bd26 , 0x295e2ce8 , 0xa95bb844 , 0x2dce9886 ] )
+  , Mont::unsafe_make( [ 0x4577be44 , 0x640db0dd , 0xdb992b7f , 0xbb767552 , 0x5711fa1f , 0x682a5a5d , 0xdbdde4d1 , 0x18a9ff4b ] )
+  , Mont::unsafe_make( [ 0xa9d387b1 , 0x139ee7ad , 0x0e74c91c , 0x787319b6 , 0x4afbf1e3 , 0xbd0a2816 , 0xacce63d9 , 0x033b6eb5 ] )
+  , Mont::unsafe_make( [ 0xee91da7c , 0xaf628438 , 0xefc8d6fd , 0xd541ca35 , 0x9775e77f , 0x0b51c0dd , 0x0cd041a8 , 0x048c292f ] )
+  , Mont::unsafe_make( [ 0x26d68329 , 0x86b228cd , 0x103bab9a , 0x85a352fe , 0x2e00f2dc , 0xf71ec3bc , 0xc10d7d33 , 0x29482ca7 ] )
+  , Mont::unsafe_make( [ 0x21e76975 , 0xfe628459 , 0xdc56501c , 0x0f3dd06e , 0xe43dd4aa , 0xb0656664 , 0x6968fd33 , 0x1d0394f0 ] )
+  , Mont::unsafe_make( [ 0xdfa5f2ac , 0x2a57b872 , 0xe0f3ff88 , 0xf2a7d725 , 0xe50fb16e , 0x1c6543d9 , 0x5cef98ec , 0x2edcf62b ] )
+  , Mont::unsafe_make( [ 0xc8a2f1ed , 0x7b569bd4 , 0xf0a2db53 , 0xa6fa087d , 0x3f275dfa , 0xb89917a3 , 0xd6acf0ec , 0x0fb5bd6a ] )
+  , Mont::unsafe_make( [ 0xca05bfe1 , 0x543544c4 , 0x3e1306d0 , 0x63d37994 , 0x994a68a3 , 0x9877e44e , 0x389481cd , 0x15a81a57 ] )
+  , Mont::unsafe_make( [ 0x00dd065f , 0x45959aa4 , 0xde07f11f , 0xbda0acb8 , 0x8fbc7a6e , 0x4917003a , 0x2023a81b , 0x2f8c5e0d ] )
+  , Mont::unsafe_make( [ 0xa522d426 , 0x0246271b , 0x5cf755af , 0xb880da80 , 0xb9790fbc , 0xef4ad709 , 0x527f54d6 , 0x26777746 ] )
+  , Mont::unsafe_make( [ 0x6f821222 , 0xb8035b9f , 0x09e39916 , 0x6208f636 , 0xf89e844f , 0xfb3ed970 , 0x72c30147 , 0x0ea0b55e ] )
+  , Mont::unsafe_make( [ 0x78128570 , 0x39cb1c76 , 0x08196ec3 , 0x1dbfa9f1 , 0xf5a9e7eb , 0xe1a5bb3c , 0x61e0d0b1 , 0x1c7d3278 ] )
+  , Mont::unsafe_make( [ 0x47db7141 , 0xbe836187 , 0x7c378b89 , 0xd7246a67 , 0xac8dc851 , 0x25e37960 , 0x8b76521f , 0x1c011b25 ] )
+  , Mont::unsafe_make( [ 0xf5729d38 , 0x1e712994 , 0xccab6731 , 0xd238f37f , 0xb115e339 , 0x45cbc466 , 0xebd04a19 , 0x0467b8ef ] )
+  , Mont::unsafe_make( [ 0xcc468dce , 0xe410115c , 0x92ff510a , 0x5f956e62 , 0x36610c4b , 0x74bf7f11 , 0x5faab346 , 0x1c20d031 ] )
+  , Mont::unsafe_make( [ 0x13707786 , 0xb66665e0 , 0x20d1109d , 0xcb7ef693 , 0x8e02a7e0 , 0x4328a5d8 , 0xc606be01 , 0x0e4f8faf ] )
+  , Mont::unsafe_make( [ 0x2d6873a1 , 0xf084f134 , 0x15711402 , 0x5cd266da , 0x334bcb7f , 0x86df7bfe , 0x85e81c51 , 0x082d90e5 ] )
+  , Mont::unsafe_make( [ 0x858cf6d9 , 0x65da8ec7 , 0xfbc57950 , 0xa7da4fa4 , 0xb77cf353 , 0xf7c37f63 , 0x2f076b9c , 0x1b81d4f9 ] )
+  , Mont::unsafe_make( [ 0x8240d9f1 , 0x669fef6c , 0x366eb7f2 , 0x7a745a95 , 0x3af2738c , 0x1c702923 , 0x876978d5 , 0x209491a7 ] )
+  , Mont::unsafe_make( [ 0x9f932143 , 0x5c58042f , 0x1d2bf741 , 0x1e36b454 , 0x2748fc37 , 0xeb4beb38 , 0x5af42090 , 0x23f04e63 ] )
+  , Mont::unsafe_make( [ 0x3c9d6798 , 0x6f1e7ed2 , 0xa8c804de , 0xe89fec9e , 0xe6ebe68e , 0x5962f57c , 0x5d228054 , 0x0c58074d ] )
+  , Mont::unsafe_make( [ 0x0027f4b9 , 0xad3f89b1 , 0x418c9957 , 0x000d4553 , 0x7bbbbcc9 , 0xa620336e , 0x57c9b143 , 0x2cad0068 ] )
+  , Mont::unsafe_make( [ 0xb1d8fb97 , 0xf3824b8a , 0xd5a18e3e , 0xfa92fb43 , 0xfa1ac4b4 , 0x3eaae1e4 , 0x14d455b8 , 0x1b75fb61 ] )
+  , Mont::unsafe_make( [ 0x075c9ca4 , 0xabeaf1a8 , 0x33260f11 , 0xe343cd17 , 0x37840451 , 0xded2e66e , 0x8cdf9419 , 0x14ec5a88 ] )
+  , Mont::unsafe_make( [ 0x949e36d9 , 0xfe341e90 , 0x9ede147c , 0xb7991160 , 0x2de75592 , 0xa09281ad , 0xa2047f11 , 0x1be1848f ] )
+  , Mont::unsafe_make( [ 0xeff2bf3f , 0xe2a49954 , 0x2c8c09fb , 0x7fd38a81 , 0x01083c28 , 0xcc8d47a7 , 0xe1ef5c8e , 0x2c9473ca ] )
+  , Mont::unsafe_make( [ 0xa56d925d , 0x0b602e72 , 0x054a188e , 0x57ab6475 , 0xb82bf6e2 , 0x0df67f5d , 0xef3a2169 , 0x2f0efb11 ] )
+  , Mont::unsafe_make( [ 0xbbe3019e , 0xab12bd6a , 0x3f9dd15b , 0x6402c409 , 0xb665b142 , 0xd3a63c5b , 0xa1d01530 , 0x2d49db45 ] )
+  , Mont::unsafe_make( [ 0xadece1fa , 0x6b2931d4 , 0x2ef4aaf0 , 0x78c69643 , 0x4704a946 , 0xd690b0d6 , 0x3fc94f0a , 0x2c61eb21 ] )
+  , Mont::unsafe_make( [ 0x5e2794e8 , 0x2a8eb467 , 0xaf6d9323 , 0xa9f1b996 , 0x6c46bef9 , 0x130f074d , 0x20b2d19b , 0x25c095ae ] )
+  , Mont::unsafe_make( [ 0x38879c6a , 0xf35f5aa0 , 0xa5e39b9b , 0x9e883127 , 0xf2b653b6 , 0xf15e83bb , 0xfcc04a7e , 0x2afbcad4 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x93cf0b1d , 0xd42bc337 , 0x413ad99c , 0x443b9e79 , 0xb3a76ffe , 0x32dcefb3 , 0x18a38e33 , 0x0d8e96e3 ] )
+  , Mont::unsafe_make( [ 0x50dedd4f , 0xa033ac0f , 0x6028d339 , 0x03135411 , 0x18666b3d , 0x2360b7a9 , 0x88b3fbae , 0x1f439a08 ] )
+  , Mont::unsafe_make( [ 0xad88e556 , 0xc61aa87b , 0x1c5e8adf , 0xcc173830 , 0xc9f9194f , 0x15f8ba3c , 0x8a6c8bff , 0x07153b18 ] )
+  , Mont::unsafe_make( [ 0xfe3f161d , 0x13b0be9f , 0x0b285e52 , 0xdb7680c2 , 0xf41abcaf , 0x1828c47c , 0x486763b3 , 0x0f90e740 ] )
+  , Mont::unsafe_make( [ 0x7e95c8d5 , 0x20460645 , 0x4589eb5c , 0xd06cacd4 , 0x92cb0487 , 0xd87b6498 , 0xe212d278 , 0x12b84c26 ] )
+  , Mont::unsafe_make( [ 0x154ab587 , 0xb97188c9 , 0xa6887d55 , 0x1a4eec3f , 0x572c6db9 , 0x00447950 , 0x6f81a675 , 0x0a22e1cc ] )
+  , Mont::unsafe_make( [ 0x851fb9de , 0x4100f1e3 , 0xc5f377f7 , 0xd5118735 , 0xc5aef293 , 0x480df80a , 0xa81b348f , 0x29c0d165 ] )
+  , Mont::unsafe_make( [ 0x6a99729f , 0x7d2cd176 , 0xe3fc1da0 , 0x4af4451c , 0xc53408f2 , 0x31a2798c , 0x67da57aa , 0x153914c2 ] )
+  , Mont::unsafe_make( [ 0x171be72f , 0x7d949f17 , 0x37946f32 , 0x7c395e0e , 0x13b9e79c , 0xabe835db , 0x225816e7 , 0x11873358 ] )
+  , Mont::unsafe_make( [ 0xd439846c , 0xcd8c7a0e , 0x2adb2c34 , 0x7d19a32d , 0xaa983ee9 , 0x30900e6b , 0x597b8574 , 0x0815b78d ] )
+  , Mont::unsafe_make( [ 0xdb640a56 , 0xd17f8fe9 , 0xbb4bcbd3 , 0xfe5ada92 , 0x023cf315 , 0x45a801d4 , 0x213788f8 , 0x1d02fd48 ] )
+  , Mont::unsafe_make( [ 0x769c9294 , 0x6519644e , 0x8865bb57 , 0x25697c8f , 0x1e3f6f4c , 0x38fb2bdd , 0x04e53f44 , 0x0415459b ] )
+  , Mont::unsafe_make( [ 0xffca1119 , 0x9ee0eeb6 , 0x208461eb , 0xd5fb6549 , 0x4d7688d9 , 0x02a60888 , 0x74ec16ea , 0x158d1450 ] )
+  , Mont::unsafe_make( [ 0x0a6d47d8 , 0x6b197217 , 0x5e73f1bf , 0x20b96d7b , 0xe763981c , 0xe3c62216 , 0xd2d23ad8 , 0x15e38f27 ] )
+  , Mont::unsafe_make( [ 0x12d083d7 , 0xed899efb , 0x5660a294 , 0xba58941c , 0x973232a9 , 0xb14fd047 , 0xc4c2f15a , 0x1c9814dc ] )
+  , Mont::unsafe_make( [ 0x0a6b978c , 0xe64469a9 , 0xa7814eba , 0x5a3b1250 , 0x1310c4b7 , 0x7faeb195 , 0x8ff88cf8 , 0x1f047ce4 ] )
+  , Mont::unsafe_make( [ 0xf591438a , 0xf3e208aa , 0xdd29a29e , 0x9a9ef74e , 0xe8799ae5 , 0x272f116b , 0xd1a6ba32 , 0x19077bbf ] )
+  , Mont::unsafe_make( [ 0x45a66203 , 0xb9e8c607 , 0x3a5b9fe6 , 0x7024982f , 0x337254a9 , 0x33688dd1 , 0xebf1733b , 0x0e209790 ] )
+  , Mont::unsafe_make( [ 0xedc08638 , 0x18396ba1 , 0x82821b96 , 0x02d9886f , 0x18288dc4 , 0x68458cf0 , 0x1b4cc38c , 0x03d16eb6 ] )
+  , Mont::unsafe_make( [ 0x387a0186 , 0xdab707f7 , 0x70998bd4 , 0x464e7b61 , 0x49098a07 , 0xe72bede8 , 0x7ae0857c , 0x07ff9bdd ] )
+  , Mont::unsafe_make( [ 0xa65d5fcf , 0xbb017cad , 0xe7b95806 , 0x3a5d0a19 , 0xe8d961ba , 0x34955b7e , 0x804aa5ad , 0x1a784d54 ] )
+  , Mont::unsafe_make( [ 0xdd6d208e , 0xe113f426 , 0xa6db5ae0 , 0x63ae3518 , 0x2dcc7651 , 0xedbdfcd7 , 0x9aaa9a31 , 0x01b21bb1 ] )
+  , Mont::unsafe_make( [ 0x0e1ea702 , 0x2714e1fe , 0xea063c9b , 0xa32eef0f , 0x62b62304 , 0x3c90db96 , 0x93eedcc8 , 0x09834851 ] )
+  , Mont::unsafe_make( [ 0x8eccaf5f , 0x364bb446 , 0x993b3493 , 0xecee91fe , 0x24656f29 , 0x7602a515 , 0xcba1fb21 , 0x01357658 ] )
+  , Mont::unsafe_make( [ 0x42b9ffb2 , 0x5dc1e7d2 , 0x661dd231 , 0xedbbeba5 , 0x04f1e561 , 0x7fac2c10 , 0x9f86bab2 , 0x08afa84d ] )
+  , Mont::unsafe_make( [ 0x92adedd8 , 0x5a84215e , 0x950b77f7 , 0xb1c52d93 , 0x9fe7325e , 0x9407f6cd , 0xa265ae39 , 0x2f0f69c5 ] )
+  , Mont::unsafe_make( [ 0xdfa21730 , 0x2a90386c , 0x234f3e6b , 0xe77e24c4 , 0xadc1d2cd , 0x3be7dee3 , 0x1cfde65c , 0x22f35241 ] )
+  , Mont::unsafe_make( [ 0x52968ab3 , 0xed451098 , 0x658ef1f5 , 0x94e377fc , 0x697c6dbb , 0x4c48e439 , 0x6e55c233 , 0x15908832 ] )
+  , Mont::unsafe_make( [ 0x97d53c89 , 0xeb60fe7e , 0xf74b81de , 0xe468114d , 0x09536370 , 0x0a654b76 , 0x7a7d64f2 , 0x2f496f39 ] )
+  , Mont::unsafe_make( [ 0x2b7f48b8 , 0x266e0209 , 0x68428b52 , 0xfe7ef550 , 0x21fbbe08 , 0xc51101a6 , 0xbcee43f5 , 0x2de1f0b3 ] )
+  , Mont::unsafe_make( [ 0x13edea97 , 0x3d8c1b5d , 0xecb467d8 , 0x11db78fa , 0xfe9cb773 , 0x5e4164d0 , 0x7e54affa , 0x2f97a5a7 ] )
+  , Mont::unsafe_make( [ 0x9b6977a3 , 0x44b8df62 , 0x3c05657a , 0x7288bfca , 0x2e7726b4 , 0xd4178aee , 0xfddc5862 , 0x0bd959b6 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x07b17b5a , 0x85ebbd09 , 0xdf64a3af , 0x2e1df250 , 0x2e91ffc1 , 0xe72e5158 , 0x083b032f , 0x28916f97 ] )
+  , Mont::unsafe_make( [ 0xdd5cd3d9 , 0x69de0d68 , 0x9d6538ae , 0x245a60bd , 0xcd117fd1 , 0x3b364e32 , 0x967937f3 , 0x122cb80d ] )
+  , Mont::unsafe_make( [ 0xcdafb0fa , 0xfd0f907f , 0xc377420c , 0x236b3b9c , 0xa28664c3 , 0xcdeff71f , 0xfe070aae , 0x10fc94a9 ] )
+  , Mont::unsafe_make( [ 0xfe26e96d , 0x2d86e3c4 , 0x98d9c607 , 0xe05eb5e5 , 0x89a92d35 , 0x1bb42c91 , 0x1eb0abff , 0x0787815e ] )
+  , Mont::unsafe_make( [ 0x3f39162e , 0x67a7f711 , 0x5ae6f3a2 , 0x63a7cf95 , 0x0b8aa76d , 0x716460ee , 0x7eb99050 , 0x00ea7604 ] )
+  , Mont::unsafe_make( [ 0x0b4fda0d , 0xf550cd68 , 0x8ed2cb88 , 0x07d5d487 , 0x2a902ed4 , 0x225f35a3 , 0x986a4685 , 0x11ebbabd ] )
+  , Mont::unsafe_make( [ 0xb48be408 , 0x8ff413d5 , 0x57f08e96 , 0x265c41ca , 0xd500696c , 0x9d9e376b , 0xfed0da99 , 0x0ebcb5bb ] )
+  , Mont::unsafe_make( [ 0x8eb1a59a , 0x6d540bb4 , 0x4b6cf1c0 , 0x20335fe7 , 0x09f22daf , 0xd85837ef , 0x4d1d07eb , 0x21713fa6 ] )
+  , Mont::unsafe_make( [ 0xdc4f4c38 , 0x0055d27c , 0x956e88b4 , 0x83b2a1fb , 0xe3f8e85d , 0x16e160c1 , 0xa132ee7e , 0x20d3c991 ] )
+  , Mont::unsafe_make( [ 0xc60a21d8 , 0x71ebdc46 , 0x97f97e7f , 0xff47eeee , 0x7241f1a7 , 0x20c2abb1 , 0xa5ed21f8 , 0x25719a72 ] )
+  , Mont::unsafe_make( [ 0x5773f84c , 0x307d2929 , 0x40aa62fe , 0x5fa19e2d , 0x4755d0f2 , 0x01833a1b , 0x41650ec1 , 0x13e6310c ] )
+  , Mont::unsafe_make( [ 0xd8ed90e1 , 0xb6cf60fb , 0x52a68d0e , 0xfc9dd23a , 0x5bc0d285 , 0xd2c7ce9d , 0x557ea7b8 , 0x1238159b ] )
+  , Mont::unsafe_make( [ 0x87570d28 , 0xa4fc1a27 , 0x95e9723d , 0x9b5f1e53 , 0x610eb01b , 0xe9209b2e , 0xf595190f , 0x173de1f3 ] )
+  , Mont::unsafe_make( [ 0xcff86a91 , 0xe25f8d50 , 0x608ff059 , 0x73021dfd , 0x8ce4fb32 , 0xd0753216 , 0xbf6beace , 0x0b963c8d ] )
+  , Mont::unsafe_make( [ 0xe92a121a , 0xfab88d5d , 0x9530b7d0 , 0x9e134a18 , 0xb485f1f6 , 0xc33ae72e , 0x9e85a8e3 , 0x096a4563 ] )
+  , Mont::unsafe_make( [ 0xec8d8e78 , 0x43886f04 , 0x1da81516 , 0xb0b6dccc , 0xdab430a3 , 0xd298ea22 , 0x52592af3 , 0x1adc315a ] )
+  , Mont::unsafe_make( [ 0xfe64e32f , 0xf30d194f , 0x44672be8 , 0x7a61d1f4 , 0x5ee55209 , 0x74de4142 , 0x377084fa , 0x1c9d7f24 ] )
+  , Mont::unsafe_make( [ 0x31a837b8 , 0x20ef99c3 , 0xd8beae53 , 0x5cdb60ad , 0x54508a57 , 0x871d9ff2 , 0x5a12dd5d , 0x0abd266f ] )
+  , Mont::unsafe_make( [ 0xd4af892a , 0x8e7c73b7 , 0xc5f24967 , 0x9410a804 , 0x2ebea5da , 0x642bbd89 , 0xb5ca17a1 , 0x29e6910a ] )
+  , Mont::unsafe_make( [ 0xd5568483 , 0x02da6396 , 0xa04a82f9 , 0x945ea95a , 0xaf3aee52 , 0xf3a61af7 , 0x47d51b18 , 0x0b362a1b ] )
+  , Mont::unsafe_make( [ 0x28def434 , 0xf2cc6437 , 0xf490a927 , 0x08efc378 , 0x37546afd , 0x1fba6672 , 0x2f982d82 , 0x2e0c1641 ] )
+  , Mont::unsafe_make( [ 0xffa873f9 , 0x3fa08d34 , 0x67bd8afc , 0xd65096fe , 0x4ebbde35 , 0x0d47a461 , 0x338a1734 , 0x05caef88 ] )
+  , Mont::unsafe_make( [ 0x588969e7 , 0x66222214 , 0x6f7ff6fe , 0x9bd39b5c , 0xb60cd466 , 0xe6734fbd , 0xd98f9c75 , 0x207d75e3 ] )
+  , Mont::unsafe_make( [ 0xf7e29b34 , 0x93494e13 , 0xf4431b50 , 0x8ef8f8b7 , 0x9171a34b , 0xf51f6b04 , 0xb7d98efe , 0x13dc6df2 ] )
+  , Mont::unsafe_make( [ 0x5fd9a590 , 0x59e82501 , 0x7b71bd52 , 0xeced5308 , 0x1b90052d , 0x80f4cd0d , 0xd9366154 , 0x05ec9e13 ] )
+  , Mont::unsafe_make( [ 0x9f8446ff , 0x672bfe80 , 0x6baa4a63 , 0x72277068 , 0xa1acbeec , 0x748ca12a , 0x39944dd3 , 0x10547802 ] )
+  , Mont::unsafe_make( [ 0xfd4982e9 , 0xded1b7c9 , 0x0eaf269a , 0xc233294a , 0xa5b4af04 , 0xb4df6eac , 0xcd2725ca , 0x049f9236 ] )
+  , Mont::unsafe_make( [ 0xc6af7649 , 0x53bf56d7 , 0x565988f1 , 0x257810f4 , 0x26307f90 , 0x03f341cf , 0x52fc2e26 , 0x2983c4a4 ] )
+  , Mont::unsafe_make( [ 0xb1eb4067 , 0x4d18d2f0 , 0x150ead44 , 0x00b74572 , 0xf1824891 , 0xcb06b0cf , 0x5fb04b7b , 0x04d030b9 ] )
+  , Mont::unsafe_make( [ 0x847b5433 , 0xadee11b4 , 0x31ec0767 , 0xe7dbcbcd , 0xb31d199c , 0xcaef06f1 , 0xcc98924a , 0x268291c3 ] )
+  , Mont::unsafe_make( [ 0x7bedc574 , 0x37a2ed7c , 0xbb286efa , 0x147fe108 , 0x2ac62b29 , 0xc407c575 , 0xdcdce5c4 , 0x25e93799 ] )
+  , Mont::unsafe_make( [ 0x16d3564c , 0x70b5d95b , 0x570a83dd , 0x6b7bc726 , 0x690919ff , 0x285324b4 , 0x05365705 , 0x1861de3b ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xaeb6dd5f , 0xa76b6657 , 0x821dad08 , 0x55fd94dc , 0x3e45a5f2 , 0xae4afe09 , 0xe1c514b4 , 0x1f5ee706 ] )
+  , Mont::unsafe_make( [ 0x91a093c4 , 0x9ca86828 , 0xd39be956 , 0xfada87fc , 0x0ecd50dd , 0x60454649 , 0xadb0a566 , 0x2970141c ] )
+  , Mont::unsafe_make( [ 0x18841d3d , 0x3ca98117 , 0x5b064653 , 0x60efc8f9 , 0x4576daba , 0xccf3ad5e , 0x0cac3305 , 0x2634ca42 ] )
+  , Mont::unsafe_make( [ 0x1716e5ea , 0xfe7b5e48 , 0xe41ef2ee , 0x4d1620c8 , 0x44f84199 , 0xfcdc21f5 , 0x973774a5 , 0x0e1fd583 ] )
+  , Mont::unsafe_make( [ 0xeb023229 , 0xcbddcb54 , 0x7ca9f4a3 , 0x2902bb2e , 0xd2654409 , 0x9a6dca01 , 0xfe7b7e4f , 0x0b679016 ] )
+  , Mont::unsafe_make( [ 0x70265320 , 0xe7c1d5fb , 0xd2a92616 , 0xa1a6e75e , 0xe7156cb3 , 0x586addb6 , 0xbf4eae29 , 0x21974fa7 ] )
+  , Mont::unsafe_make( [ 0xbd005e25 , 0x12293903 , 0x96401260 , 0xa3cc5864 , 0x050e2b8a , 0x7ba74904 , 0xa759d256 , 0x2d95da6e ] )
+  , Mont::unsafe_make( [ 0x6a5cae71 , 0x9828be69 , 0x609a353a , 0x0cc6466e , 0x91df5db5 , 0x97257b50 , 0xf4590268 , 0x18dc9804 ] )
+  , Mont::unsafe_make( [ 0x8fa881f2 , 0xbae69a56 , 0xe96bf0d9 , 0x09b63291 , 0x25bc2e06 , 0x14fe6482 , 0x0dbd9ba3 , 0x1bb4de06 ] )
+  , Mont::unsafe_make( [ 0x071eb282 , 0x59f99808 , 0xfbe5e67b , 0x9082d970 , 0x74fbcd7b , 0x9225ddeb , 0x61a40ec2 , 0x117733f8 ] )
+  , Mont::unsafe_make( [ 0xd4d41674 , 0xf78b817b , 0xc319df28 , 0x21a607f6 , 0xc4f52a50 , 0xdc4794bc , 0xd8ea5d98 , 0x113bffb1 ] )
+  , Mont::unsafe_make( [ 0x0fd807c7 , 0x1ecf195e , 0xf6ac6501 , 0x317d6d4d , 0x1054fedd , 0x0cea7273 , 0xb290ca5c , 0x01749868 ] )
+  , Mont::unsafe_make( [ 0xdbd1e207 , 0x6a7c6157 , 0x0fc76f72 , 0x5488b0eb , 0x2b42e436 , 0x44612d57 , 0x4e30bb7a , 0x0acf56d4 ] )
+  , Mont::unsafe_make( [ 0x59fdb370 , 0xb0fab79b , 0xf77612f1 , 0x201a8236 , 0xb88c8951 , 0xfdd3230e , 0x10114dd7 , 0x1ffc8b46 ] )
+  , Mont::unsafe_make( [ 0xe1b73ba8 , 0xd4b75cdd , 0x01b45f5b , 0x1d1e47c1 , 0x26e747c0 , 0xf198946d , 0x15b74181 , 0x0601ef83 ] )
+  , Mont::unsafe_make( [ 0xaf384b52 , 0x7d504032 , 0x0ee6148d , 0xd5d42408 , 0xfd44e781 , 0x7b4d983e , 0x0ef83ef2 , 0x0ba0b313 ] )
+  , Mont::unsafe_make( [ 0x3417e885 , 0x2f2e0a30 , 0x1414e0d3 , 0x749270e8 , 0x3bd6de66 , 0x9d3db424 , 0x6e104fb2 , 0x21d1b362 ] )
+  , Mont::unsafe_make( [ 0xf308f897 , 0xb24fbb4e , 0xfadba3e5 , 0xc7aaedd3 , 0x52b967ff , 0xbf70356e , 0x3d434d09 , 0x2b189c77 ] )
+  , Mont::unsafe_make( [ 0x4aa02249 , 0x0b56b4c8 , 0x5bf8b6fc , 0x152570e0 , 0x84e3f416 , 0xc178936d , 0x87ec6c1b , 0x09fce6fc ] )
+  , Mont::unsafe_make( [ 0x5cc7792c , 0xda5220b9 , 0xac362f62 , 0xacd81d94 , 0x7d5b642f , 0x4e0bb676 , 0x979425a5 , 0x1783a105 ] )
+  , Mont::unsafe_make( [ 0xdbd7327d , 0x41348910 , 0x5bda4cc0 , 0xac95e002 , 0x5855c0c0 , 0x4d466300 , 0xbe6ab1dc , 0x2b011326 ] )
+  , Mont::unsafe_make( [ 0xa71ef0ff , 0xe7c64a13 , 0xc379a35d , 0xb07bfc87 , 0x23aaa73c , 0xbc354acc , 0x5d6a4c81 , 0x1a1bb067 ] )
+  , Mont::unsafe_make( [ 0x32ca005d , 0x0632b088 , 0xd8c67f76 , 0xe1cec0f5 , 0x98f3d783 , 0xc3a62c7a , 0x57dc204d , 0x100e3563 ] )
+  , Mont::unsafe_make( [ 0x76fe5a94 , 0x0440b1d6 , 0xef70a952 , 0xd6d9f074 , 0x46b23304 , 0x8ef5b7f7 , 0x71357ff8 , 0x00d9a797 ] )
+  , Mont::unsafe_make( [ 0xd92ba4f7 , 0x1686114a , 0x516354bd , 0x805b6e58 , 0x189062c2 , 0x1bfc9598 , 0x6af13ea4 , 0x184280d7 ] )
+  , Mont::unsafe_make( [ 0x20ee5854 , 0xf200c415 , 0x9b77c24d , 0xa5afc6bf , 0xaf1e7e35 , 0x713330a0 , 0x0fa715cd , 0x0597ca91 ] )
+  , Mont::unsafe_make( [ 0x8226cbfa , 0x75ca2e2a , 0x93e546c6 , 0x135d87d6 , 0xb2955bca , 0x7527e9f0 , 0x7874eea6 , 0x01fdaf34 ] )
+  , Mont::unsafe_make( [ 0x2142f912 , 0x4eb495f3 , 0x77eb5b60 , 0xd1e2697e , 0x2573b82c , 0x9d726ed6 , 0x1c560222 , 0x264686e1 ] )
+  , Mont::unsafe_make( [ 0xf8a21d87 , 0x29293a4e , 0x73ab0385 , 0x5e46a112 , 0x95bb3be4 , 0xfbb66c11 , 0x078e1b68 , 0x0ba6a019 ] )
+  , Mont::unsafe_make( [ 0x9a498e54 , 0xbc7995ce , 0x5d274a6a , 0x611d984c , 0x8f931218 , 0xb3b79a39 , 0x80369f59 , 0x26272429 ] )
+  , Mont::unsafe_make( [ 0x9def7235 , 0x7cb8b641 , 0x15480a09 , 0x7d21cc8f , 0xb40be719 , 0x5538a245 , 0xede25340 , 0x20fc15a6 ] )
+  , Mont::unsafe_make( [ 0xc6eb6d0f , 0xe05ee138 , 0x6c7c1702 , 0x777012b5 , 0xd8a23469 , 0xf1863db9 , 0xcc6044c5 , 0x2392491a ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x2193731f , 0x8ed63ced , 0x5cfacedb , 0xb111bcfd , 0x86def464 , 0x2c0d7dac , 0xb7f4a7c4 , 0x0b580a15 ] )
+  , Mont::unsafe_make( [ 0x61c00798 , 0x0ffa52af , 0x440161d2 , 0x95d1e297 , 0x68aceced , 0xb4906ba6 , 0x2c59dfa3 , 0x2c121a6d ] )
+  , Mont::unsafe_make( [ 0xb884c719 , 0x5da4a963 , 0xa47c0d39 , 0x3024a617 , 0x80d7b1c1 , 0x586d8482 , 0x51494a69 , 0x0c9f3803 ] )
+  , Mont::unsafe_make( [ 0xbfc0cae4 , 0x1f9876e2 , 0xea6771c3 , 0x0bce3d08 , 0x7ea1ed84 , 0x83419677 , 0x27e17d04 , 0x1f6785d0 ] )
+  , Mont::unsafe_make( [ 0x1ce8c596 , 0x59bbca6b , 0x7434a312 , 0x9c851d23 , 0x975fce89 , 0x324a2e98 , 0x62bc4190 , 0x0e176f36 ] )
+  , Mont::unsafe_make( [ 0x2ccdb045 , 0x06961f1a , 0x8bb58f67 , 0xc9395b3e , 0x24752797 , 0x9cf83975 , 0x95f1f5ca , 0x2f4ff326 ] )
+  , Mont::unsafe_make( [ 0x56cb02f9 , 0xadabf6e9 , 0x0c88fe66 , 0xb1c359a7 , 0x41c3a084 , 0x77c7e2ac , 0xee8b8cac , 0x1b827694 ] )
+  , Mont::unsafe_make( [ 0x65d1df63 , 0x4e533edd , 0xbd210436 , 0xf5a7f156 , 0x7dce0ed6 , 0x59088304 , 0x0afadbc9 , 0x08d399d6 ] )
+  , Mont::unsafe_make( [ 0xc90e4624 , 0x21cb793a , 0x5662e739 , 0xa5866e6d , 0xf7dab928 , 0xb954668c , 0x7b2c7449 , 0x22868868 ] )
+  , Mont::unsafe_make( [ 0x1fa3a447 , 0x44b58399 , 0xbf23a9aa , 0x73648d61 , 0x32c66369 , 0x2881219e , 0xc72910db , 0x2777cf22 ] )
+  , Mont::unsafe_make( [ 0xb6b08c0c , 0x83d7fc99 , 0x120954d7 , 0x5cb67655 , 0x6a6ab36d , 0x9e0b68fb , 0xe852b639 , 0x2b6e106c ] )
+  , Mont::unsafe_make( [ 0x2a8f09c9 , 0xe51739cf , 0x42406e23 , 0x860fafdc , 0xb2ab13c5 , 0x6bf796e1 , 0xb169bfc7 , 0x0c87b78f ] )
+  , Mont::unsafe_make( [ 0x72f6d60a , 0x8284631a , 0xa8285ec0 , 0x529c8d18 , 0xec38119b , 0x34072ff3 , 0x4b05a776 , 0x1fb36661 ] )
+  , Mont::unsafe_make( [ 0x1b2233c3 , 0xff484934 , 0xe48ff779 , 0xbe72edfb , 0x5fa34201 , 0x7116dff5 , 0x54ad6871 , 0x182a4fc9 ] )
+  , Mont::unsafe_make( [ 0x12d7b06d , 0xbcf490e9 , 0x72c07e75 , 0xa31c8aba , 0x2e0f641e , 0x0bc30e2f , 0xfaffed8c , 0x20630535 ] )
+  , Mont::unsafe_make( [ 0xc0685231 , 0x703c4b70 , 0xc259c3b8 , 0xf5d6e5c3 , 0xc7924426 , 0x3ef59ace , 0x4963d6a0 , 0x248d7487 ] )
+  , Mont::unsafe_make( [ 0x231a5317 , 0x491ccf74 , 0xf02c9641 , 0xd7f8d3af , 0x967e191d , 0xa1a97367 , 0x7674b76a , 0x0b3c006d ] )
+  , Mont::unsafe_make( [ 0x09e65ab6 , 0x304b3d17 , 0xf0b7834e , 0xf5deafe4 , 0xc3e82e76 , 0xf848bcec , 0xc6acff4b , 0x0f11219a ] )
+  , Mont::unsafe_make( [ 0x98d84c0e , 0x1610a7f2 , 0x64a80d89 , 0xc71d1de7 , 0x6b3802db , 0xcb4e99f4 , 0x4b2c8161 , 0x27b2b343 ] )
+  , Mont::unsafe_make( [ 0xc914fa49 , 0x8e855b89 , 0xbff790d7 , 0xc9d3cad7 , 0x8ab562a5 , 0x75d70381 , 0x5ff9acd4 , 0x248811ca ] )
+  , Mont::unsafe_make( [ 0x3f4c36b7 , 0x503c4f3a , 0x4a8d3b80 , 0xd073c656 , 0x775c902a , 0xe75c46f5 , 0x63646038 , 0x2decd00e ] )
+  , Mont::unsafe_make( [ 0xcfeefd4a , 0x5a687b26 , 0xa65ef0d3 , 0x8417b3d8 , 0x2ac7ef6f , 0x5474d8d4 , 0xf6bad00a , 0x0b07ba15 ] )
+  , Mont::unsafe_make( [ 0x8cbdbca8 , 0xfbe03aa5 , 0x7c600688 , 0x16081d96 , 0x91d4ce4d , 0xc85fddef , 0x52bba680 , 0x1f7d1349 ] )
+  , Mont::unsafe_make( [ 0x4a294887 , 0x64aa59d2 , 0x29f58b40 , 0x93c3a93b , 0x593fce21 , 0x3c835d7f , 0xfea75b99 , 0x00fedecf ] )
+  , Mont::unsafe_make( [ 0xa41e3ffc , 0xdf775038 , 0xae1b32b3 , 0x4b76a019 , 0x68ee3758 , 0xcc10dfe3 , 0xabca8961 , 0x1eb1791c ] )
+  , Mont::unsafe_make( [ 0x4ece6770 , 0x94e2f2c1 , 0xe5ed0880 , 0xddaa5c75 , 0x6310dc1d , 0xbad23960 , 0xeada0f1c , 0x0a27eff7 ] )
+  , Mont::unsafe_make( [ 0x28f43874 , 0xeea7d869 , 0x54691feb , 0xfeed79ad , 0x7a6aced5 , 0x74d01b01 , 0xe5d61eb0 , 0x12c7da07 ] )
+  , Mont::unsafe_make( [ 0x8f007c1c , 0x37bc4f05 , 0x2b96f804 , 0x21308607 , 0xacc7c693 , 0xe8583c15 , 0x43c0d9c5 , 0x01122cb4 ] )
+  , Mont::unsafe_make( [ 0xfe8fa638 , 0x7a9b20d6 , 0xd8945dbc , 0x431a2785 , 0x214ed4b6 , 0x383cc989 , 0x19f8fb06 , 0x0a4342b5 ] )
+  , Mont::unsafe_make( [ 0x849b8eeb , 0x0b2f6f66 , 0xd5340875 , 0x4777ba62 , 0x32e3f551 , 0x7bdb641e , 0x5b791f5c , 0x1abf7c40 ] )
+  , Mont::unsafe_make( [ 0x5daf2990 , 0xc8404a01 , 0x88729e5d , 0x510b7512 , 0x418adc49 , 0x89d2c260 , 0x20b9d54a , 0x1b79e1f8 ] )
+  , Mont::unsafe_make( [ 0x706f6c3b , 0x7ddbd45e , 0xbc484115 , 0x07bf5076 , 0x5c81aebd , 0x4c7e8d36 , 0xb91a30eb , 0x238b4734 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x9eea05d3 , 0x9151b30a , 0x3736a797 , 0x26396f43 , 0xfdeb3e70 , 0xbb6ef978 , 0x05eae1d9 , 0x1596f6e5 ] )
+  , Mont::unsafe_make( [ 0xe1e6513f , 0xa8b7d938 , 0xf7cdbf27 , 0x10f78d3e , 0x98d6be64 , 0xbc9f6ec9 , 0x079a594c , 0x07bd95a3 ] )
+  , Mont::unsafe_make( [ 0x1a3d6cc4 , 0x8cbc3b3f , 0x247fb5ea , 0x0b9c368e , 0xb7a09aaf , 0x41e462d0 , 0xfe6cefe8 , 0x198b1323 ] )
+  , Mont::unsafe_make( [ 0xa53b7277 , 0x07c1dcbe , 0xab9b19d3 , 0x1e5be6a3 , 0xac063a2b , 0xaa791da5 , 0x1d074fab , 0x0d42cc87 ] )
+  , Mont::unsafe_make( [ 0xc2ea5b23 , 0xb23f8bef , 0x1fb0e648 , 0xbeb65d2c , 0x70c90ca9 , 0x74701680 , 0xd5ad0ce7 , 0x0c4141d4 ] )
+  , Mont::unsafe_make( [ 0x8eab90e8 , 0xcd054c2a , 0x4b08ea71 , 0x33c9cdfa , 0x6cdae2cf , 0x735df6b1 , 0xb4bdf273 , 0x1ce18e74 ] )
+  , Mont::unsafe_make( [ 0x18c800f5 , 0x9a1f272c , 0x091ca3d1 , 0x5c1bc949 , 0x52452b42 , 0xe731cd8d , 0xf840e116 , 0x0a8a08de ] )
+  , Mont::unsafe_make( [ 0x629711d3 , 0xdbbaf8e8 , 0x0f41f1c4 , 0x2b4fed4b , 0x40f85e6d , 0x4fe78663 , 0xb5e9c0ba , 0x139883c0 ] )
+  , Mont::unsafe_make( [ 0x5bcb6a4b , 0xbadc9479 , 0x862f51c1 , 0x26c8bd9f , 0xbd69586c , 0xc574fb75 , 0xc5804857 , 0x2714816c ] )
+  , Mont::unsafe_make( [ 0x7421f7f7 , 0xd589c54c , 0x123f4e95 , 0x9f788761 , 0x8f3617ff , 0x3a21b605 , 0x4d8b36ec , 0x27438503 ] )
+  , Mont::unsafe_make( [ 0x49bd68c5 , 0x0661a4ef , 0x5ce17fe3 , 0xfc015854 , 0x57da3630 , 0x7b909bb6 , 0x7fc4296a , 0x062fcc2a ] )
+  , Mont::unsafe_make( [ 0xab981348 , 0xfdda2425 , 0x42216a83 , 0xeb51a66c , 0x30e472bb , 0x8eaf3e10 , 0xdd9e9dbb , 0x1517eaea ] )
+  , Mont::unsafe_make( [ 0x599bf569 , 0x89a24303 , 0xba21cf2d , 0x110e3783 , 0x5d43eeb7 , 0xc7c9af3c , 0xb4fa9652 , 0x26b85923 ] )
+  , Mont::unsafe_make( [ 0x6c38b911 , 0xe9a24f9d , 0x5c6364e7 , 0xe810700b , 0x83fa32dc , 0xffcd3bc4 , 0xdab9fd84 , 0x1737a2f6 ] )
+  , Mont::unsafe_make( [ 0x571fa0b2 , 0x1781c87f , 0xd54906a4 , 0x52d3f27f , 0xf8594d10 , 0x838e1c4b , 0x19a6f56a , 0x297b9190 ] )
+  , Mont::unsafe_make( [ 0xa7d3ee37 , 0x6de771fe , 0x198253e4 , 0xd76f6935 , 0x76c5006b , 0x8c6cba46 , 0xd1b63a13 , 0x13040b08 ] )
+  , Mont::unsafe_make( [ 0xcb4fa7fb , 0x93149009 , 0x83dc3644 , 0xf80d0357 , 0x7cce6422 , 0x29238a0a , 0x1818b0a1 , 0x1f94de44 ] )
+  , Mont::unsafe_make( [ 0x3d954cf7 , 0x506e0d5f , 0x8b950eb3 , 0x1b11f025 , 0x1cd181fe , 0x98cf7e17 , 0x01a36e41 , 0x1b4580b0 ] )
+  , Mont::unsafe_make( [ 0x31dbf68d , 0xb8bb40e3 , 0x300ed2d2 , 0xd5c4a848 , 0xca1a3c3e , 0x652598b6 , 0x97a1a0c3 , 0x0b620b97 ] )
+  , Mont::unsafe_make( [ 0x5d22b46f , 0xa5b29f29 , 0x8840497c , 0x376e8e28 , 0x91d777ba , 0x60ce68a5 , 0xa087b210 , 0x16a96ffa ] )
+  , Mont::unsafe_make( [ 0x832440c5 , 0x9e9713ed , 0x4cbe62cf , 0x5231aa22 , 0x4f3d7373 , 0x21c052cf , 0xb24e6cc4 , 0x032082a0 ] )
+  , Mont::unsafe_make( [ 0x1b4717ab , 0x5e59ff63 , 0x18ae2538 , 0xd3c9a4e1 , 0x293c50f4 , 0x8b11e962 , 0x24e1b627 , 0x18862537 ] )
+  , Mont::unsafe_make( [ 0x28f3c58f , 0x2cc5eece , 0x533831c4 , 0x3a3903de , 0x7ebd117a , 0x0c62d26b , 0xb14b6dbe , 0x16db76b4 ] )
+  , Mont::unsafe_make( [ 0xf4701e2d , 0xf5720e25 , 0x757eb229 , 0x756e18b7 , 0xbe1257ab , 0xac41ba4a , 0xe209cbb5 , 0x02fb10b0 ] )
+  , Mont::unsafe_make( [ 0x977fde96 , 0xd8d1b5dc , 0x2c1aa067 , 0x82807b18 , 0x7f50ca67 , 0x9234ba44 , 0x7daf4399 , 0x2b040ea2 ] )
+  , Mont::unsafe_make( [ 0x872ceb99 , 0x72950c1f , 0x4512bcce , 0x88942694 , 0x849e6908 , 0xf6852d3a , 0x24e11e37 , 0x1e1bbb88 ] )
+  , Mont::unsafe_make( [ 0xc19d2865 , 0x4f715216 , 0x187703b3 , 0x33b493ee , 0x85deab47 , 0xd5055023 , 0x7e2b5e9a , 0x1e9fa22b ] )
+  , Mont::unsafe_make( [ 0xa10a6a65 , 0x38e0628a , 0x0baf6876 , 0xec0eb223 , 0xc0c6bb90 , 0x31979c07 , 0x35524251 , 0x18b5c6bc ] )
+  , Mont::unsafe_make( [ 0x3764c4b9 , 0x07315b88 , 0x69045a0a , 0x1e8976b7 , 0x41b6213d , 0x13112377 , 0xe7b7e213 , 0x1a27a44d ] )
+  , Mont::unsafe_make( [ 0xaf0f60bd , 0x79bdbc1c , 0x16eabebb , 0x788ba178 , 0xab551e56 , 0xb3662fe1 , 0xff28221f , 0x1484be70 ] )
+  , Mont::unsafe_make( [ 0xe9e6548f , 0xb0b34372 , 0xa4a03c6f , 0x8f27e027 , 0x5913d8b5 , 0x06a1cbe3 , 0xa55fd721 , 0x10b226ce ] )
+  , Mont::unsafe_make( [ 0xd8d291b9 , 0xd1446765 , 0xd3f8985c , 0xff3e5e25 , 0x9bfd521a , 0x0099120f , 0x250786ad , 0x2b2516dc ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x7a0b3b01 , 0x0b5a4d1c , 0x0c06cf96 , 0x08e25fcc , 0xbb3fff40 , 0x44b31553 , 0x215f12ca , 0x2f28db13 ] )
+  , Mont::unsafe_make( [ 0xa0d92d34 , 0x5708a60c , 0xdfe47d57 , 0x3e098e17 , 0xe2bf01ac , 0xedd7d348 , 0x4e35600c , 0x2ae0452d ] )
+  , Mont::unsafe_make( [ 0xf17d3d9d , 0x9e2792c5 , 0xa5957d11 , 0x7a3aed28 , 0x4772306b , 0xcea477a1 , 0xd7d46bf3 , 0x2854b97b ] )
+  , Mont::unsafe_make( [ 0xc72db29d , 0x852f73fc , 0x859571d7 , 0xc8fc09b9 , 0xec9085ac , 0x37bd06a7 , 0x694d614f , 0x046aa87c ] )
+  , Mont::unsafe_make( [ 0xb216339b , 0x83aa00fd , 0x87371d31 , 0xfd5b7149 , 0xfe989b81 , 0xc4cbea55 , 0x8a6e5925 , 0x0d1f090f ] )
+  , Mont::unsafe_make( [ 0x9d04ac5b , 0xa3a4a6a4 , 0xe3b68fff , 0x8192ac79 , 0x1d4ab292 , 0x100da8a4 , 0x0827957e , 0x1772d039 ] )
+  , Mont::unsafe_make( [ 0x8a7ca8ca , 0xbdb70023 , 0x088ae83a , 0x484977ee , 0x1ee69797 , 0x4655fab9 , 0x61ae187e , 0x2483852f ] )
+  , Mont::unsafe_make( [ 0xcd75cc13 , 0x8397ad77 , 0xa77ca2c1 , 0xa9132148 , 0x0317fd8e , 0xb10f7e4f , 0x7b7ee469 , 0x06b6b31d ] )
+  , Mont::unsafe_make( [ 0x73a394ad , 0x463ee503 , 0x41def653 , 0x1f663017 , 0x96f3d918 , 0xa537248f , 0x37f250c8 , 0x10974916 ] )
+  , Mont::unsafe_make( [ 0x4ce32a81 , 0xe132a6bf , 0x016721f7 , 0x612f2a97 , 0xb43acda3 , 0xc1fb0b85 , 0xb651b890 , 0x2098c6c6 ] )
+  , Mont::unsafe_make( [ 0x3e57bfa3 , 0xc08892ac , 0x49b204b8 , 0x72ae6708 , 0xcbdd9384 , 0xbc9dddc9 , 0xdfb9bf8f , 0x2a16fc1f ] )
+  , Mont::unsafe_make( [ 0x372c8211 , 0x4f141bc8 , 0xf4d9aea2 , 0xbead6bbc , 0x595e5aa9 , 0x16d0c32d , 0xb7cb3e92 , 0x0a430cae ] )
+  , Mont::unsafe_make( [ 0x56c323d6 , 0xbf249d7f , 0x3cba3557 , 0x57c2c4a6 , 0x3514337f , 0x0cefbc2a , 0x1a4158e5 , 0x00f1df6c ] )
+  , Mont::unsafe_make( [ 0x2669cebc , 0x53a6cbc5 , 0xef5f94a0 , 0x0b47c0c5 , 0x376ec1ef , 0x93cb2374 , 0xd6fce61a , 0x1320296e ] )
+  , Mont::unsafe_make( [ 0x074038a1 , 0xe989abf1 , 0x1fd6d090 , 0xa8eb76b9 , 0xe24be002 , 0x1085e661 , 0x20e8f4c1 , 0x0854d9f5 ] )
+  , Mont::unsafe_make( [ 0x07ed2186 , 0xa92a7d98 , 0x10b6b37d , 0xfb797340 , 0xf79a0776 , 0x3c90cb3f , 0xa7977e8d , 0x199edbb7 ] )
+  , Mont::unsafe_make( [ 0xbdf9adcf , 0x27f79904 , 0xac31d24f , 0xcaf65374 , 0xfc756845 , 0xbd5f9909 , 0xfd8c55ba , 0x15af04fb ] )
+  , Mont::unsafe_make( [ 0xa123067d , 0x987e79b2 , 0x0993f091 , 0x2cd1a6e0 , 0x310f1bf5 , 0x3897d544 , 0x25cc47f9 , 0x142e5dec ] )
+  , Mont::unsafe_make( [ 0xa38a2c67 , 0x860cde05 , 0x97bb243f , 0x5dbb2284 , 0x7d496f97 , 0x4adb4394 , 0xd806ea34 , 0x2438c6c1 ] )
+  , Mont::unsafe_make( [ 0x8d4061b7 , 0xecaad5f6 , 0x69a2a341 , 0x4e8ab33c , 0x9d26babe , 0x73cb7119 , 0x26be2754 , 0x1d41924d ] )
+  , Mont::unsafe_make( [ 0x110aa7d4 , 0xa950a135 , 0xe357783f , 0x48f5c6cd , 0x882b546d , 0x2d2388ef , 0xe2cd626a , 0x2abad145 ] )
+  , Mont::unsafe_make( [ 0xbcd9ec96 , 0xde1fa42e , 0xa6629125 , 0xfb671dc9 , 0x92adaf68 , 0x6fc243ce , 0x0596d1bb , 0x127419fb ] )
+  , Mont::unsafe_make( [ 0xe7c3808a , 0x04e0c748 , 0xee35a333 , 0x396244ed , 0xb7046d0c , 0xfd10f3dd , 0x6f690daf , 0x0c70941f ] )
+  , Mont::unsafe_make( [ 0x354d9c34 , 0xaab59be9 , 0x4384f3d3 , 0x0cc63070 , 0x5a5f4e37 , 0xec252e6a , 0x1b179f57 , 0x2685ef33 ] )
+  , Mont::unsafe_make( [ 0x06267c18 , 0xa0cdea0c , 0x898736b5 , 0x65a0c70c , 0xaf7657d0 , 0x2aaf68b2 , 0x981f64cd , 0x05c5e077 ] )
+  , Mont::unsafe_make( [ 0xadf36708 , 0x259d2dfb , 0xdf90fb0c , 0x16d7a53f , 0xb8892626 , 0x2d71dc58 , 0xa44d8f95 , 0x24b6e002 ] )
+  , Mont::unsafe_make( [ 0x108b3872 , 0xc3011624 , 0xce5762cd , 0x7c3f617c , 0xeeba811e , 0xb1dc14c1 , 0xa46a97bb , 0x01fc29e8 ] )
+  , Mont::unsafe_make( [ 0x6b006972 , 0x3a3ba942 , 0x2649a64d , 0x3eb26bd7 , 0x4cfe42ca , 0xd781d73f , 0xf9b88ecc , 0x063ac067 ] )
+  , Mont::unsafe_make( [ 0x97855913 , 0x38a50f11 , 0xe54891c3 , 0x061683c5 , 0xa9a34c0d , 0x0a46cfd7 , 0x72776bdf , 0x21102d5b ] )
+  , Mont::unsafe_make( [ 0xbfe5f354 , 0x00ad47e2 , 0x1effd958 , 0x2d5bf458 , 0x89284ac7 , 0xf777f84c , 0x7c0cd6fc , 0x2e5c249d ] )
+  , Mont::unsafe_make( [ 0xffe42c9e , 0x268cf4dd , 0x9097d261 , 0x987c9e3c , 0xbc7ce85a , 0xb5e62034 , 0x54a5f32b , 0x1b5c79ec ] )
+  , Mont::unsafe_make( [ 0x739fdbec , 0x282bd603 , 0x6b53e942 , 0xf1e11f3f , 0x7a3dc8df , 0x37892dbe , 0x5856c2dd , 0x0a75c599 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x68627135 , 0xc170c5f3 , 0xa579ba83 , 0x898b7958 , 0x73f0ab97 , 0xb4f0f74c , 0x8079916f , 0x25aa377b ] )
+  , Mont::unsafe_make( [ 0xe248c008 , 0x3c657557 , 0xfa160005 , 0xd2a4cf0a , 0x0f2a3fd5 , 0xd35977ba , 0xd3d225f8 , 0x065ed362 ] )
+  , Mont::unsafe_make( [ 0xa1a2b50c , 0x9715e91f , 0x6a6ad145 , 0x33766c2e , 0x4e1915e1 , 0xa580797a , 0x1d4ad8c4 , 0x0fb60538 ] )
+  , Mont::unsafe_make( [ 0xfa32b8a1 , 0x3be303f8 , 0xb3c6bab8 , 0xdd241d09 , 0xcc2c397d , 0x4d4ac2e4 , 0x711f7bda , 0x2e271654 ] )
+  , Mont::unsafe_make( [ 0xf6dd0e04 , 0xb127d442 , 0xb8d1fa66 , 0x5e6aa983 , 0xadc6b2ce , 0x8e06aaee , 0x11a0b83b , 0x294666e0 ] )
+  , Mont::unsafe_make( [ 0x3f0090e3 , 0xf57ec5a4 , 0xa5d89b35 , 0x1d0e99cd , 0x271e4e73 , 0xdca11d68 , 0xbef3bc8b , 0x0c20e81d ] )
+  , Mont::unsafe_make( [ 0x49a47ef7 , 0x987b06c5 , 0xfbac4f3b , 0xed61b1ad , 0x838d55f1 , 0x97ca3f5e , 0xd7b3aab5 , 0x11fae4a1 ] )
+  , Mont::unsafe_make( [ 0xcb82eca6 , 0x3c4a881e , 0x59b52ab4 , 0x7cf98026 , 0x218efb6d , 0x3ea22ddd , 0x12b2362f , 0x05b7d19b ] )
+  , Mont::unsafe_make( [ 0x1cb7839b , 0x4d1b092f , 0x36f62b95 , 0xfe5263e9 , 0x0c33afcc , 0xf530cea3 , 0xdf841935 , 0x098729d6 ] )
+  , Mont::unsafe_make( [ 0x0bfa23aa , 0x501065d6 , 0x3845ae90 , 0xa20564e8 , 0xb722c494 , 0x0685ea2e , 0x0131d1cc , 0x0161a579 ] )
+  , Mont::unsafe_make( [ 0x7e7949b9 , 0x1d2c155f , 0x4f1576f8 , 0x9b19e8f4 , 0x7c6d66c3 , 0xe6313871 , 0x1f573553 , 0x2bd08b18 ] )
+  , Mont::unsafe_make( [ 0x5b9b2b7e , 0x68edafd7 , 0x2e1eb7a4 , 0x5ff83103 , 0xb9204948 , 0x41e14cfb , 0x6bae0ace , 0x23de43f1 ] )
+  , Mont::unsafe_make( [ 0x69e793b3 , 0xc9315ae7 , 0x8a4caa26 , 0x20bfd5e3 , 0x6c8c6d83 , 0xec8c1e28 , 0x30ea814c , 0x08f09754 ] )
+  , Mont::unsafe_make( [ 0xffe88486 , 0x464e2743 , 0xae52bbbc , 0x20754548 , 0x8efd264e , 0xe37efbbb , 0x702c0876 , 0x04d3af96 ] )
+  , Mont::unsafe_make( [ 0x9e24184a , 0x42fb82a8 , 0xdf8b0a98 , 0x6a6f2b20 , 0x224efc7d , 0x0e1285ab , 0xddc6fba5 , 0x19f7b1d0 ] )
+  , Mont::unsafe_make( [ 0xf96dc624 , 0x8da2a0f3 , 0x4c058025 , 0x8682865d , 0xee2e2705 , 0x3320a23e , 0x0bde8e20 , 0x26143b34 ] )
+  , Mont::unsafe_make( [ 0x447f2bc6 , 0xd5ba4e5f , 0x049fc0b9 , 0xc459aa37 , 0x01bbaf9e , 0xb00af6a2 , 0x414d7a47 , 0x1db11aa9 ] )
+  , Mont::unsafe_make( [ 0x08ee6124 , 0xe5758bb0 , 0x6621c03c , 0xd781a8e5 , 0x30e85470 , 0x30112a78 , 0x3c6d7985 , 0x14cb9959 ] )
+  , Mont::unsafe_make( [ 0xfb20ed3c , 0xbd672f4f , 0x66774e1d , 0x9d427307 , 0x48f33c51 , 0x2966e5c0 , 0xf7475593 , 0x2b20a8e4 ] )
+  , Mont::unsafe_make( [ 0x3c12dba3 , 0x67aa6223 , 0xc3db8e9d , 0xf0660c5c , 0xd463a2ef , 0xcf7d8acf , 0xcdac3aaa , 0x15ef118a ] )
+  , Mont::unsafe_make( [ 0x723eef93 , 0xe2b1f7e4 , 0xbac4c8f5 , 0xa0406f4e , 0x56558e62 , 0xf2883895 , 0xb6b06122 , 0x11e74ae8 ] )
+  , Mont::unsafe_make( [ 0xb24a529d , 0xcb4e3e8f , 0xec8d4b6d , 0x11297f62 , 0x79cee903 , 0x1d63348f , 0x9f5132dc , 0x06e1bc0f ] )
+  , Mont::unsafe_make( [ 0x7488c3cc , 0xc4eac8ec , 0xa34639bd , 0xa4397a09 , 0x4eea7139 , 0x198bf265 , 0xdb484d76 , 0x108337f3 ] )
+  , Mont::unsafe_make( [ 0xa1ba0aa7 , 0x4c15980a , 0x92aef6d3 , 0x42258abc , 0x73d77c5c , 0x15ec7e5c , 0x7268a5c0 , 0x073f8a6e ] )
+  , Mont::unsafe_make( [ 0x252105f5 , 0xe908203d , 0x7d0dea5f , 0x1bc0d246 , 0x32203088 , 0x75bad558 , 0xb927208f , 0x1517d0c9 ] )
+  , Mont::unsafe_make( [ 0x081282e2 , 0x463a3e4b , 0x339de8eb , 0x2fcb420c , 0x121dd212 , 0x626dc62f , 0x4f262519 , 0x11fda314 ] )
+  , Mont::unsafe_make( [ 0x3b25efad , 0xfe0ab5b8 , 0x647bef6c , 0xdbf0ebdf , 0x22acf525 , 0x8b6aee3d , 0x8f0b771f , 0x1443b8aa ] )
+  , Mont::unsafe_make( [ 0x1db64bac , 0xe1b17bb3 , 0x8af71bdd , 0x3e152933 , 0x569cdf0b , 0x614ce5cc , 0x7a3d9b03 , 0x107579f3 ] )
+  , Mont::unsafe_make( [ 0xad623d88 , 0x1f7b472f , 0x53840f50 , 0x5346488d , 0xbb12a53f , 0x6929ad5b , 0xc79c073e , 0x15bdd9ac ] )
+  , Mont::unsafe_make( [ 0xe4fca015 , 0xdbbe3e52 , 0x9cb946cd , 0xec2cff24 , 0xcdf1bd13 , 0x33d06bd7 , 0x2b6470b9 , 0x218898ce ] )
+  , Mont::unsafe_make( [ 0xd6c3adb5 , 0x6c09bcf2 , 0x445674af , 0x3cccb1b9 , 0x8753996c , 0x98dea8e0 , 0x27da8c0a , 0x09b20599 ] )
+  , Mont::unsafe_make( [ 0x7283debc , 0x0088de92 , 0x0fc1d70b , 0x993c900e , 0x4984455a , 0x63239f14 , 0xc4b59c2b , 0x075e471e ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x7baca9bc , 0x5693fca9 , 0x16111076 , 0xa1553fec , 0xb9663eed , 0x502621d5 , 0x111d47b9 , 0x151665c7 ] )
+  , Mont::unsafe_make( [ 0x0c3cc786 , 0xb9e4d203 , 0xed427d26 , 0xd0d78b45 , 0x162658f6 , 0xfd61d2f3 , 0xc7c5cdf2 , 0x0d455033 ] )
+  , Mont::unsafe_make( [ 0x2445d931 , 0xdfd1b5db , 0x488a529d , 0x5c26f909 , 0xe71a8461 , 0xac9ec5cb , 0x009663b4 , 0x18527b99 ] )
+  , Mont::unsafe_make( [ 0x774147be , 0x73ac8a0d , 0x4b7d8e70 , 0x5205ff34 , 0x356bb666 , 0x134379ff , 0x32ada3c1 , 0x1e4b2e3f ] )
+  , Mont::unsafe_make( [ 0x30440b53 , 0x8198a8f8 , 0x008209e7 , 0x1ea5cfc9 , 0xab425d6f , 0x4d9a45c6 , 0x8d017cb5 , 0x0e32a6fc ] )
+  , Mont::unsafe_make( [ 0xa6cc9bc1 , 0x0071618e , 0xf048b2c8 , 0x65eea056 , 0x4e65c7ae , 0x62fe6771 , 0x0fd3a5c6 , 0x10082655 ] )
+  , Mont::unsafe_make( [ 0x39f1cee5 , 0x6d514dc4 , 0x156dd568 , 0x46cdea92 , 0xce2537a3 , 0xde4d6eaa , 0x04575b70 , 0x22aedac4 ] )
+  , Mont::unsafe_make( [ 0xf6474abc , 0x2c1443fa , 0x80e75806 , 0x93286bc3 , 0x81751943 , 0x5aeed5e0 , 0xe9837533 , 0x229c0e14 ] )
+  , Mont::unsafe_make( [ 0x06c20fd8 , 0x13d3ca4c , 0xdfcef154 , 0x8f16836f , 0xab4223c7 , 0xceac2975 , 0x1a878531 , 0x2383ca25 ] )
+  , Mont::unsafe_make( [ 0x7c8d2387 , 0x8f68aeca , 0x4f1e751f , 0x362414cf , 0x27abd304 , 0x92f2a5dc , 0x6a36116d , 0x2ee061f9 ] )
+  , Mont::unsafe_make( [ 0xda59475b , 0x80231c0b , 0x7eb2ef9f , 0x6bf20969 , 0xdbef0e66 , 0xf5f2354d , 0x9046e699 , 0x13c834a9 ] )
+  , Mont::unsafe_make( [ 0xa352b9a2 , 0xc6bd90bc , 0x6183f5bd , 0xe7f0bbc6 , 0x64751057 , 0x5f142b73 , 0x5a89945f , 0x119c37b7 ] )
+  , Mont::unsafe_make( [ 0xbea98d75 , 0xe2854ef1 , 0x8c2a4ea1 , 0xfaeac013 , 0xec002a24 , 0xef361740 , 0xa03852d5 , 0x130eaa9c ] )
+  , Mont::unsafe_make( [ 0xf12b2766 , 0xf6b9b326 , 0xaa392735 , 0x4d5f1db3 , 0xcefdae9a , 0xd2a2e2de , 0xe46f3bed , 0x0dc88023 ] )
+  , Mont::unsafe_make( [ 0xa653e206 , 0x90fcf64a , 0x788c683b , 0x19a1231e , 0x7009cdce , 0x94ac882e , 0x581af859 , 0x1b9dfce6 ] )
+  , Mont::unsafe_make( [ 0x52d898ed , 0xec2c7e74 , 0xc98a63b5 , 0x85f2665a , 0xeb4207da , 0x663fed45 , 0x75e4e751 , 0x0484ffdc ] )
+  , Mont::unsafe_make( [ 0xa245ad2e , 0x27304f45 , 0xd69bbf33 , 0x88a1e28d , 0xa1a6e475 , 0xc5a06697 , 0x5f1d42b1 , 0x0593a236 ] )
+  , Mont::unsafe_make( [ 0x5bf2e465 , 0xf489ddf4 , 0xc6f3499c , 0x84b6bae7 , 0x0b1e1b51 , 0xbc0e8ef3 , 0x76463f61 , 0x15506a2a ] )
+  , Mont::unsafe_make( [ 0x5f3e4320 , 0x4eea4dee , 0xdbb33ee9 , 0xdbcdc79f , 0x29ed23c7 , 0xcc5b0ee8 , 0x201bfe2b , 0x225f39d2 ] )
+  , Mont::unsafe_make( [ 0xd55aeee5 , 0x7bac9330 , 0x8a089de2 , 0x983aa905 , 0xc817e291 , 0xf8f9510f , 0x30a2a485 , 0x022ed8e8 ] )
+  , Mont::unsafe_make( [ 0x0d98501d , 0x485655e6 , 0xd457a9a8 , 0x15a284f1 , 0x73c156f5 , 0x6fcdd463 , 0x71a669b4 , 0x19620bb8 ] )
+  , Mont::unsafe_make( [ 0xb2c45661 , 0xf872f778 , 0xa533e109 , 0x3f9c5b69 , 0x4b9cef29 , 0xd3c1502e , 0xfe32c097 , 0x2fa481b5 ] )
+  , Mont::unsafe_make( [ 0xf6de5455 , 0x1ff9a46b , 0xc0a72550 , 0xcf6826ff , 0x8f63a528 , 0x461e80b9 , 0x00b7adce , 0x2b3218aa ] )
+  , Mont::unsafe_make( [ 0x3227dc59 , 0x8d1e99eb , 0xf79f7744 , 0xa1c99e39 , 0x370e8125 , 0xc6215fa7 , 0x3b001ab0 , 0x1ff6574d ] )
+  , Mont::unsafe_make( [ 0xb64d322a , 0x51d6e720 , 0xd7171c09 , 0x954242aa , 0x01a6685f , 0xbd77dfb3 , 0x8eae33d3 , 0x08e8a10d ] )
+  , Mont::unsafe_make( [ 0x899ed3b1 , 0xf76b5466 , 0xc60f9394 , 0x58d65deb , 0x9ab8ffe7 , 0x22bf762b , 0x749a6fc5 , 0x1a23a669 ] )
+  , Mont::unsafe_make( [ 0x8d904119 , 0xa038e20f , 0xbab44cca , 0x58759fe0 , 0x5ec831e0 , 0x1242e604 , 0x74fb6071 , 0x1cae65c4 ] )
+  , Mont::unsafe_make( [ 0x37f90c6f , 0x16c52f86 , 0x5f9a70eb , 0xb15f70d5 , 0x4f306c4c , 0x04cada14 , 0xf3a8ed21 , 0x1d968ba5 ] )
+  , Mont::unsafe_make( [ 0x40763ba6 , 0x11548762 , 0x87c5bae6 , 0x8aa86fa2 , 0xc4828f40 , 0x7e303bbe , 0xb51aba86 , 0x2ca32e57 ] )
+  , Mont::unsafe_make( [ 0x3eb84a81 , 0xe4c37e8c , 0xd368ed83 , 0x6f1ef867 , 0x04e359a5 , 0x50f2f85a , 0x349df154 , 0x27265e1d ] )
+  , Mont::unsafe_make( [ 0xbd298993 , 0x4f482690 , 0x9107085e , 0x6e78de86 , 0xca1483b8 , 0x8a4fdf26 , 0xe01d43f5 , 0x0fd32353 ] )
+  , Mont::unsafe_make( [ 0x8abf426a , 0x1c0fdf3e , 0xe36820d2 , 0xfeb1b69a , 0x24ec0de2 , 0xe3a079a7 , 0x02f72b9a , 0x2bc0691a ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xa862de79 , 0xa1139fcd , 0x73c6d1ed , 0xd77cbdb5 , 0x538e9059 , 0xfb2036b0 , 0x2be8c163 , 0x115f5c44 ] )
+  , Mont::unsafe_make( [ 0x4ba5cf18 , 0x93329b12 , 0x3d49475d , 0x23b3b33c , 0x2b753382 , 0xfcb3d6e1 , 0x84539c08 , 0x26b6acfb ] )
+  , Mont::unsafe_make( [ 0x382974c8 , 0xb9037a93 , 0x3faefaef , 0xea4458fe , 0xe171b74b , 0x7957cb9d , 0x9a263797 , 0x004ba86e ] )
+  , Mont::unsafe_make( [ 0xff53d453 , 0xb098ec26 , 0x8ca2734a , 0x31016ac3 , 0x2bd1cda1 , 0x247f7d36 , 0x7196da1b , 0x16d67f2f ] )
+  , Mont::unsafe_make( [ 0x3166567b , 0xf23be70f , 0x84ed6adb , 0x5b12ada8 , 0x228a4fa9 , 0x913b4de3 , 0x97c2ad9c , 0x0ef416ab ] )
+  , Mont::unsafe_make( [ 0x77509649 , 0xb19beb5b , 0x9682203f , 0x13e89196 , 0x5bd50f1d , 0xe7bf866c , 0xea1be584 , 0x14a7440d ] )
+  , Mont::unsafe_make( [ 0xa099a449 , 0xedb1f72d , 0xaceadb71 , 0x16371ca6 , 0x0f6055cd , 0x7fbf785b , 0x9353d283 , 0x070c1798 ] )
+  , Mont::unsafe_make( [ 0x95b3c5a8 , 0xde6318c9 , 0xfaf454bf , 0xbf9a5ba3 , 0xde79a3bc , 0x73854774 , 0xca75c46c , 0x2eb3dc09 ] )
+  , Mont::unsafe_make( [ 0xd0e8953a , 0x8bf2f2c5 , 0x636fbed8 , 0xa31f0074 , 0x011120c2 , 0x07020bee , 0x8c1fadee , 0x038013ce ] )
+  , Mont::unsafe_make( [ 0xdd8b7a18 , 0x0863d2e2 , 0x86ae6fc9 , 0x5fae9b8b , 0xfdc75d3b , 0x4387968a , 0x6f96142c , 0x301801ae ] )
+  , Mont::unsafe_make( [ 0x82b87861 , 0xcdeef201 , 0xfc2514a5 , 0x65cc37d9 , 0xbeb762ac , 0x4978b0e3 , 0x8ba4cb0b , 0x2d59d401 ] )
+  , Mont::unsafe_make( [ 0x57c9b4a8 , 0x0b7bd706 , 0x51406541 , 0xdb4d337c , 0xd1ffd4d7 , 0x41c9362c , 0x559d4535 , 0x09f0a127 ] )
+  , Mont::unsafe_make( [ 0x20215424 , 0xccc08f42 , 0xdf75e3b9 , 0xb270e631 , 0x7d60a6dd , 0x83e01d1c , 0x892b178d , 0x1948b78e ] )
+  , Mont::unsafe_make( [ 0xd69ac10c , 0xd84c3700 , 0xcd8beb3f , 0x7a99d732 , 0xcf350610 , 0x39a80891 , 0x505c8dcb , 0x1bf19623 ] )
+  , Mont::unsafe_make( [ 0x901a17fc , 0x15eb7995 , 0x15c5837d , 0x9e3ce450 , 0x755f83dc , 0x7527ece8 , 0x5d8e7bbf , 0x132ff5ea ] )
+  , Mont::unsafe_make( [ 0xbcc14806 , 0xf65573e9 , 0x6086929f , 0xaaeb8c54 , 0xc752d35a , 0xdbb08ab4 , 0x6158952e , 0x04e06f62 ] )
+  , Mont::unsafe_make( [ 0xf62900da , 0xc7af257d , 0xbfcbb605 , 0x8ac1b938 , 0x3041d582 , 0x582a90b5 , 0x9cd1cbe8 , 0x08d67605 ] )
+  , Mont::unsafe_make( [ 0xcb033448 , 0xcfa9e315 , 0x500693bb , 0x24a640f0 , 0x02a39180 , 0x1bd8981a , 0x4374c581 , 0x03137153 ] )
+  , Mont::unsafe_make( [ 0x39ef41e0 , 0xf05b4f3b , 0x076a4ba3 , 0x111f1cf6 , 0xbdeb74b0 , 0xf2257f2f , 0xe1b03864 , 0x1bf76f85 ] )
+  , Mont::unsafe_make( [ 0xb758bda7 , 0xc653f1d8 , 0x02fbb0b6 , 0x3cee72ea , 0xb9fb4640 , 0x48d6f53f , 0x9f4fb78f , 0x1b8e7884 ] )
+  , Mont::unsafe_make( [ 0x659db65c , 0xa5b4e803 , 0x1a401b56 , 0xfbf6ce74 , 0xe16de4e1 , 0xafbdaa21 , 0xbeea5683 , 0x2cfd9196 ] )
+  , Mont::unsafe_make( [ 0x7d35829e , 0x5779ab2e , 0x763dee4a , 0x2f0df035 , 0xc840987f , 0xab590fb1 , 0xd340010f , 0x16e35280 ] )
+  , Mont::unsafe_make( [ 0x2feec680 , 0x63c06d6c , 0xec8ce063 , 0x0e1fe2c2 , 0x51a6687f , 0x161c4788 , 0xb19f9a7a , 0x27938ba7 ] )
+  , Mont::unsafe_make( [ 0x062cae2e , 0x3262b47a , 0x66244fd9 , 0x8c8fb276 , 0xbab9facb , 0x6a4ae7bf , 0x49368c86 , 0x2fdb1c77 ] )
+  , Mont::unsafe_make( [ 0x82e26b2c , 0x5a0fe9ba , 0x82677105 , 0xb86b9318 , 0xe840c86d , 0x6847c012 , 0xf4af9fb8 , 0x2f04526a ] )
+  , Mont::unsafe_make( [ 0x7eeb567a , 0xa89d89c7 , 0x7326530e , 0x0d54f587 , 0x2be3765c , 0xc876d3f3 , 0xf71ee1f5 , 0x253475e3 ] )
+  , Mont::unsafe_make( [ 0x42bbf2cb , 0xf3db5bbb , 0x0d57f57c , 0x3dc23af5 , 0x2725aaef , 0x6084dc0a , 0x6eda6572 , 0x1b0c99e4 ] )
+  , Mont::unsafe_make( [ 0x430186ed , 0x1af387d1 , 0x062d6955 , 0x5113ecba , 0xb817ee04 , 0x6752fa17 , 0x145c01fe , 0x28e96e27 ] )
+  , Mont::unsafe_make( [ 0x099fe16f , 0x60453418 , 0xfddc7c87 , 0xfb03a542 , 0x24851233 , 0x068cf2f1 , 0xae2417ef , 0x1e854a6a ] )
+  , Mont::unsafe_make( [ 0xa66d8cba , 0xf117e613 , 0x2b630cf8 , 0x6cc8d8d0 , 0x0dad5fac , 0xec488668 , 0x0b26db6c , 0x25d737f0 ] )
+  , Mont::unsafe_make( [ 0xe9c7e633 , 0xf040ee75 , 0xcf67982f , 0x20d6587f , 0x1e5927c7 , 0xb5fe5dbf , 0x12c9c133 , 0x16154ddd ] )
+  , Mont::unsafe_make( [ 0xe0282523 , 0xe2736d52 , 0x9b6de38c , 0xc61bed7c , 0x83d8ac50 , 0x532d05da , 0x8fc0ca07 , 0x2bf28443 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x54afebe3 , 0xa35a1031 , 0x814a8b9d , 0xff15ed41 , 0x406abf32 , 0xc9523800 , 0x7606dcf4 , 0x02f3436b ] )
+  , Mont::unsafe_make( [ 0x06f4dc85 , 0x2d33f055 , 0xdcb7de4f , 0x2841f351 , 0xda497629 , 0x8682a51c , 0x5e04d176 , 0x2098bc46 ] )
+  , Mont::unsafe_make( [ 0x1d670257 , 0xa7c5f1a0 , 0xa31c97f8 , 0x536f9323 , 0xf3c88715 , 0x6b132c2f , 0x0e4ae0a7 , 0x2546f5ba ] )
+  , Mont::unsafe_make( [ 0x5e46eb7c , 0x05fab41f , 0x8124bd1a , 0x8b54279a , 0xa17c1308 , 0x5f2f8fa4 , 0x68e7f2fd , 0x23ac2ba2 ] )
+  , Mont::unsafe_make( [ 0xe89c1793 , 0x62e0c165 , 0x9793eb69 , 0x91c202d9 , 0xa3abe5c6 , 0xeda70da0 , 0x89561863 , 0x059fd928 ] )
+  , Mont::unsafe_make( [ 0x8d47e551 , 0x65d5333c , 0x269149ae , 0x9de66958 , 0x90267c01 , 0x401a2be2 , 0x59be2361 , 0x1e5c4440 ] )
+  , Mont::unsafe_make( [ 0x026d2c76 , 0xdee0ccd3 , 0x36774a5d , 0xd4ddf6c5 , 0x9e057f6e , 0x97fce5b4 , 0x4ed4a17d , 0x04b7865f ] )
+  , Mont::unsafe_make( [ 0xc557e501 , 0x92f27566 , 0xe6ebe2f2 , 0xd993af51 , 0x5891f38f , 0xd5a3a851 , 0x71cafd52 , 0x3033cfa2 ] )
+  , Mont::unsafe_make( [ 0xe2f2d279 , 0x90ee5a54 , 0x47954e9b , 0xeff7bcb1 , 0xcf0111aa , 0xf727e9bf , 0xa916ed77 , 0x11822d4d ] )
+  , Mont::unsafe_make( [ 0xb3e7cb90 , 0x1887b8ca , 0x3ac140f8 , 0xad1a190d , 0xdf8f1793 , 0x3c23057d , 0x98789c71 , 0x0895dc04 ] )
+  , Mont::unsafe_make( [ 0xca40bae6 , 0xfa687d2c , 0xcdf8e9f1 , 0x5ac036c6 , 0x779ec5a9 , 0x59949bba , 0x7d64cec9 , 0x2bb36e18 ] )
+  , Mont::unsafe_make( [ 0xda004666 , 0x7c362f19 , 0x8ffb4a73 , 0x0d8748d6 , 0x9c477897 , 0x23aa57eb , 0xb2d5903d , 0x01734379 ] )
+  , Mont::unsafe_make( [ 0xc48e6038 , 0xbb932793 , 0xd5dd47b9 , 0x752162a7 , 0xc9c79be9 , 0x249fcef6 , 0xa760e6ae , 0x2ad53271 ] )
+  , Mont::unsafe_make( [ 0x1a44375b , 0xf80001d1 , 0xf2cbd3d6 , 0x9cab118d , 0x9f25026f , 0xc6755f46 , 0xb9fff0c9 , 0x3026a09c ] )
+  , Mont::unsafe_make( [ 0xa14934cf , 0x9a6b6079 , 0xea9d14c0 , 0x15a88594 , 0xc0330519 , 0x1d4d941d , 0x86dea812 , 0x25c0b276 ] )
+  , Mont::unsafe_make( [ 0xba66310e , 0x392ec8a3 , 0x2607df5d , 0x5ceb8f6a , 0xbb8c2860 , 0x81db2333 , 0xcefabc0c , 0x24946e54 ] )
+  , Mont::unsafe_make( [ 0x0e1232b4 , 0x97798626 , 0xdef3227e , 0xb304ce93 , 0x3018b64b , 0xe13d75f8 , 0xa0ef3b0c , 0x2757e6dd ] )
+  , Mont::unsafe_make( [ 0x0115607e , 0xe9c2497d , 0x36203e26 , 0xbd7345ab , 0x497f9120 , 0x9e6237c4 , 0x59442ae1 , 0x02677b89 ] )
+  , Mont::unsafe_make( [ 0xd8a2541b , 0x6575ba9f , 0xacb93eff , 0xf13e21aa , 0x4aeeefa7 , 0xb3a538db , 0x71b814dd , 0x1ce0e8ea ] )
+  , Mont::unsafe_make( [ 0x0df64465 , 0xa598700f , 0xce3a8c0d , 0xb72c9997 , 0xc5062bf9 , 0x0410cb04 , 0x7c8e8272 , 0x214d12a9 ] )
+  , Mont::unsafe_make( [ 0x8815436f , 0x331e921f , 0x243a20dc , 0x8380f432 , 0x5425b16a , 0x98484ed4 , 0xf1c914f0 , 0x242f1a60 ] )
+  , Mont::unsafe_make( [ 0x585daaef , 0x73af098d , 0x0f311fef , 0xea751793 , 0x505e943d , 0x1fb7f17a , 0xb827f36c , 0x27b960f9 ] )
+  , Mont::unsafe_make( [ 0x7388c1c4 , 0x28128af4 , 0x38e1ab20 , 0x5ef4df98 , 0x75aa66ea , 0x6de89ade , 0x7432c220 , 0x155e4879 ] )
+  , Mont::unsafe_make( [ 0x6a789a93 , 0x4f74bad0 , 0xd76dbb5d , 0x9a37a818 , 0xf7bfe5c8 , 0x16967605 , 0x9ec42de8 , 0x2fb3e8ad ] )
+  , Mont::unsafe_make( [ 0x49321936 , 0xfbeb9ee0 , 0x1ed49aca , 0xf3bfbaa1 , 0xd0079d68 , 0xc4498da4 , 0x07a4aa3e , 0x273055a2 ] )
+  , Mont::unsafe_make( [ 0xd431325a , 0xda687b2f , 0xdcc20c8e , 0x9a770a91 , 0xa94b5f05 , 0x0700bb69 , 0x14cfb73b , 0x2e01d0c8 ] )
+  , Mont::unsafe_make( [ 0x46cb4cfd , 0x83720b53 , 0xddc812e8 , 0xff1d9f13 , 0x759c89f7 , 0x17473c9a , 0xcda437fa , 0x2cc1abf9 ] )
+  , Mont::unsafe_make( [ 0x6807eb34 , 0x2f9022ae , 0x70dd3aba , 0xbe422ed2 , 0x6b493854 , 0x97487649 , 0xdac8d127 , 0x1b879d31 ] )
+  , Mont::unsafe_make( [ 0x71b5651d , 0xd115c8d0 , 0x9d78799f , 0x2068d7e5 , 0x99138297 , 0xb2af0843 , 0x61f795ea , 0x00140f89 ] )
+  , Mont::unsafe_make( [ 0x672a99ae , 0xc101a80b , 0x49d9ae1f , 0x0382cfe3 , 0xc9d36f2f , 0x1ebca098 , 0x5bc1f616 , 0x1d8c9be7 ] )
+  , Mont::unsafe_make( [ 0x4898fc53 , 0xba866fda , 0x0370c57f , 0x6b1e6321 , 0x5ed6f6e7 , 0xc73dbef2 , 0x573f10cf , 0x08e89fed ] )
+  , Mont::unsafe_make( [ 0xf45fdc2f , 0xa27837a7 , 0xa61ab9d9 , 0x34fa63e6 , 0x281fbd19 , 0xb3e2e7b1 , 0x3b739bd5 , 0x098ab184 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x8d20d814 , 0x292b9ecb , 0xa4b66c88 , 0x1468b6b4 , 0xafd6d608 , 0x1f509e54 , 0x91ec911a , 0x05275ffb ] )
+  , Mont::unsafe_make( [ 0x16073eeb , 0xebf263fb , 0xd8299b31 , 0xa6d0ae7e , 0x350cfb16 , 0xab2ae0a2 , 0x1122a916 , 0x075157c3 ] )
+  , Mont::unsafe_make( [ 0xd0b5bdd6 , 0xa2e4f1b8 , 0x2d16f1d9 , 0x50779e35 , 0x128fccd1 , 0x53811343 , 0x8c9993ed , 0x15082a02 ] )
+  , Mont::unsafe_make( [ 0x798edf02 , 0x73747800 , 0x601f8d83 , 0x43cba138 , 0xf0a8933e , 0xd71d9b7e , 0xa0307115 , 0x0c284944 ] )
+  , Mont::unsafe_make( [ 0x615951ad , 0x80a08ee0 , 0xf7f1f9d9 , 0x872411f2 , 0x84593d01 , 0xf685960e , 0xc9907ea2 , 0x13a5f71f ] )
+  , Mont::unsafe_make( [ 0xf200b421 , 0x5eb4b2a5 , 0xaeeeffcc , 0x960f5b54 , 0x3538a379 , 0xf6af73bd , 0x38864b0c , 0x0716e91a ] )
+  , Mont::unsafe_make( [ 0x45052c94 , 0xa22d88cd , 0xf927121a , 0x23cabedb , 0x83880826 , 0x1e15e680 , 0x6f77493e , 0x0f323cd1 ] )
+  , Mont::unsafe_make( [ 0x229fa33e , 0xad7c1eb4 , 0x388d8492 , 0x34eaff38 , 0xcf810eb1 , 0xfb2051d9 , 0xa40d0651 , 0x14b4e647 ] )
+  , Mont::unsafe_make( [ 0x9330d6f9 , 0x113e8ee4 , 0x44eebfe3 , 0x28f083d5 , 0x4c9bc349 , 0x5f2c5c1e , 0xe4dacf16 , 0x1a4e50d3 ] )
+  , Mont::unsafe_make( [ 0x81ee3cf0 , 0xf8cfda81 , 0x2facd27f , 0x187322fb , 0x7d1a6367 , 0x7235d63b , 0x831aaf67 , 0x134ddc53 ] )
+  , Mont::unsafe_make( [ 0x07ed3362 , 0x038209d1 , 0x3fe83b8d , 0x7f53e322 , 0x61377af5 , 0xa99f1c1b , 0x560ad748 , 0x0fb10127 ] )
+  , Mont::unsafe_make( [ 0x102874b5 , 0xcd0b83f3 , 0xb181fd38 , 0xf2125b69 , 0x58bca421 , 0x57841954 , 0xfb833bb6 , 0x22bb69db ] )
+  , Mont::unsafe_make( [ 0x203f384c , 0x91e0462a , 0x38e8c526 , 0x357ebb2f , 0x7b0e24fd , 0x9479b432 , 0xd90ae79f , 0x145cf419 ] )
+  , Mont::unsafe_make( [ 0xaa7e4fb5 , 0x12f65565 , 0xe08441c9 , 0xaf8f4a22 , 0x3b33bdb5 , 0xa7643d42 , 0x4ac89463 , 0x01515d3a ] )
+  , Mont::unsafe_make( [ 0x7c7c976c , 0x5eadcdbe , 0xbd85b3c3 , 0x2c0f4f10 , 0x8a6b83fb , 0x8101001e , 0xe0fa349a , 0x254be349 ] )
+  , Mont::unsafe_make( [ 0x04dc9128 , 0xea3b2a48 , 0xa7fab5be , 0xbc8683c8 , 0x5624b63e , 0xc8273342 , 0x81cbe9f8 , 0x20e03015 ] )
+  , Mont::unsafe_make( [ 0x3db87042 , 0x88ab8334 , 0x6ca6588b , 0x400ab8cf , 0x4b7dca0b , 0xef0fe1ac , 0x7758e4a5 , 0x15a3bf2b ] )
+  , Mont::unsafe_make( [ 0x31d251d9 , 0x59677c63 , 0xd9221b6a , 0x76836719 , 0x9fee0030 , 0x020ea3d5 , 0x9748bb78 , 0x09a28a87 ] )
+  , Mont::unsafe_make( [ 0x0b319e2f , 0x3bac00fa , 0x777a19c5 , 0x1a8b0d62 , 0x20d05fed , 0x72775b70 , 0xbf101e59 , 0x22c49415 ] )
+  , Mont::unsafe_make( [ 0x4ff04f11 , 0xb14b929b , 0xa28cf29a , 0x8c8acce8 , 0x423bb931 , 0x57768a56 , 0xe59c0832 , 0x039e359a ] )
+  , Mont::unsafe_make( [ 0xfcea0459 , 0xa83daafe , 0x8de23914 , 0x9781832c , 0x667f0012 , 0x7c446205 , 0x5965e0e9 , 0x21194451 ] )
+  , Mont::unsafe_make( [ 0xa6e34e7a , 0x0718961c , 0x382e4747 , 0x076e3d87 , 0x88fcb313 , 0xd2926f9f , 0x143dbe92 , 0x16a5ed89 ] )
+  , Mont::unsafe_make( [ 0x56a17d84 , 0xdae06a34 , 0xaafd9ddc , 0x07be113c , 0x78da6009 , 0xdcdaad31 , 0x13584a10 , 0x08d33404 ] )
+  , Mont::unsafe_make( [ 0xe9c1c915 , 0xcfe0000c , 0x1310fae0 , 0x6726727c , 0x5e9fae37 , 0x9a6c757e , 0x35f52065 , 0x01535d28 ] )
+  , Mont::unsafe_make( [ 0x7598d12c , 0x4a592d4a , 0x8f0d0fb0 , 0xfa1925c5 , 0x6fc02d7d , 0xcabf0b4c , 0x7d012307 , 0x199d7c22 ] )
+  , Mont::unsafe_make( [ 0x45394c93 , 0x4fd2d625 , 0xecc3482a , 0x5ff770e0 , 0xf0995691 , 0x2619eddc , 0xda429fc7 , 0x2ef80140 ] )
+  , Mont::unsafe_make( [ 0x8ba587b5 , 0x95600246 , 0x11afbbda , 0x7c605068 , 0x2a97ff5f , 0x8742577a , 0x48bf40ae , 0x18cf1665 ] )
+  , Mont::unsafe_make( [ 0xdc0e250c , 0xca041c62 , 0x423297af , 0x21082178 , 0x8d3a62b5 , 0xaf297c29 , 0x3d1e6570 , 0x11d72cb5 ] )
+  , Mont::unsafe_make( [ 0x61fe99b1 , 0xe03e5c73 , 0x02628f1a , 0x063178b0 , 0x45d28d7b , 0x293c445c , 0xca6b1644 , 0x0aa8c889 ] )
+  , Mont::unsafe_make( [ 0x13d1acac , 0xf98dc1d9 , 0xbbeb63a8 , 0xcd0a8df0 , 0xbe6579b5 , 0x2b99d2da , 0x53899511 , 0x0098abe3 ] )
+  , Mont::unsafe_make( [ 0x43de8875 , 0x75d6419e , 0x39b10e4b , 0x314aafc2 , 0x6d51d719 , 0x5242b878 , 0x772e1892 , 0x1573f40f ] )
+  , Mont::unsafe_make( [ 0x76307395 , 0x472881c0 , 0x5ab271ae , 0xe9db053d , 0xaa6944da , 0x9faceac3 , 0x7d32b44a , 0x186ca420 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x11fc5342 , 0x00191a61 , 0xec18197d , 0x9b76f5ea , 0x54d7919a , 0x37592c1b , 0x442e3cc7 , 0x26760d06 ] )
+  , Mont::unsafe_make( [ 0x98f70cf9 , 0xac79655b , 0x366d2d19 , 0xe1518915 , 0xaccebbbe , 0x39b98607 , 0xbd5765f7 , 0x05f282f9 ] )
+  , Mont::unsafe_make( [ 0xf2ca68c7 , 0x753ad17b , 0xc348356e , 0xb2390db8 , 0xd2a440e6 , 0x4dd7e05e , 0x3450fbc0 , 0x24ddeb74 ] )
+  , Mont::unsafe_make( [ 0xe67b29c3 , 0x7045a476 , 0x0bdd200e , 0xb804b071 , 0x7e6b77d8 , 0x67efa4d3 , 0x13824fa5 , 0x11e189d4 ] )
+  , Mont::unsafe_make( [ 0x844ecfee , 0x7e11e1b1 , 0x489ee6d6 , 0x992ff763 , 0x70ccf5ab , 0x39321edc , 0x91f71d32 , 0x152cf190 ] )
+  , Mont::unsafe_make( [ 0x3817bb32 , 0x15d9ff6f , 0x70d56539 , 0xbcf0fac7 , 0x6f9dc259 , 0x145d97a9 , 0x49fd275c , 0x0816448a ] )
+  , Mont::unsafe_make( [ 0xa3287c21 , 0x68cf970f , 0xd56c0fe0 , 0x69319470 , 0x2c64d85e , 0x5692501e , 0xc9f1d883 , 0x2d7c62a3 ] )
+  , Mont::unsafe_make( [ 0xdbb5fae2 , 0xcc2cf129 , 0x55268721 , 0xc32bf965 , 0x6c4f00a2 , 0x6ec7f0f4 , 0x922355b0 , 0x02cfd45d ] )
+  , Mont::unsafe_make( [ 0x2d19ce3f , 0x825b9fac , 0x4203f7bb , 0xe97901e0 , 0x0c41ec5e , 0x18ca5a39 , 0x706b6163 , 0x2fc34c90 ] )
+  , Mont::unsafe_make( [ 0x43af00e4 , 0x88e32b0e , 0x7b2a7416 , 0xba6ad8ae , 0xd1eb2607 , 0xb444af2c , 0x423caf8c , 0x1e92bdac ] )
+  , Mont::unsafe_make( [ 0x6ee632bb , 0xa952bd5d , 0xd97df074 , 0x6da36991 , 0x1b2a04f2 , 0xb1041e85 , 0x8b8f5368 , 0x1e8f38ce ] )
+  , Mont::unsafe_make( [ 0x24b9dde8 , 0x990704dc , 0xf333ad5a , 0x0f1c44ae , 0x7b24c659 , 0x05167ae6 , 0xa68e71a4 , 0x1cadb416 ] )
+  , Mont::unsafe_make( [ 0x27b39058 , 0x7e0b789c , 0x98bb642f , 0x9315c345 , 0xa0911715 , 0xad21e10f , 0x2d935227 , 0x016a1aac ] )
+  , Mont::unsafe_make( [ 0x1523421a , 0xb93dea46 , 0x06627ce0 , 0x2ce9d9fc , 0xd008a251 , 0xe08bd2d3 , 0x0e58d1ed , 0x049b87ec ] )
+  , Mont::unsafe_make( [ 0xc6e0c5e9 , 0x61f31413 , 0xcb0a9b58 , 0xc526abbb , 0xf7467b90 , 0x4ddb21e7 , 0x02515eaf , 0x086a21e3 ] )
+  , Mont::unsafe_make( [ 0x52c56f84 , 0xdde423f3 , 0xe094f2ca , 0xd4fadf2d , 0x1d74d31a , 0x90f864c2 , 0x8125ece2 , 0x1e20c56e ] )
+  , Mont::unsafe_make( [ 0x5cd986c1 , 0x29b83f73 , 0x304c908c , 0x6fb1b669 , 0xe6aabae0 , 0x3b69d202 , 0xa39293c5 , 0x2b959d94 ] )
+  , Mont::unsafe_make( [ 0xa0a94e25 , 0x7ebdf14f , 0x707596ed , 0x7c7ff62b , 0xb4cc75a8 , 0xdd1278c6 , 0x48e79b4d , 0x0ead0409 ] )
+  , Mont::unsafe_make( [ 0x52bf7068 , 0x57c483ea , 0x8572fba2 , 0xa1fb8897 , 0x29dbae0f , 0x30ed9e9e , 0x8292cce7 , 0x0ffcc75f ] )
+  , Mont::unsafe_make( [ 0xd1f6b0c9 , 0xcfacc2d6 , 0xb0952ff5 , 0x02d25f1d , 0xd0a6e77b , 0x5259ed0d , 0x0c13e212 , 0x10372658 ] )
+  , Mont::unsafe_make( [ 0x7c42983b , 0x42a972b5 , 0x9ca3ec19 , 0x5c5e938c , 0xcf0ac344 , 0x0d188c81 , 0x14e6b661 , 0x20a5f143 ] )
+  , Mont::unsafe_make( [ 0xe87d86fc , 0xe3f940f2 , 0x69116b50 , 0xe0627a3d , 0x76329152 , 0xb84dab7b , 0xf2203163 , 0x16582a26 ] )
+  , Mont::unsafe_make( [ 0x0d8d6e41 , 0x9a3e4a3b , 0xc9d40e40 , 0xfa503ca4 , 0xece47fcc , 0x2ebfd03e , 0x9f9afc87 , 0x20d4266b ] )
+  , Mont::unsafe_make( [ 0x01458aeb , 0xb33e2b56 , 0xb6386128 , 0x42392db5 , 0x19c730ce , 0x36ba7a9a , 0x1b195c14 , 0x0ce528e9 ] )
+  , Mont::unsafe_make( [ 0xf8981b52 , 0x23c95abb , 0xb26fa997 , 0xf375d298 , 0x2449a508 , 0xafa86ed0 , 0x30a034eb , 0x1c58bd72 ] )
+  , Mont::unsafe_make( [ 0xfdb03a8b , 0xe36e96e4 , 0xb2bfc5e8 , 0xf9a4ba30 , 0x2e78364a , 0x73cc6263 , 0x915dcead , 0x143fce89 ] )
+  , Mont::unsafe_make( [ 0xb78864eb , 0x7c283e96 , 0x3ba702b4 , 0x813a9123 , 0xae6a0098 , 0x6e5d290a , 0xdf218044 , 0x0d44804e ] )
+  , Mont::unsafe_make( [ 0x8fd5dd56 , 0x2897cd53 , 0xc9c695bb , 0x781bac7b , 0x35429adc , 0xdae1af61 , 0x3f780f81 , 0x0d50f4ff ] )
+  , Mont::unsafe_make( [ 0xca83ad8b , 0xda1ed998 , 0x8fa1dfb0 , 0xf5d06adb , 0x63078ca3 , 0x9d47f626 , 0x3a7c78a6 , 0x15cdd76c ] )
+  , Mont::unsafe_make( [ 0xa8497d4d , 0xcdbb8bae , 0xe285130c , 0x27e880aa , 0xe861db11 , 0xd91ff653 , 0x80dc3011 , 0x27ae4654 ] )
+  , Mont::unsafe_make( [ 0x5eeaeb60 , 0x0d84c528 , 0xec6f2a4b , 0xa7876d4a , 0x3fec7e62 , 0xdd8e65b7 , 0x8ba84feb , 0x00403ac1 ] )
+  , Mont::unsafe_make( [ 0x7f3dd208 , 0x798b161a , 0xb1677da5 , 0x29f4ad14 , 0xed1fa64b , 0x015ded93 , 0xcdadfb3c , 0x08db9348 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x306f2e0e , 0x4db8a783 , 0x3c7bea2d , 0x94bbf1d0 , 0xa9f11cb2 , 0x526c195e , 0xa11900b1 , 0x11f9a4e3 ] )
+  , Mont::unsafe_make( [ 0x54002e8c , 0xe9fe4445 , 0xe4c7b107 , 0x8426fc3b , 0xbc0289bc , 0x297c498b , 0x32c3d919 , 0x1a9fe34d ] )
+  , Mont::unsafe_make( [ 0x73935ab2 , 0xb843f84e , 0xd9b0fc96 , 0x0f05826a , 0x4ac8ab97 , 0x9f2d5a13 , 0x0cbf6609 , 0x05bd9151 ] )
+  , Mont::unsafe_make( [ 0x19d442f7 , 0x7172c40b , 0x0097ab81 , 0x9014edf4 , 0xf0f29181 , 0x891339fc , 0xdc5410a0 , 0x2d58873d ] )
+  , Mont::unsafe_make( [ 0xb66062dc , 0x88dfbecc , 0x096e0848 , 0x4d2bc79c , 0xe8a63fae , 0x1bf60f75 , 0xee2e375c , 0x0e2665c0 ] )
+  , Mont::unsafe_make( [ 0x3ea1c289 , 0x0f10fff1 , 0x3d8032e3 , 0x699996bd , 0x40a294b9 , 0xd2c56d2c , 0x519eec8c , 0x093fddb3 ] )
+  , Mont::unsafe_make( [ 0x7959040d , 0x9d29f8ee , 0xf59f4486 , 0x5239b58e , 0xbb3ad917 , 0x41ecb35b , 0xf59832c2 , 0x2b5cf7ef ] )
+  , Mont::unsafe_make( [ 0xa771612b , 0xe2af4472 , 0xfb943fdc , 0x9a0992a1 , 0xbccaf133 , 0xa8df865d , 0x50b93428 , 0x2ef85239 ] )
+  , Mont::unsafe_make( [ 0x029004e5 , 0xe4c6e620 , 0x56437bcb , 0xb68c66ab , 0x5d800304 , 0x4b0012a9 , 0x9551a31c , 0x083264e6 ] )
+  , Mont::unsafe_make( [ 0xd1c7c4cf , 0x1fab2a0b , 0x50893519 , 0x9e6c55c1 , 0x2c434eb8 , 0x0a7dc95e , 0x249e6b8c , 0x22150a73 ] )
+  , Mont::unsafe_make( [ 0x6050e7c2 , 0x772ef419 , 0x4d123a12 , 0x49532d37 , 0x1c9d9af5 , 0x95e7b1f0 , 0xddd65c25 , 0x269990a3 ] )
+  , Mont::unsafe_make( [ 0x78ad6ce1 , 0x4c4842b1 , 0x4b4ae0fb , 0x992092fa , 0xd98ede8a , 0xacbc7633 , 0x7cac5313 , 0x0c37dde8 ] )
+  , Mont::unsafe_make( [ 0x25a1c51a , 0x1b4c04ae , 0x675adcb3 , 0xf758bf58 , 0xb6818eb6 , 0xa7fc7a5b , 0x41705bd4 , 0x22a26afc ] )
+  , Mont::unsafe_make( [ 0x78b7afdb , 0xb9ad6051 , 0x0c3734e5 , 0x98effcad , 0xe2d96357 , 0x88f642e7 , 0x81dddfa7 , 0x219bd9de ] )
+  , Mont::unsafe_make( [ 0x4ed564fe , 0xc6fa3a63 , 0xf97b4d4f , 0x5e3c64c6 , 0x95bdcbf0 , 0x32ecfd52 , 0x03dadd9a , 0x26348702 ] )
+  , Mont::unsafe_make( [ 0xa9774715 , 0xf15afafa , 0x84d092d8 , 0x863b118e , 0xe502bf6b , 0x8180e402 , 0xafab2efe , 0x2dc32dfa ] )
+  , Mont::unsafe_make( [ 0xb3b24b77 , 0x492b7e68 , 0x92a67cf9 , 0x53262ecd , 0x62ec65a9 , 0x1b678059 , 0x58d46ec8 , 0x1fb5ac21 ] )
+  , Mont::unsafe_make( [ 0xe3bf9a56 , 0xbad14824 , 0xedf7f6b3 , 0x52c22ab9 , 0x3fc008b6 , 0xa77ff483 , 0xa330ccb4 , 0x199cf263 ] )
+  , Mont::unsafe_make( [ 0x1f50d500 , 0xcdd73fd2 , 0xda081cb0 , 0xacccc386 , 0xdd2ccdee , 0x7b917017 , 0xb5d20888 , 0x20052ae3 ] )
+  , Mont::unsafe_make( [ 0x72e5241b , 0x18cb67ff , 0x347993bf , 0xe579b860 , 0x0c646620 , 0x361cf8da , 0x709078ba , 0x1c8a0e3a ] )
+  , Mont::unsafe_make( [ 0x0e5db04f , 0x261f518b , 0x9a716599 , 0xf6f6e004 , 0x8eda3751 , 0xb2388085 , 0xa1223129 , 0x1240ca8b ] )
+  , Mont::unsafe_make( [ 0xb15ec10d , 0x04bf1d37 , 0xeaa6f01f , 0x0ed2e68d , 0x3b48b676 , 0x0517518f , 0x449c5433 , 0x015365a0 ] )
+  , Mont::unsafe_make( [ 0x5361a2dd , 0x7d16850f , 0xdcaf1ec7 , 0x44a87b70 , 0x4b0ab69e , 0x7bcbeb54 , 0x99cb9a4a , 0x101d7e1b ] )
+  , Mont::unsafe_make( [ 0x1729726e , 0x791d16ce , 0x2c07bfdf , 0xeb989798 , 0xe6e2cb21 , 0x8f34c7d3 , 0x2bc916ba , 0x06ad4827 ] )
+  , Mont::unsafe_make( [ 0xce1407d1 , 0xc0b52287 , 0x153809df , 0x3f852599 , 0xaf7c36f9 , 0x90ca7633 , 0x1205a460 , 0x1008ccad ] )
+  , Mont::unsafe_make( [ 0x02dd56b4 , 0x084c5ddc , 0xee11df89 , 0x0666a9ea , 0x6a8ffc7d , 0x016fa439 , 0xc4fd9d34 , 0x2110b059 ] )
+  , Mont::unsafe_make( [ 0xd184a68c , 0x9c8e2427 , 0x6e69b98e , 0x4006cbf9 , 0xe7877fdd , 0xaafda8d0 , 0xf4dd3b59 , 0x2f222b97 ] )
+  , Mont::unsafe_make( [ 0x6043a0fe , 0xe6f36131 , 0xf44c96ea , 0xf22a60a0 , 0x92883e67 , 0x05ae8039 , 0xb0e44054 , 0x0c6e728e ] )
+  , Mont::unsafe_make( [ 0x2e371c78 , 0xe3746a11 , 0xc557cbc3 , 0xe604e1db , 0x2b241dea , 0x3fc7a5d9 , 0xcaf85132 , 0x2efdf9c7 ] )
+  , Mont::unsafe_make( [ 0xb5d6f027 , 0x6316261c , 0x25b9e69f , 0x6bf8583d , 0x7fd02c19 , 0xb95940f4 , 0x9dd36c64 , 0x09fc1f7d ] )
+  , Mont::unsafe_make( [ 0xd3de63c5 , 0x704edad6 , 0x4db7f681 , 0x99bb8437 , 0x4fbf1b78 , 0x722b04e9 , 0xc7d2e365 , 0x1a346205 ] )
+  , Mont::unsafe_make( [ 0xad2f6d9d , 0x920d4ece , 0xb6ad15e2 , 0x3068344b , 0xdf49966d , 0x8c3d081d , 0x3763db6f , 0x304f233b ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x6e7cfe65 , 0xf4822124 , 0xda39a35a , 0x4b72f74d , 0xb681767c , 0x02f527b4 , 0xd266b993 , 0x21b6981c ] )
+  , Mont::unsafe_make( [ 0xc22bf313 , 0x05a56bf3 , 0x882bdd99 , 0xcdda4ed3 , 0x7958ad19 , 0x50dad820 , 0xb48bc3b6 , 0x06b6b7dc ] )
+  , Mont::unsafe_make( [ 0x16167508 , 0xa78b8002 , 0x4edd455a , 0xc3039d39 , 0xb0be744f , 0x56c8fbbc , 0x19f3f93f , 0x19429665 ] )
+  , Mont::unsafe_make( [ 0x12509406 , 0x24d592ef , 0xdbf5862e , 0xf8154d3b , 0xe17b3e02 , 0x57680a93 , 0x09025c15 , 0x1d96a115 ] )
+  , Mont::unsafe_make( [ 0xf41e01f8 , 0x0689a3f0 , 0x2ad7ae38 , 0x99a0916c , 0xdd8fc449 , 0xb0b570c2 , 0x7c90f26e , 0x224ae7c0 ] )
+  , Mont::unsafe_make( [ 0x41f623c6 , 0x620e5ce2 , 0xa446c885 , 0x5ba0005a , 0xe494bd4b , 0x823f6c06 , 0xe56bc395 , 0x263a3b57 ] )
+  , Mont::unsafe_make( [ 0x2fe498dc , 0xa490d964 , 0xdd697d8c , 0x14566376 , 0xeeb4cafd , 0xad274a18 , 0x6618812f , 0x21bede82 ] )
+  , Mont::unsafe_make( [ 0x45257bdc , 0x2b312c14 , 0x2be9f746 , 0x52f6636c , 0x983afef0 , 0xafc0c5c0 , 0x87c1d5a4 , 0x019b2696 ] )
+  , Mont::unsafe_make( [ 0xafad7109 , 0x752c874a , 0x57834c22 , 0x1d836a55 , 0x5cbe4773 , 0x8bfdf381 , 0x4044bc51 , 0x242c245e ] )
+  , Mont::unsafe_make( [ 0xf014caf1 , 0x2dbc5b7b , 0x9b33f5a9 , 0xc82d50ab , 0x67452388 , 0x776d3c39 , 0x2dbd7432 , 0x190a846a ] )
+  , Mont::unsafe_make( [ 0xb2887b84 , 0xe9d1036e , 0xe3b63ae3 , 0x3bc18ac1 , 0xa7636da8 , 0x3c229ac5 , 0x84229e3c , 0x2290a270 ] )
+  , Mont::unsafe_make( [ 0x113d95c2 , 0xdd3c03a9 , 0x1a319c65 , 0xbbd2deca , 0x1818fae2 , 0x3be72309 , 0xa6cf6d3e , 0x27354a36 ] )
+  , Mont::unsafe_make( [ 0x332cc492 , 0xedf10052 , 0x7f925d21 , 0x0450d39e , 0xbe915fd6 , 0x8ddd02ea , 0x76426d7f , 0x04e4e65c ] )
+  , Mont::unsafe_make( [ 0x0b00e794 , 0xd0bbf02d , 0x49c7d56a , 0x9ad9bab5 , 0x65d3c47e , 0xb59a9f98 , 0x06de132a , 0x03aac08f ] )
+  , Mont::unsafe_make( [ 0x1940e1c7 , 0x94dd6e5a , 0x975bb64d , 0x88f8b263 , 0x9ca67a48 , 0x0e7a2987 , 0x0277218c , 0x084e5e9d ] )
+  , Mont::unsafe_make( [ 0xda81c5e5 , 0xd48f6a97 , 0xd6d24460 , 0x384e3c51 , 0x00403838 , 0xe0fafdf2 , 0x23231169 , 0x12ae76a9 ] )
+  , Mont::unsafe_make( [ 0xd49eb1dc , 0x26256d6f , 0xe4ae79b9 , 0xf84f603e , 0x90309530 , 0x7618bc4e , 0x8c27e8a1 , 0x2381029b ] )
+  , Mont::unsafe_make( [ 0x585fc3b7 , 0xe25967e9 , 0x862125a0 , 0xac677b8a , 0xe7899f68 , 0xc5ed6b1f , 0xbc13905f , 0x1a748f5d ] )
+  , Mont::unsafe_make( [ 0xf2874956 , 0xdf5f70c4 , 0xebcd88c6 , 0x80db9ce6 , 0x12446627 , 0xcb1c1145 , 0x98dc3f73 , 0x291242db ] )
+  , Mont::unsafe_make( [ 0x18347436 , 0x5b9ac52c , 0xdb45a892 , 0xeccd549b , 0x530a46da , 0x4d98fcfa , 0x29a82b71 , 0x0906a314 ] )
+  , Mont::unsafe_make( [ 0x221c4948 , 0x65d9f8f2 , 0x0dfa2f65 , 0x207ca0e2 , 0x2a679ee2 , 0xff7a980f , 0x98ed9443 , 0x17a2ae68 ] )
+  , Mont::unsafe_make( [ 0xe256afc5 , 0x7dd00bae , 0x41b895be , 0x191ec603 , 0x7622de9c , 0xe4c243f8 , 0xf607a09a , 0x1bcf2e5a ] )
+  , Mont::unsafe_make( [ 0x4d037d25 , 0xcfd17159 , 0x11ca572f , 0xe0f59851 , 0xef0ef7fb , 0x8cced62c , 0x0035cc59 , 0x0221f301 ] )
+  , Mont::unsafe_make( [ 0xe61e53b0 , 0x660bcf20 , 0x3892a58f , 0x397e67ea , 0xe009c553 , 0xafe8ebe4 , 0x847a2dc1 , 0x02d2f6f9 ] )
+  , Mont::unsafe_make( [ 0xffd7fe75 , 0x73d21fac , 0xe42c3510 , 0x6e06b0c1 , 0x3297fb79 , 0x97bb3f51 , 0x3ad64d6e , 0x00ed99fb ] )
+  , Mont::unsafe_make( [ 0x6674adf3 , 0x1f2c4dd1 , 0x8611a856 , 0x8410ce73 , 0x15c8460f , 0x066d1553 , 0x1e104a23 , 0x029dca29 ] )
+  , Mont::unsafe_make( [ 0xbb0585d6 , 0xc1b689ba , 0xaac87524 , 0x0342ab21 , 0x87a52288 , 0x40c13eba , 0xd9f45eee , 0x29b2ccdb ] )
+  , Mont::unsafe_make( [ 0xfaf5fb69 , 0x2ef04ac2 , 0xbf813408 , 0xc33bbc1c , 0xdf988683 , 0xd88cd53c , 0x98e04a88 , 0x1bbae215 ] )
+  , Mont::unsafe_make( [ 0x06bea3bb , 0xf0976986 , 0x1de1a481 , 0xd2ec1b80 , 0x0249d73d , 0xd4fb6f73 , 0x2540bb8c , 0x2c96269a ] )
+  , Mont::unsafe_make( [ 0x8cac6c72 , 0x2332d3f9 , 0x89d987e9 , 0x5444e4b9 , 0x4002ff18 , 0x6456f2ed , 0x2b932f80 , 0x010cc44a ] )
+  , Mont::unsafe_make( [ 0x91996631 , 0xe43b03d4 , 0xfcd61a55 , 0x3dc65605 , 0xa24124b2 , 0xd9ff397a , 0x4c0a4d08 , 0x2396b630 ] )
+  , Mont::unsafe_make( [ 0x27950b07 , 0x99ef97e1 , 0xd1508e50 , 0x6dbd8241 , 0xf7d1717a , 0xb39169ff , 0xb8359b7e , 0x08c61d18 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x28f58926 , 0x843f7afd , 0xb28b90d1 , 0x0bf78d69 , 0x99dc83b2 , 0x164afa70 , 0x6542bd38 , 0x1a89ec06 ] )
+  , Mont::unsafe_make( [ 0xd794bf0e , 0xdc554ce6 , 0x20a27709 , 0x81f38e27 , 0x1beb0f4f , 0x2c815f99 , 0x489bc296 , 0x0878d111 ] )
+  , Mont::unsafe_make( [ 0x99aab79d , 0xfb97f3f7 , 0xc10ff25b , 0x808f53df , 0xd61e63eb , 0xe7710960 , 0xd6b2b682 , 0x270c952d ] )
+  , Mont::unsafe_make( [ 0x1ffe63a6 , 0xbd30bdda , 0x977bde7e , 0x73fa8bda , 0x84fa7ed0 , 0xaf2be55e , 0xad42fe7f , 0x1fa881f5 ] )
+  , Mont::unsafe_make( [ 0xb68eda72 , 0xdbde66fa , 0x1f0e5e2e , 0x272f071d , 0x0b643f3d , 0xa8a6f7c6 , 0xe357cc2c , 0x1e47866e ] )
+  , Mont::unsafe_make( [ 0x9215225f , 0x2b62f86f , 0x94d78e26 , 0x777517d8 , 0xb67c501d , 0x2d5b1392 , 0x0a57bed3 , 0x2e69a984 ] )
+  , Mont::unsafe_make( [ 0x22d75cee , 0xe0ea4b71 , 0x2f3350fa , 0x5eb5e61e , 0xd987c138 , 0xda3b537d , 0x72db952b , 0x1bc46a2e ] )
+  , Mont::unsafe_make( [ 0x56776193 , 0x40296e99 , 0x7486bbca , 0x6e5d4d23 , 0x3b8b27a9 , 0x06de2d1e , 0x7efc4d7d , 0x16b1a443 ] )
+  , Mont::unsafe_make( [ 0x3197a479 , 0x16dbd31b , 0x9b4149cd , 0xab77edf3 , 0x6cd5c231 , 0xb9cf3b2c , 0x36df41a3 , 0x0535ca49 ] )
+  , Mont::unsafe_make( [ 0x56d800e0 , 0x13c849a8 , 0x23186d1a , 0x72d4b504 , 0x0e39ade1 , 0x1a9057d4 , 0xce9bbf82 , 0x18fa7bec ] )
+  , Mont::unsafe_make( [ 0x72417f67 , 0x7ca4fc57 , 0xece31d12 , 0x30319aeb , 0xdec1a811 , 0xf9b12a0a , 0x0ecbf45d , 0x04a2f0fb ] )
+  , Mont::unsafe_make( [ 0xbbaf08a1 , 0xc46a809a , 0x9de8d039 , 0x4b5cecd4 , 0xcecb5518 , 0xfb416f48 , 0xee32d7d9 , 0x09f4c45f ] )
+  , Mont::unsafe_make( [ 0x06728f41 , 0xd7cbd43d , 0xeadd9f63 , 0xa9cb4b2a , 0x6a44508a , 0x43e10019 , 0x12f66fe8 , 0x08841737 ] )
+  , Mont::unsafe_make( [ 0xe4e94e48 , 0x9b640470 , 0x785b8407 , 0xc618441a , 0xabf19a1f , 0x38ae1959 , 0x1a5e13b3 , 0x20c37a1a ] )
+  , Mont::unsafe_make( [ 0xba16904d , 0x1bf818ce , 0x5cf350cd , 0x9fca841d , 0x7b272ef8 , 0x1bd5f713 , 0x8d4574d8 , 0x26f98172 ] )
+  , Mont::unsafe_make( [ 0x1dc1bc34 , 0xc55ad497 , 0x41a2f9bf , 0xbc688215 , 0xfdd9d5d8 , 0x3d06f0d8 , 0xfe4168b3 , 0x0cf876af ] )
+  , Mont::unsafe_make( [ 0x111b21b4 , 0x463e6eb7 , 0x2ee8a7b3 , 0xe1d967d5 , 0x20bc4453 , 0x9a1e690f , 0x83f7c066 , 0x2b5390e0 ] )
+  , Mont::unsafe_make( [ 0xfdb1bff6 , 0x43fd37b2 , 0xcb342a27 , 0xcd17bb9b , 0x62e66e9d , 0x0b0bb1cd , 0x0b03e4c1 , 0x137ecdcc ] )
+  , Mont::unsafe_make( [ 0xb6e3b05c , 0x73b35829 , 0x1485b1a9 , 0xbf4f2248 , 0x59b339cc , 0x2ef91b37 , 0xc435eae6 , 0x1a050acf ] )
+  , Mont::unsafe_make( [ 0xcffb38f6 , 0xe2df0790 , 0x76850359 , 0x195cfd96 , 0x52f7efea , 0x262a0576 , 0xbad6ca76 , 0x193d3c1d ] )
+  , Mont::unsafe_make( [ 0xbfa0511a , 0x98dc5134 , 0x51512828 , 0x3b351308 , 0x5ce61c2f , 0x5fe7819d , 0xec2de17f , 0x1c91da76 ] )
+  , Mont::unsafe_make( [ 0x98c50b0e , 0x644ef5f9 , 0xbb93432d , 0x4e43406b , 0x8f2a18d7 , 0x302c40d9 , 0x560f1ea3 , 0x208b3964 ] )
+  , Mont::unsafe_make( [ 0x0e9f8e71 , 0x6b7fe13f , 0x1ce5f92e , 0xf4b77c7a , 0x3a17669a , 0xe11dd2c6 , 0x40e78054 , 0x02357cee ] )
+  , Mont::unsafe_make( [ 0x9305c2d5 , 0xcfa3a221 , 0xb87e9cd5 , 0x2747f246 , 0x1e187647 , 0xf64694ce , 0x3a250892 , 0x05d63455 ] )
+  , Mont::unsafe_make( [ 0xc60b8d13 , 0xc93b5d39 , 0x68ed976d , 0xffd0d015 , 0xb162db37 , 0x0952ba56 , 0xe30f9bf3 , 0x02a5a2ac ] )
+  , Mont::unsafe_make( [ 0xc57adf8b , 0x081dc669 , 0x7120592b , 0x34a3927f , 0xeb15b034 , 0x679c4509 , 0xbd69bc79 , 0x0f3e569c ] )
+  , Mont::unsafe_make( [ 0x121f3378 , 0x338f1ce6 , 0xd33fa159 , 0xd5a6f1ac , 0xd86e7ce1 , 0xea2b2359 , 0x71d0ee48 , 0x273b5bd2 ] )
+  , Mont::unsafe_make( [ 0xafa26a6c , 0xb3665fbb , 0x93ce17f5 , 0x6f6db907 , 0x22750579 , 0x85c1cf23 , 0x70412bb4 , 0x06e4f888 ] )
+  , Mont::unsafe_make( [ 0xf98929d2 , 0x0ba27804 , 0xbd829562 , 0x633b98f3 , 0xbd9c21e1 , 0xe1cae8c0 , 0x9911c4e2 , 0x1cb832f1 ] )
+  , Mont::unsafe_make( [ 0x4325c251 , 0xe02768f9 , 0xacb57d83 , 0xa38e3790 , 0x4046c8b6 , 0x54dee6c2 , 0xf81c6b84 , 0x16836a91 ] )
+  , Mont::unsafe_make( [ 0x26af0ca8 , 0x7896576f , 0x5869b86f , 0xfa126f57 , 0xc4f58bbd , 0x7c88e91c , 0x67923056 , 0x24e1184b ] )
+  , Mont::unsafe_make( [ 0x29c2bc8f , 0xcf36c923 , 0x79f99c74 , 0x899bbe20 , 0xcf7b6e43 , 0xdb2e211e , 0xd3785d9a , 0x2c249119 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x1399449f , 0x190b0bbf , 0x3975ce2f , 0x0ce6c23e , 0x4b73c30b , 0x79bb0c84 , 0x8a5292d6 , 0x0df1c42f ] )
+  , Mont::unsafe_make( [ 0x7dec99c4 , 0x6a61a3d7 , 0x1a16059e , 0x108de41f , 0x923b9576 , 0x46e48613 , 0x9f2bc05e , 0x15c5223d ] )
+  , Mont::unsafe_make( [ 0xe46f146d , 0x1007894b , 0x7a04206a , 0x4708e7e8 , 0x7767e558 , 0x02cbab45 , 0xa639a5b3 , 0x16742ecd ] )
+  , Mont::unsafe_make( [ 0x310cabf4 , 0x4a730fa5 , 0x01d70235 , 0x17e8a5db , 0x0b6a87f4 , 0xf130c759 , 0x886e5796 , 0x2adea6de ] )
+  , Mont::unsafe_make( [ 0x5d0e4cad , 0x9ccbe552 , 0x9edd39df , 0x54870b23 , 0x883244a3 , 0x23db2eb9 , 0xb901058d , 0x0d65cd19 ] )
+  , Mont::unsafe_make( [ 0xd756151c , 0x329c161d , 0x68f97b3f , 0x3eb7a46f , 0xcb20f1a9 , 0x62e97827 , 0xfb9400a1 , 0x03b9d8a8 ] )
+  , Mont::unsafe_make( [ 0x85c05fa3 , 0x5469939e , 0xcb3bfa23 , 0x0a92eb16 , 0xabdc891c , 0x61344e12 , 0x2f5eea8f , 0x1d61e9e0 ] )
+  , Mont::unsafe_make( [ 0xd0bff6b3 , 0x46fd212b , 0xac6f1264 , 0x52674f7a , 0x0e43497c , 0x57e9da0c , 0x04cb07c1 , 0x03206180 ] )
+  , Mont::unsafe_make( [ 0xfb0a4a91 , 0x3c8f9a5f , 0x0fad6f74 , 0xae0c0a68 , 0xebf98d03 , 0xf66b5191 , 0x47e6e455 , 0x00bb648e ] )
+  , Mont::unsafe_make( [ 0xe4975f60 , 0xa300f171 , 0x1b91f3a4 , 0xadcb39b6 , 0x5fc2369e , 0x4d1f3493 , 0x7c152525 , 0x19dfb424 ] )
+  , Mont::unsafe_make( [ 0xb00648ab , 0x40217044 , 0x1d8a5ab6 , 0xeb160b10 , 0xdaec0445 , 0x9bc51527 , 0x2ed5da98 , 0x23e8f1b0 ] )
+  , Mont::unsafe_make( [ 0x26c8dd05 , 0x702ca228 , 0x98c8c73b , 0xb7b8e50c , 0xf69ddc56 , 0x0f60b1c8 , 0x9a816ae6 , 0x056c2546 ] )
+  , Mont::unsafe_make( [ 0x1c6ec68c , 0xba9e4866 , 0x714ef2a6 , 0x3dde4fe9 , 0x461947dd , 0x0cd7f343 , 0x9d9d58b0 , 0x2b37d5d3 ] )
+  , Mont::unsafe_make( [ 0xd086ffcb , 0x65e8899c , 0xcbcde19d , 0x18633494 , 0xa7610d8f , 0x251a7a65 , 0xe21dafe0 , 0x1328e728 ] )
+  , Mont::unsafe_make( [ 0x7b894a20 , 0x41b6763d , 0xa3961e6a , 0x0144996a , 0xf12802c4 , 0x581f17f7 , 0xfc534159 , 0x1ec14e4d ] )
+  , Mont::unsafe_make( [ 0x616f4859 , 0x2d9aff10 , 0x5349bb26 , 0x2db5d7ec , 0x2bffcc9b , 0x9335e44e , 0x8228e0ea , 0x046c1430 ] )
+  , Mont::unsafe_make( [ 0xab2b5358 , 0x8005bb39 , 0x506b39c3 , 0xd90c3020 , 0xa5a8bd6c , 0xf73e620f , 0x99152398 , 0x2bac0dc1 ] )
+  , Mont::unsafe_make( [ 0x923393f8 , 0xe89b65bf , 0x4bcc73ba , 0x8b4f4b9a , 0xf505608b , 0xd0742804 , 0x524081fe , 0x0244be97 ] )
+  , Mont::unsafe_make( [ 0x49c7ca4c , 0x1c130b35 , 0xe85cca8a , 0xd6a9dcf5 , 0x4f96f6e2 , 0xe21a24bd , 0x82e59927 , 0x030e7132 ] )
+  , Mont::unsafe_make( [ 0x0d866748 , 0xeeb8c69b , 0x976313d7 , 0xb182fde2 , 0x63d0c44b , 0x03b271d6 , 0x8913122f , 0x07e586a3 ] )
+  , Mont::unsafe_make( [ 0xaf388aca , 0xe1101370 , 0xf24ea5c8 , 0x94b1b751 , 0xff86aff6 , 0x0768317b , 0x0fe69ad3 , 0x1f2e8d06 ] )
+  , Mont::unsafe_make( [ 0x65710f71 , 0x64df387a , 0x615d1578 , 0x01a6926b , 0xacb3e04e , 0x4c6e413f , 0x37454482 , 0x0296b3d5 ] )
+  , Mont::unsafe_make( [ 0x1d976b96 , 0x7b328cdc , 0x767e5d1a , 0x970fdc3e , 0x9dfbd92f , 0x5a9bf4f4 , 0x2c2f81a8 , 0x20c5f179 ] )
+  , Mont::unsafe_make( [ 0x212c710d , 0xcecd36c9 , 0x82f4d84e , 0x139b0d7b , 0xa07c02fc , 0x4114da9a , 0x850c3f67 , 0x1acfd3d3 ] )
+  , Mont::unsafe_make( [ 0x32e058eb , 0x72f26979 , 0x6e1b7d5c , 0xe1b05b4c , 0x7063a280 , 0x9b0b82a4 , 0x65a548b0 , 0x269d400a ] )
+  , Mont::unsafe_make( [ 0xf42e88c0 , 0xaf5075e2 , 0xe7ec9289 , 0x4af6511c , 0x5f29a736 , 0x3a0fc9f6 , 0x23163df1 , 0x2efd03e1 ] )
+  , Mont::unsafe_make( [ 0xc3840a6c , 0xe8ed36ab , 0x780f44f8 , 0xd9f26748 , 0xd17d3dda , 0x24472e5e , 0x92cdf8e5 , 0x0134ba4d ] )
+  , Mont::unsafe_make( [ 0xd5b35a83 , 0x69ea0e6a , 0x28bf6ed1 , 0x7aca5065 , 0x9b5cec71 , 0x3f0e896d , 0xc7509c13 , 0x063a8ff4 ] )
+  , Mont::unsafe_make( [ 0xf85ef79f , 0xbe83e353 , 0x1d3d07c5 , 0x27e9ad42 , 0x47bd19d2 , 0x2928fe67 , 0xed515ed3 , 0x02d8c8d6 ] )
+  , Mont::unsafe_make( [ 0x49475531 , 0xa3cee06c , 0x8e2f98f8 , 0x292a40e5 , 0x3953295d , 0x2236e189 , 0x4363a6c8 , 0x290c197f ] )
+  , Mont::unsafe_make( [ 0x9f0ea079 , 0xb8bdc8ed , 0x60c994e4 , 0x9f8481aa , 0x70969117 , 0x929603a5 , 0xd86e7acf , 0x157b357f ] )
+  , Mont::unsafe_make( [ 0x8776b549 , 0x41e230d0 , 0x08fa10c3 , 0x19b8a315 , 0xef7fe981 , 0x447eee35 , 0x386e65af , 0x0b3791ac ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x43c92210 , 0x7b13c8d4 , 0x9be6ccbc , 0x1fab6715 , 0x031e08ef , 0xb73f4eb0 , 0x1fbdb1b9 , 0x1b795ec6 ] )
+  , Mont::unsafe_make( [ 0xc3aad771 , 0xcf2777be , 0x6aa2e50b , 0x2515c5df , 0x3c2d216d , 0xedce0b37 , 0xd967895c , 0x045dcc84 ] )
+  , Mont::unsafe_make( [ 0x0d1a1f22 , 0xfc87ce0d , 0x22b5a835 , 0xcc1142a6 , 0x88044d5f , 0x468c0bd5 , 0x140ce693 , 0x1cd8b181 ] )
+  , Mont::unsafe_make( [ 0xb949ab02 , 0x64b0fb50 , 0x2ed7b4e0 , 0x6e6c9742 , 0xbf5eb02f , 0x441facf4 , 0xa78070f3 , 0x24138960 ] )
+  , Mont::unsafe_make( [ 0x7acc6c6a , 0xfad21183 , 0x24a5113f , 0x91497acd , 0x75df9f45 , 0x46a5e3e4 , 0x7a6047e2 , 0x2e61f262 ] )
+  , Mont::unsafe_make( [ 0xf1a9ac01 , 0x800c7dcf , 0x856ab956 , 0x28beade0 , 0xa2ddfeb4 , 0xdb43c7b2 , 0xf037c910 , 0x024a2761 ] )
+  , Mont::unsafe_make( [ 0x137c8dde , 0xed712a42 , 0x32455ebd , 0x971fb4d6 , 0x3f7fd923 , 0xb7d46cc3 , 0xbba85b4a , 0x23e96acc ] )
+  , Mont::unsafe_make( [ 0x307f26b8 , 0xe2965295 , 0xb9eb538f , 0xd5720dfc , 0x8db43d4a , 0xaac98ec9 , 0x42020a8e , 0x11eb4a32 ] )
+  , Mont::unsafe_make( [ 0x5eb3df73 , 0xbebea31f , 0x9168090f , 0xd70939c3 , 0x3d256aeb , 0xb21c42d0 , 0x0d365bce , 0x0efb135d ] )
+  , Mont::unsafe_make( [ 0x71d3655f , 0xbc65ded6 , 0x92cb8b68 , 0xad459397 , 0x980ac437 , 0x0d6b6c05 , 0x825fef94 , 0x23b47a38 ] )
+  , Mont::unsafe_make( [ 0x78896d76 , 0x0ceb6318 , 0x420e2453 , 0x21c8b24f , 0x773c5d25 , 0x6fdee15c , 0xe021636a , 0x2be01f6f ] )
+  , Mont::unsafe_make( [ 0x7e408923 , 0xe7429939 , 0x49171247 , 0x1fc013ef , 0xfd36f5a4 , 0xd6581100 , 0x796b0295 , 0x2bdc02e1 ] )
+  , Mont::unsafe_make( [ 0x37ef6ce1 , 0xfc9ded5f , 0x410c10d5 , 0x60d6a59f , 0x149c196f , 0x698b4d93 , 0x305fbdee , 0x03ac4422 ] )
+  , Mont::unsafe_make( [ 0x2bbaf80d , 0x7537ef62 , 0x504371d8 , 0x32ea54c4 , 0xc12f6c14 , 0xffbb5714 , 0x9fbf9f9c , 0x305e4433 ] )
+  , Mont::unsafe_make( [ 0xff0189ec , 0x3174a3d3 , 0x07125efe , 0x8a079e78 , 0x9249b238 , 0xbd423097 , 0x89a1481e , 0x0bc1a555 ] )
+  , Mont::unsafe_make( [ 0x9350d9ea , 0xb325ad6e , 0xdce7a814 , 0xee2e85cc , 0x4cb838dc , 0x0f64750a , 0x8542072c , 0x2d182caa ] )
+  , Mont::unsafe_make( [ 0xe0507bc2 , 0x1dffbe62 , 0x60c06416 , 0xcffcd91a , 0xf3b317d7 , 0xfc521dd1 , 0xe8ce9482 , 0x09121da3 ] )
+  , Mont::unsafe_make( [ 0x9a7f208f , 0xcdebc4d7 , 0x0fbb373d , 0x7dc77401 , 0x965b28e1 , 0xc2e1f860 , 0x5fc664ea , 0x03eea7ff ] )
+  , Mont::unsafe_make( [ 0x7d65c276 , 0x69ccdeb8 , 0xae2df613 , 0xaf7fba6d , 0x8839eee9 , 0x2bf7bf2a , 0xdf2519e3 , 0x00744de9 ] )
+  , Mont::unsafe_make( [ 0xe363b9ae , 0xd720bf9e , 0x3091420d , 0xdee47888 , 0x86e131c1 , 0x702f31c4 , 0x3540073e , 0x02ae4a1d ] )
+  , Mont::unsafe_make( [ 0x23f137ec , 0x8496187b , 0xcf5b7f12 , 0x5256160a , 0xf9846382 , 0x18a955fd , 0x3868cc4d , 0x1acfba8c ] )
+  , Mont::unsafe_make( [ 0xaeddd3a9 , 0x3d3395c1 , 0x7964560c , 0x92fda9a6 , 0x7326d701 , 0xf91a7675 , 0x681d39c2 , 0x2e7b0656 ] )
+  , Mont::unsafe_make( [ 0x11da3ca7 , 0xcf43f8b2 , 0x7bb69aa3 , 0xa1b6ad95 , 0x8a18ef60 , 0x4c5568e6 , 0x1978e7ba , 0x2e00d447 ] )
+  , Mont::unsafe_make( [ 0x2207e705 , 0xb185d9d6 , 0x8f1ee9fb , 0x8fff8926 , 0xecb9dc17 , 0x3f7dccb2 , 0x67f441c8 , 0x02e0441b ] )
+  , Mont::unsafe_make( [ 0xf0ceebcf , 0x2c782817 , 0x61a3e86f , 0xa27b9a4b , 0x59d76502 , 0x05278b03 , 0x1a1b5316 , 0x1abe7efc ] )
+  , Mont::unsafe_make( [ 0xa04bb7ce , 0x11378d1a , 0xecaeb89d , 0x39af2210 , 0xd482a3d3 , 0x0eaa4849 , 0x8246516c , 0x18af633f ] )
+  , Mont::unsafe_make( [ 0xe9b60b8f , 0xd4ac7aa2 , 0xe82aaafb , 0x9aee487c , 0x3dc4b97c , 0xcdcd4c4a , 0xb05da1f5 , 0x2722e551 ] )
+  , Mont::unsafe_make( [ 0xca542ddb , 0xbe042fc2 , 0xe9b6c431 , 0xd5fcdb4f , 0x5532bfb8 , 0x99adc824 , 0x4692fcdc , 0x0c4d7b15 ] )
+  , Mont::unsafe_make( [ 0x3874d5e5 , 0x5bb6f630 , 0xcd769c18 , 0x35c63d87 , 0x7328d559 , 0x7df8eaa9 , 0xd01620ee , 0x174bb1ce ] )
+  , Mont::unsafe_make( [ 0xdcbd12f4 , 0xeed04989 , 0x38002264 , 0x4171855f , 0x035bfa89 , 0x2acd0d65 , 0x7e6f7d9f , 0x25f67f96 ] )
+  , Mont::unsafe_make( [ 0x72fa1cc3 , 0xecae1155 , 0x8ca08aca , 0x8a50df5c , 0x3bbbfddd , 0xff592036 , 0x071839f1 , 0x0697d303 ] )
+  , Mont::unsafe_make( [ 0x4a415125 , 0xb643564d , 0x8407322d , 0x0e9c0325 , 0x23c2bd7e , 0x482b5023 , 0x33f645f9 , 0x2ac0ecd9 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x74e4a8b4 , 0xfb6444c5 , 0x662b9df7 , 0x631c9890 , 0x114e11be , 0x6b90cd7d , 0xf3405a0e , 0x2b9ef6d4 ] )
+  , Mont::unsafe_make( [ 0x1648a343 , 0xcb06cc3b , 0x05b75001 , 0xeba0e779 , 0x3531319a , 0x7e3c6043 , 0x68e13110 , 0x2533143d ] )
+  , Mont::unsafe_make( [ 0x39e27660 , 0xfb299b30 , 0x5c1f477e , 0xc2d07346 , 0x1a547934 , 0xd095756d , 0xb485dadb , 0x0cdc07c6 ] )
+  , Mont::unsafe_make( [ 0x90923b0d , 0x5981e4b4 , 0x4853a218 , 0xf54d3226 , 0x54dcd50a , 0xb53bfd6b , 0x6cd05fcf , 0x118d65d4 ] )
+  , Mont::unsafe_make( [ 0x09de94ff , 0x29c56674 , 0x4a6b5396 , 0x7a6ff080 , 0x45d887f2 , 0x80c8a865 , 0xc58b90fd , 0x0c2002d8 ] )
+  , Mont::unsafe_make( [ 0x20ff278b , 0x48370b05 , 0xa6a7e45d , 0xef8466d5 , 0x4db39f7d , 0xc8ff20c2 , 0x165f89fc , 0x0031bfe1 ] )
+  , Mont::unsafe_make( [ 0xdf0d5b90 , 0xad5fc309 , 0x9969e119 , 0x13c4172e , 0xb8abee60 , 0x1243072c , 0x2c9896e0 , 0x2092360b ] )
+  , Mont::unsafe_make( [ 0x7d6099a4 , 0xfa1d3382 , 0x6e7f3b87 , 0x4db22c77 , 0xa91370c3 , 0x103b8c27 , 0xcbdf85ef , 0x1ba36fd5 ] )
+  , Mont::unsafe_make( [ 0xf9bdb629 , 0x91e862f8 , 0x8004a833 , 0xc71327c2 , 0x048ebb0a , 0x48e935a5 , 0x9be8b601 , 0x14c1c74b ] )
+  , Mont::unsafe_make( [ 0x3270cd42 , 0x45c826c6 , 0xe2b2eb22 , 0x7b48f617 , 0x8ab13aec , 0xf56cb88b , 0x9296d7cc , 0x02f32d33 ] )
+  , Mont::unsafe_make( [ 0x3d27bcfe , 0x6b87105d , 0x79d5f23a , 0xe8218110 , 0x92bf2de4 , 0xb999926d , 0xf7303258 , 0x26641f68 ] )
+  , Mont::unsafe_make( [ 0x315d8743 , 0xa0c5f5dd , 0x40073918 , 0x29a67db3 , 0xfaec0e29 , 0xd0d04375 , 0x3108908d , 0x065be5bb ] )
+  , Mont::unsafe_make( [ 0x2100e552 , 0x24bde13c , 0x1a35acf5 , 0x03bc8110 , 0xd74fd605 , 0x12b0f3c1 , 0x788e514b , 0x0ac1c7ec ] )
+  , Mont::unsafe_make( [ 0xc44b7210 , 0x19373ee8 , 0xa8a73a78 , 0xbc9cb36e , 0xe21ffcde , 0x7bf1a8ed , 0x70a97201 , 0x2c4fbb07 ] )
+  , Mont::unsafe_make( [ 0x1eb21f82 , 0xe439c574 , 0x3633266c , 0x4cbf3e7d , 0x2949e78e , 0xd4c66b8b , 0x3c08bd61 , 0x05ce00e9 ] )
+  , Mont::unsafe_make( [ 0xfddea5d3 , 0x10f4e114 , 0x141894b2 , 0x200e744e , 0x11026a11 , 0xf701b3d4 , 0x7a4f8676 , 0x2641ad34 ] )
+  , Mont::unsafe_make( [ 0x1175b824 , 0xb0703ca9 , 0x0e265b0d , 0x446a43a4 , 0xe41da37e , 0x9e42b202 , 0xefaf5d29 , 0x064ad4a7 ] )
+  , Mont::unsafe_make( [ 0x5ee8568f , 0x91da8f59 , 0xab92bea1 , 0x0185ddbd , 0xefca64df , 0xfbd6b20f , 0x86b555a3 , 0x1a8737c0 ] )
+  , Mont::unsafe_make( [ 0x0ed21e45 , 0x0a1b0ccb , 0xeaebd1c3 , 0xf38e2631 , 0xac90a102 , 0x50820d5b , 0xd5bf4382 , 0x0ff99225 ] )
+  , Mont::unsafe_make( [ 0x4c9a8daf , 0x1982f375 , 0x3e3fc1f4 , 0xfa8279ae , 0xac349683 , 0x9d07ac7e , 0xc42e7a4a , 0x2d069dbc ] )
+  , Mont::unsafe_make( [ 0x74ba699e , 0x1830f8dd , 0x261e4ba2 , 0x6d348dab , 0xc5bbb323 , 0x0c9d45d9 , 0x692222e8 , 0x243bacce ] )
+  , Mont::unsafe_make( [ 0x0e01dc8a , 0x8f504cad , 0xa25134d1 , 0xd23469a6 , 0x9afc4f5c , 0x1ec4ea45 , 0xbc7b7b21 , 0x134d9321 ] )
+  , Mont::unsafe_make( [ 0x679288bf , 0x4c1163f9 , 0x432f5711 , 0x09bb2bb3 , 0xf1e6f8b6 , 0x871a7c72 , 0x4cd470d4 , 0x18148c47 ] )
+  , Mont::unsafe_make( [ 0xc64dd410 , 0x1489a7c8 , 0x4ef1a29c , 0x9103034a , 0x69b32a33 , 0x6a445d62 , 0x176e40a7 , 0x0334e997 ] )
+  , Mont::unsafe_make( [ 0xc7130fdc , 0xac83d2d1 , 0xac8d4c8d , 0x12182da3 , 0x6b26ada6 , 0x9c1b53ad , 0x36dfbac9 , 0x211a0caf ] )
+  , Mont::unsafe_make( [ 0x22003d3e , 0xbc7b611b , 0xb7e4ee31 , 0x73b3a5fa , 0x0bef3df6 , 0x44f8f9a4 , 0xc5a55745 , 0x2b442b21 ] )
+  , Mont::unsafe_make( [ 0x3f178907 , 0x01b92365 , 0x4b28340c , 0x5c148f7c , 0xfa0326bb , 0xbbddb890 , 0xab4f2c94 , 0x2de63391 ] )
+  , Mont::unsafe_make( [ 0x273f150a , 0x41932b74 , 0x3c8e8058 , 0x08408565 , 0xec97aa47 , 0x868d5ef2 , 0xa8f3426f , 0x1dc123c3 ] )
+  , Mont::unsafe_make( [ 0xa4491d69 , 0x4961b5c1 , 0x00c6ca7a , 0x92559e8d , 0x757e51f1 , 0xa25b5b7f , 0x108f87cb , 0x1cde67d0 ] )
+  , Mont::unsafe_make( [ 0x9f616365 , 0x2b25619d , 0x4368c1fa , 0x05d8ec5e , 0xc5838921 , 0x9614ffff , 0xfe0fe3e8 , 0x0b7514e5 ] )
+  , Mont::unsafe_make( [ 0xe2ab3e87 , 0xcb03ed1f , 0x76180854 , 0xdeb90c93 , 0x42bf2e05 , 0x34a14096 , 0x729a9850 , 0x1d987454 ] )
+  , Mont::unsafe_make( [ 0x13084612 , 0x498a0973 , 0x2cd263a9 , 0x9d2c6131 , 0x996b77f6 , 0x0edbe9af , 0x74ee5937 , 0x043932be ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x7a1920f4 , 0x34e60036 , 0xa47f5774 , 0x98047665 , 0x7e1db4f0 , 0x95ae156e , 0x735873d3 , 0x06c8f6c8 ] )
+  , Mont::unsafe_make( [ 0xc10b29c3 , 0xbcdc14ab , 0x383fdaea , 0x33255305 , 0xf5bf41e4 , 0x88a149c4 , 0xd1a24680 , 0x132a9502 ] )
+  , Mont::unsafe_make( [ 0x40e9e3c5 , 0x57eeb19b , 0xce8d91ae , 0xaa63bfd6 , 0x976794ad , 0xbe5a3d08 , 0x0ccaf712 , 0x0851f897 ] )
+  , Mont::unsafe_make( [ 0x8d96ffeb , 0x9779651d , 0xebb53f4e , 0xa08df653 , 0xd588e19e , 0xa375b230 , 0x627cfe0c , 0x2a1912ba ] )
+  , Mont::unsafe_make( [ 0xa9a00b9e , 0x562cae24 , 0x7e2e6914 , 0xa1aaff07 , 0xa1d28db5 , 0x8a488b26 , 0xa3db9e32 , 0x25e5abf9 ] )
+  , Mont::unsafe_make( [ 0x9aa74337 , 0x4d59567a , 0x8edaa096 , 0x0c21dbf8 , 0xd395403f , 0x3eed5133 , 0x700bd5db , 0x1c3790f0 ] )
+  , Mont::unsafe_make( [ 0x8a4e76fe , 0x34867bf8 , 0xc8b69d48 , 0x16ee9f14 , 0x68711353 , 0xe0842660 , 0x5c502418 , 0x1c660949 ] )
+  , Mont::unsafe_make( [ 0x8aba9bd9 , 0x2a630e51 , 0x767741ad , 0x54c3cfa5 , 0x34b8670e , 0x1eabbe38 , 0x0096ea1a , 0x157af6ac ] )
+  , Mont::unsafe_make( [ 0xdd72c200 , 0xfe94a6d5 , 0xde788bd8 , 0xaef97ebf , 0x6a07f11e , 0x20cdc1cc , 0x8be8f088 , 0x0066c000 ] )
+  , Mont::unsafe_make( [ 0x75931f95 , 0xd1060b7f , 0xd9e1a3e9 , 0xdedbb197 , 0x171ea1b4 , 0x9dfafe7b , 0xe552dabf , 0x1f82ea41 ] )
+  , Mont::unsafe_make( [ 0x68ad21f7 , 0x2f06c347 , 0x71cb16d9 , 0x14b4d47d , 0x8f317549 , 0x6bab52c8 , 0xdaffdd13 , 0x244fa332 ] )
+  , Mont::unsafe_make( [ 0xba750d14 , 0x68917fb8 , 0xc3429fc2 , 0x2257fed5 , 0x91025741 , 0x89b8d10d , 0x03de8f9f , 0x0e74edc9 ] )
+  , Mont::unsafe_make( [ 0x95c3505d , 0xde05db1c , 0xf83968b8 , 0x824e9252 , 0xf807eb9c , 0x30605a53 , 0x82c90105 , 0x0b21c80a ] )
+  , Mont::unsafe_make( [ 0x331c582b , 0x356198c4 , 0x89f5b165 , 0x9277ef67 , 0xe57b9356 , 0x3a1f68fc , 0xba4ee8d0 , 0x1b761616 ] )
+  , Mont::unsafe_make( [ 0x754e52da , 0x704d6b3c , 0xab0844a0 , 0xfe65abe2 , 0x70a4f781 , 0xc1bafe57 , 0xc8ebca84 , 0x2d575039 ] )
+  , Mont::unsafe_make( [ 0xa8893aea , 0xf80f95f1 , 0x492225e8 , 0xf9e5bd13 , 0xb856304d , 0x0b55760d , 0x2129e941 , 0x12bf0653 ] )
+  , Mont::unsafe_make( [ 0xe98cbb6f , 0xafbf9b67 , 0x8e95c9a6 , 0x95be971b , 0x37c1aba4 , 0x7bda172f , 0x6b751351 , 0x2e9e99d4 ] )
+  , Mont::unsafe_make( [ 0xdd8989d3 , 0xb48eebc3 , 0xd6ebc72b , 0xd25ab232 , 0xadd7a9e9 , 0x20148262 , 0xd4a0c050 , 0x2c9e90c8 ] )
+  , Mont::unsafe_make( [ 0xc10d12d7 , 0x0578557b , 0xd26a71dd , 0x33330d70 , 0xfaaefe36 , 0x6a479cd6 , 0xff6da47d , 0x10fe3325 ] )
+  , Mont::unsafe_make( [ 0xd517efd5 , 0xc43134a5 , 0x22b0c3d1 , 0x3ed87014 , 0x4570b04c , 0x7d653cd1 , 0x04d5974a , 0x0d400d61 ] )
+  , Mont::unsafe_make( [ 0xbf21ca07 , 0xc9073780 , 0xa4c6b818 , 0xab61078f , 0x619fc218 , 0xfd3185f3 , 0x3e8224f2 , 0x25f482ed ] )
+  , Mont::unsafe_make( [ 0x6efb4d9d , 0x7cee30c0 , 0x9b4222fe , 0x8a98bafa , 0xf1a33dd1 , 0x9db5c029 , 0x6c506b0d , 0x149b05b1 ] )
+  , Mont::unsafe_make( [ 0x7147e2dc , 0x1e26cca8 , 0x007041b8 , 0x82068a39 , 0x012632d0 , 0xaca3acee , 0x96984ab9 , 0x040f4ffe ] )
+  , Mont::unsafe_make( [ 0x27cbb3df , 0x9531397c , 0x2d4e9c65 , 0x3a62e34e , 0xc12dc609 , 0xe49cf8b8 , 0x8be1a54f , 0x03a09a05 ] )
+  , Mont::unsafe_make( [ 0x90ef9331 , 0x8c003e0c , 0x83b9389f , 0xb879607e , 0x6194b9b2 , 0x5b334cca , 0x96f679a0 , 0x0a1940ce ] )
+  , Mont::unsafe_make( [ 0x7f94776b , 0x91016f6f , 0x04c2e63a , 0x9a05d014 , 0x815faedb , 0x49249c51 , 0x374011a6 , 0x17912084 ] )
+  , Mont::unsafe_make( [ 0x8bef88a6 , 0x7661fa16 , 0xa48e362c , 0xd1527c08 , 0xedfdecaa , 0x21be4f54 , 0x424b4bc4 , 0x25007bf3 ] )
+  , Mont::unsafe_make( [ 0x9ce21aea , 0x95ae41a3 , 0xb530e087 , 0xb0ca0596 , 0xeb86e043 , 0x3b07fde6 , 0xa9433694 , 0x22b2793d ] )
+  , Mont::unsafe_make( [ 0xc226ece2 , 0x90707fe8 , 0x319982ed , 0xf79f2d4a , 0x609a9441 , 0x8c54c730 , 0x62297c61 , 0x288b6824 ] )
+  , Mont::unsafe_make( [ 0x6b77dc8f , 0x1e71458d , 0x0c6f1bab , 0x0600d8aa , 0x979c00cc , 0x7104b5a6 , 0x5a448e25 , 0x0e1a9af9 ] )
+  , Mont::unsafe_make( [ 0x421946e8 , 0xd9aecc6e , 0x3e6cbc03 , 0x943d0f5c , 0x24514510 , 0x4881eae9 , 0x6d431af3 , 0x1d6aab64 ] )
+  , Mont::unsafe_make( [ 0xf01d80e4 , 0xbd4f1af6 , 0x9f1ff61f , 0xd85e6bf1 , 0x5ceaad14 , 0x9bf4874e , 0x2ac1b737 , 0x23611952 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xb9a5a1f0 , 0x6db4de11 , 0xcdfa37bc , 0xb2adc213 , 0x7b760420 , 0x1a33d61b , 0x50d5e36c , 0x205c9eb5 ] )
+  , Mont::unsafe_make( [ 0xf6c7a276 , 0xa065240e , 0x688fb267 , 0xe6a9fa94 , 0x50778609 , 0x3a528227 , 0xd6f1a7be , 0x1b458706 ] )
+  , Mont::unsafe_make( [ 0xc94336ce , 0xee6b7058 , 0x6939bbe1 , 0x5e808de4 , 0x89da5dc5 , 0xdaa265fd , 0x21c4d26b , 0x067105e3 ] )
+  , Mont::unsafe_make( [ 0x835cdabf , 0xd4716ad5 , 0xb1401491 , 0xd93a1ca3 , 0x39ce0ab3 , 0x37f54e1e , 0x0f022871 , 0x2400a233 ] )
+  , Mont::unsafe_make( [ 0xb1b8154e , 0x41772a6a , 0x49205609 , 0x672582fb , 0x5a515117 , 0x990d6e09 , 0x9260eb0a , 0x0fa095ee ] )
+  , Mont::unsafe_make( [ 0x06617b9c , 0x1848c056 , 0x845bff45 , 0x66cb7b2d , 0x186719e2 , 0x98f95d04 , 0x6c22e0f4 , 0x100bf4c7 ] )
+  , Mont::unsafe_make( [ 0x789016b0 , 0xbb7282af , 0xcca02526 , 0x2e17af09 , 0xf969b118 , 0x4190d157 , 0x904e5795 , 0x1fb7bcf5 ] )
+  , Mont::unsafe_make( [ 0x73010792 , 0xf6085abb , 0x0582d32b , 0x28506750 , 0xf09c18fb , 0xf2a59970 , 0x45955ba3 , 0x229d5d45 ] )
+  , Mont::unsafe_make( [ 0xf4ef5f84 , 0xf84ab9a7 , 0x794def26 , 0xc90d1b52 , 0x7fbc58e0 , 0xdf483695 , 0x3ea7fcfd , 0x0a8d7676 ] )
+  , Mont::unsafe_make( [ 0x3dea8df9 , 0x5471a267 , 0x5b38168b , 0x619a7b59 , 0x830a0f6b , 0xb43adfd3 , 0x3de0dcbd , 0x208729d7 ] )
+  , Mont::unsafe_make( [ 0x55f535ba , 0x9dafe905 , 0x22b71419 , 0x8a774ac1 , 0x474b1187 , 0xbdeaf3a1 , 0x32772120 , 0x2432faba ] )
+  , Mont::unsafe_make( [ 0xecf5b682 , 0x64f494b3 , 0x86fc5174 , 0x87099478 , 0x5d6752d1 , 0x3d7fae7c , 0x5a8e04ed , 0x0cde16db ] )
+  , Mont::unsafe_make( [ 0xeb47d0c6 , 0x3531ec1f , 0x12a213ee , 0x10457faa , 0xbe70bc21 , 0x98863df5 , 0x4385ba17 , 0x17f9265a ] )
+  , Mont::unsafe_make( [ 0xf2c9a5e7 , 0xba6b6f2e , 0x2dd2eca3 , 0xb4a2b5d5 , 0xba0f5754 , 0xdf3943f6 , 0x3b44e7c5 , 0x0a8c7157 ] )
+  , Mont::unsafe_make( [ 0xedccad1e , 0x0329a112 , 0x22a656a0 , 0x660023ea , 0x888ffd16 , 0xc71633c2 , 0x4d436379 , 0x2f322d1d ] )
+  , Mont::unsafe_make( [ 0xf24c5858 , 0x3d244063 , 0xa782e860 , 0x2a627ba6 , 0x79bd95f8 , 0xf99c6b9b , 0x7d75fa94 , 0x2f86c876 ] )
+  , Mont::unsafe_make( [ 0xc4760acc , 0x0768f8b3 , 0x4774a678 , 0x9d2808f1 , 0x09bfc1b6 , 0x78e11f59 , 0x0c3c1540 , 0x0acf5b3d ] )
+  , Mont::unsafe_make( [ 0x7dc5bc70 , 0xc4b7ccb0 , 0x6f3960c1 , 0xcc2a8c7a , 0xfec69bb2 , 0xc579ff47 , 0x54738956 , 0x1576d080 ] )
+  , Mont::unsafe_make( [ 0xd0b8dd2c , 0xd695f41f , 0xb08dfe66 , 0xd9ee794f , 0x7c51ce33 , 0xbcf27c39 , 0x3cfaa4d5 , 0x03d0f37b ] )
+  , Mont::unsafe_make( [ 0x6c0a5049 , 0x11cc214f , 0x9197d5b2 , 0xf161f38b , 0x71528601 , 0xb2a26e7d , 0xc89dbe81 , 0x1a78cf40 ] )
+  , Mont::unsafe_make( [ 0x995326e2 , 0x9a460851 , 0x8ed80397 , 0x5edffa00 , 0x718d2601 , 0xe39390d6 , 0x994d62dd , 0x24034fe3 ] )
+  , Mont::unsafe_make( [ 0xdcc63de7 , 0x006e3230 , 0x4b93e9c3 , 0xc48e77fe , 0x735ae560 , 0xd448159b , 0xf1d14381 , 0x1209f14a ] )
+  , Mont::unsafe_make( [ 0x2904fa26 , 0x20849518 , 0x75878b73 , 0x992707a9 , 0x1c9235f8 , 0xfe197799 , 0xf873fac7 , 0x2a84d2c6 ] )
+  , Mont::unsafe_make( [ 0x2f7048fb , 0x4372bd14 , 0x43423cef , 0x54a6b7ce , 0xe7057096 , 0x1c0bfd9b , 0xa454abef , 0x2b64458a ] )
+  , Mont::unsafe_make( [ 0x0e9a2d46 , 0x4762f9f6 , 0x6dcda691 , 0xc6b1cc50 , 0x60522287 , 0x7f235504 , 0x8df3b030 , 0x1722da75 ] )
+  , Mont::unsafe_make( [ 0x8b17958a , 0xaf7778a6 , 0xbfdc9bec , 0x18dbbe51 , 0x211df264 , 0x3b69368d , 0xfde09c82 , 0x289a1970 ] )
+  , Mont::unsafe_make( [ 0x2e9e6d2b , 0x4b29a74a , 0x012267c3 , 0x9a4744b9 , 0x24d1a832 , 0x9adee3a5 , 0x103e385b , 0x25725e6c ] )
+  , Mont::unsafe_make( [ 0xee9588fe , 0x6e6b17d1 , 0xec110e21 , 0xc86b9b70 , 0xf156d9e4 , 0x9d9c5bf7 , 0x94ab419d , 0x08fd535f ] )
+  , Mont::unsafe_make( [ 0xc99b1b25 , 0x31c6bb63 , 0xf240295e , 0x1b26c04d , 0x55adf03c , 0xaf7cd80b , 0xb5117f87 , 0x00492966 ] )
+  , Mont::unsafe_make( [ 0x24878d94 , 0x4e5f349d , 0x5edee6f9 , 0xd3c6bc0c , 0x2271e711 , 0xbb51bfa7 , 0xf37f24d9 , 0x2571166a ] )
+  , Mont::unsafe_make( [ 0xb209d2ff , 0xb9962c61 , 0xe6fb182e , 0xc8e67a93 , 0xb4a6737f , 0x82842c11 , 0xb687521f , 0x21caf80b ] )
+  , Mont::unsafe_make( [ 0x2ef3416c , 0x12fbfe88 , 0x900c6af5 , 0x2663d6b6 , 0x47ccfc1c , 0x29de7748 , 0x7b8bb1d9 , 0x25c085f0 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x67ed7f8d , 0x8cc5586e , 0x3b7fe392 , 0xb3f939aa , 0xfe640430 , 0x7de0ccbb , 0x3d22b435 , 0x0ed3bf6d ] )
+  , Mont::unsafe_make( [ 0x90916e39 , 0x9d3b88c9 , 0xf2d8bce1 , 0xca9fe239 , 0xb426776d , 0xcb7d1dea , 0x4c314b96 , 0x27cba512 ] )
+  , Mont::unsafe_make( [ 0x7f1feda4 , 0xeff510ae , 0x7e421bc5 , 0x0594b168 , 0x5b93f083 , 0x85e316a0 , 0x2e38d3be , 0x0e18dc63 ] )
+  , Mont::unsafe_make( [ 0x552fe3bd , 0x41f240b7 , 0x330e704a , 0xdcec068b , 0x819e03c3 , 0xb1adc141 , 0xa1285574 , 0x048706e0 ] )
+  , Mont::unsafe_make( [ 0x5f049a17 , 0x50122108 , 0xbe302cc3 , 0x55e055df , 0xfe7a9577 , 0x80bf975d , 0x2efe9760 , 0x24e5d2cc ] )
+  , Mont::unsafe_make( [ 0x48ccaf22 , 0x382cf0fd , 0xe4a69690 , 0x1e1a15aa , 0xa3b1b757 , 0xd4f7d3c0 , 0xdbe5cc1c , 0x2b036e5e ] )
+  , Mont::unsafe_make( [ 0x4b3aa031 , 0x0aa267ad , 0x03750203 , 0x31c5aeb0 , 0x966a675a , 0x4b4987b6 , 0xa8ba675d , 0x053a60a4 ] )
+  , Mont::unsafe_make( [ 0x8d3d5933 , 0x2fe92b35 , 0xa86b1616 , 0xd063aca9 , 0x2eeaf1c7 , 0xbef25ab3 , 0xed2e9556 , 0x0c4de0ff ] )
+  , Mont::unsafe_make( [ 0xf7958b38 , 0x25ea345b , 0xdac57050 , 0x74df7ade , 0xb43a5885 , 0x8d0ef457 , 0xa342316e , 0x2cb1d0fe ] )
+  , Mont::unsafe_make( [ 0x1829863b , 0x96d211fc , 0x82b327bc , 0x22717e46 , 0xee62e1c9 , 0xfa3489df , 0x18375a88 , 0x22a97d05 ] )
+  , Mont::unsafe_make( [ 0x2dca5db8 , 0x0810850e , 0x03efd430 , 0x4af9467c , 0x005aa61b , 0x58e9f9dc , 0x66774083 , 0x0e2e879d ] )
+  , Mont::unsafe_make( [ 0x778bc3c7 , 0xee97f146 , 0xd907f21f , 0x8100ecbc , 0xb0659932 , 0x2afc79cb , 0x10b06b43 , 0x2f6b1ef0 ] )
+  , Mont::unsafe_make( [ 0xe30a51f0 , 0x3e01d937 , 0x8951bd3d , 0xf6a11e24 , 0x792af002 , 0x00a8bca3 , 0x8b9996bf , 0x2503360b ] )
+  , Mont::unsafe_make( [ 0x480751c6 , 0xfc25f933 , 0x64db6c92 , 0x4508bfcd , 0xc51a7e91 , 0x1e9827b9 , 0x72fc1bde , 0x272cae5e ] )
+  , Mont::unsafe_make( [ 0xe7eafc47 , 0x518271db , 0x057fe8fd , 0x2608d6b6 , 0xcb152e14 , 0x9a2b342c , 0x5107e3c2 , 0x0c8016db ] )
+  , Mont::unsafe_make( [ 0x86e76d92 , 0xf54b5735 , 0x72060432 , 0x1f7b77a8 , 0x0a9b5036 , 0x1a00e44d , 0x50310870 , 0x2cc849fe ] )
+  , Mont::unsafe_make( [ 0x405c5f33 , 0x8b37d3b4 , 0x61fd1eb9 , 0xd08e4ab3 , 0x1eb1adbf , 0x93009949 , 0xb3c8fcc7 , 0x299acd02 ] )
+  , Mont::unsafe_make( [ 0x20869de2 , 0x2f8742a8 , 0xd286163f , 0x754c6d4f , 0x10301d75 , 0x4054454c , 0x19d94f44 , 0x26e0dad1 ] )
+  , Mont::unsafe_make( [ 0x4c77e58b , 0x8a414af8 , 0x34874e46 , 0xc74bed62 , 0x7ec3eab8 , 0x5788b5bf , 0x2dfab948 , 0x1d5b10a7 ] )
+  , Mont::unsafe_make( [ 0xc0b0f578 , 0x4571140f , 0x94bad414 , 0x011ad4ee , 0xab85ffc2 , 0xdadcf8c5 , 0x9a79b238 , 0x0afb8232 ] )
+  , Mont::unsafe_make( [ 0x8741573d , 0x43d63645 , 0x69249086 , 0x440b02b3 , 0x5082d745 , 0x9eec8e0b , 0x7a02bd42 , 0x1810b9e3 ] )
+  , Mont::unsafe_make( [ 0x0a6cdc40 , 0x6694d4d1 , 0x92799d63 , 0xe5cae204 , 0x451550d4 , 0x6a3036ec , 0x8452ea54 , 0x0a66a8e1 ] )
+  , Mont::unsafe_make( [ 0xbd8b201a , 0x05e0e68c , 0xc0b76210 , 0x7ca028c1 , 0xaf70c6eb , 0xb0253b9a , 0xe0c6c736 , 0x073a79ef ] )
+  , Mont::unsafe_make( [ 0xd828cdb7 , 0xcb76316a , 0xd2397767 , 0xa8ca71c3 , 0x0c971581 , 0xbf7fd916 , 0x5eddbfff , 0x1300f6bf ] )
+  , Mont::unsafe_make( [ 0xeb84243d , 0xa9faa746 , 0x0df45056 , 0xcda64115 , 0xed6388b5 , 0x4421f03c , 0x4f04c113 , 0x04621df3 ] )
+  , Mont::unsafe_make( [ 0xc5d67e3c , 0xe42805e8 , 0x595e163e , 0xec85f025 , 0xcccc42dd , 0x24ed7f61 , 0x72385756 , 0x1229a41c ] )
+  , Mont::unsafe_make( [ 0x83c1dfdb , 0x1a10470b , 0x9c3ddad8 , 0x3bd205dd , 0x48e84a7f , 0x6c7100f0 , 0x7c9aff43 , 0x0d3c703f ] )
+  , Mont::unsafe_make( [ 0x45b75445 , 0x33687eaa , 0x427a0a22 , 0x8f0da450 , 0xf13b1a57 , 0x02853d9a , 0xab30c216 , 0x090c5359 ] )
+  , Mont::unsafe_make( [ 0x47526c3a , 0x1f254c2d , 0xb98bc25a , 0x8f2c89ba , 0x5d67214d , 0x52f2d801 , 0xd989034d , 0x0daa6a29 ] )
+  , Mont::unsafe_make( [ 0x8f66f7af , 0xb7c13f2c , 0x0beb66fe , 0x54892cdd , 0xb26cf63d , 0x941aec94 , 0x44d089a8 , 0x0bcb97d6 ] )
+  , Mont::unsafe_make( [ 0x7b0aadfe , 0xa6163b21 , 0xbd934227 , 0x261fecba , 0x9982f909 , 0x24e762c4 , 0x340f5b4c , 0x20a8054b ] )
+  , Mont::unsafe_make( [ 0x999bc174 , 0xd062a742 , 0xff7c50fe , 0xeace3abc , 0xa7648230 , 0x0c8a4638 , 0x4a937410 , 0x07b5d0f1 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x02e09720 , 0x9d8b78ca , 0x7c47e6cc , 0x9fa5556e , 0x9a5e011e , 0xcdf04092 , 0xd4fe4d89 , 0x0e77e9be ] )
+  , Mont::unsafe_make( [ 0xc5a3f89f , 0xc91df73a , 0x0014df7e , 0x31676de0 , 0xff44541e , 0x41d7653c , 0xaea33dd6 , 0x0e4fe959 ] )
+  , Mont::unsafe_make( [ 0xc5b3e9a6 , 0xf05eb4d7 , 0x80bfc730 , 0xd855ea37 , 0x440aad5e , 0xe195be86 , 0xca9ff139 , 0x0fb44a54 ] )
+  , Mont::unsafe_make( [ 0x3338d2e4 , 0xdba68ad7 , 0xefe657ad , 0xbcddc46c , 0x5cc6bc1f , 0x0b0ad2e0 , 0xf95cb4c7 , 0x1c2c17a8 ] )
+  , Mont::unsafe_make( [ 0xaa33ab84 , 0x84b1916b , 0x0c95e8ab , 0xf5a57ff9 , 0xafe67d6d , 0xf8dd662f , 0x595120a5 , 0x17aa4d32 ] )
+  , Mont::unsafe_make( [ 0x8ced9bbc , 0x288ce413 , 0x919207b8 , 0xece33e57 , 0xfe3eb913 , 0xa06b685c , 0x17809864 , 0x2809d585 ] )
+  , Mont::unsafe_make( [ 0x9ea6aabd , 0x822de4bd , 0x896244ef , 0x5f2a4089 , 0xc74a6fd9 , 0x07288c59 , 0x696ded34 , 0x0a8c1fdd ] )
+  , Mont::unsafe_make( [ 0x89c85fdd , 0x79790e47 , 0xd31db8f4 , 0x3a047059 , 0xc2564b58 , 0x370a189c , 0x29dd251e , 0x2425a67a ] )
+  , Mont::unsafe_make( [ 0x3b33535a , 0xab6d6ddc , 0xdfff5598 , 0x6e5e3647 , 0x139d99b7 , 0xedbdc13f , 0x23b6ec11 , 0x1caebcf4 ] )
+  , Mont::unsafe_make( [ 0x0b89cd21 , 0xcb0ae272 , 0xbc74e2a3 , 0xdf1a3b3e , 0x58b47ccd , 0xfd1304ec , 0x29a3f915 , 0x0056b526 ] )
+  , Mont::unsafe_make( [ 0x3bef5b60 , 0xba8d82a7 , 0x00b1d058 , 0x67eb1876 , 0x7e4b7cc3 , 0x5470b6b1 , 0x3c48a8d8 , 0x1ad7963b ] )
+  , Mont::unsafe_make( [ 0xb8cbfc53 , 0x9df3e56d , 0xf79c4487 , 0x5cba086b , 0x921cd02a , 0xd1bc525a , 0x10972cfa , 0x0a8f8456 ] )
+  , Mont::unsafe_make( [ 0xabde28a5 , 0x244e329d , 0xbe02e0a8 , 0x1af5cab5 , 0x7781adcd , 0xc2cb95da , 0x140e1db8 , 0x19a2d90c ] )
+  , Mont::unsafe_make( [ 0xa277a3e8 , 0x737a7217 , 0x8b24aa4f , 0xc66b10ec , 0xf6ddb00c , 0xe5eb73f0 , 0xba8d226a , 0x0702d7af ] )
+  , Mont::unsafe_make( [ 0x5d0d0d7c , 0x988de2ec , 0x551880e2 , 0x6d2f66d9 , 0x53c81ba7 , 0x5711afd3 , 0xf41d8c16 , 0x0f7ea359 ] )
+  , Mont::unsafe_make( [ 0xafcd237d , 0x36785c6b , 0xd585010d , 0x8a802e9e , 0x22634f6d , 0x2d375d24 , 0x3e69a87d , 0x006c77d5 ] )
+  , Mont::unsafe_make( [ 0x95822261 , 0xaf0c9405 , 0x63f2ae83 , 0x0ecedb93 , 0xaf8aa9ab , 0x5315ec6b , 0xfa7fc271 , 0x2d526d48 ] )
+  , Mont::unsafe_make( [ 0x70ef824d , 0xe9c00603 , 0xf089b730 , 0x3755fd8e , 0x921b02c7 , 0x87b14234 , 0x6051500b , 0x2770cd92 ] )
+  , Mont::unsafe_make( [ 0x66091564 , 0xad93f4fc , 0x391e8d94 , 0xc1c9d6b9 , 0x9ec85fc5 , 0x07a69abb , 0x0e39d795 , 0x2619499c ] )
+  , Mont::unsafe_make( [ 0x650a4798 , 0x94a7a93e , 0x42bba653 , 0x4bbc0d6b , 0xdd9f02a3 , 0x8c7b481a , 0xbddd2c7e , 0x081b0588 ] )
+  , Mont::unsafe_make( [ 0x2cafee97 , 0x651b67f0 , 0xf84677de , 0xd603e4ed , 0x02a61e02 , 0xb4c42585 , 0xc70685bd , 0x2dcf51ac ] )
+  , Mont::unsafe_make( [ 0xea769c5f , 0xd9ca2372 , 0x8b5530d4 , 0xecc95279 , 0x03161a60 , 0x33a26ca2 , 0xb1133c8e , 0x01d1135f ] )
+  , Mont::unsafe_make( [ 0x108e71f0 , 0x469b6381 , 0x1cf46781 , 0xcc6ee06e , 0xf4016284 , 0x14c35f57 , 0xb7ad0192 , 0x2c177f9b ] )
+  , Mont::unsafe_make( [ 0x6a1c1e16 , 0x0fa56bc4 , 0x9209dc08 , 0xe1799b6b , 0xd58b431c , 0x0b68a154 , 0xea9b9741 , 0x055fea78 ] )
+  , Mont::unsafe_make( [ 0x9d186ab8 , 0x0436859e , 0xfb2473fd , 0x0470efd6 , 0x166efa74 , 0x3406447e , 0x7892dadc , 0x0f1ce307 ] )
+  , Mont::unsafe_make( [ 0xb4a498ec , 0x5a21de42 , 0x904c49ac , 0xe7202c8b , 0x3d3406d9 , 0x8be1b467 , 0xa47ef4fb , 0x2f805f2e ] )
+  , Mont::unsafe_make( [ 0x0d6f516c , 0x69728eed , 0xff1e7f24 , 0x2ee7d34f , 0x80040799 , 0x94298a67 , 0x373cb106 , 0x14091901 ] )
+  , Mont::unsafe_make( [ 0xab54bd67 , 0x7f1e9092 , 0xd24628ee , 0x3d5660ed , 0x6f6e4f0e , 0xe05df810 , 0xc4daf850 , 0x2f617e91 ] )
+  , Mont::unsafe_make( [ 0x013697fa , 0x5fcf276e , 0x04fd52f2 , 0x14059655 , 0x7f122587 , 0xf532a6c8 , 0x5ef4bfcb , 0x2f63f958 ] )
+  , Mont::unsafe_make( [ 0x694a9694 , 0x6673406e , 0x24b3fe4c , 0x461f4a2f , 0x5619d650 , 0xb453d6e2 , 0xe43adab7 , 0x1fe4cec6 ] )
+  , Mont::unsafe_make( [ 0x00a88125 , 0xdf6c185f , 0x021c8a83 , 0xab0cb25b , 0x622be3a3 , 0xaf7d3b25 , 0xccb6f5c6 , 0x1bd02824 ] )
+  , Mont::unsafe_make( [ 0x5ef59186 , 0x5807ee44 , 0x560cf25d , 0x441e923a , 0x7595a611 , 0x9bef61ac , 0xf78bba09 , 0x15eef43a ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x0ddbc448 , 0xeca1b6fc , 0xac9f67a4 , 0x204a60b0 , 0xc657e2d3 , 0x10f18cd3 , 0xfcdcee8d , 0x2e4da7a6 ] )
+  , Mont::unsafe_make( [ 0xcdf49405 , 0x968c73df , 0xb7515f19 , 0x34cab5e5 , 0x5117e4fd , 0x33135fe9 , 0xf1641ac6 , 0x1411ea95 ] )
+  , Mont::unsafe_make( [ 0x407b82b0 , 0xf6c27b19 , 0xefb6eee4 , 0xc7d23b72 , 0xb3bdba89 , 0x47068127 , 0x9c9d73d3 , 0x1476a326 ] )
+  , Mont::unsafe_make( [ 0x110b1c0e , 0xa69c061b , 0x22465342 , 0xb8900c07 , 0x281c4c3f , 0x8956ba8a , 0xcce58fe9 , 0x1f2256aa ] )
+  , Mont::unsafe_make( [ 0x8d4e09e4 , 0x628e3408 , 0xadd1fed9 , 0x187639c3 , 0x06385f95 , 0x8c8e26d4 , 0x5dc096c8 , 0x2fe86c6b ] )
+  , Mont::unsafe_make( [ 0xe3df8efa , 0xdc33aad1 , 0x33faa181 , 0x5bfd4a37 , 0xa2b637cc , 0x45aa1a4e , 0x5d59cfe7 , 0x0d45819e ] )
+  , Mont::unsafe_make( [ 0x95de68dd , 0xa4773164 , 0xdb187a48 , 0x156ac566 , 0x8e0cd3aa , 0x750b1cb5 , 0x9b877f8f , 0x286e5a61 ] )
+  , Mont::unsafe_make( [ 0xe773a1ca , 0xeb4220e4 , 0xcd93fd6d , 0x5945f42c , 0xb2f25417 , 0x9dd07d9a , 0xf452dff5 , 0x13cc8221 ] )
+  , Mont::unsafe_make( [ 0x16db0bcd , 0xe2bfc00d , 0x34ad5ca5 , 0x49810e7d , 0x474d1847 , 0x6af56f45 , 0xff86a382 , 0x07409e31 ] )
+  , Mont::unsafe_make( [ 0xd3df7b15 , 0x36a905fd , 0x4dc04e9c , 0x15ba2fd9 , 0x03f171ea , 0x8fa4752b , 0xb97ed79b , 0x27e69b6e ] )
+  , Mont::unsafe_make( [ 0xd833cbc7 , 0xdb48bdd1 , 0x60b68c58 , 0xd423265e , 0x51c70376 , 0xec0f54fb , 0x1471b31e , 0x28dc9930 ] )
+  , Mont::unsafe_make( [ 0x84261766 , 0xd2f7cc53 , 0xd4007628 , 0xc5411854 , 0x1f81436d , 0x14f55f8b , 0x2f755efa , 0x10feb971 ] )
+  , Mont::unsafe_make( [ 0x05cd0368 , 0x7b841879 , 0x82ec1a77 , 0xb3cdc85f , 0xc9e54c5d , 0xa51b96bd , 0xba8012cf , 0x1852af45 ] )
+  , Mont::unsafe_make( [ 0x9f2a2afe , 0xe2ed21e5 , 0xc90db4ef , 0xe51a313c , 0x685b1771 , 0x2486c2ca , 0xd7281f9b , 0x224921e9 ] )
+  , Mont::unsafe_make( [ 0x36e6499f , 0xbb7fbd6d , 0x2f35820f , 0x475e7ec5 , 0x9292b4d7 , 0x71d6fea5 , 0xc6a8c72d , 0x1ff5100b ] )
+  , Mont::unsafe_make( [ 0x4eecf4d3 , 0x4eef63cf , 0xfdabc869 , 0xa320b6a1 , 0xcb7ccfbb , 0xa76abed5 , 0xfab6faab , 0x1818b2f6 ] )
+  , Mont::unsafe_make( [ 0x1919d140 , 0xe3bcac23 , 0x7a9b98e4 , 0xef3c0d1a , 0x9bf1d91d , 0xdf9d1fda , 0x02dd8e7a , 0x0e682422 ] )
+  , Mont::unsafe_make( [ 0xc6f614dd , 0x8daaaed0 , 0x9683ad63 , 0x6432a381 , 0x4e53382a , 0xffa55850 , 0x3a008247 , 0x1b75704b ] )
+  , Mont::unsafe_make( [ 0x199a1ba9 , 0x09ff0f2d , 0xc26fc7f4 , 0x23645004 , 0xf4b9a9f6 , 0xb78fa373 , 0xd2f154d1 , 0x0816b432 ] )
+  , Mont::unsafe_make( [ 0x15eb4a22 , 0x28ef4423 , 0x887f40bc , 0x6fcfb622 , 0xc3650a21 , 0x20704990 , 0xba0fc431 , 0x135326af ] )
+  , Mont::unsafe_make( [ 0x043355f1 , 0x67023be4 , 0xb57af096 , 0x060e4aed , 0x725f4bb4 , 0x5534fbad , 0x6fca0786 , 0x24272d7b ] )
+  , Mont::unsafe_make( [ 0x3665cf73 , 0x0550bd58 , 0xddfcba94 , 0xc0d983fb , 0xe67d5dca , 0x5262a3e0 , 0x719fa2b2 , 0x14a08bc1 ] )
+  , Mont::unsafe_make( [ 0x72d50d1d , 0x8fa8f0f5 , 0xdb09e1f0 , 0xe1026911 , 0xe72aabad , 0x2fafec35 , 0xce841245 , 0x2c70748a ] )
+  , Mont::unsafe_make( [ 0x5b066ef1 , 0xf8e647bd , 0x65495472 , 0x86013cd2 , 0x1839aa41 , 0xec029523 , 0x852bde19 , 0x2e530b98 ] )
+  , Mont::unsafe_make( [ 0xbb75a7c2 , 0x9051b633 , 0x28f2baa5 , 0x2f2951da , 0xbdff4b5b , 0xd07370e0 , 0x16dd578f , 0x13c59a05 ] )
+  , Mont::unsafe_make( [ 0x33ac4689 , 0x58ca08c5 , 0x93abea72 , 0x45eab959 , 0xcecf5fbe , 0x268387c2 , 0x126bb5cb , 0x0d4a353d ] )
+  , Mont::unsafe_make( [ 0xdfcc584b , 0xea176209 , 0x20169d62 , 0xe2ef9955 , 0x3fcd2bde , 0x700f6705 , 0x26bbf638 , 0x2878d180 ] )
+  , Mont::unsafe_make( [ 0x24c864f1 , 0xc41c61e9 , 0x632bfe9a , 0xaa4d3896 , 0xc47d215d , 0x7e292830 , 0xd197ac53 , 0x173e7f8e ] )
+  , Mont::unsafe_make( [ 0x5647f89f , 0x9a06623b , 0xe19ed172 , 0x6fe75d44 , 0xb278d7ab , 0x95fa5182 , 0xb3f930b1 , 0x2b7f5e7e ] )
+  , Mont::unsafe_make( [ 0x30f6a7f5 , 0xda3143bb , 0x855a3417 , 0x61c4145b , 0x362813d6 , 0x90634f49 , 0x7b12f0e4 , 0x244c22af ] )
+  , Mont::unsafe_make( [ 0xa4cd25d7 , 0xdebfaf63 , 0xcd79049e , 0x5b6799b4 , 0x58194991 , 0x308b9f7f , 0xdfc8a689 , 0x073c16e5 ] )
+  , Mont::unsafe_make( [ 0xb10cc25f , 0x4693059a , 0x9c11a08c , 0x2ca78d86 , 0x019f45f5 , 0xff803ce3 , 0x97f54603 , 0x2d72e8ce ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x5a4b4bd6 , 0x70d7b863 , 0xee45536b , 0x0564ca36 , 0x6981f240 , 0x25ca877d , 0xcefe016a , 0x1a2d7401 ] )
+  , Mont::unsafe_make( [ 0x7ad11ca8 , 0xc65bd9b9 , 0x160f0720 , 0x105e74a8 , 0xac5865fe , 0xf80379c9 , 0x0cec7358 , 0x1827c2f5 ] )
+  , Mont::unsafe_make( [ 0xd017df44 , 0xda871396 , 0x14cce5ca , 0xa4deb7c2 , 0x091cb905 , 0x7054e7d5 , 0x034b3c2c , 0x14c21007 ] )
+  , Mont::unsafe_make( [ 0x484104f1 , 0x201a840c , 0xe56c3eb3 , 0x02827f73 , 0x39bff78b , 0x4fab9f65 , 0xaf73141d , 0x27c25b5f ] )
+  , Mont::unsafe_make( [ 0x78547990 , 0x7635b528 , 0xe740540f , 0x00937c60 , 0x683c6753 , 0x05950eff , 0x331fffc8 , 0x06a3d8e3 ] )
+  , Mont::unsafe_make( [ 0x6ad7355d , 0x1c55e4a4 , 0xfb748af8 , 0x6e4dafb6 , 0x6a7e9f1d , 0xd38d1785 , 0x0ef5f73f , 0x1e1c928b ] )
+  , Mont::unsafe_make( [ 0xbb6fc1e7 , 0x55ce9c10 , 0x316e4e6f , 0xa52e724c , 0x0901bd28 , 0x068a012b , 0xa58c6455 , 0x11d4f189 ] )
+  , Mont::unsafe_make( [ 0x114d03d4 , 0x066e32a9 , 0x601b32ef , 0x5205e52f , 0xfcfac4e0 , 0xfbf36417 , 0x4407aa6d , 0x1d4543a2 ] )
+  , Mont::unsafe_make( [ 0xe657c943 , 0x323d27f5 , 0xf4c7a4fa , 0x38cfa11f , 0x8e21a3bb , 0x6227c7f3 , 0xb3e598ce , 0x18c96e23 ] )
+  , Mont::unsafe_make( [ 0xeb3b53ef , 0x1d823fd1 , 0xd5eb4ef8 , 0xee43e9e3 , 0x0d711009 , 0xd7b40153 , 0x3115a174 , 0x161f48f4 ] )
+  , Mont::unsafe_make( [ 0x128ca7f6 , 0x384e9719 , 0x106eff69 , 0x9e6ea4fd , 0x2cccd633 , 0x5fc5f47e , 0xfcb5b071 , 0x0ec6a6c6 ] )
+  , Mont::unsafe_make( [ 0xc91207c4 , 0x729822c2 , 0xb9b926e7 , 0x1996a126 , 0xa4e23f46 , 0x5661a679 , 0xf3b0a7b4 , 0x026d70a4 ] )
+  , Mont::unsafe_make( [ 0x75ce6c65 , 0x35a30db8 , 0x94556c6b , 0x8bbbece7 , 0x4fd4a4f2 , 0x5ef37422 , 0x17f6f97a , 0x059de45c ] )
+  , Mont::unsafe_make( [ 0x87968ddd , 0x58cac019 , 0x0bdd00c1 , 0x33ae2027 , 0x3ae2ff55 , 0x090641c3 , 0xd51eedd1 , 0x22008601 ] )
+  , Mont::unsafe_make( [ 0xc5441a22 , 0x175ea62e , 0x17871f34 , 0x21068305 , 0xec9ecb15 , 0x60859ee9 , 0xb72b970a , 0x1051e745 ] )
+  , Mont::unsafe_make( [ 0x29a90cd6 , 0x9f98337a , 0x5a8a2f78 , 0xf3cc56c6 , 0x54f7bfbe , 0x8d3ecb09 , 0x0e9b868c , 0x05f946e1 ] )
+  , Mont::unsafe_make( [ 0x7ac3c0a1 , 0xc15d5e58 , 0xc6b6e262 , 0x2b35889b , 0x357574e3 , 0x392b9403 , 0x045f5a6d , 0x294570b2 ] )
+  , Mont::unsafe_make( [ 0x6bf70df8 , 0xa08fb5cf , 0xadaa66f5 , 0xbc6dac99 , 0xd01715a1 , 0x65c49134 , 0x44d10363 , 0x0d6ae4a8 ] )
+  , Mont::unsafe_make( [ 0x06e4e6b9 , 0xf209fc27 , 0x424e030a , 0x7563a1df , 0x588fac6b , 0xe6bc4e58 , 0xa71115ee , 0x10ee91b9 ] )
+  , Mont::unsafe_make( [ 0x0fad06c6 , 0x5f58c081 , 0x721cabe1 , 0xfe9621a2 , 0x5a205eb2 , 0x237f3324 , 0xf9bd6d0c , 0x0936400c ] )
+  , Mont::unsafe_make( [ 0x5e24c23a , 0xa0c3d3e9 , 0x418bbcfe , 0x6a6d5192 , 0x703198e4 , 0x16fad9c3 , 0xcfc6ff83 , 0x17e447f1 ] )
+  , Mont::unsafe_make( [ 0x0b82ae72 , 0xe390e937 , 0x50d1323f , 0x461ddcf9 , 0x25fc8967 , 0x3cfdd25e , 0xe272e86f , 0x2f840f8e ] )
+  , Mont::unsafe_make( [ 0x69a91fe3 , 0x7db6c29a , 0x6e6d2e86 , 0x6ff136ad , 0xc899af76 , 0x5ae927fd , 0xab979755 , 0x1cb5f1ac ] )
+  , Mont::unsafe_make( [ 0x2cf18852 , 0x6881895c , 0x158c1d29 , 0x559ae537 , 0x74f3d3c9 , 0xeac4f9c2 , 0xbdc809e1 , 0x281bf0ba ] )
+  , Mont::unsafe_make( [ 0xddefe893 , 0x1416b457 , 0x6dca23c0 , 0x63eb8ced , 0xa5d827c9 , 0x1b8ab831 , 0xbd5d768e , 0x0338da51 ] )
+  , Mont::unsafe_make( [ 0x0690e181 , 0x26f3529c , 0x2190ace6 , 0xc4e83198 , 0xbe9d2ff7 , 0x84161f8f , 0x66325737 , 0x0589b271 ] )
+  , Mont::unsafe_make( [ 0x4b70f5ce , 0x9f1d8cc2 , 0xe145f328 , 0xf15bab63 , 0x5e43c30f , 0x5cda2152 , 0x1df8b59c , 0x0422dbec ] )
+  , Mont::unsafe_make( [ 0x7f9b98f2 , 0x8d94b789 , 0x023bcea8 , 0xb1b455c8 , 0xff477e9f , 0x4f36961c , 0xc121645a , 0x055dc638 ] )
+  , Mont::unsafe_make( [ 0x99eb6f53 , 0x65c98def , 0xc68cff43 , 0x8bcc867f , 0xed65288d , 0x13615d9d , 0x97c988bf , 0x0a78ea43 ] )
+  , Mont::unsafe_make( [ 0x68fb1767 , 0x0e047790 , 0x1c1a5a4e , 0x6ca328ca , 0x85a1b92b , 0xef90b997 , 0x9b6b2f67 , 0x2f328095 ] )
+  , Mont::unsafe_make( [ 0xf84f26e2 , 0xdf4b8bf1 , 0x81ef70e0 , 0x12f991a7 , 0x3e136b73 , 0xe6cdb6d7 , 0x3a200c2d , 0x26cee223 ] )
+  , Mont::unsafe_make( [ 0x02224cd7 , 0x64a942c3 , 0x8612db58 , 0x3a259c4c , 0x508d406a , 0x4f7f5648 , 0x0e27a5c5 , 0x1f122b4e ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x5964a4db , 0xf2f8d3b8 , 0xebe1f680 , 0x03e71c3d , 0xe198740b , 0xbf458fb8 , 0xdc8d8d7e , 0x14e789f1 ] )
+  , Mont::unsafe_make( [ 0xdef7470b , 0x7331c8ea , 0xe7bd86e7 , 0xb65ef02d , 0xc7c4797b , 0x6e2eb073 , 0x6b2544ea , 0x07aaa7bd ] )
+  , Mont::unsafe_make( [ 0x89e4d5cd , 0x0458a86c , 0xd0c08ca5 , 0x0014cb86 , 0xc8678705 , 0x3fc141ab , 0x21700245 , 0x1895e3e6 ] )
+  , Mont::unsafe_make( [ 0xd22c2d16 , 0x4e56ec4b , 0x724b00d3 , 0x9756bfb5 , 0xefeb58f2 , 0x728a7dac , 0xae8b0074 , 0x0cca6f1e ] )
+  , Mont::unsafe_make( [ 0xae89afaa , 0xaec3c36f , 0xc25d773e , 0xea0ee420 , 0x28d89680 , 0xf45807ff , 0xaf4651ef , 0x11b91c90 ] )
+  , Mont::unsafe_make( [ 0x7536d14c , 0xa78503dd , 0xbdb56a23 , 0x320a4140 , 0x8de48c22 , 0x85c80f2e , 0x04d96108 , 0x220a84b8 ] )
+  , Mont::unsafe_make( [ 0x98ea79a5 , 0x7a5b74a8 , 0x6200840b , 0x5c9de4c1 , 0x9ece8367 , 0x44d94274 , 0x2b1131af , 0x1101ae43 ] )
+  , Mont::unsafe_make( [ 0x52fa0448 , 0x40654e85 , 0xee2dafb7 , 0x12eec830 , 0xa1c5d068 , 0x574887ca , 0x6bf96506 , 0x293410fd ] )
+  , Mont::unsafe_make( [ 0x95ac4f3c , 0xa5933d5a , 0xf89e21e2 , 0x5227db48 , 0x1fbbf5e6 , 0xfa1218e6 , 0x6947bab1 , 0x23018b2e ] )
+  , Mont::unsafe_make( [ 0x6a38aa80 , 0x6a30b7a9 , 0xb5575d1e , 0xc1b77a06 , 0x13f37ad4 , 0xe82ff941 , 0xebc0a9eb , 0x02398ed4 ] )
+  , Mont::unsafe_make( [ 0xefedbf4b , 0x6d8f6db5 , 0xc9ec08c2 , 0xf4337ef7 , 0xdbbd9b55 , 0x16771082 , 0x841387d7 , 0x23e5cf9a ] )
+  , Mont::unsafe_make( [ 0xa421d9ba , 0x2f9574a9 , 0x1abd4976 , 0xf9a6ac2f , 0x4a46cd67 , 0x34c5a241 , 0x3571bed9 , 0x107a40f5 ] )
+  , Mont::unsafe_make( [ 0x9152a7a6 , 0x50d04a4c , 0xed9cdfb8 , 0x74b5ce82 , 0x211c2eb7 , 0x5a773362 , 0xa6400d86 , 0x17e7e71c ] )
+  , Mont::unsafe_make( [ 0xc9b24b49 , 0x5179c7bb , 0x18b9a6d3 , 0xfeb45b4c , 0x4267c110 , 0x74211749 , 0xccc3c172 , 0x260d9b6c ] )
+  , Mont::unsafe_make( [ 0x51e6f63b , 0xbdf2c348 , 0x1efb55fb , 0x3ed60dbc , 0x4d063f58 , 0x7bf7d211 , 0x25071d7f , 0x12d66b66 ] )
+  , Mont::unsafe_make( [ 0xbe3ae8fa , 0x74bf6375 , 0x8afe75d4 , 0xdbe030da , 0x0d6399c4 , 0x42fa0843 , 0x9beffc1a , 0x23a905a6 ] )
+  , Mont::unsafe_make( [ 0x3ddb1733 , 0x05bff85d , 0x2da86922 , 0x31a89b8a , 0x2adb4886 , 0xd876cb29 , 0x92f1be1d , 0x1c7216b1 ] )
+  , Mont::unsafe_make( [ 0xe3a220dc , 0x5600a61d , 0xf998b2ff , 0x33756852 , 0xc3d443fc , 0x1aaaef3b , 0x691e348e , 0x2a445dde ] )
+  , Mont::unsafe_make( [ 0x7c69edd7 , 0x6b3a66de , 0xfb690a72 , 0x16658742 , 0xfcbabb32 , 0x9c4a4bd9 , 0xff1da101 , 0x038974ac ] )
+  , Mont::unsafe_make( [ 0x112a6fe0 , 0x0c752eb2 , 0xf1665241 , 0xee142f24 , 0x001b7888 , 0xe6869bae , 0x139bceca , 0x28fdbbc5 ] )
+  , Mont::unsafe_make( [ 0x92e91af3 , 0x4a121bff , 0xc2519d9b , 0xce143a76 , 0x89578ffb , 0xad969dca , 0xe04da620 , 0x2ff8cd05 ] )
+  , Mont::unsafe_make( [ 0x41f88301 , 0x077bb5a6 , 0xed1bbc05 , 0xa1bbb648 , 0x42b81a9d , 0xefd855fd , 0xa54f04d0 , 0x090cf739 ] )
+  , Mont::unsafe_make( [ 0xc815574a , 0x931131e6 , 0x70483432 , 0xb889cfb1 , 0xa6cd603f , 0x473b55da , 0x9ec27495 , 0x104e10c9 ] )
+  , Mont::unsafe_make( [ 0xb76b6991 , 0xd616ce2e , 0xfaeae1ba , 0x5bfa4d7f , 0x08687d54 , 0xd7d2708e , 0x1c213d5a , 0x2f8de6af ] )
+  , Mont::unsafe_make( [ 0xef27dd70 , 0xbff7158f , 0x6a3058d8 , 0xb765dc8b , 0x254bfc55 , 0x3071f9b1 , 0x0582e4c8 , 0x1f008376 ] )
+  , Mont::unsafe_make( [ 0xc4f00a32 , 0x2a8133f0 , 0xe7aae0f0 , 0x38f6efd3 , 0x822ba4a5 , 0x9062ddb4 , 0x9ac7e7dc , 0x07bf2f54 ] )
+  , Mont::unsafe_make( [ 0x84d60768 , 0xd561ee8a , 0xc93dea53 , 0x11ef0d01 , 0x382b2fd4 , 0xf2773eef , 0x4e52bf26 , 0x013ce0a6 ] )
+  , Mont::unsafe_make( [ 0xad3fb97e , 0x6c7b78ae , 0x29a942e1 , 0xbc92dc5a , 0x89beedf0 , 0xafb3616a , 0x388819db , 0x15fe95c2 ] )
+  , Mont::unsafe_make( [ 0xc6d21c1e , 0x03901e0e , 0xaa266ba0 , 0xebb66630 , 0x1e26db5c , 0x6bc77a27 , 0x33d30a3d , 0x1d8f8efa ] )
+  , Mont::unsafe_make( [ 0x5800bf15 , 0xe53cfafc , 0x68777904 , 0x649db265 , 0x0ad63f93 , 0x22e3476c , 0xb5d556cb , 0x235e55a9 ] )
+  , Mont::unsafe_make( [ 0xe168b83d , 0xefe3cbec , 0xc106e426 , 0x97cae03d , 0x0e59da9d , 0xa514de16 , 0x35e65806 , 0x15f1391a ] )
+  , Mont::unsafe_make( [ 0x289d42b0 , 0xd0e12447 , 0xdfe43bb8 , 0x5e492e6f , 0xa8736d14 , 0x00a2baba , 0x93f8048b , 0x05851f06 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x82dab984 , 0x671a491c , 0xbc7daf76 , 0x6ed20c6d , 0x0cb8ccc4 , 0x30480a94 , 0x5a946860 , 0x02e30734 ] )
+  , Mont::unsafe_make( [ 0x64d13730 , 0x35c8c5bd , 0xaa2f144f , 0x52d484a4 , 0x0a944306 , 0x41ca507d , 0x4f13e8fa , 0x05d0849d ] )
+  , Mont::unsafe_make( [ 0xb0c3129c , 0x14bb8346 , 0x8968344d , 0x3ffc6426 , 0x6d7e43cc , 0xb43dfd4b , 0x8717948d , 0x01b74be9 ] )
+  , Mont::unsafe_make( [ 0xc7ff077a , 0xa9929356 , 0xfb41bbad , 0x9e8672bf , 0x3a889e19 , 0xc5a71444 , 0x76f3c524 , 0x29b894fd ] )
+  , Mont::unsafe_make( [ 0x584596f7 , 0x6ea8d536 , 0xa256413b , 0x4193fb68 , 0x412af2d3 , 0x7d07ccc1 , 0x15a43777 , 0x112f6f2d ] )
+  , Mont::unsafe_make( [ 0x6a491130 , 0xe9f59044 , 0x8baae4b4 , 0xd85ea5af , 0x4885147e , 0x5b710833 , 0x2e890022 , 0x14b110ea ] )
+  , Mont::unsafe_make( [ 0x9bcaaff8 , 0xc615bc01 , 0x6ddf703c , 0x8a6c625a , 0xe74da0f9 , 0xd168d982 , 0x6a9fd71d , 0x09d22dd8 ] )
+  , Mont::unsafe_make( [ 0x0379f759 , 0xfe1df312 , 0xba4554f3 , 0xf427cca0 , 0x478f402e , 0x77ad84d9 , 0xe7ad5313 , 0x05875fb4 ] )
+  , Mont::unsafe_make( [ 0x916aa54a , 0x9ccfaa87 , 0x74d9a73a , 0x2e695875 , 0x70bb5e4b , 0xf5a397da , 0x703c55c6 , 0x02c018ae ] )
+  , Mont::unsafe_make( [ 0x82e59434 , 0x21401925 , 0xafb381ce , 0xeb872755 , 0xcd2a55e9 , 0xeeb81f49 , 0x78070645 , 0x1a5cb2c2 ] )
+  , Mont::unsafe_make( [ 0xaaa0572f , 0xece1b8ba , 0xbabee4ab , 0xb8816e44 , 0x38c1a13b , 0xff6ff920 , 0x96b51d03 , 0x29b19f83 ] )
+  , Mont::unsafe_make( [ 0x833bc6e9 , 0xb85081bc , 0x0f4ec7a7 , 0x8f4aa00b , 0xc2e567e3 , 0xa1dd0c6f , 0x50a6f9a2 , 0x18ac94a3 ] )
+  , Mont::unsafe_make( [ 0x44bf2389 , 0xd8be4a6c , 0xac8f553b , 0x986cd1e4 , 0x5211480d , 0x84231941 , 0xe764eed2 , 0x0a0e4599 ] )
+  , Mont::unsafe_make( [ 0xb1c5ce98 , 0x9e67c6f2 , 0xd08d98fa , 0x961b59e5 , 0x916d6eff , 0xa8ba4488 , 0x30457c48 , 0x21544c39 ] )
+  , Mont::unsafe_make( [ 0x249a3fed , 0xe185141d , 0x9a8b5576 , 0x5e662c37 , 0xcf4fde04 , 0x4bb7b09e , 0x040e54cf , 0x1c4d935b ] )
+  , Mont::unsafe_make( [ 0xb1f1f1c9 , 0x69d28eea , 0x2461b98b , 0x193d0c30 , 0xde4695cb , 0x8585d5cc , 0xd052e63e , 0x0cd561bb ] )
+  , Mont::unsafe_make( [ 0xbcef7700 , 0x46fcc704 , 0x77187702 , 0x9585a75a , 0x99dff18f , 0x3beccd77 , 0xc6d37dfa , 0x0eb70b77 ] )
+  , Mont::unsafe_make( [ 0xc92b22ab , 0x8b96f827 , 0xa97a3a01 , 0x5d8af479 , 0x1bfa3cc5 , 0xb5ecf18a , 0x701c53bc , 0x0c1e607f ] )
+  , Mont::unsafe_make( [ 0xbf3d60af , 0xcf0e3216 , 0xb038a58f , 0x63dd98e2 , 0xc80d4c2b , 0x1d1cf153 , 0x2c72dd74 , 0x14cca37e ] )
+  , Mont::unsafe_make( [ 0x7da64c10 , 0x40c9178e , 0xfd211679 , 0x4ec28d9d , 0xb7cf99f5 , 0xb03bffd0 , 0x44ceb1cf , 0x2e8f8bde ] )
+  , Mont::unsafe_make( [ 0xc593124b , 0x0a356ff8 , 0xd9e4a1da , 0x6c2917f2 , 0xb847f49f , 0xff5b141a , 0xebedb4f5 , 0x11b2fa0a ] )
+  , Mont::unsafe_make( [ 0x9a012e17 , 0xd3a67778 , 0x84af96ab , 0x16fb3ed1 , 0x67a19129 , 0x3d6545e5 , 0x5ac27c6e , 0x08ca241b ] )
+  , Mont::unsafe_make( [ 0xf6792a4b , 0x21708dbd , 0x22f62993 , 0x3c9e5dba , 0xb7a3a946 , 0x48bc7a60 , 0x48f3d608 , 0x121ab380 ] )
+  , Mont::unsafe_make( [ 0x829ef55d , 0xa765a3f5 , 0xd5ebac69 , 0x3d9c6be6 , 0x6b44a3cf , 0xca79b82b , 0x2c8c49d2 , 0x0acaca68 ] )
+  , Mont::unsafe_make( [ 0xac2b3574 , 0x4a2fbbbe , 0x1e6a0c7f , 0x5e373386 , 0xb633f246 , 0x5a0f91d6 , 0x8e75cf68 , 0x07ea3252 ] )
+  , Mont::unsafe_make( [ 0xed9c0dc9 , 0x0a0f3e5c , 0xc2efb4dc , 0x2dce8f75 , 0x190bd649 , 0x950bc901 , 0x7ced0c00 , 0x01a971d9 ] )
+  , Mont::unsafe_make( [ 0x057892a1 , 0xd466ea77 , 0x4dbaa765 , 0x19472208 , 0x710c5b3a , 0x0ecd7e24 , 0xfc07a370 , 0x28f1917b ] )
+  , Mont::unsafe_make( [ 0x45db3039 , 0x03e2ba25 , 0x42847dd5 , 0x4d7cd2f8 , 0xd3471fc2 , 0x683326ba , 0x4c4ed6c5 , 0x076ac3a6 ] )
+  , Mont::unsafe_make( [ 0x446363b8 , 0xab3fd6bc , 0xd37917e7 , 0xc59eb8f0 , 0x801fa57b , 0x3f77ced1 , 0x6cdac964 , 0x02140f5b ] )
+  , Mont::unsafe_make( [ 0x72883d1f , 0xc018f70e , 0x978652e9 , 0x73ac4678 , 0x1f83604a , 0x3590a465 , 0x18f9b0fc , 0x001b637f ] )
+  , Mont::unsafe_make( [ 0x76f51d37 , 0xfca020bf , 0x9dc683d5 , 0xf6c850d4 , 0x59c92c8e , 0x7b6236b5 , 0xc353e08a , 0x260a0cc1 ] )
+  , Mont::unsafe_make( [ 0x775d18ca , 0x327cb439 , 0xa71ff4f5 , 0xa0f24e8f , 0xed220eeb , 0x2e03210b , 0x8e7feeb2 , 0x09a7d58c ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x1a3442ae , 0x29e2364c , 0xd704c11d , 0x27d31eda , 0xcdf9f640 , 0x3a4916a5 , 0x1694797c , 0x0ac359ae ] )
+  , Mont::unsafe_make( [ 0xeccb9ece , 0xae8c08cd , 0xe219fe5d , 0x44262b00 , 0xabd3f1e0 , 0x87de7056 , 0x5ee3ff15 , 0x12115171 ] )
+  , Mont::unsafe_make( [ 0xceb0764e , 0x59451c74 , 0x140ecd0a , 0xb50f252c , 0xa75e9adb , 0xe7a01960 , 0x1dddf127 , 0x20d25e88 ] )
+  , Mont::unsafe_make( [ 0x8cb154ed , 0x1b2acb04 , 0x1a908c24 , 0x74694c48 , 0xa96e019f , 0x05366521 , 0x0205e473 , 0x1c1c7407 ] )
+  , Mont::unsafe_make( [ 0xe9c07019 , 0x2f683a3d , 0xb0a3fb65 , 0xa2b87616 , 0x232251cf , 0x6cd3bc5d , 0xfffa3fc2 , 0x12e4711a ] )
+  , Mont::unsafe_make( [ 0xfb4e5608 , 0xf7f8e6b7 , 0xcb79cbd2 , 0x927014dd , 0x1db64156 , 0xe540b7f6 , 0x184ba829 , 0x0e0ab719 ] )
+  , Mont::unsafe_make( [ 0x0b4216b7 , 0x3f9f2805 , 0x43d7d333 , 0xcc1e999d , 0x1d9fb68a , 0x2025d408 , 0xed92826c , 0x16e26ba9 ] )
+  , Mont::unsafe_make( [ 0x5045df64 , 0x003f5e3f , 0x85c3c2b5 , 0x381c0d40 , 0xa1798bcf , 0xe7c7614a , 0x10634b21 , 0x12038c12 ] )
+  , Mont::unsafe_make( [ 0xf21b633f , 0xae0c5d01 , 0x97f9d1e0 , 0x02133726 , 0x86029c9d , 0x1358c5e4 , 0xb68a6e8b , 0x1c9aecd8 ] )
+  , Mont::unsafe_make( [ 0xb1f301fc , 0x6258ec04 , 0x940f8f01 , 0xe18e77b5 , 0xf820f1e7 , 0xd4214f87 , 0xb612d65b , 0x0bbc29c7 ] )
+  , Mont::unsafe_make( [ 0x5cc8c8cc , 0xb7821680 , 0xf2758cbb , 0xde51c279 , 0x06e6d864 , 0xccb04f02 , 0x91749eaf , 0x07816d3e ] )
+  , Mont::unsafe_make( [ 0x80606801 , 0x2e36a000 , 0xc68e58ab , 0x7ca9b5e9 , 0x73c8ea01 , 0xeae7b84b , 0x136d5649 , 0x288f0c23 ] )
+  , Mont::unsafe_make( [ 0x5d90e82c , 0x9046b499 , 0xb4f9bcd9 , 0x1015520c , 0x27e08f25 , 0x993dca55 , 0xc10f33ed , 0x02528d87 ] )
+  , Mont::unsafe_make( [ 0xd2ab4c2b , 0x5f769db3 , 0xb3d02118 , 0x6f8b0d98 , 0xdd58690c , 0xa1a39d26 , 0xecfb5310 , 0x26eb3ae4 ] )
+  , Mont::unsafe_make( [ 0x0d3d6ddb , 0x4ac8c713 , 0xc6e12b43 , 0xb67c2071 , 0x5d213626 , 0x067a6a0b , 0x5f491d7b , 0x0664eea1 ] )
+  , Mont::unsafe_make( [ 0xa4b9044b , 0x1eb86899 , 0xf832688d , 0x670245e0 , 0xa9fe211b , 0x542925c3 , 0x2bf0173f , 0x1d3a2371 ] )
+  , Mont::unsafe_make( [ 0x2a14e6c1 , 0x20f282d1 , 0xa1736195 , 0x0d869c54 , 0x6e1163e0 , 0x91e248e3 , 0x7961e7ef , 0x1c58ba4b ] )
+  , Mont::unsafe_make( [ 0x8f48540d , 0xe0a6846d , 0x61bb71a7 , 0xfbd2d1d0 , 0x44808f77 , 0xa9509089 , 0x2452537e , 0x1a21468b ] )
+  , Mont::unsafe_make( [ 0x7697508c , 0x17531a35 , 0xf4ea8f7d , 0xf3ca9d6e , 0x68d7b78c , 0xa2fc2af2 , 0x1256ac48 , 0x012ffb2e ] )
+  , Mont::unsafe_make( [ 0x6e9211d0 , 0x4b6a9045 , 0x29aea6f0 , 0xb5462efc , 0x0dcb2fc7 , 0x677e9ffd , 0xc6e25d81 , 0x1c07fab0 ] )
+  , Mont::unsafe_make( [ 0xee2e3e59 , 0x8009d0e2 , 0xb2957abe , 0x168ca4f6 , 0xee2c6633 , 0x06808b9f , 0x84762e72 , 0x1143b71d ] )
+  , Mont::unsafe_make( [ 0xb6354ce3 , 0x21b3deb3 , 0x35ad6705 , 0xaa7dcf95 , 0x49a651cd , 0x24ff2c36 , 0x96d8e900 , 0x09bf9871 ] )
+  , Mont::unsafe_make( [ 0x069d84d9 , 0xa94d5669 , 0xfc8c90b0 , 0x6e32fb5f , 0xfa216b6b , 0x60dbce40 , 0x5f65f80c , 0x23d441da ] )
+  , Mont::unsafe_make( [ 0xa55de547 , 0x2935c316 , 0xed5404bb , 0xa1bca4d4 , 0x72dab537 , 0x01079adc , 0xaa27f9ae , 0x0fb849c4 ] )
+  , Mont::unsafe_make( [ 0x78e8c47a , 0x0a424151 , 0x4f5f9f93 , 0xa6ab7f8e , 0xeac4cc4c , 0x3d53e569 , 0x24eac9b3 , 0x0bc132a8 ] )
+  , Mont::unsafe_make( [ 0x938e51bf , 0x334028f3 , 0x01cddd4c , 0x981b38fd , 0x34d8b085 , 0x88377645 , 0xf3a29118 , 0x04da18d8 ] )
+  , Mont::unsafe_make( [ 0x54bf3967 , 0x7fdbd72d , 0x649df7ff , 0x1b0ad7ef , 0xed9edc8b , 0x73f3a79f , 0x462e33b8 , 0x0c757548 ] )
+  , Mont::unsafe_make( [ 0xc46ca74a , 0xa4118607 , 0xb25594f9 , 0x1d29a4e2 , 0x514a237d , 0x6dcfa267 , 0xbd9a5000 , 0x2dcf3eec ] )
+  , Mont::unsafe_make( [ 0x925ea3a2 , 0x0e626798 , 0x87c73211 , 0xeecd6b06 , 0x75238bc3 , 0x744988e0 , 0x03f72509 , 0x162d4293 ] )
+  , Mont::unsafe_make( [ 0x43594352 , 0x6ea19a2b , 0x8f7f6dac , 0xb205925e , 0x44f9361b , 0xa9d49fd8 , 0xac292c7f , 0x1546b92d ] )
+  , Mont::unsafe_make( [ 0x22f1bd57 , 0x45002056 , 0x302535ef , 0x957541b2 , 0x64278d77 , 0xc908b9f0 , 0xd0d86460 , 0x031eaacc ] )
+  , Mont::unsafe_make( [ 0x460bdb07 , 0x5478f300 , 0x4b4e4b91 , 0x7ff8300c , 0x07d774b7 , 0x92f669e0 , 0xca675510 , 0x0b79b9fb ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xfee246ae , 0xf6c4b56f , 0x1947e42c , 0xf5bcdfe9 , 0x3e489d70 , 0xfb40a98b , 0x1a481033 , 0x27e4bb77 ] )
+  , Mont::unsafe_make( [ 0x695fd4a7 , 0xeae2fdd3 , 0x9740b6f3 , 0x45298c5e , 0x33883669 , 0x0363b055 , 0x81e5e269 , 0x2c0ed50d ] )
+  , Mont::unsafe_make( [ 0x15b708c8 , 0xbf3f51bc , 0xf2eab3b2 , 0xb5b7850d , 0x937b4a19 , 0xabea4b71 , 0xfc50e6ef , 0x0a1fc95a ] )
+  , Mont::unsafe_make( [ 0x7a3ba1ee , 0xeb8274b3 , 0xc3f34daa , 0xc37c0c35 , 0xef6d4ea4 , 0xe0d340c3 , 0x7fb1caab , 0x1601d07d ] )
+  , Mont::unsafe_make( [ 0x4ffa7c87 , 0xa367f9e5 , 0xa247f04a , 0xc4035764 , 0xffbc323b , 0xd7779e1f , 0x6f4f37ab , 0x00906cea ] )
+  , Mont::unsafe_make( [ 0xaf80401b , 0x16f690c0 , 0xad63fa65 , 0xb0ee4599 , 0x1f1ff935 , 0x303358d3 , 0x13bed0ab , 0x022485c1 ] )
+  , Mont::unsafe_make( [ 0xaf587de0 , 0xfb5d80bb , 0x26c503cc , 0x8366c702 , 0xd4020dc6 , 0xfafe6571 , 0x4e5ef702 , 0x3006590a ] )
+  , Mont::unsafe_make( [ 0x4a8fa259 , 0x73c7decd , 0x1034623b , 0x6159717e , 0x1c97cd3a , 0x17669324 , 0x11570a8e , 0x19f9baa1 ] )
+  , Mont::unsafe_make( [ 0xfab1cec5 , 0xa4be7875 , 0x8c6eb124 , 0x60811225 , 0xc77e7de1 , 0x93518c40 , 0xf816f506 , 0x127fda1f ] )
+  , Mont::unsafe_make( [ 0x24734cc6 , 0xe75d9993 , 0xbd6b3243 , 0xa8facb82 , 0xbd0bf2e5 , 0x2429cde2 , 0x02f6ab12 , 0x0879f88d ] )
+  , Mont::unsafe_make( [ 0xdf8f43c3 , 0x482e8843 , 0x82fed748 , 0x304f6ef3 , 0x95c00ccc , 0x36221b7a , 0x4bfc0a41 , 0x1d485f02 ] )
+  , Mont::unsafe_make( [ 0xba4b0978 , 0x173b800e , 0xc0ef3972 , 0xa58ebb37 , 0xe717a6ca , 0xd0529c70 , 0xd04ac0e1 , 0x09a3e3b9 ] )
+  , Mont::unsafe_make( [ 0x35259f89 , 0xbaa28354 , 0x27d92068 , 0x9799ac9d , 0x022ee917 , 0x416ca7cd , 0x32e67374 , 0x2d8f77c7 ] )
+  , Mont::unsafe_make( [ 0x47b428a6 , 0xe9af3275 , 0xdc49198d , 0x860ac31d , 0x5c526ce6 , 0xfc8f8b17 , 0x19202768 , 0x2e6b088f ] )
+  , Mont::unsafe_make( [ 0xd4005c98 , 0xddc044bc , 0xce6e23d3 , 0x3a33a9ce , 0x81e71a1d , 0x8e5b8586 , 0x21f76773 , 0x2ed04d7f ] )
+  , Mont::unsafe_make( [ 0x569b1c6b , 0x690d0dce , 0xf21b0597 , 0x8a49400b , 0x2dbc5815 , 0x50f310a3 , 0xa8b1e9a0 , 0x2df41464 ] )
+  , Mont::unsafe_make( [ 0x74614bcf , 0xa3f74b54 , 0x063ab701 , 0xe3449c47 , 0xcfd092b6 , 0x64c6a4a8 , 0xa01074d4 , 0x07336d16 ] )
+  , Mont::unsafe_make( [ 0x496481a5 , 0x136205b9 , 0x848a64f9 , 0x978171c7 , 0x7b8df23b , 0x39582099 , 0xb906e40a , 0x2f3d1ae9 ] )
+  , Mont::unsafe_make( [ 0x154fd736 , 0x664ee2cc , 0x477e1295 , 0x6b1d0445 , 0xe9d500aa , 0x09380d3a , 0x82265362 , 0x170e50ea ] )
+  , Mont::unsafe_make( [ 0xec691925 , 0xb469f6b9 , 0x6a870f21 , 0x08e50105 , 0xa40ee1c4 , 0xabeff5b3 , 0x0e97f4ef , 0x02986131 ] )
+  , Mont::unsafe_make( [ 0x9916946a , 0x6675c7c7 , 0xe4a33656 , 0xc2d3f589 , 0x062aa576 , 0xd4ac3567 , 0x8ea57a37 , 0x06f70041 ] )
+  , Mont::unsafe_make( [ 0x38d0a4b0 , 0x072af406 , 0x4aae0abe , 0xea3e1d20 , 0x8c49d86f , 0xfb6a10a3 , 0x1ed982d3 , 0x01c8f0b6 ] )
+  , Mont::unsafe_make( [ 0xe2dd62bf , 0x41652d74 , 0xbf4d0faa , 0x0dff3f54 , 0x9687be74 , 0x753f1d2c , 0xf086c42d , 0x12c39608 ] )
+  , Mont::unsafe_make( [ 0x4b46ab1f , 0x98097c2e , 0x990e7303 , 0x3e7d8cca , 0x8f9af691 , 0x81b26e6a , 0xe5d1f010 , 0x2b3af7ed ] )
+  , Mont::unsafe_make( [ 0xa50dcf7f , 0x6c03a10f , 0x24163af6 , 0x7e1b9f19 , 0x8c62318d , 0x7f8c74f4 , 0x77d5b2c6 , 0x2660ebcb ] )
+  , Mont::unsafe_make( [ 0x152d1605 , 0xd697e27b , 0xaff6698c , 0x81dfea18 , 0x5d3b9304 , 0xf7bb8c4a , 0x862d9db0 , 0x12a26ba5 ] )
+  , Mont::unsafe_make( [ 0x77557a98 , 0x2d03ca9f , 0x14b1a126 , 0xfc842634 , 0xcfb48b19 , 0xbf15043e , 0xc8f0e4bc , 0x03099d68 ] )
+  , Mont::unsafe_make( [ 0xa79639c8 , 0x5d73e4cd , 0xf21b834b , 0x23a223e0 , 0x15ff9192 , 0x9cc8816f , 0x5636c671 , 0x1ade4942 ] )
+  , Mont::unsafe_make( [ 0x76e6f635 , 0x35b941fe , 0xe4af0277 , 0xcc878ce4 , 0x999f4d91 , 0xf59adeb4 , 0x3a77e31c , 0x09eb1830 ] )
+  , Mont::unsafe_make( [ 0xd365ba3c , 0x8698b056 , 0xda39e080 , 0x4a61f1b6 , 0xd54ca57c , 0x0026f6c6 , 0x241e9b78 , 0x000fec03 ] )
+  , Mont::unsafe_make( [ 0x26876d11 , 0x7a00811b , 0x81b7ccc7 , 0xeea2f628 , 0x935e9f8b , 0x7211d5cf , 0xa2b9fe7d , 0x00b4d8da ] )
+  , Mont::unsafe_make( [ 0x086ab5cf , 0x21199424 , 0xb1b09a7f , 0xbed640e2 , 0x9633eee9 , 0x8c7cc1c0 , 0xf07c0730 , 0x13110d79 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xd0f88699 , 0x4175152f , 0xee413549 , 0xe8dcf345 , 0x07729ea3 , 0xf2d92e57 , 0x2d268957 , 0x07045343 ] )
+  , Mont::unsafe_make( [ 0x6fc65e4c , 0xba087233 , 0x6b4f88e7 , 0x7d550d84 , 0x8b657427 , 0x01645389 , 0x96a4ac55 , 0x17b3d821 ] )
+  , Mont::unsafe_make( [ 0xe4d2f663 , 0xa267300a , 0x6592cb59 , 0x11215d47 , 0x5bcfadff , 0xce6bcaa7 , 0x9ce5ab0d , 0x05b32910 ] )
+  , Mont::unsafe_make( [ 0xa3c5a87f , 0x7c55f5b2 , 0x1179b585 , 0x9ea413f1 , 0x269795f2 , 0xcf632edf , 0xf2374865 , 0x15734ad9 ] )
+  , Mont::unsafe_make( [ 0xdbea567b , 0x7678f6ec , 0x07ca4f16 , 0x79c4d07d , 0x23697a4b , 0xc4b2ec56 , 0x12fc5a18 , 0x203b62f9 ] )
+  , Mont::unsafe_make( [ 0xa84023ea , 0xa88c2606 , 0x1b047460 , 0xcb0348ba , 0x2c4dc0fa , 0x579b196f , 0x80ee735d , 0x066fa3f9 ] )
+  , Mont::unsafe_make( [ 0xb59751d3 , 0x555216e0 , 0xcd3d1f10 , 0xd5b9a34d , 0xa180e06f , 0x42e21f3c , 0x4d025123 , 0x2c70cc69 ] )
+  , Mont::unsafe_make( [ 0x8a77ea6e , 0x2e486537 , 0x468ed02c , 0x94a8a6de , 0x30736e09 , 0x05cc004b , 0xd6a47620 , 0x091e3975 ] )
+  , Mont::unsafe_make( [ 0x94e61974 , 0x54e49db0 , 0x20c7aee1 , 0x4c49bb54 , 0x4d47d235 , 0xe1c05e84 , 0x0f73462d , 0x09ceb470 ] )
+  , Mont::unsafe_make( [ 0x172c41f4 , 0x1851e9a3 , 0xb19ab878 , 0xd27055ed , 0x1ec4a844 , 0x5598fa38 , 0x7fd1ce67 , 0x304ac73a ] )
+  , Mont::unsafe_make( [ 0x0fd0de3e , 0x350de680 , 0xaa3fbfc0 , 0x4a641f66 , 0xe9d172f4 , 0x175958bf , 0x0cd50bb8 , 0x03369cb2 ] )
+  , Mont::unsafe_make( [ 0xc1b8686b , 0x3a5d752f , 0x60db1ec2 , 0xc47b242e , 0x8a0feb69 , 0x07fe1d24 , 0xa8dd5f2d , 0x2912938d ] )
+  , Mont::unsafe_make( [ 0xcb8567e8 , 0xa64d9b8b , 0x8fac284c , 0x7e53d42a , 0xf1f75648 , 0xf22197fc , 0x8ca27b22 , 0x2ee6a998 ] )
+  , Mont::unsafe_make( [ 0x4ffc8ed8 , 0xabb90aa3 , 0xaa7a8e3d , 0x49c813bd , 0x170c53ab , 0xfe39333d , 0xbb0b2f38 , 0x0eafae47 ] )
+  , Mont::unsafe_make( [ 0xfa869be4 , 0xb22be1fb , 0x29789818 , 0xb431c3aa , 0x6e5ae8ca , 0xa9448e41 , 0xe2a8393c , 0x0418f1ee ] )
+  , Mont::unsafe_make( [ 0x651173ba , 0xedbafa1d , 0x407a84fc , 0x1f2d04a8 , 0x006388f2 , 0x19a8032d , 0xcc5c1413 , 0x2c9c9323 ] )
+  , Mont::unsafe_make( [ 0x90415f03 , 0xc82b0b78 , 0xdc5f8cfa , 0x1020bf85 , 0x33f33ee0 , 0x6a498999 , 0x308f66e2 , 0x06e4dbad ] )
+  , Mont::unsafe_make( [ 0xb2fdf243 , 0x8ff7e6bc , 0x5f584096 , 0x43d6e701 , 0x4c4a5f9e , 0xd05b3131 , 0xcd65ecca , 0x1381c772 ] )
+  , Mont::unsafe_make( [ 0x6d5a8e2b , 0xd6584075 , 0x2bf883a6 , 0xd909a88c , 0x1ca40a45 , 0x327811fe , 0xd746d827 , 0x1293242a ] )
+  , Mont::unsafe_make( [ 0xb9925d42 , 0x01863e0f , 0xdedda5f8 , 0x9d35aaea , 0x038fb4f1 , 0x6ac84a29 , 0x4178bb2c , 0x170ab346 ] )
+  , Mont::unsafe_make( [ 0xf9b5a06c , 0x4795e511 , 0x5433f0d6 , 0x91fcebe5 , 0x042c730f , 0x60f9ca05 , 0x423109ee , 0x02af6268 ] )
+  , Mont::unsafe_make( [ 0x7e725897 , 0x19393250 , 0x317b0676 , 0xcfe116f7 , 0xa99994ca , 0x9b22a228 , 0xc52f8f74 , 0x01584e46 ] )
+  , Mont::unsafe_make( [ 0x5210df5a , 0x504b36c0 , 0x75cfef79 , 0x16386ee9 , 0xabcdb63d , 0xd616d237 , 0xe9974109 , 0x04a0d3f4 ] )
+  , Mont::unsafe_make( [ 0x096286ef , 0xa8b10fab , 0x22a87843 , 0x9367f7d7 , 0xa5033b67 , 0xe4d2b2e4 , 0xb33a8293 , 0x0d966da3 ] )
+  , Mont::unsafe_make( [ 0x1af16d1b , 0x4f417571 , 0x8f2ab9c0 , 0x80782324 , 0x1d6a8366 , 0x0febcdae , 0x42e58450 , 0x08670fbb ] )
+  , Mont::unsafe_make( [ 0xacc54a08 , 0xb5eaa356 , 0x42a7ddf9 , 0xa3b707c4 , 0x6009f2ee , 0x130ddaac , 0xe797c4a2 , 0x07d2067f ] )
+  , Mont::unsafe_make( [ 0x427c86d1 , 0x17ca1d2f , 0x2775ca6b , 0xc83f7374 , 0x4d149830 , 0x05363474 , 0xb9ed4323 , 0x159ee72d ] )
+  , Mont::unsafe_make( [ 0xacf6194e , 0xace4c2be , 0xe41524b5 , 0x84076e03 , 0xfb6e05f9 , 0x1c59e915 , 0xf434f2ce , 0x0474b03c ] )
+  , Mont::unsafe_make( [ 0xcd07770f , 0x5059e3a3 , 0xabfbad60 , 0xcca0b6b2 , 0x474482c0 , 0x6bb64476 , 0xf1fcda8d , 0x164b1986 ] )
+  , Mont::unsafe_make( [ 0xeaa58640 , 0x51722d99 , 0x8f58e874 , 0x0dd8044f , 0x73befa94 , 0xdd8bfe89 , 0x62e880a7 , 0x18e79728 ] )
+  , Mont::unsafe_make( [ 0xda7a776c , 0xe6e7c36d , 0xb277ef59 , 0x83c41385 , 0x67e3034e , 0xc02bf90b , 0x942ccc76 , 0x2ee7ed84 ] )
+  , Mont::unsafe_make( [ 0xdae9f115 , 0x5861d041 , 0x0f4c09e3 , 0xa5f87f48 , 0xd4aa1629 , 0x2b61e0c3 , 0x14305e59 , 0x00e50324 ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0xcd6e6017 , 0x3b869504 , 0xf2dc6e09 , 0x8a607fc7 , 0xdd2d8896 , 0x0e794715 , 0x4394f693 , 0x2f3ae14e ] )
+  , Mont::unsafe_make( [ 0x8897960e , 0xed4018ad , 0x2e995a97 , 0x5c2694ff , 0xe7c68bc4 , 0xc4be839d , 0x04d81109 , 0x2c1ba15f ] )
+  , Mont::unsafe_make( [ 0x2f4710e5 , 0x5b0b3516 , 0xa03bc42f , 0x04bcdf47 , 0xe589aa44 , 0x6a34be7d , 0xb06fda7b , 0x10f43184 ] )
+  , Mont::unsafe_make( [ 0xbe211802 , 0xfa75785d , 0x3ac026ab , 0x92e1048d , 0x592a9da2 , 0xb30f47a3 , 0x4dae86d8 , 0x2a17d2d1 ] )
+  , Mont::unsafe_make( [ 0x0ad0c8b9 , 0x55ae5383 , 0xa68898a0 , 0xb5936558 , 0xb38c9475 , 0xe2593998 , 0x11bee088 , 0x1a9f2295 ] )
+  , Mont::unsafe_make( [ 0x22557c30 , 0x3200db1d , 0x23b5aff7 , 0xde751e68 , 0xbdd23d54 , 0xa985c0c0 , 0x3ba0aef6 , 0x0babb821 ] )
+  , Mont::unsafe_make( [ 0x14bdccdf , 0xd0d3e660 , 0xb2d20b94 , 0xc3b79e39 , 0xb949b14f , 0xe7f2a879 , 0x35111cd9 , 0x26ba900f ] )
+  , Mont::unsafe_make( [ 0xe973ca5a , 0xa4fc0dd5 , 0x98399334 , 0x57abd710 , 0x97445d56 , 0xab2bf1c5 , 0x3e734110 , 0x0d8f3088 ] )
+  , Mont::unsafe_make( [ 0xe6dfb79b , 0x8af9b0ec , 0x2052e77d , 0xf82c1242 , 0x3fbbdc0f , 0x8ba1f500 , 0xf557a641 , 0x23b2994d ] )
+  , Mont::unsafe_make( [ 0x9aff1c49 , 0x18dae9d5 , 0x85e6080b , 0xbbf4256d , 0x8329d53c , 0xed335a13 , 0x4944a622 , 0x2e2927f3 ] )
+  , Mont::unsafe_make( [ 0x57a728ed , 0xed8d4abe , 0x15cc4456 , 0xe5cfcd0a , 0x4ee28f67 , 0x54ea114e , 0x835cc61d , 0x2f23bff3 ] )
+  , Mont::unsafe_make( [ 0xc66278d6 , 0xd3d5ca9d , 0x8912211f , 0x2f018d95 , 0xb278a177 , 0x40f7b02f , 0xfe72955f , 0x2684c302 ] )
+  , Mont::unsafe_make( [ 0x1c0a4866 , 0x33c43a3a , 0x05031ba4 , 0x5ad783a5 , 0xcfc79929 , 0x65dc6c54 , 0x00d91b47 , 0x1c576757 ] )
+  , Mont::unsafe_make( [ 0x173ef61a , 0x4e7bc1e1 , 0x769745fe , 0x4facfb5b , 0x7f54bd17 , 0x906f36ae , 0x1bdb0c14 , 0x1c28af8d ] )
+  , Mont::unsafe_make( [ 0xb650fc5e , 0x2f421440 , 0xb20dbbcc , 0x3d128bb1 , 0x1f082df4 , 0x785dd000 , 0x39a119aa , 0x19a3593c ] )
+  , Mont::unsafe_make( [ 0xbef1363e , 0x63985092 , 0x354a8443 , 0x4e873b1f , 0x2445b012 , 0xbc506820 , 0x713e74f4 , 0x26958f1a ] )
+  , Mont::unsafe_make( [ 0x2799763d , 0x6e0eb319 , 0xa0451b51 , 0x2398422b , 0x4ecd6f1f , 0x07140e78 , 0x4e49ad37 , 0x2f8cc775 ] )
+  , Mont::unsafe_make( [ 0x3591f5e5 , 0xfc1940ca , 0xfe3ae98b , 0x40c5e169 , 0x0dceba9d , 0x8cbdc45f , 0x571e2427 , 0x0d6ab584 ] )
+  , Mont::unsafe_make( [ 0x07bb33e5 , 0xcba24ab9 , 0x274860f7 , 0x68deedc9 , 0x29416c95 , 0x009a3006 , 0xe9ef2a47 , 0x0592f3a7 ] )
+  , Mont::unsafe_make( [ 0x031236c3 , 0xc3f1cdcd , 0xa9183a5b , 0xa0b00af2 , 0x85ecc025 , 0x66b70acd , 0x5e1956f8 , 0x22230087 ] )
+  , Mont::unsafe_make( [ 0xf9a9c7a2 , 0xce3dc6c9 , 0x9f7daf5f , 0x17f8d0af , 0x75346571 , 0x74e47d78 , 0xaabd6e6b , 0x029715d9 ] )
+  , Mont::unsafe_make( [ 0x8dc5504c , 0x7b089be3 , 0x941f7a20 , 0x25aebc09 , 0x75bae17c , 0x66c51874 , 0x6ddd5787 , 0x0c270f54 ] )
+  , Mont::unsafe_make( [ 0x3ad26bf7 , 0x9f689426 , 0x653252fc , 0x87eb2ccb , 0x99efd2fe , 0x4d833ab5 , 0xd6e3be1c , 0x2e191023 ] )
+  , Mont::unsafe_make( [ 0x46cb0be9 , 0x6e3450e9 , 0x1f005b27 , 0x7bc02298 , 0x4ce318d5 , 0x56b8088c , 0x66a33a11 , 0x2ec9b4ac ] )
+  , Mont::unsafe_make( [ 0x44e10a1c , 0xf83df563 , 0xed947575 , 0x68af06ef , 0xbbbdfc65 , 0x1c684bb1 , 0x93a7653b , 0x1c83c16e ] )
+  , Mont::unsafe_make( [ 0x557a9724 , 0xb83a7e0c , 0x03e03337 , 0x9c5fb57d , 0x7af22bf3 , 0x2fffc7f0 , 0xe8da1d91 , 0x2b3fc48e ] )
+  , Mont::unsafe_make( [ 0xcaff50a7 , 0x6801cd41 , 0x02ffe097 , 0x4abe22fa , 0xf8001391 , 0xfa760075 , 0x67421305 , 0x0e26bb2c ] )
+  , Mont::unsafe_make( [ 0xd5fff778 , 0x7e07da17 , 0x20ac733c , 0xbd3933e2 , 0xf1957914 , 0x9758ecd0 , 0xb7ca931a , 0x06b43324 ] )
+  , Mont::unsafe_make( [ 0x66205f67 , 0x190273d7 , 0x36823203 , 0xed5b2cef , 0xd1777f85 , 0x4e021046 , 0xc020f051 , 0x17e3b29f ] )
+  , Mont::unsafe_make( [ 0x9ea928d0 , 0x2cb7e6b2 , 0xc7eff3b5 , 0x669f92dc , 0x59e88f0d , 0x1ce8d6ad , 0xf295376d , 0x007a5001 ] )
+  , Mont::unsafe_make( [ 0x30e1c92e , 0x1fa37c19 , 0x9e150672 , 0x0ccb0929 , 0xa65633ad , 0x9cd6aaca , 0x68fb1f42 , 0x064a8a52 ] )
+  , Mont::unsafe_make( [ 0x27bf08cc , 0x8bf608e3 , 0x7bcbeb62 , 0xa38368ae , 0x4cf9bc61 , 0x5873fcc3 , 0xd317d3f4 , 0x2ba9931d ] )
+  , Mont::unsafe_make( [ 0xf29de6d9 , 0x6837f3bd , 0x6c973b22 , 0xa7810f92 , 0x7bff5986 , 0x8e509170 , 0x6901246a , 0x215e61ea ] )
+  , Mont::unsafe_make( [ 0x66e01423 , 0x2f5c4f37 , 0x16393aa5 , 0x080704a8 , 0x17ec81d5 , 0x36ce0a1c , 0xf5496390 , 0x17ba8532 ] )
+  , Mont::unsafe_make( [ 0x9eab0c75 , 0x1cfcf458 , 0x4b9bac69 , 0xa7d7260c , 0x4d4356b1 , 0xb851dc24 , 0x5a4140f7 , 0x08180b44 ] )
+  , Mont::unsafe_make( [ 0x24a129e0 , 0xbf267edd , 0x09dd669f , 0xa1d2d853 , 0x9509f6fe , 0x24c3e119 , 0xa4aab42d , 0x0778202f ] )
+  , Mont::unsafe_make( [ 0x4a27863b , 0xe5f6f1ad , 0x39359c7f , 0xce4dc625 , 0x52d89a33 , 0x78c3b5f3 , 0x57ef252f , 0x27a9a9f7 ] )
+  , Mont::unsafe_make( [ 0x5bfc4dd4 , 0xd3c2c29d , 0xa3badfff , 0x25763436 , 0xdcb359b2 , 0xd90e560a , 0xbaf45188 , 0x28e151b0 ] )
+  , Mont::unsafe_make( [ 0x72091a69 , 0x81cabf8e , 0x59b581bc , 0xc937d808 , 0x6699d59a , 0x319c11ae , 0xa029b9f6 , 0x12997c57 ] )
+  , Mont::unsafe_make( [ 0x6dfae378 , 0x749ef8fb , 0x4142587f , 0xc9e95f6c , 0x0c72dbfa , 0x2b2b00c5 , 0xb3ce7052 , 0x21f367f0 ] )
+  , Mont::unsafe_make( [ 0xd0216c39 , 0xa4c64834 , 0x0fcc3095 , 0x2adaa7ad , 0xe4877873 , 0xe50f1bdf , 0xb0d2fbb1 , 0x153f20c1 ] )
+  , Mont::unsafe_make( [ 0xd4c29330 , 0xde0cee21 , 0x4ddf86b6 , 0xe0a4ef43 , 0x4c7f3fe8 , 0xb81d9542 , 0x01de16be , 0x13f0d051 ] )
+  , Mont::unsafe_make( [ 0xe5c0f997 , 0x4e76e728 , 0xd004cd20 , 0x1e575747 , 0xa3f2b32f , 0x1dcc5226 , 0x73895d75 , 0x2e7c5788 ] )
+  , Mont::unsafe_make( [ 0xed62d681 , 0x08e7d80f , 0xb9642e62 , 0xbee6f1c8 , 0xb0d96e82 , 0x3665b6bc , 0xe632f7ef , 0x1d60aea6 ] )
+  , Mont::unsafe_make( [ 0xdb1f05dd , 0xa0784bc2 , 0xa7e0505c , 0x1e417a77 , 0x78c1bbaa , 0x34d590c8 , 0x4b1bfd1a , 0x07196a6a ] )
+  , Mont::unsafe_make( [ 0xda54aa9c , 0x68d756c0 , 0xdd213854 , 0x2ef4f387 , 0x0d7ed794 , 0xaa01dfc0 , 0x568e5ee3 , 0x17519f10 ] )
+  , Mont::unsafe_make( [ 0xa67874c0 , 0x44c554c4 , 0xa6e8b8f5 , 0x0b57cdd1 , 0xc7d6cbe5 , 0x6c3f3961 , 0x06baa446 , 0x06e793a5 ] )
+  , Mont::unsafe_make( [ 0xcab7991f , 0x706bd5f1 , 0x02231af9 , 0xa6c31e37 , 0x2c36941e , 0x796ea18f , 0x802bff9c , 0x279acb82 ] )
+  , Mont::unsafe_make( [ 0x2d32cf95 , 0xf1da0e2c , 0xc4335fa5 , 0xe24a351e , 0x2ade541c , 0x9aa73108 , 0x382ffb34 , 0x25624f0d ] )
+  , Mont::unsafe_make( [ 0x144d605e , 0xadd6db61 , 0x74e7c82b , 0x20bbee6c , 0x7b627e2e , 0xa2a71d61 , 0x75fd8bc5 , 0x14bd5bc2 ] )
+  , Mont::unsafe_make( [ 0xc7a602e9 , 0xe3e1d438 , 0xba5517ff , 0x24ba1543 , 0x80e485da , 0x0b60b8c4 , 0xc7b614f1 , 0x2fb664db ] )
+  , Mont::unsafe_make( [ 0xa54eb27b , 0x87251d38 , 0x6105c773 , 0x518f2c73 , 0x8387f6c7 , 0xed0fb65b , 0x10fbf3d5 , 0x0c7edd45 ] )
+  , Mont::unsafe_make( [ 0x867ee852 , 0xbfecc666 , 0x22a37175 , 0x8dcbb13b , 0xcd684669 , 0xeb82f589 , 0x991b85ce , 0x2638fcaa ] )
+  , Mont::unsafe_make( [ 0x77fd0bfd , 0x69b9d0ac , 0xed93f198 , 0x4ed777ef , 0x5d79ef35 , 0x7d905f08 , 0x56eebcaf , 0x1aa80bc6 ] )
+  , Mont::unsafe_make( [ 0xf0e4f062 , 0xade73be8 , 0x06d579b8 , 0x8f97706e , 0x18f003f4 , 0x770a2ad4 , 0x07389f3c , 0x185cdbb3 ] )
+  , Mont::unsafe_make( [ 0x08e83e6a , 0x367583d8 , 0xdeb2ea92 , 0xcbe55ee5 , 0xb4462849 , 0x57a6dd91 , 0x1291c9f0 , 0x0ed9c011 ] )
+  , Mont::unsafe_make( [ 0xde90f86d , 0xa977d1a6 , 0x1e53776f , 0x856729af , 0xe6ef676a , 0xa6bc20de , 0x4911ea76 , 0x0056a0e7 ] )
+  , Mont::unsafe_make( [ 0x27ed2ffd , 0x28c7de0c , 0x4b9a0d09 , 0x4b4d4ae7 , 0x96b58aa0 , 0xaf39dbb0 , 0xe1a45038 , 0x1317a09a ] )
+  , Mont::unsafe_make( [ 0x21b0a895 , 0x2ab2f07b , 0x1fe99be0 , 0x9b872ef2 , 0x33b1cd08 , 0xcf351661 , 0x45efb142 , 0x0575da2a ] )
+  , Mont::unsafe_make( [ 0x79066b4a , 0x1c163b35 , 0x89581996 , 0x66f1d50c , 0x3c07b220 , 0x57bbd928 , 0xd57c07af , 0x2f984d0c ] )
+  , Mont::unsafe_make( [ 0xf8866607 , 0x90a7899c , 0xc3ae1ff4 , 0xb67b1af1 , 0x136a3c70 , 0x89e64c3d , 0x44f60d4b , 0x087dc521 ] )
+  , Mont::unsafe_make( [ 0xd62a0d1b , 0xd87a41f8 , 0x0ddc5fbb , 0x33eec69b , 0x36a9ae2f , 0xb08618e6 , 0x285da1a9 , 0x07ac3862 ] )
+  , Mont::unsafe_make( [ 0xa76deaf3 , 0x9d839df5 , 0x7de76fd0 , 0x03709cf0 , 0xe6d8b4c3 , 0x60f9f1e4 , 0x50ffa113 , 0x0f7a5748 ] )
+  , Mont::unsafe_make( [ 0xecdbcd8b , 0x95bad511 , 0x3a8fac2c , 0xcc9bd8d2 , 0x588bbbfb , 0xdee9be8f , 0xd6d97429 , 0x19e231af ] )
+  , Mont::unsafe_make( [ 0xb3ba2d06 , 0x21088edc , 0x2f148643 , 0x37ff596c , 0x904c58c5 , 0xc923354b , 0x99d5443d , 0x2caab928 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
@@ -0,0 +1,872 @@
+
+// circomlib's Poseidon constants for t=6
+
+use crate::bn254::montgomery::*;
+
+
+//------------------------------------------------------------------------------
+
+// round constants (t for external, 1 for internal rounds; flattened)
+pub const CONST_C: [Mont; 108] = 
+  [ Mont::unsafe_make( [ 0xb3b06f10 , 0x3966746e , 0xc0f39fd5 , 0x67c6466f , 0xc85d5250 , 0x3a03de41 , 0x544d1217 , 0x0d857a86 ] )
+  , Mont::unsafe_make( [ 0xda885fac , 0xbc41a286 , 0x57aba248 , 0x7a38ffb8 , 0x1d795ad9 , 0xb512f729 , 0x7c13d076 , 0x10fb7fec ] )
+  , Mont::unsafe_make( [ 0xdea84ce0 , 0x6d1d4964 , 0xc2f648e3 , 0x8d4a87de , 0x414bfd32 , 0xa40f4401 , 0x0ef4dbe9 , 0x2dc18639 ] )
+  , Mont::unsafe_make( [ 0x512ca337 , 0x18a8327c , 0x32ea851f , 0xb086d4a5 , 0x34619d2f , 0x9ccdfb6b , 0xb701f891 , 0x2e597226 ] )
+  , Mont::unsafe_make( [ 0x7682efd7 , 0x08bc1e35 , 0x245550ba , 0x32233461 , 0x566b525c , 0xaafed5b7 , 0x7aad0a7c , 0x1df63ae0 ] )
+  , Mont::unsafe_make( [ 0xbdd0b678 , 0x7a670fa6 , 0x4768646c , 0x47d788f2 , 0xad8e8097 , 0xd9afdd5f , 0x958b58b4 , 0x16a41755 ] )
+  , Mont::unsafe_make( [ 0x72f05e03 , 0x5770b521 , 0x3d2ab605 , 0xb83d7ea3 , 0xeb5d8c42 , 0xbd3d9bc3 , 0xc91ed350 , 0x2ffe4daf ] )
+  , Mont::unsafe_make( [ 0x63e07798 , 0xfe789a10 , 0xa241183b , 0xa7067921 , 0xfada62c3 , 0xd1624c0d , 0x18f3436e , 0x03adb8af ] )
+  , Mont::unsafe_make( [ 0xc3495b75 , 0xc7135adc , 0x7d3dd433 , 0xb5f64f60 , 0xa4f28250 , 0x138a5bab , 0x2bd744b6 , 0x149fffaa ] )
+  , Mont::unsafe_make( [ 0x5a07d9f1 , 0x4d2a0439 , 0x5a5444e9 , 0xe8692cb5 , 0xdd5c9327 , 0xb73f3984 , 0xe78465c3 , 0x00656603 ] )
+  , Mont::unsafe_make( [ 0x34258f91 , 0x1a7acb3d , 0x9446e069 , 0xa6452ee9 , 0x85595da0 , 0xcf4cca19 , 0x4de7e3c7 , 0x25d1f1d6 ] )
+  , Mont::unsafe_make( [ 0x0a0b3762 , 0xf817bc3a , 0xef099da1 , 0x7605766f , 0xa5ccc73a , 0x1285eb81 , 0x94e24b1e , 0x13798ae1 ] )
+  , Mont::unsafe_make( [ 0x87d9f391 , 0xc7f44513 , 0x81cf873c , 0x824eb102 , 0xc54441e0 , 0xf0f1be04 , 0x493085dd , 0x2e33a916 ] )
+  , Mont::unsafe_make( [ 0xeb1804bf , 0x834492a6 , 0x979c86d4 , 0x6d3f9058 , 0xb25f95dd , 0x86da725c , 0xbdaec685 , 0x0317958e ] )
+  , Mont::unsafe_make( [ 0x6a89b4f3 , 0x5e3631bc , 0x142c78de , 0x93b346cd , 0x4678600f , 0x021eb6b6 , 0x4ee57898 , 0x14cef02f ] )
+  , Mont::unsafe_make( [ 0x75252c51 , 0x530e4065 , 0xf8339b27 , 0xdc24681c , 0xf93ef70f , 0x5f6c6d8d , 0x82068118 , 0x24b603be ] )
+  , Mont::unsafe_make( [ 0x9abbfc12 , 0xadfba138 , 0xcab3ef40 , 0x504d9ebe , 0x93162a25 , 0x9b9fcd78 , 0x3d3d16e9 , 0x0e882b28 ] )
+  , Mont::unsafe_make( [ 0x66580e4b , 0x2fd28cd2 , 0x9937ca03 , 0x75f45708 , 0x8dd5b07b , 0x232502da , 0xd6d8318a , 0x050bec51 ] )
+  , Mont::unsafe_make( [ 0xe7c52cd4 , 0x95017f94 , 0xa88cb70e , 0xe8061ccc , 0x72d47406 , 0xc0e004dc , 0x3c644edb , 0x0f8be95d ] )
+  , Mont::unsafe_make( [ 0x810a0d92 , 0x95c74bda , 0x89a9f9ec , 0x9e364f54 , 0x37eabf4c , 0xd3f3e864 , 0xbeb01c02 , 0x1c12fe7f ] )
+  , Mont::unsafe_make( [ 0xb4edd455 , 0x690b5372 , 0x85da0166 , 0xf48d7c63 , 0x4d7b10c4 , 0xa69757fa , 0xa0d59306 , 0x07c4a92b ] )
+  , Mont::unsafe_make( [ 0x5807cdbe , 0x0a305a24 , 0xd2eb3ec2 , 0xa1e6ae57 , 0xb817c178 , 0x9aacd0f6 , 0xe05d7536 , 0x02f9cc2d ] )
+  , Mont::unsafe_make( [ 0x31de9c0c , 0x6919471d , 0x2c7cef2f , 0x1c3a3296 , 0x7a6d96e8 , 0xab4c65c4 , 0x79637e0c , 0x0682869f ] )
+  , Mont::unsafe_make( [ 0xf62e424a , 0x0b03d56c , 0x3cda3f20 , 0x0690ee82 , 0x0131051b , 0x87939754 , 0x4e3fc48b , 0x2bbf60c0 ] )
+  , Mont::unsafe_make( [ 0x353a81af , 0x0f3528b7 , 0x064930f7 , 0x72501911 , 0xdabafe1b , 0x5c6add64 , 0x9ae34a6e , 0x142b5b98 ] )
+  , Mont::unsafe_make( [ 0x74fe938d , 0x9bd8200e , 0x55e4b2aa , 0xfc4b7eb1 , 0x41c52a2a , 0x35aa2342 , 0x6cf45787 , 0x0e7eda7d ] )
+  , Mont::unsafe_make( [ 0xf7d262fa , 0x8c4f5884 , 0xcbb18017 , 0x08bcec53 , 0x26fe6d6a , 0xf07feac5 , 0xcc99a0ec , 0x2e61aa6c ] )
+  , Mont::unsafe_make( [ 0x7b144cee , 0x13a09aae , 0xd59a5881 , 0xc1af368a , 0xbf3db790 , 0x5e96fc14 , 0xf03672fe , 0x15f359e1 ] )
+  , Mont::unsafe_make( [ 0x778cd920 , 0x26c588f2 , 0xc9d97bd5 , 0xfc5c754c , 0xcb761fd3 , 0x31981920 , 0xaccaa423 , 0x196858ee ] )
+  , Mont::unsafe_make( [ 0x55c26ca1 , 0x75a4fbed , 0xab6a905e , 0xf3e8edfc , 0x33e69027 , 0x53226f79 , 0xe3344ffc , 0x26a91350 ] )
+  , Mont::unsafe_make( [ 0x577bb9e9 , 0x69a1b625 , 0x89937050 , 0x31d78e85 , 0xac39e8df , 0x5531e703 , 0x012e2197 , 0x1a40c5ed ] )
+  , Mont::unsafe_make( [ 0x90e72a88 , 0x5fb92489 , 0xc86fa04a , 0x25091f08 , 0xfa09d918 , 0x9642a74c , 0x35121b13 , 0x1ccfd222 ] )
+  , Mont::unsafe_make( [ 0x6cabe593 , 0xa3d22b22 , 0x73a91750 , 0xdcde08cd , 0x41d455ef , 0xe9132a49 , 0xb50adddf , 0x2727c342 ] )
+  , Mont::unsafe_make( [ 0x7d65aa1e , 0x070c6b25 , 0x80a796c7 , 0x753f78af , 0xe3795e90 , 0x331a5762 , 0x14609fe4 , 0x18bcc2cc ] )
+  , Mont::unsafe_make( [ 0x2885919d , 0x487e179f , 0xa85e2c03 , 0xa5304af9 , 0x9fdee38c , 0x60e6199f , 0xbc7f7de5 , 0x1561cae8 ] )
+  , Mont::unsafe_make( [ 0x7b66f754 , 0x1f331d9a , 0xb5e2f092 , 0x68906623 , 0x5b83c81a , 0x3d9be482 , 0xd291cdc6 , 0x09e2f285 ] )
+  , Mont::unsafe_make( [ 0xfeea13ad , 0x63bc5e26 , 0x65cf9c3c , 0xba5cc2bf , 0x3dcacf4a , 0x31203390 , 0x425b4f5c , 0x092db083 ] )
+  , Mont::unsafe_make( [ 0x404b5b08 , 0x19d5a7cd , 0xe5d1b7b9 , 0x1698f00b , 0xb372cab9 , 0xf2e5d3d1 , 0xd9640320 , 0x03c1e484 ] )
+  , Mont::unsafe_make( [ 0x11e22145 , 0x09950be7 , 0x41debcc0 , 0xa352f107 , 0xe047db0c , 0xc917bd49 , 0x7addb36b , 0x0fdf9e27 ] )
+  , Mont::unsafe_make( [ 0xe26b25a7 , 0x2cd568d5 , 0x24e7b5ee , 0xf8d215ac , 0xe2a96473 , 0x8c426319 , 0x2c8eba34 , 0x25437b13 ] )
+  , Mont::unsafe_make( [ 0x29c5a707 , 0x9c4a8575 , 0xa2254278 , 0x610ceeeb , 0xd68aa397 , 0x4a854039 , 0x3f919f6d , 0x15de65e9 ] )
+  , Mont::unsafe_make( [ 0xed41f3b8 , 0x4c8fdc31 , 0xe81c47c8 , 0x67707f6b , 0x59e0ed77 , 0xc5657bf6 , 0x8b060eb7 , 0x120f0cdc ] )
+  , Mont::unsafe_make( [ 0x53b402e5 , 0x1955c026 , 0x268f194a , 0x820f1cc6 , 0x31ef3614 , 0x72328704 , 0x830bdbb5 , 0x04544eec ] )
+  , Mont::unsafe_make( [ 0x6ac46502 , 0x809bb422 , 0x6d1db7ca , 0xb6166210 , 0x3c806046 , 0x42feb2e5 , 0xa361187e , 0x15606a69 ] )
+  , Mont::unsafe_make( [ 0x73faa351 , 0xa0fd9ad2 , 0xe4b52aaf , 0xc8aad486 , 0x6fc58c78 , 0x7a463af3 , 0xe7ec8182 , 0x1139556b ] )
+  , Mont::unsafe_make( [ 0x7e51829c , 0xf21c3ac5 , 0x1ba71e17 , 0x58d3daab , 0x9f3c2ece , 0x5f41e7e5 , 0xf8dee011 , 0x26e16d34 ] )
+  , Mont::unsafe_make( [ 0x77d20120 , 0x1de6d530 , 0xd049a31d , 0x36afcf64 , 0x2508920e , 0xb88de27b , 0x05dbfeab , 0x1b07cb6e ] )
+  , Mont::unsafe_make( [ 0xac122b66 , 0x46ee2235 , 0x32922c43 , 0x3aa1283f , 0x9e7962ef , 0x8658e151 , 0x1ed428ee , 0x1aafaf98 ] )
+  , Mont::unsafe_make( [ 0x5264bdad , 0x75b8cb9f , 0xe2c31979 , 0x3bc775c5 , 0x6cb01983 , 0xf6cc6643 , 0xe0a3ba49 , 0x10bcc19f ] )
+  , Mont::unsafe_make( [ 0x3f2c405e , 0xd0b1a312 , 0xa13aa47d , 0x3dbc1b5c , 0x2c229087 , 0x04283505 , 0x40344bfb , 0x09eff172 ] )
+  , Mont::unsafe_make( [ 0xcdc2b19c , 0x3dfc2172 , 0x3ecfbaaa , 0x753f5fbd , 0xa657b4a0 , 0xe127231b , 0x9509ab21 , 0x0ca66ea8 ] )
+  , Mont::unsafe_make( [ 0x94d45fbb , 0xc4707386 , 0x039ccc47 , 0x1fd50b52 , 0xdbb164e9 , 0xc63dffb1 , 0x2b5acc57 , 0x083a2112 ] )
+  , Mont::unsafe_make( [ 0x537c5bd0 , 0xc9e3da66 , 0x0e404d00 , 0x91e45d40 , 0x0b5d6c38 , 0x4e15a83d , 0xa6bce5dd , 0x0e287e9b ] )
+  , Mont::unsafe_make( [ 0x7b9edb69 , 0x648c87a6 , 0x876aaca2 , 0xd7acf1e5 , 0xe104ae91 , 0xc6aeac70 , 0xc97c7795 , 0x1ef8bb95 ] )
+  , Mont::unsafe_make( [ 0x8eafe5f7 , 0x6d2fd9c9 , 0x653d4249 , 0xa52283a4 , 0x7274c0e7 , 0xa1e13b76 , 0xea7503a7 , 0x2f2a493f ] )
+  , Mont::unsafe_make( [ 0x3ec8a0ee , 0x8a24fdd2 , 0xf421cc29 , 0xb3311e84 , 0x6debaec5 , 0x6a95589d , 0x807f00cd , 0x20849eee ] )
+  , Mont::unsafe_make( [ 0xcff493a3 , 0xbe48530d , 0x44cfc6fe , 0x114314bf , 0xecc0ff5f , 0x84090f8b , 0xe4f7458c , 0x138f0156 ] )
+  , Mont::unsafe_make( [ 0x9462cec6 , 0x014cd865 , 0x165595e2 , 0x08134589 , 0x42458f50 , 0x10a3cc97 , 0xa07d5373 , 0x0e801d29 ] )
+  , Mont::unsafe_make( [ 0x596c34c1 , 0x7312db32 , 0xb163b1d1 , 0x2b0e480e , 0x0813b89b , 0x33181af1 , 0xdbc7e918 , 0x1d291bd1 ] )
+  , Mont::unsafe_make( [ 0x89ba78d0 , 0xf61085d3 , 0xad9ecb06 , 0x6807902a , 0xebf2908e , 0xe4684163 , 0x6172dbb4 , 0x1ba79e0a ] )
+  , Mont::unsafe_make( [ 0x909cf3b9 , 0x4d9832f5 , 0x8ef2b7a5 , 0xa2927050 , 0xfebc4af0 , 0x8cfba28a , 0x7c18c5f1 , 0x0b12fd70 ] )
+  , Mont::unsafe_make( [ 0x2fdbb319 , 0xa80bc3cd , 0x250cdf08 , 0x28f497e5 , 0x9c78a0d3 , 0xb8b0aead , 0x418810c1 , 0x0f365462 ] )
+  , Mont::unsafe_make( [ 0x2a4de51b , 0x363de575 , 0x67b78df1 , 0x055e9d23 , 0xe7b9040f , 0x69696d5a , 0x726e088c , 0x2c6dcc8b ] )
+  , Mont::unsafe_make( [ 0x25b5d239 , 0xa54f6156 , 0x7890d05f , 0x3081c94b , 0x6c7b2d74 , 0x63934bc9 , 0xe9f8da2c , 0x0b803f21 ] )
+  , Mont::unsafe_make( [ 0x2fa26e4f , 0xeb523e2c , 0x1c59e0dd , 0x183e7c6d , 0xcae75157 , 0x09379ccd , 0xc990b28e , 0x1fcdf75b ] )
+  , Mont::unsafe_make( [ 0xe78e0bf2 , 0x618f7f55 , 0x39dcfa04 , 0x714f2bfa , 0xb1629325 , 0x15ffb8cb , 0x895d3eaa , 0x03e316d8 ] )
+  , Mont::unsafe_make( [ 0x820cda07 , 0xc1bc6dc3 , 0x9b5f85c5 , 0x86800d4e , 0x65bfbacb , 0x4fa30355 , 0x4e492dcb , 0x16d85948 ] )
+  , Mont::unsafe_make( [ 0xbb8abbaa , 0x0068145a , 0x41b39edc , 0x9fae8739 , 0x5022b9e4 , 0x33f08e90 , 0xa7dd4add , 0x2f2c97b2 ] )
+  , Mont::unsafe_make( [ 0x8bde7a3a , 0x3f77b1fb , 0x58fbe90a , 0x8e9b4082 , 0x616e6cbe , 0x414ea1e7 , 0x2019afa9 , 0x263aba17 ] )
+  , Mont::unsafe_make( [ 0xac330c84 , 0x0a145f32 , 0x5bb517ff , 0x05b24a3e , 0xeea13418 , 0x78272f56 , 0x3dd8b52d , 0x1720d38a ] )
+  , Mont::unsafe_make( [ 0xa21b8a82 , 0x24b78ca0 , 0x01598eef , 0x1d6ce16f , 0x37c8b904 , 0x9243eb48 , 0xb5403f81 , 0x1fc3b28b ] )
+  , Mont::unsafe_make( [ 0xcf9a8e18 , 0xee543e48 , 0x4ff93f38 , 0x93652302 , 0xfd9522ba , 0x7ee8d7bf , 0x9c230b15 , 0x070745d3 ] )
+  , Mont::unsafe_make( [ 0x92031d54 , 0x73e9cbf5 , 0x0c1c6768 , 0x9d637ba5 , 0x02df9b35 , 0x8e8f77d0 , 0x1f6dbef7 , 0x0a3f26ee ] )
+  , Mont::unsafe_make( [ 0x9e8782e9 , 0x38713077 , 0x2b9eacb2 , 0x63a2469b , 0x7ed10304 , 0x7c068dee , 0xb16106bb , 0x03cb7240 ] )
+  , Mont::unsafe_make( [ 0xfe45c768 , 0x82bafef4 , 0x1713349f , 0x5f33f4bf , 0x075a9997 , 0x665446e4 , 0xe26884cb , 0x15307985 ] )
+  , Mont::unsafe_make( [ 0x5421d86a , 0xd51c3040 , 0x3c3db464 , 0x70b23f58 , 0x9dd0fc5a , 0x146534ba , 0x9e235326 , 0x0b7b9467 ] )
+  , Mont::unsafe_make( [ 0x5c34648e , 0xba8e7501 , 0xfa877f0d , 0x6703a83e , 0x9b103904 , 0x4929190b , 0x069a1ad9 , 0x227cfe2c ] )
+  , Mont::unsafe_make( [ 0xe27ea7c4 , 0x4ba716c7 , 0x9fac6f70 , 0x830e9d6d , 0x15b06784 , 0xd4df4afd , 0xff83421b , 0x11acdd6d ] )
+  , Mont::unsafe_make( [ 0x2f36556d , 0x308ba2f3 , 0x23eec844 , 0xc3ac4eca , 0xb1b31102 , 0x53dc8428 , 0xe1e6c5b2 , 0x04331860 ] )
+  , Mont::unsafe_make( [ 0xa3cf3c35 , 0xf3743969 , 0x1fce9a1a , 0xb8e59d69 , 0xcf72729e , 0x1b19d798 , 0x17b6704d , 0x25c88503 ] )
+  , Mont::unsafe_make( [ 0x759d6ef5 , 0x7ade9400 , 0x322d6370 , 0x4d9bfa3e , 0x2042bc39 , 0xa05ea142 , 0x53d1c232 , 0x1b86463e ] )
+  , Mont::unsafe_make( [ 0xe564f0fa , 0xd762f013 , 0x75c38f87 , 0x640fb82c , 0xab049bd8 , 0xac143c03 , 0x12fa500d , 0x20dcd8d1 ] )
+  , Mont::unsafe_make( [ 0xd05eae87 , 0xd63241fb , 0x2c341be9 , 0x808573ae , 0x4dfaf567 , 0xec41b4a9 , 0x816b3da6 , 0x2df1c8e4 ] )
+  , Mont::unsafe_make( [ 0xe6e86745 , 0xaf1dc159 , 0xcbd15204 , 0x6557160f , 0x9855152d , 0x6a9b0259 , 0x8eec56ed , 0x114fc72f ] )
+  , Mont::unsafe_make( [ 0x7eb3105e , 0xa28e9d61 , 0xfeef2904 , 0xf6157a88 , 0xf8f7796b , 0xfd676102 , 0xaee2f3d8 , 0x18bb9b91 ] )
+  , Mont::unsafe_make( [ 0x4dfebc6c , 0x919ca365 , 0xb201f100 , 0x4c94b707 , 0xa84145d4 , 0x3dbfce01 , 0xf193db16 , 0x04600329 ] )
+  , Mont::unsafe_make( [ 0x737a7eef , 0x6086a39c , 0xe10b64c3 , 0xa5d60099 , 0x97da96bf , 0xcc34b721 , 0xd43a85d5 , 0x267c94e1 ] )
+  , Mont::unsafe_make( [ 0xf0a34481 , 0xa667da24 , 0x0aed9141 , 0x97004452 , 0x72af62e4 , 0xd79cb3b0 , 0x172d3d9c , 0x06443ec6 ] )
+  , Mont::unsafe_make( [ 0x70a904e4 , 0xce505e61 , 0xd2062af0 , 0xc176ee2c , 0x2cb04d3a , 0x5d8eab62 , 0xfa64a043 , 0x07927863 ] )
+  , Mont::unsafe_make( [ 0xb841201f , 0xb7a2e823 , 0xdb3bc2fb , 0x52f1543b , 0x6057dc46 , 0xd5b8f523 , 0x28235dc7 , 0x0c93f5ad ] )
+  , Mont::unsafe_make( [ 0xcd00b1d2 , 0x1297bf62 , 0x77ac47ab , 0x5632d703 , 0xbefa593f , 0x9df56e12 , 0xe2c0025c , 0x095d1013 ] )
+  , Mont::unsafe_make( [ 0x9e1b6ab4 , 0x3c4909cc , 0x635615c7 , 0x087aa883 , 0xf84d5af1 , 0x8c6ddcf1 , 0x20eea442 , 0x253b0955 ] )
+  , Mont::unsafe_make( [ 0x1fbcc8f4 , 0x9653f55e , 0x695e9ad9 , 0xafa705bb , 0xe519ba92 , 0x52284b22 , 0x5130d0f7 , 0x2bac9a27 ] )
+  , Mont::unsafe_make( [ 0xd182e2e2 , 0x5a97518e , 0x98729b02 , 0x369f07de , 0x928e2f47 , 0xd723214b , 0xa01317e8 , 0x131572ca ] )
+  , Mont::unsafe_make( [ 0x6f71e703 , 0xaad31fc9 , 0x9e237317 , 0x5822cc81 , 0x84525cc5 , 0x6fa327cc , 0xc4f0e140 , 0x23719e68 ] )
+  , Mont::unsafe_make( [ 0xaf01eac6 , 0x91889c36 , 0x2e282b18 , 0xdde092ee , 0x89bdf382 , 0xe7ba2060 , 0x4e75f99d , 0x09e54697 ] )
+  , Mont::unsafe_make( [ 0x92d74ca7 , 0x19edac78 , 0x8d749e87 , 0x823a3aa9 , 0xc0830221 , 0x142d5d30 , 0x5f4c5b33 , 0x0b966926 ] )
+  , Mont::unsafe_make( [ 0x5f6f5086 , 0x307dc675 , 0x1f5c8b31 , 0x3fb558e9 , 0x79f60767 , 0x801b4d78 , 0xf665c9dc , 0x14d8b7c3 ] )
+  , Mont::unsafe_make( [ 0x5b777950 , 0xff47342f , 0xdd1cfe9f , 0x84ff51f3 , 0x323bad6e , 0xf1164817 , 0x3a223756 , 0x167bce6a ] )
+  , Mont::unsafe_make( [ 0xbb2d28c5 , 0xeb252198 , 0x67466e97 , 0xb32e619b , 0x575e1f7d , 0x98db3fe8 , 0xf13226f0 , 0x20cfadb9 ] )
+  , Mont::unsafe_make( [ 0x1379aa56 , 0xcb2adf81 , 0x74a982df , 0x7f155c08 , 0x1f8e6039 , 0x3bd69f07 , 0x9a4106e7 , 0x04910b60 ] )
+  , Mont::unsafe_make( [ 0x5c3cedf1 , 0x1b162d1a , 0x52227795 , 0x9925278a , 0xd4aa301d , 0xca5faf67 , 0xfb568528 , 0x2c6a7e5c ] )
+  , Mont::unsafe_make( [ 0x65cd6f96 , 0x4c839363 , 0xce762b76 , 0x9dbf892c , 0xaa96edc1 , 0x12970b53 , 0xc038b12e , 0x2cc42371 ] )
+  , Mont::unsafe_make( [ 0x3bf3e815 , 0xe056ec24 , 0x8bbe6be4 , 0x58eddd9f , 0x9ca91b89 , 0x51758e71 , 0x6cd29832 , 0x1e97fca0 ] )
+  , Mont::unsafe_make( [ 0x4e3d673d , 0x962d090b , 0xbb7577ea , 0x40d006e5 , 0x322f31f4 , 0x44a7d4c9 , 0xc07d811a , 0x1352fb6e ] )
+  , Mont::unsafe_make( [ 0x5ee73a91 , 0x00b951b3 , 0x578acae4 , 0xa879e073 , 0x311ce91a , 0x0b3c360b , 0xfbc8ddb0 , 0x0bf7f6ba ] )
+  , Mont::unsafe_make( [ 0x79cd4a4a , 0x55348dbe , 0xfc390eb5 , 0xc1da53f5 , 0x15213643 , 0x258e63af , 0x670c49c7 , 0x2b771e56 ] )
+  , Mont::unsafe_make( [ 0xbc7ec255 , 0x68ae3c0a , 0xaec11a1d , 0x12ee75b7 , 0x86c16161 , 0x4f5cc073 , 0xd902242f , 0x0c006672 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// external mixing matrix (`t x t`, flattened)
+pub const CONST_M: [Mont; 36] = 
+  [ Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xd9992600 , 0xefda51ba , 0x76521488 , 0xb16fd81c , 0xc9ea82ab , 0x2cb484be , 0x8fa91fdf , 0x23da01c0 ] )
+  , Mont::unsafe_make( [ 0x1b61380e , 0x3e66e111 , 0x482fed80 , 0xb3bb4d0a , 0x8b3c87c3 , 0xf1fc52e4 , 0x270a7a26 , 0x29c2b69d ] )
+  , Mont::unsafe_make( [ 0xf72c8118 , 0xa891ce4c , 0xdca7b6dc , 0x05ea1137 , 0xb17f099b , 0x5d29edc5 , 0x4c751416 , 0x0d2055ef ] )
+  , Mont::unsafe_make( [ 0x3d059f09 , 0xd5084b98 , 0x0ef857ed , 0xb26e4cc7 , 0x71981283 , 0x4b26508a , 0x185ba003 , 0x2b1a7b81 ] )
+  , Mont::unsafe_make( [ 0x5961d9e4 , 0xa582444f , 0x27fd1e0a , 0xff793618 , 0x275f16e3 , 0x017b68f5 , 0x1a0f5f1f , 0x2cdb7c00 ] )
+  , Mont::unsafe_make( [ 0x60ba3373 , 0x1eb9eb4e , 0x6cd757d0 , 0xf0076556 , 0xdb55846f , 0x268dc55a , 0xa2aa48eb , 0x269cfc86 ] )
+  , Mont::unsafe_make( [ 0xfccd5f05 , 0x8ebbefa3 , 0x19dde090 , 0x5426dfcd , 0xa7e6d97e , 0x20be5e39 , 0x15f5e4b4 , 0x0f7c06a6 ] )
+  , Mont::unsafe_make( [ 0xec6e0ee3 , 0x3cc59fe2 , 0xb490e1ac , 0x37485e8b , 0xcc0c998e , 0x70f730f0 , 0x523b237d , 0x0c104d3d ] )
+  , Mont::unsafe_make( [ 0xd589914c , 0xfe40bc12 , 0x41012cab , 0xc80b5c96 , 0x9a0f2a09 , 0x06141dd4 , 0xd0fcf525 , 0x1801513d ] )
+  , Mont::unsafe_make( [ 0xb2f5e970 , 0x63cc2e19 , 0x5dadf3e2 , 0x627de97f , 0xfa8e4cfe , 0xbea9a303 , 0x12ef3efb , 0x04673cdd ] )
+  , Mont::unsafe_make( [ 0xf63a99b9 , 0x3f97d29c , 0x9f5d0179 , 0x585ce6f5 , 0x98bef091 , 0xb5eb9fe4 , 0x5939535b , 0x1def2825 ] )
+  , Mont::unsafe_make( [ 0xe707c83d , 0xfbd0fae3 , 0xbcdfee7a , 0x0747923d , 0xd47c883f , 0xe557147e , 0x4d18fe5f , 0x17e8bde0 ] )
+  , Mont::unsafe_make( [ 0x033d60f4 , 0x23d1b77b , 0xf771ca28 , 0x3a318f06 , 0x9c2a62cf , 0x27463793 , 0x65cf9e63 , 0x11a65374 ] )
+  , Mont::unsafe_make( [ 0x9b81b64d , 0x8766ea04 , 0x8f7ee7ff , 0xd7eb0b58 , 0x60b40d40 , 0xa680d523 , 0x59a7c287 , 0x2ca41a0d ] )
+  , Mont::unsafe_make( [ 0x35fcd279 , 0x5a828f0a , 0x376aa04c , 0x964872d3 , 0x51f0077d , 0x1b96474d , 0xb468d563 , 0x1865ba3d ] )
+  , Mont::unsafe_make( [ 0xa6c0667c , 0x1867682f , 0xf9ad0b1f , 0x85c52d48 , 0xd863a0b4 , 0x0ecad0b8 , 0x10a0fb6c , 0x2a8b3bd3 ] )
+  , Mont::unsafe_make( [ 0x12364a91 , 0xbf051a57 , 0x0036058f , 0x944cc312 , 0xd9ba986a , 0x009bc4bb , 0xe7583230 , 0x2f67f5aa ] )
+  , Mont::unsafe_make( [ 0xa957f067 , 0x7bc95074 , 0x793e04d2 , 0x92742eda , 0xbcea54eb , 0x8a21a997 , 0x751ec116 , 0x1940a532 ] )
+  , Mont::unsafe_make( [ 0xd5f9e814 , 0x484cc8e7 , 0x9b98a57b , 0xe648b135 , 0xef353142 , 0x3ff7f647 , 0xc20ff9ff , 0x27b31ee9 ] )
+  , Mont::unsafe_make( [ 0x919792ca , 0xf082905d , 0xe730d31b , 0x356f1731 , 0xa1ab56d5 , 0xb875ae04 , 0xad23b140 , 0x19ec9b0b ] )
+  , Mont::unsafe_make( [ 0xa26e38d7 , 0x6d095f01 , 0x41d1ee84 , 0xb0584782 , 0xbee0343e , 0x20fdad0c , 0x804448fe , 0x15e1aa2a ] )
+  , Mont::unsafe_make( [ 0x7b446a43 , 0xd0109ef6 , 0xcb2cf7bd , 0x8f1c5c34 , 0x1d70e88b , 0x2b943354 , 0x28cfe694 , 0x2f0a2102 ] )
+  , Mont::unsafe_make( [ 0x79578ecd , 0x93e0d9d2 , 0x0336c640 , 0x3f5900a1 , 0x0ae2c921 , 0x3c07e383 , 0x2ad30a18 , 0x26fb3f9e ] )
+  , Mont::unsafe_make( [ 0x5618e722 , 0x452d49d1 , 0x58c159cf , 0xf190e2a1 , 0xc2664285 , 0xf3872d51 , 0xbe9003c9 , 0x0178a647 ] )
+  , Mont::unsafe_make( [ 0x349ed952 , 0xb3bd0f39 , 0x8a75b33d , 0x7312b9eb , 0x22f8707b , 0xa4cb7e2f , 0xaf40c21d , 0x211e5f99 ] )
+  , Mont::unsafe_make( [ 0xa8ffd3d7 , 0xcb79be0c , 0x8721cb2d , 0xa44293e8 , 0x973a5e2e , 0x3b18a7d5 , 0xe637f10d , 0x02e98dd0 ] )
+  , Mont::unsafe_make( [ 0x24fb5920 , 0xf42b9ce4 , 0x6a5c0bbb , 0x04ce2081 , 0x771c499e , 0xbb768122 , 0xc72c04b4 , 0x029c56ea ] )
+  , Mont::unsafe_make( [ 0x2f851f60 , 0x415264bb , 0x5b764f1b , 0x654b6ae0 , 0xbf86cc97 , 0x95ec4302 , 0x53aa2132 , 0x2ff1febb ] )
+  , Mont::unsafe_make( [ 0x44be8ff5 , 0xb4e790ea , 0x64bee9d3 , 0xa263d62f , 0x1b5d3f9d , 0xfc321bdc , 0x0018c1d9 , 0x0acba80b ] )
+  , Mont::unsafe_make( [ 0xd3eddaf9 , 0xc7ffe564 , 0xb23d1285 , 0x35a45ad2 , 0x101207a9 , 0xb93fe800 , 0x33f34aad , 0x0e899bc0 ] )
+  , Mont::unsafe_make( [ 0xb960b0e9 , 0xe689b0b0 , 0x2b376397 , 0xa5965446 , 0x0456d0b5 , 0xc1b82be0 , 0xeb5e06f3 , 0x1e160fca ] )
+  , Mont::unsafe_make( [ 0x3a524eac , 0x3f4a96b4 , 0xc556059c , 0x0eae4179 , 0x842f2f44 , 0xe5f44612 , 0x9e3a2ebf , 0x1de7db92 ] )
+  , Mont::unsafe_make( [ 0xb40dea2f , 0x053ee9a8 , 0x6d015743 , 0x8c0f06c7 , 0x8da2d6b7 , 0x9fa29e57 , 0x9f8127f6 , 0x23b88cab ] )
+  , Mont::unsafe_make( [ 0x0180df7a , 0x77ace166 , 0x8991cad5 , 0x81e9f480 , 0xd60fe288 , 0x70948c9c , 0x00ff1a52 , 0x155f8347 ] )
+  , Mont::unsafe_make( [ 0x22396dee , 0x1cf38605 , 0xcc78648a , 0x6e8ac845 , 0x2c684b44 , 0x8befbc79 , 0xce3dace9 , 0x27d25218 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// internal mixing matrix (`t x t`s, flattened)
+pub const CONST_P: [Mont; 36] = 
+  [ Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xc910a73c , 0x373ebb2a , 0xe21847c2 , 0x186fac98 , 0x65898dc4 , 0xef7be589 , 0xbf092f33 , 0x10589862 ] )
+  , Mont::unsafe_make( [ 0x0f3bfa70 , 0x5ed01703 , 0xaba8cd5a , 0xda85d02f , 0x6e40391a , 0xd922173b , 0x2bb6bc88 , 0x210006c3 ] )
+  , Mont::unsafe_make( [ 0x4f5c4702 , 0xd6958552 , 0xc0ff74b3 , 0x4deff16a , 0xf432fcfe , 0x7e064453 , 0xc614180d , 0x1bfa6368 ] )
+  , Mont::unsafe_make( [ 0x8cbcc6e0 , 0x77c3c0e5 , 0x6cde277e , 0xbe89a7fd , 0x290dc805 , 0x5cecd011 , 0x733988e7 , 0x154016c1 ] )
+  , Mont::unsafe_make( [ 0xcef3b47a , 0xaf9d8d6e , 0xb1633fc7 , 0x19a964df , 0x538fa3a2 , 0x032a012f , 0x4a02f0fb , 0x2f27e1cf ] )
+  , Mont::unsafe_make( [ 0x60ba3373 , 0x1eb9eb4e , 0x6cd757d0 , 0xf0076556 , 0xdb55846f , 0x268dc55a , 0xa2aa48eb , 0x269cfc86 ] )
+  , Mont::unsafe_make( [ 0x6a6cb74d , 0xb363656a , 0x5efdf0ba , 0xf4b785c2 , 0xe7c45ff4 , 0x2a6984c5 , 0x48597819 , 0x1491b6ef ] )
+  , Mont::unsafe_make( [ 0x20fbc95f , 0x8e42b931 , 0x2f23a275 , 0x4264192a , 0x8ca5fb61 , 0x5bd3d345 , 0xb62424ee , 0x2dd3ae9b ] )
+  , Mont::unsafe_make( [ 0x41ab2a4f , 0xeb813a64 , 0x93fdfda8 , 0x70bd2d2f , 0x8beed1f7 , 0x9e124179 , 0xeef8fb8f , 0x1052ece7 ] )
+  , Mont::unsafe_make( [ 0xa2468064 , 0x0ed98951 , 0xb30a8d3d , 0x8ad97f4d , 0x9e63789f , 0x5a843c45 , 0xf7d59eea , 0x21058b91 ] )
+  , Mont::unsafe_make( [ 0x0799b0a7 , 0x161a18ce , 0xf699bbf9 , 0xafcf1d3f , 0x534e4c32 , 0x69c3d530 , 0x2f9012dc , 0x26710fa7 ] )
+  , Mont::unsafe_make( [ 0xe707c83d , 0xfbd0fae3 , 0xbcdfee7a , 0x0747923d , 0xd47c883f , 0xe557147e , 0x4d18fe5f , 0x17e8bde0 ] )
+  , Mont::unsafe_make( [ 0xe29e8ede , 0xb78f079e , 0xffc18f7b , 0xe953445a , 0x7e123f36 , 0xa927dbd5 , 0xdbf0c030 , 0x0d04b863 ] )
+  , Mont::unsafe_make( [ 0xf988c19e , 0xeaa2d3b8 , 0xd8fe7bf9 , 0xe213bd56 , 0x4618b672 , 0x27afdf39 , 0x7b91b817 , 0x024d1fe6 ] )
+  , Mont::unsafe_make( [ 0x2ef798cd , 0xf4175a7c , 0x356828dd , 0x9e19a519 , 0xbc208b72 , 0x988d1fb4 , 0x1a0bd5b7 , 0x04364799 ] )
+  , Mont::unsafe_make( [ 0xc34828c4 , 0x9ea6c62d , 0x7e64bc7e , 0x5a9c12b4 , 0x2a50e5e4 , 0x32c97832 , 0x33e1b4fe , 0x2a346375 ] )
+  , Mont::unsafe_make( [ 0x4b194f01 , 0xfe2accec , 0xd660bb88 , 0xa8c43a7d , 0x2ca486e0 , 0x7877e2b7 , 0xff398082 , 0x22cc44d4 ] )
+  , Mont::unsafe_make( [ 0xa957f067 , 0x7bc95074 , 0x793e04d2 , 0x92742eda , 0xbcea54eb , 0x8a21a997 , 0x751ec116 , 0x1940a532 ] )
+  , Mont::unsafe_make( [ 0x58f087b8 , 0x0336547a , 0xc1a3739b , 0x7fc66af9 , 0xd671438d , 0xcef15a71 , 0x5e08380c , 0x2e56bed9 ] )
+  , Mont::unsafe_make( [ 0xb09c5bf4 , 0x4a43e507 , 0x7673da78 , 0x408d7295 , 0xc9b67b91 , 0xeb622e4a , 0x91a547dc , 0x1b2d60f1 ] )
+  , Mont::unsafe_make( [ 0x8f7ba8c4 , 0x3e530421 , 0xda46048e , 0xd867352e , 0x5d2d43ba , 0x968213d3 , 0x6ec7d4a4 , 0x09b46d2f ] )
+  , Mont::unsafe_make( [ 0x6dd789d3 , 0x3dc1f529 , 0xd16f77ec , 0xad513b60 , 0x4fad81be , 0x9e6894fe , 0xef4e261f , 0x06bf096b ] )
+  , Mont::unsafe_make( [ 0xac0fc47c , 0x245117ff , 0x517f0e6f , 0x732e784b , 0xfac6c4d7 , 0x60e798e5 , 0xacd0f4a4 , 0x16f86cc5 ] )
+  , Mont::unsafe_make( [ 0x5618e722 , 0x452d49d1 , 0x58c159cf , 0xf190e2a1 , 0xc2664285 , 0xf3872d51 , 0xbe9003c9 , 0x0178a647 ] )
+  , Mont::unsafe_make( [ 0x1186908a , 0xfc47b812 , 0xa8575fce , 0xb4d07bd7 , 0x0c6ae446 , 0x8a8a149c , 0x9855fd95 , 0x13abeeae ] )
+  , Mont::unsafe_make( [ 0x15f97832 , 0x45c69867 , 0x516deefa , 0x86d2b6f5 , 0xdac5e382 , 0xfa21aad9 , 0xd341824b , 0x0d601008 ] )
+  , Mont::unsafe_make( [ 0xf0500774 , 0x80969a30 , 0x584fa8c5 , 0xf6a604ee , 0x151d6e29 , 0x8f249ee4 , 0xf17cb271 , 0x2b278bd9 ] )
+  , Mont::unsafe_make( [ 0x2e7a07dc , 0x79bf39a0 , 0xef7142aa , 0xebba9734 , 0xf899385b , 0x100448dd , 0x75492eaf , 0x26af7192 ] )
+  , Mont::unsafe_make( [ 0x269c4ce2 , 0x6ee40192 , 0x60457288 , 0x74136dfc , 0x64bb91f3 , 0x58881f79 , 0xf8dacd00 , 0x20a7182d ] )
+  , Mont::unsafe_make( [ 0xd3eddaf9 , 0xc7ffe564 , 0xb23d1285 , 0x35a45ad2 , 0x101207a9 , 0xb93fe800 , 0x33f34aad , 0x0e899bc0 ] )
+  , Mont::unsafe_make( [ 0x78a12f28 , 0x9a9c8a10 , 0x81bd43e2 , 0x40a30ac7 , 0xc0dc20df , 0x907f05d7 , 0x76031d3b , 0x1143b487 ] )
+  , Mont::unsafe_make( [ 0x63575ae7 , 0x9f54dfca , 0x9902be98 , 0x875e2fd4 , 0x93818155 , 0x2f680c4b , 0x50a41d01 , 0x07193967 ] )
+  , Mont::unsafe_make( [ 0x8e6977a3 , 0xddba3c54 , 0x43954246 , 0x021359f1 , 0x79038ae0 , 0x0b5ec591 , 0x2ae94242 , 0x18ee1b91 ] )
+  , Mont::unsafe_make( [ 0x6779bfb7 , 0xe87efc6b , 0xe3837f0a , 0xcd0556a0 , 0x1fdbc61d , 0xbb3c267f , 0xa7eeab5e , 0x05694a02 ] )
+  , Mont::unsafe_make( [ 0xf613c2e2 , 0x756af64e , 0xaa583de0 , 0x7b865aa7 , 0x66c79389 , 0x27615c66 , 0x9b896bb9 , 0x018216b8 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// circomlib's optimization whatever
+pub const CONST_S: [Mont; 660] = 
+  [ Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x46269a04 , 0xe56bada9 , 0x8cfb4d5b , 0xce84daf8 , 0x76d64747 , 0x9add3d85 , 0xf58e4f33 , 0x1fefeab9 ] )
+  , Mont::unsafe_make( [ 0x3e3ddf75 , 0x5c0fb8a5 , 0x1057f436 , 0xeaa5bb59 , 0xd89c2abb , 0x94c058ee , 0x0cac7c61 , 0x0e75a941 ] )
+  , Mont::unsafe_make( [ 0xcdaf06e8 , 0x18b77cd4 , 0x85c13cf0 , 0x16a9f1b9 , 0xa4c362c6 , 0x22ff79ea , 0x5b516547 , 0x0ef209a5 ] )
+  , Mont::unsafe_make( [ 0xdb5e61bd , 0x7ceb842f , 0x745e0d0f , 0x1f118ca8 , 0x7a1f49fc , 0x3d50a329 , 0x92afd3ff , 0x1441e309 ] )
+  , Mont::unsafe_make( [ 0x8941e081 , 0xd24b533b , 0x7d9cb302 , 0xb68dcdb6 , 0xa8f23d6e , 0x17a6dc2a , 0x2225f710 , 0x0abfacd9 ] )
+  , Mont::unsafe_make( [ 0x58a6fd97 , 0xdc94efaf , 0xd134abb1 , 0xf88f4227 , 0xdbf4f8eb , 0x4b932649 , 0xa22f7079 , 0x147cf45b ] )
+  , Mont::unsafe_make( [ 0xa3a96c85 , 0x82e94f6a , 0xe46e8675 , 0xb4f9f2fe , 0xc7c90bcb , 0x91a8c391 , 0x41fa66ac , 0x2e5f2dd8 ] )
+  , Mont::unsafe_make( [ 0x17c2f05f , 0x1af9ac84 , 0x0677e541 , 0x57258958 , 0x4958152e , 0xa1b65c24 , 0x47fbf2be , 0x01bcf467 ] )
+  , Mont::unsafe_make( [ 0x29741225 , 0x6bad7d4a , 0x406471d9 , 0x3e187dab , 0x83bbaac3 , 0x2ed33263 , 0xe88ef0c8 , 0x18773bb8 ] )
+  , Mont::unsafe_make( [ 0x9e88df6d , 0x7d716caf , 0x422afcef , 0x1740b8bd , 0x13109f9d , 0x6e557c2f , 0x98c29a22 , 0x1997725e ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x333b90d5 , 0x0534eba6 , 0x92953a95 , 0xde3b2b4a , 0x29e70511 , 0x806947d5 , 0x09467290 , 0x198b52a0 ] )
+  , Mont::unsafe_make( [ 0x2c3aa1a7 , 0x02db64e4 , 0x7a1d18da , 0x397184e2 , 0x9d0e9d56 , 0x7b4a4c46 , 0x04927a3d , 0x1f87cfa3 ] )
+  , Mont::unsafe_make( [ 0x33160cd1 , 0x669b619a , 0x779e2709 , 0xaddc967f , 0x1ebe2614 , 0xd47e2b3d , 0x43874c42 , 0x0c174041 ] )
+  , Mont::unsafe_make( [ 0xd446542a , 0x3e3c1a66 , 0x9fc39880 , 0x0bbe485d , 0x7df4fd91 , 0x57b47792 , 0xcc7b1b66 , 0x007d078a ] )
+  , Mont::unsafe_make( [ 0x51be88bd , 0x605d833b , 0xdde73a3a , 0x4fda3ee4 , 0x319547a6 , 0xe9795d58 , 0xe46a527b , 0x154059ae ] )
+  , Mont::unsafe_make( [ 0x638a0b12 , 0x1ccfbb72 , 0xec50b234 , 0x8a4fc9cf , 0xf143d336 , 0x149cef59 , 0xe3c0b9fa , 0x054dbf98 ] )
+  , Mont::unsafe_make( [ 0x8359695e , 0x2284ec70 , 0x71d9d1ee , 0xb8b9a869 , 0x7f4e596d , 0x5d78f741 , 0xe56da4a8 , 0x23257904 ] )
+  , Mont::unsafe_make( [ 0xd608a896 , 0x36c8d997 , 0x6510b478 , 0x58884869 , 0x11a2f6aa , 0xbf7cc5c2 , 0x31619a48 , 0x0717ba79 ] )
+  , Mont::unsafe_make( [ 0x5f9a60c2 , 0x567b5018 , 0xb8860038 , 0xb80c1125 , 0xe7759e4b , 0x3fd3b650 , 0xaf28d462 , 0x19bf29d8 ] )
+  , Mont::unsafe_make( [ 0x3573192d , 0x806c228e , 0xc9c6189a , 0xf6418abe , 0xe348bbb5 , 0xc71f4ee2 , 0x2e2a947b , 0x0e31e5ac ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x25a56157 , 0x2977bbb7 , 0x756ed1af , 0x38033b79 , 0x2762395d , 0x8c3e5bce , 0x34b8d6d0 , 0x0523ca74 ] )
+  , Mont::unsafe_make( [ 0x9376ebc8 , 0x3e535982 , 0x35c57ed4 , 0x73ca5e7e , 0x5a905422 , 0x26568b69 , 0xc0807674 , 0x143eb4e0 ] )
+  , Mont::unsafe_make( [ 0x6899ef7d , 0x3572f847 , 0x82ae29a1 , 0x6feec1c4 , 0x5367f8ed , 0xcfdffc90 , 0x95ffc6cb , 0x143c9d2d ] )
+  , Mont::unsafe_make( [ 0x7a607b4e , 0x914e5dd9 , 0x7c82b358 , 0x4f4d1fc6 , 0x040f9380 , 0xf729faaa , 0x4fe5fed1 , 0x0912ea34 ] )
+  , Mont::unsafe_make( [ 0x1457ce2d , 0x0bbba62a , 0xa7d7f38b , 0xcd664978 , 0xd21b8655 , 0x5ee7ce44 , 0x2b20187e , 0x13e1eb38 ] )
+  , Mont::unsafe_make( [ 0x8e5cd08c , 0xed6999e4 , 0x9f2ed6b2 , 0x0c6b5310 , 0xdc498e4c , 0x44e4d34c , 0x4016be47 , 0x1bc0d838 ] )
+  , Mont::unsafe_make( [ 0x1210cec8 , 0xd33a5cd4 , 0xe7c78e06 , 0x9346bd1d , 0x5632e42a , 0xe8ba46a4 , 0x23db6b61 , 0x171b1ba3 ] )
+  , Mont::unsafe_make( [ 0x8dfb3a86 , 0xbe2a2116 , 0x1ac1780c , 0xe018f927 , 0xdf65e67a , 0x4264cda2 , 0xb18b4515 , 0x296a7b92 ] )
+  , Mont::unsafe_make( [ 0x783ee3e1 , 0xa3ede8e4 , 0xb1648e18 , 0xfccaf955 , 0x725cb345 , 0x79750631 , 0x3d72b681 , 0x19dd5bcc ] )
+  , Mont::unsafe_make( [ 0xac8049d8 , 0x385f1faa , 0xbf1ee0e8 , 0x8c08b58f , 0x26ab42ab , 0x221a799b , 0xc1863233 , 0x0c96d595 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x84b11d80 , 0x9f7c0403 , 0x9fa1346c , 0xbf65e9f0 , 0xf435e7a3 , 0xf07b82ca , 0xbf3f6ddd , 0x27b58c63 ] )
+  , Mont::unsafe_make( [ 0x76dcc4a4 , 0x7e2d1a79 , 0xcda0f32f , 0xe5d792f8 , 0xd85ba8dd , 0x7d3b38ef , 0x983b55d6 , 0x277e0ca9 ] )
+  , Mont::unsafe_make( [ 0xb3876cce , 0x3e577061 , 0x591390bf , 0xfa0437f8 , 0x4eab904f , 0x61442126 , 0xcbf8f502 , 0x29481fc4 ] )
+  , Mont::unsafe_make( [ 0x2eecabd0 , 0x902dd3e8 , 0xbe4e5a1b , 0x9c3db5c8 , 0x214a20db , 0x83873d08 , 0xfcaca4ce , 0x240ade7d ] )
+  , Mont::unsafe_make( [ 0x3fa7551c , 0x4231c692 , 0xfe73cc5b , 0x2e33190e , 0x360b4b7e , 0x751a01b0 , 0xab4220a8 , 0x27c8a974 ] )
+  , Mont::unsafe_make( [ 0x83f55bd7 , 0xc85422c6 , 0x114d7c10 , 0xb6bebb3c , 0xa15b367d , 0xd96c788c , 0x20691f2d , 0x2922e79c ] )
+  , Mont::unsafe_make( [ 0x1b117bd5 , 0x5b4ff790 , 0xd3ab14ee , 0xe739b31d , 0x44626bd8 , 0x56f985b8 , 0x3ecb759a , 0x11dcf953 ] )
+  , Mont::unsafe_make( [ 0x997a41a6 , 0x38b2165d , 0x45d92af0 , 0x01c8b6df , 0xf398d70f , 0xf309efde , 0x42b19093 , 0x2d56dadd ] )
+  , Mont::unsafe_make( [ 0x78879296 , 0xa6c06281 , 0x875b99ab , 0x5a75f826 , 0x149e18c7 , 0x4a4e6949 , 0x3f99c485 , 0x0c8fb77a ] )
+  , Mont::unsafe_make( [ 0xe879f9a3 , 0x4c1aad07 , 0xd553e1ca , 0x07e02853 , 0x803ac9a9 , 0x1684a821 , 0x3c7eb191 , 0x045edafc ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x26bbcf75 , 0xcb47652c , 0x5dbf0925 , 0x475c85a2 , 0x259ed6d8 , 0xb1e3cd50 , 0x2455592f , 0x1697fa6d ] )
+  , Mont::unsafe_make( [ 0x0020f750 , 0xdb520b5a , 0xc0f5cf39 , 0xa040c02f , 0x87273a58 , 0xb1e34e6f , 0xcf20582a , 0x292bc961 ] )
+  , Mont::unsafe_make( [ 0xe11059d2 , 0x499e6d24 , 0x22042f7e , 0x92bb9c53 , 0x29d3c3b6 , 0x07ed3028 , 0x292a8cf1 , 0x0e1294e1 ] )
+  , Mont::unsafe_make( [ 0x1139aa29 , 0xfa9ff9b5 , 0xea2db5b0 , 0xabc70ac7 , 0xfe368329 , 0x251f0f12 , 0xaf129aa5 , 0x26ae237a ] )
+  , Mont::unsafe_make( [ 0x8177afa5 , 0x66dfd1e4 , 0x0814e64b , 0xc5d1c82a , 0xbdcfe7a9 , 0x5c920e9d , 0xe5633a78 , 0x0feed9c2 ] )
+  , Mont::unsafe_make( [ 0xb2369262 , 0x65a885bd , 0x83ae98b0 , 0xb44ebeb5 , 0x38d31b33 , 0xe10f77d1 , 0x0250e3df , 0x0361fab3 ] )
+  , Mont::unsafe_make( [ 0x75a86076 , 0x2f0e2e80 , 0xec2449eb , 0x76a71c57 , 0x414a9f51 , 0x59efbf42 , 0x44c67e40 , 0x071e14ca ] )
+  , Mont::unsafe_make( [ 0x925f8fd7 , 0x06be7a6b , 0x026ab80d , 0x7aa26b9a , 0x8c582233 , 0x55a945f9 , 0x15961364 , 0x068f47e7 ] )
+  , Mont::unsafe_make( [ 0x15eb0490 , 0x6b5320d8 , 0xa04c842b , 0xdaf2dc0f , 0xbf1e1485 , 0xd49eabe7 , 0xd89aa40a , 0x2c580d9d ] )
+  , Mont::unsafe_make( [ 0x9888857a , 0xdeee9eeb , 0x94aba87c , 0x5aac9bc4 , 0x33f5187a , 0xd86ae3c3 , 0x9792ae13 , 0x1400d266 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x68c22aaf , 0x22f4e3ab , 0x3481728c , 0xdb4c5fb1 , 0x6cc0a912 , 0xd0278d8c , 0x42807966 , 0x209a2195 ] )
+  , Mont::unsafe_make( [ 0xb16b22f8 , 0xa285dea4 , 0x63ad5598 , 0x571c37c6 , 0x9be0f075 , 0xcd320612 , 0xe60dc721 , 0x1cfd30df ] )
+  , Mont::unsafe_make( [ 0x1f4e33ac , 0x17a96d48 , 0xe220355c , 0x9f5d4603 , 0xb35d6eac , 0xd9e5376a , 0x515dc59e , 0x2193d6cd ] )
+  , Mont::unsafe_make( [ 0x9b2674e0 , 0x682a466e , 0x75d73f88 , 0x3ba2102b , 0xe312b9cb , 0x0b42ed45 , 0x8edba899 , 0x2a1c5af6 ] )
+  , Mont::unsafe_make( [ 0x2f4a8905 , 0x5462d3f4 , 0x1b55de4f , 0x9ed2d999 , 0xb850a915 , 0xde8de6a3 , 0xcc23ac01 , 0x06d77ec4 ] )
+  , Mont::unsafe_make( [ 0xb4dec9b2 , 0x5d843022 , 0x519bd126 , 0x71b4f271 , 0x2d2af29b , 0x9e3ba250 , 0xb0fa0bd4 , 0x10c838d8 ] )
+  , Mont::unsafe_make( [ 0xeaae3e27 , 0x27a4a63a , 0x0f41f1ad , 0x60704f45 , 0xed3e8f9d , 0x52cb420a , 0x91dc7788 , 0x101e222f ] )
+  , Mont::unsafe_make( [ 0xdba53176 , 0xbe17e332 , 0xfa0acfd6 , 0x3ab434f4 , 0x21a92186 , 0x0c3a33d4 , 0x1d059dda , 0x0be054b5 ] )
+  , Mont::unsafe_make( [ 0xd701d31e , 0x98d8c7c9 , 0x12de6dd2 , 0xbd161fb7 , 0xb8689e66 , 0xe4877871 , 0x0ce602ff , 0x0901310a ] )
+  , Mont::unsafe_make( [ 0x8cbc9641 , 0x02c63849 , 0x20df30d5 , 0x4ce054d9 , 0xb631cd8b , 0x79b22096 , 0x13901d56 , 0x2f1d330c ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x960c03f0 , 0xa21f3706 , 0xf3d071af , 0xa4f604d9 , 0xc0ebb165 , 0xb88117dc , 0x1481f402 , 0x1243547f ] )
+  , Mont::unsafe_make( [ 0xd062bd2b , 0xee932e8d , 0x708466bf , 0x505c7293 , 0x457608fc , 0x354e3b5b , 0x01d7d451 , 0x03564ef4 ] )
+  , Mont::unsafe_make( [ 0xa77a8dcb , 0x72ebda0c , 0x45f5159d , 0x65ec3ad8 , 0x5ecc27db , 0x114d6c2b , 0x92eb1766 , 0x2a8c25df ] )
+  , Mont::unsafe_make( [ 0x686e1534 , 0xe42734ea , 0xae50a0b8 , 0x3a976c8f , 0x9943153d , 0x4c03fc94 , 0x84743530 , 0x08729656 ] )
+  , Mont::unsafe_make( [ 0xdddba644 , 0x7b9f39db , 0x1b809422 , 0xfe671d87 , 0x13de7613 , 0xd175d033 , 0x8952713d , 0x14fae610 ] )
+  , Mont::unsafe_make( [ 0xa3fd340a , 0x49679858 , 0xc7fe9bc8 , 0x01730224 , 0x75e57ff4 , 0xe2e69ba0 , 0xcb79335d , 0x2b356810 ] )
+  , Mont::unsafe_make( [ 0x85f8d794 , 0x6f106c96 , 0x5f828b2d , 0x66008183 , 0x9adba075 , 0x3734ab5d , 0x2e47a721 , 0x01f7e430 ] )
+  , Mont::unsafe_make( [ 0xbe5998b5 , 0x70619fd2 , 0x01e94094 , 0x19c6db20 , 0xd6beb0fc , 0x42615d14 , 0x435d8f31 , 0x02b4bb98 ] )
+  , Mont::unsafe_make( [ 0x1dbfb97c , 0x1a5d5674 , 0x79803215 , 0x6af592df , 0x2f1f6e4c , 0x0b915cc5 , 0x3927e53a , 0x0a6a8532 ] )
+  , Mont::unsafe_make( [ 0xb95098c5 , 0x3de26b9a , 0x7b1fb50f , 0xef4de6a4 , 0xde0dea2a , 0xbd653500 , 0x92c7ff43 , 0x2ef0cf92 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xbe90015f , 0x906fdfbe , 0x507fa1d2 , 0xfc300b8a , 0xa8eeb62b , 0x6095d181 , 0x9fff9753 , 0x1e25a4cb ] )
+  , Mont::unsafe_make( [ 0xdf4346be , 0xacb8fe54 , 0xc079e30a , 0x2332af11 , 0x46551547 , 0x12434c2f , 0xa9a27997 , 0x0a804b08 ] )
+  , Mont::unsafe_make( [ 0x43343ba8 , 0x16f0f128 , 0xb2083e92 , 0x43295b32 , 0x388f47ac , 0x4918a1ab , 0x0af4ed06 , 0x05d033c9 ] )
+  , Mont::unsafe_make( [ 0x9ba3cb47 , 0xce8d61f1 , 0xc1fb5a6e , 0x1ddfbe60 , 0xf0b25161 , 0xf61a7678 , 0xdea13fb6 , 0x1850108f ] )
+  , Mont::unsafe_make( [ 0x418c7e3f , 0x55ef0ae0 , 0x8e33c8cc , 0xd1b6a113 , 0xd7adabef , 0xff80588b , 0x83ad8a9b , 0x1b992c76 ] )
+  , Mont::unsafe_make( [ 0x531c2186 , 0xbaf5ef49 , 0x98c48a15 , 0xf8c626fa , 0x311cd76e , 0x8792cabb , 0x6480fa71 , 0x2d4a5b81 ] )
+  , Mont::unsafe_make( [ 0x1cec7dda , 0x9f7e0e06 , 0xfaa580f5 , 0x14224f38 , 0xaec995b1 , 0x61799f80 , 0xf8a16d47 , 0x0f6d6729 ] )
+  , Mont::unsafe_make( [ 0xebc6859a , 0xc3f2fa51 , 0xed3ce197 , 0xf3ed8121 , 0x027c0678 , 0x64b315cd , 0x084fea4d , 0x2eab1e42 ] )
+  , Mont::unsafe_make( [ 0x0f00f684 , 0x054b34b7 , 0x3280e94d , 0x0c26b5a1 , 0xe8375b0c , 0xd3530873 , 0x35b4508e , 0x0b1be972 ] )
+  , Mont::unsafe_make( [ 0x7d8c6436 , 0xcb334d27 , 0x50ac6a14 , 0x7fbfa850 , 0xc096ed96 , 0x2a799111 , 0xcc975914 , 0x0fa5844d ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x7e43b8b4 , 0x4ffb7dea , 0xd6f033df , 0xb0ba0a14 , 0x1aa7e9be , 0x6f695da3 , 0x392368c5 , 0x0a83d039 ] )
+  , Mont::unsafe_make( [ 0xa819da66 , 0x89abf8ed , 0xd1393df8 , 0x3d058c05 , 0xb7dafa2d , 0x05c1deab , 0xb0cbddf3 , 0x15dbcfac ] )
+  , Mont::unsafe_make( [ 0x1ea47826 , 0x8b8a1654 , 0x6d02ae80 , 0x4be721ca , 0x99e194b9 , 0xd4964ce3 , 0xd06d75ab , 0x063e429a ] )
+  , Mont::unsafe_make( [ 0xd602d3d4 , 0x7df86b87 , 0xf8e1794f , 0x1374dc10 , 0x731f0a72 , 0xe77bd53e , 0x3b47e19f , 0x1a07e006 ] )
+  , Mont::unsafe_make( [ 0xb48d48d7 , 0xeabba7c3 , 0x200d322d , 0xa858ce4f , 0x4f76f1c5 , 0xd9334e27 , 0x9bd65ede , 0x08232150 ] )
+  , Mont::unsafe_make( [ 0xc6e42d49 , 0x38b604c9 , 0x6cdb82be , 0x35c0323e , 0x11740713 , 0xbbb5a4e1 , 0x955bcd40 , 0x00996232 ] )
+  , Mont::unsafe_make( [ 0xfc2dc539 , 0xf8d7825c , 0x150c2088 , 0x6c3f0bb4 , 0xb469e814 , 0x04adc36a , 0xc38720b4 , 0x092a050c ] )
+  , Mont::unsafe_make( [ 0x4f698ed3 , 0x6859a650 , 0x839cdbcf , 0xe26f89a2 , 0x445a8de1 , 0x5118f5b2 , 0x8d059413 , 0x17d8a63f ] )
+  , Mont::unsafe_make( [ 0x70e1bf33 , 0x485f86f5 , 0x417f346a , 0x91075206 , 0x19a784e1 , 0x007e6ee3 , 0x0484c329 , 0x1d700acf ] )
+  , Mont::unsafe_make( [ 0xb398fcfa , 0xff50a7a3 , 0x065134f7 , 0x6f2111e5 , 0x9c15ad02 , 0x5d06fbb6 , 0x6081ce2a , 0x26944551 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x5b1877b4 , 0x268f74cf , 0x073dd5c5 , 0x92dce9bb , 0x84f2b23d , 0x4b9602ee , 0x17735308 , 0x1db89d36 ] )
+  , Mont::unsafe_make( [ 0x39bcdeb6 , 0x658f0797 , 0xc886aed7 , 0x267570b6 , 0x425c342f , 0x950feadc , 0xba8d37e2 , 0x0e2eac21 ] )
+  , Mont::unsafe_make( [ 0xdf36eb9d , 0x6ed02117 , 0x3a6a18a5 , 0x40ab9dce , 0xf9eadf1c , 0x976a0e4c , 0xa5a297c7 , 0x1e8f9304 ] )
+  , Mont::unsafe_make( [ 0x33d2743e , 0x008054cc , 0x7ac013a8 , 0x3c6f7dde , 0xfaaa4736 , 0xc4d52d1b , 0xe86bdc2e , 0x1e19b4c5 ] )
+  , Mont::unsafe_make( [ 0xf5100c62 , 0xd52d6058 , 0xd3cfb54d , 0x4522300b , 0xcde0061a , 0x1d1c66e3 , 0x23004db3 , 0x0499db9b ] )
+  , Mont::unsafe_make( [ 0x2ac9141d , 0x74ec78a0 , 0x9f92ce60 , 0x2c4bfa2b , 0x63f7c77c , 0x67c380cd , 0x05139938 , 0x0d3bdf3b ] )
+  , Mont::unsafe_make( [ 0x1b0541de , 0x3f8cac5f , 0x5b89ac70 , 0xd695e757 , 0x83115946 , 0x377d9621 , 0x356d7372 , 0x2473957c ] )
+  , Mont::unsafe_make( [ 0x08cf0937 , 0x0429f384 , 0x7f25e471 , 0x41cb511a , 0x1eeb0600 , 0x0a8cd128 , 0x3ce1de49 , 0x09745292 ] )
+  , Mont::unsafe_make( [ 0xbd2b3afa , 0x00f11b27 , 0x8c42e33f , 0xf42a70e0 , 0xf6133703 , 0x7c16df62 , 0x17556aa4 , 0x0de94c8d ] )
+  , Mont::unsafe_make( [ 0x796f7773 , 0x0af66c58 , 0x69eb8242 , 0x35f51f46 , 0xbf56a268 , 0x3957c45b , 0x7be5685b , 0x185e41b2 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x3fa5c7fb , 0x4bfa1acb , 0xf39780a8 , 0x64244460 , 0xe2ca4eee , 0x3d49ad4d , 0x01ecb0c0 , 0x1e0f89a4 ] )
+  , Mont::unsafe_make( [ 0xe0c60d9e , 0xc3d5c8cb , 0xa7534ca6 , 0x60216b1d , 0x6902b2ca , 0x15883c85 , 0x56922eae , 0x0642325d ] )
+  , Mont::unsafe_make( [ 0x566ceb3e , 0x8e7b47d2 , 0xa79a2a77 , 0x0510fe2e , 0x6401baa3 , 0x31814867 , 0x77601e58 , 0x1c54e1df ] )
+  , Mont::unsafe_make( [ 0x8bdd51ad , 0xb0438a18 , 0xad2b250d , 0xc46df62e , 0xdca86ba8 , 0x465f9157 , 0x8521183a , 0x27147325 ] )
+  , Mont::unsafe_make( [ 0x10ace590 , 0xa8cb068c , 0x51bb9f6a , 0x6b032856 , 0x66ceaafd , 0xd509cd81 , 0x98b31b9b , 0x04d3478e ] )
+  , Mont::unsafe_make( [ 0x67bfea67 , 0xf4ec747c , 0x55ff1d50 , 0xa4af5ecd , 0xfef0cbea , 0x336df9f2 , 0x8aa2c215 , 0x267c2845 ] )
+  , Mont::unsafe_make( [ 0xca3b0709 , 0x0909cd81 , 0xae07bf5a , 0xd2af2eef , 0x059246e2 , 0xcc7a113d , 0x820ffb5b , 0x2b649f24 ] )
+  , Mont::unsafe_make( [ 0x1ee136e6 , 0x99101627 , 0x10017f8b , 0xb5b48d4a , 0xe11d43fc , 0x007c6bf4 , 0x21c62d1a , 0x18a14b8f ] )
+  , Mont::unsafe_make( [ 0x05a6dd75 , 0x57239aa6 , 0x8acbf561 , 0x39a90a6e , 0xeb715ac0 , 0x6d968ab0 , 0x9760fe1c , 0x0996b53d ] )
+  , Mont::unsafe_make( [ 0xeb505a2a , 0x7ad31bed , 0x8067c493 , 0x617e6fc8 , 0xe6101ea3 , 0xa5b8870c , 0xcca28743 , 0x117ed171 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xd60e5191 , 0x41430072 , 0xe85aec4d , 0xb5243693 , 0xcff5b610 , 0xfd67dc8f , 0x7d7cc60d , 0x2cbfade4 ] )
+  , Mont::unsafe_make( [ 0x609b133c , 0xc46b4a95 , 0xfa1c5985 , 0x32ff7722 , 0xb3e2c887 , 0x0e4fe59e , 0x30c67e15 , 0x23e4a5f7 ] )
+  , Mont::unsafe_make( [ 0x0fc3d780 , 0xa7fed5ca , 0x614756c1 , 0x94c36701 , 0x40d99ad0 , 0xa9680d5b , 0xe9f75306 , 0x147bd215 ] )
+  , Mont::unsafe_make( [ 0x1da8fa78 , 0x882196e8 , 0x62b02d01 , 0x0c7629e1 , 0x72e46838 , 0x223a9ddf , 0xafdfc4e1 , 0x1e08bd6c ] )
+  , Mont::unsafe_make( [ 0x1ad8b9d9 , 0x6f78d36c , 0x23241e6c , 0x21efc593 , 0xb38e0c50 , 0x1ee913de , 0x84590668 , 0x15346c4a ] )
+  , Mont::unsafe_make( [ 0xdc06557e , 0x3f9cf2e7 , 0x6f02af3a , 0xaa5ca3e1 , 0x9292801f , 0x5f419a8c , 0x7c9a86a9 , 0x119f231d ] )
+  , Mont::unsafe_make( [ 0xcf041591 , 0x9ab10534 , 0x7cec8e79 , 0x9e7ff795 , 0x02fcb7c4 , 0x254ac1af , 0x0abc81d9 , 0x14d368bd ] )
+  , Mont::unsafe_make( [ 0x8e837141 , 0xfe08f936 , 0x89010d05 , 0x9479cf1c , 0xf97cd2f0 , 0x34875625 , 0x71759003 , 0x26cc06cc ] )
+  , Mont::unsafe_make( [ 0xe4432353 , 0x74082204 , 0x44ddea72 , 0xe7c5d836 , 0xe1e55334 , 0xf29ce5a8 , 0x19b34719 , 0x16d9c793 ] )
+  , Mont::unsafe_make( [ 0xf373b312 , 0x66e11139 , 0x52bcefe2 , 0x790daf2e , 0xe14d09c3 , 0xe985d389 , 0x2c4096cb , 0x0e216126 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xfaff3c60 , 0x794fb3a5 , 0x32a6b489 , 0xaf648183 , 0xa31e83bc , 0xa4ac750c , 0xf536eb6b , 0x12425fb9 ] )
+  , Mont::unsafe_make( [ 0xa989a23d , 0x08a27ed6 , 0x715998f8 , 0x8bc0bdd3 , 0x4cd86996 , 0x8af4567c , 0xe027b380 , 0x07d2fed5 ] )
+  , Mont::unsafe_make( [ 0x12acc9b2 , 0xb186889b , 0x39f2896e , 0x05142d2e , 0x6a53100c , 0x57e1ad78 , 0x583204d3 , 0x12ac6156 ] )
+  , Mont::unsafe_make( [ 0xdfc95f2d , 0x49f863fe , 0xe7fe3457 , 0x73fd51e7 , 0x8022cc31 , 0x58c3ad93 , 0xace080fb , 0x0f3b9184 ] )
+  , Mont::unsafe_make( [ 0xe2e0b22d , 0x4cdea302 , 0x335ca468 , 0xa322128f , 0x783f0cf7 , 0xbb494180 , 0x966373f2 , 0x0197c32f ] )
+  , Mont::unsafe_make( [ 0x2b0a28ad , 0xfe11f154 , 0x328de84a , 0x34660838 , 0x818e1c4e , 0xe3560bf5 , 0x6e9467c5 , 0x07e4a031 ] )
+  , Mont::unsafe_make( [ 0xb34b9bf9 , 0x5d335467 , 0xd7f3aed0 , 0x87ca2ca9 , 0x686b153b , 0xedc0894f , 0xdbe40b84 , 0x2fab2b16 ] )
+  , Mont::unsafe_make( [ 0xf5540d2e , 0xcc71f15c , 0x894d9a77 , 0x76472f38 , 0x5fb78376 , 0xafc2d9d0 , 0x8fac2555 , 0x071d5b76 ] )
+  , Mont::unsafe_make( [ 0xdd8c6324 , 0xca07ae90 , 0x023b061c , 0xadedc4cc , 0x10469695 , 0xaab72ae0 , 0xe05eb1e6 , 0x2769496c ] )
+  , Mont::unsafe_make( [ 0x186e39ba , 0x29f1585c , 0xa969d226 , 0xba8d1012 , 0x03f55233 , 0x19724676 , 0x88c442d2 , 0x003d1504 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xc7929241 , 0xaeb35ee1 , 0xadec7425 , 0xeea93671 , 0xa9105762 , 0xd91b998c , 0xec7066e6 , 0x237c3375 ] )
+  , Mont::unsafe_make( [ 0x9ec7c41b , 0xd22b16dd , 0x405fce04 , 0x0c1c629b , 0x325cef2c , 0x0405b2d1 , 0xd66ea46c , 0x0c07a364 ] )
+  , Mont::unsafe_make( [ 0x155440fd , 0x397cbefa , 0x52c8a0f5 , 0xa33be279 , 0x1ae21c6d , 0x3458c2c5 , 0x5fa4cf92 , 0x1ec8593b ] )
+  , Mont::unsafe_make( [ 0x4e5b28c0 , 0x9e36e150 , 0xf4214f24 , 0x262dc39e , 0x0b9cd143 , 0x869e66ed , 0x87814444 , 0x1b15e724 ] )
+  , Mont::unsafe_make( [ 0xd7da97ba , 0x842e04e8 , 0x0494ff5c , 0x19189586 , 0x6547375e , 0xa9626ecd , 0x3cb05462 , 0x0c70703d ] )
+  , Mont::unsafe_make( [ 0x24eb9a75 , 0x0ed8fe06 , 0x7b415fef , 0x8d1a02d3 , 0xfd7556b8 , 0xa8fdffd1 , 0xeb5ef63d , 0x2649d36c ] )
+  , Mont::unsafe_make( [ 0xa6afa039 , 0x94fbd350 , 0x8f7dc7f8 , 0x2dc21425 , 0xa1d33a59 , 0xa6f30ec1 , 0xecfa5698 , 0x1940600d ] )
+  , Mont::unsafe_make( [ 0x2f02fb5a , 0x7ea60b52 , 0x537dc7dc , 0x2d4888df , 0x0049ae1a , 0x074292a3 , 0x2c65cb72 , 0x25aef83d ] )
+  , Mont::unsafe_make( [ 0xd32afa02 , 0x9f616aa5 , 0x89c602a3 , 0x34664ef2 , 0xc9266c62 , 0x08cf5326 , 0x7d7a4daa , 0x13e6bf57 ] )
+  , Mont::unsafe_make( [ 0xb7783c3a , 0x16671bc6 , 0x641dae62 , 0xf3c77261 , 0xe34bffe9 , 0x7517c239 , 0x77b9ee0c , 0x17a757c3 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xbedd95a9 , 0xfe94d913 , 0xfc572b22 , 0xb1195227 , 0xa9f12f69 , 0x4a52f28b , 0x37a1e274 , 0x2831cbf4 ] )
+  , Mont::unsafe_make( [ 0xbb9da784 , 0x9dc753af , 0x651fd72c , 0x5ee9fe93 , 0x421775d1 , 0x4d61d1cb , 0x7c62e014 , 0x06fe8165 ] )
+  , Mont::unsafe_make( [ 0x9fb35a7e , 0x05609456 , 0x289875a9 , 0x6c927de3 , 0x7509758c , 0x2caf24c4 , 0xe475e211 , 0x0286b6f2 ] )
+  , Mont::unsafe_make( [ 0xba74d4de , 0x2a1fa873 , 0x03a38023 , 0xe97f1445 , 0x18348fda , 0xf2f481df , 0xdf8889cd , 0x0d574cab ] )
+  , Mont::unsafe_make( [ 0xcbb192bc , 0x398fa815 , 0xfa3ab704 , 0x0265c69e , 0x869a2e84 , 0x783999ab , 0x21bdadd4 , 0x2bf556f4 ] )
+  , Mont::unsafe_make( [ 0x457064de , 0x15c1d769 , 0x6ac48e89 , 0x0eb82881 , 0x456fe4a0 , 0x842a9872 , 0xfe4ca0d4 , 0x0f3ed8cc ] )
+  , Mont::unsafe_make( [ 0x82706d93 , 0xb0d4555e , 0x42b53b6b , 0x9367d0d6 , 0x4129722c , 0x34c83d13 , 0x43fa2aa2 , 0x188421fa ] )
+  , Mont::unsafe_make( [ 0x43e96f1b , 0xd4727b56 , 0x75edc33c , 0xedd431dd , 0xefd9ce71 , 0xacd34a01 , 0x8dd5affc , 0x003b2310 ] )
+  , Mont::unsafe_make( [ 0x8f6c01a1 , 0x3cf5a04b , 0x4e0d90d1 , 0x6c7d5bd3 , 0xea3d0cd2 , 0xaf4c16c9 , 0x1a96e586 , 0x0af57bdc ] )
+  , Mont::unsafe_make( [ 0x74cfc447 , 0xe9bceb18 , 0x6ef2f046 , 0x1918dcde , 0xcc5987a1 , 0xb40b1bbc , 0x2499a535 , 0x18b89bda ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x3d57dcfa , 0x429ef391 , 0x11afe459 , 0x1822b5ac , 0xeeb33d54 , 0x805b038b , 0x0133c5b8 , 0x171a959f ] )
+  , Mont::unsafe_make( [ 0x27ead15d , 0xa7b5f826 , 0xa7424eff , 0xf993ddaa , 0x4aad2c29 , 0x9d3b7f3e , 0x9b87c364 , 0x10818ce3 ] )
+  , Mont::unsafe_make( [ 0x97745a97 , 0x2d14c201 , 0x230c4644 , 0x4cbc69c0 , 0xf58fee20 , 0xaf6fa04f , 0xe7832d3c , 0x18079d4d ] )
+  , Mont::unsafe_make( [ 0x0ef16d40 , 0x29f8db99 , 0xc71463d1 , 0x61218841 , 0xb0ceb082 , 0x03b5b64a , 0xcecf146a , 0x214e571f ] )
+  , Mont::unsafe_make( [ 0x32382181 , 0x6639fbd7 , 0x9aad8ef0 , 0xc0109562 , 0x63806206 , 0x2bc96dca , 0x099845fb , 0x1e4a6492 ] )
+  , Mont::unsafe_make( [ 0x067d15e6 , 0x40e1bfc9 , 0xc6045484 , 0x2e9e487a , 0xff43575e , 0xa0e1dc43 , 0x7752940a , 0x1e84eade ] )
+  , Mont::unsafe_make( [ 0xceaf1057 , 0x687e57b2 , 0xbf881b5d , 0x7b83bc64 , 0xcaa457e9 , 0x9191c0d1 , 0x87fda882 , 0x26c887d4 ] )
+  , Mont::unsafe_make( [ 0xcd70376f , 0x2e67c787 , 0x404bafd6 , 0x33648911 , 0xf7863b67 , 0x76636e9c , 0x53e126e6 , 0x000dc674 ] )
+  , Mont::unsafe_make( [ 0x047ce820 , 0xfd084d64 , 0xe7690705 , 0x309c5611 , 0x5a170878 , 0xa9ad41ba , 0x1431a322 , 0x1e820c67 ] )
+  , Mont::unsafe_make( [ 0x432c6acf , 0x5cd1d942 , 0xbc38f0a6 , 0xb7a6a622 , 0x6ea406fd , 0x4b5f3fc8 , 0xff504968 , 0x05866b66 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x43bdc846 , 0x83cddca1 , 0x9f90aca5 , 0xa656025f , 0x46128738 , 0x154aeb8f , 0xdcad75be , 0x2ab01c22 ] )
+  , Mont::unsafe_make( [ 0x82b3fd52 , 0x26bc9098 , 0x4de01ff6 , 0x025266e4 , 0xe4e40337 , 0x1deb6d2c , 0xd84007f4 , 0x292020dd ] )
+  , Mont::unsafe_make( [ 0x089ad2dd , 0x239e1fdb , 0xc9df0387 , 0x49002876 , 0x7f1b52e7 , 0xc526d0b9 , 0x08b716ab , 0x3052c791 ] )
+  , Mont::unsafe_make( [ 0xf236fd28 , 0x715efe85 , 0xc5b30579 , 0x2942bb02 , 0xb13ab3d4 , 0x0083d274 , 0x9cf95ff2 , 0x0cdd5b29 ] )
+  , Mont::unsafe_make( [ 0xfafd2190 , 0xb1e51af1 , 0x93545352 , 0x2bcedb48 , 0x013005af , 0xfb4569e5 , 0xd35f39e5 , 0x2731e1af ] )
+  , Mont::unsafe_make( [ 0xf16076fa , 0xd95443bd , 0xcdad6413 , 0xd0809d9d , 0xb9f6ba1b , 0x4e6e00de , 0x0a447984 , 0x2fb982ef ] )
+  , Mont::unsafe_make( [ 0x66b262b7 , 0x7bc10d91 , 0x5d698a7c , 0xd9221905 , 0x863f55d0 , 0x3998c05c , 0x9ab00488 , 0x065faf64 ] )
+  , Mont::unsafe_make( [ 0x9ba04f9b , 0x5e36f31a , 0xa587e4fa , 0x0cb9cbaf , 0xcd23870a , 0xc6c017e8 , 0xade55294 , 0x0d42b27a ] )
+  , Mont::unsafe_make( [ 0xaae8a06a , 0x11ee6b10 , 0x3f0a1fd3 , 0x37d3f542 , 0x6554a895 , 0x766a0517 , 0x99ffe6dc , 0x0bf2ccaf ] )
+  , Mont::unsafe_make( [ 0x7eeecc50 , 0xe42ab63d , 0xc71c59d2 , 0x0fd4b4e0 , 0x2a605d04 , 0x1640313f , 0xd7002b78 , 0x13c50fe9 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x73c0c1f0 , 0x5b818405 , 0xe60375cb , 0xa74ae0c7 , 0x9f6bf7c7 , 0x437abcb1 , 0xe0393759 , 0x2813b72d ] )
+  , Mont::unsafe_make( [ 0xac6acf02 , 0x8e33214e , 0xfd12c535 , 0xdecb6a07 , 0x214212d7 , 0x22115373 , 0x5a8750da , 0x0f2b1c56 ] )
+  , Mont::unsafe_make( [ 0x913b0566 , 0x00b28ef5 , 0x87c0ace2 , 0x095b0d77 , 0xe9ea69b2 , 0x312e38da , 0x6b9add7a , 0x2fd28b34 ] )
+  , Mont::unsafe_make( [ 0x7dfa3f4d , 0x9920f054 , 0xa6e4496b , 0xbbeb9f35 , 0xac5f2350 , 0xee1c3766 , 0x5c6beacf , 0x006fabfb ] )
+  , Mont::unsafe_make( [ 0x648b0c6c , 0x48676abc , 0x04fc3e3a , 0x83a8729d , 0xa364584f , 0xa82bc05f , 0x39b9717e , 0x01354a2d ] )
+  , Mont::unsafe_make( [ 0xeb482cd2 , 0x48d7d613 , 0xc8ec0cf5 , 0x905678f0 , 0x7ca5e11f , 0x30a1dc35 , 0x42778d29 , 0x20062072 ] )
+  , Mont::unsafe_make( [ 0xbf27f08e , 0x99f1b7a5 , 0xb61560f4 , 0xa4d0ef48 , 0x5b840cb3 , 0xa5d1a08f , 0x556c5689 , 0x213e15a5 ] )
+  , Mont::unsafe_make( [ 0x966c5cae , 0xd9867cd5 , 0xb5e52676 , 0x39d98918 , 0xd48c47ef , 0xff23a911 , 0x9290448e , 0x185a1625 ] )
+  , Mont::unsafe_make( [ 0x09703b00 , 0x8beb27ff , 0x94788fa8 , 0xecb72e23 , 0xf7038f53 , 0x95d9dcef , 0x9a04127d , 0x2e0a1d99 ] )
+  , Mont::unsafe_make( [ 0x794094e7 , 0x2ec2f346 , 0xa11738b2 , 0x414c01b0 , 0xca0c4971 , 0x05f69e0d , 0xc262ed7f , 0x12039963 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x4c48c3dd , 0x72477a65 , 0x33236c0b , 0x65faac6f , 0xf84b2ffe , 0x5ba0eed9 , 0xf7f5a59b , 0x1d964a30 ] )
+  , Mont::unsafe_make( [ 0x08540179 , 0x4e91ab61 , 0xc795b862 , 0xa320c6c9 , 0x7a21ebd0 , 0x4e806c5e , 0xbc759f15 , 0x24fd3c9b ] )
+  , Mont::unsafe_make( [ 0x8e47ccf1 , 0xddd3327b , 0x13b10146 , 0xb99f5da3 , 0xd26ecb41 , 0x4839240f , 0x42a694bf , 0x0809b3af ] )
+  , Mont::unsafe_make( [ 0x43fc95ea , 0xcd6cb05c , 0x01cf5eb1 , 0x3adf5f91 , 0xa461dabd , 0x51ff7a53 , 0xbd79d9fa , 0x2cb590a9 ] )
+  , Mont::unsafe_make( [ 0xbedd573e , 0x1880a100 , 0x33794deb , 0xff738e0a , 0x39ae0dfb , 0xc781c2f8 , 0xaa8d0898 , 0x0f4e1c2c ] )
+  , Mont::unsafe_make( [ 0xfc9a2f46 , 0xf6e095ce , 0x4dea1014 , 0xb650236f , 0xd84b5cab , 0xd6073ce3 , 0x93727617 , 0x24f4681f ] )
+  , Mont::unsafe_make( [ 0xc105cfd2 , 0xe50816b5 , 0x2334ed6f , 0xfbf5c1c1 , 0x8d50514b , 0x5e400fd6 , 0xf3b1f622 , 0x1964fbf1 ] )
+  , Mont::unsafe_make( [ 0xaa00b54f , 0x2cdc4927 , 0x6b1acc42 , 0xa20a5bcd , 0xfce19da0 , 0xa3ebad50 , 0xb580ea13 , 0x0e8958de ] )
+  , Mont::unsafe_make( [ 0xa18d0055 , 0x8bc9111e , 0xea99dc47 , 0x0ba7b023 , 0xf6d0b7ac , 0xa3b0469b , 0x7e01ee19 , 0x16bb0136 ] )
+  , Mont::unsafe_make( [ 0x2d132736 , 0x39ab0805 , 0x91df9a93 , 0xf2d8807b , 0xfd2ced7a , 0xae731dde , 0x4ac0802d , 0x221de3c2 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xa0837686 , 0x4e1ed3c2 , 0xc288fd6b , 0xd165ea1d , 0x1a661646 , 0xdc2d9c41 , 0x729fbb79 , 0x14c2bdad ] )
+  , Mont::unsafe_make( [ 0x479e8a8c , 0x6e445faf , 0xe38b87f8 , 0x7ce03456 , 0x7c3cddfc , 0xd7d4cab7 , 0xe96691b6 , 0x0a6df89e ] )
+  , Mont::unsafe_make( [ 0x031c4722 , 0xd010bafa , 0x18c125c4 , 0x1d462a86 , 0x5aead703 , 0xeae9e71d , 0x2011751f , 0x144b6c2a ] )
+  , Mont::unsafe_make( [ 0x1214a1d1 , 0xf7859272 , 0x5efc80e3 , 0x5c9a8150 , 0x1208baaf , 0x87f097a2 , 0x3da570dc , 0x23c1af70 ] )
+  , Mont::unsafe_make( [ 0x376023f1 , 0xc25a89ce , 0xc9855afa , 0xbc09e07a , 0xe93565c4 , 0x751ce2bd , 0xe1de6d3c , 0x15eaeba1 ] )
+  , Mont::unsafe_make( [ 0x67ad4be4 , 0x57134c6c , 0x23988af8 , 0x03fca4fa , 0x88c6eaf1 , 0x6900e2ff , 0xb3eef5e3 , 0x12ec80fb ] )
+  , Mont::unsafe_make( [ 0x4cef5dd1 , 0xaadacffd , 0x730f8373 , 0x3a7bc9cd , 0x20fb470f , 0x06c4066d , 0xf9f41902 , 0x112e206d ] )
+  , Mont::unsafe_make( [ 0x8bf9dc72 , 0x5708c9ff , 0xdb07116f , 0x49c9282f , 0x7c35de85 , 0xf2b23c6b , 0xcdb0b54c , 0x21229b88 ] )
+  , Mont::unsafe_make( [ 0xa657f376 , 0xe62a6947 , 0xfe1e683c , 0x67bb56d2 , 0xdb55abb9 , 0x80cd972e , 0xbcc1dc20 , 0x10e7ac8c ] )
+  , Mont::unsafe_make( [ 0x6afb0ae5 , 0x7065ad48 , 0x8e556753 , 0xa323b135 , 0xc317d713 , 0x7b7468e5 , 0xe96cd87e , 0x2c8cff99 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xbed2cbfb , 0x15e2a11f , 0xa55c1a43 , 0x8a2e5b64 , 0x31754005 , 0x3449ea49 , 0xfd7bd007 , 0x17078a7b ] )
+  , Mont::unsafe_make( [ 0x94cf3eea , 0xc074ed60 , 0xd49d5255 , 0xdfcd7675 , 0x291528d2 , 0xe06c0f8c , 0x0883f677 , 0x2fe2f080 ] )
+  , Mont::unsafe_make( [ 0xb2085dbf , 0x47a4c162 , 0x92216d95 , 0xefb234aa , 0x538bd1d8 , 0xb2f6a762 , 0x77579cb2 , 0x2ded2e0c ] )
+  , Mont::unsafe_make( [ 0xaad5a041 , 0xa4ff6a48 , 0x1de55a60 , 0x04991111 , 0x23f00d73 , 0x6722ef01 , 0x730bea10 , 0x23ef24d7 ] )
+  , Mont::unsafe_make( [ 0xc3913081 , 0x9af40323 , 0x394a82a5 , 0x6f54c6f8 , 0x87608c3c , 0xbde69585 , 0x0516a326 , 0x09fdc8f1 ] )
+  , Mont::unsafe_make( [ 0x30b77038 , 0xeca0ace2 , 0xd15082eb , 0x27cae772 , 0x41750e7e , 0xace70961 , 0xd1941ec8 , 0x143dc067 ] )
+  , Mont::unsafe_make( [ 0xc7420ee5 , 0x02c71e51 , 0x1d742b07 , 0xe4508380 , 0x79d20bba , 0x6f81eec8 , 0x30cc17e3 , 0x0f56d1bb ] )
+  , Mont::unsafe_make( [ 0x8ab6764a , 0xe2e9ac67 , 0xc9ca2e42 , 0x4af11a6f , 0xccebaeef , 0x2e704185 , 0x2f6bc17b , 0x053d7003 ] )
+  , Mont::unsafe_make( [ 0x6f8a0392 , 0xc69963c3 , 0x65038ac8 , 0x9bf638ee , 0x9134fa63 , 0x0a0848d6 , 0x010797c0 , 0x042ee9f3 ] )
+  , Mont::unsafe_make( [ 0x480f778c , 0x3b586bce , 0x38c57784 , 0x7f8b339f , 0x6189fbe6 , 0x6653d974 , 0xac93e4f7 , 0x2dcf663b ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x39642670 , 0xf03330f9 , 0xcfd8e196 , 0xd3b861ef , 0xf1e815cb , 0x89f57a10 , 0xd98cfc7c , 0x28f12851 ] )
+  , Mont::unsafe_make( [ 0xd95dfaae , 0x7d1143b4 , 0xe3b9cd53 , 0x1b3ae283 , 0xa420eaa1 , 0x8c6206a9 , 0x3777dfee , 0x1db83204 ] )
+  , Mont::unsafe_make( [ 0x39d6e572 , 0xb5a070e9 , 0xecd38e8c , 0xaa166aeb , 0xf859d5e8 , 0x65c13ab1 , 0x000c0012 , 0x2df49f5b ] )
+  , Mont::unsafe_make( [ 0x2e047379 , 0xe276202c , 0x125ad73b , 0xdb0baf3d , 0xbf8cf913 , 0xd5fdf45b , 0x3ac651a6 , 0x029773c2 ] )
+  , Mont::unsafe_make( [ 0xd987f062 , 0x828b2a1e , 0x4200c17f , 0xaae70d81 , 0x9b5ff0b8 , 0xd4e93e9c , 0x73f48b9e , 0x29df6c55 ] )
+  , Mont::unsafe_make( [ 0xc18a048b , 0x18d39aaa , 0xcc794591 , 0xf618b662 , 0x296c57f0 , 0x755e5a41 , 0xa096815f , 0x07e6fc8a ] )
+  , Mont::unsafe_make( [ 0x37ac794e , 0xa000eb9e , 0xedaef80c , 0xd057cdee , 0xb0d64fcf , 0xfdc1169f , 0x8f71976a , 0x12b60d95 ] )
+  , Mont::unsafe_make( [ 0xf2c0cc86 , 0x9f2ad838 , 0xedebdeb6 , 0x579714b5 , 0xd27a2ef1 , 0x415d9660 , 0x720c2544 , 0x22d0d71a ] )
+  , Mont::unsafe_make( [ 0xbfd9600c , 0xa5478d4b , 0xb2dc4260 , 0x6564c501 , 0xcd5ea3b6 , 0x56d5fa25 , 0xe76e548a , 0x1e6c20fc ] )
+  , Mont::unsafe_make( [ 0xb88d9c57 , 0xa10e501e , 0xc4da7444 , 0x04679bb8 , 0xf36337f5 , 0x9cfba0aa , 0xc10dfbe9 , 0x2f9549ff ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xee8f54ea , 0x9b82e608 , 0x7171db1a , 0x45671d93 , 0xf3d4c505 , 0x1ae09d67 , 0x3d613bd2 , 0x229d3db7 ] )
+  , Mont::unsafe_make( [ 0xd52e3986 , 0xbe706bdb , 0xd3f90336 , 0x039c4c1e , 0x10d7bac6 , 0x9b246d43 , 0x69f74d32 , 0x07076365 ] )
+  , Mont::unsafe_make( [ 0xebb97854 , 0x41757f77 , 0x3372519d , 0xca97ae62 , 0x6e3e14b7 , 0x9161bb68 , 0xd353cb7f , 0x05e70fcb ] )
+  , Mont::unsafe_make( [ 0x289d06b8 , 0x346d94df , 0xa468d573 , 0x74ca2464 , 0x3df40b90 , 0x9eb6fc2c , 0xddafd9d2 , 0x04e00441 ] )
+  , Mont::unsafe_make( [ 0x89c07c32 , 0xdb6284fb , 0x33404448 , 0x5bd18d2e , 0xad528633 , 0x0afeec26 , 0xbc3fc0fb , 0x08682e08 ] )
+  , Mont::unsafe_make( [ 0x3967c4a3 , 0x96ae2821 , 0xc2dd4016 , 0x2deb6a8e , 0xd41ede56 , 0x699567ce , 0xb74be8bb , 0x14e5afea ] )
+  , Mont::unsafe_make( [ 0x2a3a58e6 , 0xbd95b420 , 0xffa79aff , 0x2b87b645 , 0xa1dfd85b , 0xefc85272 , 0x4b9e9ffc , 0x09f1c38d ] )
+  , Mont::unsafe_make( [ 0x645e4da0 , 0xa56dc772 , 0x1c1b123d , 0x68f40a21 , 0xa345a5eb , 0x3c408040 , 0x90b35cb9 , 0x0ecda0cd ] )
+  , Mont::unsafe_make( [ 0xc89bff90 , 0x1d44e003 , 0x8fb1114a , 0x71877634 , 0x359504a6 , 0x0ba4db03 , 0x5b8873b7 , 0x0f72e305 ] )
+  , Mont::unsafe_make( [ 0xefbb6070 , 0xd73cbdcc , 0xedd3dac6 , 0x8ebe44b8 , 0x42202504 , 0x1ce4a4b7 , 0x9c9302fd , 0x2b7bdf2a ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x195527b7 , 0x5d122485 , 0xf9311baf , 0xda42cd67 , 0xc757a651 , 0xf7de4ec1 , 0x096e1003 , 0x1e4aa4af ] )
+  , Mont::unsafe_make( [ 0x046bf7c8 , 0xef124b12 , 0x38de5ceb , 0x5df9c178 , 0x0963aba1 , 0x6e389c53 , 0x538dd39d , 0x18dc3e31 ] )
+  , Mont::unsafe_make( [ 0x5630215b , 0xfba98222 , 0x0181bdff , 0x05ac4f82 , 0x609949b0 , 0x62fed1fd , 0x48a86082 , 0x2dc25e45 ] )
+  , Mont::unsafe_make( [ 0x0f96e5ad , 0x8ef09e09 , 0x222355d0 , 0xcb7e670d , 0x4c2f11b2 , 0x313e0002 , 0x001906c2 , 0x22976570 ] )
+  , Mont::unsafe_make( [ 0x8d1ebdc0 , 0x9d8e16a1 , 0x0cc28755 , 0x50a900ea , 0x0a9291d3 , 0xac6dc9e3 , 0xa3de662d , 0x1cffaacb ] )
+  , Mont::unsafe_make( [ 0xcb8d5b57 , 0xd16eba98 , 0xf26a8b29 , 0x80bde324 , 0x309d6c0b , 0x91fbcbe0 , 0x11287c46 , 0x2e3ca2e9 ] )
+  , Mont::unsafe_make( [ 0x41193ea3 , 0xaaff999e , 0x88873cf2 , 0xc6009248 , 0x63ad4b99 , 0x14a90e09 , 0x725b65a1 , 0x2cdb7a14 ] )
+  , Mont::unsafe_make( [ 0xe82d5373 , 0xcfc5acc1 , 0x05d2e57e , 0xce7fdbe0 , 0x7238b285 , 0x08d5a513 , 0xab995075 , 0x2cb31a2e ] )
+  , Mont::unsafe_make( [ 0x1b98c834 , 0x82a47336 , 0xe4ca507b , 0x3a25ebae , 0x8220d8c1 , 0xe566973d , 0x4104c852 , 0x17311cdf ] )
+  , Mont::unsafe_make( [ 0x30be0f81 , 0x0a922ae7 , 0xbb103a49 , 0xb15c7a94 , 0xbee7ba52 , 0x0a4a381e , 0xb2b850a8 , 0x01e7ce69 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x34625136 , 0x033c5076 , 0x9106000f , 0xb4c30e6f , 0x2000555d , 0x1b508faf , 0xe382647d , 0x0e91d643 ] )
+  , Mont::unsafe_make( [ 0x1540d848 , 0x9c08cf06 , 0xd3027165 , 0xfe238a65 , 0x5b0281b8 , 0xa3194901 , 0xec603c0e , 0x162d975b ] )
+  , Mont::unsafe_make( [ 0x49a8b9a9 , 0x956cbce1 , 0xce36368d , 0x1d917674 , 0x36a75936 , 0x5848a564 , 0xd5ba01ee , 0x27d13865 ] )
+  , Mont::unsafe_make( [ 0xd7a2696d , 0xdb9d88c2 , 0xa4e29701 , 0xcaff9baa , 0xfdb7070b , 0x78c38810 , 0x107e3cbc , 0x25e79f9b ] )
+  , Mont::unsafe_make( [ 0xe81459a2 , 0x613b5a4d , 0x042d645b , 0xdbf1fa18 , 0x28dc28c1 , 0x9da80b8e , 0x514d3921 , 0x21a72c91 ] )
+  , Mont::unsafe_make( [ 0x1fcee631 , 0x80533d26 , 0x2fbe7d9d , 0xc2cb121e , 0x1fe7be19 , 0x530ff889 , 0x7125c2e2 , 0x28e2a69a ] )
+  , Mont::unsafe_make( [ 0x37ac39ce , 0xeac552a1 , 0x57f1ebc3 , 0x6f4a78c0 , 0xa75f7c1a , 0x69a7c8fc , 0x2c0c4d40 , 0x1e8f2e55 ] )
+  , Mont::unsafe_make( [ 0x5e74168e , 0xaeacbff7 , 0xc635bd5c , 0x6afc4ae1 , 0xafbabe1a , 0x84dd7449 , 0xf7411114 , 0x03af3501 ] )
+  , Mont::unsafe_make( [ 0x2953e64d , 0x3828e049 , 0xd1ad2888 , 0xf87b30e7 , 0x386e8078 , 0x085b2b20 , 0x96f11eed , 0x26486b27 ] )
+  , Mont::unsafe_make( [ 0x4cabdd2e , 0x934b5941 , 0xf3e0b2a0 , 0xcf06b221 , 0xc2188017 , 0x5fd9dc5c , 0xa5feaa25 , 0x24b37d5a ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x292fcaf8 , 0x1cfe3924 , 0xad57660a , 0xb75f7f6a , 0x673867c6 , 0xbfc9f382 , 0xf40747e8 , 0x0d2fc9e3 ] )
+  , Mont::unsafe_make( [ 0x6c21c713 , 0x46c36d00 , 0xde7bddc6 , 0x6f733c49 , 0xeebf9b8f , 0xeb27f513 , 0xc2d53e79 , 0x196545e2 ] )
+  , Mont::unsafe_make( [ 0x09d9e3b6 , 0x222cd1dc , 0xc45da548 , 0x6cb8a545 , 0x5d78c132 , 0xdd905ffc , 0x73c182d4 , 0x28374fba ] )
+  , Mont::unsafe_make( [ 0x08468a91 , 0xbdd541cb , 0x52dc99dc , 0xb751a4b9 , 0x9d6e4639 , 0xeffdc263 , 0x70257730 , 0x2f46c243 ] )
+  , Mont::unsafe_make( [ 0xd14f6eca , 0xe0887d1f , 0x4a5b8373 , 0x7a7f84aa , 0xcd2c593c , 0xe51b6012 , 0x9fb9ea3f , 0x0bf175db ] )
+  , Mont::unsafe_make( [ 0x520ea077 , 0xb162d55b , 0xdb3dd4c3 , 0xa7275f82 , 0xf80c695c , 0x0a83d114 , 0xc176046d , 0x13cb0843 ] )
+  , Mont::unsafe_make( [ 0x2b1f656e , 0xa1764446 , 0xe57ba697 , 0x5c5a0667 , 0x2f8eec62 , 0x9c5c62fe , 0x7ced7af0 , 0x0837654e ] )
+  , Mont::unsafe_make( [ 0x62d25401 , 0xdbcbc3ed , 0x12dd0115 , 0xad73a397 , 0xa5a69b00 , 0x6532df02 , 0x079dba4e , 0x18efd7ed ] )
+  , Mont::unsafe_make( [ 0x3cb17155 , 0x0953c822 , 0x8ececfe4 , 0x7f1d312c , 0x77bc3931 , 0xa313a70b , 0x608fb1e0 , 0x0066dfe8 ] )
+  , Mont::unsafe_make( [ 0x1fa616b7 , 0xa1d14339 , 0x982484a2 , 0xb49e4a8c , 0x1a440c9d , 0x2df02458 , 0xe318977c , 0x300419c3 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xa18f6425 , 0xb38639d7 , 0xdce69542 , 0x8c1f603e , 0x1788afc1 , 0x3ab36e3f , 0x67efc2f4 , 0x008e8acc ] )
+  , Mont::unsafe_make( [ 0xcadf0709 , 0xeaf51a03 , 0x27379f2d , 0xfafcc3b5 , 0x13f5ea5a , 0xe74caa34 , 0x22d80edc , 0x128fa5fc ] )
+  , Mont::unsafe_make( [ 0x95284566 , 0xdce78898 , 0x78813ded , 0x8c76217b , 0xf50a387b , 0xaf25210e , 0x29366ccf , 0x0246a727 ] )
+  , Mont::unsafe_make( [ 0xaedf5eac , 0x922f0c10 , 0x5ecc7bf3 , 0xcec10e21 , 0x0d84da14 , 0xdbbad9a7 , 0x422ef223 , 0x0acee9cd ] )
+  , Mont::unsafe_make( [ 0xd31d23bd , 0x26c12482 , 0x4f15c04b , 0xca573d2c , 0x4f0da692 , 0xfcaac7c1 , 0xe6795427 , 0x13f655eb ] )
+  , Mont::unsafe_make( [ 0x570a06bd , 0xd4345892 , 0xa47b3364 , 0x86065f77 , 0x43916b17 , 0xdb400ce5 , 0xdb6f9450 , 0x21dddf11 ] )
+  , Mont::unsafe_make( [ 0xa04ec9d5 , 0x735f7b36 , 0x18046f64 , 0x1374c239 , 0x227eb1ed , 0xc1499451 , 0x717c7f5f , 0x18da09a9 ] )
+  , Mont::unsafe_make( [ 0xe0275133 , 0x2bc8f967 , 0xdf710c42 , 0x5e9aeff8 , 0xbeb2f39f , 0xd78f1c6c , 0xd6ff5145 , 0x18699f1d ] )
+  , Mont::unsafe_make( [ 0x74dc6ff0 , 0x2b05eb6d , 0x5c8680c8 , 0x67920ec2 , 0xeb1d64e1 , 0x6a0dea7f , 0xe3bfc78f , 0x2c6412a3 ] )
+  , Mont::unsafe_make( [ 0xebf2dd5c , 0xaacc69ef , 0x40b8d09a , 0x518c3b73 , 0xc7320c74 , 0x4a28271e , 0xec2ffe39 , 0x1efb0232 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xa874d241 , 0x3ffe6bc8 , 0xc15cca98 , 0xb6408d4e , 0x69911016 , 0x9fa4c0b8 , 0x496a31bb , 0x219613c6 ] )
+  , Mont::unsafe_make( [ 0xd8935991 , 0x6628f7c2 , 0x2be3069e , 0xecc9d121 , 0x8bbeb323 , 0xb8d98371 , 0x7d847ee6 , 0x2d998595 ] )
+  , Mont::unsafe_make( [ 0x21648af0 , 0x1cc3e057 , 0xc143b409 , 0x9b8889ee , 0x7426504d , 0x8a2dfa34 , 0xea78264f , 0x09766a17 ] )
+  , Mont::unsafe_make( [ 0x5852cd52 , 0x3f2364ae , 0x70e610dd , 0x8f9bc0a0 , 0x2760f30a , 0xcff1ef5e , 0x0d136810 , 0x10765580 ] )
+  , Mont::unsafe_make( [ 0x346af9cd , 0x7548d643 , 0x96e74038 , 0x7f1365e5 , 0x3c9ceac9 , 0xe20f579a , 0xa9cbcd24 , 0x234b794c ] )
+  , Mont::unsafe_make( [ 0x56393711 , 0xdddd761d , 0xb9bbcbfe , 0x3d9d6203 , 0xef2fa325 , 0x261693ea , 0xe1986d73 , 0x15261913 ] )
+  , Mont::unsafe_make( [ 0x38f60277 , 0x193def4d , 0xe1c23fae , 0x5c60e963 , 0x887c4ea7 , 0x4a6c7ee2 , 0xf77c60d5 , 0x1a47561f ] )
+  , Mont::unsafe_make( [ 0x21a62665 , 0x830d7e92 , 0x96293442 , 0xd5a1ae4c , 0x939ff109 , 0x90ed5190 , 0xc1c2d3b8 , 0x1699aa4c ] )
+  , Mont::unsafe_make( [ 0xf03c2368 , 0x3082a4a1 , 0x0026d27b , 0x296c6487 , 0x37c9a4df , 0x6cf862f0 , 0x411f8180 , 0x2cff4e40 ] )
+  , Mont::unsafe_make( [ 0x5382773c , 0xbcd8297f , 0xd6e89e97 , 0x3062f3b2 , 0x245d5f40 , 0xfc5886cc , 0x9f360ac7 , 0x13eb9907 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xbf52227f , 0x7e85008a , 0x0ee6cdcf , 0x3741c571 , 0xd6e93c09 , 0xb47520b0 , 0x4efda591 , 0x287756ab ] )
+  , Mont::unsafe_make( [ 0xa166b418 , 0x8ab863bc , 0x1fd563f8 , 0x90dea7f7 , 0xf54a3693 , 0x218e42cc , 0x7485684f , 0x1811b394 ] )
+  , Mont::unsafe_make( [ 0xe8742c5d , 0x5f3fab8c , 0x19accf8e , 0xe2c50e64 , 0x816e7246 , 0xa7c7e554 , 0x1fbb72d4 , 0x0b7a6d24 ] )
+  , Mont::unsafe_make( [ 0x984107d2 , 0x3b9c8593 , 0x1c1563cc , 0xf7f5bd23 , 0x3ac1c02c , 0x6c441644 , 0x389fc396 , 0x1a4875f4 ] )
+  , Mont::unsafe_make( [ 0x00c9867a , 0x0f40ace7 , 0x6e788ac0 , 0x7440dbd4 , 0x23755113 , 0x983c4e41 , 0x1b78207c , 0x0ded7d44 ] )
+  , Mont::unsafe_make( [ 0xe02251d1 , 0x5a8443f7 , 0x9ba55f36 , 0x2482bf49 , 0x6a8fab0e , 0x3f9ca1b0 , 0xf6ce7bd7 , 0x112c6c8c ] )
+  , Mont::unsafe_make( [ 0xa010f7c0 , 0xc0143cc3 , 0x589561b9 , 0xf05638d2 , 0x30534477 , 0x16f1733a , 0x1d664e25 , 0x1717e412 ] )
+  , Mont::unsafe_make( [ 0x011dff44 , 0x33721052 , 0x23730ec7 , 0xd08f7819 , 0x5ea42e41 , 0x875a00dc , 0x554c8397 , 0x15aaf385 ] )
+  , Mont::unsafe_make( [ 0xc294adc1 , 0x65abfcc6 , 0x8a1864b0 , 0x6e45c06a , 0x421451a7 , 0x97f034c1 , 0x480a9057 , 0x18f0c1a7 ] )
+  , Mont::unsafe_make( [ 0x9ef58eb1 , 0xbefdb71b , 0x31f02a94 , 0xaebeaac4 , 0xefad9f37 , 0x3ec2051a , 0x5c5a4684 , 0x2decb75b ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xd7cf9477 , 0xe5f51fb7 , 0x208bb54c , 0x0275657a , 0x2f06a7de , 0x379fcf3b , 0x93b8504a , 0x0465349c ] )
+  , Mont::unsafe_make( [ 0x179da873 , 0xe836fc6b , 0x76efc6d5 , 0x41fa7583 , 0xb622b4ee , 0xac2046fe , 0x2a39a09d , 0x0713c41f ] )
+  , Mont::unsafe_make( [ 0xa6d261c7 , 0x236a8d9f , 0xb6071c3a , 0xda30349f , 0xb66028f9 , 0xca171437 , 0x6687fffc , 0x21f03961 ] )
+  , Mont::unsafe_make( [ 0xf428f532 , 0xb5ab6660 , 0x5810757a , 0x47404a4a , 0xbd9b67bf , 0xb33c97ea , 0x900f896f , 0x1f856e32 ] )
+  , Mont::unsafe_make( [ 0x4c4cb28a , 0x3981af69 , 0xda01feee , 0x28efe047 , 0x989eb1cb , 0x4cab938b , 0x2e8d9119 , 0x0f362238 ] )
+  , Mont::unsafe_make( [ 0xcba2b73d , 0xc87690a2 , 0xca8118ed , 0x937a348f , 0xb8ac3e9e , 0x41e1e94c , 0xe37bfa96 , 0x07f5f9ce ] )
+  , Mont::unsafe_make( [ 0x468015ca , 0xdf613025 , 0xabbd0e36 , 0x3650bbef , 0x1a5e6a27 , 0xaba47dc6 , 0xa0da17be , 0x1bb5162e ] )
+  , Mont::unsafe_make( [ 0x732980aa , 0xd4de7fd6 , 0x102b86f4 , 0x20fcf956 , 0x83615c00 , 0x8ce98ec5 , 0xa837cab5 , 0x0599b6de ] )
+  , Mont::unsafe_make( [ 0xe5291450 , 0x64070f51 , 0xf7b1d085 , 0x54175b46 , 0x8921170d , 0xe39636d5 , 0x8e04c1f2 , 0x200ffb2a ] )
+  , Mont::unsafe_make( [ 0xa733a235 , 0xd200fbec , 0x5afc57b7 , 0x4f72cb70 , 0x2dd9e060 , 0xa6a45d86 , 0x75c2fcc9 , 0x244a1bf3 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x468fef8c , 0xe35759f8 , 0xcab66bbf , 0x9b20f292 , 0x29039ab6 , 0xe8e87f99 , 0x16cbd6a8 , 0x07862c1e ] )
+  , Mont::unsafe_make( [ 0xff2281f8 , 0xe1acd518 , 0x0651d8bf , 0x497acccc , 0x2b519324 , 0x7a77b017 , 0xf8139e1d , 0x25dcf193 ] )
+  , Mont::unsafe_make( [ 0x21c5622c , 0xc347eb26 , 0x3c787653 , 0x4e7739ba , 0x0ab409df , 0xe2c24dd5 , 0x3507647c , 0x20bd6376 ] )
+  , Mont::unsafe_make( [ 0x35187d50 , 0xbacb56f0 , 0x94546ad1 , 0x136e972e , 0x51846e1c , 0x38d9eb6a , 0x2d91ced9 , 0x1176267a ] )
+  , Mont::unsafe_make( [ 0x55f92a62 , 0x2f409734 , 0xcab7344d , 0xd870e4bf , 0x066dcaf1 , 0xab852962 , 0x499f23c8 , 0x12db94b3 ] )
+  , Mont::unsafe_make( [ 0x074d1c16 , 0xfde15e7d , 0x9b67b65a , 0xd775a091 , 0x9c9c6584 , 0x45cf475e , 0xbe12947d , 0x2341e702 ] )
+  , Mont::unsafe_make( [ 0x84428213 , 0x0f151187 , 0x92fd57bd , 0xb400ecd6 , 0x8742b270 , 0x9d48a668 , 0xe42176f6 , 0x1ab4f55c ] )
+  , Mont::unsafe_make( [ 0x59ce1ce0 , 0x094c96a1 , 0x4872693a , 0xee05114a , 0xe3306ce4 , 0xb7dc29b6 , 0x2b6bfd92 , 0x27018fe4 ] )
+  , Mont::unsafe_make( [ 0x335b4174 , 0x3dc81a4d , 0x49f04816 , 0xe7f1f87a , 0xb1d3c99c , 0x55c26e49 , 0x9bc4e30c , 0x04b8a063 ] )
+  , Mont::unsafe_make( [ 0xda88290e , 0x831dfdba , 0x77e00b31 , 0x39a82125 , 0xa8a7c7f5 , 0x4ddc51af , 0xc15cd2be , 0x29e096f1 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x2be9d708 , 0x530c330d , 0xad1b149d , 0x75178397 , 0x6297db3e , 0xa2ac109a , 0x72f114f6 , 0x0fa86c2f ] )
+  , Mont::unsafe_make( [ 0xebbf0008 , 0x595ac96e , 0xa89d28e1 , 0x535a9f36 , 0xb1ac3705 , 0x7e4c9aba , 0xe6233999 , 0x2b4668c8 ] )
+  , Mont::unsafe_make( [ 0xa4be58bd , 0xe84630fd , 0xfad5e2b8 , 0x8f79504c , 0xeb448d25 , 0x189af7a5 , 0xaaf161d6 , 0x0be3befb ] )
+  , Mont::unsafe_make( [ 0x34f89939 , 0x4b193ef3 , 0x2dda31e6 , 0xcbba3fc5 , 0xb0dd0958 , 0xe2cee933 , 0x02fef0f1 , 0x2fea7d52 ] )
+  , Mont::unsafe_make( [ 0xbfa7d338 , 0x13750f67 , 0x4e39c09c , 0x601944b2 , 0x94a86d08 , 0xf60b7f32 , 0xd6614b97 , 0x049b4eb8 ] )
+  , Mont::unsafe_make( [ 0x82018c58 , 0x03247b98 , 0x8f6af727 , 0x51ff7e43 , 0x6d1db391 , 0x6bb0b508 , 0x582fb083 , 0x246ea9d8 ] )
+  , Mont::unsafe_make( [ 0x3eb8cc1e , 0x16673e1b , 0xed91d776 , 0x483fe447 , 0xc9838974 , 0xabb41f0f , 0x5cbdc6bb , 0x2074b386 ] )
+  , Mont::unsafe_make( [ 0x5e734bf5 , 0xc42bc888 , 0x93cfdbb5 , 0xe061bee8 , 0x71ce89d2 , 0xdc55cb59 , 0x806ff42f , 0x0ea52b04 ] )
+  , Mont::unsafe_make( [ 0x6e486e8f , 0xe4777eac , 0x2fda9754 , 0x9c97ee47 , 0x6810ea14 , 0x8eb5ee4f , 0x33d3f7ee , 0x09dd6a56 ] )
+  , Mont::unsafe_make( [ 0xd56d5536 , 0xf7263349 , 0x83b22b66 , 0x4d47d5cc , 0x628b7926 , 0x744eabda , 0xf8a38a4b , 0x087cd876 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xa982a30e , 0x802f2e07 , 0x550117c8 , 0x3dd22582 , 0xd993e4e4 , 0x0ca923fc , 0xb594809a , 0x1673f46b ] )
+  , Mont::unsafe_make( [ 0xa157f20c , 0xcb279e1e , 0x0cfd0e3c , 0xcfb1b5d6 , 0xc905a8c2 , 0x4cf13258 , 0x61c70274 , 0x12bffd63 ] )
+  , Mont::unsafe_make( [ 0x064ed533 , 0x4257d6e9 , 0xd2905471 , 0x03fd004b , 0xab2a250b , 0xa9de2211 , 0x03a15faa , 0x0c9dbe8e ] )
+  , Mont::unsafe_make( [ 0xeac09cdf , 0xb0aee6b3 , 0xbd33ba8a , 0xa18e8a2f , 0x2b244992 , 0x3d62ea19 , 0x9580807e , 0x002b8183 ] )
+  , Mont::unsafe_make( [ 0xab208fd0 , 0xda028ac4 , 0xd782d6b4 , 0x6583d5cd , 0xdc893167 , 0x6ef56688 , 0xf030e1a0 , 0x04fd4059 ] )
+  , Mont::unsafe_make( [ 0x19306bb2 , 0x7a8f7ad1 , 0x9a96ee8a , 0x90c5a2e9 , 0xe935f66a , 0x54ee7d29 , 0xabba340e , 0x15b91369 ] )
+  , Mont::unsafe_make( [ 0x6ce303b3 , 0x5ed0d4bf , 0x4a896b61 , 0x24def8fb , 0xa71a315b , 0xf2c581b5 , 0x7767067b , 0x1f54c1ad ] )
+  , Mont::unsafe_make( [ 0xba00a35b , 0x05ef81f7 , 0x30b72a1c , 0xec164cc7 , 0x85313db6 , 0x89ee2283 , 0x2577e45c , 0x2dbd7252 ] )
+  , Mont::unsafe_make( [ 0x8a54ee5f , 0xd9d7dd58 , 0x026d9d3a , 0x7ecd91fd , 0x9c62b022 , 0x5d7ad529 , 0xc8530945 , 0x093a6a7f ] )
+  , Mont::unsafe_make( [ 0x19f38b73 , 0x8167a003 , 0xf2752d81 , 0xa0f05477 , 0x0edc9e97 , 0x9453bc38 , 0x9909704a , 0x033284df ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xfea1b59b , 0x77258f5a , 0x70ad61a4 , 0x8d8fe2c9 , 0x1aad7911 , 0xda036d4a , 0xa6b46fc6 , 0x172a9621 ] )
+  , Mont::unsafe_make( [ 0xa282b119 , 0x017cdf77 , 0xe831d3b8 , 0x21bbfc86 , 0xe538aa4c , 0x3f17ade6 , 0x7dce482a , 0x1f67a3ed ] )
+  , Mont::unsafe_make( [ 0x53bc0e93 , 0xdd7714a0 , 0x7bf0c080 , 0x809f831e , 0x92afba85 , 0xaa9a5afa , 0xa97f44eb , 0x29064ad0 ] )
+  , Mont::unsafe_make( [ 0x253d6ac1 , 0xed8b6924 , 0x64b5f5e2 , 0xd235e714 , 0x0cbad3b1 , 0xad4af6a5 , 0xd1db5799 , 0x2420db9e ] )
+  , Mont::unsafe_make( [ 0x1d4cf7d6 , 0xac811f95 , 0x83fecfbf , 0x1b2b474a , 0x6520a108 , 0x5a7322c4 , 0x22c6bbdb , 0x2d5d63b6 ] )
+  , Mont::unsafe_make( [ 0xaa3c5b83 , 0x58bc5f7a , 0x47e4c1d2 , 0x9794207d , 0xb7745d8b , 0xfe80eb19 , 0xfb3358bc , 0x1d666c33 ] )
+  , Mont::unsafe_make( [ 0x02f3c92e , 0xcf73b9e8 , 0xc9c96d1b , 0x19b2a9a4 , 0xac3d4ed3 , 0x12b7b5c3 , 0xfe2bbb8f , 0x2aff7663 ] )
+  , Mont::unsafe_make( [ 0x7015ea96 , 0x1d243e85 , 0x447e8c10 , 0x68f84efa , 0xba9eb837 , 0xf295d691 , 0xfe759d0a , 0x167ec9d3 ] )
+  , Mont::unsafe_make( [ 0xfdbbcdf0 , 0x43703e35 , 0xd7797fb0 , 0xa69ce6b8 , 0x5bcc97bd , 0x4b04e567 , 0xf4897bdd , 0x2c860281 ] )
+  , Mont::unsafe_make( [ 0xf9820e70 , 0xa0e38dd0 , 0x52dcfcd7 , 0xdfa2b339 , 0x565a5c20 , 0x9d3e6c04 , 0x1748b794 , 0x211bd320 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xdec933ee , 0xb8a30998 , 0x6f0aac30 , 0x010181cd , 0x6b6510c1 , 0xb5a52408 , 0x8afe09fb , 0x291fa553 ] )
+  , Mont::unsafe_make( [ 0xb0c53fb7 , 0xe4111fb9 , 0xd0550fed , 0x9bd77889 , 0xeae43ec8 , 0x32050763 , 0x740d6660 , 0x0c7422e2 ] )
+  , Mont::unsafe_make( [ 0x9e918bb7 , 0x93f48f56 , 0x12cded27 , 0x52e2e2ef , 0x74a591bc , 0xf793912f , 0x956f0640 , 0x1630be14 ] )
+  , Mont::unsafe_make( [ 0xa8f64483 , 0x85861672 , 0x93b74a96 , 0xa8b5b86b , 0x3da7bceb , 0x059d46e0 , 0x75307052 , 0x26294e49 ] )
+  , Mont::unsafe_make( [ 0x8780e25b , 0x2431cf11 , 0xef82964c , 0x0e682ac1 , 0xa6738443 , 0xe3f7a8b6 , 0xc5c1e2b1 , 0x2caf29ae ] )
+  , Mont::unsafe_make( [ 0x5414b351 , 0x7d2d6bcc , 0xd5f366fb , 0x657e93cf , 0x65950777 , 0x79858c67 , 0x9f7c5828 , 0x08c48d8a ] )
+  , Mont::unsafe_make( [ 0x18674cb5 , 0xf15766d9 , 0xf4f444ac , 0xd774cc48 , 0x69f482df , 0xbfd08b87 , 0xa4711476 , 0x1464b666 ] )
+  , Mont::unsafe_make( [ 0x05499d2c , 0x0eecc4e5 , 0x9baf3f0c , 0x1e2c2864 , 0x278e2fbd , 0x8ac2a463 , 0xc4813760 , 0x1b9d41e3 ] )
+  , Mont::unsafe_make( [ 0xf9b701ad , 0xe4fba2ba , 0x9c79bc95 , 0x0193aa3d , 0x9177beb6 , 0x383b2fbb , 0xdb45c1b9 , 0x2de4019a ] )
+  , Mont::unsafe_make( [ 0xf9c07a0e , 0xc13c640a , 0x8e40570b , 0x97ff8546 , 0x138c1cbf , 0x21a2992d , 0x640bf882 , 0x0e931c0f ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xb6f245ef , 0xdc3c6508 , 0x39dc7a13 , 0x05598be6 , 0x4a7ecb60 , 0x62d9b5e9 , 0x09020576 , 0x0881ad9f ] )
+  , Mont::unsafe_make( [ 0x01942a72 , 0xb084988c , 0x0f52d69e , 0xda3a60d6 , 0x701dd4f4 , 0x05c4768e , 0xa897a9b3 , 0x28ab43d2 ] )
+  , Mont::unsafe_make( [ 0x489e6ba4 , 0xe2f941d2 , 0x1efc47bb , 0x9230ac6b , 0xa05b01d8 , 0x1f069f46 , 0xdb27c637 , 0x2561cf51 ] )
+  , Mont::unsafe_make( [ 0xda8c1e96 , 0xe1a1c3b8 , 0x1c76d1e1 , 0x9321d681 , 0xe6523f96 , 0x4b3cf5e4 , 0x4a4f762d , 0x29f2b296 ] )
+  , Mont::unsafe_make( [ 0xbae8aaf5 , 0xdef4b4cc , 0xb7738c0d , 0xa42e5fbc , 0xb9f625c5 , 0xb2d657f1 , 0x6ae73b8e , 0x1c0dc505 ] )
+  , Mont::unsafe_make( [ 0xd74ac3a1 , 0x57752d80 , 0x556a1aa6 , 0x063dfe34 , 0x0c52d5ab , 0x53ab0122 , 0x0c8a9f2a , 0x188aa3bc ] )
+  , Mont::unsafe_make( [ 0x3432b75d , 0x549683d5 , 0xf4d34e43 , 0x1c78faf9 , 0x4e16cd5d , 0xf80b26a6 , 0x26071d89 , 0x0548f2f0 ] )
+  , Mont::unsafe_make( [ 0xe3080753 , 0x920076c9 , 0x029c0035 , 0x3222f0ce , 0xd8b43284 , 0x7fc56bdb , 0xd6fa200c , 0x23df0ef1 ] )
+  , Mont::unsafe_make( [ 0x17ad253e , 0x23106288 , 0xceed5c39 , 0x0eac13b7 , 0x3010fb22 , 0x3fc18ad0 , 0x85108e5d , 0x214bed6e ] )
+  , Mont::unsafe_make( [ 0x846c90b5 , 0xff601fcf , 0xa162f7cc , 0x8e2020b9 , 0xfb24073c , 0x0575504b , 0xc619010b , 0x2d3768ef ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xf104c3e1 , 0xdfe144a0 , 0x5becd8bf , 0xecb86f9e , 0xa778b029 , 0xdbb9b4e0 , 0x3de1ac36 , 0x0ad3f138 ] )
+  , Mont::unsafe_make( [ 0x3af810eb , 0x4017522f , 0x1d38ebed , 0x2d68bcd8 , 0x61921469 , 0xe71a3f86 , 0xae224715 , 0x0750d03f ] )
+  , Mont::unsafe_make( [ 0xf59660bf , 0x12f35865 , 0xfa9ae98d , 0xdce03a1f , 0x8062ab5a , 0x9a90db56 , 0x1b79f427 , 0x2ec7aa58 ] )
+  , Mont::unsafe_make( [ 0x4092784f , 0x028f64b1 , 0xe767fe9f , 0xf9ffd15b , 0xa3e9b043 , 0x4e76e576 , 0xe406d504 , 0x18a74a65 ] )
+  , Mont::unsafe_make( [ 0x82e4b9bf , 0x9e817104 , 0xe25d4f3e , 0xf733bcf1 , 0x1c4c81bf , 0xeb605da2 , 0x2f13c6bd , 0x20572a03 ] )
+  , Mont::unsafe_make( [ 0xce9c1023 , 0x71f4a17f , 0xec016fbb , 0x59d3395d , 0x658f3029 , 0x66789e74 , 0xdae5522f , 0x10788507 ] )
+  , Mont::unsafe_make( [ 0x3d5763a2 , 0xd7f36728 , 0x29a7f37d , 0xdc122d72 , 0x83848525 , 0xe7e956ca , 0x161c88eb , 0x074ea384 ] )
+  , Mont::unsafe_make( [ 0x797513f8 , 0xf18ea10a , 0xd705c9e8 , 0xf33df209 , 0x4d050d7d , 0x6a75051c , 0x94eeeed9 , 0x1847b6e3 ] )
+  , Mont::unsafe_make( [ 0x04e90eb3 , 0x406201d6 , 0xb9326474 , 0x797470c2 , 0xe223f509 , 0x2391891a , 0x67301d13 , 0x164f9d72 ] )
+  , Mont::unsafe_make( [ 0xbd3995d3 , 0x3801be36 , 0x0f9185d8 , 0xe42b4527 , 0x5c3925f9 , 0xd0210af2 , 0x7d639037 , 0x1f53619f ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x7ff1f62d , 0xe23aa489 , 0x6fb4eb8d , 0x114a2604 , 0xb784d178 , 0xff1834fe , 0xcf7e9af6 , 0x07016729 ] )
+  , Mont::unsafe_make( [ 0xd70db0f4 , 0xb3dbd70d , 0x5ede9a7b , 0x00f48c81 , 0x0da7466e , 0xbdc6fd84 , 0x913749b7 , 0x03cb017f ] )
+  , Mont::unsafe_make( [ 0x75b6f5a0 , 0x6109b559 , 0xa1936242 , 0xdb4bd6b9 , 0x13cd4610 , 0x16b0c307 , 0xfe4d6d2c , 0x17fac216 ] )
+  , Mont::unsafe_make( [ 0xb8f2159f , 0xec95ce4a , 0xce7f2521 , 0x094b32f2 , 0xa6f77f7f , 0x707a1b48 , 0x3840d0ae , 0x1c8b8ba5 ] )
+  , Mont::unsafe_make( [ 0x71f38a34 , 0x89ce143f , 0xb6c6240e , 0x7c116fa2 , 0x1782d1a7 , 0xa6548f29 , 0x03dc871c , 0x2e94bd5f ] )
+  , Mont::unsafe_make( [ 0xaef719b5 , 0x10fa3263 , 0xebfa082b , 0xd30226f1 , 0x51ce107a , 0x0c9b5a66 , 0x97989d2f , 0x03da5dfc ] )
+  , Mont::unsafe_make( [ 0x869574ba , 0xc96d4428 , 0x4040f4d6 , 0xd6cad29a , 0xa115498b , 0x995caaf7 , 0xd40d5665 , 0x0cec4f3c ] )
+  , Mont::unsafe_make( [ 0x4c744ee6 , 0xd548d390 , 0xa5cf3ba7 , 0xfe9f70ef , 0x646e3f90 , 0xabded1cd , 0x6df8afb5 , 0x04f27b1d ] )
+  , Mont::unsafe_make( [ 0xae902b3d , 0x443b7b79 , 0x48da67e6 , 0x15bc733b , 0x135ea7af , 0x8bd9c23e , 0xebdce1bc , 0x0bb30322 ] )
+  , Mont::unsafe_make( [ 0x2a3ad306 , 0x25f51ada , 0x72b47277 , 0x8852509b , 0x8ffbf0e0 , 0x85cb676f , 0x6bddf858 , 0x2784d836 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x1390af0a , 0x16ce84ba , 0xe9198223 , 0xb9348d03 , 0x0ca2f1d8 , 0x34e3154a , 0xf346b308 , 0x2958f3db ] )
+  , Mont::unsafe_make( [ 0xcc4d33ef , 0x74acb244 , 0xbb8343f0 , 0xad1bd68c , 0xcb4cce22 , 0x1fb653da , 0xa554c67f , 0x2a71de9b ] )
+  , Mont::unsafe_make( [ 0xfe1d699d , 0x311109a5 , 0xfaa36932 , 0xacf49886 , 0x462349d5 , 0x8d50d74e , 0x765d4444 , 0x2f502126 ] )
+  , Mont::unsafe_make( [ 0x9eb34bf9 , 0x88d72de6 , 0x2a967872 , 0xa17d0c41 , 0x68b0332a , 0x0236992e , 0x5e6a553e , 0x1bf8fd20 ] )
+  , Mont::unsafe_make( [ 0xba8477d8 , 0xbbbe9012 , 0xfe16793d , 0x297d3b8f , 0x10673806 , 0x65f88a1c , 0xf80e9fb3 , 0x1820ebe2 ] )
+  , Mont::unsafe_make( [ 0x63470abe , 0x06612bdc , 0x63f16d0b , 0x4e918084 , 0x46492588 , 0x2e1a00df , 0xad2a80bd , 0x14be81d6 ] )
+  , Mont::unsafe_make( [ 0x2ef83532 , 0x2391e893 , 0xa8ba388f , 0x6d32e455 , 0x4a1a37db , 0x0452efb4 , 0x99f1837a , 0x151b8825 ] )
+  , Mont::unsafe_make( [ 0x230f61fd , 0x12c3627c , 0x6b86817b , 0xb12993d9 , 0x6b8f5f9e , 0xdf928e59 , 0xc1f9272a , 0x101a18b0 ] )
+  , Mont::unsafe_make( [ 0x5d564533 , 0x32e3110c , 0x9ab01e77 , 0x4863e8db , 0x45fc87c3 , 0x2c4e5661 , 0xf6783b11 , 0x087fb9bf ] )
+  , Mont::unsafe_make( [ 0xbcd83c6f , 0xc20e1944 , 0x5c0c80b7 , 0x06cfa025 , 0x68956561 , 0x6e3c903f , 0x0b022bb3 , 0x2ab0fd2b ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x0d9ea072 , 0xe7c5c80a , 0x905fa2ac , 0xe0e13d97 , 0x7c42c43a , 0x59bc6a8d , 0x96b81e67 , 0x09856950 ] )
+  , Mont::unsafe_make( [ 0x29c68ae5 , 0x9af0044c , 0x5d689ec5 , 0x16b9cb39 , 0xccbddc48 , 0xf6ea62f0 , 0xb557859c , 0x0fc822ac ] )
+  , Mont::unsafe_make( [ 0x5ecd6bba , 0xdf1c0ea1 , 0x1b9f483b , 0x049575ba , 0xe82ec93e , 0x8fe9a836 , 0x56de2f05 , 0x19b0d9a0 ] )
+  , Mont::unsafe_make( [ 0x57ab28e0 , 0x6e588b07 , 0x9ddd9d23 , 0x7afb4b27 , 0x2079fdbe , 0x444963d9 , 0x9f2a4d93 , 0x2f793224 ] )
+  , Mont::unsafe_make( [ 0x1be69ea4 , 0xa9e58f94 , 0x584fe3cf , 0x81f51517 , 0x62d67110 , 0x735298d9 , 0x87859fd5 , 0x0208d87e ] )
+  , Mont::unsafe_make( [ 0xe3ba6b99 , 0x78ed4396 , 0x5ab3d250 , 0x7e9f7fd4 , 0x6e74b966 , 0x86b801f0 , 0xb6c9c4aa , 0x1cb332a4 ] )
+  , Mont::unsafe_make( [ 0xa1ddb90a , 0xa2778250 , 0x5e12d4e2 , 0x707188f8 , 0x58079d45 , 0x6f9fedfd , 0x726aef70 , 0x2904f041 ] )
+  , Mont::unsafe_make( [ 0x9e71f574 , 0xe472fefd , 0x2bd1bf8f , 0xf18de96f , 0x6da14acc , 0x16dc2474 , 0xc22e16a2 , 0x1179e8ff ] )
+  , Mont::unsafe_make( [ 0x4d1ed826 , 0x678dc219 , 0xbe5f7140 , 0x78f9f71b , 0xcf05223f , 0xe993da78 , 0xa95d2d5a , 0x1020102f ] )
+  , Mont::unsafe_make( [ 0xb97f7e48 , 0x85c91efc , 0x69647d37 , 0x41bd2a4d , 0x1af7d0ee , 0x40f649f4 , 0xfa440837 , 0x0d500d4c ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x73d69cfb , 0xdc2644d3 , 0x20b6f49e , 0x6c4ca63e , 0xaa2a75fb , 0x9c9eba92 , 0x6d1d811c , 0x22619348 ] )
+  , Mont::unsafe_make( [ 0xf04a319c , 0x23754330 , 0xa20364e7 , 0xd0bc5be4 , 0x20b03273 , 0xdbaecec6 , 0x17c4d8b6 , 0x2d54b350 ] )
+  , Mont::unsafe_make( [ 0xeb78c09e , 0x4b1ca76a , 0x1259ac45 , 0x8803036f , 0xf7a47c4d , 0x357bc754 , 0x9d916c6e , 0x1779a667 ] )
+  , Mont::unsafe_make( [ 0x989e30df , 0x3ec3e83a , 0x5251de81 , 0xb7ec8512 , 0x2b63266f , 0xbe60cad5 , 0xe9aa1401 , 0x0506a1e2 ] )
+  , Mont::unsafe_make( [ 0x75622473 , 0x2203f156 , 0x0f51424a , 0xea640325 , 0x591001cc , 0xabba5211 , 0xea66ec81 , 0x1ea93114 ] )
+  , Mont::unsafe_make( [ 0x7220b5e4 , 0x7c98fe40 , 0xc2758c48 , 0x185be353 , 0xfb828324 , 0xd7ff240d , 0xec49e521 , 0x14ef9f6d ] )
+  , Mont::unsafe_make( [ 0xaec1f948 , 0x6c244548 , 0xc53c09eb , 0x79c3fd80 , 0x744a1486 , 0x097bcec4 , 0x28fcf967 , 0x129f19db ] )
+  , Mont::unsafe_make( [ 0xdd5c0b10 , 0x9874e75c , 0x8ac162ce , 0xe62edafb , 0x5c6174d2 , 0xff7b0d61 , 0x2061efc5 , 0x2cf09861 ] )
+  , Mont::unsafe_make( [ 0x233667e5 , 0x68608e50 , 0x76e5370d , 0x8f87222c , 0xbd4050d0 , 0x96d5356e , 0x5adb3eae , 0x21e6d4ef ] )
+  , Mont::unsafe_make( [ 0xa8203c24 , 0x6905d802 , 0x6a05ed24 , 0xd3c241a5 , 0x93718c61 , 0x853350f2 , 0x38328ee1 , 0x23449111 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x80d4bc19 , 0xa324b8d6 , 0x81659452 , 0x5167747f , 0xe4c45990 , 0x3e303891 , 0x889ad9e0 , 0x2d813bff ] )
+  , Mont::unsafe_make( [ 0xbbc82bc6 , 0x42265721 , 0x649739a1 , 0x3ad78471 , 0xe58895aa , 0xe89b5d9e , 0xc1a446fc , 0x220708f8 ] )
+  , Mont::unsafe_make( [ 0x5db65acf , 0x86d2ea77 , 0x0cc24d66 , 0xc8f0ac79 , 0xc961cf5c , 0x29cb27a9 , 0xf279f70a , 0x1d9d3301 ] )
+  , Mont::unsafe_make( [ 0xd890d558 , 0xc998154e , 0x3e955ae4 , 0x78ce1498 , 0x01521928 , 0xcf977cfe , 0xdfbfdf91 , 0x2739b644 ] )
+  , Mont::unsafe_make( [ 0x2f9bd4d0 , 0x89b34fa9 , 0xeb4829d9 , 0x5ced4e36 , 0xf417d12b , 0x16525def , 0x4da45e6a , 0x1348ee79 ] )
+  , Mont::unsafe_make( [ 0xe163ce78 , 0x10f078e7 , 0xaf8006de , 0x3fa70751 , 0x7469e591 , 0xd5b197c3 , 0x0b4501c1 , 0x2d6f51c8 ] )
+  , Mont::unsafe_make( [ 0xe784cfef , 0x8ca98c20 , 0xac570cfb , 0x489a72fb , 0xca199356 , 0x4e279a60 , 0xf29f26b0 , 0x00e6cecf ] )
+  , Mont::unsafe_make( [ 0x47783653 , 0xc24468a1 , 0x953f5015 , 0x7e4c68f1 , 0x5533966b , 0x77da5d0e , 0xad3f6abb , 0x21ef71ce ] )
+  , Mont::unsafe_make( [ 0xec4b979e , 0xfdd3a296 , 0x7d773d42 , 0x2247c06c , 0xe9bf6ec9 , 0xcc7b9d4b , 0xb0d324c5 , 0x0fe723ca ] )
+  , Mont::unsafe_make( [ 0xcafbe3a7 , 0xaabbc089 , 0x5234ae51 , 0x0dcc40d2 , 0x779f8aed , 0xf8703df9 , 0x66d156f5 , 0x06f100cf ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x0971f1a2 , 0x2bff3acf , 0x88351a23 , 0xa2478c5e , 0xb5196aff , 0x79c45d4d , 0x8797a60b , 0x1053223d ] )
+  , Mont::unsafe_make( [ 0xdd17e30b , 0x0cc9b1ec , 0x553e3d14 , 0x8cbbb958 , 0x40314923 , 0x8fa5318a , 0x9cca447f , 0x07e7b3a7 ] )
+  , Mont::unsafe_make( [ 0x3851ff20 , 0xdce29d3c , 0x20a03149 , 0x6c897aed , 0xa3fc3df4 , 0xcb3fe912 , 0xd1fd5ffb , 0x25830cad ] )
+  , Mont::unsafe_make( [ 0xeb4d1ae0 , 0xdc4c3619 , 0x9e7332f1 , 0x12414637 , 0xf004d32c , 0x159a810a , 0x5ab8511a , 0x0580d31a ] )
+  , Mont::unsafe_make( [ 0x17a66560 , 0x3916937c , 0x48f98da6 , 0xcb1b1cf5 , 0x9cbf8033 , 0x5c491101 , 0x9f252d70 , 0x2f1815cc ] )
+  , Mont::unsafe_make( [ 0x0fc4d67f , 0x16a214be , 0xc2af2cc1 , 0xcd714243 , 0x60cdfc7e , 0xccb3fbca , 0x8d1f3443 , 0x20012c15 ] )
+  , Mont::unsafe_make( [ 0x33ebaa32 , 0xa6cf2186 , 0xdfc7d4dc , 0x7a6f5fd2 , 0x380a9acd , 0xe4d2a3c9 , 0xc58acd59 , 0x0e959235 ] )
+  , Mont::unsafe_make( [ 0xc6634528 , 0xf524231b , 0x8862c433 , 0x05433629 , 0x006d798b , 0xbd479f37 , 0x58e1512a , 0x203c60be ] )
+  , Mont::unsafe_make( [ 0x9eb7bb51 , 0xf2d3f24e , 0x1adb9250 , 0xb4463e2f , 0x3f3c5f63 , 0x7cd8bbbc , 0x0afc9762 , 0x13ec2cbb ] )
+  , Mont::unsafe_make( [ 0x6dd848c5 , 0xb6b7270d , 0x543296cb , 0x2e372dc7 , 0x5fa06bf2 , 0x6ebff992 , 0x31ff91ff , 0x269be7f8 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xaa36c498 , 0x5c1af902 , 0x7eda7a04 , 0xea1b7349 , 0x40f145cf , 0x32d152d5 , 0xbf7a463d , 0x0adb934a ] )
+  , Mont::unsafe_make( [ 0xeac36d4c , 0x1c8c38a3 , 0xbf4a16fd , 0x89fc7a00 , 0x343422b0 , 0x5a9b3d9f , 0xa5b4e0af , 0x042a484a ] )
+  , Mont::unsafe_make( [ 0xfe4de3d3 , 0xc20d4c6b , 0x1654c967 , 0x63f8df7d , 0x22c658f6 , 0x78c72fc3 , 0x74eaece9 , 0x214203b4 ] )
+  , Mont::unsafe_make( [ 0x8c61dac4 , 0x4b66dd9d , 0x3076afc0 , 0x4a06017f , 0xe47a17b6 , 0xa966c010 , 0x0ab8a643 , 0x02b5e236 ] )
+  , Mont::unsafe_make( [ 0x4b676e17 , 0x0f47de49 , 0x49e2b901 , 0x55ab8208 , 0x4f254e27 , 0x0e2b4d9d , 0x1dc85b61 , 0x21e8e1ab ] )
+  , Mont::unsafe_make( [ 0x8a0e6bf3 , 0xa5eeb2d4 , 0xf2435d71 , 0x9ee7ab74 , 0x90796aa2 , 0x7b5b2323 , 0xa4386b37 , 0x293e2cc3 ] )
+  , Mont::unsafe_make( [ 0xa0ad0e63 , 0x98cfab7a , 0xc33f72c5 , 0x10ac40fa , 0x59306768 , 0xa611ad7d , 0x70cfa06f , 0x001d3a91 ] )
+  , Mont::unsafe_make( [ 0x7bb538d9 , 0x68f80031 , 0xa08331ba , 0xd540ec4d , 0xaae7f96a , 0xd10fc89b , 0xa94f57e0 , 0x13b3b3b1 ] )
+  , Mont::unsafe_make( [ 0x050158b7 , 0x7abbaab5 , 0x11abb902 , 0xeb192e31 , 0x03ec3d5e , 0xe4380466 , 0x68383086 , 0x2e69defb ] )
+  , Mont::unsafe_make( [ 0xc2a11b2b , 0x78db4f4f , 0x75e1d406 , 0xdc830615 , 0x431170e8 , 0x33a3736a , 0x575f3239 , 0x098a3fba ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xd553e2af , 0xb7d4cd0f , 0xc17ff93b , 0x9c2fb8b5 , 0xc511b905 , 0xfb4ec779 , 0xc17346e8 , 0x1412fc0a ] )
+  , Mont::unsafe_make( [ 0x68cd63ca , 0x23d648cc , 0x7831f629 , 0x7b342a81 , 0x3c4df690 , 0x956600cd , 0x07c045ad , 0x215a55ce ] )
+  , Mont::unsafe_make( [ 0x26d10a6c , 0x93e37d01 , 0xd4b1390b , 0xf1f93295 , 0xeb833382 , 0x5b4e051d , 0x2b024f0e , 0x1bae003c ] )
+  , Mont::unsafe_make( [ 0xf5dd2a32 , 0x0472ec63 , 0x19925b84 , 0x239fcfa3 , 0xdb89045b , 0xf3fb4612 , 0xccb68743 , 0x2ebde406 ] )
+  , Mont::unsafe_make( [ 0xfc5554be , 0x97db142d , 0x52384dac , 0x925027b9 , 0xe9316abb , 0xf6e782e6 , 0xb6db949c , 0x13bd6243 ] )
+  , Mont::unsafe_make( [ 0x9975c919 , 0x93f6d29f , 0x4a069ab6 , 0x3ddcded6 , 0xffa02fe8 , 0xffd5e179 , 0xd2d1a77b , 0x28df5f28 ] )
+  , Mont::unsafe_make( [ 0x9952bb2f , 0xcd128e29 , 0xaae66e46 , 0x0e489a50 , 0x1c8f0da7 , 0xeb9af9dc , 0xbb5c8410 , 0x1322412f ] )
+  , Mont::unsafe_make( [ 0x7b0c2da6 , 0x387bedf4 , 0x01ab124f , 0x6cabc3f8 , 0x5cfe2b8e , 0x9e5e952a , 0xb9f8ac2a , 0x18b3700c ] )
+  , Mont::unsafe_make( [ 0x1c7d675f , 0xe67a5571 , 0x4aecd60b , 0x874feb86 , 0x5c7e97a9 , 0xf6fbca21 , 0x2c0cfb60 , 0x08929364 ] )
+  , Mont::unsafe_make( [ 0x85bcb640 , 0x8414d996 , 0x4691adf5 , 0x1ac6c7e2 , 0xb6816b3f , 0x6ee36172 , 0xe238b146 , 0x0292f225 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x54571b8b , 0x2bf627da , 0x0e091f2f , 0x72f98ba5 , 0xd82564a7 , 0x28f2020b , 0xf87bd375 , 0x1fdb1f38 ] )
+  , Mont::unsafe_make( [ 0x4baa7e08 , 0x70cf7815 , 0xca6a4d45 , 0x7f0717de , 0x6a463c48 , 0x1d151d77 , 0x1dafd4e5 , 0x0208c392 ] )
+  , Mont::unsafe_make( [ 0xa4ad6332 , 0x799d90e7 , 0x28f47d3a , 0x401f79ac , 0xa2da07cb , 0x1a7388db , 0xf3fc9505 , 0x295f6049 ] )
+  , Mont::unsafe_make( [ 0xba016d09 , 0x69754aaf , 0x80e5c80c , 0x214694f5 , 0xb9c0bb41 , 0xd1157d4e , 0xfd3dc3c7 , 0x1c1d5b7f ] )
+  , Mont::unsafe_make( [ 0xb3cb0353 , 0xa03ff3cb , 0xe5c1a3e4 , 0xc8652330 , 0x1adce581 , 0x1e1ab5b0 , 0xc39a4729 , 0x2f5e28a7 ] )
+  , Mont::unsafe_make( [ 0x6e480bc2 , 0x449fa545 , 0x9eaff3aa , 0xa7de9089 , 0x9e50727e , 0x482411bd , 0xb681bc0f , 0x274e26eb ] )
+  , Mont::unsafe_make( [ 0x4487bf92 , 0x2b770fb0 , 0xeddcfbfd , 0x22edbeee , 0xd00f1be0 , 0x3121ed36 , 0x2b45293c , 0x007c7726 ] )
+  , Mont::unsafe_make( [ 0x0b9ae554 , 0x47669aaf , 0x40bbf5b5 , 0xf581afec , 0x7bdd6a4f , 0xa92dbb62 , 0xa67fd3e5 , 0x15fc6ca5 ] )
+  , Mont::unsafe_make( [ 0xb9d97bfb , 0x8e250f47 , 0x52d8fe06 , 0x64dc6398 , 0xf9a41212 , 0x92bb2bd4 , 0x68923e80 , 0x0c0ef781 ] )
+  , Mont::unsafe_make( [ 0xc1da5c4c , 0x56e62a2f , 0x382cbe9a , 0x788e0b84 , 0x94748b4c , 0xbad0f61a , 0x652945ac , 0x22deecb1 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xf5bf481c , 0x40922aa9 , 0xed9b8652 , 0x088fdbfc , 0x7cf58e3a , 0x16f583d4 , 0x987669a4 , 0x250567d4 ] )
+  , Mont::unsafe_make( [ 0x3a5f24f2 , 0x8936ba0d , 0x1cc1a394 , 0xab65f340 , 0x006c595a , 0xd645864d , 0x5c352ccb , 0x0b7d4f51 ] )
+  , Mont::unsafe_make( [ 0xe902ff44 , 0x40990630 , 0x02b33eba , 0xd2800a9a , 0x61110d81 , 0xd4991159 , 0xa0bc9b0d , 0x0bc2ff21 ] )
+  , Mont::unsafe_make( [ 0x74cd5549 , 0x676fd287 , 0xef94909c , 0x16d2685d , 0x700b47d2 , 0x5e60d18a , 0xf6cb5cd9 , 0x179beb51 ] )
+  , Mont::unsafe_make( [ 0x485c309d , 0x560cb5fd , 0xce2df61b , 0x7794efa1 , 0x8d3899fa , 0xeceb15f9 , 0xe71cfdcb , 0x0cdadaa1 ] )
+  , Mont::unsafe_make( [ 0xa038f8ba , 0x11f1f4f4 , 0x51aadc88 , 0x336cb87c , 0xd76decb8 , 0xf3c82731 , 0xbfa5215f , 0x0462b970 ] )
+  , Mont::unsafe_make( [ 0x27e9752d , 0x13bf2d4c , 0x60148bd5 , 0xddd8e410 , 0x9d3a6106 , 0xcf636261 , 0x22e51dca , 0x0435fb7a ] )
+  , Mont::unsafe_make( [ 0xd742a588 , 0xb25ab6f7 , 0x70c094dd , 0x6f540cf9 , 0x12d3a0a9 , 0x8a5c7914 , 0x699cebe0 , 0x18241a2e ] )
+  , Mont::unsafe_make( [ 0x108e70bd , 0x32b2d3de , 0x1f9a5db0 , 0x644e9a69 , 0xe2c291d8 , 0xb554c6fc , 0x660e96f5 , 0x2259a81c ] )
+  , Mont::unsafe_make( [ 0x07ceba9d , 0x6852d7d9 , 0x347f87da , 0x156b7f4b , 0xb04005d3 , 0xbb298e7d , 0x36b2f66e , 0x03de920c ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x4e175d58 , 0x6f14f00e , 0xa5ef69e4 , 0xac6bd457 , 0x212d7749 , 0xb361c2c8 , 0xde5e3515 , 0x1fc25ac3 ] )
+  , Mont::unsafe_make( [ 0x64a938c5 , 0xab0dea14 , 0xab04fb78 , 0xf0ea5b88 , 0x94b4724e , 0xa84c86d0 , 0x055205b6 , 0x28defae2 ] )
+  , Mont::unsafe_make( [ 0x7eb404a6 , 0xdf129409 , 0x9d9fb360 , 0x0021b7b7 , 0xbdefdf98 , 0xaac160cf , 0x60f46a3c , 0x0d1218d5 ] )
+  , Mont::unsafe_make( [ 0x336362a9 , 0xf6396e04 , 0x232eaad3 , 0x53ba9685 , 0x2401b245 , 0x4ff200a5 , 0x811c6e1f , 0x12de97f2 ] )
+  , Mont::unsafe_make( [ 0xd12e9b15 , 0x373b1f94 , 0x2c5e1022 , 0x210f7da6 , 0x4e3e1a40 , 0xb169a90c , 0x36a19c4d , 0x047fa3aa ] )
+  , Mont::unsafe_make( [ 0x2ea45168 , 0x5c5455de , 0xa8d12654 , 0xa484c975 , 0x0f770111 , 0x080919aa , 0x2d912466 , 0x1d5fcf7c ] )
+  , Mont::unsafe_make( [ 0x16cdfbc4 , 0x2ada1eec , 0x908d07e3 , 0xa61c8b92 , 0xf3816415 , 0x3991d566 , 0xd325e270 , 0x22f0cdb0 ] )
+  , Mont::unsafe_make( [ 0xd0973f04 , 0x87516d3f , 0x6714ed75 , 0x2b1dcdc2 , 0x7f710731 , 0x89030f4b , 0xde2c419f , 0x2a8b8d24 ] )
+  , Mont::unsafe_make( [ 0x12553ce2 , 0xdbcff8bb , 0x5d7ad4db , 0x3888d17e , 0xe947983f , 0x8608ec17 , 0x09203b0c , 0x147a93ea ] )
+  , Mont::unsafe_make( [ 0xb9ab8fda , 0x39b8bdfb , 0xdbde0929 , 0x714d0b2a , 0x9bc104b4 , 0x0689f320 , 0xfa2b64f0 , 0x1cf4fbbd ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xa999e7a2 , 0x3ffad384 , 0xb406ebd0 , 0x246d723a , 0x3e7c90f8 , 0x82004c21 , 0xde4bb384 , 0x0388550b ] )
+  , Mont::unsafe_make( [ 0xe6aed55b , 0x399c7a29 , 0x085f3801 , 0xc1965265 , 0x9b08d282 , 0x6e5f1f37 , 0x7848d4ca , 0x03d01253 ] )
+  , Mont::unsafe_make( [ 0x0481a43d , 0x104532c8 , 0x93a794c3 , 0x12de3391 , 0x277008bf , 0xa22f84be , 0x1ee9eefe , 0x1401b154 ] )
+  , Mont::unsafe_make( [ 0x67619545 , 0x34489f44 , 0x7bb01bcc , 0x898a504a , 0xf0729845 , 0x04401ce4 , 0x7dd6ea2a , 0x2e1705a5 ] )
+  , Mont::unsafe_make( [ 0xc1b3a75f , 0x829ac7d3 , 0xe5e03425 , 0xdaff6406 , 0x60d4261a , 0x4716d234 , 0xd8827b3c , 0x0a1227d2 ] )
+  , Mont::unsafe_make( [ 0xa6cf3597 , 0x6b64a9ef , 0x7d028cf4 , 0xf6c0e3a3 , 0x88a5766c , 0x1560fa87 , 0x352d9ab7 , 0x113a9ee4 ] )
+  , Mont::unsafe_make( [ 0xcee3664e , 0x84a6bacc , 0x36c4664c , 0x625ce800 , 0xd3483737 , 0x53e436fe , 0xdd256795 , 0x285f6c02 ] )
+  , Mont::unsafe_make( [ 0x858a678b , 0x9c839411 , 0x65444a4b , 0xb8d27858 , 0x86f7afae , 0xfd6f53c0 , 0xdeb4f8b5 , 0x238cf0c5 ] )
+  , Mont::unsafe_make( [ 0x3d2985d6 , 0xd33aedfa , 0xde6cac8c , 0x3879a7a8 , 0xa23cdc55 , 0x69491728 , 0x5fd845bb , 0x27bd6608 ] )
+  , Mont::unsafe_make( [ 0xe3baf8e7 , 0x2b3ef358 , 0x6f8c8c70 , 0x434ebdbf , 0x2d19ec91 , 0xff01e52a , 0x9225bf98 , 0x23c5a851 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xccc7e87b , 0x8ee08baa , 0x44858947 , 0x80cad1af , 0x4eb2e7a0 , 0x20ece74b , 0x23cd8da7 , 0x0f29e3df ] )
+  , Mont::unsafe_make( [ 0x4e8d6bc0 , 0x14786c47 , 0xfb476458 , 0x138b34f9 , 0xb13d1779 , 0x1ec0ac25 , 0xafd8d5d5 , 0x08b42120 ] )
+  , Mont::unsafe_make( [ 0x73854c0a , 0x59b64487 , 0xc777c6b0 , 0x5bec411e , 0x708e1e7f , 0xc42e2cb1 , 0xc94763a7 , 0x2f9cf472 ] )
+  , Mont::unsafe_make( [ 0x66483732 , 0xec5a4bf5 , 0x41f16869 , 0x78fc1c82 , 0xaa526e89 , 0x26f4ba1b , 0xed62a0fb , 0x2e97b42e ] )
+  , Mont::unsafe_make( [ 0x3d4d7beb , 0x21fd6a3d , 0xdaebe4ba , 0xd904f636 , 0xdc7a508e , 0x587e4d46 , 0x8d389b65 , 0x2edccab9 ] )
+  , Mont::unsafe_make( [ 0x1f2df031 , 0x826714bf , 0xf1f7e44a , 0xcb1c40ee , 0xac5d021a , 0x2f9b7e5e , 0x6e6970a9 , 0x13b096ce ] )
+  , Mont::unsafe_make( [ 0xc1b46fd5 , 0xb39b980c , 0x04a73302 , 0xa0d978be , 0x1bf9a0e7 , 0x83e34df7 , 0x8559c400 , 0x2b5ee14f ] )
+  , Mont::unsafe_make( [ 0x99bc1c28 , 0x90551617 , 0x482af351 , 0x3020ba95 , 0x45c4ecce , 0x59fdedbb , 0x71f60871 , 0x0df5a34b ] )
+  , Mont::unsafe_make( [ 0x504deb93 , 0xbbb82cc4 , 0x7258ae44 , 0xe4236dd4 , 0xa03e78aa , 0x8fd2f7e7 , 0xc04ec2f9 , 0x1c040056 ] )
+  , Mont::unsafe_make( [ 0x5a146252 , 0xd9ade286 , 0x32cfd2fd , 0xc07cdd66 , 0xf815ce64 , 0xa4081251 , 0xcbabf693 , 0x239d43a9 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x21ed2c50 , 0x897115a1 , 0xfcc78109 , 0x198a5f8a , 0x645a8aa8 , 0x9acd44d3 , 0xd17335b3 , 0x00d97c2c ] )
+  , Mont::unsafe_make( [ 0x2d063f56 , 0x512ff715 , 0x7ffd3b33 , 0x9fe1a59e , 0xf8e8bd76 , 0xc73e0db0 , 0x49e7d858 , 0x2803366b ] )
+  , Mont::unsafe_make( [ 0x19c9a291 , 0x7a4f14f4 , 0xa7dc79ac , 0x7fab8eef , 0x00b3e20c , 0x05c26365 , 0xf8d4a6f0 , 0x2522327c ] )
+  , Mont::unsafe_make( [ 0x7cf19660 , 0x059fb779 , 0x509bd19c , 0x97b83314 , 0xd1555876 , 0xb0fc0193 , 0x94da81bc , 0x24620aad ] )
+  , Mont::unsafe_make( [ 0x6960d5a9 , 0x558ccd87 , 0x43d92cbe , 0x5ef737be , 0xb2237288 , 0xe05174bf , 0xe6ffc32c , 0x07ca5cb8 ] )
+  , Mont::unsafe_make( [ 0x8ae83d8d , 0xb227de18 , 0xe7d1766a , 0x140c87bd , 0x01efe5cb , 0xd293bf4f , 0x4876ec5f , 0x12ce0afc ] )
+  , Mont::unsafe_make( [ 0xa061faf7 , 0x295dd604 , 0x76efadc8 , 0xf5f9dfcc , 0x7dd98866 , 0x716fbcb5 , 0xe40e62e8 , 0x183411a5 ] )
+  , Mont::unsafe_make( [ 0x4b3bbdd9 , 0x07122979 , 0xe6cfcf85 , 0xdae05511 , 0x5740c3a9 , 0xba2d0325 , 0xeaf931cc , 0x23583d48 ] )
+  , Mont::unsafe_make( [ 0xabec247a , 0xbf12424a , 0x0d8b73ff , 0x77efe338 , 0xe7794c19 , 0x20130d79 , 0x8643b584 , 0x29dda370 ] )
+  , Mont::unsafe_make( [ 0x38cac40c , 0xeb9674d6 , 0x19ba9c20 , 0x44d51b1f , 0x430515dd , 0x5cb13ff8 , 0x849c19ed , 0x27632c21 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xb952b028 , 0x42328ca5 , 0x810fa687 , 0xd7bf1ab1 , 0x75753475 , 0x47070c86 , 0x196be421 , 0x1965f6a2 ] )
+  , Mont::unsafe_make( [ 0x954b9ab7 , 0x116049bc , 0x36e441c5 , 0x9bef1ca1 , 0xb5ef8e7c , 0xb8f2cc4d , 0x17790d14 , 0x0176fcd3 ] )
+  , Mont::unsafe_make( [ 0x513ab8e7 , 0x9c1290ec , 0xef3e4365 , 0x363b1f4f , 0x247e47fb , 0x0e55ed5c , 0xb47846ae , 0x29098803 ] )
+  , Mont::unsafe_make( [ 0x000856f8 , 0x71033421 , 0x63eabac4 , 0x6f40efd3 , 0x97a26459 , 0xfc54668d , 0x51981387 , 0x1f140110 ] )
+  , Mont::unsafe_make( [ 0x9b61c62d , 0x2c5447bd , 0xfe193495 , 0xf0d39a20 , 0x6b9a32b0 , 0x9ef4ef45 , 0xe1896ce9 , 0x015c2709 ] )
+  , Mont::unsafe_make( [ 0x41043e24 , 0x446e2e93 , 0xfb23ec59 , 0x810c1373 , 0xc09c4ea0 , 0x840dccba , 0x8b0419e1 , 0x032484d8 ] )
+  , Mont::unsafe_make( [ 0x3a0fecb7 , 0x24ad72d0 , 0x1e5d4f34 , 0xe18decfb , 0x5abe66b9 , 0xd3e2ea6f , 0x8b343f0c , 0x05ab4f89 ] )
+  , Mont::unsafe_make( [ 0x98c4ef00 , 0x435481a4 , 0xec1be125 , 0x9200a012 , 0xc7793b66 , 0x4d8075bc , 0x4c22d307 , 0x0d47d81c ] )
+  , Mont::unsafe_make( [ 0x4d6141da , 0xf4e853b4 , 0x8718ecab , 0xee9b9bb0 , 0x1e41ddcb , 0x378e5e90 , 0x52d3ed2f , 0x0b35ea81 ] )
+  , Mont::unsafe_make( [ 0x8c7784ef , 0x02226a3a , 0x1aa0f892 , 0xab98a06b , 0xf03174cd , 0x8fc41cc2 , 0x7686fdfd , 0x172e3ab8 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x725396c9 , 0x9e425b62 , 0x873e74ea , 0xe22cad8f , 0x281d574a , 0x5a081871 , 0x4dfaaacb , 0x16d4a672 ] )
+  , Mont::unsafe_make( [ 0xe11a3d3d , 0x9e09ed1a , 0x032609f5 , 0xe9a1fbe6 , 0x5cfdc9d0 , 0xfa39ca8b , 0x5bde737f , 0x0cc3483c ] )
+  , Mont::unsafe_make( [ 0xa83af4d3 , 0xef96f93c , 0x3363c883 , 0x02f04b5e , 0x5d173113 , 0x5f1aa43f , 0x4c7268e9 , 0x2b2a7869 ] )
+  , Mont::unsafe_make( [ 0xf4eeba81 , 0x5179dc33 , 0x66b88994 , 0x587bd705 , 0x131691a6 , 0xb0c470c5 , 0x3a1c9090 , 0x05dab40e ] )
+  , Mont::unsafe_make( [ 0xe0103001 , 0xdc78a355 , 0xc79356d0 , 0x3f2c8721 , 0x09ba6cc6 , 0x198f1bf3 , 0xe12c8393 , 0x0f6e2d39 ] )
+  , Mont::unsafe_make( [ 0x44888893 , 0xc831f445 , 0xd4f9d84d , 0x7c93d656 , 0xd617fb08 , 0x9a8f2b3c , 0x58223201 , 0x01faf9c1 ] )
+  , Mont::unsafe_make( [ 0x04d13c57 , 0x441991cb , 0xee8e8d0e , 0x2eab3463 , 0x18b93ce4 , 0x70438ee8 , 0x50551225 , 0x24db602d ] )
+  , Mont::unsafe_make( [ 0x5dfd2468 , 0x87ec98a7 , 0x46a805eb , 0xc6f49289 , 0x1488a1eb , 0x0126e269 , 0xf53127ed , 0x1b2182ec ] )
+  , Mont::unsafe_make( [ 0xce0757b6 , 0xd2e6370a , 0xe7fff109 , 0x540abbd3 , 0xb082c11a , 0x1b37ae7b , 0xe6b13dad , 0x2f391a35 ] )
+  , Mont::unsafe_make( [ 0xc517b9d1 , 0xcde3e928 , 0xe4f46d27 , 0xb6ac99a5 , 0x8fc80eff , 0x575a48ef , 0x1ed88e3c , 0x0b9d3b32 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0xd3681f41 , 0x5c9677ca , 0x2a4758d4 , 0x41d685a6 , 0x4c99fad4 , 0x021a5d01 , 0x53b29cea , 0x22235863 ] )
+  , Mont::unsafe_make( [ 0xd28f4e21 , 0xc82d2193 , 0x5aa9f3fe , 0x66c49b82 , 0x2d43c6f8 , 0x2ceea820 , 0x9ddb773d , 0x117b8877 ] )
+  , Mont::unsafe_make( [ 0x9082f334 , 0x99ad4529 , 0x6d6f3052 , 0x78f8194f , 0xbf429d32 , 0x7f661903 , 0xea621bf4 , 0x00c9a866 ] )
+  , Mont::unsafe_make( [ 0xebf9c972 , 0x60f637c0 , 0x63f6ea5b , 0x178321a1 , 0x2b58e3a9 , 0x7d5d2307 , 0x3ad8237e , 0x1950e187 ] )
+  , Mont::unsafe_make( [ 0x874ddfe2 , 0x34af0f63 , 0xce302738 , 0x2f5f04d1 , 0x99557174 , 0xf54e5e39 , 0x66b1780f , 0x2b202a18 ] )
+  , Mont::unsafe_make( [ 0xf3ae1609 , 0x835092cb , 0xcdae2317 , 0x87f3a31c , 0xeae9d5db , 0x1e77337e , 0xa3b11108 , 0x2d1b0f87 ] )
+  , Mont::unsafe_make( [ 0xdac66bbb , 0xf3f35324 , 0xd833e152 , 0xd4449de8 , 0x6236e792 , 0x4b32182a , 0x7b17d2b4 , 0x0d7b3cdd ] )
+  , Mont::unsafe_make( [ 0x2a0f722e , 0x060d9a38 , 0xca02e207 , 0x5962a709 , 0x34951d48 , 0x4079ed30 , 0x4fcb99fd , 0x26578527 ] )
+  , Mont::unsafe_make( [ 0x977039f9 , 0xb28c8c14 , 0x5ab6afc5 , 0x5f2398e6 , 0x0f55d886 , 0xa5e1f506 , 0xfb31a3a1 , 0x03384b12 ] )
+  , Mont::unsafe_make( [ 0x531f3f5e , 0x0bbf9337 , 0x71283c55 , 0xe9e4c1aa , 0x802d76a7 , 0xac5c315c , 0xfd3e8ec4 , 0x08ccd063 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x48d40a63 , 0x6419a4a9 , 0x911dc259 , 0x75013dd8 , 0x7a816c5c , 0x6ca475ac , 0x5f92241b , 0x042d4b2e ] )
+  , Mont::unsafe_make( [ 0xd492537f , 0xb9c6e976 , 0x8b52a6e2 , 0xd89ef3f7 , 0xd0a8d9e7 , 0x3a6d1f72 , 0x3296e683 , 0x19d09492 ] )
+  , Mont::unsafe_make( [ 0x23f930e7 , 0xbbdfb53f , 0x8274fe52 , 0x9ddf832e , 0x64bef71e , 0xafb436a6 , 0x1aae8f80 , 0x114ce732 ] )
+  , Mont::unsafe_make( [ 0x2e37a246 , 0x802a1897 , 0xbc11c2bf , 0x21fadd7e , 0xf2720bbf , 0xe80ddaeb , 0x0546724e , 0x1b0fe21d ] )
+  , Mont::unsafe_make( [ 0x62aef2a4 , 0x6444111c , 0x83e5868e , 0xe65610c0 , 0xccf9ad20 , 0xd092ed43 , 0xed3a110c , 0x2c458309 ] )
+  , Mont::unsafe_make( [ 0x4fc1c302 , 0x8953e02b , 0x8fbf57b4 , 0xfd82c26d , 0xe9a0fbab , 0x85b8d8c4 , 0x1f772961 , 0x0910a023 ] )
+  , Mont::unsafe_make( [ 0x04c9ba98 , 0x87c928c2 , 0x13bbb459 , 0xfa1cdb58 , 0x55b07447 , 0xd7ac3520 , 0xe19bc836 , 0x011a6c3c ] )
+  , Mont::unsafe_make( [ 0x6f3baa6a , 0x9bf8670f , 0xa9bf4864 , 0xc01f32d9 , 0x4f7bf1b0 , 0x4ab710a8 , 0x91992fe7 , 0x0b122202 ] )
+  , Mont::unsafe_make( [ 0xb1f88ffc , 0x6d702418 , 0xf8f71bcc , 0x182aeee4 , 0x750f3fec , 0xcd676b9e , 0x82e1293a , 0x26d6d521 ] )
+  , Mont::unsafe_make( [ 0xdaab566f , 0x62c5d847 , 0x7ac2eab7 , 0x77ba26c2 , 0x738ec1e2 , 0x908bef0f , 0xe896911b , 0x229d2cda ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x4136a772 , 0x42c05a6b , 0xa8703e6e , 0x3b40b15e , 0x86bab0bd , 0x39f49688 , 0xce208df9 , 0x2a0c7c42 ] )
+  , Mont::unsafe_make( [ 0x12d1cae5 , 0x15d16b67 , 0xc19cbf8a , 0x644b24cd , 0x88c056a5 , 0x79956e04 , 0x6a9efb98 , 0x11b2ced3 ] )
+  , Mont::unsafe_make( [ 0x46788634 , 0x3f97ef62 , 0xab153a95 , 0xc97edd17 , 0xd733e700 , 0x30e7e3d1 , 0xe56dd141 , 0x075f75b8 ] )
+  , Mont::unsafe_make( [ 0x4e717fc4 , 0x90b73c09 , 0x6bc65a73 , 0x4475f90d , 0x9829db6d , 0xe622cee7 , 0x5a95687d , 0x0cab64ce ] )
+  , Mont::unsafe_make( [ 0xd8ec065e , 0xd4572cfb , 0x94f98365 , 0x1c4dffcd , 0x8af8d55a , 0xdd7999f2 , 0x1e89a417 , 0x1ce84a90 ] )
+  , Mont::unsafe_make( [ 0x08520466 , 0x144dfebb , 0x0d46255f , 0xaddb48d0 , 0x88c9ff7a , 0x24abb712 , 0x0e249168 , 0x228a8639 ] )
+  , Mont::unsafe_make( [ 0x751c6793 , 0x61fd091e , 0xe814e1c3 , 0x2ab3ce5d , 0x47e2e1b3 , 0x7d58e26b , 0xf79867ac , 0x19081a6b ] )
+  , Mont::unsafe_make( [ 0xbb076843 , 0xf2155ff2 , 0x136f211e , 0x3820bd5c , 0x9a2733d0 , 0x335e2938 , 0xa24b5472 , 0x1df5b772 ] )
+  , Mont::unsafe_make( [ 0x65086e45 , 0x1fa157d0 , 0x333f8767 , 0x5e4fd448 , 0x20a96b46 , 0xee2ed713 , 0xa0726ef6 , 0x1cf34627 ] )
+  , Mont::unsafe_make( [ 0x2799ab16 , 0x5c03db27 , 0xe194b285 , 0x373fe260 , 0x886ebb87 , 0x48fdf73a , 0x3cbfc9a6 , 0x1a8628c9 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x2cf88b53 , 0x33c11fe4 , 0x6e60e1d8 , 0x1393c1ad , 0xdb7159e0 , 0x66e600f1 , 0x8f970ea7 , 0x060706bd ] )
+  , Mont::unsafe_make( [ 0x938b160a , 0x6f250004 , 0x5313d874 , 0x684b32b4 , 0xe6ac9c7f , 0x8a274009 , 0xe28fe024 , 0x2f3f6361 ] )
+  , Mont::unsafe_make( [ 0xd272761e , 0x39d229fb , 0xa5517f69 , 0x63e9f8de , 0x00f034eb , 0xf841aca4 , 0x45118caa , 0x16702480 ] )
+  , Mont::unsafe_make( [ 0x7fff533a , 0x13dbf694 , 0xd93fa0aa , 0x518763ee , 0x195397b8 , 0x3ae13344 , 0xee29adfb , 0x08dd275d ] )
+  , Mont::unsafe_make( [ 0xa03384db , 0xf53b3c25 , 0x78bbfe48 , 0x9070fec2 , 0x59fb8829 , 0xf115d165 , 0xf7e03bff , 0x1e1acd71 ] )
+  , Mont::unsafe_make( [ 0x037614ab , 0x32f59d4b , 0xa5442cfc , 0x12e9130d , 0xdffbd077 , 0xea876367 , 0x75546401 , 0x0a9e2f7e ] )
+  , Mont::unsafe_make( [ 0x0ca5f60a , 0x23790983 , 0xcbab90fa , 0x3ebea756 , 0xfb92feff , 0xf6287d79 , 0xee4b1a61 , 0x289373eb ] )
+  , Mont::unsafe_make( [ 0x4f4e99eb , 0x85aef6bf , 0xe898135d , 0xbf880dbf , 0x2624c789 , 0xc3b69a08 , 0x2765c6ed , 0x122adf5b ] )
+  , Mont::unsafe_make( [ 0x18203e2e , 0xd105ab5e , 0x2bf61518 , 0x542a2e85 , 0x4a146247 , 0x5f5b7f7a , 0x50f0e81e , 0x2f06aee2 ] )
+  , Mont::unsafe_make( [ 0x72e46826 , 0x346cd7b4 , 0x35f18e4c , 0x0a53b1b7 , 0x88f29e9b , 0xd8547c54 , 0x73c5783a , 0x22ea9114 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x02d4234e , 0xf20a9021 , 0xee2744d6 , 0x5f0f399f , 0x41bed4ce , 0x70956973 , 0x0b4dc32e , 0x15115e8b ] )
+  , Mont::unsafe_make( [ 0xd6b3ef3c , 0x8fb9e65c , 0xb2260882 , 0xe6c85318 , 0x435b3ff2 , 0x6de44770 , 0x7551d918 , 0x278adc46 ] )
+  , Mont::unsafe_make( [ 0x0fad01be , 0x44eff35f , 0xac6141be , 0x004e5093 , 0xd38b68fa , 0xad454209 , 0xbb8e88f0 , 0x1c81b066 ] )
+  , Mont::unsafe_make( [ 0x19f3189a , 0x1813b7d0 , 0x174c5e93 , 0xd4ac2562 , 0x6b527885 , 0x85f0eb19 , 0x88874373 , 0x033b9ca3 ] )
+  , Mont::unsafe_make( [ 0xdbd5de0a , 0xa1015ea0 , 0x1ed2afc5 , 0x728b4614 , 0x6b6f7521 , 0x6dd8df85 , 0xabf74c9c , 0x0035703c ] )
+  , Mont::unsafe_make( [ 0x36338caf , 0x1bc2b185 , 0xa88ec2c9 , 0xe6be51e9 , 0x29726abf , 0xcec3bbaf , 0x302dcb27 , 0x1116ce6a ] )
+  , Mont::unsafe_make( [ 0xff8ac6da , 0xd76e4172 , 0x18821b48 , 0xb4c4162f , 0xcfd8aa80 , 0xfec2e3e8 , 0xf5a9830b , 0x0f7e599f ] )
+  , Mont::unsafe_make( [ 0xac54a40d , 0xa25d03bc , 0x4caa8358 , 0x052f51a7 , 0x24636f9d , 0xdf804c16 , 0xc1ea4476 , 0x03dcf502 ] )
+  , Mont::unsafe_make( [ 0xd853b48f , 0xa5a8b58b , 0x3cc3c426 , 0x411d87c4 , 0x024494a5 , 0x6df8735f , 0xbde60a7e , 0x04e7f309 ] )
+  , Mont::unsafe_make( [ 0x784037e1 , 0xf31b45d5 , 0xe9cf6b20 , 0xa78e141c , 0x46c2710e , 0x1ee2d646 , 0x6b46b0cd , 0x03b4f245 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x0342fec9 , 0xcb18956b , 0xb5f16fc6 , 0xcdc53dbe , 0x87940632 , 0x103c6093 , 0x64bbf2a5 , 0x0bfaee59 ] )
+  , Mont::unsafe_make( [ 0xf6900ce2 , 0x52a5300d , 0xd77b2bd0 , 0x9f1074aa , 0x45877f79 , 0x8b95596c , 0x708265c9 , 0x05cc62c5 ] )
+  , Mont::unsafe_make( [ 0xb78cb3ab , 0xbf55e529 , 0xeaaf7fd1 , 0xe4720d32 , 0x0c6ab87c , 0x1f452922 , 0xc7d82016 , 0x03a1b748 ] )
+  , Mont::unsafe_make( [ 0x2f24bd38 , 0xd82448a8 , 0x2736c0c4 , 0x92e473fa , 0xb3d785bd , 0x6d368d4e , 0xb5e96896 , 0x14524565 ] )
+  , Mont::unsafe_make( [ 0x5c2921e2 , 0x8bb97a5e , 0x7cc80da7 , 0xd0121b03 , 0xf85e833e , 0x5a0db6e0 , 0x112dd5d0 , 0x052789f1 ] )
+  , Mont::unsafe_make( [ 0x6e0f4025 , 0x2e7307dc , 0x90e3d420 , 0xb90d8f14 , 0xc4b65269 , 0xa0b53a8a , 0x9e3ddf12 , 0x26aec5d3 ] )
+  , Mont::unsafe_make( [ 0x38c5e997 , 0x4401b56c , 0x71dd4a5b , 0x310fb040 , 0x9026bdb0 , 0x3dfb0a0f , 0x11c5ef8b , 0x0f560da6 ] )
+  , Mont::unsafe_make( [ 0xbdf67e35 , 0xc5d0c25f , 0x6c51e1d1 , 0x3485ab29 , 0xaee8eb8b , 0xe65e5d35 , 0x2bece9ee , 0x05d30353 ] )
+  , Mont::unsafe_make( [ 0x828b1a0e , 0xb698e9e0 , 0x168e5101 , 0x2dddb20c , 0x68f904b7 , 0x8a7122bf , 0x81716ec5 , 0x188089e5 ] )
+  , Mont::unsafe_make( [ 0x424aafd5 , 0x9ac894cc , 0x424c0719 , 0x33f8036b , 0xe86af2f4 , 0x36d40ede , 0xe0159c69 , 0x10a1c4f9 ] )
+  , Mont::unsafe_make( [ 0x3ee62bad , 0x40608a78 , 0xa961436e , 0xb04481ce , 0x9ed8c008 , 0x97a709db , 0xbf2d293a , 0x281f30c1 ] )
+  , Mont::unsafe_make( [ 0x848ad008 , 0x3e34cdbe , 0x429adb61 , 0x7d698ab6 , 0xca72596d , 0x6356939e , 0x14ea54ad , 0x1ff42882 ] )
+  , Mont::unsafe_make( [ 0x52a1bed5 , 0x757bfa69 , 0x7b2e59fd , 0xb41c5768 , 0xb2fd8199 , 0x36f2fa14 , 0x816717a3 , 0x271b9fb0 ] )
+  , Mont::unsafe_make( [ 0x4536184b , 0x1e7c331b , 0x2bd93d16 , 0x884a4c7c , 0x2cf75e92 , 0xc93f944a , 0xacffa3e4 , 0x25dd20bf ] )
+  , Mont::unsafe_make( [ 0xe730d45a , 0x5448c245 , 0xcf3f3147 , 0x6c9f7791 , 0x34f594a4 , 0x8f78c2ac , 0x138ebb9c , 0x1470797e ] )
+  , Mont::unsafe_make( [ 0xa33d6383 , 0x9a13d19c , 0x102f00d4 , 0xafe226b4 , 0x271dbb53 , 0x1b223bbf , 0x65e932b6 , 0x05c5a902 ] )
+  , Mont::unsafe_make( [ 0xd9992600 , 0xefda51ba , 0x76521488 , 0xb16fd81c , 0xc9ea82ab , 0x2cb484be , 0x8fa91fdf , 0x23da01c0 ] )
+  , Mont::unsafe_make( [ 0x1b61380e , 0x3e66e111 , 0x482fed80 , 0xb3bb4d0a , 0x8b3c87c3 , 0xf1fc52e4 , 0x270a7a26 , 0x29c2b69d ] )
+  , Mont::unsafe_make( [ 0xf72c8118 , 0xa891ce4c , 0xdca7b6dc , 0x05ea1137 , 0xb17f099b , 0x5d29edc5 , 0x4c751416 , 0x0d2055ef ] )
+  , Mont::unsafe_make( [ 0x3d059f09 , 0xd5084b98 , 0x0ef857ed , 0xb26e4cc7 , 0x71981283 , 0x4b26508a , 0x185ba003 , 0x2b1a7b81 ] )
+  , Mont::unsafe_make( [ 0x5961d9e4 , 0xa582444f , 0x27fd1e0a , 0xff793618 , 0x275f16e3 , 0x017b68f5 , 0x1a0f5f1f , 0x2cdb7c00 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
@@ -0,0 +1,1068 @@
+
+// circomlib's Poseidon constants for t=7
+
+use crate::bn254::montgomery::*;
+
+
+//------------------------------------------------------------------------------
+
+// round constants (t for external, 1 for internal rounds; flattened)
+pub const CONST_C: [Mont; 119] = 
+  [ Mont::unsafe_make( [ 0x70770602 , 0x219faa09 , 0x6e820969 , 0x9a73c1dc , 0x4becff8b , 0x1117bae4 , 0x1d27ec8d , 0x00c3f5af ] )
+  , Mont::unsafe_make( [ 0x9aeb8abf , 0xcd7fa627 , 0x5eb55d49 , 0xf16ba25b , 0x8e2f542c , 0x9fda5ae5 , 0xeb9bd349 , 0x0f79c548 ] )
+  , Mont::unsafe_make( [ 0x77e72db9 , 0xaa94898f , 0xd5538b5f , 0x5491d93c , 0x5eb5c3cc , 0xcf7c09e3 , 0x63dcaa2e , 0x16c9a998 ] )
+  , Mont::unsafe_make( [ 0xec82d4fc , 0x1136ce7c , 0x175d44e6 , 0xf67145b7 , 0x48ce29fd , 0x38580139 , 0x33748e1d , 0x24d2e998 ] )
+  , Mont::unsafe_make( [ 0x651ae724 , 0x49cf12e8 , 0x24cf1717 , 0xff0df554 , 0x5be1c82a , 0x638677e7 , 0x37a30ad1 , 0x0e0c4070 ] )
+  , Mont::unsafe_make( [ 0x019e997b , 0x84aff868 , 0xcccc7093 , 0x4e831233 , 0x0a03284b , 0xc37b5d53 , 0xb17ee697 , 0x00cd3bf7 ] )
+  , Mont::unsafe_make( [ 0x5d3a8e91 , 0x8f51804c , 0xa30e5b85 , 0x41eed532 , 0x2cc9f18e , 0x5e7f612b , 0x36319154 , 0x0808e26e ] )
+  , Mont::unsafe_make( [ 0xe020d83e , 0xedb36625 , 0x533612a1 , 0x544cffe0 , 0x6aa7a465 , 0xf43e7e65 , 0x98d76959 , 0x1844983f ] )
+  , Mont::unsafe_make( [ 0xa9da20c5 , 0xac83bf51 , 0x5a141dcd , 0x1c5ddf0e , 0xe381d90b , 0x8c3dda65 , 0x7f832f2c , 0x08117227 ] )
+  , Mont::unsafe_make( [ 0xfde18ea2 , 0x93a89dd0 , 0x8955a1d8 , 0x0a8053ae , 0x510a10b0 , 0x78a13b11 , 0xd1edffcf , 0x1ebe0ecd ] )
+  , Mont::unsafe_make( [ 0x9c3ac209 , 0x7148b992 , 0xc8d31d19 , 0xbb00d8f7 , 0xab93d03e , 0x3e523850 , 0x9e62d4d2 , 0x1afb12f0 ] )
+  , Mont::unsafe_make( [ 0x0891f90e , 0x858635ca , 0x33692c89 , 0xd132e85e , 0x6b402e46 , 0x2530b98b , 0x6a55d2ea , 0x0788a14a ] )
+  , Mont::unsafe_make( [ 0x1987d9c2 , 0x54430841 , 0x04405ef1 , 0x0f5335bb , 0x987e0d10 , 0x1b4e3d08 , 0x022219c6 , 0x17a06563 ] )
+  , Mont::unsafe_make( [ 0x84e5d630 , 0x5c2c0b61 , 0x131d954f , 0x335c4e2a , 0x2cfe93b5 , 0x0170bdea , 0xfbd69b3a , 0x1323123b ] )
+  , Mont::unsafe_make( [ 0x319c1c47 , 0x6a366293 , 0x2d33e700 , 0xeac3cb6a , 0x03a0bc09 , 0xe44b54fa , 0xfd366aea , 0x223cb8be ] )
+  , Mont::unsafe_make( [ 0x0b8458af , 0x6916f7ed , 0xd9e711d4 , 0x443226ac , 0xdf763908 , 0xe2c92c79 , 0xee37870e , 0x1c3fcb39 ] )
+  , Mont::unsafe_make( [ 0x999f0751 , 0x110a8c02 , 0xcd21b4a0 , 0x3c9f75c4 , 0xe72cd933 , 0x20ddb5ce , 0x601f0416 , 0x12a1c289 ] )
+  , Mont::unsafe_make( [ 0xbcfd461d , 0x6ab56b0e , 0xa5768859 , 0xfd8f3c33 , 0x70879443 , 0xee89b830 , 0x0fd87ec4 , 0x2c1af86f ] )
+  , Mont::unsafe_make( [ 0xcfd0a649 , 0x0f01b471 , 0x592c614f , 0x5d07d050 , 0x84918d86 , 0x0f686190 , 0xe7b2e10c , 0x19ab365f ] )
+  , Mont::unsafe_make( [ 0x1a8dddf9 , 0x2c8c48d6 , 0x17b474f5 , 0xc0812b0c , 0x44a191ed , 0xd486c6df , 0x3ed2dcd8 , 0x0c59fbdb ] )
+  , Mont::unsafe_make( [ 0xdfd6088f , 0x884e19a4 , 0xde0d8fdc , 0x05fdce78 , 0x7980fab1 , 0xd21fbb36 , 0xe8f9b813 , 0x2ce1a784 ] )
+  , Mont::unsafe_make( [ 0x0470701f , 0xa3e8eba3 , 0x24f23609 , 0xf1bae361 , 0x3c422b20 , 0x01ad3d46 , 0x74f91fdf , 0x275b1016 ] )
+  , Mont::unsafe_make( [ 0xee0a095a , 0x5ed68166 , 0x91d4b26b , 0x0acb8eaa , 0xbcc2e347 , 0x46d16308 , 0x5b2e1513 , 0x1590fb3b ] )
+  , Mont::unsafe_make( [ 0xcfb1e551 , 0x5765e645 , 0xe2b1025f , 0xa294f48b , 0xbd3f8911 , 0xa0e1441f , 0x03fa1537 , 0x1a12c8d2 ] )
+  , Mont::unsafe_make( [ 0xbad058d9 , 0xeb8db505 , 0x5056f21f , 0xca502a9f , 0x59a1c050 , 0xbf0c45cc , 0x9e187f38 , 0x2bc98cf1 ] )
+  , Mont::unsafe_make( [ 0xf5f97b47 , 0xc9e4f779 , 0xb2332295 , 0x7735fdd8 , 0xa4a659b6 , 0x64b02f6b , 0xd4016412 , 0x1dd0c927 ] )
+  , Mont::unsafe_make( [ 0xc76bce30 , 0x942237ff , 0xf605d799 , 0x7f803326 , 0x891ba0f2 , 0x37a28307 , 0x990c078d , 0x11dab23f ] )
+  , Mont::unsafe_make( [ 0xb461143b , 0x53cebf62 , 0x12cd1238 , 0xb211842e , 0x2570b5d8 , 0x1ebdb7b5 , 0x3748b9f3 , 0x0b12b2ef ] )
+  , Mont::unsafe_make( [ 0x6ff1460d , 0x89bec1db , 0xe7ede9b6 , 0x300c8604 , 0x4afbda6f , 0x05bfff3b , 0x32c76de1 , 0x2671d0b3 ] )
+  , Mont::unsafe_make( [ 0xb0e102e4 , 0x5f03d8c2 , 0x5076c8ad , 0xa696a61f , 0xa31d6da2 , 0x218bfa42 , 0xaf1f1eb7 , 0x2da903db ] )
+  , Mont::unsafe_make( [ 0x889f329b , 0xc3e73961 , 0x38026ab0 , 0x949591c7 , 0xf393c182 , 0x8748f9a8 , 0xc563441b , 0x0cd805ac ] )
+  , Mont::unsafe_make( [ 0xd1f7a9ac , 0x7368e29d , 0x91f23cf1 , 0xb58d44b4 , 0x41132068 , 0xfd16bf76 , 0xb0691af2 , 0x1b93e61f ] )
+  , Mont::unsafe_make( [ 0x8be96168 , 0xe7c86366 , 0x45c4fda7 , 0x64a2f60c , 0xbc702b24 , 0x653d96bb , 0xfd574f2e , 0x26cdf479 ] )
+  , Mont::unsafe_make( [ 0x99e9f752 , 0xfe576be6 , 0x2bf0b310 , 0x78d5a5ca , 0xa2229c99 , 0x10853931 , 0x0384cc20 , 0x05224494 ] )
+  , Mont::unsafe_make( [ 0x6f43b6c4 , 0xbb56a83c , 0xd92203cb , 0x6e9348a6 , 0x17a942b5 , 0x73bc5e46 , 0x7453d5b5 , 0x0c20543c ] )
+  , Mont::unsafe_make( [ 0xbb5cc82c , 0x80f25963 , 0x0f5b6f07 , 0x85d07e6f , 0x20792699 , 0x0580beef , 0x91d4429d , 0x23593e06 ] )
+  , Mont::unsafe_make( [ 0xb594365a , 0x5c83a4dc , 0x8755d6d0 , 0x76bcd1e0 , 0xcfd6e193 , 0x73c422c1 , 0xc838c739 , 0x2b8d9af7 ] )
+  , Mont::unsafe_make( [ 0x470a93f9 , 0xe8a163f2 , 0x63a54619 , 0x23c99a2e , 0x8d17e564 , 0xa01ca2e2 , 0x043e1cfd , 0x19752e5c ] )
+  , Mont::unsafe_make( [ 0xf2b50a65 , 0xf145fc99 , 0x1dc792d6 , 0x9b4b1862 , 0x92d0bbd5 , 0xac650189 , 0x367d3c87 , 0x14e72bec ] )
+  , Mont::unsafe_make( [ 0x823d61f0 , 0x619e18b8 , 0x3242f244 , 0xa6d3f078 , 0xa72c2b36 , 0x79a28e3a , 0x2a28d2ce , 0x2e310df8 ] )
+  , Mont::unsafe_make( [ 0xadc86acc , 0x8faf67ae , 0xd0061b80 , 0xad5a3a11 , 0x722fdaed , 0x937feda1 , 0xfd58a97e , 0x0b7ab5a5 ] )
+  , Mont::unsafe_make( [ 0x5cc19fa8 , 0x092c0f88 , 0xfbfe4177 , 0x7d8b2402 , 0xda51b506 , 0xc55fc986 , 0x5216aad5 , 0x29220821 ] )
+  , Mont::unsafe_make( [ 0xad2c515c , 0x12d8a726 , 0xda7df2f3 , 0xe2b7d148 , 0x0ec62f6e , 0x8827b980 , 0xe4583d4d , 0x1ce64d9d ] )
+  , Mont::unsafe_make( [ 0xcfc0de7b , 0x941f8358 , 0x1934c1a8 , 0xf578a108 , 0xae391419 , 0xd320b52a , 0xd808cb2f , 0x029b065d ] )
+  , Mont::unsafe_make( [ 0x58340b9e , 0xdec09657 , 0x63bcc587 , 0x068fea6c , 0x389a7589 , 0x13c04c38 , 0x691c7390 , 0x03890364 ] )
+  , Mont::unsafe_make( [ 0x0293ffd8 , 0xffbf3091 , 0x44d51168 , 0x597213f3 , 0x0c97065e , 0xd86faac9 , 0xdc449e64 , 0x20510cc4 ] )
+  , Mont::unsafe_make( [ 0x9b498230 , 0xa90badb5 , 0x5d009cf4 , 0x86ebd80a , 0x98412c6a , 0xfcbd49ee , 0x01b908cf , 0x0ab6da50 ] )
+  , Mont::unsafe_make( [ 0x64d9eaac , 0x6074209c , 0x69e4d625 , 0x03e2140a , 0xf3439457 , 0x06eb570e , 0x43562382 , 0x1d49a255 ] )
+  , Mont::unsafe_make( [ 0xf2ffc4a4 , 0x66b2679d , 0xac7d5014 , 0x61061c26 , 0x7082654b , 0xc81404c8 , 0x98b493d4 , 0x068ea7b3 ] )
+  , Mont::unsafe_make( [ 0x93368756 , 0xe95ee30c , 0x63dc4a24 , 0xad597d34 , 0x71181911 , 0xd54254ad , 0x593e2de7 , 0x18c6c3aa ] )
+  , Mont::unsafe_make( [ 0x1706ffff , 0x65627e0b , 0x2d3cf38d , 0xae758f34 , 0x57dcc0e9 , 0x1b105545 , 0x3f759f36 , 0x2b4c1758 ] )
+  , Mont::unsafe_make( [ 0x08952451 , 0x47b24f04 , 0x6fa22504 , 0xf926859e , 0xef1462c5 , 0x13d15cf5 , 0x3bbbe07f , 0x29fddf73 ] )
+  , Mont::unsafe_make( [ 0xb9bbf9b0 , 0xe44237d0 , 0xefdf62e9 , 0xc13fb41c , 0x4e909b69 , 0x5a122088 , 0xa4e13e1e , 0x189f2d69 ] )
+  , Mont::unsafe_make( [ 0x0462ac66 , 0x2022ece9 , 0x75955a18 , 0xb2a1ba66 , 0x01ff6da2 , 0x7114828a , 0x4fa38131 , 0x0228fee2 ] )
+  , Mont::unsafe_make( [ 0xfa699165 , 0x21bb382f , 0xe5abf9aa , 0x95919cc7 , 0x5417ad02 , 0x24c7ac1b , 0x8d846a9d , 0x120bbc6e ] )
+  , Mont::unsafe_make( [ 0xf19a05a3 , 0xa3a9b455 , 0x3661d77c , 0x28d4f016 , 0xa746d156 , 0xca0b9b64 , 0xf4a5f4cc , 0x148ff321 ] )
+  , Mont::unsafe_make( [ 0x2a1b9cde , 0xa3957ffd , 0xc9c430fa , 0x6ce58e07 , 0xc776a61b , 0xba79e813 , 0xeeb5e7eb , 0x1029b843 ] )
+  , Mont::unsafe_make( [ 0x0f4decfd , 0x199bfc3e , 0x04fd9f02 , 0xa2ffe55b , 0xaf1e0a26 , 0xb6c6e13d , 0x6bc2bb1c , 0x21bf0440 ] )
+  , Mont::unsafe_make( [ 0xba7e86fe , 0xaf422996 , 0xad37afa2 , 0x0366a7df , 0xb131ee8f , 0xac1a39e5 , 0xdd0a5e8d , 0x24523304 ] )
+  , Mont::unsafe_make( [ 0xffb65ef2 , 0x9b72f184 , 0x0da8c277 , 0xeac8cc4a , 0xf6aaa502 , 0x7f755ebe , 0x727cbe1c , 0x257c71c8 ] )
+  , Mont::unsafe_make( [ 0xe68fa24e , 0xcd8e3c3d , 0xd0c30edd , 0xdb13f60e , 0x322bf7ff , 0x4dbec62f , 0xed63ef8a , 0x0d53c645 ] )
+  , Mont::unsafe_make( [ 0x19b335e5 , 0x56442434 , 0x323e863b , 0x4a5ab00d , 0x1f0bf1af , 0xa95e1e80 , 0x641e8eda , 0x09f5c25d ] )
+  , Mont::unsafe_make( [ 0x0ae0f542 , 0x05540943 , 0xd5856e3b , 0x1b316511 , 0xa259959d , 0x2e9de16a , 0x85975907 , 0x26ed2e4d ] )
+  , Mont::unsafe_make( [ 0x347ed1f6 , 0x12f1d9eb , 0xa4dff9ee , 0x416498b8 , 0xd4b4260f , 0xc9d842e9 , 0xc7f863e0 , 0x2b3f5d41 ] )
+  , Mont::unsafe_make( [ 0x61e83e81 , 0x75fafb90 , 0x47da2c55 , 0x26f4d463 , 0x7beaa41f , 0xa8f66b65 , 0xaf61f296 , 0x196d74b2 ] )
+  , Mont::unsafe_make( [ 0xf0b675c2 , 0xaab946c4 , 0x7cada72f , 0x4dab6c74 , 0x2b0699a9 , 0xca559676 , 0xa1ea2ef3 , 0x12095884 ] )
+  , Mont::unsafe_make( [ 0xc69cdae2 , 0x6ce40223 , 0x226fe3d2 , 0x5aae7cee , 0x96bba4ee , 0x2730f30a , 0x91a5d8a6 , 0x24aa2978 ] )
+  , Mont::unsafe_make( [ 0xecccc229 , 0xbe6def6f , 0xcbb0d821 , 0xa5f5157c , 0x79592ef1 , 0xa8e32fa6 , 0x37f925b4 , 0x206b97d3 ] )
+  , Mont::unsafe_make( [ 0x19f8aff8 , 0xc8b015b0 , 0x67a7f051 , 0x84429ec6 , 0xc04bdb3a , 0x719d77f6 , 0xaab26f34 , 0x1bf0c68a ] )
+  , Mont::unsafe_make( [ 0x4a8074de , 0x4f5a8213 , 0xf09bdf3f , 0xbc91dcd1 , 0x444fc266 , 0x13e4afa0 , 0x1b568cc0 , 0x2c971b3e ] )
+  , Mont::unsafe_make( [ 0xf365b951 , 0xc98dafac , 0x169a000a , 0xd292cfc3 , 0x9e65e1c9 , 0x8bde5846 , 0x3d0c5103 , 0x00254152 ] )
+  , Mont::unsafe_make( [ 0xbd9b09f9 , 0x20b002ec , 0x87fcd9f1 , 0x56b7c316 , 0x33934279 , 0xc9a7dc04 , 0x984ae1ff , 0x2ab4ebe0 ] )
+  , Mont::unsafe_make( [ 0x7cbac6a1 , 0x6883464d , 0xabf44561 , 0xd4dc3282 , 0xa6f0933b , 0xb280ceb8 , 0xf16295d3 , 0x11160121 ] )
+  , Mont::unsafe_make( [ 0xb560f056 , 0x3322b277 , 0x352d6a41 , 0xa5bf8968 , 0x122650f6 , 0x2cf00bf1 , 0x373e7eac , 0x2ce08d33 ] )
+  , Mont::unsafe_make( [ 0x7ca191aa , 0x37afbfb3 , 0x1c962981 , 0x62a6a42d , 0xc74bf02a , 0xafdeff89 , 0x0cea0f38 , 0x0c19aed2 ] )
+  , Mont::unsafe_make( [ 0xb678ce11 , 0x0f4e4a43 , 0x1d7b252b , 0xc45f0fb6 , 0xae4771a6 , 0x197169bf , 0xb1ca6d61 , 0x1b4ca955 ] )
+  , Mont::unsafe_make( [ 0x4e8c2954 , 0x823ff3be , 0xae774bdc , 0x8e4fcce1 , 0x229c2fc9 , 0x6fce9161 , 0x43762404 , 0x11142e3d ] )
+  , Mont::unsafe_make( [ 0x8c6200b6 , 0xe41bc7be , 0x1cfea1a8 , 0x4b8de883 , 0xd3984702 , 0xc393a11a , 0x6a56b9fa , 0x1dce1ad5 ] )
+  , Mont::unsafe_make( [ 0x869b74b4 , 0x479dacb6 , 0x1531bc9c , 0x5ca1e3b7 , 0xc6009c14 , 0xbb6687ec , 0xab731b16 , 0x07601b4b ] )
+  , Mont::unsafe_make( [ 0x21695f26 , 0xad7be27a , 0x57133ca5 , 0x5dcc50c7 , 0xfbc36c0f , 0xf3110da3 , 0xffd8f967 , 0x09b8aa01 ] )
+  , Mont::unsafe_make( [ 0x05013ed6 , 0x697c2c2f , 0x39cca0c4 , 0x786d87d1 , 0x5b54fc01 , 0x2668d22c , 0xcbf4d841 , 0x29bc2aa3 ] )
+  , Mont::unsafe_make( [ 0x82734326 , 0x92c0c881 , 0x0c5f38ac , 0x5bab6585 , 0xc928c5c3 , 0xb84c29a8 , 0x0a4d1949 , 0x179d388c ] )
+  , Mont::unsafe_make( [ 0xa9545c56 , 0x9a80f132 , 0xc3f8deef , 0xb2de5485 , 0xc68693e2 , 0x31047e35 , 0xe06f253c , 0x2a099500 ] )
+  , Mont::unsafe_make( [ 0xd86a18eb , 0xbe573d08 , 0x424695b5 , 0x71e077c0 , 0xf504ab1b , 0xa7a079b7 , 0x98fe4b33 , 0x088af521 ] )
+  , Mont::unsafe_make( [ 0x227a29d4 , 0xd63a5b84 , 0x32d7e6b8 , 0xcb9dcd9b , 0x0756b61f , 0xe4b81056 , 0x548dd266 , 0x104ae41b ] )
+  , Mont::unsafe_make( [ 0x4bdf7728 , 0x3ebf1ae0 , 0xbdb6b145 , 0x20d4bef4 , 0x9416d69a , 0xf809da0d , 0x15e61c30 , 0x09b1e943 ] )
+  , Mont::unsafe_make( [ 0x06a4baf2 , 0x6fb4d5ec , 0x31636bb6 , 0xb4128a4f , 0x100062b8 , 0x713f1481 , 0xbafb33d3 , 0x2abf4ff9 ] )
+  , Mont::unsafe_make( [ 0xf0eb1421 , 0xd0cb839c , 0xa7d1bd66 , 0x23d5d9ea , 0xc770791d , 0x0cadfbfb , 0xe35a7524 , 0x186b2d5d ] )
+  , Mont::unsafe_make( [ 0x624d1526 , 0xde365d76 , 0xd977c7e3 , 0x98acf72c , 0x9439ec6a , 0x47422989 , 0x8ed809ca , 0x06ce777d ] )
+  , Mont::unsafe_make( [ 0x764dc85d , 0x8a720b77 , 0x65906240 , 0xbfca1995 , 0xb1b8d49c , 0x90af036c , 0x4e63e3fc , 0x2930aeba ] )
+  , Mont::unsafe_make( [ 0xc6736793 , 0x7c4904e4 , 0x60573994 , 0xb740df22 , 0xe344b329 , 0x47c72939 , 0x51475257 , 0x092df16b ] )
+  , Mont::unsafe_make( [ 0x8c8de542 , 0xc25cea9d , 0x567822cf , 0x0cd71031 , 0x6776d8af , 0xee7e4194 , 0xe878f559 , 0x17939970 ] )
+  , Mont::unsafe_make( [ 0xd77ef87f , 0x56624a6c , 0xaec80907 , 0xc1bd62ad , 0x29b08dd7 , 0x6ea2a43c , 0xada2fb45 , 0x29c30977 ] )
+  , Mont::unsafe_make( [ 0x87283fd7 , 0xc7dfc848 , 0xfbe7b079 , 0xe5e636ec , 0xbcde43d2 , 0x9b7cdbac , 0x369b225c , 0x08e9be7b ] )
+  , Mont::unsafe_make( [ 0x764a06ad , 0xdb76ce48 , 0x672d8c9e , 0x19a7e534 , 0x0fd2ed05 , 0xb1b5ac8e , 0xb601353b , 0x1e399e8d ] )
+  , Mont::unsafe_make( [ 0xa513cb3e , 0xf620bf58 , 0xb2a1e57f , 0xc6478eea , 0xe48672e2 , 0xa067aca8 , 0x3cb233dd , 0x23c34c21 ] )
+  , Mont::unsafe_make( [ 0x1c486dd5 , 0x79f98957 , 0xd7013599 , 0xf5cce916 , 0x17eb278e , 0x2c05b9c1 , 0x37776bc1 , 0x1c9f41c9 ] )
+  , Mont::unsafe_make( [ 0x7f823433 , 0xfd5e72c5 , 0x0535e378 , 0x8ce8c3b7 , 0xa0c72619 , 0xb8a15459 , 0x276210a6 , 0x149bce19 ] )
+  , Mont::unsafe_make( [ 0xc0c9b8b3 , 0x06855dd6 , 0xa2c1f490 , 0x3e5e257c , 0x44cf0ddb , 0xa952965e , 0xd7aea75c , 0x2f907a44 ] )
+  , Mont::unsafe_make( [ 0xf461c464 , 0xcfd67582 , 0xece1efe5 , 0x5cc75e76 , 0x22579d2d , 0xdc678341 , 0x178a18e0 , 0x1e7a7b48 ] )
+  , Mont::unsafe_make( [ 0x0f31d77d , 0x2a09c9f3 , 0x228f4358 , 0xd3759197 , 0x2c83eb4c , 0x870a6251 , 0xdc30ec6c , 0x212c14a3 ] )
+  , Mont::unsafe_make( [ 0xca546cbd , 0xeccac0b8 , 0x9eadb6f9 , 0x855f4540 , 0x7416cec9 , 0xf3cf782e , 0x27d0ebad , 0x0e537069 ] )
+  , Mont::unsafe_make( [ 0x4512625f , 0xff25057f , 0x0a594fa0 , 0x41687e7c , 0x45e46000 , 0x1713f988 , 0xb6b1df0e , 0x29dfc48a ] )
+  , Mont::unsafe_make( [ 0x2f464f72 , 0x8ae7deb5 , 0x2faf89f6 , 0x6be4a23b , 0x04d791c2 , 0xb301dbed , 0xcecf8c8a , 0x12f6f481 ] )
+  , Mont::unsafe_make( [ 0x763757a1 , 0x2c2867f0 , 0x85928607 , 0x7f30de47 , 0x144749c8 , 0x78a6870d , 0xb65078be , 0x25a95ccb ] )
+  , Mont::unsafe_make( [ 0x9e1a1e05 , 0xed4bf26d , 0x97ea024c , 0xb9b68126 , 0x6a6c2b53 , 0x4fb8f750 , 0x268ada9b , 0x09a0a5c8 ] )
+  , Mont::unsafe_make( [ 0x84f4bd64 , 0x163b8987 , 0x7f638d4f , 0x6a594877 , 0x6c042320 , 0x80cba3e1 , 0x9395a52f , 0x1a2fbd66 ] )
+  , Mont::unsafe_make( [ 0x8e3e48e1 , 0x606506c0 , 0x460ce319 , 0x04cc0d2b , 0xd767f3af , 0x46ed82a8 , 0x620a43e1 , 0x1bd5f975 ] )
+  , Mont::unsafe_make( [ 0x57fafff8 , 0x5c222704 , 0x02c5c231 , 0x1e43a74a , 0x0f3d9b29 , 0xa3dab74c , 0xe2b40f1b , 0x0a08ac60 ] )
+  , Mont::unsafe_make( [ 0xa9907c15 , 0xdc173c60 , 0x0c15f9c1 , 0xfd8f045c , 0x3a79ad3e , 0x62247d5b , 0xbea3fa8d , 0x17690090 ] )
+  , Mont::unsafe_make( [ 0x2357ddee , 0x5d718622 , 0x86770a66 , 0x2bbd3098 , 0x7da055d6 , 0x74730452 , 0x9d80a046 , 0x04316d24 ] )
+  , Mont::unsafe_make( [ 0x102a8e64 , 0x6cf98e3a , 0x6737da0e , 0x644b84cc , 0x348289f1 , 0x2bff2fad , 0xa6fd05c8 , 0x1c6fb218 ] )
+  , Mont::unsafe_make( [ 0x4c0bb962 , 0x9a269774 , 0x90f0cf53 , 0x1e919de1 , 0x6e7a957d , 0x1273baf5 , 0x24503b35 , 0x034cb73a ] )
+  , Mont::unsafe_make( [ 0xc3d7ca07 , 0x0f23d304 , 0x3479aa43 , 0x3473b386 , 0xbec967f0 , 0x1b90326f , 0x396a6a5e , 0x1d603b20 ] )
+  , Mont::unsafe_make( [ 0xe20c877e , 0x833beb91 , 0xc043dc13 , 0x7ae0ae34 , 0xd2f8c0fc , 0x3893af4b , 0x1d0b7590 , 0x1dd2ce75 ] )
+  , Mont::unsafe_make( [ 0xbdbbb20a , 0x5665386d , 0x2feda157 , 0x9be2ed9c , 0x8433f11d , 0x5ad30228 , 0x830252f1 , 0x18325304 ] )
+  , Mont::unsafe_make( [ 0x3aaacb59 , 0x9c5d9ecc , 0xcf170d61 , 0xf91cc18d , 0xaf04947f , 0x85857b2c , 0x7b8bd6d0 , 0x212adf1f ] )
+  , Mont::unsafe_make( [ 0xf681b9d4 , 0xd9b5b309 , 0xe2871112 , 0x3c66d1d0 , 0xf47fee30 , 0x8eff7e65 , 0x803d93c0 , 0x0c98ba6c ] )
+  , Mont::unsafe_make( [ 0x6fd7cac5 , 0x3cd78fe9 , 0xcc32720a , 0xec0fd39a , 0xeb480d18 , 0xe6fb5e51 , 0x5ca852bd , 0x0759f2e8 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// external mixing matrix (`t x t`, flattened)
+pub const CONST_M: [Mont; 49] = 
+  [ Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x444bddeb , 0xa6288e89 , 0xcf91505b , 0x05262308 , 0xac5b6cff , 0x815ea488 , 0xb0750542 , 0x12a21d19 ] )
+  , Mont::unsafe_make( [ 0x340d3543 , 0xacf060a6 , 0x64009ca3 , 0xd15a3d9f , 0x951c6839 , 0x004dda80 , 0x8397fc89 , 0x05e8e1e8 ] )
+  , Mont::unsafe_make( [ 0x23646878 , 0x7ce2b3f1 , 0x5a3e6448 , 0x6ea7b19d , 0x326f2e2b , 0x650a96ac , 0x08e47a91 , 0x2472b4dc ] )
+  , Mont::unsafe_make( [ 0x09222f64 , 0xf6da1f20 , 0xd56352b5 , 0x5b8d0fa7 , 0x8a3867d1 , 0xfb0c47ff , 0xd411b669 , 0x013aa976 ] )
+  , Mont::unsafe_make( [ 0xbd9bec31 , 0xfd1f47fc , 0xdd296b2f , 0x85c85f72 , 0xf6223673 , 0x057dcab9 , 0x2db383a3 , 0x2f057750 ] )
+  , Mont::unsafe_make( [ 0x30d995cb , 0x36ddad97 , 0x24765e8f , 0x75b5ca13 , 0x1cd94422 , 0x12ece299 , 0x16efc2bb , 0x09577579 ] )
+  , Mont::unsafe_make( [ 0x6f0d6a83 , 0xd3604c38 , 0x69790c0d , 0x2ebdcd83 , 0x9ddcf452 , 0xb395dcde , 0xee99cda2 , 0x088c4fcf ] )
+  , Mont::unsafe_make( [ 0x793bfe78 , 0xe0129bb5 , 0x0f22a44d , 0x6e6491be , 0xcd193438 , 0x521a38cb , 0xbe995f7b , 0x2facf856 ] )
+  , Mont::unsafe_make( [ 0xec6860d7 , 0x65329832 , 0x1007097a , 0xe3322b78 , 0xb5fbf09f , 0x380a12b9 , 0x5a421daf , 0x2c613494 ] )
+  , Mont::unsafe_make( [ 0x2a3d67b8 , 0xfd37e730 , 0x3a4528b8 , 0x6b185756 , 0xc3e5138d , 0x0e89a868 , 0x8bb72edd , 0x1ee7dce9 ] )
+  , Mont::unsafe_make( [ 0xd132e5db , 0x19a01977 , 0x191dac18 , 0x5ba04635 , 0x71003a1a , 0x61e5896c , 0x676a4b83 , 0x0c7d0228 ] )
+  , Mont::unsafe_make( [ 0xf1804e3d , 0xf75bb4dc , 0x5f694b0f , 0x29bd1476 , 0x91a3067c , 0x9bcfa280 , 0xcdab122d , 0x03bf8809 ] )
+  , Mont::unsafe_make( [ 0xed5875b6 , 0xee7092ea , 0x36a9828b , 0xcd7896c3 , 0x3d91eae5 , 0xee2d125b , 0x03d80d31 , 0x1e0cfc11 ] )
+  , Mont::unsafe_make( [ 0xeb7723c5 , 0xd572efd7 , 0x1e189fdd , 0xf02b75bb , 0x5167ab58 , 0xfec9b9eb , 0xdc20a9b8 , 0x25a4b7ed ] )
+  , Mont::unsafe_make( [ 0xcc0efd37 , 0x681af83a , 0xc937ef57 , 0x53fb77cb , 0x4b73d9ff , 0x56dc8aee , 0x8e8b5006 , 0x045fd564 ] )
+  , Mont::unsafe_make( [ 0x463f86fb , 0x2e42e36e , 0x87b96707 , 0xc4890e6e , 0x1c090d3d , 0x970821f9 , 0x155cdaa9 , 0x0f24e914 ] )
+  , Mont::unsafe_make( [ 0xef1cedbc , 0x06b7d5fa , 0x8f432b6f , 0x16a64245 , 0x83343bf9 , 0x81e4856b , 0xbe70ddaf , 0x0bd95bdb ] )
+  , Mont::unsafe_make( [ 0x48dff194 , 0x95bc78fc , 0x379eea9d , 0xc4fb06ad , 0x3f52711b , 0x2861a397 , 0xcf30787a , 0x12b62627 ] )
+  , Mont::unsafe_make( [ 0x4603e105 , 0xee23c973 , 0xed9db9d3 , 0xccae13f8 , 0x28dbdcd2 , 0xb351d2d5 , 0x22110748 , 0x2c7056c8 ] )
+  , Mont::unsafe_make( [ 0x03581fc4 , 0x5acd8a9b , 0xa05bb124 , 0xede777bf , 0x880dc821 , 0xea38bb49 , 0x3e09b31d , 0x0afa842c ] )
+  , Mont::unsafe_make( [ 0x993f47d2 , 0xe2b13562 , 0xbb43def6 , 0x001b9962 , 0xd4e782d9 , 0x27a024c4 , 0xc1d0fa22 , 0x0b27576c ] )
+  , Mont::unsafe_make( [ 0xa0d86058 , 0xc1f53732 , 0xddef2289 , 0x5d54cba1 , 0x47afe0a6 , 0x42649236 , 0x49a53ce5 , 0x21064467 ] )
+  , Mont::unsafe_make( [ 0xfd825bc2 , 0x99e55903 , 0x59c449cf , 0xfae8d629 , 0x2aebe3f7 , 0xce91904f , 0xc1b6b0ce , 0x1696350c ] )
+  , Mont::unsafe_make( [ 0x30c2f2b0 , 0xcfb52234 , 0xb1739037 , 0xbe13b789 , 0x39854c38 , 0x6a5dc7f4 , 0x969cdfd9 , 0x10da2dcb ] )
+  , Mont::unsafe_make( [ 0xfb7ebca9 , 0xf838c178 , 0x0258f056 , 0x3ea7a781 , 0x9fbe1f3a , 0x777ca974 , 0xf2a9c482 , 0x12556ee2 ] )
+  , Mont::unsafe_make( [ 0x6fffca88 , 0x69c9f149 , 0x81bac453 , 0x42ec99ae , 0x5c449989 , 0xfffc382a , 0x10812d9e , 0x25ce4513 ] )
+  , Mont::unsafe_make( [ 0x8b193ca8 , 0x09b97997 , 0xf2f2bc34 , 0xdd175096 , 0x920b3e37 , 0x0ed3125f , 0x9069c72e , 0x2145f0ea ] )
+  , Mont::unsafe_make( [ 0x92f4f8ca , 0xb5877103 , 0x3c28b0ff , 0x7a6620eb , 0x64b9c657 , 0x1bf9bb14 , 0xf4f5d58d , 0x1d075685 ] )
+  , Mont::unsafe_make( [ 0xb01d0ae6 , 0x46b95c4d , 0x2b55134c , 0x43db7817 , 0xf480f9c1 , 0x4c1a7136 , 0x99a91701 , 0x069b9d95 ] )
+  , Mont::unsafe_make( [ 0x9f7cd095 , 0x0d1e19f0 , 0xc2b700be , 0x1fd3db7f , 0x003e30ca , 0x7a4a42f0 , 0x5117169d , 0x162cb8a3 ] )
+  , Mont::unsafe_make( [ 0x9bede31d , 0xe21353da , 0x9cb09388 , 0x2d14f51f , 0x4773a18a , 0x75d08d2a , 0xe54c5115 , 0x1ea53526 ] )
+  , Mont::unsafe_make( [ 0x20c2db55 , 0x33d52f16 , 0x56487bc7 , 0x46f7d1e3 , 0x550aa33a , 0xfaf65580 , 0x3032515f , 0x01988500 ] )
+  , Mont::unsafe_make( [ 0x328bee81 , 0x83a11bb2 , 0x02defe30 , 0xd08bf1a4 , 0xfbfa6a28 , 0x0e26ee05 , 0xe181343e , 0x0ccf6942 ] )
+  , Mont::unsafe_make( [ 0x05c1154d , 0xa7cd35a2 , 0x62c0b2ea , 0x4698a110 , 0xcb69fdea , 0x81d91215 , 0x146b51b1 , 0x0d6652b7 ] )
+  , Mont::unsafe_make( [ 0x86eaf1f3 , 0x0f4ec707 , 0xabd9f903 , 0x90c7788e , 0x6fdccee4 , 0x05abe8fd , 0x42af3c43 , 0x099592e6 ] )
+  , Mont::unsafe_make( [ 0x7dcb301b , 0x04b3a556 , 0x44535f78 , 0x3fb4749a , 0x19fa9ecc , 0xb813a9e9 , 0xc96cda59 , 0x1797bc29 ] )
+  , Mont::unsafe_make( [ 0xa4c7a784 , 0x08ce831c , 0xefd3eeec , 0xcaca0fc0 , 0xc959161f , 0xf3fecbd6 , 0x3d8b8857 , 0x08aeddac ] )
+  , Mont::unsafe_make( [ 0x7176ced8 , 0xb19c2178 , 0xa2ad0949 , 0x69f4f538 , 0x17ff8ceb , 0xeb7b8ad4 , 0x899a77bd , 0x191a4fd4 ] )
+  , Mont::unsafe_make( [ 0x1eb48dda , 0x5ece5d3c , 0xade54f81 , 0xde65f387 , 0x2a7c767f , 0x1e4e5526 , 0x3e457687 , 0x1a5da6e5 ] )
+  , Mont::unsafe_make( [ 0xb9ea43d0 , 0x7f2617dc , 0xf6652147 , 0x45901366 , 0xc40bcac1 , 0x755687c3 , 0x04513a2c , 0x2944c7c4 ] )
+  , Mont::unsafe_make( [ 0xc6f516a3 , 0x512344e9 , 0x5f20b07c , 0xc559c8d0 , 0x7f2391aa , 0x1a8a8d84 , 0xbb921248 , 0x21fd58c3 ] )
+  , Mont::unsafe_make( [ 0xe186aff6 , 0xdca5c3fd , 0xce121671 , 0xb2bf6e32 , 0x67ac8216 , 0xd8d345b4 , 0x586c8afb , 0x2e599650 ] )
+  , Mont::unsafe_make( [ 0x0e627ec7 , 0x9659c8b1 , 0xd7b09b38 , 0xe2ff5c1b , 0xca221ad3 , 0xcd2adbfa , 0x6d8a8269 , 0x0457228e ] )
+  , Mont::unsafe_make( [ 0xda0d17ce , 0x67c94dd5 , 0x37680599 , 0x32c7d965 , 0x9ad76907 , 0x90f45155 , 0x72544719 , 0x228c91d7 ] )
+  , Mont::unsafe_make( [ 0x673b6172 , 0x78bce69a , 0xee6762e5 , 0x066bebc3 , 0x5168043f , 0xf010cfed , 0x8db5005b , 0x1aaa0c3b ] )
+  , Mont::unsafe_make( [ 0xf97d78e5 , 0x9514ae38 , 0x8f2e8273 , 0x12374988 , 0x321b370e , 0x468f7701 , 0xd8f9ba6a , 0x2790a61a ] )
+  , Mont::unsafe_make( [ 0x1824623c , 0x45db03d8 , 0xb3c06cd0 , 0xfae9a4f4 , 0x67312d9f , 0xed0ddceb , 0x5c43a8bc , 0x13330f83 ] )
+  , Mont::unsafe_make( [ 0x58fd318c , 0x5e8e3b22 , 0xeeae689c , 0x3a3c3d21 , 0x8e5751dc , 0x899282f4 , 0x17696de2 , 0x260360a9 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// internal mixing matrix (`t x t`s, flattened)
+pub const CONST_P: [Mont; 49] = 
+  [ Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xb6f2a523 , 0x3cdd0ec8 , 0x7ced4269 , 0xab45b6fd , 0x4f53dd80 , 0xc7b8a2e4 , 0x24fd3f08 , 0x2e31e8af ] )
+  , Mont::unsafe_make( [ 0x8858b6e4 , 0x1dfd7bd7 , 0x7db14226 , 0x9b873e09 , 0x6923395c , 0xa3455456 , 0x7cbd1ade , 0x0490ae4e ] )
+  , Mont::unsafe_make( [ 0xd85f3daf , 0x095b3b74 , 0x5c56423c , 0xc3a4b0be , 0x1aca7b73 , 0x3bed300c , 0x4d3fb9f6 , 0x173d42f9 ] )
+  , Mont::unsafe_make( [ 0x40288654 , 0x3b6e5339 , 0xd514f120 , 0x51650a9f , 0x1f9f299c , 0x18a38505 , 0x5ec1d03f , 0x0775798c ] )
+  , Mont::unsafe_make( [ 0x650036fb , 0x1bd1bc66 , 0x6f60888f , 0x6c69c088 , 0xa51e41c4 , 0xe2199463 , 0xffa34e1b , 0x205b4858 ] )
+  , Mont::unsafe_make( [ 0xb0f9f5a9 , 0x299c9e1a , 0x165327ef , 0xf5ce38ea , 0xdcb5f1d6 , 0x8bf60567 , 0x3087aa1b , 0x18b0aaed ] )
+  , Mont::unsafe_make( [ 0x6f0d6a83 , 0xd3604c38 , 0x69790c0d , 0x2ebdcd83 , 0x9ddcf452 , 0xb395dcde , 0xee99cda2 , 0x088c4fcf ] )
+  , Mont::unsafe_make( [ 0x29ac426b , 0x00ab0eed , 0xf39aa164 , 0xffc9b731 , 0x9b13b0ef , 0x161acd3d , 0x1fd7a4cf , 0x0855526f ] )
+  , Mont::unsafe_make( [ 0xea93e0e8 , 0x8c70722d , 0x70155f04 , 0xdfd52d4e , 0xf39e2f97 , 0x13a16924 , 0xad080aac , 0x15ea2a66 ] )
+  , Mont::unsafe_make( [ 0x20b83954 , 0xc013c5b9 , 0x08bff681 , 0x3f88dc89 , 0xf03068aa , 0x11b8e031 , 0xf16ef0a5 , 0x2adcf968 ] )
+  , Mont::unsafe_make( [ 0xc715a64c , 0xf8a37ba5 , 0xf6bcb758 , 0x5188e438 , 0x8e896eb4 , 0x69785349 , 0x319ac234 , 0x11f76417 ] )
+  , Mont::unsafe_make( [ 0x98d3c082 , 0x9b24f2e6 , 0xb6fca9d6 , 0x1561881e , 0xaef4b710 , 0xf82c7320 , 0x1300754a , 0x2bf2498b ] )
+  , Mont::unsafe_make( [ 0x021ca5fb , 0x9070faa6 , 0x90d639b4 , 0x713da292 , 0x55f30554 , 0xc35beef3 , 0xcad85f33 , 0x16e671bc ] )
+  , Mont::unsafe_make( [ 0xeb7723c5 , 0xd572efd7 , 0x1e189fdd , 0xf02b75bb , 0x5167ab58 , 0xfec9b9eb , 0xdc20a9b8 , 0x25a4b7ed ] )
+  , Mont::unsafe_make( [ 0x80af6c72 , 0x363abaa9 , 0x5f54dd8d , 0x93dc07eb , 0xd211ceaa , 0xc2b96032 , 0xeb92ec8b , 0x29c17059 ] )
+  , Mont::unsafe_make( [ 0x355a4125 , 0xd9d9ef11 , 0xf8f6a6ac , 0x7200917a , 0x93d376c2 , 0x9fb2e143 , 0x05218dbe , 0x17a9e337 ] )
+  , Mont::unsafe_make( [ 0xc8bc66c2 , 0xcba6e44b , 0x64c6f9dd , 0x1d81fd65 , 0x6e558552 , 0x5e20d77f , 0x51ecc9a8 , 0x2e47eb1a ] )
+  , Mont::unsafe_make( [ 0x54808f33 , 0x25ca18f5 , 0x1db17f85 , 0x555295f8 , 0xb9a957e7 , 0x70e429bb , 0xfebbb7b7 , 0x284c9082 ] )
+  , Mont::unsafe_make( [ 0x819ad23f , 0x7757b153 , 0x45569843 , 0xc0de2306 , 0xec4eaced , 0x0370b910 , 0xa700a30a , 0x02beb191 ] )
+  , Mont::unsafe_make( [ 0xda7f41ec , 0x2b56c1f7 , 0x0fe83588 , 0xeb4da5a4 , 0x29680445 , 0xbc23861e , 0xefc7fe36 , 0x1792be40 ] )
+  , Mont::unsafe_make( [ 0x993f47d2 , 0xe2b13562 , 0xbb43def6 , 0x001b9962 , 0xd4e782d9 , 0x27a024c4 , 0xc1d0fa22 , 0x0b27576c ] )
+  , Mont::unsafe_make( [ 0x66107de7 , 0x020c3edb , 0x3b81430b , 0x01e690f4 , 0x67afb1eb , 0x6c62dffd , 0x385f0cdc , 0x0b2a67af ] )
+  , Mont::unsafe_make( [ 0xb5857d61 , 0x21db1ad2 , 0xb8b62147 , 0xef285828 , 0x17c656ff , 0x880c29cb , 0x805e5d6f , 0x2d8474fc ] )
+  , Mont::unsafe_make( [ 0xf01543e8 , 0xccd660be , 0xef7e0992 , 0x49262ad6 , 0xa74b68b9 , 0x5db80944 , 0x6d48158e , 0x1b382805 ] )
+  , Mont::unsafe_make( [ 0x7a0eeecd , 0x7b644158 , 0xbede1030 , 0x5512e922 , 0xb5f1215e , 0x8451c7c8 , 0xd25ee4e3 , 0x1e0976e3 ] )
+  , Mont::unsafe_make( [ 0xc44032cc , 0xa608089e , 0xc8fb730b , 0x52d74ab4 , 0x96ca151e , 0x837e407f , 0xe6fbd603 , 0x10c9af2e ] )
+  , Mont::unsafe_make( [ 0x8230b784 , 0xf29ee8de , 0x476a042e , 0xf9a81824 , 0x1a9a42c2 , 0x562cf669 , 0xe0f79c84 , 0x121dde01 ] )
+  , Mont::unsafe_make( [ 0x92f4f8ca , 0xb5877103 , 0x3c28b0ff , 0x7a6620eb , 0x64b9c657 , 0x1bf9bb14 , 0xf4f5d58d , 0x1d075685 ] )
+  , Mont::unsafe_make( [ 0x8d98a0ca , 0x79a02908 , 0xab499e38 , 0xd70a71d6 , 0x87cebe9b , 0x6acae2f5 , 0x8c31472e , 0x24201a85 ] )
+  , Mont::unsafe_make( [ 0x5abae42f , 0xf3731e10 , 0xb6433e55 , 0x31bccfe5 , 0x6efcc2b4 , 0xec303f33 , 0x9d3904fe , 0x0d00c640 ] )
+  , Mont::unsafe_make( [ 0x9425dba3 , 0x4b76d77f , 0x4e2fffc5 , 0xa9b88006 , 0x1e12e8e0 , 0x03cf00f2 , 0x2bc73c03 , 0x0b22e0be ] )
+  , Mont::unsafe_make( [ 0xc95321ce , 0x6380f378 , 0xd316b777 , 0x5c7c373a , 0x3440973d , 0x305e9f76 , 0xb069a0c0 , 0x0b09f526 ] )
+  , Mont::unsafe_make( [ 0x39adba89 , 0xcdc19524 , 0x3bdb800f , 0x6869eee8 , 0x0a1bb3ef , 0xcbfad8a0 , 0xfbce240d , 0x10f51bb5 ] )
+  , Mont::unsafe_make( [ 0x9009003e , 0xfce1500a , 0x632b7d49 , 0x4f54bfb9 , 0x7e12292c , 0xbcf9f6bb , 0xb8a14f0a , 0x0adda77a ] )
+  , Mont::unsafe_make( [ 0x86eaf1f3 , 0x0f4ec707 , 0xabd9f903 , 0x90c7788e , 0x6fdccee4 , 0x05abe8fd , 0x42af3c43 , 0x099592e6 ] )
+  , Mont::unsafe_make( [ 0xf977685f , 0x8aca7e11 , 0xc30b3399 , 0xfba949e9 , 0x04a00e7d , 0x56a0939c , 0x85df6cba , 0x1c4e19ea ] )
+  , Mont::unsafe_make( [ 0x2d85be8d , 0x4d164fba , 0x587bfcc3 , 0x4c736f6c , 0x02ac7d38 , 0x7c7403ee , 0x4572eff0 , 0x2779d287 ] )
+  , Mont::unsafe_make( [ 0xeea454db , 0x62957719 , 0x36f391ac , 0xea088175 , 0x4cc1a103 , 0xdd702333 , 0xd57b3c41 , 0x27daed4a ] )
+  , Mont::unsafe_make( [ 0x6aea1c58 , 0x528c145c , 0xfa40d284 , 0x1b4fec2c , 0x86f7bb62 , 0x3137ad5e , 0x5cc77264 , 0x0db09678 ] )
+  , Mont::unsafe_make( [ 0x3e16ab76 , 0xad3892b6 , 0xb6671619 , 0x59c97e35 , 0x9e3d5789 , 0x13e9a069 , 0x135248d5 , 0x10663059 ] )
+  , Mont::unsafe_make( [ 0x773de522 , 0x4d4194d9 , 0x2fc1e674 , 0xe7eb1432 , 0x0462794e , 0xcf7d6746 , 0x4ad35389 , 0x2d9a2ec8 ] )
+  , Mont::unsafe_make( [ 0xe186aff6 , 0xdca5c3fd , 0xce121671 , 0xb2bf6e32 , 0x67ac8216 , 0xd8d345b4 , 0x586c8afb , 0x2e599650 ] )
+  , Mont::unsafe_make( [ 0x6567f86e , 0xc39aa409 , 0x3737df54 , 0x09cc0cfc , 0xded9779e , 0xc1501e86 , 0xfd6767cc , 0x0e426c67 ] )
+  , Mont::unsafe_make( [ 0xdd4e7209 , 0xbcbb3c85 , 0xef6c73be , 0xb67d3d6c , 0xf03f4bab , 0x22ddf29c , 0xfd22c0d0 , 0x2373d9d2 ] )
+  , Mont::unsafe_make( [ 0xae7dfa24 , 0xb6b01165 , 0x07d03895 , 0x33138471 , 0x4fb22ffe , 0xc3e6085d , 0x41fa6572 , 0x2d835d46 ] )
+  , Mont::unsafe_make( [ 0xae62458e , 0x40bbec5f , 0xaa326c94 , 0x422c88f7 , 0x31829cb0 , 0x4dbbb98c , 0xe335702a , 0x1601f374 ] )
+  , Mont::unsafe_make( [ 0x34ecb3a5 , 0xe219818e , 0xff3c5ab4 , 0x77436305 , 0x9774ef96 , 0xe7dc1ad7 , 0xfa772624 , 0x1c171fb4 ] )
+  , Mont::unsafe_make( [ 0x2dc2e3f8 , 0x3b120171 , 0x502f8775 , 0x3d7db2cd , 0x897aaa84 , 0x319a897f , 0x67a407b3 , 0x24077446 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// circomlib's optimization whatever
+pub const CONST_S: [Mont; 819] = 
+  [ Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x302356e4 , 0xaf24f234 , 0xbfd9b66c , 0xa9b285b9 , 0xa091fa3b , 0x9e765850 , 0xb87638e5 , 0x1c319bcf ] )
+  , Mont::unsafe_make( [ 0xb3017e21 , 0xed24673e , 0x5e434c50 , 0x23a7a40a , 0x384e441a , 0x374712c3 , 0x9baa2348 , 0x0e218796 ] )
+  , Mont::unsafe_make( [ 0x0a08d099 , 0x7c5cd45b , 0x4f947eae , 0x4beac957 , 0xe3689197 , 0xca5fd3a2 , 0xc70a580c , 0x283228b2 ] )
+  , Mont::unsafe_make( [ 0x612eac6b , 0x672f7f9a , 0xcb9451f5 , 0x0ec9c90e , 0x1b489a44 , 0xe05a077e , 0xae4b7605 , 0x1a9f805f ] )
+  , Mont::unsafe_make( [ 0x35ea6207 , 0x6901e06d , 0x15fa2d53 , 0x5116c97b , 0xbcd4955b , 0xaa235259 , 0xa5ff229f , 0x090219a5 ] )
+  , Mont::unsafe_make( [ 0xeedf9afb , 0x0f94d509 , 0x6427603b , 0x4172a06f , 0xc169dd69 , 0x9c46d1e3 , 0x1115e219 , 0x1a68d505 ] )
+  , Mont::unsafe_make( [ 0x35d3b5ed , 0x1b201ae6 , 0xae15763b , 0x822f440d , 0x0ace7b9e , 0xdc4fa72e , 0x4844b40e , 0x1380a94e ] )
+  , Mont::unsafe_make( [ 0x7fc2d29e , 0xdd0dab53 , 0x287a1408 , 0xc6a59f5f , 0xe01d34d9 , 0x0ac9dc8c , 0xce165134 , 0x254f821e ] )
+  , Mont::unsafe_make( [ 0x7b970760 , 0x1ce2e305 , 0x160a898f , 0xfe3a3f10 , 0x90823461 , 0xe4398f90 , 0xba214354 , 0x18bb3c1b ] )
+  , Mont::unsafe_make( [ 0x8fc4585f , 0x953772a5 , 0xdc1d4e07 , 0x99d43bb2 , 0x77ff1ceb , 0x33993a26 , 0x9523dcef , 0x1cde159f ] )
+  , Mont::unsafe_make( [ 0x05badd14 , 0x4a36230c , 0xf7d935e3 , 0xaa7c6422 , 0x586a4e18 , 0xa98cfb8d , 0x8d4ece80 , 0x07ad8350 ] )
+  , Mont::unsafe_make( [ 0xb8a96ad6 , 0xec2ffed6 , 0x150d41aa , 0xf99bfe00 , 0x49a52b30 , 0x1a3e0986 , 0xbd1f5e45 , 0x10c5dad9 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x0582570e , 0x986f3e7c , 0xa7c48aeb , 0x32aa5a5d , 0x97461516 , 0x0ad6ce1f , 0x955e88be , 0x2c8bc575 ] )
+  , Mont::unsafe_make( [ 0xf29c414b , 0x382a8bff , 0x981ab182 , 0x3e5c6023 , 0x156121b9 , 0x3555f8e0 , 0x1e3cb84c , 0x1cf59cf7 ] )
+  , Mont::unsafe_make( [ 0x1df89bc6 , 0x38e9d6d3 , 0x041c3824 , 0xfc84c2bb , 0xde55cac6 , 0x7e633423 , 0x53702eff , 0x07b36330 ] )
+  , Mont::unsafe_make( [ 0x3a40cd0a , 0xd8d73b67 , 0xef7e0885 , 0x0f18d383 , 0xed54a9cd , 0xd43b36ec , 0x3fc9ec71 , 0x14be906e ] )
+  , Mont::unsafe_make( [ 0x8c2d2186 , 0x3530f517 , 0x39b99fcf , 0xac35b480 , 0x9c015771 , 0xbae8f060 , 0xf370207d , 0x2fc134b6 ] )
+  , Mont::unsafe_make( [ 0x5035906e , 0x41f1a473 , 0x46a71e85 , 0x1bd12e93 , 0x6fac8bfe , 0xef3c505d , 0x6dc4ac8b , 0x130fc4dd ] )
+  , Mont::unsafe_make( [ 0x65e96aa5 , 0x99397be8 , 0xaae35833 , 0xceb7d655 , 0x291db117 , 0x4977275b , 0x0271abe4 , 0x0ada3c0e ] )
+  , Mont::unsafe_make( [ 0x73ce309a , 0x43af2b6f , 0x5e501a39 , 0x04131c4e , 0x41f0d1dc , 0x5f90aa7d , 0x083fdfa4 , 0x2fe8524a ] )
+  , Mont::unsafe_make( [ 0xbf49d040 , 0xb5d5a9b7 , 0x1ec0518e , 0x5d7a714e , 0xf74aecf5 , 0x6a728491 , 0x3046c1d3 , 0x153a4c11 ] )
+  , Mont::unsafe_make( [ 0x2ba5724f , 0xcbe5d32b , 0x0bce9de2 , 0x9fa49146 , 0x6a39432d , 0xbe671e1f , 0x389f4062 , 0x01a61963 ] )
+  , Mont::unsafe_make( [ 0xe2c5ef53 , 0x47452aa5 , 0x6f299952 , 0x66536a98 , 0xeb7bb626 , 0xfbc0702e , 0x8aa2f557 , 0x23e6f626 ] )
+  , Mont::unsafe_make( [ 0x732affce , 0x1ca5d0ad , 0x6e2d1056 , 0x4a663993 , 0x817e6b40 , 0x7f829c2a , 0x29aa24cc , 0x24882235 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xb47b394f , 0x8bd0d9bd , 0x081317d8 , 0x3de39eae , 0x13b9f603 , 0x3d31c0f3 , 0x09bc7e7a , 0x27bf8a4e ] )
+  , Mont::unsafe_make( [ 0x06386956 , 0x049c7210 , 0x617bb8bb , 0xe9343def , 0x41c59094 , 0x20718616 , 0x8de4385c , 0x256f91b3 ] )
+  , Mont::unsafe_make( [ 0xdc7f5ed9 , 0x58e0970f , 0x7bff7385 , 0x5260a84c , 0xe98115d0 , 0xa26b4bd7 , 0x5faed2c3 , 0x0b49a72b ] )
+  , Mont::unsafe_make( [ 0xa9b2e47c , 0xdf7525d2 , 0x91e8525e , 0xa569dd5b , 0x16aa0909 , 0x927d816b , 0xb9c4be1a , 0x0e414b59 ] )
+  , Mont::unsafe_make( [ 0x8ef1d057 , 0x5a414d3b , 0x6905aad0 , 0xaa224833 , 0xd3391eb4 , 0xf93a6a15 , 0x41b45d6d , 0x2a147329 ] )
+  , Mont::unsafe_make( [ 0x1c301dd2 , 0x4ebae0ff , 0xaaf29cc1 , 0xf1151bf1 , 0x1af7c81f , 0x09f7948d , 0x0ef6a535 , 0x2a0ff031 ] )
+  , Mont::unsafe_make( [ 0xee0e11dd , 0x212b07e9 , 0x69b23f6c , 0x789e29c5 , 0x47e60d32 , 0xbdbbb490 , 0x1ad2a5ec , 0x0c4af807 ] )
+  , Mont::unsafe_make( [ 0xfd165be1 , 0xb0af3cae , 0x12863195 , 0xe5a1d012 , 0xec367b2b , 0x3a1e8bb7 , 0xc52b8336 , 0x2f39ce32 ] )
+  , Mont::unsafe_make( [ 0xd380cfcf , 0xa9595b05 , 0x5a3250eb , 0x8aa04f23 , 0xd7544a4a , 0x727d25f5 , 0xf67c3590 , 0x1a18d8e4 ] )
+  , Mont::unsafe_make( [ 0x4f2ed612 , 0x91aaa832 , 0xfaffa8d0 , 0x41fc08fe , 0x9134a718 , 0x59b15b70 , 0xb7f53591 , 0x24c3fabc ] )
+  , Mont::unsafe_make( [ 0x46df4b10 , 0xacbb660c , 0xca718cfd , 0x798c7f4b , 0x68dc56a9 , 0x1564fd86 , 0x17363284 , 0x1ce265a1 ] )
+  , Mont::unsafe_make( [ 0xecd82890 , 0x8df97aeb , 0x0ef6f413 , 0xb027d50f , 0x7d32b9d0 , 0x97a37fe8 , 0x06ee3416 , 0x196d9809 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x2e2484ec , 0x321bf738 , 0x90b12683 , 0xe10211a5 , 0x086cf235 , 0x9a5f914c , 0x6be4918e , 0x00e3937d ] )
+  , Mont::unsafe_make( [ 0xbd736fa1 , 0x0a345b0d , 0x109df720 , 0xfd50d453 , 0xf71a9683 , 0xadd37a7d , 0x85cdde45 , 0x1b7f4037 ] )
+  , Mont::unsafe_make( [ 0x3a63810e , 0x72d92f03 , 0x7fa1cf39 , 0x61ee104e , 0xaa54507a , 0x25e7855e , 0xdcbb09c3 , 0x0740c877 ] )
+  , Mont::unsafe_make( [ 0x2abbc004 , 0xaafc9de1 , 0x21570524 , 0x95f571c7 , 0xfb428a92 , 0xdbb090d1 , 0x8406034e , 0x0eae42af ] )
+  , Mont::unsafe_make( [ 0x0a46f0e6 , 0xc837a790 , 0x3227dba7 , 0x88224a24 , 0x6656afbc , 0x3cf53485 , 0x8b4c840f , 0x29f165d2 ] )
+  , Mont::unsafe_make( [ 0xe5b784b8 , 0x0b0e4b86 , 0x51fdd2bb , 0xe7e0cb25 , 0x1221025f , 0x48a6840a , 0x1e7cfba9 , 0x2064e20c ] )
+  , Mont::unsafe_make( [ 0x50f9ff3a , 0x669d55fe , 0xbbff55dd , 0x882a0169 , 0x963dcea5 , 0x68371d3f , 0x90ad578f , 0x12fd719b ] )
+  , Mont::unsafe_make( [ 0x0843d9de , 0xd9aae910 , 0x083912ed , 0x98aa651f , 0x6f429027 , 0xeaafc381 , 0x5402cd49 , 0x1ed89432 ] )
+  , Mont::unsafe_make( [ 0x9650db27 , 0xfa35fc10 , 0x0927a1d1 , 0x4437fbf6 , 0x3788d62c , 0x1a82aa1a , 0x7c624dd9 , 0x109b0468 ] )
+  , Mont::unsafe_make( [ 0xac3e58d1 , 0x472be784 , 0xd5662c4d , 0xb1172de7 , 0xcc77b32e , 0xa4244844 , 0x6ecaed12 , 0x2186f593 ] )
+  , Mont::unsafe_make( [ 0xe80ba5c8 , 0x5b632489 , 0x5251b469 , 0x192fe9cb , 0x2588add7 , 0xe1dc0e1b , 0xf6f7f76d , 0x05634dc4 ] )
+  , Mont::unsafe_make( [ 0x10367f8d , 0x52bffd0a , 0xc50e874d , 0xf7ab2ce3 , 0xf38b7a72 , 0xfc221d47 , 0x5ee36aa0 , 0x2c987968 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x2322fcc0 , 0x240fa195 , 0x2a930489 , 0xcb0725ad , 0xe263350a , 0xb1eb8424 , 0x61b13c73 , 0x002dc29d ] )
+  , Mont::unsafe_make( [ 0x61f5ed0d , 0x8886a42d , 0x39321bb6 , 0x02cda70b , 0xf307b601 , 0xb5829595 , 0x7e4d59b5 , 0x27868935 ] )
+  , Mont::unsafe_make( [ 0x53a2a718 , 0x71078a24 , 0x7b7d5aae , 0x96fad3f6 , 0x416ae548 , 0x4c6dd0bb , 0xd1fc8d09 , 0x0f5de394 ] )
+  , Mont::unsafe_make( [ 0x6ee71382 , 0xe054e0af , 0x54fe87b8 , 0x72e78d45 , 0xf7fc21df , 0xc60261f8 , 0x23a236ab , 0x15e87d2b ] )
+  , Mont::unsafe_make( [ 0x98c80827 , 0x7ab7c0c9 , 0xbc03c671 , 0x3d412acb , 0x342f3c6b , 0x61fb2848 , 0x47534f9f , 0x06568ad3 ] )
+  , Mont::unsafe_make( [ 0xce8f4d95 , 0x9e2f18d7 , 0xd590d44e , 0x549d3b85 , 0x35be4b89 , 0x3e50754a , 0xcc2f8309 , 0x11906f16 ] )
+  , Mont::unsafe_make( [ 0x77650d48 , 0x2d79dc29 , 0xd78d13ff , 0x1c313f6a , 0xa6d60251 , 0xe00a4d92 , 0xeb330468 , 0x1306dca3 ] )
+  , Mont::unsafe_make( [ 0x1916e448 , 0xdd4eab71 , 0x58d07954 , 0x9d306ff9 , 0x6d70a0dd , 0xcac1e072 , 0x876b76ab , 0x13cb078b ] )
+  , Mont::unsafe_make( [ 0x0b44086f , 0x53277866 , 0x5350f817 , 0x5ea825d7 , 0xa734757d , 0xd8d8e7af , 0xc387d01a , 0x093e755e ] )
+  , Mont::unsafe_make( [ 0x583a4978 , 0x4f73b831 , 0xbaeb5445 , 0xd8096840 , 0x32a4d000 , 0x8b3221a1 , 0x4f9f1852 , 0x230555cb ] )
+  , Mont::unsafe_make( [ 0x5daf70fc , 0x4fbf14ec , 0x5f4b59db , 0xd1cdaa7f , 0xe9c6e43e , 0x62767ab6 , 0x550e5bea , 0x0a4f4bcb ] )
+  , Mont::unsafe_make( [ 0x28b950c6 , 0xe3de63cf , 0x7ab524a8 , 0x3cd75afe , 0xa717986f , 0x4ca52a2f , 0xf37c754e , 0x176a43c3 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x56f98d3a , 0x16cda024 , 0xd32230c0 , 0x0838c389 , 0x5968c202 , 0x7e31da82 , 0xa3640a0b , 0x1d9f42c1 ] )
+  , Mont::unsafe_make( [ 0x7cd7a7f9 , 0x533a5933 , 0x760b88c3 , 0xfb803fd7 , 0x853d6d92 , 0x5e1bf1d8 , 0x527f7906 , 0x059b17fe ] )
+  , Mont::unsafe_make( [ 0xfd3cb06d , 0x18f34c5f , 0x84f5cdd7 , 0x8e2c7851 , 0xfbbc4e25 , 0x7bff6964 , 0x6de52617 , 0x108c83a4 ] )
+  , Mont::unsafe_make( [ 0x0754f1a9 , 0x27da63af , 0x12e23ee3 , 0x2f3bb2e2 , 0x91632a44 , 0x4abb6335 , 0x4f08f5d8 , 0x0ed657ac ] )
+  , Mont::unsafe_make( [ 0xb1675d5b , 0x31436484 , 0x17d23781 , 0xcc182460 , 0x02615851 , 0xe7620ece , 0xe32836e6 , 0x208e2740 ] )
+  , Mont::unsafe_make( [ 0xbe1f9193 , 0xe5493547 , 0xb07dea0d , 0x77437e69 , 0xfbebe60c , 0x172685f8 , 0xce09fa8b , 0x0fe2da43 ] )
+  , Mont::unsafe_make( [ 0xea6ec2b4 , 0xb9014000 , 0x3a256ce7 , 0x252f524f , 0xd859d252 , 0x293da9bc , 0x4137e8d0 , 0x11036a3d ] )
+  , Mont::unsafe_make( [ 0xbd95976a , 0xc2e1c0e5 , 0xa8dc99ca , 0x306a1503 , 0x8fbf4569 , 0x9d9701a9 , 0x468e68d0 , 0x2293ac54 ] )
+  , Mont::unsafe_make( [ 0xdf603bc3 , 0x92dee532 , 0x17af657e , 0x85ba21a4 , 0xa5e698b4 , 0x20620e0c , 0xabb53d77 , 0x1ff24a83 ] )
+  , Mont::unsafe_make( [ 0xdc40e147 , 0x2475fdf3 , 0xf05b78af , 0x9628c9d6 , 0xb81b019f , 0xaf872b97 , 0x1f32bd17 , 0x128d9e9e ] )
+  , Mont::unsafe_make( [ 0xbf37e32b , 0x8ed4c433 , 0xd0637f81 , 0x1c5d06cc , 0x70c747b7 , 0xd8c55dbe , 0xe5b27483 , 0x1bf3405d ] )
+  , Mont::unsafe_make( [ 0x985ae473 , 0xe2e5dae6 , 0x5fd84ee8 , 0x20695809 , 0x6e3692e0 , 0x4fbc154a , 0x9af6795d , 0x1c28ff23 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xb50b309b , 0xeb1a6c62 , 0x3c953538 , 0x69aa369b , 0x27f2353c , 0x29ec880e , 0x44ccabce , 0x2bd1c4ea ] )
+  , Mont::unsafe_make( [ 0x6dc05640 , 0x84aa161f , 0x64299307 , 0x6c42613c , 0x33f09f1d , 0xe58b86b3 , 0xaa281d7e , 0x0dde2f75 ] )
+  , Mont::unsafe_make( [ 0xa9b7488e , 0xd4395e56 , 0xcc2342db , 0x9f0c7a9c , 0xd930e718 , 0xc78f28af , 0xcf907ae9 , 0x254745be ] )
+  , Mont::unsafe_make( [ 0x53e75aad , 0x4ff22f82 , 0x50c3882e , 0xdd79afc7 , 0x9bfc77bb , 0xa101ffcf , 0xa1e921c6 , 0x2d838696 ] )
+  , Mont::unsafe_make( [ 0xd7d8dd3c , 0x4cd83bbc , 0xf4fe57f5 , 0x3a29e550 , 0x55c2824f , 0xeec30980 , 0x76e50879 , 0x16d7b62e ] )
+  , Mont::unsafe_make( [ 0x78b3311e , 0x6f18aefa , 0xa827bba6 , 0x5726102b , 0x7f8fc019 , 0xf543b428 , 0x5417204d , 0x0177a117 ] )
+  , Mont::unsafe_make( [ 0x908a95dc , 0xa64c8a1a , 0xc06b0c3b , 0x72c4e347 , 0x00c7dcee , 0x826d81ef , 0x1e6e9782 , 0x24b2d92f ] )
+  , Mont::unsafe_make( [ 0xe2dbb57e , 0x5e05f4ce , 0x39b6b924 , 0x1a44e53a , 0x2275e893 , 0xb596787c , 0xb272bc33 , 0x221dad9c ] )
+  , Mont::unsafe_make( [ 0x2f2bfff1 , 0xbe883b3a , 0x48279795 , 0x8c142201 , 0xdc04513e , 0x7eb54f58 , 0x3a5f4de8 , 0x11c3874d ] )
+  , Mont::unsafe_make( [ 0xd3668676 , 0x4f6f0e7c , 0xef436aec , 0x81bd4bfe , 0x233f74c9 , 0x3feef09d , 0x50b93321 , 0x2dda00ca ] )
+  , Mont::unsafe_make( [ 0xbd3daae1 , 0x18d1a6ec , 0xdad97675 , 0x583531ca , 0x4379bb96 , 0xed710d0c , 0x6ad280ed , 0x299e7145 ] )
+  , Mont::unsafe_make( [ 0x166bd6fb , 0xb3e8b9bd , 0x743bac75 , 0xafa11fae , 0x7b4c4f24 , 0xa7575836 , 0xfbb9a188 , 0x2eaaa9e8 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xeda1afe8 , 0xc089da24 , 0x8a60a481 , 0xf5beb2cd , 0x9fd3a44e , 0xc3feb815 , 0xba5fdea5 , 0x01e55d70 ] )
+  , Mont::unsafe_make( [ 0x05f3f8ba , 0x360b94ae , 0xb274b0cb , 0xe47b0e09 , 0x8af1b378 , 0xb90b2268 , 0xbfe66521 , 0x215c5f7a ] )
+  , Mont::unsafe_make( [ 0x8aaf51ae , 0x084a4201 , 0x7ab5147c , 0x2ca605d2 , 0xebac7b4c , 0x93e17eb1 , 0xa6bc29f4 , 0x0ffc090a ] )
+  , Mont::unsafe_make( [ 0x986f81e5 , 0xe8a5e558 , 0x8519639f , 0xb17b9dd5 , 0xcb5b51f7 , 0x3abe2e6f , 0x7af13959 , 0x15060355 ] )
+  , Mont::unsafe_make( [ 0x2be5bb31 , 0x35da04ce , 0x3aa74183 , 0xac3c580c , 0x999450cf , 0x64bd0389 , 0x6567c01b , 0x208e260f ] )
+  , Mont::unsafe_make( [ 0x3414d33f , 0x43d9615e , 0x36a4fe01 , 0x7ebdc37e , 0x9d9a75c6 , 0xa71ce53c , 0x3079c449 , 0x1f06b3b8 ] )
+  , Mont::unsafe_make( [ 0xa9464bea , 0x806ed0b9 , 0x717f7086 , 0xc9f123b7 , 0xb5a53578 , 0x35020c6b , 0xc9dce13d , 0x0511d531 ] )
+  , Mont::unsafe_make( [ 0xe96234d7 , 0x0d1479a0 , 0x07eca598 , 0xd79b7db1 , 0x72bc2cd6 , 0x9a3629b0 , 0x084ccd44 , 0x19fe1ef0 ] )
+  , Mont::unsafe_make( [ 0x45627740 , 0x95ec9a00 , 0x3c3d579f , 0xc135902b , 0x67b3d714 , 0xae8180ec , 0x11317352 , 0x06319d6c ] )
+  , Mont::unsafe_make( [ 0x9e8fa411 , 0xf3f88f56 , 0x353927eb , 0xe7c5d2dc , 0xc53caec0 , 0x081cb3ad , 0x41e0bd7f , 0x2ba582d7 ] )
+  , Mont::unsafe_make( [ 0xf22dd5c1 , 0xcc923c72 , 0x7da3e841 , 0xb5d3bc25 , 0x1a000568 , 0x22a81500 , 0x67e7d265 , 0x09381bd2 ] )
+  , Mont::unsafe_make( [ 0x232deb93 , 0x4ee012b2 , 0x93ccf868 , 0x8f7d8933 , 0x5e5c07af , 0x874a0c10 , 0xf4304a42 , 0x2e781da5 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x703287ba , 0x4be707f8 , 0xbdfa9614 , 0x9b0506fd , 0x52511e6d , 0xfcaf3534 , 0xa1f7be29 , 0x15e5a55d ] )
+  , Mont::unsafe_make( [ 0x1436dc12 , 0xd81eb9e9 , 0x65df4403 , 0x493134fc , 0x7e44764a , 0x96ad0bea , 0x91091594 , 0x06ed1db1 ] )
+  , Mont::unsafe_make( [ 0xf6d5c08e , 0x44a799ea , 0x6bb3adb1 , 0x82f8f532 , 0x0062a9f4 , 0x86150570 , 0xefd0ac59 , 0x1273bdb5 ] )
+  , Mont::unsafe_make( [ 0x81a5dea2 , 0x9a16fd2d , 0x07ee8758 , 0xd1880702 , 0xf001ea25 , 0x77ebde57 , 0x1a27c67c , 0x15f3ab7e ] )
+  , Mont::unsafe_make( [ 0x3b8d6124 , 0x8a5233cc , 0x012252ae , 0xd60f2469 , 0xe314b8e6 , 0x04bc0d99 , 0x94dfcd62 , 0x2029de2f ] )
+  , Mont::unsafe_make( [ 0x42d3f634 , 0xa6d92ba0 , 0x1e197d86 , 0xef3106cd , 0xacafb8c5 , 0x2e57ea93 , 0x299f7c73 , 0x2e58d00a ] )
+  , Mont::unsafe_make( [ 0xf340ae0d , 0x50ea46e7 , 0x09118f34 , 0x59e59ede , 0x3a451ca9 , 0x263be167 , 0x0cfe86f8 , 0x25fcdf8d ] )
+  , Mont::unsafe_make( [ 0x30da7407 , 0x8f314c53 , 0x1d51ef75 , 0xc21e259e , 0xd5a2c145 , 0x80a5d299 , 0x08292cc6 , 0x27e48a81 ] )
+  , Mont::unsafe_make( [ 0x7cacaa6b , 0xbb36fa2e , 0x5adc7df6 , 0x4b6e7a25 , 0xd95dc51c , 0x2d76612d , 0xf3b5eeca , 0x216d79a9 ] )
+  , Mont::unsafe_make( [ 0xc1a78749 , 0xcbb22e51 , 0xeac6d05c , 0x7b98a41a , 0x415081ba , 0x4a948001 , 0x26fdc044 , 0x080b11c4 ] )
+  , Mont::unsafe_make( [ 0xc73fca81 , 0x175820d8 , 0x5cc0e2be , 0x5e98dda7 , 0x0158c25c , 0x83115aa3 , 0xebcfa174 , 0x184f8e98 ] )
+  , Mont::unsafe_make( [ 0xd4bd60c9 , 0xe27feec4 , 0x83789743 , 0x88c678eb , 0xe3767744 , 0x84291073 , 0x8554f843 , 0x1677e88f ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x82ed3b81 , 0xec1fde8a , 0x966fb34d , 0xad016552 , 0x3c22d4cb , 0x9755a20e , 0x815520a0 , 0x2724aaa5 ] )
+  , Mont::unsafe_make( [ 0xfb9f76b2 , 0x426d01d2 , 0xb6178e12 , 0x0ef9798a , 0x8b34ce61 , 0xb5670e47 , 0xbae15192 , 0x222fabe1 ] )
+  , Mont::unsafe_make( [ 0x61a4dc57 , 0xd7fe786b , 0x6455d31c , 0x34b94fd1 , 0xd46a2f25 , 0x3c92a4de , 0xa8473d51 , 0x2db571ac ] )
+  , Mont::unsafe_make( [ 0x3a1adc9f , 0x4bfdcad4 , 0xb3ace048 , 0x2f529a7f , 0xbc3f2ecc , 0x587e6f50 , 0x879150d2 , 0x193163b5 ] )
+  , Mont::unsafe_make( [ 0x7092d040 , 0x8d0d62de , 0x94e768fc , 0xef3da408 , 0x060ba1a5 , 0x59a78624 , 0x2a513cb6 , 0x1d16f8bc ] )
+  , Mont::unsafe_make( [ 0x13c602a2 , 0xa1d805dc , 0x00f47daa , 0x4d72c252 , 0x794f0ce9 , 0x092d62fa , 0xd0ab5535 , 0x2fd652af ] )
+  , Mont::unsafe_make( [ 0xa3ec3d65 , 0x709fd801 , 0x9c7f035c , 0x1fa5aab9 , 0x277b3329 , 0x1695332b , 0x04f2fded , 0x0bac2c4d ] )
+  , Mont::unsafe_make( [ 0xb874b9b7 , 0x8655c5be , 0x4e2016ae , 0x6b4cc3ed , 0xed032261 , 0xd08907d1 , 0x2043c4d3 , 0x1f1b1096 ] )
+  , Mont::unsafe_make( [ 0x803cdfe4 , 0xfce447df , 0x18bcc278 , 0xda22db69 , 0x576853cf , 0x5efad863 , 0x7fa19a7e , 0x28065dc7 ] )
+  , Mont::unsafe_make( [ 0x0ca69a0c , 0xfabc0301 , 0x3b752cf8 , 0xc7285728 , 0x17fb6fe5 , 0x8763cb71 , 0x81b99de8 , 0x132f8ac6 ] )
+  , Mont::unsafe_make( [ 0x3eea52c8 , 0x98da316b , 0x37ffe81c , 0x2d2b6673 , 0xabf629c6 , 0x694df393 , 0x0ed532ad , 0x22ee3f57 ] )
+  , Mont::unsafe_make( [ 0x3871296f , 0x4d3f111b , 0x75796c81 , 0x75f0f47d , 0xb3d4a0fb , 0xe11c927f , 0x92c9de7c , 0x0da84126 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x1b0c3f5c , 0xaf273ba7 , 0xe65ac8bb , 0x64d9a375 , 0xf9affa2d , 0xaa89e8e6 , 0xedeebdcd , 0x069f2eeb ] )
+  , Mont::unsafe_make( [ 0x989a36d3 , 0x0e176f4c , 0xc3ee6311 , 0x4f3fb50c , 0xc89634d6 , 0x293d61ec , 0x79661a11 , 0x1bcde9c7 ] )
+  , Mont::unsafe_make( [ 0x4b39d66e , 0xc5c59aff , 0x7791e185 , 0xf293314e , 0xd6586e2e , 0xef7c4c88 , 0x16f3e1c0 , 0x0ce1389c ] )
+  , Mont::unsafe_make( [ 0xb54029e6 , 0x66976539 , 0x5685b82c , 0xaf12c05d , 0x0791b540 , 0x89d08168 , 0xbe24a461 , 0x019e6935 ] )
+  , Mont::unsafe_make( [ 0x0f25e54f , 0xc38fe06b , 0x89b2988b , 0x17595020 , 0xfced91b9 , 0x9423b633 , 0x45e22f53 , 0x17bf27cf ] )
+  , Mont::unsafe_make( [ 0x48a6ece3 , 0x5b04c750 , 0x291f7235 , 0x65730249 , 0xa262a91a , 0x32d5209a , 0xa63914ae , 0x2fe71901 ] )
+  , Mont::unsafe_make( [ 0x5076738b , 0x7a189ce3 , 0xa31301f0 , 0xf7af64d7 , 0x4caaa0cf , 0x31f86e23 , 0x6bdb2380 , 0x25fd3666 ] )
+  , Mont::unsafe_make( [ 0x3af87596 , 0xd1a93a91 , 0x193b4f91 , 0x7fc3bb0b , 0xa95afff7 , 0x9fa20f18 , 0x14c8c0c0 , 0x2c9df988 ] )
+  , Mont::unsafe_make( [ 0xa03f12f6 , 0xe1b3b3c0 , 0xeb974a0f , 0x4bba9810 , 0x01b03f7e , 0x0e85a54d , 0x7ecec3b6 , 0x1925e5d7 ] )
+  , Mont::unsafe_make( [ 0xdd32be53 , 0xc876c2e7 , 0x6f1b12e2 , 0xcd1393cd , 0xca643c1c , 0xc0cd8d4f , 0xd15874c9 , 0x052d2bc1 ] )
+  , Mont::unsafe_make( [ 0x831ef022 , 0xd57a2234 , 0xdb309509 , 0xcca4f202 , 0x8439706a , 0x352fb6ec , 0x6544c99d , 0x2b8606b6 ] )
+  , Mont::unsafe_make( [ 0xff5beceb , 0x3e683e15 , 0x79264f85 , 0xe0d2c2c3 , 0x483faed0 , 0x7f58fe55 , 0x917ecb70 , 0x0b8987b9 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x3717e6bb , 0x057e254d , 0x9650b399 , 0x0896406c , 0xf073ae31 , 0x0b3808bf , 0xbee6c496 , 0x1ff2752e ] )
+  , Mont::unsafe_make( [ 0x37fecb0e , 0xac0f636f , 0xf4ad41cb , 0x27caea4d , 0x8e26117a , 0x1ab081ba , 0xfea6ccb9 , 0x2316792a ] )
+  , Mont::unsafe_make( [ 0xd6f64c55 , 0xa05b96f3 , 0x879dfd1a , 0x7c40824f , 0x94196319 , 0x1fe6694e , 0x75a6e8ac , 0x0cddfe0f ] )
+  , Mont::unsafe_make( [ 0xb0e48fda , 0xa795130a , 0xd6d443fc , 0xdaffb75a , 0xcdc401cc , 0x06b71a41 , 0x0fb74ba6 , 0x29622f22 ] )
+  , Mont::unsafe_make( [ 0xb15c148f , 0x27adf793 , 0xa44332e3 , 0xf59bfce8 , 0x1f85ff26 , 0xbe5ca49e , 0x431bc6ff , 0x0bc1404b ] )
+  , Mont::unsafe_make( [ 0x77e586dd , 0x3aec06f3 , 0x20d7a08f , 0x71d6f2cf , 0x97a2fbf1 , 0xad82e331 , 0xb0e6d5cd , 0x2b8d1d84 ] )
+  , Mont::unsafe_make( [ 0xae6136c0 , 0xd1fb1388 , 0x1b35b4e9 , 0xc75662ad , 0xbf3a3055 , 0x6bfc9b75 , 0x387d767c , 0x26859ecf ] )
+  , Mont::unsafe_make( [ 0xa085a831 , 0xd0af7b8e , 0x9ad8cf0f , 0x4fbb6573 , 0x10517e79 , 0x80435a29 , 0xf9463650 , 0x2b11038b ] )
+  , Mont::unsafe_make( [ 0xf8c11e67 , 0xa39306ac , 0xb97c0064 , 0xa8053ed7 , 0x34f8fdb6 , 0x437f3788 , 0x7d1ebd7e , 0x166f2e8c ] )
+  , Mont::unsafe_make( [ 0x2dc1dd47 , 0x894d0667 , 0x09e064c9 , 0xb1ed808d , 0xc3f775e8 , 0xa1696584 , 0xf211c82b , 0x2b1d73c2 ] )
+  , Mont::unsafe_make( [ 0xd6b9e2e9 , 0x069275de , 0x1ca7775b , 0x83be84c7 , 0xc8b9d3fe , 0xf7d24939 , 0x30656581 , 0x2b8fe8c2 ] )
+  , Mont::unsafe_make( [ 0xe6ec2340 , 0x10eb753f , 0x93e24215 , 0x15ab7b72 , 0x24935c4c , 0x8c66d2ea , 0xbdfb0a25 , 0x067d9fbc ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xe770a300 , 0x3824548e , 0x5aec3bb3 , 0xe4efc0bc , 0x4e63258c , 0x6562d39e , 0x6752a295 , 0x06faec4c ] )
+  , Mont::unsafe_make( [ 0x6765b77e , 0x2dbfe5ad , 0x0af9c9c9 , 0xa8768f9d , 0xc4d16be7 , 0xa2fb1532 , 0xa087e644 , 0x065bed36 ] )
+  , Mont::unsafe_make( [ 0x238fbc6e , 0xbd0ba8f6 , 0xa13fed0d , 0xe76e4d73 , 0x2a86f6f0 , 0x254ffb75 , 0x2e7bcd45 , 0x0d98cd2e ] )
+  , Mont::unsafe_make( [ 0x9aaf183f , 0x0ca8ac63 , 0xd4d6a36b , 0x09a46abe , 0x8fe00b92 , 0xb656e46e , 0x44858690 , 0x082dc7d5 ] )
+  , Mont::unsafe_make( [ 0xf10d1053 , 0x48725831 , 0x5fb36eb7 , 0x736614b9 , 0x0ca1d952 , 0x71ee2bdc , 0xf207d114 , 0x022e2cc5 ] )
+  , Mont::unsafe_make( [ 0xe7141a43 , 0x1d4570d8 , 0x4e7da200 , 0x4dddba3a , 0xbf65c7f7 , 0x69685912 , 0x89ae6630 , 0x2207daa7 ] )
+  , Mont::unsafe_make( [ 0x682329a1 , 0x30783bbf , 0x833a4974 , 0x8b8f5f42 , 0x4f5c1e02 , 0x7738ce55 , 0x46434666 , 0x000b99ac ] )
+  , Mont::unsafe_make( [ 0xbaf51aa0 , 0xcae10b83 , 0x81f64e74 , 0x474c521a , 0x3b3939a5 , 0x076dfb1e , 0x445a0616 , 0x246b2bd3 ] )
+  , Mont::unsafe_make( [ 0x8d8dd776 , 0x95ca6c0b , 0x7fac9b6b , 0x7a2db54b , 0xe577b5c7 , 0xf25b1488 , 0x9c028e2a , 0x2e7efb53 ] )
+  , Mont::unsafe_make( [ 0xfe8fa781 , 0x585b1e1e , 0x19bddc14 , 0xeaf71f88 , 0xd15270bf , 0x091db388 , 0x1f2566ab , 0x10545989 ] )
+  , Mont::unsafe_make( [ 0xe6fb3918 , 0xe89fd12b , 0x5b57642d , 0xf3e399c4 , 0x2351956c , 0x88c21429 , 0x3dfce799 , 0x271d7e6a ] )
+  , Mont::unsafe_make( [ 0xcb028515 , 0x24c3782e , 0xf2ffef19 , 0x52267349 , 0x82df01c3 , 0x12e836eb , 0xa841e6f9 , 0x184c94e5 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xb5997223 , 0x16e68324 , 0x1d581413 , 0xad5ff6fa , 0xa823596a , 0x0fb5f116 , 0xe3c7d196 , 0x03614650 ] )
+  , Mont::unsafe_make( [ 0xead4a4e7 , 0xb14681a9 , 0x0dd7caed , 0x322f170b , 0x41b5f3df , 0xb62bdd92 , 0x80608ff2 , 0x20b3e0c4 ] )
+  , Mont::unsafe_make( [ 0xbe223d80 , 0xad0cc0ed , 0xd621bbdb , 0x76702d1c , 0x5da3706e , 0x63a95718 , 0x2404ba33 , 0x23772a75 ] )
+  , Mont::unsafe_make( [ 0x0fdd2261 , 0x2f75f5aa , 0x99d518fd , 0xf0daa903 , 0xa8e67d0e , 0x4f7be602 , 0xd306caee , 0x044f8862 ] )
+  , Mont::unsafe_make( [ 0x8745b00a , 0x00c354cd , 0x5a8c1837 , 0xa9325735 , 0x2c6fdcc7 , 0xcdb975da , 0x239d0b17 , 0x0e9fd19e ] )
+  , Mont::unsafe_make( [ 0x16f43036 , 0xa9702bc0 , 0x0eaaed89 , 0xbb728529 , 0xa0ba93cd , 0x962daf03 , 0x025ef6e5 , 0x045aaeff ] )
+  , Mont::unsafe_make( [ 0x90b7c1ec , 0x1f73b271 , 0x63352a8e , 0x73625c6d , 0x315e69e6 , 0x508b424c , 0x46a8d5ba , 0x29cb5169 ] )
+  , Mont::unsafe_make( [ 0x7ab15c5c , 0xf21f75ca , 0xaee2b05a , 0x4d9ead59 , 0x4f699f81 , 0x2dab1e1a , 0x9ef02255 , 0x06302d82 ] )
+  , Mont::unsafe_make( [ 0x47d7af1c , 0x46314ffd , 0x8a41afd3 , 0xd8443e4c , 0xd836f31c , 0x4cde535d , 0xbdbfd64d , 0x15d9729e ] )
+  , Mont::unsafe_make( [ 0x802c389d , 0x55ee6ad1 , 0x4f6f10b5 , 0xf7796002 , 0x3d9fe0ec , 0x34ab7f5a , 0x9ac313dd , 0x1cad1975 ] )
+  , Mont::unsafe_make( [ 0xc906471c , 0xb2f43249 , 0x39af9fb2 , 0x2cc12a5d , 0x470975ba , 0x783dbccd , 0x467f27c1 , 0x2caf3a6f ] )
+  , Mont::unsafe_make( [ 0x0128cc98 , 0x8cce6ff1 , 0x2f8e1560 , 0xd60853e4 , 0x785e68a0 , 0x04dbc8a9 , 0xf8d24d71 , 0x0fe5e31b ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xe707ed36 , 0xde35749b , 0x0f112adb , 0x21b9a340 , 0x0e1eca99 , 0x2fcd01a7 , 0x2fba7d10 , 0x14174511 ] )
+  , Mont::unsafe_make( [ 0x2e1ae107 , 0x6a9441df , 0x9a217ee7 , 0xbc06f30e , 0xae3663c7 , 0x84ac3218 , 0x2d139bb6 , 0x279931f4 ] )
+  , Mont::unsafe_make( [ 0x27ea87f8 , 0x25b607d8 , 0x54fb9505 , 0xbed37993 , 0x7b3fa0b7 , 0xd14739f7 , 0xe8986a26 , 0x04a4785a ] )
+  , Mont::unsafe_make( [ 0xecc035ac , 0x903b5580 , 0x0dbc8f39 , 0x8c5bfb44 , 0x345d25c7 , 0x9bc7a2fa , 0xbab1bdf3 , 0x22955681 ] )
+  , Mont::unsafe_make( [ 0x9eade362 , 0xd397b764 , 0xe85326e7 , 0xb1262b37 , 0x6f4eeca8 , 0x7b61624b , 0x1eca0d0f , 0x2d7c92bb ] )
+  , Mont::unsafe_make( [ 0x11dcc5f4 , 0x20213cac , 0xa9d8c86d , 0xbbe634df , 0xd39cf3e6 , 0x165e66b8 , 0x53280e74 , 0x299dae5c ] )
+  , Mont::unsafe_make( [ 0x106e7b32 , 0xb2ac7dbc , 0x28ba69b6 , 0x80be1254 , 0xbd49ba56 , 0xd6cf9519 , 0xd155db8f , 0x042eae34 ] )
+  , Mont::unsafe_make( [ 0x54a657a2 , 0xb537b7ce , 0x4683d23a , 0x462adb35 , 0x4a52c3b4 , 0x6c686fb2 , 0xf4c722b7 , 0x164ead8e ] )
+  , Mont::unsafe_make( [ 0x48a7170e , 0x7fcb2a46 , 0x96ab8a52 , 0x665281ae , 0x72b1edd6 , 0x82d0f5d0 , 0xd7aaf6f3 , 0x29075886 ] )
+  , Mont::unsafe_make( [ 0xe05741c1 , 0x5a414534 , 0x312ae3f3 , 0x17e2a78b , 0x3000cdf8 , 0x5b75fd49 , 0xb04fca5a , 0x27770d48 ] )
+  , Mont::unsafe_make( [ 0xa319d9b1 , 0xa3d19840 , 0x24b2e0bc , 0xbcbc78cc , 0xaa1dbfd0 , 0x3c2c3867 , 0xcfd2cc67 , 0x1a5e6ea2 ] )
+  , Mont::unsafe_make( [ 0x720d1366 , 0x7caba48d , 0x82f89359 , 0xd1067896 , 0xd0765c25 , 0xb3d21712 , 0xfba90f81 , 0x2c7f1827 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x9636d56b , 0x23aa86bd , 0xcff2dfc8 , 0xa75eb174 , 0xbfb89675 , 0x3c299aec , 0x3ea91bf3 , 0x226f0bd4 ] )
+  , Mont::unsafe_make( [ 0xa6586d26 , 0x3540bb5d , 0x91bdc2ea , 0xbc2894d8 , 0x55ffd4f9 , 0x620a8713 , 0xd9e408a1 , 0x180bbf2c ] )
+  , Mont::unsafe_make( [ 0x480de69c , 0xccdadc8c , 0x2a55ba78 , 0x41b074fa , 0x999bd547 , 0x36a09fdc , 0xee0799bf , 0x13b8ef42 ] )
+  , Mont::unsafe_make( [ 0x8606c163 , 0x8d4e36f5 , 0xfb93f2e8 , 0xef4e1fcf , 0xbdc5adba , 0x91e71c28 , 0x9efa7aed , 0x2b299b18 ] )
+  , Mont::unsafe_make( [ 0xed891e92 , 0x6e2720b6 , 0xe2229fa3 , 0x0f97dc3b , 0x831dacae , 0xc77b2c2e , 0xb63ec390 , 0x032968b3 ] )
+  , Mont::unsafe_make( [ 0xaf05bf69 , 0x8d16a654 , 0xee73cf5d , 0xea51c9b7 , 0x0442a41d , 0xfa45cf3b , 0x7941935c , 0x24ed9059 ] )
+  , Mont::unsafe_make( [ 0x479c7fe9 , 0x268cc198 , 0x47567b03 , 0xb00cef8f , 0x4cf1faad , 0x8434572e , 0x02c538d7 , 0x184d4e72 ] )
+  , Mont::unsafe_make( [ 0x2cedb936 , 0x0b33d8d0 , 0x8b4a950b , 0x950c9900 , 0x08f405d0 , 0x7a2ddab3 , 0xaf6fb273 , 0x06916ba7 ] )
+  , Mont::unsafe_make( [ 0x86a4ea9f , 0x1c4f0949 , 0x1d145ca9 , 0xae111ab1 , 0x6913edd0 , 0xe3a61884 , 0x73ff9498 , 0x0ffdfbb5 ] )
+  , Mont::unsafe_make( [ 0x497f7223 , 0xba31c82a , 0x31758d83 , 0x0d657141 , 0x8d029a9f , 0xc88afb1c , 0x2ca0ce79 , 0x10622b2f ] )
+  , Mont::unsafe_make( [ 0x7ea130bd , 0x3c8d34f5 , 0x281c249e , 0x01c59c47 , 0xd90491b0 , 0xc7af2586 , 0x35ed45de , 0x105a7f6a ] )
+  , Mont::unsafe_make( [ 0x7f1adc2b , 0x403855dc , 0x142005dc , 0xcac2e632 , 0x58e8800f , 0x57996192 , 0xb700b47e , 0x03b7db38 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x400715f1 , 0xc04ce767 , 0x36ded787 , 0x6492726f , 0x14553ca6 , 0x47538cc6 , 0xbf8186b9 , 0x00910dca ] )
+  , Mont::unsafe_make( [ 0x30592a95 , 0xd4a739c7 , 0x632eb68b , 0x51552078 , 0xfc1d74aa , 0x13961877 , 0xdc55bc44 , 0x2c1b2aa6 ] )
+  , Mont::unsafe_make( [ 0x538b022c , 0x7eceefba , 0x4d92dffb , 0xc8400c4c , 0x5b313b14 , 0x596b2e1c , 0x4d30a369 , 0x26a2b90c ] )
+  , Mont::unsafe_make( [ 0x89a91034 , 0x68d70f23 , 0x01867c7b , 0x674c314d , 0xda24cfc2 , 0x76edcb57 , 0xa62aaa16 , 0x104d89e8 ] )
+  , Mont::unsafe_make( [ 0x03ebdca9 , 0x3b6c180e , 0x4abe9803 , 0xc74b2d03 , 0xaad486a0 , 0x5090da58 , 0x03a3f0ae , 0x15dbceb9 ] )
+  , Mont::unsafe_make( [ 0x81c0bdcf , 0x4a695a4d , 0x79f65bfd , 0xf2e5992b , 0xa7cb6824 , 0x4d3c4939 , 0x51f7c44f , 0x0b2beac4 ] )
+  , Mont::unsafe_make( [ 0xcaca5084 , 0xc5655f92 , 0x26f7227a , 0x175bc8b5 , 0xdd248fdb , 0x76b02135 , 0x952ab0f3 , 0x174ce0a4 ] )
+  , Mont::unsafe_make( [ 0x8f16265a , 0xdb722027 , 0x97211c16 , 0xf2d796a4 , 0xd2be3387 , 0x42cfdead , 0x0242d12d , 0x1e3fce62 ] )
+  , Mont::unsafe_make( [ 0x2cdf2ff6 , 0x3e7a0d37 , 0xbea4c57f , 0xbb26c752 , 0xb6bd7507 , 0x89562cb7 , 0x704040e7 , 0x19bd95ab ] )
+  , Mont::unsafe_make( [ 0x6fd70034 , 0xf85eac83 , 0xc49959ea , 0xdbeef22a , 0xf0b1c0a5 , 0xd1f7aa3f , 0x653e3ff7 , 0x2eb7cfc4 ] )
+  , Mont::unsafe_make( [ 0xa13249e9 , 0x68b6672c , 0x7cc3cf4f , 0x3d0a58a3 , 0xfc84bc5d , 0x6279016d , 0xf04c036a , 0x0ea10254 ] )
+  , Mont::unsafe_make( [ 0x61945aad , 0xe01e89d7 , 0xa97b8f62 , 0x001ea0b6 , 0xc0510eb2 , 0x082a3979 , 0xc9016c48 , 0x2f19b68d ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xb54e5edf , 0xe5ec6ea2 , 0xbac9e814 , 0x8d1448c3 , 0x819b4d24 , 0x156ee5bc , 0x0780ef04 , 0x0532b4c5 ] )
+  , Mont::unsafe_make( [ 0xca2ff074 , 0x90d9f227 , 0x9f5652b9 , 0x9333d9e9 , 0xdb51944a , 0x1bd02863 , 0x762a2c36 , 0x1650eafa ] )
+  , Mont::unsafe_make( [ 0x9539a19d , 0x83e0864d , 0x03140adf , 0xedbd6cf3 , 0x63692b8b , 0x824f9074 , 0x70e22351 , 0x1493ff78 ] )
+  , Mont::unsafe_make( [ 0xb98a1640 , 0x3a406531 , 0x10aef24a , 0x6adf145c , 0x70929e7c , 0x7a49efec , 0xec285ccd , 0x0cd44328 ] )
+  , Mont::unsafe_make( [ 0x56fa108c , 0x23ed36fb , 0x82acc9a5 , 0x27b42d30 , 0x7d7b2ad3 , 0xd982731c , 0x6e238c4c , 0x25466e6a ] )
+  , Mont::unsafe_make( [ 0x06857101 , 0x97f7c094 , 0x24f232be , 0xb7ec2fab , 0x5078f780 , 0xd9a41380 , 0xcb2d461b , 0x0bc8042e ] )
+  , Mont::unsafe_make( [ 0xe563c8d8 , 0x335cfa06 , 0x60f47aaa , 0x764cbfee , 0xd48a8246 , 0xa539ef20 , 0x46dca375 , 0x24a3559c ] )
+  , Mont::unsafe_make( [ 0x724ce69e , 0x1dea9848 , 0x9faec045 , 0xe267079c , 0xafaf9a7e , 0xae0f62ce , 0xeb83ee1b , 0x23a76b17 ] )
+  , Mont::unsafe_make( [ 0xa10f485f , 0xbee06d6e , 0x2eb9c713 , 0xc99b1eaa , 0xe0f7369f , 0xaafbffea , 0x2c35ddf7 , 0x2f9f42d2 ] )
+  , Mont::unsafe_make( [ 0xd4bf5d32 , 0x31a14dde , 0xbd9b1527 , 0xb9c19afb , 0x9018ff83 , 0xc4d5f188 , 0xa7781aac , 0x1ae6e967 ] )
+  , Mont::unsafe_make( [ 0x3a556c61 , 0xfc0a1c5e , 0xf3ecb334 , 0x43d9d0e4 , 0x47851188 , 0xde500815 , 0x38797229 , 0x2767ad61 ] )
+  , Mont::unsafe_make( [ 0x80141d01 , 0xf1ef7f11 , 0xe288a84b , 0x9c0fc77e , 0xcfc36215 , 0x453c49cc , 0xaf5c0165 , 0x0f42e3d5 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x024b3f8d , 0x73b8d05d , 0x7ce02165 , 0xbdd0aea1 , 0xe8b3d6bf , 0x2243ab0e , 0xb23629c8 , 0x02e8884a ] )
+  , Mont::unsafe_make( [ 0x5b683274 , 0x1002d127 , 0x07c65d30 , 0xc179ac05 , 0x717d4e99 , 0x343d3194 , 0x7ced0776 , 0x1d100d3e ] )
+  , Mont::unsafe_make( [ 0x9411bbec , 0xff328a07 , 0x2361a55a , 0xbfc9c664 , 0x456203dd , 0xcd039a04 , 0x0af8e35b , 0x0ab7db9b ] )
+  , Mont::unsafe_make( [ 0x4d8a9c60 , 0xfa6be422 , 0x8fd3e2a9 , 0x9dda343c , 0x1e7379f8 , 0xf13d1a4c , 0x9a99235c , 0x1cef6741 ] )
+  , Mont::unsafe_make( [ 0x999406a0 , 0x19f823de , 0x838a8a47 , 0x59ea814f , 0xb4335f88 , 0x29d01e3c , 0xf3079689 , 0x0344bc15 ] )
+  , Mont::unsafe_make( [ 0x3b4f1317 , 0xa7cec72d , 0xae067d14 , 0xd074bc20 , 0x0c0d3f09 , 0x9fca8e42 , 0x06618666 , 0x1482752c ] )
+  , Mont::unsafe_make( [ 0x2664c965 , 0xb6d65a03 , 0xc080967a , 0x3355e09c , 0xbdf83775 , 0x603a8324 , 0x9ea3e717 , 0x04464571 ] )
+  , Mont::unsafe_make( [ 0xc9f85d6a , 0x894eeba1 , 0x8c6a0fe2 , 0x366db1e1 , 0xeeecec42 , 0xc64966bf , 0xa7673fe6 , 0x1d1f1055 ] )
+  , Mont::unsafe_make( [ 0xa3da850e , 0x3b1d06a0 , 0x3228a6b8 , 0x56a83e8c , 0x8501e276 , 0x3ea52305 , 0xd7585cca , 0x2ff95985 ] )
+  , Mont::unsafe_make( [ 0xe34a4d1d , 0x266df4c1 , 0x842255e9 , 0x7682c7be , 0xd1def84d , 0xee9030a8 , 0x503e9077 , 0x09ead199 ] )
+  , Mont::unsafe_make( [ 0x132465eb , 0x532120a0 , 0x2468c042 , 0x2dba55b9 , 0x9544de38 , 0xee22dfec , 0x199a5782 , 0x134411c6 ] )
+  , Mont::unsafe_make( [ 0xd8609b25 , 0xd236008f , 0xa90d5a4b , 0xce233290 , 0x7bc709f6 , 0x4a444d72 , 0x41b1ba76 , 0x25e551e4 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x99efb2ca , 0x41733cd5 , 0x7335cb42 , 0x3795e3a5 , 0x4e0448d1 , 0x56440450 , 0xb8a6dc72 , 0x254650f2 ] )
+  , Mont::unsafe_make( [ 0x63937412 , 0xf5617d10 , 0xa70a1825 , 0xc22c059c , 0x499b09ab , 0xed011b20 , 0xa55f884f , 0x17cb5d38 ] )
+  , Mont::unsafe_make( [ 0x6618da59 , 0xe9df9d5b , 0xf67e9609 , 0x3a8f0373 , 0x6fe2752b , 0x852245ad , 0x9eca4205 , 0x06952d4c ] )
+  , Mont::unsafe_make( [ 0x77a1e571 , 0xde37a5c2 , 0x1c8cbdf2 , 0x1d008da4 , 0xd4fffb3e , 0xca459898 , 0x9a9ccdd1 , 0x04239ed2 ] )
+  , Mont::unsafe_make( [ 0x7dbb683c , 0x42b2e9e5 , 0x6d03c9ca , 0xe473cf40 , 0xe2319e69 , 0xcae943d1 , 0x1ff53c01 , 0x23d700e0 ] )
+  , Mont::unsafe_make( [ 0xe83d647b , 0x0d197b9b , 0xebb21255 , 0x28cf320c , 0x899b19eb , 0x1ecf72c0 , 0x0db32ecd , 0x2fc94693 ] )
+  , Mont::unsafe_make( [ 0x023aa7f7 , 0x1ed6ad09 , 0x583572c8 , 0x8ef79459 , 0x4b86483b , 0x0c08cd99 , 0xb0f0f33c , 0x062e7ea8 ] )
+  , Mont::unsafe_make( [ 0x9636a821 , 0xd24942f8 , 0x61225aea , 0xe5098bb5 , 0x3d8c984c , 0xf7e543f7 , 0xf40ee40d , 0x066f1851 ] )
+  , Mont::unsafe_make( [ 0xc754717e , 0x40ffd06d , 0x470b0f80 , 0x3a7a4717 , 0x7a88b4f8 , 0xc2d4dbee , 0xbe0dca81 , 0x2e5eaf56 ] )
+  , Mont::unsafe_make( [ 0x06214b00 , 0xa9606b3d , 0x00f8a3eb , 0x9ac07cf4 , 0xd90a1fa5 , 0x7ab62aed , 0x3b4da53c , 0x21dabd6e ] )
+  , Mont::unsafe_make( [ 0xf2f8877c , 0x5406a2c7 , 0xb80801df , 0x287aac2e , 0x5a1c4166 , 0x74c8489d , 0x7d380433 , 0x259fa962 ] )
+  , Mont::unsafe_make( [ 0xbd935706 , 0xef0f4870 , 0x086aba66 , 0x1d655114 , 0x90d48515 , 0x74ea7e15 , 0x25f9a9dd , 0x079b3bad ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x898058f4 , 0x760e33ea , 0x2f73e550 , 0x5b2d13a7 , 0x4559c66e , 0xd207f815 , 0xa456c019 , 0x242a27bc ] )
+  , Mont::unsafe_make( [ 0xad7cc584 , 0xe34b73e5 , 0x7de79961 , 0xdb54a575 , 0xe31b7b30 , 0xd1c0b56a , 0x1ed9e148 , 0x23170f94 ] )
+  , Mont::unsafe_make( [ 0x9430d3c5 , 0xb6c8ebed , 0xcb48ec38 , 0x6ed4bfb6 , 0x57b5fc56 , 0x533a5747 , 0xcf3f013e , 0x2f670783 ] )
+  , Mont::unsafe_make( [ 0x0448ba9f , 0x74e77c12 , 0x55be4074 , 0xfda67612 , 0xc8b40544 , 0x6bf96477 , 0xb0b9d2f2 , 0x0984595d ] )
+  , Mont::unsafe_make( [ 0xd124b7ca , 0x7b3546ee , 0x5ce5a8ad , 0x2e512371 , 0x5b1338d2 , 0x5bcf173e , 0x40a7ea22 , 0x0b23ee30 ] )
+  , Mont::unsafe_make( [ 0x61e0b055 , 0x57ce351d , 0x8e4654ee , 0xc2be4c85 , 0x0c0e34e9 , 0xee775762 , 0xfac9d6db , 0x07232807 ] )
+  , Mont::unsafe_make( [ 0x35fa44b0 , 0x3cce3fdd , 0x7516dd55 , 0xf18c38c0 , 0x5f1c2874 , 0xaacad12e , 0x0c9b8a84 , 0x0601ae48 ] )
+  , Mont::unsafe_make( [ 0x71b017d0 , 0xc0b05b29 , 0xbf331333 , 0xbb0d6599 , 0x1fbe5c6a , 0xf080baec , 0x8773b958 , 0x0af1f8a0 ] )
+  , Mont::unsafe_make( [ 0x9317e8ed , 0xf902392d , 0xe16f9a10 , 0xee0a3612 , 0x771ba80a , 0xe530cc26 , 0xcf9e6f89 , 0x06275083 ] )
+  , Mont::unsafe_make( [ 0x2e8d6129 , 0x486cefcc , 0x72b100cf , 0xc5f92977 , 0x117da95e , 0x1b90b865 , 0xe2cf436d , 0x0010e7e6 ] )
+  , Mont::unsafe_make( [ 0x7cbffe44 , 0x0889f275 , 0xf409298c , 0xbed5b69e , 0x5636681a , 0xb7fcceee , 0xf664c98e , 0x301c17f1 ] )
+  , Mont::unsafe_make( [ 0x9a6b0e7a , 0xac0a5b79 , 0xe88bfb8c , 0x77cce241 , 0x025d4bef , 0x5110a397 , 0xaf4c278e , 0x1d44ff48 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x678c57f2 , 0x63af3858 , 0xf196698e , 0xa576fdd9 , 0x8c7559ce , 0x20d5698c , 0x0b92bb09 , 0x029394d5 ] )
+  , Mont::unsafe_make( [ 0xd0e84047 , 0x2b771463 , 0x63491135 , 0xc57b0172 , 0xe2a30719 , 0x6159956f , 0x6d3b2453 , 0x1eb6a1b3 ] )
+  , Mont::unsafe_make( [ 0x906b1970 , 0xe6e731b4 , 0x6f2b8ce4 , 0xe36ef329 , 0x1423f3ca , 0xab774348 , 0x521b811a , 0x15a41af3 ] )
+  , Mont::unsafe_make( [ 0x3be77184 , 0xb255e72e , 0x0fbb5577 , 0x356e5200 , 0xbae11f36 , 0xb01163e7 , 0x7be35db1 , 0x1ff28e72 ] )
+  , Mont::unsafe_make( [ 0x388e1182 , 0xcf1f6eb9 , 0xb0e53ac3 , 0x0d0c184a , 0x22b2404b , 0x7ecac630 , 0x2fb51516 , 0x1b76617b ] )
+  , Mont::unsafe_make( [ 0x184be80a , 0x3c6e6b05 , 0x55472eed , 0xc7b433b5 , 0xc4c4b470 , 0x610fbee8 , 0xcf409c51 , 0x0e606203 ] )
+  , Mont::unsafe_make( [ 0x0cd27cb2 , 0xb9d18184 , 0xabd5362e , 0xcbc78ef1 , 0xb0cf08b8 , 0x53757fe4 , 0xff28deb6 , 0x09b8a035 ] )
+  , Mont::unsafe_make( [ 0xacd214bc , 0xc5a3ae8a , 0x000f1017 , 0x2ab1f1fb , 0x623cabcb , 0x7222e2a8 , 0x29526ed6 , 0x04d964ed ] )
+  , Mont::unsafe_make( [ 0xe8b9572a , 0xee6c468c , 0x61b3eaed , 0xe3005537 , 0xbcba7d7a , 0xaa738044 , 0xb0676d36 , 0x03136e3d ] )
+  , Mont::unsafe_make( [ 0x6c591ce0 , 0x20176ff0 , 0xcf06f4fc , 0x79d05797 , 0xcd85e76b , 0x6bc91f64 , 0xabee4681 , 0x10cf7678 ] )
+  , Mont::unsafe_make( [ 0x66ae887d , 0x2a3605e8 , 0x76618bed , 0xd7bc8528 , 0x235295b5 , 0xe0333669 , 0x8ed1c273 , 0x21fbd2d3 ] )
+  , Mont::unsafe_make( [ 0x7534da00 , 0x19d70969 , 0x7e329af2 , 0x6f153e9a , 0x89a7b16e , 0x43b0511f , 0x02aa7c54 , 0x05c7c3da ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xef8b32fe , 0x981fb3eb , 0xce8193c4 , 0x5eac7ae3 , 0xc6bceeb0 , 0x7bbc8434 , 0x06006e46 , 0x109f684a ] )
+  , Mont::unsafe_make( [ 0x8d2bec87 , 0x896038ac , 0x47c91ce9 , 0x46f93b30 , 0x476b4a7d , 0xe4329fb7 , 0x3fd0be44 , 0x09871cd2 ] )
+  , Mont::unsafe_make( [ 0x3bfdf008 , 0xa619ac1e , 0xcc41943b , 0x062bc783 , 0xff16a104 , 0xdd76ad66 , 0xcc336421 , 0x247724d4 ] )
+  , Mont::unsafe_make( [ 0x513fca46 , 0x005a9863 , 0x6788dd9e , 0x1b499bc9 , 0xc888dfed , 0x2902ca61 , 0x9fd686c4 , 0x156502ca ] )
+  , Mont::unsafe_make( [ 0x203428db , 0x89d0b93c , 0xb6076f0e , 0x42621450 , 0x0cdcfcea , 0x11c5cbb4 , 0x2887663f , 0x0c152047 ] )
+  , Mont::unsafe_make( [ 0x77a8d18f , 0x28d9a6c7 , 0xba6b5c36 , 0x3a2e6251 , 0x46811135 , 0xf0db99d7 , 0x0403055f , 0x023a21b1 ] )
+  , Mont::unsafe_make( [ 0x345ab383 , 0x98b1e1d1 , 0x75448766 , 0xb89eae65 , 0xff931db0 , 0x4c373d0c , 0xb0f59e80 , 0x03df4352 ] )
+  , Mont::unsafe_make( [ 0xfe2ae4c5 , 0xe9a8dc97 , 0x6c3c2139 , 0x30999e19 , 0x6130ea2b , 0xaafa91bc , 0x47e25079 , 0x2083dd02 ] )
+  , Mont::unsafe_make( [ 0x46824733 , 0x6d89e942 , 0x9e86672c , 0xaed5e889 , 0x72937ca1 , 0x5dccde66 , 0x9a04ba98 , 0x19d04307 ] )
+  , Mont::unsafe_make( [ 0xc5a9cf73 , 0xb2682ca4 , 0x64c65f9b , 0xc2378c6d , 0x2e95e36e , 0x6c7c249c , 0x489baed1 , 0x1cb3c4fd ] )
+  , Mont::unsafe_make( [ 0xb7a0de89 , 0xc7d1f299 , 0xb15993d2 , 0x061fc7fc , 0xb5090b87 , 0xd5bff2f4 , 0x00ebf22b , 0x19a76158 ] )
+  , Mont::unsafe_make( [ 0x896e46cc , 0xd0a30b77 , 0x747d9ba5 , 0xc8285ead , 0x2a81c47d , 0xbc0fe264 , 0x5657a65e , 0x1fdbdf57 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x45c4042d , 0xb15ac62c , 0x5773bbc8 , 0xbe8ac6ca , 0xd6c166c5 , 0xe9a1ddf2 , 0x056a0588 , 0x16a4b7c9 ] )
+  , Mont::unsafe_make( [ 0x8f88c14b , 0xa7a7d669 , 0xf71ae5b0 , 0xf0c11799 , 0x0728fcc2 , 0x6c8108f7 , 0x1d877988 , 0x0f2879dc ] )
+  , Mont::unsafe_make( [ 0x61f5d048 , 0xc1fb52ca , 0x846b16f5 , 0x573587d2 , 0xf9d5d60d , 0xc7175597 , 0x12bc7482 , 0x102f8ada ] )
+  , Mont::unsafe_make( [ 0x792dd3a4 , 0x7fdcdee0 , 0x9cc8d935 , 0x4d40d9fd , 0x047193b8 , 0x14dc6d47 , 0x91b25f9f , 0x14789d2d ] )
+  , Mont::unsafe_make( [ 0x9c5992c7 , 0x1676f342 , 0x97c57e89 , 0xcf30f0f0 , 0x78ffb058 , 0x7d9d665a , 0xa2af14b6 , 0x12f490f2 ] )
+  , Mont::unsafe_make( [ 0xccbf03f6 , 0x272b1de6 , 0x97631d93 , 0xbc203fb1 , 0xd47fd231 , 0xa85079a6 , 0x096f51e5 , 0x1a81ffe8 ] )
+  , Mont::unsafe_make( [ 0x16589f30 , 0xbe5372fd , 0xe26fe11d , 0x25739ba4 , 0xab7573fd , 0x1130f3d1 , 0xf76c7c39 , 0x022341ff ] )
+  , Mont::unsafe_make( [ 0x9382796d , 0x3a4791b0 , 0xb6aca310 , 0xe5466e78 , 0x83b0ce4e , 0x2655ce80 , 0x827aa821 , 0x1d8af932 ] )
+  , Mont::unsafe_make( [ 0xc2d6944c , 0xc46119cb , 0xfc16551f , 0x524eb940 , 0x4bd480b1 , 0xf7b537f3 , 0x026095a6 , 0x1b67fe64 ] )
+  , Mont::unsafe_make( [ 0x4ea0e455 , 0x474be6e9 , 0x9972e313 , 0x3554824c , 0x3bdea007 , 0xa903a9bf , 0x1f4883cb , 0x03815b80 ] )
+  , Mont::unsafe_make( [ 0x24185ce2 , 0xf257b467 , 0xe1febb83 , 0xf81599a1 , 0xce5e2182 , 0xf29f3df4 , 0xc6f86abf , 0x02701e61 ] )
+  , Mont::unsafe_make( [ 0x53154521 , 0xbb43ec2f , 0xe16c4430 , 0x7a66d6af , 0xa47ec253 , 0xfeaf0baf , 0xb50d5edc , 0x1ecc4c3a ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x7d08e1fd , 0xb4844fdf , 0x120d7a44 , 0x53fc9a5a , 0x29abbccf , 0x65f66bbe , 0x6140f94e , 0x0e43e010 ] )
+  , Mont::unsafe_make( [ 0xa09a0d87 , 0xd206cb62 , 0x4bd5b414 , 0xb47e117b , 0x5228214c , 0x16828988 , 0xc294ca45 , 0x0f3255c4 ] )
+  , Mont::unsafe_make( [ 0x8a4363c2 , 0xa21bc6df , 0x188b090d , 0x0ad06ecd , 0x061b633d , 0xa42432db , 0x0c909d37 , 0x1e8d4ced ] )
+  , Mont::unsafe_make( [ 0x9f23b72a , 0xa0671e64 , 0xc88c54ea , 0x5ea26691 , 0x45fb0693 , 0x24fd9bd9 , 0x030a91f8 , 0x1b85c6f0 ] )
+  , Mont::unsafe_make( [ 0x243fafa6 , 0xa584c0e2 , 0x54f2a091 , 0xd84dc65a , 0x6f8e7e8e , 0xfefbea36 , 0xd3777d2a , 0x02053b92 ] )
+  , Mont::unsafe_make( [ 0x9c789e3f , 0xaff1acdd , 0xd00e6110 , 0x447184cc , 0x66e60840 , 0x7980e7f3 , 0xb664d218 , 0x162e51b4 ] )
+  , Mont::unsafe_make( [ 0x107cfdd7 , 0xd4149d83 , 0x1e3f1f3d , 0x96d94c50 , 0x717bd990 , 0xd4eb20ca , 0xa064c26b , 0x10f81213 ] )
+  , Mont::unsafe_make( [ 0xeaaa3e72 , 0xcec0fdeb , 0x63bab2be , 0x9804e49f , 0xe82887ac , 0x51c3841d , 0xb011fddd , 0x250de5d6 ] )
+  , Mont::unsafe_make( [ 0x9abf103d , 0xcc77c277 , 0x2334b10d , 0xe4140dae , 0xde2a0023 , 0x5e4e3c69 , 0xebcaf085 , 0x27b0c670 ] )
+  , Mont::unsafe_make( [ 0x063c6b87 , 0x27a15062 , 0xb8afd38a , 0x2e74b2ac , 0x05bcc001 , 0xa44a93b8 , 0xc913b441 , 0x1a2c19fc ] )
+  , Mont::unsafe_make( [ 0xa4bf192a , 0xaf859296 , 0x4c506c04 , 0xf4040f6e , 0xb19e22c7 , 0xd08c0472 , 0xab8ce4a1 , 0x05be380b ] )
+  , Mont::unsafe_make( [ 0x57c867d1 , 0x14ede453 , 0x8cff8c50 , 0x2eaebb52 , 0x404e5084 , 0xd6d349c1 , 0x8b159e77 , 0x0c93e528 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x43055f52 , 0xa07467b9 , 0x8480f4f9 , 0x258aa117 , 0x3a5b21e7 , 0xba8dfbf3 , 0xc2634ca3 , 0x1484d709 ] )
+  , Mont::unsafe_make( [ 0x9980bee8 , 0x75b18b68 , 0x8b21e706 , 0xa62f095a , 0xb2ed3a26 , 0xbddac0b3 , 0xdc8a860f , 0x1aa7eac6 ] )
+  , Mont::unsafe_make( [ 0x6905682e , 0xc3d59409 , 0x7ae54a09 , 0x1306311a , 0xc0ae763a , 0x76107c9a , 0x557d37cf , 0x05d54287 ] )
+  , Mont::unsafe_make( [ 0x427b3e83 , 0xafaf6ff6 , 0xbea813dd , 0x071be4be , 0x4df99d9d , 0x3f6ddbcd , 0x5daa53aa , 0x10f005b0 ] )
+  , Mont::unsafe_make( [ 0xe6e13aaf , 0x7ec4bb4d , 0xdbf587a2 , 0xbcc2688c , 0xac0e336c , 0x8f566c16 , 0x5f36bfa8 , 0x1e0a6cc7 ] )
+  , Mont::unsafe_make( [ 0xc2814426 , 0xb39f498b , 0xe667156b , 0xc9b1a7bc , 0xc2d11172 , 0x857fee4c , 0x90ceb902 , 0x1057d791 ] )
+  , Mont::unsafe_make( [ 0x5a6b1080 , 0x499f9e0b , 0xa9a66420 , 0xe0748635 , 0x0b77bb99 , 0x1552f41c , 0x23add13b , 0x1cdeacb7 ] )
+  , Mont::unsafe_make( [ 0x9cefe2aa , 0x4b36f5c9 , 0x06d90c8d , 0xa3bd52b9 , 0x323505f4 , 0x0cfc9cfe , 0x2c0c6230 , 0x2615cb99 ] )
+  , Mont::unsafe_make( [ 0x7eea1a64 , 0xffb0c88d , 0xf32eb74d , 0xcac17cc8 , 0x786daadd , 0x41844ccb , 0xb781634e , 0x20a28d9f ] )
+  , Mont::unsafe_make( [ 0xcda4d0a6 , 0xfdf98b32 , 0xa8b83b60 , 0xdb2424df , 0xa2321fb0 , 0x8bfd09ac , 0x086ee241 , 0x305016df ] )
+  , Mont::unsafe_make( [ 0x19691e31 , 0x72b518af , 0x06e6272c , 0x67dfeaf0 , 0x7f27df73 , 0x02cf8d96 , 0xac39d3d4 , 0x0a519b5d ] )
+  , Mont::unsafe_make( [ 0x9ef66183 , 0x9db52bb3 , 0x96d4bfee , 0x1bfb785e , 0x874cbd8c , 0xf017256c , 0x16050c53 , 0x0be4a273 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xe5c57b1b , 0x331153f2 , 0x0be0c469 , 0x2474d4bc , 0x4896e3c6 , 0xfd4fb1cb , 0x75ba2bf0 , 0x1d0133df ] )
+  , Mont::unsafe_make( [ 0x3a15ddff , 0x2669a4ba , 0x46871035 , 0x8bc68034 , 0x7b4b8803 , 0x9f383979 , 0x5af969c0 , 0x07461ac4 ] )
+  , Mont::unsafe_make( [ 0x4dc86733 , 0xa1e4cd41 , 0x28a74b90 , 0x836733f8 , 0xf50e61ed , 0x3ccf5a98 , 0x7b3c3db0 , 0x1ad4a386 ] )
+  , Mont::unsafe_make( [ 0x15f18066 , 0xf08a7541 , 0xdc1b40b7 , 0x3f4861e7 , 0xe7b63784 , 0x04eb54ba , 0x1031e63c , 0x1e36ff9a ] )
+  , Mont::unsafe_make( [ 0xf985ef72 , 0xc13cea8a , 0x881c09e9 , 0x6752a1de , 0xda9a9394 , 0xceb2737f , 0xeb0ec9df , 0x0914bf6d ] )
+  , Mont::unsafe_make( [ 0xa6de75dc , 0x9e0bab26 , 0xa7192f5e , 0x30410542 , 0x23a461e1 , 0x0b8e2f56 , 0xdb089948 , 0x2a58a7e3 ] )
+  , Mont::unsafe_make( [ 0xe1b34f54 , 0x46e170e7 , 0xf3e5ff93 , 0xd640bcea , 0xb04bb8ca , 0x18aa1a65 , 0xd336b135 , 0x05204b41 ] )
+  , Mont::unsafe_make( [ 0x2f6a982d , 0xf7f87639 , 0x4d566ee8 , 0xb2e3badd , 0x945d3f48 , 0xf0567ab0 , 0x6d73aee3 , 0x2ae88cc2 ] )
+  , Mont::unsafe_make( [ 0x7d037788 , 0xbe716319 , 0x3f5eaba9 , 0x69e117a7 , 0xcfd04717 , 0xf3e7af41 , 0x1fc96e78 , 0x22a7abaf ] )
+  , Mont::unsafe_make( [ 0xbf1beb3d , 0x5b445c36 , 0x7527d6f4 , 0x10a450dd , 0x8d273499 , 0xfece28be , 0xcb015ebf , 0x19c44967 ] )
+  , Mont::unsafe_make( [ 0xdc9954ed , 0x31d09fb4 , 0x53de23dc , 0xd38482d2 , 0x107fc00c , 0x602fac41 , 0x420553c6 , 0x05fe74a1 ] )
+  , Mont::unsafe_make( [ 0x29f97074 , 0x01ebdfa9 , 0xafc73694 , 0xa3a36b8b , 0x42d49adc , 0x403c44ad , 0xe8f827cc , 0x1a9d7f93 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x5b21b03a , 0x1c07261a , 0xa93c9b5f , 0x7bb6518c , 0x3fa45fdb , 0x090cb444 , 0x855e7a72 , 0x21557fda ] )
+  , Mont::unsafe_make( [ 0x88ec9849 , 0xa7069820 , 0x11646d64 , 0xda3c90da , 0x831cee40 , 0xb29d8a1d , 0xd09a6f2a , 0x09cb83bc ] )
+  , Mont::unsafe_make( [ 0x4a8f452f , 0x52391c14 , 0x78d98d37 , 0x904d754f , 0x7678d311 , 0x83506780 , 0x5dfcabf1 , 0x1e691536 ] )
+  , Mont::unsafe_make( [ 0x7d38e228 , 0xea28a105 , 0xea282c2c , 0x0731a9c6 , 0x525a4ac2 , 0xadfb63f0 , 0x3b55b7dc , 0x1be99682 ] )
+  , Mont::unsafe_make( [ 0x61fbab4e , 0x921ea2aa , 0x68327975 , 0x423a0c50 , 0x58f60bec , 0xc3109cc7 , 0x56052563 , 0x22f1bd56 ] )
+  , Mont::unsafe_make( [ 0xd3c5faed , 0x4ab70138 , 0x633dbd75 , 0x5bb73069 , 0x0bfef36c , 0x9c8788a8 , 0x381cf7a8 , 0x1b177731 ] )
+  , Mont::unsafe_make( [ 0x6942445e , 0xa07088d2 , 0x22e663d2 , 0x697cb536 , 0x9173096d , 0x8c08061a , 0x69044b21 , 0x0eea44b0 ] )
+  , Mont::unsafe_make( [ 0x68f2e2e2 , 0xfc1241e7 , 0x8c52c7dd , 0x44e97eca , 0x267335f2 , 0xbfa88af6 , 0x8fc7f94b , 0x1b085dc7 ] )
+  , Mont::unsafe_make( [ 0x7a4d0f24 , 0xd939a7f9 , 0x1bff22c4 , 0x482f2ff3 , 0xcb3447a9 , 0x81ca71ce , 0x8d6b9dad , 0x00900ce4 ] )
+  , Mont::unsafe_make( [ 0x85fe42f8 , 0xd212ba25 , 0x5a96e7d1 , 0x13400b7e , 0x3a06fb6b , 0x870b9f5c , 0x42301bbe , 0x21f2e44b ] )
+  , Mont::unsafe_make( [ 0x5435ad56 , 0x7df05abb , 0x15ef5269 , 0xe39444a1 , 0x5f52f37e , 0x15de963e , 0xea738a9a , 0x03fa9d74 ] )
+  , Mont::unsafe_make( [ 0x9ee982aa , 0xb41e7df0 , 0x660579f7 , 0xd5841924 , 0xbfd5f268 , 0x3b4e1937 , 0x06e34cc0 , 0x0b64cf3c ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xd2f0d746 , 0xcb228cd4 , 0x17367fcc , 0xc8d2420b , 0xe78b00a6 , 0x2c5ad4f8 , 0xec5968f6 , 0x26d242b2 ] )
+  , Mont::unsafe_make( [ 0xe8d0f9b4 , 0x1aa99dff , 0x34303662 , 0x4135d2a2 , 0x6e2bfdb7 , 0x1304bf3c , 0x3edba096 , 0x0cc5a3fb ] )
+  , Mont::unsafe_make( [ 0x83500d0b , 0xc4310bc2 , 0xa772372f , 0xd73fa280 , 0xb598a29e , 0x72420544 , 0x35517d48 , 0x0f5e7b4e ] )
+  , Mont::unsafe_make( [ 0x8809b06b , 0xe9627169 , 0xa4bd2dc7 , 0x002e6466 , 0x5eac4a91 , 0x3fd179da , 0x85c2063d , 0x1afe27b9 ] )
+  , Mont::unsafe_make( [ 0x06263d19 , 0xb92e62cd , 0xd2d7c945 , 0x5ba195ee , 0xfc62f861 , 0xc825dc09 , 0x4d0ccd1b , 0x2ea52ab5 ] )
+  , Mont::unsafe_make( [ 0x3fa57521 , 0xa3145eda , 0xe2af4635 , 0x87d9b35b , 0x60a30e46 , 0x0a724986 , 0x5c1551fd , 0x104e1ce0 ] )
+  , Mont::unsafe_make( [ 0xa553ba02 , 0x3419b4a6 , 0xf4455fe8 , 0xfc47bcf3 , 0x53457116 , 0x0209a927 , 0xfdf73ba2 , 0x135a204d ] )
+  , Mont::unsafe_make( [ 0xa5fd415f , 0x610bc588 , 0x62313e0b , 0x54aa0265 , 0x234a6950 , 0x0494bea5 , 0x6c5a6cc3 , 0x10a993da ] )
+  , Mont::unsafe_make( [ 0x979ec867 , 0xbad54837 , 0xa2367622 , 0xae281f2f , 0x08de841e , 0x69d7bc6e , 0xe57ab6c7 , 0x25b62ffc ] )
+  , Mont::unsafe_make( [ 0x0597afa1 , 0xc3b85874 , 0x55eb31ff , 0x6178941c , 0xd69b448b , 0x9046758e , 0xf7324312 , 0x238a3d87 ] )
+  , Mont::unsafe_make( [ 0x3cacbbd4 , 0x513e259a , 0x7df5d881 , 0x46e906c6 , 0x7569491c , 0x75ed8ad2 , 0x450ecfe1 , 0x18772d43 ] )
+  , Mont::unsafe_make( [ 0x795921bd , 0x9cc6b754 , 0x66fd8277 , 0xe1d80af7 , 0xae8986f1 , 0xdc298098 , 0xcd381c3b , 0x13c2363f ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x8dccab53 , 0xd2ef6b44 , 0x0a3f7a99 , 0xad721fd1 , 0x8fe84ee0 , 0x33ccb7dd , 0x31eeabf9 , 0x24b922fd ] )
+  , Mont::unsafe_make( [ 0x7a184d13 , 0x7553d088 , 0x04542aaf , 0x002b9276 , 0xe072e7d4 , 0x54d4e3cb , 0x0f16a34c , 0x242e6470 ] )
+  , Mont::unsafe_make( [ 0x0c2ff96d , 0x2ace8a78 , 0x10ab61fb , 0xab9d63a7 , 0xfae6a6dc , 0x9bcfe56a , 0x522e8f08 , 0x217f7418 ] )
+  , Mont::unsafe_make( [ 0x01057aa7 , 0xd14398bf , 0x318dde4d , 0x9de286ac , 0x5c21d9eb , 0x3b17d54e , 0xbc9203ef , 0x0ce0548f ] )
+  , Mont::unsafe_make( [ 0x4ae15d85 , 0x06249ae4 , 0x69508739 , 0x7b335c18 , 0x1dc9f4f2 , 0xa2b6bb2d , 0x05456dca , 0x199174d5 ] )
+  , Mont::unsafe_make( [ 0xa4fc190d , 0xd1af1d3a , 0xa0cc44c3 , 0xeead4cdf , 0x9ffb7eca , 0x22192bb4 , 0x5468e06b , 0x0ab22e6e ] )
+  , Mont::unsafe_make( [ 0x895dd5f0 , 0x7f8dd2a8 , 0xc5ea4a9a , 0x6ec5878f , 0xaae8811c , 0x6cffbf8b , 0x2c8bf3fd , 0x0e470802 ] )
+  , Mont::unsafe_make( [ 0xf4e56df0 , 0x85d7a903 , 0xeaa1966e , 0x3277d9a7 , 0x6d09857e , 0xec5d8028 , 0xabae6741 , 0x2ccec272 ] )
+  , Mont::unsafe_make( [ 0x60450beb , 0x7ea71453 , 0xedb2596b , 0x3bcc25ae , 0x6417c7b1 , 0xb5b2291a , 0x69ca4faf , 0x05dadfdb ] )
+  , Mont::unsafe_make( [ 0x8b6f6460 , 0xdf5aa97a , 0x7566f2c9 , 0x65ac19bc , 0x26aa82ab , 0x5a955dcd , 0xc13074b5 , 0x1f37c201 ] )
+  , Mont::unsafe_make( [ 0x85424c1c , 0xe7d32ed4 , 0x34133c35 , 0xde163d4e , 0x3a0a9177 , 0xf42eaddb , 0x7d1b074d , 0x1e8af297 ] )
+  , Mont::unsafe_make( [ 0x580da22f , 0x77f7292b , 0xb4182505 , 0xc643c868 , 0x2737888a , 0x0ae8501e , 0x4c43850b , 0x1267173d ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x684a6e73 , 0x3ffbce80 , 0xfbd6f5f0 , 0x9ac1269f , 0x91a03b06 , 0x7f02627d , 0xfbaa2f51 , 0x2c72f002 ] )
+  , Mont::unsafe_make( [ 0xc872e92b , 0xd32a14bd , 0x5dfd1851 , 0x870bccfc , 0x3cb63150 , 0x7cca9ae6 , 0x1d1a5f26 , 0x1f4ad023 ] )
+  , Mont::unsafe_make( [ 0x1f690316 , 0xdb43978c , 0xe15a30bb , 0x9547546a , 0x888007f7 , 0xf6bf05e7 , 0xc477ae29 , 0x2354d896 ] )
+  , Mont::unsafe_make( [ 0x015e96d0 , 0xd31f3b02 , 0xfc76ea79 , 0xc6397de8 , 0x0497dd03 , 0xb220ee8a , 0x13ea95bf , 0x1607857b ] )
+  , Mont::unsafe_make( [ 0xc70b1188 , 0xb6ebd343 , 0xf06efe51 , 0xc2e0b5af , 0x3c7dd98a , 0x90713ecc , 0xe43dba86 , 0x2479ea2f ] )
+  , Mont::unsafe_make( [ 0xef9be126 , 0x8684d28e , 0x8d4322e5 , 0x578d8ceb , 0x5d58d9f5 , 0xb13a224d , 0x2b13fed1 , 0x0ee62160 ] )
+  , Mont::unsafe_make( [ 0xa99bda6e , 0xb8e441b0 , 0x8ef3d506 , 0x0fc17df3 , 0xf22c37cb , 0xb3a97301 , 0x95d720b7 , 0x1ab3c030 ] )
+  , Mont::unsafe_make( [ 0xd7635514 , 0x0187d252 , 0xb48e0ab9 , 0xc18f44a1 , 0xc40767ae , 0xabbe7d68 , 0x63d9d0fd , 0x010a789c ] )
+  , Mont::unsafe_make( [ 0x36d627f6 , 0x5aac660b , 0x66c0fae8 , 0x714c9a4b , 0x448c6520 , 0x1e52260c , 0xc09346d4 , 0x0e917cc1 ] )
+  , Mont::unsafe_make( [ 0x5d1d0333 , 0x05a6d2f5 , 0x17f17356 , 0x04353039 , 0xdacc5f4f , 0x4844f32a , 0xeed24aa7 , 0x03b1d7e1 ] )
+  , Mont::unsafe_make( [ 0x6bd9c7e8 , 0x199204df , 0xc482fb89 , 0xeeeb1266 , 0x02f5a255 , 0xa35b17f8 , 0xbdef6da3 , 0x1eadcc4f ] )
+  , Mont::unsafe_make( [ 0xb4451de5 , 0x717f0573 , 0xacd75e93 , 0x779a0620 , 0x3db23d29 , 0x246d7215 , 0x0017e9fe , 0x12831e8e ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x2c8f76f8 , 0x15a2dbda , 0xd9b3c79c , 0x6d9245a6 , 0xe3d3f728 , 0x7d2301e5 , 0x84fbf148 , 0x1281548b ] )
+  , Mont::unsafe_make( [ 0x84d6d70a , 0x5195b487 , 0xf4b97e24 , 0xcef3950f , 0x7907323e , 0x50765a39 , 0x752e5fd4 , 0x13def835 ] )
+  , Mont::unsafe_make( [ 0x35b69c4d , 0x6dd234d6 , 0x0247a56c , 0x3d905e84 , 0xb9633ae8 , 0x4cf9b352 , 0x101548e5 , 0x195d2209 ] )
+  , Mont::unsafe_make( [ 0x8e17c257 , 0x716e2dd8 , 0xc260404a , 0xfd6f18e2 , 0xfa77c9dd , 0x6bba2ba2 , 0xd65a8e4d , 0x26bb87e8 ] )
+  , Mont::unsafe_make( [ 0x5c5f2efe , 0x6d9937ca , 0xb4cad413 , 0x36d5ccde , 0x8c127ab3 , 0xafa8ed5d , 0x12c747cb , 0x20376d02 ] )
+  , Mont::unsafe_make( [ 0x2c03e785 , 0x230af88a , 0x1344ca62 , 0x61eabf08 , 0x17305711 , 0xf868c3cd , 0x294794ad , 0x1e95e3fe ] )
+  , Mont::unsafe_make( [ 0x8d8e2440 , 0xbc0b39f2 , 0xa3b72179 , 0x578048e4 , 0x3463eca6 , 0x4611b1b0 , 0x2233824d , 0x0de1847f ] )
+  , Mont::unsafe_make( [ 0x93f2529e , 0xd7f27ad1 , 0x53c002ba , 0xdd608758 , 0x5a2fa7e8 , 0xf59ce599 , 0x672b449b , 0x28b6d1e7 ] )
+  , Mont::unsafe_make( [ 0x3b3d97d0 , 0xae00177b , 0xea74b537 , 0xefcf7256 , 0xb11e1f3d , 0xefc1342d , 0x02b5972d , 0x08ee760f ] )
+  , Mont::unsafe_make( [ 0x073ba3c1 , 0x4184c5dd , 0x181b4c8d , 0xadf28fdb , 0x5e23b1be , 0xf78f5ebd , 0x58a39c4b , 0x07ebcc00 ] )
+  , Mont::unsafe_make( [ 0xe91924c0 , 0xf41694be , 0xc8f09013 , 0xd2a2c03d , 0xb0aeab07 , 0xfb3de403 , 0x0a34ac9d , 0x07cfa935 ] )
+  , Mont::unsafe_make( [ 0x13da460e , 0x814f0d12 , 0x3cd70c36 , 0xa1995390 , 0x100361ac , 0x307a18e5 , 0x20c4c2ca , 0x0479ae27 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xece76d1f , 0xbf14b0b4 , 0x2377018e , 0x78afac0f , 0x5ab527be , 0x36c769fc , 0x84f58d89 , 0x18f67efa ] )
+  , Mont::unsafe_make( [ 0x683252b3 , 0x512d8158 , 0x021affc4 , 0x0ac79e9e , 0x441015d9 , 0xbfda245f , 0x7978fc7a , 0x0018a4ec ] )
+  , Mont::unsafe_make( [ 0xd73096c6 , 0x005cd496 , 0xdd6b00e0 , 0xbdc4a1e1 , 0x5c00d0b7 , 0x72d177cc , 0xe01ed3ba , 0x05f86703 ] )
+  , Mont::unsafe_make( [ 0xcbd5ea06 , 0x584d4ce2 , 0x53e4b32d , 0x9a788004 , 0xb3151f13 , 0xf0de3407 , 0x772023f4 , 0x21c21028 ] )
+  , Mont::unsafe_make( [ 0x2d0e719a , 0x6e96948b , 0xec773824 , 0xcd46b0bc , 0xedabc420 , 0xfcce21e5 , 0x84d7b8c3 , 0x2e4459ab ] )
+  , Mont::unsafe_make( [ 0x9457fdc3 , 0xd833c1c0 , 0xb6cf8d0e , 0x7c65c11d , 0xa7985150 , 0x1e6896f3 , 0xe01fbe63 , 0x179f405a ] )
+  , Mont::unsafe_make( [ 0xb3fd89ac , 0x2789d2f3 , 0xe592d21f , 0xc2a89665 , 0x45c1f33d , 0xecd76aec , 0x43bd367b , 0x053c9c0d ] )
+  , Mont::unsafe_make( [ 0x1d71e924 , 0xe5989863 , 0x37f2f41b , 0x7441d863 , 0x82caace7 , 0x317fa9d4 , 0xaf93dffd , 0x227977ec ] )
+  , Mont::unsafe_make( [ 0xa8ae5a4c , 0x4766f3fc , 0x28281d0f , 0x8c9cc5a6 , 0x20e82cab , 0x7f73aef4 , 0xc9528385 , 0x25a80adf ] )
+  , Mont::unsafe_make( [ 0xfe5f8811 , 0x6134e73d , 0x555690c5 , 0xe1ecd5fe , 0x4b52f774 , 0x1ace95a0 , 0x8d7982c0 , 0x21fd521d ] )
+  , Mont::unsafe_make( [ 0xf9c48e62 , 0x86489a7e , 0x7d973f7f , 0x9f17685a , 0x1218e7a3 , 0xfe11e83b , 0xadd9acff , 0x1f3e23ea ] )
+  , Mont::unsafe_make( [ 0xfe95e8ba , 0xccfa4f37 , 0xe6df9569 , 0xe9d536d0 , 0x50aa5211 , 0xd7c14346 , 0x97e8664c , 0x238e2fb4 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x7791f014 , 0xd1f4db47 , 0xa469df89 , 0xf4c3aae3 , 0x7dc5e349 , 0xda00ddd0 , 0x8a869a37 , 0x2d685c52 ] )
+  , Mont::unsafe_make( [ 0x5096fb71 , 0xdf8113ef , 0x7fcdbe9f , 0x97ee24f0 , 0xb3d7e53b , 0xfd2e7726 , 0x3885128d , 0x20e9bc4b ] )
+  , Mont::unsafe_make( [ 0xb42ef180 , 0x8f5bdd20 , 0x63f8cf11 , 0xfd8452b1 , 0x514fcd3e , 0x3d5bc9e2 , 0xf9133018 , 0x05eea13b ] )
+  , Mont::unsafe_make( [ 0x09f0f0b3 , 0xd8240f35 , 0xe8bbfadb , 0x9ee96eb3 , 0x777d5a8b , 0x60567611 , 0x794ec4af , 0x0a7d40dd ] )
+  , Mont::unsafe_make( [ 0x7e9c9996 , 0x95e4c4d2 , 0xf6d593a5 , 0xc2a80dce , 0xd24cbfa5 , 0xb51db40d , 0x3fc7583a , 0x195e10cd ] )
+  , Mont::unsafe_make( [ 0xfb8940c6 , 0x8e2ae9cb , 0x14fdd3e7 , 0x3ee90673 , 0x53d130da , 0xf237efe8 , 0x22504cb6 , 0x252c3a87 ] )
+  , Mont::unsafe_make( [ 0x5cab95d7 , 0xd4beb880 , 0xa4215a26 , 0xf94c0930 , 0x0f1cf2b7 , 0xe9a5058a , 0x9ccfbb30 , 0x08d8c680 ] )
+  , Mont::unsafe_make( [ 0x1a9e8468 , 0x8fd28027 , 0x16a0fcff , 0x7cef7b23 , 0x4caea816 , 0xada86ff1 , 0xaef58cf6 , 0x0430cb7c ] )
+  , Mont::unsafe_make( [ 0x626ea94e , 0x4313d7ef , 0x750d9588 , 0x14f6c7a4 , 0xab0005c7 , 0x678e1fb0 , 0xf407b5ed , 0x00fe0f0d ] )
+  , Mont::unsafe_make( [ 0x3f23e4d2 , 0x2de69dd6 , 0xe129cff7 , 0x913173b0 , 0xf1864e5b , 0x48a36f9a , 0xb7395e6d , 0x14397752 ] )
+  , Mont::unsafe_make( [ 0xff824ce5 , 0x4eb062eb , 0x3ac85034 , 0x04b45de9 , 0x51fcbe57 , 0xf681eec4 , 0xe63ec980 , 0x03492d01 ] )
+  , Mont::unsafe_make( [ 0x2d0a8959 , 0x375a64f7 , 0x408f3815 , 0xc0ea88f6 , 0x23e48747 , 0x090de812 , 0xc7c28f81 , 0x2b65a116 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x93189d8e , 0xb5b6427b , 0x93b88756 , 0xfff7603a , 0x30eb7cd1 , 0xd189453c , 0xcdeb8e81 , 0x1558ce0c ] )
+  , Mont::unsafe_make( [ 0xb476d3dc , 0x4a495907 , 0xdc669b6f , 0xf13ef94e , 0x2fbd7f75 , 0x80dba7ba , 0xd8f12f99 , 0x01009de3 ] )
+  , Mont::unsafe_make( [ 0xeb721739 , 0x9775c703 , 0x0c9cfc6c , 0x5e775040 , 0x8aefb55b , 0x2f51f6e4 , 0x2c2984ba , 0x03241095 ] )
+  , Mont::unsafe_make( [ 0xb5ea9979 , 0xb0da0315 , 0x23a320b4 , 0x39d38829 , 0x06638403 , 0x2560dc52 , 0x4d2f4c30 , 0x22f7c1a9 ] )
+  , Mont::unsafe_make( [ 0x18c352e4 , 0x34c29b67 , 0x3bb9f72e , 0xe077f760 , 0x8704604c , 0x4f1af956 , 0x19d50c94 , 0x01d6cab7 ] )
+  , Mont::unsafe_make( [ 0xf88b2f59 , 0xefcacadb , 0xb814098e , 0x2b599540 , 0xc24d5bf0 , 0x9234e23a , 0xf8f4e74e , 0x2836b973 ] )
+  , Mont::unsafe_make( [ 0xdb06a2a9 , 0xd8e92daf , 0xeba56427 , 0x9f1e0068 , 0x49155e6d , 0x971515d4 , 0xd07eb7fc , 0x240e63f4 ] )
+  , Mont::unsafe_make( [ 0xb310f2d2 , 0xa8f05afb , 0xd99a6ade , 0x74029fbe , 0xd0bd703e , 0x99c7e49b , 0x53e47522 , 0x290ac574 ] )
+  , Mont::unsafe_make( [ 0x6eb84f70 , 0xa2c80a45 , 0xcf448009 , 0x130d0d3a , 0x86a9b4bd , 0x62e52264 , 0xb3868449 , 0x238a696d ] )
+  , Mont::unsafe_make( [ 0x5ed27445 , 0x368757ff , 0xa6f3cf91 , 0xaf434201 , 0xcda43bf9 , 0x3745a48f , 0x8b5bf6b5 , 0x1f41d6d0 ] )
+  , Mont::unsafe_make( [ 0xcef43abf , 0x5112f875 , 0xb2d51393 , 0x27c2ef04 , 0x796a1e99 , 0xb5f7bff1 , 0x842ec99a , 0x10dd0d61 ] )
+  , Mont::unsafe_make( [ 0x97303045 , 0xcb3a4ffc , 0x115d22cb , 0x46cc9909 , 0x6bcecec3 , 0x7a392edd , 0x0a8e6144 , 0x2663a19d ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xd698286f , 0x3050ff84 , 0x640ad142 , 0xab5811d9 , 0x23072b2f , 0x21f10f98 , 0x21b38474 , 0x27f97bca ] )
+  , Mont::unsafe_make( [ 0x3ce41069 , 0x22f9934d , 0xa506bb9b , 0x9b14a014 , 0xe5c88f41 , 0x02ebaf47 , 0x89482e00 , 0x0424f366 ] )
+  , Mont::unsafe_make( [ 0x6e076226 , 0x30bf4e7d , 0x63e141b3 , 0x0d33ab09 , 0xcfaf61ef , 0xf2acb6ae , 0x0c875c50 , 0x0ec158a2 ] )
+  , Mont::unsafe_make( [ 0x27122cb0 , 0x18ccc96d , 0xcb0f43d1 , 0xf96d9d32 , 0x643fc292 , 0x4999eaf2 , 0xae791451 , 0x21a18173 ] )
+  , Mont::unsafe_make( [ 0x615627a4 , 0xee3e7d05 , 0x22b815d6 , 0x590b434e , 0xde01f365 , 0xd7233d94 , 0xf0456279 , 0x2050242d ] )
+  , Mont::unsafe_make( [ 0x7c021c3c , 0x2b4da72d , 0x85dd6834 , 0xac68b227 , 0x2b4d8723 , 0x453ea146 , 0x6c820504 , 0x2cb6cc65 ] )
+  , Mont::unsafe_make( [ 0xb9a7641b , 0x7d9b6a93 , 0xb05dca3e , 0x911adbc9 , 0x84b3385b , 0x061b7899 , 0x30429b92 , 0x28bae591 ] )
+  , Mont::unsafe_make( [ 0xdcb7596e , 0xa6e7bc84 , 0x7c61dade , 0x4c9d2fbe , 0x5ea355a2 , 0x0f5546a3 , 0x8d65d4d7 , 0x2b418b30 ] )
+  , Mont::unsafe_make( [ 0xc469a538 , 0x1432d3fb , 0x3102b1d4 , 0x9e85693c , 0xc77043b7 , 0x089eb117 , 0xc215438a , 0x05b5e448 ] )
+  , Mont::unsafe_make( [ 0x2584d469 , 0xeeebfdc6 , 0x12b23904 , 0x8542d604 , 0x2ca70cf2 , 0x5cc2a95b , 0x77c317b6 , 0x2320d5e0 ] )
+  , Mont::unsafe_make( [ 0x6d5c8558 , 0x4d787bdf , 0xa740217e , 0xd36f5505 , 0x18789599 , 0x3d05f742 , 0x845bc801 , 0x26f95ae8 ] )
+  , Mont::unsafe_make( [ 0x0f3fa694 , 0x34df8552 , 0x63c41d74 , 0x8ab094a5 , 0xfe8b3cfd , 0xbc6fad90 , 0x44268aa6 , 0x0dd6c533 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xac1d544a , 0xa63c98f7 , 0x6ab9d0e5 , 0x33a511a1 , 0x438f1638 , 0x10da899c , 0x8e06f52b , 0x14767ccc ] )
+  , Mont::unsafe_make( [ 0x52e1d477 , 0x4f5dd6c7 , 0x54d22310 , 0xdb004a56 , 0x1841cf1a , 0x573137f4 , 0xe33a09cf , 0x110f9cf6 ] )
+  , Mont::unsafe_make( [ 0xf9238687 , 0x2517654b , 0x1abba13c , 0xfba520e8 , 0xc2055a8f , 0xbb4fac6b , 0xcdcbfdbf , 0x175c8b89 ] )
+  , Mont::unsafe_make( [ 0xf241a71a , 0x182d9c64 , 0x11550acd , 0x779a6dd1 , 0x99692562 , 0x20571205 , 0x913494e0 , 0x1258ed0e ] )
+  , Mont::unsafe_make( [ 0xfa1d84bb , 0x9a22e8dc , 0x64046ee2 , 0xe3f1bc3a , 0x68eb2ee0 , 0x508b4d29 , 0x1b633a1b , 0x0d18a04c ] )
+  , Mont::unsafe_make( [ 0x987748c0 , 0xc0c17d22 , 0x6b680e8b , 0x74e6235a , 0x9e44667f , 0xc1da8e25 , 0xfd09bdd2 , 0x0e4be05a ] )
+  , Mont::unsafe_make( [ 0x962ba61c , 0x59443fec , 0x8b750f52 , 0xb7549e4e , 0xa6324d18 , 0x09b2fec1 , 0x8a86e4c7 , 0x1ee3342b ] )
+  , Mont::unsafe_make( [ 0x2da49fe8 , 0xc9a4fea5 , 0x215e6a5c , 0x9bc77c9e , 0x3e57b7df , 0xfae949c1 , 0x368151b2 , 0x1fd24eb1 ] )
+  , Mont::unsafe_make( [ 0x6615d4f7 , 0xc57b4854 , 0xb9b07b2b , 0x452d6045 , 0x67929866 , 0x38819ec6 , 0xbc39ab4d , 0x0df96dd6 ] )
+  , Mont::unsafe_make( [ 0xefe7b449 , 0xef6840fe , 0xdf190b01 , 0x7bb04e99 , 0x13098522 , 0xb740165f , 0xef2149b7 , 0x1663c532 ] )
+  , Mont::unsafe_make( [ 0xd5fde376 , 0xc95b2866 , 0xd0d41af4 , 0x37a1836e , 0x7821e184 , 0xa8075e5d , 0x503292ac , 0x09c8fcdc ] )
+  , Mont::unsafe_make( [ 0xcac844f8 , 0xf6dc68f5 , 0x837631a2 , 0xeb0ae431 , 0x59c0e8f3 , 0x279571ca , 0x0271f890 , 0x302e7ef5 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xfdc2fd4f , 0x32e142e7 , 0x1f246e90 , 0x77294576 , 0x1f90fa4c , 0x57be2290 , 0xdca39a8e , 0x1e5596fa ] )
+  , Mont::unsafe_make( [ 0xaff1c06a , 0x84a34d69 , 0x7dd91769 , 0x87218fc0 , 0xdac92323 , 0x24739614 , 0x530a5e18 , 0x01b1ca17 ] )
+  , Mont::unsafe_make( [ 0xfc6df8ee , 0x07c4f714 , 0x787ba6e6 , 0xd3e689e0 , 0x4ce22e16 , 0x96d083c1 , 0x65494bae , 0x2755b6fe ] )
+  , Mont::unsafe_make( [ 0x8dfdded6 , 0x8be4512c , 0x00c8c862 , 0x79b9f59c , 0x36550825 , 0x7c0217e2 , 0xdb1d6956 , 0x204ea5c6 ] )
+  , Mont::unsafe_make( [ 0x5d0f5a45 , 0x77102b81 , 0x4402b0cf , 0xd804277a , 0x6983d603 , 0x6a59593e , 0x6ce081e9 , 0x23d6712c ] )
+  , Mont::unsafe_make( [ 0x34430229 , 0x239563bc , 0x30c21bee , 0x922be19f , 0x73ab6eb0 , 0xb9af3ff8 , 0x25e13307 , 0x20498ceb ] )
+  , Mont::unsafe_make( [ 0xb6e6a1ca , 0xea65ac4f , 0x2983e116 , 0xeedeb6f9 , 0x986ba574 , 0xe2f3660f , 0xb20ed3c0 , 0x0e147b65 ] )
+  , Mont::unsafe_make( [ 0x1f88c568 , 0xd48dc076 , 0x05c99bf7 , 0xff06ad0e , 0x7fa9568e , 0x2a2b3849 , 0x6252027a , 0x2dcc75c3 ] )
+  , Mont::unsafe_make( [ 0x6a35291e , 0xbbefa111 , 0x623c2201 , 0x765a7b60 , 0x3ea2211a , 0x68abb539 , 0xd01ba20f , 0x295fb1fa ] )
+  , Mont::unsafe_make( [ 0x72a06f23 , 0x22dd4462 , 0xb4ebb389 , 0x9281274b , 0x46b3bbf5 , 0xf23432d6 , 0x40364ac9 , 0x2e255db3 ] )
+  , Mont::unsafe_make( [ 0x64fd00a8 , 0x7d61a19c , 0x7b02d52b , 0x63246dc4 , 0xe7e5e730 , 0x40e7e05b , 0x552902db , 0x19097ad7 ] )
+  , Mont::unsafe_make( [ 0x1c36eff1 , 0x8127d985 , 0x774f8ef3 , 0xd6db46b9 , 0x060f7065 , 0x004a8e6b , 0xce9188f1 , 0x065ff541 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x810b8e13 , 0xe6c7bc96 , 0x22771696 , 0x63197a08 , 0x3db03055 , 0x38a9415d , 0x592e3da2 , 0x2984e2ff ] )
+  , Mont::unsafe_make( [ 0x01b6afb6 , 0x46c2dd3e , 0x23495c97 , 0x06d209f4 , 0x14be54c8 , 0x93604f22 , 0x6f17eb33 , 0x02937ff6 ] )
+  , Mont::unsafe_make( [ 0x6730a216 , 0x2ab8e8c7 , 0x88617237 , 0x82f9597a , 0xef739e66 , 0x7bfa78b1 , 0x044b0b46 , 0x22435706 ] )
+  , Mont::unsafe_make( [ 0x56056ad7 , 0x57bf0d35 , 0x5739b83e , 0x6fcc1189 , 0x0c0d558c , 0x14eff1df , 0x57d64907 , 0x0308fb70 ] )
+  , Mont::unsafe_make( [ 0x44caf19a , 0xacaeeb2b , 0xa654fc1a , 0xabf2046a , 0x57ec5bcf , 0x6ff1c413 , 0xa51db7a9 , 0x0320d3fe ] )
+  , Mont::unsafe_make( [ 0x973faacc , 0xf9949ecc , 0x308fd0e8 , 0xe84d6073 , 0x0d7c08e3 , 0x4fcfef12 , 0xb0850dd6 , 0x0c41d37f ] )
+  , Mont::unsafe_make( [ 0x4b61fc0e , 0xc0a4560c , 0xac524863 , 0xe4110320 , 0x97d12c13 , 0x5c318717 , 0xf4876cd2 , 0x2876bd7c ] )
+  , Mont::unsafe_make( [ 0xdbd3d94c , 0x21e6825e , 0x1c673e1f , 0xe187d25c , 0x82aca731 , 0xd60b8a5f , 0x377f9b61 , 0x2ad61bd4 ] )
+  , Mont::unsafe_make( [ 0x2d36ca51 , 0x645ce9ea , 0x9eb8e9be , 0x9c1cc2be , 0xfcec27fd , 0x04e3d4c1 , 0x9303b795 , 0x288f0a69 ] )
+  , Mont::unsafe_make( [ 0x9c9e9452 , 0x836cff97 , 0x343da62a , 0x0885bd10 , 0x99ed38b6 , 0xbec5647c , 0xa23c87e3 , 0x2cbdda37 ] )
+  , Mont::unsafe_make( [ 0x51bd69b9 , 0xf930ece1 , 0xb393bc1e , 0xe4602d09 , 0x4170edec , 0xb15824fc , 0x88db85c9 , 0x03779523 ] )
+  , Mont::unsafe_make( [ 0x655ca511 , 0x1e649d20 , 0xa1ebb990 , 0x5de6938c , 0x52780e0c , 0x7ce5bc2f , 0xd359cf21 , 0x12d92d44 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x00282b7c , 0xeea7d394 , 0x02ceb823 , 0xf4a6936a , 0xf292c19c , 0x95555640 , 0x59c9e3be , 0x2ad7fa86 ] )
+  , Mont::unsafe_make( [ 0x7467a76c , 0x47d9f137 , 0x8cef0480 , 0xf4cca5ac , 0x5a5431da , 0xa835132c , 0xba4604e0 , 0x1957774e ] )
+  , Mont::unsafe_make( [ 0x48ab0c78 , 0xf498c06f , 0x88792204 , 0x4dc56a5f , 0x696faba8 , 0xdb827aca , 0x49613619 , 0x26821795 ] )
+  , Mont::unsafe_make( [ 0x2d89c886 , 0xd985c4f1 , 0xd0328adb , 0x439cddcc , 0x80570adb , 0x400749c5 , 0x965fb790 , 0x05c0481d ] )
+  , Mont::unsafe_make( [ 0x4a7672a6 , 0x486079e2 , 0x511fe2f1 , 0xa89a31bc , 0x2a1bea5a , 0xa1d07423 , 0x34d5ccec , 0x104ef940 ] )
+  , Mont::unsafe_make( [ 0xc2521082 , 0xe3eeebf4 , 0xc00e8932 , 0x13240350 , 0x99eca118 , 0x7594ca5a , 0xf5bd8e62 , 0x0bcef3cf ] )
+  , Mont::unsafe_make( [ 0x87fad9a7 , 0x6f0ce2a9 , 0xbff01de7 , 0x0cd46c6b , 0xaafdff4f , 0x7da4b0c7 , 0x510c58a8 , 0x003cc7fd ] )
+  , Mont::unsafe_make( [ 0xb676f795 , 0xffd97c28 , 0xe0aabb8a , 0x50f1eb5e , 0xee7899b3 , 0x7072e94a , 0x6545b5f1 , 0x2a8712df ] )
+  , Mont::unsafe_make( [ 0xb475e65f , 0x3366fdd4 , 0xc6d7f525 , 0x56bc5d49 , 0x8169e630 , 0x4c0e881a , 0xc42baf1d , 0x2d3d155e ] )
+  , Mont::unsafe_make( [ 0x7c6598a3 , 0xefbc5343 , 0xf45179ff , 0xa69d3652 , 0x3f79867c , 0xbcf2b579 , 0x8c914e57 , 0x1807f8aa ] )
+  , Mont::unsafe_make( [ 0xdf20a5f3 , 0x2eb297ab , 0x967d0ad1 , 0x911cb51c , 0xdbfc4c81 , 0x9c7b62e9 , 0xd73504fc , 0x039c9908 ] )
+  , Mont::unsafe_make( [ 0x08122d9c , 0xb16ea108 , 0x3fcc26e6 , 0x53791776 , 0x83f04949 , 0xa4b6e6b1 , 0x5c5bf5d1 , 0x237f8a24 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xa81717f5 , 0x72785ca8 , 0xb87d881c , 0x5b67e80d , 0x0ee4d8bc , 0x18e3c9c3 , 0x036e2347 , 0x17fff3c3 ] )
+  , Mont::unsafe_make( [ 0xf3c712c5 , 0xe745b545 , 0xa15b65bd , 0x72a6eb57 , 0xe7e958fa , 0xf712af92 , 0xa0f4b234 , 0x1436491c ] )
+  , Mont::unsafe_make( [ 0x4e3cd442 , 0xc15bd927 , 0x8a9739e4 , 0x73baedd0 , 0x09e99aff , 0x702535ca , 0x6e04b861 , 0x0987dc71 ] )
+  , Mont::unsafe_make( [ 0xa33e2407 , 0x377886bf , 0xbf0af360 , 0xb6e792f5 , 0x2bc8e39b , 0x97c57a81 , 0x8ca0c098 , 0x2735b0d5 ] )
+  , Mont::unsafe_make( [ 0x4dbf246d , 0x770a65c2 , 0x2473c34c , 0x1533686f , 0x0d5ecab2 , 0xa8539400 , 0x69ccef3b , 0x03676747 ] )
+  , Mont::unsafe_make( [ 0xbeeb510e , 0x786beda4 , 0xfd745e60 , 0x1bd1b8f5 , 0x98cfa6ff , 0xa8bead56 , 0xb6cec198 , 0x0b0130bd ] )
+  , Mont::unsafe_make( [ 0xe15cb726 , 0x91c8a771 , 0xbbd2deb3 , 0xa82a326e , 0xf2b59326 , 0x88054c6c , 0xf8b69430 , 0x29d882d8 ] )
+  , Mont::unsafe_make( [ 0x8cd8a91c , 0xbaba4778 , 0x009d41af , 0x8b7bab4c , 0x944f73f9 , 0x36356c2e , 0x51753c89 , 0x27c7b20c ] )
+  , Mont::unsafe_make( [ 0xde1d79ac , 0xfa2a0e28 , 0xe95a3525 , 0xcb152660 , 0x145d25b6 , 0x07979eea , 0xfab1b4b9 , 0x1a251e57 ] )
+  , Mont::unsafe_make( [ 0x472b5623 , 0x7402e9ff , 0xd5f5b49e , 0xf646a828 , 0x5c389d9d , 0x7b4882f5 , 0x77794b8e , 0x27db18c0 ] )
+  , Mont::unsafe_make( [ 0x3da2caf5 , 0x2f1e3f16 , 0xbf50052a , 0xaf22b093 , 0xa6568ba8 , 0xdd2e70cc , 0x349b5ef0 , 0x1f38dadc ] )
+  , Mont::unsafe_make( [ 0x855fd4c2 , 0x39328fd5 , 0xc62d0ea2 , 0xeecf80e3 , 0x2f752639 , 0xd94bf412 , 0x10d67dd7 , 0x07f9344c ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xa09aca00 , 0xb85bb5df , 0x838322a7 , 0xcfb26e50 , 0x43554b81 , 0x53ffd488 , 0x246817ec , 0x08289ade ] )
+  , Mont::unsafe_make( [ 0xe05ed553 , 0x0d648ee5 , 0x62a0b6ec , 0xe5cdb3b9 , 0x8eefe10f , 0xcd8676b4 , 0x30c22822 , 0x013ecf9c ] )
+  , Mont::unsafe_make( [ 0xb14794e2 , 0x6fd6db41 , 0xc8bfbb84 , 0xfa759f1e , 0x7474fc20 , 0xfef089fb , 0x8ce50db9 , 0x2fbb70ff ] )
+  , Mont::unsafe_make( [ 0x574b5269 , 0x2e00d6ec , 0x2ff4c5ba , 0x148e590c , 0x61e110ff , 0x90557fb3 , 0x81e535a0 , 0x09642953 ] )
+  , Mont::unsafe_make( [ 0xae7161c3 , 0x51cd2c2c , 0xc6583796 , 0x3f183b22 , 0x5fab7722 , 0xc68c8047 , 0x371b4b1a , 0x0907531b ] )
+  , Mont::unsafe_make( [ 0x82128c0d , 0x66cb884d , 0xb7aad075 , 0x485d4428 , 0x5371f89a , 0x741d29fb , 0x15933681 , 0x00833caf ] )
+  , Mont::unsafe_make( [ 0x139272c8 , 0xe61a1781 , 0x10779c8d , 0x5c12a293 , 0x81d55f53 , 0xf4dc8ded , 0x07cbb99f , 0x0753a37d ] )
+  , Mont::unsafe_make( [ 0x91d36540 , 0xe596e668 , 0xe5b956f6 , 0xe6e5d230 , 0x969fd054 , 0x258f6194 , 0xd11e6600 , 0x2abf9c36 ] )
+  , Mont::unsafe_make( [ 0x9f367c00 , 0x59cde53e , 0x126618ee , 0x02ffd502 , 0xdfe5cbe9 , 0xd2013e3f , 0x99349f43 , 0x1158052e ] )
+  , Mont::unsafe_make( [ 0xa32eeabf , 0x305915c3 , 0xdf74a860 , 0xf8cf3ab3 , 0xc3b32d93 , 0x0356c5ed , 0x83ebe401 , 0x0af3a95d ] )
+  , Mont::unsafe_make( [ 0x79ec9e34 , 0x1209186d , 0x8ce99680 , 0x009e5886 , 0x429e2713 , 0x8ec179dd , 0xcf64aac7 , 0x29662327 ] )
+  , Mont::unsafe_make( [ 0xf151c66c , 0x136102ee , 0x6e0235ac , 0x662c1b51 , 0xf459e9c1 , 0xd287b95e , 0x4dadb2cc , 0x1c3c8e00 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x2c5170f3 , 0x505c185e , 0xebbca946 , 0x0f54ef6a , 0xaee3e1f2 , 0x58ffac5c , 0xae325784 , 0x1036a654 ] )
+  , Mont::unsafe_make( [ 0x74cfdb56 , 0x5695d441 , 0x4a7cd469 , 0x07ce86cb , 0xe9a4e457 , 0x00fbb8cf , 0x8266ce2a , 0x0b462689 ] )
+  , Mont::unsafe_make( [ 0xe62e3cda , 0xaa1c4236 , 0xfb9cd60b , 0x7f69cc96 , 0xdcb6ed34 , 0x27aa76ac , 0xdf0a804d , 0x09ac69c6 ] )
+  , Mont::unsafe_make( [ 0xb306a1cb , 0x0dcecee4 , 0x714c78f2 , 0x9ca008fa , 0x1cee8f04 , 0x65201a24 , 0xb0ffce0a , 0x15d04697 ] )
+  , Mont::unsafe_make( [ 0x74e9b3d5 , 0xab35ac09 , 0xada1383a , 0xd3aee02e , 0xbc76821a , 0x46e5d2f2 , 0x72afa901 , 0x273b2d91 ] )
+  , Mont::unsafe_make( [ 0x094fc52d , 0xaf95c006 , 0x866566de , 0x005e3a43 , 0xc436e46f , 0x5bc6a0da , 0x5253ae79 , 0x142839ad ] )
+  , Mont::unsafe_make( [ 0xc85626ff , 0x51cda609 , 0x266b4b8b , 0x52c8e250 , 0xe725fad4 , 0x07d08c00 , 0xba53643e , 0x0c18076c ] )
+  , Mont::unsafe_make( [ 0x24c32668 , 0x4d552d4d , 0xa786a936 , 0x0bc5d440 , 0x048fa28b , 0x57c926ee , 0x453c5c6c , 0x2e3dd573 ] )
+  , Mont::unsafe_make( [ 0x5aba2528 , 0x12b8b339 , 0x908cc3a8 , 0x9eb1b2e2 , 0x07fdfb35 , 0x1c2dc8cb , 0x1fcd28b4 , 0x041106b8 ] )
+  , Mont::unsafe_make( [ 0x7a0fe27e , 0x1fc5083e , 0xdfe96f70 , 0x4e94b5d9 , 0x984f65a2 , 0xcdb08fb2 , 0x3e5f4a37 , 0x290600d4 ] )
+  , Mont::unsafe_make( [ 0x7bc3e827 , 0xabe6bb0a , 0xe0287ad4 , 0x601a0e2d , 0xed84b0e0 , 0xa671d302 , 0x50ee9594 , 0x0888ec47 ] )
+  , Mont::unsafe_make( [ 0xc1b44f7a , 0x491a366c , 0x12d6228a , 0xc812a75c , 0x44f5fb8a , 0x834e6e19 , 0xb5426c75 , 0x2c676fca ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x871fabbd , 0xd958d151 , 0xb36c70a6 , 0x8ff00cc9 , 0x94ff0a85 , 0xfd8ead90 , 0x996f24a2 , 0x20494cbc ] )
+  , Mont::unsafe_make( [ 0xf863877b , 0xcb6ed39d , 0x3895dc8b , 0x3e0523e8 , 0x4da3f9f9 , 0x3c0ecda0 , 0x18f3e484 , 0x2dcf391b ] )
+  , Mont::unsafe_make( [ 0x95d10921 , 0x5662bf4d , 0xe9ed16b4 , 0x3eff93da , 0x6d16c505 , 0xe737326d , 0x4db5385d , 0x2df0bc87 ] )
+  , Mont::unsafe_make( [ 0xa80f995b , 0x2bc077b2 , 0x15b9a3f7 , 0x92e746d7 , 0xfe738f75 , 0xfa824e78 , 0xed903794 , 0x026c629b ] )
+  , Mont::unsafe_make( [ 0x0db8d539 , 0xd717bedf , 0x4358f4cb , 0x8c2f3a02 , 0x1fe19122 , 0xd0f241ce , 0x6d8db3b9 , 0x274dd253 ] )
+  , Mont::unsafe_make( [ 0xed476381 , 0x28db9176 , 0x15583b26 , 0x06105520 , 0xdfc027fe , 0x365a1fca , 0xd0020fba , 0x2782eab1 ] )
+  , Mont::unsafe_make( [ 0xbc03b417 , 0x71812560 , 0x68554390 , 0x3303c488 , 0x37627a34 , 0xa278d3ee , 0x05cea346 , 0x25883362 ] )
+  , Mont::unsafe_make( [ 0x287d1e90 , 0x66fa9d33 , 0xc81ea34c , 0x7a576406 , 0x5f43f545 , 0x14cd9451 , 0xa86e928c , 0x2d651062 ] )
+  , Mont::unsafe_make( [ 0x69da766b , 0x226192e9 , 0x1a59a4ea , 0x9d63360a , 0xce7b9de7 , 0xb012604e , 0x32a7faa5 , 0x1c02503b ] )
+  , Mont::unsafe_make( [ 0xc374afd9 , 0x3932078f , 0x30dca83b , 0x7e67885d , 0xa43ea3dc , 0x2038f687 , 0x216531be , 0x2e54c108 ] )
+  , Mont::unsafe_make( [ 0xb473c131 , 0xe3635747 , 0x088e362c , 0x25009cdd , 0x9e93456a , 0x53f86ba1 , 0x92362068 , 0x1da721ce ] )
+  , Mont::unsafe_make( [ 0x08aa29ef , 0xb1a8f319 , 0x4ef03b49 , 0x0ca13e77 , 0x45befa38 , 0xd16f41fe , 0x869996b9 , 0x2b64f4a2 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x6c69e9e9 , 0x9767f448 , 0x67b40f8e , 0x5e240276 , 0x2b63b2ff , 0x1a0c2f8e , 0x7939f669 , 0x0e07bcfb ] )
+  , Mont::unsafe_make( [ 0x3462c15d , 0x57e5e995 , 0x65c32efd , 0x5a9bc48f , 0x2adf6707 , 0x18ec4381 , 0xb5005f99 , 0x17a18eee ] )
+  , Mont::unsafe_make( [ 0x53dcaa58 , 0x1e5fa685 , 0x10f95f26 , 0x40a14f46 , 0x638a27ad , 0x13e62585 , 0x89bafc9c , 0x189ea5e8 ] )
+  , Mont::unsafe_make( [ 0x5c258ffc , 0xc5e9c026 , 0x70f8d8d5 , 0x6edc921e , 0x1a342f4c , 0xeb85d02b , 0xbf894a50 , 0x1ecdf60d ] )
+  , Mont::unsafe_make( [ 0x140fafc1 , 0x62153135 , 0x6ab9f852 , 0x779d5753 , 0x6410ce7e , 0x49a32925 , 0x20f19e7a , 0x11e1671a ] )
+  , Mont::unsafe_make( [ 0x1a6ece01 , 0x6d1fc798 , 0x562b9d6b , 0xe3ac7fd7 , 0x81a65369 , 0x4c3cf5af , 0x4ecf4228 , 0x13e4ea04 ] )
+  , Mont::unsafe_make( [ 0x129f49dd , 0x767dadb1 , 0x8fd88f2f , 0x66a72e42 , 0xdc47f6e7 , 0xa2962f5e , 0xf0db29c4 , 0x2e1405f7 ] )
+  , Mont::unsafe_make( [ 0xdea73867 , 0xa077b112 , 0x1da39571 , 0xbc901258 , 0x814ea280 , 0xee06da66 , 0x4c85a36c , 0x1f23bf31 ] )
+  , Mont::unsafe_make( [ 0x6b78412d , 0xc60e5723 , 0xe11b11cc , 0x5c75b82d , 0xdd119a85 , 0x37f52b51 , 0xb01bacf9 , 0x25ca7cfd ] )
+  , Mont::unsafe_make( [ 0x312c5a18 , 0x4543a281 , 0xc7eb1b8b , 0xe54cbbf7 , 0xc7d0b399 , 0x982fb0f6 , 0x44ba03cc , 0x24ddc83b ] )
+  , Mont::unsafe_make( [ 0x6684d571 , 0xebc04545 , 0x1543d806 , 0x8aa81379 , 0x03e51eff , 0x412b203f , 0x34342e99 , 0x080f6fd7 ] )
+  , Mont::unsafe_make( [ 0x29c5e7b1 , 0x3eab83a8 , 0x2a00f0d8 , 0x2cf34078 , 0xba9455ce , 0x5b2b1011 , 0x7da69855 , 0x1879d025 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xd5fc0ab2 , 0x03801630 , 0xb4f022f3 , 0x48fdce88 , 0x5c75796d , 0x752443c6 , 0x9c7c2205 , 0x0f7e4dba ] )
+  , Mont::unsafe_make( [ 0xfb3d0b83 , 0x4faa7584 , 0x5b771895 , 0x2e805bba , 0x34220166 , 0x7c332a19 , 0x49fb5e3b , 0x2b50c573 ] )
+  , Mont::unsafe_make( [ 0xb1e47811 , 0xf23b0b87 , 0x8af90011 , 0x159269a0 , 0xf3c4fe79 , 0x8310fbf5 , 0xc208e6a3 , 0x21f67d77 ] )
+  , Mont::unsafe_make( [ 0xc9030a41 , 0x0586aa99 , 0x1f497276 , 0xf03001c4 , 0x9d61dac7 , 0x1143696c , 0x3f0ad49a , 0x23689501 ] )
+  , Mont::unsafe_make( [ 0xac011ced , 0xf45799d7 , 0x5891a2b9 , 0x97410b3e , 0x0f0ed0c7 , 0x72f424be , 0xe5c7eae0 , 0x27aca528 ] )
+  , Mont::unsafe_make( [ 0xfec08033 , 0x720bc832 , 0x2437a18e , 0x10669e1b , 0x91a7f8dd , 0x6efc1670 , 0x7bac13fe , 0x23f66de3 ] )
+  , Mont::unsafe_make( [ 0xfdaf7151 , 0xa85eedfb , 0x4043e021 , 0x656f6705 , 0x23ba2bf6 , 0x7885d197 , 0x4b6ab0c0 , 0x2b0416b0 ] )
+  , Mont::unsafe_make( [ 0x713ea3bf , 0xc562d688 , 0x5590d78c , 0x5e2a7ea0 , 0x7f083b86 , 0xb8c02636 , 0x9361c23f , 0x2dc3a9a0 ] )
+  , Mont::unsafe_make( [ 0xf52aabeb , 0x71f69afa , 0xe7b31811 , 0xd03e4a47 , 0x1ee95c44 , 0xb55e8a75 , 0x97144dcf , 0x2fec7640 ] )
+  , Mont::unsafe_make( [ 0x44e75ac1 , 0x6836d275 , 0xd6a47a7a , 0x1cf6b15c , 0xdae58c42 , 0x019152fa , 0x53fa3a1b , 0x1014c4bf ] )
+  , Mont::unsafe_make( [ 0x606d82e6 , 0xd45e2deb , 0x4f3d6866 , 0x5adb9d49 , 0xd1aeda25 , 0xe97ce4d8 , 0x13cc6023 , 0x000acfec ] )
+  , Mont::unsafe_make( [ 0x71d773a4 , 0x7afb46c0 , 0xce4ae82a , 0x8cdaa2ac , 0x20492d13 , 0xb69490a2 , 0x07120b99 , 0x254b21ac ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x3caf425c , 0x3b331f28 , 0xbff908cb , 0x388a23a4 , 0x6fcf23e4 , 0xe78b9e19 , 0xa6e8e373 , 0x2cb07015 ] )
+  , Mont::unsafe_make( [ 0x00050892 , 0x3992f977 , 0xb9e43ea1 , 0xe598da72 , 0x2ec6a6fe , 0xc19b20bd , 0xd7a6dc27 , 0x15003dbc ] )
+  , Mont::unsafe_make( [ 0xb8e5fc71 , 0x6f276200 , 0x904004ac , 0xcf97132b , 0xead3d72f , 0x5c284af1 , 0xeb12ddd8 , 0x01ce680c ] )
+  , Mont::unsafe_make( [ 0x8fb9a50a , 0x9380b884 , 0xd02bdfa0 , 0xdfacfbb2 , 0x8fdabd71 , 0x79e1a686 , 0xebe69920 , 0x273db7c1 ] )
+  , Mont::unsafe_make( [ 0xad1da4bf , 0x5204b58d , 0x9e3f0fbc , 0x73120963 , 0xf5056fbb , 0x8ef1cffb , 0x5fc243f5 , 0x0105a896 ] )
+  , Mont::unsafe_make( [ 0xc747b42c , 0x26d26d8a , 0xb2204986 , 0x55d8fca4 , 0xbd2c1bcc , 0x2aaefda8 , 0xe928fcce , 0x0403ffdd ] )
+  , Mont::unsafe_make( [ 0x06ca3d58 , 0x31ec90ef , 0xb0b2cd89 , 0xcf7bdb93 , 0x7df1ddd6 , 0x7a42f6fc , 0x5e7ffb14 , 0x16493f45 ] )
+  , Mont::unsafe_make( [ 0x04bafab2 , 0x37bd6c49 , 0x89a48be0 , 0x1c92e1eb , 0x4d9ea00c , 0xfed82eb4 , 0x38f35cf3 , 0x22984bb3 ] )
+  , Mont::unsafe_make( [ 0x6aa9d1c3 , 0x2d7bc3ae , 0x5e70b784 , 0x64343e6d , 0x94ba7980 , 0xf1f9b6b6 , 0xcf7ff8bb , 0x13082433 ] )
+  , Mont::unsafe_make( [ 0x40d391df , 0x7a1d06c8 , 0x3a8c223c , 0xcbb4d7e4 , 0x58b8529c , 0x3e571bab , 0xe9162c17 , 0x0d13341a ] )
+  , Mont::unsafe_make( [ 0xd5c2980b , 0x996e113b , 0xd5ee0b90 , 0x16bd8688 , 0x487a81c6 , 0xa83aa879 , 0x2f038fba , 0x00309844 ] )
+  , Mont::unsafe_make( [ 0xdc2e91e4 , 0x92ecd5bb , 0x1e703658 , 0x91e80856 , 0x9b8cb406 , 0xa486bd91 , 0xc77b3e19 , 0x13150b69 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x448a37dd , 0x4a1a8ab8 , 0xf59d6eef , 0x70b5a38f , 0xf1fc9e00 , 0x5acfd887 , 0xfdf4cd48 , 0x2a1debdc ] )
+  , Mont::unsafe_make( [ 0xe6676a41 , 0x6214baa1 , 0xc1e44344 , 0xd1a12000 , 0xdb52d5be , 0x4b153a0e , 0xa7458cd6 , 0x135e4537 ] )
+  , Mont::unsafe_make( [ 0x64aa0b90 , 0xa5059ac6 , 0xabd87cca , 0xcfa350a2 , 0x55fb8faf , 0x579ee0f8 , 0x23f0799f , 0x258f722e ] )
+  , Mont::unsafe_make( [ 0x0c30c449 , 0xba1ad115 , 0xf4059383 , 0x0fcb57ac , 0x7afc121f , 0x69474028 , 0xec4fa35e , 0x2868e185 ] )
+  , Mont::unsafe_make( [ 0xc5892aa8 , 0x3f08aec5 , 0x57b3fb5c , 0x3e6c7504 , 0x3fbb745f , 0xd6d11660 , 0x9f28ef66 , 0x2b362e60 ] )
+  , Mont::unsafe_make( [ 0xc9b6fdad , 0x4675dc47 , 0x66b2f2ad , 0x1d38f7bc , 0x834db7f1 , 0x74196099 , 0x97eb6d65 , 0x1c633c6e ] )
+  , Mont::unsafe_make( [ 0xf0681245 , 0x80630efe , 0xbed0aba1 , 0xed40ae57 , 0x11a932af , 0x2906bd6a , 0x8eb7d77f , 0x02b68956 ] )
+  , Mont::unsafe_make( [ 0xb6ffa46a , 0x1d56b3b9 , 0x1ff595d2 , 0xf8813f4f , 0xeacd843d , 0xae496d33 , 0x7e6fec7f , 0x12f27116 ] )
+  , Mont::unsafe_make( [ 0x0bd90c34 , 0x8b64ae5a , 0x08e5a760 , 0x296c6bba , 0x58be6f9d , 0x685c74ef , 0x74ef20aa , 0x2e93a75a ] )
+  , Mont::unsafe_make( [ 0xb599a5db , 0x61fe29db , 0xb17fb039 , 0x8732b1d2 , 0x4db4883a , 0x19704be0 , 0x7eb684fd , 0x214d3b20 ] )
+  , Mont::unsafe_make( [ 0x9d503f43 , 0xfe2b8e6c , 0x74606cf3 , 0xc6f7bdee , 0x3d991f77 , 0x7a148c60 , 0x15c9e6a0 , 0x11193ca2 ] )
+  , Mont::unsafe_make( [ 0x6936432b , 0xa00e77aa , 0xe4c67fc2 , 0xf79a6dce , 0x73bc0fe0 , 0xa24eb240 , 0xea3b32d4 , 0x21bd5556 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x5ab29102 , 0x53f9f61d , 0x6c4cb546 , 0xfe7fcd05 , 0x9fe40575 , 0x5068352d , 0x4e5d0041 , 0x2f655238 ] )
+  , Mont::unsafe_make( [ 0xa7fa467f , 0x60320543 , 0xf5da3996 , 0x246c011c , 0xa55892c1 , 0xcbf29e17 , 0xc00ecaab , 0x1b24759d ] )
+  , Mont::unsafe_make( [ 0x0c74728e , 0x833367c7 , 0x6f1e1026 , 0x219217cf , 0x25ee9096 , 0x0b3b91b1 , 0x2777d6e1 , 0x2e71bfba ] )
+  , Mont::unsafe_make( [ 0x9b414d69 , 0x50d9cee1 , 0xecdd9df0 , 0x136a9e3e , 0x7730c691 , 0x502e1a49 , 0xfc9c5f2e , 0x0a503c7f ] )
+  , Mont::unsafe_make( [ 0xd9440834 , 0x9e7ce9c9 , 0xea882429 , 0x086510dd , 0x6cbab6d6 , 0x63cf7250 , 0x280e298f , 0x1a3f1f10 ] )
+  , Mont::unsafe_make( [ 0x01dd88bd , 0x20d5e687 , 0xdf5d1255 , 0x19eb1756 , 0x708fe87b , 0xa33c9085 , 0xafecb648 , 0x1587b720 ] )
+  , Mont::unsafe_make( [ 0xdcb7debe , 0x8341edf9 , 0xd1c61115 , 0x26d74ea9 , 0xb1d8809c , 0x2bce4c75 , 0x5aaf4abe , 0x05d925d8 ] )
+  , Mont::unsafe_make( [ 0xc0b00e48 , 0x2d46844e , 0x426b57f5 , 0x444042fc , 0xf69b5802 , 0x108db483 , 0xac9214a4 , 0x1fa8ddfa ] )
+  , Mont::unsafe_make( [ 0xb629af5c , 0x6251ff20 , 0x0ead0ce2 , 0x316d336a , 0xa6466962 , 0xedf2fd70 , 0x2bf74746 , 0x194a93c3 ] )
+  , Mont::unsafe_make( [ 0x1722ffa8 , 0x613bbfa6 , 0x9235b4d0 , 0xcc13cfca , 0xa66366c1 , 0xc45c390d , 0x0da6d9f6 , 0x28979270 ] )
+  , Mont::unsafe_make( [ 0xc902f745 , 0x2881bce5 , 0x05f68b0b , 0x1a7bb534 , 0x9c299467 , 0x78a22320 , 0xfdf25f78 , 0x1ee2e6da ] )
+  , Mont::unsafe_make( [ 0xa8a19c9c , 0xcd358b5f , 0xbedb9f18 , 0x89bf2771 , 0x3853c40d , 0xfadc745c , 0x5fe21eaa , 0x114d11fc ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x226ddaea , 0xbd69ffb4 , 0x04c48a2a , 0x011ebe29 , 0x20d742a4 , 0xf343346e , 0x8bae0f4f , 0x2d4baca1 ] )
+  , Mont::unsafe_make( [ 0xd2638ad7 , 0xa438dcca , 0x46a34aeb , 0xc1df5a5f , 0x8e28b136 , 0xbd5a99cb , 0x3880e5b3 , 0x1f85cb31 ] )
+  , Mont::unsafe_make( [ 0x61ffcae0 , 0x50056bff , 0x5da70671 , 0xc028b135 , 0x9cb07025 , 0x06e64f5f , 0x44299696 , 0x16996e88 ] )
+  , Mont::unsafe_make( [ 0x3f7c9598 , 0x9c95796d , 0x008f9ff6 , 0x5661e173 , 0x6ffae236 , 0x110c3c38 , 0x7e6fc33f , 0x0fd6bc07 ] )
+  , Mont::unsafe_make( [ 0xb2c0e066 , 0xa9025868 , 0x39606006 , 0x4023aad2 , 0x86c60d43 , 0x3da5e009 , 0x0242a202 , 0x20fa972a ] )
+  , Mont::unsafe_make( [ 0x914d6042 , 0x00cca61c , 0x79e4e9c7 , 0xb8ee0ca2 , 0x5a869f11 , 0x6a655940 , 0x1fc7dea2 , 0x20052700 ] )
+  , Mont::unsafe_make( [ 0x0c1e8baf , 0xd1435401 , 0x299c0571 , 0x57e5356e , 0x3c5164b4 , 0x514cae8f , 0xfe7c2059 , 0x20f20d64 ] )
+  , Mont::unsafe_make( [ 0x334f69b4 , 0xdb098810 , 0x90370953 , 0x813b760c , 0x7ad7d7f7 , 0x3d061d64 , 0xad7465ad , 0x06e0650c ] )
+  , Mont::unsafe_make( [ 0x0028db57 , 0xb31e6b1a , 0x047cf8e0 , 0x5012a063 , 0x715f7037 , 0x05ade735 , 0xd011133f , 0x30578100 ] )
+  , Mont::unsafe_make( [ 0x82b30e3e , 0x7b387c70 , 0x08eaf86e , 0xa7c79507 , 0x188b1656 , 0xa26ddb3e , 0x6df6ae1b , 0x044d3d2a ] )
+  , Mont::unsafe_make( [ 0x2f8c0d1d , 0xe5195d03 , 0x0cf249a1 , 0x4f5496d7 , 0x92f36d14 , 0xfcda2430 , 0x049594c7 , 0x00c5baf1 ] )
+  , Mont::unsafe_make( [ 0x4a3e116e , 0x6773c5a8 , 0x359d2d2d , 0xda188545 , 0x1dbed217 , 0xcad80dac , 0x9355f846 , 0x2e51fc1a ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x236afa19 , 0xdc056c86 , 0xcd5290ad , 0xa0be4b2b , 0x20a61a13 , 0x2fe47688 , 0x12b7bc0c , 0x1032bc5d ] )
+  , Mont::unsafe_make( [ 0x69fd25fe , 0x2e050f49 , 0x0a29ab3a , 0x26649b43 , 0x8e1c7033 , 0x4eeb3f57 , 0xeba60a4d , 0x0bf80659 ] )
+  , Mont::unsafe_make( [ 0xe7429c88 , 0xacc46532 , 0xb7894a15 , 0x385eb4a2 , 0x70b45e01 , 0x68dffbac , 0x94749fc4 , 0x184e50fb ] )
+  , Mont::unsafe_make( [ 0x077764fc , 0x895365e4 , 0xddc5c321 , 0x9675b788 , 0x3aad3127 , 0x86434a76 , 0x45541364 , 0x2669afa5 ] )
+  , Mont::unsafe_make( [ 0x8ee82ec8 , 0xa6702327 , 0x5d4f080c , 0xea6176e0 , 0xbdc3fc4f , 0xf9f839e5 , 0x612dd4c2 , 0x2ff2b434 ] )
+  , Mont::unsafe_make( [ 0x3930b41c , 0x6567b7b8 , 0xa4ad420b , 0x4c5599df , 0xd29a48f8 , 0x8ac305c9 , 0xd1e4900e , 0x1bb7fac9 ] )
+  , Mont::unsafe_make( [ 0x1e339c2a , 0xda596c04 , 0xfef6a99e , 0xea489dd3 , 0x974d9fab , 0xfecf6ded , 0x6a1cadab , 0x2fe853d4 ] )
+  , Mont::unsafe_make( [ 0xe6eac8d5 , 0xbdc6601c , 0x1cf8d8b5 , 0x90d95e02 , 0xe3b24dd6 , 0xf7aa9d0b , 0xf3e6d840 , 0x0c7e1fd4 ] )
+  , Mont::unsafe_make( [ 0xc433492e , 0x52a4cb32 , 0x70575d99 , 0x62b50f59 , 0x0aa2a820 , 0x3cc9683c , 0x201c1fc7 , 0x035704f4 ] )
+  , Mont::unsafe_make( [ 0xe7f80a55 , 0x15417d52 , 0x58e69766 , 0x8ae65e4e , 0xf5968109 , 0x347834fd , 0x093d82f8 , 0x1eb904c4 ] )
+  , Mont::unsafe_make( [ 0x7f47d997 , 0x52d2e0a9 , 0x2563733d , 0xb8471d61 , 0xd946ee4b , 0x341cec55 , 0x85cb43d9 , 0x13ba8796 ] )
+  , Mont::unsafe_make( [ 0xc5f27346 , 0x4903bb3d , 0x95f9890e , 0x78ff1945 , 0xd957adc9 , 0xd84ec9d7 , 0x8997abef , 0x192510f4 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x733fdafb , 0x9d987f7d , 0x9a9e050b , 0x97078e13 , 0x6a856464 , 0xbdb3b8e0 , 0x92a6216a , 0x0139ea07 ] )
+  , Mont::unsafe_make( [ 0x20ddba20 , 0x4455b96c , 0xceb0439d , 0x272d7992 , 0xd0c370e7 , 0xc9246da6 , 0x430021d5 , 0x14d2b65f ] )
+  , Mont::unsafe_make( [ 0xc4b0ed79 , 0xbccae3aa , 0x0b3480e8 , 0x3e74ade9 , 0xe06c7790 , 0x88a86027 , 0x00642666 , 0x27823292 ] )
+  , Mont::unsafe_make( [ 0x6191bc05 , 0xb9360243 , 0xd71a97f7 , 0x3ee0d4a0 , 0x862ac78c , 0xcf036278 , 0x1715ebb4 , 0x14e8b55a ] )
+  , Mont::unsafe_make( [ 0x5d5afc36 , 0x8a49713c , 0x35766df9 , 0x56153fdd , 0x8edcd40e , 0x1fd9e176 , 0x0ae4a3b4 , 0x0e9b6b78 ] )
+  , Mont::unsafe_make( [ 0xcfbee66f , 0xc1defbb6 , 0xb81f43fb , 0x85afc5eb , 0x0cb5e036 , 0xf6162059 , 0xdc9dff2c , 0x079c5070 ] )
+  , Mont::unsafe_make( [ 0x15c0e631 , 0x9a44bfa3 , 0xee7002a3 , 0xc1069b48 , 0xcce0a1ea , 0x508fd031 , 0x3fb5c096 , 0x1509347a ] )
+  , Mont::unsafe_make( [ 0xe57c1b50 , 0xb5a9a315 , 0xf2687b15 , 0x82e15985 , 0x3afafbca , 0xb9405ffa , 0x17a95998 , 0x1962ac3a ] )
+  , Mont::unsafe_make( [ 0x7be829f7 , 0xf0938e86 , 0xb976c62b , 0xd8691878 , 0x9b16184c , 0x5d16aa21 , 0xd000f024 , 0x29ae06a5 ] )
+  , Mont::unsafe_make( [ 0x479fcc51 , 0x003ebc57 , 0x241e8e26 , 0xfbc2f3a7 , 0x0cc5b7f8 , 0xa1d90165 , 0xf45fd8c7 , 0x04885845 ] )
+  , Mont::unsafe_make( [ 0xb5c747ad , 0x77510377 , 0x9790081e , 0x920f287d , 0xc4f90f20 , 0x2ba031ad , 0x972184ae , 0x1ad36c2b ] )
+  , Mont::unsafe_make( [ 0xe2e9416f , 0xcd134a29 , 0x021f6716 , 0x03e5fb96 , 0x1bd4b662 , 0x916a0134 , 0x851c725c , 0x22dedb7a ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x52320577 , 0x361da890 , 0x8cbab1b0 , 0x3207b59f , 0x336bc95b , 0x0bfd96de , 0xa3c091de , 0x143ecd93 ] )
+  , Mont::unsafe_make( [ 0x960b3a66 , 0x950ffad8 , 0x3d02e1fc , 0x3c55b5b7 , 0x21d4ad6f , 0x5fbcc1ff , 0x07a572f4 , 0x1b086f43 ] )
+  , Mont::unsafe_make( [ 0x22636bac , 0xdba99a30 , 0xbd5420e2 , 0x436b34a3 , 0x50c42e55 , 0xc16bc476 , 0x3321b892 , 0x15a3b310 ] )
+  , Mont::unsafe_make( [ 0xd2baa593 , 0x88f3fcde , 0x9ccb38d8 , 0x6c3f3c86 , 0x42985a30 , 0xc885c44f , 0x9c2fd111 , 0x24136b12 ] )
+  , Mont::unsafe_make( [ 0x6e40266a , 0xde3bcdc8 , 0x25919365 , 0x4c92dda0 , 0x24363412 , 0xfc8fae49 , 0x7df5bf7d , 0x13bd0212 ] )
+  , Mont::unsafe_make( [ 0x8d28ce11 , 0x7c5c7f39 , 0x349bb44c , 0xb41af3ff , 0x35a163d8 , 0xbfbb9e98 , 0x369e2cb4 , 0x1f7a65fb ] )
+  , Mont::unsafe_make( [ 0x0e65842f , 0xc5669df9 , 0xc7d26a5d , 0xc740c047 , 0x29c1a552 , 0x6b53f8c6 , 0xab0dd0fc , 0x03f69697 ] )
+  , Mont::unsafe_make( [ 0x4448e225 , 0x4d718b49 , 0xa9dfb415 , 0x1448b330 , 0xd7b0afbc , 0x2f752d6c , 0x74134c2f , 0x1884c5ca ] )
+  , Mont::unsafe_make( [ 0xce9d2c7a , 0x2919f567 , 0xd6f5ea67 , 0x48c35edf , 0x1583430b , 0x9ac3fb44 , 0x03639b27 , 0x01194008 ] )
+  , Mont::unsafe_make( [ 0x865db92a , 0x18d918fe , 0x7eb6fc73 , 0xe20b8d31 , 0xc9e98da6 , 0xacc37b50 , 0x043dc81c , 0x0e800844 ] )
+  , Mont::unsafe_make( [ 0x6c0c1383 , 0x3bdea714 , 0xab1b6e05 , 0x1ec74a2a , 0xf202d95c , 0xc4119b69 , 0x674b55eb , 0x2b36a206 ] )
+  , Mont::unsafe_make( [ 0x82e79f84 , 0x438e2bed , 0x7cf9cc0a , 0x5a6b7eca , 0xdf4222e1 , 0x04397513 , 0x5ef9bab0 , 0x0bf74003 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x065fb92f , 0x72b222bc , 0x83ef4bc8 , 0x5c5df156 , 0xc2267613 , 0xf58634bc , 0xab16a2db , 0x07559076 ] )
+  , Mont::unsafe_make( [ 0x0f73dd36 , 0x9601bb89 , 0x769dab4f , 0x38f9c459 , 0xd19020c4 , 0x6eeba917 , 0xfeff870a , 0x11908a22 ] )
+  , Mont::unsafe_make( [ 0xab5947a8 , 0xc6db2f74 , 0x60468efe , 0xa51d3722 , 0x816e52c2 , 0xe0eb9ec9 , 0x5965619e , 0x01138322 ] )
+  , Mont::unsafe_make( [ 0xfc1e3a2b , 0x69417ab4 , 0xbd232932 , 0x4ab53a49 , 0x2dfc7830 , 0xffff56e0 , 0x93fbcd78 , 0x0f42a005 ] )
+  , Mont::unsafe_make( [ 0xb2269729 , 0x5cb005c4 , 0xcd8a24ca , 0x27f02b02 , 0x5f9869a9 , 0xdef709be , 0x6557e36b , 0x08964f79 ] )
+  , Mont::unsafe_make( [ 0x6195bd2f , 0x57191dd3 , 0xd7f89984 , 0x5a22a09d , 0x2f4c07b1 , 0xce5076eb , 0xea48d74c , 0x2a1fc39b ] )
+  , Mont::unsafe_make( [ 0x3fbd96ce , 0x4cbd0cca , 0xdc880aa8 , 0xabb560d8 , 0x329c52db , 0xaae53459 , 0x5b57f2e2 , 0x21e8bd2d ] )
+  , Mont::unsafe_make( [ 0x126a201d , 0xbca1083d , 0xb340db9d , 0x4a764f01 , 0x507f0a49 , 0x59771530 , 0xfd5e65c9 , 0x21a6a36f ] )
+  , Mont::unsafe_make( [ 0x47b47e5c , 0x6e1f6679 , 0x251d8e94 , 0x62fc6903 , 0x2934087a , 0xef86c4cc , 0x1486a30a , 0x1dd693a3 ] )
+  , Mont::unsafe_make( [ 0xb22ac177 , 0xb00949f2 , 0x29a60cc8 , 0xddcdf840 , 0x24ea0ad1 , 0xb41040e4 , 0x4a9fd090 , 0x090f204f ] )
+  , Mont::unsafe_make( [ 0x9b085dcc , 0x33e36f6e , 0x1387cc77 , 0x3909cca1 , 0x53d1c822 , 0x6edb4240 , 0xa49a325c , 0x09daf013 ] )
+  , Mont::unsafe_make( [ 0xca55b2f6 , 0x98f80af4 , 0x17e06b5d , 0x2b0afb11 , 0x54fb2720 , 0xdcefd73d , 0x5c2fc086 , 0x20a4d4ab ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x2022064b , 0x0c42dcd4 , 0xa37a1d73 , 0x77ba5dec , 0x11b39c3a , 0x540b259c , 0xa875a54d , 0x30362fbe ] )
+  , Mont::unsafe_make( [ 0x0528ae03 , 0x9a5eea02 , 0x22ac1743 , 0x0bb42df3 , 0xeb165a60 , 0xabde2f3b , 0xa9e35abc , 0x2c937863 ] )
+  , Mont::unsafe_make( [ 0xfb400d8f , 0xf0cd6236 , 0x15fce789 , 0x29d4c952 , 0x3bb422ab , 0x9c03282d , 0x2fb4d035 , 0x1c9246d1 ] )
+  , Mont::unsafe_make( [ 0xfbb0a317 , 0x2b2d580c , 0x68eb4cb1 , 0xa8d31956 , 0x868794f0 , 0x05159acf , 0x94af06f3 , 0x24b9c865 ] )
+  , Mont::unsafe_make( [ 0x460087b0 , 0x126b1150 , 0x1f63e528 , 0x3ad9a1b4 , 0x5b8880e6 , 0xa3a1caf7 , 0x12677967 , 0x2574214b ] )
+  , Mont::unsafe_make( [ 0xcdeaaaa2 , 0x25f810e5 , 0x3bcb0eb7 , 0x06030963 , 0x54d714f5 , 0xe69e957d , 0x5b0364d2 , 0x179ca7c3 ] )
+  , Mont::unsafe_make( [ 0x90cc2631 , 0xf5c91e2e , 0xe8a0c5d4 , 0x2f660298 , 0x6a2945a7 , 0x6a1dad8f , 0xe5ac9338 , 0x07a29882 ] )
+  , Mont::unsafe_make( [ 0xd7cbf9bb , 0x373fabd1 , 0x2827619f , 0xc64b5419 , 0x861d37c1 , 0x80ad12c6 , 0x300ab175 , 0x21ad5441 ] )
+  , Mont::unsafe_make( [ 0x941a25bc , 0x344a6204 , 0x7daf7bb3 , 0x0b956a7c , 0x7ef797ab , 0x514f2f16 , 0x0dbd7fe0 , 0x0837e627 ] )
+  , Mont::unsafe_make( [ 0x45708b5d , 0x4e2d84c8 , 0xc0cc0234 , 0xaac72101 , 0x65a8f1cf , 0x480fb072 , 0xdffa3648 , 0x06b2c650 ] )
+  , Mont::unsafe_make( [ 0x23dbb970 , 0xd924a5c0 , 0xad7a53f5 , 0x6984f5a2 , 0x5983f5db , 0xf9fb578d , 0x6d232ce7 , 0x1e2d68ac ] )
+  , Mont::unsafe_make( [ 0x0303c367 , 0xa8230e2b , 0x3d6ccfc3 , 0x66c4ecec , 0x39d49ed2 , 0x9eebe020 , 0x6de1b24a , 0x27e9ba8b ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x97fae1d0 , 0xf4460ce5 , 0x98c4a90e , 0x8afaf516 , 0x7ec987a8 , 0x00caa7c6 , 0x1744212c , 0x1b58e8b3 ] )
+  , Mont::unsafe_make( [ 0x6ff76b09 , 0xf2b05652 , 0x3a69ce72 , 0xaaee6377 , 0xc9b5cfd5 , 0x6d2b1caf , 0xd5e4b110 , 0x01bc28aa ] )
+  , Mont::unsafe_make( [ 0xc3130b01 , 0x43230517 , 0x2d88543f , 0x3a389eec , 0x9f48b246 , 0x5042cbe0 , 0x483438bc , 0x2b850ea7 ] )
+  , Mont::unsafe_make( [ 0x4a70344f , 0x4033bfbf , 0xed2e6e5b , 0x0f8a242d , 0xd553471b , 0x0d4a3a6b , 0x6002b7e7 , 0x24ee26e2 ] )
+  , Mont::unsafe_make( [ 0x6e4d8f3a , 0x223dafb5 , 0xa5e89cbd , 0x58afd171 , 0xa258de2c , 0x1363e67d , 0x90c7de1b , 0x0f56574b ] )
+  , Mont::unsafe_make( [ 0xa1711b7f , 0x83ec94a9 , 0xfc711da3 , 0x6b87ea5a , 0x79bf8ee2 , 0xe09653b2 , 0x42eb429e , 0x15b8901c ] )
+  , Mont::unsafe_make( [ 0x13ea93af , 0xea0528c4 , 0x4c4a7b2c , 0x3aeb3cdb , 0xbfba7759 , 0x80726546 , 0xdc413ff4 , 0x10704abc ] )
+  , Mont::unsafe_make( [ 0x536bb68e , 0xbcd60afc , 0x3087d57c , 0x136ca5b9 , 0xb15ef149 , 0xb9e36498 , 0x8aa712cd , 0x0fa9fa4d ] )
+  , Mont::unsafe_make( [ 0x4c710e60 , 0xd56d68a3 , 0xf2751868 , 0x6e087348 , 0x88be1eb7 , 0x3e2d2bbc , 0x25ec13a1 , 0x18571fc5 ] )
+  , Mont::unsafe_make( [ 0xe2edad01 , 0x412cf293 , 0xf6dc71f6 , 0x38287208 , 0xe66f7edc , 0x723e67ea , 0x058fea28 , 0x0113b2f6 ] )
+  , Mont::unsafe_make( [ 0x7d3aecbf , 0x13a67aa7 , 0xfb779d52 , 0xdca6432b , 0x787f6ef3 , 0x9b9bc330 , 0x34dc6472 , 0x15a56510 ] )
+  , Mont::unsafe_make( [ 0x928719f3 , 0x713f6087 , 0xfc936750 , 0x12e4e6b3 , 0x173acad3 , 0x393f21d2 , 0x464e619b , 0x0b8b97d7 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x1ae45565 , 0x699c57eb , 0x93d8d950 , 0xf78a2f5b , 0x6f53528b , 0x09c6830f , 0x00f872b0 , 0x2409c2a7 ] )
+  , Mont::unsafe_make( [ 0x05af5d55 , 0x32028515 , 0x67b4bff8 , 0x30523926 , 0xb1ce6662 , 0x053e59da , 0x39d4406f , 0x07474074 ] )
+  , Mont::unsafe_make( [ 0xb54115b0 , 0xdef31b23 , 0xd03da92b , 0x47d8470c , 0xe3cd9358 , 0x26e30158 , 0x7e636f6d , 0x0375b4cf ] )
+  , Mont::unsafe_make( [ 0x3c14bc1d , 0x59413dfd , 0x5f8d87af , 0xa789306c , 0x5a1aa01a , 0xa9524a7c , 0x2be387d4 , 0x045a2679 ] )
+  , Mont::unsafe_make( [ 0x68fbb78d , 0x47da9462 , 0xa9efc5b6 , 0xddf3ad2d , 0x20be15ab , 0x0e9d4538 , 0x31786b33 , 0x2ca5f8c0 ] )
+  , Mont::unsafe_make( [ 0x68bceb02 , 0x3707b167 , 0x85471278 , 0xc049e783 , 0x48ee8794 , 0xff2546eb , 0x597d5042 , 0x2c0e3b67 ] )
+  , Mont::unsafe_make( [ 0xfe809099 , 0x939ca450 , 0xa1ec9cc3 , 0x4ded5b77 , 0x18dceed4 , 0x283ff63f , 0x013e5721 , 0x164f31bb ] )
+  , Mont::unsafe_make( [ 0xe03d884f , 0xcb486333 , 0x14d2e19e , 0xa212b8c9 , 0xb99f0ce2 , 0x23b9b0f0 , 0x674aaca1 , 0x28270715 ] )
+  , Mont::unsafe_make( [ 0xb996c1e4 , 0xd27070dd , 0x2628d9dc , 0x4873c878 , 0x7ed1ddbf , 0x32d321a7 , 0x376248c2 , 0x28830585 ] )
+  , Mont::unsafe_make( [ 0x96ddee82 , 0x28f213f7 , 0x190a5aaa , 0xcbe89376 , 0xb98ee515 , 0x582b589e , 0xa33b7583 , 0x0f09cec2 ] )
+  , Mont::unsafe_make( [ 0x64cdaf11 , 0x50800533 , 0x7d3678eb , 0x754f41bb , 0x68479069 , 0xba6a7f14 , 0x92fc8657 , 0x226d7c81 ] )
+  , Mont::unsafe_make( [ 0x58d013dc , 0xa16b7c6a , 0xc7d37a4e , 0xa4d6d127 , 0xbec17a40 , 0xb720b31a , 0x00f7b071 , 0x0cbdf7b7 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x5988f2ab , 0x9d81b1dd , 0x7ab240a8 , 0x6d16ca2e , 0x87a5b971 , 0xe34c4887 , 0x1350d9e7 , 0x292cf6ce ] )
+  , Mont::unsafe_make( [ 0x859ad4b8 , 0xbb766d88 , 0xd0dfb598 , 0x72eeea57 , 0x3416cd34 , 0x8929d8f9 , 0x8403a799 , 0x039879b7 ] )
+  , Mont::unsafe_make( [ 0x86b8154e , 0x5d7ce357 , 0x906e07d3 , 0x3edfc0d5 , 0x6cbaeeac , 0xb8a5ad30 , 0x6592f7a9 , 0x1f54f684 ] )
+  , Mont::unsafe_make( [ 0x4b4bb4c3 , 0x1ce8fc76 , 0x3ce1aaba , 0x8e0d0a9e , 0xa9119d9f , 0x685d20d9 , 0x8f5b5108 , 0x0e1a47bb ] )
+  , Mont::unsafe_make( [ 0xc69abee5 , 0x9d714ef9 , 0x203fe9bf , 0xd3b0973b , 0xd94c2c85 , 0xe96b9659 , 0x28f1c9e0 , 0x185e5d72 ] )
+  , Mont::unsafe_make( [ 0x2f886b6b , 0xaddd8faf , 0xe833104b , 0x93372013 , 0x70f58c54 , 0xd0993b8e , 0xeb3e19f7 , 0x1c1641a3 ] )
+  , Mont::unsafe_make( [ 0xe6dc0ae9 , 0x10452a13 , 0x71e47b7c , 0xa9f3f534 , 0x264bf5ab , 0x67449dbc , 0x63123a65 , 0x297acb1e ] )
+  , Mont::unsafe_make( [ 0x8d37c6e9 , 0x98207a71 , 0x869a3a08 , 0xf9c957f5 , 0x0aacbfe3 , 0x97bea777 , 0xcccf7477 , 0x28c11916 ] )
+  , Mont::unsafe_make( [ 0x932049fd , 0x2583720c , 0x252659b9 , 0x34084462 , 0x36f28043 , 0x0d01f995 , 0x3090b121 , 0x1da0e6d1 ] )
+  , Mont::unsafe_make( [ 0x9636ff7d , 0xdcd12b61 , 0xd178db58 , 0xc4d5015d , 0x1a2980dc , 0xefe92f8b , 0xd38efa44 , 0x0b799560 ] )
+  , Mont::unsafe_make( [ 0x231708ca , 0x4d2f8bb8 , 0xd6507ce7 , 0x9edc9732 , 0x2ebe146c , 0xe99a064f , 0xc489ba5f , 0x1fd1315e ] )
+  , Mont::unsafe_make( [ 0x4c488b19 , 0xcbff3c6e , 0xb9fe014e , 0xf73bfdaa , 0xfaf61492 , 0x875e5b11 , 0x69a4a508 , 0x0be45554 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x5558a2d5 , 0x4b26477e , 0xf0a539f3 , 0xfe576dd4 , 0x8e42b024 , 0x53002158 , 0x81b028b1 , 0x20801980 ] )
+  , Mont::unsafe_make( [ 0xb51f3de0 , 0x1d65b678 , 0xfa470539 , 0x8f570516 , 0xdedcc953 , 0x096e2a61 , 0xdf3ba09d , 0x1c89715a ] )
+  , Mont::unsafe_make( [ 0xb941d1f4 , 0x74b4c619 , 0x6404aaa9 , 0xa2e99a29 , 0x2714b881 , 0x68eaa104 , 0x2680d237 , 0x1ec37e7d ] )
+  , Mont::unsafe_make( [ 0xdb3dd4d5 , 0x221b4e37 , 0xd40cfd61 , 0xc023a7f3 , 0xdcb69f58 , 0xf50a5bad , 0x369a04f0 , 0x22a407a1 ] )
+  , Mont::unsafe_make( [ 0x3614e3d7 , 0x1a31f2ad , 0x5a38bf93 , 0xb70a6583 , 0xd1be3687 , 0x3ccc6a34 , 0x2cbba4d2 , 0x03ddf5a6 ] )
+  , Mont::unsafe_make( [ 0x083c743f , 0x44550f3a , 0xd0c99e66 , 0x7496929f , 0x7312f70e , 0xc5aedad8 , 0x9abfd011 , 0x031af209 ] )
+  , Mont::unsafe_make( [ 0x268ece15 , 0x1a986265 , 0x422f0931 , 0xf30a868e , 0x6edacde2 , 0x03bb6c50 , 0x114e867b , 0x0bd90fcf ] )
+  , Mont::unsafe_make( [ 0xc07d3bc5 , 0xadd8466c , 0x70b50433 , 0x0422e2f5 , 0xc8906e56 , 0x2f2531b3 , 0xe9ffe953 , 0x051fb7d1 ] )
+  , Mont::unsafe_make( [ 0x361a77ab , 0x0107bb8e , 0x9bcf6f40 , 0x7b9f8eb4 , 0xabeb21b0 , 0xdc4ceb3d , 0x780fcca5 , 0x303e9732 ] )
+  , Mont::unsafe_make( [ 0xdbbfa658 , 0xa818b4c0 , 0xba991c58 , 0xea2720a7 , 0x2ea9c119 , 0x404ffd55 , 0x66256ef7 , 0x1d44ffb5 ] )
+  , Mont::unsafe_make( [ 0xb1fb8e1a , 0xd789675f , 0x97c62937 , 0xb63af211 , 0x0ac3f558 , 0xa6571111 , 0x0ab4cbd7 , 0x033cbaff ] )
+  , Mont::unsafe_make( [ 0x8d277cff , 0x28358b13 , 0xaa702ccd , 0x2b745793 , 0x08613ae5 , 0xf176ae1b , 0xca0fd67b , 0x06f6d805 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0xabf52116 , 0x95fe067d , 0x68544384 , 0x59d519e7 , 0xb9f5c62e , 0x9454bc60 , 0x49174159 , 0x24c3a78c ] )
+  , Mont::unsafe_make( [ 0x1195f0c8 , 0xbb299cce , 0x9147acc0 , 0x1914f433 , 0x35f5bb8a , 0x7a78aac3 , 0x99769e03 , 0x2184477e ] )
+  , Mont::unsafe_make( [ 0xf428ec28 , 0x5fcbe5bd , 0x9ce77e05 , 0x42e3921f , 0xe10de2e9 , 0x74d4f60f , 0xa2f29ff8 , 0x20809b44 ] )
+  , Mont::unsafe_make( [ 0xdd43288b , 0xd15d1f64 , 0xa06363f6 , 0xdbbd3811 , 0x565c96d0 , 0xb2b72525 , 0x9c449bd5 , 0x09087b11 ] )
+  , Mont::unsafe_make( [ 0xa289d6fb , 0x3e6ec60a , 0x09a0c081 , 0x5b25f69d , 0x61932613 , 0x769bac9e , 0xf8bd9ca7 , 0x167bebd0 ] )
+  , Mont::unsafe_make( [ 0x9e9ed647 , 0xc61520fa , 0x4ca2003a , 0x74001d28 , 0x707508a3 , 0xe4774551 , 0x3e85db14 , 0x232ec36d ] )
+  , Mont::unsafe_make( [ 0xd103b0a7 , 0x1c370cc3 , 0x36f2eb25 , 0x62181259 , 0x48a50569 , 0x76fe91a3 , 0xda4df629 , 0x11ada2a5 ] )
+  , Mont::unsafe_make( [ 0x139c2ea4 , 0x17993fc3 , 0xc55475e1 , 0xe452aee3 , 0xfa57f637 , 0x0cff25ef , 0xa39a9ed1 , 0x17cce4a1 ] )
+  , Mont::unsafe_make( [ 0xfd2e00d4 , 0x49ded0a1 , 0x29f23ba4 , 0xaa5c8e35 , 0x1c7761d3 , 0x277a9590 , 0x0aba2f43 , 0x11bbbb88 ] )
+  , Mont::unsafe_make( [ 0xa82b7c83 , 0x77700b0a , 0x498bd963 , 0x56bac495 , 0x39f27611 , 0x594dd38d , 0x04af1155 , 0x2fa5b343 ] )
+  , Mont::unsafe_make( [ 0x6787a627 , 0x6e8fdfd8 , 0xa4ce1559 , 0xfc0de19f , 0x199f6258 , 0x3ac7441c , 0x342f0374 , 0x2ce42b45 ] )
+  , Mont::unsafe_make( [ 0xd73773b4 , 0x384d9c99 , 0x49c41706 , 0x4625dd59 , 0xccbb0f15 , 0x2ec95f89 , 0x54dbf1c8 , 0x11fd8f80 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x7b15529e , 0x60960682 , 0x7f5938ed , 0x0453788c , 0xa88eee13 , 0xa6ed2333 , 0xfe0b605e , 0x0bfcbf9c ] )
+  , Mont::unsafe_make( [ 0x34d94f0f , 0xb82991bd , 0x75124118 , 0xdf1a797a , 0xb54790e5 , 0x7ea75bcd , 0xd1617c5f , 0x1bff4716 ] )
+  , Mont::unsafe_make( [ 0x61fbb1e1 , 0x3a9809dc , 0x438e7888 , 0x005649a0 , 0x3e9d8167 , 0xa4c58b58 , 0x2fd38b99 , 0x07f74b73 ] )
+  , Mont::unsafe_make( [ 0x7b1d09f7 , 0xf3964305 , 0xb9e05cce , 0x3d8c8f90 , 0x7cac4c38 , 0x1da8c8d7 , 0xb97fd233 , 0x21ca6f7b ] )
+  , Mont::unsafe_make( [ 0xcf2a01c1 , 0x6ffc4cc2 , 0x5c988daf , 0xa8de6301 , 0x6318f2ea , 0x842e87a5 , 0x8e684d48 , 0x0546dba5 ] )
+  , Mont::unsafe_make( [ 0x40e58912 , 0xfc6c5865 , 0x07990192 , 0xb4c78984 , 0xcbf801e3 , 0xfcbfac0b , 0xbd0cbae0 , 0x1f1598ea ] )
+  , Mont::unsafe_make( [ 0x85ca72b8 , 0x898e6db3 , 0x442bad72 , 0xbf10bcb8 , 0x55fa90f9 , 0xd4e9b523 , 0xcbf177f3 , 0x1bca9fca ] )
+  , Mont::unsafe_make( [ 0x7d4e1f75 , 0x842baed9 , 0x08285d9d , 0x9f5b4d88 , 0xd8243ad9 , 0x8212e44f , 0x870558ff , 0x1f81eb82 ] )
+  , Mont::unsafe_make( [ 0x2f35422b , 0x916c1589 , 0x82c1d5a5 , 0x14aace52 , 0xc2ed8b9f , 0x8f5b9bad , 0xa9da2c19 , 0x27d97db9 ] )
+  , Mont::unsafe_make( [ 0xa354da05 , 0xbac191bd , 0x1fcec82e , 0x8e7dc8aa , 0xdd4a121c , 0xf39d297a , 0x728ca287 , 0x13915e1a ] )
+  , Mont::unsafe_make( [ 0x9d398459 , 0x33e6e079 , 0xfa322a05 , 0xcfba8d32 , 0x3eade0d2 , 0x1f15aeae , 0xf26b8dd1 , 0x080b3f95 ] )
+  , Mont::unsafe_make( [ 0xfb89d6c8 , 0xbb5e7d61 , 0x61317e5a , 0x455a0050 , 0x8555de90 , 0xbd24fe0c , 0xd71b0353 , 0x10393222 ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x4ec64ccd , 0xbc36f112 , 0x770097a4 , 0x0a84913c , 0x1777a8a8 , 0x7b8b27ee , 0x75f0c036 , 0x21dad859 ] )
+  , Mont::unsafe_make( [ 0xca043e5d , 0x91a4a888 , 0xc86cdd5c , 0xcaf1cbdc , 0xe2460608 , 0x215fff91 , 0x80894331 , 0x0dbaae27 ] )
+  , Mont::unsafe_make( [ 0xe7f0d26b , 0x87c0d269 , 0x4047d575 , 0xa343dff5 , 0xd6ba2d8e , 0x72cb10c8 , 0x78f03a99 , 0x29be4c55 ] )
+  , Mont::unsafe_make( [ 0xc13bbf05 , 0xbc265e9c , 0x6da91669 , 0xf3bb0e61 , 0x2f160961 , 0xce622ea5 , 0x48252cbe , 0x1da2a224 ] )
+  , Mont::unsafe_make( [ 0xa9e98dcb , 0x7ca97976 , 0xf02af270 , 0x5c8474b5 , 0x7382a4c5 , 0xbe24c2ab , 0x02081cf7 , 0x01d36f64 ] )
+  , Mont::unsafe_make( [ 0x8a22b1de , 0x9f779017 , 0x9ddc05ad , 0x27f00a3f , 0x9e1b32cf , 0xdc7fda44 , 0x3215d172 , 0x2fad8931 ] )
+  , Mont::unsafe_make( [ 0xbfbb1a92 , 0x25774a6b , 0xee148e8f , 0xd9f2368c , 0x1500679d , 0xd015f2f6 , 0xb0eceee5 , 0x06dae302 ] )
+  , Mont::unsafe_make( [ 0x9523260f , 0xbb37ae8b , 0xbb4202b5 , 0xc4c60db0 , 0xfd41d8ac , 0x0b7ba5af , 0x165dae49 , 0x16a3ce34 ] )
+  , Mont::unsafe_make( [ 0xbd5d32c6 , 0x166cab79 , 0x3619e7d5 , 0x01c04dec , 0xc51b8f69 , 0x9640caa9 , 0x01b97c9f , 0x20531bff ] )
+  , Mont::unsafe_make( [ 0x81a819ce , 0xea379026 , 0x5d774cc2 , 0xad34e020 , 0xf1ae851f , 0x4dfdfb6f , 0xc240789e , 0x2b3ede51 ] )
+  , Mont::unsafe_make( [ 0xa3cc93bf , 0x2bba81c6 , 0xdd882234 , 0xd4d54bca , 0xfac5bda6 , 0x32b57f42 , 0x01820e5f , 0x05c6eb20 ] )
+  , Mont::unsafe_make( [ 0xb66a23fb , 0xed6f05ab , 0xff4d583c , 0x6c48d393 , 0x82dbace0 , 0x5aa356aa , 0x8ba80d1f , 0x2afe72bd ] )
+  , Mont::unsafe_make( [ 0xfde9412b , 0xd24ccb91 , 0x7ec99d80 , 0xb32bd1c9 , 0xff15e715 , 0x26d1f83c , 0x331ad1be , 0x04ab17c2 ] )
+  , Mont::unsafe_make( [ 0x1529e28d , 0x55b2f99c , 0x5c2ea08d , 0xabfbeb1b , 0xe7ba79c9 , 0x07357b53 , 0x203714e2 , 0x1e93dede ] )
+  , Mont::unsafe_make( [ 0x6608f63b , 0x873d76ed , 0x11d89355 , 0x2ed21d9a , 0x9fead42d , 0x58ffaea9 , 0xe87aab94 , 0x1036b4f0 ] )
+  , Mont::unsafe_make( [ 0xd50e3255 , 0x775db84b , 0xbe20ee59 , 0x7de82be3 , 0xd4b49631 , 0x815976c8 , 0xaad8d613 , 0x1ab6a935 ] )
+  , Mont::unsafe_make( [ 0xef05d783 , 0x00a19846 , 0x3a89c4d7 , 0x9a3952e9 , 0x2b3cd0f4 , 0x27eddcdb , 0x84612b8c , 0x20120548 ] )
+  , Mont::unsafe_make( [ 0x5102cca9 , 0x89549cda , 0x711476f5 , 0x55330824 , 0x3a2dc5ac , 0xaa5d0c44 , 0x717cc14f , 0x2d70da99 ] )
+  , Mont::unsafe_make( [ 0x6212e7d1 , 0xdf3c0b9f , 0xbdfcf871 , 0xb0e790a7 , 0x812a2e5c , 0xfba032f4 , 0x6c78527b , 0x0fe5b5d9 ] )
+  , Mont::unsafe_make( [ 0x444bddeb , 0xa6288e89 , 0xcf91505b , 0x05262308 , 0xac5b6cff , 0x815ea488 , 0xb0750542 , 0x12a21d19 ] )
+  , Mont::unsafe_make( [ 0x340d3543 , 0xacf060a6 , 0x64009ca3 , 0xd15a3d9f , 0x951c6839 , 0x004dda80 , 0x8397fc89 , 0x05e8e1e8 ] )
+  , Mont::unsafe_make( [ 0x23646878 , 0x7ce2b3f1 , 0x5a3e6448 , 0x6ea7b19d , 0x326f2e2b , 0x650a96ac , 0x08e47a91 , 0x2472b4dc ] )
+  , Mont::unsafe_make( [ 0x09222f64 , 0xf6da1f20 , 0xd56352b5 , 0x5b8d0fa7 , 0x8a3867d1 , 0xfb0c47ff , 0xd411b669 , 0x013aa976 ] )
+  , Mont::unsafe_make( [ 0xbd9bec31 , 0xfd1f47fc , 0xdd296b2f , 0x85c85f72 , 0xf6223673 , 0x057dcab9 , 0x2db383a3 , 0x2f057750 ] )
+  , Mont::unsafe_make( [ 0x30d995cb , 0x36ddad97 , 0x24765e8f , 0x75b5ca13 , 0x1cd94422 , 0x12ece299 , 0x16efc2bb , 0x09577579 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
@@ -0,0 +1,1248 @@
+
+// circomlib's Poseidon constants for t=8
+
+use crate::bn254::montgomery::*;
+
+
+//------------------------------------------------------------------------------
+
+// round constants (t for external, 1 for internal rounds; flattened)
+pub const CONST_C: [Mont; 128] = 
+  [ Mont::unsafe_make( [ 0xa52d8e34 , 0xb9d56dea , 0xa3d90188 , 0xd1abc4bb , 0xcffb7668 , 0xa8cae9bb , 0xc1c4c5f0 , 0x244a8228 ] )
+  , Mont::unsafe_make( [ 0xc2eaa7c4 , 0x3dadf724 , 0x575c7e07 , 0xc51336d7 , 0x1f971dcb , 0x989515c7 , 0xc5db59f4 , 0x17c6e62c ] )
+  , Mont::unsafe_make( [ 0xaedd0e6a , 0x9a785811 , 0x31c5d29d , 0x4abd2d0e , 0xf07ad24d , 0xb5691668 , 0x9055812c , 0x21a1412a ] )
+  , Mont::unsafe_make( [ 0x65873e06 , 0x1a1f57ac , 0x2cd63bce , 0x09a87109 , 0x92b291e9 , 0xb2a301dc , 0x3e0b640e , 0x02df60a7 ] )
+  , Mont::unsafe_make( [ 0x8f3279c8 , 0xf185359b , 0xfad0e2fb , 0x6e07da68 , 0xaa5c94eb , 0xf5906abc , 0x351fab4a , 0x1b9106f8 ] )
+  , Mont::unsafe_make( [ 0x5f251d5c , 0xcbdee6a3 , 0xda47733b , 0x2af53ea3 , 0x463dbb63 , 0xa70bd5f2 , 0x8d643595 , 0x16678a74 ] )
+  , Mont::unsafe_make( [ 0x4f28de64 , 0xe19b312a , 0x11e9a52f , 0xb8539e8e , 0xffce5651 , 0xd256feab , 0x36613add , 0x24330960 ] )
+  , Mont::unsafe_make( [ 0x15b94415 , 0xf0f41cae , 0x1699fc8f , 0xb0576cef , 0x8826e716 , 0x94fdfec7 , 0x70b7152e , 0x0ce6b8ea ] )
+  , Mont::unsafe_make( [ 0xd475eefd , 0x3a3d8ea5 , 0x066ab8ed , 0x62737792 , 0xff093bf6 , 0x0168ce46 , 0xd40581f3 , 0x22e35562 ] )
+  , Mont::unsafe_make( [ 0x25f52461 , 0x3fba7e3f , 0xc9009c62 , 0x6f00cba9 , 0x04d7e56e , 0x0d00414b , 0xabffb18f , 0x1791a6cc ] )
+  , Mont::unsafe_make( [ 0x6657e1e7 , 0x46cd16b3 , 0x95051081 , 0xef98a693 , 0x64442cc7 , 0x608779cd , 0x9f50cba0 , 0x195cb5c6 ] )
+  , Mont::unsafe_make( [ 0x8cb43361 , 0xc560b700 , 0xc471188a , 0x22314018 , 0x6b9b9e28 , 0xe0bd05c5 , 0x0015c513 , 0x2b524aea ] )
+  , Mont::unsafe_make( [ 0x216da199 , 0x8c442371 , 0x1fa0452f , 0xb966d480 , 0x38fab814 , 0x9e9fbaeb , 0x0ad033f4 , 0x12eec225 ] )
+  , Mont::unsafe_make( [ 0x7f96ec54 , 0x2d5ba11d , 0xd737c9c4 , 0xbf1c89e3 , 0x556186fa , 0x8d822985 , 0x4459d14e , 0x24e8ad75 ] )
+  , Mont::unsafe_make( [ 0x540b2747 , 0x1cb26e71 , 0xe6a63327 , 0x68161264 , 0x38e0606c , 0x99b56d80 , 0x74855adf , 0x2f654957 ] )
+  , Mont::unsafe_make( [ 0x87cce954 , 0x384b65d3 , 0x4b1c0bbd , 0x8b2d8a75 , 0x81e9b9bc , 0x905ccb8b , 0x73336fcd , 0x242d14e3 ] )
+  , Mont::unsafe_make( [ 0xbe937f99 , 0x97e6072e , 0x0981cc4d , 0x3920e85b , 0x042a30ea , 0x519c47be , 0x82de6d66 , 0x09a3037e ] )
+  , Mont::unsafe_make( [ 0xb6d9606e , 0x79fd1850 , 0x564b3fe3 , 0xab7b220c , 0x9a49151c , 0x0351cf0a , 0xfa4a3a25 , 0x1627baa8 ] )
+  , Mont::unsafe_make( [ 0x5285441a , 0xaca66625 , 0xdab02f28 , 0x29a6edfe , 0xeb40e47e , 0xd7741aa6 , 0x676c8132 , 0x1caaad04 ] )
+  , Mont::unsafe_make( [ 0xf7885875 , 0x45fd459a , 0xebf74255 , 0xced596f7 , 0x610754d0 , 0xb9ca615a , 0xad26189c , 0x0bfa209a ] )
+  , Mont::unsafe_make( [ 0x19c08627 , 0x6db56d1e , 0x139747bd , 0x0bf01a87 , 0xa9237344 , 0x3f82c1ee , 0x56d44867 , 0x172ea6f2 ] )
+  , Mont::unsafe_make( [ 0x769c00d5 , 0x1d2d8437 , 0xc0e6add4 , 0x2080dbf7 , 0xa7fadd85 , 0x45bd528a , 0x4f7c7727 , 0x032b6406 ] )
+  , Mont::unsafe_make( [ 0xd1e024d5 , 0xda3c4aa4 , 0x4d90c47a , 0x0eac7f81 , 0xaafeee36 , 0x3523e191 , 0xc3eb35e8 , 0x0cf39f9b ] )
+  , Mont::unsafe_make( [ 0xe53c0318 , 0x3215d26f , 0x947e9878 , 0xabbd0120 , 0x3ef5624e , 0x3f3b5d64 , 0xf687f663 , 0x19fb38c1 ] )
+  , Mont::unsafe_make( [ 0x24454b35 , 0x4d3719a1 , 0xc480d9d1 , 0x0a98e086 , 0xc047bc7c , 0x7d5ae69b , 0xb28a19f9 , 0x088b5354 ] )
+  , Mont::unsafe_make( [ 0x5cd51abd , 0x06a97994 , 0x9bc704f9 , 0xcd72f062 , 0xdc588749 , 0x83353b67 , 0xa0d9aa81 , 0x1e32cf09 ] )
+  , Mont::unsafe_make( [ 0xbc746c0c , 0x36343341 , 0x14028761 , 0x373bd3e9 , 0x7e1537dc , 0xc4b5b882 , 0xb9724bab , 0x05b3969f ] )
+  , Mont::unsafe_make( [ 0x3b3e7c3d , 0x7c0aa428 , 0x53c0d17b , 0x3f64f68f , 0x1efb0b2b , 0xb373c40e , 0x84c33c51 , 0x0b6b4a7c ] )
+  , Mont::unsafe_make( [ 0x717099da , 0x353714e8 , 0x1d6f24e3 , 0x4fbf9629 , 0x15884a2f , 0x99adb25c , 0xf39c0f2f , 0x135e1659 ] )
+  , Mont::unsafe_make( [ 0xaca703a0 , 0x99c14198 , 0x7aecbd71 , 0x99792087 , 0x5ce74463 , 0x5b45964d , 0x85f626ce , 0x13eb76ed ] )
+  , Mont::unsafe_make( [ 0xa74fce53 , 0x5332939a , 0xe25c62ff , 0xe668db54 , 0xc75cd99a , 0xdb00651c , 0x719dd89e , 0x1f7d7544 ] )
+  , Mont::unsafe_make( [ 0x8ebcf21a , 0x6155093a , 0x6df58e99 , 0x6f7d6890 , 0xd7072e95 , 0x50ebc3bf , 0x5e3c9867 , 0x06967739 ] )
+  , Mont::unsafe_make( [ 0xf477971e , 0x19077a47 , 0x6c96c232 , 0x536e0489 , 0xbc583447 , 0x12e6170b , 0xfefb096b , 0x2603ac2d ] )
+  , Mont::unsafe_make( [ 0x128da709 , 0x15235b24 , 0xf5cf7854 , 0xb16cffaa , 0x80ad5f1e , 0xe7a7d701 , 0x081c9951 , 0x18085d4a ] )
+  , Mont::unsafe_make( [ 0xf11155c1 , 0x32a056d1 , 0x6cd6fe14 , 0xd68c460b , 0x60d22749 , 0x20c1122e , 0xde60cca3 , 0x0693ef0d ] )
+  , Mont::unsafe_make( [ 0xd78a83ca , 0x7b2a8190 , 0x8a5f53ff , 0x0c495993 , 0xa51ab993 , 0x19203888 , 0x1a85b523 , 0x1081fab0 ] )
+  , Mont::unsafe_make( [ 0xa3775ca1 , 0xcc69bb79 , 0x37938e7a , 0x7904fbfc , 0x2d79c629 , 0xf967edf5 , 0x769ebc3e , 0x02d74ead ] )
+  , Mont::unsafe_make( [ 0x30eddc7f , 0x450976bd , 0x2c9ca3e2 , 0x3e255d16 , 0xc431399a , 0xe82d70ec , 0x99cbc366 , 0x0c910638 ] )
+  , Mont::unsafe_make( [ 0xecb4c6df , 0xca87a605 , 0x1c080ba8 , 0x9bcfd0f5 , 0xb97783ad , 0x4c241136 , 0xaf00a70f , 0x2ab0b3d6 ] )
+  , Mont::unsafe_make( [ 0xf14e31e9 , 0x426d1185 , 0x2cc7dbfc , 0x43ffeeb2 , 0x969e9f97 , 0x35c1526d , 0x2224b6b6 , 0x1d4b2dac ] )
+  , Mont::unsafe_make( [ 0x2454bb27 , 0xa982bc5f , 0xb13c00ce , 0x8569ddcc , 0x50097d64 , 0x30a69a69 , 0x9ac1b4f0 , 0x1ecc6592 ] )
+  , Mont::unsafe_make( [ 0x1c5939a0 , 0x6514fb39 , 0xe77ecb17 , 0xb385b534 , 0xfe813877 , 0x351b156d , 0x35132534 , 0x1559df6f ] )
+  , Mont::unsafe_make( [ 0xb6c064e7 , 0x29239532 , 0x02d83939 , 0xaa6e59c4 , 0x1ddd0699 , 0x851aab09 , 0x1404214c , 0x2b642eeb ] )
+  , Mont::unsafe_make( [ 0xc5c062d6 , 0xceb3b347 , 0xf6f3db3f , 0x24539e05 , 0x6fed68f8 , 0x714122d5 , 0x03262a81 , 0x17e00c83 ] )
+  , Mont::unsafe_make( [ 0x1fa75671 , 0x6aa174b3 , 0x55794451 , 0x04eff09f , 0x347737e7 , 0x52315a18 , 0xadbda0b3 , 0x1804b2e6 ] )
+  , Mont::unsafe_make( [ 0x37d57b78 , 0x582ad326 , 0x9a638bb9 , 0xf9837d1f , 0xe26ed2bb , 0xabf7dbd5 , 0x4c8c579b , 0x247b8e42 ] )
+  , Mont::unsafe_make( [ 0xc9ff8d8e , 0x2cd3d148 , 0xa587463c , 0x22e18941 , 0xb219db06 , 0x85dc9ee9 , 0x08719541 , 0x0575efce ] )
+  , Mont::unsafe_make( [ 0x14a1844e , 0xcfdb778e , 0xfc8fa91c , 0x2efcb787 , 0x70e3613f , 0xfe2a1fff , 0x9d2126ac , 0x18baea25 ] )
+  , Mont::unsafe_make( [ 0xf1760613 , 0x4bc6f1d3 , 0x11d25657 , 0x1537858e , 0x427724c3 , 0x938ebd67 , 0xf2aef8ba , 0x18671225 ] )
+  , Mont::unsafe_make( [ 0x709ccf39 , 0x3994e04a , 0xd633149e , 0x8ab11ba4 , 0xab84b4ef , 0xcc27b4a5 , 0x20670163 , 0x069f9422 ] )
+  , Mont::unsafe_make( [ 0xe93b7da9 , 0x4c7d2279 , 0x1579427a , 0x1829ef40 , 0xafbfad4f , 0x6bc7f630 , 0x82081692 , 0x1dd51a43 ] )
+  , Mont::unsafe_make( [ 0x58538ca1 , 0xea83e55d , 0xe6cea7aa , 0x5b2f4f11 , 0x660c1928 , 0x8378cc5a , 0x04a8fc36 , 0x2e07b275 ] )
+  , Mont::unsafe_make( [ 0x75368973 , 0xe0d38f11 , 0x0b8f9c41 , 0x96170333 , 0xfea42000 , 0x56096566 , 0x19204f07 , 0x2a936a24 ] )
+  , Mont::unsafe_make( [ 0xb54fec7f , 0x6ab2dc48 , 0x6edbeadb , 0x0bb9ca30 , 0x8a7a4d43 , 0xb968794f , 0x58c4ed8a , 0x1ce28544 ] )
+  , Mont::unsafe_make( [ 0x3b0e0cfd , 0x02ab7baa , 0x9351736d , 0x70a252c0 , 0xf2f260bc , 0xb33e7d6c , 0xe72368e3 , 0x20566fed ] )
+  , Mont::unsafe_make( [ 0x02d46fda , 0x1aff422a , 0xff48c111 , 0x92b183fd , 0x224b48ab , 0x6243f50f , 0xe5344038 , 0x05f53e32 ] )
+  , Mont::unsafe_make( [ 0x58634f88 , 0xf7c5ea1c , 0x54f2f9e1 , 0xe664e265 , 0x6db6a713 , 0x1d5ae370 , 0x2bd5db02 , 0x0720fff0 ] )
+  , Mont::unsafe_make( [ 0x02ec6b3e , 0xe4d5443f , 0x61d906c6 , 0x6649f56d , 0x43a723f2 , 0xa55fa819 , 0x02e4d958 , 0x0a673cff ] )
+  , Mont::unsafe_make( [ 0xf06fb74c , 0x09d2fab2 , 0x75515e2a , 0x3b0fcbb5 , 0x9c083eec , 0x146720f8 , 0x4874c0d9 , 0x1d841c56 ] )
+  , Mont::unsafe_make( [ 0xb7b743af , 0x1efdc28d , 0xce9222ef , 0x51650aab , 0xf4de2474 , 0x87df82ad , 0x0671b4d4 , 0x1c58d0b1 ] )
+  , Mont::unsafe_make( [ 0x8b031c66 , 0x0f96b171 , 0x2981534a , 0xce3a54e3 , 0x8e011814 , 0xd872eefd , 0x90f6376d , 0x2ff9015c ] )
+  , Mont::unsafe_make( [ 0x57c38781 , 0x1fd1b409 , 0x91497bb7 , 0x7c715849 , 0xf8d5001f , 0xcfe53d8c , 0x885ed226 , 0x1c1f2c54 ] )
+  , Mont::unsafe_make( [ 0x0457291f , 0x142c50dd , 0x079d32b8 , 0x8825e3fa , 0x024741e6 , 0xaa4a85b3 , 0xff8d0176 , 0x0e3b9fd7 ] )
+  , Mont::unsafe_make( [ 0x0095b482 , 0x4d8b668e , 0x9ebbf5a4 , 0xd8212db8 , 0x894bf050 , 0xe0f95cf5 , 0x14ca8327 , 0x1f3b4e1b ] )
+  , Mont::unsafe_make( [ 0xea7de42e , 0x43ab9be5 , 0x957d2f81 , 0xc4d0ff05 , 0xb7f2645e , 0xfea011e7 , 0xb352fbd0 , 0x0df1b1ba ] )
+  , Mont::unsafe_make( [ 0x3e027e67 , 0x59a659dd , 0xa925ca6c , 0xeaaa9b75 , 0xc8f870ba , 0x66074ca7 , 0xaee60b4a , 0x2120f984 ] )
+  , Mont::unsafe_make( [ 0x254e4f32 , 0x50ef08c3 , 0x3bff9162 , 0x61dfb273 , 0x0f033230 , 0x5b829b06 , 0xa29f190d , 0x20b8caf7 ] )
+  , Mont::unsafe_make( [ 0x9930111b , 0x0c85c4f6 , 0xc3ffa334 , 0x4e247706 , 0x42a09aca , 0x960d60c6 , 0xbda43b95 , 0x2f29b1d9 ] )
+  , Mont::unsafe_make( [ 0xf2fc74f5 , 0xe0f23713 , 0x83779927 , 0x11a34894 , 0x52d7285b , 0x833271a8 , 0x9114f9d1 , 0x1322eeb3 ] )
+  , Mont::unsafe_make( [ 0x3b40b336 , 0xbe8f971d , 0xa480707c , 0xcbe6c214 , 0x21428323 , 0xb8daccbf , 0x8971a6dc , 0x2068b680 ] )
+  , Mont::unsafe_make( [ 0x5f5ee904 , 0x30a304de , 0x0a322976 , 0xe982d9fb , 0xa02800eb , 0xb66e91ab , 0x1ad9cf38 , 0x185c08ec ] )
+  , Mont::unsafe_make( [ 0xcfaa8323 , 0x2bc71a16 , 0x1802b04c , 0xb249cb54 , 0x56d1efdc , 0x4a44ca2f , 0x84d21b67 , 0x2f2e026c ] )
+  , Mont::unsafe_make( [ 0x80a64bcc , 0x736ea872 , 0x09874bff , 0x96c34be8 , 0x87b04419 , 0xcfb33cc9 , 0x217748e3 , 0x1ff2812e ] )
+  , Mont::unsafe_make( [ 0xf12af6e6 , 0x682932fc , 0xf1ffa279 , 0xdc1768f9 , 0xcad04538 , 0x5f8cdd4a , 0x0aa2b740 , 0x1f5bacbd ] )
+  , Mont::unsafe_make( [ 0xa125d766 , 0xfe562877 , 0xb3697a6c , 0x4175c8c3 , 0x1017fc2d , 0x68b8b253 , 0xa4b58fe1 , 0x1a0de5fb ] )
+  , Mont::unsafe_make( [ 0x92d9197e , 0x136b17ee , 0xef33773e , 0xf009ff5d , 0x309d512e , 0x62022347 , 0xa83734a7 , 0x2ae5468d ] )
+  , Mont::unsafe_make( [ 0x38e1ae73 , 0xbb1d20b3 , 0x87ff9fa9 , 0x497d1ed2 , 0xca6b0527 , 0xf62b9229 , 0x32ee40ee , 0x22294da4 ] )
+  , Mont::unsafe_make( [ 0xb0c2e3c8 , 0xb2cb8019 , 0x6faa43b5 , 0x83624d09 , 0x7d224f88 , 0x97ba934f , 0x9967f39f , 0x15d42e84 ] )
+  , Mont::unsafe_make( [ 0xf8ab8db1 , 0x01e26401 , 0x1c0c97bb , 0xbb2b0ea3 , 0x7dde7578 , 0x09a3c901 , 0x228f978a , 0x2550e462 ] )
+  , Mont::unsafe_make( [ 0x18321bc9 , 0xfc0196b8 , 0x03999a1e , 0x9da75135 , 0x36b4a39f , 0x9de72b6f , 0xc4d155cb , 0x0b8dbb25 ] )
+  , Mont::unsafe_make( [ 0xa39a5a35 , 0xc071bf38 , 0xe3d443e2 , 0x8743d9b8 , 0x28860877 , 0x49cdca3a , 0xf7795701 , 0x25b47d07 ] )
+  , Mont::unsafe_make( [ 0x6cf93830 , 0xa5262a23 , 0xbb0d4276 , 0x2f746e41 , 0x46673522 , 0xeb25edaa , 0x600ff8b3 , 0x085d9552 ] )
+  , Mont::unsafe_make( [ 0xda0eff55 , 0x29aa1988 , 0xdb9ce1d5 , 0xb25ee5ec , 0xd19cb697 , 0x96f57b1b , 0x28cd993e , 0x072ea942 ] )
+  , Mont::unsafe_make( [ 0x24ddcf88 , 0x3df2e516 , 0x97c70cf1 , 0x105c9fb2 , 0x9e5450cd , 0x0ae39b7c , 0x45fbdcb4 , 0x1230dc8d ] )
+  , Mont::unsafe_make( [ 0x103f00b2 , 0x97117c02 , 0x2fc44119 , 0x11e4b3e4 , 0x5854ee7b , 0xf6c29289 , 0x79b17ccd , 0x1a5e56c1 ] )
+  , Mont::unsafe_make( [ 0x53c299f9 , 0x7da5e9e9 , 0x6440be55 , 0x6432ee32 , 0x5c3f5acd , 0x152d37d8 , 0x03f22c02 , 0x1758490e ] )
+  , Mont::unsafe_make( [ 0xe238acc7 , 0x1fb68d1c , 0x63458907 , 0x8c217647 , 0x5214b221 , 0x7a25969f , 0x2d2808e0 , 0x13ae0e96 ] )
+  , Mont::unsafe_make( [ 0x2c179d13 , 0x39ba3ce4 , 0x96254ac0 , 0x65e4c25a , 0xf02cd7a8 , 0x1900aa20 , 0x35aa1fca , 0x1016ad8b ] )
+  , Mont::unsafe_make( [ 0xf624a38e , 0xfb28c6e3 , 0x131f5fd5 , 0xb20ced1e , 0x507a68bd , 0xbafab3fd , 0xb8b5562f , 0x15639a44 ] )
+  , Mont::unsafe_make( [ 0xb19632b9 , 0xc5b17754 , 0x2e04621d , 0xf6de0243 , 0xed383846 , 0xda20a0d7 , 0xda85ecdf , 0x093c8f9d ] )
+  , Mont::unsafe_make( [ 0xa2a44e09 , 0xa3cef955 , 0x6f5d7fab , 0xe4b12c33 , 0x944006f3 , 0xfbe19c58 , 0x36fe3901 , 0x2dcb1ed2 ] )
+  , Mont::unsafe_make( [ 0x1f7979e4 , 0xe2f773f1 , 0x1191594a , 0x2fe46e15 , 0x5850363a , 0x18ebd87e , 0xcf054e76 , 0x0a1c8dbf ] )
+  , Mont::unsafe_make( [ 0x51d889bd , 0x1b7b90db , 0x3d02cf0d , 0xa3dffa8f , 0x6e9d9bfa , 0x6ceb2876 , 0xa0b61804 , 0x2fa5be7d ] )
+  , Mont::unsafe_make( [ 0xc7c70ad4 , 0x8d672e60 , 0x01d3aeb8 , 0xe123b88b , 0x773e564b , 0xea5d4cf9 , 0x90d1582f , 0x25590c22 ] )
+  , Mont::unsafe_make( [ 0x68864c22 , 0xf5ff6e6e , 0x57d22c73 , 0x80846a46 , 0x3486169e , 0x0a88fc94 , 0x61c32d77 , 0x29e0fc16 ] )
+  , Mont::unsafe_make( [ 0xbcd81367 , 0xed25751c , 0x33d6561b , 0xde748a04 , 0xbb6da75a , 0xd5c4f7e0 , 0xee99ca2d , 0x06754037 ] )
+  , Mont::unsafe_make( [ 0x78e9061e , 0xdf41f10a , 0xbd8c58d0 , 0x51baf5a7 , 0xf3daac80 , 0x4ad52962 , 0x454208f1 , 0x2f6c3a56 ] )
+  , Mont::unsafe_make( [ 0x3bf5f725 , 0x5f47b75c , 0x888557e3 , 0x8703285b , 0xa16e6144 , 0x76ab9de5 , 0x66da076b , 0x062550af ] )
+  , Mont::unsafe_make( [ 0x91817438 , 0xdf91ab8e , 0x6f3955a8 , 0x59021e4c , 0x5e6401f8 , 0x8d446645 , 0xd708dd53 , 0x2f3df36a ] )
+  , Mont::unsafe_make( [ 0x27190d27 , 0x5428f0d1 , 0x750d8d6e , 0x7d145122 , 0x606a10b1 , 0x29ce6de5 , 0xd59558b1 , 0x23911323 ] )
+  , Mont::unsafe_make( [ 0x1a53366b , 0x3e850101 , 0xf220f3a3 , 0x0c4c4abd , 0xcb3ccfa4 , 0x9dd55b03 , 0x1952c886 , 0x19f48fd0 ] )
+  , Mont::unsafe_make( [ 0x15c1b6e4 , 0xeabfd588 , 0x3627499f , 0x78ba52bb , 0x0772169b , 0x117bb91d , 0xaa48e3d5 , 0x16afaead ] )
+  , Mont::unsafe_make( [ 0x92cc4e5c , 0xc1434ec0 , 0xb6c46b25 , 0x0bd7f6f8 , 0xd03cfb65 , 0x7577933a , 0xee27964a , 0x088a6c46 ] )
+  , Mont::unsafe_make( [ 0x473ecca7 , 0xe7e63240 , 0x3172bdb9 , 0xdb771994 , 0x1347acaf , 0x599b3f14 , 0xdb14af81 , 0x1716c7d9 ] )
+  , Mont::unsafe_make( [ 0x10fa60d4 , 0xb6e7b67b , 0x8eeb8fff , 0xb85bc97f , 0x37dd7230 , 0x8a890a4a , 0x643a57d8 , 0x09ce8db2 ] )
+  , Mont::unsafe_make( [ 0x9fc7ea7c , 0x5bb3a284 , 0x7d36b06d , 0xaf457e88 , 0xc4d470dd , 0x398a8d34 , 0xafac1bd1 , 0x22b0d70c ] )
+  , Mont::unsafe_make( [ 0x40ca6975 , 0xbd8d82d0 , 0xea398391 , 0x805908b7 , 0x74108914 , 0xca0a8de6 , 0x5dea12fa , 0x0133478a ] )
+  , Mont::unsafe_make( [ 0xb4af9dba , 0xb0f2349b , 0xbceb8693 , 0x10eb11db , 0x297f72c3 , 0x8fb8c71d , 0xc0a4654f , 0x0bdebb59 ] )
+  , Mont::unsafe_make( [ 0x6fce3ef8 , 0x65a29a1b , 0x24339f6b , 0x6864a2b8 , 0x18b18077 , 0x7d1556c9 , 0x545494a5 , 0x2c959a04 ] )
+  , Mont::unsafe_make( [ 0x5c479e55 , 0x39dee2c7 , 0x6eecc30c , 0xc75c2b40 , 0x118a12d7 , 0x5b10d602 , 0x0ee7a108 , 0x19b0d416 ] )
+  , Mont::unsafe_make( [ 0xebab5af7 , 0x3eae1ca5 , 0xedd2a8bb , 0xa930c3ef , 0x85dbdb68 , 0x44b2a0c5 , 0x73dfa196 , 0x242cd0be ] )
+  , Mont::unsafe_make( [ 0xc212b03e , 0x2db857fd , 0xeb54b088 , 0x16f43723 , 0x295ae63d , 0xe7115c7c , 0xb263c029 , 0x1f3ccac6 ] )
+  , Mont::unsafe_make( [ 0xe0811c6f , 0xf22a1b1f , 0xf8b5d509 , 0x8046bf4b , 0xc330f9f2 , 0xf05f436b , 0x4bd44a12 , 0x08d7da0e ] )
+  , Mont::unsafe_make( [ 0xeac09240 , 0xc217b92a , 0xd35574a0 , 0x118a414e , 0xd942ccb8 , 0xf7afb6f8 , 0xb1a4fd8e , 0x1f84eede ] )
+  , Mont::unsafe_make( [ 0xf61b795a , 0xcdcb42c5 , 0xb48290d3 , 0x4331615e , 0xece32aa0 , 0x7031a4fa , 0x676b5425 , 0x0194bd6e ] )
+  , Mont::unsafe_make( [ 0x1710ef43 , 0x1d16e129 , 0x69e00da4 , 0xb5a09e29 , 0xe966867b , 0xe0a1301a , 0xd780fbd9 , 0x188813a8 ] )
+  , Mont::unsafe_make( [ 0xee782f3d , 0x5b333b96 , 0xffabe1f2 , 0xc5c8f3b2 , 0x3d52fe43 , 0xf7f71b91 , 0xd4e22085 , 0x21bb1ea8 ] )
+  , Mont::unsafe_make( [ 0xc3c4eff4 , 0x9714017b , 0x0e497a00 , 0xd2f08186 , 0x1cb0f5a7 , 0x338d5508 , 0xa85fb61c , 0x2c5d670a ] )
+  , Mont::unsafe_make( [ 0x85f4dfe1 , 0xd369199c , 0x38abebe8 , 0x4fc42c9f , 0xabd2af8d , 0x0206a93a , 0xc7d2c2b2 , 0x0528a1d0 ] )
+  , Mont::unsafe_make( [ 0x9d702ca8 , 0x143509e5 , 0x1d1a8822 , 0x1b79f7d7 , 0xbbe1680d , 0x3c41259e , 0xd2f3e006 , 0x079aff2c ] )
+  , Mont::unsafe_make( [ 0x43f0ee80 , 0xa56836b3 , 0x65bf46a0 , 0x36aef247 , 0xaad72d71 , 0x9c895d58 , 0xc8eccb0c , 0x0b8eb0f1 ] )
+  , Mont::unsafe_make( [ 0x260d132b , 0xd7308594 , 0x02e42a03 , 0xe7a541ec , 0x6b7e8940 , 0x71769242 , 0xd1ded1fb , 0x02f30e2e ] )
+  , Mont::unsafe_make( [ 0x8ce6e655 , 0x81216931 , 0xba95d0cb , 0x478fa5c4 , 0x0c707bd5 , 0x46e48046 , 0xc6537524 , 0x26bb8d8f ] )
+  , Mont::unsafe_make( [ 0x210dc4ba , 0x20010fe5 , 0xec7b659e , 0x94606d36 , 0x274dca1e , 0x72f0c30a , 0x460d4f7b , 0x1f4c4b29 ] )
+  , Mont::unsafe_make( [ 0xe4dabaf9 , 0xbdcdf263 , 0x3f70336d , 0x185a03d1 , 0xe38ca11e , 0xe3061515 , 0x2ef1f0a0 , 0x116fd21d ] )
+  , Mont::unsafe_make( [ 0xbadad0b0 , 0xa7f70fee , 0xcf9236bf , 0x6ad54ad6 , 0xe316257a , 0x97b04517 , 0x67417472 , 0x1a562d28 ] )
+  , Mont::unsafe_make( [ 0x1468d1fe , 0x78000d04 , 0xf19b3b14 , 0x5ea01381 , 0x6ce89d8d , 0x4c265e0b , 0x141dc231 , 0x232c79c8 ] )
+  , Mont::unsafe_make( [ 0x062d9ce0 , 0xba7ffe2c , 0x86021715 , 0x01663a7e , 0x1a435a34 , 0x3ff9bba2 , 0x733496f3 , 0x26e362d9 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// external mixing matrix (`t x t`, flattened)
+pub const CONST_M: [Mont; 64] = 
+  [ Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x10347fb5 , 0xe1927edc , 0x13074c96 , 0x15482219 , 0xc1cf5809 , 0x9ed2715e , 0x7597d2b8 , 0x2a968c59 ] )
+  , Mont::unsafe_make( [ 0xcbb1af36 , 0x783657ea , 0x0218341d , 0x79ee316f , 0x5ba466f1 , 0xcf168768 , 0x30c90c63 , 0x1d52e0e6 ] )
+  , Mont::unsafe_make( [ 0x7ed7f8de , 0x24d33919 , 0x1ee4a45d , 0x0b305ae3 , 0xa285676d , 0xe26f53f1 , 0xdb57a297 , 0x191ea399 ] )
+  , Mont::unsafe_make( [ 0xff9e4f1d , 0xf1fe47b4 , 0xa708f057 , 0x042443fc , 0x44820d98 , 0xca263621 , 0x0b9da342 , 0x1117dfea ] )
+  , Mont::unsafe_make( [ 0x5326decd , 0xad5a4534 , 0xb0c68695 , 0xb34d1fb1 , 0x670611d5 , 0x9e4ae2ce , 0x1aade6d1 , 0x2d50c8b9 ] )
+  , Mont::unsafe_make( [ 0xff67658d , 0x9229c5ff , 0x7d64cf7c , 0xa7cd285e , 0x84e0f885 , 0x2e9d2585 , 0xd71547f1 , 0x1c5ec1e7 ] )
+  , Mont::unsafe_make( [ 0xfca2240b , 0x26dc3f69 , 0xf4b2504e , 0x7275fade , 0x3cc71a9c , 0xd9b37615 , 0x6642b7f0 , 0x0f4c91b2 ] )
+  , Mont::unsafe_make( [ 0x1ce44eb9 , 0xa44f4ce9 , 0x96a4526d , 0xd2b3b7e2 , 0xc01cd83a , 0xaf5f5999 , 0x5af71cfa , 0x2633983e ] )
+  , Mont::unsafe_make( [ 0x722c8813 , 0x30487c6d , 0x527d6985 , 0xeb319ebb , 0xfa8e5594 , 0x3ece65ef , 0x55eeecd4 , 0x1c771bbf ] )
+  , Mont::unsafe_make( [ 0x8ce6207b , 0x327f363b , 0xcf05d3f0 , 0x05762a08 , 0xe941a6c4 , 0x0e65703b , 0xef398d8f , 0x0a5eaa5a ] )
+  , Mont::unsafe_make( [ 0x9baa6068 , 0xd02efaa0 , 0xd0bf5124 , 0xf7a58094 , 0x74ae8ff6 , 0x8cd2d609 , 0x296a0b09 , 0x1b820864 ] )
+  , Mont::unsafe_make( [ 0xac027097 , 0xa77d0650 , 0x5373ad3e , 0x6880be99 , 0xecaa3abb , 0x94e2745e , 0x59613b8e , 0x28ae5c01 ] )
+  , Mont::unsafe_make( [ 0xa8f40af1 , 0xa188fa32 , 0x932f7c2e , 0x2678597a , 0x4735603f , 0x18fda36e , 0x93b61794 , 0x1f4098e8 ] )
+  , Mont::unsafe_make( [ 0x6b72facd , 0x2b8fd37b , 0x05d057eb , 0xd8968977 , 0x86a34ce1 , 0x95a97686 , 0xf2e8cfa1 , 0x0a486f8f ] )
+  , Mont::unsafe_make( [ 0x597da227 , 0x32aacc16 , 0x9238716f , 0x58c91b9c , 0x1fdaa9e6 , 0x551a461b , 0xbc224f9a , 0x152c5904 ] )
+  , Mont::unsafe_make( [ 0x77ae014b , 0x644e2094 , 0x06dbc7c2 , 0xd826a529 , 0x102d9137 , 0x52a48302 , 0x33a00547 , 0x2e2bd016 ] )
+  , Mont::unsafe_make( [ 0x70f84f9c , 0x03657101 , 0xde5787ba , 0x1188a5fb , 0x52e6b1a7 , 0x3e13b3c7 , 0x8b6c9105 , 0x20c3a8a0 ] )
+  , Mont::unsafe_make( [ 0xf258aa08 , 0x37e9fa58 , 0x16714126 , 0xe19ed5f9 , 0xf92045e5 , 0xfbfbf0be , 0xbca21375 , 0x1c8622d2 ] )
+  , Mont::unsafe_make( [ 0x993e7b51 , 0xe31b5dc4 , 0x5e3f6830 , 0x7477eb84 , 0x8ebc71bf , 0xd700d11b , 0x282ba8ce , 0x1a23fe2f ] )
+  , Mont::unsafe_make( [ 0x296fc6f7 , 0xc8b26043 , 0x2582f51c , 0x899896e5 , 0xb1c7067e , 0x60f84b5c , 0xa0c066da , 0x122b293c ] )
+  , Mont::unsafe_make( [ 0xa2024e64 , 0xba141bad , 0x068472ba , 0x11316690 , 0xd8cb23a8 , 0xab15bc78 , 0xb7488b91 , 0x12f1a41c ] )
+  , Mont::unsafe_make( [ 0xe0f4c486 , 0xee28a597 , 0xb1e61d4d , 0x1373a6dc , 0xa889d0da , 0x71e0869e , 0x8cb1b37b , 0x0c12a897 ] )
+  , Mont::unsafe_make( [ 0xfdc19fc8 , 0x3ef8b8e0 , 0xe1f9dcfc , 0x59dc4372 , 0xcfaddf15 , 0x9e7318cf , 0xe24ef836 , 0x2fdf1459 ] )
+  , Mont::unsafe_make( [ 0x90f639d4 , 0x66bf5ed3 , 0x735ff1f7 , 0x4c3ad200 , 0x111697d9 , 0x74eb895d , 0x8e6b75ed , 0x22b60870 ] )
+  , Mont::unsafe_make( [ 0x5f98c2be , 0x743553eb , 0x52f2e522 , 0xfdb60bfa , 0x280ac6a5 , 0x0eb83e7b , 0x63123974 , 0x036484b8 ] )
+  , Mont::unsafe_make( [ 0x336b2bcd , 0x057e69a4 , 0x64457537 , 0x07f30bbf , 0x83ab3376 , 0x2adc43af , 0xc4186980 , 0x1cb21b6e ] )
+  , Mont::unsafe_make( [ 0xa9c704c1 , 0x547db540 , 0x850ba845 , 0x09bd792f , 0x244ae1b9 , 0x399e17cd , 0x4d87eea6 , 0x039ee5e5 ] )
+  , Mont::unsafe_make( [ 0xf692fff9 , 0xe6f1d3db , 0x4de807a3 , 0x193fc091 , 0x62f2f1b0 , 0x57346736 , 0xe60384be , 0x105e83c3 ] )
+  , Mont::unsafe_make( [ 0x5155d59b , 0x9d78d12f , 0x99968e39 , 0x5ac3cf47 , 0x693ff608 , 0xd917bcf5 , 0x897aa0ff , 0x2202d5b4 ] )
+  , Mont::unsafe_make( [ 0x643ae516 , 0xc173c626 , 0x6bb284e2 , 0x6ece99de , 0x301e905d , 0x193e6ea9 , 0x54b8c6ff , 0x046d1996 ] )
+  , Mont::unsafe_make( [ 0xb170f6f5 , 0x6175a88c , 0xc830947d , 0x00ca8f93 , 0xe5aad452 , 0x586cd165 , 0x4ead679d , 0x09a0c83f ] )
+  , Mont::unsafe_make( [ 0x6228f056 , 0xb00e5542 , 0x9a9461db , 0xab672526 , 0x6c802343 , 0x34338b9d , 0xf521bc21 , 0x26274666 ] )
+  , Mont::unsafe_make( [ 0xd13bfab7 , 0xe765c293 , 0xab0f4071 , 0x0b1f09e3 , 0x71f6bc22 , 0x14f97c4a , 0x2b363175 , 0x23f546cb ] )
+  , Mont::unsafe_make( [ 0xc0dde997 , 0x8125f97b , 0x3e4b5519 , 0x922f9592 , 0xe0c84054 , 0x3e26e5fc , 0xfff91b8a , 0x07e5c9a3 ] )
+  , Mont::unsafe_make( [ 0x7cbbef26 , 0x22a77792 , 0xb0b78f77 , 0xb54889b6 , 0xc57c25a4 , 0x560f20d5 , 0x40aa403f , 0x150ec35a ] )
+  , Mont::unsafe_make( [ 0x04b1ab63 , 0x9875a0af , 0x55eaf3b2 , 0x72f1a777 , 0x9ce7a042 , 0x4933de61 , 0xbce7d72e , 0x276347b8 ] )
+  , Mont::unsafe_make( [ 0x7024fb42 , 0x631d6a00 , 0x99386501 , 0xa9fe14e1 , 0x9b994c65 , 0xa0fa612a , 0x9bcac476 , 0x1575df8b ] )
+  , Mont::unsafe_make( [ 0x0c2063a8 , 0xd3162055 , 0x606aea1a , 0xc34eae3f , 0xb38c8144 , 0xf19c5b2b , 0x6c47f866 , 0x1bc0df4b ] )
+  , Mont::unsafe_make( [ 0xdfa4a22e , 0xe0ff544a , 0xa282e14c , 0x99ca87cc , 0x47a36a02 , 0xd17f3141 , 0xbc913d1d , 0x145c3be0 ] )
+  , Mont::unsafe_make( [ 0x7fa76441 , 0xeca0bea3 , 0xd09dab40 , 0xa5ff4d32 , 0x74e4f7de , 0x7270e1a0 , 0x705d0f93 , 0x057f7b5e ] )
+  , Mont::unsafe_make( [ 0x168553c5 , 0x1fd66cd3 , 0x810f760b , 0xfa01b014 , 0xdaecd429 , 0xc2656f39 , 0xfcf0ee6b , 0x2efd7e16 ] )
+  , Mont::unsafe_make( [ 0x28e3826e , 0x62cddda0 , 0x23b1f377 , 0x042f00bf , 0xbe7bc26c , 0x59f4aedd , 0xe170e7c0 , 0x1e3f9099 ] )
+  , Mont::unsafe_make( [ 0xc348e438 , 0x1b31c6f6 , 0xd5ef3f9f , 0x06019a71 , 0xe6f64b0d , 0x79983c44 , 0xce3713d6 , 0x28a8f282 ] )
+  , Mont::unsafe_make( [ 0x18e65d67 , 0x0c16a391 , 0xce9052dd , 0x667cfa3c , 0xd8af893a , 0xb88851e4 , 0xc57099e8 , 0x1f38658f ] )
+  , Mont::unsafe_make( [ 0x21cf5735 , 0xad8e3111 , 0x48ccb116 , 0xbc634583 , 0xafbf6627 , 0xefa7608a , 0x8933af86 , 0x1154ecf2 ] )
+  , Mont::unsafe_make( [ 0xac4a5950 , 0x19edaf4f , 0x9383c262 , 0x50b74d22 , 0xdacb132a , 0xb72bd0de , 0xf4282fb1 , 0x2d88dc23 ] )
+  , Mont::unsafe_make( [ 0x33482eb4 , 0xc9883340 , 0x3f7f4314 , 0x7877e1ca , 0x183438e9 , 0xfff56249 , 0x831f84c4 , 0x180a038a ] )
+  , Mont::unsafe_make( [ 0xb5185840 , 0x3807b96d , 0x24013a2d , 0x88040483 , 0x2ccd16c7 , 0x2d82aea1 , 0xd8531339 , 0x23360c90 ] )
+  , Mont::unsafe_make( [ 0x2e767a1e , 0x4f26d45c , 0x2bff12dd , 0x52379f66 , 0x75b6ea3d , 0x0b13910e , 0xb00bc39d , 0x07f054ff ] )
+  , Mont::unsafe_make( [ 0xcd9d9023 , 0x23609899 , 0x4efacc85 , 0x0422a547 , 0xf4373bbb , 0xc2189a16 , 0xc059d5dd , 0x2fdb3a1a ] )
+  , Mont::unsafe_make( [ 0x42f5a84e , 0x34705a0d , 0x3c2ee169 , 0xc6451f37 , 0x2b1e7550 , 0x71d379a7 , 0xaa99e69b , 0x099c8aab ] )
+  , Mont::unsafe_make( [ 0xb3dce21e , 0x2f97ebe2 , 0x8628e738 , 0xe6956726 , 0xfdfb5f87 , 0x1275be39 , 0x53cb87de , 0x3035f091 ] )
+  , Mont::unsafe_make( [ 0x31786c94 , 0x319dfb41 , 0x7b4585f0 , 0x8b0479b0 , 0xe45f7cb0 , 0x993a10a8 , 0xa66812e8 , 0x06e0293d ] )
+  , Mont::unsafe_make( [ 0xeff8580d , 0x716141c4 , 0x174ac058 , 0x4b2f1a22 , 0xba12709e , 0x156c34f9 , 0xfd82c81d , 0x0c466d87 ] )
+  , Mont::unsafe_make( [ 0x209b306f , 0xd46a3817 , 0xf8f88e8d , 0x4b06c0c0 , 0x32d2e169 , 0x62056a0f , 0xf7f3a1cb , 0x01bf0627 ] )
+  , Mont::unsafe_make( [ 0x2e8cd2c2 , 0x173a927c , 0xfc14542d , 0x657ef693 , 0x448e4496 , 0xc44e777e , 0x4c5622ce , 0x11616648 ] )
+  , Mont::unsafe_make( [ 0xe0188efe , 0x92ff2fb1 , 0xd6a6999e , 0xd0ea2231 , 0x1febd7b8 , 0x56bcea06 , 0x248f8513 , 0x1bc087a8 ] )
+  , Mont::unsafe_make( [ 0xd960e34a , 0x91685342 , 0x2632ba11 , 0xf7ec5c0e , 0x23c6c7bd , 0xeeae223a , 0x366e0896 , 0x07b30713 ] )
+  , Mont::unsafe_make( [ 0xe6c7e3ee , 0x23c21ba2 , 0x088d4a82 , 0x82b0f30e , 0xd9c0da9f , 0xef7ea200 , 0xf5ed4454 , 0x0a9c07a3 ] )
+  , Mont::unsafe_make( [ 0xf78ae33d , 0x4b42c709 , 0x78f7ca27 , 0x8776723c , 0xb03b6a82 , 0x1b24eb99 , 0x546bb09e , 0x29eb35cd ] )
+  , Mont::unsafe_make( [ 0xba566069 , 0x34b6a4cd , 0x58e27600 , 0x41ea373a , 0x7f0ee028 , 0x25284609 , 0x0935ad55 , 0x136b99f2 ] )
+  , Mont::unsafe_make( [ 0x5b374c95 , 0xf5dc0d31 , 0x2c2fb2ea , 0x66f3e21e , 0xf15b4d79 , 0x34e83b2f , 0xadd4e815 , 0x2a69d692 ] )
+  , Mont::unsafe_make( [ 0x31bd305e , 0x1328e6f8 , 0xf4c4da22 , 0x72ab5629 , 0xd5b38778 , 0xda548457 , 0x0feac66e , 0x25d3f15e ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// internal mixing matrix (`t x t`s, flattened)
+pub const CONST_P: [Mont; 64] = 
+  [ Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x9a80344d , 0xb67ebaf2 , 0x3ce41582 , 0x73edb5ba , 0x49de8909 , 0xb356fab5 , 0x69af33ce , 0x18cdd482 ] )
+  , Mont::unsafe_make( [ 0xb509dbbb , 0x1a79b3c9 , 0x1eb4e2ae , 0x40e3e5ee , 0xff36104e , 0x0b65cb74 , 0x05319694 , 0x06f47751 ] )
+  , Mont::unsafe_make( [ 0x74c90532 , 0x6b082c18 , 0x019835e6 , 0xe875a023 , 0x6e5b6be6 , 0xf48f1130 , 0x2ee6e23d , 0x254210dc ] )
+  , Mont::unsafe_make( [ 0xc567e8f2 , 0x0e86acba , 0xad587872 , 0x081b3192 , 0xd05ac9bc , 0x4bf88bdb , 0x82def4a8 , 0x11ed25dd ] )
+  , Mont::unsafe_make( [ 0xcd67e7c5 , 0xa0d640de , 0x0e09592f , 0xf8b169cc , 0xc35d39d4 , 0xeede07eb , 0x2ac3fdb8 , 0x01ca9f17 ] )
+  , Mont::unsafe_make( [ 0x8641233e , 0xca674a07 , 0xfa1c3958 , 0x7ab89691 , 0x4bb0d555 , 0xca5e33a8 , 0xda4e01f5 , 0x10b71175 ] )
+  , Mont::unsafe_make( [ 0xb1c15934 , 0xd82389ec , 0xfa10d9f6 , 0x7c50f8b6 , 0x7a600829 , 0xf4132c09 , 0x8769e179 , 0x06bc265b ] )
+  , Mont::unsafe_make( [ 0x1ce44eb9 , 0xa44f4ce9 , 0x96a4526d , 0xd2b3b7e2 , 0xc01cd83a , 0xaf5f5999 , 0x5af71cfa , 0x2633983e ] )
+  , Mont::unsafe_make( [ 0x86d06dd4 , 0x8d1a98ba , 0x6a0d159b , 0xbbba3ba7 , 0x017b031d , 0xb5544819 , 0xab780953 , 0x0946857d ] )
+  , Mont::unsafe_make( [ 0xc9f2b909 , 0x5841009d , 0x954917fb , 0xfd9817ab , 0xe5c5ed7d , 0x47616239 , 0xb4f8f975 , 0x0810a58d ] )
+  , Mont::unsafe_make( [ 0xcd028586 , 0x79053da8 , 0xe951972a , 0x511e9619 , 0x42109e8c , 0x9087f396 , 0xd001f86f , 0x2381f7b2 ] )
+  , Mont::unsafe_make( [ 0x4d1cec7b , 0xf6080f32 , 0x436fa930 , 0x6419ad4d , 0xbc58018d , 0x8ac9fd9b , 0x263aa2b2 , 0x2345c0e2 ] )
+  , Mont::unsafe_make( [ 0x165adbe7 , 0x7defcc17 , 0x6123e1e1 , 0x724ffb90 , 0x57e1e94e , 0x603464e7 , 0xd9456cf3 , 0x1da64068 ] )
+  , Mont::unsafe_make( [ 0xb7a5e058 , 0xf2c2b895 , 0xfe6d6eef , 0x1249bc8a , 0xbbb6197a , 0xb94ea63c , 0xe595a183 , 0x22c47044 ] )
+  , Mont::unsafe_make( [ 0x0f9c9225 , 0x43364d8b , 0xef651c8c , 0x3ef9d288 , 0x480519e4 , 0xa2b2602c , 0x675e2181 , 0x2eea904d ] )
+  , Mont::unsafe_make( [ 0x77ae014b , 0x644e2094 , 0x06dbc7c2 , 0xd826a529 , 0x102d9137 , 0x52a48302 , 0x33a00547 , 0x2e2bd016 ] )
+  , Mont::unsafe_make( [ 0x77cfacae , 0x78ccb4ab , 0x563b4159 , 0x6032fd7a , 0x7e6e9b36 , 0xf8f81178 , 0xe834a652 , 0x01feb20c ] )
+  , Mont::unsafe_make( [ 0x29788bf7 , 0x83f34e44 , 0x303064d3 , 0x00189abe , 0x162a67d2 , 0xbc022d33 , 0x57b5ae57 , 0x14f6cbda ] )
+  , Mont::unsafe_make( [ 0xc56e773a , 0x3adda76f , 0xc40405a2 , 0x0cebc09b , 0xccae6992 , 0x62a0ddb0 , 0x33c74b0f , 0x28357d67 ] )
+  , Mont::unsafe_make( [ 0xd0e880c2 , 0x982afbb5 , 0x059510e9 , 0x738f501b , 0xe6c4f721 , 0x32d6d817 , 0x18469cbd , 0x256d2cb2 ] )
+  , Mont::unsafe_make( [ 0xccbc0a27 , 0x12075193 , 0x262f3def , 0x2d63baeb , 0xe780b81c , 0x023e24e4 , 0xdcafed58 , 0x0731403f ] )
+  , Mont::unsafe_make( [ 0xce44b368 , 0x941e4a7a , 0x7bd4094d , 0x723a3516 , 0x4673cb60 , 0x69e42bdd , 0xdb40d3ba , 0x04e697b0 ] )
+  , Mont::unsafe_make( [ 0xb0176451 , 0xd986b277 , 0xd8fd8ce4 , 0x8362ab84 , 0x02ab60ce , 0x48ba8539 , 0x62833d95 , 0x099c547b ] )
+  , Mont::unsafe_make( [ 0x90f639d4 , 0x66bf5ed3 , 0x735ff1f7 , 0x4c3ad200 , 0x111697d9 , 0x74eb895d , 0x8e6b75ed , 0x22b60870 ] )
+  , Mont::unsafe_make( [ 0xb8ea9482 , 0x269ad4a0 , 0x53d37028 , 0xf4561f87 , 0x95f3a2ae , 0xb81653b7 , 0xe01c761c , 0x0504cc77 ] )
+  , Mont::unsafe_make( [ 0x9a681775 , 0x25bcba04 , 0xa247ddc7 , 0x4987fb44 , 0x04f2f9e6 , 0x1e2966ff , 0x56a4c8a7 , 0x22d8961d ] )
+  , Mont::unsafe_make( [ 0x13ed726d , 0x07cd3319 , 0x09e39a45 , 0x941e7494 , 0xa40ec3fb , 0x15c7b54c , 0x3938e245 , 0x2b0e98d5 ] )
+  , Mont::unsafe_make( [ 0x3bdf1283 , 0xb6b2e72b , 0x662c2a7c , 0x97bfd125 , 0xefd42b18 , 0x1876e7c3 , 0xfa6067f4 , 0x14e4c875 ] )
+  , Mont::unsafe_make( [ 0x6486ce29 , 0x6272ac51 , 0x2eea10da , 0x864b9da3 , 0x2996e4ba , 0xc0b071c1 , 0x06a4ca86 , 0x21986dd3 ] )
+  , Mont::unsafe_make( [ 0x491d609f , 0xd25be08e , 0x50ff5042 , 0xd6b6941c , 0xed621c3b , 0x311ee717 , 0xd2a7bf69 , 0x1a8ea5a1 ] )
+  , Mont::unsafe_make( [ 0x1e2a3e8a , 0x95fb9e02 , 0xc5c214ee , 0x87e6e39c , 0x365e177f , 0x55f6f013 , 0x25ea4795 , 0x270a2201 ] )
+  , Mont::unsafe_make( [ 0x6228f056 , 0xb00e5542 , 0x9a9461db , 0xab672526 , 0x6c802343 , 0x34338b9d , 0xf521bc21 , 0x26274666 ] )
+  , Mont::unsafe_make( [ 0xee713417 , 0xca52af0e , 0x965a256b , 0x7a090952 , 0xae93b6b7 , 0x851237aa , 0x1ea3d688 , 0x0e583bfd ] )
+  , Mont::unsafe_make( [ 0x489d695c , 0xb505785c , 0x6cb7d6da , 0xeb489962 , 0x0dbc6f45 , 0x02996ff7 , 0xf17870cc , 0x1cd2c35a ] )
+  , Mont::unsafe_make( [ 0x625f8361 , 0x5c9c9df5 , 0x74d94f12 , 0x453028af , 0x5e873262 , 0x9196ec64 , 0xf10028f6 , 0x1e1f045d ] )
+  , Mont::unsafe_make( [ 0xc2c95eb9 , 0x9ca25d29 , 0xc724bb31 , 0xa2058b97 , 0x2b2811e9 , 0x9e883242 , 0x772ae222 , 0x242d68f8 ] )
+  , Mont::unsafe_make( [ 0xdc33f836 , 0xcd2e1022 , 0xd8ee4134 , 0x290068fd , 0xc3c1996e , 0x34e8c2ac , 0xc527debc , 0x2e26f22e ] )
+  , Mont::unsafe_make( [ 0x04271f8f , 0x1b560284 , 0x2fd32a6b , 0x4238a192 , 0xf4b9c317 , 0x3a895a20 , 0xa3457f6d , 0x0c7ff8c7 ] )
+  , Mont::unsafe_make( [ 0xedb221fe , 0x971eec72 , 0xae23690e , 0x7489bd51 , 0xa3df59c2 , 0x51712d0c , 0xe65d18eb , 0x13caa9f0 ] )
+  , Mont::unsafe_make( [ 0x7fa76441 , 0xeca0bea3 , 0xd09dab40 , 0xa5ff4d32 , 0x74e4f7de , 0x7270e1a0 , 0x705d0f93 , 0x057f7b5e ] )
+  , Mont::unsafe_make( [ 0xcfd6c0b5 , 0x73b9dacb , 0x45302bf0 , 0x6bdd2ad4 , 0xdc1feee4 , 0x78a957a9 , 0x72688dfa , 0x13c79cc3 ] )
+  , Mont::unsafe_make( [ 0xdae1bc9a , 0x5023b03c , 0x761550c1 , 0x32618a8a , 0x194d50f7 , 0x6a34dd6d , 0xd46f13ec , 0x2618dca7 ] )
+  , Mont::unsafe_make( [ 0xc588f272 , 0xf6924092 , 0x9a85f563 , 0x59fe1da9 , 0x192aa5ea , 0x6eca98db , 0x3c984954 , 0x2420ee15 ] )
+  , Mont::unsafe_make( [ 0xc431288f , 0xf657e866 , 0x1d436cd5 , 0xdd43aa1f , 0x7437a1db , 0x40d24e50 , 0x4227d324 , 0x2d09c1e9 ] )
+  , Mont::unsafe_make( [ 0x3940562a , 0xb40c126d , 0xf4bba0de , 0x5a9a60d1 , 0xccb42bf9 , 0x7c1e45fc , 0xf0bdd96c , 0x259649e2 ] )
+  , Mont::unsafe_make( [ 0x216460d2 , 0xea62e756 , 0x56ac2164 , 0x4edecf32 , 0x0a50a2f6 , 0x78f991cf , 0x6b033de7 , 0x0115d3a8 ] )
+  , Mont::unsafe_make( [ 0x0a6fbdc0 , 0x331393f7 , 0xd7e71cb4 , 0x8968b0bb , 0x11d39fc0 , 0x2c8d05c7 , 0x716d01f1 , 0x1be782fc ] )
+  , Mont::unsafe_make( [ 0xb5185840 , 0x3807b96d , 0x24013a2d , 0x88040483 , 0x2ccd16c7 , 0x2d82aea1 , 0xd8531339 , 0x23360c90 ] )
+  , Mont::unsafe_make( [ 0x6071b0b1 , 0x10859117 , 0x6f38214a , 0x5ef04290 , 0xbc24dece , 0xb5df79eb , 0x2ada8063 , 0x067f8ad5 ] )
+  , Mont::unsafe_make( [ 0xe13a7a73 , 0xa6faa389 , 0xade92f1e , 0x2156d89d , 0x1ff01be8 , 0xcca4c7e5 , 0xa109d2bb , 0x03f16ff5 ] )
+  , Mont::unsafe_make( [ 0xa234afc2 , 0x7d41bcb5 , 0x2dee2677 , 0x7d3a07b5 , 0xdb293794 , 0x3e57cc9a , 0x4b7bd301 , 0x131c2374 ] )
+  , Mont::unsafe_make( [ 0x2b3c6850 , 0x3cca075d , 0x9ebac5bd , 0x69cf36ae , 0x908a0530 , 0x09753d3c , 0x3fcbdd91 , 0x0e902146 ] )
+  , Mont::unsafe_make( [ 0xbf995933 , 0x5fbfb66a , 0x8c7ad435 , 0x4c83b787 , 0x68ae4da4 , 0xe63c00a7 , 0x166db18f , 0x2a096ca0 ] )
+  , Mont::unsafe_make( [ 0xba71b90f , 0xa602fed9 , 0xee116502 , 0xe5ab946e , 0x02f6a1b7 , 0xae1278f9 , 0xcf6b260c , 0x218d2a45 ] )
+  , Mont::unsafe_make( [ 0xcfdcf1de , 0xacb01cb0 , 0x2eba9724 , 0x3e74a67e , 0x0a76a854 , 0x71b7fd6b , 0x86fb21df , 0x25444186 ] )
+  , Mont::unsafe_make( [ 0x2e8cd2c2 , 0x173a927c , 0xfc14542d , 0x657ef693 , 0x448e4496 , 0xc44e777e , 0x4c5622ce , 0x11616648 ] )
+  , Mont::unsafe_make( [ 0xddb1eef8 , 0x8515ab05 , 0xd839987b , 0x83d777a2 , 0xf6335ddb , 0x65ee17d1 , 0x294d7413 , 0x1690546a ] )
+  , Mont::unsafe_make( [ 0xcf4b794d , 0x24df0c9c , 0x728e9f46 , 0x6a2cdbcc , 0xac7277de , 0xbcd22829 , 0x64d6226f , 0x1d444b72 ] )
+  , Mont::unsafe_make( [ 0x206d71e9 , 0xefc859f3 , 0x96347d77 , 0xbfe9d107 , 0xe73c3ef0 , 0xf389aa67 , 0xc0abc06b , 0x0bb4980d ] )
+  , Mont::unsafe_make( [ 0xe9fcb9cb , 0x6a511f9c , 0x9a7550c2 , 0x180a0511 , 0x725cd94b , 0x306757bc , 0xb5ebdaca , 0x0e20313f ] )
+  , Mont::unsafe_make( [ 0xc4cb784f , 0x49ea862b , 0xc3a09cd6 , 0x57baa9fc , 0x18ca9220 , 0x94fef2ce , 0x88853c44 , 0x2f1be3ea ] )
+  , Mont::unsafe_make( [ 0xacbf7b52 , 0x49e1cf0e , 0xd72bc939 , 0x7bf5efc0 , 0xefe6871b , 0x730dd61f , 0xd6d09ba1 , 0x18f25c3a ] )
+  , Mont::unsafe_make( [ 0xc5883e33 , 0x97a624c7 , 0xe2db8176 , 0x67efd59e , 0xe7ad9cc9 , 0xe2c0be86 , 0x580dd60f , 0x124a6f6d ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// circomlib's optimization whatever
+pub const CONST_S: [Mont; 960] = 
+  [ Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x40071f01 , 0xb37120cc , 0x4976f1ee , 0xb7c4f3a5 , 0x2a7741be , 0x3d2181a2 , 0x7a959c72 , 0x09dd3ead ] )
+  , Mont::unsafe_make( [ 0xbdfc410a , 0x64d339d6 , 0xcb2f2740 , 0x1e3bb85f , 0x22d8e2a5 , 0x6be5b1f2 , 0x4ed4778b , 0x19f9335b ] )
+  , Mont::unsafe_make( [ 0xa5c3512b , 0x128faae8 , 0x97182416 , 0x6e0da37b , 0x6ca0d829 , 0x4376cde6 , 0x7970ded4 , 0x2d5fbd93 ] )
+  , Mont::unsafe_make( [ 0x1e42fb5a , 0x44e12b71 , 0xe92ecc16 , 0x5e63faa7 , 0x491678c5 , 0xf13518d1 , 0x2f578baa , 0x0ffd2cf7 ] )
+  , Mont::unsafe_make( [ 0x591b258b , 0xaf3ebfbc , 0x7e2dcc3a , 0x9ebada01 , 0x3cb2d1c2 , 0xaebbf454 , 0x13fa065d , 0x0f11122d ] )
+  , Mont::unsafe_make( [ 0x6e9ee916 , 0x59085c69 , 0x226996f0 , 0x6d3c2d89 , 0xe9c227b8 , 0x4a0d66d2 , 0x8cce8627 , 0x1faa624c ] )
+  , Mont::unsafe_make( [ 0x47e75584 , 0x87449732 , 0x02872ac5 , 0x91013a38 , 0xea859742 , 0x4dd630b9 , 0x1669e0bb , 0x12e556b9 ] )
+  , Mont::unsafe_make( [ 0x1ae2cd3c , 0x31ae1d24 , 0xcc1f4a4e , 0x0aeac450 , 0x1e87f5a2 , 0xa2e4073b , 0xddf4df8e , 0x0892dec3 ] )
+  , Mont::unsafe_make( [ 0x093ed0c8 , 0xa024f4a5 , 0xda2ff23e , 0x55c2385f , 0x28d67cd8 , 0xc5ee4cac , 0x9a19109e , 0x1070c558 ] )
+  , Mont::unsafe_make( [ 0xc9c0d73f , 0xa8f29099 , 0x2972296e , 0x96dd5e56 , 0x2d53b019 , 0xac5b31a6 , 0xb56d5d8d , 0x19d43673 ] )
+  , Mont::unsafe_make( [ 0x0a0ec546 , 0x4dc14016 , 0xa1b63a31 , 0xb3a80f91 , 0xc3a95e7b , 0x5b369eb7 , 0xbc49521e , 0x2a70a00c ] )
+  , Mont::unsafe_make( [ 0xf3da8336 , 0xcf9cac57 , 0xccb03180 , 0xb6966ae8 , 0xec4b6502 , 0x86df5fa1 , 0x97696c6a , 0x1697297d ] )
+  , Mont::unsafe_make( [ 0x01f55767 , 0xaf856d09 , 0xdfab845d , 0x96e59979 , 0x8a32109e , 0x05f14e18 , 0x6eec8d16 , 0x0a131699 ] )
+  , Mont::unsafe_make( [ 0x90ef1cbb , 0xc0bcce2d , 0x9e863957 , 0xcb1361d4 , 0x550ef198 , 0x457d3bcc , 0x3611410b , 0x179be986 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x9d53e022 , 0x99e224ef , 0xcb322b37 , 0x1d0b7a3a , 0xa50e656e , 0xbaef8956 , 0x246749ab , 0x12fadd33 ] )
+  , Mont::unsafe_make( [ 0x11721cdf , 0x00ca027a , 0xb94506ec , 0x4b999e2c , 0x64e425e1 , 0x2070b357 , 0xb442201a , 0x22744ed5 ] )
+  , Mont::unsafe_make( [ 0x38bdea4c , 0xb5ed050e , 0xe4b27102 , 0x91d41630 , 0xde85804a , 0x6dff9e0c , 0xbb4ff251 , 0x2a6c4ea3 ] )
+  , Mont::unsafe_make( [ 0xfb9c23fc , 0xb415bdc6 , 0x9dbcedbf , 0x49160b42 , 0x4c18663d , 0xce001c30 , 0x431ca448 , 0x1585048f ] )
+  , Mont::unsafe_make( [ 0x9951b643 , 0x9036880a , 0x2cc2e654 , 0xf5605bf8 , 0x7e3de1f3 , 0x3c2a808b , 0xb786a3a6 , 0x0a439098 ] )
+  , Mont::unsafe_make( [ 0x73cb6a7b , 0x864fda54 , 0x008c3b60 , 0x3dec6c12 , 0x9db05104 , 0x27d68be2 , 0xd853d59e , 0x16826c90 ] )
+  , Mont::unsafe_make( [ 0x4e1570a2 , 0x65696620 , 0x38067bea , 0x97fc5c1f , 0xd16534a3 , 0x7980980b , 0x9cf249ab , 0x184b4d96 ] )
+  , Mont::unsafe_make( [ 0x04af3c14 , 0xffe152c5 , 0x7b945a35 , 0x3b2f9093 , 0x4127be0d , 0x037af4bb , 0x7ddfd4db , 0x168e3050 ] )
+  , Mont::unsafe_make( [ 0xba385414 , 0xcae326c0 , 0x99ae3938 , 0xbed2f510 , 0x7533a086 , 0xf876ee07 , 0x3f8c8653 , 0x1f003b42 ] )
+  , Mont::unsafe_make( [ 0xccbc5ec6 , 0xbe5e17f5 , 0xddd96852 , 0x24ab8d0b , 0xd3ddd022 , 0xcc1106b9 , 0xd2d45787 , 0x231b4ff1 ] )
+  , Mont::unsafe_make( [ 0xd4cec65a , 0x08077b52 , 0x7c73aa62 , 0x6beb8f61 , 0xfecaea1f , 0x4484c4ff , 0x9559baac , 0x17262680 ] )
+  , Mont::unsafe_make( [ 0x05093207 , 0x185a755b , 0xca335b23 , 0xe99dc622 , 0x68d738d2 , 0x0d8136cf , 0x2d6ccbc0 , 0x0bcd915d ] )
+  , Mont::unsafe_make( [ 0xbc00200a , 0x0df24020 , 0x8b06c70f , 0x14a596fb , 0x5d5f361d , 0x832b77b5 , 0x393e9563 , 0x2a19abd9 ] )
+  , Mont::unsafe_make( [ 0x48681a92 , 0x40520736 , 0x8c9f613e , 0x864b3944 , 0x07ce8425 , 0x792a3a5d , 0xcd77101b , 0x2fbe2972 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xed23b6c2 , 0x848e954f , 0x0c9c2693 , 0x8938cf92 , 0x34eef810 , 0x127d4985 , 0xaa274e30 , 0x2a05fbb6 ] )
+  , Mont::unsafe_make( [ 0x7194385d , 0x3ffaf069 , 0x49cde595 , 0x97d4a930 , 0x2e1a77ad , 0xf9c22a89 , 0x1c79631a , 0x15b8d058 ] )
+  , Mont::unsafe_make( [ 0x0168b6c7 , 0x1de0f1da , 0xe0c2b9ad , 0x4431f026 , 0x7ebadb96 , 0x8d234bb0 , 0x7131240b , 0x281c05db ] )
+  , Mont::unsafe_make( [ 0x5641ddae , 0xcecfd2c8 , 0xc0af21b8 , 0xabbc2a9a , 0x140f1f83 , 0xe1620214 , 0x8b045024 , 0x05ce1c37 ] )
+  , Mont::unsafe_make( [ 0x6edc5833 , 0xdbad04f3 , 0x860f66a6 , 0x59f9a8ba , 0xc65d671b , 0xa1200f7c , 0xa17de3ee , 0x0c9de25e ] )
+  , Mont::unsafe_make( [ 0xd73e70c5 , 0x29a2194a , 0xd49571e1 , 0x407366ef , 0x6e239dc9 , 0xa62395b2 , 0x4dfe2b0e , 0x04615b00 ] )
+  , Mont::unsafe_make( [ 0x5640e4e7 , 0x30f1fe06 , 0x3bccea05 , 0x41f851c8 , 0x911e6e36 , 0xa2120436 , 0xe2da6c30 , 0x1422867c ] )
+  , Mont::unsafe_make( [ 0x57c46cfd , 0xa4595053 , 0xaa222eaa , 0x2b0db074 , 0xebd224da , 0x3a59dcd6 , 0xb269dfa5 , 0x05994dc3 ] )
+  , Mont::unsafe_make( [ 0xe33cdf1a , 0xd08e96da , 0x4b62fb67 , 0x7514690f , 0xce622df7 , 0x63e4ead2 , 0x26352ad1 , 0x08bacd4f ] )
+  , Mont::unsafe_make( [ 0xc60b700e , 0x18012c19 , 0x35b5e88d , 0x73a4b7ae , 0x87929366 , 0x66567792 , 0xa941fb48 , 0x2a199af7 ] )
+  , Mont::unsafe_make( [ 0xffec4406 , 0x7acbc1db , 0x7b383aaf , 0xd91f1e32 , 0xd86b8242 , 0xe08de1df , 0x1cd6f98a , 0x265fff92 ] )
+  , Mont::unsafe_make( [ 0xe90f2942 , 0x69b34506 , 0x89cc0012 , 0x369f3c74 , 0xf2a8407d , 0xf0e6ba32 , 0xb50eaf2c , 0x0763a935 ] )
+  , Mont::unsafe_make( [ 0xf351c0dc , 0x49802a6e , 0x01ec9e31 , 0xaac61383 , 0xf4ed15c1 , 0xc9ae2f02 , 0x9c7c897e , 0x0c4b565b ] )
+  , Mont::unsafe_make( [ 0xc56253cd , 0xc3748db2 , 0x65870fd9 , 0x94779eb0 , 0x98a30288 , 0xbfc5acf7 , 0xa97017d3 , 0x170c38c9 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xe3494765 , 0xa1421b41 , 0xfcfaf113 , 0xe8278ad3 , 0x1c48e860 , 0x25ba6b44 , 0x764704f6 , 0x1fa8b7e0 ] )
+  , Mont::unsafe_make( [ 0xefd418ca , 0x24b0ac83 , 0xcc1530ac , 0xad1d1dac , 0x8aa22019 , 0x380b76a5 , 0x1d05bf89 , 0x0eb64ff5 ] )
+  , Mont::unsafe_make( [ 0x81a340d9 , 0x5cb323d9 , 0x99d9a31c , 0xcbf486aa , 0xaea18b06 , 0x3aa923d8 , 0x246cb726 , 0x11121715 ] )
+  , Mont::unsafe_make( [ 0x69c11530 , 0x0d576e05 , 0xb9a601b6 , 0x0ef9a85b , 0xd9b7da7a , 0x92a1fe6a , 0x703d99d0 , 0x2f4c85aa ] )
+  , Mont::unsafe_make( [ 0x91b91ae4 , 0x1b47babe , 0x7a234219 , 0xe53d66df , 0xb47c0bdf , 0x90323d96 , 0xaa8afc6c , 0x301cbec7 ] )
+  , Mont::unsafe_make( [ 0x6f3a6dfe , 0x242ad4c9 , 0xcb7936b4 , 0x54bcddff , 0xe05ef64c , 0x062514e8 , 0x554bc37f , 0x246a5f7e ] )
+  , Mont::unsafe_make( [ 0x1b2f3d93 , 0x12737021 , 0x1f744aa1 , 0x85d9972a , 0x6122c50d , 0x6d605e0c , 0xf50d5be1 , 0x0a73c5e5 ] )
+  , Mont::unsafe_make( [ 0x93e12871 , 0xa8ca07c5 , 0xed2149ce , 0x386396d7 , 0xf8028ff9 , 0xf077e8c1 , 0x887bca0f , 0x0471883b ] )
+  , Mont::unsafe_make( [ 0xeaf1d86b , 0x601b70ab , 0x80ad6ebb , 0x0ad4bcf7 , 0x5b922a3d , 0xfb1e7443 , 0x7a6830f0 , 0x1c0d1d16 ] )
+  , Mont::unsafe_make( [ 0x6fc3e657 , 0x29fcbff3 , 0x192e388f , 0xd9df6784 , 0xf8f6c759 , 0x7e04c918 , 0x880606a8 , 0x06aac0b1 ] )
+  , Mont::unsafe_make( [ 0x7b053891 , 0xc3753c93 , 0x2da9c54d , 0xe0e84759 , 0xb9689d33 , 0x6e3f762b , 0x1bbcde75 , 0x19ce46ed ] )
+  , Mont::unsafe_make( [ 0xebd9d192 , 0x44935fa9 , 0x77e6ba7a , 0xef9b0c90 , 0x9dce7ed6 , 0xfd494036 , 0x68865757 , 0x1fc06110 ] )
+  , Mont::unsafe_make( [ 0x17da204a , 0x24525a48 , 0xb4dc2241 , 0x456293be , 0x9d065e10 , 0x89757632 , 0xd379b22e , 0x1020c558 ] )
+  , Mont::unsafe_make( [ 0xe18bfd5c , 0xa3d0f7a0 , 0xa2a975bb , 0xe095c337 , 0xb8213d85 , 0x971a3e26 , 0xe74bdc32 , 0x2e0069b5 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x1f121f98 , 0xc6d146b8 , 0x1b7c5e92 , 0x7d9cabfd , 0x35d74b3c , 0xaf36185b , 0x64c2d387 , 0x1df76347 ] )
+  , Mont::unsafe_make( [ 0x36cf3a34 , 0xf76b1cfa , 0xffb52407 , 0xce0af472 , 0xe9d90f35 , 0xee17add7 , 0x9ddbc681 , 0x041a9ee7 ] )
+  , Mont::unsafe_make( [ 0x88314705 , 0x5972e625 , 0x2cf16aa6 , 0xdfcb6afd , 0x12590f20 , 0x89612de2 , 0x9de32041 , 0x205fcefc ] )
+  , Mont::unsafe_make( [ 0x1e0c746e , 0xe8ac543d , 0x3da2b518 , 0x5e52a41c , 0x3c19a1fc , 0x0ac063b1 , 0xa619352b , 0x17d473b1 ] )
+  , Mont::unsafe_make( [ 0x25e01491 , 0xb0d7e3a0 , 0xce0e1c98 , 0x57f2ced4 , 0x966b21ba , 0x63076a71 , 0xd77add02 , 0x17c48372 ] )
+  , Mont::unsafe_make( [ 0xd108c831 , 0xaa3723a4 , 0x03062780 , 0x43ba7b4b , 0x4364b17e , 0xb7b880c8 , 0xb86101ac , 0x10dc6bb8 ] )
+  , Mont::unsafe_make( [ 0xf0e4fa1c , 0x6f5c00ba , 0x11e0f6f2 , 0x0db9d652 , 0x0cda4a75 , 0x19bc6e5b , 0x2124d20f , 0x1c9ffe81 ] )
+  , Mont::unsafe_make( [ 0x847fc4fd , 0x851bdd64 , 0x2eb2d777 , 0x39ee1447 , 0xa3650985 , 0xcd97d39b , 0xc291c532 , 0x190051ff ] )
+  , Mont::unsafe_make( [ 0x7e2e01b0 , 0xee61ca5e , 0x040996f5 , 0xa0b3053e , 0x11b8ad96 , 0x4829861c , 0x6555c2e2 , 0x2ba083ab ] )
+  , Mont::unsafe_make( [ 0x1502fd43 , 0xc43df348 , 0x7fa424f9 , 0xc7978dbc , 0xde3d2b37 , 0x261f5638 , 0xaeef88cb , 0x026027be ] )
+  , Mont::unsafe_make( [ 0xb6ba8652 , 0x86e52ebf , 0xbe383bcd , 0x47a05ac8 , 0xf747fff5 , 0xbee49e5c , 0x78928d4c , 0x25aff359 ] )
+  , Mont::unsafe_make( [ 0x71572142 , 0x1edd4d83 , 0x107112be , 0x228f4a5c , 0x69f3829c , 0xb7311391 , 0xe5846d37 , 0x138a8a21 ] )
+  , Mont::unsafe_make( [ 0x0ddb13ff , 0xe91c221b , 0x39e135de , 0xc39a3bad , 0x674918b4 , 0xed354ac3 , 0x9e956f72 , 0x27faf332 ] )
+  , Mont::unsafe_make( [ 0xddffc651 , 0x16838938 , 0x816a604c , 0x651b508f , 0x81f825ef , 0xd80ee79f , 0xddb5dcfb , 0x2c9ca1a1 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x4123d24e , 0xf50cf4dd , 0xe0fdf795 , 0x2e029152 , 0xaa8e7514 , 0xd7e97475 , 0xd235cacb , 0x24945595 ] )
+  , Mont::unsafe_make( [ 0x5fd455b5 , 0xb03565da , 0x008150c6 , 0x5d09c827 , 0x5cdda438 , 0xd88c4bca , 0x8d760abd , 0x09020e99 ] )
+  , Mont::unsafe_make( [ 0xbdbc81d9 , 0xa6b36f5b , 0x24614645 , 0xaf40cd07 , 0x9b5e510b , 0xf654bf2c , 0x4a190d1d , 0x236a253f ] )
+  , Mont::unsafe_make( [ 0xf2222660 , 0x5d5ef1d8 , 0xe230c708 , 0x74852fb3 , 0x8290aa7a , 0x16b99a2d , 0x2fe67a07 , 0x2db7350f ] )
+  , Mont::unsafe_make( [ 0xa2300dc2 , 0xa41fe260 , 0x418fb849 , 0x59aa9663 , 0xea231b89 , 0x0751817b , 0x41775c5c , 0x1eaedd20 ] )
+  , Mont::unsafe_make( [ 0xd119cdfd , 0x617423eb , 0x6b35a001 , 0x80e92f2f , 0x647627cb , 0xbfe0d1a4 , 0x943ce037 , 0x16f5e6bf ] )
+  , Mont::unsafe_make( [ 0xbe95b3e2 , 0xd2adcdd6 , 0x7f080980 , 0x943962bc , 0xfcc64393 , 0x6654aa13 , 0xc12b61e1 , 0x25cfe724 ] )
+  , Mont::unsafe_make( [ 0x5639f408 , 0x62dc6658 , 0xd922e062 , 0xc9f300ff , 0xb04ef414 , 0xaa2a815d , 0xf0dea314 , 0x09c045b8 ] )
+  , Mont::unsafe_make( [ 0xbfcb64e0 , 0x8fc10a91 , 0x23e2f6d0 , 0x2d6f9301 , 0x184f33b2 , 0xb74e2b23 , 0x626919a9 , 0x044352b7 ] )
+  , Mont::unsafe_make( [ 0x117ed586 , 0x13bbee0d , 0x9751c11f , 0x6eb304ef , 0xc491fd09 , 0x741e47c2 , 0xa774c086 , 0x0392e625 ] )
+  , Mont::unsafe_make( [ 0xaaa33f16 , 0x768c1fc1 , 0x9e80b391 , 0xbb26cf93 , 0x87dfd6a4 , 0x66c8c02c , 0x90113f0f , 0x1299a00a ] )
+  , Mont::unsafe_make( [ 0x2d6580cd , 0x700b6fa6 , 0x1763949e , 0x552b2bbe , 0xf33faa74 , 0xfeb59e05 , 0x24f78e0e , 0x2605b663 ] )
+  , Mont::unsafe_make( [ 0xd472987b , 0xf0f6262b , 0x0e3cba5f , 0xcb8011f8 , 0x97d06c31 , 0x53547f58 , 0xb80b1d72 , 0x118a612a ] )
+  , Mont::unsafe_make( [ 0x6e2d0999 , 0xa655dd5a , 0xbdcc7529 , 0xfe09224f , 0x01b942bf , 0xb26ad118 , 0x01bfac6c , 0x014abdff ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xb8e167eb , 0xf8017bd8 , 0xe7b5a638 , 0xa7816759 , 0xc92e169c , 0x5d0007b1 , 0x993118f1 , 0x1ac3e0d8 ] )
+  , Mont::unsafe_make( [ 0x27ec71a9 , 0x45cd4407 , 0x16e9b260 , 0x8acde77f , 0xd317dfce , 0x063d1d61 , 0xa8f81573 , 0x1cf1cf6b ] )
+  , Mont::unsafe_make( [ 0xd4267d99 , 0x0e7d1b47 , 0x680f94ad , 0x77d248e8 , 0x5fe1c805 , 0x05acf07e , 0x288e7d63 , 0x0cbc046c ] )
+  , Mont::unsafe_make( [ 0x9ba3d0ac , 0xd51eb6eb , 0x3c4bd172 , 0x987778d8 , 0x197f14ee , 0xd2593ebb , 0xa6895978 , 0x121a9f4e ] )
+  , Mont::unsafe_make( [ 0xcd408149 , 0xd8b609d7 , 0xecf9360e , 0x5b314572 , 0x5d264183 , 0x32c92de4 , 0x04373d48 , 0x055a3c37 ] )
+  , Mont::unsafe_make( [ 0xa9553495 , 0x0b17b29b , 0x21f42966 , 0x8217751c , 0xb76df78b , 0x7796c690 , 0x5aaca5a1 , 0x20c17cea ] )
+  , Mont::unsafe_make( [ 0x1e5e288a , 0xbf4162fe , 0x141d652d , 0x4128cf55 , 0x1972f8ad , 0x4c8d89b5 , 0xb37b937b , 0x1a8b61db ] )
+  , Mont::unsafe_make( [ 0xb7059055 , 0xd7fe4534 , 0x38341cfb , 0x701b0b78 , 0xa05efa7c , 0x876f0c18 , 0xc92b93c5 , 0x221efdac ] )
+  , Mont::unsafe_make( [ 0x49c55159 , 0x89216216 , 0xac099421 , 0x37251215 , 0xdc923082 , 0xaa9a0a4b , 0x20a6291d , 0x12f276d8 ] )
+  , Mont::unsafe_make( [ 0x7579ce8b , 0x485ed814 , 0x3ab6c249 , 0xf73b09a1 , 0x58b7fb62 , 0x4dec3d28 , 0xcd7373f7 , 0x2e1bcf4a ] )
+  , Mont::unsafe_make( [ 0x6bbff7f1 , 0x988e845f , 0xd02ad02c , 0xc7b42489 , 0xf82cf923 , 0x1085212a , 0x9a1ce837 , 0x0f8aa880 ] )
+  , Mont::unsafe_make( [ 0x6b2b5fde , 0x2725957a , 0x0ac4c3e4 , 0x02c3b0e2 , 0x7494bf44 , 0x6345f9ad , 0x7d798f82 , 0x2bffcbf0 ] )
+  , Mont::unsafe_make( [ 0xb9059baa , 0xeedc218c , 0x1db462de , 0x7822d626 , 0xfb8cbc74 , 0x19a5ca84 , 0x66d1fc65 , 0x199630d7 ] )
+  , Mont::unsafe_make( [ 0x96cf1424 , 0x74158b9d , 0x0318c2d1 , 0x6806315c , 0x0941af8f , 0x728e6094 , 0x8115325d , 0x1306c844 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x56de2e31 , 0x1f41fd01 , 0x6ba07e1f , 0x1f8a75a9 , 0x99e166dc , 0xdee4dfe9 , 0x603a4abd , 0x11e4bae3 ] )
+  , Mont::unsafe_make( [ 0x0ccb2135 , 0x2985a47f , 0x27e91d4d , 0x333ebf60 , 0xd6ca0e24 , 0x3e379d63 , 0x26acdb3d , 0x15d005fd ] )
+  , Mont::unsafe_make( [ 0x2a9d2976 , 0x1dd8bd6c , 0x133c252c , 0x39eb13e5 , 0x94cbb220 , 0xb5061fcc , 0x96922567 , 0x2a5af707 ] )
+  , Mont::unsafe_make( [ 0x115afe36 , 0x2fe6b83f , 0xad75504a , 0x72b5f5ef , 0xc7b46d02 , 0x95ca4790 , 0x38cfc474 , 0x08c82cac ] )
+  , Mont::unsafe_make( [ 0x1b45bbfd , 0x969401a8 , 0xc1c0ce72 , 0x1e502b96 , 0x11e0a0bb , 0xc5c21fbc , 0x1566f12b , 0x0eaad3a2 ] )
+  , Mont::unsafe_make( [ 0xda5e03e0 , 0x2e6a2d8d , 0x7fb970b4 , 0x63f118e5 , 0xd61232a5 , 0x3818480a , 0xf91125fc , 0x2b824917 ] )
+  , Mont::unsafe_make( [ 0xe9c6bbcf , 0x3cc381a8 , 0x64f88630 , 0x1cbb0250 , 0x29ca631e , 0x2a180e07 , 0x6fb020c4 , 0x1e0b6e3d ] )
+  , Mont::unsafe_make( [ 0xdba4d6b9 , 0x0a1cb1eb , 0xbb082156 , 0xe4f41963 , 0x6ef6d9cc , 0x85a577db , 0xf62ba158 , 0x1bc2b514 ] )
+  , Mont::unsafe_make( [ 0x1bd7afe6 , 0x1aefd6ed , 0x2d6dd750 , 0x80cb50f0 , 0xf6b86c58 , 0x10d25393 , 0xbefe86bd , 0x0311e914 ] )
+  , Mont::unsafe_make( [ 0xa00f48ec , 0x58536cce , 0x207e6f42 , 0x44487a57 , 0x6c31bf98 , 0xe7884573 , 0x42178018 , 0x0e0e464f ] )
+  , Mont::unsafe_make( [ 0xb48a566e , 0x02e397de , 0x5e41a2f2 , 0x79e6f656 , 0x95d0ab01 , 0x315ef8a2 , 0x0ccd5d4f , 0x078f5dd0 ] )
+  , Mont::unsafe_make( [ 0x1e07fec5 , 0x9e607576 , 0xf9af038c , 0x53a5515e , 0x258344a4 , 0xfc9205f0 , 0xc6d153dc , 0x2295b163 ] )
+  , Mont::unsafe_make( [ 0x1422215a , 0x3c64a17f , 0x3088f035 , 0x1ba13d90 , 0x2952ca7f , 0xb44a9ca6 , 0x2165016c , 0x2a54c2a2 ] )
+  , Mont::unsafe_make( [ 0x6dc3b6f0 , 0xe31b3e74 , 0xfe172be5 , 0x05670f72 , 0x2798058a , 0x78712318 , 0x61925df1 , 0x00ec81ae ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x9a67363e , 0x37b768d3 , 0x2f25aea1 , 0x56ce5f19 , 0x5298853b , 0x21014624 , 0xff237dbb , 0x09fd7ca5 ] )
+  , Mont::unsafe_make( [ 0x4cc96fd9 , 0x8da37e56 , 0x676612e7 , 0xcb1c8c2b , 0x4826626e , 0x1bd43ee3 , 0x025aa700 , 0x13a9db05 ] )
+  , Mont::unsafe_make( [ 0x38e6fbc1 , 0x73273dd8 , 0xaded6484 , 0xfa639f4f , 0x2ff43954 , 0x0e99668f , 0x02eb7e2b , 0x218bca15 ] )
+  , Mont::unsafe_make( [ 0x28986ac3 , 0x3eccc526 , 0xe7d9cfd9 , 0x7e460529 , 0x032f2174 , 0x9f817072 , 0x8e9dc951 , 0x17aaaa3c ] )
+  , Mont::unsafe_make( [ 0x7f3c2814 , 0xff07c552 , 0x07b0ff61 , 0xe7cbb8a7 , 0x1fa2f461 , 0x2b3bbf00 , 0x0d8362b9 , 0x01022432 ] )
+  , Mont::unsafe_make( [ 0xcc64d323 , 0xd76b577b , 0x398d72e2 , 0x43d0b15e , 0x0e391f8e , 0xd33caced , 0xc21a6261 , 0x1c43584d ] )
+  , Mont::unsafe_make( [ 0xd43e38ef , 0x8b40435e , 0x259b6ea1 , 0x8cdf0eb6 , 0x5bb09fd3 , 0x68785045 , 0x6aa4489b , 0x21b070b5 ] )
+  , Mont::unsafe_make( [ 0x1d8d154c , 0xd610c170 , 0x21c406bf , 0x3a0afdd1 , 0xb1cc7379 , 0x2f95259c , 0x1f4dcb4c , 0x2c92042a ] )
+  , Mont::unsafe_make( [ 0xcc0afe33 , 0x49546095 , 0x961f78d0 , 0xa7fe8837 , 0x71905b4d , 0xcf2120a1 , 0xe01c89d4 , 0x04843584 ] )
+  , Mont::unsafe_make( [ 0x1219d0dd , 0xb9c4713d , 0x644ab3bb , 0x839fbb17 , 0x43f43d57 , 0xf9debd69 , 0xe43d26e5 , 0x147ec66b ] )
+  , Mont::unsafe_make( [ 0x1856ab2f , 0x32fc84ce , 0x532c7746 , 0x2e82de44 , 0x3f81a9de , 0x47293d4b , 0x65d99c2d , 0x15ac3230 ] )
+  , Mont::unsafe_make( [ 0x551ab76c , 0xfa68c7c7 , 0x2d8123d6 , 0x2004737a , 0x15f68df0 , 0xb84f0dfd , 0xb73cce15 , 0x09a8e237 ] )
+  , Mont::unsafe_make( [ 0x8d8961c6 , 0xcded096e , 0x61c52d3d , 0xbbed3f89 , 0x2748c5ff , 0x403b7498 , 0xf981c1c6 , 0x24c3317f ] )
+  , Mont::unsafe_make( [ 0x39f1239c , 0x1060e012 , 0x86dc4da8 , 0x890c25a1 , 0xe76b54fc , 0x1fe01e80 , 0x3590cb0c , 0x14a5b241 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xd77de18c , 0x9cd33dbc , 0x0f448ade , 0x756b9f6e , 0xb895cc55 , 0xea79f222 , 0x7f1ac08e , 0x2a024fd3 ] )
+  , Mont::unsafe_make( [ 0xcee2a6b0 , 0x879cbea9 , 0xdd83e056 , 0x5c29814e , 0x9fb52e7d , 0x3dd6897a , 0xcfeb2c05 , 0x130a5ac3 ] )
+  , Mont::unsafe_make( [ 0x4887f62f , 0x38d64006 , 0xad311323 , 0x1f59f2e1 , 0xf65ef38c , 0x68d1e770 , 0x0bf34f5b , 0x2f7416e8 ] )
+  , Mont::unsafe_make( [ 0x10912c02 , 0xeb4b8436 , 0xd54e9907 , 0x6431f02c , 0x8ba3a87b , 0x2b88a05a , 0xe4b04990 , 0x17c32166 ] )
+  , Mont::unsafe_make( [ 0x73e3a0f3 , 0xf7cf0413 , 0x44c45ba3 , 0x0d626d29 , 0x02a7226e , 0xb8f53276 , 0xab724589 , 0x245140d3 ] )
+  , Mont::unsafe_make( [ 0x08ce2e46 , 0x8a81fed2 , 0x2824b359 , 0xa1c2292d , 0xc3f14f36 , 0x31b377fa , 0x2385416d , 0x01efd6fc ] )
+  , Mont::unsafe_make( [ 0x8bd223e9 , 0x5455d2df , 0x61467c63 , 0x4192f8d9 , 0x2d046775 , 0x2b550feb , 0x16756c6b , 0x26fec75e ] )
+  , Mont::unsafe_make( [ 0x8bc03149 , 0x027a2708 , 0xdcaa9acc , 0x6981ef44 , 0x23afeeba , 0x9372b2b1 , 0xb6708d15 , 0x05b8cf69 ] )
+  , Mont::unsafe_make( [ 0xf00a85ac , 0xfb78c750 , 0x222791bc , 0xe2ebf809 , 0x86ff639a , 0x66eaf3e3 , 0x5009be19 , 0x075f49d5 ] )
+  , Mont::unsafe_make( [ 0xe479ccb2 , 0xc176645c , 0x1d51ca2f , 0x99709529 , 0xb5692914 , 0x6a7f0dc6 , 0x9218487b , 0x06911a2f ] )
+  , Mont::unsafe_make( [ 0x8edc8646 , 0xef641d7d , 0xc6d4b10c , 0x9ba28b03 , 0xb683a612 , 0x25b03dc7 , 0xccae958a , 0x2ec8b9bd ] )
+  , Mont::unsafe_make( [ 0xa7651cf8 , 0x4a596d3e , 0xee6f01d5 , 0x57483e31 , 0x2fa5d663 , 0x2f86b98a , 0x9223d9a7 , 0x15e2b78f ] )
+  , Mont::unsafe_make( [ 0x405781d1 , 0x072d7064 , 0x12c26221 , 0x762c2c63 , 0x67463a13 , 0x445d3dc3 , 0x04031825 , 0x17c2015e ] )
+  , Mont::unsafe_make( [ 0xfbac6faf , 0x56b36876 , 0xf706a72b , 0xdd7f73ed , 0x59cdd10a , 0x23603c25 , 0x7cf6123f , 0x29e5cc3e ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x7eea42ff , 0x58eba4d8 , 0xf5e76327 , 0xbfcdb414 , 0x5e5214e4 , 0x5c92a74f , 0x1160cad1 , 0x0c45b813 ] )
+  , Mont::unsafe_make( [ 0x37479a5d , 0x69f8b50e , 0xc358cf46 , 0xb0516802 , 0x76bccfea , 0xb3e4e9c0 , 0x1f52736f , 0x14b72c62 ] )
+  , Mont::unsafe_make( [ 0x3a6a2a8c , 0x2fc5b18c , 0xa50ea655 , 0xa46afca4 , 0xf5faaa03 , 0xd8801422 , 0x858b8562 , 0x2854e786 ] )
+  , Mont::unsafe_make( [ 0xda6f9765 , 0x1976bcd8 , 0xbe9f8599 , 0xb53c3b9e , 0x5604a7a9 , 0x86c59ef4 , 0xd71a94cf , 0x2e3eaea2 ] )
+  , Mont::unsafe_make( [ 0x4ccec8bb , 0x4497b1ba , 0x4ff17d86 , 0xcc6383f5 , 0x94dd0ef7 , 0x79ab5778 , 0xda8f1957 , 0x08ba72af ] )
+  , Mont::unsafe_make( [ 0xf848badd , 0xa5d997ed , 0x4c8066a4 , 0x2254e645 , 0x57cf3857 , 0x079a8695 , 0xa01440e7 , 0x0b3d4ca3 ] )
+  , Mont::unsafe_make( [ 0xf0c6023a , 0x0ba62876 , 0x3cf1058c , 0x46881328 , 0x7b932c35 , 0xd5b7a739 , 0xb1d9c016 , 0x1ec91738 ] )
+  , Mont::unsafe_make( [ 0xb0b47f6c , 0x3d334f2b , 0x846caad0 , 0x8fccaa78 , 0x5e8bb890 , 0x63f9871c , 0x7b053c7e , 0x1327fcc0 ] )
+  , Mont::unsafe_make( [ 0x56c7123c , 0x403f4423 , 0x175daaa8 , 0xeed2d3fa , 0xce934623 , 0x6efab428 , 0xbf832c51 , 0x0456767d ] )
+  , Mont::unsafe_make( [ 0x8507a0e1 , 0x60eb2b3d , 0xb65eca95 , 0x694a888b , 0x56e7948c , 0x6774173c , 0x0f05642d , 0x2b10a803 ] )
+  , Mont::unsafe_make( [ 0x86c93538 , 0xa47972e3 , 0x7de4fd47 , 0xca9a5f9a , 0x86988236 , 0x7b43caf3 , 0x1bd63244 , 0x056ac724 ] )
+  , Mont::unsafe_make( [ 0xfcd30019 , 0x8eaa0ed1 , 0x95fe8da8 , 0xb2e2aed7 , 0x14f41ad8 , 0x34dfe58f , 0x1185e0fc , 0x2c34ca13 ] )
+  , Mont::unsafe_make( [ 0x6b5293ec , 0xd856319a , 0xe878935d , 0xf8f5d290 , 0xa9319a63 , 0x305f757e , 0xd1ebab27 , 0x2eff1968 ] )
+  , Mont::unsafe_make( [ 0xf3c2c8c0 , 0x426574c7 , 0xd5430a19 , 0x968dd30a , 0xa35b81e9 , 0x6573ccb5 , 0xe8d6d381 , 0x0f0b11dc ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xea787032 , 0x9fddc8a2 , 0x9d866926 , 0xfc346cf4 , 0xace6935c , 0xc3f0fd2c , 0xc460fd68 , 0x10982d6e ] )
+  , Mont::unsafe_make( [ 0x29dcc296 , 0x407acdac , 0x73b4b6cf , 0x5ffb1bc8 , 0x7d2c9e4f , 0x80e1ee28 , 0xa5217617 , 0x23b9bedf ] )
+  , Mont::unsafe_make( [ 0x15bfe9a2 , 0x00370b75 , 0x19b69d9a , 0x42e85973 , 0x2cece495 , 0x67e14c32 , 0x058e4897 , 0x14106752 ] )
+  , Mont::unsafe_make( [ 0x2976e4a5 , 0x999e8b4c , 0x7e4a8154 , 0x7cb712d3 , 0x7426cd34 , 0x46c5f2e2 , 0x35ff685f , 0x21e3ff76 ] )
+  , Mont::unsafe_make( [ 0x63be3203 , 0x30cced6f , 0x98d7ee9d , 0x08eb6aa9 , 0xd66377f9 , 0x35c3dd67 , 0x5922cb48 , 0x23957238 ] )
+  , Mont::unsafe_make( [ 0xf7a7eb4c , 0x81ab6640 , 0xd9bb016c , 0x8d393744 , 0x24bf44a0 , 0x23dee58e , 0xa7d39464 , 0x1715085c ] )
+  , Mont::unsafe_make( [ 0xd2b1fdb5 , 0x3279dff9 , 0x3d7a61e6 , 0xee72ec8d , 0xaf33cbf9 , 0xadf386f6 , 0x7467b3de , 0x1ea67a58 ] )
+  , Mont::unsafe_make( [ 0xd6affb37 , 0x2fe17dec , 0x51a2f900 , 0x1bcdc843 , 0x6340c5bd , 0x7af18558 , 0xaf1d1f67 , 0x29dc9ad0 ] )
+  , Mont::unsafe_make( [ 0xfefe2ff8 , 0x75906930 , 0x910d71dd , 0x67055df7 , 0x3e301310 , 0xace9e20b , 0x1cc3966c , 0x2f9c5629 ] )
+  , Mont::unsafe_make( [ 0xf2d76c8c , 0x4c0a6b4a , 0x2ce95d73 , 0x8dc8e5ec , 0x07f1009f , 0xca03bfad , 0x38dbd630 , 0x08b2800d ] )
+  , Mont::unsafe_make( [ 0xa93ed51a , 0x5b2ba99c , 0xc51fa51c , 0x949e89bc , 0x73dc0814 , 0x1b11aa78 , 0x51a32d3f , 0x0f4f3cfe ] )
+  , Mont::unsafe_make( [ 0x14c04182 , 0x75c10b7c , 0x0ba13130 , 0xbc1c9933 , 0x7ad89fc3 , 0x06cebd95 , 0xfaaa4427 , 0x081279b7 ] )
+  , Mont::unsafe_make( [ 0x42e3ef6b , 0x008ae6c6 , 0x0c948f89 , 0x477ef9e3 , 0xd044f43a , 0x7e0defb1 , 0x9121d25c , 0x13d07054 ] )
+  , Mont::unsafe_make( [ 0xadb139b9 , 0x45adca00 , 0xc83652c9 , 0x57c30472 , 0xf91d5e8a , 0x0792a855 , 0x00a79c7f , 0x0efe9432 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xbe4e9c3e , 0x1d4ac946 , 0xe490393b , 0xd5a234c8 , 0xd4b84e6a , 0x14db198c , 0x63864bbe , 0x101b62cb ] )
+  , Mont::unsafe_make( [ 0x091c89ee , 0x453418cc , 0xf3e2487d , 0xe05509c8 , 0x8a2205c2 , 0x5e3ab7e5 , 0x142748ff , 0x02285f4f ] )
+  , Mont::unsafe_make( [ 0x520489bd , 0x9b3f02b2 , 0x73bdd85b , 0xb12c0cfb , 0xb287a9d5 , 0xdf1be5fa , 0xbbfe8ed3 , 0x189f7541 ] )
+  , Mont::unsafe_make( [ 0xb28dffb8 , 0x4e2be216 , 0xe490b0da , 0x08a42576 , 0x0e4d3220 , 0xfc90b9cb , 0x0b9e5539 , 0x16a24c3e ] )
+  , Mont::unsafe_make( [ 0xe6348464 , 0x1e83612f , 0x0dbaa3af , 0x8e98a455 , 0x4f1dd91d , 0xb69be366 , 0x3acdad78 , 0x2b78fd22 ] )
+  , Mont::unsafe_make( [ 0xcde9ad05 , 0x48c137c3 , 0xdf132266 , 0x411459de , 0x15ff1b3a , 0x0b391114 , 0x6dae629b , 0x255c38fc ] )
+  , Mont::unsafe_make( [ 0xc9f49869 , 0x20a09674 , 0xad56e5d2 , 0xd10483e7 , 0xbaa7053e , 0x71714e80 , 0x545c4ede , 0x19bd6ed1 ] )
+  , Mont::unsafe_make( [ 0x49fd0a6f , 0x81d9d411 , 0x6e5c2075 , 0x46e6effb , 0x6a1fd40c , 0xc5e2be07 , 0x4bb4553f , 0x0821b576 ] )
+  , Mont::unsafe_make( [ 0xdd276d62 , 0x7f8fefcc , 0xbfe97abb , 0x3848e3e8 , 0xd3d6ab7f , 0xb374dbc0 , 0x0a9f5cb8 , 0x15245e5e ] )
+  , Mont::unsafe_make( [ 0x8c4cecba , 0xca402428 , 0x0732673d , 0xb89d122e , 0xf74d02d2 , 0x6ba5ecb3 , 0xe42e499e , 0x2a7036c4 ] )
+  , Mont::unsafe_make( [ 0xe32e155b , 0x96436ab0 , 0x33854100 , 0x59b15c46 , 0x2a750213 , 0x1945aa96 , 0xed36e7c3 , 0x0e0ddf08 ] )
+  , Mont::unsafe_make( [ 0xaa447134 , 0xa105a0e2 , 0xaa945656 , 0x70f504e7 , 0x8552de6e , 0x33468c2e , 0x1b85eb64 , 0x1491ad51 ] )
+  , Mont::unsafe_make( [ 0x9ccc2bcc , 0x8f852e2f , 0x02cbce78 , 0x1a2bb394 , 0x5c270599 , 0x01bfedcc , 0xfa82b010 , 0x26c70f72 ] )
+  , Mont::unsafe_make( [ 0x0c206781 , 0x5bb5c31a , 0x77ef47b5 , 0x1c1c0e70 , 0xa4c26be0 , 0x01497dfe , 0xa0db69ac , 0x15af106b ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xe4f40198 , 0xfa6f0ce3 , 0xd6a7350f , 0xd6eeda51 , 0x4f3ca5ae , 0x9b873550 , 0x0cbca162 , 0x01de323d ] )
+  , Mont::unsafe_make( [ 0xa6d2af29 , 0x024e54f4 , 0x61545a77 , 0x53179f7d , 0x2d4c9127 , 0xa7b55069 , 0xf7503016 , 0x107cda76 ] )
+  , Mont::unsafe_make( [ 0xe0da002c , 0xc6d0e95e , 0x5d8348d0 , 0xcd70f56e , 0xa11e764d , 0xd0378611 , 0xd45b52ff , 0x1fc2ed0c ] )
+  , Mont::unsafe_make( [ 0x3f029530 , 0x5b05869b , 0x3be4c6fb , 0xd924ac27 , 0x7961c967 , 0xe26eeafc , 0xba4ccf38 , 0x2373e203 ] )
+  , Mont::unsafe_make( [ 0xdf7cb22e , 0xe0817311 , 0x89bd6a44 , 0x048a0a50 , 0xfded7bff , 0xca87aa70 , 0xf00729b1 , 0x1e9a852e ] )
+  , Mont::unsafe_make( [ 0xf4b0b188 , 0x207cfc61 , 0x736595b1 , 0x1c922510 , 0xc42d425c , 0x307f2bb7 , 0x4876529c , 0x0abe929d ] )
+  , Mont::unsafe_make( [ 0x3737feb5 , 0x1a36e6bd , 0xea3bb742 , 0x44f15744 , 0x03a40c59 , 0x81b32b4e , 0x974f9af7 , 0x2c74fcf7 ] )
+  , Mont::unsafe_make( [ 0x46367707 , 0xf66781a2 , 0x5e23535a , 0x9f4e8063 , 0xde2380a8 , 0x38df353e , 0x1e9d787e , 0x2e52182f ] )
+  , Mont::unsafe_make( [ 0x60c9c47a , 0x579fd110 , 0x1c3f5fd0 , 0xd6fa0ece , 0x3766f3f1 , 0x71997300 , 0xe5c5b733 , 0x290db336 ] )
+  , Mont::unsafe_make( [ 0x421c8db5 , 0xcce6e9c9 , 0x5e867ae2 , 0x22f33ee2 , 0x4a78772a , 0xc53252b4 , 0x1250b2ad , 0x1bdcaf1c ] )
+  , Mont::unsafe_make( [ 0x7bd6db66 , 0xccc426c9 , 0x31d5fc21 , 0x8f41e9cd , 0x4226cc6a , 0xd7e14b83 , 0xca57c316 , 0x0e9fb2c8 ] )
+  , Mont::unsafe_make( [ 0xca90ff20 , 0x4759d2f3 , 0x546203cc , 0x2f9c8a22 , 0x929332fd , 0xea18d729 , 0x11ebe922 , 0x020fbca6 ] )
+  , Mont::unsafe_make( [ 0x7d633e52 , 0x2a4179e7 , 0xedbc318a , 0x55ab4d99 , 0xd3157eda , 0xe4c1a08a , 0xdd774b02 , 0x28f693aa ] )
+  , Mont::unsafe_make( [ 0x0e71ad33 , 0x7a2025f3 , 0x3fadef3b , 0x08748672 , 0x559a5460 , 0x134bc83d , 0x2ef3ef42 , 0x1822f163 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xfa998474 , 0x8168f987 , 0x8039e80a , 0x99dfb59e , 0x8af3f616 , 0x8c148bf7 , 0x8edc88bc , 0x0c908325 ] )
+  , Mont::unsafe_make( [ 0x1418866a , 0x0a3e6c6a , 0x27f3cea1 , 0x91248b44 , 0x3a814b8b , 0x0197af0d , 0x73f886dd , 0x18f0d1f5 ] )
+  , Mont::unsafe_make( [ 0x8f78d873 , 0x4fd5b8c0 , 0x763a6f81 , 0x0b2792f7 , 0x0289dfa6 , 0x8fba8c0c , 0x58cadb88 , 0x213a59e4 ] )
+  , Mont::unsafe_make( [ 0x9dc7f598 , 0xaf2d434a , 0xa0871a25 , 0x73aa9b16 , 0x756ed0b2 , 0x97f906f2 , 0x8e0c482b , 0x032f6a4f ] )
+  , Mont::unsafe_make( [ 0xad54b589 , 0xa8972f87 , 0xbeb33699 , 0x968aa248 , 0x169f82a2 , 0x7a7a0bbe , 0xe2efb5f8 , 0x0c127b9e ] )
+  , Mont::unsafe_make( [ 0x3a939887 , 0x8e997ba3 , 0x2e778721 , 0x852c85f7 , 0x3165c05a , 0xb9903661 , 0x8d858575 , 0x2f4e4225 ] )
+  , Mont::unsafe_make( [ 0x0369c539 , 0x241659d7 , 0xb8e192e7 , 0xf8fa807a , 0xf119df4f , 0xf041f668 , 0x56c1376b , 0x13077d12 ] )
+  , Mont::unsafe_make( [ 0x5f54c438 , 0x4f7e086b , 0x04e7f149 , 0x3c086f26 , 0xaa628c1d , 0x9773a45a , 0x7f1e7cd3 , 0x1fcc3564 ] )
+  , Mont::unsafe_make( [ 0x6cd386c7 , 0x8421dd84 , 0x17c1d31f , 0x5abf599b , 0x84d48b94 , 0x33f12192 , 0x1ff57891 , 0x1880c4dd ] )
+  , Mont::unsafe_make( [ 0x42593687 , 0xf26b5a1e , 0x72cc8350 , 0xc238e5db , 0xe9e0f16e , 0xd339dbd8 , 0x085f3640 , 0x0a9dd510 ] )
+  , Mont::unsafe_make( [ 0xe2c7a58a , 0x2c165afb , 0xd9b87a05 , 0xf58335e9 , 0x2f60f85c , 0x35997186 , 0x49bc7bb8 , 0x148bccbd ] )
+  , Mont::unsafe_make( [ 0x6ef069fd , 0x13a09532 , 0xfd706b63 , 0x66726b4d , 0x6dc163b3 , 0xbf9fdf0c , 0x7bb0593d , 0x1f42f942 ] )
+  , Mont::unsafe_make( [ 0x86c69280 , 0xbd6c5f1b , 0x1a26043d , 0xa93a6889 , 0x7bec0a5a , 0xbb4a7065 , 0x0330ba8b , 0x2e418745 ] )
+  , Mont::unsafe_make( [ 0x6b5179bd , 0x6abf09c4 , 0x10e86ee4 , 0x770f406b , 0xb23c42e4 , 0x6152c27a , 0x9bda63fa , 0x04763fad ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xf07c4539 , 0x54e083aa , 0x65f1d232 , 0x1f06ecb9 , 0xae927299 , 0xf1c04360 , 0xff44d4b2 , 0x150466a8 ] )
+  , Mont::unsafe_make( [ 0x5a03c282 , 0x561797a1 , 0xab2d70e7 , 0xba0f8cc3 , 0x642936fd , 0xd71bb027 , 0x358efe8a , 0x238926ea ] )
+  , Mont::unsafe_make( [ 0x05876edc , 0x5a7b2037 , 0xe763e6ac , 0xae9dfd4d , 0x6d6241bf , 0x1ea4ce8c , 0x7d2fa585 , 0x1ea44765 ] )
+  , Mont::unsafe_make( [ 0xa0da10f5 , 0x32f15717 , 0x3a2a3452 , 0xeb47a36d , 0xf26e80df , 0x1fc83aee , 0x0cac72b1 , 0x29418858 ] )
+  , Mont::unsafe_make( [ 0x22152210 , 0xf984557a , 0x3abcb127 , 0x9bb922da , 0x3b691d1e , 0x43ba0841 , 0x954fbf00 , 0x1bb663f6 ] )
+  , Mont::unsafe_make( [ 0xe0ffb035 , 0xd4d8a46e , 0x00f5b333 , 0x7effb2fc , 0x58faecbc , 0x63dad460 , 0x99b41476 , 0x1ba2ab47 ] )
+  , Mont::unsafe_make( [ 0x08a42dd7 , 0xe43c1237 , 0x577a474b , 0x6328a9be , 0x74fa1fff , 0x35a7d1f8 , 0x49827f4b , 0x083d3e9f ] )
+  , Mont::unsafe_make( [ 0x511147c6 , 0x1ee48dd5 , 0x9dffba10 , 0xd3a9d39c , 0xede1231b , 0xbd2217ae , 0xd4fd8a3b , 0x0f578e99 ] )
+  , Mont::unsafe_make( [ 0xc3314f2d , 0x3c50e645 , 0x209f2db1 , 0xcbec0504 , 0xc177b091 , 0xc80ee867 , 0x96f4df4d , 0x07024ecc ] )
+  , Mont::unsafe_make( [ 0xc212cf0c , 0x4a19e9c4 , 0x100d14fe , 0xffc1b2a2 , 0xc336dd51 , 0xeaa8d20c , 0x03691d59 , 0x15079520 ] )
+  , Mont::unsafe_make( [ 0xc540874e , 0x888114a7 , 0x2bed61fb , 0x93849895 , 0xf23f67ad , 0xb30cd241 , 0xa9d06b66 , 0x10f05c13 ] )
+  , Mont::unsafe_make( [ 0xe767815a , 0xf76ef7a8 , 0x0fc80680 , 0xbc65b5ed , 0x273cbe8b , 0xca9d7fed , 0x9ded7d96 , 0x1be3db1e ] )
+  , Mont::unsafe_make( [ 0xb5581abe , 0x23ed9319 , 0x4916560e , 0xabfbd210 , 0xd58c2257 , 0x0e0d94ce , 0x8a1c41e8 , 0x0e9e55e0 ] )
+  , Mont::unsafe_make( [ 0x77fd037d , 0x481925ec , 0x3c718552 , 0xf5a88002 , 0x756ab8d7 , 0x2451dbd4 , 0x801718cf , 0x09e423eb ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x9f6d4ac2 , 0x3ce57f41 , 0xc0cdeecf , 0x1de3096e , 0xd5573c89 , 0x5c68598e , 0xb97b6e67 , 0x07809611 ] )
+  , Mont::unsafe_make( [ 0xbaee8f9e , 0x5e527e59 , 0xeb9e1826 , 0x10708f70 , 0xd87abee8 , 0x60cb9555 , 0x9a3c759c , 0x26e54d8f ] )
+  , Mont::unsafe_make( [ 0xc36c3342 , 0x7c767c5f , 0xd0185f6d , 0x8bc024d1 , 0x5b4afb5a , 0x1625b92a , 0xc00ff978 , 0x215eb63f ] )
+  , Mont::unsafe_make( [ 0x13da88e1 , 0xde9b42e8 , 0x1e14d823 , 0xd711985f , 0xf836cfb1 , 0x8624115a , 0x7b9bee77 , 0x04bf28b3 ] )
+  , Mont::unsafe_make( [ 0xf344dc01 , 0x22c3bb34 , 0xe6526240 , 0x9d449bee , 0xe00f47a9 , 0x34d79120 , 0x95aa4c3c , 0x2141a666 ] )
+  , Mont::unsafe_make( [ 0x5fba5f0b , 0xae50212c , 0x0c16fede , 0x71446efb , 0x6ca50907 , 0x8b3aabaf , 0xe130c664 , 0x28cad651 ] )
+  , Mont::unsafe_make( [ 0x52102890 , 0xad3c58f3 , 0x9facf322 , 0x22607f65 , 0x9703df21 , 0xe7a73ac7 , 0x7ef7c998 , 0x211b784c ] )
+  , Mont::unsafe_make( [ 0xf73bb9ee , 0xa9ad4c6b , 0x960d497d , 0xe60d3690 , 0x26e0d3e8 , 0x1adf8ded , 0xbff7637c , 0x2c6de6b0 ] )
+  , Mont::unsafe_make( [ 0x4c757d61 , 0xccb097bb , 0xddfb1f3e , 0x50475f80 , 0xe064b0b7 , 0xd831b4f5 , 0xf8c8d6ea , 0x02e12dc9 ] )
+  , Mont::unsafe_make( [ 0xe227d38d , 0xe0032527 , 0x7160652b , 0x76be47c2 , 0x80951803 , 0x9d825245 , 0xa7843904 , 0x11a16dc4 ] )
+  , Mont::unsafe_make( [ 0xb9696e0f , 0x0eaa9f32 , 0x093a488c , 0x09c796e6 , 0xc01052da , 0x6b81829c , 0xe282f2cc , 0x016bf0ea ] )
+  , Mont::unsafe_make( [ 0xa1005cd4 , 0x0f564781 , 0x339d0056 , 0x6e50c58d , 0x1aa00d7c , 0x4eb28730 , 0x389636c7 , 0x003a057a ] )
+  , Mont::unsafe_make( [ 0xc6e658b2 , 0xc931f0b7 , 0x152b6706 , 0x86e11cbe , 0x659b99d6 , 0xa359d47e , 0x59a2f7ac , 0x22529375 ] )
+  , Mont::unsafe_make( [ 0x1bfa319f , 0x50a9025f , 0x608de846 , 0x6bbe39cb , 0xf8df9203 , 0xa565c57b , 0xc74f1b1d , 0x2996acc1 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x163e15ae , 0x2b03e274 , 0xe25d0bc2 , 0x609a43db , 0x9b954756 , 0x5417953c , 0xe5666cf0 , 0x17ae86c6 ] )
+  , Mont::unsafe_make( [ 0x89159e46 , 0x370c9379 , 0x7c6d9429 , 0x3717421d , 0x22cd54fb , 0x27c82c4d , 0x066bebb4 , 0x1eaaa818 ] )
+  , Mont::unsafe_make( [ 0xb5598979 , 0x2270602d , 0x8678bc46 , 0x20fe463f , 0x08d76dc5 , 0x7a2ad311 , 0xb9cecf1a , 0x0166febd ] )
+  , Mont::unsafe_make( [ 0x9ac90038 , 0xcc71d8a4 , 0x06c2d020 , 0xade575f4 , 0x91622b56 , 0x15120f76 , 0x23d122ca , 0x10395601 ] )
+  , Mont::unsafe_make( [ 0xc9c99914 , 0x13011884 , 0x3d798cf7 , 0x11b6d748 , 0xe21b3928 , 0x80f2f190 , 0x9dc30cdb , 0x28e0cee8 ] )
+  , Mont::unsafe_make( [ 0x7bfff31d , 0x9ca4a483 , 0xbe467841 , 0xeb1b260b , 0xad48de27 , 0xd78411d2 , 0xac998818 , 0x0da60907 ] )
+  , Mont::unsafe_make( [ 0xa3d9c639 , 0x60c0b9fe , 0x567181dd , 0x5f508f1e , 0x9373928c , 0x80b50467 , 0xf6edb753 , 0x2abb06d1 ] )
+  , Mont::unsafe_make( [ 0x7468ff10 , 0x618ec63c , 0x43f6a642 , 0xf9eaa13e , 0x6119c01c , 0x7a7bf8be , 0x38afc0ef , 0x05318b86 ] )
+  , Mont::unsafe_make( [ 0x06e1af77 , 0x2ee33f2b , 0xf519b2b8 , 0xdde5c83f , 0xf0989ec4 , 0x77fe3d75 , 0x32b8ea1b , 0x0d25978b ] )
+  , Mont::unsafe_make( [ 0x8b14870f , 0x3a234636 , 0x08866738 , 0xd850d408 , 0xec8103a7 , 0x2af337e0 , 0x55b3fdab , 0x191aeba7 ] )
+  , Mont::unsafe_make( [ 0xff2adc60 , 0xb828c2b7 , 0xa63e609e , 0x572b697a , 0x75e63f29 , 0xb1d763a1 , 0xd83b3516 , 0x280b3db8 ] )
+  , Mont::unsafe_make( [ 0xfa5d127f , 0x6ed5e342 , 0x37afeda9 , 0x8086c53c , 0x3aed9192 , 0xd36c6f06 , 0x0ec97400 , 0x2ae9358b ] )
+  , Mont::unsafe_make( [ 0xc8044175 , 0x27b05cd9 , 0x2416db80 , 0x64dbba23 , 0xc2aa2155 , 0xd0268011 , 0xbd26b787 , 0x25db1bdd ] )
+  , Mont::unsafe_make( [ 0xcef2c19d , 0x12bb339b , 0x143a38eb , 0xb1c1968a , 0x7503e2de , 0x47ef9f0d , 0x16b6904d , 0x1a588b24 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x163910bb , 0x3406230d , 0x0210f4ef , 0xf7ff313b , 0xe780ed98 , 0x51289a36 , 0xd9f3a1fe , 0x09f92c98 ] )
+  , Mont::unsafe_make( [ 0xee1072b1 , 0x8b4babe5 , 0x9b67274a , 0xe46c9f8e , 0x84e5e8aa , 0xfdc1ec5d , 0xb559ad67 , 0x22815a17 ] )
+  , Mont::unsafe_make( [ 0x7ac0b243 , 0xb6649199 , 0xc483dea7 , 0xa6127406 , 0xfbaa72d9 , 0xcaa113b7 , 0x1b6cac6c , 0x205c03f3 ] )
+  , Mont::unsafe_make( [ 0xa09a560e , 0x6044e970 , 0x72bd9fe0 , 0xd6be50af , 0x96a4cb0f , 0x839e7d55 , 0x6f82fba8 , 0x2d7715c1 ] )
+  , Mont::unsafe_make( [ 0xe248ba9c , 0x8abc8073 , 0x000823b2 , 0x062dca75 , 0x2563ec19 , 0x82e3b7e0 , 0x803a0d00 , 0x29b43d93 ] )
+  , Mont::unsafe_make( [ 0x6a1149de , 0x32a05d82 , 0xe4f165bb , 0x8c0ed194 , 0xa461a367 , 0xce9d85f2 , 0x6266a92c , 0x247cb909 ] )
+  , Mont::unsafe_make( [ 0x47a551b1 , 0x34e91468 , 0x84ef0dbc , 0xcd699b01 , 0x219bedb8 , 0x2da8371c , 0x8db81dde , 0x2506704f ] )
+  , Mont::unsafe_make( [ 0xd985410f , 0x06b69a75 , 0x10508b8e , 0x4047eed2 , 0xfc15a4f7 , 0x63673d07 , 0x9ebfcf96 , 0x098ea18f ] )
+  , Mont::unsafe_make( [ 0xcd30c504 , 0xc6a9ed7d , 0x0c10e2df , 0x5c4e9c90 , 0x9e5fd8a3 , 0x9d4e3241 , 0x8661db65 , 0x170ea3a4 ] )
+  , Mont::unsafe_make( [ 0xb2027963 , 0x6e7b6496 , 0xbe64a9b7 , 0x8d6df9b7 , 0x8f0bb271 , 0xc1f178f9 , 0xa4241cdb , 0x26c6b47e ] )
+  , Mont::unsafe_make( [ 0xb1e59103 , 0x6c3fcb28 , 0xc5fce989 , 0x84df3efc , 0xcd54433d , 0xe31314dd , 0xc828331f , 0x05d4be42 ] )
+  , Mont::unsafe_make( [ 0x886de6ff , 0xd226dd25 , 0x436b69b3 , 0x3a0f70b1 , 0x032c533d , 0x77ed5c58 , 0x08bc439b , 0x05edbc2b ] )
+  , Mont::unsafe_make( [ 0x75fb0f37 , 0x4695d7a2 , 0xe88c6211 , 0x7270cde9 , 0xc6aa8143 , 0xf180019d , 0xc989197b , 0x1a1f9577 ] )
+  , Mont::unsafe_make( [ 0x70343fcb , 0x01af02c4 , 0xddab86b1 , 0x7ed28d56 , 0x4476b776 , 0xc68c00bf , 0x6450592b , 0x0c5acff0 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x2e18fbe2 , 0x5bb0a000 , 0xbd39af9b , 0xf1424975 , 0xeb9d93b1 , 0x41f74124 , 0xcfe9581e , 0x03a67529 ] )
+  , Mont::unsafe_make( [ 0x4a8b02d6 , 0xee596695 , 0x8b1986d3 , 0x7a17877b , 0x89ed8e40 , 0x02b4fe1e , 0x7ff1b8df , 0x2c3edc72 ] )
+  , Mont::unsafe_make( [ 0x171bdb02 , 0xf8a26354 , 0x56a976b0 , 0x4f8e53d2 , 0xb581ed3f , 0x5406867b , 0x8a70f7e5 , 0x12f2f38c ] )
+  , Mont::unsafe_make( [ 0x074a8142 , 0x73ac7827 , 0xf14d16e1 , 0x9e37e4f8 , 0xd5f012f5 , 0x0b9c74e9 , 0x3e2baad0 , 0x16497859 ] )
+  , Mont::unsafe_make( [ 0x4a826dfa , 0x28c45eaa , 0x5e219df4 , 0x293ff950 , 0x3c5aaacd , 0x3ecd4ada , 0x8b060a5c , 0x073ad782 ] )
+  , Mont::unsafe_make( [ 0xf28117ba , 0x99d57613 , 0x724ec4fb , 0x8d7e6e4a , 0x719349fe , 0xa157ac83 , 0x437c06fd , 0x29bc5f05 ] )
+  , Mont::unsafe_make( [ 0x8e9402df , 0x99d55c3e , 0xe764ad99 , 0x47177425 , 0x22355366 , 0xfc78869a , 0x2ed84985 , 0x237137fc ] )
+  , Mont::unsafe_make( [ 0x0b868c8d , 0x16f4a9c4 , 0x60528257 , 0x4a668f59 , 0x39d36df1 , 0x2e3aeb83 , 0x7b2619cf , 0x22257b57 ] )
+  , Mont::unsafe_make( [ 0x66ec04ea , 0x3c8b7315 , 0x855b298d , 0xf330c30e , 0xc1bdad2a , 0xb93d98bf , 0x3eabd33d , 0x1c1b8a7b ] )
+  , Mont::unsafe_make( [ 0x0a8cbc57 , 0x80636d8d , 0x5b9ae779 , 0xc2ba2f66 , 0xd0879884 , 0x3bf8a3d6 , 0x1ad933a9 , 0x21ab536d ] )
+  , Mont::unsafe_make( [ 0x30fbd075 , 0xa47bd7a3 , 0x64c00dd2 , 0x58c639f6 , 0xeaae440e , 0x78908afc , 0xca410b37 , 0x2215fafd ] )
+  , Mont::unsafe_make( [ 0x4ec11c18 , 0x58202cc6 , 0x948403b5 , 0xf95dc8d8 , 0x0d8bd479 , 0x3e970f62 , 0x29bb3db2 , 0x1241930a ] )
+  , Mont::unsafe_make( [ 0x4226f6b5 , 0x0f3ade70 , 0x739eb02c , 0xf490329d , 0x74eaa8f9 , 0xfc36b99f , 0x30448dd5 , 0x2d534142 ] )
+  , Mont::unsafe_make( [ 0xdee6a4f9 , 0x1126dd2b , 0xdf38b78b , 0xb6fc5aee , 0xa0c770bc , 0x31539cb8 , 0xb5cf5849 , 0x18f460ac ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x242fe252 , 0x97a3c3b5 , 0xbfcd317c , 0xff2c252a , 0xd993f69c , 0x220351eb , 0x9bfce905 , 0x18d9f78f ] )
+  , Mont::unsafe_make( [ 0x10b5dc99 , 0x93927ef8 , 0x22f24028 , 0xc30e3ed7 , 0x23499b1b , 0x0de6f6ac , 0x74d45782 , 0x269d198e ] )
+  , Mont::unsafe_make( [ 0x79e28652 , 0x6ab27931 , 0x52ea724b , 0x645a62ca , 0xfc61c173 , 0xb3e4f9b8 , 0x45795a1d , 0x2ae5f063 ] )
+  , Mont::unsafe_make( [ 0x69d0a88d , 0x532592b0 , 0x70f782dd , 0xa95775b3 , 0xffd9aefe , 0x8d8bcc06 , 0xda2a5623 , 0x2fc73ada ] )
+  , Mont::unsafe_make( [ 0xf1cdddc5 , 0x7565ac70 , 0x98bc65e6 , 0xc6e944a6 , 0x4a7702a6 , 0x61f288c5 , 0x4d58937a , 0x19a5371b ] )
+  , Mont::unsafe_make( [ 0x33d16bd6 , 0x53369751 , 0x6b4e9ff8 , 0xe2b2d469 , 0x1efe356f , 0x1218ba80 , 0xd1aec6e1 , 0x2c0303e5 ] )
+  , Mont::unsafe_make( [ 0x561287a4 , 0x610bb4ea , 0x96d6694d , 0x21babf8a , 0x596627da , 0x5723f56a , 0x16f8328c , 0x08b12182 ] )
+  , Mont::unsafe_make( [ 0x74b65ba6 , 0x0b4546f0 , 0x67a71c6f , 0xdf510de1 , 0x38512c16 , 0x35a58676 , 0x3aac11d6 , 0x305a1d69 ] )
+  , Mont::unsafe_make( [ 0x4992080f , 0xced2f74c , 0xbc32d2c3 , 0x6e5568dc , 0xc2fc3f6e , 0xc5c227b4 , 0x79aaaaf5 , 0x09d3474f ] )
+  , Mont::unsafe_make( [ 0x46d609fa , 0xa8165c60 , 0x6ac6fbc8 , 0x4fce9799 , 0x713c1c8f , 0xee9e8ece , 0x998fe12b , 0x2a950816 ] )
+  , Mont::unsafe_make( [ 0xaf8194ce , 0xa400a7a2 , 0x1984d7d1 , 0xcd050510 , 0x293d4307 , 0xc6f99481 , 0xe879d76a , 0x0c8609d5 ] )
+  , Mont::unsafe_make( [ 0xc35aa25e , 0x7e4adbac , 0x69e8229d , 0x69a203cc , 0xea36d164 , 0x246a7968 , 0xe7d53931 , 0x12b99114 ] )
+  , Mont::unsafe_make( [ 0x439ccd7f , 0x4e33a6fe , 0x89eb3785 , 0x1bd81693 , 0x08cdba16 , 0x84b18793 , 0x4739b535 , 0x120b3a47 ] )
+  , Mont::unsafe_make( [ 0x61ed63e2 , 0x1b1eb532 , 0x22d0c692 , 0x842833cc , 0x9a5ceb9e , 0x24a51993 , 0x080cd25c , 0x1b4f0818 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x124b77b6 , 0xc1b757b4 , 0x8d290868 , 0x267a245c , 0x44fb6cde , 0x25d9fce7 , 0x4b922049 , 0x0188b6c9 ] )
+  , Mont::unsafe_make( [ 0x4186c8b2 , 0xdd804a85 , 0xf321936c , 0x2a311802 , 0xc4bec645 , 0x6f23a109 , 0x75fb8800 , 0x0c9722c5 ] )
+  , Mont::unsafe_make( [ 0xe7c04bad , 0x671d6326 , 0xf1ee0ee0 , 0xd60a0c0a , 0xbe915408 , 0xdf55479d , 0x97668f12 , 0x0c46ce64 ] )
+  , Mont::unsafe_make( [ 0xa9cb31bf , 0xb9ccb5bb , 0x71964626 , 0xca0ef65f , 0x7f2fff59 , 0x63793812 , 0x0403a67b , 0x099bf217 ] )
+  , Mont::unsafe_make( [ 0xdb11eff8 , 0x5495d7a1 , 0xdb5dbcf0 , 0xe56da4de , 0x517f71de , 0x4a5607f5 , 0x65a19ddc , 0x05eb6123 ] )
+  , Mont::unsafe_make( [ 0x3459c571 , 0x05ee6527 , 0xc4b36631 , 0x75c0c256 , 0x1de089d2 , 0x2f52ac80 , 0x108c4689 , 0x2f70c9ac ] )
+  , Mont::unsafe_make( [ 0xe6931504 , 0x7d72e849 , 0x2267be26 , 0xcbb0043d , 0xcf688edc , 0xdd3c367c , 0x312aa5b7 , 0x305d70b8 ] )
+  , Mont::unsafe_make( [ 0x496655fc , 0x67f3431c , 0xa1b4671c , 0xf24a7808 , 0x297fbde1 , 0x81ad5c38 , 0x86885b88 , 0x1f92eee6 ] )
+  , Mont::unsafe_make( [ 0x341d8d18 , 0xb45c9359 , 0x3b5e822c , 0x34f1c3c0 , 0xad73303f , 0x4179398f , 0x831a2606 , 0x1d4d5f1d ] )
+  , Mont::unsafe_make( [ 0xdc0e47c1 , 0xe6a3f12e , 0x97e23c7f , 0xebe536af , 0xa55f1638 , 0x30d09475 , 0x64ddfe60 , 0x1b3fb5e9 ] )
+  , Mont::unsafe_make( [ 0x8de6ae67 , 0xe482b316 , 0x6a19b9d9 , 0x10fa8f8e , 0x55704050 , 0x2f94e4fd , 0x64f66c60 , 0x1d6e7e65 ] )
+  , Mont::unsafe_make( [ 0x82a3de8d , 0x7bded8b8 , 0x8be0cf4c , 0xb5e55e4d , 0x0aa0bffa , 0xbf80bab3 , 0x6a6fdad5 , 0x2cccb4ec ] )
+  , Mont::unsafe_make( [ 0x760ce134 , 0xecc28e44 , 0xd4f34fe9 , 0xa1d5a386 , 0xabc392ee , 0x2d8706bc , 0xdb50cd49 , 0x2b43cd99 ] )
+  , Mont::unsafe_make( [ 0x72baa642 , 0xe637b2d8 , 0x83f64d6b , 0x1b506b77 , 0xcac09945 , 0x70c8bef7 , 0x3fa00bd3 , 0x2c951ca3 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xe621c8c6 , 0x34db854f , 0x78f47041 , 0x9cbabf2f , 0xcd6ad468 , 0x0ffa890e , 0x585e19fe , 0x29a743c4 ] )
+  , Mont::unsafe_make( [ 0x06620bb3 , 0x2d2f1b69 , 0x83e5a2c4 , 0x2e4952ab , 0x4383289b , 0x36b16fe1 , 0x9ebd43f9 , 0x069c5723 ] )
+  , Mont::unsafe_make( [ 0xaad82ae8 , 0x396230cb , 0x543ba60e , 0x3a275fce , 0xd3f7fc6c , 0x55584f02 , 0xf13d5f99 , 0x0b6c67cc ] )
+  , Mont::unsafe_make( [ 0x3c7d61a7 , 0x6e6e65e4 , 0x7f718fb1 , 0xe6c5afb0 , 0x468d6936 , 0x52b06dee , 0xa6106ae6 , 0x13be339f ] )
+  , Mont::unsafe_make( [ 0xbb504a6b , 0x187ddfe4 , 0xfc2e9929 , 0x6529d815 , 0xd90dd9c4 , 0x06c2286d , 0xe0fc54d6 , 0x2a36eced ] )
+  , Mont::unsafe_make( [ 0x4f1bd5ff , 0xe3cc7d49 , 0xa8767d17 , 0x686ae6b4 , 0x5f4e7af0 , 0x44ecacc1 , 0xf22d8819 , 0x23b165f9 ] )
+  , Mont::unsafe_make( [ 0x74ce142f , 0x690194e8 , 0xbc4934b1 , 0xa058469e , 0x92c5ca32 , 0x409dab32 , 0x9395fe47 , 0x2c939cac ] )
+  , Mont::unsafe_make( [ 0x75816667 , 0x767e11d3 , 0x6a38dbbf , 0x67e5d5bb , 0x71ce47b0 , 0x1d9c500b , 0x1dde82e1 , 0x233ca822 ] )
+  , Mont::unsafe_make( [ 0xf4340994 , 0x113bc592 , 0x27244685 , 0xf11c34d5 , 0x7fb229d8 , 0xec3ba2f2 , 0xe0ea2e0d , 0x06d371de ] )
+  , Mont::unsafe_make( [ 0xc6ee7961 , 0x8b7d4edf , 0xb5e051ab , 0x03878dfb , 0xbaf8c8df , 0x38e57fc7 , 0x5d445184 , 0x18d0fb10 ] )
+  , Mont::unsafe_make( [ 0xcef4012d , 0x6f364760 , 0x4e685143 , 0x0c47d0b3 , 0x1f02705e , 0x209fed94 , 0x5909fda8 , 0x13dbd01b ] )
+  , Mont::unsafe_make( [ 0x63b060d4 , 0x8899fe44 , 0xcd00f83d , 0xfda0954b , 0xf47ddcbd , 0x2ba24515 , 0x9602fd8a , 0x2784c6f7 ] )
+  , Mont::unsafe_make( [ 0xab5b4956 , 0xda52d1e2 , 0xab3f4a34 , 0xef75bfaa , 0xda4ee050 , 0x88dc5879 , 0x9bdfde17 , 0x261dbf14 ] )
+  , Mont::unsafe_make( [ 0x1e0ffced , 0x3b46f7a8 , 0x1ae69908 , 0x228ef392 , 0xdba80131 , 0x25dc3b2a , 0x66253c92 , 0x2841c55f ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x42c38fb6 , 0x5ab5c266 , 0xa6dbaa64 , 0x9b215c2c , 0x6f132d76 , 0x97134652 , 0x9f7925b8 , 0x07985098 ] )
+  , Mont::unsafe_make( [ 0xb3ad0228 , 0xb3c309a2 , 0x11a8c9c7 , 0x52df62ee , 0x6dd98c87 , 0x4d04573b , 0x5f4d4947 , 0x0311b0de ] )
+  , Mont::unsafe_make( [ 0x5ffdf772 , 0x4489980e , 0xda9c7683 , 0x9eddfe93 , 0x2f254384 , 0xc9e7e637 , 0xf28d821b , 0x165019a7 ] )
+  , Mont::unsafe_make( [ 0xfd6df865 , 0x21d9d3ca , 0x46599bc9 , 0xa6706f7b , 0x527d0315 , 0x310d81a8 , 0xd6ea3044 , 0x039ec855 ] )
+  , Mont::unsafe_make( [ 0x67c14d41 , 0x42efa74c , 0x0797c16b , 0x78b0bf25 , 0x367245e0 , 0xbea984e6 , 0x424eda39 , 0x16e5ae50 ] )
+  , Mont::unsafe_make( [ 0xa893784f , 0xe979847a , 0x0b804c90 , 0x8b426f87 , 0xccf6ebf1 , 0x28db9c18 , 0x21c66bef , 0x12060782 ] )
+  , Mont::unsafe_make( [ 0xdafb9b7a , 0x0441456a , 0x2312d798 , 0xa67f6364 , 0xc4ca712b , 0x19e0ac12 , 0x96971b04 , 0x29c652d3 ] )
+  , Mont::unsafe_make( [ 0x76d842ed , 0x8ceb79d9 , 0x9783ff50 , 0x5d59f235 , 0x5809b63b , 0x8f115c2e , 0xa3bb65c0 , 0x288885b3 ] )
+  , Mont::unsafe_make( [ 0x75783b6d , 0x31584ee8 , 0xab0bf621 , 0xfe73a96e , 0x0a696175 , 0x861827ab , 0x987a3eab , 0x241d6adf ] )
+  , Mont::unsafe_make( [ 0x7ee794ed , 0xeb69afcb , 0xb6b8df76 , 0x31b6ea41 , 0xda5cdaf1 , 0xc3ddbbc1 , 0xe082b60a , 0x2f9e6741 ] )
+  , Mont::unsafe_make( [ 0x061e6ec8 , 0xcdad0c67 , 0x841c462a , 0x1d5c0c39 , 0x16c570e2 , 0x4313d189 , 0x4fc4e404 , 0x1a3f4482 ] )
+  , Mont::unsafe_make( [ 0xa9f260f9 , 0xfa62345f , 0x4d42694b , 0xc36a9b3c , 0x98f14bb2 , 0x490a4014 , 0xfa2be7ea , 0x12755191 ] )
+  , Mont::unsafe_make( [ 0x3e92937b , 0x700c1cf1 , 0x1f742b96 , 0x2ed58e67 , 0x5497a10c , 0xaa17744f , 0x429955ad , 0x01ad03f7 ] )
+  , Mont::unsafe_make( [ 0x6dce50bd , 0xdd14098f , 0xac3b5bf2 , 0xd290c8b5 , 0x3f20c642 , 0xddf35d69 , 0xb7f04016 , 0x287cef0f ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x532da1e1 , 0x0a8a3cec , 0x81efd026 , 0x7cbfd60d , 0x56f568c1 , 0x4354971e , 0x85093f4b , 0x24f6d8bf ] )
+  , Mont::unsafe_make( [ 0x4401f575 , 0xb346df28 , 0x4c26cbfd , 0x315c94c4 , 0xdb35013a , 0x998573c2 , 0xe2556c80 , 0x1af65634 ] )
+  , Mont::unsafe_make( [ 0x103d3312 , 0x88dda3ea , 0x3d5b8d75 , 0xd5747d3c , 0xfd33b233 , 0x1256c68b , 0x8caa86b5 , 0x1a11a913 ] )
+  , Mont::unsafe_make( [ 0x72527459 , 0xa605ba71 , 0x80c6fd12 , 0xec328c66 , 0x29e9d13f , 0xc71b410d , 0x17816ad8 , 0x2eeea9da ] )
+  , Mont::unsafe_make( [ 0xbec54588 , 0xb3fd39e8 , 0x3d8920ec , 0x1f64055f , 0x0d7a8de8 , 0xef8eee86 , 0x2cd101c1 , 0x0045766d ] )
+  , Mont::unsafe_make( [ 0xdb6f7e5f , 0xdf06708a , 0xfcc550c8 , 0x4b20ede3 , 0x6611ae9e , 0x442eccd3 , 0x4a308412 , 0x2f5b769d ] )
+  , Mont::unsafe_make( [ 0x4ef7d437 , 0x9a5d6b85 , 0x4b1fdcd1 , 0x5c73eb11 , 0xd902983f , 0x46f7ec7c , 0xe0aaa422 , 0x21866100 ] )
+  , Mont::unsafe_make( [ 0x9e58433f , 0xbef08f80 , 0x4e622d42 , 0x3fade013 , 0xb1375b43 , 0xd6635272 , 0x1087f71d , 0x2ad4183a ] )
+  , Mont::unsafe_make( [ 0x605f44ca , 0xd78a86c1 , 0x218b28f5 , 0xc0362cc9 , 0x9a3c6e3a , 0x99b00959 , 0xba27ce2c , 0x12c59fa7 ] )
+  , Mont::unsafe_make( [ 0x354ac00d , 0x74f41c2f , 0x4cdfaa70 , 0x20683c00 , 0x478e58fb , 0x37886b0e , 0x5e4b9faf , 0x272127b7 ] )
+  , Mont::unsafe_make( [ 0x1fe491f7 , 0x7280c36d , 0x2a73162b , 0xd9d578ad , 0x4415bcee , 0x21abf3b4 , 0x7e3e366c , 0x2b246d2d ] )
+  , Mont::unsafe_make( [ 0x1631150d , 0xd6a77b19 , 0xef3aa60d , 0x26388fd0 , 0xcf1eead3 , 0xe18d22a9 , 0xca23f151 , 0x2c146eb5 ] )
+  , Mont::unsafe_make( [ 0x3bf392ff , 0xa710a54f , 0x799d8074 , 0x6aef294d , 0x4ea4dc12 , 0xff6b3599 , 0xa8eefac8 , 0x0d71df50 ] )
+  , Mont::unsafe_make( [ 0x85e577be , 0x417f72ac , 0xda131611 , 0x5c460dbc , 0xaebd6f97 , 0x8472e7a1 , 0x944a8aa0 , 0x132fe4b3 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xf596accb , 0xd8924f9a , 0xc3b57897 , 0xdfafc193 , 0x6d6db3e1 , 0x4d5acc3b , 0xefdb8970 , 0x2fa88580 ] )
+  , Mont::unsafe_make( [ 0xdd1ea3f4 , 0x77b5a67e , 0x8a2d3662 , 0x0ee505d7 , 0x55ca68ca , 0x9d2967f3 , 0xcd383545 , 0x1a74e070 ] )
+  , Mont::unsafe_make( [ 0x59d84247 , 0x4c4ad9e4 , 0x294e5df2 , 0x338e0f72 , 0x375a196b , 0x52c3588e , 0x21cad358 , 0x00177f3a ] )
+  , Mont::unsafe_make( [ 0x78640845 , 0x83226c79 , 0x779940d8 , 0xb9e39c74 , 0x985c6b22 , 0xffd51732 , 0xaa2a348f , 0x2ab24463 ] )
+  , Mont::unsafe_make( [ 0x8e2faca3 , 0x92a92f46 , 0x84dd9a81 , 0xce2b58ec , 0x271be8d3 , 0x8fb190a9 , 0x7277e292 , 0x06eb162a ] )
+  , Mont::unsafe_make( [ 0x9ee13671 , 0x44c4e853 , 0x03c679c5 , 0x7ab5dbe3 , 0xd59f0b78 , 0x055d6d67 , 0xd5aa6826 , 0x219d2ff2 ] )
+  , Mont::unsafe_make( [ 0x507a4dbc , 0x3d011fe6 , 0x0c2c6bd5 , 0xb6ce5ebe , 0x8fb04bd4 , 0x2a88c97b , 0x8562484b , 0x103a14f9 ] )
+  , Mont::unsafe_make( [ 0x90328695 , 0x324aadbb , 0x1c45b6b0 , 0x92b20869 , 0x1bd42196 , 0x46e7be43 , 0x073894b7 , 0x0c83c7c6 ] )
+  , Mont::unsafe_make( [ 0xfae8e1ab , 0x4c95a6f3 , 0x81229dd7 , 0x52f37b6f , 0x4322b649 , 0xd14be464 , 0x03833b5e , 0x2440fbe2 ] )
+  , Mont::unsafe_make( [ 0xccbe0f27 , 0x7038389b , 0xbfe8e3fe , 0x8c58c547 , 0xad98d5a6 , 0x33cfc41f , 0x020233bf , 0x2936c6b2 ] )
+  , Mont::unsafe_make( [ 0xdda60e35 , 0x2f9738eb , 0xc15e0bbb , 0xf2878e39 , 0x95b3c1dd , 0xbe0294b9 , 0xba2e2390 , 0x1ed164ce ] )
+  , Mont::unsafe_make( [ 0x074d9004 , 0x564165ee , 0x7315b12d , 0x392cd4d8 , 0x9841b358 , 0x969564c1 , 0xbb545389 , 0x0f986599 ] )
+  , Mont::unsafe_make( [ 0xe1efaa83 , 0x72ccf5fe , 0xfc5e40c8 , 0x91222685 , 0x44d761b1 , 0xb78e4465 , 0x052718cc , 0x12059202 ] )
+  , Mont::unsafe_make( [ 0x533272b3 , 0xa8170a90 , 0x0d19ceeb , 0x0a1a5025 , 0x803d97d7 , 0x57aede99 , 0xa2acf038 , 0x05ef0108 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x087808cf , 0x9e2730db , 0x472f7fd8 , 0xbd2ff8d2 , 0x685b86a4 , 0x60dd1410 , 0x90a51da3 , 0x096123af ] )
+  , Mont::unsafe_make( [ 0xf1766acf , 0x9b0d58c5 , 0xa432bd50 , 0xafab36e4 , 0x31d5fa3e , 0x9a0e38b0 , 0xfffbf163 , 0x0ab46516 ] )
+  , Mont::unsafe_make( [ 0x88b026b5 , 0x746adb05 , 0xca3ebebe , 0x41a5bc95 , 0x3cae48d0 , 0x814ff23d , 0x3c8fe548 , 0x26ca1c61 ] )
+  , Mont::unsafe_make( [ 0xc30e267e , 0xb7863896 , 0x1648e919 , 0x52c291b6 , 0x1e816c3c , 0x9dc05cee , 0x0cd7fc5d , 0x0e97e930 ] )
+  , Mont::unsafe_make( [ 0x47e8143f , 0x3b735cba , 0x9140e648 , 0x829b34c2 , 0x7b0deaa0 , 0x81261bfa , 0xc551c5c7 , 0x1bac93a3 ] )
+  , Mont::unsafe_make( [ 0xea9d81f1 , 0x1d0503b5 , 0x8a416f18 , 0xc1c53163 , 0x05c31150 , 0x0c56e592 , 0xcfb0f952 , 0x169abf27 ] )
+  , Mont::unsafe_make( [ 0x6ae16251 , 0x0055ec14 , 0xea754acd , 0x3fb6d18d , 0x73470488 , 0x50cc0207 , 0x7a92746b , 0x08f0b791 ] )
+  , Mont::unsafe_make( [ 0x949f5650 , 0xe346bd13 , 0x81fa92ac , 0x27849f07 , 0x942cf230 , 0xc14e7d05 , 0xc93393b1 , 0x02b1b75f ] )
+  , Mont::unsafe_make( [ 0x5a9c84db , 0x1a1e4e1a , 0xe2623442 , 0x1a4c1cf7 , 0x24c297f0 , 0x17ead7f6 , 0x86208c55 , 0x266f76b2 ] )
+  , Mont::unsafe_make( [ 0x22c9a15e , 0x38ddb4f2 , 0xa0be7950 , 0x62ef9125 , 0x76b2cfad , 0x9cf18ddc , 0x9f5f4f9e , 0x0c568593 ] )
+  , Mont::unsafe_make( [ 0xce0b82cd , 0xb7c4e149 , 0x81a1c1f5 , 0xacb7973b , 0x16a7408a , 0x7f82d98b , 0xf2833624 , 0x00a87a04 ] )
+  , Mont::unsafe_make( [ 0x4d1cd38f , 0x980b2219 , 0x71f48c67 , 0x5b92c21d , 0x8bdfea66 , 0x68e9205e , 0xfeccfc98 , 0x0afef84c ] )
+  , Mont::unsafe_make( [ 0x5036a75b , 0x6f3b2156 , 0x2466ac59 , 0xd21fe165 , 0x4ab072bb , 0xe86d9743 , 0xd0fe6ac7 , 0x07c71e8e ] )
+  , Mont::unsafe_make( [ 0x47fa78fe , 0xc186c36e , 0x4476cb03 , 0xb1421c17 , 0xfb4f9205 , 0xdaf49b97 , 0x3485bd54 , 0x27f5f263 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x211d2f9b , 0x576e2bb1 , 0x2a82f77e , 0xa33bfcd6 , 0x9971619f , 0x1e4ec2cb , 0x0e4f4184 , 0x144b8f6b ] )
+  , Mont::unsafe_make( [ 0x5f07da5a , 0xab92ab25 , 0x9d689e1a , 0x5e2b9ad0 , 0x85ab5f83 , 0x2dcb7ed5 , 0x28a6a85f , 0x07cf3433 ] )
+  , Mont::unsafe_make( [ 0x8812a21c , 0x6009d2cd , 0x00a58e87 , 0xe56ba3b6 , 0xfdc845eb , 0x4a7e8728 , 0xed78fe95 , 0x2ff7644b ] )
+  , Mont::unsafe_make( [ 0x949fbc45 , 0x24f3394b , 0x6c114fdc , 0xf5db4bec , 0x859bb5f7 , 0xbe0400d9 , 0xd83b2b97 , 0x0a4ddb84 ] )
+  , Mont::unsafe_make( [ 0xd5527fd2 , 0x1a9161e2 , 0xeb2314e0 , 0xce4bc6f0 , 0x1602ba6d , 0xfe6e4e13 , 0xe872ed28 , 0x02b6bfd9 ] )
+  , Mont::unsafe_make( [ 0xb0ee02b5 , 0xfc68fd33 , 0x0ae99427 , 0x73f135ae , 0x1a25e45b , 0x8f7bc873 , 0x1de5d6bd , 0x135d88b1 ] )
+  , Mont::unsafe_make( [ 0x1567672e , 0xdbfadc85 , 0x0a4f1d20 , 0xd64a7f19 , 0x6b0ba429 , 0xc92739fd , 0xd4d0e030 , 0x29dfa39f ] )
+  , Mont::unsafe_make( [ 0x555c095d , 0xd205fd8b , 0x78b6eacc , 0xf6156d8c , 0x86b79ed5 , 0xfe1073e4 , 0x323393f1 , 0x200b72af ] )
+  , Mont::unsafe_make( [ 0xcfd01807 , 0x26d9e894 , 0x23e2e28b , 0x903dff1a , 0xd17ce32d , 0x7585ba1e , 0x3f43b439 , 0x2af650dc ] )
+  , Mont::unsafe_make( [ 0x18dd9c45 , 0xbc34af49 , 0x5e3e0de7 , 0x5f4f78c2 , 0x0409dd83 , 0xf359a0bf , 0xe21e63d6 , 0x0628513e ] )
+  , Mont::unsafe_make( [ 0xf3759ccd , 0xfcd68a40 , 0x3b8e37d3 , 0xfd93f191 , 0x71fbabcf , 0xc484d1c8 , 0xdc3b5d56 , 0x128ddeb1 ] )
+  , Mont::unsafe_make( [ 0x1b057ccc , 0x610fb218 , 0x036d4993 , 0x6a107c51 , 0x6a8e4d41 , 0x7fd38053 , 0xccea4b99 , 0x079f7c5d ] )
+  , Mont::unsafe_make( [ 0x1080ff4b , 0xb9359a41 , 0xb4123217 , 0x384cf048 , 0x07e9eb61 , 0xeba903e2 , 0x04154202 , 0x004b46e0 ] )
+  , Mont::unsafe_make( [ 0xa4ebf873 , 0x89253696 , 0xdebcdf20 , 0xedad0ff4 , 0xcb7c8734 , 0xd596fed1 , 0x734851b3 , 0x2aa0c4be ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xe7f03162 , 0x04fce40e , 0x6164180b , 0xf62869e1 , 0x5436f18a , 0x0dcd7d34 , 0x4466a1af , 0x054de7c9 ] )
+  , Mont::unsafe_make( [ 0x409d5502 , 0x033302ae , 0x33352206 , 0x1d84f266 , 0xeb184040 , 0x9a293b9c , 0x435e0387 , 0x0681d8af ] )
+  , Mont::unsafe_make( [ 0x7c642e15 , 0xd20c8c12 , 0x03229299 , 0x8cbf9fd9 , 0xb9576567 , 0x47c91a71 , 0xda1fc6be , 0x0a1a32e2 ] )
+  , Mont::unsafe_make( [ 0xcf5608c8 , 0x8493ebe0 , 0xd382b0a5 , 0x95adb0fb , 0x231b922b , 0x37516a9a , 0x0dc93450 , 0x045631f5 ] )
+  , Mont::unsafe_make( [ 0x4ddcd022 , 0xf9baef57 , 0x77762858 , 0x861329a2 , 0x2e163957 , 0xdf27bf7d , 0x0a7bf89f , 0x29a79d74 ] )
+  , Mont::unsafe_make( [ 0xe9a60eb3 , 0x44524173 , 0xcbee055a , 0xb384ade1 , 0xc9749fa7 , 0x40ef29b1 , 0x1bc8ec70 , 0x2c087e09 ] )
+  , Mont::unsafe_make( [ 0xa059b786 , 0x927b1ac8 , 0x716e22f6 , 0x1c131d5d , 0x93ae4d41 , 0x67b32d2c , 0xcf9da683 , 0x1925de33 ] )
+  , Mont::unsafe_make( [ 0xd094b643 , 0x1f17158c , 0x0d9c9dd8 , 0x5a52110a , 0x11214ef1 , 0x0712703a , 0x42a26a37 , 0x2e927c5b ] )
+  , Mont::unsafe_make( [ 0x0f9d5813 , 0xb1909c6f , 0xa88b37b6 , 0x7a38ef96 , 0xaf952ff9 , 0x018e06fb , 0x9c9e069b , 0x17bf39aa ] )
+  , Mont::unsafe_make( [ 0x441fca74 , 0xa351b63b , 0x5b619174 , 0xef67013b , 0x1aaebcbc , 0x3d44382d , 0x94ad8d10 , 0x2045d8b3 ] )
+  , Mont::unsafe_make( [ 0x50c2f5a0 , 0xbae5d083 , 0xebd97aa7 , 0x38be7b6a , 0x8127f724 , 0x409fd25a , 0xf627fca2 , 0x21941dcc ] )
+  , Mont::unsafe_make( [ 0xf69e55b8 , 0x98752f39 , 0x9dbd9317 , 0x5e6a63a6 , 0x6ed29346 , 0x4a043181 , 0xd14c6ef7 , 0x2cfe4c1e ] )
+  , Mont::unsafe_make( [ 0x7d403408 , 0xbce59814 , 0xfa3cb4af , 0x20b6c542 , 0xaecd1305 , 0x2a4d6928 , 0x16a5fd0c , 0x0aa2b1e2 ] )
+  , Mont::unsafe_make( [ 0x7b8bcb48 , 0xa8d6fcd5 , 0xc56f7af6 , 0xa292cffc , 0x4aba36cd , 0x8920df98 , 0x4150e968 , 0x1dd04f2d ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xc6310dbc , 0x4dfd7500 , 0x285bf484 , 0xbc0103de , 0xbdc5a806 , 0xa51d0f53 , 0x05b8f6a4 , 0x03bfae02 ] )
+  , Mont::unsafe_make( [ 0xac6a3170 , 0xe7122e10 , 0x245b277a , 0x67c42c56 , 0x886cf3ab , 0xd1f432be , 0x6cda0909 , 0x0cd1f0e6 ] )
+  , Mont::unsafe_make( [ 0xb749dade , 0x71c21271 , 0xb9045892 , 0x8cb9f631 , 0x83ce63fc , 0xad3e0230 , 0x5179f00f , 0x082965f1 ] )
+  , Mont::unsafe_make( [ 0x512c4d7d , 0x3aa421d2 , 0xd78e4e8d , 0x0ab75422 , 0xecd15205 , 0xde540618 , 0xa1726588 , 0x283b0bda ] )
+  , Mont::unsafe_make( [ 0xa41be0fb , 0xeee496e7 , 0xa35eb228 , 0x5b7b4384 , 0xc45bbb28 , 0x58629db4 , 0x16b6a63e , 0x1e739554 ] )
+  , Mont::unsafe_make( [ 0x07f9b31e , 0x2e251e69 , 0x15d5e56f , 0x5613374b , 0x5820c630 , 0x1d209bad , 0x3641bc8e , 0x0b91a397 ] )
+  , Mont::unsafe_make( [ 0xd04575b8 , 0xff8737b5 , 0x997f8171 , 0x0402fa66 , 0xde6a50a0 , 0x66af0dd2 , 0xa690c9eb , 0x0e5153bc ] )
+  , Mont::unsafe_make( [ 0x3c885e5b , 0xf034607b , 0x0cb7bdf6 , 0x5759e83a , 0xb3641da3 , 0x296881f4 , 0x6c9ff7ae , 0x2bcdc9b9 ] )
+  , Mont::unsafe_make( [ 0x2c246dc2 , 0x8dc5f9c3 , 0x03b89b56 , 0x2d8e61b1 , 0xe4f206b5 , 0x2c41aac1 , 0x0485f0bf , 0x2a17be45 ] )
+  , Mont::unsafe_make( [ 0xe3d708d0 , 0xa268d4aa , 0x88d3b8d9 , 0xa2d278a2 , 0x2094175f , 0x68f99141 , 0xd06190bd , 0x0b498d36 ] )
+  , Mont::unsafe_make( [ 0x7b9e418c , 0x1489d793 , 0xeebd4b1d , 0x51a695c8 , 0x26be914e , 0xb127d56c , 0x4d30fe32 , 0x1876e43f ] )
+  , Mont::unsafe_make( [ 0x36cd1187 , 0xb33dbf19 , 0xad9dd131 , 0x24b76f1f , 0x3a0d8b82 , 0x74df4ddc , 0x9e214572 , 0x05d25631 ] )
+  , Mont::unsafe_make( [ 0x1e3c59f7 , 0x10539542 , 0x51d315ba , 0x5343bdd8 , 0x00f4702e , 0xbf18e216 , 0xc3a34efe , 0x021e8537 ] )
+  , Mont::unsafe_make( [ 0x438272e6 , 0xa6f7b98b , 0x4a8f1134 , 0x06874c9c , 0x0737ee36 , 0x758c3b3b , 0x83f66d75 , 0x2910b64d ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x90db8fca , 0x038b44fd , 0x4c6d9f38 , 0x0cc69a04 , 0x46daf77b , 0x7e2f928e , 0x0a347c60 , 0x12760e71 ] )
+  , Mont::unsafe_make( [ 0x963602ff , 0xc4fb40d0 , 0xf7557059 , 0x6b2acf23 , 0x99bf6592 , 0x241fc882 , 0x0ab3f29d , 0x0b8a69db ] )
+  , Mont::unsafe_make( [ 0x2a04e046 , 0x5e6749ed , 0xe894ac13 , 0x08ca0c52 , 0x7fd6ad11 , 0x6714d4ea , 0x9af3ef3c , 0x0f23cb46 ] )
+  , Mont::unsafe_make( [ 0x1b9d910b , 0x5b285b3a , 0x967311a6 , 0x72bdbfe6 , 0x8954fee1 , 0x60818384 , 0x9b699b70 , 0x21914c63 ] )
+  , Mont::unsafe_make( [ 0x1bcb985f , 0xa7844e92 , 0x76fd31a8 , 0x8520a23c , 0x73979f06 , 0xfb25aee1 , 0xc69e5bfe , 0x25925813 ] )
+  , Mont::unsafe_make( [ 0x9c51de13 , 0xd595ce3b , 0x527c84d9 , 0x66f5f591 , 0xadcab14e , 0xeacc70c6 , 0x5199c5f5 , 0x1653aeaf ] )
+  , Mont::unsafe_make( [ 0x29bd6975 , 0xf976095a , 0x1fee2963 , 0x54050d4b , 0x549c5634 , 0x2a152b16 , 0xf98143cb , 0x0b95012f ] )
+  , Mont::unsafe_make( [ 0x4d38044f , 0x0c9c0208 , 0x53812caf , 0xc04fd8fc , 0xe084ce27 , 0x54f2124e , 0xdcfbbc4f , 0x06474adf ] )
+  , Mont::unsafe_make( [ 0xee10f52a , 0x9bcade8d , 0x23d446dc , 0x2c3cc61b , 0x2c889107 , 0xedf0a01a , 0x584db896 , 0x215e582e ] )
+  , Mont::unsafe_make( [ 0xda82a863 , 0x3560c62e , 0x1079c012 , 0x47ff7d69 , 0xd15c0eaf , 0x7e899a48 , 0x77b7d85d , 0x22967a30 ] )
+  , Mont::unsafe_make( [ 0xf2530e0b , 0x9a3ac4f9 , 0xfe5e6920 , 0xbfeb56d9 , 0x74932594 , 0x47bd1ad7 , 0x97f1ed4d , 0x156ff751 ] )
+  , Mont::unsafe_make( [ 0x83921d25 , 0x909efebf , 0x834169fa , 0x548193ff , 0x1ae23d47 , 0xd7a65600 , 0x022e0b1c , 0x2937071b ] )
+  , Mont::unsafe_make( [ 0x2d9db896 , 0x38f6783f , 0x677647bb , 0x8a710791 , 0x7eb176a5 , 0x6ec15155 , 0x593544fb , 0x1d90849e ] )
+  , Mont::unsafe_make( [ 0xb857a3c0 , 0xac4470cc , 0x2cee4c9d , 0xd091f328 , 0x1e260edf , 0xc4023fc9 , 0xe1f250bb , 0x2943937e ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xe7a47ede , 0x008ae9ed , 0x5939b75a , 0x597703e3 , 0xa74300c2 , 0xbf9f83b1 , 0xe0242b46 , 0x13472715 ] )
+  , Mont::unsafe_make( [ 0x99fef7cc , 0x2c3e244a , 0x59fb2bbc , 0x0a65c2f2 , 0x557941d1 , 0x3be2d741 , 0xf5e87d37 , 0x0aaa0140 ] )
+  , Mont::unsafe_make( [ 0xd29dfba2 , 0xbccb30dd , 0x866c8930 , 0x72f64e0d , 0x82d883cc , 0x2f32f71c , 0xfbe57e73 , 0x290ad220 ] )
+  , Mont::unsafe_make( [ 0x16537bee , 0x2a2716bf , 0x73556bad , 0x9dac86f1 , 0xb363d13f , 0x413c6eae , 0xc3fe9995 , 0x09184d6f ] )
+  , Mont::unsafe_make( [ 0x6d8e9a99 , 0x46bc75ca , 0x362cf44b , 0x36467df1 , 0x6c57c457 , 0xcd7bdb26 , 0x96122c7d , 0x1123dbbf ] )
+  , Mont::unsafe_make( [ 0xbc14f560 , 0x9b42917c , 0x445ebc48 , 0x95844fa3 , 0x72949cc8 , 0xe7d19ef8 , 0x84409c3a , 0x239ff148 ] )
+  , Mont::unsafe_make( [ 0x6f983701 , 0xe7c14fe2 , 0xd055bbd9 , 0x000d592d , 0x13594749 , 0xd4435ab8 , 0x57a7a7c6 , 0x07508af8 ] )
+  , Mont::unsafe_make( [ 0xe6bf68ca , 0x4a2a2d8f , 0xbbab7f50 , 0x8f2dfe38 , 0x7f4974e6 , 0x7e278589 , 0x9bd98634 , 0x25ce9a0b ] )
+  , Mont::unsafe_make( [ 0x7bcadee9 , 0x0594ba4b , 0x5d80c936 , 0x7441f37a , 0xa3157019 , 0xbdd728c9 , 0x2886a26e , 0x2fad3df8 ] )
+  , Mont::unsafe_make( [ 0x13edc0a9 , 0xc73f0ef5 , 0x7c507138 , 0x09ad24ea , 0x4c32453e , 0x8f710aa0 , 0x85c8bfc2 , 0x2015b7bf ] )
+  , Mont::unsafe_make( [ 0x29d0615c , 0xdcdfdbb0 , 0xd9197e3c , 0x0ced5026 , 0x2fa972c3 , 0x475b031e , 0xcda76c17 , 0x278ffd72 ] )
+  , Mont::unsafe_make( [ 0x54d5bc71 , 0x358a6c65 , 0x5a7c40de , 0xfaad57cb , 0xe7dea56a , 0x80b53b8b , 0xa3758891 , 0x24d94119 ] )
+  , Mont::unsafe_make( [ 0x25dc2eed , 0x19f8a6b1 , 0x10294340 , 0xd80aa64b , 0x818db240 , 0x7853bbc6 , 0x5a55b93c , 0x20ddbe0c ] )
+  , Mont::unsafe_make( [ 0x3441326d , 0xd0fc5fa4 , 0x04e98469 , 0x9520a1f2 , 0x051720df , 0xe7c27ada , 0x75ef2960 , 0x244b8e26 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x67650068 , 0x772b3cd0 , 0xf19da9f3 , 0x59f15cde , 0x16c8efe2 , 0x28c20a8c , 0xc87f0831 , 0x0cc57b91 ] )
+  , Mont::unsafe_make( [ 0x8b095ad7 , 0x99bfc9e4 , 0x4459eaf9 , 0x0bc25604 , 0x13598775 , 0xeebacf43 , 0x221cc5bc , 0x25892623 ] )
+  , Mont::unsafe_make( [ 0xa86e122b , 0x269dbbe1 , 0xdfb242e0 , 0x84f78f0a , 0xd1485f7c , 0xa3399bb1 , 0x344b99c4 , 0x16512791 ] )
+  , Mont::unsafe_make( [ 0xd16b577d , 0x759f4651 , 0x16ef431d , 0x0b1d15f3 , 0x93553d8b , 0x8ff6a78c , 0x426a0673 , 0x1b716e36 ] )
+  , Mont::unsafe_make( [ 0xc7e8d900 , 0x035a78bd , 0x033b8a8a , 0x7a8b55a9 , 0xba561124 , 0x9546dc32 , 0x8a8af1db , 0x0fbeebc2 ] )
+  , Mont::unsafe_make( [ 0x3702120e , 0xbe67dab5 , 0xa7c289d0 , 0x716afed1 , 0x28fa03de , 0x95f0d2be , 0xa56715e3 , 0x27283164 ] )
+  , Mont::unsafe_make( [ 0x4c5937be , 0x0cff41c5 , 0x99a5282f , 0x4ff403b6 , 0x000fb897 , 0x824a270d , 0x13542ce9 , 0x02f54e46 ] )
+  , Mont::unsafe_make( [ 0xfdd40f69 , 0xfe35b9a1 , 0xf953f4f4 , 0xde592c53 , 0x586748f6 , 0xd8046957 , 0xf195acc7 , 0x0e2d8f56 ] )
+  , Mont::unsafe_make( [ 0x1841ea8a , 0xb687edd5 , 0xdff73e74 , 0xca83a73c , 0x4e73e038 , 0x4488472d , 0x9cd9bc72 , 0x12ee4c10 ] )
+  , Mont::unsafe_make( [ 0x83a5d0cb , 0xc8b1d8ba , 0x2f7d4b59 , 0x0d37adf7 , 0xb6138419 , 0xbbf950a5 , 0xb96c69a6 , 0x101aa0db ] )
+  , Mont::unsafe_make( [ 0xe8b20ed4 , 0x7f287b33 , 0x0a6a0dc5 , 0x4e95d852 , 0x2dc1f436 , 0xe62d70ee , 0x5bfb2861 , 0x2cd5582f ] )
+  , Mont::unsafe_make( [ 0x8f6aeebd , 0x225f6afc , 0x6efb37b7 , 0x0ad29818 , 0x8db1bdcb , 0x7bdb202b , 0xa0c84fa6 , 0x2a102257 ] )
+  , Mont::unsafe_make( [ 0xb173c49d , 0x7e0a1c76 , 0x34647ec0 , 0xe8c494a9 , 0xbfb4d625 , 0x57e1dbed , 0x52bc2dec , 0x304c6f72 ] )
+  , Mont::unsafe_make( [ 0x83cda1af , 0xad694426 , 0x76994cf2 , 0x94a8b5aa , 0xa918dbcc , 0x3777f1a9 , 0xdf435289 , 0x166905eb ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x49d4d9c8 , 0x20f2a942 , 0x7efa9eb2 , 0xeb4a1dd3 , 0xb10375e9 , 0x55919ee1 , 0x96c277b8 , 0x0eaa4f33 ] )
+  , Mont::unsafe_make( [ 0xa3573c37 , 0x185ca9d7 , 0x72b0014b , 0x914afddb , 0x0882a614 , 0x4707e2d1 , 0x406dfd48 , 0x11e953b9 ] )
+  , Mont::unsafe_make( [ 0x36f62a5a , 0x6a6ebf86 , 0x73f0d271 , 0x54788125 , 0x370c6b0a , 0x161b413d , 0x8da79c12 , 0x05431d68 ] )
+  , Mont::unsafe_make( [ 0x560bf579 , 0x9db7ad26 , 0x9f732573 , 0x8ea5fc40 , 0xf0744498 , 0xe82e859d , 0xdc22ddff , 0x2d4b0cc2 ] )
+  , Mont::unsafe_make( [ 0xd0c40faf , 0x74f1411d , 0x4527079c , 0xa1b6b673 , 0xf56202f0 , 0x249dc028 , 0xad8f2961 , 0x00578a31 ] )
+  , Mont::unsafe_make( [ 0x630e3737 , 0x06405829 , 0xc52b3090 , 0x551b8561 , 0x9623c521 , 0x94c65f12 , 0x978b684d , 0x25a366d9 ] )
+  , Mont::unsafe_make( [ 0xe68bc500 , 0x5b5e5c8f , 0xd8595653 , 0x0205d496 , 0xbd92b235 , 0x28922f0a , 0x7348ea40 , 0x2fd753fc ] )
+  , Mont::unsafe_make( [ 0x44757c15 , 0xed1baee0 , 0x2301b758 , 0x5375fe94 , 0x58b78b90 , 0xf2b8c238 , 0x1ed0fdd1 , 0x076d935c ] )
+  , Mont::unsafe_make( [ 0x86460bda , 0x70742584 , 0x4ebf60f4 , 0xbb33e9cb , 0xe1bb9a33 , 0x1b7e649c , 0xc37c0011 , 0x10da7aeb ] )
+  , Mont::unsafe_make( [ 0xdf58d4e3 , 0x728c84c3 , 0x3c6af5ef , 0x090c1b1e , 0x28207304 , 0x766292ef , 0x06145abb , 0x22766afc ] )
+  , Mont::unsafe_make( [ 0xb5306bec , 0x117b520a , 0xe23625a0 , 0x619fa727 , 0x4976f602 , 0x93931455 , 0x6d3ec67e , 0x2f9071f3 ] )
+  , Mont::unsafe_make( [ 0x4c32d9e6 , 0x10899e25 , 0xb437ed9a , 0x0bab3795 , 0xd36cb836 , 0x8b455030 , 0x170505e3 , 0x29dfdc80 ] )
+  , Mont::unsafe_make( [ 0xbf42caec , 0x1316d342 , 0xa91a2869 , 0xcc5b74f9 , 0xc215e6b8 , 0xf79dc820 , 0xbc5ef877 , 0x2d3d0574 ] )
+  , Mont::unsafe_make( [ 0xb0e213af , 0x032beba5 , 0xadd50141 , 0x471d38f6 , 0x19bdc499 , 0x9116025c , 0xead2a630 , 0x174cbd4a ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x9c634298 , 0xdaffdd57 , 0x6502b617 , 0xf4aa9b21 , 0x58ff95c4 , 0x4cddea65 , 0x51823afa , 0x04dfa1fb ] )
+  , Mont::unsafe_make( [ 0xdeb85fdb , 0xf63d97f7 , 0x1d0518be , 0x9399fb41 , 0x9fa565fa , 0xa7e3934d , 0xde302fff , 0x04adc029 ] )
+  , Mont::unsafe_make( [ 0xca7677ca , 0x9ee040a7 , 0x40f7f8b9 , 0xa0fefb5e , 0xb1040661 , 0x84ad9cd0 , 0x18b33c97 , 0x16465207 ] )
+  , Mont::unsafe_make( [ 0xa984faa6 , 0x83419c47 , 0xebeef617 , 0xc38f4be3 , 0x05f2fec4 , 0x559a1ea0 , 0x1a5b4171 , 0x01ffb2c1 ] )
+  , Mont::unsafe_make( [ 0x682999b9 , 0xb19b6d34 , 0x4e4d9a9d , 0x2cd317fb , 0xa1a43240 , 0x51d42b5c , 0x1300254d , 0x017f111c ] )
+  , Mont::unsafe_make( [ 0xcabbb4fb , 0x3bb35a25 , 0x9315cb74 , 0xb8df4275 , 0x3d096560 , 0xefdbbe5e , 0x56321596 , 0x0e013f3c ] )
+  , Mont::unsafe_make( [ 0xdc3db0be , 0x9f9c996d , 0xd208dc29 , 0x71af1e73 , 0x905ad07b , 0xb9db5d45 , 0x33e630eb , 0x0907d0cb ] )
+  , Mont::unsafe_make( [ 0x04a2e738 , 0x773162a2 , 0x6706b94c , 0x9576c0b9 , 0x63665486 , 0xd1a973d2 , 0xdafda3ca , 0x2716733e ] )
+  , Mont::unsafe_make( [ 0x3bc2b602 , 0x74df53e0 , 0x641817a0 , 0xa70d0469 , 0xafb8fb95 , 0xa8959e79 , 0xb92c65aa , 0x2b1da03b ] )
+  , Mont::unsafe_make( [ 0x245a617c , 0x72396045 , 0xdbc58e50 , 0xb0ddf93b , 0x0b0a1dce , 0x7a8bd88c , 0xe3069a56 , 0x0ea0abcd ] )
+  , Mont::unsafe_make( [ 0xa18ff877 , 0xb233d7f3 , 0x414fc699 , 0x3d0f5363 , 0xd0d119c0 , 0x3ccafcca , 0x21ff5b7b , 0x1a594796 ] )
+  , Mont::unsafe_make( [ 0x603a0706 , 0xcb71cb24 , 0x9b6850f7 , 0xc010e62f , 0xa9df3bb3 , 0x589d28ef , 0x9d60b78d , 0x29cf86c3 ] )
+  , Mont::unsafe_make( [ 0xd84662c9 , 0xbbbc9923 , 0x3028836c , 0xc6db0898 , 0x423dff5d , 0xad556d8d , 0x7cde256d , 0x27a896f5 ] )
+  , Mont::unsafe_make( [ 0xcdd8e40a , 0x0ca8e617 , 0x7916d40c , 0xf9909539 , 0x1e465bf6 , 0xd4f8a8ee , 0x39cd5062 , 0x17a1d641 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xef04d586 , 0x573014a0 , 0x87a97c52 , 0xa7420d83 , 0x9da21fad , 0x481dba91 , 0x38b9e570 , 0x219b68ba ] )
+  , Mont::unsafe_make( [ 0x6b0c5169 , 0xe6d63ee4 , 0x6c355ca9 , 0xb88b60de , 0x8ba9302f , 0xa4cef12a , 0x55a371c2 , 0x01c0fc42 ] )
+  , Mont::unsafe_make( [ 0x06de3183 , 0xf9b47117 , 0x5cb413ff , 0x5e79181a , 0xd0646f2b , 0x924a7dd9 , 0x9500b981 , 0x046804e2 ] )
+  , Mont::unsafe_make( [ 0xa5fafd8e , 0x895697c8 , 0xcf7ad5f2 , 0xf42740c7 , 0x917ae35f , 0x23ba3976 , 0xf7e82605 , 0x11f7780a ] )
+  , Mont::unsafe_make( [ 0x1ea1f8e8 , 0xb7386c59 , 0xa6964bd9 , 0x043dc9e2 , 0xc042f4b0 , 0xc51bc6dd , 0x30036306 , 0x08cd343c ] )
+  , Mont::unsafe_make( [ 0x5f85ed4a , 0x453335cc , 0xc5bd7530 , 0xa7775fd4 , 0x06763fd5 , 0xd55b7b42 , 0xbc34d7e0 , 0x28a654cc ] )
+  , Mont::unsafe_make( [ 0x91fe0d7b , 0x8b545d6c , 0x9d26ce29 , 0xdb0f8da5 , 0x663bc99a , 0xc71eec92 , 0x18426552 , 0x04ab280f ] )
+  , Mont::unsafe_make( [ 0x034ea656 , 0xa30ebf5d , 0x3e0b81be , 0xb0bdf755 , 0xa4d36cd7 , 0x85f827b1 , 0xa1bcdf4d , 0x2a6673ae ] )
+  , Mont::unsafe_make( [ 0x6a29b305 , 0x0bc9d9cb , 0x0a66dc6a , 0xe641e548 , 0x2e36971d , 0x6af83a05 , 0x1c86d03e , 0x1f5b4290 ] )
+  , Mont::unsafe_make( [ 0x61486220 , 0xf777c453 , 0x1bf6e3a4 , 0xf57f59ad , 0x8b05d16f , 0xe0473c45 , 0x32d4aa02 , 0x20205619 ] )
+  , Mont::unsafe_make( [ 0xe57ecfca , 0x343a29b4 , 0x0dd0b60b , 0x2056bf95 , 0x5f5c048b , 0x5e773090 , 0xc68c783f , 0x212b389f ] )
+  , Mont::unsafe_make( [ 0x20cab0c7 , 0x1496b34f , 0x1aebd590 , 0xb7db2c43 , 0x8ee7f854 , 0xdf1938d5 , 0x83ccfd6d , 0x105aae2d ] )
+  , Mont::unsafe_make( [ 0x3b80550f , 0x44c3af96 , 0xf2be5c2b , 0x6c62f15a , 0x7940314d , 0xcbf27ccc , 0xc16718d6 , 0x10850d9b ] )
+  , Mont::unsafe_make( [ 0xb20786ea , 0xe8c986fc , 0x89815e8f , 0x6183e531 , 0x6d8901c3 , 0x52b645ff , 0xc79a127b , 0x145a37c6 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x70849482 , 0x11ed3964 , 0x9090e339 , 0xc5e0fecc , 0x30fd3567 , 0xc832a671 , 0xd38fd89a , 0x0e845357 ] )
+  , Mont::unsafe_make( [ 0xd1f53c98 , 0x918ad72c , 0x736a4f26 , 0x84b7b5d9 , 0x1c5681eb , 0xfe12a747 , 0xd8c5cd14 , 0x1d525b43 ] )
+  , Mont::unsafe_make( [ 0xefc90e68 , 0x6c40b39f , 0x8b6bc770 , 0x09f73b08 , 0x1b92a35f , 0x96eebd0c , 0x19808ffc , 0x16e4a3b9 ] )
+  , Mont::unsafe_make( [ 0x5819a65a , 0x0b91ccae , 0x0f8757e8 , 0xf8df1107 , 0xf7300e99 , 0xd59057a8 , 0x02beef4b , 0x14f1037f ] )
+  , Mont::unsafe_make( [ 0x92350108 , 0xe63a86df , 0x5d14b4f6 , 0xde89106b , 0x2391bc2b , 0x069407a6 , 0x1c007b02 , 0x2fc562a7 ] )
+  , Mont::unsafe_make( [ 0x0ee90527 , 0xad207815 , 0x418a5b9c , 0xcc6008dd , 0x3e94280c , 0x583413b7 , 0x57020dc6 , 0x1ff00978 ] )
+  , Mont::unsafe_make( [ 0x01dc867a , 0x0c8111d9 , 0x9151c323 , 0xd9c58a61 , 0x83c0a1b3 , 0x9496d967 , 0x5f0616e8 , 0x093210a5 ] )
+  , Mont::unsafe_make( [ 0x68e9b001 , 0xc56b4c3a , 0x8cf279bd , 0x0dab2f66 , 0xf1a504f0 , 0x0093bfc7 , 0xa40c4a53 , 0x1a00d2c8 ] )
+  , Mont::unsafe_make( [ 0x556ad4ce , 0x7bdf01b6 , 0x939b9717 , 0x3baa8eb5 , 0x0f56cbc4 , 0xf6f4176c , 0xee4af80e , 0x02224999 ] )
+  , Mont::unsafe_make( [ 0x071606c0 , 0x5e812189 , 0xadf74c82 , 0x0233b7eb , 0x7c57268f , 0xc586d42a , 0xe3876bd1 , 0x22ace246 ] )
+  , Mont::unsafe_make( [ 0x99a468b3 , 0x53995e3d , 0xd5dc24dd , 0x2f94f7b2 , 0x5c4db685 , 0x00ecde56 , 0x74329b26 , 0x172b9b83 ] )
+  , Mont::unsafe_make( [ 0xc623cb2e , 0x3bfde4ab , 0xb5e7a64a , 0x062d2ece , 0xf2aed9f4 , 0x49058802 , 0x50124ff9 , 0x08f02549 ] )
+  , Mont::unsafe_make( [ 0x69066d16 , 0x0aae35ab , 0x9f49bc66 , 0xfa1c9202 , 0x6210ab69 , 0x5207cc2f , 0x2008dd35 , 0x216399cb ] )
+  , Mont::unsafe_make( [ 0xa0703aff , 0xdd088adf , 0xe848f6ce , 0xf9d253f2 , 0xb285db82 , 0xbe08064a , 0xa394e899 , 0x04631e85 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x70d140fa , 0x32a1cda2 , 0x7281184f , 0x1490efee , 0xe290fef5 , 0x583d8343 , 0xd25e09c6 , 0x20ac0a23 ] )
+  , Mont::unsafe_make( [ 0x9ac977cd , 0xb1ea1e99 , 0x91eeab2f , 0x5cda2965 , 0x9d14e20a , 0x40a16b0b , 0xb53804c4 , 0x18452da6 ] )
+  , Mont::unsafe_make( [ 0xbe21aa63 , 0xfa7ca7de , 0xc3188a67 , 0x8495ede4 , 0x0870dac3 , 0x303bf11e , 0x5e067a39 , 0x116e4a53 ] )
+  , Mont::unsafe_make( [ 0x4e6e0bfd , 0xc6743dea , 0x5cf9443e , 0x29906371 , 0x45c42d83 , 0xb30c8782 , 0x924ac2a5 , 0x2d71ba53 ] )
+  , Mont::unsafe_make( [ 0x480aea88 , 0x930db857 , 0x14b5f898 , 0xe8b5a11f , 0xd7fce850 , 0xd724a0d6 , 0xa44cdb0e , 0x1cc23d3f ] )
+  , Mont::unsafe_make( [ 0x62df8bbc , 0x3fc1152a , 0xf764a68b , 0xa095d191 , 0x62311c9d , 0x9a392f54 , 0x022d385c , 0x17cd2a11 ] )
+  , Mont::unsafe_make( [ 0x8df8282b , 0xfbed7c9c , 0x3a3b9192 , 0x0b34bb8b , 0xe5e2cd7a , 0x0df35e36 , 0xeeb218e2 , 0x1e298256 ] )
+  , Mont::unsafe_make( [ 0xd3ac931b , 0xff808f81 , 0xcf7ddd5a , 0xd0a3cfe8 , 0x06d19889 , 0xb0a14451 , 0x50ab2c43 , 0x2958de3a ] )
+  , Mont::unsafe_make( [ 0x1736587f , 0x88cac316 , 0x3e699447 , 0x53f3658e , 0x4a88ae44 , 0xd2bc944d , 0x8b18b587 , 0x14b0eb25 ] )
+  , Mont::unsafe_make( [ 0x1b3def76 , 0x817cdace , 0xad083430 , 0xc825b443 , 0x90391f51 , 0x8fc9ec3f , 0xf7614ad2 , 0x004e5b36 ] )
+  , Mont::unsafe_make( [ 0xff2a91fa , 0x0018b83e , 0x5f686e69 , 0x7576e0e6 , 0x61d6d899 , 0x8fd3f086 , 0x64763f26 , 0x2b44e064 ] )
+  , Mont::unsafe_make( [ 0xd12ee14b , 0x21b1fec2 , 0xd8a6ed2c , 0x50136f80 , 0xc2ec3cbf , 0xe1b45d78 , 0x71f74c6c , 0x20c29fd4 ] )
+  , Mont::unsafe_make( [ 0x9477593e , 0x6b075c3d , 0x15bf4551 , 0xc09832f5 , 0x6892f5a7 , 0xec6d0c57 , 0xf1c783b9 , 0x1cf1602f ] )
+  , Mont::unsafe_make( [ 0x029aa25d , 0x1a902806 , 0xce9e0c13 , 0x6f779d5b , 0x82db996b , 0xeb49cbcd , 0x1c0c81be , 0x2cd9f089 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x7cf154b9 , 0xe507f575 , 0x6bb1ca31 , 0xe666257d , 0x633cef66 , 0x6844e45b , 0x4937664c , 0x0858734c ] )
+  , Mont::unsafe_make( [ 0xe6783f04 , 0xae839583 , 0x1c685de0 , 0xcb57af29 , 0x0ffce86b , 0x1adbc087 , 0xb1649fb2 , 0x1100de6d ] )
+  , Mont::unsafe_make( [ 0xc5c7a6f3 , 0x9f7ce3ff , 0x0c8a0171 , 0xdd327ee5 , 0x2c32cccc , 0x8072717b , 0xd2976ae3 , 0x1054b5a7 ] )
+  , Mont::unsafe_make( [ 0x095527ca , 0xe3fd400a , 0xe01231e5 , 0x09d28112 , 0x9bf2b454 , 0xc681f8f3 , 0x3d3449b8 , 0x19197532 ] )
+  , Mont::unsafe_make( [ 0xa51b5a82 , 0xa231c39e , 0xcd335870 , 0x6b748329 , 0x9f6fcac4 , 0x50c288b4 , 0xf98aec4f , 0x0908f007 ] )
+  , Mont::unsafe_make( [ 0xa9b9a0e8 , 0x095b21df , 0xe485f375 , 0x9b87a9b6 , 0x64e64590 , 0x06edc7a6 , 0x6987e4e6 , 0x230cc673 ] )
+  , Mont::unsafe_make( [ 0xb8ee23cc , 0x3da4c8f1 , 0x99ed8b57 , 0x41ce234d , 0x7958cd57 , 0x2f2ceea0 , 0x60aff878 , 0x22a5779f ] )
+  , Mont::unsafe_make( [ 0x9859b161 , 0x7d24fdac , 0x290e1ce4 , 0xaa35de95 , 0xbcd60638 , 0xde884b9f , 0x7ae4cc10 , 0x2036b2f5 ] )
+  , Mont::unsafe_make( [ 0x33dc477a , 0x5310bd08 , 0x7caf573c , 0x2c9c99cb , 0x4a815891 , 0xbaec983a , 0x18486c27 , 0x19b7cb2c ] )
+  , Mont::unsafe_make( [ 0x6cc71b91 , 0xfc2f3006 , 0xbd11bc19 , 0x07a6eebb , 0xe5c405dc , 0x5ce812e1 , 0x4889dd50 , 0x30195e6a ] )
+  , Mont::unsafe_make( [ 0xebaad892 , 0x7a3d4fc5 , 0x65aed87f , 0xc7e27c13 , 0xbb1c21f7 , 0xcb33649d , 0x43e753ce , 0x23ac77d5 ] )
+  , Mont::unsafe_make( [ 0x95003c33 , 0xbc844132 , 0x153ec97e , 0x5d900640 , 0x4966147f , 0xad5da53b , 0xffe40603 , 0x076184a3 ] )
+  , Mont::unsafe_make( [ 0xcc5a1fd2 , 0x945642e2 , 0x77225f42 , 0x2766cdc6 , 0x2b4a7c34 , 0xe76e985d , 0xdb3690ed , 0x2aa94988 ] )
+  , Mont::unsafe_make( [ 0x43def03d , 0x2739d930 , 0xb442e497 , 0xc294b408 , 0x98256cb3 , 0xddd1998c , 0xf46af5d0 , 0x0b9c8cc0 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x111edcef , 0x262f2b72 , 0x266ea8bd , 0xe73ff83b , 0xd1f53f1b , 0x86e6ea72 , 0x8c88774f , 0x2a9ebfbc ] )
+  , Mont::unsafe_make( [ 0x69e64ec4 , 0x70690b2c , 0x68663bff , 0x6652bf4c , 0xcdad0506 , 0x38d9412f , 0xdf21d3c6 , 0x0919012e ] )
+  , Mont::unsafe_make( [ 0x2f87b20b , 0x86a06a19 , 0xdb062361 , 0x99b42e74 , 0x17a03ba4 , 0x279ddd3b , 0xc09c07a5 , 0x03ba4f04 ] )
+  , Mont::unsafe_make( [ 0xb766c04b , 0xdba758ff , 0x820dcc96 , 0x6928743a , 0xd864be07 , 0x6b4b35c2 , 0x25f963b5 , 0x1b716d0e ] )
+  , Mont::unsafe_make( [ 0x2858d88e , 0x1f8bdf12 , 0xc69b406c , 0xa2c16afa , 0xc260e319 , 0x482007b7 , 0x1cc14ac9 , 0x1ea66a4c ] )
+  , Mont::unsafe_make( [ 0xc5496597 , 0x8358d9d3 , 0xbf6a73ca , 0x62186a8c , 0x3b2c1714 , 0x7648681a , 0xc5107dd7 , 0x14ca2d7b ] )
+  , Mont::unsafe_make( [ 0x1e2a4eee , 0x7c06dd52 , 0x4b64a5e1 , 0x0cb431e4 , 0xd343dd81 , 0x0ad76771 , 0x7cbbba67 , 0x05867c3a ] )
+  , Mont::unsafe_make( [ 0xf5be83a8 , 0x8b49ca79 , 0x28a365b0 , 0x0dc089df , 0xf4f072e0 , 0x2cb53777 , 0xb25de950 , 0x19815600 ] )
+  , Mont::unsafe_make( [ 0x5d62bd8b , 0x373c1391 , 0x64b26a81 , 0x2e36877e , 0x06a86316 , 0x63cbb19c , 0x67ce5476 , 0x0ca66901 ] )
+  , Mont::unsafe_make( [ 0xb77634cc , 0x9faa8a03 , 0x19e7dec2 , 0xb57291bb , 0x242404d7 , 0x34477d11 , 0x6dc50c8c , 0x0fd62bbc ] )
+  , Mont::unsafe_make( [ 0x0b2ca14e , 0x861b8480 , 0x8c2b8341 , 0x668ccba0 , 0xdcf239f8 , 0x3f00eab9 , 0x67422b99 , 0x1578c83f ] )
+  , Mont::unsafe_make( [ 0xa216766e , 0x67e5a944 , 0x2e0a9fce , 0xbbe45234 , 0x6b840ef7 , 0x6c047dac , 0xed746f63 , 0x05ac5e39 ] )
+  , Mont::unsafe_make( [ 0x00c952a3 , 0xf0630186 , 0xa79817dc , 0xeb029c5e , 0x324093eb , 0x205dbc78 , 0x7752d5f9 , 0x244ec726 ] )
+  , Mont::unsafe_make( [ 0xac179c73 , 0x212201db , 0x30ee3c23 , 0x770900bb , 0x9240713b , 0x2dc5893a , 0xbf263843 , 0x302770e7 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xa5f6ee4d , 0x50d74a6f , 0x2cef933f , 0xa3ea368f , 0x34e63f15 , 0x9781cc32 , 0x1b65bbc0 , 0x239ceb16 ] )
+  , Mont::unsafe_make( [ 0xc57eab6c , 0x4df1036c , 0x498fd9a1 , 0x204bfeed , 0xeb12edf9 , 0x895dd6ea , 0x6e31f99c , 0x2f9f0cee ] )
+  , Mont::unsafe_make( [ 0xb465ae92 , 0xbae40227 , 0xae9239dc , 0x76544491 , 0x9b5bbfdb , 0x9aad8a72 , 0xeaa4d645 , 0x101fd8fa ] )
+  , Mont::unsafe_make( [ 0x10514a49 , 0x8bc869db , 0x9a7d744b , 0xcb3070f1 , 0x143f4203 , 0x3734274a , 0x6f386cce , 0x008b9755 ] )
+  , Mont::unsafe_make( [ 0x03f7f3ee , 0xd150308e , 0xb5aaffc7 , 0xaa84f339 , 0x1be9f3b5 , 0x6cfa1c6b , 0x71c60326 , 0x0796b902 ] )
+  , Mont::unsafe_make( [ 0x1c29e6ad , 0x9bb2f3b2 , 0x34c33af2 , 0x2f11855c , 0x44709c3e , 0xe98ad97d , 0x26b7b079 , 0x056a1f27 ] )
+  , Mont::unsafe_make( [ 0x7f8fb331 , 0xc19e5c8d , 0x982b619b , 0x7a1a2f07 , 0xd3ed6ba8 , 0xdfdd8765 , 0x389a5663 , 0x2ea6a4f1 ] )
+  , Mont::unsafe_make( [ 0xdcf30ea3 , 0x454cccb8 , 0xe2b516b6 , 0x76908746 , 0x55f2cacf , 0xa8108565 , 0x41bd7a41 , 0x2448171f ] )
+  , Mont::unsafe_make( [ 0xebe3ec86 , 0x88e46be3 , 0xceff7e1e , 0x9d9a2385 , 0xbd05141d , 0x07e4d5d3 , 0x279b48b8 , 0x2f22ee19 ] )
+  , Mont::unsafe_make( [ 0x90e463cc , 0x7fb59188 , 0x66f8741c , 0x5228c1ed , 0x91d4c90f , 0x8cfad08b , 0xb7723c52 , 0x1f82a3d1 ] )
+  , Mont::unsafe_make( [ 0x3314fc02 , 0x246b2096 , 0x6205cac6 , 0xf48069d4 , 0x4aa94f78 , 0x9befb405 , 0xf01b30a2 , 0x0c890f77 ] )
+  , Mont::unsafe_make( [ 0x2a044317 , 0x468e8acc , 0x09249a2f , 0xf4fd4e24 , 0x80bd240d , 0x08ea2c70 , 0x6e9e5cfc , 0x17f0cbeb ] )
+  , Mont::unsafe_make( [ 0x5c8da69b , 0x8ab5d632 , 0x2e50179f , 0x6b6d388e , 0xda0a4003 , 0xcfe044f8 , 0xc8eae1d6 , 0x0b19af51 ] )
+  , Mont::unsafe_make( [ 0x1e6a96e5 , 0x93c7c070 , 0x3ff829dd , 0x67b14712 , 0x621a1785 , 0x579baa2f , 0x878567e5 , 0x07af1faf ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x74ffacab , 0xaf678755 , 0x0266d1ff , 0xd738066b , 0xa7a2b2dc , 0x306a5b7b , 0xd6db1aad , 0x2f12c834 ] )
+  , Mont::unsafe_make( [ 0xc2d9dd93 , 0x05ea9d91 , 0x2a6febce , 0xc5c7b531 , 0x3363e3e8 , 0x0e7fe30e , 0xec2a1530 , 0x189d7566 ] )
+  , Mont::unsafe_make( [ 0x5a7e3af7 , 0x2033e431 , 0x34a57cf8 , 0xdabbdd02 , 0xfe3021d6 , 0xdaeea2bb , 0xe4262988 , 0x0ba2f329 ] )
+  , Mont::unsafe_make( [ 0x17a9c570 , 0xa1df8a84 , 0x19324933 , 0xba165f9f , 0x65611b83 , 0x8adb8ce7 , 0x0b727fa4 , 0x19aa34a1 ] )
+  , Mont::unsafe_make( [ 0x13b02410 , 0xc0b4f600 , 0x6d539cef , 0x24441d8b , 0xd4ec6ab2 , 0x4f87be6f , 0xd29d4123 , 0x24386edc ] )
+  , Mont::unsafe_make( [ 0x18fab1d2 , 0x9159f74d , 0x1c3a05f2 , 0xb2f2068a , 0x4797a26b , 0x2eec59da , 0x0e13aa94 , 0x1e43c39d ] )
+  , Mont::unsafe_make( [ 0xe6ca00de , 0xe3f17cd0 , 0xe007e74d , 0x250183ad , 0xca8c6794 , 0xf45fa74b , 0x2ee4469d , 0x1b9e1a8c ] )
+  , Mont::unsafe_make( [ 0x79f8a853 , 0x81b5e70b , 0x83f95c25 , 0xeb915ec2 , 0x826aed81 , 0xa47c26ec , 0x59239faf , 0x261972ca ] )
+  , Mont::unsafe_make( [ 0xd4aa87c0 , 0x37710ee0 , 0x744e6f23 , 0xe0f45633 , 0xb8ece8e1 , 0x4a427c94 , 0x663eaad1 , 0x0904dbee ] )
+  , Mont::unsafe_make( [ 0xddd16c9a , 0xa68fd719 , 0x1646f667 , 0xacde481b , 0x2862f4fc , 0x1e1eca14 , 0xec7efec1 , 0x0d1a3083 ] )
+  , Mont::unsafe_make( [ 0x7fdb737f , 0x3a9a0a9c , 0x34d1fe4b , 0x1488fdae , 0x737b20d5 , 0x15b31f0c , 0xdf4b9ad1 , 0x2c483b29 ] )
+  , Mont::unsafe_make( [ 0xf700facb , 0x82d9d8f6 , 0x9dce65cc , 0x55343ef5 , 0x1875ba69 , 0x3f374abf , 0x7417c036 , 0x08944b21 ] )
+  , Mont::unsafe_make( [ 0xb9e79052 , 0x70a44e12 , 0x119d7c94 , 0xdee1f292 , 0x0990f469 , 0xaf38de78 , 0x417f8cf8 , 0x1ef0ebee ] )
+  , Mont::unsafe_make( [ 0xb2c21b4c , 0x14665311 , 0x91fd404d , 0xb8b05c3c , 0xcb3ec4be , 0x28cfa169 , 0x3fb91910 , 0x2e95760a ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x4db87102 , 0xbf8b913d , 0xc948b3a1 , 0xd04e3e52 , 0xa962e7fd , 0xe039b093 , 0xb731c059 , 0x0940eebd ] )
+  , Mont::unsafe_make( [ 0xcdbb98a7 , 0x17616e77 , 0x82d66bfc , 0xc45ab060 , 0xd653b846 , 0x5694afd4 , 0x3b7fd645 , 0x2426afd3 ] )
+  , Mont::unsafe_make( [ 0x349e2974 , 0x4c6939f8 , 0xdcc745f0 , 0xf27e60af , 0x8a7ce722 , 0xf1705809 , 0x50e9d206 , 0x0b03cd66 ] )
+  , Mont::unsafe_make( [ 0x65ff0437 , 0x5425dba1 , 0xcbefdc2a , 0xcdae8dc5 , 0x28273550 , 0x90efcaba , 0xb9385b37 , 0x14ac7259 ] )
+  , Mont::unsafe_make( [ 0x3150c3e7 , 0x0c3c97ee , 0x6099b5de , 0x33c5c184 , 0x2d67184c , 0x2aa829a4 , 0x7e6f7268 , 0x0266cc04 ] )
+  , Mont::unsafe_make( [ 0x7e38d60d , 0x693fb880 , 0x0e64b1e6 , 0x93d684ae , 0xef85b2ae , 0xaa580ae8 , 0xdce4b6d1 , 0x036c6474 ] )
+  , Mont::unsafe_make( [ 0x1a6c2394 , 0xd4a61d1f , 0xa7e6b505 , 0xd5e40fff , 0xcd67fb4a , 0xb6137aa6 , 0x91201c20 , 0x04150d34 ] )
+  , Mont::unsafe_make( [ 0xb854bd93 , 0x8ed80f8f , 0x492459d4 , 0x75fda0b7 , 0xd5be3688 , 0x521eacd5 , 0xeedb66eb , 0x113bd48f ] )
+  , Mont::unsafe_make( [ 0x50a208df , 0x13b06d0e , 0xd71c5c4b , 0x5b06c769 , 0xe0af1569 , 0x1f80c681 , 0x1f1371b4 , 0x2d3b9a11 ] )
+  , Mont::unsafe_make( [ 0xa439a5ef , 0xd536dc86 , 0x63a79d9c , 0x3a107ffb , 0x3eb1d096 , 0xebc02254 , 0x2f95c722 , 0x1d7511d9 ] )
+  , Mont::unsafe_make( [ 0x90673173 , 0x4c7153fe , 0x47f4701b , 0x0b92f818 , 0xd3da5cc5 , 0xd83419c5 , 0xe5915b22 , 0x173bccd7 ] )
+  , Mont::unsafe_make( [ 0xd6e1035b , 0x2b30a93d , 0xe543dc94 , 0x2e10a759 , 0x353296e9 , 0x0cffee92 , 0xf8354ab5 , 0x0ffafe35 ] )
+  , Mont::unsafe_make( [ 0xa457ec9c , 0x6480540e , 0x5b2d92b6 , 0x0fb168aa , 0x33de9f74 , 0x7306a537 , 0x50fb6919 , 0x19679694 ] )
+  , Mont::unsafe_make( [ 0x03e51253 , 0x2f3e49da , 0xb5a3bf7c , 0x64264057 , 0xcd70bd2c , 0xaf07886a , 0xb64fb899 , 0x13b2544b ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xf88499e5 , 0x54ab23f3 , 0x3bb2c075 , 0xf2df04a8 , 0xf2e4a454 , 0x7e717f17 , 0x16454382 , 0x230f72bf ] )
+  , Mont::unsafe_make( [ 0x9611c083 , 0x32ffef17 , 0x9e7f54fe , 0x847d3c49 , 0xac915b8b , 0x06ec5274 , 0xf8d45551 , 0x268abf39 ] )
+  , Mont::unsafe_make( [ 0x125c5649 , 0xc1aa1be9 , 0x982c8fe4 , 0x72da31c8 , 0x6110a2a1 , 0x9dbb094a , 0xf48be1f3 , 0x2239dea4 ] )
+  , Mont::unsafe_make( [ 0xc4b51c2d , 0xb0e2ff05 , 0x2322ed4a , 0xcbd39f55 , 0x77662caa , 0x6a83e03e , 0x5b032491 , 0x0b434176 ] )
+  , Mont::unsafe_make( [ 0x70c802a5 , 0xe3891942 , 0x158a8818 , 0x9aff9999 , 0x102abd68 , 0x459a8c82 , 0x3622e8ce , 0x2e5903da ] )
+  , Mont::unsafe_make( [ 0xd49769bb , 0x3e24b540 , 0xa2df7080 , 0x51cfff60 , 0x858f11ea , 0xf437fb2c , 0xb8b1809a , 0x0d56f57a ] )
+  , Mont::unsafe_make( [ 0x62d54881 , 0x1a66226a , 0x2592b0f3 , 0x31dee304 , 0xfc0eb288 , 0xe5f02e24 , 0x45426d21 , 0x21406c4b ] )
+  , Mont::unsafe_make( [ 0x58645dc5 , 0x028e0fe4 , 0xcf699831 , 0x0a180ce7 , 0xd5e0727c , 0x2fd2d877 , 0x34241db6 , 0x06d4b6e8 ] )
+  , Mont::unsafe_make( [ 0x915d51cb , 0x5d4eecf8 , 0x73262d26 , 0xed1ce7f4 , 0xdefe8299 , 0x57ac94b1 , 0x4dcdbece , 0x26b5c193 ] )
+  , Mont::unsafe_make( [ 0x288ed372 , 0xd8cb421c , 0xcef9188d , 0x800f8b69 , 0x40c534e9 , 0xa6aaf3ed , 0xd92edfc3 , 0x23c61bde ] )
+  , Mont::unsafe_make( [ 0x8b8b6aeb , 0x106beb62 , 0x260d0522 , 0xed411804 , 0xa11e6a0d , 0xeeb7d149 , 0xa386f5b2 , 0x2e234414 ] )
+  , Mont::unsafe_make( [ 0xe4225db3 , 0x1dc8a93d , 0x079715ed , 0x94e20235 , 0x51e7d001 , 0x812f30f1 , 0xac1f6926 , 0x01d7946f ] )
+  , Mont::unsafe_make( [ 0x6faa74fa , 0x3c01c7e8 , 0x61e69da8 , 0x64658be2 , 0x613a4ca8 , 0xb252917b , 0x59dacd2c , 0x20d9edfe ] )
+  , Mont::unsafe_make( [ 0x4b172b04 , 0x9550b6ef , 0x42b895d0 , 0x559f916f , 0x4cd0da11 , 0x6bed42db , 0x7f707abb , 0x2d90e217 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x4690de91 , 0x99f009b3 , 0x7fe5aa8b , 0x739eb58d , 0x7bcb6433 , 0xbd465245 , 0xcdc19411 , 0x1f27a7a7 ] )
+  , Mont::unsafe_make( [ 0x2b2aa5ba , 0x80dabd60 , 0xa1f4659d , 0xec4cae50 , 0x36c2f71a , 0x21721a5f , 0x63415f7e , 0x1f1005b1 ] )
+  , Mont::unsafe_make( [ 0x690c4d0c , 0xaacda1f6 , 0xdf60be73 , 0x197f3a93 , 0xb87681c5 , 0xa103f8b5 , 0xdb52d166 , 0x24175872 ] )
+  , Mont::unsafe_make( [ 0x0a895fd4 , 0x2672329e , 0x2d9a3b68 , 0xe9f00a77 , 0xeaad1cad , 0xa7127ef2 , 0x76713bf4 , 0x0aca09bc ] )
+  , Mont::unsafe_make( [ 0x31a3db35 , 0x8623b574 , 0xee141460 , 0x63e0509e , 0xf2052f68 , 0xd4955c41 , 0xa483d25e , 0x0830fd63 ] )
+  , Mont::unsafe_make( [ 0xa4dad892 , 0x889eed81 , 0x7b10cca0 , 0x85e7ed4d , 0x1ca66414 , 0x0cfa37f6 , 0xe1b5f2e9 , 0x2ede6fd0 ] )
+  , Mont::unsafe_make( [ 0xe8ff81e1 , 0x19c06c58 , 0xa6cb40c8 , 0x6b468dc3 , 0xc2bd0d8c , 0xcc7da3da , 0x691de5e5 , 0x225eb26f ] )
+  , Mont::unsafe_make( [ 0x6eb0d2f2 , 0xac343286 , 0x99a1432d , 0x9b01e7ea , 0xdf94a307 , 0x1ed946f1 , 0x0623c5c6 , 0x1310078b ] )
+  , Mont::unsafe_make( [ 0xaf3058fd , 0xdcadf300 , 0x88741037 , 0xebadd840 , 0xd848f40d , 0x2170e3c0 , 0x46a346c2 , 0x1f3551f0 ] )
+  , Mont::unsafe_make( [ 0x3d8899d2 , 0xe69e14ef , 0x638c5ddc , 0xeee24a1f , 0x7551b4f8 , 0x09c21819 , 0x5e2e5b5d , 0x0495e2cb ] )
+  , Mont::unsafe_make( [ 0xa78bd05d , 0x05cf88e1 , 0x2910f988 , 0x0f45e8fa , 0xe577ed5a , 0xb5071a27 , 0x9dcd04b0 , 0x0f9d97f7 ] )
+  , Mont::unsafe_make( [ 0xc19addcf , 0x9d412996 , 0xa450b2c2 , 0xd15fb5a7 , 0xf295d708 , 0xd60e6cba , 0x9ece991f , 0x0af45ea0 ] )
+  , Mont::unsafe_make( [ 0x8ad35359 , 0x2257ef1e , 0xa6a4096d , 0x9521d654 , 0x48c9688b , 0x72c6b62e , 0x12cfe0f6 , 0x2e0e0087 ] )
+  , Mont::unsafe_make( [ 0x63640a87 , 0x42ea02cc , 0x22ac6eba , 0xc130a752 , 0x92dec4ad , 0x35564d8c , 0xaf52ef6e , 0x2bb6e8ee ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x782c0014 , 0xb099c8c6 , 0xba952084 , 0x269c353d , 0x7c6dfdaf , 0x2b8ae4b8 , 0x9d7c8b5e , 0x0a2f1324 ] )
+  , Mont::unsafe_make( [ 0x3b114432 , 0x08cd4a0f , 0x93150c62 , 0x1d79f64a , 0x9d3b9c69 , 0x85740846 , 0xef85978c , 0x10f827ad ] )
+  , Mont::unsafe_make( [ 0xd474697c , 0x7ec48410 , 0xe83e22ad , 0xfd92a2ee , 0xf4cf182d , 0xdd24e693 , 0x0228ea18 , 0x169cfff4 ] )
+  , Mont::unsafe_make( [ 0x5f4d2317 , 0xac3929a1 , 0x11b10ebb , 0xa99abb45 , 0x51ae83fc , 0xa09d4234 , 0x779d2b45 , 0x0a82b03b ] )
+  , Mont::unsafe_make( [ 0x30263d08 , 0x60f84b13 , 0xb37b563d , 0x0a913a81 , 0xcc0a553d , 0xd5eea45e , 0x8c5c4b87 , 0x06368d55 ] )
+  , Mont::unsafe_make( [ 0x168b4f8d , 0xa79cf9d3 , 0x86dde8b1 , 0x8c690ee6 , 0x3fd593a1 , 0x927afd22 , 0xefab1472 , 0x101c91a9 ] )
+  , Mont::unsafe_make( [ 0x2a85c3c8 , 0xcd006ee6 , 0x4b6bc7d9 , 0x410cab13 , 0x876441e1 , 0x57c18b07 , 0x4083ac5e , 0x2fc82e9c ] )
+  , Mont::unsafe_make( [ 0x13ec31db , 0xa1204835 , 0xf203d669 , 0x82435a82 , 0x8af391b3 , 0x13ff5302 , 0x0d6c9d6d , 0x00519568 ] )
+  , Mont::unsafe_make( [ 0x6b3eddf4 , 0x1df73aa9 , 0x5520e0c3 , 0x11d8443c , 0xb6e9e341 , 0x5fee261f , 0xfe888d1b , 0x11dd695a ] )
+  , Mont::unsafe_make( [ 0xeb052791 , 0x58f415f7 , 0xc81cf5b8 , 0xbce0d1dc , 0xc07a4ed4 , 0x483d188d , 0xb9a06688 , 0x29c8cd7c ] )
+  , Mont::unsafe_make( [ 0x1eaa35ca , 0x5ddae93c , 0x60f875d6 , 0xc3035606 , 0xf16a8387 , 0xae6b6787 , 0xf30d34c1 , 0x0fb3c759 ] )
+  , Mont::unsafe_make( [ 0xa1692e9c , 0x8f06a6fb , 0x39f6999f , 0x8e3d5d03 , 0x9e57356b , 0x6d677cd5 , 0x34c73cdd , 0x3032612c ] )
+  , Mont::unsafe_make( [ 0xe6a527f3 , 0xc943e731 , 0xf91d1f92 , 0xdb68f7a8 , 0x162d72a5 , 0xe7c86d22 , 0xb3054953 , 0x24d0cc1a ] )
+  , Mont::unsafe_make( [ 0xcac64b3b , 0x577a5b17 , 0x0c6fd473 , 0xd0b0c9e3 , 0x6c54b794 , 0xd7053267 , 0x10cc4d9e , 0x0268e1de ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x52fd64c3 , 0xb1430c7b , 0xe21421d5 , 0xe21b9a95 , 0x18d42f0f , 0x60f6e01c , 0x8c4a5c01 , 0x2faa6524 ] )
+  , Mont::unsafe_make( [ 0x36f75de9 , 0x49567114 , 0x2a51918f , 0xd2a9188e , 0x743932c1 , 0x92383055 , 0xdca23f5f , 0x2601906d ] )
+  , Mont::unsafe_make( [ 0xa048cc5d , 0x443dda85 , 0xd2f6d3be , 0xdf9e7988 , 0x49c7b78e , 0x3c78c278 , 0x7551150f , 0x2566a843 ] )
+  , Mont::unsafe_make( [ 0xcf84bba3 , 0xde1c02be , 0x21578e82 , 0xbe27af00 , 0x2e9f16cf , 0x1b37655d , 0x436a8c4b , 0x1a1e7512 ] )
+  , Mont::unsafe_make( [ 0xb51c3b0f , 0x14e25728 , 0x333f93cd , 0x8d4b92f4 , 0xc5f89080 , 0xcf2efc39 , 0x2e3da98d , 0x18d493d2 ] )
+  , Mont::unsafe_make( [ 0xa50e5b21 , 0xbb6b6e27 , 0xb43c45ce , 0x66817265 , 0xef566c93 , 0xef34d0ba , 0x72b8b69a , 0x1590cd2c ] )
+  , Mont::unsafe_make( [ 0xeb635e52 , 0x80a97b6a , 0x994b1fd5 , 0xb9a24606 , 0xdef05bba , 0x7bfd47af , 0xe8ad53f7 , 0x26c26219 ] )
+  , Mont::unsafe_make( [ 0x580d4d9a , 0xd5065cd5 , 0x4c688637 , 0x4625f0f5 , 0xe613d91a , 0xb77665bf , 0xd575d717 , 0x21a51977 ] )
+  , Mont::unsafe_make( [ 0xb79a6de0 , 0x1d944a68 , 0x9e4bb2b9 , 0x79ec1b0a , 0xd4ee2889 , 0x5842c1bb , 0x24eff5ff , 0x2ce4737e ] )
+  , Mont::unsafe_make( [ 0xdeb76e24 , 0xe121858f , 0x4c7403ec , 0xf90df09e , 0x3f10816b , 0xed417b82 , 0xf81130c6 , 0x1f23ea5e ] )
+  , Mont::unsafe_make( [ 0xd579cd2b , 0x852f96b7 , 0x958a82aa , 0x64e1c7c0 , 0x18d90735 , 0x315fac6f , 0x75f32e36 , 0x084787ad ] )
+  , Mont::unsafe_make( [ 0x72872f95 , 0xd9071404 , 0x7492fa0b , 0x9905bc1c , 0xf9d43b4b , 0x2ec76e95 , 0x76ef8d32 , 0x2ffcbecf ] )
+  , Mont::unsafe_make( [ 0xddd7cc41 , 0x19f12218 , 0xcc672aeb , 0x7e02769f , 0x764b41ed , 0x466d36fb , 0x630aca04 , 0x06e1e70d ] )
+  , Mont::unsafe_make( [ 0x6a24fe11 , 0x9dee7db3 , 0x5e964c99 , 0xa7bd2637 , 0xba8b4c30 , 0xe85a2aaf , 0xbec44cb9 , 0x24726c29 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xcc374665 , 0x90c4c68a , 0xe1e1fa60 , 0x3b0be49b , 0xd0c0f470 , 0x68d8de72 , 0xd88e127a , 0x0fd2f113 ] )
+  , Mont::unsafe_make( [ 0x0797b7a9 , 0xec914a17 , 0xfcfb17ce , 0xd2f8dc5a , 0x6eb49883 , 0x0e0e06c1 , 0x673b055f , 0x19390518 ] )
+  , Mont::unsafe_make( [ 0xf625b5e3 , 0x6b6196d1 , 0x0c6d93c9 , 0xe5694336 , 0xb9c2610b , 0x05fc9042 , 0xac34b7e7 , 0x2f8253e2 ] )
+  , Mont::unsafe_make( [ 0x31598851 , 0x9623b188 , 0x9bfa1a9c , 0xb6a3a359 , 0x2e78a050 , 0x167c281b , 0xad41c667 , 0x164c05ed ] )
+  , Mont::unsafe_make( [ 0xba7d7d88 , 0x9be68fac , 0x94eb4537 , 0x5ee2d7da , 0x3bb36627 , 0x46f35ab4 , 0xeb84c27b , 0x1c48f790 ] )
+  , Mont::unsafe_make( [ 0x04f0414e , 0xca5017db , 0x72ddb71d , 0xf96120e2 , 0x99d650a4 , 0xd81d653c , 0x6821482c , 0x26fa34e9 ] )
+  , Mont::unsafe_make( [ 0x4a718b71 , 0xa8d2e46b , 0x83ef094a , 0xae215f30 , 0xfcaf600a , 0xb69e6153 , 0x205f35db , 0x2f503cce ] )
+  , Mont::unsafe_make( [ 0x77ba8317 , 0x69e551ef , 0x4377ca70 , 0x2271830e , 0x162a01e8 , 0x73f1482a , 0x9435f708 , 0x13ff4cf4 ] )
+  , Mont::unsafe_make( [ 0xfc6a4e22 , 0xc6338537 , 0x250d6232 , 0xc5d92574 , 0x8b662de4 , 0xd1970a2d , 0x21224759 , 0x038d809d ] )
+  , Mont::unsafe_make( [ 0x967b0d82 , 0x5f2711d6 , 0x5daec84f , 0x3d00961f , 0xe6e158ee , 0xad23cd15 , 0xbd36e089 , 0x1e971a8e ] )
+  , Mont::unsafe_make( [ 0xade2e759 , 0xda1eb5bd , 0x6aaaeda2 , 0x09a1cb15 , 0x61fae43f , 0xfc3083df , 0x6e7d6ce4 , 0x1c6e9480 ] )
+  , Mont::unsafe_make( [ 0x7f99e91d , 0xe4843f47 , 0x0635ddbe , 0x6e659e38 , 0x966d1f3c , 0xd5975ee8 , 0xbab58538 , 0x0fd1f121 ] )
+  , Mont::unsafe_make( [ 0x31cdbfc5 , 0x9fc14d9f , 0x88c3db0c , 0x04f9c35e , 0xfc83fe0d , 0xa4cc3c76 , 0xcf360c33 , 0x1d40ccd2 ] )
+  , Mont::unsafe_make( [ 0xd8f92224 , 0xa207c12c , 0x0a7599d8 , 0x64f496a1 , 0x9b9c8f81 , 0x2c021e5d , 0xdddafdbf , 0x0e798a27 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x46cd6ef7 , 0xdd3a0ed2 , 0x4e2a926d , 0xb1168268 , 0x18ed3bf5 , 0x2bed4097 , 0x69cf61d9 , 0x075c4f79 ] )
+  , Mont::unsafe_make( [ 0xd18c394f , 0x331ae4e4 , 0xf0c2be47 , 0xcb6f6c73 , 0xb5ed1a87 , 0xb3770d3a , 0xdd309bd7 , 0x14333529 ] )
+  , Mont::unsafe_make( [ 0xe9cf90b9 , 0x457a69e2 , 0x71f7311c , 0x92154cbf , 0xc970d1de , 0x468941a5 , 0xc9a18744 , 0x170e7046 ] )
+  , Mont::unsafe_make( [ 0xd5baed4e , 0x56940369 , 0x260050b3 , 0xc3aac183 , 0xc376dfc1 , 0x683a4d6f , 0x9f72e3c3 , 0x28993beb ] )
+  , Mont::unsafe_make( [ 0x2dd3cef2 , 0xa48c8269 , 0x9a4e8b85 , 0xda02bdf1 , 0xeaeabd8c , 0x621bc756 , 0x9ab59227 , 0x19b26cb5 ] )
+  , Mont::unsafe_make( [ 0x74c15b24 , 0xe18c179f , 0xae90233f , 0x5987014b , 0x03b51c23 , 0x607925e7 , 0xf8cd6496 , 0x1ff2152e ] )
+  , Mont::unsafe_make( [ 0x09ab3e2f , 0x7ead20dd , 0x36923934 , 0x1b41487b , 0x57f8b1ac , 0x4952f011 , 0x07c5da6b , 0x2e9c47d4 ] )
+  , Mont::unsafe_make( [ 0x99f9df83 , 0x2993db35 , 0xe7e2126e , 0xd00d9d39 , 0x4c32885e , 0x31b38792 , 0x2baff09b , 0x2dbff2ac ] )
+  , Mont::unsafe_make( [ 0x693f5a62 , 0xfb86e3f8 , 0x7a367031 , 0x3d26a595 , 0x62d198ce , 0x5c4872b4 , 0xcea4ddc2 , 0x1b7c5efa ] )
+  , Mont::unsafe_make( [ 0xcc763601 , 0x5f06ff11 , 0x16238e67 , 0x7ddf5dbe , 0x68dcc464 , 0xc5c6aa43 , 0x7c4a1243 , 0x21f34191 ] )
+  , Mont::unsafe_make( [ 0xbbed180c , 0x73d25ce3 , 0x65494e0c , 0x84e293f4 , 0xf152b987 , 0xe04faf54 , 0xe1c31d4e , 0x22414070 ] )
+  , Mont::unsafe_make( [ 0x87180d4e , 0xb275e4eb , 0x72d2082e , 0xf5a34530 , 0xe98d5668 , 0x4c33f6b7 , 0xc75444a2 , 0x02d1de2d ] )
+  , Mont::unsafe_make( [ 0x5708a8d5 , 0x30d53b51 , 0x2304e647 , 0x61b87385 , 0x4d247ad4 , 0x1b4083ae , 0x5ff209b6 , 0x257b579a ] )
+  , Mont::unsafe_make( [ 0x95521a8c , 0x213b290a , 0x566df220 , 0x7e0cf54a , 0x66a63031 , 0xebf63c86 , 0x3ac7cb78 , 0x15fa3776 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x8bb9abe4 , 0x27c8ffb2 , 0xe27cb194 , 0xc07d0ab9 , 0x755ed9d0 , 0x845e6844 , 0xd9e3e9d3 , 0x0d10198e ] )
+  , Mont::unsafe_make( [ 0x5329576a , 0x021b924b , 0x9b0b0b56 , 0x0954fb0b , 0x83ef4469 , 0xe20ead03 , 0xa53011dd , 0x069d8c3e ] )
+  , Mont::unsafe_make( [ 0x82b7bbd6 , 0xca59d421 , 0xfd46a455 , 0xa2a9b329 , 0x4fdfd71e , 0x98aeff0a , 0xac579f19 , 0x1e7b1824 ] )
+  , Mont::unsafe_make( [ 0x5f7d6ec1 , 0x58106340 , 0x2473ccf6 , 0x6d5d4d36 , 0x543b3ebc , 0x3c3413a6 , 0x5ab73e0e , 0x06c32581 ] )
+  , Mont::unsafe_make( [ 0xc48a2400 , 0xd1d7a8c9 , 0xabe37199 , 0x1f58ee2a , 0xa1f38122 , 0xc34a8573 , 0x26ba3d6d , 0x1a7b1d1f ] )
+  , Mont::unsafe_make( [ 0x0b45edae , 0xf26006a1 , 0x1b45172b , 0x85c9fb24 , 0x88314011 , 0xe5012ae8 , 0xc15957b7 , 0x172878b6 ] )
+  , Mont::unsafe_make( [ 0x9a63e2f9 , 0xfad9ab06 , 0x2b783161 , 0xa3f4f7cd , 0x4aff8589 , 0x9bfdbd09 , 0x1cdfb7c7 , 0x29b5915f ] )
+  , Mont::unsafe_make( [ 0x4710d661 , 0x1b88dcf3 , 0xcf72a978 , 0x0988979f , 0xebd8a3ee , 0x63691f8e , 0x9ffb8a68 , 0x05c0b28c ] )
+  , Mont::unsafe_make( [ 0xe071ef31 , 0x9c7e71b8 , 0x59aaaf02 , 0x681ae300 , 0x64a8e2b9 , 0xa02c5fb0 , 0xab726edb , 0x1a0c36d4 ] )
+  , Mont::unsafe_make( [ 0xe38d56ab , 0x311e50c4 , 0xfdea23b8 , 0xbd0f87d5 , 0x7be83aa3 , 0x019223f9 , 0xd4d6a2bc , 0x1477d4c1 ] )
+  , Mont::unsafe_make( [ 0xe9d1a391 , 0x03f73575 , 0x0ebbef27 , 0xd4cc4b54 , 0xbbce5ead , 0x8697ef9c , 0x66a915f7 , 0x1d7624dd ] )
+  , Mont::unsafe_make( [ 0x78e9fb4f , 0x8ce732ec , 0x67f693af , 0xbf480fad , 0x206ea58b , 0xbac95da3 , 0x32e08421 , 0x284ff14e ] )
+  , Mont::unsafe_make( [ 0x2ee6d6d9 , 0x27883677 , 0xf346eb73 , 0x807afb85 , 0x1a2e5bbd , 0x79ef1724 , 0x4d8a67c1 , 0x182517d7 ] )
+  , Mont::unsafe_make( [ 0x9e62fe7e , 0x02f761fd , 0xa528bca3 , 0x16e4e018 , 0x408ec534 , 0x4ea6d48b , 0x09e65c86 , 0x0657a294 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x1088ad25 , 0x3c5a7c0c , 0xca0934d4 , 0x8a30b74f , 0x64fe6717 , 0xd84ccd72 , 0x89d8ed76 , 0x21f8003a ] )
+  , Mont::unsafe_make( [ 0xd0ca2780 , 0x8ecdcf8c , 0x59a7d270 , 0x4d34dcf3 , 0x950ee1a7 , 0xe401abb7 , 0xbbcde5b5 , 0x2db8e527 ] )
+  , Mont::unsafe_make( [ 0x55c13c28 , 0x73682d09 , 0x7bfc8ca3 , 0x3d9c9efe , 0xf6cbb94c , 0x59595810 , 0x62acbdab , 0x0514de2d ] )
+  , Mont::unsafe_make( [ 0x8b73bdab , 0x34e487bd , 0x26a2cf0a , 0x2d6035f9 , 0x5f1b224a , 0x37a9c3c2 , 0x8390e510 , 0x12d07de8 ] )
+  , Mont::unsafe_make( [ 0xa6082e26 , 0x64302efc , 0x2ec946d5 , 0x6021bb9b , 0x7064b653 , 0x9abed2aa , 0xc9d39519 , 0x2422c3e4 ] )
+  , Mont::unsafe_make( [ 0x30f6c1ac , 0x0a41a6dc , 0x15c9c11f , 0xf1ee7334 , 0x0f049b4d , 0x0b93dea4 , 0xcb6eed6e , 0x19044d7f ] )
+  , Mont::unsafe_make( [ 0x5e68a518 , 0xf0a58021 , 0x8f34ae4e , 0x74780837 , 0x761a9a51 , 0xc32fd046 , 0xb1f28403 , 0x0e47e5e3 ] )
+  , Mont::unsafe_make( [ 0x8a2ce809 , 0x37459db7 , 0x1e1cabcf , 0x1fcef639 , 0x90f1ba9f , 0x2201343a , 0xf87a25ee , 0x2658ba5c ] )
+  , Mont::unsafe_make( [ 0x9c6e5a50 , 0x32391ed2 , 0xc366511e , 0x336e4b1f , 0x6982e8ab , 0xe7142415 , 0x9db07596 , 0x195180ab ] )
+  , Mont::unsafe_make( [ 0x97b95bfe , 0x519e9b10 , 0x1260f301 , 0x24fe94b1 , 0xdf63c46d , 0x1cc8d355 , 0xab2cf284 , 0x0f994586 ] )
+  , Mont::unsafe_make( [ 0xd88a3927 , 0xe2587295 , 0x4aaae62c , 0x6b30f34f , 0xe860e407 , 0x5825d96d , 0x0309c9ff , 0x1494919c ] )
+  , Mont::unsafe_make( [ 0x26a12166 , 0x18f5a42a , 0x8a8006f1 , 0xfabbcd41 , 0xca3f7d18 , 0x2b0ae167 , 0x13f48757 , 0x0f136db9 ] )
+  , Mont::unsafe_make( [ 0x6bae51e3 , 0xe58bf632 , 0x037ee76f , 0xd3669f09 , 0xf0868cd6 , 0x3a25366a , 0x5d7f5618 , 0x137456f5 ] )
+  , Mont::unsafe_make( [ 0x926e211f , 0x30a82342 , 0x558b51f4 , 0x1a5970b8 , 0xf4ae6171 , 0x87dac67f , 0x53a8b72f , 0x1efdd2ef ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xddf3e5e7 , 0xaa2a2aae , 0x34ccabe4 , 0x28d2efeb , 0xda347a9d , 0xb0f2e56f , 0xe311abe0 , 0x22c00380 ] )
+  , Mont::unsafe_make( [ 0x71440856 , 0x7b235d59 , 0xf616215e , 0xb87e7d75 , 0x4e3b38c9 , 0x63327528 , 0xb050a463 , 0x01cf48d0 ] )
+  , Mont::unsafe_make( [ 0xece86de8 , 0x2b2eb600 , 0xccdb30cc , 0x262cc9e8 , 0x72d5d4e3 , 0x24401c2b , 0x8ec6f8bb , 0x1c6e8a17 ] )
+  , Mont::unsafe_make( [ 0x2adbe2ef , 0x7f4f74ba , 0xea1c853d , 0xa7b6dbdb , 0xcd592349 , 0x4c4c3ba7 , 0x8cf2f90b , 0x212cd697 ] )
+  , Mont::unsafe_make( [ 0xf7b6b60a , 0x2c3ef4b1 , 0x56c51eab , 0x8197c672 , 0xf3dc8296 , 0x26a53c3d , 0x56d13646 , 0x0fc6275f ] )
+  , Mont::unsafe_make( [ 0x811508b9 , 0x1a0afd22 , 0x49df52ce , 0x290aacf4 , 0x6e2a5a5f , 0x2158605f , 0xf5898d3e , 0x050f5029 ] )
+  , Mont::unsafe_make( [ 0xcdb2603a , 0x83139353 , 0x44a521be , 0xf1ff306e , 0x6c72c21f , 0x4863d3e9 , 0xcc8d6af6 , 0x1c665778 ] )
+  , Mont::unsafe_make( [ 0xac787f47 , 0x0d9a4639 , 0xbfd3ed29 , 0x2d1a7206 , 0x92d4da6a , 0x52783a00 , 0xa5b4618f , 0x18cf3c85 ] )
+  , Mont::unsafe_make( [ 0x08a590c0 , 0x2310130e , 0xe4b5308d , 0x6d5f1644 , 0x8fd3b6b3 , 0x2f7fba2e , 0xbf3d3d7b , 0x1f917565 ] )
+  , Mont::unsafe_make( [ 0x3ee49aa7 , 0x0e032864 , 0x54280c8d , 0x047f7263 , 0x2c26146f , 0x86db3df3 , 0x7e688701 , 0x2a1f2ac3 ] )
+  , Mont::unsafe_make( [ 0x849dadf0 , 0xe1b1a02f , 0xc4ed1995 , 0x69475095 , 0x5ae2f81e , 0x91841bc0 , 0x75c792db , 0x26d338ca ] )
+  , Mont::unsafe_make( [ 0x7059bef4 , 0xd7a39ca1 , 0x9a739bda , 0x96b2106e , 0xb07375be , 0x58242fd9 , 0x66497998 , 0x2e428d58 ] )
+  , Mont::unsafe_make( [ 0x8aeca967 , 0x7047b551 , 0x5ef4ad6f , 0x210e7bc4 , 0x3cc7d4f8 , 0xf3b832d6 , 0xda76c1e7 , 0x05dd3eda ] )
+  , Mont::unsafe_make( [ 0x5f7b9d2e , 0x4cbf1193 , 0x3757d167 , 0xaade379f , 0x59dbaebf , 0x7915d2e2 , 0x333b108a , 0x233ae406 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x8d7136a3 , 0x9708f9fe , 0xfb2b0d51 , 0xee18a1b8 , 0x2bb357ae , 0xdc5076ba , 0x5ce29022 , 0x064b0969 ] )
+  , Mont::unsafe_make( [ 0x79506ba9 , 0x1db3c376 , 0x30337c0d , 0xed3ddc57 , 0xdcaabb9c , 0x6f76cc61 , 0x9986a366 , 0x2e091e1b ] )
+  , Mont::unsafe_make( [ 0xe005c050 , 0x059f54ff , 0xa5958d42 , 0x92f8638d , 0x98ec94ab , 0xc0e6fb49 , 0x604861b9 , 0x1664153a ] )
+  , Mont::unsafe_make( [ 0x4ed471dd , 0xd57a1ae5 , 0xde1d1a8a , 0x1786188c , 0x8fc067a0 , 0x485e4a2f , 0x924d35f3 , 0x25ade54c ] )
+  , Mont::unsafe_make( [ 0x3432746a , 0x0ed4e899 , 0x62fc3e15 , 0x796e5682 , 0xffcab6e2 , 0x31997f09 , 0x73140648 , 0x028180a9 ] )
+  , Mont::unsafe_make( [ 0x2083fb32 , 0x545cb150 , 0x624cd18c , 0x3545a2c7 , 0x30948d04 , 0x2f3254d4 , 0x2e0dbfa3 , 0x05f2a9c1 ] )
+  , Mont::unsafe_make( [ 0xa5b61136 , 0xf2de44c2 , 0x6f5f4e95 , 0x8de2cd57 , 0x86eb1cb1 , 0xb1207b40 , 0x1b057139 , 0x018882df ] )
+  , Mont::unsafe_make( [ 0x372e1391 , 0xe2ec5220 , 0x97cf289a , 0x89b39872 , 0xf7a98087 , 0xd8e42e91 , 0x513ba436 , 0x06f637f1 ] )
+  , Mont::unsafe_make( [ 0x103883da , 0x835ea71c , 0x8a35c81c , 0xf956a291 , 0xaf74eae0 , 0x54bd6c57 , 0x76938cc5 , 0x2416d8d5 ] )
+  , Mont::unsafe_make( [ 0x3f2c0333 , 0xaa0a3822 , 0x299c4001 , 0x5ceca67e , 0x2236c8ae , 0x265c072f , 0x841f4ea1 , 0x1c983822 ] )
+  , Mont::unsafe_make( [ 0x65af43f1 , 0xb8b53958 , 0x36d82623 , 0x0e62eb6e , 0x823e8871 , 0xfc39e3dd , 0x4f7a877f , 0x23eabfd0 ] )
+  , Mont::unsafe_make( [ 0xaa748493 , 0xd914f706 , 0x877418ce , 0xdf832e7f , 0x883a1a9e , 0x86f797d3 , 0xcfcad566 , 0x08347675 ] )
+  , Mont::unsafe_make( [ 0xcc9ad100 , 0x6e0ebdc0 , 0x6ed1f983 , 0xf52f2b3d , 0x5e497597 , 0x1814e1da , 0x06356144 , 0x0b17708e ] )
+  , Mont::unsafe_make( [ 0x430a9718 , 0x5ad99eee , 0xf19b05fa , 0x39c59e24 , 0x6672f174 , 0x6d042bf6 , 0x643d9273 , 0x0ae4a547 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xa0e69a2d , 0x1be1c509 , 0x3bef3909 , 0x49a0a3a7 , 0x2db2dc48 , 0xbeed9347 , 0xa36e2dfa , 0x171c2387 ] )
+  , Mont::unsafe_make( [ 0xd1b62d6e , 0xc63fd93b , 0x3add4a86 , 0xd465db0a , 0x7258ff17 , 0xe45d98c0 , 0x31da333e , 0x2523227b ] )
+  , Mont::unsafe_make( [ 0x1a00db0d , 0xf9dcc015 , 0xe0d902c3 , 0xec41dbc2 , 0xb3dedcaa , 0x57423945 , 0xd05e809e , 0x2251371c ] )
+  , Mont::unsafe_make( [ 0xf245f379 , 0xf8cd5c97 , 0x280e43bb , 0xa2a48655 , 0xd16907c1 , 0x2122c867 , 0xc5cf054a , 0x1cdc9b57 ] )
+  , Mont::unsafe_make( [ 0xc96e34e6 , 0xd1fccf6d , 0xd3c71c2f , 0xc5d613b7 , 0x3a714ecd , 0x75449e64 , 0x7ce30e88 , 0x277e69d4 ] )
+  , Mont::unsafe_make( [ 0x2cfc19b8 , 0x7db0b67d , 0x766e348e , 0x93cbb7da , 0xe3685f36 , 0xf531e5bc , 0xf4ee38ec , 0x17c4569c ] )
+  , Mont::unsafe_make( [ 0x0291f606 , 0xb11cfa58 , 0xc170bedb , 0xfe76af88 , 0xceaa4447 , 0x8af06c15 , 0x86d35749 , 0x199b72e9 ] )
+  , Mont::unsafe_make( [ 0x12f91d5e , 0xf6086a90 , 0xf47d146b , 0x9a234acf , 0xeb068ff5 , 0x06ad31ec , 0xf26297b9 , 0x0ed59450 ] )
+  , Mont::unsafe_make( [ 0x4ebfa497 , 0x7b949ebd , 0x3474c406 , 0xec8cd1ff , 0xe79519f1 , 0xa6e0ee72 , 0x5a491824 , 0x18ea745b ] )
+  , Mont::unsafe_make( [ 0xb87d9222 , 0xf32d506e , 0x38d33b62 , 0xdf30ee38 , 0xb82efce6 , 0xd677469b , 0x9c40f295 , 0x2d54e615 ] )
+  , Mont::unsafe_make( [ 0x70b05176 , 0xc012b9cc , 0xda8f6264 , 0x9ee6b0cc , 0xe0d48dd4 , 0xdacc31f0 , 0xe3298add , 0x271c0d0e ] )
+  , Mont::unsafe_make( [ 0xcd65b344 , 0x7be32420 , 0x2a96c59b , 0x1281270d , 0x0a86af32 , 0x6e9efe09 , 0x5c5f0517 , 0x0f5c9efd ] )
+  , Mont::unsafe_make( [ 0x33626c78 , 0xc01457d2 , 0x7136d674 , 0xfc070eb5 , 0x3f2f1aae , 0xf14295e8 , 0x1b16a7ea , 0x0e544a6e ] )
+  , Mont::unsafe_make( [ 0x7748a8ef , 0xf4761cf1 , 0xb1db5286 , 0x2148f9a9 , 0xad6c14bd , 0xe7ee2b2b , 0x8ab3b541 , 0x03abfa86 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x1bb60a3c , 0x3f444ef8 , 0xe36e410d , 0x747fcf37 , 0x4d757709 , 0xb7d78e58 , 0x1fb2e8d2 , 0x133cd3a6 ] )
+  , Mont::unsafe_make( [ 0x802ced5a , 0x63297a07 , 0x0fbfefa5 , 0xccdd4d11 , 0x9168e200 , 0x050264b5 , 0x21900358 , 0x177a79a4 ] )
+  , Mont::unsafe_make( [ 0x2fab6749 , 0xc03c3b77 , 0x912bd437 , 0x14243b46 , 0x61421eca , 0xa8e3d535 , 0x3801f6ee , 0x09112782 ] )
+  , Mont::unsafe_make( [ 0x1c9222a9 , 0xa0a7275b , 0x7c6131d0 , 0xe7a7307f , 0x5bec45aa , 0x2dfd26e0 , 0xbc1137c4 , 0x1bc9b1b5 ] )
+  , Mont::unsafe_make( [ 0xbf464641 , 0xb77be270 , 0x24631c2f , 0x2fea31eb , 0xe47381fa , 0x70213ae6 , 0x63b1670e , 0x2f3f6d32 ] )
+  , Mont::unsafe_make( [ 0xf84316f0 , 0x348d7b37 , 0xae04eadc , 0x758c9ad4 , 0xe52643a7 , 0x552d51fa , 0xbe47b876 , 0x02ce7662 ] )
+  , Mont::unsafe_make( [ 0xc873afce , 0xda6ecc80 , 0xda438f0b , 0x1f9fe5c7 , 0x6f78b09b , 0xb3baa1f2 , 0x3336e51f , 0x2423e4f6 ] )
+  , Mont::unsafe_make( [ 0x22af45a7 , 0xeb0d77ff , 0x31b5cf7a , 0x992e73fb , 0xdee2545a , 0xfb0d60a3 , 0xee99de13 , 0x2e29bbb0 ] )
+  , Mont::unsafe_make( [ 0x0f2056d4 , 0x9c054e6c , 0x8a8125f8 , 0x18872a69 , 0x7b0615a7 , 0xf9c4d93f , 0x2abeb961 , 0x0ce77790 ] )
+  , Mont::unsafe_make( [ 0xeaa3e483 , 0x682fc26e , 0x6e6cd332 , 0xaab58637 , 0x2f6a4932 , 0x573daa90 , 0x23db96f8 , 0x03dc0102 ] )
+  , Mont::unsafe_make( [ 0xd8247e58 , 0xba00548a , 0xcda6390a , 0x657dbdb3 , 0x215db49f , 0x859df157 , 0x5a0fb4c6 , 0x11f8f8d7 ] )
+  , Mont::unsafe_make( [ 0xd663f2ae , 0x0adcd915 , 0x34170b30 , 0x91eb577d , 0x77bfa530 , 0x6de973b7 , 0x69bf9598 , 0x253f58be ] )
+  , Mont::unsafe_make( [ 0x23f78836 , 0xebed1e33 , 0xef9c5e2e , 0x9b633d37 , 0xd67e9723 , 0xa99cd1c6 , 0xb33bc33c , 0x0997b99f ] )
+  , Mont::unsafe_make( [ 0x4a0716db , 0xfbad731e , 0x23b80de5 , 0x8de32e96 , 0xbda75818 , 0x30002741 , 0xc8de6112 , 0x12e723d8 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xde3bee92 , 0xd842f41d , 0x92190a5a , 0x86fbf6a2 , 0x644828ae , 0xcdd56f6f , 0x458c3d49 , 0x12f21b93 ] )
+  , Mont::unsafe_make( [ 0x1044c378 , 0xbb25fbd1 , 0x8b7e8e28 , 0xe6b33f7e , 0x93f03628 , 0xdfe037de , 0x10e432da , 0x2b4aae75 ] )
+  , Mont::unsafe_make( [ 0xbd154e47 , 0x154ef823 , 0xd6c59a71 , 0xdb057d66 , 0x725d9fc8 , 0x7f75ccd9 , 0xf5bd1fce , 0x14ca8929 ] )
+  , Mont::unsafe_make( [ 0x76d8a9d8 , 0x9da0b138 , 0x1612306b , 0xb1461821 , 0x6a682a22 , 0xd3668d7e , 0x62291a8b , 0x2fe3298e ] )
+  , Mont::unsafe_make( [ 0xd2162e7a , 0xd5a7a2d5 , 0x59a41daa , 0xa6728882 , 0x6b1d5de1 , 0xdc41f927 , 0xa10e121a , 0x2a33b5f1 ] )
+  , Mont::unsafe_make( [ 0x1a63d25b , 0xf980ae44 , 0x3d1e1e5b , 0xfb40aa1d , 0xc7e40a44 , 0xc4fcf545 , 0x99d9245c , 0x13595602 ] )
+  , Mont::unsafe_make( [ 0x7b7e3a92 , 0x648a2dc3 , 0xf12a2f2a , 0xc00fcd3a , 0x50620136 , 0x76f1bb20 , 0xfde1d959 , 0x3023eacf ] )
+  , Mont::unsafe_make( [ 0x0096f58e , 0xc0379124 , 0x3c9d5bbf , 0x3b5378aa , 0x4303d284 , 0x9905748a , 0x043d4b9b , 0x16b36e67 ] )
+  , Mont::unsafe_make( [ 0x24bc2aaa , 0x960d5260 , 0x9f89da16 , 0xf27961f9 , 0xe5908fce , 0x654ee6bc , 0x68e5bb64 , 0x0ce0666b ] )
+  , Mont::unsafe_make( [ 0x13c6ef1b , 0x9f4d71a5 , 0x81922630 , 0x1a4cfa62 , 0x0f4fe91f , 0x3a206f55 , 0xaf2687dc , 0x17696194 ] )
+  , Mont::unsafe_make( [ 0x923d2e03 , 0xb61134c2 , 0x6b4b502c , 0x62af85a3 , 0xbc5f477a , 0x4d243321 , 0xce35f4c0 , 0x17ed3ebe ] )
+  , Mont::unsafe_make( [ 0xfe2b0762 , 0x36aa82fc , 0x9d696ae5 , 0x964a6ef6 , 0x2a9b8810 , 0xab240a56 , 0x94c3d515 , 0x24653795 ] )
+  , Mont::unsafe_make( [ 0xca346f25 , 0xf51bc8ae , 0x1c9e35bd , 0xa0e4629b , 0x3788b5bd , 0x4523eef7 , 0x4f07fc42 , 0x26535d51 ] )
+  , Mont::unsafe_make( [ 0x3b2a2156 , 0x7050b47e , 0x0a183d5e , 0x094e468d , 0x381a29b8 , 0x87c6f21e , 0xc6fef1cc , 0x0a489a6e ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xf4918724 , 0xd0b827a5 , 0x6ba2dea1 , 0x0f6c108e , 0xfae9e95c , 0x48f13c55 , 0xd3702e16 , 0x1a590a8d ] )
+  , Mont::unsafe_make( [ 0xd52f1b1c , 0x398f9b51 , 0xd4c96a25 , 0xe1a22c1b , 0x19cca056 , 0x72981a3c , 0x9c769782 , 0x0722c7f8 ] )
+  , Mont::unsafe_make( [ 0xef6b09e8 , 0x1f780758 , 0xd68103df , 0x3fd7028b , 0xd8bc009b , 0xcfed8af4 , 0x6830c142 , 0x0159b423 ] )
+  , Mont::unsafe_make( [ 0xbf1c31d8 , 0x8187511d , 0xb941f151 , 0x07ac3660 , 0xd58264af , 0x57eb1123 , 0xbbcebde4 , 0x1dee149e ] )
+  , Mont::unsafe_make( [ 0x69026a37 , 0x37e180bd , 0xae2c849f , 0x99c32b44 , 0xe84c1c59 , 0x32b87924 , 0x6f850d43 , 0x01c4c26d ] )
+  , Mont::unsafe_make( [ 0xc21cca27 , 0xa1689a3d , 0xfd1119d4 , 0x48b9f1de , 0x25f073f4 , 0x37482763 , 0x335d515c , 0x0ef8684e ] )
+  , Mont::unsafe_make( [ 0x912e32f1 , 0x805eca9b , 0xa35d854c , 0x1853143e , 0xe59dcd7e , 0xa2af1196 , 0x62008d0f , 0x2a0d55be ] )
+  , Mont::unsafe_make( [ 0x76765fcb , 0x179cd6b0 , 0x252ceef4 , 0xe2d7f9a4 , 0x5d0150a1 , 0x0ba75b92 , 0x725ce865 , 0x12bc8b15 ] )
+  , Mont::unsafe_make( [ 0x876d57bb , 0x1357a165 , 0xff1119cf , 0x64d823b5 , 0x4a63cdf0 , 0x7052c643 , 0x8a4451aa , 0x15bc1b0f ] )
+  , Mont::unsafe_make( [ 0xad4c5c99 , 0x0d8c9f8f , 0x74b1f924 , 0xad2a5af6 , 0xa288cee2 , 0xc58f3d5f , 0x108c9c38 , 0x01be3449 ] )
+  , Mont::unsafe_make( [ 0x8d7eab66 , 0x64db5ab7 , 0x843141eb , 0xcf0df749 , 0x85ebb2d6 , 0x5e0e546d , 0x919f621d , 0x1a3d1314 ] )
+  , Mont::unsafe_make( [ 0x02ea76e2 , 0x69630652 , 0x947f71b6 , 0xa3804313 , 0x283f77f6 , 0x51fae812 , 0x46226dfb , 0x0214bbd3 ] )
+  , Mont::unsafe_make( [ 0xf120d0ef , 0x25decdbc , 0xf822ac5d , 0x156da550 , 0x1670276f , 0x5deeb265 , 0x1670a0f9 , 0x188ab8d2 ] )
+  , Mont::unsafe_make( [ 0x7dd4d739 , 0x49582c46 , 0x9b644a74 , 0x38f60bf0 , 0x53ecbb5b , 0xee7c642e , 0x36acde53 , 0x2e3c9a90 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x9ae50e5e , 0x5c3e02e0 , 0x9108dfa9 , 0x12545efc , 0x70916eb7 , 0xc70f0247 , 0xa771884f , 0x0095459d ] )
+  , Mont::unsafe_make( [ 0xb067789f , 0x88663100 , 0x73701010 , 0x40062eff , 0xf2441446 , 0xa8926f2d , 0x5a8716cc , 0x0e6f1d07 ] )
+  , Mont::unsafe_make( [ 0x034beb16 , 0x372efa33 , 0x08a7e1c5 , 0x2bc28701 , 0xd49e71ce , 0xf4afddca , 0xba5736d1 , 0x18babfca ] )
+  , Mont::unsafe_make( [ 0x9ecd4ecc , 0xd8974e4b , 0x80082316 , 0xee7c45c7 , 0xd0f1b564 , 0xf87b2e6c , 0x9b32fe77 , 0x0f193ebf ] )
+  , Mont::unsafe_make( [ 0x00a09b63 , 0x799fd2c0 , 0x629724cc , 0xbfafc218 , 0x273ad0b8 , 0xed52ca97 , 0x4788d40b , 0x126f1e83 ] )
+  , Mont::unsafe_make( [ 0xa777d12a , 0x60d26870 , 0xa7335c55 , 0x8bfadd29 , 0x6512e3df , 0xd005d560 , 0x844cf3ca , 0x10bf1454 ] )
+  , Mont::unsafe_make( [ 0x09946b05 , 0x9fbfc5c5 , 0xc1890aad , 0xc359ca22 , 0xec63d4bc , 0x45151895 , 0x98f62b07 , 0x05e1adb0 ] )
+  , Mont::unsafe_make( [ 0xb1b6d60b , 0xf0e6410d , 0x77814c19 , 0x63da416a , 0xae5f6ead , 0x5b157df5 , 0x176f7ad4 , 0x22556154 ] )
+  , Mont::unsafe_make( [ 0xeb654e47 , 0x0a42c8ac , 0x0a6f6d4e , 0xa166fee2 , 0xebf3c171 , 0x91018a0b , 0xad2253f2 , 0x1c0ce4bf ] )
+  , Mont::unsafe_make( [ 0x6e824b61 , 0xff27453d , 0xc2ddc5a8 , 0x5a887301 , 0xa037c3ea , 0xd24ec0c6 , 0x0b3c66f5 , 0x224dbbbf ] )
+  , Mont::unsafe_make( [ 0x9f26b23f , 0xbd813eb2 , 0x83de199a , 0xa8ef7bc5 , 0xf85fdef0 , 0xe498f660 , 0xd6bbcac2 , 0x28ec2608 ] )
+  , Mont::unsafe_make( [ 0x833e0df0 , 0x81a0e21b , 0x8f37f70d , 0x24a3fb4d , 0xebfc4133 , 0x126f6eb2 , 0x9cc2a491 , 0x17738e24 ] )
+  , Mont::unsafe_make( [ 0x09439ca7 , 0x52b134e3 , 0xe5b2d110 , 0xb7ba155c , 0x37f1a96f , 0x31fc4251 , 0xf437b6f7 , 0x28b940c7 ] )
+  , Mont::unsafe_make( [ 0x1faca857 , 0xfcaa493d , 0xacf32d21 , 0xf6fc1166 , 0xf6ec6ba5 , 0x3b35579c , 0x7bc6323c , 0x19e0531f ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xa03f683f , 0x1e8bad58 , 0x739f99de , 0x058327f2 , 0xb5ce5a11 , 0xc6527865 , 0x19950e2e , 0x068c347f ] )
+  , Mont::unsafe_make( [ 0x6a17e5c3 , 0x828822ec , 0x9cc1adbd , 0x8b6759b1 , 0x426e9e75 , 0xc14e0419 , 0xdd1a3647 , 0x143bd167 ] )
+  , Mont::unsafe_make( [ 0x891c5e81 , 0xd53b7183 , 0x1e9ddabf , 0x079a51f7 , 0x615192bc , 0x6f118cf9 , 0xc91b8cc8 , 0x1f2c9628 ] )
+  , Mont::unsafe_make( [ 0x205a1866 , 0x7d991e87 , 0xe66729df , 0x75b486aa , 0x36076150 , 0x743ea933 , 0x482ec6fd , 0x1bf1d256 ] )
+  , Mont::unsafe_make( [ 0xcc523a47 , 0x157a9e4e , 0xd73838e3 , 0xb69a307a , 0xd4b9e851 , 0x463a8859 , 0x55985e48 , 0x0c14185d ] )
+  , Mont::unsafe_make( [ 0x716d60c6 , 0xe745963c , 0xf560e072 , 0x3f798a11 , 0x8db48ce4 , 0x6567080e , 0x4ec14225 , 0x3062393c ] )
+  , Mont::unsafe_make( [ 0x5e56af84 , 0x2a1e315d , 0xee0b9c16 , 0x8e923b03 , 0x2b904c9e , 0x570e24ce , 0x47268e97 , 0x1a1db630 ] )
+  , Mont::unsafe_make( [ 0x572710c3 , 0x13447732 , 0x650e446d , 0xe41bde51 , 0xdf4bb2f4 , 0x903d320f , 0x7529f8fa , 0x2eb8d505 ] )
+  , Mont::unsafe_make( [ 0x962d18f7 , 0x2b44a022 , 0x4275ceb5 , 0xd4e41eae , 0xe56fe20f , 0x5c8a1ff8 , 0x043fe6c9 , 0x29167048 ] )
+  , Mont::unsafe_make( [ 0x8fd66a04 , 0x7a04a5c6 , 0xce0fd99a , 0xff98f37e , 0xcede7bd8 , 0xbbae9f41 , 0x128b0c49 , 0x2bf8c8bd ] )
+  , Mont::unsafe_make( [ 0x5599dc72 , 0x092b6c38 , 0xf3aa1d70 , 0xe97c5910 , 0x44a9c248 , 0x550b51a9 , 0xaa570a76 , 0x20b0ec6e ] )
+  , Mont::unsafe_make( [ 0xe0dc90c8 , 0xf2ddc8f8 , 0x45cbc5cd , 0xc77988d5 , 0xd474d41c , 0x4d573a27 , 0xa061fb71 , 0x0b445ace ] )
+  , Mont::unsafe_make( [ 0x081ecf84 , 0x9391095f , 0xe8397f7e , 0x4f0a4ead , 0x98158579 , 0x06d559e4 , 0x9def4691 , 0x154be7ec ] )
+  , Mont::unsafe_make( [ 0x757f218c , 0xf301fda5 , 0x8acce434 , 0xd5a1aa4e , 0x47a1233f , 0x6a13345d , 0xc0d14203 , 0x21dff52e ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x1669c7d1 , 0x1944a074 , 0xae436ff4 , 0xdaf2f3fa , 0xe5bb931a , 0x0688527e , 0x14aeb433 , 0x1e93ab6d ] )
+  , Mont::unsafe_make( [ 0x512073ed , 0x96f234f3 , 0x941cb377 , 0xaa0f3ecd , 0xa5ca498f , 0xf0164450 , 0xfb287fe7 , 0x2bc9fc45 ] )
+  , Mont::unsafe_make( [ 0x8be64fd8 , 0xa30a7459 , 0x7062bed7 , 0x6327e0c8 , 0x513a534e , 0xf3b80fa8 , 0x9b47b692 , 0x0154e460 ] )
+  , Mont::unsafe_make( [ 0x35bb18a5 , 0x55d8465d , 0x31543cc8 , 0x58b94a87 , 0xb7bca221 , 0xcf446b45 , 0x21486af5 , 0x0a958b8a ] )
+  , Mont::unsafe_make( [ 0x6631e2b9 , 0x3f38d3dc , 0x8e98b497 , 0x3fce673a , 0x8901abf7 , 0x03334337 , 0xdc647774 , 0x238dc727 ] )
+  , Mont::unsafe_make( [ 0x0514883d , 0x212121ad , 0x0e8279b2 , 0xdfb52e47 , 0xe85e0d74 , 0x9c60c5d8 , 0xb0c0178a , 0x2c146ec1 ] )
+  , Mont::unsafe_make( [ 0x5cf29687 , 0x1f876e02 , 0x98df22b4 , 0x9bbc2dbd , 0x1be0cac8 , 0xec4550fe , 0x14ba0f59 , 0x2cf55546 ] )
+  , Mont::unsafe_make( [ 0x31632a1c , 0x8795cfbd , 0x7e148fd9 , 0x4d0f0fcb , 0x5de4ea3b , 0xadeeefb6 , 0x1dff0170 , 0x05714f03 ] )
+  , Mont::unsafe_make( [ 0xe4e8fed9 , 0x3da54ddf , 0xa042f905 , 0xf15a48bf , 0x91a4edf5 , 0xdf02cb80 , 0xf232a979 , 0x03d1697d ] )
+  , Mont::unsafe_make( [ 0x7ab4137a , 0x5e62d9aa , 0x69fb6fac , 0x5b4a4d2e , 0x480dc567 , 0x9f277418 , 0xa8496077 , 0x2f33aff8 ] )
+  , Mont::unsafe_make( [ 0xef46bd63 , 0x379e47aa , 0xbc2eda23 , 0x87d3e91f , 0x68fb8f95 , 0xc680d138 , 0x29db57ad , 0x07cdcb99 ] )
+  , Mont::unsafe_make( [ 0x1304d411 , 0x4d31e54f , 0x9e136456 , 0xce75f4a2 , 0x3e4df92f , 0x0f3e246d , 0x33d3cecb , 0x1a30ed4a ] )
+  , Mont::unsafe_make( [ 0x5656d719 , 0x0b8acadc , 0x5b47c4b6 , 0x25ef7ac3 , 0xec51fc0d , 0xef7ecd5a , 0x560d093e , 0x0277b93d ] )
+  , Mont::unsafe_make( [ 0xcc3a0a52 , 0x0b630cf7 , 0x6fa2b74b , 0x5b2c9b26 , 0xca1319b4 , 0xad44c35e , 0xaf2fef40 , 0x2a5795df ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x26df4029 , 0x1bc310bd , 0xbce4e1dd , 0x931c5306 , 0x6d1cbd17 , 0x49d89f1f , 0x229c6177 , 0x227ec157 ] )
+  , Mont::unsafe_make( [ 0xe824ebbf , 0x5e0ab9f2 , 0xb33f20e8 , 0x10ba2ec2 , 0x5d3d514c , 0xbe29ccc5 , 0x9fb9291b , 0x2ae05741 ] )
+  , Mont::unsafe_make( [ 0x21b79183 , 0x209e0533 , 0x0126fb91 , 0x23c7ef51 , 0x9e59dabe , 0xd70f56cc , 0xb3032ac9 , 0x29b9de5b ] )
+  , Mont::unsafe_make( [ 0x929277c7 , 0x94e4c0b4 , 0xc88a603e , 0xca02eb7b , 0x9fa55cff , 0xa1f2d5d1 , 0x3b61063d , 0x1e1d2fb8 ] )
+  , Mont::unsafe_make( [ 0xafffc315 , 0x0ac3306c , 0x1deba196 , 0xcf90cead , 0xa9f2537a , 0xb285a208 , 0x76b82732 , 0x24cd9cc2 ] )
+  , Mont::unsafe_make( [ 0x59ccfea6 , 0x11f3eba3 , 0x4c0a2f59 , 0x605ff80c , 0x4d2bb856 , 0xb1196c9a , 0x7c90768b , 0x2c07af4c ] )
+  , Mont::unsafe_make( [ 0xf9b0cc7c , 0xdec7459c , 0xe52731a7 , 0x7aa213d3 , 0x4b6228b0 , 0x43c484bf , 0xa2a5693a , 0x1a15874f ] )
+  , Mont::unsafe_make( [ 0x60a142dc , 0x52742738 , 0x9dd418e1 , 0xacd50464 , 0x50e5a8df , 0x539f3854 , 0x78e7cc7f , 0x1501e671 ] )
+  , Mont::unsafe_make( [ 0xfc9732d2 , 0x4a0f390a , 0x2280658d , 0x2cfef52a , 0xfa56d616 , 0x48fa5b51 , 0xfd0071af , 0x20c1113d ] )
+  , Mont::unsafe_make( [ 0x33822e56 , 0xf9ac9ed8 , 0x850c1856 , 0x7382e232 , 0x30a45d25 , 0x22adae58 , 0x0ccae89b , 0x2f24e2ce ] )
+  , Mont::unsafe_make( [ 0x37e5bf0b , 0x9efee9f3 , 0x922a7995 , 0xbedf1948 , 0x67e78ea2 , 0x269edb27 , 0x4b1022a4 , 0x0398de5e ] )
+  , Mont::unsafe_make( [ 0x70732bff , 0x8b0a9d9a , 0x0a5ac795 , 0x4c600c01 , 0x727486ce , 0xeaced48a , 0x926f1c12 , 0x072ac521 ] )
+  , Mont::unsafe_make( [ 0x6a63cdc2 , 0xafc4282c , 0xb3c346c8 , 0x574a0e82 , 0xc1293c19 , 0x47789f4b , 0x65bcdfdd , 0x24167e52 ] )
+  , Mont::unsafe_make( [ 0xfd78316e , 0xf4077244 , 0x6c212acf , 0xfa939cf2 , 0x343670e4 , 0x3a4c97b6 , 0x82237a95 , 0x1c3b6648 ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xb04f78bb , 0xb47fc64e , 0x8ffc99d2 , 0x0418c6ba , 0xc0c2bd04 , 0x67b58669 , 0x8e73cfc2 , 0x03841cfd ] )
+  , Mont::unsafe_make( [ 0xce38d56c , 0x4056e6fe , 0x8d3adc21 , 0x0176099d , 0xeca03563 , 0xc32a920d , 0x22a08d93 , 0x04b72758 ] )
+  , Mont::unsafe_make( [ 0xfba1a3ce , 0x008f875a , 0x333b5537 , 0xe97b8dbe , 0x6b88f060 , 0x5f74e64f , 0x3e0185d8 , 0x02651969 ] )
+  , Mont::unsafe_make( [ 0xc75a718c , 0x301c0ab7 , 0xe93c0b23 , 0x7e576ce2 , 0x78acdcd0 , 0xb906c36f , 0x32e381d9 , 0x0fe646e7 ] )
+  , Mont::unsafe_make( [ 0x2f3e6da3 , 0x63ea9e36 , 0xd16dcaac , 0x8825368a , 0xc8aa2c15 , 0xa7ec5d4f , 0x5c52281e , 0x2b2f55db ] )
+  , Mont::unsafe_make( [ 0x3dc0e7e9 , 0xb11b4c0f , 0x32187841 , 0x631dcf9a , 0xcf771338 , 0x60e5be59 , 0xe94e8bb0 , 0x29bd0f42 ] )
+  , Mont::unsafe_make( [ 0x57b0add5 , 0x6196412e , 0x9a6074d9 , 0xc4049d42 , 0x21676a5c , 0x058aa1d2 , 0x03c50204 , 0x28b3f490 ] )
+  , Mont::unsafe_make( [ 0x55e64c1a , 0x78dc8be6 , 0x49d1ef63 , 0x9158abd3 , 0xa2cc478f , 0x81d65e65 , 0x38774c89 , 0x2da9e476 ] )
+  , Mont::unsafe_make( [ 0x228e8c22 , 0xa246c018 , 0x3d4f811d , 0x46d4f8b6 , 0x27a37050 , 0xdfb8b489 , 0xd4dac4ce , 0x14a5e941 ] )
+  , Mont::unsafe_make( [ 0xf56cc5db , 0xcebcf000 , 0x07ff434d , 0x5f15cb33 , 0x49817fa9 , 0xd0f8c5f9 , 0xeb968c49 , 0x1868acec ] )
+  , Mont::unsafe_make( [ 0x5352de86 , 0xad3a0b35 , 0x1106d8ca , 0x09a3ce37 , 0x17a5e88e , 0x4fbdc7b5 , 0xa52306ed , 0x2af933de ] )
+  , Mont::unsafe_make( [ 0x0bca9ce0 , 0x47503138 , 0xa5c5a743 , 0x132829a4 , 0x6d3315a3 , 0xa22406b1 , 0x33f09dd8 , 0x1f0c436c ] )
+  , Mont::unsafe_make( [ 0xbf0c3de2 , 0x8de4e7f0 , 0xca75dec1 , 0x3d8acf12 , 0xf953d0f1 , 0xd4c22130 , 0xd3b9c901 , 0x10e54398 ] )
+  , Mont::unsafe_make( [ 0x60e2feb6 , 0xd634e8ac , 0x8a1c1151 , 0xd44307a3 , 0x39fd78b0 , 0x87768eeb , 0xe84c7285 , 0x1b9ba79c ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0x2446c2ca , 0x69279892 , 0xfce73b8a , 0x9607856d , 0x4a65d466 , 0xcc850c97 , 0xbe19ecb7 , 0x05d83392 ] )
+  , Mont::unsafe_make( [ 0x51621626 , 0x7c7c3f07 , 0xaa6620c0 , 0x205c011f , 0x30d3b3c6 , 0x526550dc , 0xdb8cfcd6 , 0x1bab2abf ] )
+  , Mont::unsafe_make( [ 0x46a24fcc , 0x8fe6e1ac , 0xdaf00b2b , 0x685e5e7e , 0x0abbec7e , 0xb1d1437e , 0xb17fa2fe , 0x299a2c0f ] )
+  , Mont::unsafe_make( [ 0x7a13789d , 0xd2af4940 , 0xe8433a3a , 0x9eeff840 , 0x7e88d292 , 0xc19cff13 , 0x08afa593 , 0x27f155f9 ] )
+  , Mont::unsafe_make( [ 0xc2c00c72 , 0x09e9bd23 , 0x19a17d52 , 0x5d55d0a9 , 0x0dcf7898 , 0xddab95fa , 0x194dd006 , 0x1b6b36f3 ] )
+  , Mont::unsafe_make( [ 0x7ab783ea , 0x44cacd30 , 0x77d78297 , 0xd3be2a06 , 0x35a78ee1 , 0x79eedd15 , 0xbedc5ea3 , 0x16f6fe92 ] )
+  , Mont::unsafe_make( [ 0x9e99b40a , 0xd1634052 , 0xdcf62da8 , 0x45ac8ac1 , 0xcb0cc9b4 , 0xf5685a5d , 0x607b7add , 0x1445d455 ] )
+  , Mont::unsafe_make( [ 0xc4a4daa5 , 0xaa3917d9 , 0xd2df1d09 , 0x743d55fe , 0x3cd6cb3a , 0x1507e410 , 0x79d45c56 , 0x108c29eb ] )
+  , Mont::unsafe_make( [ 0x02d15b0c , 0x579e9218 , 0x6faa5244 , 0x0bdf35fa , 0x7219af9e , 0x6cd20da1 , 0x2f959f98 , 0x0f78513a ] )
+  , Mont::unsafe_make( [ 0x38dc2a56 , 0x69c0b842 , 0xb17b6641 , 0x012488c8 , 0xaf7ace2c , 0x1d3e629e , 0x629f7bb3 , 0x0c375b33 ] )
+  , Mont::unsafe_make( [ 0x3cb382cf , 0x0f8a4ffa , 0xf2e0eea3 , 0xdc5becfe , 0xf2d454d5 , 0xadf3501b , 0x2903a7f2 , 0x03f91898 ] )
+  , Mont::unsafe_make( [ 0xc3b3aef1 , 0x4b0a82bd , 0xa63c023a , 0x9ec809a7 , 0xd5d8e02e , 0x6dd5641b , 0xd77dd89b , 0x06772f66 ] )
+  , Mont::unsafe_make( [ 0x7a74e68d , 0xbb0a4220 , 0xce785798 , 0x10e1e0a0 , 0x316ddd7a , 0xe76c516f , 0xd345cd86 , 0x2da66432 ] )
+  , Mont::unsafe_make( [ 0xde31a9b9 , 0x3d6ebbc4 , 0xcf94b825 , 0x2492236e , 0x7f5be246 , 0x2b048cfe , 0x1a97c372 , 0x1b89c09b ] )
+  , Mont::unsafe_make( [ 0xf090b931 , 0x1f4dbe8b , 0x88fca25a , 0xd395b737 , 0x94b1efd6 , 0xdc238285 , 0x70bf495c , 0x1141a501 ] )
+  , Mont::unsafe_make( [ 0xe06c2e5e , 0x6a75c055 , 0x9c1d7980 , 0xb269a5d4 , 0x24ae57b9 , 0xc5c52853 , 0x97d43a2a , 0x072e6408 ] )
+  , Mont::unsafe_make( [ 0xa287ab35 , 0x404d1d14 , 0xe339f054 , 0xc1940925 , 0xa9be66fa , 0x2f6fa1aa , 0xafaee0df , 0x2dec47a4 ] )
+  , Mont::unsafe_make( [ 0x704ab9aa , 0x99e3ad83 , 0x64c7a9ee , 0x8c0f7c65 , 0x0af263c7 , 0x6537c8dd , 0x8f01cbb4 , 0x25a3a9a8 ] )
+  , Mont::unsafe_make( [ 0xe118d7c0 , 0x6aa412c8 , 0xe4b9d613 , 0x2a3dbf34 , 0x1d6c8ed9 , 0x7a84f261 , 0xfaca796f , 0x20516808 ] )
+  , Mont::unsafe_make( [ 0xc538a14e , 0x9c7b8833 , 0x9429b8b5 , 0x84a98467 , 0x087df4ea , 0x30cc6413 , 0x4b580b77 , 0x29144fa2 ] )
+  , Mont::unsafe_make( [ 0x0c0aa873 , 0x08b989c2 , 0xbc15d234 , 0xc7db7dcd , 0xc9adad68 , 0x3aba77b4 , 0x760d3a5a , 0x1cbf35b4 ] )
+  , Mont::unsafe_make( [ 0xdd0e9c7a , 0x50cf441c , 0x2bdf77a9 , 0xbaee6dc3 , 0x936b30dc , 0x0ccf8b74 , 0x14077fd2 , 0x155ce9d8 ] )
+  , Mont::unsafe_make( [ 0x10347fb5 , 0xe1927edc , 0x13074c96 , 0x15482219 , 0xc1cf5809 , 0x9ed2715e , 0x7597d2b8 , 0x2a968c59 ] )
+  , Mont::unsafe_make( [ 0xcbb1af36 , 0x783657ea , 0x0218341d , 0x79ee316f , 0x5ba466f1 , 0xcf168768 , 0x30c90c63 , 0x1d52e0e6 ] )
+  , Mont::unsafe_make( [ 0x7ed7f8de , 0x24d33919 , 0x1ee4a45d , 0x0b305ae3 , 0xa285676d , 0xe26f53f1 , 0xdb57a297 , 0x191ea399 ] )
+  , Mont::unsafe_make( [ 0xff9e4f1d , 0xf1fe47b4 , 0xa708f057 , 0x042443fc , 0x44820d98 , 0xca263621 , 0x0b9da342 , 0x1117dfea ] )
+  , Mont::unsafe_make( [ 0x5326decd , 0xad5a4534 , 0xb0c68695 , 0xb34d1fb1 , 0x670611d5 , 0x9e4ae2ce , 0x1aade6d1 , 0x2d50c8b9 ] )
+  , Mont::unsafe_make( [ 0xff67658d , 0x9229c5ff , 0x7d64cf7c , 0xa7cd285e , 0x84e0f885 , 0x2e9d2585 , 0xd71547f1 , 0x1c5ec1e7 ] )
+  , Mont::unsafe_make( [ 0xfca2240b , 0x26dc3f69 , 0xf4b2504e , 0x7275fade , 0x3cc71a9c , 0xd9b37615 , 0x6642b7f0 , 0x0f4c91b2 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
@@ -0,0 +1,1400 @@
+
+// circomlib's Poseidon constants for t=9
+
+use crate::bn254::montgomery::*;
+
+
+//------------------------------------------------------------------------------
+
+// round constants (t for external, 1 for internal rounds; flattened)
+pub const CONST_C: [Mont; 135] = 
+  [ Mont::unsafe_make( [ 0x1a8a426a , 0xccc8c777 , 0x5b09dc22 , 0x12881baf , 0xb36ae936 , 0x36bef279 , 0xcb848cf7 , 0x20877252 ] )
+  , Mont::unsafe_make( [ 0x51a81044 , 0xa0182a7b , 0x58a05be1 , 0x37c66948 , 0xaff90695 , 0x41be7c08 , 0xafd59fec , 0x0fa219c0 ] )
+  , Mont::unsafe_make( [ 0x75a42340 , 0x927e7495 , 0xbe654ad7 , 0xd1c22dc2 , 0xde072c28 , 0x5519b169 , 0x90ba9bcd , 0x2306c9e5 ] )
+  , Mont::unsafe_make( [ 0x3b06c3f8 , 0xa998508b , 0x70bcd329 , 0x8fec3d5e , 0x8c3fe155 , 0x699f55d9 , 0x924fd7f0 , 0x1c02d2ef ] )
+  , Mont::unsafe_make( [ 0xc8f2ebf9 , 0xe077214d , 0xc817dbb5 , 0x4bfd1e9d , 0x69f23465 , 0x76c893d9 , 0x92ae3bbe , 0x1da257e3 ] )
+  , Mont::unsafe_make( [ 0xabc06012 , 0x4bf06b6f , 0x702c3ff3 , 0x1998f04d , 0xdfcb0546 , 0x027cb244 , 0x3607cb25 , 0x1dbf7fb6 ] )
+  , Mont::unsafe_make( [ 0x567af487 , 0xe0f73842 , 0xecee0938 , 0x1dedfc94 , 0x7cfb5a7e , 0x7f5158f3 , 0x2472b46e , 0x21377cff ] )
+  , Mont::unsafe_make( [ 0x62780ac6 , 0xefa5098a , 0x07da787a , 0x6a3e3ae2 , 0x49ab4778 , 0xf24e455a , 0x548a52d0 , 0x112d4ae0 ] )
+  , Mont::unsafe_make( [ 0x0f918d4a , 0xf86dbecf , 0x53c74dce , 0x6e8e1c69 , 0x7b097536 , 0x3e1edf87 , 0xc65e8139 , 0x102f4ba5 ] )
+  , Mont::unsafe_make( [ 0x9b489d3a , 0x40eac5ae , 0x4a85aca5 , 0x9085c882 , 0xb1ec394c , 0xea446206 , 0x35c8815c , 0x1d0defa2 ] )
+  , Mont::unsafe_make( [ 0xc102f88e , 0xe4624d78 , 0xb0059a1d , 0x38f8075d , 0xcd54d1f2 , 0x0e91337a , 0x856adc58 , 0x1c883a85 ] )
+  , Mont::unsafe_make( [ 0x01e095e1 , 0xc3c55779 , 0xb7c0e1a4 , 0x25376cb4 , 0x8f774400 , 0xa133c068 , 0xea7b0533 , 0x2f7dd8f8 ] )
+  , Mont::unsafe_make( [ 0xbb4aef2d , 0xa49cffe0 , 0x52d7ceeb , 0x72fba4ef , 0x97200b3e , 0xe325b7fe , 0xeacddac9 , 0x0114f43d ] )
+  , Mont::unsafe_make( [ 0x023faa1d , 0x89a09927 , 0xffa0eb6d , 0x6ce6deb9 , 0x320f9d1d , 0xec8fff8f , 0x463e3e9c , 0x023a467b ] )
+  , Mont::unsafe_make( [ 0xe45c9315 , 0x8fa980c1 , 0xc0270378 , 0x215f7ab3 , 0x8c9338fb , 0x1c5e07cd , 0xebb1d711 , 0x0e741134 ] )
+  , Mont::unsafe_make( [ 0x0be3f81a , 0x098cb5c7 , 0xbfbe73fd , 0x0d333d7a , 0x509aef23 , 0x7f5531a0 , 0xef012b6b , 0x18ee0bfb ] )
+  , Mont::unsafe_make( [ 0xca2db2f5 , 0x8bfa136f , 0xa93f24d4 , 0x33ee0dad , 0x1091d071 , 0xf5ffb3e5 , 0x8630de36 , 0x2d35d268 ] )
+  , Mont::unsafe_make( [ 0x2c42ad3d , 0x78b8a464 , 0x198ed07b , 0x90afe7ff , 0xf79c0928 , 0x27108863 , 0xec4616a7 , 0x1bd529b7 ] )
+  , Mont::unsafe_make( [ 0xea7f8e32 , 0x75587e0c , 0xddd0d79f , 0x5c6a6f02 , 0x8c8db73d , 0xebd00941 , 0x078245a4 , 0x03619a30 ] )
+  , Mont::unsafe_make( [ 0x709dcca4 , 0x7ffe75fe , 0x4b3b6250 , 0x05863b3e , 0x5785f27a , 0x94da17fd , 0x71472fb7 , 0x2245dca1 ] )
+  , Mont::unsafe_make( [ 0x7574e105 , 0x4808890f , 0x19a9ff17 , 0x55c7c6aa , 0xa23e7f5e , 0x3c03e33d , 0xcb71111f , 0x12aa779d ] )
+  , Mont::unsafe_make( [ 0xa3ad9aee , 0x7decd606 , 0x1c3f6ffc , 0x0c79a82e , 0x2ea89564 , 0xf7dcd91e , 0x7d1eb78a , 0x023d087a ] )
+  , Mont::unsafe_make( [ 0x3b5b0f55 , 0x1e138ad2 , 0x99bf15af , 0xfc133869 , 0x37012483 , 0x650fd082 , 0x515fa3eb , 0x05779de5 ] )
+  , Mont::unsafe_make( [ 0xd64508f0 , 0xc0778d0b , 0xdeb32b17 , 0xfaaec01a , 0x3d3d802c , 0x9db0cc00 , 0xfa2ff600 , 0x182c05ca ] )
+  , Mont::unsafe_make( [ 0x49d125f9 , 0xfb856c4e , 0x5e32a1c2 , 0x6e38c5fb , 0x5680bd16 , 0x0f979a2b , 0xe69196a4 , 0x1465691a ] )
+  , Mont::unsafe_make( [ 0xe8144bbf , 0x6177eb80 , 0xf38df821 , 0x005750e8 , 0x5d5457a7 , 0x967446a1 , 0x73516c52 , 0x0e5ad68e ] )
+  , Mont::unsafe_make( [ 0x87d76807 , 0xa47fd9b0 , 0x0e2da047 , 0x488ec176 , 0x6cd78ebc , 0xa5680355 , 0xa470ec54 , 0x0ab3635f ] )
+  , Mont::unsafe_make( [ 0xc8b2adf3 , 0xe2d47610 , 0xa383e140 , 0x03986a92 , 0x66def002 , 0x1af69922 , 0xed5c907c , 0x141edae9 ] )
+  , Mont::unsafe_make( [ 0xbca83d20 , 0xd1f0570d , 0xcb983002 , 0xc0af234e , 0x70510179 , 0x987221af , 0xbafd1641 , 0x2ea17d1c ] )
+  , Mont::unsafe_make( [ 0xd2eed9d7 , 0x967e6408 , 0x2540f5fd , 0x6fe8c6e9 , 0x90a26999 , 0x72c6c333 , 0x1eb2e839 , 0x1be9940d ] )
+  , Mont::unsafe_make( [ 0xd6c2626d , 0x785640c2 , 0x197bea02 , 0xae162aec , 0xd6e7a9ec , 0x594a2df6 , 0x5ff86a6c , 0x26d22958 ] )
+  , Mont::unsafe_make( [ 0x7fa2df10 , 0x107eeb54 , 0xb84048df , 0xd2fc2b99 , 0xc5c3afda , 0xbb5dc120 , 0x2124c477 , 0x22617137 ] )
+  , Mont::unsafe_make( [ 0xb9af0cc3 , 0xe42fba77 , 0x37f1d4a1 , 0x5955ce1d , 0xd75bc45e , 0xf4e3cdf5 , 0x20f47bab , 0x2a6707db ] )
+  , Mont::unsafe_make( [ 0x803b7bd4 , 0x75a01379 , 0xf58740ea , 0xd9540102 , 0x3c976d08 , 0x957c8d07 , 0xd4bdfacf , 0x2eea0266 ] )
+  , Mont::unsafe_make( [ 0x63eb19ef , 0x9fca6ff0 , 0xba3e724d , 0xdd32444a , 0x7e78878d , 0x76c7c044 , 0xe9789663 , 0x053f2761 ] )
+  , Mont::unsafe_make( [ 0x68be8ece , 0x4f4f2feb , 0x6b64b6e5 , 0x84f8568d , 0x99d3d2d5 , 0x0d939423 , 0x03132ff6 , 0x0163918e ] )
+  , Mont::unsafe_make( [ 0xd43846d1 , 0xc88eebe2 , 0x57f019ee , 0xb39a2d12 , 0x91e2832d , 0x6e7dfae1 , 0xb04be30a , 0x2e8207c9 ] )
+  , Mont::unsafe_make( [ 0x525c6cae , 0x74e7101d , 0xc89e717e , 0xb25952dd , 0xca41aebb , 0x9cb9302c , 0x1389785e , 0x1b45310f ] )
+  , Mont::unsafe_make( [ 0x1e088e11 , 0x133914ce , 0x4923cedd , 0xa8204ff4 , 0xef34b91a , 0x0ed2f8b7 , 0x5fa04a73 , 0x1dc8d30d ] )
+  , Mont::unsafe_make( [ 0x64bd2ed6 , 0x2bb95dc6 , 0xd4891506 , 0x69222d8a , 0xf2ea4045 , 0x5601b74b , 0x5978ed9f , 0x200ab282 ] )
+  , Mont::unsafe_make( [ 0x5a1449b9 , 0xa1ea420f , 0x020646fe , 0x81b2a0f8 , 0x6ab7c7c2 , 0x45f18a09 , 0x62ae58e7 , 0x01252f51 ] )
+  , Mont::unsafe_make( [ 0x64c244ee , 0x36d8ac10 , 0xd89e07d8 , 0x2baa4d02 , 0xb9dacb69 , 0xf7bf9984 , 0xdf606178 , 0x06634e5a ] )
+  , Mont::unsafe_make( [ 0x8353efe7 , 0x20732dc8 , 0xb05cf18f , 0x63a6d6c9 , 0x6802580f , 0x602eb560 , 0x3483f8d8 , 0x1a3accb9 ] )
+  , Mont::unsafe_make( [ 0x5e37024a , 0xe6928319 , 0xacbd09fc , 0xdcc0ee34 , 0xbe9b3803 , 0x5e968b16 , 0xd507a585 , 0x06f1e08d ] )
+  , Mont::unsafe_make( [ 0x1fcaa5cc , 0x632f7a03 , 0x014d0f37 , 0xa2d56b16 , 0x17e32077 , 0x64d62ef5 , 0x37d20b7f , 0x0cd8d140 ] )
+  , Mont::unsafe_make( [ 0xf4d39f0f , 0x9f9ab046 , 0xbaa39a7e , 0xb69f372f , 0x144e6935 , 0xfccc773f , 0xad5ff034 , 0x0421957b ] )
+  , Mont::unsafe_make( [ 0x17217e5e , 0x7e1a92db , 0xbefa5bac , 0x0557a695 , 0x99a422e7 , 0x63c0a3b0 , 0x6c75924c , 0x10cafc81 ] )
+  , Mont::unsafe_make( [ 0xc8936011 , 0x58ebe3a1 , 0x00098a5f , 0x4a37c11c , 0x01f6a056 , 0x004d0d1f , 0x1779e6ac , 0x1ee9b154 ] )
+  , Mont::unsafe_make( [ 0xeef96d69 , 0xa50b517b , 0xa12bace9 , 0x7250006b , 0x50ee4ac7 , 0x0bc6550f , 0xfa9ab0c5 , 0x24736a53 ] )
+  , Mont::unsafe_make( [ 0xfa425e07 , 0x6476d175 , 0x6f33c30f , 0xcf12f98c , 0x71fe1729 , 0x2cc1f42d , 0x1b6c3fcc , 0x0735b786 ] )
+  , Mont::unsafe_make( [ 0x3de02cc8 , 0xe99cc12d , 0xf2cac611 , 0xbe9fa8a9 , 0x4f6a1ffa , 0x15a062e5 , 0x447b5fd2 , 0x1e412107 ] )
+  , Mont::unsafe_make( [ 0xce7145ea , 0x6e48a65c , 0xbf2d4435 , 0x95dfa2c9 , 0x25a24360 , 0x72c98d4e , 0x7f88463c , 0x199d92f1 ] )
+  , Mont::unsafe_make( [ 0xab9eb33e , 0xa546f702 , 0x589784e8 , 0x87153cec , 0x4c64e041 , 0xb289664c , 0x760af24a , 0x08598433 ] )
+  , Mont::unsafe_make( [ 0xc2081526 , 0x2bcf9a1a , 0x8985d14d , 0x6f75ae38 , 0x05b85068 , 0xb9e3eefa , 0x73ebd040 , 0x03b8b6a5 ] )
+  , Mont::unsafe_make( [ 0x51641045 , 0xc0827a91 , 0xfd693b9b , 0x32048d71 , 0x6ceab02e , 0x4b2e6a1f , 0x476c0ffa , 0x175186e7 ] )
+  , Mont::unsafe_make( [ 0xfcb0de22 , 0x29d09ddc , 0xe394f2e4 , 0x19472100 , 0xbe55528e , 0x1fe3d16c , 0xff75f857 , 0x07de8cab ] )
+  , Mont::unsafe_make( [ 0xf38f3152 , 0xa5184461 , 0x37022084 , 0x39466063 , 0x5aed2962 , 0x4c28db3b , 0x66810cbe , 0x1f846214 ] )
+  , Mont::unsafe_make( [ 0x2788ffe4 , 0x09b57ee6 , 0xe0b15860 , 0x572cfeaf , 0xaa3933d6 , 0xae952a43 , 0x3a006bd4 , 0x0d5b6c2c ] )
+  , Mont::unsafe_make( [ 0x0836e6d0 , 0x9294dbc0 , 0xd6073b6b , 0xa6434e70 , 0x0a9ecc48 , 0x4cd1450f , 0x14c0060b , 0x23c22649 ] )
+  , Mont::unsafe_make( [ 0x55527dc8 , 0x7bcb3e81 , 0x5793ae9f , 0xff4e45b5 , 0xd5f41518 , 0xd90324b2 , 0x86417368 , 0x0c04cea5 ] )
+  , Mont::unsafe_make( [ 0xabb4c53d , 0x89e5dfb3 , 0x5c67ab1e , 0xf8f35527 , 0x125756b3 , 0xa0d2af17 , 0x052f1814 , 0x17748dba ] )
+  , Mont::unsafe_make( [ 0x91f79278 , 0x25771e30 , 0x1adb1e40 , 0x76b4f5de , 0x1e2d5f0b , 0xd45e4f2e , 0xe56e84cc , 0x2e31a604 ] )
+  , Mont::unsafe_make( [ 0x6e5f30ab , 0x6b65b2ac , 0x5bc69688 , 0x6fc91d2f , 0x65a55b5d , 0x323d488e , 0x7f48aca6 , 0x152db12c ] )
+  , Mont::unsafe_make( [ 0x63e096be , 0x2ec2ccde , 0x0648378d , 0xae47d438 , 0xe9b6bc65 , 0x7183191a , 0x104cf57d , 0x050f7f2d ] )
+  , Mont::unsafe_make( [ 0x87c79905 , 0xf86dcee3 , 0x36838001 , 0x4f69ec38 , 0x2c0e3ffa , 0xea5146ac , 0x2f1dd131 , 0x2bec76eb ] )
+  , Mont::unsafe_make( [ 0x06f65605 , 0x4cfc4fd1 , 0x18013ac4 , 0x8e22ecc3 , 0x3d7f7a56 , 0x74926505 , 0x7e056e09 , 0x230b4643 ] )
+  , Mont::unsafe_make( [ 0x92bef429 , 0x9edca2df , 0x373295a4 , 0x920ddeff , 0x430c4404 , 0x1422b57d , 0xdaf242df , 0x22836dbf ] )
+  , Mont::unsafe_make( [ 0x3155675b , 0xd6dd3f6c , 0x2b39ce92 , 0xd53b92ef , 0x05ebbd43 , 0x361bf1ea , 0xe55cd58c , 0x01c63000 ] )
+  , Mont::unsafe_make( [ 0xd8e03a60 , 0x55b2bb68 , 0xaeb50590 , 0x94bfced3 , 0x1d2a624a , 0x0a796ca5 , 0x84d00482 , 0x2127e3e3 ] )
+  , Mont::unsafe_make( [ 0x6538de90 , 0xf4f7b862 , 0x48752486 , 0x31a348c5 , 0x5d0f93ba , 0x0e647557 , 0xbc7e4785 , 0x098570da ] )
+  , Mont::unsafe_make( [ 0x8ae9baf6 , 0x002a17e4 , 0x9e5666ee , 0x2b80fffb , 0x750d291a , 0x34ce446f , 0x0805f70e , 0x26d8d7d1 ] )
+  , Mont::unsafe_make( [ 0xb0d3acd1 , 0xf5b949e5 , 0x4038ab22 , 0x560cf98b , 0x0e7d3934 , 0x76047246 , 0x818bf339 , 0x2133be3a ] )
+  , Mont::unsafe_make( [ 0xf51748fe , 0xbd903ad8 , 0x1e7facdf , 0xa2e3734c , 0xc7f4366f , 0x5c656c26 , 0x77809aca , 0x08debf7e ] )
+  , Mont::unsafe_make( [ 0x7658ea0e , 0x024e456a , 0x027816ef , 0x78af8282 , 0x009fce88 , 0x5442c5f3 , 0x80d3e12a , 0x2184349c ] )
+  , Mont::unsafe_make( [ 0xbc2dd100 , 0x6291ea0b , 0xa5c2ea30 , 0x621d8535 , 0x9f8ad3c0 , 0x82a65b5a , 0xead60bc0 , 0x21983bb7 ] )
+  , Mont::unsafe_make( [ 0xba1e6dec , 0x7b821f4b , 0x545aa985 , 0x409f007c , 0x9dd035e8 , 0xf10413fd , 0x6cef962e , 0x132068e4 ] )
+  , Mont::unsafe_make( [ 0xd9952c79 , 0xc2d682ea , 0x4bde8097 , 0x85cd35b1 , 0x77d6c69a , 0x899d4e84 , 0x908fa88e , 0x1d6d8cba ] )
+  , Mont::unsafe_make( [ 0x73c9c8ab , 0x4e6279a6 , 0xe24c0d2f , 0x9c2071d8 , 0xa1b28718 , 0x8c01ddfc , 0x4b55e193 , 0x2b1dab88 ] )
+  , Mont::unsafe_make( [ 0xc49a2cdd , 0x37e19945 , 0xcc6f75af , 0x24b53c8e , 0x2cca35ad , 0x647869e4 , 0x3822360f , 0x2bd5f649 ] )
+  , Mont::unsafe_make( [ 0xf7197aef , 0xb1a516e3 , 0x211849a0 , 0x30ef569b , 0x7c56571b , 0x1f330e4c , 0x291a64ff , 0x212740c5 ] )
+  , Mont::unsafe_make( [ 0x0ef539ab , 0xfbfa110b , 0xa6abfedd , 0x48f6dc95 , 0xfdb906e8 , 0x47b9275d , 0x07365407 , 0x14605006 ] )
+  , Mont::unsafe_make( [ 0x65ad7ff0 , 0xaf26b84f , 0x1f0cf15b , 0xc226254c , 0x85e3acd3 , 0xa16a8fd4 , 0xc99864c6 , 0x18819d59 ] )
+  , Mont::unsafe_make( [ 0x8d9768fe , 0xc47492ce , 0x1f16b251 , 0x6945d9e7 , 0x23bb18ba , 0xa9ca1bd4 , 0x60b50814 , 0x2c11833b ] )
+  , Mont::unsafe_make( [ 0x3de00b4d , 0xc603048d , 0x5f9c7e6d , 0x7e419bd6 , 0xc8b937ab , 0x0bb61f13 , 0x8738833b , 0x2647abda ] )
+  , Mont::unsafe_make( [ 0x7bb46069 , 0x36ef5f68 , 0x28bfddc7 , 0x29c23809 , 0x209b53e4 , 0x3f8d12f1 , 0xd4eaa782 , 0x2a0d2c95 ] )
+  , Mont::unsafe_make( [ 0xeb7776da , 0x6aaa7656 , 0xc3270dc4 , 0x92916296 , 0x534f23bf , 0xa9f8a54b , 0xba2b3f3e , 0x05ced87f ] )
+  , Mont::unsafe_make( [ 0x21f89988 , 0x91929fe4 , 0xdf395489 , 0x3fadb4e1 , 0xccfea643 , 0x1784a27e , 0xfb09a41c , 0x084a9b5c ] )
+  , Mont::unsafe_make( [ 0x7ca21721 , 0x05854b96 , 0x010455f3 , 0x9a441b41 , 0x44d9ff65 , 0x8b3dcbf2 , 0x56c5af15 , 0x07016f4c ] )
+  , Mont::unsafe_make( [ 0xc0a2e228 , 0x61453bd3 , 0x6dfafd06 , 0xb0b0a9d3 , 0x56cefb9f , 0xecb42e82 , 0x93c3add2 , 0x0a5440b8 ] )
+  , Mont::unsafe_make( [ 0xe6d20cac , 0xd7255c82 , 0x96a33967 , 0xdab57d49 , 0x72eaecde , 0xd6d7350d , 0x082abbc8 , 0x2a7bdb2d ] )
+  , Mont::unsafe_make( [ 0x2661945c , 0x9a4991bc , 0x17ba023a , 0x66eec88f , 0x3021c9f0 , 0x2f1994f5 , 0xcbdfcd53 , 0x01395d04 ] )
+  , Mont::unsafe_make( [ 0xf38171df , 0xb042e8e0 , 0xdd7c198c , 0xa5c3dedf , 0xc676cf49 , 0xcc7eecc2 , 0x1ec578df , 0x26db7da7 ] )
+  , Mont::unsafe_make( [ 0x5c73de12 , 0x6d847c50 , 0xd8fd4c5b , 0x76d42775 , 0x2ea58741 , 0x5153d715 , 0x1e0cb9cb , 0x2b23fec6 ] )
+  , Mont::unsafe_make( [ 0xb1e3aca3 , 0xdaab28db , 0xc408bf38 , 0xb42acea9 , 0x7285f556 , 0xc1f5394c , 0x628709c1 , 0x0cf03143 ] )
+  , Mont::unsafe_make( [ 0xa21ba501 , 0xecdaae51 , 0xbc216e32 , 0xd8eea0d1 , 0x49eb294e , 0x878e6ace , 0x554bb42e , 0x2bbe1c7c ] )
+  , Mont::unsafe_make( [ 0xf6a8c919 , 0xc768e11a , 0x71ffeaf0 , 0x96965dea , 0xcab3ab7e , 0x0baa39ea , 0x164e212e , 0x2b7ef222 ] )
+  , Mont::unsafe_make( [ 0x6ee3d127 , 0x3cedf8fa , 0xe4ffdd63 , 0x5d26a9f0 , 0xa2329404 , 0x11798c11 , 0x391b479c , 0x181594f6 ] )
+  , Mont::unsafe_make( [ 0x02cfc648 , 0xd83cfcaa , 0x3815a568 , 0xf8aaae9e , 0xe97803be , 0x13d5ca05 , 0x97d51241 , 0x155aaeda ] )
+  , Mont::unsafe_make( [ 0x3384eb6a , 0x1683fa4c , 0x2affee5c , 0x07ae6d53 , 0x97f85da9 , 0x993385c6 , 0x9d03203d , 0x1a4bb464 ] )
+  , Mont::unsafe_make( [ 0x3471de57 , 0x4a64358b , 0x51b8b7c2 , 0x44e66253 , 0x1f158d94 , 0x2961c168 , 0x8f67bef1 , 0x0702fb82 ] )
+  , Mont::unsafe_make( [ 0x03a3ef54 , 0x01ed7660 , 0xe750bf0a , 0xc753a805 , 0xcf705aac , 0xc8ca6200 , 0x3f8516b1 , 0x1e3949ad ] )
+  , Mont::unsafe_make( [ 0x38be4cb2 , 0x25ebc933 , 0x6086d7b8 , 0x2fe497cf , 0x5c5a41d6 , 0x5032192b , 0xc6921e77 , 0x004a6766 ] )
+  , Mont::unsafe_make( [ 0xf4efa722 , 0x35dc80d4 , 0x88d5a43c , 0x7eafbe3b , 0x1aa701bd , 0x1d74553d , 0x2a7eeb07 , 0x19c732c1 ] )
+  , Mont::unsafe_make( [ 0xd1c3e28a , 0x22974651 , 0xd5b015aa , 0x13bcd54b , 0x4329244c , 0x048297d5 , 0x3bb81bf9 , 0x040738c2 ] )
+  , Mont::unsafe_make( [ 0x7fdef160 , 0x5382e782 , 0x186980c7 , 0x21108afe , 0xeb735336 , 0x2071b901 , 0xb7a9925d , 0x09701175 ] )
+  , Mont::unsafe_make( [ 0x6390fa40 , 0xc95c00bf , 0x0d30c097 , 0xd766bcde , 0xd4270974 , 0xc0d66e39 , 0xe4d454b4 , 0x2590e010 ] )
+  , Mont::unsafe_make( [ 0x700c8778 , 0x711cb631 , 0x7051f222 , 0x5f7244d7 , 0x58ef41e5 , 0xcc8f87dd , 0xbfccbf6f , 0x24ea9e0d ] )
+  , Mont::unsafe_make( [ 0xe551caa3 , 0xaff3a7a1 , 0x4db3198f , 0x9fb7b871 , 0xd5c0b7e1 , 0x30f9c8d5 , 0x637196ec , 0x18a63e43 ] )
+  , Mont::unsafe_make( [ 0x5d550487 , 0x742992bb , 0x63a2170b , 0xc8e1fdb1 , 0xf21f911f , 0xa5f43aad , 0x64d474f4 , 0x2f872b6e ] )
+  , Mont::unsafe_make( [ 0x000f3e8c , 0x57c7a097 , 0xf8636aa8 , 0xc9b68c7e , 0x115df632 , 0xa60db086 , 0x1350c3ac , 0x2fe6b1a8 ] )
+  , Mont::unsafe_make( [ 0x74170ba7 , 0x1743e9d5 , 0xf01924c6 , 0xe89ac36b , 0x6f1dfac2 , 0xcdfa4bbf , 0x9c842a61 , 0x20ee4746 ] )
+  , Mont::unsafe_make( [ 0x6fcb34ff , 0x241eb874 , 0xf18ce352 , 0xdc313a48 , 0x0aa2a348 , 0x913970c3 , 0xca9c4be8 , 0x2bdc189e ] )
+  , Mont::unsafe_make( [ 0x3ee077d6 , 0x614139d0 , 0x3a88360a , 0xe553d9ef , 0xee1be4d9 , 0xf97ecc6b , 0x13a20071 , 0x24edd1f0 ] )
+  , Mont::unsafe_make( [ 0xf8334c86 , 0x32aac896 , 0x045c0dff , 0x66b4fcfd , 0x97473870 , 0xbaefd1cb , 0x74f77aaa , 0x00c2631e ] )
+  , Mont::unsafe_make( [ 0xfcf0e965 , 0x3fad8765 , 0xed654d83 , 0x5cccf30a , 0x9964958d , 0x13e7dcff , 0xf90c426d , 0x0067416f ] )
+  , Mont::unsafe_make( [ 0xa4648d9b , 0xc28dd5de , 0xd9a3a89d , 0x04d8f7f7 , 0x084e7f13 , 0xba96f485 , 0x26fa6ed2 , 0x07b543db ] )
+  , Mont::unsafe_make( [ 0x96fd9ae4 , 0x670df2bc , 0xfb98606b , 0xd1f0bded , 0x1ff45bc5 , 0x5596d08e , 0x42f8dbae , 0x164fa6ee ] )
+  , Mont::unsafe_make( [ 0xaf04907a , 0x1c7d1f69 , 0xaec1b98f , 0x29856408 , 0x5f7649d0 , 0xacd29d42 , 0x52662d93 , 0x2774bbcf ] )
+  , Mont::unsafe_make( [ 0x142f4ab5 , 0x52530c32 , 0xd6c088ca , 0x818f3718 , 0x8a5a0d7c , 0x1c5db4f8 , 0x469fb2af , 0x0e57f956 ] )
+  , Mont::unsafe_make( [ 0x2e68b884 , 0x82b6df60 , 0xd8b11177 , 0x98c90b57 , 0xa8a556f6 , 0x2ba9c116 , 0x5af5ce8c , 0x20728c96 ] )
+  , Mont::unsafe_make( [ 0x964e64ed , 0xe33934c3 , 0x7f2e24d0 , 0x563fdf64 , 0x1014aa34 , 0xef3ffe9c , 0x79517047 , 0x226fc3c9 ] )
+  , Mont::unsafe_make( [ 0x2a799e4b , 0x79fb327c , 0x68e19981 , 0x7290ab1b , 0xf743907c , 0x005e1a7b , 0xfe979815 , 0x2e1f6fd2 ] )
+  , Mont::unsafe_make( [ 0x7746647e , 0x18c2c05f , 0xd7507790 , 0xeccdb042 , 0x8582cf86 , 0x3e95d45f , 0x00da053e , 0x0ddc8a6a ] )
+  , Mont::unsafe_make( [ 0x62b9cf6d , 0x84399aba , 0x75779b93 , 0xab14dce9 , 0x1e901aaf , 0xb7705039 , 0x08086d4a , 0x186748b8 ] )
+  , Mont::unsafe_make( [ 0xc4171459 , 0xffb1a711 , 0xf77d3b06 , 0x96ba31e0 , 0xf6963b46 , 0x8cc31e9b , 0x9cd93779 , 0x17e2f217 ] )
+  , Mont::unsafe_make( [ 0xbc67a4ac , 0x38bb68ea , 0x80f62c15 , 0x4cdc9d2e , 0x8d41f3cc , 0x8c23ba2b , 0xe49fd084 , 0x302e8945 ] )
+  , Mont::unsafe_make( [ 0x46565ab4 , 0xaa703399 , 0xdfd58635 , 0xdcda51bb , 0xc487e99e , 0xb580458c , 0xbe03ed1b , 0x0665c434 ] )
+  , Mont::unsafe_make( [ 0x8f514eb3 , 0xf3b9965f , 0xcd776c18 , 0xa3a49226 , 0xfc607ec2 , 0x957b6490 , 0x52f52d13 , 0x0162dc54 ] )
+  , Mont::unsafe_make( [ 0x68b4de69 , 0x07a1c416 , 0x9d69c3ea , 0x6a2688f1 , 0x96207ba1 , 0xbeb9dc69 , 0xe230fa04 , 0x2e88cc37 ] )
+  , Mont::unsafe_make( [ 0x73d4d488 , 0xccd6ae3c , 0x8e5a03c9 , 0x465e2448 , 0x2c80e287 , 0x2274ca1b , 0xefc09223 , 0x0e982a9c ] )
+  , Mont::unsafe_make( [ 0xc2f74aaf , 0xa3dd4e95 , 0x513fafff , 0xebf39b92 , 0x345462f6 , 0xdbce9358 , 0xf048c810 , 0x2252ff21 ] )
+  , Mont::unsafe_make( [ 0xe0ee285e , 0xc2780478 , 0x3fa7eba8 , 0x6bf1ee04 , 0x3c1a7d45 , 0xbb667d45 , 0xe409de82 , 0x1a9b4674 ] )
+  , Mont::unsafe_make( [ 0x643f8f8a , 0x34ac8405 , 0x657cb1f3 , 0xf7892306 , 0xd035c947 , 0xbb4e2dc8 , 0xeb9c59ea , 0x023b9a33 ] )
+  , Mont::unsafe_make( [ 0x0d7bf7bc , 0xbc3784fd , 0x1bb98c1b , 0xbecb62dd , 0x3f56f2f9 , 0xfdd6281c , 0x2185d9ea , 0x26f44893 ] )
+  , Mont::unsafe_make( [ 0xc3b81f5f , 0x3be9ba0d , 0x972eb26c , 0x85395606 , 0x8ebf619d , 0x9f54fee9 , 0x88f1508d , 0x0b58140c ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// external mixing matrix (`t x t`, flattened)
+pub const CONST_M: [Mont; 81] = 
+  [ Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x7243f6d8 , 0x7e7083a0 , 0x0c94b77a , 0xb8c7eee4 , 0x655726ae , 0x280253be , 0xeaf92fef , 0x26f1b7e9 ] )
+  , Mont::unsafe_make( [ 0xf40cc989 , 0x338ffacd , 0x2bc1c676 , 0x46745de7 , 0x71319719 , 0xc99780aa , 0x2fd62565 , 0x1da55b3b ] )
+  , Mont::unsafe_make( [ 0x0592adb9 , 0x9bcf846b , 0xabe8d4e8 , 0x6bde3de2 , 0xc2aeb1d7 , 0x0bb7a94b , 0xd14d99df , 0x1a266fb9 ] )
+  , Mont::unsafe_make( [ 0xe1d43ff7 , 0x1c30902b , 0x98a63431 , 0xab6cddb6 , 0x75e962e3 , 0x026924a4 , 0x67438b82 , 0x1ee583c3 ] )
+  , Mont::unsafe_make( [ 0x4eb0a9bd , 0x5ed0e7f5 , 0x30572d9e , 0xb45e664a , 0x712057ac , 0x8bcadb4e , 0xd83d85a7 , 0x164cffae ] )
+  , Mont::unsafe_make( [ 0xf1b24ecd , 0x2076110f , 0x0370350a , 0xd9e44f5c , 0x5038a1ad , 0xf3085fad , 0xc04c235e , 0x12fa7587 ] )
+  , Mont::unsafe_make( [ 0xaccb8db7 , 0xe703896a , 0x17cae125 , 0x8ef1887f , 0xd1cbcd94 , 0xe01f8961 , 0x0deee04c , 0x1f6f9235 ] )
+  , Mont::unsafe_make( [ 0x07114704 , 0x64da9b53 , 0x96d7dd9f , 0x173b5f37 , 0x1be86711 , 0x7185dca4 , 0xcea1fa56 , 0x15174305 ] )
+  , Mont::unsafe_make( [ 0xc0a0cbda , 0xfb5f006c , 0x6cbf22af , 0xe6fcd1a9 , 0xc4e06bad , 0xc5cbd466 , 0x6b702d1d , 0x036de2ff ] )
+  , Mont::unsafe_make( [ 0xa5074180 , 0xbd95d9fb , 0x2ef79a62 , 0x28fb5483 , 0xf931a19e , 0x191a5f19 , 0xe28559db , 0x1bb7d169 ] )
+  , Mont::unsafe_make( [ 0x3f8d5a22 , 0x7329fcdb , 0x90686b6d , 0xd846888d , 0x59f4c50d , 0x1381c9bd , 0xd93dc624 , 0x0b9d589b ] )
+  , Mont::unsafe_make( [ 0x1819634f , 0x1671e58f , 0x096c1b6f , 0x77a96a64 , 0xceed93cb , 0x67f592a7 , 0x3a2d551f , 0x19b7d50c ] )
+  , Mont::unsafe_make( [ 0xb9180bdf , 0xcc0c1769 , 0x159d374b , 0x864adc08 , 0x30b12e29 , 0xa445023c , 0xb4bcf120 , 0x031535e1 ] )
+  , Mont::unsafe_make( [ 0x801cadd4 , 0xe2d54016 , 0x6ac533c4 , 0xafa367c6 , 0xcdef717f , 0x13b4c599 , 0x449d013f , 0x0745ad22 ] )
+  , Mont::unsafe_make( [ 0x4f76f0f8 , 0xa4efed09 , 0xdf017235 , 0xbb5e9757 , 0xa44f739e , 0xc1301607 , 0x4ad67b8e , 0x0a1db287 ] )
+  , Mont::unsafe_make( [ 0xb4815ef3 , 0xbe29dff8 , 0x0b1005d6 , 0x92516f81 , 0x7a9629fa , 0xf3ec75a9 , 0xd3cda839 , 0x24365fb5 ] )
+  , Mont::unsafe_make( [ 0x0f3709d3 , 0x63e242ce , 0x6ad701ee , 0x8bbe234f , 0xb6650a12 , 0x79cc8f27 , 0x2efd18a6 , 0x1fac862e ] )
+  , Mont::unsafe_make( [ 0x66f69a98 , 0xab8f9a2c , 0x2bcf4b4e , 0x4fe4cbcc , 0x6056998f , 0xc878f3b7 , 0x301557c0 , 0x2d9ba2cd ] )
+  , Mont::unsafe_make( [ 0xb2e761b7 , 0xdae8ad17 , 0xe32b1f18 , 0xa394a961 , 0xd4d18c97 , 0x92586aaf , 0x96a2a1cb , 0x2f90a086 ] )
+  , Mont::unsafe_make( [ 0x80d72aea , 0xadd6713e , 0xa274662f , 0x625fd736 , 0xd44f8783 , 0xc09d9143 , 0x243298c5 , 0x09a58732 ] )
+  , Mont::unsafe_make( [ 0x2b063330 , 0xf87deb21 , 0x1e8d3075 , 0x2dd08a4f , 0x3dbe027e , 0x270381b9 , 0xde922d42 , 0x285fecf0 ] )
+  , Mont::unsafe_make( [ 0xf98f8efc , 0xa8d65547 , 0xfd7f326b , 0x34d76540 , 0x16753b24 , 0x451b134b , 0xccf5827e , 0x172368e5 ] )
+  , Mont::unsafe_make( [ 0xd0f98734 , 0x53f90cc4 , 0xcafb3a7d , 0x76190219 , 0xcf1d405d , 0xa3775d53 , 0x076c3a51 , 0x1afc7f36 ] )
+  , Mont::unsafe_make( [ 0xf40bb764 , 0x109ad0ee , 0x9fb7d2e5 , 0xf5cfafa9 , 0x73f67c23 , 0xba65aabb , 0x1f13756a , 0x06536557 ] )
+  , Mont::unsafe_make( [ 0xdf9f6b3e , 0x6c5b97e0 , 0x972a9b8c , 0x4a0fb2b0 , 0xf978f3f8 , 0xc9174c67 , 0xeeaa8dff , 0x1e6ec4bf ] )
+  , Mont::unsafe_make( [ 0x22b32873 , 0x342c4e65 , 0x7cd28156 , 0x963e6b74 , 0x0af2acdf , 0xe21d9ea8 , 0x7d6837cc , 0x11dd360f ] )
+  , Mont::unsafe_make( [ 0xf498c374 , 0x08b0eec3 , 0xef7936d4 , 0x72d5d765 , 0xdddc5d90 , 0xdebc3a6f , 0x605f269b , 0x2fcfd53a ] )
+  , Mont::unsafe_make( [ 0x6785c327 , 0xcd51ca4f , 0x1b993673 , 0x36a671bb , 0xfd0f0e0c , 0xf70a07d2 , 0x0eb394dd , 0x09b8f729 ] )
+  , Mont::unsafe_make( [ 0xe386c0c2 , 0x16f3ae99 , 0x5c82caa5 , 0xe2372fb8 , 0x0db48fd1 , 0x80ba13f8 , 0x9f93fe11 , 0x2f736954 ] )
+  , Mont::unsafe_make( [ 0x028eddec , 0x3fe8638b , 0xf78c06b7 , 0x2e404431 , 0xf8420392 , 0xb3f76028 , 0x186129df , 0x1d177bdf ] )
+  , Mont::unsafe_make( [ 0xadc0cdb3 , 0x4ce5c52c , 0x29fa46de , 0xce9e389c , 0x58355f68 , 0x5da460ed , 0x430e0a5a , 0x0d6af2a7 ] )
+  , Mont::unsafe_make( [ 0xe51f0b8f , 0x578dad2b , 0x777afa41 , 0x6c7311a8 , 0xf6a30946 , 0x709ef23a , 0xc062c5d7 , 0x0f156af7 ] )
+  , Mont::unsafe_make( [ 0xfdb9817a , 0x015ab9ab , 0x72526793 , 0x500d41cc , 0x7be70013 , 0x03df46a3 , 0x69ff6b9f , 0x29b21620 ] )
+  , Mont::unsafe_make( [ 0xd17f7d39 , 0x414fbd9a , 0x51eb84d7 , 0xf01b0f4f , 0x3013434a , 0x98b9bf88 , 0x88a57d24 , 0x2127d6cc ] )
+  , Mont::unsafe_make( [ 0xe292e2e6 , 0xda2fb975 , 0x6ce6ac3b , 0xa191b9af , 0xc6d464d1 , 0xe1b92926 , 0x34b6ffe2 , 0x24a7b2c1 ] )
+  , Mont::unsafe_make( [ 0xd778781f , 0xd7cff19c , 0xe4b41950 , 0x30d61fd6 , 0xbdcf6384 , 0x0f95c9c8 , 0x7ac46432 , 0x19ccdff2 ] )
+  , Mont::unsafe_make( [ 0x02048f42 , 0x525c91da , 0x863d98c7 , 0xf3976db0 , 0xace08680 , 0xdcd4e76f , 0xf9c19ffd , 0x04733fab ] )
+  , Mont::unsafe_make( [ 0xf08c2e58 , 0x44cc9863 , 0x33e2bbe1 , 0x60aabea8 , 0xea92a11c , 0x86e0f589 , 0x82e8252b , 0x20e59646 ] )
+  , Mont::unsafe_make( [ 0xdd57779e , 0x04b997d0 , 0xf0c5c9c3 , 0xe2e49afa , 0x0200280e , 0x802a5b60 , 0xc242c5b8 , 0x0b77e25d ] )
+  , Mont::unsafe_make( [ 0xb5a8fb7a , 0x1852248f , 0xb65d2085 , 0xd38084ed , 0x7d7655af , 0x2aa2e672 , 0xb2269eb9 , 0x2fa5e6d9 ] )
+  , Mont::unsafe_make( [ 0x3cba8e09 , 0xa7882111 , 0x1e1b3ab2 , 0xc4764005 , 0x0b708370 , 0x6da6f510 , 0xcaeb5e07 , 0x13e87538 ] )
+  , Mont::unsafe_make( [ 0xaac92938 , 0x4d030b57 , 0x22c61eac , 0xc6e174cc , 0x1fda687f , 0xd221f284 , 0xa361f599 , 0x035c4588 ] )
+  , Mont::unsafe_make( [ 0x4fc2a65d , 0x51a972ce , 0x6f1417c6 , 0xd1d08b80 , 0xac65d5d6 , 0xdc6ef2f1 , 0xe36b8232 , 0x22ea5c1d ] )
+  , Mont::unsafe_make( [ 0x13552b25 , 0x6951d4d9 , 0x4e7bf542 , 0x4e8fa43e , 0x3e76c350 , 0x479cc47c , 0x8ac0aca9 , 0x02ca7f3d ] )
+  , Mont::unsafe_make( [ 0x755c7dbb , 0xf4ddc6bd , 0x406ece46 , 0x31ca9a3f , 0x7452f1fa , 0xa9c95d71 , 0xa2e109aa , 0x0fd0b2f9 ] )
+  , Mont::unsafe_make( [ 0xc86729d9 , 0x24cd4443 , 0x67e92ec2 , 0x51903066 , 0x5192a6da , 0x3e8db494 , 0xb4aab49f , 0x10d8eee9 ] )
+  , Mont::unsafe_make( [ 0x79d0959b , 0xb13ae540 , 0xcb5f45fa , 0x19f163ab , 0xe6757efa , 0x8cda66b8 , 0x09553525 , 0x27e1ad8c ] )
+  , Mont::unsafe_make( [ 0xe4f3e294 , 0xd44dc1c6 , 0xb17b70e8 , 0x0b4cee0c , 0x219dc2b4 , 0xac0caf46 , 0xab91314c , 0x135f788c ] )
+  , Mont::unsafe_make( [ 0xe802eb62 , 0xfe0dc7ee , 0x6dd63a2b , 0xa788f16e , 0x31a75c0a , 0xfd1dfeb3 , 0x1e0535e1 , 0x29559070 ] )
+  , Mont::unsafe_make( [ 0xbbc6e8ed , 0xd5f027bb , 0x730a2e3b , 0x79327333 , 0x407536b5 , 0xce4df9a8 , 0xa4444dc3 , 0x1fa697cc ] )
+  , Mont::unsafe_make( [ 0xd376983a , 0x85055e18 , 0xe72223b2 , 0xc19f6056 , 0x8b265a79 , 0xa4faffe4 , 0x11cd7cf3 , 0x2b8593fd ] )
+  , Mont::unsafe_make( [ 0xba2f07fd , 0x5b084f9e , 0x3509d94d , 0x0cea4161 , 0xed1cf6d2 , 0xc62df6dc , 0xcd4cbcb8 , 0x005826dc ] )
+  , Mont::unsafe_make( [ 0x4994a391 , 0x77c43fd6 , 0x691ddf77 , 0x3d54347b , 0xd714cdf8 , 0x4f9ffc41 , 0xa67716c6 , 0x0848a0ef ] )
+  , Mont::unsafe_make( [ 0x47576e24 , 0x3cdd1730 , 0x52b55dbb , 0xc3a2fc5a , 0x630bdb14 , 0x0e184adb , 0x1a20f923 , 0x1c6eb118 ] )
+  , Mont::unsafe_make( [ 0xbf377d06 , 0xbb409478 , 0x4234f4b0 , 0x4b775f21 , 0xcf5e54e8 , 0x9d7e960c , 0xa309db58 , 0x03e8ba93 ] )
+  , Mont::unsafe_make( [ 0x6cde909a , 0x8ad3905c , 0x6b320136 , 0xcba7a7f7 , 0xd2455b86 , 0xd06f46c6 , 0xe3c3860f , 0x03955349 ] )
+  , Mont::unsafe_make( [ 0xcdb2c2b8 , 0x63cd9586 , 0xf77a98c1 , 0xa0bde09c , 0x263e2808 , 0x565e370e , 0xbd8d4f38 , 0x2cad86cb ] )
+  , Mont::unsafe_make( [ 0xd2671e28 , 0x5fd9e557 , 0x8e96b811 , 0xb8df08a1 , 0x6aced682 , 0xa2dbe3db , 0xf82d824b , 0x2afe5d9f ] )
+  , Mont::unsafe_make( [ 0xdb823107 , 0xaf49e196 , 0x25befcaf , 0xac786e74 , 0x92f5e6dd , 0xb2db5980 , 0x37b5daf2 , 0x281412d4 ] )
+  , Mont::unsafe_make( [ 0x80782d90 , 0x4a59d041 , 0x1da520fa , 0x0efb8997 , 0x9e2e0471 , 0x1de6efcc , 0xd22cebec , 0x12e3b71a ] )
+  , Mont::unsafe_make( [ 0x9c176bc5 , 0x8455fd31 , 0xc52d5229 , 0x6b53f8e3 , 0x826c7d4e , 0x4188be59 , 0x7d0c36ab , 0x1b7901cd ] )
+  , Mont::unsafe_make( [ 0xd6913ddb , 0x6030767a , 0x40ba9ec7 , 0xc025a1a1 , 0x39a3fa12 , 0x0be13da1 , 0x2a30f654 , 0x0d91ad95 ] )
+  , Mont::unsafe_make( [ 0xe96142a2 , 0xc82c6921 , 0x2e1d5e3b , 0xdf385385 , 0x9a78ea0b , 0xdce16950 , 0xfc1e50dd , 0x20f68c53 ] )
+  , Mont::unsafe_make( [ 0xc2719e4c , 0xcc0d3e2a , 0xc8448fcc , 0x5a4870ce , 0xad194c85 , 0x71012050 , 0x474c7d6b , 0x2347ae94 ] )
+  , Mont::unsafe_make( [ 0x214b8510 , 0xa737e615 , 0xe2f1d678 , 0x795be6ac , 0xa55251c2 , 0x67444e2c , 0xd92cb857 , 0x0f5fe37e ] )
+  , Mont::unsafe_make( [ 0x748e7c4c , 0x561961b0 , 0x4631429e , 0x44ee2fb3 , 0x09480e28 , 0x6db3ad4b , 0xe326a017 , 0x0b084658 ] )
+  , Mont::unsafe_make( [ 0xcb024276 , 0xa8b3afe3 , 0x5fe41132 , 0xb1220a8f , 0xb48d20d3 , 0x3c77e9cb , 0x7cd96859 , 0x13b789bd ] )
+  , Mont::unsafe_make( [ 0x6decf524 , 0x3dea1b11 , 0x365dc95e , 0x3e928a9d , 0x4848d18b , 0xa845c946 , 0x8ca9873b , 0x177e4ccb ] )
+  , Mont::unsafe_make( [ 0x90d93060 , 0x3d95e8c4 , 0x0b5ee1ec , 0x5af497b1 , 0xc82a2eed , 0xc449a713 , 0x4fcd8a37 , 0x1a818ef4 ] )
+  , Mont::unsafe_make( [ 0xc4db3897 , 0x73ee3228 , 0x04d82e5c , 0xf5a8ea1e , 0xa5208021 , 0x5fd16967 , 0x441c2bd2 , 0x22d8cece ] )
+  , Mont::unsafe_make( [ 0xb55b0ad3 , 0x130bc23b , 0x016c072b , 0x2d0af687 , 0x06929722 , 0xdeb1a4f0 , 0xd887eb0f , 0x02fe8d1a ] )
+  , Mont::unsafe_make( [ 0xa1669582 , 0xeba36e2f , 0x9c468c5c , 0x13eac76f , 0x64ab1724 , 0xea3e128c , 0x14780a51 , 0x0f3c3267 ] )
+  , Mont::unsafe_make( [ 0xa6089b15 , 0x75f9f678 , 0xdb44b8e5 , 0xa1c0aa0e , 0x3c6d38c3 , 0xba511ec8 , 0x35b7d8ee , 0x0ae5c61f ] )
+  , Mont::unsafe_make( [ 0xf21e9796 , 0x7546fdd3 , 0xeb962160 , 0xe5989d3f , 0xd20bc802 , 0x27487e4d , 0xa1f0668a , 0x0e74c050 ] )
+  , Mont::unsafe_make( [ 0xf9e12e5c , 0x99f5eff7 , 0xf6559711 , 0x3381b06f , 0x114219c6 , 0x79d03b60 , 0xff83cc37 , 0x084e36aa ] )
+  , Mont::unsafe_make( [ 0x50504204 , 0xdf433e42 , 0x4463a108 , 0x9dd9e358 , 0xf05a6430 , 0x864f5273 , 0x37d671a2 , 0x1f71f247 ] )
+  , Mont::unsafe_make( [ 0x80606810 , 0x1b9dbad1 , 0x81b4f0bb , 0x9bad1ada , 0x9d6304b1 , 0x7c23e4b0 , 0x457cd37d , 0x2270eb71 ] )
+  , Mont::unsafe_make( [ 0x7e2e43a7 , 0xb77b3410 , 0x0e45fc30 , 0xea308d96 , 0x9add7c3e , 0xeb5af8d1 , 0x070c326f , 0x2e1d5a51 ] )
+  , Mont::unsafe_make( [ 0xca7c4322 , 0x2a8086ee , 0x764d0c16 , 0x233227dd , 0x1804d6e9 , 0x7b9eb542 , 0x9ffab917 , 0x0e2795d4 ] )
+  , Mont::unsafe_make( [ 0x63f7ff6f , 0xe6691da6 , 0xa037efc4 , 0x1ecc9cd1 , 0x3de15b75 , 0xdb82bae6 , 0xb718914c , 0x2d6facaf ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// internal mixing matrix (`t x t`s, flattened)
+pub const CONST_P: [Mont; 81] = 
+  [ Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xf7d7aca3 , 0x394be6b6 , 0xb8f238c2 , 0x3eab0f89 , 0x2dad91f1 , 0x3791e1ac , 0x077fcf86 , 0x03b4bd40 ] )
+  , Mont::unsafe_make( [ 0xf50deb60 , 0xba238f34 , 0x4d378913 , 0x652f2cc5 , 0x937e3ce4 , 0xf7b30797 , 0x772685ce , 0x28f93168 ] )
+  , Mont::unsafe_make( [ 0xd4b492f7 , 0xac92c5b0 , 0x920c6ce6 , 0x6c65adb3 , 0xa8bb6aa9 , 0x9ee1b120 , 0x6aaa1e09 , 0x1f4b4e75 ] )
+  , Mont::unsafe_make( [ 0x14c29bae , 0xfe28b15c , 0xba440b7a , 0x847a492b , 0x8b3ea0f8 , 0x103f7775 , 0xd12f7b32 , 0x2f910898 ] )
+  , Mont::unsafe_make( [ 0x40e2a52f , 0xbf7a5762 , 0x23ee2a80 , 0x02b84931 , 0xd662131d , 0x039f6487 , 0x8d4feef2 , 0x036e7aff ] )
+  , Mont::unsafe_make( [ 0x51e9bd15 , 0x20528d90 , 0x1fa7441c , 0xacbc4226 , 0x31537cc0 , 0xc198bd29 , 0xe8ff6ba7 , 0x2c05d351 ] )
+  , Mont::unsafe_make( [ 0x1fc8f923 , 0x4ef4b134 , 0xe2ab5b27 , 0x5ddd6860 , 0x46a6068a , 0xbf8b2ec8 , 0x671d6df2 , 0x2abceba1 ] )
+  , Mont::unsafe_make( [ 0x89d335d8 , 0x4ab61f24 , 0xe92d6fcf , 0xe4dbeaa9 , 0x7cd57c05 , 0xdb1c0d5f , 0x2d323dea , 0x16d46a81 ] )
+  , Mont::unsafe_make( [ 0xc0a0cbda , 0xfb5f006c , 0x6cbf22af , 0xe6fcd1a9 , 0xc4e06bad , 0xc5cbd466 , 0x6b702d1d , 0x036de2ff ] )
+  , Mont::unsafe_make( [ 0x849f6183 , 0x1fd88575 , 0x33ef6b8d , 0xe3581410 , 0x1278aadc , 0xcb67bb11 , 0x918d3e05 , 0x28f98d01 ] )
+  , Mont::unsafe_make( [ 0x12f96804 , 0x8d25bef3 , 0x68427237 , 0xca401b29 , 0x2922a7c6 , 0x34ffc617 , 0x859a327e , 0x13c5cfa7 ] )
+  , Mont::unsafe_make( [ 0xa3167bde , 0xb0ed0b4b , 0x8421e17d , 0xc0f6bbf3 , 0xf9d9e19d , 0x96b30ab9 , 0x29583b94 , 0x1b107c6b ] )
+  , Mont::unsafe_make( [ 0x4ea2e21b , 0xdba846ba , 0x323e9f25 , 0x3fda49de , 0x465a7fd3 , 0xb1ea313a , 0x89da0ba0 , 0x28e82a23 ] )
+  , Mont::unsafe_make( [ 0xb08e60a7 , 0x7e143f3e , 0x593b9634 , 0x516a939c , 0xda836dd2 , 0x6d743705 , 0xc5d5bbbf , 0x0116cbe8 ] )
+  , Mont::unsafe_make( [ 0xc56706d2 , 0x3980d182 , 0x49720f5b , 0xcc43af89 , 0x55f9b4f3 , 0xe07e0dbd , 0x3fcc0314 , 0x03e7e9d8 ] )
+  , Mont::unsafe_make( [ 0xc5a8ec3d , 0x5a0e3e16 , 0x32834fb8 , 0x49c23435 , 0xcd7f5a34 , 0x6f2251ec , 0x2c051eaf , 0x22f6ef19 ] )
+  , Mont::unsafe_make( [ 0xf47799dd , 0xe93c90e2 , 0xc73fe6ed , 0xf0fc4411 , 0x1430750f , 0xd05a59a9 , 0x6732855f , 0x2fd95f1f ] )
+  , Mont::unsafe_make( [ 0x66f69a98 , 0xab8f9a2c , 0x2bcf4b4e , 0x4fe4cbcc , 0x6056998f , 0xc878f3b7 , 0x301557c0 , 0x2d9ba2cd ] )
+  , Mont::unsafe_make( [ 0x51e268c6 , 0x3c64dca0 , 0x6c998443 , 0x07a2a5b1 , 0xbdb2b579 , 0x3e723829 , 0x67e0e810 , 0x01a49332 ] )
+  , Mont::unsafe_make( [ 0x226ee5de , 0x1f7e12a9 , 0xce195527 , 0xde1eb7a0 , 0xd51ec19b , 0x7c920709 , 0x95a356f3 , 0x19c7ee83 ] )
+  , Mont::unsafe_make( [ 0xfbba5a6d , 0x4740005c , 0xf7885423 , 0xa4e3e106 , 0x75b1456a , 0xf54ea3d0 , 0x7e94eb15 , 0x0a80a1d8 ] )
+  , Mont::unsafe_make( [ 0x7baad4ce , 0x69f946cb , 0xe48f82ef , 0x8c7499b6 , 0x2d52feac , 0xa88bb112 , 0x8f5fda28 , 0x2b5c7edf ] )
+  , Mont::unsafe_make( [ 0xf856de61 , 0x7f40d48f , 0x614237ac , 0xfc54aada , 0xb2bc65ca , 0x9e0fbd5e , 0xdef4ff36 , 0x22bf15ef ] )
+  , Mont::unsafe_make( [ 0x74eb4382 , 0xfc2f4c3a , 0xaee93f26 , 0x4fddc836 , 0x4ff1df8e , 0xe59c0c6f , 0x0df671a2 , 0x075a8c5b ] )
+  , Mont::unsafe_make( [ 0x4f84962c , 0x7fd90c49 , 0x49d044ef , 0xc9a8e24f , 0x48045f3b , 0x00da9c45 , 0x979afe45 , 0x15ca76f8 ] )
+  , Mont::unsafe_make( [ 0xee8818af , 0xf8383bb7 , 0x91afa1ee , 0x2ecbfa10 , 0x7bf3d66d , 0x55afb8d5 , 0x5db46be9 , 0x2e08fe08 ] )
+  , Mont::unsafe_make( [ 0xf498c374 , 0x08b0eec3 , 0xef7936d4 , 0x72d5d765 , 0xdddc5d90 , 0xdebc3a6f , 0x605f269b , 0x2fcfd53a ] )
+  , Mont::unsafe_make( [ 0x9829a95c , 0x68654493 , 0x1aa77048 , 0x814c95b7 , 0x229698f3 , 0xfe16359b , 0x4f1f94d7 , 0x21190995 ] )
+  , Mont::unsafe_make( [ 0xca498add , 0x288e6ba9 , 0xfb7c869e , 0x850a6123 , 0x96cdd054 , 0x5fd9438e , 0xa5c99405 , 0x25f5a754 ] )
+  , Mont::unsafe_make( [ 0xdb2fa022 , 0x8237a8d7 , 0xd0d7822a , 0xfe4f466e , 0xf7ed4bf4 , 0xc5e804ce , 0x9b864102 , 0x23f72c9c ] )
+  , Mont::unsafe_make( [ 0x3fe30d44 , 0x75c0faac , 0xbd6ff540 , 0xce5c9830 , 0x2bf310ca , 0x6260e9e1 , 0x1da46f4b , 0x1ccfff37 ] )
+  , Mont::unsafe_make( [ 0x09e138b5 , 0x45b11433 , 0xa7027c54 , 0xf9c3b044 , 0x93e854cf , 0xf574bc0b , 0x91d5114a , 0x1f4c5cd8 ] )
+  , Mont::unsafe_make( [ 0xa95877c5 , 0xef1facaa , 0xbbad82d6 , 0x818893f9 , 0xfa91639f , 0x5629693a , 0x2772f22e , 0x05f44d53 ] )
+  , Mont::unsafe_make( [ 0x55b133a4 , 0xd9d9dd99 , 0x5f0200ca , 0xcc092c1c , 0xc78e6149 , 0x264f2171 , 0x401f2f29 , 0x2ffbf00e ] )
+  , Mont::unsafe_make( [ 0x55b31e68 , 0xf30020a5 , 0x4f671c89 , 0xd3cb81d3 , 0xe6d5a03d , 0xb4d5b8da , 0xbfc9516f , 0x264f1b84 ] )
+  , Mont::unsafe_make( [ 0xd778781f , 0xd7cff19c , 0xe4b41950 , 0x30d61fd6 , 0xbdcf6384 , 0x0f95c9c8 , 0x7ac46432 , 0x19ccdff2 ] )
+  , Mont::unsafe_make( [ 0x670d8c5d , 0xd3427896 , 0x7bd58c8f , 0x64d29541 , 0x0ed24fcd , 0x51762abd , 0x832f428f , 0x0fb4790e ] )
+  , Mont::unsafe_make( [ 0x52de8a9d , 0x99fb985f , 0x8a917b08 , 0x22b7176a , 0x695a9841 , 0xd57d5d95 , 0x597aa681 , 0x2bf1f06a ] )
+  , Mont::unsafe_make( [ 0x312d4b19 , 0x074786be , 0xbb33b828 , 0x547c387a , 0x736bb60b , 0x71a2035b , 0xd124880b , 0x24a7d21a ] )
+  , Mont::unsafe_make( [ 0x9e56880d , 0xe65d9ef7 , 0xe11cb313 , 0x38a5206b , 0xee485711 , 0xba18adcc , 0x3b39972a , 0x01cdb22e ] )
+  , Mont::unsafe_make( [ 0xe50886b1 , 0x25e86a38 , 0x0bf847de , 0x6ad64ec4 , 0xc40ed39e , 0xfae90b57 , 0x89ce5a00 , 0x1861a474 ] )
+  , Mont::unsafe_make( [ 0x388c0292 , 0x2d9429e0 , 0xe6308d5f , 0x444fd1dc , 0xdacca874 , 0x4e9d3828 , 0x8d77eab6 , 0x160d4977 ] )
+  , Mont::unsafe_make( [ 0x5da2fee2 , 0xf2e831c1 , 0xb32eccb5 , 0x2280c3e7 , 0x40238a62 , 0x1b98ca92 , 0xe75a4e71 , 0x02c73c6b ] )
+  , Mont::unsafe_make( [ 0x648a23b1 , 0x43009e3a , 0x4c92153c , 0xa3e4d1ce , 0xf830a534 , 0x173ce0b3 , 0xb643935f , 0x1a29d758 ] )
+  , Mont::unsafe_make( [ 0x755c7dbb , 0xf4ddc6bd , 0x406ece46 , 0x31ca9a3f , 0x7452f1fa , 0xa9c95d71 , 0xa2e109aa , 0x0fd0b2f9 ] )
+  , Mont::unsafe_make( [ 0x4904a2c4 , 0xf07f6147 , 0xb847464c , 0xf64ad212 , 0xede4360f , 0xa16f9dbb , 0xb984ca2d , 0x2e569014 ] )
+  , Mont::unsafe_make( [ 0x9cf2461f , 0x2816a926 , 0xd8ff1a53 , 0x1a431c85 , 0xacb64b2a , 0xd05291aa , 0x85113465 , 0x0fbbca9a ] )
+  , Mont::unsafe_make( [ 0x1f67ae11 , 0x0d9e07d7 , 0xcdec6c91 , 0x748e699d , 0x8fd74358 , 0x908e374e , 0xb9ff2a42 , 0x0e4b0750 ] )
+  , Mont::unsafe_make( [ 0x3b938c2f , 0x573d4eb6 , 0x4b15b420 , 0xc03bf14e , 0x44595dc4 , 0x134d7c15 , 0x0e55fe8d , 0x25fad577 ] )
+  , Mont::unsafe_make( [ 0xd11a19cc , 0x9b8d615d , 0x7d9535e4 , 0x2ba90cf4 , 0xe5e8a504 , 0xef549a3a , 0xb82bdce8 , 0x10624804 ] )
+  , Mont::unsafe_make( [ 0x8a3c566b , 0x2761c0cc , 0x39e86335 , 0x8cfb84df , 0xabd00f75 , 0xbd5937bd , 0x2c58d8f4 , 0x04595ec5 ] )
+  , Mont::unsafe_make( [ 0xc71c4f0c , 0x2616bdcd , 0xfe247370 , 0x6a2ebd01 , 0x96cabd38 , 0xc183b8d9 , 0x31248aae , 0x2146b07b ] )
+  , Mont::unsafe_make( [ 0x50bc8f38 , 0x6dbc6125 , 0x6c663b09 , 0xe4d74c2f , 0x8a1d2302 , 0xfc0d5f09 , 0xffe743f1 , 0x0265c68a ] )
+  , Mont::unsafe_make( [ 0x47576e24 , 0x3cdd1730 , 0x52b55dbb , 0xc3a2fc5a , 0x630bdb14 , 0x0e184adb , 0x1a20f923 , 0x1c6eb118 ] )
+  , Mont::unsafe_make( [ 0x99d2d8a5 , 0x2c9adcb5 , 0x4f20bebd , 0x500e67b4 , 0x867e28bc , 0xe21a8ea0 , 0x138be467 , 0x0951d0a9 ] )
+  , Mont::unsafe_make( [ 0x6b8f504e , 0xe02b04dd , 0x503c8734 , 0x778b82ad , 0xd9c2589e , 0x5aa1aae5 , 0xdc84b201 , 0x24b7ae0b ] )
+  , Mont::unsafe_make( [ 0xe72c5668 , 0x2f5f686b , 0x6638858b , 0xf1a76685 , 0x96545629 , 0x62a4018a , 0x15d9164d , 0x04216d71 ] )
+  , Mont::unsafe_make( [ 0x4f4ef557 , 0x1545269e , 0xee71680a , 0x5e090d65 , 0x54ac4a70 , 0x04440412 , 0xc146c2eb , 0x23f565f8 ] )
+  , Mont::unsafe_make( [ 0x0ef8d7be , 0x2568433b , 0xfb7197e9 , 0x989ce596 , 0xc042ffdf , 0xa0571e50 , 0x6e400d2a , 0x0fa89ec3 ] )
+  , Mont::unsafe_make( [ 0xea49f6b0 , 0x6c3af867 , 0x5ab39a4a , 0xde1a49a9 , 0x01d32a74 , 0x09b04b86 , 0x42b8d7b7 , 0x0afa0125 ] )
+  , Mont::unsafe_make( [ 0x9874b1b9 , 0xe70c13df , 0xf38da34b , 0xc066e163 , 0x1d4e4528 , 0x0558ee5e , 0x2436f3b7 , 0x2814e18e ] )
+  , Mont::unsafe_make( [ 0x53b67b8d , 0x8b84c3ed , 0x0f511ec6 , 0x4a05d18c , 0xc6010e1d , 0x25ff2985 , 0xf64bb372 , 0x2da9af86 ] )
+  , Mont::unsafe_make( [ 0xe96142a2 , 0xc82c6921 , 0x2e1d5e3b , 0xdf385385 , 0x9a78ea0b , 0xdce16950 , 0xfc1e50dd , 0x20f68c53 ] )
+  , Mont::unsafe_make( [ 0xef8bc17f , 0x0ecc8a20 , 0xae15fe71 , 0x7b24e05e , 0x2bf4ded5 , 0x4b9f0865 , 0x0aac7d36 , 0x1aa7a92d ] )
+  , Mont::unsafe_make( [ 0xdd338256 , 0xbb197ae8 , 0x70de486e , 0x83abb716 , 0x89be172f , 0x5823dca0 , 0x17be659e , 0x29ecafdd ] )
+  , Mont::unsafe_make( [ 0xe2eb2c6c , 0x013aab37 , 0x8e54c6be , 0xce87cbda , 0xc196072f , 0xd4bb52be , 0xd6af2637 , 0x2c441239 ] )
+  , Mont::unsafe_make( [ 0xc8fbe936 , 0x0eab8f09 , 0x3b6b823a , 0xf2626901 , 0xd4191513 , 0xe4bcd4a9 , 0x5b077827 , 0x29906278 ] )
+  , Mont::unsafe_make( [ 0xb49322a3 , 0xc08f02d7 , 0xef206de1 , 0xf469adaf , 0xec5c50ed , 0x4fdeac29 , 0x6b4a0ff8 , 0x1890da73 ] )
+  , Mont::unsafe_make( [ 0xe4d6dec5 , 0x49144cf2 , 0x563f1c69 , 0x9bf2b8b2 , 0x7e6a7010 , 0x5569ee11 , 0x0b6fcf4b , 0x29dc7dc2 ] )
+  , Mont::unsafe_make( [ 0xfeb66193 , 0x2bbc7446 , 0xdc991c10 , 0x070376eb , 0x08e8b939 , 0x625bf03c , 0x80cff687 , 0x1bd93c85 ] )
+  , Mont::unsafe_make( [ 0xeceadd32 , 0x4b0a292c , 0x02a855ac , 0x288b8207 , 0xb7728de2 , 0xa33c7c41 , 0xf7173c25 , 0x0ad52995 ] )
+  , Mont::unsafe_make( [ 0xa1669582 , 0xeba36e2f , 0x9c468c5c , 0x13eac76f , 0x64ab1724 , 0xea3e128c , 0x14780a51 , 0x0f3c3267 ] )
+  , Mont::unsafe_make( [ 0x295d2c25 , 0x2cf72972 , 0x8deb359f , 0x1022d3b5 , 0x4f19d6eb , 0x7c29bcbf , 0x0ef4e4ed , 0x0b856956 ] )
+  , Mont::unsafe_make( [ 0x7655d24c , 0x33eb987d , 0x20e55e04 , 0x4a5d8307 , 0x46dac77a , 0xcaabcaf2 , 0xbee5b8c3 , 0x0a89900b ] )
+  , Mont::unsafe_make( [ 0x3d462640 , 0x57f1010b , 0xbb4229f9 , 0xdace04b0 , 0x55b82179 , 0x14ff54f8 , 0x1f685d3c , 0x1a73b92b ] )
+  , Mont::unsafe_make( [ 0x109c909f , 0xe5caedb0 , 0x3b0698c0 , 0xa9f7b779 , 0x9431298f , 0xe0ed52af , 0x9b7391b3 , 0x1afab4dc ] )
+  , Mont::unsafe_make( [ 0x507442d8 , 0xe10c7a98 , 0xfb201a3f , 0xf7f8b9ed , 0x2919521c , 0xbf78f0d4 , 0xd07742fe , 0x13b43b64 ] )
+  , Mont::unsafe_make( [ 0xb2dedb4e , 0x77ffc575 , 0x60eb39e2 , 0x7d0708e4 , 0xd8e3707f , 0x7aa5d974 , 0x68ffb7d8 , 0x1098629c ] )
+  , Mont::unsafe_make( [ 0x83e01ed0 , 0x9bc24249 , 0x9922b358 , 0xcb80020d , 0xf5f6eaaf , 0xe1a7164f , 0x77c31d83 , 0x0bb152ca ] )
+  , Mont::unsafe_make( [ 0xf55ab9ca , 0x7f74e7fb , 0xf2429263 , 0x96ad3566 , 0x45965ab1 , 0x72df2de5 , 0x2bcc52ed , 0x3051343e ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// circomlib's optimization whatever
+pub const CONST_S: [Mont; 1071] = 
+  [ Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x936c3d8c , 0xee9447ac , 0x55f5f725 , 0xfe0c3cef , 0x62cb22a8 , 0x03970162 , 0x0c3a0401 , 0x08ebbaeb ] )
+  , Mont::unsafe_make( [ 0x88f5934c , 0x5e731dce , 0x65621ab6 , 0x49fe6ffd , 0x54f0e6dc , 0x783f79b8 , 0xd50140ad , 0x148e423b ] )
+  , Mont::unsafe_make( [ 0xbc9893a1 , 0x68d403f1 , 0xdac501d0 , 0x61030966 , 0xcb1fd34c , 0x7addcb06 , 0x6572dcf7 , 0x29e405f1 ] )
+  , Mont::unsafe_make( [ 0xc2a1ee28 , 0x3e988395 , 0x1a291ffe , 0x0b218903 , 0xa5c6bd15 , 0xda44ac10 , 0xc46b9d29 , 0x293d4fbc ] )
+  , Mont::unsafe_make( [ 0x9ad53827 , 0x23c8a389 , 0xb3239c88 , 0x694d7aeb , 0xdee65093 , 0xbeab19f2 , 0x90b9c9e6 , 0x2f3e4203 ] )
+  , Mont::unsafe_make( [ 0xabc2ae99 , 0x8ff24d02 , 0x4e8f28ac , 0x7050ba34 , 0x60dcf56a , 0xfce0d34e , 0xca30fd42 , 0x1c35cdef ] )
+  , Mont::unsafe_make( [ 0x7c74bdb7 , 0x8b150b47 , 0x6075b47c , 0x492f8cf6 , 0x54bbe54a , 0x4aac879b , 0xbfc3391f , 0x164e07f8 ] )
+  , Mont::unsafe_make( [ 0x96761323 , 0x5a704ac3 , 0xbcbe208d , 0x7373f9be , 0xe44b0c29 , 0xe024a78d , 0xb2d54c81 , 0x178e341b ] )
+  , Mont::unsafe_make( [ 0x2ebfa734 , 0xbfe1037b , 0xfac0ccdf , 0x100f35c3 , 0xab8f33b1 , 0xdee6da61 , 0x0e2a30f1 , 0x24506f07 ] )
+  , Mont::unsafe_make( [ 0x4d96b6d1 , 0x03e23d6e , 0x7cb76694 , 0x2f84c825 , 0xda684228 , 0x1ca52387 , 0x68f97d04 , 0x2a3bb6a9 ] )
+  , Mont::unsafe_make( [ 0x473448cd , 0xa0777328 , 0x6b20ee1c , 0xa693b520 , 0xfc7c9707 , 0xd2fa064a , 0xce1e2d5b , 0x2ad6e72e ] )
+  , Mont::unsafe_make( [ 0x45c20776 , 0x1ad4e4d7 , 0x92f01619 , 0x0a57e0a9 , 0xcad05497 , 0xffb5c121 , 0x0902416e , 0x1a271365 ] )
+  , Mont::unsafe_make( [ 0x9c03f82e , 0x20b14156 , 0x5f86ee8c , 0x6be287fd , 0x706eed9a , 0x4afca466 , 0xbdecee1b , 0x17a991a6 ] )
+  , Mont::unsafe_make( [ 0x6c452a29 , 0x657b83f2 , 0x2271c1dc , 0xe3dc3d8c , 0x9f076bc7 , 0x562f0f42 , 0x0b5f735c , 0x016c4c24 ] )
+  , Mont::unsafe_make( [ 0xf2e20081 , 0x83f66320 , 0x95e0ab0b , 0xeda11f52 , 0x3c0093da , 0x938f7703 , 0x20ff8882 , 0x0ca77e5c ] )
+  , Mont::unsafe_make( [ 0xbd889f39 , 0x358d1480 , 0xa09048e4 , 0xcb40d28a , 0xc95b54dd , 0x6e0a5563 , 0xf1e0866b , 0x2e18c91e ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x8178f1b7 , 0x0a4f5a91 , 0x78778c3f , 0xe258bb27 , 0xe627b7e4 , 0x027641f3 , 0x7390b700 , 0x1f602e98 ] )
+  , Mont::unsafe_make( [ 0xd28b79b9 , 0x93a59673 , 0x526374d0 , 0x09137bc2 , 0xb2c06a10 , 0x7956cdd8 , 0x6faeca08 , 0x1be81be4 ] )
+  , Mont::unsafe_make( [ 0xf35b414e , 0x5ce332aa , 0xb801eee0 , 0x6752fb9a , 0x913f47a8 , 0x9e4eff20 , 0x1c4ad4c5 , 0x1aac29e6 ] )
+  , Mont::unsafe_make( [ 0x1a4a9728 , 0xc79492b2 , 0xb093c15d , 0x1a01e6ba , 0x46f50d60 , 0xde73ecf4 , 0x2d29fe3b , 0x0faa4412 ] )
+  , Mont::unsafe_make( [ 0x2362bb91 , 0x0980bdcb , 0x8f422bb7 , 0xbadc9671 , 0x9a97152b , 0x43ed1e6a , 0x0259c6e9 , 0x2e38decb ] )
+  , Mont::unsafe_make( [ 0xc869fa87 , 0x9fe8d96e , 0x37aa5604 , 0x021327eb , 0x3f162c7d , 0xce676f48 , 0x7aa5b625 , 0x0c42db72 ] )
+  , Mont::unsafe_make( [ 0x900c79e6 , 0x378df5fb , 0x69b99172 , 0x6b736e84 , 0xc1d8d1ad , 0x36556d47 , 0x67c80f0e , 0x2f6b6289 ] )
+  , Mont::unsafe_make( [ 0xb4f208ff , 0xa41b0c6f , 0x7e64e173 , 0x7da39e46 , 0x3ab8525f , 0x60b3c139 , 0x75baa506 , 0x0bb0d13e ] )
+  , Mont::unsafe_make( [ 0x20591894 , 0x79cfc036 , 0x6eca3259 , 0x4261a2b8 , 0xd6fc46e0 , 0x46c8d21c , 0xa2f40430 , 0x001da335 ] )
+  , Mont::unsafe_make( [ 0xe6b5f22c , 0x718dfa5b , 0x4827c6af , 0x62d4353b , 0x52460f5a , 0x59dd0c34 , 0xb8e60a44 , 0x088512a1 ] )
+  , Mont::unsafe_make( [ 0x6bfd1a9f , 0x6130718e , 0xd31b0d94 , 0x7d3ffad7 , 0x5cee2e3e , 0xc377838d , 0xd042b9a3 , 0x22d68e60 ] )
+  , Mont::unsafe_make( [ 0x6a91e3e0 , 0xb91280ba , 0x73723aff , 0x740cd0f0 , 0x269a09cc , 0x66d09668 , 0x083b2fba , 0x01e0f6a0 ] )
+  , Mont::unsafe_make( [ 0x21c88881 , 0x1d0d1878 , 0xbc7ff5aa , 0x68bcafcf , 0xa935ff7a , 0x26abe178 , 0x02b6af5f , 0x02c0311f ] )
+  , Mont::unsafe_make( [ 0xf7d3df8d , 0x465b58db , 0xadf9c401 , 0xe0d54ecc , 0xd0f6d7cc , 0x4a433c21 , 0x735391ef , 0x118beb22 ] )
+  , Mont::unsafe_make( [ 0x921d01eb , 0x3c4d2025 , 0x5f8fc767 , 0x083f42fa , 0xb9450bd4 , 0x9af8c023 , 0x8468e9b8 , 0x1a1ddf1b ] )
+  , Mont::unsafe_make( [ 0x9150fffc , 0xfbacd167 , 0x1c55a850 , 0xb2a23903 , 0x2a9aad86 , 0x78bdbed9 , 0x980c8914 , 0x11114ac2 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xde840245 , 0x4a5de958 , 0xd2944b9a , 0x9320d9e2 , 0xdabaeeee , 0x623980df , 0xd5ac87f2 , 0x04754d3f ] )
+  , Mont::unsafe_make( [ 0x6b89465c , 0x222f398e , 0x86dfec46 , 0xff907518 , 0xbbcad6d4 , 0xb5eedf8b , 0x37d7ecce , 0x2f65905d ] )
+  , Mont::unsafe_make( [ 0x28a86e29 , 0xacfba1d0 , 0xffcae19e , 0xfe35cabf , 0x72910555 , 0x4277d930 , 0xa3b2b847 , 0x0821881a ] )
+  , Mont::unsafe_make( [ 0xb92b5f11 , 0x59caae98 , 0x84c047e5 , 0x2787a847 , 0x38825c83 , 0x6fe637a0 , 0xc43110c7 , 0x22fc7b26 ] )
+  , Mont::unsafe_make( [ 0xa3a4b488 , 0xa8e749bf , 0x076a739c , 0xa90c4761 , 0x7a8fa652 , 0x18a86980 , 0xebb79313 , 0x128b977b ] )
+  , Mont::unsafe_make( [ 0x67ed5645 , 0x13acf147 , 0x8d2e9ae6 , 0xe4200572 , 0x0b9ebd9c , 0xb2973965 , 0x450f5f1f , 0x1075c7c9 ] )
+  , Mont::unsafe_make( [ 0x70c045c9 , 0x5eda9dec , 0xb80467fa , 0x1f6ceacf , 0x1de1c38e , 0x8717f2ef , 0xb45b6525 , 0x3053938e ] )
+  , Mont::unsafe_make( [ 0xaaae5ddd , 0x31b42693 , 0xa2943b5f , 0x34ffac5f , 0x14e83eb2 , 0x661166d3 , 0x9b3c6717 , 0x1e0326bf ] )
+  , Mont::unsafe_make( [ 0x78f4f17d , 0x8a388e36 , 0x82dc903e , 0xe38f6b40 , 0x0a88686a , 0xcbf1c108 , 0xa1f329c3 , 0x1a119ce8 ] )
+  , Mont::unsafe_make( [ 0xd530e28d , 0xc79a427e , 0xcaae5480 , 0xc4b4593f , 0x416df0bb , 0x887d69f7 , 0xca1ee3d2 , 0x11e4c2fe ] )
+  , Mont::unsafe_make( [ 0x3aa821f7 , 0xe109091e , 0xb5037d89 , 0xe9456367 , 0xba82848b , 0x0378c1ae , 0xd12e910b , 0x286bcda4 ] )
+  , Mont::unsafe_make( [ 0xb0f371cd , 0x72bd3ce5 , 0x49813603 , 0xe1a9610b , 0xc733baa8 , 0xd9dff9d6 , 0x09e91aac , 0x29e019e6 ] )
+  , Mont::unsafe_make( [ 0xc0db0a66 , 0xd706076b , 0xab01cf6f , 0x31006874 , 0x7e0c3a5b , 0x55ed3848 , 0x7df6bf9e , 0x187232d9 ] )
+  , Mont::unsafe_make( [ 0x75822d72 , 0x868709ed , 0x98fbc7c3 , 0xbc491efc , 0x55df94c4 , 0x8ae95b21 , 0xeee04845 , 0x12796c91 ] )
+  , Mont::unsafe_make( [ 0x7354c51f , 0x25192328 , 0x501f60bb , 0xfa2a2411 , 0x54b8bfcc , 0x0e7c69ac , 0x766cac65 , 0x02b0705d ] )
+  , Mont::unsafe_make( [ 0xedd342b5 , 0x84a9e309 , 0x4b0930a7 , 0x45cb2965 , 0x4e373e9b , 0x2a16b2e8 , 0xd147fa31 , 0x0eaad989 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x01253dd0 , 0xbb284ed3 , 0x80b3524d , 0x188af33c , 0xe5273cd2 , 0x633613db , 0x41297052 , 0x04832af7 ] )
+  , Mont::unsafe_make( [ 0x6c4fc571 , 0xd302e76f , 0x920114b0 , 0xdefa1a53 , 0x9ba8d37d , 0x49924cbc , 0x1d312347 , 0x18029e6c ] )
+  , Mont::unsafe_make( [ 0xbb14d062 , 0x7eb7e5be , 0xd3a5a0ee , 0x14c6f5bd , 0x918797e4 , 0x966f83df , 0xe9b63bad , 0x1b75458a ] )
+  , Mont::unsafe_make( [ 0x121a89fa , 0x057bae4b , 0x176f73ae , 0x4b94df2a , 0xc5e64a96 , 0xb707f115 , 0x5b3210b3 , 0x03b818d5 ] )
+  , Mont::unsafe_make( [ 0xac6fd147 , 0xe8b6a19d , 0x6863692e , 0x531984a2 , 0xc21cbfd7 , 0x21e42b40 , 0x311d3ac6 , 0x1f393885 ] )
+  , Mont::unsafe_make( [ 0xf130f685 , 0x82004ebc , 0x16ce3aa0 , 0x00facaa6 , 0xcd2d27e2 , 0x5babc244 , 0xf4a9254b , 0x2b7ddd5b ] )
+  , Mont::unsafe_make( [ 0x675db823 , 0xb6d1329e , 0xa6e428a0 , 0xa6ce5fb7 , 0x80a76fb3 , 0xa4d378f0 , 0xdbf62e6d , 0x12f5023d ] )
+  , Mont::unsafe_make( [ 0x0cdf23aa , 0xf391499c , 0x1153d4af , 0x6be07cb6 , 0x480b1ec6 , 0xdea32df2 , 0x4866b730 , 0x0158e3c4 ] )
+  , Mont::unsafe_make( [ 0x5e925988 , 0xebf12cb8 , 0x4bd9d822 , 0xd38a155f , 0x46753805 , 0x6222701e , 0x1cf255f0 , 0x2cf6e9b8 ] )
+  , Mont::unsafe_make( [ 0x66939eb6 , 0x9e09df2b , 0xf28f5af4 , 0xa9bfa955 , 0xe9de6624 , 0x3e0c86ad , 0x41c32e88 , 0x15f0a42b ] )
+  , Mont::unsafe_make( [ 0xa5b355c9 , 0x991b54f5 , 0xbcecd2c0 , 0xb38af33d , 0x524507be , 0x15d98992 , 0x32972cc8 , 0x207695c9 ] )
+  , Mont::unsafe_make( [ 0xca08fa7a , 0x7d17789a , 0x3b957b3f , 0x70e61404 , 0x616a9282 , 0xe3cfc631 , 0x199c2986 , 0x0bebdd5e ] )
+  , Mont::unsafe_make( [ 0xa30c6cdf , 0x6e11f243 , 0x008d2adb , 0xbbac1177 , 0x521e1005 , 0x0473b108 , 0xd979ec0f , 0x18938da2 ] )
+  , Mont::unsafe_make( [ 0x336d949f , 0xafef4693 , 0xa65d7189 , 0x8e95c156 , 0x5d328d84 , 0x2dd3fc0f , 0xd3c1f9e9 , 0x2af78010 ] )
+  , Mont::unsafe_make( [ 0x35d37eac , 0x6ffe8f01 , 0xac86d769 , 0xd3e8d378 , 0x4c5aaee3 , 0x344a8161 , 0x9e935c27 , 0x25695fe2 ] )
+  , Mont::unsafe_make( [ 0x870d704b , 0xdc811cd5 , 0xc9b5269d , 0x72c1575d , 0x3496dc9e , 0xb9e79899 , 0x49cdd00d , 0x25aab998 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x6f4bd621 , 0x499487ca , 0x326cf1dc , 0x69972fba , 0x7269b83a , 0x0a3969e8 , 0x43c5cc4a , 0x0bfe5e8f ] )
+  , Mont::unsafe_make( [ 0x35e6a7cf , 0xabaf77fa , 0x5ae4a6c1 , 0xb3b55a31 , 0xcecc784e , 0x2c8d1055 , 0xdfb33b9a , 0x1cfbbcd9 ] )
+  , Mont::unsafe_make( [ 0x21256500 , 0x9e8a967d , 0x1dc772c6 , 0x07b9b2a0 , 0x50f16e85 , 0x07fec145 , 0xed507a8a , 0x0717ea6b ] )
+  , Mont::unsafe_make( [ 0x48ea5887 , 0x499972c8 , 0xa51c2df7 , 0xdb22238b , 0xd08733bb , 0x31a2ea0a , 0xd1510139 , 0x0635f91d ] )
+  , Mont::unsafe_make( [ 0xd7be939d , 0x8a75f980 , 0x3db0a424 , 0x3033ffc1 , 0x966cbf95 , 0xcc427191 , 0xd72a2c29 , 0x0a67841c ] )
+  , Mont::unsafe_make( [ 0x89fcaf3e , 0xe556d1b1 , 0x52321409 , 0x1513e621 , 0x41948a42 , 0x76f8ee0a , 0xa7aaf05d , 0x1ec2ebd7 ] )
+  , Mont::unsafe_make( [ 0xe0489b56 , 0xb35ba6dc , 0xa736778c , 0x11b5b878 , 0x2a7f06d8 , 0x7d36a917 , 0xfc115c66 , 0x27eb2475 ] )
+  , Mont::unsafe_make( [ 0x96186dfc , 0x9d9b5e7f , 0xef60fdf3 , 0xb442d758 , 0x9fdc066e , 0x1e42f1a1 , 0x4beeb580 , 0x12552e76 ] )
+  , Mont::unsafe_make( [ 0xf8047491 , 0x5c5ab04b , 0xce72cc44 , 0x6b7c9f85 , 0x0128e54d , 0x0b9acc0d , 0x350d6dcb , 0x2494d4b2 ] )
+  , Mont::unsafe_make( [ 0xc7f4b1e6 , 0x7fd23db8 , 0x8fa953c3 , 0x5211e06a , 0x5d78b7ea , 0x0e7dad41 , 0xa9f3e6a4 , 0x18e8b8af ] )
+  , Mont::unsafe_make( [ 0x0b3712fb , 0x47ff6a91 , 0x5f696ab3 , 0x1857d298 , 0xffcf4d8e , 0x7e6922d9 , 0xc0660d1a , 0x26af3d15 ] )
+  , Mont::unsafe_make( [ 0xe5a7d3b4 , 0xa17091a1 , 0xeacc837c , 0x7f59a33e , 0x0a5b193f , 0x13f9e22b , 0xcaa827ad , 0x0ef39564 ] )
+  , Mont::unsafe_make( [ 0x8dd6a48f , 0xb9feff2e , 0x4d52cc5e , 0x1b82abb4 , 0xefaeda06 , 0x8e108561 , 0x776cc214 , 0x2ec4dadc ] )
+  , Mont::unsafe_make( [ 0x143d7907 , 0x432f61d4 , 0x6a62c17f , 0x32cae56a , 0xd7943374 , 0xd34e7a7d , 0x828416d1 , 0x2a9fd135 ] )
+  , Mont::unsafe_make( [ 0xa69c46e9 , 0x22063691 , 0x73ae5465 , 0xd827d0a8 , 0x8e8f4ae6 , 0x5d61877e , 0xde03b866 , 0x18fe2ecc ] )
+  , Mont::unsafe_make( [ 0x29b74e56 , 0xc0b3e5b9 , 0x1513c452 , 0xba849cff , 0x1c0786e4 , 0x229f228b , 0x41cc8401 , 0x0d1a570d ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xdf71fd57 , 0xa2c91935 , 0xcd343b9f , 0x0b43fe8d , 0xec9b52e3 , 0xeeaea904 , 0xde4578a7 , 0x2b3b96ae ] )
+  , Mont::unsafe_make( [ 0xb32162ae , 0x876dc4ab , 0x5e170723 , 0x66c9006c , 0x6ef5359f , 0x6ef5c3e9 , 0xfa5179ca , 0x0cdff386 ] )
+  , Mont::unsafe_make( [ 0xcaf637a1 , 0x58d55411 , 0xe53e40a8 , 0xca243ea0 , 0x6255ed85 , 0x6b70232d , 0x527e94bb , 0x144365b3 ] )
+  , Mont::unsafe_make( [ 0x824ef4eb , 0xe3a8134f , 0x59f9cdcc , 0x1f969fe3 , 0xa956d730 , 0x12bc6e08 , 0xe92006f2 , 0x1e309293 ] )
+  , Mont::unsafe_make( [ 0xc9db03b0 , 0x39a9549c , 0x62bce546 , 0x5d0e123d , 0xcdddf1be , 0x8a37b193 , 0x38a54f18 , 0x17cab577 ] )
+  , Mont::unsafe_make( [ 0xe5e0f269 , 0x37c875f2 , 0xc22a8ae8 , 0x104b4b19 , 0xa493b8f2 , 0x0e10d72a , 0x7abdbd6b , 0x0c9df1ff ] )
+  , Mont::unsafe_make( [ 0x3413eca9 , 0xf80dbc56 , 0x02dbd510 , 0xd1a62f24 , 0xcf7183cd , 0xf9b7f469 , 0xbe6252b5 , 0x1f730e67 ] )
+  , Mont::unsafe_make( [ 0xe3642bc0 , 0x773b8eda , 0x672a9b78 , 0x5622e77b , 0xe87b0d9a , 0x2bc63ce2 , 0xc42ec368 , 0x10f1e08a ] )
+  , Mont::unsafe_make( [ 0x67625a1a , 0x152d9f38 , 0xe8cdab62 , 0xd7c9b223 , 0x53faab01 , 0x9baa2f00 , 0xf51ba827 , 0x145bb204 ] )
+  , Mont::unsafe_make( [ 0xe4246005 , 0xa5ef27b5 , 0xe5b41344 , 0x84df7066 , 0x17bc5700 , 0x1608bf94 , 0x550e6aa1 , 0x24c34a24 ] )
+  , Mont::unsafe_make( [ 0x7daaebdd , 0x2e42aa4c , 0x7cfc4261 , 0x66bd8d1a , 0x2f06771a , 0xa5db6000 , 0x7b40eebd , 0x12440e3a ] )
+  , Mont::unsafe_make( [ 0x5c10af38 , 0xba527092 , 0xfa73f338 , 0xa015caaf , 0x14c5bbc5 , 0xc5e038d0 , 0xd7b1e3bf , 0x1018cf50 ] )
+  , Mont::unsafe_make( [ 0xf44aa45f , 0x8110596b , 0xf17f5276 , 0x460e5195 , 0x5a807389 , 0xf2f4319e , 0x6a68c48e , 0x00a3d6f0 ] )
+  , Mont::unsafe_make( [ 0x97f009aa , 0x66d9aad0 , 0xb12ff01d , 0x6378fcaf , 0x16e35a98 , 0x90ebedbb , 0x057348b3 , 0x09401f6d ] )
+  , Mont::unsafe_make( [ 0x00f7e839 , 0x645790d2 , 0x5350f501 , 0xa4b3e0c6 , 0x3ce33535 , 0x5f82e3b6 , 0x198bcc69 , 0x1348eff8 ] )
+  , Mont::unsafe_make( [ 0xdfccd516 , 0xf9bb8c83 , 0x63a9c451 , 0x1fd80ae5 , 0x164cce67 , 0xcac47a06 , 0x478a59ed , 0x28044f0f ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x7a1dc6a9 , 0x8f1e76b9 , 0x4db597aa , 0x9c4f09b1 , 0x4ad4eb8e , 0x82872030 , 0xa7f56b4e , 0x015e695b ] )
+  , Mont::unsafe_make( [ 0xccb00ede , 0x39a0d388 , 0xb613d521 , 0x663430f0 , 0xd3453c23 , 0x663ec0a7 , 0x40c6adcc , 0x0e7def2d ] )
+  , Mont::unsafe_make( [ 0xaef13d10 , 0x712e7e31 , 0x27ae5c36 , 0x32c41aad , 0x72678c72 , 0xba0f01fc , 0x47fc1749 , 0x0b1af8be ] )
+  , Mont::unsafe_make( [ 0x23a72658 , 0x80107178 , 0xf86858a8 , 0x6b70811c , 0x7549f927 , 0xfd68a0df , 0x66f58706 , 0x0449c879 ] )
+  , Mont::unsafe_make( [ 0x74f2c78e , 0xa0e9db77 , 0xad25e348 , 0x67be2562 , 0xc5c34118 , 0xc075766e , 0x5d03801f , 0x01dab665 ] )
+  , Mont::unsafe_make( [ 0x6f3def6d , 0x9a0eae29 , 0xea6fd1d6 , 0xe41d204f , 0xfc250f8e , 0x44154d08 , 0xa15d2d9e , 0x086a72c4 ] )
+  , Mont::unsafe_make( [ 0xcb0a6dcd , 0x451b497b , 0xcf6429f2 , 0xdf94c66b , 0x8d1b3cba , 0xebc14ea8 , 0xba5d6041 , 0x17e0ec3b ] )
+  , Mont::unsafe_make( [ 0xc628de8f , 0x50dcb8b9 , 0x732694a8 , 0x1c39bc5b , 0x80034231 , 0x0c11b6ed , 0x1c353e79 , 0x124dd351 ] )
+  , Mont::unsafe_make( [ 0x619703c1 , 0x4f9ef6fc , 0x2ca018c9 , 0x0714032d , 0xc814781f , 0xc8c12a73 , 0x66074b5f , 0x17ef004e ] )
+  , Mont::unsafe_make( [ 0x10b6382f , 0x784522ce , 0xd682cd4f , 0x40011c7b , 0x41e36a3a , 0x284dbc7e , 0xb2ae0df6 , 0x291a3e63 ] )
+  , Mont::unsafe_make( [ 0x712d0465 , 0xbb618fc1 , 0xb02df665 , 0xc7717a8b , 0xcfd04422 , 0xf0716a92 , 0x2fb1f924 , 0x08388049 ] )
+  , Mont::unsafe_make( [ 0x82fe7b62 , 0xf4b2da40 , 0x0a651ee6 , 0x0d2eb723 , 0x59eb2efe , 0xe0f8b9b2 , 0x332b1612 , 0x0ad60533 ] )
+  , Mont::unsafe_make( [ 0x24179ac1 , 0x72c3ba8f , 0x02431319 , 0x63b929b0 , 0x454a5785 , 0x8e4ede09 , 0x0bc45f0c , 0x04c55375 ] )
+  , Mont::unsafe_make( [ 0x66cad716 , 0xc93b4c22 , 0xeb26c736 , 0xbbe043ab , 0x23927497 , 0xe042195c , 0x125e299e , 0x01d85f2f ] )
+  , Mont::unsafe_make( [ 0x94a651c0 , 0x89bb9532 , 0x115a2e4e , 0xbfd8c98f , 0xbfca6f63 , 0x70e6b8d8 , 0x2349e4a9 , 0x1ac88e76 ] )
+  , Mont::unsafe_make( [ 0xea4038a5 , 0xb7e2a883 , 0x3445fec7 , 0x3351b2b1 , 0x96e08200 , 0xc7fd5ed0 , 0x5b8804e8 , 0x0b675a15 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xef8bfc28 , 0x41e3d54b , 0x15d46ea0 , 0xcd2bdd60 , 0x68f101e9 , 0xd0938a4e , 0x12c092c2 , 0x2498d2a6 ] )
+  , Mont::unsafe_make( [ 0x6e8aeda1 , 0x64c455cf , 0xf07a06a9 , 0x83580c75 , 0xbf81e7b3 , 0x05f35b1d , 0x1a0cc98b , 0x08544051 ] )
+  , Mont::unsafe_make( [ 0x71da9229 , 0xe15b0dda , 0xe4c0275a , 0x61532ef0 , 0x0a3c98f0 , 0x2065a860 , 0x57e2677d , 0x07d8947a ] )
+  , Mont::unsafe_make( [ 0x7d3c52e0 , 0xf43dc077 , 0xc8d5172a , 0x58014d30 , 0x27553ee4 , 0x5a71ddf9 , 0xa1e2e3a7 , 0x0348e727 ] )
+  , Mont::unsafe_make( [ 0xf07d7b2b , 0x90aa2504 , 0xfb0fd516 , 0x453e3844 , 0x48435159 , 0x461d289b , 0x9c31edf7 , 0x12493443 ] )
+  , Mont::unsafe_make( [ 0x7ff30170 , 0xa968bffb , 0x4b2ee578 , 0x2b3c4f81 , 0x3ec71a54 , 0x636ac10a , 0x20c05f32 , 0x0a5a6682 ] )
+  , Mont::unsafe_make( [ 0xa678d119 , 0xbbb8bb43 , 0x693c6874 , 0x56daf51e , 0xce540113 , 0xa581e681 , 0x19f750b1 , 0x24d972c8 ] )
+  , Mont::unsafe_make( [ 0x6ec09392 , 0x7dbb5b69 , 0x170b6f29 , 0xe260095a , 0x4f9bf5fa , 0xd135eeb9 , 0x045498cc , 0x2010a504 ] )
+  , Mont::unsafe_make( [ 0xc21338b7 , 0x73fd96dd , 0x37b4bf6d , 0x865de9de , 0xf01ab525 , 0x85bfbe3c , 0x45ff6d43 , 0x1467912e ] )
+  , Mont::unsafe_make( [ 0x3733bbfb , 0x6eedb6ed , 0x7ac2b88b , 0xeb9928ac , 0x48afe9df , 0xf62ac5d3 , 0x5c41a5d0 , 0x07540321 ] )
+  , Mont::unsafe_make( [ 0x1127a992 , 0x75b946e9 , 0xdf01f36a , 0x73f79ce2 , 0x44394326 , 0xc252166a , 0x94f1aa31 , 0x1f8be047 ] )
+  , Mont::unsafe_make( [ 0xb9a426b1 , 0xeb852585 , 0xf6d6652b , 0x8becb91c , 0x643cb25d , 0xc4b64622 , 0x66128c4e , 0x1c0895d3 ] )
+  , Mont::unsafe_make( [ 0x20bd58e4 , 0x8fecb0de , 0xc90d276c , 0x2e4b53f1 , 0x464d5018 , 0x14b4891e , 0x5d608cdd , 0x01785ff1 ] )
+  , Mont::unsafe_make( [ 0x99704048 , 0x0f65836f , 0xe6d84a9d , 0x56d0882e , 0x8d2c62af , 0x1caf17ac , 0x81b52b25 , 0x2a251c12 ] )
+  , Mont::unsafe_make( [ 0x25724e74 , 0xe40d59c0 , 0x9e4c98ae , 0x56c06356 , 0xc294f45b , 0x1f968d30 , 0xd8d62517 , 0x19d92f13 ] )
+  , Mont::unsafe_make( [ 0x7167e797 , 0xddd85099 , 0xf3874cf1 , 0xdcdd77e2 , 0x1c539b33 , 0x9ae640f7 , 0x979c1249 , 0x1eacf3a1 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x2ed3e82e , 0xe080fdea , 0x117608f9 , 0xce067497 , 0x13b65f0b , 0x0304282f , 0x45c9528b , 0x2f3c2286 ] )
+  , Mont::unsafe_make( [ 0x9144db20 , 0x4c484493 , 0x85498373 , 0x3fe902f6 , 0x27e3eac5 , 0x63a10600 , 0x6dbb6354 , 0x2fa2b305 ] )
+  , Mont::unsafe_make( [ 0x25f17e65 , 0x7f2deaf0 , 0xafa0cd2d , 0x7e1a9bd2 , 0x5b03c6b2 , 0x8fbcab1d , 0x5ee75ac8 , 0x05735d96 ] )
+  , Mont::unsafe_make( [ 0x82125201 , 0x05895734 , 0x495c3bd7 , 0x4ce2f385 , 0xd05c0fda , 0x899da9d3 , 0x31ffd65c , 0x2ffdac72 ] )
+  , Mont::unsafe_make( [ 0xdc467dee , 0x28a62dd3 , 0x6496e1c6 , 0x6da203aa , 0x9be7f88b , 0x5ec21a3d , 0x986adccb , 0x2145843e ] )
+  , Mont::unsafe_make( [ 0x1fce4166 , 0x3e59d534 , 0x267cdc94 , 0xff7f1861 , 0xef8aa69f , 0xa0bccde0 , 0xa089fa70 , 0x134cd0d3 ] )
+  , Mont::unsafe_make( [ 0x27d886fd , 0x21bc3cfd , 0xb5bf1f3b , 0x78339fef , 0xdc7f4072 , 0xae13915c , 0xbff20428 , 0x1e85b8c8 ] )
+  , Mont::unsafe_make( [ 0xec2897fd , 0xe7491a2f , 0xadc8e11d , 0xbc8607a9 , 0xba4d8195 , 0x3d3ec4f8 , 0x28a4887f , 0x059a5d8a ] )
+  , Mont::unsafe_make( [ 0x5bc22cc4 , 0x0ad299d1 , 0x88094551 , 0x92582f3e , 0x5230f3be , 0x638cb6eb , 0x9f333b7c , 0x2c9daf83 ] )
+  , Mont::unsafe_make( [ 0xde3f0d4c , 0xbbd70415 , 0xccf8b4a7 , 0x3e12386a , 0x2f0c2ecd , 0x4edfda34 , 0xcd319aca , 0x1dc826c6 ] )
+  , Mont::unsafe_make( [ 0x35148144 , 0xb94cf649 , 0xd7c05be2 , 0x1fd78cae , 0x07816041 , 0x05afeb27 , 0xd36ffa6a , 0x2d07657d ] )
+  , Mont::unsafe_make( [ 0x5b8e9aaf , 0x666e1941 , 0xd5d2c0af , 0x175d2313 , 0x8e8a681a , 0x7749acde , 0x97d8f6e8 , 0x022171bf ] )
+  , Mont::unsafe_make( [ 0x8fb560fb , 0x131bafb1 , 0x2390b8c9 , 0x9f7f71d1 , 0x42c6b3a3 , 0xadcd96be , 0x52aae3bd , 0x23fb3bab ] )
+  , Mont::unsafe_make( [ 0x5e3013ea , 0x9763133c , 0xa275cdc1 , 0xa694eb39 , 0x6ac37a65 , 0xcd6a6497 , 0x262737a3 , 0x0f8ec91e ] )
+  , Mont::unsafe_make( [ 0x396bdd38 , 0x39b1e322 , 0x85064640 , 0xe57c844a , 0x1382cd32 , 0xa11e054d , 0x21d21763 , 0x21511b43 ] )
+  , Mont::unsafe_make( [ 0xffc4f6ad , 0xb9578a86 , 0x0ab0a9b8 , 0xb65dac4c , 0x9fa713c0 , 0xec5c34bc , 0xa731556b , 0x1b43f0ad ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xf94e0c82 , 0x2846a4c2 , 0x949e453f , 0x40f97ed8 , 0xdfa20832 , 0xf1e0407b , 0x33c46ebd , 0x0e38604c ] )
+  , Mont::unsafe_make( [ 0x64e9970e , 0x43af393f , 0x7e4747e4 , 0xc0e4e81b , 0x27f90059 , 0x01b0896b , 0xf203d00e , 0x043897ac ] )
+  , Mont::unsafe_make( [ 0x21bbf45b , 0xe96c6ef2 , 0x8c83ac86 , 0x98fac47a , 0x2e423185 , 0xc67b33c6 , 0xe70ec13e , 0x23ea99ed ] )
+  , Mont::unsafe_make( [ 0x1d2b9080 , 0x8abc5088 , 0xcae584b3 , 0x1633766b , 0xc9124058 , 0xb1bbba15 , 0x32669948 , 0x1b9b011d ] )
+  , Mont::unsafe_make( [ 0x6ad9bae6 , 0xd35b8712 , 0x93b8a13f , 0xef8f2a98 , 0xd0787639 , 0xe85b9561 , 0xf015834d , 0x2dbbd13b ] )
+  , Mont::unsafe_make( [ 0x98a87d7d , 0x8994470f , 0x52d8b809 , 0xc80ef772 , 0x418fe873 , 0xc406746f , 0x4d63ad60 , 0x20a8ada1 ] )
+  , Mont::unsafe_make( [ 0xe5240cfa , 0x5b4de7c8 , 0xf674c25c , 0x98d4a8d5 , 0x589895f6 , 0xc34075e4 , 0x5626de13 , 0x25feb1b5 ] )
+  , Mont::unsafe_make( [ 0xbfa19067 , 0x7cd19120 , 0x43c5c573 , 0x35f93d30 , 0xdb36185b , 0x0d0ef02e , 0x025c71ca , 0x1951abc0 ] )
+  , Mont::unsafe_make( [ 0x10cfe41c , 0xea6de4cb , 0xd0c3dcac , 0x6d5d3f79 , 0x08abdcae , 0x1d3cc6ba , 0x88f30355 , 0x11892546 ] )
+  , Mont::unsafe_make( [ 0xd55d46f7 , 0x0b48417d , 0x1aa33269 , 0xdd1e6c09 , 0x9f4554ee , 0xfd832a76 , 0x2992827e , 0x02260eed ] )
+  , Mont::unsafe_make( [ 0x9a362b70 , 0x40a6b862 , 0x6fdc930c , 0xda7661c9 , 0x44faf802 , 0x7bba59cf , 0x682c1a3f , 0x2298998a ] )
+  , Mont::unsafe_make( [ 0x6853a780 , 0x2a47aad0 , 0xa70c5f2f , 0x99f0a9f9 , 0xe238ac0d , 0x39d28906 , 0x6a810836 , 0x29acc024 ] )
+  , Mont::unsafe_make( [ 0x56a5c812 , 0x53faa5a1 , 0x2449495d , 0x20985c22 , 0x47a1b99f , 0x0fd15d22 , 0x91a2e6ad , 0x0d4382a8 ] )
+  , Mont::unsafe_make( [ 0x72deca69 , 0x571f9a60 , 0x8c15d394 , 0xcfdcdfc6 , 0x8c50b452 , 0xa1cc8c57 , 0x50bf4afb , 0x24de54a6 ] )
+  , Mont::unsafe_make( [ 0xdb6ea68f , 0x22c0a23e , 0x29e8a261 , 0xa7c3c18b , 0xf0076a6d , 0x4dffab1f , 0x0f028ed0 , 0x1d088165 ] )
+  , Mont::unsafe_make( [ 0x17c66006 , 0x960fa801 , 0x1772e93f , 0x37d7895d , 0x91543dda , 0xb7d6f50c , 0xdbf0a389 , 0x0afc0646 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x2e7c645a , 0xf793280f , 0x72c32a2d , 0x6dc15c90 , 0x667dad9a , 0x4a7473a0 , 0xdcb854b9 , 0x0e56dc3a ] )
+  , Mont::unsafe_make( [ 0x5c1026a1 , 0x05c27682 , 0x06b6ae43 , 0x147a28e5 , 0x1185770c , 0x069c49fb , 0x09890e9c , 0x0798e826 ] )
+  , Mont::unsafe_make( [ 0x61767879 , 0x2463c104 , 0xe6d13d83 , 0x895395e5 , 0x00f65015 , 0x5fc25bd4 , 0x57c01eb9 , 0x081414ec ] )
+  , Mont::unsafe_make( [ 0xe706175e , 0x8827ccdd , 0xbf4c42e2 , 0x5ff148f3 , 0xc5ca06f8 , 0x74dbb7a5 , 0xc4a406ce , 0x05c47abc ] )
+  , Mont::unsafe_make( [ 0x6a172676 , 0xbaecceda , 0xb57c2cea , 0xae0434c2 , 0x24b4aa92 , 0x740f9184 , 0xd4e401be , 0x0aa9976b ] )
+  , Mont::unsafe_make( [ 0xa1313191 , 0x7d05bda8 , 0x6cb4161f , 0xa1650624 , 0xcefac5e8 , 0x1d9b1906 , 0x6a7f442e , 0x29561de5 ] )
+  , Mont::unsafe_make( [ 0x8c5b7e40 , 0xdb88af1a , 0xcbf2f655 , 0xeadef952 , 0xd8b407af , 0x970d568a , 0x4d4a3439 , 0x2213ed18 ] )
+  , Mont::unsafe_make( [ 0xa54cd6dc , 0x20885868 , 0x9b56b3f1 , 0x7ab90e58 , 0x2f85265d , 0xe23f6580 , 0x1d1f91ae , 0x22bbf998 ] )
+  , Mont::unsafe_make( [ 0x57af4b4b , 0xb6734cff , 0xef49a84f , 0xfb3df629 , 0x41097cb6 , 0x77934be3 , 0xb41b3e13 , 0x1965c6f7 ] )
+  , Mont::unsafe_make( [ 0x1a325271 , 0xab2d9588 , 0x828f6ff1 , 0x346e7c16 , 0x880376b0 , 0x56c70a61 , 0xa69d386b , 0x04a7774d ] )
+  , Mont::unsafe_make( [ 0x4bdde1b6 , 0x6d82d606 , 0x0807e0f2 , 0x6a2044e2 , 0x5c231b47 , 0xd9b979d7 , 0xaefdc408 , 0x16a80492 ] )
+  , Mont::unsafe_make( [ 0xa9ab6a14 , 0x56255537 , 0xdabec8a4 , 0xa3e11049 , 0x3091b73c , 0xc40107ef , 0xb7f51cc9 , 0x0489270b ] )
+  , Mont::unsafe_make( [ 0x81081d14 , 0xa1ef915e , 0x798a573d , 0x159f6fc1 , 0xb5d39edf , 0xecada253 , 0xc18f1234 , 0x14077a52 ] )
+  , Mont::unsafe_make( [ 0x31408f01 , 0x55bd2063 , 0xab54d577 , 0x4e7ac3e9 , 0xcd779a56 , 0x58d2791f , 0x13e83284 , 0x23580ade ] )
+  , Mont::unsafe_make( [ 0x97bba96a , 0x43af5171 , 0x64ce407c , 0xba8b483d , 0x260b6aaf , 0xd46b7c7e , 0x8d953e80 , 0x01bbd5f4 ] )
+  , Mont::unsafe_make( [ 0xd1dfd05b , 0x96067af9 , 0xb1127956 , 0x30a9c0f7 , 0xcfadddf9 , 0x6b72d6f8 , 0x8a988586 , 0x02ada437 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x5df9fc16 , 0x314f7b36 , 0x7dae008b , 0x97514ef9 , 0x17b9f5d5 , 0x9e2006a4 , 0xe15ef93b , 0x2d1a8b3a ] )
+  , Mont::unsafe_make( [ 0x218a5a02 , 0xea591139 , 0x8c36f520 , 0xf24d0e50 , 0xd6aa98f3 , 0x3d6f162b , 0xa99230c3 , 0x24a74785 ] )
+  , Mont::unsafe_make( [ 0xe33d8053 , 0x39cf74db , 0x331ef1d0 , 0x72e5a6a5 , 0x3fb07333 , 0x69b0b1c3 , 0x8b385cd0 , 0x006e97ba ] )
+  , Mont::unsafe_make( [ 0x1eec49e5 , 0xcd37334c , 0x0d1cae72 , 0xdb4e41a0 , 0x64d9199a , 0x128408d7 , 0xcc975994 , 0x16fea9ff ] )
+  , Mont::unsafe_make( [ 0x3e662357 , 0x8d8abbdf , 0xd23c39e2 , 0xc6e6c1cc , 0x3348f2cf , 0x906b06de , 0x6089a401 , 0x174c5166 ] )
+  , Mont::unsafe_make( [ 0x2ebe5ff6 , 0xb9dc865f , 0xd83b7c81 , 0x8f1a685d , 0x0915b3b0 , 0x15f995ca , 0xb2e5260b , 0x1e43a8a3 ] )
+  , Mont::unsafe_make( [ 0x313285eb , 0xa99d0f43 , 0x21b44a41 , 0x83ac7626 , 0xfdca67ef , 0x9afdf158 , 0xde8c7d4f , 0x252c6a2a ] )
+  , Mont::unsafe_make( [ 0x77c5a0d5 , 0x9bfbb269 , 0xfb893a71 , 0x069f2447 , 0xb7ea13a8 , 0xd56de9d7 , 0xeff5bec4 , 0x21f2472b ] )
+  , Mont::unsafe_make( [ 0x8a857035 , 0xda124688 , 0xd654d81c , 0x0c23a1df , 0x667ddeef , 0xf69c8c8b , 0x8814e18b , 0x1adf7e94 ] )
+  , Mont::unsafe_make( [ 0x0f7f387a , 0xe6d2af80 , 0x8f1853fe , 0x6b968b51 , 0xa06d4034 , 0x840209d0 , 0xe8b49b78 , 0x224104e4 ] )
+  , Mont::unsafe_make( [ 0xfd0bf4b1 , 0x9c6920b6 , 0xf72b5c8c , 0x3f1eb027 , 0xd280090a , 0x2b57c33e , 0x42bf0604 , 0x17e5185c ] )
+  , Mont::unsafe_make( [ 0xc304a669 , 0x859c07c4 , 0xfc3e27f1 , 0x59943316 , 0x758b5140 , 0x0f60213c , 0xaed0b3e2 , 0x1c273494 ] )
+  , Mont::unsafe_make( [ 0xd3b9289c , 0x8a05951f , 0x1965eb2b , 0x6f34c985 , 0x7f5c75f2 , 0x763eb4e9 , 0xb172300e , 0x1be89f71 ] )
+  , Mont::unsafe_make( [ 0xd74c6e3b , 0xe26de175 , 0x297ea85c , 0x7a1b742d , 0x3d43d22c , 0x76ec84ba , 0x323a2df7 , 0x00909b73 ] )
+  , Mont::unsafe_make( [ 0x1e2a2263 , 0x80698dde , 0xb4e43f62 , 0x75f00361 , 0x31eea199 , 0xc8526793 , 0x62c82466 , 0x0fc790ef ] )
+  , Mont::unsafe_make( [ 0x4bb208e1 , 0xfa67fc8c , 0x161d1de7 , 0x9da03ba6 , 0xfebbbb61 , 0x91404e38 , 0x91c7a17b , 0x1b11fc09 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x6c1c4db1 , 0x8a7025ca , 0x95ba7ee4 , 0x4f9239bc , 0xf5c82288 , 0xc9410256 , 0x7ef345fb , 0x2eb62b31 ] )
+  , Mont::unsafe_make( [ 0x10fd08eb , 0x485bf824 , 0x2d11fe24 , 0xab9a949a , 0xee2953ef , 0x24f0f9c7 , 0x7ea96613 , 0x2bb83466 ] )
+  , Mont::unsafe_make( [ 0x740d13b7 , 0x05c51210 , 0x1071a185 , 0xe9a63d62 , 0xef6c25c0 , 0x87bfc2f3 , 0x476443d2 , 0x301e37ff ] )
+  , Mont::unsafe_make( [ 0xe1eb71e1 , 0x644f06ba , 0x4c0d2b65 , 0x381df976 , 0xfa456662 , 0xa80394b8 , 0xa7bcc1a3 , 0x098cc84a ] )
+  , Mont::unsafe_make( [ 0x97cf657e , 0x45b8c3ef , 0xf9a2380d , 0xd7a8bbbe , 0x7425b99e , 0x2a676b1b , 0xd757d719 , 0x07a07c07 ] )
+  , Mont::unsafe_make( [ 0x26c774dc , 0xf06a7497 , 0xb3abcb4c , 0x7f638876 , 0x0cebcc91 , 0x07649844 , 0x47437942 , 0x298430ac ] )
+  , Mont::unsafe_make( [ 0x09ca4b9f , 0xecdffc12 , 0x00f0aa96 , 0x3af144c3 , 0xd91a71dc , 0x4e33e73f , 0x0f378e20 , 0x021d7d0a ] )
+  , Mont::unsafe_make( [ 0xd8a6d891 , 0x1b99c6a7 , 0x450c823e , 0x88842757 , 0x6b1877d2 , 0xfe1a9ee8 , 0xa31e1f5a , 0x1723136e ] )
+  , Mont::unsafe_make( [ 0xa8082126 , 0x1bbd9b78 , 0x9d23e11d , 0x10912e82 , 0x242d9ea9 , 0xb920516f , 0x06cb17ba , 0x2d929a57 ] )
+  , Mont::unsafe_make( [ 0x9c0816a2 , 0x24fa7aff , 0xf47290a9 , 0xf5100b35 , 0x60d3c010 , 0x4c058a8b , 0x0c9ace20 , 0x13abeeef ] )
+  , Mont::unsafe_make( [ 0xe1954cbd , 0x18805a5f , 0xafefd276 , 0xda7fbc46 , 0x48ee981d , 0x81b98a24 , 0x34fa6dfe , 0x1beeed71 ] )
+  , Mont::unsafe_make( [ 0x873bb0ed , 0x6f47b86e , 0x2cdde85c , 0x978114cb , 0x468e79f2 , 0x039415cf , 0x75a5ce74 , 0x00d6feb3 ] )
+  , Mont::unsafe_make( [ 0xc2f240f0 , 0xea0fb433 , 0x65deee24 , 0xef35377f , 0x8d25adc5 , 0xdd89d55f , 0x5cbbf660 , 0x0999779e ] )
+  , Mont::unsafe_make( [ 0x35dc296c , 0x87c34e01 , 0x1d25836f , 0x25dcea26 , 0x92bb94a0 , 0xc5941265 , 0xcb9daaf3 , 0x1527be9d ] )
+  , Mont::unsafe_make( [ 0x4d64e6cf , 0x6aebf70e , 0xe0e54e17 , 0x9e20bd47 , 0xce7a552b , 0xc815070b , 0xdbac057d , 0x11e01280 ] )
+  , Mont::unsafe_make( [ 0xe979c0c5 , 0x15ed3405 , 0x02285b73 , 0x264cb62c , 0x17f897f2 , 0x85a42d89 , 0x2de62ab2 , 0x0d986d0c ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x76496d0e , 0x91ac3f11 , 0x00d3ecdf , 0x70a4d6a5 , 0x668da869 , 0xc32f0145 , 0x37386532 , 0x299c05fb ] )
+  , Mont::unsafe_make( [ 0xfc5bfc06 , 0x1513f6d5 , 0x8e89de28 , 0x885165b0 , 0x553aeea5 , 0x379b6f2f , 0x469f5885 , 0x0b052159 ] )
+  , Mont::unsafe_make( [ 0x8fd0c3c6 , 0x2af3fc3b , 0x11fcaf23 , 0x4d155463 , 0x16a713d4 , 0xf6b493e2 , 0xd1f2ac16 , 0x1b2fbe46 ] )
+  , Mont::unsafe_make( [ 0xe36e713c , 0x4a92cedb , 0x7c592d92 , 0x49122681 , 0xd9b23353 , 0x1b4746e7 , 0x9af03d5f , 0x0ea0805d ] )
+  , Mont::unsafe_make( [ 0x3c1d969c , 0xee2b19cd , 0xb96b4a17 , 0x64f1c735 , 0x725ee444 , 0x77c2fe77 , 0xd0e95330 , 0x17892a84 ] )
+  , Mont::unsafe_make( [ 0xf03d727d , 0x52fddaf4 , 0x371672de , 0x7db351ba , 0xfb23132f , 0x2e9b674b , 0x24e26368 , 0x21bcb9fb ] )
+  , Mont::unsafe_make( [ 0x8f4ac457 , 0xc44dd702 , 0x5f9fc252 , 0xb8423723 , 0xe0566f91 , 0x171a76a7 , 0xa193b693 , 0x2c8e3c0b ] )
+  , Mont::unsafe_make( [ 0x35f9918a , 0x269188ab , 0x0cf5bbc1 , 0x2e30e976 , 0x26d2b4aa , 0x13c9f110 , 0x9928f7d9 , 0x17270aa9 ] )
+  , Mont::unsafe_make( [ 0x0023b323 , 0xdbffae65 , 0xd2f3fe15 , 0xe9aa2803 , 0x66998356 , 0xc413cc64 , 0xa73e88f9 , 0x265abe25 ] )
+  , Mont::unsafe_make( [ 0xa3da6a29 , 0x999746a5 , 0x17634466 , 0x1b07d182 , 0xbaf377c1 , 0xb4b85e75 , 0x12356992 , 0x13137b93 ] )
+  , Mont::unsafe_make( [ 0xd7cd52b9 , 0xc3c3b16a , 0xc3d55a3f , 0x7e2ec2a1 , 0x2baf9eb6 , 0x87ae1f34 , 0x5c61eefd , 0x158ba124 ] )
+  , Mont::unsafe_make( [ 0x29e0de36 , 0xfb071e8a , 0xafc06670 , 0xe464f0d9 , 0x59223988 , 0x9947b842 , 0x38de9a6e , 0x20667cc5 ] )
+  , Mont::unsafe_make( [ 0x19d2f87c , 0x0e68fb43 , 0x7290ea50 , 0x8d55096a , 0x9b4dfe49 , 0x3a6d8840 , 0xec325bed , 0x1d145221 ] )
+  , Mont::unsafe_make( [ 0xf936b0b6 , 0x26ad496d , 0x3a3cd6d1 , 0x43d67398 , 0x1c937ede , 0x971aebe1 , 0xebe05958 , 0x30094b52 ] )
+  , Mont::unsafe_make( [ 0x2c2369aa , 0x439fbd76 , 0x0c52552c , 0xb250eac8 , 0x9ea775fc , 0x1b107cd8 , 0x5be04cde , 0x058abd68 ] )
+  , Mont::unsafe_make( [ 0x0ed55337 , 0x6b86abb1 , 0xb535a835 , 0xff9d91c7 , 0xb42cd532 , 0x7e16d95c , 0xe7f9196b , 0x1eb6eaf5 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x1aaa7bee , 0x2940481e , 0xe27b74be , 0x0913f980 , 0xbf1dea9a , 0x208f11ef , 0x826c7c49 , 0x28f655b4 ] )
+  , Mont::unsafe_make( [ 0xf821b92b , 0xd989e8ea , 0x5acd15b6 , 0x8933f20e , 0x2935613b , 0xe5a20afc , 0x08feab1a , 0x1321a400 ] )
+  , Mont::unsafe_make( [ 0xd1ddc84c , 0x47202511 , 0x017b3cac , 0xf120209a , 0x549769f0 , 0x4020db31 , 0xc4ff9805 , 0x2a68911d ] )
+  , Mont::unsafe_make( [ 0x730e8b70 , 0x20a0ae54 , 0x55271668 , 0x74e33327 , 0x0fd038f3 , 0xdda748b4 , 0x6a01f7b5 , 0x20c38ed4 ] )
+  , Mont::unsafe_make( [ 0x7f68ac12 , 0xb66e464e , 0x6a61c648 , 0xaa0fec6c , 0x51ae2c20 , 0x14c1f908 , 0x17eec410 , 0x182f34fd ] )
+  , Mont::unsafe_make( [ 0x309cd431 , 0x50d160b8 , 0xc133a535 , 0x0bbbccfd , 0xd980c528 , 0x8489ea24 , 0xe9e76aeb , 0x05a41b88 ] )
+  , Mont::unsafe_make( [ 0xa686c670 , 0x24c2f7ae , 0xd20c8338 , 0x40481062 , 0x7b81c589 , 0xad47c4be , 0xa4bea159 , 0x19b4258b ] )
+  , Mont::unsafe_make( [ 0x3611d691 , 0x60ba7513 , 0xc59163ea , 0x8f76ad9f , 0x5f55f1d7 , 0x215bf30e , 0xecade2c1 , 0x14637a5b ] )
+  , Mont::unsafe_make( [ 0xa5115a8c , 0xa3bad68f , 0xa2b0daeb , 0xd9443bf6 , 0x2de51378 , 0x439383a9 , 0xc62163c8 , 0x105da736 ] )
+  , Mont::unsafe_make( [ 0xd0efe3e5 , 0xb29a6c18 , 0x812aa14f , 0xf46a29c5 , 0xe278199d , 0xc2e43904 , 0x50817373 , 0x2553d4c7 ] )
+  , Mont::unsafe_make( [ 0x768c1614 , 0xa62d7e19 , 0xb799f9c8 , 0x6398aaf4 , 0x5ab97a19 , 0x68f9cc10 , 0x7da6f13a , 0x0c66e703 ] )
+  , Mont::unsafe_make( [ 0x88e703e5 , 0x572e6164 , 0xa53295e7 , 0xc669d988 , 0xa579eb3b , 0x0ea2a5e3 , 0x602655f7 , 0x112b8773 ] )
+  , Mont::unsafe_make( [ 0xd18a2445 , 0xe1466c73 , 0x6e4c4ba5 , 0xa0babd82 , 0xd47bb54a , 0x643dc345 , 0xcc79657c , 0x02163814 ] )
+  , Mont::unsafe_make( [ 0x30e947d5 , 0xafd4124f , 0xb70036d0 , 0x58945818 , 0x2404629e , 0x8ce4b28e , 0x87d4b300 , 0x246bd16d ] )
+  , Mont::unsafe_make( [ 0x8920bb14 , 0x7492b939 , 0xa227cb98 , 0xaa459e55 , 0x736b5a85 , 0x2ce1ed16 , 0x8e6c82c4 , 0x2c51bd57 ] )
+  , Mont::unsafe_make( [ 0xbf93cf4f , 0x47b36310 , 0x370f81ce , 0xb67d0b04 , 0xb26627ab , 0x730909ae , 0xe87eb64a , 0x1b082277 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xea2e95ac , 0x73bc1b14 , 0xa5396943 , 0x4a3696b9 , 0xcd373e18 , 0x274e2205 , 0x7dfebd60 , 0x050d91d2 ] )
+  , Mont::unsafe_make( [ 0x314916d2 , 0x9509239c , 0x150cc24b , 0x5678172c , 0x5501938a , 0x5bac618a , 0x3fde1286 , 0x0870d1b2 ] )
+  , Mont::unsafe_make( [ 0x0303c39c , 0xbc8dbeb1 , 0x20f88b35 , 0x7dfa5bf2 , 0x461ad790 , 0x88fefb0e , 0xf8e6db74 , 0x1b8d1312 ] )
+  , Mont::unsafe_make( [ 0x6a475612 , 0xa2b19853 , 0xb311eada , 0x0ec8a7a7 , 0x71cb05c9 , 0x1414e92c , 0xc076022f , 0x1f6b19ab ] )
+  , Mont::unsafe_make( [ 0xecdc76cf , 0x37c6a493 , 0xfb3acaf0 , 0x82748ea5 , 0x2fff0e13 , 0x15c82b39 , 0x1ca32ed8 , 0x27a9083d ] )
+  , Mont::unsafe_make( [ 0xc12edb72 , 0x10a29b4f , 0x3f3f3fd0 , 0x8412e2be , 0x669065aa , 0x30a60db5 , 0xe769dc7c , 0x04bfa4c1 ] )
+  , Mont::unsafe_make( [ 0x5a3622ed , 0x824c065b , 0x2884d79d , 0x0469cc0f , 0xa4521fc9 , 0x261bdbb1 , 0x356b870d , 0x01de8d76 ] )
+  , Mont::unsafe_make( [ 0x88fa7974 , 0x69e3a86c , 0x0f822531 , 0x5bfef5b0 , 0x77c47fb3 , 0xa0af3786 , 0xa0b7824b , 0x1a9e8c5c ] )
+  , Mont::unsafe_make( [ 0xbc009d54 , 0xe0b80abf , 0x3ab8b6be , 0x63528192 , 0x1efc5a20 , 0x7ddf336a , 0x8a8d409f , 0x115cf635 ] )
+  , Mont::unsafe_make( [ 0x25e7cce2 , 0xdfa9265f , 0xab05fce7 , 0xb8356298 , 0x7ee2f44a , 0xd774a1e9 , 0xf0b3696b , 0x2806c523 ] )
+  , Mont::unsafe_make( [ 0x07908145 , 0x81577df6 , 0xdabfae0c , 0xdeb3a1ba , 0xffc17ec1 , 0xdd113ca1 , 0x38b23b56 , 0x10f24dc5 ] )
+  , Mont::unsafe_make( [ 0xcb587059 , 0x1b581ee9 , 0xbb325721 , 0x5c11b111 , 0x4071def7 , 0x25152ab9 , 0xd18434b9 , 0x1d7c5f75 ] )
+  , Mont::unsafe_make( [ 0x026f95ab , 0x3d101f19 , 0x990e2313 , 0x5586b075 , 0xbb7d74b9 , 0xed3b49d0 , 0x2f2cd84b , 0x161d889c ] )
+  , Mont::unsafe_make( [ 0xd959cddc , 0xf84e6de9 , 0x287ce553 , 0x02a1d8c3 , 0x985ed4fa , 0xffea473f , 0x079f925d , 0x20fbafc1 ] )
+  , Mont::unsafe_make( [ 0xfd50e1cb , 0x0b21bc90 , 0x7d644d9e , 0x82d70758 , 0xb3355f9e , 0x5506c3c3 , 0x7de83b52 , 0x0b8828b1 ] )
+  , Mont::unsafe_make( [ 0x40db7c8e , 0x0f9a65f9 , 0x20aba224 , 0xf7df5c52 , 0x281b2719 , 0x0de0227a , 0x9ca6f976 , 0x13eb2fd0 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xba3ca444 , 0x5fcb5b8b , 0xd08b35b6 , 0x29979565 , 0x1c25f0c7 , 0x8c7f9282 , 0x4e342c9f , 0x2f266e09 ] )
+  , Mont::unsafe_make( [ 0x38599315 , 0xc8e4432d , 0xa55422f6 , 0x712e9408 , 0xb4930b6a , 0x8e0319e8 , 0x08c69d89 , 0x1f7a51d1 ] )
+  , Mont::unsafe_make( [ 0x919fa68a , 0x02f87583 , 0x1f0a9105 , 0xe1b0bb6f , 0x6508175f , 0x6df344ae , 0x689821a3 , 0x23e497bd ] )
+  , Mont::unsafe_make( [ 0x2d9b1264 , 0xabae3fd7 , 0x59fe42ac , 0x4edf4c83 , 0xe1882cb5 , 0xef350171 , 0xb439681f , 0x102f3bb2 ] )
+  , Mont::unsafe_make( [ 0xfa8635b9 , 0x337ce4b5 , 0x1c639aa2 , 0x01250456 , 0x2660be54 , 0x35d7f37f , 0xe3a60a1a , 0x23ff2b8e ] )
+  , Mont::unsafe_make( [ 0x3d8b42f5 , 0x31fba701 , 0xb5b017d6 , 0x4d23c93f , 0xef41bba8 , 0x5bc6f355 , 0x60d7dec7 , 0x07e4b081 ] )
+  , Mont::unsafe_make( [ 0xa245c34d , 0x7ae30504 , 0xa7a1e211 , 0x9b909c45 , 0x3cd3215e , 0x6bfd3351 , 0xfd33c5f2 , 0x1817e0d8 ] )
+  , Mont::unsafe_make( [ 0x1f983b01 , 0x2aa8cb0c , 0x8dc98ebd , 0x7b253846 , 0xfda9f76e , 0x4a3e53b5 , 0x0bc8af3b , 0x296787b6 ] )
+  , Mont::unsafe_make( [ 0x2de76a2b , 0xb4ef1cb4 , 0xd711a4f2 , 0xa7728345 , 0x6aa9ae2a , 0x4eb6f2bb , 0xc3b698a6 , 0x2da8f6aa ] )
+  , Mont::unsafe_make( [ 0xa1a1003c , 0xe604b544 , 0xa3543cef , 0xc522d468 , 0x1581fb76 , 0xdcaa038b , 0x4cdc00d2 , 0x21e44d14 ] )
+  , Mont::unsafe_make( [ 0x6057b715 , 0x7e87bc0d , 0x80acc7f5 , 0xb08ef4b8 , 0x5107a461 , 0xcfef8061 , 0xc6784df2 , 0x058f0d24 ] )
+  , Mont::unsafe_make( [ 0x5e9ecde6 , 0xcfe6c46b , 0x54af09ef , 0x704de688 , 0x3219e054 , 0x56cb0d7e , 0xcfeab45d , 0x07d96ca5 ] )
+  , Mont::unsafe_make( [ 0x60e65ada , 0xb6631012 , 0x078fea6d , 0xaaf8ba8f , 0xc5ef8413 , 0xe89cc176 , 0x1e218ce3 , 0x200bf29c ] )
+  , Mont::unsafe_make( [ 0xc5f3baca , 0x2c83fac0 , 0xc2c46b12 , 0x8f3c9a6f , 0xd3212d29 , 0x1447b0a8 , 0x8f25fb7c , 0x0ca5bb22 ] )
+  , Mont::unsafe_make( [ 0xacdf3001 , 0xed2d345e , 0x6ff92ef7 , 0x4fc7da09 , 0x192aff7d , 0x6df047b6 , 0x14a6052a , 0x0f995a33 ] )
+  , Mont::unsafe_make( [ 0xf9907b09 , 0x80ea8f62 , 0x5e994364 , 0xd1fb1a36 , 0xfcf26db7 , 0xe81a4b87 , 0x3d73f50e , 0x201c4481 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x78449211 , 0xa127223c , 0xcc1f02ca , 0x60d77767 , 0x7a98c846 , 0xd67f69c9 , 0x1195ba8e , 0x0380df0f ] )
+  , Mont::unsafe_make( [ 0xcd9c9de3 , 0xe1d45cff , 0x89a1c538 , 0xdc076fab , 0xf305a09f , 0x2ba67f49 , 0xef880174 , 0x12a263ab ] )
+  , Mont::unsafe_make( [ 0xe2bcf4cb , 0x8637cbad , 0xd6d26992 , 0x92afc56d , 0xfa47b5ca , 0x09897e2b , 0x7cd1a513 , 0x13e1fa89 ] )
+  , Mont::unsafe_make( [ 0xb9246704 , 0xb265f092 , 0xebdaa2f5 , 0x8d05b90e , 0x922b5793 , 0x6bae330d , 0xd367797b , 0x0e82e419 ] )
+  , Mont::unsafe_make( [ 0x65cdcefb , 0xbd3b7e50 , 0xc54ab8fd , 0x087866b8 , 0xfd892e8a , 0x7a4ef292 , 0x0411a4ae , 0x2c62a786 ] )
+  , Mont::unsafe_make( [ 0x8f89fb92 , 0xd4a8d997 , 0x77201c16 , 0xad6373fc , 0x8f703748 , 0x6630e7ac , 0xeda9698f , 0x2799ed77 ] )
+  , Mont::unsafe_make( [ 0x778dbd0f , 0x763ec363 , 0x98806014 , 0x0392b6db , 0x6df92010 , 0xae9e2f8e , 0xb7a659c5 , 0x0594c648 ] )
+  , Mont::unsafe_make( [ 0x9bad36f7 , 0x93f88711 , 0x9a648b36 , 0x55169140 , 0x3867b1da , 0x4de96804 , 0x423ad327 , 0x2190bfd1 ] )
+  , Mont::unsafe_make( [ 0xd7b8f496 , 0xabc52103 , 0xfb360a78 , 0x6d5c4fb9 , 0x6b9a0f0d , 0x3d6133c5 , 0x672b8977 , 0x2fe8ebf9 ] )
+  , Mont::unsafe_make( [ 0x1a51eece , 0x734ae218 , 0x1022333d , 0x1b43ec5f , 0x20fcbed8 , 0xa3688fa0 , 0xa058ab13 , 0x22e1ed40 ] )
+  , Mont::unsafe_make( [ 0x223f6852 , 0x2953a37d , 0x6087c892 , 0xfda0dc4e , 0x8442c456 , 0xe92fed7b , 0x8c1b8625 , 0x066dc44b ] )
+  , Mont::unsafe_make( [ 0x241fc865 , 0x775edce4 , 0xa534f9b5 , 0x265eda21 , 0x4b95224e , 0xe72d9522 , 0x8ec09716 , 0x2bc45002 ] )
+  , Mont::unsafe_make( [ 0x7cb05f3f , 0x2156948e , 0x0a355265 , 0x2aba5876 , 0xcf141196 , 0x1b959f52 , 0xeffba0d8 , 0x14c0dda1 ] )
+  , Mont::unsafe_make( [ 0xa6548f06 , 0xb690c140 , 0x7430749c , 0x55de7d28 , 0x7cf45404 , 0x08619e18 , 0x79547117 , 0x06b4176d ] )
+  , Mont::unsafe_make( [ 0xa84de5d9 , 0x56ca7939 , 0xc9f9c22f , 0x83f04f66 , 0x6b949ea9 , 0x11798d40 , 0x82157f0c , 0x0b4b71cb ] )
+  , Mont::unsafe_make( [ 0x3a4d17aa , 0x7e55a814 , 0x64bcc58a , 0x567683f9 , 0x088f1c99 , 0x1806adf7 , 0x4d09970a , 0x022d22de ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xedecce67 , 0xe39ce0ec , 0xddc5cc2a , 0xcbad37eb , 0x3b987539 , 0xd3cdab1b , 0xe2beded2 , 0x1263e120 ] )
+  , Mont::unsafe_make( [ 0xdeef823b , 0x46f59f12 , 0x04af52f1 , 0x7ab92d59 , 0x4ef873f3 , 0xb4c694ce , 0x125f7874 , 0x29b825a4 ] )
+  , Mont::unsafe_make( [ 0x477ef2e6 , 0x1dc2a49d , 0xadd4f0a2 , 0xcedca1f9 , 0xc4fa143b , 0x217e79b0 , 0xf8c8ae98 , 0x2b625dc6 ] )
+  , Mont::unsafe_make( [ 0xd74661eb , 0xbb4147fc , 0xe0869118 , 0x0a475cf4 , 0xb7f30b9b , 0x2792f75a , 0x2194ba74 , 0x1a9480b4 ] )
+  , Mont::unsafe_make( [ 0xf3f4aead , 0x7bf80510 , 0xa80d56b9 , 0xb674e831 , 0x91c303f0 , 0x9e7074cf , 0x1749e20b , 0x1c951848 ] )
+  , Mont::unsafe_make( [ 0x8e1c30a0 , 0x58caaa84 , 0x2d599ebf , 0xe4da769d , 0x54f2579a , 0x46e8d5c3 , 0x2bbd69f4 , 0x0099efc6 ] )
+  , Mont::unsafe_make( [ 0x5f7ce710 , 0x7cb9e85d , 0xe1f8d71c , 0x28dc81dc , 0xfd433c3e , 0x2ba2a28f , 0xdb36ea21 , 0x13d623ac ] )
+  , Mont::unsafe_make( [ 0xd16b7155 , 0x2ddbf575 , 0x51d3fc66 , 0x22c720dd , 0x508075ff , 0xd3837202 , 0x51019732 , 0x133bce2c ] )
+  , Mont::unsafe_make( [ 0xdfb65a2a , 0x3d575ae4 , 0x34327b7b , 0x0e58b20f , 0x67ae29ca , 0xa59bb492 , 0x73806614 , 0x2e4ea8f2 ] )
+  , Mont::unsafe_make( [ 0x1f8429f1 , 0xd49bb79e , 0xd47f5d75 , 0xa9d51978 , 0xdfbf2ee2 , 0xe3c1fa04 , 0xb7f1e9f8 , 0x0724c42f ] )
+  , Mont::unsafe_make( [ 0x7ad6bc1d , 0x3a0c7aa8 , 0x3c0228f3 , 0xe1fb466c , 0x4511085a , 0x966bb183 , 0xf241c4a8 , 0x26438941 ] )
+  , Mont::unsafe_make( [ 0x57995fa6 , 0xb38fec99 , 0xd0b55bea , 0x6bc62769 , 0xa735377c , 0xb48d227e , 0xb55c02d2 , 0x0c350894 ] )
+  , Mont::unsafe_make( [ 0x707d5cc5 , 0xd64d0dd7 , 0xe0720d57 , 0x8f70a615 , 0x13a341f6 , 0x46079496 , 0x534ce174 , 0x18289727 ] )
+  , Mont::unsafe_make( [ 0x33a82156 , 0xfb7164dd , 0xb570b77e , 0x704948b0 , 0x659ba751 , 0x42d6ad65 , 0x8976b563 , 0x073b87ba ] )
+  , Mont::unsafe_make( [ 0x1954a1c9 , 0x402a165f , 0x14e53723 , 0x891c1e91 , 0xaedca978 , 0xb3c7e109 , 0x9286afd6 , 0x2840f21e ] )
+  , Mont::unsafe_make( [ 0x36124e53 , 0x233b9ebf , 0x8f5fe488 , 0x237abd2d , 0xa43a45ee , 0x54bc3913 , 0x6329f6ee , 0x1cbabd45 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xe93b5d5e , 0x7ab84192 , 0x7f53173a , 0x5befabe4 , 0x23a9d07f , 0xdbf3ee0c , 0xdc5e31ea , 0x229d46c5 ] )
+  , Mont::unsafe_make( [ 0x8b6c6e55 , 0x27258f55 , 0xee2becbb , 0xe21f3313 , 0x0f2dcabc , 0x0235e62d , 0x5e665781 , 0x20c87209 ] )
+  , Mont::unsafe_make( [ 0xfdbac0f5 , 0x8b1f128e , 0x0ce2eaf3 , 0x1fbc15ab , 0x3c8bf558 , 0x96064174 , 0x52067c9d , 0x1089e898 ] )
+  , Mont::unsafe_make( [ 0x749e08a7 , 0x30af1c10 , 0x9869526c , 0xdfdc5a3d , 0x481ebad1 , 0x22e77fdb , 0x755f4369 , 0x1e95f058 ] )
+  , Mont::unsafe_make( [ 0x2d29562a , 0x53646d15 , 0x2d49fabc , 0x8ffb8a50 , 0xcdd44640 , 0x872818e2 , 0xbc39251c , 0x1291e855 ] )
+  , Mont::unsafe_make( [ 0xd2f029e8 , 0xe3bdeba2 , 0xcbc5342c , 0xd0c4e715 , 0x58b4bc6a , 0xbcea2d60 , 0x20d66cc6 , 0x1d9b2704 ] )
+  , Mont::unsafe_make( [ 0xf62af76f , 0x3eea3288 , 0xae07e3b6 , 0x43db317d , 0x10036a18 , 0x267edd2c , 0x5f4cb6fe , 0x1cc18cfd ] )
+  , Mont::unsafe_make( [ 0x1a11c6d8 , 0xc3bbfec7 , 0xb11dbe32 , 0x8972a993 , 0x2db6b1bd , 0xb2f519de , 0x009f0c6f , 0x2abdc810 ] )
+  , Mont::unsafe_make( [ 0xbf22f6ac , 0x607d0871 , 0x21d78138 , 0xc9b2b033 , 0xed55e5a5 , 0xf8001a18 , 0x2f2b8da4 , 0x0acacd04 ] )
+  , Mont::unsafe_make( [ 0x5ab0358a , 0xd696efc6 , 0x4d19d7ea , 0xbf45249c , 0xca0561f3 , 0x86f2956a , 0x014287a4 , 0x287d65c4 ] )
+  , Mont::unsafe_make( [ 0xfa6572cc , 0x5b724e7e , 0xb3ba9bc1 , 0x6970b48d , 0x66e71288 , 0x05d2c0ad , 0xe138baaf , 0x06003603 ] )
+  , Mont::unsafe_make( [ 0xee12a18e , 0x75ca2fa7 , 0x945e8be1 , 0x79c3a97f , 0x0217dbf9 , 0x32407712 , 0xb7662e1f , 0x0cd32ad4 ] )
+  , Mont::unsafe_make( [ 0xdd8610fc , 0xfd68e640 , 0xe2876eb1 , 0xafd98171 , 0x69b5d00d , 0x24dbf82e , 0x7bc6f986 , 0x09240fd3 ] )
+  , Mont::unsafe_make( [ 0xcde46605 , 0x09e5a7cf , 0x8de10de5 , 0xa65067ee , 0xe5c812cd , 0x9d1a1d57 , 0xd9b46caa , 0x287794e7 ] )
+  , Mont::unsafe_make( [ 0x1af49cd5 , 0xaf7d346e , 0x67d8cdff , 0x9f68eb8b , 0x4229a5b8 , 0xd09fbdd9 , 0xa8baead9 , 0x03671c2e ] )
+  , Mont::unsafe_make( [ 0x301797ec , 0x12998131 , 0xa4cf7acc , 0xb7892a39 , 0x019cc94d , 0x520bec55 , 0x96d220fd , 0x0f618302 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xe53f7429 , 0xa87e3312 , 0xfc49fde6 , 0x5d9c2c0e , 0xba1a3f86 , 0x354f80d2 , 0x853758dd , 0x0d7a6695 ] )
+  , Mont::unsafe_make( [ 0x1bb231ef , 0x539a63c8 , 0xd812c131 , 0xba636d6f , 0x7817f3b7 , 0x891d20ea , 0x63d43e8e , 0x14144f5c ] )
+  , Mont::unsafe_make( [ 0xa0310934 , 0xfdbab555 , 0x26f3a402 , 0x7ccaacaa , 0xe8d3ab0b , 0x78c6bd49 , 0x4a2c30af , 0x175fae80 ] )
+  , Mont::unsafe_make( [ 0x6d0d8c7a , 0x7f8f860c , 0x607a85d6 , 0x6f04a898 , 0x7f2b1a7b , 0x402ff680 , 0x56e297c2 , 0x2e4e6c36 ] )
+  , Mont::unsafe_make( [ 0xb4947bc1 , 0xf5238a04 , 0x2a2111d7 , 0xa6fe2fcd , 0x0a63af7d , 0x75212a12 , 0x61f9b56f , 0x06ce7604 ] )
+  , Mont::unsafe_make( [ 0xe608b9ef , 0x1931fd19 , 0xb3daba13 , 0x271b4295 , 0x84d322fb , 0xbe147dfd , 0x50f23b29 , 0x2009915c ] )
+  , Mont::unsafe_make( [ 0x5027f539 , 0x831a29a0 , 0xf25561ad , 0x28101bad , 0x6a437ced , 0xfd209e76 , 0xe1141e04 , 0x1ac6c9af ] )
+  , Mont::unsafe_make( [ 0x03a043fb , 0xe56205f0 , 0xe57b7ea1 , 0xbe04cf0d , 0x130f4684 , 0xce19b1da , 0x6da05692 , 0x01745fa4 ] )
+  , Mont::unsafe_make( [ 0xaf3c3bf2 , 0x0fb4edf9 , 0x19d4ecc2 , 0x62e1ddad , 0xadaec26d , 0x24fe10e1 , 0x5649ffe2 , 0x0abf1753 ] )
+  , Mont::unsafe_make( [ 0xa316df63 , 0x63f54afb , 0x1fb5436e , 0xac12e5bf , 0x7f22a29c , 0xc4ede20d , 0x1de120c6 , 0x13935145 ] )
+  , Mont::unsafe_make( [ 0x9dbe1d19 , 0x52503b2e , 0xdc2b4be6 , 0xffb0b6f0 , 0x974b7192 , 0x2f1c1a26 , 0x0f0b1d03 , 0x0602e1fa ] )
+  , Mont::unsafe_make( [ 0x62d97d6f , 0x6b88cd93 , 0xf045abde , 0x5f006562 , 0xc56e8531 , 0xeec1bfac , 0xa7f4c545 , 0x16b0bf95 ] )
+  , Mont::unsafe_make( [ 0xd467d008 , 0xd4cc7bc3 , 0xbf9e256e , 0x9c797577 , 0x2ad85e70 , 0x59a79a6c , 0xfac4d3f0 , 0x03775585 ] )
+  , Mont::unsafe_make( [ 0xed33a26c , 0xea8482ce , 0x4d38e913 , 0x83feef88 , 0x2e5a2bd4 , 0x84c28c81 , 0xce2bbdca , 0x0b65260d ] )
+  , Mont::unsafe_make( [ 0x76316cdc , 0x446924b8 , 0x63faed5d , 0x97e9a21f , 0x877b3234 , 0xfc2a3222 , 0xafba7bbd , 0x116d5e43 ] )
+  , Mont::unsafe_make( [ 0x1414ff88 , 0xe0d499c3 , 0x4d72e6a6 , 0xf148ddd1 , 0xa9225353 , 0x05ff5eef , 0x469019ba , 0x28f12a15 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x6f4df0d3 , 0xc06388cb , 0x2ec2a244 , 0xefff0a8f , 0xa6e6653d , 0xf922dfbc , 0x75ba0f88 , 0x2062d165 ] )
+  , Mont::unsafe_make( [ 0x58f4ba01 , 0x0a005499 , 0x276d4e9a , 0xdd4a705d , 0xdf813544 , 0x44d58a07 , 0x96633d42 , 0x1b72ff1a ] )
+  , Mont::unsafe_make( [ 0x5a752cc7 , 0x5cfc9f36 , 0x323afe40 , 0x2f19987f , 0x478b5731 , 0x333950b1 , 0xd72066b8 , 0x1161484f ] )
+  , Mont::unsafe_make( [ 0x6995dc81 , 0xd8b4647d , 0x07393b42 , 0x35e649cb , 0x250b6bb8 , 0x0fa1b1f2 , 0x9f9c9d93 , 0x14ff8212 ] )
+  , Mont::unsafe_make( [ 0x450cc576 , 0xef938233 , 0xb30ddea6 , 0x19f3ce91 , 0xe87d643f , 0x00282ce0 , 0x0487f57a , 0x07b4efbd ] )
+  , Mont::unsafe_make( [ 0x58b5f712 , 0x4eefff10 , 0xfe739385 , 0xfb64bc32 , 0x4c037f65 , 0xdd63cdb3 , 0x1fdbe3ee , 0x0ac7ba06 ] )
+  , Mont::unsafe_make( [ 0x151a13f6 , 0x43356453 , 0xcd18ffee , 0x3125881a , 0x0ae9e174 , 0x3b0bd09f , 0xa889dc64 , 0x2b5e0e6e ] )
+  , Mont::unsafe_make( [ 0x68d88a8e , 0x975808c6 , 0x4927f424 , 0x39ac679a , 0x354e9729 , 0xca688f7a , 0xc9a36ad9 , 0x126cc60f ] )
+  , Mont::unsafe_make( [ 0xbf183000 , 0x24ef0742 , 0x8b3cca37 , 0x19cc2c51 , 0x2a5499f4 , 0xae9d5ae0 , 0x0f8a6382 , 0x24e90a3a ] )
+  , Mont::unsafe_make( [ 0x11dea015 , 0x6524f0b2 , 0x40fc035d , 0x04920a8c , 0x49a229fe , 0x1aa4054b , 0x61ff6da5 , 0x0f09e164 ] )
+  , Mont::unsafe_make( [ 0x832131a1 , 0x503b1d2e , 0x67667004 , 0x3eb7a65c , 0xb665ea02 , 0xca8ff982 , 0xad6e7b33 , 0x1a3db767 ] )
+  , Mont::unsafe_make( [ 0xe0db975c , 0xbc6024fb , 0x7425cc76 , 0x95300e68 , 0x947365c1 , 0xb1c1a1da , 0x30adf201 , 0x23b82b63 ] )
+  , Mont::unsafe_make( [ 0x89b51d8a , 0xba5568d4 , 0x6976bae5 , 0x0d6fcb41 , 0x4a29ef24 , 0xeddbc7a6 , 0x7d734ab9 , 0x24895b90 ] )
+  , Mont::unsafe_make( [ 0x43fe037a , 0xb80500dc , 0x5becfef5 , 0x3eb7e296 , 0x485aa8df , 0xe15a65aa , 0x7fd02427 , 0x13f41303 ] )
+  , Mont::unsafe_make( [ 0x01952a74 , 0x1d08715d , 0xf263890a , 0xda15fa76 , 0x18a1ee78 , 0xc95d28e1 , 0xa8912f61 , 0x1ec343e0 ] )
+  , Mont::unsafe_make( [ 0x47fc0d6a , 0xd03c80cf , 0x434ae56b , 0x8fdcd212 , 0x55f34754 , 0x0aeac16d , 0xd592c5a4 , 0x01fbf34f ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xaf0cdbc9 , 0x00711e77 , 0x82402d13 , 0xfaf072a9 , 0x5058a82f , 0xcb923087 , 0xf568b5fd , 0x0a43f455 ] )
+  , Mont::unsafe_make( [ 0x89518360 , 0xaf8b71f6 , 0x60b6022e , 0x5e5621e2 , 0xae383e36 , 0xeca560e8 , 0x23828d5a , 0x1f9cf75c ] )
+  , Mont::unsafe_make( [ 0xa276271e , 0x3aebdeb0 , 0xbcccc7be , 0x5e275c21 , 0xc0c6006e , 0x96aeb4da , 0x840548a3 , 0x028de3b4 ] )
+  , Mont::unsafe_make( [ 0x330f0fc6 , 0x470dcfb0 , 0xfeeef85d , 0x7e3c7f37 , 0x42bdae69 , 0xf8543f2a , 0xd13da3df , 0x203bf1ef ] )
+  , Mont::unsafe_make( [ 0x5163a1a6 , 0xc051b9eb , 0x7ddf27d8 , 0xbe49180c , 0xf382b411 , 0x7a8ad43e , 0x4d770723 , 0x13b49972 ] )
+  , Mont::unsafe_make( [ 0x7155195f , 0x92f4b059 , 0xd83880c2 , 0x6deddbe9 , 0x3f455e4b , 0xa293b344 , 0x33ef20e2 , 0x02e4c1cc ] )
+  , Mont::unsafe_make( [ 0xee817d61 , 0x401861d5 , 0xe4660a45 , 0x4683671d , 0x0ff8df09 , 0x42f60c31 , 0xd821c310 , 0x1ba4c945 ] )
+  , Mont::unsafe_make( [ 0x33fbb841 , 0xf42d840e , 0x60624b15 , 0xa0238d7d , 0x3d7c40a5 , 0x04ed22a3 , 0x2469479b , 0x074ccffe ] )
+  , Mont::unsafe_make( [ 0x47726897 , 0xf097a1c8 , 0x4fe55b2c , 0x53acb4f7 , 0x5665c05d , 0x164980c0 , 0xbfa2bacd , 0x20c7ee2d ] )
+  , Mont::unsafe_make( [ 0x8d95c3b1 , 0xa38b9ebd , 0x438b7e54 , 0x4f0d23f5 , 0xbd5256ed , 0xf3960f1c , 0xe0335632 , 0x0cd36979 ] )
+  , Mont::unsafe_make( [ 0xbdedff3b , 0x302a07b4 , 0xc9aa71a8 , 0x3fec9316 , 0x66fb5c8f , 0xd2f21a66 , 0x5d353123 , 0x2e9ce833 ] )
+  , Mont::unsafe_make( [ 0x7b472a4e , 0x2a87d5aa , 0xdcb1433f , 0xb78f1bb9 , 0x669234aa , 0x87407366 , 0x2b7eb9cc , 0x17778d7f ] )
+  , Mont::unsafe_make( [ 0x9bad5f7a , 0xa8696805 , 0xbf68d33d , 0x92d8f8bd , 0x54e3bfdb , 0xbf86e392 , 0xe32aba6b , 0x2ca1d9a4 ] )
+  , Mont::unsafe_make( [ 0x791687d6 , 0x42298c6e , 0x1448f41c , 0x7b8989b2 , 0x76dc2c63 , 0x7e683031 , 0x6cffa5e3 , 0x00cae0f9 ] )
+  , Mont::unsafe_make( [ 0x3dbb6a6e , 0xcd0a4618 , 0xd7530222 , 0xbadffdba , 0xa4ffc5c7 , 0x32e4f6be , 0xfac13075 , 0x00c9c621 ] )
+  , Mont::unsafe_make( [ 0x5f87220f , 0xb1b6651e , 0xb7a7ae77 , 0x94625b57 , 0x4d1be30e , 0xc098f036 , 0x89071203 , 0x069628a7 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x891b9124 , 0x0a95727d , 0xd8adac53 , 0x95623798 , 0x0ea0c006 , 0xd29600ab , 0xe148d516 , 0x0e31967d ] )
+  , Mont::unsafe_make( [ 0xf14c4e16 , 0x28b5898e , 0xd33ab219 , 0xb7de9e1f , 0x0bca310a , 0x0818841b , 0x7f5d80f0 , 0x083948f4 ] )
+  , Mont::unsafe_make( [ 0x85f292b6 , 0x9e95fd3e , 0x3db75a51 , 0x6d975053 , 0x165e2dcb , 0x0f2ed2b2 , 0xe0454291 , 0x1f5741db ] )
+  , Mont::unsafe_make( [ 0xe74d0c96 , 0x155e81e6 , 0x8c840a75 , 0xa62852b1 , 0x6a2f46a7 , 0x19fb9a62 , 0x50b1b57c , 0x083f2237 ] )
+  , Mont::unsafe_make( [ 0xc97abbe3 , 0x22fafa90 , 0xe93e1560 , 0x2d920bae , 0x4bfe8f6a , 0xf6745b4a , 0x86fb08af , 0x2c13e73a ] )
+  , Mont::unsafe_make( [ 0xbc2f316a , 0x6f0f7a5a , 0x9ef9501c , 0x734d5eb7 , 0x18d14e9f , 0x3ec8758e , 0xefee4076 , 0x140bccba ] )
+  , Mont::unsafe_make( [ 0x39101fa2 , 0xef90da0e , 0x400d35f2 , 0x868a7d6c , 0xeba9a6f5 , 0x3e376a09 , 0x931db067 , 0x09a94f27 ] )
+  , Mont::unsafe_make( [ 0x10687ebe , 0x02885eb1 , 0x9c62c17f , 0x18b6bbd5 , 0x2764edb7 , 0xbc67bd35 , 0x06030c73 , 0x10256dcf ] )
+  , Mont::unsafe_make( [ 0x18507a46 , 0xb0a91285 , 0xaae49fd0 , 0x3de3c0b9 , 0x1cdda849 , 0x7ba4c7f6 , 0x9f85b50a , 0x033fa959 ] )
+  , Mont::unsafe_make( [ 0xd26ff318 , 0xc0039b4b , 0x74d405d6 , 0x96d4a890 , 0xe747e59d , 0xb447b7e4 , 0x14828f90 , 0x3015589f ] )
+  , Mont::unsafe_make( [ 0x2b5b26c3 , 0x4979fe6b , 0x7fd1de23 , 0xe8e9c781 , 0x7de086fa , 0x56082d2c , 0x8b5a8682 , 0x10c945b8 ] )
+  , Mont::unsafe_make( [ 0x3e027ea0 , 0x060a546c , 0x8c9cb575 , 0x4a79a676 , 0x931cb3d3 , 0x29f97207 , 0xd3774a85 , 0x2736a2f9 ] )
+  , Mont::unsafe_make( [ 0x444fdfe6 , 0xa8775caa , 0x816b11d4 , 0x16a6e741 , 0x3ac184a4 , 0x3a43fc91 , 0x8efb0bf2 , 0x2cf1096f ] )
+  , Mont::unsafe_make( [ 0x6836997d , 0x27624f7d , 0xf2405ca5 , 0xe2c0a8e3 , 0xc7bbfdde , 0x7ed05a23 , 0xd68fde9e , 0x1119df80 ] )
+  , Mont::unsafe_make( [ 0x6966a252 , 0x671a5e99 , 0x388d8c54 , 0xa46397d8 , 0x116cebff , 0xb968badf , 0x60e26664 , 0x230bbc48 ] )
+  , Mont::unsafe_make( [ 0x7771428e , 0x93b01942 , 0xafa22f6f , 0xde7d2ad4 , 0xca37b7aa , 0x57da60cb , 0x33d78f03 , 0x2b0d36a0 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x86f24630 , 0x1faac89a , 0xb6272e79 , 0x38e7b6f7 , 0xd3e86506 , 0xf01f9b94 , 0x60b9ae01 , 0x1ee70853 ] )
+  , Mont::unsafe_make( [ 0xb07c620d , 0xbe05295a , 0x88035701 , 0x04a47798 , 0xec4afce2 , 0xe535503d , 0xdd56be9b , 0x09876fc4 ] )
+  , Mont::unsafe_make( [ 0x4a732348 , 0xd3307640 , 0xc05e1b79 , 0xc12b19c6 , 0x800f560f , 0x0a4ce978 , 0x9b20a842 , 0x1ed33bb9 ] )
+  , Mont::unsafe_make( [ 0x7605551b , 0xd5b6c600 , 0x986a23ed , 0xbeaf309f , 0x4619bf31 , 0xb62eac25 , 0xc1a417b1 , 0x1a854153 ] )
+  , Mont::unsafe_make( [ 0xdc032adf , 0xaebc91f9 , 0x9ac569b7 , 0x14b8e7e4 , 0x1439dd2e , 0xcfcf81b9 , 0x6e002e57 , 0x1418d4e4 ] )
+  , Mont::unsafe_make( [ 0xf035a756 , 0xf0a48fed , 0xb28655df , 0x2d2aaf48 , 0xdb93b86a , 0x9b7427f6 , 0x7e56cb82 , 0x0d2d14ca ] )
+  , Mont::unsafe_make( [ 0xbc55696b , 0x847f91e5 , 0x7c949995 , 0x1da54be4 , 0xdfc4a5a9 , 0xb59beaff , 0xc790741f , 0x04588f94 ] )
+  , Mont::unsafe_make( [ 0x92467681 , 0xf02f3a30 , 0x97917b58 , 0xb03f7e90 , 0x21b52e05 , 0x3c5822f6 , 0x7f9c5598 , 0x04a38207 ] )
+  , Mont::unsafe_make( [ 0xfb59d131 , 0xc866987a , 0x7b52c2f9 , 0x401d690f , 0x9a1b48b2 , 0x2b948dc2 , 0xd57b52db , 0x0f99ff20 ] )
+  , Mont::unsafe_make( [ 0x6194d348 , 0x61b1380d , 0x98be9911 , 0x484f2243 , 0xbe536265 , 0x347eb806 , 0x4826e3ba , 0x06397231 ] )
+  , Mont::unsafe_make( [ 0xa79e5dd5 , 0x1f84b1e7 , 0xf443a04e , 0x8ceab3fb , 0xe80ed152 , 0x23c536ac , 0x0453f7ca , 0x1e0e8855 ] )
+  , Mont::unsafe_make( [ 0x39e146d6 , 0x3e96e3fc , 0xa9bf1f0c , 0x078bc2d2 , 0x21f5b157 , 0x30d8e519 , 0xca95e75a , 0x11b50c09 ] )
+  , Mont::unsafe_make( [ 0x32434789 , 0x659bf549 , 0x88d0ea8d , 0x1ac7aae2 , 0x92dc769c , 0x630c74ef , 0x6d693c44 , 0x2e32ca17 ] )
+  , Mont::unsafe_make( [ 0xa2dc32cc , 0x516402bb , 0x48ce2e1f , 0xd5179103 , 0xd75d3023 , 0xcd6f36e5 , 0xae4ff035 , 0x15e93e31 ] )
+  , Mont::unsafe_make( [ 0x75161562 , 0xb4f2ef78 , 0xef2108a0 , 0x48bd4327 , 0x0398c4fe , 0x8da35cec , 0x4463da8d , 0x25e9366d ] )
+  , Mont::unsafe_make( [ 0xd8f90643 , 0x9d230bd0 , 0x1ce18a96 , 0x6c7236cf , 0x9e185945 , 0xa0b3869b , 0xc76fa364 , 0x27b3b702 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xf37c2d07 , 0xa4eff7dc , 0x6b002f19 , 0x93d5f1ef , 0xedb5ddd7 , 0x19091805 , 0xe2a9b58e , 0x151cc61e ] )
+  , Mont::unsafe_make( [ 0x2a0c0a9a , 0x5b8b989d , 0xf4f15c22 , 0x1ce1fc59 , 0xfe5b376b , 0x2bf64e9a , 0x8b9dc8b7 , 0x22952484 ] )
+  , Mont::unsafe_make( [ 0xa7afc8c9 , 0x9b7fa70e , 0xeda91ea6 , 0xbec73ae2 , 0x37bcd947 , 0x105d95b0 , 0xa60a02d4 , 0x0e67323d ] )
+  , Mont::unsafe_make( [ 0xa24faca4 , 0xdefbaf2c , 0x1758b6ff , 0xdf1a1639 , 0xb770e914 , 0x57f8b837 , 0xa21cb1c5 , 0x1200119f ] )
+  , Mont::unsafe_make( [ 0xe6782bce , 0x3d5e4ecf , 0x7427b830 , 0x77e90b07 , 0xb3d590bf , 0x5b7e9d6f , 0x6dc8d6c1 , 0x161bfb3b ] )
+  , Mont::unsafe_make( [ 0x76329125 , 0x06a3c607 , 0x8c5c8bfb , 0x292dd31a , 0x3cf3353b , 0xe451272e , 0x2d032034 , 0x22931094 ] )
+  , Mont::unsafe_make( [ 0x7308666b , 0xef29bad5 , 0x3cbd0edc , 0x0225fd1a , 0x4099db23 , 0x7548eb17 , 0x118735cd , 0x0c855a94 ] )
+  , Mont::unsafe_make( [ 0xda69ab40 , 0x76a3c278 , 0xb57ebbc0 , 0x000b1d53 , 0x887211e5 , 0x4ab46be9 , 0xa8340605 , 0x127bbbd5 ] )
+  , Mont::unsafe_make( [ 0xf5505e7a , 0xfc95e41b , 0xc653958f , 0x0a6aab30 , 0x8a215dac , 0x13214aba , 0xba8de3cf , 0x0ff91fa6 ] )
+  , Mont::unsafe_make( [ 0x3911fca2 , 0xe17d1f28 , 0x68a9d2a1 , 0xf0e12784 , 0x5608dac1 , 0xe4c6c7b8 , 0xf4be1481 , 0x1270d6f7 ] )
+  , Mont::unsafe_make( [ 0x9b290e67 , 0x918a4de5 , 0xd55c6ea3 , 0xbace9e77 , 0x81aec130 , 0x7a37a98a , 0x3a8ecfc5 , 0x1e7e19da ] )
+  , Mont::unsafe_make( [ 0xc76efb14 , 0xbcabbecf , 0x9100475f , 0x4f607aba , 0xd2618e3d , 0x7abaee36 , 0xdd4d29ef , 0x1f1b00dc ] )
+  , Mont::unsafe_make( [ 0x128479fd , 0xedb2c6b2 , 0x03c58291 , 0x9f37393c , 0xb63dabfc , 0x4f3994f9 , 0xb8a5b34a , 0x077ef912 ] )
+  , Mont::unsafe_make( [ 0xa7487033 , 0x6758de27 , 0x3b05d5a4 , 0xb50f6ed3 , 0x69bf0bc1 , 0xeb2cf0f2 , 0x188dc36c , 0x19db8804 ] )
+  , Mont::unsafe_make( [ 0x54d1d002 , 0x82780542 , 0x3631a6f5 , 0x6ba0e039 , 0xadbba542 , 0xce002b2e , 0x5c2cb0e0 , 0x26a2029c ] )
+  , Mont::unsafe_make( [ 0x105e77c9 , 0xca0ac0b4 , 0x06c0ee40 , 0xb858ebb0 , 0x8926fca9 , 0x13444cd7 , 0xbc24407c , 0x301a6141 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x5fd1944e , 0xb72bb77d , 0x24d30158 , 0x858e6149 , 0x13a544fb , 0x1fb4bfd5 , 0xcb69587a , 0x0b98327b ] )
+  , Mont::unsafe_make( [ 0x407bf192 , 0x181f4e7f , 0x6270d9c2 , 0xe8c099ff , 0x33082f1c , 0xa81a08cc , 0xca6caa99 , 0x02742d1e ] )
+  , Mont::unsafe_make( [ 0x8ddd3a7f , 0xd81761a0 , 0xc8ebd8bc , 0x08450906 , 0xb65b15ef , 0xa62487a0 , 0x9e21b709 , 0x1757d5b7 ] )
+  , Mont::unsafe_make( [ 0x46baf6da , 0x99b23ba3 , 0xc4525206 , 0x2ecf8371 , 0x992a3919 , 0x436166b7 , 0xdbc1d65f , 0x26ebc397 ] )
+  , Mont::unsafe_make( [ 0x90efb16b , 0xd8647f0a , 0x5880bd4a , 0x42b7c680 , 0x7e8aaf8e , 0xbcfc05ab , 0xf997b3e7 , 0x26adaa10 ] )
+  , Mont::unsafe_make( [ 0x6d9502fc , 0x022e9de2 , 0xcde460f2 , 0xd0aae4ab , 0xdd2f2ba8 , 0x3b41e2e7 , 0x16f55515 , 0x06266a17 ] )
+  , Mont::unsafe_make( [ 0xf8063493 , 0xa022056f , 0x8eabe3c5 , 0xcfe1bfd5 , 0x4da1082c , 0xb492f679 , 0xab23b966 , 0x010e255f ] )
+  , Mont::unsafe_make( [ 0x74f24c36 , 0x7fab216d , 0xbe9a841d , 0x1b2b15f2 , 0xda36cda7 , 0x8909d1fe , 0x39cb185c , 0x1a5453d6 ] )
+  , Mont::unsafe_make( [ 0x1bfeee86 , 0x01227157 , 0xfda36d30 , 0xa3d70a39 , 0x982769b2 , 0xe85f8ea4 , 0xcf01e874 , 0x1abb1a05 ] )
+  , Mont::unsafe_make( [ 0x426314ed , 0xec6b7c9d , 0x2a4ffbf8 , 0x16070544 , 0x56c71922 , 0x07a8f421 , 0x472bf587 , 0x2cd40285 ] )
+  , Mont::unsafe_make( [ 0xc353528e , 0xaa1d6e3a , 0x11d72ca5 , 0x40aaa34f , 0xa1b9d1d2 , 0xd95442a3 , 0x0599734f , 0x2d7f0804 ] )
+  , Mont::unsafe_make( [ 0x21f0f4c6 , 0x57506ff0 , 0x084ca547 , 0x49e3c896 , 0x754ae5bf , 0x8c4f1814 , 0x9f410af9 , 0x2678982d ] )
+  , Mont::unsafe_make( [ 0xeea035c3 , 0x7617704b , 0xdc458d14 , 0xe8620de6 , 0x6fd74732 , 0x7ba34744 , 0xf94f689f , 0x0f831f8d ] )
+  , Mont::unsafe_make( [ 0x4de31bdd , 0xd88ddfb3 , 0xb50c01da , 0x867dc8bb , 0x252561bf , 0xaa94a613 , 0x0b91380a , 0x05a0cc06 ] )
+  , Mont::unsafe_make( [ 0x050e38a9 , 0x9e96a9f9 , 0x029d17ac , 0x19d90113 , 0x7f9ce11d , 0xbfd0421e , 0x65c5feca , 0x17bde21f ] )
+  , Mont::unsafe_make( [ 0x8673e457 , 0xeef90c30 , 0x3b3d730a , 0x98e684c5 , 0x2ad2ad3e , 0x8586cb4c , 0xe1eec335 , 0x11a1c899 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xd24f4d64 , 0xd5420dd2 , 0x1f5d8138 , 0x48ba7e42 , 0xd1c92bfa , 0x54ef3a0f , 0x840c2a5b , 0x04b2bec0 ] )
+  , Mont::unsafe_make( [ 0x7f8a9c7e , 0xf5e4b27e , 0x17548100 , 0x3dbd00d5 , 0x340164dc , 0x39362066 , 0xb75ce89b , 0x0d198f5c ] )
+  , Mont::unsafe_make( [ 0xa6fe74ac , 0xbf43ca2a , 0x8da584c0 , 0x1bd5bd88 , 0xc1cf7c51 , 0xb0bf8d39 , 0x52ec248a , 0x2180443a ] )
+  , Mont::unsafe_make( [ 0xbae3a273 , 0x802a5069 , 0x0d37857a , 0xf66b6685 , 0x717bb6c9 , 0x0fab961a , 0x74187cbe , 0x08b336ad ] )
+  , Mont::unsafe_make( [ 0xb654a808 , 0x3b6653ac , 0x24a15a99 , 0x6d636a24 , 0x6e9294a5 , 0x6b6513f4 , 0x9250aa93 , 0x1ee0a68f ] )
+  , Mont::unsafe_make( [ 0x626a9d75 , 0xe2c1b52f , 0xd55bcb83 , 0xc8328ea2 , 0x58d57fbc , 0x055038ec , 0x66f5f063 , 0x28004a73 ] )
+  , Mont::unsafe_make( [ 0xb10ea76c , 0x9b9b51ba , 0x7db12a72 , 0x8fcbce4d , 0x26beeb4c , 0xc97f7883 , 0x3f6e8291 , 0x126fadc6 ] )
+  , Mont::unsafe_make( [ 0x3a7d57a7 , 0x4f6b3009 , 0x40a2aac4 , 0xce1bad36 , 0xc1643b8c , 0x5fd50c7d , 0xd8107b2a , 0x09ec1bf3 ] )
+  , Mont::unsafe_make( [ 0xac19f790 , 0x0236df38 , 0x599ff26f , 0xcbb256f9 , 0x8849f8b7 , 0x104cfe1c , 0xcaa43bc3 , 0x17512b42 ] )
+  , Mont::unsafe_make( [ 0xa52be0b6 , 0x0c318c8f , 0xc10aa8a3 , 0xaea5d6b3 , 0x08cb26cb , 0xbe681fb2 , 0xd4c3e422 , 0x179a1d91 ] )
+  , Mont::unsafe_make( [ 0x358b5b64 , 0x80a4d647 , 0x4beb5dd4 , 0x503a1abe , 0x3ed48b62 , 0xca62ba33 , 0x9cbd8862 , 0x1d7c17c0 ] )
+  , Mont::unsafe_make( [ 0x718316a5 , 0x02591f21 , 0x625a5e3e , 0xd1650d32 , 0xd07d96ca , 0xeb8f64d4 , 0xebe084c5 , 0x2ddcda08 ] )
+  , Mont::unsafe_make( [ 0xa31b1af5 , 0x2b2f5850 , 0x1bacbee8 , 0x4bca45d8 , 0x4db784d1 , 0x9acce657 , 0x95b8a7de , 0x063b78ab ] )
+  , Mont::unsafe_make( [ 0xdc1630a0 , 0xa69ec2e2 , 0x380ba76a , 0x8f30f704 , 0xcbf36ab2 , 0xb4f3f6c4 , 0x683e6654 , 0x1069414e ] )
+  , Mont::unsafe_make( [ 0xef460b78 , 0xaa8e44a3 , 0x514a1833 , 0xf89b5487 , 0xa899fde5 , 0xf7b19bc1 , 0x07f99199 , 0x0124f67d ] )
+  , Mont::unsafe_make( [ 0x562ff753 , 0x40b3475b , 0x2fe09901 , 0x619fa60a , 0x426057f0 , 0x15438877 , 0x9a8ba0f9 , 0x193790f1 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xfe314d55 , 0x71a27fe6 , 0x5b2265ec , 0x691573f6 , 0xa6d8c028 , 0x4a6fdb25 , 0x71b100ab , 0x0ea0c604 ] )
+  , Mont::unsafe_make( [ 0x26e3c50f , 0xaa56bd93 , 0x5e7d102f , 0x7ed93fe3 , 0xb7d698cc , 0xa47ff983 , 0x6a9a0710 , 0x23859915 ] )
+  , Mont::unsafe_make( [ 0xb8e6154b , 0x45a02152 , 0x0f89b8aa , 0xe0e13aaf , 0xe822fcce , 0xf6d55653 , 0x8fa88f32 , 0x1b8c42f9 ] )
+  , Mont::unsafe_make( [ 0xcc454e32 , 0x0d692827 , 0xdd5a9274 , 0xf9d554a4 , 0x1f9bb122 , 0xbe16872f , 0xe91572a4 , 0x2c18da91 ] )
+  , Mont::unsafe_make( [ 0x15a99011 , 0xa53b8727 , 0xde17bd91 , 0x5b9cea7d , 0x735a889b , 0x92f25053 , 0x3c1a19a3 , 0x29a3b2df ] )
+  , Mont::unsafe_make( [ 0x80a1faf9 , 0xc1e389be , 0xcb2a8fd7 , 0x47f407f1 , 0xaa7b50c3 , 0x2d80b35e , 0xb29227fe , 0x30227f1a ] )
+  , Mont::unsafe_make( [ 0x91a7142a , 0xfb380735 , 0x0ef99243 , 0x3b2d7aa6 , 0x0b92d01d , 0xdd232c74 , 0xfde4b489 , 0x0d97156f ] )
+  , Mont::unsafe_make( [ 0x7ea4fe12 , 0x341b380f , 0xe89166d8 , 0x6e58c6c5 , 0x934f669c , 0x5484a0f0 , 0xa6351917 , 0x2931bc3a ] )
+  , Mont::unsafe_make( [ 0x797f4f97 , 0xb3bff396 , 0x0f9ba4b8 , 0xdf354c7b , 0xfb50588a , 0x5b9d28c8 , 0x3f834eb1 , 0x2e5a5731 ] )
+  , Mont::unsafe_make( [ 0xc69f837e , 0x641dab8a , 0x3c026e4c , 0x6168e0c3 , 0xab9294b2 , 0x9d6c28af , 0x6780df5a , 0x01c3f24e ] )
+  , Mont::unsafe_make( [ 0x58f5c0a7 , 0xd5c6dcee , 0x447934c4 , 0x67bd642e , 0x9f3ddea8 , 0x570fd192 , 0x56ea5683 , 0x020e4e3f ] )
+  , Mont::unsafe_make( [ 0xd62d03f1 , 0xb324f420 , 0x4a481818 , 0x95037446 , 0x917c590f , 0x2947bb92 , 0x7824ffea , 0x27dcebe6 ] )
+  , Mont::unsafe_make( [ 0x5916e612 , 0x31c91a96 , 0xe8671633 , 0xbff54ac8 , 0x359abc91 , 0x8a99a096 , 0x44c8bff1 , 0x25c44399 ] )
+  , Mont::unsafe_make( [ 0x4307a50c , 0x6aef738b , 0x03ce3217 , 0x1cd48f98 , 0x8e0c0655 , 0xc1c13be4 , 0x1499d9dd , 0x090feff6 ] )
+  , Mont::unsafe_make( [ 0xe5930748 , 0x3333bd50 , 0x1dd177ed , 0xb5b27882 , 0xb7d01d89 , 0x9f4082d1 , 0xfaef3877 , 0x187d555c ] )
+  , Mont::unsafe_make( [ 0x296753aa , 0x95e155e2 , 0x5cadf0f8 , 0xd8ef87de , 0x4053d1bf , 0x931e3a64 , 0xcc521810 , 0x145da626 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x46482059 , 0x38b0832e , 0xaf19bd10 , 0x4e92df7a , 0x99bee69f , 0x86ffcd2a , 0xaebac4a9 , 0x091b62cb ] )
+  , Mont::unsafe_make( [ 0x6d2b9920 , 0x79055239 , 0xa403e2a5 , 0x3c133b44 , 0x2470938c , 0xb32c0b7c , 0x40161b51 , 0x1111dc6b ] )
+  , Mont::unsafe_make( [ 0x8cf541e1 , 0x91df6041 , 0x92c5eb19 , 0x1ec724ff , 0xe49bb72c , 0xf0e48cfb , 0x2d7e193a , 0x1738460f ] )
+  , Mont::unsafe_make( [ 0xa43deae7 , 0x858144e2 , 0xbe6af82b , 0x57a96b3d , 0x9b3a5b0c , 0x5c8a77c6 , 0x887b8d73 , 0x17d8b762 ] )
+  , Mont::unsafe_make( [ 0x4f124fb0 , 0x0db5aac0 , 0xde06c8a9 , 0xcc8fc5cd , 0xdd5e949d , 0xf0fab891 , 0x8e37670f , 0x1fc6598e ] )
+  , Mont::unsafe_make( [ 0x87e875c4 , 0x9ecc78dd , 0x5e1ba0d2 , 0x1adc87a2 , 0x9cd2e889 , 0x88228a9c , 0x59ffd4e3 , 0x09792872 ] )
+  , Mont::unsafe_make( [ 0x8f4e1569 , 0x801076ba , 0xa13fe861 , 0x5e1d907c , 0x301d4ac1 , 0xd5831d93 , 0xc3b9996a , 0x0a3dbc16 ] )
+  , Mont::unsafe_make( [ 0x36c539ae , 0x33f3a2f7 , 0x9e7bcf13 , 0xdc471cd9 , 0x648cf771 , 0xb4c0cb4c , 0x6d7f6da4 , 0x068d4557 ] )
+  , Mont::unsafe_make( [ 0x120fa392 , 0xf76a9394 , 0x5778ec76 , 0xcb226bef , 0xb593da5f , 0xe48fa12c , 0x1fe978d9 , 0x0999d2fe ] )
+  , Mont::unsafe_make( [ 0xe4883018 , 0xb9165b82 , 0x66f39ffd , 0xe16f97d4 , 0x5a689027 , 0xea6b46dc , 0x4566c806 , 0x146d98d8 ] )
+  , Mont::unsafe_make( [ 0x910ff48e , 0x634e1438 , 0xe41b985c , 0xa7f0ad6a , 0x6d13ff85 , 0x2dfe8f97 , 0x35d30514 , 0x1a84dee9 ] )
+  , Mont::unsafe_make( [ 0xa5308f23 , 0x3967ba38 , 0x0d88c8d8 , 0x3a234cc9 , 0x508f2f58 , 0xb127d278 , 0x5ba945ec , 0x233faead ] )
+  , Mont::unsafe_make( [ 0x9d51221a , 0xd37b6611 , 0x252602bf , 0xd76704c9 , 0xe09120d8 , 0x31d582e0 , 0xfbe7f598 , 0x0143050d ] )
+  , Mont::unsafe_make( [ 0xb0572bbb , 0xcbba2d5f , 0x7d60e211 , 0xe8d1050b , 0xdc0d03c8 , 0x64196175 , 0xc2733acf , 0x18e37a7f ] )
+  , Mont::unsafe_make( [ 0x7edf38e8 , 0xcfe47844 , 0xed29fb73 , 0x17f1886c , 0x831121e0 , 0x07b4664f , 0x3c041561 , 0x02b2f40c ] )
+  , Mont::unsafe_make( [ 0xa4d499c1 , 0xae3d8444 , 0x23a8da0e , 0xc4922597 , 0xc18e92e7 , 0x154ff617 , 0x360ec79e , 0x05995ef8 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x510cd5d7 , 0x7b70534f , 0xcc23187a , 0x1df6c69b , 0x7f7d5946 , 0xe00c43b5 , 0x99005df8 , 0x2a4e9bbb ] )
+  , Mont::unsafe_make( [ 0x60d2e62d , 0xa75d0f01 , 0xc7445ec7 , 0x46062070 , 0x3dce21df , 0x40720ad1 , 0xe7dd9014 , 0x0d0b8b06 ] )
+  , Mont::unsafe_make( [ 0x963134cd , 0x6fe28e8d , 0x40acd507 , 0x3b8ff021 , 0x843a22e5 , 0x7c3478d4 , 0x6c1c6d89 , 0x2f4e7311 ] )
+  , Mont::unsafe_make( [ 0x2ff5ca14 , 0xe3825449 , 0xb2d47717 , 0x39546db7 , 0x0a96dd25 , 0xa7dbb8ee , 0xcc07432e , 0x087e6f72 ] )
+  , Mont::unsafe_make( [ 0x69969437 , 0xc9639818 , 0xd1cba6a2 , 0x0b1d8699 , 0xa52b6680 , 0xda23bdc1 , 0x8fd8ab4a , 0x174ec93c ] )
+  , Mont::unsafe_make( [ 0x99e1d173 , 0x385f570b , 0xc315208a , 0x51854c1e , 0x48d633f3 , 0x198231ec , 0xd2a0c0c2 , 0x119c6bc8 ] )
+  , Mont::unsafe_make( [ 0x269c548a , 0x7c30123c , 0xd1d0d70a , 0xec73fedf , 0x735404c4 , 0x4ddc22d3 , 0x3e9d1945 , 0x282bd65d ] )
+  , Mont::unsafe_make( [ 0x03c51195 , 0x58a4fd3c , 0x3c6c7f6d , 0x126bdc0d , 0x8adfeec2 , 0x7401ef20 , 0xd315be3b , 0x16a76dca ] )
+  , Mont::unsafe_make( [ 0x7bd6a36b , 0xab6bb9dc , 0xbaaf152c , 0x1367e86d , 0x13a4966c , 0xbe9cbd68 , 0x301d3fe0 , 0x0072e37b ] )
+  , Mont::unsafe_make( [ 0xfd731dfb , 0x45ff851a , 0x74bb3979 , 0xf75559c0 , 0x69e5c2c2 , 0xd1a8d32e , 0xe87da08e , 0x29c2d59d ] )
+  , Mont::unsafe_make( [ 0x71e939ef , 0x86dd1b72 , 0xc01a0bb7 , 0xa71fcd40 , 0x15e3255c , 0xfe40320f , 0xda2130b3 , 0x0d935214 ] )
+  , Mont::unsafe_make( [ 0x634bd8e5 , 0x9972c552 , 0x49deb0be , 0x78dcf0be , 0xdc83939b , 0xeb8edacf , 0x9eb13a2c , 0x1a6b5b92 ] )
+  , Mont::unsafe_make( [ 0x9c1b5e16 , 0xb7a64698 , 0x8a7b99b9 , 0x7123abac , 0x318b7691 , 0x3bb6b440 , 0xfa547b43 , 0x07c03643 ] )
+  , Mont::unsafe_make( [ 0x4bef6af4 , 0x16889016 , 0x778d7a89 , 0x7be8ccdc , 0x20de1556 , 0x7a88fc0d , 0x4f9d924f , 0x25a1f253 ] )
+  , Mont::unsafe_make( [ 0x610d0752 , 0x008ee461 , 0x8ce14b4b , 0xe63d111d , 0x44cf861d , 0x2483227b , 0x737b0a6d , 0x19a7e18e ] )
+  , Mont::unsafe_make( [ 0x91faa0c2 , 0x569b9ebf , 0x8373992c , 0xde575e7d , 0xca5924d3 , 0xb61bceed , 0xea6400ca , 0x05031731 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x730c95ec , 0x8910c73a , 0x1e301587 , 0xb75c046f , 0x92ba3819 , 0x65476cfd , 0x899d5b90 , 0x107752b4 ] )
+  , Mont::unsafe_make( [ 0xfc5d00cc , 0xbd0d62ed , 0x58f0e8e7 , 0x321c99c1 , 0xf70d3e35 , 0x1d3a990c , 0xf790c2c6 , 0x06245211 ] )
+  , Mont::unsafe_make( [ 0xfa4b24a6 , 0x0910e429 , 0x03362a45 , 0xaa82a9f0 , 0x8aa24e58 , 0x21bb4371 , 0x3aaa9aaa , 0x096ba67f ] )
+  , Mont::unsafe_make( [ 0xa18a5e90 , 0x62007afe , 0xa787c7e6 , 0xff2771cb , 0x2ee02c5b , 0xa2c12f87 , 0x040492a7 , 0x2eb34c92 ] )
+  , Mont::unsafe_make( [ 0xc185b0c3 , 0xf818ddd3 , 0x3ca17e15 , 0xaa4db2b7 , 0xd2148825 , 0x5322be53 , 0x58ef05b9 , 0x2d81e033 ] )
+  , Mont::unsafe_make( [ 0x7eedff9e , 0x99fab533 , 0x66da00e6 , 0xa4685d28 , 0xf56f3e75 , 0x1040889f , 0xbca2b9fb , 0x007f7424 ] )
+  , Mont::unsafe_make( [ 0xdf768193 , 0x31690fdf , 0x3d5ced0b , 0x323e815b , 0x7a58403c , 0xafc03618 , 0x726c1ff3 , 0x2802bc34 ] )
+  , Mont::unsafe_make( [ 0xdea04486 , 0xe578972c , 0x185a8c70 , 0xddd40044 , 0xdfea39be , 0x38a5ebee , 0xadd68e6f , 0x2a6e7beb ] )
+  , Mont::unsafe_make( [ 0x5b8528df , 0x035b70ce , 0x4959da35 , 0xb2061878 , 0x06c91e39 , 0x051925cc , 0xd4c6af50 , 0x18046e62 ] )
+  , Mont::unsafe_make( [ 0xfe6a252c , 0xd3367769 , 0xd83ad26d , 0x7e3e088d , 0x5190aace , 0x9e91e750 , 0xfdd0256e , 0x011b8749 ] )
+  , Mont::unsafe_make( [ 0xbb3fc72a , 0xb471dc68 , 0x71c91142 , 0x6b4b5913 , 0x82a7833b , 0x6711e21e , 0xbbac37b3 , 0x29706c03 ] )
+  , Mont::unsafe_make( [ 0x81e85bdb , 0xea84da10 , 0xc3e6e7e9 , 0xbedbc92d , 0xf7022a39 , 0x8961222c , 0x519e711b , 0x2b73542b ] )
+  , Mont::unsafe_make( [ 0x00301d27 , 0x96acc6ca , 0x21919f5f , 0xa2896bb0 , 0x4a81cda8 , 0x1422cde3 , 0x115a6a82 , 0x2491c499 ] )
+  , Mont::unsafe_make( [ 0x3924ac4e , 0x5e7495e3 , 0x57bfb524 , 0xaf7b2389 , 0xe99413b9 , 0xd976251c , 0xb41cd7e4 , 0x17e0ee79 ] )
+  , Mont::unsafe_make( [ 0x881979dc , 0xd25da98e , 0x27cd3f7b , 0x20ce1909 , 0x15f9cc48 , 0xc8b3c86b , 0x1bae5590 , 0x28c9ae30 ] )
+  , Mont::unsafe_make( [ 0x9b86e69e , 0xb4cfd6a8 , 0x481316c7 , 0xb39e0b59 , 0x95ee5e2a , 0x0d939686 , 0x2bc2d506 , 0x0a9d86c5 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x893d91df , 0x94946020 , 0xa891ed3a , 0x68492d9f , 0x152872ad , 0xf468632a , 0x92c4d7e9 , 0x29234c9f ] )
+  , Mont::unsafe_make( [ 0xf38215b4 , 0x394e3d23 , 0xd78326f3 , 0xb25d0e32 , 0xb84147e8 , 0xbe4cfec2 , 0xf4a2b13c , 0x2259360f ] )
+  , Mont::unsafe_make( [ 0x639ab788 , 0x19e5edc7 , 0x4960c5e9 , 0xef6f3378 , 0x1103de02 , 0x9cf6be9e , 0x5cf9a20b , 0x05acca66 ] )
+  , Mont::unsafe_make( [ 0x4e2544a8 , 0x99e2bcf2 , 0x1d5d0404 , 0x31813680 , 0xba78bfd6 , 0xc6bcc9fe , 0xa06cc601 , 0x28a7c12f ] )
+  , Mont::unsafe_make( [ 0x1db8cae8 , 0xb2503da2 , 0x59826c98 , 0xb0f8284a , 0xf56eaecb , 0xf8050291 , 0xecb9b71b , 0x0b8c3ce4 ] )
+  , Mont::unsafe_make( [ 0xa6987fe5 , 0xf4a7f961 , 0x10504e83 , 0xdb8a12cc , 0x52de2d00 , 0x95ca3675 , 0xe4ecca02 , 0x0e4bb4e2 ] )
+  , Mont::unsafe_make( [ 0x4eda2ebe , 0xa1aebf96 , 0x73f1a17b , 0x5f2cc4fc , 0x7900ed8d , 0x7b4cc89c , 0xb7a4a51d , 0x288e51b9 ] )
+  , Mont::unsafe_make( [ 0xcf4b11c2 , 0x95e31df7 , 0x74492308 , 0x817f4f61 , 0x6bb2a116 , 0xea2464a0 , 0x930f0c49 , 0x2ce5fc34 ] )
+  , Mont::unsafe_make( [ 0x0d710f60 , 0x04b43000 , 0xc3d89b38 , 0xce133a4f , 0xce18ed06 , 0x7a3bb0d1 , 0xdf7586ff , 0x06f2cea0 ] )
+  , Mont::unsafe_make( [ 0x87d3a674 , 0xba1f1940 , 0x3f27fc6f , 0xb9192162 , 0xad95dace , 0x3fd17b21 , 0x0aad9787 , 0x2a53b3bb ] )
+  , Mont::unsafe_make( [ 0xb8d3c0ef , 0x026a251f , 0x58c2e447 , 0x2666b7d8 , 0x61fa1aef , 0x8dd35864 , 0x08e61258 , 0x02a9a651 ] )
+  , Mont::unsafe_make( [ 0xacafaaa8 , 0xda37345b , 0xace50601 , 0x35bb70f9 , 0xcc4cb2d8 , 0x98936781 , 0x33ef1174 , 0x1eb3d9f9 ] )
+  , Mont::unsafe_make( [ 0xe40558d5 , 0x934c19a2 , 0x9af9b673 , 0x83e815fa , 0xcc1bcec6 , 0x49fecfb6 , 0x56e6610b , 0x299e8a60 ] )
+  , Mont::unsafe_make( [ 0xc7cbead9 , 0x045cfb8f , 0xb650dd9a , 0x48b2b99d , 0xc92ff541 , 0x7c564d83 , 0x24aeac82 , 0x2d279cb3 ] )
+  , Mont::unsafe_make( [ 0x10f7ed57 , 0xc75efbc8 , 0xd2dea420 , 0xd64d3471 , 0x5584bac7 , 0xafd481a7 , 0x293d6973 , 0x290f360c ] )
+  , Mont::unsafe_make( [ 0x0dda31ea , 0x470cd302 , 0x8183dc62 , 0x70d71ed3 , 0x90a57fe3 , 0x489ba5a8 , 0xe65edcd0 , 0x10712090 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x9c550e36 , 0xd1c881ce , 0x645a0bb1 , 0x02a4c7ff , 0xd0b7be31 , 0x0b0b961b , 0x7f1fe79f , 0x25493516 ] )
+  , Mont::unsafe_make( [ 0x820f6dda , 0x4735675b , 0x6530dc66 , 0x3e9cd71b , 0x5593ddd0 , 0xd3fa2769 , 0x38cc2165 , 0x22275afd ] )
+  , Mont::unsafe_make( [ 0x22562d3e , 0x5472a649 , 0x044e3d82 , 0x404c93ac , 0x1882f23b , 0xbafd038d , 0x42d7dd2b , 0x208b08b8 ] )
+  , Mont::unsafe_make( [ 0x99f013a3 , 0x39650869 , 0x398f2479 , 0x256e2af9 , 0x882257dd , 0xc1d18bd5 , 0x9600d4cc , 0x075f60e6 ] )
+  , Mont::unsafe_make( [ 0x0284e235 , 0xdaa2a9c1 , 0x3a8333b5 , 0x2e3e27d1 , 0xdd126efd , 0x74cabf91 , 0x29d90ea4 , 0x174bfbe6 ] )
+  , Mont::unsafe_make( [ 0x540d8de5 , 0x026462ff , 0x13f8bc4a , 0xd2eb32e7 , 0x8a4d582d , 0x264d41fa , 0x48f61ef9 , 0x2fc3bc96 ] )
+  , Mont::unsafe_make( [ 0x02dc6b8e , 0x23e74632 , 0x69b90ddb , 0x15ce751d , 0xd55bcad6 , 0x8d76260c , 0xbd87b87b , 0x1140a53f ] )
+  , Mont::unsafe_make( [ 0xfbe9c521 , 0x6729e127 , 0x6c1434be , 0x8ac40ea6 , 0x44aa3362 , 0x41a8d160 , 0x24797419 , 0x25fd2581 ] )
+  , Mont::unsafe_make( [ 0xf5109082 , 0x38d8212b , 0x2ae1e079 , 0xc3c17eba , 0x88c11b3b , 0xf77c2ff2 , 0x38ab85ad , 0x2ff1f928 ] )
+  , Mont::unsafe_make( [ 0x2acdc472 , 0xc00cea11 , 0xdde217a4 , 0x66e5bc31 , 0x969d76d4 , 0x73ffa31f , 0xfa4403c6 , 0x0ebc8d17 ] )
+  , Mont::unsafe_make( [ 0x37a77d51 , 0x6ccaf1c8 , 0xcf16dcd4 , 0xe97b8f90 , 0x545931b3 , 0xc87dfe8d , 0x0d185c09 , 0x2aadf580 ] )
+  , Mont::unsafe_make( [ 0x1e7200c7 , 0x3603216a , 0xbc649e7a , 0x766ca954 , 0x44539448 , 0x2c8bb547 , 0xa606c19d , 0x02d543cb ] )
+  , Mont::unsafe_make( [ 0x23a6bdd4 , 0x538f60ee , 0xd76249b6 , 0x950295a9 , 0x91668cf2 , 0xcc264287 , 0x56df4382 , 0x0acd8b57 ] )
+  , Mont::unsafe_make( [ 0xb8eadaea , 0xa94d4673 , 0x4e666d17 , 0xb1ee7a01 , 0xb9b55e09 , 0x50356cdb , 0xe99f4801 , 0x0e564e83 ] )
+  , Mont::unsafe_make( [ 0x4f8e78a0 , 0x023bf3eb , 0x80c9bd04 , 0x55f32c5a , 0xce6a5b5d , 0xfb8412a0 , 0x2dc640e6 , 0x19ee1a8a ] )
+  , Mont::unsafe_make( [ 0x3046e286 , 0xb51c2553 , 0x4a1ec3ff , 0xc639835d , 0x7cd231f4 , 0x23b3bba3 , 0x0304997c , 0x196c051f ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x59769113 , 0xe3af469b , 0x0cbeb0a4 , 0x88adebbd , 0x8f6f3561 , 0x522083c2 , 0x402616f1 , 0x2ee87ae5 ] )
+  , Mont::unsafe_make( [ 0xd78abbcc , 0x5ac81913 , 0x47cb0044 , 0xa64ec369 , 0x5876fafe , 0xa5aa7ced , 0x49c4626f , 0x24314b51 ] )
+  , Mont::unsafe_make( [ 0x5595140d , 0x53e7c926 , 0xfcf07034 , 0xb89c45c7 , 0x598847c5 , 0x909da9eb , 0x0659c2ef , 0x23c94d99 ] )
+  , Mont::unsafe_make( [ 0x7a824133 , 0xda53c9ff , 0x6f2804cb , 0xd635950c , 0x59c5fab2 , 0xb4903cfc , 0x04b33e13 , 0x1b702d27 ] )
+  , Mont::unsafe_make( [ 0xa8a1a86c , 0xe9a13074 , 0xd83562ff , 0x541fe062 , 0x57236144 , 0x33e19fe0 , 0x3b15ced0 , 0x126a5322 ] )
+  , Mont::unsafe_make( [ 0x80ae2e9f , 0x686eea2c , 0xdcc3a5d6 , 0x66760863 , 0x5b3b2e31 , 0x1119e4f2 , 0x0e8299af , 0x210f669f ] )
+  , Mont::unsafe_make( [ 0xada9f2fa , 0xf0a0952f , 0x75d29d11 , 0x0eb3a1aa , 0x44ba29b0 , 0x98b6063b , 0xbb43b9da , 0x1c092c29 ] )
+  , Mont::unsafe_make( [ 0xcdce0ea1 , 0xa2987cdc , 0xde1f37bf , 0x7c4c9e2a , 0x325a4ca3 , 0xe3e43cb0 , 0xa70153de , 0x2295967e ] )
+  , Mont::unsafe_make( [ 0xfcec6c5f , 0xa21c19ac , 0xfdf598db , 0x9025db2f , 0x154d7ac7 , 0x093e2cfc , 0xd13f5f51 , 0x0a8caa8c ] )
+  , Mont::unsafe_make( [ 0x3b6e8d9e , 0x7cf8638f , 0x31da7d7a , 0x8d7b0d3a , 0xebf0e1ba , 0x2dca4636 , 0x8521c20a , 0x27d7b782 ] )
+  , Mont::unsafe_make( [ 0xed89f474 , 0x33090eae , 0x5e68ed22 , 0x05f7c1e5 , 0xf394b6d7 , 0x7abc8b2f , 0x310fb362 , 0x29f1fbb5 ] )
+  , Mont::unsafe_make( [ 0xdfb99e38 , 0xef031e3a , 0xd2897527 , 0x79442393 , 0x3386c6d3 , 0xf0214a3c , 0x71178dbe , 0x0fc82272 ] )
+  , Mont::unsafe_make( [ 0x90e9826a , 0x2276cd3e , 0x8d94a36e , 0xf24cb778 , 0xae50768f , 0x1a2028a5 , 0x3d4069a1 , 0x0622f673 ] )
+  , Mont::unsafe_make( [ 0x495aeacf , 0x4008a8cb , 0x77e8cbaa , 0x1b3edd7e , 0x85fa5b08 , 0x6ee1f183 , 0xddfd3f52 , 0x0d70826d ] )
+  , Mont::unsafe_make( [ 0x90a20d21 , 0x4ff82e2b , 0x0b24d2d6 , 0xf14bb18d , 0xab78e510 , 0x371d85cf , 0x91f9d878 , 0x2e3a5e21 ] )
+  , Mont::unsafe_make( [ 0xd5d350e9 , 0x56352c63 , 0x93d89723 , 0xf3f6a087 , 0xb371fe60 , 0x9c41a0a4 , 0x454ed780 , 0x1ac736c6 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xc2d9d53e , 0x9db4914a , 0x2721d25c , 0x14e2ad68 , 0x87d80996 , 0xce9f9e01 , 0x8bbb029d , 0x0107d4f7 ] )
+  , Mont::unsafe_make( [ 0x1f0152c5 , 0x45bae810 , 0xc727d37a , 0x181cdc05 , 0xa1003d5a , 0x8af0918c , 0xd1846558 , 0x100b5455 ] )
+  , Mont::unsafe_make( [ 0x54212e45 , 0x6c2a680c , 0xb4e9b86d , 0xf9d523bb , 0xc2fa949a , 0x38154654 , 0xdb0325c2 , 0x20fe6de6 ] )
+  , Mont::unsafe_make( [ 0x38240c39 , 0xb3c60eb0 , 0xe7a71972 , 0x87082ff0 , 0x1d86f4b2 , 0x6ad043c2 , 0x302b30cc , 0x0d9e3dc8 ] )
+  , Mont::unsafe_make( [ 0xb74dea73 , 0x5b4ee12a , 0x45ea0d67 , 0xfad93789 , 0x6fc339e5 , 0x5620349d , 0xba1672bf , 0x210c8a59 ] )
+  , Mont::unsafe_make( [ 0xacb3555f , 0x201dfb93 , 0xfc1622f4 , 0x5cd43fba , 0x0f3936d6 , 0xa6dd2f1b , 0x55df104f , 0x131f11a6 ] )
+  , Mont::unsafe_make( [ 0xb126dc76 , 0xad1b39d3 , 0xa603d66e , 0x7ac82cb1 , 0xe02b88a8 , 0x56d00b3f , 0x428aa35e , 0x0f7ef4d8 ] )
+  , Mont::unsafe_make( [ 0x320792aa , 0x27c42993 , 0x806a0d5c , 0x0f719ba6 , 0xc9c63e5b , 0xac3fc746 , 0x858b377b , 0x1b43cdbd ] )
+  , Mont::unsafe_make( [ 0x8a80922a , 0xa08939b6 , 0x4492757e , 0x5b34d687 , 0xf6338fed , 0xfc067d1a , 0x776934e2 , 0x22c8597b ] )
+  , Mont::unsafe_make( [ 0x88308d82 , 0x6cca620f , 0xe84472a0 , 0xd4cd8b20 , 0xeb9f4387 , 0x7b856b3b , 0xce980c79 , 0x0f97d4d7 ] )
+  , Mont::unsafe_make( [ 0x730fc81f , 0xa4925eb6 , 0x8f2131e1 , 0xd79b81ec , 0x63869ad4 , 0xf8e9f74e , 0xcc53d8c3 , 0x2175cbfb ] )
+  , Mont::unsafe_make( [ 0x70f6d83a , 0xc6c63bd0 , 0xabe05856 , 0x7f457ef4 , 0xf558c8cc , 0xaa412116 , 0x91da6d82 , 0x02dd37e6 ] )
+  , Mont::unsafe_make( [ 0xb2b24985 , 0xe5ddf0d8 , 0xbeb5c554 , 0x37302873 , 0x9bb5174f , 0xec12a607 , 0x610dfbe1 , 0x2486528c ] )
+  , Mont::unsafe_make( [ 0x60fd36a6 , 0x1bb52055 , 0xfe276deb , 0x59f6275f , 0xce218210 , 0x187d8fc9 , 0xa1067854 , 0x2af9fa1d ] )
+  , Mont::unsafe_make( [ 0x205f84a8 , 0x55c3cda4 , 0x5863001d , 0x6ea153b4 , 0x8ef5df42 , 0x444a81a1 , 0x64025ecd , 0x088f93b3 ] )
+  , Mont::unsafe_make( [ 0xa4882596 , 0xb25a46dc , 0xfafc6704 , 0x20e19279 , 0x2a4d6240 , 0x556b0b61 , 0xb2cc7ca0 , 0x06a3a713 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xf5ea2d14 , 0xa2f33d7c , 0x7aec9c72 , 0x27b5eaae , 0xd84c64a8 , 0xb165fc73 , 0x90c0bec1 , 0x1dda04cc ] )
+  , Mont::unsafe_make( [ 0x5f79a214 , 0x786724d0 , 0x0cc1a0d7 , 0xe45b5ef9 , 0xd3bd6bf6 , 0x704c140d , 0x05338cf9 , 0x17ab4b13 ] )
+  , Mont::unsafe_make( [ 0x21458f45 , 0x3af567df , 0x62176037 , 0x1727b551 , 0x96eb4318 , 0x50a85189 , 0xdad59268 , 0x08b87ac7 ] )
+  , Mont::unsafe_make( [ 0xa4cfca3d , 0xad1f8e37 , 0xa60a060b , 0x14022308 , 0x9273d594 , 0xdac93b9f , 0xe5fb7abc , 0x1deddd19 ] )
+  , Mont::unsafe_make( [ 0x5b93ad88 , 0xd051d6a6 , 0x820afe34 , 0x51b26710 , 0x3138323b , 0x2c1da78e , 0xc417cff3 , 0x1c1e18ac ] )
+  , Mont::unsafe_make( [ 0x4b6c637e , 0x39c97776 , 0x663cf676 , 0xfa01aa66 , 0xed22fa98 , 0x53f0d3f2 , 0xb995cb43 , 0x08583f18 ] )
+  , Mont::unsafe_make( [ 0x2273394e , 0x03c485a5 , 0xa30b32d0 , 0xc6ad70a8 , 0x03b8543a , 0x0fb7ee7a , 0x1f7a97ba , 0x0af79ddc ] )
+  , Mont::unsafe_make( [ 0x9d93f0c5 , 0x97a1ebf6 , 0x9e246d20 , 0xb4c828b1 , 0xe241198d , 0x9afd9a37 , 0x2dd98f5e , 0x2bd902ce ] )
+  , Mont::unsafe_make( [ 0xe1811993 , 0xcffc72a2 , 0x73a85e9b , 0xb6313618 , 0x9c549795 , 0xec207cbd , 0x3862421e , 0x2511c69b ] )
+  , Mont::unsafe_make( [ 0xd835f1ee , 0x76859ad7 , 0xb4a404b5 , 0x3339a212 , 0x4671a385 , 0xc912a931 , 0xec9d0a10 , 0x1f14ebd4 ] )
+  , Mont::unsafe_make( [ 0xcec0161a , 0x73e62530 , 0xc3c5396c , 0x33b2b2c0 , 0x9a007c36 , 0xe34eeedc , 0xf8362210 , 0x0ccd944e ] )
+  , Mont::unsafe_make( [ 0x4b7ce3eb , 0x2094ac5d , 0xa00cfe58 , 0xc1c9dc4d , 0xbda4b8fb , 0xd1ad17c5 , 0xbbb66383 , 0x16464c85 ] )
+  , Mont::unsafe_make( [ 0xed2a9f86 , 0x85bc1590 , 0xd04d3a74 , 0xb0cf7f26 , 0x4f333aed , 0x1cf93d13 , 0x0ddca5e6 , 0x08e007be ] )
+  , Mont::unsafe_make( [ 0x3423ad21 , 0x88d46832 , 0xe144aec2 , 0x1f21d863 , 0x3419a1f1 , 0xfb2107dd , 0x2997af63 , 0x1e280a45 ] )
+  , Mont::unsafe_make( [ 0xc8a1923b , 0x68a84774 , 0x47479155 , 0xd2e50419 , 0x051b6ac3 , 0xa125227e , 0x994e2cb0 , 0x1bb412dd ] )
+  , Mont::unsafe_make( [ 0x9f5b356a , 0x5156b2e4 , 0x56e09ee8 , 0xd9ba6d63 , 0xcb4958a0 , 0x56a96f73 , 0x8bb53795 , 0x27ad5148 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xd9f2de35 , 0x611bc53e , 0xd794b7aa , 0x418571a8 , 0xec0530d7 , 0x65d9889e , 0x4637d60e , 0x29f83269 ] )
+  , Mont::unsafe_make( [ 0xc9086bb1 , 0x4ce524ae , 0x28e246a6 , 0x0bf342df , 0x7fea3535 , 0x0a21f534 , 0x82904e19 , 0x0b2936d6 ] )
+  , Mont::unsafe_make( [ 0xe18b071d , 0xcf1f6c6e , 0x850d4da5 , 0x03ce4c97 , 0xee50b9a0 , 0x28625f1a , 0x59320254 , 0x0ec0058a ] )
+  , Mont::unsafe_make( [ 0x7c1245ea , 0x28ded4ec , 0x136c0e4f , 0x6bd96255 , 0x23278316 , 0x2b13f93d , 0xb2939a46 , 0x1d16e1aa ] )
+  , Mont::unsafe_make( [ 0x4892fe4b , 0xc0df53f2 , 0xfb4f1037 , 0xe5ec095f , 0xc326dd8c , 0x182303d5 , 0xb1781b57 , 0x1898aeec ] )
+  , Mont::unsafe_make( [ 0x9be42f69 , 0x776b2414 , 0x49fa1935 , 0xd8c16728 , 0x14b7feb2 , 0xd48bacd6 , 0x4ea7a9eb , 0x29ea3ec6 ] )
+  , Mont::unsafe_make( [ 0x0223442c , 0xa6d0e716 , 0xb412d567 , 0xe4c80801 , 0xad6f9aeb , 0xde3e0c07 , 0x7638e208 , 0x2aaae4b1 ] )
+  , Mont::unsafe_make( [ 0xfd50a432 , 0x1843e5bd , 0xdded6755 , 0xba1b5ed1 , 0xe9eda379 , 0xc8404458 , 0x496df286 , 0x16994b0e ] )
+  , Mont::unsafe_make( [ 0xf595c2d9 , 0xc4f5a4f1 , 0x2174bec8 , 0xcf0bf446 , 0xd15660ae , 0xff5842f0 , 0xa21ab714 , 0x2f723399 ] )
+  , Mont::unsafe_make( [ 0xa233e482 , 0x51a5e0e5 , 0xe5c247c3 , 0x58241884 , 0x3984d522 , 0x6f029f33 , 0x10486a38 , 0x0eb4825e ] )
+  , Mont::unsafe_make( [ 0x0eb90206 , 0x948f3ca1 , 0x58ff2ebc , 0x9c5e154d , 0x3380d3b2 , 0x7865aac6 , 0x1942d9c6 , 0x17feba48 ] )
+  , Mont::unsafe_make( [ 0xb5a22073 , 0x6b99dec5 , 0xba82758d , 0x722d2836 , 0x223ea94a , 0xd0fe241e , 0x4f0dbfd6 , 0x27b55849 ] )
+  , Mont::unsafe_make( [ 0x4e960934 , 0x32e78a65 , 0x5236e429 , 0xfb3ddcb0 , 0xfcbd9208 , 0x38ba150d , 0xe71823f7 , 0x1e754b44 ] )
+  , Mont::unsafe_make( [ 0x6c128289 , 0x5832f43d , 0x3335ad61 , 0x1049aa0e , 0xa3788442 , 0x3ca0f57c , 0x20948b34 , 0x19c44793 ] )
+  , Mont::unsafe_make( [ 0x626248bd , 0x5b85ba7d , 0x963cea0a , 0xb9ba7b7f , 0x4f5a0e39 , 0x9550f9db , 0x4e46be65 , 0x05ea36dd ] )
+  , Mont::unsafe_make( [ 0xa2546125 , 0xea701305 , 0x696d2da3 , 0x88086b12 , 0x2ed35538 , 0x40b81d51 , 0xbfab8794 , 0x1fa2b710 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xa9f54ede , 0x1dbb7dff , 0x50ed79e2 , 0x3ed46b33 , 0x36077c83 , 0xd7ec8acb , 0xf7e6435d , 0x0f4dd180 ] )
+  , Mont::unsafe_make( [ 0x6e0c2ce7 , 0x29eb12da , 0xc7be7382 , 0xe8b34c3d , 0x28a3ccf3 , 0xea8d66d8 , 0x000ae01a , 0x18088b86 ] )
+  , Mont::unsafe_make( [ 0x2c20d47a , 0x4d0ddda0 , 0xf64ca3ed , 0x0a80d2e5 , 0x8f631872 , 0x1f182c3b , 0x6764480e , 0x29bf48df ] )
+  , Mont::unsafe_make( [ 0x0f6ef927 , 0x90a28fdf , 0xfcca60df , 0xced58d45 , 0xeda16e80 , 0xde3a2807 , 0x72169f1c , 0x2e108269 ] )
+  , Mont::unsafe_make( [ 0x5b059724 , 0x80780c9c , 0x126c3d3f , 0x1bce0d62 , 0x1e822a51 , 0x0018ed54 , 0x7935bf47 , 0x1a4540cd ] )
+  , Mont::unsafe_make( [ 0xfa7297c7 , 0xd136345b , 0x9843b673 , 0x17ad7233 , 0xea60e980 , 0x0e576f6a , 0x8a22e269 , 0x25fd3287 ] )
+  , Mont::unsafe_make( [ 0x2b3ba778 , 0x7cdad43f , 0x76f0b684 , 0xe18ed314 , 0xfa894144 , 0x3860975b , 0xdf301e5d , 0x0433d924 ] )
+  , Mont::unsafe_make( [ 0x4c815a82 , 0xdf468ee6 , 0x0b83c12d , 0x16805de3 , 0x120b5184 , 0x93d188cb , 0x14cb61f9 , 0x0005b236 ] )
+  , Mont::unsafe_make( [ 0xcc04edab , 0x706af9bb , 0x731802fb , 0xdc621b47 , 0x04b2c04c , 0xc861e922 , 0x69c6390e , 0x2052345e ] )
+  , Mont::unsafe_make( [ 0x391f2505 , 0x02dede55 , 0x69ef707d , 0x0b11267b , 0xef419396 , 0x1ba04f41 , 0xd3f5ff85 , 0x221068f8 ] )
+  , Mont::unsafe_make( [ 0xc8342c35 , 0xa35b3ef6 , 0x0b577f02 , 0x9116a516 , 0x3b2717e6 , 0x51429ba3 , 0xbab83d24 , 0x1b6c9540 ] )
+  , Mont::unsafe_make( [ 0x15cb5c07 , 0xf1adcbda , 0x26729248 , 0xc8cd3e66 , 0x876ac632 , 0x24a94813 , 0x81e34453 , 0x28f8a9be ] )
+  , Mont::unsafe_make( [ 0x18bef86e , 0xe2f6c293 , 0xfa0d0392 , 0x4753255b , 0x0b705974 , 0x7c0064b8 , 0x546ecce8 , 0x25dd29b6 ] )
+  , Mont::unsafe_make( [ 0x19b7a96b , 0x86cfb66e , 0xa237a0e0 , 0x18333105 , 0xaa7e6e66 , 0x92cc66c5 , 0xc6e3b3f2 , 0x18f479f7 ] )
+  , Mont::unsafe_make( [ 0x92d3c5a1 , 0x5d7e558a , 0x82b9ae5d , 0x8b4e46b4 , 0x61cef1e8 , 0x43944607 , 0xea418bd6 , 0x16fa9f6b ] )
+  , Mont::unsafe_make( [ 0xccf5da35 , 0x4553b738 , 0x79d3fcad , 0xced91ea3 , 0xb1d58869 , 0xd1cb393a , 0x8dd3f584 , 0x1763f5cd ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xdb124982 , 0x983c036d , 0x1d84215b , 0xbce21923 , 0x4d709932 , 0xb74e5334 , 0x7d2e214c , 0x2058c7da ] )
+  , Mont::unsafe_make( [ 0x7a68b7eb , 0xe9792e17 , 0xc8035a84 , 0xc093e276 , 0x15566a83 , 0x3d8626f7 , 0xd468f5d8 , 0x2faa3a12 ] )
+  , Mont::unsafe_make( [ 0x3ced54dd , 0x0729a96b , 0x9b1206c6 , 0x1431a253 , 0xe4288c0a , 0x59cce736 , 0x5e74b773 , 0x017c66c1 ] )
+  , Mont::unsafe_make( [ 0x9e69dc28 , 0x38180ae8 , 0x18ed7239 , 0x0abb75e4 , 0x97fc8094 , 0x1824e55a , 0x0ebc51e1 , 0x1e9322a6 ] )
+  , Mont::unsafe_make( [ 0x6351b46b , 0xca753d40 , 0xb6c1b9e7 , 0x8d247e0a , 0xa85b4f80 , 0x54b09040 , 0xc675a032 , 0x0b88bd8a ] )
+  , Mont::unsafe_make( [ 0xf3e16510 , 0xc9d85fba , 0x4cbb4dc8 , 0x67117983 , 0xcd0b6139 , 0xcadedc8a , 0x8e142387 , 0x2f4539b8 ] )
+  , Mont::unsafe_make( [ 0x9676d4ba , 0x2563abff , 0x48089645 , 0x5aad168e , 0x6968ea06 , 0xa408f9a7 , 0x0f89e31b , 0x10b5c154 ] )
+  , Mont::unsafe_make( [ 0xdac50f96 , 0x702836df , 0x379574ad , 0x61830b0c , 0x7268a200 , 0x41c27b99 , 0xc809dd9c , 0x2610cdeb ] )
+  , Mont::unsafe_make( [ 0xfd840930 , 0xe22350fb , 0x09a4874a , 0x8265cf24 , 0x34f61340 , 0x7fda7b00 , 0x1df8985d , 0x0ef41927 ] )
+  , Mont::unsafe_make( [ 0x4af1e726 , 0x400995ee , 0x60295095 , 0x00e1e562 , 0x8b1c8afb , 0x82bac762 , 0x730a4a77 , 0x0e48663b ] )
+  , Mont::unsafe_make( [ 0x54d4297c , 0x4705aa73 , 0x1f651ab3 , 0x5a333950 , 0xc3ecf434 , 0x6c67c698 , 0x39c85ab5 , 0x132f767a ] )
+  , Mont::unsafe_make( [ 0xa60c7339 , 0x3d80c3ab , 0x948938f5 , 0x3e79ac82 , 0x666228ad , 0x6f3ebbd4 , 0xc8517533 , 0x2f9267b8 ] )
+  , Mont::unsafe_make( [ 0xe2a96bd9 , 0x5063f73b , 0xe7ad9d01 , 0x8ea41c2a , 0x658e3896 , 0xf0c42839 , 0xd6a1c987 , 0x26cda7a5 ] )
+  , Mont::unsafe_make( [ 0x3ce1964b , 0xbcdd5d54 , 0xed327fd7 , 0xa539abe9 , 0x02b3419c , 0x2136b1bc , 0x1b79dfdc , 0x11e75c8d ] )
+  , Mont::unsafe_make( [ 0x4a2da385 , 0xf5dbe5bc , 0xa54f3fa1 , 0xcdd9a15a , 0x2aa2685a , 0x50655d7a , 0x9e30a451 , 0x12ea91ca ] )
+  , Mont::unsafe_make( [ 0x88f0e3ab , 0xd55efd8d , 0x6099ee6f , 0x23071959 , 0x5f6c3252 , 0x98e42577 , 0x2b9f5c04 , 0x084e79ca ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xc19a32b8 , 0x1340e92f , 0xd727b58d , 0x58b8cc66 , 0x04d9543a , 0x930061f9 , 0x92b1e3f1 , 0x2ee885a5 ] )
+  , Mont::unsafe_make( [ 0xc477cccb , 0x9f7289e1 , 0x9325a6f4 , 0xc9daecf0 , 0x457f7700 , 0x6146168d , 0x661b29f6 , 0x2b651bdc ] )
+  , Mont::unsafe_make( [ 0xc2036a9f , 0x05416cbd , 0x17027f8a , 0xde3ff7bd , 0xde0ef233 , 0x26f8846b , 0xaca2f06f , 0x03dad97e ] )
+  , Mont::unsafe_make( [ 0xd5ff3480 , 0xc0127aee , 0x8649e8e7 , 0x880dc145 , 0xb44292c4 , 0x592821e8 , 0xa4199b99 , 0x29d558d0 ] )
+  , Mont::unsafe_make( [ 0xc8f0a4a0 , 0xf961b1f1 , 0x556e4026 , 0x3c8aff5a , 0x9dc23ef8 , 0x0aed1775 , 0x4651cd23 , 0x186aba86 ] )
+  , Mont::unsafe_make( [ 0x82f1d341 , 0xf42ee3bc , 0x85f4767c , 0x1656d8ba , 0xb431cc1d , 0xf3b72a20 , 0xa92bfc97 , 0x0a9c431b ] )
+  , Mont::unsafe_make( [ 0x8ebb3cc9 , 0x560d9cae , 0x2b09a640 , 0x1aee1e6a , 0x21d051ca , 0x2c8e3123 , 0x83de53a2 , 0x07e79b6e ] )
+  , Mont::unsafe_make( [ 0xd85350a2 , 0x44212be2 , 0xf512a2f4 , 0x33d444bc , 0x7dc89b6d , 0xb4f0e6bf , 0xeabf8343 , 0x2ecab3ff ] )
+  , Mont::unsafe_make( [ 0x3d8cff02 , 0x8cf44abb , 0x888bb90b , 0x1567928e , 0xf3b2c1c6 , 0xcc9e5363 , 0x810073d1 , 0x20b3bfee ] )
+  , Mont::unsafe_make( [ 0x543806fb , 0xc67fc21a , 0x34eac6c9 , 0x45509e94 , 0xbb11d542 , 0x7aa53571 , 0xa7bc9598 , 0x26ba3c85 ] )
+  , Mont::unsafe_make( [ 0xe246bbb9 , 0xeaac1aa0 , 0x94b7d5ac , 0xc0cb4e60 , 0xba19e207 , 0xe7f882a2 , 0x800e13f3 , 0x1fd36076 ] )
+  , Mont::unsafe_make( [ 0xcc878827 , 0xcf3374c4 , 0x11df7fc1 , 0x41781ce8 , 0x62d6ef77 , 0x914c4f7d , 0x5f76f00a , 0x1f5132b1 ] )
+  , Mont::unsafe_make( [ 0x68542902 , 0x79928f09 , 0x68fc6732 , 0x777826e4 , 0x10a85050 , 0xd56b837f , 0x71736e6a , 0x19974311 ] )
+  , Mont::unsafe_make( [ 0xbdc0fdc1 , 0x1f7f917f , 0x681b7157 , 0x9125dd5c , 0xde24d96d , 0xd091a72e , 0x68efe186 , 0x08db4990 ] )
+  , Mont::unsafe_make( [ 0xe1aeef18 , 0xaba55f28 , 0x04fdff65 , 0xe7ccf7c2 , 0x145fddaa , 0x91176fe2 , 0xeaf66515 , 0x1682b95c ] )
+  , Mont::unsafe_make( [ 0x15a2a892 , 0x902daf33 , 0xc160be4e , 0x2adde125 , 0x291b4f7c , 0x60320d9e , 0xa4a214d7 , 0x234bfe5d ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xeea8a30c , 0xd725e3c7 , 0xe40b73c8 , 0xf0744eea , 0xbb98041c , 0x08919b02 , 0x5bc5dd32 , 0x17580dfb ] )
+  , Mont::unsafe_make( [ 0xde62d65b , 0x7adb891d , 0x757a1b6a , 0x747db4ad , 0x49121412 , 0xe3f4e522 , 0xfecb96c8 , 0x0181999c ] )
+  , Mont::unsafe_make( [ 0x0dd8bdd1 , 0x07ae19d4 , 0x34ab09db , 0x245bc35a , 0x50faa2f8 , 0x4e844888 , 0xfb7980b5 , 0x0dd94877 ] )
+  , Mont::unsafe_make( [ 0x75e1a3a3 , 0x01e2cbfa , 0x5d3de009 , 0x785a5389 , 0x88c9fe34 , 0x02b432ca , 0x738a854a , 0x288cae5b ] )
+  , Mont::unsafe_make( [ 0x41dbae79 , 0xd6d1dee6 , 0x86fd6aa3 , 0xee03e86d , 0xaff2ab37 , 0x51addf62 , 0xcc7965b7 , 0x0fb33221 ] )
+  , Mont::unsafe_make( [ 0xb23eac0a , 0xac48a685 , 0x35d32f72 , 0xf8913819 , 0xb3ef2f00 , 0xdafed532 , 0x9c43069a , 0x201000f7 ] )
+  , Mont::unsafe_make( [ 0xd0aebd3e , 0x7a2d472f , 0x9c11b57e , 0xd2dbfb84 , 0xacc8905d , 0xf4970a58 , 0x41f3f963 , 0x0b6b62ef ] )
+  , Mont::unsafe_make( [ 0xdb013b57 , 0x949c70f6 , 0x7e327e77 , 0xdd8df13b , 0x9d3ee732 , 0x4f6285f5 , 0x09d0b5d6 , 0x01c5e1cf ] )
+  , Mont::unsafe_make( [ 0x3ce76e33 , 0x80fb905a , 0xa5ba7f94 , 0x778a74ec , 0xa7fb055a , 0x69ca9ce8 , 0xe8f4a1e5 , 0x12d9d202 ] )
+  , Mont::unsafe_make( [ 0x7657b8ea , 0xb7c1d970 , 0xaa313e8e , 0x9d797bf8 , 0x5fa11b85 , 0xd206359d , 0x4e749c78 , 0x0b04ed3f ] )
+  , Mont::unsafe_make( [ 0x1e31ae80 , 0x506effab , 0xe1eefb6f , 0x299e4018 , 0x27d025d7 , 0x0577b45f , 0x77901db1 , 0x1e6e0447 ] )
+  , Mont::unsafe_make( [ 0x67357232 , 0xafe5d889 , 0xf7e851f9 , 0x6a70c519 , 0x6a11b5e0 , 0xb5a4e60a , 0x9b57477c , 0x0ec0a8c5 ] )
+  , Mont::unsafe_make( [ 0xa69defa2 , 0x43d4fe38 , 0xe803680a , 0x1b9a11dd , 0xb5448fe4 , 0x1d9b9818 , 0x344374e5 , 0x2486d318 ] )
+  , Mont::unsafe_make( [ 0x7f714473 , 0x75468377 , 0x013b60cc , 0xba852f30 , 0xfe8473dd , 0xfeee7bbc , 0x396858bb , 0x21c944fd ] )
+  , Mont::unsafe_make( [ 0xfac34eaf , 0x4e6fcbbf , 0xea9e6afb , 0xe1e76a12 , 0xccdbe334 , 0xc4cd59bc , 0x9eba816b , 0x284fba86 ] )
+  , Mont::unsafe_make( [ 0x7a75dfb2 , 0x367d6c94 , 0x969056dd , 0xac59076e , 0xf492992d , 0xaf137648 , 0x0d689c2e , 0x03a051c7 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xf8ec4a95 , 0x414f04a6 , 0xf2de6f00 , 0x6b80a2ce , 0xa7924923 , 0x49ee586d , 0x2ab7a209 , 0x213dd532 ] )
+  , Mont::unsafe_make( [ 0x129c8b9a , 0x4446ef0e , 0x51fc3762 , 0x92fb59d2 , 0xc1a5a85e , 0x5277657c , 0x763b8375 , 0x26f6f313 ] )
+  , Mont::unsafe_make( [ 0x42155e4e , 0x85d4f208 , 0x36a20a75 , 0x51f918dc , 0x03e9fca2 , 0xb65aa22d , 0x0cebcb64 , 0x04147be4 ] )
+  , Mont::unsafe_make( [ 0x174d6ea9 , 0x911ea212 , 0x0f4365bb , 0x8dd021b9 , 0x025aeddb , 0x18e22fee , 0x395af014 , 0x2a2cba2f ] )
+  , Mont::unsafe_make( [ 0x584a09d1 , 0x07a3124e , 0xa9a7aec6 , 0x3c716254 , 0x28d735f4 , 0x66e734f8 , 0x4f547d7c , 0x25d6d274 ] )
+  , Mont::unsafe_make( [ 0xcc5a4a86 , 0xc094c80d , 0x8f404e97 , 0x87d80e0e , 0x62724195 , 0x46adbf5f , 0x8161155e , 0x13435a20 ] )
+  , Mont::unsafe_make( [ 0x7700b186 , 0x48e0dfd8 , 0x2239e162 , 0xf09a883d , 0x48140ad7 , 0x3b907cfe , 0x739d431f , 0x2396e718 ] )
+  , Mont::unsafe_make( [ 0x0d2b9add , 0xe99edd82 , 0x2e419a50 , 0x049e804a , 0xd2a88ddd , 0x3cc9fd52 , 0x0a3712b4 , 0x2c088e54 ] )
+  , Mont::unsafe_make( [ 0x9ae4d99b , 0xafe6478a , 0x2e46385a , 0x93628d83 , 0xa3812e51 , 0xdeabf9dc , 0xf1d4dd58 , 0x010a3a3e ] )
+  , Mont::unsafe_make( [ 0x45f52b6f , 0x3631a26a , 0x240f5941 , 0xa71686ba , 0x74ff448e , 0xa8cd46af , 0x938e1706 , 0x1b5dff38 ] )
+  , Mont::unsafe_make( [ 0x80083bed , 0xddd7d1a1 , 0x49d883d0 , 0x1ebf712f , 0x3e267924 , 0xc554cc61 , 0xa68b92b9 , 0x27f3f825 ] )
+  , Mont::unsafe_make( [ 0x806b8ccc , 0xea5ad50a , 0xb31c2ff0 , 0xacb26fc1 , 0xa7d2c4a5 , 0x5b3832ce , 0x07282930 , 0x0c5a9b40 ] )
+  , Mont::unsafe_make( [ 0x6b5ee8a5 , 0xe48a8cc6 , 0x211875f1 , 0xb4651b72 , 0x02ecb315 , 0xa0445af6 , 0xa82465a5 , 0x14b19e78 ] )
+  , Mont::unsafe_make( [ 0x553b2f32 , 0xc671fb16 , 0x0fd10e6f , 0xa1e0c862 , 0x8e606cc7 , 0xee40d855 , 0xdf56d01e , 0x1729911a ] )
+  , Mont::unsafe_make( [ 0x634ca35a , 0xeb66d0b0 , 0xc6268ef9 , 0xfc854bee , 0xba7df933 , 0xbef8e737 , 0xbcd34b3c , 0x22c8d424 ] )
+  , Mont::unsafe_make( [ 0xcecb6641 , 0xa65f930f , 0x494b0c1e , 0x5b8acc0a , 0x449fbcbd , 0xa6968a70 , 0xff624afa , 0x119a1b54 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xab722aba , 0xc5b86fb4 , 0x0baa9af2 , 0x584a5304 , 0xd0af41bc , 0xe56e80ae , 0xbb9f1165 , 0x246b29a0 ] )
+  , Mont::unsafe_make( [ 0x1f823e5e , 0x3879a5db , 0x6e6b8052 , 0x2294656b , 0xd9c825d2 , 0xf6b297af , 0xb79f40cc , 0x036f6a2b ] )
+  , Mont::unsafe_make( [ 0xe4cf68c8 , 0x868d5b47 , 0x90478b11 , 0xd83d07c5 , 0xe096d0ba , 0xa5e67be1 , 0x6eeab82c , 0x073e9e4f ] )
+  , Mont::unsafe_make( [ 0xc001ffa6 , 0xf7adc656 , 0x4ef061c8 , 0xab151dbb , 0xd3ddc019 , 0x3453c722 , 0x2d868aed , 0x29d8877d ] )
+  , Mont::unsafe_make( [ 0xfe3f0c63 , 0x8e61bcbd , 0x3c7a7e33 , 0x69fd2876 , 0x29809a87 , 0xc2e9d6fa , 0x53cf26b9 , 0x1d2fb225 ] )
+  , Mont::unsafe_make( [ 0xda312de8 , 0x89196680 , 0x1a24bb37 , 0x7b3cce76 , 0xdc2d84b3 , 0x7930ff94 , 0x3cf541ac , 0x04a79280 ] )
+  , Mont::unsafe_make( [ 0x00c053e7 , 0x7e1ff7a9 , 0x25586f97 , 0x215e9740 , 0xe659cd9b , 0x3cae65d0 , 0xb3d692c2 , 0x0bdb0331 ] )
+  , Mont::unsafe_make( [ 0x150823c4 , 0x57f20575 , 0xdee9feee , 0xf5cab3bd , 0xf661a81c , 0x60912008 , 0xd8186871 , 0x0cd7e271 ] )
+  , Mont::unsafe_make( [ 0x82280d64 , 0x32565b12 , 0x8a263d76 , 0xed69e96a , 0x685acdd7 , 0xee576fac , 0xf94aefff , 0x24b72c35 ] )
+  , Mont::unsafe_make( [ 0x7f9671f2 , 0x2640068a , 0x1f2101ef , 0x6bea0c37 , 0xc598e7c8 , 0xc3bed031 , 0x637e5dc8 , 0x0b34fd3d ] )
+  , Mont::unsafe_make( [ 0xafe4f7df , 0x83e4fc9d , 0xee0a6788 , 0x5170f2ba , 0x42fb7a89 , 0x9329fbdf , 0x35f8a6b2 , 0x0826c90b ] )
+  , Mont::unsafe_make( [ 0xb3bd1f09 , 0x045e85f1 , 0xda43647a , 0x2c21121b , 0x0bb5e4e2 , 0x68f066a3 , 0x0a3b1bfa , 0x078f2bf3 ] )
+  , Mont::unsafe_make( [ 0x3003a324 , 0xd063c94d , 0x9f61883e , 0x8ddc5ffe , 0x243a4ef0 , 0x9521b8ff , 0x2f2ecc30 , 0x067de426 ] )
+  , Mont::unsafe_make( [ 0xa741c572 , 0x5468c297 , 0xf8fa6641 , 0xf06fb6e6 , 0xe6d165e6 , 0x1dce762f , 0xb9c5ae36 , 0x169203f7 ] )
+  , Mont::unsafe_make( [ 0x0836aa6c , 0x905548eb , 0xb8eb9df8 , 0x693f41e6 , 0x56021954 , 0xe162e1bd , 0xeab4f676 , 0x1e4963a5 ] )
+  , Mont::unsafe_make( [ 0x5baf5efb , 0x7d335217 , 0x67fe2e30 , 0x9f6193ee , 0xb346c6d1 , 0x0c77393b , 0x4b24e31e , 0x2d9ee9d4 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xc0b89dae , 0x2c84a26e , 0x45764de0 , 0x317c9d7a , 0xc9690bc9 , 0xe93effe5 , 0xf7e21b93 , 0x2a1c3380 ] )
+  , Mont::unsafe_make( [ 0x925614d6 , 0x5c2ede58 , 0xdbd8fdd8 , 0x607bbd40 , 0xb0c592a9 , 0x7412b60c , 0x565ac710 , 0x26efe4f2 ] )
+  , Mont::unsafe_make( [ 0x71adebac , 0xcab649a0 , 0x06a388ee , 0x5b020c5b , 0xa0d4827b , 0x921156e3 , 0xc0e51479 , 0x2eefb15b ] )
+  , Mont::unsafe_make( [ 0x4b2a5433 , 0x41848d13 , 0xe356ad51 , 0x948944bf , 0x7666cc6a , 0x03ae3519 , 0xe2537b3d , 0x0c1f17bc ] )
+  , Mont::unsafe_make( [ 0x0e060c86 , 0xc02e8f1e , 0x8398cf64 , 0x9e8c0dc4 , 0x621f0436 , 0x3e11b156 , 0xfc686236 , 0x10b9484d ] )
+  , Mont::unsafe_make( [ 0xcbabd545 , 0xa37af816 , 0xd5df249a , 0x9b373f56 , 0xfc4617ff , 0x3c4133b9 , 0x0f3e33c2 , 0x007319ae ] )
+  , Mont::unsafe_make( [ 0x4a7c1009 , 0xddae310f , 0xcbc017d5 , 0x910d5436 , 0x3316bc57 , 0x213f6066 , 0x16c3a4a0 , 0x087005c2 ] )
+  , Mont::unsafe_make( [ 0xfce65619 , 0xe4e64347 , 0xe96d5068 , 0xf9dc2a66 , 0xb8a6f0b1 , 0xdd890dc5 , 0xdea7ff15 , 0x0f418f6d ] )
+  , Mont::unsafe_make( [ 0xa162b272 , 0xf48652aa , 0x26759115 , 0x5da6ae6e , 0x0537e12a , 0x660819b8 , 0x01ac7fef , 0x1875e7a0 ] )
+  , Mont::unsafe_make( [ 0x2d7368b0 , 0x74969549 , 0x36428eda , 0x634bedef , 0xecb8d37c , 0x654d6375 , 0xde97a3fc , 0x20bf46b3 ] )
+  , Mont::unsafe_make( [ 0x071ab521 , 0xabaaf4a8 , 0xd4c81110 , 0xb4d65073 , 0x367b073f , 0xe9216bc2 , 0xaa557a81 , 0x17cbcc7f ] )
+  , Mont::unsafe_make( [ 0x2ef5dfd3 , 0x7d422969 , 0x4ff2779e , 0xb77a234b , 0x0a6b794c , 0xbc5b38ad , 0xdc34cfdf , 0x1dec3318 ] )
+  , Mont::unsafe_make( [ 0x1e8e443b , 0x813aa9d9 , 0xceb9d0a0 , 0xf3ec150e , 0x2aa1ade4 , 0x8b09d933 , 0xb8f2e51d , 0x1a493253 ] )
+  , Mont::unsafe_make( [ 0x1c1f2e62 , 0x54b122b4 , 0x54e8419d , 0x33de3012 , 0xcecec0e2 , 0x7707cdfb , 0x77398f59 , 0x1e164d0a ] )
+  , Mont::unsafe_make( [ 0x2c860324 , 0x8c1d1b80 , 0x73bdb050 , 0x48aa6c07 , 0xe9c9f8eb , 0xf93f90e7 , 0x7b5ae1ca , 0x07025d77 ] )
+  , Mont::unsafe_make( [ 0xd499068d , 0xa43a38b7 , 0xf44c978b , 0xfadf6eb7 , 0x9de83a20 , 0x5df6b563 , 0xba722bdc , 0x166a1e86 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x94e3c389 , 0x0bba6ed0 , 0xc9c46cfe , 0x866e18fc , 0xbe2b38e8 , 0xe2887f40 , 0x05f50b55 , 0x0cd4e568 ] )
+  , Mont::unsafe_make( [ 0x3334b105 , 0xb913a1e6 , 0xdc8c1054 , 0xedde8978 , 0xae5b9f3c , 0x73132d97 , 0x593fa7a5 , 0x1542bd00 ] )
+  , Mont::unsafe_make( [ 0xcbb1c86e , 0x3924b98c , 0x54a38886 , 0x1223e9b6 , 0x8a6dce05 , 0x74ca3240 , 0xfcfbd745 , 0x0909b4d6 ] )
+  , Mont::unsafe_make( [ 0x80aa755b , 0xb8ff7c7f , 0xfbfb78c5 , 0x579c2b57 , 0xc34bd2b2 , 0x93843460 , 0x7a1e2031 , 0x180042c5 ] )
+  , Mont::unsafe_make( [ 0x233813eb , 0xa40d4ece , 0x79e10e28 , 0xa9705d43 , 0x5a2f2cf5 , 0xe00054fe , 0x3c99a404 , 0x0e942457 ] )
+  , Mont::unsafe_make( [ 0xfb855e1e , 0x931c11e6 , 0xfa16ccc2 , 0x87cd81bc , 0x8f6c0060 , 0x2aadf608 , 0x76fd7ffe , 0x089a7d14 ] )
+  , Mont::unsafe_make( [ 0xedab9738 , 0x09a58c39 , 0x901cefb2 , 0x6e47ff8b , 0xd70283c0 , 0x00c368bb , 0xd3c23885 , 0x06a5f3db ] )
+  , Mont::unsafe_make( [ 0x8781b62e , 0xeb73b52e , 0x38104598 , 0x044d0751 , 0xbc77381d , 0x3c643787 , 0x011e49ec , 0x0cdc48d0 ] )
+  , Mont::unsafe_make( [ 0xb7be3054 , 0x929b100a , 0x11aeb028 , 0xa5636c42 , 0x2532b026 , 0x657be4c8 , 0xc8d00f29 , 0x2d5f7135 ] )
+  , Mont::unsafe_make( [ 0x89fcc101 , 0xbc30bce4 , 0x9757078f , 0xa1ac8a18 , 0x039443d4 , 0xe2328f64 , 0x1806b5ee , 0x0575d892 ] )
+  , Mont::unsafe_make( [ 0x6eedf42e , 0x4db104b5 , 0x9cee5c82 , 0x15600c95 , 0xee2f2e43 , 0x857ff52d , 0xe1b11e52 , 0x20ccc7ef ] )
+  , Mont::unsafe_make( [ 0x154ab821 , 0x1bd06a46 , 0x57f1c6cb , 0x1891bf4b , 0x7d252dee , 0x0571ab31 , 0xdce7265f , 0x2864697c ] )
+  , Mont::unsafe_make( [ 0x6c5b582c , 0x25aaa2ae , 0xb19a0a5b , 0x319514b2 , 0xf76978ec , 0xb38dc700 , 0x5442a911 , 0x15005fc7 ] )
+  , Mont::unsafe_make( [ 0xf4f7099f , 0x7e2b7a50 , 0x7f1e306b , 0x284ab356 , 0x52cecd6a , 0xd083dc28 , 0x9c1e00b7 , 0x1ee358a9 ] )
+  , Mont::unsafe_make( [ 0x8bc51609 , 0xadf71cfa , 0x9c633f2b , 0x45528375 , 0x5c72cad8 , 0x3d0ab38c , 0x39da92e3 , 0x037d809a ] )
+  , Mont::unsafe_make( [ 0xffbcc8ef , 0x5e887b78 , 0xff410d63 , 0x8f3bc026 , 0x86706057 , 0xb452ebdf , 0x46365ec4 , 0x15658dd9 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xfff44f89 , 0xf0b8517b , 0xfb7e5740 , 0xe9a67bdb , 0x7f6679ad , 0x48b8e1b4 , 0xcf16e316 , 0x03fbe835 ] )
+  , Mont::unsafe_make( [ 0x8facc051 , 0x9068e785 , 0xc8e6a05c , 0x3cd7b84e , 0x1b27c215 , 0x2cf0a3d2 , 0xae68c7b9 , 0x18bd116d ] )
+  , Mont::unsafe_make( [ 0xb25a4fba , 0x9f52f93b , 0xa1bc02c5 , 0xbde5add0 , 0x076666cc , 0x4fef7473 , 0x31d903fd , 0x29eb9cc3 ] )
+  , Mont::unsafe_make( [ 0x5c9c5e20 , 0x12fcf2f4 , 0x0bca804b , 0x47ba1026 , 0xef206b78 , 0x4cadaadd , 0x5ac321ad , 0x001691f0 ] )
+  , Mont::unsafe_make( [ 0xe5e7c0c0 , 0xba6d90a8 , 0xa621070c , 0xabfd4c38 , 0x395cf98c , 0x57ca1431 , 0x56d49d04 , 0x05ae385c ] )
+  , Mont::unsafe_make( [ 0xf6da9605 , 0xd4218162 , 0xf365be4a , 0x0377b0a8 , 0xa937c677 , 0x1d77bad2 , 0x1f9e3c10 , 0x03f42fcb ] )
+  , Mont::unsafe_make( [ 0x96ab1f68 , 0xb052ab40 , 0x070bf247 , 0x45b69c3c , 0x7147f543 , 0xce2ab847 , 0x45d480af , 0x29ec09cf ] )
+  , Mont::unsafe_make( [ 0xb7bac84c , 0x90ca1ef9 , 0x771d146b , 0xa20ce455 , 0xe94583d4 , 0xaba5cc67 , 0x2ef08147 , 0x2b9a6609 ] )
+  , Mont::unsafe_make( [ 0x158cabb9 , 0x771c4b06 , 0x1dd761d0 , 0x61e81c51 , 0x017194ee , 0x8d697fc5 , 0xf97898ec , 0x0923c63e ] )
+  , Mont::unsafe_make( [ 0xe04a3c8d , 0x0e4a24dd , 0x487121e8 , 0x497dd563 , 0x921e019c , 0x8ae9d062 , 0xc78730c9 , 0x1424344f ] )
+  , Mont::unsafe_make( [ 0x5a412ba4 , 0x879c949e , 0x9aef6c3d , 0xac5c949d , 0xbcf2d44f , 0x44ec13b6 , 0x27202c60 , 0x1825f547 ] )
+  , Mont::unsafe_make( [ 0xf3f867d5 , 0x13861e0e , 0x90619220 , 0x0e52b267 , 0x6fa8f319 , 0xdaf1715a , 0x413e4f2d , 0x0a57657b ] )
+  , Mont::unsafe_make( [ 0xd1113f41 , 0x421a749b , 0x96b185bc , 0xad5ab183 , 0xc5d9bbcf , 0xab62fdc9 , 0x592d2379 , 0x259f20db ] )
+  , Mont::unsafe_make( [ 0xc45ccf86 , 0xca5c26ed , 0x0784aae0 , 0x9bf8948e , 0x878f448a , 0x1fb5a60e , 0x5b185af5 , 0x14a7f8be ] )
+  , Mont::unsafe_make( [ 0x77c9b53d , 0x52eb3526 , 0x69312ad2 , 0xd775eb9c , 0xe39d1757 , 0xd99ac886 , 0x52b9ccfa , 0x2da9a367 ] )
+  , Mont::unsafe_make( [ 0x2d75ddbc , 0x892b452f , 0xfa069b30 , 0x6a8d2c8d , 0x860e576a , 0xf52aaf9d , 0x47950564 , 0x2517eac0 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x19165970 , 0xd8a0d03a , 0x723337f0 , 0x6f018308 , 0xbc6bf437 , 0xa12d2e12 , 0xf9e6aae8 , 0x07942ef9 ] )
+  , Mont::unsafe_make( [ 0x30d8dddb , 0x80a06b69 , 0x30a940e9 , 0xf6ff3f73 , 0x50f91449 , 0xaa2acb32 , 0x3198c85a , 0x14c01a7f ] )
+  , Mont::unsafe_make( [ 0xf265ab93 , 0x6b464bc0 , 0xb5e39087 , 0x2becc4f7 , 0xcfea3de0 , 0xa51d39c4 , 0x20fbf3fe , 0x0245088a ] )
+  , Mont::unsafe_make( [ 0x6bd0aa26 , 0x89e7856e , 0x30cc4c26 , 0x9bd04470 , 0xecc3cfcc , 0x13873db3 , 0x7c2494cb , 0x1941293b ] )
+  , Mont::unsafe_make( [ 0x2db0a16c , 0x42904ede , 0x6f233fd8 , 0x81b551bd , 0x18f09a2c , 0xb9aa40bd , 0x0cda9a6d , 0x2620a9d7 ] )
+  , Mont::unsafe_make( [ 0xabbf172e , 0xc79f8f8e , 0x6330b2d6 , 0xcf232b31 , 0xa0303d8b , 0xf9a373ca , 0x262c34d2 , 0x26370751 ] )
+  , Mont::unsafe_make( [ 0x5c9c3cae , 0xbbb88ac6 , 0x6c4d88b2 , 0x3d29da25 , 0x94129092 , 0xd13f4efd , 0xaf419f06 , 0x03d7acac ] )
+  , Mont::unsafe_make( [ 0xfcc7f807 , 0xd267b0d7 , 0x1ccaed0e , 0x7fd9d754 , 0x4b77cc77 , 0x02a50b13 , 0xc067ceac , 0x04553407 ] )
+  , Mont::unsafe_make( [ 0x2e49074b , 0x3ab0b289 , 0x9b63c687 , 0xd5344d4e , 0xb2322d99 , 0x01b3b577 , 0x2de39bee , 0x021bb974 ] )
+  , Mont::unsafe_make( [ 0x7acca4a3 , 0x094c0189 , 0x730f29e1 , 0xe958b206 , 0x41ca2cb9 , 0x10e1faf6 , 0x91588b4e , 0x0ce13478 ] )
+  , Mont::unsafe_make( [ 0x22eac665 , 0x45a6a9a1 , 0x39598066 , 0xaf39f83b , 0x4bedbce2 , 0xdb93c24d , 0x790b15c6 , 0x0659ad4a ] )
+  , Mont::unsafe_make( [ 0x26f668dc , 0xe38af8e5 , 0x8f8df6cc , 0xc1922491 , 0x079a14f5 , 0xfa2e2e37 , 0x0773af72 , 0x135df64a ] )
+  , Mont::unsafe_make( [ 0x674a610f , 0x86396427 , 0x63d080ab , 0x18ee1bc7 , 0x2e69ab6f , 0xd28ad3b0 , 0xd70b1bf6 , 0x2b8e9ad1 ] )
+  , Mont::unsafe_make( [ 0x6cefa911 , 0x7e0f9e7b , 0x5b50f1cd , 0xbab9f371 , 0x326ece78 , 0x25ac3f88 , 0xd9f73757 , 0x0378260b ] )
+  , Mont::unsafe_make( [ 0x097f9bc1 , 0xe62ad5c4 , 0x43334041 , 0xc66c0eb1 , 0x694addeb , 0xb388fde2 , 0xe3ecf6ac , 0x10df522b ] )
+  , Mont::unsafe_make( [ 0x2c4b911c , 0x6fbc5343 , 0x0590da37 , 0x3da55261 , 0xc2502d71 , 0xc41e8df5 , 0xb3d7e025 , 0x10b950c1 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xd85ce038 , 0x41dd4488 , 0x865a9463 , 0x8e1bcf48 , 0x2d2c250a , 0xe37cd819 , 0x1ad81804 , 0x2fdc8804 ] )
+  , Mont::unsafe_make( [ 0xc6f81358 , 0x89f9dda7 , 0x96ec5212 , 0x091344ea , 0x832a5acd , 0x188bee0a , 0x0a8ed5ac , 0x2b080dfc ] )
+  , Mont::unsafe_make( [ 0x84ca9fa2 , 0xdde560eb , 0xaa2288ef , 0xc31a2ed5 , 0x7d1dd8e4 , 0x24ace01b , 0xda5fa285 , 0x29912249 ] )
+  , Mont::unsafe_make( [ 0x5957df23 , 0x5c9c7f7d , 0xb6acf518 , 0x8bf595a8 , 0xede31e01 , 0xb80835c9 , 0xbc10978f , 0x09b379df ] )
+  , Mont::unsafe_make( [ 0x3cedbdeb , 0xa174485a , 0x78e6163f , 0x0b4b025c , 0xf2e93374 , 0x3de63524 , 0xe4346bb5 , 0x2b2d5cb3 ] )
+  , Mont::unsafe_make( [ 0xb18325bd , 0x03b189c8 , 0x8172bd32 , 0x0160d698 , 0xeaa5cf84 , 0x0d115231 , 0x954f150a , 0x2b63190b ] )
+  , Mont::unsafe_make( [ 0xfb943d34 , 0xe23a7788 , 0x1ef1d81f , 0x44ee58ae , 0x72ff95bf , 0xbe8969bc , 0x30c75fa7 , 0x0ac12def ] )
+  , Mont::unsafe_make( [ 0x2208cd7f , 0x0fbff959 , 0x66a32da4 , 0xbe98d1d8 , 0x39025b64 , 0x4cf06403 , 0xd38024b4 , 0x1f9ad3e4 ] )
+  , Mont::unsafe_make( [ 0xc7abfaf3 , 0x31b10116 , 0xd87daa50 , 0x5a36c0e9 , 0x0c1225b7 , 0xd75f75fa , 0xede7500d , 0x23601fcf ] )
+  , Mont::unsafe_make( [ 0x2d578fb4 , 0xaacd2b14 , 0xc53f355b , 0xb810a9ff , 0x0ae81d1f , 0x05e81a34 , 0xe6d0dc57 , 0x2a8611bc ] )
+  , Mont::unsafe_make( [ 0x8e822429 , 0xe2feee3d , 0x5681c9a0 , 0x65cc6925 , 0x5273f91f , 0x9a8c7675 , 0xbd34c991 , 0x2b652cae ] )
+  , Mont::unsafe_make( [ 0x3820e77c , 0x003002f6 , 0xf130634d , 0x55db8a29 , 0xf3864f48 , 0x4d64c4ef , 0x4b252418 , 0x1f84bce9 ] )
+  , Mont::unsafe_make( [ 0x92ca22d9 , 0x48a67907 , 0x39b6b475 , 0x29600909 , 0x654849fc , 0xa9adb29e , 0xe8261532 , 0x275661f4 ] )
+  , Mont::unsafe_make( [ 0xe563b336 , 0x706e088a , 0x898738e2 , 0x1e4a0212 , 0x5f2e0ab3 , 0xcd94aa1f , 0x1169c2b3 , 0x1f2d879a ] )
+  , Mont::unsafe_make( [ 0xec3e4a23 , 0x5c45ba4e , 0x317977cd , 0x4a89844b , 0x7f38a630 , 0x7c54625e , 0xa5a662ff , 0x114ac44b ] )
+  , Mont::unsafe_make( [ 0xb4521a99 , 0x17016a11 , 0xbc88be2b , 0xe3328452 , 0x6975ee6e , 0xea5e2d9f , 0xede9c7a9 , 0x086ada13 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x7dff4382 , 0x8739a7b0 , 0x89e8a95f , 0x505929fb , 0xe042c288 , 0x3412303f , 0x50f6e492 , 0x1e1bc842 ] )
+  , Mont::unsafe_make( [ 0xd7fb7071 , 0x77fb7b16 , 0x6c1ce7a1 , 0x4861c775 , 0xc533d436 , 0x63dd8373 , 0x4183d317 , 0x211b22b5 ] )
+  , Mont::unsafe_make( [ 0x970a5deb , 0x622bfdb6 , 0xe9b36db5 , 0xbb0a8a6e , 0xba3798fa , 0x4d2d5909 , 0x5f9df806 , 0x0569aad0 ] )
+  , Mont::unsafe_make( [ 0x51b5cb58 , 0x133080ab , 0x8659c3a4 , 0x75d2c4a5 , 0x4962b99a , 0xc1344c8e , 0x3b6e7294 , 0x03fda8c0 ] )
+  , Mont::unsafe_make( [ 0x7393c8c2 , 0xe009aea5 , 0x044a2c6d , 0xbff8a2e5 , 0x888f47b2 , 0xf7cc4ee1 , 0xf46adc00 , 0x145a9245 ] )
+  , Mont::unsafe_make( [ 0x5c63698c , 0x20f8effc , 0xa1dbcc4f , 0x05ad7c9c , 0xfd15bbd6 , 0x4c04457e , 0xe4eac410 , 0x2011a197 ] )
+  , Mont::unsafe_make( [ 0x1306b865 , 0x41d4e255 , 0x3bd99398 , 0x871aeac0 , 0x8dbde9d1 , 0xbbfd1bb1 , 0x1b6d3cf6 , 0x0ab87581 ] )
+  , Mont::unsafe_make( [ 0x7775aba0 , 0xf1c76a39 , 0xea2a4dff , 0xe054da34 , 0x83429e3c , 0x74a12dd2 , 0xa2fa61f7 , 0x253e943a ] )
+  , Mont::unsafe_make( [ 0x9d140e0b , 0xab5d4634 , 0x839fc22b , 0x8223fcc5 , 0xa4de7b22 , 0x84f24a17 , 0x86662a24 , 0x086eb22b ] )
+  , Mont::unsafe_make( [ 0x1dbf0abd , 0x0f62c35d , 0x60e0320e , 0xc6f92601 , 0x54c0a447 , 0xe4862b06 , 0x4d349125 , 0x2f28635e ] )
+  , Mont::unsafe_make( [ 0xb20539bb , 0xcb9dbf21 , 0x00a12870 , 0xf1e1271a , 0xfaa96c1e , 0x0d38a953 , 0xef28918e , 0x0567b676 ] )
+  , Mont::unsafe_make( [ 0x6376ff30 , 0x6833a67b , 0x2466a050 , 0x67394245 , 0x2fab8bd3 , 0xef74376a , 0x9be8a9fc , 0x003a1d07 ] )
+  , Mont::unsafe_make( [ 0xa9566a91 , 0x5bdaf5e3 , 0x25daf5fa , 0xbded2dbf , 0x19c09b09 , 0x4e20d748 , 0x15f2c066 , 0x18cd5586 ] )
+  , Mont::unsafe_make( [ 0x3644c316 , 0x6633863d , 0x06f083ac , 0xc4d63a00 , 0xdffcb4f5 , 0xc56cacde , 0x37756356 , 0x1bd58f0b ] )
+  , Mont::unsafe_make( [ 0xe7ad9a80 , 0x798538ba , 0x5615383f , 0xd1f8fde0 , 0x74582e4a , 0x6665914a , 0xcc92e1f0 , 0x060d0dda ] )
+  , Mont::unsafe_make( [ 0x9795a62b , 0x6b975840 , 0xde42c677 , 0xf7607bb3 , 0x61827cb2 , 0x4ad0c8e9 , 0xfbe16b0f , 0x169c2cc6 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x27a85d02 , 0x2598d679 , 0xaa881223 , 0x08a544a2 , 0xebb41fe2 , 0xe9fbef72 , 0x0a57d09e , 0x132496ed ] )
+  , Mont::unsafe_make( [ 0xc11eaee5 , 0xc16ef936 , 0x8ef7c3f6 , 0x2056ef9b , 0x28ffe707 , 0x5a3cfaff , 0x0a40d25f , 0x137bdca2 ] )
+  , Mont::unsafe_make( [ 0x3d835e30 , 0x788684aa , 0xfdc29894 , 0x8227eb81 , 0x9c27bcc1 , 0x6516afb0 , 0x19b127d1 , 0x219534fd ] )
+  , Mont::unsafe_make( [ 0x6072bd3b , 0xb5264b86 , 0xe9e1beb3 , 0x79f2cb51 , 0xcee5d7a6 , 0xa477343a , 0x1b6133b6 , 0x24c79b1b ] )
+  , Mont::unsafe_make( [ 0x28c9a86e , 0x1d405120 , 0xbec7e936 , 0x4dc16c4a , 0x98a0505c , 0x75bfdce1 , 0x84a1da7a , 0x165fbf5a ] )
+  , Mont::unsafe_make( [ 0x7e7f9e51 , 0xdc0224f9 , 0xee393f8b , 0x73a695f6 , 0x08a8a220 , 0x41e43a85 , 0x82294365 , 0x2f29b779 ] )
+  , Mont::unsafe_make( [ 0xe208cd12 , 0xc00f6db7 , 0x5f06fea8 , 0x993477b5 , 0x0aa38205 , 0x346e8e64 , 0x97faeef1 , 0x135b47f5 ] )
+  , Mont::unsafe_make( [ 0x0891ba3a , 0x16026601 , 0x523c849e , 0xb810ef7e , 0xe24b716c , 0x3372efbf , 0x93f8c2c1 , 0x2a99e5aa ] )
+  , Mont::unsafe_make( [ 0x5e9638a4 , 0x23b89552 , 0x220c7692 , 0x995be12a , 0x56c114be , 0x8ce09789 , 0xab077651 , 0x09a50423 ] )
+  , Mont::unsafe_make( [ 0xe614b90f , 0x4024f29d , 0xf7ff1090 , 0x98fc6d80 , 0xe955c810 , 0x228f4873 , 0x8d9f1dc5 , 0x2d067ce9 ] )
+  , Mont::unsafe_make( [ 0x2efb8aa4 , 0x2b6676d1 , 0x1f940c97 , 0x5cfa0928 , 0x11806316 , 0x33c373ee , 0x82db173d , 0x040f9027 ] )
+  , Mont::unsafe_make( [ 0x6ea75ae7 , 0xa8dfc700 , 0x0bc61daa , 0xf235655d , 0x41db7eec , 0x8c151664 , 0x3edbf6a3 , 0x19bf3de2 ] )
+  , Mont::unsafe_make( [ 0x07ae520d , 0x728c583a , 0x359afce9 , 0x35a4e752 , 0x01bd88bb , 0x67ceb756 , 0x87971253 , 0x25517a82 ] )
+  , Mont::unsafe_make( [ 0xba3d4b95 , 0xcdc6cd63 , 0x1aec75e9 , 0x5f203363 , 0xc51696c1 , 0x189877ac , 0x805f89c7 , 0x227550ed ] )
+  , Mont::unsafe_make( [ 0xc26a65fa , 0x77b7a594 , 0xb7f4da94 , 0xbd844622 , 0xfbd64e72 , 0x8834bad0 , 0x18cc7757 , 0x30180f13 ] )
+  , Mont::unsafe_make( [ 0x0f238065 , 0x340b863d , 0x91329220 , 0xb26d09e0 , 0x555ea351 , 0x88287578 , 0xe2c5fc45 , 0x1c7526eb ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x0a85cb87 , 0xc64da989 , 0xbd0416d9 , 0xa1a80ca9 , 0xd025d42c , 0x07cb61e3 , 0x27fc9e42 , 0x05ce1b76 ] )
+  , Mont::unsafe_make( [ 0x7723ba5e , 0x428c0932 , 0xefa4b7d7 , 0x761d2319 , 0x54b752d9 , 0xdf7d868f , 0x17465c08 , 0x07df2b58 ] )
+  , Mont::unsafe_make( [ 0x17f211d4 , 0x0da4f1cd , 0x61fe84fb , 0x8c5cba5a , 0x81617674 , 0x8843e587 , 0xa535ebab , 0x161fb86e ] )
+  , Mont::unsafe_make( [ 0xe8852e7b , 0x78c2a211 , 0xa18baa09 , 0x18c132aa , 0xf5d762ce , 0x65fbb567 , 0x9aa005d3 , 0x2138de80 ] )
+  , Mont::unsafe_make( [ 0xc5fe8f21 , 0xcf5641d9 , 0x07c6c3f7 , 0x5969de57 , 0xddba995c , 0x750e9909 , 0xf93bf1d2 , 0x27556857 ] )
+  , Mont::unsafe_make( [ 0xa89f8257 , 0xacf97f9f , 0x0c780622 , 0xd72ef2b8 , 0x9cbccd7f , 0xe4a8834d , 0x4ebb1356 , 0x0daa7742 ] )
+  , Mont::unsafe_make( [ 0xcac5b2f6 , 0x5d2f6faf , 0x59f16eb0 , 0x04ce6ff2 , 0x316af819 , 0xb7a25a3b , 0x0e80e1a2 , 0x0b40a0e0 ] )
+  , Mont::unsafe_make( [ 0x44ebd322 , 0x9521596e , 0xccdf1f94 , 0xf4d19716 , 0xa02ab541 , 0xb7fe7a38 , 0x3348fe58 , 0x2624c5f8 ] )
+  , Mont::unsafe_make( [ 0x54aa6bfe , 0xbb26747f , 0xaf3a3124 , 0xeff6130b , 0x9011d131 , 0x2acd84f1 , 0x003a25a8 , 0x163a3d69 ] )
+  , Mont::unsafe_make( [ 0x8bcb8026 , 0xf1ec75e9 , 0x46eadf47 , 0x291fb11a , 0x90dc898e , 0x40610bf9 , 0xbb551f9c , 0x11986e15 ] )
+  , Mont::unsafe_make( [ 0xe5eac1ab , 0x2bda82eb , 0x627368c7 , 0x82d42eca , 0x179cbb40 , 0x7c0fcdff , 0xd25e5292 , 0x1709e21a ] )
+  , Mont::unsafe_make( [ 0xab964b34 , 0x1cc29f8d , 0x5ff4ca49 , 0x782b49b0 , 0xcda83f33 , 0x9d241a62 , 0x0d2a98fe , 0x26e7854b ] )
+  , Mont::unsafe_make( [ 0x26de0e26 , 0xf629d6c6 , 0xe4f31939 , 0xa38e2cea , 0x49655f4c , 0xcdf6630c , 0x6cfde9af , 0x2c661c73 ] )
+  , Mont::unsafe_make( [ 0xc161f5d5 , 0x6c2ddeac , 0xe5a625e0 , 0xd5033b98 , 0x15c1bd19 , 0x4b0fc66b , 0x9e37f712 , 0x1da6cb34 ] )
+  , Mont::unsafe_make( [ 0x6a09160c , 0x6dd73727 , 0xb2b84774 , 0xf980632a , 0xdeda3aa5 , 0x9fee493a , 0x9985668f , 0x0fa704cb ] )
+  , Mont::unsafe_make( [ 0x2da69c04 , 0x3552f48b , 0x39711ea2 , 0xb4d61dfc , 0x42e76aeb , 0x54b49e7f , 0x9a008085 , 0x22785b79 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x58684fbc , 0xcab99b58 , 0x780fab42 , 0x55057617 , 0xe5355fa8 , 0x1af9175d , 0xfc96240c , 0x2f6f77b4 ] )
+  , Mont::unsafe_make( [ 0x335f7cfd , 0xad33d91c , 0xb57c2903 , 0xb1136ad5 , 0x6a278721 , 0x805e4e8c , 0x08ab8b80 , 0x2b1d5418 ] )
+  , Mont::unsafe_make( [ 0xfc4f9e48 , 0x50d48eda , 0x4ab90f64 , 0x67eec2d7 , 0x5b7a5d3a , 0x64a08847 , 0x80e10e4b , 0x0938fe5b ] )
+  , Mont::unsafe_make( [ 0xda27dc0e , 0xbff9ae53 , 0xb4edec9d , 0xa1943c71 , 0x8c7fff14 , 0x8dae486d , 0xd254c381 , 0x039c69ed ] )
+  , Mont::unsafe_make( [ 0x328fa826 , 0xea50a736 , 0x1b65b9a6 , 0xe2761801 , 0xf26c3ea4 , 0x2d9e00b8 , 0xf7a56338 , 0x066d6382 ] )
+  , Mont::unsafe_make( [ 0x80bb225c , 0x8e5c26af , 0xbb24aef9 , 0x523bf45b , 0xcb26154e , 0x7846e84a , 0x3a4aa4b7 , 0x1dfea333 ] )
+  , Mont::unsafe_make( [ 0x3b410e6d , 0xa3676479 , 0x6a67850b , 0x0d2a8efb , 0xa10305ad , 0x623ea4a5 , 0xbf69e4f4 , 0x0012ce69 ] )
+  , Mont::unsafe_make( [ 0x57fc43a7 , 0xff6ef6fa , 0xded78a73 , 0x3174e80f , 0xa138192f , 0x85f0fcd2 , 0x3b529ba2 , 0x0782fff5 ] )
+  , Mont::unsafe_make( [ 0x495ebe3f , 0x037859b3 , 0x6472efd4 , 0x0a97a57c , 0x4abcf5de , 0x52d99f3c , 0xdeb42bee , 0x05e88cdf ] )
+  , Mont::unsafe_make( [ 0xa5137366 , 0xc4cc8d1e , 0x35b6abb3 , 0xf78d32ed , 0x932bc6d8 , 0xeb469ea3 , 0x8b3fb800 , 0x23888ba1 ] )
+  , Mont::unsafe_make( [ 0xb28d5b94 , 0x2d1bad76 , 0xa784b91b , 0xa37a87d8 , 0xb58a880e , 0xdac4b775 , 0x91110138 , 0x27641818 ] )
+  , Mont::unsafe_make( [ 0xcea35c5f , 0x229dc606 , 0x35137fac , 0xffcb40af , 0x5e807dd5 , 0xf9e3b498 , 0x93e19b8a , 0x2ee13aad ] )
+  , Mont::unsafe_make( [ 0x7fb9e371 , 0x8e448f72 , 0x89f26296 , 0x0b6a5f3e , 0x8af0d37b , 0x1252ebd5 , 0x0e284bc4 , 0x15e0a697 ] )
+  , Mont::unsafe_make( [ 0xe860fa23 , 0xd2485f52 , 0x49041828 , 0xec57bd71 , 0xe2efa812 , 0x43f60f64 , 0xe5bcb063 , 0x2963afa5 ] )
+  , Mont::unsafe_make( [ 0x7dea1fab , 0x27cda4ee , 0x7e65af69 , 0x13517ade , 0xbce96a2e , 0x587b1cbf , 0x5abb7897 , 0x15d22c07 ] )
+  , Mont::unsafe_make( [ 0x214ed0f7 , 0x60131fcf , 0xe3a29134 , 0xe02a5d12 , 0xd8df8fd3 , 0x22cff0a3 , 0x1717be3d , 0x21ec4bb1 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x955687e2 , 0x1f38292b , 0x53d8c301 , 0xd98dfc32 , 0x533bd6da , 0xb0e6c8be , 0xcd30b450 , 0x0ee57657 ] )
+  , Mont::unsafe_make( [ 0xf290a56a , 0x1e45e622 , 0x9fa86b8d , 0xaebff3ec , 0xd5e99ee5 , 0xc8d4d1c8 , 0xb7f11fff , 0x2f4319e7 ] )
+  , Mont::unsafe_make( [ 0xf3a17b6b , 0x61d67769 , 0x6e395c22 , 0x89f8daf5 , 0x2201add9 , 0x54ae7527 , 0x3fbba98d , 0x05e0a777 ] )
+  , Mont::unsafe_make( [ 0xa7de5096 , 0xbf5a2824 , 0xd548922e , 0xb4d20bb9 , 0xa0df26df , 0xbfa372f2 , 0x5109b41d , 0x164f14cf ] )
+  , Mont::unsafe_make( [ 0x2945f195 , 0x40b34751 , 0xa41e6141 , 0x17d9e3ea , 0x672e2091 , 0x44ccb246 , 0x89901a9a , 0x156e0f5a ] )
+  , Mont::unsafe_make( [ 0x25756080 , 0x325b987b , 0xa88b35d9 , 0xb78e4975 , 0x440b6923 , 0xe2b28662 , 0xc9f5ee80 , 0x0a037d76 ] )
+  , Mont::unsafe_make( [ 0xd48ce365 , 0x5df6c7f2 , 0x784146fa , 0x5a811d28 , 0x31055302 , 0xaf7e70a7 , 0x3ca93f37 , 0x2dad08f6 ] )
+  , Mont::unsafe_make( [ 0x7abb92ec , 0xad6137fb , 0x4baef2ab , 0xf5a0ea81 , 0x83ce8376 , 0x7a574ec6 , 0x657f4490 , 0x11290f29 ] )
+  , Mont::unsafe_make( [ 0xf1b19a6f , 0x0c789bef , 0x6acca73b , 0x30aac399 , 0x023e34d6 , 0xa72759ac , 0x9afe659b , 0x281248e4 ] )
+  , Mont::unsafe_make( [ 0xe2df66d6 , 0x7c6c9dee , 0x3041f33f , 0xdf06fb1e , 0xd72c4461 , 0x5a488424 , 0x27b2b864 , 0x1cfba819 ] )
+  , Mont::unsafe_make( [ 0xf3606236 , 0x23da076f , 0x1a2ef12d , 0xaa4aef0f , 0xe646aeee , 0x6e9468e7 , 0x9bacffbb , 0x0723d5d7 ] )
+  , Mont::unsafe_make( [ 0x1147ae6f , 0xa578bdb7 , 0x2574cd5f , 0xf4b9c9a1 , 0x3150cfa0 , 0x6422edcc , 0x52259d8c , 0x0bc0228c ] )
+  , Mont::unsafe_make( [ 0x89fe5efc , 0x93dba45d , 0x915eda2e , 0xf840be19 , 0x3c64cfb7 , 0x6f123b0c , 0x45da5497 , 0x1b23301e ] )
+  , Mont::unsafe_make( [ 0xccafdd51 , 0x6a6cac65 , 0xacf52467 , 0x5cdcfc78 , 0x82521b78 , 0x1c50a0d6 , 0xeacf6ee2 , 0x1370859e ] )
+  , Mont::unsafe_make( [ 0xf596a752 , 0x356d7e3b , 0x589b70c3 , 0xfb0ed3fa , 0xe05fc248 , 0x72a8afd1 , 0x25aadda9 , 0x276f77c9 ] )
+  , Mont::unsafe_make( [ 0x92f7be1b , 0x946f74e5 , 0x492006ef , 0x2a4ef0d3 , 0x98634037 , 0x919b2526 , 0x211e787b , 0x1aef47e3 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0xa88a3228 , 0xf96300da , 0x6fbb674b , 0x3aef969c , 0x6ee75d8a , 0x92329801 , 0xfa6032e4 , 0x2524d8c9 ] )
+  , Mont::unsafe_make( [ 0x51a2ab77 , 0xfe672ee9 , 0xfb7d2a6a , 0x4529d20f , 0xefd290c7 , 0xe773f327 , 0xaf087219 , 0x301c8941 ] )
+  , Mont::unsafe_make( [ 0x6ed23bf4 , 0xe3070063 , 0x59574a19 , 0xc00b471b , 0x29e03af4 , 0x766cb160 , 0x9b126939 , 0x090fde9a ] )
+  , Mont::unsafe_make( [ 0xeb539897 , 0xed4c385f , 0x36f849ab , 0x855349ab , 0xbeef7e18 , 0x200b70d1 , 0x9091c297 , 0x0c709b78 ] )
+  , Mont::unsafe_make( [ 0xa3faa194 , 0xdbd4123e , 0xb621a68d , 0xf67c158e , 0x3fbb4727 , 0x787809f1 , 0x88bcc93b , 0x2cc7edcc ] )
+  , Mont::unsafe_make( [ 0x83d6276c , 0x8e29fc81 , 0xc65fc4d0 , 0xe880ccff , 0x18122c79 , 0x94ef6fcb , 0x5f05286b , 0x1e7c803b ] )
+  , Mont::unsafe_make( [ 0xc26e7c8e , 0xb959042a , 0xb9581680 , 0x94714c73 , 0x1aa87d3d , 0x8014702b , 0x07270b93 , 0x1a410214 ] )
+  , Mont::unsafe_make( [ 0x7cc9859a , 0xcaa14c46 , 0x7d18d083 , 0xe6b53769 , 0x3c674361 , 0x34670cdd , 0xa088ebad , 0x2688c155 ] )
+  , Mont::unsafe_make( [ 0x45b873e7 , 0xa1bc0a9d , 0xfac631a8 , 0x748b1ac0 , 0xc06fecf8 , 0x12418074 , 0x7bc6e67a , 0x29b93026 ] )
+  , Mont::unsafe_make( [ 0x8ce4c1d3 , 0x859e7c99 , 0x284c809a , 0xa0f59c57 , 0x36a9e01e , 0x73660314 , 0xbdbd2512 , 0x0b520026 ] )
+  , Mont::unsafe_make( [ 0xc40f7f63 , 0x4f49bf7e , 0x9b80f1ad , 0xe785a1b6 , 0xaa8d04b2 , 0x16a918a5 , 0x83a2404a , 0x21b151ab ] )
+  , Mont::unsafe_make( [ 0xa706f5ec , 0xd59053a0 , 0xbeaa6dd2 , 0x47ca84a2 , 0x06027059 , 0x522e0c0d , 0x71fe2023 , 0x2b34b015 ] )
+  , Mont::unsafe_make( [ 0x6b860954 , 0xe5620c9d , 0x2eb06492 , 0x01db249f , 0xe1f5847d , 0x87ef23d2 , 0x170afe52 , 0x06453cb4 ] )
+  , Mont::unsafe_make( [ 0x2e357538 , 0xb86a9419 , 0x2cd000e1 , 0x6c8d5adf , 0xb6e9ab0a , 0x7aa37881 , 0xe6e2ee01 , 0x1045383a ] )
+  , Mont::unsafe_make( [ 0xf3f0cd53 , 0x14c5a562 , 0x96f20c99 , 0xefeabf3e , 0x054b9fff , 0x04c50ea5 , 0x1f5a3651 , 0x14c6747b ] )
+  , Mont::unsafe_make( [ 0x35568cae , 0x35f06782 , 0xc25abd26 , 0x2f154d02 , 0x27fe4b5b , 0x1fdcfca5 , 0xc195510f , 0x0a8e9705 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x0e244539 , 0xffa704b2 , 0x412b196e , 0x9dd9359a , 0x3595a591 , 0x0aff0f5a , 0x952efb4b , 0x122cd079 ] )
+  , Mont::unsafe_make( [ 0x8536c391 , 0xfbb81bcf , 0x6737dbf9 , 0xfb976301 , 0xaf7d0458 , 0xe92997f3 , 0xb316bdb5 , 0x2b0a8d51 ] )
+  , Mont::unsafe_make( [ 0xb1d59692 , 0x15717918 , 0xf84d23cd , 0x59664b15 , 0xbfbd75e6 , 0xdab4da62 , 0x214b42ea , 0x138263a1 ] )
+  , Mont::unsafe_make( [ 0xa4c1f752 , 0x3e18f1fd , 0xc586bc54 , 0x4a6eadc8 , 0x99bf997d , 0x2e4f1a14 , 0xd4dadbd7 , 0x09eb4e32 ] )
+  , Mont::unsafe_make( [ 0x945e88c6 , 0xb75ee514 , 0xcd259d23 , 0x8144a2f3 , 0xf8d8968c , 0x2f42e947 , 0x359e6748 , 0x024ee02b ] )
+  , Mont::unsafe_make( [ 0xc82b1840 , 0x35815aa3 , 0xc48431ca , 0x3d28e16a , 0x3560312a , 0x020ff84a , 0xd1d0a595 , 0x18f9f1ca ] )
+  , Mont::unsafe_make( [ 0x3b725067 , 0x9adf7ca2 , 0x8e7cb792 , 0x5cb53b81 , 0x772023ef , 0x115b3cf0 , 0x36c11386 , 0x1f125e60 ] )
+  , Mont::unsafe_make( [ 0x289666ca , 0xf607e44e , 0xb342901d , 0xe29c61b4 , 0x6ec0a068 , 0xba8bf9c5 , 0x797278ef , 0x18780db9 ] )
+  , Mont::unsafe_make( [ 0x128176cb , 0x69513900 , 0x6abbd96c , 0xd1354349 , 0x450a3601 , 0xc578bd36 , 0x997b81f5 , 0x1fb002b3 ] )
+  , Mont::unsafe_make( [ 0xedee28e8 , 0x0b634a67 , 0xb8c0729c , 0x07e531e6 , 0x51442f8b , 0x1232e648 , 0xbd579bab , 0x1c4a16b6 ] )
+  , Mont::unsafe_make( [ 0xc37f9ed9 , 0xe387bf2c , 0x25a4df6f , 0xdb776ebb , 0x1138930b , 0x69f889f3 , 0xf56ccd5a , 0x1b8cf655 ] )
+  , Mont::unsafe_make( [ 0xd8f5c413 , 0x120093f9 , 0x3f632724 , 0xbe6005a7 , 0x1624d193 , 0x2c6ed220 , 0x8232a50f , 0x0a6011fe ] )
+  , Mont::unsafe_make( [ 0x9f7d13d7 , 0x506ac393 , 0xf80c471e , 0xe8e5f35f , 0x751dc019 , 0xf4a03478 , 0x9a6e1669 , 0x2f5b549b ] )
+  , Mont::unsafe_make( [ 0xa488246a , 0xd34ff8e7 , 0x20dfb5bf , 0xbcb91fa7 , 0xf00d3000 , 0xba689223 , 0x92380d68 , 0x277b0499 ] )
+  , Mont::unsafe_make( [ 0x5baaecb4 , 0xe801bf0a , 0x2d247898 , 0x03309d50 , 0xe03ad614 , 0x2d8d0c1a , 0x94589e77 , 0x229ef032 ] )
+  , Mont::unsafe_make( [ 0x7e6d6d19 , 0x8e16cc65 , 0xedb3d2ca , 0x59817811 , 0x4727942e , 0x20a7c414 , 0x3df33886 , 0x221e1309 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x9384a923 , 0xf58ead90 , 0x4f18fab5 , 0xc45be070 , 0x030c7dc6 , 0x011c4c56 , 0xe10ec707 , 0x0e4e306e ] )
+  , Mont::unsafe_make( [ 0x6be2e1ae , 0x42c1db2e , 0xd7f24564 , 0xbbb44a39 , 0xf36b31a9 , 0x6c9de9f3 , 0xb59e1902 , 0x0a0d3042 ] )
+  , Mont::unsafe_make( [ 0x8f09ac27 , 0x63008c12 , 0xe8dffb6b , 0xc4ef3a7f , 0xf79b7932 , 0x87d66720 , 0x43e4b085 , 0x1d7cbeab ] )
+  , Mont::unsafe_make( [ 0x5034b4e0 , 0xb94485e9 , 0x59c07518 , 0x8a9a98a8 , 0x2898dcb5 , 0xd671618c , 0x50d14954 , 0x121995a7 ] )
+  , Mont::unsafe_make( [ 0x351ff69c , 0x600fcddc , 0x283604ae , 0x78143c4d , 0xc96dd65b , 0x9629a6f4 , 0xc89aae1f , 0x2544db6f ] )
+  , Mont::unsafe_make( [ 0xbe56a24f , 0x3ce63030 , 0xfdaeaa57 , 0x34ffb1d6 , 0xa4d41ef4 , 0x14f53da3 , 0x84190a77 , 0x112fc834 ] )
+  , Mont::unsafe_make( [ 0xba4c8e81 , 0x78799e57 , 0xfd67b2dc , 0x825a7107 , 0x770ad672 , 0x84868b6f , 0xa5519449 , 0x026bd1f5 ] )
+  , Mont::unsafe_make( [ 0x465a3cf8 , 0xb14c31f3 , 0xed206da6 , 0xcfa2497a , 0xbd557140 , 0xa72335d4 , 0x9221e66f , 0x2093caf4 ] )
+  , Mont::unsafe_make( [ 0x7cd41e23 , 0x95abb033 , 0xd487e912 , 0xaab572b4 , 0xb5e26ed5 , 0x790701f2 , 0x5ba80626 , 0x13426dba ] )
+  , Mont::unsafe_make( [ 0xd1b9fa3b , 0xd1be1704 , 0xf79f19e5 , 0x215c51fc , 0x26644167 , 0x81ffa7fd , 0x2e2b502b , 0x248b06fd ] )
+  , Mont::unsafe_make( [ 0xd81030b9 , 0x70514279 , 0x939c965a , 0xb72c242b , 0x9dd0d006 , 0xd3ea7d14 , 0xd72d8825 , 0x27aa1773 ] )
+  , Mont::unsafe_make( [ 0x8845742d , 0x06cb6db0 , 0x2f0eb0f4 , 0x2dd127dc , 0x145744f0 , 0xfde0a1f7 , 0xc7bce7e1 , 0x1905e07c ] )
+  , Mont::unsafe_make( [ 0x2ee0b6c3 , 0xa6bd2c6c , 0xe450bef6 , 0xb7578b25 , 0x31993e62 , 0x010c24c0 , 0x750bab88 , 0x08520ff6 ] )
+  , Mont::unsafe_make( [ 0x38970fba , 0xcb0e0845 , 0x1020b97c , 0x2975e586 , 0x7ae28c7f , 0xef20aa7c , 0x9523a753 , 0x208aa132 ] )
+  , Mont::unsafe_make( [ 0xe7fa7186 , 0x47adf4e1 , 0xfd29ebf0 , 0xb0f49c73 , 0xef030657 , 0x194194b0 , 0x5745af01 , 0x2d6f7539 ] )
+  , Mont::unsafe_make( [ 0x8f7b1847 , 0xd009f2a5 , 0x8e015cba , 0xe9c155ea , 0xcffa161b , 0x4f8e9827 , 0x850aafd5 , 0x1d29f44e ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x732f9c47 , 0x2039ecdf , 0xc4d8cfc7 , 0xf1d61a11 , 0x8c3450c1 , 0xeb8a8c2e , 0x7cd6786f , 0x0d2267b9 ] )
+  , Mont::unsafe_make( [ 0x5e150549 , 0x1695f4a9 , 0xf5d24f6c , 0xb20d0384 , 0x520df619 , 0xca6d928f , 0x94a1a260 , 0x1b9f3c86 ] )
+  , Mont::unsafe_make( [ 0xc9f42589 , 0x1c7e4569 , 0x34878e8f , 0xf4ccebc0 , 0xb84a8fb4 , 0xa6fd36c2 , 0x29c85c16 , 0x29e26dff ] )
+  , Mont::unsafe_make( [ 0x3847ee32 , 0x0ce93bda , 0x2e56e5ca , 0xcd334eac , 0x863ec819 , 0x665163e5 , 0x287e855a , 0x137ec6e0 ] )
+  , Mont::unsafe_make( [ 0xa0351142 , 0x901d2fa6 , 0xc3198394 , 0x1279cc2c , 0x813bbc5e , 0x3d8862c8 , 0xf7d0e06b , 0x10da739a ] )
+  , Mont::unsafe_make( [ 0x8cd64416 , 0xd39e12d6 , 0xd4e94354 , 0xec09f6da , 0x3f69165e , 0x9e109f47 , 0x7d3c80a0 , 0x018fbb71 ] )
+  , Mont::unsafe_make( [ 0x0aeaaf5d , 0xdfb14f1f , 0x50030161 , 0xe253751d , 0xbb586af2 , 0xa53ea426 , 0x8fd30408 , 0x0a5367eb ] )
+  , Mont::unsafe_make( [ 0x0b9bbbd9 , 0xafc5417f , 0x2ef5ba70 , 0x9ccad79b , 0x52d222bc , 0xeb8bd27c , 0xbe6e43ee , 0x2d60e3ba ] )
+  , Mont::unsafe_make( [ 0xbd04c770 , 0xb42c4412 , 0xe9a78568 , 0x6a4937f6 , 0x702ba287 , 0x70efc107 , 0x753ac734 , 0x273322e6 ] )
+  , Mont::unsafe_make( [ 0xc71e46dc , 0x7fd8697f , 0xfa10ec46 , 0x6b2e00b5 , 0xcf72e525 , 0xae791b43 , 0xd131fa4e , 0x25a257c1 ] )
+  , Mont::unsafe_make( [ 0x7cc3497b , 0x25b5d268 , 0xffb08607 , 0x10a743f4 , 0x3ab7fd03 , 0xc89553b1 , 0x9ebba5ec , 0x1c2af3b9 ] )
+  , Mont::unsafe_make( [ 0x3f32b1d0 , 0xb411734b , 0xde43fc95 , 0x30c28bbb , 0xadb86c82 , 0x4be1b4de , 0xfb1bdad5 , 0x077ba010 ] )
+  , Mont::unsafe_make( [ 0x0422c975 , 0x5f048047 , 0xca498427 , 0xd4a1d296 , 0x654db6ae , 0xc5147bd2 , 0x7b968b25 , 0x000afe5b ] )
+  , Mont::unsafe_make( [ 0xf6176483 , 0xa56d5486 , 0x95a646f1 , 0x8e473163 , 0xc7ee3953 , 0xcea6a662 , 0xb1e90d87 , 0x0424b0d4 ] )
+  , Mont::unsafe_make( [ 0x5c1e56e4 , 0x31132834 , 0x9ab9914f , 0x014ad98a , 0xeb7ca2ad , 0xd7a2470a , 0x1fd4f89e , 0x060744b9 ] )
+  , Mont::unsafe_make( [ 0x94ddab27 , 0x5ed40ca9 , 0x35b37bd3 , 0x79812abf , 0x198b9fc5 , 0x433217b2 , 0x913532b0 , 0x07e4dd81 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x6e55300b , 0xc526e402 , 0x2ce90c11 , 0xd753099d , 0x984a152b , 0x8853d384 , 0x8f612063 , 0x2f40151e ] )
+  , Mont::unsafe_make( [ 0x6f34f336 , 0x65c1472e , 0x3fb83217 , 0x2702f83d , 0x494ae816 , 0x55c5503c , 0x8c110567 , 0x2e01d9a6 ] )
+  , Mont::unsafe_make( [ 0xb620615c , 0x87112cba , 0x27c77b14 , 0xf6fc93dd , 0x0c91fc7a , 0x3bdd5e79 , 0xaa1172f2 , 0x2ce6dd5a ] )
+  , Mont::unsafe_make( [ 0x84417506 , 0xc7e8b9af , 0x36c48608 , 0xac94b95b , 0xca977dae , 0x9ff66d1d , 0x103001b6 , 0x2087a428 ] )
+  , Mont::unsafe_make( [ 0x771cbd47 , 0xeaeccb2c , 0x176d4223 , 0x50403dd2 , 0x1dbfa53c , 0xc5ac31ec , 0xb53119a0 , 0x05f684c3 ] )
+  , Mont::unsafe_make( [ 0x27f1b871 , 0x7d494c3a , 0x710e3be6 , 0x898d28a6 , 0xa3b7a3e9 , 0x925c0afa , 0xccc4688b , 0x2dfb816a ] )
+  , Mont::unsafe_make( [ 0xa25b49f6 , 0x9ce6f9a8 , 0x4484d3da , 0x725f739b , 0xe4cad3d9 , 0xb018333e , 0x27604669 , 0x0fde1f01 ] )
+  , Mont::unsafe_make( [ 0x01ead9f6 , 0x821b3ad1 , 0xbd73133d , 0xe3ba61b1 , 0x12f595e9 , 0xb6be012c , 0x156435a6 , 0x07519269 ] )
+  , Mont::unsafe_make( [ 0x34248169 , 0x1d7d9304 , 0xe76788a0 , 0x86fd7f43 , 0x48d8c477 , 0x50b23d3b , 0xa3187cb2 , 0x1388fa55 ] )
+  , Mont::unsafe_make( [ 0xda84e548 , 0x50ed58ea , 0x11653589 , 0x1b0e158b , 0xee7a9aea , 0xe322d7b6 , 0x0fbff994 , 0x2ce9c042 ] )
+  , Mont::unsafe_make( [ 0xb1de13ec , 0x2b275d63 , 0x58eefe88 , 0x1631982a , 0x80a23612 , 0x538ab88b , 0x115c0ad8 , 0x2207f7de ] )
+  , Mont::unsafe_make( [ 0x3021c7c4 , 0x5dfa1338 , 0x4e617531 , 0xc9d8cd75 , 0x5f1f4135 , 0x9741581e , 0xaa30b931 , 0x23044516 ] )
+  , Mont::unsafe_make( [ 0xe937a79f , 0xed6bd400 , 0xeed85628 , 0xbf5c540a , 0x1ba9afd3 , 0x6b3b9149 , 0x9f776be3 , 0x06f8abd2 ] )
+  , Mont::unsafe_make( [ 0x3ae23258 , 0xb9957de9 , 0xcfb416ca , 0x01c95f28 , 0x9974d077 , 0xf0d73649 , 0xfef4e26c , 0x0a5944c6 ] )
+  , Mont::unsafe_make( [ 0xf6fe1272 , 0xe34c2703 , 0x2e61d027 , 0x744a3366 , 0x635c813d , 0xd0ae32c6 , 0xd43a8bb6 , 0x19417782 ] )
+  , Mont::unsafe_make( [ 0xf6d4dd79 , 0xeade0050 , 0x06e98b2b , 0x898b6107 , 0x1f856663 , 0xac51913e , 0xd73e8c41 , 0x2b5cd4eb ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x63c5ff10 , 0x5ad0cf87 , 0x431fdb23 , 0x3d1bb239 , 0x2b152cef , 0x6d66cbf7 , 0x8736a29d , 0x2c0390ff ] )
+  , Mont::unsafe_make( [ 0x890c6303 , 0x7f5ecb21 , 0x2983b782 , 0x5db535fa , 0xfe5cb132 , 0xb314dbaf , 0x32209ef1 , 0x2bc58dee ] )
+  , Mont::unsafe_make( [ 0xe9dbbe1e , 0x9037567f , 0x752d9838 , 0xeb23d61d , 0x8d78d4b6 , 0xb30f6473 , 0x168c0036 , 0x019ca258 ] )
+  , Mont::unsafe_make( [ 0x90eadc28 , 0x6f3f9af6 , 0xbc5a3db3 , 0x52140f67 , 0x75b4879c , 0xef9be7bd , 0x0fe03d64 , 0x2c954467 ] )
+  , Mont::unsafe_make( [ 0x928a0f2f , 0x18b5f59d , 0xea61a8ea , 0x20f3f1b7 , 0x3dece220 , 0xa45676c2 , 0xf82dd548 , 0x1b5747d9 ] )
+  , Mont::unsafe_make( [ 0xf89d517b , 0xe8b85b48 , 0x0cb1ee38 , 0x1f9e3a70 , 0xd011e2ce , 0xc913f65b , 0x70287679 , 0x27a3f344 ] )
+  , Mont::unsafe_make( [ 0xeef1f5b2 , 0x9cb865b7 , 0x0e6dea89 , 0x4be6c85e , 0xb0a90f51 , 0xb3a2fa08 , 0xd1cc8628 , 0x258f1449 ] )
+  , Mont::unsafe_make( [ 0x0a3b2b37 , 0xd7ddaed8 , 0x577c1a99 , 0xc0ad0f26 , 0xb4bfb798 , 0x202b6a9c , 0xa607658d , 0x165f6010 ] )
+  , Mont::unsafe_make( [ 0x4aa4c3d7 , 0xb2c2c06e , 0x78613343 , 0x79049658 , 0x18b78d7b , 0xbd6b8519 , 0xa7216f09 , 0x1f71b463 ] )
+  , Mont::unsafe_make( [ 0x345705ba , 0x022e46f1 , 0x9df6e28e , 0xa5eddfa9 , 0x3d461f39 , 0xc1d967e9 , 0xc14e20c8 , 0x2c537c37 ] )
+  , Mont::unsafe_make( [ 0xbbfb1870 , 0xd150e33f , 0x2d092ce3 , 0xf6f4e15e , 0xed326f94 , 0x9a3515af , 0x254fcd9a , 0x03e96a45 ] )
+  , Mont::unsafe_make( [ 0x6c99f7a6 , 0xca52bbe9 , 0x6eb2b205 , 0x9e9b4ff5 , 0x0d00cd02 , 0xb5bdaba2 , 0x93c2e193 , 0x29ed1177 ] )
+  , Mont::unsafe_make( [ 0x8a950ee9 , 0xee5fd8a2 , 0x0da07882 , 0x8685d5ae , 0xa3a0e031 , 0x69d1d6dc , 0x94ddc16d , 0x29948eb5 ] )
+  , Mont::unsafe_make( [ 0xfeae0041 , 0x38bc3b65 , 0x2b1cd790 , 0x238f6142 , 0xbf40cf2e , 0x63248724 , 0xbd7e3a1e , 0x274832b7 ] )
+  , Mont::unsafe_make( [ 0xa9ccbb8f , 0xb2dfd6d7 , 0x4fc700ec , 0xff0c1234 , 0x5b082794 , 0xc5af493b , 0x4498c8c8 , 0x2a26d356 ] )
+  , Mont::unsafe_make( [ 0x427dcbcc , 0xb81163d4 , 0x3fc95576 , 0xb7b0ee15 , 0x8f0fb900 , 0x34ddd1de , 0x9cd1a06d , 0x293e5453 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x531a0ef3 , 0x651d2cc7 , 0x082e661d , 0x3c02301a , 0x2903f270 , 0x8fb34cbf , 0xf781a5ba , 0x049179cf ] )
+  , Mont::unsafe_make( [ 0x6ac43929 , 0xde77e0f6 , 0xaed2db72 , 0x3f9840fc , 0x08d011e0 , 0x0300071a , 0x670ed891 , 0x2a73af37 ] )
+  , Mont::unsafe_make( [ 0x5ea7e37d , 0x435eca2b , 0xa9f733f5 , 0xda9f73a3 , 0x32b4e928 , 0xdc4ac60a , 0xc23fe053 , 0x0f4c100a ] )
+  , Mont::unsafe_make( [ 0x0af0f8cd , 0x5139f0e2 , 0xee59bb9b , 0xebdc3a35 , 0xef25c304 , 0x94199f0a , 0xd9637691 , 0x30233db5 ] )
+  , Mont::unsafe_make( [ 0x04aba49b , 0x122fad8b , 0x3f53767a , 0x41599b55 , 0xe2adb719 , 0xa36f7b89 , 0x39847967 , 0x25829bdc ] )
+  , Mont::unsafe_make( [ 0xe6f07864 , 0x47803f37 , 0x2cc56cab , 0xcb0edc91 , 0x665b7581 , 0x7b653f71 , 0x1441362f , 0x0f91da1a ] )
+  , Mont::unsafe_make( [ 0xb16932ee , 0x85bb353e , 0xc842a59b , 0x54fc0e5a , 0x5a8d42c3 , 0x196d15bb , 0xf395d4f6 , 0x07e7004f ] )
+  , Mont::unsafe_make( [ 0x5e045067 , 0xe887c081 , 0x554b462c , 0x05bc5922 , 0x1e25b793 , 0x90896538 , 0x56364a73 , 0x2adde193 ] )
+  , Mont::unsafe_make( [ 0x8f9e1ae7 , 0xdf6690a0 , 0x04f78719 , 0xdb1be927 , 0x22fc4696 , 0xedcf0dfb , 0x1c3a8d94 , 0x2b2b98d5 ] )
+  , Mont::unsafe_make( [ 0xc87b798d , 0x6656bce8 , 0xf5c63109 , 0x7956907b , 0xfd6e23b7 , 0x3ea3825e , 0xbe68d45e , 0x0255c11c ] )
+  , Mont::unsafe_make( [ 0x7aba4e9f , 0x10dbc675 , 0x8241868a , 0x403ce82e , 0x3f491a46 , 0x4df7d152 , 0x28861803 , 0x2a981462 ] )
+  , Mont::unsafe_make( [ 0xc7e419c8 , 0x2a8eba7a , 0x120d84a7 , 0x3a7cdf01 , 0x1036a52a , 0x7eb425e2 , 0xf9b2b656 , 0x241179e9 ] )
+  , Mont::unsafe_make( [ 0x4588602a , 0xc6cad596 , 0xd2cb1e4d , 0x8ab17c3d , 0x6180f70e , 0x489d304b , 0x5f52d4f5 , 0x2cb6bc74 ] )
+  , Mont::unsafe_make( [ 0x08d9b324 , 0xa9fac4ee , 0x6ba80d17 , 0x600febf5 , 0xaff074c1 , 0xf3dc9451 , 0xd1f3ab11 , 0x1f398e35 ] )
+  , Mont::unsafe_make( [ 0xc508fc7d , 0xcdbe205c , 0x35a132a7 , 0xdfec98eb , 0xd5a86508 , 0x88bf6b0d , 0x244d90a1 , 0x2c85e190 ] )
+  , Mont::unsafe_make( [ 0x5c08e5a3 , 0x1ffc0486 , 0x5b5cf966 , 0x95ed41f7 , 0xd4954eed , 0xb450aef9 , 0xd01ca3c4 , 0x1c8ebc8a ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x697ae2c0 , 0x2b3834f1 , 0xa464ecfd , 0x5f1d5569 , 0x436df9a6 , 0x4b80e124 , 0x36bb845f , 0x248dcc88 ] )
+  , Mont::unsafe_make( [ 0x96beacd8 , 0x502df3f2 , 0x598da070 , 0xd55a2f4a , 0x67ec0619 , 0x66f9be82 , 0xa86a8c5c , 0x2c4ec70b ] )
+  , Mont::unsafe_make( [ 0xd77e4495 , 0x87a4066c , 0xa1659e2f , 0xf4fa1d7a , 0x9d774bb7 , 0xcdf110eb , 0x6d1c074a , 0x2b3ec575 ] )
+  , Mont::unsafe_make( [ 0x6bf3c86a , 0xeef20f14 , 0x86a8ded1 , 0x79ac342a , 0xb3a24479 , 0x74c42dac , 0x5fe7794e , 0x091f3db3 ] )
+  , Mont::unsafe_make( [ 0x5fd2bab3 , 0x3732cb26 , 0xeab94c34 , 0xab2dba3c , 0x98af1972 , 0x2e74577f , 0x22a2ac6e , 0x13d15d3b ] )
+  , Mont::unsafe_make( [ 0x6799c79d , 0x1c195d90 , 0x9eab8c9d , 0x421b5ff5 , 0x16962240 , 0xebb448ab , 0x5d332c5a , 0x15cd8681 ] )
+  , Mont::unsafe_make( [ 0xd34a6d54 , 0x894e6f66 , 0x66c225d8 , 0x6dee3ca0 , 0x465ca3eb , 0xb1ea2257 , 0xce9dc178 , 0x247f1567 ] )
+  , Mont::unsafe_make( [ 0xa0dfc7fc , 0x2986f494 , 0xedbecb54 , 0x15c32196 , 0x1ae8fe24 , 0x5f4ed3eb , 0x9747d2b1 , 0x1c1ef31b ] )
+  , Mont::unsafe_make( [ 0xf3e31765 , 0x849c2e58 , 0x524050d7 , 0xaa54fba2 , 0xee3a9d1d , 0xb36c3382 , 0xb1fae88e , 0x03fee4bd ] )
+  , Mont::unsafe_make( [ 0x6f1e732a , 0x4feb2846 , 0x27c0a497 , 0xeeb196a4 , 0xf0276750 , 0xa5b29c93 , 0x4d4d8439 , 0x0653e895 ] )
+  , Mont::unsafe_make( [ 0x655209e7 , 0x93245f8a , 0xcb650063 , 0xfd408381 , 0xb5f5740d , 0xcd946fcf , 0xa4f15b5e , 0x2ed5b317 ] )
+  , Mont::unsafe_make( [ 0xc01742e2 , 0x141042ce , 0x8d7754cf , 0x66e89744 , 0x364739cd , 0x190b7725 , 0xa7db7d5c , 0x25924acb ] )
+  , Mont::unsafe_make( [ 0xbba511a7 , 0xe0095dbf , 0xb791b4db , 0xb9f9df8f , 0x2ae1a353 , 0x8fb019b2 , 0xd0e8eeb0 , 0x1bf5f666 ] )
+  , Mont::unsafe_make( [ 0x886055b5 , 0xf1402682 , 0x4fddd238 , 0x391f7574 , 0x85a342b0 , 0xd747b19a , 0x5e618a03 , 0x030f1288 ] )
+  , Mont::unsafe_make( [ 0x3e763ee7 , 0x75e096b5 , 0x13221afb , 0xb2c53154 , 0x8d9d402b , 0xcd23f0e2 , 0x9e358ff5 , 0x0d3ca425 ] )
+  , Mont::unsafe_make( [ 0x42e60026 , 0x87b468e8 , 0x6b8632f6 , 0xdb80c729 , 0x45b1c608 , 0x5c2297e9 , 0x70d50500 , 0x2197eec5 ] )
+  , Mont::unsafe_make( [ 0x4d8567bf , 0x44776690 , 0x9253cb2d , 0x1c57e6b7 , 0x7ab9a1c8 , 0x51acb0f6 , 0xed6d914f , 0x1cb4e8b5 ] )
+  , Mont::unsafe_make( [ 0x9de00648 , 0xec8d089c , 0xaaae0539 , 0xc13cefdc , 0x22e72983 , 0xabbe30b6 , 0xb85f46aa , 0x03a75ebc ] )
+  , Mont::unsafe_make( [ 0x7f221191 , 0x63973469 , 0xae6369ff , 0xa668bc52 , 0xe6cf043c , 0xee39fc70 , 0x6d601e99 , 0x0a93935d ] )
+  , Mont::unsafe_make( [ 0x56ba7cb0 , 0x7fc61aa5 , 0x0ef0fe1e , 0xac6f5425 , 0x5543cedb , 0x02dfac6c , 0xa0b4a4b1 , 0x0a1ba100 ] )
+  , Mont::unsafe_make( [ 0xea9a33b3 , 0xe69b1b59 , 0xb3655c97 , 0xfc504a01 , 0x962180ff , 0x8a247208 , 0xd0928cfd , 0x02859b42 ] )
+  , Mont::unsafe_make( [ 0x5adb9c86 , 0x9d6f23b4 , 0x7cb9f0e3 , 0x703da180 , 0xb0c6ec8d , 0x9e9e15f0 , 0xc2ff1237 , 0x109cb8c9 ] )
+  , Mont::unsafe_make( [ 0x73466b8c , 0x76622fa6 , 0xb074360d , 0xedffdfce , 0x75cda4c3 , 0x355d7248 , 0x3e2b39f8 , 0x2b67c62b ] )
+  , Mont::unsafe_make( [ 0x5da54217 , 0x03855f31 , 0xcb633ece , 0x94c2f3fc , 0x8bf1c7a1 , 0xb80bb0dc , 0xbc000aa8 , 0x1f4f4167 ] )
+  , Mont::unsafe_make( [ 0x481a29bc , 0x33ab1003 , 0x5cd317d7 , 0xee8eacef , 0x4ed4c4c9 , 0x7c29b27f , 0x1795d89d , 0x265ec029 ] )
+  , Mont::unsafe_make( [ 0x7243f6d8 , 0x7e7083a0 , 0x0c94b77a , 0xb8c7eee4 , 0x655726ae , 0x280253be , 0xeaf92fef , 0x26f1b7e9 ] )
+  , Mont::unsafe_make( [ 0xf40cc989 , 0x338ffacd , 0x2bc1c676 , 0x46745de7 , 0x71319719 , 0xc99780aa , 0x2fd62565 , 0x1da55b3b ] )
+  , Mont::unsafe_make( [ 0x0592adb9 , 0x9bcf846b , 0xabe8d4e8 , 0x6bde3de2 , 0xc2aeb1d7 , 0x0bb7a94b , 0xd14d99df , 0x1a266fb9 ] )
+  , Mont::unsafe_make( [ 0xe1d43ff7 , 0x1c30902b , 0x98a63431 , 0xab6cddb6 , 0x75e962e3 , 0x026924a4 , 0x67438b82 , 0x1ee583c3 ] )
+  , Mont::unsafe_make( [ 0x4eb0a9bd , 0x5ed0e7f5 , 0x30572d9e , 0xb45e664a , 0x712057ac , 0x8bcadb4e , 0xd83d85a7 , 0x164cffae ] )
+  , Mont::unsafe_make( [ 0xf1b24ecd , 0x2076110f , 0x0370350a , 0xd9e44f5c , 0x5038a1ad , 0xf3085fad , 0xc04c235e , 0x12fa7587 ] )
+  , Mont::unsafe_make( [ 0xaccb8db7 , 0xe703896a , 0x17cae125 , 0x8ef1887f , 0xd1cbcd94 , 0xe01f8961 , 0x0deee04c , 0x1f6f9235 ] )
+  , Mont::unsafe_make( [ 0x07114704 , 0x64da9b53 , 0x96d7dd9f , 0x173b5f37 , 0x1be86711 , 0x7185dca4 , 0xcea1fa56 , 0x15174305 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
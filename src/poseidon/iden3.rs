@@ -0,0 +1,158 @@
+
+//
+// variable-length hashing, following `go-iden3-crypto`
+//
+// These are the `SpongeHash` / `HashBytes` constructions of `go-iden3-crypto`'s
+// `poseidon` package (used by the iden3 / Polygon ID identity protocols), on top
+// of the circomlib Poseidon compression function.
+//
+// The input is split into frames of `FRAME` elements (16 by default). Each full
+// frame is compressed (`compress::<FRAME>`, that is, Poseidon with `t = FRAME+1`),
+// and the result becomes the first element of the next frame; the last, partial
+// frame is padded with zeros. Byte strings are first packed into field elements,
+// 31 bytes each, interpreted as big-endian integers (the last chunk is padded with
+// zero bytes on the right).
+//
+// note: `go-iden3-crypto` returns `nil` for empty input, we return `None`.
+//
+// note: this follows the Go source, but it's not yet tested against the vectors of
+// `go-iden3-crypto` (`TestSpongeHash`, `TestHashBytes`); the tests below only check
+// the single-frame case against circomlibjs, and the chaining and packing rules 
+// against our own compression function.
+//
+
+use crate::bn254::field::*;
+use crate::poseidon::permutation::{Params,PoseidonCompress,compress};
+
+//------------------------------------------------------------------------------
+
+// the default frame size
+pub const SPONGE_INPUTS: usize = 16;
+
+// the number of bytes packed into a single field element
+pub const SPONGE_CHUNK_SIZE: usize = 31;
+
+//------------------------------------------------------------------------------
+
+// `SpongeHashX`, with frame size `2 <= FRAME <= 16`
+pub fn sponge_hash_x<const FRAME: usize>(inputs: &[Felt]) -> Option<Felt> where Params: PoseidonCompress<FRAME> {
+  assert!( FRAME >= 2 , "iden3::sponge_hash_x: incorrect frame size" );
+
+  let mut frame: [Felt; FRAME] = [Felt::zero(); FRAME];
+  let mut hash:  Option<Felt>  = None;
+  let mut dirty: bool  = false;
+  let mut k:     usize = 0;
+
+  for x in inputs {
+    dirty    = true;
+    frame[k] = *x;
+    if k == FRAME-1 {
+      let h: Felt = compress::<FRAME>(frame);
+      hash     = Some(h);
+      dirty    = false;
+      frame    = [Felt::zero(); FRAME];
+      frame[0] = h;
+      k = 1;
+    }
+    else {
+      k += 1;
+    }
+  }
+
+  if dirty {
+    hash = Some( compress::<FRAME>(frame) );
+  }
+  hash
+}
+
+// `SpongeHash` (frames of 16 elements)
+pub fn sponge_hash(inputs: &[Felt]) -> Option<Felt> {
+  sponge_hash_x::<SPONGE_INPUTS>(inputs)
+}
+
+//------------------------------------------------------------------------------
+
+// packs bytes into field elements, 31 bytes each (big-endian, the last
+// chunk is padded with zeros on the right)
+pub fn bytes_to_felts(msg: &[u8]) -> Vec<Felt> {
+  msg.chunks(SPONGE_CHUNK_SIZE).map( |chunk| {
+    let mut buf: [u8; 32] = [0; 32];
+    buf[1..(1+chunk.len())].copy_from_slice(chunk);
+    Felt::try_from_be_bytes(buf).unwrap()     // less than 2^248, so always in range
+  }).collect()
+}
+
+// `HashBytesX`, with frame size `2 <= FRAME <= 16`
+pub fn hash_bytes_x<const FRAME: usize>(msg: &[u8]) -> Option<Felt> where Params: PoseidonCompress<FRAME> {
+  sponge_hash_x::<FRAME>( &bytes_to_felts(msg) )
+}
+
+// `HashBytes` (frames of 16 elements)
+pub fn hash_bytes(msg: &[u8]) -> Option<Felt> {
+  hash_bytes_x::<SPONGE_INPUTS>(msg)
+}
+
+//==============================================================================
+// *** TESTS
+
+#[cfg(test)]
+mod test {
+
+  use super::*;
+
+  fn felts(n: u32) -> Vec<Felt> {
+    (1..=n).map( Felt::from_u32 ).collect()
+  }
+
+  #[test]
+  fn sponge_empty() {
+    assert_eq!( sponge_hash(&[]) , None );
+    assert_eq!( hash_bytes(&[])  , None );
+  }
+
+  // a single full frame is just circomlib's `Poseidon(16)` (the test vector is from circomlibjs)
+  #[test]
+  fn sponge_single_frame() {
+    let hash: Felt = sponge_hash( &felts(16) ).unwrap();
+    assert_eq!( Felt::to_decimal_string(hash) , "9989051620750914585850546081941653841776809718687451684622678807385399211877" );
+    let xs: Vec<Felt> = felts(5);
+    let mut frame: [Felt; 16] = [Felt::zero(); 16];
+    frame[0..5].copy_from_slice(&xs);
+    assert_eq!( sponge_hash(&xs) , Some(compress::<16>(frame)) );
+  }
+
+  // after the first frame, the previous hash takes the place of the first element
+  #[test]
+  fn sponge_chaining() {
+    let xs: Vec<Felt> = felts(40);
+    let h1: Felt = compress::<16>( xs[0..16].try_into().unwrap() );
+    let mut frame: [Felt; 16] = [h1; 16];
+    frame[1..16].copy_from_slice(&xs[16..31]);
+    let h2: Felt = compress::<16>(frame);
+    let mut frame: [Felt; 16] = [Felt::zero(); 16];
+    frame[0] = h2;
+    frame[1..10].copy_from_slice(&xs[31..40]);
+    let h3: Felt = compress::<16>(frame);
+    assert_eq!( sponge_hash(&xs[0..31]) , Some(h2) );
+    assert_eq!( sponge_hash(&xs)        , Some(h3) );
+  }
+
+  #[test]
+  fn sponge_frame_size() {
+    let xs: Vec<Felt> = felts(3);
+    assert_eq!( sponge_hash_x::<2>(&xs) , Some( compress::<2>([ compress::<2>([ xs[0] , xs[1] ]) , xs[2] ]) ) );
+  }
+
+  #[test]
+  fn bytes_packing() {
+    let msg: Vec<u8> = (1..=35).collect();
+    let xs: Vec<Felt> = bytes_to_felts(&msg);
+    assert_eq!( xs.len() , 2 );
+    assert_eq!( Felt::to_hex_string(xs[0]) , "0x000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f" );
+    assert_eq!( Felt::to_hex_string(xs[1]) , "0x0020212223000000000000000000000000000000000000000000000000000000" );
+    assert_eq!( hash_bytes(&msg) , sponge_hash(&xs) );
+  }
+
+}
+
+//------------------------------------------------------------------------------
@@ -1,14 +1,34 @@
 
 pub mod constants;
 pub mod permutation;
+pub mod iden3;
 
 pub use permutation::hash1;
 pub use permutation::hash2;
 pub use permutation::hash3;
 pub use permutation::hash4;
+pub use permutation::hash5;
+pub use permutation::hash6;
+pub use permutation::hash7;
+pub use permutation::hash8;
+pub use permutation::hash9;
+pub use permutation::hash10;
+pub use permutation::hash11;
+pub use permutation::hash12;
+pub use permutation::hash13;
+pub use permutation::hash14;
+pub use permutation::hash15;
+pub use permutation::hash16;
 
 pub use permutation::compress;
+pub use permutation::compress_mont;
+pub use permutation::{compress_ex,compress_ex_mont};
+pub use permutation::{compress_batch,compress_batch_with_threads};
 
-pub use permutation::{Params,PoseidonParams};
+pub use permutation::{Params,PoseidonParams,PoseidonCompress};
 pub use permutation::permute;
-pub use permutation::permute_mont;
\ No newline at end of file
+pub use permutation::permute_mont;
+pub use permutation::{permute_in_place,permute_mont_in_place};
+pub use permutation::permute_many;
+pub use permutation::permute_many_mont;
+pub use permutation::{permute_batch,permute_batch_with_threads};
\ No newline at end of file
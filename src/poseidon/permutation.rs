@@ -6,14 +6,29 @@
 #![allow(unused)]
 #![allow(dead_code)]
 #![allow(non_snake_case)]
+// the `hashN` functions mirror circomlib's `Poseidon(N)`, up to 16 inputs
+#![allow(clippy::too_many_arguments)]
 
 use crate::bn254::field::*;
 use crate::bn254::montgomery::*;
+use crate::parallel;
 
 use crate::poseidon::constants::t2;
 use crate::poseidon::constants::t3;
 use crate::poseidon::constants::t4;
 use crate::poseidon::constants::t5;
+use crate::poseidon::constants::t6;
+use crate::poseidon::constants::t7;
+use crate::poseidon::constants::t8;
+use crate::poseidon::constants::t9;
+use crate::poseidon::constants::t10;
+use crate::poseidon::constants::t11;
+use crate::poseidon::constants::t12;
+use crate::poseidon::constants::t13;
+use crate::poseidon::constants::t14;
+use crate::poseidon::constants::t15;
+use crate::poseidon::constants::t16;
+use crate::poseidon::constants::t17;
 
 //------------------------------------------------------------------------------
 
@@ -46,6 +61,18 @@ impl_params!(2, t2);
 impl_params!(3, t3);
 impl_params!(4, t4);
 impl_params!(5, t5);
+impl_params!(6, t6);
+impl_params!(7, t7);
+impl_params!(8, t8);
+impl_params!(9, t9);
+impl_params!(10, t10);
+impl_params!(11, t11);
+impl_params!(12, t12);
+impl_params!(13, t13);
+impl_params!(14, t14);
+impl_params!(15, t15);
+impl_params!(16, t16);
+impl_params!(17, t17);
 
 //------------------------------------------------------------------------------
 
@@ -56,48 +83,59 @@ fn sbox(x: Mont) -> Mont {
   Mont::mul(x,x4)
 }
 
-fn matrix_mul<const T: usize>(input: [Mont; T], mtx: &[Mont]) -> [Mont; T] {
-  let mut out: [Mont; T] = [Mont::zero(); T];
-  for i in 0..T {
-    let mut acc: Mont = Mont::zero();
-    for j in 0..T {
-      acc = Mont::mulAdd( mtx[j*T+i] , input[j] , acc );
+// the sbox of `L` independent lanes, step by step 
+#[inline(always)]
+fn sbox_many<const L: usize>(xs: [Mont; L]) -> [Mont; L] {
+  let x2: [Mont; L] = xs.map( Mont::sqr );
+  let x4: [Mont; L] = x2.map( Mont::sqr );
+  core::array::from_fn( |l| Mont::mul( xs[l] , x4[l] ) )
+}
+
+// the rounds work in place. note: the sums are accumulated unreduced, 
+// with a single reduction per output
+fn matrix_mul<const T: usize>(state: &mut [Mont; T], mtx: &[Mont]) {
+  let mut accs: [MontAcc; T] = [MontAcc::zero(); T];
+  for j in 0..T {
+    let x: Mont = state[j];
+    for i in 0..T {
+      accs[i] = MontAcc::add_mul( accs[i] , mtx[j*T+i] , x );
     }
-    out[i] = acc;
   }
-  out
+  for i in 0..T {
+    state[i] = MontAcc::reduce(accs[i]);
+  }
 }
 
-fn mix_S<const T: usize>(input: [Mont; T], scoeffs: &[Mont]) -> [Mont; T] { 
-  let mut out: [Mont; T] = [Mont::zero(); T];
-  let mut acc: Mont = Mont::zero();
+fn mix_S<const T: usize>(state: &mut [Mont; T], scoeffs: &[Mont]) { 
+  let mut acc: MontAcc = MontAcc::zero();
   for j in 0..T {
-    acc = Mont::mulAdd( scoeffs[j] , input[j] , acc );
+    acc = MontAcc::add_mul( acc , scoeffs[j] , state[j] );
   }
-  out[0] = acc;
+  let x0: Mont = state[0];
+  state[0] = MontAcc::reduce(acc);
   for j in 1..T {
-    out[j] = Mont::mulAdd( scoeffs[T+j-1] , input[0] , input[j] );
+    state[j] = MontAcc::reduce( MontAcc::from_mul_add( scoeffs[T+j-1] , x0 , state[j] ) );
   }
-  out
 }
 
-fn internal_round<const T: usize>(input: [Mont; T], rc: Mont, scoeffs: &[Mont]) -> [Mont; T] {
-  let mut xs: [Mont; T] = input;
-  xs[0] = Mont::add( sbox( xs[0] ) , rc );
-  mix_S::<T>(xs, scoeffs)
+fn internal_round<const T: usize>(state: &mut [Mont; T], rc: Mont, scoeffs: &[Mont]) {
+  state[0] = Mont::add( sbox( state[0] ) , rc );
+  mix_S::<T>(state, scoeffs)
 }
 
-fn external_round<const T: usize>(input: [Mont; T], rcs: &[Mont], mtx: &[Mont]) -> [Mont; T] {
-  let mut xs: [Mont; T] = [Mont::zero(); T];
-  for j in 0..T {
-    xs[j] = Mont::add( sbox( input[j] ) , rcs[j] );
+// the very last round has no round constants
+fn external_round<const T: usize>(state: &mut [Mont; T], rcs: Option<&[Mont]>, mtx: &[Mont]) {
+  match rcs {
+    Some(rcs) => for (x,rc) in state.iter_mut().zip(rcs) { *x = Mont::add( sbox(*x) , *rc ); },
+    None      => for x in state.iter_mut() { *x = sbox(*x); },
   }
-  matrix_mul::<T>(xs, mtx)
+  matrix_mul::<T>(state, mtx)
 }
 
 //------------------------------------------------------------------------------
 
-pub fn permute_mont<const T: usize>(input: [Mont; T]) -> [Mont; T] where Params: PoseidonParams<T> {
+// the permutation, in place, without allocation or copying of the state
+pub fn permute_mont_in_place<const T: usize>(state: &mut [Mont; T]) where Params: PoseidonParams<T> {
 
   let TT = 2*T - 1;
   let NP = <Params as PoseidonParams<T>>::NP;
@@ -106,34 +144,141 @@ pub fn permute_mont<const T: usize>(input: [Mont; T]) -> [Mont; T] where Params:
   let P  = <Params as PoseidonParams<T>>::const_P();
   let S  = <Params as PoseidonParams<T>>::const_S();
 
-  let mut state: [Mont; T] = input;
   for j in 0..T { 
     state[j] = Mont::add( state[j] , C[j] );
   }
   for i in 0..4  { 
     let rcs: &[Mont] = &C[ ((i+1)*T) .. ((i+2)*T) ];
     let mat = if i<3 { M } else { P };
-    state = external_round::<T>( state , rcs , mat ); 
+    external_round::<T>( state , Some(rcs) , mat ); 
   }
   for i in 0..NP { 
     let rc: Mont = C[ i + 5*T ];
     let scoeffs: &[Mont]  = &S[ (i*TT) .. ((i+1)*TT) ];
-    state = internal_round::<T>( state , rc , scoeffs );
+    internal_round::<T>( state , rc , scoeffs );
   }
   for i in 4..8  { 
-    let rcs: &[Mont] = if i<7  { &C[ (NP + (i+1)*T) .. (NP + (i+2)*T) ] } else { &[Mont::zero(); T] };
-    state = external_round::<T>( state , rcs , M ); 
+    let rcs: Option<&[Mont]> = if i<7  { Some( &C[ (NP + (i+1)*T) .. (NP + (i+2)*T) ] ) } else { None };
+    external_round::<T>( state , rcs , M ); 
   }
+}
+
+pub fn permute_mont<const T: usize>(input: [Mont; T]) -> [Mont; T] where Params: PoseidonParams<T> {
+  let mut state: [Mont; T] = input;
+  permute_mont_in_place::<T>(&mut state);
   state
 }
 
-pub fn compress_mont<const K: usize>(input: [Mont; K]) -> Mont where Params: PoseidonParams<{K+1}> {
-  let mut state: [Mont; K+1] = [Mont::zero(); K+1]; 
-  for i in 0..K { state[i+1] = input[i]; }
-  state = permute_mont::<{K+1}>(state);
+// permutes `L` independent states, processing them round by round, interleaved.
+// The result is exactly the same as calling `permute_mont` on each state.
+// note: this is not faster than calling `permute_mont` `L` times; on x86_64 it's 
+// about the same or slightly slower, and a lane-major (structure of arrays) layout 
+// measured even slower, as the wide Montgomery multiplications already keep the
+// CPU busy
+pub fn permute_many_mont<const T: usize, const L: usize>(states: &mut [[Mont; T]; L]) where Params: PoseidonParams<T> {
+
+  let TT = 2*T - 1;
+  let NP = <Params as PoseidonParams<T>>::NP;
+  let C  = <Params as PoseidonParams<T>>::const_C();
+  let M  = <Params as PoseidonParams<T>>::const_M();
+  let P  = <Params as PoseidonParams<T>>::const_P();
+  let S  = <Params as PoseidonParams<T>>::const_S();
+
+  for state in states.iter_mut() {
+    for j in 0..T { 
+      state[j] = Mont::add( state[j] , C[j] );
+    }
+  }
+  for i in 0..4  { 
+    let rcs: &[Mont] = &C[ ((i+1)*T) .. ((i+2)*T) ];
+    let mat = if i<3 { M } else { P };
+    for state in states.iter_mut() { external_round::<T>( state , Some(rcs) , mat ); }
+  }
+  for i in 0..NP { 
+    let rc: Mont = C[ i + 5*T ];
+    let scoeffs: &[Mont]  = &S[ (i*TT) .. ((i+1)*TT) ];
+    let ys: [Mont; L] = sbox_many::<L>( core::array::from_fn( |l| states[l][0] ) );
+    for l in 0..L { 
+      states[l][0] = Mont::add( ys[l] , rc );
+      mix_S::<T>( &mut states[l] , scoeffs ); 
+    }
+  }
+  for i in 4..8  { 
+    let rcs: Option<&[Mont]> = if i<7  { Some( &C[ (NP + (i+1)*T) .. (NP + (i+2)*T) ] ) } else { None };
+    for state in states.iter_mut() { external_round::<T>( state , rcs , M ); }
+  }
+}
+
+//------------------------------------------------------------------------------
+// compression (`K` inputs, state width `T = K+1`)
+
+// stable rust cannot express `PoseidonParams<{K+1}>`, so we map 
+// the number of inputs `K` to the state width with a trait instead
+pub trait PoseidonCompress<const K: usize> {
+  fn compress_mont(input: [Mont; K]) -> Mont;
+  fn compress_ex_mont<const N_OUT: usize>(initial_state: Mont, input: [Mont; K]) -> [Mont; N_OUT];
+}
+
+fn compress_mont_generic<const K: usize, const T: usize>(input: [Mont; K]) -> Mont where Params: PoseidonParams<T> {
+  const { assert!( T == K+1 ) };
+  let mut state: [Mont; T] = [Mont::zero(); T]; 
+  state[1..].copy_from_slice(&input);
+  permute_mont_in_place::<T>(&mut state);
   state[0]
 }
 
+// circomlib's `PoseidonEx`: the capacity element is `initial_state` instead of zero,
+// and we return the first `N_OUT` elements of the state (so `N_OUT <= T`, which is
+// checked at compile time)
+fn compress_ex_mont_generic<const K: usize, const T: usize, const N_OUT: usize>(initial_state: Mont, input: [Mont; K]) -> [Mont; N_OUT] 
+where Params: PoseidonParams<T> {
+  const { assert!( T == K+1 ) };
+  const { assert!( N_OUT <= T , "compress_ex: at most `t` outputs" ) };
+  let mut state: [Mont; T] = [Mont::zero(); T]; 
+  state[0] = initial_state;
+  state[1..].copy_from_slice(&input);
+  permute_mont_in_place::<T>(&mut state);
+  let mut out: [Mont; N_OUT] = [Mont::zero(); N_OUT];
+  out.copy_from_slice(&state[0..N_OUT]);
+  out
+}
+
+macro_rules! impl_compress {
+  ($K:literal, $T:literal) => {
+    impl PoseidonCompress<$K> for Params {
+      fn compress_mont(input: [Mont; $K]) -> Mont { compress_mont_generic::<$K,$T>(input) }
+      fn compress_ex_mont<const N_OUT: usize>(initial_state: Mont, input: [Mont; $K]) -> [Mont; N_OUT] { 
+        compress_ex_mont_generic::<$K,$T,N_OUT>(initial_state, input) 
+      }
+    }
+  };
+}
+
+impl_compress!(1, 2);
+impl_compress!(2, 3);
+impl_compress!(3, 4);
+impl_compress!(4, 5);
+impl_compress!(5, 6);
+impl_compress!(6, 7);
+impl_compress!(7, 8);
+impl_compress!(8, 9);
+impl_compress!(9, 10);
+impl_compress!(10, 11);
+impl_compress!(11, 12);
+impl_compress!(12, 13);
+impl_compress!(13, 14);
+impl_compress!(14, 15);
+impl_compress!(15, 16);
+impl_compress!(16, 17);
+
+pub fn compress_mont<const K: usize>(input: [Mont; K]) -> Mont where Params: PoseidonCompress<K> {
+  <Params as PoseidonCompress<K>>::compress_mont(input)
+}
+
+pub fn compress_ex_mont<const K: usize, const N_OUT: usize>(initial_state: Mont, input: [Mont; K]) -> [Mont; N_OUT] where Params: PoseidonCompress<K> {
+  <Params as PoseidonCompress<K>>::compress_ex_mont::<N_OUT>(initial_state, input)
+}
+
 //------------------------------------------------------------------------------
 
 pub fn permute<const T: usize>(input: [Felt; T]) -> [Felt; T] where Params: PoseidonParams<T> {
@@ -142,11 +287,51 @@ pub fn permute<const T: usize>(input: [Felt; T]) -> [Felt; T] where Params: Pose
   Felt::from_mont_vec(output)
 }
 
-pub fn compress<const K: usize>(input: [Felt; K]) -> Felt where Params: PoseidonParams<{K+1}> {
-  let mut state: [Mont; K+1] = [Mont::zero(); K+1]; 
-  for i in 0..K { state[i+1] = Felt::to_mont(input[i]); }
-  state = permute_mont::<{K+1}>(state);
-  Felt::from_mont(state[0])
+// the same in place; `Felt` is a transparent wrapper around `Mont`, so this is free
+pub fn permute_in_place<const T: usize>(state: &mut [Felt; T]) where Params: PoseidonParams<T> {
+  permute_mont_in_place::<T>( Felt::as_mont_array_mut(state) )
+}
+
+pub fn permute_many<const T: usize, const L: usize>(states: &mut [[Felt; T]; L]) where Params: PoseidonParams<T> {
+  let mut monts: [[Mont; T]; L] = states.map( Felt::to_mont_vec );
+  permute_many_mont::<T,L>(&mut monts);
+  *states = monts.map( Felt::from_mont_vec );
+}
+
+pub fn compress<const K: usize>(input: [Felt; K]) -> Felt where Params: PoseidonCompress<K> {
+  let state: [Mont; K] = Felt::to_mont_vec(input);
+  Felt::from_mont( compress_mont::<K>(state) )
+}
+
+// the same as circomlibjs's `poseidon(inputs, initState, nOut)`;
+// `compress_ex::<K,1>(Felt::zero(), xs)` is just `[ compress::<K>(xs) ]`
+pub fn compress_ex<const K: usize, const N_OUT: usize>(initial_state: Felt, input: [Felt; K]) -> [Felt; N_OUT] where Params: PoseidonCompress<K> {
+  let state: [Mont; K] = Felt::to_mont_vec(input);
+  Felt::from_mont_vec( compress_ex_mont::<K,N_OUT>( Felt::to_mont(initial_state) , state ) )
+}
+
+//------------------------------------------------------------------------------
+// batch hashing, split between threads (see `crate::parallel`). 
+// `threads = 0` means the default (the available parallelism). Otherwise `threads`
+// is an upper bound: at most one thread is used per 64 items, so small batches 
+// (less than 128 items) run on the calling thread
+
+pub fn compress_batch_with_threads<const K: usize>(inputs: &[[Felt; K]], threads: usize) -> Vec<Felt> where Params: PoseidonCompress<K> {
+  let mut out: Vec<Felt> = vec![Felt::zero(); inputs.len()];
+  parallel::map_into( threads , inputs , &mut out , |xs| compress::<K>(*xs) );
+  out
+}
+
+pub fn permute_batch_with_threads<const T: usize>(states: &mut [[Felt; T]], threads: usize) where Params: PoseidonParams<T> {
+  parallel::map_in_place( threads , states , |xs| permute::<T>(*xs) );
+}
+
+pub fn compress_batch<const K: usize>(inputs: &[[Felt; K]]) -> Vec<Felt> where Params: PoseidonCompress<K> {
+  compress_batch_with_threads::<K>(inputs, 0)
+}
+
+pub fn permute_batch<const T: usize>(states: &mut [[Felt; T]]) where Params: PoseidonParams<T> {
+  permute_batch_with_threads::<T>(states, 0)
 }
 
 //------------------------------------------------------------------------------
@@ -167,6 +352,54 @@ pub fn hash4(a: Felt, b: Felt, c: Felt, d: Felt) -> Felt {
   compress::<4>([ a, b, c, d ])
 }
 
+pub fn hash5(a: Felt, b: Felt, c: Felt, d: Felt, e: Felt) -> Felt {
+  compress::<5>([ a, b, c, d, e ])
+}
+
+pub fn hash6(a: Felt, b: Felt, c: Felt, d: Felt, e: Felt, f: Felt) -> Felt {
+  compress::<6>([ a, b, c, d, e, f ])
+}
+
+pub fn hash7(a: Felt, b: Felt, c: Felt, d: Felt, e: Felt, f: Felt, g: Felt) -> Felt {
+  compress::<7>([ a, b, c, d, e, f, g ])
+}
+
+pub fn hash8(a: Felt, b: Felt, c: Felt, d: Felt, e: Felt, f: Felt, g: Felt, h: Felt) -> Felt {
+  compress::<8>([ a, b, c, d, e, f, g, h ])
+}
+
+pub fn hash9(a: Felt, b: Felt, c: Felt, d: Felt, e: Felt, f: Felt, g: Felt, h: Felt, i: Felt) -> Felt {
+  compress::<9>([ a, b, c, d, e, f, g, h, i ])
+}
+
+pub fn hash10(a: Felt, b: Felt, c: Felt, d: Felt, e: Felt, f: Felt, g: Felt, h: Felt, i: Felt, j: Felt) -> Felt {
+  compress::<10>([ a, b, c, d, e, f, g, h, i, j ])
+}
+
+pub fn hash11(a: Felt, b: Felt, c: Felt, d: Felt, e: Felt, f: Felt, g: Felt, h: Felt, i: Felt, j: Felt, k: Felt) -> Felt {
+  compress::<11>([ a, b, c, d, e, f, g, h, i, j, k ])
+}
+
+pub fn hash12(a: Felt, b: Felt, c: Felt, d: Felt, e: Felt, f: Felt, g: Felt, h: Felt, i: Felt, j: Felt, k: Felt, l: Felt) -> Felt {
+  compress::<12>([ a, b, c, d, e, f, g, h, i, j, k, l ])
+}
+
+pub fn hash13(a: Felt, b: Felt, c: Felt, d: Felt, e: Felt, f: Felt, g: Felt, h: Felt, i: Felt, j: Felt, k: Felt, l: Felt, m: Felt) -> Felt {
+  compress::<13>([ a, b, c, d, e, f, g, h, i, j, k, l, m ])
+}
+
+pub fn hash14(a: Felt, b: Felt, c: Felt, d: Felt, e: Felt, f: Felt, g: Felt, h: Felt, i: Felt, j: Felt, k: Felt, l: Felt, m: Felt, n: Felt) -> Felt {
+  compress::<14>([ a, b, c, d, e, f, g, h, i, j, k, l, m, n ])
+}
+
+pub fn hash15(a: Felt, b: Felt, c: Felt, d: Felt, e: Felt, f: Felt, g: Felt, h: Felt, i: Felt, j: Felt, k: Felt, l: Felt, m: Felt, n: Felt, o: Felt) -> Felt {
+  compress::<15>([ a, b, c, d, e, f, g, h, i, j, k, l, m, n, o ])
+}
+
+pub fn hash16(a: Felt, b: Felt, c: Felt, d: Felt, e: Felt, f: Felt, g: Felt, h: Felt, i: Felt, j: Felt, k: Felt, l: Felt, m: Felt, n: Felt, o: Felt, p: Felt) -> Felt {
+  compress::<16>([ a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p ])
+}
+
 //==============================================================================
 // *** TESTS
 
@@ -176,6 +409,37 @@ mod test {
   use crate::bn254::field::*;
   use super::*;
 
+  fn check_permute_many<const T: usize, const L: usize>() where Params: PoseidonParams<T> {
+    crate::testing::check_permute_many::<T,L>( permute::<T> , permute_many::<T,L> );
+  }
+
+  #[test]
+  fn permute_many_vs_scalar() {
+    check_permute_many::<2,1>();
+    check_permute_many::<2,4>();
+    check_permute_many::<3,3>();
+    check_permute_many::<4,2>();
+    check_permute_many::<5,4>();
+  }
+
+  fn check_in_place<const T: usize>() where Params: PoseidonParams<T> {
+    crate::testing::check_in_place::<T>( permute::<T> , permute_in_place::<T> , permute_mont::<T> , permute_mont_in_place::<T> );
+  }
+
+  #[test]
+  fn in_place_vs_by_value() {
+    check_in_place::<2>();
+    check_in_place::<3>();
+    check_in_place::<4>();
+    check_in_place::<5>();
+  }
+
+  #[test]
+  fn batch_vs_scalar() {
+    crate::testing::check_batch( compress::<2> , compress_batch_with_threads::<2> , permute_batch_with_threads::<3> , 
+      |xs| [ Felt::zero() , xs[0] , xs[1] ] );
+  }
+
   #[test]
   fn hash1_kat() {
     assert_eq!( 
@@ -208,6 +472,79 @@ mod test {
     );
   }
 
+  // the test vectors below are from circomlibjs
+
+  #[test]
+  fn hash5_kat() {
+    assert_eq!( 
+      Felt::to_decimal_string( hash5( 1u32.into(), 2u32.into(), 3u32.into(), 4u32.into(), 5u32.into() ) ), 
+      "6183221330272524995739186171720101788151706631170188140075976616310159254464"
+    );
+  }
+
+  #[test]
+  fn hash6_kat() {
+    assert_eq!( 
+      Felt::to_decimal_string( hash6( 1u32.into(), 2u32.into(), 3u32.into(), 4u32.into(), 5u32.into(), 6u32.into() ) ), 
+      "20400040500897583745843009878988256314335038853985262692600694741116813247201"
+    );
+  }
+
+  #[test]
+  fn hash16_kat() {
+    let xs: [Felt; 16] = core::array::from_fn( |i| Felt::from_u32( (i+1) as u32 ) );
+    assert_eq!( 
+      Felt::to_decimal_string( compress::<16>(xs) ), 
+      "9989051620750914585850546081941653841776809718687451684622678807385399211877"
+    );
+  }
+
+  #[test]
+  fn compress_ex_vs_permute() {
+    let xs: [Felt; 4] = [ 1u32.into(), 2u32.into(), 3u32.into(), 4u32.into() ];
+    assert_eq!( compress_ex::<4,1>( Felt::zero() , xs ) , [ hash4( xs[0], xs[1], xs[2], xs[3] ) ] );
+    let init: Felt = 12345u32.into();
+    let out: [Felt; 5] = permute::<5>( [ init, xs[0], xs[1], xs[2], xs[3] ] );
+    assert_eq!( compress_ex::<4,3>( init , xs ) , [ out[0] , out[1] , out[2] ] );
+    assert_eq!( compress_ex::<4,5>( init , xs ) , out );
+    assert_eq!( compress_ex::<4,0>( init , xs ) , [] );
+  }
+
+  // circomlibjs's `poseidon([1,2],0,3)` and `poseidon([1,2,3,4],0,5)`, which are the
+  // reference permutations `poseidonperm_x5_254_3` and `poseidonperm_x5_254_5`
+  #[test]
+  fn compress_ex_kat() {
+    let out: [Felt; 3] = compress_ex::<2,3>( Felt::zero() , [ 1u32.into(), 2u32.into() ] );
+    assert_eq!( Felt::to_hex_string( out[0] ) , "0x115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a" );
+    assert_eq!( Felt::to_hex_string( out[1] ) , "0x0fca49b798923ab0239de1c9e7a4a9a2210312b6a2f616d18b5a87f9b628ae29" );
+    assert_eq!( Felt::to_hex_string( out[2] ) , "0x0e7ae82e40091e63cbd4f16a6d16310b3729d4b6e138fcf54110e2867045a30c" );
+    let out: [Felt; 5] = compress_ex::<4,5>( Felt::zero() , [ 1u32.into(), 2u32.into(), 3u32.into(), 4u32.into() ] );
+    assert_eq!( Felt::to_hex_string( out[0] ) , "0x299c867db6c1fdd79dcefa40e4510b9837e60ebb1ce0663dbaa525df65250465" );
+    assert_eq!( Felt::to_hex_string( out[1] ) , "0x1148aaef609aa338b27dafd89bb98862d8bb2b429aceac47d86206154ffe053d" );
+    assert_eq!( Felt::to_hex_string( out[2] ) , "0x24febb87fed7462e23f6665ff9a0111f4044c38ee1672c1ac6b0637d34f24907" );
+    assert_eq!( Felt::to_hex_string( out[3] ) , "0x0eb08f6d809668a981c186beaf6110060707059576406b248e5d9cf6e78b3d3e" );
+    assert_eq!( Felt::to_hex_string( out[4] ) , "0x07748bc6877c9b82c8b98666ee9d0626ec7f5be4205f79ee8528ef1c4a376fc7" );
+  }
+
+  // `poseidon([1,2,...,N])` for the remaining widths. These were computed by the 
+  // reference implementation `constants/circomlib/poseidon_circomlib.py`, which reads 
+  // the constants straight from circomlib's `poseidon_constants.circom` (so it's 
+  // independent of our tables), and reproduces the circomlibjs values above
+  #[test]
+  fn hash7_to_hash15_kat() {
+    let xs: [Felt; 15] = core::array::from_fn( |i| Felt::from_u32( (i+1) as u32 ) );
+    let [a,b,c,d,e,f,g,h,i,j,k,l,m,n,o] = xs;
+    assert_eq!( Felt::to_decimal_string( hash7 (a,b,c,d,e,f,g) )                 , "12748163991115452309045839028154629052133952896122405799815156419278439301912" );
+    assert_eq!( Felt::to_decimal_string( hash8 (a,b,c,d,e,f,g,h) )               , "18604317144381847857886385684060986177838410221561136253933256952257712543953" );
+    assert_eq!( Felt::to_decimal_string( hash9 (a,b,c,d,e,f,g,h,i) )             , "13589767895268936107593642967621470491511464502761040466226072462545218539640" );
+    assert_eq!( Felt::to_decimal_string( hash10(a,b,c,d,e,f,g,h,i,j) )           , "3657500514307717306974218405144578736633140001277925127187636780142269815841" );
+    assert_eq!( Felt::to_decimal_string( hash11(a,b,c,d,e,f,g,h,i,j,k) )         , "3572015662710076994097916907865950486270383304442561406230608893458731714472" );
+    assert_eq!( Felt::to_decimal_string( hash12(a,b,c,d,e,f,g,h,i,j,k,l) )       , "2501997477381648492950318384533644783248002172679259592360114615426357826485" );
+    assert_eq!( Felt::to_decimal_string( hash13(a,b,c,d,e,f,g,h,i,j,k,l,m) )     , "7041832639553862712666971417715061873827921493498355005117622707743491651590" );
+    assert_eq!( Felt::to_decimal_string( hash14(a,b,c,d,e,f,g,h,i,j,k,l,m,n) )   , "8354478399926161176778659061636406690034081872658507739535256090879947077494" );
+    assert_eq!( Felt::to_decimal_string( hash15(a,b,c,d,e,f,g,h,i,j,k,l,m,n,o) ) , "4203130618016961831408770638653325366880478848856764494148034853759773445968" );
+  }
+
 }
 
 //------------------------------------------------------------------------------
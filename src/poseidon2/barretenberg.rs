@@ -0,0 +1,114 @@
+
+//
+// variable-length hashing compatible with Aztec's Barretenberg and Noir
+//
+// This is the sponge of Barretenberg's `crypto::Poseidon2::hash` (`FieldSponge`),
+// which is also Noir's `std::hash::poseidon2::Poseidon2::hash`: Poseidon2 with
+// `t = 4` and the "new" parameters, rate 3 and capacity 1. The capacity element
+// is initialized to `len * 2^64`, where `len` is the number of input elements.
+// The input is absorbed in chunks of 3 elements (the last chunk is padded with 
+// zeros), each chunk added into the state followed by a permutation; the output
+// is the first element of the final state. The empty input still takes a single
+// permutation.
+//
+
+use crate::bn254::field::*;
+use crate::bn254::montgomery::*;
+use crate::poseidon2::permutation::{permute_mont_in_place};
+
+//------------------------------------------------------------------------------
+
+pub const RATE: usize = 3;
+
+// the initial value of the capacity element: `len * 2^64`
+fn capacity_iv(len: usize) -> Mont {
+  let mut buf: [u8; 32] = [0; 32];
+  buf[16..24].copy_from_slice( &(len as u64).to_be_bytes() );
+  Felt::to_mont( Felt::try_from_be_bytes(buf).unwrap() )      // less than 2^128, so always in range
+}
+
+pub fn hash(input: &[Felt]) -> Felt {
+  let mut state: [Mont; 4] = [Mont::zero(); 4];
+  state[RATE] = capacity_iv( input.len() );
+
+  if input.is_empty() {
+    permute_mont_in_place::<true,4>( &mut state );
+  }
+  for chunk in input.chunks(RATE) {
+    for i in 0..chunk.len() {
+      state[i] = Mont::add( state[i] , Felt::to_mont(chunk[i]) );
+    }
+    permute_mont_in_place::<true,4>( &mut state );
+  }
+
+  Felt::from_mont( state[0] )
+}
+
+//==============================================================================
+// *** TESTS
+
+#[cfg(test)]
+mod test {
+
+  use super::*;
+  use crate::poseidon2::new::{permute};
+
+  fn felts(n: u32) -> Vec<Felt> {
+    (1..=n).map( Felt::from_u32 ).collect()
+  }
+
+  fn iv(len: u32) -> Felt {
+    Felt::from_mont( capacity_iv( len as usize ) )
+  }
+
+  #[test]
+  fn capacity_iv_value() {
+    assert_eq!( Felt::to_hex_string( iv(5) ) , "0x0000000000000000000000000000000000000000000000050000000000000000" );
+  }
+
+  // Barretenberg's `HashConsistencyCheck` test (the input is reduced modulo `p`)
+  #[test]
+  fn hash_barretenberg_kat() {
+    let a: Felt = Felt::from_hex_str("0x09539008b8b85db277c0f1b060715e72864101bf0927e05ce0280e4553456786").unwrap();
+    assert_eq!( Felt::to_hex_string( hash(&[a,a,a,a]) ) , "0x2f43a0f83b51a6f5fc839dea0ecec74947637802a579fa9841930a25a0bcec11" );
+  }
+
+  // Noir's `Poseidon2::hash([1], 1)`
+  #[test]
+  fn hash_noir_kat() {
+    assert_eq!( Felt::to_hex_string( hash(&felts(1)) ) , "0x168758332d5b3e2d13be8048c8011b454590e06c44bce7f702f09103eef5a373" );
+  }
+
+  #[test]
+  fn hash_empty() {
+    let out: [Felt; 4] = permute::<4>([ Felt::zero() , Felt::zero() , Felt::zero() , Felt::zero() ]);
+    assert_eq!( hash(&[]) , out[0] );
+  }
+
+  // up to 3 elements fit into a single permutation
+  #[test]
+  fn hash_single_chunk() {
+    let xs: Vec<Felt> = felts(3);
+    assert_eq!( hash(&xs) , permute::<4>([ xs[0] , xs[1] , xs[2] , iv(3) ])[0] );
+    assert_eq!( hash(&xs[0..1]) , permute::<4>([ xs[0] , Felt::zero() , Felt::zero() , iv(1) ])[0] );
+  }
+
+  // further chunks are added into the rate part of the state
+  #[test]
+  fn hash_multi_chunk() {
+    let xs: Vec<Felt> = felts(5);
+    let s1: [Felt; 4] = permute::<4>([ xs[0] , xs[1] , xs[2] , iv(5) ]);
+    let s2: [Felt; 4] = permute::<4>([ s1[0] + xs[3] , s1[1] + xs[4] , s1[2] , s1[3] ]);
+    assert_eq!( hash(&xs) , s2[0] );
+  }
+
+  // the length is part of the initial state, so zero padding doesn't collide
+  #[test]
+  fn hash_length_matters() {
+    let xs: Vec<Felt> = vec![ Felt::from_u32(7) , Felt::zero() ];
+    assert_ne!( hash(&xs) , hash(&xs[0..1]) );
+  }
+
+}
+
+//------------------------------------------------------------------------------
@@ -0,0 +1,3 @@
+
+pub mod t2;
+pub mod t3;
@@ -0,0 +1,97 @@
+
+// gnark-crypto's Poseidon2 constants for `t = 2` (`rF = 6`, `rP = 50`)
+
+use crate::bn254::montgomery::*;
+
+
+//------------------------------------------------------------------------------
+
+// initial (external) round constants (flattened)
+pub const INITIAL: [Mont; 6] = 
+  [ Mont::unsafe_make( [ 0x0ef7ffce , 0x3e178f38 , 0x3206d4ca , 0xb41c86fb , 0xeaffe80b , 0x353e2359 , 0x9f58a8f7 , 0x1034b2bd ] )
+  , Mont::unsafe_make( [ 0xab17c599 , 0xc7872ae1 , 0x3e3ade07 , 0x718a70f8 , 0x2d09dad2 , 0x3c4b61c9 , 0xb03b8101 , 0x03c28439 ] )
+  , Mont::unsafe_make( [ 0x8349deac , 0x12ff67b8 , 0xf1024fe1 , 0x23f977ec , 0x086d542e , 0xbb190cd9 , 0xb6022d52 , 0x1ce80bf7 ] )
+  , Mont::unsafe_make( [ 0x4622045a , 0x62575d33 , 0x190da3f2 , 0xdc1b41a1 , 0xca37ecfd , 0x666cad8d , 0xbb4e82b3 , 0x224e59c9 ] )
+  , Mont::unsafe_make( [ 0x6f694d1a , 0xa4512f78 , 0xf68bc1c0 , 0x87326258 , 0xba08d638 , 0x916a1677 , 0x9f1865a5 , 0x0a2a6bdc ] )
+  , Mont::unsafe_make( [ 0xbc8502c5 , 0x1cfdce12 , 0xd24e3fcf , 0x2cea8ba6 , 0x3da698f8 , 0x21f145a2 , 0x1df27eee , 0x2ddbecf9 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// middle (internal) round constants
+pub const INTERNAL: [Mont; 50] = 
+  [ Mont::unsafe_make( [ 0x64db7745 , 0xee282afd , 0x0eca2f95 , 0x4b15b9e9 , 0x6e2dd360 , 0x261026ab , 0x24528c1d , 0x2029209a ] )
+  , Mont::unsafe_make( [ 0x848f4f4b , 0xeb6f7dc7 , 0x22da499c , 0xad03394d , 0xb57e8157 , 0x23a7a079 , 0x3ab1aaad , 0x1d3bc667 ] )
+  , Mont::unsafe_make( [ 0x39ad77a7 , 0x4d76a6a8 , 0xc8de0dd4 , 0x489f866b , 0x8610cb7f , 0x5c9b1575 , 0xd289bfdd , 0x04d5b745 ] )
+  , Mont::unsafe_make( [ 0x24969b37 , 0x93ebc206 , 0x6b54dfe8 , 0xc8768a70 , 0x4babf443 , 0x28ee6ad1 , 0x32dfeb3f , 0x0018645a ] )
+  , Mont::unsafe_make( [ 0xbce0860f , 0xfe0f7b8d , 0x94af0cbc , 0xfd790251 , 0x8f413e52 , 0x8d0e0775 , 0x5469d684 , 0x1f63f050 ] )
+  , Mont::unsafe_make( [ 0x16772e71 , 0x840b6fd2 , 0x37071f67 , 0xbe889389 , 0xac076648 , 0x0afa9077 , 0x426a92c3 , 0x2d767745 ] )
+  , Mont::unsafe_make( [ 0x276961f3 , 0xb71e5f25 , 0x2cea1662 , 0xdc1f319f , 0x37fdc929 , 0x8925cbe2 , 0x60f3f925 , 0x09a03ac0 ] )
+  , Mont::unsafe_make( [ 0x1ead1e6e , 0xe3c86ae4 , 0x1ede85ca , 0xdeda3920 , 0xcbc449a3 , 0xdd67fdb3 , 0x1e6cad9c , 0x22cd461a ] )
+  , Mont::unsafe_make( [ 0x81c9d8ea , 0x3b1fd68c , 0x5345ebd1 , 0xa99d2cc0 , 0x88cdbf65 , 0x58e8fff4 , 0x8d92dd57 , 0x109a56d3 ] )
+  , Mont::unsafe_make( [ 0xe0316a28 , 0xca1a5862 , 0x872c8256 , 0x04c9f474 , 0xde1e175d , 0x37a2e0d3 , 0x77ddb206 , 0x1d135c57 ] )
+  , Mont::unsafe_make( [ 0xaeb9fa02 , 0x366f2bb7 , 0xfe208d24 , 0x2ba6a4c8 , 0xb5eb4a74 , 0x53ff3c46 , 0xe17a5351 , 0x03de8a98 ] )
+  , Mont::unsafe_make( [ 0x186941e7 , 0xc3999c9b , 0x9cf864ed , 0x0f9dd175 , 0x84584741 , 0xd0764581 , 0xf59294c4 , 0x2bcd0790 ] )
+  , Mont::unsafe_make( [ 0xe44cc0ac , 0x3991379c , 0x305847ce , 0x990bb88a , 0x286383ed , 0x4f16d43e , 0x229ea886 , 0x0e8a8bef ] )
+  , Mont::unsafe_make( [ 0x5cd10ca1 , 0x5260fba8 , 0xd18e649d , 0x2ff0852b , 0xf745646b , 0xea6bcc17 , 0x418fe76e , 0x0f4892d2 ] )
+  , Mont::unsafe_make( [ 0x883f78fe , 0xf882b58c , 0xdf6f1781 , 0x20315c18 , 0xe7f7d81b , 0x64b8f2e0 , 0x255be1f1 , 0x16c29d7c ] )
+  , Mont::unsafe_make( [ 0x34cd9e08 , 0x8b5bf868 , 0x8af0d6a7 , 0xfb1af17e , 0xaa4c44d2 , 0xecd851f3 , 0x38dad61e , 0x12697a99 ] )
+  , Mont::unsafe_make( [ 0xd58141ac , 0xa038849e , 0x4837656f , 0x8f8b791e , 0x0fc62aea , 0x9e18e2f4 , 0xcb86ecfd , 0x05a11fa0 ] )
+  , Mont::unsafe_make( [ 0x5e53760d , 0xa91dfdfc , 0xd0252491 , 0x68cec7bc , 0xf03f9a50 , 0x6f4d3aa9 , 0x34797bab , 0x125dfad2 ] )
+  , Mont::unsafe_make( [ 0x6edf8932 , 0xf13b7445 , 0x406c10f9 , 0x724e0aed , 0xe920dac2 , 0xf50a8006 , 0xfa8ca312 , 0x095e73ab ] )
+  , Mont::unsafe_make( [ 0xb4c531c6 , 0x012a09a0 , 0xa364d0f8 , 0x7093e46b , 0x131d8aef , 0xa4f83255 , 0xcbe60759 , 0x054b61b4 ] )
+  , Mont::unsafe_make( [ 0x5d864c65 , 0xa0eee0a1 , 0xeee33742 , 0x73622b94 , 0x7028c988 , 0xc34cf43b , 0x7c60ea50 , 0x0978422e ] )
+  , Mont::unsafe_make( [ 0x74968aee , 0x27a04635 , 0xf4e6e11f , 0xc257ff22 , 0xd81bb00b , 0xbfbfebb8 , 0xffaa3704 , 0x0820fbe4 ] )
+  , Mont::unsafe_make( [ 0x9544d468 , 0x0e324c88 , 0x40dcb30d , 0x12183378 , 0x6532d74f , 0x52ac5114 , 0x2b949d3f , 0x15e37117 ] )
+  , Mont::unsafe_make( [ 0xc5479c04 , 0xc1813033 , 0x95c14c8d , 0x1c45181d , 0x4bb060c2 , 0x26e34033 , 0xa160ac93 , 0x1a167926 ] )
+  , Mont::unsafe_make( [ 0x2fbfbd61 , 0xe164d2f6 , 0xbfa3a1a5 , 0xe9879643 , 0x4c383d94 , 0xd9062e38 , 0x81b632ab , 0x2a77a479 ] )
+  , Mont::unsafe_make( [ 0x26c29c49 , 0xcdd823a3 , 0xf7587e3e , 0x84d5fab7 , 0xb6bd7ed2 , 0x720ea5e7 , 0xf469b87c , 0x0ff823b4 ] )
+  , Mont::unsafe_make( [ 0xd8c1d6df , 0x3e5d4b46 , 0x9a7ed258 , 0x31ac1cfb , 0x02c31b48 , 0x4b8e94a6 , 0x995f4c90 , 0x2344ee31 ] )
+  , Mont::unsafe_make( [ 0x2b1354d2 , 0x1e358ad5 , 0x73d79fda , 0xe45eb9aa , 0xf9592652 , 0x3a8ae980 , 0x39e0eb3c , 0x01e37a51 ] )
+  , Mont::unsafe_make( [ 0x1114e4a5 , 0xb435dfc8 , 0x11e21e5d , 0xda7a6af2 , 0xbbee0616 , 0x0250a718 , 0xe62e2d46 , 0x2b433386 ] )
+  , Mont::unsafe_make( [ 0x296a1454 , 0xab7431d0 , 0x5e11ff9f , 0x0410d5d1 , 0x15a079a0 , 0x1152785a , 0xaf67c714 , 0x1bd516a9 ] )
+  , Mont::unsafe_make( [ 0x7e794552 , 0x1c31b01f , 0xf59ce62f , 0x7b482d1a , 0x489dffe8 , 0xc90c5a46 , 0x2a7a1274 , 0x2c906709 ] )
+  , Mont::unsafe_make( [ 0x35965945 , 0x3b91d722 , 0x0d170cb3 , 0x10a82874 , 0xdb243d3c , 0xf27ba8e7 , 0x2b3acc9d , 0x2332dc5d ] )
+  , Mont::unsafe_make( [ 0x0f687771 , 0x62b9864e , 0x8ac56ccd , 0x3a765cfa , 0xaab16545 , 0x5482c7b8 , 0xae5d9e0f , 0x221c8363 ] )
+  , Mont::unsafe_make( [ 0xa41681dd , 0xa281a6bf , 0xb3337c07 , 0x93dcdbc4 , 0xc13e6a93 , 0xb7f4c0d0 , 0xb5a957a0 , 0x124d7c53 ] )
+  , Mont::unsafe_make( [ 0x6d5597f5 , 0xf984ecc5 , 0x6fa41af5 , 0x54b4e675 , 0xfeea4e86 , 0xd16a1316 , 0x5036cbad , 0x10860d00 ] )
+  , Mont::unsafe_make( [ 0x8725e308 , 0x82af3503 , 0xacf05599 , 0x701073d7 , 0x7ed9325a , 0x2507bc04 , 0xdf722e35 , 0x000866ff ] )
+  , Mont::unsafe_make( [ 0x7f5a3c32 , 0x38a974e8 , 0x0d318e71 , 0xbc0fbea7 , 0x62cdf71f , 0x3ff9b911 , 0x6e8574dd , 0x22b30cbf ] )
+  , Mont::unsafe_make( [ 0xfe1f2a6b , 0xad2098fe , 0x2c0d9d77 , 0x0def2464 , 0xbb853dc2 , 0xde2bc407 , 0x44578b69 , 0x103f85d5 ] )
+  , Mont::unsafe_make( [ 0x75012379 , 0xd44659c4 , 0x5bbc5a2a , 0x8d4aadbb , 0x70d33b94 , 0x9794f155 , 0x6bfe6b81 , 0x0db232b8 ] )
+  , Mont::unsafe_make( [ 0x658c465b , 0xc0224690 , 0xaf61c2a4 , 0xc4fbf5a2 , 0x2ba03253 , 0x6a1f5f07 , 0xa53157f6 , 0x203d0438 ] )
+  , Mont::unsafe_make( [ 0x0b034e67 , 0xeacee4ef , 0xafcefafb , 0xceafb0ed , 0x99a95fc2 , 0x9776b469 , 0xa05dc1a6 , 0x1d3a57a1 ] )
+  , Mont::unsafe_make( [ 0x4b7770e5 , 0x896e56b9 , 0x6e4405e7 , 0x4a231a0f , 0x0ed24352 , 0x9c6e80e1 , 0x51e594c6 , 0x27993bf4 ] )
+  , Mont::unsafe_make( [ 0x69e8c88f , 0x248ff0e1 , 0x7a24365e , 0xbef8c0fd , 0xf6869e3b , 0xd528071c , 0xcb8e4b9c , 0x0df561a6 ] )
+  , Mont::unsafe_make( [ 0x8983f03d , 0x5be6f307 , 0xa60d4623 , 0x8954b751 , 0xb0486f90 , 0xd44d0d2c , 0xf6d1b56c , 0x195539bc ] )
+  , Mont::unsafe_make( [ 0xf313d679 , 0x75044b91 , 0xe431a315 , 0x847cac18 , 0xfb2aa9e0 , 0x29b9326f , 0xee3af35e , 0x1873dd9d ] )
+  , Mont::unsafe_make( [ 0x28152077 , 0x97a6713d , 0x9cee4969 , 0xae467322 , 0xf0d60f8e , 0xcd8a1ad4 , 0xdf613d1c , 0x26f3b531 ] )
+  , Mont::unsafe_make( [ 0x91c760ac , 0x932d607a , 0xd5f2d71f , 0x83c154f0 , 0x396a3e6a , 0x0624312a , 0x3d26cf0a , 0x0f314c81 ] )
+  , Mont::unsafe_make( [ 0x48b0b19a , 0x47a84b34 , 0x515c3fa1 , 0x4e7468ec , 0x0610be66 , 0x19624710 , 0x488017cb , 0x038b7cb6 ] )
+  , Mont::unsafe_make( [ 0x24307cf0 , 0xd95e1062 , 0xc8d6ef8d , 0x3d14d008 , 0xa21f99b8 , 0x330b20c4 , 0x23f72859 , 0x01a08d60 ] )
+  , Mont::unsafe_make( [ 0x6f83354f , 0x3c47bf71 , 0x0e59604f , 0x94ab8f51 , 0x0e5f74a1 , 0x27af63bc , 0x3aaaccbd , 0x1d85c51e ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// final (external) round constants (flattened)
+pub const FINAL: [Mont; 6] = 
+  [ Mont::unsafe_make( [ 0xc325d3ed , 0x63f96ee9 , 0xeadfb2da , 0x09a64e7b , 0x4ac45276 , 0x031c2f7c , 0x09e353e7 , 0x2b403215 ] )
+  , Mont::unsafe_make( [ 0x4b2e6220 , 0xd9c7d0f1 , 0xc7a7f7bd , 0x3d5b785f , 0xa2872fae , 0x2ce41049 , 0xfe795dc0 , 0x08435a73 ] )
+  , Mont::unsafe_make( [ 0x6119afd8 , 0x8177875c , 0x27a9f6e2 , 0xa1925d18 , 0x57f2e7f4 , 0xd9936cbb , 0xd338651d , 0x00f6b236 ] )
+  , Mont::unsafe_make( [ 0xadf5c8c2 , 0xd7181597 , 0xdfa2f936 , 0x97c52d91 , 0xc360830a , 0x0ac7aa7f , 0xd5f9b2c4 , 0x194d95f0 ] )
+  , Mont::unsafe_make( [ 0x0fd73235 , 0x40cb5c4c , 0x8254dd85 , 0x50c1bf92 , 0x540f5cd1 , 0x376868a8 , 0x00de28c6 , 0x2f19c117 ] )
+  , Mont::unsafe_make( [ 0x0f8e60e8 , 0x9abe0ad9 , 0xb40248f8 , 0x2892c140 , 0x20f7001c , 0xf4742b98 , 0x77341015 , 0x07d9ed29 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// regression answer for the input [0,1,..]: from the reference
+// permutation of the generator script, not from gnark-crypto
+pub const KAT_MONT: [Mont; 2] = 
+  [ Mont::unsafe_make( [ 0x8e5b614b , 0x64394c3b , 0xaa745bb2 , 0x0253a8bf , 0x95fa06a7 , 0x81ea6393 , 0xf8d04851 , 0x09390f78 ] )
+  , Mont::unsafe_make( [ 0x78d931c8 , 0xd84bf623 , 0x56841adf , 0xf71a4369 , 0xd3429f2b , 0xeeb36351 , 0x8796810f , 0x01cf2f66 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
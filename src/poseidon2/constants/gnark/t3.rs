@@ -0,0 +1,116 @@
+
+// gnark-crypto's Poseidon2 constants for `t = 3` (`rF = 8`, `rP = 56`)
+
+use crate::bn254::montgomery::*;
+
+
+//------------------------------------------------------------------------------
+
+// initial (external) round constants (flattened)
+pub const INITIAL: [Mont; 12] = 
+  [ Mont::unsafe_make( [ 0xb6deccda , 0xa02421fd , 0xeabeea8d , 0x8a0ea7d5 , 0xd472d5a5 , 0x6cd171e9 , 0x5436659a , 0x16253653 ] )
+  , Mont::unsafe_make( [ 0xb3783319 , 0x9d8b0f5e , 0xd0a90962 , 0x8fc3c864 , 0x2f85b8bd , 0x5ed220bc , 0x78eb738f , 0x040b5f0d ] )
+  , Mont::unsafe_make( [ 0x4ce931b5 , 0x42a9ee04 , 0xf55aac8e , 0xfba21336 , 0x1ac8b2db , 0xc0fd098a , 0x7da55218 , 0x02eac6b6 ] )
+  , Mont::unsafe_make( [ 0x47846db0 , 0x952e77e5 , 0x012d26af , 0x48a1e46b , 0x59f216c7 , 0xa2135bc5 , 0x0fd8e363 , 0x083aede1 ] )
+  , Mont::unsafe_make( [ 0xf783dbe1 , 0x127d2aa5 , 0xd8f412b2 , 0xe26ed784 , 0x771151b0 , 0xee1f69de , 0xc02fa84b , 0x04b31423 ] )
+  , Mont::unsafe_make( [ 0xe6a08820 , 0xb463acf8 , 0xd5c147a9 , 0x8584c138 , 0x314fe3e4 , 0x187236ac , 0x9e0756da , 0x19043c2c ] )
+  , Mont::unsafe_make( [ 0xbad490f9 , 0xc3e8d351 , 0x57def9a4 , 0x69c4bab3 , 0xdd7ddd95 , 0x30643d47 , 0x770403f2 , 0x2af1df06 ] )
+  , Mont::unsafe_make( [ 0x3c765ef8 , 0x3acb3994 , 0x81d474f4 , 0xf57cb2ea , 0x5cc5a35a , 0x260dc82a , 0x55f29fbf , 0x1de0baed ] )
+  , Mont::unsafe_make( [ 0xc9a0ed3a , 0x180575e7 , 0xc5e79bcf , 0xd51bf967 , 0x42e17411 , 0xc0ce5a3a , 0x685b48bd , 0x00f2b84b ] )
+  , Mont::unsafe_make( [ 0x9b22a7af , 0x48e66f54 , 0x7ff5ae24 , 0x68733505 , 0x6360b2bb , 0x19b4a765 , 0x9e0f9ae3 , 0x2c0c3965 ] )
+  , Mont::unsafe_make( [ 0x14a4e40b , 0xc8c57f8b , 0xc714ea64 , 0x08b95fb7 , 0x91a2be59 , 0x0ca0c65b , 0x6c164f7c , 0x2d89d85e ] )
+  , Mont::unsafe_make( [ 0xf1aa7bfe , 0xc827ffa5 , 0x09dbfb58 , 0x7b87affc , 0x59016c61 , 0x9d0c360c , 0x655e6ae6 , 0x0d855ed0 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// middle (internal) round constants
+pub const INTERNAL: [Mont; 56] = 
+  [ Mont::unsafe_make( [ 0x846a3393 , 0xeb638ea0 , 0x3bdaffab , 0x54c02753 , 0x1674e1e3 , 0x23770474 , 0x8aede31b , 0x12cfa538 ] )
+  , Mont::unsafe_make( [ 0xd0f59a58 , 0x040a5d9f , 0x78cbee4e , 0x112bd90c , 0x30eb9ba8 , 0xf10433b7 , 0x1f4520e0 , 0x1936c44a ] )
+  , Mont::unsafe_make( [ 0xe893500a , 0x788c4b18 , 0xfa01ef1e , 0xf4e96b8d , 0x4bc37f9e , 0x9701aefc , 0x9559774d , 0x178db04a ] )
+  , Mont::unsafe_make( [ 0xbe2049a3 , 0xec7c99fb , 0x8f8696d6 , 0x4df1d2c8 , 0x3d23498f , 0xfd1691a6 , 0x928051ac , 0x059e3db8 ] )
+  , Mont::unsafe_make( [ 0x1a3db038 , 0xef14bcb2 , 0x07e59979 , 0x9769f2df , 0x0054248f , 0xdc7a5914 , 0x9f891f93 , 0x2b597320 ] )
+  , Mont::unsafe_make( [ 0x78bafaf4 , 0x133da276 , 0x21748dba , 0xd5955035 , 0xa4620f2a , 0xef077d0a , 0xb357375f , 0x00eca92d ] )
+  , Mont::unsafe_make( [ 0xbe9b279d , 0x0a6bddaf , 0xf427601d , 0x066abba1 , 0xf40663c5 , 0x52b00fa2 , 0x76226e2e , 0x0541b7b9 ] )
+  , Mont::unsafe_make( [ 0xea44deec , 0x2a849bc4 , 0xd0788fb2 , 0xaab57e08 , 0xcd97874a , 0x27d800b6 , 0x7ef8eb44 , 0x0a8779ef ] )
+  , Mont::unsafe_make( [ 0x218fa55b , 0xa0005ceb , 0xc0f7f1e7 , 0xd8ba71c8 , 0x09f35a31 , 0x17548d70 , 0x3b4d29bb , 0x10def89e ] )
+  , Mont::unsafe_make( [ 0xe0464b54 , 0x893e82eb , 0x3171887a , 0xe8091c4c , 0x57f62e85 , 0x583fe06e , 0x6d09828a , 0x1af01a7f ] )
+  , Mont::unsafe_make( [ 0xdf9ef201 , 0x6eaa84e1 , 0x12408c18 , 0xa50ebc97 , 0x955f4269 , 0xa355a4ad , 0xa7f4d825 , 0x21923123 ] )
+  , Mont::unsafe_make( [ 0xc15f65be , 0x50b9d175 , 0x8bc8526e , 0xb23a8def , 0xbf8d8f00 , 0x3c884f94 , 0xef04476f , 0x0b013dc7 ] )
+  , Mont::unsafe_make( [ 0x2717011d , 0x7bfa3d7a , 0x12ddb9c2 , 0xe1eebb67 , 0x7dc650a2 , 0xda38591d , 0xb118d48d , 0x152688a8 ] )
+  , Mont::unsafe_make( [ 0x68af96b0 , 0x28c12ddb , 0x85dc47cd , 0x0129f574 , 0x85a6685a , 0xec251b11 , 0x7316cfef , 0x01fb8f32 ] )
+  , Mont::unsafe_make( [ 0xb432b962 , 0xa463426a , 0xe3d2058a , 0xf76b0312 , 0xa8422fd6 , 0x0627decd , 0xf0f32555 , 0x022ee065 ] )
+  , Mont::unsafe_make( [ 0xdd647001 , 0x9946e578 , 0x8f57a7f5 , 0xba6d1942 , 0x26d7f18e , 0xd6741580 , 0x19971b45 , 0x2edf9408 ] )
+  , Mont::unsafe_make( [ 0x06397e1d , 0x2b046306 , 0x5b1eb48a , 0x1b51d245 , 0x7f86bf81 , 0xd5966827 , 0xa2d41c8f , 0x104f5d5d ] )
+  , Mont::unsafe_make( [ 0x333bb7f5 , 0x6621636c , 0x912ae392 , 0x4e57f83e , 0xa23aea63 , 0x9ea8aff5 , 0xab416a28 , 0x277fd292 ] )
+  , Mont::unsafe_make( [ 0x3b279067 , 0x85d01e1e , 0xec539292 , 0x89e26ac8 , 0x4c02ec7b , 0x78ad679f , 0xf2da3db3 , 0x186a8e6f ] )
+  , Mont::unsafe_make( [ 0x0eaa01af , 0x250445d8 , 0x89adb3eb , 0xe36179f0 , 0xde06f01c , 0x68ef3d38 , 0x9fcfad38 , 0x09a21568 ] )
+  , Mont::unsafe_make( [ 0xe8635f22 , 0x7f732688 , 0x14ccc7f2 , 0xecca4b0a , 0x76eb7e95 , 0xa7e19a96 , 0x1019bb74 , 0x1598ef83 ] )
+  , Mont::unsafe_make( [ 0x2f8d10a4 , 0xd54f8fe2 , 0x433c42ef , 0x57d0347c , 0x1ad74110 , 0x76675100 , 0xdb7111a0 , 0x0c36d04c ] )
+  , Mont::unsafe_make( [ 0x5cd0349b , 0x8acf9512 , 0x87d272bb , 0xd8841bac , 0xd61626d6 , 0x25b7c8ab , 0xd65b93a7 , 0x21ef118d ] )
+  , Mont::unsafe_make( [ 0xb0267410 , 0x8e572787 , 0xe1308c77 , 0x1cee5423 , 0x039dbe57 , 0x1377f3b8 , 0x1a657418 , 0x1061ec63 ] )
+  , Mont::unsafe_make( [ 0xd913e876 , 0xde7ef49a , 0xda8bf10d , 0xc7faded3 , 0x0610f856 , 0x53d67882 , 0x4bc75b94 , 0x06c6c22b ] )
+  , Mont::unsafe_make( [ 0x46bdc0b8 , 0x8268cd38 , 0x4c868b9a , 0xb3f2cd6d , 0x4f10078f , 0x27c2c7b7 , 0xa8938fa2 , 0x0fd108b6 ] )
+  , Mont::unsafe_make( [ 0xa07d629a , 0xa0fe8808 , 0xf7358033 , 0x0371e2b2 , 0xc4d92a4a , 0x158a921f , 0x67da6ff8 , 0x01606eb8 ] )
+  , Mont::unsafe_make( [ 0x0239b9e6 , 0x8d5f5144 , 0x426fffcb , 0x49998cad , 0x4dda7298 , 0x034ca378 , 0x3a47f830 , 0x24164cfb ] )
+  , Mont::unsafe_make( [ 0xd0467b89 , 0xbfb12244 , 0x76f638a9 , 0x564ea447 , 0xa550f4d5 , 0xc40b34de , 0x4498a2cf , 0x2fc0e53a ] )
+  , Mont::unsafe_make( [ 0x5ee16359 , 0xe1eaf652 , 0xd7a07917 , 0xd0ec49e9 , 0xef1d483d , 0xd984ca88 , 0x257793e7 , 0x27e1fc31 ] )
+  , Mont::unsafe_make( [ 0x4ecbcb04 , 0xd6b5c7be , 0x2ca4e1b6 , 0xaec42ae6 , 0x693ce6c2 , 0xc0c5373a , 0xb0269784 , 0x27130376 ] )
+  , Mont::unsafe_make( [ 0x1142aa2f , 0xe2316b03 , 0xab16e3bb , 0x72cf64d3 , 0xbed81a5e , 0xc67ea8c0 , 0xb61b60ab , 0x021f4479 ] )
+  , Mont::unsafe_make( [ 0x47c96e68 , 0x51060b03 , 0x32ff4b56 , 0xb7faacc2 , 0xded9bee0 , 0xbd87d4b0 , 0x6ee2cc78 , 0x2751d369 ] )
+  , Mont::unsafe_make( [ 0x281fbfab , 0x80217365 , 0x07c91968 , 0x6eeb2003 , 0xbe8ced79 , 0xf7e047ec , 0x63888795 , 0x186ca0c4 ] )
+  , Mont::unsafe_make( [ 0x2da36adb , 0xb0a49a48 , 0x9ad966d3 , 0x4121618f , 0x2e889b2c , 0xd5f01858 , 0x34fe44ea , 0x29055420 ] )
+  , Mont::unsafe_make( [ 0xb1d14d74 , 0xcbe5b6e5 , 0x11c1efa6 , 0xa1c1503c , 0x2ec46c06 , 0xc071d0c5 , 0x808d3685 , 0x1811ca00 ] )
+  , Mont::unsafe_make( [ 0x0daf2f26 , 0x40aa76c7 , 0xb6f84447 , 0x9a128b09 , 0x55cccd92 , 0x3d443d57 , 0x1d2fe112 , 0x173a359d ] )
+  , Mont::unsafe_make( [ 0xccd905be , 0xf13af70f , 0x71f763de , 0x6b77c418 , 0x8c61124c , 0x36291031 , 0xf9f6f90e , 0x08b8d92c ] )
+  , Mont::unsafe_make( [ 0x8e284e56 , 0xe731190c , 0xf8f875dc , 0x9ffe54ce , 0xa0f44401 , 0x62310b92 , 0xaf44704f , 0x1241222e ] )
+  , Mont::unsafe_make( [ 0xd8d44dfb , 0xd9582c05 , 0x5fff0785 , 0xca3a9d04 , 0x9614e7b3 , 0xa34f6c3e , 0x0000e1e2 , 0x2f847dff ] )
+  , Mont::unsafe_make( [ 0x4e3fb694 , 0xd7a3d1de , 0x2bba5fdb , 0x8bf10a01 , 0xfe9f2669 , 0xc3305d23 , 0xbd2d1cfc , 0x1a6b0de9 ] )
+  , Mont::unsafe_make( [ 0x5f634709 , 0xee5869ff , 0x54a6ad52 , 0x9bc57a37 , 0x948ad2bb , 0xcf471b2b , 0x59907372 , 0x1259104f ] )
+  , Mont::unsafe_make( [ 0x3029f41f , 0x77d25fac , 0xfa97fffb , 0xcd3f1fff , 0xeb9a222a , 0xcb78af07 , 0xf6c328d8 , 0x26a5462d ] )
+  , Mont::unsafe_make( [ 0x420c87a4 , 0xb40fa5e9 , 0x4766cd88 , 0x84567bd0 , 0x1459a2ba , 0x2e2f87de , 0xbe32748c , 0x034a16f9 ] )
+  , Mont::unsafe_make( [ 0xbb0c4be5 , 0xe78ff54f , 0x39b1597b , 0xe4273e5b , 0x56b500a4 , 0x3ea5bb3b , 0x640bfa7c , 0x250f3812 ] )
+  , Mont::unsafe_make( [ 0x2c5b90a1 , 0x25280912 , 0x2fc18454 , 0xfbb935c8 , 0x53cf161a , 0x46556bfb , 0xabc08e4c , 0x0236fde8 ] )
+  , Mont::unsafe_make( [ 0x655cd328 , 0x60b1ec7c , 0x6c0c5b0d , 0x000a75f7 , 0x5144d320 , 0x105b3df5 , 0xc62be28e , 0x03adb8ec ] )
+  , Mont::unsafe_make( [ 0xb3d30637 , 0xe7620eb8 , 0x147a21b2 , 0xa37401e4 , 0xded00e55 , 0xb57c29b6 , 0xf7559aa2 , 0x025fcbb1 ] )
+  , Mont::unsafe_make( [ 0xb0017cdb , 0xf50d0f17 , 0xe9d2d06d , 0xfe1a7ebd , 0x3d2191ef , 0x7a675169 , 0xc9452b63 , 0x2dbdfeb8 ] )
+  , Mont::unsafe_make( [ 0xbbbae91e , 0xa4fcea35 , 0x085fe772 , 0x4d52c4b6 , 0x6d43404f , 0xf140e4c6 , 0x06a6baca , 0x242ea695 ] )
+  , Mont::unsafe_make( [ 0xcadfcc4c , 0x0ffbf532 , 0x328edc13 , 0x9c6bb86b , 0x4c170c76 , 0xd0efc9b4 , 0x42530515 , 0x16c2d23b ] )
+  , Mont::unsafe_make( [ 0x5901788d , 0x6df86497 , 0xd3c2cdd8 , 0x23aebf27 , 0x03e8f1c4 , 0xa114bbce , 0x80693753 , 0x0f1bdfb9 ] )
+  , Mont::unsafe_make( [ 0xe06d5f25 , 0x149d3e84 , 0xb9bf6cb2 , 0x0ecd58d2 , 0x8d84ba74 , 0x56485841 , 0x0f05f13d , 0x1d48a3f1 ] )
+  , Mont::unsafe_make( [ 0x6036a45b , 0xf12714c4 , 0x5c5fc36d , 0xe62b845f , 0x35c1705c , 0x9f0f21e0 , 0xe1f9430e , 0x12501801 ] )
+  , Mont::unsafe_make( [ 0x989a4c5a , 0x22fc5030 , 0xce824c46 , 0xbd046a89 , 0x49fdf78a , 0x5a95fb4f , 0x13269d9e , 0x0d2c6631 ] )
+  , Mont::unsafe_make( [ 0x92ab9583 , 0x8bfdb7f7 , 0xbe720df2 , 0xb459850c , 0xa4fb8bb9 , 0xf6ad0642 , 0x483ff5ec , 0x096d3e73 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// final (external) round constants (flattened)
+pub const FINAL: [Mont; 12] = 
+  [ Mont::unsafe_make( [ 0xd4e7d229 , 0x75770341 , 0x6803e2e7 , 0xa9702783 , 0xa4ed8397 , 0x54c3054e , 0x2d247b46 , 0x244c597a ] )
+  , Mont::unsafe_make( [ 0xb739e126 , 0x48b26ec7 , 0x55d42f92 , 0xb09cfd05 , 0xd67de1bd , 0xce1338da , 0x3a840864 , 0x06402cdc ] )
+  , Mont::unsafe_make( [ 0x34783fbc , 0x6eec0b0c , 0xd30c6490 , 0xd8d24910 , 0xcda4f7bb , 0x86e9e11c , 0x72a7d45d , 0x1fbd0502 ] )
+  , Mont::unsafe_make( [ 0x4c3145f4 , 0x7ce774da , 0x845b5a5e , 0x296647a0 , 0x1de8f6f0 , 0x2805794c , 0x80784285 , 0x1d0edd98 ] )
+  , Mont::unsafe_make( [ 0xc8d49495 , 0xd3a4af88 , 0x84da9d90 , 0x8600776c , 0x344b67b1 , 0xa9438e5d , 0x92168d7f , 0x175b68ec ] )
+  , Mont::unsafe_make( [ 0x5cb91b95 , 0x4f6298ea , 0xd4c96389 , 0x0703d35b , 0xfe22aece , 0xef1406f7 , 0xe89aee04 , 0x08d380c6 ] )
+  , Mont::unsafe_make( [ 0xfd3d0233 , 0x8b131dce , 0x28a8cacd , 0xc3adef52 , 0x441f295d , 0xff7bb305 , 0x71935e54 , 0x0480a10e ] )
+  , Mont::unsafe_make( [ 0xa044a067 , 0x223750a5 , 0x21d99087 , 0xa12e2ba1 , 0xe11c24ea , 0x5acb1ce8 , 0x00260095 , 0x02a83010 ] )
+  , Mont::unsafe_make( [ 0x16acf7f2 , 0x24bd6436 , 0x078c113a , 0x079fba46 , 0xbec13f01 , 0xff62e026 , 0x2ddfd9fa , 0x06117714 ] )
+  , Mont::unsafe_make( [ 0x98366382 , 0xb0e3bab8 , 0x6bffe983 , 0xa3ecc5c9 , 0x0d6ee0a0 , 0xaf2ca0ce , 0x41c40320 , 0x13ee3606 ] )
+  , Mont::unsafe_make( [ 0x68fa422a , 0x9dca6274 , 0x6ac24e08 , 0x8acc295e , 0xe10f3c94 , 0x70a47f65 , 0xaa8e2c9d , 0x05185d71 ] )
+  , Mont::unsafe_make( [ 0x82ba146f , 0xc941a0b1 , 0xbb2eb359 , 0x2a91289d , 0x05df992d , 0xe6bca073 , 0x5f222e4b , 0x0b7aaba5 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
+// regression answer for the input [0,1,..]: from the reference
+// permutation of the generator script, not from gnark-crypto
+pub const KAT_MONT: [Mont; 3] = 
+  [ Mont::unsafe_make( [ 0xa43ca385 , 0x485c424e , 0xa8aac07d , 0x1a5292c0 , 0x310b81ea , 0xc8d4cdad , 0x6250a0a1 , 0x03976ada ] )
+  , Mont::unsafe_make( [ 0x80a2db3f , 0x5291d595 , 0x3cd7eb40 , 0x7f59d3ff , 0x59393a67 , 0x68f1b8c1 , 0xd69da203 , 0x2e72d477 ] )
+  , Mont::unsafe_make( [ 0x217875c4 , 0xf338f8d8 , 0x1b83ac37 , 0x34760279 , 0x3acaa948 , 0xedc31117 , 0x0db40ed7 , 0x042db805 ] )
+  ];
+
+//------------------------------------------------------------------------------
+
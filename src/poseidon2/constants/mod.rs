@@ -1,3 +1,4 @@
 
 pub mod old;
 pub mod new;
+pub mod gnark;
@@ -1,6 +1,14 @@
 
 //
-// internal diffusion matrices (diagonal + constant 1 matrix)
+// internal diffusion matrices (diagonal + constant 1 matrix), applied in place
+//
+// note: unlike in Poseidon, lazy reduction (see `MontAcc`) doesn't pay off here:
+// there is at most one product per output, and the fused CIOS multiplication plus 
+// a modular addition is faster than a full product plus a separate reduction.
+// With `MontAcc::from_mul_add` in the `t=4` diagonal, the permutation takes 594k 
+// instead of 568k instructions on RV32IM, and 17.0us instead of 14.6us on x86_64.
+// For `t=2,3` the diagonal is small (`mul_small_add`), but for `t=4` the diagonal
+// entries are arbitrary field elements, so these need full multiplications
 //
 
 #![allow(dead_code)]
@@ -20,21 +28,22 @@ fn add4(x: Mont, y: Mont, z: Mont, w: Mont) -> Mont {
   Mont::add(Mont::add(x,y),Mont::add(z,w))
 }
 
+// the diagonal entry 2 uses `mul_small_add(x,2,s)` instead of `dbl(x) + s`: on 
+// x86_64 the `t=3` layer is about 5% faster this way with 64 bit limbs (and the same 
+// with 32 bit limbs); on RV32IM `dbl` saves 0.2% of the permutation
 #[inline(always)]
-fn diag_T2(x: [Mont; 2]) -> [Mont; 2] {
+fn diag_T2(x: &mut [Mont; 2]) {
   let s = Mont::add( x[0] , x[1] );
-  [ Mont::add( s ,            x[0]   )
-  , Mont::add( s , Mont::dbl( x[1] ) )
-  ]
+  x[0] = Mont::add( s , x[0] );
+  x[1] = Mont::mul_small_add( x[1] , 2 , s );
 }
  
 #[inline(always)]
-fn diag_T3(x: [Mont; 3]) -> [Mont; 3] {
+fn diag_T3(x: &mut [Mont; 3]) {
   let s = add3( x[0], x[1], x[2] );
-  [ Mont::add( s ,            x[0]   )
-  , Mont::add( s ,            x[1]   )
-  , Mont::add( s , Mont::dbl( x[2] ) )
-  ]
+  x[0] = Mont::add( s , x[0] );
+  x[1] = Mont::add( s , x[1] );
+  x[2] = Mont::mul_small_add( x[2] , 2 , s );
 }
 
 //------------------------------------------------------------------------------
@@ -46,7 +55,7 @@ pub mod old {
     use super::super::*;
 
     #[inline(always)]
-    pub fn diag(x: [Mont; 2]) -> [Mont; 2] { diag_T2(x) }
+    pub fn diag(x: &mut [Mont; 2]) { diag_T2(x) }
 
   }
 
@@ -54,7 +63,7 @@ pub mod old {
     use super::super::*;
 
     #[inline(always)]
-    pub fn diag(x: [Mont; 3]) -> [Mont; 3] { diag_T3(x) }
+    pub fn diag(x: &mut [Mont; 3]) { diag_T3(x) }
 
   }
 
@@ -64,13 +73,12 @@ pub mod old {
     use super::super::{add4};
 
     #[inline(always)]
-    pub fn diag(x: [Mont; 4]) -> [Mont; 4] {
+    pub fn diag(x: &mut [Mont; 4]) {
       let s = add4( x[0], x[1], x[2], x[3] );
-      [ Mont::add( s , Mont::mul( x[0] , DIAGONAL[0] ) )
-      , Mont::add( s , Mont::mul( x[1] , DIAGONAL[1] ) )
-      , Mont::add( s , Mont::mul( x[2] , DIAGONAL[2] ) )
-      , Mont::add( s , Mont::mul( x[3] , DIAGONAL[3] ) )
-      ]
+      x[0] = Mont::add( s , Mont::mul( x[0] , DIAGONAL[0] ) );
+      x[1] = Mont::add( s , Mont::mul( x[1] , DIAGONAL[1] ) );
+      x[2] = Mont::add( s , Mont::mul( x[2] , DIAGONAL[2] ) );
+      x[3] = Mont::add( s , Mont::mul( x[3] , DIAGONAL[3] ) );
     }
 
   }
@@ -86,7 +94,7 @@ pub mod new {
     use super::super::*;
 
     #[inline(always)]
-    pub fn diag(x: [Mont; 2]) -> [Mont; 2] { diag_T2(x) }
+    pub fn diag(x: &mut [Mont; 2]) { diag_T2(x) }
 
   }
 
@@ -94,7 +102,7 @@ pub mod new {
     use super::super::*;
 
     #[inline(always)]
-    pub fn diag(x: [Mont; 3]) -> [Mont; 3] { diag_T3(x) }
+    pub fn diag(x: &mut [Mont; 3]) { diag_T3(x) }
 
   }
 
@@ -104,13 +112,12 @@ pub mod new {
     use super::super::{add4};
 
     #[inline(always)]
-    pub fn diag(x: [Mont; 4]) -> [Mont; 4] {
+    pub fn diag(x: &mut [Mont; 4]) {
       let s = add4( x[0], x[1], x[2], x[3] );
-      [ Mont::add( s , Mont::mul( x[0] , DIAGONAL[0] ) )
-      , Mont::add( s , Mont::mul( x[1] , DIAGONAL[1] ) )
-      , Mont::add( s , Mont::mul( x[2] , DIAGONAL[2] ) )
-      , Mont::add( s , Mont::mul( x[3] , DIAGONAL[3] ) )
-      ]
+      x[0] = Mont::add( s , Mont::mul( x[0] , DIAGONAL[0] ) );
+      x[1] = Mont::add( s , Mont::mul( x[1] , DIAGONAL[1] ) );
+      x[2] = Mont::add( s , Mont::mul( x[2] , DIAGONAL[2] ) );
+      x[3] = Mont::add( s , Mont::mul( x[3] , DIAGONAL[3] ) );
     }
   
   }
@@ -118,4 +125,27 @@ pub mod new {
 }
 
 //------------------------------------------------------------------------------
+// *** gnark-crypto's params (the same matrices as HorizenLabs' for `t=2,3`) ***
+
+pub mod gnark {
+
+  pub mod t2 {
+    use super::super::*;
+
+    #[inline(always)]
+    pub fn diag(x: &mut [Mont; 2]) { diag_T2(x) }
+
+  }
+
+  pub mod t3 {
+    use super::super::*;
+
+    #[inline(always)]
+    pub fn diag(x: &mut [Mont; 3]) { diag_T3(x) }
+
+  }
+
+}
+
+//------------------------------------------------------------------------------
 
@@ -3,8 +3,10 @@ pub mod constants;
 pub mod permutation;
 pub mod mds;
 pub mod diag;
+pub mod sponge;
+pub mod barretenberg;
 
-pub use permutation::{Params,Poseidon2Params};
+pub use permutation::{Params,Poseidon2Params,Poseidon2Compress};
 
 //------------------------------------------------------------------------------
 
@@ -14,44 +16,95 @@ pub mod aux {
   use crate::bn254::field::{Felt};
 
   pub fn kat_input<const T: usize>() -> [Felt; T] {
-    let mut xs: [Felt; T] = [Default::default(); T];
-    for i in 0..T {
-      xs[i] = Felt::from_u32(i as u32);
-    }
-    xs
+    core::array::from_fn( |i| Felt::from_u32(i as u32) )
   }
 
   pub fn print_state<const T: usize>( prefix: &str, xs: [Felt; T] ) {
-    for i in 0..T {
-      println!(" - {}[{}] -> {}" , prefix, i , xs[i] );
+    for (i,x) in xs.iter().enumerate() {
+      println!(" - {}[{}] -> {}" , prefix, i , x );
     }
   }
 
 }
 
 //------------------------------------------------------------------------------
-// "old" set of constants
 
-pub mod old {
-  use crate::bn254::field::{Felt};
-  pub use crate::poseidon2::permutation::{Params,Poseidon2Params};
+// the public interface of a parameter set (`Old`, `New` or `Gnark`); this is the
+// same for all sets, so switching between them is just changing the module
+macro_rules! parameter_set {
+  ($P:ident) => {
+    use crate::bn254::field::{Felt};
+    use crate::bn254::montgomery::{Mont};
+    use crate::poseidon2::permutation as perm;
+    pub use crate::poseidon2::permutation::{$P,Poseidon2Instance,Poseidon2CompressInstance};
 
-  pub fn permute<const T: usize>(input: [Felt; T]) -> [Felt; T] where Params: Poseidon2Params<false,T> {
-    crate::poseidon2::permutation::permute::<false,T>( input )  
-  }
+    pub fn permute_mont<const T: usize>(input: [Mont; T]) -> [Mont; T] where $P: Poseidon2Instance<T> {
+      perm::permute_mont_generic::<$P,T>( input )
+    }
 
-  pub fn compress<const K: usize>(input: [Felt; K]) -> Felt where Params: Poseidon2Params<false,{K+1}> {
-    crate::poseidon2::permutation::compress::<false,K>( input )
-  }
+    pub fn permute_mont_in_place<const T: usize>(state: &mut [Mont; T]) where $P: Poseidon2Instance<T> {
+      perm::permute_mont_in_place_generic::<$P,T>( state )
+    }
+
+    pub fn permute<const T: usize>(input: [Felt; T]) -> [Felt; T] where $P: Poseidon2Instance<T> {
+      perm::permute_generic::<$P,T>( input )
+    }
+
+    pub fn permute_in_place<const T: usize>(state: &mut [Felt; T]) where $P: Poseidon2Instance<T> {
+      perm::permute_in_place_generic::<$P,T>( state )
+    }
+
+    // permutes `L` independent states, interleaved (see `permutation::permute_many_mont_generic`)
+    pub fn permute_many<const T: usize, const L: usize>(states: &mut [[Felt; T]; L]) where $P: Poseidon2Instance<T> {
+      perm::permute_many_generic::<$P,T,L>( states )
+    }
+
+    pub fn compress<const K: usize>(input: [Felt; K]) -> Felt where $P: Poseidon2CompressInstance<K> {
+      perm::compress_generic::<$P,K>( input )
+    }
+
+    // batch hashing, using the available parallelism (see `crate::parallel`)
+    pub fn compress_batch<const K: usize>(inputs: &[[Felt; K]]) -> Vec<Felt> where $P: Poseidon2CompressInstance<K> {
+      perm::compress_batch_with_threads_generic::<$P,K>( inputs , 0 )
+    }
+
+    pub fn permute_batch<const T: usize>(states: &mut [[Felt; T]]) where $P: Poseidon2Instance<T> {
+      perm::permute_batch_with_threads_generic::<$P,T>( states , 0 )
+    }
+
+    // the same with an explicit maximum number of threads (at most one per 64 items is used)
+    pub fn compress_batch_with_threads<const K: usize>(inputs: &[[Felt; K]], threads: usize) -> Vec<Felt> where $P: Poseidon2CompressInstance<K> {
+      perm::compress_batch_with_threads_generic::<$P,K>( inputs , threads )
+    }
+
+    pub fn permute_batch_with_threads<const T: usize>(states: &mut [[Felt; T]], threads: usize) where $P: Poseidon2Instance<T> {
+      perm::permute_batch_with_threads_generic::<$P,T>( states , threads )
+    }
+
+    pub fn hash1(a: Felt) -> Felt {
+      compress::<1>([ a ])
+    }
+
+    pub fn hash2(a: Felt, b: Felt) -> Felt {
+      compress::<2>([ a, b ])
+    }
+
+    // sponge with `RATE` elements of rate and `T - RATE` elements of capacity
+    pub type Sponge<const T: usize, const RATE: usize> = crate::poseidon2::sponge::Sponge<$P,T,RATE>;
+
+    pub fn sponge_hash<const T: usize, const RATE: usize>(input: &[Felt]) -> Felt where $P: Poseidon2Instance<T> {
+      crate::poseidon2::sponge::hash::<$P,T,RATE>( input )
+    }
+  };
+}
+
+//------------------------------------------------------------------------------
+// "old" set of constants
+
+pub mod old {
+  parameter_set!(Old);
+  pub use crate::poseidon2::permutation::{Params,Poseidon2Params,Poseidon2Compress};
 
-  pub fn hash1(a: Felt) -> Felt {
-    compress::<1>([ a ])
-  }
-  
-  pub fn hash2(a: Felt, b: Felt) -> Felt {
-    compress::<2>([ a, b ])
-  }
-  
   pub fn hash3(a: Felt, b: Felt, c: Felt) -> Felt {
     compress::<3>([ a, b, c ])
   }
@@ -93,6 +146,36 @@ pub mod old {
       assert_eq!( Felt::to_hex_string( out[3] ) , "0x1e894ea2894a467e113d91475ce583b82c6b421633989cfb2fb98d2008c7283c" );
     }
 
+    fn check_permute_many<const T: usize, const L: usize>() where Old: Poseidon2Instance<T> {
+      crate::testing::check_permute_many::<T,L>( permute::<T> , permute_many::<T,L> );
+    }
+
+    #[test]
+    fn old_permute_many() {
+      check_permute_many::<2,1>();
+      check_permute_many::<2,4>();
+      check_permute_many::<3,3>();
+      check_permute_many::<4,2>();
+      check_permute_many::<4,5>();
+    }
+
+    #[test]
+    fn old_batch() {
+      crate::testing::check_batch( compress::<2> , compress_batch_with_threads::<2> , permute_batch_with_threads::<3> , 
+        |xs| [ xs[0] , xs[1] , Felt::zero() ] );
+    }
+
+    fn check_in_place<const T: usize>() where Old: Poseidon2Instance<T> {
+      crate::testing::check_in_place::<T>( permute::<T> , permute_in_place::<T> , permute_mont::<T> , permute_mont_in_place::<T> );
+    }
+
+    #[test]
+    fn old_permute_in_place() {
+      check_in_place::<2>();
+      check_in_place::<3>();
+      check_in_place::<4>();
+    }
+
     #[test]
     fn old_hash1() {
       let hash: Felt = hash1( Felt::from_u32(111) );
@@ -122,25 +205,9 @@ pub mod old {
 // "new" set of constants
 
 pub mod new {
-  use crate::bn254::field::{Felt};
-  pub use crate::poseidon2::permutation::{Params,Poseidon2Params};
-
-  pub fn permute<const T: usize>(input: [Felt; T]) -> [Felt; T] where Params: Poseidon2Params<true,T> {
-    crate::poseidon2::permutation::permute::<true,T>( input )
-  }
-
-  pub fn compress<const K: usize>(input: [Felt; K]) -> Felt where Params: Poseidon2Params<true,{K+1}> {
-    crate::poseidon2::permutation::compress::<true,K>( input )
-  }
+  parameter_set!(New);
+  pub use crate::poseidon2::permutation::{Params,Poseidon2Params,Poseidon2Compress};
 
-  pub fn hash1(a: Felt) -> Felt {
-    compress::<1>([ a ])
-  }
-  
-  pub fn hash2(a: Felt, b: Felt) -> Felt {
-    compress::<2>([ a, b ])
-  }
-  
   pub fn hash3(a: Felt, b: Felt, c: Felt) -> Felt {
     compress::<3>([ a, b, c ])
   }
@@ -182,6 +249,36 @@ pub mod new {
       assert_eq!( Felt::to_hex_string( out[3] ) , "0x2e11c5cff2a22c64d01304b778d78f6998eff1ab73163a35603f54794c30847a" );
     }
 
+    fn check_permute_many<const T: usize, const L: usize>() where New: Poseidon2Instance<T> {
+      crate::testing::check_permute_many::<T,L>( permute::<T> , permute_many::<T,L> );
+    }
+
+    #[test]
+    fn new_permute_many() {
+      check_permute_many::<2,1>();
+      check_permute_many::<2,4>();
+      check_permute_many::<3,3>();
+      check_permute_many::<4,2>();
+      check_permute_many::<4,5>();
+    }
+
+    #[test]
+    fn new_batch() {
+      crate::testing::check_batch( compress::<2> , compress_batch_with_threads::<2> , permute_batch_with_threads::<3> , 
+        |xs| [ xs[0] , xs[1] , Felt::zero() ] );
+    }
+
+    fn check_in_place<const T: usize>() where New: Poseidon2Instance<T> {
+      crate::testing::check_in_place::<T>( permute::<T> , permute_in_place::<T> , permute_mont::<T> , permute_mont_in_place::<T> );
+    }
+
+    #[test]
+    fn new_permute_in_place() {
+      check_in_place::<2>();
+      check_in_place::<3>();
+      check_in_place::<4>();
+    }
+
     #[test]
     fn new_hash1() {
       let hash: Felt = hash1( Felt::from_u32(111) );
@@ -208,4 +305,110 @@ pub mod new {
 }
 
 //------------------------------------------------------------------------------
+// gnark-crypto's parameters (`t=2,3`)
+//
+// gnark-crypto (`ecc/bn254/fr/poseidon2`) uses the same linear layers as 
+// HorizenLabs for `t=2,3`, but different round numbers (`rF=6, rP=50` for
+// `t=2`, which is the default used by gnark; `rF=8, rP=56` for `t=3`) and 
+// round constants derived from its own seed (see `constants/gnark`).
+//
+// note: the outputs are not yet checked against gnark-crypto itself; the test
+// below is only a regression test against the script's reference permutation.
+
+pub mod gnark {
+  parameter_set!(Gnark);
+
+  // gnark's 2-to-1 compression function `Permutation.Compress`: the last
+  // element of the permuted state, plus the right input (feed-forward). Note 
+  // that this is different from `compress::<2>` above, which is the same 
+  // construction as for the other parameter sets
+  pub fn merkle_damgard_compress(left: Felt, right: Felt) -> Felt {
+    let out: [Felt; 2] = permute::<2>([ left , right ]);
+    out[1] + right
+  }
+
+  // gnark's default Poseidon2 hash: a Merkle-Damgard construction on top of
+  // `merkle_damgard_compress`, with zero initial state (the empty input hashes to zero)
+  pub fn merkle_damgard_hash(input: &[Felt]) -> Felt {
+    input.iter().fold( Felt::zero() , |state, x| merkle_damgard_compress( state , *x ) )
+  }
+
+  //--------------------------------------------------------
+  // tests for the gnark-crypto permutations
+
+  #[cfg(test)]
+  mod test {
+
+    use crate::bn254::field::{Felt};
+    use super::super::aux::*;
+    use super::*;
+
+    // regression test: the expected outputs were computed by the reference permutation
+    // in `constants/gnark/poseidon2_gnark.py`, not by gnark-crypto, so this checks that 
+    // we implement the same thing as the script, but not gnark compatibility
+    fn check_regression<const T: usize>() where Gnark: Poseidon2Instance<T> {
+      let out: [Mont; T] = permute_mont::<T>( Felt::to_mont_vec( kat_input::<T>() ) );
+      assert_eq!( out.as_slice() , <Gnark as Poseidon2Instance<T>>::const_KAT() );
+    }
+
+    #[test]
+    fn gnark_permute_regression() {
+      check_regression::<2>();
+      check_regression::<3>();
+    }
+
+    #[test]
+    fn gnark_differs_from_horizen() {
+      let input: [Felt; 2] = kat_input::<2>();
+      assert_ne!( permute::<2>(input) , crate::poseidon2::old::permute::<2>(input) );
+      assert_ne!( permute::<2>(input) , crate::poseidon2::new::permute::<2>(input) );
+    }
+
+    #[test]
+    fn gnark_permute_many() {
+      crate::testing::check_permute_many::<2,4>( permute::<2> , permute_many::<2,4> );
+      crate::testing::check_permute_many::<3,3>( permute::<3> , permute_many::<3,3> );
+    }
+
+    #[test]
+    fn gnark_batch() {
+      crate::testing::check_batch( compress::<2> , compress_batch_with_threads::<2> , permute_batch_with_threads::<3> , 
+        |xs| [ xs[0] , xs[1] , Felt::zero() ] );
+    }
+
+    #[test]
+    fn gnark_permute_in_place() {
+      crate::testing::check_in_place::<2>( permute::<2> , permute_in_place::<2> , permute_mont::<2> , permute_mont_in_place::<2> );
+      crate::testing::check_in_place::<3>( permute::<3> , permute_in_place::<3> , permute_mont::<3> , permute_mont_in_place::<3> );
+    }
+
+    #[test]
+    fn gnark_compress_and_sponge() {
+      let a: Felt = Felt::from_u32(111);
+      let b: Felt = Felt::from_u32(222);
+      assert_eq!( hash1(a)   , permute::<2>([ a , Felt::zero() ])[0] );
+      assert_eq!( hash2(a,b) , permute::<3>([ a , b , Felt::zero() ])[0] );
+      let mut sponge: Sponge<3,2> = Sponge::new();
+      sponge.absorb(a);
+      assert_eq!( sponge.squeeze() , permute::<3>([ a , Felt::one() , Felt::zero() ])[0] );
+      assert_eq!( sponge_hash::<3,2>(&[a]) , permute::<3>([ a , Felt::one() , Felt::zero() ])[0] );
+    }
+
+    #[test]
+    fn gnark_merkle_damgard_hash() {
+      let xs: Vec<Felt> = (1..=3u32).map( Felt::from_u32 ).collect();
+      let h1: Felt = merkle_damgard_compress( Felt::zero() , xs[0] );
+      let h2: Felt = merkle_damgard_compress( h1 , xs[1] );
+      let h3: Felt = merkle_damgard_compress( h2 , xs[2] );
+      assert_eq!( merkle_damgard_hash(&[])  , Felt::zero() );
+      assert_eq!( merkle_damgard_hash(&xs[0..1]) , h1 );
+      assert_eq!( merkle_damgard_hash(&xs) , h3 );
+      assert_eq!( h1 , permute::<2>([ Felt::zero() , xs[0] ])[1] + xs[0] );
+    }
+
+  }
+
+}
+
+//------------------------------------------------------------------------------
 
@@ -4,9 +4,11 @@
 
 use crate::bn254::field::*;
 use crate::bn254::montgomery::*;
+use crate::parallel;
 
 use crate::poseidon2::constants::old;
 use crate::poseidon2::constants::new;
+use crate::poseidon2::constants::gnark;
 
 use crate::poseidon2::mds;
 use crate::poseidon2::diag;
@@ -15,65 +17,77 @@ use crate::poseidon2::diag;
 
 pub struct Params;
 
-pub trait Poseidon2Params<const NEW: bool, const T: usize> {
-  const NP: usize;
+// the parameter sets: HorizenLabs' "old" and "new" constants, and gnark-crypto's
+#[derive(Debug, Copy, Clone)] pub struct Old;
+#[derive(Debug, Copy, Clone)] pub struct New;
+#[derive(Debug, Copy, Clone)] pub struct Gnark;
+
+// a concrete Poseidon2 instance with state width `T`
+pub trait Poseidon2Instance<const T: usize> {
+  const NF: usize;        // number of full rounds at the beginning (and again at the end)
+  const NP: usize;        // number of partial rounds
   fn const_initial () -> &'static [Mont];
   fn const_internal() -> &'static [Mont];
   fn const_final   () -> &'static [Mont];
   fn const_KAT     () -> &'static [Mont];
-  fn mul_by_mds    ( xs: [Mont; T] ) -> [Mont; T];
-  fn mul_by_diag   ( xs: [Mont; T] ) -> [Mont; T];
-}
-
-macro_rules! impl_params {
-  ($NEW:literal, $T:literal, $oldnew:ident, $tmod:ident) => {
-    impl Poseidon2Params<$NEW,$T> for Params {
-      const NP: usize = 56;
-      fn const_initial () -> &'static [Mont] { &$oldnew::$tmod::INITIAL  }
-      fn const_internal() -> &'static [Mont] { &$oldnew::$tmod::INTERNAL }
-      fn const_final   () -> &'static [Mont] { &$oldnew::$tmod::FINAL    }
-      fn const_KAT     () -> &'static [Mont] { &$oldnew::$tmod::KAT_MONT }
-      fn mul_by_mds ( xs: [Mont; $T] ) -> [Mont; $T] { mds::$tmod::mds(xs) }
-      fn mul_by_diag( xs: [Mont; $T] ) -> [Mont; $T] { diag::$oldnew::$tmod::diag(xs) }
+  fn mul_by_mds    ( xs: &mut [Mont; T] );
+  fn mul_by_diag   ( xs: &mut [Mont; T] );
+}
+
+macro_rules! impl_instance {
+  ($P:ident, $T:literal, $NF:literal, $NP:literal, $set:ident, $tmod:ident) => {
+    impl Poseidon2Instance<$T> for $P {
+      const NF: usize = $NF;
+      const NP: usize = $NP;
+      fn const_initial () -> &'static [Mont] { &$set::$tmod::INITIAL  }
+      fn const_internal() -> &'static [Mont] { &$set::$tmod::INTERNAL }
+      fn const_final   () -> &'static [Mont] { &$set::$tmod::FINAL    }
+      fn const_KAT     () -> &'static [Mont] { &$set::$tmod::KAT_MONT }
+      fn mul_by_mds ( xs: &mut [Mont; $T] ) { mds::$tmod::mds(xs) }
+      fn mul_by_diag( xs: &mut [Mont; $T] ) { diag::$set::$tmod::diag(xs) }
     }
   };
 }
 
 // old parameters
-impl_params!( false, 2, old, t2 );
-impl_params!( false, 3, old, t3 );
-impl_params!( false, 4, old, t4 );
+impl_instance!( Old, 2, 4, 56, old, t2 );
+impl_instance!( Old, 3, 4, 56, old, t3 );
+impl_instance!( Old, 4, 4, 56, old, t4 );
 
 // new parameters
-impl_params!( true, 2, new, t2 );
-impl_params!( true, 3, new, t3 );
-impl_params!( true, 4, new, t4 );
+impl_instance!( New, 2, 4, 56, new, t2 );
+impl_instance!( New, 3, 4, 56, new, t3 );
+impl_instance!( New, 4, 4, 56, new, t4 );
+
+// gnark-crypto's parameters (`NewParameters(2,6,50)` and `NewParameters(3,8,56)`)
+impl_instance!( Gnark, 2, 3, 50, gnark, t2 );
+impl_instance!( Gnark, 3, 4, 56, gnark, t3 );
+
+// selects HorizenLabs' "old" (`NEW = false`) or "new" (`NEW = true`) instance
+pub trait Poseidon2Params<const NEW: bool, const T: usize> {
+  type Instance: Poseidon2Instance<T>;
+}
+
+impl<const T: usize> Poseidon2Params<false,T> for Params where Old: Poseidon2Instance<T> { type Instance = Old; }
+impl<const T: usize> Poseidon2Params<true ,T> for Params where New: Poseidon2Instance<T> { type Instance = New; }
 
 //------------------------------------------------------------------------------
 
+// the round constants are borrowed directly from the constant tables
+
 #[inline(always)]
-fn get_initial_rcs<const NEW: bool, const T: usize>(round: usize) -> [Mont; T] where Params: Poseidon2Params<NEW,T> {
-  let mut rcs: [Mont; T] = [Default::default(); T];
-  let k = round * T;
-  for i in 0..T {
-    rcs[i] = <Params as Poseidon2Params<NEW,T>>::const_initial() [k+i];
-  }
-  rcs
+fn get_initial_rcs<P: Poseidon2Instance<T>, const T: usize>(round: usize) -> &'static [Mont] {
+  &P::const_initial()[ (round*T) .. ((round+1)*T) ]
 }
 
 #[inline(always)]
-fn get_final_rcs<const NEW: bool, const T: usize>(round: usize) -> [Mont; T] where Params: Poseidon2Params<NEW,T> {
-  let mut rcs: [Mont; T] = [Default::default(); T];
-  let k = round * T;
-  for i in 0..T {
-    rcs[i] = <Params as Poseidon2Params<NEW,T>>::const_final() [k+i];
-  }
-  rcs
+fn get_final_rcs<P: Poseidon2Instance<T>, const T: usize>(round: usize) -> &'static [Mont] {
+  &P::const_final()[ (round*T) .. ((round+1)*T) ]
 }
 
 #[inline(always)]
-fn get_internal_rc<const NEW: bool, const T: usize>(round: usize) -> Mont where Params: Poseidon2Params<NEW,T> {
-  <Params as Poseidon2Params<NEW,T>>::const_internal()[ round ]
+fn get_internal_rc<P: Poseidon2Instance<T>, const T: usize>(round: usize) -> Mont {
+  P::const_internal()[ round ]
 }
 
 //------------------------------------------------------------------------------
@@ -85,53 +99,226 @@ fn sbox(x: Mont) -> Mont {
   Mont::mul(x,x4)
 }
 
+// the sbox of `L` independent lanes, step by step 
+#[inline(always)]
+fn sbox_many<const L: usize>(xs: [Mont; L]) -> [Mont; L] {
+  let x2: [Mont; L] = xs.map( Mont::sqr );
+  let x4: [Mont; L] = x2.map( Mont::sqr );
+  core::array::from_fn( |l| Mont::mul( xs[l] , x4[l] ) )
+}
+
 //------------------------------------------------------------------------------
+// the rounds and the linear layers work in place
 
 #[inline(always)]
-fn internal_round<const NEW: bool, const T: usize>(input: [Mont; T], rc: Mont) -> [Mont; T] 
-where Params: Poseidon2Params<NEW,T> {
-  let mut xs: [Mont; T] = input;
-  xs[0] = sbox( Mont::add( xs[0] , rc ) );
-  <Params as Poseidon2Params<NEW,T>>::mul_by_diag( xs )
+fn internal_round<P: Poseidon2Instance<T>, const T: usize>(state: &mut [Mont; T], rc: Mont) {
+  state[0] = sbox( Mont::add( state[0] , rc ) );
+  P::mul_by_diag( state );
 }
 
-fn external_round<const NEW: bool, const T: usize>(input: [Mont; T], rcs: [Mont;T]) -> [Mont; T] 
-where Params: Poseidon2Params<NEW,T> {
-  let mut xs: [Mont; T] = [Default::default(); T];
+fn external_round<P: Poseidon2Instance<T>, const T: usize>(state: &mut [Mont; T], rcs: &[Mont]) {
   for i in 0..T {
-    xs[i] = sbox( Mont::add( input[i] , rcs[i] ) );
+    state[i] = sbox( Mont::add( state[i] , rcs[i] ) );
   }
-  <Params as Poseidon2Params<NEW,T>>::mul_by_mds( xs )
+  P::mul_by_mds( state );
+}
+
+// the permutation of an arbitrary instance, in place, without allocation or copying of the state
+pub fn permute_mont_in_place_generic<P: Poseidon2Instance<T>, const T: usize>(state: &mut [Mont; T]) {
+  P::mul_by_mds( state );
+  for i in 0..P::NF { external_round::<P,T>( state , get_initial_rcs::<P,T>(i) ); }
+  for i in 0..P::NP { internal_round::<P,T>( state , get_internal_rc::<P,T>(i) ); }
+  for i in 0..P::NF { external_round::<P,T>( state , get_final_rcs  ::<P,T>(i) ); }
+}
+
+pub fn permute_mont_generic<P: Poseidon2Instance<T>, const T: usize>(input: [Mont; T]) -> [Mont; T] {
+  let mut state: [Mont; T] = input;
+  permute_mont_in_place_generic::<P,T>(&mut state);
+  state
+}
+
+// HorizenLabs' permutations
+pub fn permute_mont_in_place<const NEW: bool, const T: usize>(state: &mut [Mont; T]) 
+where Params: Poseidon2Params<NEW,T> {
+  permute_mont_in_place_generic::<<Params as Poseidon2Params<NEW,T>>::Instance,T>(state)
 }
 
 pub fn permute_mont<const NEW: bool, const T: usize>(input: [Mont; T]) -> [Mont; T] 
 where Params: Poseidon2Params<NEW,T> {
-  let mut state = <Params as Poseidon2Params<NEW,T>>::mul_by_mds(input);
-  for i in 0..4  { state = external_round::<NEW,T>( state , get_initial_rcs::<NEW,T>(i) ); }
-  for i in 0..56 { state = internal_round::<NEW,T>( state , get_internal_rc::<NEW,T>(i) ); }
-  for i in 0..4  { state = external_round::<NEW,T>( state , get_final_rcs  ::<NEW,T>(i) ); }
-  state
+  permute_mont_generic::<<Params as Poseidon2Params<NEW,T>>::Instance,T>(input)
 }
 
 //------------------------------------------------------------------------------
 
-pub fn compress<const NEW: bool, const K: usize>(input: [Felt; K]) -> Felt 
-where Params: Poseidon2Params<NEW,{K+1}> {
-  let mut state: [Mont; K+1] = [Mont::zero(); K+1];
-  for i in 0..K {
-    state[i] = Felt::to_mont(input[i]);
+// permutes `L` independent states, processing them round by round, interleaved.
+// The result is exactly the same as calling `permute_mont` on each state.
+// note: this is not faster than calling `permute_mont` `L` times; on x86_64 it's 
+// about the same or slightly slower, and a lane-major (structure of arrays) layout 
+// measured even slower, as the wide Montgomery multiplications already keep the
+// CPU busy
+pub fn permute_many_mont_generic<P: Poseidon2Instance<T>, const T: usize, const L: usize>(states: &mut [[Mont; T]; L]) {
+  for state in states.iter_mut() { P::mul_by_mds(state); }
+  for i in 0..P::NF {
+    let rcs = get_initial_rcs::<P,T>(i);
+    for state in states.iter_mut() { external_round::<P,T>( state , rcs ); }
+  }
+  for i in 0..P::NP {
+    let rc = get_internal_rc::<P,T>(i);
+    let ys: [Mont; L] = sbox_many::<L>( core::array::from_fn( |l| Mont::add( states[l][0] , rc ) ) );
+    for l in 0..L { 
+      states[l][0] = ys[l];
+      P::mul_by_diag( &mut states[l] ); 
+    }
+  }
+  for i in 0..P::NF {
+    let rcs = get_final_rcs::<P,T>(i);
+    for state in states.iter_mut() { external_round::<P,T>( state , rcs ); }
   }
-  state = permute_mont::<NEW,{K+1}>(state);
-  Felt::from_mont(state[0])
 }
 
-pub fn permute<const NEW: bool, const T: usize>(input: [Felt; T]) -> [Felt; T] 
+pub fn permute_many_mont<const NEW: bool, const T: usize, const L: usize>(states: &mut [[Mont; T]; L]) 
 where Params: Poseidon2Params<NEW,T> {
+  permute_many_mont_generic::<<Params as Poseidon2Params<NEW,T>>::Instance,T,L>(states)
+}
+
+//------------------------------------------------------------------------------
+
+// compression (`K` inputs, state width `T = K+1`)
+//
+// stable rust cannot express `Poseidon2Instance<{K+1}>`, so we map 
+// the number of inputs `K` to the state width with a trait instead
+pub trait Poseidon2CompressInstance<const K: usize> {
+  fn compress_mont(input: [Mont; K]) -> Mont;
+}
+
+fn compress_mont_with_width<P: Poseidon2Instance<T>, const K: usize, const T: usize>(input: [Mont; K]) -> Mont {
+  const { assert!( T == K+1 ) };
+  let mut state: [Mont; T] = [Mont::zero(); T];
+  state[0..K].copy_from_slice(&input);
+  permute_mont_in_place_generic::<P,T>(&mut state);
+  state[0]
+}
+
+macro_rules! impl_compress {
+  ($P:ident, $K:literal, $T:literal) => {
+    impl Poseidon2CompressInstance<$K> for $P {
+      fn compress_mont(input: [Mont; $K]) -> Mont { compress_mont_with_width::<$P,$K,$T>(input) }
+    }
+  };
+}
+
+impl_compress!( Old, 1, 2 );
+impl_compress!( Old, 2, 3 );
+impl_compress!( Old, 3, 4 );
+
+impl_compress!( New, 1, 2 );
+impl_compress!( New, 2, 3 );
+impl_compress!( New, 3, 4 );
+
+impl_compress!( Gnark, 1, 2 );
+impl_compress!( Gnark, 2, 3 );
+
+// selects the "old" or "new" compression, as `Poseidon2Params` does for the permutation
+pub trait Poseidon2Compress<const NEW: bool, const K: usize> {
+  type Instance: Poseidon2CompressInstance<K>;
+}
+
+impl<const K: usize> Poseidon2Compress<false,K> for Params where Old: Poseidon2CompressInstance<K> { type Instance = Old; }
+impl<const K: usize> Poseidon2Compress<true ,K> for Params where New: Poseidon2CompressInstance<K> { type Instance = New; }
+
+//------------------------------------------------------------------------------
+// the `Felt` interface of an arbitrary instance
+
+pub fn compress_mont_generic<P: Poseidon2CompressInstance<K>, const K: usize>(input: [Mont; K]) -> Mont {
+  P::compress_mont(input)
+}
+
+pub fn compress_generic<P: Poseidon2CompressInstance<K>, const K: usize>(input: [Felt; K]) -> Felt {
+  let state: [Mont; K] = Felt::to_mont_vec(input);
+  Felt::from_mont( P::compress_mont(state) )
+}
+
+pub fn permute_generic<P: Poseidon2Instance<T>, const T: usize>(input: [Felt; T]) -> [Felt; T] {
   let state: [Mont; T] = Felt::to_mont_vec(input);
-  let output = permute_mont::<NEW,T>(state);
+  let output = permute_mont_generic::<P,T>(state);
   Felt::from_mont_vec(output) 
 }
 
+// the same in place; `Felt` is a transparent wrapper around `Mont`, so this is free
+pub fn permute_in_place_generic<P: Poseidon2Instance<T>, const T: usize>(state: &mut [Felt; T]) {
+  permute_mont_in_place_generic::<P,T>( Felt::as_mont_array_mut(state) )
+}
+
+pub fn permute_many_generic<P: Poseidon2Instance<T>, const T: usize, const L: usize>(states: &mut [[Felt; T]; L]) {
+  let mut monts: [[Mont; T]; L] = states.map( Felt::to_mont_vec );
+  permute_many_mont_generic::<P,T,L>(&mut monts);
+  *states = monts.map( Felt::from_mont_vec );
+}
+
+//------------------------------------------------------------------------------
+// HorizenLabs' instances, selected by `NEW`
+
+pub fn compress_mont<const NEW: bool, const K: usize>(input: [Mont; K]) -> Mont 
+where Params: Poseidon2Compress<NEW,K> {
+  compress_mont_generic::<<Params as Poseidon2Compress<NEW,K>>::Instance,K>(input)
+}
+
+pub fn compress<const NEW: bool, const K: usize>(input: [Felt; K]) -> Felt 
+where Params: Poseidon2Compress<NEW,K> {
+  compress_generic::<<Params as Poseidon2Compress<NEW,K>>::Instance,K>(input)
+}
+
+pub fn permute<const NEW: bool, const T: usize>(input: [Felt; T]) -> [Felt; T] 
+where Params: Poseidon2Params<NEW,T> {
+  permute_generic::<<Params as Poseidon2Params<NEW,T>>::Instance,T>(input)
+}
+
+pub fn permute_in_place<const NEW: bool, const T: usize>(state: &mut [Felt; T]) 
+where Params: Poseidon2Params<NEW,T> {
+  permute_in_place_generic::<<Params as Poseidon2Params<NEW,T>>::Instance,T>(state)
+}
+
+pub fn permute_many<const NEW: bool, const T: usize, const L: usize>(states: &mut [[Felt; T]; L]) 
+where Params: Poseidon2Params<NEW,T> {
+  permute_many_generic::<<Params as Poseidon2Params<NEW,T>>::Instance,T,L>(states)
+}
+
+//------------------------------------------------------------------------------
+// batch hashing, split between threads (see `crate::parallel`). 
+// `threads = 0` means the default (the available parallelism). Otherwise `threads`
+// is an upper bound: at most one thread is used per 64 items, so small batches 
+// (less than 128 items) run on the calling thread
+
+pub fn compress_batch_with_threads_generic<P: Poseidon2CompressInstance<K>, const K: usize>(inputs: &[[Felt; K]], threads: usize) -> Vec<Felt> {
+  let mut out: Vec<Felt> = vec![Felt::zero(); inputs.len()];
+  parallel::map_into( threads , inputs , &mut out , |xs| compress_generic::<P,K>(*xs) );
+  out
+}
+
+pub fn permute_batch_with_threads_generic<P: Poseidon2Instance<T>, const T: usize>(states: &mut [[Felt; T]], threads: usize) {
+  parallel::map_in_place( threads , states , |xs| permute_generic::<P,T>(*xs) );
+}
+
+pub fn compress_batch_with_threads<const NEW: bool, const K: usize>(inputs: &[[Felt; K]], threads: usize) -> Vec<Felt> 
+where Params: Poseidon2Compress<NEW,K> {
+  compress_batch_with_threads_generic::<<Params as Poseidon2Compress<NEW,K>>::Instance,K>(inputs, threads)
+}
+
+pub fn permute_batch_with_threads<const NEW: bool, const T: usize>(states: &mut [[Felt; T]], threads: usize) 
+where Params: Poseidon2Params<NEW,T> {
+  permute_batch_with_threads_generic::<<Params as Poseidon2Params<NEW,T>>::Instance,T>(states, threads)
+}
+
+pub fn compress_batch<const NEW: bool, const K: usize>(inputs: &[[Felt; K]]) -> Vec<Felt> 
+where Params: Poseidon2Compress<NEW,K> {
+  compress_batch_with_threads::<NEW,K>(inputs, 0)
+}
+
+pub fn permute_batch<const NEW: bool, const T: usize>(states: &mut [[Felt; T]]) 
+where Params: Poseidon2Params<NEW,T> {
+  permute_batch_with_threads::<NEW,T>(states, 0)
+}
+
 /*
 pub fn permute_iterated<const NEW: bool, const T: usize>(input: [Felt; T], count: usize) -> [Felt; T] 
 where Params: Poseidon2Params<NEW,T> {
@@ -0,0 +1,210 @@
+
+//
+// sponge construction on top of the Poseidon2 permutation
+//
+// The state is split into the "rate" part (the first `RATE` elements, which
+// the input is added into and the output is read from) and the "capacity" part
+// (the remaining `T - RATE` elements, which are never directly touched by the
+// user, except for initializing the first one with a domain separator).
+//
+// Padding is the standard `10*` padding: after the last input element we
+// absorb a `1`, then zeros until the end of the rate block. This makes the
+// construction injective for inputs of different lengths (including the
+// empty input), so no length prefix is needed.
+//
+
+#![allow(dead_code)]
+#![allow(non_snake_case)]
+
+use crate::bn254::field::*;
+use crate::bn254::montgomery::*;
+use core::marker::PhantomData;
+
+use crate::poseidon2::permutation::{Poseidon2Instance,permute_mont_in_place_generic};
+
+//------------------------------------------------------------------------------
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Mode {
+  Absorbing,
+  Squeezing,
+}
+
+// `P` is the parameter set (`Old`, `New` or `Gnark`)
+#[derive(Debug, Copy, Clone)]
+pub struct Sponge<P, const T: usize, const RATE: usize> {
+  state : [Mont; T],
+  pos   : usize,        // position inside the current rate block
+  mode  : Mode,
+  set   : PhantomData<P>,
+}
+
+//------------------------------------------------------------------------------
+
+impl<P: Poseidon2Instance<T>, const T: usize, const RATE: usize> Default for Sponge<P,T,RATE> {
+  fn default() -> Self { Sponge::new() }
+}
+
+impl<P: Poseidon2Instance<T>, const T: usize, const RATE: usize> Sponge<P,T,RATE> {
+
+  // the capacity (number of field elements never exposed)
+  pub const CAPACITY: usize = T - RATE;
+
+  // a sponge with zero domain separator
+  pub fn new() -> Self {
+    Sponge::new_with_domain( Felt::zero() )
+  }
+
+  // the domain separator is put into the first capacity element, so different
+  // applications (or different output lengths) can be made independent
+  pub fn new_with_domain(domain: Felt) -> Self {
+    const { assert!( RATE >= 1 && RATE < T , "Sponge: we need 1 <= RATE < T" ) };
+    let mut state: [Mont; T] = [Mont::zero(); T];
+    state[RATE] = Felt::to_mont(domain);
+    Sponge { state, pos: 0, mode: Mode::Absorbing, set: PhantomData }
+  }
+
+  //------------------------------------
+  // absorbing
+
+  pub fn absorb_mont(&mut self, x: Mont) {
+    assert!( self.mode == Mode::Absorbing , "Sponge::absorb: cannot absorb after squeezing" );
+    if self.pos == RATE {
+      permute_mont_in_place_generic::<P,T>(&mut self.state);
+      self.pos   = 0;
+    }
+    self.state[self.pos] = Mont::add( self.state[self.pos] , x );
+    self.pos += 1;
+  }
+
+  pub fn absorb(&mut self, x: Felt) {
+    self.absorb_mont( Felt::to_mont(x) );
+  }
+
+  pub fn absorb_slice(&mut self, xs: &[Felt]) {
+    for x in xs { self.absorb(*x); }
+  }
+
+  pub fn absorb_mont_slice(&mut self, xs: &[Mont]) {
+    for x in xs { self.absorb_mont(*x); }
+  }
+
+  // `10*` padding, then the final permutation of the absorbing phase
+  fn finish_absorbing(&mut self) {
+    if self.pos == RATE {
+      permute_mont_in_place_generic::<P,T>(&mut self.state);
+      self.pos   = 0;
+    }
+    self.state[self.pos] = Mont::add( self.state[self.pos] , Mont::one() );
+    permute_mont_in_place_generic::<P,T>(&mut self.state);
+    self.pos   = 0;
+    self.mode  = Mode::Squeezing;
+  }
+
+  //------------------------------------
+  // squeezing
+
+  pub fn squeeze_mont(&mut self) -> Mont {
+    if self.mode == Mode::Absorbing {
+      self.finish_absorbing();
+    }
+    if self.pos == RATE {
+      permute_mont_in_place_generic::<P,T>(&mut self.state);
+      self.pos   = 0;
+    }
+    let out = self.state[self.pos];
+    self.pos += 1;
+    out
+  }
+
+  pub fn squeeze(&mut self) -> Felt {
+    Felt::from_mont( self.squeeze_mont() )
+  }
+
+  pub fn squeeze_array<const N: usize>(&mut self) -> [Felt; N] {
+    core::array::from_fn( |_| self.squeeze() )
+  }
+
+}
+
+//------------------------------------------------------------------------------
+
+// hash an arbitrary sequence of field elements into a single one
+pub fn hash<P: Poseidon2Instance<T>, const T: usize, const RATE: usize>(input: &[Felt]) -> Felt {
+  let mut sponge: Sponge<P,T,RATE> = Sponge::new();
+  sponge.absorb_slice(input);
+  sponge.squeeze()
+}
+
+//------------------------------------------------------------------------------
+
+//==============================================================================
+// *** TESTS
+
+#[cfg(test)]
+mod test {
+
+  use crate::poseidon2::permutation::{Old,New,permute};
+  use super::*;
+
+  fn felts(xs: &[u32]) -> Vec<Felt> {
+    xs.iter().map( |x| Felt::from_u32(*x) ).collect()
+  }
+
+  #[test]
+  fn sponge_single_block() {
+    // one input element, rate 2: the padded block is `[a, 1]`
+    let a   = Felt::from_u32(111);
+    let out = permute::<false,3>( [ a , Felt::one() , Felt::zero() ] );
+    assert_eq!( hash::<Old,3,2>( &[a] ) , out[0] );
+  }
+
+  #[test]
+  fn sponge_full_block() {
+    // a full rate block needs an extra permutation for the padding
+    let a   = Felt::from_u32(111);
+    let b   = Felt::from_u32(222);
+    let s1  = permute::<true,3>( [ a , b , Felt::zero() ] );
+    let s2  = permute::<true,3>( [ s1[0] + Felt::one() , s1[1] , s1[2] ] );
+    assert_eq!( hash::<New,3,2>( &[a,b] ) , s2[0] );
+  }
+
+  #[test]
+  fn sponge_domain_separator() {
+    let mut sponge: Sponge<Old,4,3> = Sponge::new_with_domain( Felt::from_u32(42) );
+    sponge.absorb( Felt::from_u32(7) );
+    let out = permute::<false,4>( felts(&[7,1,0,42]).try_into().unwrap() );
+    assert_eq!( sponge.squeeze() , out[0] );
+  }
+
+  #[test]
+  fn sponge_streaming() {
+    let xs = felts(&[1,2,3,4,5,6,7,8,9,10,11]);
+    let mut sponge: Sponge<Old,3,2> = Sponge::new();
+    for x in &xs { sponge.absorb(*x); }
+    assert_eq!( sponge.squeeze() , hash::<Old,3,2>(&xs) );
+  }
+
+  #[test]
+  fn sponge_padding_is_injective() {
+    assert_ne!( hash::<Old,3,2>( &felts(&[])    ) , hash::<Old,3,2>( &felts(&[0])     ) );
+    assert_ne!( hash::<Old,3,2>( &felts(&[5])   ) , hash::<Old,3,2>( &felts(&[5,0])   ) );
+    assert_ne!( hash::<Old,3,2>( &felts(&[5,0]) ) , hash::<Old,3,2>( &felts(&[5,0,0]) ) );
+  }
+
+  #[test]
+  fn sponge_squeeze_many() {
+    // squeezing more than `RATE` elements continues with further permutations
+    let mut sponge: Sponge<New,2,1> = Sponge::new();
+    sponge.absorb( Felt::from_u32(3) );
+    let out: [Felt; 3] = sponge.squeeze_array::<3>();
+    let s0 = permute::<true,2>( [ Felt::from_u32(3) , Felt::zero() ] );
+    let s1 = permute::<true,2>( [ s0[0] + Felt::one() , s0[1] ] );
+    let s2 = permute::<true,2>( s1 );
+    let s3 = permute::<true,2>( s2 );
+    assert_eq!( out , [ s1[0] , s2[0] , s3[0] ] );
+  }
+
+}
+
+//------------------------------------------------------------------------------
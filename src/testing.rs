@@ -0,0 +1,61 @@
+
+//
+// test helpers shared by the Poseidon and Poseidon2 tests; the permutations
+// are passed in as functions, so that any instance can be checked
+//
+
+use crate::bn254::field::{Felt};
+use crate::bn254::montgomery::{Mont};
+
+//------------------------------------------------------------------------------
+
+// permuting `L` states at once must agree with permuting them one by one
+pub fn check_permute_many<const T: usize, const L: usize>
+  ( permute:      impl Fn([Felt; T]) -> [Felt; T]
+  , permute_many: impl Fn(&mut [[Felt; T]; L])
+  ) {
+  let mut states: [[Felt; T]; L] = core::array::from_fn( |l| core::array::from_fn( |i| Felt::from_u32( (100*l + i) as u32 ) ) );
+  let expected: [[Felt; T]; L] = states.map( &permute );
+  permute_many( &mut states );
+  assert_eq!( states , expected );
+}
+
+// the in-place permutations (both on `Felt` and `Mont`) must agree with the by-value ones
+pub fn check_in_place<const T: usize>
+  ( permute:               impl Fn([Felt; T]) -> [Felt; T]
+  , permute_in_place:      impl Fn(&mut [Felt; T])
+  , permute_mont:          impl Fn([Mont; T]) -> [Mont; T]
+  , permute_mont_in_place: impl Fn(&mut [Mont; T])
+  ) {
+  let input: [Felt; T] = core::array::from_fn( |i| Felt::from_u32( (i*i + 3) as u32 ) );
+  let mut state: [Felt; T] = input;
+  permute_in_place( &mut state );
+  assert_eq!( state , permute(input) );
+  let mut monts: [Mont; T] = Felt::to_mont_vec(input);
+  permute_mont_in_place( &mut monts );
+  assert_eq!( monts , permute_mont( Felt::to_mont_vec(input) ) );
+}
+
+//------------------------------------------------------------------------------
+
+// the batch functions must agree with the sequential ones, for any number of threads. 
+// `to_state` puts the two inputs of `compress` into a `t=3` state, such that the 
+// hash is the element 0 of the permuted state
+pub fn check_batch
+  ( compress:                    impl Fn([Felt; 2]) -> Felt
+  , compress_batch_with_threads: impl Fn(&[[Felt; 2]], usize) -> Vec<Felt>
+  , permute_batch_with_threads:  impl Fn(&mut [[Felt; 3]], usize)
+  , to_state:                    impl Fn([Felt; 2]) -> [Felt; 3]
+  ) {
+  let inputs: Vec<[Felt; 2]> = (0..300u32).map( |i| [ Felt::from_u32(i) , Felt::from_u32(7*i+1) ] ).collect();
+  let expected: Vec<Felt> = inputs.iter().map( |xs| compress(*xs) ).collect();
+  for threads in [0, 1, 3, 100] {
+    assert_eq!( compress_batch_with_threads(&inputs, threads) , expected );
+    let mut states: Vec<[Felt; 3]> = inputs.iter().map( |xs| to_state(*xs) ).collect();
+    permute_batch_with_threads(&mut states, threads);
+    assert_eq!( states.iter().map( |xs| xs[0] ).collect::<Vec<Felt>>() , expected );
+  }
+  assert_eq!( compress_batch_with_threads(&[], 0) , vec![] );
+}
+
+//------------------------------------------------------------------------------